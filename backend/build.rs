@@ -0,0 +1,12 @@
+//! Generates the gRPC service code from `proto/` when the `grpc` feature is on.
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    #[cfg(feature = "grpc")]
+    {
+        std::env::set_var("PROTOC", protoc_bin_vendored::protoc_bin_path()?);
+        tonic_build::configure()
+            .build_client(true)
+            .compile(&["proto/choice_sherpa/v1/choice_sherpa.proto"], &["proto"])?;
+    }
+    Ok(())
+}
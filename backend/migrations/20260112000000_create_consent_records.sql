@@ -0,0 +1,36 @@
+-- 20260112000000_create_consent_records.sql
+-- Append-only consent ledger (terms of service, privacy policy, AI processing, profiling)
+
+CREATE TABLE consent_records (
+    id UUID PRIMARY KEY,
+    user_id VARCHAR(255) NOT NULL,
+    consent_type VARCHAR(50) NOT NULL CHECK (consent_type IN (
+        'terms_of_service', 'privacy_policy', 'ai_processing',
+        'profile_collection', 'profile_analysis', 'profile_agent_access'
+    )),
+    version VARCHAR(50) NOT NULL,
+    granted BOOLEAN NOT NULL,
+    source VARCHAR(50),
+    recorded_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+-- Lookup of a user's ledger in chronological order
+CREATE INDEX idx_consent_records_user_recorded ON consent_records(user_id, recorded_at);
+
+-- Records are append-only: reject updates at the database level
+CREATE OR REPLACE FUNCTION reject_consent_record_update()
+RETURNS TRIGGER AS $$
+BEGIN
+    RAISE EXCEPTION 'consent_records is append-only';
+END;
+$$ LANGUAGE plpgsql;
+
+CREATE TRIGGER consent_records_append_only
+    BEFORE UPDATE ON consent_records
+    FOR EACH ROW
+    EXECUTE FUNCTION reject_consent_record_update();
+
+-- Table comments
+COMMENT ON TABLE consent_records IS 'Append-only ledger of consent grants and withdrawals';
+COMMENT ON COLUMN consent_records.version IS 'Version of the document or purpose text the user accepted';
+COMMENT ON COLUMN consent_records.granted IS 'TRUE for a grant, FALSE for a withdrawal';
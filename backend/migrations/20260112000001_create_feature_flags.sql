@@ -0,0 +1,17 @@
+-- 20260112000001_create_feature_flags.sql
+-- Runtime-reloadable feature flags with per-tier and per-user targeting
+
+CREATE TABLE feature_flags (
+    key VARCHAR(64) PRIMARY KEY CHECK (key ~ '^[a-z0-9_.-]+$'),
+    description TEXT,
+    enabled BOOLEAN NOT NULL DEFAULT FALSE,
+    tier_overrides JSONB NOT NULL DEFAULT '{}'::jsonb,
+    user_overrides JSONB NOT NULL DEFAULT '{}'::jsonb,
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+-- Table comments
+COMMENT ON TABLE feature_flags IS 'Feature flags that can be changed without a restart';
+COMMENT ON COLUMN feature_flags.enabled IS 'Default value when no override matches';
+COMMENT ON COLUMN feature_flags.tier_overrides IS 'Map of membership tier to enabled, e.g. {"annual": true}';
+COMMENT ON COLUMN feature_flags.user_overrides IS 'Map of user ID to enabled; takes precedence over tier overrides';
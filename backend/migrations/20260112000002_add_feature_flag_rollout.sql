@@ -0,0 +1,8 @@
+-- 20260112000002_add_feature_flag_rollout.sql
+-- Percentage rollouts for feature flags
+
+ALTER TABLE feature_flags
+    ADD COLUMN rollout_percentage SMALLINT
+        CHECK (rollout_percentage BETWEEN 0 AND 100);
+
+COMMENT ON COLUMN feature_flags.rollout_percentage IS 'Share of users (0-100) who receive an enabled default; NULL means everyone';
@@ -0,0 +1,13 @@
+-- 20260113000000_create_document_templates.sql
+-- Per-organization Tera templates for the Markdown decision document export
+
+CREATE TABLE document_templates (
+    organization VARCHAR(253) PRIMARY KEY CHECK (organization ~ '^[a-z0-9][a-z0-9.-]*$'),
+    source TEXT NOT NULL,
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+-- Table comments
+COMMENT ON TABLE document_templates IS 'Custom decision document layouts, validated before they are stored';
+COMMENT ON COLUMN document_templates.organization IS 'Organization key (currently the verified email domain)';
+COMMENT ON COLUMN document_templates.source IS 'Tera template source rendering Markdown';
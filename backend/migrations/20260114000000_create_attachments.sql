@@ -0,0 +1,21 @@
+-- 20260114000000_create_attachments.sql
+-- Files attached to cycle components; bytes live in document storage
+
+CREATE TABLE attachments (
+    id UUID PRIMARY KEY,
+    cycle_id UUID NOT NULL REFERENCES cycles(id) ON DELETE CASCADE,
+    component_type VARCHAR(50) NOT NULL,
+    file_name VARCHAR(100) NOT NULL,
+    content_type VARCHAR(100) NOT NULL,
+    size_bytes BIGINT NOT NULL CHECK (size_bytes > 0),
+    caption TEXT,
+    uploaded_by VARCHAR(255) NOT NULL,
+    uploaded_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX idx_attachments_cycle ON attachments(cycle_id, uploaded_at);
+
+-- Table comments
+COMMENT ON TABLE attachments IS 'Metadata for files attached to cycle components';
+COMMENT ON COLUMN attachments.file_name IS 'Sanitized file name; the storage key is attachments/{cycle_id}/{id}/{file_name}';
+COMMENT ON COLUMN attachments.caption IS 'Optional caption shown with the file in exported documents';
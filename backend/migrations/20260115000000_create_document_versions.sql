@@ -0,0 +1,14 @@
+-- 20260115000000_create_document_versions.sql
+-- Version history of each cycle's Markdown decision document
+
+CREATE TABLE document_versions (
+    cycle_id UUID NOT NULL REFERENCES cycles(id) ON DELETE CASCADE,
+    version INTEGER NOT NULL CHECK (version > 0),
+    content TEXT NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    PRIMARY KEY (cycle_id, version)
+);
+
+-- Table comments
+COMMENT ON TABLE document_versions IS 'Full Markdown source of every stored revision of a decision document';
+COMMENT ON COLUMN document_versions.version IS 'Numbered from 1 per cycle; diffs are computed on read';
@@ -0,0 +1,31 @@
+-- 20260116000000_create_document_deliveries.sql
+-- Emailing completed decision documents: opt-in preferences and delivery log
+
+CREATE TABLE document_email_preferences (
+    user_id VARCHAR(255) PRIMARY KEY,
+    email VARCHAR(320) NOT NULL,
+    enabled BOOLEAN NOT NULL DEFAULT FALSE,
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE TABLE document_deliveries (
+    id UUID PRIMARY KEY,
+    cycle_id UUID NOT NULL REFERENCES cycles(id) ON DELETE CASCADE,
+    user_id VARCHAR(255) NOT NULL,
+    recipient VARCHAR(320) NOT NULL,
+    status VARCHAR(20) NOT NULL CHECK (status IN ('pending', 'sent', 'failed')),
+    attempts INTEGER NOT NULL DEFAULT 0,
+    last_error TEXT,
+    next_attempt_at TIMESTAMPTZ NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    sent_at TIMESTAMPTZ
+);
+
+CREATE INDEX idx_document_deliveries_due ON document_deliveries(next_attempt_at)
+    WHERE status = 'pending';
+CREATE INDEX idx_document_deliveries_cycle ON document_deliveries(cycle_id, user_id);
+
+-- Table comments
+COMMENT ON TABLE document_email_preferences IS 'Opt-in for emailing the decision document when a cycle completes';
+COMMENT ON TABLE document_deliveries IS 'Emailed decision documents; pending rows are the retry queue';
+COMMENT ON COLUMN document_deliveries.next_attempt_at IS 'When a pending delivery is next attempted (exponential backoff)';
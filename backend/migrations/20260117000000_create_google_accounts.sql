@@ -0,0 +1,15 @@
+-- 20260117000000_create_google_accounts.sql
+-- Google accounts connected for exporting decision documents to Google Docs
+
+CREATE TABLE google_accounts (
+    user_id VARCHAR(255) PRIMARY KEY,
+    access_token TEXT NOT NULL,
+    refresh_token TEXT,
+    expires_at TIMESTAMPTZ NOT NULL,
+    connected_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+-- Table comments
+COMMENT ON TABLE google_accounts IS 'Per-user Google OAuth credentials (drive.file scope) for Google Docs export';
+COMMENT ON COLUMN google_accounts.refresh_token IS 'Long-lived; deleted when the user disconnects';
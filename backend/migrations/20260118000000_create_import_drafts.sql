@@ -0,0 +1,20 @@
+-- 20260118000000_create_import_drafts.sql
+-- AI-drafted objectives, alternatives, and ratings imported from pasted notes
+
+CREATE TABLE import_drafts (
+    id UUID PRIMARY KEY,
+    cycle_id UUID NOT NULL REFERENCES cycles(id) ON DELETE CASCADE,
+    created_by VARCHAR(255) NOT NULL,
+    status VARCHAR(20) NOT NULL CHECK (status IN ('pending', 'confirmed', 'rejected', 'expired')),
+    draft JSONB NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX idx_import_drafts_cycle_pending ON import_drafts (cycle_id, created_at DESC)
+    WHERE status = 'pending';
+
+-- Table comments
+COMMENT ON TABLE import_drafts IS 'AI-drafted imports that only reach component outputs once the user confirms';
+COMMENT ON COLUMN import_drafts.id IS 'Same as the draft''s confirmation request ID';
+COMMENT ON COLUMN import_drafts.draft IS 'Serialized ImportDraft, including its confirmation request';
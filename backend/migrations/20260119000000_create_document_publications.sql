@@ -0,0 +1,20 @@
+-- 20260119000000_create_document_publications.sql
+-- Public, read-only snapshots of decision documents shared by signed link
+
+CREATE TABLE document_publications (
+    id UUID PRIMARY KEY,
+    cycle_id UUID NOT NULL REFERENCES cycles(id) ON DELETE CASCADE,
+    published_by VARCHAR(255) NOT NULL,
+    title TEXT NOT NULL,
+    html TEXT NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    expires_at TIMESTAMPTZ NOT NULL,
+    revoked_at TIMESTAMPTZ
+);
+
+CREATE INDEX idx_document_publications_cycle ON document_publications (cycle_id, created_at DESC);
+
+-- Table comments
+COMMENT ON TABLE document_publications IS 'Rendered decision document snapshots served to holders of a signed public link';
+COMMENT ON COLUMN document_publications.html IS 'Standalone HTML page, rendered once at publish time';
+COMMENT ON COLUMN document_publications.revoked_at IS 'Set when unpublished; revoked snapshots are not served even if the link has not expired';
@@ -0,0 +1,23 @@
+-- 20260120000000_create_data_exports.sql
+-- GDPR data portability: requests to export all of a user's data
+
+CREATE TABLE data_exports (
+    id UUID PRIMARY KEY,
+    user_id VARCHAR(255) NOT NULL,
+    recipient VARCHAR(320) NOT NULL,
+    status VARCHAR(20) NOT NULL CHECK (status IN ('pending', 'ready', 'failed')),
+    attempts INTEGER NOT NULL DEFAULT 0,
+    last_error TEXT,
+    next_attempt_at TIMESTAMPTZ NOT NULL,
+    requested_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    archive_key TEXT,
+    completed_at TIMESTAMPTZ
+);
+
+CREATE INDEX idx_data_exports_due ON data_exports(next_attempt_at)
+    WHERE status = 'pending';
+CREATE INDEX idx_data_exports_user ON data_exports(user_id, requested_at DESC);
+
+-- Table comments
+COMMENT ON TABLE data_exports IS 'GDPR data export requests; pending rows are the work queue';
+COMMENT ON COLUMN data_exports.archive_key IS 'Document storage key of the finished JSON archive';
@@ -0,0 +1,17 @@
+-- 20260121000000_create_decision_records.sql
+-- Decision history behind the decision profile: one row per completed decision
+
+CREATE TABLE decision_records (
+    user_id VARCHAR(255) NOT NULL,
+    cycle_id UUID NOT NULL REFERENCES cycles(id) ON DELETE CASCADE,
+    decided_at TIMESTAMPTZ NOT NULL,
+    record JSONB NOT NULL,
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    PRIMARY KEY (user_id, cycle_id)
+);
+
+CREATE INDEX idx_decision_records_user ON decision_records(user_id, decided_at);
+
+-- Table comments
+COMMENT ON TABLE decision_records IS 'Completed decisions and their outcomes, aggregated into the decision profile';
+COMMENT ON COLUMN decision_records.record IS 'Serialized DecisionRecord, including the outcome once recorded';
@@ -0,0 +1,32 @@
+-- 20260122000000_add_profile_team_sharing_consent.sql
+-- Consent to include an anonymized decision profile in the team profile,
+-- and organization-level team profile settings
+
+ALTER TABLE consent_records DROP CONSTRAINT consent_records_consent_type_check;
+ALTER TABLE consent_records ADD CONSTRAINT consent_records_consent_type_check
+    CHECK (consent_type IN (
+        'terms_of_service', 'privacy_policy', 'ai_processing',
+        'profile_collection', 'profile_analysis', 'profile_agent_access',
+        'profile_team_sharing'
+    ));
+
+CREATE TABLE profile_summaries (
+    user_id VARCHAR(255) PRIMARY KEY,
+    organization VARCHAR(253),
+    summary JSONB NOT NULL,
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX idx_profile_summaries_organization ON profile_summaries(organization)
+    WHERE organization IS NOT NULL;
+
+CREATE TABLE team_profile_settings (
+    organization VARCHAR(253) PRIMARY KEY,
+    agent_context_enabled BOOLEAN NOT NULL DEFAULT FALSE,
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+-- Table comments
+COMMENT ON TABLE profile_summaries IS 'Latest decision profile summary per user, with the organization they belong to';
+COMMENT ON TABLE team_profile_settings IS 'Per-organization settings for the anonymized team profile';
+COMMENT ON COLUMN team_profile_settings.agent_context_enabled IS 'Whether agents see the team profile in sessions of organization members';
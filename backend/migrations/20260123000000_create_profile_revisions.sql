@@ -0,0 +1,15 @@
+-- 20260123000000_create_profile_revisions.sql
+-- Version history of users' decision profile summaries
+
+CREATE TABLE profile_revisions (
+    user_id VARCHAR(255) NOT NULL,
+    version INTEGER NOT NULL CHECK (version > 0),
+    summary JSONB NOT NULL,
+    reason JSONB NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    PRIMARY KEY (user_id, version)
+);
+
+-- Table comments
+COMMENT ON TABLE profile_revisions IS 'Append-only history of decision profile summaries; rollbacks add a new revision';
+COMMENT ON COLUMN profile_revisions.reason IS 'Why the revision was created: analysis after a decision, or a rollback to an earlier version';
@@ -0,0 +1,10 @@
+-- 20260124000000_create_conversation_style_overrides.sql
+-- Conversations where the user turned off profile-driven agent style
+
+CREATE TABLE conversation_style_overrides (
+    conversation_id UUID PRIMARY KEY REFERENCES conversations(id) ON DELETE CASCADE,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+-- Table comments
+COMMENT ON TABLE conversation_style_overrides IS 'Opt-outs from adapting agent tone and pacing to the decision profile; adaptive style is on unless a row exists';
@@ -0,0 +1,29 @@
+-- 20260125000000_create_outcome_reminders.sql
+-- Outcome journaling: emailed satisfaction surveys sent after a decision's expected-outcome date
+
+CREATE TABLE outcome_reminders (
+    id UUID PRIMARY KEY,
+    user_id VARCHAR(255) NOT NULL,
+    cycle_id UUID NOT NULL REFERENCES cycles(id) ON DELETE CASCADE,
+    recipient VARCHAR(320) NOT NULL,
+    decision_title TEXT NOT NULL,
+    status VARCHAR(20) NOT NULL
+        CHECK (status IN ('scheduled', 'sent', 'responded', 'failed', 'cancelled')),
+    attempts INTEGER NOT NULL DEFAULT 0,
+    last_error TEXT,
+    next_attempt_at TIMESTAMPTZ NOT NULL,
+    survey_token_hash CHAR(64),
+    sent_at TIMESTAMPTZ,
+    responded_at TIMESTAMPTZ,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX idx_outcome_reminders_due ON outcome_reminders(next_attempt_at)
+    WHERE status = 'scheduled';
+CREATE INDEX idx_outcome_reminders_cycle ON outcome_reminders(user_id, cycle_id, created_at DESC);
+CREATE UNIQUE INDEX idx_outcome_reminders_token ON outcome_reminders(survey_token_hash)
+    WHERE survey_token_hash IS NOT NULL;
+
+-- Table comments
+COMMENT ON TABLE outcome_reminders IS 'Outcome survey reminders; scheduled rows are the work queue';
+COMMENT ON COLUMN outcome_reminders.survey_token_hash IS 'SHA-256 hex digest of the emailed survey link token';
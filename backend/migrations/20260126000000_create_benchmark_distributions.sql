@@ -0,0 +1,24 @@
+-- 20260126000000_create_benchmark_distributions.sql
+-- Opt-in anonymous benchmarking: consent type and the published aggregates
+
+ALTER TABLE consent_records DROP CONSTRAINT consent_records_consent_type_check;
+ALTER TABLE consent_records ADD CONSTRAINT consent_records_consent_type_check
+    CHECK (consent_type IN (
+        'terms_of_service', 'privacy_policy', 'ai_processing',
+        'profile_collection', 'profile_analysis', 'profile_agent_access',
+        'profile_team_sharing', 'profile_benchmarking'
+    ));
+
+CREATE TABLE benchmark_distributions (
+    domain VARCHAR(20) NOT NULL,
+    metric VARCHAR(255) NOT NULL,
+    contributors INTEGER NOT NULL CHECK (contributors > 0),
+    quantiles REAL[] NOT NULL,
+    generated_at TIMESTAMPTZ NOT NULL,
+    PRIMARY KEY (domain, metric)
+);
+
+-- Table comments
+COMMENT ON TABLE benchmark_distributions IS 'Anonymized per-domain distributions of decision metrics across opted-in users';
+COMMENT ON COLUMN benchmark_distributions.quantiles IS 'Metric values at the 5th, 10th, ... 95th percentiles';
+COMMENT ON COLUMN benchmark_distributions.contributors IS 'Users behind the distribution; never below the publishing minimum';
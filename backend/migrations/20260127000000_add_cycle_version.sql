@@ -0,0 +1,5 @@
+-- 20260127000000_add_cycle_version.sql
+-- Aggregate version for cycles, returned by commands so clients can reconcile
+-- optimistic updates with WebSocket events
+
+ALTER TABLE cycles ADD COLUMN version BIGINT NOT NULL DEFAULT 0;
@@ -0,0 +1,35 @@
+-- 20260128000000_create_slack_integration.sql
+-- Slack workspaces connected for sharing recommendations, and the share log
+
+CREATE TABLE slack_workspaces (
+    user_id VARCHAR(255) NOT NULL,
+    team_id VARCHAR(32) NOT NULL,
+    team_name VARCHAR(255) NOT NULL,
+    bot_token TEXT NOT NULL,
+    connected_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    PRIMARY KEY (user_id, team_id)
+);
+
+CREATE TABLE slack_shares (
+    id UUID PRIMARY KEY,
+    cycle_id UUID NOT NULL REFERENCES cycles(id) ON DELETE CASCADE,
+    user_id VARCHAR(255) NOT NULL,
+    team_id VARCHAR(32) NOT NULL,
+    channel VARCHAR(255) NOT NULL,
+    digest JSONB NOT NULL,
+    status VARCHAR(20) NOT NULL CHECK (status IN ('pending', 'sent', 'failed')),
+    attempts INTEGER NOT NULL DEFAULT 0,
+    last_error TEXT,
+    next_attempt_at TIMESTAMPTZ NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    sent_at TIMESTAMPTZ,
+    message_ts VARCHAR(32)
+);
+
+CREATE INDEX idx_slack_shares_due ON slack_shares(next_attempt_at)
+    WHERE status = 'pending';
+
+-- Table comments
+COMMENT ON TABLE slack_workspaces IS 'Per-user Slack app installs; bot tokens have the chat:write scopes';
+COMMENT ON TABLE slack_shares IS 'Recommendations shared to Slack; pending rows are the retry queue';
+COMMENT ON COLUMN slack_shares.digest IS 'Snapshot of the recommendation summary and public link, reposted unchanged on retry';
@@ -0,0 +1,42 @@
+-- 20260129000000_create_teams_integration.sql
+-- Teams channels registered per organization, the chat platform each organization shares to, and the Teams share log
+
+CREATE TABLE organization_chat_settings (
+    organization VARCHAR(255) PRIMARY KEY,
+    platform VARCHAR(10) NOT NULL CHECK (platform IN ('slack', 'teams')),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE TABLE organization_teams_channels (
+    organization VARCHAR(255) NOT NULL
+        REFERENCES organization_chat_settings(organization) ON DELETE CASCADE ON UPDATE CASCADE,
+    position INTEGER NOT NULL,
+    name VARCHAR(255) NOT NULL,
+    webhook_url BYTEA NOT NULL,
+    PRIMARY KEY (organization, position)
+);
+
+CREATE TABLE teams_shares (
+    id UUID PRIMARY KEY,
+    cycle_id UUID NOT NULL REFERENCES cycles(id) ON DELETE CASCADE,
+    user_id VARCHAR(255) NOT NULL,
+    organization VARCHAR(255) NOT NULL,
+    channel VARCHAR(255) NOT NULL,
+    digest JSONB NOT NULL,
+    status VARCHAR(20) NOT NULL CHECK (status IN ('pending', 'sent', 'failed')),
+    attempts INTEGER NOT NULL DEFAULT 0,
+    last_error TEXT,
+    next_attempt_at TIMESTAMPTZ NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    sent_at TIMESTAMPTZ
+);
+
+CREATE INDEX idx_teams_shares_due ON teams_shares(next_attempt_at)
+    WHERE status = 'pending';
+
+-- Table comments
+COMMENT ON TABLE organization_chat_settings IS 'Chat platform each organization shares recommendations to';
+COMMENT ON TABLE organization_teams_channels IS 'Teams channels an organization registered, in display order';
+COMMENT ON COLUMN organization_teams_channels.webhook_url IS 'Incoming-webhook URL encrypted with pgp_sym_encrypt; the URL is a credential';
+COMMENT ON TABLE teams_shares IS 'Recommendations shared to Teams; pending rows are the retry queue';
+COMMENT ON COLUMN teams_shares.channel IS 'Registered channel name; its webhook is looked up when posting';
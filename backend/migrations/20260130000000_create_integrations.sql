@@ -0,0 +1,43 @@
+-- 20260130000000_create_integrations.sql
+-- User-configured outbound integrations and their deliveries
+
+CREATE TABLE integrations (
+    id UUID PRIMARY KEY,
+    user_id VARCHAR(255) NOT NULL,
+    name VARCHAR(100) NOT NULL,
+    filter JSONB NOT NULL,
+    action JSONB NOT NULL,
+    template TEXT,
+    enabled BOOLEAN NOT NULL DEFAULT TRUE,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX idx_integrations_user ON integrations(user_id, created_at);
+
+CREATE TABLE integration_deliveries (
+    id UUID PRIMARY KEY,
+    integration_id UUID NOT NULL REFERENCES integrations(id) ON DELETE CASCADE,
+    user_id VARCHAR(255) NOT NULL,
+    event_id VARCHAR(255) NOT NULL,
+    event_type VARCHAR(100) NOT NULL,
+    context JSONB NOT NULL,
+    status VARCHAR(20) NOT NULL CHECK (status IN ('pending', 'sent', 'failed')),
+    attempts INTEGER NOT NULL DEFAULT 0,
+    last_error TEXT,
+    next_attempt_at TIMESTAMPTZ NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    sent_at TIMESTAMPTZ
+);
+
+CREATE INDEX idx_integration_deliveries_due ON integration_deliveries(next_attempt_at)
+    WHERE status = 'pending';
+CREATE INDEX idx_integration_deliveries_integration
+    ON integration_deliveries(integration_id, created_at DESC);
+
+-- Table comments
+COMMENT ON TABLE integrations IS 'Event-triggered automations: a filter, an action, and an optional payload template';
+COMMENT ON COLUMN integrations.filter IS 'EventFilter as {event_types, conditions}';
+COMMENT ON COLUMN integrations.action IS 'IntegrationAction tagged by type: http_post, slack, or email';
+COMMENT ON TABLE integration_deliveries IS 'Triggered integration actions; pending rows are the retry queue';
+COMMENT ON COLUMN integration_deliveries.context IS 'Template context captured from the triggering event';
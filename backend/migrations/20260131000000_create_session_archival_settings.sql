@@ -0,0 +1,24 @@
+-- 20260131000000_create_session_archival_settings.sql
+-- Auto-archive state for idle sessions: per-session exclusion and warnings
+
+CREATE TABLE session_archival_settings (
+    session_id UUID PRIMARY KEY REFERENCES sessions(id) ON DELETE CASCADE,
+    user_id VARCHAR(255) NOT NULL,
+    excluded BOOLEAN NOT NULL DEFAULT FALSE,
+    notify_email VARCHAR(320),
+    warned_at TIMESTAMPTZ,
+    archived_at TIMESTAMPTZ,
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX idx_session_archival_settings_user ON session_archival_settings(user_id);
+
+-- Idle-session scans order active sessions by their last update
+CREATE INDEX idx_sessions_active_updated_at ON sessions(updated_at)
+    WHERE status = 'active';
+
+-- Table comments
+COMMENT ON TABLE session_archival_settings IS 'Auto-archive exclusion and warning state; kept apart from sessions so recording a warning does not count as activity';
+COMMENT ON COLUMN session_archival_settings.notify_email IS 'Owner address archive warnings are emailed to';
+COMMENT ON COLUMN session_archival_settings.warned_at IS 'Latest archive warning; void once the session sees newer activity';
+COMMENT ON COLUMN session_archival_settings.archived_at IS 'When the auto-archive policy archived the session';
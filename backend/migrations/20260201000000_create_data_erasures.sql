@@ -0,0 +1,24 @@
+-- 20260201000000_create_data_erasures.sql
+-- GDPR right to erasure: requests to delete all of a user's data
+
+CREATE TABLE data_erasures (
+    id UUID PRIMARY KEY,
+    user_id VARCHAR(255) NOT NULL,
+    recipient VARCHAR(320),
+    status VARCHAR(20) NOT NULL CHECK (status IN ('pending', 'completed', 'failed')),
+    attempts INTEGER NOT NULL DEFAULT 0,
+    last_error TEXT,
+    next_attempt_at TIMESTAMPTZ NOT NULL,
+    requested_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    report JSONB,
+    completed_at TIMESTAMPTZ
+);
+
+CREATE INDEX idx_data_erasures_due ON data_erasures(next_attempt_at)
+    WHERE status = 'pending';
+CREATE INDEX idx_data_erasures_user ON data_erasures(user_id, requested_at DESC);
+
+-- Table comments
+COMMENT ON TABLE data_erasures IS 'GDPR erasure requests, kept as proof of erasure; pending rows are the work queue';
+COMMENT ON COLUMN data_erasures.recipient IS 'Completion email address; cleared once the email is sent';
+COMMENT ON COLUMN data_erasures.report IS 'Per-section erasure counts and records remaining at verification';
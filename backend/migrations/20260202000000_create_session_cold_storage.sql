@@ -0,0 +1,24 @@
+-- 20260202000000_create_session_cold_storage.sql
+-- Finished sessions whose cycles were moved to the document store
+
+CREATE TABLE session_cold_storage (
+    session_id UUID PRIMARY KEY REFERENCES sessions(id) ON DELETE CASCADE,
+    user_id VARCHAR(255) NOT NULL,
+    blob_key VARCHAR(500) NOT NULL,
+    cycle_ids UUID[] NOT NULL,
+    offloaded_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX idx_session_cold_storage_user ON session_cold_storage(user_id);
+
+-- Access by cycle ID finds the cold session holding the cycle
+CREATE INDEX idx_session_cold_storage_cycle_ids ON session_cold_storage USING GIN (cycle_ids);
+
+-- Cold storage scans order archived sessions by their last update
+CREATE INDEX idx_sessions_archived_updated_at ON sessions(updated_at)
+    WHERE status = 'archived';
+
+-- Table comments
+COMMENT ON TABLE session_cold_storage IS 'Sessions whose cycles and components were serialized to the document store and removed from the hot tables';
+COMMENT ON COLUMN session_cold_storage.blob_key IS 'Document storage key of the JSON snapshot';
+COMMENT ON COLUMN session_cold_storage.cycle_ids IS 'Cycles held in the snapshot';
@@ -0,0 +1,26 @@
+-- 20260203000000_create_event_log.sql
+-- Append-only log of published events, and how far each projection has read
+
+CREATE TABLE event_log (
+    position BIGSERIAL PRIMARY KEY,
+    event_id VARCHAR(100) NOT NULL UNIQUE,
+    event_type VARCHAR(100) NOT NULL,
+    aggregate_type VARCHAR(100) NOT NULL,
+    aggregate_id VARCHAR(255) NOT NULL,
+    occurred_at TIMESTAMPTZ NOT NULL,
+    envelope JSONB NOT NULL
+);
+
+-- Lag queries count events of a projection's types past its checkpoint
+CREATE INDEX idx_event_log_type_position ON event_log(event_type, position);
+
+CREATE TABLE projection_checkpoints (
+    projection VARCHAR(100) PRIMARY KEY,
+    position BIGINT NOT NULL,
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+-- Table comments
+COMMENT ON TABLE event_log IS 'Every published event in publication order, replayed to rebuild projections';
+COMMENT ON COLUMN event_log.envelope IS 'The full event envelope as published';
+COMMENT ON TABLE projection_checkpoints IS 'Last event log position each projection has read';
@@ -0,0 +1,83 @@
+-- 20260204000000_create_help_articles.sql
+-- Versioned, localized help articles explaining the decision methodology
+
+CREATE TABLE help_articles (
+    id VARCHAR(100) NOT NULL CHECK (id ~ '^[a-z0-9_-]+(\.[a-z0-9_-]+)*$'),
+    locale VARCHAR(35) NOT NULL,
+    version INTEGER NOT NULL CHECK (version > 0),
+    kind VARCHAR(20) NOT NULL CHECK (kind IN ('concept', 'component_guide', 'glossary')),
+    component_type VARCHAR(50),
+    title TEXT NOT NULL,
+    summary TEXT NOT NULL,
+    body TEXT NOT NULL,
+    updated_by VARCHAR(255),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    PRIMARY KEY (id, locale, version)
+);
+
+-- Latest-version lookups scan an article's versions newest first
+CREATE INDEX idx_help_articles_latest ON help_articles(locale, id, version DESC);
+
+-- Table comments
+COMMENT ON TABLE help_articles IS 'Every saved version of each help article per locale; the highest version is live';
+COMMENT ON COLUMN help_articles.id IS 'Stable dotted ID referenced from agent responses as [[help:<id>]]';
+COMMENT ON COLUMN help_articles.body IS 'Markdown shown when a learn-more card is expanded';
+
+-- Initial English content
+INSERT INTO help_articles (id, locale, version, kind, component_type, title, summary, body) VALUES
+('concept.proact', 'en', 1, 'concept', NULL,
+ 'The PrOACT approach',
+ 'PrOACT breaks a hard decision into Problem, Objectives, Alternatives, Consequences and Tradeoffs, so each part gets proper attention.',
+ 'PrOACT is a structured approach to making decisions, developed by Hammond, Keeney and Raiffa. Instead of trying to weigh everything at once, you work through five elements in turn: the **Problem** you are actually solving, the **Objectives** that matter to you, the **Alternatives** available, the **Consequences** of each, and the **Tradeoffs** between them.'),
+('concept.decision_quality', 'en', 1, 'concept', NULL,
+ 'Decision quality',
+ 'A good decision is judged by how it was made, not by how it turned out. Decision quality rates six elements of the process.',
+ 'Outcomes depend partly on luck, so Choice Sherpa rates the decision process instead. Each of six elements is scored from 0 to 100%: an appropriate frame, creative alternatives, relevant information, clear values and tradeoffs, sound reasoning, and commitment to action. The overall score is the lowest element, because a decision is only as strong as its weakest part.'),
+('component.issue_raising', 'en', 1, 'component_guide', 'issue_raising',
+ 'Issue Raising',
+ 'Get everything on your mind about the situation out in the open before deciding what the decision is.',
+ 'Start by listing the concerns, facts, uncertainties and ideas around your situation without judging them. Sorting them later into potential decisions, objectives and uncertainties makes sure nothing important is lost.'),
+('component.problem_frame', 'en', 1, 'component_guide', 'problem_frame',
+ 'Problem Frame',
+ 'State exactly what you are deciding, who decides, and by when.',
+ 'A well-framed problem names the decision, the decision maker, the deadline, and what is out of scope. Solving the wrong problem well is a common and expensive mistake.'),
+('component.objectives', 'en', 1, 'component_guide', 'objectives',
+ 'Objectives',
+ 'List what you want to achieve, separating what you care about in itself from the means of getting there.',
+ 'Fundamental objectives are what you ultimately care about; means objectives matter only because they help achieve them. Give each fundamental objective a way to measure it so alternatives can be compared.'),
+('component.alternatives', 'en', 1, 'component_guide', 'alternatives',
+ 'Alternatives',
+ 'Generate genuinely different options, including doing nothing.',
+ 'A decision can be no better than the best alternative considered. Aim for options that differ in kind, not just degree, and keep the status quo as a baseline.'),
+('component.consequences', 'en', 1, 'component_guide', 'consequences',
+ 'Consequences',
+ 'Describe how well each alternative does on each objective.',
+ 'Fill in the consequences table one objective at a time, rating every alternative against it. Note where you are uncertain; those cells are where more information is most valuable.'),
+('component.tradeoffs', 'en', 1, 'component_guide', 'tradeoffs',
+ 'Tradeoffs',
+ 'Remove options that are beaten on every objective, then decide what you would give up for what.',
+ 'An alternative is dominated when another is at least as good on every objective and better on one. Once those are removed, the remaining choice comes down to explicit tradeoffs between objectives.'),
+('component.recommendation', 'en', 1, 'component_guide', 'recommendation',
+ 'Recommendation',
+ 'Pull the analysis together into a choice and the reasons for it.',
+ 'The recommendation summarizes which alternative best serves your objectives, the key tradeoffs accepted, and what would change the answer.'),
+('component.decision_quality', 'en', 1, 'component_guide', 'decision_quality',
+ 'Decision Quality check',
+ 'Rate how well each element of the decision process was done before committing.',
+ 'Score each decision quality element honestly. The lowest score shows where a little more work would improve the decision the most.'),
+('component.notes_next_steps', 'en', 1, 'component_guide', 'notes_next_steps',
+ 'Notes and next steps',
+ 'Record what you decided to do next and anything to revisit later.',
+ 'Capture open questions, the first concrete actions, and when you will check how the decision turned out.'),
+('glossary.dominated-alternative', 'en', 1, 'glossary', NULL,
+ 'Dominated alternative',
+ 'An option that another option beats or ties on every objective.',
+ 'If alternative A is at least as good as B on every objective and strictly better on at least one, B is dominated and can be removed without losing anything.'),
+('glossary.fundamental-objective', 'en', 1, 'glossary', NULL,
+ 'Fundamental objective',
+ 'Something you care about for its own sake, rather than as a means to something else.',
+ 'Ask "why does this matter?" of each objective. When the answer is "it just does", you have reached a fundamental objective.'),
+('glossary.swing-weight', 'en', 1, 'glossary', NULL,
+ 'Swing weight',
+ 'How much you value moving an objective from its worst to its best level among your alternatives.',
+ 'Swing weights compare the ranges actually at stake rather than objectives in the abstract, which keeps weights honest when alternatives differ little on an objective you care about a lot.');
@@ -0,0 +1,42 @@
+-- 20260205000000_create_organizations.sql
+-- Organizations (teams), their members, and sessions shared with them
+
+CREATE TABLE organizations (
+    id UUID PRIMARY KEY,
+    name VARCHAR(100) NOT NULL,
+    billing_membership_id UUID REFERENCES memberships(id) ON DELETE SET NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX idx_organizations_billing_membership ON organizations(billing_membership_id)
+    WHERE billing_membership_id IS NOT NULL;
+
+CREATE TABLE organization_members (
+    organization_id UUID NOT NULL REFERENCES organizations(id) ON DELETE CASCADE,
+    user_id VARCHAR(255) NOT NULL,
+    role VARCHAR(20) NOT NULL CHECK (role IN ('owner', 'admin', 'member')),
+    joined_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    PRIMARY KEY (organization_id, user_id)
+);
+
+-- Access checks and "my organizations" look members up by user
+CREATE INDEX idx_organization_members_user ON organization_members(user_id);
+
+ALTER TABLE sessions
+    ADD COLUMN organization_id UUID REFERENCES organizations(id) ON DELETE SET NULL;
+
+CREATE INDEX idx_sessions_organization ON sessions(organization_id, updated_at DESC)
+    WHERE organization_id IS NOT NULL;
+
+-- Trigger for updated_at (reuse function from memberships migration)
+CREATE TRIGGER update_organizations_updated_at
+    BEFORE UPDATE ON organizations
+    FOR EACH ROW
+    EXECUTE FUNCTION update_updated_at_column();
+
+-- Table comments
+COMMENT ON TABLE organizations IS 'Teams whose members share sessions and a subscription';
+COMMENT ON COLUMN organizations.billing_membership_id IS 'Membership whose plan covers every member; NULL until an owner attaches one';
+COMMENT ON TABLE organization_members IS 'Users in each organization with their role';
+COMMENT ON COLUMN sessions.organization_id IS 'Organization the session is shared with, if any';
@@ -0,0 +1,32 @@
+-- 20260206000000_create_provisioned_users.sql
+-- Users managed by an enterprise identity provider through SCIM
+
+CREATE TABLE provisioned_users (
+    user_id VARCHAR(255) PRIMARY KEY,
+    user_name VARCHAR(255) NOT NULL,
+    email VARCHAR(255),
+    display_name VARCHAR(255),
+    active BOOLEAN NOT NULL DEFAULT TRUE,
+    provisioned_by VARCHAR(255) NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+-- SCIM userName uniqueness is case-insensitive
+CREATE UNIQUE INDEX idx_provisioned_users_user_name ON provisioned_users(LOWER(user_name));
+
+-- Access checks look for deactivated users
+CREATE INDEX idx_provisioned_users_inactive ON provisioned_users(user_id)
+    WHERE NOT active;
+
+-- Trigger for updated_at (reuse function from memberships migration)
+CREATE TRIGGER update_provisioned_users_updated_at
+    BEFORE UPDATE ON provisioned_users
+    FOR EACH ROW
+    EXECUTE FUNCTION update_updated_at_column();
+
+-- Table comments
+COMMENT ON TABLE provisioned_users IS 'Users created and kept in sync by an identity provider via SCIM';
+COMMENT ON COLUMN provisioned_users.user_id IS 'Subject of the user at the identity provider';
+COMMENT ON COLUMN provisioned_users.active IS 'FALSE once deprovisioned; deactivated users have no plan access';
+COMMENT ON COLUMN provisioned_users.provisioned_by IS 'OAuth client ID of the provisioning service';
@@ -0,0 +1,7 @@
+-- 20260207000000_add_membership_seats.sql
+-- Seat counts for memberships that pay for an organization
+
+ALTER TABLE memberships
+    ADD COLUMN seats INTEGER NOT NULL DEFAULT 1 CHECK (seats >= 1);
+
+COMMENT ON COLUMN memberships.seats IS 'Seats paid for; matches the subscription quantity and the size of the organization billed to it';
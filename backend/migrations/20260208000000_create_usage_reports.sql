@@ -0,0 +1,18 @@
+-- 20260208000000_create_usage_reports.sql
+-- Ledger of AI usage reported to metered subscriptions, one row per user and UTC day
+
+CREATE TABLE usage_reports (
+    user_id VARCHAR(255) NOT NULL,
+    usage_day TIMESTAMPTZ NOT NULL,
+    subscription_id VARCHAR(255) NOT NULL,
+    tokens BIGINT NOT NULL CHECK (tokens >= 0),
+    cost_cents INTEGER NOT NULL CHECK (cost_cents >= 0),
+    idempotency_key VARCHAR(255) NOT NULL,
+    reported_at TIMESTAMPTZ NOT NULL,
+    PRIMARY KEY (user_id, usage_day)
+);
+
+CREATE INDEX idx_usage_reports_usage_day ON usage_reports(usage_day);
+
+COMMENT ON TABLE usage_reports IS 'Daily token usage accepted by the payment provider; a row means the day is billed';
+COMMENT ON COLUMN usage_reports.idempotency_key IS 'Key sent with the report so retries are not billed twice';
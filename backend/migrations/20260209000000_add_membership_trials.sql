@@ -0,0 +1,13 @@
+-- 20260209000000_add_membership_trials.sql
+-- Free trials of paid tiers
+--
+-- The original status constraint predates the past_due state as well, so
+-- it is replaced with one covering every MembershipStatus.
+
+ALTER TABLE memberships DROP CONSTRAINT IF EXISTS memberships_status_check;
+
+ALTER TABLE memberships
+    ADD CONSTRAINT memberships_status_check
+    CHECK (status IN ('pending', 'trialing', 'active', 'past_due', 'cancelled', 'expired'));
+
+COMMENT ON COLUMN memberships.current_period_end IS 'End of the billing period, or of the free trial while status is trialing';
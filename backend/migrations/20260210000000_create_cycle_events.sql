@@ -0,0 +1,25 @@
+-- 20260210000000_create_cycle_events.sql
+-- Event streams and periodic snapshots for cycles, so any past version of a
+-- cycle can be rebuilt
+
+CREATE TABLE cycle_events (
+    cycle_id UUID NOT NULL REFERENCES cycles(id) ON DELETE CASCADE,
+    version BIGINT NOT NULL CHECK (version > 0),
+    event_type VARCHAR(100) NOT NULL,
+    schema_version INTEGER NOT NULL,
+    payload JSONB NOT NULL,
+    occurred_at TIMESTAMPTZ NOT NULL,
+    PRIMARY KEY (cycle_id, version)
+);
+
+CREATE TABLE cycle_snapshots (
+    cycle_id UUID NOT NULL REFERENCES cycles(id) ON DELETE CASCADE,
+    version BIGINT NOT NULL,
+    state JSONB NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    PRIMARY KEY (cycle_id, version)
+);
+
+COMMENT ON TABLE cycle_events IS 'Every change to a cycle, numbered by the cycle version it produced';
+COMMENT ON COLUMN cycle_events.event_type IS 'Versioned type (e.g. cycle.created.v1); older versions are upcast on read';
+COMMENT ON TABLE cycle_snapshots IS 'Cycle state every 50 events, and when a cycle without history is first saved';
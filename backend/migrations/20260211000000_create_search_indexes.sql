@@ -0,0 +1,15 @@
+-- 20260211000000_create_search_indexes.sql
+-- Full-text indexes behind GET /api/search
+--
+-- Sessions are already indexed by idx_sessions_search. Queries must repeat
+-- these expressions exactly for the planner to use the indexes.
+
+-- String values of component outputs; keys and numbers are not searchable
+CREATE INDEX idx_components_output_search ON components USING GIN (
+    jsonb_to_tsvector('english', output, '["string"]')
+);
+
+-- Only user and assistant messages are shown, so system prompts are not indexed
+CREATE INDEX idx_messages_content_search ON messages USING GIN (
+    to_tsvector('english', content)
+) WHERE role IN ('user', 'assistant');
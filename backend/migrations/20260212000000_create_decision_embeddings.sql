@@ -0,0 +1,28 @@
+-- 20260212000000_create_decision_embeddings.sql
+-- Embeddings of component outputs and conversation summaries for finding
+-- similar past decisions
+
+CREATE EXTENSION IF NOT EXISTS vector;
+
+CREATE TABLE decision_embeddings (
+    cycle_id UUID NOT NULL REFERENCES cycles(id) ON DELETE CASCADE,
+    component_type VARCHAR(50) NOT NULL,
+    source VARCHAR(30) NOT NULL CHECK (source IN ('component_output', 'conversation_summary')),
+    session_id UUID NOT NULL,
+    user_id VARCHAR(255) NOT NULL,
+    content_hash CHAR(64) NOT NULL,
+    model VARCHAR(100) NOT NULL,
+    embedding vector(1536) NOT NULL,
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    PRIMARY KEY (cycle_id, component_type, source)
+);
+
+-- Searches never cross users, and one user's decisions are few enough to
+-- compare exactly, so there is no approximate (HNSW/IVFFlat) index
+CREATE INDEX idx_decision_embeddings_user ON decision_embeddings(user_id, model);
+
+-- Table comments
+COMMENT ON TABLE decision_embeddings IS 'Vector embeddings of past decisions for semantic similarity search';
+COMMENT ON COLUMN decision_embeddings.user_id IS 'Session owner; similarity is only computed within one user''s decisions';
+COMMENT ON COLUMN decision_embeddings.content_hash IS 'SHA-256 of the embedded text, to skip re-embedding unchanged content';
+COMMENT ON COLUMN decision_embeddings.model IS 'Embedding model; vectors from different models are never compared';
@@ -0,0 +1,28 @@
+-- 20260213000000_add_session_trash.sql
+-- Deleted sessions stay in the trash, restorable, until they are purged
+
+ALTER TABLE sessions DROP CONSTRAINT sessions_status_check;
+ALTER TABLE sessions ADD CONSTRAINT sessions_status_check
+    CHECK (status IN ('active', 'archived', 'deleted'));
+
+ALTER TABLE sessions
+    ADD COLUMN deleted_at TIMESTAMPTZ,
+    ADD COLUMN status_before_deletion VARCHAR(20)
+        CHECK (status_before_deletion IN ('active', 'archived'));
+
+ALTER TABLE sessions ADD CONSTRAINT sessions_deletion_consistent CHECK (
+    (status = 'deleted') = (deleted_at IS NOT NULL AND status_before_deletion IS NOT NULL)
+);
+
+-- Trash listings show a user's deleted sessions, most recent first
+CREATE INDEX idx_sessions_trash ON sessions(user_id, deleted_at DESC)
+    WHERE status = 'deleted';
+
+-- Purge scans take the longest deleted sessions of any user
+CREATE INDEX idx_sessions_deleted_at ON sessions(deleted_at)
+    WHERE status = 'deleted';
+
+-- Column comments
+COMMENT ON COLUMN sessions.status IS 'Session status: active, archived, or deleted (in the trash)';
+COMMENT ON COLUMN sessions.deleted_at IS 'When the session was moved to the trash; purged 30 days later';
+COMMENT ON COLUMN sessions.status_before_deletion IS 'Status the session returns to when restored from the trash';
@@ -0,0 +1,39 @@
+-- 20260214000000_create_audit_log.sql
+-- Append-only, hash-chained log of every command handler execution
+
+CREATE TABLE audit_log (
+    sequence BIGINT PRIMARY KEY CHECK (sequence > 0),
+    actor_id VARCHAR(255) NOT NULL,
+    command_type VARCHAR(100) NOT NULL,
+    aggregate_type VARCHAR(50) NOT NULL,
+    aggregate_id VARCHAR(255) NOT NULL,
+    correlation_id VARCHAR(255),
+    source VARCHAR(50),
+    before_summary JSONB,
+    after_summary JSONB,
+    recorded_at TIMESTAMPTZ NOT NULL,
+    previous_hash CHAR(64) NOT NULL,
+    hash CHAR(64) NOT NULL UNIQUE
+);
+
+-- Admin lookups by actor and by aggregate, newest first
+CREATE INDEX idx_audit_log_actor ON audit_log(actor_id, sequence DESC);
+CREATE INDEX idx_audit_log_aggregate ON audit_log(aggregate_type, aggregate_id, sequence DESC);
+
+-- Entries are immutable: reject updates and deletes at the database level
+CREATE OR REPLACE FUNCTION reject_audit_log_change()
+RETURNS TRIGGER AS $$
+BEGIN
+    RAISE EXCEPTION 'audit_log is append-only';
+END;
+$$ LANGUAGE plpgsql;
+
+CREATE TRIGGER audit_log_append_only
+    BEFORE UPDATE OR DELETE ON audit_log
+    FOR EACH ROW
+    EXECUTE FUNCTION reject_audit_log_change();
+
+-- Table comments
+COMMENT ON TABLE audit_log IS 'Append-only record of command executions, each entry hashing the one before';
+COMMENT ON COLUMN audit_log.sequence IS 'Position in the chain, starting at 1 with no gaps';
+COMMENT ON COLUMN audit_log.hash IS 'SHA-256 over previous_hash and the entry content, hex-encoded';
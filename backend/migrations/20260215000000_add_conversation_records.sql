@@ -0,0 +1,28 @@
+-- 20260215000000_add_conversation_records.sql
+-- Columns the conversation handlers persist: agent phase, system prompt,
+-- per-message token counts, and a stable message order for regeneration
+
+ALTER TABLE conversations
+    ADD COLUMN IF NOT EXISTS component_type VARCHAR(20) CHECK (
+        component_type IN (
+            'issue_raising', 'problem_frame', 'objectives', 'alternatives',
+            'consequences', 'tradeoffs', 'recommendation', 'decision_quality',
+            'notes_next_steps'
+        )
+    ),
+    ADD COLUMN IF NOT EXISTS current_phase VARCHAR(20) NOT NULL DEFAULT 'intro' CHECK (
+        current_phase IN ('intro', 'gather', 'clarify', 'extract', 'confirm')
+    ),
+    ADD COLUMN IF NOT EXISTS system_prompt TEXT NOT NULL DEFAULT '';
+
+ALTER TABLE messages
+    ADD COLUMN token_count INTEGER CHECK (token_count >= 0),
+    ADD COLUMN position BIGINT GENERATED ALWAYS AS IDENTITY;
+
+-- Pagination and "last message" lookups walk messages in insertion order
+CREATE INDEX idx_messages_conversation_position
+    ON messages(conversation_id, position);
+
+COMMENT ON COLUMN conversations.system_prompt IS 'System prompt the conversation was started with';
+COMMENT ON COLUMN messages.token_count IS 'Completion tokens used to generate the message, when known';
+COMMENT ON COLUMN messages.position IS 'Insertion order; messages created in the same instant keep their order';
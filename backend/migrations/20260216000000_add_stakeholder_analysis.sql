@@ -0,0 +1,64 @@
+-- 20260216000000_add_stakeholder_analysis.sql
+-- Stakeholder Analysis component between Problem Frame and Objectives
+
+ALTER TABLE cycles DROP CONSTRAINT cycles_branch_point_check;
+ALTER TABLE cycles ADD CONSTRAINT cycles_branch_point_check CHECK (
+    branch_point IS NULL OR
+    branch_point IN (
+        'issue_raising', 'problem_frame', 'stakeholder_analysis', 'objectives',
+        'alternatives', 'consequences', 'tradeoffs', 'recommendation',
+        'decision_quality', 'notes_next_steps'
+    )
+);
+
+ALTER TABLE cycles DROP CONSTRAINT cycles_current_step_check;
+ALTER TABLE cycles ADD CONSTRAINT cycles_current_step_check CHECK (
+    current_step IN (
+        'issue_raising', 'problem_frame', 'stakeholder_analysis', 'objectives',
+        'alternatives', 'consequences', 'tradeoffs', 'recommendation',
+        'decision_quality', 'notes_next_steps'
+    )
+);
+
+ALTER TABLE components DROP CONSTRAINT components_component_type_check;
+ALTER TABLE components ADD CONSTRAINT components_component_type_check CHECK (
+    component_type IN (
+        'issue_raising', 'problem_frame', 'stakeholder_analysis', 'objectives',
+        'alternatives', 'consequences', 'tradeoffs', 'recommendation',
+        'decision_quality', 'notes_next_steps'
+    )
+);
+
+ALTER TABLE conversations DROP CONSTRAINT conversations_component_type_check;
+ALTER TABLE conversations ADD CONSTRAINT conversations_component_type_check CHECK (
+    component_type IN (
+        'issue_raising', 'problem_frame', 'stakeholder_analysis', 'objectives',
+        'alternatives', 'consequences', 'tradeoffs', 'recommendation',
+        'decision_quality', 'notes_next_steps'
+    )
+);
+
+ALTER TABLE tool_invocations DROP CONSTRAINT valid_component;
+ALTER TABLE tool_invocations ADD CONSTRAINT valid_component CHECK (component IN (
+    'issue_raising', 'problem_frame', 'stakeholder_analysis', 'objectives',
+    'alternatives', 'consequences', 'tradeoffs', 'recommendation', 'decision_quality'
+));
+
+ALTER TABLE revisit_suggestions DROP CONSTRAINT valid_target_component;
+ALTER TABLE revisit_suggestions ADD CONSTRAINT valid_target_component CHECK (target_component IN (
+    'issue_raising', 'problem_frame', 'stakeholder_analysis', 'objectives',
+    'alternatives', 'consequences', 'tradeoffs', 'recommendation', 'decision_quality'
+));
+
+-- Cycles created before this migration get the component, not started, so
+-- they can still reach Objectives
+INSERT INTO components (id, cycle_id, component_type, status, output)
+SELECT gen_random_uuid(), id, 'stakeholder_analysis', 'not_started', '{}'
+FROM cycles
+ON CONFLICT (cycle_id, component_type) DO NOTHING;
+
+INSERT INTO help_articles (id, locale, version, kind, component_type, title, summary, body) VALUES
+('component.stakeholder_analysis', 'en', 1, 'component_guide', 'stakeholder_analysis',
+ 'Stakeholder Analysis',
+ 'Map who the decision affects and who can shape it, and plan how to involve each of them.',
+ 'Place each stakeholder on a grid of power (how much they can shape or block the decision) and interest (how much the outcome matters to them). Manage high-power, high-interest stakeholders closely, keep powerful but less interested ones satisfied, and keep interested ones informed. Their concerns are often objectives you would otherwise miss.');
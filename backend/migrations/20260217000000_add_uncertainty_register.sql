@@ -0,0 +1,66 @@
+-- 20260217000000_add_uncertainty_register.sql
+-- Uncertainty Register component between Consequences and Tradeoffs
+
+ALTER TABLE cycles DROP CONSTRAINT cycles_branch_point_check;
+ALTER TABLE cycles ADD CONSTRAINT cycles_branch_point_check CHECK (
+    branch_point IS NULL OR
+    branch_point IN (
+        'issue_raising', 'problem_frame', 'stakeholder_analysis', 'objectives',
+        'alternatives', 'consequences', 'uncertainty_register', 'tradeoffs',
+        'recommendation', 'decision_quality', 'notes_next_steps'
+    )
+);
+
+ALTER TABLE cycles DROP CONSTRAINT cycles_current_step_check;
+ALTER TABLE cycles ADD CONSTRAINT cycles_current_step_check CHECK (
+    current_step IN (
+        'issue_raising', 'problem_frame', 'stakeholder_analysis', 'objectives',
+        'alternatives', 'consequences', 'uncertainty_register', 'tradeoffs',
+        'recommendation', 'decision_quality', 'notes_next_steps'
+    )
+);
+
+ALTER TABLE components DROP CONSTRAINT components_component_type_check;
+ALTER TABLE components ADD CONSTRAINT components_component_type_check CHECK (
+    component_type IN (
+        'issue_raising', 'problem_frame', 'stakeholder_analysis', 'objectives',
+        'alternatives', 'consequences', 'uncertainty_register', 'tradeoffs',
+        'recommendation', 'decision_quality', 'notes_next_steps'
+    )
+);
+
+ALTER TABLE conversations DROP CONSTRAINT conversations_component_type_check;
+ALTER TABLE conversations ADD CONSTRAINT conversations_component_type_check CHECK (
+    component_type IN (
+        'issue_raising', 'problem_frame', 'stakeholder_analysis', 'objectives',
+        'alternatives', 'consequences', 'uncertainty_register', 'tradeoffs',
+        'recommendation', 'decision_quality', 'notes_next_steps'
+    )
+);
+
+ALTER TABLE tool_invocations DROP CONSTRAINT valid_component;
+ALTER TABLE tool_invocations ADD CONSTRAINT valid_component CHECK (component IN (
+    'issue_raising', 'problem_frame', 'stakeholder_analysis', 'objectives',
+    'alternatives', 'consequences', 'uncertainty_register', 'tradeoffs',
+    'recommendation', 'decision_quality'
+));
+
+ALTER TABLE revisit_suggestions DROP CONSTRAINT valid_target_component;
+ALTER TABLE revisit_suggestions ADD CONSTRAINT valid_target_component CHECK (target_component IN (
+    'issue_raising', 'problem_frame', 'stakeholder_analysis', 'objectives',
+    'alternatives', 'consequences', 'uncertainty_register', 'tradeoffs',
+    'recommendation', 'decision_quality'
+));
+
+-- Cycles created before this migration get the component, not started, so
+-- they can still reach Tradeoffs
+INSERT INTO components (id, cycle_id, component_type, status, output)
+SELECT gen_random_uuid(), id, 'uncertainty_register', 'not_started', '{}'
+FROM cycles
+ON CONFLICT (cycle_id, component_type) DO NOTHING;
+
+INSERT INTO help_articles (id, locale, version, kind, component_type, title, summary, body) VALUES
+('component.uncertainty_register', 'en', 1, 'component_guide', 'uncertainty_register',
+ 'Uncertainty Register',
+ 'List the uncertainties behind your consequence ratings, how likely and how serious each is, and what you could do about them.',
+ 'For each key uncertainty, estimate the chance that things go the wrong way and how much that would change the decision. Uncertainties that are both likely and serious deserve attention before you commit: you may be able to resolve them with more information, make them less likely, hedge against them, or decide to accept them. Entries can link back to the uncertainties noted in the consequences table.');
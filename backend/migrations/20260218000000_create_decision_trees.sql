@@ -0,0 +1,20 @@
+-- 20260218000000_create_decision_trees.sql
+-- Decision trees for sequential decisions within a cycle
+
+CREATE TABLE decision_trees (
+    id UUID PRIMARY KEY,
+    cycle_id UUID NOT NULL REFERENCES cycles(id) ON DELETE CASCADE,
+    user_id VARCHAR(255) NOT NULL,
+    name VARCHAR(200) NOT NULL,
+    payoff_unit VARCHAR(50),
+    root_id VARCHAR(20),
+    nodes JSONB NOT NULL DEFAULT '[]',
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX idx_decision_trees_cycle ON decision_trees(cycle_id, created_at);
+
+-- Table comments
+COMMENT ON TABLE decision_trees IS 'Decision, chance, and terminal nodes modelling a cycle''s sequential decision';
+COMMENT ON COLUMN decision_trees.nodes IS 'Nodes as [{id, label, kind, payoff?, branches: [{label, child_id, probability?}]}]';
@@ -0,0 +1,62 @@
+-- 20260219000000_key_organization_settings_by_id.sql
+-- Key per-organization settings by organization id instead of email domain
+--
+-- Document templates, chat settings and team profiles were keyed by the
+-- user's email domain before organizations existed. Each such domain
+-- becomes an organization, the users known to have an address there join
+-- it, and the settings move to the organization's id. Organization admins
+-- used to be configured outside the database, so migrated organizations
+-- start without owners; platform admins can still manage their settings.
+
+CREATE TEMP TABLE domain_organizations (
+    domain VARCHAR(253) PRIMARY KEY,
+    organization_id UUID NOT NULL
+) ON COMMIT DROP;
+
+INSERT INTO domain_organizations (domain, organization_id)
+SELECT domain, gen_random_uuid()
+FROM (
+    SELECT organization AS domain FROM document_templates
+    UNION SELECT organization FROM team_profile_settings
+    UNION SELECT organization FROM organization_chat_settings
+    UNION SELECT organization FROM profile_summaries WHERE organization IS NOT NULL
+) domains;
+
+INSERT INTO organizations (id, name)
+SELECT organization_id, LEFT(domain, 100) FROM domain_organizations;
+
+-- Users known by an address at the domain, or whose profile recorded it
+INSERT INTO organization_members (organization_id, user_id, role)
+SELECT DISTINCT d.organization_id, u.user_id, 'member'
+FROM domain_organizations d
+JOIN (
+    SELECT user_id, LOWER(SPLIT_PART(email, '@', 2)) AS domain
+    FROM provisioned_users WHERE email IS NOT NULL
+    UNION SELECT user_id, LOWER(SPLIT_PART(email, '@', 2))
+    FROM document_email_preferences
+    UNION SELECT user_id, organization FROM profile_summaries WHERE organization IS NOT NULL
+) u ON u.domain = d.domain;
+
+UPDATE document_templates t SET organization = d.organization_id::TEXT
+FROM domain_organizations d WHERE t.organization = d.domain;
+
+UPDATE team_profile_settings t SET organization = d.organization_id::TEXT
+FROM domain_organizations d WHERE t.organization = d.domain;
+
+UPDATE organization_chat_settings t SET organization = d.organization_id::TEXT
+FROM domain_organizations d WHERE t.organization = d.domain;
+
+-- Teams shares record the organization owning the channel; registered
+-- channels follow their settings row through ON UPDATE CASCADE
+UPDATE teams_shares s SET organization = d.organization_id::TEXT
+FROM domain_organizations d WHERE s.organization = d.domain;
+
+-- Membership now lives in organization_members
+DROP INDEX idx_profile_summaries_organization;
+ALTER TABLE profile_summaries DROP COLUMN organization;
+
+-- Table comments
+COMMENT ON COLUMN document_templates.organization IS 'Organization id the template applies to';
+COMMENT ON COLUMN team_profile_settings.organization IS 'Organization id the settings apply to';
+COMMENT ON COLUMN organization_chat_settings.organization IS 'Organization id the settings apply to';
+COMMENT ON TABLE profile_summaries IS 'Latest decision profile summary per user';
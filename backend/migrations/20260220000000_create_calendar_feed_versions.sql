@@ -0,0 +1,12 @@
+-- 20260220000000_create_calendar_feed_versions.sql
+-- Per-user calendar feed versions, so a leaked feed URL can be retired
+
+CREATE TABLE calendar_feed_versions (
+    user_id VARCHAR(255) PRIMARY KEY,
+    version INTEGER NOT NULL CHECK (version > 0),
+    rotated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+-- Table comments
+COMMENT ON TABLE calendar_feed_versions IS 'Calendar feed version signed into each user''s feed URL; users without a row are on version 0';
+COMMENT ON COLUMN calendar_feed_versions.version IS 'Feed URLs signed for any other version are refused';
@@ -0,0 +1,14 @@
+-- 20260221000000_create_audit_pseudonym_keys.sql
+-- Per-user keys that users are pseudonymized with in the audit log. The
+-- log itself is append-only; erasing a user deletes their key instead,
+-- which unlinks their entries from them.
+
+CREATE TABLE audit_pseudonym_keys (
+    user_id VARCHAR(255) PRIMARY KEY,
+    key TEXT NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+-- Table comments
+COMMENT ON TABLE audit_pseudonym_keys IS 'HMAC key each user''s id is pseudonymized with in audit_log';
+COMMENT ON COLUMN audit_pseudonym_keys.key IS 'Deleted on erasure, after which the user''s audit entries cannot be linked to them';
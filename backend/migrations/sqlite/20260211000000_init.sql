@@ -0,0 +1,91 @@
+-- 20260211000000_init.sql
+-- SQLite schema for single-binary self-hosting
+--
+-- Mirrors the PostgreSQL tables the SQLite adapters use. UUIDs are stored
+-- as hyphenated TEXT, timestamps as RFC 3339 TEXT and component output as
+-- JSON TEXT.
+
+CREATE TABLE sessions (
+    id TEXT PRIMARY KEY,
+    user_id TEXT NOT NULL,
+    organization_id TEXT,
+    title TEXT NOT NULL,
+    description TEXT,
+    status TEXT NOT NULL CHECK (status IN ('active', 'archived')),
+    created_at TEXT NOT NULL,
+    updated_at TEXT NOT NULL
+);
+
+CREATE INDEX idx_sessions_user_status ON sessions(user_id, status);
+CREATE INDEX idx_sessions_organization ON sessions(organization_id);
+
+CREATE TABLE cycles (
+    id TEXT PRIMARY KEY,
+    session_id TEXT NOT NULL REFERENCES sessions(id) ON DELETE CASCADE,
+    parent_cycle_id TEXT REFERENCES cycles(id) ON DELETE CASCADE,
+    branch_point TEXT CHECK (
+        branch_point IS NULL OR
+        branch_point IN (
+            'issue_raising', 'problem_frame', 'objectives', 'alternatives',
+            'consequences', 'tradeoffs', 'recommendation', 'decision_quality',
+            'notes_next_steps'
+        )
+    ),
+    status TEXT NOT NULL CHECK (status IN ('active', 'completed', 'archived')),
+    current_step TEXT NOT NULL CHECK (
+        current_step IN (
+            'issue_raising', 'problem_frame', 'objectives', 'alternatives',
+            'consequences', 'tradeoffs', 'recommendation', 'decision_quality',
+            'notes_next_steps'
+        )
+    ),
+    created_at TEXT NOT NULL,
+    updated_at TEXT NOT NULL,
+    version INTEGER NOT NULL DEFAULT 0
+);
+
+CREATE INDEX idx_cycles_session_id ON cycles(session_id);
+CREATE INDEX idx_cycles_parent_id ON cycles(parent_cycle_id);
+
+-- Keyed by cycle and type: branches copy their parent's components, IDs
+-- included
+CREATE TABLE components (
+    id TEXT NOT NULL,
+    cycle_id TEXT NOT NULL REFERENCES cycles(id) ON DELETE CASCADE,
+    component_type TEXT NOT NULL CHECK (
+        component_type IN (
+            'issue_raising', 'problem_frame', 'objectives', 'alternatives',
+            'consequences', 'tradeoffs', 'recommendation', 'decision_quality',
+            'notes_next_steps'
+        )
+    ),
+    status TEXT NOT NULL CHECK (
+        status IN ('not_started', 'in_progress', 'complete', 'needs_revision')
+    ),
+    output TEXT NOT NULL DEFAULT '{}',
+    created_at TEXT NOT NULL,
+    updated_at TEXT NOT NULL,
+
+    PRIMARY KEY (cycle_id, component_type)
+);
+
+CREATE TABLE memberships (
+    id TEXT PRIMARY KEY,
+    user_id TEXT NOT NULL UNIQUE,
+    tier TEXT NOT NULL CHECK (tier IN ('free', 'monthly', 'annual')),
+    status TEXT NOT NULL CHECK (
+        status IN ('pending', 'trialing', 'active', 'past_due', 'cancelled', 'expired')
+    ),
+    stripe_customer_id TEXT,
+    stripe_subscription_id TEXT,
+    seats INTEGER NOT NULL DEFAULT 1 CHECK (seats >= 1),
+    promo_code TEXT,
+    current_period_start TEXT,
+    current_period_end TEXT,
+    created_at TEXT NOT NULL,
+    updated_at TEXT NOT NULL,
+    version INTEGER NOT NULL DEFAULT 1
+);
+
+CREATE INDEX idx_memberships_stripe_customer ON memberships(stripe_customer_id);
+CREATE INDEX idx_memberships_stripe_subscription ON memberships(stripe_subscription_id);
@@ -0,0 +1,11 @@
+-- 20260213000000_add_session_trash.sql
+-- Deleted sessions stay in the trash, restorable, until they are purged
+--
+-- SQLite cannot widen the status CHECK in place, so unlike PostgreSQL a
+-- deleted session keeps the status it is restored to and deleted_at alone
+-- marks it as trashed.
+
+ALTER TABLE sessions ADD COLUMN deleted_at TEXT;
+
+CREATE INDEX idx_sessions_deleted_at ON sessions(deleted_at)
+    WHERE deleted_at IS NOT NULL;
@@ -0,0 +1,86 @@
+-- 20260216000000_add_stakeholder_analysis.sql
+-- Stakeholder Analysis component between Problem Frame and Objectives
+--
+-- SQLite cannot widen a CHECK in place, so cycles and components are
+-- rebuilt. The old tables are renamed first so the new ones never reference
+-- a table that is about to be dropped, and foreign key checks are deferred
+-- until commit so branches can be copied before their parents.
+
+PRAGMA defer_foreign_keys = ON;
+
+ALTER TABLE components RENAME TO components_old;
+ALTER TABLE cycles RENAME TO cycles_old;
+
+CREATE TABLE cycles (
+    id TEXT PRIMARY KEY,
+    session_id TEXT NOT NULL REFERENCES sessions(id) ON DELETE CASCADE,
+    parent_cycle_id TEXT REFERENCES cycles(id) ON DELETE CASCADE,
+    branch_point TEXT CHECK (
+        branch_point IS NULL OR
+        branch_point IN (
+            'issue_raising', 'problem_frame', 'stakeholder_analysis', 'objectives',
+            'alternatives', 'consequences', 'tradeoffs', 'recommendation',
+            'decision_quality', 'notes_next_steps'
+        )
+    ),
+    status TEXT NOT NULL CHECK (status IN ('active', 'completed', 'archived')),
+    current_step TEXT NOT NULL CHECK (
+        current_step IN (
+            'issue_raising', 'problem_frame', 'stakeholder_analysis', 'objectives',
+            'alternatives', 'consequences', 'tradeoffs', 'recommendation',
+            'decision_quality', 'notes_next_steps'
+        )
+    ),
+    created_at TEXT NOT NULL,
+    updated_at TEXT NOT NULL,
+    version INTEGER NOT NULL DEFAULT 0
+);
+
+INSERT INTO cycles SELECT * FROM cycles_old;
+
+CREATE TABLE components (
+    id TEXT NOT NULL,
+    cycle_id TEXT NOT NULL REFERENCES cycles(id) ON DELETE CASCADE,
+    component_type TEXT NOT NULL CHECK (
+        component_type IN (
+            'issue_raising', 'problem_frame', 'stakeholder_analysis', 'objectives',
+            'alternatives', 'consequences', 'tradeoffs', 'recommendation',
+            'decision_quality', 'notes_next_steps'
+        )
+    ),
+    status TEXT NOT NULL CHECK (
+        status IN ('not_started', 'in_progress', 'complete', 'needs_revision')
+    ),
+    output TEXT NOT NULL DEFAULT '{}',
+    created_at TEXT NOT NULL,
+    updated_at TEXT NOT NULL,
+
+    PRIMARY KEY (cycle_id, component_type)
+);
+
+INSERT INTO components SELECT * FROM components_old;
+
+DROP TABLE components_old;
+DROP TABLE cycles_old;
+
+CREATE INDEX idx_cycles_session_id ON cycles(session_id);
+CREATE INDEX idx_cycles_parent_id ON cycles(parent_cycle_id);
+
+-- Cycles created before this migration get the component, not started, so
+-- they can still reach Objectives. Component ids are random v4 UUIDs; WHERE
+-- true keeps the upsert clause from parsing as a join constraint.
+INSERT INTO components (
+    id, cycle_id, component_type, status, output, created_at, updated_at
+)
+SELECT
+    lower(
+        hex(randomblob(4)) || '-' || hex(randomblob(2)) || '-4' ||
+        substr(hex(randomblob(2)), 2) || '-' ||
+        substr('89ab', 1 + (abs(random()) % 4), 1) ||
+        substr(hex(randomblob(2)), 2) || '-' || hex(randomblob(6))
+    ),
+    id, 'stakeholder_analysis', 'not_started', '{}',
+    strftime('%Y-%m-%dT%H:%M:%fZ', 'now'), strftime('%Y-%m-%dT%H:%M:%fZ', 'now')
+FROM cycles
+WHERE true
+ON CONFLICT (cycle_id, component_type) DO NOTHING;
@@ -16,6 +16,13 @@
 //!
 //! Uses Server-Sent Events (SSE) with Anthropic's event format. Events include
 //! `message_start`, `content_block_delta`, and `message_delta` for streaming.
+//! A `tool_use` content block opens with `content_block_start` and streams its
+//! input as `input_json_delta` fragments, which become `ToolCallDelta`s.
+//!
+//! # Batches
+//!
+//! `submit_batch` uses the Message Batches API. Results are fetched as JSONL
+//! once the batch has ended and are priced at half the interactive rate.
 
 use async_trait::async_trait;
 use futures::stream::{self, Stream, StreamExt};
@@ -27,8 +34,9 @@ use std::time::Duration;
 use tokio::time::sleep;
 
 use crate::ports::{
-    AIError, AIProvider, CompletionRequest, CompletionResponse, FinishReason, ProviderInfo,
-    StreamChunk, TokenUsage,
+    validate_batch, AIError, AIProvider, BatchJob, BatchRequest, BatchResult, BatchStatus,
+    CompletionRequest, CompletionResponse, FinishReason, ProviderInfo, StreamChunk, TokenUsage,
+    ToolCallDelta,
 };
 
 /// Configuration for the Anthropic provider.
@@ -113,6 +121,11 @@ impl AnthropicProvider {
         format!("{}/v1/messages", self.config.base_url)
     }
 
+    /// Builds the message batches endpoint URL.
+    fn batches_url(&self) -> String {
+        format!("{}/v1/messages/batches", self.config.base_url)
+    }
+
     /// Converts our request to Anthropic's format.
     fn to_anthropic_request(&self, request: &CompletionRequest, stream: bool) -> AnthropicRequest {
         let mut messages = Vec::new();
@@ -146,6 +159,11 @@ impl AnthropicProvider {
             max_tokens: request.max_tokens.unwrap_or(4096),
             temperature: request.temperature,
             stream: Some(stream),
+            tools: request
+                .tools
+                .iter()
+                .map(|tool| tool.to_anthropic_format())
+                .collect(),
         }
     }
 
@@ -269,6 +287,11 @@ impl AnthropicProvider {
             .await
             .map_err(|e| AIError::parse(format!("Failed to parse response: {}", e)))?;
 
+        Ok(self.to_completion(anthropic_response))
+    }
+
+    /// Converts a Messages API response to our format.
+    fn to_completion(&self, anthropic_response: AnthropicResponse) -> CompletionResponse {
         let content = anthropic_response
             .content
             .into_iter()
@@ -285,6 +308,7 @@ impl AnthropicProvider {
         let finish_reason = match anthropic_response.stop_reason.as_deref() {
             Some("end_turn") | Some("stop_sequence") => FinishReason::Stop,
             Some("max_tokens") => FinishReason::Length,
+            Some("tool_use") => FinishReason::ToolUse,
             _ => FinishReason::Stop,
         };
 
@@ -297,12 +321,52 @@ impl AnthropicProvider {
             ),
         );
 
-        Ok(CompletionResponse {
+        CompletionResponse {
             content,
             usage,
             model: anthropic_response.model,
             finish_reason,
-        })
+        }
+    }
+
+    /// Sends a message batches API call.
+    async fn send_batch_call(&self, request: reqwest::RequestBuilder) -> Result<Response, AIError> {
+        let response = request
+            .header("x-api-key", self.config.api_key())
+            .header("anthropic-version", ANTHROPIC_API_VERSION)
+            .send()
+            .await
+            .map_err(|e| AIError::network(e.to_string()))?;
+        self.handle_response_status(response).await
+    }
+
+    /// Parses a JSONL results file.
+    fn parse_batch_results(&self, body: &str) -> Result<Vec<BatchResult>, AIError> {
+        body.lines()
+            .filter(|line| !line.trim().is_empty())
+            .map(|line| {
+                let entry: BatchResultLine = serde_json::from_str(line)
+                    .map_err(|e| AIError::parse(format!("Invalid batch result: {}", e)))?;
+                let result = match entry.result {
+                    BatchOutcome::Succeeded { message } => {
+                        let mut completion = self.to_completion(message);
+                        completion.usage = completion.usage.at_batch_price();
+                        Ok(completion)
+                    }
+                    BatchOutcome::Errored { error } => {
+                        Err(AIError::InvalidRequest(error.to_string()))
+                    }
+                    BatchOutcome::Canceled => {
+                        Err(AIError::unavailable("Batch request was cancelled"))
+                    }
+                    BatchOutcome::Expired => Err(AIError::unavailable("Batch request expired")),
+                };
+                Ok(BatchResult {
+                    custom_id: entry.custom_id,
+                    result,
+                })
+            })
+            .collect()
     }
 
     /// Calculates estimated cost in cents based on model and token counts.
@@ -325,6 +389,17 @@ impl AnthropicProvider {
 
 #[async_trait]
 impl AIProvider for AnthropicProvider {
+    #[tracing::instrument(
+        name = "ai.complete",
+        skip_all,
+        fields(
+            ai.provider = "anthropic",
+            ai.model = %self.config.model,
+            ai.prompt_tokens = tracing::field::Empty,
+            ai.completion_tokens = tracing::field::Empty,
+        ),
+        err
+    )]
     async fn complete(&self, request: CompletionRequest) -> Result<CompletionResponse, AIError> {
         let mut last_error = AIError::network("No attempts made");
         let mut retry_count = 0;
@@ -333,7 +408,12 @@ impl AIProvider for AnthropicProvider {
             match self.send_request(&request).await {
                 Ok(response) => {
                     match self.parse_response(response).await {
-                        Ok(completion) => return Ok(completion),
+                        Ok(completion) => {
+                            let span = tracing::Span::current();
+                            span.record("ai.prompt_tokens", completion.usage.prompt_tokens);
+                            span.record("ai.completion_tokens", completion.usage.completion_tokens);
+                            return Ok(completion);
+                        }
                         Err(err) => {
                             if !err.is_retryable() || retry_count >= self.config.max_retries {
                                 return Err(err);
@@ -359,6 +439,12 @@ impl AIProvider for AnthropicProvider {
         Err(last_error)
     }
 
+    #[tracing::instrument(
+        name = "ai.stream_complete",
+        skip_all,
+        fields(ai.provider = "anthropic", ai.model = %self.config.model),
+        err
+    )]
     async fn stream_complete(
         &self,
         request: CompletionRequest,
@@ -418,9 +504,66 @@ impl AIProvider for AnthropicProvider {
         ProviderInfo::new("anthropic", &self.config.model, max_context)
             .with_streaming(true)
             .with_functions(true)
+            .with_batch(true)
+    }
+
+    #[tracing::instrument(
+        name = "ai.submit_batch",
+        skip_all,
+        fields(ai.provider = "anthropic", ai.model = %self.config.model, ai.batch_size = requests.len()),
+        err
+    )]
+    async fn submit_batch(&self, requests: Vec<BatchRequest>) -> Result<BatchJob, AIError> {
+        validate_batch(&requests)?;
+
+        let body = AnthropicBatchRequest {
+            requests: requests
+                .iter()
+                .map(|item| {
+                    let mut params = self.to_anthropic_request(&item.request, false);
+                    // Batched requests cannot stream
+                    params.stream = None;
+                    AnthropicBatchItem {
+                        custom_id: item.custom_id.clone(),
+                        params,
+                    }
+                })
+                .collect(),
+        };
+
+        let response = self
+            .send_batch_call(self.client.post(self.batches_url()).json(&body))
+            .await?;
+        parse_batch(response).await
+    }
+
+    async fn batch_status(&self, batch_id: &str) -> Result<BatchJob, AIError> {
+        let url = format!("{}/{}", self.batches_url(), batch_id);
+        let response = self.send_batch_call(self.client.get(url)).await?;
+        parse_batch(response).await
+    }
+
+    async fn batch_results(&self, batch_id: &str) -> Result<Vec<BatchResult>, AIError> {
+        let url = format!("{}/{}/results", self.batches_url(), batch_id);
+        let body = self
+            .send_batch_call(self.client.get(url))
+            .await?
+            .text()
+            .await
+            .map_err(|e| AIError::network(e.to_string()))?;
+        self.parse_batch_results(&body)
     }
 }
 
+/// Parses a message batch object.
+async fn parse_batch(response: Response) -> Result<BatchJob, AIError> {
+    let batch: AnthropicBatch = response
+        .json()
+        .await
+        .map_err(|e| AIError::parse(format!("Failed to parse batch: {}", e)))?;
+    Ok(batch.into_job())
+}
+
 /// Parses Anthropic SSE format into StreamChunks.
 ///
 /// Anthropic SSE format uses `event:` and `data:` lines:
@@ -441,9 +584,27 @@ fn parse_anthropic_sse(
             current_event = event_type.to_string();
         } else if let Some(data) = line.strip_prefix("data: ") {
             match current_event.as_str() {
+                "content_block_start" => {
+                    if let Ok(start) = serde_json::from_str::<ContentBlockStart>(data) {
+                        if start.content_block.block_type == "tool_use" {
+                            results.push(Ok(StreamChunk::tool_call(ToolCallDelta::start(
+                                start.index,
+                                start.content_block.id.unwrap_or_default(),
+                                start.content_block.name.unwrap_or_default(),
+                            ))));
+                        }
+                    }
+                }
                 "content_block_delta" => {
                     if let Ok(delta) = serde_json::from_str::<ContentBlockDelta>(data) {
-                        if let Some(text) = delta.delta.text {
+                        if let Some(json) = delta.delta.partial_json {
+                            if !json.is_empty() {
+                                results.push(Ok(StreamChunk::tool_call(ToolCallDelta::arguments(
+                                    delta.index,
+                                    json,
+                                ))));
+                            }
+                        } else if let Some(text) = delta.delta.text {
                             if !text.is_empty() {
                                 results.push(Ok(StreamChunk::content(&text)));
                             }
@@ -455,6 +616,7 @@ fn parse_anthropic_sse(
                         let finish_reason = match delta.delta.stop_reason.as_deref() {
                             Some("end_turn") | Some("stop_sequence") => FinishReason::Stop,
                             Some("max_tokens") => FinishReason::Length,
+                            Some("tool_use") => FinishReason::ToolUse,
                             _ => FinishReason::Stop,
                         };
 
@@ -507,6 +669,8 @@ struct AnthropicRequest {
     temperature: Option<f32>,
     #[serde(skip_serializing_if = "Option::is_none")]
     stream: Option<bool>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    tools: Vec<serde_json::Value>,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -515,6 +679,70 @@ struct AnthropicMessage {
     content: String,
 }
 
+#[derive(Debug, Serialize)]
+struct AnthropicBatchRequest {
+    requests: Vec<AnthropicBatchItem>,
+}
+
+#[derive(Debug, Serialize)]
+struct AnthropicBatchItem {
+    custom_id: String,
+    params: AnthropicRequest,
+}
+
+#[derive(Debug, Deserialize)]
+struct AnthropicBatch {
+    id: String,
+    processing_status: String,
+    request_counts: BatchRequestCounts,
+}
+
+impl AnthropicBatch {
+    fn into_job(self) -> BatchJob {
+        let counts = self.request_counts;
+        BatchJob {
+            id: self.id,
+            // "canceling" batches still end, with their remaining requests canceled
+            status: match self.processing_status.as_str() {
+                "ended" => BatchStatus::Ended,
+                _ => BatchStatus::InProgress,
+            },
+            request_count: counts.processing
+                + counts.succeeded
+                + counts.errored
+                + counts.canceled
+                + counts.expired,
+            succeeded_count: counts.succeeded,
+            failed_count: counts.errored + counts.canceled + counts.expired,
+        }
+    }
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+struct BatchRequestCounts {
+    processing: u32,
+    succeeded: u32,
+    errored: u32,
+    canceled: u32,
+    expired: u32,
+}
+
+#[derive(Debug, Deserialize)]
+struct BatchResultLine {
+    custom_id: String,
+    result: BatchOutcome,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+enum BatchOutcome {
+    Succeeded { message: AnthropicResponse },
+    Errored { error: serde_json::Value },
+    Canceled,
+    Expired,
+}
+
 #[derive(Debug, Deserialize)]
 struct AnthropicResponse {
     model: String,
@@ -537,8 +765,25 @@ struct AnthropicUsage {
 }
 
 // Streaming response types
+#[derive(Debug, Deserialize)]
+struct ContentBlockStart {
+    #[serde(default)]
+    index: u32,
+    content_block: StartedBlock,
+}
+
+#[derive(Debug, Deserialize)]
+struct StartedBlock {
+    #[serde(rename = "type")]
+    block_type: String,
+    id: Option<String>,
+    name: Option<String>,
+}
+
 #[derive(Debug, Deserialize)]
 struct ContentBlockDelta {
+    #[serde(default)]
+    index: u32,
     delta: TextDelta,
 }
 
@@ -548,6 +793,8 @@ struct TextDelta {
     #[allow(dead_code)]
     delta_type: Option<String>,
     text: Option<String>,
+    /// Fragment of a tool_use block's input (`input_json_delta`).
+    partial_json: Option<String>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -687,6 +934,108 @@ mod tests {
         assert_eq!(chunks[1].as_ref().unwrap().delta, " there");
     }
 
+    #[test]
+    fn parse_sse_tool_use_block() {
+        let data = "event: content_block_start\ndata: {\"type\":\"content_block_start\",\"index\":1,\"content_block\":{\"type\":\"tool_use\",\"id\":\"toolu_01\",\"name\":\"add_objective\",\"input\":{}}}\n\nevent: content_block_delta\ndata: {\"type\":\"content_block_delta\",\"index\":1,\"delta\":{\"type\":\"input_json_delta\",\"partial_json\":\"{\\\"name\\\": \\\"Cost\\\"}\"}}\n\nevent: message_delta\ndata: {\"delta\":{\"stop_reason\":\"tool_use\"},\"usage\":{\"output_tokens\":20}}";
+        let chunks = parse_anthropic_sse(data, 300, 1500);
+
+        assert_eq!(chunks.len(), 3);
+        assert_eq!(
+            chunks[0].as_ref().unwrap().tool_call,
+            Some(ToolCallDelta::start(1, "toolu_01", "add_objective"))
+        );
+        assert_eq!(
+            chunks[1].as_ref().unwrap().tool_call,
+            Some(ToolCallDelta::arguments(1, "{\"name\": \"Cost\"}"))
+        );
+        assert_eq!(chunks[2].as_ref().unwrap().finish_reason, Some(FinishReason::ToolUse));
+    }
+
+    #[test]
+    fn request_includes_tools_in_anthropic_format() {
+        use crate::domain::conversation::tools::ToolDefinition;
+        use crate::domain::foundation::{ConversationId, SessionId, UserId};
+        use crate::ports::{MessageRole, RequestMetadata};
+
+        let provider = AnthropicProvider::new(AnthropicConfig::new("test"));
+        let metadata = RequestMetadata::new(
+            UserId::new("test-user").unwrap(),
+            SessionId::new(),
+            ConversationId::new(),
+            "trace-123",
+        );
+        let request = CompletionRequest::new(metadata)
+            .with_message(MessageRole::User, "Hi")
+            .with_tools(vec![ToolDefinition::simple(
+                "list_objectives",
+                "List objectives",
+            )]);
+
+        let json = serde_json::to_value(provider.to_anthropic_request(&request, true)).unwrap();
+        assert_eq!(json["tools"][0]["name"], "list_objectives");
+        assert!(json["tools"][0]["input_schema"].is_object());
+
+        let without = CompletionRequest::new(request.metadata.clone());
+        let json = serde_json::to_value(provider.to_anthropic_request(&without, true)).unwrap();
+        assert!(json.get("tools").is_none());
+    }
+
+    #[test]
+    fn batch_object_maps_to_job() {
+        let batch: AnthropicBatch = serde_json::from_str(
+            r#"{"id":"msgbatch_01","type":"message_batch","processing_status":"ended",
+                "request_counts":{"processing":0,"succeeded":3,"errored":1,"canceled":0,"expired":1}}"#,
+        )
+        .unwrap();
+
+        let job = batch.into_job();
+        assert_eq!(job.id, "msgbatch_01");
+        assert_eq!(job.status, BatchStatus::Ended);
+        assert_eq!(job.request_count, 5);
+        assert_eq!(job.succeeded_count, 3);
+        assert_eq!(job.failed_count, 2);
+
+        let batch: AnthropicBatch = serde_json::from_str(
+            r#"{"id":"msgbatch_02","processing_status":"canceling","request_counts":{"processing":2}}"#,
+        )
+        .unwrap();
+        assert_eq!(batch.into_job().status, BatchStatus::InProgress);
+    }
+
+    #[test]
+    fn batch_results_are_priced_at_half_rate() {
+        let provider = AnthropicProvider::new(
+            AnthropicConfig::new("test").with_model("claude-3-opus-20240229"),
+        );
+        let body = concat!(
+            r#"{"custom_id":"profile-1","result":{"type":"succeeded","message":{"model":"claude-3-opus-20240229","content":[{"type":"text","text":"Summary"}],"stop_reason":"end_turn","usage":{"input_tokens":1000000,"output_tokens":0}}}}"#,
+            "\n",
+            r#"{"custom_id":"profile-2","result":{"type":"errored","error":{"type":"error","error":{"type":"invalid_request_error","message":"bad"}}}}"#,
+            "\n",
+            r#"{"custom_id":"profile-3","result":{"type":"expired"}}"#,
+            "\n"
+        );
+
+        let results = provider.parse_batch_results(body).unwrap();
+        assert_eq!(results.len(), 3);
+
+        let completion = results[0].result.as_ref().unwrap();
+        assert_eq!(results[0].custom_id, "profile-1");
+        assert_eq!(completion.content, "Summary");
+        // $15 per 1M input tokens at full price
+        assert_eq!(completion.usage.estimated_cost_cents, 750);
+
+        assert!(matches!(results[1].result, Err(AIError::InvalidRequest(_))));
+        assert!(matches!(results[2].result, Err(AIError::Unavailable { .. })));
+    }
+
+    #[test]
+    fn provider_supports_batches() {
+        let provider = AnthropicProvider::new(AnthropicConfig::new("test"));
+
+        assert!(provider.provider_info().supports_batch);
+    }
+
     #[test]
     fn parse_retry_after_default() {
         let error = r#"{"error":{"message":"Rate limit exceeded"}}"#;
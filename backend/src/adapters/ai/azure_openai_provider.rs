@@ -0,0 +1,355 @@
+//! Azure OpenAI Provider - Implementation of AIProvider for Azure-hosted OpenAI.
+//!
+//! Azure serves the OpenAI chat completions protocol, so request and response
+//! mapping is shared with [`OpenAIProvider`](super::OpenAIProvider). What
+//! differs is addressing and auth: requests go to a named *deployment* on the
+//! resource endpoint, carry an `api-version` query parameter, and authenticate
+//! with an `api-key` header.
+//!
+//! # Configuration
+//!
+//! ```ignore
+//! let config = AzureOpenAIConfig::new(
+//!     "https://my-resource.openai.azure.com",
+//!     api_key,
+//!     "gpt-4o-prod",
+//! )
+//! .with_model("gpt-4o")
+//! .with_component_deployment(ComponentType::Consequences, "gpt-4o-large-quota");
+//!
+//! let provider = AzureOpenAIProvider::new(config);
+//! ```
+//!
+//! Deployment names are chosen by whoever provisions the resource, so `model`
+//! names the underlying model for pricing and context limits.
+
+use async_trait::async_trait;
+use futures::stream::Stream;
+use reqwest::{Client, Response};
+use secrecy::{ExposeSecret, Secret};
+use std::collections::HashMap;
+use std::pin::Pin;
+use std::time::Duration;
+use tokio::time::sleep;
+
+use super::openai_provider::{
+    handle_response_status, parse_response, sse_stream, to_openai_request,
+};
+use crate::domain::foundation::ComponentType;
+use crate::ports::{
+    AIError, AIProvider, CompletionRequest, CompletionResponse, ProviderInfo, StreamChunk,
+};
+
+/// Default Azure OpenAI data-plane API version.
+pub const DEFAULT_AZURE_API_VERSION: &str = "2024-10-21";
+
+/// Configuration for the Azure OpenAI provider.
+#[derive(Debug, Clone)]
+pub struct AzureOpenAIConfig {
+    /// Resource endpoint (e.g., "https://my-resource.openai.azure.com").
+    pub endpoint: String,
+    /// API key for authentication.
+    api_key: Secret<String>,
+    /// Deployment used when no component-specific one is configured.
+    pub deployment: String,
+    /// Deployments to use instead of `deployment` for specific components.
+    pub component_deployments: HashMap<ComponentType, String>,
+    /// Data-plane API version (e.g., "2024-10-21").
+    pub api_version: String,
+    /// Model behind the deployments (e.g., "gpt-4o"), used for pricing.
+    pub model: String,
+    /// Request timeout.
+    pub timeout: Duration,
+    /// Maximum retries on transient failures.
+    pub max_retries: u32,
+}
+
+impl AzureOpenAIConfig {
+    /// Creates a new configuration for one deployment on a resource.
+    pub fn new(
+        endpoint: impl Into<String>,
+        api_key: impl Into<String>,
+        deployment: impl Into<String>,
+    ) -> Self {
+        Self {
+            endpoint: endpoint.into(),
+            api_key: Secret::new(api_key.into()),
+            deployment: deployment.into(),
+            component_deployments: HashMap::new(),
+            api_version: DEFAULT_AZURE_API_VERSION.to_string(),
+            model: "gpt-4o".to_string(),
+            timeout: Duration::from_secs(60),
+            max_retries: 3,
+        }
+    }
+
+    /// Sets the model behind the deployments.
+    pub fn with_model(mut self, model: impl Into<String>) -> Self {
+        self.model = model.into();
+        self
+    }
+
+    /// Routes one component to a different deployment.
+    pub fn with_component_deployment(
+        mut self,
+        component_type: ComponentType,
+        deployment: impl Into<String>,
+    ) -> Self {
+        self.component_deployments
+            .insert(component_type, deployment.into());
+        self
+    }
+
+    /// Sets the API version.
+    pub fn with_api_version(mut self, api_version: impl Into<String>) -> Self {
+        self.api_version = api_version.into();
+        self
+    }
+
+    /// Sets the request timeout.
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// Sets the maximum retry count.
+    pub fn with_max_retries(mut self, max_retries: u32) -> Self {
+        self.max_retries = max_retries;
+        self
+    }
+
+    /// Deployment to use for a request.
+    pub fn deployment_for(&self, component_type: Option<ComponentType>) -> &str {
+        component_type
+            .and_then(|ct| self.component_deployments.get(&ct))
+            .unwrap_or(&self.deployment)
+    }
+
+    /// Exposes the API key (for making requests).
+    fn api_key(&self) -> &str {
+        self.api_key.expose_secret()
+    }
+}
+
+/// Azure OpenAI provider implementation.
+pub struct AzureOpenAIProvider {
+    config: AzureOpenAIConfig,
+    client: Client,
+}
+
+impl AzureOpenAIProvider {
+    /// Creates a new Azure OpenAI provider with the given configuration.
+    pub fn new(config: AzureOpenAIConfig) -> Self {
+        let client = Client::builder()
+            .timeout(config.timeout)
+            .build()
+            .expect("Failed to create HTTP client");
+
+        Self { config, client }
+    }
+
+    /// Builds the chat completions URL for a deployment.
+    fn completions_url(&self, deployment: &str) -> String {
+        format!(
+            "{}/openai/deployments/{}/chat/completions?api-version={}",
+            self.config.endpoint.trim_end_matches('/'),
+            deployment,
+            self.config.api_version
+        )
+    }
+
+    /// Sends a request to the deployment routed for it.
+    async fn send_request(
+        &self,
+        request: &CompletionRequest,
+        stream: bool,
+    ) -> Result<Response, AIError> {
+        let deployment = self.config.deployment_for(request.component_type);
+        // Azure ignores `model` in the body; the deployment selects the model
+        let openai_request = to_openai_request(&self.config.model, request, stream);
+
+        self.client
+            .post(self.completions_url(deployment))
+            .header("api-key", self.config.api_key())
+            .header("Content-Type", "application/json")
+            .json(&openai_request)
+            .send()
+            .await
+            .map_err(|e| {
+                if e.is_timeout() {
+                    AIError::Timeout {
+                        timeout_secs: self.config.timeout.as_secs() as u32,
+                    }
+                } else if e.is_connect() {
+                    AIError::network(format!("Connection failed: {}", e))
+                } else {
+                    AIError::network(e.to_string())
+                }
+            })
+    }
+}
+
+#[async_trait]
+impl AIProvider for AzureOpenAIProvider {
+    #[tracing::instrument(
+        name = "ai.complete",
+        skip_all,
+        fields(
+            ai.provider = "azure_openai",
+            ai.model = %self.config.model,
+            ai.deployment = %self.config.deployment_for(request.component_type),
+            ai.prompt_tokens = tracing::field::Empty,
+            ai.completion_tokens = tracing::field::Empty,
+        ),
+        err
+    )]
+    async fn complete(&self, request: CompletionRequest) -> Result<CompletionResponse, AIError> {
+        let mut last_error = AIError::network("No attempts made");
+        let mut retry_count = 0;
+
+        while retry_count <= self.config.max_retries {
+            let result = match self.send_request(&request, false).await {
+                Ok(response) => parse_response(response, &self.config.model).await,
+                Err(err) => Err(err),
+            };
+
+            match result {
+                Ok(completion) => {
+                    let span = tracing::Span::current();
+                    span.record("ai.prompt_tokens", completion.usage.prompt_tokens);
+                    span.record("ai.completion_tokens", completion.usage.completion_tokens);
+                    return Ok(completion);
+                }
+                Err(err) => {
+                    if !err.is_retryable() || retry_count >= self.config.max_retries {
+                        return Err(err);
+                    }
+                    last_error = err;
+                }
+            }
+
+            // Exponential backoff: 1s, 2s, 4s, ...
+            let delay = Duration::from_secs(1 << retry_count);
+            sleep(delay).await;
+            retry_count += 1;
+        }
+
+        Err(last_error)
+    }
+
+    #[tracing::instrument(
+        name = "ai.stream_complete",
+        skip_all,
+        fields(
+            ai.provider = "azure_openai",
+            ai.model = %self.config.model,
+            ai.deployment = %self.config.deployment_for(request.component_type),
+        ),
+        err
+    )]
+    async fn stream_complete(
+        &self,
+        request: CompletionRequest,
+    ) -> Result<Pin<Box<dyn Stream<Item = Result<StreamChunk, AIError>> + Send>>, AIError> {
+        let response = self.send_request(&request, true).await?;
+        let response = handle_response_status(response).await?;
+
+        Ok(sse_stream(response, &self.config.model))
+    }
+
+    fn estimate_tokens(&self, text: &str) -> u32 {
+        // GPT models use ~4 characters per token on average
+        (text.len() / 4).max(1) as u32
+    }
+
+    fn provider_info(&self) -> ProviderInfo {
+        let max_context = match self.config.model.as_str() {
+            m if m.starts_with("gpt-4o") || m.starts_with("gpt-4-turbo") => 128000,
+            m if m.starts_with("gpt-4-32k") => 32768,
+            m if m.starts_with("gpt-4") => 8192,
+            m if m.starts_with("gpt-35-turbo-16k") => 16384,
+            m if m.starts_with("gpt-35") || m.starts_with("gpt-3.5") => 4096,
+            _ => 128000,
+        };
+
+        ProviderInfo::new("azure_openai", &self.config.model, max_context)
+            .with_streaming(true)
+            .with_functions(true)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config() -> AzureOpenAIConfig {
+        AzureOpenAIConfig::new(
+            "https://my-resource.openai.azure.com/",
+            "test-key",
+            "gpt4o-prod",
+        )
+    }
+
+    #[test]
+    fn config_builder_works() {
+        let config = config()
+            .with_model("gpt-4o-mini")
+            .with_api_version("2025-01-01-preview")
+            .with_timeout(Duration::from_secs(30))
+            .with_max_retries(5);
+
+        assert_eq!(config.deployment, "gpt4o-prod");
+        assert_eq!(config.model, "gpt-4o-mini");
+        assert_eq!(config.api_version, "2025-01-01-preview");
+        assert_eq!(config.timeout, Duration::from_secs(30));
+        assert_eq!(config.max_retries, 5);
+        assert_eq!(config.api_key(), "test-key");
+    }
+
+    #[test]
+    fn completions_url_targets_deployment() {
+        let provider = AzureOpenAIProvider::new(config());
+
+        assert_eq!(
+            provider.completions_url("gpt4o-prod"),
+            "https://my-resource.openai.azure.com/openai/deployments/gpt4o-prod/chat/completions?api-version=2024-10-21"
+        );
+    }
+
+    #[test]
+    fn components_route_to_their_deployment() {
+        let config =
+            config().with_component_deployment(ComponentType::Consequences, "gpt4o-high-quota");
+
+        assert_eq!(config.deployment_for(None), "gpt4o-prod");
+        assert_eq!(
+            config.deployment_for(Some(ComponentType::Objectives)),
+            "gpt4o-prod"
+        );
+        assert_eq!(
+            config.deployment_for(Some(ComponentType::Consequences)),
+            "gpt4o-high-quota"
+        );
+    }
+
+    #[test]
+    fn azure_model_names_price_like_openai() {
+        use super::super::openai_provider::calculate_cost;
+
+        // 1M prompt tokens = $2.50 = 250 cents
+        // 1M completion tokens = $10 = 1000 cents
+        assert_eq!(calculate_cost("gpt-4o", 1_000_000, 1_000_000), 1250);
+    }
+
+    #[test]
+    fn provider_info_reports_azure() {
+        let provider = AzureOpenAIProvider::new(config());
+
+        let info = provider.provider_info();
+        assert_eq!(info.name, "azure_openai");
+        assert_eq!(info.model, "gpt-4o");
+        assert_eq!(info.max_context_tokens, 128000);
+        assert!(info.supports_streaming);
+        assert!(info.supports_functions);
+    }
+}
@@ -0,0 +1,535 @@
+//! Cached AI Provider - Serves repeated completions from a shared cache.
+//!
+//! Wraps a provider and stores its completions in a `Cache` (Redis in
+//! production) keyed by a hash of everything that shapes the response: model,
+//! system prompt, messages, sampling settings and offered tools. Prompts are
+//! normalized before hashing, so requests that differ only in whitespace
+//! share an entry.
+//!
+//! # Example
+//!
+//! ```ignore
+//! let provider = CachedAIProvider::new(primary, Arc::new(RedisCache::new(conn)))
+//!     .with_ttl(Duration::from_secs(3600))
+//!     .with_namespace("2025-06-prompts");
+//!
+//! // After changing the Objectives prompt:
+//! provider.invalidate_component(ComponentType::Objectives).await?;
+//! ```
+//!
+//! Requests with `metadata.bypass_cache` set (regeneration) always reach the
+//! model, and responses from the mock provider are never cached so scripted
+//! responses play back in order. Only complete text responses are stored;
+//! tool calls, truncated and filtered responses are not. Cache failures are
+//! logged and treated as misses.
+
+use async_trait::async_trait;
+use futures::{stream, Stream, StreamExt};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::pin::Pin;
+use std::sync::Arc;
+use std::time::Duration;
+
+use crate::domain::conversation::tools::ToolDefinition;
+use crate::domain::foundation::ComponentType;
+use crate::ports::{
+    AIError, AIProvider, BatchJob, BatchRequest, BatchResult, Cache, CacheEntryOptions, CacheError,
+    CacheExt, CompletionRequest, CompletionResponse, FinishReason, MessageRole, ProviderInfo,
+    StreamChunk, TokenUsage,
+};
+
+/// Default lifetime of a cached completion.
+pub const DEFAULT_AI_CACHE_TTL: Duration = Duration::from_secs(60 * 60);
+
+/// Tag carried by every cached completion.
+const ALL_COMPLETIONS_TAG: &str = "ai:completions";
+
+/// Provider name whose responses are never cached.
+const MOCK_PROVIDER: &str = "mock";
+
+/// AI provider wrapper that caches completions.
+pub struct CachedAIProvider<P: AIProvider> {
+    inner: P,
+    cache: Arc<dyn Cache>,
+    ttl: Duration,
+    namespace: String,
+}
+
+/// A completion as stored in the cache.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct CachedCompletion {
+    content: String,
+    model: String,
+    usage: TokenUsage,
+}
+
+impl CachedCompletion {
+    /// The cached completion as a response. Serving it costs nothing, so
+    /// the usage keeps its token counts but drops the cost.
+    fn into_response(self) -> CompletionResponse {
+        CompletionResponse {
+            content: self.content,
+            usage: TokenUsage::new(self.usage.prompt_tokens, self.usage.completion_tokens, 0),
+            model: self.model,
+            finish_reason: FinishReason::Stop,
+        }
+    }
+}
+
+/// Everything about a request that can change its response.
+#[derive(Serialize)]
+struct KeyMaterial<'a> {
+    namespace: &'a str,
+    model: &'a str,
+    system_prompt: Option<String>,
+    messages: Vec<(MessageRole, String)>,
+    max_tokens: Option<u32>,
+    temperature: Option<u32>,
+    component_type: Option<ComponentType>,
+    tools: &'a [ToolDefinition],
+}
+
+impl<P: AIProvider> CachedAIProvider<P> {
+    /// Wraps `inner`, caching its completions in `cache`.
+    pub fn new(inner: P, cache: Arc<dyn Cache>) -> Self {
+        Self {
+            inner,
+            cache,
+            ttl: DEFAULT_AI_CACHE_TTL,
+            namespace: "v1".to_string(),
+        }
+    }
+
+    /// Sets how long completions stay cached.
+    pub fn with_ttl(mut self, ttl: Duration) -> Self {
+        self.ttl = ttl;
+        self
+    }
+
+    /// Sets the key namespace. Changing it orphans every existing entry,
+    /// e.g. when a deploy changes prompts.
+    pub fn with_namespace(mut self, namespace: impl Into<String>) -> Self {
+        self.namespace = namespace.into();
+        self
+    }
+
+    /// Drops every cached completion, returning how many.
+    pub async fn invalidate_all(&self) -> Result<u64, CacheError> {
+        self.cache.invalidate_tag(ALL_COMPLETIONS_TAG).await
+    }
+
+    /// Drops cached completions for one component, returning how many.
+    pub async fn invalidate_component(
+        &self,
+        component_type: ComponentType,
+    ) -> Result<u64, CacheError> {
+        self.cache
+            .invalidate_tag(&component_tag(component_type))
+            .await
+    }
+
+    /// Drops cached completions from one model, returning how many.
+    pub async fn invalidate_model(&self, model: &str) -> Result<u64, CacheError> {
+        self.cache.invalidate_tag(&model_tag(model)).await
+    }
+
+    /// Cache key for a request, or `None` if it must not be cached.
+    fn key_for(&self, request: &CompletionRequest) -> Option<String> {
+        let info = self.inner.provider_info();
+        if request.metadata.bypass_cache || info.name == MOCK_PROVIDER {
+            return None;
+        }
+        Some(cache_key(&self.namespace, &info.model, request))
+    }
+
+    async fn lookup(&self, key: &str) -> Option<CachedCompletion> {
+        match self.cache.get_json(key).await {
+            Ok(hit) => hit,
+            Err(e) => {
+                tracing::warn!(error = %e, "AI response cache read failed");
+                None
+            }
+        }
+    }
+}
+
+/// Stores a completion under its key and invalidation tags.
+async fn store(
+    cache: &dyn Cache,
+    key: &str,
+    ttl: Duration,
+    component_type: Option<ComponentType>,
+    completion: &CachedCompletion,
+) {
+    let mut options = CacheEntryOptions::new(ttl)
+        .with_tag(ALL_COMPLETIONS_TAG)
+        .with_tag(model_tag(&completion.model));
+    if let Some(ct) = component_type {
+        options = options.with_tag(component_tag(ct));
+    }
+    if let Err(e) = cache.set_json(key, completion, &options).await {
+        tracing::warn!(error = %e, "AI response cache write failed");
+    }
+}
+
+/// Hashes the parts of a request that shape its response.
+pub(super) fn cache_key(namespace: &str, model: &str, request: &CompletionRequest) -> String {
+    let material = KeyMaterial {
+        namespace,
+        model,
+        system_prompt: request.system_prompt.as_deref().map(normalize),
+        messages: request
+            .messages
+            .iter()
+            .map(|m| (m.role, normalize(&m.content)))
+            .collect(),
+        max_tokens: request.max_tokens,
+        temperature: request.temperature.map(f32::to_bits),
+        component_type: request.component_type,
+        tools: &request.tools,
+    };
+    let bytes = serde_json::to_vec(&material).unwrap_or_default();
+    format!("ai:completion:{:x}", Sha256::digest(&bytes))
+}
+
+/// Trims the text and collapses runs of whitespace to a single space.
+fn normalize(text: &str) -> String {
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+fn component_tag(component_type: ComponentType) -> String {
+    let name = serde_json::to_value(component_type)
+        .ok()
+        .and_then(|v| v.as_str().map(str::to_string))
+        .unwrap_or_default();
+    format!("ai:component:{}", name)
+}
+
+fn model_tag(model: &str) -> String {
+    format!("ai:model:{}", model)
+}
+
+#[async_trait]
+impl<P: AIProvider> AIProvider for CachedAIProvider<P> {
+    async fn complete(&self, request: CompletionRequest) -> Result<CompletionResponse, AIError> {
+        let Some(key) = self.key_for(&request) else {
+            return self.inner.complete(request).await;
+        };
+        if let Some(hit) = self.lookup(&key).await {
+            tracing::debug!(key = %key, "AI response cache hit");
+            return Ok(hit.into_response());
+        }
+
+        let component_type = request.component_type;
+        let response = self.inner.complete(request).await?;
+        if response.finish_reason == FinishReason::Stop {
+            let completion = CachedCompletion {
+                content: response.content.clone(),
+                model: response.model.clone(),
+                usage: response.usage.clone(),
+            };
+            store(
+                self.cache.as_ref(),
+                &key,
+                self.ttl,
+                component_type,
+                &completion,
+            )
+            .await;
+        }
+        Ok(response)
+    }
+
+    async fn stream_complete(
+        &self,
+        request: CompletionRequest,
+    ) -> Result<Pin<Box<dyn Stream<Item = Result<StreamChunk, AIError>> + Send>>, AIError> {
+        let Some(key) = self.key_for(&request) else {
+            return self.inner.stream_complete(request).await;
+        };
+        if let Some(hit) = self.lookup(&key).await {
+            tracing::debug!(key = %key, "AI response cache hit");
+            let response = hit.into_response();
+            return Ok(Box::pin(stream::iter([
+                Ok(StreamChunk::content(response.content)),
+                Ok(StreamChunk::final_chunk(
+                    response.finish_reason,
+                    response.usage,
+                )),
+            ])));
+        }
+
+        let component_type = request.component_type;
+        let stream = self.inner.stream_complete(request).await?;
+        let model = self.inner.provider_info().model;
+        let cache = Arc::clone(&self.cache);
+        let ttl = self.ttl;
+        let mut content = String::new();
+        let mut cacheable = true;
+
+        // Pass chunks through untouched; store the response once it finishes
+        Ok(Box::pin(stream.map(move |item| {
+            match &item {
+                Ok(chunk) => {
+                    content.push_str(&chunk.delta);
+                    cacheable &= chunk.tool_call.is_none();
+                    if chunk.finish_reason == Some(FinishReason::Stop) && cacheable {
+                        let completion = CachedCompletion {
+                            content: std::mem::take(&mut content),
+                            model: model.clone(),
+                            usage: chunk.usage.clone().unwrap_or_default(),
+                        };
+                        let cache = Arc::clone(&cache);
+                        let key = key.clone();
+                        tokio::spawn(async move {
+                            store(cache.as_ref(), &key, ttl, component_type, &completion).await;
+                        });
+                    }
+                }
+                Err(_) => cacheable = false,
+            }
+            item
+        })))
+    }
+
+    fn estimate_tokens(&self, text: &str) -> u32 {
+        self.inner.estimate_tokens(text)
+    }
+
+    fn provider_info(&self) -> ProviderInfo {
+        self.inner.provider_info()
+    }
+
+    async fn submit_batch(&self, requests: Vec<BatchRequest>) -> Result<BatchJob, AIError> {
+        // Batches are already priced down and collected later, so they skip the cache
+        self.inner.submit_batch(requests).await
+    }
+
+    async fn batch_status(&self, batch_id: &str) -> Result<BatchJob, AIError> {
+        self.inner.batch_status(batch_id).await
+    }
+
+    async fn batch_results(&self, batch_id: &str) -> Result<Vec<BatchResult>, AIError> {
+        self.inner.batch_results(batch_id).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::adapters::ai::MockAIProvider;
+    use crate::adapters::cache::InMemoryCache;
+    use crate::domain::foundation::{ConversationId, SessionId, UserId};
+    use crate::ports::RequestMetadata;
+    use std::sync::atomic::{AtomicU32, Ordering};
+
+    /// Provider that numbers its responses, so a cache hit is visible.
+    struct CountingProvider {
+        calls: AtomicU32,
+        finish_reason: FinishReason,
+    }
+
+    impl CountingProvider {
+        fn new() -> Self {
+            Self {
+                calls: AtomicU32::new(0),
+                finish_reason: FinishReason::Stop,
+            }
+        }
+
+        fn calls(&self) -> u32 {
+            self.calls.load(Ordering::SeqCst)
+        }
+    }
+
+    #[async_trait]
+    impl AIProvider for CountingProvider {
+        async fn complete(
+            &self,
+            _request: CompletionRequest,
+        ) -> Result<CompletionResponse, AIError> {
+            let n = self.calls.fetch_add(1, Ordering::SeqCst) + 1;
+            Ok(CompletionResponse {
+                content: format!("response {}", n),
+                usage: TokenUsage::new(10, 5, 3),
+                model: "counting-model".to_string(),
+                finish_reason: self.finish_reason,
+            })
+        }
+
+        async fn stream_complete(
+            &self,
+            _request: CompletionRequest,
+        ) -> Result<Pin<Box<dyn Stream<Item = Result<StreamChunk, AIError>> + Send>>, AIError>
+        {
+            let n = self.calls.fetch_add(1, Ordering::SeqCst) + 1;
+            Ok(Box::pin(stream::iter([
+                Ok(StreamChunk::content("streamed ")),
+                Ok(StreamChunk::content(n.to_string())),
+                Ok(StreamChunk::final_chunk(
+                    self.finish_reason,
+                    TokenUsage::new(10, 5, 3),
+                )),
+            ])))
+        }
+
+        fn estimate_tokens(&self, text: &str) -> u32 {
+            text.len() as u32
+        }
+
+        fn provider_info(&self) -> ProviderInfo {
+            ProviderInfo::new("counting", "counting-model", 1000)
+        }
+    }
+
+    fn request(message: &str) -> CompletionRequest {
+        CompletionRequest::new(RequestMetadata::new(
+            UserId::new("test-user").unwrap(),
+            SessionId::new(),
+            ConversationId::new(),
+            "trace-123",
+        ))
+        .with_system_prompt("Be helpful")
+        .with_component_type(ComponentType::Objectives)
+        .with_message(MessageRole::User, message)
+    }
+
+    async fn collect(provider: &impl AIProvider, request: CompletionRequest) -> String {
+        let mut stream = provider.stream_complete(request).await.unwrap();
+        let mut content = String::new();
+        while let Some(chunk) = stream.next().await {
+            content.push_str(&chunk.unwrap().delta);
+        }
+        content
+    }
+
+    #[tokio::test]
+    async fn identical_requests_are_served_from_cache() {
+        let cache = Arc::new(InMemoryCache::new());
+        let provider = CachedAIProvider::new(CountingProvider::new(), cache);
+
+        let first = provider.complete(request("What matters?")).await.unwrap();
+        let second = provider.complete(request("What matters?")).await.unwrap();
+
+        assert_eq!(first.content, "response 1");
+        assert_eq!(second.content, "response 1");
+        assert_eq!(second.usage.completion_tokens, 5);
+        assert_eq!(second.usage.estimated_cost_cents, 0);
+        assert_eq!(provider.inner.calls(), 1);
+    }
+
+    #[tokio::test]
+    async fn whitespace_differences_share_an_entry() {
+        let cache = Arc::new(InMemoryCache::new());
+        let provider = CachedAIProvider::new(CountingProvider::new(), cache);
+
+        provider.complete(request("What  matters?")).await.unwrap();
+        let near = provider
+            .complete(request("  What matters?\n"))
+            .await
+            .unwrap();
+        let different = provider.complete(request("What else?")).await.unwrap();
+
+        assert_eq!(near.content, "response 1");
+        assert_eq!(different.content, "response 2");
+    }
+
+    #[tokio::test]
+    async fn bypass_always_reaches_the_model() {
+        let cache = Arc::new(InMemoryCache::new());
+        let provider = CachedAIProvider::new(CountingProvider::new(), cache);
+
+        provider.complete(request("What matters?")).await.unwrap();
+        let mut regenerate = request("What matters?");
+        regenerate.metadata = regenerate.metadata.with_cache_bypass();
+        let response = provider.complete(regenerate).await.unwrap();
+
+        assert_eq!(response.content, "response 2");
+    }
+
+    #[tokio::test]
+    async fn mock_responses_are_never_cached() {
+        let cache = Arc::new(InMemoryCache::new());
+        let mock = MockAIProvider::new()
+            .with_response("first")
+            .with_response("second");
+        let provider = CachedAIProvider::new(mock, cache);
+
+        provider.complete(request("Hi")).await.unwrap();
+        let second = provider.complete(request("Hi")).await.unwrap();
+
+        assert_eq!(second.content, "second");
+    }
+
+    #[tokio::test]
+    async fn incomplete_responses_are_not_cached() {
+        let cache = Arc::new(InMemoryCache::new());
+        let mut inner = CountingProvider::new();
+        inner.finish_reason = FinishReason::Length;
+        let provider = CachedAIProvider::new(inner, cache);
+
+        provider.complete(request("Hi")).await.unwrap();
+        provider.complete(request("Hi")).await.unwrap();
+
+        assert_eq!(provider.inner.calls(), 2);
+    }
+
+    #[tokio::test]
+    async fn streamed_responses_are_cached_and_replayed() {
+        let cache = Arc::new(InMemoryCache::new());
+        let provider = CachedAIProvider::new(CountingProvider::new(), cache.clone());
+
+        assert_eq!(collect(&provider, request("Hi")).await, "streamed 1");
+        // The write happens in the background once the stream finishes
+        let key = cache_key("v1", "counting-model", &request("Hi"));
+        for _ in 0..50 {
+            if cache.get(&key).await.unwrap().is_some() {
+                break;
+            }
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+
+        assert_eq!(collect(&provider, request("Hi")).await, "streamed 1");
+        assert_eq!(provider.inner.calls(), 1);
+    }
+
+    #[tokio::test]
+    async fn invalidation_drops_entries_by_component() {
+        let cache = Arc::new(InMemoryCache::new());
+        let provider = CachedAIProvider::new(CountingProvider::new(), cache);
+
+        provider.complete(request("Hi")).await.unwrap();
+        assert_eq!(
+            provider
+                .invalidate_component(ComponentType::Alternatives)
+                .await
+                .unwrap(),
+            0
+        );
+        assert_eq!(
+            provider
+                .invalidate_component(ComponentType::Objectives)
+                .await
+                .unwrap(),
+            1
+        );
+
+        let response = provider.complete(request("Hi")).await.unwrap();
+        assert_eq!(response.content, "response 2");
+    }
+
+    #[test]
+    fn keys_depend_on_namespace_model_and_settings() {
+        let base = cache_key("v1", "model-a", &request("Hi"));
+
+        assert_eq!(base, cache_key("v1", "model-a", &request("Hi")));
+        assert_ne!(base, cache_key("v2", "model-a", &request("Hi")));
+        assert_ne!(base, cache_key("v1", "model-b", &request("Hi")));
+        assert_ne!(
+            base,
+            cache_key("v1", "model-a", &request("Hi").with_temperature(0.2))
+        );
+        assert!(base.starts_with("ai:completion:"));
+    }
+}
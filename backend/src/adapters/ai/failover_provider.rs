@@ -10,23 +10,32 @@
 //! let fallback = AnthropicProvider::new(anthropic_config);
 //!
 //! let provider = FailoverAIProvider::new(primary)
-//!     .with_fallback(fallback);
+//!     .with_fallback(fallback)
+//!     .with_price_table(Arc::new(PriceTable::with_default_prices()));
 //! ```
+//!
+//! Every completion emits `ai.tokens_used` with model, latency, originating
+//! component, and cycle. With a price table configured, cost is priced from
+//! the table rather than the provider's built-in estimate.
 
 use async_trait::async_trait;
 use futures::Stream;
 use std::pin::Pin;
 use std::sync::Arc;
+use std::time::Instant;
 
 use crate::ports::{
-    AIError, AIProvider, CompletionRequest, CompletionResponse, ProviderInfo, StreamChunk,
+    AIError, AIProvider, BatchJob, BatchRequest, BatchResult, CompletionRequest,
+    CompletionResponse, PriceTable, ProviderInfo, StreamChunk, MICRO_CENTS_PER_CENT,
 };
 
 /// AI domain events for cost tracking and failover monitoring.
 pub mod events {
     use serde::{Deserialize, Serialize};
 
-    use crate::domain::foundation::{domain_event, ComponentType, EventId, SessionId, Timestamp, UserId};
+    use crate::domain::foundation::{
+        domain_event, ComponentType, CycleId, EventId, SessionId, Timestamp, UserId,
+    };
 
     /// Emitted when AI tokens are used for a completion.
     ///
@@ -46,6 +55,15 @@ pub mod events {
         pub estimated_cost_cents: u32,
         /// PrOACT component type for analytics (optional).
         pub component_type: Option<ComponentType>,
+        /// Cycle the request was made for (optional).
+        #[serde(default)]
+        pub cycle_id: Option<CycleId>,
+        /// Exact cost in micro-cents (millionths of a cent).
+        #[serde(default)]
+        pub cost_micro_cents: u64,
+        /// Wall-clock time of the provider call.
+        #[serde(default)]
+        pub latency_ms: Option<u64>,
         pub request_id: String,
         pub occurred_at: Timestamp,
     }
@@ -74,11 +92,33 @@ pub mod events {
                 completion_tokens,
                 estimated_cost_cents,
                 component_type,
+                cycle_id: None,
+                cost_micro_cents: estimated_cost_cents as u64 * super::MICRO_CENTS_PER_CENT,
+                latency_ms: None,
                 request_id: request_id.into(),
                 occurred_at: Timestamp::now(),
             }
         }
 
+        /// Attributes the usage to a cycle.
+        pub fn with_cycle_id(mut self, cycle_id: Option<CycleId>) -> Self {
+            self.cycle_id = cycle_id;
+            self
+        }
+
+        /// Sets the exact cost, keeping `estimated_cost_cents` consistent.
+        pub fn with_cost_micro_cents(mut self, cost_micro_cents: u64) -> Self {
+            self.cost_micro_cents = cost_micro_cents;
+            self.estimated_cost_cents = (cost_micro_cents / super::MICRO_CENTS_PER_CENT) as u32;
+            self
+        }
+
+        /// Records how long the provider call took.
+        pub fn with_latency_ms(mut self, latency_ms: u64) -> Self {
+            self.latency_ms = Some(latency_ms);
+            self
+        }
+
         /// Total tokens used in this request.
         pub fn total_tokens(&self) -> u32 {
             self.prompt_tokens + self.completion_tokens
@@ -163,6 +203,7 @@ pub struct FailoverAIProvider<P: AIProvider, F: AIProvider = NoFallback> {
     primary: P,
     fallback: Option<F>,
     event_callback: Arc<dyn AIEventCallback>,
+    price_table: Option<Arc<PriceTable>>,
 }
 
 /// Marker type for when no fallback is configured.
@@ -197,6 +238,7 @@ impl<P: AIProvider> FailoverAIProvider<P, NoFallback> {
             primary,
             fallback: None,
             event_callback: Arc::new(NoOpEventCallback),
+            price_table: None,
         }
     }
 
@@ -206,6 +248,7 @@ impl<P: AIProvider> FailoverAIProvider<P, NoFallback> {
             primary: self.primary,
             fallback: Some(fallback),
             event_callback: self.event_callback,
+            price_table: self.price_table,
         }
     }
 }
@@ -217,25 +260,43 @@ impl<P: AIProvider, F: AIProvider> FailoverAIProvider<P, F> {
         self
     }
 
+    /// Prices completions from `price_table` instead of provider estimates.
+    pub fn with_price_table(mut self, price_table: Arc<PriceTable>) -> Self {
+        self.price_table = Some(price_table);
+        self
+    }
+
     /// Emits a tokens used event with full user context.
     fn emit_tokens_used(
         &self,
+        provider_name: &str,
         request: &CompletionRequest,
         response: &CompletionResponse,
         request_id: &str,
+        started_at: Instant,
     ) {
-        let info = self.primary.provider_info();
+        let usage = &response.usage;
+        let cost_micro_cents = match &self.price_table {
+            Some(table) => {
+                table.cost_micro_cents(&response.model, usage.prompt_tokens, usage.completion_tokens)
+            }
+            None => usage.estimated_cost_cents as u64 * MICRO_CENTS_PER_CENT,
+        };
+
         let event = events::AITokensUsed::new(
             request.metadata.user_id.clone(),
             request.metadata.session_id,
-            info.name,
+            provider_name,
             &response.model,
-            response.usage.prompt_tokens,
-            response.usage.completion_tokens,
-            response.usage.estimated_cost_cents,
+            usage.prompt_tokens,
+            usage.completion_tokens,
+            usage.estimated_cost_cents,
             request.component_type,
             request_id,
-        );
+        )
+        .with_cycle_id(request.metadata.cycle_id)
+        .with_cost_micro_cents(cost_micro_cents)
+        .with_latency_ms(started_at.elapsed().as_millis() as u64);
         self.event_callback.on_tokens_used(event);
     }
 
@@ -261,9 +322,11 @@ impl<P: AIProvider + 'static, F: AIProvider + 'static> AIProvider for FailoverAI
         let request_id = uuid::Uuid::new_v4().to_string();
 
         // Try primary provider
+        let started_at = Instant::now();
         match self.primary.complete(request.clone()).await {
             Ok(response) => {
-                self.emit_tokens_used(&request, &response, &request_id);
+                let provider_name = self.primary.provider_info().name;
+                self.emit_tokens_used(&provider_name, &request, &response, &request_id, started_at);
                 Ok(response)
             }
             Err(err) if err.is_retryable() && self.fallback.is_some() => {
@@ -272,8 +335,10 @@ impl<P: AIProvider + 'static, F: AIProvider + 'static> AIProvider for FailoverAI
 
                 // Try fallback
                 let fallback = self.fallback.as_ref().unwrap();
+                let started_at = Instant::now();
                 let response = fallback.complete(request.clone()).await?;
-                self.emit_tokens_used(&request, &response, &request_id);
+                let provider_name = fallback.provider_info().name;
+                self.emit_tokens_used(&provider_name, &request, &response, &request_id, started_at);
                 Ok(response)
             }
             Err(err) => Err(err),
@@ -314,6 +379,19 @@ impl<P: AIProvider + 'static, F: AIProvider + 'static> AIProvider for FailoverAI
         // Report primary provider's info
         self.primary.provider_info()
     }
+
+    async fn submit_batch(&self, requests: Vec<BatchRequest>) -> Result<BatchJob, AIError> {
+        // Batch IDs belong to the provider that issued them, so batches never fail over
+        self.primary.submit_batch(requests).await
+    }
+
+    async fn batch_status(&self, batch_id: &str) -> Result<BatchJob, AIError> {
+        self.primary.batch_status(batch_id).await
+    }
+
+    async fn batch_results(&self, batch_id: &str) -> Result<Vec<BatchResult>, AIError> {
+        self.primary.batch_results(batch_id).await
+    }
 }
 
 #[cfg(test)]
@@ -329,11 +407,13 @@ mod tests {
     struct TestEventCallback {
         tokens_used_count: AtomicU32,
         fallback_count: AtomicU32,
+        last_tokens_used: std::sync::Mutex<Option<events::AITokensUsed>>,
     }
 
     impl AIEventCallback for TestEventCallback {
-        fn on_tokens_used(&self, _event: events::AITokensUsed) {
+        fn on_tokens_used(&self, event: events::AITokensUsed) {
             self.tokens_used_count.fetch_add(1, Ordering::SeqCst);
+            *self.last_tokens_used.lock().unwrap() = Some(event);
         }
 
         fn on_fallback(&self, _event: events::ProviderFallback) {
@@ -481,6 +561,36 @@ mod tests {
         assert_eq!(event.request_id, "req-123");
     }
 
+    #[tokio::test]
+    async fn tokens_used_event_carries_attribution() {
+        use crate::domain::foundation::{ComponentType, CycleId};
+        use crate::ports::{ModelPrice, PriceTable, TokenUsage};
+
+        let primary = MockAIProvider::new().with_response_full(
+            "Hi",
+            TokenUsage::new(1000, 200, 0),
+            crate::ports::FinishReason::Stop,
+        );
+        let callback = Arc::new(TestEventCallback::default());
+        let prices = PriceTable::new().with_price("mock-model", ModelPrice::new(300, 1500));
+        let provider = FailoverAIProvider::new(primary)
+            .with_event_callback(callback.clone())
+            .with_price_table(Arc::new(prices));
+
+        let cycle_id = CycleId::new();
+        let mut request = CompletionRequest::new(test_metadata().with_cycle_id(cycle_id))
+            .with_message(MessageRole::User, "Hello");
+        request.component_type = Some(ComponentType::Objectives);
+        provider.complete(request).await.unwrap();
+
+        let event = callback.last_tokens_used.lock().unwrap().clone().unwrap();
+        assert_eq!(event.provider, "mock");
+        assert_eq!(event.cycle_id, Some(cycle_id));
+        assert_eq!(event.component_type, Some(ComponentType::Objectives));
+        assert_eq!(event.cost_micro_cents, 600_000);
+        assert!(event.latency_ms.is_some());
+    }
+
     #[test]
     fn provider_fallback_event_creates_correctly() {
         let event = events::ProviderFallback::new("openai", "anthropic", "Rate limited", "req-456");
@@ -0,0 +1,764 @@
+//! Gemini Provider - Implementation of AIProvider for Google's Gemini API.
+//!
+//! Supports Gemini 1.5, 2.0 and 2.5 models through the Generative Language
+//! API, with streaming completions via SSE.
+//!
+//! # Configuration
+//!
+//! ```ignore
+//! let config = GeminiConfig::new(api_key)
+//!     .with_model("gemini-2.5-pro")
+//!     .with_base_url("https://generativelanguage.googleapis.com");
+//!
+//! let provider = GeminiProvider::new(config);
+//! ```
+//!
+//! # Streaming
+//!
+//! `streamGenerateContent?alt=sse` emits one complete `GenerateContentResponse`
+//! per `data:` line. Each carries a text fragment; the last one also carries
+//! `finishReason` and the cumulative `usageMetadata`.
+
+use async_trait::async_trait;
+use futures::stream::{self, Stream, StreamExt};
+use reqwest::{Client, Response};
+use secrecy::{ExposeSecret, Secret};
+use serde::{Deserialize, Serialize};
+use std::pin::Pin;
+use std::time::Duration;
+use tokio::time::sleep;
+
+use crate::ports::{
+    AIError, AIProvider, CompletionRequest, CompletionResponse, FinishReason, MessageRole,
+    ProviderInfo, StreamChunk, TokenUsage,
+};
+
+/// Configuration for the Gemini provider.
+#[derive(Debug, Clone)]
+pub struct GeminiConfig {
+    /// API key for authentication.
+    api_key: Secret<String>,
+    /// Model to use (e.g., "gemini-2.5-flash", "gemini-2.5-pro").
+    pub model: String,
+    /// Base URL for the API (default: https://generativelanguage.googleapis.com).
+    pub base_url: String,
+    /// Request timeout.
+    pub timeout: Duration,
+    /// Maximum retries on transient failures.
+    pub max_retries: u32,
+}
+
+impl GeminiConfig {
+    /// Creates a new configuration with the given API key.
+    pub fn new(api_key: impl Into<String>) -> Self {
+        Self {
+            api_key: Secret::new(api_key.into()),
+            model: "gemini-2.5-flash".to_string(),
+            base_url: "https://generativelanguage.googleapis.com".to_string(),
+            timeout: Duration::from_secs(60),
+            max_retries: 3,
+        }
+    }
+
+    /// Sets the model to use.
+    pub fn with_model(mut self, model: impl Into<String>) -> Self {
+        self.model = model.into();
+        self
+    }
+
+    /// Sets the base URL.
+    pub fn with_base_url(mut self, url: impl Into<String>) -> Self {
+        self.base_url = url.into();
+        self
+    }
+
+    /// Sets the request timeout.
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// Sets the maximum retry count.
+    pub fn with_max_retries(mut self, max_retries: u32) -> Self {
+        self.max_retries = max_retries;
+        self
+    }
+
+    /// Exposes the API key (for making requests).
+    fn api_key(&self) -> &str {
+        self.api_key.expose_secret()
+    }
+}
+
+/// Gemini API provider implementation.
+pub struct GeminiProvider {
+    config: GeminiConfig,
+    client: Client,
+}
+
+impl GeminiProvider {
+    /// Creates a new Gemini provider with the given configuration.
+    pub fn new(config: GeminiConfig) -> Self {
+        let client = Client::builder()
+            .timeout(config.timeout)
+            .build()
+            .expect("Failed to create HTTP client");
+
+        Self { config, client }
+    }
+
+    /// Builds the non-streaming endpoint URL.
+    fn generate_url(&self) -> String {
+        format!(
+            "{}/v1beta/models/{}:generateContent",
+            self.config.base_url, self.config.model
+        )
+    }
+
+    /// Builds the streaming endpoint URL.
+    fn stream_url(&self) -> String {
+        format!(
+            "{}/v1beta/models/{}:streamGenerateContent?alt=sse",
+            self.config.base_url, self.config.model
+        )
+    }
+
+    /// Converts our request to Gemini's format.
+    fn to_gemini_request(&self, request: &CompletionRequest) -> GeminiRequest {
+        // Gemini takes system text separately, as `systemInstruction`
+        let mut system_parts: Vec<GeminiPart> = request
+            .system_prompt
+            .iter()
+            .map(|text| GeminiPart { text: text.clone() })
+            .collect();
+        let mut contents: Vec<GeminiContent> = Vec::new();
+
+        for msg in &request.messages {
+            let role = match msg.role {
+                MessageRole::System => {
+                    system_parts.push(GeminiPart {
+                        text: msg.content.clone(),
+                    });
+                    continue;
+                }
+                MessageRole::User => "user",
+                MessageRole::Assistant => "model",
+            };
+            // Consecutive turns from the same role are merged into one content
+            match contents.last_mut() {
+                Some(last) if last.role == role => last.parts.push(GeminiPart {
+                    text: msg.content.clone(),
+                }),
+                _ => contents.push(GeminiContent {
+                    role: role.to_string(),
+                    parts: vec![GeminiPart {
+                        text: msg.content.clone(),
+                    }],
+                }),
+            }
+        }
+
+        // Gemini rejects requests with no contents
+        if contents.is_empty() {
+            contents.push(GeminiContent {
+                role: "user".to_string(),
+                parts: vec![GeminiPart {
+                    text: "Hello".to_string(),
+                }],
+            });
+        }
+
+        GeminiRequest {
+            contents,
+            system_instruction: if system_parts.is_empty() {
+                None
+            } else {
+                Some(GeminiSystemInstruction {
+                    parts: system_parts,
+                })
+            },
+            generation_config: GenerationConfig {
+                max_output_tokens: request.max_tokens,
+                temperature: request.temperature,
+            },
+        }
+    }
+
+    /// Posts a request to the given endpoint.
+    async fn post(&self, url: String, request: &CompletionRequest) -> Result<Response, AIError> {
+        let gemini_request = self.to_gemini_request(request);
+
+        self.client
+            .post(url)
+            .header("x-goog-api-key", self.config.api_key())
+            .header("Content-Type", "application/json")
+            .json(&gemini_request)
+            .send()
+            .await
+            .map_err(|e| {
+                if e.is_timeout() {
+                    AIError::Timeout {
+                        timeout_secs: self.config.timeout.as_secs() as u32,
+                    }
+                } else if e.is_connect() {
+                    AIError::network(format!("Connection failed: {}", e))
+                } else {
+                    AIError::network(e.to_string())
+                }
+            })
+    }
+
+    /// Parses the API response status and handles errors.
+    async fn handle_response_status(&self, response: Response) -> Result<Response, AIError> {
+        let status = response.status();
+
+        if status.is_success() {
+            return Ok(response);
+        }
+
+        let error_body = response.text().await.unwrap_or_default();
+
+        match status.as_u16() {
+            // Google returns 400 API_KEY_INVALID for a bad key
+            400 if error_body.contains("API_KEY_INVALID") => Err(AIError::AuthenticationFailed),
+            401 | 403 => Err(AIError::AuthenticationFailed),
+            429 => Err(AIError::rate_limited(Self::parse_retry_after(&error_body))),
+            400 => {
+                if error_body.contains("exceeds the maximum number of tokens") {
+                    Err(AIError::context_too_long(0, 0))
+                } else {
+                    Err(AIError::InvalidRequest(error_body))
+                }
+            }
+            500..=599 => Err(AIError::unavailable(format!(
+                "Server error {}: {}",
+                status, error_body
+            ))),
+            _ => Err(AIError::network(format!(
+                "Unexpected status {}: {}",
+                status, error_body
+            ))),
+        }
+    }
+
+    /// Parses the retry delay from a RESOURCE_EXHAUSTED error body.
+    ///
+    /// Google reports it as a `google.rpc.RetryInfo` detail, e.g.
+    /// `{"@type": "...RetryInfo", "retryDelay": "17s"}`.
+    fn parse_retry_after(error_body: &str) -> u32 {
+        serde_json::from_str::<serde_json::Value>(error_body)
+            .ok()
+            .and_then(|parsed| {
+                parsed
+                    .get("error")?
+                    .get("details")?
+                    .as_array()?
+                    .iter()
+                    .find_map(|detail| detail.get("retryDelay")?.as_str().map(str::to_string))
+            })
+            .and_then(|delay| delay.trim_end_matches('s').parse::<f64>().ok())
+            .map(|secs| secs.ceil() as u32)
+            .unwrap_or(30)
+    }
+
+    /// Parses a non-streaming response.
+    async fn parse_response(&self, response: Response) -> Result<CompletionResponse, AIError> {
+        let response = self.handle_response_status(response).await?;
+
+        let gemini_response: GeminiResponse = response
+            .json()
+            .await
+            .map_err(|e| AIError::parse(format!("Failed to parse response: {}", e)))?;
+
+        if let Some(reason) = gemini_response.blocked_reason() {
+            return Err(AIError::content_filtered(reason));
+        }
+
+        let usage = gemini_response
+            .usage_metadata
+            .as_ref()
+            .map(|u| {
+                TokenUsage::new(
+                    u.prompt_token_count,
+                    u.candidates_token_count,
+                    self.calculate_cost(u.prompt_token_count, u.candidates_token_count),
+                )
+            })
+            .unwrap_or_default();
+        let finish_reason = gemini_response
+            .finish_reason()
+            .unwrap_or(FinishReason::Stop);
+
+        Ok(CompletionResponse {
+            content: gemini_response.text(),
+            usage,
+            model: gemini_response
+                .model_version
+                .unwrap_or_else(|| self.config.model.clone()),
+            finish_reason,
+        })
+    }
+
+    /// Calculates estimated cost in cents based on model and token counts.
+    fn calculate_cost(&self, input_tokens: u32, output_tokens: u32) -> u32 {
+        let (input_price, output_price) = model_prices(&self.config.model);
+        cost_cents(input_tokens, output_tokens, input_price, output_price)
+    }
+}
+
+/// Prices per 1M tokens (in cents) for prompts up to 200k tokens.
+fn model_prices(model: &str) -> (u64, u64) {
+    match model {
+        m if m.contains("2.5-pro") => (125, 1000), // $1.25/$10 per 1M
+        m if m.contains("2.5-flash-lite") => (10, 40), // $0.10/$0.40 per 1M
+        m if m.contains("2.5-flash") => (30, 250), // $0.30/$2.50 per 1M
+        m if m.contains("2.0-flash-lite") => (8, 30), // $0.075/$0.30 per 1M
+        m if m.contains("2.0-flash") => (10, 40),  // $0.10/$0.40 per 1M
+        m if m.contains("1.5-pro") => (125, 500),  // $1.25/$5 per 1M
+        m if m.contains("1.5-flash") => (8, 30),   // $0.075/$0.30 per 1M
+        _ => (30, 250),                            // Default to 2.5 Flash pricing
+    }
+}
+
+fn cost_cents(input_tokens: u32, output_tokens: u32, input_price: u64, output_price: u64) -> u32 {
+    let input_cost = (input_tokens as u64 * input_price) / 1_000_000;
+    let output_cost = (output_tokens as u64 * output_price) / 1_000_000;
+    (input_cost + output_cost) as u32
+}
+
+fn map_finish_reason(reason: &str) -> FinishReason {
+    match reason {
+        "MAX_TOKENS" => FinishReason::Length,
+        "SAFETY" | "RECITATION" | "BLOCKLIST" | "PROHIBITED_CONTENT" | "SPII" => {
+            FinishReason::ContentFilter
+        }
+        _ => FinishReason::Stop,
+    }
+}
+
+#[async_trait]
+impl AIProvider for GeminiProvider {
+    #[tracing::instrument(
+        name = "ai.complete",
+        skip_all,
+        fields(
+            ai.provider = "gemini",
+            ai.model = %self.config.model,
+            ai.prompt_tokens = tracing::field::Empty,
+            ai.completion_tokens = tracing::field::Empty,
+        ),
+        err
+    )]
+    async fn complete(&self, request: CompletionRequest) -> Result<CompletionResponse, AIError> {
+        let mut last_error = AIError::network("No attempts made");
+        let mut retry_count = 0;
+
+        while retry_count <= self.config.max_retries {
+            let result = match self.post(self.generate_url(), &request).await {
+                Ok(response) => self.parse_response(response).await,
+                Err(err) => Err(err),
+            };
+
+            match result {
+                Ok(completion) => {
+                    let span = tracing::Span::current();
+                    span.record("ai.prompt_tokens", completion.usage.prompt_tokens);
+                    span.record("ai.completion_tokens", completion.usage.completion_tokens);
+                    return Ok(completion);
+                }
+                Err(err) => {
+                    if !err.is_retryable() || retry_count >= self.config.max_retries {
+                        return Err(err);
+                    }
+                    last_error = err;
+                }
+            }
+
+            // Exponential backoff: 1s, 2s, 4s, ...
+            let delay = Duration::from_secs(1 << retry_count);
+            sleep(delay).await;
+            retry_count += 1;
+        }
+
+        Err(last_error)
+    }
+
+    #[tracing::instrument(
+        name = "ai.stream_complete",
+        skip_all,
+        fields(ai.provider = "gemini", ai.model = %self.config.model),
+        err
+    )]
+    async fn stream_complete(
+        &self,
+        request: CompletionRequest,
+    ) -> Result<Pin<Box<dyn Stream<Item = Result<StreamChunk, AIError>> + Send>>, AIError> {
+        let response = self.post(self.stream_url(), &request).await?;
+        let response = self.handle_response_status(response).await?;
+
+        let (input_price, output_price) = model_prices(&self.config.model);
+
+        // A data line may be split across network chunks, so keep the
+        // unterminated tail and prepend it to the next chunk.
+        let stream = response
+            .bytes_stream()
+            .scan(String::new(), move |buffer, chunk_result| {
+                let results = match chunk_result {
+                    Ok(bytes) => {
+                        buffer.push_str(&String::from_utf8_lossy(&bytes));
+                        let complete_len = buffer.rfind('\n').map_or(0, |idx| idx + 1);
+                        let complete: String = buffer.drain(..complete_len).collect();
+                        parse_gemini_sse(&complete, input_price, output_price)
+                    }
+                    Err(e) => vec![Err(AIError::network(format!("Stream error: {}", e)))],
+                };
+                futures::future::ready(Some(results))
+            })
+            .flat_map(stream::iter);
+
+        Ok(Box::pin(stream))
+    }
+
+    fn estimate_tokens(&self, text: &str) -> u32 {
+        // Gemini tokenizers average ~4 characters per token for English
+        (text.len() / 4).max(1) as u32
+    }
+
+    fn provider_info(&self) -> ProviderInfo {
+        let max_context = match self.config.model.as_str() {
+            m if m.contains("1.5-pro") => 2_097_152,
+            _ => 1_048_576, // Flash and 2.x models have a 1M context window
+        };
+
+        ProviderInfo::new("gemini", &self.config.model, max_context)
+            .with_streaming(true)
+            .with_functions(true)
+    }
+}
+
+/// Parses Gemini SSE format into StreamChunks.
+///
+/// Each event is a single `data:` line holding a full response object:
+/// ```text
+/// data: {"candidates":[{"content":{"parts":[{"text":"Hello"}],"role":"model"}}]}
+/// ```
+fn parse_gemini_sse(
+    text: &str,
+    input_price_factor: u64,
+    output_price_factor: u64,
+) -> Vec<Result<StreamChunk, AIError>> {
+    let mut results = Vec::new();
+
+    for line in text.lines() {
+        let Some(data) = line.strip_prefix("data:").map(str::trim_start) else {
+            continue;
+        };
+
+        if let Ok(error) = serde_json::from_str::<StreamError>(data) {
+            results.push(Err(AIError::unavailable(
+                error
+                    .error
+                    .message
+                    .unwrap_or_else(|| "Stream error".to_string()),
+            )));
+            continue;
+        }
+
+        let Ok(response) = serde_json::from_str::<GeminiResponse>(data) else {
+            continue;
+        };
+
+        if let Some(reason) = response.blocked_reason() {
+            results.push(Err(AIError::content_filtered(reason)));
+            continue;
+        }
+
+        let text = response.text();
+        if !text.is_empty() {
+            results.push(Ok(StreamChunk::content(&text)));
+        }
+
+        if let Some(finish_reason) = response.finish_reason() {
+            let usage = response.token_usage(input_price_factor, output_price_factor);
+            results.push(Ok(StreamChunk::final_chunk(finish_reason, usage)));
+        }
+    }
+
+    results
+}
+
+// ----- Gemini API Types -----
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct GeminiRequest {
+    contents: Vec<GeminiContent>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    system_instruction: Option<GeminiSystemInstruction>,
+    generation_config: GenerationConfig,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct GeminiContent {
+    #[serde(default)]
+    role: String,
+    #[serde(default)]
+    parts: Vec<GeminiPart>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct GeminiPart {
+    #[serde(default)]
+    text: String,
+}
+
+#[derive(Debug, Serialize)]
+struct GeminiSystemInstruction {
+    parts: Vec<GeminiPart>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct GenerationConfig {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    max_output_tokens: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    temperature: Option<f32>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct GeminiResponse {
+    #[serde(default)]
+    candidates: Vec<Candidate>,
+    prompt_feedback: Option<PromptFeedback>,
+    usage_metadata: Option<UsageMetadata>,
+    model_version: Option<String>,
+}
+
+impl GeminiResponse {
+    /// Concatenated text of the first candidate.
+    fn text(&self) -> String {
+        self.candidates
+            .first()
+            .and_then(|c| c.content.as_ref())
+            .map(|content| {
+                content
+                    .parts
+                    .iter()
+                    .map(|p| p.text.as_str())
+                    .collect::<String>()
+            })
+            .unwrap_or_default()
+    }
+
+    fn finish_reason(&self) -> Option<FinishReason> {
+        self.candidates
+            .first()
+            .and_then(|c| c.finish_reason.as_deref())
+            .map(map_finish_reason)
+    }
+
+    /// Set when the prompt itself was blocked and no candidates were produced.
+    fn blocked_reason(&self) -> Option<String> {
+        self.prompt_feedback
+            .as_ref()
+            .and_then(|f| f.block_reason.clone())
+            .map(|reason| format!("Prompt blocked: {}", reason))
+    }
+
+    fn token_usage(&self, input_price: u64, output_price: u64) -> TokenUsage {
+        self.usage_metadata
+            .as_ref()
+            .map(|u| {
+                TokenUsage::new(
+                    u.prompt_token_count,
+                    u.candidates_token_count,
+                    cost_cents(
+                        u.prompt_token_count,
+                        u.candidates_token_count,
+                        input_price,
+                        output_price,
+                    ),
+                )
+            })
+            .unwrap_or_default()
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct Candidate {
+    content: Option<GeminiContent>,
+    finish_reason: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct PromptFeedback {
+    block_reason: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct UsageMetadata {
+    #[serde(default)]
+    prompt_token_count: u32,
+    #[serde(default)]
+    candidates_token_count: u32,
+}
+
+#[derive(Debug, Deserialize)]
+struct StreamError {
+    error: StreamErrorContent,
+}
+
+#[derive(Debug, Deserialize)]
+struct StreamErrorContent {
+    message: Option<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::foundation::{ConversationId, SessionId, UserId};
+    use crate::ports::RequestMetadata;
+
+    #[test]
+    fn config_builder_works() {
+        let config = GeminiConfig::new("test-key")
+            .with_model("gemini-2.5-pro")
+            .with_base_url("https://custom.api.com")
+            .with_timeout(Duration::from_secs(30))
+            .with_max_retries(5);
+
+        assert_eq!(config.model, "gemini-2.5-pro");
+        assert_eq!(config.base_url, "https://custom.api.com");
+        assert_eq!(config.timeout, Duration::from_secs(30));
+        assert_eq!(config.max_retries, 5);
+        assert_eq!(config.api_key(), "test-key");
+    }
+
+    #[test]
+    fn endpoints_include_model() {
+        let provider =
+            GeminiProvider::new(GeminiConfig::new("test").with_model("gemini-2.0-flash"));
+
+        assert_eq!(
+            provider.generate_url(),
+            "https://generativelanguage.googleapis.com/v1beta/models/gemini-2.0-flash:generateContent"
+        );
+        assert!(provider
+            .stream_url()
+            .ends_with("gemini-2.0-flash:streamGenerateContent?alt=sse"));
+    }
+
+    #[test]
+    fn request_maps_roles_and_system_instruction() {
+        let provider = GeminiProvider::new(GeminiConfig::new("test"));
+        let metadata = RequestMetadata::new(
+            UserId::new("test-user").unwrap(),
+            SessionId::new(),
+            ConversationId::new(),
+            "trace-123",
+        );
+        let request = CompletionRequest::new(metadata)
+            .with_system_prompt("Be concise")
+            .with_message(MessageRole::User, "Hi")
+            .with_message(MessageRole::Assistant, "Hello")
+            .with_message(MessageRole::User, "Help me decide")
+            .with_message(MessageRole::User, "between two jobs")
+            .with_max_tokens(256);
+
+        let json = serde_json::to_value(provider.to_gemini_request(&request)).unwrap();
+
+        assert_eq!(json["systemInstruction"]["parts"][0]["text"], "Be concise");
+        assert_eq!(json["contents"][0]["role"], "user");
+        assert_eq!(json["contents"][1]["role"], "model");
+        // Consecutive user turns are merged
+        assert_eq!(json["contents"].as_array().unwrap().len(), 3);
+        assert_eq!(json["contents"][2]["parts"].as_array().unwrap().len(), 2);
+        assert_eq!(json["generationConfig"]["maxOutputTokens"], 256);
+    }
+
+    #[test]
+    fn cost_calculation_pro() {
+        let provider = GeminiProvider::new(GeminiConfig::new("test").with_model("gemini-2.5-pro"));
+
+        // 1M input tokens = $1.25 = 125 cents
+        // 1M output tokens = $10 = 1000 cents
+        assert_eq!(provider.calculate_cost(1_000_000, 1_000_000), 1125);
+    }
+
+    #[test]
+    fn cost_calculation_flash() {
+        let provider =
+            GeminiProvider::new(GeminiConfig::new("test").with_model("gemini-2.5-flash"));
+
+        // 1M input tokens = $0.30 = 30 cents
+        // 1M output tokens = $2.50 = 250 cents
+        assert_eq!(provider.calculate_cost(1_000_000, 1_000_000), 280);
+    }
+
+    #[test]
+    fn provider_info_reports_gemini() {
+        let provider = GeminiProvider::new(GeminiConfig::new("test"));
+
+        let info = provider.provider_info();
+        assert_eq!(info.name, "gemini");
+        assert_eq!(info.model, "gemini-2.5-flash");
+        assert_eq!(info.max_context_tokens, 1_048_576);
+        assert!(info.supports_streaming);
+        assert!(info.supports_functions);
+    }
+
+    #[test]
+    fn parse_retry_after_reads_retry_info() {
+        let error = r#"{"error":{"code":429,"status":"RESOURCE_EXHAUSTED","details":[{"@type":"type.googleapis.com/google.rpc.RetryInfo","retryDelay":"17s"}]}}"#;
+        assert_eq!(GeminiProvider::parse_retry_after(error), 17);
+        assert_eq!(GeminiProvider::parse_retry_after("{}"), 30);
+    }
+
+    #[test]
+    fn parse_sse_content_chunks() {
+        let data = "data: {\"candidates\":[{\"content\":{\"parts\":[{\"text\":\"Hi\"}],\"role\":\"model\"}}]}\r\n\r\ndata: {\"candidates\":[{\"content\":{\"parts\":[{\"text\":\" there\"}],\"role\":\"model\"}}]}\r\n\r\n";
+        let chunks = parse_gemini_sse(data, 30, 250);
+
+        assert_eq!(chunks.len(), 2);
+        assert_eq!(chunks[0].as_ref().unwrap().delta, "Hi");
+        assert_eq!(chunks[1].as_ref().unwrap().delta, " there");
+        assert!(!chunks[1].as_ref().unwrap().is_final());
+    }
+
+    #[test]
+    fn parse_sse_final_chunk_with_usage() {
+        let data = "data: {\"candidates\":[{\"content\":{\"parts\":[{\"text\":\"Done\"}],\"role\":\"model\"},\"finishReason\":\"MAX_TOKENS\"}],\"usageMetadata\":{\"promptTokenCount\":1000000,\"candidatesTokenCount\":1000000,\"totalTokenCount\":2000000}}\n";
+        let chunks = parse_gemini_sse(data, 30, 250);
+
+        assert_eq!(chunks.len(), 2);
+        assert_eq!(chunks[0].as_ref().unwrap().delta, "Done");
+        let last = chunks[1].as_ref().unwrap();
+        assert!(last.is_final());
+        assert_eq!(last.finish_reason, Some(FinishReason::Length));
+        let usage = last.usage.as_ref().unwrap();
+        assert_eq!(usage.prompt_tokens, 1_000_000);
+        assert_eq!(usage.completion_tokens, 1_000_000);
+        assert_eq!(usage.estimated_cost_cents, 280);
+    }
+
+    #[test]
+    fn parse_sse_blocked_prompt_is_content_filtered() {
+        let data = "data: {\"promptFeedback\":{\"blockReason\":\"SAFETY\"}}\n";
+        let chunks = parse_gemini_sse(data, 30, 250);
+
+        assert_eq!(chunks.len(), 1);
+        assert!(matches!(chunks[0], Err(AIError::ContentFiltered { .. })));
+    }
+
+    #[test]
+    fn safety_finish_maps_to_content_filter() {
+        assert_eq!(map_finish_reason("SAFETY"), FinishReason::ContentFilter);
+        assert_eq!(map_finish_reason("STOP"), FinishReason::Stop);
+        assert_eq!(map_finish_reason("MAX_TOKENS"), FinishReason::Length);
+    }
+}
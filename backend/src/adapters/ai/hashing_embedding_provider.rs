@@ -0,0 +1,105 @@
+//! Hashing Embedding Provider - Local, deterministic embeddings.
+//!
+//! Hashes each lowercased word into one of [`EMBEDDING_DIMENSIONS`] buckets
+//! (the "hashing trick") and normalizes the counts. Texts sharing words end
+//! up close together; synonyms do not. Good enough for development,
+//! tests and self-hosted installs without an embeddings API, and stable
+//! across runs because the hash is FNV-1a rather than std's random hasher.
+
+use async_trait::async_trait;
+
+use crate::domain::foundation::Embedding;
+use crate::ports::{truncate_embedding_input, AIError, EmbeddingProvider, EMBEDDING_DIMENSIONS};
+
+/// Model name stored with hashed embeddings.
+pub const HASHING_EMBEDDING_MODEL: &str = "hashing-bow-v1";
+
+/// Bag-of-words embeddings computed in-process.
+#[derive(Debug, Clone, Default)]
+pub struct HashingEmbeddingProvider;
+
+impl HashingEmbeddingProvider {
+    pub fn new() -> Self {
+        Self
+    }
+
+    fn embed_one(text: &str) -> Embedding {
+        let mut values = vec![0.0f32; EMBEDDING_DIMENSIONS];
+        for word in truncate_embedding_input(text)
+            .split(|c: char| !c.is_alphanumeric())
+            .filter(|word| !word.is_empty())
+        {
+            let hash = fnv1a(&word.to_lowercase());
+            let bucket = (hash % EMBEDDING_DIMENSIONS as u64) as usize;
+            // A second hash bit spreads collisions out instead of stacking them
+            let sign = if hash >> 63 == 0 { 1.0 } else { -1.0 };
+            values[bucket] += sign;
+        }
+
+        let norm = values.iter().map(|v| v * v).sum::<f32>().sqrt();
+        if norm > 0.0 {
+            for value in &mut values {
+                *value /= norm;
+            }
+        }
+        Embedding::new(values).expect("hashed embeddings are finite and non-empty")
+    }
+}
+
+fn fnv1a(text: &str) -> u64 {
+    text.bytes().fold(0xcbf29ce484222325, |hash, byte| {
+        (hash ^ u64::from(byte)).wrapping_mul(0x100000001b3)
+    })
+}
+
+#[async_trait]
+impl EmbeddingProvider for HashingEmbeddingProvider {
+    async fn embed(&self, inputs: &[String]) -> Result<Vec<Embedding>, AIError> {
+        Ok(inputs.iter().map(|input| Self::embed_one(input)).collect())
+    }
+
+    fn model(&self) -> &str {
+        HASHING_EMBEDDING_MODEL
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    async fn embed(texts: &[&str]) -> Vec<Embedding> {
+        HashingEmbeddingProvider::new()
+            .embed(&texts.iter().map(|t| t.to_string()).collect::<Vec<_>>())
+            .await
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn shared_words_are_more_similar_than_unrelated_text() {
+        let embeddings = embed(&[
+            "Should I move to Lisbon for the job?",
+            "Moving to Lisbon for a new job",
+            "Which laptop to buy",
+        ])
+        .await;
+
+        let related = embeddings[0].cosine_similarity(&embeddings[1]);
+        let unrelated = embeddings[0].cosine_similarity(&embeddings[2]);
+        assert!(related > unrelated);
+    }
+
+    #[tokio::test]
+    async fn embeddings_are_deterministic_and_case_insensitive() {
+        let embeddings = embed(&["Lisbon Job", "lisbon job"]).await;
+
+        assert_eq!(embeddings[0], embeddings[1]);
+        assert_eq!(embeddings[0].dimensions(), EMBEDDING_DIMENSIONS);
+    }
+
+    #[tokio::test]
+    async fn empty_text_embeds_to_zero_vector() {
+        let embeddings = embed(&[""]).await;
+
+        assert!(embeddings[0].as_slice().iter().all(|v| *v == 0.0));
+    }
+}
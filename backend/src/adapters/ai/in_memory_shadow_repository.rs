@@ -0,0 +1,81 @@
+//! In-memory shadow comparison store.
+//!
+//! Keeps comparisons in process for development and short evaluation runs.
+//! Data is lost on restart.
+
+use async_trait::async_trait;
+use std::collections::BTreeSet;
+use std::sync::Mutex;
+
+use crate::domain::foundation::DomainError;
+use crate::ports::{ShadowComparison, ShadowComparisonRepository};
+
+/// In-memory implementation of the ShadowComparisonRepository port.
+#[derive(Default)]
+pub struct InMemoryShadowRepository {
+    comparisons: Mutex<Vec<ShadowComparison>>,
+}
+
+impl InMemoryShadowRepository {
+    /// Creates an empty store.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Number of stored comparisons.
+    pub fn len(&self) -> usize {
+        self.comparisons.lock().unwrap().len()
+    }
+
+    /// Returns true if nothing has been recorded.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+#[async_trait]
+impl ShadowComparisonRepository for InMemoryShadowRepository {
+    async fn record(&self, comparison: &ShadowComparison) -> Result<(), DomainError> {
+        self.comparisons.lock().unwrap().push(comparison.clone());
+        Ok(())
+    }
+
+    async fn list_by_variant(
+        &self,
+        variant: &str,
+        limit: u32,
+    ) -> Result<Vec<ShadowComparison>, DomainError> {
+        Ok(self
+            .comparisons
+            .lock()
+            .unwrap()
+            .iter()
+            .rev()
+            .filter(|c| c.variant == variant)
+            .take(limit as usize)
+            .cloned()
+            .collect())
+    }
+
+    async fn all_by_variant(&self, variant: &str) -> Result<Vec<ShadowComparison>, DomainError> {
+        Ok(self
+            .comparisons
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|c| c.variant == variant)
+            .cloned()
+            .collect())
+    }
+
+    async fn variants(&self) -> Result<Vec<String>, DomainError> {
+        let names: BTreeSet<_> = self
+            .comparisons
+            .lock()
+            .unwrap()
+            .iter()
+            .map(|c| c.variant.clone())
+            .collect();
+        Ok(names.into_iter().collect())
+    }
+}
@@ -13,9 +13,10 @@ use async_trait::async_trait;
 use std::collections::HashMap;
 use std::sync::Mutex;
 
-use crate::domain::foundation::{SessionId, Timestamp, UserId};
+use crate::domain::foundation::{CycleId, SessionId, Timestamp, UserId};
 use crate::ports::{
-    ProviderUsage, UsageLimitStatus, UsageRecord, UsageSummary, UsageTracker, UsageTrackerError,
+    CycleCostRollup, ProviderUsage, UsageLimitStatus, UsageRecord, UsageSummary, UsageTracker,
+    UsageTrackerError,
 };
 
 /// In-memory implementation of the UsageTracker port.
@@ -111,6 +112,13 @@ impl UsageTracker for InMemoryUsageTracker {
         Ok(total)
     }
 
+    async fn get_cycle_costs(&self, cycle_id: CycleId) -> Result<CycleCostRollup, UsageTrackerError> {
+        let records = self.records.lock().unwrap();
+        Ok(CycleCostRollup::from_records(
+            records.iter().filter(|r| r.cycle_id == Some(cycle_id)),
+        ))
+    }
+
     async fn get_usage_summary(
         &self,
         user_id: &UserId,
@@ -149,6 +157,22 @@ impl UsageTracker for InMemoryUsageTracker {
         })
     }
 
+    async fn list_users_with_usage(
+        &self,
+        from: Timestamp,
+        to: Timestamp,
+    ) -> Result<Vec<UserId>, UsageTrackerError> {
+        let records = self.records.lock().unwrap();
+        let mut user_ids: Vec<UserId> = records
+            .iter()
+            .filter(|r| r.occurred_at >= from && r.occurred_at <= to)
+            .map(|r| r.user_id.clone())
+            .collect();
+        user_ids.sort_by(|a, b| a.as_str().cmp(b.as_str()));
+        user_ids.dedup();
+        Ok(user_ids)
+    }
+
     async fn check_daily_limit(
         &self,
         user_id: &UserId,
@@ -166,6 +190,13 @@ impl UsageTracker for InMemoryUsageTracker {
         let current = self.get_session_cost(session_id).await?;
         Ok(UsageLimitStatus::from_usage(current, limit_cents))
     }
+
+    async fn erase_user(&self, user_id: &UserId) -> Result<u64, UsageTrackerError> {
+        let mut records = self.records.lock().unwrap();
+        let before = records.len();
+        records.retain(|r| &r.user_id != user_id);
+        Ok((before - records.len()) as u64)
+    }
 }
 
 #[cfg(test)]
@@ -236,6 +267,41 @@ mod tests {
         assert_eq!(daily_cost, 45); // 15 + 30
     }
 
+    #[tokio::test]
+    async fn rolls_up_costs_for_one_cycle() {
+        use crate::domain::foundation::ComponentType;
+
+        let tracker = InMemoryUsageTracker::new();
+        let user_id = UserId::new("user-1").unwrap();
+        let session_id = SessionId::new();
+        let cycle_id = CycleId::new();
+
+        for (cycle, cost) in [(cycle_id, 400_000), (cycle_id, 300_000), (CycleId::new(), 900_000)] {
+            tracker
+                .record_usage(
+                    UsageRecord::new(
+                        user_id.clone(),
+                        session_id,
+                        "anthropic",
+                        "claude-sonnet-4",
+                        100,
+                        50,
+                        0,
+                        Some(ComponentType::Objectives),
+                    )
+                    .with_cycle_id(cycle)
+                    .with_cost_micro_cents(cost),
+                )
+                .await
+                .unwrap();
+        }
+
+        let rollup = tracker.get_cycle_costs(cycle_id).await.unwrap();
+        assert_eq!(rollup.total.requests, 2);
+        assert_eq!(rollup.total.cost_micro_cents, 700_000);
+        assert_eq!(rollup.component(ComponentType::Objectives).requests, 2);
+    }
+
     #[tokio::test]
     async fn calculates_session_cost() {
         let tracker = InMemoryUsageTracker::new();
@@ -428,4 +494,38 @@ mod tests {
         assert_eq!(summary.request_count, 2);
         assert_eq!(summary.by_provider.len(), 2);
     }
+
+    #[tokio::test]
+    async fn lists_each_user_with_usage_once() {
+        let tracker = InMemoryUsageTracker::new();
+        let alice = UserId::new("alice").unwrap();
+        let bob = UserId::new("bob").unwrap();
+        for user_id in [&bob, &alice, &bob] {
+            tracker
+                .record_usage(UsageRecord::new(
+                    user_id.clone(),
+                    SessionId::new(),
+                    "openai",
+                    "gpt-4",
+                    100,
+                    50,
+                    15,
+                    None,
+                ))
+                .await
+                .unwrap();
+        }
+
+        let from = Timestamp::now().minus_days(1);
+        let to = Timestamp::now().plus_days(1);
+        let users = tracker.list_users_with_usage(from, to).await.unwrap();
+        assert_eq!(users, vec![alice, bob]);
+
+        let tomorrow = Timestamp::now().plus_days(1);
+        let none = tracker
+            .list_users_with_usage(tomorrow, tomorrow.plus_days(1))
+            .await
+            .unwrap();
+        assert!(none.is_empty());
+    }
 }
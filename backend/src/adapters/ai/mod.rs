@@ -4,23 +4,51 @@
 //!
 //! ## Available Adapters
 //!
-//! - `MockAIProvider` - Configurable mock for testing
+//! - `MockAIProvider` - Configurable mock for testing (`test-support`)
 //! - `OpenAIProvider` - OpenAI GPT models (GPT-4, GPT-3.5)
+//! - `OpenAIEmbeddingProvider` - OpenAI embeddings for semantic search
+//! - `HashingEmbeddingProvider` - Local bag-of-words embeddings for development and self-hosting
 //! - `AnthropicProvider` - Anthropic Claude models (Opus, Sonnet, Haiku)
+//! - `AzureOpenAIProvider` - OpenAI models hosted on Azure, routed by deployment
+//! - `GeminiProvider` - Google Gemini models (Pro, Flash)
+//! - `OllamaProvider` - Local open-weight models served by Ollama
 //! - `FailoverAIProvider` - Wrapper with automatic failover between providers
+//! - `CachedAIProvider` - Wrapper serving repeated completions from a shared cache
 //! - `AIUsageHandler` - Event handler for tracking AI token usage
 //! - `InMemoryUsageTracker` - In-memory usage tracking for dev/testing
+//! - `ShadowAIProvider` - Wrapper replaying sampled requests to a candidate variant
+//! - `InMemoryShadowRepository` - In-memory store of shadow comparisons
 
 mod anthropic_provider;
+mod azure_openai_provider;
+mod cached_provider;
 mod failover_provider;
+mod gemini_provider;
+mod hashing_embedding_provider;
+mod in_memory_shadow_repository;
 mod in_memory_usage_tracker;
+#[cfg(any(test, feature = "test-support"))]
 mod mock_provider;
+mod ollama_provider;
+mod openai_embedding_provider;
 mod openai_provider;
+mod shadow_provider;
 mod usage_handler;
 
 pub use anthropic_provider::{AnthropicConfig, AnthropicProvider};
+pub use azure_openai_provider::{
+    AzureOpenAIConfig, AzureOpenAIProvider, DEFAULT_AZURE_API_VERSION,
+};
+pub use cached_provider::{CachedAIProvider, DEFAULT_AI_CACHE_TTL};
 pub use failover_provider::{events as ai_events, AIEventCallback, FailoverAIProvider};
+pub use gemini_provider::{GeminiConfig, GeminiProvider};
+pub use hashing_embedding_provider::{HashingEmbeddingProvider, HASHING_EMBEDDING_MODEL};
+pub use in_memory_shadow_repository::InMemoryShadowRepository;
 pub use in_memory_usage_tracker::InMemoryUsageTracker;
+#[cfg(any(test, feature = "test-support"))]
 pub use mock_provider::{MockAIProvider, MockError, MockResponse};
+pub use ollama_provider::{OllamaConfig, OllamaProvider};
+pub use openai_embedding_provider::{OpenAIEmbeddingProvider, DEFAULT_OPENAI_EMBEDDING_MODEL};
 pub use openai_provider::{OpenAIConfig, OpenAIProvider};
+pub use shadow_provider::{ShadowAIProvider, ShadowVariant};
 pub use usage_handler::AIUsageHandler;
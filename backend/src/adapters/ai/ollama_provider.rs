@@ -0,0 +1,640 @@
+//! Ollama Provider - Implementation of AIProvider for a local Ollama server.
+//!
+//! Lets self-hosted deployments run on open-weight models (Llama, Qwen,
+//! Mistral, ...) with no external AI costs. All usage is reported at zero
+//! cost.
+//!
+//! # Configuration
+//!
+//! ```ignore
+//! let config = OllamaConfig::new()
+//!     .with_base_url("http://localhost:11434")
+//!     .with_model("llama3.1:8b")
+//!     .with_component_model(ComponentType::Consequences, "qwen2.5:32b");
+//!
+//! let provider = OllamaProvider::new(config);
+//! ```
+//!
+//! # Streaming
+//!
+//! `/api/chat` streams newline-delimited JSON rather than SSE. Every line is a
+//! message fragment; the last has `"done": true` plus the token counts.
+//!
+//! # Tool Calls
+//!
+//! Not every local model supports tool calling. `provider_info` reports
+//! `supports_functions` from a list of known tool-capable families (or an
+//! explicit override) so callers can fall back to plain prompting.
+
+use async_trait::async_trait;
+use futures::stream::{self, Stream, StreamExt};
+use reqwest::{Client, Response};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::pin::Pin;
+use std::time::Duration;
+use tokio::time::sleep;
+
+use crate::domain::foundation::ComponentType;
+use crate::ports::{
+    AIError, AIProvider, CompletionRequest, CompletionResponse, FinishReason, MessageRole,
+    ProviderInfo, StreamChunk, TokenUsage,
+};
+
+/// Model families whose Ollama builds support tool calling.
+const TOOL_CAPABLE_FAMILIES: &[&str] = &[
+    "llama3.1",
+    "llama3.2",
+    "llama3.3",
+    "llama4",
+    "qwen2.5",
+    "qwen3",
+    "mistral-nemo",
+    "mistral-small",
+    "mistral-large",
+    "command-r",
+    "firefunction",
+    "hermes3",
+    "granite3",
+];
+
+/// Configuration for the Ollama provider.
+#[derive(Debug, Clone)]
+pub struct OllamaConfig {
+    /// Default model tag (e.g., "llama3.1:8b", "qwen2.5:14b").
+    pub model: String,
+    /// Model tags to use instead of `model` for specific components.
+    pub component_models: HashMap<ComponentType, String>,
+    /// Base URL of the Ollama server (default: http://localhost:11434).
+    pub base_url: String,
+    /// Request timeout. Local models can be slow, so this defaults high.
+    pub timeout: Duration,
+    /// Maximum retries on transient failures.
+    pub max_retries: u32,
+    /// Context window to request, in tokens.
+    pub context_tokens: u32,
+    /// Overrides tool-call detection for models not in the known list.
+    pub supports_tools: Option<bool>,
+}
+
+impl OllamaConfig {
+    /// Creates a configuration for a server on localhost.
+    pub fn new() -> Self {
+        Self {
+            model: "llama3.1:8b".to_string(),
+            component_models: HashMap::new(),
+            base_url: "http://localhost:11434".to_string(),
+            timeout: Duration::from_secs(300),
+            max_retries: 1,
+            context_tokens: 8192,
+            supports_tools: None,
+        }
+    }
+
+    /// Sets the default model.
+    pub fn with_model(mut self, model: impl Into<String>) -> Self {
+        self.model = model.into();
+        self
+    }
+
+    /// Uses a different model for one component.
+    pub fn with_component_model(
+        mut self,
+        component_type: ComponentType,
+        model: impl Into<String>,
+    ) -> Self {
+        self.component_models.insert(component_type, model.into());
+        self
+    }
+
+    /// Sets the base URL.
+    pub fn with_base_url(mut self, url: impl Into<String>) -> Self {
+        self.base_url = url.into();
+        self
+    }
+
+    /// Sets the request timeout.
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// Sets the maximum retry count.
+    pub fn with_max_retries(mut self, max_retries: u32) -> Self {
+        self.max_retries = max_retries;
+        self
+    }
+
+    /// Sets the context window size passed as `num_ctx`.
+    pub fn with_context_tokens(mut self, tokens: u32) -> Self {
+        self.context_tokens = tokens;
+        self
+    }
+
+    /// Declares whether the configured models support tool calls.
+    pub fn with_tool_support(mut self, supports: bool) -> Self {
+        self.supports_tools = Some(supports);
+        self
+    }
+
+    /// Model to use for a request.
+    pub fn model_for(&self, component_type: Option<ComponentType>) -> &str {
+        component_type
+            .and_then(|ct| self.component_models.get(&ct))
+            .unwrap_or(&self.model)
+    }
+}
+
+impl Default for OllamaConfig {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Whether a model tag belongs to a tool-capable family.
+fn model_supports_tools(model: &str) -> bool {
+    // Strip any namespace ("library/llama3.1") and tag (":8b")
+    let name = model.rsplit('/').next().unwrap_or(model);
+    let name = name.split(':').next().unwrap_or(name);
+    TOOL_CAPABLE_FAMILIES
+        .iter()
+        .any(|family| name.starts_with(family))
+}
+
+/// Ollama API provider implementation.
+pub struct OllamaProvider {
+    config: OllamaConfig,
+    client: Client,
+}
+
+impl OllamaProvider {
+    /// Creates a new Ollama provider with the given configuration.
+    pub fn new(config: OllamaConfig) -> Self {
+        let client = Client::builder()
+            .timeout(config.timeout)
+            .build()
+            .expect("Failed to create HTTP client");
+
+        Self { config, client }
+    }
+
+    /// Builds the chat endpoint URL.
+    fn chat_url(&self) -> String {
+        format!("{}/api/chat", self.config.base_url.trim_end_matches('/'))
+    }
+
+    /// Converts our request to Ollama's format.
+    fn to_ollama_request(&self, request: &CompletionRequest, stream: bool) -> OllamaRequest {
+        let mut messages = Vec::new();
+
+        if let Some(system) = &request.system_prompt {
+            messages.push(OllamaMessage {
+                role: "system".to_string(),
+                content: system.clone(),
+            });
+        }
+
+        for msg in &request.messages {
+            let role = match msg.role {
+                MessageRole::System => "system",
+                MessageRole::User => "user",
+                MessageRole::Assistant => "assistant",
+            };
+            messages.push(OllamaMessage {
+                role: role.to_string(),
+                content: msg.content.clone(),
+            });
+        }
+
+        OllamaRequest {
+            model: self.config.model_for(request.component_type).to_string(),
+            messages,
+            stream,
+            options: OllamaOptions {
+                num_predict: request.max_tokens,
+                temperature: request.temperature,
+                num_ctx: self.config.context_tokens,
+            },
+        }
+    }
+
+    /// Sends a request to the chat endpoint.
+    async fn send_request(
+        &self,
+        request: &CompletionRequest,
+        stream: bool,
+    ) -> Result<Response, AIError> {
+        let ollama_request = self.to_ollama_request(request, stream);
+
+        self.client
+            .post(self.chat_url())
+            .json(&ollama_request)
+            .send()
+            .await
+            .map_err(|e| {
+                if e.is_timeout() {
+                    AIError::Timeout {
+                        timeout_secs: self.config.timeout.as_secs() as u32,
+                    }
+                } else if e.is_connect() {
+                    AIError::unavailable(format!(
+                        "Cannot reach Ollama at {}; is `ollama serve` running? ({})",
+                        self.config.base_url, e
+                    ))
+                } else {
+                    AIError::network(e.to_string())
+                }
+            })
+    }
+
+    /// Parses the API response status and handles errors.
+    async fn handle_response_status(&self, response: Response) -> Result<Response, AIError> {
+        let status = response.status();
+
+        if status.is_success() {
+            return Ok(response);
+        }
+
+        let error_body = response.text().await.unwrap_or_default();
+        let message = serde_json::from_str::<OllamaError>(&error_body)
+            .map(|e| e.error)
+            .unwrap_or(error_body);
+
+        match status.as_u16() {
+            // Ollama answers 404 for models that have not been pulled
+            404 => Err(AIError::InvalidRequest(format!(
+                "{} (run `ollama pull` for the configured model)",
+                message
+            ))),
+            400 => Err(AIError::InvalidRequest(message)),
+            500..=599 => Err(AIError::unavailable(format!(
+                "Server error {}: {}",
+                status, message
+            ))),
+            _ => Err(AIError::network(format!(
+                "Unexpected status {}: {}",
+                status, message
+            ))),
+        }
+    }
+
+    /// Parses a non-streaming response.
+    async fn parse_response(&self, response: Response) -> Result<CompletionResponse, AIError> {
+        let response = self.handle_response_status(response).await?;
+
+        let ollama_response: OllamaResponse = response
+            .json()
+            .await
+            .map_err(|e| AIError::parse(format!("Failed to parse response: {}", e)))?;
+
+        Ok(CompletionResponse {
+            content: ollama_response
+                .message
+                .as_ref()
+                .map(|m| m.content.clone())
+                .unwrap_or_default(),
+            usage: ollama_response.token_usage(),
+            finish_reason: ollama_response.finish_reason(),
+            model: ollama_response.model,
+        })
+    }
+}
+
+#[async_trait]
+impl AIProvider for OllamaProvider {
+    #[tracing::instrument(
+        name = "ai.complete",
+        skip_all,
+        fields(
+            ai.provider = "ollama",
+            ai.model = %self.config.model_for(request.component_type),
+            ai.prompt_tokens = tracing::field::Empty,
+            ai.completion_tokens = tracing::field::Empty,
+        ),
+        err
+    )]
+    async fn complete(&self, request: CompletionRequest) -> Result<CompletionResponse, AIError> {
+        let mut last_error = AIError::network("No attempts made");
+        let mut retry_count = 0;
+
+        while retry_count <= self.config.max_retries {
+            let result = match self.send_request(&request, false).await {
+                Ok(response) => self.parse_response(response).await,
+                Err(err) => Err(err),
+            };
+
+            match result {
+                Ok(completion) => {
+                    let span = tracing::Span::current();
+                    span.record("ai.prompt_tokens", completion.usage.prompt_tokens);
+                    span.record("ai.completion_tokens", completion.usage.completion_tokens);
+                    return Ok(completion);
+                }
+                Err(err) => {
+                    if !err.is_retryable() || retry_count >= self.config.max_retries {
+                        return Err(err);
+                    }
+                    last_error = err;
+                }
+            }
+
+            // Exponential backoff: 1s, 2s, 4s, ...
+            let delay = Duration::from_secs(1 << retry_count);
+            sleep(delay).await;
+            retry_count += 1;
+        }
+
+        Err(last_error)
+    }
+
+    #[tracing::instrument(
+        name = "ai.stream_complete",
+        skip_all,
+        fields(ai.provider = "ollama", ai.model = %self.config.model_for(request.component_type)),
+        err
+    )]
+    async fn stream_complete(
+        &self,
+        request: CompletionRequest,
+    ) -> Result<Pin<Box<dyn Stream<Item = Result<StreamChunk, AIError>> + Send>>, AIError> {
+        let response = self.send_request(&request, true).await?;
+        let response = self.handle_response_status(response).await?;
+
+        // A JSON line may be split across network chunks, so keep the
+        // unterminated tail and prepend it to the next chunk.
+        let stream = response
+            .bytes_stream()
+            .scan(String::new(), |buffer, chunk_result| {
+                let results = match chunk_result {
+                    Ok(bytes) => {
+                        buffer.push_str(&String::from_utf8_lossy(&bytes));
+                        let complete_len = buffer.rfind('\n').map_or(0, |idx| idx + 1);
+                        let complete: String = buffer.drain(..complete_len).collect();
+                        parse_ollama_ndjson(&complete)
+                    }
+                    Err(e) => vec![Err(AIError::network(format!("Stream error: {}", e)))],
+                };
+                futures::future::ready(Some(results))
+            })
+            .flat_map(stream::iter);
+
+        Ok(Box::pin(stream))
+    }
+
+    fn estimate_tokens(&self, text: &str) -> u32 {
+        // Llama-family tokenizers average ~4 characters per token for English
+        (text.len() / 4).max(1) as u32
+    }
+
+    fn provider_info(&self) -> ProviderInfo {
+        let supports_tools = self.config.supports_tools.unwrap_or_else(|| {
+            model_supports_tools(&self.config.model)
+                && self
+                    .config
+                    .component_models
+                    .values()
+                    .all(|model| model_supports_tools(model))
+        });
+
+        ProviderInfo::new("ollama", &self.config.model, self.config.context_tokens)
+            .with_streaming(true)
+            .with_functions(supports_tools)
+    }
+}
+
+/// Parses Ollama's newline-delimited JSON stream into StreamChunks.
+///
+/// ```text
+/// {"model":"llama3.1:8b","message":{"role":"assistant","content":"Hel"},"done":false}
+/// {"model":"llama3.1:8b","message":{"role":"assistant","content":""},"done":true,"done_reason":"stop","prompt_eval_count":26,"eval_count":290}
+/// ```
+fn parse_ollama_ndjson(text: &str) -> Vec<Result<StreamChunk, AIError>> {
+    let mut results = Vec::new();
+
+    for line in text.lines().map(str::trim).filter(|l| !l.is_empty()) {
+        if let Ok(error) = serde_json::from_str::<OllamaError>(line) {
+            results.push(Err(AIError::unavailable(error.error)));
+            continue;
+        }
+
+        let Ok(response) = serde_json::from_str::<OllamaResponse>(line) else {
+            continue;
+        };
+
+        if let Some(content) = response.message.as_ref().map(|m| m.content.as_str()) {
+            if !content.is_empty() {
+                results.push(Ok(StreamChunk::content(content)));
+            }
+        }
+
+        if response.done {
+            results.push(Ok(StreamChunk::final_chunk(
+                response.finish_reason(),
+                response.token_usage(),
+            )));
+        }
+    }
+
+    results
+}
+
+// ----- Ollama API Types -----
+
+#[derive(Debug, Serialize)]
+struct OllamaRequest {
+    model: String,
+    messages: Vec<OllamaMessage>,
+    stream: bool,
+    options: OllamaOptions,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct OllamaMessage {
+    role: String,
+    #[serde(default)]
+    content: String,
+}
+
+#[derive(Debug, Serialize)]
+struct OllamaOptions {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    num_predict: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    temperature: Option<f32>,
+    num_ctx: u32,
+}
+
+#[derive(Debug, Deserialize)]
+struct OllamaResponse {
+    #[serde(default)]
+    model: String,
+    message: Option<OllamaMessage>,
+    #[serde(default)]
+    done: bool,
+    done_reason: Option<String>,
+    #[serde(default)]
+    prompt_eval_count: u32,
+    #[serde(default)]
+    eval_count: u32,
+}
+
+impl OllamaResponse {
+    fn finish_reason(&self) -> FinishReason {
+        match self.done_reason.as_deref() {
+            Some("length") => FinishReason::Length,
+            _ => FinishReason::Stop,
+        }
+    }
+
+    /// Local inference is free, so cost is always zero.
+    fn token_usage(&self) -> TokenUsage {
+        TokenUsage::new(self.prompt_eval_count, self.eval_count, 0)
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct OllamaError {
+    error: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::foundation::{ConversationId, SessionId, UserId};
+    use crate::ports::RequestMetadata;
+
+    fn make_request() -> CompletionRequest {
+        let metadata = RequestMetadata::new(
+            UserId::new("test-user").unwrap(),
+            SessionId::new(),
+            ConversationId::new(),
+            "trace-123",
+        );
+        CompletionRequest::new(metadata)
+            .with_system_prompt("Be concise")
+            .with_message(MessageRole::User, "Hello")
+    }
+
+    #[test]
+    fn config_builder_works() {
+        let config = OllamaConfig::new()
+            .with_model("qwen2.5:14b")
+            .with_base_url("http://gpu-box:11434")
+            .with_timeout(Duration::from_secs(30))
+            .with_max_retries(5)
+            .with_context_tokens(32_768);
+
+        assert_eq!(config.model, "qwen2.5:14b");
+        assert_eq!(config.base_url, "http://gpu-box:11434");
+        assert_eq!(config.timeout, Duration::from_secs(30));
+        assert_eq!(config.max_retries, 5);
+        assert_eq!(config.context_tokens, 32_768);
+    }
+
+    #[test]
+    fn component_models_override_default() {
+        let config = OllamaConfig::new()
+            .with_model("llama3.1:8b")
+            .with_component_model(ComponentType::Consequences, "qwen2.5:32b");
+
+        assert_eq!(config.model_for(None), "llama3.1:8b");
+        assert_eq!(
+            config.model_for(Some(ComponentType::Objectives)),
+            "llama3.1:8b"
+        );
+        assert_eq!(
+            config.model_for(Some(ComponentType::Consequences)),
+            "qwen2.5:32b"
+        );
+    }
+
+    #[test]
+    fn request_uses_component_model_and_system_message() {
+        let provider = OllamaProvider::new(
+            OllamaConfig::new().with_component_model(ComponentType::Objectives, "mistral-nemo"),
+        );
+        let request = make_request()
+            .with_component_type(ComponentType::Objectives)
+            .with_max_tokens(128);
+
+        let json = serde_json::to_value(provider.to_ollama_request(&request, true)).unwrap();
+
+        assert_eq!(json["model"], "mistral-nemo");
+        assert_eq!(json["stream"], true);
+        assert_eq!(json["messages"][0]["role"], "system");
+        assert_eq!(json["messages"][1]["content"], "Hello");
+        assert_eq!(json["options"]["num_predict"], 128);
+        assert_eq!(json["options"]["num_ctx"], 8192);
+    }
+
+    #[test]
+    fn chat_url_tolerates_trailing_slash() {
+        let provider =
+            OllamaProvider::new(OllamaConfig::new().with_base_url("http://localhost:11434/"));
+        assert_eq!(provider.chat_url(), "http://localhost:11434/api/chat");
+    }
+
+    #[test]
+    fn tool_support_follows_model_family() {
+        assert!(model_supports_tools("llama3.1:8b"));
+        assert!(model_supports_tools("library/qwen2.5:14b"));
+        assert!(!model_supports_tools("gemma2:9b"));
+        assert!(!model_supports_tools("llama2"));
+
+        let tools = OllamaProvider::new(OllamaConfig::new().with_model("llama3.1:8b"));
+        assert!(tools.provider_info().supports_functions);
+
+        let no_tools = OllamaProvider::new(OllamaConfig::new().with_model("gemma2:9b"));
+        assert!(!no_tools.provider_info().supports_functions);
+
+        let mixed = OllamaProvider::new(
+            OllamaConfig::new().with_component_model(ComponentType::Alternatives, "phi3"),
+        );
+        assert!(!mixed.provider_info().supports_functions);
+
+        let overridden = OllamaProvider::new(
+            OllamaConfig::new()
+                .with_model("my-finetune")
+                .with_tool_support(true),
+        );
+        assert!(overridden.provider_info().supports_functions);
+    }
+
+    #[test]
+    fn provider_info_reports_ollama() {
+        let provider = OllamaProvider::new(OllamaConfig::new());
+
+        let info = provider.provider_info();
+        assert_eq!(info.name, "ollama");
+        assert_eq!(info.model, "llama3.1:8b");
+        assert_eq!(info.max_context_tokens, 8192);
+        assert!(info.supports_streaming);
+    }
+
+    #[test]
+    fn parse_ndjson_content_and_final_chunk() {
+        let data = "{\"model\":\"llama3.1:8b\",\"message\":{\"role\":\"assistant\",\"content\":\"Hi\"},\"done\":false}\n\
+                    {\"model\":\"llama3.1:8b\",\"message\":{\"role\":\"assistant\",\"content\":\" there\"},\"done\":false}\n\
+                    {\"model\":\"llama3.1:8b\",\"message\":{\"role\":\"assistant\",\"content\":\"\"},\"done\":true,\"done_reason\":\"length\",\"prompt_eval_count\":26,\"eval_count\":290}\n";
+        let chunks = parse_ollama_ndjson(data);
+
+        assert_eq!(chunks.len(), 3);
+        assert_eq!(chunks[0].as_ref().unwrap().delta, "Hi");
+        assert_eq!(chunks[1].as_ref().unwrap().delta, " there");
+        let last = chunks[2].as_ref().unwrap();
+        assert!(last.is_final());
+        assert_eq!(last.finish_reason, Some(FinishReason::Length));
+        let usage = last.usage.as_ref().unwrap();
+        assert_eq!(usage.prompt_tokens, 26);
+        assert_eq!(usage.completion_tokens, 290);
+        assert_eq!(usage.estimated_cost_cents, 0);
+    }
+
+    #[test]
+    fn parse_ndjson_error_line() {
+        let chunks = parse_ollama_ndjson("{\"error\":\"model runner crashed\"}\n");
+
+        assert_eq!(chunks.len(), 1);
+        assert!(matches!(chunks[0], Err(AIError::Unavailable { .. })));
+    }
+}
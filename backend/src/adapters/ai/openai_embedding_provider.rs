@@ -0,0 +1,192 @@
+//! OpenAI Embedding Provider - Implementation of EmbeddingProvider for OpenAI.
+//!
+//! Calls `/embeddings` with the configured model, asking for
+//! [`EMBEDDING_DIMENSIONS`] dimensions so vectors fit the stored column.
+//! Reuses [`OpenAIConfig`]; set its model to an embedding model:
+//!
+//! ```ignore
+//! let config = OpenAIConfig::new(api_key).with_model(DEFAULT_OPENAI_EMBEDDING_MODEL);
+//! let provider = OpenAIEmbeddingProvider::new(config);
+//! ```
+
+use async_trait::async_trait;
+use reqwest::Client;
+use serde::{Deserialize, Serialize};
+
+use crate::domain::foundation::Embedding;
+use crate::ports::{truncate_embedding_input, AIError, EmbeddingProvider, EMBEDDING_DIMENSIONS};
+
+use super::openai_provider::{handle_response_status, parse_json};
+use super::OpenAIConfig;
+
+/// Embedding model used unless configured otherwise.
+pub const DEFAULT_OPENAI_EMBEDDING_MODEL: &str = "text-embedding-3-small";
+
+/// OpenAI embeddings API provider.
+pub struct OpenAIEmbeddingProvider {
+    config: OpenAIConfig,
+    client: Client,
+}
+
+impl OpenAIEmbeddingProvider {
+    /// Creates a new provider with the given configuration.
+    pub fn new(config: OpenAIConfig) -> Self {
+        let client = Client::builder()
+            .timeout(config.timeout)
+            .build()
+            .expect("Failed to create HTTP client");
+
+        Self { config, client }
+    }
+}
+
+#[derive(Debug, Serialize)]
+struct EmbeddingRequest<'a> {
+    model: &'a str,
+    input: Vec<&'a str>,
+    dimensions: usize,
+    encoding_format: &'static str,
+}
+
+#[derive(Debug, Deserialize)]
+struct EmbeddingResponse {
+    data: Vec<EmbeddingData>,
+}
+
+#[derive(Debug, Deserialize)]
+struct EmbeddingData {
+    index: usize,
+    embedding: Vec<f32>,
+}
+
+#[async_trait]
+impl EmbeddingProvider for OpenAIEmbeddingProvider {
+    #[tracing::instrument(
+        name = "ai.embed",
+        skip_all,
+        fields(ai.provider = "openai", ai.model = %self.config.model, ai.inputs = inputs.len()),
+        err
+    )]
+    async fn embed(&self, inputs: &[String]) -> Result<Vec<Embedding>, AIError> {
+        if inputs.is_empty() {
+            return Ok(vec![]);
+        }
+
+        let request = EmbeddingRequest {
+            model: &self.config.model,
+            input: inputs
+                .iter()
+                .map(|input| truncate_embedding_input(input))
+                .collect(),
+            dimensions: EMBEDDING_DIMENSIONS,
+            encoding_format: "float",
+        };
+        let response = self
+            .client
+            .post(format!("{}/embeddings", self.config.base_url))
+            .header("Authorization", format!("Bearer {}", self.config.api_key()))
+            .json(&request)
+            .send()
+            .await
+            .map_err(|e| {
+                if e.is_timeout() {
+                    AIError::Timeout {
+                        timeout_secs: self.config.timeout.as_secs() as u32,
+                    }
+                } else {
+                    AIError::network(e.to_string())
+                }
+            })?;
+        let response: EmbeddingResponse =
+            parse_json(handle_response_status(response).await?).await?;
+
+        to_embeddings(response, inputs.len())
+    }
+
+    fn model(&self) -> &str {
+        &self.config.model
+    }
+}
+
+/// Orders the returned vectors by input and checks one came back per input.
+fn to_embeddings(
+    mut response: EmbeddingResponse,
+    expected: usize,
+) -> Result<Vec<Embedding>, AIError> {
+    if response.data.len() != expected {
+        return Err(AIError::parse(format!(
+            "Expected {} embeddings, received {}",
+            expected,
+            response.data.len()
+        )));
+    }
+    response.data.sort_by_key(|data| data.index);
+    response
+        .data
+        .into_iter()
+        .map(|data| {
+            if data.embedding.len() != EMBEDDING_DIMENSIONS {
+                return Err(AIError::parse(format!(
+                    "Expected {} dimensions, received {}",
+                    EMBEDDING_DIMENSIONS,
+                    data.embedding.len()
+                )));
+            }
+            Embedding::new(data.embedding).map_err(|e| AIError::parse(e.to_string()))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn data(index: usize, first: f32) -> EmbeddingData {
+        let mut embedding = vec![0.0; EMBEDDING_DIMENSIONS];
+        embedding[0] = first;
+        EmbeddingData { index, embedding }
+    }
+
+    #[test]
+    fn embeddings_are_returned_in_input_order() {
+        let response = EmbeddingResponse {
+            data: vec![data(1, 2.0), data(0, 1.0)],
+        };
+
+        let embeddings = to_embeddings(response, 2).unwrap();
+
+        assert_eq!(embeddings[0].as_slice()[0], 1.0);
+        assert_eq!(embeddings[1].as_slice()[0], 2.0);
+    }
+
+    #[test]
+    fn missing_or_wrongly_sized_embeddings_are_errors() {
+        let short = EmbeddingResponse {
+            data: vec![data(0, 1.0)],
+        };
+        assert!(to_embeddings(short, 2).is_err());
+
+        let wrong_size = EmbeddingResponse {
+            data: vec![EmbeddingData {
+                index: 0,
+                embedding: vec![1.0; 3],
+            }],
+        };
+        assert!(to_embeddings(wrong_size, 1).is_err());
+    }
+
+    #[test]
+    fn request_asks_for_stored_dimensions() {
+        let request = EmbeddingRequest {
+            model: DEFAULT_OPENAI_EMBEDDING_MODEL,
+            input: vec!["Move to Lisbon?"],
+            dimensions: EMBEDDING_DIMENSIONS,
+            encoding_format: "float",
+        };
+
+        let json = serde_json::to_value(&request).unwrap();
+
+        assert_eq!(json["dimensions"], EMBEDDING_DIMENSIONS);
+        assert_eq!(json["input"][0], "Move to Lisbon?");
+    }
+}
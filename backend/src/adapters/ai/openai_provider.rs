@@ -16,6 +16,15 @@
 //!
 //! Uses Server-Sent Events (SSE) for streaming responses. Each chunk is parsed
 //! and yielded as a `StreamChunk` until the `[DONE]` marker is received.
+//! Function calls arrive as `delta.tool_calls` fragments keyed by index and
+//! are passed on as `ToolCallDelta`s.
+//!
+//! # Batches
+//!
+//! `submit_batch` uploads the requests as a JSONL file and starts a Batch API
+//! job against `/v1/chat/completions` with a 24 hour completion window.
+//! Results are read from the job's output and error files and priced at
+//! half the interactive rate.
 
 use async_trait::async_trait;
 use futures::stream::{self, Stream, StreamExt};
@@ -27,8 +36,9 @@ use std::time::Duration;
 use tokio::time::sleep;
 
 use crate::ports::{
-    AIError, AIProvider, CompletionRequest, CompletionResponse, FinishReason, ProviderInfo,
-    StreamChunk, TokenUsage,
+    validate_batch, AIError, AIProvider, BatchJob, BatchRequest, BatchResult, BatchStatus,
+    CompletionRequest, CompletionResponse, FinishReason, ProviderInfo, StreamChunk, TokenUsage,
+    ToolCallDelta,
 };
 
 /// Configuration for the OpenAI provider.
@@ -83,7 +93,7 @@ impl OpenAIConfig {
     }
 
     /// Exposes the API key (for making requests).
-    fn api_key(&self) -> &str {
+    pub(super) fn api_key(&self) -> &str {
         self.api_key.expose_secret()
     }
 }
@@ -110,50 +120,9 @@ impl OpenAIProvider {
         format!("{}/chat/completions", self.config.base_url)
     }
 
-    /// Converts our request to OpenAI's format.
-    fn to_openai_request(&self, request: &CompletionRequest, stream: bool) -> OpenAIRequest {
-        let mut messages = Vec::new();
-
-        // Add system prompt if present
-        if let Some(ref prompt) = request.system_prompt {
-            messages.push(OpenAIMessage {
-                role: "system".to_string(),
-                content: prompt.clone(),
-            });
-        }
-
-        // Add conversation messages
-        for msg in &request.messages {
-            messages.push(OpenAIMessage {
-                role: match msg.role {
-                    crate::ports::MessageRole::System => "system",
-                    crate::ports::MessageRole::User => "user",
-                    crate::ports::MessageRole::Assistant => "assistant",
-                }
-                .to_string(),
-                content: msg.content.clone(),
-            });
-        }
-
-        OpenAIRequest {
-            model: self.config.model.clone(),
-            messages,
-            max_tokens: request.max_tokens,
-            temperature: request.temperature,
-            stream: Some(stream),
-            stream_options: if stream {
-                Some(StreamOptions {
-                    include_usage: true,
-                })
-            } else {
-                None
-            },
-        }
-    }
-
     /// Sends a request and handles the response.
     async fn send_request(&self, request: &CompletionRequest) -> Result<Response, AIError> {
-        let openai_request = self.to_openai_request(request, false);
+        let openai_request = to_openai_request(&self.config.model, request, false);
 
         self.client
             .post(self.completions_url())
@@ -180,7 +149,7 @@ impl OpenAIProvider {
         &self,
         request: &CompletionRequest,
     ) -> Result<Response, AIError> {
-        let openai_request = self.to_openai_request(request, true);
+        let openai_request = to_openai_request(&self.config.model, request, true);
 
         self.client
             .post(self.completions_url())
@@ -202,44 +171,30 @@ impl OpenAIProvider {
             })
     }
 
-    /// Parses the API response status and handles errors.
-    async fn handle_response_status(&self, response: Response) -> Result<Response, AIError> {
-        let status = response.status();
-
-        if status.is_success() {
-            return Ok(response);
-        }
+    /// Sends a files or batches API call.
+    async fn send_batch_call(&self, request: reqwest::RequestBuilder) -> Result<Response, AIError> {
+        let response = request
+            .header("Authorization", format!("Bearer {}", self.config.api_key()))
+            .send()
+            .await
+            .map_err(|e| AIError::network(e.to_string()))?;
+        handle_response_status(response).await
+    }
 
-        // Try to parse error body
-        let error_body = response.text().await.unwrap_or_default();
+    /// Downloads the contents of an uploaded or generated file.
+    async fn file_content(&self, file_id: &str) -> Result<String, AIError> {
+        let url = format!("{}/files/{}/content", self.config.base_url, file_id);
+        self.send_batch_call(self.client.get(url))
+            .await?
+            .text()
+            .await
+            .map_err(|e| AIError::network(e.to_string()))
+    }
 
-        match status.as_u16() {
-            401 => Err(AIError::AuthenticationFailed),
-            429 => {
-                // Try to extract retry-after from error
-                let retry_after = Self::parse_retry_after(&error_body);
-                Err(AIError::rate_limited(retry_after))
-            }
-            400 => {
-                // Check for context length error
-                if error_body.contains("maximum context length")
-                    || error_body.contains("context_length_exceeded")
-                {
-                    // Try to parse the numbers from the error
-                    Err(AIError::context_too_long(0, 0)) // Simplified - real impl would parse
-                } else {
-                    Err(AIError::InvalidRequest(error_body))
-                }
-            }
-            500..=599 => Err(AIError::unavailable(format!(
-                "Server error {}: {}",
-                status, error_body
-            ))),
-            _ => Err(AIError::network(format!(
-                "Unexpected status {}: {}",
-                status, error_body
-            ))),
-        }
+    /// Loads a batch object.
+    async fn get_batch(&self, batch_id: &str) -> Result<OpenAIBatch, AIError> {
+        let url = format!("{}/batches/{}", self.config.base_url, batch_id);
+        parse_json(self.send_batch_call(self.client.get(url)).await?).await
     }
 
     /// Parses retry-after from error response.
@@ -263,67 +218,21 @@ impl OpenAIProvider {
         }
         30 // Default retry after
     }
-
-    /// Parses a non-streaming response.
-    async fn parse_response(&self, response: Response) -> Result<CompletionResponse, AIError> {
-        let response = self.handle_response_status(response).await?;
-
-        let openai_response: OpenAIResponse = response
-            .json()
-            .await
-            .map_err(|e| AIError::parse(format!("Failed to parse response: {}", e)))?;
-
-        let choice = openai_response
-            .choices
-            .into_iter()
-            .next()
-            .ok_or_else(|| AIError::parse("No choices in response"))?;
-
-        let finish_reason = match choice.finish_reason.as_deref() {
-            Some("stop") => FinishReason::Stop,
-            Some("length") => FinishReason::Length,
-            Some("content_filter") => FinishReason::ContentFilter,
-            _ => FinishReason::Stop,
-        };
-
-        let usage = openai_response.usage.map(|u| {
-            TokenUsage::new(
-                u.prompt_tokens,
-                u.completion_tokens,
-                self.calculate_cost(u.prompt_tokens, u.completion_tokens),
-            )
-        }).unwrap_or_default();
-
-        Ok(CompletionResponse {
-            content: choice.message.content,
-            usage,
-            model: openai_response.model,
-            finish_reason,
-        })
-    }
-
-    /// Calculates estimated cost in cents based on model and token counts.
-    fn calculate_cost(&self, prompt_tokens: u32, completion_tokens: u32) -> u32 {
-        // Prices per 1M tokens as of 2024 (in cents)
-        let (prompt_price, completion_price) = match self.config.model.as_str() {
-            m if m.starts_with("gpt-4-turbo") || m.starts_with("gpt-4-0125") => (1000, 3000), // $10/$30 per 1M
-            m if m.starts_with("gpt-4-1106") => (1000, 3000),
-            m if m.starts_with("gpt-4o") => (250, 1000), // $2.50/$10 per 1M
-            m if m.starts_with("gpt-4") => (3000, 6000), // $30/$60 per 1M (base GPT-4)
-            m if m.starts_with("gpt-3.5") => (50, 150),  // $0.50/$1.50 per 1M
-            _ => (1000, 3000), // Default to GPT-4 turbo pricing
-        };
-
-        // Calculate cost in cents (divide by 1M for per-token rate)
-        let prompt_cost = (prompt_tokens as u64 * prompt_price) / 1_000_000;
-        let completion_cost = (completion_tokens as u64 * completion_price) / 1_000_000;
-
-        (prompt_cost + completion_cost) as u32
-    }
 }
 
 #[async_trait]
 impl AIProvider for OpenAIProvider {
+    #[tracing::instrument(
+        name = "ai.complete",
+        skip_all,
+        fields(
+            ai.provider = "openai",
+            ai.model = %self.config.model,
+            ai.prompt_tokens = tracing::field::Empty,
+            ai.completion_tokens = tracing::field::Empty,
+        ),
+        err
+    )]
     async fn complete(&self, request: CompletionRequest) -> Result<CompletionResponse, AIError> {
         let mut last_error = AIError::network("No attempts made");
         let mut retry_count = 0;
@@ -331,8 +240,13 @@ impl AIProvider for OpenAIProvider {
         while retry_count <= self.config.max_retries {
             match self.send_request(&request).await {
                 Ok(response) => {
-                    match self.parse_response(response).await {
-                        Ok(completion) => return Ok(completion),
+                    match parse_response(response, &self.config.model).await {
+                        Ok(completion) => {
+                            let span = tracing::Span::current();
+                            span.record("ai.prompt_tokens", completion.usage.prompt_tokens);
+                            span.record("ai.completion_tokens", completion.usage.completion_tokens);
+                            return Ok(completion);
+                        }
                         Err(err) => {
                             if !err.is_retryable() || retry_count >= self.config.max_retries {
                                 return Err(err);
@@ -358,47 +272,20 @@ impl AIProvider for OpenAIProvider {
         Err(last_error)
     }
 
+    #[tracing::instrument(
+        name = "ai.stream_complete",
+        skip_all,
+        fields(ai.provider = "openai", ai.model = %self.config.model),
+        err
+    )]
     async fn stream_complete(
         &self,
         request: CompletionRequest,
     ) -> Result<Pin<Box<dyn Stream<Item = Result<StreamChunk, AIError>> + Send>>, AIError> {
         let response = self.send_streaming_request(&request).await?;
-        let response = self.handle_response_status(response).await?;
-
-        // Get the byte stream and parse SSE
-        let bytes_stream = response.bytes_stream();
-        let model = self.config.model.clone();
-        let prompt_price_factor = match model.as_str() {
-            m if m.starts_with("gpt-4o") => 250,
-            m if m.starts_with("gpt-4") => 1000,
-            m if m.starts_with("gpt-3.5") => 50,
-            _ => 1000,
-        };
-        let completion_price_factor = match model.as_str() {
-            m if m.starts_with("gpt-4o") => 1000,
-            m if m.starts_with("gpt-4") => 3000,
-            m if m.starts_with("gpt-3.5") => 150,
-            _ => 3000,
-        };
-
-        // Parse SSE stream
-        let stream = bytes_stream
-            .map(move |chunk_result| {
-                chunk_result
-                    .map_err(|e| AIError::network(format!("Stream error: {}", e)))
-            })
-            .map(move |chunk_result| {
-                match chunk_result {
-                    Ok(bytes) => {
-                        let text = String::from_utf8_lossy(&bytes);
-                        parse_sse_chunks(&text, prompt_price_factor, completion_price_factor)
-                    }
-                    Err(e) => vec![Err(e)],
-                }
-            })
-            .flat_map(stream::iter);
+        let response = handle_response_status(response).await?;
 
-        Ok(Box::pin(stream))
+        Ok(sse_stream(response, &self.config.model))
     }
 
     fn estimate_tokens(&self, text: &str) -> u32 {
@@ -422,7 +309,339 @@ impl AIProvider for OpenAIProvider {
         ProviderInfo::new("openai", &self.config.model, max_context)
             .with_streaming(true)
             .with_functions(true)
+            .with_batch(true)
+    }
+
+    #[tracing::instrument(
+        name = "ai.submit_batch",
+        skip_all,
+        fields(ai.provider = "openai", ai.model = %self.config.model, ai.batch_size = requests.len()),
+        err
+    )]
+    async fn submit_batch(&self, requests: Vec<BatchRequest>) -> Result<BatchJob, AIError> {
+        validate_batch(&requests)?;
+
+        let input = batch_input_jsonl(&self.config.model, &requests)?;
+        let form = reqwest::multipart::Form::new()
+            .text("purpose", "batch")
+            .part(
+                "file",
+                reqwest::multipart::Part::bytes(input.into_bytes()).file_name("batch.jsonl"),
+            );
+        let file: OpenAIFile = parse_json(
+            self.send_batch_call(
+                self.client
+                    .post(format!("{}/files", self.config.base_url))
+                    .multipart(form),
+            )
+            .await?,
+        )
+        .await?;
+
+        let batch: OpenAIBatch = parse_json(
+            self.send_batch_call(
+                self.client
+                    .post(format!("{}/batches", self.config.base_url))
+                    .json(&serde_json::json!({
+                        "input_file_id": file.id,
+                        "endpoint": BATCH_ENDPOINT,
+                        "completion_window": "24h",
+                    })),
+            )
+            .await?,
+        )
+        .await?;
+        Ok(batch.into_job())
+    }
+
+    async fn batch_status(&self, batch_id: &str) -> Result<BatchJob, AIError> {
+        Ok(self.get_batch(batch_id).await?.into_job())
+    }
+
+    async fn batch_results(&self, batch_id: &str) -> Result<Vec<BatchResult>, AIError> {
+        let batch = self.get_batch(batch_id).await?;
+
+        let mut results = Vec::new();
+        // Successful requests land in the output file, failed ones in the error file
+        for file_id in [batch.output_file_id, batch.error_file_id]
+            .into_iter()
+            .flatten()
+        {
+            let body = self.file_content(&file_id).await?;
+            results.extend(parse_batch_output(&self.config.model, &body)?);
+        }
+        Ok(results)
+    }
+}
+
+/// Chat completions endpoint batched requests are run against.
+const BATCH_ENDPOINT: &str = "/v1/chat/completions";
+
+/// Encodes batch requests as a Batch API input file.
+fn batch_input_jsonl(model: &str, requests: &[BatchRequest]) -> Result<String, AIError> {
+    let mut input = String::new();
+    for item in requests {
+        let line = serde_json::json!({
+            "custom_id": item.custom_id,
+            "method": "POST",
+            "url": BATCH_ENDPOINT,
+            "body": to_openai_request(model, &item.request, false),
+        });
+        input.push_str(
+            &serde_json::to_string(&line)
+                .map_err(|e| AIError::InvalidRequest(format!("Unencodable request: {}", e)))?,
+        );
+        input.push('\n');
     }
+    Ok(input)
+}
+
+/// Parses a Batch API output or error file, pricing usage as `pricing_model`.
+fn parse_batch_output(pricing_model: &str, body: &str) -> Result<Vec<BatchResult>, AIError> {
+    body.lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| {
+            let entry: BatchOutputLine = serde_json::from_str(line)
+                .map_err(|e| AIError::parse(format!("Invalid batch result: {}", e)))?;
+            let result = match (entry.response, entry.error) {
+                (Some(response), None) if response.status_code == 200 => {
+                    serde_json::from_value::<OpenAIResponse>(response.body)
+                        .map_err(|e| AIError::parse(format!("Invalid batch response: {}", e)))
+                        .and_then(|r| to_completion(r, pricing_model))
+                        .map(|mut completion| {
+                            completion.usage = completion.usage.at_batch_price();
+                            completion
+                        })
+                }
+                (Some(response), None) => Err(AIError::InvalidRequest(format!(
+                    "Status {}: {}",
+                    response.status_code, response.body
+                ))),
+                (_, error) => Err(AIError::InvalidRequest(
+                    error.map(|e| e.to_string()).unwrap_or_default(),
+                )),
+            };
+            Ok(BatchResult {
+                custom_id: entry.custom_id,
+                result,
+            })
+        })
+        .collect()
+}
+
+/// Parses a JSON response body.
+pub(super) async fn parse_json<T: serde::de::DeserializeOwned>(response: Response) -> Result<T, AIError> {
+    response
+        .json()
+        .await
+        .map_err(|e| AIError::parse(format!("Failed to parse response: {}", e)))
+}
+
+/// Converts our request to OpenAI's chat completions format.
+///
+/// Shared with the Azure OpenAI provider, which speaks the same protocol.
+pub(super) fn to_openai_request(
+    model: &str,
+    request: &CompletionRequest,
+    stream: bool,
+) -> OpenAIRequest {
+    let mut messages = Vec::new();
+
+    // Add system prompt if present
+    if let Some(ref prompt) = request.system_prompt {
+        messages.push(OpenAIMessage {
+            role: "system".to_string(),
+            content: prompt.clone(),
+        });
+    }
+
+    // Add conversation messages
+    for msg in &request.messages {
+        messages.push(OpenAIMessage {
+            role: match msg.role {
+                crate::ports::MessageRole::System => "system",
+                crate::ports::MessageRole::User => "user",
+                crate::ports::MessageRole::Assistant => "assistant",
+            }
+            .to_string(),
+            content: msg.content.clone(),
+        });
+    }
+
+    OpenAIRequest {
+        model: model.to_string(),
+        messages,
+        max_tokens: request.max_tokens,
+        temperature: request.temperature,
+        stream: Some(stream),
+        stream_options: if stream {
+            Some(StreamOptions {
+                include_usage: true,
+            })
+        } else {
+            None
+        },
+        tools: request
+            .tools
+            .iter()
+            .map(|tool| tool.to_openai_format())
+            .collect(),
+    }
+}
+
+/// Parses the API response status and handles errors.
+pub(super) async fn handle_response_status(response: Response) -> Result<Response, AIError> {
+    let status = response.status();
+
+    if status.is_success() {
+        return Ok(response);
+    }
+
+    // Try to parse error body
+    let error_body = response.text().await.unwrap_or_default();
+
+    match status.as_u16() {
+        401 => Err(AIError::AuthenticationFailed),
+        429 => {
+            // Try to extract retry-after from error
+            let retry_after = OpenAIProvider::parse_retry_after(&error_body);
+            Err(AIError::rate_limited(retry_after))
+        }
+        400 => {
+            // Check for context length error
+            if error_body.contains("maximum context length")
+                || error_body.contains("context_length_exceeded")
+            {
+                // Try to parse the numbers from the error
+                Err(AIError::context_too_long(0, 0)) // Simplified - real impl would parse
+            } else if error_body.contains("\"content_filter\"") {
+                // Azure rejects prompts that trip its content filters
+                Err(AIError::content_filtered(error_body))
+            } else {
+                Err(AIError::InvalidRequest(error_body))
+            }
+        }
+        500..=599 => Err(AIError::unavailable(format!(
+            "Server error {}: {}",
+            status, error_body
+        ))),
+        _ => Err(AIError::network(format!(
+            "Unexpected status {}: {}",
+            status, error_body
+        ))),
+    }
+}
+
+/// Parses a non-streaming response, pricing it as `pricing_model`.
+pub(super) async fn parse_response(
+    response: Response,
+    pricing_model: &str,
+) -> Result<CompletionResponse, AIError> {
+    let response = handle_response_status(response).await?;
+
+    let openai_response: OpenAIResponse = response
+        .json()
+        .await
+        .map_err(|e| AIError::parse(format!("Failed to parse response: {}", e)))?;
+
+    to_completion(openai_response, pricing_model)
+}
+
+/// Converts a chat completions response to our format.
+fn to_completion(
+    openai_response: OpenAIResponse,
+    pricing_model: &str,
+) -> Result<CompletionResponse, AIError> {
+    let choice = openai_response
+        .choices
+        .into_iter()
+        .next()
+        .ok_or_else(|| AIError::parse("No choices in response"))?;
+
+    let finish_reason = match choice.finish_reason.as_deref() {
+        Some("stop") => FinishReason::Stop,
+        Some("length") => FinishReason::Length,
+        Some("content_filter") => FinishReason::ContentFilter,
+        Some("tool_calls") => FinishReason::ToolUse,
+        _ => FinishReason::Stop,
+    };
+
+    let usage = openai_response
+        .usage
+        .map(|u| {
+            TokenUsage::new(
+                u.prompt_tokens,
+                u.completion_tokens,
+                calculate_cost(pricing_model, u.prompt_tokens, u.completion_tokens),
+            )
+        })
+        .unwrap_or_default();
+
+    Ok(CompletionResponse {
+        content: choice.message.content.unwrap_or_default(),
+        usage,
+        model: openai_response.model,
+        finish_reason,
+    })
+}
+
+/// Calculates estimated cost in cents based on model and token counts.
+pub(super) fn calculate_cost(model: &str, prompt_tokens: u32, completion_tokens: u32) -> u32 {
+    // Prices per 1M tokens as of 2024 (in cents)
+    let (prompt_price, completion_price) = match model {
+        m if m.starts_with("gpt-4-turbo") || m.starts_with("gpt-4-0125") => (1000, 3000), // $10/$30 per 1M
+        m if m.starts_with("gpt-4-1106") => (1000, 3000),
+        m if m.starts_with("gpt-4o") => (250, 1000), // $2.50/$10 per 1M
+        m if m.starts_with("gpt-4") => (3000, 6000), // $30/$60 per 1M (base GPT-4)
+        m if m.starts_with("gpt-3.5") => (50, 150),  // $0.50/$1.50 per 1M
+        _ => (1000, 3000), // Default to GPT-4 turbo pricing
+    };
+
+    // Calculate cost in cents (divide by 1M for per-token rate)
+    let prompt_cost = (prompt_tokens as u64 * prompt_price) / 1_000_000;
+    let completion_cost = (completion_tokens as u64 * completion_price) / 1_000_000;
+
+    (prompt_cost + completion_cost) as u32
+}
+
+/// Turns a streaming response body into StreamChunks, pricing usage as `model`.
+pub(super) fn sse_stream(
+    response: Response,
+    model: &str,
+) -> Pin<Box<dyn Stream<Item = Result<StreamChunk, AIError>> + Send>> {
+    // Get the byte stream and parse SSE
+    let bytes_stream = response.bytes_stream();
+    let prompt_price_factor = match model {
+        m if m.starts_with("gpt-4o") => 250,
+        m if m.starts_with("gpt-4") => 1000,
+        m if m.starts_with("gpt-3.5") => 50,
+        _ => 1000,
+    };
+    let completion_price_factor = match model {
+        m if m.starts_with("gpt-4o") => 1000,
+        m if m.starts_with("gpt-4") => 3000,
+        m if m.starts_with("gpt-3.5") => 150,
+        _ => 3000,
+    };
+
+    // Parse SSE stream
+    let stream = bytes_stream
+        .map(move |chunk_result| {
+            chunk_result
+                .map_err(|e| AIError::network(format!("Stream error: {}", e)))
+        })
+        .map(move |chunk_result| {
+            match chunk_result {
+                Ok(bytes) => {
+                    let text = String::from_utf8_lossy(&bytes);
+                    parse_sse_chunks(&text, prompt_price_factor, completion_price_factor)
+                }
+                Err(e) => vec![Err(e)],
+            }
+        })
+        .flat_map(stream::iter);
+
+    Box::pin(stream)
 }
 
 /// Parses SSE data chunks into StreamChunks.
@@ -451,12 +670,27 @@ fn parse_sse_chunks(
                             }
                         }
 
+                        // Tool call deltas
+                        for call in choice.delta.tool_calls.iter().flatten() {
+                            let function = call.function.as_ref();
+                            let delta = ToolCallDelta {
+                                index: call.index,
+                                id: call.id.clone(),
+                                name: function.and_then(|f| f.name.clone()),
+                                arguments_delta: function
+                                    .and_then(|f| f.arguments.clone())
+                                    .unwrap_or_default(),
+                            };
+                            results.push(Ok(StreamChunk::tool_call(delta)));
+                        }
+
                         // Check for finish reason
                         if let Some(ref reason) = choice.finish_reason {
                             let finish = match reason.as_str() {
                                 "stop" => FinishReason::Stop,
                                 "length" => FinishReason::Length,
                                 "content_filter" => FinishReason::ContentFilter,
+                                "tool_calls" => FinishReason::ToolUse,
                                 _ => FinishReason::Stop,
                             };
 
@@ -497,7 +731,7 @@ fn parse_sse_chunks(
 // ----- OpenAI API Types -----
 
 #[derive(Debug, Serialize)]
-struct OpenAIRequest {
+pub(super) struct OpenAIRequest {
     model: String,
     messages: Vec<OpenAIMessage>,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -508,19 +742,76 @@ struct OpenAIRequest {
     stream: Option<bool>,
     #[serde(skip_serializing_if = "Option::is_none")]
     stream_options: Option<StreamOptions>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    tools: Vec<serde_json::Value>,
 }
 
 #[derive(Debug, Serialize)]
-struct StreamOptions {
+pub(super) struct StreamOptions {
     include_usage: bool,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
-struct OpenAIMessage {
+pub(super) struct OpenAIMessage {
     role: String,
     content: String,
 }
 
+#[derive(Debug, Deserialize)]
+struct OpenAIFile {
+    id: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct OpenAIBatch {
+    id: String,
+    status: String,
+    #[serde(default)]
+    request_counts: OpenAIBatchCounts,
+    output_file_id: Option<String>,
+    error_file_id: Option<String>,
+}
+
+impl OpenAIBatch {
+    fn into_job(self) -> BatchJob {
+        BatchJob {
+            id: self.id,
+            status: match self.status.as_str() {
+                "completed" => BatchStatus::Ended,
+                "failed" => BatchStatus::Failed,
+                "expired" => BatchStatus::Expired,
+                "cancelled" => BatchStatus::Cancelled,
+                // validating, in_progress, finalizing, cancelling
+                _ => BatchStatus::InProgress,
+            },
+            request_count: self.request_counts.total,
+            succeeded_count: self.request_counts.completed,
+            failed_count: self.request_counts.failed,
+        }
+    }
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+struct OpenAIBatchCounts {
+    total: u32,
+    completed: u32,
+    failed: u32,
+}
+
+#[derive(Debug, Deserialize)]
+struct BatchOutputLine {
+    custom_id: String,
+    response: Option<BatchOutputResponse>,
+    error: Option<serde_json::Value>,
+}
+
+#[derive(Debug, Deserialize)]
+struct BatchOutputResponse {
+    status_code: u16,
+    body: serde_json::Value,
+}
+
 #[derive(Debug, Deserialize)]
 struct OpenAIResponse {
     model: String,
@@ -530,10 +821,16 @@ struct OpenAIResponse {
 
 #[derive(Debug, Deserialize)]
 struct OpenAIChoice {
-    message: OpenAIMessage,
+    message: OpenAIResponseMessage,
     finish_reason: Option<String>,
 }
 
+/// Response message; `content` is null when the model only calls tools.
+#[derive(Debug, Deserialize)]
+struct OpenAIResponseMessage {
+    content: Option<String>,
+}
+
 #[derive(Debug, Deserialize)]
 struct OpenAIUsage {
     prompt_tokens: u32,
@@ -555,6 +852,20 @@ struct StreamChoice {
 #[derive(Debug, Deserialize)]
 struct StreamDelta {
     content: Option<String>,
+    tool_calls: Option<Vec<StreamToolCall>>,
+}
+
+#[derive(Debug, Deserialize)]
+struct StreamToolCall {
+    index: u32,
+    id: Option<String>,
+    function: Option<StreamFunction>,
+}
+
+#[derive(Debug, Deserialize)]
+struct StreamFunction {
+    name: Option<String>,
+    arguments: Option<String>,
 }
 
 #[cfg(test)]
@@ -584,7 +895,7 @@ mod tests {
         // 1M prompt tokens = $10 = 1000 cents
         // 1M completion tokens = $30 = 3000 cents
         // 1000 prompt + 500 completion = 1 cent + 1.5 cents = 2 cents (rounded)
-        let cost = provider.calculate_cost(1000, 500);
+        let cost = calculate_cost(&provider.config.model, 1000, 500);
         // With integer math: (1000 * 1000) / 1_000_000 = 1 cent for prompt
         // (500 * 3000) / 1_000_000 = 1 cent for completion
         assert_eq!(cost, 2);
@@ -598,7 +909,7 @@ mod tests {
         // 1M prompt tokens = $0.50 = 50 cents
         // 1M completion tokens = $1.50 = 150 cents
         // 100,000 tokens = 5 cents prompt, 15 cents completion = 20 cents
-        let cost = provider.calculate_cost(100_000, 100_000);
+        let cost = calculate_cost(&provider.config.model, 100_000, 100_000);
         assert_eq!(cost, 20);
     }
 
@@ -665,6 +976,32 @@ mod tests {
         assert!(chunks.is_empty());
     }
 
+    #[test]
+    fn parse_sse_tool_call_deltas() {
+        let data = concat!(
+            r#"data: {"choices":[{"delta":{"tool_calls":[{"index":0,"id":"call_1","type":"function","function":{"name":"add_objective","arguments":""}}]},"finish_reason":null}]}"#,
+            "\n\n",
+            r#"data: {"choices":[{"delta":{"tool_calls":[{"index":0,"function":{"arguments":"{\"name\":\"Cost\"}"}}]},"finish_reason":null}]}"#,
+            "\n\n",
+            r#"data: {"choices":[{"delta":{},"finish_reason":"tool_calls"}],"usage":{"prompt_tokens":10,"completion_tokens":5}}"#,
+        );
+        let chunks = parse_sse_chunks(data, 1000, 3000);
+
+        assert_eq!(chunks.len(), 3);
+        assert_eq!(
+            chunks[0].as_ref().unwrap().tool_call,
+            Some(ToolCallDelta::start(0, "call_1", "add_objective"))
+        );
+        assert_eq!(
+            chunks[1].as_ref().unwrap().tool_call,
+            Some(ToolCallDelta::arguments(0, r#"{"name":"Cost"}"#))
+        );
+        assert_eq!(
+            chunks[2].as_ref().unwrap().finish_reason,
+            Some(FinishReason::ToolUse)
+        );
+    }
+
     #[test]
     fn parse_retry_after_from_message() {
         let error = r#"{"error":{"message":"Rate limit exceeded. Please try again in 30 seconds."}}"#;
@@ -672,6 +1009,84 @@ mod tests {
         assert_eq!(retry, 30);
     }
 
+    #[test]
+    fn batch_input_is_one_request_per_line() {
+        use crate::domain::foundation::{ConversationId, SessionId, UserId};
+        use crate::ports::{MessageRole, RequestMetadata};
+
+        let request = CompletionRequest::new(RequestMetadata::new(
+            UserId::new("test-user").unwrap(),
+            SessionId::new(),
+            ConversationId::new(),
+            "trace-123",
+        ))
+        .with_message(MessageRole::User, "Summarize");
+        let requests = vec![
+            BatchRequest::new("dq-1", request.clone()),
+            BatchRequest::new("dq-2", request),
+        ];
+
+        let input = batch_input_jsonl("gpt-4o", &requests).unwrap();
+        let lines: Vec<serde_json::Value> = input
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect();
+        assert_eq!(lines.len(), 2);
+        assert_eq!(lines[0]["custom_id"], "dq-1");
+        assert_eq!(lines[0]["url"], "/v1/chat/completions");
+        assert_eq!(lines[0]["body"]["model"], "gpt-4o");
+        assert_eq!(lines[0]["body"]["stream"], false);
+    }
+
+    #[test]
+    fn batch_status_maps_to_job() {
+        let batch: OpenAIBatch = serde_json::from_str(
+            r#"{"id":"batch_abc","status":"finalizing","request_counts":{"total":4,"completed":3,"failed":1},
+                "output_file_id":null,"error_file_id":null}"#,
+        )
+        .unwrap();
+
+        let job = batch.into_job();
+        assert_eq!(job.id, "batch_abc");
+        assert_eq!(job.status, BatchStatus::InProgress);
+        assert_eq!(job.request_count, 4);
+        assert_eq!(job.succeeded_count, 3);
+        assert_eq!(job.failed_count, 1);
+
+        for (status, expected) in [
+            ("completed", BatchStatus::Ended),
+            ("failed", BatchStatus::Failed),
+            ("expired", BatchStatus::Expired),
+            ("cancelled", BatchStatus::Cancelled),
+        ] {
+            let batch: OpenAIBatch =
+                serde_json::from_value(serde_json::json!({"id": "b", "status": status})).unwrap();
+            assert_eq!(batch.into_job().status, expected);
+        }
+    }
+
+    #[test]
+    fn batch_output_is_priced_at_half_rate() {
+        let body = concat!(
+            r#"{"id":"r1","custom_id":"dq-1","response":{"status_code":200,"body":{"model":"gpt-4o","choices":[{"message":{"content":"Narrative"},"finish_reason":"stop"}],"usage":{"prompt_tokens":1000000,"completion_tokens":0}}},"error":null}"#,
+            "\n",
+            r#"{"id":"r2","custom_id":"dq-2","response":{"status_code":400,"body":{"error":{"message":"bad"}}},"error":null}"#,
+            "\n",
+            r#"{"id":"r3","custom_id":"dq-3","response":null,"error":{"code":"batch_expired","message":"expired"}}"#,
+        );
+
+        let results = parse_batch_output("gpt-4o", body).unwrap();
+        assert_eq!(results.len(), 3);
+
+        let completion = results[0].result.as_ref().unwrap();
+        assert_eq!(completion.content, "Narrative");
+        // $2.50 per 1M prompt tokens at full price
+        assert_eq!(completion.usage.estimated_cost_cents, 125);
+        assert_eq!(results[1].custom_id, "dq-2");
+        assert!(results[1].result.is_err());
+        assert!(results[2].result.is_err());
+    }
+
     #[test]
     fn parse_retry_after_default() {
         let error = r#"{"error":{"message":"Something went wrong"}}"#;
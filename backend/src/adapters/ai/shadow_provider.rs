@@ -0,0 +1,397 @@
+//! Shadow AI Provider - Replays sampled production traffic to a candidate.
+//!
+//! Wraps the production provider. Users always get the primary's response;
+//! for a sample of requests the same `CompletionRequest` is then sent to a
+//! candidate model and/or prompt in a background task, and both outputs are
+//! recorded in a `ShadowComparisonRepository` for side-by-side evaluation.
+//!
+//! # Example
+//!
+//! ```ignore
+//! let variant = ShadowVariant::new("objectives-v2", 0.05)
+//!     .with_system_prompt(new_objectives_prompt);
+//!
+//! let provider = ShadowAIProvider::new(primary, candidate, variant, repository);
+//! ```
+//!
+//! Sampling is keyed on the request's trace ID, so a retried request is
+//! shadowed (or not) consistently. Candidate failures are recorded, never
+//! surfaced.
+
+use async_trait::async_trait;
+use futures::{Stream, StreamExt};
+use std::pin::Pin;
+use std::sync::Arc;
+use std::time::Instant;
+use uuid::Uuid;
+
+use crate::domain::conversation::DataExtractor;
+use crate::domain::foundation::{ComponentType, Timestamp};
+use crate::ports::{
+    AIError, AIProvider, BatchJob, BatchRequest, BatchResult, CompletionRequest,
+    CompletionResponse, ProviderInfo, ShadowComparison, ShadowComparisonRepository, ShadowOutput,
+    StreamChunk, TokenUsage,
+};
+
+/// The candidate configuration being evaluated.
+#[derive(Debug, Clone)]
+pub struct ShadowVariant {
+    /// Name comparisons are recorded under.
+    pub name: String,
+    /// Replaces the request's system prompt for the candidate.
+    pub system_prompt: Option<String>,
+    /// Share of requests to shadow, 0.0-1.0.
+    pub sample_rate: f64,
+}
+
+impl ShadowVariant {
+    /// Creates a variant that only swaps the model.
+    pub fn new(name: impl Into<String>, sample_rate: f64) -> Self {
+        Self {
+            name: name.into(),
+            system_prompt: None,
+            sample_rate: sample_rate.clamp(0.0, 1.0),
+        }
+    }
+
+    /// Sends the candidate a different system prompt.
+    pub fn with_system_prompt(mut self, prompt: impl Into<String>) -> Self {
+        self.system_prompt = Some(prompt.into());
+        self
+    }
+
+    /// Whether the request with this trace ID falls in the sample.
+    pub fn samples(&self, trace_id: &str) -> bool {
+        if self.sample_rate <= 0.0 {
+            return false;
+        }
+        // FNV-1a with a murmur3 finalizer, so the decision is stable across
+        // processes and releases and similar trace IDs spread evenly
+        let mut hash = self
+            .name
+            .bytes()
+            .chain([0])
+            .chain(trace_id.bytes())
+            .fold(0xcbf2_9ce4_8422_2325u64, |hash, byte| {
+                (hash ^ byte as u64).wrapping_mul(0x0100_0000_01b3)
+            });
+        hash ^= hash >> 33;
+        hash = hash.wrapping_mul(0xff51_afd7_ed55_8ccd);
+        hash ^= hash >> 33;
+        hash = hash.wrapping_mul(0xc4ce_b9fe_1a85_ec53);
+        hash ^= hash >> 33;
+        (hash as f64 / u64::MAX as f64) < self.sample_rate
+    }
+}
+
+/// AI provider wrapper that shadows sampled requests to a candidate.
+pub struct ShadowAIProvider<P: AIProvider> {
+    primary: P,
+    shadow: Arc<Shadow>,
+}
+
+struct Shadow {
+    candidate: Arc<dyn AIProvider>,
+    variant: ShadowVariant,
+    repository: Arc<dyn ShadowComparisonRepository>,
+    extractor: DataExtractor,
+}
+
+impl<P: AIProvider> ShadowAIProvider<P> {
+    /// Wraps `primary`, shadowing sampled requests to `candidate`.
+    pub fn new(
+        primary: P,
+        candidate: Arc<dyn AIProvider>,
+        variant: ShadowVariant,
+        repository: Arc<dyn ShadowComparisonRepository>,
+    ) -> Self {
+        Self {
+            primary,
+            shadow: Arc::new(Shadow {
+                candidate,
+                variant,
+                repository,
+                extractor: DataExtractor::new(),
+            }),
+        }
+    }
+}
+
+impl Shadow {
+    fn output(
+        &self,
+        component_type: Option<ComponentType>,
+        model: String,
+        content: String,
+        usage: TokenUsage,
+        latency_ms: u64,
+    ) -> ShadowOutput {
+        let extraction_succeeded =
+            component_type.map(|ct| self.extractor.extract(ct, &content).is_ok());
+        ShadowOutput {
+            model,
+            content,
+            latency_ms,
+            prompt_tokens: usage.prompt_tokens,
+            completion_tokens: usage.completion_tokens,
+            cost_cents: usage.estimated_cost_cents,
+            extraction_succeeded,
+        }
+    }
+
+    /// Runs the candidate in the background and records the comparison.
+    fn spawn(self: &Arc<Self>, mut request: CompletionRequest, primary: ShadowOutput) {
+        let shadow = Arc::clone(self);
+        tokio::spawn(async move {
+            if let Some(prompt) = &shadow.variant.system_prompt {
+                request.system_prompt = Some(prompt.clone());
+            }
+            let component_type = request.component_type;
+            let trace_id = request.metadata.trace_id.clone();
+
+            let started = Instant::now();
+            let candidate = shadow
+                .candidate
+                .complete(request)
+                .await
+                .map(|response| {
+                    shadow.output(
+                        component_type,
+                        response.model,
+                        response.content,
+                        response.usage,
+                        started.elapsed().as_millis() as u64,
+                    )
+                })
+                .map_err(|e| e.to_string());
+
+            let comparison = ShadowComparison {
+                id: Uuid::new_v4(),
+                variant: shadow.variant.name.clone(),
+                component_type,
+                trace_id,
+                primary,
+                candidate,
+                recorded_at: Timestamp::now(),
+            };
+            if let Err(e) = shadow.repository.record(&comparison).await {
+                tracing::warn!(
+                    variant = %comparison.variant,
+                    error = %e,
+                    "Failed to record shadow comparison"
+                );
+            }
+        });
+    }
+}
+
+#[async_trait]
+impl<P: AIProvider> AIProvider for ShadowAIProvider<P> {
+    async fn complete(&self, request: CompletionRequest) -> Result<CompletionResponse, AIError> {
+        if !self.shadow.variant.samples(&request.metadata.trace_id) {
+            return self.primary.complete(request).await;
+        }
+
+        let started = Instant::now();
+        let response = self.primary.complete(request.clone()).await?;
+        let primary = self.shadow.output(
+            request.component_type,
+            response.model.clone(),
+            response.content.clone(),
+            response.usage.clone(),
+            started.elapsed().as_millis() as u64,
+        );
+        self.shadow.spawn(request, primary);
+        Ok(response)
+    }
+
+    async fn stream_complete(
+        &self,
+        request: CompletionRequest,
+    ) -> Result<Pin<Box<dyn Stream<Item = Result<StreamChunk, AIError>> + Send>>, AIError> {
+        if !self.shadow.variant.samples(&request.metadata.trace_id) {
+            return self.primary.stream_complete(request).await;
+        }
+
+        let started = Instant::now();
+        let stream = self.primary.stream_complete(request.clone()).await?;
+        let model = self.primary.provider_info().model;
+        let shadow = Arc::clone(&self.shadow);
+        let mut content = String::new();
+        let mut pending = Some(request);
+
+        // Pass chunks through untouched; shadow once the primary finishes
+        Ok(Box::pin(stream.map(move |item| {
+            if let Ok(chunk) = &item {
+                content.push_str(&chunk.delta);
+                if chunk.is_final() {
+                    if let Some(request) = pending.take() {
+                        let primary = shadow.output(
+                            request.component_type,
+                            model.clone(),
+                            std::mem::take(&mut content),
+                            chunk.usage.clone().unwrap_or_default(),
+                            started.elapsed().as_millis() as u64,
+                        );
+                        shadow.spawn(request, primary);
+                    }
+                }
+            }
+            item
+        })))
+    }
+
+    fn estimate_tokens(&self, text: &str) -> u32 {
+        self.primary.estimate_tokens(text)
+    }
+
+    fn provider_info(&self) -> ProviderInfo {
+        self.primary.provider_info()
+    }
+
+    async fn submit_batch(&self, requests: Vec<BatchRequest>) -> Result<BatchJob, AIError> {
+        // Batches are background work; only interactive traffic is shadowed
+        self.primary.submit_batch(requests).await
+    }
+
+    async fn batch_status(&self, batch_id: &str) -> Result<BatchJob, AIError> {
+        self.primary.batch_status(batch_id).await
+    }
+
+    async fn batch_results(&self, batch_id: &str) -> Result<Vec<BatchResult>, AIError> {
+        self.primary.batch_results(batch_id).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::adapters::ai::{InMemoryShadowRepository, MockAIProvider, MockError};
+    use crate::domain::foundation::{ConversationId, SessionId, UserId};
+    use crate::ports::{MessageRole, RequestMetadata};
+    use std::time::Duration;
+
+    fn request(trace_id: &str) -> CompletionRequest {
+        CompletionRequest::new(RequestMetadata::new(
+            UserId::new("test-user").unwrap(),
+            SessionId::new(),
+            ConversationId::new(),
+            trace_id,
+        ))
+        .with_system_prompt("production prompt")
+        .with_message(MessageRole::User, "Hello")
+    }
+
+    async fn wait_for(repository: &InMemoryShadowRepository, count: usize) {
+        for _ in 0..100 {
+            if repository.len() >= count {
+                return;
+            }
+            tokio::time::sleep(Duration::from_millis(5)).await;
+        }
+        panic!("expected {} shadow comparisons", count);
+    }
+
+    #[test]
+    fn sample_rate_bounds_the_sample() {
+        let never = ShadowVariant::new("v", 0.0);
+        let always = ShadowVariant::new("v", 1.0);
+        let half = ShadowVariant::new("v", 0.5);
+
+        let traces: Vec<_> = (0..1000).map(|i| format!("trace-{}", i)).collect();
+        assert!(traces.iter().all(|t| !never.samples(t)));
+        assert!(traces.iter().all(|t| always.samples(t)));
+        let sampled = traces.iter().filter(|t| half.samples(t)).count();
+        assert!((350..650).contains(&sampled), "sampled {}", sampled);
+        assert_eq!(half.samples("trace-1"), half.samples("trace-1"));
+    }
+
+    #[tokio::test]
+    async fn records_candidate_output_with_variant_prompt() {
+        let candidate = Arc::new(MockAIProvider::new().with_response("candidate answer"));
+        let repository = Arc::new(InMemoryShadowRepository::new());
+        let provider = ShadowAIProvider::new(
+            MockAIProvider::new().with_response("primary answer"),
+            candidate.clone(),
+            ShadowVariant::new("terse", 1.0).with_system_prompt("candidate prompt"),
+            repository.clone(),
+        );
+
+        let response = provider.complete(request("trace-1")).await.unwrap();
+        assert_eq!(response.content, "primary answer");
+
+        wait_for(&repository, 1).await;
+        let comparison = repository
+            .list_by_variant("terse", 10)
+            .await
+            .unwrap()
+            .remove(0);
+        assert_eq!(comparison.trace_id, "trace-1");
+        assert_eq!(comparison.primary.content, "primary answer");
+        assert_eq!(comparison.candidate.unwrap().content, "candidate answer");
+        assert_eq!(
+            candidate.get_calls()[0].system_prompt.as_deref(),
+            Some("candidate prompt")
+        );
+    }
+
+    #[tokio::test]
+    async fn candidate_failure_is_recorded_not_returned() {
+        let repository = Arc::new(InMemoryShadowRepository::new());
+        let provider = ShadowAIProvider::new(
+            MockAIProvider::new().with_response("primary answer"),
+            Arc::new(MockAIProvider::new().with_error(MockError::Unavailable {
+                message: "down".to_string(),
+            })),
+            ShadowVariant::new("broken", 1.0),
+            repository.clone(),
+        );
+
+        assert!(provider.complete(request("trace-1")).await.is_ok());
+
+        wait_for(&repository, 1).await;
+        let comparisons = repository.all_by_variant("broken").await.unwrap();
+        assert!(comparisons[0].candidate.is_err());
+    }
+
+    #[tokio::test]
+    async fn unsampled_requests_are_not_shadowed() {
+        let candidate = Arc::new(MockAIProvider::new());
+        let repository = Arc::new(InMemoryShadowRepository::new());
+        let provider = ShadowAIProvider::new(
+            MockAIProvider::new(),
+            candidate.clone(),
+            ShadowVariant::new("off", 0.0),
+            repository.clone(),
+        );
+
+        provider.complete(request("trace-1")).await.unwrap();
+        tokio::time::sleep(Duration::from_millis(20)).await;
+
+        assert_eq!(candidate.call_count(), 0);
+        assert!(repository.is_empty());
+    }
+
+    #[tokio::test]
+    async fn streamed_response_is_shadowed_after_final_chunk() {
+        let repository = Arc::new(InMemoryShadowRepository::new());
+        let provider = ShadowAIProvider::new(
+            MockAIProvider::new().with_response("streamed primary"),
+            Arc::new(MockAIProvider::new().with_response("candidate")),
+            ShadowVariant::new("stream", 1.0),
+            repository.clone(),
+        );
+
+        let chunks: Vec<_> = provider
+            .stream_complete(request("trace-1"))
+            .await
+            .unwrap()
+            .collect()
+            .await;
+        assert!(chunks.last().unwrap().as_ref().unwrap().is_final());
+
+        wait_for(&repository, 1).await;
+        let comparison = repository.all_by_variant("stream").await.unwrap().remove(0);
+        assert_eq!(comparison.primary.content, "streamed primary ");
+    }
+}
@@ -51,7 +51,16 @@ impl AIUsageHandler {
             event.completion_tokens,
             event.estimated_cost_cents,
             event.component_type,
-        );
+        )
+        .with_cost_micro_cents(event.cost_micro_cents);
+        let record = match event.cycle_id {
+            Some(cycle_id) => record.with_cycle_id(cycle_id),
+            None => record,
+        };
+        let record = match event.latency_ms {
+            Some(latency_ms) => record.with_latency_ms(latency_ms),
+            None => record,
+        };
 
         // Record to tracker for cost attribution and limit enforcement
         self.tracker
@@ -0,0 +1,390 @@
+//! Anonymizes domain events into pseudonymous usage events.
+//!
+//! Only allowlisted event types are handled, and from each only the fields
+//! analytics needs are read. Everything else in the payload — rationales,
+//! suggestions, tool parameters, names of options — is never looked at.
+//! User, cycle and event IDs are replaced by
+//! `HMAC-SHA256(key, "{kind}\n{id}")`, so the same user keeps the same
+//! pseudonym across events without the sink being able to reverse it.
+//! Rotating the key starts every pseudonym afresh.
+
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+use async_trait::async_trait;
+use hmac::{Hmac, Mac};
+use secrecy::{ExposeSecret, SecretString};
+use serde::Deserialize;
+use sha2::Sha256;
+
+use crate::adapters::storage::hex_encode;
+use crate::domain::conversation::tools::ToolResult;
+use crate::domain::foundation::{ComponentType, DomainError, EventEnvelope, Timestamp};
+use crate::domain::proact::DQ_ELEMENT_NAMES;
+use crate::ports::{
+    AnalyticsSink, DqElementUsage, EventHandler, EventSubscriber, UsageEvent, UsageEventKind,
+};
+
+type HmacSha256 = Hmac<Sha256>;
+
+/// Domain events turned into usage events.
+pub const ANALYTICS_EVENT_TYPES: &[&str] = &[
+    "component.started.v1",
+    "component.completed.v1",
+    "tool.invoked.v1",
+    "analysis.dq_scores_computed.v1",
+];
+
+/// Started components remembered for durations; the oldest is forgotten
+/// beyond this.
+const MAX_OPEN_COMPONENTS: usize = 10_000;
+
+/// Usage event timestamps are truncated to this many seconds.
+const TIMESTAMP_GRANULARITY_SECS: u64 = 3600;
+
+/// Bytes of the HMAC kept in a pseudonym.
+const PSEUDONYM_BYTES: usize = 16;
+
+#[derive(Deserialize)]
+struct ComponentPayload {
+    component_type: ComponentType,
+}
+
+#[derive(Deserialize)]
+struct ToolPayload {
+    component: ComponentType,
+    tool_name: String,
+    result: ToolResult,
+    duration_ms: u32,
+}
+
+#[derive(Deserialize)]
+struct DqPayload {
+    overall_score: u8,
+    element_scores: Vec<DqElementPayload>,
+}
+
+#[derive(Deserialize)]
+struct DqElementPayload {
+    element_name: String,
+    score: u8,
+}
+
+/// Event handler that anonymizes events and records them in a sink.
+pub struct AnalyticsAnonymizer {
+    key: SecretString,
+    sink: Arc<dyn AnalyticsSink>,
+    /// Start times of components not yet completed, by cycle pseudonym.
+    open_components: Mutex<HashMap<(String, ComponentType), Timestamp>>,
+}
+
+impl AnalyticsAnonymizer {
+    /// Create an anonymizer.
+    ///
+    /// # Arguments
+    /// * `key` - Pseudonymization key (at least 32 random bytes recommended)
+    /// * `sink` - Where usage events are recorded
+    pub fn new(key: impl Into<String>, sink: Arc<dyn AnalyticsSink>) -> Self {
+        Self {
+            key: SecretString::new(key.into()),
+            sink,
+            open_components: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Register this anonymizer for every analytics event type.
+    pub fn register(self: &Arc<Self>, subscriber: &impl EventSubscriber) {
+        subscriber.subscribe_all(ANALYTICS_EVENT_TYPES, self.clone());
+    }
+
+    /// Anonymize a domain event, or `None` if it is not one analytics
+    /// uses or its payload is not as expected.
+    pub fn anonymize(&self, event: &EventEnvelope) -> Option<UsageEvent> {
+        let cycle = self.pseudonym("cycle", &event.aggregate_id);
+        let kind = match self.kind(event, &cycle) {
+            Ok(kind) => kind?,
+            Err(error) => {
+                tracing::warn!(
+                    event_id = %event.event_id,
+                    event_type = %event.event_type,
+                    error = %error,
+                    "Dropping malformed event from analytics"
+                );
+                return None;
+            }
+        };
+
+        let secs = event.occurred_at.as_unix_secs();
+        Some(UsageEvent {
+            id: self.pseudonym("event", event.event_id.as_str()),
+            occurred_at: Timestamp::from_unix_secs(secs - secs % TIMESTAMP_GRANULARITY_SECS),
+            user: event
+                .metadata
+                .user_id
+                .as_deref()
+                .map(|user_id| self.pseudonym("user", user_id)),
+            cycle,
+            kind,
+        })
+    }
+
+    fn kind(
+        &self,
+        event: &EventEnvelope,
+        cycle: &str,
+    ) -> Result<Option<UsageEventKind>, serde_json::Error> {
+        let payload = event.payload.clone();
+        let kind = match event.event_type.as_str() {
+            "component.started.v1" => {
+                let component = serde_json::from_value::<ComponentPayload>(payload)?.component_type;
+                self.component_started(cycle, component, event.occurred_at);
+                UsageEventKind::ComponentStarted { component }
+            }
+            "component.completed.v1" => {
+                let component = serde_json::from_value::<ComponentPayload>(payload)?.component_type;
+                UsageEventKind::ComponentCompleted {
+                    component,
+                    duration_secs: self.component_completed(cycle, component, event.occurred_at),
+                }
+            }
+            "tool.invoked.v1" => {
+                let tool: ToolPayload = serde_json::from_value(payload)?;
+                if !is_identifier(&tool.tool_name) {
+                    return Ok(None);
+                }
+                UsageEventKind::ToolInvoked {
+                    component: tool.component,
+                    tool: tool.tool_name,
+                    result: tool.result,
+                    duration_ms: tool.duration_ms,
+                }
+            }
+            "analysis.dq_scores_computed.v1" => {
+                let scores: DqPayload = serde_json::from_value(payload)?;
+                UsageEventKind::DqScored {
+                    overall_score: scores.overall_score,
+                    element_scores: scores
+                        .element_scores
+                        .into_iter()
+                        .filter(|e| DQ_ELEMENT_NAMES.contains(&e.element_name.as_str()))
+                        .map(|e| DqElementUsage {
+                            element: e.element_name,
+                            score: e.score,
+                        })
+                        .collect(),
+                }
+            }
+            _ => return Ok(None),
+        };
+        Ok(Some(kind))
+    }
+
+    fn component_started(&self, cycle: &str, component: ComponentType, at: Timestamp) {
+        let mut open = self.open_components.lock().unwrap();
+        if open.len() >= MAX_OPEN_COMPONENTS {
+            let oldest = open
+                .iter()
+                .min_by_key(|(_, started_at)| started_at.as_unix_secs())
+                .map(|(key, _)| key.clone());
+            if let Some(oldest) = oldest {
+                open.remove(&oldest);
+            }
+        }
+        open.insert((cycle.to_string(), component), at);
+    }
+
+    fn component_completed(
+        &self,
+        cycle: &str,
+        component: ComponentType,
+        at: Timestamp,
+    ) -> Option<u64> {
+        let started_at = self
+            .open_components
+            .lock()
+            .unwrap()
+            .remove(&(cycle.to_string(), component))?;
+        Some(at.as_unix_secs().saturating_sub(started_at.as_unix_secs()))
+    }
+
+    fn pseudonym(&self, kind: &str, id: &str) -> String {
+        let mut mac = HmacSha256::new_from_slice(self.key.expose_secret().as_bytes())
+            .expect("HMAC can take key of any size");
+        mac.update(kind.as_bytes());
+        mac.update(b"\n");
+        mac.update(id.as_bytes());
+        hex_encode(&mac.finalize().into_bytes()[..PSEUDONYM_BYTES])
+    }
+}
+
+/// Whether a tool name looks like one from the registry rather than text.
+fn is_identifier(name: &str) -> bool {
+    !name.is_empty()
+        && name
+            .chars()
+            .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_')
+}
+
+#[async_trait]
+impl EventHandler for AnalyticsAnonymizer {
+    async fn handle(&self, event: EventEnvelope) -> Result<(), DomainError> {
+        let Some(usage) = self.anonymize(&event) else {
+            return Ok(());
+        };
+        // Analytics is best-effort and must never hold up event processing
+        if let Err(error) = self.sink.record(&usage).await {
+            tracing::warn!(error = %error, "Failed to record usage event");
+        }
+        Ok(())
+    }
+
+    fn name(&self) -> &'static str {
+        "AnalyticsAnonymizer"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::adapters::analytics::InMemoryAnalyticsSink;
+    use serde_json::json;
+
+    const KEY: &str = "0123456789abcdef0123456789abcdef";
+
+    fn anonymizer() -> AnalyticsAnonymizer {
+        AnalyticsAnonymizer::new(KEY, Arc::new(InMemoryAnalyticsSink::new()))
+    }
+
+    fn component_event(event_type: &str, cycle_id: &str, at: u64) -> EventEnvelope {
+        let mut event = EventEnvelope::new(
+            event_type,
+            cycle_id,
+            "Cycle",
+            json!({"cycle_id": cycle_id, "component_type": "objectives"}),
+        )
+        .with_user_id("user-42");
+        event.occurred_at = Timestamp::from_unix_secs(at);
+        event
+    }
+
+    #[test]
+    fn pairs_component_start_and_completion_into_a_duration() {
+        let anonymizer = anonymizer();
+        let started =
+            anonymizer.anonymize(&component_event("component.started.v1", "cycle-1", 7_300));
+        let completed =
+            anonymizer.anonymize(&component_event("component.completed.v1", "cycle-1", 7_420));
+
+        let started = started.unwrap();
+        let completed = completed.unwrap();
+        assert_eq!(
+            completed.kind,
+            UsageEventKind::ComponentCompleted {
+                component: ComponentType::Objectives,
+                duration_secs: Some(120),
+            }
+        );
+        assert_eq!(completed.occurred_at, Timestamp::from_unix_secs(7_200));
+        assert_eq!(started.cycle, completed.cycle);
+        assert_eq!(started.user, completed.user);
+        assert_ne!(started.id, completed.id);
+
+        let json = serde_json::to_string(&completed).unwrap();
+        assert!(!json.contains("cycle-1"));
+        assert!(!json.contains("user-42"));
+    }
+
+    #[test]
+    fn pseudonyms_depend_on_the_key() {
+        let other = AnalyticsAnonymizer::new("another key", Arc::new(InMemoryAnalyticsSink::new()));
+        let event = component_event("component.completed.v1", "cycle-1", 0);
+
+        let ours = anonymizer().anonymize(&event).unwrap();
+        let theirs = other.anonymize(&event).unwrap();
+
+        assert_ne!(ours.cycle, theirs.cycle);
+        assert_ne!(ours.user, theirs.user);
+        assert_eq!(ours.cycle.len(), PSEUDONYM_BYTES * 2);
+    }
+
+    #[test]
+    fn keeps_dq_scores_and_drops_rationales() {
+        let event = EventEnvelope::new(
+            "analysis.dq_scores_computed.v1",
+            "cycle-1",
+            "Analysis",
+            json!({
+                "cycle_id": "cycle-1",
+                "session_id": "session-1",
+                "element_scores": [
+                    {"element_name": "Clear Objectives", "score": 80, "rationale": "Wants to live by the sea"},
+                    {"element_name": "Whether to move to Lisbon", "score": 10, "rationale": ""}
+                ],
+                "overall_score": 60,
+                "weakest_element": "Clear Objectives",
+                "improvement_suggestions": ["Ask your partner about Lisbon"],
+            }),
+        );
+
+        let usage = anonymizer().anonymize(&event).unwrap();
+
+        assert_eq!(
+            usage.kind,
+            UsageEventKind::DqScored {
+                overall_score: 60,
+                element_scores: vec![DqElementUsage {
+                    element: "Clear Objectives".to_string(),
+                    score: 80,
+                }],
+            }
+        );
+        let json = serde_json::to_string(&usage).unwrap();
+        assert!(!json.contains("sea"));
+        assert!(!json.contains("Lisbon"));
+        assert!(!json.contains("session-1"));
+    }
+
+    #[tokio::test]
+    async fn records_tool_invocations_and_ignores_other_events() {
+        let sink = Arc::new(InMemoryAnalyticsSink::new());
+        let anonymizer = AnalyticsAnonymizer::new(KEY, sink.clone());
+        let tool = |name: &str| {
+            EventEnvelope::new(
+                "tool.invoked.v1",
+                "cycle-1",
+                "Cycle",
+                json!({
+                    "component": "alternatives",
+                    "tool_name": name,
+                    "result": "success",
+                    "duration_ms": 35,
+                }),
+            )
+        };
+
+        anonymizer.handle(tool("add_alternative")).await.unwrap();
+        anonymizer.handle(tool("Move to Lisbon")).await.unwrap();
+        anonymizer
+            .handle(EventEnvelope::new(
+                "session.renamed.v1",
+                "session-1",
+                "Session",
+                json!({"title": "Move to Lisbon?"}),
+            ))
+            .await
+            .unwrap();
+
+        let events = sink.events();
+        assert_eq!(events.len(), 1);
+        assert_eq!(
+            events[0].kind,
+            UsageEventKind::ToolInvoked {
+                component: ComponentType::Alternatives,
+                tool: "add_alternative".to_string(),
+                result: ToolResult::Success,
+                duration_ms: 35,
+            }
+        );
+        assert!(events[0].user.is_none());
+    }
+}
@@ -0,0 +1,33 @@
+//! In-memory analytics sink for tests and development.
+
+use std::sync::Mutex;
+
+use async_trait::async_trait;
+
+use crate::domain::foundation::DomainError;
+use crate::ports::{AnalyticsSink, UsageEvent};
+
+/// Keeps recorded usage events in memory.
+#[derive(Default)]
+pub struct InMemoryAnalyticsSink {
+    events: Mutex<Vec<UsageEvent>>,
+}
+
+impl InMemoryAnalyticsSink {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Events recorded so far, oldest first.
+    pub fn events(&self) -> Vec<UsageEvent> {
+        self.events.lock().unwrap().clone()
+    }
+}
+
+#[async_trait]
+impl AnalyticsSink for InMemoryAnalyticsSink {
+    async fn record(&self, event: &UsageEvent) -> Result<(), DomainError> {
+        self.events.lock().unwrap().push(event.clone());
+        Ok(())
+    }
+}
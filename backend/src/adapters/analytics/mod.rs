@@ -0,0 +1,16 @@
+//! Analytics adapters.
+//!
+//! Usage analytics without decision content:
+//!
+//! ```text
+//! Event bus ── AnalyticsAnonymizer (allowlist, pseudonymize) ─► AnalyticsSink
+//! ```
+//!
+//! - `AnalyticsAnonymizer` - Event handler producing pseudonymous usage events
+//! - `InMemoryAnalyticsSink` - Keeps usage events in memory for tests and development
+
+mod anonymizer;
+mod in_memory_sink;
+
+pub use anonymizer::{AnalyticsAnonymizer, ANALYTICS_EVENT_TYPES};
+pub use in_memory_sink::InMemoryAnalyticsSink;
@@ -0,0 +1,157 @@
+//! In-memory audit log for testing and development.
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use tokio::sync::RwLock;
+
+use crate::domain::audit::{AuditEntry, AuditRecord, AUDIT_GENESIS_HASH};
+use crate::domain::foundation::{DomainError, Timestamp};
+use crate::ports::{AuditLog, AuditPage, AuditQuery};
+
+/// In-memory hash-chained audit log.
+#[derive(Debug, Clone, Default)]
+pub struct InMemoryAuditLog {
+    entries: Arc<RwLock<Vec<AuditEntry>>>,
+}
+
+impl InMemoryAuditLog {
+    /// Create an empty log.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Every entry, oldest first.
+    pub async fn entries(&self) -> Vec<AuditEntry> {
+        self.entries.read().await.clone()
+    }
+
+    /// Replaces the entry at `sequence`, bypassing the chain, so tests can
+    /// simulate tampering with the underlying store.
+    pub async fn overwrite(&self, entry: AuditEntry) {
+        let mut entries = self.entries.write().await;
+        if let Some(existing) = entries.iter_mut().find(|e| e.sequence == entry.sequence) {
+            *existing = entry;
+        }
+    }
+}
+
+fn matches(entry: &AuditEntry, query: &AuditQuery) -> bool {
+    let record = &entry.record;
+    query
+        .actor_id
+        .as_ref()
+        .is_none_or(|a| &record.actor_id == a)
+        && query
+            .aggregate_type
+            .as_ref()
+            .is_none_or(|t| &record.aggregate_type == t)
+        && query
+            .aggregate_id
+            .as_ref()
+            .is_none_or(|id| &record.aggregate_id == id)
+        && query
+            .command_type
+            .as_ref()
+            .is_none_or(|c| &record.command_type == c)
+}
+
+#[async_trait]
+impl AuditLog for InMemoryAuditLog {
+    async fn append(&self, record: AuditRecord) -> Result<AuditEntry, DomainError> {
+        // Holding the write lock keeps the tail from moving while sealing
+        let mut entries = self.entries.write().await;
+        let (sequence, previous_hash) = match entries.last() {
+            Some(last) => (last.sequence + 1, last.hash.clone()),
+            None => (1, AUDIT_GENESIS_HASH.to_string()),
+        };
+        let entry = AuditEntry::seal(record, sequence, previous_hash, Timestamp::now());
+        entries.push(entry.clone());
+        Ok(entry)
+    }
+
+    async fn list(&self, query: &AuditQuery) -> Result<AuditPage, DomainError> {
+        let entries = self.entries.read().await;
+        let matching: Vec<&AuditEntry> =
+            entries.iter().rev().filter(|e| matches(e, query)).collect();
+        let total = matching.len() as u64;
+        let offset = query.offset.unwrap_or(0) as usize;
+        let limit = query.limit.map_or(usize::MAX, |l| l as usize);
+        let items: Vec<AuditEntry> = matching
+            .into_iter()
+            .skip(offset)
+            .take(limit)
+            .cloned()
+            .collect();
+        let has_more = ((offset + items.len()) as u64) < total;
+        Ok(AuditPage {
+            items,
+            total,
+            has_more,
+        })
+    }
+
+    async fn entries_after(
+        &self,
+        after_sequence: u64,
+        limit: u32,
+    ) -> Result<Vec<AuditEntry>, DomainError> {
+        Ok(self
+            .entries
+            .read()
+            .await
+            .iter()
+            .filter(|e| e.sequence > after_sequence)
+            .take(limit as usize)
+            .cloned()
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::audit::verify_chain;
+    use crate::domain::foundation::{CommandMetadata, UserId};
+
+    fn record(user: &str, aggregate_id: &str) -> AuditRecord {
+        let metadata = CommandMetadata::new(UserId::new(user).unwrap());
+        AuditRecord::new(&metadata, "RenameSession", "session", aggregate_id)
+    }
+
+    #[tokio::test]
+    async fn appends_form_a_verifiable_chain() {
+        let log = InMemoryAuditLog::new();
+        for i in 0..3 {
+            log.append(record("alice", &format!("s-{}", i)))
+                .await
+                .unwrap();
+        }
+
+        let entries = log.entries_after(0, 10).await.unwrap();
+
+        assert_eq!(entries.len(), 3);
+        assert_eq!(verify_chain(&entries, None), Ok(()));
+    }
+
+    #[tokio::test]
+    async fn lists_matching_entries_newest_first() {
+        let log = InMemoryAuditLog::new();
+        log.append(record("alice", "s-1")).await.unwrap();
+        log.append(record("bob", "s-2")).await.unwrap();
+        log.append(record("alice", "s-3")).await.unwrap();
+
+        let page = log
+            .list(&AuditQuery {
+                actor_id: Some(UserId::new("alice").unwrap()),
+                limit: Some(1),
+                ..AuditQuery::default()
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(page.total, 2);
+        assert!(page.has_more);
+        assert_eq!(page.items[0].record.aggregate_id, "s-3");
+    }
+}
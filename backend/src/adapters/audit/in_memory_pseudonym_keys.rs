@@ -0,0 +1,65 @@
+//! In-memory audit pseudonym keys for testing and development.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use tokio::sync::RwLock;
+
+use crate::domain::audit::PseudonymKey;
+use crate::domain::foundation::{DomainError, UserId};
+use crate::ports::AuditPseudonymKeys;
+
+/// In-memory audit pseudonym keys, one per user.
+#[derive(Debug, Clone, Default)]
+pub struct InMemoryAuditPseudonymKeys {
+    keys: Arc<RwLock<HashMap<UserId, PseudonymKey>>>,
+}
+
+impl InMemoryAuditPseudonymKeys {
+    /// Create a store with no keys.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Deletes the user's key, as erasing the user does.
+    pub async fn erase(&self, user_id: &UserId) {
+        self.keys.write().await.remove(user_id);
+    }
+}
+
+#[async_trait]
+impl AuditPseudonymKeys for InMemoryAuditPseudonymKeys {
+    async fn key_for(&self, user_id: &UserId) -> Result<PseudonymKey, DomainError> {
+        Ok(self
+            .keys
+            .write()
+            .await
+            .entry(user_id.clone())
+            .or_insert_with(PseudonymKey::generate)
+            .clone())
+    }
+
+    async fn find(&self, user_id: &UserId) -> Result<Option<PseudonymKey>, DomainError> {
+        Ok(self.keys.read().await.get(user_id).cloned())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn keys_are_created_once_and_erased() {
+        let keys = InMemoryAuditPseudonymKeys::new();
+        let user_id = UserId::new("user-1").unwrap();
+        assert_eq!(keys.find(&user_id).await.unwrap(), None);
+
+        let key = keys.key_for(&user_id).await.unwrap();
+        assert_eq!(keys.key_for(&user_id).await.unwrap(), key);
+        assert_eq!(keys.find(&user_id).await.unwrap(), Some(key));
+
+        keys.erase(&user_id).await;
+        assert_eq!(keys.find(&user_id).await.unwrap(), None);
+    }
+}
@@ -0,0 +1,11 @@
+//! Audit adapters.
+//!
+//! In-memory implementations of the `AuditLog` and `AuditPseudonymKeys`
+//! ports for tests and development. The production adapters live in
+//! `adapters::postgres`.
+
+mod in_memory_audit_log;
+mod in_memory_pseudonym_keys;
+
+pub use in_memory_audit_log::InMemoryAuditLog;
+pub use in_memory_pseudonym_keys::InMemoryAuditPseudonymKeys;
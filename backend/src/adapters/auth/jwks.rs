@@ -0,0 +1,668 @@
+//! JWKS key management shared by the OIDC session validators.
+//!
+//! JWKS are cached and refreshed so that IdP key rotations never cause a burst
+//! of 401s or a thundering herd of JWKS fetches:
+//!
+//! - All fetches are single-flight: concurrent callers wait for one request
+//! - An unknown `kid` triggers exactly one refetch, throttled by a cooldown so
+//!   forged tokens cannot force repeated fetches
+//! - If the IdP is unreachable, previously fetched keys are served for a
+//!   bounded grace period and counted in [`JwksCacheMetrics`]
+//! - After a failed fetch, requests are answered from the cache (or fail)
+//!   without fetching until the same cooldown passes, so an outage does not
+//!   queue every request behind a fetch timeout
+//!
+//! The validators own the JWKS URL; [`JwksStore`] only fetches and caches it.
+
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::{Duration, Instant};
+
+use jsonwebtoken::{
+    decode, decode_header, jwk::JwkSet, Algorithm, DecodingKey, Header, TokenData, Validation,
+};
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use tokio::sync::{Mutex, RwLock};
+
+use crate::domain::foundation::AuthError;
+
+/// How keys are cached and refreshed.
+#[derive(Debug, Clone, Copy)]
+pub(super) struct JwksSettings {
+    /// How long a fetched key set is fresh.
+    pub cache_duration: Duration,
+    /// Minimum time between refetches triggered by an unknown `kid`, and
+    /// before retrying a failed fetch.
+    pub kid_miss_cooldown: Duration,
+    /// How long past expiry keys may be served when the IdP is unreachable.
+    pub stale_grace_period: Duration,
+}
+
+/// Audience can be a single string or array of strings in JWTs.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(untagged)]
+pub(super) enum Audience {
+    #[default]
+    None,
+    Single(String),
+    Multiple(Vec<String>),
+}
+
+impl Audience {
+    pub(super) fn contains(&self, expected: &str) -> bool {
+        match self {
+            Audience::None => false,
+            Audience::Single(s) => s == expected,
+            Audience::Multiple(v) => v.iter().any(|s| s == expected),
+        }
+    }
+}
+
+/// Cached JWKS with expiry tracking.
+struct JwksCache {
+    jwks: JwkSet,
+    fetched_at: Instant,
+    cache_duration: Duration,
+}
+
+impl JwksCache {
+    fn new(jwks: JwkSet, cache_duration: Duration) -> Self {
+        Self {
+            jwks,
+            fetched_at: Instant::now(),
+            cache_duration,
+        }
+    }
+
+    fn is_expired(&self) -> bool {
+        self.fetched_at.elapsed() > self.cache_duration
+    }
+
+    /// Whether expired keys may still be served while the IdP is unreachable.
+    fn is_within_grace(&self, grace: Duration) -> bool {
+        self.fetched_at.elapsed() <= self.cache_duration + grace
+    }
+}
+
+/// Snapshot of JWKS cache behaviour for monitoring key rotation.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct JwksCacheMetrics {
+    /// Validations served from a fresh cache.
+    pub cache_hits: u64,
+
+    /// Successful JWKS fetches (initial, expiry, background, kid-miss).
+    pub fetches: u64,
+
+    /// Failed JWKS fetches.
+    pub fetch_failures: u64,
+
+    /// Refetches triggered by a token whose `kid` was not in the cache.
+    pub kid_miss_refetches: u64,
+
+    /// Unknown `kid`s rejected without refetch because of the cooldown.
+    pub kid_miss_throttled: u64,
+
+    /// Times expired keys were served because a refresh failed.
+    pub stale_keys_served: u64,
+
+    /// Fetches skipped because one failed within the cooldown.
+    pub failed_fetch_throttled: u64,
+
+    /// Age of the currently cached key set, if any.
+    pub key_age: Option<Duration>,
+}
+
+#[derive(Debug, Default)]
+struct JwksCounters {
+    cache_hits: AtomicU64,
+    fetches: AtomicU64,
+    fetch_failures: AtomicU64,
+    kid_miss_refetches: AtomicU64,
+    kid_miss_throttled: AtomicU64,
+    stale_keys_served: AtomicU64,
+    failed_fetch_throttled: AtomicU64,
+}
+
+impl JwksCounters {
+    fn incr(counter: &AtomicU64) {
+        counter.fetch_add(1, Ordering::Relaxed);
+    }
+}
+
+/// Fetches and caches one IdP's signing keys.
+pub(super) struct JwksStore {
+    settings: JwksSettings,
+    http_client: reqwest::Client,
+    cache: RwLock<Option<JwksCache>>,
+    /// Serializes JWKS fetches so concurrent misses share one request.
+    refresh_lock: Mutex<()>,
+    /// When the last fetch failed, cleared by a successful one.
+    last_failed_at: RwLock<Option<Instant>>,
+    counters: JwksCounters,
+}
+
+impl JwksStore {
+    pub(super) fn new(settings: JwksSettings, http_client: reqwest::Client) -> Self {
+        Self {
+            settings,
+            http_client,
+            cache: RwLock::new(None),
+            refresh_lock: Mutex::new(()),
+            last_failed_at: RwLock::new(None),
+            counters: JwksCounters::default(),
+        }
+    }
+
+    /// Current JWKS cache metrics.
+    pub(super) async fn metrics(&self) -> JwksCacheMetrics {
+        let key_age = self
+            .cache
+            .read()
+            .await
+            .as_ref()
+            .map(|cached| cached.fetched_at.elapsed());
+
+        JwksCacheMetrics {
+            cache_hits: self.counters.cache_hits.load(Ordering::Relaxed),
+            fetches: self.counters.fetches.load(Ordering::Relaxed),
+            fetch_failures: self.counters.fetch_failures.load(Ordering::Relaxed),
+            kid_miss_refetches: self.counters.kid_miss_refetches.load(Ordering::Relaxed),
+            kid_miss_throttled: self.counters.kid_miss_throttled.load(Ordering::Relaxed),
+            stale_keys_served: self.counters.stale_keys_served.load(Ordering::Relaxed),
+            failed_fetch_throttled: self.counters.failed_fetch_throttled.load(Ordering::Relaxed),
+            key_age,
+        }
+    }
+
+    /// Decoding key and algorithm for a token, refetching once if its `kid`
+    /// is unknown (the IdP probably rotated keys).
+    pub(super) async fn key_for(
+        &self,
+        jwks_url: &str,
+        token: &str,
+    ) -> Result<(DecodingKey, Algorithm), AuthError> {
+        let header = decode_header(token).map_err(|e| {
+            tracing::debug!("Failed to decode JWT header: {}", e);
+            AuthError::InvalidToken
+        })?;
+
+        let mut jwks = self.get_jwks(jwks_url).await?;
+
+        if let Some(kid) = header.kid.as_deref() {
+            if jwks.find(kid).is_none() {
+                let observed_at = self.cached_fetched_at().await;
+                jwks = self
+                    .refetch_for_unknown_kid(jwks_url, kid, observed_at)
+                    .await?;
+            }
+        }
+
+        find_decoding_key(&header, &jwks)
+    }
+
+    /// Refreshes the key set now, e.g. from a background task.
+    pub(super) async fn refresh(&self, jwks_url: &str) -> Result<JwkSet, AuthError> {
+        let _guard = self.refresh_lock.lock().await;
+        self.fetch_and_store(jwks_url).await
+    }
+
+    /// Fetch JWKS from the IdP.
+    pub(super) async fn fetch_jwks(&self, url: &str) -> Result<JwkSet, AuthError> {
+        tracing::debug!("Fetching JWKS from {}", url);
+
+        let response = self.http_client.get(url).send().await.map_err(|e| {
+            tracing::error!("Failed to fetch JWKS: {}", e);
+            AuthError::ServiceUnavailable(format!("Failed to fetch JWKS: {}", e))
+        })?;
+
+        if !response.status().is_success() {
+            let status = response.status();
+            tracing::error!("JWKS endpoint returned {}", status);
+            return Err(AuthError::ServiceUnavailable(format!(
+                "JWKS endpoint returned {}",
+                status
+            )));
+        }
+
+        let jwks: JwkSet = response.json().await.map_err(|e| {
+            tracing::error!("Failed to parse JWKS: {}", e);
+            AuthError::ServiceUnavailable(format!("Failed to parse JWKS: {}", e))
+        })?;
+
+        tracing::debug!("Fetched {} keys from JWKS", jwks.keys.len());
+
+        Ok(jwks)
+    }
+
+    /// Get JWKS, using cache if available and not expired.
+    async fn get_jwks(&self, url: &str) -> Result<JwkSet, AuthError> {
+        if let Some(jwks) = self.fresh_cached_jwks().await {
+            JwksCounters::incr(&self.counters.cache_hits);
+            return Ok(jwks);
+        }
+
+        if let Some(answer) = self.answer_after_recent_failure().await {
+            return answer;
+        }
+
+        // Cache miss or expired - single-flight fetch
+        let _guard = self.refresh_lock.lock().await;
+
+        // Another caller may have refreshed, or failed to, while we waited
+        if let Some(jwks) = self.fresh_cached_jwks().await {
+            JwksCounters::incr(&self.counters.cache_hits);
+            return Ok(jwks);
+        }
+        if let Some(answer) = self.answer_after_recent_failure().await {
+            return answer;
+        }
+
+        self.fetch_and_store(url).await
+    }
+
+    /// Refetch JWKS once because a token referenced an unknown `kid`.
+    ///
+    /// `observed_at` is the fetch time of the key set the caller searched. If
+    /// the cache changed since then, the newer set is returned without another
+    /// fetch. Refetches are throttled by `kid_miss_cooldown`, both after the
+    /// last fetch and after the last failed one.
+    async fn refetch_for_unknown_kid(
+        &self,
+        url: &str,
+        kid: &str,
+        observed_at: Option<Instant>,
+    ) -> Result<JwkSet, AuthError> {
+        let _guard = self.refresh_lock.lock().await;
+
+        {
+            let cache = self.cache.read().await;
+            if let Some(ref cached) = *cache {
+                if Some(cached.fetched_at) != observed_at {
+                    // Someone else refreshed while we waited
+                    return Ok(cached.jwks.clone());
+                }
+                if cached.fetched_at.elapsed() < self.settings.kid_miss_cooldown {
+                    JwksCounters::incr(&self.counters.kid_miss_throttled);
+                    tracing::debug!("Unknown kid {} within refetch cooldown", kid);
+                    return Ok(cached.jwks.clone());
+                }
+            }
+        }
+        if let Some(answer) = self.answer_after_recent_failure().await {
+            return answer;
+        }
+
+        JwksCounters::incr(&self.counters.kid_miss_refetches);
+        tracing::info!("Unknown kid {}, refetching JWKS for key rotation", kid);
+        self.fetch_and_store(url).await
+    }
+
+    /// Fetch JWKS and update the cache. Caller must hold `refresh_lock`.
+    ///
+    /// On fetch failure, falls back to the cached key set while it is within
+    /// the stale grace period.
+    async fn fetch_and_store(&self, url: &str) -> Result<JwkSet, AuthError> {
+        match self.fetch_jwks(url).await {
+            Ok(jwks) => {
+                JwksCounters::incr(&self.counters.fetches);
+                *self.last_failed_at.write().await = None;
+                let mut cache = self.cache.write().await;
+                *cache = Some(JwksCache::new(jwks.clone(), self.settings.cache_duration));
+                Ok(jwks)
+            }
+            Err(e) => {
+                JwksCounters::incr(&self.counters.fetch_failures);
+                *self.last_failed_at.write().await = Some(Instant::now());
+                self.stale_cached_jwks().await.ok_or(e)
+            }
+        }
+    }
+
+    /// Answer for a caller that would fetch, if a fetch failed within
+    /// `kid_miss_cooldown`: the cached keys, stale ones within the grace
+    /// period, or unavailable. `None` when fetching is allowed.
+    async fn answer_after_recent_failure(&self) -> Option<Result<JwkSet, AuthError>> {
+        let failed_at = (*self.last_failed_at.read().await)?;
+        if failed_at.elapsed() >= self.settings.kid_miss_cooldown {
+            return None;
+        }
+
+        JwksCounters::incr(&self.counters.failed_fetch_throttled);
+        Some(self.stale_cached_jwks().await.ok_or_else(|| {
+            AuthError::ServiceUnavailable(
+                "JWKS fetch failed recently; not retrying until the cooldown passes".to_string(),
+            )
+        }))
+    }
+
+    /// Cached JWKS if within the stale grace period, counted as stale keys
+    /// served.
+    async fn stale_cached_jwks(&self) -> Option<JwkSet> {
+        let cache = self.cache.read().await;
+        let cached = cache
+            .as_ref()
+            .filter(|cached| cached.is_within_grace(self.settings.stale_grace_period))?;
+        JwksCounters::incr(&self.counters.stale_keys_served);
+        tracing::warn!(
+            "Serving stale JWKS (age {:?}) after refresh failure",
+            cached.fetched_at.elapsed()
+        );
+        Some(cached.jwks.clone())
+    }
+
+    /// Cached JWKS if present and not expired.
+    async fn fresh_cached_jwks(&self) -> Option<JwkSet> {
+        let cache = self.cache.read().await;
+        cache
+            .as_ref()
+            .filter(|cached| !cached.is_expired())
+            .map(|cached| cached.jwks.clone())
+    }
+
+    /// Fetch time of the currently cached key set.
+    async fn cached_fetched_at(&self) -> Option<Instant> {
+        self.cache
+            .read()
+            .await
+            .as_ref()
+            .map(|cached| cached.fetched_at)
+    }
+}
+
+/// Find the decoding key for a JWT.
+fn find_decoding_key(
+    header: &Header,
+    jwks: &JwkSet,
+) -> Result<(DecodingKey, Algorithm), AuthError> {
+    // Get the key ID from the JWT header
+    let kid = header.kid.as_ref().ok_or_else(|| {
+        tracing::warn!("JWT missing 'kid' header");
+        AuthError::InvalidToken
+    })?;
+
+    // Find matching key in JWKS
+    let jwk = jwks.find(kid).ok_or_else(|| {
+        tracing::warn!("No matching key found for kid: {}", kid);
+        AuthError::InvalidToken
+    })?;
+
+    // Determine algorithm
+    let algorithm = match jwk.common.key_algorithm {
+        Some(jsonwebtoken::jwk::KeyAlgorithm::RS256) => Algorithm::RS256,
+        Some(jsonwebtoken::jwk::KeyAlgorithm::RS384) => Algorithm::RS384,
+        Some(jsonwebtoken::jwk::KeyAlgorithm::RS512) => Algorithm::RS512,
+        Some(jsonwebtoken::jwk::KeyAlgorithm::ES256) => Algorithm::ES256,
+        Some(jsonwebtoken::jwk::KeyAlgorithm::ES384) => Algorithm::ES384,
+        Some(other) => {
+            tracing::warn!("Unsupported algorithm: {:?}", other);
+            return Err(AuthError::InvalidToken);
+        }
+        None => {
+            // Default to RS256 if not specified (common for OIDC)
+            Algorithm::RS256
+        }
+    };
+
+    // Create decoding key
+    let decoding_key = DecodingKey::from_jwk(jwk).map_err(|e| {
+        tracing::warn!("Failed to create decoding key: {}", e);
+        AuthError::InvalidToken
+    })?;
+
+    Ok((decoding_key, algorithm))
+}
+
+/// Validate a JWT's signature, issuer, audience and expiry, and decode its
+/// claims.
+pub(super) fn validate_token<C: DeserializeOwned>(
+    token: &str,
+    decoding_key: &DecodingKey,
+    algorithm: Algorithm,
+    issuer: &str,
+    audience: &str,
+) -> Result<TokenData<C>, AuthError> {
+    let mut validation = Validation::new(algorithm);
+
+    // SECURITY (A07): Validate issuer
+    validation.set_issuer(&[issuer]);
+
+    // SECURITY (A07): Validate audience
+    validation.set_audience(&[audience]);
+
+    // SECURITY (A07): Validate expiry (enabled by default)
+    validation.validate_exp = true;
+
+    // Require these claims to be present
+    validation.set_required_spec_claims(&["exp", "iss", "sub"]);
+
+    decode::<C>(token, decoding_key, &validation).map_err(|e| {
+        use jsonwebtoken::errors::ErrorKind;
+        match e.kind() {
+            ErrorKind::ExpiredSignature => {
+                tracing::debug!("Token expired");
+                AuthError::TokenExpired
+            }
+            ErrorKind::InvalidIssuer => {
+                tracing::warn!("Invalid issuer in token");
+                AuthError::InvalidToken
+            }
+            ErrorKind::InvalidAudience => {
+                tracing::warn!("Invalid audience in token");
+                AuthError::InvalidToken
+            }
+            _ => {
+                tracing::warn!("Token validation failed: {}", e);
+                AuthError::InvalidToken
+            }
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // ════════════════════════════════════════════════════════════════════════════
+    // Audience Parsing Tests
+    // ════════════════════════════════════════════════════════════════════════════
+
+    #[test]
+    fn audience_single_string_contains() {
+        let aud = Audience::Single("my-api".to_string());
+        assert!(aud.contains("my-api"));
+        assert!(!aud.contains("other-api"));
+    }
+
+    #[test]
+    fn audience_multiple_contains() {
+        let aud = Audience::Multiple(vec!["api-1".to_string(), "api-2".to_string()]);
+        assert!(aud.contains("api-1"));
+        assert!(aud.contains("api-2"));
+        assert!(!aud.contains("api-3"));
+    }
+
+    #[test]
+    fn audience_none_contains_nothing() {
+        let aud = Audience::None;
+        assert!(!aud.contains("anything"));
+    }
+
+    // ════════════════════════════════════════════════════════════════════════════
+    // JWKS Cache Tests
+    // ════════════════════════════════════════════════════════════════════════════
+
+    #[test]
+    fn jwks_cache_not_expired_initially() {
+        let jwks = JwkSet { keys: vec![] };
+        let cache = JwksCache::new(jwks, Duration::from_secs(3600));
+        assert!(!cache.is_expired());
+    }
+
+    #[test]
+    fn jwks_cache_expires_after_duration() {
+        let jwks = JwkSet { keys: vec![] };
+        let cache = JwksCache::new(jwks, Duration::from_millis(1));
+        std::thread::sleep(Duration::from_millis(10));
+        assert!(cache.is_expired());
+    }
+
+    #[test]
+    fn jwks_cache_within_grace_after_expiry() {
+        let jwks = JwkSet { keys: vec![] };
+        let cache = JwksCache::new(jwks, Duration::from_millis(1));
+        std::thread::sleep(Duration::from_millis(10));
+        assert!(cache.is_expired());
+        assert!(cache.is_within_grace(Duration::from_secs(60)));
+        assert!(!cache.is_within_grace(Duration::ZERO));
+    }
+
+    // ════════════════════════════════════════════════════════════════════════════
+    // Key Rotation Tests
+    // ════════════════════════════════════════════════════════════════════════════
+
+    // Port 9 (discard) on localhost refuses connections, so fetches fail fast
+    const UNREACHABLE_JWKS: &str = "http://127.0.0.1:9/.well-known/jwks.json";
+
+    fn unreachable_store() -> JwksStore {
+        JwksStore::new(
+            JwksSettings {
+                cache_duration: Duration::from_secs(3600),
+                kid_miss_cooldown: Duration::from_secs(60),
+                stale_grace_period: Duration::from_secs(3600),
+            },
+            reqwest::Client::new(),
+        )
+    }
+
+    async fn seed_cache(store: &JwksStore, duration: Duration) -> Instant {
+        let cache = JwksCache::new(JwkSet { keys: vec![] }, duration);
+        let fetched_at = cache.fetched_at;
+        *store.cache.write().await = Some(cache);
+        fetched_at
+    }
+
+    #[tokio::test]
+    async fn fresh_cache_is_served_without_fetch() {
+        let store = unreachable_store();
+        seed_cache(&store, Duration::from_secs(3600)).await;
+
+        assert!(store.get_jwks(UNREACHABLE_JWKS).await.is_ok());
+
+        let metrics = store.metrics().await;
+        assert_eq!(metrics.cache_hits, 1);
+        assert_eq!(metrics.fetches, 0);
+        assert!(metrics.key_age.is_some());
+    }
+
+    #[tokio::test]
+    async fn kid_miss_within_cooldown_does_not_refetch() {
+        let store = unreachable_store();
+        let fetched_at = seed_cache(&store, Duration::from_secs(3600)).await;
+
+        let jwks = store
+            .refetch_for_unknown_kid(UNREACHABLE_JWKS, "rotated-kid", Some(fetched_at))
+            .await
+            .unwrap();
+        assert!(jwks.find("rotated-kid").is_none());
+
+        let metrics = store.metrics().await;
+        assert_eq!(metrics.kid_miss_throttled, 1);
+        assert_eq!(metrics.kid_miss_refetches, 0);
+        assert_eq!(metrics.fetch_failures, 0);
+    }
+
+    #[tokio::test]
+    async fn kid_miss_uses_newer_cache_without_refetch() {
+        let store = unreachable_store();
+        seed_cache(&store, Duration::from_secs(3600)).await;
+
+        // Caller observed an older key set than the one now cached
+        let result = store
+            .refetch_for_unknown_kid(UNREACHABLE_JWKS, "kid", None)
+            .await;
+        assert!(result.is_ok());
+
+        let metrics = store.metrics().await;
+        assert_eq!(metrics.kid_miss_refetches, 0);
+        assert_eq!(metrics.kid_miss_throttled, 0);
+    }
+
+    #[tokio::test]
+    async fn failed_refresh_serves_stale_keys_within_grace() {
+        let store = unreachable_store();
+        seed_cache(&store, Duration::from_millis(1)).await;
+        tokio::time::sleep(Duration::from_millis(10)).await;
+
+        assert!(store.get_jwks(UNREACHABLE_JWKS).await.is_ok());
+
+        let metrics = store.metrics().await;
+        assert_eq!(metrics.fetch_failures, 1);
+        assert_eq!(metrics.stale_keys_served, 1);
+    }
+
+    #[tokio::test]
+    async fn failed_fetch_without_cache_is_unavailable() {
+        let store = unreachable_store();
+
+        let result = store.get_jwks(UNREACHABLE_JWKS).await;
+        assert!(matches!(result, Err(AuthError::ServiceUnavailable(_))));
+        assert_eq!(store.metrics().await.stale_keys_served, 0);
+    }
+
+    #[tokio::test]
+    async fn failed_fetch_is_not_retried_within_cooldown() {
+        let store = unreachable_store();
+
+        for _ in 0..3 {
+            let result = store.get_jwks(UNREACHABLE_JWKS).await;
+            assert!(matches!(result, Err(AuthError::ServiceUnavailable(_))));
+        }
+
+        let metrics = store.metrics().await;
+        assert_eq!(metrics.fetch_failures, 1);
+        assert_eq!(metrics.failed_fetch_throttled, 2);
+    }
+
+    #[tokio::test]
+    async fn stale_keys_are_served_without_refetch_within_cooldown() {
+        let store = unreachable_store();
+        seed_cache(&store, Duration::from_millis(1)).await;
+        tokio::time::sleep(Duration::from_millis(10)).await;
+
+        assert!(store.get_jwks(UNREACHABLE_JWKS).await.is_ok());
+        assert!(store.get_jwks(UNREACHABLE_JWKS).await.is_ok());
+
+        let metrics = store.metrics().await;
+        assert_eq!(metrics.fetch_failures, 1);
+        assert_eq!(metrics.failed_fetch_throttled, 1);
+        assert_eq!(metrics.stale_keys_served, 2);
+    }
+
+    #[tokio::test]
+    async fn failed_fetch_is_retried_after_cooldown() {
+        let store = JwksStore::new(
+            JwksSettings {
+                cache_duration: Duration::from_secs(3600),
+                kid_miss_cooldown: Duration::ZERO,
+                stale_grace_period: Duration::from_secs(3600),
+            },
+            reqwest::Client::new(),
+        );
+
+        assert!(store.get_jwks(UNREACHABLE_JWKS).await.is_err());
+        assert!(store.get_jwks(UNREACHABLE_JWKS).await.is_err());
+
+        let metrics = store.metrics().await;
+        assert_eq!(metrics.fetch_failures, 2);
+        assert_eq!(metrics.failed_fetch_throttled, 0);
+    }
+
+    #[tokio::test]
+    async fn malformed_token_is_rejected_before_fetching() {
+        let store = unreachable_store();
+
+        let result = store.key_for(UNREACHABLE_JWKS, "not-a-jwt").await;
+        assert!(matches!(result, Err(AuthError::InvalidToken)));
+        assert_eq!(store.metrics().await.fetch_failures, 0);
+    }
+}
@@ -28,7 +28,9 @@ use std::sync::RwLock;
 
 use async_trait::async_trait;
 
-use crate::domain::foundation::{AuthenticatedUser, AuthError, UserId};
+use crate::domain::foundation::{
+    AuthError, AuthenticatedPrincipal, AuthenticatedUser, ServicePrincipal, UserId,
+};
 use crate::ports::{AuthProvider, SessionValidator};
 
 /// Mock session validator for testing.
@@ -38,6 +40,8 @@ use crate::ports::{AuthProvider, SessionValidator};
 pub struct MockSessionValidator {
     /// Map of valid tokens to their associated users
     tokens: RwLock<HashMap<String, AuthenticatedUser>>,
+    /// Map of valid client-credentials tokens to their services
+    service_tokens: RwLock<HashMap<String, ServicePrincipal>>,
     /// Optional error to return for all validations (for error testing)
     force_error: RwLock<Option<AuthError>>,
 }
@@ -70,6 +74,17 @@ impl MockSessionValidator {
         self.with_user(token, user)
     }
 
+    /// Adds a valid client-credentials token that maps to a service.
+    ///
+    /// `validate()` rejects this token; `validate_principal()` returns the service.
+    pub fn with_service(self, token: impl Into<String>, service: ServicePrincipal) -> Self {
+        self.service_tokens
+            .write()
+            .unwrap()
+            .insert(token.into(), service);
+        self
+    }
+
     /// Forces all validations to return the specified error.
     ///
     /// Useful for testing error handling paths.
@@ -115,6 +130,14 @@ impl SessionValidator for MockSessionValidator {
             .cloned()
             .ok_or(AuthError::InvalidToken)
     }
+
+    async fn validate_principal(&self, token: &str) -> Result<AuthenticatedPrincipal, AuthError> {
+        let service = self.service_tokens.read().unwrap().get(token).cloned();
+        match service {
+            Some(service) if self.force_error.read().unwrap().is_none() => Ok(service.into()),
+            _ => self.validate(token).await.map(AuthenticatedPrincipal::User),
+        }
+    }
 }
 
 /// Mock auth provider for testing.
@@ -253,6 +276,21 @@ mod tests {
         assert!(user.email.contains("user-456"));
     }
 
+    #[tokio::test]
+    async fn mock_validator_returns_service_principal_for_service_token() {
+        let service = ServicePrincipal::new("sync-worker", ["sessions:read"]);
+        let validator = MockSessionValidator::new().with_service("m2m-token", service.clone());
+
+        let principal = validator.validate_principal("m2m-token").await.unwrap();
+        assert_eq!(principal.as_service(), Some(&service));
+
+        // Service tokens carry no user
+        assert!(matches!(
+            validator.validate("m2m-token").await,
+            Err(AuthError::InvalidToken)
+        ));
+    }
+
     #[tokio::test]
     async fn mock_validator_with_error_forces_error() {
         let validator = MockSessionValidator::new()
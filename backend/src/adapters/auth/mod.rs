@@ -2,11 +2,20 @@
 //!
 //! Implementations of the `SessionValidator` and `AuthProvider` ports:
 //!
-//! - `mock` - Test implementations that don't require external services
+//! - `mock` - Test implementations that don't require external services (`test-support`)
+//! - `oidc` - Generic OIDC implementation (Keycloak, Auth0, ...) via discovery
 //! - `zitadel` - Production Zitadel OIDC implementation
+//!
+//! Both OIDC validators share JWKS caching and key rotation from `jwks`.
 
+mod jwks;
+#[cfg(any(test, feature = "test-support"))]
 mod mock;
+mod oidc;
 mod zitadel;
 
+pub use jwks::JwksCacheMetrics;
+#[cfg(any(test, feature = "test-support"))]
 pub use mock::{MockAuthProvider, MockSessionValidator};
+pub use oidc::{OidcConfig, OidcSessionValidator};
 pub use zitadel::{ZitadelConfig, ZitadelSessionValidator};
@@ -0,0 +1,720 @@
+//! Generic OIDC adapter for JWT validation.
+//!
+//! Implements the `SessionValidator` port for any OpenID Connect provider
+//! (Keycloak, Auth0, Okta, ...). Unlike the Zitadel adapter, nothing about the
+//! provider is hard-coded:
+//!
+//! 1. The JWKS URL comes from the issuer's discovery document
+//!    (`{issuer}/.well-known/openid-configuration`)
+//! 2. JWT signature, issuer, audience, and expiry are validated (OWASP A07)
+//! 3. User id, email, and name are read from configurable claims
+//!
+//! # Security
+//!
+//! The discovery document's `issuer` must equal the configured issuer exactly,
+//! so a misconfigured or spoofed discovery endpoint cannot redirect key
+//! lookups. Configure the issuer exactly as the provider writes it into `iss`
+//! (Auth0 issuers end with a `/`, Keycloak issuers do not).
+//!
+//! # Claim Mapping
+//!
+//! Claim names are looked up verbatim first, so namespaced claims such as
+//! Auth0's `https://example.com/email` work. Otherwise a dotted name walks
+//! nested objects, e.g. `profile.email`.
+//!
+//! # Service Accounts
+//!
+//! Client-credentials tokens carry no email. A token without the email claim
+//! whose `client_id` (or `azp`) is listed in `service_client_ids` maps to a
+//! `ServicePrincipal`, with scopes from `scope` (space-separated) or `scp`.
+//!
+//! # Example
+//!
+//! ```ignore
+//! let config = OidcConfig::new(
+//!     "https://keycloak.example.com/realms/choice-sherpa",
+//!     "choice-sherpa-api",
+//! )
+//! .with_user_id_claim("preferred_username");
+//!
+//! let validator = Arc::new(OidcSessionValidator::new(config));
+//! validator.spawn_background_refresh();
+//! let user = validator.validate("eyJ...").await?;
+//! ```
+
+use std::sync::{Arc, Weak};
+use std::time::Duration;
+
+use async_trait::async_trait;
+use serde::Deserialize;
+use serde_json::{Map, Value};
+use tokio::sync::OnceCell;
+
+use super::jwks::{self, Audience, JwksCacheMetrics, JwksSettings, JwksStore};
+use crate::domain::foundation::{
+    AuthError, AuthenticatedPrincipal, AuthenticatedUser, ServicePrincipal, Timestamp, UserId,
+};
+use crate::ports::SessionValidator;
+
+/// Configuration for the generic OIDC adapter.
+#[derive(Debug, Clone)]
+pub struct OidcConfig {
+    /// The issuer URL, exactly as it appears in the `iss` claim.
+    pub issuer_url: String,
+
+    /// Expected audience claim in JWTs.
+    pub audience: String,
+
+    /// Claim holding the user ID. Defaults to `sub`.
+    pub user_id_claim: String,
+
+    /// Claim holding the email address. Defaults to `email`.
+    pub email_claim: String,
+
+    /// Claim holding the display name. Defaults to `name`, falling back to
+    /// `preferred_username` when absent.
+    pub name_claim: String,
+
+    /// Claim holding whether the email is verified. Defaults to
+    /// `email_verified`.
+    pub email_verified_claim: String,
+
+    /// Client IDs allowed to authenticate as services with
+    /// client-credentials tokens.
+    pub service_client_ids: Vec<String>,
+
+    /// Optional: How long to cache JWKS before refetching.
+    /// Defaults to 1 hour if not specified.
+    pub jwks_cache_duration: Option<Duration>,
+
+    /// Optional: How often the background task refreshes JWKS.
+    /// Defaults to 80% of the cache duration so keys never expire in-band.
+    pub jwks_refresh_interval: Option<Duration>,
+
+    /// Optional: Minimum time between refetches triggered by an unknown `kid`,
+    /// and before retrying a failed fetch. Defaults to 30 seconds.
+    pub kid_miss_cooldown: Option<Duration>,
+
+    /// Optional: How long past expiry cached keys may be served when the
+    /// provider is unreachable. Defaults to 1 hour.
+    pub stale_grace_period: Option<Duration>,
+}
+
+impl OidcConfig {
+    /// Create a new configuration with standard OIDC claim names.
+    pub fn new(issuer_url: impl Into<String>, audience: impl Into<String>) -> Self {
+        Self {
+            issuer_url: issuer_url.into(),
+            audience: audience.into(),
+            user_id_claim: "sub".to_string(),
+            email_claim: "email".to_string(),
+            name_claim: "name".to_string(),
+            email_verified_claim: "email_verified".to_string(),
+            service_client_ids: Vec::new(),
+            jwks_cache_duration: None,
+            jwks_refresh_interval: None,
+            kid_miss_cooldown: None,
+            stale_grace_period: None,
+        }
+    }
+
+    /// Read the user ID from a different claim.
+    pub fn with_user_id_claim(mut self, claim: impl Into<String>) -> Self {
+        self.user_id_claim = claim.into();
+        self
+    }
+
+    /// Read the email address from a different claim.
+    pub fn with_email_claim(mut self, claim: impl Into<String>) -> Self {
+        self.email_claim = claim.into();
+        self
+    }
+
+    /// Read the display name from a different claim.
+    pub fn with_name_claim(mut self, claim: impl Into<String>) -> Self {
+        self.name_claim = claim.into();
+        self
+    }
+
+    /// Read email verification from a different claim.
+    pub fn with_email_verified_claim(mut self, claim: impl Into<String>) -> Self {
+        self.email_verified_claim = claim.into();
+        self
+    }
+
+    /// Allow a client to authenticate as a service.
+    pub fn with_service_client(mut self, client_id: impl Into<String>) -> Self {
+        self.service_client_ids.push(client_id.into());
+        self
+    }
+
+    /// Set custom JWKS cache duration.
+    pub fn with_cache_duration(mut self, duration: Duration) -> Self {
+        self.jwks_cache_duration = Some(duration);
+        self
+    }
+
+    /// Set custom background refresh interval.
+    pub fn with_refresh_interval(mut self, interval: Duration) -> Self {
+        self.jwks_refresh_interval = Some(interval);
+        self
+    }
+
+    /// Set custom cooldown between `kid`-miss refetches and failed fetches.
+    pub fn with_kid_miss_cooldown(mut self, cooldown: Duration) -> Self {
+        self.kid_miss_cooldown = Some(cooldown);
+        self
+    }
+
+    /// Set custom grace period for serving stale keys.
+    pub fn with_stale_grace_period(mut self, grace: Duration) -> Self {
+        self.stale_grace_period = Some(grace);
+        self
+    }
+
+    fn cache_duration(&self) -> Duration {
+        self.jwks_cache_duration
+            .unwrap_or(Duration::from_secs(3600)) // Default 1 hour
+    }
+
+    fn refresh_interval(&self) -> Duration {
+        self.jwks_refresh_interval
+            .unwrap_or_else(|| self.cache_duration() * 4 / 5)
+    }
+
+    fn jwks_settings(&self) -> JwksSettings {
+        JwksSettings {
+            cache_duration: self.cache_duration(),
+            kid_miss_cooldown: self.kid_miss_cooldown.unwrap_or(Duration::from_secs(30)),
+            stale_grace_period: self.stale_grace_period.unwrap_or(Duration::from_secs(3600)),
+        }
+    }
+
+    /// Get the discovery document URL for this issuer.
+    fn discovery_url(&self) -> String {
+        format!(
+            "{}/.well-known/openid-configuration",
+            self.issuer_url.trim_end_matches('/')
+        )
+    }
+}
+
+/// The parts of the OIDC discovery document this adapter uses.
+#[derive(Debug, Clone, Deserialize)]
+struct OidcDiscovery {
+    issuer: String,
+    jwks_uri: String,
+}
+
+/// Registered claims checked again after validation; everything else is
+/// kept for claim mapping.
+#[derive(Debug, Deserialize)]
+struct OidcClaims {
+    iss: String,
+
+    #[serde(default)]
+    aud: Audience,
+
+    #[serde(flatten)]
+    other: Map<String, Value>,
+}
+
+/// Generic OIDC session validator.
+///
+/// Discovers the provider's JWKS endpoint on first use and validates JWTs
+/// against it, mapping configured claims to `AuthenticatedUser`.
+pub struct OidcSessionValidator {
+    config: OidcConfig,
+    http_client: reqwest::Client,
+    discovery: OnceCell<OidcDiscovery>,
+    jwks: JwksStore,
+}
+
+impl OidcSessionValidator {
+    /// Create a new OIDC validator.
+    ///
+    /// Neither discovery nor JWKS are fetched here - both are fetched lazily
+    /// on first validation to avoid blocking during startup.
+    pub fn new(config: OidcConfig) -> Self {
+        let http_client = reqwest::Client::builder()
+            .timeout(Duration::from_secs(10))
+            .build()
+            .expect("Failed to create HTTP client");
+
+        let jwks = JwksStore::new(config.jwks_settings(), http_client.clone());
+        Self {
+            config,
+            http_client,
+            discovery: OnceCell::new(),
+            jwks,
+        }
+    }
+
+    /// Spawn a background task that refreshes JWKS before the cache expires.
+    ///
+    /// The task holds only a weak reference and exits once the validator is
+    /// dropped. Failed refreshes are logged and retried on the next tick.
+    pub fn spawn_background_refresh(self: &Arc<Self>) -> tokio::task::JoinHandle<()> {
+        let validator: Weak<Self> = Arc::downgrade(self);
+        let interval = self.config.refresh_interval();
+
+        tokio::spawn(async move {
+            let mut ticker = tokio::time::interval(interval);
+            loop {
+                ticker.tick().await;
+
+                let Some(validator) = validator.upgrade() else {
+                    tracing::debug!("JWKS background refresh stopping: validator dropped");
+                    break;
+                };
+
+                let refreshed = match validator.jwks_uri().await {
+                    Ok(jwks_uri) => validator.jwks.refresh(&jwks_uri).await.map(drop),
+                    Err(e) => Err(e),
+                };
+                if let Err(e) = refreshed {
+                    tracing::warn!("Background JWKS refresh failed: {}", e);
+                }
+            }
+        })
+    }
+
+    /// Current JWKS cache metrics.
+    pub async fn metrics(&self) -> JwksCacheMetrics {
+        self.jwks.metrics().await
+    }
+
+    /// JWKS URL from the discovery document, fetched once.
+    ///
+    /// Failed fetches are not cached, so the next validation retries.
+    async fn jwks_uri(&self) -> Result<String, AuthError> {
+        let discovery = self
+            .discovery
+            .get_or_try_init(|| self.fetch_discovery())
+            .await?;
+        Ok(discovery.jwks_uri.clone())
+    }
+
+    /// Fetch and check the discovery document.
+    async fn fetch_discovery(&self) -> Result<OidcDiscovery, AuthError> {
+        let url = self.config.discovery_url();
+
+        tracing::debug!("Fetching OIDC discovery from {}", url);
+
+        let response = self.http_client.get(&url).send().await.map_err(|e| {
+            tracing::error!("Failed to fetch OIDC discovery: {}", e);
+            AuthError::ServiceUnavailable(format!("Failed to fetch OIDC discovery: {}", e))
+        })?;
+
+        if !response.status().is_success() {
+            let status = response.status();
+            tracing::error!("OIDC discovery endpoint returned {}", status);
+            return Err(AuthError::ServiceUnavailable(format!(
+                "OIDC discovery endpoint returned {}",
+                status
+            )));
+        }
+
+        let discovery: OidcDiscovery = response.json().await.map_err(|e| {
+            tracing::error!("Failed to parse OIDC discovery: {}", e);
+            AuthError::ServiceUnavailable(format!("Failed to parse OIDC discovery: {}", e))
+        })?;
+
+        check_discovery(&self.config, discovery)
+    }
+}
+
+/// Reject a discovery document issued for a different issuer.
+fn check_discovery(
+    config: &OidcConfig,
+    discovery: OidcDiscovery,
+) -> Result<OidcDiscovery, AuthError> {
+    // SECURITY: OIDC Discovery 1.0 section 4.3 - issuers must match exactly
+    if discovery.issuer != config.issuer_url {
+        tracing::error!(
+            "OIDC discovery issuer mismatch: expected '{}', got '{}'",
+            config.issuer_url,
+            discovery.issuer
+        );
+        return Err(AuthError::ServiceUnavailable(
+            "OIDC discovery issuer does not match configured issuer".to_string(),
+        ));
+    }
+    Ok(discovery)
+}
+
+/// Look up a claim verbatim, then as a dotted path into nested objects.
+fn claim<'a>(claims: &'a Map<String, Value>, name: &str) -> Option<&'a Value> {
+    claims.get(name).or_else(|| {
+        let mut parts = name.split('.');
+        let mut value = claims.get(parts.next()?)?;
+        for part in parts {
+            value = value.get(part)?;
+        }
+        Some(value)
+    })
+}
+
+/// Non-empty string claim.
+fn string_claim(claims: &Map<String, Value>, name: &str) -> Option<String> {
+    claim(claims, name)
+        .and_then(Value::as_str)
+        .filter(|s| !s.trim().is_empty())
+        .map(str::to_string)
+}
+
+/// Service principal for a client-credentials token from an allowed client.
+fn service_principal(config: &OidcConfig, claims: &Map<String, Value>) -> Option<ServicePrincipal> {
+    // Client-credentials tokens have no email; user tokens always need one
+    if claim(claims, &config.email_claim).is_some() {
+        return None;
+    }
+    let client_id = string_claim(claims, "client_id").or_else(|| string_claim(claims, "azp"))?;
+    if !config.service_client_ids.contains(&client_id) {
+        tracing::warn!(
+            "Client {} is not allowed to authenticate as a service",
+            client_id
+        );
+        return None;
+    }
+
+    let scopes: Vec<String> = match (claims.get("scope"), claims.get("scp")) {
+        (Some(Value::String(scope)), _) => scope.split_whitespace().map(str::to_string).collect(),
+        (_, Some(Value::Array(scp))) => scp
+            .iter()
+            .filter_map(Value::as_str)
+            .map(str::to_string)
+            .collect(),
+        _ => Vec::new(),
+    };
+    Some(ServicePrincipal::new(client_id, scopes))
+}
+
+/// Map validated claims to a domain user.
+fn map_user(
+    config: &OidcConfig,
+    claims: &Map<String, Value>,
+) -> Result<AuthenticatedUser, AuthError> {
+    // Extract email - required for our domain
+    let email = string_claim(claims, &config.email_claim).ok_or_else(|| {
+        tracing::warn!("Token missing '{}' claim", config.email_claim);
+        AuthError::InvalidToken
+    })?;
+
+    let subject = string_claim(claims, &config.user_id_claim).ok_or_else(|| {
+        tracing::warn!("Token missing '{}' claim", config.user_id_claim);
+        AuthError::InvalidToken
+    })?;
+    let user_id = UserId::new(&subject).map_err(|_| {
+        tracing::warn!("Invalid user ID in token: {}", subject);
+        AuthError::InvalidToken
+    })?;
+
+    let display_name = string_claim(claims, &config.name_claim)
+        .or_else(|| string_claim(claims, "preferred_username"));
+    let email_verified = claim(claims, &config.email_verified_claim)
+        .and_then(Value::as_bool)
+        .unwrap_or(false);
+
+    let mut user = AuthenticatedUser::new(user_id, email, display_name, email_verified);
+    if let Some(iat) = claim(claims, "iat").and_then(Value::as_u64) {
+        user = user.with_issued_at(Timestamp::from_unix_secs(iat));
+    }
+    Ok(match string_claim(claims, "locale") {
+        Some(locale) => user.with_locale(locale),
+        None => user,
+    })
+}
+
+impl OidcSessionValidator {
+    /// Validate a token's signature and registered claims.
+    async fn validated_claims(&self, token: &str) -> Result<Map<String, Value>, AuthError> {
+        let jwks_uri = self.jwks_uri().await?;
+
+        // Find the signing key, refetching once if the IdP rotated keys
+        let (decoding_key, algorithm) = self.jwks.key_for(&jwks_uri, token).await?;
+
+        // Validate token and extract claims
+        let token_data = jwks::validate_token::<OidcClaims>(
+            token,
+            &decoding_key,
+            algorithm,
+            &self.config.issuer_url,
+            &self.config.audience,
+        )?;
+        let claims = token_data.claims;
+
+        // SECURITY: Double-check issuer (defense in depth)
+        if claims.iss != self.config.issuer_url {
+            tracing::warn!(
+                "Issuer mismatch after validation: expected '{}', got '{}'",
+                self.config.issuer_url,
+                claims.iss
+            );
+            return Err(AuthError::InvalidToken);
+        }
+
+        // SECURITY: Double-check audience (defense in depth)
+        if !claims.aud.contains(&self.config.audience) {
+            tracing::warn!(
+                "Audience mismatch after validation: expected '{}', got '{:?}'",
+                self.config.audience,
+                claims.aud
+            );
+            return Err(AuthError::InvalidToken);
+        }
+
+        Ok(claims.other)
+    }
+}
+
+#[async_trait]
+impl SessionValidator for OidcSessionValidator {
+    async fn validate(&self, token: &str) -> Result<AuthenticatedUser, AuthError> {
+        map_user(&self.config, &self.validated_claims(token).await?)
+    }
+
+    async fn validate_principal(&self, token: &str) -> Result<AuthenticatedPrincipal, AuthError> {
+        let claims = self.validated_claims(token).await?;
+        match service_principal(&self.config, &claims) {
+            Some(service) => Ok(service.into()),
+            None => map_user(&self.config, &claims).map(AuthenticatedPrincipal::User),
+        }
+    }
+}
+
+impl std::fmt::Debug for OidcSessionValidator {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("OidcSessionValidator")
+            .field("issuer_url", &self.config.issuer_url)
+            .field("audience", &self.config.audience)
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn claims(value: Value) -> Map<String, Value> {
+        match value {
+            Value::Object(map) => map,
+            _ => panic!("claims must be an object"),
+        }
+    }
+
+    // ════════════════════════════════════════════════════════════════════════════
+    // Configuration Tests
+    // ════════════════════════════════════════════════════════════════════════════
+
+    #[test]
+    fn config_uses_standard_claims_by_default() {
+        let config = OidcConfig::new("https://auth.example.com", "my-api");
+        assert_eq!(config.user_id_claim, "sub");
+        assert_eq!(config.email_claim, "email");
+        assert_eq!(config.name_claim, "name");
+        assert_eq!(config.email_verified_claim, "email_verified");
+    }
+
+    #[test]
+    fn config_builds_discovery_url() {
+        let keycloak = OidcConfig::new("https://kc.example.com/realms/app", "my-api");
+        assert_eq!(
+            keycloak.discovery_url(),
+            "https://kc.example.com/realms/app/.well-known/openid-configuration"
+        );
+
+        let auth0 = OidcConfig::new("https://tenant.auth0.com/", "my-api");
+        assert_eq!(
+            auth0.discovery_url(),
+            "https://tenant.auth0.com/.well-known/openid-configuration"
+        );
+    }
+
+    #[test]
+    fn config_refresh_interval_defaults_to_80_percent_of_cache_duration() {
+        let config = OidcConfig::new("https://auth.example.com", "my-api")
+            .with_cache_duration(Duration::from_secs(1000));
+        assert_eq!(config.refresh_interval(), Duration::from_secs(800));
+    }
+
+    // ════════════════════════════════════════════════════════════════════════════
+    // Discovery Tests
+    // ════════════════════════════════════════════════════════════════════════════
+
+    #[test]
+    fn discovery_with_matching_issuer_is_accepted() {
+        let config = OidcConfig::new("https://tenant.auth0.com/", "my-api");
+        let discovery = OidcDiscovery {
+            issuer: "https://tenant.auth0.com/".to_string(),
+            jwks_uri: "https://tenant.auth0.com/.well-known/jwks.json".to_string(),
+        };
+
+        let discovery = check_discovery(&config, discovery).unwrap();
+        assert_eq!(
+            discovery.jwks_uri,
+            "https://tenant.auth0.com/.well-known/jwks.json"
+        );
+    }
+
+    #[test]
+    fn discovery_with_other_issuer_is_rejected() {
+        let config = OidcConfig::new("https://kc.example.com/realms/app", "my-api");
+        let discovery = OidcDiscovery {
+            issuer: "https://kc.example.com/realms/other".to_string(),
+            jwks_uri: "https://kc.example.com/realms/other/protocol/openid-connect/certs"
+                .to_string(),
+        };
+
+        assert!(matches!(
+            check_discovery(&config, discovery),
+            Err(AuthError::ServiceUnavailable(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn unreachable_discovery_is_unavailable_and_retried() {
+        // Port 9 (discard) on localhost refuses connections, so fetches fail fast
+        let validator = OidcSessionValidator::new(OidcConfig::new("http://127.0.0.1:9", "my-api"));
+
+        let result = validator.validate("eyJhbGciOiJSUzI1NiJ9.e30.c2ln").await;
+        assert!(matches!(result, Err(AuthError::ServiceUnavailable(_))));
+        assert!(validator.discovery.get().is_none());
+    }
+
+    // ════════════════════════════════════════════════════════════════════════════
+    // Claim Mapping Tests
+    // ════════════════════════════════════════════════════════════════════════════
+
+    #[test]
+    fn standard_claims_map_to_user() {
+        let config = OidcConfig::new("https://auth.example.com", "my-api");
+        let claims = claims(json!({
+            "sub": "user-123",
+            "email": "alice@example.com",
+            "email_verified": true,
+            "preferred_username": "alice",
+            "locale": "de-DE",
+        }));
+
+        let user = map_user(&config, &claims).unwrap();
+        assert_eq!(user.id.as_str(), "user-123");
+        assert_eq!(user.email, "alice@example.com");
+        assert_eq!(user.display_name.as_deref(), Some("alice"));
+        assert!(user.email_verified);
+        assert_eq!(user.locale.as_deref(), Some("de-DE"));
+    }
+
+    #[test]
+    fn namespaced_claims_are_looked_up_verbatim() {
+        // Auth0 access tokens carry custom claims under a URL namespace
+        let config = OidcConfig::new("https://tenant.auth0.com/", "my-api")
+            .with_email_claim("https://choicesherpa.com/email")
+            .with_name_claim("https://choicesherpa.com/name");
+        let claims = claims(json!({
+            "sub": "auth0|abc123",
+            "https://choicesherpa.com/email": "bob@example.com",
+            "https://choicesherpa.com/name": "Bob",
+        }));
+
+        let user = map_user(&config, &claims).unwrap();
+        assert_eq!(user.id.as_str(), "auth0|abc123");
+        assert_eq!(user.email, "bob@example.com");
+        assert_eq!(user.display_name.as_deref(), Some("Bob"));
+        assert!(!user.email_verified);
+    }
+
+    #[test]
+    fn dotted_claims_walk_nested_objects() {
+        let config = OidcConfig::new("https://auth.example.com", "my-api")
+            .with_user_id_claim("user.id")
+            .with_email_claim("user.contact.email");
+        let claims = claims(json!({
+            "sub": "service-subject",
+            "user": { "id": "u-42", "contact": { "email": "carol@example.com" } },
+        }));
+
+        let user = map_user(&config, &claims).unwrap();
+        assert_eq!(user.id.as_str(), "u-42");
+        assert_eq!(user.email, "carol@example.com");
+    }
+
+    #[test]
+    fn missing_mapped_claims_are_rejected() {
+        let config = OidcConfig::new("https://auth.example.com", "my-api")
+            .with_user_id_claim("preferred_username");
+
+        let no_email = claims(json!({ "sub": "user-1", "preferred_username": "dave" }));
+        assert!(matches!(
+            map_user(&config, &no_email),
+            Err(AuthError::InvalidToken)
+        ));
+
+        let no_user_id = claims(json!({ "sub": "user-1", "email": "dave@example.com" }));
+        assert!(matches!(
+            map_user(&config, &no_user_id),
+            Err(AuthError::InvalidToken)
+        ));
+    }
+
+    // ════════════════════════════════════════════════════════════════════════════
+    // Service Account Tests
+    // ════════════════════════════════════════════════════════════════════════════
+
+    #[test]
+    fn auth0_client_credentials_map_to_service() {
+        let config =
+            OidcConfig::new("https://tenant.auth0.com/", "my-api").with_service_client("worker");
+        let claims = claims(json!({
+            "sub": "worker@clients",
+            "azp": "worker",
+            "gty": "client-credentials",
+            "scope": "sessions:read exports:write",
+        }));
+
+        let service = service_principal(&config, &claims).unwrap();
+        assert_eq!(service.client_id, "worker");
+        assert!(service.has_scope("exports:write"));
+    }
+
+    #[test]
+    fn scp_array_scopes_are_read() {
+        let config =
+            OidcConfig::new("https://auth.example.com", "my-api").with_service_client("worker");
+        let claims = claims(json!({
+            "sub": "worker",
+            "client_id": "worker",
+            "scp": ["sessions:read"],
+        }));
+
+        let service = service_principal(&config, &claims).unwrap();
+        assert_eq!(service.scopes, vec!["sessions:read"]);
+    }
+
+    #[test]
+    fn unlisted_clients_and_user_tokens_are_not_services() {
+        let config =
+            OidcConfig::new("https://auth.example.com", "my-api").with_service_client("worker");
+
+        let unlisted = claims(json!({ "sub": "spa", "azp": "web-frontend" }));
+        assert!(service_principal(&config, &unlisted).is_none());
+
+        let user = claims(json!({
+            "sub": "user-1",
+            "azp": "worker",
+            "email": "user@example.com",
+        }));
+        assert!(service_principal(&config, &user).is_none());
+    }
+
+    // ════════════════════════════════════════════════════════════════════════════
+    // Type Safety Tests
+    // ════════════════════════════════════════════════════════════════════════════
+
+    #[test]
+    fn oidc_validator_is_send_sync() {
+        fn assert_send_sync<T: Send + Sync>() {}
+        assert_send_sync::<OidcSessionValidator>();
+    }
+}
@@ -15,6 +15,18 @@
 //! - **Audience (aud)**: Must contain our application identifier
 //! - **Expiry (exp)**: Must be in the future
 //!
+//! # Service Accounts
+//!
+//! Machine users authenticate with client-credentials tokens, which carry a
+//! `client_id` but no email. Such tokens map to a `ServicePrincipal` only when
+//! the client is listed in `service_client_ids`; `validate` still rejects them.
+//!
+//! # Key Rotation
+//!
+//! JWKS caching, single-flight refresh, `kid`-miss refetch and stale-key
+//! grace are shared with the generic OIDC adapter; see the `jwks` module. An
+//! optional background task refreshes keys before the cache expires.
+//!
 //! # Example
 //!
 //! ```ignore
@@ -31,17 +43,16 @@
 //! let user = validator.validate("eyJ...").await?;
 //! ```
 
-use std::sync::Arc;
-use std::time::{Duration, Instant};
+use std::sync::{Arc, Weak};
+use std::time::Duration;
 
 use async_trait::async_trait;
-use jsonwebtoken::{
-    decode, decode_header, jwk::JwkSet, Algorithm, DecodingKey, TokenData, Validation,
-};
 use serde::{Deserialize, Serialize};
-use tokio::sync::RwLock;
 
-use crate::domain::foundation::{AuthError, AuthenticatedUser, UserId};
+use super::jwks::{self, Audience, JwksCacheMetrics, JwksSettings, JwksStore};
+use crate::domain::foundation::{
+    AuthError, AuthenticatedPrincipal, AuthenticatedUser, ServicePrincipal, Timestamp, UserId,
+};
 use crate::ports::SessionValidator;
 
 /// Configuration for the Zitadel OIDC adapter.
@@ -55,9 +66,25 @@ pub struct ZitadelConfig {
     /// Tokens must contain this audience to be accepted.
     pub audience: String,
 
+    /// Client IDs allowed to authenticate as services with
+    /// client-credentials tokens.
+    pub service_client_ids: Vec<String>,
+
     /// Optional: How long to cache JWKS before refetching.
     /// Defaults to 1 hour if not specified.
     pub jwks_cache_duration: Option<Duration>,
+
+    /// Optional: How often the background task refreshes JWKS.
+    /// Defaults to 80% of the cache duration so keys never expire in-band.
+    pub jwks_refresh_interval: Option<Duration>,
+
+    /// Optional: Minimum time between refetches triggered by an unknown `kid`,
+    /// and before retrying a failed fetch. Defaults to 30 seconds.
+    pub kid_miss_cooldown: Option<Duration>,
+
+    /// Optional: How long past expiry cached keys may be served when Zitadel
+    /// is unreachable. Defaults to 1 hour.
+    pub stale_grace_period: Option<Duration>,
 }
 
 impl ZitadelConfig {
@@ -66,16 +93,70 @@ impl ZitadelConfig {
         Self {
             issuer_url: issuer_url.into(),
             audience: audience.into(),
+            service_client_ids: Vec::new(),
             jwks_cache_duration: None,
+            jwks_refresh_interval: None,
+            kid_miss_cooldown: None,
+            stale_grace_period: None,
         }
     }
 
+    /// Allow a client to authenticate as a service.
+    pub fn with_service_client(mut self, client_id: impl Into<String>) -> Self {
+        self.service_client_ids.push(client_id.into());
+        self
+    }
+
     /// Set custom JWKS cache duration.
     pub fn with_cache_duration(mut self, duration: Duration) -> Self {
         self.jwks_cache_duration = Some(duration);
         self
     }
 
+    /// Set custom background refresh interval.
+    pub fn with_refresh_interval(mut self, interval: Duration) -> Self {
+        self.jwks_refresh_interval = Some(interval);
+        self
+    }
+
+    /// Set custom cooldown between `kid`-miss refetches and failed fetches.
+    pub fn with_kid_miss_cooldown(mut self, cooldown: Duration) -> Self {
+        self.kid_miss_cooldown = Some(cooldown);
+        self
+    }
+
+    /// Set custom grace period for serving stale keys.
+    pub fn with_stale_grace_period(mut self, grace: Duration) -> Self {
+        self.stale_grace_period = Some(grace);
+        self
+    }
+
+    fn cache_duration(&self) -> Duration {
+        self.jwks_cache_duration
+            .unwrap_or(Duration::from_secs(3600)) // Default 1 hour
+    }
+
+    fn refresh_interval(&self) -> Duration {
+        self.jwks_refresh_interval
+            .unwrap_or_else(|| self.cache_duration() * 4 / 5)
+    }
+
+    fn kid_miss_cooldown(&self) -> Duration {
+        self.kid_miss_cooldown.unwrap_or(Duration::from_secs(30))
+    }
+
+    fn stale_grace_period(&self) -> Duration {
+        self.stale_grace_period.unwrap_or(Duration::from_secs(3600))
+    }
+
+    fn jwks_settings(&self) -> JwksSettings {
+        JwksSettings {
+            cache_duration: self.cache_duration(),
+            kid_miss_cooldown: self.kid_miss_cooldown(),
+            stale_grace_period: self.stale_grace_period(),
+        }
+    }
+
     /// Get the JWKS URL for this issuer.
     fn jwks_url(&self) -> String {
         format!("{}/.well-known/jwks.json", self.issuer_url.trim_end_matches('/'))
@@ -117,47 +198,18 @@ struct ZitadelClaims {
     /// User's preferred username
     #[serde(default)]
     preferred_username: Option<String>,
-}
 
-/// Audience can be a single string or array of strings in JWTs.
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
-#[serde(untagged)]
-enum Audience {
-    #[default]
-    None,
-    Single(String),
-    Multiple(Vec<String>),
-}
-
-impl Audience {
-    fn contains(&self, expected: &str) -> bool {
-        match self {
-            Audience::None => false,
-            Audience::Single(s) => s == expected,
-            Audience::Multiple(v) => v.iter().any(|s| s == expected),
-        }
-    }
-}
-
-/// Cached JWKS with expiry tracking.
-struct JwksCache {
-    jwks: JwkSet,
-    fetched_at: Instant,
-    cache_duration: Duration,
-}
+    /// User's preferred locale (BCP 47), from their profile
+    #[serde(default)]
+    locale: Option<String>,
 
-impl JwksCache {
-    fn new(jwks: JwkSet, cache_duration: Duration) -> Self {
-        Self {
-            jwks,
-            fetched_at: Instant::now(),
-            cache_duration,
-        }
-    }
+    /// OAuth client the token was issued to
+    #[serde(default)]
+    client_id: Option<String>,
 
-    fn is_expired(&self) -> bool {
-        self.fetched_at.elapsed() > self.cache_duration
-    }
+    /// Granted scopes, space-separated
+    #[serde(default)]
+    scope: Option<String>,
 }
 
 /// Zitadel OIDC session validator.
@@ -166,8 +218,7 @@ impl JwksCache {
 /// This is the production implementation of `SessionValidator`.
 pub struct ZitadelSessionValidator {
     config: ZitadelConfig,
-    http_client: reqwest::Client,
-    jwks_cache: Arc<RwLock<Option<JwksCache>>>,
+    jwks: JwksStore,
 }
 
 impl ZitadelSessionValidator {
@@ -181,182 +232,54 @@ impl ZitadelSessionValidator {
             .build()
             .expect("Failed to create HTTP client");
 
-        Self {
-            config,
-            http_client,
-            jwks_cache: Arc::new(RwLock::new(None)),
-        }
-    }
-
-    /// Fetch JWKS from Zitadel.
-    async fn fetch_jwks(&self) -> Result<JwkSet, AuthError> {
-        let url = self.config.jwks_url();
-
-        tracing::debug!("Fetching JWKS from {}", url);
-
-        let response = self
-            .http_client
-            .get(&url)
-            .send()
-            .await
-            .map_err(|e| {
-                tracing::error!("Failed to fetch JWKS: {}", e);
-                AuthError::ServiceUnavailable(format!("Failed to fetch JWKS: {}", e))
-            })?;
-
-        if !response.status().is_success() {
-            let status = response.status();
-            tracing::error!("JWKS endpoint returned {}", status);
-            return Err(AuthError::ServiceUnavailable(format!(
-                "JWKS endpoint returned {}",
-                status
-            )));
-        }
-
-        let jwks: JwkSet = response.json().await.map_err(|e| {
-            tracing::error!("Failed to parse JWKS: {}", e);
-            AuthError::ServiceUnavailable(format!("Failed to parse JWKS: {}", e))
-        })?;
-
-        tracing::debug!("Fetched {} keys from JWKS", jwks.keys.len());
-
-        Ok(jwks)
+        let jwks = JwksStore::new(config.jwks_settings(), http_client);
+        Self { config, jwks }
     }
 
-    /// Get JWKS, using cache if available and not expired.
-    async fn get_jwks(&self) -> Result<JwkSet, AuthError> {
-        // Check cache first
-        {
-            let cache = self.jwks_cache.read().await;
-            if let Some(ref cached) = *cache {
-                if !cached.is_expired() {
-                    return Ok(cached.jwks.clone());
-                }
-            }
-        }
-
-        // Cache miss or expired - fetch new JWKS
-        let jwks = self.fetch_jwks().await?;
-
-        // Update cache
-        {
-            let mut cache = self.jwks_cache.write().await;
-            let duration = self
-                .config
-                .jwks_cache_duration
-                .unwrap_or(Duration::from_secs(3600)); // Default 1 hour
-            *cache = Some(JwksCache::new(jwks.clone(), duration));
-        }
-
-        Ok(jwks)
-    }
-
-    /// Find the decoding key for a JWT.
-    fn find_decoding_key(
-        &self,
-        header: &jsonwebtoken::Header,
-        jwks: &JwkSet,
-    ) -> Result<(DecodingKey, Algorithm), AuthError> {
-        // Get the key ID from the JWT header
-        let kid = header.kid.as_ref().ok_or_else(|| {
-            tracing::warn!("JWT missing 'kid' header");
-            AuthError::InvalidToken
-        })?;
-
-        // Find matching key in JWKS
-        let jwk = jwks.find(kid).ok_or_else(|| {
-            tracing::warn!("No matching key found for kid: {}", kid);
-            AuthError::InvalidToken
-        })?;
-
-        // Determine algorithm
-        let algorithm = match jwk.common.key_algorithm {
-            Some(jsonwebtoken::jwk::KeyAlgorithm::RS256) => Algorithm::RS256,
-            Some(jsonwebtoken::jwk::KeyAlgorithm::RS384) => Algorithm::RS384,
-            Some(jsonwebtoken::jwk::KeyAlgorithm::RS512) => Algorithm::RS512,
-            Some(jsonwebtoken::jwk::KeyAlgorithm::ES256) => Algorithm::ES256,
-            Some(jsonwebtoken::jwk::KeyAlgorithm::ES384) => Algorithm::ES384,
-            Some(other) => {
-                tracing::warn!("Unsupported algorithm: {:?}", other);
-                return Err(AuthError::InvalidToken);
-            }
-            None => {
-                // Default to RS256 if not specified (common for OIDC)
-                Algorithm::RS256
-            }
-        };
-
-        // Create decoding key
-        let decoding_key = DecodingKey::from_jwk(jwk).map_err(|e| {
-            tracing::warn!("Failed to create decoding key: {}", e);
-            AuthError::InvalidToken
-        })?;
-
-        Ok((decoding_key, algorithm))
-    }
-
-    /// Validate a JWT and extract claims.
-    fn validate_token(
-        &self,
-        token: &str,
-        decoding_key: &DecodingKey,
-        algorithm: Algorithm,
-    ) -> Result<TokenData<ZitadelClaims>, AuthError> {
-        let mut validation = Validation::new(algorithm);
-
-        // SECURITY (A07): Validate issuer
-        validation.set_issuer(&[&self.config.issuer_url]);
-
-        // SECURITY (A07): Validate audience
-        validation.set_audience(&[&self.config.audience]);
-
-        // SECURITY (A07): Validate expiry (enabled by default)
-        validation.validate_exp = true;
-
-        // Require these claims to be present
-        validation.set_required_spec_claims(&["exp", "iss", "sub"]);
-
-        decode::<ZitadelClaims>(token, decoding_key, &validation).map_err(|e| {
-            use jsonwebtoken::errors::ErrorKind;
-            match e.kind() {
-                ErrorKind::ExpiredSignature => {
-                    tracing::debug!("Token expired");
-                    AuthError::TokenExpired
-                }
-                ErrorKind::InvalidIssuer => {
-                    tracing::warn!("Invalid issuer in token");
-                    AuthError::InvalidToken
-                }
-                ErrorKind::InvalidAudience => {
-                    tracing::warn!("Invalid audience in token");
-                    AuthError::InvalidToken
-                }
-                _ => {
-                    tracing::warn!("Token validation failed: {}", e);
-                    AuthError::InvalidToken
+    /// Spawn a background task that refreshes JWKS before the cache expires.
+    ///
+    /// The task holds only a weak reference and exits once the validator is
+    /// dropped. Failed refreshes are logged and retried on the next tick;
+    /// in-band validation keeps using the cached (or stale) keys meanwhile.
+    pub fn spawn_background_refresh(self: &Arc<Self>) -> tokio::task::JoinHandle<()> {
+        let validator: Weak<Self> = Arc::downgrade(self);
+        let interval = self.config.refresh_interval();
+
+        tokio::spawn(async move {
+            let mut ticker = tokio::time::interval(interval);
+            loop {
+                ticker.tick().await;
+
+                let Some(validator) = validator.upgrade() else {
+                    tracing::debug!("JWKS background refresh stopping: validator dropped");
+                    break;
+                };
+
+                if let Err(e) = validator.jwks.refresh(&validator.config.jwks_url()).await {
+                    tracing::warn!("Background JWKS refresh failed: {}", e);
                 }
             }
         })
     }
-}
-
-#[async_trait]
-impl SessionValidator for ZitadelSessionValidator {
-    async fn validate(&self, token: &str) -> Result<AuthenticatedUser, AuthError> {
-        // Decode header to get key ID
-        let header = decode_header(token).map_err(|e| {
-            tracing::debug!("Failed to decode JWT header: {}", e);
-            AuthError::InvalidToken
-        })?;
 
-        // Get JWKS (cached or fresh)
-        let jwks = self.get_jwks().await?;
+    /// Current JWKS cache metrics.
+    pub async fn metrics(&self) -> JwksCacheMetrics {
+        self.jwks.metrics().await
+    }
 
-        // Find the matching key
-        let (decoding_key, algorithm) = self.find_decoding_key(&header, &jwks)?;
+    /// Validate a token's signature and registered claims.
+    async fn validated_claims(&self, token: &str) -> Result<ZitadelClaims, AuthError> {
+        // Find the signing key, refetching once if the IdP rotated keys
+        let (decoding_key, algorithm) = self.jwks.key_for(&self.config.jwks_url(), token).await?;
 
         // Validate token and extract claims
-        let token_data = self.validate_token(token, &decoding_key, algorithm)?;
+        let token_data = jwks::validate_token::<ZitadelClaims>(
+            token,
+            &decoding_key,
+            algorithm,
+            &self.config.issuer_url,
+            &self.config.audience,
+        )?;
         let claims = token_data.claims;
 
         // SECURITY: Double-check issuer (defense in depth)
@@ -379,24 +302,73 @@ impl SessionValidator for ZitadelSessionValidator {
             return Err(AuthError::InvalidToken);
         }
 
-        // Extract email - required for our domain
-        let email = claims.email.ok_or_else(|| {
-            tracing::warn!("Token missing email claim");
-            AuthError::InvalidToken
-        })?;
-
-        // Create user ID from subject
-        let user_id = UserId::new(&claims.sub).map_err(|_| {
-            tracing::warn!("Invalid user ID in token: {}", claims.sub);
-            AuthError::InvalidToken
-        })?;
-
-        Ok(AuthenticatedUser::new(
-            user_id,
-            email,
-            claims.name.or(claims.preferred_username),
-            claims.email_verified.unwrap_or(false),
-        ))
+        Ok(claims)
+    }
+}
+
+/// Service principal for a client-credentials token from an allowed client.
+fn service_principal(config: &ZitadelConfig, claims: &ZitadelClaims) -> Option<ServicePrincipal> {
+    // Machine users have no email; user tokens always need one
+    if claims.email.is_some() {
+        return None;
+    }
+    let client_id = claims.client_id.as_deref()?;
+    if !config.service_client_ids.iter().any(|id| id == client_id) {
+        tracing::warn!(
+            "Client {} is not allowed to authenticate as a service",
+            client_id
+        );
+        return None;
+    }
+    let scopes = claims
+        .scope
+        .as_deref()
+        .unwrap_or_default()
+        .split_whitespace();
+    Some(ServicePrincipal::new(client_id, scopes))
+}
+
+/// Map validated claims to a domain user.
+fn user_from_claims(claims: ZitadelClaims) -> Result<AuthenticatedUser, AuthError> {
+    // Extract email - required for our domain
+    let email = claims.email.ok_or_else(|| {
+        tracing::warn!("Token missing email claim");
+        AuthError::InvalidToken
+    })?;
+
+    // Create user ID from subject
+    let user_id = UserId::new(&claims.sub).map_err(|_| {
+        tracing::warn!("Invalid user ID in token: {}", claims.sub);
+        AuthError::InvalidToken
+    })?;
+
+    let mut user = AuthenticatedUser::new(
+        user_id,
+        email,
+        claims.name.or(claims.preferred_username),
+        claims.email_verified.unwrap_or(false),
+    );
+    if let Some(iat) = claims.iat.and_then(|iat| u64::try_from(iat).ok()) {
+        user = user.with_issued_at(Timestamp::from_unix_secs(iat));
+    }
+    Ok(match claims.locale.filter(|l| !l.trim().is_empty()) {
+        Some(locale) => user.with_locale(locale),
+        None => user,
+    })
+}
+
+#[async_trait]
+impl SessionValidator for ZitadelSessionValidator {
+    async fn validate(&self, token: &str) -> Result<AuthenticatedUser, AuthError> {
+        user_from_claims(self.validated_claims(token).await?)
+    }
+
+    async fn validate_principal(&self, token: &str) -> Result<AuthenticatedPrincipal, AuthError> {
+        let claims = self.validated_claims(token).await?;
+        match service_principal(&self.config, &claims) {
+            Some(service) => Ok(service.into()),
+            None => user_from_claims(claims).map(AuthenticatedPrincipal::User),
+        }
     }
 }
 
@@ -435,6 +407,24 @@ mod tests {
         );
     }
 
+    #[test]
+    fn config_refresh_interval_defaults_to_80_percent_of_cache_duration() {
+        let config = ZitadelConfig::new("https://auth.example.com", "my-api")
+            .with_cache_duration(Duration::from_secs(1000));
+        assert_eq!(config.refresh_interval(), Duration::from_secs(800));
+    }
+
+    #[test]
+    fn config_with_custom_rotation_settings() {
+        let config = ZitadelConfig::new("https://auth.example.com", "my-api")
+            .with_refresh_interval(Duration::from_secs(120))
+            .with_kid_miss_cooldown(Duration::from_secs(5))
+            .with_stale_grace_period(Duration::from_secs(600));
+        assert_eq!(config.refresh_interval(), Duration::from_secs(120));
+        assert_eq!(config.kid_miss_cooldown(), Duration::from_secs(5));
+        assert_eq!(config.stale_grace_period(), Duration::from_secs(600));
+    }
+
     #[test]
     fn config_with_custom_cache_duration() {
         let config = ZitadelConfig::new("https://auth.example.com", "my-api")
@@ -443,47 +433,66 @@ mod tests {
     }
 
     // ════════════════════════════════════════════════════════════════════════════
-    // Audience Parsing Tests
+    // Service Account Tests
     // ════════════════════════════════════════════════════════════════════════════
 
-    #[test]
-    fn audience_single_string_contains() {
-        let aud = Audience::Single("my-api".to_string());
-        assert!(aud.contains("my-api"));
-        assert!(!aud.contains("other-api"));
+    fn claims(value: serde_json::Value) -> ZitadelClaims {
+        serde_json::from_value(value).unwrap()
     }
 
     #[test]
-    fn audience_multiple_contains() {
-        let aud = Audience::Multiple(vec!["api-1".to_string(), "api-2".to_string()]);
-        assert!(aud.contains("api-1"));
-        assert!(aud.contains("api-2"));
-        assert!(!aud.contains("api-3"));
+    fn allowed_machine_user_maps_to_service() {
+        let config = ZitadelConfig::new("https://auth.example.com", "my-api")
+            .with_service_client("sync-worker");
+        let claims = claims(serde_json::json!({
+            "sub": "machine-1",
+            "iss": "https://auth.example.com",
+            "aud": "my-api",
+            "exp": 0,
+            "client_id": "sync-worker",
+            "scope": "sessions:read cycles:write",
+        }));
+
+        let service = service_principal(&config, &claims).unwrap();
+        assert_eq!(service.client_id, "sync-worker");
+        assert_eq!(service.scopes, vec!["sessions:read", "cycles:write"]);
     }
 
     #[test]
-    fn audience_none_contains_nothing() {
-        let aud = Audience::None;
-        assert!(!aud.contains("anything"));
+    fn unlisted_clients_and_user_tokens_are_not_services() {
+        let config = ZitadelConfig::new("https://auth.example.com", "my-api")
+            .with_service_client("sync-worker");
+
+        let unlisted = claims(serde_json::json!({
+            "sub": "machine-2", "iss": "x", "exp": 0, "client_id": "other-client",
+        }));
+        assert!(service_principal(&config, &unlisted).is_none());
+
+        let user = claims(serde_json::json!({
+            "sub": "user-1", "iss": "x", "exp": 0,
+            "client_id": "sync-worker", "email": "user@example.com",
+        }));
+        assert!(service_principal(&config, &user).is_none());
     }
 
     // ════════════════════════════════════════════════════════════════════════════
-    // JWKS Cache Tests
+    // Key Rotation Tests
     // ════════════════════════════════════════════════════════════════════════════
 
-    #[test]
-    fn jwks_cache_not_expired_initially() {
-        let jwks = JwkSet { keys: vec![] };
-        let cache = JwksCache::new(jwks, Duration::from_secs(3600));
-        assert!(!cache.is_expired());
-    }
+    #[tokio::test]
+    async fn unreachable_issuer_is_unavailable() {
+        // Port 9 (discard) on localhost refuses connections, so fetches fail fast
+        let config = ZitadelConfig::new("http://127.0.0.1:9", "my-api");
+        let validator = ZitadelSessionValidator::new(config);
 
-    #[test]
-    fn jwks_cache_expires_after_duration() {
-        let jwks = JwkSet { keys: vec![] };
-        let cache = JwksCache::new(jwks, Duration::from_millis(1));
-        std::thread::sleep(Duration::from_millis(10));
-        assert!(cache.is_expired());
+        // A well-formed header with a kid, so validation reaches the JWKS fetch
+        let token = "eyJhbGciOiJSUzI1NiIsImtpZCI6ImsxIn0.e30.c2ln";
+        let result = validator.validate(token).await;
+        assert!(matches!(result, Err(AuthError::ServiceUnavailable(_))));
+
+        let metrics = validator.metrics().await;
+        assert_eq!(metrics.fetch_failures, 1);
+        assert!(metrics.key_age.is_none());
     }
 
     // ════════════════════════════════════════════════════════════════════════════
@@ -511,7 +520,10 @@ mod tests {
         let config = ZitadelConfig::new(&issuer, "test-audience");
         let validator = ZitadelSessionValidator::new(config);
 
-        let result = validator.fetch_jwks().await;
+        let result = validator
+            .jwks
+            .fetch_jwks(&validator.config.jwks_url())
+            .await;
         assert!(result.is_ok(), "Failed to fetch JWKS: {:?}", result.err());
 
         let jwks = result.unwrap();
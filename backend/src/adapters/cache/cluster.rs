@@ -0,0 +1,263 @@
+//! Slot routing for Redis Cluster.
+//!
+//! Keys map to one of 16384 hash slots (CRC16 of the key, or of its
+//! `{hash tag}` if it has one). The router reads the slot → node map with
+//! `CLUSTER SLOTS`, keeps one multiplexed connection per primary, and
+//! reloads the map when a node answers `MOVED` or `ASK` during resharding.
+
+use std::collections::HashMap;
+
+use redis::aio::MultiplexedConnection;
+use redis::{ConnectionAddr, ConnectionInfo, ErrorKind, FromRedisValue, IntoConnectionInfo, Value};
+use tokio::sync::{Mutex, RwLock};
+
+use crate::ports::CacheError;
+
+/// Number of hash slots in a Redis Cluster.
+pub const SLOT_COUNT: u16 = 16384;
+
+/// CRC16-CCITT (XMODEM), as specified for cluster key hashing.
+fn crc16(bytes: &[u8]) -> u16 {
+    let mut crc: u16 = 0;
+    for &byte in bytes {
+        crc ^= (byte as u16) << 8;
+        for _ in 0..8 {
+            crc = if crc & 0x8000 != 0 {
+                (crc << 1) ^ 0x1021
+            } else {
+                crc << 1
+            };
+        }
+    }
+    crc
+}
+
+/// Hash slot of a key, honouring `{hash tags}`.
+pub fn key_slot(key: &str) -> u16 {
+    let bytes = key.as_bytes();
+    let hashed = bytes
+        .iter()
+        .position(|&b| b == b'{')
+        .and_then(|open| {
+            let rest = &bytes[open + 1..];
+            rest.iter()
+                .position(|&b| b == b'}')
+                .filter(|&close| close > 0)
+                .map(|close| &rest[..close])
+        })
+        .unwrap_or(bytes);
+    crc16(hashed) % SLOT_COUNT
+}
+
+fn unavailable(e: impl std::fmt::Display) -> CacheError {
+    CacheError::Unavailable(e.to_string())
+}
+
+struct SlotRange {
+    start: u16,
+    end: u16,
+    node: String,
+}
+
+/// Routes commands to the Redis Cluster node owning each key.
+pub struct RedisClusterRouter {
+    seeds: Vec<ConnectionInfo>,
+    slots: RwLock<Vec<SlotRange>>,
+    connections: Mutex<HashMap<String, MultiplexedConnection>>,
+}
+
+impl RedisClusterRouter {
+    /// Connects through the first reachable seed node and loads the slot map.
+    ///
+    /// Credentials and TLS settings of the seed URLs are reused for every node.
+    pub async fn connect(seed_urls: &[&str]) -> Result<Self, CacheError> {
+        let seeds = seed_urls
+            .iter()
+            .map(|url| url.into_connection_info().map_err(unavailable))
+            .collect::<Result<Vec<_>, _>>()?;
+        if seeds.is_empty() {
+            return Err(CacheError::Unavailable(
+                "no Redis Cluster seed nodes configured".to_string(),
+            ));
+        }
+        let router = Self {
+            seeds,
+            slots: RwLock::new(Vec::new()),
+            connections: Mutex::new(HashMap::new()),
+        };
+        router.refresh_slots().await?;
+        Ok(router)
+    }
+
+    /// Runs a single-key command on the node owning `key`, following one
+    /// redirect.
+    pub async fn query<T: FromRedisValue>(
+        &self,
+        key: &str,
+        cmd: &redis::Cmd,
+    ) -> Result<T, CacheError> {
+        let slot = key_slot(key);
+        let mut conn = self.connection_for_slot(slot).await?;
+        match cmd.query_async(&mut conn).await {
+            Ok(value) => Ok(value),
+            Err(e) if matches!(e.kind(), ErrorKind::Moved | ErrorKind::Ask) => {
+                tracing::debug!(slot, error = %e, "Redis Cluster redirect, reloading slots");
+                self.refresh_slots().await?;
+                let mut conn = self.connection_for_slot(slot).await?;
+                cmd.query_async(&mut conn).await.map_err(unavailable)
+            }
+            Err(e) => Err(unavailable(e)),
+        }
+    }
+
+    async fn connection_for_slot(&self, slot: u16) -> Result<MultiplexedConnection, CacheError> {
+        let node = self
+            .slots
+            .read()
+            .await
+            .iter()
+            .find(|r| r.start <= slot && slot <= r.end)
+            .map(|r| r.node.clone())
+            .ok_or_else(|| CacheError::Unavailable(format!("no node serves slot {}", slot)))?;
+        self.connection(&node).await
+    }
+
+    async fn connection(&self, node: &str) -> Result<MultiplexedConnection, CacheError> {
+        let mut connections = self.connections.lock().await;
+        if let Some(conn) = connections.get(node) {
+            return Ok(conn.clone());
+        }
+        let (host, port) = node
+            .rsplit_once(':')
+            .and_then(|(host, port)| Some((host, port.parse::<u16>().ok()?)))
+            .ok_or_else(|| CacheError::Unavailable(format!("invalid node address {}", node)))?;
+        let info = self.node_info(host, port);
+        let conn = redis::Client::open(info)
+            .map_err(unavailable)?
+            .get_multiplexed_tokio_connection()
+            .await
+            .map_err(unavailable)?;
+        connections.insert(node.to_string(), conn.clone());
+        Ok(conn)
+    }
+
+    /// Connection settings for a node, copied from the first seed.
+    fn node_info(&self, host: &str, port: u16) -> ConnectionInfo {
+        let mut info = self.seeds[0].clone();
+        info.addr = match info.addr {
+            ConnectionAddr::TcpTls {
+                insecure,
+                tls_params,
+                ..
+            } => ConnectionAddr::TcpTls {
+                host: host.to_string(),
+                port,
+                insecure,
+                tls_params,
+            },
+            _ => ConnectionAddr::Tcp(host.to_string(), port),
+        };
+        info
+    }
+
+    async fn refresh_slots(&self) -> Result<(), CacheError> {
+        let mut last_error = None;
+        for seed in &self.seeds {
+            let loaded = async {
+                let mut conn = redis::Client::open(seed.clone())
+                    .map_err(unavailable)?
+                    .get_multiplexed_tokio_connection()
+                    .await
+                    .map_err(unavailable)?;
+                let value: Value = redis::cmd("CLUSTER")
+                    .arg("SLOTS")
+                    .query_async(&mut conn)
+                    .await
+                    .map_err(unavailable)?;
+                parse_slots(&value)
+            }
+            .await;
+            match loaded {
+                Ok(ranges) => {
+                    *self.slots.write().await = ranges;
+                    return Ok(());
+                }
+                Err(e) => last_error = Some(e),
+            }
+        }
+        Err(last_error.unwrap_or_else(|| unavailable("no seed nodes")))
+    }
+}
+
+/// Parses a `CLUSTER SLOTS` reply: `[[start, end, [host, port, ..], replicas..], ..]`.
+fn parse_slots(value: &Value) -> Result<Vec<SlotRange>, CacheError> {
+    let malformed = || CacheError::Unavailable("malformed CLUSTER SLOTS reply".to_string());
+    let Value::Bulk(entries) = value else {
+        return Err(malformed());
+    };
+    entries
+        .iter()
+        .map(|entry| {
+            let Value::Bulk(fields) = entry else {
+                return Err(malformed());
+            };
+            let (Some(Value::Int(start)), Some(Value::Int(end)), Some(Value::Bulk(primary))) =
+                (fields.first(), fields.get(1), fields.get(2))
+            else {
+                return Err(malformed());
+            };
+            let (Some(Value::Data(host)), Some(Value::Int(port))) =
+                (primary.first(), primary.get(1))
+            else {
+                return Err(malformed());
+            };
+            Ok(SlotRange {
+                start: *start as u16,
+                end: *end as u16,
+                node: format!("{}:{}", String::from_utf8_lossy(host), port),
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn slots_match_redis_cluster_spec() {
+        assert_eq!(crc16(b"123456789"), 0x31C3);
+        assert_eq!(key_slot("foo"), 12182);
+        // Keys sharing a hash tag land in the same slot
+        assert_eq!(
+            key_slot("{user1000}.following"),
+            key_slot("{user1000}.followers")
+        );
+        // Empty braces are not a hash tag
+        assert_eq!(key_slot("foo{}{bar}"), crc16(b"foo{}{bar}") % SLOT_COUNT);
+    }
+
+    #[test]
+    fn parses_cluster_slots_reply() {
+        let node = |host: &str, port| Value::Bulk(vec![Value::Data(host.into()), Value::Int(port)]);
+        let reply = Value::Bulk(vec![
+            Value::Bulk(vec![
+                Value::Int(0),
+                Value::Int(8191),
+                node("10.0.0.1", 6379),
+            ]),
+            Value::Bulk(vec![
+                Value::Int(8192),
+                Value::Int(16383),
+                node("10.0.0.2", 6380),
+                node("10.0.0.3", 6380),
+            ]),
+        ]);
+
+        let ranges = parse_slots(&reply).unwrap();
+        assert_eq!(ranges.len(), 2);
+        assert_eq!((ranges[1].start, ranges[1].end), (8192, 16383));
+        assert_eq!(ranges[1].node, "10.0.0.2:6380");
+        assert!(parse_slots(&Value::Okay).is_err());
+    }
+}
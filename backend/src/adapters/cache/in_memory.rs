@@ -0,0 +1,147 @@
+//! In-memory cache for testing and single-server deployments.
+
+use std::collections::{HashMap, HashSet};
+use std::time::Instant;
+
+use async_trait::async_trait;
+use tokio::sync::Mutex;
+
+use crate::ports::{Cache, CacheEntryOptions, CacheError};
+
+struct Entry {
+    value: Vec<u8>,
+    expires_at: Instant,
+}
+
+#[derive(Default)]
+struct State {
+    entries: HashMap<String, Entry>,
+    /// Keys stored with each tag. May name keys that have since expired.
+    tags: HashMap<String, HashSet<String>>,
+}
+
+/// In-memory `Cache`. Expired entries are dropped when next read.
+#[derive(Default)]
+pub struct InMemoryCache {
+    state: Mutex<State>,
+}
+
+impl InMemoryCache {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Number of unexpired entries.
+    pub async fn len(&self) -> usize {
+        let now = Instant::now();
+        self.state
+            .lock()
+            .await
+            .entries
+            .values()
+            .filter(|e| e.expires_at > now)
+            .count()
+    }
+
+    pub async fn is_empty(&self) -> bool {
+        self.len().await == 0
+    }
+}
+
+#[async_trait]
+impl Cache for InMemoryCache {
+    async fn get(&self, key: &str) -> Result<Option<Vec<u8>>, CacheError> {
+        let mut state = self.state.lock().await;
+        match state.entries.get(key) {
+            Some(entry) if entry.expires_at > Instant::now() => Ok(Some(entry.value.clone())),
+            Some(_) => {
+                state.entries.remove(key);
+                Ok(None)
+            }
+            None => Ok(None),
+        }
+    }
+
+    async fn set(
+        &self,
+        key: &str,
+        value: Vec<u8>,
+        options: &CacheEntryOptions,
+    ) -> Result<(), CacheError> {
+        let mut state = self.state.lock().await;
+        state.entries.insert(
+            key.to_string(),
+            Entry {
+                value,
+                expires_at: Instant::now() + options.ttl,
+            },
+        );
+        for tag in &options.tags {
+            state
+                .tags
+                .entry(tag.clone())
+                .or_default()
+                .insert(key.to_string());
+        }
+        Ok(())
+    }
+
+    async fn invalidate(&self, key: &str) -> Result<(), CacheError> {
+        self.state.lock().await.entries.remove(key);
+        Ok(())
+    }
+
+    async fn invalidate_tag(&self, tag: &str) -> Result<u64, CacheError> {
+        let mut state = self.state.lock().await;
+        let keys = state.tags.remove(tag).unwrap_or_default();
+        let now = Instant::now();
+        let mut removed = 0;
+        for key in keys {
+            if let Some(entry) = state.entries.remove(&key) {
+                if entry.expires_at > now {
+                    removed += 1;
+                }
+            }
+        }
+        Ok(removed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    #[tokio::test]
+    async fn expires_and_invalidates_by_tag() {
+        let cache = InMemoryCache::new();
+        let minute = CacheEntryOptions::new(Duration::from_secs(60));
+        cache
+            .set("a", b"1".to_vec(), &minute.clone().with_tag("cycle:1"))
+            .await
+            .unwrap();
+        cache
+            .set("b", b"2".to_vec(), &minute.clone().with_tag("cycle:1"))
+            .await
+            .unwrap();
+        cache.set("c", b"3".to_vec(), &minute).await.unwrap();
+        cache
+            .set(
+                "expired",
+                b"4".to_vec(),
+                &CacheEntryOptions::new(Duration::ZERO),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(cache.get("a").await.unwrap(), Some(b"1".to_vec()));
+        assert_eq!(cache.get("expired").await.unwrap(), None);
+
+        assert_eq!(cache.invalidate_tag("cycle:1").await.unwrap(), 2);
+        assert_eq!(cache.get("a").await.unwrap(), None);
+        assert_eq!(cache.get("c").await.unwrap(), Some(b"3".to_vec()));
+
+        cache.invalidate("c").await.unwrap();
+        assert!(cache.is_empty().await);
+    }
+}
@@ -0,0 +1,35 @@
+//! Cache adapters.
+//!
+//! Implementations of the Cache port for different backends.
+//!
+//! ## Available Adapters
+//!
+//! - `InMemoryCache` - In-memory for testing and single-server
+//! - `RedisCache` - Redis-backed for production multi-server, on a single
+//!   node or a Redis Cluster (through `RedisClusterRouter`)
+//!
+//! `ViewCache` layers JSON encoding and a TTL on top for the reader adapters.
+//!
+//! ## Usage
+//!
+//! ```ignore
+//! // Single node
+//! let cache = RedisCache::new(conn);
+//!
+//! // Redis Cluster
+//! let router = RedisClusterRouter::connect(&["redis://10.0.0.1:6379"]).await?;
+//! let cache = RedisCache::cluster(Arc::new(router));
+//!
+//! let reader = PostgresCycleReader::new(pool)
+//!     .with_cache(ViewCache::new(Arc::new(cache)));
+//! ```
+
+mod cluster;
+mod in_memory;
+mod redis;
+mod view_cache;
+
+pub use cluster::{key_slot, RedisClusterRouter};
+pub use in_memory::InMemoryCache;
+pub use redis::RedisCache;
+pub use view_cache::{ViewCache, DEFAULT_VIEW_TTL};
@@ -0,0 +1,163 @@
+//! Redis-backed cache for multi-server deployments, on a single node or a
+//! Redis Cluster.
+//!
+//! # Keys
+//!
+//! ```text
+//! cache:entry:{key}  STRING  cached value, expires with the entry TTL
+//! cache:tag:{tag}    SET     keys stored with the tag
+//! ```
+//!
+//! A tag set lives as long as its longest-lived entry. Every command
+//! touches a single key, so each can be routed to whichever cluster node
+//! owns it.
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use redis::aio::MultiplexedConnection;
+use redis::FromRedisValue;
+
+use crate::ports::{Cache, CacheEntryOptions, CacheError};
+
+use super::cluster::RedisClusterRouter;
+
+/// Adds KEYS[1] to the tag set and extends the set's TTL to ARGV[2] ms if
+/// it would otherwise expire sooner.
+const TAG_SCRIPT: &str = r#"
+redis.call('SADD', KEYS[1], ARGV[1])
+if redis.call('PTTL', KEYS[1]) < tonumber(ARGV[2]) then
+  redis.call('PEXPIRE', KEYS[1], ARGV[2])
+end
+return 1
+"#;
+
+fn entry_key(key: &str) -> String {
+    format!("cache:entry:{}", key)
+}
+
+fn tag_key(tag: &str) -> String {
+    format!("cache:tag:{}", tag)
+}
+
+#[derive(Clone)]
+enum Nodes {
+    Single(MultiplexedConnection),
+    Cluster(Arc<RedisClusterRouter>),
+}
+
+/// Redis-backed `Cache`.
+#[derive(Clone)]
+pub struct RedisCache {
+    nodes: Nodes,
+}
+
+impl RedisCache {
+    /// Cache on a single Redis node (or a primary with replicas).
+    pub fn new(conn: MultiplexedConnection) -> Self {
+        Self {
+            nodes: Nodes::Single(conn),
+        }
+    }
+
+    /// Cache spread across a Redis Cluster.
+    pub fn cluster(router: Arc<RedisClusterRouter>) -> Self {
+        Self {
+            nodes: Nodes::Cluster(router),
+        }
+    }
+
+    async fn query<T: FromRedisValue>(&self, key: &str, cmd: &redis::Cmd) -> Result<T, CacheError> {
+        match &self.nodes {
+            Nodes::Single(conn) => cmd
+                .query_async(&mut conn.clone())
+                .await
+                .map_err(|e| CacheError::Unavailable(e.to_string())),
+            Nodes::Cluster(router) => router.query(key, cmd).await,
+        }
+    }
+
+    /// Deletes keys, returning how many existed.
+    async fn delete(&self, keys: &[String]) -> Result<u64, CacheError> {
+        match &self.nodes {
+            Nodes::Single(_) if !keys.is_empty() => {
+                self.query(&keys[0], redis::cmd("DEL").arg(keys)).await
+            }
+            Nodes::Single(_) => Ok(0),
+            // Keys of one tag can live on different nodes
+            Nodes::Cluster(_) => {
+                let mut deleted = 0;
+                for key in keys {
+                    deleted += self.query::<u64>(key, redis::cmd("DEL").arg(key)).await?;
+                }
+                Ok(deleted)
+            }
+        }
+    }
+}
+
+#[async_trait]
+impl Cache for RedisCache {
+    #[tracing::instrument(name = "RedisCache::get", skip_all, fields(db.system = "redis"))]
+    async fn get(&self, key: &str) -> Result<Option<Vec<u8>>, CacheError> {
+        let key = entry_key(key);
+        self.query(&key, redis::cmd("GET").arg(&key)).await
+    }
+
+    #[tracing::instrument(name = "RedisCache::set", skip_all, fields(db.system = "redis"))]
+    async fn set(
+        &self,
+        key: &str,
+        value: Vec<u8>,
+        options: &CacheEntryOptions,
+    ) -> Result<(), CacheError> {
+        let key = entry_key(key);
+        let ttl_ms = options.ttl.as_millis().max(1) as u64;
+        self.query::<()>(
+            &key,
+            redis::cmd("SET").arg(&key).arg(value).arg("PX").arg(ttl_ms),
+        )
+        .await?;
+        for tag in &options.tags {
+            let tag = tag_key(tag);
+            self.query::<i64>(
+                &tag,
+                redis::cmd("EVAL")
+                    .arg(TAG_SCRIPT)
+                    .arg(1)
+                    .arg(&tag)
+                    .arg(&key)
+                    .arg(ttl_ms),
+            )
+            .await?;
+        }
+        Ok(())
+    }
+
+    #[tracing::instrument(name = "RedisCache::invalidate", skip_all, fields(db.system = "redis"))]
+    async fn invalidate(&self, key: &str) -> Result<(), CacheError> {
+        self.delete(&[entry_key(key)]).await.map(|_| ())
+    }
+
+    #[tracing::instrument(name = "RedisCache::invalidate_tag", skip_all, fields(db.system = "redis"))]
+    async fn invalidate_tag(&self, tag: &str) -> Result<u64, CacheError> {
+        let tag = tag_key(tag);
+        let keys: Vec<String> = self.query(&tag, redis::cmd("SMEMBERS").arg(&tag)).await?;
+        let deleted = self.delete(&keys).await?;
+        self.delete(&[tag]).await?;
+        Ok(deleted)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn entry_and_tag_keys_never_collide() {
+        assert_ne!(entry_key("tag:cycle:1"), tag_key("cycle:1"));
+    }
+
+    // Note: Redis integration tests require a running Redis instance
+    // (or a cluster for `RedisCache::cluster`) and are run separately.
+}
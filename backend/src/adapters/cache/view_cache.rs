@@ -0,0 +1,81 @@
+//! ViewCache - Read-through caching for reader adapters.
+//!
+//! Wraps a `Cache` with a TTL and JSON encoding. Cache failures are logged
+//! and treated as misses, so a cache outage only costs extra queries.
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+use crate::ports::{Cache, CacheEntryOptions, CacheExt};
+
+/// Default lifetime of a cached view. Events invalidate views as they
+/// change; the TTL bounds staleness from writes that publish no event.
+pub const DEFAULT_VIEW_TTL: Duration = Duration::from_secs(60);
+
+/// A cache of serialized read views.
+#[derive(Clone)]
+pub struct ViewCache {
+    cache: Arc<dyn Cache>,
+    ttl: Duration,
+}
+
+impl ViewCache {
+    pub fn new(cache: Arc<dyn Cache>) -> Self {
+        Self {
+            cache,
+            ttl: DEFAULT_VIEW_TTL,
+        }
+    }
+
+    pub fn with_ttl(mut self, ttl: Duration) -> Self {
+        self.ttl = ttl;
+        self
+    }
+
+    /// The cached view, or `None` on a miss or cache failure.
+    pub async fn get<T: DeserializeOwned>(&self, key: &str) -> Option<T> {
+        match self.cache.get_json(key).await {
+            Ok(value) => value,
+            Err(e) => {
+                tracing::warn!(key, error = %e, "View cache read failed");
+                None
+            }
+        }
+    }
+
+    /// Caches a view under the given invalidation tags.
+    pub async fn put<T: Serialize + Sync>(&self, key: &str, value: &T, tags: Vec<String>) {
+        let options = CacheEntryOptions {
+            ttl: self.ttl,
+            tags,
+        };
+        if let Err(e) = self.cache.set_json(key, value, &options).await {
+            tracing::warn!(key, error = %e, "View cache write failed");
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::adapters::cache::InMemoryCache;
+
+    #[tokio::test]
+    async fn round_trips_json_views() {
+        let cache = Arc::new(InMemoryCache::new());
+        let views = ViewCache::new(cache.clone());
+
+        views
+            .put("view", &vec![1, 2, 3], vec!["cycle:1".to_string()])
+            .await;
+        assert_eq!(views.get::<Vec<i32>>("view").await, Some(vec![1, 2, 3]));
+        // A view of another shape is a miss, not an error
+        assert_eq!(views.get::<String>("view").await, None);
+
+        cache.invalidate_tag("cycle:1").await.unwrap();
+        assert_eq!(views.get::<Vec<i32>>("view").await, None);
+    }
+}
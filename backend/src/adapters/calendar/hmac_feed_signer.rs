@@ -0,0 +1,172 @@
+//! HMAC Calendar Feed Signer
+//!
+//! Signs users' calendar feed URLs. Feeds have the form:
+//!
+//! ```text
+//! {base_url}/public/calendar/{hex(user_id)}.{version}.{signature}.ics
+//! ```
+//!
+//! where `signature = HMAC-SHA256(secret, "calendar-feed\n{user_id}\n{version}")`,
+//! made and checked by [`HmacSigning`]. The `calendar-feed` prefix keeps these
+//! signatures from being valid as public document or download signatures
+//! made with the same secret.
+//!
+//! Feed URLs do not expire, because calendar apps poll them indefinitely.
+//! A user retires their URL by rotating to a new feed version; rotating the
+//! secret invalidates every issued feed.
+
+use crate::adapters::storage::{hex_decode, hex_encode, HmacSigning};
+use crate::domain::foundation::UserId;
+use crate::ports::{CalendarFeedError, CalendarFeedSigner, CalendarFeedToken};
+
+/// Path prefix under which calendar feeds are served.
+pub const CALENDAR_FEED_PATH: &str = "/public/calendar";
+
+/// First part of every signed message.
+const PURPOSE: &str = "calendar-feed";
+
+/// Issues HMAC-signed calendar feed URLs.
+pub struct HmacCalendarFeedSigner {
+    signing: HmacSigning,
+    base_url: String,
+}
+
+impl HmacCalendarFeedSigner {
+    /// Create a signer.
+    ///
+    /// # Arguments
+    /// * `secret` - Signing key (at least 32 random bytes recommended)
+    /// * `base_url` - Public origin serving the feeds, e.g. "https://api.choicesherpa.com"
+    pub fn new(secret: impl Into<String>, base_url: impl Into<String>) -> Self {
+        Self {
+            signing: HmacSigning::new(secret),
+            base_url: base_url.into().trim_end_matches('/').to_string(),
+        }
+    }
+
+    fn signature(&self, user_id: &UserId, version: u32) -> String {
+        self.signing
+            .sign(&[PURPOSE, user_id.as_str(), &version.to_string()])
+    }
+}
+
+impl CalendarFeedSigner for HmacCalendarFeedSigner {
+    fn feed_url(&self, user_id: &UserId, version: u32) -> String {
+        format!(
+            "{}{}/{}.{}.{}.ics",
+            self.base_url,
+            CALENDAR_FEED_PATH,
+            hex_encode(user_id.as_str().as_bytes()),
+            version,
+            self.signature(user_id, version)
+        )
+    }
+
+    fn verify(&self, token: &str) -> Result<CalendarFeedToken, CalendarFeedError> {
+        let token = token.strip_suffix(".ics").unwrap_or(token);
+        let mut parts = token.split('.');
+        let (Some(user), Some(version), Some(signature), None) =
+            (parts.next(), parts.next(), parts.next(), parts.next())
+        else {
+            return Err(CalendarFeedError::Malformed);
+        };
+        let user = hex_decode(user)
+            .and_then(|bytes| String::from_utf8(bytes).ok())
+            .ok_or(CalendarFeedError::Malformed)?;
+        let user_id = UserId::new(user).map_err(|_| CalendarFeedError::Malformed)?;
+        let version: u32 = version.parse().map_err(|_| CalendarFeedError::Malformed)?;
+
+        let message = [PURPOSE, user_id.as_str(), &version.to_string()];
+        if !self.signing.verify(&message, signature) {
+            tracing::warn!("Rejected calendar feed with invalid signature");
+            return Err(CalendarFeedError::InvalidSignature);
+        }
+
+        Ok(CalendarFeedToken { user_id, version })
+    }
+}
+
+impl std::fmt::Debug for HmacCalendarFeedSigner {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("HmacCalendarFeedSigner")
+            .field("base_url", &self.base_url)
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn signer() -> HmacCalendarFeedSigner {
+        HmacCalendarFeedSigner::new(
+            "test-secret-that-is-long-enough",
+            "https://api.example.com/",
+        )
+    }
+
+    fn token(url: &str) -> &str {
+        url.rsplit('/').next().unwrap()
+    }
+
+    fn user() -> UserId {
+        UserId::new("user-1").unwrap()
+    }
+
+    #[test]
+    fn issued_feeds_verify() {
+        let signer = signer();
+        let url = signer.feed_url(&user(), 3);
+
+        assert!(url.starts_with("https://api.example.com/public/calendar/"));
+        assert!(url.ends_with(".ics"));
+        assert_eq!(
+            signer.verify(token(&url)),
+            Ok(CalendarFeedToken {
+                user_id: user(),
+                version: 3
+            })
+        );
+    }
+
+    #[test]
+    fn feeds_for_other_users_are_rejected() {
+        let signer = signer();
+        let url = signer.feed_url(&user(), 0);
+        let signature = token(&url).split('.').nth(2).unwrap();
+        let forged = format!("{}.0.{}.ics", hex_encode(b"user-2"), signature);
+
+        assert_eq!(
+            signer.verify(&forged),
+            Err(CalendarFeedError::InvalidSignature)
+        );
+        assert_eq!(signer.verify("nope"), Err(CalendarFeedError::Malformed));
+        assert_eq!(signer.verify("zz.0.00"), Err(CalendarFeedError::Malformed));
+    }
+
+    #[test]
+    fn feeds_for_other_versions_are_rejected() {
+        let signer = signer();
+        let url = signer.feed_url(&user(), 0);
+        let signature = token(&url).split('.').nth(2).unwrap();
+        let forged = format!("{}.1.{}.ics", hex_encode(b"user-1"), signature);
+
+        assert_eq!(
+            signer.verify(&forged),
+            Err(CalendarFeedError::InvalidSignature)
+        );
+    }
+
+    #[test]
+    fn other_secrets_do_not_verify() {
+        let url = signer().feed_url(&user(), 0);
+        let other = HmacCalendarFeedSigner::new(
+            "another-secret-that-is-long-enough",
+            "https://api.example.com",
+        );
+        assert_eq!(
+            other.verify(token(&url)),
+            Err(CalendarFeedError::InvalidSignature)
+        );
+    }
+}
@@ -0,0 +1,177 @@
+//! iCalendar renderer - Implementation of CalendarRenderer.
+//!
+//! Produces RFC 5545 documents with one `VEVENT` per event. Text values are
+//! escaped and long lines folded at 75 octets, as calendar apps reject
+//! documents that break either rule. All-day events use `DATE` values;
+//! others are instants in UTC.
+
+use chrono::{DateTime, Duration, Utc};
+
+use crate::domain::foundation::Timestamp;
+use crate::ports::{CalendarEvent, CalendarRenderer};
+
+/// Identifies the generating product in `PRODID`.
+const PRODUCT_ID: &str = "-//Choice Sherpa//Decision Calendar//EN";
+
+/// RFC 5545 limit on content line length, excluding the line break.
+const MAX_LINE_OCTETS: usize = 75;
+
+/// Renders events as iCalendar.
+#[derive(Debug, Clone, Default)]
+pub struct IcsCalendarRenderer;
+
+impl IcsCalendarRenderer {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl CalendarRenderer for IcsCalendarRenderer {
+    fn render(&self, name: &str, events: &[CalendarEvent]) -> String {
+        let stamp = format_instant(Timestamp::now().as_datetime());
+        let mut lines = vec![
+            "BEGIN:VCALENDAR".to_string(),
+            "VERSION:2.0".to_string(),
+            format!("PRODID:{}", PRODUCT_ID),
+            "CALSCALE:GREGORIAN".to_string(),
+            "METHOD:PUBLISH".to_string(),
+            format!("X-WR-CALNAME:{}", escape(name)),
+        ];
+
+        for event in events {
+            lines.push("BEGIN:VEVENT".to_string());
+            lines.push(format!("UID:{}", escape(&event.uid)));
+            lines.push(format!("DTSTAMP:{}", stamp));
+            let starts_at = event.starts_at.as_datetime();
+            if event.all_day {
+                lines.push(format!("DTSTART;VALUE=DATE:{}", format_date(starts_at)));
+                lines.push(format!(
+                    "DTEND;VALUE=DATE:{}",
+                    format_date(&(*starts_at + Duration::days(1)))
+                ));
+            } else {
+                lines.push(format!("DTSTART:{}", format_instant(starts_at)));
+            }
+            lines.push(format!("SUMMARY:{}", escape(&event.summary)));
+            if !event.description.is_empty() {
+                lines.push(format!("DESCRIPTION:{}", escape(&event.description)));
+            }
+            lines.push("TRANSP:TRANSPARENT".to_string());
+            lines.push("END:VEVENT".to_string());
+        }
+        lines.push("END:VCALENDAR".to_string());
+
+        lines.iter().map(|line| fold(line)).collect()
+    }
+}
+
+fn format_instant(at: &DateTime<Utc>) -> String {
+    at.format("%Y%m%dT%H%M%SZ").to_string()
+}
+
+fn format_date(at: &DateTime<Utc>) -> String {
+    at.format("%Y%m%d").to_string()
+}
+
+/// Escapes a TEXT value.
+fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '\\' => escaped.push_str("\\\\"),
+            ';' => escaped.push_str("\\;"),
+            ',' => escaped.push_str("\\,"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => {}
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// Splits a content line into CRLF-terminated lines of at most 75 octets,
+/// continuation lines starting with a space. Never splits a character.
+fn fold(line: &str) -> String {
+    let mut folded = String::with_capacity(line.len() + line.len() / MAX_LINE_OCTETS * 3 + 2);
+    let mut octets = 0;
+    for c in line.chars() {
+        if octets + c.len_utf8() > MAX_LINE_OCTETS {
+            folded.push_str("\r\n ");
+            // The leading space counts toward the continuation line
+            octets = 1;
+        }
+        folded.push(c);
+        octets += c.len_utf8();
+    }
+    folded.push_str("\r\n");
+    folded
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn at(rfc3339: &str) -> Timestamp {
+        Timestamp::from_datetime(rfc3339.parse().unwrap())
+    }
+
+    fn event(all_day: bool) -> CalendarEvent {
+        CalendarEvent {
+            uid: "deadline-1@choicesherpa.com".to_string(),
+            summary: "Decide: Move to Lisbon?".to_string(),
+            description: "Cost, career; family\nand friends".to_string(),
+            starts_at: at("2026-03-01T17:30:00Z"),
+            all_day,
+        }
+    }
+
+    #[test]
+    fn renders_a_calendar_with_one_event_per_entry() {
+        let ics = IcsCalendarRenderer::new().render("Decisions", &[event(false), event(true)]);
+
+        assert!(ics.starts_with("BEGIN:VCALENDAR\r\nVERSION:2.0\r\n"));
+        assert!(ics.ends_with("END:VCALENDAR\r\n"));
+        assert_eq!(ics.matches("BEGIN:VEVENT").count(), 2);
+        assert!(ics.contains("X-WR-CALNAME:Decisions\r\n"));
+        assert!(ics.contains("UID:deadline-1@choicesherpa.com\r\n"));
+        assert!(ics.contains("SUMMARY:Decide: Move to Lisbon?\r\n"));
+    }
+
+    #[test]
+    fn timed_events_are_utc_instants() {
+        let ics = IcsCalendarRenderer::new().render("Decisions", &[event(false)]);
+        assert!(ics.contains("DTSTART:20260301T173000Z\r\n"));
+        assert!(!ics.contains("DTEND"));
+    }
+
+    #[test]
+    fn all_day_events_span_the_date() {
+        let ics = IcsCalendarRenderer::new().render("Decisions", &[event(true)]);
+        assert!(ics.contains("DTSTART;VALUE=DATE:20260301\r\n"));
+        assert!(ics.contains("DTEND;VALUE=DATE:20260302\r\n"));
+    }
+
+    #[test]
+    fn text_values_are_escaped() {
+        let ics = IcsCalendarRenderer::new().render("Decisions", &[event(false)]);
+        assert!(ics.contains("DESCRIPTION:Cost\\, career\\; family\\nand friends\r\n"));
+    }
+
+    #[test]
+    fn long_lines_are_folded_on_character_boundaries() {
+        let line = format!("SUMMARY:{}", "é".repeat(60));
+        let folded = fold(&line);
+
+        for physical in folded.split("\r\n").filter(|l| !l.is_empty()) {
+            assert!(physical.len() <= MAX_LINE_OCTETS, "{}", physical);
+        }
+        assert_eq!(folded.replace("\r\n ", "").trim_end(), line);
+    }
+
+    #[test]
+    fn empty_calendars_are_valid() {
+        let ics = IcsCalendarRenderer::new().render("Decisions", &[]);
+        assert!(!ics.contains("VEVENT"));
+        assert!(ics.contains("PRODID:-//Choice Sherpa//Decision Calendar//EN\r\n"));
+    }
+}
@@ -0,0 +1,43 @@
+//! In-memory calendar feed versions for testing and development.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use tokio::sync::RwLock;
+
+use crate::domain::foundation::{DomainError, UserId};
+use crate::ports::CalendarFeedVersionRepository;
+
+/// In-memory feed versions keyed by user.
+#[derive(Debug, Clone, Default)]
+pub struct InMemoryCalendarFeedVersionRepository {
+    versions: Arc<RwLock<HashMap<UserId, u32>>>,
+}
+
+impl InMemoryCalendarFeedVersionRepository {
+    /// Create an empty repository.
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait]
+impl CalendarFeedVersionRepository for InMemoryCalendarFeedVersionRepository {
+    async fn current(&self, user_id: &UserId) -> Result<u32, DomainError> {
+        Ok(self
+            .versions
+            .read()
+            .await
+            .get(user_id)
+            .copied()
+            .unwrap_or(0))
+    }
+
+    async fn rotate(&self, user_id: &UserId) -> Result<u32, DomainError> {
+        let mut versions = self.versions.write().await;
+        let version = versions.entry(user_id.clone()).or_insert(0);
+        *version += 1;
+        Ok(*version)
+    }
+}
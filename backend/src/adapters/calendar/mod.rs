@@ -0,0 +1,13 @@
+//! Calendar adapters.
+//!
+//! - `IcsCalendarRenderer` - iCalendar documents for feeds and email attachments
+//! - `HmacCalendarFeedSigner` - Signed, non-expiring calendar feed URLs
+//! - `InMemoryCalendarFeedVersionRepository` - Feed versions in memory (testing/development)
+
+mod hmac_feed_signer;
+mod ics;
+mod in_memory_feed_version_repository;
+
+pub use hmac_feed_signer::{HmacCalendarFeedSigner, CALENDAR_FEED_PATH};
+pub use ics::IcsCalendarRenderer;
+pub use in_memory_feed_version_repository::InMemoryCalendarFeedVersionRepository;
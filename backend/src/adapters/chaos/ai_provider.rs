@@ -0,0 +1,161 @@
+//! ChaosAIProvider - Injects faults into an AI provider.
+//!
+//! | Fault | `complete` | `stream_complete` |
+//! |-------|------------|-------------------|
+//! | Latency | Delayed | Delayed before the stream opens |
+//! | Error | `AIError::unavailable` | `AIError::unavailable` |
+//! | Drop | `AIError::network` | Stream fails after the first chunk |
+//!
+//! Both errors are retryable, so wrapping the primary provider exercises the
+//! failover path in `FailoverAIProvider`.
+
+use async_trait::async_trait;
+use futures::{stream, Stream, StreamExt};
+use std::pin::Pin;
+use std::sync::Arc;
+
+use crate::ports::{
+    AIError, AIProvider, BatchJob, BatchRequest, BatchResult, CompletionRequest,
+    CompletionResponse, ProviderInfo, StreamChunk,
+};
+
+use super::{FaultInjector, FaultTarget, InjectedFault};
+
+/// AI provider decorator driven by a `FaultInjector`.
+pub struct ChaosAIProvider<A> {
+    inner: A,
+    injector: Arc<FaultInjector>,
+}
+
+impl<A: AIProvider> ChaosAIProvider<A> {
+    pub fn new(inner: A, injector: Arc<FaultInjector>) -> Self {
+        Self { inner, injector }
+    }
+}
+
+fn injected_error(operation: &str) -> AIError {
+    AIError::unavailable(format!("injected fault: {}", operation))
+}
+
+#[async_trait]
+impl<A: AIProvider> AIProvider for ChaosAIProvider<A> {
+    async fn complete(&self, request: CompletionRequest) -> Result<CompletionResponse, AIError> {
+        match self
+            .injector
+            .inject(FaultTarget::AiProvider, "complete")
+            .await
+        {
+            Some(InjectedFault::Error) => Err(injected_error("complete")),
+            Some(InjectedFault::Drop) => {
+                Err(AIError::network("injected fault: connection dropped"))
+            }
+            None => self.inner.complete(request).await,
+        }
+    }
+
+    async fn stream_complete(
+        &self,
+        request: CompletionRequest,
+    ) -> Result<Pin<Box<dyn Stream<Item = Result<StreamChunk, AIError>> + Send>>, AIError> {
+        let fault = self
+            .injector
+            .inject(FaultTarget::AiProvider, "stream_complete")
+            .await;
+        match fault {
+            Some(InjectedFault::Error) => Err(injected_error("stream_complete")),
+            Some(InjectedFault::Drop) => {
+                let inner = self.inner.stream_complete(request).await?;
+                let cut =
+                    stream::once(async { Err(AIError::network("injected fault: stream dropped")) });
+                Ok(Box::pin(inner.take(1).chain(cut)))
+            }
+            None => self.inner.stream_complete(request).await,
+        }
+    }
+
+    fn estimate_tokens(&self, text: &str) -> u32 {
+        self.inner.estimate_tokens(text)
+    }
+
+    fn provider_info(&self) -> ProviderInfo {
+        self.inner.provider_info()
+    }
+
+    async fn submit_batch(&self, requests: Vec<BatchRequest>) -> Result<BatchJob, AIError> {
+        self.inner.submit_batch(requests).await
+    }
+
+    async fn batch_status(&self, batch_id: &str) -> Result<BatchJob, AIError> {
+        self.inner.batch_status(batch_id).await
+    }
+
+    async fn batch_results(&self, batch_id: &str) -> Result<Vec<BatchResult>, AIError> {
+        self.inner.batch_results(batch_id).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::adapters::ai::MockAIProvider;
+    use crate::adapters::chaos::{FaultKind, FaultRule};
+    use crate::domain::foundation::{ConversationId, SessionId, UserId};
+    use crate::ports::{MessageRole, RequestMetadata};
+
+    fn make_request() -> CompletionRequest {
+        let metadata = RequestMetadata::new(
+            UserId::new("test-user").unwrap(),
+            SessionId::new(),
+            ConversationId::new(),
+            "trace-123",
+        );
+        CompletionRequest::new(metadata).with_message(MessageRole::User, "Hello")
+    }
+
+    fn provider(fault: Option<FaultRule>) -> ChaosAIProvider<MockAIProvider> {
+        let injector = Arc::new(FaultInjector::new());
+        if let Some(rule) = fault {
+            injector.add_rule(rule).unwrap();
+        }
+        let mock = MockAIProvider::new().with_response("one two three");
+        ChaosAIProvider::new(mock, injector)
+    }
+
+    #[tokio::test]
+    async fn passes_through_without_rules() {
+        let provider = provider(None);
+        assert!(provider.complete(make_request()).await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn error_fault_is_retryable() {
+        let provider = provider(Some(FaultRule::new(
+            FaultTarget::AiProvider,
+            FaultKind::Error,
+        )));
+
+        let err = provider.complete(make_request()).await.unwrap_err();
+
+        assert!(err.is_retryable());
+        assert_eq!(provider.inner.call_count(), 0);
+    }
+
+    #[tokio::test]
+    async fn drop_fault_cuts_stream_after_first_chunk() {
+        let provider = provider(Some(
+            FaultRule::new(FaultTarget::AiProvider, FaultKind::Drop)
+                .for_operation("stream_complete"),
+        ));
+
+        let chunks: Vec<_> = provider
+            .stream_complete(make_request())
+            .await
+            .unwrap()
+            .collect()
+            .await;
+
+        assert_eq!(chunks.len(), 2);
+        assert!(chunks[0].is_ok());
+        assert!(chunks[1].as_ref().unwrap_err().is_retryable());
+    }
+}
@@ -0,0 +1,104 @@
+//! ChaosEventPublisher - Injects faults into event publishing.
+//!
+//! Drop faults acknowledge the publish without delivering the event, which
+//! is the failure the outbox and idempotent handlers exist to survive.
+
+use async_trait::async_trait;
+use std::sync::Arc;
+
+use crate::domain::foundation::{DomainError, ErrorCode, EventEnvelope};
+use crate::ports::EventPublisher;
+
+use super::{FaultInjector, FaultTarget, InjectedFault};
+
+/// Event publisher decorator driven by a `FaultInjector`.
+pub struct ChaosEventPublisher {
+    inner: Arc<dyn EventPublisher>,
+    injector: Arc<FaultInjector>,
+}
+
+impl ChaosEventPublisher {
+    pub fn new(inner: Arc<dyn EventPublisher>, injector: Arc<FaultInjector>) -> Self {
+        Self { inner, injector }
+    }
+}
+
+fn injected_error(operation: &str) -> DomainError {
+    DomainError::new(
+        ErrorCode::ExternalServiceError,
+        format!("injected fault: {}", operation),
+    )
+}
+
+#[async_trait]
+impl EventPublisher for ChaosEventPublisher {
+    async fn publish(&self, event: EventEnvelope) -> Result<(), DomainError> {
+        match self.injector.inject(FaultTarget::EventBus, "publish").await {
+            Some(InjectedFault::Error) => Err(injected_error("publish")),
+            Some(InjectedFault::Drop) => {
+                tracing::warn!(
+                    event_type = %event.event_type,
+                    event_id = %event.event_id,
+                    "Injected fault: event dropped"
+                );
+                Ok(())
+            }
+            None => self.inner.publish(event).await,
+        }
+    }
+
+    async fn publish_all(&self, events: Vec<EventEnvelope>) -> Result<(), DomainError> {
+        match self
+            .injector
+            .inject(FaultTarget::EventBus, "publish_all")
+            .await
+        {
+            Some(InjectedFault::Error) => Err(injected_error("publish_all")),
+            Some(InjectedFault::Drop) => {
+                tracing::warn!(count = events.len(), "Injected fault: events dropped");
+                Ok(())
+            }
+            None => self.inner.publish_all(events).await,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::adapters::chaos::{FaultKind, FaultRule};
+    use crate::adapters::events::InMemoryEventBus;
+    use serde_json::json;
+
+    fn event() -> EventEnvelope {
+        EventEnvelope::new("session.created.v1", "session-1", "Session", json!({}))
+    }
+
+    fn publisher(rule: FaultRule) -> (ChaosEventPublisher, Arc<InMemoryEventBus>) {
+        let bus = Arc::new(InMemoryEventBus::new());
+        let injector = Arc::new(FaultInjector::new());
+        injector.add_rule(rule).unwrap();
+        (ChaosEventPublisher::new(bus.clone(), injector), bus)
+    }
+
+    #[tokio::test]
+    async fn drop_fault_acknowledges_without_delivering() {
+        let (publisher, bus) =
+            publisher(FaultRule::new(FaultTarget::EventBus, FaultKind::Drop).limited_to(1));
+
+        publisher.publish(event()).await.unwrap();
+        publisher.publish(event()).await.unwrap();
+
+        assert_eq!(bus.event_count(), 1);
+    }
+
+    #[tokio::test]
+    async fn error_fault_fails_publish() {
+        let (publisher, bus) = publisher(FaultRule::new(FaultTarget::EventBus, FaultKind::Error));
+
+        let err = publisher.publish_all(vec![event()]).await.unwrap_err();
+
+        assert_eq!(err.code, ErrorCode::ExternalServiceError);
+        assert_eq!(bus.event_count(), 0);
+    }
+}
@@ -0,0 +1,349 @@
+//! Fault rules and the shared injector that evaluates them.
+
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::RwLock;
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+use uuid::Uuid;
+
+use crate::domain::foundation::Timestamp;
+
+/// Longest latency a rule may inject.
+pub const MAX_INJECTED_LATENCY_MS: u64 = 60_000;
+
+/// Adapter family a rule applies to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FaultTarget {
+    AiProvider,
+    Postgres,
+    Redis,
+    EventBus,
+}
+
+/// What happens when a rule fires.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum FaultKind {
+    /// Delay the call, then let it proceed.
+    Latency { ms: u64 },
+    /// Fail the call with the adapter's "unavailable" error.
+    Error,
+    /// Lose the work: events are silently discarded, AI streams are cut
+    /// off mid-response, Postgres connections are closed on checkout.
+    Drop,
+}
+
+/// A fault injection rule.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FaultRule {
+    pub id: String,
+    pub target: FaultTarget,
+    /// Restrict to one operation (e.g. "stream_complete", "publish").
+    /// `None` matches every operation on the target.
+    pub operation: Option<String>,
+    pub fault: FaultKind,
+    /// Chance the rule fires on a matching call (0.0 - 1.0).
+    pub probability: f64,
+    /// Times the rule may still fire; `None` is unlimited.
+    pub remaining: Option<u32>,
+    pub created_at: Timestamp,
+}
+
+impl FaultRule {
+    /// Creates a rule that always fires on every operation of `target`.
+    pub fn new(target: FaultTarget, fault: FaultKind) -> Self {
+        Self {
+            id: Uuid::new_v4().to_string(),
+            target,
+            operation: None,
+            fault,
+            probability: 1.0,
+            remaining: None,
+            created_at: Timestamp::now(),
+        }
+    }
+
+    pub fn for_operation(mut self, operation: impl Into<String>) -> Self {
+        self.operation = Some(operation.into());
+        self
+    }
+
+    pub fn with_probability(mut self, probability: f64) -> Self {
+        self.probability = probability;
+        self
+    }
+
+    /// Stops the rule after it has fired `times` times.
+    pub fn limited_to(mut self, times: u32) -> Self {
+        self.remaining = Some(times);
+        self
+    }
+
+    fn matches(&self, target: FaultTarget, operation: &str) -> bool {
+        let operation_matches = match &self.operation {
+            Some(op) => op == operation,
+            None => true,
+        };
+        self.target == target && operation_matches && self.remaining != Some(0)
+    }
+}
+
+/// Errors from rule management.
+#[derive(Debug, Clone, Error, PartialEq)]
+pub enum ChaosError {
+    #[error("probability must be between 0.0 and 1.0, got {0}")]
+    InvalidProbability(f64),
+
+    #[error("latency must be at most {MAX_INJECTED_LATENCY_MS}ms, got {0}ms")]
+    LatencyTooLong(u64),
+}
+
+/// Fault that should be applied to the current call.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InjectedFault {
+    Error,
+    Drop,
+}
+
+/// Shared rule set consulted by the chaos decorators.
+///
+/// Only build this outside production; the decorators consult it on every
+/// call and have no other kill switch.
+#[derive(Debug, Default)]
+pub struct FaultInjector {
+    rules: RwLock<Vec<FaultRule>>,
+    injected: AtomicU64,
+}
+
+impl FaultInjector {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Validates and adds a rule.
+    pub fn add_rule(&self, rule: FaultRule) -> Result<FaultRule, ChaosError> {
+        if !(0.0..=1.0).contains(&rule.probability) {
+            return Err(ChaosError::InvalidProbability(rule.probability));
+        }
+        if let FaultKind::Latency { ms } = rule.fault {
+            if ms > MAX_INJECTED_LATENCY_MS {
+                return Err(ChaosError::LatencyTooLong(ms));
+            }
+        }
+        self.rules.write().unwrap().push(rule.clone());
+        tracing::warn!(
+            rule_id = %rule.id,
+            target = ?rule.target,
+            fault = ?rule.fault,
+            probability = rule.probability,
+            "Fault injection rule added"
+        );
+        Ok(rule)
+    }
+
+    /// Removes a rule. Returns false if it did not exist.
+    pub fn remove_rule(&self, id: &str) -> bool {
+        let mut rules = self.rules.write().unwrap();
+        let before = rules.len();
+        rules.retain(|r| r.id != id);
+        rules.len() != before
+    }
+
+    /// Removes every rule.
+    pub fn clear(&self) {
+        self.rules.write().unwrap().clear();
+    }
+
+    pub fn rules(&self) -> Vec<FaultRule> {
+        self.rules.read().unwrap().clone()
+    }
+
+    /// Faults injected since startup.
+    pub fn injected_count(&self) -> u64 {
+        self.injected.load(Ordering::Relaxed)
+    }
+
+    /// Picks the faults that fire for this call.
+    fn fire(&self, target: FaultTarget, operation: &str) -> Vec<FaultKind> {
+        // Cheap path for the common case of no rules
+        if self.rules.read().unwrap().is_empty() {
+            return Vec::new();
+        }
+
+        let mut rules = self.rules.write().unwrap();
+        let mut fired = Vec::new();
+        for rule in rules.iter_mut().filter(|r| r.matches(target, operation)) {
+            if roll() >= rule.probability {
+                continue;
+            }
+            if let Some(remaining) = rule.remaining.as_mut() {
+                *remaining -= 1;
+            }
+            fired.push(rule.fault);
+        }
+        fired
+    }
+
+    /// Applies matching rules to a call.
+    ///
+    /// Latency faults are slept here. Returns the first error or drop fault
+    /// for the caller to turn into its adapter-specific failure.
+    pub async fn inject(&self, target: FaultTarget, operation: &str) -> Option<InjectedFault> {
+        let fired = self.fire(target, operation);
+        if fired.is_empty() {
+            return None;
+        }
+        self.injected
+            .fetch_add(fired.len() as u64, Ordering::Relaxed);
+
+        let delay: u64 = fired
+            .iter()
+            .map(|fault| match fault {
+                FaultKind::Latency { ms } => *ms,
+                _ => 0,
+            })
+            .sum();
+        if delay > 0 {
+            tracing::debug!(?target, operation, delay_ms = delay, "Injecting latency");
+            tokio::time::sleep(Duration::from_millis(delay)).await;
+        }
+
+        let fault = fired.iter().find_map(|fault| match fault {
+            FaultKind::Error => Some(InjectedFault::Error),
+            FaultKind::Drop => Some(InjectedFault::Drop),
+            FaultKind::Latency { .. } => None,
+        });
+        if let Some(fault) = fault {
+            tracing::debug!(?target, operation, ?fault, "Injecting fault");
+        }
+        fault
+    }
+}
+
+/// Uniform value in `[0.0, 1.0)`.
+fn roll() -> f64 {
+    (Uuid::new_v4().as_u128() % 1_000_000) as f64 / 1_000_000.0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn no_rules_injects_nothing() {
+        let injector = FaultInjector::new();
+        assert_eq!(
+            injector.inject(FaultTarget::Postgres, "acquire").await,
+            None
+        );
+        assert_eq!(injector.injected_count(), 0);
+    }
+
+    #[tokio::test]
+    async fn rule_matches_target_and_operation() {
+        let injector = FaultInjector::new();
+        injector
+            .add_rule(
+                FaultRule::new(FaultTarget::EventBus, FaultKind::Drop).for_operation("publish"),
+            )
+            .unwrap();
+
+        assert_eq!(
+            injector.inject(FaultTarget::EventBus, "publish").await,
+            Some(InjectedFault::Drop)
+        );
+        assert_eq!(
+            injector.inject(FaultTarget::EventBus, "publish_all").await,
+            None
+        );
+        assert_eq!(injector.inject(FaultTarget::Redis, "publish").await, None);
+    }
+
+    #[tokio::test]
+    async fn limited_rule_stops_firing() {
+        let injector = FaultInjector::new();
+        injector
+            .add_rule(FaultRule::new(FaultTarget::Redis, FaultKind::Error).limited_to(2))
+            .unwrap();
+
+        assert!(injector.inject(FaultTarget::Redis, "check").await.is_some());
+        assert!(injector.inject(FaultTarget::Redis, "check").await.is_some());
+        assert!(injector.inject(FaultTarget::Redis, "check").await.is_none());
+        assert_eq!(injector.injected_count(), 2);
+    }
+
+    #[tokio::test]
+    async fn zero_probability_never_fires() {
+        let injector = FaultInjector::new();
+        injector
+            .add_rule(
+                FaultRule::new(FaultTarget::AiProvider, FaultKind::Error).with_probability(0.0),
+            )
+            .unwrap();
+
+        for _ in 0..50 {
+            assert!(injector
+                .inject(FaultTarget::AiProvider, "complete")
+                .await
+                .is_none());
+        }
+    }
+
+    #[tokio::test]
+    async fn latency_delays_without_failing() {
+        let injector = FaultInjector::new();
+        injector
+            .add_rule(FaultRule::new(
+                FaultTarget::Postgres,
+                FaultKind::Latency { ms: 20 },
+            ))
+            .unwrap();
+
+        let started = tokio::time::Instant::now();
+        assert_eq!(
+            injector.inject(FaultTarget::Postgres, "acquire").await,
+            None
+        );
+        assert!(started.elapsed() >= Duration::from_millis(20));
+    }
+
+    #[test]
+    fn rejects_invalid_rules() {
+        let injector = FaultInjector::new();
+        assert_eq!(
+            injector.add_rule(
+                FaultRule::new(FaultTarget::Redis, FaultKind::Error).with_probability(1.5)
+            ),
+            Err(ChaosError::InvalidProbability(1.5))
+        );
+        assert_eq!(
+            injector.add_rule(FaultRule::new(
+                FaultTarget::Redis,
+                FaultKind::Latency { ms: 120_000 }
+            )),
+            Err(ChaosError::LatencyTooLong(120_000))
+        );
+        assert!(injector.rules().is_empty());
+    }
+
+    #[test]
+    fn remove_and_clear_rules() {
+        let injector = FaultInjector::new();
+        let rule = injector
+            .add_rule(FaultRule::new(FaultTarget::Redis, FaultKind::Error))
+            .unwrap();
+        injector
+            .add_rule(FaultRule::new(FaultTarget::Postgres, FaultKind::Drop))
+            .unwrap();
+
+        assert!(injector.remove_rule(&rule.id));
+        assert!(!injector.remove_rule(&rule.id));
+        assert_eq!(injector.rules().len(), 1);
+
+        injector.clear();
+        assert!(injector.rules().is_empty());
+    }
+}
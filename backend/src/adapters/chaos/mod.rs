@@ -0,0 +1,29 @@
+//! Fault injection adapters for testing environments.
+//!
+//! Decorators that consult a shared `FaultInjector` before delegating, so
+//! circuit breakers, retries, and failover can be exercised against real
+//! adapters. Rules are managed at runtime through the admin chaos API.
+//!
+//! - `FaultInjector` - Rule set deciding which calls get latency, errors, or drops
+//! - `ChaosAIProvider` - `AIProvider` decorator
+//! - `ChaosEventPublisher` - `EventPublisher` decorator
+//! - `ChaosRateLimiter` - `RateLimiter` decorator (Redis faults)
+//! - `with_fault_injection` - `PgPoolOptions` hooks (Postgres faults)
+//!
+//! Only wire these up when `ChaosConfig::enabled` is set; configuration
+//! validation refuses to enable chaos in production.
+
+mod ai_provider;
+mod event_publisher;
+mod injector;
+mod postgres;
+mod rate_limiter;
+
+pub use ai_provider::ChaosAIProvider;
+pub use event_publisher::ChaosEventPublisher;
+pub use injector::{
+    ChaosError, FaultInjector, FaultKind, FaultRule, FaultTarget, InjectedFault,
+    MAX_INJECTED_LATENCY_MS,
+};
+pub use postgres::with_fault_injection;
+pub use rate_limiter::ChaosRateLimiter;
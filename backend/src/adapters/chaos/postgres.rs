@@ -0,0 +1,58 @@
+//! Postgres fault injection via connection pool hooks.
+//!
+//! Repositories hold a `PgPool` rather than a trait object, so faults are
+//! injected at connection checkout instead of by wrapping each repository:
+//!
+//! | Operation | Hook | Latency | Error | Drop |
+//! |-----------|------|---------|-------|------|
+//! | `connect` | `after_connect` | Delayed | Connection fails | Connection fails |
+//! | `acquire` | `before_acquire` | Delayed | Connection closed | Connection closed |
+//!
+//! sqlx only runs `before_acquire` on idle connections and replaces any it
+//! rejects with a fresh one, so `acquire` faults churn the pool; pair them
+//! with `connect` errors to make queries fail outright.
+
+use std::sync::Arc;
+
+use sqlx::postgres::PgPoolOptions;
+
+use super::{FaultInjector, FaultTarget, InjectedFault};
+
+/// Installs fault injection hooks on pool options.
+///
+/// # Example
+///
+/// ```ignore
+/// let options = PgPoolOptions::new().max_connections(10);
+/// let pool = with_fault_injection(options, injector.clone())
+///     .connect(&database_url)
+///     .await?;
+/// ```
+pub fn with_fault_injection(options: PgPoolOptions, injector: Arc<FaultInjector>) -> PgPoolOptions {
+    let on_connect = Arc::clone(&injector);
+    options
+        .after_connect(move |_conn, _meta| {
+            let injector = Arc::clone(&on_connect);
+            Box::pin(async move {
+                match injector.inject(FaultTarget::Postgres, "connect").await {
+                    Some(_) => Err(sqlx::Error::Io(std::io::Error::new(
+                        std::io::ErrorKind::ConnectionRefused,
+                        "injected fault: connect",
+                    ))),
+                    None => Ok(()),
+                }
+            })
+        })
+        .before_acquire(move |_conn, _meta| {
+            let injector = Arc::clone(&injector);
+            Box::pin(async move {
+                match injector.inject(FaultTarget::Postgres, "acquire").await {
+                    Some(InjectedFault::Error) | Some(InjectedFault::Drop) => {
+                        tracing::debug!("Injected fault: pooled connection closed");
+                        Ok(false)
+                    }
+                    None => Ok(true),
+                }
+            })
+        })
+}
@@ -0,0 +1,77 @@
+//! ChaosRateLimiter - Injects Redis faults into the rate limiter.
+//!
+//! The rate limiter is the only Redis consumer, so it is where Redis
+//! outages surface. Error and drop faults both report the backend as
+//! unavailable, exercising the middleware's fail-open path.
+
+use async_trait::async_trait;
+use std::sync::Arc;
+
+use crate::ports::{RateLimitError, RateLimitKey, RateLimitResult, RateLimitStatus, RateLimiter};
+
+use super::{FaultInjector, FaultTarget, InjectedFault};
+
+/// Rate limiter decorator driven by a `FaultInjector`.
+pub struct ChaosRateLimiter {
+    inner: Arc<dyn RateLimiter>,
+    injector: Arc<FaultInjector>,
+}
+
+impl ChaosRateLimiter {
+    pub fn new(inner: Arc<dyn RateLimiter>, injector: Arc<FaultInjector>) -> Self {
+        Self { inner, injector }
+    }
+
+    async fn inject(&self, operation: &str) -> Result<(), RateLimitError> {
+        match self.injector.inject(FaultTarget::Redis, operation).await {
+            Some(InjectedFault::Error) => Err(RateLimitError::Unavailable(format!(
+                "injected fault: {}",
+                operation
+            ))),
+            Some(InjectedFault::Drop) => Err(RateLimitError::Unavailable(
+                "injected fault: connection dropped".to_string(),
+            )),
+            None => Ok(()),
+        }
+    }
+}
+
+#[async_trait]
+impl RateLimiter for ChaosRateLimiter {
+    async fn check(&self, key: RateLimitKey) -> Result<RateLimitResult, RateLimitError> {
+        self.inject("check").await?;
+        self.inner.check(key).await
+    }
+
+    async fn status(&self, key: RateLimitKey) -> Result<RateLimitStatus, RateLimitError> {
+        self.inject("status").await?;
+        self.inner.status(key).await
+    }
+
+    async fn reset(&self, key: RateLimitKey) -> Result<(), RateLimitError> {
+        self.inject("reset").await?;
+        self.inner.reset(key).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::adapters::chaos::{FaultKind, FaultRule};
+    use crate::adapters::rate_limiter::InMemoryRateLimiter;
+
+    #[tokio::test]
+    async fn faults_report_backend_unavailable() {
+        let injector = Arc::new(FaultInjector::new());
+        injector
+            .add_rule(FaultRule::new(FaultTarget::Redis, FaultKind::Error).for_operation("check"))
+            .unwrap();
+        let limiter =
+            ChaosRateLimiter::new(Arc::new(InMemoryRateLimiter::with_defaults()), injector);
+
+        let result = limiter.check(RateLimitKey::ip("10.0.0.1")).await;
+
+        assert!(matches!(result, Err(RateLimitError::Unavailable(_))));
+        assert!(limiter.status(RateLimitKey::ip("10.0.0.1")).await.is_ok());
+    }
+}
@@ -0,0 +1,72 @@
+//! In-memory consent repository for testing and development.
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use tokio::sync::RwLock;
+
+use crate::domain::consent::ConsentRecord;
+use crate::domain::foundation::{DomainError, UserId};
+use crate::ports::ConsentRepository;
+
+/// In-memory append-only consent ledger.
+#[derive(Debug, Clone, Default)]
+pub struct InMemoryConsentRepository {
+    records: Arc<RwLock<Vec<ConsentRecord>>>,
+}
+
+impl InMemoryConsentRepository {
+    /// Create an empty repository.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Total number of records across all users.
+    pub async fn record_count(&self) -> usize {
+        self.records.read().await.len()
+    }
+}
+
+#[async_trait]
+impl ConsentRepository for InMemoryConsentRepository {
+    async fn append(&self, record: &ConsentRecord) -> Result<(), DomainError> {
+        self.records.write().await.push(record.clone());
+        Ok(())
+    }
+
+    async fn list_for_user(&self, user_id: &UserId) -> Result<Vec<ConsentRecord>, DomainError> {
+        let mut records: Vec<ConsentRecord> = self
+            .records
+            .read()
+            .await
+            .iter()
+            .filter(|r| &r.user_id == user_id)
+            .cloned()
+            .collect();
+        records.sort_by_key(|r| r.recorded_at);
+        Ok(records)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::consent::ConsentType;
+
+    #[tokio::test]
+    async fn lists_only_the_users_records() {
+        let repo = InMemoryConsentRepository::new();
+        let alice = UserId::new("alice").unwrap();
+        let bob = UserId::new("bob").unwrap();
+
+        repo.append(&ConsentRecord::grant(alice.clone(), ConsentType::AiProcessing, "1").unwrap())
+            .await
+            .unwrap();
+        repo.append(&ConsentRecord::grant(bob, ConsentType::AiProcessing, "1").unwrap())
+            .await
+            .unwrap();
+
+        assert_eq!(repo.list_for_user(&alice).await.unwrap().len(), 1);
+        assert_eq!(repo.record_count().await, 2);
+    }
+}
@@ -0,0 +1,8 @@
+//! Consent adapters.
+//!
+//! In-memory implementation of the `ConsentRepository` port for tests and
+//! development. The production adapter lives in `adapters::postgres`.
+
+mod in_memory_consent_repository;
+
+pub use in_memory_consent_repository::InMemoryConsentRepository;
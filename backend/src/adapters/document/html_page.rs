@@ -0,0 +1,302 @@
+//! HtmlPageExporter - The decision document as a read-only web page.
+//!
+//! Produces one self-contained HTML page (inline styles, no scripts, no
+//! external requests) for sharing with people outside the app. It shows the
+//! decision, recommendation, objectives, alternatives, consequences table,
+//! and decision quality; the conversation and attachments are left out. The
+//! page asks search engines not to index it.
+
+use std::fmt::Write;
+
+use async_trait::async_trait;
+
+use crate::domain::dashboard::DashboardOverview;
+use crate::ports::{
+    DecisionDocument, DocumentExportError, DocumentExporter, ExportFormat, ExportedDocument,
+};
+
+use super::{download_name, escape_html};
+
+const STYLE: &str = r#"
+body { font-family: -apple-system, "Segoe UI", Helvetica, Arial, sans-serif; color: #222; max-width: 52em; margin: 2em auto; padding: 0 1em; line-height: 1.5; }
+h1 { margin-bottom: 0.2em; }
+.muted { color: #777; }
+table { border-collapse: collapse; width: 100%; margin: 1em 0; }
+th, td { border: 1px solid #ddd; padding: 0.4em 0.6em; text-align: left; }
+th { background: #f5f5f5; }
+td.rating { text-align: center; font-weight: 600; }
+td.positive { background: #e8f5e9; }
+td.neutral { background: #fffde7; }
+td.negative { background: #ffebee; }
+tr.dominated td { color: #999; }
+.recommendation { border-left: 4px solid #3f51b5; padding: 0.5em 1em; background: #f5f7ff; }
+footer { margin-top: 3em; font-size: 0.85em; }
+"#;
+
+/// Renders the decision document as a standalone, read-only HTML page.
+#[derive(Debug, Default, Clone)]
+pub struct HtmlPageExporter;
+
+impl HtmlPageExporter {
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Renders the page.
+    pub fn render(&self, document: &DecisionDocument) -> String {
+        let overview = &document.overview;
+        let generated = document
+            .generated_at
+            .as_datetime()
+            .format("%B %-d, %Y")
+            .to_string();
+
+        let mut html = String::new();
+        let _ = write!(
+            html,
+            r#"<!DOCTYPE html>
+<html lang="en">
+<head>
+<meta charset="utf-8">
+<meta name="viewport" content="width=device-width, initial-scale=1">
+<meta name="robots" content="noindex, nofollow">
+<title>{title}</title>
+<style>{style}</style>
+</head>
+<body>
+<h1>{title}</h1>
+"#,
+            title = escape_html(&overview.session_title),
+            style = STYLE,
+        );
+        if let Some(statement) = &overview.decision_statement {
+            let _ = writeln!(html, "<p>{}</p>", escape_html(statement));
+        }
+
+        recommendation_section(&mut html, overview);
+        objectives_section(&mut html, overview);
+        alternatives_section(&mut html, overview);
+        consequences_section(&mut html, overview);
+        quality_section(&mut html, overview);
+
+        let _ = write!(
+            html,
+            r#"<footer class="muted">Shared from Choice Sherpa on {}. This is a read-only snapshot.</footer>
+</body>
+</html>
+"#,
+            escape_html(&generated)
+        );
+        html
+    }
+}
+
+#[async_trait]
+impl DocumentExporter for HtmlPageExporter {
+    fn format(&self) -> ExportFormat {
+        ExportFormat::Html
+    }
+
+    async fn export(
+        &self,
+        document: &DecisionDocument,
+    ) -> Result<ExportedDocument, DocumentExportError> {
+        Ok(ExportedDocument {
+            format: ExportFormat::Html,
+            file_name: download_name(&document.overview.session_title, ExportFormat::Html),
+            bytes: self.render(document).into_bytes(),
+        })
+    }
+}
+
+// ════════════════════════════════════════════════════════════════════════════
+// Sections
+// ════════════════════════════════════════════════════════════════════════════
+
+fn recommendation_section(html: &mut String, overview: &DashboardOverview) {
+    html.push_str("<h2>Recommendation</h2>\n");
+    let Some(recommendation) = &overview.recommendation else {
+        html.push_str("<p class=\"muted\">No recommendation yet.</p>\n");
+        return;
+    };
+    html.push_str("<div class=\"recommendation\">\n");
+    if let Some(name) = recommendation
+        .standout_name
+        .as_deref()
+        .filter(|_| recommendation.has_standout)
+    {
+        let _ = writeln!(html, "<p><strong>{}</strong></p>", escape_html(name));
+    }
+    let _ = writeln!(
+        html,
+        "<p>{}</p>",
+        escape_html(&recommendation.synthesis_preview)
+    );
+    html.push_str("</div>\n");
+}
+
+fn objectives_section(html: &mut String, overview: &DashboardOverview) {
+    html.push_str("<h2>Objectives</h2>\n");
+    if overview.objectives.is_empty() {
+        html.push_str("<p class=\"muted\">No objectives recorded.</p>\n");
+        return;
+    }
+    html.push_str("<ul>\n");
+    for objective in &overview.objectives {
+        let measure = objective
+            .measure
+            .as_deref()
+            .map(|m| format!(" <span class=\"muted\">({})</span>", escape_html(m)))
+            .unwrap_or_default();
+        let _ = writeln!(
+            html,
+            "<li>{}{}</li>",
+            escape_html(&objective.description),
+            measure
+        );
+    }
+    html.push_str("</ul>\n");
+}
+
+fn alternatives_section(html: &mut String, overview: &DashboardOverview) {
+    html.push_str("<h2>Alternatives</h2>\n");
+    if overview.alternatives.is_empty() {
+        html.push_str("<p class=\"muted\">No alternatives recorded.</p>\n");
+        return;
+    }
+    let mut ranked: Vec<_> = overview.alternatives.iter().collect();
+    ranked.sort_by_key(|a| (a.rank.is_none(), a.rank));
+
+    html.push_str(
+        "<table>\n<thead><tr><th>Rank</th><th>Alternative</th><th>Pugh score</th></tr></thead>\n<tbody>\n",
+    );
+    for alternative in ranked {
+        let note = match (alternative.is_status_quo, alternative.is_dominated) {
+            (true, _) => " <span class=\"muted\">(status quo)</span>",
+            (false, true) => " <span class=\"muted\">(dominated)</span>",
+            _ => "",
+        };
+        let _ = writeln!(
+            html,
+            "<tr{}><td>{}</td><td>{}{}</td><td>{}</td></tr>",
+            if alternative.is_dominated {
+                " class=\"dominated\""
+            } else {
+                ""
+            },
+            alternative
+                .rank
+                .map(|r| r.to_string())
+                .unwrap_or_else(|| "–".to_string()),
+            escape_html(&alternative.name),
+            note,
+            alternative
+                .pugh_score
+                .map(|s| format!("{:+}", s))
+                .unwrap_or_else(|| "–".to_string()),
+        );
+    }
+    html.push_str("</tbody>\n</table>\n");
+}
+
+fn consequences_section(html: &mut String, overview: &DashboardOverview) {
+    let Some(table) = &overview.consequences_table else {
+        return;
+    };
+    html.push_str("<h2>Consequences</h2>\n<table>\n<thead><tr><th>Objective</th>");
+    for name in &table.alternative_names {
+        let _ = write!(html, "<th>{}</th>", escape_html(name));
+    }
+    html.push_str("</tr></thead>\n<tbody>\n");
+    for (objective, row) in table.objective_names.iter().zip(&table.cells) {
+        let _ = write!(html, "<tr><td>{}</td>", escape_html(objective));
+        for cell in row {
+            let class = match cell.rating {
+                r if r > 0 => "positive",
+                0 => "neutral",
+                _ => "negative",
+            };
+            let title = cell
+                .explanation_preview
+                .as_deref()
+                .map(|e| format!(" title=\"{}\"", escape_html(e)))
+                .unwrap_or_default();
+            let _ = write!(
+                html,
+                "<td class=\"rating {}\"{}>{:+}</td>",
+                class, title, cell.rating
+            );
+        }
+        html.push_str("</tr>\n");
+    }
+    html.push_str("</tbody>\n</table>\n<p class=\"muted\">Ratings compare each alternative with the status quo, from −2 (much worse) to +2 (much better).</p>\n");
+}
+
+fn quality_section(html: &mut String, overview: &DashboardOverview) {
+    html.push_str("<h2>Decision Quality</h2>\n");
+    match overview.dq_score {
+        Some(score) => {
+            let _ = writeln!(html, "<p><strong>{}</strong></p>", score);
+        }
+        None => html.push_str("<p class=\"muted\">Decision quality has not been assessed.</p>\n"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::dashboard::{AlternativeSummary, DashboardOverview};
+    use crate::domain::foundation::{CycleId, SessionId, Timestamp};
+
+    fn document() -> DecisionDocument {
+        DecisionDocument {
+            cycle_id: CycleId::new(),
+            overview: DashboardOverview {
+                session_id: SessionId::new(),
+                session_title: "Lisbon <or> Porto?".to_string(),
+                decision_statement: Some("Where should the team relocate?".to_string()),
+                stakeholders: vec![],
+                objectives: vec![],
+                alternatives: vec![AlternativeSummary {
+                    id: "lisbon".to_string(),
+                    name: "Lisbon".to_string(),
+                    is_status_quo: false,
+                    pugh_score: Some(3),
+                    rank: Some(1),
+                    is_dominated: false,
+                }],
+                consequences_table: None,
+                key_uncertainties: vec![],
+                recommendation: None,
+                dq_score: None,
+                active_cycle_id: None,
+                cycle_count: 1,
+                last_updated: chrono::Utc::now(),
+            },
+            cycle_tree: None,
+            organization: None,
+            attachments: Vec::new(),
+            generated_at: Timestamp::now(),
+            reading_level: Default::default(),
+        }
+    }
+
+    #[tokio::test]
+    async fn exports_a_noindex_page_without_scripts() {
+        let exported = HtmlPageExporter::new().export(&document()).await.unwrap();
+
+        assert_eq!(exported.format, ExportFormat::Html);
+        assert_eq!(exported.file_name, "lisbon-or-porto.html");
+        let html = String::from_utf8(exported.bytes).unwrap();
+        assert!(html.contains(r#"<meta name="robots" content="noindex, nofollow">"#));
+        assert!(!html.contains("<script"));
+        assert!(html.contains("<td>Lisbon</td>"));
+    }
+
+    #[test]
+    fn escapes_user_content() {
+        let html = HtmlPageExporter::new().render(&document());
+        assert!(html.contains("Lisbon &lt;or&gt; Porto?"));
+        assert!(!html.contains("<or>"));
+    }
+}
@@ -0,0 +1,93 @@
+//! In-memory attachment repository for testing and development.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use tokio::sync::RwLock;
+
+use crate::domain::document::Attachment;
+use crate::domain::foundation::{AttachmentId, CycleId, DomainError};
+use crate::ports::AttachmentRepository;
+
+/// In-memory attachment metadata keyed by ID.
+#[derive(Debug, Clone, Default)]
+pub struct InMemoryAttachmentRepository {
+    attachments: Arc<RwLock<HashMap<AttachmentId, Attachment>>>,
+}
+
+impl InMemoryAttachmentRepository {
+    /// Create an empty repository.
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait]
+impl AttachmentRepository for InMemoryAttachmentRepository {
+    async fn save(&self, attachment: &Attachment) -> Result<(), DomainError> {
+        self.attachments
+            .write()
+            .await
+            .insert(attachment.id, attachment.clone());
+        Ok(())
+    }
+
+    async fn find_by_id(&self, id: &AttachmentId) -> Result<Option<Attachment>, DomainError> {
+        Ok(self.attachments.read().await.get(id).cloned())
+    }
+
+    async fn list_by_cycle(&self, cycle_id: &CycleId) -> Result<Vec<Attachment>, DomainError> {
+        let mut attachments: Vec<Attachment> = self
+            .attachments
+            .read()
+            .await
+            .values()
+            .filter(|a| &a.cycle_id == cycle_id)
+            .cloned()
+            .collect();
+        attachments.sort_by_key(|a| a.uploaded_at);
+        Ok(attachments)
+    }
+
+    async fn delete(&self, id: &AttachmentId) -> Result<(), DomainError> {
+        self.attachments.write().await.remove(id);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::foundation::{ComponentType, UserId};
+
+    fn attachment(cycle_id: CycleId) -> Attachment {
+        Attachment::new(
+            cycle_id,
+            ComponentType::Alternatives,
+            "board.png",
+            "image/png",
+            10,
+            None,
+            UserId::new("user-1").unwrap(),
+        )
+        .unwrap()
+    }
+
+    #[tokio::test]
+    async fn lists_only_the_cycles_attachments() {
+        let repo = InMemoryAttachmentRepository::new();
+        let cycle_id = CycleId::new();
+        let mine = attachment(cycle_id);
+        repo.save(&mine).await.unwrap();
+        repo.save(&attachment(CycleId::new())).await.unwrap();
+
+        assert_eq!(
+            repo.list_by_cycle(&cycle_id).await.unwrap(),
+            vec![mine.clone()]
+        );
+
+        repo.delete(&mine.id).await.unwrap();
+        assert!(repo.find_by_id(&mine.id).await.unwrap().is_none());
+    }
+}
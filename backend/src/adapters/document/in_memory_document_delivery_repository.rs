@@ -0,0 +1,153 @@
+//! In-memory document delivery and email preference repositories for testing
+//! and development.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use tokio::sync::RwLock;
+
+use crate::domain::document::{DocumentDelivery, DocumentEmailPreference};
+use crate::domain::foundation::{CycleId, DocumentDeliveryId, DomainError, Timestamp, UserId};
+use crate::ports::{DocumentDeliveryRepository, DocumentEmailPreferenceRepository};
+
+/// In-memory document deliveries keyed by ID.
+#[derive(Debug, Clone, Default)]
+pub struct InMemoryDocumentDeliveryRepository {
+    deliveries: Arc<RwLock<HashMap<DocumentDeliveryId, DocumentDelivery>>>,
+}
+
+impl InMemoryDocumentDeliveryRepository {
+    /// Create an empty repository.
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait]
+impl DocumentDeliveryRepository for InMemoryDocumentDeliveryRepository {
+    async fn save(&self, delivery: &DocumentDelivery) -> Result<(), DomainError> {
+        self.deliveries
+            .write()
+            .await
+            .insert(delivery.id, delivery.clone());
+        Ok(())
+    }
+
+    async fn find_by_id(
+        &self,
+        id: &DocumentDeliveryId,
+    ) -> Result<Option<DocumentDelivery>, DomainError> {
+        Ok(self.deliveries.read().await.get(id).cloned())
+    }
+
+    async fn list_for_cycle(
+        &self,
+        cycle_id: &CycleId,
+        user_id: &UserId,
+    ) -> Result<Vec<DocumentDelivery>, DomainError> {
+        let mut deliveries: Vec<DocumentDelivery> = self
+            .deliveries
+            .read()
+            .await
+            .values()
+            .filter(|d| &d.cycle_id == cycle_id && &d.user_id == user_id)
+            .cloned()
+            .collect();
+        deliveries.sort_by_key(|d| d.created_at);
+        Ok(deliveries)
+    }
+
+    async fn list_due(
+        &self,
+        now: Timestamp,
+        limit: u32,
+    ) -> Result<Vec<DocumentDelivery>, DomainError> {
+        let mut due: Vec<DocumentDelivery> = self
+            .deliveries
+            .read()
+            .await
+            .values()
+            .filter(|d| d.is_due(now))
+            .cloned()
+            .collect();
+        due.sort_by_key(|d| d.next_attempt_at);
+        due.truncate(limit as usize);
+        Ok(due)
+    }
+}
+
+/// In-memory document email preferences keyed by user.
+#[derive(Debug, Clone, Default)]
+pub struct InMemoryDocumentEmailPreferenceRepository {
+    preferences: Arc<RwLock<HashMap<UserId, DocumentEmailPreference>>>,
+}
+
+impl InMemoryDocumentEmailPreferenceRepository {
+    /// Create an empty repository.
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait]
+impl DocumentEmailPreferenceRepository for InMemoryDocumentEmailPreferenceRepository {
+    async fn get(&self, user_id: &UserId) -> Result<Option<DocumentEmailPreference>, DomainError> {
+        Ok(self.preferences.read().await.get(user_id).cloned())
+    }
+
+    async fn set(&self, preference: &DocumentEmailPreference) -> Result<(), DomainError> {
+        self.preferences
+            .write()
+            .await
+            .insert(preference.user_id.clone(), preference.clone());
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn lists_only_due_pending_deliveries() {
+        let repo = InMemoryDocumentDeliveryRepository::new();
+        let user = UserId::new("user-1").unwrap();
+        let due = DocumentDelivery::new(CycleId::new(), user.clone(), "jo@example.com");
+        let mut later = DocumentDelivery::new(CycleId::new(), user.clone(), "jo@example.com");
+        later.record_failure("timeout", true);
+        let mut sent = DocumentDelivery::new(CycleId::new(), user, "jo@example.com");
+        sent.record_sent();
+        for delivery in [&due, &later, &sent] {
+            repo.save(delivery).await.unwrap();
+        }
+
+        let listed = repo.list_due(Timestamp::now(), 10).await.unwrap();
+
+        assert_eq!(listed, vec![due]);
+    }
+
+    #[tokio::test]
+    async fn preferences_are_replaced() {
+        let repo = InMemoryDocumentEmailPreferenceRepository::new();
+        let user = UserId::new("user-1").unwrap();
+        repo.set(&DocumentEmailPreference::new(
+            user.clone(),
+            "a@example.com",
+            true,
+        ))
+        .await
+        .unwrap();
+        repo.set(&DocumentEmailPreference::new(
+            user.clone(),
+            "b@example.com",
+            false,
+        ))
+        .await
+        .unwrap();
+
+        let preference = repo.get(&user).await.unwrap().unwrap();
+        assert_eq!(preference.email, "b@example.com");
+        assert!(!preference.enabled);
+    }
+}
@@ -0,0 +1,105 @@
+//! In-memory document publication repository for testing and development.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use tokio::sync::RwLock;
+
+use crate::domain::document::DocumentPublication;
+use crate::domain::foundation::{CycleId, DomainError, ErrorCode, PublicationId};
+use crate::ports::DocumentPublicationRepository;
+
+/// In-memory publications keyed by ID.
+#[derive(Debug, Clone, Default)]
+pub struct InMemoryDocumentPublicationRepository {
+    publications: Arc<RwLock<HashMap<PublicationId, DocumentPublication>>>,
+}
+
+impl InMemoryDocumentPublicationRepository {
+    /// Create an empty repository.
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait]
+impl DocumentPublicationRepository for InMemoryDocumentPublicationRepository {
+    async fn save(&self, publication: &DocumentPublication) -> Result<(), DomainError> {
+        self.publications
+            .write()
+            .await
+            .insert(publication.id, publication.clone());
+        Ok(())
+    }
+
+    async fn update(&self, publication: &DocumentPublication) -> Result<(), DomainError> {
+        let mut publications = self.publications.write().await;
+        match publications.get_mut(&publication.id) {
+            Some(existing) => {
+                *existing = publication.clone();
+                Ok(())
+            }
+            None => Err(DomainError::new(
+                ErrorCode::NotFound,
+                format!("Publication {} not found", publication.id),
+            )),
+        }
+    }
+
+    async fn find_by_id(
+        &self,
+        id: &PublicationId,
+    ) -> Result<Option<DocumentPublication>, DomainError> {
+        Ok(self.publications.read().await.get(id).cloned())
+    }
+
+    async fn list_by_cycle(
+        &self,
+        cycle_id: &CycleId,
+    ) -> Result<Vec<DocumentPublication>, DomainError> {
+        let mut publications: Vec<DocumentPublication> = self
+            .publications
+            .read()
+            .await
+            .values()
+            .filter(|p| &p.cycle_id == cycle_id)
+            .cloned()
+            .collect();
+        publications.sort_by_key(|p| std::cmp::Reverse(p.created_at));
+        Ok(publications)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::foundation::UserId;
+
+    fn publication(cycle_id: CycleId) -> DocumentPublication {
+        DocumentPublication::new(
+            cycle_id,
+            UserId::new("user-1").unwrap(),
+            "Move?",
+            "<html></html>",
+            7,
+        )
+    }
+
+    #[tokio::test]
+    async fn stores_revocations_and_lists_by_cycle() {
+        let repo = InMemoryDocumentPublicationRepository::new();
+        let cycle_id = CycleId::new();
+        let mut published = publication(cycle_id);
+        repo.save(&published).await.unwrap();
+        repo.save(&publication(CycleId::new())).await.unwrap();
+
+        published.revoke();
+        repo.update(&published).await.unwrap();
+
+        let listed = repo.list_by_cycle(&cycle_id).await.unwrap();
+        assert_eq!(listed.len(), 1);
+        assert!(listed[0].is_revoked());
+        assert!(repo.update(&publication(cycle_id)).await.is_err());
+    }
+}
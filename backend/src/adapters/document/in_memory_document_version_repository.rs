@@ -0,0 +1,104 @@
+//! In-memory document version repository for testing and development.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use tokio::sync::RwLock;
+
+use crate::domain::document::DocumentVersion;
+use crate::domain::foundation::{CycleId, DomainError, ErrorCode};
+use crate::ports::DocumentVersionRepository;
+
+/// In-memory document versions keyed by cycle and version number.
+#[derive(Debug, Clone, Default)]
+pub struct InMemoryDocumentVersionRepository {
+    versions: Arc<RwLock<HashMap<(CycleId, u32), DocumentVersion>>>,
+}
+
+impl InMemoryDocumentVersionRepository {
+    /// Create an empty repository.
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait]
+impl DocumentVersionRepository for InMemoryDocumentVersionRepository {
+    async fn save(&self, version: &DocumentVersion) -> Result<(), DomainError> {
+        let mut versions = self.versions.write().await;
+        let key = (version.cycle_id, version.version);
+        if versions.contains_key(&key) {
+            return Err(DomainError::new(
+                ErrorCode::DatabaseError,
+                format!("Document version {} already exists", version.label()),
+            ));
+        }
+        versions.insert(key, version.clone());
+        Ok(())
+    }
+
+    async fn find(
+        &self,
+        cycle_id: &CycleId,
+        version: u32,
+    ) -> Result<Option<DocumentVersion>, DomainError> {
+        Ok(self
+            .versions
+            .read()
+            .await
+            .get(&(*cycle_id, version))
+            .cloned())
+    }
+
+    async fn latest(&self, cycle_id: &CycleId) -> Result<Option<DocumentVersion>, DomainError> {
+        Ok(self
+            .versions
+            .read()
+            .await
+            .values()
+            .filter(|v| &v.cycle_id == cycle_id)
+            .max_by_key(|v| v.version)
+            .cloned())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::document::MarkdownContent;
+
+    #[tokio::test]
+    async fn finds_versions_and_rejects_duplicates() {
+        let repo = InMemoryDocumentVersionRepository::new();
+        let cycle_id = CycleId::new();
+        for number in 1..=3 {
+            let content = MarkdownContent::new(format!("# Draft {}", number));
+            repo.save(&DocumentVersion::new(cycle_id, number, content))
+                .await
+                .unwrap();
+        }
+        repo.save(&DocumentVersion::new(
+            CycleId::new(),
+            9,
+            MarkdownContent::new("# Other"),
+        ))
+        .await
+        .unwrap();
+
+        assert_eq!(repo.latest(&cycle_id).await.unwrap().unwrap().version, 3);
+        assert_eq!(
+            repo.find(&cycle_id, 2)
+                .await
+                .unwrap()
+                .unwrap()
+                .content
+                .as_str(),
+            "# Draft 2"
+        );
+        assert!(repo.find(&cycle_id, 4).await.unwrap().is_none());
+
+        let duplicate = DocumentVersion::new(cycle_id, 3, MarkdownContent::new("# Again"));
+        assert!(repo.save(&duplicate).await.is_err());
+    }
+}
@@ -0,0 +1,112 @@
+//! In-memory import draft repository for testing and development.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use tokio::sync::RwLock;
+
+use crate::domain::document::ImportDraft;
+use crate::domain::foundation::{ConfirmationRequestId, CycleId, DomainError, ErrorCode};
+use crate::ports::ImportDraftRepository;
+
+/// In-memory import drafts keyed by ID.
+#[derive(Debug, Clone, Default)]
+pub struct InMemoryImportDraftRepository {
+    drafts: Arc<RwLock<HashMap<ConfirmationRequestId, ImportDraft>>>,
+}
+
+impl InMemoryImportDraftRepository {
+    /// Create an empty repository.
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait]
+impl ImportDraftRepository for InMemoryImportDraftRepository {
+    async fn save(&self, draft: &ImportDraft) -> Result<(), DomainError> {
+        self.drafts.write().await.insert(draft.id(), draft.clone());
+        Ok(())
+    }
+
+    async fn update(&self, draft: &ImportDraft) -> Result<(), DomainError> {
+        let mut drafts = self.drafts.write().await;
+        match drafts.get_mut(&draft.id()) {
+            Some(existing) => {
+                *existing = draft.clone();
+                Ok(())
+            }
+            None => Err(DomainError::new(
+                ErrorCode::NotFound,
+                format!("Import draft {} not found", draft.id()),
+            )),
+        }
+    }
+
+    async fn find_by_id(
+        &self,
+        id: &ConfirmationRequestId,
+    ) -> Result<Option<ImportDraft>, DomainError> {
+        Ok(self.drafts.read().await.get(id).cloned())
+    }
+
+    async fn list_pending(&self, cycle_id: &CycleId) -> Result<Vec<ImportDraft>, DomainError> {
+        let mut drafts: Vec<ImportDraft> = self
+            .drafts
+            .read()
+            .await
+            .values()
+            .filter(|d| &d.cycle_id == cycle_id && d.confirmation.is_pending())
+            .cloned()
+            .collect();
+        drafts.sort_by_key(|d| std::cmp::Reverse(d.created_at));
+        Ok(drafts)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::conversation::tools::ToolCall;
+    use crate::domain::foundation::UserId;
+
+    fn draft(cycle_id: CycleId) -> ImportDraft {
+        let call = ToolCall::new(
+            "add_alternative",
+            serde_json::json!({"name": "Stay put", "description": "", "is_status_quo": true}),
+        );
+        ImportDraft::from_tool_calls(cycle_id, UserId::new("user-1").unwrap(), vec![call]).unwrap()
+    }
+
+    #[tokio::test]
+    async fn lists_only_pending_drafts_for_the_cycle() {
+        let repo = InMemoryImportDraftRepository::new();
+        let cycle_id = CycleId::new();
+        let pending = draft(cycle_id);
+        let mut resolved = draft(cycle_id);
+        repo.save(&pending).await.unwrap();
+        repo.save(&resolved).await.unwrap();
+        repo.save(&draft(CycleId::new())).await.unwrap();
+
+        resolved.confirmation.reject();
+        repo.update(&resolved).await.unwrap();
+
+        let listed = repo.list_pending(&cycle_id).await.unwrap();
+        assert_eq!(listed.len(), 1);
+        assert_eq!(listed[0].id(), pending.id());
+        assert!(!repo
+            .find_by_id(&resolved.id())
+            .await
+            .unwrap()
+            .unwrap()
+            .confirmation
+            .is_pending());
+    }
+
+    #[tokio::test]
+    async fn update_requires_an_existing_draft() {
+        let repo = InMemoryImportDraftRepository::new();
+        assert!(repo.update(&draft(CycleId::new())).await.is_err());
+    }
+}
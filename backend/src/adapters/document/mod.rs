@@ -0,0 +1,97 @@
+//! Decision document exporters.
+//!
+//! Implementations of the `DocumentExporter` port, one per file format:
+//!
+//! - `HtmlPageExporter` - Read-only web page for published documents
+//! - `PdfDocumentExporter` - Paginated PDF with a consequences table and cycle tree appendix
+//! - `SlideDeckExporter` - Short reveal.js deck summarizing the recommendation for stakeholders
+//! - `TemplateDocumentExporter` - Markdown from per-organization Tera templates
+//!
+//! Also holds the in-memory stores for document data:
+//! `InMemoryAttachmentRepository` for the metadata of files attached to
+//! components, `InMemoryDocumentVersionRepository` for document history, and
+//! `InMemoryDocumentDeliveryRepository` / `InMemoryDocumentEmailPreferenceRepository`
+//! for emailed documents, `InMemoryImportDraftRepository` for AI-drafted
+//! imports awaiting confirmation, and `InMemoryDocumentPublicationRepository`
+//! for publicly shared snapshots.
+
+pub mod html_page;
+mod in_memory_attachment_repository;
+mod in_memory_document_delivery_repository;
+mod in_memory_document_publication_repository;
+mod in_memory_document_version_repository;
+mod in_memory_import_draft_repository;
+pub mod pdf;
+pub mod slides;
+pub mod template;
+
+pub use html_page::HtmlPageExporter;
+pub use in_memory_attachment_repository::InMemoryAttachmentRepository;
+pub use in_memory_document_delivery_repository::{
+    InMemoryDocumentDeliveryRepository, InMemoryDocumentEmailPreferenceRepository,
+};
+pub use in_memory_document_publication_repository::InMemoryDocumentPublicationRepository;
+pub use in_memory_document_version_repository::InMemoryDocumentVersionRepository;
+pub use in_memory_import_draft_repository::InMemoryImportDraftRepository;
+pub use pdf::PdfDocumentExporter;
+pub use slides::SlideDeckExporter;
+pub use template::{
+    FileDocumentTemplateStore, InMemoryDocumentTemplateStore, TemplateDocumentExporter,
+    TemplateError, TemplateSandbox,
+};
+
+use crate::ports::ExportFormat;
+
+/// Download name derived from the session title.
+fn download_name(title: &str, format: ExportFormat) -> String {
+    let slug: String = title
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() {
+                c.to_ascii_lowercase()
+            } else {
+                '-'
+            }
+        })
+        .collect();
+    let slug = slug
+        .split('-')
+        .filter(|part| !part.is_empty())
+        .collect::<Vec<_>>()
+        .join("-");
+    if slug.is_empty() {
+        format!("decision.{}", format.extension())
+    } else {
+        format!("{}.{}", &slug[..slug.len().min(60)], format.extension())
+    }
+}
+
+/// Escapes text for HTML element content and attribute values.
+fn escape_html(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn download_name_falls_back_for_empty_titles() {
+        assert_eq!(download_name("   ", ExportFormat::Pdf), "decision.pdf");
+        assert_eq!(
+            download_name("Job: A vs. B", ExportFormat::Slides),
+            "job-a-vs-b.html"
+        );
+    }
+}
@@ -0,0 +1,871 @@
+//! PdfDocumentExporter - Lays out the decision document as a PDF.
+//!
+//! Sections follow the PrOACT order: decision statement, objectives,
+//! alternatives, consequences, recommendation, and decision quality, followed
+//! by the cycle's attachments and the session's cycle tree as an appendix. Every page carries a running header
+//! with the session title and a "Page n of m" footer.
+//!
+//! The consequences table never wraps mid-row: alternatives that do not fit
+//! the page width are split into column groups (repeating the objective
+//! column), long cell text is clamped with an ellipsis, and rows that would
+//! cross a page boundary move to the next page under a repeated header row.
+//!
+//! JPEG and PNG attachments are drawn inline at up to the content width;
+//! other attachments (and images the writer cannot embed) are listed by name.
+
+use async_trait::async_trait;
+
+use crate::domain::dashboard::{CellColor, CompactConsequencesTable, DashboardOverview};
+use crate::domain::foundation::CycleId;
+use crate::ports::{
+    CycleTreeNode, DecisionDocument, DocumentAttachment, DocumentExportError, DocumentExporter,
+    ExportFormat, ExportedDocument,
+};
+
+use super::super::download_name;
+use super::writer::{
+    text_width, wrap_clamped, wrap_text, Color, Font, Image, PageCanvas, PdfWriter, PAGE_HEIGHT,
+    PAGE_WIDTH,
+};
+
+const MARGIN: f32 = 54.0;
+const CONTENT_WIDTH: f32 = PAGE_WIDTH - 2.0 * MARGIN;
+const CONTENT_TOP: f32 = PAGE_HEIGHT - 66.0;
+const CONTENT_BOTTOM: f32 = 54.0;
+
+const BODY_SIZE: f32 = 10.0;
+const BODY_LEADING: f32 = 14.0;
+const HEADING_SIZE: f32 = 14.0;
+
+const TABLE_SIZE: f32 = 8.0;
+const TABLE_LEADING: f32 = 10.0;
+const CELL_PADDING: f32 = 4.0;
+const MIN_ALTERNATIVE_COLUMN: f32 = 84.0;
+const MAX_OBJECTIVE_COLUMN: f32 = 150.0;
+const MAX_HEADER_LINES: usize = 3;
+const MAX_OBJECTIVE_LINES: usize = 4;
+const MAX_CELL_LINES: usize = 3;
+const MAX_IMAGE_HEIGHT: f32 = 320.0;
+
+const RULE_COLOR: Color = Color(0.8, 0.8, 0.8);
+const HEADER_FILL: Color = Color(0.93, 0.93, 0.95);
+const RED_FILL: Color = Color(0.98, 0.86, 0.86);
+const YELLOW_FILL: Color = Color(1.0, 0.96, 0.8);
+const GREEN_FILL: Color = Color(0.86, 0.95, 0.86);
+
+/// Renders the decision document to PDF.
+#[derive(Debug, Default, Clone)]
+pub struct PdfDocumentExporter;
+
+impl PdfDocumentExporter {
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Renders the document synchronously.
+    pub fn render(&self, document: &DecisionDocument) -> Vec<u8> {
+        let overview = &document.overview;
+        let generated = document
+            .generated_at
+            .as_datetime()
+            .format("%B %-d, %Y")
+            .to_string();
+
+        let mut layout = Layout::new(&overview.session_title);
+        layout.title(
+            &overview.session_title,
+            &format!("Decision document · {}", generated),
+        );
+
+        layout.heading("Decision");
+        match &overview.decision_statement {
+            Some(statement) => layout.paragraph(statement),
+            None => layout.note("The problem frame has not been completed."),
+        }
+
+        layout.heading("Objectives");
+        if overview.objectives.is_empty() {
+            layout.note("No objectives recorded.");
+        }
+        for objective in &overview.objectives {
+            let mut text = objective.description.clone();
+            if let Some(measure) = &objective.measure {
+                text.push_str(&format!(" (measured by {})", measure));
+            }
+            if !objective.is_fundamental {
+                text.push_str(" [means]");
+            }
+            layout.bullet(&text);
+        }
+
+        layout.heading("Alternatives");
+        if overview.alternatives.is_empty() {
+            layout.note("No alternatives recorded.");
+        }
+        for alternative in &overview.alternatives {
+            let mut details = Vec::new();
+            if alternative.is_status_quo {
+                details.push("status quo".to_string());
+            }
+            if let Some(rank) = alternative.rank {
+                details.push(format!("rank {}", rank));
+            }
+            if let Some(score) = alternative.pugh_score {
+                details.push(format!("Pugh score {:+}", score));
+            }
+            if alternative.is_dominated {
+                details.push("dominated".to_string());
+            }
+            let text = if details.is_empty() {
+                alternative.name.clone()
+            } else {
+                format!("{} — {}", alternative.name, details.join(", "))
+            };
+            layout.bullet(&text);
+        }
+
+        layout.heading("Consequences");
+        match &overview.consequences_table {
+            Some(table) if !table.alternative_names.is_empty() => layout.consequences_table(table),
+            _ => layout.note("The consequences table has not been completed."),
+        }
+
+        layout.heading("Recommendation");
+        recommendation(&mut layout, overview);
+
+        layout.heading("Decision Quality");
+        match overview.dq_score {
+            Some(score) => layout.paragraph(&format!("Overall decision quality: {}", score)),
+            None => layout.note("Decision quality has not been assessed."),
+        }
+
+        if !document.attachments.is_empty() {
+            layout.heading("Attachments");
+            for attachment in &document.attachments {
+                attachment_entry(&mut layout, attachment);
+            }
+        }
+
+        if let Some(tree) = &document.cycle_tree {
+            layout.new_page();
+            layout.heading("Appendix: Cycle Tree");
+            layout.note("All cycles explored in this session. Branches are indented under the cycle they came from.");
+            cycle_tree(&mut layout, tree, &document.cycle_id, 0);
+        }
+
+        layout.finish()
+    }
+}
+
+#[async_trait]
+impl DocumentExporter for PdfDocumentExporter {
+    fn format(&self) -> ExportFormat {
+        ExportFormat::Pdf
+    }
+
+    async fn export(
+        &self,
+        document: &DecisionDocument,
+    ) -> Result<ExportedDocument, DocumentExportError> {
+        Ok(ExportedDocument {
+            format: ExportFormat::Pdf,
+            file_name: download_name(&document.overview.session_title, ExportFormat::Pdf),
+            bytes: self.render(document),
+        })
+    }
+}
+
+fn recommendation(layout: &mut Layout, overview: &DashboardOverview) {
+    let Some(recommendation) = &overview.recommendation else {
+        layout.note("No recommendation yet.");
+        return;
+    };
+    if let Some(name) = recommendation
+        .standout_name
+        .as_deref()
+        .filter(|_| recommendation.has_standout)
+    {
+        layout.paragraph_in(&format!("Standout option: {}", name), Font::Bold);
+    }
+    layout.paragraph(&recommendation.synthesis_preview);
+    if recommendation.caveat_count > 0 {
+        layout.note(&format!(
+            "{} caveat{} noted.",
+            recommendation.caveat_count,
+            if recommendation.caveat_count == 1 {
+                ""
+            } else {
+                "s"
+            }
+        ));
+    }
+}
+
+fn attachment_entry(layout: &mut Layout, entry: &DocumentAttachment) {
+    let attachment = &entry.attachment;
+    let component = attachment.component_type.display_name();
+    let image = entry
+        .image
+        .as_ref()
+        .filter(|_| attachment.is_image())
+        .and_then(|bytes| Image::parse(bytes.clone()));
+
+    match image {
+        Some(image) => {
+            layout.image(image);
+            layout.note(&format!("{} ({})", attachment.label(), component));
+        }
+        None => {
+            let text = match &attachment.caption {
+                Some(caption) => format!(
+                    "{} \u{2014} {} ({})",
+                    attachment.file_name, caption, component
+                ),
+                None => format!("{} ({})", attachment.file_name, component),
+            };
+            layout.bullet(&text);
+        }
+    }
+}
+
+fn cycle_tree(layout: &mut Layout, node: &CycleTreeNode, current: &CycleId, depth: usize) {
+    let cycle = &node.cycle;
+    let id = cycle.id.to_string();
+    let mut text = format!(
+        "Cycle {} — {}, {}% complete, at {}",
+        &id[..id.len().min(8)],
+        cycle.status,
+        cycle.progress_percent,
+        cycle.current_step
+    );
+    if let Some(branch_point) = cycle.branch_point {
+        text.push_str(&format!(" (branched at {})", branch_point));
+    }
+    let font = if &cycle.id == current {
+        text.push_str(" — this document");
+        Font::Bold
+    } else {
+        Font::Regular
+    };
+    layout.tree_line(&text, font, depth);
+    for child in &node.children {
+        cycle_tree(layout, child, current, depth + 1);
+    }
+}
+
+fn cell_fill(color: CellColor) -> Color {
+    match color {
+        CellColor::Red => RED_FILL,
+        CellColor::Yellow => YELLOW_FILL,
+        CellColor::Green => GREEN_FILL,
+    }
+}
+
+fn rating_label(rating: i8) -> String {
+    if rating > 0 {
+        format!("+{}", rating)
+    } else {
+        rating.to_string()
+    }
+}
+
+// ════════════════════════════════════════════════════════════════════════════
+// Page layout
+// ════════════════════════════════════════════════════════════════════════════
+
+/// Flows content top to bottom, breaking pages as needed.
+struct Layout {
+    running_title: String,
+    pages: Vec<PageCanvas>,
+    canvas: PageCanvas,
+    images: Vec<Image>,
+    y: f32,
+}
+
+/// Wrapped text for one consequences table row.
+struct TableRow {
+    name: Vec<String>,
+    cells: Vec<TableCell>,
+}
+
+struct TableCell {
+    label: String,
+    explanation: Vec<String>,
+    color: CellColor,
+}
+
+/// One column group of the consequences table.
+struct ColumnGroup<'a> {
+    objective_width: f32,
+    column_width: f32,
+    alternatives: std::ops::Range<usize>,
+    table: &'a CompactConsequencesTable,
+}
+
+impl Layout {
+    fn new(running_title: &str) -> Self {
+        Self {
+            running_title: running_title.to_string(),
+            pages: Vec::new(),
+            canvas: PageCanvas::new(),
+            images: Vec::new(),
+            y: CONTENT_TOP,
+        }
+    }
+
+    fn new_page(&mut self) {
+        let page = std::mem::take(&mut self.canvas);
+        self.pages.push(page);
+        self.y = CONTENT_TOP;
+    }
+
+    /// Starts a new page unless `height` fits in the remaining space.
+    fn ensure_space(&mut self, height: f32) {
+        if self.y - height < CONTENT_BOTTOM && self.y < CONTENT_TOP {
+            self.new_page();
+        }
+    }
+
+    fn title(&mut self, title: &str, subtitle: &str) {
+        for line in wrap_text(title, Font::Bold, 20.0, CONTENT_WIDTH) {
+            self.y -= 24.0;
+            self.canvas
+                .text(MARGIN, self.y, Font::Bold, 20.0, Color::BLACK, &line);
+        }
+        self.y -= 16.0;
+        self.canvas.text(
+            MARGIN,
+            self.y,
+            Font::Regular,
+            BODY_SIZE,
+            Color::GRAY,
+            subtitle,
+        );
+        self.y -= 8.0;
+    }
+
+    fn heading(&mut self, text: &str) {
+        // Keep headings with at least two lines of what follows
+        self.ensure_space(30.0 + 2.0 * BODY_LEADING);
+        self.y -= 30.0;
+        self.canvas
+            .text(MARGIN, self.y, Font::Bold, HEADING_SIZE, Color::BLACK, text);
+        self.y -= 6.0;
+        self.canvas.line(
+            MARGIN,
+            self.y,
+            MARGIN + CONTENT_WIDTH,
+            self.y,
+            0.5,
+            RULE_COLOR,
+        );
+        self.y -= 4.0;
+    }
+
+    fn lines(&mut self, text: &str, font: Font, color: Color, indent: f32) {
+        for line in wrap_text(text, font, BODY_SIZE, CONTENT_WIDTH - indent) {
+            self.ensure_space(BODY_LEADING);
+            self.y -= BODY_LEADING;
+            self.canvas
+                .text(MARGIN + indent, self.y, font, BODY_SIZE, color, &line);
+        }
+    }
+
+    fn paragraph(&mut self, text: &str) {
+        self.paragraph_in(text, Font::Regular);
+    }
+
+    fn paragraph_in(&mut self, text: &str, font: Font) {
+        self.lines(text, font, Color::BLACK, 0.0);
+        self.y -= 4.0;
+    }
+
+    fn note(&mut self, text: &str) {
+        self.lines(text, Font::Regular, Color::GRAY, 0.0);
+        self.y -= 4.0;
+    }
+
+    fn bullet(&mut self, text: &str) {
+        self.ensure_space(BODY_LEADING);
+        self.canvas.text(
+            MARGIN + 4.0,
+            self.y - BODY_LEADING,
+            Font::Regular,
+            BODY_SIZE,
+            Color::BLACK,
+            "•",
+        );
+        self.lines(text, Font::Regular, Color::BLACK, 16.0);
+        self.y -= 2.0;
+    }
+
+    /// Draws an image at its aspect ratio, no wider than the content area and
+    /// no taller than `MAX_IMAGE_HEIGHT`.
+    fn image(&mut self, image: Image) {
+        let aspect = image.height as f32 / image.width as f32;
+        let mut width = CONTENT_WIDTH.min(image.width as f32);
+        let mut height = width * aspect;
+        if height > MAX_IMAGE_HEIGHT {
+            height = MAX_IMAGE_HEIGHT;
+            width = height / aspect;
+        }
+
+        self.ensure_space(height + 8.0);
+        self.y -= height + 8.0;
+        self.canvas
+            .image(self.images.len(), MARGIN, self.y, width, height);
+        self.images.push(image);
+    }
+
+    fn tree_line(&mut self, text: &str, font: Font, depth: usize) {
+        let indent = (depth as f32 * 18.0).min(CONTENT_WIDTH / 2.0);
+        self.ensure_space(BODY_LEADING);
+        self.canvas.text(
+            MARGIN + indent,
+            self.y - BODY_LEADING,
+            font,
+            BODY_SIZE,
+            Color::BLACK,
+            "•",
+        );
+        self.lines(text, font, Color::BLACK, indent + 12.0);
+    }
+
+    fn consequences_table(&mut self, table: &CompactConsequencesTable) {
+        let objective_width = (CONTENT_WIDTH * 0.3).min(MAX_OBJECTIVE_COLUMN);
+        let available = CONTENT_WIDTH - objective_width;
+        let per_group = ((available / MIN_ALTERNATIVE_COLUMN).floor() as usize).max(1);
+        let total = table.alternative_names.len();
+
+        let mut start = 0;
+        while start < total {
+            let end = (start + per_group).min(total);
+            let group = ColumnGroup {
+                objective_width,
+                column_width: available / (end - start) as f32,
+                alternatives: start..end,
+                table,
+            };
+            if total > per_group {
+                self.y -= 4.0;
+                self.note(&format!("Alternatives {}–{} of {}", start + 1, end, total));
+            }
+            self.table_group(&group);
+            self.y -= 10.0;
+            start = end;
+        }
+    }
+
+    fn table_group(&mut self, group: &ColumnGroup<'_>) {
+        let header: Vec<Vec<String>> = group.table.alternative_names[group.alternatives.clone()]
+            .iter()
+            .map(|name| {
+                wrap_clamped(
+                    name,
+                    Font::Bold,
+                    TABLE_SIZE,
+                    group.column_width - 2.0 * CELL_PADDING,
+                    MAX_HEADER_LINES,
+                )
+            })
+            .collect();
+        let header_height = row_height(header.iter().map(Vec::len).max().unwrap_or(1));
+
+        let padding = 2.0 * CELL_PADDING;
+        let rows: Vec<TableRow> = group
+            .table
+            .objective_names
+            .iter()
+            .enumerate()
+            .map(|(row, objective)| TableRow {
+                name: wrap_clamped(
+                    objective,
+                    Font::Regular,
+                    TABLE_SIZE,
+                    group.objective_width - padding,
+                    MAX_OBJECTIVE_LINES,
+                ),
+                cells: group
+                    .alternatives
+                    .clone()
+                    .map(
+                        |column| match group.table.cells.get(row).and_then(|r| r.get(column)) {
+                            Some(cell) => TableCell {
+                                label: rating_label(cell.rating),
+                                explanation: cell
+                                    .explanation_preview
+                                    .as_deref()
+                                    .map(|text| {
+                                        wrap_clamped(
+                                            text,
+                                            Font::Regular,
+                                            TABLE_SIZE,
+                                            group.column_width - padding,
+                                            MAX_CELL_LINES,
+                                        )
+                                    })
+                                    .unwrap_or_default(),
+                                color: cell.color,
+                            },
+                            None => TableCell {
+                                label: "—".to_string(),
+                                explanation: Vec::new(),
+                                color: CellColor::Yellow,
+                            },
+                        },
+                    )
+                    .collect(),
+            })
+            .collect();
+
+        self.ensure_space(header_height + row_height(1));
+        self.table_header(group, &header, header_height);
+
+        for row in &rows {
+            let lines = row
+                .cells
+                .iter()
+                .map(|cell| cell.explanation.len() + 1)
+                .max()
+                .unwrap_or(1)
+                .max(row.name.len());
+            let height = row_height(lines);
+            if self.y - height < CONTENT_BOTTOM {
+                self.new_page();
+                self.table_header(group, &header, header_height);
+            }
+
+            let top = self.y;
+            let name_x = MARGIN + CELL_PADDING;
+            for (i, line) in row.name.iter().enumerate() {
+                self.canvas.text(
+                    name_x,
+                    top - CELL_PADDING - TABLE_LEADING * (i as f32 + 0.8),
+                    Font::Regular,
+                    TABLE_SIZE,
+                    Color::BLACK,
+                    line,
+                );
+            }
+            for (i, cell) in row.cells.iter().enumerate() {
+                let x = MARGIN + group.objective_width + group.column_width * i as f32;
+                self.canvas.fill_rect(
+                    x + 1.0,
+                    top - height + 1.0,
+                    group.column_width - 2.0,
+                    height - 2.0,
+                    cell_fill(cell.color),
+                );
+                self.canvas.text(
+                    x + CELL_PADDING,
+                    top - CELL_PADDING - TABLE_LEADING * 0.8,
+                    Font::Bold,
+                    TABLE_SIZE,
+                    Color::BLACK,
+                    &cell.label,
+                );
+                for (j, line) in cell.explanation.iter().enumerate() {
+                    self.canvas.text(
+                        x + CELL_PADDING,
+                        top - CELL_PADDING - TABLE_LEADING * (j as f32 + 1.8),
+                        Font::Regular,
+                        TABLE_SIZE,
+                        Color::BLACK,
+                        line,
+                    );
+                }
+            }
+            self.y -= height;
+            self.canvas.line(
+                MARGIN,
+                self.y,
+                MARGIN + CONTENT_WIDTH,
+                self.y,
+                0.5,
+                RULE_COLOR,
+            );
+        }
+    }
+
+    fn table_header(&mut self, group: &ColumnGroup<'_>, header: &[Vec<String>], height: f32) {
+        let top = self.y;
+        self.canvas
+            .fill_rect(MARGIN, top - height, CONTENT_WIDTH, height, HEADER_FILL);
+        self.canvas.text(
+            MARGIN + CELL_PADDING,
+            top - CELL_PADDING - TABLE_LEADING * 0.8,
+            Font::Bold,
+            TABLE_SIZE,
+            Color::BLACK,
+            "Objective",
+        );
+        for (i, lines) in header.iter().enumerate() {
+            let x = MARGIN + group.objective_width + group.column_width * i as f32 + CELL_PADDING;
+            for (j, line) in lines.iter().enumerate() {
+                self.canvas.text(
+                    x,
+                    top - CELL_PADDING - TABLE_LEADING * (j as f32 + 0.8),
+                    Font::Bold,
+                    TABLE_SIZE,
+                    Color::BLACK,
+                    line,
+                );
+            }
+        }
+        self.y -= height;
+    }
+
+    /// Adds running headers and footers and serializes the document.
+    fn finish(mut self) -> Vec<u8> {
+        self.new_page();
+        let total = self.pages.len();
+        let mut writer = PdfWriter::new(self.running_title.clone());
+        for image in self.images {
+            writer.add_image(image);
+        }
+        for (index, mut page) in self.pages.into_iter().enumerate() {
+            let header_y = PAGE_HEIGHT - 36.0;
+            let title = wrap_clamped(
+                &self.running_title,
+                Font::Regular,
+                8.0,
+                CONTENT_WIDTH * 0.7,
+                1,
+            );
+            page.text(MARGIN, header_y, Font::Regular, 8.0, Color::GRAY, &title[0]);
+            let label = "Choice Sherpa decision document";
+            page.text(
+                MARGIN + CONTENT_WIDTH - text_width(label, Font::Regular, 8.0),
+                header_y,
+                Font::Regular,
+                8.0,
+                Color::GRAY,
+                label,
+            );
+            page.line(
+                MARGIN,
+                header_y - 6.0,
+                MARGIN + CONTENT_WIDTH,
+                header_y - 6.0,
+                0.5,
+                RULE_COLOR,
+            );
+
+            let footer = format!("Page {} of {}", index + 1, total);
+            let footer_x = (PAGE_WIDTH - text_width(&footer, Font::Regular, 8.0)) / 2.0;
+            page.text(footer_x, 30.0, Font::Regular, 8.0, Color::GRAY, &footer);
+            writer.add_page(page);
+        }
+        writer.finish()
+    }
+}
+
+fn row_height(lines: usize) -> f32 {
+    lines as f32 * TABLE_LEADING + 2.0 * CELL_PADDING
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::writer::fixtures::shown_text;
+    use super::*;
+    use crate::domain::dashboard::{AlternativeSummary, CellSummary, ObjectiveSummary};
+    use crate::domain::foundation::{ComponentType, CycleStatus, SessionId, Timestamp};
+    use crate::ports::CycleSummary;
+
+    fn overview(alternatives: usize, objectives: usize) -> DashboardOverview {
+        let alternative_names: Vec<String> = (0..alternatives)
+            .map(|i| format!("Alternative {}", i + 1))
+            .collect();
+        let objective_names: Vec<String> = (0..objectives)
+            .map(|i| format!("Objective {}", i + 1))
+            .collect();
+        let cells = (0..objectives)
+            .map(|_| {
+                (0..alternatives)
+                    .map(|i| CellSummary {
+                        rating: (i % 5) as i8 - 2,
+                        color: CellColor::from((i % 5) as i8 - 2),
+                        explanation_preview: Some(
+                            "A fairly long explanation of the rating that will need wrapping"
+                                .to_string(),
+                        ),
+                    })
+                    .collect()
+            })
+            .collect();
+
+        DashboardOverview {
+            session_id: SessionId::new(),
+            session_title: "Should we move to Lisbon?".to_string(),
+            decision_statement: Some(
+                "Decide where the family lives for the next five years.".to_string(),
+            ),
+            stakeholders: vec![],
+            objectives: objective_names
+                .iter()
+                .enumerate()
+                .map(|(i, name)| ObjectiveSummary {
+                    id: format!("obj-{}", i),
+                    description: name.clone(),
+                    is_fundamental: true,
+                    measure: None,
+                })
+                .collect(),
+            alternatives: alternative_names
+                .iter()
+                .enumerate()
+                .map(|(i, name)| AlternativeSummary {
+                    id: format!("alt-{}", i),
+                    name: name.clone(),
+                    is_status_quo: i == 0,
+                    pugh_score: Some(i as i32),
+                    rank: Some(i as u8 + 1),
+                    is_dominated: false,
+                })
+                .collect(),
+            consequences_table: Some(CompactConsequencesTable {
+                alternative_names,
+                objective_names,
+                cells,
+            }),
+            key_uncertainties: vec![],
+            recommendation: None,
+            dq_score: None,
+            active_cycle_id: None,
+            cycle_count: 1,
+            last_updated: chrono::Utc::now(),
+        }
+    }
+
+    fn document(overview: DashboardOverview) -> DecisionDocument {
+        let cycle_id = CycleId::new();
+        let summary = |id: CycleId, branch: Option<ComponentType>| CycleSummary {
+            id,
+            is_branch: branch.is_some(),
+            branch_point: branch,
+            status: CycleStatus::Active,
+            current_step: ComponentType::Consequences,
+            progress_percent: 50,
+            created_at: Timestamp::now(),
+        };
+        DecisionDocument {
+            cycle_id,
+            overview,
+            cycle_tree: Some(CycleTreeNode {
+                cycle: summary(cycle_id, None),
+                children: vec![CycleTreeNode {
+                    cycle: summary(CycleId::new(), Some(ComponentType::Alternatives)),
+                    children: vec![],
+                }],
+            }),
+            organization: None,
+            attachments: Vec::new(),
+            generated_at: Timestamp::now(),
+            reading_level: Default::default(),
+        }
+    }
+
+    fn page_count(bytes: &[u8]) -> usize {
+        String::from_utf8_lossy(bytes)
+            .matches("/Type /Page ")
+            .count()
+    }
+
+    #[tokio::test]
+    async fn exports_pdf_with_file_name() {
+        let exported = PdfDocumentExporter::new()
+            .export(&document(overview(3, 3)))
+            .await
+            .unwrap();
+
+        assert_eq!(exported.format, ExportFormat::Pdf);
+        assert_eq!(exported.file_name, "should-we-move-to-lisbon.pdf");
+        assert!(exported.bytes.starts_with(b"%PDF-"));
+    }
+
+    #[test]
+    fn includes_headers_footers_and_appendix() {
+        let bytes = PdfDocumentExporter::new().render(&document(overview(3, 3)));
+        let shown = shown_text(&bytes);
+
+        let pages = page_count(&bytes);
+        assert_eq!(pages, 2, "appendix starts on its own page");
+        assert!(shown.iter().any(|text| text == "Page 1 of 2"));
+        assert!(shown.iter().any(|text| text == "Appendix: Cycle Tree"));
+        assert!(shown
+            .iter()
+            .any(|text| text.contains("branched at Alternatives")));
+    }
+
+    #[test]
+    fn wide_tables_are_split_into_column_groups() {
+        let bytes = PdfDocumentExporter::new().render(&document(overview(9, 2)));
+        let shown = shown_text(&bytes);
+
+        assert!(shown
+            .iter()
+            .any(|text| text.starts_with("Alternatives 1\u{2013}")));
+        assert!(shown.iter().any(|text| text.contains("Alternative 9")));
+    }
+
+    #[test]
+    fn long_tables_repeat_the_header_on_new_pages() {
+        let bytes = PdfDocumentExporter::new().render(&document(overview(3, 60)));
+
+        assert!(page_count(&bytes) > 3);
+        let headers = shown_text(&bytes)
+            .iter()
+            .filter(|text| *text == "Objective")
+            .count();
+        assert!(headers > 1);
+    }
+
+    #[test]
+    fn embeds_jpeg_attachments_and_lists_other_files() {
+        use crate::domain::document::Attachment;
+        use crate::domain::foundation::UserId;
+
+        let mut document = document(overview(3, 3));
+        let attachment = |file_name: &str, content_type: &str, caption: Option<&str>| {
+            Attachment::new(
+                document.cycle_id,
+                ComponentType::Alternatives,
+                file_name,
+                content_type,
+                100,
+                caption.map(str::to_string),
+                UserId::new("user-1").unwrap(),
+            )
+            .unwrap()
+        };
+        // SOI, then a baseline frame header for a 400x300 RGB image
+        let jpeg = vec![
+            0xFF, 0xD8, 0xFF, 0xC0, 0x00, 0x11, 0x08, 0x01, 0x2C, 0x01, 0x90, 0x03, 0x01, 0x11,
+            0x00, 0x02, 0x11, 0x01, 0x03, 0x11, 0x01, 0xFF, 0xD9,
+        ];
+        document.attachments = vec![
+            DocumentAttachment {
+                attachment: attachment("board.jpg", "image/jpeg", Some("Whiteboard list")),
+                image: Some(jpeg),
+            },
+            DocumentAttachment {
+                attachment: attachment("quotes.pdf", "application/pdf", Some("Moving quotes")),
+                image: None,
+            },
+        ];
+
+        let bytes = PdfDocumentExporter::new().render(&document);
+        let text = String::from_utf8_lossy(&bytes);
+        let shown = shown_text(&bytes);
+
+        assert!(shown.iter().any(|text| text == "Attachments"));
+        assert!(text.contains("/Subtype /Image /Width 400 /Height 300"));
+        assert!(text.contains("/Im1 Do"));
+        assert!(shown
+            .iter()
+            .any(|text| text == "Whiteboard list (Alternatives)"));
+        assert!(shown
+            .iter()
+            .any(|text| text == "quotes.pdf \u{2014} Moving quotes (Alternatives)"));
+    }
+}
@@ -0,0 +1,453 @@
+//! TrueType fonts embedded in PDF exports.
+//!
+//! PDF's standard fonts only cover WinAnsi, so text is set in DejaVu Sans
+//! (bundled in `fonts/`, see its LICENSE), which also covers Greek,
+//! Cyrillic, and most symbols. Each PDF embeds a subset of the font: glyph
+//! ids are kept so text can be shown by glyph id, but the outlines of glyphs
+//! the document does not use are dropped.
+
+use std::collections::{BTreeSet, HashMap};
+
+use once_cell::sync::Lazy;
+
+static REGULAR: Lazy<TrueTypeFont> = Lazy::new(|| {
+    TrueTypeFont::parse("DejaVuSans", include_bytes!("fonts/DejaVuSans.ttf"))
+        .expect("bundled DejaVu Sans is a valid TrueType font")
+});
+
+static BOLD: Lazy<TrueTypeFont> = Lazy::new(|| {
+    TrueTypeFont::parse(
+        "DejaVuSans-Bold",
+        include_bytes!("fonts/DejaVuSans-Bold.ttf"),
+    )
+    .expect("bundled DejaVu Sans Bold is a valid TrueType font")
+});
+
+/// Bundled regular weight.
+pub fn regular() -> &'static TrueTypeFont {
+    &REGULAR
+}
+
+/// Bundled bold weight.
+pub fn bold() -> &'static TrueTypeFont {
+    &BOLD
+}
+
+/// Tables a PDF viewer needs to render glyphs; everything else (including
+/// `cmap`, since text is shown by glyph id) is left out of subsets.
+const SUBSET_TABLES: [&[u8; 4]; 9] = [
+    b"cvt ", b"fpgm", b"glyf", b"head", b"hhea", b"hmtx", b"loca", b"maxp", b"prep",
+];
+
+// Composite glyph flags
+const ARG_1_AND_2_ARE_WORDS: u16 = 0x0001;
+const WE_HAVE_A_SCALE: u16 = 0x0008;
+const MORE_COMPONENTS: u16 = 0x0020;
+const WE_HAVE_AN_X_AND_Y_SCALE: u16 = 0x0040;
+const WE_HAVE_A_TWO_BY_TWO: u16 = 0x0080;
+
+/// A parsed TrueType font.
+#[derive(Debug)]
+pub struct TrueTypeFont {
+    name: &'static str,
+    data: &'static [u8],
+    /// Tag to (offset, length) within `data`.
+    tables: HashMap<[u8; 4], (usize, usize)>,
+    units_per_em: u16,
+    bbox: [i16; 4],
+    ascent: i16,
+    descent: i16,
+    cap_height: i16,
+    /// Start of each glyph within `glyf`, plus the end of the last.
+    glyph_offsets: Vec<usize>,
+    advances: Vec<u16>,
+    glyph_ids: HashMap<char, u16>,
+}
+
+impl TrueTypeFont {
+    /// Parses the tables the PDF writer uses. Returns `None` if any is
+    /// missing or malformed.
+    pub fn parse(name: &'static str, data: &'static [u8]) -> Option<Self> {
+        let num_tables = usize::from(read_u16(data, 4)?);
+        let mut tables = HashMap::new();
+        for i in 0..num_tables {
+            let record = 12 + i * 16;
+            let tag: [u8; 4] = data.get(record..record + 4)?.try_into().ok()?;
+            let offset = read_u32(data, record + 8)? as usize;
+            let length = read_u32(data, record + 12)? as usize;
+            data.get(offset..offset.checked_add(length)?)?;
+            tables.insert(tag, (offset, length));
+        }
+        let table = |tag: &[u8; 4]| {
+            tables
+                .get(tag)
+                .map(|&(offset, length)| &data[offset..offset + length])
+        };
+
+        let head = table(b"head")?;
+        let hhea = table(b"hhea")?;
+        let num_glyphs = usize::from(read_u16(table(b"maxp")?, 4)?);
+        let cap_height = table(b"OS/2")
+            .filter(|os2| read_u16(os2, 0).is_some_and(|version| version >= 2))
+            .and_then(|os2| read_i16(os2, 88));
+
+        let long_loca = read_i16(head, 50)? == 1;
+        let loca = table(b"loca")?;
+        let glyph_offsets = (0..=num_glyphs)
+            .map(|i| match long_loca {
+                true => read_u32(loca, i * 4).map(|offset| offset as usize),
+                false => read_u16(loca, i * 2).map(|offset| usize::from(offset) * 2),
+            })
+            .collect::<Option<Vec<_>>>()?;
+        if glyph_offsets.windows(2).any(|pair| pair[0] > pair[1])
+            || glyph_offsets[num_glyphs] > table(b"glyf")?.len()
+        {
+            return None;
+        }
+
+        let hmtx = table(b"hmtx")?;
+        let metrics = usize::from(read_u16(hhea, 34)?).clamp(1, num_glyphs.max(1));
+        let mut advances = (0..metrics)
+            .map(|i| read_u16(hmtx, i * 4))
+            .collect::<Option<Vec<_>>>()?;
+        advances.resize(num_glyphs, *advances.last()?);
+
+        let ascent = read_i16(hhea, 4)?;
+        Some(Self {
+            name,
+            data,
+            units_per_em: read_u16(head, 18)?.max(1),
+            bbox: [
+                read_i16(head, 36)?,
+                read_i16(head, 38)?,
+                read_i16(head, 40)?,
+                read_i16(head, 42)?,
+            ],
+            ascent,
+            descent: read_i16(hhea, 6)?,
+            cap_height: cap_height.unwrap_or(ascent),
+            // Subsets have no cmap and map no characters
+            glyph_ids: match table(b"cmap") {
+                Some(cmap) => parse_cmap(cmap, num_glyphs)?,
+                None => HashMap::new(),
+            },
+            tables,
+            glyph_offsets,
+            advances,
+        })
+    }
+
+    /// PostScript name, used as the PDF `BaseFont`.
+    pub fn name(&self) -> &str {
+        self.name
+    }
+
+    /// The glyph drawn for `c`, if the font has one.
+    pub fn glyph_id(&self, c: char) -> Option<u16> {
+        self.glyph_ids.get(&c).copied()
+    }
+
+    /// Advance width of `glyph` in 1/1000 em, as PDF font metrics expect.
+    pub fn advance(&self, glyph: u16) -> u16 {
+        let units = self.advances.get(usize::from(glyph)).copied().unwrap_or(0);
+        self.scale(i32::from(units)) as u16
+    }
+
+    /// The character `glyph` is drawn for, preferring the lowest code point
+    /// when several share it.
+    #[cfg(test)]
+    pub fn char_for(&self, glyph: u16) -> Option<char> {
+        self.glyph_ids
+            .iter()
+            .filter(|(_, &id)| id == glyph)
+            .map(|(&c, _)| c)
+            .min()
+    }
+
+    pub fn bbox(&self) -> [i32; 4] {
+        self.bbox.map(|value| self.scale(i32::from(value)))
+    }
+
+    pub fn ascent(&self) -> i32 {
+        self.scale(i32::from(self.ascent))
+    }
+
+    pub fn descent(&self) -> i32 {
+        self.scale(i32::from(self.descent))
+    }
+
+    pub fn cap_height(&self) -> i32 {
+        self.scale(i32::from(self.cap_height))
+    }
+
+    fn scale(&self, units: i32) -> i32 {
+        units * 1000 / i32::from(self.units_per_em)
+    }
+
+    /// A TrueType font keeping only the outlines of `glyphs` (and of the
+    /// glyphs they are composed of, and `.notdef`). Glyph ids are unchanged.
+    pub fn subset(&self, glyphs: &BTreeSet<u16>) -> Vec<u8> {
+        let glyf = self.table(b"glyf");
+        let mut keep = BTreeSet::new();
+        let mut pending: Vec<u16> = glyphs.iter().copied().chain([0]).collect();
+        while let Some(glyph) = pending.pop() {
+            if usize::from(glyph) < self.advances.len() && keep.insert(glyph) {
+                pending.extend(composite_components(self.glyph(glyf, glyph)));
+            }
+        }
+
+        // Dropped glyphs become empty, which loca expresses as a zero length
+        let mut new_glyf = Vec::new();
+        let mut new_loca = Vec::with_capacity((self.advances.len() + 1) * 4);
+        for glyph in 0..self.advances.len() {
+            new_loca.extend_from_slice(&(new_glyf.len() as u32).to_be_bytes());
+            if keep.contains(&(glyph as u16)) {
+                new_glyf.extend_from_slice(self.glyph(glyf, glyph as u16));
+                new_glyf.resize(new_glyf.len().next_multiple_of(4), 0);
+            }
+        }
+        new_loca.extend_from_slice(&(new_glyf.len() as u32).to_be_bytes());
+
+        let mut head = self.table(b"head").to_vec();
+        head[8..12].fill(0);
+        head[50..52].copy_from_slice(&1i16.to_be_bytes());
+
+        let tables: Vec<(&[u8; 4], Vec<u8>)> = SUBSET_TABLES
+            .into_iter()
+            .filter(|tag| self.tables.contains_key(*tag))
+            .map(|tag| {
+                let body = match tag {
+                    b"glyf" => std::mem::take(&mut new_glyf),
+                    b"loca" => std::mem::take(&mut new_loca),
+                    b"head" => std::mem::take(&mut head),
+                    _ => self.table(tag).to_vec(),
+                };
+                (tag, body)
+            })
+            .collect();
+        write_font(&tables)
+    }
+
+    fn table(&self, tag: &[u8; 4]) -> &'static [u8] {
+        self.tables
+            .get(tag)
+            .map_or(&[], |&(offset, length)| &self.data[offset..offset + length])
+    }
+
+    fn glyph<'a>(&self, glyf: &'a [u8], glyph: u16) -> &'a [u8] {
+        let glyph = usize::from(glyph);
+        match self.glyph_offsets.get(glyph..glyph + 2) {
+            Some(&[start, end]) => &glyf[start..end],
+            _ => &[],
+        }
+    }
+}
+
+/// Maps characters to glyphs from the Windows Unicode `cmap` subtable,
+/// preferring the full-repertoire format 12 over the BMP-only format 4.
+fn parse_cmap(cmap: &[u8], num_glyphs: usize) -> Option<HashMap<char, u16>> {
+    let mut bmp = None;
+    let mut full = None;
+    for i in 0..usize::from(read_u16(cmap, 2)?) {
+        let record = 4 + i * 8;
+        let platform = read_u16(cmap, record)?;
+        let encoding = read_u16(cmap, record + 2)?;
+        let offset = read_u32(cmap, record + 4)? as usize;
+        match (platform, encoding, read_u16(cmap, offset)?) {
+            (3, 10, 12) => full = Some(offset),
+            (3, 1, 4) => bmp = Some(offset),
+            _ => {}
+        }
+    }
+
+    let mut glyph_ids = HashMap::new();
+    let mut insert = |code: u32, glyph: u32| {
+        if let Some(c) = char::from_u32(code) {
+            if glyph != 0 && (glyph as usize) < num_glyphs {
+                glyph_ids.insert(c, glyph as u16);
+            }
+        }
+    };
+    if let Some(offset) = full {
+        for i in 0..read_u32(cmap, offset + 12)? as usize {
+            let group = offset + 16 + i * 12;
+            let start = read_u32(cmap, group)?;
+            let end = read_u32(cmap, group + 4)?.min(0x10FFFF);
+            let first_glyph = read_u32(cmap, group + 8)?;
+            for code in start..=end {
+                insert(code, first_glyph + (code - start));
+            }
+        }
+    } else {
+        let offset = bmp?;
+        let segments = usize::from(read_u16(cmap, offset + 6)? / 2);
+        let ends = offset + 14;
+        let starts = ends + segments * 2 + 2;
+        let deltas = starts + segments * 2;
+        let range_offsets = deltas + segments * 2;
+        for segment in 0..segments {
+            let end = read_u16(cmap, ends + segment * 2)?;
+            let start = read_u16(cmap, starts + segment * 2)?;
+            let delta = read_u16(cmap, deltas + segment * 2)?;
+            let range_offset_at = range_offsets + segment * 2;
+            let range_offset = usize::from(read_u16(cmap, range_offset_at)?);
+            for code in start..=end.min(0xFFFE) {
+                let glyph = if range_offset == 0 {
+                    code.wrapping_add(delta)
+                } else {
+                    let at = range_offset_at + range_offset + usize::from(code - start) * 2;
+                    match read_u16(cmap, at)? {
+                        0 => 0,
+                        glyph => glyph.wrapping_add(delta),
+                    }
+                };
+                insert(u32::from(code), u32::from(glyph));
+            }
+        }
+    }
+    Some(glyph_ids)
+}
+
+/// Glyphs a composite glyph is built from; empty for simple glyphs.
+fn composite_components(glyph: &[u8]) -> Vec<u16> {
+    let mut components = Vec::new();
+    if !matches!(read_i16(glyph, 0), Some(contours) if contours < 0) {
+        return components;
+    }
+    let mut at = 10;
+    while let (Some(flags), Some(component)) = (read_u16(glyph, at), read_u16(glyph, at + 2)) {
+        components.push(component);
+        at += 4;
+        at += if flags & ARG_1_AND_2_ARE_WORDS != 0 {
+            4
+        } else {
+            2
+        };
+        if flags & WE_HAVE_A_SCALE != 0 {
+            at += 2;
+        } else if flags & WE_HAVE_AN_X_AND_Y_SCALE != 0 {
+            at += 4;
+        } else if flags & WE_HAVE_A_TWO_BY_TWO != 0 {
+            at += 8;
+        }
+        if flags & MORE_COMPONENTS == 0 {
+            break;
+        }
+    }
+    components
+}
+
+/// Serializes `tables` (sorted by tag) as a TrueType font file.
+fn write_font(tables: &[(&[u8; 4], Vec<u8>)]) -> Vec<u8> {
+    let num_tables = tables.len() as u16;
+    let entry_selector = 15 - num_tables.leading_zeros() as u16;
+    let search_range = (1u16 << entry_selector) * 16;
+
+    let mut font = Vec::new();
+    font.extend_from_slice(&0x0001_0000u32.to_be_bytes());
+    for value in [
+        num_tables,
+        search_range,
+        entry_selector,
+        num_tables * 16 - search_range,
+    ] {
+        font.extend_from_slice(&value.to_be_bytes());
+    }
+
+    let mut offset = 12 + tables.len() * 16;
+    for (tag, body) in tables {
+        font.extend_from_slice(*tag);
+        font.extend_from_slice(&checksum(body).to_be_bytes());
+        font.extend_from_slice(&(offset as u32).to_be_bytes());
+        font.extend_from_slice(&(body.len() as u32).to_be_bytes());
+        offset += body.len().next_multiple_of(4);
+    }
+    let mut head_offset = None;
+    for (tag, body) in tables {
+        if *tag == b"head" {
+            head_offset = Some(font.len());
+        }
+        font.extend_from_slice(body);
+        font.resize(font.len().next_multiple_of(4), 0);
+    }
+
+    if let Some(head) = head_offset {
+        let adjustment = 0xB1B0_AFBAu32.wrapping_sub(checksum(&font));
+        font[head + 8..head + 12].copy_from_slice(&adjustment.to_be_bytes());
+    }
+    font
+}
+
+fn checksum(data: &[u8]) -> u32 {
+    data.chunks(4).fold(0u32, |sum, chunk| {
+        let mut word = [0u8; 4];
+        word[..chunk.len()].copy_from_slice(chunk);
+        sum.wrapping_add(u32::from_be_bytes(word))
+    })
+}
+
+fn read_u16(data: &[u8], at: usize) -> Option<u16> {
+    Some(u16::from_be_bytes(data.get(at..at + 2)?.try_into().ok()?))
+}
+
+fn read_i16(data: &[u8], at: usize) -> Option<i16> {
+    read_u16(data, at).map(|value| value as i16)
+}
+
+fn read_u32(data: &[u8], at: usize) -> Option<u32> {
+    Some(u32::from_be_bytes(data.get(at..at + 4)?.try_into().ok()?))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn maps_characters_beyond_win_ansi() {
+        let font = regular();
+
+        for c in ['A', 'é', 'Ω', 'Ж', '€', '→'] {
+            assert!(font.glyph_id(c).is_some(), "{}", c);
+        }
+        assert_eq!(font.glyph_id('日'), None);
+        assert!(
+            font.advance(font.glyph_id('W').unwrap()) > font.advance(font.glyph_id('i').unwrap())
+        );
+    }
+
+    #[test]
+    fn subsets_keep_glyph_ids_and_only_used_outlines() {
+        let font = regular();
+        let used = font.glyph_id('Ω').unwrap();
+        let unused = font.glyph_id('Z').unwrap();
+
+        let bytes = font.subset(&BTreeSet::from([used]));
+        let subset = TrueTypeFont::parse("Subset", bytes.leak()).unwrap();
+        let glyf = subset.table(b"glyf");
+
+        assert!(subset.data.len() < font.data.len() / 4);
+        assert_eq!(subset.advances.len(), font.advances.len());
+        assert!(!subset.glyph(glyf, used).is_empty());
+        assert!(subset.glyph(glyf, unused).is_empty());
+        assert_eq!(checksum(subset.data), 0xB1B0_AFBA);
+    }
+
+    #[test]
+    fn subsets_keep_the_parts_of_composite_glyphs() {
+        let font = regular();
+        let glyf = font.table(b"glyf");
+        let (composite, parts) = font
+            .glyph_ids
+            .values()
+            .map(|&glyph| (glyph, composite_components(font.glyph(glyf, glyph))))
+            .find(|(_, parts)| !parts.is_empty())
+            .unwrap();
+
+        let bytes = font.subset(&BTreeSet::from([composite]));
+        let subset = TrueTypeFont::parse("Subset", bytes.leak()).unwrap();
+
+        for part in parts {
+            assert!(!subset.glyph(subset.table(b"glyf"), part).is_empty());
+        }
+    }
+}
@@ -0,0 +1,7 @@
+//! PDF rendering of the decision document.
+
+mod exporter;
+mod font;
+mod writer;
+
+pub use exporter::PdfDocumentExporter;
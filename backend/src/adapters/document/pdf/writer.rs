@@ -0,0 +1,1032 @@
+//! Minimal PDF 1.4 writer.
+//!
+//! Produces text-and-rectangle documents. Text is set in the bundled DejaVu
+//! Sans (see [`super::font`]), embedded as a subset of the glyphs each
+//! document uses, so any character the font covers renders as itself and
+//! only characters it lacks (such as CJK) are replaced with `?`. Text is
+//! shown by glyph id, with a `ToUnicode` map so it can still be searched and
+//! copied.
+//!
+//! JPEG images are embedded as-is: PDF decodes baseline and progressive JPEG
+//! natively (`DCTDecode`), so only the dimensions need to be read from the
+//! file header. So are non-interlaced PNGs of up to 8 bits per sample
+//! without alpha, whose compressed data is a valid `FlateDecode` stream with
+//! PNG predictors. PNGs with alpha or 16-bit samples are decoded and
+//! re-compressed, with the alpha channel drawn as a soft mask. Interlaced
+//! PNGs and other formats are not supported.
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt::Write as _;
+use std::io::{Read, Write};
+
+use flate2::read::ZlibDecoder;
+use flate2::write::ZlibEncoder;
+use flate2::Compression;
+
+use super::font::{self, TrueTypeFont};
+
+/// US Letter width in points.
+pub const PAGE_WIDTH: f32 = 612.0;
+
+/// US Letter height in points.
+pub const PAGE_HEIGHT: f32 = 792.0;
+
+const PNG_SIGNATURE: &[u8] = b"\x89PNG\r\n\x1a\n";
+
+/// Largest PNG accepted for decoding, in decompressed bytes, so a small file
+/// cannot inflate into an unbounded allocation.
+const MAX_DECODED_PNG_BYTES: usize = 64 * 1024 * 1024;
+
+/// Font used for a text run.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Font {
+    Regular,
+    Bold,
+}
+
+impl Font {
+    fn resource_name(self) -> &'static str {
+        match self {
+            Font::Regular => "F1",
+            Font::Bold => "F2",
+        }
+    }
+
+    fn face(self) -> &'static TrueTypeFont {
+        match self {
+            Font::Regular => font::regular(),
+            Font::Bold => font::bold(),
+        }
+    }
+
+    /// The glyph drawn for `c` and the character it shows: `?` for
+    /// characters the font lacks, and a space for tabs and line breaks.
+    fn glyph(self, c: char) -> (u16, char) {
+        let c = if matches!(c, '\t' | '\n' | '\r') {
+            ' '
+        } else {
+            c
+        };
+        match self.face().glyph_id(c) {
+            Some(glyph) => (glyph, c),
+            None => (self.face().glyph_id('?').unwrap_or(0), '?'),
+        }
+    }
+}
+
+/// RGB color with components in `0.0..=1.0`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Color(pub f32, pub f32, pub f32);
+
+impl Color {
+    pub const BLACK: Color = Color(0.0, 0.0, 0.0);
+    pub const GRAY: Color = Color(0.45, 0.45, 0.45);
+}
+
+/// Drawing operations for one page.
+#[derive(Debug, Default, Clone)]
+pub struct PageCanvas {
+    content: String,
+    /// Glyphs drawn, by font, with the character each shows.
+    glyphs: BTreeMap<(Font, u16), char>,
+}
+
+impl PageCanvas {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Draws a single line of text with its baseline at `(x, y)`.
+    pub fn text(&mut self, x: f32, y: f32, font: Font, size: f32, color: Color, text: &str) {
+        let mut glyph_ids = String::with_capacity(text.len() * 4);
+        for c in text.chars() {
+            let (glyph, shown) = font.glyph(c);
+            self.glyphs.insert((font, glyph), shown);
+            let _ = write!(glyph_ids, "{:04X}", glyph);
+        }
+        let _ = writeln!(
+            self.content,
+            "BT {:.3} {:.3} {:.3} rg /{} {:.1} Tf {:.2} {:.2} Td <{}> Tj ET",
+            color.0,
+            color.1,
+            color.2,
+            font.resource_name(),
+            size,
+            x,
+            y,
+            glyph_ids
+        );
+    }
+
+    /// Fills a rectangle whose lower-left corner is `(x, y)`.
+    pub fn fill_rect(&mut self, x: f32, y: f32, width: f32, height: f32, color: Color) {
+        let _ = writeln!(
+            self.content,
+            "{:.3} {:.3} {:.3} rg {:.2} {:.2} {:.2} {:.2} re f",
+            color.0, color.1, color.2, x, y, width, height
+        );
+    }
+
+    /// Strokes a straight line.
+    pub fn line(&mut self, x1: f32, y1: f32, x2: f32, y2: f32, width: f32, color: Color) {
+        let _ = writeln!(
+            self.content,
+            "{:.3} {:.3} {:.3} RG {:.2} w {:.2} {:.2} m {:.2} {:.2} l S",
+            color.0, color.1, color.2, width, x1, y1, x2, y2
+        );
+    }
+
+    /// Draws image `index` (as returned by [`PdfWriter::add_image`]) scaled
+    /// to `width` x `height` with its lower-left corner at `(x, y)`.
+    pub fn image(&mut self, index: usize, x: f32, y: f32, width: f32, height: f32) {
+        let _ = writeln!(
+            self.content,
+            "q {:.2} 0 0 {:.2} {:.2} {:.2} cm /Im{} Do Q",
+            width,
+            height,
+            x,
+            y,
+            index + 1
+        );
+    }
+}
+
+/// A JPEG or PNG file ready to embed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Image {
+    pub width: u32,
+    pub height: u32,
+    color_space: String,
+    bits_per_component: u8,
+    filter: &'static str,
+    decode_parms: Option<String>,
+    data: Vec<u8>,
+    /// Flate-compressed 8-bit alpha channel, drawn as a soft mask.
+    alpha: Option<Vec<u8>>,
+}
+
+impl Image {
+    /// Reads a JPEG or PNG. Returns `None` for other formats and for
+    /// variants PDF cannot draw.
+    pub fn parse(data: Vec<u8>) -> Option<Self> {
+        if data.starts_with(&[0xFF, 0xD8]) {
+            Self::parse_jpeg(data)
+        } else if data.starts_with(PNG_SIGNATURE) {
+            Self::parse_png(&data)
+        } else {
+            None
+        }
+    }
+
+    /// Reads the frame header of a grayscale or RGB JPEG. CMYK JPEGs are
+    /// rejected, since their color handling varies by encoder.
+    fn parse_jpeg(data: Vec<u8>) -> Option<Self> {
+        let mut i = 2;
+        while i + 4 <= data.len() {
+            if data[i] != 0xFF {
+                return None;
+            }
+            let marker = data[i + 1];
+            match marker {
+                // Fill byte before a marker
+                0xFF => {
+                    i += 1;
+                    continue;
+                }
+                // Markers without a length field
+                0x01 | 0xD0..=0xD7 => {
+                    i += 2;
+                    continue;
+                }
+                _ => {}
+            }
+            let length = usize::from(u16::from_be_bytes([data[i + 2], data[i + 3]]));
+            let is_frame = matches!(marker, 0xC0..=0xCF) && !matches!(marker, 0xC4 | 0xC8 | 0xCC);
+            if is_frame {
+                let header = data.get(i + 4..i + 10)?;
+                let height = u32::from(u16::from_be_bytes([header[1], header[2]]));
+                let width = u32::from(u16::from_be_bytes([header[3], header[4]]));
+                let color_space = match header[5] {
+                    1 => "/DeviceGray",
+                    3 => "/DeviceRGB",
+                    _ => return None,
+                };
+                if width == 0 || height == 0 {
+                    return None;
+                }
+                return Some(Self {
+                    width,
+                    height,
+                    color_space: color_space.to_string(),
+                    bits_per_component: 8,
+                    filter: "DCTDecode",
+                    decode_parms: None,
+                    data,
+                    alpha: None,
+                });
+            }
+            i += 2 + length;
+        }
+        None
+    }
+
+    fn parse_png(data: &[u8]) -> Option<Self> {
+        let mut header = None;
+        let mut palette = None;
+        let mut compressed = Vec::new();
+        let mut at = PNG_SIGNATURE.len();
+        while let Some(length) = data.get(at..at + 4) {
+            let length = u32::from_be_bytes(length.try_into().ok()?) as usize;
+            let kind = data.get(at + 4..at + 8)?;
+            let body = data.get(at + 8..(at + 8).checked_add(length)?)?;
+            match kind {
+                b"IHDR" => header = Some(body),
+                b"PLTE" => palette = Some(body),
+                b"IDAT" => compressed.extend_from_slice(body),
+                b"IEND" => break,
+                _ => {}
+            }
+            at += 12 + length;
+        }
+
+        let header = header.filter(|header| header.len() >= 13)?;
+        let width = u32::from_be_bytes(header[0..4].try_into().ok()?);
+        let height = u32::from_be_bytes(header[4..8].try_into().ok()?);
+        let (depth, color_type, interlaced) = (header[8], header[9], header[12] != 0);
+        if width == 0 || height == 0 || interlaced || compressed.is_empty() {
+            return None;
+        }
+        let (channels, valid_depth) = match color_type {
+            0 => (1, matches!(depth, 1 | 2 | 4 | 8 | 16)),
+            2 => (3, matches!(depth, 8 | 16)),
+            3 => (1, matches!(depth, 1 | 2 | 4 | 8)),
+            4 => (2, matches!(depth, 8 | 16)),
+            6 => (4, matches!(depth, 8 | 16)),
+            _ => return None,
+        };
+        if !valid_depth {
+            return None;
+        }
+        let color_space = match color_type {
+            0 | 4 => "/DeviceGray".to_string(),
+            2 | 6 => "/DeviceRGB".to_string(),
+            _ => {
+                let palette = palette
+                    .filter(|palette| !palette.is_empty() && palette.len() % 3 == 0)
+                    .filter(|palette| palette.len() <= 256 * 3)?;
+                format!(
+                    "[/Indexed /DeviceRGB {} <{}>]",
+                    palette.len() / 3 - 1,
+                    hex(palette)
+                )
+            }
+        };
+
+        let has_alpha = matches!(color_type, 4 | 6);
+        if depth <= 8 && !has_alpha {
+            return Some(Self {
+                width,
+                height,
+                color_space,
+                bits_per_component: depth,
+                filter: "FlateDecode",
+                decode_parms: Some(format!(
+                    "<< /Predictor 15 /Colors {} /BitsPerComponent {} /Columns {} >>",
+                    channels, depth, width
+                )),
+                data: compressed,
+                alpha: None,
+            });
+        }
+
+        // Split into 8-bit color and alpha planes, keeping the high byte of
+        // 16-bit samples
+        let pixels = unfilter_png(&compressed, width, height, channels, depth)?;
+        let sample_bytes = usize::from(depth / 8);
+        let color_channels = if has_alpha { channels - 1 } else { channels };
+        let mut color = Vec::with_capacity(pixels.len() / sample_bytes);
+        let mut alpha = Vec::new();
+        for pixel in pixels.chunks_exact(channels * sample_bytes) {
+            for (channel, sample) in pixel.chunks_exact(sample_bytes).enumerate() {
+                if channel < color_channels {
+                    color.push(sample[0]);
+                } else {
+                    alpha.push(sample[0]);
+                }
+            }
+        }
+        Some(Self {
+            width,
+            height,
+            color_space,
+            bits_per_component: 8,
+            filter: "FlateDecode",
+            decode_parms: None,
+            data: deflate(&color),
+            alpha: has_alpha.then(|| deflate(&alpha)),
+        })
+    }
+
+    /// Objects embedding the image: the image itself with id `id`, then its
+    /// soft mask, if any.
+    fn objects(&self, id: usize) -> Vec<Vec<u8>> {
+        let mut dictionary = format!(
+            "<< /Type /XObject /Subtype /Image /Width {} /Height {} /ColorSpace {} \
+             /BitsPerComponent {} /Filter /{}",
+            self.width, self.height, self.color_space, self.bits_per_component, self.filter
+        );
+        if let Some(decode_parms) = &self.decode_parms {
+            let _ = write!(dictionary, " /DecodeParms {}", decode_parms);
+        }
+        if self.alpha.is_some() {
+            let _ = write!(dictionary, " /SMask {} 0 R", id + 1);
+        }
+        let mut objects = vec![stream_object(&dictionary, &self.data)];
+        if let Some(alpha) = &self.alpha {
+            let dictionary = format!(
+                "<< /Type /XObject /Subtype /Image /Width {} /Height {} \
+                 /ColorSpace /DeviceGray /BitsPerComponent 8 /Filter /FlateDecode",
+                self.width, self.height
+            );
+            objects.push(stream_object(&dictionary, alpha));
+        }
+        objects
+    }
+
+    fn object_count(&self) -> usize {
+        1 + usize::from(self.alpha.is_some())
+    }
+}
+
+/// Reverses PNG's per-row filters, returning the raw samples.
+fn unfilter_png(
+    compressed: &[u8],
+    width: u32,
+    height: u32,
+    channels: usize,
+    depth: u8,
+) -> Option<Vec<u8>> {
+    let bits_per_pixel = channels * usize::from(depth);
+    let pixel_bytes = bits_per_pixel.div_ceil(8);
+    let stride = (width as usize).checked_mul(bits_per_pixel)?.div_ceil(8);
+    let height = height as usize;
+    let filtered_len = (stride + 1).checked_mul(height)?;
+    if filtered_len > MAX_DECODED_PNG_BYTES {
+        return None;
+    }
+
+    let mut filtered = Vec::with_capacity(filtered_len);
+    ZlibDecoder::new(compressed)
+        .take(filtered_len as u64)
+        .read_to_end(&mut filtered)
+        .ok()?;
+    if filtered.len() != filtered_len {
+        return None;
+    }
+
+    let mut pixels = vec![0u8; stride * height];
+    for (row, line) in filtered.chunks_exact(stride + 1).enumerate() {
+        let (done, rest) = pixels.split_at_mut(row * stride);
+        let previous = row
+            .checked_sub(1)
+            .map(|previous| &done[previous * stride..]);
+        let current = &mut rest[..stride];
+        for i in 0..stride {
+            let left = if i >= pixel_bytes {
+                current[i - pixel_bytes]
+            } else {
+                0
+            };
+            let up = previous.map_or(0, |previous| previous[i]);
+            let up_left = match previous {
+                Some(previous) if i >= pixel_bytes => previous[i - pixel_bytes],
+                _ => 0,
+            };
+            let prediction = match line[0] {
+                0 => 0,
+                1 => left,
+                2 => up,
+                3 => ((u16::from(left) + u16::from(up)) / 2) as u8,
+                4 => paeth(left, up, up_left),
+                _ => return None,
+            };
+            current[i] = line[i + 1].wrapping_add(prediction);
+        }
+    }
+    Some(pixels)
+}
+
+fn paeth(left: u8, up: u8, up_left: u8) -> u8 {
+    let estimate = i16::from(left) + i16::from(up) - i16::from(up_left);
+    let distance = |value: u8| (estimate - i16::from(value)).abs();
+    if distance(left) <= distance(up) && distance(left) <= distance(up_left) {
+        left
+    } else if distance(up) <= distance(up_left) {
+        up
+    } else {
+        up_left
+    }
+}
+
+/// A PDF document assembled page by page.
+#[derive(Debug, Default)]
+pub struct PdfWriter {
+    title: String,
+    pages: Vec<String>,
+    images: Vec<Image>,
+    glyphs: BTreeMap<(Font, u16), char>,
+}
+
+impl PdfWriter {
+    pub fn new(title: impl Into<String>) -> Self {
+        Self {
+            title: title.into(),
+            ..Self::default()
+        }
+    }
+
+    /// Adds an image any page can draw, returning its index.
+    pub fn add_image(&mut self, image: Image) -> usize {
+        self.images.push(image);
+        self.images.len() - 1
+    }
+
+    pub fn add_page(&mut self, canvas: PageCanvas) {
+        self.pages.push(canvas.content);
+        self.glyphs.extend(canvas.glyphs);
+    }
+
+    /// Serializes the document.
+    pub fn finish(self) -> Vec<u8> {
+        // Object layout: 1 catalog, 2 page tree, 3-4 fonts, 5 info, then a
+        // page object and a content stream per page, then the images (with
+        // their soft masks), then the objects each font is built from.
+        const FIRST_PAGE_OBJECT: usize = 6;
+        let page_ids: Vec<usize> = (0..self.pages.len())
+            .map(|i| FIRST_PAGE_OBJECT + i * 2)
+            .collect();
+        let mut image_ids = Vec::with_capacity(self.images.len());
+        let mut next_id = FIRST_PAGE_OBJECT + self.pages.len() * 2;
+        for image in &self.images {
+            image_ids.push(next_id);
+            next_id += image.object_count();
+        }
+        let x_objects = if self.images.is_empty() {
+            String::new()
+        } else {
+            let entries: Vec<String> = image_ids
+                .iter()
+                .enumerate()
+                .map(|(i, id)| format!("/Im{} {} 0 R", i + 1, id))
+                .collect();
+            format!(" /XObject << {} >>", entries.join(" "))
+        };
+        let fonts: Vec<EmbeddedFont> = [Font::Regular, Font::Bold]
+            .into_iter()
+            .map(|font| {
+                let glyphs = self
+                    .glyphs
+                    .range((font, 0)..=(font, u16::MAX))
+                    .map(|(&(_, glyph), &c)| (glyph, c))
+                    .collect();
+                let embedded = EmbeddedFont::new(font.face(), &glyphs, next_id);
+                next_id += EmbeddedFont::OBJECT_COUNT;
+                embedded
+            })
+            .collect();
+
+        let mut objects: Vec<Vec<u8>> = Vec::with_capacity(next_id);
+        objects.push(b"<< /Type /Catalog /Pages 2 0 R >>".to_vec());
+        let kids: Vec<String> = page_ids.iter().map(|id| format!("{} 0 R", id)).collect();
+        objects.push(
+            format!(
+                "<< /Type /Pages /Kids [{}] /Count {} >>",
+                kids.join(" "),
+                page_ids.len()
+            )
+            .into_bytes(),
+        );
+        for font in &fonts {
+            objects.push(font.type0.clone());
+        }
+        objects.push(
+            format!(
+                "<< /Title <FEFF{}> /Producer (Choice Sherpa) >>",
+                utf16_hex(&self.title)
+            )
+            .into_bytes(),
+        );
+
+        for (page_id, content) in page_ids.iter().zip(self.pages) {
+            objects.push(
+                format!(
+                    "<< /Type /Page /Parent 2 0 R /MediaBox [0 0 {} {}] \
+                     /Resources << /Font << /F1 3 0 R /F2 4 0 R >>{} >> /Contents {} 0 R >>",
+                    PAGE_WIDTH,
+                    PAGE_HEIGHT,
+                    x_objects,
+                    page_id + 1
+                )
+                .into_bytes(),
+            );
+            objects.push(stream_object("<<", content.as_bytes()));
+        }
+        for (image, id) in self.images.iter().zip(image_ids) {
+            objects.extend(image.objects(id));
+        }
+        for font in fonts {
+            objects.extend(font.objects);
+        }
+
+        let mut out = b"%PDF-1.4\n%\xe2\xe3\xcf\xd3\n".to_vec();
+        let mut offsets = Vec::with_capacity(objects.len());
+        for (i, body) in objects.iter().enumerate() {
+            offsets.push(out.len());
+            out.extend_from_slice(format!("{} 0 obj\n", i + 1).as_bytes());
+            out.extend_from_slice(body);
+            out.extend_from_slice(b"\nendobj\n");
+        }
+
+        let xref_offset = out.len();
+        let mut xref = format!("xref\n0 {}\n0000000000 65535 f \n", objects.len() + 1);
+        for offset in offsets {
+            let _ = writeln!(xref, "{:010} 00000 n ", offset);
+        }
+        let _ = writeln!(
+            xref,
+            "trailer\n<< /Size {} /Root 1 0 R /Info 5 0 R >>\nstartxref\n{}\n%%EOF",
+            objects.len() + 1,
+            xref_offset
+        );
+        out.extend_from_slice(xref.as_bytes());
+        out
+    }
+}
+
+/// A font subset as a `Type0` font showing glyph ids (`Identity-H`).
+struct EmbeddedFont {
+    /// The font dictionary pages refer to.
+    type0: Vec<u8>,
+    /// Its descendant CID font, descriptor, font file, and `ToUnicode` map,
+    /// numbered from the id passed to [`EmbeddedFont::new`].
+    objects: Vec<Vec<u8>>,
+}
+
+impl EmbeddedFont {
+    const OBJECT_COUNT: usize = 4;
+
+    fn new(face: &TrueTypeFont, glyphs: &BTreeMap<u16, char>, first_id: usize) -> Self {
+        let [cid_font_id, descriptor_id, file_id, to_unicode_id] =
+            [0, 1, 2, 3].map(|i| first_id + i);
+        let name = format!("{}+{}", subset_tag(face.name(), glyphs), face.name());
+
+        let type0 = format!(
+            "<< /Type /Font /Subtype /Type0 /BaseFont /{} /Encoding /Identity-H \
+             /DescendantFonts [{} 0 R] /ToUnicode {} 0 R >>",
+            name, cid_font_id, to_unicode_id
+        );
+        let widths: Vec<String> = glyphs
+            .keys()
+            .map(|&glyph| format!("{} [{}]", glyph, face.advance(glyph)))
+            .collect();
+        let cid_font = format!(
+            "<< /Type /Font /Subtype /CIDFontType2 /BaseFont /{} \
+             /CIDSystemInfo << /Registry (Adobe) /Ordering (Identity) /Supplement 0 >> \
+             /FontDescriptor {} 0 R /W [{}] /CIDToGIDMap /Identity >>",
+            name,
+            descriptor_id,
+            widths.join(" ")
+        );
+        let [x_min, y_min, x_max, y_max] = face.bbox();
+        let descriptor = format!(
+            "<< /Type /FontDescriptor /FontName /{} /Flags 32 /FontBBox [{} {} {} {}] \
+             /ItalicAngle 0 /Ascent {} /Descent {} /CapHeight {} /StemV 80 /FontFile2 {} 0 R >>",
+            name,
+            x_min,
+            y_min,
+            x_max,
+            y_max,
+            face.ascent(),
+            face.descent(),
+            face.cap_height(),
+            file_id
+        );
+        let subset = face.subset(&glyphs.keys().copied().collect::<BTreeSet<_>>());
+        let file = stream_object(
+            &format!("<< /Length1 {} /Filter /FlateDecode", subset.len()),
+            &deflate(&subset),
+        );
+
+        Self {
+            type0: type0.into_bytes(),
+            objects: vec![
+                cid_font.into_bytes(),
+                descriptor.into_bytes(),
+                file,
+                stream_object("<<", to_unicode_cmap(glyphs).as_bytes()),
+            ],
+        }
+    }
+}
+
+/// Maps glyph ids back to the characters they show, for text extraction.
+fn to_unicode_cmap(glyphs: &BTreeMap<u16, char>) -> String {
+    let mut cmap = String::from(
+        "/CIDInit /ProcSet findresource begin\n12 dict begin\nbegincmap\n\
+         /CIDSystemInfo << /Registry (Adobe) /Ordering (UCS) /Supplement 0 >> def\n\
+         /CMapName /Adobe-Identity-UCS def\n/CMapType 2 def\n\
+         1 begincodespacerange\n<0000> <FFFF>\nendcodespacerange\n",
+    );
+    let glyphs: Vec<(&u16, &char)> = glyphs.iter().collect();
+    // At most 100 mappings per block
+    for block in glyphs.chunks(100) {
+        let _ = writeln!(cmap, "{} beginbfchar", block.len());
+        for (glyph, c) in block {
+            let _ = writeln!(cmap, "<{:04X}> <{}>", glyph, utf16_hex(&c.to_string()));
+        }
+        cmap.push_str("endbfchar\n");
+    }
+    cmap.push_str("endcmap\nCMapName currentdict /CMap defineresource pop\nend\nend");
+    cmap
+}
+
+/// The six uppercase letters PDF requires before a subset font's name,
+/// derived from the glyphs so different subsets get different names.
+fn subset_tag(name: &str, glyphs: &BTreeMap<u16, char>) -> String {
+    // FNV-1a
+    let mut hash: u32 = 0x811C_9DC5;
+    let bytes = name
+        .bytes()
+        .chain(glyphs.keys().flat_map(|glyph| glyph.to_be_bytes()));
+    for byte in bytes {
+        hash = (hash ^ u32::from(byte)).wrapping_mul(0x0100_0193);
+    }
+    (0..6)
+        .map(|i| char::from(b'A' + (hash >> (i * 5) & 31) as u8 % 26))
+        .collect()
+}
+
+/// A stream object: `dictionary` (unterminated, so `/Length` can be added)
+/// followed by `data`.
+fn stream_object(dictionary: &str, data: &[u8]) -> Vec<u8> {
+    let mut object = format!("{} /Length {} >>\nstream\n", dictionary, data.len()).into_bytes();
+    object.extend_from_slice(data);
+    object.extend_from_slice(b"\nendstream");
+    object
+}
+
+fn deflate(data: &[u8]) -> Vec<u8> {
+    let mut encoder = ZlibEncoder::new(Vec::new(), Compression::default());
+    encoder
+        .write_all(data)
+        .and_then(|()| encoder.finish())
+        .expect("compressing into memory cannot fail")
+}
+
+fn hex(data: &[u8]) -> String {
+    data.iter()
+        .fold(String::with_capacity(data.len() * 2), |mut hex, byte| {
+            let _ = write!(hex, "{:02X}", byte);
+            hex
+        })
+}
+
+/// `text` as UTF-16BE hex, the encoding of PDF text strings beyond ASCII.
+fn utf16_hex(text: &str) -> String {
+    let bytes: Vec<u8> = text.encode_utf16().flat_map(u16::to_be_bytes).collect();
+    hex(&bytes)
+}
+
+/// Width of `text` in points when set in `font` at `size`.
+pub fn text_width(text: &str, font: Font, size: f32) -> f32 {
+    let face = font.face();
+    let units: u32 = text
+        .chars()
+        .map(|c| u32::from(face.advance(font.glyph(c).0)))
+        .sum();
+    units as f32 * size / 1000.0
+}
+
+/// Breaks `text` into lines no wider than `max_width`.
+///
+/// Words longer than a full line are split mid-word.
+pub fn wrap_text(text: &str, font: Font, size: f32, max_width: f32) -> Vec<String> {
+    let mut lines = Vec::new();
+    for paragraph in text.lines() {
+        let mut line = String::new();
+        for word in paragraph.split_whitespace() {
+            let candidate = if line.is_empty() {
+                word.to_string()
+            } else {
+                format!("{} {}", line, word)
+            };
+            if text_width(&candidate, font, size) <= max_width {
+                line = candidate;
+                continue;
+            }
+            if !line.is_empty() {
+                lines.push(std::mem::take(&mut line));
+            }
+            // Split words that cannot fit on a line of their own
+            for c in word.chars() {
+                line.push(c);
+                if text_width(&line, font, size) > max_width && line.chars().count() > 1 {
+                    line.pop();
+                    lines.push(std::mem::take(&mut line));
+                    line.push(c);
+                }
+            }
+        }
+        if !line.is_empty() || lines.is_empty() {
+            lines.push(line);
+        }
+    }
+    lines
+}
+
+/// Wraps `text` and keeps at most `max_lines`, ending the last with an ellipsis
+/// when anything was cut.
+pub fn wrap_clamped(
+    text: &str,
+    font: Font,
+    size: f32,
+    max_width: f32,
+    max_lines: usize,
+) -> Vec<String> {
+    let mut lines = wrap_text(text, font, size, max_width);
+    if lines.len() > max_lines {
+        lines.truncate(max_lines);
+        if let Some(last) = lines.last_mut() {
+            while !last.is_empty() && text_width(&format!("{}…", last), font, size) > max_width {
+                last.pop();
+            }
+            last.push('…');
+        }
+    }
+    lines
+}
+
+#[cfg(test)]
+pub(super) mod fixtures {
+    //! Reads back the text a PDF from [`super::PdfWriter`] shows.
+
+    use super::Font;
+
+    /// Each text run shown, in order, decoded from its glyph ids.
+    pub fn shown_text(pdf: &[u8]) -> Vec<String> {
+        String::from_utf8_lossy(pdf)
+            .lines()
+            .filter(|line| line.starts_with("BT ") && line.ends_with("> Tj ET"))
+            .map(|line| {
+                let font = if line.contains("/F2 ") {
+                    Font::Bold
+                } else {
+                    Font::Regular
+                };
+                let glyph_ids = &line[line.rfind('<').unwrap() + 1..line.rfind('>').unwrap()];
+                (0..glyph_ids.len())
+                    .step_by(4)
+                    .map(|i| u16::from_str_radix(&glyph_ids[i..i + 4], 16).unwrap())
+                    .map(|glyph| font.face().char_for(glyph).unwrap_or('\u{fffd}'))
+                    .collect()
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::fixtures::shown_text;
+    use super::*;
+
+    #[test]
+    fn shows_text_by_glyph_id_including_non_win_ansi_characters() {
+        let mut canvas = PageCanvas::new();
+        canvas.text(
+            72.0,
+            720.0,
+            Font::Regular,
+            12.0,
+            Color::BLACK,
+            "Δ café (ok)\t→ Жить",
+        );
+        canvas.text(72.0, 700.0, Font::Bold, 12.0, Color::BLACK, "日本");
+        let mut writer = PdfWriter::new("Решение");
+        writer.add_page(canvas);
+
+        let bytes = writer.finish();
+
+        assert_eq!(shown_text(&bytes), ["Δ café (ok) → Жить", "??"]);
+        let text = String::from_utf8_lossy(&bytes);
+        assert!(text.contains("/Subtype /Type0"));
+        assert!(text.contains("/Encoding /Identity-H"));
+        assert!(text.contains("/FontFile2"));
+        // The ToUnicode map covers the Cyrillic and Greek letters
+        assert!(text.contains(&format!("<{:04X}> <0416>", Font::Regular.glyph('Ж').0)));
+        assert!(text.contains(&format!("/Title <FEFF{}>", utf16_hex("Решение"))));
+    }
+
+    #[test]
+    fn embeds_only_the_glyphs_used() {
+        let render = |text: &str| {
+            let mut canvas = PageCanvas::new();
+            canvas.text(72.0, 720.0, Font::Regular, 12.0, Color::BLACK, text);
+            let mut writer = PdfWriter::new("Fonts");
+            writer.add_page(canvas);
+            writer.finish()
+        };
+
+        let short = render("Hi");
+        let long = render(&('!'..='~').chain('Α'..='ω').collect::<String>());
+
+        assert!(short.len() < 40_000, "{} bytes", short.len());
+        assert!(long.len() > short.len());
+    }
+
+    #[test]
+    fn text_width_uses_the_font_metrics() {
+        assert!(text_width("WWW", Font::Regular, 10.0) > text_width("iii", Font::Regular, 10.0));
+        assert!(text_width("abc", Font::Bold, 10.0) > text_width("abc", Font::Regular, 10.0));
+        assert_eq!(text_width("", Font::Regular, 10.0), 0.0);
+    }
+
+    #[test]
+    fn wraps_on_word_boundaries() {
+        let lines = wrap_text("alpha beta gamma delta", Font::Regular, 10.0, 60.0);
+        assert!(lines.len() > 1);
+        for line in &lines {
+            assert!(text_width(line, Font::Regular, 10.0) <= 60.0);
+        }
+        assert_eq!(lines.join(" "), "alpha beta gamma delta");
+    }
+
+    #[test]
+    fn splits_words_longer_than_a_line() {
+        let lines = wrap_text(&"x".repeat(40), Font::Regular, 10.0, 50.0);
+        assert!(lines.len() > 1);
+        assert_eq!(lines.concat().len(), 40);
+    }
+
+    #[test]
+    fn clamps_to_max_lines_with_ellipsis() {
+        let text = "one two three four five six seven eight nine ten";
+        let lines = wrap_clamped(text, Font::Regular, 10.0, 40.0, 2);
+        assert_eq!(lines.len(), 2);
+        assert!(lines[1].ends_with('…'));
+    }
+
+    #[test]
+    fn writes_well_formed_document() {
+        let mut writer = PdfWriter::new("Test");
+        let mut canvas = PageCanvas::new();
+        canvas.text(72.0, 720.0, Font::Bold, 12.0, Color::BLACK, "Hello");
+        writer.add_page(canvas);
+        writer.add_page(PageCanvas::new());
+
+        let bytes = writer.finish();
+        let text = String::from_utf8_lossy(&bytes);
+
+        assert!(bytes.starts_with(b"%PDF-1.4"));
+        assert!(text.ends_with("%%EOF\n"));
+        assert!(text.contains("/Count 2"));
+        assert_eq!(shown_text(&bytes), ["Hello"]);
+
+        // startxref must point at the xref table
+        let startxref: usize = text
+            .rsplit("startxref\n")
+            .next()
+            .unwrap()
+            .lines()
+            .next()
+            .unwrap()
+            .parse()
+            .unwrap();
+        assert!(bytes[startxref..].starts_with(b"xref"));
+    }
+
+    /// Smallest header a JPEG parser needs: SOI, an APP0 segment, and a
+    /// baseline frame header for a 3x2 RGB image.
+    fn tiny_jpeg() -> Vec<u8> {
+        vec![
+            0xFF, 0xD8, // SOI
+            0xFF, 0xE0, 0x00, 0x04, 0x00, 0x00, // APP0
+            0xFF, 0xC0, 0x00, 0x11, 0x08, 0x00, 0x02, 0x00, 0x03, 0x03, // SOF0
+            0x01, 0x11, 0x00, 0x02, 0x11, 0x01, 0x03, 0x11, 0x01, // components
+            0xFF, 0xD9, // EOI
+        ]
+    }
+
+    /// A non-interlaced PNG of `rows`, each starting with its filter byte.
+    fn png(width: u32, depth: u8, color_type: u8, rows: &[&[u8]]) -> Vec<u8> {
+        let chunk = |kind: &[u8], body: &[u8]| {
+            let mut chunk = (body.len() as u32).to_be_bytes().to_vec();
+            chunk.extend_from_slice(kind);
+            chunk.extend_from_slice(body);
+            // Readers here do not check the CRC
+            chunk.extend_from_slice(&[0; 4]);
+            chunk
+        };
+        let mut header = width.to_be_bytes().to_vec();
+        header.extend_from_slice(&(rows.len() as u32).to_be_bytes());
+        header.extend_from_slice(&[depth, color_type, 0, 0, 0]);
+
+        let mut png = PNG_SIGNATURE.to_vec();
+        png.extend(chunk(b"IHDR", &header));
+        png.extend(chunk(b"IDAT", &deflate(&rows.concat())));
+        png.extend(chunk(b"IEND", &[]));
+        png
+    }
+
+    #[test]
+    fn reads_jpeg_dimensions() {
+        let image = Image::parse(tiny_jpeg()).unwrap();
+        assert_eq!((image.width, image.height), (3, 2));
+        assert!(Image::parse(b"\x89PNG\r\n".to_vec()).is_none());
+        assert!(Image::parse(b"GIF89a".to_vec()).is_none());
+        assert!(Image::parse(vec![0xFF, 0xD8]).is_none());
+    }
+
+    #[test]
+    fn passes_opaque_png_data_through_with_predictors() {
+        let data = png(
+            2,
+            8,
+            2,
+            &[&[0, 255, 0, 0, 0, 255, 0], &[2, 0, 0, 0, 0, 0, 0]],
+        );
+
+        let image = Image::parse(data).unwrap();
+
+        assert_eq!((image.width, image.height), (2, 2));
+        assert_eq!(image.color_space, "/DeviceRGB");
+        assert!(image.alpha.is_none());
+        assert_eq!(
+            image.decode_parms.as_deref(),
+            Some("<< /Predictor 15 /Colors 3 /BitsPerComponent 8 /Columns 2 >>")
+        );
+    }
+
+    #[test]
+    fn decodes_png_alpha_into_a_soft_mask() {
+        // Row 1 unfiltered; row 2 uses the Up filter, so it repeats row 1
+        let data = png(
+            2,
+            8,
+            6,
+            &[
+                &[0, 10, 20, 30, 255, 40, 50, 60, 0],
+                &[2, 0, 0, 0, 0, 0, 0, 0, 0],
+            ],
+        );
+
+        let image = Image::parse(data).unwrap();
+
+        let inflate = |data: &[u8]| {
+            let mut out = Vec::new();
+            ZlibDecoder::new(data).read_to_end(&mut out).unwrap();
+            out
+        };
+        assert_eq!(
+            inflate(&image.data),
+            [10, 20, 30, 40, 50, 60, 10, 20, 30, 40, 50, 60]
+        );
+        assert_eq!(inflate(image.alpha.as_ref().unwrap()), [255, 0, 255, 0]);
+
+        let mut writer = PdfWriter::new("Alpha");
+        writer.add_image(image);
+        writer.add_page(PageCanvas::new());
+        let text = String::from_utf8_lossy(&writer.finish()).into_owned();
+        assert!(text.contains("/SMask 9 0 R"));
+        assert!(text.contains(
+            "9 0 obj\n<< /Type /XObject /Subtype /Image /Width 2 /Height 2 /ColorSpace /DeviceGray"
+        ));
+    }
+
+    #[test]
+    fn rejects_unsupported_pngs() {
+        let mut interlaced = png(1, 8, 2, &[&[0, 1, 2, 3]]);
+        interlaced[8 + 8 + 12] = 1;
+        assert!(Image::parse(interlaced).is_none());
+
+        // Palette images need their palette
+        assert!(Image::parse(png(1, 8, 3, &[&[0, 0]])).is_none());
+        // Truncated image data
+        assert!(Image::parse(png(4, 8, 6, &[&[0, 1, 2, 3]])).is_none());
+    }
+
+    #[test]
+    fn embeds_images_as_xobjects() {
+        let mut writer = PdfWriter::new("Images");
+        let index = writer.add_image(Image::parse(tiny_jpeg()).unwrap());
+        let mut canvas = PageCanvas::new();
+        canvas.image(index, 72.0, 500.0, 300.0, 200.0);
+        writer.add_page(canvas);
+
+        let text = String::from_utf8_lossy(&writer.finish()).into_owned();
+
+        assert!(text.contains("/XObject << /Im1 8 0 R >>"));
+        assert!(text.contains("/Im1 Do"));
+        assert!(text.contains("8 0 obj\n<< /Type /XObject /Subtype /Image /Width 3 /Height 2"));
+        assert!(text.contains("/Filter /DCTDecode"));
+    }
+}
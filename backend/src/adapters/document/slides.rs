@@ -0,0 +1,487 @@
+//! SlideDeckExporter - Turns the recommendation into a short stakeholder deck.
+//!
+//! Produces a single reveal.js HTML page (reveal.js itself loads from a CDN)
+//! with five slides: the decision, the recommendation, the Pugh summary, the
+//! key tradeoffs of the leading alternative, and decision quality. The deck
+//! only uses data on the dashboard overview, so it always matches what the
+//! user sees in the app.
+
+use std::fmt::Write;
+
+use async_trait::async_trait;
+
+use crate::domain::dashboard::{AlternativeSummary, DashboardOverview};
+use crate::ports::{
+    DecisionDocument, DocumentExportError, DocumentExporter, ExportFormat, ExportedDocument,
+};
+
+use super::{download_name, escape_html};
+
+const REVEAL_CDN: &str = "https://cdn.jsdelivr.net/npm/reveal.js@5.1.0/dist";
+
+/// Gains and sacrifices listed per side on the tradeoffs slide.
+const MAX_TRADEOFFS: usize = 4;
+
+const STYLE: &str = r#"
+.reveal table { font-size: 0.6em; margin: 0 auto; }
+.reveal .muted { color: #777; }
+.reveal .tag { font-size: 0.6em; color: #777; }
+.reveal tr.dominated td { color: #999; text-decoration: line-through; }
+.reveal .columns { display: flex; gap: 2em; text-align: left; }
+.reveal .columns > div { flex: 1; }
+.reveal .gain { color: #2e7d32; }
+.reveal .loss { color: #c62828; }
+.reveal .meter { height: 0.6em; background: #eee; border-radius: 0.3em; margin: 0.5em auto; width: 60%; }
+.reveal .meter > div { height: 100%; background: #3f51b5; border-radius: 0.3em; }
+"#;
+
+/// Renders the decision document as a reveal.js slide deck.
+#[derive(Debug, Default, Clone)]
+pub struct SlideDeckExporter;
+
+impl SlideDeckExporter {
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Renders the deck as a standalone HTML page.
+    pub fn render(&self, document: &DecisionDocument) -> String {
+        let overview = &document.overview;
+        let generated = document
+            .generated_at
+            .as_datetime()
+            .format("%B %-d, %Y")
+            .to_string();
+
+        let mut html = String::new();
+        let _ = write!(
+            html,
+            r#"<!DOCTYPE html>
+<html lang="en">
+<head>
+<meta charset="utf-8">
+<meta name="viewport" content="width=device-width, initial-scale=1">
+<title>{title}</title>
+<link rel="stylesheet" href="{cdn}/reveal.css">
+<link rel="stylesheet" href="{cdn}/theme/white.css">
+<style>{style}</style>
+</head>
+<body>
+<div class="reveal"><div class="slides">
+"#,
+            title = escape_html(&overview.session_title),
+            cdn = REVEAL_CDN,
+            style = STYLE,
+        );
+
+        title_slide(&mut html, overview, &generated);
+        recommendation_slide(&mut html, overview);
+        pugh_slide(&mut html, overview);
+        tradeoffs_slide(&mut html, overview);
+        quality_slide(&mut html, overview);
+
+        let _ = write!(
+            html,
+            r#"</div></div>
+<script src="{cdn}/reveal.js"></script>
+<script>Reveal.initialize({{ hash: true }});</script>
+</body>
+</html>
+"#,
+            cdn = REVEAL_CDN,
+        );
+        html
+    }
+}
+
+#[async_trait]
+impl DocumentExporter for SlideDeckExporter {
+    fn format(&self) -> ExportFormat {
+        ExportFormat::Slides
+    }
+
+    async fn export(
+        &self,
+        document: &DecisionDocument,
+    ) -> Result<ExportedDocument, DocumentExportError> {
+        Ok(ExportedDocument {
+            format: ExportFormat::Slides,
+            file_name: download_name(&document.overview.session_title, ExportFormat::Slides),
+            bytes: self.render(document).into_bytes(),
+        })
+    }
+}
+
+// ════════════════════════════════════════════════════════════════════════════
+// Slides
+// ════════════════════════════════════════════════════════════════════════════
+
+fn title_slide(html: &mut String, overview: &DashboardOverview, generated: &str) {
+    html.push_str("<section>\n");
+    let _ = writeln!(html, "<h2>{}</h2>", escape_html(&overview.session_title));
+    if let Some(statement) = &overview.decision_statement {
+        let _ = writeln!(html, "<p>{}</p>", escape_html(statement));
+    }
+    let _ = writeln!(html, r#"<p class="muted">{}</p>"#, escape_html(generated));
+    html.push_str("</section>\n");
+}
+
+fn recommendation_slide(html: &mut String, overview: &DashboardOverview) {
+    html.push_str("<section>\n<h3>Recommendation</h3>\n");
+    match &overview.recommendation {
+        Some(recommendation) => {
+            if let Some(name) = recommendation
+                .standout_name
+                .as_deref()
+                .filter(|_| recommendation.has_standout)
+            {
+                let _ = writeln!(html, "<h2>{}</h2>", escape_html(name));
+            }
+            let _ = writeln!(html, "<p>{}</p>", escape_html(&recommendation.synthesis_preview));
+            if recommendation.caveat_count > 0 {
+                let _ = writeln!(
+                    html,
+                    r#"<p class="muted">{} caveat{} noted.</p>"#,
+                    recommendation.caveat_count,
+                    if recommendation.caveat_count == 1 {
+                        ""
+                    } else {
+                        "s"
+                    }
+                );
+            }
+        }
+        None => html.push_str(r#"<p class="muted">No recommendation yet.</p>"#),
+    }
+    html.push_str("</section>\n");
+}
+
+fn pugh_slide(html: &mut String, overview: &DashboardOverview) {
+    html.push_str("<section>\n<h3>Alternatives at a Glance</h3>\n");
+    if overview.alternatives.is_empty() {
+        html.push_str(r#"<p class="muted">No alternatives recorded.</p>"#);
+        html.push_str("\n</section>\n");
+        return;
+    }
+
+    html.push_str("<table>\n<thead><tr><th>Rank</th><th>Alternative</th><th>Pugh score</th></tr></thead>\n<tbody>\n");
+    for alternative in ranked(&overview.alternatives) {
+        let mut tags = Vec::new();
+        if alternative.is_status_quo {
+            tags.push("baseline");
+        }
+        if alternative.is_dominated {
+            tags.push("dominated");
+        }
+        let tags = if tags.is_empty() {
+            String::new()
+        } else {
+            format!(r#" <span class="tag">({})</span>"#, tags.join(", "))
+        };
+        let _ = writeln!(
+            html,
+            "<tr{}><td>{}</td><td>{}{}</td><td>{}</td></tr>",
+            if alternative.is_dominated {
+                r#" class="dominated""#
+            } else {
+                ""
+            },
+            alternative
+                .rank
+                .map(|r| r.to_string())
+                .unwrap_or_else(|| "–".to_string()),
+            escape_html(&alternative.name),
+            tags,
+            alternative
+                .pugh_score
+                .map(|s| format!("{:+}", s))
+                .unwrap_or_else(|| "–".to_string()),
+        );
+    }
+    html.push_str("</tbody>\n</table>\n</section>\n");
+}
+
+fn tradeoffs_slide(html: &mut String, overview: &DashboardOverview) {
+    html.push_str("<section>\n<h3>Key Tradeoffs</h3>\n");
+    let Some(tradeoffs) = key_tradeoffs(overview) else {
+        html.push_str(
+            r#"<p class="muted">Rate at least two alternatives in the consequences table to see tradeoffs.</p>"#,
+        );
+        html.push_str("\n</section>\n");
+        return;
+    };
+
+    let _ = writeln!(
+        html,
+        r#"<p class="muted">{} compared with {}</p>"#,
+        escape_html(&tradeoffs.leader),
+        escape_html(&tradeoffs.runner_up)
+    );
+    html.push_str(r#"<div class="columns">"#);
+    tradeoff_column(html, "Where it wins", "gain", &tradeoffs.gains);
+    tradeoff_column(html, "What it gives up", "loss", &tradeoffs.sacrifices);
+    html.push_str("</div>\n</section>\n");
+}
+
+fn tradeoff_column(html: &mut String, heading: &str, class: &str, items: &[Tradeoff]) {
+    let _ = write!(html, "<div><h4>{}</h4>", heading);
+    if items.is_empty() {
+        html.push_str(r#"<p class="muted">Nothing notable.</p>"#);
+    } else {
+        html.push_str("<ul>");
+        for item in items {
+            let _ = write!(
+                html,
+                r#"<li>{} <span class="{}">{:+} vs {:+}</span></li>"#,
+                escape_html(&item.objective),
+                class,
+                item.leader_rating,
+                item.runner_up_rating
+            );
+        }
+        html.push_str("</ul>");
+    }
+    html.push_str("</div>\n");
+}
+
+fn quality_slide(html: &mut String, overview: &DashboardOverview) {
+    html.push_str("<section>\n<h3>Decision Quality</h3>\n");
+    match overview.dq_score {
+        Some(score) => {
+            let _ = writeln!(html, "<h1>{}</h1>", score);
+            let _ = writeln!(
+                html,
+                r#"<div class="meter"><div style="width: {}%"></div></div>"#,
+                score.value()
+            );
+            html.push_str(
+                r#"<p class="muted">The weakest element of decision quality caps the overall score.</p>"#,
+            );
+        }
+        None => html.push_str(r#"<p class="muted">Decision quality has not been assessed.</p>"#),
+    }
+    html.push_str("\n</section>\n");
+}
+
+// ════════════════════════════════════════════════════════════════════════════
+// Tradeoff analysis
+// ════════════════════════════════════════════════════════════════════════════
+
+/// The leading alternative's strongest gains and sacrifices against the runner-up.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct KeyTradeoffs {
+    leader: String,
+    runner_up: String,
+    gains: Vec<Tradeoff>,
+    sacrifices: Vec<Tradeoff>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Tradeoff {
+    objective: String,
+    leader_rating: i8,
+    runner_up_rating: i8,
+}
+
+/// Alternatives ordered by rank, unranked ones last.
+fn ranked(alternatives: &[AlternativeSummary]) -> Vec<&AlternativeSummary> {
+    let mut ranked: Vec<_> = alternatives.iter().collect();
+    ranked.sort_by_key(|a| (a.rank.is_none(), a.rank));
+    ranked
+}
+
+/// Compares the leader (the recommendation's standout, else the top-ranked
+/// alternative) with the next best alternative, objective by objective.
+fn key_tradeoffs(overview: &DashboardOverview) -> Option<KeyTradeoffs> {
+    let table = overview.consequences_table.as_ref()?;
+    let standout = overview
+        .recommendation
+        .as_ref()
+        .filter(|r| r.has_standout)
+        .and_then(|r| r.standout_name.clone());
+    let ranked: Vec<&str> = ranked(&overview.alternatives)
+        .into_iter()
+        .map(|a| a.name.as_str())
+        .filter(|name| table.alternative_names.iter().any(|n| n == name))
+        .collect();
+
+    let leader = match standout {
+        Some(name) if ranked.contains(&name.as_str()) => name,
+        _ => ranked.first()?.to_string(),
+    };
+    let runner_up = ranked.iter().find(|name| **name != leader)?.to_string();
+    let leader_index = table.alternative_names.iter().position(|n| *n == leader)?;
+    let runner_up_index = table
+        .alternative_names
+        .iter()
+        .position(|n| *n == runner_up)?;
+
+    let mut differences: Vec<Tradeoff> = table
+        .objective_names
+        .iter()
+        .zip(&table.cells)
+        .filter_map(|(objective, row)| {
+            Some(Tradeoff {
+                objective: objective.clone(),
+                leader_rating: row.get(leader_index)?.rating,
+                runner_up_rating: row.get(runner_up_index)?.rating,
+            })
+        })
+        .filter(|t| t.leader_rating != t.runner_up_rating)
+        .collect();
+    differences.sort_by_key(|t| {
+        std::cmp::Reverse((i16::from(t.leader_rating) - i16::from(t.runner_up_rating)).abs())
+    });
+
+    let (gains, sacrifices): (Vec<_>, Vec<_>) = differences
+        .into_iter()
+        .partition(|t| t.leader_rating > t.runner_up_rating);
+    Some(KeyTradeoffs {
+        leader,
+        runner_up,
+        gains: gains.into_iter().take(MAX_TRADEOFFS).collect(),
+        sacrifices: sacrifices.into_iter().take(MAX_TRADEOFFS).collect(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::dashboard::{
+        CellColor, CellSummary, CompactConsequencesTable, RecommendationSummary,
+    };
+    use crate::domain::foundation::{CycleId, Percentage, SessionId, Timestamp};
+
+    fn cell(rating: i8) -> CellSummary {
+        CellSummary {
+            rating,
+            color: CellColor::from(rating),
+            explanation_preview: None,
+        }
+    }
+
+    fn alternative(name: &str, rank: u8, score: i32) -> AlternativeSummary {
+        AlternativeSummary {
+            id: name.to_lowercase(),
+            name: name.to_string(),
+            is_status_quo: rank == 3,
+            pugh_score: Some(score),
+            rank: Some(rank),
+            is_dominated: false,
+        }
+    }
+
+    fn overview() -> DashboardOverview {
+        DashboardOverview {
+            session_id: SessionId::new(),
+            session_title: "Lisbon <or> Porto?".to_string(),
+            decision_statement: Some("Where should the team relocate?".to_string()),
+            stakeholders: vec![],
+            objectives: vec![],
+            alternatives: vec![
+                alternative("Stay", 3, 0),
+                alternative("Lisbon", 1, 3),
+                alternative("Porto", 2, 1),
+            ],
+            consequences_table: Some(CompactConsequencesTable {
+                alternative_names: vec!["Stay".into(), "Lisbon".into(), "Porto".into()],
+                objective_names: vec!["Cost".into(), "Talent pool".into(), "Commute".into()],
+                cells: vec![
+                    vec![cell(0), cell(-2), cell(-1)],
+                    vec![cell(0), cell(2), cell(0)],
+                    vec![cell(0), cell(1), cell(1)],
+                ],
+            }),
+            key_uncertainties: vec![],
+            recommendation: Some(RecommendationSummary {
+                has_standout: true,
+                standout_name: Some("Lisbon".to_string()),
+                synthesis_preview: "Lisbon wins on talent at a modest cost premium.".to_string(),
+                caveat_count: 2,
+            }),
+            dq_score: Some(Percentage::new(72)),
+            active_cycle_id: None,
+            cycle_count: 1,
+            last_updated: chrono::Utc::now(),
+        }
+    }
+
+    fn document(overview: DashboardOverview) -> DecisionDocument {
+        DecisionDocument {
+            cycle_id: CycleId::new(),
+            overview,
+            cycle_tree: None,
+            organization: None,
+            attachments: Vec::new(),
+            generated_at: Timestamp::now(),
+            reading_level: Default::default(),
+        }
+    }
+
+    #[tokio::test]
+    async fn exports_html_deck_with_file_name() {
+        let exported = SlideDeckExporter::new()
+            .export(&document(overview()))
+            .await
+            .unwrap();
+
+        assert_eq!(exported.format, ExportFormat::Slides);
+        assert_eq!(exported.file_name, "lisbon-or-porto.html");
+        let html = String::from_utf8(exported.bytes).unwrap();
+        assert_eq!(html.matches("<section>").count(), 5);
+        assert!(html.contains("Reveal.initialize"));
+    }
+
+    #[test]
+    fn escapes_user_content() {
+        let html = SlideDeckExporter::new().render(&document(overview()));
+
+        assert!(html.contains("Lisbon &lt;or&gt; Porto?"));
+        assert!(!html.contains("<or>"));
+    }
+
+    #[test]
+    fn pugh_summary_is_ordered_by_rank() {
+        let html = SlideDeckExporter::new().render(&document(overview()));
+
+        let lisbon = html.find("<td>Lisbon").unwrap();
+        let porto = html.find("<td>Porto").unwrap();
+        let stay = html.find("<td>Stay").unwrap();
+        assert!(lisbon < porto && porto < stay);
+        assert!(html.contains("<td>+3</td>"));
+    }
+
+    #[test]
+    fn key_tradeoffs_compare_leader_with_runner_up() {
+        let tradeoffs = key_tradeoffs(&overview()).unwrap();
+
+        assert_eq!(tradeoffs.leader, "Lisbon");
+        assert_eq!(tradeoffs.runner_up, "Porto");
+        assert_eq!(
+            tradeoffs.gains,
+            vec![Tradeoff {
+                objective: "Talent pool".to_string(),
+                leader_rating: 2,
+                runner_up_rating: 0,
+            }]
+        );
+        assert_eq!(tradeoffs.sacrifices.len(), 1);
+        assert_eq!(tradeoffs.sacrifices[0].objective, "Cost");
+    }
+
+    #[test]
+    fn incomplete_decisions_render_placeholders() {
+        let mut overview = overview();
+        overview.alternatives.clear();
+        overview.consequences_table = None;
+        overview.recommendation = None;
+        overview.dq_score = None;
+
+        let html = SlideDeckExporter::new().render(&document(overview));
+
+        assert_eq!(html.matches("<section>").count(), 5);
+        assert!(html.contains("No recommendation yet."));
+        assert!(html.contains("No alternatives recorded."));
+        assert!(html.contains("Decision quality has not been assessed."));
+    }
+}
@@ -0,0 +1,673 @@
+//! TemplateDocumentExporter - Renders the decision document as Markdown from a template.
+//!
+//! Organizations can store their own Tera template to match their report
+//! house style; everyone else gets the built-in layout (`default.md.tera`).
+//! Templates see these variables:
+//!
+//! | Variable | Contents |
+//! |----------|----------|
+//! | `title`, `decision_statement`, `generated_at` | Strings (`decision_statement` may be null) |
+//! | `stakeholders[]` | `name`, `role`, `engagement`, `concerns[]` |
+//! | `objectives[]` | `description`, `measure`, `is_fundamental` |
+//! | `alternatives[]` | `name`, `is_status_quo`, `rank`, `pugh_score`, `pugh_label`, `is_dominated` |
+//! | `consequences` | `alternatives[]` names and `rows[]` of `objective` and `cells[]` (`rating`, `label`, `color`, `explanation`); null when incomplete |
+//! | `uncertainties[]` | `description`, `impact`, `probability_label` (e.g. `40%`, may be null), `mitigated` |
+//! | `recommendation` | `standout`, `synthesis`, `caveat_count`; null when missing |
+//! | `dq_score` | Overall decision quality 0-100, or null |
+//! | `attachments[]` | `file_name`, `caption`, `label` (caption or file name), `content_type`, `is_image`, `component`, `url` |
+//! | `cycles[]` | Flattened cycle tree: `short_id`, `status`, `progress_percent`, `current_step`, `branch_point`, `depth`, `is_current` |
+//! | `reading_level`, `plain_language` | The reader's reading level (`standard`, `plain`, `simple`) and whether it asks for plain wording |
+//!
+//! The built-in layout swaps decision-analysis terms ("Pugh score",
+//! "dominated", "status quo") for everyday wording when `plain_language` is
+//! set. Content the user or agent wrote is left as-is.
+//!
+//! Templates are validated against both a complete and an empty sample
+//! document before they are stored, so they must handle missing sections.
+//! A stored template that still fails on real data falls back to the
+//! built-in layout rather than blocking the export.
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use serde::Serialize;
+
+use crate::domain::dashboard::{
+    AlternativeSummary, CellColor, CellSummary, CompactConsequencesTable, DashboardOverview,
+    ObjectiveSummary, RecommendationSummary, StakeholderSummary, UncertaintySummary,
+};
+use crate::domain::document::Attachment;
+use crate::domain::foundation::{
+    ComponentType, CycleId, CycleStatus, Percentage, SessionId, Timestamp, UserId,
+};
+use crate::domain::profile::ReadingLevel;
+use crate::ports::{
+    CycleSummary, CycleTreeNode, DecisionDocument, DocumentAttachment, DocumentExportError,
+    DocumentExporter, DocumentTemplate, DocumentTemplateStore, ExportFormat, ExportedDocument,
+};
+
+use super::super::download_name;
+use super::sandbox::{TemplateError, TemplateSandbox};
+
+/// Built-in Markdown layout.
+pub const DEFAULT_TEMPLATE: &str = include_str!("default.md.tera");
+
+/// Renders the decision document through per-organization Tera templates.
+pub struct TemplateDocumentExporter {
+    stores: Vec<Arc<dyn DocumentTemplateStore>>,
+    sandbox: TemplateSandbox,
+    default_template: String,
+}
+
+impl TemplateDocumentExporter {
+    /// Stores are consulted in order; the first template found wins.
+    pub fn new(stores: Vec<Arc<dyn DocumentTemplateStore>>) -> Self {
+        Self {
+            stores,
+            sandbox: TemplateSandbox::default(),
+            default_template: DEFAULT_TEMPLATE.to_string(),
+        }
+    }
+
+    pub fn with_sandbox(mut self, sandbox: TemplateSandbox) -> Self {
+        self.sandbox = sandbox;
+        self
+    }
+
+    /// Replace the built-in layout used when an organization has no template.
+    pub fn with_default_template(
+        mut self,
+        source: impl Into<String>,
+    ) -> Result<Self, TemplateError> {
+        let source = source.into();
+        self.validate(&source)?;
+        self.default_template = source;
+        Ok(self)
+    }
+
+    /// Check that a template parses and renders a complete, an empty, and a
+    /// plain-language decision document within the sandbox limits.
+    pub fn validate(&self, source: &str) -> Result<(), TemplateError> {
+        self.sandbox.compile(source)?;
+        let plain = DecisionDocument {
+            reading_level: ReadingLevel::Simple,
+            ..sample_document(true)
+        };
+        for document in [sample_document(true), sample_document(false), plain] {
+            self.render(source, &document)?;
+        }
+        Ok(())
+    }
+
+    /// Render a document with the given template source.
+    pub fn render(&self, source: &str, document: &DecisionDocument) -> Result<String, TemplateError> {
+        self.sandbox.render(source, &TemplateContext::new(document))
+    }
+
+    async fn template_for(&self, organization: &str) -> Option<DocumentTemplate> {
+        for store in &self.stores {
+            match store.get(organization).await {
+                Ok(Some(template)) => return Some(template),
+                Ok(None) => {}
+                Err(e) => {
+                    tracing::warn!(organization, error = %e, "Failed to load document template");
+                }
+            }
+        }
+        None
+    }
+
+    fn render_default(&self, document: &DecisionDocument) -> Result<String, DocumentExportError> {
+        self.render(&self.default_template, document)
+            .map_err(|e| DocumentExportError::Render(e.to_string()))
+    }
+}
+
+#[async_trait]
+impl DocumentExporter for TemplateDocumentExporter {
+    fn format(&self) -> ExportFormat {
+        ExportFormat::Markdown
+    }
+
+    async fn export(
+        &self,
+        document: &DecisionDocument,
+    ) -> Result<ExportedDocument, DocumentExportError> {
+        let custom = match &document.organization {
+            Some(organization) => self.template_for(organization).await,
+            None => None,
+        };
+
+        let markdown = match custom {
+            Some(template) => match self.render(&template.source, document) {
+                Ok(markdown) => markdown,
+                Err(e) => {
+                    tracing::warn!(
+                        organization = %template.organization,
+                        error = %e,
+                        "Custom document template failed; using the default layout"
+                    );
+                    self.render_default(document)?
+                }
+            },
+            None => self.render_default(document)?,
+        };
+
+        Ok(ExportedDocument {
+            format: ExportFormat::Markdown,
+            file_name: download_name(&document.overview.session_title, ExportFormat::Markdown),
+            bytes: markdown.into_bytes(),
+        })
+    }
+}
+
+// ════════════════════════════════════════════════════════════════════════════
+// Template context
+// ════════════════════════════════════════════════════════════════════════════
+
+#[derive(Serialize)]
+struct TemplateContext<'a> {
+    title: &'a str,
+    decision_statement: Option<&'a str>,
+    stakeholders: Vec<StakeholderContext<'a>>,
+    generated_at: String,
+    objectives: Vec<ObjectiveContext<'a>>,
+    alternatives: Vec<AlternativeContext<'a>>,
+    consequences: Option<ConsequencesContext<'a>>,
+    uncertainties: Vec<UncertaintyContext<'a>>,
+    recommendation: Option<RecommendationContext<'a>>,
+    dq_score: Option<u8>,
+    attachments: Vec<AttachmentContext<'a>>,
+    cycles: Vec<CycleContext>,
+    reading_level: &'static str,
+    plain_language: bool,
+}
+
+#[derive(Serialize)]
+struct StakeholderContext<'a> {
+    name: &'a str,
+    role: &'a str,
+    engagement: &'a str,
+    concerns: &'a [String],
+}
+
+#[derive(Serialize)]
+struct UncertaintyContext<'a> {
+    description: &'a str,
+    impact: &'a str,
+    probability_label: Option<String>,
+    mitigated: bool,
+}
+
+#[derive(Serialize)]
+struct ObjectiveContext<'a> {
+    description: &'a str,
+    measure: Option<&'a str>,
+    is_fundamental: bool,
+}
+
+#[derive(Serialize)]
+struct AlternativeContext<'a> {
+    name: &'a str,
+    is_status_quo: bool,
+    rank: Option<u8>,
+    pugh_score: Option<i32>,
+    pugh_label: Option<String>,
+    is_dominated: bool,
+}
+
+#[derive(Serialize)]
+struct ConsequencesContext<'a> {
+    alternatives: &'a [String],
+    rows: Vec<RowContext<'a>>,
+}
+
+#[derive(Serialize)]
+struct RowContext<'a> {
+    objective: &'a str,
+    cells: Vec<CellContext<'a>>,
+}
+
+#[derive(Serialize)]
+struct CellContext<'a> {
+    rating: i8,
+    label: String,
+    color: &'static str,
+    explanation: Option<&'a str>,
+}
+
+#[derive(Serialize)]
+struct RecommendationContext<'a> {
+    standout: Option<&'a str>,
+    synthesis: &'a str,
+    caveat_count: usize,
+}
+
+#[derive(Serialize)]
+struct AttachmentContext<'a> {
+    file_name: &'a str,
+    caption: Option<&'a str>,
+    label: &'a str,
+    content_type: &'a str,
+    is_image: bool,
+    component: &'static str,
+    url: String,
+}
+
+#[derive(Serialize)]
+struct CycleContext {
+    short_id: String,
+    status: String,
+    progress_percent: u8,
+    current_step: String,
+    branch_point: Option<String>,
+    depth: usize,
+    is_current: bool,
+}
+
+impl<'a> TemplateContext<'a> {
+    fn new(document: &'a DecisionDocument) -> Self {
+        let overview = &document.overview;
+        let mut cycles = Vec::new();
+        if let Some(tree) = &document.cycle_tree {
+            flatten_cycles(tree, &document.cycle_id, 0, &mut cycles);
+        }
+
+        Self {
+            title: &overview.session_title,
+            decision_statement: overview.decision_statement.as_deref(),
+            stakeholders: overview
+                .stakeholders
+                .iter()
+                .map(|s| StakeholderContext {
+                    name: &s.name,
+                    role: &s.role,
+                    engagement: &s.engagement,
+                    concerns: &s.concerns,
+                })
+                .collect(),
+            generated_at: document
+                .generated_at
+                .as_datetime()
+                .format("%B %-d, %Y")
+                .to_string(),
+            objectives: overview
+                .objectives
+                .iter()
+                .map(|o| ObjectiveContext {
+                    description: &o.description,
+                    measure: o.measure.as_deref(),
+                    is_fundamental: o.is_fundamental,
+                })
+                .collect(),
+            alternatives: overview
+                .alternatives
+                .iter()
+                .map(|a| AlternativeContext {
+                    name: &a.name,
+                    is_status_quo: a.is_status_quo,
+                    rank: a.rank,
+                    pugh_score: a.pugh_score,
+                    pugh_label: a.pugh_score.map(|s| format!("{:+}", s)),
+                    is_dominated: a.is_dominated,
+                })
+                .collect(),
+            consequences: overview
+                .consequences_table
+                .as_ref()
+                .filter(|t| !t.alternative_names.is_empty())
+                .map(consequences_context),
+            uncertainties: overview
+                .key_uncertainties
+                .iter()
+                .map(|u| UncertaintyContext {
+                    description: &u.description,
+                    impact: &u.impact,
+                    probability_label: u.probability.map(|p| format!("{:.0}%", p * 100.0)),
+                    mitigated: u.mitigated,
+                })
+                .collect(),
+            recommendation: overview
+                .recommendation
+                .as_ref()
+                .map(|r| RecommendationContext {
+                    standout: r.standout_name.as_deref().filter(|_| r.has_standout),
+                    synthesis: &r.synthesis_preview,
+                    caveat_count: r.caveat_count,
+                }),
+            dq_score: overview.dq_score.map(|s| s.value()),
+            attachments: document
+                .attachments
+                .iter()
+                .map(|a| &a.attachment)
+                .map(|a| AttachmentContext {
+                    file_name: &a.file_name,
+                    caption: a.caption.as_deref(),
+                    label: a.label(),
+                    content_type: &a.content_type,
+                    is_image: a.is_image(),
+                    component: a.component_type.display_name(),
+                    url: a.download_path(),
+                })
+                .collect(),
+            cycles,
+            reading_level: document.reading_level.as_str(),
+            plain_language: document.reading_level.is_plain_language(),
+        }
+    }
+}
+
+fn consequences_context(table: &CompactConsequencesTable) -> ConsequencesContext<'_> {
+    ConsequencesContext {
+        alternatives: &table.alternative_names,
+        rows: table
+            .objective_names
+            .iter()
+            .zip(&table.cells)
+            .map(|(objective, cells)| RowContext {
+                objective,
+                cells: cells.iter().map(cell_context).collect(),
+            })
+            .collect(),
+    }
+}
+
+fn cell_context(cell: &CellSummary) -> CellContext<'_> {
+    CellContext {
+        rating: cell.rating,
+        label: if cell.rating > 0 {
+            format!("+{}", cell.rating)
+        } else {
+            cell.rating.to_string()
+        },
+        color: match cell.color {
+            CellColor::Red => "red",
+            CellColor::Yellow => "yellow",
+            CellColor::Green => "green",
+        },
+        explanation: cell.explanation_preview.as_deref(),
+    }
+}
+
+fn flatten_cycles(
+    node: &CycleTreeNode,
+    current: &CycleId,
+    depth: usize,
+    cycles: &mut Vec<CycleContext>,
+) {
+    let cycle = &node.cycle;
+    let id = cycle.id.to_string();
+    cycles.push(CycleContext {
+        short_id: id[..id.len().min(8)].to_string(),
+        status: cycle.status.to_string(),
+        progress_percent: cycle.progress_percent,
+        current_step: cycle.current_step.to_string(),
+        branch_point: cycle.branch_point.map(|c| c.to_string()),
+        depth,
+        is_current: &cycle.id == current,
+    });
+    for child in &node.children {
+        flatten_cycles(child, current, depth + 1, cycles);
+    }
+}
+
+// ════════════════════════════════════════════════════════════════════════════
+// Validation samples
+// ════════════════════════════════════════════════════════════════════════════
+
+/// A complete decision (every section populated) or an empty one.
+fn sample_document(complete: bool) -> DecisionDocument {
+    let cycle_id = CycleId::new();
+    let mut overview = DashboardOverview {
+        session_id: SessionId::new(),
+        session_title: "Sample decision".to_string(),
+        decision_statement: None,
+        stakeholders: vec![],
+        objectives: vec![],
+        alternatives: vec![],
+        consequences_table: None,
+        key_uncertainties: vec![],
+        recommendation: None,
+        dq_score: None,
+        active_cycle_id: None,
+        cycle_count: 1,
+        last_updated: chrono::Utc::now(),
+    };
+    let mut cycle_tree = None;
+    let mut attachments = Vec::new();
+
+    if complete {
+        overview.decision_statement = Some("Choose a supplier for the new product line.".into());
+        overview.stakeholders = vec![StakeholderSummary {
+            id: "finance".into(),
+            name: "Finance team".into(),
+            role: "Approves the budget".into(),
+            engagement: "Keep satisfied".into(),
+            concerns: vec!["Payment terms".into()],
+        }];
+        overview.objectives = vec![ObjectiveSummary {
+            id: "obj-1".into(),
+            description: "Minimize unit cost".into(),
+            is_fundamental: true,
+            measure: Some("USD".into()),
+        }];
+        overview.alternatives = ["Current supplier", "New supplier"]
+            .iter()
+            .enumerate()
+            .map(|(i, name)| AlternativeSummary {
+                id: format!("alt-{}", i),
+                name: name.to_string(),
+                is_status_quo: i == 0,
+                pugh_score: Some(i as i32),
+                rank: Some(2 - i as u8),
+                is_dominated: false,
+            })
+            .collect();
+        overview.consequences_table = Some(CompactConsequencesTable {
+            alternative_names: vec!["Current supplier".into(), "New supplier".into()],
+            objective_names: vec!["Minimize unit cost".into()],
+            cells: vec![vec![
+                CellSummary {
+                    rating: 0,
+                    color: CellColor::Yellow,
+                    explanation_preview: None,
+                },
+                CellSummary {
+                    rating: 1,
+                    color: CellColor::Green,
+                    explanation_preview: Some("Ten percent cheaper".into()),
+                },
+            ]],
+        });
+        overview.key_uncertainties = vec![UncertaintySummary {
+            id: "r1".into(),
+            description: "Supplier capacity next year".into(),
+            probability: Some(0.25),
+            impact: "high".into(),
+            mitigated: false,
+        }];
+        overview.recommendation = Some(RecommendationSummary {
+            has_standout: true,
+            standout_name: Some("New supplier".into()),
+            synthesis_preview: "The new supplier is cheaper with similar quality.".into(),
+            caveat_count: 1,
+        });
+        overview.dq_score = Some(Percentage::new(80));
+
+        let summary = |id: CycleId, branch_point: Option<ComponentType>| CycleSummary {
+            id,
+            is_branch: branch_point.is_some(),
+            branch_point,
+            status: CycleStatus::Active,
+            current_step: ComponentType::Recommendation,
+            progress_percent: 80,
+            created_at: Timestamp::now(),
+        };
+        cycle_tree = Some(CycleTreeNode {
+            cycle: summary(cycle_id, None),
+            children: vec![CycleTreeNode {
+                cycle: summary(CycleId::new(), Some(ComponentType::Alternatives)),
+                children: vec![],
+            }],
+        });
+
+        let attachment = |file_name: &str, content_type: &str, caption: Option<&str>| {
+            Attachment::new(
+                cycle_id,
+                ComponentType::Alternatives,
+                file_name,
+                content_type,
+                1024,
+                caption.map(str::to_string),
+                UserId::new("sample-user").expect("valid sample user"),
+            )
+            .map(|attachment| DocumentAttachment {
+                attachment,
+                image: None,
+            })
+        };
+        attachments = [
+            attachment("whiteboard.jpg", "image/jpeg", Some("Whiteboard list")),
+            attachment("quotes.pdf", "application/pdf", None),
+        ]
+        .into_iter()
+        .flatten()
+        .collect();
+    }
+
+    DecisionDocument {
+        cycle_id,
+        overview,
+        cycle_tree,
+        organization: None,
+        attachments,
+        generated_at: Timestamp::now(),
+        reading_level: Default::default(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::adapters::document::template::InMemoryDocumentTemplateStore;
+
+    fn exporter(store: Arc<InMemoryDocumentTemplateStore>) -> TemplateDocumentExporter {
+        TemplateDocumentExporter::new(vec![store])
+    }
+
+    fn document_for(organization: Option<&str>) -> DecisionDocument {
+        DecisionDocument {
+            organization: organization.map(str::to_string),
+            ..sample_document(true)
+        }
+    }
+
+    #[test]
+    fn default_template_is_valid() {
+        let exporter = TemplateDocumentExporter::new(vec![]);
+        assert_eq!(exporter.validate(DEFAULT_TEMPLATE), Ok(()));
+    }
+
+    #[test]
+    fn default_template_renders_every_section() {
+        let exporter = TemplateDocumentExporter::new(vec![]);
+
+        let markdown = exporter
+            .render(DEFAULT_TEMPLATE, &sample_document(true))
+            .unwrap();
+
+        assert!(markdown.starts_with("# Sample decision\n"));
+        assert!(markdown.contains(
+            "- **Finance team** (Approves the budget) — Keep satisfied; concerns: Payment terms"
+        ));
+        assert!(markdown.contains("- Minimize unit cost (measured by USD)"));
+        assert!(markdown.contains("| Objective | Current supplier | New supplier |"));
+        assert!(markdown.contains("| Minimize unit cost | 0 | +1 — Ten percent cheaper |"));
+        assert!(markdown.contains(
+            "- **Supplier capacity next year** — high impact, 25% likely, unmitigated"
+        ));
+        assert!(markdown.contains("**Standout option: New supplier**"));
+        assert!(markdown.contains("Overall decision quality: 80%"));
+        assert!(markdown.contains("- ![Whiteboard list](/api/cycles/"));
+        assert!(markdown.contains("- [quotes.pdf](/api/cycles/"));
+        assert!(markdown.contains("  - Cycle "));
+    }
+
+    #[test]
+    fn default_template_uses_plain_wording_for_plain_readers() {
+        let exporter = TemplateDocumentExporter::new(vec![]);
+        let document = DecisionDocument {
+            reading_level: ReadingLevel::Plain,
+            ..sample_document(true)
+        };
+
+        let markdown = exporter.render(DEFAULT_TEMPLATE, &document).unwrap();
+
+        assert!(markdown.contains("## Your options"));
+        assert!(markdown.contains("Higher is better"));
+        assert!(markdown.contains("**Best fit so far: New supplier**"));
+        for term in ["Pugh", "dominated", "status quo", "## Consequences"] {
+            assert!(!markdown.contains(term), "{} should be reworded", term);
+        }
+        // What the user wrote is untouched
+        assert!(markdown.contains("- Minimize unit cost (measured by USD)"));
+    }
+
+    #[tokio::test]
+    async fn uses_the_organization_template() {
+        let store = Arc::new(InMemoryDocumentTemplateStore::new());
+        store
+            .save(DocumentTemplate::new("acme.com", "ACME REPORT: {{ title }}"))
+            .await
+            .unwrap();
+        let exporter = exporter(store);
+
+        let acme = exporter
+            .export(&document_for(Some("acme.com")))
+            .await
+            .unwrap();
+        let other = exporter
+            .export(&document_for(Some("other.org")))
+            .await
+            .unwrap();
+
+        assert_eq!(acme.bytes, b"ACME REPORT: Sample decision");
+        assert_eq!(acme.file_name, "sample-decision.md");
+        assert!(other.bytes.starts_with(b"# Sample decision"));
+    }
+
+    #[tokio::test]
+    async fn broken_organization_templates_fall_back_to_default() {
+        let store = Arc::new(InMemoryDocumentTemplateStore::new());
+        store
+            .save(DocumentTemplate::new("acme.com", "{{ missing.field }}"))
+            .await
+            .unwrap();
+
+        let exported = exporter(store)
+            .export(&document_for(Some("acme.com")))
+            .await
+            .unwrap();
+
+        assert!(exported.bytes.starts_with(b"# Sample decision"));
+    }
+
+    #[test]
+    fn validation_requires_handling_missing_sections() {
+        let exporter = TemplateDocumentExporter::new(vec![]);
+
+        let result = exporter.validate("{{ recommendation.synthesis }}");
+
+        assert!(matches!(result, Err(TemplateError::Render(_))));
+        assert_eq!(
+            exporter.validate("{% if recommendation %}{{ recommendation.synthesis }}{% endif %}"),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn invalid_default_templates_are_rejected() {
+        let result = TemplateDocumentExporter::new(vec![]).with_default_template("{% if %}");
+        assert!(matches!(result, Err(TemplateError::Syntax(_))));
+    }
+}
@@ -0,0 +1,116 @@
+//! File-backed document template store for templates shipped with deployment config.
+//!
+//! Reads `<dir>/<organization>.md.tera`. The directory is managed alongside
+//! the rest of the deployment configuration, so the store is read-only.
+
+use std::path::{Path, PathBuf};
+
+use async_trait::async_trait;
+use tokio::fs;
+
+use crate::domain::foundation::Timestamp;
+use crate::ports::{
+    validate_organization, DocumentTemplate, DocumentTemplateError, DocumentTemplateStore,
+};
+
+/// File extension of template files.
+pub const TEMPLATE_FILE_EXTENSION: &str = "md.tera";
+
+/// Read-only document templates loaded from a directory.
+#[derive(Debug, Clone)]
+pub struct FileDocumentTemplateStore {
+    dir: PathBuf,
+}
+
+impl FileDocumentTemplateStore {
+    pub fn new<P: AsRef<Path>>(dir: P) -> Self {
+        Self {
+            dir: dir.as_ref().to_path_buf(),
+        }
+    }
+
+    fn template_path(&self, organization: &str) -> Result<PathBuf, DocumentTemplateError> {
+        validate_organization(organization)?;
+        Ok(self
+            .dir
+            .join(format!("{}.{}", organization, TEMPLATE_FILE_EXTENSION)))
+    }
+
+    fn read_only(&self) -> DocumentTemplateError {
+        DocumentTemplateError::ReadOnly(format!(
+            "templates in {} are managed with deployment config",
+            self.dir.display()
+        ))
+    }
+}
+
+#[async_trait]
+impl DocumentTemplateStore for FileDocumentTemplateStore {
+    async fn get(
+        &self,
+        organization: &str,
+    ) -> Result<Option<DocumentTemplate>, DocumentTemplateError> {
+        let path = self.template_path(organization)?;
+        match fs::read_to_string(&path).await {
+            Ok(source) => {
+                let updated_at = fs::metadata(&path)
+                    .await
+                    .and_then(|m| m.modified())
+                    .map(|t| Timestamp::from_datetime(t.into()))
+                    .unwrap_or_else(|_| Timestamp::now());
+                Ok(Some(DocumentTemplate {
+                    organization: organization.to_string(),
+                    source,
+                    updated_at,
+                }))
+            }
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(None),
+            Err(e) => Err(DocumentTemplateError::Storage(e.to_string())),
+        }
+    }
+
+    async fn save(&self, _template: DocumentTemplate) -> Result<(), DocumentTemplateError> {
+        Err(self.read_only())
+    }
+
+    async fn delete(&self, _organization: &str) -> Result<bool, DocumentTemplateError> {
+        Err(self.read_only())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[tokio::test]
+    async fn reads_organization_templates() {
+        let dir = TempDir::new().unwrap();
+        std::fs::write(dir.path().join("acme.com.md.tera"), "# {{ title }}").unwrap();
+        let store = FileDocumentTemplateStore::new(dir.path());
+
+        let template = store.get("acme.com").await.unwrap().unwrap();
+
+        assert_eq!(template.organization, "acme.com");
+        assert_eq!(template.source, "# {{ title }}");
+        assert!(store.get("other.org").await.unwrap().is_none());
+    }
+
+    #[tokio::test]
+    async fn rejects_path_traversal() {
+        let store = FileDocumentTemplateStore::new("/tmp");
+        assert!(matches!(
+            store.get("../etc/passwd").await,
+            Err(DocumentTemplateError::InvalidOrganization(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn is_read_only() {
+        let store = FileDocumentTemplateStore::new("/tmp");
+        assert!(matches!(
+            store.save(DocumentTemplate::new("acme.com", "x")).await,
+            Err(DocumentTemplateError::ReadOnly(_))
+        ));
+    }
+}
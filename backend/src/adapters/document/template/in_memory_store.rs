@@ -0,0 +1,79 @@
+//! In-memory document template store for testing and development.
+
+use std::collections::HashMap;
+
+use async_trait::async_trait;
+use tokio::sync::RwLock;
+
+use crate::ports::{
+    validate_organization, DocumentTemplate, DocumentTemplateError, DocumentTemplateStore,
+};
+
+/// In-memory document template store. Templates are lost on restart.
+#[derive(Debug, Default)]
+pub struct InMemoryDocumentTemplateStore {
+    templates: RwLock<HashMap<String, DocumentTemplate>>,
+}
+
+impl InMemoryDocumentTemplateStore {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait]
+impl DocumentTemplateStore for InMemoryDocumentTemplateStore {
+    async fn get(
+        &self,
+        organization: &str,
+    ) -> Result<Option<DocumentTemplate>, DocumentTemplateError> {
+        Ok(self.templates.read().await.get(organization).cloned())
+    }
+
+    async fn save(&self, template: DocumentTemplate) -> Result<(), DocumentTemplateError> {
+        validate_organization(&template.organization)?;
+        self.templates
+            .write()
+            .await
+            .insert(template.organization.clone(), template);
+        Ok(())
+    }
+
+    async fn delete(&self, organization: &str) -> Result<bool, DocumentTemplateError> {
+        Ok(self.templates.write().await.remove(organization).is_some())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn saves_replaces_and_deletes() {
+        let store = InMemoryDocumentTemplateStore::new();
+
+        store
+            .save(DocumentTemplate::new("acme.com", "v1"))
+            .await
+            .unwrap();
+        store
+            .save(DocumentTemplate::new("acme.com", "v2"))
+            .await
+            .unwrap();
+        assert_eq!(store.get("acme.com").await.unwrap().unwrap().source, "v2");
+
+        assert!(store.delete("acme.com").await.unwrap());
+        assert!(!store.delete("acme.com").await.unwrap());
+        assert!(store.get("acme.com").await.unwrap().is_none());
+    }
+
+    #[tokio::test]
+    async fn rejects_invalid_organizations() {
+        let store = InMemoryDocumentTemplateStore::new();
+        let result = store.save(DocumentTemplate::new("../acme", "x")).await;
+        assert!(matches!(
+            result,
+            Err(DocumentTemplateError::InvalidOrganization(_))
+        ));
+    }
+}
@@ -0,0 +1,21 @@
+//! Markdown export of the decision document through Tera templates.
+//!
+//! - `TemplateDocumentExporter` - Renders with the organization's template or the built-in layout
+//! - `TemplateSandbox` - Size limits and disabled builtins for untrusted templates
+//! - `InMemoryDocumentTemplateStore` - Template store for tests and development
+//! - `FileDocumentTemplateStore` - Read-only templates from a config directory
+//!
+//! The Postgres-backed template store lives in `adapters::postgres`.
+
+mod exporter;
+mod file_store;
+mod in_memory_store;
+mod sandbox;
+
+pub use exporter::{TemplateDocumentExporter, DEFAULT_TEMPLATE};
+pub use file_store::{FileDocumentTemplateStore, TEMPLATE_FILE_EXTENSION};
+pub use in_memory_store::InMemoryDocumentTemplateStore;
+pub use sandbox::{
+    TemplateError, TemplateSandbox, DEFAULT_MAX_OUTPUT_BYTES, DEFAULT_MAX_TEMPLATE_BYTES,
+    MAX_RANGE_LENGTH,
+};
@@ -0,0 +1,242 @@
+//! TemplateSandbox - Compiles and renders untrusted Tera templates safely.
+//!
+//! Templates are written by customer admins, so rendering is locked down:
+//!
+//! - Each render uses a fresh `Tera` holding only the one template, so
+//!   `include`, `extends`, and `import` cannot reach other templates or files
+//! - `get_env`, `now`, and `get_random` are replaced with functions that fail
+//! - `range` is capped at [`MAX_RANGE_LENGTH`] items to bound loop work
+//! - Template source and rendered output are size-limited
+//! - Autoescaping is off; the output is Markdown, not HTML
+
+use std::collections::HashMap;
+
+use serde::Serialize;
+use tera::{Context, Tera, Value};
+
+/// Default maximum template source size.
+pub const DEFAULT_MAX_TEMPLATE_BYTES: usize = 64 * 1024;
+
+/// Default maximum rendered document size.
+pub const DEFAULT_MAX_OUTPUT_BYTES: usize = 1024 * 1024;
+
+/// Largest list `range` will produce.
+pub const MAX_RANGE_LENGTH: i64 = 1_000;
+
+const TEMPLATE_NAME: &str = "document";
+
+/// Builtin functions that would leak environment, clock, or randomness.
+const DISABLED_FUNCTIONS: [&str; 3] = ["get_env", "now", "get_random"];
+
+/// Errors from compiling or rendering a template.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum TemplateError {
+    #[error("Template is {size} bytes; the limit is {max}")]
+    TooLarge { size: usize, max: usize },
+
+    #[error("Template syntax error: {0}")]
+    Syntax(String),
+
+    #[error("Template rendering failed: {0}")]
+    Render(String),
+
+    #[error("Rendered document exceeds {0} bytes")]
+    OutputTooLarge(usize),
+}
+
+/// Renders templates under size limits with unsafe builtins disabled.
+#[derive(Debug, Clone)]
+pub struct TemplateSandbox {
+    max_template_bytes: usize,
+    max_output_bytes: usize,
+}
+
+impl Default for TemplateSandbox {
+    fn default() -> Self {
+        Self::new(DEFAULT_MAX_TEMPLATE_BYTES, DEFAULT_MAX_OUTPUT_BYTES)
+    }
+}
+
+impl TemplateSandbox {
+    pub fn new(max_template_bytes: usize, max_output_bytes: usize) -> Self {
+        Self {
+            max_template_bytes,
+            max_output_bytes,
+        }
+    }
+
+    /// Parse the template without rendering it.
+    pub fn compile(&self, source: &str) -> Result<(), TemplateError> {
+        self.engine(source).map(|_| ())
+    }
+
+    /// Render the template with `context` as its variables.
+    pub fn render(&self, source: &str, context: &impl Serialize) -> Result<String, TemplateError> {
+        let tera = self.engine(source)?;
+        let context =
+            Context::from_serialize(context).map_err(|e| TemplateError::Render(describe(&e)))?;
+        let output = tera
+            .render(TEMPLATE_NAME, &context)
+            .map_err(|e| TemplateError::Render(describe(&e)))?;
+        if output.len() > self.max_output_bytes {
+            return Err(TemplateError::OutputTooLarge(self.max_output_bytes));
+        }
+        Ok(output)
+    }
+
+    fn engine(&self, source: &str) -> Result<Tera, TemplateError> {
+        if source.len() > self.max_template_bytes {
+            return Err(TemplateError::TooLarge {
+                size: source.len(),
+                max: self.max_template_bytes,
+            });
+        }
+
+        let mut tera = Tera::default();
+        tera.autoescape_on(vec![]);
+        for name in DISABLED_FUNCTIONS {
+            tera.register_function(name, move |_: &HashMap<String, Value>| -> tera::Result<Value> {
+                Err(tera::Error::msg(format!(
+                    "`{}` is not available in document templates",
+                    name
+                )))
+            });
+        }
+        tera.register_function("range", bounded_range);
+        tera.register_filter("md_cell", markdown_cell);
+        tera.add_raw_template(TEMPLATE_NAME, source)
+            .map_err(|e| TemplateError::Syntax(describe(&e)))?;
+        Ok(tera)
+    }
+}
+
+/// `range(end, start=0, step_by=1)`, refusing to produce more than
+/// [`MAX_RANGE_LENGTH`] items.
+fn bounded_range(args: &HashMap<String, Value>) -> tera::Result<Value> {
+    let int = |name: &str, default: Option<i64>| match args.get(name) {
+        Some(value) => value
+            .as_i64()
+            .ok_or_else(|| tera::Error::msg(format!("`range`: `{}` must be an integer", name))),
+        None => default.ok_or_else(|| tera::Error::msg(format!("`range`: missing `{}`", name))),
+    };
+    let start = int("start", Some(0))?;
+    let end = int("end", None)?;
+    let step = int("step_by", Some(1))?;
+    if step <= 0 {
+        return Err(tera::Error::msg("`range`: `step_by` must be positive"));
+    }
+    if end.saturating_sub(start) / step > MAX_RANGE_LENGTH {
+        return Err(tera::Error::msg(format!(
+            "`range` is limited to {} items",
+            MAX_RANGE_LENGTH
+        )));
+    }
+    Ok(Value::Array(
+        (start..end)
+            .step_by(step as usize)
+            .map(Value::from)
+            .collect(),
+    ))
+}
+
+/// Make a value safe inside a Markdown table cell.
+fn markdown_cell(value: &Value, _: &HashMap<String, Value>) -> tera::Result<Value> {
+    let text = match value {
+        Value::String(s) => s.clone(),
+        Value::Null => String::new(),
+        other => other.to_string(),
+    };
+    Ok(Value::String(
+        text.replace('|', "\\|").replace(['\r', '\n'], " "),
+    ))
+}
+
+/// Tera reports the useful detail (line, column, cause) in the source chain.
+fn describe(error: &tera::Error) -> String {
+    let mut message = error.to_string();
+    let mut source = std::error::Error::source(error);
+    while let Some(cause) = source {
+        message.push_str(": ");
+        message.push_str(&cause.to_string());
+        source = cause.source();
+    }
+    message
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn renders_context_variables() {
+        let output = TemplateSandbox::default()
+            .render("# {{ title }}", &json!({ "title": "Move to Lisbon" }))
+            .unwrap();
+        assert_eq!(output, "# Move to Lisbon");
+    }
+
+    #[test]
+    fn does_not_html_escape() {
+        let output = TemplateSandbox::default()
+            .render("{{ text }}", &json!({ "text": "R&D <team>" }))
+            .unwrap();
+        assert_eq!(output, "R&D <team>");
+    }
+
+    #[test]
+    fn reports_syntax_errors() {
+        let result = TemplateSandbox::default().compile("{% for x in items %}");
+        assert!(matches!(result, Err(TemplateError::Syntax(_))));
+    }
+
+    #[test]
+    fn environment_access_is_disabled() {
+        let result = TemplateSandbox::default().render(r#"{{ get_env(name="HOME") }}"#, &json!({}));
+        match result {
+            Err(TemplateError::Render(message)) => assert!(message.contains("not available")),
+            other => panic!("expected render error, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn other_templates_cannot_be_included() {
+        let result = TemplateSandbox::default().render(r#"{% include "secrets" %}"#, &json!({}));
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn range_is_bounded() {
+        let sandbox = TemplateSandbox::default();
+        assert_eq!(
+            sandbox
+                .render("{% for i in range(end=3) %}{{ i }}{% endfor %}", &json!({}))
+                .unwrap(),
+            "012"
+        );
+        assert!(sandbox
+            .render("{% for i in range(end=100000000) %}x{% endfor %}", &json!({}))
+            .is_err());
+    }
+
+    #[test]
+    fn enforces_size_limits() {
+        let sandbox = TemplateSandbox::new(16, 8);
+        assert!(matches!(
+            sandbox.compile("a template that is far too long"),
+            Err(TemplateError::TooLarge { max: 16, .. })
+        ));
+        assert_eq!(
+            sandbox.render("{{ text }}", &json!({ "text": "more than eight" })),
+            Err(TemplateError::OutputTooLarge(8))
+        );
+    }
+
+    #[test]
+    fn md_cell_escapes_pipes_and_newlines() {
+        let output = TemplateSandbox::default()
+            .render("{{ text | md_cell }}", &json!({ "text": "a|b\nc" }))
+            .unwrap();
+        assert_eq!(output, "a\\|b c");
+    }
+}
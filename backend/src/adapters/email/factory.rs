@@ -0,0 +1,82 @@
+//! Builds the configured email sender from `EmailConfig`.
+
+use std::sync::Arc;
+
+use crate::config::{EmailConfig, EmailProvider, SmtpSecurity};
+use crate::ports::EmailSender;
+
+use super::{
+    mime, FailoverEmailSender, ResendConfig, ResendEmailSender, SesConfig, SesEmailSender,
+    SmtpConfig, SmtpEmailSender, SmtpTls,
+};
+
+/// Creates the primary provider, wrapped with the fallback when one is
+/// configured. Expects `config` to have passed `EmailConfig::validate`.
+pub fn build_email_sender(config: &EmailConfig) -> Arc<dyn EmailSender> {
+    let primary = build_provider(config, config.provider);
+    match config.fallback_provider {
+        Some(fallback) => Arc::new(
+            FailoverEmailSender::new(primary).with_fallback(build_provider(config, fallback)),
+        ),
+        None => primary,
+    }
+}
+
+fn build_provider(config: &EmailConfig, provider: EmailProvider) -> Arc<dyn EmailSender> {
+    let from = mime::mailbox(&config.from_name, &config.from_email);
+    match provider {
+        EmailProvider::Resend => Arc::new(ResendEmailSender::new(
+            ResendConfig::new(&config.resend_api_key, from).with_timeout(config.timeout()),
+        )),
+        EmailProvider::Smtp => {
+            let tls = match config.smtp_security {
+                SmtpSecurity::Starttls => SmtpTls::Starttls,
+                SmtpSecurity::Tls => SmtpTls::Implicit,
+                SmtpSecurity::None => SmtpTls::None,
+            };
+            let mut smtp = SmtpConfig::new(&config.smtp_host, config.smtp_port, &config.from_email)
+                .with_tls(tls)
+                .with_from_header(from)
+                .with_timeout(config.timeout());
+            if let (Some(username), Some(password)) = (&config.smtp_username, &config.smtp_password)
+            {
+                if config.has_smtp_credentials() {
+                    smtp = smtp.with_credentials(username, password);
+                }
+            }
+            Arc::new(SmtpEmailSender::new(smtp))
+        }
+        EmailProvider::Ses => Arc::new(SesEmailSender::new(
+            SesConfig::new(
+                &config.ses_region,
+                &config.ses_access_key_id,
+                &config.ses_secret_access_key,
+                &config.from_email,
+            )
+            .with_from_header(from)
+            .with_timeout(config.timeout()),
+        )),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn selects_the_configured_provider() {
+        let config = EmailConfig {
+            provider: EmailProvider::Smtp,
+            smtp_host: "smtp.example.com".to_string(),
+            ..Default::default()
+        };
+        assert_eq!(build_email_sender(&config).name(), "smtp");
+
+        let config = EmailConfig {
+            provider: EmailProvider::Ses,
+            fallback_provider: Some(EmailProvider::Resend),
+            ..Default::default()
+        };
+        assert_eq!(build_email_sender(&config).name(), "ses");
+    }
+}
@@ -0,0 +1,111 @@
+//! Failover email sender - Tries a fallback provider when the primary is down.
+//!
+//! Only retryable errors (provider unreachable or throttling) fail over; a
+//! message the primary rejected would be rejected by the fallback too.
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use crate::ports::{EmailError, EmailMessage, EmailSender};
+
+/// Email sender wrapper with automatic failover.
+pub struct FailoverEmailSender {
+    primary: Arc<dyn EmailSender>,
+    fallback: Option<Arc<dyn EmailSender>>,
+}
+
+impl FailoverEmailSender {
+    /// Creates a sender with only a primary provider.
+    pub fn new(primary: Arc<dyn EmailSender>) -> Self {
+        Self {
+            primary,
+            fallback: None,
+        }
+    }
+
+    /// Adds a fallback provider.
+    pub fn with_fallback(mut self, fallback: Arc<dyn EmailSender>) -> Self {
+        self.fallback = Some(fallback);
+        self
+    }
+}
+
+#[async_trait]
+impl EmailSender for FailoverEmailSender {
+    async fn send(&self, message: &EmailMessage) -> Result<(), EmailError> {
+        let error = match self.primary.send(message).await {
+            Ok(()) => return Ok(()),
+            Err(error) => error,
+        };
+
+        match &self.fallback {
+            Some(fallback) if error.is_retryable() => {
+                tracing::warn!(
+                    primary = self.primary.name(),
+                    fallback = fallback.name(),
+                    error = %error,
+                    "Email provider unavailable, failing over"
+                );
+                fallback.send(message).await
+            }
+            _ => Err(error),
+        }
+    }
+
+    fn name(&self) -> &'static str {
+        self.primary.name()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::adapters::email::InMemoryEmailSender;
+
+    fn message() -> EmailMessage {
+        EmailMessage {
+            to: "jo@example.com".to_string(),
+            subject: "Hi".to_string(),
+            text_body: "Body".to_string(),
+            attachments: vec![],
+        }
+    }
+
+    #[tokio::test]
+    async fn unavailable_primary_fails_over() {
+        let primary = InMemoryEmailSender::new();
+        let fallback = InMemoryEmailSender::new();
+        primary.fail_next(EmailError::Unavailable("down".to_string()));
+        let sender = FailoverEmailSender::new(Arc::new(primary.clone()))
+            .with_fallback(Arc::new(fallback.clone()));
+
+        sender.send(&message()).await.unwrap();
+
+        assert!(primary.sent().is_empty());
+        assert_eq!(fallback.sent().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn rejected_messages_do_not_fail_over() {
+        let primary = InMemoryEmailSender::new();
+        let fallback = InMemoryEmailSender::new();
+        primary.fail_next(EmailError::Rejected("bad address".to_string()));
+        let sender =
+            FailoverEmailSender::new(Arc::new(primary)).with_fallback(Arc::new(fallback.clone()));
+
+        let err = sender.send(&message()).await.unwrap_err();
+
+        assert!(matches!(err, EmailError::Rejected(_)));
+        assert!(fallback.sent().is_empty());
+    }
+
+    #[tokio::test]
+    async fn without_fallback_the_primary_error_is_returned() {
+        let primary = InMemoryEmailSender::new();
+        primary.fail_next(EmailError::Unavailable("down".to_string()));
+        let sender = FailoverEmailSender::new(Arc::new(primary));
+
+        assert!(sender.send(&message()).await.unwrap_err().is_retryable());
+    }
+}
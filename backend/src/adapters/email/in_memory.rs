@@ -0,0 +1,72 @@
+//! In-memory email sender for testing.
+
+use std::collections::VecDeque;
+use std::sync::{Arc, Mutex};
+
+use async_trait::async_trait;
+
+use crate::ports::{EmailError, EmailMessage, EmailSender};
+
+/// Records sent messages. Failures can be queued to exercise retry paths.
+#[derive(Debug, Clone, Default)]
+pub struct InMemoryEmailSender {
+    sent: Arc<Mutex<Vec<EmailMessage>>>,
+    failures: Arc<Mutex<VecDeque<EmailError>>>,
+}
+
+impl InMemoryEmailSender {
+    /// Create a sender that accepts every message.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Make the next send fail with `error`. Queued failures are used in order.
+    pub fn fail_next(&self, error: EmailError) {
+        self.failures.lock().unwrap().push_back(error);
+    }
+
+    /// Messages sent so far, oldest first.
+    pub fn sent(&self) -> Vec<EmailMessage> {
+        self.sent.lock().unwrap().clone()
+    }
+}
+
+#[async_trait]
+impl EmailSender for InMemoryEmailSender {
+    async fn send(&self, message: &EmailMessage) -> Result<(), EmailError> {
+        if let Some(error) = self.failures.lock().unwrap().pop_front() {
+            return Err(error);
+        }
+        self.sent.lock().unwrap().push(message.clone());
+        Ok(())
+    }
+
+    fn name(&self) -> &'static str {
+        "in_memory"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn message() -> EmailMessage {
+        EmailMessage {
+            to: "jo@example.com".to_string(),
+            subject: "Hello".to_string(),
+            text_body: "Hi".to_string(),
+            attachments: vec![],
+        }
+    }
+
+    #[tokio::test]
+    async fn records_messages_and_replays_queued_failures() {
+        let sender = InMemoryEmailSender::new();
+        sender.fail_next(EmailError::Unavailable("down".to_string()));
+
+        assert!(sender.send(&message()).await.is_err());
+        sender.send(&message()).await.unwrap();
+
+        assert_eq!(sender.sent(), vec![message()]);
+    }
+}
@@ -0,0 +1,218 @@
+//! MIME encoding shared by the SMTP and SES senders.
+//!
+//! Bodies and attachments are base64-encoded, so the message is 7-bit clean
+//! and no line starts with a dot. Header values have CR and LF removed to
+//! rule out header injection; non-ASCII values become RFC 2047 encoded words.
+
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine;
+
+use crate::ports::EmailMessage;
+
+/// Longest base64 line in a body part (RFC 2045).
+const BASE64_LINE_CHARS: usize = 76;
+
+/// Bytes of UTF-8 per encoded word, keeping each word under 75 characters.
+const ENCODED_WORD_BYTES: usize = 45;
+
+/// Formats a `From`/`To` mailbox, encoding the display name as needed.
+pub(super) fn mailbox(name: &str, address: &str) -> String {
+    let name = single_line(name);
+    let address = single_line(address);
+    if name.is_empty() {
+        return format!("<{}>", address);
+    }
+    if name.is_ascii() {
+        let quoted = name.replace('\\', "\\\\").replace('"', "\\\"");
+        format!("\"{}\" <{}>", quoted, address)
+    } else {
+        format!("{} <{}>", encode_header(&name), address)
+    }
+}
+
+/// Builds the full RFC 5322 message, CRLF line endings throughout.
+pub(super) fn build_message(from: &str, message: &EmailMessage) -> String {
+    let domain = from
+        .rsplit_once('@')
+        .map(|(_, domain)| domain.trim_end_matches('>'))
+        .unwrap_or("localhost");
+    let mut out = String::new();
+    header(&mut out, "From", &single_line(from));
+    header(&mut out, "To", &single_line(&message.to));
+    header(
+        &mut out,
+        "Subject",
+        &encode_header(&single_line(&message.subject)),
+    );
+    header(&mut out, "Date", &chrono::Utc::now().to_rfc2822());
+    header(
+        &mut out,
+        "Message-ID",
+        &format!("<{}@{}>", uuid::Uuid::new_v4(), domain),
+    );
+    header(&mut out, "MIME-Version", "1.0");
+
+    if message.attachments.is_empty() {
+        text_part(&mut out, &message.text_body);
+        return out;
+    }
+
+    let boundary = format!("=_cs_{}", uuid::Uuid::new_v4().simple());
+    header(
+        &mut out,
+        "Content-Type",
+        &format!("multipart/mixed; boundary=\"{}\"", boundary),
+    );
+    out.push_str("\r\n");
+
+    out.push_str(&format!("--{}\r\n", boundary));
+    text_part(&mut out, &message.text_body);
+    for attachment in &message.attachments {
+        let file_name = file_name(&attachment.file_name);
+        out.push_str(&format!("--{}\r\n", boundary));
+        header(
+            &mut out,
+            "Content-Type",
+            &format!(
+                "{}; name={}",
+                single_line(&attachment.content_type),
+                file_name
+            ),
+        );
+        header(
+            &mut out,
+            "Content-Disposition",
+            &format!("attachment; filename={}", file_name),
+        );
+        header(&mut out, "Content-Transfer-Encoding", "base64");
+        out.push_str("\r\n");
+        base64_lines(&mut out, &attachment.bytes);
+    }
+    out.push_str(&format!("--{}--\r\n", boundary));
+    out
+}
+
+fn text_part(out: &mut String, body: &str) {
+    header(out, "Content-Type", "text/plain; charset=utf-8");
+    header(out, "Content-Transfer-Encoding", "base64");
+    out.push_str("\r\n");
+    base64_lines(out, body.as_bytes());
+}
+
+fn header(out: &mut String, name: &str, value: &str) {
+    out.push_str(name);
+    out.push_str(": ");
+    out.push_str(value);
+    out.push_str("\r\n");
+}
+
+fn base64_lines(out: &mut String, bytes: &[u8]) {
+    let encoded = STANDARD.encode(bytes);
+    for line in encoded.as_bytes().chunks(BASE64_LINE_CHARS) {
+        // Base64 output is ASCII, so every chunk is valid UTF-8
+        out.push_str(std::str::from_utf8(line).unwrap_or_default());
+        out.push_str("\r\n");
+    }
+}
+
+/// Quoted (or encoded) attachment file name.
+fn file_name(name: &str) -> String {
+    let name = single_line(name).replace(['"', '\\'], "_");
+    if name.is_ascii() {
+        format!("\"{}\"", name)
+    } else {
+        format!("\"{}\"", encode_header(&name))
+    }
+}
+
+fn single_line(value: &str) -> String {
+    value.replace(['\r', '\n'], " ").trim().to_string()
+}
+
+/// RFC 2047 `B` encoding for non-ASCII header text, split on character
+/// boundaries into folded encoded words.
+fn encode_header(value: &str) -> String {
+    if value.is_ascii() {
+        return value.to_string();
+    }
+    let mut words = Vec::new();
+    let mut start = 0;
+    while start < value.len() {
+        let mut end = (start + ENCODED_WORD_BYTES).min(value.len());
+        while !value.is_char_boundary(end) {
+            end -= 1;
+        }
+        words.push(format!(
+            "=?UTF-8?B?{}?=",
+            STANDARD.encode(&value.as_bytes()[start..end])
+        ));
+        start = end;
+    }
+    words.join("\r\n ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ports::EmailAttachment;
+
+    fn message() -> EmailMessage {
+        EmailMessage {
+            to: "jo@example.com".to_string(),
+            subject: "Your decision".to_string(),
+            text_body: "Hello\n.\nBye".to_string(),
+            attachments: vec![],
+        }
+    }
+
+    #[test]
+    fn plain_messages_are_single_part_and_dot_free() {
+        let raw = build_message("\"Choice Sherpa\" <noreply@example.com>", &message());
+
+        assert!(raw.contains("Subject: Your decision\r\n"));
+        assert!(raw.contains("Content-Type: text/plain; charset=utf-8\r\n"));
+        assert!(raw.contains("@example.com>\r\nMIME-Version"));
+        assert!(raw.contains(&STANDARD.encode("Hello\n.\nBye")));
+        assert!(!raw.lines().any(|line| line.starts_with('.')));
+        assert!(!raw.replace("\r\n", "").contains('\n'));
+    }
+
+    #[test]
+    fn header_values_cannot_inject_headers() {
+        let mut injected = message();
+        injected.subject = "Hi\r\nBcc: victim@example.com".to_string();
+        injected.to = "jo@example.com\nBcc: victim@example.com".to_string();
+        let raw = build_message("<noreply@example.com>", &injected);
+
+        assert!(!raw.contains("\r\nBcc:"));
+    }
+
+    #[test]
+    fn non_ascii_headers_use_encoded_words() {
+        let encoded = encode_header(&"Décision ".repeat(10));
+        assert!(encoded
+            .split("\r\n ")
+            .all(|word| word.starts_with("=?UTF-8?B?") && word.len() <= 75));
+
+        assert_eq!(
+            mailbox("Choice \"Sherpa\"", "a@example.com"),
+            "\"Choice \\\"Sherpa\\\"\" <a@example.com>"
+        );
+    }
+
+    #[test]
+    fn attachments_become_a_multipart_message() {
+        let mut with_attachment = message();
+        with_attachment.attachments.push(EmailAttachment {
+            file_name: "follow-up.ics".to_string(),
+            content_type: "text/calendar".to_string(),
+            bytes: b"BEGIN:VCALENDAR".to_vec(),
+        });
+        let raw = build_message("<noreply@example.com>", &with_attachment);
+
+        assert!(raw.contains("Content-Type: multipart/mixed; boundary=\"=_cs_"));
+        assert!(raw.contains("Content-Disposition: attachment; filename=\"follow-up.ics\"\r\n"));
+        assert!(raw.contains(&STANDARD.encode("BEGIN:VCALENDAR")));
+        assert!(raw.trim_end().ends_with("--"));
+    }
+}
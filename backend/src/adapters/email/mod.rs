@@ -0,0 +1,33 @@
+//! Email adapters - Implementations of the `EmailSender` port.
+//!
+//! - `ResendEmailSender` - Resend HTTP API
+//! - `SmtpEmailSender` - Any SMTP relay (STARTTLS, implicit TLS, or plain)
+//! - `SesEmailSender` - AWS SES v2 API with SigV4 signing
+//! - `FailoverEmailSender` - Falls back to a second provider when the first
+//!   is unavailable
+//! - `InMemoryEmailSender` - Records messages instead of sending them, for
+//!   tests (`test-support`)
+//!
+//! `build_email_sender` picks providers from `EmailConfig`.
+//!
+//! `TeraEmailTemplateRenderer` renders the built-in, per-locale subjects and
+//! bodies in `templates/`.
+
+mod factory;
+mod failover;
+#[cfg(any(test, feature = "test-support"))]
+mod in_memory;
+mod mime;
+mod resend;
+mod ses;
+mod smtp;
+mod template_renderer;
+
+pub use factory::build_email_sender;
+pub use failover::FailoverEmailSender;
+#[cfg(any(test, feature = "test-support"))]
+pub use in_memory::InMemoryEmailSender;
+pub use resend::{ResendConfig, ResendEmailSender};
+pub use ses::{SesConfig, SesEmailSender};
+pub use smtp::{SmtpConfig, SmtpEmailSender, SmtpTls};
+pub use template_renderer::TeraEmailTemplateRenderer;
@@ -0,0 +1,162 @@
+//! Resend email sender - Sends through the Resend HTTP API.
+//!
+//! 5xx, 429, and connection failures are reported as unavailable; other 4xx
+//! responses mean Resend refused the message.
+
+use std::time::Duration;
+
+use async_trait::async_trait;
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine;
+use reqwest::{header, Client, StatusCode};
+use secrecy::{ExposeSecret, Secret};
+use serde_json::json;
+
+use crate::ports::{EmailError, EmailMessage, EmailSender};
+
+const DEFAULT_BASE_URL: &str = "https://api.resend.com";
+
+/// Resend sender settings.
+#[derive(Debug, Clone)]
+pub struct ResendConfig {
+    pub api_key: Secret<String>,
+    /// `From` header, e.g. `Choice Sherpa <noreply@choicesherpa.com>`.
+    pub from: String,
+    pub base_url: String,
+    /// Request timeout per message.
+    pub timeout: Duration,
+}
+
+impl ResendConfig {
+    pub fn new(api_key: impl Into<String>, from: impl Into<String>) -> Self {
+        Self {
+            api_key: Secret::new(api_key.into()),
+            from: from.into(),
+            base_url: DEFAULT_BASE_URL.to_string(),
+            timeout: Duration::from_secs(30),
+        }
+    }
+
+    /// Sets the request timeout.
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+}
+
+/// Sends email through Resend.
+pub struct ResendEmailSender {
+    client: Client,
+    config: ResendConfig,
+}
+
+impl ResendEmailSender {
+    pub fn new(config: ResendConfig) -> Self {
+        let client = Client::builder()
+            .timeout(config.timeout)
+            .build()
+            .expect("Failed to create HTTP client");
+        Self { client, config }
+    }
+
+    fn body(&self, message: &EmailMessage) -> serde_json::Value {
+        let attachments: Vec<serde_json::Value> = message
+            .attachments
+            .iter()
+            .map(|a| {
+                json!({
+                    "filename": a.file_name,
+                    "content": STANDARD.encode(&a.bytes),
+                    "content_type": a.content_type,
+                })
+            })
+            .collect();
+        json!({
+            "from": self.config.from,
+            "to": [message.to],
+            "subject": message.subject,
+            "text": message.text_body,
+            "attachments": attachments,
+        })
+    }
+}
+
+#[async_trait]
+impl EmailSender for ResendEmailSender {
+    async fn send(&self, message: &EmailMessage) -> Result<(), EmailError> {
+        let response = self
+            .client
+            .post(format!("{}/emails", self.config.base_url))
+            .header(
+                header::AUTHORIZATION,
+                format!("Bearer {}", self.config.api_key.expose_secret()),
+            )
+            .json(&self.body(message))
+            .send()
+            .await
+            .map_err(|e| EmailError::Unavailable(e.to_string()))?;
+
+        classify_status(response.status())
+    }
+
+    fn name(&self) -> &'static str {
+        "resend"
+    }
+}
+
+fn classify_status(status: StatusCode) -> Result<(), EmailError> {
+    if status.is_success() {
+        Ok(())
+    } else if status.is_server_error() || status == StatusCode::TOO_MANY_REQUESTS {
+        Err(EmailError::Unavailable(format!(
+            "Resend returned {}",
+            status
+        )))
+    } else {
+        Err(EmailError::Rejected(format!("Resend returned {}", status)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ports::EmailAttachment;
+
+    #[test]
+    fn throttling_and_server_errors_are_retryable() {
+        assert!(classify_status(StatusCode::OK).is_ok());
+        assert!(classify_status(StatusCode::BAD_GATEWAY)
+            .unwrap_err()
+            .is_retryable());
+        assert!(classify_status(StatusCode::TOO_MANY_REQUESTS)
+            .unwrap_err()
+            .is_retryable());
+        assert!(!classify_status(StatusCode::UNPROCESSABLE_ENTITY)
+            .unwrap_err()
+            .is_retryable());
+    }
+
+    #[test]
+    fn body_carries_base64_attachments() {
+        let sender = ResendEmailSender::new(ResendConfig::new("re_test", "CS <a@example.com>"));
+        let body = sender.body(&EmailMessage {
+            to: "jo@example.com".to_string(),
+            subject: "Hi".to_string(),
+            text_body: "Body".to_string(),
+            attachments: vec![EmailAttachment {
+                file_name: "a.txt".to_string(),
+                content_type: "text/plain".to_string(),
+                bytes: b"abc".to_vec(),
+            }],
+        });
+
+        assert_eq!(body["to"][0], "jo@example.com");
+        assert_eq!(body["attachments"][0]["content"], "YWJj");
+    }
+
+    #[test]
+    fn config_debug_hides_api_key() {
+        let config = ResendConfig::new("re_super_secret", "a@example.com");
+        assert!(!format!("{:?}", config).contains("re_super_secret"));
+    }
+}
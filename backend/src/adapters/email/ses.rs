@@ -0,0 +1,269 @@
+//! AWS SES email sender - Sends raw MIME through the SES v2 API.
+//!
+//! Requests are signed with AWS Signature Version 4. 5xx, 429, and
+//! connection failures are reported as unavailable; other 4xx responses mean
+//! SES refused the message (unverified sender, suppressed recipient).
+
+use std::time::Duration;
+
+use async_trait::async_trait;
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine;
+use chrono::{DateTime, Utc};
+use hmac::{Hmac, Mac};
+use reqwest::{header, Client, StatusCode};
+use secrecy::{ExposeSecret, Secret};
+use serde_json::json;
+use sha2::{Digest, Sha256};
+
+use super::mime;
+use crate::adapters::storage::hex_encode;
+use crate::ports::{EmailError, EmailMessage, EmailSender};
+
+type HmacSha256 = Hmac<Sha256>;
+
+const SERVICE: &str = "ses";
+const SEND_PATH: &str = "/v2/email/outbound-emails";
+
+/// SES sender settings.
+#[derive(Debug, Clone)]
+pub struct SesConfig {
+    /// AWS region, e.g. `eu-west-1`.
+    pub region: String,
+    pub access_key_id: String,
+    pub secret_access_key: Secret<String>,
+    /// Verified sender address.
+    pub from_address: String,
+    /// `From` header, e.g. `"Choice Sherpa" <noreply@choicesherpa.com>`.
+    pub from: String,
+    /// Request timeout per message.
+    pub timeout: Duration,
+}
+
+impl SesConfig {
+    pub fn new(
+        region: impl Into<String>,
+        access_key_id: impl Into<String>,
+        secret_access_key: impl Into<String>,
+        from_address: impl Into<String>,
+    ) -> Self {
+        let from_address = from_address.into();
+        Self {
+            region: region.into(),
+            access_key_id: access_key_id.into(),
+            secret_access_key: Secret::new(secret_access_key.into()),
+            from: format!("<{}>", from_address),
+            from_address,
+            timeout: Duration::from_secs(30),
+        }
+    }
+
+    /// Sets the `From` header.
+    pub fn with_from_header(mut self, from: impl Into<String>) -> Self {
+        self.from = from.into();
+        self
+    }
+
+    /// Sets the request timeout.
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    fn host(&self) -> String {
+        format!("email.{}.amazonaws.com", self.region)
+    }
+}
+
+/// Sends email through Amazon SES.
+pub struct SesEmailSender {
+    client: Client,
+    config: SesConfig,
+}
+
+impl SesEmailSender {
+    pub fn new(config: SesConfig) -> Self {
+        let client = Client::builder()
+            .timeout(config.timeout)
+            .build()
+            .expect("Failed to create HTTP client");
+        Self { client, config }
+    }
+
+    fn body(&self, message: &EmailMessage) -> String {
+        let raw = mime::build_message(&self.config.from, message);
+        json!({
+            "FromEmailAddress": self.config.from_address,
+            "Destination": { "ToAddresses": [message.to] },
+            "Content": { "Raw": { "Data": STANDARD.encode(raw) } },
+        })
+        .to_string()
+    }
+
+    /// `Authorization` header value for a JSON POST to [`SEND_PATH`].
+    fn authorization(&self, body: &str, now: DateTime<Utc>) -> String {
+        let amz_date = now.format("%Y%m%dT%H%M%SZ").to_string();
+        let date = now.format("%Y%m%d").to_string();
+        let scope = format!("{}/{}/{}/aws4_request", date, self.config.region, SERVICE);
+        let signed_headers = "content-type;host;x-amz-date";
+
+        let canonical_request = format!(
+            "POST\n{}\n\ncontent-type:application/json\nhost:{}\nx-amz-date:{}\n\n{}\n{}",
+            SEND_PATH,
+            self.config.host(),
+            amz_date,
+            signed_headers,
+            hex_encode(&Sha256::digest(body.as_bytes())),
+        );
+        let string_to_sign = format!(
+            "AWS4-HMAC-SHA256\n{}\n{}\n{}",
+            amz_date,
+            scope,
+            hex_encode(&Sha256::digest(canonical_request.as_bytes())),
+        );
+
+        let key = signing_key(
+            self.config.secret_access_key.expose_secret(),
+            &date,
+            &self.config.region,
+            SERVICE,
+        );
+        let signature = hex_encode(&hmac(&key, &string_to_sign));
+
+        format!(
+            "AWS4-HMAC-SHA256 Credential={}/{}, SignedHeaders={}, Signature={}",
+            self.config.access_key_id, scope, signed_headers, signature
+        )
+    }
+}
+
+#[async_trait]
+impl EmailSender for SesEmailSender {
+    async fn send(&self, message: &EmailMessage) -> Result<(), EmailError> {
+        let body = self.body(message);
+        let now = Utc::now();
+
+        let response = self
+            .client
+            .post(format!("https://{}{}", self.config.host(), SEND_PATH))
+            .header(header::CONTENT_TYPE, "application/json")
+            .header("x-amz-date", now.format("%Y%m%dT%H%M%SZ").to_string())
+            .header(header::AUTHORIZATION, self.authorization(&body, now))
+            .body(body)
+            .send()
+            .await
+            .map_err(|e| EmailError::Unavailable(e.to_string()))?;
+
+        classify_status(response.status())
+    }
+
+    fn name(&self) -> &'static str {
+        "ses"
+    }
+}
+
+/// SigV4 signing key for `service` on `date` (`YYYYMMDD`).
+fn signing_key(secret: &str, date: &str, region: &str, service: &str) -> Vec<u8> {
+    let k_date = hmac(format!("AWS4{}", secret).as_bytes(), date);
+    let k_region = hmac(&k_date, region);
+    let k_service = hmac(&k_region, service);
+    hmac(&k_service, "aws4_request")
+}
+
+fn hmac(key: &[u8], data: &str) -> Vec<u8> {
+    let mut mac = HmacSha256::new_from_slice(key).expect("HMAC accepts any key length");
+    mac.update(data.as_bytes());
+    mac.finalize().into_bytes().to_vec()
+}
+
+fn classify_status(status: StatusCode) -> Result<(), EmailError> {
+    if status.is_success() {
+        Ok(())
+    } else if status.is_server_error() || status == StatusCode::TOO_MANY_REQUESTS {
+        Err(EmailError::Unavailable(format!("SES returned {}", status)))
+    } else {
+        Err(EmailError::Rejected(format!("SES returned {}", status)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn sender() -> SesEmailSender {
+        SesEmailSender::new(SesConfig::new(
+            "us-east-1",
+            "AKIDEXAMPLE",
+            "wJalrXUtnFEMI/K7MDENG+bPxRfiCYEXAMPLEKEY",
+            "noreply@example.com",
+        ))
+    }
+
+    fn message() -> EmailMessage {
+        EmailMessage {
+            to: "jo@example.com".to_string(),
+            subject: "Hi".to_string(),
+            text_body: "Body".to_string(),
+            attachments: vec![],
+        }
+    }
+
+    #[test]
+    fn signing_key_matches_aws_reference() {
+        // Worked example from the AWS SigV4 documentation
+        let key = signing_key(
+            "wJalrXUtnFEMI/K7MDENG+bPxRfiCYEXAMPLEKEY",
+            "20120215",
+            "us-east-1",
+            "iam",
+        );
+        assert_eq!(
+            hex_encode(&key),
+            "f4780e2d9f65fa895f9c67b32ce1baf0b0d8a43505a000a1a9e090d414db404d"
+        );
+    }
+
+    #[test]
+    fn authorization_names_scope_and_signed_headers() {
+        let now = Utc.with_ymd_and_hms(2026, 3, 1, 12, 0, 0).unwrap();
+        let auth = sender().authorization("{}", now);
+
+        assert!(auth.starts_with(
+            "AWS4-HMAC-SHA256 Credential=AKIDEXAMPLE/20260301/us-east-1/ses/aws4_request, \
+             SignedHeaders=content-type;host;x-amz-date, Signature="
+        ));
+        let signature = auth.rsplit('=').next().unwrap();
+        assert_eq!(signature.len(), 64);
+        assert_ne!(auth, sender().authorization("{\"a\":1}", now));
+    }
+
+    #[test]
+    fn body_carries_raw_mime() {
+        let body: serde_json::Value = serde_json::from_str(&sender().body(&message())).unwrap();
+
+        assert_eq!(body["FromEmailAddress"], "noreply@example.com");
+        assert_eq!(body["Destination"]["ToAddresses"][0], "jo@example.com");
+        let raw = STANDARD
+            .decode(body["Content"]["Raw"]["Data"].as_str().unwrap())
+            .unwrap();
+        assert!(String::from_utf8(raw).unwrap().contains("Subject: Hi\r\n"));
+    }
+
+    #[test]
+    fn throttling_and_server_errors_are_retryable() {
+        assert!(classify_status(StatusCode::OK).is_ok());
+        assert!(classify_status(StatusCode::TOO_MANY_REQUESTS)
+            .unwrap_err()
+            .is_retryable());
+        assert!(!classify_status(StatusCode::BAD_REQUEST)
+            .unwrap_err()
+            .is_retryable());
+    }
+
+    #[test]
+    fn config_debug_hides_secret_key() {
+        let config = SesConfig::new("us-east-1", "AKID", "super-secret", "a@example.com");
+        assert!(!format!("{:?}", config).contains("super-secret"));
+    }
+}
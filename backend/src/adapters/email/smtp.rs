@@ -0,0 +1,488 @@
+//! SMTP email sender - Delivers through any SMTP relay.
+//!
+//! Speaks just enough SMTP (RFC 5321) for one message per connection:
+//! EHLO, optional STARTTLS, AUTH PLAIN, MAIL/RCPT/DATA, QUIT. 4xx replies
+//! and connection failures are reported as unavailable; 5xx replies mean
+//! the relay refused the message.
+
+use std::time::Duration;
+
+use async_trait::async_trait;
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine;
+use secrecy::{ExposeSecret, Secret};
+use tokio::io::{AsyncBufReadExt, AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt, BufReader};
+use tokio::net::TcpStream;
+use tokio_native_tls::{native_tls, TlsConnector};
+
+use super::mime;
+use crate::ports::{EmailError, EmailMessage, EmailSender};
+
+/// Longest reply line accepted from the relay.
+const MAX_REPLY_LINE_BYTES: usize = 4096;
+
+/// How the connection to the relay is secured.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SmtpTls {
+    /// Upgrade a plain connection with STARTTLS; fails if the relay does
+    /// not offer it.
+    Starttls,
+    /// TLS from the first byte.
+    Implicit,
+    /// Plain text throughout.
+    None,
+}
+
+/// SMTP sender settings.
+#[derive(Debug, Clone)]
+pub struct SmtpConfig {
+    pub host: String,
+    pub port: u16,
+    pub tls: SmtpTls,
+    /// Username and password for AUTH PLAIN.
+    pub credentials: Option<(String, Secret<String>)>,
+    /// Envelope sender address.
+    pub from_address: String,
+    /// `From` header, e.g. `"Choice Sherpa" <noreply@choicesherpa.com>`.
+    pub from: String,
+    /// Limit on the whole SMTP conversation.
+    pub timeout: Duration,
+}
+
+impl SmtpConfig {
+    pub fn new(host: impl Into<String>, port: u16, from_address: impl Into<String>) -> Self {
+        let from_address = from_address.into();
+        Self {
+            host: host.into(),
+            port,
+            tls: SmtpTls::Starttls,
+            credentials: None,
+            from: format!("<{}>", from_address),
+            from_address,
+            timeout: Duration::from_secs(30),
+        }
+    }
+
+    /// Sets how the connection is secured.
+    pub fn with_tls(mut self, tls: SmtpTls) -> Self {
+        self.tls = tls;
+        self
+    }
+
+    /// Authenticates with AUTH PLAIN.
+    pub fn with_credentials(
+        mut self,
+        username: impl Into<String>,
+        password: impl Into<String>,
+    ) -> Self {
+        self.credentials = Some((username.into(), Secret::new(password.into())));
+        self
+    }
+
+    /// Sets the `From` header.
+    pub fn with_from_header(mut self, from: impl Into<String>) -> Self {
+        self.from = from.into();
+        self
+    }
+
+    /// Sets the conversation timeout.
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+}
+
+/// Sends email through an SMTP relay, one connection per message.
+pub struct SmtpEmailSender {
+    config: SmtpConfig,
+}
+
+impl SmtpEmailSender {
+    pub fn new(config: SmtpConfig) -> Self {
+        Self { config }
+    }
+
+    async fn deliver(&self, message: &EmailMessage) -> Result<(), EmailError> {
+        let tcp = TcpStream::connect((self.config.host.as_str(), self.config.port))
+            .await
+            .map_err(|e| EmailError::Unavailable(format!("SMTP connect failed: {}", e)))?;
+
+        match self.config.tls {
+            SmtpTls::Implicit => {
+                let tls = self.tls_handshake(tcp).await?;
+                let mut session = Session::new(tls);
+                session.expect(220).await?;
+                let capabilities = session.ehlo(self.ehlo_domain()).await?;
+                self.transact(&mut session, &capabilities, message).await
+            }
+            SmtpTls::Starttls => {
+                let mut session = Session::new(tcp);
+                session.expect(220).await?;
+                let capabilities = session.ehlo(self.ehlo_domain()).await?;
+                if !capabilities
+                    .iter()
+                    .any(|c| c.eq_ignore_ascii_case("STARTTLS"))
+                {
+                    return Err(EmailError::Unavailable(
+                        "SMTP relay does not offer STARTTLS".to_string(),
+                    ));
+                }
+                session.command("STARTTLS", 220).await?;
+                let tls = self.tls_handshake(session.into_inner()).await?;
+                let mut session = Session::new(tls);
+                let capabilities = session.ehlo(self.ehlo_domain()).await?;
+                self.transact(&mut session, &capabilities, message).await
+            }
+            SmtpTls::None => {
+                let mut session = Session::new(tcp);
+                session.expect(220).await?;
+                let capabilities = session.ehlo(self.ehlo_domain()).await?;
+                self.transact(&mut session, &capabilities, message).await
+            }
+        }
+    }
+
+    /// Name announced in EHLO: the sender's mail domain.
+    fn ehlo_domain(&self) -> &str {
+        self.config
+            .from_address
+            .rsplit_once('@')
+            .map_or("localhost", |(_, domain)| domain)
+    }
+
+    async fn tls_handshake(
+        &self,
+        tcp: TcpStream,
+    ) -> Result<tokio_native_tls::TlsStream<TcpStream>, EmailError> {
+        let connector = native_tls::TlsConnector::new()
+            .map_err(|e| EmailError::Unavailable(format!("TLS setup failed: {}", e)))?;
+        TlsConnector::from(connector)
+            .connect(&self.config.host, tcp)
+            .await
+            .map_err(|e| EmailError::Unavailable(format!("TLS handshake failed: {}", e)))
+    }
+
+    /// Authenticates if configured, then sends the message and quits.
+    async fn transact<S: AsyncRead + AsyncWrite + Unpin>(
+        &self,
+        session: &mut Session<S>,
+        capabilities: &[String],
+        message: &EmailMessage,
+    ) -> Result<(), EmailError> {
+        if let Some((username, password)) = &self.config.credentials {
+            if !capabilities
+                .iter()
+                .any(|c| c.to_ascii_uppercase().starts_with("AUTH") && c.contains("PLAIN"))
+            {
+                return Err(EmailError::Unavailable(
+                    "SMTP relay does not offer AUTH PLAIN".to_string(),
+                ));
+            }
+            let token = STANDARD.encode(format!("\0{}\0{}", username, password.expose_secret()));
+            session
+                .command(&format!("AUTH PLAIN {}", token), 235)
+                .await?;
+        }
+
+        session
+            .command(&format!("MAIL FROM:<{}>", self.config.from_address), 250)
+            .await?;
+        session
+            .command(&format!("RCPT TO:<{}>", message.to), 250)
+            .await?;
+        session.command("DATA", 354).await?;
+        session
+            .send_data(&mime::build_message(&self.config.from, message))
+            .await?;
+        // The message is accepted; a failed QUIT does not matter
+        let _ = session.command("QUIT", 221).await;
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl EmailSender for SmtpEmailSender {
+    async fn send(&self, message: &EmailMessage) -> Result<(), EmailError> {
+        if !is_plain_address(&message.to) {
+            return Err(EmailError::Rejected(
+                "Invalid recipient address".to_string(),
+            ));
+        }
+        tokio::time::timeout(self.config.timeout, self.deliver(message))
+            .await
+            .map_err(|_| EmailError::Unavailable("SMTP conversation timed out".to_string()))?
+    }
+
+    fn name(&self) -> &'static str {
+        "smtp"
+    }
+}
+
+/// An address that can go between `<>` in an SMTP command.
+fn is_plain_address(address: &str) -> bool {
+    address.contains('@')
+        && !address
+            .chars()
+            .any(|c| c.is_whitespace() || c.is_control() || c == '<' || c == '>')
+}
+
+/// One SMTP connection: commands out, multi-line replies in.
+struct Session<S> {
+    stream: BufReader<S>,
+}
+
+impl<S: AsyncRead + AsyncWrite + Unpin> Session<S> {
+    fn new(stream: S) -> Self {
+        Self {
+            stream: BufReader::new(stream),
+        }
+    }
+
+    fn into_inner(self) -> S {
+        self.stream.into_inner()
+    }
+
+    /// Reads a reply and returns its code and text lines.
+    async fn read_reply(&mut self) -> Result<(u16, Vec<String>), EmailError> {
+        let mut lines = Vec::new();
+        loop {
+            let mut line = String::new();
+            let read = (&mut self.stream)
+                .take(MAX_REPLY_LINE_BYTES as u64)
+                .read_line(&mut line)
+                .await
+                .map_err(io_error)?;
+            if read == 0 {
+                return Err(EmailError::Unavailable(
+                    "SMTP relay closed the connection".to_string(),
+                ));
+            }
+            let line = line.trim_end();
+            let code = line
+                .get(..3)
+                .and_then(|c| c.parse::<u16>().ok())
+                .ok_or_else(|| {
+                    EmailError::Unavailable(format!("Malformed SMTP reply: {}", line))
+                })?;
+            lines.push(line.get(4..).unwrap_or_default().to_string());
+            // "250-" continues a multi-line reply; "250 " ends it
+            if line.as_bytes().get(3) != Some(&b'-') {
+                return Ok((code, lines));
+            }
+        }
+    }
+
+    /// Reads a reply and fails unless it has the expected code.
+    async fn expect(&mut self, expected: u16) -> Result<Vec<String>, EmailError> {
+        let (code, lines) = self.read_reply().await?;
+        if code == expected || (expected == 250 && code == 251) {
+            return Ok(lines);
+        }
+        let detail = format!("SMTP relay replied {} {}", code, lines.join(" "));
+        if (500..600).contains(&code) {
+            Err(EmailError::Rejected(detail))
+        } else {
+            Err(EmailError::Unavailable(detail))
+        }
+    }
+
+    async fn command(&mut self, line: &str, expected: u16) -> Result<Vec<String>, EmailError> {
+        let stream = self.stream.get_mut();
+        stream
+            .write_all(format!("{}\r\n", line).as_bytes())
+            .await
+            .map_err(io_error)?;
+        stream.flush().await.map_err(io_error)?;
+        self.expect(expected).await
+    }
+
+    /// Sends EHLO and returns the advertised extensions.
+    async fn ehlo(&mut self, domain: &str) -> Result<Vec<String>, EmailError> {
+        let mut lines = self.command(&format!("EHLO {}", domain), 250).await?;
+        // The first line is the relay's greeting, not an extension
+        if !lines.is_empty() {
+            lines.remove(0);
+        }
+        Ok(lines)
+    }
+
+    /// Sends the message body, dot-stuffed and terminated by `.`.
+    async fn send_data(&mut self, data: &str) -> Result<(), EmailError> {
+        let mut body = String::with_capacity(data.len() + 8);
+        for line in data.split("\r\n") {
+            if line.starts_with('.') {
+                body.push('.');
+            }
+            body.push_str(line);
+            body.push_str("\r\n");
+        }
+        if body.ends_with("\r\n\r\n") {
+            body.truncate(body.len() - 2);
+        }
+        body.push_str(".\r\n");
+
+        let stream = self.stream.get_mut();
+        stream.write_all(body.as_bytes()).await.map_err(io_error)?;
+        stream.flush().await.map_err(io_error)?;
+        self.expect(250).await.map(|_| ())
+    }
+}
+
+fn io_error(e: std::io::Error) -> EmailError {
+    EmailError::Unavailable(format!("SMTP connection failed: {}", e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tokio::io::{duplex, DuplexStream};
+
+    fn message() -> EmailMessage {
+        EmailMessage {
+            to: "jo@example.com".to_string(),
+            subject: "Hi".to_string(),
+            text_body: "Body".to_string(),
+            attachments: vec![],
+        }
+    }
+
+    fn sender(credentials: bool) -> SmtpEmailSender {
+        let config = SmtpConfig::new("smtp.example.com", 587, "noreply@example.com");
+        let config = if credentials {
+            config.with_credentials("mailer", "hunter2")
+        } else {
+            config
+        };
+        SmtpEmailSender::new(config)
+    }
+
+    /// Plays the relay side: answers each client line from `replies` in
+    /// order and returns everything the client sent.
+    async fn relay(mut server: DuplexStream, replies: Vec<&'static str>) -> String {
+        let mut transcript = String::new();
+        let mut replies = replies.into_iter();
+        server
+            .write_all(replies.next().unwrap().as_bytes())
+            .await
+            .unwrap();
+        let mut in_data = false;
+        let mut buf = vec![0u8; 8192];
+        let mut pending = String::new();
+        loop {
+            let n = server.read(&mut buf).await.unwrap();
+            if n == 0 {
+                return transcript;
+            }
+            pending.push_str(std::str::from_utf8(&buf[..n]).unwrap());
+            while let Some(end) = pending.find("\r\n") {
+                let line: String = pending.drain(..end + 2).collect();
+                transcript.push_str(&line);
+                if in_data && line != ".\r\n" {
+                    continue;
+                }
+                in_data = line == "DATA\r\n";
+                match replies.next() {
+                    Some(reply) => server.write_all(reply.as_bytes()).await.unwrap(),
+                    None => return transcript,
+                }
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn authenticates_and_sends_one_message() {
+        let (client, server) = duplex(64 * 1024);
+        let relay = tokio::spawn(relay(
+            server,
+            vec![
+                "220 relay ready\r\n",
+                "250-relay\r\n250-AUTH LOGIN PLAIN\r\n250 8BITMIME\r\n",
+                "235 ok\r\n",
+                "250 ok\r\n",
+                "250 ok\r\n",
+                "354 go ahead\r\n",
+                "250 queued\r\n",
+                "221 bye\r\n",
+            ],
+        ));
+
+        let sender = sender(true);
+        let mut session = Session::new(client);
+        session.expect(220).await.unwrap();
+        let capabilities = session.ehlo("example.com").await.unwrap();
+        sender
+            .transact(&mut session, &capabilities, &message())
+            .await
+            .unwrap();
+        drop(session);
+
+        let transcript = relay.await.unwrap();
+        assert!(transcript.starts_with("EHLO example.com\r\n"));
+        assert!(transcript.contains(&format!(
+            "AUTH PLAIN {}\r\n",
+            STANDARD.encode("\0mailer\0hunter2")
+        )));
+        assert!(transcript.contains("MAIL FROM:<noreply@example.com>\r\n"));
+        assert!(transcript.contains("RCPT TO:<jo@example.com>\r\n"));
+        assert!(transcript.contains("\r\n.\r\nQUIT\r\n"));
+    }
+
+    #[tokio::test]
+    async fn permanent_and_transient_replies_are_classified() {
+        let (client, server) = duplex(64 * 1024);
+        tokio::spawn(relay(
+            server,
+            vec![
+                "220 relay ready\r\n",
+                "250 relay\r\n",
+                "250 ok\r\n",
+                "550 no such user\r\n",
+            ],
+        ));
+        let mut session = Session::new(client);
+        session.expect(220).await.unwrap();
+        let capabilities = session.ehlo("example.com").await.unwrap();
+        let err = sender(false)
+            .transact(&mut session, &capabilities, &message())
+            .await
+            .unwrap_err();
+        assert!(matches!(err, EmailError::Rejected(_)));
+
+        let (client, server) = duplex(64 * 1024);
+        tokio::spawn(relay(server, vec!["421 try later\r\n"]));
+        let mut session = Session::new(client);
+        assert!(session.expect(220).await.unwrap_err().is_retryable());
+    }
+
+    #[tokio::test]
+    async fn refuses_to_authenticate_without_auth_plain() {
+        let (client, server) = duplex(64 * 1024);
+        tokio::spawn(relay(
+            server,
+            vec!["220 relay ready\r\n", "250-relay\r\n250 AUTH LOGIN\r\n"],
+        ));
+        let mut session = Session::new(client);
+        session.expect(220).await.unwrap();
+        let capabilities = session.ehlo("example.com").await.unwrap();
+        let err = sender(true)
+            .transact(&mut session, &capabilities, &message())
+            .await
+            .unwrap_err();
+        assert!(err.is_retryable());
+    }
+
+    #[tokio::test]
+    async fn recipients_that_would_break_the_envelope_are_rejected() {
+        let mut bad = message();
+        bad.to = "jo@example.com>\r\nRCPT TO:<victim@example.com".to_string();
+        let err = sender(false).send(&bad).await.unwrap_err();
+        assert!(matches!(err, EmailError::Rejected(_)));
+    }
+
+    #[test]
+    fn config_debug_hides_password() {
+        let config = SmtpConfig::new("smtp.example.com", 587, "a@example.com")
+            .with_credentials("mailer", "super-secret");
+        assert!(!format!("{:?}", config).contains("super-secret"));
+    }
+}
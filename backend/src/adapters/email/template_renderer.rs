@@ -0,0 +1,238 @@
+//! Built-in transactional email templates, rendered with Tera.
+//!
+//! Templates live in `templates/<locale>/<kind>.txt.tera` and are compiled
+//! into the binary. The first rendered line is the subject; the body starts
+//! after the blank line that follows it. Every locale must provide every
+//! [`EmailTemplateKind`]; the snapshot tests below enforce it.
+
+use std::collections::HashSet;
+
+use serde_json::Value as JsonValue;
+use tera::{Context, Tera};
+
+use crate::ports::{
+    locale_fallbacks, EmailTemplateError, EmailTemplateKind, EmailTemplateRenderer, RenderedEmail,
+    DEFAULT_EMAIL_LOCALE,
+};
+
+macro_rules! locale_templates {
+    ($locale:literal) => {
+        [
+            (
+                $locale,
+                EmailTemplateKind::Welcome,
+                include_str!(concat!("templates/", $locale, "/welcome.txt.tera")),
+            ),
+            (
+                $locale,
+                EmailTemplateKind::Dunning,
+                include_str!(concat!("templates/", $locale, "/dunning.txt.tera")),
+            ),
+            (
+                $locale,
+                EmailTemplateKind::CycleCompleted,
+                include_str!(concat!("templates/", $locale, "/cycle_completed.txt.tera")),
+            ),
+            (
+                $locale,
+                EmailTemplateKind::OutcomeReminder,
+                include_str!(concat!("templates/", $locale, "/outcome_reminder.txt.tera")),
+            ),
+            (
+                $locale,
+                EmailTemplateKind::ShareInvitation,
+                include_str!(concat!("templates/", $locale, "/share_invitation.txt.tera")),
+            ),
+            (
+                $locale,
+                EmailTemplateKind::ArchiveWarning,
+                include_str!(concat!("templates/", $locale, "/archive_warning.txt.tera")),
+            ),
+        ]
+    };
+}
+
+/// Locales with built-in templates, default first.
+const LOCALES: [&str; 3] = [DEFAULT_EMAIL_LOCALE, "es", "fr"];
+
+/// Renders the built-in email templates.
+pub struct TeraEmailTemplateRenderer {
+    tera: Tera,
+    available: HashSet<String>,
+}
+
+impl TeraEmailTemplateRenderer {
+    /// Compiles the built-in templates.
+    ///
+    /// # Panics
+    ///
+    /// If a built-in template does not parse; the tests below catch that.
+    pub fn new() -> Self {
+        let templates = locale_templates!("en")
+            .into_iter()
+            .chain(locale_templates!("es"))
+            .chain(locale_templates!("fr"));
+
+        let mut tera = Tera::default();
+        // Plain-text email; nothing to escape
+        tera.autoescape_on(vec![]);
+        let mut available = HashSet::new();
+        for (locale, kind, source) in templates {
+            let name = template_name(locale, kind);
+            tera.add_raw_template(&name, source)
+                .unwrap_or_else(|e| panic!("Built-in email template {} is invalid: {}", name, e));
+            available.insert(name);
+        }
+        Self { tera, available }
+    }
+}
+
+impl Default for TeraEmailTemplateRenderer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl EmailTemplateRenderer for TeraEmailTemplateRenderer {
+    fn render(
+        &self,
+        kind: EmailTemplateKind,
+        locale: &str,
+        context: &JsonValue,
+    ) -> Result<RenderedEmail, EmailTemplateError> {
+        let locale = locale_fallbacks(locale)
+            .into_iter()
+            .find(|candidate| self.available.contains(&template_name(candidate, kind)))
+            .unwrap_or_else(|| DEFAULT_EMAIL_LOCALE.to_string());
+
+        let context = Context::from_value(context.clone())
+            .map_err(|e| EmailTemplateError::Render(describe(&e)))?;
+        let output = self
+            .tera
+            .render(&template_name(&locale, kind), &context)
+            .map_err(|e| EmailTemplateError::Render(describe(&e)))?;
+
+        let (subject, body) = output.split_once('\n').unwrap_or((&output, ""));
+        let subject = subject.trim();
+        if subject.is_empty() {
+            return Err(EmailTemplateError::MissingSubject);
+        }
+        Ok(RenderedEmail {
+            locale,
+            subject: subject.to_string(),
+            text_body: body.trim_start_matches('\n').to_string(),
+        })
+    }
+
+    fn locales(&self) -> Vec<String> {
+        LOCALES.iter().map(|l| l.to_string()).collect()
+    }
+}
+
+fn template_name(locale: &str, kind: EmailTemplateKind) -> String {
+    format!("{}/{}", locale, kind.as_str())
+}
+
+/// Tera nests the useful message (e.g. the missing variable) in `source`.
+fn describe(error: &tera::Error) -> String {
+    let mut message = error.to_string();
+    let mut source = std::error::Error::source(error);
+    while let Some(inner) = source {
+        message = format!("{}: {}", message, inner);
+        source = inner.source();
+    }
+    message
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    /// Set to rewrite the snapshots from the current templates.
+    const UPDATE_ENV: &str = "UPDATE_EMAIL_SNAPSHOTS";
+
+    fn snapshot_path(locale: &str, kind: EmailTemplateKind) -> PathBuf {
+        PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+            .join("src/adapters/email/templates/snapshots")
+            .join(locale)
+            .join(format!("{}.txt", kind.as_str()))
+    }
+
+    #[test]
+    fn rendered_templates_match_snapshots() {
+        let renderer = TeraEmailTemplateRenderer::new();
+        let update = std::env::var_os(UPDATE_ENV).is_some();
+        let mut mismatches = Vec::new();
+
+        for locale in renderer.locales() {
+            for kind in EmailTemplateKind::ALL {
+                let email = renderer
+                    .render(kind, &locale, &kind.sample_context())
+                    .unwrap_or_else(|e| panic!("{}/{}: {}", locale, kind, e));
+                assert_eq!(email.locale, locale, "{} has no {} variant", kind, locale);
+                let rendered = format!("Subject: {}\n\n{}", email.subject, email.text_body);
+
+                let path = snapshot_path(&locale, kind);
+                if update {
+                    std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+                    std::fs::write(&path, &rendered).unwrap();
+                } else if std::fs::read_to_string(&path).ok().as_deref() != Some(&rendered) {
+                    mismatches.push(format!("{}/{}", locale, kind));
+                }
+            }
+        }
+
+        assert!(
+            mismatches.is_empty(),
+            "Rendered emails differ from snapshots: {:?}. Review the change and rerun with {}=1.",
+            mismatches,
+            UPDATE_ENV
+        );
+    }
+
+    #[test]
+    fn unknown_regions_and_locales_fall_back() {
+        let renderer = TeraEmailTemplateRenderer::new();
+        let context = EmailTemplateKind::Welcome.sample_context();
+
+        let email = renderer
+            .render(EmailTemplateKind::Welcome, "es-MX", &context)
+            .unwrap();
+        assert_eq!(email.locale, "es");
+        assert_eq!(email.subject, "Te damos la bienvenida a Choice Sherpa, Jo");
+
+        let email = renderer
+            .render(EmailTemplateKind::Welcome, "ja", &context)
+            .unwrap();
+        assert_eq!(email.locale, "en");
+    }
+
+    #[test]
+    fn missing_variables_are_reported() {
+        let renderer = TeraEmailTemplateRenderer::new();
+        let err = renderer
+            .render(
+                EmailTemplateKind::ShareInvitation,
+                "en",
+                &serde_json::json!({ "inviter_name": "Sam" }),
+            )
+            .unwrap_err();
+
+        assert!(matches!(err, EmailTemplateError::Render(ref m) if m.contains("decision_title")));
+    }
+
+    #[test]
+    fn optional_variables_can_be_omitted() {
+        let renderer = TeraEmailTemplateRenderer::new();
+        let email = renderer
+            .render(
+                EmailTemplateKind::CycleCompleted,
+                "en",
+                &serde_json::json!({}),
+            )
+            .unwrap();
+
+        assert_eq!(email.subject, "Your decision document");
+    }
+}
@@ -0,0 +1,189 @@
+//! In-memory cycle event store.
+//!
+//! For development and tests. Follows the same snapshot rules as the
+//! PostgreSQL store, but keeps events as decoded values, so nothing is
+//! upcast on read.
+
+use std::collections::{BTreeMap, HashMap};
+use std::sync::RwLock;
+
+use async_trait::async_trait;
+
+use crate::domain::cycle::{crosses_snapshot_boundary, Cycle, CycleSnapshot, RecordedCycleEvent};
+use crate::domain::foundation::{CycleId, DomainError, ErrorCode};
+use crate::ports::CycleEventStore;
+
+#[derive(Default)]
+struct CycleHistory {
+    events: BTreeMap<u64, RecordedCycleEvent>,
+    snapshots: BTreeMap<u64, CycleSnapshot>,
+}
+
+/// Cycle event store holding each cycle's history in maps keyed by version.
+#[derive(Default)]
+pub struct InMemoryCycleEventStore {
+    histories: RwLock<HashMap<CycleId, CycleHistory>>,
+}
+
+impl InMemoryCycleEventStore {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Versions of the snapshots stored for a cycle, oldest first.
+    pub fn snapshot_versions(&self, cycle_id: &CycleId) -> Vec<u64> {
+        let histories = self.histories.read().unwrap_or_else(|e| e.into_inner());
+        histories
+            .get(cycle_id)
+            .map(|h| h.snapshots.keys().copied().collect())
+            .unwrap_or_default()
+    }
+}
+
+#[async_trait]
+impl CycleEventStore for InMemoryCycleEventStore {
+    async fn append(&self, cycle: &Cycle) -> Result<(), DomainError> {
+        let pending = cycle.uncommitted_events();
+        let loaded_version = cycle.loaded_version();
+
+        let mut histories = self.histories.write().unwrap_or_else(|e| e.into_inner());
+        let history = histories.entry(cycle.id()).or_default();
+        let has_history = !history.events.is_empty() || !history.snapshots.is_empty();
+
+        if pending
+            .iter()
+            .any(|recorded| history.events.contains_key(&recorded.version))
+        {
+            return Err(DomainError::new(
+                ErrorCode::ConcurrentModification,
+                format!(
+                    "Cycle {} was modified since version {}",
+                    cycle.id(),
+                    loaded_version
+                ),
+            ));
+        }
+
+        for recorded in pending {
+            history.events.insert(recorded.version, recorded.clone());
+        }
+
+        let needs_baseline = !has_history && loaded_version > 0;
+        if needs_baseline || crosses_snapshot_boundary(loaded_version, cycle.version()) {
+            history
+                .snapshots
+                .entry(cycle.version())
+                .or_insert_with(|| cycle.snapshot());
+        }
+
+        Ok(())
+    }
+
+    async fn load_events(
+        &self,
+        cycle_id: &CycleId,
+        after_version: u64,
+        up_to_version: Option<u64>,
+    ) -> Result<Vec<RecordedCycleEvent>, DomainError> {
+        let histories = self.histories.read().unwrap_or_else(|e| e.into_inner());
+        let Some(history) = histories.get(cycle_id) else {
+            return Ok(Vec::new());
+        };
+        Ok(history
+            .events
+            .range(after_version + 1..=up_to_version.unwrap_or(u64::MAX))
+            .map(|(_, event)| event.clone())
+            .collect())
+    }
+
+    async fn latest_snapshot(
+        &self,
+        cycle_id: &CycleId,
+        at_version: Option<u64>,
+    ) -> Result<Option<CycleSnapshot>, DomainError> {
+        let histories = self.histories.read().unwrap_or_else(|e| e.into_inner());
+        Ok(histories.get(cycle_id).and_then(|history| {
+            history
+                .snapshots
+                .range(..=at_version.unwrap_or(u64::MAX))
+                .next_back()
+                .map(|(_, snapshot)| snapshot.clone())
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::cycle::SNAPSHOT_INTERVAL;
+    use crate::domain::foundation::{ComponentType, SessionId};
+
+    #[tokio::test]
+    async fn appending_stored_version_is_rejected() {
+        let store = InMemoryCycleEventStore::new();
+        let cycle = Cycle::new(SessionId::new());
+
+        store.append(&cycle).await.unwrap();
+        let err = store.append(&cycle).await.unwrap_err();
+
+        assert_eq!(err.code, ErrorCode::ConcurrentModification);
+        let events = store.load_events(&cycle.id(), 0, None).await.unwrap();
+        assert_eq!(events.len(), 1);
+        assert!(store.snapshot_versions(&cycle.id()).is_empty());
+    }
+
+    #[tokio::test]
+    async fn cycle_without_history_gets_baseline_snapshot() {
+        let store = InMemoryCycleEventStore::new();
+        let mut cycle = Cycle::new(SessionId::new());
+        cycle.start_component(ComponentType::IssueRaising).unwrap();
+        cycle.take_events();
+
+        store.append(&cycle).await.unwrap();
+
+        assert_eq!(store.snapshot_versions(&cycle.id()), vec![2]);
+    }
+
+    #[tokio::test]
+    async fn snapshots_are_taken_every_interval() {
+        let store = InMemoryCycleEventStore::new();
+        let mut cycle = Cycle::new(SessionId::new());
+        cycle.start_component(ComponentType::IssueRaising).unwrap();
+        store.append(&cycle).await.unwrap();
+
+        while cycle.version() < SNAPSHOT_INTERVAL + 1 {
+            cycle.take_events();
+            cycle.navigate_to(ComponentType::IssueRaising).unwrap();
+            store.append(&cycle).await.unwrap();
+        }
+
+        assert_eq!(
+            store.snapshot_versions(&cycle.id()),
+            vec![SNAPSHOT_INTERVAL]
+        );
+        let snapshot = store
+            .latest_snapshot(&cycle.id(), None)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(snapshot.version, SNAPSHOT_INTERVAL);
+        assert!(store
+            .latest_snapshot(&cycle.id(), Some(SNAPSHOT_INTERVAL - 1))
+            .await
+            .unwrap()
+            .is_none());
+    }
+
+    #[tokio::test]
+    async fn load_events_respects_version_bounds() {
+        let store = InMemoryCycleEventStore::new();
+        let mut cycle = Cycle::new(SessionId::new());
+        cycle.start_component(ComponentType::IssueRaising).unwrap();
+        cycle.navigate_to(ComponentType::IssueRaising).unwrap();
+        store.append(&cycle).await.unwrap();
+
+        let events = store.load_events(&cycle.id(), 1, Some(2)).await.unwrap();
+        let versions: Vec<u64> = events.iter().map(|e| e.version).collect();
+        assert_eq!(versions, vec![2]);
+    }
+}
@@ -0,0 +1,117 @@
+//! In-memory event store and projection checkpoints.
+//!
+//! For development and tests; everything is lost on restart, so projections
+//! built on these start from scratch each time.
+
+use std::collections::HashMap;
+use std::sync::RwLock;
+
+use async_trait::async_trait;
+
+use crate::domain::foundation::{DomainError, EventEnvelope};
+use crate::ports::{EventStore, ProjectionCheckpoint, ProjectionCheckpointStore, StoredEvent};
+
+/// Event store holding events in a vector.
+#[derive(Default)]
+pub struct InMemoryEventStore {
+    events: RwLock<Vec<EventEnvelope>>,
+}
+
+impl InMemoryEventStore {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait]
+impl EventStore for InMemoryEventStore {
+    async fn append(&self, event: &EventEnvelope) -> Result<u64, DomainError> {
+        let mut events = self.events.write().unwrap_or_else(|e| e.into_inner());
+        events.push(event.clone());
+        Ok(events.len() as u64)
+    }
+
+    async fn read_after(&self, position: u64, limit: u32) -> Result<Vec<StoredEvent>, DomainError> {
+        let events = self.events.read().unwrap_or_else(|e| e.into_inner());
+        Ok(events
+            .iter()
+            .enumerate()
+            .skip(position as usize)
+            .take(limit as usize)
+            .map(|(index, event)| StoredEvent {
+                position: index as u64 + 1,
+                event: event.clone(),
+            })
+            .collect())
+    }
+
+    async fn count_after(&self, position: u64, event_types: &[&str]) -> Result<u64, DomainError> {
+        let events = self.events.read().unwrap_or_else(|e| e.into_inner());
+        Ok(events
+            .iter()
+            .skip(position as usize)
+            .filter(|e| event_types.contains(&e.event_type.as_str()))
+            .count() as u64)
+    }
+
+    async fn head_position(&self) -> Result<u64, DomainError> {
+        Ok(self.events.read().unwrap_or_else(|e| e.into_inner()).len() as u64)
+    }
+}
+
+/// Projection checkpoints held in a map.
+#[derive(Default)]
+pub struct InMemoryProjectionCheckpointStore {
+    checkpoints: RwLock<HashMap<String, ProjectionCheckpoint>>,
+}
+
+impl InMemoryProjectionCheckpointStore {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait]
+impl ProjectionCheckpointStore for InMemoryProjectionCheckpointStore {
+    async fn load(&self, projection: &str) -> Result<Option<ProjectionCheckpoint>, DomainError> {
+        Ok(self
+            .checkpoints
+            .read()
+            .unwrap_or_else(|e| e.into_inner())
+            .get(projection)
+            .cloned())
+    }
+
+    async fn save(&self, checkpoint: &ProjectionCheckpoint) -> Result<(), DomainError> {
+        self.checkpoints
+            .write()
+            .unwrap_or_else(|e| e.into_inner())
+            .insert(checkpoint.projection.clone(), checkpoint.clone());
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn event(event_type: &str) -> EventEnvelope {
+        EventEnvelope::new(event_type, "agg-1", "Cycle", json!({}))
+    }
+
+    #[tokio::test]
+    async fn numbers_events_from_one_and_reads_after_a_position() {
+        let store = InMemoryEventStore::new();
+        for event_type in ["a.v1", "b.v1", "a.v1"] {
+            store.append(&event(event_type)).await.unwrap();
+        }
+
+        let after_first = store.read_after(1, 10).await.unwrap();
+        let positions: Vec<_> = after_first.iter().map(|e| e.position).collect();
+        assert_eq!(positions, vec![2, 3]);
+        assert_eq!(store.read_after(0, 1).await.unwrap().len(), 1);
+        assert_eq!(store.count_after(1, &["a.v1"]).await.unwrap(), 1);
+        assert_eq!(store.head_position().await.unwrap(), 3);
+    }
+}
@@ -3,14 +3,31 @@
 //! Adapters implement the event publishing and subscribing ports
 //! for different environments:
 //!
-//! - `InMemoryEventBus` - Synchronous, in-process bus for testing
+//! - `InMemoryEventBus` - Synchronous, in-process bus for testing (`test-support`)
 //! - `IdempotentHandler` - Wrapper for at-most-once event processing
 //! - `OutboxPublisher` - Background service for reliable event delivery
+//! - `StoringEventPublisher` - Appends each event to the `EventStore` that
+//!   projections read before publishing it
+//! - `SchemaCheckingPublisher` - Rejects events that don't match their
+//!   recorded schema
+//! - `InMemoryEventStore` / `InMemoryProjectionCheckpointStore` - Event log and
+//!   projection checkpoints for development
+//! - `InMemoryCycleEventStore` - Cycle event streams and snapshots for development
 
+#[cfg(any(test, feature = "test-support"))]
 mod in_memory;
 mod idempotent_handler;
+mod in_memory_cycle_event_store;
+mod in_memory_event_store;
 mod outbox_publisher;
+mod schema_checking_publisher;
+mod storing_publisher;
 
+#[cfg(any(test, feature = "test-support"))]
 pub use in_memory::InMemoryEventBus;
 pub use idempotent_handler::IdempotentHandler;
+pub use in_memory_cycle_event_store::InMemoryCycleEventStore;
+pub use in_memory_event_store::{InMemoryEventStore, InMemoryProjectionCheckpointStore};
 pub use outbox_publisher::{OutboxPublisher, OutboxPublisherConfig};
+pub use schema_checking_publisher::SchemaCheckingPublisher;
+pub use storing_publisher::StoringEventPublisher;
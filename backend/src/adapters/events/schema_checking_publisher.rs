@@ -0,0 +1,102 @@
+//! SchemaCheckingPublisher - Rejects events that don't match their
+//! recorded schema before publishing them.
+//!
+//! Wrap the server's publisher in this so a payload that drifts from its
+//! declared version never reaches subscribers or the event log. Batches are
+//! checked in full before any event is published.
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use crate::domain::foundation::{DomainError, ErrorCode, EventEnvelope, EventSchemaRegistry};
+use crate::ports::EventPublisher;
+
+/// Publisher decorator checking events against an `EventSchemaRegistry`.
+pub struct SchemaCheckingPublisher {
+    inner: Arc<dyn EventPublisher>,
+    schemas: Arc<EventSchemaRegistry>,
+}
+
+impl SchemaCheckingPublisher {
+    pub fn new(inner: Arc<dyn EventPublisher>, schemas: Arc<EventSchemaRegistry>) -> Self {
+        Self { inner, schemas }
+    }
+
+    fn check(&self, event: &EventEnvelope) -> Result<(), DomainError> {
+        self.schemas.validate(event).map_err(|e| {
+            tracing::error!(event_type = %event.event_type, "Rejected event: {}", e);
+            DomainError::new(ErrorCode::ValidationFailed, e.to_string())
+        })
+    }
+}
+
+#[async_trait]
+impl EventPublisher for SchemaCheckingPublisher {
+    async fn publish(&self, event: EventEnvelope) -> Result<(), DomainError> {
+        self.check(&event)?;
+        self.inner.publish(event).await
+    }
+
+    async fn publish_all(&self, events: Vec<EventEnvelope>) -> Result<(), DomainError> {
+        for event in &events {
+            self.check(event)?;
+        }
+        self.inner.publish_all(events).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::adapters::events::InMemoryEventBus;
+    use crate::domain::foundation::EventSchema;
+    use serde_json::json;
+
+    fn publisher(bus: Arc<InMemoryEventBus>) -> SchemaCheckingPublisher {
+        let mut schemas = EventSchemaRegistry::new();
+        schemas.register(
+            "test.event.v1",
+            EventSchema::infer(&json!({"test": "data"})),
+        );
+        SchemaCheckingPublisher::new(bus, Arc::new(schemas))
+    }
+
+    #[tokio::test]
+    async fn publishes_matching_events() {
+        let bus = Arc::new(InMemoryEventBus::new());
+
+        publisher(bus.clone())
+            .publish(EventEnvelope::test_fixture())
+            .await
+            .unwrap();
+
+        assert_eq!(bus.event_count(), 1);
+    }
+
+    #[tokio::test]
+    async fn rejects_mismatched_event() {
+        let bus = Arc::new(InMemoryEventBus::new());
+        let mut event = EventEnvelope::test_fixture();
+        event.payload = json!({"test": 42});
+
+        let err = publisher(bus.clone()).publish(event).await.unwrap_err();
+
+        assert_eq!(err.code, ErrorCode::ValidationFailed);
+        assert_eq!(bus.event_count(), 0);
+    }
+
+    #[tokio::test]
+    async fn rejects_whole_batch_with_one_mismatch() {
+        let bus = Arc::new(InMemoryEventBus::new());
+        let mut bad = EventEnvelope::test_fixture();
+        bad.payload = json!({});
+
+        let result = publisher(bus.clone())
+            .publish_all(vec![EventEnvelope::test_fixture(), bad])
+            .await;
+
+        assert!(result.is_err());
+        assert_eq!(bus.event_count(), 0);
+    }
+}
@@ -0,0 +1,64 @@
+//! StoringEventPublisher - Appends events to the event store before
+//! publishing them.
+//!
+//! Wrap the server's publisher in this so every event reaches the log that
+//! projections are built and rebuilt from. An event that cannot be stored is
+//! not published, so subscribers never see an event the log is missing.
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use crate::domain::foundation::{DomainError, EventEnvelope};
+use crate::ports::{EventPublisher, EventStore};
+
+/// Publisher decorator recording events in an `EventStore`.
+pub struct StoringEventPublisher {
+    inner: Arc<dyn EventPublisher>,
+    store: Arc<dyn EventStore>,
+}
+
+impl StoringEventPublisher {
+    pub fn new(inner: Arc<dyn EventPublisher>, store: Arc<dyn EventStore>) -> Self {
+        Self { inner, store }
+    }
+}
+
+#[async_trait]
+impl EventPublisher for StoringEventPublisher {
+    async fn publish(&self, event: EventEnvelope) -> Result<(), DomainError> {
+        self.store.append(&event).await?;
+        self.inner.publish(event).await
+    }
+
+    async fn publish_all(&self, events: Vec<EventEnvelope>) -> Result<(), DomainError> {
+        for event in &events {
+            self.store.append(event).await?;
+        }
+        self.inner.publish_all(events).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::adapters::events::{InMemoryEventBus, InMemoryEventStore};
+
+    #[tokio::test]
+    async fn stores_then_publishes() {
+        let bus = Arc::new(InMemoryEventBus::new());
+        let store = Arc::new(InMemoryEventStore::new());
+        let publisher = StoringEventPublisher::new(bus.clone(), store.clone());
+
+        publisher
+            .publish_all(vec![
+                EventEnvelope::test_fixture(),
+                EventEnvelope::test_fixture(),
+            ])
+            .await
+            .unwrap();
+
+        assert_eq!(store.head_position().await.unwrap(), 2);
+        assert_eq!(bus.event_count(), 2);
+    }
+}
@@ -0,0 +1,61 @@
+//! Watch channel shared by feature flag providers.
+
+use std::sync::Arc;
+
+use tokio::sync::watch;
+
+use crate::ports::FeatureFlagSet;
+
+/// Holds the latest flag snapshot and notifies subscribers on change.
+pub(crate) struct FlagChannel {
+    sender: watch::Sender<Arc<FeatureFlagSet>>,
+}
+
+impl FlagChannel {
+    pub(crate) fn new(initial: FeatureFlagSet) -> Self {
+        let (sender, _) = watch::channel(Arc::new(initial));
+        Self { sender }
+    }
+
+    pub(crate) fn current(&self) -> Arc<FeatureFlagSet> {
+        self.sender.borrow().clone()
+    }
+
+    pub(crate) fn subscribe(&self) -> watch::Receiver<Arc<FeatureFlagSet>> {
+        self.sender.subscribe()
+    }
+
+    /// Publishes `next` if it differs from the current snapshot.
+    ///
+    /// Returns true if subscribers were notified.
+    pub(crate) fn publish(&self, next: FeatureFlagSet) -> bool {
+        self.sender.send_if_modified(|current| {
+            if **current == next {
+                return false;
+            }
+            *current = Arc::new(next);
+            true
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ports::{FeatureFlag, FlagContext};
+
+    #[tokio::test]
+    async fn publish_notifies_only_on_change() {
+        let channel = FlagChannel::new(FeatureFlagSet::default());
+        let mut receiver = channel.subscribe();
+
+        let set = FeatureFlagSet::new(vec![FeatureFlag::new("a", true).unwrap()]);
+        assert!(channel.publish(set.clone()));
+        assert!(receiver.has_changed().unwrap());
+        receiver.borrow_and_update();
+
+        assert!(!channel.publish(set));
+        assert!(!receiver.has_changed().unwrap());
+        assert!(channel.current().is_enabled("a", &FlagContext::anonymous()));
+    }
+}
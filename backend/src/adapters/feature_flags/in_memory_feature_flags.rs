@@ -0,0 +1,134 @@
+//! In-memory feature flag provider for testing and development.
+
+use async_trait::async_trait;
+use std::sync::Arc;
+use tokio::sync::{watch, Mutex};
+
+use crate::ports::{FeatureFlag, FeatureFlagError, FeatureFlagProvider, FeatureFlagSet};
+
+use super::FlagChannel;
+
+/// In-memory feature flag provider.
+///
+/// Changes are visible to subscribers immediately but are lost on restart.
+pub struct InMemoryFeatureFlagProvider {
+    channel: FlagChannel,
+    write_lock: Mutex<()>,
+}
+
+impl InMemoryFeatureFlagProvider {
+    /// Create a provider with no flags.
+    pub fn new() -> Self {
+        Self::with_flags(Vec::new())
+    }
+
+    /// Create a provider seeded with flags (e.g. from boot-time configuration).
+    pub fn with_flags(flags: Vec<FeatureFlag>) -> Self {
+        Self {
+            channel: FlagChannel::new(FeatureFlagSet::new(flags)),
+            write_lock: Mutex::new(()),
+        }
+    }
+}
+
+impl Default for InMemoryFeatureFlagProvider {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl FeatureFlagProvider for InMemoryFeatureFlagProvider {
+    fn current(&self) -> Arc<FeatureFlagSet> {
+        self.channel.current()
+    }
+
+    fn subscribe(&self) -> watch::Receiver<Arc<FeatureFlagSet>> {
+        self.channel.subscribe()
+    }
+
+    async fn upsert(&self, flag: FeatureFlag) -> Result<(), FeatureFlagError> {
+        let _guard = self.write_lock.lock().await;
+        let mut flags: Vec<FeatureFlag> = self.current().flags().into_iter().cloned().collect();
+        flags.push(flag);
+        self.channel.publish(FeatureFlagSet::new(flags));
+        Ok(())
+    }
+
+    async fn remove(&self, key: &str) -> Result<(), FeatureFlagError> {
+        let _guard = self.write_lock.lock().await;
+        let current = self.current();
+        if current.get(key).is_none() {
+            return Err(FeatureFlagError::NotFound(key.to_string()));
+        }
+        let flags = current
+            .flags()
+            .into_iter()
+            .filter(|flag| flag.key != key)
+            .cloned()
+            .collect::<Vec<_>>();
+        self.channel.publish(FeatureFlagSet::new(flags));
+        Ok(())
+    }
+
+    async fn refresh(&self) -> Result<(), FeatureFlagError> {
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::membership::MembershipTier;
+    use crate::ports::FlagContext;
+
+    #[tokio::test]
+    async fn upsert_is_visible_to_subscribers() {
+        let provider = InMemoryFeatureFlagProvider::new();
+        let mut receiver = provider.subscribe();
+
+        provider
+            .upsert(FeatureFlag::new("streaming", true).unwrap())
+            .await
+            .unwrap();
+
+        receiver.changed().await.unwrap();
+        assert!(receiver.borrow().is_enabled("streaming", &FlagContext::anonymous()));
+    }
+
+    #[tokio::test]
+    async fn upsert_replaces_existing_flag() {
+        let provider =
+            InMemoryFeatureFlagProvider::with_flags(vec![FeatureFlag::new("export", false).unwrap()]);
+
+        provider
+            .upsert(
+                FeatureFlag::new("export", false)
+                    .unwrap()
+                    .with_tier_override(MembershipTier::Annual, true),
+            )
+            .await
+            .unwrap();
+
+        let ctx = FlagContext::anonymous().with_tier(MembershipTier::Annual);
+        assert_eq!(provider.current().len(), 1);
+        assert!(provider.is_enabled("export", &ctx));
+    }
+
+    #[tokio::test]
+    async fn remove_missing_flag_fails() {
+        let provider = InMemoryFeatureFlagProvider::new();
+        assert_eq!(
+            provider.remove("nope").await,
+            Err(FeatureFlagError::NotFound("nope".to_string()))
+        );
+    }
+
+    #[tokio::test]
+    async fn remove_deletes_flag() {
+        let provider =
+            InMemoryFeatureFlagProvider::with_flags(vec![FeatureFlag::new("a", true).unwrap()]);
+        provider.remove("a").await.unwrap();
+        assert!(provider.current().is_empty());
+    }
+}
@@ -0,0 +1,16 @@
+//! Feature flag adapters.
+//!
+//! Implementations of the `FeatureFlagProvider` port:
+//!
+//! - `InMemoryFeatureFlagProvider` - For tests and development
+//! - `UnleashFeatureFlagProvider` - Flags managed in Unleash, with streaming updates
+//!
+//! The Postgres-backed adapter lives in `adapters::postgres`.
+
+mod flag_channel;
+mod in_memory_feature_flags;
+mod unleash_provider;
+
+pub(crate) use flag_channel::FlagChannel;
+pub use in_memory_feature_flags::InMemoryFeatureFlagProvider;
+pub use unleash_provider::{UnleashConfig, UnleashFeatureFlagProvider, TIER_CONTEXT_FIELD};
@@ -0,0 +1,686 @@
+//! Unleash Provider - Implementation of FeatureFlagProvider backed by Unleash.
+//!
+//! Flags are managed in the Unleash UI and read through the client API.
+//! Supported activation strategies map onto the port's targeting model:
+//!
+//! | Unleash strategy | FeatureFlag |
+//! |------------------|-------------|
+//! | `default` | `enabled = true` |
+//! | `flexibleRollout` / `gradualRolloutUserId` | `enabled = true`, `rollout_percentage` |
+//! | `userWithId` | `user_overrides` |
+//! | any strategy with a `tier IN [...]` constraint | `tier_overrides` |
+//!
+//! Strategies with other constraints are skipped, so a flag never turns on
+//! for more users than Unleash would enable it for.
+//!
+//! # Configuration
+//!
+//! ```ignore
+//! let config = UnleashConfig::new("https://unleash.internal/api", token)
+//!     .with_app_name("choice-sherpa-api")
+//!     .with_fallback_values(app_config.features.fallback_values());
+//!
+//! let provider = Arc::new(UnleashFeatureFlagProvider::new(config));
+//! provider.refresh().await.ok();
+//! provider.spawn_sync();
+//! ```
+//!
+//! # Updates
+//!
+//! With streaming enabled, the provider listens on Unleash's SSE endpoint and
+//! refetches flags whenever it announces a change. If the stream drops, it
+//! polls on `refresh_interval` until the stream reconnects.
+//!
+//! # Fallbacks
+//!
+//! Fallback values (typically `FeatureFlags::fallback_values()`) are served
+//! until the first successful fetch and for any flag Unleash does not define.
+
+use std::collections::HashMap;
+use std::sync::{Arc, Weak};
+use std::time::Duration;
+
+use async_trait::async_trait;
+use futures::StreamExt;
+use reqwest::{header, Client, StatusCode};
+use secrecy::{ExposeSecret, Secret};
+use serde::Deserialize;
+use tokio::sync::{watch, Mutex};
+
+use crate::domain::foundation::Timestamp;
+use crate::domain::membership::MembershipTier;
+use crate::ports::{
+    validate_flag_key, FeatureFlag, FeatureFlagError, FeatureFlagProvider, FeatureFlagSet,
+};
+
+use super::FlagChannel;
+
+/// Unleash context field used for tier targeting.
+pub const TIER_CONTEXT_FIELD: &str = "tier";
+
+/// Delay before reconnecting a dropped stream.
+const STREAM_RECONNECT_DELAY: Duration = Duration::from_secs(5);
+
+/// Configuration for the Unleash provider.
+#[derive(Debug, Clone)]
+pub struct UnleashConfig {
+    /// Unleash API URL, e.g. "https://unleash.example.com/api".
+    pub api_url: String,
+    /// Client API token.
+    api_token: Secret<String>,
+    /// Application name reported to Unleash.
+    pub app_name: String,
+    /// Instance ID reported to Unleash.
+    pub instance_id: String,
+    /// Interval between polls when not streaming.
+    pub refresh_interval: Duration,
+    /// Whether to listen for change notifications over SSE.
+    pub streaming: bool,
+    /// Request timeout for flag fetches.
+    pub timeout: Duration,
+    /// Values served when Unleash is unreachable or a flag is undefined.
+    pub fallback: Vec<FeatureFlag>,
+}
+
+impl UnleashConfig {
+    /// Creates a new configuration with the given API URL and token.
+    pub fn new(api_url: impl Into<String>, api_token: impl Into<String>) -> Self {
+        Self {
+            api_url: api_url.into().trim_end_matches('/').to_string(),
+            api_token: Secret::new(api_token.into()),
+            app_name: "choice-sherpa".to_string(),
+            instance_id: uuid::Uuid::new_v4().to_string(),
+            refresh_interval: Duration::from_secs(15),
+            streaming: true,
+            timeout: Duration::from_secs(10),
+            fallback: Vec::new(),
+        }
+    }
+
+    /// Sets the application name.
+    pub fn with_app_name(mut self, app_name: impl Into<String>) -> Self {
+        self.app_name = app_name.into();
+        self
+    }
+
+    /// Sets the instance ID.
+    pub fn with_instance_id(mut self, instance_id: impl Into<String>) -> Self {
+        self.instance_id = instance_id.into();
+        self
+    }
+
+    /// Sets the polling interval.
+    pub fn with_refresh_interval(mut self, interval: Duration) -> Self {
+        self.refresh_interval = interval;
+        self
+    }
+
+    /// Enables or disables SSE streaming.
+    pub fn with_streaming(mut self, streaming: bool) -> Self {
+        self.streaming = streaming;
+        self
+    }
+
+    /// Sets the request timeout.
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// Sets fallback values from `(key, enabled)` pairs. Invalid keys are
+    /// skipped.
+    pub fn with_fallback_values<K: Into<String>>(
+        mut self,
+        values: impl IntoIterator<Item = (K, bool)>,
+    ) -> Self {
+        self.fallback = values
+            .into_iter()
+            .filter_map(|(key, enabled)| FeatureFlag::new(key, enabled).ok())
+            .collect();
+        self
+    }
+
+    /// Exposes the API token (for making requests).
+    fn api_token(&self) -> &str {
+        self.api_token.expose_secret()
+    }
+}
+
+/// Feature flag provider backed by Unleash.
+///
+/// Read-only: `upsert` and `remove` return `FeatureFlagError::ReadOnly`
+/// because flags are managed in Unleash.
+pub struct UnleashFeatureFlagProvider {
+    config: UnleashConfig,
+    client: Client,
+    channel: FlagChannel,
+    etag: Mutex<Option<String>>,
+}
+
+impl UnleashFeatureFlagProvider {
+    /// Creates a provider serving the configured fallback values.
+    pub fn new(config: UnleashConfig) -> Self {
+        let client = Client::builder()
+            .timeout(config.timeout)
+            .build()
+            .expect("Failed to create HTTP client");
+        let channel = FlagChannel::new(FeatureFlagSet::new(config.fallback.clone()));
+
+        Self {
+            config,
+            client,
+            channel,
+            etag: Mutex::new(None),
+        }
+    }
+
+    /// Spawn a background task that keeps flags in sync with Unleash.
+    ///
+    /// The task holds only a weak reference and exits once the provider is
+    /// dropped. Failed fetches are logged; the previous snapshot stays active.
+    pub fn spawn_sync(self: &Arc<Self>) -> tokio::task::JoinHandle<()> {
+        let provider: Weak<Self> = Arc::downgrade(self);
+        let streaming = self.config.streaming;
+        let interval = self.config.refresh_interval;
+
+        tokio::spawn(async move {
+            loop {
+                let Some(current) = provider.upgrade() else {
+                    tracing::debug!("Unleash sync stopping: provider dropped");
+                    break;
+                };
+
+                if streaming {
+                    match current.open_stream().await {
+                        Ok(response) => {
+                            drop(current);
+                            Self::follow_stream(&provider, response).await;
+                            tracing::info!("Unleash stream closed; reconnecting");
+                        }
+                        Err(e) => {
+                            tracing::warn!("Unleash stream unavailable, polling instead: {}", e);
+                            drop(current);
+                        }
+                    }
+                    if !Self::poll_once(&provider).await {
+                        break;
+                    }
+                    tokio::time::sleep(STREAM_RECONNECT_DELAY.max(interval)).await;
+                } else {
+                    drop(current);
+                    tokio::time::sleep(interval).await;
+                    if !Self::poll_once(&provider).await {
+                        break;
+                    }
+                }
+            }
+        })
+    }
+
+    /// Refreshes once. Returns false if the provider has been dropped.
+    async fn poll_once(provider: &Weak<Self>) -> bool {
+        let Some(provider) = provider.upgrade() else {
+            return false;
+        };
+        if let Err(e) = provider.refresh().await {
+            tracing::warn!("Unleash refresh failed: {}", e);
+        }
+        true
+    }
+
+    /// Reads SSE events until the stream ends, refetching on each change.
+    async fn follow_stream(provider: &Weak<Self>, response: reqwest::Response) {
+        let mut body = response.bytes_stream();
+        let mut buffer = String::new();
+
+        while let Some(chunk) = body.next().await {
+            let chunk = match chunk {
+                Ok(chunk) => chunk,
+                Err(e) => {
+                    tracing::warn!("Unleash stream error: {}", e);
+                    return;
+                }
+            };
+            buffer.push_str(&String::from_utf8_lossy(&chunk));
+
+            let events = drain_sse_events(&mut buffer);
+            if !events.iter().any(|event| is_change_event(event)) {
+                continue;
+            }
+
+            let Some(provider) = provider.upgrade() else {
+                return;
+            };
+            if let Err(e) = provider.refresh().await {
+                tracing::warn!("Unleash refresh after stream event failed: {}", e);
+            }
+        }
+    }
+
+    async fn open_stream(&self) -> Result<reqwest::Response, FeatureFlagError> {
+        // No overall timeout: the stream stays open until the server closes it
+        let response = Client::new()
+            .get(format!("{}/client/streaming", self.config.api_url))
+            .header(header::AUTHORIZATION, self.config.api_token())
+            .header(header::ACCEPT, "text/event-stream")
+            .header("UNLEASH-APPNAME", &self.config.app_name)
+            .header("UNLEASH-INSTANCEID", &self.config.instance_id)
+            .send()
+            .await
+            .map_err(|e| FeatureFlagError::Storage(format!("Unleash stream request failed: {}", e)))?;
+
+        if !response.status().is_success() {
+            return Err(FeatureFlagError::Storage(format!(
+                "Unleash stream returned {}",
+                response.status()
+            )));
+        }
+        Ok(response)
+    }
+
+    /// Fetches features, or `None` if unchanged since the last fetch.
+    async fn fetch_features(&self) -> Result<Option<UnleashFeatures>, FeatureFlagError> {
+        let mut etag = self.etag.lock().await;
+
+        let mut request = self
+            .client
+            .get(format!("{}/client/features", self.config.api_url))
+            .header(header::AUTHORIZATION, self.config.api_token())
+            .header("UNLEASH-APPNAME", &self.config.app_name)
+            .header("UNLEASH-INSTANCEID", &self.config.instance_id);
+        if let Some(etag) = etag.as_deref() {
+            request = request.header(header::IF_NONE_MATCH, etag);
+        }
+
+        let response = request
+            .send()
+            .await
+            .map_err(|e| FeatureFlagError::Storage(format!("Unleash request failed: {}", e)))?;
+
+        match response.status() {
+            StatusCode::NOT_MODIFIED => return Ok(None),
+            status if !status.is_success() => {
+                return Err(FeatureFlagError::Storage(format!(
+                    "Unleash returned {}",
+                    status
+                )))
+            }
+            _ => {}
+        }
+
+        let next_etag = response
+            .headers()
+            .get(header::ETAG)
+            .and_then(|value| value.to_str().ok())
+            .map(str::to_string);
+
+        let features = response
+            .json::<UnleashFeatures>()
+            .await
+            .map_err(|e| FeatureFlagError::Storage(format!("Invalid Unleash response: {}", e)))?;
+
+        *etag = next_etag;
+        Ok(Some(features))
+    }
+}
+
+#[async_trait]
+impl FeatureFlagProvider for UnleashFeatureFlagProvider {
+    fn current(&self) -> Arc<FeatureFlagSet> {
+        self.channel.current()
+    }
+
+    fn subscribe(&self) -> watch::Receiver<Arc<FeatureFlagSet>> {
+        self.channel.subscribe()
+    }
+
+    async fn upsert(&self, flag: FeatureFlag) -> Result<(), FeatureFlagError> {
+        Err(FeatureFlagError::ReadOnly(format!(
+            "change '{}' in Unleash",
+            flag.key
+        )))
+    }
+
+    async fn remove(&self, key: &str) -> Result<(), FeatureFlagError> {
+        Err(FeatureFlagError::ReadOnly(format!("remove '{}' in Unleash", key)))
+    }
+
+    #[tracing::instrument(name = "UnleashFeatureFlagProvider::refresh", skip_all, err)]
+    async fn refresh(&self) -> Result<(), FeatureFlagError> {
+        let Some(features) = self.fetch_features().await? else {
+            return Ok(());
+        };
+
+        let flags = merge_with_fallback(&self.config.fallback, features);
+        if self.channel.publish(flags) {
+            tracing::info!(count = self.current().len(), "Feature flags updated from Unleash");
+        }
+        Ok(())
+    }
+}
+
+impl std::fmt::Debug for UnleashFeatureFlagProvider {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("UnleashFeatureFlagProvider")
+            .field("api_url", &self.config.api_url)
+            .field("app_name", &self.config.app_name)
+            .finish_non_exhaustive()
+    }
+}
+
+// ════════════════════════════════════════════════════════════════════════════
+// Unleash client API types
+// ════════════════════════════════════════════════════════════════════════════
+
+#[derive(Debug, Deserialize)]
+struct UnleashFeatures {
+    #[serde(default)]
+    features: Vec<UnleashFeature>,
+}
+
+#[derive(Debug, Deserialize)]
+struct UnleashFeature {
+    name: String,
+    #[serde(default)]
+    description: Option<String>,
+    enabled: bool,
+    #[serde(default)]
+    strategies: Vec<UnleashStrategy>,
+}
+
+#[derive(Debug, Deserialize)]
+struct UnleashStrategy {
+    name: String,
+    #[serde(default)]
+    parameters: HashMap<String, String>,
+    #[serde(default)]
+    constraints: Vec<UnleashConstraint>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct UnleashConstraint {
+    context_name: String,
+    operator: String,
+    #[serde(default)]
+    values: Vec<String>,
+    #[serde(default)]
+    inverted: bool,
+}
+
+// ════════════════════════════════════════════════════════════════════════════
+// Conversion
+// ════════════════════════════════════════════════════════════════════════════
+
+/// Overlays Unleash flags on the fallback set.
+fn merge_with_fallback(fallback: &[FeatureFlag], features: UnleashFeatures) -> FeatureFlagSet {
+    let remote = features.features.into_iter().filter_map(|feature| {
+        let name = feature.name.clone();
+        match to_feature_flag(feature) {
+            Ok(flag) => Some(flag),
+            Err(e) => {
+                tracing::warn!(flag = %name, "Skipping Unleash flag: {}", e);
+                None
+            }
+        }
+    });
+
+    FeatureFlagSet::new(fallback.iter().cloned().chain(remote))
+}
+
+fn to_feature_flag(feature: UnleashFeature) -> Result<FeatureFlag, FeatureFlagError> {
+    validate_flag_key(&feature.name)?;
+
+    let mut flag = FeatureFlag::new(feature.name, false)?;
+    flag.description = feature.description;
+    flag.updated_at = Timestamp::now();
+
+    if !feature.enabled {
+        return Ok(flag);
+    }
+
+    // An enabled flag without strategies is on for everyone
+    if feature.strategies.is_empty() {
+        flag.enabled = true;
+        return Ok(flag);
+    }
+
+    let mut rollout: Option<u8> = None;
+    for strategy in &feature.strategies {
+        let tiers = match tier_constraint(&strategy.constraints) {
+            Some(tiers) => tiers,
+            None => {
+                tracing::debug!(
+                    flag = %flag.key,
+                    strategy = %strategy.name,
+                    "Skipping Unleash strategy with unsupported constraints"
+                );
+                continue;
+            }
+        };
+
+        match strategy.name.as_str() {
+            "default" if tiers.is_empty() => {
+                flag.enabled = true;
+                rollout = Some(100);
+            }
+            "flexibleRollout" | "gradualRolloutUserId" if tiers.is_empty() => {
+                let percentage = parse_rollout(&strategy.parameters);
+                flag.enabled = true;
+                rollout = Some(rollout.map_or(percentage, |current| current.max(percentage)));
+            }
+            "default" | "flexibleRollout" | "gradualRolloutUserId" => {
+                for tier in tiers {
+                    flag.tier_overrides.insert(tier, true);
+                }
+            }
+            "userWithId" if tiers.is_empty() => {
+                let user_ids = strategy.parameters.get("userIds").map(String::as_str).unwrap_or("");
+                for user_id in user_ids.split(',').map(str::trim).filter(|id| !id.is_empty()) {
+                    flag.user_overrides.insert(user_id.to_string(), true);
+                }
+            }
+            other => {
+                tracing::debug!(flag = %flag.key, strategy = %other, "Ignoring unsupported Unleash strategy");
+            }
+        }
+    }
+
+    if let Some(percentage) = rollout.filter(|p| *p < 100) {
+        flag = flag.with_rollout(percentage)?;
+    }
+    Ok(flag)
+}
+
+/// Returns the tiers a strategy is limited to (empty for no constraint), or
+/// `None` if it has constraints this provider cannot evaluate.
+fn tier_constraint(constraints: &[UnleashConstraint]) -> Option<Vec<MembershipTier>> {
+    match constraints {
+        [] => Some(Vec::new()),
+        [constraint]
+            if constraint.context_name == TIER_CONTEXT_FIELD
+                && constraint.operator == "IN"
+                && !constraint.inverted =>
+        {
+            constraint
+                .values
+                .iter()
+                .map(|value| serde_json::from_value(serde_json::Value::String(value.to_lowercase())).ok())
+                .collect()
+        }
+        _ => None,
+    }
+}
+
+fn parse_rollout(parameters: &HashMap<String, String>) -> u8 {
+    parameters
+        .get("rollout")
+        .or_else(|| parameters.get("percentage"))
+        .and_then(|value| value.trim().parse::<u8>().ok())
+        .map_or(0, |percentage| percentage.min(100))
+}
+
+// ════════════════════════════════════════════════════════════════════════════
+// SSE parsing
+// ════════════════════════════════════════════════════════════════════════════
+
+/// Removes complete events from `buffer`, returning their event names.
+///
+/// Events without an `event:` line are reported as "message".
+fn drain_sse_events(buffer: &mut String) -> Vec<String> {
+    let mut events = Vec::new();
+
+    while let Some(end) = buffer.find("\n\n") {
+        let block: String = buffer.drain(..end + 2).collect();
+        let mut name = None;
+        let mut has_data = false;
+        for line in block.lines() {
+            if let Some(event) = line.strip_prefix("event:") {
+                name = Some(event.trim().to_string());
+            } else if line.starts_with("data:") {
+                has_data = true;
+            }
+        }
+        if name.is_some() || has_data {
+            events.push(name.unwrap_or_else(|| "message".to_string()));
+        }
+    }
+
+    events
+}
+
+fn is_change_event(event: &str) -> bool {
+    matches!(event, "unleash-connected" | "unleash-updated" | "message")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::foundation::UserId;
+    use crate::ports::FlagContext;
+
+    fn parse(json: &str) -> UnleashFeatures {
+        serde_json::from_str(json).unwrap()
+    }
+
+    fn single(json: &str) -> FeatureFlag {
+        let set = merge_with_fallback(&[], parse(json));
+        set.flags().into_iter().next().cloned().unwrap()
+    }
+
+    fn user(id: &str) -> FlagContext {
+        FlagContext::for_user(UserId::new(id).unwrap())
+    }
+
+    #[test]
+    fn disabled_feature_is_off() {
+        let flag = single(
+            r#"{"features":[{"name":"new_agent","enabled":false,"strategies":[{"name":"default"}]}]}"#,
+        );
+        assert!(!flag.evaluate(&user("u1")));
+    }
+
+    #[test]
+    fn default_strategy_enables_for_everyone() {
+        let flag = single(
+            r#"{"features":[{"name":"new_agent","enabled":true,"strategies":[{"name":"default"}]}]}"#,
+        );
+        assert!(flag.enabled);
+        assert_eq!(flag.rollout_percentage, None);
+    }
+
+    #[test]
+    fn flexible_rollout_sets_percentage() {
+        let flag = single(
+            r#"{"features":[{"name":"new_agent","enabled":true,"strategies":[
+                {"name":"flexibleRollout","parameters":{"rollout":"25","stickiness":"userId"}}
+            ]}]}"#,
+        );
+        assert!(flag.enabled);
+        assert_eq!(flag.rollout_percentage, Some(25));
+    }
+
+    #[test]
+    fn user_with_id_becomes_user_overrides() {
+        let flag = single(
+            r#"{"features":[{"name":"new_agent","enabled":true,"strategies":[
+                {"name":"userWithId","parameters":{"userIds":"u1, u2"}}
+            ]}]}"#,
+        );
+        assert!(!flag.enabled);
+        assert!(flag.evaluate(&user("u1")));
+        assert!(flag.evaluate(&user("u2")));
+        assert!(!flag.evaluate(&user("u3")));
+    }
+
+    #[test]
+    fn tier_constraint_becomes_tier_override() {
+        let flag = single(
+            r#"{"features":[{"name":"new_agent","enabled":true,"strategies":[
+                {"name":"default","constraints":[{"contextName":"tier","operator":"IN","values":["annual"]}]}
+            ]}]}"#,
+        );
+        assert!(!flag.enabled);
+        assert_eq!(flag.tier_overrides.get(&MembershipTier::Annual), Some(&true));
+    }
+
+    #[test]
+    fn unsupported_constraints_are_skipped() {
+        let flag = single(
+            r#"{"features":[{"name":"new_agent","enabled":true,"strategies":[
+                {"name":"default","constraints":[{"contextName":"region","operator":"IN","values":["eu"]}]}
+            ]}]}"#,
+        );
+        assert!(!flag.enabled);
+        assert!(flag.tier_overrides.is_empty());
+    }
+
+    #[test]
+    fn remote_flags_override_fallback_and_keep_others() {
+        let config = UnleashConfig::new("http://unleash", "token")
+            .with_fallback_values(vec![("enable_streaming", false), ("enable_tracing", true)]);
+        let set = merge_with_fallback(
+            &config.fallback,
+            parse(r#"{"features":[{"name":"enable_streaming","enabled":true,"strategies":[]}]}"#),
+        );
+
+        let ctx = FlagContext::anonymous();
+        assert!(set.is_enabled("enable_streaming", &ctx));
+        assert!(set.is_enabled("enable_tracing", &ctx));
+    }
+
+    #[test]
+    fn invalid_remote_names_are_skipped() {
+        let set = merge_with_fallback(
+            &[],
+            parse(r#"{"features":[{"name":"Bad Name","enabled":true}]}"#),
+        );
+        assert!(set.is_empty());
+    }
+
+    #[test]
+    fn sse_events_are_drained_when_complete() {
+        let mut buffer = "event: unleash-connected\ndata: {}\n\nevent: unleash-upd".to_string();
+        assert_eq!(drain_sse_events(&mut buffer), vec!["unleash-connected"]);
+        assert_eq!(buffer, "event: unleash-upd");
+
+        buffer.push_str("ated\ndata: {}\n\n: keep-alive\n\n");
+        assert_eq!(drain_sse_events(&mut buffer), vec!["unleash-updated"]);
+        assert!(buffer.is_empty());
+    }
+
+    #[tokio::test]
+    async fn writes_are_rejected() {
+        let provider = UnleashFeatureFlagProvider::new(UnleashConfig::new("http://unleash", "token"));
+        let result = provider.upsert(FeatureFlag::new("a", true).unwrap()).await;
+        assert!(matches!(result, Err(FeatureFlagError::ReadOnly(_))));
+    }
+
+    #[test]
+    fn fallback_is_served_before_first_fetch() {
+        let config = UnleashConfig::new("http://unleash", "token")
+            .with_fallback_values(vec![("enable_streaming", true)]);
+        let provider = UnleashFeatureFlagProvider::new(config);
+        assert!(provider.is_enabled("enable_streaming", &FlagContext::anonymous()));
+    }
+}
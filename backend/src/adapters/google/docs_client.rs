@@ -0,0 +1,319 @@
+//! Google Docs API client - Implementation of GoogleDocsClient.
+//!
+//! Users authorize the `drive.file` scope, which only grants access to files
+//! this application creates. Documents are created with a Drive multipart
+//! upload of the Markdown export, converted by Drive into a native Google Doc
+//! so headings, emphasis, lists, and tables keep their formatting.
+//!
+//! # Configuration
+//!
+//! ```ignore
+//! let config = GoogleDocsConfig::new(client_id, client_secret, "https://app.example.com/integrations/google");
+//! let client = Arc::new(GoogleDocsApiClient::new(config));
+//! ```
+
+use std::time::Duration;
+
+use async_trait::async_trait;
+use reqwest::{header, Client, StatusCode, Url};
+use secrecy::{ExposeSecret, Secret};
+use serde::Deserialize;
+
+use crate::domain::foundation::Timestamp;
+use crate::ports::{GoogleDoc, GoogleDocsClient, GoogleDocsError, GoogleTokens};
+
+/// OAuth scope limited to files created by the application.
+pub const DRIVE_FILE_SCOPE: &str = "https://www.googleapis.com/auth/drive.file";
+
+const GOOGLE_DOC_MIME_TYPE: &str = "application/vnd.google-apps.document";
+const MULTIPART_BOUNDARY: &str = "choice-sherpa-google-doc";
+
+/// Google OAuth and Drive settings.
+#[derive(Debug, Clone)]
+pub struct GoogleDocsConfig {
+    pub client_id: String,
+    client_secret: Secret<String>,
+    /// Where Google sends the user back with the authorization code.
+    pub redirect_uri: String,
+    pub auth_url: String,
+    pub token_url: String,
+    pub upload_url: String,
+    /// Request timeout.
+    pub timeout: Duration,
+}
+
+impl GoogleDocsConfig {
+    pub fn new(
+        client_id: impl Into<String>,
+        client_secret: impl Into<String>,
+        redirect_uri: impl Into<String>,
+    ) -> Self {
+        Self {
+            client_id: client_id.into(),
+            client_secret: Secret::new(client_secret.into()),
+            redirect_uri: redirect_uri.into(),
+            auth_url: "https://accounts.google.com/o/oauth2/v2/auth".to_string(),
+            token_url: "https://oauth2.googleapis.com/token".to_string(),
+            upload_url: "https://www.googleapis.com/upload/drive/v3/files".to_string(),
+            timeout: Duration::from_secs(30),
+        }
+    }
+
+    /// Sets the request timeout.
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+}
+
+/// Google OAuth and Drive API client.
+pub struct GoogleDocsApiClient {
+    client: Client,
+    config: GoogleDocsConfig,
+}
+
+impl GoogleDocsApiClient {
+    pub fn new(config: GoogleDocsConfig) -> Self {
+        let client = Client::builder()
+            .timeout(config.timeout)
+            .build()
+            .expect("Failed to create HTTP client");
+        Self { client, config }
+    }
+
+    async fn request_tokens(
+        &self,
+        params: &[(&str, &str)],
+    ) -> Result<TokenResponse, GoogleDocsError> {
+        let response = self
+            .client
+            .post(&self.config.token_url)
+            .form(params)
+            .send()
+            .await
+            .map_err(|e| GoogleDocsError::Unavailable(e.to_string()))?;
+
+        let status = response.status();
+        if status == StatusCode::BAD_REQUEST || status == StatusCode::UNAUTHORIZED {
+            // invalid_grant: the code was used or the user revoked access
+            let body = response.text().await.unwrap_or_default();
+            return Err(GoogleDocsError::Unauthorized(body));
+        }
+        let response = check_status(response).await?;
+        response
+            .json()
+            .await
+            .map_err(|e| GoogleDocsError::Api(format!("Invalid token response: {}", e)))
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct TokenResponse {
+    access_token: String,
+    expires_in: u64,
+    refresh_token: Option<String>,
+}
+
+impl TokenResponse {
+    fn into_tokens(self, fallback_refresh: Option<Secret<String>>) -> GoogleTokens {
+        GoogleTokens {
+            access_token: Secret::new(self.access_token),
+            refresh_token: self.refresh_token.map(Secret::new).or(fallback_refresh),
+            expires_at: Timestamp::now().plus_secs(self.expires_in),
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct DriveFile {
+    id: String,
+    web_view_link: Option<String>,
+}
+
+impl From<DriveFile> for GoogleDoc {
+    fn from(file: DriveFile) -> Self {
+        let url = file
+            .web_view_link
+            .unwrap_or_else(|| format!("https://docs.google.com/document/d/{}/edit", file.id));
+        GoogleDoc {
+            document_id: file.id,
+            url,
+        }
+    }
+}
+
+#[async_trait]
+impl GoogleDocsClient for GoogleDocsApiClient {
+    fn authorization_url(&self, state: &str) -> String {
+        Url::parse_with_params(
+            &self.config.auth_url,
+            &[
+                ("client_id", self.config.client_id.as_str()),
+                ("redirect_uri", self.config.redirect_uri.as_str()),
+                ("response_type", "code"),
+                ("scope", DRIVE_FILE_SCOPE),
+                // Offline access with forced consent so a refresh token is issued
+                ("access_type", "offline"),
+                ("prompt", "consent"),
+                ("state", state),
+            ],
+        )
+        .map(String::from)
+        .unwrap_or_else(|_| self.config.auth_url.clone())
+    }
+
+    #[tracing::instrument(name = "GoogleDocsApiClient::exchange_code", skip_all, err)]
+    async fn exchange_code(&self, code: &str) -> Result<GoogleTokens, GoogleDocsError> {
+        let response = self
+            .request_tokens(&[
+                ("grant_type", "authorization_code"),
+                ("code", code),
+                ("client_id", &self.config.client_id),
+                ("client_secret", self.config.client_secret.expose_secret()),
+                ("redirect_uri", &self.config.redirect_uri),
+            ])
+            .await?;
+        Ok(response.into_tokens(None))
+    }
+
+    #[tracing::instrument(name = "GoogleDocsApiClient::refresh", skip_all, err)]
+    async fn refresh(&self, tokens: &GoogleTokens) -> Result<GoogleTokens, GoogleDocsError> {
+        let refresh_token = tokens.refresh_token.as_ref().ok_or_else(|| {
+            GoogleDocsError::Unauthorized("No refresh token; reconnect Google".to_string())
+        })?;
+        let response = self
+            .request_tokens(&[
+                ("grant_type", "refresh_token"),
+                ("refresh_token", refresh_token.expose_secret()),
+                ("client_id", &self.config.client_id),
+                ("client_secret", self.config.client_secret.expose_secret()),
+            ])
+            .await?;
+        Ok(response.into_tokens(tokens.refresh_token.clone()))
+    }
+
+    #[tracing::instrument(name = "GoogleDocsApiClient::create_document", skip_all, err)]
+    async fn create_document(
+        &self,
+        access_token: &Secret<String>,
+        title: &str,
+        markdown: &str,
+    ) -> Result<GoogleDoc, GoogleDocsError> {
+        let response = self
+            .client
+            .post(&self.config.upload_url)
+            .query(&[("uploadType", "multipart"), ("fields", "id,webViewLink")])
+            .bearer_auth(access_token.expose_secret())
+            .header(
+                header::CONTENT_TYPE,
+                format!("multipart/related; boundary={}", MULTIPART_BOUNDARY),
+            )
+            .body(multipart_body(title, markdown))
+            .send()
+            .await
+            .map_err(|e| GoogleDocsError::Unavailable(e.to_string()))?;
+
+        if response.status() == StatusCode::UNAUTHORIZED {
+            return Err(GoogleDocsError::Unauthorized(
+                "Google rejected the access token".to_string(),
+            ));
+        }
+        let file: DriveFile = check_status(response)
+            .await?
+            .json()
+            .await
+            .map_err(|e| GoogleDocsError::Api(format!("Invalid Drive response: {}", e)))?;
+        Ok(file.into())
+    }
+}
+
+/// Maps non-success responses to errors, treating 429 and 5xx as transient.
+async fn check_status(response: reqwest::Response) -> Result<reqwest::Response, GoogleDocsError> {
+    let status = response.status();
+    if status.is_success() {
+        return Ok(response);
+    }
+    let body = response.text().await.unwrap_or_default();
+    let message = format!("{}: {}", status, body);
+    if status == StatusCode::TOO_MANY_REQUESTS || status.is_server_error() {
+        Err(GoogleDocsError::Unavailable(message))
+    } else {
+        Err(GoogleDocsError::Api(message))
+    }
+}
+
+/// Drive `multipart/related` upload: JSON metadata requesting conversion to a
+/// Google Doc, followed by the Markdown source.
+fn multipart_body(title: &str, markdown: &str) -> String {
+    let metadata = serde_json::json!({
+        "name": title,
+        "mimeType": GOOGLE_DOC_MIME_TYPE,
+    });
+    format!(
+        "--{b}\r\nContent-Type: application/json; charset=UTF-8\r\n\r\n{metadata}\r\n\
+         --{b}\r\nContent-Type: text/markdown; charset=UTF-8\r\n\r\n{markdown}\r\n--{b}--\r\n",
+        b = MULTIPART_BOUNDARY,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn client() -> GoogleDocsApiClient {
+        GoogleDocsApiClient::new(GoogleDocsConfig::new(
+            "client-123",
+            "secret",
+            "https://app.example.com/integrations/google",
+        ))
+    }
+
+    #[test]
+    fn authorization_url_requests_offline_drive_file_access() {
+        let url = Url::parse(&client().authorization_url("abc 123")).unwrap();
+        let params: std::collections::HashMap<_, _> = url.query_pairs().into_owned().collect();
+
+        assert_eq!(url.host_str(), Some("accounts.google.com"));
+        assert_eq!(params["client_id"], "client-123");
+        assert_eq!(params["scope"], DRIVE_FILE_SCOPE);
+        assert_eq!(params["access_type"], "offline");
+        assert_eq!(params["state"], "abc 123");
+    }
+
+    #[test]
+    fn multipart_body_converts_markdown_to_a_google_doc() {
+        let body = multipart_body("Choose a \"supplier\"", "# Decision\n");
+
+        assert!(body.starts_with("--choice-sherpa-google-doc\r\n"));
+        assert!(body.contains(r#""mimeType":"application/vnd.google-apps.document""#));
+        assert!(body.contains(r#""name":"Choose a \"supplier\"""#));
+        assert!(body.contains("Content-Type: text/markdown; charset=UTF-8\r\n\r\n# Decision\n"));
+        assert!(body.ends_with("--choice-sherpa-google-doc--\r\n"));
+    }
+
+    #[test]
+    fn refreshed_tokens_keep_the_existing_refresh_token() {
+        let response: TokenResponse =
+            serde_json::from_str(r#"{"access_token":"new","expires_in":3599}"#).unwrap();
+
+        let tokens = response.into_tokens(Some(Secret::new("refresh".to_string())));
+
+        assert_eq!(tokens.access_token.expose_secret(), "new");
+        assert_eq!(
+            tokens
+                .refresh_token
+                .as_ref()
+                .map(|t| t.expose_secret().as_str()),
+            Some("refresh")
+        );
+        assert!(!tokens.is_expired(Timestamp::now()));
+    }
+
+    #[test]
+    fn falls_back_to_a_docs_link_without_web_view_link() {
+        let file: DriveFile = serde_json::from_str(r#"{"id":"doc-1"}"#).unwrap();
+        let doc = GoogleDoc::from(file);
+        assert_eq!(doc.url, "https://docs.google.com/document/d/doc-1/edit");
+    }
+}
@@ -0,0 +1,43 @@
+//! In-memory Google account store for testing and development.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use tokio::sync::RwLock;
+
+use crate::domain::foundation::{DomainError, UserId};
+use crate::ports::{GoogleAccountStore, GoogleTokens};
+
+/// Connected Google accounts keyed by user.
+#[derive(Debug, Clone, Default)]
+pub struct InMemoryGoogleAccountStore {
+    accounts: Arc<RwLock<HashMap<UserId, GoogleTokens>>>,
+}
+
+impl InMemoryGoogleAccountStore {
+    /// Create an empty store.
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait]
+impl GoogleAccountStore for InMemoryGoogleAccountStore {
+    async fn get(&self, user_id: &UserId) -> Result<Option<GoogleTokens>, DomainError> {
+        Ok(self.accounts.read().await.get(user_id).cloned())
+    }
+
+    async fn save(&self, user_id: &UserId, tokens: &GoogleTokens) -> Result<(), DomainError> {
+        self.accounts
+            .write()
+            .await
+            .insert(user_id.clone(), tokens.clone());
+        Ok(())
+    }
+
+    async fn delete(&self, user_id: &UserId) -> Result<(), DomainError> {
+        self.accounts.write().await.remove(user_id);
+        Ok(())
+    }
+}
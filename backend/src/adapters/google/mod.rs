@@ -0,0 +1,10 @@
+//! Google Workspace adapters.
+//!
+//! - `GoogleDocsApiClient` - OAuth and Drive API client that creates Google Docs
+//! - `InMemoryGoogleAccountStore` - Connected accounts held in memory
+
+mod docs_client;
+mod in_memory;
+
+pub use docs_client::{GoogleDocsApiClient, GoogleDocsConfig, DRIVE_FILE_SCOPE};
+pub use in_memory::InMemoryGoogleAccountStore;
@@ -0,0 +1,165 @@
+//! Bearer token authentication for gRPC calls.
+//!
+//! Tonic's `Interceptor` is synchronous, while `SessionValidator` is async,
+//! so instead of a tonic interceptor each service holds a `GrpcAuthenticator`
+//! and calls it first thing in every RPC. Tokens travel in the
+//! `authorization` metadata exactly as in the HTTP `Authorization` header,
+//! and go through the same validator and revocation check.
+
+use std::sync::Arc;
+
+use tonic::{Request, Status};
+
+use crate::domain::foundation::{AuthError, AuthenticatedPrincipal, AuthenticatedUser};
+use crate::ports::{is_token_revoked, SessionValidator, TokenRevocationStore};
+
+/// Validates the bearer token on incoming gRPC requests.
+#[derive(Clone)]
+pub struct GrpcAuthenticator {
+    validator: Arc<dyn SessionValidator>,
+    revocations: Option<Arc<dyn TokenRevocationStore>>,
+}
+
+impl GrpcAuthenticator {
+    pub fn new(validator: Arc<dyn SessionValidator>) -> Self {
+        Self {
+            validator,
+            revocations: None,
+        }
+    }
+
+    /// Rejects user tokens issued before the user's last logout-all.
+    pub fn with_revocation_store(mut self, store: Arc<dyn TokenRevocationStore>) -> Self {
+        self.revocations = Some(store);
+        self
+    }
+
+    /// The user the request's token belongs to.
+    ///
+    /// # Errors
+    ///
+    /// - `Unauthenticated` if the token is missing, invalid, expired or
+    ///   revoked, or belongs to a service rather than a user
+    /// - `Unavailable` if the auth provider cannot be reached
+    pub async fn authenticate<T>(&self, request: &Request<T>) -> Result<AuthenticatedUser, Status> {
+        let token =
+            bearer_token(request).ok_or_else(|| Status::unauthenticated("Missing bearer token"))?;
+
+        let principal = self
+            .validator
+            .validate_principal(token)
+            .await
+            .map_err(auth_status)?;
+        let AuthenticatedPrincipal::User(user) = principal else {
+            return Err(Status::unauthenticated(
+                "Service tokens cannot call user endpoints",
+            ));
+        };
+
+        if let Some(store) = &self.revocations {
+            let cutoff = store
+                .revoked_before(&user.id)
+                .await
+                .map_err(|e| auth_status(AuthError::service_unavailable(e.to_string())))?;
+            if is_token_revoked(user.issued_at.as_ref(), cutoff.as_ref()) {
+                return Err(auth_status(AuthError::TokenRevoked));
+            }
+        }
+
+        Ok(user)
+    }
+}
+
+/// The token from `authorization: Bearer <token>` metadata.
+fn bearer_token<T>(request: &Request<T>) -> Option<&str> {
+    request
+        .metadata()
+        .get("authorization")
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.strip_prefix("Bearer "))
+}
+
+fn auth_status(err: AuthError) -> Status {
+    match err {
+        AuthError::TokenExpired => Status::unauthenticated("Token expired"),
+        AuthError::InvalidToken => Status::unauthenticated("Invalid token"),
+        AuthError::TokenRevoked => Status::unauthenticated("Token revoked"),
+        AuthError::ServiceUnavailable(msg) => {
+            tracing::error!("Auth service unavailable: {}", msg);
+            Status::unavailable("Authentication service unavailable")
+        }
+        _ => Status::unauthenticated("Authentication failed"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::adapters::auth::MockSessionValidator;
+    use crate::domain::foundation::UserId;
+    use tonic::Code;
+
+    fn request_with(authorization: &str) -> Request<()> {
+        let mut request = Request::new(());
+        request
+            .metadata_mut()
+            .insert("authorization", authorization.parse().unwrap());
+        request
+    }
+
+    fn authenticator() -> GrpcAuthenticator {
+        let validator = MockSessionValidator::new().with_user(
+            "good-token",
+            AuthenticatedUser::new(
+                UserId::new("user-1").unwrap(),
+                "user@example.com",
+                None,
+                true,
+            ),
+        );
+        GrpcAuthenticator::new(Arc::new(validator))
+    }
+
+    #[tokio::test]
+    async fn valid_tokens_authenticate_their_user() {
+        let user = authenticator()
+            .authenticate(&request_with("Bearer good-token"))
+            .await
+            .unwrap();
+
+        assert_eq!(user.id.as_str(), "user-1");
+    }
+
+    #[tokio::test]
+    async fn missing_tokens_are_unauthenticated() {
+        let status = authenticator()
+            .authenticate(&Request::new(()))
+            .await
+            .unwrap_err();
+
+        assert_eq!(status.code(), Code::Unauthenticated);
+    }
+
+    #[tokio::test]
+    async fn unknown_tokens_are_unauthenticated() {
+        let status = authenticator()
+            .authenticate(&request_with("Bearer bad-token"))
+            .await
+            .unwrap_err();
+
+        assert_eq!(status.code(), Code::Unauthenticated);
+    }
+
+    #[test]
+    fn tokens_need_the_bearer_prefix() {
+        assert_eq!(bearer_token(&request_with("Bearer abc")), Some("abc"));
+        assert_eq!(bearer_token(&request_with("Basic abc")), None);
+    }
+
+    #[test]
+    fn unavailable_auth_maps_to_unavailable() {
+        let status = auth_status(AuthError::service_unavailable("down"));
+
+        assert_eq!(status.code(), Code::Unavailable);
+    }
+}
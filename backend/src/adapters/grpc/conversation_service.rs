@@ -0,0 +1,216 @@
+//! gRPC `ConversationService` - conversation queries and message streaming.
+//!
+//! Mirrors the conversation HTTP endpoints: ownership is checked through the
+//! component the conversation belongs to, and `SendMessage` streams the same
+//! events the SSE endpoint does.
+
+use std::pin::Pin;
+use std::sync::Arc;
+
+use futures::Stream;
+use tonic::{Request, Response, Status};
+
+use crate::adapters::http::conversation::{
+    ConversationSendMessageHandler, PaginationParams, MAX_MESSAGE_LENGTH,
+};
+use crate::application::handlers::conversation::{
+    ComponentOwnershipChecker, ConversationRecord, ConversationRepository, SendMessageCommand,
+    SendMessageError,
+};
+use crate::domain::foundation::{ComponentId, ConversationId, UserId};
+
+use super::auth::GrpcAuthenticator;
+use super::convert::{conversation_view, domain_status, message_view, parse_id, stream_event};
+use super::proto;
+use super::proto::conversation_service_server::ConversationService;
+
+/// Stream of assistant response events.
+pub type SendMessageStream = Pin<Box<dyn Stream<Item = Result<proto::StreamEvent, Status>> + Send>>;
+
+/// Serves `choice_sherpa.v1.ConversationService`.
+pub struct GrpcConversationService {
+    conversation_repo: Arc<dyn ConversationRepository>,
+    ownership_checker: Arc<dyn ComponentOwnershipChecker>,
+    send_message: Option<Arc<ConversationSendMessageHandler>>,
+    auth: GrpcAuthenticator,
+}
+
+impl GrpcConversationService {
+    pub fn new(
+        conversation_repo: Arc<dyn ConversationRepository>,
+        ownership_checker: Arc<dyn ComponentOwnershipChecker>,
+        auth: GrpcAuthenticator,
+    ) -> Self {
+        Self {
+            conversation_repo,
+            ownership_checker,
+            send_message: None,
+            auth,
+        }
+    }
+
+    /// Enables `SendMessage`; without it the RPC is unimplemented.
+    pub fn with_send_message(mut self, handler: Arc<ConversationSendMessageHandler>) -> Self {
+        self.send_message = Some(handler);
+        self
+    }
+
+    async fn authorize_component(
+        &self,
+        user_id: &UserId,
+        component_id: &ComponentId,
+    ) -> Result<(), Status> {
+        self.ownership_checker
+            .check_ownership(user_id, component_id)
+            .await
+            .map(|_| ())
+            .map_err(|e| domain_status(&e))
+    }
+
+    async fn owned_conversation(
+        &self,
+        user_id: &UserId,
+        conversation_id: &ConversationId,
+    ) -> Result<ConversationRecord, Status> {
+        let conversation = self
+            .conversation_repo
+            .find_by_id(conversation_id)
+            .await
+            .map_err(|e| domain_status(&e))?
+            .ok_or_else(|| {
+                Status::not_found(format!("Conversation not found: {}", conversation_id))
+            })?;
+        self.authorize_component(user_id, &conversation.component_id)
+            .await?;
+        Ok(conversation)
+    }
+}
+
+#[tonic::async_trait]
+impl ConversationService for GrpcConversationService {
+    type SendMessageStream = SendMessageStream;
+
+    async fn get_conversation(
+        &self,
+        request: Request<proto::GetConversationRequest>,
+    ) -> Result<Response<proto::ConversationView>, Status> {
+        let user = self.auth.authenticate(&request).await?;
+        let component_id: ComponentId = parse_id("component ID", &request.get_ref().component_id)?;
+        self.authorize_component(&user.id, &component_id).await?;
+
+        let conversation = self
+            .conversation_repo
+            .find_by_component(&component_id)
+            .await
+            .map_err(|e| domain_status(&e))?
+            .ok_or_else(|| {
+                Status::not_found(format!("Conversation not found: {}", component_id))
+            })?;
+
+        Ok(Response::new(conversation_view(&conversation)))
+    }
+
+    async fn list_messages(
+        &self,
+        request: Request<proto::ListMessagesRequest>,
+    ) -> Result<Response<proto::MessagePage>, Status> {
+        let user = self.auth.authenticate(&request).await?;
+        let body = request.get_ref();
+        let conversation_id: ConversationId = parse_id("conversation ID", &body.conversation_id)?;
+        self.owned_conversation(&user.id, &conversation_id).await?;
+
+        let params = PaginationParams {
+            offset: Some(body.offset),
+            limit: (body.limit > 0).then_some(body.limit),
+        };
+        let offset = params.effective_offset();
+        let limit = params.effective_limit();
+        let (messages, total) = self
+            .conversation_repo
+            .get_messages(&conversation_id, offset, limit)
+            .await
+            .map_err(|e| domain_status(&e))?;
+
+        Ok(Response::new(proto::MessagePage {
+            has_more: (offset + messages.len() as u32) < total,
+            items: messages.iter().map(message_view).collect(),
+            total,
+            offset,
+            limit,
+        }))
+    }
+
+    async fn send_message(
+        &self,
+        request: Request<proto::SendMessageRequest>,
+    ) -> Result<Response<Self::SendMessageStream>, Status> {
+        let user = self.auth.authenticate(&request).await?;
+        let handler = self
+            .send_message
+            .clone()
+            .ok_or_else(|| Status::unimplemented("Message streaming is not enabled"))?;
+        let body = request.into_inner();
+        let conversation_id: ConversationId = parse_id("conversation ID", &body.conversation_id)?;
+        if body.content.len() > MAX_MESSAGE_LENGTH {
+            return Err(Status::invalid_argument(
+                "Message content exceeds maximum length",
+            ));
+        }
+        let conversation = self.owned_conversation(&user.id, &conversation_id).await?;
+
+        let exchange = handler
+            .stream(SendMessageCommand::new(
+                user.id,
+                conversation.component_id,
+                body.content,
+            ))
+            .await
+            .map_err(send_status)?;
+
+        let events = futures::stream::unfold(exchange.events, |mut events| async move {
+            let event = events.recv().await?;
+            Some((Ok(stream_event(event)), events))
+        });
+        Ok(Response::new(Box::pin(events)))
+    }
+}
+
+fn send_status(err: SendMessageError) -> Status {
+    match err {
+        SendMessageError::EmptyContent => Status::invalid_argument(err.to_string()),
+        SendMessageError::ConversationComplete => Status::failed_precondition(err.to_string()),
+        SendMessageError::Forbidden => {
+            Status::permission_denied("User does not own this conversation")
+        }
+        SendMessageError::ComponentNotFound(id) => {
+            Status::not_found(format!("Component not found: {}", id))
+        }
+        SendMessageError::AIProviderError(_)
+        | SendMessageError::RepositoryError(_)
+        | SendMessageError::DomainError(_) => {
+            tracing::error!("Internal error: {}", err);
+            Status::internal("An internal error occurred")
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tonic::Code;
+
+    #[test]
+    fn complete_conversations_fail_the_precondition() {
+        let status = send_status(SendMessageError::ConversationComplete);
+
+        assert_eq!(status.code(), Code::FailedPrecondition);
+    }
+
+    #[test]
+    fn provider_errors_are_not_leaked() {
+        let status = send_status(SendMessageError::AIProviderError("key sk-123".to_string()));
+
+        assert_eq!(status.code(), Code::Internal);
+        assert!(!status.message().contains("sk-123"));
+    }
+}
@@ -0,0 +1,235 @@
+//! Conversions between protobuf messages and domain types.
+//!
+//! Enum-like values cross the wire as the snake_case names serde gives them
+//! in the JSON API, so they are converted through serde rather than with
+//! hand-written tables that could drift from the JSON names.
+
+use std::str::FromStr;
+
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use tonic::Status;
+
+use crate::application::handlers::conversation::{
+    ConversationRecord, MessageRole, StoredMessage, StreamEvent,
+};
+use crate::domain::foundation::{DomainError, ErrorCode};
+use crate::ports::{CycleView, TokenUsage};
+
+use super::proto;
+use super::proto::stream_event::Event;
+
+/// Parses an ID field, naming the field in the error.
+pub(super) fn parse_id<T: FromStr>(field: &str, value: &str) -> Result<T, Status> {
+    value
+        .parse()
+        .map_err(|_| Status::invalid_argument(format!("Invalid {} format", field)))
+}
+
+/// Parses an enum-like field from its JSON name.
+pub(super) fn parse_enum<T: DeserializeOwned>(field: &str, value: &str) -> Result<T, Status> {
+    serde_json::from_value(serde_json::Value::String(value.to_string()))
+        .map_err(|_| Status::invalid_argument(format!("Unknown {}: {}", field, value)))
+}
+
+/// The JSON name of an enum-like value.
+pub(super) fn enum_name<T: Serialize>(value: &T) -> String {
+    match serde_json::to_value(value) {
+        Ok(serde_json::Value::String(name)) => name,
+        _ => String::new(),
+    }
+}
+
+/// Maps a domain error to the gRPC status closest to its HTTP mapping.
+pub(super) fn domain_status(err: &DomainError) -> Status {
+    match err.code {
+        ErrorCode::Forbidden => Status::permission_denied(err.message.clone()),
+        ErrorCode::Unauthorized => Status::unauthenticated(err.message.clone()),
+        ErrorCode::ValidationFailed
+        | ErrorCode::EmptyField
+        | ErrorCode::OutOfRange
+        | ErrorCode::InvalidFormat => Status::invalid_argument(err.message.clone()),
+        ErrorCode::SessionNotFound
+        | ErrorCode::CycleNotFound
+        | ErrorCode::ComponentNotFound
+        | ErrorCode::ConversationNotFound
+        | ErrorCode::NotFound => Status::not_found(err.message.clone()),
+        ErrorCode::DatabaseError | ErrorCode::InternalError => {
+            tracing::error!("Internal error: {}", err);
+            Status::internal("An internal error occurred")
+        }
+        _ => Status::failed_precondition(err.message.clone()),
+    }
+}
+
+pub(super) fn cycle_view(view: CycleView) -> proto::CycleView {
+    proto::CycleView {
+        id: view.id.to_string(),
+        session_id: view.session_id.to_string(),
+        parent_cycle_id: view.parent_cycle_id.map(|id| id.to_string()),
+        branch_point: view.branch_point.as_ref().map(enum_name),
+        status: enum_name(&view.status),
+        current_step: enum_name(&view.current_step),
+        component_statuses: view
+            .component_statuses
+            .iter()
+            .map(|item| proto::ComponentStatusItem {
+                component_type: enum_name(&item.component_type),
+                status: enum_name(&item.status),
+                is_current: item.is_current,
+            })
+            .collect(),
+        progress_percent: u32::from(view.progress_percent),
+        is_complete: view.is_complete,
+        branch_count: view.branch_count,
+        created_at: view.created_at.as_datetime().to_rfc3339(),
+        updated_at: view.updated_at.as_datetime().to_rfc3339(),
+    }
+}
+
+pub(super) fn conversation_view(record: &ConversationRecord) -> proto::ConversationView {
+    proto::ConversationView {
+        id: record.id.to_string(),
+        component_id: record.component_id.to_string(),
+        component_type: enum_name(&record.component_type),
+        state: enum_name(&record.state),
+        phase: enum_name(&record.phase),
+        message_count: record.messages.len() as u32,
+        created_at: record.created_at.as_datetime().to_rfc3339(),
+        updated_at: record.updated_at.as_datetime().to_rfc3339(),
+    }
+}
+
+pub(super) fn message_view(message: &StoredMessage) -> proto::MessageView {
+    proto::MessageView {
+        id: message.id.to_string(),
+        role: match message.role {
+            MessageRole::System => "system",
+            MessageRole::User => "user",
+            MessageRole::Assistant => "assistant",
+        }
+        .to_string(),
+        content: message.content.clone(),
+        timestamp: message.created_at.as_datetime().to_rfc3339(),
+        // Only completion tokens are tracked per message
+        token_usage: message.token_count.map(|count| proto::TokenUsage {
+            prompt_tokens: 0,
+            completion_tokens: count,
+            total_tokens: count,
+            estimated_cost_cents: 0,
+        }),
+    }
+}
+
+fn token_usage(usage: &TokenUsage) -> proto::TokenUsage {
+    proto::TokenUsage {
+        prompt_tokens: usage.prompt_tokens,
+        completion_tokens: usage.completion_tokens,
+        total_tokens: usage.total_tokens,
+        estimated_cost_cents: usage.estimated_cost_cents,
+    }
+}
+
+pub(super) fn stream_event(event: StreamEvent) -> proto::StreamEvent {
+    let (message_id, event) = match event {
+        StreamEvent::Chunk { message_id, delta } => (
+            message_id,
+            Event::Chunk(proto::stream_event::Chunk { delta }),
+        ),
+        StreamEvent::ToolCall {
+            message_id,
+            name,
+            parameters,
+        } => (
+            message_id,
+            Event::ToolCall(proto::stream_event::ToolCall {
+                name,
+                parameters_json: parameters.to_string(),
+            }),
+        ),
+        StreamEvent::ToolResult {
+            message_id,
+            name,
+            response,
+            error,
+        } => (
+            message_id,
+            Event::ToolResult(proto::stream_event::ToolResult {
+                name,
+                response_json: response.and_then(|response| serde_json::to_string(&response).ok()),
+                error,
+            }),
+        ),
+        StreamEvent::Complete {
+            message_id,
+            full_content,
+            usage,
+        } => (
+            message_id,
+            Event::Complete(proto::stream_event::Complete {
+                full_content,
+                usage: usage.as_ref().map(token_usage),
+            }),
+        ),
+        StreamEvent::Error { message_id, error } => (
+            message_id,
+            Event::Error(proto::stream_event::Error { error }),
+        ),
+    };
+    proto::StreamEvent {
+        message_id: message_id.to_string(),
+        event: Some(event),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::handlers::conversation::MessageId;
+    use crate::domain::foundation::{ComponentType, CycleId};
+    use tonic::Code;
+
+    #[test]
+    fn component_types_use_their_json_names() {
+        let parsed: ComponentType = parse_enum("component type", "problem_frame").unwrap();
+
+        assert_eq!(parsed, ComponentType::ProblemFrame);
+        assert_eq!(enum_name(&parsed), "problem_frame");
+    }
+
+    #[test]
+    fn unknown_enum_names_are_invalid_arguments() {
+        let status = parse_enum::<ComponentType>("component type", "vibes").unwrap_err();
+
+        assert_eq!(status.code(), Code::InvalidArgument);
+        assert!(status.message().contains("vibes"));
+    }
+
+    #[test]
+    fn malformed_ids_name_their_field() {
+        let status = parse_id::<CycleId>("cycle ID", "not-a-uuid").unwrap_err();
+
+        assert_eq!(status.code(), Code::InvalidArgument);
+        assert_eq!(status.message(), "Invalid cycle ID format");
+    }
+
+    #[test]
+    fn forbidden_errors_are_permission_denied() {
+        let err = DomainError::new(ErrorCode::Forbidden, "not yours");
+
+        assert_eq!(domain_status(&err).code(), Code::PermissionDenied);
+    }
+
+    #[test]
+    fn stream_events_keep_their_message_id() {
+        let message_id = MessageId::new();
+
+        let event = stream_event(StreamEvent::Chunk {
+            message_id,
+            delta: "Hi".to_string(),
+        });
+
+        assert_eq!(event.message_id, message_id.to_string());
+        assert!(matches!(event.event, Some(Event::Chunk(chunk)) if chunk.delta == "Hi"));
+    }
+}
@@ -0,0 +1,353 @@
+//! gRPC `CycleService` - cycle commands and queries.
+//!
+//! Each RPC authenticates the caller, checks they own the session the cycle
+//! belongs to, then hands off to the same application handler the HTTP API
+//! uses.
+
+use std::sync::Arc;
+
+use tonic::{Request, Response, Status};
+
+use crate::application::handlers::cycle::{
+    BranchCycleCommand, BranchCycleError, BranchCycleHandler, CompleteComponentCommand,
+    CompleteComponentError, CompleteComponentHandler, CreateCycleCommand, CreateCycleError,
+    CreateCycleHandler, GetCycleHandler, GetCycleQuery, NavigateToComponentCommand,
+    NavigateToComponentError, NavigateToComponentHandler, StartComponentCommand,
+    StartComponentError, StartComponentHandler, UpdateComponentOutputCommand,
+    UpdateComponentOutputError, UpdateComponentOutputHandler,
+};
+use crate::domain::foundation::{
+    CommandMetadata, ComponentType, CycleId, EventId, SessionId, UserId,
+};
+use crate::ports::{
+    AccessChecker, CycleReader, CycleRepository, EventPublisher, SessionRepository,
+};
+
+use super::auth::GrpcAuthenticator;
+use super::convert::{cycle_view, domain_status, parse_enum, parse_id};
+use super::proto;
+use super::proto::cycle_service_server::CycleService;
+
+/// Serves `choice_sherpa.v1.CycleService`.
+pub struct GrpcCycleService {
+    cycle_repository: Arc<dyn CycleRepository>,
+    cycle_reader: Arc<dyn CycleReader>,
+    session_repository: Arc<dyn SessionRepository>,
+    access_checker: Arc<dyn AccessChecker>,
+    event_publisher: Arc<dyn EventPublisher>,
+    auth: GrpcAuthenticator,
+}
+
+impl GrpcCycleService {
+    pub fn new(
+        cycle_repository: Arc<dyn CycleRepository>,
+        cycle_reader: Arc<dyn CycleReader>,
+        session_repository: Arc<dyn SessionRepository>,
+        access_checker: Arc<dyn AccessChecker>,
+        event_publisher: Arc<dyn EventPublisher>,
+        auth: GrpcAuthenticator,
+    ) -> Self {
+        Self {
+            cycle_repository,
+            cycle_reader,
+            session_repository,
+            access_checker,
+            event_publisher,
+            auth,
+        }
+    }
+
+    /// Checks `user_id` owns the session.
+    async fn authorize_session(
+        &self,
+        user_id: &UserId,
+        session_id: &SessionId,
+    ) -> Result<(), Status> {
+        let session = self
+            .session_repository
+            .find_by_id(session_id)
+            .await
+            .map_err(|e| domain_status(&e))?
+            .ok_or_else(|| Status::not_found(format!("Session not found: {}", session_id)))?;
+        session.authorize(user_id).map_err(|e| domain_status(&e))
+    }
+
+    /// Checks `user_id` owns the session the cycle belongs to.
+    async fn authorize_cycle(&self, user_id: &UserId, cycle_id: &CycleId) -> Result<(), Status> {
+        let cycle = self
+            .cycle_repository
+            .find_by_id(cycle_id)
+            .await
+            .map_err(|e| domain_status(&e))?
+            .ok_or_else(|| cycle_not_found(cycle_id))?;
+        self.authorize_session(user_id, &cycle.session_id()).await
+    }
+
+    /// Authenticates the caller and checks they own the request's cycle,
+    /// returning the metadata to run the command under.
+    async fn component_command<T>(
+        &self,
+        request: &Request<T>,
+        cycle_id: &str,
+        component_type: &str,
+    ) -> Result<(CommandMetadata, CycleId, ComponentType), Status> {
+        let user = self.auth.authenticate(request).await?;
+        let cycle_id: CycleId = parse_id("cycle ID", cycle_id)?;
+        let component_type: ComponentType = parse_enum("component type", component_type)?;
+        self.authorize_cycle(&user.id, &cycle_id).await?;
+        Ok((CommandMetadata::new(user.id), cycle_id, component_type))
+    }
+}
+
+#[tonic::async_trait]
+impl CycleService for GrpcCycleService {
+    async fn create_cycle(
+        &self,
+        request: Request<proto::CreateCycleRequest>,
+    ) -> Result<Response<proto::CycleCommandResponse>, Status> {
+        let user = self.auth.authenticate(&request).await?;
+        let session_id: SessionId = parse_id("session ID", &request.get_ref().session_id)?;
+        self.authorize_session(&user.id, &session_id).await?;
+
+        let result = CreateCycleHandler::new(
+            self.cycle_repository.clone(),
+            self.session_repository.clone(),
+            self.access_checker.clone(),
+            self.event_publisher.clone(),
+        )
+        .handle(
+            CreateCycleCommand { session_id },
+            CommandMetadata::new(user.id),
+        )
+        .await
+        .map_err(|err| match err {
+            CreateCycleError::SessionNotFound(id) => {
+                Status::not_found(format!("Session not found: {}", id))
+            }
+            CreateCycleError::AccessDenied(reason) => {
+                Status::permission_denied(format!("Access denied: {:?}", reason))
+            }
+            CreateCycleError::Domain(e) => domain_status(&e),
+        })?;
+
+        Ok(command_response(
+            result.cycle.id(),
+            "Cycle created successfully".to_string(),
+            result.version,
+            &result.event_ids,
+        ))
+    }
+
+    async fn branch_cycle(
+        &self,
+        request: Request<proto::BranchCycleRequest>,
+    ) -> Result<Response<proto::CycleCommandResponse>, Status> {
+        let user = self.auth.authenticate(&request).await?;
+        let body = request.into_inner();
+        let cycle_id: CycleId = parse_id("cycle ID", &body.cycle_id)?;
+        let branch_point: ComponentType = parse_enum("branch point", &body.branch_point)?;
+        self.authorize_cycle(&user.id, &cycle_id).await?;
+
+        let result = BranchCycleHandler::new(
+            self.cycle_repository.clone(),
+            self.access_checker.clone(),
+            self.event_publisher.clone(),
+        )
+        .handle(
+            BranchCycleCommand {
+                parent_cycle_id: cycle_id,
+                branch_point,
+                branch_label: body.branch_label,
+            },
+            CommandMetadata::new(user.id),
+        )
+        .await
+        .map_err(|err| match err {
+            BranchCycleError::CycleNotFound(id) => cycle_not_found(&id),
+            BranchCycleError::AccessDenied(reason) => {
+                Status::permission_denied(format!("Access denied: {:?}", reason))
+            }
+            BranchCycleError::Domain(e) => domain_status(&e),
+        })?;
+
+        Ok(command_response(
+            result.branch.id(),
+            format!("Branched at {:?}", result.event.branch_point),
+            result.version,
+            &result.event_ids,
+        ))
+    }
+
+    async fn get_cycle(
+        &self,
+        request: Request<proto::GetCycleRequest>,
+    ) -> Result<Response<proto::CycleView>, Status> {
+        let user = self.auth.authenticate(&request).await?;
+        let cycle_id: CycleId = parse_id("cycle ID", &request.get_ref().cycle_id)?;
+
+        let view = GetCycleHandler::new(self.cycle_reader.clone())
+            .handle(GetCycleQuery { cycle_id })
+            .await
+            .map_err(|e| domain_status(&e))?
+            .ok_or_else(|| cycle_not_found(&cycle_id))?;
+        self.authorize_session(&user.id, &view.session_id).await?;
+
+        Ok(Response::new(cycle_view(view)))
+    }
+
+    async fn start_component(
+        &self,
+        request: Request<proto::ComponentCommandRequest>,
+    ) -> Result<Response<proto::CycleCommandResponse>, Status> {
+        let body = request.get_ref();
+        let (metadata, cycle_id, component_type) = self
+            .component_command(&request, &body.cycle_id, &body.component_type)
+            .await?;
+
+        let result =
+            StartComponentHandler::new(self.cycle_repository.clone(), self.event_publisher.clone())
+                .handle(
+                    StartComponentCommand {
+                        cycle_id,
+                        component_type,
+                    },
+                    metadata,
+                )
+                .await
+                .map_err(|err| match err {
+                    StartComponentError::CycleNotFound(id) => cycle_not_found(&id),
+                    StartComponentError::Domain(e) => domain_status(&e),
+                })?;
+
+        Ok(command_response(
+            cycle_id,
+            format!("Started {}", component_type),
+            result.version,
+            &result.event_ids,
+        ))
+    }
+
+    async fn complete_component(
+        &self,
+        request: Request<proto::ComponentCommandRequest>,
+    ) -> Result<Response<proto::CycleCommandResponse>, Status> {
+        let body = request.get_ref();
+        let (metadata, cycle_id, component_type) = self
+            .component_command(&request, &body.cycle_id, &body.component_type)
+            .await?;
+
+        let result = CompleteComponentHandler::new(
+            self.cycle_repository.clone(),
+            self.event_publisher.clone(),
+        )
+        .handle(
+            CompleteComponentCommand {
+                cycle_id,
+                component_type,
+            },
+            metadata,
+        )
+        .await
+        .map_err(|err| match err {
+            CompleteComponentError::CycleNotFound(id) => cycle_not_found(&id),
+            CompleteComponentError::Domain(e) => domain_status(&e),
+        })?;
+
+        Ok(command_response(
+            cycle_id,
+            format!("Completed {}", component_type),
+            result.version,
+            &result.event_ids,
+        ))
+    }
+
+    async fn navigate_to_component(
+        &self,
+        request: Request<proto::ComponentCommandRequest>,
+    ) -> Result<Response<proto::CycleCommandResponse>, Status> {
+        let body = request.get_ref();
+        let (metadata, cycle_id, component_type) = self
+            .component_command(&request, &body.cycle_id, &body.component_type)
+            .await?;
+
+        let result = NavigateToComponentHandler::new(
+            self.cycle_repository.clone(),
+            self.event_publisher.clone(),
+        )
+        .handle(
+            NavigateToComponentCommand {
+                cycle_id,
+                component_type,
+            },
+            metadata,
+        )
+        .await
+        .map_err(|err| match err {
+            NavigateToComponentError::CycleNotFound(id) => cycle_not_found(&id),
+            NavigateToComponentError::Domain(e) => domain_status(&e),
+        })?;
+
+        Ok(command_response(
+            cycle_id,
+            format!("Navigated to {}", component_type),
+            result.version,
+            &result.event_ids,
+        ))
+    }
+
+    async fn update_component_output(
+        &self,
+        request: Request<proto::UpdateComponentOutputRequest>,
+    ) -> Result<Response<proto::CycleCommandResponse>, Status> {
+        let body = request.get_ref();
+        let (metadata, cycle_id, component_type) = self
+            .component_command(&request, &body.cycle_id, &body.component_type)
+            .await?;
+        let output = serde_json::from_str(&body.output_json).map_err(|e| {
+            Status::invalid_argument(format!("Component output is not valid JSON: {}", e))
+        })?;
+
+        let result = UpdateComponentOutputHandler::new(
+            self.cycle_repository.clone(),
+            self.event_publisher.clone(),
+        )
+        .handle(
+            UpdateComponentOutputCommand {
+                cycle_id,
+                component_type,
+                output,
+            },
+            metadata,
+        )
+        .await
+        .map_err(|err| match err {
+            UpdateComponentOutputError::CycleNotFound(id) => cycle_not_found(&id),
+            UpdateComponentOutputError::Domain(e) => domain_status(&e),
+        })?;
+
+        Ok(command_response(
+            cycle_id,
+            format!("Updated {} output", component_type),
+            result.version,
+            &result.event_ids,
+        ))
+    }
+}
+
+fn cycle_not_found(cycle_id: &CycleId) -> Status {
+    Status::not_found(format!("Cycle not found: {}", cycle_id))
+}
+
+fn command_response(
+    cycle_id: CycleId,
+    message: String,
+    version: u64,
+    event_ids: &[EventId],
+) -> Response<proto::CycleCommandResponse> {
+    Response::new(proto::CycleCommandResponse {
+        cycle_id: cycle_id.to_string(),
+        message,
+        version,
+        event_ids: event_ids.iter().map(ToString::to_string).collect(),
+    })
+}
@@ -0,0 +1,44 @@
+//! gRPC adapters - Cycle and conversation services for internal consumers.
+//!
+//! Enabled by the `grpc` cargo feature. Service code is generated at build
+//! time from `proto/choice_sherpa/v1/choice_sherpa.proto`, whose messages
+//! mirror the HTTP DTOs, and the services delegate to the same application
+//! handlers as the HTTP API. Callers authenticate with the same bearer
+//! tokens, validated through `SessionValidator` by [`GrpcAuthenticator`].
+//!
+//! # Services
+//!
+//! - `CycleService` - Create, branch and read cycles; start, complete,
+//!   navigate to and update components
+//! - `ConversationService` - Read conversations and messages; send a
+//!   message and stream the response
+//!
+//! Serve them with `tonic::transport::Server`:
+//!
+//! ```ignore
+//! Server::builder()
+//!     .add_service(CycleServiceServer::new(cycle_service))
+//!     .add_service(ConversationServiceServer::new(conversation_service))
+//!     .serve(addr)
+//!     .await?;
+//! ```
+
+// `tonic::Status` is the error type every generated service method returns
+#![allow(clippy::result_large_err)]
+
+mod auth;
+mod conversation_service;
+mod convert;
+mod cycle_service;
+
+/// Code generated from the protobuf definitions, including clients.
+#[allow(clippy::all)]
+pub mod proto {
+    tonic::include_proto!("choice_sherpa.v1");
+}
+
+pub use auth::GrpcAuthenticator;
+pub use conversation_service::{GrpcConversationService, SendMessageStream};
+pub use cycle_service::GrpcCycleService;
+pub use proto::conversation_service_server::ConversationServiceServer;
+pub use proto::cycle_service_server::CycleServiceServer;
@@ -0,0 +1,201 @@
+//! In-memory help content repository for testing and development.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use tokio::sync::RwLock;
+
+use crate::ports::{
+    validate_help_id, HelpArticle, HelpArticleKind, HelpContentError, HelpContentRepository,
+};
+
+/// Versions of each article, keyed by ID and locale, oldest first.
+type ArticleVersions = HashMap<(String, String), Vec<HelpArticle>>;
+
+/// Every version of every article held in memory.
+#[derive(Debug, Clone, Default)]
+pub struct InMemoryHelpContentRepository {
+    articles: Arc<RwLock<ArticleVersions>>,
+}
+
+impl InMemoryHelpContentRepository {
+    /// Create an empty repository.
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait]
+impl HelpContentRepository for InMemoryHelpContentRepository {
+    async fn get(&self, id: &str, locale: &str) -> Result<Option<HelpArticle>, HelpContentError> {
+        Ok(self
+            .articles
+            .read()
+            .await
+            .get(&(id.to_string(), locale.to_string()))
+            .and_then(|versions| versions.last().cloned()))
+    }
+
+    async fn list(
+        &self,
+        locale: &str,
+        kind: Option<HelpArticleKind>,
+    ) -> Result<Vec<HelpArticle>, HelpContentError> {
+        let mut articles: Vec<_> = self
+            .articles
+            .read()
+            .await
+            .iter()
+            .filter(|((_, article_locale), _)| article_locale == locale)
+            .filter_map(|(_, versions)| versions.last().cloned())
+            .filter(|article| kind.is_none_or(|kind| article.kind == kind))
+            .collect();
+        articles.sort_by(|a, b| a.id.cmp(&b.id));
+        Ok(articles)
+    }
+
+    async fn save(
+        &self,
+        mut article: HelpArticle,
+        expected_version: u32,
+    ) -> Result<HelpArticle, HelpContentError> {
+        validate_help_id(&article.id)?;
+
+        let mut articles = self.articles.write().await;
+        let versions = articles
+            .entry((article.id.clone(), article.locale.clone()))
+            .or_default();
+        let current = versions.last().map_or(0, |latest| latest.version);
+        if current != expected_version {
+            return Err(HelpContentError::VersionConflict {
+                expected: expected_version,
+                actual: current,
+            });
+        }
+        article.version = current + 1;
+        versions.push(article.clone());
+        Ok(article)
+    }
+
+    async fn history(&self, id: &str, locale: &str) -> Result<Vec<HelpArticle>, HelpContentError> {
+        Ok(self
+            .articles
+            .read()
+            .await
+            .get(&(id.to_string(), locale.to_string()))
+            .map(|versions| versions.iter().rev().cloned().collect())
+            .unwrap_or_default())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::foundation::Timestamp;
+
+    fn article(id: &str, locale: &str, title: &str) -> HelpArticle {
+        HelpArticle {
+            id: id.to_string(),
+            locale: locale.to_string(),
+            version: 0,
+            kind: HelpArticleKind::Glossary,
+            component_type: None,
+            title: title.to_string(),
+            summary: "Summary".to_string(),
+            body: "Body".to_string(),
+            updated_by: None,
+            updated_at: Timestamp::now(),
+        }
+    }
+
+    #[tokio::test]
+    async fn edits_become_new_versions() {
+        let repo = InMemoryHelpContentRepository::new();
+        let id = "glossary.swing-weight";
+
+        let first = repo
+            .save(article(id, "en", "Swing weight"), 0)
+            .await
+            .unwrap();
+        let second = repo
+            .save(article(id, "en", "Swing weighting"), 1)
+            .await
+            .unwrap();
+
+        assert_eq!((first.version, second.version), (1, 2));
+        assert_eq!(
+            repo.get(id, "en").await.unwrap().unwrap().title,
+            "Swing weighting"
+        );
+        let versions: Vec<_> = repo
+            .history(id, "en")
+            .await
+            .unwrap()
+            .iter()
+            .map(|a| a.version)
+            .collect();
+        assert_eq!(versions, vec![2, 1]);
+    }
+
+    #[tokio::test]
+    async fn stale_edit_is_rejected() {
+        let repo = InMemoryHelpContentRepository::new();
+        let id = "glossary.swing-weight";
+        repo.save(article(id, "en", "One"), 0).await.unwrap();
+        repo.save(article(id, "en", "Two"), 1).await.unwrap();
+
+        let result = repo.save(article(id, "en", "Stale"), 1).await;
+
+        assert_eq!(
+            result,
+            Err(HelpContentError::VersionConflict {
+                expected: 1,
+                actual: 2
+            })
+        );
+    }
+
+    #[tokio::test]
+    async fn localized_lookup_falls_back_to_language_then_default() {
+        let repo = InMemoryHelpContentRepository::new();
+        let id = "concept.proact";
+        repo.save(article(id, "en", "PrOACT"), 0).await.unwrap();
+        repo.save(article(id, "pt", "PrOACT (pt)"), 0)
+            .await
+            .unwrap();
+
+        let pt_br = repo.get_localized(id, "pt-BR").await.unwrap().unwrap();
+        let fr = repo.get_localized(id, "fr").await.unwrap().unwrap();
+
+        assert_eq!(pt_br.locale, "pt");
+        assert_eq!(fr.locale, "en");
+    }
+
+    #[tokio::test]
+    async fn list_filters_by_locale_and_kind() {
+        let repo = InMemoryHelpContentRepository::new();
+        repo.save(article("glossary.b", "en", "B"), 0)
+            .await
+            .unwrap();
+        repo.save(article("glossary.a", "en", "A"), 0)
+            .await
+            .unwrap();
+        repo.save(article("glossary.a", "es", "A (es)"), 0)
+            .await
+            .unwrap();
+
+        let english = repo
+            .list("en", Some(HelpArticleKind::Glossary))
+            .await
+            .unwrap();
+        let concepts = repo
+            .list("en", Some(HelpArticleKind::Concept))
+            .await
+            .unwrap();
+
+        let ids: Vec<_> = english.iter().map(|a| a.id.as_str()).collect();
+        assert_eq!(ids, vec!["glossary.a", "glossary.b"]);
+        assert!(concepts.is_empty());
+    }
+}
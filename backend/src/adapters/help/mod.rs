@@ -0,0 +1,7 @@
+//! Help content adapters.
+//!
+//! - `InMemoryHelpContentRepository` - Versioned help articles held in memory
+
+mod in_memory;
+
+pub use in_memory::InMemoryHelpContentRepository;
@@ -47,6 +47,8 @@ pub struct SendMessageResponse {
     pub response: String,
     pub current_step: ComponentType,
     pub turn_count: u32,
+    /// Help article IDs the response links with `[[help:<id>]]` markers.
+    pub help_refs: Vec<String>,
 }
 
 /// Response for getting conversation state
@@ -15,7 +15,7 @@ use crate::application::handlers::ai_engine::{
     StartConversationError, StartConversationHandler,
 };
 use crate::domain::foundation::{ComponentType, CycleId, SessionId};
-use crate::ports::{AIProvider, StateStorage};
+use crate::ports::{help_references, AIProvider, StateStorage};
 use std::str::FromStr;
 
 use super::dto::{
@@ -186,6 +186,7 @@ pub async fn send_message(
 
     // Build response
     let response = SendMessageResponse {
+        help_refs: help_references(&result.ai_response),
         response: result.ai_response,
         current_step: result.updated_state.current_step,
         turn_count,
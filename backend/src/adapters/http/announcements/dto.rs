@@ -0,0 +1,142 @@
+//! HTTP DTOs for announcement endpoints.
+
+use serde::{Deserialize, Serialize};
+
+use crate::adapters::websocket::{
+    Announcement, AnnouncementAudience, AnnouncementKind, AnnouncementStatus,
+};
+use crate::domain::foundation::Timestamp;
+use crate::domain::membership::MembershipTier;
+
+// ════════════════════════════════════════════════════════════════════════════
+// Request DTOs
+// ════════════════════════════════════════════════════════════════════════════
+
+/// Request to publish an announcement.
+#[derive(Debug, Clone, Deserialize)]
+pub struct CreateAnnouncementRequest {
+    pub kind: AnnouncementKind,
+    pub title: String,
+    pub body: String,
+    /// Only users on these tiers see it. Omit for everyone.
+    #[serde(default)]
+    pub tiers: Option<Vec<MembershipTier>>,
+    /// When to deliver it. Omit to deliver now.
+    #[serde(default)]
+    pub starts_at: Option<Timestamp>,
+    #[serde(default)]
+    pub expires_at: Option<Timestamp>,
+}
+
+// ════════════════════════════════════════════════════════════════════════════
+// Response DTOs
+// ════════════════════════════════════════════════════════════════════════════
+
+/// An announcement (admin view).
+#[derive(Debug, Clone, Serialize)]
+pub struct AnnouncementResponse {
+    pub id: String,
+    pub kind: AnnouncementKind,
+    pub title: String,
+    pub body: String,
+    /// `None` when it is for everyone.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tiers: Option<Vec<MembershipTier>>,
+    pub starts_at: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expires_at: Option<String>,
+    /// "scheduled", "live", or "expired".
+    pub status: String,
+    pub created_by: String,
+    pub created_at: String,
+}
+
+impl From<&Announcement> for AnnouncementResponse {
+    fn from(announcement: &Announcement) -> Self {
+        let status = match announcement.status(&Timestamp::now()) {
+            AnnouncementStatus::Scheduled => "scheduled",
+            AnnouncementStatus::Live => "live",
+            AnnouncementStatus::Expired => "expired",
+        };
+        Self {
+            id: announcement.id.to_string(),
+            kind: announcement.kind,
+            title: announcement.title.clone(),
+            body: announcement.body.clone(),
+            tiers: match &announcement.audience {
+                AnnouncementAudience::All => None,
+                AnnouncementAudience::Tiers(tiers) => Some(tiers.clone()),
+            },
+            starts_at: announcement.starts_at.as_datetime().to_rfc3339(),
+            expires_at: announcement
+                .expires_at
+                .map(|t| t.as_datetime().to_rfc3339()),
+            status: status.to_string(),
+            created_by: announcement.created_by.to_string(),
+            created_at: announcement.created_at.as_datetime().to_rfc3339(),
+        }
+    }
+}
+
+/// Standard error response.
+#[derive(Debug, Clone, Serialize)]
+pub struct ErrorResponse {
+    pub code: String,
+    pub message: String,
+}
+
+impl ErrorResponse {
+    pub fn bad_request(message: impl Into<String>) -> Self {
+        Self {
+            code: "BAD_REQUEST".to_string(),
+            message: message.into(),
+        }
+    }
+
+    pub fn not_found(resource_type: &str, id: &str) -> Self {
+        Self {
+            code: "NOT_FOUND".to_string(),
+            message: format!("{} not found: {}", resource_type, id),
+        }
+    }
+
+    pub fn forbidden(message: impl Into<String>) -> Self {
+        Self {
+            code: "FORBIDDEN".to_string(),
+            message: message.into(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::foundation::UserId;
+
+    #[test]
+    fn create_request_defaults_to_everyone_now() {
+        let req: CreateAnnouncementRequest = serde_json::from_str(
+            r#"{"kind":"feature","title":"New export","body":"Try PDF export."}"#,
+        )
+        .unwrap();
+        assert_eq!(req.kind, AnnouncementKind::Feature);
+        assert!(req.tiers.is_none());
+        assert!(req.starts_at.is_none());
+    }
+
+    #[test]
+    fn response_reports_tiers_and_status() {
+        let announcement = Announcement::new(
+            AnnouncementKind::Maintenance,
+            "Maintenance",
+            "Down at 02:00 UTC.",
+            UserId::new("admin-1").unwrap(),
+        )
+        .for_tiers(vec![MembershipTier::Annual])
+        .starting_at(Timestamp::now().plus_days(1));
+
+        let json = serde_json::to_value(AnnouncementResponse::from(&announcement)).unwrap();
+        assert_eq!(json["tiers"], serde_json::json!(["annual"]));
+        assert_eq!(json["status"], "scheduled");
+    }
+}
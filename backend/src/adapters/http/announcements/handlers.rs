@@ -0,0 +1,139 @@
+//! HTTP handlers for announcement endpoints.
+
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    Json,
+};
+use uuid::Uuid;
+
+use crate::adapters::http::middleware::RequireAuth;
+use crate::adapters::websocket::{Announcement, AnnouncementBoard, AnnouncementError};
+use crate::domain::foundation::UserId;
+
+use super::dto::{AnnouncementResponse, CreateAnnouncementRequest, ErrorResponse};
+
+// ════════════════════════════════════════════════════════════════════════════
+// Application State
+// ════════════════════════════════════════════════════════════════════════════
+
+/// Shared state for announcement endpoints.
+#[derive(Clone)]
+pub struct AnnouncementsAppState {
+    pub board: AnnouncementBoard,
+    /// Users allowed to publish announcements.
+    pub admin_user_ids: Arc<HashSet<UserId>>,
+}
+
+impl AnnouncementsAppState {
+    fn require_admin(&self, user_id: &UserId) -> Result<(), Box<Response>> {
+        if self.admin_user_ids.contains(user_id) {
+            return Ok(());
+        }
+        Err(Box::new((
+            StatusCode::FORBIDDEN,
+            Json(ErrorResponse::forbidden("Admin access required")),
+        )
+            .into_response()))
+    }
+}
+
+// ════════════════════════════════════════════════════════════════════════════
+// HTTP handlers
+// ════════════════════════════════════════════════════════════════════════════
+
+/// GET /api/admin/announcements - Scheduled and live announcements
+pub async fn list_announcements(
+    State(state): State<AnnouncementsAppState>,
+    RequireAuth(user): RequireAuth,
+) -> Response {
+    if let Err(rejection) = state.require_admin(&user.id) {
+        return *rejection;
+    }
+
+    let response: Vec<AnnouncementResponse> =
+        state.board.list().await.iter().map(Into::into).collect();
+    (StatusCode::OK, Json(response)).into_response()
+}
+
+/// POST /api/admin/announcements - Publish or schedule an announcement
+pub async fn create_announcement(
+    State(state): State<AnnouncementsAppState>,
+    RequireAuth(user): RequireAuth,
+    Json(req): Json<CreateAnnouncementRequest>,
+) -> Response {
+    if let Err(rejection) = state.require_admin(&user.id) {
+        return *rejection;
+    }
+
+    let mut announcement = Announcement::new(req.kind, req.title, req.body, user.id);
+    if let Some(tiers) = req.tiers {
+        announcement = announcement.for_tiers(tiers);
+    }
+    if let Some(starts_at) = req.starts_at {
+        announcement = announcement.starting_at(starts_at);
+    }
+    if let Some(expires_at) = req.expires_at {
+        announcement = announcement.expiring_at(expires_at);
+    }
+
+    let response = AnnouncementResponse::from(&announcement);
+    match state.board.publish(announcement).await {
+        Ok(()) => (StatusCode::CREATED, Json(response)).into_response(),
+        Err(e) => handle_announcement_error(e),
+    }
+}
+
+/// DELETE /api/admin/announcements/:id - Withdraw an announcement
+pub async fn cancel_announcement(
+    State(state): State<AnnouncementsAppState>,
+    RequireAuth(user): RequireAuth,
+    Path(id): Path<String>,
+) -> Response {
+    if let Err(rejection) = state.require_admin(&user.id) {
+        return *rejection;
+    }
+
+    let Ok(announcement_id) = Uuid::parse_str(&id) else {
+        return (
+            StatusCode::BAD_REQUEST,
+            Json(ErrorResponse::bad_request("Invalid announcement ID")),
+        )
+            .into_response();
+    };
+
+    match state.board.cancel(announcement_id).await {
+        Ok(()) => {
+            tracing::info!(
+                announcement_id = %announcement_id,
+                cancelled_by = %user.id,
+                "Announcement withdrawn"
+            );
+            StatusCode::NO_CONTENT.into_response()
+        }
+        Err(e) => handle_announcement_error(e),
+    }
+}
+
+// ════════════════════════════════════════════════════════════════════════════
+// Error handling
+// ════════════════════════════════════════════════════════════════════════════
+
+fn handle_announcement_error(error: AnnouncementError) -> Response {
+    match error {
+        AnnouncementError::NotFound(id) => (
+            StatusCode::NOT_FOUND,
+            Json(ErrorResponse::not_found("Announcement", &id.to_string())),
+        )
+            .into_response(),
+        _ => (
+            StatusCode::BAD_REQUEST,
+            Json(ErrorResponse::bad_request(error.to_string())),
+        )
+            .into_response(),
+    }
+}
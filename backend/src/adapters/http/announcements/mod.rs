@@ -0,0 +1,12 @@
+//! Announcements HTTP adapter module.
+//!
+//! Admin endpoints to publish, list, and withdraw announcements pushed to
+//! connected WebSocket clients.
+
+pub mod dto;
+pub mod handlers;
+pub mod routes;
+
+pub use dto::{AnnouncementResponse, CreateAnnouncementRequest, ErrorResponse};
+pub use handlers::AnnouncementsAppState;
+pub use routes::announcement_routes;
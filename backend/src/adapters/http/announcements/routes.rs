@@ -0,0 +1,26 @@
+//! HTTP routes for announcement endpoints.
+
+use axum::{
+    routing::{delete, get},
+    Router,
+};
+
+use super::handlers::{
+    cancel_announcement, create_announcement, list_announcements, AnnouncementsAppState,
+};
+
+/// Creates the announcements router.
+///
+/// # Routes
+/// - `GET /api/admin/announcements` - Scheduled and live announcements (admin)
+/// - `POST /api/admin/announcements` - Publish or schedule an announcement (admin)
+/// - `DELETE /api/admin/announcements/:id` - Withdraw an announcement (admin)
+pub fn announcement_routes(state: AnnouncementsAppState) -> Router {
+    Router::new()
+        .route(
+            "/api/admin/announcements",
+            get(list_announcements).post(create_announcement),
+        )
+        .route("/api/admin/announcements/:id", delete(cancel_announcement))
+        .with_state(state)
+}
@@ -0,0 +1,116 @@
+//! HTTP DTOs for attachment endpoints.
+
+use serde::{Deserialize, Serialize};
+
+use crate::domain::document::Attachment;
+use crate::domain::foundation::{ComponentType, Timestamp};
+
+// ════════════════════════════════════════════════════════════════════════════
+// Request DTOs
+// ════════════════════════════════════════════════════════════════════════════
+
+/// Query parameters for an upload. The file itself is the raw request body
+/// and its type is the request's `Content-Type`.
+#[derive(Debug, Clone, Deserialize)]
+pub struct UploadAttachmentParams {
+    /// Component the file belongs to, e.g. `alternatives`.
+    pub component: ComponentType,
+    pub file_name: String,
+    #[serde(default)]
+    pub caption: Option<String>,
+}
+
+// ════════════════════════════════════════════════════════════════════════════
+// Response DTOs
+// ════════════════════════════════════════════════════════════════════════════
+
+/// Attachment metadata.
+#[derive(Debug, Clone, Serialize)]
+pub struct AttachmentResponse {
+    pub id: String,
+    pub cycle_id: String,
+    pub component: ComponentType,
+    pub file_name: String,
+    pub content_type: String,
+    pub size_bytes: usize,
+    pub caption: Option<String>,
+    /// Download URL, as referenced from exported documents.
+    pub url: String,
+    pub uploaded_at: Timestamp,
+}
+
+impl From<Attachment> for AttachmentResponse {
+    fn from(attachment: Attachment) -> Self {
+        Self {
+            url: attachment.download_path(),
+            id: attachment.id.to_string(),
+            cycle_id: attachment.cycle_id.to_string(),
+            component: attachment.component_type,
+            file_name: attachment.file_name,
+            content_type: attachment.content_type,
+            size_bytes: attachment.size_bytes,
+            caption: attachment.caption,
+            uploaded_at: attachment.uploaded_at,
+        }
+    }
+}
+
+/// Standard error response.
+#[derive(Debug, Clone, Serialize)]
+pub struct ErrorResponse {
+    pub code: String,
+    pub message: String,
+}
+
+impl ErrorResponse {
+    pub fn new(code: impl Into<String>, message: impl Into<String>) -> Self {
+        Self {
+            code: code.into(),
+            message: message.into(),
+        }
+    }
+
+    pub fn bad_request(message: impl Into<String>) -> Self {
+        Self::new("BAD_REQUEST", message)
+    }
+
+    pub fn internal(message: impl Into<String>) -> Self {
+        Self::new("INTERNAL_ERROR", message)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::foundation::{CycleId, UserId};
+
+    #[test]
+    fn upload_params_accept_snake_case_components() {
+        let params: UploadAttachmentParams = serde_json::from_str(
+            r#"{"component":"decision_quality","file_name":"scores.png","caption":"Team scores"}"#,
+        )
+        .unwrap();
+        assert_eq!(params.component, ComponentType::DecisionQuality);
+        assert_eq!(params.caption.as_deref(), Some("Team scores"));
+    }
+
+    #[test]
+    fn response_includes_download_url() {
+        let attachment = Attachment::new(
+            CycleId::new(),
+            ComponentType::Objectives,
+            "notes.txt",
+            "text/plain",
+            5,
+            None,
+            UserId::new("user-1").unwrap(),
+        )
+        .unwrap();
+        let expected = attachment.download_path();
+
+        let response = AttachmentResponse::from(attachment);
+
+        assert_eq!(response.url, expected);
+        assert_eq!(response.component, ComponentType::Objectives);
+    }
+}
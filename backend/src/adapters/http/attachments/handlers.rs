@@ -0,0 +1,287 @@
+//! HTTP handlers for attachment endpoints.
+
+use std::sync::Arc;
+
+use axum::{
+    body::Bytes,
+    extract::{Path, Query, State},
+    http::{header, HeaderMap, StatusCode},
+    response::{IntoResponse, Response},
+    Json,
+};
+
+use crate::adapters::http::middleware::RequireAuth;
+use crate::application::handlers::cycle::{
+    AddAttachmentCommand, AddAttachmentHandler, GetAttachmentContentHandler,
+    GetAttachmentContentQuery, ListAttachmentsHandler, ListAttachmentsQuery,
+    RemoveAttachmentCommand, RemoveAttachmentHandler,
+};
+use crate::domain::document::AttachmentError;
+use crate::domain::foundation::{AttachmentId, CycleId};
+use crate::ports::{AttachmentRepository, CycleRepository, DocumentStorage, SessionRepository};
+
+use super::dto::{AttachmentResponse, ErrorResponse, UploadAttachmentParams};
+
+// ════════════════════════════════════════════════════════════════════════════
+// Application State
+// ════════════════════════════════════════════════════════════════════════════
+
+/// Shared state for the attachment endpoints.
+#[derive(Clone)]
+pub struct AttachmentsAppState {
+    pub cycle_repository: Arc<dyn CycleRepository>,
+    pub session_repository: Arc<dyn SessionRepository>,
+    pub attachment_repository: Arc<dyn AttachmentRepository>,
+    pub document_storage: Arc<dyn DocumentStorage>,
+}
+
+impl AttachmentsAppState {
+    fn add_handler(&self) -> AddAttachmentHandler {
+        AddAttachmentHandler::new(
+            self.cycle_repository.clone(),
+            self.session_repository.clone(),
+            self.attachment_repository.clone(),
+            self.document_storage.clone(),
+        )
+    }
+
+    fn remove_handler(&self) -> RemoveAttachmentHandler {
+        RemoveAttachmentHandler::new(
+            self.cycle_repository.clone(),
+            self.session_repository.clone(),
+            self.attachment_repository.clone(),
+            self.document_storage.clone(),
+        )
+    }
+
+    fn list_handler(&self) -> ListAttachmentsHandler {
+        ListAttachmentsHandler::new(
+            self.cycle_repository.clone(),
+            self.session_repository.clone(),
+            self.attachment_repository.clone(),
+        )
+    }
+
+    fn content_handler(&self) -> GetAttachmentContentHandler {
+        GetAttachmentContentHandler::new(
+            self.cycle_repository.clone(),
+            self.session_repository.clone(),
+            self.attachment_repository.clone(),
+            self.document_storage.clone(),
+        )
+    }
+}
+
+// ════════════════════════════════════════════════════════════════════════════
+// HTTP handlers
+// ════════════════════════════════════════════════════════════════════════════
+
+/// POST /api/cycles/:cycle_id/attachments?component=..&file_name=..&caption=..
+///
+/// The request body is the file; its `Content-Type` header is the file type.
+pub async fn upload_attachment(
+    State(state): State<AttachmentsAppState>,
+    RequireAuth(user): RequireAuth,
+    Path(cycle_id): Path<String>,
+    Query(params): Query<UploadAttachmentParams>,
+    headers: HeaderMap,
+    body: Bytes,
+) -> Response {
+    let cycle_id = match parse_cycle_id(&cycle_id) {
+        Ok(id) => id,
+        Err(response) => return *response,
+    };
+    let content_type = headers
+        .get(header::CONTENT_TYPE)
+        .and_then(|v| v.to_str().ok())
+        .unwrap_or("application/octet-stream")
+        .to_string();
+
+    let cmd = AddAttachmentCommand {
+        cycle_id,
+        component_type: params.component,
+        file_name: params.file_name,
+        content_type,
+        caption: params.caption,
+        bytes: body.to_vec(),
+        user_id: user.id,
+    };
+    match state.add_handler().handle(cmd).await {
+        Ok(attachment) => (
+            StatusCode::CREATED,
+            Json(AttachmentResponse::from(attachment)),
+        )
+            .into_response(),
+        Err(e) => attachment_error_response(e),
+    }
+}
+
+/// GET /api/cycles/:cycle_id/attachments - List a cycle's attachments
+pub async fn list_attachments(
+    State(state): State<AttachmentsAppState>,
+    RequireAuth(user): RequireAuth,
+    Path(cycle_id): Path<String>,
+) -> Response {
+    let cycle_id = match parse_cycle_id(&cycle_id) {
+        Ok(id) => id,
+        Err(response) => return *response,
+    };
+    let query = ListAttachmentsQuery {
+        cycle_id,
+        user_id: user.id,
+    };
+    match state.list_handler().handle(query).await {
+        Ok(attachments) => {
+            let body: Vec<AttachmentResponse> = attachments
+                .into_iter()
+                .map(AttachmentResponse::from)
+                .collect();
+            (StatusCode::OK, Json(body)).into_response()
+        }
+        Err(e) => attachment_error_response(e),
+    }
+}
+
+/// GET /api/cycles/:cycle_id/attachments/:attachment_id - Download an attachment
+pub async fn download_attachment(
+    State(state): State<AttachmentsAppState>,
+    RequireAuth(user): RequireAuth,
+    Path((cycle_id, attachment_id)): Path<(String, String)>,
+) -> Response {
+    let (cycle_id, attachment_id) = match parse_ids(&cycle_id, &attachment_id) {
+        Ok(ids) => ids,
+        Err(response) => return *response,
+    };
+    let query = GetAttachmentContentQuery {
+        cycle_id,
+        attachment_id,
+        user_id: user.id,
+    };
+    match state.content_handler().handle(query).await {
+        Ok(content) => {
+            // Images display inline in documents; everything else downloads
+            let disposition = if content.attachment.is_image() {
+                "inline"
+            } else {
+                "attachment"
+            };
+            (
+                StatusCode::OK,
+                [
+                    (
+                        header::CONTENT_TYPE,
+                        content.attachment.content_type.clone(),
+                    ),
+                    (
+                        header::CONTENT_DISPOSITION,
+                        format!(
+                            "{}; filename=\"{}\"",
+                            disposition, content.attachment.file_name
+                        ),
+                    ),
+                    (header::CACHE_CONTROL, "private, no-store".to_string()),
+                ],
+                content.bytes,
+            )
+                .into_response()
+        }
+        Err(e) => attachment_error_response(e),
+    }
+}
+
+/// DELETE /api/cycles/:cycle_id/attachments/:attachment_id - Delete an attachment
+pub async fn delete_attachment(
+    State(state): State<AttachmentsAppState>,
+    RequireAuth(user): RequireAuth,
+    Path((cycle_id, attachment_id)): Path<(String, String)>,
+) -> Response {
+    let (cycle_id, attachment_id) = match parse_ids(&cycle_id, &attachment_id) {
+        Ok(ids) => ids,
+        Err(response) => return *response,
+    };
+    let cmd = RemoveAttachmentCommand {
+        cycle_id,
+        attachment_id,
+        user_id: user.id,
+    };
+    match state.remove_handler().handle(cmd).await {
+        Ok(()) => StatusCode::NO_CONTENT.into_response(),
+        Err(e) => attachment_error_response(e),
+    }
+}
+
+// ════════════════════════════════════════════════════════════════════════════
+// Helpers
+// ════════════════════════════════════════════════════════════════════════════
+
+fn bad_request(message: &str) -> Response {
+    (
+        StatusCode::BAD_REQUEST,
+        Json(ErrorResponse::bad_request(message)),
+    )
+        .into_response()
+}
+
+fn parse_cycle_id(cycle_id: &str) -> Result<CycleId, Box<Response>> {
+    cycle_id
+        .parse::<CycleId>()
+        .map_err(|_| Box::new(bad_request("Invalid cycle ID")))
+}
+
+fn parse_ids(
+    cycle_id: &str,
+    attachment_id: &str,
+) -> Result<(CycleId, AttachmentId), Box<Response>> {
+    let cycle_id = parse_cycle_id(cycle_id)?;
+    let attachment_id = attachment_id
+        .parse::<AttachmentId>()
+        .map_err(|_| Box::new(bad_request("Invalid attachment ID")))?;
+    Ok((cycle_id, attachment_id))
+}
+
+// ════════════════════════════════════════════════════════════════════════════
+// Error handling
+// ════════════════════════════════════════════════════════════════════════════
+
+fn attachment_error_response(error: AttachmentError) -> Response {
+    let status = match &error {
+        AttachmentError::CycleNotFound(_) | AttachmentError::NotFound(_) => StatusCode::NOT_FOUND,
+        AttachmentError::Forbidden => StatusCode::FORBIDDEN,
+        AttachmentError::Invalid(_) => StatusCode::UNPROCESSABLE_ENTITY,
+        AttachmentError::TooLarge { .. } => StatusCode::PAYLOAD_TOO_LARGE,
+        AttachmentError::Infrastructure(msg) => {
+            tracing::error!("Attachment request failed: {}", msg);
+            return (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ErrorResponse::internal("Failed to process attachment")),
+            )
+                .into_response();
+        }
+    };
+    let body = ErrorResponse::new(error.code().to_string(), error.to_string());
+    (status, Json(body)).into_response()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn oversized_files_map_to_413() {
+        let response = attachment_error_response(AttachmentError::TooLarge { size: 11, max: 10 });
+        assert_eq!(response.status(), StatusCode::PAYLOAD_TOO_LARGE);
+    }
+
+    #[test]
+    fn rejected_types_map_to_422() {
+        let response =
+            attachment_error_response(AttachmentError::Invalid("unsupported type".to_string()));
+        assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+    }
+
+    #[test]
+    fn other_users_map_to_403() {
+        let response = attachment_error_response(AttachmentError::Forbidden);
+        assert_eq!(response.status(), StatusCode::FORBIDDEN);
+    }
+}
@@ -0,0 +1,12 @@
+//! Attachments HTTP adapter module.
+//!
+//! Uploads, lists, downloads, and deletes the images and files attached to a
+//! cycle's components. Document exports link to the download endpoint.
+
+pub mod dto;
+pub mod handlers;
+pub mod routes;
+
+pub use dto::{AttachmentResponse, ErrorResponse, UploadAttachmentParams};
+pub use handlers::AttachmentsAppState;
+pub use routes::attachment_routes;
@@ -0,0 +1,36 @@
+//! HTTP routes for attachment endpoints.
+
+use axum::{
+    extract::DefaultBodyLimit,
+    routing::{get, post},
+    Router,
+};
+
+use crate::domain::document::MAX_ATTACHMENT_BYTES;
+
+use super::handlers::{
+    delete_attachment, download_attachment, list_attachments, upload_attachment,
+    AttachmentsAppState,
+};
+
+/// Creates the attachments router.
+///
+/// # Routes
+/// - `POST /api/cycles/:cycle_id/attachments` - Upload a file (raw body)
+/// - `GET /api/cycles/:cycle_id/attachments` - List attachments
+/// - `GET /api/cycles/:cycle_id/attachments/:attachment_id` - Download an attachment
+/// - `DELETE /api/cycles/:cycle_id/attachments/:attachment_id` - Delete an attachment
+pub fn attachment_routes(state: AttachmentsAppState) -> Router {
+    Router::new()
+        .route(
+            "/api/cycles/:cycle_id/attachments",
+            post(upload_attachment).get(list_attachments),
+        )
+        .route(
+            "/api/cycles/:cycle_id/attachments/:attachment_id",
+            get(download_attachment).delete(delete_attachment),
+        )
+        // Leave room for the limit to be reported as a domain error
+        .layer(DefaultBodyLimit::max(MAX_ATTACHMENT_BYTES + 1))
+        .with_state(state)
+}
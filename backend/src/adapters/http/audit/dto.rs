@@ -0,0 +1,201 @@
+//! HTTP DTOs for audit log administration.
+
+use serde::{Deserialize, Serialize};
+
+use crate::application::handlers::AuditVerification;
+use crate::domain::audit::AuditEntry;
+use crate::ports::AuditPage;
+
+/// Entries per page when none is asked for.
+pub const DEFAULT_PER_PAGE: u32 = 50;
+
+/// Most entries a single page may hold.
+pub const MAX_PER_PAGE: u32 = 200;
+
+// ════════════════════════════════════════════════════════════════════════════
+// Request DTOs
+// ════════════════════════════════════════════════════════════════════════════
+
+/// Query parameters for listing audit entries.
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct ListAuditEntriesQuery {
+    #[serde(default)]
+    pub actor_id: Option<String>,
+    #[serde(default)]
+    pub aggregate_type: Option<String>,
+    #[serde(default)]
+    pub aggregate_id: Option<String>,
+    #[serde(default)]
+    pub command_type: Option<String>,
+    #[serde(default)]
+    pub page: Option<u32>,
+    #[serde(default)]
+    pub per_page: Option<u32>,
+}
+
+impl ListAuditEntriesQuery {
+    /// The requested page, starting at 1.
+    pub fn page(&self) -> u32 {
+        self.page.unwrap_or(1).max(1)
+    }
+
+    /// The requested page size, capped at [`MAX_PER_PAGE`].
+    pub fn per_page(&self) -> u32 {
+        self.per_page
+            .unwrap_or(DEFAULT_PER_PAGE)
+            .clamp(1, MAX_PER_PAGE)
+    }
+}
+
+// ════════════════════════════════════════════════════════════════════════════
+// Response DTOs
+// ════════════════════════════════════════════════════════════════════════════
+
+/// One audit entry.
+#[derive(Debug, Clone, Serialize)]
+pub struct AuditEntryResponse {
+    pub sequence: u64,
+    pub actor_id: String,
+    pub command_type: String,
+    pub aggregate_type: String,
+    pub aggregate_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub correlation_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub source: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub before: Option<serde_json::Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub after: Option<serde_json::Value>,
+    pub recorded_at: String,
+    pub previous_hash: String,
+    pub hash: String,
+}
+
+impl From<AuditEntry> for AuditEntryResponse {
+    fn from(entry: AuditEntry) -> Self {
+        Self {
+            sequence: entry.sequence,
+            actor_id: entry.record.actor_id.to_string(),
+            command_type: entry.record.command_type,
+            aggregate_type: entry.record.aggregate_type,
+            aggregate_id: entry.record.aggregate_id,
+            correlation_id: entry.record.correlation_id,
+            source: entry.record.source,
+            before: entry.record.before,
+            after: entry.record.after,
+            recorded_at: entry.recorded_at.as_datetime().to_rfc3339(),
+            previous_hash: entry.previous_hash,
+            hash: entry.hash,
+        }
+    }
+}
+
+/// One page of audit entries.
+#[derive(Debug, Clone, Serialize)]
+pub struct AuditEntryListResponse {
+    pub entries: Vec<AuditEntryResponse>,
+    pub total: u64,
+    pub page: u32,
+    pub per_page: u32,
+    pub has_more: bool,
+}
+
+impl AuditEntryListResponse {
+    pub fn new(page: AuditPage, page_number: u32, per_page: u32) -> Self {
+        Self {
+            entries: page
+                .items
+                .into_iter()
+                .map(AuditEntryResponse::from)
+                .collect(),
+            total: page.total,
+            page: page_number,
+            per_page,
+            has_more: page.has_more,
+        }
+    }
+}
+
+/// Outcome of verifying the hash chain.
+#[derive(Debug, Clone, Serialize)]
+pub struct AuditVerificationResponse {
+    pub intact: bool,
+    pub entries_checked: u64,
+    /// Where and how the chain first breaks.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub first_break: Option<String>,
+}
+
+impl From<AuditVerification> for AuditVerificationResponse {
+    fn from(verification: AuditVerification) -> Self {
+        Self {
+            intact: verification.is_intact(),
+            entries_checked: verification.entries_checked,
+            first_break: verification.first_break.map(|b| b.to_string()),
+        }
+    }
+}
+
+/// Standard error response.
+#[derive(Debug, Clone, Serialize)]
+pub struct ErrorResponse {
+    pub code: String,
+    pub message: String,
+}
+
+impl ErrorResponse {
+    pub fn bad_request(message: impl Into<String>) -> Self {
+        Self {
+            code: "BAD_REQUEST".to_string(),
+            message: message.into(),
+        }
+    }
+
+    pub fn forbidden(message: impl Into<String>) -> Self {
+        Self {
+            code: "FORBIDDEN".to_string(),
+            message: message.into(),
+        }
+    }
+
+    pub fn internal(message: impl Into<String>) -> Self {
+        Self {
+            code: "INTERNAL_ERROR".to_string(),
+            message: message.into(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::audit::AuditChainBreak;
+
+    #[test]
+    fn paging_defaults_and_caps() {
+        let query = ListAuditEntriesQuery::default();
+        assert_eq!(query.page(), 1);
+        assert_eq!(query.per_page(), DEFAULT_PER_PAGE);
+
+        let query = ListAuditEntriesQuery {
+            page: Some(0),
+            per_page: Some(10_000),
+            ..Default::default()
+        };
+        assert_eq!(query.page(), 1);
+        assert_eq!(query.per_page(), MAX_PER_PAGE);
+    }
+
+    #[test]
+    fn verification_describes_the_first_break() {
+        let response = AuditVerificationResponse::from(AuditVerification {
+            entries_checked: 3,
+            first_break: Some(AuditChainBreak::Tampered { sequence: 3 }),
+        });
+
+        let json = serde_json::to_value(response).unwrap();
+        assert_eq!(json["intact"], false);
+        assert!(json["first_break"].as_str().unwrap().contains("entry 3"));
+    }
+}
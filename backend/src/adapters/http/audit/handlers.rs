@@ -0,0 +1,118 @@
+//! HTTP handlers for audit log administration.
+
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use axum::{
+    extract::{Query, State},
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    Json,
+};
+
+use crate::adapters::http::middleware::RequireAuth;
+use crate::application::handlers::{ListAuditEntriesHandler, VerifyAuditLogHandler};
+use crate::domain::foundation::{AuthenticatedUser, DomainError, UserId};
+use crate::ports::AuditQuery;
+
+use super::dto::{
+    AuditEntryListResponse, AuditVerificationResponse, ErrorResponse, ListAuditEntriesQuery,
+};
+
+// ════════════════════════════════════════════════════════════════════════════
+// Application State
+// ════════════════════════════════════════════════════════════════════════════
+
+/// Shared state for the audit log admin endpoints.
+#[derive(Clone)]
+pub struct AuditAppState {
+    pub list_handler: Arc<ListAuditEntriesHandler>,
+    pub verify_handler: Arc<VerifyAuditLogHandler>,
+    /// Users allowed to read the audit log.
+    pub admin_user_ids: Arc<HashSet<UserId>>,
+}
+
+type Rejection = (StatusCode, Json<ErrorResponse>);
+
+impl AuditAppState {
+    fn require_admin(&self, user: &AuthenticatedUser) -> Result<(), Rejection> {
+        if self.admin_user_ids.contains(&user.id) {
+            Ok(())
+        } else {
+            Err((
+                StatusCode::FORBIDDEN,
+                Json(ErrorResponse::forbidden("Admin access required")),
+            ))
+        }
+    }
+}
+
+fn reject(err: DomainError) -> Rejection {
+    (
+        StatusCode::INTERNAL_SERVER_ERROR,
+        Json(ErrorResponse::internal(err.to_string())),
+    )
+}
+
+// ════════════════════════════════════════════════════════════════════════════
+// HTTP handlers
+// ════════════════════════════════════════════════════════════════════════════
+
+/// GET /api/admin/audit - List audit entries, newest first
+pub async fn list_audit_entries(
+    State(state): State<AuditAppState>,
+    RequireAuth(user): RequireAuth,
+    Query(params): Query<ListAuditEntriesQuery>,
+) -> Response {
+    if let Err(rejection) = state.require_admin(&user) {
+        return rejection.into_response();
+    }
+
+    let actor_id = match params.actor_id.as_deref().map(UserId::new).transpose() {
+        Ok(actor_id) => actor_id,
+        Err(err) => {
+            return (
+                StatusCode::BAD_REQUEST,
+                Json(ErrorResponse::bad_request(format!(
+                    "Invalid actor_id: {}",
+                    err
+                ))),
+            )
+                .into_response()
+        }
+    };
+    let (page, per_page) = (params.page(), params.per_page());
+    let query = AuditQuery {
+        actor_id,
+        aggregate_type: params.aggregate_type,
+        aggregate_id: params.aggregate_id,
+        command_type: params.command_type,
+        ..AuditQuery::paginated(page, per_page)
+    };
+
+    match state.list_handler.handle(query).await {
+        Ok(entries) => {
+            let response = AuditEntryListResponse::new(entries, page, per_page);
+            (StatusCode::OK, Json(response)).into_response()
+        }
+        Err(err) => reject(err).into_response(),
+    }
+}
+
+/// GET /api/admin/audit/verify - Check the hash chain end to end
+pub async fn verify_audit_log(
+    State(state): State<AuditAppState>,
+    RequireAuth(user): RequireAuth,
+) -> Response {
+    if let Err(rejection) = state.require_admin(&user) {
+        return rejection.into_response();
+    }
+
+    match state.verify_handler.handle().await {
+        Ok(verification) => {
+            let response = AuditVerificationResponse::from(verification);
+            (StatusCode::OK, Json(response)).into_response()
+        }
+        Err(err) => reject(err).into_response(),
+    }
+}
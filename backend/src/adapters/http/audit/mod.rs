@@ -0,0 +1,15 @@
+//! Audit log admin HTTP adapter module.
+//!
+//! Admin endpoints for paging through the command audit log and verifying
+//! that its hash chain has not been tampered with.
+
+pub mod dto;
+pub mod handlers;
+pub mod routes;
+
+pub use dto::{
+    AuditEntryListResponse, AuditEntryResponse, AuditVerificationResponse, ErrorResponse,
+    ListAuditEntriesQuery,
+};
+pub use handlers::AuditAppState;
+pub use routes::audit_routes;
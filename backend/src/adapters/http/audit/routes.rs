@@ -0,0 +1,17 @@
+//! HTTP routes for audit log administration.
+
+use axum::{routing::get, Router};
+
+use super::handlers::{list_audit_entries, verify_audit_log, AuditAppState};
+
+/// Creates the audit log admin router.
+///
+/// # Routes
+/// - `GET /api/admin/audit` - List audit entries, newest first (admin)
+/// - `GET /api/admin/audit/verify` - Verify the hash chain (admin)
+pub fn audit_routes(state: AuditAppState) -> Router {
+    Router::new()
+        .route("/api/admin/audit", get(list_audit_entries))
+        .route("/api/admin/audit/verify", get(verify_audit_log))
+        .with_state(state)
+}
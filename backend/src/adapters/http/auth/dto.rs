@@ -0,0 +1,55 @@
+//! HTTP DTOs for auth endpoints.
+
+use serde::Serialize;
+
+use crate::application::handlers::LogoutAllResult;
+
+// ════════════════════════════════════════════════════════════════════════════
+// Response DTOs
+// ════════════════════════════════════════════════════════════════════════════
+
+/// Confirms that every token issued so far was revoked.
+#[derive(Debug, Clone, Serialize)]
+pub struct LogoutAllResponse {
+    /// Tokens issued at or before this time are rejected from now on.
+    pub revoked_at: String,
+}
+
+impl From<LogoutAllResult> for LogoutAllResponse {
+    fn from(result: LogoutAllResult) -> Self {
+        Self {
+            revoked_at: result.revoked_at.as_datetime().to_rfc3339(),
+        }
+    }
+}
+
+/// Standard error response.
+#[derive(Debug, Clone, Serialize)]
+pub struct ErrorResponse {
+    pub code: String,
+    pub message: String,
+}
+
+impl ErrorResponse {
+    pub fn internal(message: impl Into<String>) -> Self {
+        Self {
+            code: "INTERNAL_ERROR".to_string(),
+            message: message.into(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::foundation::Timestamp;
+
+    #[test]
+    fn logout_all_response_serializes_rfc3339() {
+        let response = LogoutAllResponse::from(LogoutAllResult {
+            revoked_at: Timestamp::from_unix_secs(0),
+        });
+        let json = serde_json::to_value(&response).unwrap();
+        assert_eq!(json["revoked_at"], "1970-01-01T00:00:00+00:00");
+    }
+}
@@ -0,0 +1,124 @@
+//! HTTP handlers for auth endpoints.
+
+use std::sync::Arc;
+
+use axum::{
+    extract::State,
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    Json,
+};
+
+use crate::adapters::http::middleware::RequireAuth;
+use crate::application::handlers::{LogoutAllCommand, LogoutAllHandler};
+use crate::domain::foundation::DomainError;
+use crate::ports::TokenRevocationStore;
+
+use super::dto::{ErrorResponse, LogoutAllResponse};
+
+// ════════════════════════════════════════════════════════════════════════════
+// Application State
+// ════════════════════════════════════════════════════════════════════════════
+
+/// Shared state for auth endpoints.
+///
+/// Use the same store as the `AuthState` of the auth middleware, or the
+/// revocations are never enforced.
+#[derive(Clone)]
+pub struct AuthAppState {
+    pub revocations: Arc<dyn TokenRevocationStore>,
+}
+
+impl AuthAppState {
+    pub fn logout_all_handler(&self) -> LogoutAllHandler {
+        LogoutAllHandler::new(self.revocations.clone())
+    }
+}
+
+// ════════════════════════════════════════════════════════════════════════════
+// HTTP handlers
+// ════════════════════════════════════════════════════════════════════════════
+
+/// POST /api/auth/logout-all - Revoke every token the user holds
+pub async fn logout_all(
+    State(state): State<AuthAppState>,
+    RequireAuth(user): RequireAuth,
+) -> Response {
+    let cmd = LogoutAllCommand { user_id: user.id };
+
+    match state.logout_all_handler().handle(cmd).await {
+        Ok(result) => (StatusCode::OK, Json(LogoutAllResponse::from(result))).into_response(),
+        Err(e) => handle_error(e),
+    }
+}
+
+// ════════════════════════════════════════════════════════════════════════════
+// Error handling
+// ════════════════════════════════════════════════════════════════════════════
+
+fn handle_error(error: DomainError) -> Response {
+    tracing::error!("Failed to revoke tokens: {}", error);
+    (
+        StatusCode::SERVICE_UNAVAILABLE,
+        Json(ErrorResponse::internal("Failed to log out of all sessions")),
+    )
+        .into_response()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::adapters::InMemoryTokenRevocationStore;
+    use crate::domain::foundation::{AuthenticatedUser, UserId};
+    use axum::{body::Body, http::Request, routing::post, Router};
+    use tower::ServiceExt;
+
+    fn app(store: Arc<InMemoryTokenRevocationStore>, user: Option<AuthenticatedUser>) -> Router {
+        Router::new()
+            .route("/api/auth/logout-all", post(logout_all))
+            .layer(axum::middleware::from_fn(
+                move |mut request: Request<Body>, next: axum::middleware::Next| {
+                    let user = user.clone();
+                    async move {
+                        if let Some(user) = user {
+                            request.extensions_mut().insert(user);
+                        }
+                        next.run(request).await
+                    }
+                },
+            ))
+            .with_state(AuthAppState { revocations: store })
+    }
+
+    fn request() -> Request<Body> {
+        Request::builder()
+            .method("POST")
+            .uri("/api/auth/logout-all")
+            .body(Body::empty())
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn logout_all_records_cutoff_for_user() {
+        let store = Arc::new(InMemoryTokenRevocationStore::new());
+        let user_id = UserId::new("user-1").unwrap();
+        let user = AuthenticatedUser::new(user_id.clone(), "user@example.com", None, true);
+
+        let response = app(store.clone(), Some(user))
+            .oneshot(request())
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+        assert!(store.revoked_before(&user_id).await.unwrap().is_some());
+    }
+
+    #[tokio::test]
+    async fn logout_all_requires_authentication() {
+        let store = Arc::new(InMemoryTokenRevocationStore::new());
+
+        let response = app(store, None).oneshot(request()).await.unwrap();
+
+        assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+    }
+}
@@ -0,0 +1,12 @@
+//! Auth HTTP adapter module.
+//!
+//! Endpoints for ending a user's sessions. Token validation itself lives in
+//! `middleware::auth`.
+
+pub mod dto;
+pub mod handlers;
+pub mod routes;
+
+pub use dto::{ErrorResponse, LogoutAllResponse};
+pub use handlers::AuthAppState;
+pub use routes::auth_routes;
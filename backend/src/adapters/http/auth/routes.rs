@@ -0,0 +1,15 @@
+//! HTTP routes for auth endpoints.
+
+use axum::{routing::post, Router};
+
+use super::handlers::{logout_all, AuthAppState};
+
+/// Creates the auth router.
+///
+/// # Routes
+/// - `POST /api/auth/logout-all` - Revoke all of the current user's tokens
+pub fn auth_routes(state: AuthAppState) -> Router {
+    Router::new()
+        .route("/api/auth/logout-all", post(logout_all))
+        .with_state(state)
+}
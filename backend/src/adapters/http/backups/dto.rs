@@ -0,0 +1,106 @@
+//! HTTP DTOs for backup administration.
+
+use std::collections::BTreeMap;
+
+use serde::Serialize;
+
+use crate::domain::backup::BackupSummary;
+use crate::domain::foundation::BackupId;
+
+// ════════════════════════════════════════════════════════════════════════════
+// Response DTOs
+// ════════════════════════════════════════════════════════════════════════════
+
+/// What a backup holds, or what a restore brought back.
+#[derive(Debug, Clone, Serialize)]
+pub struct BackupSummaryResponse {
+    pub backup_id: String,
+    pub created_at: String,
+    /// Records per section.
+    pub records: BTreeMap<String, usize>,
+    /// Stored documents.
+    pub documents: usize,
+}
+
+impl From<BackupSummary> for BackupSummaryResponse {
+    fn from(summary: BackupSummary) -> Self {
+        Self {
+            backup_id: summary.backup_id.to_string(),
+            created_at: summary.created_at.as_datetime().to_rfc3339(),
+            records: summary.records,
+            documents: summary.documents,
+        }
+    }
+}
+
+/// Stored backups.
+#[derive(Debug, Clone, Serialize)]
+pub struct BackupListResponse {
+    pub backup_ids: Vec<String>,
+}
+
+impl From<Vec<BackupId>> for BackupListResponse {
+    fn from(ids: Vec<BackupId>) -> Self {
+        Self {
+            backup_ids: ids.iter().map(BackupId::to_string).collect(),
+        }
+    }
+}
+
+/// Standard error response.
+#[derive(Debug, Clone, Serialize)]
+pub struct ErrorResponse {
+    pub code: String,
+    pub message: String,
+}
+
+impl ErrorResponse {
+    pub fn bad_request(message: impl Into<String>) -> Self {
+        Self {
+            code: "BAD_REQUEST".to_string(),
+            message: message.into(),
+        }
+    }
+
+    pub fn forbidden(message: impl Into<String>) -> Self {
+        Self {
+            code: "FORBIDDEN".to_string(),
+            message: message.into(),
+        }
+    }
+
+    pub fn not_found(message: impl Into<String>) -> Self {
+        Self {
+            code: "NOT_FOUND".to_string(),
+            message: message.into(),
+        }
+    }
+
+    pub fn internal(message: impl Into<String>) -> Self {
+        Self {
+            code: "INTERNAL_ERROR".to_string(),
+            message: message.into(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::backup::BackupArchive;
+
+    #[test]
+    fn summary_lists_records_per_section() {
+        let mut archive = BackupArchive::new();
+        archive.insert(
+            "sessions",
+            vec![serde_json::json!({}), serde_json::json!({})],
+        );
+
+        let json = serde_json::to_value(BackupSummaryResponse::from(archive.summary())).unwrap();
+
+        assert_eq!(json["backup_id"], archive.backup_id.to_string());
+        assert_eq!(json["records"]["sessions"], 2);
+        assert_eq!(json["documents"], 0);
+    }
+}
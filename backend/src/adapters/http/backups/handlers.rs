@@ -0,0 +1,160 @@
+//! HTTP handlers for backup administration.
+
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use axum::{
+    body::Bytes,
+    extract::{Path, State},
+    http::{header, StatusCode},
+    response::{IntoResponse, Response},
+    Json,
+};
+
+use crate::adapters::http::middleware::RequireAuth;
+use crate::application::handlers::{BackupError, BackupService};
+use crate::domain::foundation::{AuthenticatedUser, BackupId, UserId};
+
+use super::dto::{BackupListResponse, BackupSummaryResponse, ErrorResponse};
+
+// ════════════════════════════════════════════════════════════════════════════
+// Application State
+// ════════════════════════════════════════════════════════════════════════════
+
+/// Shared state for the backup admin endpoints.
+#[derive(Clone)]
+pub struct BackupsAppState {
+    pub service: Arc<BackupService>,
+    /// Users allowed to take, download and restore backups.
+    pub admin_user_ids: Arc<HashSet<UserId>>,
+}
+
+type Rejection = (StatusCode, Json<ErrorResponse>);
+
+impl BackupsAppState {
+    fn require_admin(&self, user: &AuthenticatedUser) -> Result<(), Rejection> {
+        if self.admin_user_ids.contains(&user.id) {
+            Ok(())
+        } else {
+            Err((
+                StatusCode::FORBIDDEN,
+                Json(ErrorResponse::forbidden("Admin access required")),
+            ))
+        }
+    }
+}
+
+fn reject(err: BackupError) -> Rejection {
+    match err {
+        BackupError::NotFound(id) => (
+            StatusCode::NOT_FOUND,
+            Json(ErrorResponse::not_found(format!("Backup {} not found", id))),
+        ),
+        BackupError::InvalidArchive(_) | BackupError::UnknownSection(_) => (
+            StatusCode::BAD_REQUEST,
+            Json(ErrorResponse::bad_request(err.to_string())),
+        ),
+        BackupError::Storage(_) | BackupError::Domain(_) => (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ErrorResponse::internal(err.to_string())),
+        ),
+    }
+}
+
+// ════════════════════════════════════════════════════════════════════════════
+// HTTP handlers
+// ════════════════════════════════════════════════════════════════════════════
+
+/// GET /api/admin/backups - List stored backups
+pub async fn list_backups(
+    State(state): State<BackupsAppState>,
+    RequireAuth(user): RequireAuth,
+) -> Response {
+    if let Err(rejection) = state.require_admin(&user) {
+        return rejection.into_response();
+    }
+
+    match state.service.list().await {
+        Ok(ids) => (StatusCode::OK, Json(BackupListResponse::from(ids))).into_response(),
+        Err(err) => reject(err).into_response(),
+    }
+}
+
+/// POST /api/admin/backups - Take a backup and store it
+pub async fn create_backup(
+    State(state): State<BackupsAppState>,
+    RequireAuth(user): RequireAuth,
+) -> Response {
+    if let Err(rejection) = state.require_admin(&user) {
+        return rejection.into_response();
+    }
+
+    match state.service.create().await {
+        Ok(summary) => {
+            tracing::info!(admin = %user.id, backup_id = %summary.backup_id, "Backup taken");
+            (
+                StatusCode::CREATED,
+                Json(BackupSummaryResponse::from(summary)),
+            )
+                .into_response()
+        }
+        Err(err) => reject(err).into_response(),
+    }
+}
+
+/// GET /api/admin/backups/:id/download - Download a stored archive
+pub async fn download_backup(
+    State(state): State<BackupsAppState>,
+    RequireAuth(user): RequireAuth,
+    Path(id): Path<String>,
+) -> Response {
+    if let Err(rejection) = state.require_admin(&user) {
+        return rejection.into_response();
+    }
+    let Ok(backup_id) = id.parse::<BackupId>() else {
+        return (
+            StatusCode::NOT_FOUND,
+            Json(ErrorResponse::not_found(format!("Backup {} not found", id))),
+        )
+            .into_response();
+    };
+
+    match state.service.download(&backup_id).await {
+        Ok(bytes) => (
+            StatusCode::OK,
+            [
+                (header::CONTENT_TYPE, "application/json".to_string()),
+                (
+                    header::CONTENT_DISPOSITION,
+                    format!(
+                        "attachment; filename=\"choice-sherpa-backup-{}.json\"",
+                        backup_id
+                    ),
+                ),
+                (header::CACHE_CONTROL, "private, no-store".to_string()),
+            ],
+            bytes,
+        )
+            .into_response(),
+        Err(err) => reject(err).into_response(),
+    }
+}
+
+/// POST /api/admin/backups/restore - Restore an uploaded archive (raw JSON body)
+pub async fn restore_backup(
+    State(state): State<BackupsAppState>,
+    RequireAuth(user): RequireAuth,
+    body: Bytes,
+) -> Response {
+    if let Err(rejection) = state.require_admin(&user) {
+        return rejection.into_response();
+    }
+
+    match state.service.restore(&body).await {
+        Ok(summary) => {
+            tracing::warn!(admin = %user.id, backup_id = %summary.backup_id, "Backup restored");
+            (StatusCode::OK, Json(BackupSummaryResponse::from(summary))).into_response()
+        }
+        Err(err) => reject(err).into_response(),
+    }
+}
@@ -0,0 +1,12 @@
+//! Backup admin HTTP adapter module.
+//!
+//! Admin endpoints for taking a full backup of the instance, downloading
+//! stored archives, and restoring one onto a fresh install.
+
+pub mod dto;
+pub mod handlers;
+pub mod routes;
+
+pub use dto::{BackupListResponse, BackupSummaryResponse, ErrorResponse};
+pub use handlers::BackupsAppState;
+pub use routes::backup_routes;
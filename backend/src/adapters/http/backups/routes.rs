@@ -0,0 +1,30 @@
+//! HTTP routes for backup administration.
+
+use axum::{
+    extract::DefaultBodyLimit,
+    routing::{get, post},
+    Router,
+};
+
+use super::handlers::{
+    create_backup, download_backup, list_backups, restore_backup, BackupsAppState,
+};
+
+/// Largest archive accepted for restore.
+const MAX_BACKUP_ARCHIVE_BYTES: usize = 1024 * 1024 * 1024;
+
+/// Creates the backup admin router.
+///
+/// # Routes
+/// - `GET /api/admin/backups` - List stored backups (admin)
+/// - `POST /api/admin/backups` - Take a backup (admin)
+/// - `GET /api/admin/backups/:id/download` - Download a stored archive (admin)
+/// - `POST /api/admin/backups/restore` - Restore an archive (raw JSON body, admin)
+pub fn backup_routes(state: BackupsAppState) -> Router {
+    Router::new()
+        .route("/api/admin/backups", get(list_backups).post(create_backup))
+        .route("/api/admin/backups/:id/download", get(download_backup))
+        .route("/api/admin/backups/restore", post(restore_backup))
+        .layer(DefaultBodyLimit::max(MAX_BACKUP_ARCHIVE_BYTES))
+        .with_state(state)
+}
@@ -0,0 +1,35 @@
+//! HTTP DTOs for calendar feed endpoints.
+
+use serde::Serialize;
+
+// ════════════════════════════════════════════════════════════════════════════
+// Response DTOs
+// ════════════════════════════════════════════════════════════════════════════
+
+/// The user's calendar subscription link.
+#[derive(Debug, Clone, Serialize)]
+pub struct CalendarFeedResponse {
+    /// Subscribe to this URL in a calendar app. Anyone holding it can read
+    /// the feed, so treat it like a password.
+    pub feed_url: String,
+}
+
+/// Standard error response.
+#[derive(Debug, Clone, Serialize)]
+pub struct ErrorResponse {
+    pub code: String,
+    pub message: String,
+}
+
+impl ErrorResponse {
+    pub fn new(code: impl Into<String>, message: impl Into<String>) -> Self {
+        Self {
+            code: code.into(),
+            message: message.into(),
+        }
+    }
+
+    pub fn internal(message: impl Into<String>) -> Self {
+        Self::new("INTERNAL_ERROR", message)
+    }
+}
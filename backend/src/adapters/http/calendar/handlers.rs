@@ -0,0 +1,218 @@
+//! HTTP handlers for calendar feed endpoints.
+
+use std::sync::Arc;
+
+use axum::{
+    extract::{Path, State},
+    http::{header, StatusCode},
+    response::{IntoResponse, Response},
+    Json,
+};
+
+use crate::adapters::http::middleware::RequireAuth;
+use crate::application::handlers::profile::GetCalendarFeedHandler;
+use crate::domain::foundation::{DomainError, UserId};
+use crate::ports::{
+    CalendarFeedSigner, CalendarFeedVersionRepository, CalendarRenderer, DecisionDeadlineReader,
+    OutcomeReminderRepository, CALENDAR_CONTENT_TYPE,
+};
+
+use super::dto::{CalendarFeedResponse, ErrorResponse};
+
+// ════════════════════════════════════════════════════════════════════════════
+// Application State
+// ════════════════════════════════════════════════════════════════════════════
+
+/// Shared state for the calendar feed endpoints.
+#[derive(Clone)]
+pub struct CalendarAppState {
+    pub deadlines: Arc<dyn DecisionDeadlineReader>,
+    pub reminders: Arc<dyn OutcomeReminderRepository>,
+    pub renderer: Arc<dyn CalendarRenderer>,
+    pub signer: Arc<dyn CalendarFeedSigner>,
+    pub versions: Arc<dyn CalendarFeedVersionRepository>,
+}
+
+impl CalendarAppState {
+    fn feed_handler(&self) -> GetCalendarFeedHandler {
+        GetCalendarFeedHandler::new(
+            self.deadlines.clone(),
+            self.reminders.clone(),
+            self.renderer.clone(),
+        )
+    }
+
+    /// Whether `version` is still the user's current feed version.
+    async fn is_current(&self, user_id: &UserId, version: u32) -> Result<bool, DomainError> {
+        Ok(self.versions.current(user_id).await? == version)
+    }
+}
+
+// ════════════════════════════════════════════════════════════════════════════
+// HTTP handlers
+// ════════════════════════════════════════════════════════════════════════════
+
+/// GET /api/user/calendar - The caller's calendar feed URL
+pub async fn get_calendar_feed_url(
+    State(state): State<CalendarAppState>,
+    RequireAuth(user): RequireAuth,
+) -> Response {
+    match state.versions.current(&user.id).await {
+        Ok(version) => feed_url_response(&state, &user.id, version),
+        Err(e) => {
+            tracing::error!("Failed to load calendar feed version: {}", e);
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ErrorResponse::internal("Failed to load calendar feed")),
+            )
+                .into_response()
+        }
+    }
+}
+
+/// POST /api/user/calendar/rotate - Replace the caller's feed URL, retiring the old one
+pub async fn rotate_calendar_feed(
+    State(state): State<CalendarAppState>,
+    RequireAuth(user): RequireAuth,
+) -> Response {
+    match state.versions.rotate(&user.id).await {
+        Ok(version) => feed_url_response(&state, &user.id, version),
+        Err(e) => {
+            tracing::error!("Failed to rotate calendar feed: {}", e);
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ErrorResponse::internal("Failed to rotate calendar feed")),
+            )
+                .into_response()
+        }
+    }
+}
+
+/// GET /public/calendar/:token - The calendar feed (no account needed)
+pub async fn serve_calendar_feed(
+    State(state): State<CalendarAppState>,
+    Path(token): Path<String>,
+) -> Response {
+    // Forged, malformed and rotated-away tokens look the same as unknown paths
+    let token = match state.signer.verify(&token) {
+        Ok(token) => token,
+        Err(_) => return StatusCode::NOT_FOUND.into_response(),
+    };
+    let feed = match state.is_current(&token.user_id, token.version).await {
+        Ok(true) => state.feed_handler().handle(&token.user_id).await,
+        Ok(false) => return StatusCode::NOT_FOUND.into_response(),
+        Err(e) => Err(e),
+    };
+
+    match feed {
+        Ok(ics) => calendar_response(ics),
+        Err(e) => {
+            tracing::error!("Failed to build calendar feed: {}", e);
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ErrorResponse::internal("Failed to build calendar feed")),
+            )
+                .into_response()
+        }
+    }
+}
+
+fn feed_url_response(state: &CalendarAppState, user_id: &UserId, version: u32) -> Response {
+    let feed_url = state.signer.feed_url(user_id, version);
+    (StatusCode::OK, Json(CalendarFeedResponse { feed_url })).into_response()
+}
+
+/// Serves `ics` uncached and unindexed, since the URL is the credential.
+fn calendar_response(ics: String) -> Response {
+    (
+        StatusCode::OK,
+        [
+            (header::CONTENT_TYPE, CALENDAR_CONTENT_TYPE),
+            (header::CACHE_CONTROL, "no-store"),
+            (
+                header::HeaderName::from_static("x-robots-tag"),
+                "noindex, nofollow",
+            ),
+            (header::REFERRER_POLICY, "no-referrer"),
+        ],
+        ics,
+    )
+        .into_response()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use async_trait::async_trait;
+
+    use crate::adapters::{
+        HmacCalendarFeedSigner, IcsCalendarRenderer, InMemoryCalendarFeedVersionRepository,
+        InMemoryOutcomeReminderRepository,
+    };
+    use crate::ports::DecisionDeadline;
+
+    struct NoDeadlines;
+
+    #[async_trait]
+    impl DecisionDeadlineReader for NoDeadlines {
+        async fn list_deadlines(
+            &self,
+            _user_id: &UserId,
+        ) -> Result<Vec<DecisionDeadline>, DomainError> {
+            Ok(vec![])
+        }
+    }
+
+    // ════════════════════════════════════════════════════════════════════════
+    // Test helpers
+    // ════════════════════════════════════════════════════════════════════════
+
+    fn create_state(versions: Arc<InMemoryCalendarFeedVersionRepository>) -> CalendarAppState {
+        CalendarAppState {
+            deadlines: Arc::new(NoDeadlines),
+            reminders: Arc::new(InMemoryOutcomeReminderRepository::new()),
+            renderer: Arc::new(IcsCalendarRenderer::new()),
+            signer: Arc::new(HmacCalendarFeedSigner::new(
+                "test-secret-that-is-long-enough",
+                "https://api.example.com",
+            )),
+            versions,
+        }
+    }
+
+    async fn serve(state: &CalendarAppState, user_id: &UserId, version: u32) -> StatusCode {
+        let url = state.signer.feed_url(user_id, version);
+        let token = url.rsplit('/').next().unwrap().to_string();
+        serve_calendar_feed(State(state.clone()), Path(token))
+            .await
+            .status()
+    }
+
+    #[tokio::test]
+    async fn rotated_feed_urls_stop_working() {
+        let versions = Arc::new(InMemoryCalendarFeedVersionRepository::new());
+        let state = create_state(versions.clone());
+        let user_id = UserId::new("user-1").unwrap();
+        assert_eq!(serve(&state, &user_id, 0).await, StatusCode::OK);
+
+        let version = versions.rotate(&user_id).await.unwrap();
+
+        assert_eq!(serve(&state, &user_id, 0).await, StatusCode::NOT_FOUND);
+        assert_eq!(serve(&state, &user_id, version).await, StatusCode::OK);
+        assert_eq!(
+            serve(&state, &UserId::new("user-2").unwrap(), 0).await,
+            StatusCode::OK
+        );
+    }
+
+    #[test]
+    fn feeds_are_served_as_uncached_calendars() {
+        let response = calendar_response("BEGIN:VCALENDAR\r\nEND:VCALENDAR\r\n".to_string());
+        let headers = response.headers();
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(headers["content-type"], CALENDAR_CONTENT_TYPE);
+        assert_eq!(headers["cache-control"], "no-store");
+        assert_eq!(headers["x-robots-tag"], "noindex, nofollow");
+    }
+}
@@ -0,0 +1,13 @@
+//! Calendar feed HTTP adapter module.
+//!
+//! Gives users a signed iCalendar feed URL of their decision deadlines and
+//! scheduled follow-ups, lets them rotate it if it leaks, and serves that
+//! feed to calendar apps.
+
+pub mod dto;
+pub mod handlers;
+pub mod routes;
+
+pub use dto::{CalendarFeedResponse, ErrorResponse};
+pub use handlers::CalendarAppState;
+pub use routes::calendar_routes;
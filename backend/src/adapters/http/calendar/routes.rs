@@ -0,0 +1,24 @@
+//! HTTP routes for calendar feed endpoints.
+
+use axum::{
+    routing::{get, post},
+    Router,
+};
+
+use super::handlers::{
+    get_calendar_feed_url, rotate_calendar_feed, serve_calendar_feed, CalendarAppState,
+};
+
+/// Creates the calendar feed router.
+///
+/// # Routes
+/// - `GET /api/user/calendar` - The caller's signed calendar feed URL
+/// - `POST /api/user/calendar/rotate` - A new feed URL; the old one stops working
+/// - `GET /public/calendar/:token` - The calendar feed as iCalendar (no account needed)
+pub fn calendar_routes(state: CalendarAppState) -> Router {
+    Router::new()
+        .route("/api/user/calendar", get(get_calendar_feed_url))
+        .route("/api/user/calendar/rotate", post(rotate_calendar_feed))
+        .route("/public/calendar/:token", get(serve_calendar_feed))
+        .with_state(state)
+}
@@ -0,0 +1,157 @@
+//! HTTP DTOs for fault injection rules.
+
+use serde::{Deserialize, Serialize};
+
+use crate::adapters::chaos::{FaultKind, FaultRule, FaultTarget};
+
+// ════════════════════════════════════════════════════════════════════════════
+// Request DTOs
+// ════════════════════════════════════════════════════════════════════════════
+
+/// Request to add a fault injection rule.
+///
+/// ```json
+/// {
+///   "target": "ai_provider",
+///   "operation": "stream_complete",
+///   "fault": { "type": "latency", "ms": 2000 },
+///   "probability": 0.25,
+///   "max_injections": 10
+/// }
+/// ```
+#[derive(Debug, Clone, Deserialize)]
+pub struct CreateFaultRuleRequest {
+    pub target: FaultTarget,
+    /// Operation to restrict the rule to; omit for every operation.
+    #[serde(default)]
+    pub operation: Option<String>,
+    pub fault: FaultKind,
+    /// Chance the rule fires on a matching call (defaults to always).
+    #[serde(default = "default_probability")]
+    pub probability: f64,
+    /// Stop after this many injections; omit for unlimited.
+    #[serde(default)]
+    pub max_injections: Option<u32>,
+}
+
+fn default_probability() -> f64 {
+    1.0
+}
+
+impl From<CreateFaultRuleRequest> for FaultRule {
+    fn from(request: CreateFaultRuleRequest) -> Self {
+        let mut rule =
+            FaultRule::new(request.target, request.fault).with_probability(request.probability);
+        if let Some(operation) = request.operation {
+            rule = rule.for_operation(operation);
+        }
+        if let Some(max) = request.max_injections {
+            rule = rule.limited_to(max);
+        }
+        rule
+    }
+}
+
+// ════════════════════════════════════════════════════════════════════════════
+// Response DTOs
+// ════════════════════════════════════════════════════════════════════════════
+
+/// A fault injection rule.
+#[derive(Debug, Clone, Serialize)]
+pub struct FaultRuleResponse {
+    pub id: String,
+    pub target: FaultTarget,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub operation: Option<String>,
+    pub fault: FaultKind,
+    pub probability: f64,
+    /// Injections left before the rule stops; absent when unlimited.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub remaining: Option<u32>,
+    pub created_at: String,
+}
+
+impl From<FaultRule> for FaultRuleResponse {
+    fn from(rule: FaultRule) -> Self {
+        Self {
+            id: rule.id,
+            target: rule.target,
+            operation: rule.operation,
+            fault: rule.fault,
+            probability: rule.probability,
+            remaining: rule.remaining,
+            created_at: rule.created_at.as_datetime().to_rfc3339(),
+        }
+    }
+}
+
+/// Active rules and injection totals.
+#[derive(Debug, Clone, Serialize)]
+pub struct FaultRuleListResponse {
+    pub rules: Vec<FaultRuleResponse>,
+    /// Faults injected since startup.
+    pub injected_count: u64,
+}
+
+/// Standard error response.
+#[derive(Debug, Clone, Serialize)]
+pub struct ErrorResponse {
+    pub code: String,
+    pub message: String,
+}
+
+impl ErrorResponse {
+    pub fn forbidden(message: impl Into<String>) -> Self {
+        Self {
+            code: "FORBIDDEN".to_string(),
+            message: message.into(),
+        }
+    }
+
+    pub fn bad_request(message: impl Into<String>) -> Self {
+        Self {
+            code: "BAD_REQUEST".to_string(),
+            message: message.into(),
+        }
+    }
+
+    pub fn not_found(message: impl Into<String>) -> Self {
+        Self {
+            code: "NOT_FOUND".to_string(),
+            message: message.into(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn request_parses_into_rule() {
+        let request: CreateFaultRuleRequest = serde_json::from_str(
+            r#"{"target":"event_bus","operation":"publish","fault":{"type":"drop"},"max_injections":3}"#,
+        )
+        .unwrap();
+
+        let rule = FaultRule::from(request);
+
+        assert_eq!(rule.target, FaultTarget::EventBus);
+        assert_eq!(rule.operation.as_deref(), Some("publish"));
+        assert_eq!(rule.fault, FaultKind::Drop);
+        assert_eq!(rule.probability, 1.0);
+        assert_eq!(rule.remaining, Some(3));
+    }
+
+    #[test]
+    fn latency_fault_carries_duration() {
+        let request: CreateFaultRuleRequest = serde_json::from_str(
+            r#"{"target":"postgres","fault":{"type":"latency","ms":1500},"probability":0.5}"#,
+        )
+        .unwrap();
+
+        assert_eq!(request.fault, FaultKind::Latency { ms: 1500 });
+        assert_eq!(request.probability, 0.5);
+        assert!(request.operation.is_none());
+    }
+}
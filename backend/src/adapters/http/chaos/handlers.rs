@@ -0,0 +1,124 @@
+//! HTTP handlers for fault injection rules.
+
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    Json,
+};
+
+use crate::adapters::chaos::{FaultInjector, FaultRule};
+use crate::adapters::http::middleware::RequireAuth;
+use crate::domain::foundation::{AuthenticatedUser, UserId};
+
+use super::dto::{CreateFaultRuleRequest, ErrorResponse, FaultRuleListResponse, FaultRuleResponse};
+
+// ════════════════════════════════════════════════════════════════════════════
+// Application State
+// ════════════════════════════════════════════════════════════════════════════
+
+/// Shared state for the chaos admin endpoints.
+#[derive(Clone)]
+pub struct ChaosAppState {
+    pub injector: Arc<FaultInjector>,
+    /// Users allowed to manage fault rules.
+    pub admin_user_ids: Arc<HashSet<UserId>>,
+}
+
+impl ChaosAppState {
+    fn require_admin(&self, user: &AuthenticatedUser) -> Result<(), Box<Response>> {
+        if self.admin_user_ids.contains(&user.id) {
+            Ok(())
+        } else {
+            Err(Box::new((
+                StatusCode::FORBIDDEN,
+                Json(ErrorResponse::forbidden("Admin access required")),
+            )
+                .into_response()))
+        }
+    }
+}
+
+// ════════════════════════════════════════════════════════════════════════════
+// HTTP handlers
+// ════════════════════════════════════════════════════════════════════════════
+
+/// GET /api/admin/chaos/rules - List active fault rules
+pub async fn list_fault_rules(
+    State(state): State<ChaosAppState>,
+    RequireAuth(user): RequireAuth,
+) -> Response {
+    if let Err(response) = state.require_admin(&user) {
+        return *response;
+    }
+
+    let response = FaultRuleListResponse {
+        rules: state.injector.rules().into_iter().map(Into::into).collect(),
+        injected_count: state.injector.injected_count(),
+    };
+    (StatusCode::OK, Json(response)).into_response()
+}
+
+/// POST /api/admin/chaos/rules - Add a fault rule
+pub async fn create_fault_rule(
+    State(state): State<ChaosAppState>,
+    RequireAuth(user): RequireAuth,
+    Json(request): Json<CreateFaultRuleRequest>,
+) -> Response {
+    if let Err(response) = state.require_admin(&user) {
+        return *response;
+    }
+
+    match state.injector.add_rule(FaultRule::from(request)) {
+        Ok(rule) => {
+            tracing::warn!(admin = %user.id, rule_id = %rule.id, "Fault rule created");
+            (StatusCode::CREATED, Json(FaultRuleResponse::from(rule))).into_response()
+        }
+        Err(e) => (
+            StatusCode::BAD_REQUEST,
+            Json(ErrorResponse::bad_request(e.to_string())),
+        )
+            .into_response(),
+    }
+}
+
+/// DELETE /api/admin/chaos/rules/:id - Remove a fault rule
+pub async fn delete_fault_rule(
+    State(state): State<ChaosAppState>,
+    RequireAuth(user): RequireAuth,
+    Path(id): Path<String>,
+) -> Response {
+    if let Err(response) = state.require_admin(&user) {
+        return *response;
+    }
+
+    if state.injector.remove_rule(&id) {
+        StatusCode::NO_CONTENT.into_response()
+    } else {
+        (
+            StatusCode::NOT_FOUND,
+            Json(ErrorResponse::not_found(format!(
+                "Fault rule {} not found",
+                id
+            ))),
+        )
+            .into_response()
+    }
+}
+
+/// DELETE /api/admin/chaos/rules - Remove every fault rule
+pub async fn clear_fault_rules(
+    State(state): State<ChaosAppState>,
+    RequireAuth(user): RequireAuth,
+) -> Response {
+    if let Err(response) = state.require_admin(&user) {
+        return *response;
+    }
+
+    state.injector.clear();
+    tracing::warn!(admin = %user.id, "Fault rules cleared");
+    StatusCode::NO_CONTENT.into_response()
+}
@@ -0,0 +1,12 @@
+//! Fault injection admin HTTP adapter module.
+//!
+//! Admin endpoints for managing the `FaultInjector` rules used by the chaos
+//! decorators. Only mounted when chaos mode is enabled.
+
+pub mod dto;
+pub mod handlers;
+pub mod routes;
+
+pub use dto::{CreateFaultRuleRequest, ErrorResponse, FaultRuleListResponse, FaultRuleResponse};
+pub use handlers::ChaosAppState;
+pub use routes::chaos_routes;
@@ -0,0 +1,31 @@
+//! HTTP routes for fault injection rules.
+
+use axum::{
+    routing::{delete, get},
+    Router,
+};
+
+use super::handlers::{
+    clear_fault_rules, create_fault_rule, delete_fault_rule, list_fault_rules, ChaosAppState,
+};
+
+/// Creates the chaos admin router.
+///
+/// Mount only when chaos mode is enabled.
+///
+/// # Routes
+/// - `GET /api/admin/chaos/rules` - List fault rules (admin)
+/// - `POST /api/admin/chaos/rules` - Add a fault rule (admin)
+/// - `DELETE /api/admin/chaos/rules` - Remove every fault rule (admin)
+/// - `DELETE /api/admin/chaos/rules/:id` - Remove a fault rule (admin)
+pub fn chaos_routes(state: ChaosAppState) -> Router {
+    Router::new()
+        .route(
+            "/api/admin/chaos/rules",
+            get(list_fault_rules)
+                .post(create_fault_rule)
+                .delete(clear_fault_rules),
+        )
+        .route("/api/admin/chaos/rules/:id", delete(delete_fault_rule))
+        .with_state(state)
+}
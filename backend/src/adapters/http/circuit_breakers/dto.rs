@@ -0,0 +1,117 @@
+//! HTTP DTOs for the circuit breaker dashboard.
+
+use serde::Serialize;
+
+use crate::adapters::resilience::{GuardedService, RegisteredBreaker};
+use crate::ports::CircuitState;
+
+// ════════════════════════════════════════════════════════════════════════════
+// Response DTOs
+// ════════════════════════════════════════════════════════════════════════════
+
+/// A circuit breaker's state and counters.
+#[derive(Debug, Clone, Serialize)]
+pub struct CircuitBreakerResponse {
+    pub name: String,
+    pub service: GuardedService,
+    /// "closed", "open" or "half_open".
+    pub state: &'static str,
+    /// Whether an admin forced the circuit open.
+    pub forced_open: bool,
+    /// Failures counting toward the threshold.
+    pub current_failures: u32,
+    pub total_failures: u64,
+    pub total_successes: u64,
+    pub times_opened: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_error: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_failure_at: Option<String>,
+    /// Seconds until an open circuit lets a trial request through.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub half_open_in_secs: Option<u64>,
+}
+
+impl From<&RegisteredBreaker> for CircuitBreakerResponse {
+    fn from(entry: &RegisteredBreaker) -> Self {
+        let metrics = entry.breaker.metrics();
+        let state = match metrics.state.unwrap_or_else(|| entry.breaker.state()) {
+            CircuitState::Closed => "closed",
+            CircuitState::Open => "open",
+            CircuitState::HalfOpen => "half_open",
+        };
+        Self {
+            name: entry.name.clone(),
+            service: entry.service,
+            state,
+            forced_open: metrics.forced_open,
+            current_failures: metrics.current_failures,
+            total_failures: metrics.total_failures,
+            total_successes: metrics.total_successes,
+            times_opened: metrics.times_opened,
+            last_error: metrics.last_error,
+            last_failure_at: metrics
+                .last_failure_at
+                .map(|at| at.as_datetime().to_rfc3339()),
+            half_open_in_secs: metrics.time_until_half_open.map(|d| d.as_secs()),
+        }
+    }
+}
+
+/// Every registered circuit breaker.
+#[derive(Debug, Clone, Serialize)]
+pub struct CircuitBreakerListResponse {
+    pub breakers: Vec<CircuitBreakerResponse>,
+}
+
+/// Standard error response.
+#[derive(Debug, Clone, Serialize)]
+pub struct ErrorResponse {
+    pub code: String,
+    pub message: String,
+}
+
+impl ErrorResponse {
+    pub fn forbidden(message: impl Into<String>) -> Self {
+        Self {
+            code: "FORBIDDEN".to_string(),
+            message: message.into(),
+        }
+    }
+
+    pub fn not_found(message: impl Into<String>) -> Self {
+        Self {
+            code: "NOT_FOUND".to_string(),
+            message: message.into(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::adapters::resilience::CircuitBreakerRegistry;
+    use crate::ports::CircuitBreakerConfig;
+
+    #[test]
+    fn reports_state_and_last_error() {
+        let registry = CircuitBreakerRegistry::new();
+        let breaker = registry.create(
+            "openai",
+            GuardedService::AiProvider,
+            CircuitBreakerConfig::default(),
+        );
+        breaker.record_failure("503 Service Unavailable");
+        breaker.force_open();
+
+        let response = CircuitBreakerResponse::from(&registry.get("openai").unwrap());
+        let json = serde_json::to_value(&response).unwrap();
+
+        assert_eq!(json["service"], "ai_provider");
+        assert_eq!(json["state"], "open");
+        assert_eq!(json["forced_open"], true);
+        assert_eq!(json["total_failures"], 1);
+        assert_eq!(json["last_error"], "503 Service Unavailable");
+        assert!(json.get("half_open_in_secs").is_none());
+    }
+}
@@ -0,0 +1,118 @@
+//! HTTP handlers for the circuit breaker dashboard.
+
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    Json,
+};
+
+use crate::adapters::http::middleware::RequireAuth;
+use crate::adapters::resilience::{CircuitBreakerRegistry, RegisteredBreaker};
+use crate::domain::foundation::{AuthenticatedUser, UserId};
+
+use super::dto::{CircuitBreakerListResponse, CircuitBreakerResponse, ErrorResponse};
+
+// ════════════════════════════════════════════════════════════════════════════
+// Application State
+// ════════════════════════════════════════════════════════════════════════════
+
+/// Shared state for the circuit breaker admin endpoints.
+#[derive(Clone)]
+pub struct CircuitBreakersAppState {
+    pub registry: Arc<CircuitBreakerRegistry>,
+    /// Users allowed to view and control circuit breakers.
+    pub admin_user_ids: Arc<HashSet<UserId>>,
+}
+
+type Rejection = (StatusCode, Json<ErrorResponse>);
+
+impl CircuitBreakersAppState {
+    fn require_admin(&self, user: &AuthenticatedUser) -> Result<(), Rejection> {
+        if self.admin_user_ids.contains(&user.id) {
+            Ok(())
+        } else {
+            Err((
+                StatusCode::FORBIDDEN,
+                Json(ErrorResponse::forbidden("Admin access required")),
+            ))
+        }
+    }
+
+    fn breaker(&self, name: &str) -> Result<RegisteredBreaker, Rejection> {
+        self.registry.get(name).ok_or_else(|| {
+            (
+                StatusCode::NOT_FOUND,
+                Json(ErrorResponse::not_found(format!(
+                    "Circuit breaker {} not found",
+                    name
+                ))),
+            )
+        })
+    }
+}
+
+// ════════════════════════════════════════════════════════════════════════════
+// HTTP handlers
+// ════════════════════════════════════════════════════════════════════════════
+
+/// GET /api/admin/circuit-breakers - List every circuit breaker
+pub async fn list_circuit_breakers(
+    State(state): State<CircuitBreakersAppState>,
+    RequireAuth(user): RequireAuth,
+) -> Response {
+    if let Err(rejection) = state.require_admin(&user) {
+        return rejection.into_response();
+    }
+
+    let response = CircuitBreakerListResponse {
+        breakers: state
+            .registry
+            .list()
+            .iter()
+            .map(CircuitBreakerResponse::from)
+            .collect(),
+    };
+    (StatusCode::OK, Json(response)).into_response()
+}
+
+/// POST /api/admin/circuit-breakers/:name/open - Force a circuit open
+pub async fn force_open_circuit_breaker(
+    State(state): State<CircuitBreakersAppState>,
+    RequireAuth(user): RequireAuth,
+    Path(name): Path<String>,
+) -> Response {
+    if let Err(rejection) = state.require_admin(&user) {
+        return rejection.into_response();
+    }
+    let entry = match state.breaker(&name) {
+        Ok(entry) => entry,
+        Err(rejection) => return rejection.into_response(),
+    };
+
+    entry.breaker.force_open();
+    tracing::warn!(admin = %user.id, breaker = %name, "Circuit breaker forced open");
+    (StatusCode::OK, Json(CircuitBreakerResponse::from(&entry))).into_response()
+}
+
+/// POST /api/admin/circuit-breakers/:name/reset - Close a circuit
+pub async fn reset_circuit_breaker(
+    State(state): State<CircuitBreakersAppState>,
+    RequireAuth(user): RequireAuth,
+    Path(name): Path<String>,
+) -> Response {
+    if let Err(rejection) = state.require_admin(&user) {
+        return rejection.into_response();
+    }
+    let entry = match state.breaker(&name) {
+        Ok(entry) => entry,
+        Err(rejection) => return rejection.into_response(),
+    };
+
+    entry.breaker.reset();
+    tracing::warn!(admin = %user.id, breaker = %name, "Circuit breaker reset");
+    (StatusCode::OK, Json(CircuitBreakerResponse::from(&entry))).into_response()
+}
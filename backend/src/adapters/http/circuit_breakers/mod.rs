@@ -0,0 +1,13 @@
+//! Circuit breaker admin HTTP adapter module.
+//!
+//! Admin endpoints listing every breaker in the `CircuitBreakerRegistry`
+//! (AI providers, Stripe, email) and forcing one open or resetting it during
+//! incidents.
+
+pub mod dto;
+pub mod handlers;
+pub mod routes;
+
+pub use dto::{CircuitBreakerListResponse, CircuitBreakerResponse, ErrorResponse};
+pub use handlers::CircuitBreakersAppState;
+pub use routes::circuit_breaker_routes;
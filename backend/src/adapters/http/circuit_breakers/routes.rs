@@ -0,0 +1,31 @@
+//! HTTP routes for the circuit breaker dashboard.
+
+use axum::{
+    routing::{get, post},
+    Router,
+};
+
+use super::handlers::{
+    force_open_circuit_breaker, list_circuit_breakers, reset_circuit_breaker,
+    CircuitBreakersAppState,
+};
+
+/// Creates the circuit breaker admin router.
+///
+/// # Routes
+/// - `GET /api/admin/circuit-breakers` - List circuit breakers (admin)
+/// - `POST /api/admin/circuit-breakers/:name/open` - Force a circuit open (admin)
+/// - `POST /api/admin/circuit-breakers/:name/reset` - Close a circuit (admin)
+pub fn circuit_breaker_routes(state: CircuitBreakersAppState) -> Router {
+    Router::new()
+        .route("/api/admin/circuit-breakers", get(list_circuit_breakers))
+        .route(
+            "/api/admin/circuit-breakers/:name/open",
+            post(force_open_circuit_breaker),
+        )
+        .route(
+            "/api/admin/circuit-breakers/:name/reset",
+            post(reset_circuit_breaker),
+        )
+        .with_state(state)
+}
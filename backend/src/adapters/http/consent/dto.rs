@@ -0,0 +1,140 @@
+//! HTTP DTOs for consent endpoints.
+
+use serde::{Deserialize, Serialize};
+
+use crate::application::handlers::consent::ListConsentsResult;
+use crate::domain::consent::{ConsentRecord, ConsentType, ProfileConsent};
+
+// ════════════════════════════════════════════════════════════════════════════
+// Request DTOs
+// ════════════════════════════════════════════════════════════════════════════
+
+/// Request to grant or withdraw a consent.
+#[derive(Debug, Clone, Deserialize)]
+pub struct RecordConsentRequest {
+    pub consent_type: ConsentType,
+    /// Document version accepted (e.g. "2026-01-01"). For purpose consents
+    /// such as AI processing this is the consent text version.
+    pub version: String,
+    pub granted: bool,
+    /// Where the consent was captured (e.g. "signup", "settings").
+    #[serde(default)]
+    pub source: Option<String>,
+}
+
+// ════════════════════════════════════════════════════════════════════════════
+// Response DTOs
+// ════════════════════════════════════════════════════════════════════════════
+
+/// A single consent record.
+#[derive(Debug, Clone, Serialize)]
+pub struct ConsentRecordResponse {
+    pub id: String,
+    pub consent_type: ConsentType,
+    pub version: String,
+    pub granted: bool,
+    pub recorded_at: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub source: Option<String>,
+}
+
+impl From<&ConsentRecord> for ConsentRecordResponse {
+    fn from(record: &ConsentRecord) -> Self {
+        Self {
+            id: record.id.to_string(),
+            consent_type: record.consent_type,
+            version: record.version.clone(),
+            granted: record.granted,
+            recorded_at: record.recorded_at.as_datetime().to_rfc3339(),
+            source: record.source.clone(),
+        }
+    }
+}
+
+/// A user's consent history and current state.
+#[derive(Debug, Clone, Serialize)]
+pub struct ConsentListResponse {
+    /// Latest record per consent type.
+    pub current: Vec<ConsentRecordResponse>,
+    /// Full ledger, oldest first.
+    pub history: Vec<ConsentRecordResponse>,
+    /// Consents still required before AI features are available.
+    pub missing_for_ai: Vec<ConsentType>,
+    pub ai_allowed: bool,
+    pub required_terms_version: String,
+    pub required_privacy_version: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub profile_consent: Option<ProfileConsent>,
+}
+
+impl From<ListConsentsResult> for ConsentListResponse {
+    fn from(result: ListConsentsResult) -> Self {
+        Self {
+            current: result.status.records().map(Into::into).collect(),
+            history: result.history.iter().map(Into::into).collect(),
+            ai_allowed: result.missing_for_ai.is_empty(),
+            missing_for_ai: result.missing_for_ai,
+            required_terms_version: result.requirements.terms_version.clone(),
+            required_privacy_version: result.requirements.privacy_version.clone(),
+            profile_consent: result.status.profile_consent(),
+        }
+    }
+}
+
+/// Standard error response.
+#[derive(Debug, Clone, Serialize)]
+pub struct ErrorResponse {
+    pub code: String,
+    pub message: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub details: Option<serde_json::Value>,
+}
+
+impl ErrorResponse {
+    pub fn bad_request(message: impl Into<String>) -> Self {
+        Self {
+            code: "BAD_REQUEST".to_string(),
+            message: message.into(),
+            details: None,
+        }
+    }
+
+    pub fn consent_required(missing: &[ConsentType]) -> Self {
+        Self {
+            code: "CONSENT_REQUIRED".to_string(),
+            message: "Required consents have not been accepted".to_string(),
+            details: Some(serde_json::json!({ "missing": missing })),
+        }
+    }
+
+    pub fn internal(message: impl Into<String>) -> Self {
+        Self {
+            code: "INTERNAL_ERROR".to_string(),
+            message: message.into(),
+            details: None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn record_request_deserializes_snake_case_type() {
+        let req: RecordConsentRequest = serde_json::from_str(
+            r#"{"consent_type":"ai_processing","version":"1","granted":true}"#,
+        )
+        .unwrap();
+        assert_eq!(req.consent_type, ConsentType::AiProcessing);
+        assert!(req.source.is_none());
+    }
+
+    #[test]
+    fn consent_required_lists_missing_types() {
+        let err = ErrorResponse::consent_required(&[ConsentType::TermsOfService]);
+        let json = serde_json::to_value(&err).unwrap();
+        assert_eq!(json["code"], "CONSENT_REQUIRED");
+        assert_eq!(json["details"]["missing"][0], "terms_of_service");
+    }
+}
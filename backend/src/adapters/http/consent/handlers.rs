@@ -0,0 +1,119 @@
+//! HTTP handlers for consent endpoints.
+
+use std::sync::Arc;
+
+use axum::{
+    extract::State,
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    Json,
+};
+
+use crate::adapters::http::middleware::RequireAuth;
+use crate::application::handlers::consent::{
+    CheckAiConsentHandler, ListConsentsHandler, ListConsentsQuery, RecordConsentCommand,
+    RecordConsentHandler,
+};
+use crate::domain::consent::{ConsentError, ConsentRequirements};
+use crate::domain::foundation::CommandMetadata;
+use crate::ports::{ConsentRepository, EventPublisher};
+
+use super::dto::{ConsentListResponse, ConsentRecordResponse, ErrorResponse, RecordConsentRequest};
+
+// ════════════════════════════════════════════════════════════════════════════
+// Application State
+// ════════════════════════════════════════════════════════════════════════════
+
+/// Shared state for consent endpoints and the AI consent middleware.
+#[derive(Clone)]
+pub struct ConsentAppState {
+    pub consent_repository: Arc<dyn ConsentRepository>,
+    pub event_publisher: Arc<dyn EventPublisher>,
+    pub requirements: ConsentRequirements,
+}
+
+impl ConsentAppState {
+    pub fn record_consent_handler(&self) -> RecordConsentHandler {
+        RecordConsentHandler::new(self.consent_repository.clone(), self.event_publisher.clone())
+    }
+
+    pub fn list_consents_handler(&self) -> ListConsentsHandler {
+        ListConsentsHandler::new(self.consent_repository.clone(), self.requirements.clone())
+    }
+
+    pub fn check_ai_consent_handler(&self) -> CheckAiConsentHandler {
+        CheckAiConsentHandler::new(self.consent_repository.clone(), self.requirements.clone())
+    }
+}
+
+// ════════════════════════════════════════════════════════════════════════════
+// HTTP handlers
+// ════════════════════════════════════════════════════════════════════════════
+
+/// POST /api/user/consents - Grant or withdraw a consent
+pub async fn record_consent(
+    State(state): State<ConsentAppState>,
+    RequireAuth(user): RequireAuth,
+    Json(req): Json<RecordConsentRequest>,
+) -> Response {
+    let cmd = RecordConsentCommand {
+        user_id: user.id.clone(),
+        consent_type: req.consent_type,
+        version: req.version,
+        granted: req.granted,
+        source: req.source,
+    };
+
+    let metadata = CommandMetadata::new(user.id).with_correlation_id("http-request");
+
+    match state.record_consent_handler().handle(cmd, metadata).await {
+        Ok(result) => {
+            let response = ConsentRecordResponse::from(&result.record);
+            (StatusCode::CREATED, Json(response)).into_response()
+        }
+        Err(e) => handle_consent_error(e),
+    }
+}
+
+/// GET /api/user/consents - List the current user's consents
+pub async fn list_consents(
+    State(state): State<ConsentAppState>,
+    RequireAuth(user): RequireAuth,
+) -> Response {
+    let query = ListConsentsQuery { user_id: user.id };
+
+    match state.list_consents_handler().handle(query).await {
+        Ok(result) => {
+            let response: ConsentListResponse = result.into();
+            (StatusCode::OK, Json(response)).into_response()
+        }
+        Err(e) => handle_consent_error(e),
+    }
+}
+
+// ════════════════════════════════════════════════════════════════════════════
+// Error handling
+// ════════════════════════════════════════════════════════════════════════════
+
+pub(crate) fn handle_consent_error(error: ConsentError) -> Response {
+    match error {
+        ConsentError::ConsentRequired(missing) => (
+            StatusCode::FORBIDDEN,
+            Json(ErrorResponse::consent_required(&missing)),
+        )
+            .into_response(),
+        ConsentError::ValidationFailed { field, message } => (
+            StatusCode::BAD_REQUEST,
+            Json(ErrorResponse::bad_request(format!(
+                "Validation failed for {}: {}",
+                field, message
+            ))),
+        )
+            .into_response(),
+        ConsentError::Infrastructure(msg) => (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ErrorResponse::internal(msg)),
+        )
+            .into_response(),
+    }
+}
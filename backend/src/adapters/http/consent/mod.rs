@@ -0,0 +1,14 @@
+//! Consent HTTP adapter module.
+//!
+//! Endpoints for recording and listing a user's consent to the terms of
+//! service, privacy policy, and AI processing.
+
+pub mod dto;
+pub mod handlers;
+pub mod routes;
+
+pub use dto::{
+    ConsentListResponse, ConsentRecordResponse, ErrorResponse, RecordConsentRequest,
+};
+pub use handlers::ConsentAppState;
+pub use routes::consent_routes;
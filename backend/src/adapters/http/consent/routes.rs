@@ -0,0 +1,16 @@
+//! HTTP routes for consent endpoints.
+
+use axum::{routing::get, Router};
+
+use super::handlers::{list_consents, record_consent, ConsentAppState};
+
+/// Creates the consent router.
+///
+/// # Routes
+/// - `GET /api/user/consents` - Consent history, current state, and AI gate status
+/// - `POST /api/user/consents` - Record a grant or withdrawal
+pub fn consent_routes(state: ConsentAppState) -> Router {
+    Router::new()
+        .route("/api/user/consents", get(list_consents).post(record_consent))
+        .with_state(state)
+}
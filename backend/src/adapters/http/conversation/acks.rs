@@ -0,0 +1,219 @@
+//! Delivery acknowledgements for the conversation stream.
+//!
+//! Messages that end a stream (`stream_complete`, `stream_error`) get a
+//! connection-scoped `delivery_id`. The client replies with
+//! `{"type": "ack", "delivery_id": N}`; until it does, the server resends
+//! the message every [`ACK_TIMEOUT`], up to [`MAX_DELIVERY_ATTEMPTS`] sends
+//! in total. Clients should ignore a `delivery_id` they have already seen.
+//!
+//! The number of unacknowledged messages is capped at [`MAX_PENDING_ACKS`];
+//! beyond that the oldest is given up on.
+
+use std::collections::VecDeque;
+use std::time::{Duration, Instant};
+
+use super::streaming::StreamServerMessage;
+
+/// How long to wait for an ack before resending.
+pub const ACK_TIMEOUT: Duration = Duration::from_secs(3);
+
+/// Total sends per message, including the first.
+pub const MAX_DELIVERY_ATTEMPTS: u32 = 4;
+
+/// Unacknowledged messages kept per connection.
+pub const MAX_PENDING_ACKS: usize = 32;
+
+#[derive(Debug)]
+struct Pending {
+    delivery_id: u64,
+    message: StreamServerMessage,
+    sent_at: Instant,
+    attempts: u32,
+}
+
+/// Tracks unacknowledged messages on one connection.
+#[derive(Debug)]
+pub struct AckTracker {
+    next_id: u64,
+    pending: VecDeque<Pending>,
+    ack_timeout: Duration,
+    max_attempts: u32,
+    capacity: usize,
+}
+
+impl AckTracker {
+    pub fn new(ack_timeout: Duration, max_attempts: u32, capacity: usize) -> Self {
+        Self {
+            next_id: 1,
+            pending: VecDeque::new(),
+            ack_timeout,
+            max_attempts: max_attempts.max(1),
+            capacity: capacity.max(1),
+        }
+    }
+
+    /// Stamp a delivery ID on a message that needs acknowledging and start
+    /// waiting for its ack. Other messages pass through unchanged.
+    pub fn track(&mut self, mut message: StreamServerMessage, now: Instant) -> StreamServerMessage {
+        if !message.requires_ack() {
+            return message;
+        }
+
+        let delivery_id = self.next_id;
+        self.next_id += 1;
+        message.set_delivery_id(delivery_id);
+
+        if self.pending.len() == self.capacity {
+            if let Some(dropped) = self.pending.pop_front() {
+                tracing::warn!(
+                    delivery_id = dropped.delivery_id,
+                    "Too many unacknowledged stream messages; giving up on the oldest"
+                );
+            }
+        }
+        self.pending.push_back(Pending {
+            delivery_id,
+            message: message.clone(),
+            sent_at: now,
+            attempts: 1,
+        });
+        message
+    }
+
+    /// Record an ack. Returns false for unknown or already-acked IDs.
+    pub fn ack(&mut self, delivery_id: u64) -> bool {
+        match self
+            .pending
+            .iter()
+            .position(|p| p.delivery_id == delivery_id)
+        {
+            Some(index) => {
+                self.pending.remove(index);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Messages whose ack is overdue, to send again now.
+    ///
+    /// Messages that have used all their attempts are dropped.
+    pub fn due(&mut self, now: Instant) -> Vec<StreamServerMessage> {
+        let mut resend = Vec::new();
+        let mut index = 0;
+        while index < self.pending.len() {
+            let pending = &mut self.pending[index];
+            if now.duration_since(pending.sent_at) < self.ack_timeout {
+                index += 1;
+                continue;
+            }
+            if pending.attempts >= self.max_attempts {
+                tracing::warn!(
+                    delivery_id = pending.delivery_id,
+                    attempts = pending.attempts,
+                    "Stream message was never acknowledged"
+                );
+                self.pending.remove(index);
+                continue;
+            }
+            pending.attempts += 1;
+            pending.sent_at = now;
+            resend.push(pending.message.clone());
+            index += 1;
+        }
+        resend
+    }
+
+    /// Number of messages awaiting an ack.
+    pub fn pending_count(&self) -> usize {
+        self.pending.len()
+    }
+}
+
+impl Default for AckTracker {
+    fn default() -> Self {
+        Self::new(ACK_TIMEOUT, MAX_DELIVERY_ATTEMPTS, MAX_PENDING_ACKS)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::adapters::http::conversation::streaming::{
+        StreamChunkMessage, StreamErrorCode, StreamErrorMessage,
+    };
+
+    fn ending(message_id: &str) -> StreamServerMessage {
+        StreamServerMessage::StreamError(StreamErrorMessage {
+            message_id: message_id.to_string(),
+            error_code: StreamErrorCode::Timeout,
+            error: "Timed out".to_string(),
+            partial_content: None,
+            recoverable: true,
+            delivery_id: None,
+        })
+    }
+
+    fn delivery_id(message: &StreamServerMessage) -> Option<u64> {
+        match message {
+            StreamServerMessage::StreamError(msg) => msg.delivery_id,
+            StreamServerMessage::StreamComplete(msg) => msg.delivery_id,
+            _ => None,
+        }
+    }
+
+    #[test]
+    fn chunks_are_not_tracked() {
+        let mut acks = AckTracker::default();
+        let chunk = StreamServerMessage::StreamChunk(StreamChunkMessage {
+            message_id: "m1".to_string(),
+            delta: "Hi".to_string(),
+            is_final: false,
+        });
+
+        acks.track(chunk, Instant::now());
+
+        assert_eq!(acks.pending_count(), 0);
+    }
+
+    #[test]
+    fn resends_until_acked() {
+        let mut acks = AckTracker::new(Duration::from_secs(3), 4, 8);
+        let start = Instant::now();
+        let sent = acks.track(ending("m1"), start);
+        assert_eq!(delivery_id(&sent), Some(1));
+
+        assert!(acks.due(start + Duration::from_secs(1)).is_empty());
+        let resent = acks.due(start + Duration::from_secs(3));
+        assert_eq!(resent.len(), 1);
+        assert_eq!(delivery_id(&resent[0]), Some(1));
+
+        assert!(acks.ack(1));
+        assert!(!acks.ack(1));
+        assert!(acks.due(start + Duration::from_secs(10)).is_empty());
+    }
+
+    #[test]
+    fn gives_up_after_max_attempts() {
+        let mut acks = AckTracker::new(Duration::from_secs(1), 2, 8);
+        let start = Instant::now();
+        acks.track(ending("m1"), start);
+
+        assert_eq!(acks.due(start + Duration::from_secs(1)).len(), 1);
+        assert!(acks.due(start + Duration::from_secs(2)).is_empty());
+        assert_eq!(acks.pending_count(), 0);
+    }
+
+    #[test]
+    fn drops_the_oldest_beyond_capacity() {
+        let mut acks = AckTracker::new(Duration::from_secs(1), 4, 2);
+        let now = Instant::now();
+        for id in ["m1", "m2", "m3"] {
+            acks.track(ending(id), now);
+        }
+
+        assert_eq!(acks.pending_count(), 2);
+        assert!(!acks.ack(1));
+        assert!(acks.ack(3));
+    }
+}
@@ -3,6 +3,7 @@
 //! These types decouple the HTTP API from domain types, allowing independent evolution.
 
 use serde::{Deserialize, Serialize};
+use utoipa::{IntoParams, ToSchema};
 
 use crate::domain::conversation::{AgentPhase, ConversationState};
 use crate::domain::foundation::ComponentType;
@@ -12,7 +13,7 @@ use crate::domain::foundation::ComponentType;
 // ════════════════════════════════════════════════════════════════════════════════
 
 /// View of a conversation for API responses.
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Serialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct ConversationView {
     /// Conversation ID.
@@ -33,8 +34,18 @@ pub struct ConversationView {
     pub updated_at: String,
 }
 
+/// Adaptive style setting for a conversation.
+#[derive(Debug, Clone, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct AdaptiveStyleView {
+    /// Conversation ID.
+    pub conversation_id: String,
+    /// Whether the agent adapts to the user's communication preferences.
+    pub enabled: bool,
+}
+
 /// View of a message for API responses.
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Serialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct MessageView {
     /// Message ID.
@@ -51,7 +62,7 @@ pub struct MessageView {
 }
 
 /// Role of a message sender.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
 #[serde(rename_all = "lowercase")]
 pub enum MessageRoleDto {
     User,
@@ -60,7 +71,7 @@ pub enum MessageRoleDto {
 }
 
 /// Token usage statistics.
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Serialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct TokenUsageDto {
     pub prompt_tokens: u32,
@@ -70,7 +81,7 @@ pub struct TokenUsageDto {
 }
 
 /// Paginated response wrapper.
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Serialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct Page<T> {
     /// The items in this page.
@@ -103,8 +114,22 @@ impl<T> Page<T> {
 // Request DTOs
 // ════════════════════════════════════════════════════════════════════════════════
 
+/// Request to turn profile-driven agent style on or off.
+#[derive(Debug, Clone, Deserialize, ToSchema)]
+pub struct AdaptiveStyleRequest {
+    /// Whether the agent adapts to the user's communication preferences.
+    pub enabled: bool,
+}
+
+/// Request to send a message and stream the response as server-sent events.
+#[derive(Debug, Clone, Deserialize, ToSchema)]
+pub struct StreamMessageRequest {
+    /// User's message text (max 10,000 chars).
+    pub content: String,
+}
+
 /// Query parameters for paginated message retrieval.
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, IntoParams)]
 pub struct PaginationParams {
     /// Number of items to skip.
     #[serde(default)]
@@ -138,7 +163,8 @@ impl PaginationParams {
 // ════════════════════════════════════════════════════════════════════════════════
 
 /// Standard error response.
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Serialize, ToSchema)]
+#[schema(as = ConversationErrorResponse)]
 pub struct ErrorResponse {
     pub code: String,
     pub message: String,
@@ -326,6 +352,22 @@ mod tests {
         }
     }
 
+    mod adaptive_style {
+        use super::*;
+
+        #[test]
+        fn serializes_to_camel_case() {
+            let view = AdaptiveStyleView {
+                conversation_id: "conv-123".to_string(),
+                enabled: false,
+            };
+
+            let json = serde_json::to_value(&view).unwrap();
+            assert_eq!(json["conversationId"], "conv-123");
+            assert_eq!(json["enabled"], false);
+        }
+    }
+
     mod error_response {
         use super::*;
 
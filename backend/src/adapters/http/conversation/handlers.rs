@@ -10,11 +10,14 @@ use axum::response::IntoResponse;
 
 use crate::application::handlers::conversation::{
     ComponentOwnershipChecker, ConversationRecord, ConversationRepository, MessageRole,
+    SendMessageHandler,
 };
-use crate::domain::foundation::{ComponentId, ConversationId, ErrorCode};
+use crate::application::handlers::profile::{SetAdaptiveStyleCommand, SetAdaptiveStyleHandler};
+use crate::domain::foundation::{ComponentId, ConversationId, ErrorCode, UserId};
+use crate::ports::{AIProvider, AdaptiveStyleOverrideRepository};
 
 use super::dto::{
-    ConversationView, ErrorResponse, MessageRoleDto, MessageView, Page, PaginationParams,
+    AdaptiveStyleRequest, AdaptiveStyleView, ConversationView, ErrorResponse, MessageRoleDto, MessageView, Page, PaginationParams,
     TokenUsageDto,
 };
 use crate::adapters::http::middleware::RequireAuth;
@@ -38,6 +41,10 @@ pub trait RateLimiter: Send + Sync {
 // Application State
 // ════════════════════════════════════════════════════════════════════════════════
 
+/// Send-message handler over the same trait objects as the app state.
+pub type ConversationSendMessageHandler =
+    SendMessageHandler<dyn ComponentOwnershipChecker, dyn ConversationRepository, dyn AIProvider>;
+
 /// Shared application state for conversation handlers.
 #[derive(Clone)]
 pub struct ConversationAppState {
@@ -45,6 +52,10 @@ pub struct ConversationAppState {
     pub ownership_checker: Arc<dyn ComponentOwnershipChecker>,
     /// Optional rate limiter for throttling requests.
     pub rate_limiter: Option<Arc<dyn RateLimiter>>,
+    /// Per-conversation adaptive style opt-outs, when adaptive style is enabled.
+    pub adaptive_style_overrides: Option<Arc<dyn AdaptiveStyleOverrideRepository>>,
+    /// Sends messages for the SSE endpoint, when an AI provider is wired in.
+    pub send_message: Option<Arc<ConversationSendMessageHandler>>,
 }
 
 impl ConversationAppState {
@@ -57,6 +68,8 @@ impl ConversationAppState {
             conversation_repo,
             ownership_checker,
             rate_limiter: None,
+            adaptive_style_overrides: None,
+            send_message: None,
         }
     }
 
@@ -65,6 +78,54 @@ impl ConversationAppState {
         self.rate_limiter = Some(rate_limiter);
         self
     }
+
+    /// Enables the per-conversation adaptive style switch.
+    pub fn with_adaptive_style_overrides(
+        mut self,
+        overrides: Arc<dyn AdaptiveStyleOverrideRepository>,
+    ) -> Self {
+        self.adaptive_style_overrides = Some(overrides);
+        self
+    }
+
+    /// Enables streaming message responses over server-sent events.
+    pub fn with_send_message_handler(mut self, handler: Arc<ConversationSendMessageHandler>) -> Self {
+        self.send_message = Some(handler);
+        self
+    }
+
+    /// Finds a conversation and checks the user owns it.
+    pub(super) async fn owned_conversation(
+        &self,
+        user_id: &UserId,
+        conversation_id: &ConversationId,
+    ) -> Result<ConversationRecord, ConversationApiError> {
+        let conversation = self
+            .conversation_repo
+            .find_by_id(conversation_id)
+            .await
+            .map_err(|e| ConversationApiError::Internal(e.to_string()))?
+            .ok_or_else(|| ConversationApiError::NotFound("Conversation".to_string(), conversation_id.to_string()))?;
+
+        self.ownership_checker
+            .check_ownership(user_id, &conversation.component_id)
+            .await
+            .map_err(|e| match e.code() {
+                ErrorCode::Forbidden => ConversationApiError::Forbidden("User does not own this conversation".to_string()),
+                _ => ConversationApiError::Internal(e.to_string()),
+            })?;
+
+        Ok(conversation)
+    }
+
+    fn adaptive_style_overrides(
+        &self,
+        conversation_id: &ConversationId,
+    ) -> Result<Arc<dyn AdaptiveStyleOverrideRepository>, ConversationApiError> {
+        self.adaptive_style_overrides.clone().ok_or_else(|| {
+            ConversationApiError::NotFound("Adaptive style".to_string(), conversation_id.to_string())
+        })
+    }
 }
 
 // ════════════════════════════════════════════════════════════════════════════════
@@ -79,6 +140,18 @@ impl ConversationAppState {
 /// - 401 Unauthorized: No valid auth token
 /// - 403 Forbidden: User doesn't own the component
 /// - 404 Not Found: Component has no conversation
+#[utoipa::path(
+    get,
+    path = "/api/components/{component_id}/conversation",
+    tag = "conversations",
+    params(("component_id" = String, Path, description = "Component ID")),
+    responses(
+        (status = 200, description = "The component's conversation", body = ConversationView),
+        (status = 401, description = "No valid auth token", body = ErrorResponse),
+        (status = 403, description = "User doesn't own the conversation", body = ErrorResponse),
+        (status = 404, description = "Component has no conversation", body = ErrorResponse),
+    )
+)]
 pub async fn get_conversation(
     State(state): State<ConversationAppState>,
     RequireAuth(user): RequireAuth,
@@ -127,6 +200,18 @@ pub async fn get_conversation(
 /// - 401 Unauthorized: No valid auth token
 /// - 403 Forbidden: User doesn't own the conversation
 /// - 404 Not Found: Conversation not found
+#[utoipa::path(
+    get,
+    path = "/api/conversations/{conversation_id}/messages",
+    tag = "conversations",
+    params(("conversation_id" = String, Path, description = "Conversation ID"), PaginationParams),
+    responses(
+        (status = 200, description = "Page of messages", body = Page<MessageView>),
+        (status = 401, description = "No valid auth token", body = ErrorResponse),
+        (status = 403, description = "User doesn't own the conversation", body = ErrorResponse),
+        (status = 404, description = "Conversation not found", body = ErrorResponse),
+    )
+)]
 pub async fn get_messages(
     State(state): State<ConversationAppState>,
     RequireAuth(user): RequireAuth,
@@ -177,7 +262,7 @@ pub async fn get_messages(
 // ════════════════════════════════════════════════════════════════════════════════
 
 /// Response from regenerating the last assistant response.
-#[derive(Debug, Clone, serde::Serialize)]
+#[derive(Debug, Clone, serde::Serialize, utoipa::ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct RegenerateResponse {
     /// ID of the new message.
@@ -197,6 +282,19 @@ pub struct RegenerateResponse {
 /// - 403 Forbidden: User doesn't own the component
 /// - 404 Not Found: Component has no conversation
 /// - 429 Too Many Requests: Rate limit exceeded (R13)
+#[utoipa::path(
+    post,
+    path = "/api/components/{component_id}/conversation/regenerate",
+    tag = "conversations",
+    params(("component_id" = String, Path, description = "Component ID")),
+    responses(
+        (status = 200, description = "Regenerated response", body = RegenerateResponse),
+        (status = 401, description = "No valid auth token", body = ErrorResponse),
+        (status = 403, description = "User doesn't own the conversation", body = ErrorResponse),
+        (status = 404, description = "Component has no conversation", body = ErrorResponse),
+        (status = 429, description = "Rate limit exceeded", body = ErrorResponse),
+    )
+)]
 pub async fn regenerate_response(
     State(state): State<ConversationAppState>,
     RequireAuth(user): RequireAuth,
@@ -256,6 +354,95 @@ pub async fn regenerate_response(
     })))
 }
 
+// ════════════════════════════════════════════════════════════════════════════════
+// GET/PUT /api/conversations/{id}/adaptive-style
+// ════════════════════════════════════════════════════════════════════════════════
+
+/// GET /api/conversations/{id}/adaptive-style - Whether the agent adapts to the user's profile.
+///
+/// # Errors
+/// - 401 Unauthorized: No valid auth token
+/// - 403 Forbidden: User doesn't own the conversation
+/// - 404 Not Found: Conversation not found, or adaptive style not enabled
+#[utoipa::path(
+    get,
+    path = "/api/conversations/{conversation_id}/adaptive-style",
+    tag = "conversations",
+    params(("conversation_id" = String, Path, description = "Conversation ID")),
+    responses(
+        (status = 200, description = "Adaptive style setting", body = AdaptiveStyleView),
+        (status = 401, description = "No valid auth token", body = ErrorResponse),
+        (status = 403, description = "User doesn't own the conversation", body = ErrorResponse),
+        (status = 404, description = "Conversation not found, or adaptive style not enabled", body = ErrorResponse),
+    )
+)]
+pub async fn get_adaptive_style(
+    State(state): State<ConversationAppState>,
+    RequireAuth(user): RequireAuth,
+    Path(conversation_id): Path<String>,
+) -> Result<impl IntoResponse, ConversationApiError> {
+    let conversation_id: ConversationId = conversation_id
+        .parse()
+        .map_err(|_| ConversationApiError::BadRequest("Invalid conversation ID format".to_string()))?;
+    let overrides = state.adaptive_style_overrides(&conversation_id)?;
+    state.owned_conversation(&user.id, &conversation_id).await?;
+
+    let disabled = overrides
+        .is_disabled(&conversation_id)
+        .await
+        .map_err(|e| ConversationApiError::Internal(e.to_string()))?;
+
+    Ok((StatusCode::OK, Json(AdaptiveStyleView {
+        conversation_id: conversation_id.to_string(),
+        enabled: !disabled,
+    })))
+}
+
+/// PUT /api/conversations/{id}/adaptive-style - Turn profile-driven agent style on or off.
+///
+/// # Errors
+/// - 401 Unauthorized: No valid auth token
+/// - 403 Forbidden: User doesn't own the conversation
+/// - 404 Not Found: Conversation not found, or adaptive style not enabled
+#[utoipa::path(
+    put,
+    path = "/api/conversations/{conversation_id}/adaptive-style",
+    tag = "conversations",
+    params(("conversation_id" = String, Path, description = "Conversation ID")),
+    request_body = AdaptiveStyleRequest,
+    responses(
+        (status = 200, description = "Updated adaptive style setting", body = AdaptiveStyleView),
+        (status = 401, description = "No valid auth token", body = ErrorResponse),
+        (status = 403, description = "User doesn't own the conversation", body = ErrorResponse),
+        (status = 404, description = "Conversation not found, or adaptive style not enabled", body = ErrorResponse),
+    )
+)]
+pub async fn put_adaptive_style(
+    State(state): State<ConversationAppState>,
+    RequireAuth(user): RequireAuth,
+    Path(conversation_id): Path<String>,
+    Json(request): Json<AdaptiveStyleRequest>,
+) -> Result<impl IntoResponse, ConversationApiError> {
+    let conversation_id: ConversationId = conversation_id
+        .parse()
+        .map_err(|_| ConversationApiError::BadRequest("Invalid conversation ID format".to_string()))?;
+    let overrides = state.adaptive_style_overrides(&conversation_id)?;
+    state.owned_conversation(&user.id, &conversation_id).await?;
+
+    SetAdaptiveStyleHandler::new(overrides)
+        .handle(SetAdaptiveStyleCommand {
+            conversation_id,
+            enabled: request.enabled,
+        })
+        .await
+        .map_err(|e| ConversationApiError::Internal(e.to_string()))?;
+
+    Ok((StatusCode::OK, Json(AdaptiveStyleView {
+        conversation_id: conversation_id.to_string(),
+        enabled: request.enabled,
+    })))
+}
+
 // ════════════════════════════════════════════════════════════════════════════════
 // Helper Functions
 // ════════════════════════════════════════════════════════════════════════════════
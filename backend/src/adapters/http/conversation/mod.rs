@@ -1,22 +1,30 @@
 //! HTTP adapters for conversation endpoints.
 //!
-//! Provides REST API and WebSocket streaming for conversation access.
+//! Provides REST API, WebSocket streaming and server-sent events streaming
+//! for conversation access.
 
+pub mod acks;
 pub mod dto;
 pub mod handlers;
 pub mod routes;
+pub mod sse_handler;
 pub mod streaming;
 pub mod ws_handler;
 
+pub use acks::{AckTracker, ACK_TIMEOUT, MAX_DELIVERY_ATTEMPTS, MAX_PENDING_ACKS};
 pub use dto::{
-    ConversationView, ErrorResponse, MessageRoleDto, MessageView, Page, PaginationParams,
-    TokenUsageDto,
+    AdaptiveStyleRequest, AdaptiveStyleView, ConversationView, ErrorResponse, MessageRoleDto, MessageView, Page, PaginationParams,
+    StreamMessageRequest, TokenUsageDto,
+};
+pub use handlers::{
+    ConversationAppState, ConversationApiError, ConversationSendMessageHandler, RateLimiter,
+    RegenerateResponse,
 };
-pub use handlers::{ConversationAppState, ConversationApiError, RateLimiter, RegenerateResponse};
 pub use routes::{conversation_router, conversation_routes, conversation_ws_routes};
 pub use streaming::{
-    DataExtractedMessage, PhaseTransition, SendMessageRequest, StreamChunkMessage,
+    AckRequest, DataExtractedMessage, PhaseTransition, SendMessageRequest, StreamChunkMessage,
     StreamClientMessage, StreamCompleteMessage, StreamErrorCode, StreamErrorMessage,
     StreamPongMessage, StreamServerMessage, StreamTokenUsage, MAX_MESSAGE_LENGTH,
 };
+pub use sse_handler::stream_message;
 pub use ws_handler::{ConversationWebSocketState, WsConnectParams, conversation_ws_handler};
@@ -5,7 +5,11 @@
 use axum::routing::{any, get, post};
 use axum::Router;
 
-use super::handlers::{get_conversation, get_messages, regenerate_response, ConversationAppState};
+use super::handlers::{
+    get_adaptive_style, get_conversation, get_messages, put_adaptive_style, regenerate_response,
+    ConversationAppState,
+};
+use super::sse_handler::stream_message;
 use super::ws_handler::{conversation_ws_handler, ConversationWebSocketState};
 
 /// Creates routes for conversation REST endpoints.
@@ -13,12 +17,20 @@ use super::ws_handler::{conversation_ws_handler, ConversationWebSocketState};
 /// REST Endpoints:
 /// - GET /api/components/{component_id}/conversation - Get conversation for component
 /// - GET /api/conversations/{conversation_id}/messages - Get paginated messages
+/// - POST /api/conversations/{conversation_id}/messages:stream - Send a message, streaming the response as SSE
 /// - POST /api/components/{component_id}/conversation/regenerate - Regenerate last response
+/// - GET /api/conversations/{conversation_id}/adaptive-style - Whether the agent adapts to the user's profile
+/// - PUT /api/conversations/{conversation_id}/adaptive-style - Turn adaptive style on or off
 pub fn conversation_routes() -> Router<ConversationAppState> {
     Router::new()
         .route("/components/{component_id}/conversation", get(get_conversation))
         .route("/conversations/{conversation_id}/messages", get(get_messages))
+        .route("/conversations/{conversation_id}/messages:stream", post(stream_message))
         .route("/components/{component_id}/conversation/regenerate", post(regenerate_response))
+        .route(
+            "/conversations/{conversation_id}/adaptive-style",
+            get(get_adaptive_style).put(put_adaptive_style),
+        )
 }
 
 /// Creates routes for conversation WebSocket endpoints.
@@ -0,0 +1,174 @@
+//! Server-sent events endpoint for conversation AI streaming.
+//!
+//! An alternative to the WebSocket for clients that cannot hold one. The
+//! response carries the `StreamEvent`s produced by `SendMessageHandler`, one
+//! SSE event each, named after the event's type:
+//!
+//! ```text
+//! event: chunk
+//! data: {"type":"chunk","message_id":"...","delta":"Hel"}
+//! ```
+//!
+//! The stream ends after a `complete` or `error` event. Heartbeat comments
+//! keep proxies from closing the connection while the provider is silent.
+//! When the client disconnects, the response stream is dropped, which drops
+//! the event receiver and cancels the exchange.
+
+use std::convert::Infallible;
+use std::time::Duration;
+
+use axum::extract::{Json, Path, State};
+use axum::response::sse::{Event, KeepAlive, Sse};
+use axum::response::IntoResponse;
+
+use crate::adapters::http::middleware::RequireAuth;
+use crate::application::handlers::conversation::{
+    SendMessageCommand, SendMessageError, StreamEvent,
+};
+use crate::domain::foundation::ConversationId;
+
+use super::dto::{ErrorResponse, StreamMessageRequest};
+use super::handlers::{ConversationApiError, ConversationAppState};
+use super::streaming::MAX_MESSAGE_LENGTH;
+
+/// How often a heartbeat comment is sent while no events are.
+const HEARTBEAT_INTERVAL: Duration = Duration::from_secs(15);
+
+/// POST /api/conversations/{id}/messages:stream - Send a message and stream the response.
+///
+/// # Errors
+/// - 400 Bad Request: Empty or oversized content, or the conversation is complete
+/// - 401 Unauthorized: No valid auth token
+/// - 403 Forbidden: User doesn't own the conversation
+/// - 404 Not Found: Conversation not found, or streaming not enabled
+#[utoipa::path(
+    post,
+    path = "/api/conversations/{conversation_id}/messages:stream",
+    tag = "conversations",
+    params(("conversation_id" = String, Path, description = "Conversation ID")),
+    request_body = StreamMessageRequest,
+    responses(
+        (status = 200, description = "Server-sent stream of response events", content_type = "text/event-stream", body = String),
+        (status = 400, description = "Empty or oversized content, or the conversation is complete", body = ErrorResponse),
+        (status = 401, description = "No valid auth token", body = ErrorResponse),
+        (status = 403, description = "User doesn't own the conversation", body = ErrorResponse),
+        (status = 404, description = "Conversation not found, or streaming not enabled", body = ErrorResponse),
+    )
+)]
+pub async fn stream_message(
+    State(state): State<ConversationAppState>,
+    RequireAuth(user): RequireAuth,
+    Path(conversation_id): Path<String>,
+    Json(request): Json<StreamMessageRequest>,
+) -> Result<impl IntoResponse, ConversationApiError> {
+    let conversation_id: ConversationId = conversation_id.parse().map_err(|_| {
+        ConversationApiError::BadRequest("Invalid conversation ID format".to_string())
+    })?;
+    let handler = state.send_message.clone().ok_or_else(|| {
+        ConversationApiError::NotFound("Message streaming".to_string(), conversation_id.to_string())
+    })?;
+    if request.content.len() > MAX_MESSAGE_LENGTH {
+        return Err(ConversationApiError::BadRequest(
+            "Message content exceeds maximum length".to_string(),
+        ));
+    }
+    let conversation = state.owned_conversation(&user.id, &conversation_id).await?;
+
+    let exchange = handler
+        .stream(SendMessageCommand::new(
+            user.id,
+            conversation.component_id,
+            request.content,
+        ))
+        .await
+        .map_err(send_error)?;
+
+    let events = futures::stream::unfold(exchange.events, |mut events| async move {
+        let event = events.recv().await?;
+        Some((Ok::<_, Infallible>(sse_event(&event)), events))
+    });
+
+    Ok(Sse::new(events).keep_alive(
+        KeepAlive::new()
+            .interval(HEARTBEAT_INTERVAL)
+            .text("heartbeat"),
+    ))
+}
+
+/// Encodes a stream event as an SSE event named after its type.
+fn sse_event(event: &StreamEvent) -> Event {
+    let name = match event {
+        StreamEvent::Chunk { .. } => "chunk",
+        StreamEvent::ToolCall { .. } => "tool_call",
+        StreamEvent::ToolResult { .. } => "tool_result",
+        StreamEvent::Complete { .. } => "complete",
+        StreamEvent::Error { .. } => "error",
+    };
+    let data = serde_json::to_string(event).expect("StreamEvent serializes to JSON");
+    Event::default().event(name).data(data)
+}
+
+fn send_error(err: SendMessageError) -> ConversationApiError {
+    match err {
+        SendMessageError::EmptyContent | SendMessageError::ConversationComplete => {
+            ConversationApiError::BadRequest(err.to_string())
+        }
+        SendMessageError::Forbidden => {
+            ConversationApiError::Forbidden("User does not own this conversation".to_string())
+        }
+        SendMessageError::ComponentNotFound(id) => {
+            ConversationApiError::NotFound("Component".to_string(), id.to_string())
+        }
+        SendMessageError::AIProviderError(_)
+        | SendMessageError::RepositoryError(_)
+        | SendMessageError::DomainError(_) => ConversationApiError::Internal(err.to_string()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::handlers::conversation::MessageId;
+
+    #[tokio::test]
+    async fn events_are_named_after_their_type() {
+        let message_id = MessageId::new();
+        let events = futures::stream::iter(
+            [
+                StreamEvent::Chunk {
+                    message_id,
+                    delta: "Hi".to_string(),
+                },
+                StreamEvent::Complete {
+                    message_id,
+                    full_content: "Hi".to_string(),
+                    usage: None,
+                },
+            ]
+            .map(|event| Ok::<_, Infallible>(sse_event(&event))),
+        );
+
+        let body = Sse::new(events).into_response().into_body();
+        let bytes = axum::body::to_bytes(body, usize::MAX).await.unwrap();
+        let text = String::from_utf8(bytes.to_vec()).unwrap();
+
+        assert!(text.starts_with("event: chunk\ndata: {\"type\":\"chunk\""));
+        assert!(text.contains("event: complete\ndata: {\"type\":\"complete\""));
+    }
+
+    #[test]
+    fn send_errors_map_to_client_errors() {
+        assert!(matches!(
+            send_error(SendMessageError::ConversationComplete),
+            ConversationApiError::BadRequest(_)
+        ));
+        assert!(matches!(
+            send_error(SendMessageError::Forbidden),
+            ConversationApiError::Forbidden(_)
+        ));
+        assert!(matches!(
+            send_error(SendMessageError::AIProviderError("down".to_string())),
+            ConversationApiError::Internal(_)
+        ));
+    }
+}
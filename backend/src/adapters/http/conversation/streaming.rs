@@ -1,8 +1,11 @@
 //! WebSocket streaming message types for conversation endpoints.
 //!
 //! Defines the protocol between server and connected clients for AI streaming:
-//! - Client → Server: SendMessage, CancelStream, Ping
+//! - Client → Server: SendMessage, CancelStream, Ack, Ping
 //! - Server → Client: StreamChunk, StreamComplete, StreamError, Pong, DataExtracted
+//!
+//! `StreamComplete` and `StreamError` end a stream, so they carry a
+//! `delivery_id` the client must acknowledge; see [`super::acks`].
 
 use serde::{Deserialize, Serialize};
 
@@ -21,6 +24,8 @@ pub enum StreamClientMessage {
     SendMessage(SendMessageRequest),
     /// Cancel an in-progress stream.
     CancelStream(CancelStreamRequest),
+    /// Acknowledge a message that carried a `delivery_id`.
+    Ack(AckRequest),
     /// Heartbeat ping.
     Ping,
 }
@@ -43,6 +48,14 @@ pub struct CancelStreamRequest {
     pub message_id: String,
 }
 
+/// Acknowledgement of a delivered message.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub struct AckRequest {
+    /// `delivery_id` of the message received.
+    pub delivery_id: u64,
+}
+
 // ════════════════════════════════════════════════════════════════════════════════
 // Server → Client Messages
 // ════════════════════════════════════════════════════════════════════════════════
@@ -88,6 +101,9 @@ pub struct StreamCompleteMessage {
     /// If agent phase changed.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub phase_transition: Option<PhaseTransition>,
+    /// Connection-scoped ID to acknowledge; repeated on retransmits.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub delivery_id: Option<u64>,
 }
 
 /// Token usage statistics for a streaming response.
@@ -123,6 +139,9 @@ pub struct StreamErrorMessage {
     pub partial_content: Option<String>,
     /// Whether retry is recommended.
     pub recoverable: bool,
+    /// Connection-scoped ID to acknowledge; repeated on retransmits.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub delivery_id: Option<u64>,
 }
 
 /// Error codes for stream errors.
@@ -175,6 +194,25 @@ pub struct DataExtractedMessage {
     pub extracted_at: String,
 }
 
+impl StreamServerMessage {
+    /// Whether the client must acknowledge this message.
+    ///
+    /// Only messages that end a stream need it: a lost chunk is repaired by
+    /// `full_content`, but a lost ending leaves the UI waiting.
+    pub fn requires_ack(&self) -> bool {
+        matches!(self, Self::StreamComplete(_) | Self::StreamError(_))
+    }
+
+    /// Stamp the delivery ID on messages that require acknowledgement.
+    pub fn set_delivery_id(&mut self, id: u64) {
+        match self {
+            Self::StreamComplete(msg) => msg.delivery_id = Some(id),
+            Self::StreamError(msg) => msg.delivery_id = Some(id),
+            _ => {}
+        }
+    }
+}
+
 // ════════════════════════════════════════════════════════════════════════════════
 // Message Validation
 // ════════════════════════════════════════════════════════════════════════════════
@@ -239,6 +277,16 @@ mod tests {
             }
         }
 
+        #[test]
+        fn deserializes_ack() {
+            let json = r#"{"type": "ack", "delivery_id": 7}"#;
+            let msg: StreamClientMessage = serde_json::from_str(json).unwrap();
+            assert!(matches!(
+                msg,
+                StreamClientMessage::Ack(AckRequest { delivery_id: 7 })
+            ));
+        }
+
         #[test]
         fn deserializes_ping() {
             let json = r#"{"type": "ping"}"#;
@@ -276,12 +324,14 @@ mod tests {
                     estimated_cost_cents: 1,
                 },
                 phase_transition: None,
+                delivery_id: None,
             });
 
             let json = serde_json::to_string(&msg).unwrap();
             assert!(json.contains(r#""type":"stream_complete""#));
             assert!(json.contains(r#""full_content":"Hello, world!""#));
             assert!(!json.contains("phase_transition"));
+            assert!(!json.contains("delivery_id"));
         }
 
         #[test]
@@ -299,6 +349,7 @@ mod tests {
                     from_phase: AgentPhase::Intro,
                     to_phase: AgentPhase::Gather,
                 }),
+                delivery_id: None,
             });
 
             let json = serde_json::to_string(&msg).unwrap();
@@ -315,6 +366,7 @@ mod tests {
                 error: "Too many requests".to_string(),
                 partial_content: None,
                 recoverable: true,
+                delivery_id: None,
             });
 
             let json = serde_json::to_string(&msg).unwrap();
@@ -323,6 +375,35 @@ mod tests {
             assert!(json.contains(r#""recoverable":true"#));
         }
 
+        #[test]
+        fn only_stream_endings_carry_delivery_ids() {
+            let mut error = StreamServerMessage::StreamError(StreamErrorMessage {
+                message_id: "abc".to_string(),
+                error_code: StreamErrorCode::Timeout,
+                error: "Timed out".to_string(),
+                partial_content: None,
+                recoverable: true,
+                delivery_id: None,
+            });
+            let mut chunk = StreamServerMessage::StreamChunk(StreamChunkMessage {
+                message_id: "abc".to_string(),
+                delta: "Hi".to_string(),
+                is_final: false,
+            });
+
+            assert!(error.requires_ack());
+            assert!(!chunk.requires_ack());
+            error.set_delivery_id(3);
+            chunk.set_delivery_id(3);
+
+            assert!(serde_json::to_string(&error)
+                .unwrap()
+                .contains(r#""delivery_id":3"#));
+            assert!(!serde_json::to_string(&chunk)
+                .unwrap()
+                .contains("delivery_id"));
+        }
+
         #[test]
         fn serializes_pong() {
             let msg = StreamServerMessage::Pong(StreamPongMessage {
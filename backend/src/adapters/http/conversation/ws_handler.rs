@@ -6,14 +6,19 @@
 //! # Connection Flow
 //! 1. Client requests WebSocket upgrade with auth token
 //! 2. Server validates auth and component ownership (R14, R15)
-//! 3. On success, upgrade connection to WebSocket
+//! 3. On success, upgrade connection to WebSocket, agreeing JSON or
+//!    MessagePack framing (see [`crate::adapters::websocket::codec`])
 //! 4. Client sends SendMessage with user content (R16)
 //! 5. Server streams TokenChunk events (R17)
 //! 6. Server sends StreamComplete when done (R18)
 //! 7. On AI error, sends StreamError (R19)
-//! 8. On disconnect, cleanup resources (R20)
+//! 8. Client acks StreamComplete/StreamError; unacked ones are resent
+//! 9. Server pings periodically and closes idle or long-lived connections
+//!    per the [`ConnectionPolicy`]
+//! 10. On disconnect, cleanup resources (R20)
 
 use std::sync::Arc;
+use std::time::{Duration, Instant};
 
 use axum::{
     extract::{
@@ -26,14 +31,19 @@ use axum::{
 use futures::{SinkExt, StreamExt};
 use serde::Deserialize;
 
+use crate::adapters::websocket::{ConnectionPolicy, WireCodec, SUPPORTED_PROTOCOLS};
 use crate::application::handlers::conversation::{ComponentOwnershipChecker, ConversationRepository};
 use crate::domain::foundation::{ComponentId, ErrorCode, Timestamp, UserId};
 
+use super::acks::AckTracker;
 use super::streaming::{
     SendMessageRequest, StreamChunkMessage, StreamClientMessage, StreamCompleteMessage,
     StreamErrorCode, StreamErrorMessage, StreamPongMessage, StreamServerMessage, StreamTokenUsage,
 };
 
+/// How often to check for unacknowledged messages to resend.
+const RETRANSMIT_CHECK_INTERVAL: Duration = Duration::from_secs(1);
+
 // ════════════════════════════════════════════════════════════════════════════════
 // WebSocket State
 // ════════════════════════════════════════════════════════════════════════════════
@@ -45,6 +55,8 @@ pub struct ConversationWebSocketState {
     pub conversation_repo: Arc<dyn ConversationRepository>,
     /// Checker for component ownership validation.
     pub ownership_checker: Arc<dyn ComponentOwnershipChecker>,
+    /// Heartbeat cadence and idle/lifetime limits.
+    pub policy: ConnectionPolicy,
     // AI provider would be added here for actual streaming
     // pub ai_provider: Arc<dyn AIProvider>,
 }
@@ -58,8 +70,15 @@ impl ConversationWebSocketState {
         Self {
             conversation_repo,
             ownership_checker,
+            policy: ConnectionPolicy::default(),
         }
     }
+
+    /// Override the default heartbeat and idle limits.
+    pub fn with_policy(mut self, policy: ConnectionPolicy) -> Self {
+        self.policy = policy;
+        self
+    }
 }
 
 // ════════════════════════════════════════════════════════════════════════════════
@@ -134,7 +153,10 @@ pub async fn conversation_ws_handler(
     }
 
     // R14: Upgrade to WebSocket
-    ws.on_upgrade(move |socket| handle_conversation_socket(socket, component_id, user_id, state))
+    ws.protocols(SUPPORTED_PROTOCOLS).on_upgrade(move |socket| {
+        let codec = WireCodec::from_protocol(socket.protocol());
+        handle_conversation_socket(socket, codec, component_id, user_id, state)
+    })
 }
 
 // ════════════════════════════════════════════════════════════════════════════════
@@ -144,6 +166,7 @@ pub async fn conversation_ws_handler(
 /// Handle an established WebSocket connection for conversation streaming.
 async fn handle_conversation_socket(
     socket: WebSocket,
+    codec: WireCodec,
     component_id: ComponentId,
     user_id: UserId,
     state: ConversationWebSocketState,
@@ -156,12 +179,47 @@ async fn handle_conversation_socket(
         "WebSocket connection established"
     );
 
-    // Process incoming messages
-    while let Some(result) = receiver.next().await {
+    let mut acks = AckTracker::default();
+    let mut retransmit = tokio::time::interval(RETRANSMIT_CHECK_INTERVAL);
+    let connected_at = Instant::now();
+    let mut last_activity = connected_at;
+    let mut heartbeat = state.policy.heartbeat();
+
+    // Process incoming messages, resending unacknowledged ones meanwhile
+    loop {
+        let result = tokio::select! {
+            result = receiver.next() => match result {
+                Some(result) => result,
+                None => break,
+            },
+            _ = retransmit.tick() => {
+                if resend_due(&mut sender, codec, &mut acks).await.is_err() {
+                    break;
+                }
+                continue;
+            }
+            _ = heartbeat.tick() => {
+                if let Some(reason) = state.policy.check(connected_at, last_activity, Instant::now()) {
+                    tracing::debug!(
+                        component_id = %component_id,
+                        code = reason.code(),
+                        "Closing connection under policy"
+                    );
+                    let _ = sender.send(reason.close_message()).await;
+                    break;
+                }
+                if sender.send(Message::Ping(Vec::new())).await.is_err() {
+                    break;
+                }
+                continue;
+            }
+        };
+        last_activity = Instant::now();
+
         match result {
-            Ok(Message::Text(text)) => {
-                match serde_json::from_str::<StreamClientMessage>(&text) {
-                    Ok(client_msg) => {
+            Ok(frame @ (Message::Text(_) | Message::Binary(_))) => {
+                match WireCodec::decode::<StreamClientMessage>(&frame) {
+                    Some(Ok(client_msg)) => {
                         match client_msg {
                             // R16: Handle user message
                             StreamClientMessage::SendMessage(req) => {
@@ -172,15 +230,16 @@ async fn handle_conversation_socket(
                                         error: e.to_string(),
                                         partial_content: None,
                                         recoverable: false,
+                                        delivery_id: None,
                                     });
-                                    if send_server_message(&mut sender, &error_msg).await.is_err() {
+                                    if send_tracked(&mut sender, codec, &mut acks, error_msg).await.is_err() {
                                         break;
                                     }
                                     continue;
                                 }
 
                                 // R17, R18: Stream AI response
-                                handle_send_message(&mut sender, &req, &component_id, &state).await;
+                                handle_send_message(&mut sender, codec, &mut acks, &req, &component_id, &state).await;
                             }
 
                             // Handle cancel request
@@ -196,26 +255,38 @@ async fn handle_conversation_socket(
                                     error: "Stream cancelled by user".to_string(),
                                     partial_content: None,
                                     recoverable: false,
+                                    delivery_id: None,
                                 });
-                                if send_server_message(&mut sender, &cancelled).await.is_err() {
+                                if send_tracked(&mut sender, codec, &mut acks, cancelled).await.is_err() {
                                     break;
                                 }
                             }
 
+                            // Stop resending an acknowledged message
+                            StreamClientMessage::Ack(req) => {
+                                if !acks.ack(req.delivery_id) {
+                                    tracing::trace!(
+                                        delivery_id = req.delivery_id,
+                                        "Ack for unknown or already acknowledged delivery"
+                                    );
+                                }
+                            }
+
                             // Handle ping
                             StreamClientMessage::Ping => {
                                 let pong = StreamServerMessage::Pong(StreamPongMessage {
                                     timestamp: Timestamp::now().as_datetime().to_rfc3339(),
                                 });
-                                if send_server_message(&mut sender, &pong).await.is_err() {
+                                if send_server_message(&mut sender, codec, &pong).await.is_err() {
                                     break;
                                 }
                             }
                         }
                     }
-                    Err(e) => {
+                    Some(Err(e)) => {
                         tracing::warn!("Failed to parse client message: {}", e);
                     }
+                    None => {}
                 }
             }
             Ok(Message::Close(_)) => {
@@ -252,6 +323,8 @@ async fn handle_conversation_socket(
 /// Handle a SendMessage request by streaming AI response.
 async fn handle_send_message<S>(
     sender: &mut S,
+    codec: WireCodec,
+    acks: &mut AckTracker,
     req: &SendMessageRequest,
     component_id: &ComponentId,
     _state: &ConversationWebSocketState,
@@ -287,7 +360,7 @@ async fn handle_send_message<S>(
             is_final,
         });
 
-        if let Err(e) = send_server_message(sender, &chunk_msg).await {
+        if let Err(e) = send_server_message(sender, codec, &chunk_msg).await {
             tracing::debug!("Failed to send chunk: {:?}", e);
             // R19: Send error on failure
             let error_msg = StreamServerMessage::StreamError(StreamErrorMessage {
@@ -296,8 +369,9 @@ async fn handle_send_message<S>(
                 error: "Failed to stream response".to_string(),
                 partial_content: Some(full_content),
                 recoverable: true,
+                delivery_id: None,
             });
-            let _ = send_server_message(sender, &error_msg).await;
+            let _ = send_tracked(sender, codec, acks, error_msg).await;
             return;
         }
 
@@ -316,9 +390,11 @@ async fn handle_send_message<S>(
             estimated_cost_cents: 1,
         },
         phase_transition: None,
+        delivery_id: None,
     });
 
-    if let Err(e) = send_server_message(sender, &complete_msg).await {
+    // Tracked even if this send fails, so it is resent on the next check
+    if let Err(e) = send_tracked(sender, codec, acks, complete_msg).await {
         tracing::debug!("Failed to send complete message: {:?}", e);
     }
 }
@@ -346,13 +422,47 @@ async fn validate_auth_token(token: &Option<String>) -> Result<UserId, &'static
     }
 }
 
-/// Send a server message over the WebSocket.
-async fn send_server_message<S>(sender: &mut S, msg: &StreamServerMessage) -> Result<(), S::Error>
+/// Send a server message over the WebSocket in the connection's encoding.
+async fn send_server_message<S>(
+    sender: &mut S,
+    codec: WireCodec,
+    msg: &StreamServerMessage,
+) -> Result<(), S::Error>
+where
+    S: SinkExt<Message> + Unpin,
+{
+    sender.send(codec.encode(msg)).await
+}
+
+/// Send a message, first registering it for acknowledgement if it ends a
+/// stream.
+async fn send_tracked<S>(
+    sender: &mut S,
+    codec: WireCodec,
+    acks: &mut AckTracker,
+    msg: StreamServerMessage,
+) -> Result<(), S::Error>
 where
     S: SinkExt<Message> + Unpin,
 {
-    let json = serde_json::to_string(msg).expect("Failed to serialize message");
-    sender.send(Message::Text(json)).await
+    let msg = acks.track(msg, Instant::now());
+    send_server_message(sender, codec, &msg).await
+}
+
+/// Resend messages whose acknowledgement is overdue.
+async fn resend_due<S>(
+    sender: &mut S,
+    codec: WireCodec,
+    acks: &mut AckTracker,
+) -> Result<(), S::Error>
+where
+    S: SinkExt<Message> + Unpin,
+{
+    for msg in acks.due(Instant::now()) {
+        tracing::debug!("Resending unacknowledged stream message");
+        send_server_message(sender, codec, &msg).await?;
+    }
+    Ok(())
 }
 
 /// Cleanup resources when connection closes (R20).
@@ -3,7 +3,9 @@
 //! These types decouple the HTTP API from domain types, allowing independent evolution.
 
 use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
 
+use crate::application::handlers::cycle::ComponentBatchOperation;
 use crate::domain::foundation::ComponentType;
 
 // ════════════════════════════════════════════════════════════════════════════════
@@ -11,32 +13,74 @@ use crate::domain::foundation::ComponentType;
 // ════════════════════════════════════════════════════════════════════════════════
 
 /// Request to create a new cycle.
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, ToSchema)]
 pub struct CreateCycleRequest {
     pub session_id: String,
 }
 
 /// Request to branch a cycle.
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, ToSchema)]
 pub struct BranchCycleRequest {
     pub branch_point: ComponentType,
     #[serde(default)]
     pub branch_label: Option<String>,
 }
 
+/// One command within a component batch.
+#[derive(Debug, Clone, Deserialize, ToSchema)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ComponentBatchOperationRequest {
+    /// Replace a component's output.
+    UpdateOutput {
+        component_type: ComponentType,
+        output: serde_json::Value,
+    },
+    /// Complete a component.
+    Complete { component_type: ComponentType },
+}
+
+impl From<ComponentBatchOperationRequest> for ComponentBatchOperation {
+    fn from(request: ComponentBatchOperationRequest) -> Self {
+        match request {
+            ComponentBatchOperationRequest::UpdateOutput {
+                component_type,
+                output,
+            } => ComponentBatchOperation::UpdateOutput {
+                component_type,
+                output,
+            },
+            ComponentBatchOperationRequest::Complete { component_type } => {
+                ComponentBatchOperation::Complete { component_type }
+            }
+        }
+    }
+}
+
+/// Request to apply several component commands atomically.
+#[derive(Debug, Clone, Deserialize, ToSchema)]
+pub struct ComponentBatchRequest {
+    /// Operations to apply, in order.
+    pub operations: Vec<ComponentBatchOperationRequest>,
+}
+
 // ════════════════════════════════════════════════════════════════════════════════
 // Response DTOs
 // ════════════════════════════════════════════════════════════════════════════════
 
 /// Response for cycle command operations.
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Serialize, ToSchema)]
 pub struct CycleCommandResponse {
     pub cycle_id: String,
     pub message: String,
+    /// Aggregate version after the command, for reconciling optimistic updates.
+    pub version: u64,
+    /// Ids of the events emitted by the command.
+    pub event_ids: Vec<String>,
 }
 
 /// Standard error response.
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Serialize, ToSchema)]
+#[schema(as = CycleErrorResponse)]
 pub struct ErrorResponse {
     pub code: String,
     pub message: String,
@@ -109,9 +153,30 @@ mod tests {
         let response = CycleCommandResponse {
             cycle_id: "abc-123".to_string(),
             message: "Created".to_string(),
+            version: 1,
+            event_ids: vec!["evt-1".to_string()],
         };
         let json = serde_json::to_string(&response).unwrap();
         assert!(json.contains("abc-123"));
         assert!(json.contains("Created"));
+        assert!(json.contains(r#""version":1"#));
+        assert!(json.contains(r#""event_ids":["evt-1"]"#));
+    }
+
+    #[test]
+    fn component_batch_request_deserializes_tagged_operations() {
+        let json = r#"{"operations": [
+            {"type": "update_output", "component_type": "issue_raising", "output": {"a": 1}},
+            {"type": "complete", "component_type": "issue_raising"}
+        ]}"#;
+        let request: ComponentBatchRequest = serde_json::from_str(json).unwrap();
+
+        assert_eq!(request.operations.len(), 2);
+        assert!(matches!(
+            ComponentBatchOperation::from(request.operations[1].clone()),
+            ComponentBatchOperation::Complete {
+                component_type: ComponentType::IssueRaising
+            }
+        ));
     }
 }
@@ -5,6 +5,7 @@
 //! Currently implements handlers for:
 //! - Create cycle
 //! - Branch cycle
+//! - Apply a batch of component commands
 //!
 //! Additional handlers (archive, complete, component operations, queries) will be
 //! added as the corresponding application layer handlers are implemented.
@@ -16,15 +17,17 @@ use axum::http::StatusCode;
 use axum::response::IntoResponse;
 
 use crate::application::handlers::cycle::{
+    ApplyComponentBatchCommand, ApplyComponentBatchError, ApplyComponentBatchHandler,
     BranchCycleCommand, BranchCycleError, BranchCycleHandler, CreateCycleCommand, CreateCycleError,
-    CreateCycleHandler, GetCycleTreeHandler, GetCycleTreeQuery, GetProactTreeViewHandler,
-    GetProactTreeViewQuery,
+    CreateCycleHandler, GetCycleGraphHandler, GetCycleGraphQuery, GetCycleTreeHandler,
+    GetCycleTreeQuery, GetProactTreeViewHandler, GetProactTreeViewQuery,
 };
 use crate::domain::foundation::{CommandMetadata, CycleId, SessionId, UserId};
 use crate::ports::{AccessChecker, CycleReader, CycleRepository, EventPublisher, SessionRepository};
 
 use super::dto::{
-    BranchCycleRequest, CreateCycleRequest, CycleCommandResponse, ErrorResponse,
+    BranchCycleRequest, ComponentBatchRequest, CreateCycleRequest, CycleCommandResponse,
+    ErrorResponse,
 };
 
 // ════════════════════════════════════════════════════════════════════════════════
@@ -59,10 +62,22 @@ impl CycleAppState {
         )
     }
 
+    pub fn apply_component_batch_handler(&self) -> ApplyComponentBatchHandler {
+        ApplyComponentBatchHandler::new(
+            self.cycle_repository.clone(),
+            self.session_repository.clone(),
+            self.event_publisher.clone(),
+        )
+    }
+
     pub fn get_cycle_tree_handler(&self) -> GetCycleTreeHandler {
         GetCycleTreeHandler::new(self.cycle_reader.clone())
     }
 
+    pub fn get_cycle_graph_handler(&self) -> GetCycleGraphHandler {
+        GetCycleGraphHandler::new(self.cycle_reader.clone())
+    }
+
     pub fn get_proact_tree_view_handler(&self) -> GetProactTreeViewHandler {
         GetProactTreeViewHandler::new(self.cycle_reader.clone())
     }
@@ -123,6 +138,18 @@ where
 // ════════════════════════════════════════════════════════════════════════════════
 
 /// POST /api/cycles - Create a new cycle
+#[utoipa::path(
+    post,
+    path = "/api/cycles",
+    tag = "cycles",
+    request_body = CreateCycleRequest,
+    responses(
+        (status = 201, description = "Cycle created", body = CycleCommandResponse),
+        (status = 400, description = "Invalid session ID", body = ErrorResponse),
+        (status = 403, description = "Cycle limit reached", body = ErrorResponse),
+        (status = 404, description = "Session not found", body = ErrorResponse),
+    )
+)]
 pub async fn create_cycle(
     State(state): State<CycleAppState>,
     user: AuthenticatedUser,
@@ -142,12 +169,27 @@ pub async fn create_cycle(
     let response = CycleCommandResponse {
         cycle_id: result.cycle.id().to_string(),
         message: "Cycle created successfully".to_string(),
+        version: result.version,
+        event_ids: result.event_ids.iter().map(ToString::to_string).collect(),
     };
 
     Ok((StatusCode::CREATED, Json(response)))
 }
 
 /// POST /api/cycles/:id/branch - Branch a cycle
+#[utoipa::path(
+    post,
+    path = "/api/cycles/{cycle_id}/branch",
+    tag = "cycles",
+    params(("cycle_id" = String, Path, description = "Cycle to branch from")),
+    request_body = BranchCycleRequest,
+    responses(
+        (status = 201, description = "Branch created", body = CycleCommandResponse),
+        (status = 400, description = "Invalid cycle ID or branch point", body = ErrorResponse),
+        (status = 403, description = "Branch limit reached", body = ErrorResponse),
+        (status = 404, description = "Cycle not found", body = ErrorResponse),
+    )
+)]
 pub async fn branch_cycle(
     State(state): State<CycleAppState>,
     Path(cycle_id): Path<String>,
@@ -171,16 +213,80 @@ pub async fn branch_cycle(
     let response = CycleCommandResponse {
         cycle_id: result.branch.id().to_string(),
         message: format!("Branched at {:?}", result.event.branch_point),
+        version: result.version,
+        event_ids: result.event_ids.iter().map(ToString::to_string).collect(),
     };
 
     Ok((StatusCode::CREATED, Json(response)))
 }
 
+/// Last path segment of the component batch endpoint.
+const COMPONENT_BATCH_METHOD: &str = "components:batch";
+
+/// POST /api/cycles/:id/components:batch - Apply component commands atomically
+///
+/// Operations are applied in order and saved together; if any is rejected,
+/// none are saved.
+#[utoipa::path(
+    post,
+    path = "/api/cycles/{cycle_id}/components:batch",
+    tag = "cycles",
+    params(("cycle_id" = String, Path, description = "Cycle ID")),
+    request_body = ComponentBatchRequest,
+    responses(
+        (status = 200, description = "Every operation applied", body = CycleCommandResponse),
+        (status = 400, description = "Invalid batch, or an operation was rejected", body = ErrorResponse),
+        (status = 403, description = "Not the session owner", body = ErrorResponse),
+        (status = 404, description = "Cycle not found", body = ErrorResponse),
+    )
+)]
+pub async fn apply_component_batch(
+    State(state): State<CycleAppState>,
+    Path((cycle_id, method)): Path<(String, String)>,
+    user: AuthenticatedUser,
+    Json(request): Json<ComponentBatchRequest>,
+) -> Result<impl IntoResponse, CycleApiError> {
+    if method != COMPONENT_BATCH_METHOD {
+        return Err(CycleApiError::NotFound(format!("Route not found: {}", method)));
+    }
+    let cycle_id: CycleId = cycle_id
+        .parse()
+        .map_err(|_| CycleApiError::BadRequest("Invalid cycle ID format".to_string()))?;
+
+    let handler = state.apply_component_batch_handler();
+    let cmd = ApplyComponentBatchCommand {
+        cycle_id,
+        operations: request.operations.into_iter().map(Into::into).collect(),
+    };
+    let metadata = CommandMetadata::new(user.user_id);
+
+    let result = handler.handle(cmd, metadata).await?;
+
+    let response = CycleCommandResponse {
+        cycle_id: cycle_id.to_string(),
+        message: format!("Applied {} operations", result.event.operations.len()),
+        version: result.version,
+        event_ids: result.event_ids.iter().map(ToString::to_string).collect(),
+    };
+
+    Ok((StatusCode::OK, Json(response)))
+}
+
 // ════════════════════════════════════════════════════════════════════════════════
 // Query Handlers (GET endpoints)
 // ════════════════════════════════════════════════════════════════════════════════
 
 /// GET /api/sessions/:session_id/cycles/tree - Get cycle tree
+#[utoipa::path(
+    get,
+    path = "/api/sessions/{session_id}/cycles/tree",
+    tag = "cycles",
+    params(("session_id" = String, Path, description = "Session ID")),
+    responses(
+        (status = 200, description = "Root cycle with its branches, or null", body = Option<crate::ports::CycleTreeNode>),
+        (status = 400, description = "Invalid session ID", body = ErrorResponse),
+    )
+)]
 pub async fn get_cycle_tree(
     State(state): State<CycleAppState>,
     Path(session_id): Path<String>,
@@ -197,7 +303,44 @@ pub async fn get_cycle_tree(
     Ok((StatusCode::OK, Json(result)))
 }
 
+/// GET /api/sessions/:session_id/cycle-graph - Get layout-ready branch map
+#[utoipa::path(
+    get,
+    path = "/api/sessions/{session_id}/cycle-graph",
+    tag = "cycles",
+    params(("session_id" = String, Path, description = "Session ID")),
+    responses(
+        (status = 200, description = "Layout-ready branch map, or null", body = Option<crate::application::handlers::cycle::CycleGraph>),
+        (status = 400, description = "Invalid session ID", body = ErrorResponse),
+    )
+)]
+pub async fn get_cycle_graph(
+    State(state): State<CycleAppState>,
+    Path(session_id): Path<String>,
+    _user: AuthenticatedUser,
+) -> Result<impl IntoResponse, CycleApiError> {
+    let session_id: SessionId = session_id
+        .parse()
+        .map_err(|_| CycleApiError::BadRequest("Invalid session ID format".to_string()))?;
+
+    let handler = state.get_cycle_graph_handler();
+    let query = GetCycleGraphQuery { session_id };
+
+    let result = handler.handle(query).await?;
+    Ok((StatusCode::OK, Json(result)))
+}
+
 /// GET /api/sessions/:session_id/cycles/proact-tree - Get PrOACT tree visualization
+#[utoipa::path(
+    get,
+    path = "/api/sessions/{session_id}/cycles/proact-tree",
+    tag = "cycles",
+    params(("session_id" = String, Path, description = "Session ID")),
+    responses(
+        (status = 200, description = "PrOACT tree, or null", body = Option<crate::domain::cycle::CycleTreeNode>),
+        (status = 400, description = "Invalid session ID", body = ErrorResponse),
+    )
+)]
 pub async fn get_proact_tree_view(
     State(state): State<CycleAppState>,
     Path(session_id): Path<String>,
@@ -256,9 +399,32 @@ impl From<BranchCycleError> for CycleApiError {
     }
 }
 
+impl From<ApplyComponentBatchError> for CycleApiError {
+    fn from(err: ApplyComponentBatchError) -> Self {
+        match err {
+            ApplyComponentBatchError::CycleNotFound(id) => {
+                CycleApiError::NotFound(format!("Cycle not found: {}", id))
+            }
+            ApplyComponentBatchError::Forbidden => {
+                CycleApiError::Forbidden("User does not own this cycle".to_string())
+            }
+            ApplyComponentBatchError::InvalidBatch(_)
+            | ApplyComponentBatchError::OperationFailed { .. } => {
+                CycleApiError::BadRequest(err.to_string())
+            }
+            ApplyComponentBatchError::Domain(e) => e.into(),
+        }
+    }
+}
+
 impl From<crate::domain::foundation::DomainError> for CycleApiError {
     fn from(err: crate::domain::foundation::DomainError) -> Self {
-        CycleApiError::Internal(err.to_string())
+        match err.code {
+            crate::domain::foundation::ErrorCode::ConcurrentModification => {
+                CycleApiError::Conflict(err.to_string())
+            }
+            _ => CycleApiError::Internal(err.to_string()),
+        }
     }
 }
 
@@ -295,7 +461,7 @@ impl IntoResponse for CycleApiError {
 mod tests {
     use super::*;
     use crate::domain::cycle::Cycle;
-    use crate::domain::foundation::{ComponentType, DomainError};
+    use crate::domain::foundation::{ComponentType, DomainError, OrganizationId};
     use crate::domain::membership::{MembershipTier, TierLimits};
     use crate::domain::session::Session;
     use crate::ports::{
@@ -303,6 +469,7 @@ mod tests {
         CycleTreeNode, CycleView, UsageStats,
     };
     use async_trait::async_trait;
+    use std::collections::HashMap;
     use std::sync::Mutex;
 
     // ════════════════════════════════════════════════════════════════════════════
@@ -450,6 +617,13 @@ mod tests {
             Ok(vec![])
         }
 
+        async fn list_by_organization(
+            &self,
+            _organization_id: &OrganizationId,
+        ) -> Result<HashMap<SessionId, Vec<CycleSummary>>, DomainError> {
+            Ok(HashMap::new())
+        }
+
         async fn get_tree(
             &self,
             _session_id: &SessionId,
@@ -551,5 +725,34 @@ mod tests {
         let _ = state.branch_cycle_handler();
         let _ = state.get_cycle_tree_handler();
         let _ = state.get_proact_tree_view_handler();
+        let _ = state.apply_component_batch_handler();
+    }
+
+    #[test]
+    fn rejected_batch_operations_map_to_400() {
+        let err: CycleApiError = ApplyComponentBatchError::OperationFailed {
+            index: 1,
+            error: DomainError::new(
+                crate::domain::foundation::ErrorCode::InvalidStateTransition,
+                "Component not started",
+            ),
+        }
+        .into();
+
+        assert!(matches!(&err, CycleApiError::BadRequest(msg) if msg.contains("Operation 1")));
+        assert_eq!(err.into_response().status(), StatusCode::BAD_REQUEST);
+    }
+
+    #[tokio::test]
+    async fn other_custom_methods_are_not_found() {
+        let result = apply_component_batch(
+            State(test_state()),
+            Path((CycleId::new().to_string(), "components:purge".to_string())),
+            _test_user(),
+            Json(ComponentBatchRequest { operations: vec![] }),
+        )
+        .await;
+
+        assert!(matches!(result, Err(CycleApiError::NotFound(_))));
     }
 }
@@ -6,7 +6,8 @@ use axum::routing::{get, post};
 use axum::Router;
 
 use super::handlers::{
-    branch_cycle, create_cycle, get_cycle_tree, get_proact_tree_view, CycleAppState,
+    apply_component_batch, branch_cycle, create_cycle, get_cycle_graph, get_cycle_tree,
+    get_proact_tree_view, CycleAppState,
 };
 
 /// Creates routes for cycle endpoints.
@@ -14,6 +15,7 @@ use super::handlers::{
 /// Current endpoints:
 /// - POST /api/cycles - Create a new cycle
 /// - POST /api/cycles/{cycle_id}/branch - Branch an existing cycle
+/// - POST /api/cycles/{cycle_id}/components:batch - Apply component commands atomically
 ///
 /// Future endpoints (once handlers are implemented):
 /// - GET /api/cycles/{cycle_id} - Get cycle details
@@ -27,6 +29,9 @@ pub fn cycle_routes() -> Router<CycleAppState> {
     Router::new()
         .route("/", post(create_cycle))
         .route("/{cycle_id}/branch", post(branch_cycle))
+        // The router reads `:batch` as a parameter, so the whole segment is
+        // captured and the handler checks it
+        .route("/:cycle_id/:method", post(apply_component_batch))
 }
 
 /// Creates routes for session-related cycle queries.
@@ -34,10 +39,12 @@ pub fn cycle_routes() -> Router<CycleAppState> {
 /// Current endpoints:
 /// - GET /api/sessions/{session_id}/cycles/tree - Get cycle tree
 /// - GET /api/sessions/{session_id}/cycles/proact-tree - Get PrOACT tree visualization
+/// - GET /api/sessions/{session_id}/cycle-graph - Get layout-ready branch map
 pub fn session_cycle_routes() -> Router<CycleAppState> {
     Router::new()
         .route("/:session_id/cycles/tree", get(get_cycle_tree))
         .route("/:session_id/cycles/proact-tree", get(get_proact_tree_view))
+        .route("/:session_id/cycle-graph", get(get_cycle_graph))
 }
 
 /// Combined router with all cycle routes.
@@ -7,18 +7,22 @@
 pub use crate::domain::dashboard::{
     AlternativeSummary, CellColor, CellSummary, CompactConsequencesTable, ComparisonDifference,
     ComparisonSummary, ComponentComparisonSummary, ComponentDetailView, CycleComparison,
-    CycleComparisonItem, CycleProgressSnapshot, DashboardOverview, DifferenceSignificance,
-    ObjectiveSummary, RecommendationSummary,
+    CycleComparisonItem, CycleProgressSnapshot, DashboardOverview, DecisionTreeNodeView,
+    DecisionTreeView, DifferenceSignificance, GroupAlternativeRow, GroupComparisonView,
+    GroupDissentView, GroupObjectiveRow, ObjectiveSummary, ParticipantDissentRow,
+    RecommendationSummary, WeightedComparisonView,
 };
 
 use serde::Serialize;
+use utoipa::ToSchema;
 
 // ════════════════════════════════════════════════════════════════════════════════
 // Response DTOs
 // ════════════════════════════════════════════════════════════════════════════════
 
 /// Standard error response.
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Serialize, ToSchema)]
+#[schema(as = DashboardErrorResponse)]
 pub struct ErrorResponse {
     pub code: String,
     pub message: String,
@@ -8,15 +8,21 @@ use axum::extract::{Json, Path, Query, State};
 use axum::http::StatusCode;
 use axum::response::IntoResponse;
 use serde::Deserialize;
+use utoipa::IntoParams;
 
 use crate::application::handlers::{
     CompareCyclesHandler, CompareCyclesQuery, GetComponentDetailHandler, GetComponentDetailQuery,
-    GetDashboardOverviewHandler, GetDashboardOverviewQuery,
+    GetDashboardOverviewHandler, GetDashboardOverviewQuery, GetDecisionTreesHandler,
+    GetDecisionTreesQuery, GetGroupComparisonHandler, GetGroupComparisonQuery,
+    GetWeightedComparisonHandler, GetWeightedComparisonQuery,
 };
 use crate::domain::foundation::{ComponentType, CycleId, SessionId, UserId};
-use crate::ports::{DashboardError, DashboardReader};
+use crate::ports::{DashboardError, DashboardReader, DecisionTreeRepository, UsageTracker};
 
-use super::dto::{ComponentDetailView, CycleComparison, DashboardOverview, ErrorResponse};
+use super::dto::{
+    ComponentDetailView, CycleComparison, DashboardOverview, DecisionTreeView, ErrorResponse,
+    GroupComparisonView, WeightedComparisonView,
+};
 
 // ════════════════════════════════════════════════════════════════════════════════
 // Error Type
@@ -83,6 +89,9 @@ impl From<DashboardError> for DashboardApiError {
 #[derive(Clone)]
 pub struct DashboardAppState {
     pub dashboard_reader: Arc<dyn DashboardReader>,
+    /// Source of per-cycle AI cost rollups for component detail (optional).
+    pub usage_tracker: Option<Arc<dyn UsageTracker>>,
+    pub decision_tree_repository: Arc<dyn DecisionTreeRepository>,
 }
 
 impl DashboardAppState {
@@ -91,12 +100,28 @@ impl DashboardAppState {
     }
 
     pub fn get_component_detail_handler(&self) -> GetComponentDetailHandler {
-        GetComponentDetailHandler::new(self.dashboard_reader.clone())
+        let handler = GetComponentDetailHandler::new(self.dashboard_reader.clone());
+        match &self.usage_tracker {
+            Some(tracker) => handler.with_usage_tracker(tracker.clone()),
+            None => handler,
+        }
     }
 
     pub fn compare_cycles_handler(&self) -> CompareCyclesHandler {
         CompareCyclesHandler::new(self.dashboard_reader.clone())
     }
+
+    pub fn weighted_comparison_handler(&self) -> GetWeightedComparisonHandler {
+        GetWeightedComparisonHandler::new(self.dashboard_reader.clone())
+    }
+
+    pub fn group_comparison_handler(&self) -> GetGroupComparisonHandler {
+        GetGroupComparisonHandler::new(self.dashboard_reader.clone())
+    }
+
+    pub fn decision_trees_handler(&self) -> GetDecisionTreesHandler {
+        GetDecisionTreesHandler::new(self.decision_tree_repository.clone())
+    }
 }
 
 // ════════════════════════════════════════════════════════════════════════════════
@@ -154,19 +179,26 @@ where
 // ════════════════════════════════════════════════════════════════════════════════
 
 /// Query parameters for dashboard overview endpoint.
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, IntoParams)]
 pub struct DashboardOverviewParams {
     /// Optional cycle ID to view specific cycle.
     pub cycle_id: Option<String>,
 }
 
 /// Query parameters for cycle comparison endpoint.
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, IntoParams)]
 pub struct CompareCyclesParams {
     /// Comma-separated list of cycle IDs.
     pub cycles: String,
 }
 
+/// Query parameters for group comparison endpoint.
+#[derive(Debug, Deserialize, IntoParams)]
+pub struct GroupComparisonParams {
+    /// Score (0-100) at which a participant approves an alternative.
+    pub approval_threshold: Option<f64>,
+}
+
 // ════════════════════════════════════════════════════════════════════════════════
 // Handlers
 // ════════════════════════════════════════════════════════════════════════════════
@@ -174,6 +206,18 @@ pub struct CompareCyclesParams {
 /// GET /api/sessions/:session_id/dashboard
 ///
 /// Returns the main dashboard overview for a session.
+#[utoipa::path(
+    get,
+    path = "/api/sessions/{session_id}/dashboard",
+    tag = "dashboard",
+    params(("session_id" = String, Path, description = "Session ID"), DashboardOverviewParams),
+    responses(
+        (status = 200, description = "Dashboard overview", body = DashboardOverview),
+        (status = 400, description = "Invalid session or cycle ID", body = ErrorResponse),
+        (status = 403, description = "Not the session owner", body = ErrorResponse),
+        (status = 404, description = "Session not found", body = ErrorResponse),
+    )
+)]
 pub async fn get_dashboard_overview(
     State(state): State<DashboardAppState>,
     Path(session_id_str): Path<String>,
@@ -212,6 +256,18 @@ pub async fn get_dashboard_overview(
 /// GET /api/cycles/:cycle_id/components/:component_type/detail
 ///
 /// Returns detailed view of a specific component.
+#[utoipa::path(
+    get,
+    path = "/api/cycles/{cycle_id}/components/{component_type}/detail",
+    tag = "dashboard",
+    params(("cycle_id" = String, Path, description = "Cycle ID"), ("component_type" = ComponentType, Path, description = "Component to show")),
+    responses(
+        (status = 200, description = "Component detail", body = ComponentDetailView),
+        (status = 400, description = "Invalid cycle ID", body = ErrorResponse),
+        (status = 403, description = "Not the session owner", body = ErrorResponse),
+        (status = 404, description = "Cycle or component not found", body = ErrorResponse),
+    )
+)]
 pub async fn get_component_detail(
     State(state): State<DashboardAppState>,
     Path((cycle_id_str, component_type)): Path<(String, ComponentType)>,
@@ -238,6 +294,17 @@ pub async fn get_component_detail(
 /// GET /api/sessions/:session_id/compare?cycles=id1,id2
 ///
 /// Returns comparison of multiple cycles.
+#[utoipa::path(
+    get,
+    path = "/api/sessions/{session_id}/compare",
+    tag = "dashboard",
+    params(("session_id" = String, Path, description = "Session ID"), CompareCyclesParams),
+    responses(
+        (status = 200, description = "Side-by-side comparison", body = CycleComparison),
+        (status = 400, description = "Missing or invalid cycle IDs", body = ErrorResponse),
+        (status = 403, description = "Not the session owner", body = ErrorResponse),
+    )
+)]
 pub async fn compare_cycles(
     State(state): State<DashboardAppState>,
     Path(_session_id_str): Path<String>,
@@ -275,3 +342,116 @@ pub async fn compare_cycles(
     Ok(Json(comparison))
 }
 
+/// GET /api/cycles/:cycle_id/comparison/weighted
+///
+/// Returns weighted scores of the cycle's alternatives with per-objective
+/// contributions.
+#[utoipa::path(
+    get,
+    path = "/api/cycles/{cycle_id}/comparison/weighted",
+    tag = "dashboard",
+    params(("cycle_id" = String, Path, description = "Cycle ID")),
+    responses(
+        (status = 200, description = "Weighted scores by alternative", body = WeightedComparisonView),
+        (status = 400, description = "Invalid cycle ID", body = ErrorResponse),
+        (status = 403, description = "Not the session owner", body = ErrorResponse),
+        (status = 404, description = "Cycle, objectives or consequences not found", body = ErrorResponse),
+    )
+)]
+pub async fn get_weighted_comparison(
+    State(state): State<DashboardAppState>,
+    Path(cycle_id_str): Path<String>,
+    user: AuthenticatedUser,
+) -> Result<Json<WeightedComparisonView>, DashboardApiError> {
+    // Parse cycle_id
+    let cycle_id: CycleId = cycle_id_str
+        .parse()
+        .map_err(|_| DashboardApiError::BadRequest("Invalid cycle ID format".to_string()))?;
+
+    // Execute query
+    let query = GetWeightedComparisonQuery {
+        cycle_id,
+        user_id: user.user_id,
+    };
+
+    let handler = state.weighted_comparison_handler();
+    let comparison = handler.handle(query).await?;
+
+    Ok(Json(comparison))
+}
+
+/// GET /api/cycles/:cycle_id/comparison/group?approval_threshold=60
+///
+/// Returns participants' preferences aggregated by Borda count, approval
+/// voting, and weight averaging, with a dissent report.
+#[utoipa::path(
+    get,
+    path = "/api/cycles/{cycle_id}/comparison/group",
+    tag = "dashboard",
+    params(("cycle_id" = String, Path, description = "Cycle ID"), GroupComparisonParams),
+    responses(
+        (status = 200, description = "Group view with dissent report", body = GroupComparisonView),
+        (status = 400, description = "Invalid cycle ID or approval threshold", body = ErrorResponse),
+        (status = 403, description = "Not the session owner", body = ErrorResponse),
+        (status = 404, description = "Cycle, tradeoffs or consequences not found", body = ErrorResponse),
+    )
+)]
+pub async fn get_group_comparison(
+    State(state): State<DashboardAppState>,
+    Path(cycle_id_str): Path<String>,
+    Query(params): Query<GroupComparisonParams>,
+    user: AuthenticatedUser,
+) -> Result<Json<GroupComparisonView>, DashboardApiError> {
+    // Parse cycle_id
+    let cycle_id: CycleId = cycle_id_str
+        .parse()
+        .map_err(|_| DashboardApiError::BadRequest("Invalid cycle ID format".to_string()))?;
+
+    // Execute query
+    let query = GetGroupComparisonQuery {
+        cycle_id,
+        user_id: user.user_id,
+        approval_threshold: params.approval_threshold,
+    };
+
+    let handler = state.group_comparison_handler();
+    let comparison = handler.handle(query).await?;
+
+    Ok(Json(comparison))
+}
+
+/// GET /api/cycles/:cycle_id/decision-trees
+///
+/// Returns the user's decision trees for the cycle, laid out for rendering
+/// with expected values and the optimal strategy.
+#[utoipa::path(
+    get,
+    path = "/api/cycles/{cycle_id}/decision-trees",
+    tag = "dashboard",
+    params(("cycle_id" = String, Path, description = "Cycle ID")),
+    responses(
+        (status = 200, description = "Decision trees, oldest first", body = Vec<DecisionTreeView>),
+        (status = 400, description = "Invalid cycle ID", body = ErrorResponse),
+    )
+)]
+pub async fn get_decision_trees(
+    State(state): State<DashboardAppState>,
+    Path(cycle_id_str): Path<String>,
+    user: AuthenticatedUser,
+) -> Result<Json<Vec<DecisionTreeView>>, DashboardApiError> {
+    // Parse cycle_id
+    let cycle_id: CycleId = cycle_id_str
+        .parse()
+        .map_err(|_| DashboardApiError::BadRequest("Invalid cycle ID format".to_string()))?;
+
+    // Execute query
+    let query = GetDecisionTreesQuery {
+        cycle_id,
+        user_id: user.user_id,
+    };
+
+    let handler = state.decision_trees_handler();
+    let trees = handler.handle(query).await?;
+
+    Ok(Json(trees))
+}
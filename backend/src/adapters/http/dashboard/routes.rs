@@ -3,7 +3,10 @@
 use axum::routing::get;
 use axum::Router;
 
-use super::handlers::{compare_cycles, get_component_detail, get_dashboard_overview, DashboardAppState};
+use super::handlers::{
+    compare_cycles, get_component_detail, get_dashboard_overview, get_decision_trees,
+    get_group_comparison, get_weighted_comparison, DashboardAppState,
+};
 
 /// Creates the dashboard router with all routes.
 pub fn dashboard_routes(state: DashboardAppState) -> Router {
@@ -14,6 +17,12 @@ pub fn dashboard_routes(state: DashboardAppState) -> Router {
         .route("/api/cycles/:cycle_id/components/:component_type/detail", get(get_component_detail))
         // GET /api/sessions/:session_id/compare
         .route("/api/sessions/:session_id/compare", get(compare_cycles))
+        // GET /api/cycles/:cycle_id/comparison/weighted
+        .route("/api/cycles/:cycle_id/comparison/weighted", get(get_weighted_comparison))
+        // GET /api/cycles/:cycle_id/comparison/group
+        .route("/api/cycles/:cycle_id/comparison/group", get(get_group_comparison))
+        // GET /api/cycles/:cycle_id/decision-trees
+        .route("/api/cycles/:cycle_id/decision-trees", get(get_decision_trees))
         .with_state(state)
 }
 
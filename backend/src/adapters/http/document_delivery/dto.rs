@@ -0,0 +1,127 @@
+//! HTTP DTOs for document delivery endpoints.
+
+use serde::{Deserialize, Serialize};
+
+use crate::domain::document::{DeliveryStatus, DocumentDelivery, DocumentEmailPreference};
+use crate::domain::foundation::Timestamp;
+
+// ════════════════════════════════════════════════════════════════════════════
+// Request DTOs
+// ════════════════════════════════════════════════════════════════════════════
+
+/// Request to turn document emails on or off.
+#[derive(Debug, Clone, Deserialize)]
+pub struct DocumentEmailPreferenceRequest {
+    pub enabled: bool,
+}
+
+// ════════════════════════════════════════════════════════════════════════════
+// Response DTOs
+// ════════════════════════════════════════════════════════════════════════════
+
+/// The user's document email preference.
+#[derive(Debug, Clone, Serialize)]
+pub struct DocumentEmailPreferenceResponse {
+    pub enabled: bool,
+    /// Address documents are sent to; absent until the user first opts in.
+    pub email: Option<String>,
+}
+
+impl From<Option<DocumentEmailPreference>> for DocumentEmailPreferenceResponse {
+    fn from(preference: Option<DocumentEmailPreference>) -> Self {
+        match preference {
+            Some(preference) => Self {
+                enabled: preference.enabled,
+                email: Some(preference.email),
+            },
+            None => Self {
+                enabled: false,
+                email: None,
+            },
+        }
+    }
+}
+
+/// One emailed copy of a cycle's decision document.
+#[derive(Debug, Clone, Serialize)]
+pub struct DocumentDeliveryResponse {
+    pub id: String,
+    pub recipient: String,
+    pub status: DeliveryStatus,
+    pub attempts: u32,
+    pub last_error: Option<String>,
+    /// When the next retry is scheduled; only set while pending.
+    pub next_attempt_at: Option<Timestamp>,
+    pub created_at: Timestamp,
+    pub sent_at: Option<Timestamp>,
+}
+
+impl From<DocumentDelivery> for DocumentDeliveryResponse {
+    fn from(delivery: DocumentDelivery) -> Self {
+        let next_attempt_at =
+            (delivery.status == DeliveryStatus::Pending).then_some(delivery.next_attempt_at);
+        Self {
+            id: delivery.id.to_string(),
+            recipient: delivery.recipient,
+            status: delivery.status,
+            attempts: delivery.attempts,
+            last_error: delivery.last_error,
+            next_attempt_at,
+            created_at: delivery.created_at,
+            sent_at: delivery.sent_at,
+        }
+    }
+}
+
+/// Standard error response.
+#[derive(Debug, Clone, Serialize)]
+pub struct ErrorResponse {
+    pub code: String,
+    pub message: String,
+}
+
+impl ErrorResponse {
+    pub fn bad_request(message: impl Into<String>) -> Self {
+        Self {
+            code: "BAD_REQUEST".to_string(),
+            message: message.into(),
+        }
+    }
+
+    pub fn internal(message: impl Into<String>) -> Self {
+        Self {
+            code: "INTERNAL_ERROR".to_string(),
+            message: message.into(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::foundation::{CycleId, UserId};
+
+    #[test]
+    fn only_pending_deliveries_report_a_next_attempt() {
+        let mut delivery = DocumentDelivery::new(
+            CycleId::new(),
+            UserId::new("user-1").unwrap(),
+            "jo@example.com",
+        );
+        assert!(DocumentDeliveryResponse::from(delivery.clone())
+            .next_attempt_at
+            .is_some());
+
+        delivery.record_sent();
+        let response = DocumentDeliveryResponse::from(delivery);
+        assert!(response.next_attempt_at.is_none());
+        assert_eq!(serde_json::to_value(&response).unwrap()["status"], "sent");
+    }
+
+    #[test]
+    fn missing_preference_reads_as_disabled() {
+        let response = DocumentEmailPreferenceResponse::from(None);
+        assert!(!response.enabled);
+        assert!(response.email.is_none());
+    }
+}
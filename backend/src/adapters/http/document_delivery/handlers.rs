@@ -0,0 +1,123 @@
+//! HTTP handlers for document delivery endpoints.
+
+use std::sync::Arc;
+
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    Json,
+};
+
+use crate::adapters::http::middleware::RequireAuth;
+use crate::domain::document::DocumentEmailPreference;
+use crate::domain::foundation::{CycleId, DomainError};
+use crate::ports::{DocumentDeliveryRepository, DocumentEmailPreferenceRepository};
+
+use super::dto::{
+    DocumentDeliveryResponse, DocumentEmailPreferenceRequest, DocumentEmailPreferenceResponse,
+    ErrorResponse,
+};
+
+// ════════════════════════════════════════════════════════════════════════════
+// Application State
+// ════════════════════════════════════════════════════════════════════════════
+
+/// Shared state for the document delivery endpoints.
+#[derive(Clone)]
+pub struct DocumentDeliveryAppState {
+    pub preferences: Arc<dyn DocumentEmailPreferenceRepository>,
+    pub deliveries: Arc<dyn DocumentDeliveryRepository>,
+}
+
+// ════════════════════════════════════════════════════════════════════════════
+// HTTP handlers
+// ════════════════════════════════════════════════════════════════════════════
+
+/// GET /api/users/me/preferences/document-email - Get the document email opt-in
+pub async fn get_document_email_preference(
+    State(state): State<DocumentDeliveryAppState>,
+    RequireAuth(user): RequireAuth,
+) -> Response {
+    match state.preferences.get(&user.id).await {
+        Ok(preference) => (
+            StatusCode::OK,
+            Json(DocumentEmailPreferenceResponse::from(preference)),
+        )
+            .into_response(),
+        Err(e) => internal_error("Failed to load document email preference", e),
+    }
+}
+
+/// PUT /api/users/me/preferences/document-email - Opt in or out of document emails
+///
+/// Documents go to the email address on the caller's token, which must be
+/// verified before opting in.
+pub async fn put_document_email_preference(
+    State(state): State<DocumentDeliveryAppState>,
+    RequireAuth(user): RequireAuth,
+    Json(req): Json<DocumentEmailPreferenceRequest>,
+) -> Response {
+    if req.enabled && !user.email_verified {
+        return (
+            StatusCode::BAD_REQUEST,
+            Json(ErrorResponse::bad_request(
+                "Verify your email address before turning on document emails",
+            )),
+        )
+            .into_response();
+    }
+
+    let preference = DocumentEmailPreference::new(user.id, user.email, req.enabled);
+    match state.preferences.set(&preference).await {
+        Ok(()) => (
+            StatusCode::OK,
+            Json(DocumentEmailPreferenceResponse::from(Some(preference))),
+        )
+            .into_response(),
+        Err(e) => internal_error("Failed to save document email preference", e),
+    }
+}
+
+/// GET /api/cycles/:cycle_id/document/deliveries - List the caller's document emails for a cycle
+pub async fn list_document_deliveries(
+    State(state): State<DocumentDeliveryAppState>,
+    RequireAuth(user): RequireAuth,
+    Path(cycle_id): Path<String>,
+) -> Response {
+    let cycle_id = match cycle_id.parse::<CycleId>() {
+        Ok(id) => id,
+        Err(_) => {
+            return (
+                StatusCode::BAD_REQUEST,
+                Json(ErrorResponse::bad_request("Invalid cycle ID")),
+            )
+                .into_response()
+        }
+    };
+
+    // Deliveries are scoped to the caller, so no separate ownership check
+    match state.deliveries.list_for_cycle(&cycle_id, &user.id).await {
+        Ok(deliveries) => {
+            let response: Vec<DocumentDeliveryResponse> = deliveries
+                .into_iter()
+                .map(DocumentDeliveryResponse::from)
+                .collect();
+            (StatusCode::OK, Json(response)).into_response()
+        }
+        Err(e) => internal_error("Failed to load document deliveries", e),
+    }
+}
+
+// ════════════════════════════════════════════════════════════════════════════
+// Error handling
+// ════════════════════════════════════════════════════════════════════════════
+
+fn internal_error(message: &str, error: DomainError) -> Response {
+    tracing::error!("{}: {}", message, error);
+    (
+        StatusCode::INTERNAL_SERVER_ERROR,
+        Json(ErrorResponse::internal(message)),
+    )
+        .into_response()
+}
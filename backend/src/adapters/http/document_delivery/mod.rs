@@ -0,0 +1,15 @@
+//! Document delivery HTTP adapter module.
+//!
+//! Opt-in for emailing the decision document when a cycle completes, and the
+//! delivery status of those emails.
+
+pub mod dto;
+pub mod handlers;
+pub mod routes;
+
+pub use dto::{
+    DocumentDeliveryResponse, DocumentEmailPreferenceRequest, DocumentEmailPreferenceResponse,
+    ErrorResponse,
+};
+pub use handlers::DocumentDeliveryAppState;
+pub use routes::document_delivery_routes;
@@ -0,0 +1,27 @@
+//! HTTP routes for document delivery endpoints.
+
+use axum::{routing::get, Router};
+
+use super::handlers::{
+    get_document_email_preference, list_document_deliveries, put_document_email_preference,
+    DocumentDeliveryAppState,
+};
+
+/// Creates the document delivery router.
+///
+/// # Routes
+/// - `GET /api/users/me/preferences/document-email` - Get the document email opt-in
+/// - `PUT /api/users/me/preferences/document-email` - Opt in or out of document emails
+/// - `GET /api/cycles/:cycle_id/document/deliveries` - List the caller's document emails for a cycle
+pub fn document_delivery_routes(state: DocumentDeliveryAppState) -> Router {
+    Router::new()
+        .route(
+            "/api/users/me/preferences/document-email",
+            get(get_document_email_preference).put(put_document_email_preference),
+        )
+        .route(
+            "/api/cycles/:cycle_id/document/deliveries",
+            get(list_document_deliveries),
+        )
+        .with_state(state)
+}
@@ -0,0 +1,114 @@
+//! HTTP DTOs for document history endpoints.
+
+use serde::{Deserialize, Serialize};
+
+use crate::domain::document::{SectionChange, VersionDiff};
+use crate::domain::foundation::CycleId;
+
+// ════════════════════════════════════════════════════════════════════════════
+// Request DTOs
+// ════════════════════════════════════════════════════════════════════════════
+
+/// Query parameters for the diff endpoint.
+#[derive(Debug, Clone, Deserialize)]
+pub struct DocumentDiffParams {
+    /// Older version, e.g. `v3`.
+    pub from: String,
+    /// Newer version, e.g. `v7`.
+    pub to: String,
+}
+
+// ════════════════════════════════════════════════════════════════════════════
+// Response DTOs
+// ════════════════════════════════════════════════════════════════════════════
+
+/// Structured and rendered difference between two document versions.
+#[derive(Debug, Clone, Serialize)]
+pub struct DocumentDiffResponse {
+    pub cycle_id: String,
+    pub from: String,
+    pub to: String,
+    /// Sections that were added, removed, or modified.
+    pub sections: Vec<SectionChange>,
+    /// Unified diff of the Markdown source.
+    pub unified: String,
+    /// The unified diff as a fenced Markdown block, ready to display.
+    pub markdown: String,
+}
+
+impl DocumentDiffResponse {
+    pub fn new(cycle_id: CycleId, diff: VersionDiff) -> Self {
+        let markdown = if diff.unified.is_empty() {
+            "_No changes._\n".to_string()
+        } else {
+            format!("```diff\n{}```\n", diff.unified)
+        };
+        Self {
+            cycle_id: cycle_id.to_string(),
+            from: format!("v{}", diff.from_version),
+            to: format!("v{}", diff.to_version),
+            sections: diff.sections,
+            unified: diff.unified,
+            markdown,
+        }
+    }
+}
+
+/// Standard error response.
+#[derive(Debug, Clone, Serialize)]
+pub struct ErrorResponse {
+    pub code: String,
+    pub message: String,
+}
+
+impl ErrorResponse {
+    pub fn bad_request(message: impl Into<String>) -> Self {
+        Self {
+            code: "BAD_REQUEST".to_string(),
+            message: message.into(),
+        }
+    }
+
+    pub fn forbidden(message: impl Into<String>) -> Self {
+        Self {
+            code: "FORBIDDEN".to_string(),
+            message: message.into(),
+        }
+    }
+
+    pub fn not_found(message: impl Into<String>) -> Self {
+        Self {
+            code: "NOT_FOUND".to_string(),
+            message: message.into(),
+        }
+    }
+
+    pub fn internal(message: impl Into<String>) -> Self {
+        Self {
+            code: "INTERNAL_ERROR".to_string(),
+            message: message.into(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::document::{DocumentVersion, MarkdownContent};
+
+    #[test]
+    fn renders_the_diff_as_a_fenced_block() {
+        let cycle_id = CycleId::new();
+        let from = DocumentVersion::new(cycle_id, 3, MarkdownContent::new("# A\n"));
+        let to = DocumentVersion::new(cycle_id, 7, MarkdownContent::new("# B\n"));
+
+        let response = DocumentDiffResponse::new(cycle_id, VersionDiff::between(&from, &to));
+
+        assert_eq!(response.from, "v3");
+        assert_eq!(response.to, "v7");
+        assert_eq!(
+            response.markdown,
+            "```diff\n@@ -1,1 +1,1 @@\n-# A\n+# B\n```\n"
+        );
+    }
+}
@@ -0,0 +1,136 @@
+//! HTTP handlers for document history endpoints.
+
+use std::sync::Arc;
+
+use axum::{
+    extract::{Path, Query, State},
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    Json,
+};
+
+use crate::adapters::http::middleware::RequireAuth;
+use crate::application::handlers::cycle::{
+    GetDocumentDiffError, GetDocumentDiffHandler, GetDocumentDiffQuery,
+};
+use crate::domain::document::DocumentVersion;
+use crate::domain::foundation::CycleId;
+use crate::ports::{CycleRepository, DocumentVersionRepository, SessionRepository};
+
+use super::dto::{DocumentDiffParams, DocumentDiffResponse, ErrorResponse};
+
+// ════════════════════════════════════════════════════════════════════════════
+// Application State
+// ════════════════════════════════════════════════════════════════════════════
+
+/// Shared state for the document history endpoints.
+#[derive(Clone)]
+pub struct DocumentHistoryAppState {
+    pub cycle_repository: Arc<dyn CycleRepository>,
+    pub session_repository: Arc<dyn SessionRepository>,
+    pub document_versions: Arc<dyn DocumentVersionRepository>,
+}
+
+impl DocumentHistoryAppState {
+    fn diff_handler(&self) -> GetDocumentDiffHandler {
+        GetDocumentDiffHandler::new(
+            self.cycle_repository.clone(),
+            self.session_repository.clone(),
+            self.document_versions.clone(),
+        )
+    }
+}
+
+// ════════════════════════════════════════════════════════════════════════════
+// HTTP handlers
+// ════════════════════════════════════════════════════════════════════════════
+
+/// GET /api/cycles/:cycle_id/document/diff?from=v3&to=v7 - Compare two document versions
+pub async fn get_document_diff(
+    State(state): State<DocumentHistoryAppState>,
+    RequireAuth(user): RequireAuth,
+    Path(cycle_id): Path<String>,
+    Query(params): Query<DocumentDiffParams>,
+) -> Response {
+    let cycle_id = match cycle_id.parse::<CycleId>() {
+        Ok(id) => id,
+        Err(_) => return bad_request("Invalid cycle ID".to_string()),
+    };
+    let (from_version, to_version) = match (
+        DocumentVersion::parse_label(&params.from),
+        DocumentVersion::parse_label(&params.to),
+    ) {
+        (Some(from), Some(to)) => (from, to),
+        _ => {
+            return bad_request(format!(
+                "Invalid version range {}..{}; use labels like v3",
+                params.from, params.to
+            ))
+        }
+    };
+
+    let query = GetDocumentDiffQuery {
+        cycle_id,
+        from_version,
+        to_version,
+        user_id: user.id,
+    };
+    match state.diff_handler().handle(query).await {
+        Ok(diff) => (
+            StatusCode::OK,
+            Json(DocumentDiffResponse::new(cycle_id, diff)),
+        )
+            .into_response(),
+        Err(e) => diff_error_response(e),
+    }
+}
+
+// ════════════════════════════════════════════════════════════════════════════
+// Error handling
+// ════════════════════════════════════════════════════════════════════════════
+
+fn bad_request(message: String) -> Response {
+    (
+        StatusCode::BAD_REQUEST,
+        Json(ErrorResponse::bad_request(message)),
+    )
+        .into_response()
+}
+
+fn diff_error_response(error: GetDocumentDiffError) -> Response {
+    let (status, body) = match &error {
+        GetDocumentDiffError::CycleNotFound(_) | GetDocumentDiffError::VersionNotFound(_) => (
+            StatusCode::NOT_FOUND,
+            ErrorResponse::not_found(error.to_string()),
+        ),
+        GetDocumentDiffError::Forbidden => (
+            StatusCode::FORBIDDEN,
+            ErrorResponse::forbidden(error.to_string()),
+        ),
+        GetDocumentDiffError::Domain(_) => {
+            tracing::error!("Document diff failed: {}", error);
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                ErrorResponse::internal("Failed to compare document versions"),
+            )
+        }
+    };
+    (status, Json(body)).into_response()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn missing_version_maps_to_404() {
+        let response = diff_error_response(GetDocumentDiffError::VersionNotFound(7));
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    }
+
+    #[test]
+    fn other_users_map_to_403() {
+        let response = diff_error_response(GetDocumentDiffError::Forbidden);
+        assert_eq!(response.status(), StatusCode::FORBIDDEN);
+    }
+}
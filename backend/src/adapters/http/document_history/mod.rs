@@ -0,0 +1,11 @@
+//! Document history HTTP adapter module.
+//!
+//! Compares stored versions of a cycle's Markdown decision document.
+
+pub mod dto;
+pub mod handlers;
+pub mod routes;
+
+pub use dto::{DocumentDiffParams, DocumentDiffResponse, ErrorResponse};
+pub use handlers::DocumentHistoryAppState;
+pub use routes::document_history_routes;
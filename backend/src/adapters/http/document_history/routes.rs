@@ -0,0 +1,18 @@
+//! HTTP routes for document history endpoints.
+
+use axum::{routing::get, Router};
+
+use super::handlers::{get_document_diff, DocumentHistoryAppState};
+
+/// Creates the document history router.
+///
+/// # Routes
+/// - `GET /api/cycles/:cycle_id/document/diff?from=v3&to=v7` - Compare two document versions
+pub fn document_history_routes(state: DocumentHistoryAppState) -> Router {
+    Router::new()
+        .route(
+            "/api/cycles/:cycle_id/document/diff",
+            get(get_document_diff),
+        )
+        .with_state(state)
+}
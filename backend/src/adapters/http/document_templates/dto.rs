@@ -0,0 +1,89 @@
+//! HTTP DTOs for document template administration.
+
+use serde::{Deserialize, Serialize};
+
+use crate::ports::DocumentTemplate;
+
+// ════════════════════════════════════════════════════════════════════════════
+// Request DTOs
+// ════════════════════════════════════════════════════════════════════════════
+
+/// Template source to validate or save. The organization comes from the path.
+#[derive(Debug, Clone, Deserialize)]
+pub struct DocumentTemplateRequest {
+    pub source: String,
+}
+
+// ════════════════════════════════════════════════════════════════════════════
+// Response DTOs
+// ════════════════════════════════════════════════════════════════════════════
+
+/// A stored template.
+#[derive(Debug, Clone, Serialize)]
+pub struct DocumentTemplateResponse {
+    pub organization: String,
+    pub source: String,
+    pub updated_at: String,
+}
+
+impl From<DocumentTemplate> for DocumentTemplateResponse {
+    fn from(template: DocumentTemplate) -> Self {
+        Self {
+            organization: template.organization,
+            source: template.source,
+            updated_at: template.updated_at.as_datetime().to_rfc3339(),
+        }
+    }
+}
+
+/// Outcome of validating a template.
+#[derive(Debug, Clone, Serialize)]
+pub struct ValidateTemplateResponse {
+    pub valid: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+/// Standard error response.
+#[derive(Debug, Clone, Serialize)]
+pub struct ErrorResponse {
+    pub code: String,
+    pub message: String,
+}
+
+impl ErrorResponse {
+    pub fn forbidden(message: impl Into<String>) -> Self {
+        Self {
+            code: "FORBIDDEN".to_string(),
+            message: message.into(),
+        }
+    }
+
+    pub fn bad_request(message: impl Into<String>) -> Self {
+        Self {
+            code: "BAD_REQUEST".to_string(),
+            message: message.into(),
+        }
+    }
+
+    pub fn invalid_template(message: impl Into<String>) -> Self {
+        Self {
+            code: "INVALID_TEMPLATE".to_string(),
+            message: message.into(),
+        }
+    }
+
+    pub fn not_found(message: impl Into<String>) -> Self {
+        Self {
+            code: "NOT_FOUND".to_string(),
+            message: message.into(),
+        }
+    }
+
+    pub fn internal(message: impl Into<String>) -> Self {
+        Self {
+            code: "INTERNAL_ERROR".to_string(),
+            message: message.into(),
+        }
+    }
+}
@@ -0,0 +1,190 @@
+//! HTTP handlers for document template administration.
+
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    Json,
+};
+
+use crate::adapters::document::TemplateDocumentExporter;
+use crate::adapters::http::middleware::RequireAuth;
+use crate::domain::foundation::UserId;
+use crate::ports::{
+    validate_organization, DocumentTemplate, DocumentTemplateError, DocumentTemplateStore,
+};
+
+use super::dto::{
+    DocumentTemplateRequest, DocumentTemplateResponse, ErrorResponse, ValidateTemplateResponse,
+};
+
+// ════════════════════════════════════════════════════════════════════════════
+// Application State
+// ════════════════════════════════════════════════════════════════════════════
+
+/// Shared state for the document template admin endpoints.
+#[derive(Clone)]
+pub struct DocumentTemplatesAppState {
+    /// Writable store (Postgres in production).
+    pub store: Arc<dyn DocumentTemplateStore>,
+    /// Exporter whose sandbox validates templates before they are saved.
+    pub exporter: Arc<TemplateDocumentExporter>,
+    /// Users allowed to manage templates.
+    pub admin_user_ids: Arc<HashSet<UserId>>,
+}
+
+impl DocumentTemplatesAppState {
+    fn require_admin(&self, user_id: &UserId) -> Result<(), Box<Response>> {
+        if self.admin_user_ids.contains(user_id) {
+            Ok(())
+        } else {
+            Err(Box::new((
+                StatusCode::FORBIDDEN,
+                Json(ErrorResponse::forbidden("Admin access required")),
+            )
+                .into_response()))
+        }
+    }
+}
+
+// ════════════════════════════════════════════════════════════════════════════
+// HTTP handlers
+// ════════════════════════════════════════════════════════════════════════════
+
+/// POST /api/admin/document-templates/validate - Check a template without saving
+pub async fn validate_document_template(
+    State(state): State<DocumentTemplatesAppState>,
+    RequireAuth(user): RequireAuth,
+    Json(req): Json<DocumentTemplateRequest>,
+) -> Response {
+    if let Err(rejection) = state.require_admin(&user.id) {
+        return *rejection;
+    }
+
+    let response = match state.exporter.validate(&req.source) {
+        Ok(()) => ValidateTemplateResponse {
+            valid: true,
+            error: None,
+        },
+        Err(e) => ValidateTemplateResponse {
+            valid: false,
+            error: Some(e.to_string()),
+        },
+    };
+    (StatusCode::OK, Json(response)).into_response()
+}
+
+/// GET /api/admin/document-templates/:organization - Get an organization's template
+pub async fn get_document_template(
+    State(state): State<DocumentTemplatesAppState>,
+    RequireAuth(user): RequireAuth,
+    Path(organization): Path<String>,
+) -> Response {
+    if let Err(rejection) = state.require_admin(&user.id) {
+        return *rejection;
+    }
+
+    match state.store.get(&organization).await {
+        Ok(Some(template)) => (
+            StatusCode::OK,
+            Json(DocumentTemplateResponse::from(template)),
+        )
+            .into_response(),
+        Ok(None) => not_found(&organization),
+        Err(e) => handle_template_error(e),
+    }
+}
+
+/// PUT /api/admin/document-templates/:organization - Validate and save a template
+pub async fn put_document_template(
+    State(state): State<DocumentTemplatesAppState>,
+    RequireAuth(user): RequireAuth,
+    Path(organization): Path<String>,
+    Json(req): Json<DocumentTemplateRequest>,
+) -> Response {
+    if let Err(rejection) = state.require_admin(&user.id) {
+        return *rejection;
+    }
+    if let Err(e) = validate_organization(&organization) {
+        return handle_template_error(e);
+    }
+    if let Err(e) = state.exporter.validate(&req.source) {
+        return (
+            StatusCode::UNPROCESSABLE_ENTITY,
+            Json(ErrorResponse::invalid_template(e.to_string())),
+        )
+            .into_response();
+    }
+
+    let template = DocumentTemplate::new(organization, req.source);
+    let response = DocumentTemplateResponse::from(template.clone());
+    match state.store.save(template).await {
+        Ok(()) => {
+            tracing::info!(
+                organization = %response.organization,
+                changed_by = %user.id,
+                "Document template updated"
+            );
+            (StatusCode::OK, Json(response)).into_response()
+        }
+        Err(e) => handle_template_error(e),
+    }
+}
+
+/// DELETE /api/admin/document-templates/:organization - Revert to the default layout
+pub async fn delete_document_template(
+    State(state): State<DocumentTemplatesAppState>,
+    RequireAuth(user): RequireAuth,
+    Path(organization): Path<String>,
+) -> Response {
+    if let Err(rejection) = state.require_admin(&user.id) {
+        return *rejection;
+    }
+
+    match state.store.delete(&organization).await {
+        Ok(true) => {
+            tracing::info!(%organization, changed_by = %user.id, "Document template deleted");
+            StatusCode::NO_CONTENT.into_response()
+        }
+        Ok(false) => not_found(&organization),
+        Err(e) => handle_template_error(e),
+    }
+}
+
+// ════════════════════════════════════════════════════════════════════════════
+// Error handling
+// ════════════════════════════════════════════════════════════════════════════
+
+fn not_found(organization: &str) -> Response {
+    (
+        StatusCode::NOT_FOUND,
+        Json(ErrorResponse::not_found(format!(
+            "No document template for {}",
+            organization
+        ))),
+    )
+        .into_response()
+}
+
+fn handle_template_error(error: DocumentTemplateError) -> Response {
+    match error {
+        DocumentTemplateError::InvalidOrganization(_) | DocumentTemplateError::ReadOnly(_) => (
+            StatusCode::BAD_REQUEST,
+            Json(ErrorResponse::bad_request(error.to_string())),
+        )
+            .into_response(),
+        DocumentTemplateError::Storage(msg) => {
+            tracing::error!("Document template storage error: {}", msg);
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ErrorResponse::internal(
+                    "Failed to access document templates",
+                )),
+            )
+                .into_response()
+        }
+    }
+}
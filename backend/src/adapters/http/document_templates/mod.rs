@@ -0,0 +1,15 @@
+//! Document template admin HTTP adapter module.
+//!
+//! Admin endpoints for managing per-organization Markdown templates used by
+//! the decision document export. Templates are validated in the sandbox
+//! before they are stored.
+
+pub mod dto;
+pub mod handlers;
+pub mod routes;
+
+pub use dto::{
+    DocumentTemplateRequest, DocumentTemplateResponse, ErrorResponse, ValidateTemplateResponse,
+};
+pub use handlers::DocumentTemplatesAppState;
+pub use routes::document_template_routes;
@@ -0,0 +1,33 @@
+//! HTTP routes for document template administration.
+
+use axum::{
+    routing::{get, post},
+    Router,
+};
+
+use super::handlers::{
+    delete_document_template, get_document_template, put_document_template,
+    validate_document_template, DocumentTemplatesAppState,
+};
+
+/// Creates the document template admin router.
+///
+/// # Routes
+/// - `POST /api/admin/document-templates/validate` - Check a template without saving (admin)
+/// - `GET /api/admin/document-templates/:organization` - Get an organization's template (admin)
+/// - `PUT /api/admin/document-templates/:organization` - Validate and save a template (admin)
+/// - `DELETE /api/admin/document-templates/:organization` - Revert to the default layout (admin)
+pub fn document_template_routes(state: DocumentTemplatesAppState) -> Router {
+    Router::new()
+        .route(
+            "/api/admin/document-templates/validate",
+            post(validate_document_template),
+        )
+        .route(
+            "/api/admin/document-templates/:organization",
+            get(get_document_template)
+                .put(put_document_template)
+                .delete(delete_document_template),
+        )
+        .with_state(state)
+}
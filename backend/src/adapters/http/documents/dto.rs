@@ -0,0 +1,57 @@
+//! HTTP DTOs for document endpoints.
+
+use serde::{Deserialize, Serialize};
+
+// ════════════════════════════════════════════════════════════════════════════════
+// Request DTOs
+// ════════════════════════════════════════════════════════════════════════════════
+
+/// Query parameters carried by a signed download URL.
+#[derive(Debug, Clone, Deserialize)]
+pub struct SignedDownloadQuery {
+    /// Unix timestamp (seconds) after which the link is invalid.
+    pub expires: u64,
+    /// Hex-encoded HMAC signature.
+    pub signature: String,
+}
+
+// ════════════════════════════════════════════════════════════════════════════════
+// Response DTOs
+// ════════════════════════════════════════════════════════════════════════════════
+
+/// Standard error response.
+#[derive(Debug, Clone, Serialize)]
+pub struct ErrorResponse {
+    pub code: String,
+    pub message: String,
+}
+
+impl ErrorResponse {
+    pub fn not_found(resource_type: &str, id: &str) -> Self {
+        Self {
+            code: "NOT_FOUND".to_string(),
+            message: format!("{} not found: {}", resource_type, id),
+        }
+    }
+
+    pub fn forbidden(message: impl Into<String>) -> Self {
+        Self {
+            code: "FORBIDDEN".to_string(),
+            message: message.into(),
+        }
+    }
+
+    pub fn gone(message: impl Into<String>) -> Self {
+        Self {
+            code: "LINK_EXPIRED".to_string(),
+            message: message.into(),
+        }
+    }
+
+    pub fn internal(message: impl Into<String>) -> Self {
+        Self {
+            code: "INTERNAL_ERROR".to_string(),
+            message: message.into(),
+        }
+    }
+}
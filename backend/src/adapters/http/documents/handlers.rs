@@ -0,0 +1,121 @@
+//! HTTP handlers for document endpoints.
+
+use std::sync::Arc;
+
+use axum::extract::{Json, Path, Query, State};
+use axum::http::{header, StatusCode};
+use axum::response::{IntoResponse, Response};
+
+use crate::adapters::storage::HmacUrlSigner;
+use crate::ports::{DocumentStorage, DocumentStorageError};
+
+use super::dto::{ErrorResponse, SignedDownloadQuery};
+
+// ════════════════════════════════════════════════════════════════════════════════
+// Application State
+// ════════════════════════════════════════════════════════════════════════════════
+
+/// State for document download routes.
+#[derive(Clone)]
+pub struct DocumentsAppState {
+    pub storage: Arc<dyn DocumentStorage>,
+    pub signer: Arc<HmacUrlSigner>,
+}
+
+impl DocumentsAppState {
+    pub fn new(storage: Arc<dyn DocumentStorage>, signer: Arc<HmacUrlSigner>) -> Self {
+        Self { storage, signer }
+    }
+}
+
+// ════════════════════════════════════════════════════════════════════════════════
+// Handlers
+// ════════════════════════════════════════════════════════════════════════════════
+
+/// GET /api/documents/*key?expires=..&signature=..
+///
+/// Serves a stored document when the signed link is valid. No bearer token is
+/// required - the signature is the credential.
+pub async fn download_signed_document(
+    State(state): State<DocumentsAppState>,
+    Path(key): Path<String>,
+    Query(query): Query<SignedDownloadQuery>,
+) -> Response {
+    if let Err(e) = state.signer.verify(&key, query.expires, &query.signature) {
+        return document_error_response(e);
+    }
+
+    match state.storage.get(&key).await {
+        Ok(document) => {
+            let filename = key.rsplit('/').next().unwrap_or("document");
+            (
+                StatusCode::OK,
+                [
+                    (header::CONTENT_TYPE, document.content_type),
+                    (
+                        header::CONTENT_DISPOSITION,
+                        format!("attachment; filename=\"{}\"", filename.replace('"', "")),
+                    ),
+                    (header::CACHE_CONTROL, "private, no-store".to_string()),
+                ],
+                document.bytes,
+            )
+                .into_response()
+        }
+        Err(e) => document_error_response(e),
+    }
+}
+
+// ════════════════════════════════════════════════════════════════════════════════
+// Error handling
+// ════════════════════════════════════════════════════════════════════════════════
+
+fn document_error_response(error: DocumentStorageError) -> Response {
+    let (status, body) = match &error {
+        DocumentStorageError::NotFound(key) => {
+            (StatusCode::NOT_FOUND, ErrorResponse::not_found("Document", key))
+        }
+        DocumentStorageError::UrlExpired => (
+            StatusCode::GONE,
+            ErrorResponse::gone("This download link has expired"),
+        ),
+        DocumentStorageError::InvalidSignature
+        | DocumentStorageError::InvalidKey(_)
+        | DocumentStorageError::TtlTooLong { .. } => (
+            StatusCode::FORBIDDEN,
+            ErrorResponse::forbidden("Invalid download link"),
+        ),
+        DocumentStorageError::Storage(msg) => {
+            tracing::error!("Document storage error: {}", msg);
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                ErrorResponse::internal("Failed to load document"),
+            )
+        }
+    };
+    (status, Json(body)).into_response()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn expired_link_maps_to_410() {
+        let response = document_error_response(DocumentStorageError::UrlExpired);
+        assert_eq!(response.status(), StatusCode::GONE);
+    }
+
+    #[test]
+    fn invalid_signature_maps_to_403() {
+        let response = document_error_response(DocumentStorageError::InvalidSignature);
+        assert_eq!(response.status(), StatusCode::FORBIDDEN);
+    }
+
+    #[test]
+    fn missing_document_maps_to_404() {
+        let response =
+            document_error_response(DocumentStorageError::NotFound("a.pdf".to_string()));
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    }
+}
@@ -0,0 +1,12 @@
+//! Documents HTTP adapter module.
+//!
+//! Serves stored documents through signed, expiring download links for
+//! storage backends that cannot presign URLs themselves.
+
+pub mod dto;
+pub mod handlers;
+pub mod routes;
+
+pub use dto::{ErrorResponse, SignedDownloadQuery};
+pub use handlers::DocumentsAppState;
+pub use routes::document_routes;
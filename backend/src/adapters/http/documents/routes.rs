@@ -0,0 +1,14 @@
+//! HTTP routes for document endpoints.
+
+use axum::routing::get;
+use axum::Router;
+
+use super::handlers::{download_signed_document, DocumentsAppState};
+
+/// Creates the document router.
+pub fn document_routes(state: DocumentsAppState) -> Router {
+    Router::new()
+        // GET /api/documents/*key?expires=..&signature=..
+        .route("/api/documents/*key", get(download_signed_document))
+        .with_state(state)
+}
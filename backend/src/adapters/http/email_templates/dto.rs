@@ -0,0 +1,63 @@
+//! HTTP DTOs for email template endpoints.
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value as JsonValue;
+
+use crate::ports::EmailTemplateKind;
+
+// ════════════════════════════════════════════════════════════════════════════
+// Request DTOs
+// ════════════════════════════════════════════════════════════════════════════
+
+/// Request to render a template without sending it.
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct PreviewEmailTemplateRequest {
+    /// Requested locale; the closest available variant is used.
+    #[serde(default)]
+    pub locale: Option<String>,
+    /// Template variables; the kind's sample variables when absent.
+    #[serde(default)]
+    pub context: Option<JsonValue>,
+}
+
+// ════════════════════════════════════════════════════════════════════════════
+// Response DTOs
+// ════════════════════════════════════════════════════════════════════════════
+
+/// Available template kinds and locales.
+#[derive(Debug, Clone, Serialize)]
+pub struct EmailTemplateCatalogResponse {
+    pub kinds: Vec<EmailTemplateKind>,
+    /// Locales with templates, default first.
+    pub locales: Vec<String>,
+}
+
+/// Standard error response.
+#[derive(Debug, Clone, Serialize)]
+pub struct ErrorResponse {
+    pub code: String,
+    pub message: String,
+}
+
+impl ErrorResponse {
+    pub fn bad_request(message: impl Into<String>) -> Self {
+        Self {
+            code: "BAD_REQUEST".to_string(),
+            message: message.into(),
+        }
+    }
+
+    pub fn not_found(resource_type: &str, id: &str) -> Self {
+        Self {
+            code: "NOT_FOUND".to_string(),
+            message: format!("{} not found: {}", resource_type, id),
+        }
+    }
+
+    pub fn forbidden(message: impl Into<String>) -> Self {
+        Self {
+            code: "FORBIDDEN".to_string(),
+            message: message.into(),
+        }
+    }
+}
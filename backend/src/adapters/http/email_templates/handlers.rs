@@ -0,0 +1,97 @@
+//! HTTP handlers for email template endpoints.
+
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    Json,
+};
+
+use crate::adapters::http::middleware::RequireAuth;
+use crate::domain::foundation::UserId;
+use crate::ports::{EmailTemplateKind, EmailTemplateRenderer, DEFAULT_EMAIL_LOCALE};
+
+use super::dto::{EmailTemplateCatalogResponse, ErrorResponse, PreviewEmailTemplateRequest};
+
+// ════════════════════════════════════════════════════════════════════════════
+// Application State
+// ════════════════════════════════════════════════════════════════════════════
+
+/// Shared state for email template endpoints.
+#[derive(Clone)]
+pub struct EmailTemplatesAppState {
+    pub renderer: Arc<dyn EmailTemplateRenderer>,
+    /// Users allowed to preview templates.
+    pub admin_user_ids: Arc<HashSet<UserId>>,
+}
+
+impl EmailTemplatesAppState {
+    /// Returns the rejection for non-admins.
+    fn reject_non_admin(&self, user_id: &UserId) -> Option<Response> {
+        if self.admin_user_ids.contains(user_id) {
+            return None;
+        }
+        Some(
+            (
+                StatusCode::FORBIDDEN,
+                Json(ErrorResponse::forbidden("Admin access required")),
+            )
+                .into_response(),
+        )
+    }
+}
+
+// ════════════════════════════════════════════════════════════════════════════
+// HTTP handlers
+// ════════════════════════════════════════════════════════════════════════════
+
+/// GET /api/admin/email-templates - List template kinds and locales
+pub async fn list_email_templates(
+    State(state): State<EmailTemplatesAppState>,
+    RequireAuth(user): RequireAuth,
+) -> Response {
+    if let Some(rejection) = state.reject_non_admin(&user.id) {
+        return rejection;
+    }
+
+    let response = EmailTemplateCatalogResponse {
+        kinds: EmailTemplateKind::ALL.to_vec(),
+        locales: state.renderer.locales(),
+    };
+    (StatusCode::OK, Json(response)).into_response()
+}
+
+/// POST /api/admin/email-templates/:kind/preview - Render a template without sending it
+pub async fn preview_email_template(
+    State(state): State<EmailTemplatesAppState>,
+    RequireAuth(user): RequireAuth,
+    Path(kind): Path<String>,
+    Json(req): Json<PreviewEmailTemplateRequest>,
+) -> Response {
+    if let Some(rejection) = state.reject_non_admin(&user.id) {
+        return rejection;
+    }
+
+    let Some(kind) = EmailTemplateKind::parse(&kind) else {
+        return (
+            StatusCode::NOT_FOUND,
+            Json(ErrorResponse::not_found("Email template", &kind)),
+        )
+            .into_response();
+    };
+
+    let locale = req.locale.as_deref().unwrap_or(DEFAULT_EMAIL_LOCALE);
+    let context = req.context.unwrap_or_else(|| kind.sample_context());
+    match state.renderer.render(kind, locale, &context) {
+        Ok(email) => (StatusCode::OK, Json(email)).into_response(),
+        // Only custom variables can fail; the samples are snapshot-tested
+        Err(e) => (
+            StatusCode::BAD_REQUEST,
+            Json(ErrorResponse::bad_request(e.to_string())),
+        )
+            .into_response(),
+    }
+}
@@ -0,0 +1,12 @@
+//! Email templates HTTP adapter module.
+//!
+//! Lets admins list the transactional email templates and preview them in
+//! any locale, with sample or custom variables.
+
+pub mod dto;
+pub mod handlers;
+pub mod routes;
+
+pub use dto::{EmailTemplateCatalogResponse, ErrorResponse, PreviewEmailTemplateRequest};
+pub use handlers::EmailTemplatesAppState;
+pub use routes::email_template_routes;
@@ -0,0 +1,23 @@
+//! HTTP routes for email template endpoints.
+
+use axum::{
+    routing::{get, post},
+    Router,
+};
+
+use super::handlers::{list_email_templates, preview_email_template, EmailTemplatesAppState};
+
+/// Creates the email templates router.
+///
+/// # Routes
+/// - `GET /api/admin/email-templates` - Template kinds and locales (admin)
+/// - `POST /api/admin/email-templates/:kind/preview` - Render a template without sending it (admin)
+pub fn email_template_routes(state: EmailTemplatesAppState) -> Router {
+    Router::new()
+        .route("/api/admin/email-templates", get(list_email_templates))
+        .route(
+            "/api/admin/email-templates/:kind/preview",
+            post(preview_email_template),
+        )
+        .with_state(state)
+}
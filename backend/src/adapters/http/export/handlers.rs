@@ -0,0 +1,183 @@
+//! HTTP handlers for decision document export.
+
+use std::sync::Arc;
+
+use axum::{
+    extract::{Path, Query, State},
+    http::{header, StatusCode},
+    response::{IntoResponse, Response},
+    Json,
+};
+
+use crate::adapters::http::middleware::RequireAuth;
+use crate::application::handlers::cycle::{
+    ExportCycleDocumentError, ExportCycleDocumentHandler, ExportCycleDocumentQuery,
+};
+use crate::application::handlers::OrganizationResolver;
+use crate::application::loaders::{RequestCycleReader, RequestDashboardReader};
+use crate::domain::foundation::{CycleId, DomainError, UserId};
+use crate::ports::{
+    AccessChecker, AttachmentRepository, CycleReader, DashboardReader, DocumentExporter,
+    DocumentStorage, ExportFormat, OrganizationRepository,
+};
+
+use super::dto::{ErrorResponse, ExportParams};
+
+// ════════════════════════════════════════════════════════════════════════════
+// Application State
+// ════════════════════════════════════════════════════════════════════════════
+
+/// Shared state for the export endpoints.
+#[derive(Clone)]
+pub struct ExportAppState {
+    pub cycle_reader: Arc<dyn CycleReader>,
+    pub dashboard_reader: Arc<dyn DashboardReader>,
+    pub access_checker: Arc<dyn AccessChecker>,
+    pub attachment_repository: Arc<dyn AttachmentRepository>,
+    /// Holds attachment bytes, so images can be embedded in exports.
+    pub document_storage: Arc<dyn DocumentStorage>,
+    /// One exporter per supported format.
+    pub exporters: Vec<Arc<dyn DocumentExporter>>,
+    /// Membership deciding whose document template applies.
+    pub organizations: Arc<dyn OrganizationRepository>,
+}
+
+impl ExportAppState {
+    /// A copy whose readers memoize for the lifetime of one request, so the
+    /// export's steps never repeat a read.
+    pub(crate) fn for_request(&self) -> Self {
+        Self {
+            cycle_reader: Arc::new(RequestCycleReader::new(self.cycle_reader.clone())),
+            dashboard_reader: Arc::new(RequestDashboardReader::new(
+                self.dashboard_reader.clone(),
+            )),
+            ..self.clone()
+        }
+    }
+
+    pub(crate) fn export_handler(&self) -> ExportCycleDocumentHandler {
+        ExportCycleDocumentHandler::new(
+            self.cycle_reader.clone(),
+            self.dashboard_reader.clone(),
+            self.access_checker.clone(),
+            self.attachment_repository.clone(),
+            self.document_storage.clone(),
+            self.exporters.clone(),
+        )
+    }
+
+    /// Key of the user's organization, whose template and chat settings
+    /// apply.
+    pub(crate) async fn user_organization(
+        &self,
+        user_id: &UserId,
+    ) -> Result<Option<String>, DomainError> {
+        Ok(OrganizationResolver::new(self.organizations.clone())
+            .organization_of(user_id)
+            .await?
+            .map(|id| id.to_string()))
+    }
+}
+
+// ════════════════════════════════════════════════════════════════════════════
+// HTTP handlers
+// ════════════════════════════════════════════════════════════════════════════
+
+/// GET /api/cycles/:cycle_id/export?format=pdf|slides|markdown - Download the decision document
+pub async fn export_cycle_document(
+    State(state): State<ExportAppState>,
+    RequireAuth(user): RequireAuth,
+    Path(cycle_id): Path<String>,
+    Query(params): Query<ExportParams>,
+) -> Response {
+    let cycle_id = match cycle_id.parse::<CycleId>() {
+        Ok(id) => id,
+        Err(_) => {
+            return (
+                StatusCode::BAD_REQUEST,
+                Json(ErrorResponse::bad_request("Invalid cycle ID")),
+            )
+                .into_response()
+        }
+    };
+    let format = match params.format.parse::<ExportFormat>() {
+        Ok(format) => format,
+        Err(e) => {
+            return (
+                StatusCode::BAD_REQUEST,
+                Json(ErrorResponse::bad_request(e.to_string())),
+            )
+                .into_response()
+        }
+    };
+
+    let organization = match state.user_organization(&user.id).await {
+        Ok(organization) => organization,
+        Err(e) => return organization_error_response(e),
+    };
+    let query = ExportCycleDocumentQuery {
+        cycle_id,
+        format,
+        user_id: user.id,
+        organization,
+    };
+
+    match state.for_request().export_handler().handle(query).await {
+        Ok(document) => (
+            StatusCode::OK,
+            [
+                (
+                    header::CONTENT_TYPE,
+                    document.format.content_type().to_string(),
+                ),
+                (
+                    header::CONTENT_DISPOSITION,
+                    format!("attachment; filename=\"{}\"", document.file_name),
+                ),
+                (header::CACHE_CONTROL, "private, no-store".to_string()),
+            ],
+            document.bytes,
+        )
+            .into_response(),
+        Err(e) => export_error_response(e),
+    }
+}
+
+// ════════════════════════════════════════════════════════════════════════════
+// Error handling
+// ════════════════════════════════════════════════════════════════════════════
+
+/// Response when the user's organization cannot be looked up.
+pub(crate) fn organization_error_response(error: DomainError) -> Response {
+    tracing::error!("Failed to resolve organization: {}", error);
+    (
+        StatusCode::INTERNAL_SERVER_ERROR,
+        Json(ErrorResponse::internal("Failed to resolve organization")),
+    )
+        .into_response()
+}
+
+pub(crate) fn export_error_response(error: ExportCycleDocumentError) -> Response {
+    let (status, body) = match &error {
+        ExportCycleDocumentError::UnsupportedFormat(_) => (
+            StatusCode::BAD_REQUEST,
+            ErrorResponse::bad_request(error.to_string()),
+        ),
+        ExportCycleDocumentError::AccessDenied(_) | ExportCycleDocumentError::Unauthorized => (
+            StatusCode::FORBIDDEN,
+            ErrorResponse::forbidden(error.to_string()),
+        ),
+        ExportCycleDocumentError::CycleNotFound(_) => (
+            StatusCode::NOT_FOUND,
+            ErrorResponse::not_found(error.to_string()),
+        ),
+        ExportCycleDocumentError::Export(_) | ExportCycleDocumentError::Domain(_) => {
+            tracing::error!("Document export failed: {}", error);
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                ErrorResponse::internal("Failed to export document"),
+            )
+        }
+    };
+    (status, Json(body)).into_response()
+}
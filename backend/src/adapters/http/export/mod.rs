@@ -0,0 +1,12 @@
+//! Decision document export HTTP adapter module.
+//!
+//! Renders a cycle's decision document through the configured
+//! `DocumentExporter`s and returns it as a file download.
+
+pub mod dto;
+pub mod handlers;
+pub mod routes;
+
+pub use dto::{ErrorResponse, ExportParams};
+pub use handlers::ExportAppState;
+pub use routes::export_routes;
@@ -0,0 +1,15 @@
+//! HTTP routes for decision document export.
+
+use axum::{routing::get, Router};
+
+use super::handlers::{export_cycle_document, ExportAppState};
+
+/// Creates the export router.
+///
+/// # Routes
+/// - `GET /api/cycles/:cycle_id/export?format=pdf|slides|markdown` - Download the decision document
+pub fn export_routes(state: ExportAppState) -> Router {
+    Router::new()
+        .route("/api/cycles/:cycle_id/export", get(export_cycle_document))
+        .with_state(state)
+}
@@ -0,0 +1,128 @@
+//! HTTP DTOs for feature flag endpoints.
+
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::domain::membership::MembershipTier;
+use crate::ports::FeatureFlag;
+
+// ════════════════════════════════════════════════════════════════════════════
+// Request DTOs
+// ════════════════════════════════════════════════════════════════════════════
+
+/// Request to create or replace a flag. The key comes from the path.
+#[derive(Debug, Clone, Deserialize)]
+pub struct UpsertFeatureFlagRequest {
+    pub enabled: bool,
+    #[serde(default)]
+    pub description: Option<String>,
+    #[serde(default)]
+    pub tier_overrides: HashMap<MembershipTier, bool>,
+    #[serde(default)]
+    pub user_overrides: HashMap<String, bool>,
+    #[serde(default)]
+    pub rollout_percentage: Option<u8>,
+}
+
+// ════════════════════════════════════════════════════════════════════════════
+// Response DTOs
+// ════════════════════════════════════════════════════════════════════════════
+
+/// Full flag definition (admin view).
+#[derive(Debug, Clone, Serialize)]
+pub struct FeatureFlagResponse {
+    pub key: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    pub enabled: bool,
+    pub tier_overrides: HashMap<MembershipTier, bool>,
+    pub user_overrides: HashMap<String, bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rollout_percentage: Option<u8>,
+    pub updated_at: String,
+}
+
+impl From<&FeatureFlag> for FeatureFlagResponse {
+    fn from(flag: &FeatureFlag) -> Self {
+        Self {
+            key: flag.key.clone(),
+            description: flag.description.clone(),
+            enabled: flag.enabled,
+            tier_overrides: flag.tier_overrides.clone(),
+            user_overrides: flag.user_overrides.clone(),
+            rollout_percentage: flag.rollout_percentage,
+            updated_at: flag.updated_at.as_datetime().to_rfc3339(),
+        }
+    }
+}
+
+/// Flags evaluated for the current user.
+#[derive(Debug, Clone, Serialize)]
+pub struct EvaluatedFlagsResponse {
+    pub flags: HashMap<String, bool>,
+}
+
+/// Standard error response.
+#[derive(Debug, Clone, Serialize)]
+pub struct ErrorResponse {
+    pub code: String,
+    pub message: String,
+}
+
+impl ErrorResponse {
+    pub fn bad_request(message: impl Into<String>) -> Self {
+        Self {
+            code: "BAD_REQUEST".to_string(),
+            message: message.into(),
+        }
+    }
+
+    pub fn not_found(resource_type: &str, id: &str) -> Self {
+        Self {
+            code: "NOT_FOUND".to_string(),
+            message: format!("{} not found: {}", resource_type, id),
+        }
+    }
+
+    pub fn forbidden(message: impl Into<String>) -> Self {
+        Self {
+            code: "FORBIDDEN".to_string(),
+            message: message.into(),
+        }
+    }
+
+    pub fn conflict(message: impl Into<String>) -> Self {
+        Self {
+            code: "CONFLICT".to_string(),
+            message: message.into(),
+        }
+    }
+
+    pub fn internal(message: impl Into<String>) -> Self {
+        Self {
+            code: "INTERNAL_ERROR".to_string(),
+            message: message.into(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn upsert_request_defaults_overrides() {
+        let req: UpsertFeatureFlagRequest = serde_json::from_str(r#"{"enabled":true}"#).unwrap();
+        assert!(req.enabled);
+        assert!(req.tier_overrides.is_empty());
+        assert!(req.user_overrides.is_empty());
+    }
+
+    #[test]
+    fn upsert_request_parses_tier_overrides() {
+        let req: UpsertFeatureFlagRequest =
+            serde_json::from_str(r#"{"enabled":false,"tier_overrides":{"annual":true}}"#).unwrap();
+        assert_eq!(req.tier_overrides.get(&MembershipTier::Annual), Some(&true));
+    }
+}
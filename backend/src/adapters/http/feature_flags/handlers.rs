@@ -0,0 +1,182 @@
+//! HTTP handlers for feature flag endpoints.
+
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    Json,
+};
+
+use crate::adapters::http::middleware::RequireAuth;
+use crate::domain::foundation::{Timestamp, UserId};
+use crate::ports::{FeatureFlag, FeatureFlagError, FeatureFlagProvider, FlagContext, MembershipReader};
+
+use super::dto::{
+    ErrorResponse, EvaluatedFlagsResponse, FeatureFlagResponse, UpsertFeatureFlagRequest,
+};
+
+// ════════════════════════════════════════════════════════════════════════════
+// Application State
+// ════════════════════════════════════════════════════════════════════════════
+
+/// Shared state for feature flag endpoints.
+#[derive(Clone)]
+pub struct FeatureFlagsAppState {
+    pub provider: Arc<dyn FeatureFlagProvider>,
+    pub membership_reader: Arc<dyn MembershipReader>,
+    /// Users allowed to change flags.
+    pub admin_user_ids: Arc<HashSet<UserId>>,
+}
+
+impl FeatureFlagsAppState {
+    fn require_admin(&self, user_id: &UserId) -> Result<(), Box<Response>> {
+        if self.admin_user_ids.contains(user_id) {
+            return Ok(());
+        }
+        Err(Box::new((
+            StatusCode::FORBIDDEN,
+            Json(ErrorResponse::forbidden("Admin access required")),
+        )
+            .into_response()))
+    }
+}
+
+// ════════════════════════════════════════════════════════════════════════════
+// HTTP handlers
+// ════════════════════════════════════════════════════════════════════════════
+
+/// GET /api/feature-flags - Flags evaluated for the current user
+pub async fn get_evaluated_flags(
+    State(state): State<FeatureFlagsAppState>,
+    RequireAuth(user): RequireAuth,
+) -> Response {
+    // Tier lookup failures degrade to default/user targeting rather than failing the request
+    let tier = match state.membership_reader.get_tier(&user.id).await {
+        Ok(tier) => tier,
+        Err(e) => {
+            tracing::warn!("Tier lookup failed for flag evaluation: {}", e);
+            None
+        }
+    };
+
+    let mut ctx = FlagContext::for_user(user.id);
+    ctx.tier = tier;
+
+    let response = EvaluatedFlagsResponse {
+        flags: state.provider.current().evaluate_all(&ctx),
+    };
+    (StatusCode::OK, Json(response)).into_response()
+}
+
+/// GET /api/admin/feature-flags - List all flag definitions
+pub async fn list_feature_flags(
+    State(state): State<FeatureFlagsAppState>,
+    RequireAuth(user): RequireAuth,
+) -> Response {
+    if let Err(rejection) = state.require_admin(&user.id) {
+        return *rejection;
+    }
+
+    let flags = state.provider.current();
+    let response: Vec<FeatureFlagResponse> = flags.flags().into_iter().map(Into::into).collect();
+    (StatusCode::OK, Json(response)).into_response()
+}
+
+/// PUT /api/admin/feature-flags/:key - Create or replace a flag
+pub async fn upsert_feature_flag(
+    State(state): State<FeatureFlagsAppState>,
+    RequireAuth(user): RequireAuth,
+    Path(key): Path<String>,
+    Json(req): Json<UpsertFeatureFlagRequest>,
+) -> Response {
+    if let Err(rejection) = state.require_admin(&user.id) {
+        return *rejection;
+    }
+
+    let mut flag = match FeatureFlag::new(key, req.enabled) {
+        Ok(flag) => flag,
+        Err(e) => return handle_flag_error(e),
+    };
+    flag.description = req.description;
+    flag.tier_overrides = req.tier_overrides;
+    flag.user_overrides = req.user_overrides;
+    if let Some(percentage) = req.rollout_percentage {
+        flag = match flag.with_rollout(percentage) {
+            Ok(flag) => flag,
+            Err(e) => return handle_flag_error(e),
+        };
+    }
+    flag.updated_at = Timestamp::now();
+
+    tracing::info!(
+        flag = %flag.key,
+        enabled = flag.enabled,
+        changed_by = %user.id,
+        "Feature flag updated"
+    );
+
+    let response = FeatureFlagResponse::from(&flag);
+    match state.provider.upsert(flag).await {
+        Ok(()) => (StatusCode::OK, Json(response)).into_response(),
+        Err(e) => handle_flag_error(e),
+    }
+}
+
+/// DELETE /api/admin/feature-flags/:key - Delete a flag
+pub async fn delete_feature_flag(
+    State(state): State<FeatureFlagsAppState>,
+    RequireAuth(user): RequireAuth,
+    Path(key): Path<String>,
+) -> Response {
+    if let Err(rejection) = state.require_admin(&user.id) {
+        return *rejection;
+    }
+
+    match state.provider.remove(&key).await {
+        Ok(()) => {
+            tracing::info!(flag = %key, changed_by = %user.id, "Feature flag deleted");
+            StatusCode::NO_CONTENT.into_response()
+        }
+        Err(e) => handle_flag_error(e),
+    }
+}
+
+// ════════════════════════════════════════════════════════════════════════════
+// Error handling
+// ════════════════════════════════════════════════════════════════════════════
+
+fn handle_flag_error(error: FeatureFlagError) -> Response {
+    match error {
+        FeatureFlagError::InvalidKey(key) => (
+            StatusCode::BAD_REQUEST,
+            Json(ErrorResponse::bad_request(format!("Invalid flag key: {}", key))),
+        )
+            .into_response(),
+        FeatureFlagError::InvalidRollout(_) => (
+            StatusCode::BAD_REQUEST,
+            Json(ErrorResponse::bad_request(error.to_string())),
+        )
+            .into_response(),
+        FeatureFlagError::NotFound(key) => (
+            StatusCode::NOT_FOUND,
+            Json(ErrorResponse::not_found("Feature flag", &key)),
+        )
+            .into_response(),
+        FeatureFlagError::ReadOnly(msg) => (
+            StatusCode::CONFLICT,
+            Json(ErrorResponse::conflict(format!(
+                "Flags are managed by an external provider: {}",
+                msg
+            ))),
+        )
+            .into_response(),
+        FeatureFlagError::Storage(msg) => (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ErrorResponse::internal(msg)),
+        )
+            .into_response(),
+    }
+}
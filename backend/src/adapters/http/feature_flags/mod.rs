@@ -0,0 +1,14 @@
+//! Feature flags HTTP adapter module.
+//!
+//! User endpoint returning flags evaluated for the caller, plus admin
+//! endpoints to create, change, and delete flags at runtime.
+
+pub mod dto;
+pub mod handlers;
+pub mod routes;
+
+pub use dto::{
+    ErrorResponse, EvaluatedFlagsResponse, FeatureFlagResponse, UpsertFeatureFlagRequest,
+};
+pub use handlers::FeatureFlagsAppState;
+pub use routes::feature_flag_routes;
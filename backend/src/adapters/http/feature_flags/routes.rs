@@ -0,0 +1,29 @@
+//! HTTP routes for feature flag endpoints.
+
+use axum::{
+    routing::{get, put},
+    Router,
+};
+
+use super::handlers::{
+    delete_feature_flag, get_evaluated_flags, list_feature_flags, upsert_feature_flag,
+    FeatureFlagsAppState,
+};
+
+/// Creates the feature flag router.
+///
+/// # Routes
+/// - `GET /api/feature-flags` - Flags evaluated for the current user
+/// - `GET /api/admin/feature-flags` - All flag definitions (admin)
+/// - `PUT /api/admin/feature-flags/:key` - Create or replace a flag (admin)
+/// - `DELETE /api/admin/feature-flags/:key` - Delete a flag (admin)
+pub fn feature_flag_routes(state: FeatureFlagsAppState) -> Router {
+    Router::new()
+        .route("/api/feature-flags", get(get_evaluated_flags))
+        .route("/api/admin/feature-flags", get(list_feature_flags))
+        .route(
+            "/api/admin/feature-flags/:key",
+            put(upsert_feature_flag).delete(delete_feature_flag),
+        )
+        .with_state(state)
+}
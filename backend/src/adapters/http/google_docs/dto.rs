@@ -0,0 +1,99 @@
+//! HTTP DTOs for Google Docs endpoints.
+
+use serde::{Deserialize, Serialize};
+
+use crate::ports::GoogleDoc;
+
+// ════════════════════════════════════════════════════════════════════════════
+// Request DTOs
+// ════════════════════════════════════════════════════════════════════════════
+
+/// Query parameters for starting the OAuth flow.
+#[derive(Debug, Clone, Deserialize)]
+pub struct AuthorizeParams {
+    /// Opaque value Google echoes back; the client checks it on return.
+    pub state: String,
+}
+
+/// Authorization code Google returned to the client's redirect URI.
+#[derive(Debug, Clone, Deserialize)]
+pub struct ConnectGoogleRequest {
+    pub code: String,
+}
+
+// ════════════════════════════════════════════════════════════════════════════
+// Response DTOs
+// ════════════════════════════════════════════════════════════════════════════
+
+/// Google consent screen to send the user to.
+#[derive(Debug, Clone, Serialize)]
+pub struct GoogleAuthorizationResponse {
+    pub authorization_url: String,
+}
+
+/// Whether the user has a Google account connected.
+#[derive(Debug, Clone, Serialize)]
+pub struct GoogleConnectionResponse {
+    pub connected: bool,
+}
+
+/// A decision document published to Google Docs.
+#[derive(Debug, Clone, Serialize)]
+pub struct GoogleDocResponse {
+    pub document_id: String,
+    pub url: String,
+}
+
+impl From<GoogleDoc> for GoogleDocResponse {
+    fn from(doc: GoogleDoc) -> Self {
+        Self {
+            document_id: doc.document_id,
+            url: doc.url,
+        }
+    }
+}
+
+/// Standard error response.
+#[derive(Debug, Clone, Serialize)]
+pub struct ErrorResponse {
+    pub code: String,
+    pub message: String,
+}
+
+impl ErrorResponse {
+    pub fn bad_request(message: impl Into<String>) -> Self {
+        Self {
+            code: "BAD_REQUEST".to_string(),
+            message: message.into(),
+        }
+    }
+
+    /// The user must connect (or reconnect) their Google account.
+    pub fn not_connected(message: impl Into<String>) -> Self {
+        Self {
+            code: "GOOGLE_NOT_CONNECTED".to_string(),
+            message: message.into(),
+        }
+    }
+
+    pub fn bad_gateway(message: impl Into<String>) -> Self {
+        Self {
+            code: "BAD_GATEWAY".to_string(),
+            message: message.into(),
+        }
+    }
+
+    pub fn unavailable(message: impl Into<String>) -> Self {
+        Self {
+            code: "SERVICE_UNAVAILABLE".to_string(),
+            message: message.into(),
+        }
+    }
+
+    pub fn internal(message: impl Into<String>) -> Self {
+        Self {
+            code: "INTERNAL_ERROR".to_string(),
+            message: message.into(),
+        }
+    }
+}
@@ -0,0 +1,225 @@
+//! HTTP handlers for Google Docs endpoints.
+
+use std::sync::Arc;
+
+use axum::{
+    extract::{Path, Query, State},
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    Json,
+};
+
+use crate::adapters::http::export::handlers::{export_error_response, organization_error_response};
+use crate::adapters::http::export::ExportAppState;
+use crate::adapters::http::middleware::RequireAuth;
+use crate::application::handlers::cycle::{
+    ExportToGoogleDocsCommand, ExportToGoogleDocsError, ExportToGoogleDocsHandler,
+};
+use crate::domain::foundation::{CycleId, DomainError};
+use crate::ports::{GoogleAccountStore, GoogleDocsClient, GoogleDocsError};
+
+use super::dto::{
+    AuthorizeParams, ConnectGoogleRequest, ErrorResponse, GoogleAuthorizationResponse,
+    GoogleConnectionResponse, GoogleDocResponse,
+};
+
+// ════════════════════════════════════════════════════════════════════════════
+// Application State
+// ════════════════════════════════════════════════════════════════════════════
+
+/// Shared state for the Google Docs endpoints.
+#[derive(Clone)]
+pub struct GoogleDocsAppState {
+    /// Renders the document, with the same checks as downloads.
+    pub export: ExportAppState,
+    pub accounts: Arc<dyn GoogleAccountStore>,
+    pub google: Arc<dyn GoogleDocsClient>,
+}
+
+impl GoogleDocsAppState {
+    fn export_to_google_docs_handler(&self) -> ExportToGoogleDocsHandler {
+        ExportToGoogleDocsHandler::new(
+            Arc::new(self.export.for_request().export_handler()),
+            self.accounts.clone(),
+            self.google.clone(),
+        )
+    }
+}
+
+// ════════════════════════════════════════════════════════════════════════════
+// HTTP handlers
+// ════════════════════════════════════════════════════════════════════════════
+
+/// GET /api/integrations/google - Whether a Google account is connected
+pub async fn get_google_connection(
+    State(state): State<GoogleDocsAppState>,
+    RequireAuth(user): RequireAuth,
+) -> Response {
+    match state.accounts.get(&user.id).await {
+        Ok(tokens) => (
+            StatusCode::OK,
+            Json(GoogleConnectionResponse {
+                connected: tokens.is_some(),
+            }),
+        )
+            .into_response(),
+        Err(e) => internal_error("Failed to load Google account", e),
+    }
+}
+
+/// GET /api/integrations/google/authorize?state=... - Google consent screen URL
+pub async fn authorize_google(
+    State(state): State<GoogleDocsAppState>,
+    RequireAuth(_user): RequireAuth,
+    Query(params): Query<AuthorizeParams>,
+) -> Response {
+    let response = GoogleAuthorizationResponse {
+        authorization_url: state.google.authorization_url(&params.state),
+    };
+    (StatusCode::OK, Json(response)).into_response()
+}
+
+/// POST /api/integrations/google/connect - Exchange the authorization code and save the account
+pub async fn connect_google(
+    State(state): State<GoogleDocsAppState>,
+    RequireAuth(user): RequireAuth,
+    Json(req): Json<ConnectGoogleRequest>,
+) -> Response {
+    if req.code.trim().is_empty() {
+        return (
+            StatusCode::BAD_REQUEST,
+            Json(ErrorResponse::bad_request("Authorization code is required")),
+        )
+            .into_response();
+    }
+
+    let tokens = match state.google.exchange_code(&req.code).await {
+        Ok(tokens) => tokens,
+        Err(e) => return google_error_response(e),
+    };
+    match state.accounts.save(&user.id, &tokens).await {
+        Ok(()) => (
+            StatusCode::OK,
+            Json(GoogleConnectionResponse { connected: true }),
+        )
+            .into_response(),
+        Err(e) => internal_error("Failed to save Google account", e),
+    }
+}
+
+/// DELETE /api/integrations/google - Forget the connected Google account
+pub async fn disconnect_google(
+    State(state): State<GoogleDocsAppState>,
+    RequireAuth(user): RequireAuth,
+) -> Response {
+    match state.accounts.delete(&user.id).await {
+        Ok(()) => StatusCode::NO_CONTENT.into_response(),
+        Err(e) => internal_error("Failed to disconnect Google account", e),
+    }
+}
+
+/// POST /api/cycles/:cycle_id/export/google-docs - Publish the decision document to Google Docs
+pub async fn export_to_google_docs(
+    State(state): State<GoogleDocsAppState>,
+    RequireAuth(user): RequireAuth,
+    Path(cycle_id): Path<String>,
+) -> Response {
+    let cycle_id = match cycle_id.parse::<CycleId>() {
+        Ok(id) => id,
+        Err(_) => {
+            return (
+                StatusCode::BAD_REQUEST,
+                Json(ErrorResponse::bad_request("Invalid cycle ID")),
+            )
+                .into_response()
+        }
+    };
+
+    let organization = match state.export.user_organization(&user.id).await {
+        Ok(organization) => organization,
+        Err(e) => return organization_error_response(e),
+    };
+    let cmd = ExportToGoogleDocsCommand {
+        cycle_id,
+        user_id: user.id,
+        organization,
+    };
+
+    match state.export_to_google_docs_handler().handle(cmd).await {
+        Ok(doc) => (StatusCode::CREATED, Json(GoogleDocResponse::from(doc))).into_response(),
+        Err(e) => export_to_google_docs_error_response(e),
+    }
+}
+
+// ════════════════════════════════════════════════════════════════════════════
+// Error handling
+// ════════════════════════════════════════════════════════════════════════════
+
+fn internal_error(message: &str, error: DomainError) -> Response {
+    tracing::error!("{}: {}", message, error);
+    (
+        StatusCode::INTERNAL_SERVER_ERROR,
+        Json(ErrorResponse::internal(message)),
+    )
+        .into_response()
+}
+
+fn google_error_response(error: GoogleDocsError) -> Response {
+    let (status, body) = match &error {
+        GoogleDocsError::Unauthorized(_) => (
+            StatusCode::CONFLICT,
+            ErrorResponse::not_connected("Reconnect your Google account"),
+        ),
+        GoogleDocsError::Api(_) => {
+            tracing::warn!("Google API error: {}", error);
+            (
+                StatusCode::BAD_GATEWAY,
+                ErrorResponse::bad_gateway("Google rejected the request"),
+            )
+        }
+        GoogleDocsError::Unavailable(_) => (
+            StatusCode::SERVICE_UNAVAILABLE,
+            ErrorResponse::unavailable("Google is unavailable; try again shortly"),
+        ),
+    };
+    (status, Json(body)).into_response()
+}
+
+fn export_to_google_docs_error_response(error: ExportToGoogleDocsError) -> Response {
+    match error {
+        ExportToGoogleDocsError::NotConnected => (
+            StatusCode::CONFLICT,
+            Json(ErrorResponse::not_connected(
+                "Connect a Google account first",
+            )),
+        )
+            .into_response(),
+        ExportToGoogleDocsError::Export(e) => export_error_response(e),
+        ExportToGoogleDocsError::Google(e) => google_error_response(e),
+        ExportToGoogleDocsError::Domain(e) => internal_error("Failed to export to Google Docs", e),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn missing_connection_maps_to_409() {
+        let response = export_to_google_docs_error_response(ExportToGoogleDocsError::NotConnected);
+        assert_eq!(response.status(), StatusCode::CONFLICT);
+    }
+
+    #[test]
+    fn revoked_grants_ask_the_user_to_reconnect() {
+        let response =
+            google_error_response(GoogleDocsError::Unauthorized("invalid_grant".to_string()));
+        assert_eq!(response.status(), StatusCode::CONFLICT);
+    }
+
+    #[test]
+    fn google_outages_map_to_503() {
+        let response = google_error_response(GoogleDocsError::Unavailable("timeout".to_string()));
+        assert_eq!(response.status(), StatusCode::SERVICE_UNAVAILABLE);
+    }
+}
@@ -0,0 +1,15 @@
+//! Google Docs HTTP adapter module.
+//!
+//! Connects a user's Google account and publishes decision documents to
+//! Google Docs in their Drive.
+
+pub mod dto;
+pub mod handlers;
+pub mod routes;
+
+pub use dto::{
+    AuthorizeParams, ConnectGoogleRequest, ErrorResponse, GoogleAuthorizationResponse,
+    GoogleConnectionResponse, GoogleDocResponse,
+};
+pub use handlers::GoogleDocsAppState;
+pub use routes::google_docs_routes;
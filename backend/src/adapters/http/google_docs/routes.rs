@@ -0,0 +1,34 @@
+//! HTTP routes for Google Docs endpoints.
+
+use axum::{
+    routing::{get, post},
+    Router,
+};
+
+use super::handlers::{
+    authorize_google, connect_google, disconnect_google, export_to_google_docs,
+    get_google_connection, GoogleDocsAppState,
+};
+
+/// Creates the Google Docs router.
+///
+/// # Routes
+/// - `GET /api/integrations/google` - Whether a Google account is connected
+/// - `DELETE /api/integrations/google` - Forget the connected Google account
+/// - `GET /api/integrations/google/authorize?state=...` - Google consent screen URL
+/// - `POST /api/integrations/google/connect` - Exchange the authorization code and save the account
+/// - `POST /api/cycles/:cycle_id/export/google-docs` - Publish the decision document to Google Docs
+pub fn google_docs_routes(state: GoogleDocsAppState) -> Router {
+    Router::new()
+        .route(
+            "/api/integrations/google",
+            get(get_google_connection).delete(disconnect_google),
+        )
+        .route("/api/integrations/google/authorize", get(authorize_google))
+        .route("/api/integrations/google/connect", post(connect_google))
+        .route(
+            "/api/cycles/:cycle_id/export/google-docs",
+            post(export_to_google_docs),
+        )
+        .with_state(state)
+}
@@ -0,0 +1,117 @@
+//! HTTP DTOs for help content.
+
+use serde::{Deserialize, Serialize};
+
+use crate::domain::foundation::ComponentType;
+use crate::ports::{HelpArticle, HelpArticleKind};
+
+// ════════════════════════════════════════════════════════════════════════════
+// Request DTOs
+// ════════════════════════════════════════════════════════════════════════════
+
+/// Query parameters for listing articles.
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct HelpListQuery {
+    pub kind: Option<HelpArticleKind>,
+}
+
+/// An edited article. The ID and locale come from the path.
+#[derive(Debug, Clone, Deserialize)]
+pub struct SaveHelpArticleRequest {
+    pub kind: HelpArticleKind,
+    #[serde(default)]
+    pub component_type: Option<ComponentType>,
+    pub title: String,
+    pub summary: String,
+    pub body: String,
+    /// Version the edit started from; 0 for a new article or translation.
+    pub expected_version: u32,
+}
+
+// ════════════════════════════════════════════════════════════════════════════
+// Response DTOs
+// ════════════════════════════════════════════════════════════════════════════
+
+/// One version of a help article.
+#[derive(Debug, Clone, Serialize)]
+pub struct HelpArticleResponse {
+    pub id: String,
+    pub locale: String,
+    pub version: u32,
+    pub kind: HelpArticleKind,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub component_type: Option<ComponentType>,
+    pub title: String,
+    pub summary: String,
+    pub body: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub updated_by: Option<String>,
+    pub updated_at: String,
+}
+
+impl From<HelpArticle> for HelpArticleResponse {
+    fn from(article: HelpArticle) -> Self {
+        Self {
+            id: article.id,
+            locale: article.locale,
+            version: article.version,
+            kind: article.kind,
+            component_type: article.component_type,
+            title: article.title,
+            summary: article.summary,
+            body: article.body,
+            updated_by: article.updated_by.map(|u| u.to_string()),
+            updated_at: article.updated_at.as_datetime().to_rfc3339(),
+        }
+    }
+}
+
+/// A list of articles.
+#[derive(Debug, Clone, Serialize)]
+pub struct HelpArticleListResponse {
+    pub articles: Vec<HelpArticleResponse>,
+}
+
+/// Standard error response.
+#[derive(Debug, Clone, Serialize)]
+pub struct ErrorResponse {
+    pub code: String,
+    pub message: String,
+}
+
+impl ErrorResponse {
+    pub fn forbidden(message: impl Into<String>) -> Self {
+        Self {
+            code: "FORBIDDEN".to_string(),
+            message: message.into(),
+        }
+    }
+
+    pub fn bad_request(message: impl Into<String>) -> Self {
+        Self {
+            code: "BAD_REQUEST".to_string(),
+            message: message.into(),
+        }
+    }
+
+    pub fn not_found(message: impl Into<String>) -> Self {
+        Self {
+            code: "NOT_FOUND".to_string(),
+            message: message.into(),
+        }
+    }
+
+    pub fn conflict(message: impl Into<String>) -> Self {
+        Self {
+            code: "CONFLICT".to_string(),
+            message: message.into(),
+        }
+    }
+
+    pub fn internal(message: impl Into<String>) -> Self {
+        Self {
+            code: "INTERNAL_ERROR".to_string(),
+            message: message.into(),
+        }
+    }
+}
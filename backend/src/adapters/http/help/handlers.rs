@@ -0,0 +1,272 @@
+//! HTTP handlers for help content.
+
+use std::collections::{BTreeMap, HashSet};
+use std::sync::Arc;
+
+use axum::{
+    extract::{Path, Query, State},
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    Json,
+};
+
+use crate::adapters::http::middleware::{RequestLocale, RequireAuth};
+use crate::domain::foundation::{Timestamp, UserId};
+use crate::ports::{
+    locale_fallbacks, validate_help_id, HelpArticle, HelpContentError, HelpContentRepository,
+};
+
+use super::dto::{
+    ErrorResponse, HelpArticleListResponse, HelpArticleResponse, HelpListQuery,
+    SaveHelpArticleRequest,
+};
+
+// ════════════════════════════════════════════════════════════════════════════
+// Application State
+// ════════════════════════════════════════════════════════════════════════════
+
+/// Shared state for the help content endpoints.
+#[derive(Clone)]
+pub struct HelpAppState {
+    pub repository: Arc<dyn HelpContentRepository>,
+    /// Users allowed to edit help content.
+    pub admin_user_ids: Arc<HashSet<UserId>>,
+}
+
+type Rejection = (StatusCode, Json<ErrorResponse>);
+
+impl HelpAppState {
+    fn require_admin(&self, user_id: &UserId) -> Result<(), Rejection> {
+        if self.admin_user_ids.contains(user_id) {
+            Ok(())
+        } else {
+            Err((
+                StatusCode::FORBIDDEN,
+                Json(ErrorResponse::forbidden("Admin access required")),
+            ))
+        }
+    }
+}
+
+// ════════════════════════════════════════════════════════════════════════════
+// HTTP handlers
+// ════════════════════════════════════════════════════════════════════════════
+
+/// GET /api/help - Articles in the request locale
+///
+/// Articles without a translation are served in the closest locale that has
+/// one, so the list is always complete.
+pub async fn list_help_articles(
+    State(state): State<HelpAppState>,
+    locale: RequestLocale,
+    Query(query): Query<HelpListQuery>,
+) -> Response {
+    let mut articles: BTreeMap<String, HelpArticle> = BTreeMap::new();
+    for candidate in locale_fallbacks(locale.as_str()) {
+        match state.repository.list(&candidate, query.kind).await {
+            Ok(found) => {
+                for article in found {
+                    articles.entry(article.id.clone()).or_insert(article);
+                }
+            }
+            Err(e) => return handle_help_error(e),
+        }
+    }
+
+    let response = HelpArticleListResponse {
+        articles: articles.into_values().map(Into::into).collect(),
+    };
+    (StatusCode::OK, Json(response)).into_response()
+}
+
+/// GET /api/help/:id - One article in the closest available locale
+pub async fn get_help_article(
+    State(state): State<HelpAppState>,
+    locale: RequestLocale,
+    Path(id): Path<String>,
+) -> Response {
+    match state.repository.get_localized(&id, locale.as_str()).await {
+        Ok(Some(article)) => {
+            (StatusCode::OK, Json(HelpArticleResponse::from(article))).into_response()
+        }
+        Ok(None) => not_found(&id),
+        Err(e) => handle_help_error(e),
+    }
+}
+
+/// PUT /api/admin/help/:id/:locale - Save an edit as a new version
+pub async fn save_help_article(
+    State(state): State<HelpAppState>,
+    RequireAuth(user): RequireAuth,
+    Path((id, locale)): Path<(String, String)>,
+    Json(req): Json<SaveHelpArticleRequest>,
+) -> Response {
+    if let Err(rejection) = state.require_admin(&user.id) {
+        return rejection.into_response();
+    }
+    if let Err(e) = validate_help_id(&id) {
+        return handle_help_error(e);
+    }
+    if req.title.trim().is_empty() || req.summary.trim().is_empty() {
+        return (
+            StatusCode::BAD_REQUEST,
+            Json(ErrorResponse::bad_request("Title and summary are required")),
+        )
+            .into_response();
+    }
+
+    let article = HelpArticle {
+        id,
+        locale: locale.trim().replace('_', "-").to_ascii_lowercase(),
+        version: 0,
+        kind: req.kind,
+        component_type: req.component_type,
+        title: req.title,
+        summary: req.summary,
+        body: req.body,
+        updated_by: Some(user.id.clone()),
+        updated_at: Timestamp::now(),
+    };
+    match state.repository.save(article, req.expected_version).await {
+        Ok(saved) => {
+            tracing::info!(
+                id = %saved.id,
+                locale = %saved.locale,
+                version = saved.version,
+                changed_by = %user.id,
+                "Help article updated"
+            );
+            (StatusCode::OK, Json(HelpArticleResponse::from(saved))).into_response()
+        }
+        Err(e) => handle_help_error(e),
+    }
+}
+
+/// GET /api/admin/help/:id/:locale/history - Every version of an article
+pub async fn get_help_article_history(
+    State(state): State<HelpAppState>,
+    RequireAuth(user): RequireAuth,
+    Path((id, locale)): Path<(String, String)>,
+) -> Response {
+    if let Err(rejection) = state.require_admin(&user.id) {
+        return rejection.into_response();
+    }
+
+    match state.repository.history(&id, &locale).await {
+        Ok(versions) if versions.is_empty() => not_found(&id),
+        Ok(versions) => (
+            StatusCode::OK,
+            Json(HelpArticleListResponse {
+                articles: versions.into_iter().map(Into::into).collect(),
+            }),
+        )
+            .into_response(),
+        Err(e) => handle_help_error(e),
+    }
+}
+
+// ════════════════════════════════════════════════════════════════════════════
+// Error handling
+// ════════════════════════════════════════════════════════════════════════════
+
+fn not_found(id: &str) -> Response {
+    (
+        StatusCode::NOT_FOUND,
+        Json(ErrorResponse::not_found(format!("No help article {}", id))),
+    )
+        .into_response()
+}
+
+fn handle_help_error(error: HelpContentError) -> Response {
+    match error {
+        HelpContentError::InvalidId(_) => (
+            StatusCode::BAD_REQUEST,
+            Json(ErrorResponse::bad_request(error.to_string())),
+        )
+            .into_response(),
+        HelpContentError::VersionConflict { .. } => (
+            StatusCode::CONFLICT,
+            Json(ErrorResponse::conflict(error.to_string())),
+        )
+            .into_response(),
+        HelpContentError::Storage(msg) => {
+            tracing::error!("Help content storage error: {}", msg);
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ErrorResponse::internal("Failed to access help content")),
+            )
+                .into_response()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::adapters::help::InMemoryHelpContentRepository;
+    use crate::ports::HelpArticleKind;
+    use axum::body::to_bytes;
+
+    fn article(id: &str, locale: &str, title: &str) -> HelpArticle {
+        HelpArticle {
+            id: id.to_string(),
+            locale: locale.to_string(),
+            version: 0,
+            kind: HelpArticleKind::Glossary,
+            component_type: None,
+            title: title.to_string(),
+            summary: "Summary".to_string(),
+            body: "Body".to_string(),
+            updated_by: None,
+            updated_at: Timestamp::now(),
+        }
+    }
+
+    #[tokio::test]
+    async fn list_fills_missing_translations_from_default_locale() {
+        let repository = Arc::new(InMemoryHelpContentRepository::new());
+        repository
+            .save(article("glossary.a", "en", "A"), 0)
+            .await
+            .unwrap();
+        repository
+            .save(article("glossary.b", "en", "B"), 0)
+            .await
+            .unwrap();
+        repository
+            .save(article("glossary.a", "es", "A (es)"), 0)
+            .await
+            .unwrap();
+        let state = HelpAppState {
+            repository,
+            admin_user_ids: Arc::new(HashSet::new()),
+        };
+
+        let response = list_help_articles(
+            State(state),
+            RequestLocale("es-MX".to_string()),
+            Query(HelpListQuery::default()),
+        )
+        .await;
+
+        assert_eq!(response.status(), StatusCode::OK);
+        let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        let json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        let titles: Vec<_> = json["articles"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|a| a["title"].as_str().unwrap())
+            .collect();
+        assert_eq!(titles, vec!["A (es)", "B"]);
+    }
+
+    #[test]
+    fn version_conflict_maps_to_409() {
+        let response = handle_help_error(HelpContentError::VersionConflict {
+            expected: 1,
+            actual: 2,
+        });
+        assert_eq!(response.status(), StatusCode::CONFLICT);
+    }
+}
@@ -0,0 +1,14 @@
+//! Help content HTTP adapter module.
+//!
+//! Serves methodology articles in the request locale for "learn more" cards,
+//! and lets admins edit them. Edits are stored as new versions.
+
+pub mod dto;
+pub mod handlers;
+pub mod routes;
+
+pub use dto::{
+    ErrorResponse, HelpArticleListResponse, HelpArticleResponse, SaveHelpArticleRequest,
+};
+pub use handlers::HelpAppState;
+pub use routes::help_routes;
@@ -0,0 +1,29 @@
+//! HTTP routes for help content.
+
+use axum::{
+    routing::{get, put},
+    Router,
+};
+
+use super::handlers::{
+    get_help_article, get_help_article_history, list_help_articles, save_help_article, HelpAppState,
+};
+
+/// Creates the help content router.
+///
+/// # Routes
+/// - `GET /api/help` - Articles in the request locale, optionally `?kind=`
+/// - `GET /api/help/:id` - One article in the closest available locale
+/// - `PUT /api/admin/help/:id/:locale` - Save an edit as a new version (admin)
+/// - `GET /api/admin/help/:id/:locale/history` - Every version of an article (admin)
+pub fn help_routes(state: HelpAppState) -> Router {
+    Router::new()
+        .route("/api/help", get(list_help_articles))
+        .route("/api/help/:id", get(get_help_article))
+        .route("/api/admin/help/:id/:locale", put(save_help_article))
+        .route(
+            "/api/admin/help/:id/:locale/history",
+            get(get_help_article_history),
+        )
+        .with_state(state)
+}
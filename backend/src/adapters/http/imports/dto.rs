@@ -0,0 +1,178 @@
+//! HTTP DTOs for import endpoints.
+
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::application::handlers::cycle::ImportConsequencesResult;
+use crate::domain::conversation::tools::ConfirmationStatus;
+use crate::domain::document::{
+    ColumnMatch, DraftAlternative, DraftObjective, DraftRating, ImportDraft, ImportIssue,
+};
+
+// ════════════════════════════════════════════════════════════════════════════
+// Request DTOs
+// ════════════════════════════════════════════════════════════════════════════
+
+/// Query parameters for a matrix import. The spreadsheet itself is the raw
+/// request body and its format is the request's `Content-Type`.
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct ImportConsequencesParams {
+    /// Used to detect the format when the `Content-Type` is generic.
+    #[serde(default)]
+    pub file_name: Option<String>,
+    /// JSON object from column header to objective ID, e.g.
+    /// `{"Rent":"obj-cost"}`, for headers that do not match an objective.
+    #[serde(default)]
+    pub mapping: Option<String>,
+}
+
+impl ImportConsequencesParams {
+    /// Parses the column mapping, which is empty when not given.
+    pub fn mapping(&self) -> Result<HashMap<String, String>, serde_json::Error> {
+        match self.mapping.as_deref().map(str::trim) {
+            None | Some("") => Ok(HashMap::new()),
+            Some(json) => serde_json::from_str(json),
+        }
+    }
+}
+
+/// Request to draft an import from pasted notes.
+#[derive(Debug, Clone, Deserialize)]
+pub struct DraftImportFromTextRequest {
+    pub text: String,
+}
+
+// ════════════════════════════════════════════════════════════════════════════
+// Response DTOs
+// ════════════════════════════════════════════════════════════════════════════
+
+/// How one column was matched to an objective.
+#[derive(Debug, Clone, Serialize)]
+pub struct ColumnMatchResponse {
+    pub header: String,
+    pub objective_id: String,
+}
+
+impl From<ColumnMatch> for ColumnMatchResponse {
+    fn from(column: ColumnMatch) -> Self {
+        Self {
+            header: column.header,
+            objective_id: column.objective_id,
+        }
+    }
+}
+
+/// Result of a successful import.
+#[derive(Debug, Clone, Serialize)]
+pub struct ImportConsequencesResponse {
+    pub cycle_id: String,
+    pub columns: Vec<ColumnMatchResponse>,
+    /// Names of alternatives created by the import.
+    pub added_alternatives: Vec<String>,
+    /// Cycle version after the import.
+    pub version: u64,
+    pub event_ids: Vec<String>,
+}
+
+impl From<ImportConsequencesResult> for ImportConsequencesResponse {
+    fn from(result: ImportConsequencesResult) -> Self {
+        Self {
+            cycle_id: result.cycle.id().to_string(),
+            columns: result.columns.into_iter().map(Into::into).collect(),
+            added_alternatives: result.added_alternatives,
+            version: result.version,
+            event_ids: result.event_ids.iter().map(ToString::to_string).collect(),
+        }
+    }
+}
+
+/// An AI-drafted import awaiting confirmation.
+#[derive(Debug, Clone, Serialize)]
+pub struct ImportDraftResponse {
+    pub id: String,
+    pub cycle_id: String,
+    pub status: ConfirmationStatus,
+    pub objectives: Vec<DraftObjective>,
+    pub alternatives: Vec<DraftAlternative>,
+    pub ratings: Vec<DraftRating>,
+    /// Why any of the AI's tool calls were left out.
+    pub skipped: Vec<String>,
+    pub created_at: String,
+    pub expires_at: String,
+}
+
+impl From<ImportDraft> for ImportDraftResponse {
+    fn from(draft: ImportDraft) -> Self {
+        Self {
+            id: draft.id().to_string(),
+            cycle_id: draft.cycle_id.to_string(),
+            status: draft.confirmation.status(),
+            created_at: draft.created_at.as_datetime().to_rfc3339(),
+            expires_at: draft.confirmation.expires_at().as_datetime().to_rfc3339(),
+            objectives: draft.objectives,
+            alternatives: draft.alternatives,
+            ratings: draft.ratings,
+            skipped: draft.skipped,
+        }
+    }
+}
+
+/// Every problem found in a sheet that could not be imported.
+#[derive(Debug, Clone, Serialize)]
+pub struct ImportIssuesResponse {
+    pub code: String,
+    pub message: String,
+    pub issues: Vec<ImportIssue>,
+}
+
+/// Standard error response.
+#[derive(Debug, Clone, Serialize)]
+pub struct ErrorResponse {
+    pub code: String,
+    pub message: String,
+}
+
+impl ErrorResponse {
+    pub fn new(code: impl Into<String>, message: impl Into<String>) -> Self {
+        Self {
+            code: code.into(),
+            message: message.into(),
+        }
+    }
+
+    pub fn bad_request(message: impl Into<String>) -> Self {
+        Self::new("BAD_REQUEST", message)
+    }
+
+    pub fn internal(message: impl Into<String>) -> Self {
+        Self::new("INTERNAL_ERROR", message)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn mapping_is_parsed_from_json() {
+        let params = ImportConsequencesParams {
+            file_name: None,
+            mapping: Some(r#"{"Rent":"obj-cost"}"#.to_string()),
+        };
+        assert_eq!(params.mapping().unwrap()["Rent"], "obj-cost");
+        assert!(ImportConsequencesParams::default()
+            .mapping()
+            .unwrap()
+            .is_empty());
+    }
+
+    #[test]
+    fn malformed_mapping_is_an_error() {
+        let params = ImportConsequencesParams {
+            file_name: None,
+            mapping: Some("Rent=obj-cost".to_string()),
+        };
+        assert!(params.mapping().is_err());
+    }
+}
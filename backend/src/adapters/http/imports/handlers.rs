@@ -0,0 +1,459 @@
+//! HTTP handlers for import endpoints.
+
+use std::sync::Arc;
+
+use axum::{
+    body::Bytes,
+    extract::{Path, Query, State},
+    http::{header, HeaderMap, StatusCode},
+    response::{IntoResponse, Response},
+    Json,
+};
+
+use crate::adapters::http::middleware::RequireAuth;
+use crate::application::handlers::cycle::{
+    DraftImportFromTextCommand, DraftImportFromTextError, DraftImportFromTextHandler,
+    ImportConsequencesCommand, ImportConsequencesError, ImportConsequencesHandler,
+    ListImportDraftsError, ListImportDraftsHandler, ListImportDraftsQuery,
+    ResolveImportDraftCommand, ResolveImportDraftError, ResolveImportDraftHandler,
+    UpdateComponentOutputError,
+};
+use crate::domain::foundation::{
+    CommandMetadata, ConfirmationRequestId, CycleId, DomainError, ErrorCode, UserId,
+};
+use crate::ports::{
+    AIProvider, CycleRepository, EventPublisher, ImportDraftRepository, SessionRepository,
+    SpreadsheetError, SpreadsheetFormat, SpreadsheetParser, MAX_SPREADSHEET_BYTES,
+};
+
+use super::dto::{
+    DraftImportFromTextRequest, ErrorResponse, ImportConsequencesParams,
+    ImportConsequencesResponse, ImportDraftResponse, ImportIssuesResponse,
+};
+
+// ════════════════════════════════════════════════════════════════════════════
+// Application State
+// ════════════════════════════════════════════════════════════════════════════
+
+/// Shared state for the import endpoints.
+#[derive(Clone)]
+pub struct ImportAppState {
+    pub cycle_repository: Arc<dyn CycleRepository>,
+    pub session_repository: Arc<dyn SessionRepository>,
+    pub spreadsheet_parser: Arc<dyn SpreadsheetParser>,
+    pub event_publisher: Arc<dyn EventPublisher>,
+    pub ai_provider: Arc<dyn AIProvider>,
+    pub import_drafts: Arc<dyn ImportDraftRepository>,
+}
+
+impl ImportAppState {
+    fn consequences_handler(&self) -> ImportConsequencesHandler {
+        ImportConsequencesHandler::new(
+            self.cycle_repository.clone(),
+            self.session_repository.clone(),
+            self.spreadsheet_parser.clone(),
+            self.event_publisher.clone(),
+        )
+    }
+
+    fn draft_handler(&self) -> DraftImportFromTextHandler {
+        DraftImportFromTextHandler::new(
+            self.cycle_repository.clone(),
+            self.session_repository.clone(),
+            self.ai_provider.clone(),
+            self.import_drafts.clone(),
+        )
+    }
+
+    fn list_drafts_handler(&self) -> ListImportDraftsHandler {
+        ListImportDraftsHandler::new(
+            self.cycle_repository.clone(),
+            self.session_repository.clone(),
+            self.import_drafts.clone(),
+        )
+    }
+
+    fn resolve_draft_handler(&self) -> ResolveImportDraftHandler {
+        ResolveImportDraftHandler::new(
+            self.cycle_repository.clone(),
+            self.session_repository.clone(),
+            self.import_drafts.clone(),
+            self.event_publisher.clone(),
+        )
+    }
+}
+
+// ════════════════════════════════════════════════════════════════════════════
+// HTTP handlers
+// ════════════════════════════════════════════════════════════════════════════
+
+/// POST /api/cycles/:cycle_id/import/consequences?file_name=..&mapping=..
+///
+/// The request body is the spreadsheet; its `Content-Type` header (or the
+/// `file_name` extension) selects CSV or XLSX.
+pub async fn import_consequences(
+    State(state): State<ImportAppState>,
+    RequireAuth(user): RequireAuth,
+    Path(cycle_id): Path<String>,
+    Query(params): Query<ImportConsequencesParams>,
+    headers: HeaderMap,
+    body: Bytes,
+) -> Response {
+    let cycle_id = match cycle_id.parse::<CycleId>() {
+        Ok(id) => id,
+        Err(_) => return bad_request("Invalid cycle ID"),
+    };
+    if body.len() > MAX_SPREADSHEET_BYTES {
+        return (
+            StatusCode::PAYLOAD_TOO_LARGE,
+            Json(ErrorResponse::new(
+                "PAYLOAD_TOO_LARGE",
+                format!(
+                    "Spreadsheet is {} bytes; the limit is {}",
+                    body.len(),
+                    MAX_SPREADSHEET_BYTES
+                ),
+            )),
+        )
+            .into_response();
+    }
+    let mapping = match params.mapping() {
+        Ok(mapping) => mapping,
+        Err(_) => return bad_request("mapping must be a JSON object of header to objective ID"),
+    };
+    let content_type = headers
+        .get(header::CONTENT_TYPE)
+        .and_then(|v| v.to_str().ok())
+        .unwrap_or_default();
+    let format = match SpreadsheetFormat::detect(
+        content_type,
+        params.file_name.as_deref().unwrap_or_default(),
+    ) {
+        Ok(format) => format,
+        Err(e) => return import_error_response(ImportConsequencesError::Unreadable(e)),
+    };
+
+    let cmd = ImportConsequencesCommand {
+        cycle_id,
+        format,
+        bytes: body.to_vec(),
+        mapping,
+    };
+    let metadata = CommandMetadata::new(user.id).with_correlation_id("http-request");
+    match state.consequences_handler().handle(cmd, metadata).await {
+        Ok(result) => (
+            StatusCode::OK,
+            Json(ImportConsequencesResponse::from(result)),
+        )
+            .into_response(),
+        Err(e) => import_error_response(e),
+    }
+}
+
+/// POST /api/cycles/:cycle_id/import/text
+///
+/// Drafts objectives, alternatives, and ratings from pasted notes. The draft
+/// is not applied until confirmed.
+pub async fn draft_import_from_text(
+    State(state): State<ImportAppState>,
+    RequireAuth(user): RequireAuth,
+    Path(cycle_id): Path<String>,
+    Json(request): Json<DraftImportFromTextRequest>,
+) -> Response {
+    let cycle_id = match cycle_id.parse::<CycleId>() {
+        Ok(id) => id,
+        Err(_) => return bad_request("Invalid cycle ID"),
+    };
+
+    let cmd = DraftImportFromTextCommand {
+        cycle_id,
+        text: request.text,
+    };
+    let metadata = CommandMetadata::new(user.id).with_correlation_id("http-request");
+    match state.draft_handler().handle(cmd, metadata).await {
+        Ok(draft) => (StatusCode::CREATED, Json(ImportDraftResponse::from(draft))).into_response(),
+        Err(e) => draft_error_response(e),
+    }
+}
+
+/// GET /api/cycles/:cycle_id/import/drafts
+pub async fn list_import_drafts(
+    State(state): State<ImportAppState>,
+    RequireAuth(user): RequireAuth,
+    Path(cycle_id): Path<String>,
+) -> Response {
+    let cycle_id = match cycle_id.parse::<CycleId>() {
+        Ok(id) => id,
+        Err(_) => return bad_request("Invalid cycle ID"),
+    };
+
+    let query = ListImportDraftsQuery {
+        cycle_id,
+        user_id: user.id,
+    };
+    match state.list_drafts_handler().handle(query).await {
+        Ok(drafts) => {
+            let drafts: Vec<ImportDraftResponse> = drafts.into_iter().map(Into::into).collect();
+            (StatusCode::OK, Json(drafts)).into_response()
+        }
+        Err(e) => list_error_response(e),
+    }
+}
+
+/// POST /api/cycles/:cycle_id/import/drafts/:draft_id/confirm
+pub async fn confirm_import_draft(
+    State(state): State<ImportAppState>,
+    RequireAuth(user): RequireAuth,
+    Path((cycle_id, draft_id)): Path<(String, String)>,
+) -> Response {
+    resolve_draft(state, user.id, cycle_id, draft_id, true).await
+}
+
+/// POST /api/cycles/:cycle_id/import/drafts/:draft_id/discard
+pub async fn discard_import_draft(
+    State(state): State<ImportAppState>,
+    RequireAuth(user): RequireAuth,
+    Path((cycle_id, draft_id)): Path<(String, String)>,
+) -> Response {
+    resolve_draft(state, user.id, cycle_id, draft_id, false).await
+}
+
+// ════════════════════════════════════════════════════════════════════════════
+// Helpers
+// ════════════════════════════════════════════════════════════════════════════
+
+async fn resolve_draft(
+    state: ImportAppState,
+    user_id: UserId,
+    cycle_id: String,
+    draft_id: String,
+    apply: bool,
+) -> Response {
+    let cycle_id = match cycle_id.parse::<CycleId>() {
+        Ok(id) => id,
+        Err(_) => return bad_request("Invalid cycle ID"),
+    };
+    let draft_id = match draft_id.parse::<ConfirmationRequestId>() {
+        Ok(id) => id,
+        Err(_) => return bad_request("Invalid draft ID"),
+    };
+
+    let cmd = ResolveImportDraftCommand {
+        cycle_id,
+        draft_id,
+        apply,
+    };
+    let metadata = CommandMetadata::new(user_id).with_correlation_id("http-request");
+    match state.resolve_draft_handler().handle(cmd, metadata).await {
+        Ok(result) => (
+            StatusCode::OK,
+            Json(ImportDraftResponse::from(result.draft)),
+        )
+            .into_response(),
+        Err(e) => resolve_error_response(e),
+    }
+}
+
+fn bad_request(message: &str) -> Response {
+    (
+        StatusCode::BAD_REQUEST,
+        Json(ErrorResponse::bad_request(message)),
+    )
+        .into_response()
+}
+
+// ════════════════════════════════════════════════════════════════════════════
+// Error handling
+// ════════════════════════════════════════════════════════════════════════════
+
+fn import_error_response(error: ImportConsequencesError) -> Response {
+    let (status, body) = match &error {
+        ImportConsequencesError::CycleNotFound(_)
+        | ImportConsequencesError::Update(UpdateComponentOutputError::CycleNotFound(_)) => (
+            StatusCode::NOT_FOUND,
+            ErrorResponse::new("CYCLE_NOT_FOUND", error.to_string()),
+        ),
+        ImportConsequencesError::Forbidden => (
+            StatusCode::FORBIDDEN,
+            ErrorResponse::new("FORBIDDEN", error.to_string()),
+        ),
+        ImportConsequencesError::Unreadable(SpreadsheetError::UnsupportedFormat(_)) => (
+            StatusCode::UNSUPPORTED_MEDIA_TYPE,
+            ErrorResponse::new("UNSUPPORTED_FORMAT", error.to_string()),
+        ),
+        ImportConsequencesError::Unreadable(SpreadsheetError::Malformed(_)) => (
+            StatusCode::UNPROCESSABLE_ENTITY,
+            ErrorResponse::new(ErrorCode::ValidationFailed.to_string(), error.to_string()),
+        ),
+        ImportConsequencesError::Invalid(issues) => {
+            let body = ImportIssuesResponse {
+                code: ErrorCode::ValidationFailed.to_string(),
+                message: error.to_string(),
+                issues: issues.clone(),
+            };
+            return (StatusCode::UNPROCESSABLE_ENTITY, Json(body)).into_response();
+        }
+        ImportConsequencesError::Update(UpdateComponentOutputError::Domain(err))
+        | ImportConsequencesError::Domain(err)
+            if is_state_conflict(err) =>
+        {
+            (
+                StatusCode::CONFLICT,
+                ErrorResponse::new(err.code.to_string(), err.to_string()),
+            )
+        }
+        ImportConsequencesError::Update(_) | ImportConsequencesError::Domain(_) => {
+            tracing::error!("Consequences import failed: {}", error);
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                ErrorResponse::internal("Failed to import the spreadsheet"),
+            )
+        }
+    };
+    (status, Json(body)).into_response()
+}
+
+fn draft_error_response(error: DraftImportFromTextError) -> Response {
+    let (status, body) = match &error {
+        DraftImportFromTextError::CycleNotFound(_) => (
+            StatusCode::NOT_FOUND,
+            ErrorResponse::new("CYCLE_NOT_FOUND", error.to_string()),
+        ),
+        DraftImportFromTextError::Forbidden => (
+            StatusCode::FORBIDDEN,
+            ErrorResponse::new("FORBIDDEN", error.to_string()),
+        ),
+        DraftImportFromTextError::InvalidText(_) | DraftImportFromTextError::NothingDrafted(_) => (
+            StatusCode::UNPROCESSABLE_ENTITY,
+            ErrorResponse::new(ErrorCode::ValidationFailed.to_string(), error.to_string()),
+        ),
+        DraftImportFromTextError::AIProvider(_) => {
+            tracing::error!("Text import drafting failed: {}", error);
+            (
+                StatusCode::BAD_GATEWAY,
+                ErrorResponse::new("AI_PROVIDER_ERROR", "The AI could not draft the import"),
+            )
+        }
+        DraftImportFromTextError::Domain(_) => {
+            tracing::error!("Text import drafting failed: {}", error);
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                ErrorResponse::internal("Failed to draft the import"),
+            )
+        }
+    };
+    (status, Json(body)).into_response()
+}
+
+fn list_error_response(error: ListImportDraftsError) -> Response {
+    let (status, body) = match &error {
+        ListImportDraftsError::CycleNotFound(_) => (
+            StatusCode::NOT_FOUND,
+            ErrorResponse::new("CYCLE_NOT_FOUND", error.to_string()),
+        ),
+        ListImportDraftsError::Forbidden => (
+            StatusCode::FORBIDDEN,
+            ErrorResponse::new("FORBIDDEN", error.to_string()),
+        ),
+        ListImportDraftsError::Domain(_) => {
+            tracing::error!("Listing import drafts failed: {}", error);
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                ErrorResponse::internal("Failed to list import drafts"),
+            )
+        }
+    };
+    (status, Json(body)).into_response()
+}
+
+fn resolve_error_response(error: ResolveImportDraftError) -> Response {
+    let (status, body) = match &error {
+        ResolveImportDraftError::DraftNotFound(_)
+        | ResolveImportDraftError::Update(UpdateComponentOutputError::CycleNotFound(_)) => (
+            StatusCode::NOT_FOUND,
+            ErrorResponse::new("DRAFT_NOT_FOUND", error.to_string()),
+        ),
+        ResolveImportDraftError::Forbidden => (
+            StatusCode::FORBIDDEN,
+            ErrorResponse::new("FORBIDDEN", error.to_string()),
+        ),
+        ResolveImportDraftError::AlreadyResolved | ResolveImportDraftError::Expired => (
+            StatusCode::CONFLICT,
+            ErrorResponse::new("DRAFT_RESOLVED", error.to_string()),
+        ),
+        ResolveImportDraftError::Update(UpdateComponentOutputError::Domain(err))
+        | ResolveImportDraftError::Domain(err)
+            if is_state_conflict(err) =>
+        {
+            (
+                StatusCode::CONFLICT,
+                ErrorResponse::new(err.code.to_string(), err.to_string()),
+            )
+        }
+        ResolveImportDraftError::Update(_) | ResolveImportDraftError::Domain(_) => {
+            tracing::error!("Resolving import draft failed: {}", error);
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                ErrorResponse::internal("Failed to resolve the import draft"),
+            )
+        }
+    };
+    (status, Json(body)).into_response()
+}
+
+/// Errors caused by the cycle's state rather than by the server.
+fn is_state_conflict(error: &DomainError) -> bool {
+    matches!(
+        error.code,
+        ErrorCode::ComponentLocked
+            | ErrorCode::CycleArchived
+            | ErrorCode::InvalidStateTransition
+            | ErrorCode::InvalidComponentOutput
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::document::ImportIssue;
+
+    #[test]
+    fn sheet_problems_map_to_422() {
+        let response = import_error_response(ImportConsequencesError::Invalid(vec![ImportIssue {
+            row: Some(2),
+            column: Some("Cost".to_string()),
+            message: "Expected a number".to_string(),
+        }]));
+        assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+    }
+
+    #[test]
+    fn unsupported_files_map_to_415() {
+        let response = import_error_response(ImportConsequencesError::Unreadable(
+            SpreadsheetError::UnsupportedFormat("application/pdf".to_string()),
+        ));
+        assert_eq!(response.status(), StatusCode::UNSUPPORTED_MEDIA_TYPE);
+    }
+
+    #[test]
+    fn locked_components_map_to_409() {
+        let response = import_error_response(ImportConsequencesError::Domain(DomainError::new(
+            ErrorCode::ComponentLocked,
+            "Consequences is Complete",
+        )));
+        assert_eq!(response.status(), StatusCode::CONFLICT);
+    }
+
+    #[test]
+    fn resolved_drafts_map_to_409() {
+        let response = resolve_error_response(ResolveImportDraftError::AlreadyResolved);
+        assert_eq!(response.status(), StatusCode::CONFLICT);
+    }
+
+    #[test]
+    fn ai_failures_map_to_502() {
+        let response =
+            draft_error_response(DraftImportFromTextError::AIProvider("timeout".to_string()));
+        assert_eq!(response.status(), StatusCode::BAD_GATEWAY);
+    }
+}
@@ -0,0 +1,16 @@
+//! Imports HTTP adapter module.
+//!
+//! Imports a spreadsheet comparison matrix (CSV or XLSX) into a cycle's
+//! Alternatives and Consequences outputs, and drafts objectives,
+//! alternatives, and ratings from pasted notes for the user to confirm.
+
+pub mod dto;
+pub mod handlers;
+pub mod routes;
+
+pub use dto::{
+    ColumnMatchResponse, DraftImportFromTextRequest, ErrorResponse, ImportConsequencesParams,
+    ImportConsequencesResponse, ImportDraftResponse, ImportIssuesResponse,
+};
+pub use handlers::ImportAppState;
+pub use routes::import_routes;
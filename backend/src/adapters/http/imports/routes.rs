@@ -0,0 +1,49 @@
+//! HTTP routes for import endpoints.
+
+use axum::{
+    extract::DefaultBodyLimit,
+    routing::{get, post},
+    Router,
+};
+
+use crate::ports::MAX_SPREADSHEET_BYTES;
+
+use super::handlers::{
+    confirm_import_draft, discard_import_draft, draft_import_from_text, import_consequences,
+    list_import_drafts, ImportAppState,
+};
+
+/// Creates the imports router.
+///
+/// # Routes
+/// - `POST /api/cycles/:cycle_id/import/consequences` - Import a comparison matrix (raw CSV/XLSX body)
+/// - `POST /api/cycles/:cycle_id/import/text` - Draft an import from pasted notes
+/// - `GET /api/cycles/:cycle_id/import/drafts` - List drafts awaiting confirmation
+/// - `POST /api/cycles/:cycle_id/import/drafts/:draft_id/confirm` - Apply a draft
+/// - `POST /api/cycles/:cycle_id/import/drafts/:draft_id/discard` - Discard a draft
+pub fn import_routes(state: ImportAppState) -> Router {
+    Router::new()
+        .route(
+            "/api/cycles/:cycle_id/import/consequences",
+            post(import_consequences),
+        )
+        .route(
+            "/api/cycles/:cycle_id/import/text",
+            post(draft_import_from_text),
+        )
+        .route(
+            "/api/cycles/:cycle_id/import/drafts",
+            get(list_import_drafts),
+        )
+        .route(
+            "/api/cycles/:cycle_id/import/drafts/:draft_id/confirm",
+            post(confirm_import_draft),
+        )
+        .route(
+            "/api/cycles/:cycle_id/import/drafts/:draft_id/discard",
+            post(discard_import_draft),
+        )
+        // Leave room for the limit to be reported as a domain error
+        .layer(DefaultBodyLimit::max(MAX_SPREADSHEET_BYTES + 1))
+        .with_state(state)
+}
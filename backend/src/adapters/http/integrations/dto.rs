@@ -0,0 +1,168 @@
+//! HTTP DTOs for integration endpoints.
+
+use serde::{Deserialize, Serialize};
+
+use crate::application::handlers::IntegrationSpec;
+use crate::domain::document::DeliveryStatus;
+use crate::domain::foundation::Timestamp;
+use crate::domain::integration::{
+    EventFilter, Integration, IntegrationAction, IntegrationDelivery,
+};
+
+// ════════════════════════════════════════════════════════════════════════════
+// Request DTOs
+// ════════════════════════════════════════════════════════════════════════════
+
+/// Request to create or replace an integration.
+#[derive(Debug, Clone, Deserialize)]
+pub struct IntegrationRequest {
+    pub name: String,
+    pub filter: EventFilter,
+    pub action: IntegrationAction,
+    /// Tera template for the action payload; a default is used when absent.
+    #[serde(default)]
+    pub template: Option<String>,
+    /// Ignored on create; new integrations start enabled.
+    #[serde(default = "default_enabled")]
+    pub enabled: bool,
+}
+
+fn default_enabled() -> bool {
+    true
+}
+
+impl IntegrationRequest {
+    /// Splits the request into its spec and enabled flag.
+    pub fn into_spec(self) -> (IntegrationSpec, bool) {
+        let spec = IntegrationSpec {
+            name: self.name,
+            filter: self.filter,
+            action: self.action,
+            template: self.template,
+        };
+        (spec, self.enabled)
+    }
+}
+
+// ════════════════════════════════════════════════════════════════════════════
+// Response DTOs
+// ════════════════════════════════════════════════════════════════════════════
+
+/// A configured integration.
+#[derive(Debug, Clone, Serialize)]
+pub struct IntegrationResponse {
+    pub id: String,
+    pub name: String,
+    pub filter: EventFilter,
+    pub action: IntegrationAction,
+    pub template: Option<String>,
+    pub enabled: bool,
+    pub created_at: Timestamp,
+    pub updated_at: Timestamp,
+}
+
+impl From<Integration> for IntegrationResponse {
+    fn from(integration: Integration) -> Self {
+        Self {
+            id: integration.id.to_string(),
+            name: integration.name,
+            filter: integration.filter,
+            action: integration.action,
+            template: integration.template,
+            enabled: integration.enabled,
+            created_at: integration.created_at,
+            updated_at: integration.updated_at,
+        }
+    }
+}
+
+/// One triggered run of an integration's action.
+#[derive(Debug, Clone, Serialize)]
+pub struct IntegrationDeliveryResponse {
+    pub id: String,
+    pub event_id: String,
+    pub event_type: String,
+    pub status: DeliveryStatus,
+    pub attempts: u32,
+    pub last_error: Option<String>,
+    /// When the next retry is scheduled; only set while pending.
+    pub next_attempt_at: Option<Timestamp>,
+    pub created_at: Timestamp,
+    pub sent_at: Option<Timestamp>,
+}
+
+impl From<IntegrationDelivery> for IntegrationDeliveryResponse {
+    fn from(delivery: IntegrationDelivery) -> Self {
+        let next_attempt_at =
+            (delivery.status == DeliveryStatus::Pending).then_some(delivery.next_attempt_at);
+        Self {
+            id: delivery.id.to_string(),
+            event_id: delivery.event_id.to_string(),
+            event_type: delivery.event_type,
+            status: delivery.status,
+            attempts: delivery.attempts,
+            last_error: delivery.last_error,
+            next_attempt_at,
+            created_at: delivery.created_at,
+            sent_at: delivery.sent_at,
+        }
+    }
+}
+
+/// Standard error response.
+#[derive(Debug, Clone, Serialize)]
+pub struct ErrorResponse {
+    pub code: String,
+    pub message: String,
+}
+
+impl ErrorResponse {
+    pub fn bad_request(message: impl Into<String>) -> Self {
+        Self {
+            code: "BAD_REQUEST".to_string(),
+            message: message.into(),
+        }
+    }
+
+    pub fn not_found(message: impl Into<String>) -> Self {
+        Self {
+            code: "NOT_FOUND".to_string(),
+            message: message.into(),
+        }
+    }
+
+    pub fn limit_reached(message: impl Into<String>) -> Self {
+        Self {
+            code: "LIMIT_REACHED".to_string(),
+            message: message.into(),
+        }
+    }
+
+    pub fn internal(message: impl Into<String>) -> Self {
+        Self {
+            code: "INTERNAL_ERROR".to_string(),
+            message: message.into(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn request_parses_tagged_action_and_defaults_enabled() {
+        let request: IntegrationRequest = serde_json::from_value(serde_json::json!({
+            "name": "Notify ops",
+            "filter": { "event_types": ["cycle.completed.v1"] },
+            "action": { "type": "slack", "team_id": "T1", "channel": "C1" }
+        }))
+        .unwrap();
+
+        assert!(request.enabled);
+        let (spec, _) = request.into_spec();
+        assert!(spec.filter.conditions.is_empty());
+        assert!(matches!(spec.action, IntegrationAction::Slack { .. }));
+        assert!(spec.template.is_none());
+    }
+}
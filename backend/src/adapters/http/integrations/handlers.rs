@@ -0,0 +1,224 @@
+//! HTTP handlers for integration endpoints.
+
+use std::sync::Arc;
+
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    Json,
+};
+
+use crate::adapters::http::middleware::RequireAuth;
+use crate::application::handlers::{
+    CreateIntegrationCommand, CreateIntegrationHandler, DeleteIntegrationCommand,
+    DeleteIntegrationHandler, ListIntegrationsHandler, ManageIntegrationError,
+    UpdateIntegrationCommand, UpdateIntegrationHandler,
+};
+use crate::domain::foundation::IntegrationId;
+use crate::ports::{
+    IntegrationActionAdapter, IntegrationDeliveryRepository, IntegrationRepository,
+    IntegrationTemplateEngine,
+};
+
+use super::dto::{
+    ErrorResponse, IntegrationDeliveryResponse, IntegrationRequest, IntegrationResponse,
+};
+
+// ════════════════════════════════════════════════════════════════════════════
+// Application State
+// ════════════════════════════════════════════════════════════════════════════
+
+/// Shared state for the integration endpoints.
+#[derive(Clone)]
+pub struct IntegrationsAppState {
+    pub integrations: Arc<dyn IntegrationRepository>,
+    pub deliveries: Arc<dyn IntegrationDeliveryRepository>,
+    pub templates: Arc<dyn IntegrationTemplateEngine>,
+    /// Action adapters available in this deployment.
+    pub adapters: Vec<Arc<dyn IntegrationActionAdapter>>,
+}
+
+impl IntegrationsAppState {
+    pub fn create_handler(&self) -> CreateIntegrationHandler {
+        CreateIntegrationHandler::new(
+            self.integrations.clone(),
+            self.templates.clone(),
+            self.adapters.clone(),
+        )
+    }
+
+    pub fn update_handler(&self) -> UpdateIntegrationHandler {
+        UpdateIntegrationHandler::new(
+            self.integrations.clone(),
+            self.templates.clone(),
+            self.adapters.clone(),
+        )
+    }
+
+    pub fn delete_handler(&self) -> DeleteIntegrationHandler {
+        DeleteIntegrationHandler::new(self.integrations.clone())
+    }
+
+    pub fn list_handler(&self) -> ListIntegrationsHandler {
+        ListIntegrationsHandler::new(self.integrations.clone(), self.deliveries.clone())
+    }
+}
+
+// ════════════════════════════════════════════════════════════════════════════
+// HTTP handlers
+// ════════════════════════════════════════════════════════════════════════════
+
+/// GET /api/integrations - List the caller's integrations
+pub async fn list_integrations(
+    State(state): State<IntegrationsAppState>,
+    RequireAuth(user): RequireAuth,
+) -> Response {
+    match state.list_handler().list(&user.id).await {
+        Ok(integrations) => {
+            let response: Vec<IntegrationResponse> = integrations
+                .into_iter()
+                .map(IntegrationResponse::from)
+                .collect();
+            (StatusCode::OK, Json(response)).into_response()
+        }
+        Err(e) => handle_error(e),
+    }
+}
+
+/// POST /api/integrations - Create an integration
+pub async fn create_integration(
+    State(state): State<IntegrationsAppState>,
+    RequireAuth(user): RequireAuth,
+    Json(req): Json<IntegrationRequest>,
+) -> Response {
+    let (spec, _) = req.into_spec();
+    let cmd = CreateIntegrationCommand {
+        user_id: user.id,
+        spec,
+    };
+
+    match state.create_handler().handle(cmd).await {
+        Ok(integration) => (
+            StatusCode::CREATED,
+            Json(IntegrationResponse::from(integration)),
+        )
+            .into_response(),
+        Err(e) => handle_error(e),
+    }
+}
+
+/// PUT /api/integrations/:id - Replace an integration's configuration
+pub async fn update_integration(
+    State(state): State<IntegrationsAppState>,
+    RequireAuth(user): RequireAuth,
+    Path(id): Path<String>,
+    Json(req): Json<IntegrationRequest>,
+) -> Response {
+    let integration_id = match id.parse::<IntegrationId>() {
+        Ok(id) => id,
+        Err(_) => return invalid_id(),
+    };
+    let (spec, enabled) = req.into_spec();
+    let cmd = UpdateIntegrationCommand {
+        user_id: user.id,
+        integration_id,
+        spec,
+        enabled,
+    };
+
+    match state.update_handler().handle(cmd).await {
+        Ok(integration) => {
+            (StatusCode::OK, Json(IntegrationResponse::from(integration))).into_response()
+        }
+        Err(e) => handle_error(e),
+    }
+}
+
+/// DELETE /api/integrations/:id - Delete an integration
+pub async fn delete_integration(
+    State(state): State<IntegrationsAppState>,
+    RequireAuth(user): RequireAuth,
+    Path(id): Path<String>,
+) -> Response {
+    let integration_id = match id.parse::<IntegrationId>() {
+        Ok(id) => id,
+        Err(_) => return invalid_id(),
+    };
+    let cmd = DeleteIntegrationCommand {
+        user_id: user.id,
+        integration_id,
+    };
+
+    match state.delete_handler().handle(cmd).await {
+        Ok(()) => StatusCode::NO_CONTENT.into_response(),
+        Err(e) => handle_error(e),
+    }
+}
+
+/// GET /api/integrations/:id/deliveries - List an integration's recent deliveries
+pub async fn list_integration_deliveries(
+    State(state): State<IntegrationsAppState>,
+    RequireAuth(user): RequireAuth,
+    Path(id): Path<String>,
+) -> Response {
+    let integration_id = match id.parse::<IntegrationId>() {
+        Ok(id) => id,
+        Err(_) => return invalid_id(),
+    };
+
+    match state
+        .list_handler()
+        .deliveries(&user.id, integration_id)
+        .await
+    {
+        Ok(deliveries) => {
+            let response: Vec<IntegrationDeliveryResponse> = deliveries
+                .into_iter()
+                .map(IntegrationDeliveryResponse::from)
+                .collect();
+            (StatusCode::OK, Json(response)).into_response()
+        }
+        Err(e) => handle_error(e),
+    }
+}
+
+// ════════════════════════════════════════════════════════════════════════════
+// Error handling
+// ════════════════════════════════════════════════════════════════════════════
+
+fn invalid_id() -> Response {
+    (
+        StatusCode::BAD_REQUEST,
+        Json(ErrorResponse::bad_request("Invalid integration ID")),
+    )
+        .into_response()
+}
+
+fn handle_error(error: ManageIntegrationError) -> Response {
+    let (status, body) = match &error {
+        // Other users' integrations are reported as missing
+        ManageIntegrationError::NotFound(_) => (
+            StatusCode::NOT_FOUND,
+            ErrorResponse::not_found(error.to_string()),
+        ),
+        ManageIntegrationError::Invalid(_)
+        | ManageIntegrationError::InvalidTemplate(_)
+        | ManageIntegrationError::ActionUnavailable(_) => (
+            StatusCode::BAD_REQUEST,
+            ErrorResponse::bad_request(error.to_string()),
+        ),
+        ManageIntegrationError::LimitReached => (
+            StatusCode::CONFLICT,
+            ErrorResponse::limit_reached(error.to_string()),
+        ),
+        ManageIntegrationError::Domain(e) => {
+            tracing::error!("Integration request failed: {}", e);
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                ErrorResponse::internal("Failed to process integration request"),
+            )
+        }
+    };
+    (status, Json(body)).into_response()
+}
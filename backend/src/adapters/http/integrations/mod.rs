@@ -0,0 +1,14 @@
+//! Integrations HTTP adapter module.
+//!
+//! Lets users configure event-triggered outbound integrations (HTTP POST,
+//! Slack, or email) and inspect their delivery history.
+
+pub mod dto;
+pub mod handlers;
+pub mod routes;
+
+pub use dto::{
+    ErrorResponse, IntegrationDeliveryResponse, IntegrationRequest, IntegrationResponse,
+};
+pub use handlers::IntegrationsAppState;
+pub use routes::integration_routes;
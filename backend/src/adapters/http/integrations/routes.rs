@@ -0,0 +1,36 @@
+//! HTTP routes for integration endpoints.
+
+use axum::{
+    routing::{get, put},
+    Router,
+};
+
+use super::handlers::{
+    create_integration, delete_integration, list_integration_deliveries, list_integrations,
+    update_integration, IntegrationsAppState,
+};
+
+/// Creates the integrations router.
+///
+/// # Routes
+/// - `GET /api/integrations` - List the caller's integrations
+/// - `POST /api/integrations` - Create an integration
+/// - `PUT /api/integrations/:id` - Replace an integration's configuration
+/// - `DELETE /api/integrations/:id` - Delete an integration
+/// - `GET /api/integrations/:id/deliveries` - List an integration's recent deliveries
+pub fn integration_routes(state: IntegrationsAppState) -> Router {
+    Router::new()
+        .route(
+            "/api/integrations",
+            get(list_integrations).post(create_integration),
+        )
+        .route(
+            "/api/integrations/:id",
+            put(update_integration).delete(delete_integration),
+        )
+        .route(
+            "/api/integrations/:id/deliveries",
+            get(list_integration_deliveries),
+        )
+        .with_state(state)
+}
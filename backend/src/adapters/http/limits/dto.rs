@@ -0,0 +1,174 @@
+//! HTTP DTOs for the limits endpoint.
+
+use serde::Serialize;
+
+use crate::application::handlers::membership::{AiBudget, GetLimitsResult, RateLimitBucket};
+use crate::domain::membership::MembershipTier;
+
+// ════════════════════════════════════════════════════════════════════════════
+// Response DTOs
+// ════════════════════════════════════════════════════════════════════════════
+
+/// Everything that can block the caller's next action.
+#[derive(Debug, Clone, Serialize)]
+pub struct LimitsResponse {
+    pub tier: MembershipTier,
+    pub rate_limits: Vec<RateLimitBucketResponse>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ai_budget: Option<AiBudgetResponse>,
+    pub active_sessions: QuotaResponse,
+    /// Cap applies per session; `used` is not tracked per session here.
+    pub cycles_per_session: QuotaResponse,
+    pub total_cycles: u32,
+    pub exports_this_month: u32,
+    pub export_enabled: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ai_messages_per_day: Option<u32>,
+}
+
+/// A usage count against an optional cap (`None` = unlimited).
+#[derive(Debug, Clone, Serialize)]
+pub struct QuotaResponse {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub used: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub remaining: Option<u32>,
+}
+
+impl QuotaResponse {
+    fn new(used: Option<u32>, max: Option<u32>) -> Self {
+        let remaining = match (used, max) {
+            (Some(used), Some(max)) => Some(max.saturating_sub(used)),
+            _ => None,
+        };
+        Self {
+            used,
+            max,
+            remaining,
+        }
+    }
+}
+
+/// Current state of one rate-limit bucket.
+#[derive(Debug, Clone, Serialize)]
+pub struct RateLimitBucketResponse {
+    pub resource: String,
+    pub limit: u32,
+    pub remaining: u32,
+    pub window_secs: u32,
+    pub reset_at: String,
+}
+
+impl From<RateLimitBucket> for RateLimitBucketResponse {
+    fn from(bucket: RateLimitBucket) -> Self {
+        Self {
+            resource: bucket.resource,
+            limit: bucket.limit,
+            remaining: bucket.remaining,
+            window_secs: bucket.window_secs,
+            reset_at: bucket.reset_at.as_datetime().to_rfc3339(),
+        }
+    }
+}
+
+/// Remaining AI spend for the current UTC day.
+#[derive(Debug, Clone, Serialize)]
+pub struct AiBudgetResponse {
+    pub limit_cents: u32,
+    pub spent_cents: u32,
+    pub remaining_cents: u32,
+    pub reset_at: String,
+}
+
+impl From<AiBudget> for AiBudgetResponse {
+    fn from(budget: AiBudget) -> Self {
+        Self {
+            limit_cents: budget.limit_cents,
+            spent_cents: budget.spent_cents,
+            remaining_cents: budget.remaining_cents,
+            reset_at: budget.reset_at.as_datetime().to_rfc3339(),
+        }
+    }
+}
+
+impl From<GetLimitsResult> for LimitsResponse {
+    fn from(result: GetLimitsResult) -> Self {
+        let limits = result.tier_limits;
+        Self {
+            tier: limits.tier,
+            rate_limits: result.rate_limits.into_iter().map(Into::into).collect(),
+            ai_budget: result.ai_budget.map(Into::into),
+            active_sessions: QuotaResponse::new(
+                Some(result.usage.active_sessions),
+                limits.max_active_sessions,
+            ),
+            cycles_per_session: QuotaResponse::new(None, limits.max_cycles_per_session),
+            total_cycles: result.usage.total_cycles,
+            exports_this_month: result.usage.exports_this_month,
+            export_enabled: limits.pdf_export_enabled,
+            ai_messages_per_day: limits.ai_messages_per_day,
+        }
+    }
+}
+
+/// Standard error response.
+#[derive(Debug, Clone, Serialize)]
+pub struct ErrorResponse {
+    pub code: String,
+    pub message: String,
+}
+
+impl ErrorResponse {
+    pub fn internal(message: impl Into<String>) -> Self {
+        Self {
+            code: "INTERNAL_ERROR".to_string(),
+            message: message.into(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::foundation::Timestamp;
+    use crate::domain::membership::TierLimits;
+    use crate::ports::UsageStats;
+
+    #[test]
+    fn response_reports_remaining_session_quota() {
+        let result = GetLimitsResult {
+            tier_limits: TierLimits::free(),
+            usage: UsageStats {
+                active_sessions: 2,
+                total_cycles: 4,
+                exports_this_month: 0,
+            },
+            rate_limits: vec![RateLimitBucket {
+                resource: "general".to_string(),
+                limit: 60,
+                remaining: 59,
+                window_secs: 60,
+                reset_at: Timestamp::from_unix_secs(1_700_000_000),
+            }],
+            ai_budget: None,
+        };
+
+        let json = serde_json::to_value(LimitsResponse::from(result)).unwrap();
+        assert_eq!(json["tier"], "free");
+        assert_eq!(json["active_sessions"]["remaining"], 1);
+        assert_eq!(json["cycles_per_session"]["max"], 2);
+        assert!(json["cycles_per_session"].get("used").is_none());
+        assert_eq!(json["rate_limits"][0]["reset_at"], "2023-11-14T22:13:20+00:00");
+        assert!(json.get("ai_budget").is_none());
+    }
+
+    #[test]
+    fn unlimited_quota_omits_max_and_remaining() {
+        let quota = serde_json::to_value(QuotaResponse::new(Some(7), None)).unwrap();
+        assert_eq!(quota["used"], 7);
+        assert!(quota.get("max").is_none());
+        assert!(quota.get("remaining").is_none());
+    }
+}
@@ -0,0 +1,65 @@
+//! HTTP handlers for the limits endpoint.
+
+use std::sync::Arc;
+
+use axum::{
+    extract::State,
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    Json,
+};
+
+use crate::adapters::http::middleware::RequireAuth;
+use crate::application::handlers::membership::{GetLimitsHandler, GetLimitsQuery};
+use crate::ports::{AccessChecker, RateLimiter, UsageTracker};
+
+use super::dto::{ErrorResponse, LimitsResponse};
+
+// ════════════════════════════════════════════════════════════════════════════
+// Application State
+// ════════════════════════════════════════════════════════════════════════════
+
+/// Shared state for the limits endpoint.
+#[derive(Clone)]
+pub struct LimitsAppState {
+    pub access_checker: Arc<dyn AccessChecker>,
+    pub rate_limiter: Arc<dyn RateLimiter>,
+    pub usage_tracker: Arc<dyn UsageTracker>,
+    /// Daily AI spend allowed per user, if enforced.
+    pub daily_ai_budget_cents: Option<u32>,
+}
+
+impl LimitsAppState {
+    pub fn get_limits_handler(&self) -> GetLimitsHandler {
+        GetLimitsHandler::new(
+            self.access_checker.clone(),
+            self.rate_limiter.clone(),
+            self.usage_tracker.clone(),
+            self.daily_ai_budget_cents,
+        )
+    }
+}
+
+// ════════════════════════════════════════════════════════════════════════════
+// HTTP handlers
+// ════════════════════════════════════════════════════════════════════════════
+
+/// GET /api/limits - Current quotas and reset times for the caller
+pub async fn get_limits(
+    State(state): State<LimitsAppState>,
+    RequireAuth(user): RequireAuth,
+) -> Response {
+    let query = GetLimitsQuery { user_id: user.id };
+
+    match state.get_limits_handler().handle(query).await {
+        Ok(result) => (StatusCode::OK, Json(LimitsResponse::from(result))).into_response(),
+        Err(e) => {
+            tracing::error!("Failed to load limits: {}", e);
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ErrorResponse::internal("Failed to load limits")),
+            )
+                .into_response()
+        }
+    }
+}
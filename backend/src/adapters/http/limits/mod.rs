@@ -0,0 +1,15 @@
+//! Limits HTTP adapter module.
+//!
+//! User endpoint reporting rate-limit buckets, the daily AI budget, and
+//! tier caps in one response, so the frontend can disable actions before
+//! they are rejected.
+
+pub mod dto;
+pub mod handlers;
+pub mod routes;
+
+pub use dto::{
+    AiBudgetResponse, ErrorResponse, LimitsResponse, QuotaResponse, RateLimitBucketResponse,
+};
+pub use handlers::LimitsAppState;
+pub use routes::limits_routes;
@@ -0,0 +1,15 @@
+//! HTTP routes for the limits endpoint.
+
+use axum::{routing::get, Router};
+
+use super::handlers::{get_limits, LimitsAppState};
+
+/// Creates the limits router.
+///
+/// # Routes
+/// - `GET /api/limits` - Current quotas and reset times for the caller
+pub fn limits_routes(state: LimitsAppState) -> Router {
+    Router::new()
+        .route("/api/limits", get(get_limits))
+        .with_state(state)
+}
@@ -6,20 +6,21 @@
 use crate::domain::membership::{MembershipStatus, MembershipTier, TierLimits};
 use crate::ports::{MembershipStatistics, MembershipView};
 use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
 
 // ════════════════════════════════════════════════════════════════════════════════
 // Request DTOs
 // ════════════════════════════════════════════════════════════════════════════════
 
 /// Request to create a free membership with promo code.
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, ToSchema)]
 pub struct CreateFreeMembershipRequest {
     /// The promo code for free tier access.
     pub promo_code: String,
 }
 
 /// Request to initiate paid membership checkout.
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, ToSchema)]
 pub struct CreatePaidMembershipRequest {
     /// User's email for Stripe customer.
     pub email: String,
@@ -35,7 +36,7 @@ pub struct CreatePaidMembershipRequest {
 }
 
 /// Request to cancel a membership.
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, ToSchema)]
 pub struct CancelMembershipRequest {
     /// Whether to cancel immediately or at period end.
     #[serde(default)]
@@ -47,7 +48,7 @@ pub struct CancelMembershipRequest {
 // ════════════════════════════════════════════════════════════════════════════════
 
 /// Response for membership details.
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Serialize, ToSchema)]
 pub struct MembershipResponse {
     /// The membership details, or null if none exists.
     #[serde(flatten)]
@@ -55,7 +56,7 @@ pub struct MembershipResponse {
 }
 
 /// Detailed membership view for API response.
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Serialize, ToSchema)]
 pub struct MembershipViewResponse {
     /// Membership ID.
     pub id: String,
@@ -96,7 +97,7 @@ impl From<MembershipView> for MembershipViewResponse {
 /// Response for tier limits.
 ///
 /// Contains all feature limits and capabilities for the user's membership tier.
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Serialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct TierLimitsResponse {
     /// The membership tier.
@@ -135,6 +136,8 @@ pub struct TierLimitsResponse {
     // Export & Sharing
     /// Whether PDF export is enabled.
     pub pdf_export_enabled: bool,
+    /// Whether slide deck export is enabled.
+    pub slide_deck_export_enabled: bool,
     /// Whether share link generation is enabled.
     pub share_link_enabled: bool,
     /// Whether API access is enabled.
@@ -157,6 +160,7 @@ impl From<TierLimits> for TierLimitsResponse {
             dq_scoring_enabled: limits.dq_scoring_enabled,
             improvement_suggestions_enabled: limits.improvement_suggestions_enabled,
             pdf_export_enabled: limits.pdf_export_enabled,
+            slide_deck_export_enabled: limits.slide_deck_export_enabled,
             share_link_enabled: limits.share_link_enabled,
             api_access: limits.api_access,
         }
@@ -164,28 +168,28 @@ impl From<TierLimits> for TierLimitsResponse {
 }
 
 /// Response for access check.
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Serialize, ToSchema)]
 pub struct AccessCheckResponse {
     /// Whether the user has access.
     pub has_access: bool,
 }
 
 /// Response for checkout initiation.
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Serialize, ToSchema)]
 pub struct CheckoutResponse {
     /// The Stripe checkout session URL.
     pub checkout_url: String,
 }
 
 /// Response for customer portal.
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Serialize, ToSchema)]
 pub struct PortalResponse {
     /// The Stripe customer portal URL.
     pub portal_url: String,
 }
 
 /// Response for membership statistics (admin).
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Serialize, ToSchema)]
 pub struct MembershipStatsResponse {
     /// Total number of memberships.
     pub total_count: u64,
@@ -200,7 +204,7 @@ pub struct MembershipStatsResponse {
 }
 
 /// Tier counts for stats response.
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Serialize, ToSchema)]
 pub struct TierCountsResponse {
     pub free: u64,
     pub monthly: u64,
@@ -208,9 +212,10 @@ pub struct TierCountsResponse {
 }
 
 /// Status counts for stats response.
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Serialize, ToSchema)]
 pub struct StatusCountsResponse {
     pub pending: u64,
+    pub trialing: u64,
     pub active: u64,
     pub past_due: u64,
     pub cancelled: u64,
@@ -229,6 +234,7 @@ impl From<MembershipStatistics> for MembershipStatsResponse {
             },
             by_status: StatusCountsResponse {
                 pending: stats.by_status.pending,
+                trialing: stats.by_status.trialing,
                 active: stats.by_status.active,
                 past_due: stats.by_status.past_due,
                 cancelled: stats.by_status.cancelled,
@@ -244,7 +250,8 @@ impl From<MembershipStatistics> for MembershipStatsResponse {
 // ════════════════════════════════════════════════════════════════════════════════
 
 /// Standard error response for API errors.
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Serialize, ToSchema)]
+#[schema(as = MembershipErrorResponse)]
 pub struct ErrorResponse {
     /// Error code for programmatic handling.
     pub error_code: String,
@@ -402,6 +409,7 @@ mod tests {
             },
             by_status: StatusCounts {
                 pending: 5,
+                trialing: 4,
                 active: 80,
                 past_due: 3,
                 cancelled: 7,
@@ -10,7 +10,7 @@ use axum::response::IntoResponse;
 
 use crate::application::handlers::membership::{
     CancelMembershipCommand, CancelMembershipHandler, CheckAccessHandler, CheckAccessQuery,
-    CreateFreeMembershipCommand, CreateFreeMembershipHandler, CreatePaidMembershipCommand,
+    ConvertTrialCommand, ConvertTrialHandler, CreateFreeMembershipCommand, CreateFreeMembershipHandler, CreatePaidMembershipCommand,
     CreatePaidMembershipHandler, GetMembershipHandler, GetMembershipQuery,
     GetMembershipStatsHandler, GetMembershipStatsQuery, HandlePaymentWebhookCommand,
     HandlePaymentWebhookHandler,
@@ -44,6 +44,8 @@ pub struct MembershipAppState {
     pub payment_provider: Arc<dyn PaymentProvider>,
     pub access_checker: Arc<dyn AccessChecker>,
     pub event_publisher: Arc<dyn EventPublisher>,
+    /// Free trial length offered at checkout, if any.
+    pub trial_days: Option<u32>,
 }
 
 impl MembershipAppState {
@@ -70,6 +72,15 @@ impl MembershipAppState {
             self.payment_provider.clone(),
             self.event_publisher.clone(),
         )
+        .with_trial_days(self.trial_days)
+    }
+
+    pub fn convert_trial_handler(&self) -> ConvertTrialHandler {
+        ConvertTrialHandler::new(
+            self.membership_repository.clone(),
+            self.payment_provider.clone(),
+            self.event_publisher.clone(),
+        )
     }
 
     pub fn cancel_membership_handler(&self) -> CancelMembershipHandler {
@@ -152,6 +163,15 @@ where
 // ════════════════════════════════════════════════════════════════════════════════
 
 /// GET /api/membership - Get current user's membership details
+#[utoipa::path(
+    get,
+    path = "/api/membership",
+    tag = "membership",
+    responses(
+        (status = 200, description = "The user's membership; empty if none", body = MembershipResponse),
+        (status = 401, description = "Not signed in", body = ErrorResponse),
+    )
+)]
 pub async fn get_membership(
     State(state): State<MembershipAppState>,
     user: AuthenticatedUser,
@@ -171,6 +191,15 @@ pub async fn get_membership(
 }
 
 /// GET /api/membership/limits - Get tier limits for current user
+#[utoipa::path(
+    get,
+    path = "/api/membership/limits",
+    tag = "membership",
+    responses(
+        (status = 200, description = "Limits of the user's tier", body = TierLimitsResponse),
+        (status = 401, description = "Not signed in", body = ErrorResponse),
+    )
+)]
 pub async fn get_tier_limits(
     State(state): State<MembershipAppState>,
     user: AuthenticatedUser,
@@ -181,6 +210,15 @@ pub async fn get_tier_limits(
 }
 
 /// GET /api/membership/access - Check if user has access
+#[utoipa::path(
+    get,
+    path = "/api/membership/access",
+    tag = "membership",
+    responses(
+        (status = 200, description = "Whether the user has access", body = AccessCheckResponse),
+        (status = 401, description = "Not signed in", body = ErrorResponse),
+    )
+)]
 pub async fn check_access(
     State(state): State<MembershipAppState>,
     user: AuthenticatedUser,
@@ -200,6 +238,16 @@ pub async fn check_access(
 }
 
 /// GET /api/membership/stats - Get membership statistics (admin only)
+#[utoipa::path(
+    get,
+    path = "/api/membership/stats",
+    tag = "membership",
+    responses(
+        (status = 200, description = "Membership statistics", body = MembershipStatsResponse),
+        (status = 401, description = "Not signed in", body = ErrorResponse),
+        (status = 403, description = "Not an admin", body = ErrorResponse),
+    )
+)]
 pub async fn get_membership_stats(
     State(state): State<MembershipAppState>,
     _user: AuthenticatedUser, // Would check admin role in production
@@ -218,6 +266,18 @@ pub async fn get_membership_stats(
 // ════════════════════════════════════════════════════════════════════════════════
 
 /// POST /api/membership/free - Create free membership with promo code
+#[utoipa::path(
+    post,
+    path = "/api/membership/free",
+    tag = "membership",
+    request_body = CreateFreeMembershipRequest,
+    responses(
+        (status = 201, description = "Free membership created", body = MembershipResponse),
+        (status = 400, description = "Invalid or exhausted promo code", body = ErrorResponse),
+        (status = 401, description = "Not signed in", body = ErrorResponse),
+        (status = 409, description = "Membership already exists", body = ErrorResponse),
+    )
+)]
 pub async fn create_free_membership(
     State(state): State<MembershipAppState>,
     user: AuthenticatedUser,
@@ -252,6 +312,18 @@ pub async fn create_free_membership(
 }
 
 /// POST /api/membership/checkout - Start paid checkout flow
+#[utoipa::path(
+    post,
+    path = "/api/membership/checkout",
+    tag = "membership",
+    request_body = CreatePaidMembershipRequest,
+    responses(
+        (status = 201, description = "Checkout session started", body = CheckoutResponse),
+        (status = 400, description = "Invalid tier", body = ErrorResponse),
+        (status = 401, description = "Not signed in", body = ErrorResponse),
+        (status = 409, description = "Membership already exists", body = ErrorResponse),
+    )
+)]
 pub async fn create_checkout(
     State(state): State<MembershipAppState>,
     user: AuthenticatedUser,
@@ -280,6 +352,17 @@ pub async fn create_checkout(
 ///
 /// Note: The `immediate` field in the request is currently ignored as cancellation
 /// always takes effect at the end of the current billing period.
+#[utoipa::path(
+    post,
+    path = "/api/membership/cancel",
+    tag = "membership",
+    request_body = CancelMembershipRequest,
+    responses(
+        (status = 204, description = "Membership cancelled"),
+        (status = 401, description = "Not signed in", body = ErrorResponse),
+        (status = 404, description = "No membership", body = ErrorResponse),
+    )
+)]
 pub async fn cancel_membership(
     State(state): State<MembershipAppState>,
     user: AuthenticatedUser,
@@ -295,7 +378,62 @@ pub async fn cancel_membership(
     Ok(StatusCode::NO_CONTENT)
 }
 
+/// POST /api/membership/trial/convert - End the trial and start paying now
+///
+/// Charges the payment method collected at checkout, so no new checkout
+/// session is needed.
+#[utoipa::path(
+    post,
+    path = "/api/membership/trial/convert",
+    tag = "membership",
+    responses(
+        (status = 200, description = "Trial converted to a paid membership", body = MembershipResponse),
+        (status = 401, description = "Not signed in", body = ErrorResponse),
+        (status = 402, description = "Payment failed", body = ErrorResponse),
+        (status = 409, description = "Membership is not trialing", body = ErrorResponse),
+    )
+)]
+pub async fn convert_trial(
+    State(state): State<MembershipAppState>,
+    user: AuthenticatedUser,
+) -> Result<impl IntoResponse, MembershipApiError> {
+    let handler = state.convert_trial_handler();
+    let cmd = ConvertTrialCommand {
+        user_id: user.user_id,
+    };
+
+    let result = handler.handle(cmd).await?;
+
+    let view = crate::ports::MembershipView {
+        id: result.membership.id,
+        user_id: result.membership.user_id.clone(),
+        tier: result.membership.tier,
+        status: result.membership.status,
+        has_access: result.membership.has_access(),
+        days_remaining: result.membership.days_remaining(),
+        period_end: result.membership.current_period_end,
+        promo_code: result.membership.promo_code.clone(),
+        created_at: result.membership.created_at,
+    };
+
+    let response = MembershipResponse {
+        membership: Some(MembershipViewResponse::from(view)),
+    };
+
+    Ok((StatusCode::OK, Json(response)))
+}
+
 /// GET /api/membership/portal - Get Stripe customer portal URL
+#[utoipa::path(
+    get,
+    path = "/api/membership/portal",
+    tag = "membership",
+    responses(
+        (status = 200, description = "Customer portal URL", body = PortalResponse),
+        (status = 401, description = "Not signed in", body = ErrorResponse),
+        (status = 404, description = "No membership", body = ErrorResponse),
+    )
+)]
 pub async fn get_portal_url(
     State(state): State<MembershipAppState>,
     user: AuthenticatedUser,
@@ -330,6 +468,17 @@ pub async fn get_portal_url(
 }
 
 /// POST /api/webhooks/stripe - Handle Stripe webhook events
+#[utoipa::path(
+    post,
+    path = "/api/webhooks/stripe",
+    tag = "membership",
+    security(()),
+    request_body = String,
+    responses(
+        (status = 200, description = "Event processed"),
+        (status = 401, description = "Invalid webhook signature", body = ErrorResponse),
+    )
+)]
 pub async fn handle_stripe_webhook(
     State(state): State<MembershipAppState>,
     headers: axum::http::HeaderMap,
@@ -421,7 +570,8 @@ mod tests {
         AccessChecker, AccessResult, CheckoutSession, CreateCheckoutRequest,
         CreateCustomerRequest, CreateSubscriptionRequest, Customer, EventPublisher,
         MembershipReader, MembershipRepository, MembershipStatistics, MembershipSummary,
-        MembershipView, PaymentError, PaymentProvider, PortalSession, PromoCodeValidation,
+        MembershipView, MeteredUsageReport, PaymentError, PaymentProvider, PortalSession,
+        PromoCodeValidation,
         PromoCodeValidator, Subscription, SubscriptionStatus, UsageStats, WebhookEvent,
         WebhookEventData, WebhookEventType,
     };
@@ -650,6 +800,10 @@ mod tests {
             }))
         }
 
+        async fn delete_customer(&self, _customer_id: &str) -> Result<(), PaymentError> {
+            Ok(())
+        }
+
         async fn create_subscription(
             &self,
             _request: CreateSubscriptionRequest,
@@ -662,6 +816,7 @@ mod tests {
                 current_period_end: 1735689600,
                 cancel_at_period_end: false,
                 canceled_at: None,
+                quantity: 1,
             })
         }
 
@@ -677,6 +832,7 @@ mod tests {
                 current_period_end: 1735689600,
                 cancel_at_period_end: false,
                 canceled_at: None,
+                quantity: 1,
             }))
         }
 
@@ -697,6 +853,7 @@ mod tests {
                 current_period_end: 1735689600,
                 cancel_at_period_end: at_period_end,
                 canceled_at: Some(1704153600),
+                quantity: 1,
             })
         }
 
@@ -713,9 +870,47 @@ mod tests {
                 current_period_end: 1735689600,
                 cancel_at_period_end: false,
                 canceled_at: None,
+                quantity: 1,
             })
         }
 
+        async fn update_subscription_quantity(
+            &self,
+            subscription_id: &str,
+            quantity: u32,
+        ) -> Result<Subscription, PaymentError> {
+            Ok(Subscription {
+                id: subscription_id.to_string(),
+                customer_id: "cus_test123".to_string(),
+                status: SubscriptionStatus::Active,
+                current_period_start: 1704067200,
+                current_period_end: 1735689600,
+                cancel_at_period_end: false,
+                canceled_at: None,
+                quantity,
+            })
+        }
+
+        async fn end_trial(&self, subscription_id: &str) -> Result<Subscription, PaymentError> {
+            Ok(Subscription {
+                id: subscription_id.to_string(),
+                customer_id: "cus_123".to_string(),
+                status: SubscriptionStatus::Active,
+                current_period_start: 1234567890,
+                current_period_end: 1237246290,
+                cancel_at_period_end: false,
+                canceled_at: None,
+                quantity: 1,
+            })
+        }
+
+        async fn report_metered_usage(
+            &self,
+            _report: MeteredUsageReport,
+        ) -> Result<(), PaymentError> {
+            Ok(())
+        }
+
         async fn create_checkout_session(
             &self,
             _request: CreateCheckoutRequest,
@@ -824,6 +1019,7 @@ mod tests {
             payment_provider: Arc::new(MockPaymentProvider),
             access_checker: Arc::new(MockAccessChecker::new()),
             event_publisher: Arc::new(MockEventPublisher::new()),
+            trial_days: None,
         }
     }
 
@@ -9,8 +9,8 @@ use axum::{
 };
 
 use super::handlers::{
-    cancel_membership, check_access, create_checkout, create_free_membership, get_membership,
-    get_membership_stats, get_portal_url, get_tier_limits, handle_stripe_webhook,
+    cancel_membership, check_access, convert_trial, create_checkout, create_free_membership,
+    get_membership, get_membership_stats, get_portal_url, get_tier_limits, handle_stripe_webhook,
     MembershipAppState,
 };
 
@@ -25,6 +25,7 @@ use super::handlers::{
 /// - `GET /portal` - Get Stripe customer portal URL
 /// - `POST /free` - Create free membership with promo code
 /// - `POST /checkout` - Start paid checkout flow
+/// - `POST /trial/convert` - End a free trial and start paying now
 /// - `POST /cancel` - Cancel membership
 ///
 /// ## Admin Endpoints (require admin role)
@@ -41,6 +42,7 @@ pub fn membership_routes() -> Router<MembershipAppState> {
         .route("/portal", get(get_portal_url))
         .route("/free", post(create_free_membership))
         .route("/checkout", post(create_checkout))
+        .route("/trial/convert", post(convert_trial))
         .route("/cancel", post(cancel_membership))
         // Admin endpoints
         .route("/stats", get(get_membership_stats))
@@ -91,7 +93,8 @@ mod tests {
         AccessChecker, AccessResult, CheckoutSession, CreateCheckoutRequest,
         CreateCustomerRequest, CreateSubscriptionRequest, Customer, EventPublisher,
         MembershipReader, MembershipRepository, MembershipStatistics, MembershipSummary,
-        MembershipView, PaymentError, PaymentProvider, PortalSession, PromoCodeValidation,
+        MembershipView, MeteredUsageReport, PaymentError, PaymentProvider, PortalSession,
+        PromoCodeValidation,
         PromoCodeValidator, Subscription, SubscriptionStatus, UsageStats, WebhookEvent,
         WebhookEventData, WebhookEventType,
     };
@@ -299,6 +302,10 @@ mod tests {
             }))
         }
 
+        async fn delete_customer(&self, _customer_id: &str) -> Result<(), PaymentError> {
+            Ok(())
+        }
+
         async fn create_subscription(
             &self,
             _request: CreateSubscriptionRequest,
@@ -311,6 +318,7 @@ mod tests {
                 current_period_end: 1735689600,
                 cancel_at_period_end: false,
                 canceled_at: None,
+                quantity: 1,
             })
         }
 
@@ -326,6 +334,7 @@ mod tests {
                 current_period_end: 1735689600,
                 cancel_at_period_end: false,
                 canceled_at: None,
+                quantity: 1,
             }))
         }
 
@@ -346,6 +355,7 @@ mod tests {
                 current_period_end: 1735689600,
                 cancel_at_period_end: at_period_end,
                 canceled_at: Some(1704153600),
+                quantity: 1,
             })
         }
 
@@ -362,9 +372,47 @@ mod tests {
                 current_period_end: 1735689600,
                 cancel_at_period_end: false,
                 canceled_at: None,
+                quantity: 1,
             })
         }
 
+        async fn update_subscription_quantity(
+            &self,
+            subscription_id: &str,
+            quantity: u32,
+        ) -> Result<Subscription, PaymentError> {
+            Ok(Subscription {
+                id: subscription_id.to_string(),
+                customer_id: "cus_test123".to_string(),
+                status: SubscriptionStatus::Active,
+                current_period_start: 1704067200,
+                current_period_end: 1735689600,
+                cancel_at_period_end: false,
+                canceled_at: None,
+                quantity,
+            })
+        }
+
+        async fn end_trial(&self, subscription_id: &str) -> Result<Subscription, PaymentError> {
+            Ok(Subscription {
+                id: subscription_id.to_string(),
+                customer_id: "cus_123".to_string(),
+                status: SubscriptionStatus::Active,
+                current_period_start: 1234567890,
+                current_period_end: 1237246290,
+                cancel_at_period_end: false,
+                canceled_at: None,
+                quantity: 1,
+            })
+        }
+
+        async fn report_metered_usage(
+            &self,
+            _report: MeteredUsageReport,
+        ) -> Result<(), PaymentError> {
+            Ok(())
+        }
+
         async fn create_checkout_session(
             &self,
             _request: CreateCheckoutRequest,
@@ -467,6 +515,7 @@ mod tests {
             payment_provider: Arc::new(MockPaymentProvider),
             access_checker: Arc::new(MockAccessChecker),
             event_publisher: Arc::new(MockEventPublisher::new()),
+            trial_days: None,
         }
     }
 
@@ -2,8 +2,10 @@
 //!
 //! This module provides:
 //! - `auth_middleware` - Layer that validates Bearer tokens and injects user into extensions
-//! - `RequireAuth` - Extractor that requires authentication
+//! - `RequireAuth` - Extractor that requires an authenticated user
 //! - `OptionalAuth` - Extractor for optional authentication
+//! - `RequireService` - Extractor that requires a service account
+//! - `RequirePrincipal` - Extractor that accepts either a user or a service
 //!
 //! # Architecture
 //!
@@ -16,6 +18,13 @@
 //!                              Handler → RequireAuth extractor reads from extensions
 //! ```
 //!
+//! Client-credentials tokens inject a `ServicePrincipal` instead of a user, so
+//! integration workers never pass `RequireAuth`. Every successful validation
+//! also injects the `AuthenticatedPrincipal`.
+//!
+//! With a `TokenRevocationStore` attached, user tokens issued before the
+//! user's last logout-all are rejected even though they have not expired.
+//!
 //! # Example
 //!
 //! ```ignore
@@ -24,9 +33,11 @@
 //!
 //! let validator: Arc<dyn SessionValidator> = Arc::new(MockSessionValidator::new());
 //!
+//! let auth_state = AuthState::new(validator);
+//!
 //! let app = Router::new()
 //!     .route("/api/protected", get(protected_handler))
-//!     .layer(middleware::from_fn_with_state(validator.clone(), auth_middleware));
+//!     .layer(middleware::from_fn_with_state(auth_state, auth_middleware));
 //!
 //! async fn protected_handler(RequireAuth(user): RequireAuth) -> String {
 //!     format!("Hello, {}!", user.email)
@@ -43,18 +54,65 @@ use axum::{
     Json,
 };
 
-use crate::domain::foundation::{AuthenticatedUser, AuthError};
-use crate::ports::SessionValidator;
+use crate::domain::foundation::{
+    AuthError, AuthenticatedPrincipal, AuthenticatedUser, ServicePrincipal,
+};
+use crate::ports::{is_token_revoked, SessionValidator, TokenRevocationStore};
+
+/// Auth middleware state - the session validator, plus an optional
+/// revocation store consulted once a user token validates.
+#[derive(Clone)]
+pub struct AuthState {
+    validator: Arc<dyn SessionValidator>,
+    revocations: Option<Arc<dyn TokenRevocationStore>>,
+}
+
+impl AuthState {
+    pub fn new(validator: Arc<dyn SessionValidator>) -> Self {
+        Self {
+            validator,
+            revocations: None,
+        }
+    }
+
+    /// Rejects user tokens issued before the user's last logout-all.
+    pub fn with_revocation_store(mut self, store: Arc<dyn TokenRevocationStore>) -> Self {
+        self.revocations = Some(store);
+        self
+    }
 
-/// Auth middleware state - wraps the session validator.
-pub type AuthState = Arc<dyn SessionValidator>;
+    async fn authenticate(&self, token: &str) -> Result<AuthenticatedPrincipal, AuthError> {
+        let principal = self.validator.validate_principal(token).await?;
+
+        if let (Some(store), AuthenticatedPrincipal::User(user)) = (&self.revocations, &principal) {
+            let cutoff = store
+                .revoked_before(&user.id)
+                .await
+                .map_err(|e| AuthError::service_unavailable(e.to_string()))?;
+            if is_token_revoked(user.issued_at.as_ref(), cutoff.as_ref()) {
+                return Err(AuthError::TokenRevoked);
+            }
+        }
+
+        Ok(principal)
+    }
+}
+
+impl From<Arc<dyn SessionValidator>> for AuthState {
+    fn from(validator: Arc<dyn SessionValidator>) -> Self {
+        Self::new(validator)
+    }
+}
 
 /// Authentication middleware that validates Bearer tokens.
 ///
 /// This middleware:
 /// 1. Extracts the Bearer token from the Authorization header
-/// 2. Validates the token using the `SessionValidator` port
-/// 3. On success, injects `AuthenticatedUser` into request extensions
+/// 2. Validates the token using the `SessionValidator` port, then checks
+///    user tokens against the `TokenRevocationStore` if one is attached
+/// 3. On success, injects `AuthenticatedUser` (or `ServicePrincipal` for
+///    client-credentials tokens) and `AuthenticatedPrincipal` into request
+///    extensions
 /// 4. On missing token, continues without injecting (for optional auth routes)
 /// 5. On invalid token, returns 401 Unauthorized
 ///
@@ -65,7 +123,7 @@ pub type AuthState = Arc<dyn SessionValidator>;
 /// Authorization: Bearer <token>
 /// ```
 pub async fn auth_middleware(
-    State(validator): State<AuthState>,
+    State(auth): State<AuthState>,
     mut request: Request,
     next: Next,
 ) -> Response {
@@ -79,10 +137,18 @@ pub async fn auth_middleware(
     match token {
         Some(token) => {
             // Validate the token
-            match validator.validate(token).await {
-                Ok(user) => {
-                    // Inject authenticated user into request extensions
-                    request.extensions_mut().insert(user);
+            match auth.authenticate(token).await {
+                Ok(principal) => {
+                    // Inject the authenticated user or service into request extensions
+                    match &principal {
+                        AuthenticatedPrincipal::User(user) => {
+                            request.extensions_mut().insert(user.clone());
+                        }
+                        AuthenticatedPrincipal::Service(service) => {
+                            request.extensions_mut().insert(service.clone());
+                        }
+                    }
+                    request.extensions_mut().insert(principal);
                     next.run(request).await
                 }
                 Err(e) => {
@@ -94,6 +160,9 @@ pub async fn auth_middleware(
                         AuthError::InvalidToken => {
                             (StatusCode::UNAUTHORIZED, "Invalid token")
                         }
+                        AuthError::TokenRevoked => {
+                            (StatusCode::UNAUTHORIZED, "Token revoked")
+                        }
                         AuthError::ServiceUnavailable(msg) => {
                             tracing::error!("Auth service unavailable: {}", msg);
                             (StatusCode::SERVICE_UNAVAILABLE, "Authentication service unavailable")
@@ -206,29 +275,124 @@ where
     }
 }
 
+/// Extractor that requires a service account.
+///
+/// Use in handlers meant for integration workers. User tokens are rejected,
+/// as are requests without a token.
+///
+/// # Example
+///
+/// ```ignore
+/// async fn sync_handler(service: RequireService) -> Result<impl IntoResponse, AuthRejection> {
+///     service.require_scope("sessions:read")?;
+///     // ...
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct RequireService(pub ServicePrincipal);
+
+impl RequireService {
+    /// Rejects with 403 unless the service was granted `scope`.
+    pub fn require_scope(&self, scope: &str) -> Result<(), AuthRejection> {
+        self.0
+            .require_scope(scope)
+            .map_err(|_| AuthRejection::MissingScope(scope.to_string()))
+    }
+}
+
+impl<S> axum::extract::FromRequestParts<S> for RequireService
+where
+    S: Send + Sync,
+{
+    type Rejection = AuthRejection;
+
+    fn from_request_parts<'life0, 'life1, 'async_trait>(
+        parts: &'life0 mut axum::http::request::Parts,
+        _state: &'life1 S,
+    ) -> std::pin::Pin<
+        Box<dyn std::future::Future<Output = Result<Self, Self::Rejection>> + Send + 'async_trait>,
+    >
+    where
+        'life0: 'async_trait,
+        'life1: 'async_trait,
+        Self: 'async_trait,
+    {
+        Box::pin(async move {
+            parts
+                .extensions
+                .get::<ServicePrincipal>()
+                .cloned()
+                .map(RequireService)
+                .ok_or(AuthRejection::Unauthenticated)
+        })
+    }
+}
+
+/// Extractor that accepts either an authenticated user or a service.
+///
+/// Use for endpoints shared by the app and integration workers.
+#[derive(Debug, Clone)]
+pub struct RequirePrincipal(pub AuthenticatedPrincipal);
+
+impl<S> axum::extract::FromRequestParts<S> for RequirePrincipal
+where
+    S: Send + Sync,
+{
+    type Rejection = AuthRejection;
+
+    fn from_request_parts<'life0, 'life1, 'async_trait>(
+        parts: &'life0 mut axum::http::request::Parts,
+        _state: &'life1 S,
+    ) -> std::pin::Pin<
+        Box<dyn std::future::Future<Output = Result<Self, Self::Rejection>> + Send + 'async_trait>,
+    >
+    where
+        'life0: 'async_trait,
+        'life1: 'async_trait,
+        Self: 'async_trait,
+    {
+        Box::pin(async move {
+            parts
+                .extensions
+                .get::<AuthenticatedPrincipal>()
+                .cloned()
+                .map(RequirePrincipal)
+                .ok_or(AuthRejection::Unauthenticated)
+        })
+    }
+}
+
 /// Rejection type for authentication failures.
 #[derive(Debug, Clone)]
 pub enum AuthRejection {
     /// No valid authentication token was provided.
     Unauthenticated,
+
+    /// The service token lacks a required scope.
+    MissingScope(String),
 }
 
 impl IntoResponse for AuthRejection {
     fn into_response(self) -> Response {
-        let (status, message) = match self {
-            AuthRejection::Unauthenticated => {
-                (StatusCode::UNAUTHORIZED, "Authentication required")
-            }
+        let (status, body) = match self {
+            AuthRejection::Unauthenticated => (
+                StatusCode::UNAUTHORIZED,
+                serde_json::json!({
+                    "error": "Authentication required",
+                    "code": "UNAUTHENTICATED"
+                }),
+            ),
+            AuthRejection::MissingScope(scope) => (
+                StatusCode::FORBIDDEN,
+                serde_json::json!({
+                    "error": "Insufficient scope",
+                    "code": "INSUFFICIENT_SCOPE",
+                    "required_scope": scope
+                }),
+            ),
         };
 
-        (
-            status,
-            Json(serde_json::json!({
-                "error": message,
-                "code": "UNAUTHENTICATED"
-            })),
-        )
-            .into_response()
+        (status, Json(body)).into_response()
     }
 }
 
@@ -363,6 +527,197 @@ mod tests {
         assert!(user.is_none());
     }
 
+    // ════════════════════════════════════════════════════════════════════════════
+    // Service Account Tests
+    // ════════════════════════════════════════════════════════════════════════════
+
+    async fn run_middleware(validator: MockSessionValidator, token: &str) -> Request {
+        use axum::{body::Body, middleware::from_fn_with_state, routing::get, Router};
+        use tower::ServiceExt;
+
+        let (tx, rx) = std::sync::mpsc::channel();
+        let app = Router::new()
+            .route(
+                "/",
+                get(move |request: Request| {
+                    let tx = tx.clone();
+                    async move {
+                        tx.send(request).unwrap();
+                    }
+                }),
+            )
+            .layer(from_fn_with_state(
+                AuthState::new(Arc::new(validator)),
+                auth_middleware,
+            ));
+
+        let request = axum::http::Request::builder()
+            .uri("/")
+            .header("Authorization", format!("Bearer {}", token))
+            .body(Body::empty())
+            .unwrap();
+        let response = app.oneshot(request).await.unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        rx.recv().unwrap()
+    }
+
+    #[tokio::test]
+    async fn middleware_injects_service_principal_without_user() {
+        let validator = MockSessionValidator::new().with_service(
+            "m2m-token",
+            ServicePrincipal::new("sync-worker", ["sessions:read"]),
+        );
+
+        let request = run_middleware(validator, "m2m-token").await;
+
+        let extensions = request.extensions();
+        assert!(extensions.get::<AuthenticatedUser>().is_none());
+        assert_eq!(
+            extensions.get::<ServicePrincipal>().unwrap().client_id,
+            "sync-worker"
+        );
+        assert!(extensions
+            .get::<AuthenticatedPrincipal>()
+            .unwrap()
+            .as_service()
+            .is_some());
+    }
+
+    #[tokio::test]
+    async fn middleware_injects_user_and_principal_for_user_token() {
+        let validator = MockSessionValidator::new().with_user("valid-token", test_user());
+
+        let request = run_middleware(validator, "valid-token").await;
+
+        let extensions = request.extensions();
+        assert!(extensions.get::<AuthenticatedUser>().is_some());
+        assert!(extensions.get::<ServicePrincipal>().is_none());
+        assert_eq!(
+            extensions
+                .get::<AuthenticatedPrincipal>()
+                .unwrap()
+                .subject(),
+            "user-123"
+        );
+    }
+
+    #[tokio::test]
+    async fn require_service_rejects_users() {
+        use axum::extract::FromRequestParts;
+
+        let mut request: axum::http::Request<()> = axum::http::Request::builder()
+            .uri("/test")
+            .body(())
+            .unwrap();
+        request.extensions_mut().insert(test_user());
+        let (mut parts, _body) = request.into_parts();
+
+        let result = RequireService::from_request_parts(&mut parts, &()).await;
+        assert!(matches!(result, Err(AuthRejection::Unauthenticated)));
+    }
+
+    #[test]
+    fn require_service_checks_scope() {
+        let service = RequireService(ServicePrincipal::new("sync-worker", ["sessions:read"]));
+
+        assert!(service.require_scope("sessions:read").is_ok());
+        let rejection = service.require_scope("exports:write").unwrap_err();
+        assert_eq!(rejection.into_response().status(), StatusCode::FORBIDDEN);
+    }
+
+    // ════════════════════════════════════════════════════════════════════════════
+    // Token Revocation Tests
+    // ════════════════════════════════════════════════════════════════════════════
+
+    fn revocable_state(
+        issued_at: Option<u64>,
+    ) -> (AuthState, Arc<crate::adapters::InMemoryTokenRevocationStore>) {
+        use crate::domain::foundation::Timestamp;
+
+        let user = match issued_at {
+            Some(secs) => test_user().with_issued_at(Timestamp::from_unix_secs(secs)),
+            None => test_user(),
+        };
+        let validator = MockSessionValidator::new()
+            .with_user("valid-token", user)
+            .with_service(
+                "m2m-token",
+                ServicePrincipal::new("sync-worker", ["sessions:read"]),
+            );
+        let store = Arc::new(crate::adapters::InMemoryTokenRevocationStore::new());
+        let state = AuthState::new(Arc::new(validator)).with_revocation_store(store.clone());
+        (state, store)
+    }
+
+    #[tokio::test]
+    async fn tokens_issued_before_logout_all_are_revoked() {
+        use crate::domain::foundation::Timestamp;
+
+        let (state, store) = revocable_state(Some(1_000));
+        let user_id = UserId::new("user-123").unwrap();
+        store
+            .revoke_all_for_user(&user_id, Timestamp::from_unix_secs(1_500))
+            .await
+            .unwrap();
+
+        let result = state.authenticate("valid-token").await;
+        assert!(matches!(result, Err(AuthError::TokenRevoked)));
+    }
+
+    #[tokio::test]
+    async fn tokens_issued_after_logout_all_are_accepted() {
+        use crate::domain::foundation::Timestamp;
+
+        let (state, store) = revocable_state(Some(2_000));
+        let user_id = UserId::new("user-123").unwrap();
+        store
+            .revoke_all_for_user(&user_id, Timestamp::from_unix_secs(1_500))
+            .await
+            .unwrap();
+
+        assert!(state.authenticate("valid-token").await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn service_tokens_ignore_user_revocations() {
+        use crate::domain::foundation::Timestamp;
+
+        let (state, store) = revocable_state(None);
+        store
+            .revoke_all_for_user(
+                &UserId::new("sync-worker").unwrap(),
+                Timestamp::from_unix_secs(1_500),
+            )
+            .await
+            .unwrap();
+
+        assert!(state.authenticate("m2m-token").await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn middleware_returns_401_for_revoked_token() {
+        use crate::domain::foundation::Timestamp;
+        use axum::{body::Body, middleware::from_fn_with_state, routing::get, Router};
+        use tower::ServiceExt;
+
+        let (state, store) = revocable_state(None);
+        store
+            .revoke_all_for_user(&UserId::new("user-123").unwrap(), Timestamp::now())
+            .await
+            .unwrap();
+        let app = Router::new()
+            .route("/", get(|| async { "ok" }))
+            .layer(from_fn_with_state(state, auth_middleware));
+
+        let request = axum::http::Request::builder()
+            .uri("/")
+            .header("Authorization", "Bearer valid-token")
+            .body(Body::empty())
+            .unwrap();
+        let response = app.oneshot(request).await.unwrap();
+        assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+    }
+
     // ════════════════════════════════════════════════════════════════════════════
     // AuthRejection Tests
     // ════════════════════════════════════════════════════════════════════════════
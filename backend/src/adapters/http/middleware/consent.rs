@@ -0,0 +1,151 @@
+//! Consent middleware for axum.
+//!
+//! Blocks AI-backed routes until the authenticated user has accepted the
+//! current terms of service, privacy policy, and AI-processing consent.
+//! Must run after `auth_middleware` so the `AuthenticatedUser` is available.
+//!
+//! # Example
+//!
+//! ```ignore
+//! let ai_routes = Router::new()
+//!     .route("/api/conversations/:id/messages", post(send_message))
+//!     .layer(middleware::from_fn_with_state(consent_state, require_ai_consent));
+//! ```
+
+use axum::{
+    extract::{Request, State},
+    http::StatusCode,
+    middleware::Next,
+    response::{IntoResponse, Response},
+    Json,
+};
+
+use crate::adapters::http::consent::handlers::handle_consent_error;
+use crate::adapters::http::consent::{ConsentAppState, ErrorResponse};
+use crate::application::handlers::consent::CheckAiConsentQuery;
+use crate::domain::consent::ConsentError;
+use crate::domain::foundation::AuthenticatedUser;
+
+/// Rejects requests from users who have not granted the consents required
+/// for AI features.
+///
+/// Returns 401 if no authenticated user is present and 403 with code
+/// `CONSENT_REQUIRED` (listing the missing consent types) otherwise. Consent
+/// lookups fail closed: a repository error yields 500 rather than letting the
+/// request through.
+pub async fn require_ai_consent(
+    State(state): State<ConsentAppState>,
+    request: Request,
+    next: Next,
+) -> Response {
+    let Some(user) = request.extensions().get::<AuthenticatedUser>().cloned() else {
+        return (
+            StatusCode::UNAUTHORIZED,
+            Json(ErrorResponse {
+                code: "UNAUTHORIZED".to_string(),
+                message: "Authentication required".to_string(),
+                details: None,
+            }),
+        )
+            .into_response();
+    };
+
+    let query = CheckAiConsentQuery { user_id: user.id };
+    match state.check_ai_consent_handler().handle(query).await {
+        Ok(()) => next.run(request).await,
+        Err(ConsentError::ConsentRequired(missing)) => {
+            tracing::debug!(missing = ?missing, "Blocked AI request pending consent");
+            handle_consent_error(ConsentError::ConsentRequired(missing))
+        }
+        Err(e) => {
+            tracing::error!("Consent check failed: {}", e);
+            handle_consent_error(e)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Arc;
+
+    use axum::{body::Body, middleware, routing::get, Router};
+    use tower::ServiceExt;
+
+    use crate::adapters::{InMemoryConsentRepository, InMemoryEventBus};
+    use crate::domain::consent::{ConsentRecord, ConsentRequirements, ConsentType};
+    use crate::domain::foundation::UserId;
+    use crate::ports::ConsentRepository;
+
+    fn user() -> AuthenticatedUser {
+        AuthenticatedUser::new(UserId::new("user-1").unwrap(), "user@example.com", None, true)
+    }
+
+    async fn app(grants: &[(ConsentType, &str)], with_user: bool) -> Router {
+        let repo = Arc::new(InMemoryConsentRepository::new());
+        for (t, v) in grants {
+            repo.append(&ConsentRecord::grant(user().id, *t, *v).unwrap())
+                .await
+                .unwrap();
+        }
+        let state = ConsentAppState {
+            consent_repository: repo,
+            event_publisher: Arc::new(InMemoryEventBus::new()),
+            requirements: ConsentRequirements::new("tos-1", "pp-1"),
+        };
+
+        let router = Router::new()
+            .route("/ai", get(|| async { "ok" }))
+            .layer(middleware::from_fn_with_state(state, require_ai_consent));
+
+        if with_user {
+            router.layer(middleware::from_fn(|mut req: Request, next: Next| async move {
+                req.extensions_mut().insert(user());
+                next.run(req).await
+            }))
+        } else {
+            router
+        }
+    }
+
+    async fn status(app: Router) -> StatusCode {
+        app.oneshot(Request::builder().uri("/ai").body(Body::empty()).unwrap())
+            .await
+            .unwrap()
+            .status()
+    }
+
+    #[tokio::test]
+    async fn passes_when_consents_current() {
+        let app = app(
+            &[
+                (ConsentType::TermsOfService, "tos-1"),
+                (ConsentType::PrivacyPolicy, "pp-1"),
+                (ConsentType::AiProcessing, "1"),
+            ],
+            true,
+        )
+        .await;
+        assert_eq!(status(app).await, StatusCode::OK);
+    }
+
+    #[tokio::test]
+    async fn blocks_when_terms_outdated() {
+        let app = app(
+            &[
+                (ConsentType::TermsOfService, "tos-0"),
+                (ConsentType::PrivacyPolicy, "pp-1"),
+                (ConsentType::AiProcessing, "1"),
+            ],
+            true,
+        )
+        .await;
+        assert_eq!(status(app).await, StatusCode::FORBIDDEN);
+    }
+
+    #[tokio::test]
+    async fn rejects_unauthenticated_requests() {
+        let app = app(&[], false).await;
+        assert_eq!(status(app).await, StatusCode::UNAUTHORIZED);
+    }
+}
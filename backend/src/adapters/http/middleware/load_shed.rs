@@ -0,0 +1,401 @@
+//! Priority-based load shedding middleware for axum.
+//!
+//! Caps the number of requests handled concurrently. When every slot is
+//! busy, requests wait in a queue for their priority class; a freed slot
+//! always goes to the highest-priority waiter. Requests are shed with
+//! `503 Service Unavailable` and `Retry-After` when their class queue is
+//! full or they wait longer than the queue timeout, so under sustained
+//! overload the low-priority classes are shed first.
+//!
+//! | Priority | Class | Routes |
+//! |----------|-------|--------|
+//! | 1 | `Interactive` | Conversations, streams, writes, webhooks |
+//! | 2 | `DashboardRead` | Other `GET` requests |
+//! | 3 | `Export` | Document downloads and exports |
+//! | 4 | `Analytics` | Admin reports, stats, comparisons |
+//!
+//! Health checks bypass the shedder entirely. A slot is held until the
+//! handler returns its response, so streaming bodies are not counted.
+//!
+//! # Example
+//!
+//! ```ignore
+//! let shedder = Arc::new(LoadShedder::new(LoadShedConfig::default()));
+//!
+//! let app = Router::new()
+//!     .merge(api_routes)
+//!     .layer(middleware::from_fn_with_state(shedder, load_shed_middleware));
+//! ```
+
+use std::collections::VecDeque;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use axum::{
+    extract::{Request, State},
+    http::{HeaderValue, Method, StatusCode},
+    middleware::Next,
+    response::{IntoResponse, Response},
+    Json,
+};
+use tokio::sync::oneshot;
+
+/// Load shedder middleware state.
+pub type LoadShedderState = Arc<LoadShedder>;
+
+/// Request priority class, highest first.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum RequestPriority {
+    Interactive,
+    DashboardRead,
+    Export,
+    Analytics,
+}
+
+impl RequestPriority {
+    /// Every class in priority order.
+    pub const ALL: [RequestPriority; 4] = [
+        RequestPriority::Interactive,
+        RequestPriority::DashboardRead,
+        RequestPriority::Export,
+        RequestPriority::Analytics,
+    ];
+
+    fn index(self) -> usize {
+        self as usize
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            RequestPriority::Interactive => "interactive",
+            RequestPriority::DashboardRead => "dashboard_read",
+            RequestPriority::Export => "export",
+            RequestPriority::Analytics => "analytics",
+        }
+    }
+
+    /// Classifies a request by method and path.
+    pub fn classify(method: &Method, path: &str) -> Self {
+        if path.starts_with("/api/admin/") || path.ends_with("/stats") || path.ends_with("/compare")
+        {
+            return RequestPriority::Analytics;
+        }
+        if path.starts_with("/api/documents") || path.contains("/export") {
+            return RequestPriority::Export;
+        }
+        let conversational = path.contains("/conversation")
+            || path.contains("/stream")
+            || path.contains("/messages");
+        if conversational || !(method == Method::GET || method == Method::HEAD) {
+            return RequestPriority::Interactive;
+        }
+        RequestPriority::DashboardRead
+    }
+}
+
+/// Load shedding limits.
+#[derive(Debug, Clone)]
+pub struct LoadShedConfig {
+    /// Requests handled at once across all classes.
+    pub max_concurrent: usize,
+    /// Queue depth per class, indexed by `RequestPriority`. Zero sheds the
+    /// class as soon as every slot is busy.
+    pub queue_limits: [usize; 4],
+    /// Longest a request waits for a slot before it is shed.
+    pub queue_timeout: Duration,
+}
+
+impl LoadShedConfig {
+    pub fn queue_limit(&self, priority: RequestPriority) -> usize {
+        self.queue_limits[priority.index()]
+    }
+}
+
+impl Default for LoadShedConfig {
+    fn default() -> Self {
+        Self {
+            max_concurrent: 256,
+            queue_limits: [128, 64, 16, 0],
+            queue_timeout: Duration::from_secs(2),
+        }
+    }
+}
+
+/// Why a request was shed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ShedReason {
+    QueueFull,
+    QueueTimeout,
+}
+
+#[derive(Debug, Default)]
+struct ShedderState {
+    in_flight: usize,
+    queues: [VecDeque<oneshot::Sender<()>>; 4],
+}
+
+/// Concurrency limiter with per-priority queues.
+#[derive(Debug)]
+pub struct LoadShedder {
+    config: LoadShedConfig,
+    state: Mutex<ShedderState>,
+}
+
+/// A held concurrency slot; handed to the next waiter on drop.
+#[derive(Debug)]
+pub struct LoadShedPermit {
+    shedder: Arc<LoadShedder>,
+}
+
+impl Drop for LoadShedPermit {
+    fn drop(&mut self) {
+        self.shedder.release();
+    }
+}
+
+impl LoadShedder {
+    pub fn new(config: LoadShedConfig) -> Self {
+        Self {
+            config,
+            state: Mutex::new(ShedderState::default()),
+        }
+    }
+
+    pub fn config(&self) -> &LoadShedConfig {
+        &self.config
+    }
+
+    /// Requests currently holding a slot.
+    pub fn in_flight(&self) -> usize {
+        self.state.lock().unwrap().in_flight
+    }
+
+    /// Requests waiting for a slot in `priority`'s queue.
+    pub fn queued(&self, priority: RequestPriority) -> usize {
+        self.state.lock().unwrap().queues[priority.index()].len()
+    }
+
+    /// Waits for a slot, or reports why the request should be shed.
+    pub async fn acquire(
+        self: &Arc<Self>,
+        priority: RequestPriority,
+    ) -> Result<LoadShedPermit, ShedReason> {
+        let mut receiver = {
+            let mut state = self.state.lock().unwrap();
+            if state.in_flight < self.config.max_concurrent {
+                state.in_flight += 1;
+                return Ok(self.permit());
+            }
+            let queue = &mut state.queues[priority.index()];
+            // Waiters that timed out stay queued until a release skips them
+            queue.retain(|waiter| !waiter.is_closed());
+            if queue.len() >= self.config.queue_limit(priority) {
+                return Err(ShedReason::QueueFull);
+            }
+            let (sender, receiver) = oneshot::channel();
+            queue.push_back(sender);
+            receiver
+        };
+
+        match tokio::time::timeout(self.config.queue_timeout, &mut receiver).await {
+            Ok(Ok(())) => Ok(self.permit()),
+            Ok(Err(_)) => Err(ShedReason::QueueTimeout),
+            Err(_) => {
+                // A slot may have been handed over just as the wait expired
+                receiver.close();
+                match receiver.try_recv() {
+                    Ok(()) => Ok(self.permit()),
+                    Err(_) => Err(ShedReason::QueueTimeout),
+                }
+            }
+        }
+    }
+
+    fn permit(self: &Arc<Self>) -> LoadShedPermit {
+        LoadShedPermit {
+            shedder: Arc::clone(self),
+        }
+    }
+
+    /// Passes the slot to the highest-priority live waiter, or frees it.
+    fn release(&self) {
+        let mut state = self.state.lock().unwrap();
+        for queue in state.queues.iter_mut() {
+            while let Some(waiter) = queue.pop_front() {
+                if waiter.send(()).is_ok() {
+                    return;
+                }
+            }
+        }
+        state.in_flight -= 1;
+    }
+
+    /// Seconds a shed client should wait before retrying.
+    fn retry_after_secs(&self) -> u64 {
+        self.config.queue_timeout.as_secs().max(1)
+    }
+}
+
+/// Load shedding middleware.
+///
+/// Classifies each request, waits for a concurrency slot, and returns
+/// 503 with `Retry-After` when the request is shed.
+pub async fn load_shed_middleware(
+    State(shedder): State<LoadShedderState>,
+    request: Request,
+    next: Next,
+) -> Response {
+    let path = request.uri().path();
+    if path.starts_with("/health") {
+        return next.run(request).await;
+    }
+
+    let priority = RequestPriority::classify(request.method(), path);
+    match shedder.acquire(priority).await {
+        Ok(_permit) => next.run(request).await,
+        Err(reason) => {
+            tracing::warn!(
+                priority = priority.as_str(),
+                ?reason,
+                path = %request.uri().path(),
+                "Request shed under load"
+            );
+            overloaded_response(shedder.retry_after_secs())
+        }
+    }
+}
+
+/// Create a 503 response for a shed request.
+fn overloaded_response(retry_after_secs: u64) -> Response {
+    let mut response = (
+        StatusCode::SERVICE_UNAVAILABLE,
+        Json(serde_json::json!({
+            "error": "Server is overloaded",
+            "code": "SERVICE_OVERLOADED",
+            "retry_after_secs": retry_after_secs
+        })),
+    )
+        .into_response();
+
+    response.headers_mut().insert(
+        "Retry-After",
+        HeaderValue::from_str(&retry_after_secs.to_string()).unwrap(),
+    );
+    response
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn shedder(max_concurrent: usize, queue_limits: [usize; 4]) -> Arc<LoadShedder> {
+        Arc::new(LoadShedder::new(LoadShedConfig {
+            max_concurrent,
+            queue_limits,
+            queue_timeout: Duration::from_millis(200),
+        }))
+    }
+
+    // ════════════════════════════════════════════════════════════════════════════
+    // Classification Tests
+    // ════════════════════════════════════════════════════════════════════════════
+
+    #[test]
+    fn conversation_traffic_is_interactive() {
+        assert_eq!(
+            RequestPriority::classify(&Method::POST, "/api/conversations/abc/messages"),
+            RequestPriority::Interactive
+        );
+        assert_eq!(
+            RequestPriority::classify(&Method::GET, "/api/cycles/c1/components/c2/stream"),
+            RequestPriority::Interactive
+        );
+        assert_eq!(
+            RequestPriority::classify(&Method::POST, "/api/sessions"),
+            RequestPriority::Interactive
+        );
+    }
+
+    #[test]
+    fn reads_exports_and_reports_are_lower_priority() {
+        assert_eq!(
+            RequestPriority::classify(&Method::GET, "/api/sessions/s1/dashboard"),
+            RequestPriority::DashboardRead
+        );
+        assert_eq!(
+            RequestPriority::classify(&Method::GET, "/api/documents/users/u1/doc.md"),
+            RequestPriority::Export
+        );
+        assert_eq!(
+            RequestPriority::classify(&Method::GET, "/api/sessions/s1/compare"),
+            RequestPriority::Analytics
+        );
+        assert_eq!(
+            RequestPriority::classify(&Method::GET, "/api/admin/slow-queries"),
+            RequestPriority::Analytics
+        );
+    }
+
+    // ════════════════════════════════════════════════════════════════════════════
+    // Shedder Tests
+    // ════════════════════════════════════════════════════════════════════════════
+
+    #[tokio::test]
+    async fn sheds_when_queue_is_full() {
+        let shedder = shedder(1, [1, 1, 1, 0]);
+        let _held = shedder.acquire(RequestPriority::Interactive).await.unwrap();
+
+        let result = shedder.acquire(RequestPriority::Analytics).await;
+
+        assert_eq!(result.unwrap_err(), ShedReason::QueueFull);
+    }
+
+    #[tokio::test]
+    async fn sheds_after_queue_timeout() {
+        let shedder = shedder(1, [1, 1, 1, 1]);
+        let _held = shedder.acquire(RequestPriority::Interactive).await.unwrap();
+
+        let result = shedder.acquire(RequestPriority::Export).await;
+
+        assert_eq!(result.unwrap_err(), ShedReason::QueueTimeout);
+        assert_eq!(shedder.in_flight(), 1);
+    }
+
+    #[tokio::test]
+    async fn freed_slot_goes_to_highest_priority_waiter() {
+        let shedder = shedder(1, [4, 4, 4, 4]);
+        let held = shedder.acquire(RequestPriority::Interactive).await.unwrap();
+
+        let low = tokio::spawn({
+            let shedder = shedder.clone();
+            async move { shedder.acquire(RequestPriority::Analytics).await.map(drop) }
+        });
+        tokio::task::yield_now().await;
+        let high = tokio::spawn({
+            let shedder = shedder.clone();
+            async move {
+                let permit = shedder.acquire(RequestPriority::Interactive).await?;
+                // Hold the slot past the low-priority request's timeout
+                tokio::time::sleep(Duration::from_millis(400)).await;
+                drop(permit);
+                Ok::<_, ShedReason>(())
+            }
+        });
+        while shedder.queued(RequestPriority::Interactive) == 0 {
+            tokio::task::yield_now().await;
+        }
+
+        drop(held);
+
+        assert!(high.await.unwrap().is_ok());
+        assert_eq!(low.await.unwrap().unwrap_err(), ShedReason::QueueTimeout);
+        assert_eq!(shedder.in_flight(), 0);
+    }
+
+    #[test]
+    fn overloaded_response_has_retry_after() {
+        let response = overloaded_response(2);
+        assert_eq!(response.status(), StatusCode::SERVICE_UNAVAILABLE);
+        assert_eq!(response.headers().get("Retry-After").unwrap(), "2");
+    }
+}
@@ -0,0 +1,294 @@
+//! Locale negotiation and error message localization for axum.
+//!
+//! `locale_middleware` picks the response locale from the authenticated
+//! user's profile locale, then the `Accept-Language` header, then the
+//! default, and exposes it to handlers through the `RequestLocale`
+//! extractor. For non-default locales it also rewrites the `message` of JSON
+//! error bodies (`{ "code": ..., "message": ... }`) when the catalog has a
+//! translation for the code, so individual handlers don't have to.
+//!
+//! English responses keep the handler's own, more specific message.
+//! Register the layer after `auth_middleware` so the profile locale is seen.
+//!
+//! # Example
+//!
+//! ```ignore
+//! let app = Router::new()
+//!     .route("/api/cycles/:id", get(get_cycle))
+//!     .layer(middleware::from_fn_with_state(locale_state, locale_middleware))
+//!     .layer(middleware::from_fn_with_state(auth_state, auth_middleware));
+//! ```
+
+use std::sync::Arc;
+
+use axum::{
+    body::{to_bytes, Body},
+    extract::{Request, State},
+    http::{
+        header::{ACCEPT_LANGUAGE, CONTENT_LANGUAGE, CONTENT_LENGTH, CONTENT_TYPE},
+        HeaderValue,
+    },
+    middleware::Next,
+    response::Response,
+};
+use serde_json::Value as JsonValue;
+
+use crate::domain::foundation::AuthenticatedUser;
+use crate::ports::{error_message_key, negotiate_locale, MessageCatalog, DEFAULT_EMAIL_LOCALE};
+
+/// Largest error body that will be buffered for localization.
+const MAX_LOCALIZED_BODY_BYTES: usize = 64 * 1024;
+
+/// State for `locale_middleware`.
+#[derive(Clone)]
+pub struct LocaleState {
+    catalog: Arc<dyn MessageCatalog>,
+}
+
+impl LocaleState {
+    pub fn new(catalog: Arc<dyn MessageCatalog>) -> Self {
+        Self { catalog }
+    }
+
+    pub fn catalog(&self) -> &Arc<dyn MessageCatalog> {
+        &self.catalog
+    }
+}
+
+/// The locale negotiated for the current request.
+///
+/// Falls back to the default locale when `locale_middleware` is not installed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RequestLocale(pub String);
+
+impl RequestLocale {
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl Default for RequestLocale {
+    fn default() -> Self {
+        Self(DEFAULT_EMAIL_LOCALE.to_string())
+    }
+}
+
+impl<S> axum::extract::FromRequestParts<S> for RequestLocale
+where
+    S: Send + Sync,
+{
+    type Rejection = std::convert::Infallible;
+
+    fn from_request_parts<'life0, 'life1, 'async_trait>(
+        parts: &'life0 mut axum::http::request::Parts,
+        _state: &'life1 S,
+    ) -> std::pin::Pin<
+        Box<dyn std::future::Future<Output = Result<Self, Self::Rejection>> + Send + 'async_trait>,
+    >
+    where
+        'life0: 'async_trait,
+        'life1: 'async_trait,
+        Self: 'async_trait,
+    {
+        Box::pin(async move {
+            Ok(parts
+                .extensions
+                .get::<RequestLocale>()
+                .cloned()
+                .unwrap_or_default())
+        })
+    }
+}
+
+/// Negotiates the request locale and localizes JSON error messages.
+pub async fn locale_middleware(
+    State(state): State<LocaleState>,
+    mut request: Request,
+    next: Next,
+) -> Response {
+    let supported = state.catalog.locales();
+    let profile_locale = request
+        .extensions()
+        .get::<AuthenticatedUser>()
+        .and_then(|user| user.locale.clone());
+    let requested = profile_locale.or_else(|| {
+        request
+            .headers()
+            .get(ACCEPT_LANGUAGE)
+            .and_then(|value| value.to_str().ok())
+            .map(str::to_string)
+    });
+    let locale = requested
+        .map(|value| negotiate_locale(&value, &supported))
+        .unwrap_or_else(|| DEFAULT_EMAIL_LOCALE.to_string());
+
+    request
+        .extensions_mut()
+        .insert(RequestLocale(locale.clone()));
+    let mut response = next.run(request).await;
+
+    if let Ok(value) = HeaderValue::from_str(&locale) {
+        response.headers_mut().insert(CONTENT_LANGUAGE, value);
+    }
+
+    let is_json = response
+        .headers()
+        .get(CONTENT_TYPE)
+        .and_then(|v| v.to_str().ok())
+        .is_some_and(|v| v.starts_with("application/json"));
+    let is_error = response.status().is_client_error() || response.status().is_server_error();
+    if locale == DEFAULT_EMAIL_LOCALE || !is_json || !is_error {
+        return response;
+    }
+    localize_error_body(state.catalog.as_ref(), &locale, response).await
+}
+
+async fn localize_error_body(
+    catalog: &dyn MessageCatalog,
+    locale: &str,
+    response: Response,
+) -> Response {
+    let (mut parts, body) = response.into_parts();
+    let bytes = match to_bytes(body, MAX_LOCALIZED_BODY_BYTES).await {
+        Ok(bytes) => bytes,
+        Err(e) => {
+            tracing::warn!("Could not buffer error body for localization: {}", e);
+            parts.headers.remove(CONTENT_LENGTH);
+            return Response::from_parts(parts, Body::empty());
+        }
+    };
+
+    let mut json: JsonValue = match serde_json::from_slice(&bytes) {
+        Ok(json) => json,
+        Err(_) => return Response::from_parts(parts, Body::from(bytes)),
+    };
+    let translated = json
+        .get("code")
+        .and_then(JsonValue::as_str)
+        .filter(|_| json.get("message").is_some_and(JsonValue::is_string))
+        .and_then(|code| catalog.localize(locale, &error_message_key(code), &[]));
+    let Some(message) = translated else {
+        return Response::from_parts(parts, Body::from(bytes));
+    };
+
+    json["message"] = JsonValue::String(message);
+    let body = serde_json::to_vec(&json).unwrap_or_else(|_| bytes.to_vec());
+    parts.headers.remove(CONTENT_LENGTH);
+    Response::from_parts(parts, Body::from(body))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::{http::StatusCode, middleware, response::IntoResponse, routing::get, Json, Router};
+    use serde_json::json;
+    use tower::ServiceExt;
+
+    use crate::adapters::FluentMessageCatalog;
+    use crate::domain::foundation::UserId;
+
+    fn app(user: Option<AuthenticatedUser>) -> Router {
+        let state = LocaleState::new(Arc::new(FluentMessageCatalog::new()));
+        let router = Router::new()
+            .route(
+                "/missing",
+                get(|| async {
+                    (
+                        StatusCode::NOT_FOUND,
+                        Json(json!({"code": "NOT_FOUND", "message": "Cycle not found: c-1"})),
+                    )
+                        .into_response()
+                }),
+            )
+            .route(
+                "/custom",
+                get(|| async {
+                    (
+                        StatusCode::BAD_REQUEST,
+                        Json(json!({"code": "SOMETHING_NEW", "message": "Untranslated"})),
+                    )
+                        .into_response()
+                }),
+            )
+            .route(
+                "/locale",
+                get(|locale: RequestLocale| async move { locale.0 }),
+            )
+            .layer(middleware::from_fn_with_state(state, locale_middleware));
+        match user {
+            Some(user) => router.layer(axum::Extension(user)),
+            None => router,
+        }
+    }
+
+    async fn call(app: Router, path: &str, accept_language: Option<&str>) -> (Response, String) {
+        let mut request = Request::builder().uri(path);
+        if let Some(value) = accept_language {
+            request = request.header(ACCEPT_LANGUAGE, value);
+        }
+        let response = app
+            .oneshot(request.body(Body::empty()).unwrap())
+            .await
+            .unwrap();
+        let (parts, body) = response.into_parts();
+        let bytes = to_bytes(body, usize::MAX).await.unwrap();
+        (
+            Response::from_parts(parts, Body::empty()),
+            String::from_utf8(bytes.to_vec()).unwrap(),
+        )
+    }
+
+    fn user(locale: Option<&str>) -> AuthenticatedUser {
+        let user = AuthenticatedUser::new(
+            UserId::new("user-1").unwrap(),
+            "user@example.com",
+            None,
+            true,
+        );
+        match locale {
+            Some(locale) => user.with_locale(locale),
+            None => user,
+        }
+    }
+
+    #[tokio::test]
+    async fn negotiates_from_accept_language() {
+        let (response, body) = call(app(None), "/locale", Some("fr-CA,fr;q=0.9,en;q=0.5")).await;
+        assert_eq!(body, "fr");
+        assert_eq!(response.headers()[CONTENT_LANGUAGE], "fr");
+    }
+
+    #[tokio::test]
+    async fn profile_locale_wins_over_header() {
+        let (_, body) = call(app(Some(user(Some("es-AR")))), "/locale", Some("fr")).await;
+        assert_eq!(body, "es");
+    }
+
+    #[tokio::test]
+    async fn defaults_to_english() {
+        let (_, body) = call(app(Some(user(None))), "/locale", None).await;
+        assert_eq!(body, "en");
+        let (_, body) = call(app(None), "/locale", Some("de")).await;
+        assert_eq!(body, "en");
+    }
+
+    #[tokio::test]
+    async fn localizes_error_messages_by_code() {
+        let (response, body) = call(app(None), "/missing", Some("es")).await;
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+        let json: JsonValue = serde_json::from_str(&body).unwrap();
+        assert_eq!(json["code"], "NOT_FOUND");
+        assert_eq!(json["message"], "No se encontró el recurso solicitado.");
+    }
+
+    #[tokio::test]
+    async fn keeps_english_and_untranslated_messages() {
+        let (_, body) = call(app(None), "/missing", Some("en-US")).await;
+        let json: JsonValue = serde_json::from_str(&body).unwrap();
+        assert_eq!(json["message"], "Cycle not found: c-1");
+
+        let (_, body) = call(app(None), "/custom", Some("fr")).await;
+        let json: JsonValue = serde_json::from_str(&body).unwrap();
+        assert_eq!(json["message"], "Untranslated");
+    }
+}
@@ -3,12 +3,26 @@
 //! This module contains middleware layers for cross-cutting concerns:
 //!
 //! - `auth` - Authentication middleware and extractors
+//! - `consent` - Blocks AI features until required consents are accepted
+//! - `locale` - Locale negotiation and localized error messages
+//! - `load_shed` - Priority-based concurrency limiting under overload
 //! - `rate_limit` - Rate limiting middleware
 
 pub mod auth;
+pub mod consent;
+pub mod load_shed;
+pub mod locale;
 pub mod rate_limit;
 
-pub use auth::{auth_middleware, AuthRejection, AuthState, OptionalAuth, RequireAuth};
+pub use auth::{
+    auth_middleware, AuthRejection, AuthState, OptionalAuth, RequireAuth, RequirePrincipal,
+    RequireService,
+};
+pub use consent::require_ai_consent;
+pub use load_shed::{
+    load_shed_middleware, LoadShedConfig, LoadShedder, LoadShedderState, RequestPriority,
+};
+pub use locale::{locale_middleware, LocaleState, RequestLocale};
 pub use rate_limit::{
     rate_limit_middleware, RateLimitCheck, RateLimitRejection, RateLimiterState,
 };
@@ -9,6 +9,9 @@
 //! 2. Per-IP rate limit (brute-force protection)
 //! 3. Per-user rate limit (if authenticated) with tier-based limits
 //!
+//! Service accounts skip the per-IP check, since workers often share egress
+//! IPs, and are limited in their own per-service bucket instead of step 3.
+//!
 //! Rate limit status is returned in standard HTTP headers:
 //! - `X-RateLimit-Limit`: Maximum requests allowed in the window
 //! - `X-RateLimit-Remaining`: Requests remaining in the current window
@@ -39,7 +42,7 @@ use axum::{
     Json,
 };
 
-use crate::domain::foundation::AuthenticatedUser;
+use crate::domain::foundation::{AuthenticatedUser, ServicePrincipal};
 use crate::ports::{RateLimitKey, RateLimitResult, RateLimiter};
 
 /// Rate limiter middleware state.
@@ -62,8 +65,8 @@ pub mod headers {
 /// This middleware:
 /// 1. Extracts client IP from `ConnectInfo` or forwarded headers
 /// 2. Checks global rate limit first
-/// 3. Checks per-IP rate limit
-/// 4. If authenticated, checks per-user rate limit
+/// 3. Checks per-IP rate limit, unless a service account is authenticated
+/// 4. If authenticated, checks the per-user or per-service rate limit
 /// 5. Returns 429 Too Many Requests if any limit exceeded
 /// 6. Adds rate limit headers to all responses
 ///
@@ -79,6 +82,7 @@ pub async fn rate_limit_middleware(
 
     // Extract authenticated user if present
     let user = request.extensions().get::<AuthenticatedUser>().cloned();
+    let service = request.extensions().get::<ServicePrincipal>().cloned();
 
     // Check rate limits in order of scope
     // Global limit is checked first for infrastructure protection
@@ -94,8 +98,8 @@ pub async fn rate_limit_middleware(
         Ok(RateLimitResult::Allowed(_)) => {}
     }
 
-    // Per-IP rate limit
-    if let Some(ip) = &client_ip {
+    // Per-IP rate limit (service accounts have their own bucket)
+    if let (Some(ip), None) = (&client_ip, &service) {
         let ip_key = RateLimitKey::ip(ip);
         match limiter.check(ip_key).await {
             Ok(RateLimitResult::Denied(denied)) => {
@@ -108,16 +112,20 @@ pub async fn rate_limit_middleware(
         }
     }
 
-    // Per-user rate limit (if authenticated)
-    let user_status = if let Some(ref user) = user {
-        let user_key = RateLimitKey::user(&user.id);
-        match limiter.check(user_key).await {
+    // Per-user or per-service rate limit (if authenticated)
+    let principal_key = match (&user, &service) {
+        (Some(user), _) => Some(RateLimitKey::user(&user.id)),
+        (None, Some(service)) => Some(RateLimitKey::service(&service.client_id)),
+        (None, None) => None,
+    };
+    let user_status = if let Some(principal_key) = principal_key {
+        match limiter.check(principal_key).await {
             Ok(RateLimitResult::Denied(denied)) => {
                 return rate_limit_response(denied.limit, 0, denied.retry_after_secs);
             }
             Ok(RateLimitResult::Allowed(status)) => Some(status),
             Err(e) => {
-                tracing::warn!("Rate limiter unavailable for user check: {}", e);
+                tracing::warn!("Rate limiter unavailable for principal check: {}", e);
                 None
             }
         }
@@ -128,7 +136,7 @@ pub async fn rate_limit_middleware(
     // All checks passed - continue to handler
     let mut response = next.run(request).await;
 
-    // Add rate limit headers from the most specific limit (user > IP > global)
+    // Add rate limit headers from the most specific limit (user/service > IP > global)
     if let Some(status) = user_status {
         add_rate_limit_headers(&mut response, status.limit, status.remaining, status.reset_at.as_unix_secs());
     } else if let Some(ip) = &client_ip {
@@ -258,7 +266,24 @@ impl RateLimitCheck {
         user_id: &crate::domain::foundation::UserId,
         resource: &str,
     ) -> Result<crate::ports::RateLimitStatus, RateLimitRejection> {
-        let key = RateLimitKey::user_resource(user_id, resource);
+        self.check_key(RateLimitKey::user_resource(user_id, resource))
+            .await
+    }
+
+    /// Check a service account's rate limit for a specific resource.
+    pub async fn check_service_resource(
+        &self,
+        service: &ServicePrincipal,
+        resource: &str,
+    ) -> Result<crate::ports::RateLimitStatus, RateLimitRejection> {
+        self.check_key(RateLimitKey::service_resource(&service.client_id, resource))
+            .await
+    }
+
+    async fn check_key(
+        &self,
+        key: RateLimitKey,
+    ) -> Result<crate::ports::RateLimitStatus, RateLimitRejection> {
         match self.limiter.check(key).await {
             Ok(RateLimitResult::Allowed(status)) => Ok(status),
             Ok(RateLimitResult::Denied(denied)) => Err(RateLimitRejection {
@@ -398,6 +423,33 @@ mod tests {
         assert!(err.retry_after_secs > 0);
     }
 
+    #[tokio::test]
+    async fn service_resource_check_uses_service_bucket() {
+        let mut config = RateLimitConfig::default();
+        config.per_service.ai_completions_per_minute = 1;
+
+        let limiter: Arc<dyn RateLimiter> = Arc::new(InMemoryRateLimiter::new(config));
+        let checker = RateLimitCheck::new(limiter);
+        let service = ServicePrincipal::new("sync-worker", ["sessions:read"]);
+
+        checker
+            .check_service_resource(&service, "ai_completions")
+            .await
+            .unwrap();
+        let err = checker
+            .check_service_resource(&service, "ai_completions")
+            .await
+            .unwrap_err();
+        assert_eq!(err.limit, 1);
+
+        // Users keep their own bucket
+        let user_id = UserId::new("sync-worker").unwrap();
+        assert!(checker
+            .check_resource(&user_id, "ai_completions")
+            .await
+            .is_ok());
+    }
+
     // ════════════════════════════════════════════════════════════════════════════
     // Response Tests
     // ════════════════════════════════════════════════════════════════════════════
@@ -5,31 +5,133 @@
 //! ## Middleware
 //!
 //! - `middleware::auth` - Authentication middleware and extractors
+//! - `middleware::consent` - AI consent gate
+//! - `middleware::load_shed` - Priority-based load shedding
 //! - `middleware::rate_limit` - Rate limiting middleware
 
 pub mod ai_engine;
+pub mod announcements;
+pub mod attachments;
+pub mod audit;
+pub mod auth;
+pub mod backups;
+pub mod calendar;
+pub mod chaos;
+pub mod circuit_breakers;
+pub mod consent;
 pub mod conversation;
 pub mod cycle;
 pub mod dashboard;
+pub mod document_delivery;
+pub mod document_history;
+pub mod document_templates;
+pub mod documents;
+pub mod email_templates;
+pub mod export;
+pub mod feature_flags;
+pub mod google_docs;
+pub mod help;
+pub mod imports;
+pub mod integrations;
+pub mod limits;
 pub mod membership;
 pub mod middleware;
+pub mod openapi;
+pub mod privacy;
+pub mod profile;
+pub mod projections;
+pub mod publications;
+pub mod scim;
+pub mod search;
 pub mod session;
+pub mod shadow_traffic;
+pub mod slack;
+pub mod slo;
+pub mod slow_queries;
+pub mod teams;
 pub mod tools;
 
 // Re-export key types for convenience
 pub use ai_engine::AIEngineAppState;
+pub use announcements::announcement_routes;
+pub use announcements::AnnouncementsAppState;
+pub use attachments::attachment_routes;
+pub use attachments::AttachmentsAppState;
+pub use audit::audit_routes;
+pub use audit::AuditAppState;
+pub use auth::auth_routes;
+pub use auth::AuthAppState;
+pub use backups::backup_routes;
+pub use backups::BackupsAppState;
+pub use calendar::calendar_routes;
+pub use calendar::CalendarAppState;
+pub use chaos::chaos_routes;
+pub use chaos::ChaosAppState;
+pub use circuit_breakers::circuit_breaker_routes;
+pub use circuit_breakers::CircuitBreakersAppState;
+pub use consent::consent_routes;
+pub use consent::ConsentAppState;
 pub use conversation::conversation_routes;
 pub use conversation::ConversationAppState;
 pub use cycle::CycleAppState;
 pub use dashboard::dashboard_routes;
 pub use dashboard::DashboardAppState;
+pub use document_delivery::document_delivery_routes;
+pub use document_delivery::DocumentDeliveryAppState;
+pub use document_history::document_history_routes;
+pub use document_history::DocumentHistoryAppState;
+pub use document_templates::document_template_routes;
+pub use document_templates::DocumentTemplatesAppState;
+pub use documents::document_routes;
+pub use documents::DocumentsAppState;
+pub use email_templates::email_template_routes;
+pub use email_templates::EmailTemplatesAppState;
+pub use export::export_routes;
+pub use export::ExportAppState;
+pub use feature_flags::feature_flag_routes;
+pub use feature_flags::FeatureFlagsAppState;
+pub use google_docs::google_docs_routes;
+pub use google_docs::GoogleDocsAppState;
+pub use help::help_routes;
+pub use help::HelpAppState;
+pub use imports::import_routes;
+pub use imports::ImportAppState;
+pub use integrations::integration_routes;
+pub use integrations::IntegrationsAppState;
+pub use limits::limits_routes;
+pub use limits::LimitsAppState;
 pub use membership::MembershipAppState;
 pub use membership::membership_router;
+pub use openapi::{openapi_routes, ApiDoc};
 pub use middleware::{auth_middleware, AuthRejection, AuthState, OptionalAuth, RequireAuth};
+pub use middleware::require_ai_consent;
+pub use middleware::{load_shed_middleware, LoadShedConfig, LoadShedder, LoadShedderState};
 pub use middleware::{
     rate_limit_middleware, RateLimitCheck, RateLimitRejection, RateLimiterState,
 };
+pub use privacy::privacy_routes;
+pub use privacy::PrivacyAppState;
+pub use profile::profile_routes;
+pub use profile::ProfileAppState;
+pub use projections::projection_routes;
+pub use projections::ProjectionsAppState;
+pub use publications::publication_routes;
+pub use publications::PublicationsAppState;
+pub use scim::scim_routes;
+pub use scim::ScimAppState;
+pub use search::search_routes;
+pub use search::SearchAppState;
 pub use session::session_routes;
 pub use session::SessionHandlers;
+pub use shadow_traffic::shadow_traffic_routes;
+pub use shadow_traffic::ShadowTrafficAppState;
+pub use slack::slack_routes;
+pub use slack::SlackAppState;
+pub use slo::slo_routes;
+pub use slo::SloAppState;
+pub use slow_queries::slow_query_routes;
+pub use slow_queries::SlowQueriesAppState;
+pub use teams::teams_routes;
+pub use teams::TeamsAppState;
 pub use tools::ToolsAppState;
 pub use tools::tools_router;
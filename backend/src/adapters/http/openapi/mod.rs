@@ -0,0 +1,197 @@
+//! OpenAPI document for the public REST API.
+//!
+//! The document is assembled at compile time from the `#[utoipa::path]`
+//! annotations on the conversation, cycle, dashboard, membership, session and
+//! tools handlers, and the `ToSchema` derives on their DTOs. It is served as
+//! JSON for client generation and contract testing.
+
+use axum::http::header;
+use axum::response::IntoResponse;
+use axum::{routing::get, Router};
+use once_cell::sync::Lazy;
+use utoipa::openapi::security::{HttpAuthScheme, HttpBuilder, SecurityScheme};
+use utoipa::{Modify, OpenApi};
+
+use super::{conversation, cycle, dashboard, membership, session, tools};
+
+/// The OpenAPI 3.1 description of the REST API.
+#[derive(OpenApi)]
+#[openapi(
+    info(title = "Choice Sherpa API", description = "PrOACT decision support"),
+    paths(
+        conversation::handlers::get_conversation,
+        conversation::handlers::get_messages,
+        conversation::handlers::regenerate_response,
+        conversation::handlers::get_adaptive_style,
+        conversation::handlers::put_adaptive_style,
+        conversation::sse_handler::stream_message,
+        cycle::handlers::create_cycle,
+        cycle::handlers::branch_cycle,
+        cycle::handlers::apply_component_batch,
+        cycle::handlers::get_cycle_tree,
+        cycle::handlers::get_cycle_graph,
+        cycle::handlers::get_proact_tree_view,
+        dashboard::handlers::get_dashboard_overview,
+        dashboard::handlers::get_component_detail,
+        dashboard::handlers::compare_cycles,
+        dashboard::handlers::get_weighted_comparison,
+        dashboard::handlers::get_group_comparison,
+        dashboard::handlers::get_decision_trees,
+        membership::handlers::get_membership,
+        membership::handlers::get_tier_limits,
+        membership::handlers::check_access,
+        membership::handlers::get_membership_stats,
+        membership::handlers::create_free_membership,
+        membership::handlers::create_checkout,
+        membership::handlers::cancel_membership,
+        membership::handlers::convert_trial,
+        membership::handlers::get_portal_url,
+        membership::handlers::handle_stripe_webhook,
+        session::handlers::create_session,
+        session::handlers::get_session,
+        session::handlers::list_sessions,
+        session::handlers::rename_session,
+        session::handlers::archive_session,
+        session::handlers::get_auto_archive,
+        session::handlers::update_auto_archive,
+        session::handlers::delete_session,
+        session::handlers::restore_session,
+        session::handlers::list_trash,
+        session::handlers::purge_session,
+        tools::handlers::list_tools,
+        tools::handlers::invoke_tool,
+        tools::handlers::get_invocation_history,
+        tools::handlers::get_revisit_suggestions,
+        tools::handlers::dismiss_revisit,
+        tools::handlers::get_confirmations,
+        tools::handlers::respond_to_confirmation,
+    ),
+    modifiers(&BearerAuth),
+    security(("bearer_auth" = [])),
+    tags(
+        (name = "conversations", description = "Component conversations with the AI agent"),
+        (name = "cycles", description = "Decision cycles and their branches"),
+        (name = "dashboard", description = "Read-only views over a session's cycles"),
+        (name = "membership", description = "Membership tiers, billing and access"),
+        (name = "sessions", description = "Decision sessions"),
+        (name = "tools", description = "AI agent tools, revisit suggestions and confirmations"),
+    )
+)]
+pub struct ApiDoc;
+
+/// Adds the bearer token scheme every endpoint authenticates with.
+struct BearerAuth;
+
+impl Modify for BearerAuth {
+    fn modify(&self, openapi: &mut utoipa::openapi::OpenApi) {
+        let components = openapi.components.get_or_insert_with(Default::default);
+        components.add_security_scheme(
+            "bearer_auth",
+            SecurityScheme::Http(HttpBuilder::new().scheme(HttpAuthScheme::Bearer).build()),
+        );
+    }
+}
+
+/// The document as JSON, rendered once.
+static OPENAPI_JSON: Lazy<String> = Lazy::new(|| {
+    ApiDoc::openapi()
+        .to_json()
+        .expect("OpenAPI document serializes to JSON")
+});
+
+/// GET /api/openapi.json - The OpenAPI document
+pub async fn get_openapi_json() -> impl IntoResponse {
+    (
+        [(header::CONTENT_TYPE, "application/json")],
+        OPENAPI_JSON.as_str(),
+    )
+}
+
+/// Creates the OpenAPI document router.
+///
+/// # Routes
+/// - `GET /api/openapi.json` - The OpenAPI document (unauthenticated)
+pub fn openapi_routes() -> Router {
+    Router::new().route("/api/openapi.json", get(get_openapi_json))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::body::Body;
+    use axum::http::{Request, StatusCode};
+    use tower::ServiceExt;
+
+    fn document() -> serde_json::Value {
+        serde_json::from_str(&OPENAPI_JSON).unwrap()
+    }
+
+    #[test]
+    fn document_is_openapi_3_1() {
+        assert!(document()["openapi"].as_str().unwrap().starts_with("3.1"));
+    }
+
+    #[test]
+    fn document_covers_every_annotated_module() {
+        let doc = document();
+        let paths = doc["paths"].as_object().unwrap();
+
+        for path in [
+            "/api/components/{component_id}/conversation",
+            "/api/cycles",
+            "/api/sessions/{session_id}/dashboard",
+            "/api/membership",
+            "/api/sessions",
+            "/api/tools",
+        ] {
+            assert!(paths.contains_key(path), "missing {}", path);
+        }
+    }
+
+    #[test]
+    fn referenced_schemas_are_defined() {
+        let doc = document();
+        let schemas = doc["components"]["schemas"].as_object().unwrap();
+
+        let json = doc.to_string();
+        for reference in json.split("\"$ref\":\"#/components/schemas/").skip(1) {
+            let name = reference.split('"').next().unwrap();
+            assert!(schemas.contains_key(name), "undefined schema {}", name);
+        }
+    }
+
+    #[test]
+    fn error_responses_keep_distinct_schemas() {
+        let doc = document();
+        let schemas = doc["components"]["schemas"].as_object().unwrap();
+
+        assert!(schemas.contains_key("CycleErrorResponse"));
+        assert!(schemas.contains_key("SessionErrorResponse"));
+    }
+
+    #[test]
+    fn endpoints_require_a_bearer_token() {
+        let doc = document();
+
+        assert_eq!(
+            doc["components"]["securitySchemes"]["bearer_auth"]["scheme"],
+            "bearer"
+        );
+        assert!(doc["security"][0].get("bearer_auth").is_some());
+    }
+
+    #[tokio::test]
+    async fn document_is_served_as_json() {
+        let response = openapi_routes()
+            .oneshot(
+                Request::get("/api/openapi.json")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(response.headers()[header::CONTENT_TYPE], "application/json");
+    }
+}
@@ -0,0 +1,163 @@
+//! HTTP DTOs for privacy endpoints.
+
+use serde::{Deserialize, Serialize};
+
+use crate::domain::foundation::Timestamp;
+use crate::domain::privacy::{
+    DataErasure, DataErasureStatus, DataExport, DataExportStatus, ErasureReport,
+};
+
+// ════════════════════════════════════════════════════════════════════════════
+// Request DTOs
+// ════════════════════════════════════════════════════════════════════════════
+
+/// Request to erase all of the caller's data.
+#[derive(Debug, Clone, Deserialize)]
+pub struct DeleteAllUserDataRequest {
+    /// Must be `true`; erasure cannot be undone.
+    #[serde(default)]
+    pub confirm: bool,
+}
+
+// ════════════════════════════════════════════════════════════════════════════
+// Response DTOs
+// ════════════════════════════════════════════════════════════════════════════
+
+/// A data export request and where it stands.
+#[derive(Debug, Clone, Serialize)]
+pub struct DataExportResponse {
+    pub id: String,
+    pub status: DataExportStatus,
+    pub requested_at: Timestamp,
+    pub completed_at: Option<Timestamp>,
+    /// When the archive stops being downloadable; only set once ready.
+    pub expires_at: Option<Timestamp>,
+    /// Path to download the archive; only set while downloadable.
+    pub download_url: Option<String>,
+}
+
+impl From<DataExport> for DataExportResponse {
+    fn from(export: DataExport) -> Self {
+        let download_url = export
+            .is_downloadable(Timestamp::now())
+            .then(|| format!("/api/user/export/{}/download", export.id));
+        Self {
+            id: export.id.to_string(),
+            status: export.status,
+            requested_at: export.requested_at,
+            completed_at: export.completed_at,
+            expires_at: export.expires_at(),
+            download_url,
+        }
+    }
+}
+
+/// A data erasure request, where it stands and its verification report.
+#[derive(Debug, Clone, Serialize)]
+pub struct DataErasureResponse {
+    pub id: String,
+    pub status: DataErasureStatus,
+    pub requested_at: Timestamp,
+    pub completed_at: Option<Timestamp>,
+    /// Report from the latest attempt, if one has run.
+    pub report: Option<ErasureReport>,
+}
+
+impl From<DataErasure> for DataErasureResponse {
+    fn from(erasure: DataErasure) -> Self {
+        Self {
+            id: erasure.id.to_string(),
+            status: erasure.status,
+            requested_at: erasure.requested_at,
+            completed_at: erasure.completed_at,
+            report: erasure.report,
+        }
+    }
+}
+
+/// Standard error response.
+#[derive(Debug, Clone, Serialize)]
+pub struct ErrorResponse {
+    pub code: String,
+    pub message: String,
+}
+
+impl ErrorResponse {
+    pub fn bad_request(message: impl Into<String>) -> Self {
+        Self {
+            code: "BAD_REQUEST".to_string(),
+            message: message.into(),
+        }
+    }
+
+    pub fn not_found(message: impl Into<String>) -> Self {
+        Self {
+            code: "NOT_FOUND".to_string(),
+            message: message.into(),
+        }
+    }
+
+    pub fn conflict(message: impl Into<String>) -> Self {
+        Self {
+            code: "CONFLICT".to_string(),
+            message: message.into(),
+        }
+    }
+
+    pub fn gone(message: impl Into<String>) -> Self {
+        Self {
+            code: "EXPORT_EXPIRED".to_string(),
+            message: message.into(),
+        }
+    }
+
+    pub fn internal(message: impl Into<String>) -> Self {
+        Self {
+            code: "INTERNAL_ERROR".to_string(),
+            message: message.into(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::foundation::UserId;
+
+    #[test]
+    fn only_ready_exports_have_a_download_url() {
+        let mut export = DataExport::new(UserId::new("user-1").unwrap(), "jo@example.com");
+        let pending = DataExportResponse::from(export.clone());
+        assert!(pending.download_url.is_none());
+        assert!(pending.expires_at.is_none());
+
+        export.record_ready(export.storage_key());
+        let response = DataExportResponse::from(export.clone());
+        assert_eq!(
+            response.download_url,
+            Some(format!("/api/user/export/{}/download", export.id))
+        );
+        assert_eq!(serde_json::to_value(&response).unwrap()["status"], "ready");
+    }
+
+    #[test]
+    fn erasure_requests_must_be_confirmed() {
+        let request: DeleteAllUserDataRequest = serde_json::from_str("{}").unwrap();
+        assert!(!request.confirm);
+        let request: DeleteAllUserDataRequest =
+            serde_json::from_str(r#"{"confirm": true}"#).unwrap();
+        assert!(request.confirm);
+    }
+
+    #[test]
+    fn erasure_response_hides_the_recipient() {
+        let erasure = DataErasure::new(
+            UserId::new("user-1").unwrap(),
+            Some("jo@example.com".to_string()),
+        );
+        let json = serde_json::to_value(DataErasureResponse::from(erasure)).unwrap();
+        assert_eq!(json["status"], "pending");
+        assert!(json.get("recipient").is_none());
+        assert!(json["report"].is_null());
+    }
+}
@@ -0,0 +1,263 @@
+//! HTTP handlers for privacy endpoints.
+
+use std::sync::Arc;
+
+use axum::{
+    extract::{Path, State},
+    http::{header, StatusCode},
+    response::{IntoResponse, Response},
+    Json,
+};
+
+use crate::adapters::http::middleware::RequireAuth;
+use crate::application::handlers::privacy::{
+    DeleteAllUserDataCommand, DeleteAllUserDataError, DeleteAllUserDataHandler,
+    RequestDataExportCommand, RequestDataExportError, RequestDataExportHandler,
+};
+use crate::domain::foundation::{DataExportId, DomainError, Timestamp};
+use crate::domain::privacy::DataExportStatus;
+use crate::ports::{DataErasureRepository, DataExportRepository, DocumentStorage};
+
+use super::dto::{
+    DataErasureResponse, DataExportResponse, DeleteAllUserDataRequest, ErrorResponse,
+};
+
+// ════════════════════════════════════════════════════════════════════════════
+// Application State
+// ════════════════════════════════════════════════════════════════════════════
+
+/// Shared state for the privacy endpoints.
+#[derive(Clone)]
+pub struct PrivacyAppState {
+    pub exports: Arc<dyn DataExportRepository>,
+    /// Holds finished export archives.
+    pub document_storage: Arc<dyn DocumentStorage>,
+    pub erasures: Arc<dyn DataErasureRepository>,
+}
+
+impl PrivacyAppState {
+    pub(crate) fn request_export_handler(&self) -> RequestDataExportHandler {
+        RequestDataExportHandler::new(self.exports.clone())
+    }
+
+    pub(crate) fn delete_all_user_data_handler(&self) -> DeleteAllUserDataHandler {
+        DeleteAllUserDataHandler::new(self.erasures.clone())
+    }
+}
+
+// ════════════════════════════════════════════════════════════════════════════
+// HTTP handlers
+// ════════════════════════════════════════════════════════════════════════════
+
+/// POST /api/user/export - Request an export of all the caller's data
+///
+/// The archive is built in the background; the download link goes to the
+/// email address on the caller's token, which must be verified.
+pub async fn request_data_export(
+    State(state): State<PrivacyAppState>,
+    RequireAuth(user): RequireAuth,
+) -> Response {
+    if !user.email_verified {
+        return (
+            StatusCode::BAD_REQUEST,
+            Json(ErrorResponse::bad_request(
+                "Verify your email address before requesting a data export",
+            )),
+        )
+            .into_response();
+    }
+
+    let cmd = RequestDataExportCommand {
+        user_id: user.id,
+        email: user.email,
+    };
+    match state.request_export_handler().handle(cmd).await {
+        Ok(result) => (
+            StatusCode::ACCEPTED,
+            Json(DataExportResponse::from(result.export)),
+        )
+            .into_response(),
+        Err(e @ RequestDataExportError::AlreadyInProgress(_)) => (
+            StatusCode::CONFLICT,
+            Json(ErrorResponse::conflict(e.to_string())),
+        )
+            .into_response(),
+        Err(RequestDataExportError::Domain(e)) => {
+            internal_error("Failed to request data export", e)
+        }
+    }
+}
+
+/// GET /api/user/export - Status of the caller's latest export
+pub async fn get_latest_data_export(
+    State(state): State<PrivacyAppState>,
+    RequireAuth(user): RequireAuth,
+) -> Response {
+    match state.exports.latest_for_user(&user.id).await {
+        Ok(Some(export)) => {
+            (StatusCode::OK, Json(DataExportResponse::from(export))).into_response()
+        }
+        Ok(None) => (
+            StatusCode::NOT_FOUND,
+            Json(ErrorResponse::not_found(
+                "No data export has been requested",
+            )),
+        )
+            .into_response(),
+        Err(e) => internal_error("Failed to load data export", e),
+    }
+}
+
+/// GET /api/user/export/:export_id/download - Download a finished export
+pub async fn download_data_export(
+    State(state): State<PrivacyAppState>,
+    RequireAuth(user): RequireAuth,
+    Path(export_id): Path<String>,
+) -> Response {
+    let export_id = match export_id.parse::<DataExportId>() {
+        Ok(id) => id,
+        Err(_) => {
+            return (
+                StatusCode::BAD_REQUEST,
+                Json(ErrorResponse::bad_request("Invalid export ID")),
+            )
+                .into_response()
+        }
+    };
+
+    // Other users' exports are reported as missing, not forbidden
+    let export = match state.exports.find_by_id(&export_id).await {
+        Ok(Some(export)) if export.user_id == user.id => export,
+        Ok(_) => {
+            return (
+                StatusCode::NOT_FOUND,
+                Json(ErrorResponse::not_found("Data export not found")),
+            )
+                .into_response()
+        }
+        Err(e) => return internal_error("Failed to load data export", e),
+    };
+
+    if export.status != DataExportStatus::Ready {
+        return (
+            StatusCode::CONFLICT,
+            Json(ErrorResponse::conflict(format!(
+                "Data export is {}",
+                export.status.as_str()
+            ))),
+        )
+            .into_response();
+    }
+    let key = match export.archive_key.as_deref() {
+        Some(key) if export.is_downloadable(Timestamp::now()) => key,
+        _ => {
+            return (
+                StatusCode::GONE,
+                Json(ErrorResponse::gone(
+                    "This data export has expired; request a new one",
+                )),
+            )
+                .into_response()
+        }
+    };
+
+    match state.document_storage.get(key).await {
+        Ok(stored) => (
+            StatusCode::OK,
+            [
+                (header::CONTENT_TYPE, stored.content_type),
+                (
+                    header::CONTENT_DISPOSITION,
+                    format!(
+                        "attachment; filename=\"choice-sherpa-data-{}.json\"",
+                        export.id
+                    ),
+                ),
+                (header::CACHE_CONTROL, "private, no-store".to_string()),
+            ],
+            stored.bytes,
+        )
+            .into_response(),
+        Err(e) => {
+            tracing::error!("Failed to read data export {}: {}", export.id, e);
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ErrorResponse::internal("Failed to read data export")),
+            )
+                .into_response()
+        }
+    }
+}
+
+/// DELETE /api/user/data - Erase all of the caller's data
+///
+/// Irreversible, so the body must confirm it. Erasure runs in the
+/// background; the report is emailed to the caller's address if verified.
+pub async fn request_data_erasure(
+    State(state): State<PrivacyAppState>,
+    RequireAuth(user): RequireAuth,
+    Json(request): Json<DeleteAllUserDataRequest>,
+) -> Response {
+    if !request.confirm {
+        return (
+            StatusCode::BAD_REQUEST,
+            Json(ErrorResponse::bad_request(
+                "Set \"confirm\" to true to erase all of your data",
+            )),
+        )
+            .into_response();
+    }
+
+    let cmd = DeleteAllUserDataCommand {
+        user_id: user.id,
+        email: user.email_verified.then_some(user.email),
+    };
+    match state.delete_all_user_data_handler().handle(cmd).await {
+        Ok(result) => (
+            StatusCode::ACCEPTED,
+            Json(DataErasureResponse::from(result.erasure)),
+        )
+            .into_response(),
+        Err(e @ DeleteAllUserDataError::AlreadyInProgress(_)) => (
+            StatusCode::CONFLICT,
+            Json(ErrorResponse::conflict(e.to_string())),
+        )
+            .into_response(),
+        Err(DeleteAllUserDataError::Domain(e)) => {
+            internal_error("Failed to request data erasure", e)
+        }
+    }
+}
+
+/// GET /api/user/data/erasure - Status and report of the caller's latest erasure
+pub async fn get_latest_data_erasure(
+    State(state): State<PrivacyAppState>,
+    RequireAuth(user): RequireAuth,
+) -> Response {
+    match state.erasures.latest_for_user(&user.id).await {
+        Ok(Some(erasure)) => {
+            (StatusCode::OK, Json(DataErasureResponse::from(erasure))).into_response()
+        }
+        Ok(None) => (
+            StatusCode::NOT_FOUND,
+            Json(ErrorResponse::not_found(
+                "No data erasure has been requested",
+            )),
+        )
+            .into_response(),
+        Err(e) => internal_error("Failed to load data erasure", e),
+    }
+}
+
+// ════════════════════════════════════════════════════════════════════════════
+// Error handling
+// ════════════════════════════════════════════════════════════════════════════
+
+fn internal_error(message: &str, error: DomainError) -> Response {
+    tracing::error!("{}: {}", message, error);
+    (
+        StatusCode::INTERNAL_SERVER_ERROR,
+        Json(ErrorResponse::internal(message)),
+    )
+        .into_response()
+}
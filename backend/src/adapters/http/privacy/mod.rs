@@ -0,0 +1,13 @@
+//! Privacy HTTP adapter module.
+//!
+//! GDPR data subject requests: users request a machine-readable copy of all
+//! their data, which is built in the background and downloaded once ready,
+//! or have all of it erased, with a report of what was removed.
+
+pub mod dto;
+pub mod handlers;
+pub mod routes;
+
+pub use dto::{DataErasureResponse, DataExportResponse, DeleteAllUserDataRequest, ErrorResponse};
+pub use handlers::PrivacyAppState;
+pub use routes::privacy_routes;
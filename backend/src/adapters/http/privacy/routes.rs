@@ -0,0 +1,34 @@
+//! HTTP routes for privacy endpoints.
+
+use axum::{
+    routing::{delete, get, post},
+    Router,
+};
+
+use super::handlers::{
+    download_data_export, get_latest_data_erasure, get_latest_data_export, request_data_erasure,
+    request_data_export, PrivacyAppState,
+};
+
+/// Creates the privacy router.
+///
+/// # Routes
+/// - `POST /api/user/export` - Request an export of all the caller's data
+/// - `GET /api/user/export` - Status of the caller's latest export
+/// - `GET /api/user/export/:export_id/download` - Download a finished export
+/// - `DELETE /api/user/data` - Erase all of the caller's data
+/// - `GET /api/user/data/erasure` - Status and report of the caller's latest erasure
+pub fn privacy_routes(state: PrivacyAppState) -> Router {
+    Router::new()
+        .route(
+            "/api/user/export",
+            post(request_data_export).get(get_latest_data_export),
+        )
+        .route(
+            "/api/user/export/:export_id/download",
+            get(download_data_export),
+        )
+        .route("/api/user/data", delete(request_data_erasure))
+        .route("/api/user/data/erasure", get(get_latest_data_erasure))
+        .with_state(state)
+}
@@ -0,0 +1,429 @@
+//! HTTP DTOs for decision profile endpoints.
+
+use serde::{Deserialize, Serialize};
+
+use crate::application::handlers::profile::ProfileHistoryEntry;
+use crate::domain::consent::ConsentType;
+use crate::domain::foundation::{CycleId, OutcomeReminderId, Timestamp};
+use crate::domain::profile::{
+    AccuracyTrend, BenchmarkPlacement, CategoryShare, DecisionDomain, DecisionPatternAnalytics,
+    DecisionRecord, DomainSatisfaction, DominantObjective, FieldChange, OutcomeRecord,
+    OutcomeReminder, PredictionAccuracyPoint, ProfileRevision, ProfileSummary, ReadingLevel,
+    RecurringDecision, RevisionReason, RiskClassification, SatisfactionLevel, SharedBlindSpot, StyleClassification, TeamProfile,
+    TeamProfileSettings, TimeToDecide,
+};
+
+// ════════════════════════════════════════════════════════════════════════════
+// Request DTOs
+// ════════════════════════════════════════════════════════════════════════════
+
+/// Request to restore an earlier profile version.
+#[derive(Debug, Clone, Deserialize)]
+pub struct RollbackProfileRequest {
+    pub version: u32,
+}
+
+/// Request and response body for the caller's reading level.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReadingLevelBody {
+    pub reading_level: ReadingLevel,
+    /// Approximate school grade; absent for `standard`.
+    #[serde(default, skip_deserializing, skip_serializing_if = "Option::is_none")]
+    pub target_grade: Option<u8>,
+}
+
+impl From<ReadingLevel> for ReadingLevelBody {
+    fn from(reading_level: ReadingLevel) -> Self {
+        Self {
+            reading_level,
+            target_grade: reading_level.target_grade(),
+        }
+    }
+}
+
+/// Request to change an organization's team profile settings.
+#[derive(Debug, Clone, Deserialize)]
+pub struct UpdateTeamProfileSettingsRequest {
+    pub agent_context_enabled: bool,
+}
+
+/// Request to set when the user expects to know how a decision turned out.
+#[derive(Debug, Clone, Deserialize)]
+pub struct SetExpectedOutcomeRequest {
+    pub expected_outcome_at: Timestamp,
+}
+
+/// Request to record how a decision turned out.
+#[derive(Debug, Clone, Deserialize)]
+pub struct RecordOutcomeRequest {
+    pub satisfaction: SatisfactionLevel,
+    #[serde(default)]
+    pub actual_consequences: String,
+    #[serde(default)]
+    pub surprises: Vec<String>,
+    #[serde(default)]
+    pub would_decide_same: Option<bool>,
+}
+
+impl RecordOutcomeRequest {
+    pub fn into_outcome(self) -> OutcomeRecord {
+        OutcomeRecord {
+            recorded_at: Timestamp::now(),
+            satisfaction: self.satisfaction,
+            actual_consequences: self.actual_consequences,
+            surprises: self.surprises,
+            would_decide_same: self.would_decide_same,
+        }
+    }
+}
+
+/// Answer from an emailed outcome survey: the query string of the emailed
+/// link, then the confirmation form.
+#[derive(Debug, Clone, Deserialize)]
+pub struct OutcomeSurveyAnswer {
+    /// 1 (very dissatisfied) to 5 (very satisfied).
+    pub satisfaction: u8,
+}
+
+/// Query parameters for benchmark placements.
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct BenchmarksParams {
+    /// Limit to one decision domain.
+    #[serde(default)]
+    pub domain: Option<DecisionDomain>,
+}
+
+// ════════════════════════════════════════════════════════════════════════════
+// Response DTOs
+// ════════════════════════════════════════════════════════════════════════════
+
+/// Patterns across the user's decisions.
+#[derive(Debug, Clone, Serialize)]
+pub struct DecisionAnalyticsResponse {
+    pub decisions_analyzed: u32,
+    pub low_satisfaction_domains: Vec<DomainSatisfaction>,
+    pub dominant_objectives: Vec<DominantObjective>,
+    pub time_to_decide: Option<TimeToDecide>,
+    pub prediction_accuracy: Vec<PredictionAccuracyPoint>,
+    pub accuracy_trend: AccuracyTrend,
+    pub recurring_decisions: Vec<RecurringDecision>,
+}
+
+impl From<DecisionPatternAnalytics> for DecisionAnalyticsResponse {
+    fn from(analytics: DecisionPatternAnalytics) -> Self {
+        Self {
+            decisions_analyzed: analytics.decisions_analyzed,
+            low_satisfaction_domains: analytics.low_satisfaction_domains,
+            dominant_objectives: analytics.dominant_objectives,
+            time_to_decide: analytics.time_to_decide,
+            prediction_accuracy: analytics.prediction_accuracy,
+            accuracy_trend: analytics.accuracy_trend,
+            recurring_decisions: analytics.recurring_decisions,
+        }
+    }
+}
+
+/// Where the user's decisions fall against anonymized benchmarks.
+#[derive(Debug, Clone, Serialize)]
+pub struct BenchmarksResponse {
+    pub placements: Vec<BenchmarkPlacement>,
+}
+
+/// The user's profile versions, newest first.
+#[derive(Debug, Clone, Serialize)]
+pub struct ProfileHistoryResponse {
+    pub revisions: Vec<ProfileHistoryEntryResponse>,
+}
+
+/// One profile version and what it changed.
+#[derive(Debug, Clone, Serialize)]
+pub struct ProfileHistoryEntryResponse {
+    pub version: u32,
+    pub reason: RevisionReason,
+    pub created_at: Timestamp,
+    pub changes: Vec<FieldChange>,
+}
+
+impl From<Vec<ProfileHistoryEntry>> for ProfileHistoryResponse {
+    fn from(entries: Vec<ProfileHistoryEntry>) -> Self {
+        Self {
+            revisions: entries
+                .into_iter()
+                .map(|entry| ProfileHistoryEntryResponse {
+                    version: entry.version,
+                    reason: entry.reason,
+                    created_at: entry.created_at,
+                    changes: entry.changes,
+                })
+                .collect(),
+        }
+    }
+}
+
+/// A profile version, including the full summary.
+#[derive(Debug, Clone, Serialize)]
+pub struct ProfileRevisionResponse {
+    pub version: u32,
+    pub reason: RevisionReason,
+    pub created_at: Timestamp,
+    pub summary: ProfileSummary,
+}
+
+impl From<ProfileRevision> for ProfileRevisionResponse {
+    fn from(revision: ProfileRevision) -> Self {
+        Self {
+            version: revision.version,
+            reason: revision.reason,
+            created_at: revision.created_at,
+            summary: revision.summary,
+        }
+    }
+}
+
+/// A decision's outcome and whether the user's prediction held up.
+#[derive(Debug, Clone, Serialize)]
+pub struct DecisionOutcomeResponse {
+    pub cycle_id: CycleId,
+    pub expected_outcome_at: Option<Timestamp>,
+    pub outcome: Option<OutcomeRecord>,
+    pub prediction_was_accurate: Option<bool>,
+}
+
+impl From<DecisionRecord> for DecisionOutcomeResponse {
+    fn from(record: DecisionRecord) -> Self {
+        Self {
+            prediction_was_accurate: record.prediction_was_accurate(),
+            cycle_id: record.cycle_id,
+            expected_outcome_at: record.expected_outcome_at,
+            outcome: record.outcome,
+        }
+    }
+}
+
+/// A scheduled outcome survey.
+#[derive(Debug, Clone, Serialize)]
+pub struct OutcomeReminderResponse {
+    pub id: OutcomeReminderId,
+    pub cycle_id: CycleId,
+    pub status: &'static str,
+    pub due_at: Timestamp,
+}
+
+impl From<OutcomeReminder> for OutcomeReminderResponse {
+    fn from(reminder: OutcomeReminder) -> Self {
+        Self {
+            id: reminder.id,
+            cycle_id: reminder.cycle_id,
+            status: reminder.status.as_str(),
+            due_at: reminder.next_attempt_at,
+        }
+    }
+}
+
+/// Anonymized profile of an organization's consenting members.
+#[derive(Debug, Clone, Serialize)]
+pub struct TeamProfileResponse {
+    pub organization: String,
+    pub contributing_members: u32,
+    pub risk_distribution: Vec<CategoryShare<RiskClassification>>,
+    pub style_mix: Vec<CategoryShare<StyleClassification>>,
+    pub common_blind_spots: Vec<SharedBlindSpot>,
+    pub generated_at: Timestamp,
+}
+
+impl From<TeamProfile> for TeamProfileResponse {
+    fn from(profile: TeamProfile) -> Self {
+        Self {
+            organization: profile.organization,
+            contributing_members: profile.contributing_members,
+            risk_distribution: profile.risk_distribution,
+            style_mix: profile.style_mix,
+            common_blind_spots: profile.common_blind_spots,
+            generated_at: profile.generated_at,
+        }
+    }
+}
+
+/// An organization's team profile settings.
+#[derive(Debug, Clone, Serialize)]
+pub struct TeamProfileSettingsResponse {
+    pub organization: String,
+    pub agent_context_enabled: bool,
+    pub updated_at: Option<Timestamp>,
+}
+
+impl From<TeamProfileSettings> for TeamProfileSettingsResponse {
+    fn from(settings: TeamProfileSettings) -> Self {
+        Self {
+            organization: settings.organization,
+            agent_context_enabled: settings.agent_context_enabled,
+            updated_at: Some(settings.updated_at),
+        }
+    }
+}
+
+impl TeamProfileSettingsResponse {
+    /// Settings for an organization that never configured them.
+    pub fn defaults(organization: impl Into<String>) -> Self {
+        Self {
+            organization: organization.into(),
+            agent_context_enabled: false,
+            updated_at: None,
+        }
+    }
+}
+
+/// Standard error response.
+#[derive(Debug, Clone, Serialize)]
+pub struct ErrorResponse {
+    pub code: String,
+    pub message: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub details: Option<serde_json::Value>,
+}
+
+impl ErrorResponse {
+    pub fn consent_required(missing: &[ConsentType], message: impl Into<String>) -> Self {
+        Self {
+            code: "CONSENT_REQUIRED".to_string(),
+            message: message.into(),
+            details: Some(serde_json::json!({ "missing": missing })),
+        }
+    }
+
+    pub fn team_too_small(required: u32, available: u32, message: impl Into<String>) -> Self {
+        Self {
+            code: "TEAM_TOO_SMALL".to_string(),
+            message: message.into(),
+            details: Some(serde_json::json!({
+                "required_members": required,
+                "consenting_members": available,
+            })),
+        }
+    }
+
+    pub fn not_found(message: impl Into<String>) -> Self {
+        Self {
+            code: "NOT_FOUND".to_string(),
+            message: message.into(),
+            details: None,
+        }
+    }
+
+    pub fn conflict(message: impl Into<String>) -> Self {
+        Self {
+            code: "CONFLICT".to_string(),
+            message: message.into(),
+            details: None,
+        }
+    }
+
+    pub fn forbidden(message: impl Into<String>) -> Self {
+        Self {
+            code: "FORBIDDEN".to_string(),
+            message: message.into(),
+            details: None,
+        }
+    }
+
+    pub fn bad_request(message: impl Into<String>) -> Self {
+        Self {
+            code: "BAD_REQUEST".to_string(),
+            message: message.into(),
+            details: None,
+        }
+    }
+
+    pub fn internal(message: impl Into<String>) -> Self {
+        Self {
+            code: "INTERNAL_ERROR".to_string(),
+            message: message.into(),
+            details: None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn empty_analytics_serialize_with_a_trend() {
+        let response = DecisionAnalyticsResponse::from(DecisionPatternAnalytics::from_history(&[]));
+        let json = serde_json::to_value(&response).unwrap();
+        assert_eq!(json["decisions_analyzed"], 0);
+        assert_eq!(json["accuracy_trend"], "insufficient_data");
+        assert!(json["time_to_decide"].is_null());
+    }
+
+    #[test]
+    fn reading_level_body_reports_the_target_grade() {
+        let body: ReadingLevelBody =
+            serde_json::from_str(r#"{"reading_level":"simple","target_grade":12}"#).unwrap();
+        assert_eq!(body.reading_level, ReadingLevel::Simple);
+
+        let json = serde_json::to_value(ReadingLevelBody::from(body.reading_level)).unwrap();
+        assert_eq!(json["reading_level"], "simple");
+        assert_eq!(json["target_grade"], 5);
+        let standard = serde_json::to_value(ReadingLevelBody::from(ReadingLevel::Standard)).unwrap();
+        assert!(standard.get("target_grade").is_none());
+    }
+
+    #[test]
+    fn consent_errors_name_the_missing_consent() {
+        let error = ErrorResponse::consent_required(&[ConsentType::ProfileAnalysis], "off");
+        let json = serde_json::to_value(&error).unwrap();
+        assert_eq!(json["code"], "CONSENT_REQUIRED");
+        assert_eq!(json["details"]["missing"][0], "profile_analysis");
+    }
+
+    #[test]
+    fn benchmark_placements_name_the_domain_and_metric() {
+        let params: BenchmarksParams = serde_json::from_str(r#"{"domain":"career"}"#).unwrap();
+        assert_eq!(params.domain, Some(DecisionDomain::Career));
+
+        let response = BenchmarksResponse {
+            placements: vec![BenchmarkPlacement {
+                domain: DecisionDomain::Career,
+                metric: "dq_overall".to_string(),
+                value: 72.0,
+                percentile: 64,
+                contributors: 40,
+            }],
+        };
+        let json = serde_json::to_value(&response).unwrap();
+        assert_eq!(json["placements"][0]["domain"], "career");
+        assert_eq!(json["placements"][0]["metric"], "dq_overall");
+        assert_eq!(json["placements"][0]["percentile"], 64);
+    }
+
+    #[test]
+    fn rollback_reasons_are_tagged() {
+        let entry = ProfileHistoryEntry {
+            version: 3,
+            reason: RevisionReason::Rollback { to_version: 1 },
+            created_at: Timestamp::now(),
+            changes: vec![],
+        };
+        let json = serde_json::to_value(ProfileHistoryResponse::from(vec![entry])).unwrap();
+        assert_eq!(json["revisions"][0]["reason"]["type"], "rollback");
+        assert_eq!(json["revisions"][0]["reason"]["to_version"], 1);
+    }
+
+    #[test]
+    fn outcome_requests_only_require_satisfaction() {
+        let request: RecordOutcomeRequest =
+            serde_json::from_value(serde_json::json!({"satisfaction": "satisfied"})).unwrap();
+        let outcome = request.into_outcome();
+        assert_eq!(outcome.satisfaction, SatisfactionLevel::Satisfied);
+        assert!(outcome.surprises.is_empty());
+        assert_eq!(outcome.would_decide_same, None);
+    }
+
+    #[test]
+    fn unconfigured_settings_default_to_no_agent_context() {
+        let json = serde_json::to_value(TeamProfileSettingsResponse::defaults("acme.com")).unwrap();
+        assert_eq!(json["agent_context_enabled"], false);
+        assert!(json["updated_at"].is_null());
+    }
+}
@@ -0,0 +1,645 @@
+//! HTTP handlers for decision profile endpoints.
+
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use axum::{
+    extract::{Path, Query, State},
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    Form, Json,
+};
+
+use crate::adapters::http::export::handlers::organization_error_response;
+use crate::adapters::http::middleware::RequireAuth;
+use crate::adapters::http::publications::handlers::public_page;
+use crate::application::handlers::profile::{
+    GetBenchmarksError, GetBenchmarksHandler, GetBenchmarksQuery, GetDecisionAnalyticsError,
+    GetDecisionAnalyticsHandler, GetDecisionAnalyticsQuery, GetProfileHistoryHandler,
+    GetProfileHistoryQuery, GetReadingLevelHandler, GetReadingLevelQuery, GetTeamProfileError,
+    GetTeamProfileHandler, GetTeamProfileQuery, OutcomeSurveyError, RecordOutcomeCommand,
+    RecordOutcomeError, RecordOutcomeHandler, RespondToOutcomeSurveyCommand,
+    RespondToOutcomeSurveyHandler, RollbackProfileCommand, RollbackProfileError,
+    RollbackProfileHandler, SetExpectedOutcomeCommand, SetExpectedOutcomeHandler,
+    SetReadingLevelCommand, SetReadingLevelHandler, UpdateTeamProfileSettingsCommand,
+    UpdateTeamProfileSettingsHandler,
+};
+use crate::application::handlers::OrganizationResolver;
+use crate::domain::consent::ConsentType;
+use crate::domain::foundation::{CycleId, OrganizationId, Timestamp, UserId};
+use crate::domain::profile::SatisfactionLevel;
+use crate::ports::{
+    BenchmarkRepository, ConsentRepository, DecisionEmbeddingRepository,
+    DecisionHistoryRepository, OrganizationRepository,
+    OutcomeReminderRepository, ProfileRevisionRepository, ProfileSummaryRepository,
+    TeamProfileSettingsRepository,
+};
+
+use super::dto::{
+    BenchmarksParams, BenchmarksResponse, DecisionAnalyticsResponse, DecisionOutcomeResponse,
+    ErrorResponse, OutcomeReminderResponse, OutcomeSurveyAnswer, ProfileHistoryResponse,
+    ProfileRevisionResponse, ReadingLevelBody, RecordOutcomeRequest, RollbackProfileRequest,
+    SetExpectedOutcomeRequest, TeamProfileResponse, TeamProfileSettingsResponse,
+    UpdateTeamProfileSettingsRequest,
+};
+
+// ════════════════════════════════════════════════════════════════════════════
+// Application State
+// ════════════════════════════════════════════════════════════════════════════
+
+/// Shared state for the decision profile endpoints.
+#[derive(Clone)]
+pub struct ProfileAppState {
+    pub decision_history: Arc<dyn DecisionHistoryRepository>,
+    pub consent_repository: Arc<dyn ConsentRepository>,
+    pub profile_summaries: Arc<dyn ProfileSummaryRepository>,
+    pub profile_revisions: Arc<dyn ProfileRevisionRepository>,
+    pub outcome_reminders: Arc<dyn OutcomeReminderRepository>,
+    pub benchmarks: Arc<dyn BenchmarkRepository>,
+    pub team_settings: Arc<dyn TeamProfileSettingsRepository>,
+    /// Decision embeddings behind recurring decisions, when semantic search is enabled.
+    pub decision_embeddings: Option<Arc<dyn DecisionEmbeddingRepository>>,
+    /// Membership deciding who belongs to and administers an organization.
+    pub organizations: Arc<dyn OrganizationRepository>,
+    /// Platform admins, allowed to view any organization's team profile.
+    pub admin_user_ids: Arc<HashSet<UserId>>,
+}
+
+impl ProfileAppState {
+    pub(crate) fn analytics_handler(&self) -> GetDecisionAnalyticsHandler {
+        let handler = GetDecisionAnalyticsHandler::new(
+            self.decision_history.clone(),
+            self.consent_repository.clone(),
+        );
+        match &self.decision_embeddings {
+            Some(embeddings) => handler.with_embeddings(embeddings.clone()),
+            None => handler,
+        }
+    }
+
+    pub(crate) fn benchmarks_handler(&self) -> GetBenchmarksHandler {
+        GetBenchmarksHandler::new(
+            self.decision_history.clone(),
+            self.consent_repository.clone(),
+            self.benchmarks.clone(),
+        )
+    }
+
+    pub(crate) fn record_outcome_handler(&self) -> RecordOutcomeHandler {
+        RecordOutcomeHandler::new(
+            self.decision_history.clone(),
+            self.outcome_reminders.clone(),
+        )
+    }
+
+    pub(crate) fn expected_outcome_handler(&self) -> SetExpectedOutcomeHandler {
+        SetExpectedOutcomeHandler::new(
+            self.decision_history.clone(),
+            self.outcome_reminders.clone(),
+        )
+    }
+
+    pub(crate) fn survey_handler(&self) -> RespondToOutcomeSurveyHandler {
+        RespondToOutcomeSurveyHandler::new(
+            self.decision_history.clone(),
+            self.outcome_reminders.clone(),
+        )
+    }
+
+    pub(crate) fn history_handler(&self) -> GetProfileHistoryHandler {
+        GetProfileHistoryHandler::new(self.profile_revisions.clone())
+    }
+
+    pub(crate) fn rollback_handler(&self) -> RollbackProfileHandler {
+        RollbackProfileHandler::new(
+            self.profile_summaries.clone(),
+            self.profile_revisions.clone(),
+        )
+    }
+
+    pub(crate) fn get_reading_level_handler(&self) -> GetReadingLevelHandler {
+        GetReadingLevelHandler::new(self.profile_summaries.clone())
+    }
+
+    pub(crate) fn set_reading_level_handler(&self) -> SetReadingLevelHandler {
+        SetReadingLevelHandler::new(
+            self.profile_summaries.clone(),
+            self.profile_revisions.clone(),
+        )
+    }
+
+    pub(crate) fn team_profile_handler(&self) -> GetTeamProfileHandler {
+        GetTeamProfileHandler::new(
+            self.profile_summaries.clone(),
+            self.consent_repository.clone(),
+            self.organizations.clone(),
+        )
+    }
+
+    pub(crate) fn update_team_settings_handler(&self) -> UpdateTeamProfileSettingsHandler {
+        UpdateTeamProfileSettingsHandler::new(self.team_settings.clone())
+    }
+
+    /// The key of `organization` if `user_id` owns or administers it, or
+    /// the response refusing them.
+    async fn require_org_admin(
+        &self,
+        user_id: &UserId,
+        organization: &str,
+    ) -> Result<String, Box<Response>> {
+        let Ok(organization_id) = organization.parse::<OrganizationId>() else {
+            return Err(Box::new(bad_request(&format!(
+                "Invalid organization: {}",
+                organization
+            ))));
+        };
+        if self.admin_user_ids.contains(user_id) {
+            return Ok(organization_id.to_string());
+        }
+        match OrganizationResolver::new(self.organizations.clone())
+            .is_admin(user_id, &organization_id)
+            .await
+        {
+            Ok(true) => Ok(organization_id.to_string()),
+            Ok(false) => Err(Box::new(
+                (
+                    StatusCode::FORBIDDEN,
+                    Json(ErrorResponse::forbidden(
+                        "Organization admin access required",
+                    )),
+                )
+                    .into_response(),
+            )),
+            Err(e) => Err(Box::new(organization_error_response(e))),
+        }
+    }
+}
+
+// ════════════════════════════════════════════════════════════════════════════
+// HTTP handlers
+// ════════════════════════════════════════════════════════════════════════════
+
+/// GET /api/user/analytics - Patterns across the caller's decisions
+pub async fn get_decision_analytics(
+    State(state): State<ProfileAppState>,
+    RequireAuth(user): RequireAuth,
+) -> Response {
+    let query = GetDecisionAnalyticsQuery { user_id: user.id };
+    match state.analytics_handler().handle(query).await {
+        Ok(analytics) => (
+            StatusCode::OK,
+            Json(DecisionAnalyticsResponse::from(analytics)),
+        )
+            .into_response(),
+        Err(e @ GetDecisionAnalyticsError::AnalysisNotConsented) => (
+            StatusCode::FORBIDDEN,
+            Json(ErrorResponse::consent_required(
+                &[ConsentType::ProfileAnalysis],
+                e.to_string(),
+            )),
+        )
+            .into_response(),
+        Err(GetDecisionAnalyticsError::Domain(e)) => {
+            tracing::error!("Failed to compute decision analytics: {}", e);
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ErrorResponse::internal(
+                    "Failed to compute decision analytics",
+                )),
+            )
+                .into_response()
+        }
+    }
+}
+
+/// GET /api/user/benchmarks - Where the caller's decisions fall against
+/// anonymized benchmarks
+pub async fn get_benchmarks(
+    State(state): State<ProfileAppState>,
+    RequireAuth(user): RequireAuth,
+    Query(params): Query<BenchmarksParams>,
+) -> Response {
+    let query = GetBenchmarksQuery {
+        user_id: user.id,
+        domain: params.domain,
+    };
+    match state.benchmarks_handler().handle(query).await {
+        Ok(placements) => (StatusCode::OK, Json(BenchmarksResponse { placements })).into_response(),
+        Err(e @ GetBenchmarksError::BenchmarkingNotConsented) => (
+            StatusCode::FORBIDDEN,
+            Json(ErrorResponse::consent_required(
+                &[ConsentType::ProfileBenchmarking],
+                e.to_string(),
+            )),
+        )
+            .into_response(),
+        Err(GetBenchmarksError::Domain(e)) => {
+            tracing::error!("Failed to compute benchmark placements: {}", e);
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ErrorResponse::internal(
+                    "Failed to compute benchmark placements",
+                )),
+            )
+                .into_response()
+        }
+    }
+}
+
+/// GET /api/user/profile/history - The caller's profile versions with changes
+pub async fn get_profile_history(
+    State(state): State<ProfileAppState>,
+    RequireAuth(user): RequireAuth,
+) -> Response {
+    let query = GetProfileHistoryQuery { user_id: user.id };
+    match state.history_handler().handle(query).await {
+        Ok(entries) => {
+            (StatusCode::OK, Json(ProfileHistoryResponse::from(entries))).into_response()
+        }
+        Err(e) => {
+            tracing::error!("Failed to load profile history: {}", e);
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ErrorResponse::internal("Failed to load profile history")),
+            )
+                .into_response()
+        }
+    }
+}
+
+/// POST /api/user/profile/rollback - Restore an earlier profile version
+pub async fn rollback_profile(
+    State(state): State<ProfileAppState>,
+    RequireAuth(user): RequireAuth,
+    Json(request): Json<RollbackProfileRequest>,
+) -> Response {
+    let cmd = RollbackProfileCommand {
+        user_id: user.id,
+        to_version: request.version,
+    };
+    match state.rollback_handler().handle(cmd).await {
+        Ok(revision) => (
+            StatusCode::OK,
+            Json(ProfileRevisionResponse::from(revision)),
+        )
+            .into_response(),
+        Err(e @ RollbackProfileError::VersionNotFound(_)) => (
+            StatusCode::NOT_FOUND,
+            Json(ErrorResponse::not_found(e.to_string())),
+        )
+            .into_response(),
+        Err(e @ RollbackProfileError::AlreadyCurrent(_)) => (
+            StatusCode::CONFLICT,
+            Json(ErrorResponse::conflict(e.to_string())),
+        )
+            .into_response(),
+        Err(RollbackProfileError::Domain(e)) => {
+            tracing::error!("Failed to roll back profile: {}", e);
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ErrorResponse::internal("Failed to roll back profile")),
+            )
+                .into_response()
+        }
+    }
+}
+
+/// GET /api/user/profile/reading-level - The caller's reading level
+pub async fn get_reading_level(
+    State(state): State<ProfileAppState>,
+    RequireAuth(user): RequireAuth,
+) -> Response {
+    let query = GetReadingLevelQuery { user_id: user.id };
+    match state.get_reading_level_handler().handle(query).await {
+        Ok(level) => (StatusCode::OK, Json(ReadingLevelBody::from(level))).into_response(),
+        Err(e) => {
+            tracing::error!("Failed to load reading level: {}", e);
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ErrorResponse::internal("Failed to load reading level")),
+            )
+                .into_response()
+        }
+    }
+}
+
+/// PUT /api/user/profile/reading-level - Choose the reading level for agent
+/// responses and decision documents
+pub async fn put_reading_level(
+    State(state): State<ProfileAppState>,
+    RequireAuth(user): RequireAuth,
+    Json(request): Json<ReadingLevelBody>,
+) -> Response {
+    let cmd = SetReadingLevelCommand {
+        user_id: user.id,
+        reading_level: request.reading_level,
+    };
+    match state.set_reading_level_handler().handle(cmd).await {
+        Ok(_) => (
+            StatusCode::OK,
+            Json(ReadingLevelBody::from(request.reading_level)),
+        )
+            .into_response(),
+        Err(e) => {
+            tracing::error!("Failed to save reading level: {}", e);
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ErrorResponse::internal("Failed to save reading level")),
+            )
+                .into_response()
+        }
+    }
+}
+
+/// PUT /api/user/decisions/:cycle_id/expected-outcome - Schedule the outcome survey
+pub async fn set_expected_outcome(
+    State(state): State<ProfileAppState>,
+    RequireAuth(user): RequireAuth,
+    Path(cycle_id): Path<String>,
+    Json(request): Json<SetExpectedOutcomeRequest>,
+) -> Response {
+    let cycle_id = match cycle_id.parse::<CycleId>() {
+        Ok(id) => id,
+        Err(_) => return bad_request("Invalid cycle ID"),
+    };
+    if !request.expected_outcome_at.is_after(&Timestamp::now()) {
+        return bad_request("expected_outcome_at must be in the future");
+    }
+
+    let cmd = SetExpectedOutcomeCommand {
+        user_id: user.id,
+        cycle_id,
+        expected_outcome_at: request.expected_outcome_at,
+        recipient: user.email,
+    };
+    match state.expected_outcome_handler().handle(cmd).await {
+        Ok(reminder) => (
+            StatusCode::OK,
+            Json(OutcomeReminderResponse::from(reminder)),
+        )
+            .into_response(),
+        Err(e) => record_outcome_error_response(e, "Failed to schedule outcome reminder"),
+    }
+}
+
+/// POST /api/user/decisions/:cycle_id/outcome - Record how a decision turned out
+pub async fn record_outcome(
+    State(state): State<ProfileAppState>,
+    RequireAuth(user): RequireAuth,
+    Path(cycle_id): Path<String>,
+    Json(request): Json<RecordOutcomeRequest>,
+) -> Response {
+    let cycle_id = match cycle_id.parse::<CycleId>() {
+        Ok(id) => id,
+        Err(_) => return bad_request("Invalid cycle ID"),
+    };
+
+    let cmd = RecordOutcomeCommand {
+        user_id: user.id,
+        cycle_id,
+        outcome: request.into_outcome(),
+    };
+    match state.record_outcome_handler().handle(cmd).await {
+        Ok(record) => (StatusCode::OK, Json(DecisionOutcomeResponse::from(record))).into_response(),
+        Err(e) => record_outcome_error_response(e, "Failed to record outcome"),
+    }
+}
+
+/// GET /public/outcome-survey/:token - Confirm an answer from an emailed survey link
+///
+/// Only renders a confirmation form. Mail scanners open links to check them,
+/// so following a link must not record an answer.
+pub async fn view_outcome_survey(
+    Path(token): Path<String>,
+    Query(answer): Query<OutcomeSurveyAnswer>,
+) -> Response {
+    match SatisfactionLevel::from_score(answer.satisfaction) {
+        Some(level) if is_survey_token(&token) => {
+            public_page(StatusCode::OK, survey_confirmation_page(level))
+        }
+        _ => survey_message_page(StatusCode::NOT_FOUND, "Survey link is not valid"),
+    }
+}
+
+/// POST /public/outcome-survey/:token - Record an answer from an emailed survey
+pub async fn answer_outcome_survey(
+    State(state): State<ProfileAppState>,
+    Path(token): Path<String>,
+    Form(answer): Form<OutcomeSurveyAnswer>,
+) -> Response {
+    let Some(satisfaction) = SatisfactionLevel::from_score(answer.satisfaction) else {
+        return survey_message_page(StatusCode::BAD_REQUEST, "Pick an answer from 1 to 5");
+    };
+    if !is_survey_token(&token) {
+        return survey_message_page(StatusCode::NOT_FOUND, "Survey link is not valid");
+    }
+
+    let cmd = RespondToOutcomeSurveyCommand {
+        token,
+        satisfaction,
+    };
+    match state.survey_handler().handle(cmd).await {
+        Ok(_) => survey_message_page(
+            StatusCode::OK,
+            "Thanks! Your answer has been added to your decision journal.",
+        ),
+        Err(e) => {
+            let status = match &e {
+                OutcomeSurveyError::InvalidLink => StatusCode::NOT_FOUND,
+                OutcomeSurveyError::AlreadyResponded => StatusCode::CONFLICT,
+                OutcomeSurveyError::Expired => StatusCode::GONE,
+                OutcomeSurveyError::Domain(e) => {
+                    tracing::error!("Failed to record outcome survey answer: {}", e);
+                    StatusCode::INTERNAL_SERVER_ERROR
+                }
+            };
+            let message = match &e {
+                OutcomeSurveyError::Domain(_) => {
+                    "Something went wrong. Try again later.".to_string()
+                }
+                _ => e.to_string(),
+            };
+            survey_message_page(status, &message)
+        }
+    }
+}
+
+/// GET /api/admin/organizations/:organization/team-profile - Anonymized team profile (org admin)
+pub async fn get_team_profile(
+    State(state): State<ProfileAppState>,
+    RequireAuth(user): RequireAuth,
+    Path(organization): Path<String>,
+) -> Response {
+    let organization = match state.require_org_admin(&user.id, &organization).await {
+        Ok(organization) => organization,
+        Err(rejection) => return *rejection,
+    };
+
+    let query = GetTeamProfileQuery { organization };
+    match state.team_profile_handler().handle(query).await {
+        Ok(profile) => (StatusCode::OK, Json(TeamProfileResponse::from(profile))).into_response(),
+        Err(
+            ref e @ GetTeamProfileError::TooFewMembers {
+                required,
+                available,
+            },
+        ) => (
+            StatusCode::UNPROCESSABLE_ENTITY,
+            Json(ErrorResponse::team_too_small(
+                required,
+                available,
+                e.to_string(),
+            )),
+        )
+            .into_response(),
+        Err(GetTeamProfileError::Domain(e)) => {
+            tracing::error!("Failed to build team profile: {}", e);
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ErrorResponse::internal("Failed to build team profile")),
+            )
+                .into_response()
+        }
+    }
+}
+
+/// GET /api/admin/organizations/:organization/team-profile/settings - Team profile settings (org admin)
+pub async fn get_team_profile_settings(
+    State(state): State<ProfileAppState>,
+    RequireAuth(user): RequireAuth,
+    Path(organization): Path<String>,
+) -> Response {
+    let organization = match state.require_org_admin(&user.id, &organization).await {
+        Ok(organization) => organization,
+        Err(rejection) => return *rejection,
+    };
+
+    match state.team_settings.get(&organization).await {
+        Ok(Some(settings)) => (
+            StatusCode::OK,
+            Json(TeamProfileSettingsResponse::from(settings)),
+        )
+            .into_response(),
+        Ok(None) => (
+            StatusCode::OK,
+            Json(TeamProfileSettingsResponse::defaults(organization)),
+        )
+            .into_response(),
+        Err(e) => {
+            tracing::error!("Failed to load team profile settings: {}", e);
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ErrorResponse::internal(
+                    "Failed to load team profile settings",
+                )),
+            )
+                .into_response()
+        }
+    }
+}
+
+/// PUT /api/admin/organizations/:organization/team-profile/settings - Change team profile settings (org admin)
+pub async fn put_team_profile_settings(
+    State(state): State<ProfileAppState>,
+    RequireAuth(user): RequireAuth,
+    Path(organization): Path<String>,
+    Json(request): Json<UpdateTeamProfileSettingsRequest>,
+) -> Response {
+    let organization = match state.require_org_admin(&user.id, &organization).await {
+        Ok(organization) => organization,
+        Err(rejection) => return *rejection,
+    };
+
+    let cmd = UpdateTeamProfileSettingsCommand {
+        organization,
+        agent_context_enabled: request.agent_context_enabled,
+    };
+    match state.update_team_settings_handler().handle(cmd).await {
+        Ok(settings) => (
+            StatusCode::OK,
+            Json(TeamProfileSettingsResponse::from(settings)),
+        )
+            .into_response(),
+        Err(e) => {
+            tracing::error!("Failed to save team profile settings: {}", e);
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ErrorResponse::internal(
+                    "Failed to save team profile settings",
+                )),
+            )
+                .into_response()
+        }
+    }
+}
+
+// ════════════════════════════════════════════════════════════════════════════
+// Error handling and survey pages
+// ════════════════════════════════════════════════════════════════════════════
+
+fn bad_request(message: &str) -> Response {
+    (
+        StatusCode::BAD_REQUEST,
+        Json(ErrorResponse::bad_request(message)),
+    )
+        .into_response()
+}
+
+fn record_outcome_error_response(error: RecordOutcomeError, message: &str) -> Response {
+    match error {
+        e @ RecordOutcomeError::DecisionNotFound(_) => (
+            StatusCode::NOT_FOUND,
+            Json(ErrorResponse::not_found(e.to_string())),
+        )
+            .into_response(),
+        e @ RecordOutcomeError::AlreadyRecorded(_) => (
+            StatusCode::CONFLICT,
+            Json(ErrorResponse::conflict(e.to_string())),
+        )
+            .into_response(),
+        RecordOutcomeError::Domain(e) => {
+            tracing::error!("{}: {}", message, e);
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ErrorResponse::internal(message)),
+            )
+                .into_response()
+        }
+    }
+}
+
+/// Survey tokens are 64 hex characters; anything else is rejected before it
+/// is echoed into a page or looked up.
+fn is_survey_token(token: &str) -> bool {
+    token.len() == 64 && token.bytes().all(|b| b.is_ascii_hexdigit())
+}
+
+/// Asks the user to confirm their answer, with the emailed choice selected.
+fn survey_confirmation_page(selected: SatisfactionLevel) -> String {
+    let options: String = (1..=5)
+        .rev()
+        .filter_map(SatisfactionLevel::from_score)
+        .map(|level| {
+            format!(
+                "<p><label><input type=\"radio\" name=\"satisfaction\" value=\"{}\"{}> {}</label></p>",
+                level.score(),
+                if level == selected { " checked" } else { "" },
+                level.label()
+            )
+        })
+        .collect();
+    format!(
+        "<!DOCTYPE html>\n<html lang=\"en\"><head><meta charset=\"utf-8\"><meta name=\"robots\" content=\"noindex, nofollow\"><title>How did it turn out?</title></head><body><h1>How satisfied are you with how your decision turned out?</h1><form method=\"post\">{}<p><button type=\"submit\">Save my answer</button></p></form></body></html>\n",
+        options
+    )
+}
+
+fn survey_message_page(status: StatusCode, message: &str) -> Response {
+    public_page(
+        status,
+        format!(
+            "<!DOCTYPE html>\n<html lang=\"en\"><head><meta charset=\"utf-8\"><meta name=\"robots\" content=\"noindex, nofollow\"><title>{0}</title></head><body><p>{0}</p></body></html>\n",
+            message
+        ),
+    )
+}
@@ -0,0 +1,21 @@
+//! Decision profile HTTP adapter module.
+//!
+//! Patterns across a user's decision history for the profile dashboard,
+//! opt-in percentile placements against anonymized benchmarks,
+//! profile version history with rollback, outcome journaling with its
+//! emailed one-click survey, and the anonymized team profile for
+//! organization admins.
+
+pub mod dto;
+pub mod handlers;
+pub mod routes;
+
+pub use dto::{
+    BenchmarksParams, BenchmarksResponse, DecisionAnalyticsResponse, DecisionOutcomeResponse, ErrorResponse, OutcomeReminderResponse,
+    OutcomeSurveyAnswer, ProfileHistoryEntryResponse, ProfileHistoryResponse,
+    ProfileRevisionResponse, RecordOutcomeRequest, RollbackProfileRequest,
+    SetExpectedOutcomeRequest, TeamProfileResponse, TeamProfileSettingsResponse,
+    UpdateTeamProfileSettingsRequest,
+};
+pub use handlers::ProfileAppState;
+pub use routes::profile_routes;
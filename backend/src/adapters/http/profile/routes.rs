@@ -0,0 +1,62 @@
+//! HTTP routes for decision profile endpoints.
+
+use axum::{
+    routing::{get, post, put},
+    Router,
+};
+
+use super::handlers::{
+    answer_outcome_survey, get_benchmarks, get_decision_analytics, get_profile_history,
+    get_reading_level, get_team_profile, get_team_profile_settings, put_reading_level,
+    put_team_profile_settings, record_outcome, rollback_profile, set_expected_outcome,
+    view_outcome_survey, ProfileAppState,
+};
+
+/// Creates the decision profile router.
+///
+/// # Routes
+/// - `GET /api/user/analytics` - Patterns across the caller's decisions
+/// - `GET /api/user/benchmarks` - Percentile placements against anonymized benchmarks
+/// - `GET /api/user/profile/history` - The caller's profile versions with changes
+/// - `POST /api/user/profile/rollback` - Restore an earlier profile version
+/// - `GET /api/user/profile/reading-level` - The caller's reading level
+/// - `PUT /api/user/profile/reading-level` - Choose the reading level for agent responses and documents
+/// - `PUT /api/user/decisions/:cycle_id/expected-outcome` - Schedule the outcome survey
+/// - `POST /api/user/decisions/:cycle_id/outcome` - Record how a decision turned out
+/// - `GET /public/outcome-survey/:token` - Confirm an emailed survey answer (no account needed)
+/// - `POST /public/outcome-survey/:token` - Record an emailed survey answer (no account needed)
+/// - `GET /api/admin/organizations/:organization/team-profile` - Anonymized team profile (org admin)
+/// - `GET /api/admin/organizations/:organization/team-profile/settings` - Team profile settings (org admin)
+/// - `PUT /api/admin/organizations/:organization/team-profile/settings` - Change team profile settings (org admin)
+pub fn profile_routes(state: ProfileAppState) -> Router {
+    Router::new()
+        .route("/api/user/analytics", get(get_decision_analytics))
+        .route("/api/user/benchmarks", get(get_benchmarks))
+        .route("/api/user/profile/history", get(get_profile_history))
+        .route("/api/user/profile/rollback", post(rollback_profile))
+        .route(
+            "/api/user/profile/reading-level",
+            get(get_reading_level).put(put_reading_level),
+        )
+        .route(
+            "/api/user/decisions/:cycle_id/expected-outcome",
+            put(set_expected_outcome),
+        )
+        .route(
+            "/api/user/decisions/:cycle_id/outcome",
+            post(record_outcome),
+        )
+        .route(
+            "/public/outcome-survey/:token",
+            get(view_outcome_survey).post(answer_outcome_survey),
+        )
+        .route(
+            "/api/admin/organizations/:organization/team-profile",
+            get(get_team_profile),
+        )
+        .route(
+            "/api/admin/organizations/:organization/team-profile/settings",
+            get(get_team_profile_settings).put(put_team_profile_settings),
+        )
+        .with_state(state)
+}
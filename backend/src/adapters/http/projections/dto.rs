@@ -0,0 +1,122 @@
+//! HTTP DTOs for projection administration.
+
+use serde::Serialize;
+
+use crate::application::handlers::ProjectionStatus;
+use crate::domain::foundation::ReplayStats;
+
+// ════════════════════════════════════════════════════════════════════════════
+// Response DTOs
+// ════════════════════════════════════════════════════════════════════════════
+
+/// A projection's checkpoint and lag.
+#[derive(Debug, Clone, Serialize)]
+pub struct ProjectionResponse {
+    pub name: &'static str,
+    pub handled_event_types: &'static [&'static str],
+    /// Last event store position applied.
+    pub position: u64,
+    /// Newest event store position.
+    pub head_position: u64,
+    /// Handled events not yet applied.
+    pub lag: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<String>,
+}
+
+impl From<ProjectionStatus> for ProjectionResponse {
+    fn from(status: ProjectionStatus) -> Self {
+        Self {
+            name: status.name,
+            handled_event_types: status.handled_event_types,
+            position: status.position,
+            head_position: status.head_position,
+            lag: status.lag,
+            updated_at: status.updated_at.map(|at| at.as_datetime().to_rfc3339()),
+        }
+    }
+}
+
+/// Every registered projection.
+#[derive(Debug, Clone, Serialize)]
+pub struct ProjectionListResponse {
+    pub projections: Vec<ProjectionResponse>,
+}
+
+/// Outcome of a rebuild.
+#[derive(Debug, Clone, Serialize)]
+pub struct RebuildResponse {
+    pub name: String,
+    /// Events applied to the projection.
+    pub applied: u64,
+    /// Events that could not be upcast and were skipped.
+    pub failed: u64,
+}
+
+impl RebuildResponse {
+    pub fn new(name: impl Into<String>, stats: &ReplayStats) -> Self {
+        Self {
+            name: name.into(),
+            applied: stats.processed,
+            failed: stats.failed,
+        }
+    }
+}
+
+/// Standard error response.
+#[derive(Debug, Clone, Serialize)]
+pub struct ErrorResponse {
+    pub code: String,
+    pub message: String,
+}
+
+impl ErrorResponse {
+    pub fn forbidden(message: impl Into<String>) -> Self {
+        Self {
+            code: "FORBIDDEN".to_string(),
+            message: message.into(),
+        }
+    }
+
+    pub fn not_found(message: impl Into<String>) -> Self {
+        Self {
+            code: "NOT_FOUND".to_string(),
+            message: message.into(),
+        }
+    }
+
+    pub fn internal(message: impl Into<String>) -> Self {
+        Self {
+            code: "INTERNAL_ERROR".to_string(),
+            message: message.into(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::foundation::Timestamp;
+
+    #[test]
+    fn omits_updated_at_for_projections_never_run() {
+        let status = ProjectionStatus {
+            name: "dq_trends",
+            handled_event_types: &["analysis.dq_scores_computed.v1"],
+            position: 0,
+            head_position: 12,
+            lag: 4,
+            updated_at: None,
+        };
+        let json = serde_json::to_value(ProjectionResponse::from(status.clone())).unwrap();
+        assert_eq!(json["lag"], 4);
+        assert!(json.get("updated_at").is_none());
+
+        let ran = ProjectionStatus {
+            updated_at: Some(Timestamp::now()),
+            ..status
+        };
+        let json = serde_json::to_value(ProjectionResponse::from(ran)).unwrap();
+        assert!(json["updated_at"].is_string());
+    }
+}
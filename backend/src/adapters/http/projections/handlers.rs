@@ -0,0 +1,103 @@
+//! HTTP handlers for projection administration.
+
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    Json,
+};
+
+use crate::adapters::http::middleware::RequireAuth;
+use crate::application::handlers::{ProjectionError, ProjectionRunner};
+use crate::domain::foundation::{AuthenticatedUser, UserId};
+
+use super::dto::{ErrorResponse, ProjectionListResponse, ProjectionResponse, RebuildResponse};
+
+// ════════════════════════════════════════════════════════════════════════════
+// Application State
+// ════════════════════════════════════════════════════════════════════════════
+
+/// Shared state for the projection admin endpoints.
+#[derive(Clone)]
+pub struct ProjectionsAppState {
+    pub runner: Arc<ProjectionRunner>,
+    /// Users allowed to view and rebuild projections.
+    pub admin_user_ids: Arc<HashSet<UserId>>,
+}
+
+type Rejection = (StatusCode, Json<ErrorResponse>);
+
+impl ProjectionsAppState {
+    fn require_admin(&self, user: &AuthenticatedUser) -> Result<(), Rejection> {
+        if self.admin_user_ids.contains(&user.id) {
+            Ok(())
+        } else {
+            Err((
+                StatusCode::FORBIDDEN,
+                Json(ErrorResponse::forbidden("Admin access required")),
+            ))
+        }
+    }
+}
+
+fn reject(err: ProjectionError) -> Rejection {
+    match err {
+        ProjectionError::UnknownProjection(name) => (
+            StatusCode::NOT_FOUND,
+            Json(ErrorResponse::not_found(format!(
+                "Projection {} not found",
+                name
+            ))),
+        ),
+        ProjectionError::Domain(err) => (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ErrorResponse::internal(err.to_string())),
+        ),
+    }
+}
+
+// ════════════════════════════════════════════════════════════════════════════
+// HTTP handlers
+// ════════════════════════════════════════════════════════════════════════════
+
+/// GET /api/admin/projections - List projections with their lag
+pub async fn list_projections(
+    State(state): State<ProjectionsAppState>,
+    RequireAuth(user): RequireAuth,
+) -> Response {
+    if let Err(rejection) = state.require_admin(&user) {
+        return rejection.into_response();
+    }
+
+    match state.runner.status().await {
+        Ok(statuses) => {
+            let response = ProjectionListResponse {
+                projections: statuses.into_iter().map(ProjectionResponse::from).collect(),
+            };
+            (StatusCode::OK, Json(response)).into_response()
+        }
+        Err(err) => reject(err).into_response(),
+    }
+}
+
+/// POST /api/admin/projections/:name/rebuild - Replay all events into a projection
+pub async fn rebuild_projection(
+    State(state): State<ProjectionsAppState>,
+    RequireAuth(user): RequireAuth,
+    Path(name): Path<String>,
+) -> Response {
+    if let Err(rejection) = state.require_admin(&user) {
+        return rejection.into_response();
+    }
+
+    match state.runner.rebuild(&name).await {
+        Ok(stats) => {
+            tracing::warn!(admin = %user.id, projection = %name, "Projection rebuilt");
+            (StatusCode::OK, Json(RebuildResponse::new(name, &stats))).into_response()
+        }
+        Err(err) => reject(err).into_response(),
+    }
+}
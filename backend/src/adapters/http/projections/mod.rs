@@ -0,0 +1,13 @@
+//! Projection admin HTTP adapter module.
+//!
+//! Admin endpoints showing each read model's checkpoint and lag behind the
+//! event store, and rebuilding one from scratch after a bug fix or schema
+//! change.
+
+pub mod dto;
+pub mod handlers;
+pub mod routes;
+
+pub use dto::{ErrorResponse, ProjectionListResponse, ProjectionResponse, RebuildResponse};
+pub use handlers::ProjectionsAppState;
+pub use routes::projection_routes;
@@ -0,0 +1,23 @@
+//! HTTP routes for projection administration.
+
+use axum::{
+    routing::{get, post},
+    Router,
+};
+
+use super::handlers::{list_projections, rebuild_projection, ProjectionsAppState};
+
+/// Creates the projection admin router.
+///
+/// # Routes
+/// - `GET /api/admin/projections` - List projections and their lag (admin)
+/// - `POST /api/admin/projections/:name/rebuild` - Rebuild a projection (admin)
+pub fn projection_routes(state: ProjectionsAppState) -> Router {
+    Router::new()
+        .route("/api/admin/projections", get(list_projections))
+        .route(
+            "/api/admin/projections/:name/rebuild",
+            post(rebuild_projection),
+        )
+        .with_state(state)
+}
@@ -0,0 +1,74 @@
+//! HTTP DTOs for public document link endpoints.
+
+use serde::{Deserialize, Serialize};
+
+use crate::domain::document::DocumentPublication;
+use crate::domain::foundation::Timestamp;
+
+// ════════════════════════════════════════════════════════════════════════════
+// Request DTOs
+// ════════════════════════════════════════════════════════════════════════════
+
+/// Options for publishing a document. The body may be omitted.
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct PublishDocumentRequest {
+    /// Link lifetime in days (1-365); defaults to 30.
+    #[serde(default)]
+    pub expires_in_days: Option<i64>,
+}
+
+// ════════════════════════════════════════════════════════════════════════════
+// Response DTOs
+// ════════════════════════════════════════════════════════════════════════════
+
+/// A published document snapshot.
+#[derive(Debug, Clone, Serialize)]
+pub struct PublicationResponse {
+    pub id: String,
+    pub cycle_id: String,
+    pub title: String,
+    /// Public link; only returned when publishing.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+    pub created_at: Timestamp,
+    pub expires_at: Timestamp,
+    pub revoked: bool,
+}
+
+impl PublicationResponse {
+    pub fn new(publication: &DocumentPublication, url: Option<String>) -> Self {
+        Self {
+            id: publication.id.to_string(),
+            cycle_id: publication.cycle_id.to_string(),
+            title: publication.title.clone(),
+            url,
+            created_at: publication.created_at,
+            expires_at: publication.expires_at,
+            revoked: publication.is_revoked(),
+        }
+    }
+}
+
+/// Standard error response.
+#[derive(Debug, Clone, Serialize)]
+pub struct ErrorResponse {
+    pub code: String,
+    pub message: String,
+}
+
+impl ErrorResponse {
+    pub fn new(code: impl Into<String>, message: impl Into<String>) -> Self {
+        Self {
+            code: code.into(),
+            message: message.into(),
+        }
+    }
+
+    pub fn bad_request(message: impl Into<String>) -> Self {
+        Self::new("BAD_REQUEST", message)
+    }
+
+    pub fn internal(message: impl Into<String>) -> Self {
+        Self::new("INTERNAL_ERROR", message)
+    }
+}
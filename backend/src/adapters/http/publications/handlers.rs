@@ -0,0 +1,280 @@
+//! HTTP handlers for public document link endpoints.
+
+use std::sync::Arc;
+
+use axum::{
+    extract::{Path, State},
+    http::{header, StatusCode},
+    response::{Html, IntoResponse, Response},
+    Json,
+};
+
+use crate::adapters::http::export::handlers::{export_error_response, organization_error_response};
+use crate::adapters::http::export::ExportAppState;
+use crate::adapters::http::middleware::RequireAuth;
+use crate::application::handlers::cycle::{
+    PublishDocumentCommand, PublishDocumentError, PublishDocumentHandler, UnpublishDocumentCommand,
+    UnpublishDocumentError, UnpublishDocumentHandler, ViewPublishedDocumentError,
+    ViewPublishedDocumentHandler, ViewPublishedDocumentQuery,
+};
+use crate::domain::foundation::{CycleId, DomainError, PublicationId};
+use crate::ports::{DocumentPublicationRepository, PublicLinkSigner};
+
+use super::dto::{ErrorResponse, PublicationResponse, PublishDocumentRequest};
+
+/// Headers sent with every public page: keep it out of search engines and
+/// caches, don't leak the token through referrers, and allow no scripts or
+/// external resources.
+const PUBLIC_PAGE_HEADERS: [(&str, &str); 4] = [
+    ("x-robots-tag", "noindex, nofollow"),
+    ("cache-control", "no-store"),
+    ("referrer-policy", "no-referrer"),
+    (
+        "content-security-policy",
+        "default-src 'none'; style-src 'unsafe-inline'",
+    ),
+];
+
+// ════════════════════════════════════════════════════════════════════════════
+// Application State
+// ════════════════════════════════════════════════════════════════════════════
+
+/// Shared state for the public document link endpoints.
+#[derive(Clone)]
+pub struct PublicationsAppState {
+    /// Renders the snapshot, with the same checks as downloads.
+    pub export: ExportAppState,
+    pub publications: Arc<dyn DocumentPublicationRepository>,
+    pub link_signer: Arc<dyn PublicLinkSigner>,
+}
+
+impl PublicationsAppState {
+    /// A copy whose readers memoize for the lifetime of one request.
+    pub(crate) fn for_request(&self) -> Self {
+        Self {
+            export: self.export.for_request(),
+            ..self.clone()
+        }
+    }
+
+    pub(crate) fn publish_handler(&self) -> PublishDocumentHandler {
+        PublishDocumentHandler::new(
+            Arc::new(self.export.export_handler()),
+            self.publications.clone(),
+            self.link_signer.clone(),
+        )
+    }
+
+    fn unpublish_handler(&self) -> UnpublishDocumentHandler {
+        UnpublishDocumentHandler::new(self.publications.clone())
+    }
+
+    fn view_handler(&self) -> ViewPublishedDocumentHandler {
+        ViewPublishedDocumentHandler::new(self.publications.clone(), self.link_signer.clone())
+    }
+}
+
+// ════════════════════════════════════════════════════════════════════════════
+// HTTP handlers
+// ════════════════════════════════════════════════════════════════════════════
+
+/// POST /api/cycles/:cycle_id/publications - Publish the decision document by public link
+pub async fn publish_document(
+    State(state): State<PublicationsAppState>,
+    RequireAuth(user): RequireAuth,
+    Path(cycle_id): Path<String>,
+    req: Option<Json<PublishDocumentRequest>>,
+) -> Response {
+    let cycle_id = match cycle_id.parse::<CycleId>() {
+        Ok(id) => id,
+        Err(_) => return bad_request("Invalid cycle ID"),
+    };
+    let req = req.map(|Json(req)| req).unwrap_or_default();
+
+    let organization = match state.export.user_organization(&user.id).await {
+        Ok(organization) => organization,
+        Err(e) => return organization_error_response(e),
+    };
+    let cmd = PublishDocumentCommand {
+        cycle_id,
+        user_id: user.id,
+        organization,
+        ttl_days: req.expires_in_days,
+    };
+
+    match state.for_request().publish_handler().handle(cmd).await {
+        Ok(result) => (
+            StatusCode::CREATED,
+            Json(PublicationResponse::new(
+                &result.publication,
+                Some(result.url),
+            )),
+        )
+            .into_response(),
+        Err(e) => publish_error_response(e),
+    }
+}
+
+/// DELETE /api/cycles/:cycle_id/publications/:publication_id - Revoke a public link
+pub async fn unpublish_document(
+    State(state): State<PublicationsAppState>,
+    RequireAuth(user): RequireAuth,
+    Path((cycle_id, publication_id)): Path<(String, String)>,
+) -> Response {
+    let cycle_id = match cycle_id.parse::<CycleId>() {
+        Ok(id) => id,
+        Err(_) => return bad_request("Invalid cycle ID"),
+    };
+    let publication_id = match publication_id.parse::<PublicationId>() {
+        Ok(id) => id,
+        Err(_) => return bad_request("Invalid publication ID"),
+    };
+    let cmd = UnpublishDocumentCommand {
+        cycle_id,
+        publication_id,
+        user_id: user.id,
+    };
+
+    match state.unpublish_handler().handle(cmd).await {
+        Ok(publication) => (
+            StatusCode::OK,
+            Json(PublicationResponse::new(&publication, None)),
+        )
+            .into_response(),
+        Err(e) => unpublish_error_response(e),
+    }
+}
+
+/// GET /public/documents/:token - Read a published document (no account needed)
+pub async fn view_published_document(
+    State(state): State<PublicationsAppState>,
+    Path(token): Path<String>,
+) -> Response {
+    match state
+        .view_handler()
+        .handle(ViewPublishedDocumentQuery { token })
+        .await
+    {
+        Ok(publication) => public_page(StatusCode::OK, publication.html),
+        Err(e) => view_error_response(e),
+    }
+}
+
+// ════════════════════════════════════════════════════════════════════════════
+// Error handling
+// ════════════════════════════════════════════════════════════════════════════
+
+fn bad_request(message: &str) -> Response {
+    (
+        StatusCode::BAD_REQUEST,
+        Json(ErrorResponse::bad_request(message)),
+    )
+        .into_response()
+}
+
+fn internal_error(message: &str, error: DomainError) -> Response {
+    tracing::error!("{}: {}", message, error);
+    (
+        StatusCode::INTERNAL_SERVER_ERROR,
+        Json(ErrorResponse::internal(message)),
+    )
+        .into_response()
+}
+
+/// Wraps `html` with [`PUBLIC_PAGE_HEADERS`]. Shared by the other pages
+/// served without an account.
+pub(crate) fn public_page(status: StatusCode, html: String) -> Response {
+    let mut response = (status, Html(html)).into_response();
+    let headers = response.headers_mut();
+    for (name, value) in PUBLIC_PAGE_HEADERS {
+        headers.insert(name, header::HeaderValue::from_static(value));
+    }
+    response
+}
+
+pub(crate) fn publish_error_response(error: PublishDocumentError) -> Response {
+    match error {
+        PublishDocumentError::Export(e) => export_error_response(e),
+        PublishDocumentError::Domain(e) => internal_error("Failed to publish document", e),
+    }
+}
+
+fn unpublish_error_response(error: UnpublishDocumentError) -> Response {
+    match error {
+        UnpublishDocumentError::NotFound(_) => (
+            StatusCode::NOT_FOUND,
+            Json(ErrorResponse::new("NOT_FOUND", error.to_string())),
+        )
+            .into_response(),
+        UnpublishDocumentError::Forbidden => (
+            StatusCode::FORBIDDEN,
+            Json(ErrorResponse::new(
+                "FORBIDDEN",
+                "Only the person who published this document can unpublish it",
+            )),
+        )
+            .into_response(),
+        UnpublishDocumentError::Domain(e) => internal_error("Failed to unpublish document", e),
+    }
+}
+
+fn view_error_response(error: ViewPublishedDocumentError) -> Response {
+    let status = match &error {
+        ViewPublishedDocumentError::InvalidLink => StatusCode::NOT_FOUND,
+        ViewPublishedDocumentError::Unavailable => StatusCode::GONE,
+        ViewPublishedDocumentError::Domain(e) => {
+            tracing::error!("Failed to load published document: {}", e);
+            StatusCode::INTERNAL_SERVER_ERROR
+        }
+    };
+    let message = match &error {
+        ViewPublishedDocumentError::Domain(_) => {
+            "Something went wrong. Try again later.".to_string()
+        }
+        _ => error.to_string(),
+    };
+    public_page(
+        status,
+        format!(
+            "<!DOCTYPE html>\n<html lang=\"en\"><head><meta charset=\"utf-8\"><meta name=\"robots\" content=\"noindex, nofollow\"><title>{0}</title></head><body><p>{0}</p></body></html>\n",
+            message
+        ),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn revoked_or_expired_links_map_to_410() {
+        let response = view_error_response(ViewPublishedDocumentError::Unavailable);
+        assert_eq!(response.status(), StatusCode::GONE);
+        assert_eq!(response.headers()["x-robots-tag"], "noindex, nofollow");
+    }
+
+    #[test]
+    fn forged_links_map_to_404() {
+        let response = view_error_response(ViewPublishedDocumentError::InvalidLink);
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    }
+
+    #[test]
+    fn public_pages_are_not_indexed_or_cached() {
+        let response = public_page(StatusCode::OK, "<p>Hi</p>".to_string());
+        let headers = response.headers();
+        assert_eq!(headers["x-robots-tag"], "noindex, nofollow");
+        assert_eq!(headers["cache-control"], "no-store");
+        assert_eq!(headers["referrer-policy"], "no-referrer");
+        assert!(headers["content-type"]
+            .to_str()
+            .unwrap()
+            .starts_with("text/html"));
+    }
+
+    #[test]
+    fn only_the_publisher_may_unpublish() {
+        let response = unpublish_error_response(UnpublishDocumentError::Forbidden);
+        assert_eq!(response.status(), StatusCode::FORBIDDEN);
+    }
+}
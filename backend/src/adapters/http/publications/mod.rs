@@ -0,0 +1,12 @@
+//! Public document links HTTP adapter module.
+//!
+//! Publishes read-only snapshots of the decision document behind signed
+//! links and serves them to people without an account.
+
+pub mod dto;
+pub mod handlers;
+pub mod routes;
+
+pub use dto::{ErrorResponse, PublicationResponse, PublishDocumentRequest};
+pub use handlers::PublicationsAppState;
+pub use routes::publication_routes;
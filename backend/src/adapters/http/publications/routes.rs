@@ -0,0 +1,27 @@
+//! HTTP routes for public document link endpoints.
+
+use axum::{
+    routing::{delete, get, post},
+    Router,
+};
+
+use super::handlers::{
+    publish_document, unpublish_document, view_published_document, PublicationsAppState,
+};
+
+/// Creates the public document links router.
+///
+/// # Routes
+/// - `POST /api/cycles/:cycle_id/publications` - Publish the decision document by public link
+/// - `DELETE /api/cycles/:cycle_id/publications/:publication_id` - Revoke a public link
+/// - `GET /public/documents/:token` - Read a published document (no account needed)
+pub fn publication_routes(state: PublicationsAppState) -> Router {
+    Router::new()
+        .route("/api/cycles/:cycle_id/publications", post(publish_document))
+        .route(
+            "/api/cycles/:cycle_id/publications/:publication_id",
+            delete(unpublish_document),
+        )
+        .route("/public/documents/:token", get(view_published_document))
+        .with_state(state)
+}
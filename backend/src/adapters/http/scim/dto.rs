@@ -0,0 +1,563 @@
+//! SCIM 2.0 message and resource types (RFC 7643, RFC 7644).
+//!
+//! Only the parts of the User resource the provisioning domain keeps are
+//! modelled; other attributes are accepted and ignored.
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::domain::foundation::Timestamp;
+use crate::domain::provisioning::{ProvisionedUser, ProvisionedUserChanges};
+
+pub const USER_SCHEMA: &str = "urn:ietf:params:scim:schemas:core:2.0:User";
+pub const LIST_RESPONSE_SCHEMA: &str = "urn:ietf:params:scim:api:messages:2.0:ListResponse";
+pub const PATCH_OP_SCHEMA: &str = "urn:ietf:params:scim:api:messages:2.0:PatchOp";
+pub const ERROR_SCHEMA: &str = "urn:ietf:params:scim:api:messages:2.0:Error";
+pub const SERVICE_PROVIDER_CONFIG_SCHEMA: &str =
+    "urn:ietf:params:scim:schemas:core:2.0:ServiceProviderConfig";
+
+/// Media type of every SCIM response body.
+pub const SCIM_CONTENT_TYPE: &str = "application/scim+json";
+
+// ════════════════════════════════════════════════════════════════════════════
+// Request DTOs
+// ════════════════════════════════════════════════════════════════════════════
+
+/// The user's name components.
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ScimName {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub formatted: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub given_name: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub family_name: Option<String>,
+}
+
+impl ScimName {
+    /// `formatted`, or the given and family names joined.
+    fn full_name(&self) -> Option<String> {
+        self.formatted.clone().or_else(|| {
+            let parts: Vec<&str> = [&self.given_name, &self.family_name]
+                .into_iter()
+                .flatten()
+                .map(String::as_str)
+                .collect();
+            (!parts.is_empty()).then(|| parts.join(" "))
+        })
+    }
+}
+
+/// One of the user's email addresses.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct ScimEmail {
+    pub value: String,
+    #[serde(default)]
+    pub primary: bool,
+    #[serde(default, rename = "type", skip_serializing_if = "Option::is_none")]
+    pub kind: Option<String>,
+}
+
+/// User resource sent on create (`POST`) and replace (`PUT`).
+///
+/// `externalId` must carry the user's subject at the identity provider; it
+/// becomes the resource `id`, so tokens issued to the user match it.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ScimUserRequest {
+    #[serde(default)]
+    pub external_id: Option<String>,
+    pub user_name: String,
+    #[serde(default)]
+    pub name: Option<ScimName>,
+    #[serde(default)]
+    pub display_name: Option<String>,
+    #[serde(default)]
+    pub emails: Vec<ScimEmail>,
+    #[serde(default = "default_active")]
+    pub active: bool,
+}
+
+fn default_active() -> bool {
+    true
+}
+
+impl ScimUserRequest {
+    /// The primary email, or the first one listed.
+    pub fn primary_email(&self) -> Option<String> {
+        primary_email(&self.emails)
+    }
+
+    /// `displayName`, falling back to the name components.
+    pub fn full_display_name(&self) -> Option<String> {
+        self.display_name
+            .clone()
+            .or_else(|| self.name.as_ref().and_then(ScimName::full_name))
+    }
+
+    /// Changes that make a stored user match this resource.
+    pub fn into_changes(self) -> ProvisionedUserChanges {
+        ProvisionedUserChanges {
+            email: Some(self.primary_email()),
+            display_name: Some(self.full_display_name()),
+            active: Some(self.active),
+            user_name: Some(self.user_name),
+        }
+    }
+}
+
+fn primary_email(emails: &[ScimEmail]) -> Option<String> {
+    emails
+        .iter()
+        .find(|email| email.primary)
+        .or_else(|| emails.first())
+        .map(|email| email.value.clone())
+}
+
+/// Body of a `PATCH` request.
+#[derive(Debug, Clone, Deserialize)]
+pub struct ScimPatchRequest {
+    #[serde(rename = "Operations")]
+    pub operations: Vec<ScimPatchOperation>,
+}
+
+/// One `add`, `replace`, or `remove` operation.
+#[derive(Debug, Clone, Deserialize)]
+pub struct ScimPatchOperation {
+    /// Operation name; identity providers differ in capitalisation.
+    pub op: String,
+    #[serde(default)]
+    pub path: Option<String>,
+    #[serde(default)]
+    pub value: Option<Value>,
+}
+
+impl ScimPatchRequest {
+    /// Folds the operations into one set of changes, later operations
+    /// overriding earlier ones. Fails with a SCIM `detail` message on
+    /// unsupported operations, paths, or values.
+    pub fn into_changes(self) -> Result<ProvisionedUserChanges, String> {
+        let mut changes = ProvisionedUserChanges::default();
+        for operation in self.operations {
+            let op = operation.op.to_ascii_lowercase();
+            match (op.as_str(), operation.path, operation.value) {
+                ("add" | "replace", Some(path), Some(value)) => {
+                    set_attribute(&mut changes, &path, value)?
+                }
+                ("add" | "replace", None, Some(Value::Object(attributes))) => {
+                    for (path, value) in attributes {
+                        set_attribute(&mut changes, &path, value)?;
+                    }
+                }
+                ("remove", Some(path), _) => remove_attribute(&mut changes, &path)?,
+                ("add" | "replace" | "remove", _, _) => {
+                    return Err(format!("'{}' operation is missing its path or value", op))
+                }
+                _ => return Err(format!("Unsupported operation '{}'", operation.op)),
+            }
+        }
+        Ok(changes)
+    }
+}
+
+/// Which stored attribute a SCIM path refers to.
+#[derive(Debug, PartialEq, Eq)]
+enum Attribute {
+    UserName,
+    DisplayName,
+    Email,
+    Active,
+}
+
+fn attribute(path: &str) -> Result<Attribute, String> {
+    let path = path.trim();
+    let lower = path.to_ascii_lowercase();
+    let lower = lower
+        .strip_prefix(&format!("{}:", USER_SCHEMA.to_ascii_lowercase()))
+        .unwrap_or(&lower);
+    match lower {
+        "username" => Ok(Attribute::UserName),
+        "displayname" | "name.formatted" => Ok(Attribute::DisplayName),
+        "active" => Ok(Attribute::Active),
+        "emails" => Ok(Attribute::Email),
+        // e.g. emails[type eq "work"].value
+        p if p.starts_with("emails[") && p.ends_with("].value") => Ok(Attribute::Email),
+        _ => Err(format!("Unsupported attribute path '{}'", path)),
+    }
+}
+
+fn set_attribute(
+    changes: &mut ProvisionedUserChanges,
+    path: &str,
+    value: Value,
+) -> Result<(), String> {
+    match attribute(path)? {
+        Attribute::UserName => changes.user_name = Some(string_value(path, value)?),
+        Attribute::DisplayName => changes.display_name = Some(Some(string_value(path, value)?)),
+        Attribute::Active => changes.active = Some(bool_value(path, value)?),
+        Attribute::Email => {
+            let email = match value {
+                Value::Array(_) => {
+                    let emails: Vec<ScimEmail> = serde_json::from_value(value)
+                        .map_err(|e| format!("Invalid value for '{}': {}", path, e))?;
+                    primary_email(&emails)
+                }
+                value => Some(string_value(path, value)?),
+            };
+            changes.email = Some(email);
+        }
+    }
+    Ok(())
+}
+
+fn remove_attribute(changes: &mut ProvisionedUserChanges, path: &str) -> Result<(), String> {
+    match attribute(path)? {
+        Attribute::DisplayName => changes.display_name = Some(None),
+        Attribute::Email => changes.email = Some(None),
+        Attribute::UserName | Attribute::Active => {
+            return Err(format!("'{}' is required and cannot be removed", path))
+        }
+    }
+    Ok(())
+}
+
+fn string_value(path: &str, value: Value) -> Result<String, String> {
+    match value {
+        Value::String(s) => Ok(s),
+        _ => Err(format!("'{}' must be a string", path)),
+    }
+}
+
+/// Accepts JSON booleans and the `"True"`/`"False"` strings some identity
+/// providers send.
+fn bool_value(path: &str, value: Value) -> Result<bool, String> {
+    match value {
+        Value::Bool(b) => Ok(b),
+        Value::String(s) if s.eq_ignore_ascii_case("true") => Ok(true),
+        Value::String(s) if s.eq_ignore_ascii_case("false") => Ok(false),
+        _ => Err(format!("'{}' must be a boolean", path)),
+    }
+}
+
+/// Query parameters of `GET /Users`.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ScimListQuery {
+    #[serde(default)]
+    pub filter: Option<String>,
+    /// 1-based index of the first result.
+    #[serde(default)]
+    pub start_index: Option<u32>,
+    /// Page size, capped at the advertised `maxResults`.
+    #[serde(default)]
+    pub count: Option<u32>,
+}
+
+/// Extracts the value from a `userName eq "..."` filter, the only filter
+/// identity providers need to find existing users.
+pub fn parse_user_name_filter(filter: &str) -> Option<String> {
+    let mut parts = filter.trim().splitn(3, char::is_whitespace);
+    let attribute = parts.next()?;
+    let operator = parts.next()?;
+    let value = parts.next()?.trim();
+    if !attribute.eq_ignore_ascii_case("userName") || !operator.eq_ignore_ascii_case("eq") {
+        return None;
+    }
+    value
+        .strip_prefix('"')
+        .and_then(|v| v.strip_suffix('"'))
+        .map(str::to_string)
+}
+
+// ════════════════════════════════════════════════════════════════════════════
+// Response DTOs
+// ════════════════════════════════════════════════════════════════════════════
+
+/// Resource metadata.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ScimMeta {
+    pub resource_type: &'static str,
+    pub created: Timestamp,
+    pub last_modified: Timestamp,
+    pub location: String,
+}
+
+/// A provisioned user as a SCIM User resource.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ScimUserResource {
+    pub schemas: Vec<&'static str>,
+    pub id: String,
+    pub external_id: String,
+    pub user_name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub display_name: Option<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub emails: Vec<ScimEmail>,
+    pub active: bool,
+    pub meta: ScimMeta,
+}
+
+impl From<ProvisionedUser> for ScimUserResource {
+    fn from(user: ProvisionedUser) -> Self {
+        let id = user.user_id.to_string();
+        Self {
+            schemas: vec![USER_SCHEMA],
+            meta: ScimMeta {
+                resource_type: "User",
+                created: user.created_at,
+                last_modified: user.updated_at,
+                location: format!("/scim/v2/Users/{}", id),
+            },
+            external_id: id.clone(),
+            id,
+            user_name: user.user_name,
+            display_name: user.display_name,
+            emails: user
+                .email
+                .into_iter()
+                .map(|value| ScimEmail {
+                    value,
+                    primary: true,
+                    kind: Some("work".to_string()),
+                })
+                .collect(),
+            active: user.active,
+        }
+    }
+}
+
+/// A page of resources.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ScimListResponse {
+    pub schemas: Vec<&'static str>,
+    pub total_results: u64,
+    pub start_index: u32,
+    pub items_per_page: u32,
+    #[serde(rename = "Resources")]
+    pub resources: Vec<ScimUserResource>,
+}
+
+impl ScimListResponse {
+    pub fn new(resources: Vec<ScimUserResource>, total_results: u64, start_index: u32) -> Self {
+        Self {
+            schemas: vec![LIST_RESPONSE_SCHEMA],
+            total_results,
+            start_index,
+            items_per_page: resources.len() as u32,
+            resources,
+        }
+    }
+}
+
+/// Whether an optional SCIM feature is supported.
+#[derive(Debug, Clone, Serialize)]
+pub struct ScimFeature {
+    pub supported: bool,
+}
+
+/// Filtering support, with the most results one list request returns.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ScimFilterFeature {
+    pub supported: bool,
+    pub max_results: u32,
+}
+
+/// Bulk operation support.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ScimBulkFeature {
+    pub supported: bool,
+    pub max_operations: u32,
+    pub max_payload_size: u32,
+}
+
+/// A way identity providers can authenticate.
+#[derive(Debug, Clone, Serialize)]
+pub struct ScimAuthenticationScheme {
+    #[serde(rename = "type")]
+    pub kind: &'static str,
+    pub name: &'static str,
+    pub description: &'static str,
+}
+
+/// The SCIM features this service provider supports (RFC 7643 section 5).
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ScimServiceProviderConfig {
+    pub schemas: Vec<&'static str>,
+    pub patch: ScimFeature,
+    pub bulk: ScimBulkFeature,
+    pub filter: ScimFilterFeature,
+    pub change_password: ScimFeature,
+    pub sort: ScimFeature,
+    pub etag: ScimFeature,
+    pub authentication_schemes: Vec<ScimAuthenticationScheme>,
+}
+
+impl ScimServiceProviderConfig {
+    /// Configuration advertising at most `max_results` users per list.
+    pub fn new(max_results: u32) -> Self {
+        Self {
+            schemas: vec![SERVICE_PROVIDER_CONFIG_SCHEMA],
+            patch: ScimFeature { supported: true },
+            bulk: ScimBulkFeature {
+                supported: false,
+                max_operations: 0,
+                max_payload_size: 0,
+            },
+            filter: ScimFilterFeature {
+                supported: true,
+                max_results,
+            },
+            change_password: ScimFeature { supported: false },
+            sort: ScimFeature { supported: false },
+            etag: ScimFeature { supported: false },
+            authentication_schemes: vec![ScimAuthenticationScheme {
+                kind: "oauthbearertoken",
+                name: "OAuth Bearer Token",
+                description: "Client-credentials access token with the scim:provision scope",
+            }],
+        }
+    }
+}
+
+/// SCIM error body.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ScimError {
+    pub schemas: Vec<&'static str>,
+    /// HTTP status code, as a string per RFC 7644.
+    pub status: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub scim_type: Option<&'static str>,
+    pub detail: String,
+}
+
+impl ScimError {
+    pub fn new(status: u16, scim_type: Option<&'static str>, detail: impl Into<String>) -> Self {
+        Self {
+            schemas: vec![ERROR_SCHEMA],
+            status: status.to_string(),
+            scim_type,
+            detail: detail.into(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn patch(operations: Value) -> ScimPatchRequest {
+        serde_json::from_value(json!({
+            "schemas": [PATCH_OP_SCHEMA],
+            "Operations": operations,
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn create_request_prefers_primary_email_and_formatted_name() {
+        let request: ScimUserRequest = serde_json::from_value(json!({
+            "schemas": [USER_SCHEMA],
+            "externalId": "00u1",
+            "userName": "ada@example.com",
+            "name": { "givenName": "Ada", "familyName": "Lovelace" },
+            "emails": [
+                { "value": "ada@home.example", "type": "home" },
+                { "value": "ada@example.com", "type": "work", "primary": true }
+            ]
+        }))
+        .unwrap();
+
+        assert!(request.active);
+        assert_eq!(request.primary_email().as_deref(), Some("ada@example.com"));
+        assert_eq!(request.full_display_name().as_deref(), Some("Ada Lovelace"));
+    }
+
+    #[test]
+    fn patch_with_path_deactivates_from_string_value() {
+        let changes = patch(json!([
+            { "op": "Replace", "path": "active", "value": "False" }
+        ]))
+        .into_changes()
+        .unwrap();
+
+        assert_eq!(changes.active, Some(false));
+        assert_eq!(changes.user_name, None);
+    }
+
+    #[test]
+    fn patch_without_path_applies_each_attribute() {
+        let changes = patch(json!([{
+            "op": "replace",
+            "value": {
+                "active": true,
+                "displayName": "Ada",
+                "emails[type eq \"work\"].value": "ada@example.com"
+            }
+        }]))
+        .into_changes()
+        .unwrap();
+
+        assert_eq!(changes.active, Some(true));
+        assert_eq!(changes.display_name, Some(Some("Ada".to_string())));
+        assert_eq!(changes.email, Some(Some("ada@example.com".to_string())));
+    }
+
+    #[test]
+    fn patch_remove_clears_optional_attributes_only() {
+        let changes = patch(json!([{ "op": "remove", "path": "displayName" }]))
+            .into_changes()
+            .unwrap();
+        assert_eq!(changes.display_name, Some(None));
+
+        let error = patch(json!([{ "op": "remove", "path": "userName" }]))
+            .into_changes()
+            .unwrap_err();
+        assert!(error.contains("cannot be removed"));
+    }
+
+    #[test]
+    fn patch_rejects_unknown_paths() {
+        let error = patch(json!([
+            { "op": "replace", "path": "title", "value": "CEO" }
+        ]))
+        .into_changes()
+        .unwrap_err();
+
+        assert!(error.contains("title"));
+    }
+
+    #[test]
+    fn parses_user_name_filters() {
+        assert_eq!(
+            parse_user_name_filter(r#"userName eq "ada@example.com""#).as_deref(),
+            Some("ada@example.com")
+        );
+        assert_eq!(
+            parse_user_name_filter(r#"USERNAME EQ "ada""#).as_deref(),
+            Some("ada")
+        );
+        assert_eq!(parse_user_name_filter(r#"emails co "ada""#), None);
+    }
+
+    #[test]
+    fn service_provider_config_advertises_max_results() {
+        let config = serde_json::to_value(ScimServiceProviderConfig::new(200)).unwrap();
+
+        assert_eq!(config["schemas"][0], SERVICE_PROVIDER_CONFIG_SCHEMA);
+        assert_eq!(config["filter"]["maxResults"], 200);
+        assert_eq!(config["bulk"]["supported"], false);
+        assert_eq!(
+            config["authenticationSchemes"][0]["type"],
+            "oauthbearertoken"
+        );
+    }
+}
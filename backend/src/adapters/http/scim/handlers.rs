@@ -0,0 +1,324 @@
+//! HTTP handlers for SCIM endpoints.
+//!
+//! Callers authenticate as a service account holding the
+//! [`SCIM_PROVISION_SCOPE`] scope. Errors use the SCIM error schema rather
+//! than the API's usual error body, as identity providers expect.
+
+use std::sync::Arc;
+
+use axum::{
+    extract::{Path, Query, State},
+    http::{header, StatusCode},
+    response::{IntoResponse, Response},
+    Json,
+};
+use serde::Serialize;
+
+use crate::adapters::http::middleware::RequireService;
+use crate::application::handlers::{
+    DeprovisionUserCommand, DeprovisionUserHandler, GetProvisionedUsersHandler,
+    ManageProvisionedUserError, ProvisionUserCommand, ProvisionUserHandler,
+    UpdateProvisionedUserCommand, UpdateProvisionedUserHandler, MAX_PROVISIONED_USERS_PAGE,
+};
+use crate::domain::foundation::UserId;
+use crate::ports::{EventPublisher, ProvisionedUserRepository};
+
+use super::dto::{
+    parse_user_name_filter, ScimError, ScimListQuery, ScimListResponse, ScimPatchRequest,
+    ScimServiceProviderConfig, ScimUserRequest, ScimUserResource, SCIM_CONTENT_TYPE,
+};
+
+/// Scope a service token needs to call the SCIM endpoints.
+pub const SCIM_PROVISION_SCOPE: &str = "scim:provision";
+
+/// Page size when the identity provider does not ask for one.
+const DEFAULT_PAGE_SIZE: u32 = 100;
+
+// ════════════════════════════════════════════════════════════════════════════
+// Application State
+// ════════════════════════════════════════════════════════════════════════════
+
+/// Shared state for the SCIM endpoints.
+#[derive(Clone)]
+pub struct ScimAppState {
+    pub users: Arc<dyn ProvisionedUserRepository>,
+    pub event_publisher: Arc<dyn EventPublisher>,
+}
+
+impl ScimAppState {
+    pub fn provision_handler(&self) -> ProvisionUserHandler {
+        ProvisionUserHandler::new(self.users.clone(), self.event_publisher.clone())
+    }
+
+    pub fn update_handler(&self) -> UpdateProvisionedUserHandler {
+        UpdateProvisionedUserHandler::new(self.users.clone(), self.event_publisher.clone())
+    }
+
+    pub fn deprovision_handler(&self) -> DeprovisionUserHandler {
+        DeprovisionUserHandler::new(self.users.clone(), self.event_publisher.clone())
+    }
+
+    pub fn query_handler(&self) -> GetProvisionedUsersHandler {
+        GetProvisionedUsersHandler::new(self.users.clone())
+    }
+}
+
+// ════════════════════════════════════════════════════════════════════════════
+// HTTP handlers
+// ════════════════════════════════════════════════════════════════════════════
+
+/// POST /scim/v2/Users - Provision a user
+pub async fn create_user(
+    State(state): State<ScimAppState>,
+    service: RequireService,
+    Json(req): Json<ScimUserRequest>,
+) -> Response {
+    if let Err(rejection) = service.require_scope(SCIM_PROVISION_SCOPE) {
+        return rejection.into_response();
+    }
+    let Some(user_id) = req
+        .external_id
+        .as_deref()
+        .and_then(|id| UserId::new(id).ok())
+    else {
+        return scim_error(
+            StatusCode::BAD_REQUEST,
+            Some("invalidValue"),
+            "externalId must carry the user's subject at the identity provider",
+        );
+    };
+
+    let cmd = ProvisionUserCommand {
+        client_id: service.0.client_id.clone(),
+        user_id,
+        email: req.primary_email(),
+        display_name: req.full_display_name(),
+        user_name: req.user_name,
+        active: req.active,
+    };
+
+    match state.provision_handler().handle(cmd).await {
+        Ok(user) => scim_json(StatusCode::CREATED, ScimUserResource::from(user)),
+        Err(e) => handle_error(e),
+    }
+}
+
+/// GET /scim/v2/Users - List users, optionally filtered by `userName`
+pub async fn list_users(
+    State(state): State<ScimAppState>,
+    service: RequireService,
+    Query(query): Query<ScimListQuery>,
+) -> Response {
+    if let Err(rejection) = service.require_scope(SCIM_PROVISION_SCOPE) {
+        return rejection.into_response();
+    }
+    let start_index = query.start_index.unwrap_or(1).max(1);
+    let handler = state.query_handler();
+
+    if let Some(filter) = query.filter {
+        let Some(user_name) = parse_user_name_filter(&filter) else {
+            return scim_error(
+                StatusCode::BAD_REQUEST,
+                Some("invalidFilter"),
+                "Only 'userName eq \"...\"' filters are supported",
+            );
+        };
+        return match handler
+            .find_by_user_name(&service.0.client_id, &user_name)
+            .await
+        {
+            Ok(user) => {
+                let resources: Vec<ScimUserResource> =
+                    user.into_iter().map(ScimUserResource::from).collect();
+                let total = resources.len() as u64;
+                scim_json(
+                    StatusCode::OK,
+                    ScimListResponse::new(resources, total, start_index),
+                )
+            }
+            Err(e) => handle_error(e),
+        };
+    }
+
+    let count = query
+        .count
+        .unwrap_or(DEFAULT_PAGE_SIZE)
+        .min(MAX_PROVISIONED_USERS_PAGE);
+    match handler
+        .list(&service.0.client_id, start_index - 1, count)
+        .await
+    {
+        Ok(page) => {
+            let resources = page.users.into_iter().map(ScimUserResource::from).collect();
+            scim_json(
+                StatusCode::OK,
+                ScimListResponse::new(resources, page.total, start_index),
+            )
+        }
+        Err(e) => handle_error(e),
+    }
+}
+
+/// GET /scim/v2/ServiceProviderConfig - Describe the supported SCIM features
+pub async fn service_provider_config(service: RequireService) -> Response {
+    if let Err(rejection) = service.require_scope(SCIM_PROVISION_SCOPE) {
+        return rejection.into_response();
+    }
+    scim_json(
+        StatusCode::OK,
+        ScimServiceProviderConfig::new(MAX_PROVISIONED_USERS_PAGE),
+    )
+}
+
+/// GET /scim/v2/Users/:id - Fetch one user
+pub async fn get_user(
+    State(state): State<ScimAppState>,
+    service: RequireService,
+    Path(id): Path<String>,
+) -> Response {
+    if let Err(rejection) = service.require_scope(SCIM_PROVISION_SCOPE) {
+        return rejection.into_response();
+    }
+    let Ok(user_id) = UserId::new(id) else {
+        return user_not_found();
+    };
+
+    match state
+        .query_handler()
+        .get(&service.0.client_id, &user_id)
+        .await
+    {
+        Ok(user) => scim_json(StatusCode::OK, ScimUserResource::from(user)),
+        Err(e) => handle_error(e),
+    }
+}
+
+/// PUT /scim/v2/Users/:id - Replace a user's attributes
+pub async fn replace_user(
+    State(state): State<ScimAppState>,
+    service: RequireService,
+    Path(id): Path<String>,
+    Json(req): Json<ScimUserRequest>,
+) -> Response {
+    if let Err(rejection) = service.require_scope(SCIM_PROVISION_SCOPE) {
+        return rejection.into_response();
+    }
+    let Ok(user_id) = UserId::new(id) else {
+        return user_not_found();
+    };
+    let cmd = UpdateProvisionedUserCommand {
+        client_id: service.0.client_id.clone(),
+        user_id,
+        changes: req.into_changes(),
+    };
+
+    match state.update_handler().handle(cmd).await {
+        Ok(user) => scim_json(StatusCode::OK, ScimUserResource::from(user)),
+        Err(e) => handle_error(e),
+    }
+}
+
+/// PATCH /scim/v2/Users/:id - Change some attributes, including `active`
+pub async fn patch_user(
+    State(state): State<ScimAppState>,
+    service: RequireService,
+    Path(id): Path<String>,
+    Json(req): Json<ScimPatchRequest>,
+) -> Response {
+    if let Err(rejection) = service.require_scope(SCIM_PROVISION_SCOPE) {
+        return rejection.into_response();
+    }
+    let Ok(user_id) = UserId::new(id) else {
+        return user_not_found();
+    };
+    let changes = match req.into_changes() {
+        Ok(changes) => changes,
+        Err(detail) => return scim_error(StatusCode::BAD_REQUEST, Some("invalidPath"), detail),
+    };
+    let cmd = UpdateProvisionedUserCommand {
+        client_id: service.0.client_id.clone(),
+        user_id,
+        changes,
+    };
+
+    match state.update_handler().handle(cmd).await {
+        Ok(user) => scim_json(StatusCode::OK, ScimUserResource::from(user)),
+        Err(e) => handle_error(e),
+    }
+}
+
+/// DELETE /scim/v2/Users/:id - Deprovision a user
+///
+/// The user is deactivated rather than deleted, so their decisions survive
+/// if the identity provider brings them back.
+pub async fn delete_user(
+    State(state): State<ScimAppState>,
+    service: RequireService,
+    Path(id): Path<String>,
+) -> Response {
+    if let Err(rejection) = service.require_scope(SCIM_PROVISION_SCOPE) {
+        return rejection.into_response();
+    }
+    let Ok(user_id) = UserId::new(id) else {
+        return user_not_found();
+    };
+    let cmd = DeprovisionUserCommand {
+        client_id: service.0.client_id.clone(),
+        user_id,
+    };
+
+    match state.deprovision_handler().handle(cmd).await {
+        Ok(_) => StatusCode::NO_CONTENT.into_response(),
+        Err(e) => handle_error(e),
+    }
+}
+
+// ════════════════════════════════════════════════════════════════════════════
+// Responses
+// ════════════════════════════════════════════════════════════════════════════
+
+fn scim_json(status: StatusCode, body: impl Serialize) -> Response {
+    (
+        status,
+        [(header::CONTENT_TYPE, SCIM_CONTENT_TYPE)],
+        Json(body),
+    )
+        .into_response()
+}
+
+fn scim_error(
+    status: StatusCode,
+    scim_type: Option<&'static str>,
+    detail: impl Into<String>,
+) -> Response {
+    scim_json(status, ScimError::new(status.as_u16(), scim_type, detail))
+}
+
+fn user_not_found() -> Response {
+    scim_error(StatusCode::NOT_FOUND, None, "User not found")
+}
+
+fn handle_error(error: ManageProvisionedUserError) -> Response {
+    match &error {
+        ManageProvisionedUserError::NotFound(_) => {
+            scim_error(StatusCode::NOT_FOUND, None, error.to_string())
+        }
+        ManageProvisionedUserError::AlreadyProvisioned(_)
+        | ManageProvisionedUserError::UserNameTaken(_) => {
+            scim_error(StatusCode::CONFLICT, Some("uniqueness"), error.to_string())
+        }
+        ManageProvisionedUserError::Invalid(_) => scim_error(
+            StatusCode::BAD_REQUEST,
+            Some("invalidValue"),
+            error.to_string(),
+        ),
+        ManageProvisionedUserError::Domain(e) => {
+            tracing::error!("SCIM request failed: {}", e);
+            scim_error(
+                StatusCode::INTERNAL_SERVER_ERROR,
+                None,
+                "Failed to process provisioning request",
+            )
+        }
+    }
+}
@@ -0,0 +1,13 @@
+//! SCIM 2.0 HTTP adapter module.
+//!
+//! Lets enterprise identity providers (Okta, Entra ID, ...) provision and
+//! deprovision users. Only the Users resource is supported; each change is
+//! recorded as a provisioning event for audit.
+
+pub mod dto;
+pub mod handlers;
+pub mod routes;
+
+pub use dto::{ScimError, ScimListResponse, ScimPatchRequest, ScimUserRequest, ScimUserResource};
+pub use handlers::{ScimAppState, SCIM_PROVISION_SCOPE};
+pub use routes::scim_routes;
@@ -0,0 +1,35 @@
+//! HTTP routes for SCIM endpoints.
+
+use axum::{routing::get, Router};
+
+use super::handlers::{
+    create_user, delete_user, get_user, list_users, patch_user, replace_user,
+    service_provider_config, ScimAppState,
+};
+
+/// Creates the SCIM 2.0 router.
+///
+/// # Routes
+/// - `GET /scim/v2/ServiceProviderConfig` - Supported features and page size limit
+/// - `GET /scim/v2/Users` - List users, or find one with a `userName` filter
+/// - `POST /scim/v2/Users` - Provision a user
+/// - `GET /scim/v2/Users/:id` - Fetch a user
+/// - `PUT /scim/v2/Users/:id` - Replace a user's attributes
+/// - `PATCH /scim/v2/Users/:id` - Change attributes or deactivate a user
+/// - `DELETE /scim/v2/Users/:id` - Deprovision a user
+pub fn scim_routes(state: ScimAppState) -> Router {
+    Router::new()
+        .route(
+            "/scim/v2/ServiceProviderConfig",
+            get(service_provider_config),
+        )
+        .route("/scim/v2/Users", get(list_users).post(create_user))
+        .route(
+            "/scim/v2/Users/:id",
+            get(get_user)
+                .put(replace_user)
+                .patch(patch_user)
+                .delete(delete_user),
+        )
+        .with_state(state)
+}
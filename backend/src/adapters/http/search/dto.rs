@@ -0,0 +1,155 @@
+//! HTTP DTOs for search.
+
+use serde::{Deserialize, Serialize};
+
+use crate::application::handlers::search::SimilarDecisionView;
+use crate::domain::foundation::ComponentType;
+use crate::ports::{EmbeddingSource, SearchHit, SearchHitKind, SearchResults};
+
+// ════════════════════════════════════════════════════════════════════════════
+// Request DTOs
+// ════════════════════════════════════════════════════════════════════════════
+
+/// Query parameters for a search.
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct SearchParams {
+    #[serde(default)]
+    pub q: String,
+    pub limit: Option<u32>,
+    pub offset: Option<u32>,
+}
+
+/// Query parameters for similar past decisions; give `cycle_id` or `q`.
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct SimilarDecisionsParams {
+    pub cycle_id: Option<String>,
+    pub q: Option<String>,
+    pub limit: Option<u32>,
+}
+
+// ════════════════════════════════════════════════════════════════════════════
+// Response DTOs
+// ════════════════════════════════════════════════════════════════════════════
+
+/// One ranked result.
+#[derive(Debug, Clone, Serialize)]
+pub struct SearchHitResponse {
+    pub kind: SearchHitKind,
+    pub session_id: String,
+    pub session_title: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cycle_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub component_type: Option<ComponentType>,
+    /// Matched excerpt with matching terms wrapped in `**`.
+    pub snippet: String,
+    pub rank: f32,
+    pub updated_at: String,
+}
+
+impl From<SearchHit> for SearchHitResponse {
+    fn from(hit: SearchHit) -> Self {
+        Self {
+            kind: hit.kind,
+            session_id: hit.session_id.to_string(),
+            session_title: hit.session_title,
+            cycle_id: hit.cycle_id.map(|id| id.to_string()),
+            component_type: hit.component_type,
+            snippet: hit.snippet,
+            rank: hit.rank,
+            updated_at: hit.updated_at.as_datetime().to_rfc3339(),
+        }
+    }
+}
+
+/// One page of results.
+#[derive(Debug, Clone, Serialize)]
+pub struct SearchResponse {
+    pub results: Vec<SearchHitResponse>,
+    pub has_more: bool,
+}
+
+impl From<SearchResults> for SearchResponse {
+    fn from(results: SearchResults) -> Self {
+        Self {
+            results: results.hits.into_iter().map(Into::into).collect(),
+            has_more: results.has_more,
+        }
+    }
+}
+
+/// A past decision resembling the one searched for.
+#[derive(Debug, Clone, Serialize)]
+pub struct SimilarDecisionResponse {
+    pub cycle_id: String,
+    pub session_id: String,
+    pub session_title: String,
+    /// Component whose content matched best.
+    pub component_type: ComponentType,
+    pub source: EmbeddingSource,
+    /// Cosine similarity, from -1 to 1.
+    pub similarity: f32,
+}
+
+impl From<SimilarDecisionView> for SimilarDecisionResponse {
+    fn from(view: SimilarDecisionView) -> Self {
+        Self {
+            cycle_id: view.cycle_id.to_string(),
+            session_id: view.session_id.to_string(),
+            session_title: view.session_title,
+            component_type: view.component_type,
+            source: view.source,
+            similarity: view.similarity,
+        }
+    }
+}
+
+/// Similar past decisions, most similar first.
+#[derive(Debug, Clone, Serialize)]
+pub struct SimilarDecisionsResponse {
+    pub decisions: Vec<SimilarDecisionResponse>,
+}
+
+/// Standard error response.
+#[derive(Debug, Clone, Serialize)]
+pub struct ErrorResponse {
+    pub code: String,
+    pub message: String,
+}
+
+impl ErrorResponse {
+    pub fn bad_request(message: impl Into<String>) -> Self {
+        Self {
+            code: "BAD_REQUEST".to_string(),
+            message: message.into(),
+        }
+    }
+
+    pub fn forbidden() -> Self {
+        Self {
+            code: "FORBIDDEN".to_string(),
+            message: "Permission denied".to_string(),
+        }
+    }
+
+    pub fn not_found(message: impl Into<String>) -> Self {
+        Self {
+            code: "NOT_FOUND".to_string(),
+            message: message.into(),
+        }
+    }
+
+    pub fn service_unavailable(message: impl Into<String>) -> Self {
+        Self {
+            code: "SERVICE_UNAVAILABLE".to_string(),
+            message: message.into(),
+        }
+    }
+
+    pub fn internal(message: impl Into<String>) -> Self {
+        Self {
+            code: "INTERNAL_ERROR".to_string(),
+            message: message.into(),
+        }
+    }
+}
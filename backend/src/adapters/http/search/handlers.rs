@@ -0,0 +1,369 @@
+//! HTTP handlers for search.
+
+use std::sync::Arc;
+
+use axum::{
+    extract::{Query, State},
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    Json,
+};
+
+use crate::adapters::http::middleware::RequireAuth;
+use crate::application::handlers::search::{
+    FindSimilarDecisionsError, FindSimilarDecisionsHandler, FindSimilarDecisionsQuery, SimilarTo,
+};
+use crate::domain::foundation::CycleId;
+use crate::ports::{SearchError, SearchQuery, SearchService};
+
+use super::dto::{
+    ErrorResponse, SearchParams, SearchResponse, SimilarDecisionsParams,
+    SimilarDecisionsResponse,
+};
+
+// ════════════════════════════════════════════════════════════════════════════
+// Application State
+// ════════════════════════════════════════════════════════════════════════════
+
+/// Shared state for the search endpoints.
+#[derive(Clone)]
+pub struct SearchAppState {
+    pub search: Arc<dyn SearchService>,
+    pub similar: Arc<FindSimilarDecisionsHandler>,
+}
+
+// ════════════════════════════════════════════════════════════════════════════
+// HTTP handlers
+// ════════════════════════════════════════════════════════════════════════════
+
+/// GET /api/search?q= - Ranked results the caller can access
+pub async fn search(
+    State(state): State<SearchAppState>,
+    RequireAuth(user): RequireAuth,
+    Query(params): Query<SearchParams>,
+) -> Response {
+    let query = match SearchQuery::new(&params.q, params.limit, params.offset) {
+        Ok(query) => query,
+        Err(e) => return handle_search_error(e),
+    };
+
+    match state.search.search(&user.id, &query).await {
+        Ok(results) => (StatusCode::OK, Json(SearchResponse::from(results))).into_response(),
+        Err(e) => handle_search_error(e),
+    }
+}
+
+/// GET /api/search/similar?cycle_id=|q= - The caller's past decisions
+/// resembling a cycle or a piece of text
+pub async fn similar_decisions(
+    State(state): State<SearchAppState>,
+    RequireAuth(user): RequireAuth,
+    Query(params): Query<SimilarDecisionsParams>,
+) -> Response {
+    let similar_to = match (params.cycle_id, params.q) {
+        (Some(cycle_id), None) => match cycle_id.parse::<CycleId>() {
+            Ok(cycle_id) => SimilarTo::Cycle(cycle_id),
+            Err(_) => {
+                return (
+                    StatusCode::BAD_REQUEST,
+                    Json(ErrorResponse::bad_request("Invalid cycle ID")),
+                )
+                    .into_response()
+            }
+        },
+        (None, Some(text)) => SimilarTo::Text(text),
+        _ => {
+            return (
+                StatusCode::BAD_REQUEST,
+                Json(ErrorResponse::bad_request("Give exactly one of cycle_id or q")),
+            )
+                .into_response()
+        }
+    };
+
+    let query = FindSimilarDecisionsQuery {
+        user_id: user.id,
+        similar_to,
+        limit: params.limit,
+    };
+    match state.similar.handle(query).await {
+        Ok(decisions) => (
+            StatusCode::OK,
+            Json(SimilarDecisionsResponse {
+                decisions: decisions.into_iter().map(Into::into).collect(),
+            }),
+        )
+            .into_response(),
+        Err(e) => handle_similar_error(e),
+    }
+}
+
+// ════════════════════════════════════════════════════════════════════════════
+// Error handling
+// ════════════════════════════════════════════════════════════════════════════
+
+fn handle_search_error(error: SearchError) -> Response {
+    match error {
+        SearchError::InvalidQuery(msg) => (
+            StatusCode::BAD_REQUEST,
+            Json(ErrorResponse::bad_request(msg)),
+        )
+            .into_response(),
+        SearchError::Storage(msg) => {
+            tracing::error!("Search storage error: {}", msg);
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ErrorResponse::internal("Failed to search")),
+            )
+                .into_response()
+        }
+    }
+}
+
+fn handle_similar_error(error: FindSimilarDecisionsError) -> Response {
+    match error {
+        FindSimilarDecisionsError::CycleNotFound(id) => (
+            StatusCode::NOT_FOUND,
+            Json(ErrorResponse::not_found(format!("Cycle not found: {}", id))),
+        )
+            .into_response(),
+        FindSimilarDecisionsError::Forbidden => {
+            (StatusCode::FORBIDDEN, Json(ErrorResponse::forbidden())).into_response()
+        }
+        FindSimilarDecisionsError::InvalidQuery(msg) => (
+            StatusCode::BAD_REQUEST,
+            Json(ErrorResponse::bad_request(msg)),
+        )
+            .into_response(),
+        FindSimilarDecisionsError::Embedding(e) => {
+            tracing::warn!("Embedding provider error: {}", e);
+            (
+                StatusCode::SERVICE_UNAVAILABLE,
+                Json(ErrorResponse::service_unavailable(
+                    "Similar decisions are temporarily unavailable",
+                )),
+            )
+                .into_response()
+        }
+        FindSimilarDecisionsError::Domain(e) => {
+            tracing::error!("Similar decisions error: {}", e);
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ErrorResponse::internal("Failed to find similar decisions")),
+            )
+                .into_response()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::adapters::ai::HashingEmbeddingProvider;
+    use crate::adapters::search::InMemoryDecisionEmbeddingRepository;
+    use crate::application::handlers::search::fixtures::{
+        add_decision, owner, stranger, MockConversationReader, MockCycleRepository,
+        MockSessionRepository,
+    };
+    use crate::application::handlers::search::DecisionEmbeddingIndexer;
+    use crate::application::handlers::cycle::ComponentOutputUpdatedEvent;
+    use crate::domain::foundation::{
+        AuthenticatedUser, ComponentType, EventId, SerializableDomainEvent, SessionId, Timestamp,
+        UserId,
+    };
+    use crate::ports::{EventHandler, SearchHit, SearchHitKind, SearchResults};
+    use async_trait::async_trait;
+    use axum::body::to_bytes;
+    use std::sync::Mutex;
+
+    /// Returns one message hit and records what it was asked.
+    #[derive(Default)]
+    struct RecordingSearch {
+        calls: Mutex<Vec<(UserId, SearchQuery)>>,
+    }
+
+    #[async_trait]
+    impl SearchService for RecordingSearch {
+        async fn search(
+            &self,
+            user_id: &UserId,
+            query: &SearchQuery,
+        ) -> Result<SearchResults, SearchError> {
+            self.calls
+                .lock()
+                .unwrap()
+                .push((user_id.clone(), query.clone()));
+            Ok(SearchResults {
+                hits: vec![SearchHit {
+                    kind: SearchHitKind::Message,
+                    session_id: SessionId::new(),
+                    session_title: "Which city?".to_string(),
+                    cycle_id: Some(CycleId::new()),
+                    component_type: Some(ComponentType::Objectives),
+                    snippet: "Lower **rent** matters most".to_string(),
+                    rank: 0.5,
+                    updated_at: Timestamp::now(),
+                }],
+                has_more: true,
+            })
+        }
+    }
+
+    fn user() -> RequireAuth {
+        auth(UserId::new("user-1").unwrap())
+    }
+
+    fn auth(user_id: UserId) -> RequireAuth {
+        RequireAuth(AuthenticatedUser::new(
+            user_id,
+            "user@example.com",
+            None,
+            true,
+        ))
+    }
+
+    /// A similar decisions handler over the given repositories, with nothing indexed.
+    fn similar_handler(
+        cycles: Arc<MockCycleRepository>,
+        sessions: Arc<MockSessionRepository>,
+        embeddings: Arc<InMemoryDecisionEmbeddingRepository>,
+    ) -> Arc<FindSimilarDecisionsHandler> {
+        Arc::new(FindSimilarDecisionsHandler::new(
+            cycles,
+            sessions,
+            Arc::new(HashingEmbeddingProvider::new()),
+            embeddings,
+        ))
+    }
+
+    fn state(search: Arc<RecordingSearch>) -> SearchAppState {
+        SearchAppState {
+            search,
+            similar: similar_handler(
+                Arc::default(),
+                Arc::default(),
+                Arc::new(InMemoryDecisionEmbeddingRepository::new()),
+            ),
+        }
+    }
+
+    fn similar_params(cycle_id: Option<String>, q: Option<&str>) -> Query<SimilarDecisionsParams> {
+        Query(SimilarDecisionsParams {
+            cycle_id,
+            q: q.map(str::to_string),
+            limit: None,
+        })
+    }
+
+    fn params(q: &str) -> Query<SearchParams> {
+        Query(SearchParams {
+            q: q.to_string(),
+            limit: Some(5),
+            offset: None,
+        })
+    }
+
+    #[tokio::test]
+    async fn search_returns_ranked_hits_for_the_caller() {
+        let service = Arc::new(RecordingSearch::default());
+        let state = state(service.clone());
+
+        let response = search(State(state), user(), params(" rent ")).await;
+
+        assert_eq!(response.status(), StatusCode::OK);
+        let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        let json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(json["has_more"], true);
+        assert_eq!(json["results"][0]["kind"], "message");
+        assert_eq!(json["results"][0]["component_type"], "objectives");
+
+        let calls = service.calls.lock().unwrap();
+        assert_eq!(calls[0].0.as_str(), "user-1");
+        assert_eq!(calls[0].1.text, "rent");
+        assert_eq!(calls[0].1.limit, 5);
+    }
+
+    #[tokio::test]
+    async fn blank_query_is_rejected_without_searching() {
+        let service = Arc::new(RecordingSearch::default());
+        let state = state(service.clone());
+
+        let response = search(State(state), user(), params("  ")).await;
+
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+        assert!(service.calls.lock().unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn similar_decisions_returns_the_callers_indexed_decisions() {
+        let cycles = Arc::new(MockCycleRepository::default());
+        let sessions = Arc::new(MockSessionRepository::default());
+        let embeddings = Arc::new(InMemoryDecisionEmbeddingRepository::new());
+        let lisbon = add_decision(&cycles, &sessions, owner(), "Lisbon", &["Move to Lisbon"]).await;
+        let porto = add_decision(&cycles, &sessions, owner(), "Porto", &["Move to Porto"]).await;
+        let indexer = DecisionEmbeddingIndexer::new(
+            cycles.clone(),
+            sessions.clone(),
+            Arc::new(MockConversationReader::with_user_messages(&[])),
+            Arc::new(HashingEmbeddingProvider::new()),
+            embeddings.clone(),
+        );
+        for cycle_id in [lisbon, porto] {
+            let event = ComponentOutputUpdatedEvent {
+                event_id: EventId::new(),
+                cycle_id,
+                component_type: ComponentType::IssueRaising,
+                updated_at: Timestamp::now(),
+            };
+            indexer.handle(event.to_envelope()).await.unwrap();
+        }
+        let state = SearchAppState {
+            search: Arc::new(RecordingSearch::default()),
+            similar: similar_handler(cycles, sessions, embeddings),
+        };
+
+        let response = similar_decisions(
+            State(state.clone()),
+            auth(owner()),
+            similar_params(Some(lisbon.to_string()), None),
+        )
+        .await;
+
+        assert_eq!(response.status(), StatusCode::OK);
+        let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        let json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(json["decisions"][0]["cycle_id"], porto.to_string());
+        assert_eq!(json["decisions"][0]["session_title"], "Porto");
+        assert_eq!(json["decisions"][0]["source"], "component_output");
+
+        let response = similar_decisions(
+            State(state),
+            auth(stranger()),
+            similar_params(Some(lisbon.to_string()), None),
+        )
+        .await;
+        assert_eq!(response.status(), StatusCode::FORBIDDEN);
+    }
+
+    #[tokio::test]
+    async fn similar_decisions_needs_exactly_one_of_cycle_or_text() {
+        let state = state(Arc::new(RecordingSearch::default()));
+
+        let neither = similar_decisions(State(state.clone()), user(), similar_params(None, None)).await;
+        let both = similar_decisions(
+            State(state),
+            user(),
+            similar_params(Some(CycleId::new().to_string()), Some("move")),
+        )
+        .await;
+
+        assert_eq!(neither.status(), StatusCode::BAD_REQUEST);
+        assert_eq!(both.status(), StatusCode::BAD_REQUEST);
+    }
+
+    #[test]
+    fn storage_error_maps_to_500() {
+        let response = handle_search_error(SearchError::Storage("down".to_string()));
+        assert_eq!(response.status(), StatusCode::INTERNAL_SERVER_ERROR);
+    }
+}
@@ -0,0 +1,16 @@
+//! Search HTTP adapter module.
+//!
+//! Ranked full-text search over the sessions, component outputs and
+//! conversation messages the caller can access, and semantic search for
+//! the caller's past decisions resembling a cycle or a piece of text.
+
+pub mod dto;
+pub mod handlers;
+pub mod routes;
+
+pub use dto::{
+    ErrorResponse, SearchHitResponse, SearchResponse, SimilarDecisionResponse,
+    SimilarDecisionsResponse,
+};
+pub use handlers::SearchAppState;
+pub use routes::search_routes;
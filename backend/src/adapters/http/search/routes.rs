@@ -0,0 +1,17 @@
+//! HTTP routes for search.
+
+use axum::{routing::get, Router};
+
+use super::handlers::{search, similar_decisions, SearchAppState};
+
+/// Creates the search router.
+///
+/// # Routes
+/// - `GET /api/search?q=` - Ranked results, optionally `&limit=&offset=`
+/// - `GET /api/search/similar?cycle_id=|q=` - Similar past decisions, optionally `&limit=`
+pub fn search_routes(state: SearchAppState) -> Router {
+    Router::new()
+        .route("/api/search", get(search))
+        .route("/api/search/similar", get(similar_decisions))
+        .with_state(state)
+}
@@ -3,16 +3,18 @@
 //! These types decouple the HTTP API from domain types, allowing independent evolution.
 
 use serde::{Deserialize, Serialize};
+use utoipa::{IntoParams, ToSchema};
 
 use crate::domain::foundation::{SessionStatus, Timestamp};
-use crate::ports::{SessionList as DomainSessionList, SessionSummary as DomainSessionSummary, SessionView as DomainSessionView};
+use crate::domain::session::SessionArchivalSettings;
+use crate::ports::{SessionList as DomainSessionList, SessionSummary as DomainSessionSummary, SessionView as DomainSessionView, TrashedSession};
 
 // ════════════════════════════════════════════════════════════════════════════
 // Request DTOs
 // ════════════════════════════════════════════════════════════════════════════
 
 /// Request to create a new session.
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, ToSchema)]
 pub struct CreateSessionRequest {
     pub title: String,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -20,19 +22,25 @@ pub struct CreateSessionRequest {
 }
 
 /// Request to rename a session.
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, ToSchema)]
 pub struct RenameSessionRequest {
     pub title: String,
 }
 
 /// Request to update session description.
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, ToSchema)]
 pub struct UpdateDescriptionRequest {
     pub description: Option<String>,
 }
 
+/// Request to change a session's auto-archive exclusion.
+#[derive(Debug, Clone, Deserialize, ToSchema)]
+pub struct UpdateAutoArchiveRequest {
+    pub excluded: bool,
+}
+
 /// Query parameters for listing sessions.
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, IntoParams)]
 pub struct ListSessionsQuery {
     #[serde(default)]
     pub page: Option<u32>,
@@ -49,17 +57,19 @@ pub struct ListSessionsQuery {
 // ════════════════════════════════════════════════════════════════════════════
 
 /// Response for session command operations.
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Serialize, ToSchema)]
 pub struct SessionCommandResponse {
     pub session_id: String,
     pub message: String,
 }
 
 /// Detailed session view for API responses.
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Serialize, ToSchema)]
 pub struct SessionResponse {
     pub id: String,
     pub user_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub organization_id: Option<String>,
     pub title: String,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub description: Option<String>,
@@ -74,6 +84,7 @@ impl From<DomainSessionView> for SessionResponse {
         Self {
             id: view.id.to_string(),
             user_id: view.user_id.to_string(),
+            organization_id: view.organization_id.map(|id| id.to_string()),
             title: view.title,
             description: view.description,
             status: view.status,
@@ -85,13 +96,14 @@ impl From<DomainSessionView> for SessionResponse {
 }
 
 /// Session summary for list responses.
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Serialize, ToSchema)]
 pub struct SessionSummaryResponse {
     pub id: String,
     pub title: String,
     pub status: SessionStatus,
     pub cycle_count: u32,
     pub updated_at: String,
+    pub in_cold_storage: bool,
 }
 
 impl From<DomainSessionSummary> for SessionSummaryResponse {
@@ -102,12 +114,13 @@ impl From<DomainSessionSummary> for SessionSummaryResponse {
             status: summary.status,
             cycle_count: summary.cycle_count,
             updated_at: summary.updated_at.as_datetime().to_rfc3339(),
+            in_cold_storage: summary.in_cold_storage,
         }
     }
 }
 
 /// Paginated list of sessions.
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Serialize, ToSchema)]
 pub struct SessionListResponse {
     pub items: Vec<SessionSummaryResponse>,
     pub total: u64,
@@ -124,8 +137,73 @@ impl From<DomainSessionList> for SessionListResponse {
     }
 }
 
+/// A session in the trash.
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct TrashedSessionResponse {
+    pub id: String,
+    pub title: String,
+    /// Status the session returns to when restored.
+    pub status_before_deletion: SessionStatus,
+    pub cycle_count: u32,
+    pub deleted_at: String,
+    /// When the session is permanently deleted unless restored.
+    pub purge_after: String,
+}
+
+impl From<TrashedSession> for TrashedSessionResponse {
+    fn from(session: TrashedSession) -> Self {
+        Self {
+            id: session.id.to_string(),
+            title: session.title,
+            status_before_deletion: session.status_before_deletion,
+            cycle_count: session.cycle_count,
+            deleted_at: session.deleted_at.as_datetime().to_rfc3339(),
+            purge_after: session.purge_after.as_datetime().to_rfc3339(),
+        }
+    }
+}
+
+/// The user's trash, most recently deleted first.
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct TrashListResponse {
+    pub items: Vec<TrashedSessionResponse>,
+}
+
+impl From<Vec<TrashedSession>> for TrashListResponse {
+    fn from(sessions: Vec<TrashedSession>) -> Self {
+        Self {
+            items: sessions.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+/// A session's auto-archive settings.
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct AutoArchiveSettingsResponse {
+    pub session_id: String,
+    pub excluded: bool,
+    /// Latest archive warning, if any.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub warned_at: Option<String>,
+    /// When the policy archived the session, if it did.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub archived_at: Option<String>,
+}
+
+impl From<SessionArchivalSettings> for AutoArchiveSettingsResponse {
+    fn from(settings: SessionArchivalSettings) -> Self {
+        Self {
+            session_id: settings.session_id.to_string(),
+            excluded: settings.excluded,
+            warned_at: settings.warned_at.map(|t| t.as_datetime().to_rfc3339()),
+            archived_at: settings.archived_at.map(|t| t.as_datetime().to_rfc3339()),
+        }
+    }
+}
+
 /// Standard error response.
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Serialize, ToSchema)]
+#[schema(as = SessionErrorResponse)]
 pub struct ErrorResponse {
     pub code: String,
     pub message: String,
@@ -193,6 +271,7 @@ mod tests {
         let view = DomainSessionView {
             id: SessionId::new(),
             user_id: UserId::new("user-123").unwrap(),
+            organization_id: None,
             title: "Test Session".to_string(),
             description: Some("Test description".to_string()),
             status: SessionStatus::Active,
@@ -207,6 +286,40 @@ mod tests {
         assert_eq!(response.cycle_count, 2);
     }
 
+    #[test]
+    fn auto_archive_settings_omit_unset_timestamps() {
+        let mut settings =
+            SessionArchivalSettings::new(SessionId::new(), UserId::new("user-123").unwrap());
+        settings.excluded = true;
+        settings.notify_email = Some("user@example.com".to_string());
+
+        let json = serde_json::to_value(AutoArchiveSettingsResponse::from(settings)).unwrap();
+
+        assert_eq!(json["excluded"], true);
+        assert!(json.get("warned_at").is_none());
+        assert!(json.get("notify_email").is_none());
+    }
+
+    #[test]
+    fn trashed_session_response_conversion() {
+        let deleted_at = Timestamp::now();
+        let session = TrashedSession {
+            id: SessionId::new(),
+            user_id: UserId::new("user-123").unwrap(),
+            title: "Old decision".to_string(),
+            status_before_deletion: SessionStatus::Archived,
+            cycle_count: 1,
+            deleted_at,
+            purge_after: deleted_at.add_days(30),
+        };
+
+        let json = serde_json::to_value(TrashListResponse::from(vec![session])).unwrap();
+
+        assert_eq!(json["items"][0]["title"], "Old decision");
+        assert_eq!(json["items"][0]["status_before_deletion"], "archived");
+        assert!(json["items"][0].get("user_id").is_none());
+    }
+
     #[test]
     fn error_response_bad_request_creates_correctly() {
         let error = ErrorResponse::bad_request("Invalid input");
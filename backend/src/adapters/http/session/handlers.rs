@@ -12,15 +12,19 @@ use axum::{
 use crate::adapters::http::middleware::RequireAuth;
 use crate::application::handlers::session::{
     ArchiveSessionCommand, ArchiveSessionHandler, CreateSessionCommand, CreateSessionHandler,
-    GetSessionHandler, GetSessionQuery, ListUserSessionsHandler, ListUserSessionsQuery,
-    RenameSessionCommand, RenameSessionHandler,
+    DeleteSessionCommand, DeleteSessionHandler, GetAutoArchiveSettingsHandler,
+    GetAutoArchiveSettingsQuery, GetSessionHandler, GetSessionQuery, ListTrashedSessionsHandler,
+    ListTrashedSessionsQuery, ListUserSessionsHandler, ListUserSessionsQuery, PurgeSessionCommand,
+    PurgeSessionHandler, RenameSessionCommand, RenameSessionHandler, RestoreSessionCommand,
+    RestoreSessionHandler, SetAutoArchiveExclusionCommand, SetAutoArchiveExclusionHandler,
 };
 use crate::domain::foundation::{CommandMetadata, SessionId};
 use crate::domain::session::SessionError;
 
 use super::dto::{
-    CreateSessionRequest, ErrorResponse, ListSessionsQuery, RenameSessionRequest,
-    SessionCommandResponse, SessionListResponse, SessionResponse,
+    AutoArchiveSettingsResponse, CreateSessionRequest, ErrorResponse, ListSessionsQuery,
+    RenameSessionRequest, SessionCommandResponse, SessionListResponse, SessionResponse,
+    TrashListResponse, UpdateAutoArchiveRequest,
 };
 
 // ════════════════════════════════════════════════════════════════════════════
@@ -34,15 +38,28 @@ pub struct SessionHandlers {
     archive_handler: Arc<ArchiveSessionHandler>,
     get_handler: Arc<GetSessionHandler>,
     list_handler: Arc<ListUserSessionsHandler>,
+    get_auto_archive_handler: Arc<GetAutoArchiveSettingsHandler>,
+    set_auto_archive_handler: Arc<SetAutoArchiveExclusionHandler>,
+    delete_handler: Arc<DeleteSessionHandler>,
+    restore_handler: Arc<RestoreSessionHandler>,
+    purge_handler: Arc<PurgeSessionHandler>,
+    list_trash_handler: Arc<ListTrashedSessionsHandler>,
 }
 
 impl SessionHandlers {
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         create_handler: Arc<CreateSessionHandler>,
         rename_handler: Arc<RenameSessionHandler>,
         archive_handler: Arc<ArchiveSessionHandler>,
         get_handler: Arc<GetSessionHandler>,
         list_handler: Arc<ListUserSessionsHandler>,
+        get_auto_archive_handler: Arc<GetAutoArchiveSettingsHandler>,
+        set_auto_archive_handler: Arc<SetAutoArchiveExclusionHandler>,
+        delete_handler: Arc<DeleteSessionHandler>,
+        restore_handler: Arc<RestoreSessionHandler>,
+        purge_handler: Arc<PurgeSessionHandler>,
+        list_trash_handler: Arc<ListTrashedSessionsHandler>,
     ) -> Self {
         Self {
             create_handler,
@@ -50,6 +67,12 @@ impl SessionHandlers {
             archive_handler,
             get_handler,
             list_handler,
+            get_auto_archive_handler,
+            set_auto_archive_handler,
+            delete_handler,
+            restore_handler,
+            purge_handler,
+            list_trash_handler,
         }
     }
 }
@@ -59,6 +82,17 @@ impl SessionHandlers {
 // ════════════════════════════════════════════════════════════════════════════
 
 /// POST /api/sessions - Create a new session
+#[utoipa::path(
+    post,
+    path = "/api/sessions",
+    tag = "sessions",
+    request_body = CreateSessionRequest,
+    responses(
+        (status = 201, description = "Session created", body = SessionCommandResponse),
+        (status = 400, description = "Invalid title", body = ErrorResponse),
+        (status = 403, description = "Session limit reached", body = ErrorResponse),
+    )
+)]
 pub async fn create_session(
     State(handlers): State<SessionHandlers>,
     RequireAuth(user): RequireAuth,
@@ -85,6 +119,18 @@ pub async fn create_session(
 }
 
 /// GET /api/sessions/:id - Get session details
+#[utoipa::path(
+    get,
+    path = "/api/sessions/{id}",
+    tag = "sessions",
+    params(("id" = String, Path, description = "Session ID")),
+    responses(
+        (status = 200, description = "Session details", body = SessionResponse),
+        (status = 400, description = "Invalid session ID", body = ErrorResponse),
+        (status = 403, description = "Not the session owner", body = ErrorResponse),
+        (status = 404, description = "Session not found", body = ErrorResponse),
+    )
+)]
 pub async fn get_session(
     State(handlers): State<SessionHandlers>,
     RequireAuth(user): RequireAuth,
@@ -116,6 +162,15 @@ pub async fn get_session(
 }
 
 /// GET /api/sessions - List user's sessions
+#[utoipa::path(
+    get,
+    path = "/api/sessions",
+    tag = "sessions",
+    params(ListSessionsQuery),
+    responses(
+        (status = 200, description = "Page of the user's sessions", body = SessionListResponse),
+    )
+)]
 pub async fn list_sessions(
     State(handlers): State<SessionHandlers>,
     RequireAuth(user): RequireAuth,
@@ -139,6 +194,19 @@ pub async fn list_sessions(
 }
 
 /// PATCH /api/sessions/:id/rename - Rename a session
+#[utoipa::path(
+    patch,
+    path = "/api/sessions/{id}/rename",
+    tag = "sessions",
+    params(("id" = String, Path, description = "Session ID")),
+    request_body = RenameSessionRequest,
+    responses(
+        (status = 200, description = "Session renamed", body = SessionCommandResponse),
+        (status = 400, description = "Invalid session ID", body = ErrorResponse),
+        (status = 403, description = "Not the session owner", body = ErrorResponse),
+        (status = 404, description = "Session not found", body = ErrorResponse),
+    )
+)]
 pub async fn rename_session(
     State(handlers): State<SessionHandlers>,
     RequireAuth(user): RequireAuth,
@@ -177,6 +245,18 @@ pub async fn rename_session(
 }
 
 /// POST /api/sessions/:id/archive - Archive a session
+#[utoipa::path(
+    post,
+    path = "/api/sessions/{id}/archive",
+    tag = "sessions",
+    params(("id" = String, Path, description = "Session ID")),
+    responses(
+        (status = 200, description = "Session archived", body = SessionCommandResponse),
+        (status = 400, description = "Invalid session ID", body = ErrorResponse),
+        (status = 403, description = "Not the session owner", body = ErrorResponse),
+        (status = 404, description = "Session not found", body = ErrorResponse),
+    )
+)]
 pub async fn archive_session(
     State(handlers): State<SessionHandlers>,
     RequireAuth(user): RequireAuth,
@@ -212,6 +292,266 @@ pub async fn archive_session(
     }
 }
 
+/// GET /api/sessions/:id/auto-archive - Get the session's auto-archive settings
+#[utoipa::path(
+    get,
+    path = "/api/sessions/{id}/auto-archive",
+    tag = "sessions",
+    params(("id" = String, Path, description = "Session ID")),
+    responses(
+        (status = 200, description = "Auto-archive settings", body = AutoArchiveSettingsResponse),
+        (status = 400, description = "Invalid session ID", body = ErrorResponse),
+        (status = 403, description = "Not the session owner", body = ErrorResponse),
+        (status = 404, description = "Session not found", body = ErrorResponse),
+    )
+)]
+pub async fn get_auto_archive(
+    State(handlers): State<SessionHandlers>,
+    RequireAuth(user): RequireAuth,
+    Path(session_id): Path<String>,
+) -> Response {
+    let session_id = match session_id.parse::<SessionId>() {
+        Ok(id) => id,
+        Err(_) => {
+            return (
+                StatusCode::BAD_REQUEST,
+                Json(ErrorResponse::bad_request("Invalid session ID")),
+            )
+                .into_response()
+        }
+    };
+
+    let query = GetAutoArchiveSettingsQuery {
+        session_id,
+        user_id: user.id,
+    };
+
+    match handlers.get_auto_archive_handler.handle(query).await {
+        Ok(settings) => {
+            let response: AutoArchiveSettingsResponse = settings.into();
+            (StatusCode::OK, Json(response)).into_response()
+        }
+        Err(e) => handle_session_error(e),
+    }
+}
+
+/// PUT /api/sessions/:id/auto-archive - Exclude a session from auto-archive, or include it
+#[utoipa::path(
+    put,
+    path = "/api/sessions/{id}/auto-archive",
+    tag = "sessions",
+    params(("id" = String, Path, description = "Session ID")),
+    request_body = UpdateAutoArchiveRequest,
+    responses(
+        (status = 200, description = "Updated auto-archive settings", body = AutoArchiveSettingsResponse),
+        (status = 400, description = "Invalid session ID", body = ErrorResponse),
+        (status = 403, description = "Not the session owner", body = ErrorResponse),
+        (status = 404, description = "Session not found", body = ErrorResponse),
+    )
+)]
+pub async fn update_auto_archive(
+    State(handlers): State<SessionHandlers>,
+    RequireAuth(user): RequireAuth,
+    Path(session_id): Path<String>,
+    Json(req): Json<UpdateAutoArchiveRequest>,
+) -> Response {
+    let session_id = match session_id.parse::<SessionId>() {
+        Ok(id) => id,
+        Err(_) => {
+            return (
+                StatusCode::BAD_REQUEST,
+                Json(ErrorResponse::bad_request("Invalid session ID")),
+            )
+                .into_response()
+        }
+    };
+
+    let cmd = SetAutoArchiveExclusionCommand {
+        session_id,
+        user_id: user.id.clone(),
+        excluded: req.excluded,
+        notify_email: user.email_verified.then(|| user.email.clone()),
+    };
+
+    let metadata = CommandMetadata::new(user.id).with_correlation_id("http-request");
+
+    match handlers.set_auto_archive_handler.handle(cmd, metadata).await {
+        Ok(settings) => {
+            let response: AutoArchiveSettingsResponse = settings.into();
+            (StatusCode::OK, Json(response)).into_response()
+        }
+        Err(e) => handle_session_error(e),
+    }
+}
+
+/// DELETE /api/sessions/:id - Move a session to the trash
+#[utoipa::path(
+    delete,
+    path = "/api/sessions/{id}",
+    tag = "sessions",
+    params(("id" = String, Path, description = "Session ID")),
+    responses(
+        (status = 200, description = "Session moved to the trash", body = SessionCommandResponse),
+        (status = 400, description = "Invalid session ID", body = ErrorResponse),
+        (status = 403, description = "Not the session owner", body = ErrorResponse),
+        (status = 404, description = "Session not found", body = ErrorResponse),
+    )
+)]
+pub async fn delete_session(
+    State(handlers): State<SessionHandlers>,
+    RequireAuth(user): RequireAuth,
+    Path(session_id): Path<String>,
+) -> Response {
+    let session_id = match session_id.parse::<SessionId>() {
+        Ok(id) => id,
+        Err(_) => {
+            return (
+                StatusCode::BAD_REQUEST,
+                Json(ErrorResponse::bad_request("Invalid session ID")),
+            )
+                .into_response()
+        }
+    };
+
+    let cmd = DeleteSessionCommand {
+        session_id,
+        user_id: user.id.clone(),
+    };
+
+    let metadata = CommandMetadata::new(user.id).with_correlation_id("http-request");
+
+    match handlers.delete_handler.handle(cmd, metadata).await {
+        Ok(_) => {
+            let response = SessionCommandResponse {
+                session_id: session_id.to_string(),
+                message: "Session moved to trash".to_string(),
+            };
+            (StatusCode::OK, Json(response)).into_response()
+        }
+        Err(e) => handle_session_error(e),
+    }
+}
+
+/// POST /api/sessions/:id/restore - Restore a session from the trash
+#[utoipa::path(
+    post,
+    path = "/api/sessions/{id}/restore",
+    tag = "sessions",
+    params(("id" = String, Path, description = "Session ID")),
+    responses(
+        (status = 200, description = "Session restored from the trash", body = SessionCommandResponse),
+        (status = 400, description = "Invalid session ID", body = ErrorResponse),
+        (status = 403, description = "Not the session owner", body = ErrorResponse),
+        (status = 404, description = "Session not found", body = ErrorResponse),
+    )
+)]
+pub async fn restore_session(
+    State(handlers): State<SessionHandlers>,
+    RequireAuth(user): RequireAuth,
+    Path(session_id): Path<String>,
+) -> Response {
+    let session_id = match session_id.parse::<SessionId>() {
+        Ok(id) => id,
+        Err(_) => {
+            return (
+                StatusCode::BAD_REQUEST,
+                Json(ErrorResponse::bad_request("Invalid session ID")),
+            )
+                .into_response()
+        }
+    };
+
+    let cmd = RestoreSessionCommand {
+        session_id,
+        user_id: user.id.clone(),
+    };
+
+    let metadata = CommandMetadata::new(user.id).with_correlation_id("http-request");
+
+    match handlers.restore_handler.handle(cmd, metadata).await {
+        Ok(_) => {
+            let response = SessionCommandResponse {
+                session_id: session_id.to_string(),
+                message: "Session restored successfully".to_string(),
+            };
+            (StatusCode::OK, Json(response)).into_response()
+        }
+        Err(e) => handle_session_error(e),
+    }
+}
+
+/// GET /api/sessions/trash - List the user's trashed sessions
+#[utoipa::path(
+    get,
+    path = "/api/sessions/trash",
+    tag = "sessions",
+    responses(
+        (status = 200, description = "Trashed sessions, most recently deleted first", body = TrashListResponse),
+    )
+)]
+pub async fn list_trash(
+    State(handlers): State<SessionHandlers>,
+    RequireAuth(user): RequireAuth,
+) -> Response {
+    let query = ListTrashedSessionsQuery { user_id: user.id };
+
+    match handlers.list_trash_handler.handle(query).await {
+        Ok(items) => {
+            let response: TrashListResponse = items.into();
+            (StatusCode::OK, Json(response)).into_response()
+        }
+        Err(e) => handle_session_error(e),
+    }
+}
+
+/// DELETE /api/sessions/trash/:id - Permanently delete a trashed session
+#[utoipa::path(
+    delete,
+    path = "/api/sessions/trash/{id}",
+    tag = "sessions",
+    params(("id" = String, Path, description = "Session ID")),
+    responses(
+        (status = 200, description = "Session permanently deleted", body = SessionCommandResponse),
+        (status = 400, description = "Invalid session ID", body = ErrorResponse),
+        (status = 403, description = "Not the session owner", body = ErrorResponse),
+        (status = 404, description = "Session not found", body = ErrorResponse),
+    )
+)]
+pub async fn purge_session(
+    State(handlers): State<SessionHandlers>,
+    RequireAuth(user): RequireAuth,
+    Path(session_id): Path<String>,
+) -> Response {
+    let session_id = match session_id.parse::<SessionId>() {
+        Ok(id) => id,
+        Err(_) => {
+            return (
+                StatusCode::BAD_REQUEST,
+                Json(ErrorResponse::bad_request("Invalid session ID")),
+            )
+                .into_response()
+        }
+    };
+
+    let cmd = PurgeSessionCommand {
+        session_id,
+        user_id: user.id.clone(),
+    };
+
+    let metadata = CommandMetadata::new(user.id).with_correlation_id("http-request");
+
+    match handlers.purge_handler.handle(cmd, metadata).await {
+        Ok(_) => {
+            let response = SessionCommandResponse {
+                session_id: session_id.to_string(),
+                message: "Session permanently deleted".to_string(),
+            };
+            (StatusCode::OK, Json(response)).into_response()
+        }
+        Err(e) => handle_session_error(e),
+    }
+}
+
 // ════════════════════════════════════════════════════════════════════════════
 // Error handling
 // ════════════════════════════════════════════════════════════════════════════
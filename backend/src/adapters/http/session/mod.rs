@@ -1,12 +1,13 @@
 //! HTTP adapter for session endpoints.
 
 mod dto;
-mod handlers;
+pub(crate) mod handlers;
 mod routes;
 
 pub use dto::{
-    CreateSessionRequest, ErrorResponse, ListSessionsQuery, RenameSessionRequest,
-    SessionCommandResponse, SessionListResponse, SessionResponse, SessionSummaryResponse,
+    AutoArchiveSettingsResponse, CreateSessionRequest, ErrorResponse, ListSessionsQuery,
+    RenameSessionRequest, SessionCommandResponse, SessionListResponse, SessionResponse,
+    SessionSummaryResponse, TrashListResponse, TrashedSessionResponse, UpdateAutoArchiveRequest,
 };
 pub use handlers::SessionHandlers;
 pub use routes::session_routes;
@@ -1,12 +1,14 @@
 //! HTTP routes for session endpoints.
 
 use axum::{
-    routing::{get, patch, post},
+    routing::{delete, get, patch, post, put},
     Router,
 };
 
 use super::handlers::{
-    archive_session, create_session, get_session, list_sessions, rename_session, SessionHandlers,
+    archive_session, create_session, delete_session, get_auto_archive, get_session, list_sessions,
+    list_trash, purge_session, rename_session, restore_session, update_auto_archive,
+    SessionHandlers,
 };
 
 /// Creates the session router with all endpoints.
@@ -14,9 +16,15 @@ pub fn session_routes(handlers: SessionHandlers) -> Router {
     Router::new()
         .route("/", post(create_session))
         .route("/", get(list_sessions))
+        .route("/trash", get(list_trash))
+        .route("/trash/:id", delete(purge_session))
         .route("/:id", get(get_session))
+        .route("/:id", delete(delete_session))
         .route("/:id/rename", patch(rename_session))
         .route("/:id/archive", post(archive_session))
+        .route("/:id/restore", post(restore_session))
+        .route("/:id/auto-archive", get(get_auto_archive))
+        .route("/:id/auto-archive", put(update_auto_archive))
         .with_state(handlers)
 }
 
@@ -0,0 +1,145 @@
+//! HTTP DTOs for shadow traffic evaluation.
+
+use serde::{Deserialize, Serialize};
+
+use crate::domain::foundation::ComponentType;
+use crate::ports::{ShadowComparison, ShadowMetrics, ShadowOutput};
+
+/// Default and maximum number of comparisons returned.
+pub const DEFAULT_COMPARISON_LIMIT: u32 = 20;
+pub const MAX_COMPARISON_LIMIT: u32 = 100;
+
+// ════════════════════════════════════════════════════════════════════════════
+// Request DTOs
+// ════════════════════════════════════════════════════════════════════════════
+
+/// Query parameters for listing comparisons.
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct ComparisonsQuery {
+    pub limit: Option<u32>,
+}
+
+impl ComparisonsQuery {
+    pub fn limit(&self) -> u32 {
+        self.limit
+            .unwrap_or(DEFAULT_COMPARISON_LIMIT)
+            .clamp(1, MAX_COMPARISON_LIMIT)
+    }
+}
+
+// ════════════════════════════════════════════════════════════════════════════
+// Response DTOs
+// ════════════════════════════════════════════════════════════════════════════
+
+/// Metrics for every variant with recorded comparisons.
+#[derive(Debug, Clone, Serialize)]
+pub struct ShadowMetricsListResponse {
+    pub variants: Vec<ShadowMetrics>,
+}
+
+/// One production completion and the candidate's answer.
+#[derive(Debug, Clone, Serialize)]
+pub struct ShadowComparisonResponse {
+    pub id: String,
+    pub component_type: Option<ComponentType>,
+    pub trace_id: String,
+    pub primary: ShadowOutput,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub candidate: Option<ShadowOutput>,
+    /// Why the candidate call failed.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub candidate_error: Option<String>,
+    pub recorded_at: String,
+}
+
+impl From<ShadowComparison> for ShadowComparisonResponse {
+    fn from(comparison: ShadowComparison) -> Self {
+        let (candidate, candidate_error) = match comparison.candidate {
+            Ok(output) => (Some(output), None),
+            Err(error) => (None, Some(error)),
+        };
+        Self {
+            id: comparison.id.to_string(),
+            component_type: comparison.component_type,
+            trace_id: comparison.trace_id,
+            primary: comparison.primary,
+            candidate,
+            candidate_error,
+            recorded_at: comparison.recorded_at.as_datetime().to_rfc3339(),
+        }
+    }
+}
+
+/// Recent comparisons of one variant, newest first.
+#[derive(Debug, Clone, Serialize)]
+pub struct ShadowComparisonListResponse {
+    pub variant: String,
+    pub comparisons: Vec<ShadowComparisonResponse>,
+}
+
+/// Standard error response.
+#[derive(Debug, Clone, Serialize)]
+pub struct ErrorResponse {
+    pub code: String,
+    pub message: String,
+}
+
+impl ErrorResponse {
+    pub fn forbidden(message: impl Into<String>) -> Self {
+        Self {
+            code: "FORBIDDEN".to_string(),
+            message: message.into(),
+        }
+    }
+
+    pub fn internal(message: impl Into<String>) -> Self {
+        Self {
+            code: "INTERNAL_ERROR".to_string(),
+            message: message.into(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::foundation::Timestamp;
+    use uuid::Uuid;
+
+    #[test]
+    fn failed_candidate_is_reported_as_error() {
+        let output = ShadowOutput {
+            model: "gpt-4".to_string(),
+            content: "answer".to_string(),
+            latency_ms: 120,
+            prompt_tokens: 10,
+            completion_tokens: 5,
+            cost_cents: 1,
+            extraction_succeeded: None,
+        };
+        let response = ShadowComparisonResponse::from(ShadowComparison {
+            id: Uuid::new_v4(),
+            variant: "v2".to_string(),
+            component_type: None,
+            trace_id: "trace".to_string(),
+            primary: output,
+            candidate: Err("timeout".to_string()),
+            recorded_at: Timestamp::now(),
+        });
+        let json = serde_json::to_value(&response).unwrap();
+
+        assert_eq!(json["candidate_error"], "timeout");
+        assert!(json.get("candidate").is_none());
+        assert_eq!(json["primary"]["model"], "gpt-4");
+    }
+
+    #[test]
+    fn limit_is_bounded() {
+        assert_eq!(
+            ComparisonsQuery::default().limit(),
+            DEFAULT_COMPARISON_LIMIT
+        );
+        let query = ComparisonsQuery { limit: Some(1000) };
+        assert_eq!(query.limit(), MAX_COMPARISON_LIMIT);
+    }
+}
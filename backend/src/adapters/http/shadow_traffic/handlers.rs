@@ -0,0 +1,118 @@
+//! HTTP handlers for shadow traffic evaluation.
+
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use axum::{
+    extract::{Path, Query, State},
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    Json,
+};
+
+use crate::adapters::http::middleware::RequireAuth;
+use crate::domain::foundation::{AuthenticatedUser, DomainError, UserId};
+use crate::ports::{ShadowComparisonRepository, ShadowMetrics};
+
+use super::dto::{
+    ComparisonsQuery, ErrorResponse, ShadowComparisonListResponse, ShadowComparisonResponse,
+    ShadowMetricsListResponse,
+};
+
+// ════════════════════════════════════════════════════════════════════════════
+// Application State
+// ════════════════════════════════════════════════════════════════════════════
+
+/// Shared state for the shadow traffic admin endpoints.
+#[derive(Clone)]
+pub struct ShadowTrafficAppState {
+    pub repository: Arc<dyn ShadowComparisonRepository>,
+    /// Users allowed to view shadow comparisons.
+    pub admin_user_ids: Arc<HashSet<UserId>>,
+}
+
+type Rejection = (StatusCode, Json<ErrorResponse>);
+
+impl ShadowTrafficAppState {
+    fn require_admin(&self, user: &AuthenticatedUser) -> Result<(), Rejection> {
+        if self.admin_user_ids.contains(&user.id) {
+            Ok(())
+        } else {
+            Err((
+                StatusCode::FORBIDDEN,
+                Json(ErrorResponse::forbidden("Admin access required")),
+            ))
+        }
+    }
+}
+
+fn internal(error: DomainError) -> Rejection {
+    tracing::error!(error = %error, "Failed to read shadow comparisons");
+    (
+        StatusCode::INTERNAL_SERVER_ERROR,
+        Json(ErrorResponse::internal("Failed to read shadow comparisons")),
+    )
+}
+
+// ════════════════════════════════════════════════════════════════════════════
+// HTTP handlers
+// ════════════════════════════════════════════════════════════════════════════
+
+/// GET /api/admin/shadow-traffic - Metrics for every variant
+pub async fn list_shadow_metrics(
+    State(state): State<ShadowTrafficAppState>,
+    RequireAuth(user): RequireAuth,
+) -> Response {
+    if let Err(rejection) = state.require_admin(&user) {
+        return rejection.into_response();
+    }
+
+    let variants = match state.repository.variants().await {
+        Ok(variants) => variants,
+        Err(e) => return internal(e).into_response(),
+    };
+    let mut metrics = Vec::with_capacity(variants.len());
+    for variant in variants {
+        match state.repository.all_by_variant(&variant).await {
+            Ok(comparisons) => metrics.push(ShadowMetrics::from_comparisons(variant, &comparisons)),
+            Err(e) => return internal(e).into_response(),
+        }
+    }
+
+    (
+        StatusCode::OK,
+        Json(ShadowMetricsListResponse { variants: metrics }),
+    )
+        .into_response()
+}
+
+/// GET /api/admin/shadow-traffic/:variant/comparisons - Recent comparisons
+pub async fn list_shadow_comparisons(
+    State(state): State<ShadowTrafficAppState>,
+    RequireAuth(user): RequireAuth,
+    Path(variant): Path<String>,
+    Query(query): Query<ComparisonsQuery>,
+) -> Response {
+    if let Err(rejection) = state.require_admin(&user) {
+        return rejection.into_response();
+    }
+
+    match state
+        .repository
+        .list_by_variant(&variant, query.limit())
+        .await
+    {
+        Ok(comparisons) => (
+            StatusCode::OK,
+            Json(ShadowComparisonListResponse {
+                variant,
+                comparisons: comparisons
+                    .into_iter()
+                    .map(ShadowComparisonResponse::from)
+                    .collect(),
+            }),
+        )
+            .into_response(),
+        Err(e) => internal(e).into_response(),
+    }
+}
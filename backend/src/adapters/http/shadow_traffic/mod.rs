@@ -0,0 +1,15 @@
+//! Shadow traffic admin HTTP adapter module.
+//!
+//! Admin endpoints comparing candidate variants against production output
+//! recorded by `ShadowAIProvider`.
+
+pub mod dto;
+pub mod handlers;
+pub mod routes;
+
+pub use dto::{
+    ErrorResponse, ShadowComparisonListResponse, ShadowComparisonResponse,
+    ShadowMetricsListResponse,
+};
+pub use handlers::ShadowTrafficAppState;
+pub use routes::shadow_traffic_routes;
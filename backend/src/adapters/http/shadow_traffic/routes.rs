@@ -0,0 +1,20 @@
+//! HTTP routes for shadow traffic evaluation.
+
+use axum::{routing::get, Router};
+
+use super::handlers::{list_shadow_comparisons, list_shadow_metrics, ShadowTrafficAppState};
+
+/// Creates the shadow traffic admin router.
+///
+/// # Routes
+/// - `GET /api/admin/shadow-traffic` - Metrics for every variant (admin)
+/// - `GET /api/admin/shadow-traffic/:variant/comparisons` - Recent comparisons (admin)
+pub fn shadow_traffic_routes(state: ShadowTrafficAppState) -> Router {
+    Router::new()
+        .route("/api/admin/shadow-traffic", get(list_shadow_metrics))
+        .route(
+            "/api/admin/shadow-traffic/:variant/comparisons",
+            get(list_shadow_comparisons),
+        )
+        .with_state(state)
+}
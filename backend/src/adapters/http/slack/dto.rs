@@ -0,0 +1,124 @@
+//! HTTP DTOs for Slack endpoints.
+
+use serde::{Deserialize, Serialize};
+
+use crate::domain::document::{DeliveryStatus, SlackShare};
+use crate::ports::SlackWorkspace;
+
+// ════════════════════════════════════════════════════════════════════════════
+// Request DTOs
+// ════════════════════════════════════════════════════════════════════════════
+
+/// Query parameters for starting the OAuth flow.
+#[derive(Debug, Clone, Deserialize)]
+pub struct AuthorizeParams {
+    /// Opaque value Slack echoes back; the client checks it on return.
+    pub state: String,
+}
+
+/// Authorization code Slack returned to the client's redirect URI.
+#[derive(Debug, Clone, Deserialize)]
+pub struct ConnectSlackRequest {
+    pub code: String,
+}
+
+/// Where to share a recommendation.
+#[derive(Debug, Clone, Deserialize)]
+pub struct ShareToSlackRequest {
+    pub team_id: String,
+    /// Channel ID or name.
+    pub channel: String,
+}
+
+// ════════════════════════════════════════════════════════════════════════════
+// Response DTOs
+// ════════════════════════════════════════════════════════════════════════════
+
+/// Slack install screen to send the user to.
+#[derive(Debug, Clone, Serialize)]
+pub struct SlackAuthorizationResponse {
+    pub authorization_url: String,
+}
+
+/// A connected Slack workspace; the bot token is never returned.
+#[derive(Debug, Clone, Serialize)]
+pub struct SlackWorkspaceResponse {
+    pub team_id: String,
+    pub team_name: String,
+    pub connected_at: String,
+}
+
+impl From<SlackWorkspace> for SlackWorkspaceResponse {
+    fn from(workspace: SlackWorkspace) -> Self {
+        Self {
+            team_id: workspace.team_id,
+            team_name: workspace.team_name,
+            connected_at: workspace.connected_at.as_datetime().to_rfc3339(),
+        }
+    }
+}
+
+/// A recommendation shared to Slack.
+#[derive(Debug, Clone, Serialize)]
+pub struct SlackShareResponse {
+    pub id: String,
+    pub team_id: String,
+    pub channel: String,
+    /// `pending` while a retry is scheduled.
+    pub status: DeliveryStatus,
+    /// Public link to the decision document included in the message.
+    pub link: String,
+    pub last_error: Option<String>,
+    pub sent_at: Option<String>,
+}
+
+impl From<SlackShare> for SlackShareResponse {
+    fn from(share: SlackShare) -> Self {
+        Self {
+            id: share.id.to_string(),
+            team_id: share.team_id,
+            channel: share.channel,
+            status: share.status,
+            link: share.digest.link,
+            last_error: share.last_error,
+            sent_at: share.sent_at.map(|t| t.as_datetime().to_rfc3339()),
+        }
+    }
+}
+
+/// Standard error response.
+#[derive(Debug, Clone, Serialize)]
+pub struct ErrorResponse {
+    pub code: String,
+    pub message: String,
+}
+
+impl ErrorResponse {
+    pub fn new(code: impl Into<String>, message: impl Into<String>) -> Self {
+        Self {
+            code: code.into(),
+            message: message.into(),
+        }
+    }
+
+    pub fn bad_request(message: impl Into<String>) -> Self {
+        Self::new("BAD_REQUEST", message)
+    }
+
+    /// The user must connect (or reconnect) the Slack workspace.
+    pub fn not_connected(message: impl Into<String>) -> Self {
+        Self::new("SLACK_NOT_CONNECTED", message)
+    }
+
+    pub fn bad_gateway(message: impl Into<String>) -> Self {
+        Self::new("BAD_GATEWAY", message)
+    }
+
+    pub fn unavailable(message: impl Into<String>) -> Self {
+        Self::new("SERVICE_UNAVAILABLE", message)
+    }
+
+    pub fn internal(message: impl Into<String>) -> Self {
+        Self::new("INTERNAL_ERROR", message)
+    }
+}
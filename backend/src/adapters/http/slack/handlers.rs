@@ -0,0 +1,267 @@
+//! HTTP handlers for Slack endpoints.
+
+use std::sync::Arc;
+
+use axum::{
+    extract::{Path, Query, State},
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    Json,
+};
+
+use crate::adapters::http::export::handlers::organization_error_response;
+use crate::adapters::http::middleware::RequireAuth;
+use crate::adapters::http::publications::handlers::publish_error_response;
+use crate::adapters::http::publications::PublicationsAppState;
+use crate::application::handlers::cycle::{
+    ShareToSlackCommand, ShareToSlackError, ShareToSlackHandler,
+};
+use crate::domain::foundation::{CycleId, DomainError};
+use crate::ports::{
+    OrganizationChatSettingsRepository, SlackClient, SlackError, SlackShareRepository,
+    SlackWorkspaceStore,
+};
+
+use super::dto::{
+    AuthorizeParams, ConnectSlackRequest, ErrorResponse, ShareToSlackRequest,
+    SlackAuthorizationResponse, SlackShareResponse, SlackWorkspaceResponse,
+};
+
+// ════════════════════════════════════════════════════════════════════════════
+// Application State
+// ════════════════════════════════════════════════════════════════════════════
+
+/// Shared state for the Slack endpoints.
+#[derive(Clone)]
+pub struct SlackAppState {
+    /// Publishes the document link included in each share.
+    pub publications: PublicationsAppState,
+    /// Whether the user's organization shares to Slack or Teams.
+    pub chat_settings: Arc<dyn OrganizationChatSettingsRepository>,
+    pub workspaces: Arc<dyn SlackWorkspaceStore>,
+    pub shares: Arc<dyn SlackShareRepository>,
+    pub slack: Arc<dyn SlackClient>,
+}
+
+impl SlackAppState {
+    fn share_to_slack_handler(&self) -> ShareToSlackHandler {
+        // The digest and the published snapshot read the same cycle
+        let publications = self.publications.for_request();
+        ShareToSlackHandler::new(
+            Arc::new(publications.publish_handler()),
+            publications.export.cycle_reader.clone(),
+            self.chat_settings.clone(),
+            self.workspaces.clone(),
+            self.shares.clone(),
+            self.slack.clone(),
+        )
+    }
+}
+
+// ════════════════════════════════════════════════════════════════════════════
+// HTTP handlers
+// ════════════════════════════════════════════════════════════════════════════
+
+/// GET /api/integrations/slack - Connected Slack workspaces
+pub async fn list_slack_workspaces(
+    State(state): State<SlackAppState>,
+    RequireAuth(user): RequireAuth,
+) -> Response {
+    match state.workspaces.list(&user.id).await {
+        Ok(workspaces) => {
+            let body: Vec<SlackWorkspaceResponse> =
+                workspaces.into_iter().map(Into::into).collect();
+            (StatusCode::OK, Json(body)).into_response()
+        }
+        Err(e) => internal_error("Failed to load Slack workspaces", e),
+    }
+}
+
+/// GET /api/integrations/slack/authorize?state=... - Slack install screen URL
+pub async fn authorize_slack(
+    State(state): State<SlackAppState>,
+    RequireAuth(_user): RequireAuth,
+    Query(params): Query<AuthorizeParams>,
+) -> Response {
+    let response = SlackAuthorizationResponse {
+        authorization_url: state.slack.authorization_url(&params.state),
+    };
+    (StatusCode::OK, Json(response)).into_response()
+}
+
+/// POST /api/integrations/slack/connect - Exchange the authorization code and save the workspace
+pub async fn connect_slack(
+    State(state): State<SlackAppState>,
+    RequireAuth(user): RequireAuth,
+    Json(req): Json<ConnectSlackRequest>,
+) -> Response {
+    if req.code.trim().is_empty() {
+        return bad_request("Authorization code is required");
+    }
+
+    let workspace = match state.slack.exchange_code(&req.code).await {
+        Ok(workspace) => workspace,
+        Err(e) => return slack_error_response(e),
+    };
+    match state.workspaces.save(&user.id, &workspace).await {
+        Ok(()) => (
+            StatusCode::OK,
+            Json(SlackWorkspaceResponse::from(workspace)),
+        )
+            .into_response(),
+        Err(e) => internal_error("Failed to save Slack workspace", e),
+    }
+}
+
+/// DELETE /api/integrations/slack/:team_id - Forget a connected workspace
+pub async fn disconnect_slack(
+    State(state): State<SlackAppState>,
+    RequireAuth(user): RequireAuth,
+    Path(team_id): Path<String>,
+) -> Response {
+    match state.workspaces.delete(&user.id, &team_id).await {
+        Ok(()) => StatusCode::NO_CONTENT.into_response(),
+        Err(e) => internal_error("Failed to disconnect Slack workspace", e),
+    }
+}
+
+/// POST /api/cycles/:cycle_id/share/slack - Post the recommendation to a Slack channel
+///
+/// Returns 201 once posted, or 202 if Slack was unavailable and the share is
+/// queued for retry.
+pub async fn share_to_slack(
+    State(state): State<SlackAppState>,
+    RequireAuth(user): RequireAuth,
+    Path(cycle_id): Path<String>,
+    Json(req): Json<ShareToSlackRequest>,
+) -> Response {
+    let Ok(cycle_id) = cycle_id.parse::<CycleId>() else {
+        return bad_request("Invalid cycle ID");
+    };
+
+    let organization = match state.publications.export.user_organization(&user.id).await {
+        Ok(organization) => organization,
+        Err(e) => return organization_error_response(e),
+    };
+    let cmd = ShareToSlackCommand {
+        cycle_id,
+        user_id: user.id,
+        team_id: req.team_id,
+        channel: req.channel,
+        organization,
+    };
+
+    match state.share_to_slack_handler().handle(cmd).await {
+        Ok(share) => {
+            let status = if share.sent_at.is_some() {
+                StatusCode::CREATED
+            } else {
+                StatusCode::ACCEPTED
+            };
+            (status, Json(SlackShareResponse::from(share))).into_response()
+        }
+        Err(e) => share_error_response(e),
+    }
+}
+
+// ════════════════════════════════════════════════════════════════════════════
+// Error handling
+// ════════════════════════════════════════════════════════════════════════════
+
+fn bad_request(message: &str) -> Response {
+    (
+        StatusCode::BAD_REQUEST,
+        Json(ErrorResponse::bad_request(message)),
+    )
+        .into_response()
+}
+
+fn internal_error(message: &str, error: DomainError) -> Response {
+    tracing::error!("{}: {}", message, error);
+    (
+        StatusCode::INTERNAL_SERVER_ERROR,
+        Json(ErrorResponse::internal(message)),
+    )
+        .into_response()
+}
+
+fn slack_error_response(error: SlackError) -> Response {
+    let (status, body) = match &error {
+        SlackError::Unauthorized(_) => (
+            StatusCode::CONFLICT,
+            ErrorResponse::not_connected("Reconnect the Slack workspace"),
+        ),
+        SlackError::Api(_) => {
+            tracing::warn!("Slack API error: {}", error);
+            (
+                StatusCode::BAD_GATEWAY,
+                ErrorResponse::bad_gateway("Slack rejected the request"),
+            )
+        }
+        SlackError::Unavailable(_) => (
+            StatusCode::SERVICE_UNAVAILABLE,
+            ErrorResponse::unavailable("Slack is unavailable; try again shortly"),
+        ),
+    };
+    (status, Json(body)).into_response()
+}
+
+fn share_error_response(error: ShareToSlackError) -> Response {
+    match error {
+        ShareToSlackError::NotConnected => (
+            StatusCode::CONFLICT,
+            Json(ErrorResponse::not_connected(
+                "Connect the Slack workspace first",
+            )),
+        )
+            .into_response(),
+        ShareToSlackError::InvalidChannel => bad_request("A Slack channel is required"),
+        ShareToSlackError::SlackNotEnabled => (
+            StatusCode::CONFLICT,
+            Json(ErrorResponse::new(
+                "CHAT_PLATFORM_NOT_ENABLED",
+                "Your organization shares to Teams, not Slack",
+            )),
+        )
+            .into_response(),
+        ShareToSlackError::NoRecommendation => (
+            StatusCode::UNPROCESSABLE_ENTITY,
+            Json(ErrorResponse::new(
+                "NO_RECOMMENDATION",
+                "The cycle has no recommendation to share yet",
+            )),
+        )
+            .into_response(),
+        ShareToSlackError::Publish(e) => publish_error_response(e),
+        ShareToSlackError::Domain(e) => internal_error("Failed to share to Slack", e),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn missing_workspace_maps_to_409() {
+        let response = share_error_response(ShareToSlackError::NotConnected);
+        assert_eq!(response.status(), StatusCode::CONFLICT);
+    }
+
+    #[test]
+    fn missing_recommendation_maps_to_422() {
+        let response = share_error_response(ShareToSlackError::NoRecommendation);
+        assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+    }
+
+    #[test]
+    fn organizations_on_teams_map_to_409() {
+        let response = share_error_response(ShareToSlackError::SlackNotEnabled);
+        assert_eq!(response.status(), StatusCode::CONFLICT);
+    }
+
+    #[test]
+    fn revoked_installs_ask_the_user_to_reconnect() {
+        let response = slack_error_response(SlackError::Unauthorized("invalid_code".to_string()));
+        assert_eq!(response.status(), StatusCode::CONFLICT);
+    }
+}
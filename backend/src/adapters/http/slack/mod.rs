@@ -0,0 +1,14 @@
+//! Slack HTTP adapter module.
+//!
+//! Connects Slack workspaces and shares cycle recommendations to channels.
+
+pub mod dto;
+pub mod handlers;
+pub mod routes;
+
+pub use dto::{
+    AuthorizeParams, ConnectSlackRequest, ErrorResponse, ShareToSlackRequest,
+    SlackAuthorizationResponse, SlackShareResponse, SlackWorkspaceResponse,
+};
+pub use handlers::SlackAppState;
+pub use routes::slack_routes;
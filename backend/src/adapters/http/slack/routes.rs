@@ -0,0 +1,29 @@
+//! HTTP routes for Slack endpoints.
+
+use axum::{
+    routing::{delete, get, post},
+    Router,
+};
+
+use super::handlers::{
+    authorize_slack, connect_slack, disconnect_slack, list_slack_workspaces, share_to_slack,
+    SlackAppState,
+};
+
+/// Creates the Slack router.
+///
+/// # Routes
+/// - `GET /api/integrations/slack` - Connected Slack workspaces
+/// - `GET /api/integrations/slack/authorize?state=...` - Slack install screen URL
+/// - `POST /api/integrations/slack/connect` - Exchange the authorization code and save the workspace
+/// - `DELETE /api/integrations/slack/:team_id` - Forget a connected workspace
+/// - `POST /api/cycles/:cycle_id/share/slack` - Post the recommendation to a Slack channel
+pub fn slack_routes(state: SlackAppState) -> Router {
+    Router::new()
+        .route("/api/integrations/slack", get(list_slack_workspaces))
+        .route("/api/integrations/slack/authorize", get(authorize_slack))
+        .route("/api/integrations/slack/connect", post(connect_slack))
+        .route("/api/integrations/slack/:team_id", delete(disconnect_slack))
+        .route("/api/cycles/:cycle_id/share/slack", post(share_to_slack))
+        .with_state(state)
+}
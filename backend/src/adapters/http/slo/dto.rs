@@ -0,0 +1,101 @@
+//! HTTP DTOs for the SLO report.
+
+use serde::Serialize;
+
+use crate::adapters::telemetry::{BurnSeverity, SloStatus};
+
+// ════════════════════════════════════════════════════════════════════════════
+// Response DTOs
+// ════════════════════════════════════════════════════════════════════════════
+
+/// Error budget for one SLO.
+#[derive(Debug, Clone, Serialize)]
+pub struct SloStatusResponse {
+    pub sli: String,
+    pub target: f64,
+    pub window_days: u64,
+    pub total_events: u64,
+    pub good_events: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub compliance: Option<f64>,
+    pub error_budget_remaining: f64,
+    pub burn_rate_1h: f64,
+    pub burn_rate_6h: f64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub latency_threshold_ms: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub p95_latency_ms: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub alert: Option<BurnSeverity>,
+}
+
+impl From<SloStatus> for SloStatusResponse {
+    fn from(status: SloStatus) -> Self {
+        Self {
+            sli: status.sli.to_string(),
+            target: status.target,
+            window_days: status.window_secs / 86_400,
+            total_events: status.total_events,
+            good_events: status.good_events,
+            compliance: status.compliance,
+            error_budget_remaining: status.error_budget_remaining,
+            burn_rate_1h: status.burn_rate_1h,
+            burn_rate_6h: status.burn_rate_6h,
+            latency_threshold_ms: status.latency_threshold_ms,
+            p95_latency_ms: status.p95_latency_ms,
+            alert: status.alert,
+        }
+    }
+}
+
+/// All tracked SLOs.
+#[derive(Debug, Clone, Serialize)]
+pub struct SloReportResponse {
+    pub objectives: Vec<SloStatusResponse>,
+}
+
+/// Standard error response.
+#[derive(Debug, Clone, Serialize)]
+pub struct ErrorResponse {
+    pub code: String,
+    pub message: String,
+}
+
+impl ErrorResponse {
+    pub fn forbidden(message: impl Into<String>) -> Self {
+        Self {
+            code: "FORBIDDEN".to_string(),
+            message: message.into(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ports::Sli;
+
+    #[test]
+    fn status_response_uses_stable_sli_name() {
+        let status = SloStatus {
+            sli: Sli::ConversationStreamErrors,
+            target: 0.99,
+            window_secs: 28 * 86_400,
+            total_events: 10,
+            good_events: 10,
+            compliance: Some(1.0),
+            error_budget_remaining: 1.0,
+            burn_rate_1h: 0.0,
+            burn_rate_6h: 0.0,
+            latency_threshold_ms: None,
+            p95_latency_ms: None,
+            alert: Some(BurnSeverity::Slow),
+        };
+
+        let json = serde_json::to_value(SloStatusResponse::from(status)).unwrap();
+        assert_eq!(json["sli"], "conversation_stream_errors");
+        assert_eq!(json["window_days"], 28);
+        assert_eq!(json["alert"], "slow");
+        assert!(json.get("p95_latency_ms").is_none());
+    }
+}
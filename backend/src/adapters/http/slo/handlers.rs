@@ -0,0 +1,57 @@
+//! HTTP handlers for the SLO report.
+
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use axum::{
+    extract::State,
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    Json,
+};
+
+use crate::adapters::http::middleware::RequireAuth;
+use crate::adapters::telemetry::SloTracker;
+use crate::domain::foundation::UserId;
+
+use super::dto::{ErrorResponse, SloReportResponse};
+
+// ════════════════════════════════════════════════════════════════════════════
+// Application State
+// ════════════════════════════════════════════════════════════════════════════
+
+/// Shared state for the SLO report.
+#[derive(Clone)]
+pub struct SloAppState {
+    pub tracker: Arc<SloTracker>,
+    /// Users allowed to view the report.
+    pub admin_user_ids: Arc<HashSet<UserId>>,
+}
+
+// ════════════════════════════════════════════════════════════════════════════
+// HTTP handlers
+// ════════════════════════════════════════════════════════════════════════════
+
+/// GET /api/admin/slo - Error budgets and burn rates
+pub async fn get_slo_report(
+    State(state): State<SloAppState>,
+    RequireAuth(user): RequireAuth,
+) -> Response {
+    if !state.admin_user_ids.contains(&user.id) {
+        return (
+            StatusCode::FORBIDDEN,
+            Json(ErrorResponse::forbidden("Admin access required")),
+        )
+            .into_response();
+    }
+
+    let response = SloReportResponse {
+        objectives: state
+            .tracker
+            .statuses()
+            .into_iter()
+            .map(Into::into)
+            .collect(),
+    };
+    (StatusCode::OK, Json(response)).into_response()
+}
@@ -0,0 +1,12 @@
+//! SLO HTTP adapter module.
+//!
+//! Admin endpoint reporting error budgets and burn rates for each tracked
+//! service level objective.
+
+pub mod dto;
+pub mod handlers;
+pub mod routes;
+
+pub use dto::{ErrorResponse, SloReportResponse, SloStatusResponse};
+pub use handlers::SloAppState;
+pub use routes::slo_routes;
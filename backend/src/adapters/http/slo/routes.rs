@@ -0,0 +1,15 @@
+//! HTTP routes for the SLO report.
+
+use axum::{routing::get, Router};
+
+use super::handlers::{get_slo_report, SloAppState};
+
+/// Creates the SLO report router.
+///
+/// # Routes
+/// - `GET /api/admin/slo` - Error budgets and burn rates (admin)
+pub fn slo_routes(state: SloAppState) -> Router {
+    Router::new()
+        .route("/api/admin/slo", get(get_slo_report))
+        .with_state(state)
+}
@@ -0,0 +1,121 @@
+//! HTTP DTOs for the slow query report.
+
+use serde::{Deserialize, Serialize};
+
+use crate::adapters::telemetry::SlowQueryGroup;
+
+/// Longest window the report covers; older samples are not meaningful.
+pub const MAX_WINDOW_MINUTES: u32 = 60;
+
+/// Largest number of groups returned.
+pub const MAX_LIMIT: usize = 100;
+
+// ════════════════════════════════════════════════════════════════════════════
+// Request DTOs
+// ════════════════════════════════════════════════════════════════════════════
+
+/// Query parameters for the slow query report.
+#[derive(Debug, Clone, Deserialize)]
+pub struct SlowQueryReportParams {
+    /// Window to report on, in minutes (max 60).
+    #[serde(default = "default_minutes")]
+    pub minutes: u32,
+    /// Maximum groups to return (max 100).
+    #[serde(default = "default_limit")]
+    pub limit: usize,
+}
+
+fn default_minutes() -> u32 {
+    MAX_WINDOW_MINUTES
+}
+
+fn default_limit() -> usize {
+    20
+}
+
+impl SlowQueryReportParams {
+    pub fn window_minutes(&self) -> u32 {
+        self.minutes.clamp(1, MAX_WINDOW_MINUTES)
+    }
+
+    pub fn clamped_limit(&self) -> usize {
+        self.limit.clamp(1, MAX_LIMIT)
+    }
+}
+
+// ════════════════════════════════════════════════════════════════════════════
+// Response DTOs
+// ════════════════════════════════════════════════════════════════════════════
+
+/// Slow queries from one adapter method under one handler.
+#[derive(Debug, Clone, Serialize)]
+pub struct SlowQueryGroupResponse {
+    pub query: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub handler: Option<String>,
+    pub count: u64,
+    pub total_ms: u64,
+    pub avg_ms: u64,
+    pub max_ms: u64,
+    pub last_seen: String,
+}
+
+impl From<SlowQueryGroup> for SlowQueryGroupResponse {
+    fn from(group: SlowQueryGroup) -> Self {
+        Self {
+            query: group.query,
+            handler: group.handler,
+            count: group.count,
+            total_ms: group.total_ms,
+            avg_ms: group.avg_ms,
+            max_ms: group.max_ms,
+            last_seen: group.last_seen.as_datetime().to_rfc3339(),
+        }
+    }
+}
+
+/// Slow query report, ordered by total time spent.
+#[derive(Debug, Clone, Serialize)]
+pub struct SlowQueryReportResponse {
+    pub threshold_ms: u64,
+    pub window_minutes: u32,
+    /// Slow queries seen since startup.
+    pub total_slow_queries: u64,
+    pub groups: Vec<SlowQueryGroupResponse>,
+}
+
+/// Standard error response.
+#[derive(Debug, Clone, Serialize)]
+pub struct ErrorResponse {
+    pub code: String,
+    pub message: String,
+}
+
+impl ErrorResponse {
+    pub fn forbidden(message: impl Into<String>) -> Self {
+        Self {
+            code: "FORBIDDEN".to_string(),
+            message: message.into(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn params_default_to_last_hour() {
+        let params: SlowQueryReportParams = serde_json::from_str("{}").unwrap();
+        assert_eq!(params.window_minutes(), 60);
+        assert_eq!(params.clamped_limit(), 20);
+    }
+
+    #[test]
+    fn params_are_clamped() {
+        let params: SlowQueryReportParams =
+            serde_json::from_str(r#"{"minutes":600,"limit":0}"#).unwrap();
+        assert_eq!(params.window_minutes(), 60);
+        assert_eq!(params.clamped_limit(), 1);
+    }
+}
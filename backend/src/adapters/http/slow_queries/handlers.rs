@@ -0,0 +1,61 @@
+//! HTTP handlers for the slow query report.
+
+use std::collections::HashSet;
+use std::sync::Arc;
+use std::time::Duration;
+
+use axum::{
+    extract::{Query, State},
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    Json,
+};
+
+use crate::adapters::http::middleware::RequireAuth;
+use crate::adapters::telemetry::SlowQueryLog;
+use crate::domain::foundation::UserId;
+
+use super::dto::{ErrorResponse, SlowQueryReportParams, SlowQueryReportResponse};
+
+// ════════════════════════════════════════════════════════════════════════════
+// Application State
+// ════════════════════════════════════════════════════════════════════════════
+
+/// Shared state for the slow query report.
+#[derive(Clone)]
+pub struct SlowQueriesAppState {
+    pub log: Arc<SlowQueryLog>,
+    /// Users allowed to view the report.
+    pub admin_user_ids: Arc<HashSet<UserId>>,
+}
+
+// ════════════════════════════════════════════════════════════════════════════
+// HTTP handlers
+// ════════════════════════════════════════════════════════════════════════════
+
+/// GET /api/admin/slow-queries - Top slow query groups in the recent window
+pub async fn get_slow_query_report(
+    State(state): State<SlowQueriesAppState>,
+    RequireAuth(user): RequireAuth,
+    Query(params): Query<SlowQueryReportParams>,
+) -> Response {
+    if !state.admin_user_ids.contains(&user.id) {
+        return (
+            StatusCode::FORBIDDEN,
+            Json(ErrorResponse::forbidden("Admin access required")),
+        )
+            .into_response();
+    }
+
+    let window_minutes = params.window_minutes();
+    let window = Duration::from_secs(u64::from(window_minutes) * 60);
+    let groups = state.log.top_groups(window, params.clamped_limit());
+
+    let response = SlowQueryReportResponse {
+        threshold_ms: state.log.threshold().as_millis() as u64,
+        window_minutes,
+        total_slow_queries: state.log.total_count(),
+        groups: groups.into_iter().map(Into::into).collect(),
+    };
+    (StatusCode::OK, Json(response)).into_response()
+}
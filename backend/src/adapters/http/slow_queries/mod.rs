@@ -0,0 +1,14 @@
+//! Slow query report HTTP adapter module.
+//!
+//! Admin endpoint listing the slowest Postgres query groups recorded by
+//! the telemetry `SlowQueryLayer`.
+
+pub mod dto;
+pub mod handlers;
+pub mod routes;
+
+pub use dto::{
+    ErrorResponse, SlowQueryGroupResponse, SlowQueryReportParams, SlowQueryReportResponse,
+};
+pub use handlers::SlowQueriesAppState;
+pub use routes::slow_query_routes;
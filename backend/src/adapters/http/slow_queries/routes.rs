@@ -0,0 +1,15 @@
+//! HTTP routes for the slow query report.
+
+use axum::{routing::get, Router};
+
+use super::handlers::{get_slow_query_report, SlowQueriesAppState};
+
+/// Creates the slow query report router.
+///
+/// # Routes
+/// - `GET /api/admin/slow-queries` - Top slow query groups (admin)
+pub fn slow_query_routes(state: SlowQueriesAppState) -> Router {
+    Router::new()
+        .route("/api/admin/slow-queries", get(get_slow_query_report))
+        .with_state(state)
+}
@@ -0,0 +1,211 @@
+//! HTTP DTOs for Teams endpoints.
+
+use serde::{Deserialize, Serialize};
+
+use crate::domain::document::{
+    ChatPlatform, DeliveryStatus, OrganizationChatSettings, TeamsChannel, TeamsShare,
+};
+
+// ════════════════════════════════════════════════════════════════════════════
+// Request DTOs
+// ════════════════════════════════════════════════════════════════════════════
+
+/// Where to share a recommendation in Teams.
+#[derive(Debug, Clone, Deserialize)]
+pub struct ShareToTeamsRequest {
+    /// Name of a channel registered by the organization's admin.
+    pub channel: String,
+}
+
+/// A Teams channel to register.
+#[derive(Debug, Clone, Deserialize)]
+pub struct TeamsChannelRequest {
+    pub name: String,
+    /// The channel's incoming webhook (connector or Workflows trigger).
+    /// Omit to keep the URL already registered under this name, since it
+    /// is never returned.
+    #[serde(default)]
+    pub webhook_url: Option<String>,
+}
+
+/// Replaces an organization's chat integration settings.
+#[derive(Debug, Clone, Deserialize)]
+pub struct UpdateOrganizationIntegrationsRequest {
+    pub chat_platform: ChatPlatform,
+    /// Required when `chat_platform` is `teams`; replaces the registered list.
+    #[serde(default)]
+    pub teams_channels: Vec<TeamsChannelRequest>,
+}
+
+// ════════════════════════════════════════════════════════════════════════════
+// Response DTOs
+// ════════════════════════════════════════════════════════════════════════════
+
+/// A recommendation shared to Teams.
+#[derive(Debug, Clone, Serialize)]
+pub struct TeamsShareResponse {
+    pub id: String,
+    pub channel: String,
+    /// `pending` while a retry is scheduled.
+    pub status: DeliveryStatus,
+    /// Public link to the decision document included in the message.
+    pub link: String,
+    pub last_error: Option<String>,
+    pub sent_at: Option<String>,
+}
+
+impl From<TeamsShare> for TeamsShareResponse {
+    fn from(share: TeamsShare) -> Self {
+        Self {
+            id: share.id.to_string(),
+            channel: share.channel,
+            status: share.status,
+            link: share.digest.link,
+            last_error: share.last_error,
+            sent_at: share.sent_at.map(|t| t.as_datetime().to_rfc3339()),
+        }
+    }
+}
+
+/// Which platform the caller shares to, and the Teams channels they can pick.
+#[derive(Debug, Clone, Serialize)]
+pub struct ChatIntegrationResponse {
+    pub chat_platform: ChatPlatform,
+    pub teams_channels: Vec<String>,
+}
+
+impl ChatIntegrationResponse {
+    /// Slack, for users outside an organization that chose a platform.
+    pub fn defaults() -> Self {
+        Self {
+            chat_platform: ChatPlatform::Slack,
+            teams_channels: vec![],
+        }
+    }
+}
+
+impl From<OrganizationChatSettings> for ChatIntegrationResponse {
+    fn from(settings: OrganizationChatSettings) -> Self {
+        let teams_channels = match settings.platform {
+            ChatPlatform::Teams => settings
+                .teams_channels
+                .into_iter()
+                .map(|channel| channel.name)
+                .collect(),
+            ChatPlatform::Slack => vec![],
+        };
+        Self {
+            chat_platform: settings.platform,
+            teams_channels,
+        }
+    }
+}
+
+/// A registered Teams channel. The webhook URL is a credential, so only its
+/// host is returned.
+#[derive(Debug, Clone, Serialize)]
+pub struct TeamsChannelResponse {
+    pub name: String,
+    pub webhook_host: String,
+}
+
+impl From<TeamsChannel> for TeamsChannelResponse {
+    fn from(channel: TeamsChannel) -> Self {
+        Self {
+            webhook_host: channel.webhook_host(),
+            name: channel.name,
+        }
+    }
+}
+
+/// An organization's chat integration settings.
+#[derive(Debug, Clone, Serialize)]
+pub struct OrganizationIntegrationsResponse {
+    pub organization: String,
+    pub chat_platform: ChatPlatform,
+    pub teams_channels: Vec<TeamsChannelResponse>,
+    /// Absent until the settings are first saved.
+    pub updated_at: Option<String>,
+}
+
+impl OrganizationIntegrationsResponse {
+    /// Settings of an organization that never changed them.
+    pub fn defaults(organization: String) -> Self {
+        Self {
+            organization,
+            chat_platform: ChatPlatform::Slack,
+            teams_channels: vec![],
+            updated_at: None,
+        }
+    }
+}
+
+impl From<OrganizationChatSettings> for OrganizationIntegrationsResponse {
+    fn from(settings: OrganizationChatSettings) -> Self {
+        Self {
+            organization: settings.organization,
+            chat_platform: settings.platform,
+            teams_channels: settings
+                .teams_channels
+                .into_iter()
+                .map(Into::into)
+                .collect(),
+            updated_at: Some(settings.updated_at.as_datetime().to_rfc3339()),
+        }
+    }
+}
+
+/// Standard error response.
+#[derive(Debug, Clone, Serialize)]
+pub struct ErrorResponse {
+    pub code: String,
+    pub message: String,
+}
+
+impl ErrorResponse {
+    pub fn new(code: impl Into<String>, message: impl Into<String>) -> Self {
+        Self {
+            code: code.into(),
+            message: message.into(),
+        }
+    }
+
+    pub fn bad_request(message: impl Into<String>) -> Self {
+        Self::new("BAD_REQUEST", message)
+    }
+
+    pub fn forbidden(message: impl Into<String>) -> Self {
+        Self::new("FORBIDDEN", message)
+    }
+
+    pub fn not_found(message: impl Into<String>) -> Self {
+        Self::new("NOT_FOUND", message)
+    }
+
+    pub fn internal(message: impl Into<String>) -> Self {
+        Self::new("INTERNAL_ERROR", message)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn registered_channels_never_expose_the_webhook_url() {
+        let channel = TeamsChannel::new(
+            "Decisions",
+            "https://acme.webhook.office.com/webhookb2/abc/IncomingWebhook/def",
+        )
+        .unwrap();
+        let settings =
+            OrganizationChatSettings::new("acme", ChatPlatform::Teams, vec![channel]).unwrap();
+
+        let json =
+            serde_json::to_string(&OrganizationIntegrationsResponse::from(settings)).unwrap();
+
+        assert!(json.contains("\"webhook_host\":\"acme.webhook.office.com\""));
+        assert!(!json.contains("webhookb2"));
+        assert!(!json.contains("IncomingWebhook"));
+    }
+}
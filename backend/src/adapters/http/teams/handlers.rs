@@ -0,0 +1,303 @@
+//! HTTP handlers for Teams endpoints.
+
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    Json,
+};
+
+use crate::adapters::http::export::handlers::organization_error_response;
+use crate::adapters::http::middleware::RequireAuth;
+use crate::adapters::http::publications::handlers::publish_error_response;
+use crate::adapters::http::publications::PublicationsAppState;
+use crate::application::handlers::cycle::{
+    ShareToTeamsCommand, ShareToTeamsError, ShareToTeamsHandler,
+};
+use crate::application::handlers::OrganizationResolver;
+use crate::domain::document::{ChatSettingsError, OrganizationChatSettings, TeamsChannel};
+use crate::domain::foundation::{CycleId, DomainError, OrganizationId, UserId};
+use crate::ports::{OrganizationChatSettingsRepository, TeamsClient, TeamsShareRepository};
+
+use super::dto::{
+    ChatIntegrationResponse, ErrorResponse, OrganizationIntegrationsResponse, ShareToTeamsRequest,
+    TeamsShareResponse, UpdateOrganizationIntegrationsRequest,
+};
+
+// ════════════════════════════════════════════════════════════════════════════
+// Application State
+// ════════════════════════════════════════════════════════════════════════════
+
+/// Shared state for the Teams endpoints.
+#[derive(Clone)]
+pub struct TeamsAppState {
+    /// Publishes the document link included in each share.
+    pub publications: PublicationsAppState,
+    pub chat_settings: Arc<dyn OrganizationChatSettingsRepository>,
+    pub shares: Arc<dyn TeamsShareRepository>,
+    pub teams: Arc<dyn TeamsClient>,
+    /// Platform admins, allowed to change any organization's integrations.
+    pub admin_user_ids: Arc<HashSet<UserId>>,
+}
+
+impl TeamsAppState {
+    fn share_to_teams_handler(&self) -> ShareToTeamsHandler {
+        // The digest and the published snapshot read the same cycle
+        let publications = self.publications.for_request();
+        ShareToTeamsHandler::new(
+            Arc::new(publications.publish_handler()),
+            publications.export.cycle_reader.clone(),
+            self.chat_settings.clone(),
+            self.shares.clone(),
+            self.teams.clone(),
+        )
+    }
+
+    /// The key of `organization` if `user_id` owns or administers it, or
+    /// the response refusing them.
+    async fn require_org_admin(
+        &self,
+        user_id: &UserId,
+        organization: &str,
+    ) -> Result<String, Box<Response>> {
+        let Ok(organization_id) = organization.parse::<OrganizationId>() else {
+            return Err(Box::new(bad_request(&format!(
+                "Invalid organization: {}",
+                organization
+            ))));
+        };
+        if self.admin_user_ids.contains(user_id) {
+            return Ok(organization_id.to_string());
+        }
+        match OrganizationResolver::new(self.publications.export.organizations.clone())
+            .is_admin(user_id, &organization_id)
+            .await
+        {
+            Ok(true) => Ok(organization_id.to_string()),
+            Ok(false) => Err(Box::new(
+                (
+                    StatusCode::FORBIDDEN,
+                    Json(ErrorResponse::forbidden(
+                        "Organization admin access required",
+                    )),
+                )
+                    .into_response(),
+            )),
+            Err(e) => Err(Box::new(organization_error_response(e))),
+        }
+    }
+}
+
+// ════════════════════════════════════════════════════════════════════════════
+// HTTP handlers
+// ════════════════════════════════════════════════════════════════════════════
+
+/// GET /api/integrations/chat - The caller's organization's chat platform and Teams channels
+pub async fn get_chat_integration(
+    State(state): State<TeamsAppState>,
+    RequireAuth(user): RequireAuth,
+) -> Response {
+    let organization = match state.publications.export.user_organization(&user.id).await {
+        Ok(Some(organization)) => organization,
+        Ok(None) => {
+            return (StatusCode::OK, Json(ChatIntegrationResponse::defaults())).into_response()
+        }
+        Err(e) => return organization_error_response(e),
+    };
+    match state.chat_settings.get(&organization).await {
+        Ok(settings) => {
+            let body = settings.map_or_else(ChatIntegrationResponse::defaults, Into::into);
+            (StatusCode::OK, Json(body)).into_response()
+        }
+        Err(e) => internal_error("Failed to load chat settings", e),
+    }
+}
+
+/// POST /api/cycles/:cycle_id/share/teams - Post the recommendation to a Teams channel
+///
+/// Returns 201 once posted, or 202 if Teams was unavailable and the share is
+/// queued for retry.
+pub async fn share_to_teams(
+    State(state): State<TeamsAppState>,
+    RequireAuth(user): RequireAuth,
+    Path(cycle_id): Path<String>,
+    Json(req): Json<ShareToTeamsRequest>,
+) -> Response {
+    let Ok(cycle_id) = cycle_id.parse::<CycleId>() else {
+        return bad_request("Invalid cycle ID");
+    };
+
+    let organization = match state.publications.export.user_organization(&user.id).await {
+        Ok(organization) => organization,
+        Err(e) => return organization_error_response(e),
+    };
+    let cmd = ShareToTeamsCommand {
+        cycle_id,
+        user_id: user.id,
+        channel: req.channel,
+        organization,
+    };
+
+    match state.share_to_teams_handler().handle(cmd).await {
+        Ok(share) => {
+            let status = if share.sent_at.is_some() {
+                StatusCode::CREATED
+            } else {
+                StatusCode::ACCEPTED
+            };
+            (status, Json(TeamsShareResponse::from(share))).into_response()
+        }
+        Err(e) => share_error_response(e),
+    }
+}
+
+/// GET /api/admin/organizations/:organization/integrations - Chat integration settings (org admin)
+pub async fn get_organization_integrations(
+    State(state): State<TeamsAppState>,
+    RequireAuth(user): RequireAuth,
+    Path(organization): Path<String>,
+) -> Response {
+    let organization = match state.require_org_admin(&user.id, &organization).await {
+        Ok(organization) => organization,
+        Err(rejection) => return *rejection,
+    };
+
+    match state.chat_settings.get(&organization).await {
+        Ok(Some(settings)) => (
+            StatusCode::OK,
+            Json(OrganizationIntegrationsResponse::from(settings)),
+        )
+            .into_response(),
+        Ok(None) => (
+            StatusCode::OK,
+            Json(OrganizationIntegrationsResponse::defaults(organization)),
+        )
+            .into_response(),
+        Err(e) => internal_error("Failed to load chat settings", e),
+    }
+}
+
+/// PUT /api/admin/organizations/:organization/integrations - Choose the chat platform and Teams channels (org admin)
+pub async fn put_organization_integrations(
+    State(state): State<TeamsAppState>,
+    RequireAuth(user): RequireAuth,
+    Path(organization): Path<String>,
+    Json(req): Json<UpdateOrganizationIntegrationsRequest>,
+) -> Response {
+    let organization = match state.require_org_admin(&user.id, &organization).await {
+        Ok(organization) => organization,
+        Err(rejection) => return *rejection,
+    };
+
+    // Webhook URLs are never returned, so omitted ones keep the current URL
+    let current = match state.chat_settings.get(&organization).await {
+        Ok(current) => current,
+        Err(e) => return internal_error("Failed to load chat settings", e),
+    };
+    let settings = req
+        .teams_channels
+        .into_iter()
+        .map(|channel| match channel.webhook_url {
+            Some(webhook_url) => TeamsChannel::new(channel.name, webhook_url),
+            None => current
+                .as_ref()
+                .and_then(|current| current.teams_channel(&channel.name))
+                .cloned()
+                .ok_or(ChatSettingsError::InvalidWebhookUrl(channel.name)),
+        })
+        .collect::<Result<Vec<_>, _>>()
+        .and_then(|channels| {
+            OrganizationChatSettings::new(organization, req.chat_platform, channels)
+        });
+    let settings = match settings {
+        Ok(settings) => settings,
+        Err(e) => return bad_request(&e.to_string()),
+    };
+
+    match state.chat_settings.save(&settings).await {
+        Ok(()) => (
+            StatusCode::OK,
+            Json(OrganizationIntegrationsResponse::from(settings)),
+        )
+            .into_response(),
+        Err(e) => internal_error("Failed to save chat settings", e),
+    }
+}
+
+// ════════════════════════════════════════════════════════════════════════════
+// Error handling
+// ════════════════════════════════════════════════════════════════════════════
+
+fn bad_request(message: &str) -> Response {
+    (
+        StatusCode::BAD_REQUEST,
+        Json(ErrorResponse::bad_request(message)),
+    )
+        .into_response()
+}
+
+fn internal_error(message: &str, error: DomainError) -> Response {
+    tracing::error!("{}: {}", message, error);
+    (
+        StatusCode::INTERNAL_SERVER_ERROR,
+        Json(ErrorResponse::internal(message)),
+    )
+        .into_response()
+}
+
+fn share_error_response(error: ShareToTeamsError) -> Response {
+    match error {
+        ShareToTeamsError::TeamsNotEnabled => (
+            StatusCode::CONFLICT,
+            Json(ErrorResponse::new(
+                "CHAT_PLATFORM_NOT_ENABLED",
+                "Your organization has not enabled Teams sharing",
+            )),
+        )
+            .into_response(),
+        ShareToTeamsError::InvalidChannel => bad_request("A Teams channel is required"),
+        ShareToTeamsError::UnknownChannel => (
+            StatusCode::NOT_FOUND,
+            Json(ErrorResponse::not_found(
+                "Your organization has not registered that Teams channel",
+            )),
+        )
+            .into_response(),
+        ShareToTeamsError::NoRecommendation => (
+            StatusCode::UNPROCESSABLE_ENTITY,
+            Json(ErrorResponse::new(
+                "NO_RECOMMENDATION",
+                "The cycle has no recommendation to share yet",
+            )),
+        )
+            .into_response(),
+        ShareToTeamsError::Publish(e) => publish_error_response(e),
+        ShareToTeamsError::Domain(e) => internal_error("Failed to share to Teams", e),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn missing_recommendation_maps_to_422() {
+        let response = share_error_response(ShareToTeamsError::NoRecommendation);
+        assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+    }
+
+    #[test]
+    fn organizations_without_teams_map_to_409() {
+        let response = share_error_response(ShareToTeamsError::TeamsNotEnabled);
+        assert_eq!(response.status(), StatusCode::CONFLICT);
+    }
+
+    #[test]
+    fn unregistered_channels_map_to_404() {
+        let response = share_error_response(ShareToTeamsError::UnknownChannel);
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    }
+}
@@ -0,0 +1,16 @@
+//! Teams HTTP adapter module.
+//!
+//! Lets organization admins choose Slack or Teams and register Teams
+//! channels, and shares cycle recommendations to those channels.
+
+pub mod dto;
+pub mod handlers;
+pub mod routes;
+
+pub use dto::{
+    ChatIntegrationResponse, ErrorResponse, OrganizationIntegrationsResponse, ShareToTeamsRequest,
+    TeamsChannelRequest, TeamsChannelResponse, TeamsShareResponse,
+    UpdateOrganizationIntegrationsRequest,
+};
+pub use handlers::TeamsAppState;
+pub use routes::teams_routes;
@@ -0,0 +1,29 @@
+//! HTTP routes for Teams endpoints.
+
+use axum::{
+    routing::{get, post},
+    Router,
+};
+
+use super::handlers::{
+    get_chat_integration, get_organization_integrations, put_organization_integrations,
+    share_to_teams, TeamsAppState,
+};
+
+/// Creates the Teams router.
+///
+/// # Routes
+/// - `GET /api/integrations/chat` - The caller's organization's chat platform and Teams channels
+/// - `POST /api/cycles/:cycle_id/share/teams` - Post the recommendation to a Teams channel
+/// - `GET /api/admin/organizations/:organization/integrations` - Chat integration settings (org admin)
+/// - `PUT /api/admin/organizations/:organization/integrations` - Choose the chat platform and Teams channels (org admin)
+pub fn teams_routes(state: TeamsAppState) -> Router {
+    Router::new()
+        .route("/api/integrations/chat", get(get_chat_integration))
+        .route("/api/cycles/:cycle_id/share/teams", post(share_to_teams))
+        .route(
+            "/api/admin/organizations/:organization/integrations",
+            get(get_organization_integrations).put(put_organization_integrations),
+        )
+        .with_state(state)
+}
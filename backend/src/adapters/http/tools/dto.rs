@@ -1,6 +1,7 @@
 //! Data transfer objects for tools HTTP endpoints.
 
 use serde::{Deserialize, Serialize};
+use utoipa::{IntoParams, ToSchema};
 
 use crate::domain::foundation::ComponentType;
 
@@ -9,7 +10,7 @@ use crate::domain::foundation::ComponentType;
 // ═══════════════════════════════════════════════════════════════════════════
 
 /// Request to invoke a tool.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct InvokeToolRequest {
     /// ID of the cycle (UUID string)
     pub cycle_id: String,
@@ -26,14 +27,14 @@ pub struct InvokeToolRequest {
 }
 
 /// Request to dismiss a revisit suggestion.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct DismissRevisitRequest {
     /// Reason for dismissal
     pub reason: String,
 }
 
 /// Request to respond to a confirmation.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct RespondToConfirmationRequest {
     /// User's choice (option label)
     pub choice: String,
@@ -42,7 +43,7 @@ pub struct RespondToConfirmationRequest {
 }
 
 /// Query parameters for listing tools.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, IntoParams)]
 pub struct ListToolsQuery {
     /// Component type to get tools for
     pub component: ComponentType,
@@ -63,7 +64,7 @@ fn default_true() -> bool {
 }
 
 /// Query parameters for invocation history.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, IntoParams)]
 pub struct InvocationHistoryQuery {
     /// Maximum number of results
     #[serde(default = "default_limit")]
@@ -82,7 +83,7 @@ fn default_limit() -> usize {
 }
 
 /// Query parameters for revisit suggestions.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, IntoParams)]
 pub struct RevisitSuggestionsQuery {
     /// Filter by component
     pub component: Option<String>,
@@ -91,7 +92,7 @@ pub struct RevisitSuggestionsQuery {
 }
 
 /// Query parameters for confirmation requests.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, IntoParams)]
 pub struct ConfirmationsQuery {
     /// Only pending confirmations
     #[serde(default = "default_true")]
@@ -103,7 +104,7 @@ pub struct ConfirmationsQuery {
 // ═══════════════════════════════════════════════════════════════════════════
 
 /// Response with available tools.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct ListToolsResponse {
     /// Component tools were retrieved for
     pub component: ComponentType,
@@ -116,7 +117,7 @@ pub struct ListToolsResponse {
 }
 
 /// Response from invoking a tool.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct InvokeToolResponse {
     /// Invocation ID for tracking
     pub invocation_id: String,
@@ -133,7 +134,7 @@ pub struct InvokeToolResponse {
 }
 
 /// A tool invocation record.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct InvocationRecord {
     /// Invocation ID
     pub id: String,
@@ -152,7 +153,7 @@ pub struct InvocationRecord {
 }
 
 /// Response with invocation history.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct InvocationHistoryResponse {
     /// Cycle ID
     pub cycle_id: String,
@@ -165,7 +166,7 @@ pub struct InvocationHistoryResponse {
 }
 
 /// A revisit suggestion record.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct RevisitRecord {
     /// Suggestion ID
     pub id: String,
@@ -182,7 +183,7 @@ pub struct RevisitRecord {
 }
 
 /// Response with revisit suggestions.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct RevisitSuggestionsResponse {
     /// Total pending
     pub total_pending: usize,
@@ -195,7 +196,7 @@ pub struct RevisitSuggestionsResponse {
 }
 
 /// A confirmation request record.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct ConfirmationRecord {
     /// Confirmation ID
     pub id: String,
@@ -214,7 +215,7 @@ pub struct ConfirmationRecord {
 }
 
 /// Response with confirmation requests.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct ConfirmationsResponse {
     /// Total pending
     pub pending_count: usize,
@@ -223,7 +224,7 @@ pub struct ConfirmationsResponse {
 }
 
 /// Generic success response.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct SuccessResponse {
     /// Whether operation succeeded
     pub success: bool,
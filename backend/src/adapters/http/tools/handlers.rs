@@ -41,6 +41,15 @@ pub struct ToolsAppState {
 /// Get available tools for a component.
 ///
 /// GET /tools?component=objectives&format=openai
+#[utoipa::path(
+    get,
+    path = "/api/tools",
+    tag = "tools",
+    params(ListToolsQuery),
+    responses(
+        (status = 200, description = "Tool definitions for the component", body = ListToolsResponse),
+    )
+)]
 pub async fn list_tools(
     State(state): State<ToolsAppState>,
     Query(query): Query<ListToolsQuery>,
@@ -69,6 +78,16 @@ pub async fn list_tools(
 /// Invoke a tool.
 ///
 /// POST /tools/invoke
+#[utoipa::path(
+    post,
+    path = "/api/tools/invoke",
+    tag = "tools",
+    request_body = InvokeToolRequest,
+    responses(
+        (status = 200, description = "Tool ran; `success` says whether it succeeded", body = InvokeToolResponse),
+        (status = 400, description = "Invalid cycle ID or unknown tool", body = InvokeToolResponse),
+    )
+)]
 pub async fn invoke_tool(
     State(state): State<ToolsAppState>,
     Json(request): Json<InvokeToolRequest>,
@@ -160,6 +179,16 @@ pub async fn invoke_tool(
 /// Get tool invocation history for a cycle.
 ///
 /// GET /tools/invocations/:cycle_id
+#[utoipa::path(
+    get,
+    path = "/api/tools/invocations/{cycle_id}",
+    tag = "tools",
+    params(("cycle_id" = String, Path, description = "Cycle ID"), InvocationHistoryQuery),
+    responses(
+        (status = 200, description = "Invocation history", body = InvocationHistoryResponse),
+        (status = 400, description = "Invalid cycle ID", body = InvocationHistoryResponse),
+    )
+)]
 pub async fn get_invocation_history(
     State(state): State<ToolsAppState>,
     Path(cycle_id_str): Path<String>,
@@ -245,6 +274,16 @@ pub async fn get_invocation_history(
 /// Get pending revisit suggestions for a cycle.
 ///
 /// GET /tools/revisits/:cycle_id
+#[utoipa::path(
+    get,
+    path = "/api/tools/revisits/{cycle_id}",
+    tag = "tools",
+    params(("cycle_id" = String, Path, description = "Cycle ID"), RevisitSuggestionsQuery),
+    responses(
+        (status = 200, description = "Pending revisit suggestions", body = RevisitSuggestionsResponse),
+        (status = 400, description = "Invalid cycle ID", body = RevisitSuggestionsResponse),
+    )
+)]
 pub async fn get_revisit_suggestions(
     State(state): State<ToolsAppState>,
     Path(cycle_id_str): Path<String>,
@@ -326,6 +365,18 @@ pub async fn get_revisit_suggestions(
 /// Dismiss a revisit suggestion.
 ///
 /// POST /tools/revisits/:id/dismiss
+#[utoipa::path(
+    post,
+    path = "/api/tools/revisits/{id}/dismiss",
+    tag = "tools",
+    params(("id" = String, Path, description = "Revisit suggestion ID")),
+    request_body = DismissRevisitRequest,
+    responses(
+        (status = 200, description = "Suggestion dismissed", body = SuccessResponse),
+        (status = 400, description = "Invalid suggestion ID", body = SuccessResponse),
+        (status = 404, description = "Suggestion not found", body = SuccessResponse),
+    )
+)]
 pub async fn dismiss_revisit(
     State(state): State<ToolsAppState>,
     Path(revisit_id): Path<String>,
@@ -385,6 +436,16 @@ let id = match revisit_id.parse::<RevisitSuggestionId>() {
 /// Get pending confirmation requests for a cycle.
 ///
 /// GET /tools/confirmations/:cycle_id
+#[utoipa::path(
+    get,
+    path = "/api/tools/confirmations/{cycle_id}",
+    tag = "tools",
+    params(("cycle_id" = String, Path, description = "Cycle ID"), ConfirmationsQuery),
+    responses(
+        (status = 200, description = "Confirmation requests", body = ConfirmationsResponse),
+        (status = 400, description = "Invalid cycle ID", body = ConfirmationsResponse),
+    )
+)]
 pub async fn get_confirmations(
     State(state): State<ToolsAppState>,
     Path(cycle_id_str): Path<String>,
@@ -445,6 +506,18 @@ pub async fn get_confirmations(
 /// Respond to a confirmation request.
 ///
 /// POST /tools/confirmations/:id/respond
+#[utoipa::path(
+    post,
+    path = "/api/tools/confirmations/{id}/respond",
+    tag = "tools",
+    params(("id" = String, Path, description = "Confirmation request ID")),
+    request_body = RespondToConfirmationRequest,
+    responses(
+        (status = 200, description = "Response recorded", body = SuccessResponse),
+        (status = 400, description = "Invalid ID or choice", body = SuccessResponse),
+        (status = 404, description = "Confirmation not found", body = SuccessResponse),
+    )
+)]
 pub async fn respond_to_confirmation(
     State(state): State<ToolsAppState>,
     Path(confirmation_id): Path<String>,
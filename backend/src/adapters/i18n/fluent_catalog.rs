@@ -0,0 +1,301 @@
+//! Built-in API message catalogs in Fluent syntax.
+//!
+//! Catalogs live in `locales/<locale>.ftl` and are compiled into the binary.
+//! Only the subset of Fluent the API needs is supported: `# comments`,
+//! `key = value` messages with indented continuation lines, and `{ $var }`
+//! or `{ "literal" }` placeables. Every locale must define every key in the
+//! English catalog; the tests below enforce it.
+
+use std::collections::HashMap;
+
+use thiserror::Error;
+
+use crate::ports::{MessageCatalog, DEFAULT_EMAIL_LOCALE};
+
+/// Locales with built-in catalogs, default first.
+const CATALOGS: [(&str, &str); 3] = [
+    (DEFAULT_EMAIL_LOCALE, include_str!("locales/en.ftl")),
+    ("es", include_str!("locales/es.ftl")),
+    ("fr", include_str!("locales/fr.ftl")),
+];
+
+/// A catalog source that is not valid Fluent (for the supported subset).
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+#[error("{locale}.ftl line {line}: {reason}")]
+pub struct FtlParseError {
+    pub locale: String,
+    pub line: usize,
+    pub reason: String,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Segment {
+    Text(String),
+    Variable(String),
+}
+
+type Messages = HashMap<String, Vec<Segment>>;
+
+/// Serves the built-in message catalogs.
+pub struct FluentMessageCatalog {
+    catalogs: HashMap<&'static str, Messages>,
+}
+
+impl FluentMessageCatalog {
+    /// Parses the built-in catalogs.
+    ///
+    /// # Panics
+    ///
+    /// If a built-in catalog does not parse; the tests below catch that.
+    pub fn new() -> Self {
+        Self::from_sources(&CATALOGS)
+            .unwrap_or_else(|e| panic!("Built-in catalog is invalid: {}", e))
+    }
+
+    /// Parses catalogs from `(locale, source)` pairs.
+    pub fn from_sources(sources: &[(&'static str, &str)]) -> Result<Self, FtlParseError> {
+        let catalogs = sources
+            .iter()
+            .map(|(locale, source)| Ok((*locale, parse_ftl(locale, source)?)))
+            .collect::<Result<_, FtlParseError>>()?;
+        Ok(Self { catalogs })
+    }
+}
+
+impl Default for FluentMessageCatalog {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MessageCatalog for FluentMessageCatalog {
+    fn format(&self, locale: &str, key: &str, args: &[(&str, &str)]) -> Option<String> {
+        let segments = self.catalogs.get(locale)?.get(key)?;
+        let mut output = String::new();
+        for segment in segments {
+            match segment {
+                Segment::Text(text) => output.push_str(text),
+                Segment::Variable(name) => match args.iter().find(|(n, _)| n == name) {
+                    Some((_, value)) => output.push_str(value),
+                    // Fluent's own rendering of an unresolved variable
+                    None => output.push_str(&format!("{{${}}}", name)),
+                },
+            }
+        }
+        Some(output)
+    }
+
+    fn locales(&self) -> Vec<&'static str> {
+        CATALOGS
+            .iter()
+            .map(|(locale, _)| *locale)
+            .filter(|locale| self.catalogs.contains_key(locale))
+            .collect()
+    }
+}
+
+fn parse_ftl(locale: &str, source: &str) -> Result<Messages, FtlParseError> {
+    let error = |line: usize, reason: &str| FtlParseError {
+        locale: locale.to_string(),
+        line,
+        reason: reason.to_string(),
+    };
+
+    let mut entries: Vec<(usize, String, String)> = Vec::new();
+    for (index, raw) in source.lines().enumerate() {
+        let line_no = index + 1;
+        if raw.trim().is_empty() || raw.starts_with('#') {
+            continue;
+        }
+        if raw.starts_with(' ') || raw.starts_with('\t') {
+            let Some((_, _, value)) = entries.last_mut() else {
+                return Err(error(line_no, "continuation line without a message"));
+            };
+            if !value.is_empty() {
+                value.push('\n');
+            }
+            value.push_str(raw.trim());
+            continue;
+        }
+        let Some((key, value)) = raw.split_once('=') else {
+            return Err(error(line_no, "expected `key = value`"));
+        };
+        let key = key.trim();
+        let valid_key = key.starts_with(|c: char| c.is_ascii_alphabetic())
+            && key
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_');
+        if !valid_key {
+            return Err(error(line_no, "invalid message key"));
+        }
+        if entries.iter().any(|(_, k, _)| k == key) {
+            return Err(error(line_no, "duplicate message key"));
+        }
+        entries.push((line_no, key.to_string(), value.trim().to_string()));
+    }
+
+    entries
+        .into_iter()
+        .map(|(line_no, key, value)| {
+            if value.is_empty() {
+                return Err(error(line_no, "message has no value"));
+            }
+            let segments = parse_pattern(&value).map_err(|reason| error(line_no, reason))?;
+            Ok((key, segments))
+        })
+        .collect()
+}
+
+fn parse_pattern(value: &str) -> Result<Vec<Segment>, &'static str> {
+    let mut segments = Vec::new();
+    let mut text = String::new();
+    let mut rest = value;
+    while let Some(start) = rest.find(['{', '}']) {
+        if rest[start..].starts_with('}') {
+            return Err("unbalanced `}`");
+        }
+        text.push_str(&rest[..start]);
+        // A string literal may itself contain braces
+        let body_start =
+            start + 1 + (rest[start + 1..].len() - rest[start + 1..].trim_start().len());
+        let search_from = if rest[body_start..].starts_with('"') {
+            rest[body_start + 1..]
+                .find('"')
+                .ok_or("unclosed string literal")?
+                + body_start
+                + 2
+        } else {
+            body_start
+        };
+        let end = rest[search_from..].find('}').ok_or("unclosed placeable")? + search_from;
+        let inner = rest[start + 1..end].trim();
+        if let Some(name) = inner.strip_prefix('$') {
+            if name.is_empty() || !name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_') {
+                return Err("invalid variable name");
+            }
+            if !text.is_empty() {
+                segments.push(Segment::Text(std::mem::take(&mut text)));
+            }
+            segments.push(Segment::Variable(name.to_string()));
+        } else if let Some(literal) = inner.strip_prefix('"').and_then(|s| s.strip_suffix('"')) {
+            text.push_str(literal);
+        } else {
+            return Err("unsupported placeable");
+        }
+        rest = &rest[end + 1..];
+    }
+    text.push_str(rest);
+    if !text.is_empty() {
+        segments.push(Segment::Text(text));
+    }
+    Ok(segments)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::foundation::ComponentType;
+    use crate::domain::proact::DQ_ELEMENT_NAMES;
+    use crate::ports::{localize_component, localize_dq_element};
+
+    #[test]
+    fn built_in_catalogs_parse() {
+        let catalog = FluentMessageCatalog::new();
+        assert_eq!(catalog.locales(), vec!["en", "es", "fr"]);
+    }
+
+    #[test]
+    fn every_locale_defines_every_english_key() {
+        let catalog = FluentMessageCatalog::new();
+        let english = &catalog.catalogs["en"];
+        for locale in ["es", "fr"] {
+            let messages = &catalog.catalogs[locale];
+            let mut missing: Vec<_> = english
+                .keys()
+                .filter(|k| !messages.contains_key(*k))
+                .collect();
+            missing.sort();
+            assert!(missing.is_empty(), "{} is missing {:?}", locale, missing);
+            let mut extra: Vec<_> = messages
+                .keys()
+                .filter(|k| !english.contains_key(*k))
+                .collect();
+            extra.sort();
+            assert!(extra.is_empty(), "{} has unknown keys {:?}", locale, extra);
+        }
+    }
+
+    #[test]
+    fn english_catalog_matches_built_in_names() {
+        let catalog = FluentMessageCatalog::new();
+        for component in ComponentType::all() {
+            assert_eq!(
+                localize_component(&catalog, "en", *component),
+                component.display_name()
+            );
+        }
+        for name in DQ_ELEMENT_NAMES {
+            let key = format!("dq-{}", name.to_ascii_lowercase().replace(' ', "-"));
+            assert!(catalog.format("en", &key, &[]).is_some(), "missing {}", key);
+            assert_eq!(localize_dq_element(&catalog, "en", name), *name);
+        }
+    }
+
+    #[test]
+    fn formats_variables_and_literals() {
+        let catalog = FluentMessageCatalog::from_sources(&[(
+            "en",
+            "greeting = Hi { $name }, use {\"{\"}braces{ \"}\" }\nmulti = First\n    second\n",
+        )])
+        .unwrap();
+        assert_eq!(
+            catalog
+                .format("en", "greeting", &[("name", "Jo")])
+                .as_deref(),
+            Some("Hi Jo, use {braces}")
+        );
+        assert_eq!(
+            catalog.format("en", "greeting", &[]).as_deref(),
+            Some("Hi {$name}, use {braces}")
+        );
+        assert_eq!(
+            catalog.format("en", "multi", &[]).as_deref(),
+            Some("First\nsecond")
+        );
+        assert_eq!(catalog.format("es", "greeting", &[]), None);
+    }
+
+    #[test]
+    fn localize_falls_back_to_language_then_default() {
+        let catalog = FluentMessageCatalog::new();
+        assert_eq!(
+            catalog.localize("es-MX", "error-not-found", &[]).as_deref(),
+            Some("No se encontró el recurso solicitado.")
+        );
+        assert_eq!(
+            catalog.localize("de", "error-not-found", &[]).as_deref(),
+            Some("The requested resource was not found.")
+        );
+    }
+
+    #[test]
+    fn rejects_malformed_sources() {
+        let cases = [
+            ("= value", 1),
+            ("key value", 1),
+            ("a = 1\na = 2", 2),
+            ("a = { $ }", 1),
+            ("a = { fn() }", 1),
+            ("a = open {", 1),
+            ("a = stray }", 1),
+            ("  orphan", 1),
+            ("a =", 1),
+        ];
+        for (source, line) in cases {
+            let err = FluentMessageCatalog::from_sources(&[("en", source)])
+                .err()
+                .unwrap_or_else(|| panic!("{:?} should not parse", source));
+            assert_eq!(err.line, line, "{:?}: {}", source, err);
+        }
+    }
+}
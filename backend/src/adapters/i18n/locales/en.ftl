@@ -0,0 +1,83 @@
+# Choice Sherpa API messages - English (source locale).
+#
+# Keys are shared by every locale; placeholders use Fluent syntax.
+
+## PrOACT components
+
+component-issue-raising = Issue Raising
+component-problem-frame = Problem Frame
+component-stakeholder-analysis = Stakeholder Analysis
+component-objectives = Objectives
+component-alternatives = Alternatives
+component-consequences = Consequences
+component-uncertainty-register = Uncertainty Register
+component-tradeoffs = Tradeoffs
+component-recommendation = Recommendation
+component-decision-quality = Decision Quality
+component-notes-next-steps = Notes & Next Steps
+
+## Next actions
+
+next-action-start = Start { $component }
+next-action-continue = Continue { $component }
+next-action-revise = Revise { $component }
+next-action-complete-cycle = Complete the cycle
+next-action-already-complete = Cycle is complete
+
+## Decision Quality elements
+
+dq-helpful-problem-frame = Helpful Problem Frame
+dq-clear-objectives = Clear Objectives
+dq-creative-alternatives = Creative Alternatives
+dq-reliable-consequence-information = Reliable Consequence Information
+dq-logically-correct-reasoning = Logically Correct Reasoning
+dq-clear-tradeoffs = Clear Tradeoffs
+dq-commitment-to-follow-through = Commitment to Follow Through
+
+## Errors
+
+error-validation-failed = The request contains invalid data.
+error-empty-field = A required field is empty.
+error-out-of-range = A value is out of the allowed range.
+error-invalid-format = A value has an invalid format.
+error-bad-request = The request could not be processed.
+error-session-not-found = The session was not found.
+error-cycle-not-found = The decision cycle was not found.
+error-component-not-found = The component was not found.
+error-conversation-not-found = The conversation was not found.
+error-not-found = The requested resource was not found.
+error-invalid-state-transition = This action is not allowed in the current state.
+error-session-archived = The session is archived.
+error-cycle-archived = The decision cycle is archived.
+error-component-locked = The component is locked.
+error-component-already-started = The component has already been started.
+error-previous-component-required = Complete the previous component first.
+error-invalid-component-output = The component output is invalid.
+error-cannot-branch = The cycle cannot be branched at this point.
+error-conflict = The request conflicts with the current state.
+error-unauthorized = You need to sign in.
+error-unauthenticated = You need to sign in.
+error-authentication-required = You need to sign in.
+error-forbidden = You do not have access to this resource.
+error-consent-required = Please accept the required consents to use AI features.
+error-ai-provider-error = The AI assistant is temporarily unavailable.
+error-rate-limited = Too many requests. Please try again shortly.
+error-rate-limit-exceeded = Too many requests. Please try again shortly.
+error-limit-reached = You have reached the limit for your plan.
+error-payment-required = A paid plan is required for this feature.
+error-payment-failed = The payment could not be processed.
+error-membership-not-found = No membership was found.
+error-membership-exists = A membership already exists.
+error-membership-expired = Your membership has expired.
+error-invalid-tier = The membership tier is invalid.
+error-invalid-promo-code = The promo code is invalid.
+error-promo-code-exhausted = The promo code has no uses left.
+error-invalid-webhook-signature = The webhook signature is invalid.
+error-payload-too-large = The request is too large.
+error-database-error = Something went wrong. Please try again.
+error-cache-error = Something went wrong. Please try again.
+error-external-service-error = An external service is unavailable.
+error-bad-gateway = An external service is unavailable.
+error-service-unavailable = The service is temporarily unavailable.
+error-service-overloaded = The service is busy. Please try again shortly.
+error-internal-error = Something went wrong. Please try again.
@@ -0,0 +1,81 @@
+# Choice Sherpa API messages - Spanish.
+
+## PrOACT components
+
+component-issue-raising = Planteamiento del asunto
+component-problem-frame = Marco del problema
+component-stakeholder-analysis = Análisis de partes interesadas
+component-objectives = Objetivos
+component-alternatives = Alternativas
+component-consequences = Consecuencias
+component-uncertainty-register = Registro de incertidumbres
+component-tradeoffs = Compensaciones
+component-recommendation = Recomendación
+component-decision-quality = Calidad de la decisión
+component-notes-next-steps = Notas y próximos pasos
+
+## Next actions
+
+next-action-start = Empezar { $component }
+next-action-continue = Continuar { $component }
+next-action-revise = Revisar { $component }
+next-action-complete-cycle = Completar el ciclo
+next-action-already-complete = El ciclo está completo
+
+## Decision Quality elements
+
+dq-helpful-problem-frame = Marco del problema útil
+dq-clear-objectives = Objetivos claros
+dq-creative-alternatives = Alternativas creativas
+dq-reliable-consequence-information = Información fiable sobre consecuencias
+dq-logically-correct-reasoning = Razonamiento lógicamente correcto
+dq-clear-tradeoffs = Compensaciones claras
+dq-commitment-to-follow-through = Compromiso de llevarla a cabo
+
+## Errors
+
+error-validation-failed = La solicitud contiene datos no válidos.
+error-empty-field = Falta un campo obligatorio.
+error-out-of-range = Un valor está fuera del rango permitido.
+error-invalid-format = Un valor tiene un formato no válido.
+error-bad-request = No se pudo procesar la solicitud.
+error-session-not-found = No se encontró la sesión.
+error-cycle-not-found = No se encontró el ciclo de decisión.
+error-component-not-found = No se encontró el componente.
+error-conversation-not-found = No se encontró la conversación.
+error-not-found = No se encontró el recurso solicitado.
+error-invalid-state-transition = Esta acción no está permitida en el estado actual.
+error-session-archived = La sesión está archivada.
+error-cycle-archived = El ciclo de decisión está archivado.
+error-component-locked = El componente está bloqueado.
+error-component-already-started = El componente ya se ha iniciado.
+error-previous-component-required = Completa primero el componente anterior.
+error-invalid-component-output = El resultado del componente no es válido.
+error-cannot-branch = No se puede ramificar el ciclo en este punto.
+error-conflict = La solicitud entra en conflicto con el estado actual.
+error-unauthorized = Necesitas iniciar sesión.
+error-unauthenticated = Necesitas iniciar sesión.
+error-authentication-required = Necesitas iniciar sesión.
+error-forbidden = No tienes acceso a este recurso.
+error-consent-required = Acepta los consentimientos necesarios para usar las funciones de IA.
+error-ai-provider-error = El asistente de IA no está disponible temporalmente.
+error-rate-limited = Demasiadas solicitudes. Inténtalo de nuevo en breve.
+error-rate-limit-exceeded = Demasiadas solicitudes. Inténtalo de nuevo en breve.
+error-limit-reached = Has alcanzado el límite de tu plan.
+error-payment-required = Esta función requiere un plan de pago.
+error-payment-failed = No se pudo procesar el pago.
+error-membership-not-found = No se encontró ninguna suscripción.
+error-membership-exists = Ya existe una suscripción.
+error-membership-expired = Tu suscripción ha caducado.
+error-invalid-tier = El nivel de suscripción no es válido.
+error-invalid-promo-code = El código promocional no es válido.
+error-promo-code-exhausted = El código promocional ya no tiene usos disponibles.
+error-invalid-webhook-signature = La firma del webhook no es válida.
+error-payload-too-large = La solicitud es demasiado grande.
+error-database-error = Algo salió mal. Inténtalo de nuevo.
+error-cache-error = Algo salió mal. Inténtalo de nuevo.
+error-external-service-error = Un servicio externo no está disponible.
+error-bad-gateway = Un servicio externo no está disponible.
+error-service-unavailable = El servicio no está disponible temporalmente.
+error-service-overloaded = El servicio está ocupado. Inténtalo de nuevo en breve.
+error-internal-error = Algo salió mal. Inténtalo de nuevo.
@@ -0,0 +1,81 @@
+# Choice Sherpa API messages - French.
+
+## PrOACT components
+
+component-issue-raising = Identification du sujet
+component-problem-frame = Cadrage du problème
+component-stakeholder-analysis = Analyse des parties prenantes
+component-objectives = Objectifs
+component-alternatives = Options
+component-consequences = Conséquences
+component-uncertainty-register = Registre des incertitudes
+component-tradeoffs = Arbitrages
+component-recommendation = Recommandation
+component-decision-quality = Qualité de la décision
+component-notes-next-steps = Notes et prochaines étapes
+
+## Next actions
+
+next-action-start = Commencer : { $component }
+next-action-continue = Continuer : { $component }
+next-action-revise = Réviser : { $component }
+next-action-complete-cycle = Terminer le cycle
+next-action-already-complete = Le cycle est terminé
+
+## Decision Quality elements
+
+dq-helpful-problem-frame = Cadrage utile du problème
+dq-clear-objectives = Objectifs clairs
+dq-creative-alternatives = Options créatives
+dq-reliable-consequence-information = Informations fiables sur les conséquences
+dq-logically-correct-reasoning = Raisonnement logiquement correct
+dq-clear-tradeoffs = Arbitrages clairs
+dq-commitment-to-follow-through = Engagement à passer à l'action
+
+## Errors
+
+error-validation-failed = La requête contient des données invalides.
+error-empty-field = Un champ obligatoire est vide.
+error-out-of-range = Une valeur est hors de la plage autorisée.
+error-invalid-format = Une valeur a un format invalide.
+error-bad-request = La requête n'a pas pu être traitée.
+error-session-not-found = La session est introuvable.
+error-cycle-not-found = Le cycle de décision est introuvable.
+error-component-not-found = Le composant est introuvable.
+error-conversation-not-found = La conversation est introuvable.
+error-not-found = La ressource demandée est introuvable.
+error-invalid-state-transition = Cette action n'est pas autorisée dans l'état actuel.
+error-session-archived = La session est archivée.
+error-cycle-archived = Le cycle de décision est archivé.
+error-component-locked = Le composant est verrouillé.
+error-component-already-started = Le composant a déjà été commencé.
+error-previous-component-required = Terminez d'abord le composant précédent.
+error-invalid-component-output = Le résultat du composant est invalide.
+error-cannot-branch = Le cycle ne peut pas être dupliqué à ce stade.
+error-conflict = La requête est en conflit avec l'état actuel.
+error-unauthorized = Vous devez vous connecter.
+error-unauthenticated = Vous devez vous connecter.
+error-authentication-required = Vous devez vous connecter.
+error-forbidden = Vous n'avez pas accès à cette ressource.
+error-consent-required = Veuillez accepter les consentements requis pour utiliser les fonctions d'IA.
+error-ai-provider-error = L'assistant IA est temporairement indisponible.
+error-rate-limited = Trop de requêtes. Réessayez dans un instant.
+error-rate-limit-exceeded = Trop de requêtes. Réessayez dans un instant.
+error-limit-reached = Vous avez atteint la limite de votre offre.
+error-payment-required = Cette fonctionnalité nécessite une offre payante.
+error-payment-failed = Le paiement n'a pas pu être traité.
+error-membership-not-found = Aucun abonnement n'a été trouvé.
+error-membership-exists = Un abonnement existe déjà.
+error-membership-expired = Votre abonnement a expiré.
+error-invalid-tier = Le niveau d'abonnement est invalide.
+error-invalid-promo-code = Le code promo est invalide.
+error-promo-code-exhausted = Le code promo n'a plus d'utilisations disponibles.
+error-invalid-webhook-signature = La signature du webhook est invalide.
+error-payload-too-large = La requête est trop volumineuse.
+error-database-error = Une erreur s'est produite. Veuillez réessayer.
+error-cache-error = Une erreur s'est produite. Veuillez réessayer.
+error-external-service-error = Un service externe est indisponible.
+error-bad-gateway = Un service externe est indisponible.
+error-service-unavailable = Le service est temporairement indisponible.
+error-service-overloaded = Le service est occupé. Réessayez dans un instant.
+error-internal-error = Une erreur s'est produite. Veuillez réessayer.
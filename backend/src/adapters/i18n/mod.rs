@@ -0,0 +1,8 @@
+//! Internationalization adapters - Implementations of the `MessageCatalog` port.
+//!
+//! - `FluentMessageCatalog` - Built-in per-locale catalogs in Fluent syntax,
+//!   compiled from `locales/`
+
+mod fluent_catalog;
+
+pub use fluent_catalog::{FluentMessageCatalog, FtlParseError};
@@ -0,0 +1,129 @@
+//! Email action - Implementation of IntegrationActionAdapter.
+//!
+//! Sends the rendered payload as a plain-text email with the rendered
+//! subject.
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use crate::domain::integration::{Integration, IntegrationAction, IntegrationActionKind};
+use crate::ports::{
+    EmailMessage, EmailSender, IntegrationActionAdapter, IntegrationActionError,
+    IntegrationPayload,
+};
+
+/// Emails integration payloads.
+pub struct EmailActionAdapter {
+    email_sender: Arc<dyn EmailSender>,
+}
+
+impl EmailActionAdapter {
+    pub fn new(email_sender: Arc<dyn EmailSender>) -> Self {
+        Self { email_sender }
+    }
+}
+
+#[async_trait]
+impl IntegrationActionAdapter for EmailActionAdapter {
+    fn kind(&self) -> IntegrationActionKind {
+        IntegrationActionKind::Email
+    }
+
+    async fn execute(
+        &self,
+        integration: &Integration,
+        payload: &IntegrationPayload,
+    ) -> Result<(), IntegrationActionError> {
+        let IntegrationAction::Email { to, subject } = &integration.action else {
+            return Err(IntegrationActionError::Rejected(
+                "Not an email integration".to_string(),
+            ));
+        };
+
+        let message = EmailMessage {
+            to: to.clone(),
+            // Subjects are single-line
+            subject: payload
+                .subject
+                .as_deref()
+                .unwrap_or(subject)
+                .replace(['\r', '\n'], " "),
+            text_body: payload.body.clone(),
+            attachments: Vec::new(),
+        };
+        self.email_sender.send(&message).await.map_err(|e| {
+            if e.is_retryable() {
+                IntegrationActionError::Unavailable(e.to_string())
+            } else {
+                IntegrationActionError::Rejected(e.to_string())
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::adapters::email::InMemoryEmailSender;
+    use crate::domain::foundation::{IntegrationDeliveryId, UserId};
+    use crate::domain::integration::EventFilter;
+    use crate::ports::EmailError;
+
+    fn integration() -> Integration {
+        Integration::new(
+            UserId::new("user-1").unwrap(),
+            "Digest",
+            EventFilter {
+                event_types: vec!["cycle.completed.v1".to_string()],
+                conditions: Vec::new(),
+            },
+            IntegrationAction::Email {
+                to: "team@example.com".to_string(),
+                subject: "Decision made".to_string(),
+            },
+            None,
+        )
+        .unwrap()
+    }
+
+    fn payload(subject: Option<&str>) -> IntegrationPayload {
+        IntegrationPayload {
+            delivery_id: IntegrationDeliveryId::new(),
+            event_type: "cycle.completed.v1".to_string(),
+            body: "A decision was made.".to_string(),
+            subject: subject.map(str::to_string),
+        }
+    }
+
+    #[tokio::test]
+    async fn sends_the_rendered_subject_on_one_line() {
+        let sender = Arc::new(InMemoryEmailSender::new());
+        let adapter = EmailActionAdapter::new(sender.clone());
+
+        adapter
+            .execute(&integration(), &payload(Some("Done:\nLisbon")))
+            .await
+            .unwrap();
+
+        let sent = sender.sent();
+        assert_eq!(sent[0].to, "team@example.com");
+        assert_eq!(sent[0].subject, "Done: Lisbon");
+        assert_eq!(sent[0].text_body, "A decision was made.");
+    }
+
+    #[tokio::test]
+    async fn provider_outages_are_retryable() {
+        let sender = Arc::new(InMemoryEmailSender::new());
+        sender.fail_next(EmailError::Unavailable("timeout".to_string()));
+        let adapter = EmailActionAdapter::new(sender);
+
+        let error = adapter
+            .execute(&integration(), &payload(None))
+            .await
+            .unwrap_err();
+
+        assert!(error.is_retryable());
+    }
+}
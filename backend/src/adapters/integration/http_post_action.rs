@@ -0,0 +1,140 @@
+//! HTTP POST action - Implementation of IntegrationActionAdapter.
+//!
+//! Sends the rendered payload as the request body: `application/json` when
+//! it parses as JSON, plain text otherwise. Every request names the event
+//! and carries the delivery ID, which stays the same across retries so
+//! receivers can deduplicate. 429 and 5xx responses and network errors are
+//! retried; other non-2xx responses are not.
+//!
+//! # Configuration
+//!
+//! ```ignore
+//! let adapter = Arc::new(HttpPostActionAdapter::new(Duration::from_secs(10)));
+//! ```
+
+use std::time::Duration;
+
+use async_trait::async_trait;
+use reqwest::{header, redirect, Client, StatusCode};
+
+use crate::domain::integration::{Integration, IntegrationAction, IntegrationActionKind};
+use crate::ports::{IntegrationActionAdapter, IntegrationActionError, IntegrationPayload};
+
+/// Header naming the triggering event type.
+pub const EVENT_HEADER: &str = "x-choice-sherpa-event";
+
+/// Header carrying the delivery ID.
+pub const DELIVERY_HEADER: &str = "x-choice-sherpa-delivery";
+
+/// Longest error body kept for the delivery log.
+const MAX_ERROR_BODY_CHARS: usize = 200;
+
+/// POSTs integration payloads.
+pub struct HttpPostActionAdapter {
+    client: Client,
+}
+
+impl HttpPostActionAdapter {
+    /// Creates an adapter whose requests time out after `timeout`.
+    pub fn new(timeout: Duration) -> Self {
+        // URLs are checked for public hosts when saved; following redirects
+        // would let a response send us somewhere else.
+        let client = Client::builder()
+            .timeout(timeout)
+            .redirect(redirect::Policy::none())
+            .build()
+            .expect("Failed to create HTTP client");
+        Self { client }
+    }
+}
+
+impl Default for HttpPostActionAdapter {
+    fn default() -> Self {
+        Self::new(Duration::from_secs(10))
+    }
+}
+
+#[async_trait]
+impl IntegrationActionAdapter for HttpPostActionAdapter {
+    fn kind(&self) -> IntegrationActionKind {
+        IntegrationActionKind::HttpPost
+    }
+
+    #[tracing::instrument(name = "HttpPostActionAdapter::execute", skip_all, err)]
+    async fn execute(
+        &self,
+        integration: &Integration,
+        payload: &IntegrationPayload,
+    ) -> Result<(), IntegrationActionError> {
+        let IntegrationAction::HttpPost { url } = &integration.action else {
+            return Err(IntegrationActionError::Rejected(
+                "Not an HTTP POST integration".to_string(),
+            ));
+        };
+
+        let response = self
+            .client
+            .post(url)
+            .header(header::CONTENT_TYPE, content_type(&payload.body))
+            .header(EVENT_HEADER, &payload.event_type)
+            .header(DELIVERY_HEADER, payload.delivery_id.to_string())
+            .body(payload.body.clone())
+            .send()
+            .await
+            .map_err(|e| IntegrationActionError::Unavailable(e.without_url().to_string()))?;
+
+        let status = response.status();
+        if status.is_success() {
+            return Ok(());
+        }
+        let body = response.text().await.unwrap_or_default();
+        Err(status_error(status, &body))
+    }
+}
+
+fn content_type(body: &str) -> &'static str {
+    if serde_json::from_str::<serde_json::Value>(body).is_ok() {
+        "application/json"
+    } else {
+        "text/plain; charset=utf-8"
+    }
+}
+
+fn status_error(status: StatusCode, body: &str) -> IntegrationActionError {
+    let message = if body.is_empty() {
+        status.to_string()
+    } else {
+        let body: String = body.chars().take(MAX_ERROR_BODY_CHARS).collect();
+        format!("{}: {}", status, body)
+    };
+    if status == StatusCode::TOO_MANY_REQUESTS || status.is_server_error() {
+        IntegrationActionError::Unavailable(message)
+    } else {
+        IntegrationActionError::Rejected(message)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn json_bodies_are_sent_as_json() {
+        assert_eq!(content_type(r#"{"a": 1}"#), "application/json");
+        assert_eq!(content_type("cycle completed"), "text/plain; charset=utf-8");
+    }
+
+    #[test]
+    fn throttling_and_server_errors_are_retryable() {
+        assert!(status_error(StatusCode::TOO_MANY_REQUESTS, "").is_retryable());
+        assert!(status_error(StatusCode::BAD_GATEWAY, "").is_retryable());
+        assert!(!status_error(StatusCode::NOT_FOUND, "").is_retryable());
+        assert!(!status_error(StatusCode::FOUND, "").is_retryable());
+    }
+
+    #[test]
+    fn error_bodies_are_truncated() {
+        let error = status_error(StatusCode::BAD_REQUEST, &"x".repeat(1000));
+        assert!(error.to_string().len() < 300);
+    }
+}
@@ -0,0 +1,120 @@
+//! In-memory integration and integration delivery repositories for testing
+//! and development.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use tokio::sync::RwLock;
+
+use crate::domain::foundation::{
+    DomainError, IntegrationDeliveryId, IntegrationId, Timestamp, UserId,
+};
+use crate::domain::integration::{Integration, IntegrationDelivery};
+use crate::ports::{IntegrationDeliveryRepository, IntegrationRepository};
+
+/// In-memory integrations keyed by ID.
+#[derive(Debug, Clone, Default)]
+pub struct InMemoryIntegrationRepository {
+    integrations: Arc<RwLock<HashMap<IntegrationId, Integration>>>,
+}
+
+impl InMemoryIntegrationRepository {
+    /// Create an empty repository.
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait]
+impl IntegrationRepository for InMemoryIntegrationRepository {
+    async fn save(&self, integration: &Integration) -> Result<(), DomainError> {
+        self.integrations
+            .write()
+            .await
+            .insert(integration.id, integration.clone());
+        Ok(())
+    }
+
+    async fn find_by_id(&self, id: &IntegrationId) -> Result<Option<Integration>, DomainError> {
+        Ok(self.integrations.read().await.get(id).cloned())
+    }
+
+    async fn list_for_user(&self, user_id: &UserId) -> Result<Vec<Integration>, DomainError> {
+        let mut integrations: Vec<Integration> = self
+            .integrations
+            .read()
+            .await
+            .values()
+            .filter(|i| &i.user_id == user_id)
+            .cloned()
+            .collect();
+        integrations.sort_by_key(|i| i.created_at);
+        Ok(integrations)
+    }
+
+    async fn delete(&self, id: &IntegrationId) -> Result<(), DomainError> {
+        self.integrations.write().await.remove(id);
+        Ok(())
+    }
+}
+
+/// In-memory integration deliveries keyed by ID.
+#[derive(Debug, Clone, Default)]
+pub struct InMemoryIntegrationDeliveryRepository {
+    deliveries: Arc<RwLock<HashMap<IntegrationDeliveryId, IntegrationDelivery>>>,
+}
+
+impl InMemoryIntegrationDeliveryRepository {
+    /// Create an empty repository.
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait]
+impl IntegrationDeliveryRepository for InMemoryIntegrationDeliveryRepository {
+    async fn save(&self, delivery: &IntegrationDelivery) -> Result<(), DomainError> {
+        self.deliveries
+            .write()
+            .await
+            .insert(delivery.id, delivery.clone());
+        Ok(())
+    }
+
+    async fn list_due(
+        &self,
+        now: Timestamp,
+        limit: u32,
+    ) -> Result<Vec<IntegrationDelivery>, DomainError> {
+        let mut due: Vec<IntegrationDelivery> = self
+            .deliveries
+            .read()
+            .await
+            .values()
+            .filter(|d| d.is_due(now))
+            .cloned()
+            .collect();
+        due.sort_by_key(|d| d.next_attempt_at);
+        due.truncate(limit as usize);
+        Ok(due)
+    }
+
+    async fn list_for_integration(
+        &self,
+        integration_id: &IntegrationId,
+        limit: u32,
+    ) -> Result<Vec<IntegrationDelivery>, DomainError> {
+        let mut deliveries: Vec<IntegrationDelivery> = self
+            .deliveries
+            .read()
+            .await
+            .values()
+            .filter(|d| &d.integration_id == integration_id)
+            .cloned()
+            .collect();
+        deliveries.sort_by_key(|d| std::cmp::Reverse(d.created_at));
+        deliveries.truncate(limit as usize);
+        Ok(deliveries)
+    }
+}
@@ -0,0 +1,22 @@
+//! Integration adapters - Actions, templating, and in-memory storage.
+//!
+//! - `HttpPostActionAdapter` - POSTs the payload to the integration's URL
+//! - `SlackActionAdapter` - Posts the payload through a connected Slack workspace
+//! - `EmailActionAdapter` - Emails the payload
+//! - `TeraIntegrationTemplateEngine` - Sandboxed Tera rendering of payload templates
+//! - `InMemoryIntegrationRepository` / `InMemoryIntegrationDeliveryRepository` -
+//!   Storage for tests and development
+//!
+//! The Postgres-backed repositories live in `adapters::postgres`.
+
+mod email_action;
+mod http_post_action;
+mod in_memory;
+mod slack_action;
+mod template_engine;
+
+pub use email_action::EmailActionAdapter;
+pub use http_post_action::{HttpPostActionAdapter, DELIVERY_HEADER, EVENT_HEADER};
+pub use in_memory::{InMemoryIntegrationDeliveryRepository, InMemoryIntegrationRepository};
+pub use slack_action::SlackActionAdapter;
+pub use template_engine::{TeraIntegrationTemplateEngine, MAX_RENDERED_PAYLOAD_BYTES};
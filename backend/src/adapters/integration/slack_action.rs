@@ -0,0 +1,66 @@
+//! Slack action - Implementation of IntegrationActionAdapter.
+//!
+//! Posts the rendered payload as a message through one of the owner's
+//! connected Slack workspaces, reusing the recommendation-sharing install.
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use crate::domain::integration::{Integration, IntegrationAction, IntegrationActionKind};
+use crate::ports::{
+    IntegrationActionAdapter, IntegrationActionError, IntegrationPayload, SlackClient,
+    SlackError, SlackWorkspaceStore,
+};
+
+/// Posts integration payloads to Slack.
+pub struct SlackActionAdapter {
+    workspaces: Arc<dyn SlackWorkspaceStore>,
+    client: Arc<dyn SlackClient>,
+}
+
+impl SlackActionAdapter {
+    pub fn new(workspaces: Arc<dyn SlackWorkspaceStore>, client: Arc<dyn SlackClient>) -> Self {
+        Self { workspaces, client }
+    }
+}
+
+#[async_trait]
+impl IntegrationActionAdapter for SlackActionAdapter {
+    fn kind(&self) -> IntegrationActionKind {
+        IntegrationActionKind::Slack
+    }
+
+    async fn execute(
+        &self,
+        integration: &Integration,
+        payload: &IntegrationPayload,
+    ) -> Result<(), IntegrationActionError> {
+        let IntegrationAction::Slack { team_id, channel } = &integration.action else {
+            return Err(IntegrationActionError::Rejected(
+                "Not a Slack integration".to_string(),
+            ));
+        };
+
+        let workspace = self
+            .workspaces
+            .get(&integration.user_id, team_id)
+            .await
+            .map_err(|e| IntegrationActionError::Unavailable(e.to_string()))?
+            .ok_or_else(|| {
+                IntegrationActionError::Rejected(format!(
+                    "Slack workspace {} is not connected",
+                    team_id
+                ))
+            })?;
+
+        self.client
+            .post_message(&workspace.bot_token, channel, &payload.body)
+            .await
+            .map(|_| ())
+            .map_err(|e| match e {
+                SlackError::Unavailable(_) => IntegrationActionError::Unavailable(e.to_string()),
+                _ => IntegrationActionError::Rejected(e.to_string()),
+            })
+    }
+}
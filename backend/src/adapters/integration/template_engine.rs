@@ -0,0 +1,84 @@
+//! Tera template engine - Implementation of IntegrationTemplateEngine.
+//!
+//! Payload templates are user-written, so they render in the same
+//! `TemplateSandbox` as document templates: no environment, clock, or
+//! randomness, and bounded source and output sizes.
+
+use serde_json::Value as JsonValue;
+
+use crate::adapters::document::{TemplateError, TemplateSandbox};
+use crate::domain::integration::MAX_TEMPLATE_BYTES;
+use crate::ports::{IntegrationActionError, IntegrationTemplateEngine};
+
+/// Largest rendered payload.
+pub const MAX_RENDERED_PAYLOAD_BYTES: usize = 256 * 1024;
+
+/// Renders integration payload templates with Tera.
+#[derive(Debug, Clone)]
+pub struct TeraIntegrationTemplateEngine {
+    sandbox: TemplateSandbox,
+}
+
+impl TeraIntegrationTemplateEngine {
+    pub fn new() -> Self {
+        Self {
+            sandbox: TemplateSandbox::new(MAX_TEMPLATE_BYTES, MAX_RENDERED_PAYLOAD_BYTES),
+        }
+    }
+}
+
+impl Default for TeraIntegrationTemplateEngine {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl IntegrationTemplateEngine for TeraIntegrationTemplateEngine {
+    fn check(&self, source: &str) -> Result<(), IntegrationActionError> {
+        self.sandbox.compile(source).map_err(template_error)
+    }
+
+    fn render(&self, source: &str, context: &JsonValue) -> Result<String, IntegrationActionError> {
+        self.sandbox.render(source, context).map_err(template_error)
+    }
+}
+
+fn template_error(error: TemplateError) -> IntegrationActionError {
+    IntegrationActionError::InvalidTemplate(error.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn renders_event_fields() {
+        let output = TeraIntegrationTemplateEngine::new()
+            .render(
+                r#"{"text": "{{ event.type }} for {{ payload.cycle_id }}"}"#,
+                &json!({ "event": { "type": "cycle.completed.v1" }, "payload": { "cycle_id": "c-1" } }),
+            )
+            .unwrap();
+        assert_eq!(output, r#"{"text": "cycle.completed.v1 for c-1"}"#);
+    }
+
+    #[test]
+    fn syntax_errors_are_invalid_templates() {
+        let result = TeraIntegrationTemplateEngine::new().check("{% if %}");
+        assert!(matches!(
+            result,
+            Err(IntegrationActionError::InvalidTemplate(_))
+        ));
+    }
+
+    #[test]
+    fn environment_access_is_disabled() {
+        let result = TeraIntegrationTemplateEngine::new()
+            .render(r#"{{ get_env(name="DATABASE_URL") }}"#, &json!({}));
+        assert!(matches!(
+            result,
+            Err(IntegrationActionError::InvalidTemplate(_))
+        ));
+    }
+}
@@ -0,0 +1,114 @@
+//! In-memory usage report ledger for testing and development.
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use tokio::sync::RwLock;
+
+use crate::domain::foundation::{DomainError, Timestamp, UserId};
+use crate::ports::{ReportedUsage, UsageReportRepository};
+
+/// In-memory ledger of reported usage.
+#[derive(Debug, Clone, Default)]
+pub struct InMemoryUsageReportRepository {
+    reports: Arc<RwLock<Vec<ReportedUsage>>>,
+}
+
+impl InMemoryUsageReportRepository {
+    /// Create an empty ledger.
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait]
+impl UsageReportRepository for InMemoryUsageReportRepository {
+    async fn find(
+        &self,
+        user_id: &UserId,
+        usage_day: &Timestamp,
+    ) -> Result<Option<ReportedUsage>, DomainError> {
+        Ok(self
+            .reports
+            .read()
+            .await
+            .iter()
+            .find(|r| &r.user_id == user_id && &r.usage_day == usage_day)
+            .cloned())
+    }
+
+    async fn save(&self, report: &ReportedUsage) -> Result<(), DomainError> {
+        let mut reports = self.reports.write().await;
+        reports.retain(|r| r.user_id != report.user_id || r.usage_day != report.usage_day);
+        reports.push(report.clone());
+        Ok(())
+    }
+
+    async fn list_between(
+        &self,
+        from_day: &Timestamp,
+        to_day: &Timestamp,
+    ) -> Result<Vec<ReportedUsage>, DomainError> {
+        let mut reports: Vec<ReportedUsage> = self
+            .reports
+            .read()
+            .await
+            .iter()
+            .filter(|r| &r.usage_day >= from_day && &r.usage_day <= to_day)
+            .cloned()
+            .collect();
+        reports.sort_by(|a, b| {
+            (a.usage_day, a.user_id.as_str()).cmp(&(b.usage_day, b.user_id.as_str()))
+        });
+        Ok(reports)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn report(user: &str, usage_day: Timestamp, tokens: u64) -> ReportedUsage {
+        let user_id = UserId::new(user).unwrap();
+        ReportedUsage {
+            idempotency_key: ReportedUsage::idempotency_key_for(&user_id, &usage_day),
+            user_id,
+            usage_day,
+            subscription_id: "sub_123".to_string(),
+            tokens,
+            cost_cents: 12,
+            reported_at: Timestamp::now(),
+        }
+    }
+
+    #[tokio::test]
+    async fn saving_a_day_again_replaces_it() {
+        let repo = InMemoryUsageReportRepository::new();
+        let day = Timestamp::start_of_today();
+
+        repo.save(&report("alice", day, 100)).await.unwrap();
+        repo.save(&report("alice", day, 250)).await.unwrap();
+
+        let alice = UserId::new("alice").unwrap();
+        let found = repo.find(&alice, &day).await.unwrap().unwrap();
+        assert_eq!(found.tokens, 250);
+        assert_eq!(repo.list_between(&day, &day).await.unwrap().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn lists_only_days_in_range() {
+        let repo = InMemoryUsageReportRepository::new();
+        let today = Timestamp::start_of_today();
+        let yesterday = today.minus_days(1);
+
+        repo.save(&report("bob", today, 10)).await.unwrap();
+        repo.save(&report("alice", yesterday, 20)).await.unwrap();
+        repo.save(&report("carol", yesterday.minus_days(1), 30))
+            .await
+            .unwrap();
+
+        let listed = repo.list_between(&yesterday, &today).await.unwrap();
+        let tokens: Vec<u64> = listed.iter().map(|r| r.tokens).collect();
+        assert_eq!(tokens, vec![20, 10]);
+    }
+}
@@ -1,7 +1,13 @@
 //! Membership adapters - implementations of membership-related ports.
 //!
-//! - `StubAccessChecker` - Development/testing stub that always allows access
+//! - `StubAccessChecker` - Testing stub that always allows access (`test-support`)
+//! - `InMemoryUsageReportRepository` - Ledger of reported metered usage
 
+mod in_memory_usage_report_repository;
+#[cfg(any(test, feature = "test-support"))]
 mod stub_access_checker;
 
+#[cfg(any(test, feature = "test-support"))]
 pub use stub_access_checker::StubAccessChecker;
+
+pub use in_memory_usage_report_repository::InMemoryUsageReportRepository;
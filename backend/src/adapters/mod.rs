@@ -1,50 +1,207 @@
 //! Adapters - Implementations of port interfaces.
 //!
 //! Adapters connect the domain to external systems:
-//! - `ai` - AI/LLM provider implementations (mock, OpenAI, Anthropic)
+//! - `ai` - AI/LLM provider implementations (mock, OpenAI, Azure OpenAI, Anthropic, Gemini, Ollama)
+//! - `analytics` - Pseudonymous usage analytics stripped of decision content
+//! - `audit` - Hash-chained command audit log and pseudonym keys (in-memory)
 //! - `auth` - Authentication implementations (mock, Zitadel)
+//! - `cache` - Shared view cache implementations (in-memory, Redis, Redis Cluster)
+//! - `calendar` - iCalendar rendering and signed calendar feed URLs
+//! - `chaos` - Fault injection decorators for testing environments
+//! - `consent` - Consent ledger implementations (in-memory)
+//! - `document` - Decision document exporters (PDF, slide deck, templated Markdown, HTML page), attachment metadata, document history, document deliveries, and published snapshots
+//! - `email` - Outbound email implementations (Resend, SMTP, SES, failover, in-memory)
 //! - `events` - Event bus implementations (in-memory, Redis)
+//! - `feature_flags` - Runtime feature flag implementations (in-memory, Unleash)
+//! - `google` - Google Docs export with per-user OAuth
+//! - `grpc` - gRPC cycle and conversation services for internal consumers (`grpc` feature)
+//! - `help` - Help article storage (in-memory)
 //! - `http` - HTTP/REST API implementations
-//! - `membership` - Membership access control implementations
+//! - `i18n` - Built-in message catalogs for localized API strings
+//! - `integration` - Outbound integration actions (HTTP POST, Slack, email) and payload templating
+//! - `membership` - Membership access control and metered usage ledger implementations
+//! - `organization` - Organization storage (in-memory)
 //! - `postgres` - PostgreSQL database implementations
+//! - `privacy` - GDPR data export and erasure request storage (in-memory)
+//! - `profile` - Decision profile storage: history, summaries, revisions, overrides (in-memory)
 //! - `rate_limiter` - Rate limiting implementations (in-memory, Redis)
-//! - `storage` - State storage implementations (file, in-memory)
+//! - `resilience` - Circuit breakers and the decorators applying them to AI, payment and email adapters
+//! - `search` - Decision embedding storage for semantic search (in-memory)
+//! - `session` - Session auto-archive and cold storage state (in-memory)
+//! - `sqlite` - SQLite implementations of the core repositories and readers for self-hosting (`sqlite` feature)
+//! - `slack` - Sharing recommendations to Slack with per-workspace OAuth
+//! - `siem` - Audit and auth event export to a SIEM (HTTP, syslog)
+//! - `spreadsheet` - CSV and XLSX readers for imports
+//! - `storage` - State and document storage implementations (file, in-memory)
 //! - `stripe` - Stripe payment provider implementation
+//! - `teams` - Sharing recommendations to Microsoft Teams through incoming webhooks
+//! - `telemetry` - OpenTelemetry tracing setup and instrumentation
+//! - `token_revocation` - Logout-all token revocation cutoffs (in-memory, Redis)
 //! - `validation` - Schema validation implementations
-//! - `websocket` - WebSocket real-time update implementations
+//! - `websocket` - WebSocket real-time update implementations (cross-server via Redis)
 
 pub mod ai;
+pub mod analytics;
+pub mod audit;
 pub mod auth;
+pub mod cache;
+pub mod calendar;
+pub mod chaos;
+pub mod consent;
+pub mod document;
+pub mod email;
 pub mod events;
+pub mod feature_flags;
+pub mod google;
+#[cfg(feature = "grpc")]
+pub mod grpc;
+pub mod help;
 pub mod http;
+pub mod i18n;
+pub mod integration;
 pub mod membership;
+pub mod organization;
 pub mod postgres;
+pub mod privacy;
+pub mod profile;
 pub mod rate_limiter;
+pub mod resilience;
+pub mod search;
+pub mod session;
+pub mod siem;
+pub mod slack;
+pub mod spreadsheet;
+#[cfg(feature = "sqlite")]
+pub mod sqlite;
 pub mod storage;
 pub mod stripe;
+pub mod teams;
+pub mod telemetry;
+pub mod token_revocation;
 pub mod validation;
 pub mod websocket;
 
 pub use ai::{
     ai_events, AIEventCallback, AIUsageHandler, AnthropicConfig, AnthropicProvider,
-    FailoverAIProvider, InMemoryUsageTracker, MockAIProvider, MockError, MockResponse,
-    OpenAIConfig, OpenAIProvider,
+    AzureOpenAIConfig, AzureOpenAIProvider, CachedAIProvider, FailoverAIProvider, GeminiConfig,
+    GeminiProvider, InMemoryShadowRepository, InMemoryUsageTracker, OllamaConfig, OllamaProvider,
+    OpenAIConfig, OpenAIProvider, ShadowAIProvider, ShadowVariant,
 };
+#[cfg(any(test, feature = "test-support"))]
+pub use ai::{MockAIProvider, MockError, MockResponse};
+pub use analytics::{AnalyticsAnonymizer, InMemoryAnalyticsSink};
+pub use audit::{InMemoryAuditLog, InMemoryAuditPseudonymKeys};
+#[cfg(any(test, feature = "test-support"))]
 pub use auth::{MockAuthProvider, MockSessionValidator};
-pub use events::{IdempotentHandler, InMemoryEventBus, OutboxPublisher, OutboxPublisherConfig};
+pub use cache::{InMemoryCache, RedisCache, RedisClusterRouter, ViewCache};
+pub use calendar::{
+    HmacCalendarFeedSigner, IcsCalendarRenderer, InMemoryCalendarFeedVersionRepository,
+};
+pub use chaos::{
+    ChaosAIProvider, ChaosEventPublisher, ChaosRateLimiter, FaultInjector, FaultKind, FaultRule,
+    FaultTarget,
+};
+pub use consent::InMemoryConsentRepository;
+pub use document::{
+    HtmlPageExporter, InMemoryAttachmentRepository, InMemoryDocumentDeliveryRepository,
+    InMemoryDocumentEmailPreferenceRepository, InMemoryDocumentPublicationRepository,
+    InMemoryDocumentVersionRepository, InMemoryImportDraftRepository, PdfDocumentExporter,
+    SlideDeckExporter, TemplateDocumentExporter,
+};
+pub use email::{
+    build_email_sender, FailoverEmailSender, ResendConfig, ResendEmailSender, SesConfig,
+    SesEmailSender, SmtpConfig, SmtpEmailSender, SmtpTls, TeraEmailTemplateRenderer,
+};
+#[cfg(any(test, feature = "test-support"))]
+pub use email::InMemoryEmailSender;
+pub use events::{
+    IdempotentHandler, InMemoryCycleEventStore, InMemoryEventStore,
+    InMemoryProjectionCheckpointStore, OutboxPublisher,
+    OutboxPublisherConfig, SchemaCheckingPublisher, StoringEventPublisher,
+};
+#[cfg(any(test, feature = "test-support"))]
+pub use events::InMemoryEventBus;
+pub use feature_flags::{InMemoryFeatureFlagProvider, UnleashConfig, UnleashFeatureFlagProvider};
+pub use google::{GoogleDocsApiClient, GoogleDocsConfig, InMemoryGoogleAccountStore};
+pub use help::InMemoryHelpContentRepository;
+pub use i18n::{FluentMessageCatalog, FtlParseError};
+pub use integration::{
+    EmailActionAdapter, HttpPostActionAdapter, InMemoryIntegrationDeliveryRepository,
+    InMemoryIntegrationRepository, SlackActionAdapter, TeraIntegrationTemplateEngine,
+};
+pub use membership::InMemoryUsageReportRepository;
+pub use organization::InMemoryOrganizationRepository;
+#[cfg(any(test, feature = "test-support"))]
 pub use membership::StubAccessChecker;
 pub use postgres::{
-    PostgresAccessChecker, PostgresCycleReader, PostgresCycleRepository,
-    PostgresMembershipReader, PostgresMembershipRepository,
+    PostgresAccessChecker, PostgresAdaptiveStyleOverrideRepository, PostgresAttachmentRepository,
+    PostgresAuditLog, PostgresAuditPseudonymKeys, PostgresBenchmarkRepository,
+    PostgresCalendarFeedVersionRepository,
+    PostgresConsentRepository,
+    PostgresConversationBackupSource, PostgresCycleEventStore, PostgresMembershipBackupSource,
+    PostgresOrganizationBackupSource, PostgresSessionBackupSource,
+    PostgresCycleReader, PostgresDataErasureRepository, PostgresDataExportRepository, PostgresDecisionDeadlineReader,
+    PostgresDecisionEmbeddingRepository, PostgresDecisionHistoryRepository,
+    PostgresDocumentDeliveryRepository,
+    PostgresDocumentEmailPreferenceRepository, PostgresDocumentPublicationRepository,
+    PostgresDocumentVersionRepository, PostgresEventStore, PostgresGoogleAccountStore,
+    PostgresHelpContentRepository,
+    PostgresImportDraftRepository, PostgresProjectionCheckpointStore,
+    PostgresIntegrationDeliveryRepository, PostgresIntegrationRepository,
+    PostgresCycleRepository, PostgresFeatureFlagProvider, PostgresMembershipReader,
+    PostgresMembershipRepository, PostgresOrganizationRepository,
+    PostgresOutcomeReminderRepository,
+    PostgresProfileRevisionRepository, PostgresProfileSummaryRepository,
+    PostgresProvisionedUserRepository,
+    PostgresSessionArchivalRepository, PostgresSessionColdStorageRepository,
+    PostgresSlackShareRepository, PostgresSlackWorkspaceStore,
+    PostgresOrganizationChatSettingsRepository, PostgresTeamsShareRepository,
+    PostgresTeamProfileSettingsRepository, PostgresUsageReportRepository,
+    PostgresUserRecordsEraser,
+};
+pub use privacy::{InMemoryDataErasureRepository, InMemoryDataExportRepository};
+pub use profile::{
+    InMemoryAdaptiveStyleOverrideRepository, InMemoryBenchmarkRepository,
+    InMemoryDecisionHistoryRepository, InMemoryOutcomeReminderRepository,
+    InMemoryProfileRevisionRepository, InMemoryProfileSummaryRepository,
+    InMemoryTeamProfileSettingsRepository,
 };
 pub use rate_limiter::{
-    GlobalLimits, InMemoryRateLimiter, IpLimits, RateLimitConfig, RedisRateLimiter,
-    ResourceLimits, TierAwareRateLimiter, TierRateLimits,
+    GlobalLimits, InMemoryRateLimiter, IpLimits, RateLimitAlgorithm, RateLimitConfig,
+    RedisRateLimiter, ResourceLimits, ServiceRateLimits, TierAwareRateLimiter, TierRateLimits,
+};
+pub use resilience::{
+    CircuitBreakerRegistry, CircuitBreakingAIProvider, CircuitBreakingEmailSender,
+    CircuitBreakingPaymentProvider, GuardedService, InMemoryCircuitBreaker,
+};
+pub use search::InMemoryDecisionEmbeddingRepository;
+pub use session::{InMemorySessionArchivalRepository, InMemorySessionColdStorageRepository};
+pub use siem::{
+    AuditingSessionValidator, HttpSecurityEventSink, HttpSinkConfig, SiemAuditForwarder,
+    SiemExporter, SiemExporterConfig, SyslogSecurityEventSink, SyslogTransport,
+};
+pub use slack::{
+    InMemorySlackShareRepository, InMemorySlackWorkspaceStore, SlackApiClient, SlackConfig,
+};
+pub use spreadsheet::SpreadsheetReader;
+pub use storage::{
+    FileDocumentStorage, FileStateStorage, HmacPublicLinkSigner, HmacUrlSigner,
+    InMemoryDocumentStorage, InMemoryStateStorage,
+};
+pub use stripe::{StripeConfig, StripePaymentAdapter};
+pub use teams::{
+    InMemoryOrganizationChatSettingsRepository, InMemoryTeamsShareRepository, TeamsWebhookClient,
+};
+#[cfg(any(test, feature = "test-support"))]
+pub use stripe::MockPaymentProvider;
+pub use telemetry::{
+    http_trace_layer, init_telemetry, SloObjective, SloStatus, SloTracker, SlowQueryGroup,
+    SlowQueryLog, TelemetryConfig, TelemetryGuard, TracedEventHandler, TracedEventPublisher,
 };
-pub use storage::{FileStateStorage, InMemoryStateStorage};
-pub use stripe::{MockPaymentProvider, StripeConfig, StripePaymentAdapter};
+pub use token_revocation::{InMemoryTokenRevocationStore, RedisTokenRevocationStore};
 pub use validation::JsonSchemaValidator;
 pub use websocket::{
-    websocket_router, ClientId, DashboardUpdate, DashboardUpdateType, RoomManager, ServerMessage,
+    websocket_router, AnnouncementBoard, ClientId, DashboardUpdate, DashboardUpdateType, InMemoryConnectionRegistry,
+    RedisConnectionRegistry, RedisServerMessenger, RoomManager, ServerMessage, WebSocketCluster,
     WebSocketEventBridge, WebSocketState, DASHBOARD_EVENT_TYPES,
 };
@@ -0,0 +1,61 @@
+//! In-memory organization repository for testing and development.
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use tokio::sync::RwLock;
+
+use crate::domain::foundation::{DomainError, OrganizationId, UserId};
+use crate::domain::organization::Organization;
+use crate::ports::OrganizationRepository;
+
+/// In-memory organizations, members included.
+#[derive(Debug, Clone, Default)]
+pub struct InMemoryOrganizationRepository {
+    organizations: Arc<RwLock<Vec<Organization>>>,
+}
+
+impl InMemoryOrganizationRepository {
+    /// Create an empty repository.
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait]
+impl OrganizationRepository for InMemoryOrganizationRepository {
+    async fn save(&self, organization: &Organization) -> Result<(), DomainError> {
+        let mut organizations = self.organizations.write().await;
+        organizations.retain(|o| o.id != organization.id);
+        organizations.push(organization.clone());
+        Ok(())
+    }
+
+    async fn find_by_id(&self, id: &OrganizationId) -> Result<Option<Organization>, DomainError> {
+        Ok(self
+            .organizations
+            .read()
+            .await
+            .iter()
+            .find(|o| &o.id == id)
+            .cloned())
+    }
+
+    async fn list_for_member(&self, user_id: &UserId) -> Result<Vec<Organization>, DomainError> {
+        let mut organizations: Vec<Organization> = self
+            .organizations
+            .read()
+            .await
+            .iter()
+            .filter(|o| o.is_member(user_id))
+            .cloned()
+            .collect();
+        organizations.sort_by_key(|o| o.created_at);
+        Ok(organizations)
+    }
+
+    async fn delete(&self, id: &OrganizationId) -> Result<(), DomainError> {
+        self.organizations.write().await.retain(|o| &o.id != id);
+        Ok(())
+    }
+}
@@ -0,0 +1,7 @@
+//! Organization adapters - implementations of organization-related ports.
+//!
+//! - `InMemoryOrganizationRepository` - Organizations and their members
+
+mod in_memory_organization_repository;
+
+pub use in_memory_organization_repository::InMemoryOrganizationRepository;
@@ -1,6 +1,11 @@
 //! PostgreSQL implementation of AccessChecker.
 //!
 //! Provides database-backed access control based on membership status and usage.
+//! A user's access comes from their own membership or from the billing
+//! membership of any organization they belong to, whichever grants more.
+//! An organization's membership only covers as many members as it has paid
+//! seats; the earliest to join hold them. Users deprovisioned through SCIM
+//! have no membership access at all.
 
 use crate::domain::foundation::{DomainError, ErrorCode, SessionId, UserId};
 use crate::domain::membership::{MembershipStatus, MembershipTier, TierLimits};
@@ -30,6 +35,7 @@ impl PostgresAccessChecker {
 struct MembershipAccess {
     tier: MembershipTier,
     status: MembershipStatus,
+    has_seat: bool,
     has_access: bool,
 }
 
@@ -48,6 +54,7 @@ fn parse_tier(s: &str) -> Result<MembershipTier, DomainError> {
 fn parse_status(s: &str) -> Result<MembershipStatus, DomainError> {
     match s.to_lowercase().as_str() {
         "pending" => Ok(MembershipStatus::Pending),
+        "trialing" => Ok(MembershipStatus::Trialing),
         "active" => Ok(MembershipStatus::Active),
         "past_due" => Ok(MembershipStatus::PastDue),
         "cancelled" => Ok(MembershipStatus::Cancelled),
@@ -59,6 +66,36 @@ fn parse_status(s: &str) -> Result<MembershipStatus, DomainError> {
     }
 }
 
+/// Whether a membership in `status` grants access at `now`.
+///
+/// Trials grant the same access as the paid tier they preview. Cancelled
+/// memberships keep access until the period they paid for ends.
+fn status_grants_access(
+    status: MembershipStatus,
+    period_end: Option<DateTime<Utc>>,
+    now: DateTime<Utc>,
+) -> bool {
+    if !status.has_access() {
+        return false;
+    }
+    if status == MembershipStatus::Cancelled {
+        return period_end.is_some_and(|end| now <= end);
+    }
+    true
+}
+
+/// Picks the membership granting access at the highest tier. When none
+/// grants access, the first (the user's own) explains the denial.
+fn best_access(candidates: Vec<MembershipAccess>) -> Option<MembershipAccess> {
+    candidates.into_iter().reduce(|best, next| {
+        if (next.has_access, next.tier.rank()) > (best.has_access, best.tier.rank()) {
+            next
+        } else {
+            best
+        }
+    })
+}
+
 fn parse_user_id_as_uuid(user_id: &UserId) -> Result<Uuid, DomainError> {
     Uuid::parse_str(user_id.as_str()).map_err(|e| {
         DomainError::new(
@@ -70,6 +107,10 @@ fn parse_user_id_as_uuid(user_id: &UserId) -> Result<Uuid, DomainError> {
 
 impl PostgresAccessChecker {
     /// Get membership access info for a user.
+    ///
+    /// Considers the user's own membership first, then the billing
+    /// membership of each organization they belong to. Returns `None` for
+    /// users an identity provider has deprovisioned.
     async fn get_membership_access(
         &self,
         user_id: &UserId,
@@ -77,15 +118,40 @@ impl PostgresAccessChecker {
         let user_uuid = parse_user_id_as_uuid(user_id)?;
         let now = Utc::now();
 
-        let row: Option<(String, String, Option<DateTime<Utc>>)> = sqlx::query_as(
+        let rows: Vec<(String, String, Option<DateTime<Utc>>, bool)> = sqlx::query_as(
             r#"
-            SELECT tier, status, current_period_end
-            FROM memberships
-            WHERE user_id = $1
+            SELECT tier, status, current_period_end, has_seat
+            FROM (
+                SELECT tier, status, current_period_end, TRUE AS has_seat, 0 AS source
+                FROM memberships
+                WHERE user_id = $1
+                UNION ALL
+                SELECT m.tier, m.status, m.current_period_end, om.seat <= m.seats AS has_seat,
+                       1 AS source
+                FROM (
+                    SELECT organization_id, user_id,
+                           ROW_NUMBER() OVER (
+                               PARTITION BY organization_id ORDER BY joined_at, user_id
+                           ) AS seat
+                    FROM organization_members
+                    WHERE organization_id IN (
+                        SELECT organization_id FROM organization_members WHERE user_id = $2
+                    )
+                ) om
+                JOIN organizations o ON o.id = om.organization_id
+                JOIN memberships m ON m.id = o.billing_membership_id
+                WHERE om.user_id = $2
+            ) access
+            WHERE NOT EXISTS (
+                SELECT 1 FROM provisioned_users pu
+                WHERE pu.user_id = $2 AND NOT pu.active
+            )
+            ORDER BY source
             "#,
         )
         .bind(user_uuid)
-        .fetch_optional(&self.pool)
+        .bind(user_id.as_str())
+        .fetch_all(&self.pool)
         .await
         .map_err(|e| {
             DomainError::new(
@@ -94,28 +160,22 @@ impl PostgresAccessChecker {
             )
         })?;
 
-        let Some((tier_str, status_str, period_end)) = row else {
-            return Ok(None);
-        };
+        let mut candidates = Vec::with_capacity(rows.len());
+        for (tier_str, status_str, period_end, has_seat) in rows {
+            let tier = parse_tier(&tier_str)?;
+            let status = parse_status(&status_str)?;
 
-        let tier = parse_tier(&tier_str)?;
-        let status = parse_status(&status_str)?;
+            let has_access = has_seat && status_grants_access(status, period_end, now);
 
-        // Calculate access based on status and period
-        let has_access = if !status.has_access() {
-            false
-        } else if status == MembershipStatus::Cancelled {
-            // Cancelled memberships have access until period end
-            period_end.is_some_and(|end| now <= end)
-        } else {
-            true
-        };
+            candidates.push(MembershipAccess {
+                tier,
+                status,
+                has_seat,
+                has_access,
+            });
+        }
 
-        Ok(Some(MembershipAccess {
-            tier,
-            status,
-            has_access,
-        }))
+        Ok(best_access(candidates))
     }
 
     /// Count active sessions for a user.
@@ -130,7 +190,7 @@ impl PostgresAccessChecker {
             r#"
             SELECT COUNT(*) as count
             FROM sessions
-            WHERE user_id = $1 AND status != 'archived'
+            WHERE user_id = $1 AND status = 'active'
             "#,
         )
         .bind(user_uuid)
@@ -189,12 +249,17 @@ impl PostgresAccessChecker {
 
 #[async_trait]
 impl AccessChecker for PostgresAccessChecker {
+    #[tracing::instrument(name = "PostgresAccessChecker::can_create_session", skip_all, fields(db.system = "postgresql"), err)]
     async fn can_create_session(&self, user_id: &UserId) -> Result<AccessResult, DomainError> {
         // Check membership exists and has access
         let Some(membership) = self.get_membership_access(user_id).await? else {
             return Ok(AccessResult::Denied(AccessDeniedReason::NoMembership));
         };
 
+        if !membership.has_seat {
+            return Ok(AccessResult::Denied(AccessDeniedReason::NoSeatAvailable));
+        }
+
         if !membership.has_access {
             return Ok(match membership.status {
                 MembershipStatus::Expired => {
@@ -224,6 +289,7 @@ impl AccessChecker for PostgresAccessChecker {
         Ok(AccessResult::Allowed)
     }
 
+    #[tracing::instrument(name = "PostgresAccessChecker::can_create_cycle", skip_all, fields(db.system = "postgresql"), err)]
     async fn can_create_cycle(
         &self,
         user_id: &UserId,
@@ -234,6 +300,10 @@ impl AccessChecker for PostgresAccessChecker {
             return Ok(AccessResult::Denied(AccessDeniedReason::NoMembership));
         };
 
+        if !membership.has_seat {
+            return Ok(AccessResult::Denied(AccessDeniedReason::NoSeatAvailable));
+        }
+
         if !membership.has_access {
             return Ok(match membership.status {
                 MembershipStatus::Expired => {
@@ -260,12 +330,17 @@ impl AccessChecker for PostgresAccessChecker {
         Ok(AccessResult::Allowed)
     }
 
+    #[tracing::instrument(name = "PostgresAccessChecker::can_export", skip_all, fields(db.system = "postgresql"), err)]
     async fn can_export(&self, user_id: &UserId) -> Result<AccessResult, DomainError> {
         // Check membership exists and has access
         let Some(membership) = self.get_membership_access(user_id).await? else {
             return Ok(AccessResult::Denied(AccessDeniedReason::NoMembership));
         };
 
+        if !membership.has_seat {
+            return Ok(AccessResult::Denied(AccessDeniedReason::NoSeatAvailable));
+        }
+
         if !membership.has_access {
             return Ok(AccessResult::Denied(AccessDeniedReason::MembershipExpired));
         }
@@ -282,6 +357,7 @@ impl AccessChecker for PostgresAccessChecker {
         Ok(AccessResult::Allowed)
     }
 
+    #[tracing::instrument(name = "PostgresAccessChecker::get_tier_limits", skip_all, fields(db.system = "postgresql"), err)]
     async fn get_tier_limits(&self, user_id: &UserId) -> Result<TierLimits, DomainError> {
         let membership = self.get_membership_access(user_id).await?;
 
@@ -289,6 +365,7 @@ impl AccessChecker for PostgresAccessChecker {
         Ok(TierLimits::for_tier(tier))
     }
 
+    #[tracing::instrument(name = "PostgresAccessChecker::get_usage", skip_all, fields(db.system = "postgresql"), err)]
     async fn get_usage(&self, user_id: &UserId) -> Result<UsageStats, DomainError> {
         let active_sessions = self.count_active_sessions(user_id).await?;
         let total_cycles = self.count_total_cycles(user_id).await?;
@@ -332,12 +409,33 @@ mod tests {
     #[test]
     fn parse_status_all_values() {
         assert_eq!(parse_status("pending").unwrap(), MembershipStatus::Pending);
+        assert_eq!(parse_status("trialing").unwrap(), MembershipStatus::Trialing);
         assert_eq!(parse_status("active").unwrap(), MembershipStatus::Active);
         assert_eq!(parse_status("past_due").unwrap(), MembershipStatus::PastDue);
         assert_eq!(parse_status("cancelled").unwrap(), MembershipStatus::Cancelled);
         assert_eq!(parse_status("expired").unwrap(), MembershipStatus::Expired);
     }
 
+    #[test]
+    fn trials_grant_access() {
+        let now = Utc::now();
+        let trial_end = Some(now + chrono::Duration::days(7));
+
+        assert!(status_grants_access(MembershipStatus::Trialing, trial_end, now));
+        assert!(!status_grants_access(MembershipStatus::Pending, trial_end, now));
+    }
+
+    #[test]
+    fn cancelled_access_ends_with_period() {
+        let now = Utc::now();
+        let ended = Some(now - chrono::Duration::days(1));
+        let running = Some(now + chrono::Duration::days(1));
+
+        assert!(status_grants_access(MembershipStatus::Cancelled, running, now));
+        assert!(!status_grants_access(MembershipStatus::Cancelled, ended, now));
+        assert!(!status_grants_access(MembershipStatus::Cancelled, None, now));
+    }
+
     #[test]
     fn parse_status_case_insensitive() {
         assert_eq!(parse_status("ACTIVE").unwrap(), MembershipStatus::Active);
@@ -368,10 +466,88 @@ mod tests {
         let access = MembershipAccess {
             tier: MembershipTier::Monthly,
             status: MembershipStatus::Active,
+            has_seat: true,
             has_access: true,
         };
         let debug_str = format!("{:?}", access);
         assert!(debug_str.contains("Monthly"));
         assert!(debug_str.contains("Active"));
     }
+
+    fn access(
+        tier: MembershipTier,
+        status: MembershipStatus,
+        has_access: bool,
+    ) -> MembershipAccess {
+        MembershipAccess {
+            tier,
+            status,
+            has_seat: true,
+            has_access,
+        }
+    }
+
+    fn unseated(tier: MembershipTier) -> MembershipAccess {
+        MembershipAccess {
+            tier,
+            status: MembershipStatus::Active,
+            has_seat: false,
+            has_access: false,
+        }
+    }
+
+    #[test]
+    fn organization_plan_covers_expired_own_membership() {
+        let best = best_access(vec![
+            access(MembershipTier::Monthly, MembershipStatus::Expired, false),
+            access(MembershipTier::Annual, MembershipStatus::Active, true),
+        ])
+        .unwrap();
+
+        assert!(best.has_access);
+        assert_eq!(best.tier, MembershipTier::Annual);
+    }
+
+    #[test]
+    fn highest_tier_with_access_wins() {
+        let best = best_access(vec![
+            access(MembershipTier::Annual, MembershipStatus::Active, true),
+            access(MembershipTier::Free, MembershipStatus::Active, true),
+        ])
+        .unwrap();
+
+        assert_eq!(best.tier, MembershipTier::Annual);
+    }
+
+    #[test]
+    fn own_membership_explains_denial_when_nothing_grants_access() {
+        let best = best_access(vec![
+            access(MembershipTier::Monthly, MembershipStatus::PastDue, false),
+            access(MembershipTier::Free, MembershipStatus::Expired, false),
+        ])
+        .unwrap();
+
+        assert_eq!(best.status, MembershipStatus::PastDue);
+        assert!(best_access(vec![]).is_none());
+    }
+
+    #[test]
+    fn own_membership_applies_when_organization_has_no_seat() {
+        let best = best_access(vec![
+            access(MembershipTier::Free, MembershipStatus::Active, true),
+            unseated(MembershipTier::Annual),
+        ])
+        .unwrap();
+
+        assert_eq!(best.tier, MembershipTier::Free);
+        assert!(best.has_seat);
+    }
+
+    #[test]
+    fn missing_seat_explains_denial_without_own_membership() {
+        let best = best_access(vec![unseated(MembershipTier::Annual)]).unwrap();
+
+        assert!(!best.has_seat);
+        assert!(!best.has_access);
+    }
 }
@@ -0,0 +1,74 @@
+//! PostgreSQL implementation of the adaptive style override port.
+//!
+//! Only opt-outs are stored, one row per conversation in
+//! `conversation_style_overrides`.
+
+use async_trait::async_trait;
+use sqlx::PgPool;
+
+use crate::domain::foundation::{ConversationId, DomainError, ErrorCode};
+use crate::ports::AdaptiveStyleOverrideRepository;
+
+/// PostgreSQL implementation of AdaptiveStyleOverrideRepository.
+#[derive(Clone)]
+pub struct PostgresAdaptiveStyleOverrideRepository {
+    pool: PgPool,
+}
+
+impl PostgresAdaptiveStyleOverrideRepository {
+    /// Creates a new PostgresAdaptiveStyleOverrideRepository.
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl AdaptiveStyleOverrideRepository for PostgresAdaptiveStyleOverrideRepository {
+    #[tracing::instrument(name = "PostgresAdaptiveStyleOverrideRepository::is_disabled", skip_all, fields(db.system = "postgresql"), err)]
+    async fn is_disabled(&self, conversation_id: &ConversationId) -> Result<bool, DomainError> {
+        sqlx::query_scalar(
+            "SELECT EXISTS(SELECT 1 FROM conversation_style_overrides WHERE conversation_id = $1)",
+        )
+        .bind(conversation_id.as_uuid())
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|e| {
+            DomainError::new(
+                ErrorCode::DatabaseError,
+                format!("Failed to fetch adaptive style override: {}", e),
+            )
+        })
+    }
+
+    #[tracing::instrument(name = "PostgresAdaptiveStyleOverrideRepository::set_disabled", skip_all, fields(db.system = "postgresql"), err)]
+    async fn set_disabled(
+        &self,
+        conversation_id: &ConversationId,
+        disabled: bool,
+    ) -> Result<(), DomainError> {
+        let query = if disabled {
+            sqlx::query(
+                r#"
+                INSERT INTO conversation_style_overrides (conversation_id, created_at)
+                VALUES ($1, NOW())
+                ON CONFLICT (conversation_id) DO NOTHING
+                "#,
+            )
+        } else {
+            sqlx::query("DELETE FROM conversation_style_overrides WHERE conversation_id = $1")
+        };
+
+        query
+            .bind(conversation_id.as_uuid())
+            .execute(&self.pool)
+            .await
+            .map_err(|e| {
+                DomainError::new(
+                    ErrorCode::DatabaseError,
+                    format!("Failed to save adaptive style override: {}", e),
+                )
+            })?;
+
+        Ok(())
+    }
+}
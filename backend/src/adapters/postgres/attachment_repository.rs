@@ -0,0 +1,170 @@
+//! PostgreSQL implementation of AttachmentRepository.
+//!
+//! Stores attachment metadata in the `attachments` table. File bytes are
+//! kept in document storage, not in the database.
+
+use async_trait::async_trait;
+use sqlx::{PgPool, Row};
+
+use crate::domain::document::Attachment;
+use crate::domain::foundation::{AttachmentId, CycleId, DomainError, ErrorCode, Timestamp, UserId};
+use crate::ports::AttachmentRepository;
+
+use super::cycle_repository::{component_type_to_str, str_to_component_type};
+
+/// PostgreSQL implementation of AttachmentRepository.
+#[derive(Clone)]
+pub struct PostgresAttachmentRepository {
+    pool: PgPool,
+}
+
+impl PostgresAttachmentRepository {
+    /// Creates a new PostgresAttachmentRepository.
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl AttachmentRepository for PostgresAttachmentRepository {
+    #[tracing::instrument(name = "PostgresAttachmentRepository::save", skip_all, fields(db.system = "postgresql"), err)]
+    async fn save(&self, attachment: &Attachment) -> Result<(), DomainError> {
+        sqlx::query(
+            r#"
+            INSERT INTO attachments (
+                id, cycle_id, component_type, file_name, content_type,
+                size_bytes, caption, uploaded_by, uploaded_at
+            ) VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)
+            "#,
+        )
+        .bind(attachment.id.as_uuid())
+        .bind(attachment.cycle_id.as_uuid())
+        .bind(component_type_to_str(attachment.component_type))
+        .bind(&attachment.file_name)
+        .bind(&attachment.content_type)
+        .bind(attachment.size_bytes as i64)
+        .bind(attachment.caption.as_deref())
+        .bind(attachment.uploaded_by.as_str())
+        .bind(attachment.uploaded_at.as_datetime())
+        .execute(&self.pool)
+        .await
+        .map_err(|e| {
+            DomainError::new(
+                ErrorCode::DatabaseError,
+                format!("Failed to insert attachment: {}", e),
+            )
+        })?;
+
+        Ok(())
+    }
+
+    #[tracing::instrument(name = "PostgresAttachmentRepository::find_by_id", skip_all, fields(db.system = "postgresql"), err)]
+    async fn find_by_id(&self, id: &AttachmentId) -> Result<Option<Attachment>, DomainError> {
+        let row = sqlx::query(
+            r#"
+            SELECT id, cycle_id, component_type, file_name, content_type,
+                   size_bytes, caption, uploaded_by, uploaded_at
+            FROM attachments
+            WHERE id = $1
+            "#,
+        )
+        .bind(id.as_uuid())
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| {
+            DomainError::new(
+                ErrorCode::DatabaseError,
+                format!("Failed to fetch attachment: {}", e),
+            )
+        })?;
+
+        row.map(row_to_attachment).transpose()
+    }
+
+    #[tracing::instrument(name = "PostgresAttachmentRepository::list_by_cycle", skip_all, fields(db.system = "postgresql"), err)]
+    async fn list_by_cycle(&self, cycle_id: &CycleId) -> Result<Vec<Attachment>, DomainError> {
+        let rows = sqlx::query(
+            r#"
+            SELECT id, cycle_id, component_type, file_name, content_type,
+                   size_bytes, caption, uploaded_by, uploaded_at
+            FROM attachments
+            WHERE cycle_id = $1
+            ORDER BY uploaded_at ASC
+            "#,
+        )
+        .bind(cycle_id.as_uuid())
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| {
+            DomainError::new(
+                ErrorCode::DatabaseError,
+                format!("Failed to fetch attachments: {}", e),
+            )
+        })?;
+
+        rows.into_iter().map(row_to_attachment).collect()
+    }
+
+    #[tracing::instrument(name = "PostgresAttachmentRepository::delete", skip_all, fields(db.system = "postgresql"), err)]
+    async fn delete(&self, id: &AttachmentId) -> Result<(), DomainError> {
+        sqlx::query("DELETE FROM attachments WHERE id = $1")
+            .bind(id.as_uuid())
+            .execute(&self.pool)
+            .await
+            .map_err(|e| {
+                DomainError::new(
+                    ErrorCode::DatabaseError,
+                    format!("Failed to delete attachment: {}", e),
+                )
+            })?;
+
+        Ok(())
+    }
+}
+
+fn db_error(field: &str, e: sqlx::Error) -> DomainError {
+    DomainError::new(
+        ErrorCode::DatabaseError,
+        format!("Failed to get {}: {}", field, e),
+    )
+}
+
+fn row_to_attachment(row: sqlx::postgres::PgRow) -> Result<Attachment, DomainError> {
+    let id: uuid::Uuid = row.try_get("id").map_err(|e| db_error("id", e))?;
+    let cycle_id: uuid::Uuid = row
+        .try_get("cycle_id")
+        .map_err(|e| db_error("cycle_id", e))?;
+    let component_type: String = row
+        .try_get("component_type")
+        .map_err(|e| db_error("component_type", e))?;
+    let size_bytes: i64 = row
+        .try_get("size_bytes")
+        .map_err(|e| db_error("size_bytes", e))?;
+    let uploaded_by: String = row
+        .try_get("uploaded_by")
+        .map_err(|e| db_error("uploaded_by", e))?;
+    let uploaded_at: chrono::DateTime<chrono::Utc> = row
+        .try_get("uploaded_at")
+        .map_err(|e| db_error("uploaded_at", e))?;
+
+    Ok(Attachment {
+        id: AttachmentId::from_uuid(id),
+        cycle_id: CycleId::from_uuid(cycle_id),
+        component_type: str_to_component_type(&component_type)?,
+        file_name: row
+            .try_get("file_name")
+            .map_err(|e| db_error("file_name", e))?,
+        content_type: row
+            .try_get("content_type")
+            .map_err(|e| db_error("content_type", e))?,
+        size_bytes: size_bytes.max(0) as usize,
+        caption: row.try_get("caption").map_err(|e| db_error("caption", e))?,
+        uploaded_by: UserId::new(uploaded_by).map_err(|e| {
+            DomainError::new(
+                ErrorCode::DatabaseError,
+                format!("Invalid uploaded_by: {}", e),
+            )
+        })?,
+        uploaded_at: Timestamp::from_datetime(uploaded_at),
+    })
+}
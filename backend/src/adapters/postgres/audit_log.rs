@@ -0,0 +1,207 @@
+//! PostgreSQL implementation of AuditLog.
+//!
+//! Entries live in `audit_log`, which a trigger keeps append-only. Appends
+//! lock the table against other appends for the length of the transaction,
+//! so each entry reads the true tail of the chain before sealing onto it.
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::{postgres::PgRow, PgPool, Row};
+
+use crate::domain::audit::{AuditEntry, AuditRecord, AUDIT_GENESIS_HASH};
+use crate::domain::foundation::{DomainError, ErrorCode, Timestamp, UserId};
+use crate::ports::{AuditLog, AuditPage, AuditQuery};
+
+/// Filters shared by listing and counting; `$1`-`$4` bind the query's
+/// actor, aggregate type, aggregate ID and command type.
+const FILTERS: &str = r#"
+    WHERE ($1::TEXT IS NULL OR actor_id = $1)
+      AND ($2::TEXT IS NULL OR aggregate_type = $2)
+      AND ($3::TEXT IS NULL OR aggregate_id = $3)
+      AND ($4::TEXT IS NULL OR command_type = $4)
+"#;
+
+/// PostgreSQL implementation of AuditLog.
+#[derive(Clone)]
+pub struct PostgresAuditLog {
+    pool: PgPool,
+}
+
+impl PostgresAuditLog {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl AuditLog for PostgresAuditLog {
+    #[tracing::instrument(name = "PostgresAuditLog::append", skip_all, fields(db.system = "postgresql"), err)]
+    async fn append(&self, record: AuditRecord) -> Result<AuditEntry, DomainError> {
+        let mut tx = self
+            .pool
+            .begin()
+            .await
+            .map_err(|e| db_error("begin transaction", e))?;
+
+        // Blocks other appends, not readers, until commit
+        sqlx::query("LOCK TABLE audit_log IN SHARE ROW EXCLUSIVE MODE")
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| db_error("lock audit log", e))?;
+
+        let tail: Option<(i64, String)> =
+            sqlx::query_as("SELECT sequence, hash FROM audit_log ORDER BY sequence DESC LIMIT 1")
+                .fetch_optional(&mut *tx)
+                .await
+                .map_err(|e| db_error("read audit log tail", e))?;
+        let (sequence, previous_hash) = match tail {
+            Some((sequence, hash)) => (sequence as u64 + 1, hash),
+            None => (1, AUDIT_GENESIS_HASH.to_string()),
+        };
+        let entry = AuditEntry::seal(record, sequence, previous_hash, Timestamp::now());
+
+        sqlx::query(
+            r#"
+            INSERT INTO audit_log (
+                sequence, actor_id, command_type, aggregate_type, aggregate_id,
+                correlation_id, source, before_summary, after_summary, recorded_at,
+                previous_hash, hash
+            )
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12)
+            "#,
+        )
+        .bind(entry.sequence as i64)
+        .bind(entry.record.actor_id.as_str())
+        .bind(&entry.record.command_type)
+        .bind(&entry.record.aggregate_type)
+        .bind(&entry.record.aggregate_id)
+        .bind(&entry.record.correlation_id)
+        .bind(&entry.record.source)
+        .bind(&entry.record.before)
+        .bind(&entry.record.after)
+        .bind(entry.recorded_at.as_datetime())
+        .bind(&entry.previous_hash)
+        .bind(&entry.hash)
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| db_error("append audit entry", e))?;
+
+        tx.commit()
+            .await
+            .map_err(|e| db_error("commit transaction", e))?;
+        Ok(entry)
+    }
+
+    #[tracing::instrument(name = "PostgresAuditLog::list", skip_all, fields(db.system = "postgresql"), err)]
+    async fn list(&self, query: &AuditQuery) -> Result<AuditPage, DomainError> {
+        let actor_id = query.actor_id.as_ref().map(UserId::as_str);
+
+        let rows = sqlx::query(&format!(
+            "SELECT * FROM audit_log {} ORDER BY sequence DESC LIMIT $5 OFFSET $6",
+            FILTERS
+        ))
+        .bind(actor_id)
+        .bind(&query.aggregate_type)
+        .bind(&query.aggregate_id)
+        .bind(&query.command_type)
+        .bind(query.limit.map(i64::from))
+        .bind(i64::from(query.offset.unwrap_or(0)))
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| db_error("list audit entries", e))?;
+        let items = rows
+            .iter()
+            .map(row_to_entry)
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let total: i64 = sqlx::query_scalar(&format!("SELECT COUNT(*) FROM audit_log {}", FILTERS))
+            .bind(actor_id)
+            .bind(&query.aggregate_type)
+            .bind(&query.aggregate_id)
+            .bind(&query.command_type)
+            .fetch_one(&self.pool)
+            .await
+            .map_err(|e| db_error("count audit entries", e))?;
+        let total = total as u64;
+
+        let offset = query.offset.unwrap_or(0) as u64;
+        let has_more = offset + (items.len() as u64) < total;
+
+        Ok(AuditPage {
+            items,
+            total,
+            has_more,
+        })
+    }
+
+    #[tracing::instrument(name = "PostgresAuditLog::entries_after", skip_all, fields(db.system = "postgresql"), err)]
+    async fn entries_after(
+        &self,
+        after_sequence: u64,
+        limit: u32,
+    ) -> Result<Vec<AuditEntry>, DomainError> {
+        let rows =
+            sqlx::query("SELECT * FROM audit_log WHERE sequence > $1 ORDER BY sequence LIMIT $2")
+                .bind(after_sequence as i64)
+                .bind(i64::from(limit))
+                .fetch_all(&self.pool)
+                .await
+                .map_err(|e| db_error("read audit entries", e))?;
+
+        rows.iter().map(row_to_entry).collect()
+    }
+}
+
+fn row_to_entry(row: &PgRow) -> Result<AuditEntry, DomainError> {
+    let sequence: i64 = row
+        .try_get("sequence")
+        .map_err(|e| db_error("get sequence", e))?;
+    let actor_id: String = row
+        .try_get("actor_id")
+        .map_err(|e| db_error("get actor_id", e))?;
+    let recorded_at: DateTime<Utc> = row
+        .try_get("recorded_at")
+        .map_err(|e| db_error("get recorded_at", e))?;
+
+    Ok(AuditEntry {
+        sequence: sequence as u64,
+        record: AuditRecord {
+            actor_id: UserId::new(actor_id).map_err(|e| {
+                DomainError::new(ErrorCode::DatabaseError, format!("Invalid actor_id: {}", e))
+            })?,
+            command_type: row
+                .try_get("command_type")
+                .map_err(|e| db_error("get command_type", e))?,
+            aggregate_type: row
+                .try_get("aggregate_type")
+                .map_err(|e| db_error("get aggregate_type", e))?,
+            aggregate_id: row
+                .try_get("aggregate_id")
+                .map_err(|e| db_error("get aggregate_id", e))?,
+            correlation_id: row
+                .try_get("correlation_id")
+                .map_err(|e| db_error("get correlation_id", e))?,
+            source: row
+                .try_get("source")
+                .map_err(|e| db_error("get source", e))?,
+            before: row
+                .try_get("before_summary")
+                .map_err(|e| db_error("get before_summary", e))?,
+            after: row
+                .try_get("after_summary")
+                .map_err(|e| db_error("get after_summary", e))?,
+        },
+        recorded_at: Timestamp::from_datetime(recorded_at),
+        previous_hash: row
+            .try_get("previous_hash")
+            .map_err(|e| db_error("get previous_hash", e))?,
+        hash: row.try_get("hash").map_err(|e| db_error("get hash", e))?,
+    })
+}
+
+fn db_error(action: &str, e: sqlx::Error) -> DomainError {
+    DomainError::new(
+        ErrorCode::DatabaseError,
+        format!("Failed to {}: {}", action, e),
+    )
+}
@@ -0,0 +1,66 @@
+//! PostgreSQL implementation of AuditPseudonymKeys.
+//!
+//! One key per user in `audit_pseudonym_keys`. `PostgresUserRecordsEraser`
+//! deletes the row when the user is erased.
+
+use async_trait::async_trait;
+use sqlx::PgPool;
+
+use crate::domain::audit::PseudonymKey;
+use crate::domain::foundation::{DomainError, ErrorCode, UserId};
+use crate::ports::AuditPseudonymKeys;
+
+/// PostgreSQL implementation of AuditPseudonymKeys.
+#[derive(Clone)]
+pub struct PostgresAuditPseudonymKeys {
+    pool: PgPool,
+}
+
+impl PostgresAuditPseudonymKeys {
+    /// Creates a new PostgresAuditPseudonymKeys.
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl AuditPseudonymKeys for PostgresAuditPseudonymKeys {
+    #[tracing::instrument(name = "PostgresAuditPseudonymKeys::key_for", skip_all, fields(db.system = "postgresql"), err)]
+    async fn key_for(&self, user_id: &UserId) -> Result<PseudonymKey, DomainError> {
+        // The no-op update makes RETURNING yield the row that won a race
+        let key: String = sqlx::query_scalar(
+            r#"
+            INSERT INTO audit_pseudonym_keys (user_id, key)
+            VALUES ($1, $2)
+            ON CONFLICT (user_id) DO UPDATE SET user_id = EXCLUDED.user_id
+            RETURNING key
+            "#,
+        )
+        .bind(user_id.as_str())
+        .bind(PseudonymKey::generate().expose_secret())
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|e| db_error("store audit pseudonym key", e))?;
+
+        Ok(PseudonymKey::from_secret(key))
+    }
+
+    #[tracing::instrument(name = "PostgresAuditPseudonymKeys::find", skip_all, fields(db.system = "postgresql"), err)]
+    async fn find(&self, user_id: &UserId) -> Result<Option<PseudonymKey>, DomainError> {
+        let key: Option<String> =
+            sqlx::query_scalar("SELECT key FROM audit_pseudonym_keys WHERE user_id = $1")
+                .bind(user_id.as_str())
+                .fetch_optional(&self.pool)
+                .await
+                .map_err(|e| db_error("fetch audit pseudonym key", e))?;
+
+        Ok(key.map(PseudonymKey::from_secret))
+    }
+}
+
+fn db_error(action: &str, e: sqlx::Error) -> DomainError {
+    DomainError::new(
+        ErrorCode::DatabaseError,
+        format!("Failed to {}: {}", action, e),
+    )
+}
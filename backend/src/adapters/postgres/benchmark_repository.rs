@@ -0,0 +1,147 @@
+//! PostgreSQL implementation of the benchmark port.
+//!
+//! Each published distribution is one row in `benchmark_distributions`,
+//! keyed by decision domain and metric key. Refreshes replace the whole
+//! table in one transaction, so readers never see a half-written set.
+
+use async_trait::async_trait;
+use sqlx::{PgPool, Row};
+
+use crate::domain::foundation::{DomainError, ErrorCode, Timestamp};
+use crate::domain::profile::{BenchmarkDistribution, BenchmarkMetric, DecisionDomain};
+use crate::ports::BenchmarkRepository;
+
+/// PostgreSQL implementation of BenchmarkRepository.
+#[derive(Clone)]
+pub struct PostgresBenchmarkRepository {
+    pool: PgPool,
+}
+
+impl PostgresBenchmarkRepository {
+    /// Creates a new PostgresBenchmarkRepository.
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl BenchmarkRepository for PostgresBenchmarkRepository {
+    #[tracing::instrument(name = "PostgresBenchmarkRepository::replace_all", skip_all, fields(db.system = "postgresql"), err)]
+    async fn replace_all(
+        &self,
+        distributions: &[BenchmarkDistribution],
+    ) -> Result<(), DomainError> {
+        let mut tx = self.pool.begin().await.map_err(|e| {
+            DomainError::new(
+                ErrorCode::DatabaseError,
+                format!("Failed to begin transaction: {}", e),
+            )
+        })?;
+
+        sqlx::query("DELETE FROM benchmark_distributions")
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| {
+                DomainError::new(
+                    ErrorCode::DatabaseError,
+                    format!("Failed to clear benchmark distributions: {}", e),
+                )
+            })?;
+
+        for distribution in distributions {
+            sqlx::query(
+                r#"
+                INSERT INTO benchmark_distributions
+                    (domain, metric, contributors, quantiles, generated_at)
+                VALUES ($1, $2, $3, $4, $5)
+                "#,
+            )
+            .bind(distribution.domain.as_str())
+            .bind(distribution.metric.key())
+            .bind(distribution.contributors as i32)
+            .bind(&distribution.quantiles)
+            .bind(distribution.generated_at.as_datetime())
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| {
+                DomainError::new(
+                    ErrorCode::DatabaseError,
+                    format!("Failed to save benchmark distribution: {}", e),
+                )
+            })?;
+        }
+
+        tx.commit().await.map_err(|e| {
+            DomainError::new(
+                ErrorCode::DatabaseError,
+                format!("Failed to commit transaction: {}", e),
+            )
+        })?;
+
+        Ok(())
+    }
+
+    #[tracing::instrument(name = "PostgresBenchmarkRepository::list_for_domain", skip_all, fields(db.system = "postgresql"), err)]
+    async fn list_for_domain(
+        &self,
+        domain: DecisionDomain,
+    ) -> Result<Vec<BenchmarkDistribution>, DomainError> {
+        let rows = sqlx::query(
+            "SELECT domain, metric, contributors, quantiles, generated_at \
+             FROM benchmark_distributions WHERE domain = $1 ORDER BY metric",
+        )
+        .bind(domain.as_str())
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| {
+            DomainError::new(
+                ErrorCode::DatabaseError,
+                format!("Failed to fetch benchmark distributions: {}", e),
+            )
+        })?;
+
+        rows.into_iter().map(row_to_distribution).collect()
+    }
+}
+
+fn db_error(field: &str, e: sqlx::Error) -> DomainError {
+    DomainError::new(
+        ErrorCode::DatabaseError,
+        format!("Failed to get {}: {}", field, e),
+    )
+}
+
+fn row_to_distribution(row: sqlx::postgres::PgRow) -> Result<BenchmarkDistribution, DomainError> {
+    let domain: String = row.try_get("domain").map_err(|e| db_error("domain", e))?;
+    let metric: String = row.try_get("metric").map_err(|e| db_error("metric", e))?;
+    let contributors: i32 = row
+        .try_get("contributors")
+        .map_err(|e| db_error("contributors", e))?;
+    let quantiles: Vec<f32> = row
+        .try_get("quantiles")
+        .map_err(|e| db_error("quantiles", e))?;
+    let generated_at: chrono::DateTime<chrono::Utc> = row
+        .try_get("generated_at")
+        .map_err(|e| db_error("generated_at", e))?;
+
+    let domain = DecisionDomain::parse(&domain).ok_or_else(|| {
+        DomainError::new(
+            ErrorCode::DatabaseError,
+            format!("Unknown decision domain: {}", domain),
+        )
+    })?;
+    let metric = BenchmarkMetric::parse(&metric).ok_or_else(|| {
+        DomainError::new(
+            ErrorCode::DatabaseError,
+            format!("Unknown benchmark metric: {}", metric),
+        )
+    })?;
+
+    Ok(BenchmarkDistribution {
+        domain,
+        metric,
+        contributors: contributors as u32,
+        quantiles,
+        generated_at: Timestamp::from_datetime(generated_at),
+    })
+}
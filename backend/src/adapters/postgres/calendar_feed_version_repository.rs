@@ -0,0 +1,67 @@
+//! PostgreSQL implementation of the calendar feed version port.
+//!
+//! Only rotated feeds are stored, one row per user in
+//! `calendar_feed_versions`.
+
+use async_trait::async_trait;
+use sqlx::PgPool;
+
+use crate::domain::foundation::{DomainError, ErrorCode, UserId};
+use crate::ports::CalendarFeedVersionRepository;
+
+/// PostgreSQL implementation of CalendarFeedVersionRepository.
+#[derive(Clone)]
+pub struct PostgresCalendarFeedVersionRepository {
+    pool: PgPool,
+}
+
+impl PostgresCalendarFeedVersionRepository {
+    /// Creates a new PostgresCalendarFeedVersionRepository.
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl CalendarFeedVersionRepository for PostgresCalendarFeedVersionRepository {
+    #[tracing::instrument(name = "PostgresCalendarFeedVersionRepository::current", skip_all, fields(db.system = "postgresql"), err)]
+    async fn current(&self, user_id: &UserId) -> Result<u32, DomainError> {
+        let version: Option<i32> =
+            sqlx::query_scalar("SELECT version FROM calendar_feed_versions WHERE user_id = $1")
+                .bind(user_id.as_str())
+                .fetch_optional(&self.pool)
+                .await
+                .map_err(|e| {
+                    DomainError::new(
+                        ErrorCode::DatabaseError,
+                        format!("Failed to fetch calendar feed version: {}", e),
+                    )
+                })?;
+
+        Ok(version.map_or(0, |version| version as u32))
+    }
+
+    #[tracing::instrument(name = "PostgresCalendarFeedVersionRepository::rotate", skip_all, fields(db.system = "postgresql"), err)]
+    async fn rotate(&self, user_id: &UserId) -> Result<u32, DomainError> {
+        let version: i32 = sqlx::query_scalar(
+            r#"
+            INSERT INTO calendar_feed_versions (user_id, version, rotated_at)
+            VALUES ($1, 1, NOW())
+            ON CONFLICT (user_id) DO UPDATE
+            SET version = calendar_feed_versions.version + 1, rotated_at = NOW()
+            RETURNING version
+            "#,
+        )
+        .bind(user_id.as_str())
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|e| {
+            DomainError::new(
+                ErrorCode::DatabaseError,
+                format!("Failed to rotate calendar feed version: {}", e),
+            )
+        })?;
+
+        Ok(version as u32)
+    }
+}
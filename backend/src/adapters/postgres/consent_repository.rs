@@ -0,0 +1,110 @@
+//! PostgreSQL implementation of ConsentRepository.
+//!
+//! Appends consent records to the `consent_records` ledger table.
+
+use async_trait::async_trait;
+use sqlx::{PgPool, Row};
+
+use crate::domain::consent::{ConsentRecord, ConsentType};
+use crate::domain::foundation::{ConsentRecordId, DomainError, ErrorCode, Timestamp, UserId};
+use crate::ports::ConsentRepository;
+
+/// PostgreSQL implementation of ConsentRepository.
+#[derive(Clone)]
+pub struct PostgresConsentRepository {
+    pool: PgPool,
+}
+
+impl PostgresConsentRepository {
+    /// Creates a new PostgresConsentRepository.
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl ConsentRepository for PostgresConsentRepository {
+    #[tracing::instrument(name = "PostgresConsentRepository::append", skip_all, fields(db.system = "postgresql"), err)]
+    async fn append(&self, record: &ConsentRecord) -> Result<(), DomainError> {
+        sqlx::query(
+            r#"
+            INSERT INTO consent_records (
+                id, user_id, consent_type, version, granted, source, recorded_at
+            ) VALUES ($1, $2, $3, $4, $5, $6, $7)
+            "#,
+        )
+        .bind(record.id.as_uuid())
+        .bind(record.user_id.as_str())
+        .bind(record.consent_type.as_str())
+        .bind(&record.version)
+        .bind(record.granted)
+        .bind(record.source.as_deref())
+        .bind(record.recorded_at.as_datetime())
+        .execute(&self.pool)
+        .await
+        .map_err(|e| {
+            DomainError::new(
+                ErrorCode::DatabaseError,
+                format!("Failed to insert consent record: {}", e),
+            )
+        })?;
+
+        Ok(())
+    }
+
+    #[tracing::instrument(name = "PostgresConsentRepository::list_for_user", skip_all, fields(db.system = "postgresql"), err)]
+    async fn list_for_user(&self, user_id: &UserId) -> Result<Vec<ConsentRecord>, DomainError> {
+        let rows = sqlx::query(
+            r#"
+            SELECT id, user_id, consent_type, version, granted, source, recorded_at
+            FROM consent_records
+            WHERE user_id = $1
+            ORDER BY recorded_at ASC
+            "#,
+        )
+        .bind(user_id.as_str())
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| {
+            DomainError::new(
+                ErrorCode::DatabaseError,
+                format!("Failed to fetch consent records: {}", e),
+            )
+        })?;
+
+        rows.into_iter().map(row_to_consent_record).collect()
+    }
+}
+
+fn db_error(field: &str, e: sqlx::Error) -> DomainError {
+    DomainError::new(
+        ErrorCode::DatabaseError,
+        format!("Failed to get {}: {}", field, e),
+    )
+}
+
+fn row_to_consent_record(row: sqlx::postgres::PgRow) -> Result<ConsentRecord, DomainError> {
+    let id: uuid::Uuid = row.try_get("id").map_err(|e| db_error("id", e))?;
+    let user_id: String = row.try_get("user_id").map_err(|e| db_error("user_id", e))?;
+    let consent_type: String = row
+        .try_get("consent_type")
+        .map_err(|e| db_error("consent_type", e))?;
+    let version: String = row.try_get("version").map_err(|e| db_error("version", e))?;
+    let granted: bool = row.try_get("granted").map_err(|e| db_error("granted", e))?;
+    let source: Option<String> = row.try_get("source").map_err(|e| db_error("source", e))?;
+    let recorded_at: chrono::DateTime<chrono::Utc> = row
+        .try_get("recorded_at")
+        .map_err(|e| db_error("recorded_at", e))?;
+
+    Ok(ConsentRecord {
+        id: ConsentRecordId::from_uuid(id),
+        user_id: UserId::new(user_id).map_err(|e| {
+            DomainError::new(ErrorCode::DatabaseError, format!("Invalid user_id: {}", e))
+        })?,
+        consent_type: consent_type.parse::<ConsentType>()?,
+        version,
+        granted,
+        recorded_at: Timestamp::from_datetime(recorded_at),
+        source,
+    })
+}
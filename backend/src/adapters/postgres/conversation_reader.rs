@@ -24,6 +24,7 @@ impl PostgresConversationReader {
 
 #[async_trait]
 impl ConversationReader for PostgresConversationReader {
+    #[tracing::instrument(name = "PostgresConversationReader::get", skip_all, fields(db.system = "postgresql"), err)]
     async fn get(&self, id: &ConversationId) -> Result<Option<ConversationView>, DomainError> {
         let row = sqlx::query(
             r#"
@@ -49,6 +50,7 @@ impl ConversationReader for PostgresConversationReader {
         row.map(row_to_view).transpose()
     }
 
+    #[tracing::instrument(name = "PostgresConversationReader::get_by_component", skip_all, fields(db.system = "postgresql"), err)]
     async fn get_by_component(
         &self,
         component_id: &ComponentId,
@@ -77,6 +79,7 @@ impl ConversationReader for PostgresConversationReader {
         row.map(row_to_view).transpose()
     }
 
+    #[tracing::instrument(name = "PostgresConversationReader::get_messages", skip_all, fields(db.system = "postgresql"), err)]
     async fn get_messages(
         &self,
         conversation_id: &ConversationId,
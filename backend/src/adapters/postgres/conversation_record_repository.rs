@@ -0,0 +1,517 @@
+//! PostgreSQL implementation of the conversation handlers' repository.
+//!
+//! `SendMessageHandler` and `RegenerateResponseHandler` work with
+//! `ConversationRecord`s and `StoredMessage`s rather than the `Conversation`
+//! aggregate. This stores them in the same `conversations` and `messages`
+//! tables as `PostgresConversationRepository`, keeping each message's token
+//! count, and walks messages by insertion `position` so pagination and
+//! "delete the last message" stay stable when messages share a timestamp.
+//!
+//! Conversations belong to the user who owns the component's session, so
+//! `user_id` is read through the component -> cycle -> session chain rather
+//! than stored.
+
+use async_trait::async_trait;
+use sqlx::Row;
+
+use crate::application::handlers::{
+    ConversationRecord, ConversationRepository, ConversationRepositoryExt, MessageId, MessageRole,
+    StoredMessage,
+};
+use crate::domain::conversation::{AgentPhase, ConversationState};
+use crate::domain::foundation::{
+    ComponentId, ComponentType, ConversationId, DomainError, ErrorCode, Timestamp, UserId,
+};
+
+use super::conversation_repository::{state_to_str, str_to_state};
+use super::cycle_repository::{component_type_to_str, str_to_component_type};
+use super::PostgresConversationRepository;
+
+const SELECT_CONVERSATION: &str = r#"
+    SELECT
+        c.id, c.component_id, COALESCE(c.component_type, co.component_type) AS component_type,
+        c.state, c.current_phase, c.system_prompt, c.created_at, c.updated_at,
+        s.user_id
+    FROM conversations c
+    JOIN components co ON co.id = c.component_id
+    JOIN cycles cy ON cy.id = co.cycle_id
+    JOIN sessions s ON s.id = cy.session_id
+"#;
+
+#[async_trait]
+impl ConversationRepository for PostgresConversationRepository {
+    #[tracing::instrument(name = "PostgresConversationRepository::find_record_by_component", skip_all, fields(db.system = "postgresql"), err)]
+    async fn find_by_component(
+        &self,
+        component_id: &ComponentId,
+    ) -> Result<Option<ConversationRecord>, DomainError> {
+        let row = sqlx::query(&format!(
+            "{} WHERE c.component_id = $1",
+            SELECT_CONVERSATION
+        ))
+        .bind(component_id.as_uuid())
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| db_error("fetch conversation by component", e))?;
+
+        match row {
+            Some(row) => Ok(Some(self.load_record(row).await?)),
+            None => Ok(None),
+        }
+    }
+
+    #[tracing::instrument(name = "PostgresConversationRepository::create", skip_all, fields(db.system = "postgresql"), err)]
+    async fn create(
+        &self,
+        component_id: &ComponentId,
+        component_type: ComponentType,
+        user_id: &UserId,
+        system_prompt: &str,
+    ) -> Result<ConversationRecord, DomainError> {
+        let now = Timestamp::now();
+        let record = ConversationRecord {
+            id: ConversationId::new(),
+            component_id: *component_id,
+            component_type,
+            state: ConversationState::Ready,
+            phase: AgentPhase::Intro,
+            messages: Vec::new(),
+            user_id: user_id.clone(),
+            system_prompt: system_prompt.to_string(),
+            created_at: now,
+            updated_at: now,
+        };
+
+        sqlx::query(
+            r#"
+            INSERT INTO conversations (
+                id, component_id, component_type, state, current_phase, system_prompt,
+                created_at, updated_at
+            )
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
+            "#,
+        )
+        .bind(record.id.as_uuid())
+        .bind(component_id.as_uuid())
+        .bind(component_type_to_str(component_type))
+        .bind(state_to_str(record.state))
+        .bind(phase_to_str(record.phase))
+        .bind(system_prompt)
+        .bind(now.as_datetime())
+        .bind(now.as_datetime())
+        .execute(&self.pool)
+        .await
+        .map_err(|e| {
+            if e.to_string().contains("duplicate") {
+                DomainError::new(
+                    ErrorCode::ValidationFailed,
+                    format!(
+                        "Conversation already exists for component: {}",
+                        component_id
+                    ),
+                )
+            } else {
+                db_error("insert conversation", e)
+            }
+        })?;
+
+        Ok(record)
+    }
+
+    #[tracing::instrument(name = "PostgresConversationRepository::save_record", skip_all, fields(db.system = "postgresql"), err)]
+    async fn save(&self, conversation: &ConversationRecord) -> Result<(), DomainError> {
+        let mut tx = self
+            .pool
+            .begin()
+            .await
+            .map_err(|e| db_error("begin transaction", e))?;
+
+        let result = sqlx::query(
+            r#"
+            UPDATE conversations SET
+                state = $2,
+                current_phase = $3,
+                system_prompt = $4,
+                updated_at = $5
+            WHERE id = $1
+            "#,
+        )
+        .bind(conversation.id.as_uuid())
+        .bind(state_to_str(conversation.state))
+        .bind(phase_to_str(conversation.phase))
+        .bind(&conversation.system_prompt)
+        .bind(conversation.updated_at.as_datetime())
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| db_error("update conversation", e))?;
+
+        if result.rows_affected() == 0 {
+            return Err(not_found(&conversation.id));
+        }
+
+        // The record's messages replace whatever is stored
+        let ids: Vec<uuid::Uuid> = conversation
+            .messages
+            .iter()
+            .map(|m| *m.id.as_uuid())
+            .collect();
+        sqlx::query("DELETE FROM messages WHERE conversation_id = $1 AND NOT (id = ANY($2))")
+            .bind(conversation.id.as_uuid())
+            .bind(&ids)
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| db_error("remove messages", e))?;
+        for message in &conversation.messages {
+            upsert_message(&mut tx, &conversation.id, message).await?;
+        }
+
+        tx.commit()
+            .await
+            .map_err(|e| db_error("commit transaction", e))
+    }
+
+    #[tracing::instrument(name = "PostgresConversationRepository::add_stored_message", skip_all, fields(db.system = "postgresql"), err)]
+    async fn add_message(
+        &self,
+        conversation_id: &ConversationId,
+        message: StoredMessage,
+    ) -> Result<(), DomainError> {
+        let mut tx = self
+            .pool
+            .begin()
+            .await
+            .map_err(|e| db_error("begin transaction", e))?;
+
+        let result = sqlx::query("UPDATE conversations SET updated_at = NOW() WHERE id = $1")
+            .bind(conversation_id.as_uuid())
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| db_error("update conversation timestamp", e))?;
+        if result.rows_affected() == 0 {
+            return Err(not_found(conversation_id));
+        }
+
+        upsert_message(&mut tx, conversation_id, &message).await?;
+
+        tx.commit()
+            .await
+            .map_err(|e| db_error("commit transaction", e))
+    }
+
+    #[tracing::instrument(name = "PostgresConversationRepository::update_state", skip_all, fields(db.system = "postgresql"), err)]
+    async fn update_state(
+        &self,
+        conversation_id: &ConversationId,
+        state: ConversationState,
+        phase: AgentPhase,
+    ) -> Result<(), DomainError> {
+        let result = sqlx::query(
+            r#"
+            UPDATE conversations SET
+                state = $2,
+                current_phase = $3,
+                updated_at = NOW()
+            WHERE id = $1
+            "#,
+        )
+        .bind(conversation_id.as_uuid())
+        .bind(state_to_str(state))
+        .bind(phase_to_str(phase))
+        .execute(&self.pool)
+        .await
+        .map_err(|e| db_error("update conversation state", e))?;
+
+        if result.rows_affected() == 0 {
+            return Err(not_found(conversation_id));
+        }
+        Ok(())
+    }
+
+    #[tracing::instrument(name = "PostgresConversationRepository::find_record_by_id", skip_all, fields(db.system = "postgresql"), err)]
+    async fn find_by_id(
+        &self,
+        conversation_id: &ConversationId,
+    ) -> Result<Option<ConversationRecord>, DomainError> {
+        let row = sqlx::query(&format!("{} WHERE c.id = $1", SELECT_CONVERSATION))
+            .bind(conversation_id.as_uuid())
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(|e| db_error("fetch conversation", e))?;
+
+        match row {
+            Some(row) => Ok(Some(self.load_record(row).await?)),
+            None => Ok(None),
+        }
+    }
+
+    #[tracing::instrument(name = "PostgresConversationRepository::get_messages", skip_all, fields(db.system = "postgresql"), err)]
+    async fn get_messages(
+        &self,
+        conversation_id: &ConversationId,
+        offset: u32,
+        limit: u32,
+    ) -> Result<(Vec<StoredMessage>, u32), DomainError> {
+        let rows = sqlx::query(
+            r#"
+            SELECT id, role, content, token_count, created_at
+            FROM messages
+            WHERE conversation_id = $1
+            ORDER BY position ASC
+            LIMIT $2 OFFSET $3
+            "#,
+        )
+        .bind(conversation_id.as_uuid())
+        .bind(limit as i64)
+        .bind(offset as i64)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| db_error("load messages", e))?;
+
+        let total: i64 =
+            sqlx::query_scalar("SELECT COUNT(*) FROM messages WHERE conversation_id = $1")
+                .bind(conversation_id.as_uuid())
+                .fetch_one(&self.pool)
+                .await
+                .map_err(|e| db_error("count messages", e))?;
+
+        let messages = rows
+            .into_iter()
+            .map(row_to_message)
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok((messages, total as u32))
+    }
+}
+
+#[async_trait]
+impl ConversationRepositoryExt for PostgresConversationRepository {
+    #[tracing::instrument(name = "PostgresConversationRepository::delete_last_message", skip_all, fields(db.system = "postgresql"), err)]
+    async fn delete_last_message(
+        &self,
+        conversation_id: &ConversationId,
+    ) -> Result<Option<MessageId>, DomainError> {
+        let deleted: Option<uuid::Uuid> = sqlx::query_scalar(
+            r#"
+            DELETE FROM messages
+            WHERE id = (
+                SELECT id FROM messages
+                WHERE conversation_id = $1
+                ORDER BY position DESC
+                LIMIT 1
+            )
+            RETURNING id
+            "#,
+        )
+        .bind(conversation_id.as_uuid())
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| db_error("delete last message", e))?;
+
+        Ok(deleted.map(MessageId::from_uuid))
+    }
+}
+
+impl PostgresConversationRepository {
+    async fn load_record(
+        &self,
+        row: sqlx::postgres::PgRow,
+    ) -> Result<ConversationRecord, DomainError> {
+        let id: uuid::Uuid = row.try_get("id").map_err(|e| db_error("read id", e))?;
+        let conversation_id = ConversationId::from_uuid(id);
+        let (messages, _) = self.get_messages(&conversation_id, 0, u32::MAX).await?;
+        row_to_record(row, messages)
+    }
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Helper functions
+// ─────────────────────────────────────────────────────────────────────────────
+
+async fn upsert_message(
+    tx: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+    conversation_id: &ConversationId,
+    message: &StoredMessage,
+) -> Result<(), DomainError> {
+    sqlx::query(
+        r#"
+        INSERT INTO messages (id, conversation_id, role, content, token_count, created_at)
+        VALUES ($1, $2, $3, $4, $5, $6)
+        ON CONFLICT (id) DO UPDATE SET
+            content = EXCLUDED.content,
+            token_count = EXCLUDED.token_count
+        "#,
+    )
+    .bind(message.id.as_uuid())
+    .bind(conversation_id.as_uuid())
+    .bind(role_to_str(message.role))
+    .bind(&message.content)
+    .bind(message.token_count.map(|count| count as i32))
+    .bind(message.created_at.as_datetime())
+    .execute(&mut **tx)
+    .await
+    .map_err(|e| db_error("insert message", e))?;
+
+    Ok(())
+}
+
+fn row_to_record(
+    row: sqlx::postgres::PgRow,
+    messages: Vec<StoredMessage>,
+) -> Result<ConversationRecord, DomainError> {
+    let id: uuid::Uuid = row.try_get("id").map_err(|e| db_error("read id", e))?;
+    let component_id: uuid::Uuid = row
+        .try_get("component_id")
+        .map_err(|e| db_error("read component_id", e))?;
+    let component_type: String = row
+        .try_get("component_type")
+        .map_err(|e| db_error("read component_type", e))?;
+    let state: String = row
+        .try_get("state")
+        .map_err(|e| db_error("read state", e))?;
+    let phase: String = row
+        .try_get("current_phase")
+        .map_err(|e| db_error("read current_phase", e))?;
+    let system_prompt: String = row
+        .try_get("system_prompt")
+        .map_err(|e| db_error("read system_prompt", e))?;
+    let user_id: String = row
+        .try_get("user_id")
+        .map_err(|e| db_error("read user_id", e))?;
+    let created_at: chrono::DateTime<chrono::Utc> = row
+        .try_get("created_at")
+        .map_err(|e| db_error("read created_at", e))?;
+    let updated_at: chrono::DateTime<chrono::Utc> = row
+        .try_get("updated_at")
+        .map_err(|e| db_error("read updated_at", e))?;
+
+    Ok(ConversationRecord {
+        id: ConversationId::from_uuid(id),
+        component_id: ComponentId::from_uuid(component_id),
+        component_type: str_to_component_type(&component_type)?,
+        state: str_to_state(&state)?,
+        phase: str_to_phase(&phase)?,
+        messages,
+        user_id: UserId::new(user_id).map_err(|e| {
+            DomainError::new(ErrorCode::DatabaseError, format!("Invalid user_id: {}", e))
+        })?,
+        system_prompt,
+        created_at: Timestamp::from_datetime(created_at),
+        updated_at: Timestamp::from_datetime(updated_at),
+    })
+}
+
+fn row_to_message(row: sqlx::postgres::PgRow) -> Result<StoredMessage, DomainError> {
+    let id: uuid::Uuid = row.try_get("id").map_err(|e| db_error("read id", e))?;
+    let role: String = row.try_get("role").map_err(|e| db_error("read role", e))?;
+    let content: String = row
+        .try_get("content")
+        .map_err(|e| db_error("read content", e))?;
+    let token_count: Option<i32> = row
+        .try_get("token_count")
+        .map_err(|e| db_error("read token_count", e))?;
+    let created_at: chrono::DateTime<chrono::Utc> = row
+        .try_get("created_at")
+        .map_err(|e| db_error("read created_at", e))?;
+
+    Ok(StoredMessage {
+        id: MessageId::from_uuid(id),
+        role: str_to_role(&role)?,
+        content,
+        created_at: Timestamp::from_datetime(created_at),
+        token_count: token_count.map(|count| count as u32),
+    })
+}
+
+fn phase_to_str(phase: AgentPhase) -> &'static str {
+    match phase {
+        AgentPhase::Intro => "intro",
+        AgentPhase::Gather => "gather",
+        AgentPhase::Clarify => "clarify",
+        AgentPhase::Extract => "extract",
+        AgentPhase::Confirm => "confirm",
+    }
+}
+
+fn str_to_phase(s: &str) -> Result<AgentPhase, DomainError> {
+    match s {
+        "intro" => Ok(AgentPhase::Intro),
+        "gather" => Ok(AgentPhase::Gather),
+        "clarify" => Ok(AgentPhase::Clarify),
+        "extract" => Ok(AgentPhase::Extract),
+        "confirm" => Ok(AgentPhase::Confirm),
+        _ => Err(DomainError::new(
+            ErrorCode::DatabaseError,
+            format!("Invalid agent phase: {}", s),
+        )),
+    }
+}
+
+fn role_to_str(role: MessageRole) -> &'static str {
+    match role {
+        MessageRole::System => "system",
+        MessageRole::User => "user",
+        MessageRole::Assistant => "assistant",
+    }
+}
+
+fn str_to_role(s: &str) -> Result<MessageRole, DomainError> {
+    match s {
+        "system" => Ok(MessageRole::System),
+        "user" => Ok(MessageRole::User),
+        "assistant" => Ok(MessageRole::Assistant),
+        _ => Err(DomainError::new(
+            ErrorCode::DatabaseError,
+            format!("Invalid message role: {}", s),
+        )),
+    }
+}
+
+fn not_found(id: &ConversationId) -> DomainError {
+    DomainError::new(
+        ErrorCode::ConversationNotFound,
+        format!("Conversation not found: {}", id),
+    )
+}
+
+fn db_error(action: &str, e: sqlx::Error) -> DomainError {
+    DomainError::new(
+        ErrorCode::DatabaseError,
+        format!("Failed to {}: {}", action, e),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn phases_round_trip() {
+        for phase in [
+            AgentPhase::Intro,
+            AgentPhase::Gather,
+            AgentPhase::Clarify,
+            AgentPhase::Extract,
+            AgentPhase::Confirm,
+        ] {
+            assert_eq!(str_to_phase(phase_to_str(phase)).unwrap(), phase);
+        }
+        assert!(str_to_phase("wander").is_err());
+    }
+
+    #[test]
+    fn phases_match_their_serde_names() {
+        let json = serde_json::to_value(AgentPhase::Clarify).unwrap();
+        assert_eq!(json, phase_to_str(AgentPhase::Clarify));
+    }
+
+    #[test]
+    fn roles_round_trip() {
+        for role in [
+            MessageRole::System,
+            MessageRole::User,
+            MessageRole::Assistant,
+        ] {
+            assert_eq!(str_to_role(role_to_str(role)).unwrap(), role);
+        }
+        assert!(str_to_role("narrator").is_err());
+    }
+}
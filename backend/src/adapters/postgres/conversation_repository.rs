@@ -12,7 +12,7 @@ use crate::ports::ConversationRepository;
 /// PostgreSQL implementation of ConversationRepository.
 #[derive(Clone)]
 pub struct PostgresConversationRepository {
-    pool: PgPool,
+    pub(super) pool: PgPool,
 }
 
 impl PostgresConversationRepository {
@@ -24,6 +24,7 @@ impl PostgresConversationRepository {
 
 #[async_trait]
 impl ConversationRepository for PostgresConversationRepository {
+    #[tracing::instrument(name = "PostgresConversationRepository::save", skip_all, fields(db.system = "postgresql"), err)]
     async fn save(&self, conversation: &Conversation) -> Result<(), DomainError> {
         let mut tx = self.pool.begin().await.map_err(|e| {
             DomainError::new(
@@ -78,6 +79,7 @@ impl ConversationRepository for PostgresConversationRepository {
         Ok(())
     }
 
+    #[tracing::instrument(name = "PostgresConversationRepository::update", skip_all, fields(db.system = "postgresql"), err)]
     async fn update(&self, conversation: &Conversation) -> Result<(), DomainError> {
         let result = sqlx::query(
             r#"
@@ -109,6 +111,7 @@ impl ConversationRepository for PostgresConversationRepository {
         Ok(())
     }
 
+    #[tracing::instrument(name = "PostgresConversationRepository::add_message", skip_all, fields(db.system = "postgresql"), err)]
     async fn add_message(
         &self,
         conversation_id: &ConversationId,
@@ -154,6 +157,7 @@ impl ConversationRepository for PostgresConversationRepository {
         Ok(())
     }
 
+    #[tracing::instrument(name = "PostgresConversationRepository::find_by_id", skip_all, fields(db.system = "postgresql"), err)]
     async fn find_by_id(&self, id: &ConversationId) -> Result<Option<Conversation>, DomainError> {
         let row = sqlx::query(
             r#"
@@ -181,6 +185,7 @@ impl ConversationRepository for PostgresConversationRepository {
         }
     }
 
+    #[tracing::instrument(name = "PostgresConversationRepository::find_by_component", skip_all, fields(db.system = "postgresql"), err)]
     async fn find_by_component(
         &self,
         component_id: &ComponentId,
@@ -213,6 +218,7 @@ impl ConversationRepository for PostgresConversationRepository {
         }
     }
 
+    #[tracing::instrument(name = "PostgresConversationRepository::exists_for_component", skip_all, fields(db.system = "postgresql"), err)]
     async fn exists_for_component(&self, component_id: &ComponentId) -> Result<bool, DomainError> {
         let result: (i64,) =
             sqlx::query_as("SELECT COUNT(*) FROM conversations WHERE component_id = $1")
@@ -229,6 +235,7 @@ impl ConversationRepository for PostgresConversationRepository {
         Ok(result.0 > 0)
     }
 
+    #[tracing::instrument(name = "PostgresConversationRepository::delete", skip_all, fields(db.system = "postgresql"), err)]
     async fn delete(&self, id: &ConversationId) -> Result<(), DomainError> {
         let result = sqlx::query("DELETE FROM conversations WHERE id = $1")
             .bind(id.as_uuid())
@@ -363,7 +370,7 @@ fn row_to_conversation(
     ))
 }
 
-fn state_to_str(state: ConversationState) -> &'static str {
+pub(super) fn state_to_str(state: ConversationState) -> &'static str {
     match state {
         ConversationState::Initializing => "initializing",
         ConversationState::Ready => "ready",
@@ -373,7 +380,7 @@ fn state_to_str(state: ConversationState) -> &'static str {
     }
 }
 
-fn str_to_state(s: &str) -> Result<ConversationState, DomainError> {
+pub(super) fn str_to_state(s: &str) -> Result<ConversationState, DomainError> {
     match s {
         "initializing" => Ok(ConversationState::Initializing),
         "ready" => Ok(ConversationState::Ready),
@@ -0,0 +1,248 @@
+//! PostgreSQL implementation of CycleEventStore.
+//!
+//! Events live in `cycle_events` keyed by cycle and version, with payloads
+//! stored as JSONB under their versioned type so older shapes can be upcast
+//! on read. `PostgresCycleRepository` appends in the same transaction that
+//! updates the cycle tables, so the two never disagree.
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::{PgPool, Postgres, Row, Transaction};
+
+use crate::domain::cycle::{
+    crosses_snapshot_boundary, cycle_event_upcasters, Cycle, CycleEvent, CycleSnapshot,
+    RecordedCycleEvent, CYCLE_AGGREGATE_TYPE,
+};
+use crate::domain::foundation::{
+    CycleId, DomainError, ErrorCode, EventEnvelope, Timestamp, UpcasterRegistry,
+};
+use crate::ports::CycleEventStore;
+
+/// PostgreSQL implementation of CycleEventStore.
+#[derive(Clone)]
+pub struct PostgresCycleEventStore {
+    pool: PgPool,
+    upcasters: Arc<UpcasterRegistry>,
+}
+
+impl PostgresCycleEventStore {
+    /// Creates a new PostgresCycleEventStore using the cycle upcasters.
+    pub fn new(pool: PgPool) -> Self {
+        Self {
+            pool,
+            upcasters: Arc::new(cycle_event_upcasters()),
+        }
+    }
+}
+
+#[async_trait]
+impl CycleEventStore for PostgresCycleEventStore {
+    #[tracing::instrument(name = "PostgresCycleEventStore::append", skip_all, fields(db.system = "postgresql"), err)]
+    async fn append(&self, cycle: &Cycle) -> Result<(), DomainError> {
+        let mut tx = self
+            .pool
+            .begin()
+            .await
+            .map_err(|e| db_error("begin transaction", e))?;
+        append_cycle_events(&mut tx, cycle).await?;
+        tx.commit()
+            .await
+            .map_err(|e| db_error("commit transaction", e))
+    }
+
+    #[tracing::instrument(name = "PostgresCycleEventStore::load_events", skip_all, fields(db.system = "postgresql"), err)]
+    async fn load_events(
+        &self,
+        cycle_id: &CycleId,
+        after_version: u64,
+        up_to_version: Option<u64>,
+    ) -> Result<Vec<RecordedCycleEvent>, DomainError> {
+        let rows = sqlx::query(
+            r#"
+            SELECT version, event_type, payload, occurred_at
+            FROM cycle_events
+            WHERE cycle_id = $1 AND version > $2 AND ($3::BIGINT IS NULL OR version <= $3)
+            ORDER BY version
+            "#,
+        )
+        .bind(cycle_id.as_uuid())
+        .bind(after_version as i64)
+        .bind(up_to_version.map(|v| v as i64))
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| db_error("load cycle events", e))?;
+
+        rows.into_iter()
+            .map(|row| {
+                let version: i64 = row
+                    .try_get("version")
+                    .map_err(|e| db_error("get version", e))?;
+                let event_type: String = row
+                    .try_get("event_type")
+                    .map_err(|e| db_error("get event_type", e))?;
+                let payload: serde_json::Value = row
+                    .try_get("payload")
+                    .map_err(|e| db_error("get payload", e))?;
+                let occurred_at: DateTime<Utc> = row
+                    .try_get("occurred_at")
+                    .map_err(|e| db_error("get occurred_at", e))?;
+
+                let mut envelope = EventEnvelope::new(
+                    event_type,
+                    cycle_id.to_string(),
+                    CYCLE_AGGREGATE_TYPE,
+                    payload,
+                );
+                envelope.occurred_at = Timestamp::from_datetime(occurred_at);
+                let event = CycleEvent::from_envelope(envelope, &self.upcasters).map_err(|e| {
+                    DomainError::new(
+                        ErrorCode::DatabaseError,
+                        format!(
+                            "Invalid event at version {} of cycle {}: {}",
+                            version, cycle_id, e
+                        ),
+                    )
+                })?;
+
+                Ok(RecordedCycleEvent {
+                    version: version as u64,
+                    occurred_at: Timestamp::from_datetime(occurred_at),
+                    event,
+                })
+            })
+            .collect()
+    }
+
+    #[tracing::instrument(name = "PostgresCycleEventStore::latest_snapshot", skip_all, fields(db.system = "postgresql"), err)]
+    async fn latest_snapshot(
+        &self,
+        cycle_id: &CycleId,
+        at_version: Option<u64>,
+    ) -> Result<Option<CycleSnapshot>, DomainError> {
+        let state: Option<serde_json::Value> = sqlx::query_scalar(
+            r#"
+            SELECT state FROM cycle_snapshots
+            WHERE cycle_id = $1 AND ($2::BIGINT IS NULL OR version <= $2)
+            ORDER BY version DESC
+            LIMIT 1
+            "#,
+        )
+        .bind(cycle_id.as_uuid())
+        .bind(at_version.map(|v| v as i64))
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| db_error("load cycle snapshot", e))?;
+
+        state
+            .map(|state| {
+                serde_json::from_value(state).map_err(|e| {
+                    DomainError::new(
+                        ErrorCode::DatabaseError,
+                        format!("Invalid snapshot of cycle {}: {}", cycle_id, e),
+                    )
+                })
+            })
+            .transpose()
+    }
+}
+
+/// Appends the cycle's uncommitted events within `tx`, snapshotting when the
+/// cycle crosses a snapshot boundary or has no stored history yet.
+///
+/// A version that is already stored means another writer got there first;
+/// the insert fails and the caller's transaction is rolled back.
+pub(super) async fn append_cycle_events(
+    tx: &mut Transaction<'_, Postgres>,
+    cycle: &Cycle,
+) -> Result<(), DomainError> {
+    let pending = cycle.uncommitted_events();
+    let loaded_version = cycle.loaded_version();
+
+    let has_history: bool = sqlx::query_scalar(
+        r#"
+        SELECT EXISTS (SELECT 1 FROM cycle_events WHERE cycle_id = $1)
+            OR EXISTS (SELECT 1 FROM cycle_snapshots WHERE cycle_id = $1)
+        "#,
+    )
+    .bind(cycle.id().as_uuid())
+    .fetch_one(&mut **tx)
+    .await
+    .map_err(|e| db_error("check cycle history", e))?;
+
+    for recorded in pending {
+        let envelope = recorded
+            .event
+            .to_envelope(recorded.occurred_at)
+            .map_err(|e| {
+                DomainError::new(
+                    ErrorCode::InternalError,
+                    format!("Failed to serialize cycle event: {}", e),
+                )
+            })?;
+
+        sqlx::query(
+            r#"
+            INSERT INTO cycle_events (
+                cycle_id, version, event_type, schema_version, payload, occurred_at
+            ) VALUES ($1, $2, $3, $4, $5, $6)
+            "#,
+        )
+        .bind(cycle.id().as_uuid())
+        .bind(recorded.version as i64)
+        .bind(&envelope.event_type)
+        .bind(envelope.schema_version as i32)
+        .bind(&envelope.payload)
+        .bind(recorded.occurred_at.as_datetime())
+        .execute(&mut **tx)
+        .await
+        .map_err(|e| append_error(cycle, "append cycle event", e))?;
+    }
+
+    let needs_baseline = !has_history && loaded_version > 0;
+    if needs_baseline || crosses_snapshot_boundary(loaded_version, cycle.version()) {
+        let state = serde_json::to_value(cycle.snapshot()).map_err(|e| {
+            DomainError::new(
+                ErrorCode::InternalError,
+                format!("Failed to serialize cycle snapshot: {}", e),
+            )
+        })?;
+
+        sqlx::query(
+            r#"
+            INSERT INTO cycle_snapshots (cycle_id, version, state)
+            VALUES ($1, $2, $3)
+            "#,
+        )
+        .bind(cycle.id().as_uuid())
+        .bind(cycle.version() as i64)
+        .bind(state)
+        .execute(&mut **tx)
+        .await
+        .map_err(|e| append_error(cycle, "save cycle snapshot", e))?;
+    }
+
+    Ok(())
+}
+
+fn append_error(cycle: &Cycle, action: &str, e: sqlx::Error) -> DomainError {
+    match &e {
+        sqlx::Error::Database(db_err) if db_err.is_unique_violation() => DomainError::new(
+            ErrorCode::ConcurrentModification,
+            format!(
+                "Cycle {} was modified since version {}",
+                cycle.id(),
+                cycle.loaded_version()
+            ),
+        ),
+        _ => db_error(action, e),
+    }
+}
+
+fn db_error(action: &str, e: sqlx::Error) -> DomainError {
+    DomainError::new(
+        ErrorCode::DatabaseError,
+        format!("Failed to {}: {}", action, e),
+    )
+}
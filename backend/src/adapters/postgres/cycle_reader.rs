@@ -8,31 +8,68 @@ use async_trait::async_trait;
 use sqlx::{PgPool, Row};
 use uuid::Uuid;
 
+use crate::adapters::cache::ViewCache;
 use crate::domain::foundation::{
-    ComponentStatus, ComponentType, CycleId, CycleStatus, DomainError, ErrorCode, SessionId,
-    Timestamp,
+    ComponentStatus, ComponentType, CycleId, CycleStatus, DomainError, ErrorCode, OrganizationId,
+    SessionId, Timestamp,
 };
 use crate::ports::{
-    ComponentOutputView, ComponentStatusItem, CycleProgressView, CycleReader, CycleSummary,
+    cycle_cache_tag, ComponentOutputView, ComponentStatusItem, CycleProgressView, CycleReader, CycleSummary,
     CycleTreeNode, CycleView, NextAction, NextActionType, ProgressStep,
 };
 
 /// PostgreSQL implementation of CycleReader.
+///
+/// With a `ViewCache`, cycle views and progress views are served from the
+/// cache until the cycle's tag is invalidated.
 #[derive(Clone)]
 pub struct PostgresCycleReader {
     pool: PgPool,
+    cache: Option<ViewCache>,
 }
 
 impl PostgresCycleReader {
     /// Creates a new PostgresCycleReader.
     pub fn new(pool: PgPool) -> Self {
-        Self { pool }
+        Self { pool, cache: None }
+    }
+
+    /// Caches the hot cycle views.
+    pub fn with_cache(mut self, cache: ViewCache) -> Self {
+        self.cache = Some(cache);
+        self
+    }
+
+    async fn cached<T: serde::de::DeserializeOwned>(&self, key: &str) -> Option<T> {
+        match &self.cache {
+            Some(cache) => cache.get(key).await,
+            None => None,
+        }
+    }
+
+    async fn store<T: serde::Serialize + Sync>(&self, key: &str, value: &T, id: &CycleId) {
+        if let Some(cache) = &self.cache {
+            cache.put(key, value, vec![cycle_cache_tag(id)]).await;
+        }
     }
 }
 
+fn view_key(id: &CycleId) -> String {
+    format!("cycle:view:{}", id)
+}
+
+fn progress_key(id: &CycleId) -> String {
+    format!("cycle:progress:{}", id)
+}
+
 #[async_trait]
 impl CycleReader for PostgresCycleReader {
+    #[tracing::instrument(name = "PostgresCycleReader::get_by_id", skip_all, fields(db.system = "postgresql"), err)]
     async fn get_by_id(&self, id: &CycleId) -> Result<Option<CycleView>, DomainError> {
+        if let Some(view) = self.cached(&view_key(id)).await {
+            return Ok(Some(view));
+        }
+
         // Fetch cycle
         let cycle_row = sqlx::query(
             r#"
@@ -104,8 +141,8 @@ impl CycleReader for PostgresCycleReader {
             });
         }
 
-        // Calculate progress (8 required components, NotesNextSteps is optional)
-        let required_count = 8u8;
+        // Calculate progress (10 required components, NotesNextSteps is optional)
+        let required_count = 10u8;
         let progress_percent = ((completed_count as f32 / required_count as f32) * 100.0) as u8;
         let is_complete = completed_count >= required_count;
 
@@ -113,7 +150,7 @@ impl CycleReader for PostgresCycleReader {
         let branch_point_str: Option<String> = cycle_row.get("branch_point");
         let parent_id: Option<Uuid> = cycle_row.get("parent_cycle_id");
 
-        Ok(Some(CycleView {
+        let view = CycleView {
             id: *id,
             session_id: SessionId::from_uuid(cycle_row.get("session_id")),
             parent_cycle_id: parent_id.map(CycleId::from_uuid),
@@ -128,9 +165,12 @@ impl CycleReader for PostgresCycleReader {
             branch_count: branch_count.0 as u32,
             created_at: Timestamp::from_datetime(cycle_row.get("created_at")),
             updated_at: Timestamp::from_datetime(cycle_row.get("updated_at")),
-        }))
+        };
+        self.store(&view_key(id), &view, id).await;
+        Ok(Some(view))
     }
 
+    #[tracing::instrument(name = "PostgresCycleReader::list_by_session_id", skip_all, fields(db.system = "postgresql"), err)]
     async fn list_by_session_id(
         &self,
         session_id: &SessionId,
@@ -151,34 +191,43 @@ impl CycleReader for PostgresCycleReader {
         .await
         .map_err(|e| db_error(&format!("Failed to fetch cycles: {}", e)))?;
 
-        let required_count = 8u8;
-        let mut summaries = Vec::with_capacity(rows.len());
-
-        for row in rows {
-            let parent_id: Option<Uuid> = row.get("parent_cycle_id");
-            let branch_point_str: Option<String> = row.get("branch_point");
-            let status_str: String = row.get("status");
-            let current_step_str: String = row.get("current_step");
-            let completed_count: i64 = row.get("completed_count");
+        rows.into_iter().map(row_to_cycle_summary).collect()
+    }
 
-            let progress = ((completed_count as f32 / required_count as f32) * 100.0) as u8;
+    #[tracing::instrument(name = "PostgresCycleReader::list_by_organization", skip_all, fields(db.system = "postgresql"), err)]
+    async fn list_by_organization(
+        &self,
+        organization_id: &OrganizationId,
+    ) -> Result<HashMap<SessionId, Vec<CycleSummary>>, DomainError> {
+        let rows = sqlx::query(
+            r#"
+            SELECT c.id, c.session_id, c.parent_cycle_id, c.branch_point, c.status,
+                   c.current_step, c.created_at,
+                   (SELECT COUNT(*) FROM components comp
+                    WHERE comp.cycle_id = c.id AND comp.status = 'complete') as completed_count
+            FROM cycles c
+            JOIN sessions s ON s.id = c.session_id
+            WHERE s.organization_id = $1
+            ORDER BY c.created_at DESC
+            "#,
+        )
+        .bind(organization_id.as_uuid())
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| db_error(&format!("Failed to fetch organization cycles: {}", e)))?;
 
-            summaries.push(CycleSummary {
-                id: CycleId::from_uuid(row.get("id")),
-                is_branch: parent_id.is_some(),
-                branch_point: branch_point_str
-                    .map(|s| str_to_component_type(&s))
-                    .transpose()?,
-                status: str_to_cycle_status(&status_str)?,
-                current_step: str_to_component_type(&current_step_str)?,
-                progress_percent: progress.min(100),
-                created_at: Timestamp::from_datetime(row.get("created_at")),
-            });
+        let mut by_session: HashMap<SessionId, Vec<CycleSummary>> = HashMap::new();
+        for row in rows {
+            let session_id = SessionId::from_uuid(row.get("session_id"));
+            by_session
+                .entry(session_id)
+                .or_default()
+                .push(row_to_cycle_summary(row)?);
         }
-
-        Ok(summaries)
+        Ok(by_session)
     }
 
+    #[tracing::instrument(name = "PostgresCycleReader::get_tree", skip_all, fields(db.system = "postgresql"), err)]
     async fn get_tree(&self, session_id: &SessionId) -> Result<Option<CycleTreeNode>, DomainError> {
         // Fetch all cycles for session
         let rows = sqlx::query(
@@ -201,7 +250,7 @@ impl CycleReader for PostgresCycleReader {
             return Ok(None);
         }
 
-        let required_count = 8u8;
+        let required_count = 10u8;
 
         // Build summaries and parent mapping
         let mut summaries: HashMap<Uuid, CycleSummary> = HashMap::new();
@@ -268,7 +317,12 @@ impl CycleReader for PostgresCycleReader {
         Ok(build_node(root_id, &summaries, &parent_map))
     }
 
+    #[tracing::instrument(name = "PostgresCycleReader::get_progress", skip_all, fields(db.system = "postgresql"), err)]
     async fn get_progress(&self, id: &CycleId) -> Result<Option<CycleProgressView>, DomainError> {
+        if let Some(progress) = self.cached(&progress_key(id)).await {
+            return Ok(Some(progress));
+        }
+
         // Fetch cycle
         let cycle_row = sqlx::query(
             r#"
@@ -317,123 +371,12 @@ impl CycleReader for PostgresCycleReader {
             }
         }
 
-        // Build progress steps
-        let mut steps = Vec::new();
-        let mut completed_count = 0u8;
-        let mut has_revisions = false;
-        let mut first_incomplete: Option<ComponentType> = None;
-        let mut first_revision: Option<ComponentType> = None;
-
-        // Required components (all except NotesNextSteps which is optional)
-        let required_components = [
-            ComponentType::IssueRaising,
-            ComponentType::ProblemFrame,
-            ComponentType::Objectives,
-            ComponentType::Alternatives,
-            ComponentType::Consequences,
-            ComponentType::Tradeoffs,
-            ComponentType::Recommendation,
-            ComponentType::DecisionQuality,
-        ];
-
-        for (i, ct) in ComponentType::all().iter().enumerate() {
-            let status = status_map
-                .get(ct)
-                .copied()
-                .unwrap_or(ComponentStatus::NotStarted);
-
-            let is_required = required_components.contains(ct);
-            if is_required && status == ComponentStatus::Complete {
-                completed_count += 1;
-            }
-
-            if status == ComponentStatus::NeedsRevision {
-                has_revisions = true;
-                if first_revision.is_none() {
-                    first_revision = Some(*ct);
-                }
-            }
-
-            if first_incomplete.is_none()
-                && is_required
-                && status != ComponentStatus::Complete
-            {
-                first_incomplete = Some(*ct);
-            }
-
-            // Accessible if previous step is complete or it's the first step
-            let is_accessible = i == 0
-                || ComponentType::all()
-                    .get(i - 1)
-                    .map(|prev| {
-                        status_map.get(prev).copied() == Some(ComponentStatus::Complete)
-                    })
-                    .unwrap_or(false)
-                || status != ComponentStatus::NotStarted;
-
-            steps.push(ProgressStep {
-                component_type: *ct,
-                name: component_display_name(*ct),
-                status,
-                is_current: *ct == current_step,
-                is_accessible,
-            });
-        }
-
-        let required_count = 8u8;
-        let progress_percent = ((completed_count as f32 / required_count as f32) * 100.0) as u8;
-        let is_complete = completed_count >= required_count;
-
-        // Determine next action
-        let next_action = if cycle_status == CycleStatus::Completed || is_complete {
-            Some(NextAction {
-                action_type: NextActionType::AlreadyComplete,
-                component: None,
-                description: "Cycle is complete".to_string(),
-            })
-        } else if let Some(rev_ct) = first_revision {
-            Some(NextAction {
-                action_type: NextActionType::ReviseComponent,
-                component: Some(rev_ct),
-                description: format!("Revise {}", component_display_name(rev_ct)),
-            })
-        } else if status_map.get(&current_step) == Some(&ComponentStatus::InProgress) {
-            Some(NextAction {
-                action_type: NextActionType::ContinueCurrent,
-                component: Some(current_step),
-                description: format!("Continue {}", component_display_name(current_step)),
-            })
-        } else if let Some(next_ct) = first_incomplete {
-            let action_type = if status_map.is_empty() {
-                NextActionType::StartFirst
-            } else {
-                NextActionType::StartNext
-            };
-            Some(NextAction {
-                action_type,
-                component: Some(next_ct),
-                description: format!("Start {}", component_display_name(next_ct)),
-            })
-        } else {
-            Some(NextAction {
-                action_type: NextActionType::CompleteCycle,
-                component: None,
-                description: "Complete the cycle".to_string(),
-            })
-        };
-
-        Ok(Some(CycleProgressView {
-            cycle_id: *id,
-            progress_percent: progress_percent.min(100),
-            completed_count,
-            required_count,
-            is_complete,
-            has_revisions,
-            steps,
-            next_action,
-        }))
+        let progress = progress_view(*id, current_step, cycle_status, &status_map);
+        self.store(&progress_key(id), &progress, id).await;
+        Ok(Some(progress))
     }
 
+    #[tracing::instrument(name = "PostgresCycleReader::get_lineage", skip_all, fields(db.system = "postgresql"), err)]
     async fn get_lineage(&self, id: &CycleId) -> Result<Vec<CycleSummary>, DomainError> {
         // Use recursive CTE to get lineage
         let rows = sqlx::query(
@@ -460,7 +403,7 @@ impl CycleReader for PostgresCycleReader {
         .await
         .map_err(|e| db_error(&format!("Failed to fetch lineage: {}", e)))?;
 
-        let required_count = 8u8;
+        let required_count = 10u8;
         let mut summaries = Vec::with_capacity(rows.len());
 
         for row in rows {
@@ -488,6 +431,7 @@ impl CycleReader for PostgresCycleReader {
         Ok(summaries)
     }
 
+    #[tracing::instrument(name = "PostgresCycleReader::get_component_output", skip_all, fields(db.system = "postgresql"), err)]
     async fn get_component_output(
         &self,
         cycle_id: &CycleId,
@@ -526,6 +470,50 @@ impl CycleReader for PostgresCycleReader {
         }
     }
 
+    #[tracing::instrument(name = "PostgresCycleReader::get_component_outputs", skip_all, fields(db.system = "postgresql"), err)]
+    async fn get_component_outputs(
+        &self,
+        cycle_id: &CycleId,
+        component_types: &[ComponentType],
+    ) -> Result<HashMap<ComponentType, ComponentOutputView>, DomainError> {
+        let type_strs: Vec<String> = component_types
+            .iter()
+            .map(|ct| component_type_to_str(*ct).to_string())
+            .collect();
+
+        let rows = sqlx::query(
+            r#"
+            SELECT component_type, status, output, updated_at
+            FROM components
+            WHERE cycle_id = $1 AND component_type = ANY($2)
+            "#,
+        )
+        .bind(cycle_id.as_uuid())
+        .bind(&type_strs)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| db_error(&format!("Failed to fetch component outputs: {}", e)))?;
+
+        let mut outputs = HashMap::new();
+        for row in rows {
+            let type_str: String = row.get("component_type");
+            let status_str: String = row.get("status");
+            let component_type = str_to_component_type(&type_str)?;
+            outputs.insert(
+                component_type,
+                ComponentOutputView {
+                    cycle_id: *cycle_id,
+                    component_type,
+                    status: str_to_component_status(&status_str)?,
+                    output: row.get("output"),
+                    updated_at: Timestamp::from_datetime(row.get("updated_at")),
+                },
+            );
+        }
+        Ok(outputs)
+    }
+
+    #[tracing::instrument(name = "PostgresCycleReader::get_proact_tree_view", skip_all, fields(db.system = "postgresql"), err)]
     async fn get_proact_tree_view(
         &self,
         session_id: &SessionId,
@@ -657,6 +645,159 @@ impl CycleReader for PostgresCycleReader {
 // Helper Functions
 // ════════════════════════════════════════════════════════════════════════════════
 
+/// Builds a cycle's progress view from its component statuses.
+///
+/// Shared with the SQLite reader so both backends agree on progress and the
+/// suggested next action.
+pub(crate) fn progress_view(
+    id: CycleId,
+    current_step: ComponentType,
+    cycle_status: CycleStatus,
+    status_map: &HashMap<ComponentType, ComponentStatus>,
+) -> CycleProgressView {
+    // Build progress steps
+    let mut steps = Vec::new();
+    let mut completed_count = 0u8;
+    let mut has_revisions = false;
+    let mut first_incomplete: Option<ComponentType> = None;
+    let mut first_revision: Option<ComponentType> = None;
+
+    // Required components (all except NotesNextSteps which is optional)
+    let required_components = [
+        ComponentType::IssueRaising,
+        ComponentType::ProblemFrame,
+        ComponentType::StakeholderAnalysis,
+        ComponentType::Objectives,
+        ComponentType::Alternatives,
+        ComponentType::Consequences,
+        ComponentType::UncertaintyRegister,
+        ComponentType::Tradeoffs,
+        ComponentType::Recommendation,
+        ComponentType::DecisionQuality,
+    ];
+
+    for (i, ct) in ComponentType::all().iter().enumerate() {
+        let status = status_map
+            .get(ct)
+            .copied()
+            .unwrap_or(ComponentStatus::NotStarted);
+
+        let is_required = required_components.contains(ct);
+        if is_required && status == ComponentStatus::Complete {
+            completed_count += 1;
+        }
+
+        if status == ComponentStatus::NeedsRevision {
+            has_revisions = true;
+            if first_revision.is_none() {
+                first_revision = Some(*ct);
+            }
+        }
+
+        if first_incomplete.is_none()
+            && is_required
+            && status != ComponentStatus::Complete
+        {
+            first_incomplete = Some(*ct);
+        }
+
+        // Accessible if previous step is complete or it's the first step
+        let is_accessible = i == 0
+            || ComponentType::all()
+                .get(i - 1)
+                .map(|prev| {
+                    status_map.get(prev).copied() == Some(ComponentStatus::Complete)
+                })
+                .unwrap_or(false)
+            || status != ComponentStatus::NotStarted;
+
+        steps.push(ProgressStep {
+            component_type: *ct,
+            name: component_display_name(*ct),
+            status,
+            is_current: *ct == current_step,
+            is_accessible,
+        });
+    }
+
+    let required_count = 10u8;
+    let progress_percent = ((completed_count as f32 / required_count as f32) * 100.0) as u8;
+    let is_complete = completed_count >= required_count;
+
+    // Determine next action
+    let next_action = if cycle_status == CycleStatus::Completed || is_complete {
+        Some(NextAction {
+            action_type: NextActionType::AlreadyComplete,
+            component: None,
+            description: "Cycle is complete".to_string(),
+        })
+    } else if let Some(rev_ct) = first_revision {
+        Some(NextAction {
+            action_type: NextActionType::ReviseComponent,
+            component: Some(rev_ct),
+            description: format!("Revise {}", component_display_name(rev_ct)),
+        })
+    } else if status_map.get(&current_step) == Some(&ComponentStatus::InProgress) {
+        Some(NextAction {
+            action_type: NextActionType::ContinueCurrent,
+            component: Some(current_step),
+            description: format!("Continue {}", component_display_name(current_step)),
+        })
+    } else if let Some(next_ct) = first_incomplete {
+        let action_type = if status_map.is_empty() {
+            NextActionType::StartFirst
+        } else {
+            NextActionType::StartNext
+        };
+        Some(NextAction {
+            action_type,
+            component: Some(next_ct),
+            description: format!("Start {}", component_display_name(next_ct)),
+        })
+    } else {
+        Some(NextAction {
+            action_type: NextActionType::CompleteCycle,
+            component: None,
+            description: "Complete the cycle".to_string(),
+        })
+    };
+
+    CycleProgressView {
+        cycle_id: id,
+        progress_percent: progress_percent.min(100),
+        completed_count,
+        required_count,
+        is_complete,
+        has_revisions,
+        steps,
+        next_action,
+    }
+}
+
+/// Maps a row with the columns selected by `list_by_session_id`.
+fn row_to_cycle_summary(row: sqlx::postgres::PgRow) -> Result<CycleSummary, DomainError> {
+    let required_count = 10u8;
+    let parent_id: Option<Uuid> = row.get("parent_cycle_id");
+    let branch_point_str: Option<String> = row.get("branch_point");
+    let status_str: String = row.get("status");
+    let current_step_str: String = row.get("current_step");
+    let completed_count: i64 = row.get("completed_count");
+
+    let progress = ((completed_count as f32 / required_count as f32) * 100.0) as u8;
+
+    Ok(CycleSummary {
+        id: CycleId::from_uuid(row.get("id")),
+        is_branch: parent_id.is_some(),
+        branch_point: branch_point_str
+            .map(|s| str_to_component_type(&s))
+            .transpose()?,
+        status: str_to_cycle_status(&status_str)?,
+        current_step: str_to_component_type(&current_step_str)?,
+        progress_percent: progress.min(100),
+        created_at: Timestamp::from_datetime(row.get("created_at")),
+    })
+}
+
 fn db_error(msg: &str) -> DomainError {
     DomainError::new(ErrorCode::DatabaseError, msg.to_string())
 }
@@ -665,9 +806,11 @@ fn str_to_component_type(s: &str) -> Result<ComponentType, DomainError> {
     match s {
         "issue_raising" => Ok(ComponentType::IssueRaising),
         "problem_frame" => Ok(ComponentType::ProblemFrame),
+        "stakeholder_analysis" => Ok(ComponentType::StakeholderAnalysis),
         "objectives" => Ok(ComponentType::Objectives),
         "alternatives" => Ok(ComponentType::Alternatives),
         "consequences" => Ok(ComponentType::Consequences),
+        "uncertainty_register" => Ok(ComponentType::UncertaintyRegister),
         "tradeoffs" => Ok(ComponentType::Tradeoffs),
         "recommendation" => Ok(ComponentType::Recommendation),
         "decision_quality" => Ok(ComponentType::DecisionQuality),
@@ -683,9 +826,11 @@ fn component_type_to_str(ct: ComponentType) -> &'static str {
     match ct {
         ComponentType::IssueRaising => "issue_raising",
         ComponentType::ProblemFrame => "problem_frame",
+        ComponentType::StakeholderAnalysis => "stakeholder_analysis",
         ComponentType::Objectives => "objectives",
         ComponentType::Alternatives => "alternatives",
         ComponentType::Consequences => "consequences",
+        ComponentType::UncertaintyRegister => "uncertainty_register",
         ComponentType::Tradeoffs => "tradeoffs",
         ComponentType::Recommendation => "recommendation",
         ComponentType::DecisionQuality => "decision_quality",
@@ -722,9 +867,11 @@ fn component_display_name(ct: ComponentType) -> String {
     match ct {
         ComponentType::IssueRaising => "Issue Raising".to_string(),
         ComponentType::ProblemFrame => "Problem Frame".to_string(),
+        ComponentType::StakeholderAnalysis => "Stakeholder Analysis".to_string(),
         ComponentType::Objectives => "Objectives".to_string(),
         ComponentType::Alternatives => "Alternatives".to_string(),
         ComponentType::Consequences => "Consequences".to_string(),
+        ComponentType::UncertaintyRegister => "Uncertainty Register".to_string(),
         ComponentType::Tradeoffs => "Tradeoffs".to_string(),
         ComponentType::Recommendation => "Recommendation".to_string(),
         ComponentType::DecisionQuality => "Decision Quality".to_string(),
@@ -734,9 +881,10 @@ fn component_display_name(ct: ComponentType) -> String {
 
 /// Maps a ComponentType to its corresponding PrOACTLetter.
 ///
-/// Note: IssueRaising and NotesNextSteps don't map to PrOACT letters
-/// as they are pre/post steps, not part of the core framework.
-fn component_type_to_proact_letter(ct: ComponentType) -> Option<crate::domain::cycle::PrOACTLetter> {
+/// Note: IssueRaising, StakeholderAnalysis, UncertaintyRegister and
+/// NotesNextSteps don't map to PrOACT letters as they are supporting steps,
+/// not part of the core framework.
+pub(crate) fn component_type_to_proact_letter(ct: ComponentType) -> Option<crate::domain::cycle::PrOACTLetter> {
     use crate::domain::cycle::PrOACTLetter;
 
     match ct {
@@ -746,7 +894,10 @@ fn component_type_to_proact_letter(ct: ComponentType) -> Option<crate::domain::c
         ComponentType::Consequences => Some(PrOACTLetter::A),
         ComponentType::Tradeoffs => Some(PrOACTLetter::C),
         ComponentType::Recommendation | ComponentType::DecisionQuality => Some(PrOACTLetter::T),
-        ComponentType::IssueRaising | ComponentType::NotesNextSteps => None,
+        ComponentType::IssueRaising
+        | ComponentType::StakeholderAnalysis
+        | ComponentType::UncertaintyRegister
+        | ComponentType::NotesNextSteps => None,
     }
 }
 
@@ -757,7 +908,7 @@ fn component_type_to_proact_letter(ct: ComponentType) -> Option<crate::domain::c
 /// - Completed: both are complete
 /// - InProgress: at least one is in progress
 /// - NotStarted: neither is started
-fn component_statuses_to_proact_status(
+pub(crate) fn component_statuses_to_proact_status(
     statuses: &HashMap<ComponentType, ComponentStatus>,
 ) -> crate::domain::cycle::PrOACTStatus {
     use crate::domain::cycle::{LetterStatus, PrOACTStatus};
@@ -814,9 +965,11 @@ mod tests {
         let types = [
             ("issue_raising", ComponentType::IssueRaising),
             ("problem_frame", ComponentType::ProblemFrame),
+            ("stakeholder_analysis", ComponentType::StakeholderAnalysis),
             ("objectives", ComponentType::Objectives),
             ("alternatives", ComponentType::Alternatives),
             ("consequences", ComponentType::Consequences),
+            ("uncertainty_register", ComponentType::UncertaintyRegister),
             ("tradeoffs", ComponentType::Tradeoffs),
             ("recommendation", ComponentType::Recommendation),
             ("decision_quality", ComponentType::DecisionQuality),
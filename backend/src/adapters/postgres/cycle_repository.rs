@@ -1,6 +1,8 @@
 //! PostgreSQL implementation of CycleRepository.
 //!
 //! Persists Cycle aggregates to PostgreSQL with components stored as JSONB.
+//! Each save also appends the cycle's new events to its event stream in the
+//! same transaction (see `cycle_event_store`).
 
 use std::collections::HashMap;
 
@@ -16,6 +18,8 @@ use crate::domain::foundation::{
 use crate::domain::proact::ComponentVariant;
 use crate::ports::CycleRepository;
 
+use super::cycle_event_store::append_cycle_events;
+
 /// PostgreSQL implementation of CycleRepository.
 #[derive(Clone)]
 pub struct PostgresCycleRepository {
@@ -31,6 +35,7 @@ impl PostgresCycleRepository {
 
 #[async_trait]
 impl CycleRepository for PostgresCycleRepository {
+    #[tracing::instrument(name = "PostgresCycleRepository::save", skip_all, fields(db.system = "postgresql"), err)]
     async fn save(&self, cycle: &Cycle) -> Result<(), DomainError> {
         let mut tx = self.pool.begin().await.map_err(|e| {
             DomainError::new(ErrorCode::DatabaseError, format!("Failed to begin transaction: {}", e))
@@ -41,8 +46,8 @@ impl CycleRepository for PostgresCycleRepository {
             r#"
             INSERT INTO cycles (
                 id, session_id, parent_cycle_id, branch_point, status,
-                current_step, created_at, updated_at
-            ) VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
+                current_step, created_at, updated_at, version
+            ) VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)
             "#,
         )
         .bind(cycle.id().as_uuid())
@@ -53,6 +58,7 @@ impl CycleRepository for PostgresCycleRepository {
         .bind(component_type_to_str(cycle.current_step()))
         .bind(cycle.created_at().as_datetime())
         .bind(cycle.updated_at().as_datetime())
+        .bind(cycle.version() as i64)
         .execute(&mut *tx)
         .await
         .map_err(|e| DomainError::new(ErrorCode::DatabaseError, format!("Failed to insert cycle: {}", e)))?;
@@ -64,6 +70,8 @@ impl CycleRepository for PostgresCycleRepository {
             }
         }
 
+        append_cycle_events(&mut tx, cycle).await?;
+
         tx.commit().await.map_err(|e| {
             DomainError::new(ErrorCode::DatabaseError, format!("Failed to commit transaction: {}", e))
         })?;
@@ -71,6 +79,7 @@ impl CycleRepository for PostgresCycleRepository {
         Ok(())
     }
 
+    #[tracing::instrument(name = "PostgresCycleRepository::update", skip_all, fields(db.system = "postgresql"), err)]
     async fn update(&self, cycle: &Cycle) -> Result<(), DomainError> {
         let mut tx = self.pool.begin().await.map_err(|e| {
             DomainError::new(ErrorCode::DatabaseError, format!("Failed to begin transaction: {}", e))
@@ -82,23 +91,44 @@ impl CycleRepository for PostgresCycleRepository {
             UPDATE cycles SET
                 status = $2,
                 current_step = $3,
-                updated_at = $4
-            WHERE id = $1
+                updated_at = $4,
+                version = $5
+            WHERE id = $1 AND version = $6
             "#,
         )
         .bind(cycle.id().as_uuid())
         .bind(cycle_status_to_str(cycle.status()))
         .bind(component_type_to_str(cycle.current_step()))
         .bind(cycle.updated_at().as_datetime())
+        .bind(cycle.version() as i64)
+        .bind(cycle.loaded_version() as i64)
         .execute(&mut *tx)
         .await
         .map_err(|e| DomainError::new(ErrorCode::DatabaseError, format!("Failed to update cycle: {}", e)))?;
 
         if result.rows_affected() == 0 {
-            return Err(DomainError::new(
-                ErrorCode::CycleNotFound,
-                format!("Cycle not found: {}", cycle.id()),
-            ));
+            // Either the cycle is gone or another writer saved it first.
+            let exists: bool = sqlx::query_scalar("SELECT EXISTS (SELECT 1 FROM cycles WHERE id = $1)")
+                .bind(cycle.id().as_uuid())
+                .fetch_one(&mut *tx)
+                .await
+                .map_err(|e| DomainError::new(ErrorCode::DatabaseError, format!("Failed to check cycle: {}", e)))?;
+
+            return Err(if exists {
+                DomainError::new(
+                    ErrorCode::ConcurrentModification,
+                    format!(
+                        "Cycle {} was modified since version {}",
+                        cycle.id(),
+                        cycle.loaded_version()
+                    ),
+                )
+            } else {
+                DomainError::new(
+                    ErrorCode::CycleNotFound,
+                    format!("Cycle not found: {}", cycle.id()),
+                )
+            });
         }
 
         // Update all components
@@ -108,6 +138,8 @@ impl CycleRepository for PostgresCycleRepository {
             }
         }
 
+        append_cycle_events(&mut tx, cycle).await?;
+
         tx.commit().await.map_err(|e| {
             DomainError::new(ErrorCode::DatabaseError, format!("Failed to commit transaction: {}", e))
         })?;
@@ -115,11 +147,12 @@ impl CycleRepository for PostgresCycleRepository {
         Ok(())
     }
 
+    #[tracing::instrument(name = "PostgresCycleRepository::find_by_id", skip_all, fields(db.system = "postgresql"), err)]
     async fn find_by_id(&self, id: &CycleId) -> Result<Option<Cycle>, DomainError> {
         let row = sqlx::query(
             r#"
             SELECT id, session_id, parent_cycle_id, branch_point, status,
-                   current_step, created_at, updated_at
+                   current_step, created_at, updated_at, version
             FROM cycles WHERE id = $1
             "#,
         )
@@ -138,6 +171,7 @@ impl CycleRepository for PostgresCycleRepository {
         }
     }
 
+    #[tracing::instrument(name = "PostgresCycleRepository::exists", skip_all, fields(db.system = "postgresql"), err)]
     async fn exists(&self, id: &CycleId) -> Result<bool, DomainError> {
         let result: (i64,) = sqlx::query_as("SELECT COUNT(*) FROM cycles WHERE id = $1")
             .bind(id.as_uuid())
@@ -148,11 +182,12 @@ impl CycleRepository for PostgresCycleRepository {
         Ok(result.0 > 0)
     }
 
+    #[tracing::instrument(name = "PostgresCycleRepository::find_by_session_id", skip_all, fields(db.system = "postgresql"), err)]
     async fn find_by_session_id(&self, session_id: &SessionId) -> Result<Vec<Cycle>, DomainError> {
         let rows = sqlx::query(
             r#"
             SELECT id, session_id, parent_cycle_id, branch_point, status,
-                   current_step, created_at, updated_at
+                   current_step, created_at, updated_at, version
             FROM cycles
             WHERE session_id = $1
             ORDER BY created_at DESC
@@ -175,6 +210,7 @@ impl CycleRepository for PostgresCycleRepository {
         Ok(cycles)
     }
 
+    #[tracing::instrument(name = "PostgresCycleRepository::find_primary_by_session_id", skip_all, fields(db.system = "postgresql"), err)]
     async fn find_primary_by_session_id(
         &self,
         session_id: &SessionId,
@@ -182,7 +218,7 @@ impl CycleRepository for PostgresCycleRepository {
         let row = sqlx::query(
             r#"
             SELECT id, session_id, parent_cycle_id, branch_point, status,
-                   current_step, created_at, updated_at
+                   current_step, created_at, updated_at, version
             FROM cycles
             WHERE session_id = $1 AND parent_cycle_id IS NULL
             ORDER BY created_at ASC
@@ -206,11 +242,12 @@ impl CycleRepository for PostgresCycleRepository {
         }
     }
 
+    #[tracing::instrument(name = "PostgresCycleRepository::find_branches", skip_all, fields(db.system = "postgresql"), err)]
     async fn find_branches(&self, parent_id: &CycleId) -> Result<Vec<Cycle>, DomainError> {
         let rows = sqlx::query(
             r#"
             SELECT id, session_id, parent_cycle_id, branch_point, status,
-                   current_step, created_at, updated_at
+                   current_step, created_at, updated_at, version
             FROM cycles
             WHERE parent_cycle_id = $1
             ORDER BY created_at DESC
@@ -233,6 +270,7 @@ impl CycleRepository for PostgresCycleRepository {
         Ok(cycles)
     }
 
+    #[tracing::instrument(name = "PostgresCycleRepository::count_by_session_id", skip_all, fields(db.system = "postgresql"), err)]
     async fn count_by_session_id(&self, session_id: &SessionId) -> Result<u32, DomainError> {
         let result: (i64,) = sqlx::query_as("SELECT COUNT(*) FROM cycles WHERE session_id = $1")
             .bind(session_id.as_uuid())
@@ -243,6 +281,7 @@ impl CycleRepository for PostgresCycleRepository {
         Ok(result.0 as u32)
     }
 
+    #[tracing::instrument(name = "PostgresCycleRepository::delete", skip_all, fields(db.system = "postgresql"), err)]
     async fn delete(&self, id: &CycleId) -> Result<(), DomainError> {
         let mut tx = self.pool.begin().await.map_err(|e| {
             DomainError::new(ErrorCode::DatabaseError, format!("Failed to begin transaction: {}", e))
@@ -372,6 +411,7 @@ fn row_to_cycle(
     let current_step: String = row.get("current_step");
     let created_at: chrono::DateTime<chrono::Utc> = row.get("created_at");
     let updated_at: chrono::DateTime<chrono::Utc> = row.get("updated_at");
+    let version: i64 = row.get("version");
 
     // TODO: Load branch_label from DB once migration is added
     // For now, use default (empty label)
@@ -389,6 +429,7 @@ fn row_to_cycle(
         components,
         Timestamp::from_datetime(created_at),
         Timestamp::from_datetime(updated_at),
+        version as u64,
     )
 }
 
@@ -417,13 +458,15 @@ fn row_to_component(
 // Type Conversions
 // ════════════════════════════════════════════════════════════════════════════════
 
-fn component_type_to_str(ct: ComponentType) -> &'static str {
+pub(super) fn component_type_to_str(ct: ComponentType) -> &'static str {
     match ct {
         ComponentType::IssueRaising => "issue_raising",
         ComponentType::ProblemFrame => "problem_frame",
+        ComponentType::StakeholderAnalysis => "stakeholder_analysis",
         ComponentType::Objectives => "objectives",
         ComponentType::Alternatives => "alternatives",
         ComponentType::Consequences => "consequences",
+        ComponentType::UncertaintyRegister => "uncertainty_register",
         ComponentType::Tradeoffs => "tradeoffs",
         ComponentType::Recommendation => "recommendation",
         ComponentType::DecisionQuality => "decision_quality",
@@ -431,13 +474,15 @@ fn component_type_to_str(ct: ComponentType) -> &'static str {
     }
 }
 
-fn str_to_component_type(s: &str) -> Result<ComponentType, DomainError> {
+pub(super) fn str_to_component_type(s: &str) -> Result<ComponentType, DomainError> {
     match s {
         "issue_raising" => Ok(ComponentType::IssueRaising),
         "problem_frame" => Ok(ComponentType::ProblemFrame),
+        "stakeholder_analysis" => Ok(ComponentType::StakeholderAnalysis),
         "objectives" => Ok(ComponentType::Objectives),
         "alternatives" => Ok(ComponentType::Alternatives),
         "consequences" => Ok(ComponentType::Consequences),
+        "uncertainty_register" => Ok(ComponentType::UncertaintyRegister),
         "tradeoffs" => Ok(ComponentType::Tradeoffs),
         "recommendation" => Ok(ComponentType::Recommendation),
         "decision_quality" => Ok(ComponentType::DecisionQuality),
@@ -7,25 +7,42 @@ use async_trait::async_trait;
 use serde_json::Value as JsonValue;
 use sqlx::{PgPool, Row};
 
+use crate::adapters::cache::ViewCache;
 use crate::domain::dashboard::{
     AlternativeSummary, ComparisonSummary, ComponentDetailView, CycleComparison,
-    DashboardOverview, ObjectiveSummary,
+    DashboardOverview, ObjectiveSummary, RiskRegisterView, StakeholderGrid, StakeholderSummary,
+    UncertaintySummary,
 };
 use crate::domain::foundation::{
     ComponentId, ComponentStatus, ComponentType, CycleId, SessionId, UserId,
 };
-use crate::ports::{DashboardError, DashboardReader};
+use crate::domain::proact::{StakeholderAnalysisOutput, UncertaintyRegisterOutput};
+use crate::ports::{cycle_cache_tag, session_cache_tag, DashboardError, DashboardReader};
+
+/// How many registered uncertainties the overview shows.
+const KEY_UNCERTAINTY_LIMIT: usize = 3;
 
 /// PostgreSQL implementation of DashboardReader.
+///
+/// With a `ViewCache`, overviews are served from the cache until the
+/// session's or the shown cycle's tag is invalidated. Ownership is always
+/// checked against the database first.
 #[derive(Clone)]
 pub struct PostgresDashboardReader {
     pool: PgPool,
+    cache: Option<ViewCache>,
 }
 
 impl PostgresDashboardReader {
     /// Creates a new PostgresDashboardReader.
     pub fn new(pool: PgPool) -> Self {
-        Self { pool }
+        Self { pool, cache: None }
+    }
+
+    /// Caches dashboard overviews.
+    pub fn with_cache(mut self, cache: ViewCache) -> Self {
+        self.cache = Some(cache);
+        self
     }
 
     /// Verifies user owns the session.
@@ -105,6 +122,7 @@ impl PostgresDashboardReader {
 
 #[async_trait]
 impl DashboardReader for PostgresDashboardReader {
+    #[tracing::instrument(name = "PostgresDashboardReader::get_overview", skip_all, fields(db.system = "postgresql"), err)]
     async fn get_overview(
         &self,
         session_id: SessionId,
@@ -114,6 +132,17 @@ impl DashboardReader for PostgresDashboardReader {
         // Verify authorization
         self.verify_session_ownership(&session_id, user_id).await?;
 
+        let cache_key = format!(
+            "dashboard:overview:{}:{}",
+            session_id,
+            cycle_id.map_or_else(|| "active".to_string(), |id| id.to_string())
+        );
+        if let Some(cache) = &self.cache {
+            if let Some(overview) = cache.get(&cache_key).await {
+                return Ok(overview);
+            }
+        }
+
         // Get session info
         let session_row = sqlx::query(
             r#"
@@ -162,6 +191,14 @@ impl DashboardReader for PostgresDashboardReader {
                     .map(String::from)
             });
 
+        // Get stakeholders from StakeholderAnalysis component
+        let stakeholders = self
+            .get_component_output(&target_cycle_id, ComponentType::StakeholderAnalysis)
+            .await?
+            .and_then(|json| serde_json::from_value::<StakeholderAnalysisOutput>(json).ok())
+            .map(|output| output.stakeholders.iter().map(StakeholderSummary::from).collect())
+            .unwrap_or_default();
+
         // Get objectives from Objectives component
         let objectives = self
             .get_component_output(&target_cycle_id, ComponentType::Objectives)
@@ -220,27 +257,53 @@ impl DashboardReader for PostgresDashboardReader {
         // TODO: Build consequences table from Consequences component
         let consequences_table = None;
 
+        // Get the highest-exposure uncertainties from UncertaintyRegister component
+        let key_uncertainties = self
+            .get_component_output(&target_cycle_id, ComponentType::UncertaintyRegister)
+            .await?
+            .and_then(|json| serde_json::from_value::<UncertaintyRegisterOutput>(json).ok())
+            .map(|output| {
+                output
+                    .ranked()
+                    .into_iter()
+                    .take(KEY_UNCERTAINTY_LIMIT)
+                    .map(UncertaintySummary::from)
+                    .collect()
+            })
+            .unwrap_or_default();
+
         // TODO: Build recommendation summary from Recommendation component
         let recommendation = None;
 
         // TODO: Get DQ score from DecisionQuality component
         let dq_score = None;
 
-        Ok(DashboardOverview {
+        let overview = DashboardOverview {
             session_id,
             session_title,
             decision_statement,
+            stakeholders,
             objectives,
             alternatives,
             consequences_table,
+            key_uncertainties,
             recommendation,
             dq_score,
             active_cycle_id: Some(target_cycle_id),
             cycle_count: cycle_count as usize,
             last_updated: chrono::Utc::now(),
-        })
+        };
+        if let Some(cache) = &self.cache {
+            let tags = vec![
+                session_cache_tag(&session_id),
+                cycle_cache_tag(&target_cycle_id),
+            ];
+            cache.put(&cache_key, &overview, tags).await;
+        }
+        Ok(overview)
     }
 
+    #[tracing::instrument(name = "PostgresDashboardReader::get_component_detail", skip_all, fields(db.system = "postgresql"), err)]
     async fn get_component_detail(
         &self,
         cycle_id: CycleId,
@@ -302,6 +365,9 @@ impl DashboardReader for PostgresDashboardReader {
         let can_branch = status == ComponentStatus::Complete;
         let can_revise = status == ComponentStatus::Complete;
 
+        let stakeholder_grid = StakeholderGrid::for_component(component_type, &structured_output);
+        let risk_register = RiskRegisterView::for_component(component_type, &structured_output);
+
         Ok(ComponentDetailView {
             component_id,
             cycle_id,
@@ -314,9 +380,13 @@ impl DashboardReader for PostgresDashboardReader {
             can_revise,
             previous_component,
             next_component,
+            ai_cost: None,
+            stakeholder_grid,
+            risk_register,
         })
     }
 
+    #[tracing::instrument(name = "PostgresDashboardReader::compare_cycles", skip_all, fields(db.system = "postgresql"), err)]
     async fn compare_cycles(
         &self,
         cycle_ids: &[CycleId],
@@ -374,9 +444,11 @@ fn component_type_to_str(ct: ComponentType) -> &'static str {
     match ct {
         ComponentType::IssueRaising => "issue_raising",
         ComponentType::ProblemFrame => "problem_frame",
+        ComponentType::StakeholderAnalysis => "stakeholder_analysis",
         ComponentType::Objectives => "objectives",
         ComponentType::Alternatives => "alternatives",
         ComponentType::Consequences => "consequences",
+        ComponentType::UncertaintyRegister => "uncertainty_register",
         ComponentType::Tradeoffs => "tradeoffs",
         ComponentType::Recommendation => "recommendation",
         ComponentType::DecisionQuality => "decision_quality",
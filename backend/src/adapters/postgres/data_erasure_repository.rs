@@ -0,0 +1,209 @@
+//! PostgreSQL implementation of the data erasure port.
+//!
+//! Erasure requests live in `data_erasures`, where pending rows form the
+//! work queue for `DataErasureWorker`. The verification report is stored
+//! as JSONB.
+
+use async_trait::async_trait;
+use sqlx::{PgPool, Row};
+
+use crate::domain::foundation::{DataErasureId, DomainError, ErrorCode, Timestamp, UserId};
+use crate::domain::privacy::{DataErasure, DataErasureStatus, ErasureReport};
+use crate::ports::DataErasureRepository;
+
+/// PostgreSQL implementation of DataErasureRepository.
+#[derive(Clone)]
+pub struct PostgresDataErasureRepository {
+    pool: PgPool,
+}
+
+impl PostgresDataErasureRepository {
+    /// Creates a new PostgresDataErasureRepository.
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+const ERASURE_COLUMNS: &str = "id, user_id, recipient, status, attempts, last_error, \
+     next_attempt_at, requested_at, report, completed_at";
+
+#[async_trait]
+impl DataErasureRepository for PostgresDataErasureRepository {
+    #[tracing::instrument(name = "PostgresDataErasureRepository::save", skip_all, fields(db.system = "postgresql"), err)]
+    async fn save(&self, erasure: &DataErasure) -> Result<(), DomainError> {
+        let report = erasure
+            .report
+            .as_ref()
+            .map(serde_json::to_value)
+            .transpose()
+            .map_err(|e| {
+                DomainError::new(
+                    ErrorCode::InternalError,
+                    format!("Failed to serialize erasure report: {}", e),
+                )
+            })?;
+
+        sqlx::query(
+            r#"
+            INSERT INTO data_erasures (
+                id, user_id, recipient, status, attempts, last_error,
+                next_attempt_at, requested_at, report, completed_at
+            ) VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10)
+            ON CONFLICT (id) DO UPDATE SET
+                recipient = EXCLUDED.recipient,
+                status = EXCLUDED.status,
+                attempts = EXCLUDED.attempts,
+                last_error = EXCLUDED.last_error,
+                next_attempt_at = EXCLUDED.next_attempt_at,
+                report = EXCLUDED.report,
+                completed_at = EXCLUDED.completed_at
+            "#,
+        )
+        .bind(erasure.id.as_uuid())
+        .bind(erasure.user_id.as_str())
+        .bind(erasure.recipient.as_deref())
+        .bind(erasure.status.as_str())
+        .bind(erasure.attempts as i32)
+        .bind(erasure.last_error.as_deref())
+        .bind(erasure.next_attempt_at.as_datetime())
+        .bind(erasure.requested_at.as_datetime())
+        .bind(report)
+        .bind(erasure.completed_at.as_ref().map(|t| *t.as_datetime()))
+        .execute(&self.pool)
+        .await
+        .map_err(|e| {
+            DomainError::new(
+                ErrorCode::DatabaseError,
+                format!("Failed to save data erasure: {}", e),
+            )
+        })?;
+
+        Ok(())
+    }
+
+    #[tracing::instrument(name = "PostgresDataErasureRepository::find_by_id", skip_all, fields(db.system = "postgresql"), err)]
+    async fn find_by_id(&self, id: &DataErasureId) -> Result<Option<DataErasure>, DomainError> {
+        let row = sqlx::query(&format!(
+            "SELECT {} FROM data_erasures WHERE id = $1",
+            ERASURE_COLUMNS
+        ))
+        .bind(id.as_uuid())
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| {
+            DomainError::new(
+                ErrorCode::DatabaseError,
+                format!("Failed to fetch data erasure: {}", e),
+            )
+        })?;
+
+        row.map(row_to_erasure).transpose()
+    }
+
+    #[tracing::instrument(name = "PostgresDataErasureRepository::latest_for_user", skip_all, fields(db.system = "postgresql"), err)]
+    async fn latest_for_user(&self, user_id: &UserId) -> Result<Option<DataErasure>, DomainError> {
+        let row = sqlx::query(&format!(
+            "SELECT {} FROM data_erasures WHERE user_id = $1 \
+             ORDER BY requested_at DESC LIMIT 1",
+            ERASURE_COLUMNS
+        ))
+        .bind(user_id.as_str())
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| {
+            DomainError::new(
+                ErrorCode::DatabaseError,
+                format!("Failed to fetch data erasure: {}", e),
+            )
+        })?;
+
+        row.map(row_to_erasure).transpose()
+    }
+
+    #[tracing::instrument(name = "PostgresDataErasureRepository::list_due", skip_all, fields(db.system = "postgresql"), err)]
+    async fn list_due(&self, now: Timestamp, limit: u32) -> Result<Vec<DataErasure>, DomainError> {
+        let rows = sqlx::query(&format!(
+            "SELECT {} FROM data_erasures \
+             WHERE status = 'pending' AND next_attempt_at <= $1 \
+             ORDER BY next_attempt_at ASC LIMIT $2",
+            ERASURE_COLUMNS
+        ))
+        .bind(now.as_datetime())
+        .bind(limit as i64)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| {
+            DomainError::new(
+                ErrorCode::DatabaseError,
+                format!("Failed to fetch due data erasures: {}", e),
+            )
+        })?;
+
+        rows.into_iter().map(row_to_erasure).collect()
+    }
+}
+
+fn db_error(field: &str, e: sqlx::Error) -> DomainError {
+    DomainError::new(
+        ErrorCode::DatabaseError,
+        format!("Failed to get {}: {}", field, e),
+    )
+}
+
+fn row_to_erasure(row: sqlx::postgres::PgRow) -> Result<DataErasure, DomainError> {
+    let id: uuid::Uuid = row.try_get("id").map_err(|e| db_error("id", e))?;
+    let user: String = row.try_get("user_id").map_err(|e| db_error("user_id", e))?;
+    let recipient: Option<String> = row
+        .try_get("recipient")
+        .map_err(|e| db_error("recipient", e))?;
+    let status: String = row.try_get("status").map_err(|e| db_error("status", e))?;
+    let attempts: i32 = row
+        .try_get("attempts")
+        .map_err(|e| db_error("attempts", e))?;
+    let last_error: Option<String> = row
+        .try_get("last_error")
+        .map_err(|e| db_error("last_error", e))?;
+    let next_attempt_at: chrono::DateTime<chrono::Utc> = row
+        .try_get("next_attempt_at")
+        .map_err(|e| db_error("next_attempt_at", e))?;
+    let requested_at: chrono::DateTime<chrono::Utc> = row
+        .try_get("requested_at")
+        .map_err(|e| db_error("requested_at", e))?;
+    let report: Option<serde_json::Value> =
+        row.try_get("report").map_err(|e| db_error("report", e))?;
+    let completed_at: Option<chrono::DateTime<chrono::Utc>> = row
+        .try_get("completed_at")
+        .map_err(|e| db_error("completed_at", e))?;
+
+    let status = DataErasureStatus::parse(&status).ok_or_else(|| {
+        DomainError::new(
+            ErrorCode::DatabaseError,
+            format!("Unknown data erasure status: {}", status),
+        )
+    })?;
+    let user_id = UserId::new(user).map_err(|e| {
+        DomainError::new(ErrorCode::DatabaseError, format!("Invalid user_id: {}", e))
+    })?;
+    let report = report
+        .map(serde_json::from_value::<ErasureReport>)
+        .transpose()
+        .map_err(|e| {
+            DomainError::new(
+                ErrorCode::DatabaseError,
+                format!("Invalid erasure report: {}", e),
+            )
+        })?;
+
+    Ok(DataErasure {
+        id: DataErasureId::from_uuid(id),
+        user_id,
+        recipient,
+        status,
+        attempts: attempts as u32,
+        last_error,
+        next_attempt_at: Timestamp::from_datetime(next_attempt_at),
+        requested_at: Timestamp::from_datetime(requested_at),
+        report,
+        completed_at: completed_at.map(Timestamp::from_datetime),
+    })
+}
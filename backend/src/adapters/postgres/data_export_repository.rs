@@ -0,0 +1,222 @@
+//! PostgreSQL implementation of the data export port.
+//!
+//! Export requests live in `data_exports`, where pending rows form the work
+//! queue for `UserDataExporter`.
+
+use async_trait::async_trait;
+use sqlx::{PgPool, Row};
+
+use crate::domain::foundation::{DataExportId, DomainError, ErrorCode, Timestamp, UserId};
+use crate::domain::privacy::{DataExport, DataExportStatus};
+use crate::ports::DataExportRepository;
+
+/// PostgreSQL implementation of DataExportRepository.
+#[derive(Clone)]
+pub struct PostgresDataExportRepository {
+    pool: PgPool,
+}
+
+impl PostgresDataExportRepository {
+    /// Creates a new PostgresDataExportRepository.
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+const EXPORT_COLUMNS: &str = "id, user_id, recipient, status, attempts, last_error, \
+     next_attempt_at, requested_at, archive_key, completed_at";
+
+#[async_trait]
+impl DataExportRepository for PostgresDataExportRepository {
+    #[tracing::instrument(name = "PostgresDataExportRepository::save", skip_all, fields(db.system = "postgresql"), err)]
+    async fn save(&self, export: &DataExport) -> Result<(), DomainError> {
+        sqlx::query(
+            r#"
+            INSERT INTO data_exports (
+                id, user_id, recipient, status, attempts, last_error,
+                next_attempt_at, requested_at, archive_key, completed_at
+            ) VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10)
+            ON CONFLICT (id) DO UPDATE SET
+                status = EXCLUDED.status,
+                attempts = EXCLUDED.attempts,
+                last_error = EXCLUDED.last_error,
+                next_attempt_at = EXCLUDED.next_attempt_at,
+                archive_key = EXCLUDED.archive_key,
+                completed_at = EXCLUDED.completed_at
+            "#,
+        )
+        .bind(export.id.as_uuid())
+        .bind(export.user_id.as_str())
+        .bind(&export.recipient)
+        .bind(export.status.as_str())
+        .bind(export.attempts as i32)
+        .bind(export.last_error.as_deref())
+        .bind(export.next_attempt_at.as_datetime())
+        .bind(export.requested_at.as_datetime())
+        .bind(export.archive_key.as_deref())
+        .bind(export.completed_at.as_ref().map(|t| *t.as_datetime()))
+        .execute(&self.pool)
+        .await
+        .map_err(|e| {
+            DomainError::new(
+                ErrorCode::DatabaseError,
+                format!("Failed to save data export: {}", e),
+            )
+        })?;
+
+        Ok(())
+    }
+
+    #[tracing::instrument(name = "PostgresDataExportRepository::find_by_id", skip_all, fields(db.system = "postgresql"), err)]
+    async fn find_by_id(&self, id: &DataExportId) -> Result<Option<DataExport>, DomainError> {
+        let row = sqlx::query(&format!(
+            "SELECT {} FROM data_exports WHERE id = $1",
+            EXPORT_COLUMNS
+        ))
+        .bind(id.as_uuid())
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| {
+            DomainError::new(
+                ErrorCode::DatabaseError,
+                format!("Failed to fetch data export: {}", e),
+            )
+        })?;
+
+        row.map(row_to_export).transpose()
+    }
+
+    #[tracing::instrument(name = "PostgresDataExportRepository::latest_for_user", skip_all, fields(db.system = "postgresql"), err)]
+    async fn latest_for_user(&self, user_id: &UserId) -> Result<Option<DataExport>, DomainError> {
+        let row = sqlx::query(&format!(
+            "SELECT {} FROM data_exports WHERE user_id = $1 \
+             ORDER BY requested_at DESC LIMIT 1",
+            EXPORT_COLUMNS
+        ))
+        .bind(user_id.as_str())
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| {
+            DomainError::new(
+                ErrorCode::DatabaseError,
+                format!("Failed to fetch data export: {}", e),
+            )
+        })?;
+
+        row.map(row_to_export).transpose()
+    }
+
+    #[tracing::instrument(name = "PostgresDataExportRepository::list_due", skip_all, fields(db.system = "postgresql"), err)]
+    async fn list_due(&self, now: Timestamp, limit: u32) -> Result<Vec<DataExport>, DomainError> {
+        let rows = sqlx::query(&format!(
+            "SELECT {} FROM data_exports \
+             WHERE status = 'pending' AND next_attempt_at <= $1 \
+             ORDER BY next_attempt_at ASC LIMIT $2",
+            EXPORT_COLUMNS
+        ))
+        .bind(now.as_datetime())
+        .bind(limit as i64)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| {
+            DomainError::new(
+                ErrorCode::DatabaseError,
+                format!("Failed to fetch due data exports: {}", e),
+            )
+        })?;
+
+        rows.into_iter().map(row_to_export).collect()
+    }
+
+    #[tracing::instrument(name = "PostgresDataExportRepository::list_for_user", skip_all, fields(db.system = "postgresql"), err)]
+    async fn list_for_user(&self, user_id: &UserId) -> Result<Vec<DataExport>, DomainError> {
+        let rows = sqlx::query(&format!(
+            "SELECT {} FROM data_exports WHERE user_id = $1 ORDER BY requested_at DESC",
+            EXPORT_COLUMNS
+        ))
+        .bind(user_id.as_str())
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| {
+            DomainError::new(
+                ErrorCode::DatabaseError,
+                format!("Failed to fetch data exports: {}", e),
+            )
+        })?;
+
+        rows.into_iter().map(row_to_export).collect()
+    }
+
+    #[tracing::instrument(name = "PostgresDataExportRepository::delete", skip_all, fields(db.system = "postgresql"), err)]
+    async fn delete(&self, id: &DataExportId) -> Result<(), DomainError> {
+        sqlx::query("DELETE FROM data_exports WHERE id = $1")
+            .bind(id.as_uuid())
+            .execute(&self.pool)
+            .await
+            .map_err(|e| {
+                DomainError::new(
+                    ErrorCode::DatabaseError,
+                    format!("Failed to delete data export: {}", e),
+                )
+            })?;
+
+        Ok(())
+    }
+}
+
+fn db_error(field: &str, e: sqlx::Error) -> DomainError {
+    DomainError::new(
+        ErrorCode::DatabaseError,
+        format!("Failed to get {}: {}", field, e),
+    )
+}
+
+fn row_to_export(row: sqlx::postgres::PgRow) -> Result<DataExport, DomainError> {
+    let id: uuid::Uuid = row.try_get("id").map_err(|e| db_error("id", e))?;
+    let user: String = row.try_get("user_id").map_err(|e| db_error("user_id", e))?;
+    let recipient: String = row
+        .try_get("recipient")
+        .map_err(|e| db_error("recipient", e))?;
+    let status: String = row.try_get("status").map_err(|e| db_error("status", e))?;
+    let attempts: i32 = row
+        .try_get("attempts")
+        .map_err(|e| db_error("attempts", e))?;
+    let last_error: Option<String> = row
+        .try_get("last_error")
+        .map_err(|e| db_error("last_error", e))?;
+    let next_attempt_at: chrono::DateTime<chrono::Utc> = row
+        .try_get("next_attempt_at")
+        .map_err(|e| db_error("next_attempt_at", e))?;
+    let requested_at: chrono::DateTime<chrono::Utc> = row
+        .try_get("requested_at")
+        .map_err(|e| db_error("requested_at", e))?;
+    let archive_key: Option<String> = row
+        .try_get("archive_key")
+        .map_err(|e| db_error("archive_key", e))?;
+    let completed_at: Option<chrono::DateTime<chrono::Utc>> = row
+        .try_get("completed_at")
+        .map_err(|e| db_error("completed_at", e))?;
+
+    let status = DataExportStatus::parse(&status).ok_or_else(|| {
+        DomainError::new(
+            ErrorCode::DatabaseError,
+            format!("Unknown data export status: {}", status),
+        )
+    })?;
+    let user_id = UserId::new(user).map_err(|e| {
+        DomainError::new(ErrorCode::DatabaseError, format!("Invalid user_id: {}", e))
+    })?;
+
+    Ok(DataExport {
+        id: DataExportId::from_uuid(id),
+        user_id,
+        recipient,
+        status,
+        attempts: attempts as u32,
+        last_error,
+        next_attempt_at: Timestamp::from_datetime(next_attempt_at),
+        requested_at: Timestamp::from_datetime(requested_at),
+        archive_key,
+        completed_at: completed_at.map(Timestamp::from_datetime),
+    })
+}
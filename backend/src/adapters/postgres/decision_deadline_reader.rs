@@ -0,0 +1,88 @@
+//! PostgreSQL implementation of DecisionDeadlineReader.
+//!
+//! Deadlines are the `temporal_constraint` of each active cycle's Problem
+//! Frame output, joined with the owning session for its title.
+
+use async_trait::async_trait;
+use sqlx::{PgPool, Row};
+
+use crate::domain::foundation::{CycleId, DomainError, ErrorCode, SessionId, Timestamp, UserId};
+use crate::ports::{DecisionDeadline, DecisionDeadlineReader};
+
+/// PostgreSQL implementation of DecisionDeadlineReader.
+#[derive(Clone)]
+pub struct PostgresDecisionDeadlineReader {
+    pool: PgPool,
+}
+
+impl PostgresDecisionDeadlineReader {
+    /// Creates a new PostgresDecisionDeadlineReader.
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl DecisionDeadlineReader for PostgresDecisionDeadlineReader {
+    #[tracing::instrument(name = "PostgresDecisionDeadlineReader::list_deadlines", skip_all, fields(db.system = "postgresql"), err)]
+    async fn list_deadlines(&self, user_id: &UserId) -> Result<Vec<DecisionDeadline>, DomainError> {
+        let rows = sqlx::query(
+            r#"
+            SELECT s.id AS session_id, s.title, c.id AS cycle_id,
+                   comp.output->>'temporal_constraint' AS decide_by
+            FROM sessions s
+            JOIN cycles c ON c.session_id = s.id
+            JOIN components comp ON comp.cycle_id = c.id
+            WHERE s.user_id = $1
+              AND s.status = 'active'
+              AND c.status = 'active'
+              AND comp.component_type = 'problem_frame'
+              AND comp.output->>'temporal_constraint' IS NOT NULL
+            "#,
+        )
+        .bind(user_id.as_str())
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| {
+            DomainError::new(
+                ErrorCode::DatabaseError,
+                format!("Failed to fetch decision deadlines: {}", e),
+            )
+        })?;
+
+        let mut deadlines = Vec::with_capacity(rows.len());
+        for row in rows {
+            let decide_by: String = row
+                .try_get("decide_by")
+                .map_err(|e| db_error("decide_by", e))?;
+            // Outputs are free-form JSON; skip deadlines the domain never wrote
+            let Ok(decide_by) = chrono::DateTime::parse_from_rfc3339(&decide_by) else {
+                continue;
+            };
+            let session_id: uuid::Uuid = row
+                .try_get("session_id")
+                .map_err(|e| db_error("session_id", e))?;
+            let cycle_id: uuid::Uuid = row
+                .try_get("cycle_id")
+                .map_err(|e| db_error("cycle_id", e))?;
+            let title: String = row.try_get("title").map_err(|e| db_error("title", e))?;
+
+            deadlines.push(DecisionDeadline {
+                session_id: SessionId::from_uuid(session_id),
+                cycle_id: CycleId::from_uuid(cycle_id),
+                title,
+                decide_by: Timestamp::from_datetime(decide_by.with_timezone(&chrono::Utc)),
+            });
+        }
+        deadlines.sort_by_key(|deadline| deadline.decide_by);
+
+        Ok(deadlines)
+    }
+}
+
+fn db_error(field: &str, e: sqlx::Error) -> DomainError {
+    DomainError::new(
+        ErrorCode::DatabaseError,
+        format!("Failed to get {}: {}", field, e),
+    )
+}
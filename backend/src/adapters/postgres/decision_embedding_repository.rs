@@ -0,0 +1,261 @@
+//! PostgreSQL implementation of DecisionEmbeddingRepository.
+//!
+//! Vectors live in a pgvector `vector(1536)` column. They are sent and read
+//! back in pgvector's text form (`[0.1,0.2,...]`) so no extra sqlx type
+//! support is needed. Similarity is `1 - cosine distance` (`<=>`).
+
+use async_trait::async_trait;
+use sqlx::{PgPool, Row};
+
+use crate::domain::foundation::{
+    CycleId, DomainError, Embedding, ErrorCode, SessionId, Timestamp, UserId,
+};
+use crate::ports::{
+    DecisionEmbedding, DecisionEmbeddingRepository, EmbeddingSource, SimilarDecision,
+};
+
+use super::cycle_repository::{component_type_to_str, str_to_component_type};
+
+const COLUMNS: &str = "cycle_id, component_type, source, session_id, user_id, content_hash, \
+                       model, embedding::text AS embedding, updated_at";
+
+/// PostgreSQL-backed decision embedding repository.
+#[derive(Clone)]
+pub struct PostgresDecisionEmbeddingRepository {
+    pool: PgPool,
+}
+
+impl PostgresDecisionEmbeddingRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl DecisionEmbeddingRepository for PostgresDecisionEmbeddingRepository {
+    #[tracing::instrument(name = "PostgresDecisionEmbeddingRepository::upsert", skip_all, fields(db.system = "postgresql"), err)]
+    async fn upsert(&self, embedding: &DecisionEmbedding) -> Result<(), DomainError> {
+        sqlx::query(
+            r#"
+            INSERT INTO decision_embeddings (
+                cycle_id, component_type, source, session_id, user_id,
+                content_hash, model, embedding, updated_at
+            )
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8::vector, $9)
+            ON CONFLICT (cycle_id, component_type, source) DO UPDATE SET
+                session_id = EXCLUDED.session_id,
+                user_id = EXCLUDED.user_id,
+                content_hash = EXCLUDED.content_hash,
+                model = EXCLUDED.model,
+                embedding = EXCLUDED.embedding,
+                updated_at = EXCLUDED.updated_at
+            "#,
+        )
+        .bind(embedding.cycle_id.as_uuid())
+        .bind(component_type_to_str(embedding.component_type))
+        .bind(embedding.source.as_str())
+        .bind(embedding.session_id.as_uuid())
+        .bind(embedding.user_id.as_str())
+        .bind(&embedding.content_hash)
+        .bind(&embedding.model)
+        .bind(vector_literal(&embedding.embedding))
+        .bind(embedding.updated_at.as_datetime())
+        .execute(&self.pool)
+        .await
+        .map_err(|e| {
+            DomainError::new(
+                ErrorCode::DatabaseError,
+                format!("Failed to save decision embedding: {}", e),
+            )
+        })?;
+
+        Ok(())
+    }
+
+    #[tracing::instrument(name = "PostgresDecisionEmbeddingRepository::list_for_cycle", skip_all, fields(db.system = "postgresql"), err)]
+    async fn list_for_cycle(
+        &self,
+        cycle_id: &CycleId,
+    ) -> Result<Vec<DecisionEmbedding>, DomainError> {
+        let rows = sqlx::query(&format!(
+            "SELECT {} FROM decision_embeddings WHERE cycle_id = $1",
+            COLUMNS
+        ))
+        .bind(cycle_id.as_uuid())
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| {
+            DomainError::new(
+                ErrorCode::DatabaseError,
+                format!("Failed to fetch cycle embeddings: {}", e),
+            )
+        })?;
+
+        rows.into_iter().map(row_to_embedding).collect()
+    }
+
+    #[tracing::instrument(name = "PostgresDecisionEmbeddingRepository::list_for_user", skip_all, fields(db.system = "postgresql"), err)]
+    async fn list_for_user(&self, user_id: &UserId) -> Result<Vec<DecisionEmbedding>, DomainError> {
+        let rows = sqlx::query(&format!(
+            "SELECT {} FROM decision_embeddings WHERE user_id = $1",
+            COLUMNS
+        ))
+        .bind(user_id.as_str())
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| {
+            DomainError::new(
+                ErrorCode::DatabaseError,
+                format!("Failed to fetch user embeddings: {}", e),
+            )
+        })?;
+
+        rows.into_iter().map(row_to_embedding).collect()
+    }
+
+    #[tracing::instrument(name = "PostgresDecisionEmbeddingRepository::find_similar", skip_all, fields(db.system = "postgresql"), err)]
+    async fn find_similar(
+        &self,
+        user_id: &UserId,
+        embedding: &Embedding,
+        model: &str,
+        exclude: Option<CycleId>,
+        limit: u32,
+    ) -> Result<Vec<SimilarDecision>, DomainError> {
+        let rows = sqlx::query(
+            r#"
+            SELECT cycle_id, session_id, component_type, source, similarity
+            FROM (
+                SELECT DISTINCT ON (cycle_id)
+                    cycle_id, session_id, component_type, source,
+                    1 - (embedding <=> $2::vector) AS similarity
+                FROM decision_embeddings
+                WHERE user_id = $1
+                  AND model = $3
+                  AND ($4::UUID IS NULL OR cycle_id <> $4)
+                ORDER BY cycle_id, embedding <=> $2::vector
+            ) best
+            ORDER BY similarity DESC
+            LIMIT $5
+            "#,
+        )
+        .bind(user_id.as_str())
+        .bind(vector_literal(embedding))
+        .bind(model)
+        .bind(exclude.map(|id| *id.as_uuid()))
+        .bind(i64::from(limit))
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| {
+            DomainError::new(
+                ErrorCode::DatabaseError,
+                format!("Failed to find similar decisions: {}", e),
+            )
+        })?;
+
+        rows.into_iter()
+            .map(|row| {
+                let cycle_id: uuid::Uuid = row
+                    .try_get("cycle_id")
+                    .map_err(|e| db_error("cycle_id", e))?;
+                let session_id: uuid::Uuid = row
+                    .try_get("session_id")
+                    .map_err(|e| db_error("session_id", e))?;
+                let component_type: String = row
+                    .try_get("component_type")
+                    .map_err(|e| db_error("component_type", e))?;
+                let source: String = row.try_get("source").map_err(|e| db_error("source", e))?;
+                let similarity: f64 = row
+                    .try_get("similarity")
+                    .map_err(|e| db_error("similarity", e))?;
+
+                Ok(SimilarDecision {
+                    cycle_id: CycleId::from_uuid(cycle_id),
+                    session_id: SessionId::from_uuid(session_id),
+                    component_type: str_to_component_type(&component_type)?,
+                    source: parse_source(&source)?,
+                    similarity: similarity as f32,
+                })
+            })
+            .collect()
+    }
+}
+
+/// pgvector's text form of an embedding.
+fn vector_literal(embedding: &Embedding) -> String {
+    let values: Vec<String> = embedding.as_slice().iter().map(f32::to_string).collect();
+    format!("[{}]", values.join(","))
+}
+
+fn parse_vector(text: &str) -> Result<Embedding, DomainError> {
+    let values = text
+        .trim_start_matches('[')
+        .trim_end_matches(']')
+        .split(',')
+        .map(|value| value.trim().parse::<f32>())
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|e| {
+            DomainError::new(
+                ErrorCode::DatabaseError,
+                format!("Invalid embedding: {}", e),
+            )
+        })?;
+    Embedding::new(values).map_err(|e| {
+        DomainError::new(
+            ErrorCode::DatabaseError,
+            format!("Invalid embedding: {}", e),
+        )
+    })
+}
+
+fn parse_source(source: &str) -> Result<EmbeddingSource, DomainError> {
+    EmbeddingSource::parse(source).ok_or_else(|| {
+        DomainError::new(
+            ErrorCode::DatabaseError,
+            format!("Invalid embedding source: {}", source),
+        )
+    })
+}
+
+fn db_error(field: &str, e: sqlx::Error) -> DomainError {
+    DomainError::new(
+        ErrorCode::DatabaseError,
+        format!("Failed to get {}: {}", field, e),
+    )
+}
+
+fn row_to_embedding(row: sqlx::postgres::PgRow) -> Result<DecisionEmbedding, DomainError> {
+    let cycle_id: uuid::Uuid = row
+        .try_get("cycle_id")
+        .map_err(|e| db_error("cycle_id", e))?;
+    let component_type: String = row
+        .try_get("component_type")
+        .map_err(|e| db_error("component_type", e))?;
+    let source: String = row.try_get("source").map_err(|e| db_error("source", e))?;
+    let session_id: uuid::Uuid = row
+        .try_get("session_id")
+        .map_err(|e| db_error("session_id", e))?;
+    let user_id: String = row.try_get("user_id").map_err(|e| db_error("user_id", e))?;
+    let embedding: String = row
+        .try_get("embedding")
+        .map_err(|e| db_error("embedding", e))?;
+    let updated_at: chrono::DateTime<chrono::Utc> = row
+        .try_get("updated_at")
+        .map_err(|e| db_error("updated_at", e))?;
+
+    Ok(DecisionEmbedding {
+        cycle_id: CycleId::from_uuid(cycle_id),
+        component_type: str_to_component_type(&component_type)?,
+        source: parse_source(&source)?,
+        session_id: SessionId::from_uuid(session_id),
+        user_id: UserId::new(user_id).map_err(|e| {
+            DomainError::new(ErrorCode::DatabaseError, format!("Invalid user_id: {}", e))
+        })?,
+        content_hash: row
+            .try_get("content_hash")
+            .map_err(|e| db_error("content_hash", e))?,
+        model: row.try_get("model").map_err(|e| db_error("model", e))?,
+        embedding: parse_vector(&embedding)?,
+        updated_at: Timestamp::from_datetime(updated_at),
+    })
+}
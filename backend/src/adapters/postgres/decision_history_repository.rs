@@ -0,0 +1,160 @@
+//! PostgreSQL implementation of the decision history port.
+//!
+//! Each completed decision is one row in `decision_records`, with the full
+//! `DecisionRecord` (outcome included) stored as JSONB.
+
+use async_trait::async_trait;
+use sqlx::{PgPool, Row};
+
+use crate::domain::foundation::{CycleId, DomainError, ErrorCode, UserId};
+use crate::domain::profile::DecisionRecord;
+use crate::ports::DecisionHistoryRepository;
+
+/// PostgreSQL implementation of DecisionHistoryRepository.
+#[derive(Clone)]
+pub struct PostgresDecisionHistoryRepository {
+    pool: PgPool,
+}
+
+impl PostgresDecisionHistoryRepository {
+    /// Creates a new PostgresDecisionHistoryRepository.
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl DecisionHistoryRepository for PostgresDecisionHistoryRepository {
+    #[tracing::instrument(name = "PostgresDecisionHistoryRepository::save", skip_all, fields(db.system = "postgresql"), err)]
+    async fn save(&self, user_id: &UserId, record: &DecisionRecord) -> Result<(), DomainError> {
+        let json = serde_json::to_value(record).map_err(|e| {
+            DomainError::new(
+                ErrorCode::InternalError,
+                format!("Failed to serialize decision record: {}", e),
+            )
+        })?;
+
+        sqlx::query(
+            r#"
+            INSERT INTO decision_records (user_id, cycle_id, decided_at, record, updated_at)
+            VALUES ($1, $2, $3, $4, NOW())
+            ON CONFLICT (user_id, cycle_id) DO UPDATE SET
+                decided_at = EXCLUDED.decided_at,
+                record = EXCLUDED.record,
+                updated_at = NOW()
+            "#,
+        )
+        .bind(user_id.as_str())
+        .bind(record.cycle_id.as_uuid())
+        .bind(record.date.as_datetime())
+        .bind(json)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| {
+            DomainError::new(
+                ErrorCode::DatabaseError,
+                format!("Failed to save decision record: {}", e),
+            )
+        })?;
+
+        Ok(())
+    }
+
+    #[tracing::instrument(name = "PostgresDecisionHistoryRepository::find_by_cycle", skip_all, fields(db.system = "postgresql"), err)]
+    async fn find_by_cycle(
+        &self,
+        user_id: &UserId,
+        cycle_id: &CycleId,
+    ) -> Result<Option<DecisionRecord>, DomainError> {
+        let row =
+            sqlx::query("SELECT record FROM decision_records WHERE user_id = $1 AND cycle_id = $2")
+                .bind(user_id.as_str())
+                .bind(cycle_id.as_uuid())
+                .fetch_optional(&self.pool)
+                .await
+                .map_err(|e| {
+                    DomainError::new(
+                        ErrorCode::DatabaseError,
+                        format!("Failed to fetch decision record: {}", e),
+                    )
+                })?;
+
+        row.map(row_to_record).transpose()
+    }
+
+    #[tracing::instrument(name = "PostgresDecisionHistoryRepository::list_for_user", skip_all, fields(db.system = "postgresql"), err)]
+    async fn list_for_user(&self, user_id: &UserId) -> Result<Vec<DecisionRecord>, DomainError> {
+        let rows = sqlx::query(
+            "SELECT record FROM decision_records WHERE user_id = $1 ORDER BY decided_at ASC",
+        )
+        .bind(user_id.as_str())
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| {
+            DomainError::new(
+                ErrorCode::DatabaseError,
+                format!("Failed to fetch decision history: {}", e),
+            )
+        })?;
+
+        rows.into_iter().map(row_to_record).collect()
+    }
+
+    #[tracing::instrument(name = "PostgresDecisionHistoryRepository::list_user_ids", skip_all, fields(db.system = "postgresql"), err)]
+    async fn list_user_ids(&self) -> Result<Vec<UserId>, DomainError> {
+        let rows = sqlx::query("SELECT DISTINCT user_id FROM decision_records ORDER BY user_id")
+            .fetch_all(&self.pool)
+            .await
+            .map_err(|e| {
+                DomainError::new(
+                    ErrorCode::DatabaseError,
+                    format!("Failed to list decision history users: {}", e),
+                )
+            })?;
+
+        rows.into_iter()
+            .map(|row| {
+                let user: String = row.try_get("user_id").map_err(|e| {
+                    DomainError::new(
+                        ErrorCode::DatabaseError,
+                        format!("Failed to get user_id: {}", e),
+                    )
+                })?;
+                UserId::new(user).map_err(|e| {
+                    DomainError::new(ErrorCode::DatabaseError, format!("Invalid user_id: {}", e))
+                })
+            })
+            .collect()
+    }
+
+    #[tracing::instrument(name = "PostgresDecisionHistoryRepository::delete_for_user", skip_all, fields(db.system = "postgresql"), err)]
+    async fn delete_for_user(&self, user_id: &UserId) -> Result<u64, DomainError> {
+        let result = sqlx::query("DELETE FROM decision_records WHERE user_id = $1")
+            .bind(user_id.as_str())
+            .execute(&self.pool)
+            .await
+            .map_err(|e| {
+                DomainError::new(
+                    ErrorCode::DatabaseError,
+                    format!("Failed to delete decision history: {}", e),
+                )
+            })?;
+
+        Ok(result.rows_affected())
+    }
+}
+
+fn row_to_record(row: sqlx::postgres::PgRow) -> Result<DecisionRecord, DomainError> {
+    let json: serde_json::Value = row.try_get("record").map_err(|e| {
+        DomainError::new(
+            ErrorCode::DatabaseError,
+            format!("Failed to get record: {}", e),
+        )
+    })?;
+    serde_json::from_value(json).map_err(|e| {
+        DomainError::new(
+            ErrorCode::DatabaseError,
+            format!("Invalid decision record: {}", e),
+        )
+    })
+}
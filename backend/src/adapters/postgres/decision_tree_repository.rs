@@ -0,0 +1,176 @@
+//! PostgreSQL implementation of DecisionTreeRepository.
+//!
+//! Each tree is one row of `decision_trees`, with its nodes and branches in
+//! a JSONB column; trees are small and always read whole.
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::{PgPool, Row};
+
+use crate::domain::decision_tree::{DecisionTree, TreeNode};
+use crate::domain::foundation::{
+    CycleId, DecisionTreeId, DomainError, ErrorCode, Timestamp, UserId,
+};
+use crate::ports::DecisionTreeRepository;
+
+/// PostgreSQL implementation of DecisionTreeRepository.
+#[derive(Clone)]
+pub struct PostgresDecisionTreeRepository {
+    pool: PgPool,
+}
+
+impl PostgresDecisionTreeRepository {
+    /// Creates a new PostgresDecisionTreeRepository.
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+const TREE_COLUMNS: &str =
+    "id, cycle_id, user_id, name, payoff_unit, root_id, nodes, created_at, updated_at";
+
+#[async_trait]
+impl DecisionTreeRepository for PostgresDecisionTreeRepository {
+    #[tracing::instrument(name = "PostgresDecisionTreeRepository::save", skip_all, fields(db.system = "postgresql"), err)]
+    async fn save(&self, tree: &DecisionTree) -> Result<(), DomainError> {
+        let nodes = serde_json::to_value(&tree.nodes).map_err(|e| {
+            DomainError::new(
+                ErrorCode::InternalError,
+                format!("Failed to serialize decision tree nodes: {}", e),
+            )
+        })?;
+
+        sqlx::query(
+            r#"
+            INSERT INTO decision_trees (
+                id, cycle_id, user_id, name, payoff_unit, root_id, nodes, created_at, updated_at
+            ) VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)
+            ON CONFLICT (id) DO UPDATE SET
+                name = EXCLUDED.name,
+                payoff_unit = EXCLUDED.payoff_unit,
+                root_id = EXCLUDED.root_id,
+                nodes = EXCLUDED.nodes,
+                updated_at = EXCLUDED.updated_at
+            "#,
+        )
+        .bind(tree.id.as_uuid())
+        .bind(tree.cycle_id.as_uuid())
+        .bind(tree.user_id.as_str())
+        .bind(&tree.name)
+        .bind(&tree.payoff_unit)
+        .bind(&tree.root_id)
+        .bind(nodes)
+        .bind(tree.created_at.as_datetime())
+        .bind(tree.updated_at.as_datetime())
+        .execute(&self.pool)
+        .await
+        .map_err(|e| {
+            DomainError::new(
+                ErrorCode::DatabaseError,
+                format!("Failed to save decision tree: {}", e),
+            )
+        })?;
+
+        Ok(())
+    }
+
+    #[tracing::instrument(name = "PostgresDecisionTreeRepository::find_by_id", skip_all, fields(db.system = "postgresql"), err)]
+    async fn find_by_id(&self, id: &DecisionTreeId) -> Result<Option<DecisionTree>, DomainError> {
+        let row = sqlx::query(&format!(
+            "SELECT {} FROM decision_trees WHERE id = $1",
+            TREE_COLUMNS
+        ))
+        .bind(id.as_uuid())
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| {
+            DomainError::new(
+                ErrorCode::DatabaseError,
+                format!("Failed to fetch decision tree: {}", e),
+            )
+        })?;
+
+        row.map(row_to_tree).transpose()
+    }
+
+    #[tracing::instrument(name = "PostgresDecisionTreeRepository::list_for_cycle", skip_all, fields(db.system = "postgresql"), err)]
+    async fn list_for_cycle(&self, cycle_id: &CycleId) -> Result<Vec<DecisionTree>, DomainError> {
+        let rows = sqlx::query(&format!(
+            "SELECT {} FROM decision_trees WHERE cycle_id = $1 ORDER BY created_at ASC",
+            TREE_COLUMNS
+        ))
+        .bind(cycle_id.as_uuid())
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| {
+            DomainError::new(
+                ErrorCode::DatabaseError,
+                format!("Failed to fetch decision trees: {}", e),
+            )
+        })?;
+
+        rows.into_iter().map(row_to_tree).collect()
+    }
+
+    #[tracing::instrument(name = "PostgresDecisionTreeRepository::delete", skip_all, fields(db.system = "postgresql"), err)]
+    async fn delete(&self, id: &DecisionTreeId) -> Result<(), DomainError> {
+        sqlx::query("DELETE FROM decision_trees WHERE id = $1")
+            .bind(id.as_uuid())
+            .execute(&self.pool)
+            .await
+            .map_err(|e| {
+                DomainError::new(
+                    ErrorCode::DatabaseError,
+                    format!("Failed to delete decision tree: {}", e),
+                )
+            })?;
+
+        Ok(())
+    }
+}
+
+fn db_error(field: &str, e: sqlx::Error) -> DomainError {
+    DomainError::new(
+        ErrorCode::DatabaseError,
+        format!("Failed to get {}: {}", field, e),
+    )
+}
+
+fn row_to_tree(row: sqlx::postgres::PgRow) -> Result<DecisionTree, DomainError> {
+    let id: uuid::Uuid = row.try_get("id").map_err(|e| db_error("id", e))?;
+    let cycle_id: uuid::Uuid = row.try_get("cycle_id").map_err(|e| db_error("cycle_id", e))?;
+    let user: String = row.try_get("user_id").map_err(|e| db_error("user_id", e))?;
+    let name: String = row.try_get("name").map_err(|e| db_error("name", e))?;
+    let payoff_unit: Option<String> = row
+        .try_get("payoff_unit")
+        .map_err(|e| db_error("payoff_unit", e))?;
+    let root_id: Option<String> = row.try_get("root_id").map_err(|e| db_error("root_id", e))?;
+    let nodes: serde_json::Value = row.try_get("nodes").map_err(|e| db_error("nodes", e))?;
+    let created_at: DateTime<Utc> = row
+        .try_get("created_at")
+        .map_err(|e| db_error("created_at", e))?;
+    let updated_at: DateTime<Utc> = row
+        .try_get("updated_at")
+        .map_err(|e| db_error("updated_at", e))?;
+
+    let nodes: Vec<TreeNode> = serde_json::from_value(nodes).map_err(|e| {
+        DomainError::new(
+            ErrorCode::DatabaseError,
+            format!("Invalid decision tree nodes: {}", e),
+        )
+    })?;
+
+    Ok(DecisionTree {
+        id: DecisionTreeId::from_uuid(id),
+        cycle_id: CycleId::from_uuid(cycle_id),
+        user_id: UserId::new(user).map_err(|e| {
+            DomainError::new(ErrorCode::DatabaseError, format!("Invalid user_id: {}", e))
+        })?,
+        name,
+        payoff_unit,
+        root_id,
+        nodes,
+        created_at: Timestamp::from_datetime(created_at),
+        updated_at: Timestamp::from_datetime(updated_at),
+    })
+}
@@ -0,0 +1,283 @@
+//! PostgreSQL implementations of the document delivery ports.
+//!
+//! Deliveries live in `document_deliveries`, where pending rows form the
+//! retry queue; opt-in preferences live in `document_email_preferences`.
+
+use async_trait::async_trait;
+use sqlx::{PgPool, Row};
+
+use crate::domain::document::{DeliveryStatus, DocumentDelivery, DocumentEmailPreference};
+use crate::domain::foundation::{
+    CycleId, DocumentDeliveryId, DomainError, ErrorCode, Timestamp, UserId,
+};
+use crate::ports::{DocumentDeliveryRepository, DocumentEmailPreferenceRepository};
+
+/// PostgreSQL implementation of DocumentDeliveryRepository.
+#[derive(Clone)]
+pub struct PostgresDocumentDeliveryRepository {
+    pool: PgPool,
+}
+
+impl PostgresDocumentDeliveryRepository {
+    /// Creates a new PostgresDocumentDeliveryRepository.
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+const DELIVERY_COLUMNS: &str = "id, cycle_id, user_id, recipient, status, attempts, \
+     last_error, next_attempt_at, created_at, sent_at";
+
+#[async_trait]
+impl DocumentDeliveryRepository for PostgresDocumentDeliveryRepository {
+    #[tracing::instrument(name = "PostgresDocumentDeliveryRepository::save", skip_all, fields(db.system = "postgresql"), err)]
+    async fn save(&self, delivery: &DocumentDelivery) -> Result<(), DomainError> {
+        sqlx::query(
+            r#"
+            INSERT INTO document_deliveries (
+                id, cycle_id, user_id, recipient, status, attempts,
+                last_error, next_attempt_at, created_at, sent_at
+            ) VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10)
+            ON CONFLICT (id) DO UPDATE SET
+                status = EXCLUDED.status,
+                attempts = EXCLUDED.attempts,
+                last_error = EXCLUDED.last_error,
+                next_attempt_at = EXCLUDED.next_attempt_at,
+                sent_at = EXCLUDED.sent_at
+            "#,
+        )
+        .bind(delivery.id.as_uuid())
+        .bind(delivery.cycle_id.as_uuid())
+        .bind(delivery.user_id.as_str())
+        .bind(&delivery.recipient)
+        .bind(delivery.status.as_str())
+        .bind(delivery.attempts as i32)
+        .bind(delivery.last_error.as_deref())
+        .bind(delivery.next_attempt_at.as_datetime())
+        .bind(delivery.created_at.as_datetime())
+        .bind(delivery.sent_at.as_ref().map(|t| *t.as_datetime()))
+        .execute(&self.pool)
+        .await
+        .map_err(|e| {
+            DomainError::new(
+                ErrorCode::DatabaseError,
+                format!("Failed to save document delivery: {}", e),
+            )
+        })?;
+
+        Ok(())
+    }
+
+    #[tracing::instrument(name = "PostgresDocumentDeliveryRepository::find_by_id", skip_all, fields(db.system = "postgresql"), err)]
+    async fn find_by_id(
+        &self,
+        id: &DocumentDeliveryId,
+    ) -> Result<Option<DocumentDelivery>, DomainError> {
+        let row = sqlx::query(&format!(
+            "SELECT {} FROM document_deliveries WHERE id = $1",
+            DELIVERY_COLUMNS
+        ))
+        .bind(id.as_uuid())
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| {
+            DomainError::new(
+                ErrorCode::DatabaseError,
+                format!("Failed to fetch document delivery: {}", e),
+            )
+        })?;
+
+        row.map(row_to_delivery).transpose()
+    }
+
+    #[tracing::instrument(name = "PostgresDocumentDeliveryRepository::list_for_cycle", skip_all, fields(db.system = "postgresql"), err)]
+    async fn list_for_cycle(
+        &self,
+        cycle_id: &CycleId,
+        user_id: &UserId,
+    ) -> Result<Vec<DocumentDelivery>, DomainError> {
+        let rows = sqlx::query(&format!(
+            "SELECT {} FROM document_deliveries \
+             WHERE cycle_id = $1 AND user_id = $2 ORDER BY created_at ASC",
+            DELIVERY_COLUMNS
+        ))
+        .bind(cycle_id.as_uuid())
+        .bind(user_id.as_str())
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| {
+            DomainError::new(
+                ErrorCode::DatabaseError,
+                format!("Failed to fetch document deliveries: {}", e),
+            )
+        })?;
+
+        rows.into_iter().map(row_to_delivery).collect()
+    }
+
+    #[tracing::instrument(name = "PostgresDocumentDeliveryRepository::list_due", skip_all, fields(db.system = "postgresql"), err)]
+    async fn list_due(
+        &self,
+        now: Timestamp,
+        limit: u32,
+    ) -> Result<Vec<DocumentDelivery>, DomainError> {
+        let rows = sqlx::query(&format!(
+            "SELECT {} FROM document_deliveries \
+             WHERE status = 'pending' AND next_attempt_at <= $1 \
+             ORDER BY next_attempt_at ASC LIMIT $2",
+            DELIVERY_COLUMNS
+        ))
+        .bind(now.as_datetime())
+        .bind(limit as i64)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| {
+            DomainError::new(
+                ErrorCode::DatabaseError,
+                format!("Failed to fetch due document deliveries: {}", e),
+            )
+        })?;
+
+        rows.into_iter().map(row_to_delivery).collect()
+    }
+}
+
+/// PostgreSQL implementation of DocumentEmailPreferenceRepository.
+#[derive(Clone)]
+pub struct PostgresDocumentEmailPreferenceRepository {
+    pool: PgPool,
+}
+
+impl PostgresDocumentEmailPreferenceRepository {
+    /// Creates a new PostgresDocumentEmailPreferenceRepository.
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl DocumentEmailPreferenceRepository for PostgresDocumentEmailPreferenceRepository {
+    #[tracing::instrument(name = "PostgresDocumentEmailPreferenceRepository::get", skip_all, fields(db.system = "postgresql"), err)]
+    async fn get(&self, user_id: &UserId) -> Result<Option<DocumentEmailPreference>, DomainError> {
+        let row = sqlx::query(
+            r#"
+            SELECT user_id, email, enabled, updated_at
+            FROM document_email_preferences
+            WHERE user_id = $1
+            "#,
+        )
+        .bind(user_id.as_str())
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| {
+            DomainError::new(
+                ErrorCode::DatabaseError,
+                format!("Failed to fetch document email preference: {}", e),
+            )
+        })?;
+
+        row.map(row_to_preference).transpose()
+    }
+
+    #[tracing::instrument(name = "PostgresDocumentEmailPreferenceRepository::set", skip_all, fields(db.system = "postgresql"), err)]
+    async fn set(&self, preference: &DocumentEmailPreference) -> Result<(), DomainError> {
+        sqlx::query(
+            r#"
+            INSERT INTO document_email_preferences (user_id, email, enabled, updated_at)
+            VALUES ($1, $2, $3, $4)
+            ON CONFLICT (user_id) DO UPDATE SET
+                email = EXCLUDED.email,
+                enabled = EXCLUDED.enabled,
+                updated_at = EXCLUDED.updated_at
+            "#,
+        )
+        .bind(preference.user_id.as_str())
+        .bind(&preference.email)
+        .bind(preference.enabled)
+        .bind(preference.updated_at.as_datetime())
+        .execute(&self.pool)
+        .await
+        .map_err(|e| {
+            DomainError::new(
+                ErrorCode::DatabaseError,
+                format!("Failed to save document email preference: {}", e),
+            )
+        })?;
+
+        Ok(())
+    }
+}
+
+fn db_error(field: &str, e: sqlx::Error) -> DomainError {
+    DomainError::new(
+        ErrorCode::DatabaseError,
+        format!("Failed to get {}: {}", field, e),
+    )
+}
+
+fn user_id(value: String) -> Result<UserId, DomainError> {
+    UserId::new(value)
+        .map_err(|e| DomainError::new(ErrorCode::DatabaseError, format!("Invalid user_id: {}", e)))
+}
+
+fn row_to_delivery(row: sqlx::postgres::PgRow) -> Result<DocumentDelivery, DomainError> {
+    let id: uuid::Uuid = row.try_get("id").map_err(|e| db_error("id", e))?;
+    let cycle_id: uuid::Uuid = row
+        .try_get("cycle_id")
+        .map_err(|e| db_error("cycle_id", e))?;
+    let user: String = row.try_get("user_id").map_err(|e| db_error("user_id", e))?;
+    let recipient: String = row
+        .try_get("recipient")
+        .map_err(|e| db_error("recipient", e))?;
+    let status: String = row.try_get("status").map_err(|e| db_error("status", e))?;
+    let attempts: i32 = row
+        .try_get("attempts")
+        .map_err(|e| db_error("attempts", e))?;
+    let last_error: Option<String> = row
+        .try_get("last_error")
+        .map_err(|e| db_error("last_error", e))?;
+    let next_attempt_at: chrono::DateTime<chrono::Utc> = row
+        .try_get("next_attempt_at")
+        .map_err(|e| db_error("next_attempt_at", e))?;
+    let created_at: chrono::DateTime<chrono::Utc> = row
+        .try_get("created_at")
+        .map_err(|e| db_error("created_at", e))?;
+    let sent_at: Option<chrono::DateTime<chrono::Utc>> =
+        row.try_get("sent_at").map_err(|e| db_error("sent_at", e))?;
+
+    let status = DeliveryStatus::parse(&status).ok_or_else(|| {
+        DomainError::new(
+            ErrorCode::DatabaseError,
+            format!("Unknown delivery status: {}", status),
+        )
+    })?;
+
+    Ok(DocumentDelivery {
+        id: DocumentDeliveryId::from_uuid(id),
+        cycle_id: CycleId::from_uuid(cycle_id),
+        user_id: user_id(user)?,
+        recipient,
+        status,
+        attempts: attempts as u32,
+        last_error,
+        next_attempt_at: Timestamp::from_datetime(next_attempt_at),
+        created_at: Timestamp::from_datetime(created_at),
+        sent_at: sent_at.map(Timestamp::from_datetime),
+    })
+}
+
+fn row_to_preference(row: sqlx::postgres::PgRow) -> Result<DocumentEmailPreference, DomainError> {
+    let user: String = row.try_get("user_id").map_err(|e| db_error("user_id", e))?;
+    let email: String = row.try_get("email").map_err(|e| db_error("email", e))?;
+    let enabled: bool = row.try_get("enabled").map_err(|e| db_error("enabled", e))?;
+    let updated_at: chrono::DateTime<chrono::Utc> = row
+        .try_get("updated_at")
+        .map_err(|e| db_error("updated_at", e))?;
+
+    Ok(DocumentEmailPreference {
+        user_id: user_id(user)?,
+        email,
+        enabled,
+        updated_at: Timestamp::from_datetime(updated_at),
+    })
+}
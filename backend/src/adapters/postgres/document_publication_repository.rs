@@ -0,0 +1,165 @@
+//! PostgreSQL implementation of DocumentPublicationRepository.
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::{PgPool, Row};
+
+use crate::domain::document::DocumentPublication;
+use crate::domain::foundation::{
+    CycleId, DomainError, ErrorCode, PublicationId, Timestamp, UserId,
+};
+use crate::ports::DocumentPublicationRepository;
+
+/// PostgreSQL implementation of DocumentPublicationRepository.
+#[derive(Clone)]
+pub struct PostgresDocumentPublicationRepository {
+    pool: PgPool,
+}
+
+impl PostgresDocumentPublicationRepository {
+    /// Creates a new PostgresDocumentPublicationRepository.
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl DocumentPublicationRepository for PostgresDocumentPublicationRepository {
+    #[tracing::instrument(name = "PostgresDocumentPublicationRepository::save", skip_all, fields(db.system = "postgresql"), err)]
+    async fn save(&self, publication: &DocumentPublication) -> Result<(), DomainError> {
+        sqlx::query(
+            r#"
+            INSERT INTO document_publications
+                (id, cycle_id, published_by, title, html, created_at, expires_at, revoked_at)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
+            "#,
+        )
+        .bind(publication.id.as_uuid())
+        .bind(publication.cycle_id.as_uuid())
+        .bind(publication.published_by.as_str())
+        .bind(&publication.title)
+        .bind(&publication.html)
+        .bind(publication.created_at.as_datetime())
+        .bind(publication.expires_at.as_datetime())
+        .bind(publication.revoked_at.as_ref().map(|t| *t.as_datetime()))
+        .execute(&self.pool)
+        .await
+        .map_err(|e| {
+            DomainError::new(
+                ErrorCode::DatabaseError,
+                format!("Failed to insert document publication: {}", e),
+            )
+        })?;
+
+        Ok(())
+    }
+
+    #[tracing::instrument(name = "PostgresDocumentPublicationRepository::update", skip_all, fields(db.system = "postgresql"), err)]
+    async fn update(&self, publication: &DocumentPublication) -> Result<(), DomainError> {
+        let result = sqlx::query(
+            r#"
+            UPDATE document_publications
+            SET revoked_at = $2
+            WHERE id = $1
+            "#,
+        )
+        .bind(publication.id.as_uuid())
+        .bind(publication.revoked_at.as_ref().map(|t| *t.as_datetime()))
+        .execute(&self.pool)
+        .await
+        .map_err(|e| {
+            DomainError::new(
+                ErrorCode::DatabaseError,
+                format!("Failed to update document publication: {}", e),
+            )
+        })?;
+
+        if result.rows_affected() == 0 {
+            return Err(DomainError::new(
+                ErrorCode::NotFound,
+                format!("Publication {} not found", publication.id),
+            ));
+        }
+        Ok(())
+    }
+
+    #[tracing::instrument(name = "PostgresDocumentPublicationRepository::find_by_id", skip_all, fields(db.system = "postgresql"), err)]
+    async fn find_by_id(
+        &self,
+        id: &PublicationId,
+    ) -> Result<Option<DocumentPublication>, DomainError> {
+        let row = sqlx::query(
+            r#"
+            SELECT id, cycle_id, published_by, title, html, created_at, expires_at, revoked_at
+            FROM document_publications
+            WHERE id = $1
+            "#,
+        )
+        .bind(id.as_uuid())
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| {
+            DomainError::new(
+                ErrorCode::DatabaseError,
+                format!("Failed to fetch document publication: {}", e),
+            )
+        })?;
+
+        row.map(row_to_publication).transpose()
+    }
+
+    #[tracing::instrument(name = "PostgresDocumentPublicationRepository::list_by_cycle", skip_all, fields(db.system = "postgresql"), err)]
+    async fn list_by_cycle(
+        &self,
+        cycle_id: &CycleId,
+    ) -> Result<Vec<DocumentPublication>, DomainError> {
+        let rows = sqlx::query(
+            r#"
+            SELECT id, cycle_id, published_by, title, html, created_at, expires_at, revoked_at
+            FROM document_publications
+            WHERE cycle_id = $1
+            ORDER BY created_at DESC
+            "#,
+        )
+        .bind(cycle_id.as_uuid())
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| {
+            DomainError::new(
+                ErrorCode::DatabaseError,
+                format!("Failed to list document publications: {}", e),
+            )
+        })?;
+
+        rows.into_iter().map(row_to_publication).collect()
+    }
+}
+
+fn row_to_publication(row: sqlx::postgres::PgRow) -> Result<DocumentPublication, DomainError> {
+    let column_error = |e: sqlx::Error| {
+        DomainError::new(
+            ErrorCode::DatabaseError,
+            format!("Failed to read document publication: {}", e),
+        )
+    };
+    let published_by: String = row.try_get("published_by").map_err(column_error)?;
+    let created_at: DateTime<Utc> = row.try_get("created_at").map_err(column_error)?;
+    let expires_at: DateTime<Utc> = row.try_get("expires_at").map_err(column_error)?;
+    let revoked_at: Option<DateTime<Utc>> = row.try_get("revoked_at").map_err(column_error)?;
+
+    Ok(DocumentPublication {
+        id: PublicationId::from_uuid(row.try_get("id").map_err(column_error)?),
+        cycle_id: CycleId::from_uuid(row.try_get("cycle_id").map_err(column_error)?),
+        published_by: UserId::new(published_by).map_err(|e| {
+            DomainError::new(
+                ErrorCode::DatabaseError,
+                format!("Invalid publisher ID: {}", e),
+            )
+        })?,
+        title: row.try_get("title").map_err(column_error)?,
+        html: row.try_get("html").map_err(column_error)?,
+        created_at: Timestamp::from_datetime(created_at),
+        expires_at: Timestamp::from_datetime(expires_at),
+        revoked_at: revoked_at.map(Timestamp::from_datetime),
+    })
+}
@@ -0,0 +1,100 @@
+//! PostgreSQL implementation of DocumentTemplateStore.
+//!
+//! Templates live in the `document_templates` table, one row per organization.
+
+use async_trait::async_trait;
+use sqlx::{PgPool, Row};
+
+use crate::domain::foundation::Timestamp;
+use crate::ports::{
+    validate_organization, DocumentTemplate, DocumentTemplateError, DocumentTemplateStore,
+};
+
+/// PostgreSQL-backed document template store.
+#[derive(Clone)]
+pub struct PostgresDocumentTemplateStore {
+    pool: PgPool,
+}
+
+impl PostgresDocumentTemplateStore {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl DocumentTemplateStore for PostgresDocumentTemplateStore {
+    #[tracing::instrument(name = "PostgresDocumentTemplateStore::get", skip_all, fields(db.system = "postgresql"), err)]
+    async fn get(
+        &self,
+        organization: &str,
+    ) -> Result<Option<DocumentTemplate>, DocumentTemplateError> {
+        let row = sqlx::query(
+            r#"
+            SELECT organization, source, updated_at
+            FROM document_templates
+            WHERE organization = $1
+            "#,
+        )
+        .bind(organization)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| storage_error("Failed to load document template", e))?;
+
+        row.map(row_to_template).transpose()
+    }
+
+    #[tracing::instrument(name = "PostgresDocumentTemplateStore::save", skip_all, fields(db.system = "postgresql"), err)]
+    async fn save(&self, template: DocumentTemplate) -> Result<(), DocumentTemplateError> {
+        validate_organization(&template.organization)?;
+
+        sqlx::query(
+            r#"
+            INSERT INTO document_templates (organization, source, updated_at)
+            VALUES ($1, $2, $3)
+            ON CONFLICT (organization) DO UPDATE SET
+                source = EXCLUDED.source,
+                updated_at = EXCLUDED.updated_at
+            "#,
+        )
+        .bind(&template.organization)
+        .bind(&template.source)
+        .bind(template.updated_at.as_datetime())
+        .execute(&self.pool)
+        .await
+        .map_err(|e| storage_error("Failed to save document template", e))?;
+
+        Ok(())
+    }
+
+    #[tracing::instrument(name = "PostgresDocumentTemplateStore::delete", skip_all, fields(db.system = "postgresql"), err)]
+    async fn delete(&self, organization: &str) -> Result<bool, DocumentTemplateError> {
+        let result = sqlx::query("DELETE FROM document_templates WHERE organization = $1")
+            .bind(organization)
+            .execute(&self.pool)
+            .await
+            .map_err(|e| storage_error("Failed to delete document template", e))?;
+
+        Ok(result.rows_affected() > 0)
+    }
+}
+
+fn row_to_template(row: sqlx::postgres::PgRow) -> Result<DocumentTemplate, DocumentTemplateError> {
+    let updated_at: chrono::DateTime<chrono::Utc> = row
+        .try_get("updated_at")
+        .map_err(|e| storage_error("Invalid updated_at", e))?;
+
+    Ok(DocumentTemplate {
+        organization: row
+            .try_get("organization")
+            .map_err(|e| storage_error("Invalid organization", e))?,
+        source: row
+            .try_get("source")
+            .map_err(|e| storage_error("Invalid source", e))?,
+        updated_at: Timestamp::from_datetime(updated_at),
+    })
+}
+
+fn storage_error(context: &str, e: sqlx::Error) -> DocumentTemplateError {
+    DocumentTemplateError::Storage(format!("{}: {}", context, e))
+}
@@ -0,0 +1,127 @@
+//! PostgreSQL implementation of DocumentVersionRepository.
+//!
+//! Stores the full Markdown source of each version in the
+//! `document_versions` table, keyed by cycle and version number.
+
+use async_trait::async_trait;
+use sqlx::{PgPool, Row};
+
+use crate::domain::document::{DocumentVersion, MarkdownContent};
+use crate::domain::foundation::{CycleId, DomainError, ErrorCode, Timestamp};
+use crate::ports::DocumentVersionRepository;
+
+/// PostgreSQL implementation of DocumentVersionRepository.
+#[derive(Clone)]
+pub struct PostgresDocumentVersionRepository {
+    pool: PgPool,
+}
+
+impl PostgresDocumentVersionRepository {
+    /// Creates a new PostgresDocumentVersionRepository.
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl DocumentVersionRepository for PostgresDocumentVersionRepository {
+    #[tracing::instrument(name = "PostgresDocumentVersionRepository::save", skip_all, fields(db.system = "postgresql"), err)]
+    async fn save(&self, version: &DocumentVersion) -> Result<(), DomainError> {
+        sqlx::query(
+            r#"
+            INSERT INTO document_versions (cycle_id, version, content, created_at)
+            VALUES ($1, $2, $3, $4)
+            "#,
+        )
+        .bind(version.cycle_id.as_uuid())
+        .bind(version.version as i32)
+        .bind(version.content.as_str())
+        .bind(version.created_at.as_datetime())
+        .execute(&self.pool)
+        .await
+        .map_err(|e| {
+            DomainError::new(
+                ErrorCode::DatabaseError,
+                format!("Failed to insert document version: {}", e),
+            )
+        })?;
+
+        Ok(())
+    }
+
+    #[tracing::instrument(name = "PostgresDocumentVersionRepository::find", skip_all, fields(db.system = "postgresql"), err)]
+    async fn find(
+        &self,
+        cycle_id: &CycleId,
+        version: u32,
+    ) -> Result<Option<DocumentVersion>, DomainError> {
+        let row = sqlx::query(
+            r#"
+            SELECT cycle_id, version, content, created_at
+            FROM document_versions
+            WHERE cycle_id = $1 AND version = $2
+            "#,
+        )
+        .bind(cycle_id.as_uuid())
+        .bind(version as i32)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| {
+            DomainError::new(
+                ErrorCode::DatabaseError,
+                format!("Failed to fetch document version: {}", e),
+            )
+        })?;
+
+        row.map(row_to_version).transpose()
+    }
+
+    #[tracing::instrument(name = "PostgresDocumentVersionRepository::latest", skip_all, fields(db.system = "postgresql"), err)]
+    async fn latest(&self, cycle_id: &CycleId) -> Result<Option<DocumentVersion>, DomainError> {
+        let row = sqlx::query(
+            r#"
+            SELECT cycle_id, version, content, created_at
+            FROM document_versions
+            WHERE cycle_id = $1
+            ORDER BY version DESC
+            LIMIT 1
+            "#,
+        )
+        .bind(cycle_id.as_uuid())
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| {
+            DomainError::new(
+                ErrorCode::DatabaseError,
+                format!("Failed to fetch latest document version: {}", e),
+            )
+        })?;
+
+        row.map(row_to_version).transpose()
+    }
+}
+
+fn db_error(field: &str, e: sqlx::Error) -> DomainError {
+    DomainError::new(
+        ErrorCode::DatabaseError,
+        format!("Failed to get {}: {}", field, e),
+    )
+}
+
+fn row_to_version(row: sqlx::postgres::PgRow) -> Result<DocumentVersion, DomainError> {
+    let cycle_id: uuid::Uuid = row
+        .try_get("cycle_id")
+        .map_err(|e| db_error("cycle_id", e))?;
+    let version: i32 = row.try_get("version").map_err(|e| db_error("version", e))?;
+    let content: String = row.try_get("content").map_err(|e| db_error("content", e))?;
+    let created_at: chrono::DateTime<chrono::Utc> = row
+        .try_get("created_at")
+        .map_err(|e| db_error("created_at", e))?;
+
+    Ok(DocumentVersion {
+        cycle_id: CycleId::from_uuid(cycle_id),
+        version: version as u32,
+        content: MarkdownContent::from(content),
+        created_at: Timestamp::from_datetime(created_at),
+    })
+}
@@ -0,0 +1,186 @@
+//! PostgreSQL implementations of the event store and projection checkpoints.
+//!
+//! Events live in `event_log`, numbered by a `BIGSERIAL` position; the full
+//! envelope is kept as JSONB so replays see exactly what was published.
+//! Appending an event already in the log returns its existing position.
+
+use async_trait::async_trait;
+use sqlx::{PgPool, Row};
+
+use crate::domain::foundation::{DomainError, ErrorCode, EventEnvelope, Timestamp};
+use crate::ports::{EventStore, ProjectionCheckpoint, ProjectionCheckpointStore, StoredEvent};
+
+/// PostgreSQL implementation of EventStore.
+#[derive(Clone)]
+pub struct PostgresEventStore {
+    pool: PgPool,
+}
+
+impl PostgresEventStore {
+    /// Creates a new PostgresEventStore.
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl EventStore for PostgresEventStore {
+    #[tracing::instrument(name = "PostgresEventStore::append", skip_all, fields(db.system = "postgresql"), err)]
+    async fn append(&self, event: &EventEnvelope) -> Result<u64, DomainError> {
+        let envelope = serde_json::to_value(event).map_err(|e| {
+            DomainError::new(
+                ErrorCode::InternalError,
+                format!("Failed to serialize event: {}", e),
+            )
+        })?;
+        let position: i64 = sqlx::query_scalar(
+            r#"
+            INSERT INTO event_log (event_id, event_type, aggregate_type, aggregate_id, occurred_at, envelope)
+            VALUES ($1, $2, $3, $4, $5, $6)
+            ON CONFLICT (event_id) DO UPDATE SET event_id = EXCLUDED.event_id
+            RETURNING position
+            "#,
+        )
+        .bind(event.event_id.as_str())
+        .bind(&event.event_type)
+        .bind(&event.aggregate_type)
+        .bind(&event.aggregate_id)
+        .bind(event.occurred_at.as_datetime())
+        .bind(envelope)
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|e| db_error("append event", e))?;
+
+        Ok(position as u64)
+    }
+
+    #[tracing::instrument(name = "PostgresEventStore::read_after", skip_all, fields(db.system = "postgresql"), err)]
+    async fn read_after(&self, position: u64, limit: u32) -> Result<Vec<StoredEvent>, DomainError> {
+        let rows = sqlx::query(
+            "SELECT position, envelope FROM event_log WHERE position > $1 ORDER BY position LIMIT $2",
+        )
+        .bind(position as i64)
+        .bind(limit as i64)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| db_error("read events", e))?;
+
+        rows.iter()
+            .map(|row| {
+                let position: i64 = row
+                    .try_get("position")
+                    .map_err(|e| db_error("get position", e))?;
+                let envelope: serde_json::Value = row
+                    .try_get("envelope")
+                    .map_err(|e| db_error("get envelope", e))?;
+                let event = serde_json::from_value(envelope).map_err(|e| {
+                    DomainError::new(
+                        ErrorCode::DatabaseError,
+                        format!("Invalid envelope at position {}: {}", position, e),
+                    )
+                })?;
+                Ok(StoredEvent {
+                    position: position as u64,
+                    event,
+                })
+            })
+            .collect()
+    }
+
+    #[tracing::instrument(name = "PostgresEventStore::count_after", skip_all, fields(db.system = "postgresql"), err)]
+    async fn count_after(&self, position: u64, event_types: &[&str]) -> Result<u64, DomainError> {
+        let count: i64 = sqlx::query_scalar(
+            "SELECT COUNT(*) FROM event_log WHERE position > $1 AND event_type = ANY($2)",
+        )
+        .bind(position as i64)
+        .bind(event_types)
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|e| db_error("count events", e))?;
+
+        Ok(count as u64)
+    }
+
+    #[tracing::instrument(name = "PostgresEventStore::head_position", skip_all, fields(db.system = "postgresql"), err)]
+    async fn head_position(&self) -> Result<u64, DomainError> {
+        let position: Option<i64> = sqlx::query_scalar("SELECT MAX(position) FROM event_log")
+            .fetch_one(&self.pool)
+            .await
+            .map_err(|e| db_error("read head position", e))?;
+
+        Ok(position.unwrap_or(0) as u64)
+    }
+}
+
+/// PostgreSQL implementation of ProjectionCheckpointStore.
+#[derive(Clone)]
+pub struct PostgresProjectionCheckpointStore {
+    pool: PgPool,
+}
+
+impl PostgresProjectionCheckpointStore {
+    /// Creates a new PostgresProjectionCheckpointStore.
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl ProjectionCheckpointStore for PostgresProjectionCheckpointStore {
+    #[tracing::instrument(name = "PostgresProjectionCheckpointStore::load", skip_all, fields(db.system = "postgresql"), err)]
+    async fn load(&self, projection: &str) -> Result<Option<ProjectionCheckpoint>, DomainError> {
+        let row = sqlx::query(
+            "SELECT projection, position, updated_at FROM projection_checkpoints WHERE projection = $1",
+        )
+        .bind(projection)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| db_error("load checkpoint", e))?;
+
+        row.map(|row| {
+            let projection: String = row
+                .try_get("projection")
+                .map_err(|e| db_error("get projection", e))?;
+            let position: i64 = row
+                .try_get("position")
+                .map_err(|e| db_error("get position", e))?;
+            let updated_at: chrono::DateTime<chrono::Utc> = row
+                .try_get("updated_at")
+                .map_err(|e| db_error("get updated_at", e))?;
+            Ok(ProjectionCheckpoint {
+                projection,
+                position: position as u64,
+                updated_at: Timestamp::from_datetime(updated_at),
+            })
+        })
+        .transpose()
+    }
+
+    #[tracing::instrument(name = "PostgresProjectionCheckpointStore::save", skip_all, fields(db.system = "postgresql"), err)]
+    async fn save(&self, checkpoint: &ProjectionCheckpoint) -> Result<(), DomainError> {
+        sqlx::query(
+            r#"
+            INSERT INTO projection_checkpoints (projection, position, updated_at)
+            VALUES ($1, $2, $3)
+            ON CONFLICT (projection) DO UPDATE SET
+                position = EXCLUDED.position,
+                updated_at = EXCLUDED.updated_at
+            "#,
+        )
+        .bind(&checkpoint.projection)
+        .bind(checkpoint.position as i64)
+        .bind(checkpoint.updated_at.as_datetime())
+        .execute(&self.pool)
+        .await
+        .map_err(|e| db_error("save checkpoint", e))?;
+
+        Ok(())
+    }
+}
+
+fn db_error(action: &str, e: sqlx::Error) -> DomainError {
+    DomainError::new(
+        ErrorCode::DatabaseError,
+        format!("Failed to {}: {}", action, e),
+    )
+}
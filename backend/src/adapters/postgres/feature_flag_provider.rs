@@ -0,0 +1,193 @@
+//! PostgreSQL implementation of FeatureFlagProvider.
+//!
+//! Flags live in the `feature_flags` table. Each instance keeps the latest
+//! snapshot in a watch channel; writes through this provider publish
+//! immediately, and `spawn_refresh` polls the table so changes made through
+//! other instances are picked up within one interval.
+
+use std::collections::HashMap;
+use std::sync::{Arc, Weak};
+use std::time::Duration;
+
+use async_trait::async_trait;
+use sqlx::{PgPool, Row};
+use tokio::sync::watch;
+
+use crate::adapters::feature_flags::FlagChannel;
+use crate::domain::foundation::Timestamp;
+use crate::domain::membership::MembershipTier;
+use crate::ports::{FeatureFlag, FeatureFlagError, FeatureFlagProvider, FeatureFlagSet};
+
+/// Default interval between background refreshes.
+pub const DEFAULT_FLAG_REFRESH_INTERVAL: Duration = Duration::from_secs(30);
+
+/// PostgreSQL-backed feature flag provider.
+pub struct PostgresFeatureFlagProvider {
+    pool: PgPool,
+    channel: FlagChannel,
+}
+
+impl PostgresFeatureFlagProvider {
+    /// Creates a provider with an empty snapshot. Call `refresh` (or use
+    /// `load`) before serving traffic.
+    pub fn new(pool: PgPool) -> Self {
+        Self {
+            pool,
+            channel: FlagChannel::new(FeatureFlagSet::default()),
+        }
+    }
+
+    /// Creates a provider and loads the current flags.
+    pub async fn load(pool: PgPool) -> Result<Self, FeatureFlagError> {
+        let provider = Self::new(pool);
+        provider.refresh().await?;
+        Ok(provider)
+    }
+
+    /// Spawn a background task that reloads flags every `interval`.
+    ///
+    /// The task holds only a weak reference and exits once the provider is
+    /// dropped. Failed reloads are logged; the previous snapshot stays active.
+    pub fn spawn_refresh(self: &Arc<Self>, interval: Duration) -> tokio::task::JoinHandle<()> {
+        let provider: Weak<Self> = Arc::downgrade(self);
+
+        tokio::spawn(async move {
+            let mut ticker = tokio::time::interval(interval);
+            loop {
+                ticker.tick().await;
+
+                let Some(provider) = provider.upgrade() else {
+                    tracing::debug!("Feature flag refresh stopping: provider dropped");
+                    break;
+                };
+
+                if let Err(e) = provider.refresh().await {
+                    tracing::warn!("Feature flag refresh failed: {}", e);
+                }
+            }
+        })
+    }
+
+    async fn load_all(&self) -> Result<Vec<FeatureFlag>, FeatureFlagError> {
+        let rows = sqlx::query(
+            r#"
+            SELECT key, description, enabled, tier_overrides, user_overrides,
+                   rollout_percentage, updated_at
+            FROM feature_flags
+            "#,
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| storage_error("Failed to load feature flags", e))?;
+
+        rows.into_iter().map(row_to_flag).collect()
+    }
+}
+
+#[async_trait]
+impl FeatureFlagProvider for PostgresFeatureFlagProvider {
+    fn current(&self) -> Arc<FeatureFlagSet> {
+        self.channel.current()
+    }
+
+    fn subscribe(&self) -> watch::Receiver<Arc<FeatureFlagSet>> {
+        self.channel.subscribe()
+    }
+
+    #[tracing::instrument(name = "PostgresFeatureFlagProvider::upsert", skip_all, fields(db.system = "postgresql"), err)]
+    async fn upsert(&self, flag: FeatureFlag) -> Result<(), FeatureFlagError> {
+        let tier_overrides = serde_json::to_value(&flag.tier_overrides)
+            .map_err(|e| FeatureFlagError::Storage(e.to_string()))?;
+        let user_overrides = serde_json::to_value(&flag.user_overrides)
+            .map_err(|e| FeatureFlagError::Storage(e.to_string()))?;
+
+        sqlx::query(
+            r#"
+            INSERT INTO feature_flags (
+                key, description, enabled, tier_overrides, user_overrides,
+                rollout_percentage, updated_at
+            ) VALUES ($1, $2, $3, $4, $5, $6, $7)
+            ON CONFLICT (key) DO UPDATE SET
+                description = EXCLUDED.description,
+                enabled = EXCLUDED.enabled,
+                tier_overrides = EXCLUDED.tier_overrides,
+                user_overrides = EXCLUDED.user_overrides,
+                rollout_percentage = EXCLUDED.rollout_percentage,
+                updated_at = EXCLUDED.updated_at
+            "#,
+        )
+        .bind(&flag.key)
+        .bind(flag.description.as_deref())
+        .bind(flag.enabled)
+        .bind(tier_overrides)
+        .bind(user_overrides)
+        .bind(flag.rollout_percentage.map(i16::from))
+        .bind(flag.updated_at.as_datetime())
+        .execute(&self.pool)
+        .await
+        .map_err(|e| storage_error("Failed to save feature flag", e))?;
+
+        self.refresh().await
+    }
+
+    #[tracing::instrument(name = "PostgresFeatureFlagProvider::remove", skip_all, fields(db.system = "postgresql"), err)]
+    async fn remove(&self, key: &str) -> Result<(), FeatureFlagError> {
+        let result = sqlx::query("DELETE FROM feature_flags WHERE key = $1")
+            .bind(key)
+            .execute(&self.pool)
+            .await
+            .map_err(|e| storage_error("Failed to delete feature flag", e))?;
+
+        if result.rows_affected() == 0 {
+            return Err(FeatureFlagError::NotFound(key.to_string()));
+        }
+
+        self.refresh().await
+    }
+
+    #[tracing::instrument(name = "PostgresFeatureFlagProvider::refresh", skip_all, fields(db.system = "postgresql"), err)]
+    async fn refresh(&self) -> Result<(), FeatureFlagError> {
+        let flags = self.load_all().await?;
+        if self.channel.publish(FeatureFlagSet::new(flags)) {
+            tracing::info!(count = self.current().len(), "Feature flags updated");
+        }
+        Ok(())
+    }
+}
+
+fn row_to_flag(row: sqlx::postgres::PgRow) -> Result<FeatureFlag, FeatureFlagError> {
+    let key: String = row.try_get("key").map_err(|e| storage_error("Invalid key", e))?;
+    let tier_overrides: serde_json::Value = row
+        .try_get("tier_overrides")
+        .map_err(|e| storage_error("Invalid tier_overrides", e))?;
+    let user_overrides: serde_json::Value = row
+        .try_get("user_overrides")
+        .map_err(|e| storage_error("Invalid user_overrides", e))?;
+    let rollout_percentage: Option<i16> = row
+        .try_get("rollout_percentage")
+        .map_err(|e| storage_error("Invalid rollout_percentage", e))?;
+    let updated_at: chrono::DateTime<chrono::Utc> = row
+        .try_get("updated_at")
+        .map_err(|e| storage_error("Invalid updated_at", e))?;
+
+    let tier_overrides: HashMap<MembershipTier, bool> = serde_json::from_value(tier_overrides)
+        .map_err(|e| FeatureFlagError::Storage(format!("Flag '{}' tier_overrides: {}", key, e)))?;
+    let user_overrides: HashMap<String, bool> = serde_json::from_value(user_overrides)
+        .map_err(|e| FeatureFlagError::Storage(format!("Flag '{}' user_overrides: {}", key, e)))?;
+
+    Ok(FeatureFlag {
+        description: row
+            .try_get("description")
+            .map_err(|e| storage_error("Invalid description", e))?,
+        enabled: row.try_get("enabled").map_err(|e| storage_error("Invalid enabled", e))?,
+        tier_overrides,
+        user_overrides,
+        rollout_percentage: rollout_percentage.map(|p| p.clamp(0, 100) as u8),
+        updated_at: Timestamp::from_datetime(updated_at),
+        key,
+    })
+}
+
+fn storage_error(context: &str, e: sqlx::Error) -> FeatureFlagError {
+    FeatureFlagError::Storage(format!("{}: {}", context, e))
+}
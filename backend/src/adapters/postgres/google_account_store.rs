@@ -0,0 +1,123 @@
+//! PostgreSQL implementation of GoogleAccountStore.
+//!
+//! Credentials live in `google_accounts`, one row per connected user.
+
+use async_trait::async_trait;
+use secrecy::{ExposeSecret, Secret};
+use sqlx::{PgPool, Row};
+
+use crate::domain::foundation::{DomainError, ErrorCode, Timestamp, UserId};
+use crate::ports::{GoogleAccountStore, GoogleTokens};
+
+/// PostgreSQL implementation of GoogleAccountStore.
+#[derive(Clone)]
+pub struct PostgresGoogleAccountStore {
+    pool: PgPool,
+}
+
+impl PostgresGoogleAccountStore {
+    /// Creates a new PostgresGoogleAccountStore.
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl GoogleAccountStore for PostgresGoogleAccountStore {
+    #[tracing::instrument(name = "PostgresGoogleAccountStore::get", skip_all, fields(db.system = "postgresql"), err)]
+    async fn get(&self, user_id: &UserId) -> Result<Option<GoogleTokens>, DomainError> {
+        let row = sqlx::query(
+            r#"
+            SELECT access_token, refresh_token, expires_at
+            FROM google_accounts
+            WHERE user_id = $1
+            "#,
+        )
+        .bind(user_id.as_str())
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| {
+            DomainError::new(
+                ErrorCode::DatabaseError,
+                format!("Failed to fetch Google account: {}", e),
+            )
+        })?;
+
+        row.map(row_to_tokens).transpose()
+    }
+
+    #[tracing::instrument(name = "PostgresGoogleAccountStore::save", skip_all, fields(db.system = "postgresql"), err)]
+    async fn save(&self, user_id: &UserId, tokens: &GoogleTokens) -> Result<(), DomainError> {
+        sqlx::query(
+            r#"
+            INSERT INTO google_accounts (user_id, access_token, refresh_token, expires_at)
+            VALUES ($1, $2, $3, $4)
+            ON CONFLICT (user_id) DO UPDATE SET
+                access_token = EXCLUDED.access_token,
+                refresh_token = EXCLUDED.refresh_token,
+                expires_at = EXCLUDED.expires_at,
+                updated_at = NOW()
+            "#,
+        )
+        .bind(user_id.as_str())
+        .bind(tokens.access_token.expose_secret())
+        .bind(
+            tokens
+                .refresh_token
+                .as_ref()
+                .map(|t| t.expose_secret().clone()),
+        )
+        .bind(tokens.expires_at.as_datetime())
+        .execute(&self.pool)
+        .await
+        .map_err(|e| {
+            DomainError::new(
+                ErrorCode::DatabaseError,
+                format!("Failed to save Google account: {}", e),
+            )
+        })?;
+
+        Ok(())
+    }
+
+    #[tracing::instrument(name = "PostgresGoogleAccountStore::delete", skip_all, fields(db.system = "postgresql"), err)]
+    async fn delete(&self, user_id: &UserId) -> Result<(), DomainError> {
+        sqlx::query("DELETE FROM google_accounts WHERE user_id = $1")
+            .bind(user_id.as_str())
+            .execute(&self.pool)
+            .await
+            .map_err(|e| {
+                DomainError::new(
+                    ErrorCode::DatabaseError,
+                    format!("Failed to delete Google account: {}", e),
+                )
+            })?;
+
+        Ok(())
+    }
+}
+
+fn db_error(field: &str, e: sqlx::Error) -> DomainError {
+    DomainError::new(
+        ErrorCode::DatabaseError,
+        format!("Failed to get {}: {}", field, e),
+    )
+}
+
+fn row_to_tokens(row: sqlx::postgres::PgRow) -> Result<GoogleTokens, DomainError> {
+    let access_token: String = row
+        .try_get("access_token")
+        .map_err(|e| db_error("access_token", e))?;
+    let refresh_token: Option<String> = row
+        .try_get("refresh_token")
+        .map_err(|e| db_error("refresh_token", e))?;
+    let expires_at: chrono::DateTime<chrono::Utc> = row
+        .try_get("expires_at")
+        .map_err(|e| db_error("expires_at", e))?;
+
+    Ok(GoogleTokens {
+        access_token: Secret::new(access_token),
+        refresh_token: refresh_token.map(Secret::new),
+        expires_at: Timestamp::from_datetime(expires_at),
+    })
+}
@@ -0,0 +1,214 @@
+//! PostgreSQL implementation of HelpContentRepository.
+//!
+//! Every edit is a new row in `help_articles`; the highest version of an
+//! (id, locale) pair is the live article. Concurrent edits from the same
+//! version collide on the primary key and surface as a version conflict.
+
+use async_trait::async_trait;
+use sqlx::{PgPool, Row};
+
+use crate::domain::foundation::{ComponentType, Timestamp, UserId};
+use crate::ports::{
+    validate_help_id, HelpArticle, HelpArticleKind, HelpContentError, HelpContentRepository,
+};
+
+const COLUMNS: &str =
+    "id, locale, version, kind, component_type, title, summary, body, updated_by, updated_at";
+
+/// PostgreSQL-backed help content repository.
+#[derive(Clone)]
+pub struct PostgresHelpContentRepository {
+    pool: PgPool,
+}
+
+impl PostgresHelpContentRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    async fn latest_version(&self, id: &str, locale: &str) -> Result<u32, HelpContentError> {
+        let version: Option<i32> = sqlx::query_scalar(
+            "SELECT MAX(version) FROM help_articles WHERE id = $1 AND locale = $2",
+        )
+        .bind(id)
+        .bind(locale)
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|e| storage_error("Failed to load help article version", e))?;
+        Ok(version.unwrap_or(0) as u32)
+    }
+}
+
+#[async_trait]
+impl HelpContentRepository for PostgresHelpContentRepository {
+    #[tracing::instrument(name = "PostgresHelpContentRepository::get", skip_all, fields(db.system = "postgresql"), err)]
+    async fn get(&self, id: &str, locale: &str) -> Result<Option<HelpArticle>, HelpContentError> {
+        let row = sqlx::query(&format!(
+            r#"
+            SELECT {}
+            FROM help_articles
+            WHERE id = $1 AND locale = $2
+            ORDER BY version DESC
+            LIMIT 1
+            "#,
+            COLUMNS
+        ))
+        .bind(id)
+        .bind(locale)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| storage_error("Failed to load help article", e))?;
+
+        row.map(row_to_article).transpose()
+    }
+
+    #[tracing::instrument(name = "PostgresHelpContentRepository::list", skip_all, fields(db.system = "postgresql"), err)]
+    async fn list(
+        &self,
+        locale: &str,
+        kind: Option<HelpArticleKind>,
+    ) -> Result<Vec<HelpArticle>, HelpContentError> {
+        let rows = sqlx::query(&format!(
+            r#"
+            SELECT DISTINCT ON (id) {}
+            FROM help_articles
+            WHERE locale = $1 AND ($2::TEXT IS NULL OR kind = $2)
+            ORDER BY id, version DESC
+            "#,
+            COLUMNS
+        ))
+        .bind(locale)
+        .bind(kind.map(|k| k.as_str()))
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| storage_error("Failed to list help articles", e))?;
+
+        rows.into_iter().map(row_to_article).collect()
+    }
+
+    #[tracing::instrument(name = "PostgresHelpContentRepository::save", skip_all, fields(db.system = "postgresql"), err)]
+    async fn save(
+        &self,
+        mut article: HelpArticle,
+        expected_version: u32,
+    ) -> Result<HelpArticle, HelpContentError> {
+        validate_help_id(&article.id)?;
+
+        let current = self.latest_version(&article.id, &article.locale).await?;
+        if current != expected_version {
+            return Err(HelpContentError::VersionConflict {
+                expected: expected_version,
+                actual: current,
+            });
+        }
+        article.version = current + 1;
+
+        let result = sqlx::query(
+            r#"
+            INSERT INTO help_articles
+                (id, locale, version, kind, component_type, title, summary, body, updated_by, updated_at)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10)
+            "#,
+        )
+        .bind(&article.id)
+        .bind(&article.locale)
+        .bind(article.version as i32)
+        .bind(article.kind.as_str())
+        .bind(article.component_type.map(component_type_name))
+        .bind(&article.title)
+        .bind(&article.summary)
+        .bind(&article.body)
+        .bind(article.updated_by.as_ref().map(|u| u.as_str()))
+        .bind(article.updated_at.as_datetime())
+        .execute(&self.pool)
+        .await;
+
+        match result {
+            Ok(_) => Ok(article),
+            // Another editor saved the same version first
+            Err(sqlx::Error::Database(e)) if e.is_unique_violation() => {
+                Err(HelpContentError::VersionConflict {
+                    expected: expected_version,
+                    actual: self.latest_version(&article.id, &article.locale).await?,
+                })
+            }
+            Err(e) => Err(storage_error("Failed to save help article", e)),
+        }
+    }
+
+    #[tracing::instrument(name = "PostgresHelpContentRepository::history", skip_all, fields(db.system = "postgresql"), err)]
+    async fn history(&self, id: &str, locale: &str) -> Result<Vec<HelpArticle>, HelpContentError> {
+        let rows = sqlx::query(&format!(
+            r#"
+            SELECT {}
+            FROM help_articles
+            WHERE id = $1 AND locale = $2
+            ORDER BY version DESC
+            "#,
+            COLUMNS
+        ))
+        .bind(id)
+        .bind(locale)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| storage_error("Failed to load help article history", e))?;
+
+        rows.into_iter().map(row_to_article).collect()
+    }
+}
+
+fn component_type_name(component_type: ComponentType) -> String {
+    serde_json::to_value(component_type)
+        .ok()
+        .and_then(|v| v.as_str().map(str::to_string))
+        .unwrap_or_default()
+}
+
+fn row_to_article(row: sqlx::postgres::PgRow) -> Result<HelpArticle, HelpContentError> {
+    let kind: String = row
+        .try_get("kind")
+        .map_err(|e| storage_error("Invalid kind", e))?;
+    let component_type: Option<String> = row
+        .try_get("component_type")
+        .map_err(|e| storage_error("Invalid component_type", e))?;
+    let version: i32 = row
+        .try_get("version")
+        .map_err(|e| storage_error("Invalid version", e))?;
+    let updated_by: Option<String> = row
+        .try_get("updated_by")
+        .map_err(|e| storage_error("Invalid updated_by", e))?;
+    let updated_at: chrono::DateTime<chrono::Utc> = row
+        .try_get("updated_at")
+        .map_err(|e| storage_error("Invalid updated_at", e))?;
+
+    Ok(HelpArticle {
+        id: row
+            .try_get("id")
+            .map_err(|e| storage_error("Invalid id", e))?,
+        locale: row
+            .try_get("locale")
+            .map_err(|e| storage_error("Invalid locale", e))?,
+        version: version as u32,
+        kind: HelpArticleKind::parse(&kind)
+            .ok_or_else(|| HelpContentError::Storage(format!("Unknown help kind: {}", kind)))?,
+        component_type: component_type
+            .map(|ct| serde_json::from_value(serde_json::Value::String(ct)))
+            .transpose()
+            .map_err(|e| HelpContentError::Storage(format!("Invalid component_type: {}", e)))?,
+        title: row
+            .try_get("title")
+            .map_err(|e| storage_error("Invalid title", e))?,
+        summary: row
+            .try_get("summary")
+            .map_err(|e| storage_error("Invalid summary", e))?,
+        body: row
+            .try_get("body")
+            .map_err(|e| storage_error("Invalid body", e))?,
+        updated_by: updated_by.and_then(|u| UserId::new(u).ok()),
+        updated_at: Timestamp::from_datetime(updated_at),
+    })
+}
+
+fn storage_error(context: &str, e: sqlx::Error) -> HelpContentError {
+    HelpContentError::Storage(format!("{}: {}", context, e))
+}
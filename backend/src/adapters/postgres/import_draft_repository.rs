@@ -0,0 +1,159 @@
+//! PostgreSQL implementation of ImportDraftRepository.
+//!
+//! Each draft is stored as JSONB in `import_drafts`, with its confirmation
+//! status copied to a column so pending drafts can be listed.
+
+use async_trait::async_trait;
+use sqlx::{PgPool, Row};
+
+use crate::domain::conversation::tools::ConfirmationStatus;
+use crate::domain::document::ImportDraft;
+use crate::domain::foundation::{ConfirmationRequestId, CycleId, DomainError, ErrorCode};
+use crate::ports::ImportDraftRepository;
+
+/// PostgreSQL implementation of ImportDraftRepository.
+#[derive(Clone)]
+pub struct PostgresImportDraftRepository {
+    pool: PgPool,
+}
+
+impl PostgresImportDraftRepository {
+    /// Creates a new PostgresImportDraftRepository.
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl ImportDraftRepository for PostgresImportDraftRepository {
+    #[tracing::instrument(name = "PostgresImportDraftRepository::save", skip_all, fields(db.system = "postgresql"), err)]
+    async fn save(&self, draft: &ImportDraft) -> Result<(), DomainError> {
+        sqlx::query(
+            r#"
+            INSERT INTO import_drafts (id, cycle_id, created_by, status, draft, created_at)
+            VALUES ($1, $2, $3, $4, $5, $6)
+            "#,
+        )
+        .bind(draft.id().as_uuid())
+        .bind(draft.cycle_id.as_uuid())
+        .bind(draft.created_by.as_str())
+        .bind(status_to_str(draft.confirmation.status()))
+        .bind(draft_to_json(draft)?)
+        .bind(draft.created_at.as_datetime())
+        .execute(&self.pool)
+        .await
+        .map_err(|e| {
+            DomainError::new(
+                ErrorCode::DatabaseError,
+                format!("Failed to insert import draft: {}", e),
+            )
+        })?;
+
+        Ok(())
+    }
+
+    #[tracing::instrument(name = "PostgresImportDraftRepository::update", skip_all, fields(db.system = "postgresql"), err)]
+    async fn update(&self, draft: &ImportDraft) -> Result<(), DomainError> {
+        let result = sqlx::query(
+            r#"
+            UPDATE import_drafts
+            SET status = $2, draft = $3, updated_at = NOW()
+            WHERE id = $1
+            "#,
+        )
+        .bind(draft.id().as_uuid())
+        .bind(status_to_str(draft.confirmation.status()))
+        .bind(draft_to_json(draft)?)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| {
+            DomainError::new(
+                ErrorCode::DatabaseError,
+                format!("Failed to update import draft: {}", e),
+            )
+        })?;
+
+        if result.rows_affected() == 0 {
+            return Err(DomainError::new(
+                ErrorCode::NotFound,
+                format!("Import draft {} not found", draft.id()),
+            ));
+        }
+        Ok(())
+    }
+
+    #[tracing::instrument(name = "PostgresImportDraftRepository::find_by_id", skip_all, fields(db.system = "postgresql"), err)]
+    async fn find_by_id(
+        &self,
+        id: &ConfirmationRequestId,
+    ) -> Result<Option<ImportDraft>, DomainError> {
+        let row = sqlx::query("SELECT draft FROM import_drafts WHERE id = $1")
+            .bind(id.as_uuid())
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(|e| {
+                DomainError::new(
+                    ErrorCode::DatabaseError,
+                    format!("Failed to fetch import draft: {}", e),
+                )
+            })?;
+
+        row.map(row_to_draft).transpose()
+    }
+
+    #[tracing::instrument(name = "PostgresImportDraftRepository::list_pending", skip_all, fields(db.system = "postgresql"), err)]
+    async fn list_pending(&self, cycle_id: &CycleId) -> Result<Vec<ImportDraft>, DomainError> {
+        let rows = sqlx::query(
+            r#"
+            SELECT draft
+            FROM import_drafts
+            WHERE cycle_id = $1 AND status = 'pending'
+            ORDER BY created_at DESC
+            "#,
+        )
+        .bind(cycle_id.as_uuid())
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| {
+            DomainError::new(
+                ErrorCode::DatabaseError,
+                format!("Failed to list import drafts: {}", e),
+            )
+        })?;
+
+        rows.into_iter().map(row_to_draft).collect()
+    }
+}
+
+fn status_to_str(status: ConfirmationStatus) -> &'static str {
+    match status {
+        ConfirmationStatus::Pending => "pending",
+        ConfirmationStatus::Confirmed => "confirmed",
+        ConfirmationStatus::Rejected => "rejected",
+        ConfirmationStatus::Expired => "expired",
+    }
+}
+
+fn draft_to_json(draft: &ImportDraft) -> Result<serde_json::Value, DomainError> {
+    serde_json::to_value(draft).map_err(|e| {
+        DomainError::new(
+            ErrorCode::InternalError,
+            format!("Failed to serialize import draft: {}", e),
+        )
+    })
+}
+
+fn row_to_draft(row: sqlx::postgres::PgRow) -> Result<ImportDraft, DomainError> {
+    let draft: serde_json::Value = row.try_get("draft").map_err(|e| {
+        DomainError::new(
+            ErrorCode::DatabaseError,
+            format!("Failed to get draft: {}", e),
+        )
+    })?;
+    serde_json::from_value(draft).map_err(|e| {
+        DomainError::new(
+            ErrorCode::DatabaseError,
+            format!("Invalid stored import draft: {}", e),
+        )
+    })
+}
@@ -0,0 +1,361 @@
+//! PostgreSQL implementations of the integration ports.
+//!
+//! Integrations live in `integrations`, with their filter and action as
+//! JSONB; triggered actions live in `integration_deliveries`, where pending
+//! rows form the retry queue. Deleting an integration cascades to its
+//! deliveries.
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::{PgPool, Row};
+
+use crate::domain::document::DeliveryStatus;
+use crate::domain::foundation::{
+    DomainError, ErrorCode, EventId, IntegrationDeliveryId, IntegrationId, Timestamp, UserId,
+};
+use crate::domain::integration::{
+    EventFilter, Integration, IntegrationAction, IntegrationDelivery,
+};
+use crate::ports::{IntegrationDeliveryRepository, IntegrationRepository};
+
+/// PostgreSQL implementation of IntegrationRepository.
+#[derive(Clone)]
+pub struct PostgresIntegrationRepository {
+    pool: PgPool,
+}
+
+impl PostgresIntegrationRepository {
+    /// Creates a new PostgresIntegrationRepository.
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+const INTEGRATION_COLUMNS: &str =
+    "id, user_id, name, filter, action, template, enabled, created_at, updated_at";
+
+#[async_trait]
+impl IntegrationRepository for PostgresIntegrationRepository {
+    #[tracing::instrument(name = "PostgresIntegrationRepository::save", skip_all, fields(db.system = "postgresql"), err)]
+    async fn save(&self, integration: &Integration) -> Result<(), DomainError> {
+        let filter = to_json("integration filter", &integration.filter)?;
+        let action = to_json("integration action", &integration.action)?;
+
+        sqlx::query(
+            r#"
+            INSERT INTO integrations (
+                id, user_id, name, filter, action, template, enabled, created_at, updated_at
+            ) VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)
+            ON CONFLICT (id) DO UPDATE SET
+                name = EXCLUDED.name,
+                filter = EXCLUDED.filter,
+                action = EXCLUDED.action,
+                template = EXCLUDED.template,
+                enabled = EXCLUDED.enabled,
+                updated_at = EXCLUDED.updated_at
+            "#,
+        )
+        .bind(integration.id.as_uuid())
+        .bind(integration.user_id.as_str())
+        .bind(&integration.name)
+        .bind(filter)
+        .bind(action)
+        .bind(integration.template.as_deref())
+        .bind(integration.enabled)
+        .bind(integration.created_at.as_datetime())
+        .bind(integration.updated_at.as_datetime())
+        .execute(&self.pool)
+        .await
+        .map_err(|e| {
+            DomainError::new(
+                ErrorCode::DatabaseError,
+                format!("Failed to save integration: {}", e),
+            )
+        })?;
+
+        Ok(())
+    }
+
+    #[tracing::instrument(name = "PostgresIntegrationRepository::find_by_id", skip_all, fields(db.system = "postgresql"), err)]
+    async fn find_by_id(&self, id: &IntegrationId) -> Result<Option<Integration>, DomainError> {
+        let row = sqlx::query(&format!(
+            "SELECT {} FROM integrations WHERE id = $1",
+            INTEGRATION_COLUMNS
+        ))
+        .bind(id.as_uuid())
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| {
+            DomainError::new(
+                ErrorCode::DatabaseError,
+                format!("Failed to fetch integration: {}", e),
+            )
+        })?;
+
+        row.map(row_to_integration).transpose()
+    }
+
+    #[tracing::instrument(name = "PostgresIntegrationRepository::list_for_user", skip_all, fields(db.system = "postgresql"), err)]
+    async fn list_for_user(&self, user_id: &UserId) -> Result<Vec<Integration>, DomainError> {
+        let rows = sqlx::query(&format!(
+            "SELECT {} FROM integrations WHERE user_id = $1 ORDER BY created_at ASC",
+            INTEGRATION_COLUMNS
+        ))
+        .bind(user_id.as_str())
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| {
+            DomainError::new(
+                ErrorCode::DatabaseError,
+                format!("Failed to fetch integrations: {}", e),
+            )
+        })?;
+
+        rows.into_iter().map(row_to_integration).collect()
+    }
+
+    #[tracing::instrument(name = "PostgresIntegrationRepository::delete", skip_all, fields(db.system = "postgresql"), err)]
+    async fn delete(&self, id: &IntegrationId) -> Result<(), DomainError> {
+        sqlx::query("DELETE FROM integrations WHERE id = $1")
+            .bind(id.as_uuid())
+            .execute(&self.pool)
+            .await
+            .map_err(|e| {
+                DomainError::new(
+                    ErrorCode::DatabaseError,
+                    format!("Failed to delete integration: {}", e),
+                )
+            })?;
+
+        Ok(())
+    }
+}
+
+/// PostgreSQL implementation of IntegrationDeliveryRepository.
+#[derive(Clone)]
+pub struct PostgresIntegrationDeliveryRepository {
+    pool: PgPool,
+}
+
+impl PostgresIntegrationDeliveryRepository {
+    /// Creates a new PostgresIntegrationDeliveryRepository.
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+const DELIVERY_COLUMNS: &str = "id, integration_id, user_id, event_id, event_type, context, \
+     status, attempts, last_error, next_attempt_at, created_at, sent_at";
+
+#[async_trait]
+impl IntegrationDeliveryRepository for PostgresIntegrationDeliveryRepository {
+    #[tracing::instrument(name = "PostgresIntegrationDeliveryRepository::save", skip_all, fields(db.system = "postgresql"), err)]
+    async fn save(&self, delivery: &IntegrationDelivery) -> Result<(), DomainError> {
+        sqlx::query(
+            r#"
+            INSERT INTO integration_deliveries (
+                id, integration_id, user_id, event_id, event_type, context, status,
+                attempts, last_error, next_attempt_at, created_at, sent_at
+            ) VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12)
+            ON CONFLICT (id) DO UPDATE SET
+                status = EXCLUDED.status,
+                attempts = EXCLUDED.attempts,
+                last_error = EXCLUDED.last_error,
+                next_attempt_at = EXCLUDED.next_attempt_at,
+                sent_at = EXCLUDED.sent_at
+            "#,
+        )
+        .bind(delivery.id.as_uuid())
+        .bind(delivery.integration_id.as_uuid())
+        .bind(delivery.user_id.as_str())
+        .bind(delivery.event_id.as_str())
+        .bind(&delivery.event_type)
+        .bind(&delivery.context)
+        .bind(delivery.status.as_str())
+        .bind(delivery.attempts as i32)
+        .bind(delivery.last_error.as_deref())
+        .bind(delivery.next_attempt_at.as_datetime())
+        .bind(delivery.created_at.as_datetime())
+        .bind(delivery.sent_at.as_ref().map(|t| *t.as_datetime()))
+        .execute(&self.pool)
+        .await
+        .map_err(|e| {
+            DomainError::new(
+                ErrorCode::DatabaseError,
+                format!("Failed to save integration delivery: {}", e),
+            )
+        })?;
+
+        Ok(())
+    }
+
+    #[tracing::instrument(name = "PostgresIntegrationDeliveryRepository::list_due", skip_all, fields(db.system = "postgresql"), err)]
+    async fn list_due(
+        &self,
+        now: Timestamp,
+        limit: u32,
+    ) -> Result<Vec<IntegrationDelivery>, DomainError> {
+        let rows = sqlx::query(&format!(
+            "SELECT {} FROM integration_deliveries \
+             WHERE status = 'pending' AND next_attempt_at <= $1 \
+             ORDER BY next_attempt_at ASC LIMIT $2",
+            DELIVERY_COLUMNS
+        ))
+        .bind(now.as_datetime())
+        .bind(limit as i64)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| {
+            DomainError::new(
+                ErrorCode::DatabaseError,
+                format!("Failed to fetch due integration deliveries: {}", e),
+            )
+        })?;
+
+        rows.into_iter().map(row_to_delivery).collect()
+    }
+
+    #[tracing::instrument(name = "PostgresIntegrationDeliveryRepository::list_for_integration", skip_all, fields(db.system = "postgresql"), err)]
+    async fn list_for_integration(
+        &self,
+        integration_id: &IntegrationId,
+        limit: u32,
+    ) -> Result<Vec<IntegrationDelivery>, DomainError> {
+        let rows = sqlx::query(&format!(
+            "SELECT {} FROM integration_deliveries \
+             WHERE integration_id = $1 \
+             ORDER BY created_at DESC LIMIT $2",
+            DELIVERY_COLUMNS
+        ))
+        .bind(integration_id.as_uuid())
+        .bind(limit as i64)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| {
+            DomainError::new(
+                ErrorCode::DatabaseError,
+                format!("Failed to fetch integration deliveries: {}", e),
+            )
+        })?;
+
+        rows.into_iter().map(row_to_delivery).collect()
+    }
+}
+
+fn to_json(what: &str, value: &impl serde::Serialize) -> Result<serde_json::Value, DomainError> {
+    serde_json::to_value(value).map_err(|e| {
+        DomainError::new(
+            ErrorCode::InternalError,
+            format!("Failed to serialize {}: {}", what, e),
+        )
+    })
+}
+
+fn from_json<T: serde::de::DeserializeOwned>(
+    what: &str,
+    value: serde_json::Value,
+) -> Result<T, DomainError> {
+    serde_json::from_value(value).map_err(|e| {
+        DomainError::new(
+            ErrorCode::DatabaseError,
+            format!("Invalid stored {}: {}", what, e),
+        )
+    })
+}
+
+fn db_error(field: &str, e: sqlx::Error) -> DomainError {
+    DomainError::new(
+        ErrorCode::DatabaseError,
+        format!("Failed to get {}: {}", field, e),
+    )
+}
+
+fn parse_user_id(user: String) -> Result<UserId, DomainError> {
+    UserId::new(user)
+        .map_err(|e| DomainError::new(ErrorCode::DatabaseError, format!("Invalid user_id: {}", e)))
+}
+
+fn row_to_integration(row: sqlx::postgres::PgRow) -> Result<Integration, DomainError> {
+    let id: uuid::Uuid = row.try_get("id").map_err(|e| db_error("id", e))?;
+    let user: String = row.try_get("user_id").map_err(|e| db_error("user_id", e))?;
+    let name: String = row.try_get("name").map_err(|e| db_error("name", e))?;
+    let filter: serde_json::Value = row.try_get("filter").map_err(|e| db_error("filter", e))?;
+    let action: serde_json::Value = row.try_get("action").map_err(|e| db_error("action", e))?;
+    let template: Option<String> = row
+        .try_get("template")
+        .map_err(|e| db_error("template", e))?;
+    let enabled: bool = row.try_get("enabled").map_err(|e| db_error("enabled", e))?;
+    let created_at: DateTime<Utc> = row
+        .try_get("created_at")
+        .map_err(|e| db_error("created_at", e))?;
+    let updated_at: DateTime<Utc> = row
+        .try_get("updated_at")
+        .map_err(|e| db_error("updated_at", e))?;
+
+    let filter: EventFilter = from_json("integration filter", filter)?;
+    let action: IntegrationAction = from_json("integration action", action)?;
+
+    Ok(Integration {
+        id: IntegrationId::from_uuid(id),
+        user_id: parse_user_id(user)?,
+        name,
+        filter,
+        action,
+        template,
+        enabled,
+        created_at: Timestamp::from_datetime(created_at),
+        updated_at: Timestamp::from_datetime(updated_at),
+    })
+}
+
+fn row_to_delivery(row: sqlx::postgres::PgRow) -> Result<IntegrationDelivery, DomainError> {
+    let id: uuid::Uuid = row.try_get("id").map_err(|e| db_error("id", e))?;
+    let integration_id: uuid::Uuid = row
+        .try_get("integration_id")
+        .map_err(|e| db_error("integration_id", e))?;
+    let user: String = row.try_get("user_id").map_err(|e| db_error("user_id", e))?;
+    let event_id: String = row
+        .try_get("event_id")
+        .map_err(|e| db_error("event_id", e))?;
+    let event_type: String = row
+        .try_get("event_type")
+        .map_err(|e| db_error("event_type", e))?;
+    let context: serde_json::Value = row.try_get("context").map_err(|e| db_error("context", e))?;
+    let status: String = row.try_get("status").map_err(|e| db_error("status", e))?;
+    let attempts: i32 = row
+        .try_get("attempts")
+        .map_err(|e| db_error("attempts", e))?;
+    let last_error: Option<String> = row
+        .try_get("last_error")
+        .map_err(|e| db_error("last_error", e))?;
+    let next_attempt_at: DateTime<Utc> = row
+        .try_get("next_attempt_at")
+        .map_err(|e| db_error("next_attempt_at", e))?;
+    let created_at: DateTime<Utc> = row
+        .try_get("created_at")
+        .map_err(|e| db_error("created_at", e))?;
+    let sent_at: Option<DateTime<Utc>> =
+        row.try_get("sent_at").map_err(|e| db_error("sent_at", e))?;
+
+    let status = DeliveryStatus::parse(&status).ok_or_else(|| {
+        DomainError::new(
+            ErrorCode::DatabaseError,
+            format!("Unknown delivery status: {}", status),
+        )
+    })?;
+
+    Ok(IntegrationDelivery {
+        id: IntegrationDeliveryId::from_uuid(id),
+        integration_id: IntegrationId::from_uuid(integration_id),
+        user_id: parse_user_id(user)?,
+        event_id: EventId::from_string(event_id),
+        event_type,
+        context,
+        status,
+        attempts: attempts as u32,
+        last_error,
+        next_attempt_at: Timestamp::from_datetime(next_attempt_at),
+        created_at: Timestamp::from_datetime(created_at),
+        sent_at: sent_at.map(Timestamp::from_datetime),
+    })
+}
@@ -78,6 +78,7 @@ fn parse_tier(s: &str) -> Result<MembershipTier, DomainError> {
 fn parse_status(s: &str) -> Result<MembershipStatus, DomainError> {
     match s.to_lowercase().as_str() {
         "pending" => Ok(MembershipStatus::Pending),
+        "trialing" => Ok(MembershipStatus::Trialing),
         "active" => Ok(MembershipStatus::Active),
         "past_due" => Ok(MembershipStatus::PastDue),
         "cancelled" => Ok(MembershipStatus::Cancelled),
@@ -180,6 +181,7 @@ impl TryFrom<MembershipSummaryRow> for MembershipSummary {
 
 #[async_trait]
 impl MembershipReader for PostgresMembershipReader {
+    #[tracing::instrument(name = "PostgresMembershipReader::get_by_user", skip_all, fields(db.system = "postgresql"), err)]
     async fn get_by_user(&self, user_id: &UserId) -> Result<Option<MembershipView>, DomainError> {
         let user_uuid = parse_user_id_as_uuid(user_id)?;
 
@@ -203,6 +205,7 @@ impl MembershipReader for PostgresMembershipReader {
         row.map(MembershipView::try_from).transpose()
     }
 
+    #[tracing::instrument(name = "PostgresMembershipReader::check_access", skip_all, fields(db.system = "postgresql"), err)]
     async fn check_access(&self, user_id: &UserId) -> Result<bool, DomainError> {
         let user_uuid = parse_user_id_as_uuid(user_id)?;
         let now = Utc::now();
@@ -247,6 +250,7 @@ impl MembershipReader for PostgresMembershipReader {
         Ok(true)
     }
 
+    #[tracing::instrument(name = "PostgresMembershipReader::get_tier", skip_all, fields(db.system = "postgresql"), err)]
     async fn get_tier(&self, user_id: &UserId) -> Result<Option<MembershipTier>, DomainError> {
         let user_uuid = parse_user_id_as_uuid(user_id)?;
 
@@ -267,6 +271,7 @@ impl MembershipReader for PostgresMembershipReader {
         row.map(|(tier_str,)| parse_tier(&tier_str)).transpose()
     }
 
+    #[tracing::instrument(name = "PostgresMembershipReader::list_expiring", skip_all, fields(db.system = "postgresql"), err)]
     async fn list_expiring(&self, days: u32) -> Result<Vec<MembershipSummary>, DomainError> {
         let now = Utc::now();
         let expiry_threshold = now + chrono::Duration::days(i64::from(days));
@@ -296,13 +301,14 @@ impl MembershipReader for PostgresMembershipReader {
         rows.into_iter().map(MembershipSummary::try_from).collect()
     }
 
+    #[tracing::instrument(name = "PostgresMembershipReader::get_statistics", skip_all, fields(db.system = "postgresql"), err)]
     async fn get_statistics(&self) -> Result<MembershipStatistics, DomainError> {
         // Get total and active counts
         let (total_count, active_count): (i64, i64) = sqlx::query_as(
             r#"
             SELECT
                 COUNT(*) as total,
-                COUNT(*) FILTER (WHERE status IN ('active', 'past_due', 'cancelled')) as active
+                COUNT(*) FILTER (WHERE status IN ('trialing', 'active', 'past_due', 'cancelled')) as active
             FROM memberships
             "#,
         )
@@ -363,6 +369,7 @@ impl MembershipReader for PostgresMembershipReader {
         for row in status_rows {
             match row.status.to_lowercase().as_str() {
                 "pending" => by_status.pending = row.count as u64,
+                "trialing" => by_status.trialing = row.count as u64,
                 "active" => by_status.active = row.count as u64,
                 "past_due" => by_status.past_due = row.count as u64,
                 "cancelled" => by_status.cancelled = row.count as u64,
@@ -436,6 +443,11 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn calculate_has_access_true_for_trialing() {
+        assert!(calculate_has_access(&MembershipStatus::Trialing, None));
+    }
+
     #[test]
     fn calculate_has_access_true_for_past_due() {
         assert!(calculate_has_access(&MembershipStatus::PastDue, None));
@@ -33,6 +33,7 @@ struct MembershipRow {
     status: String,
     stripe_customer_id: Option<String>,
     stripe_subscription_id: Option<String>,
+    seats: i32,
     promo_code: Option<String>,
     current_period_start: Option<DateTime<Utc>>,
     current_period_end: Option<DateTime<Utc>>,
@@ -71,6 +72,7 @@ impl TryFrom<MembershipRow> for Membership {
             promo_code: row.promo_code,
             stripe_customer_id: row.stripe_customer_id,
             stripe_subscription_id: row.stripe_subscription_id,
+            seats: row.seats as u32,
             created_at: Timestamp::from_datetime(row.created_at),
             updated_at: Timestamp::from_datetime(row.updated_at),
             cancelled_at: None, // Note: cancelled_at is derived from status, not stored separately
@@ -93,6 +95,7 @@ fn parse_tier(s: &str) -> Result<MembershipTier, DomainError> {
 fn parse_status(s: &str) -> Result<MembershipStatus, DomainError> {
     match s.to_lowercase().as_str() {
         "pending" => Ok(MembershipStatus::Pending),
+        "trialing" => Ok(MembershipStatus::Trialing),
         "active" => Ok(MembershipStatus::Active),
         "past_due" => Ok(MembershipStatus::PastDue),
         "cancelled" => Ok(MembershipStatus::Cancelled),
@@ -115,6 +118,7 @@ fn tier_to_string(tier: &MembershipTier) -> &'static str {
 fn status_to_string(status: &MembershipStatus) -> &'static str {
     match status {
         MembershipStatus::Pending => "pending",
+        MembershipStatus::Trialing => "trialing",
         MembershipStatus::Active => "active",
         MembershipStatus::PastDue => "past_due",
         MembershipStatus::Cancelled => "cancelled",
@@ -133,6 +137,7 @@ fn parse_user_id_as_uuid(user_id: &UserId) -> Result<Uuid, DomainError> {
 
 #[async_trait]
 impl MembershipRepository for PostgresMembershipRepository {
+    #[tracing::instrument(name = "PostgresMembershipRepository::save", skip_all, fields(db.system = "postgresql"), err)]
     async fn save(&self, membership: &Membership) -> Result<(), DomainError> {
         let user_uuid = parse_user_id_as_uuid(&membership.user_id)?;
 
@@ -140,8 +145,8 @@ impl MembershipRepository for PostgresMembershipRepository {
             r#"
             INSERT INTO memberships (
                 id, user_id, tier, status, stripe_customer_id, stripe_subscription_id,
-                promo_code, current_period_start, current_period_end, created_at, updated_at
-            ) VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11)
+                seats, promo_code, current_period_start, current_period_end, created_at, updated_at
+            ) VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12)
             "#,
         )
         .bind(membership.id.as_uuid())
@@ -150,6 +155,7 @@ impl MembershipRepository for PostgresMembershipRepository {
         .bind(status_to_string(&membership.status))
         .bind(&membership.stripe_customer_id)
         .bind(&membership.stripe_subscription_id)
+        .bind(membership.seats as i32)
         .bind(&membership.promo_code)
         .bind(membership.current_period_start.as_datetime())
         .bind(membership.current_period_end.as_datetime())
@@ -172,6 +178,7 @@ impl MembershipRepository for PostgresMembershipRepository {
         Ok(())
     }
 
+    #[tracing::instrument(name = "PostgresMembershipRepository::update", skip_all, fields(db.system = "postgresql"), err)]
     async fn update(&self, membership: &Membership) -> Result<(), DomainError> {
         let result = sqlx::query(
             r#"
@@ -184,6 +191,7 @@ impl MembershipRepository for PostgresMembershipRepository {
                 current_period_start = $7,
                 current_period_end = $8,
                 updated_at = $9,
+                seats = $10,
                 version = version + 1
             WHERE id = $1
             "#,
@@ -197,6 +205,7 @@ impl MembershipRepository for PostgresMembershipRepository {
         .bind(membership.current_period_start.as_datetime())
         .bind(membership.current_period_end.as_datetime())
         .bind(membership.updated_at.as_datetime())
+        .bind(membership.seats as i32)
         .execute(&self.pool)
         .await
         .map_err(|e| {
@@ -213,10 +222,11 @@ impl MembershipRepository for PostgresMembershipRepository {
         Ok(())
     }
 
+    #[tracing::instrument(name = "PostgresMembershipRepository::find_by_id", skip_all, fields(db.system = "postgresql"), err)]
     async fn find_by_id(&self, id: &MembershipId) -> Result<Option<Membership>, DomainError> {
         let row: Option<MembershipRow> = sqlx::query_as(
             r#"
-            SELECT id, user_id, tier, status, stripe_customer_id, stripe_subscription_id,
+            SELECT id, user_id, tier, status, stripe_customer_id, stripe_subscription_id, seats,
                    promo_code, current_period_start, current_period_end, created_at, updated_at, version
             FROM memberships
             WHERE id = $1
@@ -232,12 +242,13 @@ impl MembershipRepository for PostgresMembershipRepository {
         row.map(Membership::try_from).transpose()
     }
 
+    #[tracing::instrument(name = "PostgresMembershipRepository::find_by_user_id", skip_all, fields(db.system = "postgresql"), err)]
     async fn find_by_user_id(&self, user_id: &UserId) -> Result<Option<Membership>, DomainError> {
         let user_uuid = parse_user_id_as_uuid(user_id)?;
 
         let row: Option<MembershipRow> = sqlx::query_as(
             r#"
-            SELECT id, user_id, tier, status, stripe_customer_id, stripe_subscription_id,
+            SELECT id, user_id, tier, status, stripe_customer_id, stripe_subscription_id, seats,
                    promo_code, current_period_start, current_period_end, created_at, updated_at, version
             FROM memberships
             WHERE user_id = $1
@@ -253,13 +264,14 @@ impl MembershipRepository for PostgresMembershipRepository {
         row.map(Membership::try_from).transpose()
     }
 
+    #[tracing::instrument(name = "PostgresMembershipRepository::find_expiring_within_days", skip_all, fields(db.system = "postgresql"), err)]
     async fn find_expiring_within_days(&self, days: u32) -> Result<Vec<Membership>, DomainError> {
         let now = Utc::now();
         let expiry_threshold = now + chrono::Duration::days(i64::from(days));
 
         let rows: Vec<MembershipRow> = sqlx::query_as(
             r#"
-            SELECT id, user_id, tier, status, stripe_customer_id, stripe_subscription_id,
+            SELECT id, user_id, tier, status, stripe_customer_id, stripe_subscription_id, seats,
                    promo_code, current_period_start, current_period_end, created_at, updated_at, version
             FROM memberships
             WHERE status IN ('active', 'cancelled')
@@ -283,6 +295,7 @@ impl MembershipRepository for PostgresMembershipRepository {
         rows.into_iter().map(Membership::try_from).collect()
     }
 
+    #[tracing::instrument(name = "PostgresMembershipRepository::delete", skip_all, fields(db.system = "postgresql"), err)]
     async fn delete(&self, id: &MembershipId) -> Result<(), DomainError> {
         let result = sqlx::query("DELETE FROM memberships WHERE id = $1")
             .bind(id.as_uuid())
@@ -302,13 +315,14 @@ impl MembershipRepository for PostgresMembershipRepository {
         Ok(())
     }
 
+    #[tracing::instrument(name = "PostgresMembershipRepository::find_by_stripe_subscription_id", skip_all, fields(db.system = "postgresql"), err)]
     async fn find_by_stripe_subscription_id(
         &self,
         subscription_id: &str,
     ) -> Result<Option<Membership>, DomainError> {
         let row: Option<MembershipRow> = sqlx::query_as(
             r#"
-            SELECT id, user_id, tier, status, stripe_customer_id, stripe_subscription_id,
+            SELECT id, user_id, tier, status, stripe_customer_id, stripe_subscription_id, seats,
                    promo_code, current_period_start, current_period_end, created_at, updated_at, version
             FROM memberships
             WHERE stripe_subscription_id = $1
@@ -324,13 +338,14 @@ impl MembershipRepository for PostgresMembershipRepository {
         row.map(Membership::try_from).transpose()
     }
 
+    #[tracing::instrument(name = "PostgresMembershipRepository::find_by_stripe_customer_id", skip_all, fields(db.system = "postgresql"), err)]
     async fn find_by_stripe_customer_id(
         &self,
         customer_id: &str,
     ) -> Result<Option<Membership>, DomainError> {
         let row: Option<MembershipRow> = sqlx::query_as(
             r#"
-            SELECT id, user_id, tier, status, stripe_customer_id, stripe_subscription_id,
+            SELECT id, user_id, tier, status, stripe_customer_id, stripe_subscription_id, seats,
                    promo_code, current_period_start, current_period_end, created_at, updated_at, version
             FROM memberships
             WHERE stripe_customer_id = $1
@@ -369,6 +384,7 @@ mod tests {
     #[test]
     fn parse_status_works_for_all_values() {
         assert_eq!(parse_status("pending").unwrap(), MembershipStatus::Pending);
+        assert_eq!(parse_status("trialing").unwrap(), MembershipStatus::Trialing);
         assert_eq!(parse_status("active").unwrap(), MembershipStatus::Active);
         assert_eq!(parse_status("past_due").unwrap(), MembershipStatus::PastDue);
         assert_eq!(parse_status("cancelled").unwrap(), MembershipStatus::Cancelled);
@@ -391,6 +407,7 @@ mod tests {
     #[test]
     fn status_to_string_is_consistent() {
         assert_eq!(status_to_string(&MembershipStatus::Pending), "pending");
+        assert_eq!(status_to_string(&MembershipStatus::Trialing), "trialing");
         assert_eq!(status_to_string(&MembershipStatus::Active), "active");
         assert_eq!(status_to_string(&MembershipStatus::PastDue), "past_due");
         assert_eq!(status_to_string(&MembershipStatus::Cancelled), "cancelled");
@@ -428,6 +445,7 @@ mod tests {
     fn roundtrip_status_conversion() {
         for status in [
             MembershipStatus::Pending,
+            MembershipStatus::Trialing,
             MembershipStatus::Active,
             MembershipStatus::PastDue,
             MembershipStatus::Cancelled,
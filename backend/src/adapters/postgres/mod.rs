@@ -5,31 +5,166 @@
 //! # Tables
 //!
 //! - `sessions` - Session aggregate data
+//! - `session_archival_settings` - Auto-archive exclusion and warning state per session
+//! - `session_cold_storage` - Sessions whose cycles were offloaded to the document store
+//! - `consent_records` - Append-only consent ledger
+//! - `audit_log` - Append-only, hash-chained log of command executions
+//! - `audit_pseudonym_keys` - Per-user keys users are pseudonymized with in the audit log
 //! - `cycles` - Cycle aggregate metadata
+//! - `cycle_events` / `cycle_snapshots` - Versioned cycle history and periodic snapshots
+//! - `feature_flags` - Runtime feature flags with targeting
+//! - `document_templates` - Per-organization document export templates
+//! - `help_articles` - Versioned, localized help articles
 //! - `components` - Component data with JSONB outputs
+//! - `attachments` - Metadata for files attached to components
+//! - `document_versions` - Version history of decision documents
+//! - `event_log` - Every published event, replayed to rebuild projections
+//! - `projection_checkpoints` - How far each projection has read the event log
+//! - `data_exports` - GDPR data export requests and their retries
+//! - `data_erasures` - GDPR erasure requests, their retries and verification reports
+//! - `decision_records` - Decision history behind the decision profile
+//! - `decision_trees` - Decision trees for sequential decisions, nodes as JSONB
+//! - `decision_embeddings` - pgvector embeddings of past decisions for similarity search
+//! - `outcome_reminders` - Emailed outcome surveys and their retries
+//! - `calendar_feed_versions` - Rotated calendar feed URLs per user
+//! - `benchmark_distributions` - Anonymized cross-user benchmark distributions
+//! - `profile_summaries` - Latest profile summary per user
+//! - `profile_revisions` - Version history of profile summaries
+//! - `conversation_style_overrides` - Conversations opted out of adaptive style
+//! - `team_profile_settings` - Per-organization team profile settings
+//! - `document_deliveries` - Emailed decision documents and their retries
+//! - `document_email_preferences` - Opt-in for emailing completed documents
+//! - `document_publications` - Publicly shared document snapshots
+//! - `google_accounts` - Users' Google OAuth credentials for Docs export
+//! - `slack_workspaces` - Users' Slack app installs for sharing recommendations
+//! - `slack_shares` - Recommendations shared to Slack and their retries
+//! - `teams_shares` - Recommendations shared to Teams and their retries
+//! - `organization_chat_settings` - Each organization's chat platform
+//! - `organization_teams_channels` - Teams channels organizations registered, with encrypted webhook URLs
+//! - `import_drafts` - AI-drafted imports awaiting confirmation
+//! - `integrations` - Users' event-triggered outbound integrations
+//! - `integration_deliveries` - Triggered integration actions and their retries
 //! - `conversations` - Conversation aggregate
-//! - `messages` - Messages within conversations
+//! - `messages` - Messages within conversations, with token counts
 //! - `memberships` - User membership/subscription data
+//! - `organizations` - Teams sharing sessions and a billing membership
+//! - `organization_members` - Users in each organization with their role
+//! - `provisioned_users` - Users an identity provider manages via SCIM
 //! - `promo_codes` - Promotional codes for free access
+//! - `usage_reports` - Daily AI usage reported to metered subscriptions
+//!
+//! The `*BackupSource`s read the aggregate tables above for instance backups.
+//! `PostgresUserRecordsEraser` clears user-keyed rows on GDPR erasure and
+//! checks every table with a `user_id` column for leftovers.
 
 mod access_checker_impl;
+mod adaptive_style_override_repository;
+mod attachment_repository;
+mod audit_log;
+mod audit_pseudonym_keys;
+mod backup_sources;
+mod benchmark_repository;
+mod calendar_feed_version_repository;
+mod consent_repository;
 mod conversation_reader;
+mod conversation_record_repository;
 mod conversation_repository;
+mod cycle_event_store;
 mod cycle_reader;
 mod cycle_repository;
 mod dashboard_reader;
+mod data_erasure_repository;
+mod data_export_repository;
+mod decision_deadline_reader;
+mod decision_embedding_repository;
+mod decision_history_repository;
+mod decision_tree_repository;
+mod document_delivery_repository;
+mod document_publication_repository;
+mod document_template_store;
+mod document_version_repository;
+mod event_store;
+mod feature_flag_provider;
+mod google_account_store;
+mod help_content_repository;
+mod import_draft_repository;
+mod integration_repository;
 mod membership_reader;
 mod membership_repository;
+mod organization_repository;
+mod outcome_reminder_repository;
+mod profile_revision_repository;
+mod provisioned_user_repository;
+mod search_service;
+mod session_archival_repository;
+mod session_cold_storage_repository;
 mod session_reader;
 mod session_repository;
+mod slack_repository;
+mod team_profile_repository;
+mod teams_repository;
+mod usage_report_repository;
+mod user_records_eraser;
 
 pub use access_checker_impl::PostgresAccessChecker;
+pub use adaptive_style_override_repository::PostgresAdaptiveStyleOverrideRepository;
+pub use attachment_repository::PostgresAttachmentRepository;
+pub use audit_log::PostgresAuditLog;
+pub use audit_pseudonym_keys::PostgresAuditPseudonymKeys;
+pub use backup_sources::{
+    PostgresConversationBackupSource, PostgresMembershipBackupSource,
+    PostgresOrganizationBackupSource, PostgresSessionBackupSource,
+};
+pub use benchmark_repository::PostgresBenchmarkRepository;
+pub use calendar_feed_version_repository::PostgresCalendarFeedVersionRepository;
+pub use consent_repository::PostgresConsentRepository;
 pub use conversation_reader::PostgresConversationReader;
 pub use conversation_repository::PostgresConversationRepository;
 pub use cycle_reader::PostgresCycleReader;
+#[cfg(feature = "sqlite")]
+pub(crate) use cycle_reader::{
+    component_statuses_to_proact_status, component_type_to_proact_letter, progress_view,
+};
+pub use cycle_event_store::PostgresCycleEventStore;
 pub use cycle_repository::PostgresCycleRepository;
 pub use dashboard_reader::PostgresDashboardReader;
+pub use data_erasure_repository::PostgresDataErasureRepository;
+pub use data_export_repository::PostgresDataExportRepository;
+pub use decision_deadline_reader::PostgresDecisionDeadlineReader;
+pub use decision_embedding_repository::PostgresDecisionEmbeddingRepository;
+pub use decision_history_repository::PostgresDecisionHistoryRepository;
+pub use decision_tree_repository::PostgresDecisionTreeRepository;
+pub use document_template_store::PostgresDocumentTemplateStore;
+pub use document_delivery_repository::{
+    PostgresDocumentDeliveryRepository, PostgresDocumentEmailPreferenceRepository,
+};
+pub use document_publication_repository::PostgresDocumentPublicationRepository;
+pub use document_version_repository::PostgresDocumentVersionRepository;
+pub use event_store::{PostgresEventStore, PostgresProjectionCheckpointStore};
+pub use feature_flag_provider::{PostgresFeatureFlagProvider, DEFAULT_FLAG_REFRESH_INTERVAL};
+pub use google_account_store::PostgresGoogleAccountStore;
+pub use help_content_repository::PostgresHelpContentRepository;
+pub use import_draft_repository::PostgresImportDraftRepository;
+pub use integration_repository::{
+    PostgresIntegrationDeliveryRepository, PostgresIntegrationRepository,
+};
 pub use membership_reader::PostgresMembershipReader;
 pub use membership_repository::PostgresMembershipRepository;
+pub use organization_repository::PostgresOrganizationRepository;
+pub use outcome_reminder_repository::PostgresOutcomeReminderRepository;
+pub use profile_revision_repository::PostgresProfileRevisionRepository;
+pub use provisioned_user_repository::PostgresProvisionedUserRepository;
+pub use search_service::PostgresSearchService;
+pub use session_archival_repository::PostgresSessionArchivalRepository;
+pub use session_cold_storage_repository::PostgresSessionColdStorageRepository;
 pub use session_reader::PostgresSessionReader;
 pub use session_repository::PostgresSessionRepository;
+pub use slack_repository::{PostgresSlackShareRepository, PostgresSlackWorkspaceStore};
+pub use team_profile_repository::{
+    PostgresProfileSummaryRepository, PostgresTeamProfileSettingsRepository,
+};
+pub use teams_repository::{
+    PostgresOrganizationChatSettingsRepository, PostgresTeamsShareRepository,
+};
+pub use usage_report_repository::PostgresUsageReportRepository;
+pub use user_records_eraser::PostgresUserRecordsEraser;
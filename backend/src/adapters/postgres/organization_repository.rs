@@ -0,0 +1,268 @@
+//! PostgreSQL implementation of OrganizationRepository.
+//!
+//! Organizations live in `organizations` and their members in
+//! `organization_members`. Saving replaces the member rows in the same
+//! transaction, so readers never see a half-updated member list.
+
+use std::collections::HashMap;
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::{PgPool, Row};
+
+use crate::domain::foundation::{
+    DomainError, ErrorCode, MembershipId, OrganizationId, Timestamp, UserId,
+};
+use crate::domain::organization::{Organization, OrganizationMember, OrganizationRole};
+use crate::ports::OrganizationRepository;
+
+/// PostgreSQL implementation of OrganizationRepository.
+#[derive(Clone)]
+pub struct PostgresOrganizationRepository {
+    pool: PgPool,
+}
+
+impl PostgresOrganizationRepository {
+    /// Creates a new PostgresOrganizationRepository.
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    /// Loads the members of each organization row and assembles them.
+    async fn with_members(
+        &self,
+        rows: Vec<sqlx::postgres::PgRow>,
+    ) -> Result<Vec<Organization>, DomainError> {
+        if rows.is_empty() {
+            return Ok(Vec::new());
+        }
+        let ids: Vec<uuid::Uuid> = rows
+            .iter()
+            .map(|row| row.try_get("id").map_err(|e| db_error("id", e)))
+            .collect::<Result<_, _>>()?;
+
+        let member_rows = sqlx::query(
+            "SELECT organization_id, user_id, role, joined_at FROM organization_members \
+             WHERE organization_id = ANY($1) ORDER BY joined_at ASC",
+        )
+        .bind(&ids)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| {
+            DomainError::new(
+                ErrorCode::DatabaseError,
+                format!("Failed to fetch organization members: {}", e),
+            )
+        })?;
+
+        let mut members: HashMap<uuid::Uuid, Vec<OrganizationMember>> = HashMap::new();
+        for row in member_rows {
+            let organization_id: uuid::Uuid = row
+                .try_get("organization_id")
+                .map_err(|e| db_error("organization_id", e))?;
+            members
+                .entry(organization_id)
+                .or_default()
+                .push(row_to_member(row)?);
+        }
+
+        rows.into_iter()
+            .map(|row| {
+                let mut organization = row_to_organization(row)?;
+                organization.members = members
+                    .remove(organization.id.as_uuid())
+                    .unwrap_or_default();
+                Ok(organization)
+            })
+            .collect()
+    }
+}
+
+#[async_trait]
+impl OrganizationRepository for PostgresOrganizationRepository {
+    #[tracing::instrument(name = "PostgresOrganizationRepository::save", skip_all, fields(db.system = "postgresql"), err)]
+    async fn save(&self, organization: &Organization) -> Result<(), DomainError> {
+        let mut tx = self.pool.begin().await.map_err(|e| {
+            DomainError::new(
+                ErrorCode::DatabaseError,
+                format!("Failed to begin transaction: {}", e),
+            )
+        })?;
+
+        sqlx::query(
+            r#"
+            INSERT INTO organizations (id, name, billing_membership_id, created_at, updated_at)
+            VALUES ($1, $2, $3, $4, $5)
+            ON CONFLICT (id) DO UPDATE SET
+                name = EXCLUDED.name,
+                billing_membership_id = EXCLUDED.billing_membership_id,
+                updated_at = EXCLUDED.updated_at
+            "#,
+        )
+        .bind(organization.id.as_uuid())
+        .bind(&organization.name)
+        .bind(organization.billing_membership_id.map(|id| *id.as_uuid()))
+        .bind(organization.created_at.as_datetime())
+        .bind(organization.updated_at.as_datetime())
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| {
+            DomainError::new(
+                ErrorCode::DatabaseError,
+                format!("Failed to save organization: {}", e),
+            )
+        })?;
+
+        sqlx::query("DELETE FROM organization_members WHERE organization_id = $1")
+            .bind(organization.id.as_uuid())
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| {
+                DomainError::new(
+                    ErrorCode::DatabaseError,
+                    format!("Failed to clear organization members: {}", e),
+                )
+            })?;
+
+        for member in &organization.members {
+            sqlx::query(
+                r#"
+                INSERT INTO organization_members (organization_id, user_id, role, joined_at)
+                VALUES ($1, $2, $3, $4)
+                "#,
+            )
+            .bind(organization.id.as_uuid())
+            .bind(member.user_id.as_str())
+            .bind(member.role.as_str())
+            .bind(member.joined_at.as_datetime())
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| {
+                DomainError::new(
+                    ErrorCode::DatabaseError,
+                    format!("Failed to save organization member: {}", e),
+                )
+            })?;
+        }
+
+        tx.commit().await.map_err(|e| {
+            DomainError::new(
+                ErrorCode::DatabaseError,
+                format!("Failed to commit transaction: {}", e),
+            )
+        })?;
+
+        Ok(())
+    }
+
+    #[tracing::instrument(name = "PostgresOrganizationRepository::find_by_id", skip_all, fields(db.system = "postgresql"), err)]
+    async fn find_by_id(&self, id: &OrganizationId) -> Result<Option<Organization>, DomainError> {
+        let rows = sqlx::query(
+            "SELECT id, name, billing_membership_id, created_at, updated_at \
+             FROM organizations WHERE id = $1",
+        )
+        .bind(id.as_uuid())
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| {
+            DomainError::new(
+                ErrorCode::DatabaseError,
+                format!("Failed to fetch organization: {}", e),
+            )
+        })?;
+
+        Ok(self.with_members(rows).await?.pop())
+    }
+
+    #[tracing::instrument(name = "PostgresOrganizationRepository::list_for_member", skip_all, fields(db.system = "postgresql"), err)]
+    async fn list_for_member(&self, user_id: &UserId) -> Result<Vec<Organization>, DomainError> {
+        let rows = sqlx::query(
+            r#"
+            SELECT o.id, o.name, o.billing_membership_id, o.created_at, o.updated_at
+            FROM organizations o
+            JOIN organization_members om ON om.organization_id = o.id
+            WHERE om.user_id = $1
+            ORDER BY o.created_at ASC
+            "#,
+        )
+        .bind(user_id.as_str())
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| {
+            DomainError::new(
+                ErrorCode::DatabaseError,
+                format!("Failed to fetch organizations: {}", e),
+            )
+        })?;
+
+        self.with_members(rows).await
+    }
+
+    #[tracing::instrument(name = "PostgresOrganizationRepository::delete", skip_all, fields(db.system = "postgresql"), err)]
+    async fn delete(&self, id: &OrganizationId) -> Result<(), DomainError> {
+        sqlx::query("DELETE FROM organizations WHERE id = $1")
+            .bind(id.as_uuid())
+            .execute(&self.pool)
+            .await
+            .map_err(|e| {
+                DomainError::new(
+                    ErrorCode::DatabaseError,
+                    format!("Failed to delete organization: {}", e),
+                )
+            })?;
+
+        Ok(())
+    }
+}
+
+fn db_error(field: &str, e: sqlx::Error) -> DomainError {
+    DomainError::new(
+        ErrorCode::DatabaseError,
+        format!("Failed to get {}: {}", field, e),
+    )
+}
+
+/// Maps an organization row; members are filled in by the caller.
+fn row_to_organization(row: sqlx::postgres::PgRow) -> Result<Organization, DomainError> {
+    let id: uuid::Uuid = row.try_get("id").map_err(|e| db_error("id", e))?;
+    let name: String = row.try_get("name").map_err(|e| db_error("name", e))?;
+    let billing_membership_id: Option<uuid::Uuid> = row
+        .try_get("billing_membership_id")
+        .map_err(|e| db_error("billing_membership_id", e))?;
+    let created_at: DateTime<Utc> = row
+        .try_get("created_at")
+        .map_err(|e| db_error("created_at", e))?;
+    let updated_at: DateTime<Utc> = row
+        .try_get("updated_at")
+        .map_err(|e| db_error("updated_at", e))?;
+
+    Ok(Organization {
+        id: OrganizationId::from_uuid(id),
+        name,
+        members: Vec::new(),
+        billing_membership_id: billing_membership_id.map(MembershipId::from_uuid),
+        created_at: Timestamp::from_datetime(created_at),
+        updated_at: Timestamp::from_datetime(updated_at),
+    })
+}
+
+fn row_to_member(row: sqlx::postgres::PgRow) -> Result<OrganizationMember, DomainError> {
+    let user: String = row.try_get("user_id").map_err(|e| db_error("user_id", e))?;
+    let role: String = row.try_get("role").map_err(|e| db_error("role", e))?;
+    let joined_at: DateTime<Utc> = row
+        .try_get("joined_at")
+        .map_err(|e| db_error("joined_at", e))?;
+
+    Ok(OrganizationMember {
+        user_id: UserId::new(user).map_err(|e| {
+            DomainError::new(ErrorCode::DatabaseError, format!("Invalid user_id: {}", e))
+        })?,
+        role: OrganizationRole::parse(&role).ok_or_else(|| {
+            DomainError::new(
+                ErrorCode::DatabaseError,
+                format!("Unknown organization role: {}", role),
+            )
+        })?,
+        joined_at: Timestamp::from_datetime(joined_at),
+    })
+}
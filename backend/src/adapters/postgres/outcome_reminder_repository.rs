@@ -0,0 +1,241 @@
+//! PostgreSQL implementation of the outcome reminder port.
+//!
+//! Reminders live in `outcome_reminders`, where scheduled rows form the work
+//! queue for `OutcomeReminderMailer`.
+
+use async_trait::async_trait;
+use sqlx::{PgPool, Row};
+
+use crate::domain::foundation::{
+    CycleId, DomainError, ErrorCode, OutcomeReminderId, Timestamp, UserId,
+};
+use crate::domain::profile::{OutcomeReminder, OutcomeReminderStatus};
+use crate::ports::OutcomeReminderRepository;
+
+/// PostgreSQL implementation of OutcomeReminderRepository.
+#[derive(Clone)]
+pub struct PostgresOutcomeReminderRepository {
+    pool: PgPool,
+}
+
+impl PostgresOutcomeReminderRepository {
+    /// Creates a new PostgresOutcomeReminderRepository.
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+const REMINDER_COLUMNS: &str = "id, user_id, cycle_id, recipient, decision_title, status, \
+     attempts, last_error, next_attempt_at, survey_token_hash, sent_at, responded_at, created_at";
+
+#[async_trait]
+impl OutcomeReminderRepository for PostgresOutcomeReminderRepository {
+    #[tracing::instrument(name = "PostgresOutcomeReminderRepository::save", skip_all, fields(db.system = "postgresql"), err)]
+    async fn save(&self, reminder: &OutcomeReminder) -> Result<(), DomainError> {
+        sqlx::query(
+            r#"
+            INSERT INTO outcome_reminders (
+                id, user_id, cycle_id, recipient, decision_title, status, attempts,
+                last_error, next_attempt_at, survey_token_hash, sent_at, responded_at,
+                created_at
+            ) VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13)
+            ON CONFLICT (id) DO UPDATE SET
+                status = EXCLUDED.status,
+                attempts = EXCLUDED.attempts,
+                last_error = EXCLUDED.last_error,
+                next_attempt_at = EXCLUDED.next_attempt_at,
+                survey_token_hash = EXCLUDED.survey_token_hash,
+                sent_at = EXCLUDED.sent_at,
+                responded_at = EXCLUDED.responded_at
+            "#,
+        )
+        .bind(reminder.id.as_uuid())
+        .bind(reminder.user_id.as_str())
+        .bind(reminder.cycle_id.as_uuid())
+        .bind(&reminder.recipient)
+        .bind(&reminder.decision_title)
+        .bind(reminder.status.as_str())
+        .bind(reminder.attempts as i32)
+        .bind(reminder.last_error.as_deref())
+        .bind(reminder.next_attempt_at.as_datetime())
+        .bind(reminder.survey_token_hash.as_deref())
+        .bind(reminder.sent_at.as_ref().map(|t| *t.as_datetime()))
+        .bind(reminder.responded_at.as_ref().map(|t| *t.as_datetime()))
+        .bind(reminder.created_at.as_datetime())
+        .execute(&self.pool)
+        .await
+        .map_err(|e| {
+            DomainError::new(
+                ErrorCode::DatabaseError,
+                format!("Failed to save outcome reminder: {}", e),
+            )
+        })?;
+
+        Ok(())
+    }
+
+    #[tracing::instrument(name = "PostgresOutcomeReminderRepository::find_by_cycle", skip_all, fields(db.system = "postgresql"), err)]
+    async fn find_by_cycle(
+        &self,
+        user_id: &UserId,
+        cycle_id: &CycleId,
+    ) -> Result<Option<OutcomeReminder>, DomainError> {
+        let row = sqlx::query(&format!(
+            "SELECT {} FROM outcome_reminders WHERE user_id = $1 AND cycle_id = $2 \
+             ORDER BY created_at DESC LIMIT 1",
+            REMINDER_COLUMNS
+        ))
+        .bind(user_id.as_str())
+        .bind(cycle_id.as_uuid())
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| {
+            DomainError::new(
+                ErrorCode::DatabaseError,
+                format!("Failed to fetch outcome reminder: {}", e),
+            )
+        })?;
+
+        row.map(row_to_reminder).transpose()
+    }
+
+    #[tracing::instrument(name = "PostgresOutcomeReminderRepository::find_by_token_hash", skip_all, fields(db.system = "postgresql"), err)]
+    async fn find_by_token_hash(
+        &self,
+        token_hash: &str,
+    ) -> Result<Option<OutcomeReminder>, DomainError> {
+        let row = sqlx::query(&format!(
+            "SELECT {} FROM outcome_reminders WHERE survey_token_hash = $1",
+            REMINDER_COLUMNS
+        ))
+        .bind(token_hash)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| {
+            DomainError::new(
+                ErrorCode::DatabaseError,
+                format!("Failed to fetch outcome reminder: {}", e),
+            )
+        })?;
+
+        row.map(row_to_reminder).transpose()
+    }
+
+    #[tracing::instrument(name = "PostgresOutcomeReminderRepository::list_due", skip_all, fields(db.system = "postgresql"), err)]
+    async fn list_due(
+        &self,
+        now: Timestamp,
+        limit: u32,
+    ) -> Result<Vec<OutcomeReminder>, DomainError> {
+        let rows = sqlx::query(&format!(
+            "SELECT {} FROM outcome_reminders \
+             WHERE status = 'scheduled' AND next_attempt_at <= $1 \
+             ORDER BY next_attempt_at ASC LIMIT $2",
+            REMINDER_COLUMNS
+        ))
+        .bind(now.as_datetime())
+        .bind(limit as i64)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| {
+            DomainError::new(
+                ErrorCode::DatabaseError,
+                format!("Failed to fetch due outcome reminders: {}", e),
+            )
+        })?;
+
+        rows.into_iter().map(row_to_reminder).collect()
+    }
+
+    #[tracing::instrument(name = "PostgresOutcomeReminderRepository::list_scheduled_for_user", skip_all, fields(db.system = "postgresql"), err)]
+    async fn list_scheduled_for_user(
+        &self,
+        user_id: &UserId,
+    ) -> Result<Vec<OutcomeReminder>, DomainError> {
+        let rows = sqlx::query(&format!(
+            "SELECT {} FROM outcome_reminders \
+             WHERE user_id = $1 AND status = 'scheduled' \
+             ORDER BY next_attempt_at ASC",
+            REMINDER_COLUMNS
+        ))
+        .bind(user_id.as_str())
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| {
+            DomainError::new(
+                ErrorCode::DatabaseError,
+                format!("Failed to fetch scheduled outcome reminders: {}", e),
+            )
+        })?;
+
+        rows.into_iter().map(row_to_reminder).collect()
+    }
+}
+
+fn db_error(field: &str, e: sqlx::Error) -> DomainError {
+    DomainError::new(
+        ErrorCode::DatabaseError,
+        format!("Failed to get {}: {}", field, e),
+    )
+}
+
+fn row_to_reminder(row: sqlx::postgres::PgRow) -> Result<OutcomeReminder, DomainError> {
+    let id: uuid::Uuid = row.try_get("id").map_err(|e| db_error("id", e))?;
+    let user: String = row.try_get("user_id").map_err(|e| db_error("user_id", e))?;
+    let cycle_id: uuid::Uuid = row
+        .try_get("cycle_id")
+        .map_err(|e| db_error("cycle_id", e))?;
+    let recipient: String = row
+        .try_get("recipient")
+        .map_err(|e| db_error("recipient", e))?;
+    let decision_title: String = row
+        .try_get("decision_title")
+        .map_err(|e| db_error("decision_title", e))?;
+    let status: String = row.try_get("status").map_err(|e| db_error("status", e))?;
+    let attempts: i32 = row
+        .try_get("attempts")
+        .map_err(|e| db_error("attempts", e))?;
+    let last_error: Option<String> = row
+        .try_get("last_error")
+        .map_err(|e| db_error("last_error", e))?;
+    let next_attempt_at: chrono::DateTime<chrono::Utc> = row
+        .try_get("next_attempt_at")
+        .map_err(|e| db_error("next_attempt_at", e))?;
+    let survey_token_hash: Option<String> = row
+        .try_get("survey_token_hash")
+        .map_err(|e| db_error("survey_token_hash", e))?;
+    let sent_at: Option<chrono::DateTime<chrono::Utc>> =
+        row.try_get("sent_at").map_err(|e| db_error("sent_at", e))?;
+    let responded_at: Option<chrono::DateTime<chrono::Utc>> = row
+        .try_get("responded_at")
+        .map_err(|e| db_error("responded_at", e))?;
+    let created_at: chrono::DateTime<chrono::Utc> = row
+        .try_get("created_at")
+        .map_err(|e| db_error("created_at", e))?;
+
+    let status = OutcomeReminderStatus::parse(&status).ok_or_else(|| {
+        DomainError::new(
+            ErrorCode::DatabaseError,
+            format!("Unknown outcome reminder status: {}", status),
+        )
+    })?;
+    let user_id = UserId::new(user).map_err(|e| {
+        DomainError::new(ErrorCode::DatabaseError, format!("Invalid user_id: {}", e))
+    })?;
+
+    Ok(OutcomeReminder {
+        id: OutcomeReminderId::from_uuid(id),
+        user_id,
+        cycle_id: CycleId::from_uuid(cycle_id),
+        recipient,
+        decision_title,
+        status,
+        attempts: attempts as u32,
+        last_error,
+        next_attempt_at: Timestamp::from_datetime(next_attempt_at),
+        survey_token_hash,
+        sent_at: sent_at.map(Timestamp::from_datetime),
+        responded_at: responded_at.map(Timestamp::from_datetime),
+        created_at: Timestamp::from_datetime(created_at),
+    })
+}
@@ -0,0 +1,179 @@
+//! PostgreSQL implementation of the profile revision port.
+//!
+//! Each revision is one row in `profile_revisions`, keyed by user and
+//! version; the primary key rejects a second writer claiming the same
+//! version.
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::{PgPool, Row};
+
+use crate::domain::foundation::{DomainError, ErrorCode, Timestamp, UserId};
+use crate::domain::profile::ProfileRevision;
+use crate::ports::ProfileRevisionRepository;
+
+/// PostgreSQL implementation of ProfileRevisionRepository.
+#[derive(Clone)]
+pub struct PostgresProfileRevisionRepository {
+    pool: PgPool,
+}
+
+impl PostgresProfileRevisionRepository {
+    /// Creates a new PostgresProfileRevisionRepository.
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+const SELECT_COLUMNS: &str = "SELECT version, summary, reason, created_at FROM profile_revisions";
+
+#[async_trait]
+impl ProfileRevisionRepository for PostgresProfileRevisionRepository {
+    #[tracing::instrument(name = "PostgresProfileRevisionRepository::append", skip_all, fields(db.system = "postgresql"), err)]
+    async fn append(
+        &self,
+        user_id: &UserId,
+        revision: &ProfileRevision,
+    ) -> Result<(), DomainError> {
+        let summary = serde_json::to_value(&revision.summary).map_err(|e| {
+            DomainError::new(
+                ErrorCode::InternalError,
+                format!("Failed to serialize profile summary: {}", e),
+            )
+        })?;
+        let reason = serde_json::to_value(revision.reason).map_err(|e| {
+            DomainError::new(
+                ErrorCode::InternalError,
+                format!("Failed to serialize revision reason: {}", e),
+            )
+        })?;
+
+        sqlx::query(
+            r#"
+            INSERT INTO profile_revisions (user_id, version, summary, reason, created_at)
+            VALUES ($1, $2, $3, $4, $5)
+            "#,
+        )
+        .bind(user_id.as_str())
+        .bind(revision.version as i32)
+        .bind(summary)
+        .bind(reason)
+        .bind(revision.created_at.as_datetime())
+        .execute(&self.pool)
+        .await
+        .map_err(|e| {
+            DomainError::new(
+                ErrorCode::DatabaseError,
+                format!("Failed to append profile revision: {}", e),
+            )
+        })?;
+
+        Ok(())
+    }
+
+    #[tracing::instrument(name = "PostgresProfileRevisionRepository::latest", skip_all, fields(db.system = "postgresql"), err)]
+    async fn latest(&self, user_id: &UserId) -> Result<Option<ProfileRevision>, DomainError> {
+        let row = sqlx::query(&format!(
+            "{} WHERE user_id = $1 ORDER BY version DESC LIMIT 1",
+            SELECT_COLUMNS
+        ))
+        .bind(user_id.as_str())
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| {
+            DomainError::new(
+                ErrorCode::DatabaseError,
+                format!("Failed to fetch latest profile revision: {}", e),
+            )
+        })?;
+
+        row.map(row_to_revision).transpose()
+    }
+
+    #[tracing::instrument(name = "PostgresProfileRevisionRepository::find", skip_all, fields(db.system = "postgresql"), err)]
+    async fn find(
+        &self,
+        user_id: &UserId,
+        version: u32,
+    ) -> Result<Option<ProfileRevision>, DomainError> {
+        let row = sqlx::query(&format!(
+            "{} WHERE user_id = $1 AND version = $2",
+            SELECT_COLUMNS
+        ))
+        .bind(user_id.as_str())
+        .bind(version as i32)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| {
+            DomainError::new(
+                ErrorCode::DatabaseError,
+                format!("Failed to fetch profile revision: {}", e),
+            )
+        })?;
+
+        row.map(row_to_revision).transpose()
+    }
+
+    #[tracing::instrument(name = "PostgresProfileRevisionRepository::list_for_user", skip_all, fields(db.system = "postgresql"), err)]
+    async fn list_for_user(&self, user_id: &UserId) -> Result<Vec<ProfileRevision>, DomainError> {
+        let rows = sqlx::query(&format!(
+            "{} WHERE user_id = $1 ORDER BY version ASC",
+            SELECT_COLUMNS
+        ))
+        .bind(user_id.as_str())
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| {
+            DomainError::new(
+                ErrorCode::DatabaseError,
+                format!("Failed to fetch profile history: {}", e),
+            )
+        })?;
+
+        rows.into_iter().map(row_to_revision).collect()
+    }
+
+    #[tracing::instrument(name = "PostgresProfileRevisionRepository::delete_for_user", skip_all, fields(db.system = "postgresql"), err)]
+    async fn delete_for_user(&self, user_id: &UserId) -> Result<u64, DomainError> {
+        let result = sqlx::query("DELETE FROM profile_revisions WHERE user_id = $1")
+            .bind(user_id.as_str())
+            .execute(&self.pool)
+            .await
+            .map_err(|e| {
+                DomainError::new(
+                    ErrorCode::DatabaseError,
+                    format!("Failed to delete profile history: {}", e),
+                )
+            })?;
+
+        Ok(result.rows_affected())
+    }
+}
+
+fn row_to_revision(row: sqlx::postgres::PgRow) -> Result<ProfileRevision, DomainError> {
+    let db_err = |field: &str, e: sqlx::Error| {
+        DomainError::new(
+            ErrorCode::DatabaseError,
+            format!("Failed to get {}: {}", field, e),
+        )
+    };
+    let version: i32 = row.try_get("version").map_err(|e| db_err("version", e))?;
+    let summary: serde_json::Value = row.try_get("summary").map_err(|e| db_err("summary", e))?;
+    let reason: serde_json::Value = row.try_get("reason").map_err(|e| db_err("reason", e))?;
+    let created_at: DateTime<Utc> = row
+        .try_get("created_at")
+        .map_err(|e| db_err("created_at", e))?;
+
+    let invalid = |e: serde_json::Error| {
+        DomainError::new(
+            ErrorCode::DatabaseError,
+            format!("Invalid profile revision: {}", e),
+        )
+    };
+    Ok(ProfileRevision {
+        version: version as u32,
+        summary: serde_json::from_value(summary).map_err(invalid)?,
+        reason: serde_json::from_value(reason).map_err(invalid)?,
+        created_at: Timestamp::from_datetime(created_at),
+    })
+}
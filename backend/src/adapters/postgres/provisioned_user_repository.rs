@@ -0,0 +1,221 @@
+//! PostgreSQL implementation of ProvisionedUserRepository.
+//!
+//! `userName` is unique ignoring case, matching the SCIM definition of the
+//! attribute, so lookups compare lowercased values.
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::{PgPool, Row};
+
+use crate::domain::foundation::{DomainError, ErrorCode, Timestamp, UserId};
+use crate::domain::provisioning::ProvisionedUser;
+use crate::ports::{ProvisionedUserList, ProvisionedUserRepository};
+
+const SELECT_COLUMNS: &str = "SELECT user_id, user_name, email, display_name, active, \
+                              provisioned_by, created_at, updated_at FROM provisioned_users";
+
+/// PostgreSQL implementation of ProvisionedUserRepository.
+#[derive(Clone)]
+pub struct PostgresProvisionedUserRepository {
+    pool: PgPool,
+}
+
+impl PostgresProvisionedUserRepository {
+    /// Creates a new PostgresProvisionedUserRepository.
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl ProvisionedUserRepository for PostgresProvisionedUserRepository {
+    #[tracing::instrument(name = "PostgresProvisionedUserRepository::create", skip_all, fields(db.system = "postgresql"), err)]
+    async fn create(&self, user: &ProvisionedUser) -> Result<(), DomainError> {
+        sqlx::query(
+            r#"
+            INSERT INTO provisioned_users (
+                user_id, user_name, email, display_name, active, provisioned_by,
+                created_at, updated_at
+            )
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
+            "#,
+        )
+        .bind(user.user_id.as_str())
+        .bind(&user.user_name)
+        .bind(&user.email)
+        .bind(&user.display_name)
+        .bind(user.active)
+        .bind(&user.provisioned_by)
+        .bind(user.created_at.as_datetime())
+        .bind(user.updated_at.as_datetime())
+        .execute(&self.pool)
+        .await
+        .map_err(|e| match &e {
+            sqlx::Error::Database(db_err) if db_err.is_unique_violation() => DomainError::new(
+                ErrorCode::ConcurrentModification,
+                format!("Provisioned user {} already exists", user.user_id),
+            ),
+            _ => DomainError::new(
+                ErrorCode::DatabaseError,
+                format!("Failed to create provisioned user: {}", e),
+            ),
+        })?;
+
+        Ok(())
+    }
+
+    #[tracing::instrument(name = "PostgresProvisionedUserRepository::save", skip_all, fields(db.system = "postgresql"), err)]
+    async fn save(&self, user: &ProvisionedUser) -> Result<(), DomainError> {
+        sqlx::query(
+            r#"
+            INSERT INTO provisioned_users (
+                user_id, user_name, email, display_name, active, provisioned_by,
+                created_at, updated_at
+            )
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
+            ON CONFLICT (user_id) DO UPDATE SET
+                user_name = EXCLUDED.user_name,
+                email = EXCLUDED.email,
+                display_name = EXCLUDED.display_name,
+                active = EXCLUDED.active,
+                updated_at = EXCLUDED.updated_at
+            "#,
+        )
+        .bind(user.user_id.as_str())
+        .bind(&user.user_name)
+        .bind(&user.email)
+        .bind(&user.display_name)
+        .bind(user.active)
+        .bind(&user.provisioned_by)
+        .bind(user.created_at.as_datetime())
+        .bind(user.updated_at.as_datetime())
+        .execute(&self.pool)
+        .await
+        .map_err(|e| {
+            DomainError::new(
+                ErrorCode::DatabaseError,
+                format!("Failed to save provisioned user: {}", e),
+            )
+        })?;
+
+        Ok(())
+    }
+
+    #[tracing::instrument(name = "PostgresProvisionedUserRepository::find_by_id", skip_all, fields(db.system = "postgresql"), err)]
+    async fn find_by_id(&self, user_id: &UserId) -> Result<Option<ProvisionedUser>, DomainError> {
+        let row = sqlx::query(&format!("{} WHERE user_id = $1", SELECT_COLUMNS))
+            .bind(user_id.as_str())
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(|e| {
+                DomainError::new(
+                    ErrorCode::DatabaseError,
+                    format!("Failed to fetch provisioned user: {}", e),
+                )
+            })?;
+
+        row.map(row_to_user).transpose()
+    }
+
+    #[tracing::instrument(name = "PostgresProvisionedUserRepository::find_by_user_name", skip_all, fields(db.system = "postgresql"), err)]
+    async fn find_by_user_name(
+        &self,
+        user_name: &str,
+    ) -> Result<Option<ProvisionedUser>, DomainError> {
+        let row = sqlx::query(&format!(
+            "{} WHERE LOWER(user_name) = LOWER($1)",
+            SELECT_COLUMNS
+        ))
+        .bind(user_name)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| {
+            DomainError::new(
+                ErrorCode::DatabaseError,
+                format!("Failed to fetch provisioned user: {}", e),
+            )
+        })?;
+
+        row.map(row_to_user).transpose()
+    }
+
+    #[tracing::instrument(name = "PostgresProvisionedUserRepository::list", skip_all, fields(db.system = "postgresql"), err)]
+    async fn list(
+        &self,
+        client_id: &str,
+        offset: u32,
+        limit: u32,
+    ) -> Result<ProvisionedUserList, DomainError> {
+        let rows = sqlx::query(&format!(
+            "{} WHERE provisioned_by = $1 ORDER BY created_at ASC, user_id ASC LIMIT $2 OFFSET $3",
+            SELECT_COLUMNS
+        ))
+        .bind(client_id)
+        .bind(limit as i64)
+        .bind(offset as i64)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| {
+            DomainError::new(
+                ErrorCode::DatabaseError,
+                format!("Failed to list provisioned users: {}", e),
+            )
+        })?;
+
+        let total: i64 =
+            sqlx::query_scalar("SELECT COUNT(*) FROM provisioned_users WHERE provisioned_by = $1")
+                .bind(client_id)
+                .fetch_one(&self.pool)
+                .await
+                .map_err(|e| {
+                    DomainError::new(
+                        ErrorCode::DatabaseError,
+                        format!("Failed to count provisioned users: {}", e),
+                    )
+                })?;
+
+        Ok(ProvisionedUserList {
+            users: rows
+                .into_iter()
+                .map(row_to_user)
+                .collect::<Result<_, _>>()?,
+            total: total as u64,
+        })
+    }
+}
+
+fn db_error(field: &str, e: sqlx::Error) -> DomainError {
+    DomainError::new(
+        ErrorCode::DatabaseError,
+        format!("Failed to get {}: {}", field, e),
+    )
+}
+
+fn row_to_user(row: sqlx::postgres::PgRow) -> Result<ProvisionedUser, DomainError> {
+    let user_id: String = row.try_get("user_id").map_err(|e| db_error("user_id", e))?;
+    let created_at: DateTime<Utc> = row
+        .try_get("created_at")
+        .map_err(|e| db_error("created_at", e))?;
+    let updated_at: DateTime<Utc> = row
+        .try_get("updated_at")
+        .map_err(|e| db_error("updated_at", e))?;
+
+    Ok(ProvisionedUser {
+        user_id: UserId::new(user_id).map_err(|e| {
+            DomainError::new(ErrorCode::DatabaseError, format!("Invalid user_id: {}", e))
+        })?,
+        user_name: row
+            .try_get("user_name")
+            .map_err(|e| db_error("user_name", e))?,
+        email: row.try_get("email").map_err(|e| db_error("email", e))?,
+        display_name: row
+            .try_get("display_name")
+            .map_err(|e| db_error("display_name", e))?,
+        active: row.try_get("active").map_err(|e| db_error("active", e))?,
+        provisioned_by: row
+            .try_get("provisioned_by")
+            .map_err(|e| db_error("provisioned_by", e))?,
+        created_at: Timestamp::from_datetime(created_at),
+        updated_at: Timestamp::from_datetime(updated_at),
+    })
+}
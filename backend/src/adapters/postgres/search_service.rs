@@ -0,0 +1,168 @@
+//! PostgreSQL implementation of SearchService.
+//!
+//! Session titles and descriptions, the string values of component outputs,
+//! and user and assistant messages are matched with `websearch_to_tsquery`
+//! against the GIN expression indexes from the sessions and search
+//! migrations, then ranked together with `ts_rank`.
+//!
+//! Outputs and messages of sessions in cold storage have left the database
+//! and are not searchable until the session is restored.
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::PgPool;
+
+use crate::domain::foundation::{CycleId, SessionId, Timestamp, UserId};
+use crate::ports::{
+    SearchError, SearchHit, SearchHitKind, SearchQuery, SearchResults, SearchService,
+};
+
+use super::cycle_repository::str_to_component_type;
+
+/// Sessions the user owns or that are shared with one of their
+/// organizations, leaving out the trash.
+const ACCESSIBLE_SESSION: &str = r#"
+    s.status != 'deleted'
+    AND (s.user_id = $1 OR s.organization_id IN (
+        SELECT organization_id FROM organization_members WHERE user_id = $1
+    ))
+"#;
+
+/// `ts_headline` options; matched terms are wrapped in `**`.
+const HEADLINE_OPTIONS: &str = "StartSel=**, StopSel=**, MaxWords=35, MinWords=15";
+
+/// PostgreSQL-backed full-text search.
+#[derive(Clone)]
+pub struct PostgresSearchService {
+    pool: PgPool,
+}
+
+impl PostgresSearchService {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+/// One ranked match before conversion.
+#[derive(Debug, sqlx::FromRow)]
+struct SearchRow {
+    kind: String,
+    session_id: uuid::Uuid,
+    session_title: String,
+    cycle_id: Option<uuid::Uuid>,
+    component_type: Option<String>,
+    snippet: String,
+    rank: f32,
+    updated_at: DateTime<Utc>,
+}
+
+#[async_trait]
+impl SearchService for PostgresSearchService {
+    #[tracing::instrument(name = "PostgresSearchService::search", skip_all, fields(db.system = "postgresql"), err)]
+    async fn search(
+        &self,
+        user_id: &UserId,
+        query: &SearchQuery,
+    ) -> Result<SearchResults, SearchError> {
+        // Each branch repeats its index expression exactly so the planner
+        // can use it; snippets are only built for the page that is returned.
+        let sql = format!(
+            r#"
+            WITH q AS (SELECT websearch_to_tsquery('english', $2) AS query)
+            SELECT hits.kind, hits.session_id, hits.session_title, hits.cycle_id,
+                   hits.component_type, hits.rank, hits.updated_at,
+                   ts_headline('english', hits.document, q.query, '{headline}') AS snippet
+            FROM (
+                SELECT 'session' AS kind, s.id AS session_id, s.title AS session_title,
+                       NULL::UUID AS cycle_id, NULL::VARCHAR AS component_type,
+                       COALESCE(s.title, '') || ' ' || COALESCE(s.description, '') AS document,
+                       ts_rank(
+                           to_tsvector('english', COALESCE(s.title, '') || ' ' || COALESCE(s.description, '')),
+                           q.query
+                       ) AS rank,
+                       s.updated_at
+                FROM sessions s, q
+                WHERE {accessible}
+                  AND to_tsvector('english', COALESCE(s.title, '') || ' ' || COALESCE(s.description, ''))
+                      @@ q.query
+
+                UNION ALL
+
+                SELECT 'component_output', s.id, s.title, c.id, comp.component_type,
+                       (
+                           SELECT string_agg(v #>> '{{}}', ' ')
+                           FROM jsonb_path_query(
+                               comp.output, 'strict $.** ? (@.type() == "string")'
+                           ) AS v
+                       ),
+                       ts_rank(jsonb_to_tsvector('english', comp.output, '["string"]'), q.query),
+                       comp.updated_at
+                FROM components comp
+                JOIN cycles c ON c.id = comp.cycle_id
+                JOIN sessions s ON s.id = c.session_id
+                CROSS JOIN q
+                WHERE {accessible}
+                  AND jsonb_to_tsvector('english', comp.output, '["string"]') @@ q.query
+
+                UNION ALL
+
+                SELECT 'message', s.id, s.title, c.id, comp.component_type,
+                       m.content,
+                       ts_rank(to_tsvector('english', m.content), q.query),
+                       m.created_at
+                FROM messages m
+                JOIN conversations cv ON cv.id = m.conversation_id
+                JOIN components comp ON comp.id = cv.component_id
+                JOIN cycles c ON c.id = comp.cycle_id
+                JOIN sessions s ON s.id = c.session_id
+                CROSS JOIN q
+                WHERE {accessible}
+                  AND m.role IN ('user', 'assistant')
+                  AND to_tsvector('english', m.content) @@ q.query
+            ) hits, q
+            ORDER BY hits.rank DESC, hits.updated_at DESC
+            LIMIT $3 OFFSET $4
+            "#,
+            headline = HEADLINE_OPTIONS,
+            accessible = ACCESSIBLE_SESSION,
+        );
+
+        // Fetch one extra row to know whether another page exists
+        let mut rows: Vec<SearchRow> = sqlx::query_as(&sql)
+            .bind(user_id.as_str())
+            .bind(&query.text)
+            .bind(i64::from(query.limit) + 1)
+            .bind(i64::from(query.offset))
+            .fetch_all(&self.pool)
+            .await
+            .map_err(|e| SearchError::Storage(format!("Failed to search: {}", e)))?;
+
+        let has_more = rows.len() > query.limit as usize;
+        rows.truncate(query.limit as usize);
+
+        Ok(SearchResults {
+            hits: rows.into_iter().map(row_to_hit).collect::<Result<_, _>>()?,
+            has_more,
+        })
+    }
+}
+
+fn row_to_hit(row: SearchRow) -> Result<SearchHit, SearchError> {
+    Ok(SearchHit {
+        kind: SearchHitKind::parse(&row.kind).ok_or_else(|| {
+            SearchError::Storage(format!("Unknown search hit kind: {}", row.kind))
+        })?,
+        session_id: SessionId::from_uuid(row.session_id),
+        session_title: row.session_title,
+        cycle_id: row.cycle_id.map(CycleId::from_uuid),
+        component_type: row
+            .component_type
+            .as_deref()
+            .map(str_to_component_type)
+            .transpose()
+            .map_err(|e| SearchError::Storage(e.to_string()))?,
+        snippet: row.snippet,
+        rank: row.rank,
+        updated_at: Timestamp::from_datetime(row.updated_at),
+    })
+}
@@ -0,0 +1,245 @@
+//! PostgreSQL implementation of the session archival port.
+//!
+//! Settings live in `session_archival_settings`. Idle sessions are found by
+//! joining active sessions with their cycles (for last activity) and their
+//! owner's membership (for the tier), filtered by the policy's per-tier
+//! thresholds passed in as arrays.
+
+use async_trait::async_trait;
+use sqlx::{PgPool, Row};
+
+use crate::domain::foundation::{DomainError, ErrorCode, SessionId, Timestamp, UserId};
+use crate::domain::membership::MembershipTier;
+use crate::domain::session::{ArchivalPolicy, SessionArchivalSettings};
+use crate::ports::{ArchivalCandidate, SessionArchivalRepository};
+
+/// Tiers in the order their thresholds are bound.
+const TIERS: [MembershipTier; 3] = [
+    MembershipTier::Free,
+    MembershipTier::Monthly,
+    MembershipTier::Annual,
+];
+
+/// PostgreSQL implementation of SessionArchivalRepository.
+#[derive(Clone)]
+pub struct PostgresSessionArchivalRepository {
+    pool: PgPool,
+}
+
+impl PostgresSessionArchivalRepository {
+    /// Creates a new PostgresSessionArchivalRepository.
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl SessionArchivalRepository for PostgresSessionArchivalRepository {
+    #[tracing::instrument(name = "PostgresSessionArchivalRepository::find_settings", skip_all, fields(db.system = "postgresql"), err)]
+    async fn find_settings(
+        &self,
+        session_id: &SessionId,
+    ) -> Result<Option<SessionArchivalSettings>, DomainError> {
+        let row = sqlx::query(
+            "SELECT session_id, user_id, excluded, notify_email, warned_at, archived_at \
+             FROM session_archival_settings WHERE session_id = $1",
+        )
+        .bind(session_id.as_uuid())
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| {
+            DomainError::new(
+                ErrorCode::DatabaseError,
+                format!("Failed to fetch session archival settings: {}", e),
+            )
+        })?;
+
+        row.map(|row| row_to_settings(&row)).transpose()
+    }
+
+    #[tracing::instrument(name = "PostgresSessionArchivalRepository::save_settings", skip_all, fields(db.system = "postgresql"), err)]
+    async fn save_settings(&self, settings: &SessionArchivalSettings) -> Result<(), DomainError> {
+        sqlx::query(
+            r#"
+            INSERT INTO session_archival_settings (
+                session_id, user_id, excluded, notify_email, warned_at, archived_at, updated_at
+            ) VALUES ($1, $2, $3, $4, $5, $6, NOW())
+            ON CONFLICT (session_id) DO UPDATE SET
+                excluded = EXCLUDED.excluded,
+                notify_email = EXCLUDED.notify_email,
+                warned_at = EXCLUDED.warned_at,
+                archived_at = EXCLUDED.archived_at,
+                updated_at = NOW()
+            "#,
+        )
+        .bind(settings.session_id.as_uuid())
+        .bind(settings.user_id.as_str())
+        .bind(settings.excluded)
+        .bind(settings.notify_email.as_deref())
+        .bind(settings.warned_at.as_ref().map(|t| *t.as_datetime()))
+        .bind(settings.archived_at.as_ref().map(|t| *t.as_datetime()))
+        .execute(&self.pool)
+        .await
+        .map_err(|e| {
+            DomainError::new(
+                ErrorCode::DatabaseError,
+                format!("Failed to save session archival settings: {}", e),
+            )
+        })?;
+
+        Ok(())
+    }
+
+    #[tracing::instrument(name = "PostgresSessionArchivalRepository::list_due", skip_all, fields(db.system = "postgresql"), err)]
+    async fn list_due(
+        &self,
+        policy: &ArchivalPolicy,
+        now: Timestamp,
+        limit: u32,
+    ) -> Result<Vec<ArchivalCandidate>, DomainError> {
+        let (tiers, idle_days): (Vec<&str>, Vec<i32>) = TIERS
+            .iter()
+            .filter_map(|tier| Some((tier_to_string(tier), policy.idle_days(*tier)? as i32)))
+            .unzip();
+        if tiers.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let rows = sqlx::query(
+            r#"
+            WITH thresholds AS (
+                SELECT * FROM UNNEST($1::text[], $2::int[]) AS t(tier, idle_days)
+            ),
+            activity AS (
+                SELECT
+                    s.id AS session_id,
+                    s.user_id,
+                    s.title,
+                    GREATEST(s.updated_at, COALESCE(MAX(c.updated_at), s.updated_at))
+                        AS last_activity,
+                    CASE
+                        WHEN m.status IN ('active', 'past_due') THEN m.tier
+                        WHEN m.status = 'cancelled' AND m.current_period_end >= $3 THEN m.tier
+                        ELSE 'free'
+                    END AS tier
+                FROM sessions s
+                LEFT JOIN cycles c ON c.session_id = s.id
+                LEFT JOIN memberships m ON m.user_id = s.user_id
+                WHERE s.status = 'active'
+                GROUP BY s.id, m.tier, m.status, m.current_period_end
+            )
+            SELECT
+                a.session_id, a.user_id, a.title, a.last_activity, a.tier,
+                COALESCE(st.excluded, FALSE) AS excluded,
+                st.notify_email, st.warned_at, st.archived_at
+            FROM activity a
+            JOIN thresholds t ON t.tier = a.tier
+            LEFT JOIN session_archival_settings st ON st.session_id = a.session_id
+            WHERE COALESCE(st.excluded, FALSE) = FALSE
+              AND st.archived_at IS NULL
+              AND (
+                  ((st.warned_at IS NULL OR st.warned_at < a.last_activity)
+                      AND a.last_activity
+                          <= $3 - make_interval(days => GREATEST(t.idle_days - $4, 0)))
+                  OR (st.warned_at >= a.last_activity
+                      AND a.last_activity <= $3 - make_interval(days => t.idle_days)
+                      AND st.warned_at <= $3 - make_interval(days => $4))
+              )
+            ORDER BY a.last_activity ASC
+            LIMIT $5
+            "#,
+        )
+        .bind(&tiers)
+        .bind(&idle_days)
+        .bind(now.as_datetime())
+        .bind(policy.warning_days() as i32)
+        .bind(limit as i64)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| {
+            DomainError::new(
+                ErrorCode::DatabaseError,
+                format!("Failed to fetch idle sessions: {}", e),
+            )
+        })?;
+
+        rows.iter().map(row_to_candidate).collect()
+    }
+}
+
+fn db_error(field: &str, e: sqlx::Error) -> DomainError {
+    DomainError::new(
+        ErrorCode::DatabaseError,
+        format!("Failed to get {}: {}", field, e),
+    )
+}
+
+fn tier_to_string(tier: &MembershipTier) -> &'static str {
+    match tier {
+        MembershipTier::Free => "free",
+        MembershipTier::Monthly => "monthly",
+        MembershipTier::Annual => "annual",
+    }
+}
+
+fn parse_tier(s: &str) -> Result<MembershipTier, DomainError> {
+    TIERS
+        .into_iter()
+        .find(|tier| tier_to_string(tier) == s)
+        .ok_or_else(|| {
+            DomainError::new(
+                ErrorCode::DatabaseError,
+                format!("Invalid tier value: {}", s),
+            )
+        })
+}
+
+fn row_to_settings(row: &sqlx::postgres::PgRow) -> Result<SessionArchivalSettings, DomainError> {
+    let session_id: uuid::Uuid = row
+        .try_get("session_id")
+        .map_err(|e| db_error("session_id", e))?;
+    let user: String = row.try_get("user_id").map_err(|e| db_error("user_id", e))?;
+    let excluded: bool = row
+        .try_get("excluded")
+        .map_err(|e| db_error("excluded", e))?;
+    let notify_email: Option<String> = row
+        .try_get("notify_email")
+        .map_err(|e| db_error("notify_email", e))?;
+    let warned_at: Option<chrono::DateTime<chrono::Utc>> = row
+        .try_get("warned_at")
+        .map_err(|e| db_error("warned_at", e))?;
+    let archived_at: Option<chrono::DateTime<chrono::Utc>> = row
+        .try_get("archived_at")
+        .map_err(|e| db_error("archived_at", e))?;
+
+    let user_id = UserId::new(user).map_err(|e| {
+        DomainError::new(ErrorCode::DatabaseError, format!("Invalid user_id: {}", e))
+    })?;
+
+    Ok(SessionArchivalSettings {
+        session_id: SessionId::from_uuid(session_id),
+        user_id,
+        excluded,
+        notify_email,
+        warned_at: warned_at.map(Timestamp::from_datetime),
+        archived_at: archived_at.map(Timestamp::from_datetime),
+    })
+}
+
+fn row_to_candidate(row: &sqlx::postgres::PgRow) -> Result<ArchivalCandidate, DomainError> {
+    let settings = row_to_settings(row)?;
+    let title: String = row.try_get("title").map_err(|e| db_error("title", e))?;
+    let tier: String = row.try_get("tier").map_err(|e| db_error("tier", e))?;
+    let last_activity: chrono::DateTime<chrono::Utc> = row
+        .try_get("last_activity")
+        .map_err(|e| db_error("last_activity", e))?;
+
+    Ok(ArchivalCandidate {
+        session_id: settings.session_id,
+        user_id: settings.user_id.clone(),
+        title,
+        tier: parse_tier(&tier)?,
+        last_activity: Timestamp::from_datetime(last_activity),
+        settings,
+    })
+}
@@ -0,0 +1,205 @@
+//! PostgreSQL implementation of the session cold storage port.
+//!
+//! Records live in `session_cold_storage`. Candidates are archived sessions
+//! that still have cycles in the database and none of them active; a session
+//! counts as archived from the auto-archive timestamp when there is one,
+//! otherwise from its last update.
+
+use async_trait::async_trait;
+use sqlx::{PgPool, Row};
+
+use crate::domain::foundation::{CycleId, DomainError, ErrorCode, SessionId, Timestamp, UserId};
+use crate::domain::session::ColdStorageRecord;
+use crate::ports::{ColdStorageCandidate, SessionColdStorageRepository};
+
+/// PostgreSQL implementation of SessionColdStorageRepository.
+#[derive(Clone)]
+pub struct PostgresSessionColdStorageRepository {
+    pool: PgPool,
+}
+
+impl PostgresSessionColdStorageRepository {
+    /// Creates a new PostgresSessionColdStorageRepository.
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl SessionColdStorageRepository for PostgresSessionColdStorageRepository {
+    #[tracing::instrument(name = "PostgresSessionColdStorageRepository::save", skip_all, fields(db.system = "postgresql"), err)]
+    async fn save(&self, record: &ColdStorageRecord) -> Result<(), DomainError> {
+        let cycle_ids: Vec<uuid::Uuid> = record.cycle_ids.iter().map(|id| *id.as_uuid()).collect();
+        sqlx::query(
+            r#"
+            INSERT INTO session_cold_storage (session_id, user_id, blob_key, cycle_ids, offloaded_at)
+            VALUES ($1, $2, $3, $4, $5)
+            ON CONFLICT (session_id) DO UPDATE SET
+                blob_key = EXCLUDED.blob_key,
+                cycle_ids = EXCLUDED.cycle_ids,
+                offloaded_at = EXCLUDED.offloaded_at
+            "#,
+        )
+        .bind(record.session_id.as_uuid())
+        .bind(record.user_id.as_str())
+        .bind(&record.blob_key)
+        .bind(&cycle_ids)
+        .bind(record.offloaded_at.as_datetime())
+        .execute(&self.pool)
+        .await
+        .map_err(|e| {
+            DomainError::new(
+                ErrorCode::DatabaseError,
+                format!("Failed to save cold storage record: {}", e),
+            )
+        })?;
+
+        Ok(())
+    }
+
+    #[tracing::instrument(name = "PostgresSessionColdStorageRepository::find", skip_all, fields(db.system = "postgresql"), err)]
+    async fn find(&self, session_id: &SessionId) -> Result<Option<ColdStorageRecord>, DomainError> {
+        let row = sqlx::query(
+            "SELECT session_id, user_id, blob_key, cycle_ids, offloaded_at \
+             FROM session_cold_storage WHERE session_id = $1",
+        )
+        .bind(session_id.as_uuid())
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| {
+            DomainError::new(
+                ErrorCode::DatabaseError,
+                format!("Failed to fetch cold storage record: {}", e),
+            )
+        })?;
+
+        row.map(|row| row_to_record(&row)).transpose()
+    }
+
+    #[tracing::instrument(name = "PostgresSessionColdStorageRepository::find_by_cycle", skip_all, fields(db.system = "postgresql"), err)]
+    async fn find_by_cycle(
+        &self,
+        cycle_id: &CycleId,
+    ) -> Result<Option<ColdStorageRecord>, DomainError> {
+        let row = sqlx::query(
+            "SELECT session_id, user_id, blob_key, cycle_ids, offloaded_at \
+             FROM session_cold_storage WHERE cycle_ids @> ARRAY[$1::uuid]",
+        )
+        .bind(cycle_id.as_uuid())
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| {
+            DomainError::new(
+                ErrorCode::DatabaseError,
+                format!("Failed to fetch cold storage record by cycle: {}", e),
+            )
+        })?;
+
+        row.map(|row| row_to_record(&row)).transpose()
+    }
+
+    #[tracing::instrument(name = "PostgresSessionColdStorageRepository::delete", skip_all, fields(db.system = "postgresql"), err)]
+    async fn delete(&self, session_id: &SessionId) -> Result<(), DomainError> {
+        sqlx::query("DELETE FROM session_cold_storage WHERE session_id = $1")
+            .bind(session_id.as_uuid())
+            .execute(&self.pool)
+            .await
+            .map_err(|e| {
+                DomainError::new(
+                    ErrorCode::DatabaseError,
+                    format!("Failed to delete cold storage record: {}", e),
+                )
+            })?;
+
+        Ok(())
+    }
+
+    #[tracing::instrument(name = "PostgresSessionColdStorageRepository::list_candidates", skip_all, fields(db.system = "postgresql"), err)]
+    async fn list_candidates(
+        &self,
+        archived_before: Timestamp,
+        limit: u32,
+    ) -> Result<Vec<ColdStorageCandidate>, DomainError> {
+        let rows = sqlx::query(
+            r#"
+            SELECT s.id AS session_id, s.user_id,
+                   COALESCE(st.archived_at, s.updated_at) AS archived_at
+            FROM sessions s
+            LEFT JOIN session_archival_settings st ON st.session_id = s.id
+            WHERE s.status = 'archived'
+              AND COALESCE(st.archived_at, s.updated_at) < $1
+              AND NOT EXISTS (SELECT 1 FROM session_cold_storage cs WHERE cs.session_id = s.id)
+              AND EXISTS (SELECT 1 FROM cycles c WHERE c.session_id = s.id)
+              AND NOT EXISTS (
+                  SELECT 1 FROM cycles c WHERE c.session_id = s.id AND c.status = 'active'
+              )
+            ORDER BY archived_at ASC
+            LIMIT $2
+            "#,
+        )
+        .bind(archived_before.as_datetime())
+        .bind(limit as i64)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| {
+            DomainError::new(
+                ErrorCode::DatabaseError,
+                format!("Failed to fetch cold storage candidates: {}", e),
+            )
+        })?;
+
+        rows.iter().map(row_to_candidate).collect()
+    }
+}
+
+fn db_error(field: &str, e: sqlx::Error) -> DomainError {
+    DomainError::new(
+        ErrorCode::DatabaseError,
+        format!("Failed to get {}: {}", field, e),
+    )
+}
+
+fn parse_user_id(user: String) -> Result<UserId, DomainError> {
+    UserId::new(user)
+        .map_err(|e| DomainError::new(ErrorCode::DatabaseError, format!("Invalid user_id: {}", e)))
+}
+
+fn row_to_record(row: &sqlx::postgres::PgRow) -> Result<ColdStorageRecord, DomainError> {
+    let session_id: uuid::Uuid = row
+        .try_get("session_id")
+        .map_err(|e| db_error("session_id", e))?;
+    let user: String = row.try_get("user_id").map_err(|e| db_error("user_id", e))?;
+    let blob_key: String = row
+        .try_get("blob_key")
+        .map_err(|e| db_error("blob_key", e))?;
+    let cycle_ids: Vec<uuid::Uuid> = row
+        .try_get("cycle_ids")
+        .map_err(|e| db_error("cycle_ids", e))?;
+    let offloaded_at: chrono::DateTime<chrono::Utc> = row
+        .try_get("offloaded_at")
+        .map_err(|e| db_error("offloaded_at", e))?;
+
+    Ok(ColdStorageRecord {
+        session_id: SessionId::from_uuid(session_id),
+        user_id: parse_user_id(user)?,
+        blob_key,
+        cycle_ids: cycle_ids.into_iter().map(CycleId::from_uuid).collect(),
+        offloaded_at: Timestamp::from_datetime(offloaded_at),
+    })
+}
+
+fn row_to_candidate(row: &sqlx::postgres::PgRow) -> Result<ColdStorageCandidate, DomainError> {
+    let session_id: uuid::Uuid = row
+        .try_get("session_id")
+        .map_err(|e| db_error("session_id", e))?;
+    let user: String = row.try_get("user_id").map_err(|e| db_error("user_id", e))?;
+    let archived_at: chrono::DateTime<chrono::Utc> = row
+        .try_get("archived_at")
+        .map_err(|e| db_error("archived_at", e))?;
+
+    Ok(ColdStorageCandidate {
+        session_id: SessionId::from_uuid(session_id),
+        user_id: parse_user_id(user)?,
+        archived_at: Timestamp::from_datetime(archived_at),
+    })
+}
@@ -1,14 +1,18 @@
 //! PostgreSQL implementation of SessionReader.
 //!
-//! Provides read-optimized queries for session data.
+//! Provides read-optimized queries for session data. Deleted sessions
+//! only show up in trash listings and when asked for by status.
 
 use async_trait::async_trait;
 use sqlx::{PgPool, Row};
 
 use crate::domain::foundation::{
-    DomainError, ErrorCode, SessionId, SessionStatus, Timestamp, UserId,
+    DomainError, ErrorCode, OrganizationId, SessionId, SessionStatus, Timestamp, UserId,
+};
+use crate::domain::session::TRASH_RETENTION_DAYS;
+use crate::ports::{
+    ListOptions, SessionList, SessionReader, SessionSummary, SessionView, TrashedSession,
 };
-use crate::ports::{ListOptions, SessionList, SessionReader, SessionSummary, SessionView};
 
 /// PostgreSQL implementation of SessionReader.
 #[derive(Clone)]
@@ -25,10 +29,11 @@ impl PostgresSessionReader {
 
 #[async_trait]
 impl SessionReader for PostgresSessionReader {
+    #[tracing::instrument(name = "PostgresSessionReader::get_by_id", skip_all, fields(db.system = "postgresql"), err)]
     async fn get_by_id(&self, id: &SessionId) -> Result<Option<SessionView>, DomainError> {
         let row = sqlx::query(
             r#"
-            SELECT s.id, s.user_id, s.title, s.description, s.status,
+            SELECT s.id, s.user_id, s.organization_id, s.title, s.description, s.status,
                    s.created_at, s.updated_at,
                    COUNT(c.id) as cycle_count
             FROM sessions s
@@ -56,6 +61,7 @@ impl SessionReader for PostgresSessionReader {
         }
     }
 
+    #[tracing::instrument(name = "PostgresSessionReader::list_by_user", skip_all, fields(db.system = "postgresql"), err)]
     async fn list_by_user(
         &self,
         user_id: &UserId,
@@ -65,19 +71,18 @@ impl SessionReader for PostgresSessionReader {
         let mut query = String::from(
             r#"
             SELECT s.id, s.title, s.status, s.updated_at,
-                   COUNT(c.id) as cycle_count
+                   GREATEST(COUNT(c.id), COALESCE(MAX(cardinality(cs.cycle_ids)), 0))
+                       as cycle_count,
+                   BOOL_OR(cs.session_id IS NOT NULL) as in_cold_storage
             FROM sessions s
             LEFT JOIN cycles c ON c.session_id = s.id
+            LEFT JOIN session_cold_storage cs ON cs.session_id = s.id
             WHERE s.user_id = $1
             "#,
         );
 
-        // Add status filter if specified
-        if let Some(status) = options.status {
-            query.push_str(&format!(" AND s.status = '{}'", session_status_to_str(status)));
-        } else if !options.include_archived {
-            query.push_str(" AND s.status = 'active'");
-        }
+        // Add status and organization filters
+        push_list_filters(&mut query, "s.", options);
 
         // Group by and order
         query.push_str(
@@ -122,6 +127,82 @@ impl SessionReader for PostgresSessionReader {
         })
     }
 
+    #[tracing::instrument(name = "PostgresSessionReader::list_by_organization", skip_all, fields(db.system = "postgresql"), err)]
+    async fn list_by_organization(
+        &self,
+        organization_id: &OrganizationId,
+        options: &ListOptions,
+    ) -> Result<SessionList, DomainError> {
+        let mut query = String::from(
+            r#"
+            SELECT s.id, s.title, s.status, s.updated_at,
+                   GREATEST(COUNT(c.id), COALESCE(MAX(cardinality(cs.cycle_ids)), 0))
+                       as cycle_count,
+                   BOOL_OR(cs.session_id IS NOT NULL) as in_cold_storage
+            FROM sessions s
+            LEFT JOIN cycles c ON c.session_id = s.id
+            LEFT JOIN session_cold_storage cs ON cs.session_id = s.id
+            WHERE s.organization_id = $1
+            "#,
+        );
+        // The organization is already fixed by $1
+        let filters = ListOptions {
+            organization_id: None,
+            ..options.clone()
+        };
+        push_list_filters(&mut query, "s.", &filters);
+
+        query.push_str(
+            " GROUP BY s.id, s.title, s.status, s.updated_at ORDER BY s.updated_at DESC",
+        );
+        if let Some(limit) = options.limit {
+            query.push_str(&format!(" LIMIT {}", limit));
+        }
+        if let Some(offset) = options.offset {
+            query.push_str(&format!(" OFFSET {}", offset));
+        }
+
+        let rows = sqlx::query(&query)
+            .bind(organization_id.as_uuid())
+            .fetch_all(&self.pool)
+            .await
+            .map_err(|e| {
+                DomainError::new(
+                    ErrorCode::DatabaseError,
+                    format!("Failed to list organization sessions: {}", e),
+                )
+            })?;
+
+        let items: Result<Vec<SessionSummary>, DomainError> =
+            rows.into_iter().map(row_to_session_summary).collect();
+        let items = items?;
+
+        let mut count_query =
+            String::from("SELECT COUNT(*) FROM sessions WHERE organization_id = $1");
+        push_list_filters(&mut count_query, "", &filters);
+        let total: (i64,) = sqlx::query_as(&count_query)
+            .bind(organization_id.as_uuid())
+            .fetch_one(&self.pool)
+            .await
+            .map_err(|e| {
+                DomainError::new(
+                    ErrorCode::DatabaseError,
+                    format!("Failed to count organization sessions: {}", e),
+                )
+            })?;
+        let total = total.0 as u64;
+
+        let offset = options.offset.unwrap_or(0) as u64;
+        let has_more = offset + (items.len() as u64) < total;
+
+        Ok(SessionList {
+            items,
+            total,
+            has_more,
+        })
+    }
+
+    #[tracing::instrument(name = "PostgresSessionReader::search", skip_all, fields(db.system = "postgresql"), err)]
     async fn search(
         &self,
         user_id: &UserId,
@@ -132,21 +213,20 @@ impl SessionReader for PostgresSessionReader {
         let mut sql = String::from(
             r#"
             SELECT s.id, s.title, s.status, s.updated_at,
-                   COUNT(c.id) as cycle_count
+                   GREATEST(COUNT(c.id), COALESCE(MAX(cardinality(cs.cycle_ids)), 0))
+                       as cycle_count,
+                   BOOL_OR(cs.session_id IS NOT NULL) as in_cold_storage
             FROM sessions s
             LEFT JOIN cycles c ON c.session_id = s.id
+            LEFT JOIN session_cold_storage cs ON cs.session_id = s.id
             WHERE s.user_id = $1
               AND to_tsvector('english', COALESCE(s.title, '') || ' ' || COALESCE(s.description, ''))
                   @@ plainto_tsquery('english', $2)
             "#,
         );
 
-        // Add status filter
-        if let Some(status) = options.status {
-            sql.push_str(&format!(" AND s.status = '{}'", session_status_to_str(status)));
-        } else if !options.include_archived {
-            sql.push_str(" AND s.status = 'active'");
-        }
+        // Add status and organization filters
+        push_list_filters(&mut sql, "s.", options);
 
         // Group by and order
         sql.push_str(
@@ -189,6 +269,7 @@ impl SessionReader for PostgresSessionReader {
         })
     }
 
+    #[tracing::instrument(name = "PostgresSessionReader::count_by_status", skip_all, fields(db.system = "postgresql"), err)]
     async fn count_by_status(
         &self,
         user_id: &UserId,
@@ -210,6 +291,51 @@ impl SessionReader for PostgresSessionReader {
 
         Ok(result.0 as u64)
     }
+
+    #[tracing::instrument(name = "PostgresSessionReader::list_trashed", skip_all, fields(db.system = "postgresql"), err)]
+    async fn list_trashed(&self, user_id: &UserId) -> Result<Vec<TrashedSession>, DomainError> {
+        let rows = sqlx::query(&format!(
+            "{} WHERE s.user_id = $1 AND s.status = 'deleted' \
+             GROUP BY s.id ORDER BY s.deleted_at DESC",
+            SELECT_TRASHED
+        ))
+        .bind(user_id.as_str())
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| {
+            DomainError::new(
+                ErrorCode::DatabaseError,
+                format!("Failed to list trashed sessions: {}", e),
+            )
+        })?;
+
+        rows.into_iter().map(row_to_trashed_session).collect()
+    }
+
+    #[tracing::instrument(name = "PostgresSessionReader::list_purge_candidates", skip_all, fields(db.system = "postgresql"), err)]
+    async fn list_purge_candidates(
+        &self,
+        deleted_before: Timestamp,
+        limit: u32,
+    ) -> Result<Vec<TrashedSession>, DomainError> {
+        let rows = sqlx::query(&format!(
+            "{} WHERE s.status = 'deleted' AND s.deleted_at < $1 \
+             GROUP BY s.id ORDER BY s.deleted_at ASC LIMIT $2",
+            SELECT_TRASHED
+        ))
+        .bind(deleted_before.as_datetime())
+        .bind(limit as i64)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| {
+            DomainError::new(
+                ErrorCode::DatabaseError,
+                format!("Failed to list purge candidates: {}", e),
+            )
+        })?;
+
+        rows.into_iter().map(row_to_trashed_session).collect()
+    }
 }
 
 impl PostgresSessionReader {
@@ -220,12 +346,7 @@ impl PostgresSessionReader {
         options: &ListOptions,
     ) -> Result<u64, DomainError> {
         let mut query = String::from("SELECT COUNT(*) FROM sessions WHERE user_id = $1");
-
-        if let Some(status) = options.status {
-            query.push_str(&format!(" AND status = '{}'", session_status_to_str(status)));
-        } else if !options.include_archived {
-            query.push_str(" AND status = 'active'");
-        }
+        push_list_filters(&mut query, "", options);
 
         let result: (i64,) = sqlx::query_as(&query)
             .bind(user_id.as_str())
@@ -246,10 +367,21 @@ impl PostgresSessionReader {
 // Helper functions
 // ════════════════════════════════════════════════════════════════════════════
 
+/// Trashed session columns; callers add the filter, grouping and order.
+const SELECT_TRASHED: &str = r#"
+    SELECT s.id, s.user_id, s.title, s.status_before_deletion, s.deleted_at,
+           GREATEST(COUNT(c.id), COALESCE(MAX(cardinality(cs.cycle_ids)), 0))
+               as cycle_count
+    FROM sessions s
+    LEFT JOIN cycles c ON c.session_id = s.id
+    LEFT JOIN session_cold_storage cs ON cs.session_id = s.id
+"#;
+
 fn session_status_to_str(status: SessionStatus) -> &'static str {
     match status {
         SessionStatus::Active => "active",
         SessionStatus::Archived => "archived",
+        SessionStatus::Deleted => "deleted",
     }
 }
 
@@ -257,6 +389,7 @@ fn str_to_session_status(s: &str) -> Result<SessionStatus, DomainError> {
     match s {
         "active" => Ok(SessionStatus::Active),
         "archived" => Ok(SessionStatus::Archived),
+        "deleted" => Ok(SessionStatus::Deleted),
         _ => Err(DomainError::new(
             ErrorCode::DatabaseError,
             format!("Invalid session status: {}", s),
@@ -264,6 +397,30 @@ fn str_to_session_status(s: &str) -> Result<SessionStatus, DomainError> {
     }
 }
 
+/// Appends the status and organization filters of `options`, with
+/// `prefix` qualifying the column names (e.g. `"s."`).
+fn push_list_filters(sql: &mut String, prefix: &str, options: &ListOptions) {
+    if let Some(status) = options.status {
+        sql.push_str(&format!(
+            " AND {}status = '{}'",
+            prefix,
+            session_status_to_str(status)
+        ));
+    } else if !options.include_archived {
+        sql.push_str(&format!(" AND {}status = 'active'", prefix));
+    } else {
+        sql.push_str(&format!(" AND {}status != 'deleted'", prefix));
+    }
+
+    if let Some(organization_id) = options.organization_id {
+        // A formatted UUID cannot carry SQL, so it is safe to inline
+        sql.push_str(&format!(
+            " AND {}organization_id = '{}'",
+            prefix, organization_id
+        ));
+    }
+}
+
 fn row_to_session_view(row: sqlx::postgres::PgRow) -> Result<SessionView, DomainError> {
     let id: uuid::Uuid = row.try_get("id").map_err(|e| {
         DomainError::new(
@@ -293,6 +450,13 @@ fn row_to_session_view(row: sqlx::postgres::PgRow) -> Result<SessionView, Domain
         )
     })?;
 
+    let organization_id: Option<uuid::Uuid> = row.try_get("organization_id").map_err(|e| {
+        DomainError::new(
+            ErrorCode::DatabaseError,
+            format!("Failed to get organization_id: {}", e),
+        )
+    })?;
+
     let status_str: String = row.try_get("status").map_err(|e| {
         DomainError::new(
             ErrorCode::DatabaseError,
@@ -330,6 +494,7 @@ fn row_to_session_view(row: sqlx::postgres::PgRow) -> Result<SessionView, Domain
                 format!("Invalid user_id: {}", e),
             )
         })?,
+        organization_id: organization_id.map(OrganizationId::from_uuid),
         title,
         description,
         status,
@@ -376,12 +541,80 @@ fn row_to_session_summary(row: sqlx::postgres::PgRow) -> Result<SessionSummary,
         )
     })?;
 
+    let in_cold_storage: bool = row.try_get("in_cold_storage").map_err(|e| {
+        DomainError::new(
+            ErrorCode::DatabaseError,
+            format!("Failed to get in_cold_storage: {}", e),
+        )
+    })?;
+
     Ok(SessionSummary {
         id: SessionId::from_uuid(id),
         title,
         status,
         cycle_count: cycle_count as u32,
         updated_at: Timestamp::from_datetime(updated_at),
+        in_cold_storage,
+    })
+}
+
+fn row_to_trashed_session(row: sqlx::postgres::PgRow) -> Result<TrashedSession, DomainError> {
+    let id: uuid::Uuid = row.try_get("id").map_err(|e| {
+        DomainError::new(
+            ErrorCode::DatabaseError,
+            format!("Failed to get id: {}", e),
+        )
+    })?;
+
+    let user_id: String = row.try_get("user_id").map_err(|e| {
+        DomainError::new(
+            ErrorCode::DatabaseError,
+            format!("Failed to get user_id: {}", e),
+        )
+    })?;
+
+    let title: String = row.try_get("title").map_err(|e| {
+        DomainError::new(
+            ErrorCode::DatabaseError,
+            format!("Failed to get title: {}", e),
+        )
+    })?;
+
+    let status_str: String = row.try_get("status_before_deletion").map_err(|e| {
+        DomainError::new(
+            ErrorCode::DatabaseError,
+            format!("Failed to get status_before_deletion: {}", e),
+        )
+    })?;
+
+    let cycle_count: i64 = row.try_get("cycle_count").map_err(|e| {
+        DomainError::new(
+            ErrorCode::DatabaseError,
+            format!("Failed to get cycle_count: {}", e),
+        )
+    })?;
+
+    let deleted_at: chrono::DateTime<chrono::Utc> = row.try_get("deleted_at").map_err(|e| {
+        DomainError::new(
+            ErrorCode::DatabaseError,
+            format!("Failed to get deleted_at: {}", e),
+        )
+    })?;
+    let deleted_at = Timestamp::from_datetime(deleted_at);
+
+    Ok(TrashedSession {
+        id: SessionId::from_uuid(id),
+        user_id: UserId::new(user_id).map_err(|e| {
+            DomainError::new(
+                ErrorCode::DatabaseError,
+                format!("Invalid user_id: {}", e),
+            )
+        })?,
+        title,
+        status_before_deletion: str_to_session_status(&status_str)?,
+        cycle_count: cycle_count as u32,
+        deleted_at,
+        purge_after: deleted_at.add_days(TRASH_RETENTION_DAYS),
     })
 }
 
@@ -408,4 +641,31 @@ mod tests {
     fn str_to_session_status_rejects_invalid() {
         assert!(str_to_session_status("invalid").is_err());
     }
+
+    #[test]
+    fn list_filters_including_archived_leave_out_trash() {
+        let options = ListOptions::default().with_archived();
+
+        let mut sql = String::new();
+        push_list_filters(&mut sql, "", &options);
+
+        assert_eq!(sql, " AND status != 'deleted'");
+    }
+
+    #[test]
+    fn list_filters_restrict_to_organization() {
+        let organization_id = OrganizationId::new();
+        let options = ListOptions::default().with_organization(organization_id);
+
+        let mut sql = String::new();
+        push_list_filters(&mut sql, "s.", &options);
+
+        assert_eq!(
+            sql,
+            format!(
+                " AND s.status = 'active' AND s.organization_id = '{}'",
+                organization_id
+            )
+        );
+    }
 }
@@ -6,7 +6,7 @@ use async_trait::async_trait;
 use sqlx::{PgPool, Row};
 
 use crate::domain::foundation::{
-    CycleId, DomainError, ErrorCode, SessionId, SessionStatus, Timestamp, UserId,
+    CycleId, DomainError, ErrorCode, OrganizationId, SessionId, SessionStatus, Timestamp, UserId,
 };
 use crate::domain::session::Session;
 use crate::ports::SessionRepository;
@@ -26,21 +26,26 @@ impl PostgresSessionRepository {
 
 #[async_trait]
 impl SessionRepository for PostgresSessionRepository {
+    #[tracing::instrument(name = "PostgresSessionRepository::save", skip_all, fields(db.system = "postgresql"), err)]
     async fn save(&self, session: &Session) -> Result<(), DomainError> {
         sqlx::query(
             r#"
             INSERT INTO sessions (
-                id, user_id, title, description, status, created_at, updated_at
-            ) VALUES ($1, $2, $3, $4, $5, $6, $7)
+                id, user_id, organization_id, title, description, status, created_at, updated_at,
+                deleted_at, status_before_deletion
+            ) VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10)
             "#,
         )
         .bind(session.id().as_uuid())
         .bind(session.user_id().as_str())
+        .bind(session.organization_id().map(|id| *id.as_uuid()))
         .bind(session.title())
         .bind(session.description())
         .bind(session_status_to_str(session.status()))
         .bind(session.created_at().as_datetime())
         .bind(session.updated_at().as_datetime())
+        .bind(session.deleted_at().map(|at| *at.as_datetime()))
+        .bind(session.status_before_deletion().map(session_status_to_str))
         .execute(&self.pool)
         .await
         .map_err(|e| {
@@ -53,6 +58,7 @@ impl SessionRepository for PostgresSessionRepository {
         Ok(())
     }
 
+    #[tracing::instrument(name = "PostgresSessionRepository::update", skip_all, fields(db.system = "postgresql"), err)]
     async fn update(&self, session: &Session) -> Result<(), DomainError> {
         let result = sqlx::query(
             r#"
@@ -60,7 +66,10 @@ impl SessionRepository for PostgresSessionRepository {
                 title = $2,
                 description = $3,
                 status = $4,
-                updated_at = $5
+                updated_at = $5,
+                organization_id = $6,
+                deleted_at = $7,
+                status_before_deletion = $8
             WHERE id = $1
             "#,
         )
@@ -69,6 +78,9 @@ impl SessionRepository for PostgresSessionRepository {
         .bind(session.description())
         .bind(session_status_to_str(session.status()))
         .bind(session.updated_at().as_datetime())
+        .bind(session.organization_id().map(|id| *id.as_uuid()))
+        .bind(session.deleted_at().map(|at| *at.as_datetime()))
+        .bind(session.status_before_deletion().map(session_status_to_str))
         .execute(&self.pool)
         .await
         .map_err(|e| {
@@ -88,11 +100,12 @@ impl SessionRepository for PostgresSessionRepository {
         Ok(())
     }
 
+    #[tracing::instrument(name = "PostgresSessionRepository::find_by_id", skip_all, fields(db.system = "postgresql"), err)]
     async fn find_by_id(&self, id: &SessionId) -> Result<Option<Session>, DomainError> {
         let row = sqlx::query(
             r#"
-            SELECT s.id, s.user_id, s.title, s.description, s.status,
-                   s.created_at, s.updated_at,
+            SELECT s.id, s.user_id, s.organization_id, s.title, s.description, s.status,
+                   s.created_at, s.updated_at, s.deleted_at, s.status_before_deletion,
                    COALESCE(array_agg(c.id) FILTER (WHERE c.id IS NOT NULL), '{}') as cycle_ids
             FROM sessions s
             LEFT JOIN cycles c ON c.session_id = s.id
@@ -119,6 +132,7 @@ impl SessionRepository for PostgresSessionRepository {
         }
     }
 
+    #[tracing::instrument(name = "PostgresSessionRepository::exists", skip_all, fields(db.system = "postgresql"), err)]
     async fn exists(&self, id: &SessionId) -> Result<bool, DomainError> {
         let result: (i64,) = sqlx::query_as("SELECT COUNT(*) FROM sessions WHERE id = $1")
             .bind(id.as_uuid())
@@ -134,11 +148,12 @@ impl SessionRepository for PostgresSessionRepository {
         Ok(result.0 > 0)
     }
 
+    #[tracing::instrument(name = "PostgresSessionRepository::find_by_user_id", skip_all, fields(db.system = "postgresql"), err)]
     async fn find_by_user_id(&self, user_id: &UserId) -> Result<Vec<Session>, DomainError> {
         let rows = sqlx::query(
             r#"
-            SELECT s.id, s.user_id, s.title, s.description, s.status,
-                   s.created_at, s.updated_at,
+            SELECT s.id, s.user_id, s.organization_id, s.title, s.description, s.status,
+                   s.created_at, s.updated_at, s.deleted_at, s.status_before_deletion,
                    COALESCE(array_agg(c.id) FILTER (WHERE c.id IS NOT NULL), '{}') as cycle_ids
             FROM sessions s
             LEFT JOIN cycles c ON c.session_id = s.id
@@ -163,6 +178,7 @@ impl SessionRepository for PostgresSessionRepository {
         sessions
     }
 
+    #[tracing::instrument(name = "PostgresSessionRepository::count_active_by_user", skip_all, fields(db.system = "postgresql"), err)]
     async fn count_active_by_user(&self, user_id: &UserId) -> Result<u32, DomainError> {
         let result: (i64,) = sqlx::query_as(
             "SELECT COUNT(*) FROM sessions WHERE user_id = $1 AND status = 'active'",
@@ -180,6 +196,7 @@ impl SessionRepository for PostgresSessionRepository {
         Ok(result.0 as u32)
     }
 
+    #[tracing::instrument(name = "PostgresSessionRepository::delete", skip_all, fields(db.system = "postgresql"), err)]
     async fn delete(&self, id: &SessionId) -> Result<(), DomainError> {
         let result = sqlx::query("DELETE FROM sessions WHERE id = $1")
             .bind(id.as_uuid())
@@ -211,6 +228,7 @@ fn session_status_to_str(status: SessionStatus) -> &'static str {
     match status {
         SessionStatus::Active => "active",
         SessionStatus::Archived => "archived",
+        SessionStatus::Deleted => "deleted",
     }
 }
 
@@ -218,6 +236,7 @@ fn str_to_session_status(s: &str) -> Result<SessionStatus, DomainError> {
     match s {
         "active" => Ok(SessionStatus::Active),
         "archived" => Ok(SessionStatus::Archived),
+        "deleted" => Ok(SessionStatus::Deleted),
         _ => Err(DomainError::new(
             ErrorCode::DatabaseError,
             format!("Invalid session status: {}", s),
@@ -283,9 +302,32 @@ fn row_to_session(row: sqlx::postgres::PgRow) -> Result<Session, DomainError> {
         )
     })?;
 
+    let organization_id: Option<uuid::Uuid> = row.try_get("organization_id").map_err(|e| {
+        DomainError::new(
+            ErrorCode::DatabaseError,
+            format!("Failed to get organization_id: {}", e),
+        )
+    })?;
+
+    let deleted_at: Option<chrono::DateTime<chrono::Utc>> =
+        row.try_get("deleted_at").map_err(|e| {
+            DomainError::new(
+                ErrorCode::DatabaseError,
+                format!("Failed to get deleted_at: {}", e),
+            )
+        })?;
+
+    let status_before_deletion: Option<String> =
+        row.try_get("status_before_deletion").map_err(|e| {
+            DomainError::new(
+                ErrorCode::DatabaseError,
+                format!("Failed to get status_before_deletion: {}", e),
+            )
+        })?;
+
     let cycle_ids: Vec<CycleId> = cycle_uuids.into_iter().map(CycleId::from_uuid).collect();
 
-    Ok(Session::reconstitute(
+    let session = Session::reconstitute(
         SessionId::from_uuid(id),
         UserId::new(user_id).map_err(|e| {
             DomainError::new(
@@ -299,7 +341,16 @@ fn row_to_session(row: sqlx::postgres::PgRow) -> Result<Session, DomainError> {
         cycle_ids,
         Timestamp::from_datetime(created_at),
         Timestamp::from_datetime(updated_at),
-    ))
+    )
+    .with_organization(organization_id.map(OrganizationId::from_uuid));
+
+    match (deleted_at, status_before_deletion) {
+        (Some(deleted_at), Some(status_before_deletion)) => Ok(session.with_deletion(
+            Timestamp::from_datetime(deleted_at),
+            str_to_session_status(&status_before_deletion)?,
+        )),
+        _ => Ok(session),
+    }
 }
 
 #[cfg(test)]
@@ -319,6 +370,12 @@ mod tests {
             str_to_session_status(session_status_to_str(archived)).unwrap(),
             archived
         );
+
+        let deleted = SessionStatus::Deleted;
+        assert_eq!(
+            str_to_session_status(session_status_to_str(deleted)).unwrap(),
+            deleted
+        );
     }
 
     #[test]
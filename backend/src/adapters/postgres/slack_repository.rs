@@ -0,0 +1,322 @@
+//! PostgreSQL implementations of the Slack ports.
+//!
+//! Connected workspaces live in `slack_workspaces`, one row per user and
+//! team; shares live in `slack_shares`, where pending rows form the retry
+//! queue.
+
+use async_trait::async_trait;
+use secrecy::{ExposeSecret, Secret};
+use sqlx::{PgPool, Row};
+
+use crate::domain::document::{DeliveryStatus, RecommendationDigest, SlackShare};
+use crate::domain::foundation::{CycleId, DomainError, ErrorCode, SlackShareId, Timestamp, UserId};
+use crate::ports::{SlackShareRepository, SlackWorkspace, SlackWorkspaceStore};
+
+/// PostgreSQL implementation of SlackWorkspaceStore.
+#[derive(Clone)]
+pub struct PostgresSlackWorkspaceStore {
+    pool: PgPool,
+}
+
+impl PostgresSlackWorkspaceStore {
+    /// Creates a new PostgresSlackWorkspaceStore.
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl SlackWorkspaceStore for PostgresSlackWorkspaceStore {
+    #[tracing::instrument(name = "PostgresSlackWorkspaceStore::get", skip_all, fields(db.system = "postgresql"), err)]
+    async fn get(
+        &self,
+        user_id: &UserId,
+        team_id: &str,
+    ) -> Result<Option<SlackWorkspace>, DomainError> {
+        let row = sqlx::query(
+            r#"
+            SELECT team_id, team_name, bot_token, connected_at
+            FROM slack_workspaces
+            WHERE user_id = $1 AND team_id = $2
+            "#,
+        )
+        .bind(user_id.as_str())
+        .bind(team_id)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| {
+            DomainError::new(
+                ErrorCode::DatabaseError,
+                format!("Failed to fetch Slack workspace: {}", e),
+            )
+        })?;
+
+        row.map(row_to_workspace).transpose()
+    }
+
+    #[tracing::instrument(name = "PostgresSlackWorkspaceStore::list", skip_all, fields(db.system = "postgresql"), err)]
+    async fn list(&self, user_id: &UserId) -> Result<Vec<SlackWorkspace>, DomainError> {
+        let rows = sqlx::query(
+            r#"
+            SELECT team_id, team_name, bot_token, connected_at
+            FROM slack_workspaces
+            WHERE user_id = $1
+            ORDER BY team_name ASC
+            "#,
+        )
+        .bind(user_id.as_str())
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| {
+            DomainError::new(
+                ErrorCode::DatabaseError,
+                format!("Failed to fetch Slack workspaces: {}", e),
+            )
+        })?;
+
+        rows.into_iter().map(row_to_workspace).collect()
+    }
+
+    #[tracing::instrument(name = "PostgresSlackWorkspaceStore::save", skip_all, fields(db.system = "postgresql"), err)]
+    async fn save(&self, user_id: &UserId, workspace: &SlackWorkspace) -> Result<(), DomainError> {
+        sqlx::query(
+            r#"
+            INSERT INTO slack_workspaces (user_id, team_id, team_name, bot_token, connected_at)
+            VALUES ($1, $2, $3, $4, $5)
+            ON CONFLICT (user_id, team_id) DO UPDATE SET
+                team_name = EXCLUDED.team_name,
+                bot_token = EXCLUDED.bot_token,
+                connected_at = EXCLUDED.connected_at
+            "#,
+        )
+        .bind(user_id.as_str())
+        .bind(&workspace.team_id)
+        .bind(&workspace.team_name)
+        .bind(workspace.bot_token.expose_secret())
+        .bind(workspace.connected_at.as_datetime())
+        .execute(&self.pool)
+        .await
+        .map_err(|e| {
+            DomainError::new(
+                ErrorCode::DatabaseError,
+                format!("Failed to save Slack workspace: {}", e),
+            )
+        })?;
+
+        Ok(())
+    }
+
+    #[tracing::instrument(name = "PostgresSlackWorkspaceStore::delete", skip_all, fields(db.system = "postgresql"), err)]
+    async fn delete(&self, user_id: &UserId, team_id: &str) -> Result<(), DomainError> {
+        sqlx::query("DELETE FROM slack_workspaces WHERE user_id = $1 AND team_id = $2")
+            .bind(user_id.as_str())
+            .bind(team_id)
+            .execute(&self.pool)
+            .await
+            .map_err(|e| {
+                DomainError::new(
+                    ErrorCode::DatabaseError,
+                    format!("Failed to delete Slack workspace: {}", e),
+                )
+            })?;
+
+        Ok(())
+    }
+}
+
+/// PostgreSQL implementation of SlackShareRepository.
+#[derive(Clone)]
+pub struct PostgresSlackShareRepository {
+    pool: PgPool,
+}
+
+impl PostgresSlackShareRepository {
+    /// Creates a new PostgresSlackShareRepository.
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+const SHARE_COLUMNS: &str = "id, cycle_id, user_id, team_id, channel, digest, status, \
+     attempts, last_error, next_attempt_at, created_at, sent_at, message_ts";
+
+#[async_trait]
+impl SlackShareRepository for PostgresSlackShareRepository {
+    #[tracing::instrument(name = "PostgresSlackShareRepository::save", skip_all, fields(db.system = "postgresql"), err)]
+    async fn save(&self, share: &SlackShare) -> Result<(), DomainError> {
+        let digest = serde_json::to_value(&share.digest).map_err(|e| {
+            DomainError::new(
+                ErrorCode::InternalError,
+                format!("Failed to serialize Slack digest: {}", e),
+            )
+        })?;
+
+        sqlx::query(
+            r#"
+            INSERT INTO slack_shares (
+                id, cycle_id, user_id, team_id, channel, digest, status, attempts,
+                last_error, next_attempt_at, created_at, sent_at, message_ts
+            ) VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13)
+            ON CONFLICT (id) DO UPDATE SET
+                status = EXCLUDED.status,
+                attempts = EXCLUDED.attempts,
+                last_error = EXCLUDED.last_error,
+                next_attempt_at = EXCLUDED.next_attempt_at,
+                sent_at = EXCLUDED.sent_at,
+                message_ts = EXCLUDED.message_ts
+            "#,
+        )
+        .bind(share.id.as_uuid())
+        .bind(share.cycle_id.as_uuid())
+        .bind(share.user_id.as_str())
+        .bind(&share.team_id)
+        .bind(&share.channel)
+        .bind(digest)
+        .bind(share.status.as_str())
+        .bind(share.attempts as i32)
+        .bind(share.last_error.as_deref())
+        .bind(share.next_attempt_at.as_datetime())
+        .bind(share.created_at.as_datetime())
+        .bind(share.sent_at.as_ref().map(|t| *t.as_datetime()))
+        .bind(share.message_ts.as_deref())
+        .execute(&self.pool)
+        .await
+        .map_err(|e| {
+            DomainError::new(
+                ErrorCode::DatabaseError,
+                format!("Failed to save Slack share: {}", e),
+            )
+        })?;
+
+        Ok(())
+    }
+
+    #[tracing::instrument(name = "PostgresSlackShareRepository::find_by_id", skip_all, fields(db.system = "postgresql"), err)]
+    async fn find_by_id(&self, id: &SlackShareId) -> Result<Option<SlackShare>, DomainError> {
+        let row = sqlx::query(&format!(
+            "SELECT {} FROM slack_shares WHERE id = $1",
+            SHARE_COLUMNS
+        ))
+        .bind(id.as_uuid())
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| {
+            DomainError::new(
+                ErrorCode::DatabaseError,
+                format!("Failed to fetch Slack share: {}", e),
+            )
+        })?;
+
+        row.map(row_to_share).transpose()
+    }
+
+    #[tracing::instrument(name = "PostgresSlackShareRepository::list_due", skip_all, fields(db.system = "postgresql"), err)]
+    async fn list_due(&self, now: Timestamp, limit: u32) -> Result<Vec<SlackShare>, DomainError> {
+        let rows = sqlx::query(&format!(
+            "SELECT {} FROM slack_shares \
+             WHERE status = 'pending' AND next_attempt_at <= $1 \
+             ORDER BY next_attempt_at ASC LIMIT $2",
+            SHARE_COLUMNS
+        ))
+        .bind(now.as_datetime())
+        .bind(limit as i64)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| {
+            DomainError::new(
+                ErrorCode::DatabaseError,
+                format!("Failed to fetch due Slack shares: {}", e),
+            )
+        })?;
+
+        rows.into_iter().map(row_to_share).collect()
+    }
+}
+
+fn db_error(field: &str, e: sqlx::Error) -> DomainError {
+    DomainError::new(
+        ErrorCode::DatabaseError,
+        format!("Failed to get {}: {}", field, e),
+    )
+}
+
+fn row_to_workspace(row: sqlx::postgres::PgRow) -> Result<SlackWorkspace, DomainError> {
+    let team_id: String = row.try_get("team_id").map_err(|e| db_error("team_id", e))?;
+    let team_name: String = row
+        .try_get("team_name")
+        .map_err(|e| db_error("team_name", e))?;
+    let bot_token: String = row
+        .try_get("bot_token")
+        .map_err(|e| db_error("bot_token", e))?;
+    let connected_at: chrono::DateTime<chrono::Utc> = row
+        .try_get("connected_at")
+        .map_err(|e| db_error("connected_at", e))?;
+
+    Ok(SlackWorkspace {
+        team_id,
+        team_name,
+        bot_token: Secret::new(bot_token),
+        connected_at: Timestamp::from_datetime(connected_at),
+    })
+}
+
+fn row_to_share(row: sqlx::postgres::PgRow) -> Result<SlackShare, DomainError> {
+    let id: uuid::Uuid = row.try_get("id").map_err(|e| db_error("id", e))?;
+    let cycle_id: uuid::Uuid = row
+        .try_get("cycle_id")
+        .map_err(|e| db_error("cycle_id", e))?;
+    let user: String = row.try_get("user_id").map_err(|e| db_error("user_id", e))?;
+    let team_id: String = row.try_get("team_id").map_err(|e| db_error("team_id", e))?;
+    let channel: String = row.try_get("channel").map_err(|e| db_error("channel", e))?;
+    let digest: serde_json::Value = row.try_get("digest").map_err(|e| db_error("digest", e))?;
+    let status: String = row.try_get("status").map_err(|e| db_error("status", e))?;
+    let attempts: i32 = row
+        .try_get("attempts")
+        .map_err(|e| db_error("attempts", e))?;
+    let last_error: Option<String> = row
+        .try_get("last_error")
+        .map_err(|e| db_error("last_error", e))?;
+    let next_attempt_at: chrono::DateTime<chrono::Utc> = row
+        .try_get("next_attempt_at")
+        .map_err(|e| db_error("next_attempt_at", e))?;
+    let created_at: chrono::DateTime<chrono::Utc> = row
+        .try_get("created_at")
+        .map_err(|e| db_error("created_at", e))?;
+    let sent_at: Option<chrono::DateTime<chrono::Utc>> =
+        row.try_get("sent_at").map_err(|e| db_error("sent_at", e))?;
+    let message_ts: Option<String> = row
+        .try_get("message_ts")
+        .map_err(|e| db_error("message_ts", e))?;
+
+    let user_id = UserId::new(user).map_err(|e| {
+        DomainError::new(ErrorCode::DatabaseError, format!("Invalid user_id: {}", e))
+    })?;
+    let digest: RecommendationDigest = serde_json::from_value(digest).map_err(|e| {
+        DomainError::new(
+            ErrorCode::DatabaseError,
+            format!("Invalid stored Slack digest: {}", e),
+        )
+    })?;
+    let status = DeliveryStatus::parse(&status).ok_or_else(|| {
+        DomainError::new(
+            ErrorCode::DatabaseError,
+            format!("Unknown delivery status: {}", status),
+        )
+    })?;
+
+    Ok(SlackShare {
+        id: SlackShareId::from_uuid(id),
+        cycle_id: CycleId::from_uuid(cycle_id),
+        user_id,
+        team_id,
+        channel,
+        digest,
+        status,
+        attempts: attempts as u32,
+        last_error,
+        next_attempt_at: Timestamp::from_datetime(next_attempt_at),
+        created_at: Timestamp::from_datetime(created_at),
+        sent_at: sent_at.map(Timestamp::from_datetime),
+        message_ts,
+    })
+}
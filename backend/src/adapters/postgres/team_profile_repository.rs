@@ -0,0 +1,194 @@
+//! PostgreSQL implementations of the team profile ports.
+//!
+//! Profile summaries live in `profile_summaries` as JSONB; per-organization
+//! settings live in `team_profile_settings`. Who belongs to an organization
+//! comes from `organization_members`, not from here.
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::{PgPool, Row};
+
+use crate::domain::foundation::{DomainError, ErrorCode, Timestamp, UserId};
+use crate::domain::profile::{ProfileSummary, TeamProfileSettings};
+use crate::ports::{ProfileSummaryRepository, TeamProfileSettingsRepository};
+
+/// PostgreSQL implementation of ProfileSummaryRepository.
+#[derive(Clone)]
+pub struct PostgresProfileSummaryRepository {
+    pool: PgPool,
+}
+
+impl PostgresProfileSummaryRepository {
+    /// Creates a new PostgresProfileSummaryRepository.
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl ProfileSummaryRepository for PostgresProfileSummaryRepository {
+    #[tracing::instrument(name = "PostgresProfileSummaryRepository::save", skip_all, fields(db.system = "postgresql"), err)]
+    async fn save(&self, user_id: &UserId, summary: &ProfileSummary) -> Result<(), DomainError> {
+        let json = serde_json::to_value(summary).map_err(|e| {
+            DomainError::new(
+                ErrorCode::InternalError,
+                format!("Failed to serialize profile summary: {}", e),
+            )
+        })?;
+
+        sqlx::query(
+            r#"
+            INSERT INTO profile_summaries (user_id, summary, updated_at)
+            VALUES ($1, $2, NOW())
+            ON CONFLICT (user_id) DO UPDATE SET
+                summary = EXCLUDED.summary,
+                updated_at = NOW()
+            "#,
+        )
+        .bind(user_id.as_str())
+        .bind(json)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| {
+            DomainError::new(
+                ErrorCode::DatabaseError,
+                format!("Failed to save profile summary: {}", e),
+            )
+        })?;
+
+        Ok(())
+    }
+
+    #[tracing::instrument(name = "PostgresProfileSummaryRepository::find_by_user", skip_all, fields(db.system = "postgresql"), err)]
+    async fn find_by_user(&self, user_id: &UserId) -> Result<Option<ProfileSummary>, DomainError> {
+        let row = sqlx::query("SELECT summary FROM profile_summaries WHERE user_id = $1")
+            .bind(user_id.as_str())
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(|e| {
+                DomainError::new(
+                    ErrorCode::DatabaseError,
+                    format!("Failed to fetch profile summary: {}", e),
+                )
+            })?;
+
+        row.map(|row| row_to_summary(&row)).transpose()
+    }
+
+    #[tracing::instrument(name = "PostgresProfileSummaryRepository::delete_for_user", skip_all, fields(db.system = "postgresql"), err)]
+    async fn delete_for_user(&self, user_id: &UserId) -> Result<u64, DomainError> {
+        let result = sqlx::query("DELETE FROM profile_summaries WHERE user_id = $1")
+            .bind(user_id.as_str())
+            .execute(&self.pool)
+            .await
+            .map_err(|e| {
+                DomainError::new(
+                    ErrorCode::DatabaseError,
+                    format!("Failed to delete profile summary: {}", e),
+                )
+            })?;
+
+        Ok(result.rows_affected())
+    }
+}
+
+fn row_to_summary(row: &sqlx::postgres::PgRow) -> Result<ProfileSummary, DomainError> {
+    let json: serde_json::Value = row.try_get("summary").map_err(|e| {
+        DomainError::new(
+            ErrorCode::DatabaseError,
+            format!("Failed to get summary: {}", e),
+        )
+    })?;
+    serde_json::from_value(json).map_err(|e| {
+        DomainError::new(
+            ErrorCode::DatabaseError,
+            format!("Invalid profile summary: {}", e),
+        )
+    })
+}
+
+/// PostgreSQL implementation of TeamProfileSettingsRepository.
+#[derive(Clone)]
+pub struct PostgresTeamProfileSettingsRepository {
+    pool: PgPool,
+}
+
+impl PostgresTeamProfileSettingsRepository {
+    /// Creates a new PostgresTeamProfileSettingsRepository.
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl TeamProfileSettingsRepository for PostgresTeamProfileSettingsRepository {
+    #[tracing::instrument(name = "PostgresTeamProfileSettingsRepository::get", skip_all, fields(db.system = "postgresql"), err)]
+    async fn get(&self, organization: &str) -> Result<Option<TeamProfileSettings>, DomainError> {
+        let row = sqlx::query(
+            "SELECT organization, agent_context_enabled, updated_at FROM team_profile_settings WHERE organization = $1",
+        )
+        .bind(organization)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| {
+            DomainError::new(
+                ErrorCode::DatabaseError,
+                format!("Failed to fetch team profile settings: {}", e),
+            )
+        })?;
+
+        row.map(|row| {
+            let organization: String = row.try_get("organization").map_err(|e| {
+                DomainError::new(
+                    ErrorCode::DatabaseError,
+                    format!("Failed to get organization: {}", e),
+                )
+            })?;
+            let agent_context_enabled: bool =
+                row.try_get("agent_context_enabled").map_err(|e| {
+                    DomainError::new(
+                        ErrorCode::DatabaseError,
+                        format!("Failed to get agent_context_enabled: {}", e),
+                    )
+                })?;
+            let updated_at: DateTime<Utc> = row.try_get("updated_at").map_err(|e| {
+                DomainError::new(
+                    ErrorCode::DatabaseError,
+                    format!("Failed to get updated_at: {}", e),
+                )
+            })?;
+            Ok(TeamProfileSettings {
+                organization,
+                agent_context_enabled,
+                updated_at: Timestamp::from_datetime(updated_at),
+            })
+        })
+        .transpose()
+    }
+
+    #[tracing::instrument(name = "PostgresTeamProfileSettingsRepository::save", skip_all, fields(db.system = "postgresql"), err)]
+    async fn save(&self, settings: &TeamProfileSettings) -> Result<(), DomainError> {
+        sqlx::query(
+            r#"
+            INSERT INTO team_profile_settings (organization, agent_context_enabled, updated_at)
+            VALUES ($1, $2, $3)
+            ON CONFLICT (organization) DO UPDATE SET
+                agent_context_enabled = EXCLUDED.agent_context_enabled,
+                updated_at = EXCLUDED.updated_at
+            "#,
+        )
+        .bind(&settings.organization)
+        .bind(settings.agent_context_enabled)
+        .bind(settings.updated_at.as_datetime())
+        .execute(&self.pool)
+        .await
+        .map_err(|e| {
+            DomainError::new(
+                ErrorCode::DatabaseError,
+                format!("Failed to save team profile settings: {}", e),
+            )
+        })?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,365 @@
+//! PostgreSQL implementations of the Teams ports.
+//!
+//! Shares live in `teams_shares`, where pending rows form the retry queue.
+//! Each organization's platform choice lives in `organization_chat_settings`
+//! and its registered channels in `organization_teams_channels`. Webhook URLs
+//! are credentials, so they are encrypted with pgcrypto's `pgp_sym_encrypt`
+//! under a key the database never stores, and only decrypted when read back.
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use secrecy::{ExposeSecret, Secret};
+use sqlx::{PgPool, Row};
+
+use crate::domain::document::{
+    ChatPlatform, DeliveryStatus, OrganizationChatSettings, RecommendationDigest, TeamsChannel,
+    TeamsShare,
+};
+use crate::domain::foundation::{CycleId, DomainError, ErrorCode, TeamsShareId, Timestamp, UserId};
+use crate::ports::{OrganizationChatSettingsRepository, TeamsShareRepository};
+
+/// PostgreSQL implementation of TeamsShareRepository.
+#[derive(Clone)]
+pub struct PostgresTeamsShareRepository {
+    pool: PgPool,
+}
+
+impl PostgresTeamsShareRepository {
+    /// Creates a new PostgresTeamsShareRepository.
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+const SHARE_COLUMNS: &str = "id, cycle_id, user_id, organization, channel, digest, status, \
+     attempts, last_error, next_attempt_at, created_at, sent_at";
+
+#[async_trait]
+impl TeamsShareRepository for PostgresTeamsShareRepository {
+    #[tracing::instrument(name = "PostgresTeamsShareRepository::save", skip_all, fields(db.system = "postgresql"), err)]
+    async fn save(&self, share: &TeamsShare) -> Result<(), DomainError> {
+        let digest = serde_json::to_value(&share.digest).map_err(|e| {
+            DomainError::new(
+                ErrorCode::InternalError,
+                format!("Failed to serialize Teams digest: {}", e),
+            )
+        })?;
+
+        sqlx::query(
+            r#"
+            INSERT INTO teams_shares (
+                id, cycle_id, user_id, organization, channel, digest, status, attempts,
+                last_error, next_attempt_at, created_at, sent_at
+            ) VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12)
+            ON CONFLICT (id) DO UPDATE SET
+                status = EXCLUDED.status,
+                attempts = EXCLUDED.attempts,
+                last_error = EXCLUDED.last_error,
+                next_attempt_at = EXCLUDED.next_attempt_at,
+                sent_at = EXCLUDED.sent_at
+            "#,
+        )
+        .bind(share.id.as_uuid())
+        .bind(share.cycle_id.as_uuid())
+        .bind(share.user_id.as_str())
+        .bind(&share.organization)
+        .bind(&share.channel)
+        .bind(digest)
+        .bind(share.status.as_str())
+        .bind(share.attempts as i32)
+        .bind(share.last_error.as_deref())
+        .bind(share.next_attempt_at.as_datetime())
+        .bind(share.created_at.as_datetime())
+        .bind(share.sent_at.as_ref().map(|t| *t.as_datetime()))
+        .execute(&self.pool)
+        .await
+        .map_err(|e| {
+            DomainError::new(
+                ErrorCode::DatabaseError,
+                format!("Failed to save Teams share: {}", e),
+            )
+        })?;
+
+        Ok(())
+    }
+
+    #[tracing::instrument(name = "PostgresTeamsShareRepository::find_by_id", skip_all, fields(db.system = "postgresql"), err)]
+    async fn find_by_id(&self, id: &TeamsShareId) -> Result<Option<TeamsShare>, DomainError> {
+        let row = sqlx::query(&format!(
+            "SELECT {} FROM teams_shares WHERE id = $1",
+            SHARE_COLUMNS
+        ))
+        .bind(id.as_uuid())
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| {
+            DomainError::new(
+                ErrorCode::DatabaseError,
+                format!("Failed to fetch Teams share: {}", e),
+            )
+        })?;
+
+        row.map(row_to_share).transpose()
+    }
+
+    #[tracing::instrument(name = "PostgresTeamsShareRepository::list_due", skip_all, fields(db.system = "postgresql"), err)]
+    async fn list_due(&self, now: Timestamp, limit: u32) -> Result<Vec<TeamsShare>, DomainError> {
+        let rows = sqlx::query(&format!(
+            "SELECT {} FROM teams_shares \
+             WHERE status = 'pending' AND next_attempt_at <= $1 \
+             ORDER BY next_attempt_at ASC LIMIT $2",
+            SHARE_COLUMNS
+        ))
+        .bind(now.as_datetime())
+        .bind(limit as i64)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| {
+            DomainError::new(
+                ErrorCode::DatabaseError,
+                format!("Failed to fetch due Teams shares: {}", e),
+            )
+        })?;
+
+        rows.into_iter().map(row_to_share).collect()
+    }
+}
+
+/// PostgreSQL implementation of OrganizationChatSettingsRepository.
+#[derive(Clone)]
+pub struct PostgresOrganizationChatSettingsRepository {
+    pool: PgPool,
+    /// Symmetric key the webhook URLs are encrypted with.
+    encryption_key: Secret<String>,
+}
+
+impl PostgresOrganizationChatSettingsRepository {
+    /// Creates a new PostgresOrganizationChatSettingsRepository whose webhook
+    /// URLs are encrypted with `encryption_key`.
+    pub fn new(pool: PgPool, encryption_key: impl Into<String>) -> Self {
+        Self {
+            pool,
+            encryption_key: Secret::new(encryption_key.into()),
+        }
+    }
+}
+
+#[async_trait]
+impl OrganizationChatSettingsRepository for PostgresOrganizationChatSettingsRepository {
+    #[tracing::instrument(name = "PostgresOrganizationChatSettingsRepository::get", skip_all, fields(db.system = "postgresql"), err)]
+    async fn get(
+        &self,
+        organization: &str,
+    ) -> Result<Option<OrganizationChatSettings>, DomainError> {
+        let row = sqlx::query(
+            "SELECT organization, platform, updated_at FROM organization_chat_settings WHERE organization = $1",
+        )
+        .bind(organization)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| {
+            DomainError::new(
+                ErrorCode::DatabaseError,
+                format!("Failed to fetch organization chat settings: {}", e),
+            )
+        })?;
+        let Some(row) = row else {
+            return Ok(None);
+        };
+
+        let channel_rows = sqlx::query(
+            r#"
+            SELECT name, pgp_sym_decrypt(webhook_url, $2) AS webhook_url
+            FROM organization_teams_channels
+            WHERE organization = $1
+            ORDER BY position
+            "#,
+        )
+        .bind(organization)
+        .bind(self.encryption_key.expose_secret())
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| {
+            DomainError::new(
+                ErrorCode::DatabaseError,
+                format!("Failed to fetch Teams channels: {}", e),
+            )
+        })?;
+
+        let organization: String = row
+            .try_get("organization")
+            .map_err(|e| db_error("organization", e))?;
+        let platform: String = row
+            .try_get("platform")
+            .map_err(|e| db_error("platform", e))?;
+        let updated_at: DateTime<Utc> = row
+            .try_get("updated_at")
+            .map_err(|e| db_error("updated_at", e))?;
+
+        let platform = ChatPlatform::parse(&platform).ok_or_else(|| {
+            DomainError::new(
+                ErrorCode::DatabaseError,
+                format!("Unknown chat platform: {}", platform),
+            )
+        })?;
+        let teams_channels = channel_rows
+            .into_iter()
+            .map(|row| {
+                let name: String = row.try_get("name").map_err(|e| db_error("name", e))?;
+                let webhook_url: String = row
+                    .try_get("webhook_url")
+                    .map_err(|e| db_error("webhook_url", e))?;
+                Ok(TeamsChannel {
+                    name,
+                    webhook_url: Secret::new(webhook_url),
+                })
+            })
+            .collect::<Result<Vec<_>, DomainError>>()?;
+
+        Ok(Some(OrganizationChatSettings {
+            organization,
+            platform,
+            teams_channels,
+            updated_at: Timestamp::from_datetime(updated_at),
+        }))
+    }
+
+    #[tracing::instrument(name = "PostgresOrganizationChatSettingsRepository::save", skip_all, fields(db.system = "postgresql"), err)]
+    async fn save(&self, settings: &OrganizationChatSettings) -> Result<(), DomainError> {
+        let mut tx = self.pool.begin().await.map_err(|e| {
+            DomainError::new(
+                ErrorCode::DatabaseError,
+                format!("Failed to begin transaction: {}", e),
+            )
+        })?;
+
+        sqlx::query(
+            r#"
+            INSERT INTO organization_chat_settings (organization, platform, updated_at)
+            VALUES ($1, $2, $3)
+            ON CONFLICT (organization) DO UPDATE SET
+                platform = EXCLUDED.platform,
+                updated_at = EXCLUDED.updated_at
+            "#,
+        )
+        .bind(&settings.organization)
+        .bind(settings.platform.as_str())
+        .bind(settings.updated_at.as_datetime())
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| {
+            DomainError::new(
+                ErrorCode::DatabaseError,
+                format!("Failed to save organization chat settings: {}", e),
+            )
+        })?;
+
+        sqlx::query("DELETE FROM organization_teams_channels WHERE organization = $1")
+            .bind(&settings.organization)
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| {
+                DomainError::new(
+                    ErrorCode::DatabaseError,
+                    format!("Failed to clear Teams channels: {}", e),
+                )
+            })?;
+
+        for (position, channel) in settings.teams_channels.iter().enumerate() {
+            sqlx::query(
+                r#"
+                INSERT INTO organization_teams_channels (organization, position, name, webhook_url)
+                VALUES ($1, $2, $3, pgp_sym_encrypt($4, $5))
+                "#,
+            )
+            .bind(&settings.organization)
+            .bind(position as i32)
+            .bind(&channel.name)
+            .bind(channel.webhook_url.expose_secret())
+            .bind(self.encryption_key.expose_secret())
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| {
+                DomainError::new(
+                    ErrorCode::DatabaseError,
+                    format!("Failed to save Teams channel: {}", e),
+                )
+            })?;
+        }
+
+        tx.commit().await.map_err(|e| {
+            DomainError::new(
+                ErrorCode::DatabaseError,
+                format!("Failed to commit transaction: {}", e),
+            )
+        })?;
+
+        Ok(())
+    }
+}
+
+fn db_error(field: &str, e: sqlx::Error) -> DomainError {
+    DomainError::new(
+        ErrorCode::DatabaseError,
+        format!("Failed to get {}: {}", field, e),
+    )
+}
+
+fn row_to_share(row: sqlx::postgres::PgRow) -> Result<TeamsShare, DomainError> {
+    let id: uuid::Uuid = row.try_get("id").map_err(|e| db_error("id", e))?;
+    let cycle_id: uuid::Uuid = row
+        .try_get("cycle_id")
+        .map_err(|e| db_error("cycle_id", e))?;
+    let user: String = row.try_get("user_id").map_err(|e| db_error("user_id", e))?;
+    let organization: String = row
+        .try_get("organization")
+        .map_err(|e| db_error("organization", e))?;
+    let channel: String = row.try_get("channel").map_err(|e| db_error("channel", e))?;
+    let digest: serde_json::Value = row.try_get("digest").map_err(|e| db_error("digest", e))?;
+    let status: String = row.try_get("status").map_err(|e| db_error("status", e))?;
+    let attempts: i32 = row
+        .try_get("attempts")
+        .map_err(|e| db_error("attempts", e))?;
+    let last_error: Option<String> = row
+        .try_get("last_error")
+        .map_err(|e| db_error("last_error", e))?;
+    let next_attempt_at: DateTime<Utc> = row
+        .try_get("next_attempt_at")
+        .map_err(|e| db_error("next_attempt_at", e))?;
+    let created_at: DateTime<Utc> = row
+        .try_get("created_at")
+        .map_err(|e| db_error("created_at", e))?;
+    let sent_at: Option<DateTime<Utc>> =
+        row.try_get("sent_at").map_err(|e| db_error("sent_at", e))?;
+
+    let user_id = UserId::new(user).map_err(|e| {
+        DomainError::new(ErrorCode::DatabaseError, format!("Invalid user_id: {}", e))
+    })?;
+    let digest: RecommendationDigest = serde_json::from_value(digest).map_err(|e| {
+        DomainError::new(
+            ErrorCode::DatabaseError,
+            format!("Invalid stored Teams digest: {}", e),
+        )
+    })?;
+    let status = DeliveryStatus::parse(&status).ok_or_else(|| {
+        DomainError::new(
+            ErrorCode::DatabaseError,
+            format!("Unknown delivery status: {}", status),
+        )
+    })?;
+
+    Ok(TeamsShare {
+        id: TeamsShareId::from_uuid(id),
+        cycle_id: CycleId::from_uuid(cycle_id),
+        user_id,
+        organization,
+        channel,
+        digest,
+        status,
+        attempts: attempts as u32,
+        last_error,
+        next_attempt_at: Timestamp::from_datetime(next_attempt_at),
+        created_at: Timestamp::from_datetime(created_at),
+        sent_at: sent_at.map(Timestamp::from_datetime),
+    })
+}
@@ -0,0 +1,148 @@
+//! PostgreSQL implementation of UsageReportRepository.
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::{PgPool, Row};
+
+use crate::domain::foundation::{DomainError, ErrorCode, Timestamp, UserId};
+use crate::ports::{ReportedUsage, UsageReportRepository};
+
+const SELECT_COLUMNS: &str = "SELECT user_id, usage_day, subscription_id, tokens, cost_cents, \
+                              idempotency_key, reported_at FROM usage_reports";
+
+/// PostgreSQL implementation of UsageReportRepository.
+#[derive(Clone)]
+pub struct PostgresUsageReportRepository {
+    pool: PgPool,
+}
+
+impl PostgresUsageReportRepository {
+    /// Creates a new PostgresUsageReportRepository.
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl UsageReportRepository for PostgresUsageReportRepository {
+    #[tracing::instrument(name = "PostgresUsageReportRepository::find", skip_all, fields(db.system = "postgresql"), err)]
+    async fn find(
+        &self,
+        user_id: &UserId,
+        usage_day: &Timestamp,
+    ) -> Result<Option<ReportedUsage>, DomainError> {
+        let row = sqlx::query(&format!(
+            "{} WHERE user_id = $1 AND usage_day = $2",
+            SELECT_COLUMNS
+        ))
+        .bind(user_id.as_str())
+        .bind(usage_day.as_datetime())
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| {
+            DomainError::new(
+                ErrorCode::DatabaseError,
+                format!("Failed to fetch usage report: {}", e),
+            )
+        })?;
+
+        row.map(row_to_report).transpose()
+    }
+
+    #[tracing::instrument(name = "PostgresUsageReportRepository::save", skip_all, fields(db.system = "postgresql"), err)]
+    async fn save(&self, report: &ReportedUsage) -> Result<(), DomainError> {
+        sqlx::query(
+            r#"
+            INSERT INTO usage_reports (
+                user_id, usage_day, subscription_id, tokens, cost_cents,
+                idempotency_key, reported_at
+            )
+            VALUES ($1, $2, $3, $4, $5, $6, $7)
+            ON CONFLICT (user_id, usage_day) DO UPDATE SET
+                subscription_id = EXCLUDED.subscription_id,
+                tokens = EXCLUDED.tokens,
+                cost_cents = EXCLUDED.cost_cents,
+                idempotency_key = EXCLUDED.idempotency_key,
+                reported_at = EXCLUDED.reported_at
+            "#,
+        )
+        .bind(report.user_id.as_str())
+        .bind(report.usage_day.as_datetime())
+        .bind(&report.subscription_id)
+        .bind(report.tokens as i64)
+        .bind(report.cost_cents as i32)
+        .bind(&report.idempotency_key)
+        .bind(report.reported_at.as_datetime())
+        .execute(&self.pool)
+        .await
+        .map_err(|e| {
+            DomainError::new(
+                ErrorCode::DatabaseError,
+                format!("Failed to save usage report: {}", e),
+            )
+        })?;
+
+        Ok(())
+    }
+
+    #[tracing::instrument(name = "PostgresUsageReportRepository::list_between", skip_all, fields(db.system = "postgresql"), err)]
+    async fn list_between(
+        &self,
+        from_day: &Timestamp,
+        to_day: &Timestamp,
+    ) -> Result<Vec<ReportedUsage>, DomainError> {
+        let rows = sqlx::query(&format!(
+            "{} WHERE usage_day >= $1 AND usage_day <= $2 ORDER BY usage_day ASC, user_id ASC",
+            SELECT_COLUMNS
+        ))
+        .bind(from_day.as_datetime())
+        .bind(to_day.as_datetime())
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| {
+            DomainError::new(
+                ErrorCode::DatabaseError,
+                format!("Failed to list usage reports: {}", e),
+            )
+        })?;
+
+        rows.into_iter().map(row_to_report).collect()
+    }
+}
+
+fn db_error(field: &str, e: sqlx::Error) -> DomainError {
+    DomainError::new(
+        ErrorCode::DatabaseError,
+        format!("Failed to get {}: {}", field, e),
+    )
+}
+
+fn row_to_report(row: sqlx::postgres::PgRow) -> Result<ReportedUsage, DomainError> {
+    let user_id: String = row.try_get("user_id").map_err(|e| db_error("user_id", e))?;
+    let usage_day: DateTime<Utc> = row
+        .try_get("usage_day")
+        .map_err(|e| db_error("usage_day", e))?;
+    let tokens: i64 = row.try_get("tokens").map_err(|e| db_error("tokens", e))?;
+    let cost_cents: i32 = row
+        .try_get("cost_cents")
+        .map_err(|e| db_error("cost_cents", e))?;
+    let reported_at: DateTime<Utc> = row
+        .try_get("reported_at")
+        .map_err(|e| db_error("reported_at", e))?;
+
+    Ok(ReportedUsage {
+        user_id: UserId::new(user_id).map_err(|e| {
+            DomainError::new(ErrorCode::DatabaseError, format!("Invalid user_id: {}", e))
+        })?,
+        usage_day: Timestamp::from_datetime(usage_day),
+        subscription_id: row
+            .try_get("subscription_id")
+            .map_err(|e| db_error("subscription_id", e))?,
+        tokens: tokens as u64,
+        cost_cents: cost_cents as u32,
+        idempotency_key: row
+            .try_get("idempotency_key")
+            .map_err(|e| db_error("idempotency_key", e))?,
+        reported_at: Timestamp::from_datetime(reported_at),
+    })
+}
@@ -0,0 +1,178 @@
+//! PostgreSQL `UserDataEraser` for user-keyed rows outside any session.
+//!
+//! Credentials, consents, delivery preferences, integrations and the like
+//! are keyed straight by `user_id`, so erasing sessions and cycles never
+//! reaches them. This eraser deletes them, and its verification counts every
+//! table with a `user_id` column, so a table added later without an eraser
+//! fails the report instead of silently keeping data.
+//!
+//! The audit log cannot be deleted from. Users appear in it only by
+//! pseudonym, so deleting the user's `audit_pseudonym_keys` row unlinks
+//! their entries, and verification checks that no entry names the user in
+//! the clear, as actor or anywhere in a summary.
+
+use async_trait::async_trait;
+use sqlx::PgPool;
+
+use crate::domain::foundation::{DomainError, ErrorCode, UserId};
+use crate::domain::privacy::ErasedData;
+use crate::ports::UserDataEraser;
+
+/// Tables deleted by `user_id`, children before the rows they refer to.
+const USER_TABLES: &[&str] = &[
+    "google_accounts",
+    "slack_workspaces",
+    "slack_shares",
+    "teams_shares",
+    "integration_deliveries",
+    "integrations",
+    "consent_records",
+    "document_deliveries",
+    "document_email_preferences",
+    "outcome_reminders",
+    "calendar_feed_versions",
+    "session_archival_settings",
+    "decision_embeddings",
+    "decision_trees",
+    "organization_members",
+    "provisioned_users",
+    "usage_reports",
+    "audit_pseudonym_keys",
+];
+
+/// Tables with a `user_id` column that verification skips. The erasure
+/// request itself is kept as the record that the erasure happened.
+/// `audit_log` has no `user_id` column and is checked separately.
+const RETAINED_TABLES: &[&str] = &["data_erasures"];
+
+/// Deletes the user's rows from `USER_TABLES` and verifies that no table
+/// keyed by `user_id` still holds any.
+///
+/// Register it before `SessionEraser`: conversation style overrides are
+/// found through the user's sessions.
+#[derive(Clone)]
+pub struct PostgresUserRecordsEraser {
+    pool: PgPool,
+}
+
+impl PostgresUserRecordsEraser {
+    /// Creates a new PostgresUserRecordsEraser.
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    /// Every table in the current schema with a `user_id` column.
+    async fn user_keyed_tables(&self) -> Result<Vec<String>, DomainError> {
+        sqlx::query_scalar(
+            r#"
+            SELECT c.table_name::TEXT
+            FROM information_schema.columns c
+            JOIN information_schema.tables t
+              ON t.table_schema = c.table_schema AND t.table_name = c.table_name
+            WHERE c.table_schema = current_schema()
+              AND c.column_name = 'user_id'
+              AND t.table_type = 'BASE TABLE'
+            ORDER BY c.table_name
+            "#,
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| db_error("list user-keyed tables", e))
+    }
+}
+
+#[async_trait]
+impl UserDataEraser for PostgresUserRecordsEraser {
+    fn section(&self) -> &'static str {
+        "account records"
+    }
+
+    #[tracing::instrument(name = "PostgresUserRecordsEraser::erase", skip_all, fields(db.system = "postgresql"), err)]
+    async fn erase(&self, user_id: &UserId) -> Result<ErasedData, DomainError> {
+        let mut tx = self
+            .pool
+            .begin()
+            .await
+            .map_err(|e| db_error("begin transaction", e))?;
+
+        // Keyed by conversation, so found through the sessions that own them
+        let mut records = sqlx::query(
+            r#"
+            DELETE FROM conversation_style_overrides o
+            USING conversations conv, components comp, cycles c, sessions s
+            WHERE o.conversation_id = conv.id
+              AND conv.component_id = comp.id
+              AND comp.cycle_id = c.id
+              AND c.session_id = s.id
+              AND s.user_id = $1
+            "#,
+        )
+        .bind(user_id.as_str())
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| db_error("delete conversation style overrides", e))?
+        .rows_affected();
+
+        for table in USER_TABLES {
+            records += sqlx::query(&format!("DELETE FROM {} WHERE user_id = $1", table))
+                .bind(user_id.as_str())
+                .execute(&mut *tx)
+                .await
+                .map_err(|e| db_error(&format!("delete from {}", table), e))?
+                .rows_affected();
+        }
+
+        tx.commit()
+            .await
+            .map_err(|e| db_error("commit transaction", e))?;
+        Ok(ErasedData::deleted(records))
+    }
+
+    #[tracing::instrument(name = "PostgresUserRecordsEraser::remaining", skip_all, fields(db.system = "postgresql"), err)]
+    async fn remaining(&self, user_id: &UserId) -> Result<u64, DomainError> {
+        let mut remaining = 0;
+        for table in self.user_keyed_tables().await? {
+            if RETAINED_TABLES.contains(&table.as_str()) {
+                continue;
+            }
+            // Some tables key users by UUID, so compare as text
+            let count: i64 = sqlx::query_scalar(&format!(
+                "SELECT COUNT(*) FROM \"{}\" WHERE user_id::TEXT = $1",
+                table.replace('"', "\"\"")
+            ))
+            .bind(user_id.as_str())
+            .fetch_one(&self.pool)
+            .await
+            .map_err(|e| db_error(&format!("count rows in {}", table), e))?;
+            if count > 0 {
+                tracing::warn!(table = %table, count, "User rows left after erasure");
+            }
+            remaining += count as u64;
+        }
+
+        let audit_entries: i64 = sqlx::query_scalar(
+            r#"
+            SELECT COUNT(*) FROM audit_log
+            WHERE actor_id = $1
+               OR jsonb_path_exists(before_summary, '$.**.user_id ? (@ == $id)', jsonb_build_object('id', $1::TEXT))
+               OR jsonb_path_exists(after_summary, '$.**.user_id ? (@ == $id)', jsonb_build_object('id', $1::TEXT))
+            "#,
+        )
+        .bind(user_id.as_str())
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|e| db_error("count audit log entries", e))?;
+        if audit_entries > 0 {
+            tracing::warn!(count = audit_entries, "Audit entries still name the user");
+        }
+        remaining += audit_entries as u64;
+        Ok(remaining)
+    }
+}
+
+fn db_error(action: &str, e: sqlx::Error) -> DomainError {
+    DomainError::new(
+        ErrorCode::DatabaseError,
+        format!("Failed to {}: {}", action, e),
+    )
+}
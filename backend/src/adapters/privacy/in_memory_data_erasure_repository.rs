@@ -0,0 +1,64 @@
+//! In-memory data erasure repository for testing and development.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use tokio::sync::RwLock;
+
+use crate::domain::foundation::{DataErasureId, DomainError, Timestamp, UserId};
+use crate::domain::privacy::DataErasure;
+use crate::ports::DataErasureRepository;
+
+/// In-memory data erasures keyed by ID.
+#[derive(Debug, Clone, Default)]
+pub struct InMemoryDataErasureRepository {
+    erasures: Arc<RwLock<HashMap<DataErasureId, DataErasure>>>,
+}
+
+impl InMemoryDataErasureRepository {
+    /// Create an empty repository.
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait]
+impl DataErasureRepository for InMemoryDataErasureRepository {
+    async fn save(&self, erasure: &DataErasure) -> Result<(), DomainError> {
+        self.erasures
+            .write()
+            .await
+            .insert(erasure.id, erasure.clone());
+        Ok(())
+    }
+
+    async fn find_by_id(&self, id: &DataErasureId) -> Result<Option<DataErasure>, DomainError> {
+        Ok(self.erasures.read().await.get(id).cloned())
+    }
+
+    async fn latest_for_user(&self, user_id: &UserId) -> Result<Option<DataErasure>, DomainError> {
+        Ok(self
+            .erasures
+            .read()
+            .await
+            .values()
+            .filter(|e| &e.user_id == user_id)
+            .max_by_key(|e| e.requested_at)
+            .cloned())
+    }
+
+    async fn list_due(&self, now: Timestamp, limit: u32) -> Result<Vec<DataErasure>, DomainError> {
+        let mut due: Vec<DataErasure> = self
+            .erasures
+            .read()
+            .await
+            .values()
+            .filter(|e| e.is_due(now))
+            .cloned()
+            .collect();
+        due.sort_by_key(|e| e.next_attempt_at);
+        due.truncate(limit as usize);
+        Ok(due)
+    }
+}
@@ -0,0 +1,102 @@
+//! In-memory data export repository for testing and development.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use tokio::sync::RwLock;
+
+use crate::domain::foundation::{DataExportId, DomainError, Timestamp, UserId};
+use crate::domain::privacy::DataExport;
+use crate::ports::DataExportRepository;
+
+/// In-memory data exports keyed by ID.
+#[derive(Debug, Clone, Default)]
+pub struct InMemoryDataExportRepository {
+    exports: Arc<RwLock<HashMap<DataExportId, DataExport>>>,
+}
+
+impl InMemoryDataExportRepository {
+    /// Create an empty repository.
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait]
+impl DataExportRepository for InMemoryDataExportRepository {
+    async fn save(&self, export: &DataExport) -> Result<(), DomainError> {
+        self.exports.write().await.insert(export.id, export.clone());
+        Ok(())
+    }
+
+    async fn find_by_id(&self, id: &DataExportId) -> Result<Option<DataExport>, DomainError> {
+        Ok(self.exports.read().await.get(id).cloned())
+    }
+
+    async fn latest_for_user(&self, user_id: &UserId) -> Result<Option<DataExport>, DomainError> {
+        Ok(self
+            .exports
+            .read()
+            .await
+            .values()
+            .filter(|e| &e.user_id == user_id)
+            .max_by_key(|e| e.requested_at)
+            .cloned())
+    }
+
+    async fn list_due(&self, now: Timestamp, limit: u32) -> Result<Vec<DataExport>, DomainError> {
+        let mut due: Vec<DataExport> = self
+            .exports
+            .read()
+            .await
+            .values()
+            .filter(|e| e.is_due(now))
+            .cloned()
+            .collect();
+        due.sort_by_key(|e| e.next_attempt_at);
+        due.truncate(limit as usize);
+        Ok(due)
+    }
+
+    async fn list_for_user(&self, user_id: &UserId) -> Result<Vec<DataExport>, DomainError> {
+        let mut exports: Vec<DataExport> = self
+            .exports
+            .read()
+            .await
+            .values()
+            .filter(|e| &e.user_id == user_id)
+            .cloned()
+            .collect();
+        exports.sort_by_key(|e| std::cmp::Reverse(e.requested_at));
+        Ok(exports)
+    }
+
+    async fn delete(&self, id: &DataExportId) -> Result<(), DomainError> {
+        self.exports.write().await.remove(id);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn lists_only_due_pending_exports() {
+        let repo = InMemoryDataExportRepository::new();
+        let user = UserId::new("user-1").unwrap();
+        let due = DataExport::new(user.clone(), "jo@example.com");
+        let mut later = DataExport::new(user.clone(), "jo@example.com");
+        later.record_failure("timeout", true);
+        let mut ready = DataExport::new(user, "jo@example.com");
+        ready.record_ready("data-exports/x.json");
+        for export in [&due, &later, &ready] {
+            repo.save(export).await.unwrap();
+        }
+
+        let listed = repo.list_due(Timestamp::now(), 10).await.unwrap();
+
+        assert_eq!(listed, vec![due]);
+    }
+}
@@ -0,0 +1,10 @@
+//! Privacy adapters.
+//!
+//! In-memory implementations of the `DataExportRepository` and
+//! `DataErasureRepository` ports for tests and development.
+
+mod in_memory_data_erasure_repository;
+mod in_memory_data_export_repository;
+
+pub use in_memory_data_erasure_repository::InMemoryDataErasureRepository;
+pub use in_memory_data_export_repository::InMemoryDataExportRepository;
@@ -0,0 +1,44 @@
+//! In-memory adaptive style override repository for testing and development.
+
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use tokio::sync::RwLock;
+
+use crate::domain::foundation::{ConversationId, DomainError};
+use crate::ports::AdaptiveStyleOverrideRepository;
+
+/// In-memory set of conversations with adaptive style turned off.
+#[derive(Debug, Clone, Default)]
+pub struct InMemoryAdaptiveStyleOverrideRepository {
+    disabled: Arc<RwLock<HashSet<ConversationId>>>,
+}
+
+impl InMemoryAdaptiveStyleOverrideRepository {
+    /// Create an empty repository.
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait]
+impl AdaptiveStyleOverrideRepository for InMemoryAdaptiveStyleOverrideRepository {
+    async fn is_disabled(&self, conversation_id: &ConversationId) -> Result<bool, DomainError> {
+        Ok(self.disabled.read().await.contains(conversation_id))
+    }
+
+    async fn set_disabled(
+        &self,
+        conversation_id: &ConversationId,
+        disabled: bool,
+    ) -> Result<(), DomainError> {
+        let mut set = self.disabled.write().await;
+        if disabled {
+            set.insert(*conversation_id);
+        } else {
+            set.remove(conversation_id);
+        }
+        Ok(())
+    }
+}
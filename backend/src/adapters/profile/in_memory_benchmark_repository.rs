@@ -0,0 +1,48 @@
+//! In-memory benchmark repository for testing and development.
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use tokio::sync::RwLock;
+
+use crate::domain::foundation::DomainError;
+use crate::domain::profile::{BenchmarkDistribution, DecisionDomain};
+use crate::ports::BenchmarkRepository;
+
+/// In-memory benchmark distributions, replaced wholesale on each refresh.
+#[derive(Debug, Clone, Default)]
+pub struct InMemoryBenchmarkRepository {
+    distributions: Arc<RwLock<Vec<BenchmarkDistribution>>>,
+}
+
+impl InMemoryBenchmarkRepository {
+    /// Create an empty repository.
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait]
+impl BenchmarkRepository for InMemoryBenchmarkRepository {
+    async fn replace_all(
+        &self,
+        distributions: &[BenchmarkDistribution],
+    ) -> Result<(), DomainError> {
+        *self.distributions.write().await = distributions.to_vec();
+        Ok(())
+    }
+
+    async fn list_for_domain(
+        &self,
+        domain: DecisionDomain,
+    ) -> Result<Vec<BenchmarkDistribution>, DomainError> {
+        Ok(self
+            .distributions
+            .read()
+            .await
+            .iter()
+            .filter(|d| d.domain == domain)
+            .cloned()
+            .collect())
+    }
+}
@@ -0,0 +1,81 @@
+//! In-memory decision history repository for testing and development.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use tokio::sync::RwLock;
+
+use crate::domain::foundation::{CycleId, DomainError, UserId};
+use crate::domain::profile::DecisionRecord;
+use crate::ports::DecisionHistoryRepository;
+
+/// In-memory decision records keyed by user and cycle.
+#[derive(Debug, Clone, Default)]
+pub struct InMemoryDecisionHistoryRepository {
+    records: Arc<RwLock<HashMap<(UserId, CycleId), DecisionRecord>>>,
+}
+
+impl InMemoryDecisionHistoryRepository {
+    /// Create an empty repository.
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait]
+impl DecisionHistoryRepository for InMemoryDecisionHistoryRepository {
+    async fn save(&self, user_id: &UserId, record: &DecisionRecord) -> Result<(), DomainError> {
+        self.records
+            .write()
+            .await
+            .insert((user_id.clone(), record.cycle_id), record.clone());
+        Ok(())
+    }
+
+    async fn find_by_cycle(
+        &self,
+        user_id: &UserId,
+        cycle_id: &CycleId,
+    ) -> Result<Option<DecisionRecord>, DomainError> {
+        Ok(self
+            .records
+            .read()
+            .await
+            .get(&(user_id.clone(), *cycle_id))
+            .cloned())
+    }
+
+    async fn list_for_user(&self, user_id: &UserId) -> Result<Vec<DecisionRecord>, DomainError> {
+        let mut records: Vec<DecisionRecord> = self
+            .records
+            .read()
+            .await
+            .iter()
+            .filter(|((owner, _), _)| owner == user_id)
+            .map(|(_, record)| record.clone())
+            .collect();
+        records.sort_by_key(|r| r.date);
+        Ok(records)
+    }
+
+    async fn list_user_ids(&self) -> Result<Vec<UserId>, DomainError> {
+        let mut user_ids: Vec<UserId> = self
+            .records
+            .read()
+            .await
+            .keys()
+            .map(|(owner, _)| owner.clone())
+            .collect();
+        user_ids.sort_by(|a, b| a.as_str().cmp(b.as_str()));
+        user_ids.dedup();
+        Ok(user_ids)
+    }
+
+    async fn delete_for_user(&self, user_id: &UserId) -> Result<u64, DomainError> {
+        let mut records = self.records.write().await;
+        let before = records.len();
+        records.retain(|(owner, _), _| owner != user_id);
+        Ok((before - records.len()) as u64)
+    }
+}
@@ -0,0 +1,97 @@
+//! In-memory outcome reminder repository for testing and development.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use tokio::sync::RwLock;
+
+use crate::domain::foundation::{CycleId, DomainError, OutcomeReminderId, Timestamp, UserId};
+use crate::domain::profile::{OutcomeReminder, OutcomeReminderStatus};
+use crate::ports::OutcomeReminderRepository;
+
+/// In-memory outcome reminders keyed by ID.
+#[derive(Debug, Clone, Default)]
+pub struct InMemoryOutcomeReminderRepository {
+    reminders: Arc<RwLock<HashMap<OutcomeReminderId, OutcomeReminder>>>,
+}
+
+impl InMemoryOutcomeReminderRepository {
+    /// Create an empty repository.
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait]
+impl OutcomeReminderRepository for InMemoryOutcomeReminderRepository {
+    async fn save(&self, reminder: &OutcomeReminder) -> Result<(), DomainError> {
+        self.reminders
+            .write()
+            .await
+            .insert(reminder.id, reminder.clone());
+        Ok(())
+    }
+
+    async fn find_by_cycle(
+        &self,
+        user_id: &UserId,
+        cycle_id: &CycleId,
+    ) -> Result<Option<OutcomeReminder>, DomainError> {
+        Ok(self
+            .reminders
+            .read()
+            .await
+            .values()
+            .filter(|r| &r.user_id == user_id && &r.cycle_id == cycle_id)
+            .max_by_key(|r| r.created_at)
+            .cloned())
+    }
+
+    async fn find_by_token_hash(
+        &self,
+        token_hash: &str,
+    ) -> Result<Option<OutcomeReminder>, DomainError> {
+        Ok(self
+            .reminders
+            .read()
+            .await
+            .values()
+            .find(|r| r.survey_token_hash.as_deref() == Some(token_hash))
+            .cloned())
+    }
+
+    async fn list_due(
+        &self,
+        now: Timestamp,
+        limit: u32,
+    ) -> Result<Vec<OutcomeReminder>, DomainError> {
+        let mut due: Vec<OutcomeReminder> = self
+            .reminders
+            .read()
+            .await
+            .values()
+            .filter(|r| r.is_due(now))
+            .cloned()
+            .collect();
+        due.sort_by_key(|r| r.next_attempt_at);
+        due.truncate(limit as usize);
+        Ok(due)
+    }
+
+    async fn list_scheduled_for_user(
+        &self,
+        user_id: &UserId,
+    ) -> Result<Vec<OutcomeReminder>, DomainError> {
+        let mut scheduled: Vec<OutcomeReminder> = self
+            .reminders
+            .read()
+            .await
+            .values()
+            .filter(|r| &r.user_id == user_id && r.status == OutcomeReminderStatus::Scheduled)
+            .cloned()
+            .collect();
+        scheduled.sort_by_key(|r| r.next_attempt_at);
+        Ok(scheduled)
+    }
+}
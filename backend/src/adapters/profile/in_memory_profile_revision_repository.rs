@@ -0,0 +1,86 @@
+//! In-memory profile revision repository for testing and development.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use tokio::sync::RwLock;
+
+use crate::domain::foundation::{DomainError, ErrorCode, UserId};
+use crate::domain::profile::ProfileRevision;
+use crate::ports::ProfileRevisionRepository;
+
+/// In-memory profile revisions, oldest first per user.
+#[derive(Debug, Clone, Default)]
+pub struct InMemoryProfileRevisionRepository {
+    revisions: Arc<RwLock<HashMap<UserId, Vec<ProfileRevision>>>>,
+}
+
+impl InMemoryProfileRevisionRepository {
+    /// Create an empty repository.
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait]
+impl ProfileRevisionRepository for InMemoryProfileRevisionRepository {
+    async fn append(
+        &self,
+        user_id: &UserId,
+        revision: &ProfileRevision,
+    ) -> Result<(), DomainError> {
+        let mut revisions = self.revisions.write().await;
+        let history = revisions.entry(user_id.clone()).or_default();
+        if history.iter().any(|r| r.version == revision.version) {
+            return Err(DomainError::new(
+                ErrorCode::DatabaseError,
+                format!("Profile revision {} already exists", revision.version),
+            ));
+        }
+        history.push(revision.clone());
+        history.sort_by_key(|r| r.version);
+        Ok(())
+    }
+
+    async fn latest(&self, user_id: &UserId) -> Result<Option<ProfileRevision>, DomainError> {
+        Ok(self
+            .revisions
+            .read()
+            .await
+            .get(user_id)
+            .and_then(|history| history.last().cloned()))
+    }
+
+    async fn find(
+        &self,
+        user_id: &UserId,
+        version: u32,
+    ) -> Result<Option<ProfileRevision>, DomainError> {
+        Ok(self
+            .revisions
+            .read()
+            .await
+            .get(user_id)
+            .and_then(|history| history.iter().find(|r| r.version == version).cloned()))
+    }
+
+    async fn list_for_user(&self, user_id: &UserId) -> Result<Vec<ProfileRevision>, DomainError> {
+        Ok(self
+            .revisions
+            .read()
+            .await
+            .get(user_id)
+            .cloned()
+            .unwrap_or_default())
+    }
+
+    async fn delete_for_user(&self, user_id: &UserId) -> Result<u64, DomainError> {
+        Ok(self
+            .revisions
+            .write()
+            .await
+            .remove(user_id)
+            .map_or(0, |history| history.len() as u64))
+    }
+}
@@ -0,0 +1,72 @@
+//! In-memory profile summary and team settings repositories for testing and
+//! development.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use tokio::sync::RwLock;
+
+use crate::domain::foundation::{DomainError, UserId};
+use crate::domain::profile::{ProfileSummary, TeamProfileSettings};
+use crate::ports::{ProfileSummaryRepository, TeamProfileSettingsRepository};
+
+/// In-memory profile summaries keyed by user.
+#[derive(Debug, Clone, Default)]
+pub struct InMemoryProfileSummaryRepository {
+    summaries: Arc<RwLock<HashMap<UserId, ProfileSummary>>>,
+}
+
+impl InMemoryProfileSummaryRepository {
+    /// Create an empty repository.
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait]
+impl ProfileSummaryRepository for InMemoryProfileSummaryRepository {
+    async fn save(&self, user_id: &UserId, summary: &ProfileSummary) -> Result<(), DomainError> {
+        self.summaries
+            .write()
+            .await
+            .insert(user_id.clone(), summary.clone());
+        Ok(())
+    }
+
+    async fn find_by_user(&self, user_id: &UserId) -> Result<Option<ProfileSummary>, DomainError> {
+        Ok(self.summaries.read().await.get(user_id).cloned())
+    }
+
+    async fn delete_for_user(&self, user_id: &UserId) -> Result<u64, DomainError> {
+        Ok(self.summaries.write().await.remove(user_id).map_or(0, |_| 1))
+    }
+}
+
+/// In-memory team profile settings keyed by organization.
+#[derive(Debug, Clone, Default)]
+pub struct InMemoryTeamProfileSettingsRepository {
+    settings: Arc<RwLock<HashMap<String, TeamProfileSettings>>>,
+}
+
+impl InMemoryTeamProfileSettingsRepository {
+    /// Create an empty repository.
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait]
+impl TeamProfileSettingsRepository for InMemoryTeamProfileSettingsRepository {
+    async fn get(&self, organization: &str) -> Result<Option<TeamProfileSettings>, DomainError> {
+        Ok(self.settings.read().await.get(organization).cloned())
+    }
+
+    async fn save(&self, settings: &TeamProfileSettings) -> Result<(), DomainError> {
+        self.settings
+            .write()
+            .await
+            .insert(settings.organization.clone(), settings.clone());
+        Ok(())
+    }
+}
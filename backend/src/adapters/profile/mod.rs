@@ -0,0 +1,20 @@
+//! Decision profile adapters.
+//!
+//! In-memory implementations of the decision profile ports for tests and
+//! development. The production adapters live in `adapters::postgres`.
+
+mod in_memory_adaptive_style_override_repository;
+mod in_memory_benchmark_repository;
+mod in_memory_decision_history_repository;
+mod in_memory_outcome_reminder_repository;
+mod in_memory_profile_revision_repository;
+mod in_memory_team_profile;
+
+pub use in_memory_adaptive_style_override_repository::InMemoryAdaptiveStyleOverrideRepository;
+pub use in_memory_benchmark_repository::InMemoryBenchmarkRepository;
+pub use in_memory_decision_history_repository::InMemoryDecisionHistoryRepository;
+pub use in_memory_outcome_reminder_repository::InMemoryOutcomeReminderRepository;
+pub use in_memory_profile_revision_repository::InMemoryProfileRevisionRepository;
+pub use in_memory_team_profile::{
+    InMemoryProfileSummaryRepository, InMemoryTeamProfileSettingsRepository,
+};
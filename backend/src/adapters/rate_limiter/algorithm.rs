@@ -0,0 +1,440 @@
+//! Rate limiting algorithms.
+//!
+//! Each algorithm is a small state machine driven by an explicit clock in
+//! milliseconds, so the in-memory adapter and the property tests share the
+//! exact same logic. The Redis adapter mirrors these semantics in Lua.
+
+use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
+
+/// Algorithm used to enforce a `limit` per `window`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RateLimitAlgorithm {
+    /// Counter that resets when the window expires.
+    ///
+    /// Cheap, but lets up to twice the limit through around a window edge.
+    #[default]
+    FixedWindow,
+    /// Log of admitted requests over the trailing window.
+    ///
+    /// Exact: no window-length interval ever admits more than the limit.
+    SlidingWindow,
+    /// Bucket of `limit` tokens refilled continuously over the window.
+    ///
+    /// Allows a full burst after idle time, then a steady refill rate.
+    TokenBucket,
+    /// Generic cell rate algorithm (virtual scheduling).
+    ///
+    /// Same admission curve as a token bucket, stored as a single timestamp.
+    Gcra,
+}
+
+impl RateLimitAlgorithm {
+    /// Fresh per-key state for this algorithm.
+    pub(crate) fn initial_state(self, now_ms: u64, limit: u32) -> AlgorithmState {
+        match self {
+            Self::FixedWindow => AlgorithmState::FixedWindow {
+                count: 0,
+                window_start_ms: now_ms,
+            },
+            Self::SlidingWindow => AlgorithmState::SlidingWindow {
+                hits: VecDeque::new(),
+            },
+            Self::TokenBucket => AlgorithmState::TokenBucket {
+                tokens: limit as f64,
+                last_refill_ms: now_ms,
+            },
+            Self::Gcra => AlgorithmState::Gcra { tat_ms: now_ms },
+        }
+    }
+}
+
+/// Outcome of evaluating a single request.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Decision {
+    /// Request admitted.
+    Allowed {
+        /// Requests still admissible right now.
+        remaining: u32,
+        /// Milliseconds until the full quota is available again.
+        reset_after_ms: u64,
+    },
+    /// Request rejected.
+    Denied {
+        /// Milliseconds until a request would be admitted.
+        retry_after_ms: u64,
+    },
+}
+
+#[cfg(test)]
+impl Decision {
+    fn is_allowed(&self) -> bool {
+        matches!(self, Decision::Allowed { .. })
+    }
+}
+
+/// Per-key algorithm state.
+#[derive(Debug, Clone)]
+pub(crate) enum AlgorithmState {
+    FixedWindow { count: u32, window_start_ms: u64 },
+    SlidingWindow { hits: VecDeque<u64> },
+    TokenBucket { tokens: f64, last_refill_ms: u64 },
+    Gcra { tat_ms: u64 },
+}
+
+impl AlgorithmState {
+    /// Evaluates a request at `now_ms`, consuming quota if admitted.
+    pub(crate) fn check(&mut self, now_ms: u64, limit: u32, window_secs: u32) -> Decision {
+        let window_ms = window_secs as u64 * 1000;
+        if limit == 0 {
+            return Decision::Denied {
+                retry_after_ms: window_ms,
+            };
+        }
+
+        match self {
+            AlgorithmState::FixedWindow {
+                count,
+                window_start_ms,
+            } => {
+                if now_ms >= *window_start_ms + window_ms {
+                    *count = 0;
+                    *window_start_ms = now_ms;
+                }
+                let reset_after_ms = (*window_start_ms + window_ms).saturating_sub(now_ms);
+                if *count >= limit {
+                    return Decision::Denied {
+                        retry_after_ms: reset_after_ms,
+                    };
+                }
+                *count += 1;
+                Decision::Allowed {
+                    remaining: limit - *count,
+                    reset_after_ms,
+                }
+            }
+            AlgorithmState::SlidingWindow { hits } => {
+                prune_hits(hits, now_ms, window_ms);
+                if hits.len() as u32 >= limit {
+                    let oldest = hits.front().copied().unwrap_or(now_ms);
+                    return Decision::Denied {
+                        retry_after_ms: (oldest + window_ms).saturating_sub(now_ms),
+                    };
+                }
+                hits.push_back(now_ms);
+                Decision::Allowed {
+                    remaining: limit - hits.len() as u32,
+                    reset_after_ms: window_ms,
+                }
+            }
+            AlgorithmState::TokenBucket {
+                tokens,
+                last_refill_ms,
+            } => {
+                let rate = limit as f64 / window_ms as f64;
+                refill(tokens, last_refill_ms, now_ms, limit, rate);
+                if *tokens < 1.0 {
+                    return Decision::Denied {
+                        retry_after_ms: ((1.0 - *tokens) / rate).ceil() as u64,
+                    };
+                }
+                *tokens -= 1.0;
+                Decision::Allowed {
+                    remaining: tokens.floor() as u32,
+                    reset_after_ms: ((limit as f64 - *tokens) / rate).ceil() as u64,
+                }
+            }
+            AlgorithmState::Gcra { tat_ms } => {
+                let interval_ms = emission_interval_ms(limit, window_ms);
+                let new_tat = (*tat_ms).max(now_ms) + interval_ms;
+                if new_tat - now_ms > window_ms {
+                    return Decision::Denied {
+                        retry_after_ms: new_tat - window_ms - now_ms,
+                    };
+                }
+                *tat_ms = new_tat;
+                Decision::Allowed {
+                    remaining: gcra_remaining(new_tat, now_ms, window_ms, interval_ms, limit),
+                    reset_after_ms: new_tat - now_ms,
+                }
+            }
+        }
+    }
+
+    /// Remaining quota and milliseconds until full quota, without consuming.
+    pub(crate) fn peek(&self, now_ms: u64, limit: u32, window_secs: u32) -> (u32, u64) {
+        let window_ms = window_secs as u64 * 1000;
+        if limit == 0 {
+            return (0, window_ms);
+        }
+
+        match self {
+            AlgorithmState::FixedWindow {
+                count,
+                window_start_ms,
+            } => {
+                let window_end = window_start_ms + window_ms;
+                if now_ms >= window_end {
+                    (limit, window_ms)
+                } else {
+                    (limit.saturating_sub(*count), window_end - now_ms)
+                }
+            }
+            AlgorithmState::SlidingWindow { hits } => {
+                let live: Vec<u64> = hits
+                    .iter()
+                    .copied()
+                    .filter(|hit| hit + window_ms > now_ms)
+                    .collect();
+                let reset_after_ms = live
+                    .last()
+                    .map(|newest| (newest + window_ms).saturating_sub(now_ms))
+                    .unwrap_or(window_ms);
+                (limit.saturating_sub(live.len() as u32), reset_after_ms)
+            }
+            AlgorithmState::TokenBucket {
+                tokens,
+                last_refill_ms,
+            } => {
+                let rate = limit as f64 / window_ms as f64;
+                let mut tokens = *tokens;
+                let mut last_refill_ms = *last_refill_ms;
+                refill(&mut tokens, &mut last_refill_ms, now_ms, limit, rate);
+                (
+                    tokens.floor() as u32,
+                    ((limit as f64 - tokens) / rate).ceil() as u64,
+                )
+            }
+            AlgorithmState::Gcra { tat_ms } => {
+                let interval_ms = emission_interval_ms(limit, window_ms);
+                let tat = (*tat_ms).max(now_ms);
+                (
+                    gcra_remaining(tat, now_ms, window_ms, interval_ms, limit),
+                    tat - now_ms,
+                )
+            }
+        }
+    }
+}
+
+fn prune_hits(hits: &mut VecDeque<u64>, now_ms: u64, window_ms: u64) {
+    while hits.front().is_some_and(|hit| hit + window_ms <= now_ms) {
+        hits.pop_front();
+    }
+}
+
+fn refill(tokens: &mut f64, last_refill_ms: &mut u64, now_ms: u64, limit: u32, rate: f64) {
+    let elapsed = now_ms.saturating_sub(*last_refill_ms) as f64;
+    *tokens = (*tokens + elapsed * rate).min(limit as f64);
+    *last_refill_ms = (*last_refill_ms).max(now_ms);
+}
+
+/// Spacing between requests at the sustained rate, never zero.
+fn emission_interval_ms(limit: u32, window_ms: u64) -> u64 {
+    (window_ms / limit as u64).max(1)
+}
+
+fn gcra_remaining(tat: u64, now_ms: u64, window_ms: u64, interval_ms: u64, limit: u32) -> u32 {
+    let headroom = window_ms.saturating_sub(tat - now_ms);
+    ((headroom / interval_ms) as u32).min(limit)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    const LIMIT: u32 = 10;
+    const WINDOW_SECS: u32 = 60;
+    const WINDOW_MS: u64 = 60_000;
+
+    /// Runs a sequence of arrival times through `algorithm`, returning the
+    /// timestamps of admitted requests.
+    fn admitted(algorithm: RateLimitAlgorithm, arrivals: &[u64]) -> Vec<u64> {
+        let mut state = algorithm.initial_state(arrivals.first().copied().unwrap_or(0), LIMIT);
+        arrivals
+            .iter()
+            .copied()
+            .filter(|&now| state.check(now, LIMIT, WINDOW_SECS).is_allowed())
+            .collect()
+    }
+
+    /// Largest number of admissions inside any window-length interval.
+    fn peak_per_window(admitted: &[u64]) -> usize {
+        admitted
+            .iter()
+            .enumerate()
+            .map(|(i, start)| {
+                admitted[i..]
+                    .iter()
+                    .take_while(|&&t| t < start + WINDOW_MS)
+                    .count()
+            })
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// No interval admits more than a full bucket plus what refilled during it.
+    fn within_capacity_plus_refill(admitted: &[u64]) -> bool {
+        admitted.iter().enumerate().all(|(i, start)| {
+            admitted[i..].iter().enumerate().all(|(n, end)| {
+                let refilled = (end - start) * LIMIT as u64 / WINDOW_MS;
+                (n as u64) < LIMIT as u64 + refilled
+            })
+        })
+    }
+
+    /// Bursts of back-to-back requests separated by arbitrary idle gaps.
+    fn bursty_arrivals() -> impl Strategy<Value = Vec<u64>> {
+        prop::collection::vec((0u64..90_000, 1usize..25), 1..12).prop_map(|bursts| {
+            let mut now = 0;
+            let mut arrivals = Vec::new();
+            for (gap, size) in bursts {
+                now += gap;
+                arrivals.resize(arrivals.len() + size, now);
+            }
+            arrivals
+        })
+    }
+
+    // ─── Burst Fairness Properties ───────────────────────────────────
+
+    proptest! {
+        #[test]
+        fn sliding_window_never_exceeds_limit_in_any_window(arrivals in bursty_arrivals()) {
+            let admitted = admitted(RateLimitAlgorithm::SlidingWindow, &arrivals);
+            prop_assert!(peak_per_window(&admitted) <= LIMIT as usize);
+        }
+
+        #[test]
+        fn token_bucket_bounded_by_capacity_plus_refill(arrivals in bursty_arrivals()) {
+            let admitted = admitted(RateLimitAlgorithm::TokenBucket, &arrivals);
+            prop_assert!(within_capacity_plus_refill(&admitted));
+        }
+
+        #[test]
+        fn gcra_bounded_by_capacity_plus_refill(arrivals in bursty_arrivals()) {
+            let admitted = admitted(RateLimitAlgorithm::Gcra, &arrivals);
+            prop_assert!(within_capacity_plus_refill(&admitted));
+        }
+
+        #[test]
+        fn fixed_window_never_exceeds_twice_limit(arrivals in bursty_arrivals()) {
+            let admitted = admitted(RateLimitAlgorithm::FixedWindow, &arrivals);
+            prop_assert!(peak_per_window(&admitted) <= 2 * LIMIT as usize);
+        }
+
+        #[test]
+        fn saturated_traffic_receives_full_rate(
+            algorithm in prop_oneof![
+                Just(RateLimitAlgorithm::FixedWindow),
+                Just(RateLimitAlgorithm::SlidingWindow),
+                Just(RateLimitAlgorithm::TokenBucket),
+                Just(RateLimitAlgorithm::Gcra),
+            ],
+            windows in 1u64..6,
+        ) {
+            // One request every 100ms: far above the sustained rate.
+            let arrivals: Vec<u64> = (0..windows * WINDOW_MS / 100).map(|i| i * 100).collect();
+            let admitted = admitted(algorithm, &arrivals).len() as u64;
+            let limit = LIMIT as u64;
+            prop_assert!(admitted >= windows * limit - limit, "{:?} starved: {}", algorithm, admitted);
+            prop_assert!(admitted <= (windows + 1) * limit, "{:?} overshot: {}", algorithm, admitted);
+        }
+    }
+
+    // ─── Window Edge Behaviour ────────────────────────────────────────
+
+    /// `LIMIT` requests just before a window edge, then `LIMIT` just after.
+    fn edge_burst() -> Vec<u64> {
+        let mut arrivals = vec![0];
+        arrivals.resize(1 + LIMIT as usize, WINDOW_MS - 1);
+        arrivals.resize(1 + 2 * LIMIT as usize, WINDOW_MS);
+        arrivals
+    }
+
+    #[test]
+    fn fixed_window_admits_double_burst_at_edge() {
+        let admitted = admitted(RateLimitAlgorithm::FixedWindow, &edge_burst());
+        assert_eq!(peak_per_window(&admitted), 2 * LIMIT as usize - 1);
+    }
+
+    #[test]
+    fn sliding_window_rejects_burst_at_edge() {
+        let admitted = admitted(RateLimitAlgorithm::SlidingWindow, &edge_burst());
+        assert_eq!(peak_per_window(&admitted), LIMIT as usize);
+    }
+
+    #[test]
+    fn refilling_algorithms_reject_second_burst_after_edge() {
+        for algorithm in [RateLimitAlgorithm::TokenBucket, RateLimitAlgorithm::Gcra] {
+            let admitted = admitted(algorithm, &edge_burst());
+            let after_edge = admitted.iter().filter(|&&t| t >= WINDOW_MS).count();
+            assert_eq!(after_edge, 0, "{:?}", algorithm);
+        }
+    }
+
+    // ─── Per-Algorithm Behaviour ──────────────────────────────────────
+
+    #[test]
+    fn token_bucket_refills_one_token_per_interval() {
+        let mut state = RateLimitAlgorithm::TokenBucket.initial_state(0, LIMIT);
+        for _ in 0..LIMIT {
+            assert!(state.check(0, LIMIT, WINDOW_SECS).is_allowed());
+        }
+        assert_eq!(
+            state.check(0, LIMIT, WINDOW_SECS),
+            Decision::Denied {
+                retry_after_ms: 6_000
+            }
+        );
+        assert!(state.check(6_000, LIMIT, WINDOW_SECS).is_allowed());
+        assert!(!state.check(6_000, LIMIT, WINDOW_SECS).is_allowed());
+    }
+
+    #[test]
+    fn sliding_window_retry_after_tracks_oldest_hit() {
+        let mut state = RateLimitAlgorithm::SlidingWindow.initial_state(0, 2);
+        state.check(0, 2, WINDOW_SECS);
+        state.check(10_000, 2, WINDOW_SECS);
+        assert_eq!(
+            state.check(20_000, 2, WINDOW_SECS),
+            Decision::Denied {
+                retry_after_ms: 40_000
+            }
+        );
+        assert!(state.check(60_000, 2, WINDOW_SECS).is_allowed());
+    }
+
+    #[test]
+    fn peek_does_not_consume() {
+        for algorithm in [
+            RateLimitAlgorithm::FixedWindow,
+            RateLimitAlgorithm::SlidingWindow,
+            RateLimitAlgorithm::TokenBucket,
+            RateLimitAlgorithm::Gcra,
+        ] {
+            let mut state = algorithm.initial_state(0, LIMIT);
+            state.check(0, LIMIT, WINDOW_SECS);
+            let (before, _) = state.peek(0, LIMIT, WINDOW_SECS);
+            let (after, _) = state.peek(0, LIMIT, WINDOW_SECS);
+            assert_eq!(before, LIMIT - 1, "{:?}", algorithm);
+            assert_eq!(before, after, "{:?}", algorithm);
+        }
+    }
+
+    #[test]
+    fn zero_limit_always_denies() {
+        let mut state = RateLimitAlgorithm::TokenBucket.initial_state(0, 0);
+        assert!(!state.check(0, 0, WINDOW_SECS).is_allowed());
+    }
+
+    #[test]
+    fn algorithm_deserializes_from_snake_case() {
+        let algorithm: RateLimitAlgorithm = serde_json::from_str("\"sliding_window\"").unwrap();
+        assert_eq!(algorithm, RateLimitAlgorithm::SlidingWindow);
+        let algorithm: RateLimitAlgorithm = serde_json::from_str("\"gcra\"").unwrap();
+        assert_eq!(algorithm, RateLimitAlgorithm::Gcra);
+    }
+}
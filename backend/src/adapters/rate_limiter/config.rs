@@ -7,17 +7,25 @@ use crate::domain::membership::MembershipTier;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
+use super::algorithm::RateLimitAlgorithm;
+
 /// Complete rate limit configuration.
 ///
-/// Contains limits for global, per-IP, and per-tier rate limiting.
+/// Contains limits for global, per-IP, per-tier, and per-service rate limiting.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct RateLimitConfig {
+    /// Algorithm applied to every scope.
+    #[serde(default)]
+    pub algorithm: RateLimitAlgorithm,
     /// Global rate limits (infrastructure protection).
     pub global: GlobalLimits,
     /// Per-IP rate limits (brute-force protection).
     pub per_ip: IpLimits,
     /// Per-tier rate limits (tier-based quotas).
     pub per_tier: HashMap<MembershipTier, TierRateLimits>,
+    /// Per-service-account rate limits (client-credentials tokens).
+    #[serde(default)]
+    pub per_service: ServiceRateLimits,
     /// Per-resource rate limits (specific endpoint limits).
     pub resources: HashMap<String, ResourceLimits>,
 }
@@ -57,6 +65,43 @@ pub struct TierRateLimits {
     pub websocket_connections: u32,
 }
 
+/// Rate limits for each service account.
+///
+/// Integration workers run batch jobs, so they get higher general limits
+/// than any user tier, but AI usage stays bounded.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ServiceRateLimits {
+    /// General API requests per minute.
+    pub general_requests_per_minute: u32,
+    /// AI completion requests per minute.
+    pub ai_completions_per_minute: u32,
+    /// Export operations per hour.
+    pub exports_per_hour: u32,
+}
+
+impl Default for ServiceRateLimits {
+    fn default() -> Self {
+        Self {
+            general_requests_per_minute: 1_200,
+            ai_completions_per_minute: 30,
+            exports_per_hour: 100,
+        }
+    }
+}
+
+impl ServiceRateLimits {
+    /// Get the limit and window for a specific resource.
+    ///
+    /// Returns (limit, window_secs) tuple.
+    pub fn limit_for_resource(&self, resource: Option<&str>) -> (u32, u32) {
+        match resource {
+            Some("ai_completions") => (self.ai_completions_per_minute, 60),
+            Some("export") => (self.exports_per_hour, 3600),
+            _ => (self.general_requests_per_minute, 60),
+        }
+    }
+}
+
 /// Rate limits for a specific resource.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ResourceLimits {
@@ -74,6 +119,7 @@ impl Default for RateLimitConfig {
         per_tier.insert(MembershipTier::Annual, TierRateLimits::annual());
 
         Self {
+            algorithm: RateLimitAlgorithm::default(),
             global: GlobalLimits {
                 requests_per_minute: 10_000,
             },
@@ -82,6 +128,7 @@ impl Default for RateLimitConfig {
                 auth_attempts_per_hour: 10,
             },
             per_tier,
+            per_service: ServiceRateLimits::default(),
             resources: HashMap::new(),
         }
     }
@@ -224,6 +271,23 @@ mod tests {
         assert_eq!(window, 60);
     }
 
+    #[test]
+    fn service_limits_exceed_user_tiers() {
+        let service = ServiceRateLimits::default();
+        let annual = TierRateLimits::annual();
+        assert!(service.general_requests_per_minute > annual.general_requests_per_minute);
+        assert_eq!(service.limit_for_resource(Some("ai_completions")), (30, 60));
+        assert_eq!(service.limit_for_resource(None), (1_200, 60));
+    }
+
+    #[test]
+    fn config_without_per_service_deserializes_to_default() {
+        let mut json = serde_json::to_value(RateLimitConfig::default()).unwrap();
+        json.as_object_mut().unwrap().remove("per_service");
+        let config: RateLimitConfig = serde_json::from_value(json).unwrap();
+        assert_eq!(config.per_service.general_requests_per_minute, 1_200);
+    }
+
     #[test]
     fn config_limits_for_tier_returns_correct_tier() {
         let config = RateLimitConfig::default();
@@ -231,6 +295,20 @@ mod tests {
         assert_eq!(monthly.general_requests_per_minute, 300);
     }
 
+    #[test]
+    fn default_algorithm_is_fixed_window() {
+        let config = RateLimitConfig::default();
+        assert_eq!(config.algorithm, RateLimitAlgorithm::FixedWindow);
+    }
+
+    #[test]
+    fn config_without_algorithm_deserializes_to_default() {
+        let mut json = serde_json::to_value(RateLimitConfig::default()).unwrap();
+        json.as_object_mut().unwrap().remove("algorithm");
+        let config: RateLimitConfig = serde_json::from_value(json).unwrap();
+        assert_eq!(config.algorithm, RateLimitAlgorithm::FixedWindow);
+    }
+
     #[test]
     fn tier_rate_limits_serializes_to_json() {
         let limits = TierRateLimits::free();
@@ -1,7 +1,7 @@
 //! In-memory rate limiter implementation for testing and development.
 //!
-//! Uses a fixed-window counter algorithm with an in-memory HashMap.
-//! Not suitable for production multi-server deployments.
+//! Runs the algorithm selected in `RateLimitConfig` against an in-memory
+//! HashMap. Not suitable for production multi-server deployments.
 
 use async_trait::async_trait;
 use std::collections::HashMap;
@@ -15,39 +15,28 @@ use crate::ports::{
     RateLimitStatus, RateLimiter,
 };
 
+use super::algorithm::{AlgorithmState, Decision};
 use super::config::{RateLimitConfig, TierRateLimits};
 
 /// In-memory rate limiter for testing and single-server deployments.
 ///
-/// Uses a fixed-window counter algorithm. Each window tracks the count
-/// of requests and resets when the window expires.
+/// Keeps one algorithm state per key, created lazily on first check.
 #[derive(Debug)]
 pub struct InMemoryRateLimiter {
     /// Rate limit configuration.
     config: RateLimitConfig,
-    /// Per-key window state.
-    windows: Arc<RwLock<HashMap<String, WindowState>>>,
+    /// Per-key algorithm state.
+    states: Arc<RwLock<HashMap<String, AlgorithmState>>>,
     /// Default tier for users without explicit tier.
     default_tier: MembershipTier,
 }
 
-/// State for a single rate limit window.
-#[derive(Debug, Clone)]
-struct WindowState {
-    /// Number of requests in the current window.
-    count: u32,
-    /// When the current window started.
-    window_start: u64,
-    /// Window duration in seconds.
-    window_secs: u32,
-}
-
 impl InMemoryRateLimiter {
     /// Create a new in-memory rate limiter with default configuration.
     pub fn new(config: RateLimitConfig) -> Self {
         Self {
             config,
-            windows: Arc::new(RwLock::new(HashMap::new())),
+            states: Arc::new(RwLock::new(HashMap::new())),
             default_tier: MembershipTier::Free,
         }
     }
@@ -72,6 +61,10 @@ impl InMemoryRateLimiter {
                 let tier_limits = self.config.limits_for_tier(self.default_tier);
                 tier_limits.limit_for_resource(key.resource.as_deref())
             }
+            RateLimitScope::Service => self
+                .config
+                .per_service
+                .limit_for_resource(key.resource.as_deref()),
             RateLimitScope::Resource => {
                 let resource = key.resource.as_deref().unwrap_or("default");
                 self.config
@@ -83,9 +76,9 @@ impl InMemoryRateLimiter {
         }
     }
 
-    /// Get current timestamp as unix seconds.
-    fn now_secs() -> u64 {
-        Timestamp::now().as_unix_secs()
+    /// Get current timestamp as unix milliseconds.
+    fn now_ms() -> u64 {
+        Timestamp::now().as_datetime().timestamp_millis() as u64
     }
 }
 
@@ -94,93 +87,71 @@ impl RateLimiter for InMemoryRateLimiter {
     async fn check(&self, key: RateLimitKey) -> Result<RateLimitResult, RateLimitError> {
         let redis_key = key.to_redis_key();
         let (limit, window_secs) = self.limits_for(&key);
-        let now = Self::now_secs();
-
-        let mut windows = self.windows.write().await;
-
-        // Get or create window state
-        let state = windows.entry(redis_key.clone()).or_insert_with(|| WindowState {
-            count: 0,
-            window_start: now,
-            window_secs,
-        });
-
-        // Check if window has expired
-        let window_end = state.window_start + state.window_secs as u64;
-        if now >= window_end {
-            // Reset window
-            state.count = 0;
-            state.window_start = now;
-        }
-
-        // Check limit
-        if state.count >= limit {
-            let retry_after = (state.window_start + state.window_secs as u64)
-                .saturating_sub(now) as u32;
-
-            return Ok(RateLimitResult::Denied(RateLimitDenied {
+        let now = Self::now_ms();
+
+        let mut states = self.states.write().await;
+        let state = states
+            .entry(redis_key)
+            .or_insert_with(|| self.config.algorithm.initial_state(now, limit));
+
+        match state.check(now, limit, window_secs) {
+            Decision::Allowed {
+                remaining,
+                reset_after_ms,
+            } => Ok(RateLimitResult::Allowed(RateLimitStatus {
                 limit,
-                retry_after_secs: retry_after.max(1),
-                scope: key.scope,
-                message: format!(
-                    "Rate limit exceeded for {}. Retry after {} seconds.",
-                    key.scope, retry_after
-                ),
-            }));
+                remaining,
+                reset_at: Timestamp::from_unix_secs(ceil_secs(now + reset_after_ms)),
+                window_secs,
+            })),
+            Decision::Denied { retry_after_ms } => {
+                let retry_after = ceil_secs(retry_after_ms) as u32;
+                Ok(RateLimitResult::Denied(RateLimitDenied {
+                    limit,
+                    retry_after_secs: retry_after.max(1),
+                    scope: key.scope,
+                    message: format!(
+                        "Rate limit exceeded for {}. Retry after {} seconds.",
+                        key.scope, retry_after
+                    ),
+                }))
+            }
         }
-
-        // Increment counter
-        state.count += 1;
-        let remaining = limit.saturating_sub(state.count);
-        let reset_at = Timestamp::from_unix_secs(state.window_start + state.window_secs as u64);
-
-        Ok(RateLimitResult::Allowed(RateLimitStatus {
-            limit,
-            remaining,
-            reset_at,
-            window_secs,
-        }))
     }
 
     async fn status(&self, key: RateLimitKey) -> Result<RateLimitStatus, RateLimitError> {
         let redis_key = key.to_redis_key();
         let (limit, window_secs) = self.limits_for(&key);
-        let now = Self::now_secs();
+        let now = Self::now_ms();
 
-        let windows = self.windows.read().await;
+        let states = self.states.read().await;
 
-        let (count, window_start) = windows
+        let (remaining, reset_after_ms) = states
             .get(&redis_key)
-            .map(|state| {
-                // Check if window is still valid
-                let window_end = state.window_start + state.window_secs as u64;
-                if now >= window_end {
-                    (0, now) // Window expired
-                } else {
-                    (state.count, state.window_start)
-                }
-            })
-            .unwrap_or((0, now));
-
-        let remaining = limit.saturating_sub(count);
-        let reset_at = Timestamp::from_unix_secs(window_start + window_secs as u64);
+            .map(|state| state.peek(now, limit, window_secs))
+            .unwrap_or((limit, window_secs as u64 * 1000));
 
         Ok(RateLimitStatus {
             limit,
             remaining,
-            reset_at,
+            reset_at: Timestamp::from_unix_secs(ceil_secs(now + reset_after_ms)),
             window_secs,
         })
     }
 
     async fn reset(&self, key: RateLimitKey) -> Result<(), RateLimitError> {
         let redis_key = key.to_redis_key();
-        let mut windows = self.windows.write().await;
-        windows.remove(&redis_key);
+        let mut states = self.states.write().await;
+        states.remove(&redis_key);
         Ok(())
     }
 }
 
+/// Rounds milliseconds up to whole seconds.
+fn ceil_secs(ms: u64) -> u64 {
+    ms.div_ceil(1000)
+}
+
 /// In-memory rate limiter with tier awareness.
 ///
 /// Extends InMemoryRateLimiter to support per-user tier lookups.
@@ -237,6 +208,7 @@ impl RateLimiter for TierAwareRateLimiter {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::adapters::rate_limiter::RateLimitAlgorithm;
     use crate::domain::foundation::UserId;
 
     fn test_user_id() -> UserId {
@@ -398,6 +370,30 @@ mod tests {
 
     // ─── TierAwareRateLimiter Tests ───────────────────────────────────
 
+    #[tokio::test]
+    async fn service_bucket_is_independent_of_user_bucket() {
+        let mut config = RateLimitConfig::default();
+        config.per_service.general_requests_per_minute = 2;
+        let limiter = InMemoryRateLimiter::new(config);
+
+        let service = RateLimitKey::service("sync-worker");
+        assert!(limiter.check(service.clone()).await.unwrap().is_allowed());
+        assert!(limiter.check(service.clone()).await.unwrap().is_allowed());
+
+        let result = limiter.check(service).await.unwrap();
+        match result {
+            RateLimitResult::Denied(denied) => {
+                assert_eq!(denied.limit, 2);
+                assert_eq!(denied.scope, RateLimitScope::Service);
+            }
+            RateLimitResult::Allowed(_) => panic!("service limit should be exhausted"),
+        }
+
+        // A user with the same identifier keeps their own quota
+        let user = RateLimitKey::user(&UserId::new("sync-worker").unwrap());
+        assert!(limiter.check(user).await.unwrap().is_allowed());
+    }
+
     #[tokio::test]
     async fn tier_aware_limiter_defaults_to_free() {
         let limiter = TierAwareRateLimiter::new(RateLimitConfig::default());
@@ -416,6 +412,64 @@ mod tests {
         assert_eq!(tier, MembershipTier::Annual);
     }
 
+    // ─── Algorithm Selection Tests ────────────────────────────────────
+
+    #[tokio::test]
+    async fn sliding_window_denies_at_limit() {
+        let mut config = RateLimitConfig {
+            algorithm: RateLimitAlgorithm::SlidingWindow,
+            ..RateLimitConfig::default()
+        };
+        config.per_ip.requests_per_minute = 3;
+        let limiter = InMemoryRateLimiter::new(config);
+        let key = RateLimitKey::ip("10.0.0.3");
+
+        for _ in 0..3 {
+            assert!(limiter.check(key.clone()).await.unwrap().is_allowed());
+        }
+        let result = limiter.check(key.clone()).await.unwrap();
+        assert!(result.is_denied());
+
+        let status = limiter.status(key).await.unwrap();
+        assert_eq!(status.remaining, 0);
+    }
+
+    #[tokio::test]
+    async fn token_bucket_retry_after_is_refill_interval() {
+        let mut config = RateLimitConfig {
+            algorithm: RateLimitAlgorithm::TokenBucket,
+            ..RateLimitConfig::default()
+        };
+        config.per_ip.requests_per_minute = 6;
+        let limiter = InMemoryRateLimiter::new(config);
+        let key = RateLimitKey::ip("10.0.0.4");
+
+        for _ in 0..6 {
+            assert!(limiter.check(key.clone()).await.unwrap().is_allowed());
+        }
+
+        // One token refills every 10 seconds, not at the end of the window
+        match limiter.check(key).await.unwrap() {
+            RateLimitResult::Denied(denied) => assert!(denied.retry_after_secs <= 10),
+            RateLimitResult::Allowed(_) => panic!("Bucket should be empty"),
+        }
+    }
+
+    #[tokio::test]
+    async fn gcra_status_reports_full_quota_for_new_key() {
+        let mut config = RateLimitConfig {
+            algorithm: RateLimitAlgorithm::Gcra,
+            ..RateLimitConfig::default()
+        };
+        config.per_ip.requests_per_minute = 10;
+        let limiter = InMemoryRateLimiter::new(config);
+        let key = RateLimitKey::ip("10.0.0.5");
+
+        assert_eq!(limiter.status(key.clone()).await.unwrap().remaining, 10);
+        limiter.check(key.clone()).await.unwrap();
+        assert_eq!(limiter.status(key).await.unwrap().remaining, 9);
+    }
+
     // ─── Remaining Counter Accuracy Tests ────────────────────────────
 
     #[tokio::test]
@@ -7,6 +7,9 @@
 //! - `InMemoryRateLimiter` - In-memory for testing and single-server
 //! - `RedisRateLimiter` - Redis-backed for production multi-server
 //!
+//! Both run the algorithm selected by `RateLimitConfig::algorithm`:
+//! fixed window (default), sliding window, token bucket, or GCRA.
+//!
 //! ## Usage
 //!
 //! ```ignore
@@ -21,10 +24,14 @@
 //! let limiter = RedisRateLimiter::new(redis_client, RateLimitConfig::default());
 //! ```
 
+mod algorithm;
 mod config;
 mod in_memory;
 mod redis;
 
-pub use config::{GlobalLimits, IpLimits, RateLimitConfig, ResourceLimits, TierRateLimits};
+pub use algorithm::RateLimitAlgorithm;
+pub use config::{
+    GlobalLimits, IpLimits, RateLimitConfig, ResourceLimits, ServiceRateLimits, TierRateLimits,
+};
 pub use in_memory::{InMemoryRateLimiter, TierAwareRateLimiter};
 pub use redis::RedisRateLimiter;
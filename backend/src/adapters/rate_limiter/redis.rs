@@ -1,11 +1,13 @@
 //! Redis-backed rate limiter implementation for production deployments.
 //!
-//! Uses a simple fixed-window counter algorithm with Redis INCR + EXPIRE.
-//! Suitable for multi-server deployments.
+//! The default fixed-window algorithm uses Redis INCR + EXPIRE. The other
+//! algorithms run as Lua scripts that read the clock from Redis `TIME`, so
+//! every server agrees on "now". Suitable for multi-server deployments.
 
 use async_trait::async_trait;
+use once_cell::sync::Lazy;
 use redis::aio::MultiplexedConnection;
-use redis::AsyncCommands;
+use redis::{AsyncCommands, Script};
 
 use crate::domain::foundation::Timestamp;
 use crate::domain::membership::MembershipTier;
@@ -14,18 +16,114 @@ use crate::ports::{
     RateLimitStatus, RateLimiter,
 };
 
+use super::algorithm::RateLimitAlgorithm;
 use super::config::RateLimitConfig;
 
+/// Shared preamble: parses arguments and reads the Redis clock in ms.
+///
+/// ARGV: limit, window_ms, consume (1 to take quota, 0 to only inspect).
+/// Every script returns `{allowed, remaining, retry_after_ms, reset_after_ms}`.
+const SCRIPT_PRELUDE: &str = r#"
+local limit = tonumber(ARGV[1])
+local window_ms = tonumber(ARGV[2])
+local consume = ARGV[3] == '1'
+local time = redis.call('TIME')
+local now = tonumber(time[1]) * 1000 + math.floor(tonumber(time[2]) / 1000)
+"#;
+
+/// Sliding-window log in a sorted set scored by admission time.
+///
+/// KEYS[1] is the log, KEYS[2] a sequence counter keeping members unique.
+static SLIDING_WINDOW_SCRIPT: Lazy<Script> = Lazy::new(|| {
+    Script::new(&format!(
+        "{}{}",
+        SCRIPT_PRELUDE,
+        r#"
+redis.call('ZREMRANGEBYSCORE', KEYS[1], '-inf', now - window_ms)
+local count = redis.call('ZCARD', KEYS[1])
+if count >= limit then
+  local oldest = redis.call('ZRANGE', KEYS[1], 0, 0, 'WITHSCORES')
+  local retry = window_ms
+  if oldest[2] then retry = tonumber(oldest[2]) + window_ms - now end
+  return {0, 0, retry, window_ms}
+end
+if consume then
+  local seq = redis.call('INCR', KEYS[2])
+  redis.call('ZADD', KEYS[1], now, now .. ':' .. seq)
+  redis.call('PEXPIRE', KEYS[1], window_ms)
+  redis.call('PEXPIRE', KEYS[2], window_ms)
+  count = count + 1
+end
+return {1, limit - count, 0, window_ms}
+"#
+    ))
+});
+
+/// Token bucket stored as a hash of `tokens` and last refill `ts`.
+static TOKEN_BUCKET_SCRIPT: Lazy<Script> = Lazy::new(|| {
+    Script::new(&format!(
+        "{}{}",
+        SCRIPT_PRELUDE,
+        r#"
+local rate = limit / window_ms
+local state = redis.call('HMGET', KEYS[1], 'tokens', 'ts')
+local tokens = tonumber(state[1]) or limit
+local ts = tonumber(state[2]) or now
+tokens = math.min(limit, tokens + math.max(0, now - ts) * rate)
+ts = math.max(ts, now)
+if tokens < 1 then
+  return {0, 0, math.ceil((1 - tokens) / rate), math.ceil((limit - tokens) / rate)}
+end
+if consume then
+  tokens = tokens - 1
+  redis.call('HSET', KEYS[1], 'tokens', tostring(tokens), 'ts', ts)
+  redis.call('PEXPIRE', KEYS[1], window_ms)
+end
+return {1, math.floor(tokens), 0, math.ceil((limit - tokens) / rate)}
+"#
+    ))
+});
+
+/// GCRA storing only the theoretical arrival time (TAT).
+static GCRA_SCRIPT: Lazy<Script> = Lazy::new(|| {
+    Script::new(&format!(
+        "{}{}",
+        SCRIPT_PRELUDE,
+        r#"
+local interval = math.max(1, math.floor(window_ms / limit))
+local tat = math.max(tonumber(redis.call('GET', KEYS[1])) or now, now)
+local new_tat = tat + interval
+if new_tat - now > window_ms then
+  return {0, 0, new_tat - window_ms - now, tat - now}
+end
+if consume then
+  tat = new_tat
+  redis.call('SET', KEYS[1], tat, 'PX', tat - now)
+end
+return {1, math.min(limit, math.floor((window_ms - (tat - now)) / interval)), 0, tat - now}
+"#
+    ))
+});
+
+/// Result of a scripted algorithm, in milliseconds.
+struct ScriptOutcome {
+    allowed: bool,
+    remaining: u32,
+    retry_after_ms: u64,
+    reset_after_ms: u64,
+}
+
 /// Redis-backed rate limiter for production multi-server deployments.
 ///
-/// Uses a fixed-window counter algorithm:
+/// With the default fixed-window algorithm:
 /// 1. INCR the key to increment the counter
 /// 2. If count is 1, set EXPIRE for the window duration
 /// 3. If count > limit, deny the request
 ///
 /// This approach is simple and atomic but has a known edge case at window
-/// boundaries where requests can briefly exceed limits. For most use cases,
-/// this is acceptable behavior.
+/// boundaries where requests can briefly exceed limits. Sliding window,
+/// token bucket, and GCRA avoid it, each running as one atomic script
+/// under an algorithm-specific key suffix.
 #[derive(Clone)]
 pub struct RedisRateLimiter {
     conn: MultiplexedConnection,
@@ -58,6 +156,10 @@ impl RedisRateLimiter {
                 let tier_limits = self.config.limits_for_tier(self.default_tier);
                 tier_limits.limit_for_resource(key.resource.as_deref())
             }
+            RateLimitScope::Service => self
+                .config
+                .per_service
+                .limit_for_resource(key.resource.as_deref()),
             RateLimitScope::Resource => {
                 let resource = key.resource.as_deref().unwrap_or("default");
                 self.config
@@ -68,6 +170,79 @@ impl RedisRateLimiter {
             }
         }
     }
+
+    /// Keys touched by a scripted algorithm.
+    ///
+    /// Suffixed per algorithm so switching algorithms never reads a value
+    /// of the wrong Redis type.
+    fn script_keys(redis_key: &str, algorithm: RateLimitAlgorithm) -> Vec<String> {
+        match algorithm {
+            RateLimitAlgorithm::FixedWindow => vec![redis_key.to_string()],
+            RateLimitAlgorithm::SlidingWindow => {
+                vec![format!("{}:sw", redis_key), format!("{}:sw:seq", redis_key)]
+            }
+            RateLimitAlgorithm::TokenBucket => vec![format!("{}:tb", redis_key)],
+            RateLimitAlgorithm::Gcra => vec![format!("{}:gcra", redis_key)],
+        }
+    }
+
+    /// Runs the script for a non-fixed-window algorithm.
+    async fn run_script(
+        &self,
+        redis_key: &str,
+        limit: u32,
+        window_secs: u32,
+        consume: bool,
+    ) -> Result<ScriptOutcome, RateLimitError> {
+        let window_ms = window_secs as u64 * 1000;
+        if limit == 0 {
+            return Ok(ScriptOutcome {
+                allowed: false,
+                remaining: 0,
+                retry_after_ms: window_ms,
+                reset_after_ms: window_ms,
+            });
+        }
+
+        let script: &Script = match self.config.algorithm {
+            RateLimitAlgorithm::SlidingWindow => &SLIDING_WINDOW_SCRIPT,
+            RateLimitAlgorithm::TokenBucket => &TOKEN_BUCKET_SCRIPT,
+            RateLimitAlgorithm::Gcra => &GCRA_SCRIPT,
+            RateLimitAlgorithm::FixedWindow => {
+                unreachable!("fixed window does not use a script")
+            }
+        };
+
+        let mut invocation = script.prepare_invoke();
+        for key in Self::script_keys(redis_key, self.config.algorithm) {
+            invocation.key(key);
+        }
+        invocation.arg(limit).arg(window_ms).arg(if consume { 1 } else { 0 });
+
+        let mut conn = self.conn.clone();
+        let reply: Vec<i64> = invocation
+            .invoke_async(&mut conn)
+            .await
+            .map_err(|e: redis::RedisError| RateLimitError::Unavailable(e.to_string()))?;
+
+        match reply.as_slice() {
+            [allowed, remaining, retry_after_ms, reset_after_ms] => Ok(ScriptOutcome {
+                allowed: *allowed == 1,
+                remaining: (*remaining).max(0) as u32,
+                retry_after_ms: (*retry_after_ms).max(0) as u64,
+                reset_after_ms: (*reset_after_ms).max(0) as u64,
+            }),
+            _ => Err(RateLimitError::Unavailable(format!(
+                "Unexpected rate limit script reply: {:?}",
+                reply
+            ))),
+        }
+    }
+}
+
+/// Rounds milliseconds up to whole seconds.
+fn ceil_secs(ms: u64) -> u64 {
+    ms.div_ceil(1000)
 }
 
 #[async_trait]
@@ -76,6 +251,31 @@ impl RateLimiter for RedisRateLimiter {
         let redis_key = key.to_redis_key();
         let (limit, window_secs) = self.limits_for(&key);
 
+        if self.config.algorithm != RateLimitAlgorithm::FixedWindow {
+            let outcome = self.run_script(&redis_key, limit, window_secs, true).await?;
+            let now = Timestamp::now().as_unix_secs();
+
+            if !outcome.allowed {
+                let retry_after = ceil_secs(outcome.retry_after_ms) as u32;
+                return Ok(RateLimitResult::Denied(RateLimitDenied {
+                    limit,
+                    retry_after_secs: retry_after.max(1),
+                    scope: key.scope,
+                    message: format!(
+                        "Rate limit exceeded for {}. Retry after {} seconds.",
+                        key.scope, retry_after
+                    ),
+                }));
+            }
+
+            return Ok(RateLimitResult::Allowed(RateLimitStatus {
+                limit,
+                remaining: outcome.remaining,
+                reset_at: Timestamp::from_unix_secs(now + ceil_secs(outcome.reset_after_ms)),
+                window_secs,
+            }));
+        }
+
         let mut conn = self.conn.clone();
 
         // Atomic increment
@@ -128,6 +328,17 @@ impl RateLimiter for RedisRateLimiter {
         let redis_key = key.to_redis_key();
         let (limit, window_secs) = self.limits_for(&key);
 
+        if self.config.algorithm != RateLimitAlgorithm::FixedWindow {
+            let outcome = self.run_script(&redis_key, limit, window_secs, false).await?;
+            let now = Timestamp::now().as_unix_secs();
+            return Ok(RateLimitStatus {
+                limit,
+                remaining: outcome.remaining,
+                reset_at: Timestamp::from_unix_secs(now + ceil_secs(outcome.reset_after_ms)),
+                window_secs,
+            });
+        }
+
         let mut conn = self.conn.clone();
 
         // Get current count (or 0 if not set)
@@ -161,7 +372,7 @@ impl RateLimiter for RedisRateLimiter {
         let redis_key = key.to_redis_key();
         let mut conn = self.conn.clone();
 
-        conn.del::<_, ()>(&redis_key)
+        conn.del::<_, ()>(Self::script_keys(&redis_key, self.config.algorithm))
             .await
             .map_err(|e: redis::RedisError| RateLimitError::Unavailable(e.to_string()))?;
 
@@ -0,0 +1,181 @@
+//! CircuitBreakingAIProvider - Guards an AI provider with a circuit breaker.
+//!
+//! Retryable errors (unavailable, rate limited, network, timeout) count as
+//! failures. Streams count as failed if they fail to open or error before
+//! finishing. While the circuit is open calls fail fast with a retryable
+//! `AIError::Unavailable`, so `FailoverAIProvider` moves on to the next
+//! provider.
+
+use async_trait::async_trait;
+use futures::{Stream, StreamExt};
+use std::pin::Pin;
+use std::sync::Arc;
+
+use crate::ports::{
+    AIError, AIProvider, BatchJob, BatchRequest, BatchResult, CircuitBreaker, CompletionRequest,
+    CompletionResponse, ProviderInfo, StreamChunk,
+};
+
+use super::circuit_breaker::guarded;
+
+/// AI provider decorator that stops calling a failing provider.
+pub struct CircuitBreakingAIProvider<A> {
+    inner: A,
+    breaker: Arc<dyn CircuitBreaker>,
+}
+
+impl<A: AIProvider> CircuitBreakingAIProvider<A> {
+    pub fn new(inner: A, breaker: Arc<dyn CircuitBreaker>) -> Self {
+        Self { inner, breaker }
+    }
+
+    fn rejected(&self) -> AIError {
+        AIError::unavailable(format!(
+            "circuit breaker open for {}",
+            self.inner.provider_info().name
+        ))
+    }
+}
+
+#[async_trait]
+impl<A: AIProvider> AIProvider for CircuitBreakingAIProvider<A> {
+    async fn complete(&self, request: CompletionRequest) -> Result<CompletionResponse, AIError> {
+        guarded(
+            self.breaker.as_ref(),
+            self.inner.complete(request),
+            AIError::is_retryable,
+            || self.rejected(),
+        )
+        .await
+    }
+
+    async fn stream_complete(
+        &self,
+        request: CompletionRequest,
+    ) -> Result<Pin<Box<dyn Stream<Item = Result<StreamChunk, AIError>> + Send>>, AIError> {
+        if !self.breaker.should_allow() {
+            return Err(self.rejected());
+        }
+        let stream = match self.inner.stream_complete(request).await {
+            Ok(stream) => stream,
+            Err(e) => {
+                if e.is_retryable() {
+                    self.breaker.record_failure(&e.to_string());
+                } else {
+                    self.breaker.record_success();
+                }
+                return Err(e);
+            }
+        };
+
+        // Record the outcome once, on the first error or the final chunk
+        let breaker = Arc::clone(&self.breaker);
+        let mut recorded = false;
+        Ok(Box::pin(stream.inspect(move |chunk| {
+            if recorded {
+                return;
+            }
+            match chunk {
+                Err(e) if e.is_retryable() => breaker.record_failure(&e.to_string()),
+                Err(_) => breaker.record_success(),
+                Ok(chunk) if chunk.is_final() => breaker.record_success(),
+                Ok(_) => return,
+            }
+            recorded = true;
+        })))
+    }
+
+    fn estimate_tokens(&self, text: &str) -> u32 {
+        self.inner.estimate_tokens(text)
+    }
+
+    fn provider_info(&self) -> ProviderInfo {
+        self.inner.provider_info()
+    }
+
+    async fn submit_batch(&self, requests: Vec<BatchRequest>) -> Result<BatchJob, AIError> {
+        self.inner.submit_batch(requests).await
+    }
+
+    async fn batch_status(&self, batch_id: &str) -> Result<BatchJob, AIError> {
+        self.inner.batch_status(batch_id).await
+    }
+
+    async fn batch_results(&self, batch_id: &str) -> Result<Vec<BatchResult>, AIError> {
+        self.inner.batch_results(batch_id).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::adapters::ai::{MockAIProvider, MockError};
+    use crate::adapters::resilience::InMemoryCircuitBreaker;
+    use crate::domain::foundation::{ConversationId, SessionId, UserId};
+    use crate::ports::{CircuitBreakerConfig, CircuitState, MessageRole, RequestMetadata};
+
+    fn make_request() -> CompletionRequest {
+        let metadata = RequestMetadata::new(
+            UserId::new("test-user").unwrap(),
+            SessionId::new(),
+            ConversationId::new(),
+            "trace-123",
+        );
+        CompletionRequest::new(metadata).with_message(MessageRole::User, "Hello")
+    }
+
+    fn breaker() -> Arc<dyn CircuitBreaker> {
+        Arc::new(InMemoryCircuitBreaker::new(CircuitBreakerConfig {
+            failure_threshold: 1,
+            ..CircuitBreakerConfig::default()
+        }))
+    }
+
+    #[tokio::test]
+    async fn opens_on_unavailable_and_then_fails_fast() {
+        let breaker = breaker();
+        let mock = MockAIProvider::new().with_error(MockError::Unavailable {
+            message: "overloaded".to_string(),
+        });
+        let provider = CircuitBreakingAIProvider::new(mock, breaker.clone());
+
+        assert!(provider.complete(make_request()).await.is_err());
+        assert_eq!(breaker.state(), CircuitState::Open);
+        assert!(breaker.metrics().last_error.unwrap().contains("overloaded"));
+
+        let err = provider.complete(make_request()).await.unwrap_err();
+        assert!(err.is_retryable());
+        assert_eq!(provider.inner.call_count(), 1);
+    }
+
+    #[tokio::test]
+    async fn non_retryable_errors_do_not_trip() {
+        let breaker = breaker();
+        let mock = MockAIProvider::new().with_error(MockError::ContentFiltered {
+            reason: "policy".to_string(),
+        });
+        let provider = CircuitBreakingAIProvider::new(mock, breaker.clone());
+
+        assert!(provider.complete(make_request()).await.is_err());
+        assert_eq!(breaker.state(), CircuitState::Closed);
+    }
+
+    #[tokio::test]
+    async fn completed_streams_count_as_successes() {
+        let breaker = breaker();
+        let provider = CircuitBreakingAIProvider::new(
+            MockAIProvider::new().with_response("hi"),
+            breaker.clone(),
+        );
+
+        let chunks: Vec<_> = provider
+            .stream_complete(make_request())
+            .await
+            .unwrap()
+            .collect()
+            .await;
+
+        assert!(chunks.iter().all(|c| c.is_ok()));
+        assert_eq!(breaker.metrics().total_successes, 1);
+    }
+}
@@ -0,0 +1,285 @@
+//! InMemoryCircuitBreaker - Process-local circuit breaker.
+//!
+//! Each server keeps its own state; a service failing for one instance is
+//! usually failing for all of them, so sharing state is not worth a round
+//! trip per request.
+
+use std::collections::VecDeque;
+use std::fmt::Display;
+use std::future::Future;
+use std::sync::Mutex;
+use std::time::Instant;
+
+use crate::domain::foundation::Timestamp;
+use crate::ports::{CircuitBreaker, CircuitBreakerConfig, CircuitBreakerMetrics, CircuitState};
+
+/// Circuit breaker holding its state in memory.
+pub struct InMemoryCircuitBreaker {
+    config: CircuitBreakerConfig,
+    inner: Mutex<Inner>,
+}
+
+struct Inner {
+    state: CircuitState,
+    /// When the circuit last opened.
+    opened_at: Option<Instant>,
+    forced_open: bool,
+    /// Failures counting toward the threshold, oldest first.
+    failures: VecDeque<Instant>,
+    /// Successes in the current half-open period.
+    half_open_successes: u32,
+    /// Requests let through in the current half-open period and not yet
+    /// recorded.
+    half_open_in_flight: u32,
+    total_successes: u64,
+    total_failures: u64,
+    times_opened: u64,
+    last_error: Option<String>,
+    last_failure_at: Option<Timestamp>,
+}
+
+impl InMemoryCircuitBreaker {
+    pub fn new(config: CircuitBreakerConfig) -> Self {
+        Self {
+            config,
+            inner: Mutex::new(Inner {
+                state: CircuitState::Closed,
+                opened_at: None,
+                forced_open: false,
+                failures: VecDeque::new(),
+                half_open_successes: 0,
+                half_open_in_flight: 0,
+                total_successes: 0,
+                total_failures: 0,
+                times_opened: 0,
+                last_error: None,
+                last_failure_at: None,
+            }),
+        }
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, Inner> {
+        let mut inner = self.inner.lock().unwrap_or_else(|e| e.into_inner());
+        self.refresh(&mut inner, Instant::now());
+        inner
+    }
+
+    /// Moves an open circuit to half-open once the recovery timeout passes,
+    /// and forgets failures that fell out of the window.
+    fn refresh(&self, inner: &mut Inner, now: Instant) {
+        if inner.state == CircuitState::Open && !inner.forced_open {
+            if let Some(opened_at) = inner.opened_at {
+                if now.duration_since(opened_at) >= self.config.recovery_timeout {
+                    inner.state = CircuitState::HalfOpen;
+                    inner.half_open_successes = 0;
+                    inner.half_open_in_flight = 0;
+                }
+            }
+        }
+        if let Some(window) = self.config.failure_window {
+            while inner
+                .failures
+                .front()
+                .is_some_and(|at| now.duration_since(*at) > window)
+            {
+                inner.failures.pop_front();
+            }
+        }
+    }
+
+    fn open(inner: &mut Inner) {
+        inner.state = CircuitState::Open;
+        inner.opened_at = Some(Instant::now());
+        inner.failures.clear();
+        inner.half_open_successes = 0;
+        inner.half_open_in_flight = 0;
+        inner.times_opened += 1;
+    }
+}
+
+impl CircuitBreaker for InMemoryCircuitBreaker {
+    fn state(&self) -> CircuitState {
+        self.lock().state
+    }
+
+    fn should_allow(&self) -> bool {
+        let mut inner = self.lock();
+        match inner.state {
+            CircuitState::Closed => true,
+            CircuitState::Open => false,
+            CircuitState::HalfOpen => {
+                if inner.half_open_in_flight < self.config.half_open_max_requests {
+                    inner.half_open_in_flight += 1;
+                    true
+                } else {
+                    false
+                }
+            }
+        }
+    }
+
+    fn record_success(&self) {
+        let mut inner = self.lock();
+        inner.total_successes += 1;
+        match inner.state {
+            CircuitState::Closed => inner.failures.clear(),
+            CircuitState::HalfOpen => {
+                inner.half_open_in_flight = inner.half_open_in_flight.saturating_sub(1);
+                inner.half_open_successes += 1;
+                if inner.half_open_successes >= self.config.success_threshold {
+                    inner.state = CircuitState::Closed;
+                    inner.opened_at = None;
+                    inner.failures.clear();
+                    tracing::info!("Circuit closed after recovery");
+                }
+            }
+            CircuitState::Open => {}
+        }
+    }
+
+    fn record_failure(&self, error: &str) {
+        let mut inner = self.lock();
+        inner.total_failures += 1;
+        inner.last_error = Some(error.to_string());
+        inner.last_failure_at = Some(Timestamp::now());
+        match inner.state {
+            CircuitState::Closed => {
+                inner.failures.push_back(Instant::now());
+                if inner.failures.len() as u32 >= self.config.failure_threshold {
+                    Self::open(&mut inner);
+                    tracing::warn!(error, "Circuit opened");
+                }
+            }
+            CircuitState::HalfOpen => {
+                Self::open(&mut inner);
+                tracing::warn!(error, "Circuit reopened during recovery");
+            }
+            CircuitState::Open => {}
+        }
+    }
+
+    fn reset(&self) {
+        let mut inner = self.lock();
+        inner.state = CircuitState::Closed;
+        inner.opened_at = None;
+        inner.forced_open = false;
+        inner.failures.clear();
+        inner.half_open_successes = 0;
+        inner.half_open_in_flight = 0;
+    }
+
+    fn force_open(&self) {
+        let mut inner = self.lock();
+        if inner.state != CircuitState::Open {
+            Self::open(&mut inner);
+        }
+        inner.forced_open = true;
+    }
+
+    fn metrics(&self) -> CircuitBreakerMetrics {
+        let inner = self.lock();
+        let time_until_half_open = match (inner.state, inner.opened_at) {
+            (CircuitState::Open, Some(opened_at)) if !inner.forced_open => Some(
+                self.config
+                    .recovery_timeout
+                    .saturating_sub(opened_at.elapsed()),
+            ),
+            _ => None,
+        };
+        CircuitBreakerMetrics {
+            state: Some(inner.state),
+            total_successes: inner.total_successes,
+            total_failures: inner.total_failures,
+            times_opened: inner.times_opened,
+            current_failures: inner.failures.len() as u32,
+            current_successes: inner.half_open_successes,
+            time_until_half_open,
+            forced_open: inner.forced_open,
+            last_error: inner.last_error.clone(),
+            last_failure_at: inner.last_failure_at,
+        }
+    }
+}
+
+/// Runs `call` if the breaker allows it, recording the outcome. Errors for
+/// which `trips` is false mean the service answered, so they count as
+/// successes; `rejected` builds the error returned while the circuit is open.
+pub(super) async fn guarded<T, E: Display>(
+    breaker: &dyn CircuitBreaker,
+    call: impl Future<Output = Result<T, E>>,
+    trips: impl Fn(&E) -> bool,
+    rejected: impl FnOnce() -> E,
+) -> Result<T, E> {
+    if !breaker.should_allow() {
+        return Err(rejected());
+    }
+    let result = call.await;
+    match &result {
+        Err(e) if trips(e) => breaker.record_failure(&e.to_string()),
+        _ => breaker.record_success(),
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    fn breaker(recovery_timeout: Duration) -> InMemoryCircuitBreaker {
+        InMemoryCircuitBreaker::new(CircuitBreakerConfig {
+            failure_threshold: 2,
+            recovery_timeout,
+            success_threshold: 1,
+            half_open_max_requests: 1,
+            failure_window: None,
+        })
+    }
+
+    #[test]
+    fn opens_after_threshold_and_recovers_through_half_open() {
+        let breaker = breaker(Duration::ZERO);
+        breaker.record_failure("timeout");
+        assert_eq!(breaker.state(), CircuitState::Closed);
+
+        breaker.record_failure("timeout");
+        // A zero recovery timeout moves straight on to half-open
+        assert_eq!(breaker.state(), CircuitState::HalfOpen);
+        assert!(breaker.should_allow());
+        assert!(!breaker.should_allow(), "only one trial request at a time");
+
+        breaker.record_success();
+        assert_eq!(breaker.state(), CircuitState::Closed);
+        let metrics = breaker.metrics();
+        assert_eq!(metrics.times_opened, 1);
+        assert_eq!(metrics.total_failures, 2);
+        assert_eq!(metrics.last_error.as_deref(), Some("timeout"));
+        assert!(metrics.last_failure_at.is_some());
+    }
+
+    #[test]
+    fn success_in_closed_state_clears_failures() {
+        let breaker = breaker(Duration::from_secs(60));
+        breaker.record_failure("timeout");
+        breaker.record_success();
+        breaker.record_failure("timeout");
+
+        assert_eq!(breaker.state(), CircuitState::Closed);
+        assert_eq!(breaker.metrics().current_failures, 1);
+    }
+
+    #[test]
+    fn forced_open_stays_open_until_reset() {
+        let breaker = breaker(Duration::ZERO);
+        breaker.force_open();
+
+        assert_eq!(breaker.state(), CircuitState::Open);
+        assert!(!breaker.should_allow());
+        assert!(breaker.metrics().forced_open);
+        assert_eq!(breaker.metrics().time_until_half_open, None);
+
+        breaker.reset();
+        assert_eq!(breaker.state(), CircuitState::Closed);
+        assert!(!breaker.metrics().forced_open);
+    }
+}
@@ -0,0 +1,81 @@
+//! CircuitBreakingEmailSender - Guards an email sender with a circuit breaker.
+//!
+//! `EmailError::Unavailable` counts as a failure; rejected messages do not.
+//! While the circuit is open sends fail fast as unavailable, so
+//! `FailoverEmailSender` moves on and retry queues back off.
+
+use async_trait::async_trait;
+use std::sync::Arc;
+
+use crate::ports::{CircuitBreaker, EmailError, EmailMessage, EmailSender};
+
+use super::circuit_breaker::guarded;
+
+/// Email sender decorator that stops calling a failing provider.
+pub struct CircuitBreakingEmailSender<S> {
+    inner: S,
+    breaker: Arc<dyn CircuitBreaker>,
+}
+
+impl<S: EmailSender> CircuitBreakingEmailSender<S> {
+    pub fn new(inner: S, breaker: Arc<dyn CircuitBreaker>) -> Self {
+        Self { inner, breaker }
+    }
+}
+
+#[async_trait]
+impl<S: EmailSender> EmailSender for CircuitBreakingEmailSender<S> {
+    async fn send(&self, message: &EmailMessage) -> Result<(), EmailError> {
+        guarded(
+            self.breaker.as_ref(),
+            self.inner.send(message),
+            EmailError::is_retryable,
+            || EmailError::Unavailable(format!("circuit breaker open for {}", self.inner.name())),
+        )
+        .await
+    }
+
+    fn name(&self) -> &'static str {
+        self.inner.name()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::adapters::email::InMemoryEmailSender;
+    use crate::adapters::resilience::InMemoryCircuitBreaker;
+    use crate::ports::{CircuitBreakerConfig, CircuitState};
+
+    fn message() -> EmailMessage {
+        EmailMessage {
+            to: "user@example.com".to_string(),
+            subject: "Hello".to_string(),
+            text_body: "Hi".to_string(),
+            attachments: vec![],
+        }
+    }
+
+    #[tokio::test]
+    async fn rejections_pass_through_and_outages_open_the_circuit() {
+        let inner = InMemoryEmailSender::new();
+        let breaker: Arc<dyn CircuitBreaker> =
+            Arc::new(InMemoryCircuitBreaker::new(CircuitBreakerConfig {
+                failure_threshold: 1,
+                ..CircuitBreakerConfig::default()
+            }));
+        let sender = CircuitBreakingEmailSender::new(inner.clone(), breaker.clone());
+
+        inner.fail_next(EmailError::Rejected("bad address".to_string()));
+        assert!(sender.send(&message()).await.is_err());
+        assert_eq!(breaker.state(), CircuitState::Closed);
+
+        inner.fail_next(EmailError::Unavailable("timeout".to_string()));
+        assert!(sender.send(&message()).await.is_err());
+        assert_eq!(breaker.state(), CircuitState::Open);
+
+        let err = sender.send(&message()).await.unwrap_err();
+        assert!(err.is_retryable());
+        assert!(inner.sent().is_empty());
+    }
+}
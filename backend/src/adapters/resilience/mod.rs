@@ -0,0 +1,23 @@
+//! Circuit breakers for external services.
+//!
+//! - `InMemoryCircuitBreaker` - Process-local `CircuitBreaker`
+//! - `CircuitBreakerRegistry` - Named breakers, listed and controlled
+//!   through the admin circuit breaker API
+//! - `CircuitBreakingAIProvider` - `AIProvider` decorator
+//! - `CircuitBreakingPaymentProvider` - `PaymentProvider` decorator (Stripe)
+//! - `CircuitBreakingEmailSender` - `EmailSender` decorator
+//!
+//! Create each breaker through the registry when building the adapter it
+//! guards, so incidents can be seen and handled from the admin API.
+
+mod ai_provider;
+mod circuit_breaker;
+mod email_sender;
+mod payment_provider;
+mod registry;
+
+pub use ai_provider::CircuitBreakingAIProvider;
+pub use circuit_breaker::InMemoryCircuitBreaker;
+pub use email_sender::CircuitBreakingEmailSender;
+pub use payment_provider::CircuitBreakingPaymentProvider;
+pub use registry::{CircuitBreakerRegistry, GuardedService, RegisteredBreaker};
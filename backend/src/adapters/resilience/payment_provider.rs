@@ -0,0 +1,177 @@
+//! CircuitBreakingPaymentProvider - Guards a payment provider with a circuit
+//! breaker.
+//!
+//! Network errors, rate limiting and provider-side errors count as failures;
+//! declined cards and missing resources do not. Webhook verification is a
+//! local signature check and is never blocked.
+
+use async_trait::async_trait;
+use std::sync::Arc;
+
+use crate::domain::membership::MembershipTier;
+use crate::ports::{
+    CheckoutSession, CircuitBreaker, CreateCheckoutRequest, CreateCustomerRequest,
+    CreateSubscriptionRequest, Customer, MeteredUsageReport, PaymentError, PaymentErrorCode,
+    PaymentProvider, PortalSession, Subscription, WebhookEvent,
+};
+
+use super::circuit_breaker::guarded;
+
+/// Payment provider decorator that stops calling a failing provider.
+pub struct CircuitBreakingPaymentProvider<P> {
+    inner: P,
+    breaker: Arc<dyn CircuitBreaker>,
+}
+
+impl<P: PaymentProvider> CircuitBreakingPaymentProvider<P> {
+    pub fn new(inner: P, breaker: Arc<dyn CircuitBreaker>) -> Self {
+        Self { inner, breaker }
+    }
+
+    async fn call<T>(
+        &self,
+        call: impl std::future::Future<Output = Result<T, PaymentError>>,
+    ) -> Result<T, PaymentError> {
+        guarded(self.breaker.as_ref(), call, trips, || {
+            PaymentError::new(
+                PaymentErrorCode::ProviderError,
+                "Payment provider circuit breaker open",
+            )
+        })
+        .await
+    }
+}
+
+fn trips(error: &PaymentError) -> bool {
+    error.retryable || error.code == PaymentErrorCode::ProviderError
+}
+
+#[async_trait]
+impl<P: PaymentProvider> PaymentProvider for CircuitBreakingPaymentProvider<P> {
+    async fn create_customer(
+        &self,
+        request: CreateCustomerRequest,
+    ) -> Result<Customer, PaymentError> {
+        self.call(self.inner.create_customer(request)).await
+    }
+
+    async fn get_customer(&self, customer_id: &str) -> Result<Option<Customer>, PaymentError> {
+        self.call(self.inner.get_customer(customer_id)).await
+    }
+
+    async fn delete_customer(&self, customer_id: &str) -> Result<(), PaymentError> {
+        self.call(self.inner.delete_customer(customer_id)).await
+    }
+
+    async fn create_subscription(
+        &self,
+        request: CreateSubscriptionRequest,
+    ) -> Result<Subscription, PaymentError> {
+        self.call(self.inner.create_subscription(request)).await
+    }
+
+    async fn get_subscription(
+        &self,
+        subscription_id: &str,
+    ) -> Result<Option<Subscription>, PaymentError> {
+        self.call(self.inner.get_subscription(subscription_id))
+            .await
+    }
+
+    async fn cancel_subscription(
+        &self,
+        subscription_id: &str,
+        at_period_end: bool,
+    ) -> Result<Subscription, PaymentError> {
+        self.call(
+            self.inner
+                .cancel_subscription(subscription_id, at_period_end),
+        )
+        .await
+    }
+
+    async fn update_subscription(
+        &self,
+        subscription_id: &str,
+        new_tier: MembershipTier,
+    ) -> Result<Subscription, PaymentError> {
+        self.call(self.inner.update_subscription(subscription_id, new_tier))
+            .await
+    }
+
+    async fn update_subscription_quantity(
+        &self,
+        subscription_id: &str,
+        quantity: u32,
+    ) -> Result<Subscription, PaymentError> {
+        self.call(
+            self.inner
+                .update_subscription_quantity(subscription_id, quantity),
+        )
+        .await
+    }
+
+    async fn end_trial(&self, subscription_id: &str) -> Result<Subscription, PaymentError> {
+        self.call(self.inner.end_trial(subscription_id)).await
+    }
+
+    async fn report_metered_usage(&self, report: MeteredUsageReport) -> Result<(), PaymentError> {
+        self.call(self.inner.report_metered_usage(report)).await
+    }
+
+    async fn create_checkout_session(
+        &self,
+        request: CreateCheckoutRequest,
+    ) -> Result<CheckoutSession, PaymentError> {
+        self.call(self.inner.create_checkout_session(request)).await
+    }
+
+    async fn create_portal_session(
+        &self,
+        customer_id: &str,
+        return_url: &str,
+    ) -> Result<PortalSession, PaymentError> {
+        self.call(self.inner.create_portal_session(customer_id, return_url))
+            .await
+    }
+
+    async fn verify_webhook(
+        &self,
+        payload: &[u8],
+        signature: &str,
+    ) -> Result<WebhookEvent, PaymentError> {
+        self.inner.verify_webhook(payload, signature).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::adapters::resilience::InMemoryCircuitBreaker;
+    use crate::adapters::stripe::MockPaymentProvider;
+    use crate::ports::{CircuitBreakerConfig, CircuitState};
+
+    #[tokio::test]
+    async fn provider_outages_open_the_circuit_but_declines_do_not() {
+        let mock = MockPaymentProvider::new();
+        let breaker: Arc<dyn CircuitBreaker> =
+            Arc::new(InMemoryCircuitBreaker::new(CircuitBreakerConfig {
+                failure_threshold: 1,
+                ..CircuitBreakerConfig::default()
+            }));
+        let provider = CircuitBreakingPaymentProvider::new(mock, breaker.clone());
+
+        provider
+            .inner
+            .set_error(PaymentError::card_declined("declined"));
+        assert!(provider.get_customer("cus_1").await.is_err());
+        assert_eq!(breaker.state(), CircuitState::Closed);
+
+        provider.inner.set_error(PaymentError::network("timeout"));
+        assert!(provider.get_customer("cus_1").await.is_err());
+        assert_eq!(breaker.state(), CircuitState::Open);
+
+        let err = provider.get_customer("cus_1").await.unwrap_err();
+        assert_eq!(err.code, PaymentErrorCode::ProviderError);
+    }
+}
@@ -0,0 +1,127 @@
+//! CircuitBreakerRegistry - Every circuit breaker the server created.
+//!
+//! Breakers are registered under a unique name when the adapter they guard
+//! is built, so the admin API can list them and act on one by name.
+
+use std::sync::{Arc, RwLock};
+
+use serde::{Deserialize, Serialize};
+
+use crate::ports::{CircuitBreaker, CircuitBreakerConfig};
+
+use super::InMemoryCircuitBreaker;
+
+/// The kind of external service a breaker guards.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum GuardedService {
+    AiProvider,
+    Payment,
+    Email,
+}
+
+/// A breaker and what it guards.
+#[derive(Clone)]
+pub struct RegisteredBreaker {
+    /// Unique name, such as "openai" or "stripe".
+    pub name: String,
+    pub service: GuardedService,
+    pub breaker: Arc<dyn CircuitBreaker>,
+}
+
+/// Named circuit breakers, in registration order.
+#[derive(Default)]
+pub struct CircuitBreakerRegistry {
+    breakers: RwLock<Vec<RegisteredBreaker>>,
+}
+
+impl CircuitBreakerRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates and registers an in-memory breaker, replacing any breaker
+    /// already registered under `name`.
+    pub fn create(
+        &self,
+        name: impl Into<String>,
+        service: GuardedService,
+        config: CircuitBreakerConfig,
+    ) -> Arc<dyn CircuitBreaker> {
+        let breaker: Arc<dyn CircuitBreaker> = Arc::new(InMemoryCircuitBreaker::new(config));
+        self.register(name, service, breaker.clone());
+        breaker
+    }
+
+    /// Registers a breaker, replacing any already registered under `name`.
+    pub fn register(
+        &self,
+        name: impl Into<String>,
+        service: GuardedService,
+        breaker: Arc<dyn CircuitBreaker>,
+    ) {
+        let entry = RegisteredBreaker {
+            name: name.into(),
+            service,
+            breaker,
+        };
+        let mut breakers = self.breakers.write().unwrap_or_else(|e| e.into_inner());
+        match breakers.iter_mut().find(|b| b.name == entry.name) {
+            Some(existing) => *existing = entry,
+            None => breakers.push(entry),
+        }
+    }
+
+    /// All registered breakers.
+    pub fn list(&self) -> Vec<RegisteredBreaker> {
+        self.breakers
+            .read()
+            .unwrap_or_else(|e| e.into_inner())
+            .clone()
+    }
+
+    /// The breaker registered under `name`.
+    pub fn get(&self, name: &str) -> Option<RegisteredBreaker> {
+        self.breakers
+            .read()
+            .unwrap_or_else(|e| e.into_inner())
+            .iter()
+            .find(|b| b.name == name)
+            .cloned()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ports::CircuitState;
+
+    #[test]
+    fn lists_in_registration_order_and_replaces_by_name() {
+        let registry = CircuitBreakerRegistry::new();
+        registry.create(
+            "openai",
+            GuardedService::AiProvider,
+            CircuitBreakerConfig::for_ai_provider(),
+        );
+        registry.create(
+            "stripe",
+            GuardedService::Payment,
+            CircuitBreakerConfig::for_payment_provider(),
+        );
+        let replacement = registry.create(
+            "openai",
+            GuardedService::AiProvider,
+            CircuitBreakerConfig::default(),
+        );
+        replacement.force_open();
+
+        let names: Vec<_> = registry.list().into_iter().map(|b| b.name).collect();
+        assert_eq!(names, vec!["openai", "stripe"]);
+        assert_eq!(
+            registry.get("openai").unwrap().breaker.state(),
+            CircuitState::Open
+        );
+        assert!(registry.get("resend").is_none());
+    }
+}
@@ -0,0 +1,245 @@
+//! In-memory decision embedding repository for testing and development.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use tokio::sync::RwLock;
+
+use crate::domain::foundation::{ComponentType, CycleId, DomainError, Embedding, UserId};
+use crate::ports::{
+    DecisionEmbedding, DecisionEmbeddingRepository, EmbeddingSource, SimilarDecision,
+};
+
+/// Embeddings keyed by cycle, component and source.
+type Embeddings = HashMap<(CycleId, ComponentType, EmbeddingSource), DecisionEmbedding>;
+
+/// Every decision embedding held in memory.
+#[derive(Debug, Clone, Default)]
+pub struct InMemoryDecisionEmbeddingRepository {
+    embeddings: Arc<RwLock<Embeddings>>,
+}
+
+impl InMemoryDecisionEmbeddingRepository {
+    /// Create an empty repository.
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait]
+impl DecisionEmbeddingRepository for InMemoryDecisionEmbeddingRepository {
+    async fn upsert(&self, embedding: &DecisionEmbedding) -> Result<(), DomainError> {
+        self.embeddings.write().await.insert(
+            (
+                embedding.cycle_id,
+                embedding.component_type,
+                embedding.source,
+            ),
+            embedding.clone(),
+        );
+        Ok(())
+    }
+
+    async fn list_for_cycle(
+        &self,
+        cycle_id: &CycleId,
+    ) -> Result<Vec<DecisionEmbedding>, DomainError> {
+        Ok(self
+            .embeddings
+            .read()
+            .await
+            .values()
+            .filter(|embedding| embedding.cycle_id == *cycle_id)
+            .cloned()
+            .collect())
+    }
+
+    async fn list_for_user(&self, user_id: &UserId) -> Result<Vec<DecisionEmbedding>, DomainError> {
+        Ok(self
+            .embeddings
+            .read()
+            .await
+            .values()
+            .filter(|embedding| embedding.user_id == *user_id)
+            .cloned()
+            .collect())
+    }
+
+    async fn find_similar(
+        &self,
+        user_id: &UserId,
+        embedding: &Embedding,
+        model: &str,
+        exclude: Option<CycleId>,
+        limit: u32,
+    ) -> Result<Vec<SimilarDecision>, DomainError> {
+        let mut best: HashMap<CycleId, SimilarDecision> = HashMap::new();
+        for stored in self.embeddings.read().await.values() {
+            if stored.user_id != *user_id
+                || stored.model != model
+                || Some(stored.cycle_id) == exclude
+            {
+                continue;
+            }
+            let similarity = stored.embedding.cosine_similarity(embedding);
+            if best
+                .get(&stored.cycle_id)
+                .is_some_and(|current| current.similarity >= similarity)
+            {
+                continue;
+            }
+            best.insert(
+                stored.cycle_id,
+                SimilarDecision {
+                    cycle_id: stored.cycle_id,
+                    session_id: stored.session_id,
+                    component_type: stored.component_type,
+                    source: stored.source,
+                    similarity,
+                },
+            );
+        }
+
+        let mut similar: Vec<_> = best.into_values().collect();
+        similar.sort_by(|a, b| b.similarity.total_cmp(&a.similarity));
+        similar.truncate(limit as usize);
+        Ok(similar)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::foundation::{SessionId, Timestamp};
+
+    fn user(id: &str) -> UserId {
+        UserId::new(id).unwrap()
+    }
+
+    fn stored(
+        cycle_id: CycleId,
+        component_type: ComponentType,
+        user_id: &str,
+        values: Vec<f32>,
+    ) -> DecisionEmbedding {
+        DecisionEmbedding {
+            cycle_id,
+            component_type,
+            source: EmbeddingSource::ComponentOutput,
+            session_id: SessionId::new(),
+            user_id: user(user_id),
+            content_hash: "hash".to_string(),
+            model: "test-model".to_string(),
+            embedding: Embedding::new(values).unwrap(),
+            updated_at: Timestamp::now(),
+        }
+    }
+
+    #[tokio::test]
+    async fn upsert_replaces_same_cycle_component_and_source() {
+        let repo = InMemoryDecisionEmbeddingRepository::new();
+        let cycle_id = CycleId::new();
+        repo.upsert(&stored(
+            cycle_id,
+            ComponentType::IssueRaising,
+            "u1",
+            vec![1.0, 0.0],
+        ))
+        .await
+        .unwrap();
+        repo.upsert(&stored(
+            cycle_id,
+            ComponentType::IssueRaising,
+            "u1",
+            vec![0.0, 1.0],
+        ))
+        .await
+        .unwrap();
+
+        let embeddings = repo.list_for_cycle(&cycle_id).await.unwrap();
+
+        assert_eq!(embeddings.len(), 1);
+        assert_eq!(embeddings[0].embedding.as_slice(), &[0.0, 1.0]);
+    }
+
+    #[tokio::test]
+    async fn find_similar_ranks_each_cycle_once_by_best_match() {
+        let repo = InMemoryDecisionEmbeddingRepository::new();
+        let close = CycleId::new();
+        let far = CycleId::new();
+        repo.upsert(&stored(
+            close,
+            ComponentType::IssueRaising,
+            "u1",
+            vec![1.0, 0.1],
+        ))
+        .await
+        .unwrap();
+        repo.upsert(&stored(
+            close,
+            ComponentType::Objectives,
+            "u1",
+            vec![0.0, 1.0],
+        ))
+        .await
+        .unwrap();
+        repo.upsert(&stored(
+            far,
+            ComponentType::IssueRaising,
+            "u1",
+            vec![0.2, 1.0],
+        ))
+        .await
+        .unwrap();
+
+        let query = Embedding::new(vec![1.0, 0.0]).unwrap();
+        let similar = repo
+            .find_similar(&user("u1"), &query, "test-model", None, 10)
+            .await
+            .unwrap();
+
+        assert_eq!(similar.len(), 2);
+        assert_eq!(similar[0].cycle_id, close);
+        assert_eq!(similar[0].component_type, ComponentType::IssueRaising);
+        assert_eq!(similar[1].cycle_id, far);
+    }
+
+    #[tokio::test]
+    async fn find_similar_skips_other_users_models_and_excluded_cycle() {
+        let repo = InMemoryDecisionEmbeddingRepository::new();
+        let excluded = CycleId::new();
+        repo.upsert(&stored(
+            excluded,
+            ComponentType::IssueRaising,
+            "u1",
+            vec![1.0, 0.0],
+        ))
+        .await
+        .unwrap();
+        repo.upsert(&stored(
+            CycleId::new(),
+            ComponentType::IssueRaising,
+            "u2",
+            vec![1.0, 0.0],
+        ))
+        .await
+        .unwrap();
+        let mut other_model = stored(
+            CycleId::new(),
+            ComponentType::IssueRaising,
+            "u1",
+            vec![1.0, 0.0],
+        );
+        other_model.model = "other-model".to_string();
+        repo.upsert(&other_model).await.unwrap();
+
+        let query = Embedding::new(vec![1.0, 0.0]).unwrap();
+        let similar = repo
+            .find_similar(&user("u1"), &query, "test-model", Some(excluded), 10)
+            .await
+            .unwrap();
+
+        assert!(similar.is_empty());
+    }
+}
@@ -0,0 +1,8 @@
+//! Search adapters.
+//!
+//! - `InMemoryDecisionEmbeddingRepository` - Decision embeddings held in memory,
+//!   compared by exact cosine similarity
+
+mod in_memory_decision_embedding_repository;
+
+pub use in_memory_decision_embedding_repository::InMemoryDecisionEmbeddingRepository;
@@ -0,0 +1,172 @@
+//! In-memory session archival repository for testing and development.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use tokio::sync::RwLock;
+
+use crate::domain::foundation::{DomainError, SessionId, Timestamp, UserId};
+use crate::domain::membership::MembershipTier;
+use crate::domain::session::{ArchivalDecision, ArchivalPolicy, SessionArchivalSettings};
+use crate::ports::{ArchivalCandidate, SessionArchivalRepository};
+
+#[derive(Debug, Clone)]
+struct TrackedSession {
+    user_id: UserId,
+    title: String,
+    tier: MembershipTier,
+    last_activity: Timestamp,
+}
+
+/// In-memory archival settings, plus the session activity a database would
+/// derive from the sessions and cycles tables.
+#[derive(Debug, Clone, Default)]
+pub struct InMemorySessionArchivalRepository {
+    sessions: Arc<RwLock<HashMap<SessionId, TrackedSession>>>,
+    settings: Arc<RwLock<HashMap<SessionId, SessionArchivalSettings>>>,
+}
+
+impl InMemorySessionArchivalRepository {
+    /// Create an empty repository.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Records an active session's owner tier and last activity.
+    pub async fn track(
+        &self,
+        session_id: SessionId,
+        user_id: UserId,
+        title: impl Into<String>,
+        tier: MembershipTier,
+        last_activity: Timestamp,
+    ) {
+        self.sessions.write().await.insert(
+            session_id,
+            TrackedSession {
+                user_id,
+                title: title.into(),
+                tier,
+                last_activity,
+            },
+        );
+    }
+
+    /// Stops tracking a session, as when it is archived by hand.
+    pub async fn untrack(&self, session_id: &SessionId) {
+        self.sessions.write().await.remove(session_id);
+    }
+}
+
+#[async_trait]
+impl SessionArchivalRepository for InMemorySessionArchivalRepository {
+    async fn find_settings(
+        &self,
+        session_id: &SessionId,
+    ) -> Result<Option<SessionArchivalSettings>, DomainError> {
+        Ok(self.settings.read().await.get(session_id).cloned())
+    }
+
+    async fn save_settings(&self, settings: &SessionArchivalSettings) -> Result<(), DomainError> {
+        self.settings
+            .write()
+            .await
+            .insert(settings.session_id, settings.clone());
+        Ok(())
+    }
+
+    async fn list_due(
+        &self,
+        policy: &ArchivalPolicy,
+        now: Timestamp,
+        limit: u32,
+    ) -> Result<Vec<ArchivalCandidate>, DomainError> {
+        let stored = self.settings.read().await;
+        let mut due: Vec<ArchivalCandidate> = self
+            .sessions
+            .read()
+            .await
+            .iter()
+            .map(|(session_id, tracked)| ArchivalCandidate {
+                session_id: *session_id,
+                user_id: tracked.user_id.clone(),
+                title: tracked.title.clone(),
+                tier: tracked.tier,
+                last_activity: tracked.last_activity,
+                settings: stored.get(session_id).cloned().unwrap_or_else(|| {
+                    SessionArchivalSettings::new(*session_id, tracked.user_id.clone())
+                }),
+            })
+            .filter(|c| {
+                policy.evaluate(c.tier, c.last_activity, &c.settings, now) != ArchivalDecision::Keep
+            })
+            .collect();
+        due.sort_by_key(|c| c.last_activity);
+        due.truncate(limit as usize);
+        Ok(due)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn user() -> UserId {
+        UserId::new("user-1").unwrap()
+    }
+
+    #[tokio::test]
+    async fn lists_only_sessions_the_policy_acts_on() {
+        let repo = InMemorySessionArchivalRepository::new();
+        let now = Timestamp::now();
+        let stale = SessionId::new();
+        let staler = SessionId::new();
+        let fresh = SessionId::new();
+        let excluded = SessionId::new();
+        repo.track(
+            stale,
+            user(),
+            "Stale",
+            MembershipTier::Free,
+            now.minus_days(170),
+        )
+        .await;
+        repo.track(
+            staler,
+            user(),
+            "Staler",
+            MembershipTier::Free,
+            now.minus_days(300),
+        )
+        .await;
+        repo.track(
+            fresh,
+            user(),
+            "Fresh",
+            MembershipTier::Free,
+            now.minus_days(10),
+        )
+        .await;
+        repo.track(
+            excluded,
+            user(),
+            "Pinned",
+            MembershipTier::Free,
+            now.minus_days(300),
+        )
+        .await;
+        let mut settings = SessionArchivalSettings::new(excluded, user());
+        settings.excluded = true;
+        repo.save_settings(&settings).await.unwrap();
+
+        let due = repo
+            .list_due(&ArchivalPolicy::default(), now, 10)
+            .await
+            .unwrap();
+
+        let ids: Vec<_> = due.iter().map(|c| c.session_id).collect();
+        assert_eq!(ids, vec![staler, stale]);
+        assert!(!due[0].settings.excluded);
+    }
+}
@@ -0,0 +1,134 @@
+//! In-memory session cold storage repository for testing and development.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use tokio::sync::RwLock;
+
+use crate::domain::foundation::{CycleId, DomainError, SessionId, Timestamp, UserId};
+use crate::domain::session::ColdStorageRecord;
+use crate::ports::{ColdStorageCandidate, SessionColdStorageRepository};
+
+/// In-memory cold storage records, plus the finished sessions a database
+/// would find in the sessions and cycles tables.
+#[derive(Debug, Clone, Default)]
+pub struct InMemorySessionColdStorageRepository {
+    finished: Arc<RwLock<HashMap<SessionId, (UserId, Timestamp)>>>,
+    records: Arc<RwLock<HashMap<SessionId, ColdStorageRecord>>>,
+}
+
+impl InMemorySessionColdStorageRepository {
+    /// Create an empty repository.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Records an archived session whose cycles are all finished.
+    pub async fn track_finished(
+        &self,
+        session_id: SessionId,
+        user_id: UserId,
+        archived_at: Timestamp,
+    ) {
+        self.finished
+            .write()
+            .await
+            .insert(session_id, (user_id, archived_at));
+    }
+}
+
+#[async_trait]
+impl SessionColdStorageRepository for InMemorySessionColdStorageRepository {
+    async fn save(&self, record: &ColdStorageRecord) -> Result<(), DomainError> {
+        self.records
+            .write()
+            .await
+            .insert(record.session_id, record.clone());
+        Ok(())
+    }
+
+    async fn find(&self, session_id: &SessionId) -> Result<Option<ColdStorageRecord>, DomainError> {
+        Ok(self.records.read().await.get(session_id).cloned())
+    }
+
+    async fn find_by_cycle(
+        &self,
+        cycle_id: &CycleId,
+    ) -> Result<Option<ColdStorageRecord>, DomainError> {
+        Ok(self
+            .records
+            .read()
+            .await
+            .values()
+            .find(|record| record.cycle_ids.contains(cycle_id))
+            .cloned())
+    }
+
+    async fn delete(&self, session_id: &SessionId) -> Result<(), DomainError> {
+        self.records.write().await.remove(session_id);
+        Ok(())
+    }
+
+    async fn list_candidates(
+        &self,
+        archived_before: Timestamp,
+        limit: u32,
+    ) -> Result<Vec<ColdStorageCandidate>, DomainError> {
+        let records = self.records.read().await;
+        let mut due: Vec<ColdStorageCandidate> = self
+            .finished
+            .read()
+            .await
+            .iter()
+            .filter(|(session_id, (_, archived_at))| {
+                !records.contains_key(session_id) && archived_at.is_before(&archived_before)
+            })
+            .map(
+                |(session_id, (user_id, archived_at))| ColdStorageCandidate {
+                    session_id: *session_id,
+                    user_id: user_id.clone(),
+                    archived_at: *archived_at,
+                },
+            )
+            .collect();
+        due.sort_by_key(|c| c.archived_at);
+        due.truncate(limit as usize);
+        Ok(due)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn lists_long_archived_sessions_not_yet_offloaded() {
+        let repo = InMemorySessionColdStorageRepository::new();
+        let now = Timestamp::now();
+        let user = UserId::new("user-1").unwrap();
+        let [old, older, recent, offloaded] = [(); 4].map(|_| SessionId::new());
+        repo.track_finished(old, user.clone(), now.minus_days(200))
+            .await;
+        repo.track_finished(older, user.clone(), now.minus_days(400))
+            .await;
+        repo.track_finished(recent, user.clone(), now.minus_days(10))
+            .await;
+        repo.track_finished(offloaded, user.clone(), now.minus_days(500))
+            .await;
+        repo.save(&ColdStorageRecord {
+            session_id: offloaded,
+            user_id: user,
+            blob_key: ColdStorageRecord::blob_key_for(&offloaded),
+            cycle_ids: vec![CycleId::new()],
+            offloaded_at: now,
+        })
+        .await
+        .unwrap();
+
+        let due = repo.list_candidates(now.minus_days(180), 10).await.unwrap();
+
+        let ids: Vec<_> = due.iter().map(|c| c.session_id).collect();
+        assert_eq!(ids, vec![older, old]);
+    }
+}
@@ -0,0 +1,10 @@
+//! Session adapters.
+//!
+//! In-memory implementations of the session ports for tests and development.
+//! The production adapters live in `adapters::postgres`.
+
+mod in_memory_session_archival_repository;
+mod in_memory_session_cold_storage_repository;
+
+pub use in_memory_session_archival_repository::InMemorySessionArchivalRepository;
+pub use in_memory_session_cold_storage_repository::InMemorySessionColdStorageRepository;
@@ -0,0 +1,144 @@
+//! Forwards audit-relevant domain events to the SIEM exporter.
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use crate::domain::foundation::{DomainError, EventEnvelope};
+use crate::ports::{
+    EventHandler, EventSubscriber, SecurityEvent, SecurityEventCategory, SecurityOutcome,
+};
+
+use super::SiemExporter;
+
+/// Domain events shipped to the SIEM as audit events.
+pub const AUDIT_EVENT_TYPES: &[&str] = &[
+    "consent.recorded.v1",
+    "membership.created.v1",
+    "membership.activated.v1",
+    "membership.tier_upgraded.v1",
+    "membership.payment_failed.v1",
+    "membership.payment_recovered.v1",
+    "membership.cancelled.v1",
+    "membership.reactivated.v1",
+    "membership.expired.v1",
+    "session.archived.v1",
+    "session.deleted.v1",
+    "session.restored.v1",
+    "session.purged.v1",
+];
+
+/// Event types recorded with a failure outcome.
+const FAILURE_EVENT_TYPES: &[&str] = &["membership.payment_failed.v1"];
+
+/// Event handler converting audit events into `SecurityEvent`s.
+pub struct SiemAuditForwarder {
+    exporter: SiemExporter,
+}
+
+impl SiemAuditForwarder {
+    pub fn new(exporter: SiemExporter) -> Self {
+        Self { exporter }
+    }
+
+    /// Register this forwarder for every audit event type.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let forwarder = Arc::new(SiemAuditForwarder::new(exporter.clone()));
+    /// forwarder.register(&event_bus);
+    /// ```
+    pub fn register(self: &Arc<Self>, subscriber: &impl EventSubscriber) {
+        subscriber.subscribe_all(AUDIT_EVENT_TYPES, self.clone());
+    }
+
+    /// Normalize a domain event into a security event.
+    pub fn to_security_event(event: &EventEnvelope) -> SecurityEvent {
+        let outcome = if FAILURE_EVENT_TYPES.contains(&event.event_type.as_str()) {
+            SecurityOutcome::Failure
+        } else {
+            SecurityOutcome::Success
+        };
+
+        // Prefer the request's user; fall back to the user the event is about
+        let actor = event.metadata.user_id.clone().or_else(|| {
+            event
+                .payload
+                .get("user_id")
+                .and_then(|v| v.as_str())
+                .map(str::to_string)
+        });
+
+        let mut security_event = SecurityEvent::new(
+            event.event_id.to_string(),
+            SecurityEventCategory::Audit,
+            &event.event_type,
+            outcome,
+        )
+        .with_resource(&event.aggregate_type, &event.aggregate_id)
+        .with_details(event.payload.clone());
+        security_event.occurred_at = event.occurred_at;
+        security_event.actor_id = actor;
+        security_event.correlation_id = event.metadata.correlation_id.clone();
+        security_event
+    }
+}
+
+#[async_trait]
+impl EventHandler for SiemAuditForwarder {
+    async fn handle(&self, event: EventEnvelope) -> Result<(), DomainError> {
+        if AUDIT_EVENT_TYPES.contains(&event.event_type.as_str()) {
+            self.exporter.export(Self::to_security_event(&event));
+        }
+        Ok(())
+    }
+
+    fn name(&self) -> &'static str {
+        "SiemAuditForwarder"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn maps_envelope_to_audit_event() {
+        let event = EventEnvelope::new(
+            "consent.recorded.v1",
+            "user-123",
+            "Consent",
+            json!({"user_id": "user-123", "consent_type": "ai_processing", "granted": false}),
+        )
+        .with_correlation_id("req-9");
+
+        let security_event = SiemAuditForwarder::to_security_event(&event);
+
+        assert_eq!(security_event.id, event.event_id.to_string());
+        assert_eq!(security_event.category, SecurityEventCategory::Audit);
+        assert_eq!(security_event.action, "consent.recorded.v1");
+        assert_eq!(security_event.outcome, SecurityOutcome::Success);
+        assert_eq!(security_event.actor_id.as_deref(), Some("user-123"));
+        assert_eq!(security_event.resource_type.as_deref(), Some("Consent"));
+        assert_eq!(security_event.correlation_id.as_deref(), Some("req-9"));
+        assert_eq!(security_event.occurred_at, event.occurred_at);
+    }
+
+    #[test]
+    fn payment_failure_is_a_failure_outcome() {
+        let event = EventEnvelope::new(
+            "membership.payment_failed.v1",
+            "mem-1",
+            "Membership",
+            json!({}),
+        )
+        .with_user_id("user-1");
+
+        let security_event = SiemAuditForwarder::to_security_event(&event);
+
+        assert_eq!(security_event.outcome, SecurityOutcome::Failure);
+        assert_eq!(security_event.actor_id.as_deref(), Some("user-1"));
+    }
+}
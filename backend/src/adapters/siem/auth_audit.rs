@@ -0,0 +1,279 @@
+//! AuditingSessionValidator - Reports token validation outcomes to the SIEM.
+//!
+//! Wraps any `SessionValidator`, so the auth middleware is unchanged:
+//!
+//! | Outcome | Action | Recorded |
+//! |---------|--------|----------|
+//! | Valid token | `auth.authenticated` | Once per user or service per `success_interval` |
+//! | Rejected token | `auth.token_rejected` | Always |
+//! | Provider unreachable | `auth.provider_unavailable` | Always |
+//!
+//! Tokens are never exported; rejections carry a short SHA-256 fingerprint so
+//! repeated attempts with the same token can be correlated.
+
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use async_trait::async_trait;
+use serde_json::json;
+use sha2::{Digest, Sha256};
+use uuid::Uuid;
+
+use crate::domain::foundation::{AuthError, AuthenticatedPrincipal, AuthenticatedUser};
+use crate::ports::{SecurityEvent, SecurityEventCategory, SecurityOutcome, SessionValidator};
+
+use super::SiemExporter;
+
+/// Default interval between `auth.authenticated` events for the same user.
+pub const DEFAULT_SUCCESS_INTERVAL: Duration = Duration::from_secs(15 * 60);
+
+/// Number of tracked principals above which stale entries are pruned.
+const PRUNE_THRESHOLD: usize = 10_000;
+
+/// Session validator decorator that exports authentication events.
+pub struct AuditingSessionValidator {
+    inner: Arc<dyn SessionValidator>,
+    exporter: SiemExporter,
+    success_interval: Duration,
+    /// Last recorded success per user ID or service client ID.
+    last_success: Mutex<HashMap<String, Instant>>,
+}
+
+impl AuditingSessionValidator {
+    pub fn new(inner: Arc<dyn SessionValidator>, exporter: SiemExporter) -> Self {
+        Self {
+            inner,
+            exporter,
+            success_interval: DEFAULT_SUCCESS_INTERVAL,
+            last_success: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Sets how often successful authentications are recorded per user.
+    pub fn with_success_interval(mut self, interval: Duration) -> Self {
+        self.success_interval = interval;
+        self
+    }
+
+    fn should_record_success(&self, subject: &str) -> bool {
+        let now = Instant::now();
+        let mut last_success = self.last_success.lock().unwrap();
+
+        if last_success.len() >= PRUNE_THRESHOLD {
+            let interval = self.success_interval;
+            last_success.retain(|_, seen| now.duration_since(*seen) < interval);
+        }
+
+        match last_success.get(subject) {
+            Some(seen) if now.duration_since(*seen) < self.success_interval => false,
+            _ => {
+                last_success.insert(subject.to_string(), now);
+                true
+            }
+        }
+    }
+
+    fn success_event(principal: &AuthenticatedPrincipal) -> SecurityEvent {
+        let event = SecurityEvent::new(
+            Uuid::new_v4().to_string(),
+            SecurityEventCategory::Authentication,
+            "auth.authenticated",
+            SecurityOutcome::Success,
+        )
+        .with_actor(principal.subject());
+        match principal {
+            AuthenticatedPrincipal::User(_) => event,
+            AuthenticatedPrincipal::Service(service) => event.with_details(json!({
+                "principal": "service",
+                "scopes": service.scopes,
+            })),
+        }
+    }
+
+    fn record(&self, token: &str, result: Result<&AuthenticatedPrincipal, &AuthError>) {
+        match result {
+            Ok(principal) => {
+                if self.should_record_success(principal.subject()) {
+                    self.exporter.export(Self::success_event(principal));
+                }
+            }
+            Err(e) => self.exporter.export(Self::failure_event(token, e)),
+        }
+    }
+
+    fn failure_event(token: &str, error: &AuthError) -> SecurityEvent {
+        let (action, details) = match error {
+            AuthError::ServiceUnavailable(message) => (
+                "auth.provider_unavailable",
+                json!({ "error": message }),
+            ),
+            _ => (
+                "auth.token_rejected",
+                json!({
+                    "reason": rejection_reason(error),
+                    "token_fingerprint": token_fingerprint(token),
+                }),
+            ),
+        };
+        SecurityEvent::new(
+            Uuid::new_v4().to_string(),
+            SecurityEventCategory::Authentication,
+            action,
+            SecurityOutcome::Failure,
+        )
+        .with_details(details)
+    }
+}
+
+#[async_trait]
+impl SessionValidator for AuditingSessionValidator {
+    async fn validate(&self, token: &str) -> Result<AuthenticatedUser, AuthError> {
+        let result = self.inner.validate(token).await;
+        match &result {
+            Ok(user) => self.record(token, Ok(&AuthenticatedPrincipal::User(user.clone()))),
+            Err(e) => self.record(token, Err(e)),
+        }
+        result
+    }
+
+    async fn validate_principal(&self, token: &str) -> Result<AuthenticatedPrincipal, AuthError> {
+        let result = self.inner.validate_principal(token).await;
+        self.record(token, result.as_ref());
+        result
+    }
+}
+
+fn rejection_reason(error: &AuthError) -> &'static str {
+    match error {
+        AuthError::TokenExpired => "expired",
+        AuthError::InvalidToken => "invalid",
+        AuthError::TokenRevoked => "revoked",
+        AuthError::UserNotFound => "user_not_found",
+        AuthError::InsufficientPermissions => "insufficient_permissions",
+        AuthError::ServiceUnavailable(_) => "provider_unavailable",
+    }
+}
+
+/// First 16 hex chars of the token's SHA-256.
+fn token_fingerprint(token: &str) -> String {
+    Sha256::digest(token.as_bytes())
+        .iter()
+        .take(8)
+        .map(|b| format!("{:02x}", b))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::adapters::siem::SiemExporterConfig;
+    use crate::domain::foundation::{ServicePrincipal, UserId};
+    use crate::ports::{SecurityEventSink, SecurityExportError};
+    use tokio::task::JoinHandle;
+
+    #[derive(Default)]
+    struct CapturingSink {
+        events: Mutex<Vec<SecurityEvent>>,
+    }
+
+    #[async_trait]
+    impl SecurityEventSink for CapturingSink {
+        async fn send_batch(&self, events: &[SecurityEvent]) -> Result<(), SecurityExportError> {
+            self.events.lock().unwrap().extend_from_slice(events);
+            Ok(())
+        }
+
+        fn name(&self) -> &'static str {
+            "capturing"
+        }
+    }
+
+    struct FixedValidator(Result<AuthenticatedUser, AuthError>);
+
+    #[async_trait]
+    impl SessionValidator for FixedValidator {
+        async fn validate(&self, _token: &str) -> Result<AuthenticatedUser, AuthError> {
+            self.0.clone()
+        }
+    }
+
+    fn user() -> AuthenticatedUser {
+        AuthenticatedUser::new(UserId::new("user-1").unwrap(), "user@example.com", None, true)
+    }
+
+    fn validator(
+        result: Result<AuthenticatedUser, AuthError>,
+    ) -> (AuditingSessionValidator, Arc<CapturingSink>, JoinHandle<()>) {
+        let sink = Arc::new(CapturingSink::default());
+        let (exporter, handle) = SiemExporter::spawn(sink.clone(), SiemExporterConfig::default());
+        let validator = AuditingSessionValidator::new(Arc::new(FixedValidator(result)), exporter);
+        (validator, sink, handle)
+    }
+
+    #[tokio::test]
+    async fn records_rejections_without_the_token() {
+        let (validator, sink, handle) = validator(Err(AuthError::TokenExpired));
+
+        assert!(validator.validate("secret-token").await.is_err());
+        drop(validator);
+        handle.await.unwrap();
+
+        let events = sink.events.lock().unwrap();
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].action, "auth.token_rejected");
+        assert_eq!(events[0].outcome, SecurityOutcome::Failure);
+        assert_eq!(events[0].details["reason"], "expired");
+        assert_eq!(events[0].details["token_fingerprint"].as_str().unwrap().len(), 16);
+        assert!(!serde_json::to_string(&events[0]).unwrap().contains("secret-token"));
+    }
+
+    #[tokio::test]
+    async fn records_success_once_per_interval() {
+        let (validator, sink, handle) = validator(Ok(user()));
+
+        for _ in 0..3 {
+            validator.validate("token").await.unwrap();
+        }
+        drop(validator);
+        handle.await.unwrap();
+
+        let events = sink.events.lock().unwrap();
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].action, "auth.authenticated");
+        assert_eq!(events[0].actor_id.as_deref(), Some("user-1"));
+    }
+
+    #[tokio::test]
+    async fn records_service_principals_by_client_id() {
+        struct ServiceValidator;
+
+        #[async_trait]
+        impl SessionValidator for ServiceValidator {
+            async fn validate(&self, _token: &str) -> Result<AuthenticatedUser, AuthError> {
+                Err(AuthError::InvalidToken)
+            }
+
+            async fn validate_principal(
+                &self,
+                _token: &str,
+            ) -> Result<AuthenticatedPrincipal, AuthError> {
+                Ok(ServicePrincipal::new("sync-worker", ["sessions:read"]).into())
+            }
+        }
+
+        let sink = Arc::new(CapturingSink::default());
+        let (exporter, handle) = SiemExporter::spawn(sink.clone(), SiemExporterConfig::default());
+        let validator = AuditingSessionValidator::new(Arc::new(ServiceValidator), exporter);
+
+        validator.validate_principal("token").await.unwrap();
+        validator.validate_principal("token").await.unwrap();
+        drop(validator);
+        handle.await.unwrap();
+
+        let events = sink.events.lock().unwrap();
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].actor_id.as_deref(), Some("sync-worker"));
+        assert_eq!(events[0].details["principal"], "service");
+    }
+}
@@ -0,0 +1,293 @@
+//! Batching exporter in front of a `SecurityEventSink`.
+//!
+//! Producers call [`SiemExporter::export`], which never blocks: events go onto
+//! a bounded queue and a background task delivers them in batches. A batch is
+//! flushed when it reaches `batch_size` or `flush_interval` elapses. Failed
+//! deliveries are retried with exponential backoff; if the queue is full or
+//! retries are exhausted, events are dropped and counted rather than slowing
+//! request handling.
+
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+
+use tokio::sync::mpsc;
+use tokio::task::JoinHandle;
+
+use crate::ports::{SecurityEvent, SecurityEventSink};
+
+/// Exporter tuning.
+#[derive(Debug, Clone)]
+pub struct SiemExporterConfig {
+    /// Maximum events per delivery.
+    pub batch_size: usize,
+    /// Longest an event waits before its batch is flushed.
+    pub flush_interval: Duration,
+    /// Events buffered before new ones are dropped.
+    pub queue_capacity: usize,
+    /// Retries per batch after the first attempt.
+    pub max_retries: u32,
+    /// Delay before the first retry; doubles on each retry.
+    pub initial_backoff: Duration,
+    /// Upper bound on the retry delay.
+    pub max_backoff: Duration,
+}
+
+impl Default for SiemExporterConfig {
+    fn default() -> Self {
+        Self {
+            batch_size: 100,
+            flush_interval: Duration::from_secs(5),
+            queue_capacity: 10_000,
+            max_retries: 5,
+            initial_backoff: Duration::from_millis(500),
+            max_backoff: Duration::from_secs(30),
+        }
+    }
+}
+
+/// Delivery counters.
+#[derive(Debug, Default)]
+struct ExportCounters {
+    exported: AtomicU64,
+    dropped: AtomicU64,
+}
+
+/// Snapshot of exporter counters.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SiemExporterStats {
+    /// Events the sink accepted.
+    pub exported: u64,
+    /// Events lost to a full queue or exhausted retries.
+    pub dropped: u64,
+}
+
+/// Handle for queueing security events for export.
+///
+/// Cheap to clone. The background task flushes remaining events and exits
+/// once every handle has been dropped.
+#[derive(Clone)]
+pub struct SiemExporter {
+    tx: mpsc::Sender<SecurityEvent>,
+    counters: Arc<ExportCounters>,
+}
+
+impl SiemExporter {
+    /// Starts the delivery task and returns a handle for queueing events.
+    pub fn spawn(
+        sink: Arc<dyn SecurityEventSink>,
+        config: SiemExporterConfig,
+    ) -> (Self, JoinHandle<()>) {
+        let (tx, rx) = mpsc::channel(config.queue_capacity.max(1));
+        let counters = Arc::new(ExportCounters::default());
+        let worker = ExportWorker {
+            sink,
+            config,
+            counters: Arc::clone(&counters),
+        };
+        let handle = tokio::spawn(worker.run(rx));
+        (Self { tx, counters }, handle)
+    }
+
+    /// Queues an event. Drops it (and logs) if the queue is full.
+    pub fn export(&self, event: SecurityEvent) {
+        if let Err(e) = self.tx.try_send(event) {
+            self.counters.dropped.fetch_add(1, Ordering::Relaxed);
+            tracing::warn!(error = %e, "SIEM export queue rejected event");
+        }
+    }
+
+    pub fn stats(&self) -> SiemExporterStats {
+        SiemExporterStats {
+            exported: self.counters.exported.load(Ordering::Relaxed),
+            dropped: self.counters.dropped.load(Ordering::Relaxed),
+        }
+    }
+}
+
+struct ExportWorker {
+    sink: Arc<dyn SecurityEventSink>,
+    config: SiemExporterConfig,
+    counters: Arc<ExportCounters>,
+}
+
+impl ExportWorker {
+    async fn run(self, mut rx: mpsc::Receiver<SecurityEvent>) {
+        let batch_size = self.config.batch_size.max(1);
+        let mut batch = Vec::with_capacity(batch_size);
+        let mut ticker = tokio::time::interval(self.config.flush_interval);
+        ticker.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+        // The first tick completes immediately
+        ticker.tick().await;
+
+        loop {
+            tokio::select! {
+                received = rx.recv() => match received {
+                    Some(event) => {
+                        batch.push(event);
+                        if batch.len() >= batch_size {
+                            self.deliver(std::mem::take(&mut batch)).await;
+                        }
+                    }
+                    None => {
+                        if !batch.is_empty() {
+                            self.deliver(batch).await;
+                        }
+                        tracing::debug!(sink = self.sink.name(), "SIEM exporter stopping");
+                        break;
+                    }
+                },
+                _ = ticker.tick() => {
+                    if !batch.is_empty() {
+                        self.deliver(std::mem::take(&mut batch)).await;
+                    }
+                }
+            }
+        }
+    }
+
+    async fn deliver(&self, batch: Vec<SecurityEvent>) {
+        let mut backoff = self.config.initial_backoff;
+        let mut attempt = 0;
+
+        loop {
+            match self.sink.send_batch(&batch).await {
+                Ok(()) => {
+                    self.counters
+                        .exported
+                        .fetch_add(batch.len() as u64, Ordering::Relaxed);
+                    return;
+                }
+                Err(e) if e.is_retryable() && attempt < self.config.max_retries => {
+                    attempt += 1;
+                    tracing::warn!(
+                        sink = self.sink.name(),
+                        attempt,
+                        backoff_ms = backoff.as_millis() as u64,
+                        error = %e,
+                        "SIEM export failed, retrying"
+                    );
+                    tokio::time::sleep(backoff).await;
+                    backoff = (backoff * 2).min(self.config.max_backoff);
+                }
+                Err(e) => {
+                    self.counters
+                        .dropped
+                        .fetch_add(batch.len() as u64, Ordering::Relaxed);
+                    tracing::error!(
+                        sink = self.sink.name(),
+                        events = batch.len(),
+                        error = %e,
+                        "SIEM export failed, dropping batch"
+                    );
+                    return;
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ports::{SecurityEventCategory, SecurityExportError, SecurityOutcome};
+    use async_trait::async_trait;
+    use std::sync::Mutex;
+
+    #[derive(Default)]
+    struct RecordingSink {
+        batches: Mutex<Vec<usize>>,
+        failures_remaining: Mutex<u32>,
+        reject: bool,
+    }
+
+    #[async_trait]
+    impl SecurityEventSink for RecordingSink {
+        async fn send_batch(&self, events: &[SecurityEvent]) -> Result<(), SecurityExportError> {
+            if self.reject {
+                return Err(SecurityExportError::Rejected("bad request".into()));
+            }
+            let mut failures = self.failures_remaining.lock().unwrap();
+            if *failures > 0 {
+                *failures -= 1;
+                return Err(SecurityExportError::Unavailable("down".into()));
+            }
+            self.batches.lock().unwrap().push(events.len());
+            Ok(())
+        }
+
+        fn name(&self) -> &'static str {
+            "recording"
+        }
+    }
+
+    fn event(n: usize) -> SecurityEvent {
+        SecurityEvent::new(
+            format!("evt-{}", n),
+            SecurityEventCategory::Audit,
+            "consent.recorded.v1",
+            SecurityOutcome::Success,
+        )
+    }
+
+    fn config() -> SiemExporterConfig {
+        SiemExporterConfig {
+            batch_size: 3,
+            flush_interval: Duration::from_secs(3600),
+            initial_backoff: Duration::from_millis(1),
+            max_backoff: Duration::from_millis(2),
+            ..Default::default()
+        }
+    }
+
+    #[tokio::test]
+    async fn batches_by_size_and_flushes_on_shutdown() {
+        let sink = Arc::new(RecordingSink::default());
+        let (exporter, handle) = SiemExporter::spawn(sink.clone(), config());
+
+        for n in 0..7 {
+            exporter.export(event(n));
+        }
+        let counters = Arc::clone(&exporter.counters);
+        drop(exporter);
+        handle.await.unwrap();
+
+        assert_eq!(*sink.batches.lock().unwrap(), vec![3, 3, 1]);
+        assert_eq!(counters.exported.load(Ordering::Relaxed), 7);
+    }
+
+    #[tokio::test]
+    async fn retries_unavailable_sink_with_backoff() {
+        let sink = Arc::new(RecordingSink {
+            failures_remaining: Mutex::new(2),
+            ..Default::default()
+        });
+        let (exporter, handle) = SiemExporter::spawn(sink.clone(), config());
+
+        exporter.export(event(1));
+        let counters = Arc::clone(&exporter.counters);
+        drop(exporter);
+        handle.await.unwrap();
+
+        assert_eq!(*sink.batches.lock().unwrap(), vec![1]);
+        assert_eq!(counters.dropped.load(Ordering::Relaxed), 0);
+    }
+
+    #[tokio::test]
+    async fn drops_rejected_batches_without_retrying() {
+        let sink = Arc::new(RecordingSink {
+            reject: true,
+            ..Default::default()
+        });
+        let (exporter, handle) = SiemExporter::spawn(sink.clone(), config());
+
+        exporter.export(event(1));
+        exporter.export(event(2));
+        let counters = Arc::clone(&exporter.counters);
+        drop(exporter);
+        handle.await.unwrap();
+
+        assert_eq!(counters.dropped.load(Ordering::Relaxed), 2);
+        assert_eq!(counters.exported.load(Ordering::Relaxed), 0);
+    }
+}
@@ -0,0 +1,129 @@
+//! HTTP sink - Posts security event batches as a JSON array.
+//!
+//! Works with collectors that accept JSON over HTTPS (Splunk HEC raw
+//! endpoints, Elastic, Datadog, Sumo Logic HTTP sources). 5xx, 429, and
+//! connection failures are reported as retryable; other 4xx responses mean
+//! the batch itself was refused.
+
+use std::time::Duration;
+
+use async_trait::async_trait;
+use reqwest::{header, Client, StatusCode};
+use secrecy::{ExposeSecret, Secret};
+
+use crate::ports::{SecurityEvent, SecurityEventSink, SecurityExportError};
+
+/// HTTP sink settings.
+#[derive(Debug, Clone)]
+pub struct HttpSinkConfig {
+    /// Collector URL.
+    pub endpoint: String,
+    /// Sent as `Authorization: Bearer <token>` when set.
+    pub auth_token: Option<Secret<String>>,
+    /// Request timeout per batch.
+    pub timeout: Duration,
+}
+
+impl HttpSinkConfig {
+    pub fn new(endpoint: impl Into<String>) -> Self {
+        Self {
+            endpoint: endpoint.into(),
+            auth_token: None,
+            timeout: Duration::from_secs(10),
+        }
+    }
+
+    /// Sets the bearer token.
+    pub fn with_auth_token(mut self, token: impl Into<String>) -> Self {
+        self.auth_token = Some(Secret::new(token.into()));
+        self
+    }
+
+    /// Sets the request timeout.
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+}
+
+/// Delivers batches to an HTTP collector.
+pub struct HttpSecurityEventSink {
+    client: Client,
+    config: HttpSinkConfig,
+}
+
+impl HttpSecurityEventSink {
+    pub fn new(config: HttpSinkConfig) -> Self {
+        let client = Client::builder()
+            .timeout(config.timeout)
+            .build()
+            .expect("Failed to create HTTP client");
+        Self { client, config }
+    }
+}
+
+#[async_trait]
+impl SecurityEventSink for HttpSecurityEventSink {
+    async fn send_batch(&self, events: &[SecurityEvent]) -> Result<(), SecurityExportError> {
+        let mut request = self.client.post(&self.config.endpoint).json(events);
+        if let Some(token) = &self.config.auth_token {
+            request = request.header(
+                header::AUTHORIZATION,
+                format!("Bearer {}", token.expose_secret()),
+            );
+        }
+
+        let response = request
+            .send()
+            .await
+            .map_err(|e| SecurityExportError::Unavailable(e.to_string()))?;
+
+        classify_status(response.status())
+    }
+
+    fn name(&self) -> &'static str {
+        "http"
+    }
+}
+
+fn classify_status(status: StatusCode) -> Result<(), SecurityExportError> {
+    if status.is_success() {
+        Ok(())
+    } else if status.is_server_error() || status == StatusCode::TOO_MANY_REQUESTS {
+        Err(SecurityExportError::Unavailable(format!(
+            "collector returned {}",
+            status
+        )))
+    } else {
+        Err(SecurityExportError::Rejected(format!(
+            "collector returned {}",
+            status
+        )))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn throttling_and_server_errors_are_retryable() {
+        assert!(classify_status(StatusCode::OK).is_ok());
+        assert!(classify_status(StatusCode::SERVICE_UNAVAILABLE)
+            .unwrap_err()
+            .is_retryable());
+        assert!(classify_status(StatusCode::TOO_MANY_REQUESTS)
+            .unwrap_err()
+            .is_retryable());
+        assert!(!classify_status(StatusCode::UNAUTHORIZED)
+            .unwrap_err()
+            .is_retryable());
+    }
+
+    #[test]
+    fn config_debug_hides_token() {
+        let config = HttpSinkConfig::new("https://siem.example.com/ingest")
+            .with_auth_token("super-secret");
+        assert!(!format!("{:?}", config).contains("super-secret"));
+    }
+}
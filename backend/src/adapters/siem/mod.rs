@@ -0,0 +1,28 @@
+//! SIEM export adapters.
+//!
+//! Ships audit and authentication events to a security information and event
+//! management system:
+//!
+//! ```text
+//! Event bus ── SiemAuditForwarder ──────┐
+//!                                       ├─► SiemExporter (queue, batch, backoff) ─► sink
+//! auth middleware ── AuditingSessionValidator ┘
+//! ```
+//!
+//! - `SiemExporter` - Non-blocking queue with batched delivery and retries
+//! - `SiemAuditForwarder` - Event handler for audit-relevant domain events
+//! - `AuditingSessionValidator` - `SessionValidator` decorator for auth events
+//! - `HttpSecurityEventSink` - JSON batches over HTTPS
+//! - `SyslogSecurityEventSink` - RFC 5424 over UDP or TCP
+
+mod audit_forwarder;
+mod auth_audit;
+mod exporter;
+mod http_sink;
+mod syslog_sink;
+
+pub use audit_forwarder::{SiemAuditForwarder, AUDIT_EVENT_TYPES};
+pub use auth_audit::{AuditingSessionValidator, DEFAULT_SUCCESS_INTERVAL};
+pub use exporter::{SiemExporter, SiemExporterConfig, SiemExporterStats};
+pub use http_sink::{HttpSecurityEventSink, HttpSinkConfig};
+pub use syslog_sink::{SyslogSecurityEventSink, SyslogTransport};
@@ -0,0 +1,238 @@
+//! Syslog sink - RFC 5424 messages over UDP or TCP.
+//!
+//! Each event becomes one syslog message whose body is the event as JSON:
+//!
+//! ```text
+//! <84>1 2026-01-12T09:30:00.000Z api-1 choice-sherpa - auth.token_rejected - {"id":...}
+//! ```
+//!
+//! Audit events use facility 13 (log audit) and authentication events use
+//! facility 10 (authpriv). Failures are logged at warning severity, successes
+//! at informational. TCP uses octet-counting framing (RFC 6587); UDP sends one
+//! datagram per event. TLS is not supported - run a local relay (rsyslog,
+//! syslog-ng, Vector) when the collector requires it.
+
+use async_trait::async_trait;
+use chrono::SecondsFormat;
+use tokio::io::AsyncWriteExt;
+use tokio::net::{TcpStream, UdpSocket};
+use tokio::sync::Mutex;
+
+use crate::ports::{
+    SecurityEvent, SecurityEventCategory, SecurityEventSink, SecurityExportError, SecurityOutcome,
+};
+
+/// RFC 5424 limit on MSGID length.
+const MAX_MSGID_LENGTH: usize = 32;
+
+const FACILITY_AUTHPRIV: u8 = 10;
+const FACILITY_LOG_AUDIT: u8 = 13;
+const SEVERITY_WARNING: u8 = 4;
+const SEVERITY_INFORMATIONAL: u8 = 6;
+
+/// Syslog transport.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SyslogTransport {
+    Udp,
+    Tcp,
+}
+
+/// Delivers events to a syslog collector.
+pub struct SyslogSecurityEventSink {
+    address: String,
+    transport: SyslogTransport,
+    hostname: String,
+    app_name: String,
+    tcp: Mutex<Option<TcpStream>>,
+}
+
+impl SyslogSecurityEventSink {
+    /// Creates a sink for `address` ("host:port").
+    pub fn new(address: impl Into<String>, transport: SyslogTransport) -> Self {
+        Self {
+            address: address.into(),
+            transport,
+            hostname: std::env::var("HOSTNAME").unwrap_or_else(|_| "-".to_string()),
+            app_name: "choice-sherpa".to_string(),
+            tcp: Mutex::new(None),
+        }
+    }
+
+    /// Sets the APP-NAME field.
+    pub fn with_app_name(mut self, app_name: impl Into<String>) -> Self {
+        self.app_name = app_name.into();
+        self
+    }
+
+    /// Sets the HOSTNAME field (defaults to `$HOSTNAME`).
+    pub fn with_hostname(mut self, hostname: impl Into<String>) -> Self {
+        self.hostname = hostname.into();
+        self
+    }
+
+    fn format(&self, event: &SecurityEvent) -> Result<String, SecurityExportError> {
+        let body = serde_json::to_string(event)
+            .map_err(|e| SecurityExportError::Rejected(e.to_string()))?;
+        Ok(format!(
+            "<{}>1 {} {} {} - {} - {}",
+            priority(event),
+            event
+                .occurred_at
+                .as_datetime()
+                .to_rfc3339_opts(SecondsFormat::Millis, true),
+            self.hostname,
+            self.app_name,
+            msgid(&event.action),
+            body
+        ))
+    }
+
+    async fn send_udp(&self, messages: &[String]) -> Result<(), SecurityExportError> {
+        let socket = UdpSocket::bind("0.0.0.0:0").await.map_err(unavailable)?;
+        socket.connect(&self.address).await.map_err(unavailable)?;
+        for message in messages {
+            socket.send(message.as_bytes()).await.map_err(unavailable)?;
+        }
+        Ok(())
+    }
+
+    async fn send_tcp(&self, messages: &[String]) -> Result<(), SecurityExportError> {
+        let mut framed = Vec::new();
+        for message in messages {
+            framed.extend_from_slice(format!("{} {}", message.len(), message).as_bytes());
+        }
+
+        let mut connection = self.tcp.lock().await;
+        if connection.is_none() {
+            *connection = Some(
+                TcpStream::connect(&self.address)
+                    .await
+                    .map_err(unavailable)?,
+            );
+        }
+        let stream = connection.as_mut().expect("connection established above");
+        if let Err(e) = stream.write_all(&framed).await {
+            // Reconnect on the next attempt
+            *connection = None;
+            return Err(unavailable(e));
+        }
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl SecurityEventSink for SyslogSecurityEventSink {
+    async fn send_batch(&self, events: &[SecurityEvent]) -> Result<(), SecurityExportError> {
+        let messages = events
+            .iter()
+            .map(|event| self.format(event))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        match self.transport {
+            SyslogTransport::Udp => self.send_udp(&messages).await,
+            SyslogTransport::Tcp => self.send_tcp(&messages).await,
+        }
+    }
+
+    fn name(&self) -> &'static str {
+        "syslog"
+    }
+}
+
+fn priority(event: &SecurityEvent) -> u8 {
+    let facility = match event.category {
+        SecurityEventCategory::Audit => FACILITY_LOG_AUDIT,
+        SecurityEventCategory::Authentication => FACILITY_AUTHPRIV,
+    };
+    let severity = match event.outcome {
+        SecurityOutcome::Success => SEVERITY_INFORMATIONAL,
+        SecurityOutcome::Failure => SEVERITY_WARNING,
+    };
+    facility * 8 + severity
+}
+
+/// MSGID must be 1-32 printable US-ASCII characters without spaces.
+fn msgid(action: &str) -> String {
+    let id: String = action
+        .chars()
+        .map(|c| if c.is_ascii_graphic() { c } else { '_' })
+        .take(MAX_MSGID_LENGTH)
+        .collect();
+    if id.is_empty() {
+        "-".to_string()
+    } else {
+        id
+    }
+}
+
+fn unavailable(e: std::io::Error) -> SecurityExportError {
+    SecurityExportError::Unavailable(e.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tokio::io::AsyncReadExt;
+    use tokio::net::TcpListener;
+
+    fn auth_failure() -> SecurityEvent {
+        SecurityEvent::new(
+            "evt-1",
+            SecurityEventCategory::Authentication,
+            "auth.token_rejected",
+            SecurityOutcome::Failure,
+        )
+    }
+
+    #[test]
+    fn formats_rfc5424_header() {
+        let sink = SyslogSecurityEventSink::new("localhost:514", SyslogTransport::Udp)
+            .with_hostname("api-1");
+        let message = sink.format(&auth_failure()).unwrap();
+
+        // authpriv (10) * 8 + warning (4)
+        assert!(message.starts_with("<84>1 "));
+        assert!(message.contains(" api-1 choice-sherpa - auth.token_rejected - {"));
+        assert!(message.ends_with('}'));
+    }
+
+    #[test]
+    fn audit_success_uses_log_audit_facility() {
+        let event = SecurityEvent::new(
+            "evt-2",
+            SecurityEventCategory::Audit,
+            "consent.recorded.v1",
+            SecurityOutcome::Success,
+        );
+        assert_eq!(priority(&event), 13 * 8 + 6);
+    }
+
+    #[test]
+    fn msgid_is_truncated_and_sanitized() {
+        assert_eq!(msgid("a b"), "a_b");
+        assert_eq!(msgid(&"x".repeat(40)).len(), MAX_MSGID_LENGTH);
+        assert_eq!(msgid(""), "-");
+    }
+
+    #[tokio::test]
+    async fn tcp_uses_octet_counting() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener.local_addr().unwrap().to_string();
+        let sink = SyslogSecurityEventSink::new(address, SyslogTransport::Tcp);
+        let event = auth_failure();
+        let expected = sink.format(&event).unwrap();
+
+        let server = tokio::spawn(async move {
+            let (mut socket, _) = listener.accept().await.unwrap();
+            let mut received = String::new();
+            socket.read_to_string(&mut received).await.unwrap();
+            received
+        });
+
+        sink.send_batch(&[event]).await.unwrap();
+        drop(sink);
+
+        let received = server.await.unwrap();
+        assert_eq!(received, format!("{} {}", expected.len(), expected));
+    }
+}
@@ -0,0 +1,109 @@
+//! In-memory Slack workspace store and share repository for testing and
+//! development.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use tokio::sync::RwLock;
+
+use crate::domain::document::SlackShare;
+use crate::domain::foundation::{DomainError, SlackShareId, Timestamp, UserId};
+use crate::ports::{SlackShareRepository, SlackWorkspace, SlackWorkspaceStore};
+
+/// Connected Slack workspaces keyed by user and team.
+#[derive(Debug, Clone, Default)]
+pub struct InMemorySlackWorkspaceStore {
+    workspaces: Arc<RwLock<HashMap<(UserId, String), SlackWorkspace>>>,
+}
+
+impl InMemorySlackWorkspaceStore {
+    /// Create an empty store.
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait]
+impl SlackWorkspaceStore for InMemorySlackWorkspaceStore {
+    async fn get(
+        &self,
+        user_id: &UserId,
+        team_id: &str,
+    ) -> Result<Option<SlackWorkspace>, DomainError> {
+        Ok(self
+            .workspaces
+            .read()
+            .await
+            .get(&(user_id.clone(), team_id.to_string()))
+            .cloned())
+    }
+
+    async fn list(&self, user_id: &UserId) -> Result<Vec<SlackWorkspace>, DomainError> {
+        let mut workspaces: Vec<SlackWorkspace> = self
+            .workspaces
+            .read()
+            .await
+            .iter()
+            .filter(|((owner, _), _)| owner == user_id)
+            .map(|(_, workspace)| workspace.clone())
+            .collect();
+        workspaces.sort_by(|a, b| a.team_name.cmp(&b.team_name));
+        Ok(workspaces)
+    }
+
+    async fn save(&self, user_id: &UserId, workspace: &SlackWorkspace) -> Result<(), DomainError> {
+        self.workspaces.write().await.insert(
+            (user_id.clone(), workspace.team_id.clone()),
+            workspace.clone(),
+        );
+        Ok(())
+    }
+
+    async fn delete(&self, user_id: &UserId, team_id: &str) -> Result<(), DomainError> {
+        self.workspaces
+            .write()
+            .await
+            .remove(&(user_id.clone(), team_id.to_string()));
+        Ok(())
+    }
+}
+
+/// In-memory Slack shares keyed by ID.
+#[derive(Debug, Clone, Default)]
+pub struct InMemorySlackShareRepository {
+    shares: Arc<RwLock<HashMap<SlackShareId, SlackShare>>>,
+}
+
+impl InMemorySlackShareRepository {
+    /// Create an empty repository.
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait]
+impl SlackShareRepository for InMemorySlackShareRepository {
+    async fn save(&self, share: &SlackShare) -> Result<(), DomainError> {
+        self.shares.write().await.insert(share.id, share.clone());
+        Ok(())
+    }
+
+    async fn find_by_id(&self, id: &SlackShareId) -> Result<Option<SlackShare>, DomainError> {
+        Ok(self.shares.read().await.get(id).cloned())
+    }
+
+    async fn list_due(&self, now: Timestamp, limit: u32) -> Result<Vec<SlackShare>, DomainError> {
+        let mut due: Vec<SlackShare> = self
+            .shares
+            .read()
+            .await
+            .values()
+            .filter(|s| s.is_due(now))
+            .cloned()
+            .collect();
+        due.sort_by_key(|s| s.next_attempt_at);
+        due.truncate(limit as usize);
+        Ok(due)
+    }
+}
@@ -0,0 +1,11 @@
+//! Slack adapters.
+//!
+//! - `SlackApiClient` - OAuth install flow and Block Kit recommendation posts
+//! - `InMemorySlackWorkspaceStore` - Connected workspaces held in memory
+//! - `InMemorySlackShareRepository` - Shares and their retry state held in memory
+
+mod in_memory;
+mod web_client;
+
+pub use in_memory::{InMemorySlackShareRepository, InMemorySlackWorkspaceStore};
+pub use web_client::{SlackApiClient, SlackConfig, SLACK_BOT_SCOPES};
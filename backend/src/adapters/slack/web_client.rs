@@ -0,0 +1,485 @@
+//! Slack Web API client - Implementation of SlackClient.
+//!
+//! Installing the app grants a bot token for the workspace with the
+//! `chat:write` scopes, so recommendations can be posted to any public
+//! channel without inviting the bot first. Messages use Block Kit: a header
+//! with the decision title, the standout option, the synthesis, the leading
+//! caveats, the Decision Quality score once rated, and a button linking to
+//! the published decision document.
+//!
+//! # Configuration
+//!
+//! ```ignore
+//! let config = SlackConfig::new(client_id, client_secret, "https://app.example.com/integrations/slack");
+//! let client = Arc::new(SlackApiClient::new(config));
+//! ```
+
+use std::time::Duration;
+
+use async_trait::async_trait;
+use reqwest::{Client, StatusCode, Url};
+use secrecy::{ExposeSecret, Secret};
+use serde::Deserialize;
+use serde_json::{json, Value};
+
+use crate::domain::document::RecommendationDigest;
+use crate::domain::foundation::Timestamp;
+use crate::ports::{SlackClient, SlackError, SlackWorkspace};
+
+/// Bot scopes requested at install: post, including to public channels the
+/// bot has not joined.
+pub const SLACK_BOT_SCOPES: &str = "chat:write,chat:write.public";
+
+/// Slack's limit on header block text.
+const MAX_HEADER_CHARS: usize = 150;
+
+/// Slack's limit on section block text.
+const MAX_SECTION_CHARS: usize = 3000;
+
+/// Slack truncates top-level message text beyond this.
+const MAX_MESSAGE_CHARS: usize = 40_000;
+
+/// Slack error codes meaning the token no longer works.
+const UNAUTHORIZED_ERRORS: [&str; 5] = [
+    "invalid_auth",
+    "not_authed",
+    "token_revoked",
+    "account_inactive",
+    "invalid_code",
+];
+
+/// Slack OAuth and Web API settings.
+#[derive(Debug, Clone)]
+pub struct SlackConfig {
+    pub client_id: String,
+    client_secret: Secret<String>,
+    /// Where Slack sends the user back with the authorization code.
+    pub redirect_uri: String,
+    pub auth_url: String,
+    /// Base URL of the Web API methods.
+    pub api_url: String,
+    /// Request timeout.
+    pub timeout: Duration,
+}
+
+impl SlackConfig {
+    pub fn new(
+        client_id: impl Into<String>,
+        client_secret: impl Into<String>,
+        redirect_uri: impl Into<String>,
+    ) -> Self {
+        Self {
+            client_id: client_id.into(),
+            client_secret: Secret::new(client_secret.into()),
+            redirect_uri: redirect_uri.into(),
+            auth_url: "https://slack.com/oauth/v2/authorize".to_string(),
+            api_url: "https://slack.com/api".to_string(),
+            timeout: Duration::from_secs(10),
+        }
+    }
+
+    /// Sets the request timeout.
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+}
+
+/// Slack OAuth and Web API client.
+pub struct SlackApiClient {
+    client: Client,
+    config: SlackConfig,
+}
+
+impl SlackApiClient {
+    pub fn new(config: SlackConfig) -> Self {
+        let client = Client::builder()
+            .timeout(config.timeout)
+            .build()
+            .expect("Failed to create HTTP client");
+        Self { client, config }
+    }
+
+    fn method_url(&self, method: &str) -> String {
+        format!("{}/{}", self.config.api_url.trim_end_matches('/'), method)
+    }
+}
+
+/// Envelope shared by every Web API response; failures still return 200.
+#[derive(Debug, Deserialize)]
+struct ApiResponse<T> {
+    ok: bool,
+    error: Option<String>,
+    #[serde(flatten)]
+    body: Option<T>,
+}
+
+impl<T> ApiResponse<T> {
+    fn into_result(self) -> Result<T, SlackError> {
+        let error = self.error.unwrap_or_else(|| "unknown_error".to_string());
+        match (self.ok, self.body) {
+            (true, Some(body)) => Ok(body),
+            (true, None) => Err(SlackError::Api("Unexpected Slack response".to_string())),
+            (false, _) if UNAUTHORIZED_ERRORS.contains(&error.as_str()) => {
+                Err(SlackError::Unauthorized(error))
+            }
+            (false, _) if error == "ratelimited" => Err(SlackError::Unavailable(error)),
+            (false, _) => Err(SlackError::Api(error)),
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct OAuthAccess {
+    access_token: String,
+    team: Team,
+}
+
+#[derive(Debug, Deserialize)]
+struct Team {
+    id: String,
+    name: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct PostedMessage {
+    ts: String,
+}
+
+#[async_trait]
+impl SlackClient for SlackApiClient {
+    fn authorization_url(&self, state: &str) -> String {
+        Url::parse_with_params(
+            &self.config.auth_url,
+            &[
+                ("client_id", self.config.client_id.as_str()),
+                ("redirect_uri", self.config.redirect_uri.as_str()),
+                ("scope", SLACK_BOT_SCOPES),
+                ("state", state),
+            ],
+        )
+        .map(String::from)
+        .unwrap_or_else(|_| self.config.auth_url.clone())
+    }
+
+    #[tracing::instrument(name = "SlackApiClient::exchange_code", skip_all, err)]
+    async fn exchange_code(&self, code: &str) -> Result<SlackWorkspace, SlackError> {
+        let response = self
+            .client
+            .post(self.method_url("oauth.v2.access"))
+            .form(&[
+                ("code", code),
+                ("client_id", &self.config.client_id),
+                ("client_secret", self.config.client_secret.expose_secret()),
+                ("redirect_uri", &self.config.redirect_uri),
+            ])
+            .send()
+            .await
+            .map_err(|e| SlackError::Unavailable(e.to_string()))?;
+
+        let access: OAuthAccess = parse(response).await?;
+        Ok(SlackWorkspace {
+            team_id: access.team.id,
+            team_name: access.team.name,
+            bot_token: Secret::new(access.access_token),
+            connected_at: Timestamp::now(),
+        })
+    }
+
+    #[tracing::instrument(name = "SlackApiClient::post_recommendation", skip_all, err)]
+    async fn post_recommendation(
+        &self,
+        bot_token: &Secret<String>,
+        channel: &str,
+        digest: &RecommendationDigest,
+    ) -> Result<String, SlackError> {
+        let response = self
+            .client
+            .post(self.method_url("chat.postMessage"))
+            .bearer_auth(bot_token.expose_secret())
+            .json(&recommendation_message(channel, digest))
+            .send()
+            .await
+            .map_err(|e| SlackError::Unavailable(e.to_string()))?;
+
+        let posted: PostedMessage = parse(response).await?;
+        Ok(posted.ts)
+    }
+
+    #[tracing::instrument(name = "SlackApiClient::post_message", skip_all, err)]
+    async fn post_message(
+        &self,
+        bot_token: &Secret<String>,
+        channel: &str,
+        text: &str,
+    ) -> Result<String, SlackError> {
+        let response = self
+            .client
+            .post(self.method_url("chat.postMessage"))
+            .bearer_auth(bot_token.expose_secret())
+            .json(&json!({
+                "channel": channel,
+                "text": truncate(text, MAX_MESSAGE_CHARS),
+                "unfurl_links": false,
+            }))
+            .send()
+            .await
+            .map_err(|e| SlackError::Unavailable(e.to_string()))?;
+
+        let posted: PostedMessage = parse(response).await?;
+        Ok(posted.ts)
+    }
+}
+
+/// Maps HTTP failures to errors, treating 429 and 5xx as transient, then
+/// unwraps Slack's `ok` envelope.
+async fn parse<T: serde::de::DeserializeOwned>(
+    response: reqwest::Response,
+) -> Result<T, SlackError> {
+    let status = response.status();
+    if status == StatusCode::TOO_MANY_REQUESTS || status.is_server_error() {
+        return Err(SlackError::Unavailable(status.to_string()));
+    }
+    if !status.is_success() {
+        let body = response.text().await.unwrap_or_default();
+        return Err(SlackError::Api(format!("{}: {}", status, body)));
+    }
+    let envelope: ApiResponse<T> = response
+        .json()
+        .await
+        .map_err(|e| SlackError::Api(format!("Invalid Slack response: {}", e)))?;
+    envelope.into_result()
+}
+
+/// `chat.postMessage` body: Block Kit blocks plus plain `text` for
+/// notifications and clients that cannot render blocks.
+fn recommendation_message(channel: &str, digest: &RecommendationDigest) -> Value {
+    let mut blocks = vec![json!({
+        "type": "header",
+        "text": {
+            "type": "plain_text",
+            "text": truncate(&digest.title, MAX_HEADER_CHARS),
+        },
+    })];
+
+    if let Some(standout) = &digest.standout {
+        blocks.push(mrkdwn_section(&format!(
+            "*Recommended:* {}",
+            escape(standout)
+        )));
+    }
+    if !digest.synthesis.is_empty() {
+        blocks.push(mrkdwn_section(&escape(&digest.synthesis)));
+    }
+    if !digest.caveats.is_empty() {
+        let caveats: Vec<String> = digest
+            .caveats
+            .iter()
+            .map(|caveat| format!("• {}", escape(caveat)))
+            .collect();
+        blocks.push(mrkdwn_section(&format!(
+            "*Caveats*\n{}",
+            caveats.join("\n")
+        )));
+    }
+    if let Some(dq) = &digest.decision_quality {
+        let mut text = format!("*Decision quality:* {}%", dq.overall_score.value());
+        if !dq.weakest_elements.is_empty() {
+            text.push_str(&format!(
+                " (weakest: {})",
+                escape(&dq.weakest_elements.join(", "))
+            ));
+        }
+        blocks.push(mrkdwn_section(&text));
+    }
+    blocks.push(json!({
+        "type": "actions",
+        "elements": [{
+            "type": "button",
+            "text": { "type": "plain_text", "text": "View decision document" },
+            "url": digest.link,
+        }],
+    }));
+
+    json!({
+        "channel": channel,
+        "text": format!("Recommendation for {}: {}", digest.title, digest.link),
+        "blocks": blocks,
+        "unfurl_links": false,
+    })
+}
+
+fn mrkdwn_section(text: &str) -> Value {
+    json!({
+        "type": "section",
+        "text": { "type": "mrkdwn", "text": truncate(text, MAX_SECTION_CHARS) },
+    })
+}
+
+/// Escapes the characters Slack treats as control sequences in mrkdwn.
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
+
+/// Cuts `text` to at most `max` characters, ending with an ellipsis.
+fn truncate(text: &str, max: usize) -> String {
+    if text.chars().count() <= max {
+        return text.to_string();
+    }
+    let mut cut: String = text.chars().take(max - 1).collect();
+    cut.push('…');
+    cut
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::document::DecisionQualityDigest;
+    use crate::domain::foundation::Percentage;
+
+    fn client() -> SlackApiClient {
+        SlackApiClient::new(SlackConfig::new(
+            "client-123",
+            "secret",
+            "https://app.example.com/integrations/slack",
+        ))
+    }
+
+    fn digest() -> RecommendationDigest {
+        RecommendationDigest {
+            title: "Choose a supplier".to_string(),
+            standout: Some("Acme <EU>".to_string()),
+            synthesis: "Acme wins on cost & lead time.".to_string(),
+            caveats: vec!["Prices fixed for one year".to_string()],
+            link: "https://app.example.com/public/documents/abc".to_string(),
+            decision_quality: None,
+        }
+    }
+
+    #[test]
+    fn authorization_url_requests_bot_posting_scopes() {
+        let url = Url::parse(&client().authorization_url("abc 123")).unwrap();
+        let params: std::collections::HashMap<_, _> = url.query_pairs().into_owned().collect();
+
+        assert_eq!(url.host_str(), Some("slack.com"));
+        assert_eq!(params["client_id"], "client-123");
+        assert_eq!(params["scope"], SLACK_BOT_SCOPES);
+        assert_eq!(params["state"], "abc 123");
+    }
+
+    #[test]
+    fn message_formats_the_digest_as_blocks() {
+        let message = recommendation_message("C456", &digest());
+        let blocks = message["blocks"].as_array().unwrap();
+
+        assert_eq!(message["channel"], "C456");
+        assert_eq!(blocks[0]["type"], "header");
+        assert_eq!(blocks[0]["text"]["text"], "Choose a supplier");
+        assert_eq!(blocks[1]["text"]["text"], "*Recommended:* Acme &lt;EU&gt;");
+        assert_eq!(
+            blocks[2]["text"]["text"],
+            "Acme wins on cost &amp; lead time."
+        );
+        assert_eq!(
+            blocks[3]["text"]["text"],
+            "*Caveats*\n• Prices fixed for one year"
+        );
+        assert_eq!(
+            blocks[4]["elements"][0]["url"],
+            "https://app.example.com/public/documents/abc"
+        );
+        assert!(message["text"]
+            .as_str()
+            .unwrap()
+            .ends_with("/public/documents/abc"));
+    }
+
+    #[test]
+    fn message_includes_the_decision_quality_score() {
+        let digest = RecommendationDigest {
+            decision_quality: Some(DecisionQualityDigest {
+                overall_score: Percentage::new(55),
+                weakest_elements: vec!["Clear Tradeoffs".to_string()],
+            }),
+            ..digest()
+        };
+
+        let message = recommendation_message("C456", &digest);
+
+        assert_eq!(
+            message["blocks"][4]["text"]["text"],
+            "*Decision quality:* 55% (weakest: Clear Tradeoffs)"
+        );
+        assert_eq!(message["blocks"][5]["type"], "actions");
+    }
+
+    #[test]
+    fn message_omits_empty_sections() {
+        let digest = RecommendationDigest {
+            standout: None,
+            caveats: vec![],
+            ..digest()
+        };
+
+        let message = recommendation_message("C456", &digest);
+        let types: Vec<&str> = message["blocks"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|b| b["type"].as_str().unwrap())
+            .collect();
+
+        assert_eq!(types, ["header", "section", "actions"]);
+    }
+
+    #[test]
+    fn long_titles_fit_the_header_limit() {
+        let title = "x".repeat(200);
+        let truncated = truncate(&title, MAX_HEADER_CHARS);
+        assert_eq!(truncated.chars().count(), MAX_HEADER_CHARS);
+        assert!(truncated.ends_with('…'));
+    }
+
+    #[test]
+    fn error_codes_map_to_error_kinds() {
+        let response = |error: &str| ApiResponse::<PostedMessage> {
+            ok: false,
+            error: Some(error.to_string()),
+            body: None,
+        };
+
+        assert!(matches!(
+            response("token_revoked").into_result(),
+            Err(SlackError::Unauthorized(_))
+        ));
+        assert!(matches!(
+            response("ratelimited").into_result(),
+            Err(SlackError::Unavailable(_))
+        ));
+        assert!(matches!(
+            response("channel_not_found").into_result(),
+            Err(SlackError::Api(_))
+        ));
+    }
+
+    #[test]
+    fn parses_the_oauth_response() {
+        let response: ApiResponse<OAuthAccess> = serde_json::from_str(
+            r#"{"ok":true,"access_token":"xoxb-1","team":{"id":"T123","name":"Acme"}}"#,
+        )
+        .unwrap();
+
+        let access = response.into_result().unwrap();
+        assert_eq!(access.team.id, "T123");
+        assert_eq!(access.access_token, "xoxb-1");
+
+        let failed: ApiResponse<OAuthAccess> =
+            serde_json::from_str(r#"{"ok":false,"error":"invalid_code"}"#).unwrap();
+        assert!(matches!(
+            failed.into_result(),
+            Err(SlackError::Unauthorized(_))
+        ));
+    }
+}
@@ -0,0 +1,7 @@
+//! Spreadsheet adapters.
+//!
+//! - `SpreadsheetReader` - Reads CSV (RFC 4180) and XLSX uploads for imports
+
+mod reader;
+
+pub use reader::SpreadsheetReader;
@@ -0,0 +1,157 @@
+//! SpreadsheetReader - Implementation of SpreadsheetParser for CSV and XLSX.
+//!
+//! CSV follows RFC 4180: comma-separated, double-quoted fields may contain
+//! commas, newlines, and doubled quotes. A UTF-8 byte order mark, as written
+//! by Excel, is skipped. XLSX files are read with `calamine`; only the first
+//! worksheet is used.
+
+use std::io::Cursor;
+
+use calamine::{open_workbook_from_rs, Data, Reader, Xlsx};
+
+use crate::ports::{SpreadsheetError, SpreadsheetFormat, SpreadsheetParser};
+
+/// Reads CSV and XLSX spreadsheets.
+#[derive(Debug, Clone, Default)]
+pub struct SpreadsheetReader;
+
+impl SpreadsheetReader {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl SpreadsheetParser for SpreadsheetReader {
+    fn parse(
+        &self,
+        format: SpreadsheetFormat,
+        bytes: &[u8],
+    ) -> Result<Vec<Vec<String>>, SpreadsheetError> {
+        match format {
+            SpreadsheetFormat::Csv => parse_csv(bytes),
+            SpreadsheetFormat::Xlsx => parse_xlsx(bytes),
+        }
+    }
+}
+
+fn parse_csv(bytes: &[u8]) -> Result<Vec<Vec<String>>, SpreadsheetError> {
+    let text = std::str::from_utf8(bytes)
+        .map_err(|_| SpreadsheetError::Malformed("CSV must be UTF-8 encoded".to_string()))?;
+    let text = text.strip_prefix('\u{feff}').unwrap_or(text);
+
+    let mut rows = Vec::new();
+    let mut row = Vec::new();
+    let mut field = String::new();
+    let mut in_quotes = false;
+    let mut chars = text.chars().peekable();
+    while let Some(c) = chars.next() {
+        if in_quotes {
+            match c {
+                '"' if chars.peek() == Some(&'"') => {
+                    chars.next();
+                    field.push('"');
+                }
+                '"' => in_quotes = false,
+                _ => field.push(c),
+            }
+            continue;
+        }
+        match c {
+            '"' if field.is_empty() => in_quotes = true,
+            ',' => row.push(std::mem::take(&mut field).trim().to_string()),
+            '\r' if chars.peek() == Some(&'\n') => {}
+            '\n' | '\r' => {
+                row.push(std::mem::take(&mut field).trim().to_string());
+                rows.push(std::mem::take(&mut row));
+            }
+            _ => field.push(c),
+        }
+    }
+    if in_quotes {
+        return Err(SpreadsheetError::Malformed(
+            "CSV has an unterminated quoted field".to_string(),
+        ));
+    }
+    if !field.is_empty() || !row.is_empty() {
+        row.push(field.trim().to_string());
+        rows.push(row);
+    }
+    Ok(rows)
+}
+
+fn parse_xlsx(bytes: &[u8]) -> Result<Vec<Vec<String>>, SpreadsheetError> {
+    let mut workbook: Xlsx<_> = open_workbook_from_rs(Cursor::new(bytes))
+        .map_err(|e: calamine::XlsxError| SpreadsheetError::Malformed(e.to_string()))?;
+    let range = workbook
+        .worksheet_range_at(0)
+        .ok_or_else(|| SpreadsheetError::Malformed("Workbook has no worksheets".to_string()))?
+        .map_err(|e| SpreadsheetError::Malformed(e.to_string()))?;
+
+    Ok(range
+        .rows()
+        .map(|row| row.iter().map(cell_text).collect())
+        .collect())
+}
+
+fn cell_text(cell: &Data) -> String {
+    match cell {
+        Data::Empty => String::new(),
+        Data::Float(f) if f.fract() == 0.0 && f.abs() < 1e15 => format!("{}", *f as i64),
+        Data::Float(f) => f.to_string(),
+        Data::Int(i) => i.to_string(),
+        Data::Bool(b) => b.to_string(),
+        other => other.to_string().trim().to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_quoted_csv_fields() {
+        let csv = "\u{feff}Option,Cost,Notes\r\n\"Move, downtown\",2100,\"Says \"\"walkable\"\"\nfor sure\"\r\nStay put,1450,\n";
+
+        let rows = SpreadsheetReader::new()
+            .parse(SpreadsheetFormat::Csv, csv.as_bytes())
+            .unwrap();
+
+        assert_eq!(
+            rows,
+            vec![
+                vec!["Option", "Cost", "Notes"],
+                vec!["Move, downtown", "2100", "Says \"walkable\"\nfor sure"],
+                vec!["Stay put", "1450", ""],
+            ]
+        );
+    }
+
+    #[test]
+    fn keeps_a_final_row_without_newline() {
+        let rows = parse_csv(b"a,b\n1,2").unwrap();
+        assert_eq!(rows, vec![vec!["a", "b"], vec!["1", "2"]]);
+    }
+
+    #[test]
+    fn rejects_unterminated_quotes() {
+        assert!(matches!(
+            parse_csv(b"a,\"b\n1,2"),
+            Err(SpreadsheetError::Malformed(_))
+        ));
+    }
+
+    #[test]
+    fn rejects_files_that_are_not_xlsx() {
+        assert!(matches!(
+            SpreadsheetReader::new().parse(SpreadsheetFormat::Xlsx, b"not a zip"),
+            Err(SpreadsheetError::Malformed(_))
+        ));
+    }
+
+    #[test]
+    fn renders_whole_numbers_without_decimals() {
+        assert_eq!(cell_text(&Data::Float(1450.0)), "1450");
+        assert_eq!(cell_text(&Data::Float(-1.5)), "-1.5");
+        assert_eq!(cell_text(&Data::String(" +2 ".to_string())), "+2");
+    }
+}
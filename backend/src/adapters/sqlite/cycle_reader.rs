@@ -0,0 +1,559 @@
+//! SQLite implementation of CycleReader.
+//!
+//! Provides read-optimized queries for cycle data. Progress, next actions
+//! and PrOACT letter statuses are computed exactly as the PostgreSQL reader
+//! computes them.
+
+use std::collections::HashMap;
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::types::Json;
+use sqlx::SqlitePool;
+
+use crate::adapters::postgres::{
+    component_statuses_to_proact_status, component_type_to_proact_letter, progress_view,
+};
+use crate::domain::cycle::CycleTreeNode as PrOACTTreeNode;
+use crate::domain::foundation::{
+    ComponentStatus, ComponentType, CycleId, DomainError, OrganizationId, SessionId, Timestamp,
+};
+use crate::ports::{
+    ComponentOutputView, ComponentStatusItem, CycleProgressView, CycleReader, CycleSummary,
+    CycleTreeNode, CycleView,
+};
+
+use super::{
+    component_type_to_str, db_error, parse_id, str_to_component_status, str_to_component_type,
+    str_to_cycle_status,
+};
+
+/// Components counted towards progress (NotesNextSteps is optional).
+const REQUIRED_COUNT: u8 = 8;
+
+/// SQLite implementation of CycleReader.
+#[derive(Clone)]
+pub struct SqliteCycleReader {
+    pool: SqlitePool,
+}
+
+impl SqliteCycleReader {
+    /// Creates a new SqliteCycleReader.
+    pub fn new(pool: SqlitePool) -> Self {
+        Self { pool }
+    }
+
+    async fn component_statuses(
+        &self,
+        cycle_id: &str,
+    ) -> Result<HashMap<ComponentType, ComponentStatus>, DomainError> {
+        let rows: Vec<(String, String)> =
+            sqlx::query_as("SELECT component_type, status FROM components WHERE cycle_id = $1")
+                .bind(cycle_id)
+                .fetch_all(&self.pool)
+                .await
+                .map_err(|e| db_error("fetch components", e))?;
+
+        rows.iter()
+            .map(|(ct, status)| Ok((str_to_component_type(ct)?, str_to_component_status(status)?)))
+            .collect()
+    }
+
+    async fn summaries(
+        &self,
+        condition: &str,
+        value: String,
+    ) -> Result<Vec<SummaryRow>, DomainError> {
+        sqlx::query_as(&format!(
+            r#"
+            SELECT c.id, c.session_id, c.parent_cycle_id, c.branch_point, c.status,
+                   c.current_step, c.created_at, c.updated_at,
+                   (SELECT COUNT(*) FROM components comp
+                    WHERE comp.cycle_id = c.id AND comp.status = 'complete') AS completed_count
+            FROM cycles c
+            {}
+            "#,
+            condition
+        ))
+        .bind(value)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| db_error("fetch cycles", e))
+    }
+}
+
+/// Row for a cycle with its count of completed components.
+#[derive(Debug, sqlx::FromRow)]
+struct SummaryRow {
+    id: String,
+    session_id: String,
+    parent_cycle_id: Option<String>,
+    branch_point: Option<String>,
+    status: String,
+    current_step: String,
+    created_at: DateTime<Utc>,
+    updated_at: DateTime<Utc>,
+    completed_count: i64,
+}
+
+impl SummaryRow {
+    fn progress_percent(&self) -> u8 {
+        let progress = (self.completed_count as f32 / REQUIRED_COUNT as f32) * 100.0;
+        (progress as u8).min(100)
+    }
+
+    fn to_summary(&self) -> Result<CycleSummary, DomainError> {
+        Ok(CycleSummary {
+            id: parse_id("id", &self.id)?,
+            is_branch: self.parent_cycle_id.is_some(),
+            branch_point: self
+                .branch_point
+                .as_deref()
+                .map(str_to_component_type)
+                .transpose()?,
+            status: str_to_cycle_status(&self.status)?,
+            current_step: str_to_component_type(&self.current_step)?,
+            progress_percent: self.progress_percent(),
+            created_at: Timestamp::from_datetime(self.created_at),
+        })
+    }
+}
+
+/// Row for component output queries.
+#[derive(Debug, sqlx::FromRow)]
+struct OutputRow {
+    component_type: String,
+    status: String,
+    output: Json<serde_json::Value>,
+    updated_at: DateTime<Utc>,
+}
+
+impl OutputRow {
+    fn into_view(self, cycle_id: CycleId) -> Result<ComponentOutputView, DomainError> {
+        Ok(ComponentOutputView {
+            cycle_id,
+            component_type: str_to_component_type(&self.component_type)?,
+            status: str_to_component_status(&self.status)?,
+            output: self.output.0,
+            updated_at: Timestamp::from_datetime(self.updated_at),
+        })
+    }
+}
+
+#[async_trait]
+impl CycleReader for SqliteCycleReader {
+    #[tracing::instrument(name = "SqliteCycleReader::get_by_id", skip_all, fields(db.system = "sqlite"), err)]
+    async fn get_by_id(&self, id: &CycleId) -> Result<Option<CycleView>, DomainError> {
+        let Some(row) = self
+            .summaries("WHERE c.id = $1", id.to_string())
+            .await?
+            .pop()
+        else {
+            return Ok(None);
+        };
+
+        let statuses = self.component_statuses(&row.id).await?;
+        let (branch_count,): (i64,) =
+            sqlx::query_as("SELECT COUNT(*) FROM cycles WHERE parent_cycle_id = $1")
+                .bind(&row.id)
+                .fetch_one(&self.pool)
+                .await
+                .map_err(|e| db_error("count branches", e))?;
+
+        let current_step = str_to_component_type(&row.current_step)?;
+        let component_statuses = ComponentType::all()
+            .iter()
+            .map(|ct| ComponentStatusItem {
+                component_type: *ct,
+                status: statuses
+                    .get(ct)
+                    .copied()
+                    .unwrap_or(ComponentStatus::NotStarted),
+                is_current: *ct == current_step,
+            })
+            .collect();
+
+        Ok(Some(CycleView {
+            id: *id,
+            session_id: parse_id("session_id", &row.session_id)?,
+            parent_cycle_id: row
+                .parent_cycle_id
+                .as_deref()
+                .map(|id| parse_id("parent_cycle_id", id))
+                .transpose()?,
+            branch_point: row
+                .branch_point
+                .as_deref()
+                .map(str_to_component_type)
+                .transpose()?,
+            status: str_to_cycle_status(&row.status)?,
+            current_step,
+            component_statuses,
+            progress_percent: row.progress_percent(),
+            is_complete: row.completed_count >= i64::from(REQUIRED_COUNT),
+            branch_count: branch_count as u32,
+            created_at: Timestamp::from_datetime(row.created_at),
+            updated_at: Timestamp::from_datetime(row.updated_at),
+        }))
+    }
+
+    #[tracing::instrument(name = "SqliteCycleReader::list_by_session_id", skip_all, fields(db.system = "sqlite"), err)]
+    async fn list_by_session_id(
+        &self,
+        session_id: &SessionId,
+    ) -> Result<Vec<CycleSummary>, DomainError> {
+        self.summaries(
+            "WHERE c.session_id = $1 ORDER BY c.created_at DESC",
+            session_id.to_string(),
+        )
+        .await?
+        .iter()
+        .map(SummaryRow::to_summary)
+        .collect()
+    }
+
+    #[tracing::instrument(name = "SqliteCycleReader::list_by_organization", skip_all, fields(db.system = "sqlite"), err)]
+    async fn list_by_organization(
+        &self,
+        organization_id: &OrganizationId,
+    ) -> Result<HashMap<SessionId, Vec<CycleSummary>>, DomainError> {
+        let rows = self
+            .summaries(
+                "JOIN sessions s ON s.id = c.session_id \
+                 WHERE s.organization_id = $1 ORDER BY c.created_at DESC",
+                organization_id.to_string(),
+            )
+            .await?;
+
+        let mut by_session: HashMap<SessionId, Vec<CycleSummary>> = HashMap::new();
+        for row in rows {
+            by_session
+                .entry(parse_id("session_id", &row.session_id)?)
+                .or_default()
+                .push(row.to_summary()?);
+        }
+        Ok(by_session)
+    }
+
+    #[tracing::instrument(name = "SqliteCycleReader::get_tree", skip_all, fields(db.system = "sqlite"), err)]
+    async fn get_tree(&self, session_id: &SessionId) -> Result<Option<CycleTreeNode>, DomainError> {
+        let rows = self
+            .summaries(
+                "WHERE c.session_id = $1 ORDER BY c.created_at ASC",
+                session_id.to_string(),
+            )
+            .await?;
+
+        let mut nodes = HashMap::new();
+        for row in &rows {
+            nodes.insert(
+                row.id.clone(),
+                CycleTreeNode {
+                    cycle: row.to_summary()?,
+                    children: Vec::new(),
+                },
+            );
+        }
+
+        Ok(build_tree(&rows, &mut nodes, |node, children| {
+            node.children = children
+        }))
+    }
+
+    #[tracing::instrument(name = "SqliteCycleReader::get_progress", skip_all, fields(db.system = "sqlite"), err)]
+    async fn get_progress(&self, id: &CycleId) -> Result<Option<CycleProgressView>, DomainError> {
+        let row: Option<(String, String)> =
+            sqlx::query_as("SELECT current_step, status FROM cycles WHERE id = $1")
+                .bind(id.to_string())
+                .fetch_optional(&self.pool)
+                .await
+                .map_err(|e| db_error("fetch cycle", e))?;
+        let Some((current_step, status)) = row else {
+            return Ok(None);
+        };
+
+        let statuses = self.component_statuses(&id.to_string()).await?;
+        Ok(Some(progress_view(
+            *id,
+            str_to_component_type(&current_step)?,
+            str_to_cycle_status(&status)?,
+            &statuses,
+        )))
+    }
+
+    #[tracing::instrument(name = "SqliteCycleReader::get_lineage", skip_all, fields(db.system = "sqlite"), err)]
+    async fn get_lineage(&self, id: &CycleId) -> Result<Vec<CycleSummary>, DomainError> {
+        let rows: Vec<SummaryRow> = sqlx::query_as(
+            r#"
+            WITH RECURSIVE lineage AS (
+                SELECT id, 0 AS depth FROM cycles WHERE id = $1
+
+                UNION ALL
+
+                SELECT c.parent_cycle_id, l.depth + 1
+                FROM cycles c
+                JOIN lineage l ON c.id = l.id
+                WHERE c.parent_cycle_id IS NOT NULL
+            )
+            SELECT c.id, c.session_id, c.parent_cycle_id, c.branch_point, c.status,
+                   c.current_step, c.created_at, c.updated_at,
+                   (SELECT COUNT(*) FROM components comp
+                    WHERE comp.cycle_id = c.id AND comp.status = 'complete') AS completed_count
+            FROM lineage l
+            JOIN cycles c ON c.id = l.id
+            ORDER BY l.depth DESC
+            "#,
+        )
+        .bind(id.to_string())
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| db_error("fetch lineage", e))?;
+
+        rows.iter().map(SummaryRow::to_summary).collect()
+    }
+
+    #[tracing::instrument(name = "SqliteCycleReader::get_component_output", skip_all, fields(db.system = "sqlite"), err)]
+    async fn get_component_output(
+        &self,
+        cycle_id: &CycleId,
+        component_type: ComponentType,
+    ) -> Result<Option<ComponentOutputView>, DomainError> {
+        let row: Option<OutputRow> = sqlx::query_as(
+            r#"
+            SELECT component_type, status, output, updated_at
+            FROM components
+            WHERE cycle_id = $1 AND component_type = $2
+            "#,
+        )
+        .bind(cycle_id.to_string())
+        .bind(component_type_to_str(component_type))
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| db_error("fetch component output", e))?;
+
+        row.map(|row| row.into_view(*cycle_id)).transpose()
+    }
+
+    #[tracing::instrument(name = "SqliteCycleReader::get_component_outputs", skip_all, fields(db.system = "sqlite"), err)]
+    async fn get_component_outputs(
+        &self,
+        cycle_id: &CycleId,
+        component_types: &[ComponentType],
+    ) -> Result<HashMap<ComponentType, ComponentOutputView>, DomainError> {
+        if component_types.is_empty() {
+            return Ok(HashMap::new());
+        }
+        // Component type names are fixed identifiers, so they are safe to inline
+        let types = component_types
+            .iter()
+            .map(|ct| format!("'{}'", component_type_to_str(*ct)))
+            .collect::<Vec<_>>()
+            .join(", ");
+
+        let rows: Vec<OutputRow> = sqlx::query_as(&format!(
+            r#"
+            SELECT component_type, status, output, updated_at
+            FROM components
+            WHERE cycle_id = $1 AND component_type IN ({})
+            "#,
+            types
+        ))
+        .bind(cycle_id.to_string())
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| db_error("fetch component outputs", e))?;
+
+        rows.into_iter()
+            .map(|row| {
+                let view = row.into_view(*cycle_id)?;
+                Ok((view.component_type, view))
+            })
+            .collect()
+    }
+
+    #[tracing::instrument(name = "SqliteCycleReader::get_proact_tree_view", skip_all, fields(db.system = "sqlite"), err)]
+    async fn get_proact_tree_view(
+        &self,
+        session_id: &SessionId,
+    ) -> Result<Option<PrOACTTreeNode>, DomainError> {
+        let rows = self
+            .summaries(
+                "WHERE c.session_id = $1 ORDER BY c.created_at ASC",
+                session_id.to_string(),
+            )
+            .await?;
+
+        let mut nodes = HashMap::new();
+        for row in &rows {
+            let statuses = self.component_statuses(&row.id).await?;
+            nodes.insert(
+                row.id.clone(),
+                PrOACTTreeNode {
+                    cycle_id: parse_id("id", &row.id)?,
+                    label: format!("Cycle {}", &row.id[..8]),
+                    branch_point: row
+                        .branch_point
+                        .as_deref()
+                        .and_then(|s| str_to_component_type(s).ok())
+                        .and_then(component_type_to_proact_letter),
+                    letter_statuses: component_statuses_to_proact_status(&statuses),
+                    children: Vec::new(),
+                    updated_at: row.updated_at,
+                },
+            );
+        }
+
+        Ok(build_tree(&rows, &mut nodes, |node, children| {
+            node.children = children
+        }))
+    }
+}
+
+// ════════════════════════════════════════════════════════════════════════════
+// Helper functions
+// ════════════════════════════════════════════════════════════════════════════
+
+/// Assembles `nodes` (keyed by cycle ID) into a tree under the session's
+/// root cycle, keeping children in the order of `rows`.
+fn build_tree<N>(
+    rows: &[SummaryRow],
+    nodes: &mut HashMap<String, N>,
+    set_children: fn(&mut N, Vec<N>),
+) -> Option<N> {
+    fn build<N>(
+        id: &str,
+        rows: &[SummaryRow],
+        nodes: &mut HashMap<String, N>,
+        set_children: fn(&mut N, Vec<N>),
+    ) -> Option<N> {
+        let mut node = nodes.remove(id)?;
+        let children = rows
+            .iter()
+            .filter(|row| row.parent_cycle_id.as_deref() == Some(id))
+            .filter_map(|row| build(&row.id, rows, nodes, set_children))
+            .collect();
+        set_children(&mut node, children);
+        Some(node)
+    }
+
+    let root = rows.iter().find(|row| row.parent_cycle_id.is_none())?;
+    build(&root.id, rows, nodes, set_children)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::adapters::sqlite::{test_pool, SqliteCycleRepository, SqliteSessionRepository};
+    use crate::domain::cycle::{Cycle, LetterStatus};
+    use crate::domain::foundation::UserId;
+    use crate::domain::session::Session;
+    use crate::ports::{CycleRepository, NextActionType, SessionRepository};
+
+    /// A session with a primary cycle whose issue raising is in progress,
+    /// and a branch of that cycle.
+    async fn seeded() -> (SqliteCycleReader, SessionId, Cycle, Cycle) {
+        let pool = test_pool().await;
+        let session = Session::new(
+            SessionId::new(),
+            UserId::new("user-1").unwrap(),
+            "Which job?".to_string(),
+        )
+        .unwrap();
+        SqliteSessionRepository::new(pool.clone())
+            .save(&session)
+            .await
+            .unwrap();
+
+        let cycles = SqliteCycleRepository::new(pool.clone());
+        let mut primary = Cycle::new(*session.id());
+        primary
+            .start_component(ComponentType::IssueRaising)
+            .unwrap();
+        cycles.save(&primary).await.unwrap();
+
+        let branch = Cycle::reconstitute(
+            CycleId::new(),
+            *session.id(),
+            Some(primary.id()),
+            Some(ComponentType::IssueRaising),
+            Default::default(),
+            primary.status(),
+            ComponentType::IssueRaising,
+            HashMap::new(),
+            Timestamp::now(),
+            Timestamp::now(),
+            0,
+        )
+        .unwrap();
+        cycles.save(&branch).await.unwrap();
+
+        (SqliteCycleReader::new(pool), *session.id(), primary, branch)
+    }
+
+    #[tokio::test]
+    async fn get_by_id_counts_branches() {
+        let (reader, session_id, primary, _) = seeded().await;
+
+        let view = reader.get_by_id(&primary.id()).await.unwrap().unwrap();
+
+        assert_eq!(view.session_id, session_id);
+        assert_eq!(view.branch_count, 1);
+        assert_eq!(view.component_statuses.len(), ComponentType::all().len());
+        assert_eq!(
+            view.component_statuses[0].status,
+            ComponentStatus::InProgress
+        );
+    }
+
+    #[tokio::test]
+    async fn progress_suggests_continuing_current_step() {
+        let (reader, _, primary, _) = seeded().await;
+
+        let progress = reader.get_progress(&primary.id()).await.unwrap().unwrap();
+
+        let next = progress.next_action.unwrap();
+        assert_eq!(next.action_type, NextActionType::ContinueCurrent);
+        assert_eq!(next.component, Some(ComponentType::IssueRaising));
+    }
+
+    #[tokio::test]
+    async fn tree_and_lineage_follow_branches() {
+        let (reader, session_id, primary, branch) = seeded().await;
+
+        let tree = reader.get_tree(&session_id).await.unwrap().unwrap();
+        let lineage = reader.get_lineage(&branch.id()).await.unwrap();
+        let proact = reader
+            .get_proact_tree_view(&session_id)
+            .await
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(tree.cycle.id, primary.id());
+        assert_eq!(tree.children[0].cycle.id, branch.id());
+        assert_eq!(
+            lineage.iter().map(|c| c.id).collect::<Vec<_>>(),
+            vec![primary.id(), branch.id()]
+        );
+        assert_eq!(proact.children.len(), 1);
+        assert_eq!(proact.letter_statuses.p, LetterStatus::NotStarted);
+    }
+
+    #[tokio::test]
+    async fn reads_component_outputs() {
+        let (reader, _, primary, _) = seeded().await;
+
+        let outputs = reader
+            .get_component_outputs(
+                &primary.id(),
+                &[ComponentType::IssueRaising, ComponentType::Objectives],
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(outputs.len(), 2);
+        assert_eq!(
+            outputs[&ComponentType::IssueRaising].status,
+            ComponentStatus::InProgress
+        );
+    }
+}
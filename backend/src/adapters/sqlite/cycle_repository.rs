@@ -0,0 +1,565 @@
+//! SQLite implementation of CycleRepository.
+//!
+//! Persists Cycle aggregates to SQLite with component outputs stored as
+//! JSON text. Unlike the PostgreSQL repository it keeps no event stream, so
+//! cycle history is unavailable on SQLite.
+
+use std::collections::HashMap;
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::types::Json;
+use sqlx::{Sqlite, SqlitePool, Transaction};
+
+use crate::domain::cycle::{BranchMetadata, Cycle};
+use crate::domain::foundation::{
+    ComponentType, CycleId, DomainError, ErrorCode, SessionId, Timestamp,
+};
+use crate::domain::proact::ComponentVariant;
+use crate::ports::CycleRepository;
+
+use super::{
+    component_status_to_str, component_type_to_str, cycle_status_to_str, db_error, parse_id,
+    str_to_component_status, str_to_component_type, str_to_cycle_status,
+};
+
+/// SQLite implementation of CycleRepository.
+#[derive(Clone)]
+pub struct SqliteCycleRepository {
+    pool: SqlitePool,
+}
+
+impl SqliteCycleRepository {
+    /// Creates a new SqliteCycleRepository.
+    pub fn new(pool: SqlitePool) -> Self {
+        Self { pool }
+    }
+
+    async fn find_where(&self, condition: &str, value: String) -> Result<Vec<Cycle>, DomainError> {
+        let rows: Vec<CycleRow> = sqlx::query_as(&format!(
+            r#"
+            SELECT id, session_id, parent_cycle_id, branch_point, status,
+                   current_step, created_at, updated_at, version
+            FROM cycles
+            WHERE {}
+            "#,
+            condition
+        ))
+        .bind(value)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| db_error("fetch cycles", e))?;
+
+        let mut cycles = Vec::with_capacity(rows.len());
+        for row in rows {
+            let components = load_components(&self.pool, &row.id).await?;
+            cycles.push(row.into_cycle(components)?);
+        }
+        Ok(cycles)
+    }
+}
+
+/// Database row representation of a cycle.
+#[derive(Debug, sqlx::FromRow)]
+struct CycleRow {
+    id: String,
+    session_id: String,
+    parent_cycle_id: Option<String>,
+    branch_point: Option<String>,
+    status: String,
+    current_step: String,
+    created_at: DateTime<Utc>,
+    updated_at: DateTime<Utc>,
+    version: i64,
+}
+
+impl CycleRow {
+    fn into_cycle(
+        self,
+        components: HashMap<ComponentType, ComponentVariant>,
+    ) -> Result<Cycle, DomainError> {
+        Cycle::reconstitute(
+            parse_id("id", &self.id)?,
+            parse_id::<SessionId>("session_id", &self.session_id)?,
+            self.parent_cycle_id
+                .as_deref()
+                .map(|id| parse_id("parent_cycle_id", id))
+                .transpose()?,
+            self.branch_point
+                .as_deref()
+                .map(str_to_component_type)
+                .transpose()?,
+            BranchMetadata::default(),
+            str_to_cycle_status(&self.status)?,
+            str_to_component_type(&self.current_step)?,
+            components,
+            Timestamp::from_datetime(self.created_at),
+            Timestamp::from_datetime(self.updated_at),
+            self.version as u64,
+        )
+    }
+}
+
+/// Database row representation of a component.
+#[derive(Debug, sqlx::FromRow)]
+struct ComponentRow {
+    id: String,
+    component_type: String,
+    status: String,
+    output: Json<serde_json::Value>,
+    created_at: DateTime<Utc>,
+    updated_at: DateTime<Utc>,
+}
+
+#[async_trait]
+impl CycleRepository for SqliteCycleRepository {
+    #[tracing::instrument(name = "SqliteCycleRepository::save", skip_all, fields(db.system = "sqlite"), err)]
+    async fn save(&self, cycle: &Cycle) -> Result<(), DomainError> {
+        let mut tx = self
+            .pool
+            .begin()
+            .await
+            .map_err(|e| db_error("begin transaction", e))?;
+
+        sqlx::query(
+            r#"
+            INSERT INTO cycles (
+                id, session_id, parent_cycle_id, branch_point, status,
+                current_step, created_at, updated_at, version
+            ) VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)
+            "#,
+        )
+        .bind(cycle.id().to_string())
+        .bind(cycle.session_id().to_string())
+        .bind(cycle.parent_cycle_id().map(|id| id.to_string()))
+        .bind(cycle.branch_point().map(component_type_to_str))
+        .bind(cycle_status_to_str(cycle.status()))
+        .bind(component_type_to_str(cycle.current_step()))
+        .bind(cycle.created_at().as_datetime())
+        .bind(cycle.updated_at().as_datetime())
+        .bind(cycle.version() as i64)
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| db_error("insert cycle", e))?;
+
+        save_components(&mut tx, cycle).await?;
+
+        tx.commit()
+            .await
+            .map_err(|e| db_error("commit transaction", e))
+    }
+
+    #[tracing::instrument(name = "SqliteCycleRepository::update", skip_all, fields(db.system = "sqlite"), err)]
+    async fn update(&self, cycle: &Cycle) -> Result<(), DomainError> {
+        let mut tx = self
+            .pool
+            .begin()
+            .await
+            .map_err(|e| db_error("begin transaction", e))?;
+
+        let result = sqlx::query(
+            r#"
+            UPDATE cycles SET
+                status = $2,
+                current_step = $3,
+                updated_at = $4,
+                version = $5
+            WHERE id = $1 AND version = $6
+            "#,
+        )
+        .bind(cycle.id().to_string())
+        .bind(cycle_status_to_str(cycle.status()))
+        .bind(component_type_to_str(cycle.current_step()))
+        .bind(cycle.updated_at().as_datetime())
+        .bind(cycle.version() as i64)
+        .bind(cycle.loaded_version() as i64)
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| db_error("update cycle", e))?;
+
+        if result.rows_affected() == 0 {
+            // Either the cycle is gone or another writer saved it first.
+            let exists: bool =
+                sqlx::query_scalar("SELECT EXISTS (SELECT 1 FROM cycles WHERE id = $1)")
+                    .bind(cycle.id().to_string())
+                    .fetch_one(&mut *tx)
+                    .await
+                    .map_err(|e| db_error("check cycle", e))?;
+
+            return Err(if exists {
+                DomainError::new(
+                    ErrorCode::ConcurrentModification,
+                    format!(
+                        "Cycle {} was modified since version {}",
+                        cycle.id(),
+                        cycle.loaded_version()
+                    ),
+                )
+            } else {
+                DomainError::new(
+                    ErrorCode::CycleNotFound,
+                    format!("Cycle not found: {}", cycle.id()),
+                )
+            });
+        }
+
+        save_components(&mut tx, cycle).await?;
+
+        tx.commit()
+            .await
+            .map_err(|e| db_error("commit transaction", e))
+    }
+
+    #[tracing::instrument(name = "SqliteCycleRepository::find_by_id", skip_all, fields(db.system = "sqlite"), err)]
+    async fn find_by_id(&self, id: &CycleId) -> Result<Option<Cycle>, DomainError> {
+        let mut cycles = self.find_where("id = $1", id.to_string()).await?;
+        Ok(cycles.pop())
+    }
+
+    #[tracing::instrument(name = "SqliteCycleRepository::exists", skip_all, fields(db.system = "sqlite"), err)]
+    async fn exists(&self, id: &CycleId) -> Result<bool, DomainError> {
+        let (count,): (i64,) = sqlx::query_as("SELECT COUNT(*) FROM cycles WHERE id = $1")
+            .bind(id.to_string())
+            .fetch_one(&self.pool)
+            .await
+            .map_err(|e| db_error("check cycle existence", e))?;
+
+        Ok(count > 0)
+    }
+
+    #[tracing::instrument(name = "SqliteCycleRepository::find_by_session_id", skip_all, fields(db.system = "sqlite"), err)]
+    async fn find_by_session_id(&self, session_id: &SessionId) -> Result<Vec<Cycle>, DomainError> {
+        self.find_where(
+            "session_id = $1 ORDER BY created_at DESC",
+            session_id.to_string(),
+        )
+        .await
+    }
+
+    #[tracing::instrument(name = "SqliteCycleRepository::find_primary_by_session_id", skip_all, fields(db.system = "sqlite"), err)]
+    async fn find_primary_by_session_id(
+        &self,
+        session_id: &SessionId,
+    ) -> Result<Option<Cycle>, DomainError> {
+        let mut cycles = self
+            .find_where(
+                "session_id = $1 AND parent_cycle_id IS NULL ORDER BY created_at ASC LIMIT 1",
+                session_id.to_string(),
+            )
+            .await?;
+        Ok(cycles.pop())
+    }
+
+    #[tracing::instrument(name = "SqliteCycleRepository::find_branches", skip_all, fields(db.system = "sqlite"), err)]
+    async fn find_branches(&self, parent_id: &CycleId) -> Result<Vec<Cycle>, DomainError> {
+        self.find_where(
+            "parent_cycle_id = $1 ORDER BY created_at DESC",
+            parent_id.to_string(),
+        )
+        .await
+    }
+
+    #[tracing::instrument(name = "SqliteCycleRepository::count_by_session_id", skip_all, fields(db.system = "sqlite"), err)]
+    async fn count_by_session_id(&self, session_id: &SessionId) -> Result<u32, DomainError> {
+        let (count,): (i64,) = sqlx::query_as("SELECT COUNT(*) FROM cycles WHERE session_id = $1")
+            .bind(session_id.to_string())
+            .fetch_one(&self.pool)
+            .await
+            .map_err(|e| db_error("count cycles", e))?;
+
+        Ok(count as u32)
+    }
+
+    #[tracing::instrument(name = "SqliteCycleRepository::delete", skip_all, fields(db.system = "sqlite"), err)]
+    async fn delete(&self, id: &CycleId) -> Result<(), DomainError> {
+        // Components and branches go with the cycle (ON DELETE CASCADE)
+        let result = sqlx::query("DELETE FROM cycles WHERE id = $1")
+            .bind(id.to_string())
+            .execute(&self.pool)
+            .await
+            .map_err(|e| db_error("delete cycle", e))?;
+
+        if result.rows_affected() == 0 {
+            return Err(DomainError::new(
+                ErrorCode::CycleNotFound,
+                format!("Cycle not found: {}", id),
+            ));
+        }
+
+        Ok(())
+    }
+}
+
+// ════════════════════════════════════════════════════════════════════════════
+// Helper functions
+// ════════════════════════════════════════════════════════════════════════════
+
+/// Inserts or replaces every component of the cycle within `tx`.
+async fn save_components(
+    tx: &mut Transaction<'_, Sqlite>,
+    cycle: &Cycle,
+) -> Result<(), DomainError> {
+    for component_type in ComponentType::all() {
+        let Some(component) = cycle.component(*component_type) else {
+            continue;
+        };
+        sqlx::query(
+            r#"
+            INSERT INTO components (
+                id, cycle_id, component_type, status, output, created_at, updated_at
+            ) VALUES ($1, $2, $3, $4, $5, $6, $7)
+            ON CONFLICT (cycle_id, component_type) DO UPDATE SET
+                status = excluded.status,
+                output = excluded.output,
+                updated_at = excluded.updated_at
+            "#,
+        )
+        .bind(component.id().to_string())
+        .bind(cycle.id().to_string())
+        .bind(component_type_to_str(component.component_type()))
+        .bind(component_status_to_str(component.status()))
+        .bind(Json(component.output_as_value()))
+        .bind(component.created_at().as_datetime())
+        .bind(component.updated_at().as_datetime())
+        .execute(&mut **tx)
+        .await
+        .map_err(|e| db_error("save component", e))?;
+    }
+    Ok(())
+}
+
+async fn load_components(
+    pool: &SqlitePool,
+    cycle_id: &str,
+) -> Result<HashMap<ComponentType, ComponentVariant>, DomainError> {
+    let rows: Vec<ComponentRow> = sqlx::query_as(
+        r#"
+        SELECT id, component_type, status, output, created_at, updated_at
+        FROM components
+        WHERE cycle_id = $1
+        "#,
+    )
+    .bind(cycle_id)
+    .fetch_all(pool)
+    .await
+    .map_err(|e| db_error("load components", e))?;
+
+    let mut components = HashMap::new();
+    for row in rows {
+        let component_type = str_to_component_type(&row.component_type)?;
+        let component = ComponentVariant::reconstitute(
+            parse_id("component id", &row.id)?,
+            component_type,
+            str_to_component_status(&row.status)?,
+            row.output.0,
+            Timestamp::from_datetime(row.created_at),
+            Timestamp::from_datetime(row.updated_at),
+        )?;
+        components.insert(component_type, component);
+    }
+    Ok(components)
+}
+
+#[cfg(test)]
+mod tests {
+    use sqlx::Executor;
+
+    use super::*;
+    use crate::adapters::sqlite::{empty_test_pool, test_pool, SqliteSessionRepository, MIGRATOR};
+    use crate::domain::foundation::{ComponentStatus, UserId};
+    use crate::domain::session::Session;
+    use crate::ports::SessionRepository;
+
+    async fn repo_with_session() -> (SqliteCycleRepository, SessionId) {
+        let pool = test_pool().await;
+        let session = Session::new(
+            SessionId::new(),
+            UserId::new("user-1").unwrap(),
+            "Which job?".to_string(),
+        )
+        .unwrap();
+        SqliteSessionRepository::new(pool.clone())
+            .save(&session)
+            .await
+            .unwrap();
+        (SqliteCycleRepository::new(pool), *session.id())
+    }
+
+    #[tokio::test]
+    async fn saves_and_finds_cycle_with_components() {
+        let (repo, session_id) = repo_with_session().await;
+        let mut cycle = Cycle::new(session_id);
+        cycle.start_component(ComponentType::IssueRaising).unwrap();
+
+        repo.save(&cycle).await.unwrap();
+        let found = repo.find_by_id(&cycle.id()).await.unwrap().unwrap();
+
+        assert_eq!(found.session_id(), session_id);
+        assert_eq!(found.version(), cycle.version());
+        assert_eq!(
+            found.component_status(ComponentType::IssueRaising),
+            ComponentStatus::InProgress
+        );
+        assert_eq!(repo.count_by_session_id(&session_id).await.unwrap(), 1);
+    }
+
+    #[tokio::test]
+    async fn update_persists_component_changes() {
+        let (repo, session_id) = repo_with_session().await;
+        let mut cycle = Cycle::new(session_id);
+        repo.save(&cycle).await.unwrap();
+
+        cycle.take_events();
+
+        cycle.start_component(ComponentType::IssueRaising).unwrap();
+        repo.update(&cycle).await.unwrap();
+        let found = repo
+            .find_primary_by_session_id(&session_id)
+            .await
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(
+            found.component_status(ComponentType::IssueRaising),
+            ComponentStatus::InProgress
+        );
+    }
+
+    #[tokio::test]
+    async fn concurrent_updates_from_same_version_conflict() {
+        let (repo, session_id) = repo_with_session().await;
+        let cycle = Cycle::new(session_id);
+        repo.save(&cycle).await.unwrap();
+
+        let mut first = repo.find_by_id(&cycle.id()).await.unwrap().unwrap();
+        let mut second = first.clone();
+        first.start_component(ComponentType::IssueRaising).unwrap();
+        second.start_component(ComponentType::IssueRaising).unwrap();
+
+        let (a, b) = tokio::join!(repo.update(&first), repo.update(&second));
+
+        let errors: Vec<_> = [a, b].into_iter().filter_map(Result::err).collect();
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].code, ErrorCode::ConcurrentModification);
+    }
+
+    #[tokio::test]
+    async fn update_of_missing_cycle_is_not_found() {
+        let (repo, session_id) = repo_with_session().await;
+        let cycle = Cycle::new(session_id);
+
+        let err = repo.update(&cycle).await.unwrap_err();
+
+        assert_eq!(err.code, ErrorCode::CycleNotFound);
+    }
+
+    #[tokio::test]
+    async fn delete_removes_cycle() {
+        let (repo, session_id) = repo_with_session().await;
+        let cycle = Cycle::new(session_id);
+        repo.save(&cycle).await.unwrap();
+
+        repo.delete(&cycle.id()).await.unwrap();
+
+        assert!(!repo.exists(&cycle.id()).await.unwrap());
+        let err = repo.delete(&cycle.id()).await.unwrap_err();
+        assert_eq!(err.code, ErrorCode::CycleNotFound);
+    }
+
+    /// Applies the migrations whose version `applies` accepts, in order.
+    async fn migrate(pool: &SqlitePool, applies: impl Fn(i64) -> bool) {
+        for migration in MIGRATOR.iter().filter(|m| applies(m.version)) {
+            let mut tx = pool.begin().await.unwrap();
+            tx.execute(&*migration.sql).await.unwrap();
+            tx.commit().await.unwrap();
+        }
+    }
+
+    /// Stores `cycle` with only the components that existed before
+    /// Stakeholder Analysis and the Uncertainty Register, as a database
+    /// migrated before them would hold it.
+    async fn insert_pre_stakeholder_cycle(pool: &SqlitePool, cycle: &Cycle) {
+        sqlx::query(
+            r#"
+            INSERT INTO cycles (
+                id, session_id, status, current_step, created_at, updated_at, version
+            ) VALUES ($1, $2, 'active', 'problem_frame', $3, $3, 0)
+            "#,
+        )
+        .bind(cycle.id().to_string())
+        .bind(cycle.session_id().to_string())
+        .bind(cycle.created_at().as_datetime())
+        .execute(pool)
+        .await
+        .unwrap();
+        for component_type in ComponentType::all() {
+            if matches!(
+                component_type,
+                ComponentType::StakeholderAnalysis | ComponentType::UncertaintyRegister
+            ) {
+                continue;
+            }
+            let component = cycle.component(*component_type).unwrap();
+            sqlx::query(
+                r#"
+                INSERT INTO components (
+                    id, cycle_id, component_type, status, output, created_at, updated_at
+                ) VALUES ($1, $2, $3, $4, $5, $6, $6)
+                "#,
+            )
+            .bind(component.id().to_string())
+            .bind(cycle.id().to_string())
+            .bind(component_type_to_str(*component_type))
+            .bind(component_status_to_str(component.status()))
+            .bind(Json(component.output_as_value()))
+            .bind(component.created_at().as_datetime())
+            .execute(pool)
+            .await
+            .unwrap();
+        }
+    }
+
+    #[tokio::test]
+    async fn cycles_stored_before_newer_components_get_them_not_started() {
+        const STAKEHOLDER_ANALYSIS: i64 = 20260216000000;
+        let pool = empty_test_pool().await;
+        migrate(&pool, |version| version < STAKEHOLDER_ANALYSIS).await;
+        let session = Session::new(
+            SessionId::new(),
+            UserId::new("user-1").unwrap(),
+            "Which job?".to_string(),
+        )
+        .unwrap();
+        SqliteSessionRepository::new(pool.clone())
+            .save(&session)
+            .await
+            .unwrap();
+        let mut old = Cycle::new(*session.id());
+        old.start_component(ComponentType::IssueRaising).unwrap();
+        old.start_component(ComponentType::ProblemFrame).unwrap();
+        insert_pre_stakeholder_cycle(&pool, &old).await;
+
+        migrate(&pool, |version| version >= STAKEHOLDER_ANALYSIS).await;
+        let repo = SqliteCycleRepository::new(pool);
+        let mut cycle = repo.find_by_id(&old.id()).await.unwrap().unwrap();
+
+        assert_eq!(
+            cycle.component_status(ComponentType::StakeholderAnalysis),
+            ComponentStatus::NotStarted
+        );
+        assert_eq!(
+            cycle.component_status(ComponentType::UncertaintyRegister),
+            ComponentStatus::NotStarted
+        );
+        cycle
+            .start_component(ComponentType::StakeholderAnalysis)
+            .unwrap();
+        cycle.start_component(ComponentType::Objectives).unwrap();
+        repo.update(&cycle).await.unwrap();
+        let found = repo.find_by_id(&cycle.id()).await.unwrap().unwrap();
+        assert_eq!(
+            found.component_status(ComponentType::StakeholderAnalysis),
+            ComponentStatus::InProgress
+        );
+    }
+}
@@ -0,0 +1,330 @@
+//! SQLite implementation of MembershipReader.
+//!
+//! Provides read-optimized queries for membership data.
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::SqlitePool;
+
+use crate::domain::foundation::{DomainError, ErrorCode, Timestamp, UserId};
+use crate::domain::membership::{MembershipStatus, MembershipTier};
+use crate::ports::{
+    MembershipReader, MembershipStatistics, MembershipSummary, MembershipView, StatusCounts,
+    TierCounts,
+};
+
+use super::{db_error, parse_id, str_to_membership_status, str_to_tier};
+
+/// SQLite implementation of the MembershipReader port.
+pub struct SqliteMembershipReader {
+    pool: SqlitePool,
+}
+
+impl SqliteMembershipReader {
+    /// Creates a new SqliteMembershipReader with the given connection pool.
+    pub fn new(pool: SqlitePool) -> Self {
+        Self { pool }
+    }
+
+    async fn find_by_user(&self, user_id: &UserId) -> Result<Option<MembershipRow>, DomainError> {
+        sqlx::query_as(
+            r#"
+            SELECT id, user_id, tier, status, current_period_end, promo_code, created_at
+            FROM memberships
+            WHERE user_id = $1
+            "#,
+        )
+        .bind(user_id.as_str())
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| db_error("get membership", e))
+    }
+}
+
+/// Row for membership view and summary queries.
+#[derive(Debug, sqlx::FromRow)]
+struct MembershipRow {
+    id: String,
+    user_id: String,
+    tier: String,
+    status: String,
+    current_period_end: Option<DateTime<Utc>>,
+    promo_code: Option<String>,
+    created_at: DateTime<Utc>,
+}
+
+fn calculate_days_remaining(period_end: Option<DateTime<Utc>>) -> u32 {
+    let Some(end) = period_end else {
+        return 0;
+    };
+    end.signed_duration_since(Utc::now()).num_days().max(0) as u32
+}
+
+fn calculate_has_access(status: &MembershipStatus, period_end: Option<DateTime<Utc>>) -> bool {
+    if !status.has_access() {
+        return false;
+    }
+
+    // For cancelled status, check if period has ended
+    if *status == MembershipStatus::Cancelled {
+        return period_end.is_some_and(|end| Utc::now() <= end);
+    }
+
+    true
+}
+
+impl TryFrom<MembershipRow> for MembershipView {
+    type Error = DomainError;
+
+    fn try_from(row: MembershipRow) -> Result<Self, Self::Error> {
+        let status = str_to_membership_status(&row.status)?;
+
+        Ok(MembershipView {
+            id: parse_id("id", &row.id)?,
+            user_id: UserId::new(row.user_id).map_err(|e| {
+                DomainError::new(ErrorCode::DatabaseError, format!("Invalid user_id: {}", e))
+            })?,
+            tier: str_to_tier(&row.tier)?,
+            status,
+            has_access: calculate_has_access(&status, row.current_period_end),
+            days_remaining: calculate_days_remaining(row.current_period_end),
+            period_end: row
+                .current_period_end
+                .map(Timestamp::from_datetime)
+                .unwrap_or_else(Timestamp::now),
+            promo_code: row.promo_code,
+            created_at: Timestamp::from_datetime(row.created_at),
+        })
+    }
+}
+
+impl TryFrom<MembershipRow> for MembershipSummary {
+    type Error = DomainError;
+
+    fn try_from(row: MembershipRow) -> Result<Self, Self::Error> {
+        Ok(MembershipSummary {
+            id: parse_id("id", &row.id)?,
+            user_id: UserId::new(row.user_id).map_err(|e| {
+                DomainError::new(ErrorCode::DatabaseError, format!("Invalid user_id: {}", e))
+            })?,
+            tier: str_to_tier(&row.tier)?,
+            status: str_to_membership_status(&row.status)?,
+            period_end: row
+                .current_period_end
+                .map(Timestamp::from_datetime)
+                .unwrap_or_else(Timestamp::now),
+        })
+    }
+}
+
+#[async_trait]
+impl MembershipReader for SqliteMembershipReader {
+    #[tracing::instrument(name = "SqliteMembershipReader::get_by_user", skip_all, fields(db.system = "sqlite"), err)]
+    async fn get_by_user(&self, user_id: &UserId) -> Result<Option<MembershipView>, DomainError> {
+        self.find_by_user(user_id)
+            .await?
+            .map(MembershipView::try_from)
+            .transpose()
+    }
+
+    #[tracing::instrument(name = "SqliteMembershipReader::check_access", skip_all, fields(db.system = "sqlite"), err)]
+    async fn check_access(&self, user_id: &UserId) -> Result<bool, DomainError> {
+        let Some(row) = self.find_by_user(user_id).await? else {
+            return Ok(false);
+        };
+        let status = str_to_membership_status(&row.status)?;
+        Ok(calculate_has_access(&status, row.current_period_end))
+    }
+
+    #[tracing::instrument(name = "SqliteMembershipReader::get_tier", skip_all, fields(db.system = "sqlite"), err)]
+    async fn get_tier(&self, user_id: &UserId) -> Result<Option<MembershipTier>, DomainError> {
+        self.find_by_user(user_id)
+            .await?
+            .map(|row| str_to_tier(&row.tier))
+            .transpose()
+    }
+
+    #[tracing::instrument(name = "SqliteMembershipReader::list_expiring", skip_all, fields(db.system = "sqlite"), err)]
+    async fn list_expiring(&self, days: u32) -> Result<Vec<MembershipSummary>, DomainError> {
+        let now = Utc::now();
+        let expiry_threshold = now + chrono::Duration::days(i64::from(days));
+
+        let rows: Vec<MembershipRow> = sqlx::query_as(
+            r#"
+            SELECT id, user_id, tier, status, current_period_end, promo_code, created_at
+            FROM memberships
+            WHERE status IN ('active', 'cancelled')
+              AND current_period_end IS NOT NULL
+              AND julianday(current_period_end) > julianday($1)
+              AND julianday(current_period_end) <= julianday($2)
+            ORDER BY julianday(current_period_end) ASC
+            "#,
+        )
+        .bind(now)
+        .bind(expiry_threshold)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| db_error("list expiring memberships", e))?;
+
+        rows.into_iter().map(MembershipSummary::try_from).collect()
+    }
+
+    #[tracing::instrument(name = "SqliteMembershipReader::get_statistics", skip_all, fields(db.system = "sqlite"), err)]
+    async fn get_statistics(&self) -> Result<MembershipStatistics, DomainError> {
+        let rows: Vec<(String, String, i64)> =
+            sqlx::query_as("SELECT tier, status, COUNT(*) FROM memberships GROUP BY tier, status")
+                .fetch_all(&self.pool)
+                .await
+                .map_err(|e| db_error("get membership counts", e))?;
+
+        let mut total_count = 0;
+        let mut by_tier = TierCounts::default();
+        let mut by_status = StatusCounts::default();
+        for (tier, status, count) in rows {
+            let count = count as u64;
+            total_count += count;
+            match str_to_tier(&tier)? {
+                MembershipTier::Free => by_tier.free += count,
+                MembershipTier::Monthly => by_tier.monthly += count,
+                MembershipTier::Annual => by_tier.annual += count,
+            }
+            match str_to_membership_status(&status)? {
+                MembershipStatus::Pending => by_status.pending += count,
+                MembershipStatus::Trialing => by_status.trialing += count,
+                MembershipStatus::Active => by_status.active += count,
+                MembershipStatus::PastDue => by_status.past_due += count,
+                MembershipStatus::Cancelled => by_status.cancelled += count,
+                MembershipStatus::Expired => by_status.expired += count,
+            }
+        }
+        let active_count =
+            by_status.trialing + by_status.active + by_status.past_due + by_status.cancelled;
+
+        // Calculate MRR (Monthly Recurring Revenue), as the PostgreSQL reader does
+        const MONTHLY_PRICE_CENTS: i64 = 1999;
+        const ANNUAL_MONTHLY_EQUIVALENT_CENTS: i64 = 14999 / 12;
+
+        let mrr = (by_tier.monthly as i64 * MONTHLY_PRICE_CENTS)
+            + (by_tier.annual as i64 * ANNUAL_MONTHLY_EQUIVALENT_CENTS);
+
+        Ok(MembershipStatistics {
+            total_count,
+            active_count,
+            by_tier,
+            by_status,
+            monthly_recurring_revenue_cents: mrr,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::adapters::sqlite::{test_pool, SqliteMembershipRepository};
+    use crate::domain::foundation::MembershipId;
+    use crate::domain::membership::Membership;
+    use crate::ports::MembershipRepository;
+
+    async fn reader_with(
+        memberships: &[(&str, MembershipTier, MembershipStatus, i64)],
+    ) -> SqliteMembershipReader {
+        let pool = test_pool().await;
+        let repo = SqliteMembershipRepository::new(pool.clone());
+        for (user, tier, status, period_end_days) in memberships {
+            let now = Timestamp::now();
+            repo.save(&Membership {
+                id: MembershipId::new(),
+                user_id: UserId::new(*user).unwrap(),
+                tier: *tier,
+                status: *status,
+                current_period_start: now,
+                current_period_end: Timestamp::from_datetime(
+                    Utc::now() + chrono::Duration::days(*period_end_days),
+                ),
+                promo_code: None,
+                stripe_customer_id: None,
+                stripe_subscription_id: None,
+                seats: 1,
+                created_at: now,
+                updated_at: now,
+                cancelled_at: None,
+            })
+            .await
+            .unwrap();
+        }
+        SqliteMembershipReader::new(pool)
+    }
+
+    #[tokio::test]
+    async fn cancelled_membership_keeps_access_until_period_end() {
+        let reader = reader_with(&[
+            (
+                "current",
+                MembershipTier::Monthly,
+                MembershipStatus::Cancelled,
+                5,
+            ),
+            (
+                "lapsed",
+                MembershipTier::Monthly,
+                MembershipStatus::Cancelled,
+                -1,
+            ),
+        ])
+        .await;
+
+        let current = reader
+            .get_by_user(&UserId::new("current").unwrap())
+            .await
+            .unwrap()
+            .unwrap();
+
+        assert!(current.has_access);
+        assert!(current.days_remaining >= 4);
+        assert!(!reader
+            .check_access(&UserId::new("lapsed").unwrap())
+            .await
+            .unwrap());
+        assert!(!reader
+            .check_access(&UserId::new("nobody").unwrap())
+            .await
+            .unwrap());
+    }
+
+    #[tokio::test]
+    async fn statistics_count_tiers_and_revenue() {
+        let reader = reader_with(&[
+            ("a", MembershipTier::Monthly, MembershipStatus::Active, 30),
+            ("b", MembershipTier::Annual, MembershipStatus::Trialing, 30),
+            ("c", MembershipTier::Free, MembershipStatus::Expired, -1),
+        ])
+        .await;
+
+        let stats = reader.get_statistics().await.unwrap();
+
+        assert_eq!(stats.total_count, 3);
+        assert_eq!(stats.active_count, 2);
+        assert_eq!(stats.by_tier.annual, 1);
+        assert_eq!(stats.by_status.expired, 1);
+        assert_eq!(stats.monthly_recurring_revenue_cents, 1999 + 14999 / 12);
+    }
+
+    #[tokio::test]
+    async fn lists_expiring_memberships() {
+        let reader = reader_with(&[
+            ("soon", MembershipTier::Monthly, MembershipStatus::Active, 2),
+            (
+                "later",
+                MembershipTier::Monthly,
+                MembershipStatus::Active,
+                60,
+            ),
+        ])
+        .await;
+
+        let expiring = reader.list_expiring(7).await.unwrap();
+
+        assert_eq!(expiring.len(), 1);
+        assert_eq!(expiring[0].user_id.as_str(), "soon");
+    }
+}
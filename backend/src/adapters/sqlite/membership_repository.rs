@@ -0,0 +1,322 @@
+//! SQLite implementation of MembershipRepository.
+//!
+//! Provides persistent storage for Membership aggregates using SQLite.
+//! User IDs are stored as given rather than required to be UUIDs.
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::SqlitePool;
+
+use crate::domain::foundation::{DomainError, ErrorCode, MembershipId, Timestamp, UserId};
+use crate::domain::membership::Membership;
+use crate::ports::MembershipRepository;
+
+use super::{
+    db_error, membership_status_to_str, parse_id, str_to_membership_status, str_to_tier,
+    tier_to_str,
+};
+
+/// SQLite implementation of the MembershipRepository port.
+pub struct SqliteMembershipRepository {
+    pool: SqlitePool,
+}
+
+impl SqliteMembershipRepository {
+    /// Creates a new SqliteMembershipRepository with the given connection pool.
+    pub fn new(pool: SqlitePool) -> Self {
+        Self { pool }
+    }
+
+    async fn find_one(&self, column: &str, value: &str) -> Result<Option<Membership>, DomainError> {
+        let row: Option<MembershipRow> = sqlx::query_as(&format!(
+            "{} WHERE {} = $1 LIMIT 1",
+            SELECT_MEMBERSHIP, column
+        ))
+        .bind(value)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| db_error("find membership", e))?;
+
+        row.map(Membership::try_from).transpose()
+    }
+}
+
+/// Database row representation of a membership.
+#[derive(Debug, sqlx::FromRow)]
+struct MembershipRow {
+    id: String,
+    user_id: String,
+    tier: String,
+    status: String,
+    stripe_customer_id: Option<String>,
+    stripe_subscription_id: Option<String>,
+    seats: i64,
+    promo_code: Option<String>,
+    current_period_start: Option<DateTime<Utc>>,
+    current_period_end: Option<DateTime<Utc>>,
+    created_at: DateTime<Utc>,
+    updated_at: DateTime<Utc>,
+}
+
+const SELECT_MEMBERSHIP: &str = r#"
+    SELECT id, user_id, tier, status, stripe_customer_id, stripe_subscription_id, seats,
+           promo_code, current_period_start, current_period_end, created_at, updated_at
+    FROM memberships
+"#;
+
+impl TryFrom<MembershipRow> for Membership {
+    type Error = DomainError;
+
+    fn try_from(row: MembershipRow) -> Result<Self, Self::Error> {
+        // For period dates, use created_at as fallback
+        let created_at = Timestamp::from_datetime(row.created_at);
+
+        Ok(Membership {
+            id: parse_id("id", &row.id)?,
+            user_id: UserId::new(row.user_id).map_err(|e| {
+                DomainError::new(ErrorCode::DatabaseError, format!("Invalid user_id: {}", e))
+            })?,
+            tier: str_to_tier(&row.tier)?,
+            status: str_to_membership_status(&row.status)?,
+            current_period_start: row
+                .current_period_start
+                .map(Timestamp::from_datetime)
+                .unwrap_or(created_at),
+            current_period_end: row
+                .current_period_end
+                .map(Timestamp::from_datetime)
+                .unwrap_or(created_at),
+            promo_code: row.promo_code,
+            stripe_customer_id: row.stripe_customer_id,
+            stripe_subscription_id: row.stripe_subscription_id,
+            seats: row.seats as u32,
+            created_at,
+            updated_at: Timestamp::from_datetime(row.updated_at),
+            cancelled_at: None, // Derived from status, not stored separately
+        })
+    }
+}
+
+#[async_trait]
+impl MembershipRepository for SqliteMembershipRepository {
+    #[tracing::instrument(name = "SqliteMembershipRepository::save", skip_all, fields(db.system = "sqlite"), err)]
+    async fn save(&self, membership: &Membership) -> Result<(), DomainError> {
+        sqlx::query(
+            r#"
+            INSERT INTO memberships (
+                id, user_id, tier, status, stripe_customer_id, stripe_subscription_id,
+                seats, promo_code, current_period_start, current_period_end, created_at, updated_at
+            ) VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12)
+            "#,
+        )
+        .bind(membership.id.to_string())
+        .bind(membership.user_id.as_str())
+        .bind(tier_to_str(membership.tier))
+        .bind(membership_status_to_str(membership.status))
+        .bind(&membership.stripe_customer_id)
+        .bind(&membership.stripe_subscription_id)
+        .bind(i64::from(membership.seats))
+        .bind(&membership.promo_code)
+        .bind(membership.current_period_start.as_datetime())
+        .bind(membership.current_period_end.as_datetime())
+        .bind(membership.created_at.as_datetime())
+        .bind(membership.updated_at.as_datetime())
+        .execute(&self.pool)
+        .await
+        .map_err(|e| {
+            if let sqlx::Error::Database(db_err) = &e {
+                if db_err.is_unique_violation() && db_err.message().contains("user_id") {
+                    return DomainError::new(
+                        ErrorCode::MembershipExists,
+                        "User already has a membership",
+                    );
+                }
+            }
+            db_error("save membership", e)
+        })?;
+
+        Ok(())
+    }
+
+    #[tracing::instrument(name = "SqliteMembershipRepository::update", skip_all, fields(db.system = "sqlite"), err)]
+    async fn update(&self, membership: &Membership) -> Result<(), DomainError> {
+        let result = sqlx::query(
+            r#"
+            UPDATE memberships SET
+                tier = $2,
+                status = $3,
+                stripe_customer_id = $4,
+                stripe_subscription_id = $5,
+                promo_code = $6,
+                current_period_start = $7,
+                current_period_end = $8,
+                updated_at = $9,
+                seats = $10,
+                version = version + 1
+            WHERE id = $1
+            "#,
+        )
+        .bind(membership.id.to_string())
+        .bind(tier_to_str(membership.tier))
+        .bind(membership_status_to_str(membership.status))
+        .bind(&membership.stripe_customer_id)
+        .bind(&membership.stripe_subscription_id)
+        .bind(&membership.promo_code)
+        .bind(membership.current_period_start.as_datetime())
+        .bind(membership.current_period_end.as_datetime())
+        .bind(membership.updated_at.as_datetime())
+        .bind(i64::from(membership.seats))
+        .execute(&self.pool)
+        .await
+        .map_err(|e| db_error("update membership", e))?;
+
+        if result.rows_affected() == 0 {
+            return Err(DomainError::new(
+                ErrorCode::MembershipNotFound,
+                "Membership not found",
+            ));
+        }
+
+        Ok(())
+    }
+
+    #[tracing::instrument(name = "SqliteMembershipRepository::find_by_id", skip_all, fields(db.system = "sqlite"), err)]
+    async fn find_by_id(&self, id: &MembershipId) -> Result<Option<Membership>, DomainError> {
+        self.find_one("id", &id.to_string()).await
+    }
+
+    #[tracing::instrument(name = "SqliteMembershipRepository::find_by_user_id", skip_all, fields(db.system = "sqlite"), err)]
+    async fn find_by_user_id(&self, user_id: &UserId) -> Result<Option<Membership>, DomainError> {
+        self.find_one("user_id", user_id.as_str()).await
+    }
+
+    #[tracing::instrument(name = "SqliteMembershipRepository::find_expiring_within_days", skip_all, fields(db.system = "sqlite"), err)]
+    async fn find_expiring_within_days(&self, days: u32) -> Result<Vec<Membership>, DomainError> {
+        let now = Utc::now();
+        let expiry_threshold = now + chrono::Duration::days(i64::from(days));
+
+        let rows: Vec<MembershipRow> = sqlx::query_as(&format!(
+            r#"
+            {}
+            WHERE status IN ('active', 'cancelled')
+              AND current_period_end IS NOT NULL
+              AND julianday(current_period_end) > julianday($1)
+              AND julianday(current_period_end) <= julianday($2)
+            ORDER BY julianday(current_period_end) ASC
+            "#,
+            SELECT_MEMBERSHIP
+        ))
+        .bind(now)
+        .bind(expiry_threshold)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| db_error("find expiring memberships", e))?;
+
+        rows.into_iter().map(Membership::try_from).collect()
+    }
+
+    #[tracing::instrument(name = "SqliteMembershipRepository::delete", skip_all, fields(db.system = "sqlite"), err)]
+    async fn delete(&self, id: &MembershipId) -> Result<(), DomainError> {
+        let result = sqlx::query("DELETE FROM memberships WHERE id = $1")
+            .bind(id.to_string())
+            .execute(&self.pool)
+            .await
+            .map_err(|e| db_error("delete membership", e))?;
+
+        if result.rows_affected() == 0 {
+            return Err(DomainError::new(
+                ErrorCode::MembershipNotFound,
+                "Membership not found",
+            ));
+        }
+
+        Ok(())
+    }
+
+    #[tracing::instrument(name = "SqliteMembershipRepository::find_by_stripe_subscription_id", skip_all, fields(db.system = "sqlite"), err)]
+    async fn find_by_stripe_subscription_id(
+        &self,
+        subscription_id: &str,
+    ) -> Result<Option<Membership>, DomainError> {
+        self.find_one("stripe_subscription_id", subscription_id)
+            .await
+    }
+
+    #[tracing::instrument(name = "SqliteMembershipRepository::find_by_stripe_customer_id", skip_all, fields(db.system = "sqlite"), err)]
+    async fn find_by_stripe_customer_id(
+        &self,
+        customer_id: &str,
+    ) -> Result<Option<Membership>, DomainError> {
+        self.find_one("stripe_customer_id", customer_id).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::adapters::sqlite::test_pool;
+    use crate::domain::membership::{MembershipStatus, MembershipTier};
+
+    fn membership(user: &str, period_end_days: i64) -> Membership {
+        let now = Timestamp::now();
+        Membership {
+            id: MembershipId::new(),
+            user_id: UserId::new(user).unwrap(),
+            tier: MembershipTier::Monthly,
+            status: MembershipStatus::Active,
+            current_period_start: now,
+            current_period_end: Timestamp::from_datetime(
+                Utc::now() + chrono::Duration::days(period_end_days),
+            ),
+            promo_code: None,
+            stripe_customer_id: Some(format!("cus_{}", user)),
+            stripe_subscription_id: None,
+            seats: 1,
+            created_at: now,
+            updated_at: now,
+            cancelled_at: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn saves_and_finds_membership() {
+        let repo = SqliteMembershipRepository::new(test_pool().await);
+        let saved = membership("user-1", 30);
+        repo.save(&saved).await.unwrap();
+
+        let by_user = repo.find_by_user_id(&saved.user_id).await.unwrap().unwrap();
+        let by_customer = repo
+            .find_by_stripe_customer_id("cus_user-1")
+            .await
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(by_user.id, saved.id);
+        assert_eq!(by_user.tier, MembershipTier::Monthly);
+        assert_eq!(by_customer.id, saved.id);
+    }
+
+    #[tokio::test]
+    async fn rejects_second_membership_for_user() {
+        let repo = SqliteMembershipRepository::new(test_pool().await);
+        repo.save(&membership("user-1", 30)).await.unwrap();
+
+        let err = repo.save(&membership("user-1", 30)).await.unwrap_err();
+
+        assert_eq!(err.code, ErrorCode::MembershipExists);
+    }
+
+    #[tokio::test]
+    async fn finds_memberships_expiring_within_days() {
+        let repo = SqliteMembershipRepository::new(test_pool().await);
+        let soon = membership("user-1", 3);
+        repo.save(&soon).await.unwrap();
+        repo.save(&membership("user-2", 30)).await.unwrap();
+        repo.save(&membership("user-3", -1)).await.unwrap();
+
+        let expiring = repo.find_expiring_within_days(7).await.unwrap();
+
+        assert_eq!(expiring.len(), 1);
+        assert_eq!(expiring[0].id, soon.id);
+    }
+}
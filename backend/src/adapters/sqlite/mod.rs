@@ -0,0 +1,358 @@
+//! SQLite adapters - Single-binary self-hosting without PostgreSQL or Redis.
+//!
+//! Enabled by the `sqlite` cargo feature and selected by a `sqlite:` URL in
+//! `DatabaseConfig`. Covers the core session, cycle and membership ports;
+//! everything else (event log, cold storage, integrations) stays on the
+//! PostgreSQL adapters.
+//!
+//! # Tables
+//!
+//! - `sessions` - Session aggregate data
+//! - `cycles` - Cycle aggregate metadata
+//! - `components` - Component data with JSON outputs
+//! - `memberships` - User membership/subscription data
+//!
+//! The schema lives in `migrations/sqlite`, apart from the PostgreSQL
+//! migrations, and is applied by [`connect`] when `run_migrations` is set.
+
+mod cycle_reader;
+mod cycle_repository;
+mod membership_reader;
+mod membership_repository;
+mod session_reader;
+mod session_repository;
+
+pub use cycle_reader::SqliteCycleReader;
+pub use cycle_repository::SqliteCycleRepository;
+pub use membership_reader::SqliteMembershipReader;
+pub use membership_repository::SqliteMembershipRepository;
+pub use session_reader::SqliteSessionReader;
+pub use session_repository::SqliteSessionRepository;
+
+use std::fmt::Display;
+use std::str::FromStr;
+
+use sqlx::migrate::Migrator;
+use sqlx::sqlite::{SqliteConnectOptions, SqlitePoolOptions};
+use sqlx::SqlitePool;
+
+use crate::config::DatabaseConfig;
+use crate::domain::foundation::{
+    ComponentStatus, ComponentType, CycleStatus, DomainError, ErrorCode, SessionStatus,
+};
+use crate::domain::membership::{MembershipStatus, MembershipTier};
+use crate::domain::session::Session;
+
+/// Migrations for the SQLite schema.
+pub static MIGRATOR: Migrator = sqlx::migrate!("./migrations/sqlite");
+
+/// Opens the database file named by `config.url`, creating it if missing,
+/// and applies the migrations when `config.run_migrations` is set.
+pub async fn connect(config: &DatabaseConfig) -> Result<SqlitePool, sqlx::Error> {
+    let options = SqliteConnectOptions::from_str(&config.url)?
+        .create_if_missing(true)
+        .foreign_keys(true);
+
+    let pool = SqlitePoolOptions::new()
+        .min_connections(config.min_connections)
+        .max_connections(config.max_connections)
+        .acquire_timeout(config.acquire_timeout())
+        .idle_timeout(config.idle_timeout())
+        .max_lifetime(config.max_lifetime())
+        .connect_with(options)
+        .await?;
+
+    if config.run_migrations {
+        MIGRATOR.run(&pool).await?;
+    }
+    Ok(pool)
+}
+
+// ════════════════════════════════════════════════════════════════════════════
+// Helper functions
+// ════════════════════════════════════════════════════════════════════════════
+
+fn db_error(action: &str, e: impl Display) -> DomainError {
+    DomainError::new(
+        ErrorCode::DatabaseError,
+        format!("Failed to {}: {}", action, e),
+    )
+}
+
+/// Parses an ID stored as hyphenated text.
+fn parse_id<T>(column: &str, value: &str) -> Result<T, DomainError>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    value.parse().map_err(|e| {
+        DomainError::new(
+            ErrorCode::DatabaseError,
+            format!("Invalid {}: {}", column, e),
+        )
+    })
+}
+
+fn component_type_to_str(ct: ComponentType) -> &'static str {
+    match ct {
+        ComponentType::IssueRaising => "issue_raising",
+        ComponentType::ProblemFrame => "problem_frame",
+        ComponentType::StakeholderAnalysis => "stakeholder_analysis",
+        ComponentType::Objectives => "objectives",
+        ComponentType::Alternatives => "alternatives",
+        ComponentType::Consequences => "consequences",
+        ComponentType::UncertaintyRegister => "uncertainty_register",
+        ComponentType::Tradeoffs => "tradeoffs",
+        ComponentType::Recommendation => "recommendation",
+        ComponentType::DecisionQuality => "decision_quality",
+        ComponentType::NotesNextSteps => "notes_next_steps",
+    }
+}
+
+fn str_to_component_type(s: &str) -> Result<ComponentType, DomainError> {
+    ComponentType::all()
+        .iter()
+        .copied()
+        .find(|ct| component_type_to_str(*ct) == s)
+        .ok_or_else(|| {
+            DomainError::new(
+                ErrorCode::InvalidFormat,
+                format!("Invalid component type: {}", s),
+            )
+        })
+}
+
+fn cycle_status_to_str(status: CycleStatus) -> &'static str {
+    match status {
+        CycleStatus::Active => "active",
+        CycleStatus::Completed => "completed",
+        CycleStatus::Archived => "archived",
+    }
+}
+
+fn str_to_cycle_status(s: &str) -> Result<CycleStatus, DomainError> {
+    match s {
+        "active" => Ok(CycleStatus::Active),
+        "completed" => Ok(CycleStatus::Completed),
+        "archived" => Ok(CycleStatus::Archived),
+        _ => Err(DomainError::new(
+            ErrorCode::InvalidFormat,
+            format!("Invalid cycle status: {}", s),
+        )),
+    }
+}
+
+fn component_status_to_str(status: ComponentStatus) -> &'static str {
+    match status {
+        ComponentStatus::NotStarted => "not_started",
+        ComponentStatus::InProgress => "in_progress",
+        ComponentStatus::Complete => "complete",
+        ComponentStatus::NeedsRevision => "needs_revision",
+    }
+}
+
+fn str_to_component_status(s: &str) -> Result<ComponentStatus, DomainError> {
+    match s {
+        "not_started" => Ok(ComponentStatus::NotStarted),
+        "in_progress" => Ok(ComponentStatus::InProgress),
+        "complete" => Ok(ComponentStatus::Complete),
+        "needs_revision" => Ok(ComponentStatus::NeedsRevision),
+        _ => Err(DomainError::new(
+            ErrorCode::InvalidFormat,
+            format!("Invalid component status: {}", s),
+        )),
+    }
+}
+
+fn session_status_to_str(status: SessionStatus) -> &'static str {
+    match status {
+        SessionStatus::Active => "active",
+        SessionStatus::Archived => "archived",
+        // Never stored; see `stored_session_status`
+        SessionStatus::Deleted => "deleted",
+    }
+}
+
+/// The `status` column of a session. A deleted session keeps the status
+/// it is restored to, and `deleted_at` marks it as trashed.
+fn stored_session_status(session: &Session) -> &'static str {
+    session_status_to_str(
+        session
+            .status_before_deletion()
+            .unwrap_or_else(|| session.status()),
+    )
+}
+
+/// SQL condition matching sessions `s` in `status`.
+fn session_status_filter(status: SessionStatus) -> String {
+    match status {
+        SessionStatus::Deleted => "s.deleted_at IS NOT NULL".to_string(),
+        status => format!(
+            "s.status = '{}' AND s.deleted_at IS NULL",
+            session_status_to_str(status)
+        ),
+    }
+}
+
+/// A session's status from its `status` and `deleted_at` columns.
+fn row_session_status(
+    status: &str,
+    deleted_at: Option<&chrono::DateTime<chrono::Utc>>,
+) -> Result<SessionStatus, DomainError> {
+    match deleted_at {
+        Some(_) => Ok(SessionStatus::Deleted),
+        None => str_to_session_status(status),
+    }
+}
+
+fn str_to_session_status(s: &str) -> Result<SessionStatus, DomainError> {
+    match s {
+        "active" => Ok(SessionStatus::Active),
+        "archived" => Ok(SessionStatus::Archived),
+        _ => Err(DomainError::new(
+            ErrorCode::DatabaseError,
+            format!("Invalid session status: {}", s),
+        )),
+    }
+}
+
+fn tier_to_str(tier: MembershipTier) -> &'static str {
+    match tier {
+        MembershipTier::Free => "free",
+        MembershipTier::Monthly => "monthly",
+        MembershipTier::Annual => "annual",
+    }
+}
+
+fn str_to_tier(s: &str) -> Result<MembershipTier, DomainError> {
+    match s {
+        "free" => Ok(MembershipTier::Free),
+        "monthly" => Ok(MembershipTier::Monthly),
+        "annual" => Ok(MembershipTier::Annual),
+        _ => Err(DomainError::new(
+            ErrorCode::DatabaseError,
+            format!("Invalid tier value: {}", s),
+        )),
+    }
+}
+
+fn membership_status_to_str(status: MembershipStatus) -> &'static str {
+    match status {
+        MembershipStatus::Pending => "pending",
+        MembershipStatus::Trialing => "trialing",
+        MembershipStatus::Active => "active",
+        MembershipStatus::PastDue => "past_due",
+        MembershipStatus::Cancelled => "cancelled",
+        MembershipStatus::Expired => "expired",
+    }
+}
+
+fn str_to_membership_status(s: &str) -> Result<MembershipStatus, DomainError> {
+    match s {
+        "pending" => Ok(MembershipStatus::Pending),
+        "trialing" => Ok(MembershipStatus::Trialing),
+        "active" => Ok(MembershipStatus::Active),
+        "past_due" => Ok(MembershipStatus::PastDue),
+        "cancelled" => Ok(MembershipStatus::Cancelled),
+        "expired" => Ok(MembershipStatus::Expired),
+        _ => Err(DomainError::new(
+            ErrorCode::DatabaseError,
+            format!("Invalid status value: {}", s),
+        )),
+    }
+}
+
+/// A migrated in-memory database on a single connection, so every query
+/// sees the same data.
+#[cfg(test)]
+async fn test_pool() -> SqlitePool {
+    let pool = empty_test_pool().await;
+    MIGRATOR.run(&pool).await.unwrap();
+    pool
+}
+
+/// An in-memory database on a single connection, with no schema yet.
+#[cfg(test)]
+async fn empty_test_pool() -> SqlitePool {
+    let options = SqliteConnectOptions::from_str("sqlite::memory:")
+        .unwrap()
+        .foreign_keys(true);
+    SqlitePoolOptions::new()
+        .max_connections(1)
+        .idle_timeout(None)
+        .max_lifetime(None)
+        .connect_with(options)
+        .await
+        .unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn component_type_round_trips() {
+        for ct in ComponentType::all() {
+            let back = str_to_component_type(component_type_to_str(*ct)).unwrap();
+            assert_eq!(*ct, back);
+        }
+        assert!(str_to_component_type("invalid").is_err());
+    }
+
+    #[test]
+    fn membership_status_round_trips() {
+        let statuses = [
+            MembershipStatus::Pending,
+            MembershipStatus::Trialing,
+            MembershipStatus::Active,
+            MembershipStatus::PastDue,
+            MembershipStatus::Cancelled,
+            MembershipStatus::Expired,
+        ];
+        for status in statuses {
+            let back = str_to_membership_status(membership_status_to_str(status)).unwrap();
+            assert_eq!(status, back);
+        }
+    }
+
+    #[test]
+    fn deleted_sessions_are_told_apart_by_deleted_at() {
+        let deleted_at = chrono::Utc::now();
+
+        assert_eq!(
+            row_session_status("archived", Some(&deleted_at)).unwrap(),
+            SessionStatus::Deleted
+        );
+        assert_eq!(
+            row_session_status("archived", None).unwrap(),
+            SessionStatus::Archived
+        );
+        assert_eq!(
+            session_status_filter(SessionStatus::Active),
+            "s.status = 'active' AND s.deleted_at IS NULL"
+        );
+    }
+
+    #[tokio::test]
+    async fn connect_creates_and_migrates_database_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let config = DatabaseConfig {
+            url: format!("sqlite://{}", dir.path().join("sherpa.db").display()),
+            min_connections: 1,
+            max_connections: 2,
+            run_migrations: true,
+            ..Default::default()
+        };
+
+        let pool = connect(&config).await.unwrap();
+        let (tables,): (i64,) = sqlx::query_as(
+            "SELECT COUNT(*) FROM sqlite_master WHERE type = 'table' AND name IN \
+             ('sessions', 'cycles', 'components', 'memberships')",
+        )
+        .fetch_one(&pool)
+        .await
+        .unwrap();
+
+        assert_eq!(tables, 4);
+    }
+}
@@ -0,0 +1,442 @@
+//! SQLite implementation of SessionReader.
+//!
+//! Provides read-optimized queries for session data. Search matches the
+//! title and description with `LIKE`, and sessions are never in cold
+//! storage. Deleted sessions are told apart by `deleted_at` and only show
+//! up in trash listings and when asked for by status.
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::SqlitePool;
+
+use crate::domain::foundation::{
+    DomainError, ErrorCode, OrganizationId, SessionId, SessionStatus, Timestamp, UserId,
+};
+use crate::domain::session::TRASH_RETENTION_DAYS;
+use crate::ports::{
+    ListOptions, SessionList, SessionReader, SessionSummary, SessionView, TrashedSession,
+};
+
+use super::{
+    db_error, parse_id, row_session_status, session_status_filter, str_to_session_status,
+};
+
+/// SQLite implementation of SessionReader.
+#[derive(Clone)]
+pub struct SqliteSessionReader {
+    pool: SqlitePool,
+}
+
+impl SqliteSessionReader {
+    /// Creates a new SqliteSessionReader.
+    pub fn new(pool: SqlitePool) -> Self {
+        Self { pool }
+    }
+}
+
+/// Row for full session view queries.
+#[derive(Debug, sqlx::FromRow)]
+struct SessionViewRow {
+    id: String,
+    user_id: String,
+    organization_id: Option<String>,
+    title: String,
+    description: Option<String>,
+    status: String,
+    cycle_count: i64,
+    created_at: DateTime<Utc>,
+    updated_at: DateTime<Utc>,
+    deleted_at: Option<DateTime<Utc>>,
+}
+
+/// Row for session summary queries.
+#[derive(Debug, sqlx::FromRow)]
+struct SessionSummaryRow {
+    id: String,
+    title: String,
+    status: String,
+    cycle_count: i64,
+    updated_at: DateTime<Utc>,
+    deleted_at: Option<DateTime<Utc>>,
+}
+
+/// Row for trashed session queries; `status` is the one to restore.
+#[derive(Debug, sqlx::FromRow)]
+struct TrashedSessionRow {
+    id: String,
+    user_id: String,
+    title: String,
+    status: String,
+    cycle_count: i64,
+    deleted_at: DateTime<Utc>,
+}
+
+const SELECT_SUMMARY: &str = r#"
+    SELECT s.id, s.title, s.status, s.updated_at, s.deleted_at,
+           (SELECT COUNT(*) FROM cycles c WHERE c.session_id = s.id) AS cycle_count
+    FROM sessions s
+"#;
+
+const SELECT_TRASHED: &str = r#"
+    SELECT s.id, s.user_id, s.title, s.status, s.deleted_at,
+           (SELECT COUNT(*) FROM cycles c WHERE c.session_id = s.id) AS cycle_count
+    FROM sessions s
+"#;
+
+impl TryFrom<SessionViewRow> for SessionView {
+    type Error = DomainError;
+
+    fn try_from(row: SessionViewRow) -> Result<Self, Self::Error> {
+        Ok(SessionView {
+            id: parse_id("id", &row.id)?,
+            user_id: UserId::new(row.user_id).map_err(|e| {
+                DomainError::new(ErrorCode::DatabaseError, format!("Invalid user_id: {}", e))
+            })?,
+            organization_id: row
+                .organization_id
+                .as_deref()
+                .map(|id| parse_id("organization_id", id))
+                .transpose()?,
+            title: row.title,
+            description: row.description,
+            status: row_session_status(&row.status, row.deleted_at.as_ref())?,
+            cycle_count: row.cycle_count as u32,
+            created_at: Timestamp::from_datetime(row.created_at),
+            updated_at: Timestamp::from_datetime(row.updated_at),
+        })
+    }
+}
+
+impl TryFrom<SessionSummaryRow> for SessionSummary {
+    type Error = DomainError;
+
+    fn try_from(row: SessionSummaryRow) -> Result<Self, Self::Error> {
+        Ok(SessionSummary {
+            id: parse_id("id", &row.id)?,
+            title: row.title,
+            status: row_session_status(&row.status, row.deleted_at.as_ref())?,
+            cycle_count: row.cycle_count as u32,
+            updated_at: Timestamp::from_datetime(row.updated_at),
+            in_cold_storage: false,
+        })
+    }
+}
+
+impl TryFrom<TrashedSessionRow> for TrashedSession {
+    type Error = DomainError;
+
+    fn try_from(row: TrashedSessionRow) -> Result<Self, Self::Error> {
+        let deleted_at = Timestamp::from_datetime(row.deleted_at);
+        Ok(TrashedSession {
+            id: parse_id("id", &row.id)?,
+            user_id: UserId::new(row.user_id).map_err(|e| {
+                DomainError::new(ErrorCode::DatabaseError, format!("Invalid user_id: {}", e))
+            })?,
+            title: row.title,
+            status_before_deletion: str_to_session_status(&row.status)?,
+            cycle_count: row.cycle_count as u32,
+            deleted_at,
+            purge_after: deleted_at.add_days(TRASH_RETENTION_DAYS),
+        })
+    }
+}
+
+#[async_trait]
+impl SessionReader for SqliteSessionReader {
+    #[tracing::instrument(name = "SqliteSessionReader::get_by_id", skip_all, fields(db.system = "sqlite"), err)]
+    async fn get_by_id(&self, id: &SessionId) -> Result<Option<SessionView>, DomainError> {
+        let row: Option<SessionViewRow> = sqlx::query_as(
+            r#"
+            SELECT s.id, s.user_id, s.organization_id, s.title, s.description, s.status,
+                   s.created_at, s.updated_at, s.deleted_at,
+                   (SELECT COUNT(*) FROM cycles c WHERE c.session_id = s.id) AS cycle_count
+            FROM sessions s
+            WHERE s.id = $1
+            "#,
+        )
+        .bind(id.to_string())
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| db_error("fetch session", e))?;
+
+        row.map(SessionView::try_from).transpose()
+    }
+
+    #[tracing::instrument(name = "SqliteSessionReader::list_by_user", skip_all, fields(db.system = "sqlite"), err)]
+    async fn list_by_user(
+        &self,
+        user_id: &UserId,
+        options: &ListOptions,
+    ) -> Result<SessionList, DomainError> {
+        self.list("s.user_id = $1", user_id.as_str(), None, options)
+            .await
+    }
+
+    #[tracing::instrument(name = "SqliteSessionReader::list_by_organization", skip_all, fields(db.system = "sqlite"), err)]
+    async fn list_by_organization(
+        &self,
+        organization_id: &OrganizationId,
+        options: &ListOptions,
+    ) -> Result<SessionList, DomainError> {
+        // The organization is already fixed by $1
+        let filters = ListOptions {
+            organization_id: None,
+            ..options.clone()
+        };
+        self.list(
+            "s.organization_id = $1",
+            &organization_id.to_string(),
+            None,
+            &filters,
+        )
+        .await
+    }
+
+    #[tracing::instrument(name = "SqliteSessionReader::search", skip_all, fields(db.system = "sqlite"), err)]
+    async fn search(
+        &self,
+        user_id: &UserId,
+        query: &str,
+        options: &ListOptions,
+    ) -> Result<SessionList, DomainError> {
+        self.list("s.user_id = $1", user_id.as_str(), Some(query), options)
+            .await
+    }
+
+    #[tracing::instrument(name = "SqliteSessionReader::count_by_status", skip_all, fields(db.system = "sqlite"), err)]
+    async fn count_by_status(
+        &self,
+        user_id: &UserId,
+        status: SessionStatus,
+    ) -> Result<u64, DomainError> {
+        let (count,): (i64,) = sqlx::query_as(&format!(
+            "SELECT COUNT(*) FROM sessions s WHERE s.user_id = $1 AND {}",
+            session_status_filter(status)
+        ))
+        .bind(user_id.as_str())
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|e| db_error("count sessions by status", e))?;
+
+        Ok(count as u64)
+    }
+
+    #[tracing::instrument(name = "SqliteSessionReader::list_trashed", skip_all, fields(db.system = "sqlite"), err)]
+    async fn list_trashed(&self, user_id: &UserId) -> Result<Vec<TrashedSession>, DomainError> {
+        let rows: Vec<TrashedSessionRow> = sqlx::query_as(&format!(
+            "{} WHERE s.user_id = $1 AND s.deleted_at IS NOT NULL ORDER BY s.deleted_at DESC",
+            SELECT_TRASHED
+        ))
+        .bind(user_id.as_str())
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| db_error("list trashed sessions", e))?;
+
+        rows.into_iter().map(TrashedSession::try_from).collect()
+    }
+
+    #[tracing::instrument(name = "SqliteSessionReader::list_purge_candidates", skip_all, fields(db.system = "sqlite"), err)]
+    async fn list_purge_candidates(
+        &self,
+        deleted_before: Timestamp,
+        limit: u32,
+    ) -> Result<Vec<TrashedSession>, DomainError> {
+        let rows: Vec<TrashedSessionRow> = sqlx::query_as(&format!(
+            "{} WHERE s.deleted_at < $1 ORDER BY s.deleted_at ASC LIMIT $2",
+            SELECT_TRASHED
+        ))
+        .bind(deleted_before.as_datetime())
+        .bind(i64::from(limit))
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| db_error("list purge candidates", e))?;
+
+        rows.into_iter().map(TrashedSession::try_from).collect()
+    }
+}
+
+impl SqliteSessionReader {
+    /// Lists one page of sessions matching `owner` (a condition on `$1`),
+    /// the list filters and, when given, a search over title and
+    /// description bound to `$2`.
+    async fn list(
+        &self,
+        owner: &str,
+        owner_value: &str,
+        search: Option<&str>,
+        options: &ListOptions,
+    ) -> Result<SessionList, DomainError> {
+        let mut filter = format!(" WHERE {}", owner);
+        if search.is_some() {
+            filter.push_str(
+                " AND (s.title LIKE '%' || $2 || '%' OR s.description LIKE '%' || $2 || '%')",
+            );
+        }
+        push_list_filters(&mut filter, options);
+
+        let mut query = format!("{}{} ORDER BY s.updated_at DESC", SELECT_SUMMARY, filter);
+        // LIMIT is required before OFFSET in SQLite
+        if options.limit.is_some() || options.offset.is_some() {
+            query.push_str(&format!(" LIMIT {}", options.limit.map_or(-1, i64::from)));
+        }
+        if let Some(offset) = options.offset {
+            query.push_str(&format!(" OFFSET {}", offset));
+        }
+
+        let rows: Vec<SessionSummaryRow> = sqlx::query_as(&query)
+            .bind(owner_value)
+            .bind(search)
+            .fetch_all(&self.pool)
+            .await
+            .map_err(|e| db_error("list sessions", e))?;
+        let items = rows
+            .into_iter()
+            .map(SessionSummary::try_from)
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let (total,): (i64,) =
+            sqlx::query_as(&format!("SELECT COUNT(*) FROM sessions s{}", filter))
+                .bind(owner_value)
+                .bind(search)
+                .fetch_one(&self.pool)
+                .await
+                .map_err(|e| db_error("count sessions", e))?;
+        let total = total as u64;
+
+        let offset = options.offset.unwrap_or(0) as u64;
+        let has_more = offset + (items.len() as u64) < total;
+
+        Ok(SessionList {
+            items,
+            total,
+            has_more,
+        })
+    }
+}
+
+/// Appends the status and organization filters of `options`.
+fn push_list_filters(sql: &mut String, options: &ListOptions) {
+    if let Some(status) = options.status {
+        sql.push_str(&format!(" AND {}", session_status_filter(status)));
+    } else if !options.include_archived {
+        sql.push_str(" AND s.status = 'active' AND s.deleted_at IS NULL");
+    } else {
+        sql.push_str(" AND s.deleted_at IS NULL");
+    }
+
+    if let Some(organization_id) = options.organization_id {
+        // A formatted UUID cannot carry SQL, so it is safe to inline
+        sql.push_str(&format!(" AND s.organization_id = '{}'", organization_id));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::adapters::sqlite::{test_pool, SqliteSessionRepository};
+    use crate::domain::session::Session;
+    use crate::ports::SessionRepository;
+
+    fn user() -> UserId {
+        UserId::new("user-1").unwrap()
+    }
+
+    async fn reader_with(titles: &[&str]) -> (SqliteSessionReader, Vec<Session>) {
+        let pool = test_pool().await;
+        let repo = SqliteSessionRepository::new(pool.clone());
+        let mut sessions = Vec::new();
+        for title in titles {
+            let session = Session::new(SessionId::new(), user(), title.to_string()).unwrap();
+            repo.save(&session).await.unwrap();
+            sessions.push(session);
+        }
+        (SqliteSessionReader::new(pool), sessions)
+    }
+
+    #[tokio::test]
+    async fn get_by_id_returns_view() {
+        let (reader, sessions) = reader_with(&["Which job?"]).await;
+
+        let view = reader.get_by_id(sessions[0].id()).await.unwrap().unwrap();
+
+        assert_eq!(view.title, "Which job?");
+        assert_eq!(view.cycle_count, 0);
+        assert_eq!(view.status, SessionStatus::Active);
+    }
+
+    #[tokio::test]
+    async fn list_by_user_paginates() {
+        let (reader, _) = reader_with(&["One", "Two", "Three"]).await;
+
+        let page = reader
+            .list_by_user(&user(), &ListOptions::paginated(1, 2))
+            .await
+            .unwrap();
+
+        assert_eq!(page.items.len(), 2);
+        assert_eq!(page.total, 3);
+        assert!(page.has_more);
+    }
+
+    #[tokio::test]
+    async fn trashed_sessions_are_listed_apart() {
+        let (reader, mut sessions) = reader_with(&["Keep", "Trash"]).await;
+        let repo = SqliteSessionRepository::new(reader.pool.clone());
+        sessions[1].delete().unwrap();
+        repo.update(&sessions[1]).await.unwrap();
+
+        let listed = reader
+            .list_by_user(&user(), &ListOptions::default().with_archived())
+            .await
+            .unwrap();
+        let trashed = reader.list_trashed(&user()).await.unwrap();
+        let view = reader.get_by_id(sessions[1].id()).await.unwrap().unwrap();
+
+        assert_eq!(listed.total, 1);
+        assert_eq!(listed.items[0].title, "Keep");
+        assert_eq!(trashed.len(), 1);
+        assert_eq!(trashed[0].status_before_deletion, SessionStatus::Active);
+        assert_eq!(view.status, SessionStatus::Deleted);
+        assert_eq!(
+            reader
+                .count_by_status(&user(), SessionStatus::Deleted)
+                .await
+                .unwrap(),
+            1
+        );
+    }
+
+    #[tokio::test]
+    async fn purge_candidates_are_deleted_before_cutoff() {
+        let (reader, mut sessions) = reader_with(&["Trash"]).await;
+        let repo = SqliteSessionRepository::new(reader.pool.clone());
+        sessions[0].delete().unwrap();
+        repo.update(&sessions[0]).await.unwrap();
+
+        let due_now = reader
+            .list_purge_candidates(Timestamp::now().plus_secs(1), 10)
+            .await
+            .unwrap();
+        let due_earlier = reader
+            .list_purge_candidates(Timestamp::now().minus_days(1), 10)
+            .await
+            .unwrap();
+
+        assert_eq!(due_now.len(), 1);
+        assert_eq!(due_now[0].id, *sessions[0].id());
+        assert!(due_earlier.is_empty());
+    }
+
+    #[tokio::test]
+    async fn search_matches_title() {
+        let (reader, _) = reader_with(&["Move to Lisbon", "Buy a car"]).await;
+
+        let found = reader
+            .search(&user(), "lisbon", &ListOptions::default())
+            .await
+            .unwrap();
+
+        assert_eq!(found.total, 1);
+        assert_eq!(found.items[0].title, "Move to Lisbon");
+    }
+}
@@ -0,0 +1,295 @@
+//! SQLite implementation of SessionRepository.
+//!
+//! Persists Session aggregates to SQLite. A deleted session keeps the
+//! status it is restored to in `status`, with `deleted_at` marking it as
+//! trashed.
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::SqlitePool;
+
+use crate::domain::foundation::{CycleId, DomainError, ErrorCode, SessionId, Timestamp, UserId};
+use crate::domain::session::Session;
+use crate::ports::SessionRepository;
+
+use super::{db_error, parse_id, stored_session_status, str_to_session_status};
+
+/// SQLite implementation of SessionRepository.
+#[derive(Clone)]
+pub struct SqliteSessionRepository {
+    pool: SqlitePool,
+}
+
+impl SqliteSessionRepository {
+    /// Creates a new SqliteSessionRepository.
+    pub fn new(pool: SqlitePool) -> Self {
+        Self { pool }
+    }
+}
+
+/// Session row with its cycle IDs joined by commas.
+#[derive(Debug, sqlx::FromRow)]
+struct SessionRow {
+    id: String,
+    user_id: String,
+    organization_id: Option<String>,
+    title: String,
+    description: Option<String>,
+    status: String,
+    created_at: DateTime<Utc>,
+    updated_at: DateTime<Utc>,
+    deleted_at: Option<DateTime<Utc>>,
+    cycle_ids: Option<String>,
+}
+
+const SELECT_SESSION: &str = r#"
+    SELECT s.id, s.user_id, s.organization_id, s.title, s.description, s.status,
+           s.created_at, s.updated_at, s.deleted_at,
+           (SELECT group_concat(c.id) FROM cycles c WHERE c.session_id = s.id) AS cycle_ids
+    FROM sessions s
+"#;
+
+impl TryFrom<SessionRow> for Session {
+    type Error = DomainError;
+
+    fn try_from(row: SessionRow) -> Result<Self, Self::Error> {
+        let cycle_ids = row
+            .cycle_ids
+            .as_deref()
+            .map(|ids| {
+                ids.split(',')
+                    .map(|id| parse_id::<CycleId>("cycle_id", id))
+                    .collect()
+            })
+            .transpose()?
+            .unwrap_or_default();
+        let organization_id = row
+            .organization_id
+            .as_deref()
+            .map(|id| parse_id("organization_id", id))
+            .transpose()?;
+        let status = str_to_session_status(&row.status)?;
+
+        let session = Session::reconstitute(
+            parse_id("id", &row.id)?,
+            UserId::new(row.user_id).map_err(|e| {
+                DomainError::new(ErrorCode::DatabaseError, format!("Invalid user_id: {}", e))
+            })?,
+            row.title,
+            row.description,
+            status,
+            cycle_ids,
+            Timestamp::from_datetime(row.created_at),
+            Timestamp::from_datetime(row.updated_at),
+        )
+        .with_organization(organization_id);
+
+        Ok(match row.deleted_at {
+            Some(deleted_at) => session.with_deletion(Timestamp::from_datetime(deleted_at), status),
+            None => session,
+        })
+    }
+}
+
+#[async_trait]
+impl SessionRepository for SqliteSessionRepository {
+    #[tracing::instrument(name = "SqliteSessionRepository::save", skip_all, fields(db.system = "sqlite"), err)]
+    async fn save(&self, session: &Session) -> Result<(), DomainError> {
+        sqlx::query(
+            r#"
+            INSERT INTO sessions (
+                id, user_id, organization_id, title, description, status, created_at, updated_at,
+                deleted_at
+            ) VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)
+            "#,
+        )
+        .bind(session.id().to_string())
+        .bind(session.user_id().as_str())
+        .bind(session.organization_id().map(|id| id.to_string()))
+        .bind(session.title())
+        .bind(session.description())
+        .bind(stored_session_status(session))
+        .bind(session.created_at().as_datetime())
+        .bind(session.updated_at().as_datetime())
+        .bind(session.deleted_at().map(|at| *at.as_datetime()))
+        .execute(&self.pool)
+        .await
+        .map_err(|e| db_error("insert session", e))?;
+
+        Ok(())
+    }
+
+    #[tracing::instrument(name = "SqliteSessionRepository::update", skip_all, fields(db.system = "sqlite"), err)]
+    async fn update(&self, session: &Session) -> Result<(), DomainError> {
+        let result = sqlx::query(
+            r#"
+            UPDATE sessions SET
+                title = $2,
+                description = $3,
+                status = $4,
+                updated_at = $5,
+                organization_id = $6,
+                deleted_at = $7
+            WHERE id = $1
+            "#,
+        )
+        .bind(session.id().to_string())
+        .bind(session.title())
+        .bind(session.description())
+        .bind(stored_session_status(session))
+        .bind(session.updated_at().as_datetime())
+        .bind(session.organization_id().map(|id| id.to_string()))
+        .bind(session.deleted_at().map(|at| *at.as_datetime()))
+        .execute(&self.pool)
+        .await
+        .map_err(|e| db_error("update session", e))?;
+
+        if result.rows_affected() == 0 {
+            return Err(DomainError::new(
+                ErrorCode::SessionNotFound,
+                format!("Session not found: {}", session.id()),
+            ));
+        }
+
+        Ok(())
+    }
+
+    #[tracing::instrument(name = "SqliteSessionRepository::find_by_id", skip_all, fields(db.system = "sqlite"), err)]
+    async fn find_by_id(&self, id: &SessionId) -> Result<Option<Session>, DomainError> {
+        let row: Option<SessionRow> =
+            sqlx::query_as(&format!("{} WHERE s.id = $1", SELECT_SESSION))
+                .bind(id.to_string())
+                .fetch_optional(&self.pool)
+                .await
+                .map_err(|e| db_error("fetch session", e))?;
+
+        row.map(Session::try_from).transpose()
+    }
+
+    #[tracing::instrument(name = "SqliteSessionRepository::exists", skip_all, fields(db.system = "sqlite"), err)]
+    async fn exists(&self, id: &SessionId) -> Result<bool, DomainError> {
+        let (count,): (i64,) = sqlx::query_as("SELECT COUNT(*) FROM sessions WHERE id = $1")
+            .bind(id.to_string())
+            .fetch_one(&self.pool)
+            .await
+            .map_err(|e| db_error("check session existence", e))?;
+
+        Ok(count > 0)
+    }
+
+    #[tracing::instrument(name = "SqliteSessionRepository::find_by_user_id", skip_all, fields(db.system = "sqlite"), err)]
+    async fn find_by_user_id(&self, user_id: &UserId) -> Result<Vec<Session>, DomainError> {
+        let rows: Vec<SessionRow> = sqlx::query_as(&format!(
+            "{} WHERE s.user_id = $1 ORDER BY s.updated_at DESC",
+            SELECT_SESSION
+        ))
+        .bind(user_id.as_str())
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| db_error("fetch sessions by user", e))?;
+
+        rows.into_iter().map(Session::try_from).collect()
+    }
+
+    #[tracing::instrument(name = "SqliteSessionRepository::count_active_by_user", skip_all, fields(db.system = "sqlite"), err)]
+    async fn count_active_by_user(&self, user_id: &UserId) -> Result<u32, DomainError> {
+        let (count,): (i64,) = sqlx::query_as(
+            "SELECT COUNT(*) FROM sessions \
+             WHERE user_id = $1 AND status = 'active' AND deleted_at IS NULL",
+        )
+        .bind(user_id.as_str())
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|e| db_error("count active sessions", e))?;
+
+        Ok(count as u32)
+    }
+
+    #[tracing::instrument(name = "SqliteSessionRepository::delete", skip_all, fields(db.system = "sqlite"), err)]
+    async fn delete(&self, id: &SessionId) -> Result<(), DomainError> {
+        let result = sqlx::query("DELETE FROM sessions WHERE id = $1")
+            .bind(id.to_string())
+            .execute(&self.pool)
+            .await
+            .map_err(|e| db_error("delete session", e))?;
+
+        if result.rows_affected() == 0 {
+            return Err(DomainError::new(
+                ErrorCode::SessionNotFound,
+                format!("Session not found: {}", id),
+            ));
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::adapters::sqlite::test_pool;
+    use crate::domain::foundation::{OrganizationId, SessionStatus};
+
+    fn user() -> UserId {
+        UserId::new("user-1").unwrap()
+    }
+
+    #[tokio::test]
+    async fn saves_and_finds_session() {
+        let repo = SqliteSessionRepository::new(test_pool().await);
+        let session = Session::new(SessionId::new(), user(), "Which job?".to_string())
+            .unwrap()
+            .with_organization(Some(OrganizationId::new()));
+
+        repo.save(&session).await.unwrap();
+        let found = repo.find_by_id(session.id()).await.unwrap().unwrap();
+
+        assert_eq!(found.title(), "Which job?");
+        assert_eq!(found.organization_id(), session.organization_id());
+        assert!(found.cycle_ids().is_empty());
+        assert!(repo.exists(session.id()).await.unwrap());
+    }
+
+    #[tokio::test]
+    async fn counts_only_active_sessions() {
+        let repo = SqliteSessionRepository::new(test_pool().await);
+        let active = Session::new(SessionId::new(), user(), "Active".to_string()).unwrap();
+        let mut archived = Session::new(SessionId::new(), user(), "Archived".to_string()).unwrap();
+        repo.save(&active).await.unwrap();
+        repo.save(&archived).await.unwrap();
+        archived.archive().unwrap();
+        repo.update(&archived).await.unwrap();
+
+        assert_eq!(repo.count_active_by_user(&user()).await.unwrap(), 1);
+        assert_eq!(repo.find_by_user_id(&user()).await.unwrap().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn deleted_session_round_trips_with_status_to_restore() {
+        let repo = SqliteSessionRepository::new(test_pool().await);
+        let mut session = Session::new(SessionId::new(), user(), "Trashed".to_string()).unwrap();
+        repo.save(&session).await.unwrap();
+        session.archive().unwrap();
+        session.delete().unwrap();
+        repo.update(&session).await.unwrap();
+
+        let mut found = repo.find_by_id(session.id()).await.unwrap().unwrap();
+
+        assert_eq!(found.status(), SessionStatus::Deleted);
+        assert_eq!(found.deleted_at(), session.deleted_at());
+        assert_eq!(found.restore().unwrap(), SessionStatus::Archived);
+        assert_eq!(repo.count_active_by_user(&user()).await.unwrap(), 0);
+    }
+
+    #[tokio::test]
+    async fn update_and_delete_missing_session_fail() {
+        let repo = SqliteSessionRepository::new(test_pool().await);
+        let session = Session::new(SessionId::new(), user(), "Missing".to_string()).unwrap();
+
+        let update = repo.update(&session).await.unwrap_err();
+        let delete = repo.delete(session.id()).await.unwrap_err();
+
+        assert_eq!(update.code, ErrorCode::SessionNotFound);
+        assert_eq!(delete.code, ErrorCode::SessionNotFound);
+    }
+}
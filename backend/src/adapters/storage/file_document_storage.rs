@@ -0,0 +1,224 @@
+//! File-based Document Storage Adapter
+//!
+//! Stores exported documents on disk under a base directory, with the content
+//! type kept in a `.content-type` sidecar file. Signed URLs are issued by an
+//! [`HmacUrlSigner`] and served by the API's document download route.
+
+use async_trait::async_trait;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use tokio::fs;
+
+use super::HmacUrlSigner;
+use crate::ports::{
+    validate_document_key, DocumentStorage, DocumentStorageError, SignedUrlIssuer, StoredDocument,
+};
+
+const DEFAULT_CONTENT_TYPE: &str = "application/octet-stream";
+
+/// File-based storage for exported documents
+#[derive(Debug, Clone)]
+pub struct FileDocumentStorage {
+    base_path: PathBuf,
+    signer: Option<Arc<HmacUrlSigner>>,
+}
+
+impl FileDocumentStorage {
+    /// Create a new file storage with a base directory
+    ///
+    /// # Example
+    /// ```ignore
+    /// let storage = FileDocumentStorage::new("./data/documents")
+    ///     .with_signer(Arc::new(HmacUrlSigner::new(secret, "https://api.example.com")));
+    /// ```
+    pub fn new<P: AsRef<Path>>(base_path: P) -> Self {
+        Self {
+            base_path: base_path.as_ref().to_path_buf(),
+            signer: None,
+        }
+    }
+
+    /// Enable signed URLs using the given signer
+    pub fn with_signer(mut self, signer: Arc<HmacUrlSigner>) -> Self {
+        self.signer = Some(signer);
+        self
+    }
+
+    /// Resolve a validated key to a file path
+    fn document_path(&self, key: &str) -> Result<PathBuf, DocumentStorageError> {
+        validate_document_key(key)?;
+        Ok(self.base_path.join(key))
+    }
+
+    fn content_type_path(path: &Path) -> PathBuf {
+        let mut sidecar = path.as_os_str().to_owned();
+        sidecar.push(".content-type");
+        PathBuf::from(sidecar)
+    }
+}
+
+fn io_error(e: std::io::Error) -> DocumentStorageError {
+    DocumentStorageError::Storage(e.to_string())
+}
+
+#[async_trait]
+impl DocumentStorage for FileDocumentStorage {
+    async fn put(
+        &self,
+        key: &str,
+        content_type: &str,
+        bytes: Vec<u8>,
+    ) -> Result<(), DocumentStorageError> {
+        let path = self.document_path(key)?;
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent).await.map_err(io_error)?;
+        }
+
+        fs::write(&path, bytes).await.map_err(io_error)?;
+        fs::write(Self::content_type_path(&path), content_type)
+            .await
+            .map_err(io_error)?;
+        Ok(())
+    }
+
+    async fn get(&self, key: &str) -> Result<StoredDocument, DocumentStorageError> {
+        let path = self.document_path(key)?;
+        let bytes = match fs::read(&path).await {
+            Ok(bytes) => bytes,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => {
+                return Err(DocumentStorageError::NotFound(key.to_string()))
+            }
+            Err(e) => return Err(io_error(e)),
+        };
+
+        let content_type = fs::read_to_string(Self::content_type_path(&path))
+            .await
+            .unwrap_or_else(|_| DEFAULT_CONTENT_TYPE.to_string());
+
+        Ok(StoredDocument {
+            key: key.to_string(),
+            content_type,
+            bytes,
+        })
+    }
+
+    async fn delete(&self, key: &str) -> Result<(), DocumentStorageError> {
+        let path = self.document_path(key)?;
+        for target in [Self::content_type_path(&path), path] {
+            match fs::remove_file(&target).await {
+                Ok(()) => {}
+                Err(e) if e.kind() == std::io::ErrorKind::NotFound => {}
+                Err(e) => return Err(io_error(e)),
+            }
+        }
+        Ok(())
+    }
+
+    async fn exists(&self, key: &str) -> Result<bool, DocumentStorageError> {
+        let path = self.document_path(key)?;
+        Ok(fs::metadata(&path).await.is_ok())
+    }
+
+    async fn list(&self, prefix: &str) -> Result<Vec<String>, DocumentStorageError> {
+        let mut keys = Vec::new();
+        let mut pending = vec![self.base_path.clone()];
+        while let Some(dir) = pending.pop() {
+            let mut entries = match fs::read_dir(&dir).await {
+                Ok(entries) => entries,
+                Err(e) if e.kind() == std::io::ErrorKind::NotFound => continue,
+                Err(e) => return Err(io_error(e)),
+            };
+            while let Some(entry) = entries.next_entry().await.map_err(io_error)? {
+                let path = entry.path();
+                if entry.file_type().await.map_err(io_error)?.is_dir() {
+                    pending.push(path);
+                    continue;
+                }
+                if path.extension().is_some_and(|ext| ext == "content-type") {
+                    continue;
+                }
+                let Ok(relative) = path.strip_prefix(&self.base_path) else {
+                    continue;
+                };
+                let key = relative
+                    .components()
+                    .map(|part| part.as_os_str().to_string_lossy())
+                    .collect::<Vec<_>>()
+                    .join("/");
+                if key.starts_with(prefix) {
+                    keys.push(key);
+                }
+            }
+        }
+        keys.sort();
+        Ok(keys)
+    }
+
+    fn signed_url_issuer(&self) -> Option<&dyn SignedUrlIssuer> {
+        self.signer
+            .as_deref()
+            .map(|signer| signer as &dyn SignedUrlIssuer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[tokio::test]
+    async fn put_then_get_roundtrips_with_content_type() {
+        let dir = TempDir::new().unwrap();
+        let storage = FileDocumentStorage::new(dir.path());
+
+        storage
+            .put("exports/c1/decision.pdf", "application/pdf", b"%PDF".to_vec())
+            .await
+            .unwrap();
+
+        let doc = storage.get("exports/c1/decision.pdf").await.unwrap();
+        assert_eq!(doc.content_type, "application/pdf");
+        assert_eq!(doc.bytes, b"%PDF");
+    }
+
+    #[tokio::test]
+    async fn get_missing_returns_not_found() {
+        let dir = TempDir::new().unwrap();
+        let storage = FileDocumentStorage::new(dir.path());
+        let result = storage.get("missing.pdf").await;
+        assert!(matches!(result, Err(DocumentStorageError::NotFound(_))));
+    }
+
+    #[tokio::test]
+    async fn delete_is_idempotent() {
+        let dir = TempDir::new().unwrap();
+        let storage = FileDocumentStorage::new(dir.path());
+        storage.put("a.md", "text/markdown", vec![1]).await.unwrap();
+
+        storage.delete("a.md").await.unwrap();
+        storage.delete("a.md").await.unwrap();
+        assert!(!storage.exists("a.md").await.unwrap());
+    }
+
+    #[tokio::test]
+    async fn list_returns_keys_under_prefix_without_sidecars() {
+        let dir = TempDir::new().unwrap();
+        let storage = FileDocumentStorage::new(dir.path());
+        storage.put("exports/c1/a.pdf", "application/pdf", vec![1]).await.unwrap();
+        storage.put("exports/c2/b.md", "text/markdown", vec![2]).await.unwrap();
+        storage.put("backups/x.json", "application/json", vec![3]).await.unwrap();
+
+        let keys = storage.list("exports/").await.unwrap();
+
+        assert_eq!(keys, vec!["exports/c1/a.pdf", "exports/c2/b.md"]);
+        assert_eq!(storage.list("").await.unwrap().len(), 3);
+    }
+
+    #[tokio::test]
+    async fn traversal_keys_are_rejected() {
+        let dir = TempDir::new().unwrap();
+        let storage = FileDocumentStorage::new(dir.path());
+        let result = storage.get("../outside").await;
+        assert!(matches!(result, Err(DocumentStorageError::InvalidKey(_))));
+    }
+}
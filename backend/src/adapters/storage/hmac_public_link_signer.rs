@@ -0,0 +1,149 @@
+//! HMAC Public Link Signer
+//!
+//! Signs links to published decision documents. Links have the form:
+//!
+//! ```text
+//! {base_url}/public/documents/{publication_id}.{expires}.{signature}
+//! ```
+//!
+//! where `signature = HMAC-SHA256(secret, "publication\n{publication_id}\n{expires}")`,
+//! made and checked by [`HmacSigning`]. The `publication` prefix keeps these
+//! signatures from being valid as document download signatures made with the
+//! same secret.
+
+use crate::domain::foundation::{PublicationId, Timestamp};
+use crate::ports::{PublicLinkError, PublicLinkSigner};
+
+use super::hmac_signing::{is_expired, HmacSigning};
+
+/// Path prefix under which published documents are served.
+pub const PUBLIC_DOCUMENT_PATH: &str = "/public/documents";
+
+/// First part of every signed message.
+const PURPOSE: &str = "publication";
+
+/// Issues HMAC-signed links to published documents.
+pub struct HmacPublicLinkSigner {
+    signing: HmacSigning,
+    base_url: String,
+}
+
+impl HmacPublicLinkSigner {
+    /// Create a signer.
+    ///
+    /// # Arguments
+    /// * `secret` - Signing key (at least 32 random bytes recommended)
+    /// * `base_url` - Public origin serving the links, e.g. "https://api.choicesherpa.com"
+    pub fn new(secret: impl Into<String>, base_url: impl Into<String>) -> Self {
+        Self {
+            signing: HmacSigning::new(secret),
+            base_url: base_url.into().trim_end_matches('/').to_string(),
+        }
+    }
+
+    fn signature(&self, id: &PublicationId, expires: u64) -> String {
+        self.signing
+            .sign(&[PURPOSE, &id.to_string(), &expires.to_string()])
+    }
+}
+
+impl PublicLinkSigner for HmacPublicLinkSigner {
+    fn issue(&self, id: &PublicationId, expires_at: Timestamp) -> String {
+        let expires = expires_at.as_unix_secs();
+        format!(
+            "{}{}/{}.{}.{}",
+            self.base_url,
+            PUBLIC_DOCUMENT_PATH,
+            id,
+            expires,
+            self.signature(id, expires)
+        )
+    }
+
+    fn verify(&self, token: &str) -> Result<PublicationId, PublicLinkError> {
+        let mut parts = token.split('.');
+        let (Some(id), Some(expires), Some(signature), None) =
+            (parts.next(), parts.next(), parts.next(), parts.next())
+        else {
+            return Err(PublicLinkError::Malformed);
+        };
+        let id: PublicationId = id.parse().map_err(|_| PublicLinkError::Malformed)?;
+        let expires: u64 = expires.parse().map_err(|_| PublicLinkError::Malformed)?;
+
+        let message = [PURPOSE, &id.to_string(), &expires.to_string()];
+        if !self.signing.verify(&message, signature) {
+            tracing::warn!(publication_id = %id, "Rejected public link with invalid signature");
+            return Err(PublicLinkError::InvalidSignature);
+        }
+        if is_expired(expires) {
+            return Err(PublicLinkError::Expired);
+        }
+
+        Ok(id)
+    }
+}
+
+impl std::fmt::Debug for HmacPublicLinkSigner {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("HmacPublicLinkSigner")
+            .field("base_url", &self.base_url)
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn signer() -> HmacPublicLinkSigner {
+        HmacPublicLinkSigner::new(
+            "test-secret-that-is-long-enough",
+            "https://app.example.com/",
+        )
+    }
+
+    fn token(url: &str) -> &str {
+        url.rsplit('/').next().unwrap()
+    }
+
+    #[test]
+    fn issued_links_verify() {
+        let signer = signer();
+        let id = PublicationId::new();
+        let url = signer.issue(&id, Timestamp::now().plus_days(1));
+
+        assert!(url.starts_with("https://app.example.com/public/documents/"));
+        assert_eq!(signer.verify(token(&url)), Ok(id));
+    }
+
+    #[test]
+    fn tampered_links_are_rejected() {
+        let signer = signer();
+        let id = PublicationId::new();
+        let expires_at = Timestamp::now().plus_days(1);
+        let url = signer.issue(&id, expires_at);
+        let later = token(&url).replacen(
+            &expires_at.as_unix_secs().to_string(),
+            &(expires_at.as_unix_secs() + 3600).to_string(),
+            1,
+        );
+        let other_id = token(&url).replacen(&id.to_string(), &PublicationId::new().to_string(), 1);
+
+        assert_eq!(
+            signer.verify(&later),
+            Err(PublicLinkError::InvalidSignature)
+        );
+        assert_eq!(
+            signer.verify(&other_id),
+            Err(PublicLinkError::InvalidSignature)
+        );
+        assert_eq!(signer.verify("nope"), Err(PublicLinkError::Malformed));
+    }
+
+    #[test]
+    fn expired_links_are_rejected() {
+        let signer = signer();
+        let url = signer.issue(&PublicationId::new(), Timestamp::now().minus_days(1));
+        assert_eq!(signer.verify(token(&url)), Err(PublicLinkError::Expired));
+    }
+}
@@ -0,0 +1,119 @@
+//! HMAC Signing
+//!
+//! The HMAC-SHA256 signing shared by the download URL, public link, and
+//! calendar feed signers, so they agree on how messages are built, how
+//! signatures are compared, and when a signed expiry has passed.
+//!
+//! A message is its parts joined by `\n`, and signatures travel as lowercase
+//! hex. Each signer starts its messages with its own purpose prefix, so a
+//! signature made for one kind of link is never valid for another made with
+//! the same secret. Parts after the prefix must not contain `\n` themselves,
+//! or one message could be read as another.
+
+use hmac::{Hmac, Mac};
+use secrecy::{ExposeSecret, SecretString};
+use sha2::Sha256;
+use subtle::ConstantTimeEq;
+
+use crate::domain::foundation::Timestamp;
+
+type HmacSha256 = Hmac<Sha256>;
+
+/// Signs and verifies messages with one secret.
+pub(crate) struct HmacSigning {
+    secret: SecretString,
+}
+
+impl HmacSigning {
+    /// `secret` should be at least 32 random bytes.
+    pub(crate) fn new(secret: impl Into<String>) -> Self {
+        Self {
+            secret: SecretString::new(secret.into()),
+        }
+    }
+
+    /// Hex signature of `parts` joined by newlines.
+    pub(crate) fn sign(&self, parts: &[&str]) -> String {
+        hex_encode(&self.mac(parts))
+    }
+
+    /// Whether `signature_hex` is the signature of `parts`, compared in
+    /// constant time.
+    pub(crate) fn verify(&self, parts: &[&str], signature_hex: &str) -> bool {
+        let Some(provided) = hex_decode(signature_hex) else {
+            return false;
+        };
+        self.mac(parts).ct_eq(provided.as_slice()).into()
+    }
+
+    fn mac(&self, parts: &[&str]) -> Vec<u8> {
+        let mut mac = HmacSha256::new_from_slice(self.secret.expose_secret().as_bytes())
+            .expect("HMAC can take key of any size");
+        for (i, part) in parts.iter().enumerate() {
+            if i > 0 {
+                mac.update(b"\n");
+            }
+            mac.update(part.as_bytes());
+        }
+        mac.finalize().into_bytes().to_vec()
+    }
+}
+
+/// Whether a signature valid until `expires` (unix seconds) has expired.
+/// Signatures are good up to, but not including, their expiry second.
+pub(crate) fn is_expired(expires: u64) -> bool {
+    Timestamp::now().as_unix_secs() >= expires
+}
+
+pub(crate) fn hex_encode(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+pub(crate) fn hex_decode(s: &str) -> Option<Vec<u8>> {
+    if !s.len().is_multiple_of(2) {
+        return None;
+    }
+    (0..s.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(s.get(i..i + 2)?, 16).ok())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn signing() -> HmacSigning {
+        HmacSigning::new("test-secret-that-is-long-enough")
+    }
+
+    #[test]
+    fn signatures_verify_for_the_same_parts_only() {
+        let signature = signing().sign(&["exports/a.pdf", "1700000000"]);
+
+        assert!(signing().verify(&["exports/a.pdf", "1700000000"], &signature));
+        assert!(!signing().verify(&["exports/a.pdf", "1700003600"], &signature));
+        assert!(!signing().verify(&["exports/a.pdf\n1700000000"], "not-hex"));
+        assert!(!HmacSigning::new("another-secret-that-is-long-enough")
+            .verify(&["exports/a.pdf", "1700000000"], &signature));
+    }
+
+    #[test]
+    fn parts_are_joined_by_newlines() {
+        assert_eq!(signing().sign(&["a", "b"]), signing().sign(&["a\nb"]));
+    }
+
+    #[test]
+    fn expiry_second_is_already_expired() {
+        let now = Timestamp::now().as_unix_secs();
+        assert!(is_expired(now - 1));
+        assert!(is_expired(now));
+        assert!(!is_expired(now + 60));
+    }
+
+    #[test]
+    fn hex_roundtrip() {
+        let bytes = vec![0xde, 0xad, 0xbe, 0xef];
+        assert_eq!(hex_decode(&hex_encode(&bytes)), Some(bytes));
+    }
+}
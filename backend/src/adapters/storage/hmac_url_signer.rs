@@ -0,0 +1,235 @@
+//! HMAC URL Signer
+//!
+//! Issues and verifies signed, expiring download URLs for documents held in
+//! storage backends that cannot presign URLs themselves (local files, memory).
+//!
+//! URLs have the form:
+//!
+//! ```text
+//! {base_url}/api/documents/{key}?expires={unix_secs}&signature={hex}
+//! ```
+//!
+//! where `signature = HMAC-SHA256(secret, "document\n{key}\n{expires}")`,
+//! made and checked by [`HmacSigning`].
+
+use std::time::Duration;
+
+use crate::domain::foundation::Timestamp;
+use crate::ports::{
+    validate_document_key, DocumentStorageError, SignedUrl, SignedUrlIssuer, MAX_SIGNED_URL_TTL,
+};
+
+use super::hmac_signing::{is_expired, HmacSigning};
+
+/// Path prefix under which signed document downloads are served.
+pub const SIGNED_DOCUMENT_PATH: &str = "/api/documents";
+
+/// First part of every signed message.
+const PURPOSE: &str = "document";
+
+/// Issues HMAC-signed document URLs served by the API's download route.
+pub struct HmacUrlSigner {
+    signing: HmacSigning,
+    base_url: String,
+}
+
+impl HmacUrlSigner {
+    /// Create a signer.
+    ///
+    /// # Arguments
+    /// * `secret` - Signing key (at least 32 random bytes recommended)
+    /// * `base_url` - Public origin of the API, e.g. "https://api.choicesherpa.com"
+    pub fn new(secret: impl Into<String>, base_url: impl Into<String>) -> Self {
+        Self {
+            signing: HmacSigning::new(secret),
+            base_url: base_url.into().trim_end_matches('/').to_string(),
+        }
+    }
+
+    /// Verify a signature presented with a download request.
+    ///
+    /// # Errors
+    /// - `UrlExpired` if `expires` has been reached
+    /// - `InvalidSignature` if the signature does not match
+    pub fn verify(
+        &self,
+        key: &str,
+        expires: u64,
+        signature_hex: &str,
+    ) -> Result<(), DocumentStorageError> {
+        let message = [PURPOSE, key, &expires.to_string()];
+        if !self.signing.verify(&message, signature_hex) {
+            tracing::warn!(key = %key, "Rejected document URL with invalid signature");
+            return Err(DocumentStorageError::InvalidSignature);
+        }
+
+        if is_expired(expires) {
+            return Err(DocumentStorageError::UrlExpired);
+        }
+
+        Ok(())
+    }
+
+    fn signature(&self, key: &str, expires: u64) -> String {
+        self.signing.sign(&[PURPOSE, key, &expires.to_string()])
+    }
+}
+
+impl SignedUrlIssuer for HmacUrlSigner {
+    fn issue(&self, key: &str, ttl: Duration) -> Result<SignedUrl, DocumentStorageError> {
+        validate_document_key(key)?;
+        if ttl > MAX_SIGNED_URL_TTL {
+            return Err(DocumentStorageError::TtlTooLong {
+                max_secs: MAX_SIGNED_URL_TTL.as_secs(),
+            });
+        }
+
+        let expires_at = Timestamp::now().plus_secs(ttl.as_secs());
+        let expires = expires_at.as_unix_secs();
+        let signature = self.signature(key, expires);
+
+        Ok(SignedUrl {
+            url: format!(
+                "{}{}/{}?expires={}&signature={}",
+                self.base_url,
+                SIGNED_DOCUMENT_PATH,
+                encode_key(key),
+                expires,
+                signature
+            ),
+            expires_at,
+        })
+    }
+}
+
+impl std::fmt::Debug for HmacUrlSigner {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("HmacUrlSigner")
+            .field("base_url", &self.base_url)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Percent-encode a key for use in a URL path, preserving `/` separators.
+fn encode_key(key: &str) -> String {
+    key.bytes()
+        .map(|b| match b {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' | b'/' => {
+                (b as char).to_string()
+            }
+            _ => format!("%{:02X}", b),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn signer() -> HmacUrlSigner {
+        HmacUrlSigner::new("test-secret-that-is-long-enough", "https://api.example.com/")
+    }
+
+    fn parse_query(url: &str) -> (u64, String) {
+        let query = url.split('?').nth(1).unwrap();
+        let mut expires = 0;
+        let mut signature = String::new();
+        for pair in query.split('&') {
+            let (k, v) = pair.split_once('=').unwrap();
+            match k {
+                "expires" => expires = v.parse().unwrap(),
+                "signature" => signature = v.to_string(),
+                _ => {}
+            }
+        }
+        (expires, signature)
+    }
+
+    #[test]
+    fn issued_url_has_expected_shape() {
+        let url = signer()
+            .issue("exports/c1/decision.pdf", Duration::from_secs(60))
+            .unwrap();
+        assert!(url
+            .url
+            .starts_with("https://api.example.com/api/documents/exports/c1/decision.pdf?expires="));
+        assert!(url.url.contains("&signature="));
+    }
+
+    #[test]
+    fn issued_url_verifies() {
+        let signer = signer();
+        let url = signer.issue("exports/a.pdf", Duration::from_secs(60)).unwrap();
+        let (expires, signature) = parse_query(&url.url);
+
+        assert_eq!(expires, url.expires_at.as_unix_secs());
+        assert!(signer.verify("exports/a.pdf", expires, &signature).is_ok());
+    }
+
+    #[test]
+    fn signature_for_other_key_is_rejected() {
+        let signer = signer();
+        let url = signer.issue("exports/a.pdf", Duration::from_secs(60)).unwrap();
+        let (expires, signature) = parse_query(&url.url);
+
+        assert_eq!(
+            signer.verify("exports/b.pdf", expires, &signature),
+            Err(DocumentStorageError::InvalidSignature)
+        );
+    }
+
+    #[test]
+    fn tampered_expiry_is_rejected() {
+        let signer = signer();
+        let url = signer.issue("exports/a.pdf", Duration::from_secs(60)).unwrap();
+        let (expires, signature) = parse_query(&url.url);
+
+        assert_eq!(
+            signer.verify("exports/a.pdf", expires + 3600, &signature),
+            Err(DocumentStorageError::InvalidSignature)
+        );
+    }
+
+    #[test]
+    fn expired_url_is_rejected() {
+        let signer = signer();
+        let expires = Timestamp::now().as_unix_secs() - 10;
+        let signature = signer.signature("exports/a.pdf", expires);
+
+        assert_eq!(
+            signer.verify("exports/a.pdf", expires, &signature),
+            Err(DocumentStorageError::UrlExpired)
+        );
+    }
+
+    #[test]
+    fn malformed_signature_is_rejected() {
+        assert_eq!(
+            signer().verify("exports/a.pdf", u64::MAX, "not-hex"),
+            Err(DocumentStorageError::InvalidSignature)
+        );
+    }
+
+    #[test]
+    fn signatures_made_for_other_purposes_are_rejected() {
+        let signing = HmacSigning::new("test-secret-that-is-long-enough");
+        let expires = Timestamp::now().as_unix_secs() + 60;
+        let signature = signing.sign(&["publication", "p-1", &expires.to_string()]);
+
+        assert_eq!(
+            signer().verify("publication\np-1", expires, &signature),
+            Err(DocumentStorageError::InvalidSignature)
+        );
+    }
+
+    #[test]
+    fn ttl_above_maximum_is_rejected() {
+        let result = signer().issue("a.pdf", MAX_SIGNED_URL_TTL + Duration::from_secs(1));
+        assert!(matches!(result, Err(DocumentStorageError::TtlTooLong { .. })));
+    }
+
+    #[test]
+    fn keys_are_percent_encoded() {
+        assert_eq!(encode_key("exports/My Decision.pdf"), "exports/My%20Decision.pdf");
+    }
+}
@@ -0,0 +1,147 @@
+//! In-Memory Document Storage Adapter
+//!
+//! Stores exported documents in memory. Useful for testing and development.
+//! Optionally issues signed URLs through an [`HmacUrlSigner`].
+
+use async_trait::async_trait;
+use std::collections::HashMap;
+use std::sync::Arc;
+use tokio::sync::RwLock;
+
+use super::HmacUrlSigner;
+use crate::ports::{
+    validate_document_key, DocumentStorage, DocumentStorageError, SignedUrlIssuer, StoredDocument,
+};
+
+/// In-memory storage for exported documents
+#[derive(Debug, Clone, Default)]
+pub struct InMemoryDocumentStorage {
+    documents: Arc<RwLock<HashMap<String, StoredDocument>>>,
+    signer: Option<Arc<HmacUrlSigner>>,
+}
+
+impl InMemoryDocumentStorage {
+    /// Create a new in-memory storage without signed URL support
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Enable signed URLs using the given signer
+    pub fn with_signer(mut self, signer: Arc<HmacUrlSigner>) -> Self {
+        self.signer = Some(signer);
+        self
+    }
+
+    /// Get the number of stored documents
+    pub async fn document_count(&self) -> usize {
+        self.documents.read().await.len()
+    }
+}
+
+#[async_trait]
+impl DocumentStorage for InMemoryDocumentStorage {
+    async fn put(
+        &self,
+        key: &str,
+        content_type: &str,
+        bytes: Vec<u8>,
+    ) -> Result<(), DocumentStorageError> {
+        validate_document_key(key)?;
+        let document = StoredDocument {
+            key: key.to_string(),
+            content_type: content_type.to_string(),
+            bytes,
+        };
+        self.documents.write().await.insert(key.to_string(), document);
+        Ok(())
+    }
+
+    async fn get(&self, key: &str) -> Result<StoredDocument, DocumentStorageError> {
+        self.documents
+            .read()
+            .await
+            .get(key)
+            .cloned()
+            .ok_or_else(|| DocumentStorageError::NotFound(key.to_string()))
+    }
+
+    async fn delete(&self, key: &str) -> Result<(), DocumentStorageError> {
+        self.documents.write().await.remove(key);
+        Ok(())
+    }
+
+    async fn exists(&self, key: &str) -> Result<bool, DocumentStorageError> {
+        Ok(self.documents.read().await.contains_key(key))
+    }
+
+    async fn list(&self, prefix: &str) -> Result<Vec<String>, DocumentStorageError> {
+        let mut keys: Vec<String> = self
+            .documents
+            .read()
+            .await
+            .keys()
+            .filter(|key| key.starts_with(prefix))
+            .cloned()
+            .collect();
+        keys.sort();
+        Ok(keys)
+    }
+
+    fn signed_url_issuer(&self) -> Option<&dyn SignedUrlIssuer> {
+        self.signer
+            .as_deref()
+            .map(|signer| signer as &dyn SignedUrlIssuer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    #[tokio::test]
+    async fn put_then_get_roundtrips() {
+        let storage = InMemoryDocumentStorage::new();
+        storage
+            .put("exports/a.md", "text/markdown", b"# Decision".to_vec())
+            .await
+            .unwrap();
+
+        let doc = storage.get("exports/a.md").await.unwrap();
+        assert_eq!(doc.content_type, "text/markdown");
+        assert_eq!(doc.bytes, b"# Decision");
+        assert_eq!(storage.document_count().await, 1);
+    }
+
+    #[tokio::test]
+    async fn get_missing_returns_not_found() {
+        let storage = InMemoryDocumentStorage::new();
+        let result = storage.get("missing.pdf").await;
+        assert!(matches!(result, Err(DocumentStorageError::NotFound(_))));
+    }
+
+    #[tokio::test]
+    async fn delete_removes_document() {
+        let storage = InMemoryDocumentStorage::new();
+        storage.put("a.md", "text/markdown", vec![1]).await.unwrap();
+        storage.delete("a.md").await.unwrap();
+        assert!(!storage.exists("a.md").await.unwrap());
+    }
+
+    #[tokio::test]
+    async fn put_rejects_invalid_key() {
+        let storage = InMemoryDocumentStorage::new();
+        let result = storage.put("../escape", "text/plain", vec![]).await;
+        assert!(matches!(result, Err(DocumentStorageError::InvalidKey(_))));
+    }
+
+    #[test]
+    fn signed_urls_require_signer() {
+        assert!(InMemoryDocumentStorage::new().signed_url_issuer().is_none());
+
+        let signer = Arc::new(HmacUrlSigner::new("secret", "https://api.example.com"));
+        let storage = InMemoryDocumentStorage::new().with_signer(signer);
+        let issuer = storage.signed_url_issuer().expect("signer configured");
+        assert!(issuer.issue("a.pdf", Duration::from_secs(60)).is_ok());
+    }
+}
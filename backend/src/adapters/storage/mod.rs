@@ -1,11 +1,18 @@
 //! Storage Adapters
 //!
-//! Implementations of the StateStorage port for persisting conversation state.
+//! Implementations of the StateStorage port for persisting conversation state,
+//! and the DocumentStorage port for exported documents.
 //!
 //! ## Available Adapters
 //!
 //! - **FileStateStorage** - Stores state as YAML files on disk
 //! - **InMemoryStateStorage** - Stores state in memory (testing/development)
+//! - **FileDocumentStorage** - Stores exported documents on disk
+//! - **InMemoryDocumentStorage** - Stores exported documents in memory
+//! - **HmacUrlSigner** - Signed, expiring download URLs for the above
+//! - **HmacPublicLinkSigner** - Signed, expiring links to published documents
+//!
+//! The signers share `HmacSigning` for their signatures and expiry checks.
 //!
 //! ## Usage
 //!
@@ -19,8 +26,18 @@
 //! let storage = InMemoryStateStorage::new();
 //! ```
 
+mod file_document_storage;
 mod file_state_storage;
+mod hmac_public_link_signer;
+mod hmac_signing;
+mod hmac_url_signer;
+mod in_memory_document_storage;
 mod in_memory_state_storage;
 
+pub use file_document_storage::FileDocumentStorage;
 pub use file_state_storage::FileStateStorage;
+pub use hmac_public_link_signer::{HmacPublicLinkSigner, PUBLIC_DOCUMENT_PATH};
+pub(crate) use hmac_signing::{hex_decode, hex_encode, HmacSigning};
+pub use hmac_url_signer::{HmacUrlSigner, SIGNED_DOCUMENT_PATH};
+pub use in_memory_document_storage::InMemoryDocumentStorage;
 pub use in_memory_state_storage::InMemoryStateStorage;
@@ -15,8 +15,8 @@ use async_trait::async_trait;
 use crate::domain::membership::MembershipTier;
 use crate::ports::{
     CheckoutSession, CreateCheckoutRequest, CreateCustomerRequest, CreateSubscriptionRequest,
-    Customer, PaymentError, PaymentProvider, PortalSession, Subscription, SubscriptionStatus,
-    WebhookEvent, WebhookEventData, WebhookEventType,
+    Customer, MeteredUsageReport, PaymentError, PaymentErrorCode, PaymentProvider, PortalSession,
+    Subscription, SubscriptionStatus, WebhookEvent, WebhookEventData, WebhookEventType,
 };
 
 /// Mock payment provider for testing.
@@ -50,6 +50,9 @@ struct MockState {
     /// Pre-configured subscriptions by ID.
     subscriptions: HashMap<String, Subscription>,
 
+    /// Metered usage reported so far.
+    usage_reports: Vec<MeteredUsageReport>,
+
     /// Next customer ID to return.
     next_customer: Option<Customer>,
 
@@ -214,6 +217,11 @@ impl MockPaymentProvider {
         self.inner.lock().unwrap().call_log.clear();
     }
 
+    /// Metered usage reported so far, one entry per subscription and timestamp.
+    pub fn usage_reports(&self) -> Vec<MeteredUsageReport> {
+        self.inner.lock().unwrap().usage_reports.clone()
+    }
+
     // ════════════════════════════════════════════════════════════════════════════
     // Internal Helpers
     // ════════════════════════════════════════════════════════════════════════════
@@ -285,6 +293,18 @@ impl PaymentProvider for MockPaymentProvider {
         Ok(state.customers.get(customer_id).cloned())
     }
 
+    async fn delete_customer(&self, customer_id: &str) -> Result<(), PaymentError> {
+        self.record_call("delete_customer", vec![customer_id.to_string()]);
+        self.check_error("delete_customer")?;
+
+        let mut state = self.inner.lock().unwrap();
+        state.customers.remove(customer_id);
+        state
+            .subscriptions
+            .retain(|_, subscription| subscription.customer_id != customer_id);
+        Ok(())
+    }
+
     async fn create_subscription(
         &self,
         request: CreateSubscriptionRequest,
@@ -312,6 +332,7 @@ impl PaymentProvider for MockPaymentProvider {
             current_period_end: period_end,
             cancel_at_period_end: false,
             canceled_at: None,
+            quantity: 1,
         });
 
         state
@@ -381,6 +402,81 @@ impl PaymentProvider for MockPaymentProvider {
         Ok(subscription.clone())
     }
 
+    async fn update_subscription_quantity(
+        &self,
+        subscription_id: &str,
+        quantity: u32,
+    ) -> Result<Subscription, PaymentError> {
+        self.record_call(
+            "update_subscription_quantity",
+            vec![subscription_id.to_string(), quantity.to_string()],
+        );
+        self.check_error("update_subscription_quantity")?;
+
+        let mut state = self.inner.lock().unwrap();
+
+        let subscription = state
+            .subscriptions
+            .get_mut(subscription_id)
+            .ok_or_else(|| PaymentError::not_found("Subscription"))?;
+
+        subscription.quantity = quantity;
+
+        Ok(subscription.clone())
+    }
+
+    async fn end_trial(&self, subscription_id: &str) -> Result<Subscription, PaymentError> {
+        self.record_call("end_trial", vec![subscription_id.to_string()]);
+        self.check_error("end_trial")?;
+
+        let mut state = self.inner.lock().unwrap();
+
+        let subscription = state
+            .subscriptions
+            .get_mut(subscription_id)
+            .ok_or_else(|| PaymentError::not_found("Subscription"))?;
+
+        if subscription.status != SubscriptionStatus::Trialing {
+            return Err(PaymentError::new(
+                PaymentErrorCode::ProviderError,
+                "Subscription is not trialing",
+            ));
+        }
+
+        let now = chrono::Utc::now().timestamp();
+        subscription.status = SubscriptionStatus::Active;
+        subscription.current_period_start = now;
+        subscription.current_period_end = now + 30 * 24 * 60 * 60;
+
+        Ok(subscription.clone())
+    }
+
+    async fn report_metered_usage(&self, report: MeteredUsageReport) -> Result<(), PaymentError> {
+        self.record_call(
+            "report_metered_usage",
+            vec![
+                report.subscription_id.clone(),
+                report.quantity.to_string(),
+                report.idempotency_key.clone(),
+            ],
+        );
+        self.check_error("report_metered_usage")?;
+
+        let mut state = self.inner.lock().unwrap();
+
+        if !state.subscriptions.contains_key(&report.subscription_id) {
+            return Err(PaymentError::not_found("Subscription"));
+        }
+
+        // Like Stripe's `set` action, a repeat report replaces the earlier one
+        state.usage_reports.retain(|r| {
+            r.subscription_id != report.subscription_id || r.timestamp != report.timestamp
+        });
+        state.usage_reports.push(report);
+
+        Ok(())
+    }
+
     async fn create_checkout_session(
         &self,
         request: CreateCheckoutRequest,
@@ -526,11 +622,31 @@ impl MockPaymentProvider {
             current_period_end: chrono::Utc::now().timestamp() + 30 * 24 * 60 * 60,
             cancel_at_period_end: false,
             canceled_at: None,
+            quantity: 1,
         });
 
         mock
     }
 
+    /// Create a mock with a pre-configured subscription in a 14-day trial.
+    pub fn with_trialing_subscription(customer_id: &str, subscription_id: &str) -> Self {
+        let mock = Self::with_active_subscription(customer_id, subscription_id);
+
+        if let Some(subscription) = mock
+            .inner
+            .lock()
+            .unwrap()
+            .subscriptions
+            .get_mut(subscription_id)
+        {
+            subscription.status = SubscriptionStatus::Trialing;
+            subscription.current_period_end =
+                subscription.current_period_start + 14 * 24 * 60 * 60;
+        }
+
+        mock
+    }
+
     /// Create a checkout completed webhook event.
     pub fn checkout_completed_event(
         customer_id: &str,
@@ -576,6 +692,7 @@ impl MockPaymentProvider {
                 customer_id: customer_id.to_string(),
                 status: SubscriptionStatus::Canceled,
                 current_period_end: chrono::Utc::now().timestamp(),
+                quantity: None,
             },
             created_at: chrono::Utc::now().timestamp(),
         }
@@ -729,6 +846,7 @@ mod tests {
                 success_url: "https://example.com/success".to_string(),
                 cancel_url: "https://example.com/cancel".to_string(),
                 promo_code: None,
+                trial_days: None,
             })
             .await
             .unwrap();
@@ -850,6 +968,16 @@ mod tests {
         assert_eq!(mock.call_count("create_customer"), 0);
     }
 
+    #[tokio::test]
+    async fn end_trial_activates_trialing_subscription() {
+        let mock = MockPaymentProvider::with_trialing_subscription("cus_123", "sub_123");
+
+        let subscription = mock.end_trial("sub_123").await.unwrap();
+
+        assert_eq!(subscription.status, SubscriptionStatus::Active);
+        assert!(mock.end_trial("sub_123").await.is_err());
+    }
+
     // ════════════════════════════════════════════════════════════════════════════
     // Webhook Tests
     // ════════════════════════════════════════════════════════════════════════════
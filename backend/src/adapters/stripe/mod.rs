@@ -18,10 +18,12 @@
 //! - `STRIPE_API_KEY`: Stripe secret API key
 //! - `STRIPE_WEBHOOK_SECRET`: Webhook signing secret (whsec_...)
 
+#[cfg(any(test, feature = "test-support"))]
 mod mock_payment_provider;
 mod stripe_adapter;
 mod webhook_types;
 
+#[cfg(any(test, feature = "test-support"))]
 pub use mock_payment_provider::MockPaymentProvider;
 pub use stripe_adapter::{StripeConfig, StripePaymentAdapter};
 pub use webhook_types::{
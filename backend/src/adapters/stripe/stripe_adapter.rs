@@ -26,8 +26,8 @@ use subtle::ConstantTimeEq;
 use crate::domain::membership::MembershipTier;
 use crate::ports::{
     CheckoutSession, CreateCheckoutRequest, CreateCustomerRequest, CreateSubscriptionRequest,
-    Customer, PaymentError, PaymentErrorCode, PaymentProvider, PortalSession, Subscription,
-    SubscriptionStatus, WebhookEvent, WebhookEventData, WebhookEventType,
+    Customer, MeteredUsageReport, PaymentError, PaymentErrorCode, PaymentProvider, PortalSession,
+    Subscription, SubscriptionStatus, WebhookEvent, WebhookEventData, WebhookEventType,
 };
 
 use super::webhook_types::{hex_encode, SignatureHeader, StripeCheckoutSession, StripeWebhookEvent};
@@ -258,11 +258,13 @@ impl StripePaymentAdapter {
                     _ => SubscriptionStatus::Unknown,
                 };
 
+                let quantity = sub.quantity();
                 Ok(WebhookEventData::Subscription {
                     subscription_id: sub.id,
                     customer_id: sub.customer,
                     status,
                     current_period_end: sub.current_period_end,
+                    quantity: Some(quantity),
                 })
             }
 
@@ -301,6 +303,45 @@ impl StripePaymentAdapter {
             )),
         }
     }
+
+    /// Fetch a subscription with its items, which the port's
+    /// `Subscription` does not carry.
+    async fn fetch_stripe_subscription(
+        &self,
+        subscription_id: &str,
+    ) -> Result<super::webhook_types::StripeSubscription, PaymentError> {
+        let url = format!(
+            "{}/v1/subscriptions/{}",
+            self.config.api_base_url, subscription_id
+        );
+
+        let response = self
+            .http_client
+            .get(&url)
+            .basic_auth(self.config.api_key.expose_secret(), Option::<&str>::None)
+            .send()
+            .await
+            .map_err(|e| PaymentError::network(e.to_string()))?;
+
+        if response.status() == reqwest::StatusCode::NOT_FOUND {
+            return Err(PaymentError::not_found("Subscription"));
+        }
+
+        if !response.status().is_success() {
+            let error_text = response.text().await.unwrap_or_default();
+            return Err(PaymentError::new(
+                PaymentErrorCode::ProviderError,
+                format!("Stripe API error: {}", error_text),
+            ));
+        }
+
+        response.json().await.map_err(|e| {
+            PaymentError::new(
+                PaymentErrorCode::ProviderError,
+                format!("Failed to parse Stripe response: {}", e),
+            )
+        })
+    }
 }
 
 #[async_trait]
@@ -397,6 +438,33 @@ impl PaymentProvider for StripePaymentAdapter {
         }))
     }
 
+    async fn delete_customer(&self, customer_id: &str) -> Result<(), PaymentError> {
+        let url = format!("{}/v1/customers/{}", self.config.api_base_url, customer_id);
+
+        let response = self
+            .http_client
+            .delete(&url)
+            .basic_auth(self.config.api_key.expose_secret(), Option::<&str>::None)
+            .send()
+            .await
+            .map_err(|e| PaymentError::network(e.to_string()))?;
+
+        // Already deleted, e.g. by an earlier attempt
+        if response.status() == reqwest::StatusCode::NOT_FOUND {
+            return Ok(());
+        }
+
+        if !response.status().is_success() {
+            let error_text = response.text().await.unwrap_or_default();
+            return Err(PaymentError::new(
+                PaymentErrorCode::ProviderError,
+                format!("Stripe API error: {}", error_text),
+            ));
+        }
+
+        Ok(())
+    }
+
     async fn create_subscription(
         &self,
         request: CreateSubscriptionRequest,
@@ -438,6 +506,7 @@ impl PaymentProvider for StripePaymentAdapter {
                 )
             })?;
 
+        let quantity = stripe_sub.quantity();
         Ok(Subscription {
             id: stripe_sub.id,
             customer_id: stripe_sub.customer,
@@ -452,6 +521,7 @@ impl PaymentProvider for StripePaymentAdapter {
             current_period_end: stripe_sub.current_period_end,
             cancel_at_period_end: stripe_sub.cancel_at_period_end,
             canceled_at: stripe_sub.canceled_at,
+            quantity,
         })
     }
 
@@ -492,6 +562,7 @@ impl PaymentProvider for StripePaymentAdapter {
                 )
             })?;
 
+        let quantity = stripe_sub.quantity();
         Ok(Some(Subscription {
             id: stripe_sub.id,
             customer_id: stripe_sub.customer,
@@ -506,6 +577,7 @@ impl PaymentProvider for StripePaymentAdapter {
             current_period_end: stripe_sub.current_period_end,
             cancel_at_period_end: stripe_sub.cancel_at_period_end,
             canceled_at: stripe_sub.canceled_at,
+            quantity,
         }))
     }
 
@@ -553,6 +625,7 @@ impl PaymentProvider for StripePaymentAdapter {
                 )
             })?;
 
+        let quantity = stripe_sub.quantity();
         Ok(Subscription {
             id: stripe_sub.id,
             customer_id: stripe_sub.customer,
@@ -565,6 +638,7 @@ impl PaymentProvider for StripePaymentAdapter {
             current_period_end: stripe_sub.current_period_end,
             cancel_at_period_end: stripe_sub.cancel_at_period_end,
             canceled_at: stripe_sub.canceled_at,
+            quantity,
         })
     }
 
@@ -612,6 +686,7 @@ impl PaymentProvider for StripePaymentAdapter {
                 )
             })?;
 
+        let quantity = stripe_sub.quantity();
         Ok(Subscription {
             id: stripe_sub.id,
             customer_id: stripe_sub.customer,
@@ -623,9 +698,176 @@ impl PaymentProvider for StripePaymentAdapter {
             current_period_end: stripe_sub.current_period_end,
             cancel_at_period_end: stripe_sub.cancel_at_period_end,
             canceled_at: stripe_sub.canceled_at,
+            quantity,
         })
     }
 
+    async fn update_subscription_quantity(
+        &self,
+        subscription_id: &str,
+        quantity: u32,
+    ) -> Result<Subscription, PaymentError> {
+        let url = format!(
+            "{}/v1/subscriptions/{}",
+            self.config.api_base_url, subscription_id
+        );
+
+        // The quantity lives on the subscription item, so look up its ID first
+        let current = self.fetch_stripe_subscription(subscription_id).await?;
+        let item_id = current.primary_item_id().ok_or_else(|| {
+            PaymentError::new(
+                PaymentErrorCode::ProviderError,
+                format!("Subscription {} has no items", subscription_id),
+            )
+        })?;
+
+        let response = self
+            .http_client
+            .post(&url)
+            .basic_auth(self.config.api_key.expose_secret(), Option::<&str>::None)
+            .form(&[
+                ("items[0][id]", item_id.to_string()),
+                ("items[0][quantity]", quantity.to_string()),
+                ("proration_behavior", "create_prorations".to_string()),
+            ])
+            .send()
+            .await
+            .map_err(|e| PaymentError::network(e.to_string()))?;
+
+        if !response.status().is_success() {
+            let error_text = response.text().await.unwrap_or_default();
+            tracing::error!(error = %error_text, "Stripe update_subscription_quantity failed");
+            return Err(PaymentError::new(
+                PaymentErrorCode::ProviderError,
+                format!("Stripe API error: {}", error_text),
+            ));
+        }
+
+        let stripe_sub: super::webhook_types::StripeSubscription =
+            response.json().await.map_err(|e| {
+                PaymentError::new(
+                    PaymentErrorCode::ProviderError,
+                    format!("Failed to parse Stripe response: {}", e),
+                )
+            })?;
+
+        let quantity = stripe_sub.quantity();
+        Ok(Subscription {
+            id: stripe_sub.id,
+            customer_id: stripe_sub.customer,
+            status: match stripe_sub.status.as_str() {
+                "active" => SubscriptionStatus::Active,
+                "past_due" => SubscriptionStatus::PastDue,
+                "canceled" => SubscriptionStatus::Canceled,
+                "trialing" => SubscriptionStatus::Trialing,
+                _ => SubscriptionStatus::Unknown,
+            },
+            current_period_start: stripe_sub.current_period_start,
+            current_period_end: stripe_sub.current_period_end,
+            cancel_at_period_end: stripe_sub.cancel_at_period_end,
+            canceled_at: stripe_sub.canceled_at,
+            quantity,
+        })
+    }
+
+    async fn end_trial(&self, subscription_id: &str) -> Result<Subscription, PaymentError> {
+        let url = format!(
+            "{}/v1/subscriptions/{}",
+            self.config.api_base_url, subscription_id
+        );
+
+        let response = self
+            .http_client
+            .post(&url)
+            .basic_auth(self.config.api_key.expose_secret(), Option::<&str>::None)
+            .form(&[("trial_end", "now")])
+            .send()
+            .await
+            .map_err(|e| PaymentError::network(e.to_string()))?;
+
+        if !response.status().is_success() {
+            let error_text = response.text().await.unwrap_or_default();
+            tracing::error!(error = %error_text, "Stripe end_trial failed");
+            return Err(PaymentError::new(
+                PaymentErrorCode::ProviderError,
+                format!("Stripe API error: {}", error_text),
+            ));
+        }
+
+        let stripe_sub: super::webhook_types::StripeSubscription =
+            response.json().await.map_err(|e| {
+                PaymentError::new(
+                    PaymentErrorCode::ProviderError,
+                    format!("Failed to parse Stripe response: {}", e),
+                )
+            })?;
+
+        let quantity = stripe_sub.quantity();
+        Ok(Subscription {
+            id: stripe_sub.id,
+            customer_id: stripe_sub.customer,
+            status: match stripe_sub.status.as_str() {
+                "active" => SubscriptionStatus::Active,
+                "past_due" => SubscriptionStatus::PastDue,
+                "canceled" => SubscriptionStatus::Canceled,
+                "trialing" => SubscriptionStatus::Trialing,
+                "incomplete" => SubscriptionStatus::Incomplete,
+                _ => SubscriptionStatus::Unknown,
+            },
+            current_period_start: stripe_sub.current_period_start,
+            current_period_end: stripe_sub.current_period_end,
+            cancel_at_period_end: stripe_sub.cancel_at_period_end,
+            canceled_at: stripe_sub.canceled_at,
+            quantity,
+        })
+    }
+
+    async fn report_metered_usage(&self, report: MeteredUsageReport) -> Result<(), PaymentError> {
+        let subscription = self
+            .fetch_stripe_subscription(&report.subscription_id)
+            .await?;
+        let item_id = subscription.metered_item_id().ok_or_else(|| {
+            PaymentError::new(
+                PaymentErrorCode::ProviderError,
+                format!(
+                    "Subscription {} has no metered price",
+                    report.subscription_id
+                ),
+            )
+        })?;
+
+        let url = format!(
+            "{}/v1/subscription_items/{}/usage_records",
+            self.config.api_base_url, item_id
+        );
+
+        // `set` replaces the usage at this timestamp instead of adding to it
+        let response = self
+            .http_client
+            .post(&url)
+            .basic_auth(self.config.api_key.expose_secret(), Option::<&str>::None)
+            .header("Idempotency-Key", &report.idempotency_key)
+            .form(&[
+                ("quantity", report.quantity.to_string()),
+                ("timestamp", report.timestamp.to_string()),
+                ("action", "set".to_string()),
+            ])
+            .send()
+            .await
+            .map_err(|e| PaymentError::network(e.to_string()))?;
+
+        if !response.status().is_success() {
+            let error_text = response.text().await.unwrap_or_default();
+            tracing::error!(error = %error_text, "Stripe report_metered_usage failed");
+            return Err(PaymentError::new(
+                PaymentErrorCode::ProviderError,
+                format!("Stripe API error: {}", error_text),
+            ));
+        }
+
+        Ok(())
+    }
+
     async fn create_checkout_session(
         &self,
         request: CreateCheckoutRequest,
@@ -647,6 +889,10 @@ impl PaymentProvider for StripePaymentAdapter {
             params.push(("discounts[0][coupon]", promo));
         }
 
+        if let Some(days) = request.trial_days.filter(|days| *days > 0) {
+            params.push(("subscription_data[trial_period_days]", days.to_string()));
+        }
+
         let response = self
             .http_client
             .post(&url)
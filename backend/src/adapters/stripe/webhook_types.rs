@@ -302,6 +302,40 @@ pub struct StripeSubscription {
     pub items: StripeSubscriptionItems,
 }
 
+impl StripeSubscription {
+    /// Seats billed for, summed over the subscription's licensed items.
+    ///
+    /// A subscription without licensed items bills for one seat.
+    pub fn quantity(&self) -> u32 {
+        let total: i64 = self
+            .items
+            .data
+            .iter()
+            .filter(|item| !item.is_metered())
+            .map(|item| item.quantity)
+            .sum();
+        u32::try_from(total).ok().filter(|q| *q > 0).unwrap_or(1)
+    }
+
+    /// ID of the item carrying the plan's price, needed to change its quantity.
+    pub fn primary_item_id(&self) -> Option<&str> {
+        self.items
+            .data
+            .iter()
+            .find(|item| !item.is_metered())
+            .map(|item| item.id.as_str())
+    }
+
+    /// ID of the item on a metered price, which usage is reported against.
+    pub fn metered_item_id(&self) -> Option<&str> {
+        self.items
+            .data
+            .iter()
+            .find(|item| item.is_metered())
+            .map(|item| item.id.as_str())
+    }
+}
+
 /// Subscription items container.
 #[derive(Debug, Clone, Default, Deserialize, Serialize)]
 pub struct StripeSubscriptionItems {
@@ -328,6 +362,16 @@ pub struct StripeSubscriptionItem {
     pub quantity: i64,
 }
 
+impl StripeSubscriptionItem {
+    /// Whether the item bills for reported usage rather than a quantity.
+    pub fn is_metered(&self) -> bool {
+        self.price
+            .recurring
+            .as_ref()
+            .is_some_and(|recurring| recurring.usage_type == "metered")
+    }
+}
+
 fn default_quantity() -> i64 {
     1
 }
@@ -359,6 +403,14 @@ pub struct StripePriceRecurring {
 
     /// Number of intervals between billings.
     pub interval_count: i32,
+
+    /// How the price is billed ("licensed" or "metered").
+    #[serde(default = "default_usage_type")]
+    pub usage_type: String,
+}
+
+fn default_usage_type() -> String {
+    "licensed".to_string()
 }
 
 /// Stripe Invoice object.
@@ -775,6 +827,77 @@ mod tests {
         assert_eq!(sub.items.data.len(), 1);
         assert_eq!(sub.items.data[0].price.unit_amount, Some(1999));
         assert_eq!(sub.items.data[0].price.currency, "cad");
+        assert_eq!(sub.quantity(), 1);
+        assert_eq!(sub.primary_item_id(), Some("si_abc"));
+    }
+
+    #[test]
+    fn subscription_without_items_bills_one_seat() {
+        let json = r#"{
+            "id": "sub_test_123",
+            "object": "subscription",
+            "customer": "cus_xyz",
+            "status": "active",
+            "current_period_start": 1704067200,
+            "current_period_end": 1706745600
+        }"#;
+
+        let sub: StripeSubscription = serde_json::from_str(json).unwrap();
+
+        assert_eq!(sub.quantity(), 1);
+        assert_eq!(sub.primary_item_id(), None);
+    }
+
+    #[test]
+    fn metered_items_do_not_count_as_seats() {
+        let json = r#"{
+            "id": "sub_test_123",
+            "object": "subscription",
+            "customer": "cus_xyz",
+            "status": "active",
+            "current_period_start": 1704067200,
+            "current_period_end": 1706745600,
+            "items": {
+                "object": "list",
+                "data": [
+                    {
+                        "id": "si_tokens",
+                        "price": {
+                            "id": "price_tokens",
+                            "product": "prod_ai",
+                            "unit_amount": null,
+                            "currency": "cad",
+                            "recurring": {
+                                "interval": "month",
+                                "interval_count": 1,
+                                "usage_type": "metered"
+                            }
+                        }
+                    },
+                    {
+                        "id": "si_seats",
+                        "price": {
+                            "id": "price_team",
+                            "product": "prod_sherpa",
+                            "unit_amount": 1999,
+                            "currency": "cad",
+                            "recurring": {
+                                "interval": "month",
+                                "interval_count": 1,
+                                "usage_type": "licensed"
+                            }
+                        },
+                        "quantity": 4
+                    }
+                ]
+            }
+        }"#;
+
+        let sub: StripeSubscription = serde_json::from_str(json).unwrap();
+
+        assert_eq!(sub.quantity(), 4);
+        assert_eq!(sub.primary_item_id(), Some("si_seats"));
+        assert_eq!(sub.metered_item_id(), Some("si_tokens"));
     }
 
     #[test]
@@ -0,0 +1,82 @@
+//! In-memory Teams share and organization chat settings repositories for
+//! testing and development.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use tokio::sync::RwLock;
+
+use crate::domain::document::{OrganizationChatSettings, TeamsShare};
+use crate::domain::foundation::{DomainError, TeamsShareId, Timestamp};
+use crate::ports::{OrganizationChatSettingsRepository, TeamsShareRepository};
+
+/// In-memory Teams shares keyed by ID.
+#[derive(Debug, Clone, Default)]
+pub struct InMemoryTeamsShareRepository {
+    shares: Arc<RwLock<HashMap<TeamsShareId, TeamsShare>>>,
+}
+
+impl InMemoryTeamsShareRepository {
+    /// Create an empty repository.
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait]
+impl TeamsShareRepository for InMemoryTeamsShareRepository {
+    async fn save(&self, share: &TeamsShare) -> Result<(), DomainError> {
+        self.shares.write().await.insert(share.id, share.clone());
+        Ok(())
+    }
+
+    async fn find_by_id(&self, id: &TeamsShareId) -> Result<Option<TeamsShare>, DomainError> {
+        Ok(self.shares.read().await.get(id).cloned())
+    }
+
+    async fn list_due(&self, now: Timestamp, limit: u32) -> Result<Vec<TeamsShare>, DomainError> {
+        let mut due: Vec<TeamsShare> = self
+            .shares
+            .read()
+            .await
+            .values()
+            .filter(|s| s.is_due(now))
+            .cloned()
+            .collect();
+        due.sort_by_key(|s| s.next_attempt_at);
+        due.truncate(limit as usize);
+        Ok(due)
+    }
+}
+
+/// In-memory organization chat settings keyed by organization.
+#[derive(Debug, Clone, Default)]
+pub struct InMemoryOrganizationChatSettingsRepository {
+    settings: Arc<RwLock<HashMap<String, OrganizationChatSettings>>>,
+}
+
+impl InMemoryOrganizationChatSettingsRepository {
+    /// Create an empty repository.
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait]
+impl OrganizationChatSettingsRepository for InMemoryOrganizationChatSettingsRepository {
+    async fn get(
+        &self,
+        organization: &str,
+    ) -> Result<Option<OrganizationChatSettings>, DomainError> {
+        Ok(self.settings.read().await.get(organization).cloned())
+    }
+
+    async fn save(&self, settings: &OrganizationChatSettings) -> Result<(), DomainError> {
+        self.settings
+            .write()
+            .await
+            .insert(settings.organization.clone(), settings.clone());
+        Ok(())
+    }
+}
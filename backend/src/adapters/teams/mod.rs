@@ -0,0 +1,11 @@
+//! Microsoft Teams adapters.
+//!
+//! - `TeamsWebhookClient` - Adaptive Card recommendation posts to incoming webhooks
+//! - `InMemoryTeamsShareRepository` - Shares and their retry state held in memory
+//! - `InMemoryOrganizationChatSettingsRepository` - Organizations' chat settings held in memory
+
+mod in_memory;
+mod webhook_client;
+
+pub use in_memory::{InMemoryOrganizationChatSettingsRepository, InMemoryTeamsShareRepository};
+pub use webhook_client::TeamsWebhookClient;
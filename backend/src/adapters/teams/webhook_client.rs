@@ -0,0 +1,273 @@
+//! Teams incoming webhook client - Implementation of TeamsClient.
+//!
+//! Recommendations are posted as an Adaptive Card: the decision title, facts
+//! for the standout option and the Decision Quality score, the synthesis,
+//! the leading caveats, and an action opening the published decision
+//! document. Classic connector webhooks answer 200 and Workflows triggers
+//! answer 202; both are treated as delivered.
+//!
+//! # Configuration
+//!
+//! ```ignore
+//! let client = Arc::new(TeamsWebhookClient::new(Duration::from_secs(10)));
+//! ```
+
+use std::time::Duration;
+
+use async_trait::async_trait;
+use reqwest::{redirect, Client, StatusCode};
+use secrecy::{ExposeSecret, Secret};
+use serde_json::{json, Value};
+
+use crate::domain::document::RecommendationDigest;
+use crate::ports::{TeamsClient, TeamsError};
+
+/// Adaptive Card schema version supported by Teams on every client.
+const ADAPTIVE_CARD_VERSION: &str = "1.4";
+
+/// Keeps the card well under Teams' 28 KB message limit.
+const MAX_TEXT_CHARS: usize = 3000;
+
+/// Teams incoming webhook client.
+pub struct TeamsWebhookClient {
+    client: Client,
+}
+
+impl TeamsWebhookClient {
+    /// Creates a client whose requests time out after `timeout`.
+    pub fn new(timeout: Duration) -> Self {
+        // Webhook URLs are validated against Teams hosts when registered;
+        // following redirects would let a response send us elsewhere.
+        let client = Client::builder()
+            .timeout(timeout)
+            .redirect(redirect::Policy::none())
+            .build()
+            .expect("Failed to create HTTP client");
+        Self { client }
+    }
+}
+
+impl Default for TeamsWebhookClient {
+    fn default() -> Self {
+        Self::new(Duration::from_secs(10))
+    }
+}
+
+#[async_trait]
+impl TeamsClient for TeamsWebhookClient {
+    #[tracing::instrument(name = "TeamsWebhookClient::post_recommendation", skip_all, err)]
+    async fn post_recommendation(
+        &self,
+        webhook_url: &Secret<String>,
+        digest: &RecommendationDigest,
+    ) -> Result<(), TeamsError> {
+        let response = self
+            .client
+            .post(webhook_url.expose_secret())
+            .json(&recommendation_message(digest))
+            .send()
+            .await
+            .map_err(|e| TeamsError::Unavailable(e.without_url().to_string()))?;
+
+        let status = response.status();
+        let body = response.text().await.unwrap_or_default();
+        check_response(status, &body)
+    }
+}
+
+/// Maps a webhook response to an error. Classic connectors report throttling
+/// of the channel with a 200 whose body names the upstream status.
+fn check_response(status: StatusCode, body: &str) -> Result<(), TeamsError> {
+    if status == StatusCode::TOO_MANY_REQUESTS || status.is_server_error() {
+        return Err(TeamsError::Unavailable(status.to_string()));
+    }
+    if status == StatusCode::NOT_FOUND || status == StatusCode::GONE {
+        return Err(TeamsError::WebhookGone(status.to_string()));
+    }
+    if !status.is_success() {
+        return Err(TeamsError::Rejected(format!(
+            "{}: {}",
+            status,
+            truncate(body, 200)
+        )));
+    }
+    if body.contains("HTTP error 429") {
+        return Err(TeamsError::Unavailable(truncate(body, 200)));
+    }
+    Ok(())
+}
+
+/// Webhook body: a message with a single Adaptive Card attachment.
+fn recommendation_message(digest: &RecommendationDigest) -> Value {
+    let mut body = vec![json!({
+        "type": "TextBlock",
+        "text": truncate(&digest.title, MAX_TEXT_CHARS),
+        "size": "Large",
+        "weight": "Bolder",
+        "wrap": true,
+    })];
+
+    let mut facts = Vec::new();
+    if let Some(standout) = &digest.standout {
+        facts.push(json!({ "title": "Recommended", "value": standout }));
+    }
+    if let Some(dq) = &digest.decision_quality {
+        facts.push(json!({
+            "title": "Decision quality",
+            "value": format!("{}%", dq.overall_score.value()),
+        }));
+        if !dq.weakest_elements.is_empty() {
+            facts.push(json!({
+                "title": "Weakest",
+                "value": dq.weakest_elements.join(", "),
+            }));
+        }
+    }
+    if !facts.is_empty() {
+        body.push(json!({ "type": "FactSet", "facts": facts }));
+    }
+
+    if !digest.synthesis.is_empty() {
+        body.push(text_block(&digest.synthesis));
+    }
+    if !digest.caveats.is_empty() {
+        body.push(json!({
+            "type": "TextBlock",
+            "text": "Caveats",
+            "weight": "Bolder",
+            "spacing": "Medium",
+        }));
+        let caveats: Vec<String> = digest
+            .caveats
+            .iter()
+            .map(|caveat| format!("- {}", caveat.replace('\n', " ")))
+            .collect();
+        body.push(text_block(&caveats.join("\n")));
+    }
+
+    json!({
+        "type": "message",
+        "summary": format!("Recommendation for {}", digest.title),
+        "attachments": [{
+            "contentType": "application/vnd.microsoft.card.adaptive",
+            "content": {
+                "$schema": "http://adaptivecards.io/schemas/adaptive-card.json",
+                "type": "AdaptiveCard",
+                "version": ADAPTIVE_CARD_VERSION,
+                "body": body,
+                "actions": [{
+                    "type": "Action.OpenUrl",
+                    "title": "View decision document",
+                    "url": digest.link,
+                }],
+            },
+        }],
+    })
+}
+
+fn text_block(text: &str) -> Value {
+    json!({
+        "type": "TextBlock",
+        "text": truncate(text, MAX_TEXT_CHARS),
+        "wrap": true,
+    })
+}
+
+/// Cuts `text` to at most `max` characters, ending with an ellipsis.
+fn truncate(text: &str, max: usize) -> String {
+    if text.chars().count() <= max {
+        return text.to_string();
+    }
+    let mut cut: String = text.chars().take(max - 1).collect();
+    cut.push('…');
+    cut
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::document::DecisionQualityDigest;
+    use crate::domain::foundation::Percentage;
+
+    fn digest() -> RecommendationDigest {
+        RecommendationDigest {
+            title: "Choose a supplier".to_string(),
+            standout: Some("Acme".to_string()),
+            synthesis: "Acme wins on cost and lead time.".to_string(),
+            caveats: vec!["Prices fixed for one year".to_string()],
+            link: "https://app.example.com/public/documents/abc".to_string(),
+            decision_quality: Some(DecisionQualityDigest {
+                overall_score: Percentage::new(55),
+                weakest_elements: vec!["Clear Tradeoffs".to_string()],
+            }),
+        }
+    }
+
+    #[test]
+    fn message_formats_the_digest_as_an_adaptive_card() {
+        let message = recommendation_message(&digest());
+        let attachment = &message["attachments"][0];
+        let card = &attachment["content"];
+        let body = card["body"].as_array().unwrap();
+
+        assert_eq!(
+            attachment["contentType"],
+            "application/vnd.microsoft.card.adaptive"
+        );
+        assert_eq!(card["type"], "AdaptiveCard");
+        assert_eq!(body[0]["text"], "Choose a supplier");
+        assert_eq!(body[1]["type"], "FactSet");
+        assert_eq!(body[1]["facts"][0]["value"], "Acme");
+        assert_eq!(body[1]["facts"][1]["value"], "55%");
+        assert_eq!(body[1]["facts"][2]["value"], "Clear Tradeoffs");
+        assert_eq!(body[2]["text"], "Acme wins on cost and lead time.");
+        assert_eq!(body[4]["text"], "- Prices fixed for one year");
+        assert_eq!(
+            card["actions"][0]["url"],
+            "https://app.example.com/public/documents/abc"
+        );
+    }
+
+    #[test]
+    fn message_omits_empty_sections() {
+        let digest = RecommendationDigest {
+            standout: None,
+            caveats: vec![],
+            decision_quality: None,
+            ..digest()
+        };
+
+        let message = recommendation_message(&digest);
+        let body = message["attachments"][0]["content"]["body"]
+            .as_array()
+            .unwrap();
+
+        assert_eq!(body.len(), 2);
+        assert_eq!(body[1]["text"], "Acme wins on cost and lead time.");
+    }
+
+    #[test]
+    fn responses_map_to_error_kinds() {
+        assert_eq!(check_response(StatusCode::OK, "1"), Ok(()));
+        assert_eq!(check_response(StatusCode::ACCEPTED, ""), Ok(()));
+        assert!(matches!(
+            check_response(
+                StatusCode::OK,
+                "Microsoft Teams endpoint returned HTTP error 429"
+            ),
+            Err(TeamsError::Unavailable(_))
+        ));
+        assert!(matches!(
+            check_response(StatusCode::SERVICE_UNAVAILABLE, ""),
+            Err(TeamsError::Unavailable(_))
+        ));
+        assert!(matches!(
+            check_response(StatusCode::NOT_FOUND, ""),
+            Err(TeamsError::WebhookGone(_))
+        ));
+        assert!(matches!(
+            check_response(StatusCode::BAD_REQUEST, "Bad payload"),
+            Err(TeamsError::Rejected(_))
+        ));
+    }
+}
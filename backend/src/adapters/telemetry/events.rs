@@ -0,0 +1,158 @@
+//! Event bus decorators that carry trace context across async boundaries.
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use tracing::Instrument;
+use tracing_opentelemetry::OpenTelemetrySpanExt;
+
+use crate::domain::foundation::{DomainError, EventEnvelope};
+use crate::ports::{EventHandler, EventPublisher};
+
+use super::propagation::{context_from_metadata, stamp_envelope};
+
+/// Publisher decorator that records a span per publish and stamps its trace
+/// context onto each envelope's `EventMetadata`.
+pub struct TracedEventPublisher {
+    inner: Arc<dyn EventPublisher>,
+}
+
+impl TracedEventPublisher {
+    pub fn new(inner: Arc<dyn EventPublisher>) -> Self {
+        Self { inner }
+    }
+}
+
+#[async_trait]
+impl EventPublisher for TracedEventPublisher {
+    async fn publish(&self, event: EventEnvelope) -> Result<(), DomainError> {
+        let span = tracing::info_span!(
+            "event.publish",
+            otel.kind = "producer",
+            event_type = %event.event_type,
+            event_id = %event.event_id,
+        );
+        async move { self.inner.publish(stamp_envelope(event)).await }
+            .instrument(span)
+            .await
+    }
+
+    async fn publish_all(&self, events: Vec<EventEnvelope>) -> Result<(), DomainError> {
+        let span = tracing::info_span!(
+            "event.publish_all",
+            otel.kind = "producer",
+            event_count = events.len(),
+        );
+        async move {
+            let events = events.into_iter().map(stamp_envelope).collect();
+            self.inner.publish_all(events).await
+        }
+        .instrument(span)
+        .await
+    }
+}
+
+/// Handler decorator that runs the inner handler in a span parented to the
+/// publishing span recorded in the event's metadata.
+pub struct TracedEventHandler<H: EventHandler> {
+    inner: H,
+}
+
+impl<H: EventHandler> TracedEventHandler<H> {
+    pub fn new(inner: H) -> Self {
+        Self { inner }
+    }
+}
+
+#[async_trait]
+impl<H: EventHandler + 'static> EventHandler for TracedEventHandler<H> {
+    async fn handle(&self, event: EventEnvelope) -> Result<(), DomainError> {
+        let span = tracing::info_span!(
+            "event.handle",
+            otel.kind = "consumer",
+            event_type = %event.event_type,
+            event_id = %event.event_id,
+            handler = self.inner.name(),
+        );
+        if let Some(parent) = context_from_metadata(&event.metadata) {
+            span.set_parent(parent);
+        }
+
+        self.inner.handle(event).instrument(span).await
+    }
+
+    fn name(&self) -> &'static str {
+        self.inner.name()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Mutex;
+
+    use serde_json::json;
+
+    use crate::adapters::telemetry::current_trace_context;
+    use crate::adapters::telemetry::propagation::test_support::otel_subscriber;
+    use crate::adapters::InMemoryEventBus;
+
+    fn envelope() -> EventEnvelope {
+        EventEnvelope::new("session.created.v1", "session-1", "Session", json!({}))
+    }
+
+    struct CapturingHandler {
+        seen: Arc<Mutex<Option<(String, String)>>>,
+    }
+
+    #[async_trait]
+    impl EventHandler for CapturingHandler {
+        async fn handle(&self, _event: EventEnvelope) -> Result<(), DomainError> {
+            *self.seen.lock().unwrap() = current_trace_context();
+            Ok(())
+        }
+
+        fn name(&self) -> &'static str {
+            "CapturingHandler"
+        }
+    }
+
+    #[tokio::test]
+    async fn publish_stamps_trace_context() {
+        let _guard = tracing::subscriber::set_default(otel_subscriber());
+        let bus = Arc::new(InMemoryEventBus::new());
+        let publisher = TracedEventPublisher::new(bus.clone());
+
+        publisher.publish(envelope()).await.unwrap();
+
+        let published = bus.published_events();
+        assert!(published[0].metadata.trace_id.is_some());
+        assert!(published[0].metadata.span_id.is_some());
+    }
+
+    #[tokio::test]
+    async fn publish_without_otel_leaves_metadata_empty() {
+        let bus = Arc::new(InMemoryEventBus::new());
+        let publisher = TracedEventPublisher::new(bus.clone());
+
+        publisher.publish_all(vec![envelope()]).await.unwrap();
+
+        assert!(bus.published_events()[0].metadata.trace_id.is_none());
+    }
+
+    #[tokio::test]
+    async fn handler_continues_publisher_trace() {
+        let _guard = tracing::subscriber::set_default(otel_subscriber());
+        let seen = Arc::new(Mutex::new(None));
+        let handler = TracedEventHandler::new(CapturingHandler { seen: seen.clone() });
+
+        let event = envelope()
+            .with_trace_context("4bf92f3577b34da6a3ce929d0e0e4736", "00f067aa0ba902b7");
+        handler.handle(event).await.unwrap();
+
+        let (trace_id, span_id) = seen.lock().unwrap().clone().unwrap();
+        assert_eq!(trace_id, "4bf92f3577b34da6a3ce929d0e0e4736");
+        assert_ne!(span_id, "00f067aa0ba902b7");
+        assert_eq!(handler.name(), "CapturingHandler");
+    }
+}
@@ -0,0 +1,115 @@
+//! Server span per HTTP request, parented to the caller's trace context.
+
+use std::time::Duration;
+
+use axum::extract::MatchedPath;
+use axum::http::{Request, Response};
+use tower_http::classify::{ServerErrorsAsFailures, SharedClassifier};
+use tower_http::trace::{DefaultOnRequest, MakeSpan, OnResponse, TraceLayer};
+use tracing::Span;
+use tracing_opentelemetry::OpenTelemetrySpanExt;
+
+use super::propagation::context_from_headers;
+
+/// Trace layer type returned by [`http_trace_layer`].
+pub type HttpTraceLayer =
+    TraceLayer<SharedClassifier<ServerErrorsAsFailures>, OtelMakeSpan, DefaultOnRequest, OtelOnResponse>;
+
+/// Tower layer that opens an `http.request` span for every request.
+///
+/// Apply with `Router::layer` so the matched route template is available:
+///
+/// ```ignore
+/// let app = Router::new().merge(session_routes(..)).layer(http_trace_layer());
+/// ```
+pub fn http_trace_layer() -> HttpTraceLayer {
+    TraceLayer::new_for_http()
+        .make_span_with(OtelMakeSpan)
+        .on_response(OtelOnResponse)
+}
+
+/// Header carrying the caller-supplied correlation ID.
+pub const CORRELATION_ID_HEADER: &str = "x-correlation-id";
+
+/// Creates a server span named after the route template, continuing any
+/// incoming W3C `traceparent`.
+///
+/// The span carries a `correlation_id` taken from `x-correlation-id`, then
+/// `x-request-id`, or freshly generated, so every log line emitted while
+/// handling the request can be joined on it.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct OtelMakeSpan;
+
+impl<B> MakeSpan<B> for OtelMakeSpan {
+    fn make_span(&mut self, request: &Request<B>) -> Span {
+        // Prefer the route template so span names have bounded cardinality
+        let route = request
+            .extensions()
+            .get::<MatchedPath>()
+            .map(|path| path.as_str().to_string())
+            .unwrap_or_else(|| request.uri().path().to_string());
+
+        let correlation_id = [CORRELATION_ID_HEADER, "x-request-id"]
+            .iter()
+            .find_map(|name| request.headers().get(*name))
+            .and_then(|value| value.to_str().ok())
+            .map(str::to_string)
+            .unwrap_or_else(|| uuid::Uuid::new_v4().to_string());
+
+        let span = tracing::info_span!(
+            "http.request",
+            otel.name = %format!("{} {}", request.method(), route),
+            otel.kind = "server",
+            http.method = %request.method(),
+            http.route = %route,
+            http.status_code = tracing::field::Empty,
+            correlation_id = %correlation_id,
+        );
+        span.set_parent(context_from_headers(request.headers()));
+        span
+    }
+}
+
+/// Records the response status on the request span.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct OtelOnResponse;
+
+impl<B> OnResponse<B> for OtelOnResponse {
+    fn on_response(self, response: &Response<B>, latency: Duration, span: &Span) {
+        let status = response.status().as_u16();
+        span.record("http.status_code", status);
+        tracing::debug!(
+            parent: span,
+            status,
+            latency_ms = latency.as_millis() as u64,
+            "request completed"
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::{body::Body, routing::get, Router};
+    use tower::ServiceExt;
+
+    #[tokio::test]
+    async fn layer_passes_requests_through() {
+        let app = Router::new()
+            .route("/ping", get(|| async { "pong" }))
+            .layer(http_trace_layer());
+
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .uri("/ping")
+                    .header("traceparent", "00-4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7-01")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), 200);
+    }
+}
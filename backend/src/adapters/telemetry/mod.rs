@@ -0,0 +1,44 @@
+//! Telemetry adapters - OpenTelemetry tracing setup and instrumentation.
+//!
+//! Spans are created with `tracing` throughout the codebase (HTTP requests,
+//! command/query handlers, Postgres adapters, AI provider calls) and exported
+//! via OTLP when configured. Trace context crosses asynchronous boundaries by
+//! riding along in `EventMetadata`:
+//!
+//! ```text
+//! HTTP (traceparent) ─► handler span ─► TracedEventPublisher ─► EventMetadata{trace_id, span_id}
+//!                                                                       │
+//!                           TracedEventHandler span ◄── parent ─────────┘
+//! ```
+//!
+//! - `init_telemetry` - Installs the global subscriber (JSON or pretty logs
+//!   with secret redaction) and the OTLP exporter
+//! - `http_trace_layer` - Tower layer creating a server span per request
+//! - `TracedEventPublisher` / `TracedEventHandler` - Event bus decorators
+//! - `SlowQueryLayer` / `SlowQueryLog` - Logs and records slow Postgres spans
+//!   with the handler that issued them
+//! - `SloTracker` - Rolling error budgets with burn-rate alerts
+
+mod events;
+mod http;
+mod propagation;
+mod redaction;
+mod slo;
+mod slow_queries;
+mod subscriber;
+
+pub use events::{TracedEventHandler, TracedEventPublisher};
+pub use http::{
+    http_trace_layer, HttpTraceLayer, OtelMakeSpan, OtelOnResponse, CORRELATION_ID_HEADER,
+};
+pub use propagation::{
+    context_from_headers, context_from_metadata, current_trace_context, stamp_envelope,
+    HeaderExtractor,
+};
+pub use redaction::{redact, RedactingMakeWriter, RedactingWriter, REDACTED, REDACTED_EMAIL};
+pub use slo::{
+    burn_alert_event, BurnSeverity, SloObjective, SloStatus, SloTracker, FAST_BURN_RATE,
+    SLOW_BURN_RATE,
+};
+pub use slow_queries::{SlowQueryGroup, SlowQueryLayer, SlowQueryLog};
+pub use subscriber::{init_telemetry, TelemetryConfig, TelemetryError, TelemetryGuard};
@@ -0,0 +1,169 @@
+//! W3C trace-context propagation between spans, HTTP headers, and events.
+
+use std::collections::HashMap;
+
+use axum::http::HeaderMap;
+use opentelemetry::propagation::{Extractor, TextMapPropagator};
+use opentelemetry::trace::TraceContextExt;
+use opentelemetry::Context;
+use opentelemetry_sdk::propagation::TraceContextPropagator;
+use tracing_opentelemetry::OpenTelemetrySpanExt;
+
+use crate::domain::foundation::{EventEnvelope, EventMetadata};
+
+/// Returns `(trace_id, span_id)` of the current span as lowercase hex.
+///
+/// `None` when no OpenTelemetry layer is recording the current span.
+pub fn current_trace_context() -> Option<(String, String)> {
+    let context = tracing::Span::current().context();
+    let span = context.span();
+    let span_context = span.span_context();
+    if !span_context.is_valid() {
+        return None;
+    }
+    Some((
+        span_context.trace_id().to_string(),
+        span_context.span_id().to_string(),
+    ))
+}
+
+/// Stamps the current trace context onto an envelope.
+///
+/// Envelopes that already carry a trace ID (e.g. replayed or relayed events)
+/// are left untouched.
+pub fn stamp_envelope(envelope: EventEnvelope) -> EventEnvelope {
+    if envelope.metadata.trace_id.is_some() {
+        return envelope;
+    }
+    match current_trace_context() {
+        Some((trace_id, span_id)) => envelope.with_trace_context(trace_id, span_id),
+        None => envelope,
+    }
+}
+
+/// Rebuilds the remote parent context carried in event metadata.
+pub fn context_from_metadata(metadata: &EventMetadata) -> Option<Context> {
+    let traceparent = metadata.traceparent()?;
+    let mut carrier = HashMap::new();
+    carrier.insert("traceparent".to_string(), traceparent);
+
+    let context = TraceContextPropagator::new().extract(&carrier);
+    let valid = context.span().span_context().is_valid();
+    valid.then_some(context)
+}
+
+/// Extracts the remote parent context from `traceparent`/`tracestate` headers.
+///
+/// Returns an empty context when the headers are absent or malformed.
+pub fn context_from_headers(headers: &HeaderMap) -> Context {
+    TraceContextPropagator::new().extract(&HeaderExtractor(headers))
+}
+
+/// Adapts an HTTP header map to the OpenTelemetry `Extractor` interface.
+pub struct HeaderExtractor<'a>(pub &'a HeaderMap);
+
+impl Extractor for HeaderExtractor<'_> {
+    fn get(&self, key: &str) -> Option<&str> {
+        self.0.get(key).and_then(|value| value.to_str().ok())
+    }
+
+    fn keys(&self) -> Vec<&str> {
+        self.0.keys().map(|key| key.as_str()).collect()
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod test_support {
+    use std::sync::OnceLock;
+
+    use opentelemetry::trace::TracerProvider as _;
+    use opentelemetry_sdk::trace::TracerProvider;
+    use tracing_subscriber::layer::SubscriberExt;
+
+    /// Tracers only hold a weak reference to their provider, so it must
+    /// outlive every subscriber built from it.
+    static PROVIDER: OnceLock<TracerProvider> = OnceLock::new();
+
+    /// Subscriber with an OpenTelemetry layer but no exporter.
+    pub fn otel_subscriber() -> impl tracing::Subscriber + Send + Sync {
+        let tracer = PROVIDER
+            .get_or_init(|| TracerProvider::builder().build())
+            .tracer("test");
+        tracing_subscriber::registry().with(tracing_opentelemetry::layer().with_tracer(tracer))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::http::HeaderValue;
+    use serde_json::json;
+
+    const TRACE_ID: &str = "4bf92f3577b34da6a3ce929d0e0e4736";
+    const SPAN_ID: &str = "00f067aa0ba902b7";
+
+    fn envelope() -> EventEnvelope {
+        EventEnvelope::new("test.event.v1", "agg-1", "Test", json!({}))
+    }
+
+    #[test]
+    fn no_context_without_otel_layer() {
+        assert!(current_trace_context().is_none());
+        assert!(stamp_envelope(envelope()).metadata.trace_id.is_none());
+    }
+
+    #[test]
+    fn stamps_current_span_context() {
+        tracing::subscriber::with_default(test_support::otel_subscriber(), || {
+            let span = tracing::info_span!("command");
+            let _entered = span.enter();
+
+            let (trace_id, span_id) = current_trace_context().unwrap();
+            let stamped = stamp_envelope(envelope());
+
+            assert_eq!(stamped.metadata.trace_id, Some(trace_id));
+            assert_eq!(stamped.metadata.span_id, Some(span_id));
+        });
+    }
+
+    #[test]
+    fn existing_trace_id_is_preserved() {
+        tracing::subscriber::with_default(test_support::otel_subscriber(), || {
+            let span = tracing::info_span!("command");
+            let _entered = span.enter();
+
+            let stamped = stamp_envelope(envelope().with_trace_context(TRACE_ID, SPAN_ID));
+            assert_eq!(stamped.metadata.trace_id.as_deref(), Some(TRACE_ID));
+        });
+    }
+
+    #[test]
+    fn metadata_context_round_trips() {
+        let metadata = envelope().with_trace_context(TRACE_ID, SPAN_ID).metadata;
+        let context = context_from_metadata(&metadata).unwrap();
+
+        let span = context.span();
+        assert_eq!(span.span_context().trace_id().to_string(), TRACE_ID);
+        assert_eq!(span.span_context().span_id().to_string(), SPAN_ID);
+        assert!(span.span_context().is_remote());
+    }
+
+    #[test]
+    fn invalid_metadata_yields_no_context() {
+        let metadata = envelope().with_trace_context("not-hex", SPAN_ID).metadata;
+        assert!(context_from_metadata(&metadata).is_none());
+        assert!(context_from_metadata(&EventMetadata::default()).is_none());
+    }
+
+    #[test]
+    fn extracts_context_from_headers() {
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            "traceparent",
+            HeaderValue::from_str(&format!("00-{}-{}-01", TRACE_ID, SPAN_ID)).unwrap(),
+        );
+
+        let context = context_from_headers(&headers);
+        assert_eq!(context.span().span_context().trace_id().to_string(), TRACE_ID);
+    }
+}
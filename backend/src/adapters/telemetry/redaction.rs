@@ -0,0 +1,363 @@
+//! Secret redaction for log output.
+//!
+//! Log lines are scrubbed after formatting, so redaction applies equally to
+//! event fields, span fields, and free-form messages in both the pretty and
+//! JSON formats. Three kinds of values are replaced:
+//!
+//! - Values of sensitive keys (`password=...`, `"api_key":"..."`), in full:
+//!   quoted values up to their closing quote, and unquoted values up to the
+//!   end of the field or the next key, since Display-formatted fields are not
+//!   quoted even when they contain spaces
+//! - Credentials recognizable by shape (provider key prefixes, JWTs, `Bearer` tokens)
+//! - Email addresses
+
+use std::borrow::Cow;
+use std::io::{self, Write};
+
+use tracing_subscriber::fmt::MakeWriter;
+
+/// Replacement for redacted secrets.
+pub const REDACTED: &str = "[REDACTED]";
+
+/// Replacement for redacted email addresses.
+pub const REDACTED_EMAIL: &str = "[REDACTED_EMAIL]";
+
+/// Key names whose values are always redacted (compared case-insensitively,
+/// after the last `.`).
+const SENSITIVE_KEYS: &[&str] = &[
+    "password",
+    "passwd",
+    "secret",
+    "token",
+    "api_key",
+    "apikey",
+    "authorization",
+    "cookie",
+    "set_cookie",
+    "signature",
+];
+
+/// Key suffixes whose values are redacted (e.g. `stripe_webhook_secret`).
+const SENSITIVE_KEY_SUFFIXES: &[&str] = &["_password", "_secret", "_api_key", "_token"];
+
+/// Prefixes of provider credentials (OpenAI, Anthropic, Stripe, Resend).
+const SECRET_PREFIXES: &[&str] = &["sk-", "sk_live_", "sk_test_", "rk_live_", "rk_test_", "whsec_", "re_"];
+
+/// Minimum length for a prefixed token to be treated as a credential.
+const MIN_SECRET_LEN: usize = 16;
+
+/// Redact secrets and email addresses from a formatted log line.
+///
+/// Returns the input unchanged (and unallocated) when nothing matches.
+pub fn redact(input: &str) -> Cow<'_, str> {
+    let mut output = String::with_capacity(input.len());
+    let mut changed = false;
+    let mut previous_word = "";
+
+    let mut rest = input;
+    while !rest.is_empty() {
+        let delimiter_len = rest.find(|c: char| !is_delimiter(c)).unwrap_or(rest.len());
+        let (delimiters, after) = rest.split_at(delimiter_len);
+        output.push_str(delimiters);
+        rest = after;
+        if rest.is_empty() {
+            break;
+        }
+
+        let word_len = rest.find(is_delimiter).unwrap_or(rest.len());
+        let (word, after) = rest.split_at(word_len);
+        rest = after;
+
+        if is_key(rest) && is_sensitive_key(word) {
+            let (delimiters, value, after) = split_value(rest);
+            output.push_str(word);
+            output.push_str(delimiters);
+            if !value.is_empty() {
+                output.push_str(REDACTED);
+                changed = true;
+            }
+            rest = after;
+            previous_word = "";
+            continue;
+        }
+
+        let replacement = if previous_word.eq_ignore_ascii_case("bearer") || looks_like_secret(word)
+        {
+            Some(REDACTED)
+        } else if looks_like_email(word) {
+            Some(REDACTED_EMAIL)
+        } else {
+            None
+        };
+
+        match replacement {
+            Some(replacement) => {
+                output.push_str(replacement);
+                changed = true;
+            }
+            None => output.push_str(word),
+        }
+
+        previous_word = word;
+    }
+
+    if changed {
+        Cow::Owned(output)
+    } else {
+        Cow::Borrowed(input)
+    }
+}
+
+fn is_delimiter(c: char) -> bool {
+    c.is_whitespace() || matches!(c, '"' | '\'' | '=' | ',' | ';' | ':' | '{' | '}' | '[' | ']' | '(' | ')' | '\\')
+}
+
+/// A word is a key when the delimiters that follow it contain `=` or `:`.
+fn is_key(following: &str) -> bool {
+    following
+        .chars()
+        .take_while(|c| is_delimiter(*c))
+        .any(|c| c == '=' || c == ':')
+}
+
+/// Splits what follows a key into the delimiters before its value, the
+/// value, and the rest of the line.
+///
+/// A quoted value (`"..."`, or `\"...\"` inside an escaped string) runs to
+/// its closing quote, or the end of the line if it has none. An unquoted
+/// value runs to the end of the field (a line break, `,`, `;`, `}`, `]`, or
+/// `"`) or up to the next key, whichever comes first.
+fn split_value(following: &str) -> (&str, &str, &str) {
+    // The separator, then any whitespace and an opening quote
+    let separator = following.find(['=', ':']).map_or(0, |i| i + 1);
+    let mut start = separator + following[separator..].len()
+        - following[separator..].trim_start().len();
+    let escapes = following[start..].len() - following[start..].trim_start_matches('\\').len();
+    let closing = following[start + escapes..]
+        .starts_with(['"', '\''])
+        .then(|| &following[start..start + escapes + 1]);
+    start += closing.map_or(0, str::len);
+    let (delimiters, candidate) = following.split_at(start);
+
+    let value_len = match closing {
+        Some(closing) => candidate
+            .match_indices(closing)
+            .map(|(i, _)| i)
+            .find(|&i| !candidate[..i].ends_with('\\'))
+            .unwrap_or_else(|| candidate.find('\n').unwrap_or(candidate.len())),
+        None => {
+            let field_len = candidate
+                .find(['\n', ',', ';', '}', ']', '"'])
+                .unwrap_or(candidate.len());
+            let field = &candidate[..field_len];
+            let next_key = field.char_indices().find(|&(i, c)| {
+                let after = field[i..].trim_start();
+                let word_len = after.find(is_delimiter).unwrap_or(after.len());
+                c.is_whitespace() && word_len > 0 && is_key(&after[word_len..])
+            });
+            next_key.map_or(field_len, |(i, _)| i).min(field.trim_end().len())
+        }
+    };
+    let (value, rest) = candidate.split_at(value_len);
+    (delimiters, value, rest)
+}
+
+fn is_sensitive_key(word: &str) -> bool {
+    let key = word.rsplit('.').next().unwrap_or(word).to_ascii_lowercase().replace('-', "_");
+    SENSITIVE_KEYS.contains(&key.as_str())
+        || SENSITIVE_KEY_SUFFIXES.iter().any(|suffix| key.ends_with(suffix))
+}
+
+fn looks_like_secret(word: &str) -> bool {
+    if word.len() >= MIN_SECRET_LEN && SECRET_PREFIXES.iter().any(|prefix| word.starts_with(prefix)) {
+        return true;
+    }
+    looks_like_jwt(word)
+}
+
+fn looks_like_jwt(word: &str) -> bool {
+    word.starts_with("eyJ")
+        && word.split('.').count() == 3
+        && word.split('.').all(|part| !part.is_empty())
+}
+
+fn looks_like_email(word: &str) -> bool {
+    let Some((local, domain)) = word.split_once('@') else {
+        return false;
+    };
+    !local.is_empty()
+        && !domain.contains('@')
+        && domain
+            .rsplit_once('.')
+            .is_some_and(|(host, tld)| !host.is_empty() && tld.len() >= 2 && tld.chars().all(|c| c.is_ascii_alphabetic()))
+}
+
+/// `MakeWriter` wrapper that redacts each formatted log line.
+#[derive(Debug, Clone)]
+pub struct RedactingMakeWriter<M> {
+    inner: M,
+}
+
+impl<M> RedactingMakeWriter<M> {
+    pub fn new(inner: M) -> Self {
+        Self { inner }
+    }
+}
+
+impl<'a, M: MakeWriter<'a>> MakeWriter<'a> for RedactingMakeWriter<M> {
+    type Writer = RedactingWriter<M::Writer>;
+
+    fn make_writer(&'a self) -> Self::Writer {
+        RedactingWriter::new(self.inner.make_writer())
+    }
+}
+
+/// Buffers one log line, then writes it redacted on flush or drop.
+///
+/// The fmt layer creates a writer per event, so buffering until drop keeps
+/// values that span several `write` calls together.
+pub struct RedactingWriter<W: Write> {
+    inner: W,
+    buffer: Vec<u8>,
+}
+
+impl<W: Write> RedactingWriter<W> {
+    pub fn new(inner: W) -> Self {
+        Self {
+            inner,
+            buffer: Vec::new(),
+        }
+    }
+
+    fn write_buffered(&mut self) -> io::Result<()> {
+        if self.buffer.is_empty() {
+            return Ok(());
+        }
+        let text = String::from_utf8_lossy(&self.buffer);
+        self.inner.write_all(redact(&text).as_bytes())?;
+        self.buffer.clear();
+        Ok(())
+    }
+}
+
+impl<W: Write> Write for RedactingWriter<W> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.buffer.extend_from_slice(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.write_buffered()?;
+        self.inner.flush()
+    }
+}
+
+impl<W: Write> Drop for RedactingWriter<W> {
+    fn drop(&mut self) {
+        let _ = self.write_buffered();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn leaves_ordinary_lines_untouched() {
+        let line = "INFO session created session_id=4f1c prompt_tokens=120";
+        assert!(matches!(redact(line), Cow::Borrowed(_)));
+    }
+
+    #[test]
+    fn redacts_sensitive_key_values() {
+        assert_eq!(
+            redact("login password=hunter2 user=u1"),
+            "login password=[REDACTED] user=u1"
+        );
+        assert_eq!(
+            redact(r#"{"stripe_webhook_secret":"abc123","user":"u1"}"#),
+            r#"{"stripe_webhook_secret":"[REDACTED]","user":"u1"}"#
+        );
+        assert_eq!(redact("auth.token=xyz"), "auth.token=[REDACTED]");
+    }
+
+    #[test]
+    fn redacts_multi_word_values_up_to_their_delimiter() {
+        assert_eq!(
+            redact("login password=correct horse battery staple user=u1"),
+            "login password=[REDACTED] user=u1"
+        );
+        assert_eq!(
+            redact(r#"{"secret":"correct horse, battery","user":"u1"}"#),
+            r#"{"secret":"[REDACTED]","user":"u1"}"#
+        );
+        assert_eq!(
+            redact(r#"login password="correct horse \"battery\"" ok"#),
+            r#"login password="[REDACTED]" ok"#
+        );
+        assert_eq!(
+            redact(r#"{"message":"login {\"password\":\"correct horse\"} done"}"#),
+            r#"{"message":"login {\"password\":\"[REDACTED]\"} done"}"#
+        );
+        assert_eq!(
+            redact("authorization: Bearer abc.def, retries: 2"),
+            "authorization: [REDACTED], retries: 2"
+        );
+        assert_eq!(
+            redact("secret=\"correct horse\nnext line"),
+            "secret=\"[REDACTED]\nnext line"
+        );
+        assert_eq!(redact(r#"{"password":"","user":"u1"}"#), r#"{"password":"","user":"u1"}"#);
+    }
+
+    #[test]
+    fn token_counts_are_not_redacted() {
+        assert_eq!(redact("completion_tokens=42 total_tokens=50"), "completion_tokens=42 total_tokens=50");
+    }
+
+    #[test]
+    fn redacts_provider_keys_by_prefix() {
+        assert_eq!(
+            redact("using key sk-ant-REDACTED for request"),
+            "using key [REDACTED] for request"
+        );
+        assert_eq!(redact("secret whsec_1234567890abcdef"), "secret [REDACTED]");
+    }
+
+    #[test]
+    fn short_prefixed_words_are_not_secrets() {
+        assert_eq!(redact("re_try scheduled"), "re_try scheduled");
+    }
+
+    #[test]
+    fn redacts_bearer_tokens_and_jwts() {
+        assert_eq!(
+            redact("authorization header Bearer abc.def"),
+            "authorization header Bearer [REDACTED]"
+        );
+        assert_eq!(
+            redact("jwt eyJhbGciOiJIUzI1NiJ9.eyJzdWIiOiIxIn0.sig"),
+            "jwt [REDACTED]"
+        );
+    }
+
+    #[test]
+    fn redacts_emails() {
+        assert_eq!(
+            redact(r#"{"email":"alice@example.com"}"#),
+            r#"{"email":"[REDACTED_EMAIL]"}"#
+        );
+        assert_eq!(redact("not an email: user@localhost"), "not an email: user@localhost");
+    }
+
+    #[test]
+    fn writer_redacts_on_drop() {
+        let mut output = Vec::new();
+        {
+            let mut writer = RedactingWriter::new(&mut output);
+            writer.write_all(b"api_key=").unwrap();
+            writer.write_all(b"sk-live-value\n").unwrap();
+        }
+        assert_eq!(String::from_utf8(output).unwrap(), "api_key=[REDACTED]\n");
+    }
+}
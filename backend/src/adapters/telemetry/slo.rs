@@ -0,0 +1,537 @@
+//! SLO tracking with rolling error budgets.
+//!
+//! [`SloTracker`] implements the `SloRecorder` port. Events are counted in
+//! per-minute buckets over the budget window, which is enough resolution for
+//! multi-window burn-rate alerting:
+//!
+//! | Severity | Long window | Short window | Burn rate | Budget spent |
+//! |----------|-------------|--------------|-----------|--------------|
+//! | Fast | 1h | 5m | 14.4x | 2% of 30 days in an hour |
+//! | Slow | 6h | 30m | 6x | 5% of 30 days in six hours |
+//!
+//! Both windows must exceed the rate, so an alert clears quickly once the
+//! problem stops. `spawn_evaluator` periodically reports every budget as
+//! structured `slo` log fields (picked up as metrics by the log pipeline) and
+//! publishes `slo.budget_burn_alert.v1` events when a budget burns too fast.
+
+use std::collections::{HashMap, VecDeque};
+use std::sync::{Arc, Mutex, Weak};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use serde::Serialize;
+use serde_json::json;
+
+use crate::domain::foundation::EventEnvelope;
+use crate::ports::{EventPublisher, Sli, SloRecorder};
+
+/// Burn rate that pages: 2% of a 30-day budget in one hour.
+pub const FAST_BURN_RATE: f64 = 14.4;
+
+/// Burn rate that tickets: 5% of a 30-day budget in six hours.
+pub const SLOW_BURN_RATE: f64 = 6.0;
+
+/// Minutes before an unchanged alert is raised again.
+const REALERT_AFTER_MINUTES: u64 = 60;
+
+/// Latency samples kept per SLI for percentile reporting.
+const MAX_LATENCY_SAMPLES: usize = 10_000;
+
+/// Objective for one SLI.
+#[derive(Debug, Clone)]
+pub struct SloObjective {
+    pub sli: Sli,
+    /// Fraction of events that must be good (e.g. 0.99).
+    pub target: f64,
+    /// For latency SLIs, the slowest latency that still counts as good.
+    pub latency_threshold: Option<Duration>,
+}
+
+impl SloObjective {
+    pub fn new(sli: Sli, target: f64) -> Self {
+        Self {
+            sli,
+            target,
+            latency_threshold: None,
+        }
+    }
+
+    /// Judges recorded latencies against `threshold`.
+    pub fn with_latency_threshold(mut self, threshold: Duration) -> Self {
+        self.latency_threshold = Some(threshold);
+        self
+    }
+}
+
+/// How quickly a budget is burning.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BurnSeverity {
+    Slow,
+    Fast,
+}
+
+/// Point-in-time view of one SLO.
+#[derive(Debug, Clone, Serialize)]
+pub struct SloStatus {
+    pub sli: Sli,
+    pub target: f64,
+    pub window_secs: u64,
+    pub total_events: u64,
+    pub good_events: u64,
+    /// Good fraction over the window; `None` with no events.
+    pub compliance: Option<f64>,
+    /// Fraction of the error budget left (negative once exhausted).
+    pub error_budget_remaining: f64,
+    pub burn_rate_1h: f64,
+    pub burn_rate_6h: f64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub latency_threshold_ms: Option<u64>,
+    /// Observed p95 over the last hour, for latency SLIs.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub p95_latency_ms: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub alert: Option<BurnSeverity>,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Bucket {
+    minute: u64,
+    good: u64,
+    total: u64,
+}
+
+#[derive(Debug)]
+struct SliSeries {
+    objective: SloObjective,
+    buckets: VecDeque<Bucket>,
+    latencies: VecDeque<(u64, u64)>,
+    last_alert: Option<(BurnSeverity, u64)>,
+}
+
+impl SliSeries {
+    fn new(objective: SloObjective) -> Self {
+        Self {
+            objective,
+            buckets: VecDeque::new(),
+            latencies: VecDeque::new(),
+            last_alert: None,
+        }
+    }
+
+    fn record(&mut self, good: bool, minute: u64) {
+        match self.buckets.back_mut() {
+            Some(bucket) if bucket.minute == minute => {
+                bucket.total += 1;
+                bucket.good += u64::from(good);
+            }
+            _ => self.buckets.push_back(Bucket {
+                minute,
+                good: u64::from(good),
+                total: 1,
+            }),
+        }
+    }
+
+    fn prune(&mut self, now_minute: u64, window_minutes: u64) {
+        let oldest = now_minute.saturating_sub(window_minutes);
+        while self.buckets.front().is_some_and(|b| b.minute <= oldest) {
+            self.buckets.pop_front();
+        }
+        let oldest_latency = now_minute.saturating_sub(60);
+        while self
+            .latencies
+            .front()
+            .is_some_and(|(m, _)| *m <= oldest_latency)
+        {
+            self.latencies.pop_front();
+        }
+    }
+
+    /// (good, total) over the last `minutes`.
+    fn counts(&self, now_minute: u64, minutes: u64) -> (u64, u64) {
+        let oldest = now_minute.saturating_sub(minutes);
+        self.buckets
+            .iter()
+            .rev()
+            .take_while(|b| b.minute > oldest)
+            .fold((0, 0), |(good, total), b| (good + b.good, total + b.total))
+    }
+
+    fn burn_rate(&self, now_minute: u64, minutes: u64) -> f64 {
+        let (good, total) = self.counts(now_minute, minutes);
+        if total == 0 {
+            return 0.0;
+        }
+        let error_rate = (total - good) as f64 / total as f64;
+        error_rate / (1.0 - self.objective.target)
+    }
+
+    fn p95_latency_ms(&self) -> Option<u64> {
+        if self.latencies.is_empty() {
+            return None;
+        }
+        let mut values: Vec<u64> = self.latencies.iter().map(|(_, ms)| *ms).collect();
+        values.sort_unstable();
+        let rank = ((values.len() as f64) * 0.95).ceil() as usize;
+        values.get(rank.saturating_sub(1)).copied()
+    }
+
+    fn status(&self, now_minute: u64, window_minutes: u64) -> SloStatus {
+        let (good, total) = self.counts(now_minute, window_minutes);
+        let target = self.objective.target;
+        let compliance = (total > 0).then(|| good as f64 / total as f64);
+        let error_budget_remaining = if total == 0 {
+            1.0
+        } else {
+            let allowed = total as f64 * (1.0 - target);
+            1.0 - (total - good) as f64 / allowed
+        };
+
+        let burn = |minutes| self.burn_rate(now_minute, minutes);
+        let alert = if burn(60) >= FAST_BURN_RATE && burn(5) >= FAST_BURN_RATE {
+            Some(BurnSeverity::Fast)
+        } else if burn(360) >= SLOW_BURN_RATE && burn(30) >= SLOW_BURN_RATE {
+            Some(BurnSeverity::Slow)
+        } else {
+            None
+        };
+
+        SloStatus {
+            sli: self.objective.sli,
+            target,
+            window_secs: window_minutes * 60,
+            total_events: total,
+            good_events: good,
+            compliance,
+            error_budget_remaining,
+            burn_rate_1h: burn(60),
+            burn_rate_6h: burn(360),
+            latency_threshold_ms: self
+                .objective
+                .latency_threshold
+                .map(|t| t.as_millis() as u64),
+            p95_latency_ms: self.p95_latency_ms(),
+            alert,
+        }
+    }
+}
+
+/// In-memory SLO tracker.
+///
+/// Counts are per process; with several instances each reports its own
+/// budget, which is sufficient for burn-rate alerting on shared traffic.
+#[derive(Debug)]
+pub struct SloTracker {
+    window_minutes: u64,
+    series: Mutex<HashMap<Sli, SliSeries>>,
+}
+
+impl SloTracker {
+    /// Creates a tracker with a rolling budget window and no objectives.
+    pub fn new(window: Duration) -> Self {
+        Self {
+            window_minutes: (window.as_secs() / 60).max(1),
+            series: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Adds (or replaces) the objective for an SLI.
+    pub fn with_objective(self, objective: SloObjective) -> Self {
+        self.series
+            .lock()
+            .unwrap()
+            .insert(objective.sli, SliSeries::new(objective));
+        self
+    }
+
+    /// Current status of every tracked SLO, in `Sli::ALL` order.
+    pub fn statuses(&self) -> Vec<SloStatus> {
+        self.statuses_at(current_minute())
+    }
+
+    fn statuses_at(&self, now_minute: u64) -> Vec<SloStatus> {
+        let mut series = self.series.lock().unwrap();
+        let mut statuses = Vec::with_capacity(Sli::ALL.len());
+        for sli in Sli::ALL.iter() {
+            if let Some(s) = series.get_mut(sli) {
+                s.prune(now_minute, self.window_minutes);
+                statuses.push(s.status(now_minute, self.window_minutes));
+            }
+        }
+        statuses
+    }
+
+    fn record_outcome_at(&self, sli: Sli, good: bool, minute: u64) {
+        if let Some(series) = self.series.lock().unwrap().get_mut(&sli) {
+            series.record(good, minute);
+        }
+    }
+
+    fn record_latency_at(&self, sli: Sli, latency: Duration, minute: u64) {
+        let mut series = self.series.lock().unwrap();
+        let Some(series) = series.get_mut(&sli) else {
+            return;
+        };
+        let good = match series.objective.latency_threshold {
+            Some(threshold) => latency <= threshold,
+            None => true,
+        };
+        series.record(good, minute);
+        if series.latencies.len() >= MAX_LATENCY_SAMPLES {
+            series.latencies.pop_front();
+        }
+        series
+            .latencies
+            .push_back((minute, latency.as_millis() as u64));
+    }
+
+    /// Reports every budget and returns alerts that should be raised now.
+    ///
+    /// An alert is returned when a budget starts burning, when it escalates
+    /// from slow to fast, or when it is still burning an hour later.
+    pub fn evaluate(&self) -> Vec<SloStatus> {
+        self.evaluate_at(current_minute())
+    }
+
+    fn evaluate_at(&self, now_minute: u64) -> Vec<SloStatus> {
+        let statuses = self.statuses_at(now_minute);
+        let mut series = self.series.lock().unwrap();
+        let mut alerts = Vec::new();
+
+        for status in statuses {
+            tracing::info!(
+                target: "slo",
+                sli = status.sli.as_str(),
+                objective = status.target,
+                total_events = status.total_events,
+                error_budget_remaining = status.error_budget_remaining,
+                burn_rate_1h = status.burn_rate_1h,
+                burn_rate_6h = status.burn_rate_6h,
+                p95_latency_ms = status.p95_latency_ms,
+                "SLO status"
+            );
+
+            let Some(series) = series.get_mut(&status.sli) else {
+                continue;
+            };
+            match status.alert {
+                Some(severity) => {
+                    let raise = match series.last_alert {
+                        Some((last, at)) => {
+                            severity > last || now_minute >= at + REALERT_AFTER_MINUTES
+                        }
+                        None => true,
+                    };
+                    if raise {
+                        series.last_alert = Some((severity, now_minute));
+                        alerts.push(status);
+                    }
+                }
+                None => series.last_alert = None,
+            }
+        }
+        alerts
+    }
+
+    /// Spawn a background task that evaluates budgets every `interval` and
+    /// publishes burn alerts.
+    ///
+    /// The task holds only a weak reference and exits once the tracker is
+    /// dropped. Publish failures are logged; the alert is raised again on the
+    /// next re-alert interval if the budget is still burning.
+    pub fn spawn_evaluator(
+        self: &Arc<Self>,
+        publisher: Arc<dyn EventPublisher>,
+        interval: Duration,
+    ) -> tokio::task::JoinHandle<()> {
+        let tracker: Weak<Self> = Arc::downgrade(self);
+
+        tokio::spawn(async move {
+            let mut ticker = tokio::time::interval(interval);
+            loop {
+                ticker.tick().await;
+
+                let Some(tracker) = tracker.upgrade() else {
+                    tracing::debug!("SLO evaluator stopping: tracker dropped");
+                    break;
+                };
+
+                for alert in tracker.evaluate() {
+                    tracing::warn!(
+                        sli = alert.sli.as_str(),
+                        severity = ?alert.alert,
+                        burn_rate_1h = alert.burn_rate_1h,
+                        error_budget_remaining = alert.error_budget_remaining,
+                        "SLO error budget burning too fast"
+                    );
+                    if let Err(e) = publisher.publish(burn_alert_event(&alert)).await {
+                        tracing::warn!("Failed to publish SLO burn alert: {}", e);
+                    }
+                }
+            }
+        })
+    }
+}
+
+impl SloRecorder for SloTracker {
+    fn record_outcome(&self, sli: Sli, good: bool) {
+        self.record_outcome_at(sli, good, current_minute());
+    }
+
+    fn record_latency(&self, sli: Sli, latency: Duration) {
+        self.record_latency_at(sli, latency, current_minute());
+    }
+}
+
+/// Builds the `slo.budget_burn_alert.v1` event for an alerting status.
+pub fn burn_alert_event(status: &SloStatus) -> EventEnvelope {
+    EventEnvelope::new(
+        "slo.budget_burn_alert.v1",
+        status.sli.as_str(),
+        "Slo",
+        json!({
+            "sli": status.sli,
+            "severity": status.alert,
+            "target": status.target,
+            "burn_rate_1h": status.burn_rate_1h,
+            "burn_rate_6h": status.burn_rate_6h,
+            "error_budget_remaining": status.error_budget_remaining,
+        }),
+    )
+}
+
+fn current_minute() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs() / 60)
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const NOW: u64 = 1_000_000;
+
+    fn tracker() -> SloTracker {
+        SloTracker::new(Duration::from_secs(28 * 24 * 60 * 60))
+            .with_objective(SloObjective::new(Sli::WebhookProcessing, 0.99))
+            .with_objective(
+                SloObjective::new(Sli::SendMessageLatency, 0.95)
+                    .with_latency_threshold(Duration::from_secs(10)),
+            )
+    }
+
+    fn status(tracker: &SloTracker, sli: Sli, now: u64) -> SloStatus {
+        tracker
+            .statuses_at(now)
+            .into_iter()
+            .find(|s| s.sli == sli)
+            .unwrap()
+    }
+
+    #[test]
+    fn empty_series_has_full_budget() {
+        let status = status(&tracker(), Sli::WebhookProcessing, NOW);
+        assert_eq!(status.total_events, 0);
+        assert_eq!(status.compliance, None);
+        assert_eq!(status.error_budget_remaining, 1.0);
+        assert_eq!(status.alert, None);
+    }
+
+    #[test]
+    fn budget_consumed_by_bad_events() {
+        let tracker = tracker();
+        // 1000 events spread over two days, 5 bad: half the 1% budget
+        for i in 0..1000 {
+            tracker.record_outcome_at(Sli::WebhookProcessing, i % 200 != 0, NOW - 2880 + i);
+        }
+
+        let status = status(&tracker, Sli::WebhookProcessing, NOW);
+        assert_eq!(status.total_events, 1000);
+        assert_eq!(status.good_events, 995);
+        assert!((status.error_budget_remaining - 0.5).abs() < 1e-9);
+        assert_eq!(status.alert, None);
+    }
+
+    #[test]
+    fn events_outside_window_are_dropped() {
+        let tracker = SloTracker::new(Duration::from_secs(3600))
+            .with_objective(SloObjective::new(Sli::WebhookProcessing, 0.99));
+        tracker.record_outcome_at(Sli::WebhookProcessing, false, NOW - 120);
+        tracker.record_outcome_at(Sli::WebhookProcessing, true, NOW);
+
+        let status = status(&tracker, Sli::WebhookProcessing, NOW);
+        assert_eq!(status.total_events, 1);
+        assert_eq!(status.error_budget_remaining, 1.0);
+    }
+
+    #[test]
+    fn latency_judged_against_threshold() {
+        let tracker = tracker();
+        for ms in [1_000, 2_000, 3_000, 20_000] {
+            tracker.record_latency_at(Sli::SendMessageLatency, Duration::from_millis(ms), NOW);
+        }
+
+        let status = status(&tracker, Sli::SendMessageLatency, NOW);
+        assert_eq!(status.total_events, 4);
+        assert_eq!(status.good_events, 3);
+        assert_eq!(status.latency_threshold_ms, Some(10_000));
+        assert_eq!(status.p95_latency_ms, Some(20_000));
+    }
+
+    #[test]
+    fn sustained_errors_raise_fast_burn_alert_once() {
+        let tracker = tracker();
+        // 20% errors for the last hour = 20x burn rate on a 99% target
+        for minute in NOW - 59..=NOW {
+            for i in 0..5 {
+                tracker.record_outcome_at(Sli::WebhookProcessing, i != 0, minute);
+            }
+        }
+
+        let alerts = tracker.evaluate_at(NOW);
+        assert_eq!(alerts.len(), 1);
+        assert_eq!(alerts[0].alert, Some(BurnSeverity::Fast));
+        assert!(alerts[0].burn_rate_1h > FAST_BURN_RATE);
+
+        // Still burning a minute later: not raised again until the re-alert interval
+        assert!(tracker.evaluate_at(NOW + 1).is_empty());
+    }
+
+    #[test]
+    fn recovered_short_window_clears_alert() {
+        let tracker = tracker();
+        for minute in NOW - 59..=NOW - 10 {
+            tracker.record_outcome_at(Sli::WebhookProcessing, false, minute);
+        }
+        for minute in NOW - 9..=NOW {
+            tracker.record_outcome_at(Sli::WebhookProcessing, true, minute);
+        }
+
+        let status = status(&tracker, Sli::WebhookProcessing, NOW);
+        assert!(status.burn_rate_1h > FAST_BURN_RATE);
+        assert_ne!(status.alert, Some(BurnSeverity::Fast));
+    }
+
+    #[test]
+    fn untracked_sli_is_ignored() {
+        let tracker = tracker();
+        tracker.record_outcome(Sli::ConversationStreamErrors, false);
+        assert_eq!(tracker.statuses().len(), 2);
+    }
+
+    #[test]
+    fn burn_alert_event_carries_severity() {
+        let tracker = tracker();
+        for minute in NOW - 59..=NOW {
+            tracker.record_outcome_at(Sli::WebhookProcessing, false, minute);
+        }
+        let alert = tracker.evaluate_at(NOW).remove(0);
+        let event = burn_alert_event(&alert);
+
+        assert_eq!(event.event_type, "slo.budget_burn_alert.v1");
+        assert_eq!(event.aggregate_id, "webhook_processing");
+        assert_eq!(event.payload["severity"], "fast");
+    }
+}
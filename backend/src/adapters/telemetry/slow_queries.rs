@@ -0,0 +1,332 @@
+//! Slow query detection.
+//!
+//! Every Postgres adapter method is instrumented with a span carrying
+//! `db.system = "postgresql"` (see `adapters::postgres`). [`SlowQueryLayer`]
+//! times those spans and, when one runs longer than the threshold, logs it
+//! with the enclosing command/query handler and records it in a
+//! [`SlowQueryLog`] for the admin report.
+//!
+//! ```text
+//! CreateCycleHandler::handle
+//!   └─ PostgresCycleRepository::save   (db.system = "postgresql", 480ms)
+//!        ⇒ WARN slow query query=PostgresCycleRepository::save handler=CreateCycleHandler::handle
+//! ```
+//!
+//! Span duration covers the whole adapter call, including waiting for a pool
+//! connection, which is usually what matters when a projection regresses.
+
+use std::collections::{HashMap, VecDeque};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use serde::Serialize;
+use tracing::field::{Field, Visit};
+use tracing::span::{Attributes, Id};
+use tracing::Subscriber;
+use tracing_subscriber::layer::Context;
+use tracing_subscriber::registry::LookupSpan;
+use tracing_subscriber::Layer;
+
+use crate::domain::foundation::Timestamp;
+
+/// Span field marking a database call.
+const DB_SYSTEM_FIELD: &str = "db.system";
+
+/// Suffix of command/query handler span names.
+const HANDLER_SPAN_SUFFIX: &str = "::handle";
+
+/// Default number of slow samples kept in memory.
+pub const DEFAULT_MAX_SLOW_SAMPLES: usize = 10_000;
+
+/// One query that exceeded the threshold.
+#[derive(Debug, Clone)]
+struct SlowQuerySample {
+    query: &'static str,
+    handler: Option<&'static str>,
+    duration: Duration,
+    observed_at: Instant,
+    recorded_at: Timestamp,
+}
+
+/// Slow queries grouped by adapter method and calling handler.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct SlowQueryGroup {
+    /// Adapter span name, e.g. "PostgresCycleRepository::save".
+    pub query: String,
+    /// Enclosing handler span, if the query ran inside one.
+    pub handler: Option<String>,
+    pub count: u64,
+    pub total_ms: u64,
+    pub max_ms: u64,
+    pub avg_ms: u64,
+    pub last_seen: Timestamp,
+}
+
+/// Bounded in-memory record of slow queries.
+#[derive(Debug)]
+pub struct SlowQueryLog {
+    threshold: Duration,
+    max_samples: usize,
+    samples: Mutex<VecDeque<SlowQuerySample>>,
+    total: AtomicU64,
+}
+
+impl SlowQueryLog {
+    /// Creates a log that records queries slower than `threshold`.
+    pub fn new(threshold: Duration) -> Self {
+        Self {
+            threshold,
+            max_samples: DEFAULT_MAX_SLOW_SAMPLES,
+            samples: Mutex::new(VecDeque::new()),
+            total: AtomicU64::new(0),
+        }
+    }
+
+    /// Caps the number of samples kept; the oldest are dropped first.
+    pub fn with_max_samples(mut self, max_samples: usize) -> Self {
+        self.max_samples = max_samples.max(1);
+        self
+    }
+
+    pub fn threshold(&self) -> Duration {
+        self.threshold
+    }
+
+    /// Slow queries seen since startup (including ones no longer retained).
+    pub fn total_count(&self) -> u64 {
+        self.total.load(Ordering::Relaxed)
+    }
+
+    /// Records a query if it exceeded the threshold. Returns true if recorded.
+    pub fn observe(
+        &self,
+        query: &'static str,
+        handler: Option<&'static str>,
+        duration: Duration,
+    ) -> bool {
+        if duration < self.threshold {
+            return false;
+        }
+
+        self.total.fetch_add(1, Ordering::Relaxed);
+        let mut samples = self.samples.lock().unwrap();
+        if samples.len() >= self.max_samples {
+            samples.pop_front();
+        }
+        samples.push_back(SlowQuerySample {
+            query,
+            handler,
+            duration,
+            observed_at: Instant::now(),
+            recorded_at: Timestamp::now(),
+        });
+        true
+    }
+
+    /// Slowest query groups within `window`, by total time spent.
+    pub fn top_groups(&self, window: Duration, limit: usize) -> Vec<SlowQueryGroup> {
+        let now = Instant::now();
+        let samples = self.samples.lock().unwrap();
+
+        let mut groups: HashMap<(&'static str, Option<&'static str>), SlowQueryGroup> =
+            HashMap::new();
+        for sample in samples
+            .iter()
+            .filter(|s| now.duration_since(s.observed_at) <= window)
+        {
+            let ms = sample.duration.as_millis() as u64;
+            let group = groups
+                .entry((sample.query, sample.handler))
+                .or_insert_with(|| SlowQueryGroup {
+                    query: sample.query.to_string(),
+                    handler: sample.handler.map(str::to_string),
+                    count: 0,
+                    total_ms: 0,
+                    max_ms: 0,
+                    avg_ms: 0,
+                    last_seen: sample.recorded_at,
+                });
+            group.count += 1;
+            group.total_ms += ms;
+            group.max_ms = group.max_ms.max(ms);
+            group.last_seen = sample.recorded_at;
+        }
+
+        let mut groups: Vec<_> = groups
+            .into_values()
+            .map(|mut group| {
+                group.avg_ms = group.total_ms / group.count;
+                group
+            })
+            .collect();
+        groups.sort_by(|a, b| {
+            b.total_ms
+                .cmp(&a.total_ms)
+                .then_with(|| a.query.cmp(&b.query))
+        });
+        groups.truncate(limit);
+        groups
+    }
+}
+
+/// Tracing layer that times database spans and reports slow ones.
+pub struct SlowQueryLayer {
+    log: Arc<SlowQueryLog>,
+}
+
+impl SlowQueryLayer {
+    pub fn new(log: Arc<SlowQueryLog>) -> Self {
+        Self { log }
+    }
+}
+
+/// Start time stored in the extensions of database spans.
+struct QueryStart(Instant);
+
+#[derive(Default)]
+struct DbSystemVisitor {
+    found: bool,
+}
+
+impl Visit for DbSystemVisitor {
+    fn record_str(&mut self, field: &Field, _value: &str) {
+        self.found |= field.name() == DB_SYSTEM_FIELD;
+    }
+
+    fn record_debug(&mut self, field: &Field, _value: &dyn std::fmt::Debug) {
+        self.found |= field.name() == DB_SYSTEM_FIELD;
+    }
+}
+
+impl<S> Layer<S> for SlowQueryLayer
+where
+    S: Subscriber + for<'a> LookupSpan<'a>,
+{
+    fn on_new_span(&self, attrs: &Attributes<'_>, id: &Id, ctx: Context<'_, S>) {
+        let mut visitor = DbSystemVisitor::default();
+        attrs.record(&mut visitor);
+        if !visitor.found {
+            return;
+        }
+        if let Some(span) = ctx.span(id) {
+            span.extensions_mut().insert(QueryStart(Instant::now()));
+        }
+    }
+
+    fn on_close(&self, id: Id, ctx: Context<'_, S>) {
+        let Some(span) = ctx.span(&id) else {
+            return;
+        };
+        let Some(duration) = span
+            .extensions()
+            .get::<QueryStart>()
+            .map(|start| start.0.elapsed())
+        else {
+            return;
+        };
+
+        let query = span.name();
+        let handler = span
+            .scope()
+            .skip(1)
+            .map(|ancestor| ancestor.name())
+            .find(|name| name.ends_with(HANDLER_SPAN_SUFFIX));
+
+        if self.log.observe(query, handler, duration) {
+            tracing::warn!(
+                query,
+                handler = handler.unwrap_or("none"),
+                duration_ms = duration.as_millis() as u64,
+                threshold_ms = self.log.threshold().as_millis() as u64,
+                "Slow query"
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tracing_subscriber::layer::SubscriberExt;
+
+    #[test]
+    fn fast_queries_are_ignored() {
+        let log = SlowQueryLog::new(Duration::from_millis(100));
+        assert!(!log.observe("PostgresX::get", None, Duration::from_millis(50)));
+        assert_eq!(log.total_count(), 0);
+    }
+
+    #[test]
+    fn groups_by_query_and_handler_sorted_by_total_time() {
+        let log = SlowQueryLog::new(Duration::from_millis(100));
+        log.observe(
+            "PostgresA::find",
+            Some("AHandler::handle"),
+            Duration::from_millis(200),
+        );
+        log.observe(
+            "PostgresA::find",
+            Some("AHandler::handle"),
+            Duration::from_millis(400),
+        );
+        log.observe("PostgresB::save", None, Duration::from_millis(900));
+        log.observe(
+            "PostgresA::find",
+            Some("BHandler::handle"),
+            Duration::from_millis(150),
+        );
+
+        let groups = log.top_groups(Duration::from_secs(3600), 10);
+
+        assert_eq!(groups.len(), 3);
+        assert_eq!(groups[0].query, "PostgresB::save");
+        assert_eq!(groups[1].handler.as_deref(), Some("AHandler::handle"));
+        assert_eq!(groups[1].count, 2);
+        assert_eq!(groups[1].avg_ms, 300);
+        assert_eq!(groups[1].max_ms, 400);
+        assert_eq!(log.top_groups(Duration::from_secs(3600), 1).len(), 1);
+    }
+
+    #[test]
+    fn oldest_samples_are_dropped_at_capacity() {
+        let log = SlowQueryLog::new(Duration::ZERO).with_max_samples(2);
+        log.observe("PostgresA::one", None, Duration::from_millis(1));
+        log.observe("PostgresA::two", None, Duration::from_millis(1));
+        log.observe("PostgresA::three", None, Duration::from_millis(1));
+
+        let queries: Vec<_> = log
+            .top_groups(Duration::from_secs(60), 10)
+            .into_iter()
+            .map(|g| g.query)
+            .collect();
+        assert_eq!(log.total_count(), 3);
+        assert!(!queries.contains(&"PostgresA::one".to_string()));
+    }
+
+    #[test]
+    fn layer_attributes_db_spans_to_enclosing_handler() {
+        let log = Arc::new(SlowQueryLog::new(Duration::ZERO));
+        let subscriber = tracing_subscriber::registry().with(SlowQueryLayer::new(log.clone()));
+
+        tracing::subscriber::with_default(subscriber, || {
+            let handler = tracing::info_span!("CreateCycleHandler::handle");
+            let _handler = handler.enter();
+            let query =
+                tracing::info_span!("PostgresCycleRepository::save", db.system = "postgresql");
+            drop(query.enter());
+            drop(query);
+
+            // Not a database span
+            drop(tracing::info_span!("OtherWork::run"));
+        });
+
+        let groups = log.top_groups(Duration::from_secs(60), 10);
+        assert_eq!(groups.len(), 1);
+        assert_eq!(groups[0].query, "PostgresCycleRepository::save");
+        assert_eq!(
+            groups[0].handler.as_deref(),
+            Some("CreateCycleHandler::handle")
+        );
+    }
+}
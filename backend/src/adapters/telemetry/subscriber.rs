@@ -0,0 +1,247 @@
+//! Global tracing subscriber installation: log formatting, filtering,
+//! redaction, and OTLP span export.
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use opentelemetry::KeyValue;
+use opentelemetry_otlp::WithExportConfig;
+use opentelemetry_sdk::propagation::TraceContextPropagator;
+use opentelemetry_sdk::trace::{self as sdktrace, Sampler};
+use opentelemetry_sdk::Resource;
+use tracing_subscriber::layer::SubscriberExt;
+use tracing_subscriber::util::SubscriberInitExt;
+use tracing_subscriber::{EnvFilter, Layer};
+
+use super::redaction::RedactingMakeWriter;
+use super::slow_queries::{SlowQueryLayer, SlowQueryLog};
+
+/// Telemetry settings.
+#[derive(Debug, Clone)]
+pub struct TelemetryConfig {
+    /// Service name reported on every span.
+    pub service_name: String,
+
+    /// OTLP gRPC collector endpoint. `None` disables export.
+    pub otlp_endpoint: Option<String>,
+
+    /// Fraction of root traces to sample (0.0 - 1.0).
+    pub sample_ratio: f64,
+
+    /// Timeout for each export batch.
+    pub export_timeout: Duration,
+
+    /// Emit one JSON object per log line instead of human-readable output.
+    pub json_logs: bool,
+
+    /// `EnvFilter` directives (e.g. "info,sqlx=warn"). `RUST_LOG` overrides this.
+    pub log_filter: String,
+
+    /// Records Postgres spans slower than its threshold. `None` disables detection.
+    pub slow_query_log: Option<Arc<SlowQueryLog>>,
+}
+
+impl TelemetryConfig {
+    /// Creates a config that logs spans locally without exporting them.
+    pub fn new(service_name: impl Into<String>) -> Self {
+        Self {
+            service_name: service_name.into(),
+            otlp_endpoint: None,
+            sample_ratio: 1.0,
+            export_timeout: Duration::from_secs(10),
+            json_logs: false,
+            log_filter: "info".to_string(),
+            slow_query_log: None,
+        }
+    }
+
+    /// Switches log output to JSON.
+    pub fn with_json_logs(mut self, json: bool) -> Self {
+        self.json_logs = json;
+        self
+    }
+
+    /// Sets the log filter directives.
+    pub fn with_log_filter(mut self, directives: impl Into<String>) -> Self {
+        self.log_filter = directives.into();
+        self
+    }
+
+    /// Enables slow query detection, recording into the given log.
+    pub fn with_slow_query_log(mut self, log: Arc<SlowQueryLog>) -> Self {
+        self.slow_query_log = Some(log);
+        self
+    }
+
+    /// Enables OTLP export to the given collector endpoint.
+    pub fn with_otlp_endpoint(mut self, endpoint: impl Into<String>) -> Self {
+        self.otlp_endpoint = Some(endpoint.into());
+        self
+    }
+
+    /// Sets the root-trace sample ratio (clamped to 0.0 - 1.0).
+    pub fn with_sample_ratio(mut self, ratio: f64) -> Self {
+        self.sample_ratio = ratio.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Sets the export timeout.
+    pub fn with_export_timeout(mut self, timeout: Duration) -> Self {
+        self.export_timeout = timeout;
+        self
+    }
+}
+
+/// Errors raised while installing telemetry.
+#[derive(Debug, thiserror::Error)]
+pub enum TelemetryError {
+    #[error("Failed to install OTLP exporter: {0}")]
+    Exporter(String),
+
+    #[error("Invalid log filter: {0}")]
+    Filter(String),
+
+    #[error("Failed to install tracing subscriber: {0}")]
+    Subscriber(String),
+}
+
+/// Flushes and shuts down the exporter when dropped.
+///
+/// Hold this for the lifetime of the process (e.g. in `main`).
+#[must_use = "dropping the guard shuts down span export"]
+pub struct TelemetryGuard {
+    otlp_enabled: bool,
+}
+
+impl Drop for TelemetryGuard {
+    fn drop(&mut self) {
+        if self.otlp_enabled {
+            opentelemetry::global::shutdown_tracer_provider();
+        }
+    }
+}
+
+/// Install the global tracing subscriber.
+///
+/// Installs:
+/// - An `EnvFilter` from `RUST_LOG` if set, otherwise from `log_filter`
+/// - A stdout formatter (JSON or pretty, without ANSI colors) whose output
+///   passes through secret redaction
+/// - When an OTLP endpoint is configured, an OpenTelemetry layer that batches
+///   spans to the collector
+/// - When a slow query log is configured, a layer timing Postgres spans
+///
+/// JSON lines include the current span and its ancestors, so the request
+/// span's `correlation_id` appears on every log emitted while handling it.
+/// The W3C trace context propagator is registered globally either way.
+///
+/// Must be called from within a Tokio runtime when export is enabled.
+pub fn init_telemetry(config: &TelemetryConfig) -> Result<TelemetryGuard, TelemetryError> {
+    opentelemetry::global::set_text_map_propagator(TraceContextPropagator::new());
+
+    let filter = match EnvFilter::try_from_default_env() {
+        Ok(filter) => filter,
+        Err(_) => EnvFilter::try_new(&config.log_filter)
+            .map_err(|e| TelemetryError::Filter(e.to_string()))?,
+    };
+
+    let writer = RedactingMakeWriter::new(std::io::stdout);
+    let fmt_layer = if config.json_logs {
+        tracing_subscriber::fmt::layer()
+            .json()
+            .with_current_span(true)
+            .with_span_list(true)
+            .with_writer(writer)
+            .boxed()
+    } else {
+        tracing_subscriber::fmt::layer()
+            .with_ansi(false)
+            .with_writer(writer)
+            .boxed()
+    };
+
+    let otel_layer = match &config.otlp_endpoint {
+        Some(endpoint) => Some(tracing_opentelemetry::layer().with_tracer(build_tracer(config, endpoint)?)),
+        None => None,
+    };
+
+    let slow_query_layer = config.slow_query_log.clone().map(SlowQueryLayer::new);
+
+    tracing_subscriber::registry()
+        .with(filter)
+        .with(fmt_layer)
+        .with(otel_layer)
+        .with(slow_query_layer)
+        .try_init()
+        .map_err(|e| TelemetryError::Subscriber(e.to_string()))?;
+
+    tracing::info!(
+        service = %config.service_name,
+        otlp_endpoint = ?config.otlp_endpoint,
+        sample_ratio = config.sample_ratio,
+        json_logs = config.json_logs,
+        "Telemetry initialized"
+    );
+
+    Ok(TelemetryGuard {
+        otlp_enabled: config.otlp_endpoint.is_some(),
+    })
+}
+
+fn build_tracer(config: &TelemetryConfig, endpoint: &str) -> Result<sdktrace::Tracer, TelemetryError> {
+    let exporter = opentelemetry_otlp::new_exporter()
+        .tonic()
+        .with_endpoint(endpoint)
+        .with_timeout(config.export_timeout);
+
+    let trace_config = sdktrace::config()
+        .with_sampler(Sampler::ParentBased(Box::new(Sampler::TraceIdRatioBased(
+            config.sample_ratio,
+        ))))
+        .with_resource(Resource::new(vec![KeyValue::new(
+            "service.name",
+            config.service_name.clone(),
+        )]));
+
+    opentelemetry_otlp::new_pipeline()
+        .tracing()
+        .with_exporter(exporter)
+        .with_trace_config(trace_config)
+        .install_batch(opentelemetry_sdk::runtime::Tokio)
+        .map_err(|e| TelemetryError::Exporter(e.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn config_defaults_disable_export() {
+        let config = TelemetryConfig::new("choice-sherpa");
+        assert!(config.otlp_endpoint.is_none());
+        assert_eq!(config.sample_ratio, 1.0);
+        assert!(!config.json_logs);
+        assert_eq!(config.log_filter, "info");
+    }
+
+    #[test]
+    fn builder_sets_logging_options() {
+        let config = TelemetryConfig::new("svc")
+            .with_json_logs(true)
+            .with_log_filter("info,sqlx=warn");
+        assert!(config.json_logs);
+        assert_eq!(config.log_filter, "info,sqlx=warn");
+    }
+
+    #[test]
+    fn sample_ratio_is_clamped() {
+        assert_eq!(TelemetryConfig::new("svc").with_sample_ratio(2.0).sample_ratio, 1.0);
+        assert_eq!(TelemetryConfig::new("svc").with_sample_ratio(-1.0).sample_ratio, 0.0);
+    }
+
+    #[test]
+    fn builder_sets_endpoint() {
+        let config = TelemetryConfig::new("svc").with_otlp_endpoint("http://collector:4317");
+        assert_eq!(config.otlp_endpoint.as_deref(), Some("http://collector:4317"));
+    }
+}
@@ -0,0 +1,81 @@
+//! In-memory token revocation store for testing and development.
+//!
+//! Cutoffs are kept for the life of the process. Not suitable for
+//! multi-server deployments, where every server must see a logout.
+
+use std::collections::HashMap;
+
+use async_trait::async_trait;
+use tokio::sync::RwLock;
+
+use crate::domain::foundation::{DomainError, Timestamp, UserId};
+use crate::ports::TokenRevocationStore;
+
+/// In-memory TokenRevocationStore.
+#[derive(Debug, Default)]
+pub struct InMemoryTokenRevocationStore {
+    cutoffs: RwLock<HashMap<UserId, Timestamp>>,
+}
+
+impl InMemoryTokenRevocationStore {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait]
+impl TokenRevocationStore for InMemoryTokenRevocationStore {
+    async fn revoke_all_for_user(
+        &self,
+        user_id: &UserId,
+        revoked_at: Timestamp,
+    ) -> Result<(), DomainError> {
+        self.cutoffs
+            .write()
+            .await
+            .insert(user_id.clone(), revoked_at);
+        Ok(())
+    }
+
+    async fn revoked_before(&self, user_id: &UserId) -> Result<Option<Timestamp>, DomainError> {
+        Ok(self.cutoffs.read().await.get(user_id).copied())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn cutoff_is_scoped_to_user() {
+        let store = InMemoryTokenRevocationStore::new();
+        let alice = UserId::new("alice").unwrap();
+        let bob = UserId::new("bob").unwrap();
+        let now = Timestamp::from_unix_secs(1_000);
+
+        store.revoke_all_for_user(&alice, now).await.unwrap();
+
+        assert_eq!(store.revoked_before(&alice).await.unwrap(), Some(now));
+        assert_eq!(store.revoked_before(&bob).await.unwrap(), None);
+    }
+
+    #[tokio::test]
+    async fn later_revocation_replaces_cutoff() {
+        let store = InMemoryTokenRevocationStore::new();
+        let alice = UserId::new("alice").unwrap();
+
+        store
+            .revoke_all_for_user(&alice, Timestamp::from_unix_secs(1_000))
+            .await
+            .unwrap();
+        store
+            .revoke_all_for_user(&alice, Timestamp::from_unix_secs(2_000))
+            .await
+            .unwrap();
+
+        assert_eq!(
+            store.revoked_before(&alice).await.unwrap(),
+            Some(Timestamp::from_unix_secs(2_000))
+        );
+    }
+}
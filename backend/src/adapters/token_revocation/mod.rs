@@ -0,0 +1,23 @@
+//! Token revocation adapters.
+//!
+//! Implementations of the TokenRevocationStore port for different backends.
+//!
+//! ## Available Adapters
+//!
+//! - `InMemoryTokenRevocationStore` - In-memory for testing and single-server
+//! - `RedisTokenRevocationStore` - Redis-backed for production multi-server
+//!
+//! ## Usage
+//!
+//! ```ignore
+//! let store = RedisTokenRevocationStore::new(conn)
+//!     .with_ttl(Duration::from_secs(12 * 60 * 60));
+//!
+//! let auth_state = AuthState::new(validator).with_revocation_store(Arc::new(store));
+//! ```
+
+mod in_memory;
+mod redis;
+
+pub use in_memory::InMemoryTokenRevocationStore;
+pub use redis::{RedisTokenRevocationStore, DEFAULT_REVOCATION_TTL};
@@ -0,0 +1,115 @@
+//! Redis-backed token revocation store for multi-server deployments.
+//!
+//! # Keys
+//!
+//! ```text
+//! auth:revoked_before_ms:{user_id}  STRING  cutoff in Unix milliseconds, expires after the TTL
+//! auth:revoked_before:{user_id}     STRING  legacy cutoff in Unix seconds, read until it expires
+//! ```
+//!
+//! The TTL should be at least the longest access token lifetime; once it
+//! passes, every token the cutoff covered has expired on its own.
+
+use std::time::Duration;
+
+use async_trait::async_trait;
+use redis::aio::MultiplexedConnection;
+use redis::AsyncCommands;
+
+use crate::domain::foundation::{DomainError, ErrorCode, Timestamp, UserId};
+use crate::ports::TokenRevocationStore;
+
+/// Default cutoff lifetime, covering tokens valid for up to a day.
+pub const DEFAULT_REVOCATION_TTL: Duration = Duration::from_secs(24 * 60 * 60);
+
+fn cutoff_key(user_id: &UserId) -> String {
+    format!("auth:revoked_before_ms:{}", user_id)
+}
+
+/// Key cutoffs were stored under, with second precision, before
+/// [`cutoff_key`].
+fn legacy_cutoff_key(user_id: &UserId) -> String {
+    format!("auth:revoked_before:{}", user_id)
+}
+
+fn redis_error(e: redis::RedisError) -> DomainError {
+    DomainError::new(
+        ErrorCode::CacheError,
+        format!("Token revocation store unavailable: {}", e),
+    )
+}
+
+/// Redis-backed TokenRevocationStore.
+#[derive(Clone)]
+pub struct RedisTokenRevocationStore {
+    conn: MultiplexedConnection,
+    ttl: Duration,
+}
+
+impl RedisTokenRevocationStore {
+    pub fn new(conn: MultiplexedConnection) -> Self {
+        Self {
+            conn,
+            ttl: DEFAULT_REVOCATION_TTL,
+        }
+    }
+
+    /// Sets how long a cutoff is kept; use the longest access token lifetime.
+    pub fn with_ttl(mut self, ttl: Duration) -> Self {
+        self.ttl = ttl;
+        self
+    }
+}
+
+#[async_trait]
+impl TokenRevocationStore for RedisTokenRevocationStore {
+    #[tracing::instrument(name = "RedisTokenRevocationStore::revoke_all_for_user", skip_all, fields(db.system = "redis"), err)]
+    async fn revoke_all_for_user(
+        &self,
+        user_id: &UserId,
+        revoked_at: Timestamp,
+    ) -> Result<(), DomainError> {
+        let mut conn = self.conn.clone();
+        conn.set_ex::<_, _, ()>(
+            cutoff_key(user_id),
+            revoked_at.as_unix_millis(),
+            self.ttl.as_secs().max(1),
+        )
+        .await
+        .map_err(redis_error)
+    }
+
+    #[tracing::instrument(name = "RedisTokenRevocationStore::revoked_before", skip_all, fields(db.system = "redis"), err)]
+    async fn revoked_before(&self, user_id: &UserId) -> Result<Option<Timestamp>, DomainError> {
+        let mut conn = self.conn.clone();
+        let (millis, legacy_secs): (Option<u64>, Option<u64>) = redis::cmd("MGET")
+            .arg(cutoff_key(user_id))
+            .arg(legacy_cutoff_key(user_id))
+            .query_async(&mut conn)
+            .await
+            .map_err(redis_error)?;
+        // A legacy cutoff covers its whole second, as it used to.
+        let legacy = legacy_secs.map(|secs| Timestamp::from_unix_secs(secs + 1));
+        Ok(millis.map(Timestamp::from_unix_millis).max(legacy))
+    }
+}
+
+impl std::fmt::Debug for RedisTokenRevocationStore {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("RedisTokenRevocationStore")
+            .field("ttl", &self.ttl)
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cutoff_key_is_namespaced_by_user() {
+        let user_id = UserId::new("user-123").unwrap();
+        assert_eq!(cutoff_key(&user_id), "auth:revoked_before_ms:user-123");
+        assert_eq!(legacy_cutoff_key(&user_id), "auth:revoked_before:user-123");
+    }
+}
@@ -45,6 +45,9 @@ impl JsonSchemaValidator {
             ComponentType::ProblemFrame => {
                 include_str!("../../domain/proact/schemas/problem_frame.json")
             }
+            ComponentType::StakeholderAnalysis => {
+                include_str!("../../domain/proact/schemas/stakeholder_analysis.json")
+            }
             ComponentType::Objectives => {
                 include_str!("../../domain/proact/schemas/objectives.json")
             }
@@ -54,6 +57,9 @@ impl JsonSchemaValidator {
             ComponentType::Consequences => {
                 include_str!("../../domain/proact/schemas/consequences.json")
             }
+            ComponentType::UncertaintyRegister => {
+                include_str!("../../domain/proact/schemas/uncertainty_register.json")
+            }
             ComponentType::Tradeoffs => {
                 include_str!("../../domain/proact/schemas/tradeoffs.json")
             }
@@ -81,9 +87,11 @@ impl JsonSchemaValidator {
         match ct {
             ComponentType::IssueRaising => self.validate_issue_raising(output),
             ComponentType::ProblemFrame => self.validate_problem_frame(output),
+            ComponentType::StakeholderAnalysis => self.validate_stakeholder_analysis(output),
             ComponentType::Objectives => self.validate_objectives(output),
             ComponentType::Alternatives => self.validate_alternatives(output),
             ComponentType::Consequences => self.validate_consequences(output),
+            ComponentType::UncertaintyRegister => self.validate_uncertainty_register(output),
             ComponentType::Tradeoffs => self.validate_tradeoffs(output),
             ComponentType::Recommendation => self.validate_recommendation(output),
             ComponentType::DecisionQuality => self.validate_decision_quality(output),
@@ -225,6 +233,126 @@ impl JsonSchemaValidator {
         Ok(())
     }
 
+    fn validate_stakeholder_analysis(&self, output: &Value) -> Result<(), SchemaValidationError> {
+        let obj = self.require_object(output, "root")?;
+
+        // Required fields
+        self.require_field(obj, "stakeholders", "root")?;
+
+        // stakeholders must have at least 1 item
+        if let Some(arr) = obj.get("stakeholders").and_then(|v| v.as_array()) {
+            if arr.is_empty() {
+                return Err(SchemaValidationError::ArrayTooShort {
+                    field: "stakeholders".to_string(),
+                    min: 1,
+                    actual: 0,
+                });
+            }
+            for (i, item) in arr.iter().enumerate() {
+                self.validate_stakeholder(item, &format!("stakeholders[{}]", i))?;
+            }
+        }
+
+        if let Some(arr) = obj.get("engagement_plan").and_then(|v| v.as_array()) {
+            for (i, item) in arr.iter().enumerate() {
+                self.validate_engagement_action(item, &format!("engagement_plan[{}]", i))?;
+            }
+        }
+
+        Ok(())
+    }
+
+    fn validate_stakeholder(
+        &self,
+        value: &Value,
+        path: &str,
+    ) -> Result<(), SchemaValidationError> {
+        let obj = self.require_object(value, path)?;
+        self.require_non_empty_string(obj, "id", path)?;
+        self.require_non_empty_string(obj, "name", path)?;
+        self.require_string_field(obj, "role", path)?;
+        for level in ["power", "interest"] {
+            self.require_field(obj, level, path)?;
+            self.validate_enum(
+                &obj[level],
+                &["low", "medium", "high"],
+                &format!("{}.{}", path, level),
+            )?;
+        }
+        Ok(())
+    }
+
+    fn validate_engagement_action(
+        &self,
+        value: &Value,
+        path: &str,
+    ) -> Result<(), SchemaValidationError> {
+        let obj = self.require_object(value, path)?;
+        self.require_non_empty_string(obj, "stakeholder_id", path)?;
+        self.require_field(obj, "strategy", path)?;
+        self.validate_enum(
+            &obj["strategy"],
+            &["manage_closely", "keep_satisfied", "keep_informed", "monitor"],
+            &format!("{}.strategy", path),
+        )?;
+        Ok(())
+    }
+
+    fn validate_uncertainty_register(&self, output: &Value) -> Result<(), SchemaValidationError> {
+        let obj = self.require_object(output, "root")?;
+
+        // Required fields
+        self.require_field(obj, "uncertainties", "root")?;
+
+        if let Some(arr) = obj.get("uncertainties").and_then(|v| v.as_array()) {
+            for (i, item) in arr.iter().enumerate() {
+                self.validate_registered_uncertainty(item, &format!("uncertainties[{}]", i))?;
+            }
+        }
+
+        Ok(())
+    }
+
+    fn validate_registered_uncertainty(
+        &self,
+        value: &Value,
+        path: &str,
+    ) -> Result<(), SchemaValidationError> {
+        let obj = self.require_object(value, path)?;
+        self.require_non_empty_string(obj, "id", path)?;
+        self.require_non_empty_string(obj, "description", path)?;
+        self.require_field(obj, "impact", path)?;
+        self.validate_enum(
+            &obj["impact"],
+            &["low", "medium", "high", "critical"],
+            &format!("{}.impact", path),
+        )?;
+        if let Some(p) = obj.get("probability").and_then(|v| v.as_f64()) {
+            if !(0.0..=1.0).contains(&p) {
+                return Err(SchemaValidationError::OutOfRange {
+                    field: format!("{}.probability", path),
+                    value: p.to_string(),
+                    min: "0".to_string(),
+                    max: "1".to_string(),
+                });
+            }
+        }
+        if let Some(arr) = obj.get("mitigations").and_then(|v| v.as_array()) {
+            for (i, item) in arr.iter().enumerate() {
+                let item_path = format!("{}.mitigations[{}]", path, i);
+                let mitigation = self.require_object(item, &item_path)?;
+                self.require_non_empty_string(mitigation, "description", &item_path)?;
+                self.require_field(mitigation, "kind", &item_path)?;
+                self.validate_enum(
+                    &mitigation["kind"],
+                    &["resolve", "reduce", "hedge", "accept"],
+                    &format!("{}.kind", item_path),
+                )?;
+            }
+        }
+        Ok(())
+    }
+
     fn validate_objectives(&self, output: &Value) -> Result<(), SchemaValidationError> {
         let obj = self.require_object(output, "root")?;
 
@@ -275,6 +403,45 @@ impl JsonSchemaValidator {
                 }
             }
         }
+        if let Some(function) = obj
+            .get("performance_measure")
+            .and_then(|m| m.get("value_function"))
+        {
+            self.validate_value_function(function, &format!("{}.performance_measure.value_function", path))?;
+        }
+        Ok(())
+    }
+
+    fn validate_value_function(
+        &self,
+        value: &Value,
+        path: &str,
+    ) -> Result<(), SchemaValidationError> {
+        let obj = self.require_object(value, path)?;
+        self.require_field(obj, "kind", path)?;
+        self.validate_enum(
+            &obj["kind"],
+            &["linear", "piecewise_linear", "exponential"],
+            &format!("{}.kind", path),
+        )?;
+        if let Some(points) = obj.get("points").and_then(|v| v.as_array()) {
+            for (i, point) in points.iter().enumerate() {
+                let point_path = format!("{}.points[{}]", path, i);
+                let point = self.require_object(point, &point_path)?;
+                self.require_field(point, "level", &point_path)?;
+                self.require_field(point, "value", &point_path)?;
+                if let Some(v) = point["value"].as_f64() {
+                    if !(0.0..=1.0).contains(&v) {
+                        return Err(SchemaValidationError::OutOfRange {
+                            field: format!("{}.value", point_path),
+                            value: v.to_string(),
+                            min: "0".to_string(),
+                            max: "1".to_string(),
+                        });
+                    }
+                }
+            }
+        }
         Ok(())
     }
 
@@ -418,7 +585,43 @@ impl JsonSchemaValidator {
 
     fn validate_tradeoffs(&self, output: &Value) -> Result<(), SchemaValidationError> {
         // All fields are optional in tradeoffs
-        let _obj = self.require_object(output, "root")?;
+        let obj = self.require_object(output, "root")?;
+        if let Some(method) = obj.get("scoring_method") {
+            self.validate_enum(method, &["pugh", "weighted_additive"], "scoring_method")?;
+        }
+        if let Some(arr) = obj.get("participant_preferences").and_then(|v| v.as_array()) {
+            for (i, item) in arr.iter().enumerate() {
+                self.validate_participant_preferences(item, &format!("participant_preferences[{}]", i))?;
+            }
+        }
+        Ok(())
+    }
+
+    fn validate_participant_preferences(
+        &self,
+        value: &Value,
+        path: &str,
+    ) -> Result<(), SchemaValidationError> {
+        let obj = self.require_object(value, path)?;
+        self.require_non_empty_string(obj, "participant_id", path)?;
+        self.require_string_field(obj, "name", path)?;
+        for (field, max) in [("alternative_scores", 100.0), ("objective_weights", 1.0)] {
+            let Some(entries) = obj.get(field).and_then(|v| v.as_object()) else {
+                continue;
+            };
+            for (id, entry) in entries {
+                if let Some(n) = entry.as_f64() {
+                    if !(0.0..=max).contains(&n) {
+                        return Err(SchemaValidationError::OutOfRange {
+                            field: format!("{}.{}.{}", path, field, id),
+                            value: n.to_string(),
+                            min: "0".to_string(),
+                            max: max.to_string(),
+                        });
+                    }
+                }
+            }
+        }
         Ok(())
     }
 
@@ -854,6 +1057,30 @@ mod tests {
         assert!(v.validate(ComponentType::Objectives, &output).is_ok());
     }
 
+    #[test]
+    fn objectives_rejects_value_outside_unit_range() {
+        let v = validator();
+        let output = json!({
+            "fundamental_objectives": [{
+                "id": "550e8400-e29b-41d4-a716-446655440001",
+                "description": "Maximize salary",
+                "performance_measure": {
+                    "direction": "maximize",
+                    "value_function": {
+                        "kind": "piecewise_linear",
+                        "points": [
+                            { "level": 50000, "value": 0 },
+                            { "level": 150000, "value": 1.5 }
+                        ]
+                    }
+                }
+            }],
+            "means_objectives": []
+        });
+
+        assert!(v.validate(ComponentType::Objectives, &output).is_err());
+    }
+
     #[test]
     fn objectives_requires_at_least_one_fundamental() {
         let v = validator();
@@ -1165,6 +1392,91 @@ mod tests {
         assert!(v.validate(ComponentType::ProblemFrame, &output).is_ok());
     }
 
+    // =============================================================
+    // Stakeholder Analysis Tests
+    // =============================================================
+
+    #[test]
+    fn stakeholder_analysis_valid_complete() {
+        let v = validator();
+        let output = json!({
+            "stakeholders": [{
+                "id": "spouse",
+                "name": "Sam",
+                "role": "partner",
+                "power": "high",
+                "interest": "high",
+                "concerns": ["Longer commute"]
+            }],
+            "engagement_plan": [{
+                "stakeholder_id": "spouse",
+                "strategy": "manage_closely",
+                "actions": ["Decide together"],
+                "timing": "Before signing"
+            }]
+        });
+
+        assert!(v.validate(ComponentType::StakeholderAnalysis, &output).is_ok());
+    }
+
+    #[test]
+    fn stakeholder_analysis_rejects_unknown_power_level() {
+        let v = validator();
+        let output = json!({
+            "stakeholders": [{
+                "id": "spouse",
+                "name": "Sam",
+                "role": "partner",
+                "power": "total",
+                "interest": "high"
+            }]
+        });
+
+        assert!(v.validate(ComponentType::StakeholderAnalysis, &output).is_err());
+    }
+
+    // =============================================================
+    // Uncertainty Register Tests
+    // =============================================================
+
+    #[test]
+    fn uncertainty_register_valid_complete() {
+        let v = validator();
+        let output = json!({
+            "uncertainties": [{
+                "id": "rates",
+                "description": "Mortgage rates rise before the fix ends",
+                "consequence_uncertainty_id": "u1",
+                "affected_alternative_ids": ["buy"],
+                "probability": 0.4,
+                "probability_basis": "Bank forecasts",
+                "impact": "high",
+                "mitigations": [{
+                    "description": "Fix for five years",
+                    "kind": "hedge",
+                    "cost": "Higher arrangement fee"
+                }]
+            }]
+        });
+
+        assert!(v.validate(ComponentType::UncertaintyRegister, &output).is_ok());
+    }
+
+    #[test]
+    fn uncertainty_register_rejects_probability_above_one() {
+        let v = validator();
+        let output = json!({
+            "uncertainties": [{
+                "id": "rates",
+                "description": "Mortgage rates rise",
+                "probability": 1.5,
+                "impact": "high"
+            }]
+        });
+
+        assert!(v.validate(ComponentType::UncertaintyRegister, &output).is_err());
+    }
+
     // =============================================================
     // Tradeoffs Tests
     // =============================================================
@@ -1193,6 +1505,37 @@ mod tests {
         assert!(v.validate(ComponentType::Tradeoffs, &output).is_ok());
     }
 
+    #[test]
+    fn tradeoffs_rejects_unknown_scoring_method() {
+        let v = validator();
+        let output = json!({ "scoring_method": "borda" });
+
+        assert!(v.validate(ComponentType::Tradeoffs, &output).is_err());
+    }
+
+    #[test]
+    fn tradeoffs_validates_participant_preferences() {
+        let v = validator();
+        let valid = json!({
+            "participant_preferences": [{
+                "participant_id": "p1",
+                "name": "Ana",
+                "alternative_scores": { "a1": 80 },
+                "objective_weights": { "o1": 0.6 }
+            }]
+        });
+        let out_of_range = json!({
+            "participant_preferences": [{
+                "participant_id": "p1",
+                "name": "Ana",
+                "alternative_scores": { "a1": 120 }
+            }]
+        });
+
+        assert!(v.validate(ComponentType::Tradeoffs, &valid).is_ok());
+        assert!(v.validate(ComponentType::Tradeoffs, &out_of_range).is_err());
+    }
+
     #[test]
     fn tradeoffs_valid_empty() {
         let v = validator();
@@ -0,0 +1,413 @@
+//! Operator announcements pushed to connected dashboard clients.
+//!
+//! Admins publish an announcement for everyone or for specific membership
+//! tiers, optionally starting later and expiring. The [`AnnouncementBoard`]
+//! holds published announcements:
+//!
+//! - Live ones are broadcast through the [`RoomManager`] as soon as they
+//!   start, and replayed to clients that connect while they are live.
+//! - Scheduled ones are broadcast by a dispatcher task once due.
+//! - Expired ones are dropped.
+//!
+//! Announcements live in this server's memory. In a multi-server
+//! deployment, publish through each server (or the one its clients use).
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use tokio::sync::RwLock;
+use tokio::task::JoinHandle;
+use uuid::Uuid;
+
+use crate::domain::foundation::{Timestamp, UserId};
+use crate::domain::membership::MembershipTier;
+
+use super::messages::{AnnouncementKind, AnnouncementMessage, ServerMessage};
+use super::rooms::RoomManager;
+
+/// How often the dispatcher checks for scheduled announcements.
+pub const DEFAULT_DISPATCH_INTERVAL: Duration = Duration::from_secs(15);
+
+/// Maximum title length in characters.
+pub const MAX_TITLE_LENGTH: usize = 120;
+
+/// Maximum body length in characters.
+pub const MAX_BODY_LENGTH: usize = 2000;
+
+/// Who an announcement is for.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AnnouncementAudience {
+    /// Every connected client, signed in or not.
+    All,
+    /// Signed-in users on one of these tiers.
+    Tiers(Vec<MembershipTier>),
+}
+
+impl AnnouncementAudience {
+    /// Whether a client on `tier` (`None` if unknown) should see it.
+    pub fn includes(&self, tier: Option<MembershipTier>) -> bool {
+        match self {
+            Self::All => true,
+            Self::Tiers(tiers) => tier.is_some_and(|tier| tiers.contains(&tier)),
+        }
+    }
+}
+
+/// Where an announcement is in its lifetime.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AnnouncementStatus {
+    Scheduled,
+    Live,
+    Expired,
+}
+
+/// A notice from the operators.
+#[derive(Debug, Clone)]
+pub struct Announcement {
+    pub id: Uuid,
+    pub kind: AnnouncementKind,
+    pub title: String,
+    pub body: String,
+    pub audience: AnnouncementAudience,
+    pub starts_at: Timestamp,
+    pub expires_at: Option<Timestamp>,
+    pub created_by: UserId,
+    pub created_at: Timestamp,
+}
+
+impl Announcement {
+    /// An announcement for everyone, starting now and never expiring.
+    pub fn new(
+        kind: AnnouncementKind,
+        title: impl Into<String>,
+        body: impl Into<String>,
+        created_by: UserId,
+    ) -> Self {
+        let now = Timestamp::now();
+        Self {
+            id: Uuid::new_v4(),
+            kind,
+            title: title.into(),
+            body: body.into(),
+            audience: AnnouncementAudience::All,
+            starts_at: now,
+            expires_at: None,
+            created_by,
+            created_at: now,
+        }
+    }
+
+    /// Restrict to the given tiers.
+    pub fn for_tiers(mut self, tiers: Vec<MembershipTier>) -> Self {
+        self.audience = AnnouncementAudience::Tiers(tiers);
+        self
+    }
+
+    /// Deliver at `starts_at` instead of immediately.
+    pub fn starting_at(mut self, starts_at: Timestamp) -> Self {
+        self.starts_at = starts_at;
+        self
+    }
+
+    /// Stop showing the announcement at `expires_at`.
+    pub fn expiring_at(mut self, expires_at: Timestamp) -> Self {
+        self.expires_at = Some(expires_at);
+        self
+    }
+
+    pub fn status(&self, now: &Timestamp) -> AnnouncementStatus {
+        if self
+            .expires_at
+            .is_some_and(|expires| !now.is_before(&expires))
+        {
+            AnnouncementStatus::Expired
+        } else if now.is_before(&self.starts_at) {
+            AnnouncementStatus::Scheduled
+        } else {
+            AnnouncementStatus::Live
+        }
+    }
+
+    /// Check the announcement can be published at `now`.
+    pub fn validate(&self, now: &Timestamp) -> Result<(), AnnouncementError> {
+        let title = self.title.trim();
+        if title.is_empty() || title.chars().count() > MAX_TITLE_LENGTH {
+            return Err(AnnouncementError::InvalidTitle);
+        }
+        let body = self.body.trim();
+        if body.is_empty() || body.chars().count() > MAX_BODY_LENGTH {
+            return Err(AnnouncementError::InvalidBody);
+        }
+        if let AnnouncementAudience::Tiers(tiers) = &self.audience {
+            if tiers.is_empty() {
+                return Err(AnnouncementError::NoAudience);
+            }
+        }
+        if let Some(expires_at) = &self.expires_at {
+            if !self.starts_at.is_before(expires_at) {
+                return Err(AnnouncementError::ExpiresBeforeStart);
+            }
+            if !now.is_before(expires_at) {
+                return Err(AnnouncementError::AlreadyExpired);
+            }
+        }
+        Ok(())
+    }
+
+    /// Wire representation.
+    pub fn to_server_message(&self) -> ServerMessage {
+        ServerMessage::Announcement(AnnouncementMessage {
+            id: self.id.to_string(),
+            kind: self.kind,
+            title: self.title.clone(),
+            body: self.body.clone(),
+            starts_at: self.starts_at.as_datetime().to_rfc3339(),
+            expires_at: self.expires_at.map(|t| t.as_datetime().to_rfc3339()),
+        })
+    }
+}
+
+/// Errors publishing or cancelling announcements.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AnnouncementError {
+    InvalidTitle,
+    InvalidBody,
+    NoAudience,
+    ExpiresBeforeStart,
+    AlreadyExpired,
+    NotFound(Uuid),
+}
+
+impl std::fmt::Display for AnnouncementError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::InvalidTitle => write!(
+                f,
+                "Title must be between 1 and {} characters",
+                MAX_TITLE_LENGTH
+            ),
+            Self::InvalidBody => write!(
+                f,
+                "Body must be between 1 and {} characters",
+                MAX_BODY_LENGTH
+            ),
+            Self::NoAudience => write!(f, "At least one tier is required when targeting tiers"),
+            Self::ExpiresBeforeStart => write!(f, "Announcement must expire after it starts"),
+            Self::AlreadyExpired => write!(f, "Announcement has already expired"),
+            Self::NotFound(id) => write!(f, "Announcement not found: {}", id),
+        }
+    }
+}
+
+impl std::error::Error for AnnouncementError {}
+
+#[derive(Debug)]
+struct Entry {
+    announcement: Announcement,
+    /// Whether it has been broadcast to connected clients.
+    delivered: bool,
+}
+
+/// Published announcements and their delivery.
+#[derive(Clone)]
+pub struct AnnouncementBoard {
+    rooms: Arc<RoomManager>,
+    entries: Arc<RwLock<Vec<Entry>>>,
+}
+
+impl AnnouncementBoard {
+    pub fn new(rooms: Arc<RoomManager>) -> Self {
+        Self {
+            rooms,
+            entries: Arc::new(RwLock::new(Vec::new())),
+        }
+    }
+
+    /// Publish an announcement, broadcasting it now if it has started.
+    pub async fn publish(&self, announcement: Announcement) -> Result<(), AnnouncementError> {
+        let now = Timestamp::now();
+        announcement.validate(&now)?;
+
+        let delivered = announcement.status(&now) == AnnouncementStatus::Live;
+        if delivered {
+            self.rooms.announce(announcement.clone());
+        }
+        tracing::info!(
+            announcement_id = %announcement.id,
+            delivered,
+            "Announcement published"
+        );
+
+        let mut entries = self.entries.write().await;
+        entries.retain(|e| e.announcement.status(&now) != AnnouncementStatus::Expired);
+        entries.push(Entry {
+            announcement,
+            delivered,
+        });
+        Ok(())
+    }
+
+    /// Withdraw an announcement. It is no longer delivered or replayed;
+    /// clients already showing it keep it until it expires or they reload.
+    pub async fn cancel(&self, id: Uuid) -> Result<(), AnnouncementError> {
+        let mut entries = self.entries.write().await;
+        let index = entries
+            .iter()
+            .position(|e| e.announcement.id == id)
+            .ok_or(AnnouncementError::NotFound(id))?;
+        entries.remove(index);
+        Ok(())
+    }
+
+    /// All announcements that have not expired, in publication order.
+    pub async fn list(&self) -> Vec<Announcement> {
+        let now = Timestamp::now();
+        self.entries
+            .read()
+            .await
+            .iter()
+            .map(|e| e.announcement.clone())
+            .filter(|a| a.status(&now) != AnnouncementStatus::Expired)
+            .collect()
+    }
+
+    /// Live announcements a newly connected client on `tier` should see.
+    pub async fn live_for(&self, tier: Option<MembershipTier>) -> Vec<Announcement> {
+        let now = Timestamp::now();
+        self.entries
+            .read()
+            .await
+            .iter()
+            .map(|e| &e.announcement)
+            .filter(|a| a.status(&now) == AnnouncementStatus::Live && a.audience.includes(tier))
+            .cloned()
+            .collect()
+    }
+
+    /// Broadcast scheduled announcements that have started and drop
+    /// expired ones. Returns how many were broadcast.
+    pub async fn dispatch_due(&self, now: &Timestamp) -> usize {
+        let mut entries = self.entries.write().await;
+        entries.retain(|e| e.announcement.status(now) != AnnouncementStatus::Expired);
+
+        let mut dispatched = 0;
+        for entry in entries.iter_mut().filter(|e| !e.delivered) {
+            if entry.announcement.status(now) == AnnouncementStatus::Live {
+                self.rooms.announce(entry.announcement.clone());
+                entry.delivered = true;
+                dispatched += 1;
+            }
+        }
+        dispatched
+    }
+
+    /// Run [`Self::dispatch_due`] every `interval` until aborted.
+    pub fn spawn_dispatcher(&self, interval: Duration) -> JoinHandle<()> {
+        let board = self.clone();
+        tokio::spawn(async move {
+            let mut ticker = tokio::time::interval(interval);
+            loop {
+                ticker.tick().await;
+                let dispatched = board.dispatch_due(&Timestamp::now()).await;
+                if dispatched > 0 {
+                    tracing::info!(dispatched, "Scheduled announcements delivered");
+                }
+            }
+        })
+    }
+}
+
+impl std::fmt::Debug for AnnouncementBoard {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("AnnouncementBoard").finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn admin() -> UserId {
+        UserId::new("admin-1").unwrap()
+    }
+
+    fn maintenance() -> Announcement {
+        Announcement::new(
+            AnnouncementKind::Maintenance,
+            "Scheduled maintenance",
+            "We'll be down for 10 minutes at 02:00 UTC.",
+            admin(),
+        )
+    }
+
+    #[tokio::test]
+    async fn live_announcements_are_broadcast_immediately() {
+        let rooms = Arc::new(RoomManager::with_default_capacity());
+        let mut rx = rooms.subscribe_announcements();
+        let board = AnnouncementBoard::new(rooms);
+
+        board.publish(maintenance()).await.unwrap();
+
+        let received = rx.recv().await.unwrap();
+        assert_eq!(received.title, "Scheduled maintenance");
+        assert_eq!(board.live_for(None).await.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn scheduled_announcements_wait_until_due() {
+        let rooms = Arc::new(RoomManager::with_default_capacity());
+        let mut rx = rooms.subscribe_announcements();
+        let board = AnnouncementBoard::new(rooms);
+        let starts_at = Timestamp::now().plus_secs(3600);
+        board
+            .publish(maintenance().starting_at(starts_at))
+            .await
+            .unwrap();
+
+        assert_eq!(board.dispatch_due(&Timestamp::now()).await, 0);
+        assert!(rx.try_recv().is_err());
+        assert!(board.live_for(None).await.is_empty());
+
+        assert_eq!(board.dispatch_due(&starts_at).await, 1);
+        assert!(rx.try_recv().is_ok());
+        assert_eq!(board.dispatch_due(&starts_at.plus_secs(1)).await, 0);
+    }
+
+    #[tokio::test]
+    async fn tier_targeted_announcements_skip_other_tiers() {
+        let board = AnnouncementBoard::new(Arc::new(RoomManager::with_default_capacity()));
+        board
+            .publish(maintenance().for_tiers(vec![MembershipTier::Annual]))
+            .await
+            .unwrap();
+
+        assert_eq!(board.live_for(Some(MembershipTier::Annual)).await.len(), 1);
+        assert!(board.live_for(Some(MembershipTier::Free)).await.is_empty());
+        assert!(board.live_for(None).await.is_empty());
+    }
+
+    #[test]
+    fn validate_rejects_bad_expiry_and_empty_audience() {
+        let now = Timestamp::now();
+        assert_eq!(
+            maintenance().expiring_at(now.minus_days(1)).validate(&now),
+            Err(AnnouncementError::ExpiresBeforeStart)
+        );
+        assert_eq!(
+            maintenance().for_tiers(vec![]).validate(&now),
+            Err(AnnouncementError::NoAudience)
+        );
+        assert!(maintenance()
+            .expiring_at(now.plus_days(1))
+            .validate(&now)
+            .is_ok());
+    }
+
+    #[test]
+    fn serializes_as_announcement_message() {
+        let json = serde_json::to_string(&maintenance().to_server_message()).unwrap();
+        assert!(json.contains(r#""type":"announcement""#));
+        assert!(json.contains(r#""kind":"maintenance""#));
+        assert!(!json.contains("expiresAt"));
+    }
+}
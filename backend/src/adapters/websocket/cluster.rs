@@ -0,0 +1,376 @@
+//! Cross-server routing for WebSocket session rooms.
+//!
+//! Each server records the session rooms it holds in the
+//! `ConnectionRegistry`. When an event occurs, the server broadcasts it to
+//! its own room and forwards it through the `ServerMessenger` to every other
+//! server holding that room.
+//!
+//! ```text
+//! Server A (event occurs)          Redis                 Server B
+//! ───────────────────────          ─────                 ────────
+//! broadcast to local room
+//! find_session_servers(s) ───────► {A, B}
+//! publish_to_server(B, s, event) ─► ws:events:B ───────► deliver_local(s, event)
+//! ```
+//!
+//! A heartbeat task keeps this server's entries fresh and cleans up after
+//! servers that stop heartbeating (crashed pods never unregister).
+
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+
+use tokio::sync::Mutex;
+use tokio::task::JoinHandle;
+
+use crate::domain::foundation::{EventEnvelope, SessionId, UserId};
+use crate::ports::{ConnectionRegistry, ServerId, ServerMessenger};
+
+/// Default interval between heartbeats.
+pub const DEFAULT_HEARTBEAT_INTERVAL: Duration = Duration::from_secs(20);
+
+/// Default silence after which another server is considered gone.
+pub const DEFAULT_STALE_AFTER: Duration = Duration::from_secs(60);
+
+/// Local connection counts, so a room or user is unregistered only when
+/// this server's last connection for it closes.
+#[derive(Debug, Default)]
+struct LocalConnections {
+    sessions: HashMap<SessionId, usize>,
+    users: HashMap<UserId, usize>,
+}
+
+/// This server's membership in a multi-server WebSocket deployment.
+#[derive(Clone)]
+pub struct WebSocketCluster {
+    server_id: ServerId,
+    registry: Arc<dyn ConnectionRegistry>,
+    messenger: Arc<dyn ServerMessenger>,
+    local: Arc<Mutex<LocalConnections>>,
+}
+
+impl WebSocketCluster {
+    pub fn new(
+        server_id: ServerId,
+        registry: Arc<dyn ConnectionRegistry>,
+        messenger: Arc<dyn ServerMessenger>,
+    ) -> Self {
+        Self {
+            server_id,
+            registry,
+            messenger,
+            local: Arc::new(Mutex::new(LocalConnections::default())),
+        }
+    }
+
+    /// This server's ID.
+    pub fn server_id(&self) -> &ServerId {
+        &self.server_id
+    }
+
+    /// Record a new local connection to a session room.
+    ///
+    /// Registry failures are logged; the connection still works locally.
+    pub async fn connected(&self, session_id: &SessionId, user_id: Option<&UserId>) {
+        let (first_in_room, first_for_user) = {
+            let mut local = self.local.lock().await;
+            let sessions = local.sessions.entry(*session_id).or_default();
+            *sessions += 1;
+            let first_in_room = *sessions == 1;
+            let first_for_user = user_id.map(|user_id| {
+                let users = local.users.entry(user_id.clone()).or_default();
+                *users += 1;
+                *users == 1
+            });
+            (first_in_room, first_for_user.unwrap_or(false))
+        };
+
+        if first_in_room {
+            if let Err(e) = self
+                .registry
+                .join_session(session_id, &self.server_id)
+                .await
+            {
+                tracing::warn!(session_id = %session_id, "Failed to register session room: {}", e);
+            }
+        }
+        if let (true, Some(user_id)) = (first_for_user, user_id) {
+            if let Err(e) = self.registry.register(user_id, &self.server_id).await {
+                tracing::warn!(user_id = %user_id, "Failed to register connection: {}", e);
+            }
+        }
+    }
+
+    /// Record that a local connection to a session room closed.
+    pub async fn disconnected(&self, session_id: &SessionId, user_id: Option<&UserId>) {
+        let (last_in_room, last_for_user) = {
+            let mut local = self.local.lock().await;
+            let last_in_room = release(&mut local.sessions, session_id);
+            let last_for_user = user_id.is_some_and(|id| release(&mut local.users, id));
+            (last_in_room, last_for_user)
+        };
+
+        if last_in_room {
+            if let Err(e) = self
+                .registry
+                .leave_session(session_id, &self.server_id)
+                .await
+            {
+                tracing::warn!(session_id = %session_id, "Failed to unregister session room: {}", e);
+            }
+        }
+        if let (true, Some(user_id)) = (last_for_user, user_id) {
+            if let Err(e) = self.registry.unregister(user_id, &self.server_id).await {
+                tracing::warn!(user_id = %user_id, "Failed to unregister connection: {}", e);
+            }
+        }
+    }
+
+    /// Forward an event to every other server holding the session room.
+    ///
+    /// Failures are logged per server; local delivery is the caller's job.
+    pub async fn forward(&self, session_id: &SessionId, event: &EventEnvelope) {
+        let servers = match self.registry.find_session_servers(session_id).await {
+            Ok(servers) => servers,
+            Err(e) => {
+                tracing::warn!(session_id = %session_id, "Failed to look up session servers: {}", e);
+                return;
+            }
+        };
+
+        for server_id in servers.iter().filter(|s| **s != self.server_id) {
+            if let Err(e) = self
+                .messenger
+                .publish_to_server(server_id, session_id, event)
+                .await
+            {
+                tracing::warn!(
+                    server_id = %server_id,
+                    session_id = %session_id,
+                    "Failed to forward event to server: {}",
+                    e
+                );
+            }
+        }
+    }
+
+    /// One heartbeat: mark this server alive, refresh its registry entries,
+    /// and clean up after servers silent for longer than `stale_after`.
+    pub async fn heartbeat(&self, stale_after: Duration) {
+        if let Err(e) = self.registry.heartbeat_server(&self.server_id).await {
+            tracing::warn!(server_id = %self.server_id, "Server heartbeat failed: {}", e);
+            return;
+        }
+
+        let (sessions, users): (Vec<SessionId>, Vec<UserId>) = {
+            let local = self.local.lock().await;
+            (
+                local.sessions.keys().copied().collect(),
+                local.users.keys().cloned().collect(),
+            )
+        };
+        for session_id in &sessions {
+            if let Err(e) = self
+                .registry
+                .join_session(session_id, &self.server_id)
+                .await
+            {
+                tracing::warn!(session_id = %session_id, "Failed to refresh session room: {}", e);
+            }
+        }
+        for user_id in &users {
+            if let Err(e) = self.registry.heartbeat(user_id, &self.server_id).await {
+                tracing::warn!(user_id = %user_id, "Failed to refresh connection: {}", e);
+            }
+        }
+
+        match self.registry.find_stale_servers(stale_after).await {
+            Ok(stale) => {
+                for server_id in stale.iter().filter(|s| **s != self.server_id) {
+                    match self.registry.cleanup_server(server_id).await {
+                        Ok(removed) => tracing::info!(
+                            server_id = %server_id,
+                            connections = removed,
+                            "Cleaned up stale WebSocket server"
+                        ),
+                        Err(e) => tracing::warn!(
+                            server_id = %server_id,
+                            "Failed to clean up stale server: {}",
+                            e
+                        ),
+                    }
+                }
+            }
+            Err(e) => tracing::warn!("Failed to look up stale servers: {}", e),
+        }
+    }
+
+    /// Run [`Self::heartbeat`] every `interval` until aborted.
+    pub fn spawn_heartbeat(&self, interval: Duration, stale_after: Duration) -> JoinHandle<()> {
+        let cluster = self.clone();
+        tokio::spawn(async move {
+            let mut ticker = tokio::time::interval(interval);
+            loop {
+                ticker.tick().await;
+                cluster.heartbeat(stale_after).await;
+            }
+        })
+    }
+
+    /// Remove this server's entries on graceful shutdown.
+    pub async fn shutdown(&self) {
+        if let Err(e) = self.registry.cleanup_server(&self.server_id).await {
+            tracing::warn!(server_id = %self.server_id, "Failed to clean up on shutdown: {}", e);
+        }
+    }
+}
+
+impl std::fmt::Debug for WebSocketCluster {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("WebSocketCluster")
+            .field("server_id", &self.server_id)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Decrement a count, returning true when it reaches zero.
+fn release<K: std::hash::Hash + Eq>(counts: &mut HashMap<K, usize>, key: &K) -> bool {
+    match counts.get_mut(key) {
+        Some(count) if *count > 1 => {
+            *count -= 1;
+            false
+        }
+        Some(_) => {
+            counts.remove(key);
+            true
+        }
+        None => false,
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod fixtures {
+    use super::*;
+    use crate::ports::ConnectionRegistryError;
+    use async_trait::async_trait;
+
+    /// Records every event forwarded to another server.
+    #[derive(Default)]
+    pub struct RecordingMessenger {
+        pub sent: Mutex<Vec<(ServerId, SessionId, String)>>,
+    }
+
+    #[async_trait]
+    impl ServerMessenger for RecordingMessenger {
+        async fn publish_to_server(
+            &self,
+            server_id: &ServerId,
+            session_id: &SessionId,
+            event: &EventEnvelope,
+        ) -> Result<(), ConnectionRegistryError> {
+            self.sent
+                .lock()
+                .await
+                .push((server_id.clone(), *session_id, event.event_type.clone()));
+            Ok(())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::fixtures::RecordingMessenger;
+    use super::*;
+    use crate::adapters::websocket::InMemoryConnectionRegistry;
+    use crate::domain::foundation::{EventId, EventMetadata, Timestamp};
+
+    fn event() -> EventEnvelope {
+        EventEnvelope {
+            event_id: EventId::new(),
+            event_type: "cycle.created".to_string(),
+            schema_version: 1,
+            aggregate_id: "cycle-1".to_string(),
+            aggregate_type: "Cycle".to_string(),
+            occurred_at: Timestamp::now(),
+            payload: serde_json::json!({}),
+            metadata: EventMetadata::default(),
+        }
+    }
+
+    fn cluster(
+        name: &str,
+        registry: &Arc<InMemoryConnectionRegistry>,
+        messenger: &Arc<RecordingMessenger>,
+    ) -> WebSocketCluster {
+        WebSocketCluster::new(ServerId::new(name), registry.clone(), messenger.clone())
+    }
+
+    #[tokio::test]
+    async fn forwards_only_to_other_servers_holding_the_room() {
+        let registry = Arc::new(InMemoryConnectionRegistry::new());
+        let messenger = Arc::new(RecordingMessenger::default());
+        let (a, b, c) = (
+            cluster("a", &registry, &messenger),
+            cluster("b", &registry, &messenger),
+            cluster("c", &registry, &messenger),
+        );
+        let session = SessionId::new();
+        a.connected(&session, None).await;
+        b.connected(&session, None).await;
+        c.connected(&SessionId::new(), None).await;
+
+        a.forward(&session, &event()).await;
+
+        let sent = messenger.sent.lock().await;
+        assert_eq!(sent.len(), 1);
+        assert_eq!(sent[0].0, ServerId::new("b"));
+        assert_eq!(sent[0].1, session);
+    }
+
+    #[tokio::test]
+    async fn leaves_the_room_when_the_last_local_client_disconnects() {
+        let registry = Arc::new(InMemoryConnectionRegistry::new());
+        let messenger = Arc::new(RecordingMessenger::default());
+        let a = cluster("a", &registry, &messenger);
+        let session = SessionId::new();
+        let user = UserId::new("user-1").unwrap();
+
+        a.connected(&session, Some(&user)).await;
+        a.connected(&session, Some(&user)).await;
+        a.disconnected(&session, Some(&user)).await;
+        assert_eq!(
+            registry.find_session_servers(&session).await.unwrap().len(),
+            1
+        );
+        assert!(registry.is_connected(&user).await.unwrap());
+
+        a.disconnected(&session, Some(&user)).await;
+        assert!(registry
+            .find_session_servers(&session)
+            .await
+            .unwrap()
+            .is_empty());
+        assert!(!registry.is_connected(&user).await.unwrap());
+    }
+
+    #[tokio::test]
+    async fn heartbeat_cleans_up_stale_servers_but_not_itself() {
+        let registry = Arc::new(InMemoryConnectionRegistry::new());
+        let messenger = Arc::new(RecordingMessenger::default());
+        let (a, crashed) = (
+            cluster("a", &registry, &messenger),
+            cluster("crashed", &registry, &messenger),
+        );
+        let session = SessionId::new();
+        crashed.connected(&session, None).await;
+        crashed.heartbeat(Duration::from_secs(60)).await;
+        a.connected(&session, None).await;
+
+        a.heartbeat(Duration::ZERO).await;
+
+        assert_eq!(
+            registry.find_session_servers(&session).await.unwrap(),
+            vec![ServerId::new("a")]
+        );
+    }
+}
@@ -0,0 +1,176 @@
+//! Wire encodings for WebSocket messages.
+//!
+//! Clients choose an encoding through the `Sec-WebSocket-Protocol` header
+//! on the upgrade request:
+//!
+//! | Subprotocol                  | Server frames           |
+//! |------------------------------|-------------------------|
+//! | `choice-sherpa.v1.msgpack`   | Binary, MessagePack     |
+//! | `choice-sherpa.v1.json`      | Text, JSON              |
+//! | (none)                       | Text, JSON              |
+//!
+//! MessagePack maps use the same field names and `type` tags as JSON, so
+//! both encodings carry identical message shapes. Whatever the encoding,
+//! the server accepts JSON text frames and MessagePack binary frames from
+//! the client.
+
+use axum::extract::ws::Message;
+use axum::http::HeaderValue;
+use serde::{de::DeserializeOwned, Serialize};
+
+/// Subprotocol for JSON text frames.
+pub const JSON_PROTOCOL: &str = "choice-sherpa.v1.json";
+
+/// Subprotocol for MessagePack binary frames.
+pub const MSGPACK_PROTOCOL: &str = "choice-sherpa.v1.msgpack";
+
+/// Subprotocols the server accepts, most preferred first.
+pub const SUPPORTED_PROTOCOLS: [&str; 2] = [MSGPACK_PROTOCOL, JSON_PROTOCOL];
+
+/// How messages are encoded on a connection.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum WireCodec {
+    #[default]
+    Json,
+    MessagePack,
+}
+
+impl WireCodec {
+    /// The codec for the subprotocol agreed during the upgrade.
+    pub fn from_protocol(protocol: Option<&HeaderValue>) -> Self {
+        match protocol.and_then(|p| p.to_str().ok()) {
+            Some(MSGPACK_PROTOCOL) => Self::MessagePack,
+            _ => Self::Json,
+        }
+    }
+
+    /// Encode a message as a frame.
+    pub fn encode<T: Serialize>(&self, msg: &T) -> Message {
+        match self {
+            Self::Json => Message::Text(
+                serde_json::to_string(msg)
+                    .expect("WebSocket message serialization should not fail"),
+            ),
+            // Named fields, so tags and keys match the JSON encoding
+            Self::MessagePack => Message::Binary(
+                rmp_serde::to_vec_named(msg)
+                    .expect("WebSocket message serialization should not fail"),
+            ),
+        }
+    }
+
+    /// Decode a client frame. Returns `None` for control frames.
+    pub fn decode<T: DeserializeOwned>(frame: &Message) -> Option<Result<T, CodecError>> {
+        match frame {
+            Message::Text(text) => {
+                Some(serde_json::from_str(text).map_err(|e| CodecError(e.to_string())))
+            }
+            Message::Binary(bytes) => {
+                Some(rmp_serde::from_slice(bytes).map_err(|e| CodecError(e.to_string())))
+            }
+            _ => None,
+        }
+    }
+}
+
+/// A client frame could not be decoded.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CodecError(pub String);
+
+impl std::fmt::Display for CodecError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Invalid message: {}", self.0)
+    }
+}
+
+impl std::error::Error for CodecError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::adapters::http::conversation::streaming::{
+        StreamChunkMessage, StreamClientMessage, StreamCompleteMessage, StreamServerMessage,
+        StreamTokenUsage,
+    };
+    use crate::adapters::websocket::messages::{
+        ClientMessage, DashboardUpdateMessage, DashboardUpdateType, ServerMessage,
+    };
+
+    /// The MessagePack frame decodes to the same document as the JSON frame.
+    fn assert_parity<T: Serialize>(msg: &T) {
+        let Message::Text(json) = WireCodec::Json.encode(msg) else {
+            panic!("expected a text frame");
+        };
+        let Message::Binary(packed) = WireCodec::MessagePack.encode(msg) else {
+            panic!("expected a binary frame");
+        };
+        let from_json: serde_json::Value = serde_json::from_str(&json).unwrap();
+        let from_msgpack: serde_json::Value = rmp_serde::from_slice(&packed).unwrap();
+        assert_eq!(from_json, from_msgpack);
+    }
+
+    #[test]
+    fn negotiates_msgpack_only_when_agreed() {
+        let msgpack = HeaderValue::from_static(MSGPACK_PROTOCOL);
+        let json = HeaderValue::from_static(JSON_PROTOCOL);
+        assert_eq!(
+            WireCodec::from_protocol(Some(&msgpack)),
+            WireCodec::MessagePack
+        );
+        assert_eq!(WireCodec::from_protocol(Some(&json)), WireCodec::Json);
+        assert_eq!(WireCodec::from_protocol(None), WireCodec::Json);
+    }
+
+    #[test]
+    fn stream_messages_have_parity() {
+        assert_parity(&StreamServerMessage::StreamChunk(StreamChunkMessage {
+            message_id: "m1".to_string(),
+            delta: "Let's weigh".to_string(),
+            is_final: false,
+        }));
+        assert_parity(&StreamServerMessage::StreamComplete(
+            StreamCompleteMessage {
+                message_id: "m1".to_string(),
+                full_content: "Let's weigh your options.".to_string(),
+                usage: StreamTokenUsage {
+                    prompt_tokens: 120,
+                    completion_tokens: 40,
+                    total_tokens: 160,
+                    estimated_cost_cents: 1,
+                },
+                phase_transition: None,
+                delivery_id: Some(7),
+            },
+        ));
+    }
+
+    #[test]
+    fn dashboard_messages_have_parity() {
+        assert_parity(&ServerMessage::DashboardUpdate(DashboardUpdateMessage {
+            update_type: DashboardUpdateType::CycleProgress,
+            data: serde_json::json!({"cycleId": "cycle-1", "percent": 40, "done": ["issue_raising"]}),
+            timestamp: "2026-01-10T00:00:00Z".to_string(),
+            correlation_id: None,
+        }));
+    }
+
+    #[test]
+    fn decodes_client_frames_in_either_encoding() {
+        let text = Message::Text(r#"{"type":"ack","delivery_id":3}"#.to_string());
+        let binary = Message::Binary(
+            rmp_serde::to_vec_named(&serde_json::json!({"type": "ack", "delivery_id": 3})).unwrap(),
+        );
+        for frame in [text, binary] {
+            let decoded = WireCodec::decode::<StreamClientMessage>(&frame).unwrap();
+            assert!(matches!(decoded, Ok(StreamClientMessage::Ack(req)) if req.delivery_id == 3));
+        }
+
+        let ping =
+            Message::Binary(rmp_serde::to_vec_named(&serde_json::json!({"type": "ping"})).unwrap());
+        assert!(matches!(
+            WireCodec::decode::<ClientMessage>(&ping),
+            Some(Ok(ClientMessage::Ping))
+        ));
+        assert!(WireCodec::decode::<ClientMessage>(&Message::Ping(vec![])).is_none());
+    }
+}
@@ -31,6 +31,12 @@
 //! │  Broadcast to all  │
 //! │  clients in room   │
 //! └────────────────────┘
+//!          │
+//!          ▼ (multi-server only)
+//! ┌────────────────────┐
+//! │  Forward to other  │
+//! │  servers in room   │
+//! └────────────────────┘
 //! ```
 
 use std::sync::Arc;
@@ -40,6 +46,7 @@ use async_trait::async_trait;
 use crate::domain::foundation::{DomainError, EventEnvelope, SessionId};
 use crate::ports::{EventHandler, EventSubscriber};
 
+use super::cluster::WebSocketCluster;
 use super::messages::{DashboardUpdate, DashboardUpdateType};
 use super::rooms::RoomManager;
 
@@ -49,6 +56,11 @@ use super::rooms::RoomManager;
 pub const DASHBOARD_EVENT_TYPES: &[&str] = &[
     "session.created",
     "session.renamed",
+    "session.archived.v1",
+    "session.deleted.v1",
+    "session.restored.v1",
+    "session.archive_warning_issued.v1",
+    "session.auto_archive_exclusion_changed.v1",
     "cycle.created",
     "cycle.branched",
     "component.started",
@@ -58,20 +70,33 @@ pub const DASHBOARD_EVENT_TYPES: &[&str] = &[
     "pugh_scores.computed",
     "dq_scores.computed",
     "cycle.completed",
+    "document.sections_changed.v1",
 ];
 
 /// Bridge between the event bus and WebSocket connections.
 ///
 /// Implements `EventHandler` to receive domain events and broadcast
-/// them to connected clients in the appropriate session rooms.
+/// them to connected clients in the appropriate session rooms. With a
+/// cluster configured, events are also forwarded to the other servers
+/// holding the room.
 pub struct WebSocketEventBridge {
     room_manager: Arc<RoomManager>,
+    cluster: Option<WebSocketCluster>,
 }
 
 impl WebSocketEventBridge {
     /// Create a new event bridge with the given room manager.
     pub fn new(room_manager: Arc<RoomManager>) -> Self {
-        Self { room_manager }
+        Self {
+            room_manager,
+            cluster: None,
+        }
+    }
+
+    /// Forward events to other servers holding the same session rooms.
+    pub fn with_cluster(mut self, cluster: WebSocketCluster) -> Self {
+        self.cluster = Some(cluster);
+        self
     }
 
     /// Create as an Arc (for sharing with event subscriber).
@@ -93,12 +118,27 @@ impl WebSocketEventBridge {
         subscriber.subscribe_all(DASHBOARD_EVENT_TYPES, self.clone());
     }
 
+    /// Broadcast an event forwarded by another server to this server's
+    /// clients in the session room. Never forwards it again.
+    pub async fn deliver_local(&self, session_id: &SessionId, event: &EventEnvelope) {
+        if let Some(update) = self.transform(event) {
+            self.room_manager
+                .broadcast_to_session(session_id, update)
+                .await;
+        }
+    }
+
     /// Transform a domain event envelope into a dashboard update.
     ///
     /// Returns `None` if the event type is not relevant for dashboard updates.
     fn transform(&self, event: &EventEnvelope) -> Option<DashboardUpdate> {
         let update_type = match event.event_type.as_str() {
             "session.created" | "session.renamed" => DashboardUpdateType::SessionMetadata,
+            "session.archived.v1"
+            | "session.deleted.v1"
+            | "session.restored.v1"
+            | "session.archive_warning_issued.v1"
+            | "session.auto_archive_exclusion_changed.v1" => DashboardUpdateType::SessionStatus,
             "cycle.created" | "cycle.branched" => DashboardUpdateType::CycleCreated,
             "component.started" => DashboardUpdateType::ComponentStarted,
             "component.completed" => DashboardUpdateType::ComponentCompleted,
@@ -106,6 +146,7 @@ impl WebSocketEventBridge {
             "message.sent" => DashboardUpdateType::ConversationMessage,
             "pugh_scores.computed" | "dq_scores.computed" => DashboardUpdateType::AnalysisScores,
             "cycle.completed" => DashboardUpdateType::CycleCompleted,
+            "document.sections_changed.v1" => DashboardUpdateType::DocumentChanged,
             _ => return None,
         };
 
@@ -167,6 +208,11 @@ impl EventHandler for WebSocketEventBridge {
             .broadcast_to_session(&session_id, update)
             .await;
 
+        // Reach clients connected to other servers
+        if let Some(cluster) = &self.cluster {
+            cluster.forward(&session_id, &event).await;
+        }
+
         Ok(())
     }
 
@@ -230,6 +276,23 @@ mod tests {
         assert_eq!(update.update_type, DashboardUpdateType::SessionMetadata);
     }
 
+    #[test]
+    fn transform_archive_events_to_status_update() {
+        let room_manager = Arc::new(RoomManager::default());
+        let bridge = WebSocketEventBridge::new(room_manager);
+
+        for event_type in [
+            "session.archived.v1",
+            "session.archive_warning_issued.v1",
+            "session.auto_archive_exclusion_changed.v1",
+        ] {
+            let event = session_event(event_type, &test_session_id().to_string());
+            let update = bridge.transform(&event).unwrap();
+            assert_eq!(update.update_type, DashboardUpdateType::SessionStatus);
+            assert_eq!(bridge.resolve_session_id(&event), Some(test_session_id()));
+        }
+    }
+
     #[test]
     fn transform_cycle_created_to_cycle_update() {
         let room_manager = Arc::new(RoomManager::default());
@@ -247,6 +310,23 @@ mod tests {
         assert_eq!(update.update_type, DashboardUpdateType::CycleCreated);
     }
 
+    #[test]
+    fn transform_document_sections_changed_to_document_update() {
+        let room_manager = Arc::new(RoomManager::default());
+        let bridge = WebSocketEventBridge::new(room_manager);
+
+        let event = cycle_event(
+            "document.sections_changed.v1",
+            "cycle-123",
+            &test_session_id().to_string(),
+        );
+        let update = bridge.transform(&event);
+
+        assert!(update.is_some());
+        let update = update.unwrap();
+        assert_eq!(update.update_type, DashboardUpdateType::DocumentChanged);
+    }
+
     #[test]
     fn transform_component_completed_to_component_update() {
         let room_manager = Arc::new(RoomManager::default());
@@ -357,6 +437,44 @@ mod tests {
         assert_eq!(received.update_type, DashboardUpdateType::SessionMetadata);
     }
 
+    #[tokio::test]
+    async fn handle_forwards_to_other_servers_in_the_room() {
+        use crate::adapters::websocket::cluster::fixtures::RecordingMessenger;
+        use crate::adapters::websocket::InMemoryConnectionRegistry;
+        use crate::ports::ServerId;
+
+        let registry = Arc::new(InMemoryConnectionRegistry::new());
+        let messenger = Arc::new(RecordingMessenger::default());
+        let here = WebSocketCluster::new(ServerId::new("a"), registry.clone(), messenger.clone());
+        let there = WebSocketCluster::new(ServerId::new("b"), registry, messenger.clone());
+        let session_id = test_session_id();
+        there.connected(&session_id, None).await;
+        let bridge = WebSocketEventBridge::new(Arc::new(RoomManager::default())).with_cluster(here);
+
+        let event = session_event("session.renamed", &session_id.to_string());
+        bridge.handle(event).await.unwrap();
+
+        let sent = messenger.sent.lock().await;
+        assert_eq!(sent.len(), 1);
+        assert_eq!(sent[0].0, ServerId::new("b"));
+    }
+
+    #[tokio::test]
+    async fn deliver_local_broadcasts_forwarded_events() {
+        let room_manager = Arc::new(RoomManager::default());
+        let bridge = WebSocketEventBridge::new(room_manager.clone());
+        let session_id = test_session_id();
+        let mut rx = room_manager
+            .join(&session_id, super::super::ClientId::new())
+            .await;
+
+        let event = cycle_event("cycle.completed", "cycle-123", &session_id.to_string());
+        bridge.deliver_local(&session_id, &event).await;
+
+        let received = rx.recv().await.unwrap();
+        assert_eq!(received.update_type, DashboardUpdateType::CycleCompleted);
+    }
+
     #[tokio::test]
     async fn handle_skips_irrelevant_events() {
         let room_manager = Arc::new(RoomManager::default());
@@ -392,6 +510,7 @@ mod tests {
             "pugh_scores.computed",
             "dq_scores.computed",
             "cycle.completed",
+            "document.sections_changed.v1",
         ];
 
         for event_type in expected {
@@ -2,12 +2,15 @@
 //!
 //! Handles the HTTP → WebSocket upgrade and manages the connection lifecycle:
 //! 1. Validate session exists and user has access
-//! 2. Upgrade to WebSocket
-//! 3. Join session room
-//! 4. Send/receive messages until disconnect
-//! 5. Clean up room membership
+//! 2. Upgrade to WebSocket, agreeing JSON or MessagePack framing (see [`super::codec`])
+//! 3. Join session room and announce presence
+//! 4. Replay live operator announcements for the user's tier
+//! 5. Send/receive messages until disconnect, pinging the client and
+//!    closing idle or long-lived connections per the [`ConnectionPolicy`]
+//! 6. Clean up room membership (announcing the departure)
 
 use std::sync::Arc;
+use std::time::Instant;
 
 use axum::{
     extract::{
@@ -17,14 +20,20 @@ use axum::{
     response::Response,
 };
 use futures::{SinkExt, StreamExt};
-use tokio::sync::broadcast;
+use tokio::sync::{broadcast, mpsc};
 
-use crate::domain::foundation::{SessionId, Timestamp};
+use crate::adapters::http::middleware::OptionalAuth;
+use crate::domain::foundation::{AuthenticatedUser, SessionId, Timestamp, UserId};
+use crate::domain::membership::MembershipTier;
+use crate::ports::MembershipReader;
 
 use super::{
-    messages::{ClientMessage, ConnectedMessage, ServerMessage},
+    announcements::AnnouncementBoard,
+    cluster::WebSocketCluster,
+    codec::{WireCodec, SUPPORTED_PROTOCOLS},
+    messages::{ClientMessage, ConnectedMessage, Participant, RosterMessage, ServerMessage},
+    policy::{ActivityClock, ConnectionPolicy},
     rooms::{ClientId, RoomManager},
-    DashboardUpdate,
 };
 
 /// State required for WebSocket handling.
@@ -34,6 +43,14 @@ use super::{
 pub struct WebSocketState {
     /// Room manager for session-based routing.
     pub room_manager: Arc<RoomManager>,
+    /// Registers rooms for cross-server routing (multi-server only).
+    pub cluster: Option<WebSocketCluster>,
+    /// Replays live announcements to new connections.
+    pub announcements: Option<AnnouncementBoard>,
+    /// Resolves tiers for tier-targeted announcements.
+    pub membership_reader: Option<Arc<dyn MembershipReader>>,
+    /// Heartbeat cadence and idle/lifetime limits.
+    pub policy: ConnectionPolicy,
     // TODO: Add session repository for validation
     // TODO: Add auth provider for user validation
 }
@@ -41,7 +58,36 @@ pub struct WebSocketState {
 impl WebSocketState {
     /// Create a new WebSocket state.
     pub fn new(room_manager: Arc<RoomManager>) -> Self {
-        Self { room_manager }
+        Self {
+            room_manager,
+            cluster: None,
+            announcements: None,
+            membership_reader: None,
+            policy: ConnectionPolicy::default(),
+        }
+    }
+
+    /// Override the default heartbeat and idle limits.
+    pub fn with_policy(mut self, policy: ConnectionPolicy) -> Self {
+        self.policy = policy;
+        self
+    }
+
+    /// Replay live announcements on connect and target them by tier.
+    pub fn with_announcements(
+        mut self,
+        announcements: AnnouncementBoard,
+        membership_reader: Arc<dyn MembershipReader>,
+    ) -> Self {
+        self.announcements = Some(announcements);
+        self.membership_reader = Some(membership_reader);
+        self
+    }
+
+    /// Register this server's rooms so other servers can reach them.
+    pub fn with_cluster(mut self, cluster: WebSocketCluster) -> Self {
+        self.cluster = Some(cluster);
+        self
     }
 }
 
@@ -58,6 +104,7 @@ impl WebSocketState {
 /// - Validate origin header
 pub async fn ws_handler(
     ws: WebSocketUpgrade,
+    OptionalAuth(user): OptionalAuth,
     Path(session_id): Path<String>,
     State(state): State<WebSocketState>,
 ) -> Response {
@@ -77,27 +124,53 @@ pub async fn ws_handler(
     // authorize_session_access(&user_id, &session_id)?;
 
     // Upgrade to WebSocket
-    ws.on_upgrade(move |socket| handle_socket(socket, session_id, state))
+    ws.protocols(SUPPORTED_PROTOCOLS).on_upgrade(move |socket| {
+        let codec = WireCodec::from_protocol(socket.protocol());
+        handle_socket(socket, codec, session_id, user, state)
+    })
 }
 
 /// Handle an established WebSocket connection.
 ///
 /// This function runs for the lifetime of the connection, handling:
-/// - Joining the session room
-/// - Forwarding room broadcasts to the client
-/// - Processing client messages (ping, request state)
+/// - Joining the session room and announcing presence
+/// - Forwarding room broadcasts, presence events, and announcements
+/// - Processing client messages (ping, request state, roster, presence)
 /// - Cleanup on disconnect
-async fn handle_socket(socket: WebSocket, session_id: SessionId, state: WebSocketState) {
+async fn handle_socket(
+    socket: WebSocket,
+    codec: WireCodec,
+    session_id: SessionId,
+    user: Option<AuthenticatedUser>,
+    state: WebSocketState,
+) {
     let (mut sender, mut receiver) = socket.split();
 
     // Generate client ID
     let client_id = ClientId::new();
+    let connected_at = Instant::now();
+    let activity = ActivityClock::new();
+    let user_id = user.as_ref().map(|u| u.id.clone());
 
     // Join session room
-    let mut room_rx: broadcast::Receiver<DashboardUpdate> = state
+    let participant = Participant::new(
+        client_id.clone(),
+        user_id.clone(),
+        user.and_then(|u| u.display_name),
+    );
+    let (mut room_rx, mut presence_rx) = state
         .room_manager
-        .join(&session_id, client_id.clone())
+        .join_with_presence(&session_id, participant)
         .await;
+    let mut announcement_rx = state.room_manager.subscribe_announcements();
+    let tier = lookup_tier(&state, user_id.as_ref()).await;
+
+    if let Some(cluster) = &state.cluster {
+        cluster.connected(&session_id, user_id.as_ref()).await;
+    }
+
+    // Replies meant for this client only (e.g. the roster)
+    let (reply_tx, mut reply_rx) = mpsc::unbounded_channel::<ServerMessage>();
 
     // Send connected message
     let connected = ServerMessage::Connected(ConnectedMessage {
@@ -106,18 +179,63 @@ async fn handle_socket(socket: WebSocket, session_id: SessionId, state: WebSocke
         timestamp: Timestamp::now().as_datetime().to_rfc3339(),
     });
 
-    if let Err(e) = send_message(&mut sender, &connected).await {
+    if let Err(e) = send_message(&mut sender, codec, &connected).await {
         tracing::debug!("Failed to send connected message: {}", e);
-        return; // Client disconnected immediately
+        // Client disconnected immediately
+        disconnect(&state, &session_id, &client_id, user_id.as_ref()).await;
+        return;
+    }
+
+    // Announcements that went out before this client connected
+    if let Some(board) = &state.announcements {
+        for announcement in board.live_for(tier).await {
+            let _ = reply_tx.send(announcement.to_server_message());
+        }
     }
 
-    // Spawn task to forward room broadcasts to client
+    // Spawn task to forward room broadcasts and replies to client
     let mut send_task = {
         let client_id_clone = client_id.clone();
+        let policy = state.policy.clone();
+        let activity = activity.clone();
+        let mut heartbeat = policy.heartbeat();
         tokio::spawn(async move {
-            while let Ok(update) = room_rx.recv().await {
-                let msg = update.to_server_message();
-                if let Err(e) = send_message(&mut sender, &msg).await {
+            loop {
+                let msg = tokio::select! {
+                    update = room_rx.recv() => match update {
+                        Ok(update) => update.to_server_message(),
+                        Err(_) => break,
+                    },
+                    event = presence_rx.recv() => match event {
+                        Ok(event) => event.to_server_message(),
+                        Err(_) => break,
+                    },
+                    reply = reply_rx.recv() => match reply {
+                        Some(reply) => reply,
+                        None => break,
+                    },
+                    announcement = announcement_rx.recv() => match announcement {
+                        Ok(a) if a.audience.includes(tier) => a.to_server_message(),
+                        Ok(_) | Err(broadcast::error::RecvError::Lagged(_)) => continue,
+                        Err(broadcast::error::RecvError::Closed) => break,
+                    },
+                    _ = heartbeat.tick() => {
+                        if let Some(reason) = policy.check(connected_at, activity.last(), Instant::now()) {
+                            tracing::debug!(
+                                client_id = %client_id_clone,
+                                code = reason.code(),
+                                "Closing connection under policy"
+                            );
+                            let _ = sender.send(reason.close_message()).await;
+                            break;
+                        }
+                        if sender.send(Message::Ping(Vec::new())).await.is_err() {
+                            break;
+                        }
+                        continue;
+                    }
+                };
+                if let Err(e) = send_message(&mut sender, codec, &msg).await {
                     tracing::debug!(
                         client_id = %client_id_clone,
                         "Send error, closing connection: {}",
@@ -134,9 +252,10 @@ async fn handle_socket(socket: WebSocket, session_id: SessionId, state: WebSocke
     let client_id_for_recv = client_id.clone();
     let mut recv_task = tokio::spawn(async move {
         while let Some(result) = receiver.next().await {
+            activity.touch();
             match result {
-                Ok(Message::Text(text)) => {
-                    if let Ok(client_msg) = serde_json::from_str::<ClientMessage>(&text) {
+                Ok(frame @ (Message::Text(_) | Message::Binary(_))) => {
+                    if let Some(Ok(client_msg)) = WireCodec::decode::<ClientMessage>(&frame) {
                         match client_msg {
                             ClientMessage::Ping => {
                                 // Pong is handled in the send task via room broadcast
@@ -153,21 +272,33 @@ async fn handle_socket(socket: WebSocket, session_id: SessionId, state: WebSocke
                                     "State request received (not implemented)"
                                 );
                             }
+                            ClientMessage::RequestRoster => {
+                                let roster = ServerMessage::PresenceRoster(RosterMessage {
+                                    participants: room_manager
+                                        .roster(&session_id)
+                                        .await
+                                        .iter()
+                                        .map(Participant::to_info)
+                                        .collect(),
+                                    timestamp: Timestamp::now().as_datetime().to_rfc3339(),
+                                });
+                                if reply_tx.send(roster).is_err() {
+                                    break;
+                                }
+                            }
+                            ClientMessage::UpdatePresence { activity } => {
+                                room_manager
+                                    .update_activity(&client_id_for_recv, activity)
+                                    .await;
+                            }
                         }
                     }
                 }
-                Ok(Message::Binary(_)) => {
-                    // Binary messages not supported
-                    tracing::warn!(
-                        client_id = %client_id_for_recv,
-                        "Received unsupported binary message"
-                    );
-                }
                 Ok(Message::Ping(_)) => {
                     // WebSocket protocol ping - handled automatically by axum
                 }
                 Ok(Message::Pong(_)) => {
-                    // WebSocket protocol pong - handled automatically by axum
+                    // Reply to our heartbeat ping; counted as activity above
                 }
                 Ok(Message::Close(_)) => {
                     tracing::debug!(
@@ -186,9 +317,6 @@ async fn handle_socket(socket: WebSocket, session_id: SessionId, state: WebSocke
                 }
             }
         }
-
-        // Return room_manager for cleanup
-        room_manager
     });
 
     // Wait for either task to finish
@@ -196,27 +324,50 @@ async fn handle_socket(socket: WebSocket, session_id: SessionId, state: WebSocke
         _ = &mut send_task => {
             recv_task.abort();
         }
-        result = &mut recv_task => {
+        _ = &mut recv_task => {
             send_task.abort();
-            // Cleanup: leave room
-            if let Ok(room_manager) = result {
-                room_manager.leave(&client_id).await;
-            }
-            return;
         }
     }
 
-    // Cleanup: leave room (send_task finished first)
-    state.room_manager.leave(&client_id).await;
+    // Cleanup: leave room
+    disconnect(&state, &session_id, &client_id, user_id.as_ref()).await;
+}
+
+/// The user's tier for announcement targeting; `None` when anonymous or
+/// unknown.
+async fn lookup_tier(state: &WebSocketState, user_id: Option<&UserId>) -> Option<MembershipTier> {
+    let (Some(reader), Some(user_id)) = (&state.membership_reader, user_id) else {
+        return None;
+    };
+    match reader.get_tier(user_id).await {
+        Ok(tier) => tier,
+        Err(e) => {
+            tracing::warn!("Tier lookup failed for announcements: {}", e);
+            None
+        }
+    }
+}
+
+/// Leave the room and, in a cluster, release this server's registration.
+async fn disconnect(
+    state: &WebSocketState,
+    session_id: &SessionId,
+    client_id: &ClientId,
+    user_id: Option<&UserId>,
+) {
+    state.room_manager.leave(client_id).await;
+    if let Some(cluster) = &state.cluster {
+        cluster.disconnected(session_id, user_id).await;
+    }
 }
 
-/// Send a JSON message over the WebSocket.
+/// Send a message over the WebSocket in the connection's encoding.
 async fn send_message(
     sender: &mut futures::stream::SplitSink<WebSocket, Message>,
+    codec: WireCodec,
     msg: &ServerMessage,
 ) -> Result<(), axum::Error> {
-    let json = serde_json::to_string(msg).expect("ServerMessage serialization should not fail");
-    sender.send(Message::Text(json)).await
+    sender.send(codec.encode(msg)).await
 }
 
 /// Create axum router for WebSocket endpoint.
@@ -0,0 +1,221 @@
+//! In-memory connection registry for testing and single-server deployments.
+
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use async_trait::async_trait;
+use tokio::sync::RwLock;
+
+use crate::domain::foundation::{SessionId, UserId};
+use crate::ports::{ConnectionRegistry, ConnectionRegistryError, ServerId};
+
+#[derive(Debug, Default)]
+struct Registry {
+    users: HashMap<UserId, HashSet<ServerId>>,
+    sessions: HashMap<SessionId, HashSet<ServerId>>,
+    heartbeats: HashMap<ServerId, Instant>,
+}
+
+/// In-memory `ConnectionRegistry`.
+///
+/// Entries never expire on their own; stale servers are found only through
+/// their heartbeats.
+#[derive(Debug, Clone, Default)]
+pub struct InMemoryConnectionRegistry {
+    registry: Arc<RwLock<Registry>>,
+}
+
+impl InMemoryConnectionRegistry {
+    /// Create an empty registry.
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait]
+impl ConnectionRegistry for InMemoryConnectionRegistry {
+    async fn register(
+        &self,
+        user_id: &UserId,
+        server_id: &ServerId,
+    ) -> Result<(), ConnectionRegistryError> {
+        self.registry
+            .write()
+            .await
+            .users
+            .entry(user_id.clone())
+            .or_default()
+            .insert(server_id.clone());
+        Ok(())
+    }
+
+    async fn unregister(
+        &self,
+        user_id: &UserId,
+        server_id: &ServerId,
+    ) -> Result<(), ConnectionRegistryError> {
+        let mut registry = self.registry.write().await;
+        if let Some(servers) = registry.users.get_mut(user_id) {
+            servers.remove(server_id);
+            if servers.is_empty() {
+                registry.users.remove(user_id);
+            }
+        }
+        Ok(())
+    }
+
+    async fn find_servers(
+        &self,
+        user_id: &UserId,
+    ) -> Result<Vec<ServerId>, ConnectionRegistryError> {
+        Ok(self
+            .registry
+            .read()
+            .await
+            .users
+            .get(user_id)
+            .map(|servers| servers.iter().cloned().collect())
+            .unwrap_or_default())
+    }
+
+    async fn is_connected(&self, user_id: &UserId) -> Result<bool, ConnectionRegistryError> {
+        Ok(self.registry.read().await.users.contains_key(user_id))
+    }
+
+    async fn heartbeat(
+        &self,
+        user_id: &UserId,
+        server_id: &ServerId,
+    ) -> Result<(), ConnectionRegistryError> {
+        self.register(user_id, server_id).await
+    }
+
+    async fn get_server_connections(
+        &self,
+        server_id: &ServerId,
+    ) -> Result<Vec<UserId>, ConnectionRegistryError> {
+        Ok(self
+            .registry
+            .read()
+            .await
+            .users
+            .iter()
+            .filter(|(_, servers)| servers.contains(server_id))
+            .map(|(user_id, _)| user_id.clone())
+            .collect())
+    }
+
+    async fn cleanup_server(&self, server_id: &ServerId) -> Result<u64, ConnectionRegistryError> {
+        let mut registry = self.registry.write().await;
+        let mut removed = 0;
+        registry.users.retain(|_, servers| {
+            if servers.remove(server_id) {
+                removed += 1;
+            }
+            !servers.is_empty()
+        });
+        registry.sessions.retain(|_, servers| {
+            servers.remove(server_id);
+            !servers.is_empty()
+        });
+        registry.heartbeats.remove(server_id);
+        Ok(removed)
+    }
+
+    async fn join_session(
+        &self,
+        session_id: &SessionId,
+        server_id: &ServerId,
+    ) -> Result<(), ConnectionRegistryError> {
+        self.registry
+            .write()
+            .await
+            .sessions
+            .entry(*session_id)
+            .or_default()
+            .insert(server_id.clone());
+        Ok(())
+    }
+
+    async fn leave_session(
+        &self,
+        session_id: &SessionId,
+        server_id: &ServerId,
+    ) -> Result<(), ConnectionRegistryError> {
+        let mut registry = self.registry.write().await;
+        if let Some(servers) = registry.sessions.get_mut(session_id) {
+            servers.remove(server_id);
+            if servers.is_empty() {
+                registry.sessions.remove(session_id);
+            }
+        }
+        Ok(())
+    }
+
+    async fn find_session_servers(
+        &self,
+        session_id: &SessionId,
+    ) -> Result<Vec<ServerId>, ConnectionRegistryError> {
+        Ok(self
+            .registry
+            .read()
+            .await
+            .sessions
+            .get(session_id)
+            .map(|servers| servers.iter().cloned().collect())
+            .unwrap_or_default())
+    }
+
+    async fn heartbeat_server(&self, server_id: &ServerId) -> Result<(), ConnectionRegistryError> {
+        self.registry
+            .write()
+            .await
+            .heartbeats
+            .insert(server_id.clone(), Instant::now());
+        Ok(())
+    }
+
+    async fn find_stale_servers(
+        &self,
+        max_silence: Duration,
+    ) -> Result<Vec<ServerId>, ConnectionRegistryError> {
+        Ok(self
+            .registry
+            .read()
+            .await
+            .heartbeats
+            .iter()
+            .filter(|(_, last)| last.elapsed() > max_silence)
+            .map(|(server_id, _)| server_id.clone())
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn cleanup_removes_a_servers_users_sessions_and_heartbeat() {
+        let registry = InMemoryConnectionRegistry::new();
+        let (a, b) = (ServerId::new("a:8080"), ServerId::new("b:8080"));
+        let user = UserId::new("user-1").unwrap();
+        let session = SessionId::new();
+        for server in [&a, &b] {
+            registry.register(&user, server).await.unwrap();
+            registry.join_session(&session, server).await.unwrap();
+            registry.heartbeat_server(server).await.unwrap();
+        }
+
+        assert_eq!(registry.cleanup_server(&a).await.unwrap(), 1);
+
+        assert_eq!(registry.find_servers(&user).await.unwrap(), vec![b.clone()]);
+        assert_eq!(
+            registry.find_session_servers(&session).await.unwrap(),
+            vec![b.clone()]
+        );
+        let stale = registry.find_stale_servers(Duration::ZERO).await.unwrap();
+        assert_eq!(stale, vec![b]);
+    }
+}
@@ -1,12 +1,14 @@
 //! WebSocket message types for real-time dashboard updates.
 //!
 //! Defines the protocol between server and connected clients:
-//! - Server → Client: Connection status, dashboard updates, errors, pings
-//! - Client → Server: Pings, state requests
+//! - Server → Client: Connection status, dashboard updates, presence, errors, pings
+//! - Client → Server: Pings, state and roster requests, presence updates
 
 use serde::{Deserialize, Serialize};
 
-use crate::domain::foundation::{ComponentType, Timestamp};
+use crate::domain::foundation::{ComponentType, Timestamp, UserId};
+
+use super::rooms::ClientId;
 
 // ============================================
 // Server → Client Messages
@@ -23,6 +25,25 @@ pub enum ServerMessage {
     #[serde(rename = "dashboard.update")]
     DashboardUpdate(DashboardUpdateMessage),
 
+    /// Someone opened the session.
+    #[serde(rename = "presence.joined")]
+    PresenceJoined(PresenceMessage),
+
+    /// Someone switched between viewing and editing.
+    #[serde(rename = "presence.updated")]
+    PresenceUpdated(PresenceMessage),
+
+    /// Someone closed the session.
+    #[serde(rename = "presence.left")]
+    PresenceLeft(PresenceMessage),
+
+    /// Everyone currently in the session, in reply to `request.roster`.
+    #[serde(rename = "presence.roster")]
+    PresenceRoster(RosterMessage),
+
+    /// Notice from the operators (maintenance, new features).
+    Announcement(AnnouncementMessage),
+
     /// Error occurred.
     Error(ErrorMessage),
 
@@ -56,6 +77,8 @@ pub struct DashboardUpdateMessage {
 pub enum DashboardUpdateType {
     /// Session title/description changed.
     SessionMetadata,
+    /// Session archived, warned about auto-archive, or excluded from it.
+    SessionStatus,
     /// New cycle created.
     CycleCreated,
     /// Cycle progress changed.
@@ -72,6 +95,79 @@ pub enum DashboardUpdateType {
     AnalysisScores,
     /// Cycle finished.
     CycleCompleted,
+    /// A collaborator's edit changed sections of the decision document.
+    DocumentChanged,
+}
+
+/// A participant joined, left, or changed activity.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PresenceMessage {
+    pub participant: ParticipantInfo,
+    pub timestamp: String,
+}
+
+/// Everyone currently connected to a session.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RosterMessage {
+    pub participants: Vec<ParticipantInfo>,
+    pub timestamp: String,
+}
+
+/// One connection in a session room.
+///
+/// A user with several tabs open appears once per connection; clients can
+/// group by `userId`.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ParticipantInfo {
+    pub client_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub display_name: Option<String>,
+    pub activity: PresenceActivity,
+    pub joined_at: String,
+}
+
+/// What a participant is doing in the session.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum PresenceActivity {
+    /// Looking at the dashboard.
+    Viewing,
+    /// Working on a component.
+    Editing {
+        #[serde(rename = "componentType")]
+        component_type: ComponentType,
+    },
+}
+
+/// Operator notice shown to everyone it targets.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AnnouncementMessage {
+    pub id: String,
+    pub kind: AnnouncementKind,
+    pub title: String,
+    pub body: String,
+    pub starts_at: String,
+    /// Clients should hide the notice after this time.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expires_at: Option<String>,
+}
+
+/// What an announcement is about, so clients can style it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AnnouncementKind {
+    /// Planned downtime or degraded service.
+    Maintenance,
+    /// A new or changed feature.
+    Feature,
+    /// Anything else.
+    Info,
 }
 
 /// Error message sent to client.
@@ -102,6 +198,14 @@ pub enum ClientMessage {
     /// Request full dashboard state (after reconnection).
     #[serde(rename = "request.state")]
     RequestState,
+
+    /// Request everyone currently in the session.
+    #[serde(rename = "request.roster")]
+    RequestRoster,
+
+    /// Report what this client is doing (viewing or editing a component).
+    #[serde(rename = "presence.update")]
+    UpdatePresence { activity: PresenceActivity },
 }
 
 // ============================================
@@ -131,6 +235,72 @@ impl DashboardUpdate {
     }
 }
 
+/// A connection present in a session room.
+#[derive(Debug, Clone)]
+pub struct Participant {
+    pub client_id: ClientId,
+    /// `None` for connections that did not authenticate.
+    pub user_id: Option<UserId>,
+    pub display_name: Option<String>,
+    pub activity: PresenceActivity,
+    pub joined_at: Timestamp,
+}
+
+impl Participant {
+    /// A participant who has just opened the dashboard.
+    pub fn new(client_id: ClientId, user_id: Option<UserId>, display_name: Option<String>) -> Self {
+        Self {
+            client_id,
+            user_id,
+            display_name,
+            activity: PresenceActivity::Viewing,
+            joined_at: Timestamp::now(),
+        }
+    }
+
+    /// Wire representation.
+    pub fn to_info(&self) -> ParticipantInfo {
+        ParticipantInfo {
+            client_id: self.client_id.to_string(),
+            user_id: self.user_id.as_ref().map(|id| id.to_string()),
+            display_name: self.display_name.clone(),
+            activity: self.activity,
+            joined_at: self.joined_at.as_datetime().to_rfc3339(),
+        }
+    }
+}
+
+/// Kind of presence change broadcast to a room.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PresenceChange {
+    Joined,
+    Updated,
+    Left,
+}
+
+/// Internal representation of a presence change for broadcasting.
+#[derive(Debug, Clone)]
+pub struct PresenceEvent {
+    pub change: PresenceChange,
+    pub participant: Participant,
+    pub timestamp: Timestamp,
+}
+
+impl PresenceEvent {
+    /// Convert to a server message for sending to clients.
+    pub fn to_server_message(self) -> ServerMessage {
+        let msg = PresenceMessage {
+            participant: self.participant.to_info(),
+            timestamp: self.timestamp.as_datetime().to_rfc3339(),
+        };
+        match self.change {
+            PresenceChange::Joined => ServerMessage::PresenceJoined(msg),
+            PresenceChange::Updated => ServerMessage::PresenceUpdated(msg),
+            PresenceChange::Left => ServerMessage::PresenceLeft(msg),
+        }
+    }
+}
+
 // ============================================
 // Payload Types for Specific Update Types
 // ============================================
@@ -244,6 +414,42 @@ mod tests {
         assert!(matches!(msg, ClientMessage::RequestState));
     }
 
+    #[test]
+    fn client_message_deserializes_presence_update() {
+        let json = r#"{"type": "presence.update", "activity": {"kind": "editing", "componentType": "objectives"}}"#;
+        let msg: ClientMessage = serde_json::from_str(json).unwrap();
+        assert!(matches!(
+            msg,
+            ClientMessage::UpdatePresence {
+                activity: PresenceActivity::Editing {
+                    component_type: ComponentType::Objectives
+                }
+            }
+        ));
+
+        let json = r#"{"type": "request.roster"}"#;
+        let msg: ClientMessage = serde_json::from_str(json).unwrap();
+        assert!(matches!(msg, ClientMessage::RequestRoster));
+    }
+
+    #[test]
+    fn presence_event_serializes_with_type_tag() {
+        let event = PresenceEvent {
+            change: PresenceChange::Left,
+            participant: Participant::new(
+                ClientId::new(),
+                Some(UserId::new("user-1").unwrap()),
+                Some("Ana".to_string()),
+            ),
+            timestamp: Timestamp::now(),
+        };
+
+        let json = serde_json::to_string(&event.to_server_message()).unwrap();
+        assert!(json.contains(r#""type":"presence.left""#));
+        assert!(json.contains(r#""userId":"user-1""#));
+        assert!(json.contains(r#""activity":{"kind":"viewing"}"#));
+    }
+
     #[test]
     fn dashboard_update_converts_to_server_message() {
         let update = DashboardUpdate {
@@ -34,19 +34,44 @@
 //! # Components
 //!
 //! - [`messages`] - WebSocket message protocol types
-//! - [`rooms`] - Room management for session-based routing
+//! - [`rooms`] - Room management for session-based routing and presence
+//! - [`codec`] - JSON and MessagePack framing, chosen by subprotocol
 //! - [`handler`] - Axum WebSocket upgrade handler
+//! - [`announcements`] - Scheduled operator announcements for all connections
 //! - [`event_bridge`] - Bridge between event bus and WebSocket rooms
+//! - [`cluster`] - Cross-server routing of session rooms with server heartbeats
+//! - [`policy`] - Heartbeat, idle timeout, and lifetime limits for connections
+//! - [`redis_registry`] - Redis `ConnectionRegistry` and `ServerMessenger`
+//! - [`in_memory_registry`] - In-memory `ConnectionRegistry` for tests and one server
 
+pub mod announcements;
+pub mod cluster;
+pub mod codec;
 pub mod event_bridge;
 pub mod handler;
+pub mod in_memory_registry;
 pub mod messages;
+pub mod policy;
+pub mod redis_registry;
 pub mod rooms;
 
+pub use announcements::{
+    Announcement, AnnouncementAudience, AnnouncementBoard, AnnouncementError, AnnouncementStatus,
+    DEFAULT_DISPATCH_INTERVAL,
+};
+pub use cluster::{WebSocketCluster, DEFAULT_HEARTBEAT_INTERVAL, DEFAULT_STALE_AFTER};
+pub use codec::{CodecError, WireCodec, JSON_PROTOCOL, MSGPACK_PROTOCOL, SUPPORTED_PROTOCOLS};
 pub use event_bridge::{WebSocketEventBridge, DASHBOARD_EVENT_TYPES};
 pub use handler::{websocket_router, ws_handler, WebSocketState};
+pub use in_memory_registry::InMemoryConnectionRegistry;
 pub use messages::{
-    ClientMessage, ConnectedMessage, DashboardUpdate, DashboardUpdateMessage,
-    DashboardUpdateType, ErrorMessage, PongMessage, ServerMessage,
+    AnnouncementKind, AnnouncementMessage, ClientMessage, ConnectedMessage, DashboardUpdate, DashboardUpdateMessage,
+    DashboardUpdateType, ErrorMessage, Participant, ParticipantInfo, PongMessage,
+    PresenceActivity, PresenceChange, PresenceEvent, PresenceMessage, RosterMessage,
+    ServerMessage,
+};
+pub use policy::{
+    ActivityClock, CloseReason, ConnectionPolicy, CLOSE_IDLE_TIMEOUT, CLOSE_LIFETIME_EXCEEDED,
 };
+pub use redis_registry::{RedisConnectionRegistry, RedisServerMessenger, DEFAULT_CONNECTION_TTL};
 pub use rooms::{ClientId, RoomManager};
@@ -0,0 +1,183 @@
+//! Heartbeat and idle-connection policy shared by WebSocket handlers.
+//!
+//! The server pings each connection every `ping_interval`. Any frame from
+//! the client, including the automatic pong, counts as activity. On each
+//! ping the connection is checked:
+//!
+//! - no activity for `idle_timeout` → closed with [`CLOSE_IDLE_TIMEOUT`]
+//! - open longer than `max_lifetime` → closed with [`CLOSE_LIFETIME_EXCEEDED`]
+//!
+//! Both codes are in the 4000-4999 private range, so clients can tell a
+//! policy close (reconnect quietly) from an abnormal close (1006) or a
+//! server error (1011). Limits are enforced at ping granularity.
+
+use std::borrow::Cow;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use axum::extract::ws::{CloseFrame, Message};
+use tokio::time::{Interval, MissedTickBehavior};
+
+/// Close code sent when the client has gone quiet.
+pub const CLOSE_IDLE_TIMEOUT: u16 = 4000;
+
+/// Close code sent when the connection reaches its maximum lifetime.
+/// Clients should reconnect immediately.
+pub const CLOSE_LIFETIME_EXCEEDED: u16 = 4001;
+
+/// Heartbeat and idle limits for a WebSocket connection.
+#[derive(Debug, Clone)]
+pub struct ConnectionPolicy {
+    /// How often the server pings the client.
+    pub ping_interval: Duration,
+    /// Silence after which the connection is closed.
+    pub idle_timeout: Duration,
+    /// Longest a connection stays open; `None` for no limit.
+    pub max_lifetime: Option<Duration>,
+}
+
+impl ConnectionPolicy {
+    /// Ticks every `ping_interval`, starting one interval from now.
+    pub fn heartbeat(&self) -> Interval {
+        let start = tokio::time::Instant::now() + self.ping_interval;
+        let mut interval = tokio::time::interval_at(start, self.ping_interval);
+        interval.set_missed_tick_behavior(MissedTickBehavior::Delay);
+        interval
+    }
+
+    /// Why the connection should be closed at `now`, if it should.
+    pub fn check(
+        &self,
+        connected_at: Instant,
+        last_activity: Instant,
+        now: Instant,
+    ) -> Option<CloseReason> {
+        if self
+            .max_lifetime
+            .is_some_and(|max| now.duration_since(connected_at) >= max)
+        {
+            return Some(CloseReason::LifetimeExceeded);
+        }
+        if now.duration_since(last_activity) >= self.idle_timeout {
+            return Some(CloseReason::IdleTimeout);
+        }
+        None
+    }
+}
+
+impl Default for ConnectionPolicy {
+    fn default() -> Self {
+        Self {
+            ping_interval: Duration::from_secs(30),
+            idle_timeout: Duration::from_secs(90),
+            max_lifetime: Some(Duration::from_secs(4 * 60 * 60)),
+        }
+    }
+}
+
+/// Why the server closed a connection under its policy.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CloseReason {
+    IdleTimeout,
+    LifetimeExceeded,
+}
+
+impl CloseReason {
+    pub fn code(&self) -> u16 {
+        match self {
+            Self::IdleTimeout => CLOSE_IDLE_TIMEOUT,
+            Self::LifetimeExceeded => CLOSE_LIFETIME_EXCEEDED,
+        }
+    }
+
+    /// The close frame to send.
+    pub fn close_message(&self) -> Message {
+        let reason = match self {
+            Self::IdleTimeout => "idle timeout",
+            Self::LifetimeExceeded => "connection lifetime exceeded",
+        };
+        Message::Close(Some(CloseFrame {
+            code: self.code(),
+            reason: Cow::Borrowed(reason),
+        }))
+    }
+}
+
+/// Last time a connection heard from its client, shared between the
+/// receiving and sending halves.
+#[derive(Debug, Clone)]
+pub struct ActivityClock(Arc<Mutex<Instant>>);
+
+impl ActivityClock {
+    pub fn new() -> Self {
+        Self(Arc::new(Mutex::new(Instant::now())))
+    }
+
+    /// Record activity now.
+    pub fn touch(&self) {
+        *self.0.lock().unwrap_or_else(|e| e.into_inner()) = Instant::now();
+    }
+
+    pub fn last(&self) -> Instant {
+        *self.0.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+impl Default for ActivityClock {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn policy() -> ConnectionPolicy {
+        ConnectionPolicy {
+            ping_interval: Duration::from_secs(10),
+            idle_timeout: Duration::from_secs(30),
+            max_lifetime: Some(Duration::from_secs(3600)),
+        }
+    }
+
+    #[test]
+    fn closes_idle_connections() {
+        let start = Instant::now();
+        let policy = policy();
+
+        assert_eq!(
+            policy.check(start, start, start + Duration::from_secs(29)),
+            None
+        );
+        assert_eq!(
+            policy.check(start, start, start + Duration::from_secs(30)),
+            Some(CloseReason::IdleTimeout)
+        );
+    }
+
+    #[test]
+    fn closes_connections_past_their_lifetime_even_when_active() {
+        let start = Instant::now();
+        let now = start + Duration::from_secs(3600);
+
+        assert_eq!(
+            policy().check(start, now, now),
+            Some(CloseReason::LifetimeExceeded)
+        );
+
+        let unlimited = ConnectionPolicy {
+            max_lifetime: None,
+            ..policy()
+        };
+        assert_eq!(unlimited.check(start, now, now), None);
+    }
+
+    #[test]
+    fn close_frames_use_private_codes() {
+        let Message::Close(Some(frame)) = CloseReason::LifetimeExceeded.close_message() else {
+            panic!("expected a close frame");
+        };
+        assert_eq!(frame.code, 4001);
+    }
+}
@@ -0,0 +1,412 @@
+//! Redis-backed connection registry and server messenger for multi-server
+//! deployments.
+//!
+//! # Keys
+//!
+//! ```text
+//! ws:user:{user_id}             ZSET  server_id → expiry (unix secs)
+//! ws:session:{session_id}       ZSET  server_id → expiry (unix secs)
+//! ws:server:{server_id}:users   SET   user_ids connected to the server
+//! ws:server:{server_id}:sessions SET  session rooms held by the server
+//! ws:servers                    ZSET  server_id → last heartbeat (unix secs)
+//! ws:events:{server_id}         PUB/SUB channel of events for that server
+//! ```
+//!
+//! Entries carry their own expiry so a crashed server's connections stop
+//! being returned after the TTL, even before another server cleans up.
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use async_trait::async_trait;
+use futures::StreamExt;
+use redis::aio::MultiplexedConnection;
+use redis::AsyncCommands;
+use serde::{Deserialize, Serialize};
+use tokio::task::JoinHandle;
+
+use crate::domain::foundation::{EventEnvelope, SessionId, Timestamp, UserId};
+use crate::ports::{ConnectionRegistry, ConnectionRegistryError, ServerId, ServerMessenger};
+
+use super::event_bridge::WebSocketEventBridge;
+
+/// Default lifetime of a registry entry without a heartbeat.
+pub const DEFAULT_CONNECTION_TTL: Duration = Duration::from_secs(60);
+
+const SERVERS_KEY: &str = "ws:servers";
+
+fn user_key(user_id: &UserId) -> String {
+    format!("ws:user:{}", user_id)
+}
+
+fn session_key(session_id: &SessionId) -> String {
+    format!("ws:session:{}", session_id)
+}
+
+fn server_users_key(server_id: &ServerId) -> String {
+    format!("ws:server:{}:users", server_id)
+}
+
+fn server_sessions_key(server_id: &ServerId) -> String {
+    format!("ws:server:{}:sessions", server_id)
+}
+
+fn events_channel(server_id: &ServerId) -> String {
+    format!("ws:events:{}", server_id)
+}
+
+fn redis_error(e: redis::RedisError) -> ConnectionRegistryError {
+    ConnectionRegistryError::Redis(e.to_string())
+}
+
+fn now_secs() -> u64 {
+    Timestamp::now().as_unix_secs()
+}
+
+/// Redis-backed `ConnectionRegistry`.
+#[derive(Clone)]
+pub struct RedisConnectionRegistry {
+    conn: MultiplexedConnection,
+    ttl: Duration,
+}
+
+impl RedisConnectionRegistry {
+    /// Create a registry whose entries expire after [`DEFAULT_CONNECTION_TTL`].
+    pub fn new(conn: MultiplexedConnection) -> Self {
+        Self {
+            conn,
+            ttl: DEFAULT_CONNECTION_TTL,
+        }
+    }
+
+    /// Set how long entries live without a heartbeat.
+    pub fn with_ttl(mut self, ttl: Duration) -> Self {
+        self.ttl = ttl;
+        self
+    }
+
+    fn expiry(&self) -> u64 {
+        now_secs() + self.ttl.as_secs()
+    }
+
+    /// Live members of an expiring set, dropping expired ones.
+    async fn live_members(&self, key: &str) -> Result<Vec<String>, ConnectionRegistryError> {
+        let mut conn = self.conn.clone();
+        let now = now_secs();
+        let (members,): (Vec<String>,) = redis::pipe()
+            .atomic()
+            .zrembyscore(key, "-inf", format!("({}", now))
+            .ignore()
+            .zrangebyscore(key, now, "+inf")
+            .query_async(&mut conn)
+            .await
+            .map_err(redis_error)?;
+        Ok(members)
+    }
+}
+
+#[async_trait]
+impl ConnectionRegistry for RedisConnectionRegistry {
+    async fn register(
+        &self,
+        user_id: &UserId,
+        server_id: &ServerId,
+    ) -> Result<(), ConnectionRegistryError> {
+        let mut conn = self.conn.clone();
+        redis::pipe()
+            .atomic()
+            .zadd(user_key(user_id), server_id.as_str(), self.expiry())
+            .expire(user_key(user_id), self.ttl.as_secs() as i64)
+            .sadd(server_users_key(server_id), user_id.as_str())
+            .query_async::<_, ()>(&mut conn)
+            .await
+            .map_err(redis_error)
+    }
+
+    async fn unregister(
+        &self,
+        user_id: &UserId,
+        server_id: &ServerId,
+    ) -> Result<(), ConnectionRegistryError> {
+        let mut conn = self.conn.clone();
+        redis::pipe()
+            .atomic()
+            .zrem(user_key(user_id), server_id.as_str())
+            .srem(server_users_key(server_id), user_id.as_str())
+            .query_async::<_, ()>(&mut conn)
+            .await
+            .map_err(redis_error)
+    }
+
+    async fn find_servers(
+        &self,
+        user_id: &UserId,
+    ) -> Result<Vec<ServerId>, ConnectionRegistryError> {
+        let members = self.live_members(&user_key(user_id)).await?;
+        Ok(members.into_iter().map(ServerId::from).collect())
+    }
+
+    async fn is_connected(&self, user_id: &UserId) -> Result<bool, ConnectionRegistryError> {
+        let mut conn = self.conn.clone();
+        let count: u64 = conn
+            .zcount(user_key(user_id), now_secs(), "+inf")
+            .await
+            .map_err(redis_error)?;
+        Ok(count > 0)
+    }
+
+    async fn heartbeat(
+        &self,
+        user_id: &UserId,
+        server_id: &ServerId,
+    ) -> Result<(), ConnectionRegistryError> {
+        self.register(user_id, server_id).await
+    }
+
+    async fn get_server_connections(
+        &self,
+        server_id: &ServerId,
+    ) -> Result<Vec<UserId>, ConnectionRegistryError> {
+        let mut conn = self.conn.clone();
+        let members: Vec<String> = conn
+            .smembers(server_users_key(server_id))
+            .await
+            .map_err(redis_error)?;
+        members
+            .into_iter()
+            .map(|id| {
+                UserId::new(id).map_err(|e| ConnectionRegistryError::Serialization(e.to_string()))
+            })
+            .collect()
+    }
+
+    async fn cleanup_server(&self, server_id: &ServerId) -> Result<u64, ConnectionRegistryError> {
+        let mut conn = self.conn.clone();
+        let users: Vec<String> = conn
+            .smembers(server_users_key(server_id))
+            .await
+            .map_err(redis_error)?;
+        let sessions: Vec<String> = conn
+            .smembers(server_sessions_key(server_id))
+            .await
+            .map_err(redis_error)?;
+
+        let mut pipe = redis::pipe();
+        pipe.atomic();
+        for user_id in &users {
+            pipe.zrem(format!("ws:user:{}", user_id), server_id.as_str())
+                .ignore();
+        }
+        for session_id in &sessions {
+            pipe.zrem(format!("ws:session:{}", session_id), server_id.as_str())
+                .ignore();
+        }
+        pipe.del(server_users_key(server_id))
+            .ignore()
+            .del(server_sessions_key(server_id))
+            .ignore()
+            .zrem(SERVERS_KEY, server_id.as_str())
+            .ignore();
+        pipe.query_async::<_, ()>(&mut conn)
+            .await
+            .map_err(redis_error)?;
+
+        Ok(users.len() as u64)
+    }
+
+    async fn join_session(
+        &self,
+        session_id: &SessionId,
+        server_id: &ServerId,
+    ) -> Result<(), ConnectionRegistryError> {
+        let mut conn = self.conn.clone();
+        redis::pipe()
+            .atomic()
+            .zadd(session_key(session_id), server_id.as_str(), self.expiry())
+            .expire(session_key(session_id), self.ttl.as_secs() as i64)
+            .sadd(server_sessions_key(server_id), session_id.to_string())
+            .query_async::<_, ()>(&mut conn)
+            .await
+            .map_err(redis_error)
+    }
+
+    async fn leave_session(
+        &self,
+        session_id: &SessionId,
+        server_id: &ServerId,
+    ) -> Result<(), ConnectionRegistryError> {
+        let mut conn = self.conn.clone();
+        redis::pipe()
+            .atomic()
+            .zrem(session_key(session_id), server_id.as_str())
+            .srem(server_sessions_key(server_id), session_id.to_string())
+            .query_async::<_, ()>(&mut conn)
+            .await
+            .map_err(redis_error)
+    }
+
+    async fn find_session_servers(
+        &self,
+        session_id: &SessionId,
+    ) -> Result<Vec<ServerId>, ConnectionRegistryError> {
+        let members = self.live_members(&session_key(session_id)).await?;
+        Ok(members.into_iter().map(ServerId::from).collect())
+    }
+
+    async fn heartbeat_server(&self, server_id: &ServerId) -> Result<(), ConnectionRegistryError> {
+        let mut conn = self.conn.clone();
+        conn.zadd::<_, _, _, ()>(SERVERS_KEY, server_id.as_str(), now_secs())
+            .await
+            .map_err(redis_error)
+    }
+
+    async fn find_stale_servers(
+        &self,
+        max_silence: Duration,
+    ) -> Result<Vec<ServerId>, ConnectionRegistryError> {
+        let mut conn = self.conn.clone();
+        let cutoff = now_secs().saturating_sub(max_silence.as_secs());
+        let members: Vec<String> = conn
+            .zrangebyscore(SERVERS_KEY, "-inf", format!("({}", cutoff))
+            .await
+            .map_err(redis_error)?;
+        Ok(members.into_iter().map(ServerId::from).collect())
+    }
+}
+
+impl std::fmt::Debug for RedisConnectionRegistry {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("RedisConnectionRegistry")
+            .field("ttl", &self.ttl)
+            .finish_non_exhaustive()
+    }
+}
+
+/// An event on its way to another server's session room.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct RoutedEvent {
+    session_id: SessionId,
+    event: EventEnvelope,
+}
+
+/// Redis pub/sub `ServerMessenger`.
+///
+/// Each server listens on its own channel with [`RedisServerMessenger::listen`].
+#[derive(Clone)]
+pub struct RedisServerMessenger {
+    conn: MultiplexedConnection,
+}
+
+impl RedisServerMessenger {
+    pub fn new(conn: MultiplexedConnection) -> Self {
+        Self { conn }
+    }
+
+    /// Deliver events sent to `server_id` to this server's rooms.
+    ///
+    /// Runs until aborted, resubscribing if the Redis connection drops.
+    pub fn listen(
+        client: redis::Client,
+        server_id: ServerId,
+        bridge: Arc<WebSocketEventBridge>,
+    ) -> JoinHandle<()> {
+        tokio::spawn(async move {
+            let channel = events_channel(&server_id);
+            loop {
+                if let Err(e) = Self::subscribe(&client, &channel, &bridge).await {
+                    tracing::warn!(channel = %channel, "WebSocket fanout subscription failed: {}", e);
+                }
+                tokio::time::sleep(Duration::from_secs(1)).await;
+            }
+        })
+    }
+
+    async fn subscribe(
+        client: &redis::Client,
+        channel: &str,
+        bridge: &WebSocketEventBridge,
+    ) -> Result<(), redis::RedisError> {
+        let mut pubsub = client.get_async_connection().await?.into_pubsub();
+        pubsub.subscribe(channel).await?;
+        let mut messages = pubsub.on_message();
+
+        while let Some(msg) = messages.next().await {
+            let payload: String = msg.get_payload()?;
+            match serde_json::from_str::<RoutedEvent>(&payload) {
+                Ok(routed) => {
+                    bridge
+                        .deliver_local(&routed.session_id, &routed.event)
+                        .await
+                }
+                Err(e) => tracing::warn!("Dropping malformed WebSocket fanout message: {}", e),
+            }
+        }
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl ServerMessenger for RedisServerMessenger {
+    async fn publish_to_server(
+        &self,
+        server_id: &ServerId,
+        session_id: &SessionId,
+        event: &EventEnvelope,
+    ) -> Result<(), ConnectionRegistryError> {
+        let payload = serde_json::to_string(&RoutedEvent {
+            session_id: *session_id,
+            event: event.clone(),
+        })
+        .map_err(|e| ConnectionRegistryError::Serialization(e.to_string()))?;
+
+        let mut conn = self.conn.clone();
+        conn.publish::<_, _, ()>(events_channel(server_id), payload)
+            .await
+            .map_err(redis_error)
+    }
+}
+
+impl std::fmt::Debug for RedisServerMessenger {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("RedisServerMessenger")
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Note: Redis integration tests require a running Redis instance
+    // and are typically run separately from unit tests.
+
+    #[test]
+    fn keys_are_namespaced_per_server() {
+        let server = ServerId::new("pod-1:8080");
+        assert_eq!(server_users_key(&server), "ws:server:pod-1:8080:users");
+        assert_eq!(events_channel(&server), "ws:events:pod-1:8080");
+    }
+
+    #[test]
+    fn routed_events_round_trip() {
+        let event = EventEnvelope {
+            event_id: crate::domain::foundation::EventId::new(),
+            event_type: "cycle.created".to_string(),
+            schema_version: 1,
+            aggregate_id: "cycle-1".to_string(),
+            aggregate_type: "Cycle".to_string(),
+            occurred_at: Timestamp::now(),
+            payload: serde_json::json!({}),
+            metadata: Default::default(),
+        };
+        let routed = RoutedEvent {
+            session_id: SessionId::new(),
+            event,
+        };
+
+        let json = serde_json::to_string(&routed).unwrap();
+        let back: RoutedEvent = serde_json::from_str(&json).unwrap();
+        assert_eq!(back.session_id, routed.session_id);
+        assert_eq!(back.event.event_type, "cycle.created");
+    }
+}
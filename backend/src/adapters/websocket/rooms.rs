@@ -13,15 +13,25 @@
 //! ```
 //!
 //! When an event occurs for session-123, only clients a, b, c receive it.
+//!
+//! Each room also tracks who is present. Clients that join with presence
+//! are announced to the room, can report what they are working on, and
+//! are announced again when they leave.
+//!
+//! Operator announcements go to every connection regardless of room; each
+//! connection decides whether its user is in the audience.
 
 use std::collections::HashMap;
 
 use tokio::sync::{broadcast, RwLock};
 use uuid::Uuid;
 
-use crate::domain::foundation::SessionId;
+use crate::domain::foundation::{SessionId, Timestamp};
 
-use super::messages::DashboardUpdate;
+use super::announcements::Announcement;
+use super::messages::{
+    DashboardUpdate, Participant, PresenceActivity, PresenceChange, PresenceEvent,
+};
 
 /// Unique identifier for a WebSocket client connection.
 ///
@@ -54,11 +64,42 @@ impl std::fmt::Display for ClientId {
     }
 }
 
+/// A session room: its broadcast channels and who is present.
+struct Room {
+    updates: broadcast::Sender<DashboardUpdate>,
+    presence: broadcast::Sender<PresenceEvent>,
+    /// In arrival order.
+    participants: Vec<Participant>,
+}
+
+impl Room {
+    fn new(capacity: usize) -> Self {
+        let (updates, _) = broadcast::channel(capacity);
+        let (presence, _) = broadcast::channel(capacity);
+        Self {
+            updates,
+            presence,
+            participants: Vec::new(),
+        }
+    }
+
+    fn announce(&self, change: PresenceChange, participant: Participant) {
+        // Ignore send errors (no receivers is OK)
+        let _ = self.presence.send(PresenceEvent {
+            change,
+            participant,
+            timestamp: Timestamp::now(),
+        });
+    }
+}
+
 /// Manages WebSocket connection rooms organized by session.
 ///
 /// Provides:
 /// - Client join/leave operations
 /// - Broadcast to all clients in a session room
+/// - Presence: join/leave/activity events and a roster per room
+/// - Announcements to all connections
 /// - Automatic cleanup of empty rooms
 ///
 /// # Thread Safety
@@ -67,12 +108,15 @@ impl std::fmt::Display for ClientId {
 /// outnumber joins/leaves (writes). This allows concurrent broadcasts
 /// to different rooms.
 pub struct RoomManager {
-    /// Map of session_id → broadcast sender for that room.
-    rooms: RwLock<HashMap<SessionId, broadcast::Sender<DashboardUpdate>>>,
+    /// Map of session_id → channels and participants for that room.
+    rooms: RwLock<HashMap<SessionId, Room>>,
 
     /// Map of client_id → session_id for O(1) cleanup on disconnect.
     client_sessions: RwLock<HashMap<ClientId, SessionId>>,
 
+    /// Operator announcements for every connection.
+    announcements: broadcast::Sender<Announcement>,
+
     /// Channel capacity for each room's broadcast channel.
     channel_capacity: usize,
 }
@@ -86,9 +130,11 @@ impl RoomManager {
     ///   Larger values handle bursts better but use more memory.
     ///   Recommended: 100-256 for typical dashboard update rates.
     pub fn new(channel_capacity: usize) -> Self {
+        let (announcements, _) = broadcast::channel(channel_capacity);
         Self {
             rooms: RwLock::new(HashMap::new()),
             client_sessions: RwLock::new(HashMap::new()),
+            announcements,
             channel_capacity,
         }
     }
@@ -119,10 +165,9 @@ impl RoomManager {
         let mut rooms = self.rooms.write().await;
 
         // Get or create room
-        let sender = rooms.entry(*session_id).or_insert_with(|| {
-            let (tx, _) = broadcast::channel(self.channel_capacity);
-            tx
-        });
+        let room = rooms
+            .entry(*session_id)
+            .or_insert_with(|| Room::new(self.channel_capacity));
 
         // Track client's session for cleanup
         self.client_sessions
@@ -130,11 +175,72 @@ impl RoomManager {
             .await
             .insert(client_id, *session_id);
 
-        sender.subscribe()
+        room.updates.subscribe()
+    }
+
+    /// Join a session room and announce the participant to it.
+    ///
+    /// Returns receivers for dashboard updates and presence events. The
+    /// joining client receives its own `Joined` event.
+    pub async fn join_with_presence(
+        &self,
+        session_id: &SessionId,
+        participant: Participant,
+    ) -> (
+        broadcast::Receiver<DashboardUpdate>,
+        broadcast::Receiver<PresenceEvent>,
+    ) {
+        let updates = self.join(session_id, participant.client_id.clone()).await;
+
+        let mut rooms = self.rooms.write().await;
+        let room = rooms
+            .entry(*session_id)
+            .or_insert_with(|| Room::new(self.channel_capacity));
+        let presence = room.presence.subscribe();
+        room.participants.push(participant.clone());
+        room.announce(PresenceChange::Joined, participant);
+
+        (updates, presence)
+    }
+
+    /// Record what a client is doing and tell the room.
+    ///
+    /// No-op for clients that joined without presence or have left.
+    pub async fn update_activity(&self, client_id: &ClientId, activity: PresenceActivity) {
+        let Some(session_id) = self.client_sessions.read().await.get(client_id).copied() else {
+            return;
+        };
+        let mut rooms = self.rooms.write().await;
+        let Some(room) = rooms.get_mut(&session_id) else {
+            return;
+        };
+        let Some(participant) = room
+            .participants
+            .iter_mut()
+            .find(|p| &p.client_id == client_id)
+        else {
+            return;
+        };
+        if participant.activity == activity {
+            return;
+        }
+        participant.activity = activity;
+        let participant = participant.clone();
+        room.announce(PresenceChange::Updated, participant);
+    }
+
+    /// Everyone present in a session room, earliest arrival first.
+    pub async fn roster(&self, session_id: &SessionId) -> Vec<Participant> {
+        let rooms = self.rooms.read().await;
+        rooms
+            .get(session_id)
+            .map(|room| room.participants.clone())
+            .unwrap_or_default()
     }
 
     /// Remove a client from their session room.
     ///
+    /// Participants who joined with presence are announced as having left.
     /// If the room becomes empty, it's automatically cleaned up.
     ///
     /// # Arguments
@@ -144,12 +250,20 @@ impl RoomManager {
         let mut client_sessions = self.client_sessions.write().await;
 
         if let Some(session_id) = client_sessions.remove(client_id) {
-            // Check if room is empty and clean up
-            let rooms = self.rooms.read().await;
-            if let Some(sender) = rooms.get(&session_id) {
-                if sender.receiver_count() == 0 {
-                    drop(rooms);
-                    self.rooms.write().await.remove(&session_id);
+            let mut rooms = self.rooms.write().await;
+            if let Some(room) = rooms.get_mut(&session_id) {
+                if let Some(index) = room
+                    .participants
+                    .iter()
+                    .position(|p| &p.client_id == client_id)
+                {
+                    let participant = room.participants.remove(index);
+                    room.announce(PresenceChange::Left, participant);
+                }
+
+                // Check if room is empty and clean up
+                if room.updates.receiver_count() == 0 && room.participants.is_empty() {
+                    rooms.remove(&session_id);
                 }
             }
         }
@@ -168,12 +282,23 @@ impl RoomManager {
     pub async fn broadcast_to_session(&self, session_id: &SessionId, update: DashboardUpdate) {
         let rooms = self.rooms.read().await;
 
-        if let Some(sender) = rooms.get(session_id) {
+        if let Some(room) = rooms.get(session_id) {
             // Ignore send errors (no receivers is OK)
-            let _ = sender.send(update);
+            let _ = room.updates.send(update);
         }
     }
 
+    /// Send an announcement to every connection in every room.
+    pub fn announce(&self, announcement: Announcement) {
+        // Ignore send errors (no receivers is OK)
+        let _ = self.announcements.send(announcement);
+    }
+
+    /// Receive announcements sent after this call.
+    pub fn subscribe_announcements(&self) -> broadcast::Receiver<Announcement> {
+        self.announcements.subscribe()
+    }
+
     /// Get count of connected clients in a specific room.
     ///
     /// # Arguments
@@ -187,7 +312,7 @@ impl RoomManager {
         let rooms = self.rooms.read().await;
         rooms
             .get(session_id)
-            .map(|s| s.receiver_count())
+            .map(|room| room.updates.receiver_count())
             .unwrap_or(0)
     }
 
@@ -212,7 +337,7 @@ impl Default for RoomManager {
 mod tests {
     use super::*;
     use crate::adapters::websocket::messages::DashboardUpdateType;
-    use crate::domain::foundation::Timestamp;
+    use crate::domain::foundation::{ComponentType, Timestamp};
     use std::sync::Arc;
     use tokio::sync::broadcast;
 
@@ -376,6 +501,73 @@ mod tests {
         assert!(rooms.contains(&session_3));
     }
 
+    fn participant(name: &str) -> Participant {
+        Participant::new(ClientId::new(), None, Some(name.to_string()))
+    }
+
+    #[tokio::test]
+    async fn presence_announces_joins_updates_and_leaves() {
+        let manager = RoomManager::with_default_capacity();
+        let session_id = SessionId::new();
+        let (_updates, mut presence) = manager
+            .join_with_presence(&session_id, participant("Ana"))
+            .await;
+        let bea = participant("Bea");
+        let bea_id = bea.client_id.clone();
+        let _bea_rx = manager.join_with_presence(&session_id, bea).await;
+
+        manager
+            .update_activity(
+                &bea_id,
+                PresenceActivity::Editing {
+                    component_type: ComponentType::Objectives,
+                },
+            )
+            .await;
+        manager.leave(&bea_id).await;
+
+        let changes: Vec<_> = [
+            presence.recv().await.unwrap(),
+            presence.recv().await.unwrap(),
+            presence.recv().await.unwrap(),
+            presence.recv().await.unwrap(),
+        ]
+        .into_iter()
+        .map(|e| (e.change, e.participant.display_name.unwrap()))
+        .collect();
+        assert_eq!(
+            changes,
+            vec![
+                (PresenceChange::Joined, "Ana".to_string()),
+                (PresenceChange::Joined, "Bea".to_string()),
+                (PresenceChange::Updated, "Bea".to_string()),
+                (PresenceChange::Left, "Bea".to_string()),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn roster_lists_participants_in_arrival_order() {
+        let manager = RoomManager::with_default_capacity();
+        let session_id = SessionId::new();
+        let _ana = manager
+            .join_with_presence(&session_id, participant("Ana"))
+            .await;
+        let _bea = manager
+            .join_with_presence(&session_id, participant("Bea"))
+            .await;
+        // Connections without presence are not listed
+        let _rx = manager.join(&session_id, ClientId::new()).await;
+
+        let roster = manager.roster(&session_id).await;
+        let names: Vec<_> = roster
+            .iter()
+            .map(|p| p.display_name.as_deref().unwrap())
+            .collect();
+        assert_eq!(names, vec!["Ana", "Bea"]);
+        assert!(manager.roster(&SessionId::new()).await.is_empty());
+    }
+
     #[tokio::test]
     async fn client_id_display_works() {
         let client_id = ClientId::new();
@@ -61,6 +61,7 @@ impl EndConversationHandler {
         Self { storage }
     }
 
+    #[tracing::instrument(name = "EndConversationHandler::handle", skip_all)]
     pub async fn handle(&self, cmd: EndConversationCommand) -> Result<(), EndConversationError> {
         // 1. Verify conversation exists
         if !self.storage.exists(cmd.cycle_id).await? {
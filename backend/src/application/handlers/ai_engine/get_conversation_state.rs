@@ -68,6 +68,7 @@ impl GetConversationStateHandler {
         Self { storage }
     }
 
+    #[tracing::instrument(name = "GetConversationStateHandler::handle", skip_all)]
     pub async fn handle(
         &self,
         query: GetConversationStateQuery,
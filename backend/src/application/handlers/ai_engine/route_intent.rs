@@ -72,6 +72,7 @@ impl RouteIntentHandler {
         Self { storage }
     }
 
+    #[tracing::instrument(name = "RouteIntentHandler::handle", skip_all)]
     pub async fn handle(
         &self,
         cmd: RouteIntentCommand,
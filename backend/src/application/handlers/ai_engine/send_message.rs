@@ -90,6 +90,7 @@ impl<P: ?Sized + AIProvider> SendMessageHandler<P> {
         }
     }
 
+    #[tracing::instrument(name = "SendMessageHandler::handle", skip_all)]
     pub async fn handle(
         &self,
         cmd: SendMessageCommand,
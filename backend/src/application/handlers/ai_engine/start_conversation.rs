@@ -67,6 +67,7 @@ impl StartConversationHandler {
         Self { storage }
     }
 
+    #[tracing::instrument(name = "StartConversationHandler::handle", skip_all)]
     pub async fn handle(
         &self,
         cmd: StartConversationCommand,
@@ -5,6 +5,9 @@
 //! - Tradeoffs completion → Tradeoff analysis → TradeoffsAnalyzed
 //! - DecisionQuality completion → DQ calculation → DQScoresComputed
 //!
+//! `ReanalysisRequested` events re-run the same analysis for one component,
+//! so a reanalysis queued after objective weights change reuses this path.
+//!
 //! This enables the dashboard to show computed analysis results in real-time.
 
 use std::sync::Arc;
@@ -15,7 +18,7 @@ use tracing::debug;
 
 use crate::domain::analysis::{
     ConsequencesTable, ConsequencesTableBuilder, DQCalculator, DQElement,
-    DQElementScore, DQScoresComputed, PughAnalyzer, PughScoresComputed,
+    DQElementScore, DQScoresComputed, PughAnalyzer, PughScoresComputed, ReanalysisRequested,
     TensionSummary, TradeoffAnalyzer, TradeoffsAnalyzed,
 };
 use crate::domain::foundation::{
@@ -24,6 +27,9 @@ use crate::domain::foundation::{
 };
 use crate::ports::{CycleReader, EventHandler, EventPublisher};
 
+/// Event type of `ReanalysisRequested`.
+const REANALYSIS_REQUESTED: &str = "analysis.reanalysis_requested.v1";
+
 /// External ComponentCompleted event from the Cycle module.
 ///
 /// This is the expected payload format for `component.completed` events.
@@ -394,19 +400,27 @@ impl AnalysisTriggerHandler {
 #[async_trait]
 impl EventHandler for AnalysisTriggerHandler {
     async fn handle(&self, event: EventEnvelope) -> Result<(), DomainError> {
-        // Parse component completed event
-        let payload: ComponentCompletedPayload = serde_json::from_value(event.payload.clone())
-            .map_err(|e| DomainError::new(ErrorCode::ValidationFailed, e.to_string()))?;
+        // Parse component completed or reanalysis requested event
+        let (cycle_id, component_type) = if event.event_type == REANALYSIS_REQUESTED {
+            let payload: ReanalysisRequested = serde_json::from_value(event.payload.clone())
+                .map_err(|e| DomainError::new(ErrorCode::ValidationFailed, e.to_string()))?;
+            (payload.cycle_id, payload.component_type)
+        } else {
+            let payload: ComponentCompletedPayload =
+                serde_json::from_value(event.payload.clone())
+                    .map_err(|e| DomainError::new(ErrorCode::ValidationFailed, e.to_string()))?;
+            (payload.cycle_id, payload.component_type)
+        };
 
         // Get cycle info to find session_id
         let cycle_view = self
             .cycle_reader
-            .get_by_id(&payload.cycle_id)
+            .get_by_id(&cycle_id)
             .await?
             .ok_or_else(|| {
                 DomainError::new(
                     ErrorCode::CycleNotFound,
-                    format!("Cycle not found: {}", payload.cycle_id),
+                    format!("Cycle not found: {}", cycle_id),
                 )
             })?;
 
@@ -414,23 +428,23 @@ impl EventHandler for AnalysisTriggerHandler {
         let causation_id = event.event_id.as_str();
 
         // Handle based on component type
-        match payload.component_type {
+        match component_type {
             ComponentType::Consequences => {
-                self.handle_consequences_completed(payload.cycle_id, session_id, causation_id)
+                self.handle_consequences_completed(cycle_id, session_id, causation_id)
                     .await?;
             }
             ComponentType::DecisionQuality => {
-                self.handle_dq_completed(payload.cycle_id, session_id, causation_id)
+                self.handle_dq_completed(cycle_id, session_id, causation_id)
                     .await?;
             }
             ComponentType::Tradeoffs => {
-                self.handle_tradeoffs_completed(payload.cycle_id, session_id, causation_id)
+                self.handle_tradeoffs_completed(cycle_id, session_id, causation_id)
                     .await?;
             }
             _ => {
                 // Other component types don't trigger analysis
                 debug!(
-                    component_type = ?payload.component_type,
+                    component_type = ?component_type,
                     "Component completion does not trigger analysis"
                 );
             }
@@ -447,7 +461,7 @@ impl EventHandler for AnalysisTriggerHandler {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::domain::foundation::{ComponentStatus, CycleStatus};
+    use crate::domain::foundation::{ComponentStatus, CycleStatus, OrganizationId};
     use crate::ports::{
         ComponentOutputView, CycleProgressView, CycleSummary, CycleTreeNode, CycleView,
     };
@@ -508,6 +522,13 @@ mod tests {
             Ok(vec![])
         }
 
+        async fn list_by_organization(
+            &self,
+            _organization_id: &OrganizationId,
+        ) -> Result<HashMap<SessionId, Vec<CycleSummary>>, DomainError> {
+            Ok(HashMap::new())
+        }
+
         async fn get_tree(
             &self,
             _session_id: &SessionId,
@@ -847,4 +868,34 @@ mod tests {
             Some("original-event-123".to_string())
         );
     }
+
+    #[tokio::test]
+    async fn reruns_analysis_on_reanalysis_requested() {
+        let cycle_view = test_cycle_view();
+        let cycle_id = cycle_view.id;
+        let session_id = cycle_view.session_id;
+
+        let reader = Arc::new(MockCycleReader::with_cycle_and_output(
+            cycle_view,
+            ComponentType::Consequences,
+            consequences_table_output(),
+        ));
+        let publisher = Arc::new(MockEventPublisher::new());
+        let handler = AnalysisTriggerHandler::new(reader, publisher.clone());
+
+        let event = ReanalysisRequested {
+            event_id: EventId::new(),
+            cycle_id,
+            session_id,
+            component_type: ComponentType::Tradeoffs,
+            trigger: crate::domain::analysis::ReanalysisTrigger::ObjectiveWeightsChanged,
+            requested_at: Timestamp::now(),
+        }
+        .to_envelope();
+        handler.handle(event).await.unwrap();
+
+        let events = publisher.published_events();
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].event_type, "analysis.tradeoffs_analyzed.v1");
+    }
 }
@@ -0,0 +1,193 @@
+//! Audit log query handlers - Listing entries and verifying the chain.
+
+use std::sync::Arc;
+
+use crate::domain::audit::{verify_chain, AuditChainBreak, AuditEntry};
+use crate::domain::foundation::DomainError;
+use crate::ports::{AuditLog, AuditPage, AuditPseudonymKeys, AuditQuery};
+
+/// Entries read per batch while verifying.
+pub const VERIFY_BATCH_SIZE: u32 = 500;
+
+/// Handler for listing audit entries.
+pub struct ListAuditEntriesHandler {
+    log: Arc<dyn AuditLog>,
+    keys: Arc<dyn AuditPseudonymKeys>,
+}
+
+impl ListAuditEntriesHandler {
+    pub fn new(log: Arc<dyn AuditLog>, keys: Arc<dyn AuditPseudonymKeys>) -> Self {
+        Self { log, keys }
+    }
+
+    /// Entries matching `query`, newest first. Actors are recorded by
+    /// pseudonym, so an actor filter is looked up by theirs; an actor with
+    /// no key (never audited, or erased) has no entries.
+    #[tracing::instrument(name = "ListAuditEntriesHandler::handle", skip_all)]
+    pub async fn handle(&self, mut query: AuditQuery) -> Result<AuditPage, DomainError> {
+        if let Some(actor_id) = query.actor_id.take() {
+            let Some(key) = self.keys.find(&actor_id).await? else {
+                return Ok(AuditPage {
+                    items: Vec::new(),
+                    total: 0,
+                    has_more: false,
+                });
+            };
+            query.actor_id = Some(key.pseudonym(&actor_id));
+        }
+        self.log.list(&query).await
+    }
+}
+
+/// Outcome of verifying the audit log.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AuditVerification {
+    /// Entries checked, up to and including any break.
+    pub entries_checked: u64,
+    /// The first place the chain fails verification, if any.
+    pub first_break: Option<AuditChainBreak>,
+}
+
+impl AuditVerification {
+    pub fn is_intact(&self) -> bool {
+        self.first_break.is_none()
+    }
+}
+
+/// Handler for verifying the audit log's hash chain end to end.
+pub struct VerifyAuditLogHandler {
+    log: Arc<dyn AuditLog>,
+}
+
+impl VerifyAuditLogHandler {
+    pub fn new(log: Arc<dyn AuditLog>) -> Self {
+        Self { log }
+    }
+
+    /// Walks the log oldest first, stopping at the first break.
+    #[tracing::instrument(name = "VerifyAuditLogHandler::handle", skip_all)]
+    pub async fn handle(&self) -> Result<AuditVerification, DomainError> {
+        let mut previous: Option<AuditEntry> = None;
+        let mut entries_checked = 0;
+        loop {
+            let after = previous.as_ref().map_or(0, |p| p.sequence);
+            let batch = self.log.entries_after(after, VERIFY_BATCH_SIZE).await?;
+            let Some(last) = batch.last().cloned() else {
+                break;
+            };
+            if let Err(chain_break) = verify_chain(&batch, previous.as_ref()) {
+                let broken_at = match chain_break {
+                    AuditChainBreak::Tampered { sequence }
+                    | AuditChainBreak::Unlinked { sequence } => sequence,
+                    AuditChainBreak::Missing { found, .. } => found,
+                };
+                entries_checked += batch
+                    .iter()
+                    .position(|e| e.sequence == broken_at)
+                    .map_or(batch.len(), |i| i + 1) as u64;
+                tracing::warn!(error = %chain_break, "Audit log failed verification");
+                return Ok(AuditVerification {
+                    entries_checked,
+                    first_break: Some(chain_break),
+                });
+            }
+            entries_checked += batch.len() as u64;
+            previous = Some(last);
+        }
+        Ok(AuditVerification {
+            entries_checked,
+            first_break: None,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::adapters::{InMemoryAuditLog, InMemoryAuditPseudonymKeys};
+    use crate::application::handlers::CommandAuditor;
+    use crate::domain::audit::AuditRecord;
+    use crate::domain::foundation::{CommandMetadata, UserId};
+
+    async fn log_with(entries: usize) -> Arc<InMemoryAuditLog> {
+        let log = Arc::new(InMemoryAuditLog::new());
+        for i in 0..entries {
+            log.append(AuditRecord::new(
+                &CommandMetadata::test_fixture(),
+                "RenameSession",
+                "session",
+                format!("s-{}", i),
+            ))
+            .await
+            .unwrap();
+        }
+        log
+    }
+
+    #[tokio::test]
+    async fn intact_logs_verify_across_batches() {
+        let log = log_with(VERIFY_BATCH_SIZE as usize + 3).await;
+
+        let verification = VerifyAuditLogHandler::new(log).handle().await.unwrap();
+
+        assert!(verification.is_intact());
+        assert_eq!(verification.entries_checked, VERIFY_BATCH_SIZE as u64 + 3);
+    }
+
+    #[tokio::test]
+    async fn tampering_is_reported_with_its_position() {
+        let log = log_with(5).await;
+        let mut forged = log.entries().await[2].clone();
+        forged.record.actor_id = UserId::new("mallory").unwrap();
+        log.overwrite(forged).await;
+
+        let verification = VerifyAuditLogHandler::new(log).handle().await.unwrap();
+
+        assert!(!verification.is_intact());
+        assert_eq!(verification.entries_checked, 3);
+        assert_eq!(
+            verification.first_break,
+            Some(AuditChainBreak::Tampered { sequence: 3 })
+        );
+    }
+
+    #[tokio::test]
+    async fn lists_entries_through_the_log() {
+        let log = log_with(3).await;
+
+        let page = ListAuditEntriesHandler::new(log, Arc::new(InMemoryAuditPseudonymKeys::new()))
+            .handle(AuditQuery::paginated(1, 2))
+            .await
+            .unwrap();
+
+        assert_eq!(page.items.len(), 2);
+        assert_eq!(page.total, 3);
+        assert!(page.has_more);
+    }
+
+    #[tokio::test]
+    async fn filters_by_actor_until_the_actor_is_erased() {
+        let log = Arc::new(InMemoryAuditLog::new());
+        let keys = Arc::new(InMemoryAuditPseudonymKeys::new());
+        let metadata = CommandMetadata::test_fixture();
+        CommandAuditor::new(log.clone(), keys.clone())
+            .record(AuditRecord::new(
+                &metadata,
+                "RenameSession",
+                "session",
+                "s-1",
+            ))
+            .await;
+        let handler = ListAuditEntriesHandler::new(log, keys.clone());
+        let by_actor = AuditQuery {
+            actor_id: Some(metadata.user_id.clone()),
+            ..AuditQuery::default()
+        };
+
+        assert_eq!(handler.handle(by_actor.clone()).await.unwrap().total, 1);
+
+        keys.erase(&metadata.user_id).await;
+
+        assert_eq!(handler.handle(by_actor).await.unwrap().total, 0);
+    }
+}
@@ -0,0 +1,124 @@
+//! CommandAuditor - Records command handler executions in the audit log.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use crate::domain::audit::AuditRecord;
+use crate::domain::foundation::{DomainError, UserId};
+use crate::ports::{AuditLog, AuditPseudonymKeys};
+
+/// Appends an audit entry for each command a handler runs.
+///
+/// Handlers hold a disabled auditor by default and are given a real one
+/// with their `with_auditor` builder, so wiring the log is opt-in per
+/// deployment rather than per test.
+///
+/// Every user a record names is replaced by their pseudonym before the
+/// record reaches the log.
+#[derive(Clone, Default)]
+pub struct CommandAuditor {
+    log: Option<(Arc<dyn AuditLog>, Arc<dyn AuditPseudonymKeys>)>,
+}
+
+impl CommandAuditor {
+    pub fn new(log: Arc<dyn AuditLog>, keys: Arc<dyn AuditPseudonymKeys>) -> Self {
+        Self {
+            log: Some((log, keys)),
+        }
+    }
+
+    /// An auditor that records nothing.
+    pub fn disabled() -> Self {
+        Self::default()
+    }
+
+    /// Appends `record`. The command has already taken effect by the time
+    /// it is recorded, so a failure to append is logged rather than
+    /// returned.
+    pub async fn record(&self, record: AuditRecord) {
+        let Some((log, keys)) = &self.log else {
+            return;
+        };
+        let command_type = record.command_type.clone();
+        let aggregate_id = record.aggregate_id.clone();
+        let appended = match pseudonyms(keys.as_ref(), &record).await {
+            Ok(pseudonyms) => log.append(record.pseudonymized(&pseudonyms)).await,
+            Err(error) => Err(error),
+        };
+        if let Err(error) = appended {
+            tracing::error!(
+                command_type = %command_type,
+                aggregate_id = %aggregate_id,
+                error = %error,
+                "Failed to record command in the audit log"
+            );
+        }
+    }
+}
+
+/// The pseudonym of every user `record` names.
+async fn pseudonyms(
+    keys: &dyn AuditPseudonymKeys,
+    record: &AuditRecord,
+) -> Result<HashMap<UserId, UserId>, DomainError> {
+    let mut pseudonyms = HashMap::new();
+    for user_id in record.user_ids() {
+        let pseudonym = keys.key_for(&user_id).await?.pseudonym(&user_id);
+        pseudonyms.insert(user_id, pseudonym);
+    }
+    Ok(pseudonyms)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::adapters::{InMemoryAuditLog, InMemoryAuditPseudonymKeys};
+    use crate::domain::foundation::CommandMetadata;
+
+    fn record() -> AuditRecord {
+        AuditRecord::new(
+            &CommandMetadata::test_fixture(),
+            "ArchiveSession",
+            "session",
+            "s-1",
+        )
+    }
+
+    #[tokio::test]
+    async fn appends_records_to_the_log() {
+        let log = Arc::new(InMemoryAuditLog::new());
+        let auditor = CommandAuditor::new(log.clone(), Arc::new(InMemoryAuditPseudonymKeys::new()));
+
+        auditor.record(record()).await;
+
+        let entries = log.entries().await;
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].record.command_type, "ArchiveSession");
+    }
+
+    #[tokio::test]
+    async fn records_name_users_by_pseudonym() {
+        let log = Arc::new(InMemoryAuditLog::new());
+        let keys = Arc::new(InMemoryAuditPseudonymKeys::new());
+        let auditor = CommandAuditor::new(log.clone(), keys.clone());
+        let actor = CommandMetadata::test_fixture().user_id;
+
+        auditor
+            .record(record().with_after(serde_json::json!({
+                "members": [{ "user_id": "member-1", "role": "owner" }],
+            })))
+            .await;
+
+        let entry = log.entries().await.remove(0);
+        let text = serde_json::to_string(&entry.record).unwrap();
+        assert!(!text.contains(actor.as_str()));
+        assert!(!text.contains("member-1"));
+        let key = keys.find(&actor).await.unwrap().unwrap();
+        assert_eq!(entry.record.actor_id, key.pseudonym(&actor));
+    }
+
+    #[tokio::test]
+    async fn disabled_auditors_record_nothing() {
+        CommandAuditor::disabled().record(record()).await;
+    }
+}
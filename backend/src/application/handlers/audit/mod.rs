@@ -0,0 +1,17 @@
+//! Audit log handlers.
+//!
+//! ## Recording
+//! - `CommandAuditor`, given to command handlers, appends an entry for each
+//!   command they run
+//!
+//! ## Queries
+//! - List audit entries, filtered by actor, aggregate or command
+//! - Verify the hash chain across the whole log
+
+mod audit_queries;
+mod command_auditor;
+
+pub use audit_queries::{
+    AuditVerification, ListAuditEntriesHandler, VerifyAuditLogHandler, VERIFY_BATCH_SIZE,
+};
+pub use command_auditor::CommandAuditor;
@@ -0,0 +1,87 @@
+//! LogoutAllHandler - Command handler for revoking all of a user's tokens.
+
+use std::sync::Arc;
+
+use crate::domain::foundation::{DomainError, Timestamp, UserId};
+use crate::ports::TokenRevocationStore;
+
+/// Command to log a user out of every device.
+#[derive(Debug, Clone)]
+pub struct LogoutAllCommand {
+    pub user_id: UserId,
+}
+
+/// Result of revoking a user's tokens.
+#[derive(Debug, Clone)]
+pub struct LogoutAllResult {
+    /// Tokens issued at or before this time are no longer accepted.
+    pub revoked_at: Timestamp,
+}
+
+/// Handler for logging a user out everywhere.
+///
+/// Revocation covers the token used for the request too, so the caller must
+/// sign in again afterwards.
+pub struct LogoutAllHandler {
+    revocations: Arc<dyn TokenRevocationStore>,
+}
+
+impl LogoutAllHandler {
+    pub fn new(revocations: Arc<dyn TokenRevocationStore>) -> Self {
+        Self { revocations }
+    }
+
+    #[tracing::instrument(name = "LogoutAllHandler::handle", skip_all)]
+    pub async fn handle(&self, cmd: LogoutAllCommand) -> Result<LogoutAllResult, DomainError> {
+        let revoked_at = Timestamp::now();
+        self.revocations
+            .revoke_all_for_user(&cmd.user_id, revoked_at)
+            .await?;
+
+        tracing::info!(user_id = %cmd.user_id, "Revoked all tokens for user");
+
+        Ok(LogoutAllResult { revoked_at })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::adapters::InMemoryTokenRevocationStore;
+    use crate::ports::is_token_revoked;
+
+    #[tokio::test]
+    async fn revokes_tokens_issued_before_logout() {
+        let store = Arc::new(InMemoryTokenRevocationStore::new());
+        let handler = LogoutAllHandler::new(store.clone());
+        let user_id = UserId::new("user-1").unwrap();
+        let issued_at = Timestamp::from_unix_secs(Timestamp::now().as_unix_secs());
+
+        let result = handler
+            .handle(LogoutAllCommand {
+                user_id: user_id.clone(),
+            })
+            .await
+            .unwrap();
+
+        let cutoff = store.revoked_before(&user_id).await.unwrap();
+        assert_eq!(cutoff, Some(result.revoked_at));
+        assert!(is_token_revoked(Some(&issued_at), cutoff.as_ref()));
+    }
+
+    #[tokio::test]
+    async fn leaves_other_users_signed_in() {
+        let store = Arc::new(InMemoryTokenRevocationStore::new());
+        let handler = LogoutAllHandler::new(store.clone());
+
+        handler
+            .handle(LogoutAllCommand {
+                user_id: UserId::new("user-1").unwrap(),
+            })
+            .await
+            .unwrap();
+
+        let other = UserId::new("user-2").unwrap();
+        assert_eq!(store.revoked_before(&other).await.unwrap(), None);
+    }
+}
@@ -0,0 +1,8 @@
+//! Authentication command handlers.
+//!
+//! ## Commands
+//! - Log out everywhere by revoking all of a user's tokens
+
+mod logout_all;
+
+pub use logout_all::{LogoutAllCommand, LogoutAllHandler, LogoutAllResult};
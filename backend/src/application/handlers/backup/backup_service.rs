@@ -0,0 +1,391 @@
+//! BackupService - Admin-triggered backup and restore of a whole instance.
+//!
+//! A backup collects every registered `BackupSource` into a
+//! `BackupArchive`, adds every stored document except earlier archives,
+//! and writes the archive to document storage under `backups/`. Restoring
+//! puts the documents back first, so cold storage snapshots are in place
+//! before the sessions pointing at them, then hands each section to its
+//! source in registration order.
+
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+use crate::domain::backup::{BackupArchive, BackupSummary, BACKUP_KEY_PREFIX};
+use crate::domain::foundation::{BackupId, DomainError};
+use crate::ports::{BackupSource, DocumentStorage, DocumentStorageError};
+
+/// Error type for backing up and restoring.
+#[derive(Debug, Clone)]
+pub enum BackupError {
+    /// No archive is stored under this ID.
+    NotFound(BackupId),
+    /// The archive is malformed or from a newer format.
+    InvalidArchive(String),
+    /// The archive holds a section no registered source restores.
+    UnknownSection(String),
+    /// Document storage failure.
+    Storage(DocumentStorageError),
+    /// Source failure.
+    Domain(DomainError),
+}
+
+impl std::fmt::Display for BackupError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            BackupError::NotFound(id) => write!(f, "Backup not found: {}", id),
+            BackupError::InvalidArchive(msg) => write!(f, "Invalid backup archive: {}", msg),
+            BackupError::UnknownSection(section) => {
+                write!(f, "Backup section has no source: {}", section)
+            }
+            BackupError::Storage(err) => write!(f, "{}", err),
+            BackupError::Domain(err) => write!(f, "{}", err),
+        }
+    }
+}
+
+impl std::error::Error for BackupError {}
+
+impl From<DomainError> for BackupError {
+    fn from(err: DomainError) -> Self {
+        BackupError::Domain(err)
+    }
+}
+
+impl From<DocumentStorageError> for BackupError {
+    fn from(err: DocumentStorageError) -> Self {
+        BackupError::Storage(err)
+    }
+}
+
+/// Backs up and restores every registered source and stored document.
+pub struct BackupService {
+    sources: Vec<Arc<dyn BackupSource>>,
+    storage: Arc<dyn DocumentStorage>,
+}
+
+impl BackupService {
+    /// `sources` are restored in this order; see [`BackupSource`].
+    pub fn new(sources: Vec<Arc<dyn BackupSource>>, storage: Arc<dyn DocumentStorage>) -> Self {
+        Self { sources, storage }
+    }
+
+    /// Takes a backup and stores it, returning what it holds.
+    #[tracing::instrument(name = "BackupService::create", skip_all)]
+    pub async fn create(&self) -> Result<BackupSummary, BackupError> {
+        let mut archive = BackupArchive::new();
+        for source in &self.sources {
+            archive.insert(source.section(), source.export().await?);
+        }
+        for key in self.storage.list("").await? {
+            if key.starts_with(BACKUP_KEY_PREFIX) {
+                continue;
+            }
+            let document = self.storage.get(&key).await?;
+            archive.add_document(document.key, document.content_type, document.bytes);
+        }
+
+        self.storage
+            .put(
+                &archive.storage_key(),
+                "application/json",
+                archive.to_json_bytes(),
+            )
+            .await?;
+        Ok(archive.summary())
+    }
+
+    /// IDs of stored backups.
+    pub async fn list(&self) -> Result<Vec<BackupId>, BackupError> {
+        Ok(self
+            .storage
+            .list(BACKUP_KEY_PREFIX)
+            .await?
+            .iter()
+            .filter_map(|key| BackupArchive::id_from_storage_key(key))
+            .collect())
+    }
+
+    /// A stored archive's bytes, for download.
+    pub async fn download(&self, backup_id: &BackupId) -> Result<Vec<u8>, BackupError> {
+        match self
+            .storage
+            .get(&BackupArchive::storage_key_for(backup_id))
+            .await
+        {
+            Ok(document) => Ok(document.bytes),
+            Err(DocumentStorageError::NotFound(_)) => Err(BackupError::NotFound(*backup_id)),
+            Err(err) => Err(err.into()),
+        }
+    }
+
+    /// Restores an archive, returning how much was restored. Nothing is
+    /// written if the archive cannot be read or has sections no source
+    /// handles.
+    #[tracing::instrument(name = "BackupService::restore", skip_all)]
+    pub async fn restore(&self, bytes: &[u8]) -> Result<BackupSummary, BackupError> {
+        let archive = BackupArchive::from_json_bytes(bytes)
+            .map_err(|e| BackupError::InvalidArchive(e.message))?;
+        if let Some(section) = archive
+            .sections
+            .keys()
+            .find(|section| !self.sources.iter().any(|s| s.section() == *section))
+        {
+            return Err(BackupError::UnknownSection(section.clone()));
+        }
+
+        let mut documents = 0;
+        for document in archive.documents {
+            if document.key.starts_with(BACKUP_KEY_PREFIX) {
+                continue;
+            }
+            self.storage
+                .put(&document.key, &document.content_type, document.bytes)
+                .await?;
+            documents += 1;
+        }
+
+        let mut sections = archive.sections;
+        let mut records = BTreeMap::new();
+        for source in &self.sources {
+            let Some(section) = sections.remove(source.section()) else {
+                continue;
+            };
+            let restored = source.restore(section).await?;
+            records.insert(source.section().to_string(), restored);
+        }
+
+        Ok(BackupSummary {
+            backup_id: archive.backup_id,
+            created_at: archive.created_at,
+            records,
+            documents,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use async_trait::async_trait;
+    use std::sync::Mutex;
+
+    use crate::adapters::storage::InMemoryDocumentStorage;
+    use crate::domain::foundation::ErrorCode;
+
+    /// Sections in the order they were restored.
+    type RestoreLog = Arc<Mutex<Vec<&'static str>>>;
+
+    /// Holds records in memory, noting the order restores happen in.
+    struct InMemorySource {
+        section: &'static str,
+        records: Mutex<Vec<serde_json::Value>>,
+        restore_log: RestoreLog,
+    }
+
+    impl InMemorySource {
+        fn new(
+            section: &'static str,
+            records: Vec<serde_json::Value>,
+            restore_log: RestoreLog,
+        ) -> Arc<Self> {
+            Arc::new(Self {
+                section,
+                records: Mutex::new(records),
+                restore_log,
+            })
+        }
+    }
+
+    #[async_trait]
+    impl BackupSource for InMemorySource {
+        fn section(&self) -> &'static str {
+            self.section
+        }
+
+        async fn export(&self) -> Result<Vec<serde_json::Value>, DomainError> {
+            Ok(self.records.lock().unwrap().clone())
+        }
+
+        async fn restore(&self, records: Vec<serde_json::Value>) -> Result<usize, DomainError> {
+            self.restore_log.lock().unwrap().push(self.section);
+            let count = records.len();
+            *self.records.lock().unwrap() = records;
+            Ok(count)
+        }
+    }
+
+    /// Membership and session sources with a few records, the document
+    /// storage, and the log of the order sources are restored in.
+    fn setup_repositories() -> (
+        Arc<InMemorySource>,
+        Arc<InMemorySource>,
+        Arc<InMemoryDocumentStorage>,
+        RestoreLog,
+    ) {
+        let restore_log = Arc::new(Mutex::new(Vec::new()));
+        let memberships = InMemorySource::new(
+            "memberships",
+            vec![serde_json::json!({"id": "m-1"})],
+            restore_log.clone(),
+        );
+        let sessions = InMemorySource::new(
+            "sessions",
+            vec![
+                serde_json::json!({"id": "s-1"}),
+                serde_json::json!({"id": "s-2"}),
+            ],
+            restore_log.clone(),
+        );
+        (
+            memberships,
+            sessions,
+            Arc::new(InMemoryDocumentStorage::new()),
+            restore_log,
+        )
+    }
+
+    fn create_handler(
+        memberships: Arc<InMemorySource>,
+        sessions: Arc<InMemorySource>,
+        storage: Arc<InMemoryDocumentStorage>,
+    ) -> BackupService {
+        BackupService::new(vec![memberships, sessions], storage)
+    }
+
+    #[tokio::test]
+    async fn backups_hold_every_section_and_document() {
+        let (memberships, sessions, storage, _) = setup_repositories();
+        let service = create_handler(memberships, sessions, storage.clone());
+        storage
+            .put(
+                "exports/c1/decision.pdf",
+                "application/pdf",
+                b"%PDF".to_vec(),
+            )
+            .await
+            .unwrap();
+
+        let summary = service.create().await.unwrap();
+
+        assert_eq!(summary.records["memberships"], 1);
+        assert_eq!(summary.records["sessions"], 2);
+        assert_eq!(summary.documents, 1);
+        assert_eq!(service.list().await.unwrap(), vec![summary.backup_id]);
+        let bytes = service.download(&summary.backup_id).await.unwrap();
+        let archive = BackupArchive::from_json_bytes(&bytes).unwrap();
+        assert_eq!(archive.documents[0].bytes, b"%PDF");
+    }
+
+    #[tokio::test]
+    async fn earlier_backups_are_left_out() {
+        let (memberships, sessions, storage, _) = setup_repositories();
+        let service = create_handler(memberships, sessions, storage);
+        service.create().await.unwrap();
+
+        let summary = service.create().await.unwrap();
+
+        assert_eq!(summary.documents, 0);
+        assert_eq!(service.list().await.unwrap().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn restore_replays_sections_in_source_order() {
+        let (memberships, sessions, storage, restore_log) = setup_repositories();
+        let service = create_handler(memberships.clone(), sessions.clone(), storage.clone());
+        storage
+            .put(
+                "exports/c1/decision.pdf",
+                "application/pdf",
+                b"%PDF".to_vec(),
+            )
+            .await
+            .unwrap();
+        let summary = service.create().await.unwrap();
+        let bytes = service.download(&summary.backup_id).await.unwrap();
+        sessions.records.lock().unwrap().clear();
+        storage.delete("exports/c1/decision.pdf").await.unwrap();
+
+        let restored = service.restore(&bytes).await.unwrap();
+
+        assert_eq!(restored.records, summary.records);
+        assert_eq!(restored.documents, 1);
+        assert_eq!(sessions.records.lock().unwrap().len(), 2);
+        assert_eq!(memberships.records.lock().unwrap().len(), 1);
+        assert!(storage.exists("exports/c1/decision.pdf").await.unwrap());
+        assert_eq!(
+            *restore_log.lock().unwrap(),
+            vec!["memberships", "sessions"]
+        );
+    }
+
+    #[tokio::test]
+    async fn archives_with_unknown_sections_are_rejected_before_writing() {
+        let (memberships, sessions, storage, restore_log) = setup_repositories();
+        let service = create_handler(memberships, sessions, storage.clone());
+        let mut archive = BackupArchive::new();
+        archive.insert("sessions", vec![]);
+        archive.insert("telepathy", vec![serde_json::json!({})]);
+        archive.add_document("exports/a.md", "text/markdown", vec![1]);
+
+        let result = service.restore(&archive.to_json_bytes()).await;
+
+        assert!(matches!(result, Err(BackupError::UnknownSection(s)) if s == "telepathy"));
+        assert!(restore_log.lock().unwrap().is_empty());
+        assert!(!storage.exists("exports/a.md").await.unwrap());
+    }
+
+    #[tokio::test]
+    async fn malformed_archives_are_rejected() {
+        let (memberships, sessions, storage, _) = setup_repositories();
+        let service = create_handler(memberships, sessions, storage);
+
+        let result = service.restore(b"{}").await;
+
+        assert!(matches!(result, Err(BackupError::InvalidArchive(_))));
+    }
+
+    #[tokio::test]
+    async fn downloading_a_missing_backup_is_not_found() {
+        let (memberships, sessions, storage, _) = setup_repositories();
+        let service = create_handler(memberships, sessions, storage);
+        let id = BackupId::new();
+
+        let result = service.download(&id).await;
+
+        assert!(matches!(result, Err(BackupError::NotFound(missing)) if missing == id));
+    }
+
+    #[tokio::test]
+    async fn source_failures_surface_as_domain_errors() {
+        struct FailingSource;
+
+        #[async_trait]
+        impl BackupSource for FailingSource {
+            fn section(&self) -> &'static str {
+                "sessions"
+            }
+
+            async fn export(&self) -> Result<Vec<serde_json::Value>, DomainError> {
+                Err(DomainError::new(
+                    ErrorCode::DatabaseError,
+                    "connection reset",
+                ))
+            }
+
+            async fn restore(
+                &self,
+                _records: Vec<serde_json::Value>,
+            ) -> Result<usize, DomainError> {
+                Ok(0)
+            }
+        }
+
+        let storage = Arc::new(InMemoryDocumentStorage::new());
+        let service = BackupService::new(vec![Arc::new(FailingSource)], storage.clone());
+
+        let result = service.create().await;
+
+        assert!(matches!(result, Err(BackupError::Domain(_))));
+        assert_eq!(storage.document_count().await, 0);
+    }
+}
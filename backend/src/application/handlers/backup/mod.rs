@@ -0,0 +1,9 @@
+//! Backup handlers.
+//!
+//! `BackupService` writes every registered `BackupSource` and the contents
+//! of document storage into a `BackupArchive`, and restores archives by
+//! replaying them through the sources.
+
+mod backup_service;
+
+pub use backup_service::{BackupError, BackupService};
@@ -0,0 +1,94 @@
+//! CheckAiConsentHandler - Gate for AI features.
+//!
+//! AI features send decision content to third-party providers, so they are
+//! blocked until the user has accepted the current terms of service, privacy
+//! policy, and AI-processing consent.
+
+use std::sync::Arc;
+
+use crate::domain::consent::{ConsentError, ConsentRequirements, ConsentStatus};
+use crate::domain::foundation::UserId;
+use crate::ports::ConsentRepository;
+
+/// Query to check whether a user may use AI features.
+#[derive(Debug, Clone)]
+pub struct CheckAiConsentQuery {
+    pub user_id: UserId,
+}
+
+/// Handler that enforces AI consent requirements.
+pub struct CheckAiConsentHandler {
+    repository: Arc<dyn ConsentRepository>,
+    requirements: ConsentRequirements,
+}
+
+impl CheckAiConsentHandler {
+    pub fn new(repository: Arc<dyn ConsentRepository>, requirements: ConsentRequirements) -> Self {
+        Self {
+            repository,
+            requirements,
+        }
+    }
+
+    /// Returns `Ok(())` if allowed, or `ConsentError::ConsentRequired` listing
+    /// what is missing.
+    #[tracing::instrument(name = "CheckAiConsentHandler::handle", skip_all)]
+    pub async fn handle(&self, query: CheckAiConsentQuery) -> Result<(), ConsentError> {
+        let records = self.repository.list_for_user(&query.user_id).await?;
+        let missing = ConsentStatus::from_records(records).missing_for_ai(&self.requirements);
+
+        if missing.is_empty() {
+            Ok(())
+        } else {
+            Err(ConsentError::consent_required(missing))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::adapters::InMemoryConsentRepository;
+    use crate::domain::consent::{ConsentRecord, ConsentType};
+
+    async fn seeded(types: &[(ConsentType, &str)]) -> (CheckAiConsentHandler, UserId) {
+        let repo = Arc::new(InMemoryConsentRepository::new());
+        let user = UserId::new("user-1").unwrap();
+        for (t, v) in types {
+            repo.append(&ConsentRecord::grant(user.clone(), *t, *v).unwrap())
+                .await
+                .unwrap();
+        }
+        (
+            CheckAiConsentHandler::new(repo, ConsentRequirements::new("tos-1", "pp-1")),
+            user,
+        )
+    }
+
+    #[tokio::test]
+    async fn allows_when_all_consents_current() {
+        let (handler, user) = seeded(&[
+            (ConsentType::TermsOfService, "tos-1"),
+            (ConsentType::PrivacyPolicy, "pp-1"),
+            (ConsentType::AiProcessing, "1"),
+        ])
+        .await;
+
+        assert!(handler.handle(CheckAiConsentQuery { user_id: user }).await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn blocks_without_ai_processing_consent() {
+        let (handler, user) = seeded(&[
+            (ConsentType::TermsOfService, "tos-1"),
+            (ConsentType::PrivacyPolicy, "pp-1"),
+        ])
+        .await;
+
+        let result = handler.handle(CheckAiConsentQuery { user_id: user }).await;
+        assert_eq!(
+            result,
+            Err(ConsentError::ConsentRequired(vec![ConsentType::AiProcessing]))
+        );
+    }
+}
@@ -0,0 +1,79 @@
+//! ListConsentsHandler - Query handler for a user's consent ledger.
+
+use std::sync::Arc;
+
+use crate::domain::consent::{ConsentError, ConsentRecord, ConsentRequirements, ConsentStatus, ConsentType};
+use crate::domain::foundation::UserId;
+use crate::ports::ConsentRepository;
+
+/// Query to list a user's consents.
+#[derive(Debug, Clone)]
+pub struct ListConsentsQuery {
+    pub user_id: UserId,
+}
+
+/// Full consent history plus derived current state.
+#[derive(Debug, Clone)]
+pub struct ListConsentsResult {
+    /// Every record, oldest first.
+    pub history: Vec<ConsentRecord>,
+    /// Latest state per consent type.
+    pub status: ConsentStatus,
+    /// Consents still required before AI features unlock.
+    pub missing_for_ai: Vec<ConsentType>,
+    /// Document versions currently required.
+    pub requirements: ConsentRequirements,
+}
+
+/// Handler for listing consents.
+pub struct ListConsentsHandler {
+    repository: Arc<dyn ConsentRepository>,
+    requirements: ConsentRequirements,
+}
+
+impl ListConsentsHandler {
+    pub fn new(repository: Arc<dyn ConsentRepository>, requirements: ConsentRequirements) -> Self {
+        Self {
+            repository,
+            requirements,
+        }
+    }
+
+    #[tracing::instrument(name = "ListConsentsHandler::handle", skip_all)]
+    pub async fn handle(&self, query: ListConsentsQuery) -> Result<ListConsentsResult, ConsentError> {
+        let history = self.repository.list_for_user(&query.user_id).await?;
+        let status = ConsentStatus::from_records(history.clone());
+        let missing_for_ai = status.missing_for_ai(&self.requirements);
+
+        Ok(ListConsentsResult {
+            history,
+            status,
+            missing_for_ai,
+            requirements: self.requirements.clone(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::adapters::InMemoryConsentRepository;
+
+    #[tokio::test]
+    async fn returns_history_and_missing_consents() {
+        let repo = Arc::new(InMemoryConsentRepository::new());
+        let user = UserId::new("user-1").unwrap();
+        repo.append(&ConsentRecord::grant(user.clone(), ConsentType::TermsOfService, "tos-1").unwrap())
+            .await
+            .unwrap();
+
+        let handler = ListConsentsHandler::new(repo, ConsentRequirements::new("tos-1", "pp-1"));
+        let result = handler.handle(ListConsentsQuery { user_id: user }).await.unwrap();
+
+        assert_eq!(result.history.len(), 1);
+        assert_eq!(
+            result.missing_for_ai,
+            vec![ConsentType::PrivacyPolicy, ConsentType::AiProcessing]
+        );
+    }
+}
@@ -0,0 +1,16 @@
+//! Consent command and query handlers.
+//!
+//! ## Commands
+//! - Record a consent grant or withdrawal
+//!
+//! ## Queries
+//! - List a user's consent records and current status
+//! - Check whether AI features are allowed for a user
+
+mod check_ai_consent;
+mod list_consents;
+mod record_consent;
+
+pub use check_ai_consent::{CheckAiConsentHandler, CheckAiConsentQuery};
+pub use list_consents::{ListConsentsHandler, ListConsentsQuery, ListConsentsResult};
+pub use record_consent::{RecordConsentCommand, RecordConsentHandler, RecordConsentResult};
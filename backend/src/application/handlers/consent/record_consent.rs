@@ -0,0 +1,165 @@
+//! RecordConsentHandler - Command handler for granting or withdrawing consent.
+
+use std::sync::Arc;
+
+use crate::application::handlers::audit::CommandAuditor;
+use crate::domain::audit::{AuditRecord, AuditSummary};
+use crate::domain::consent::{ConsentError, ConsentRecord, ConsentRecorded, ConsentType};
+use crate::domain::foundation::{CommandMetadata, SerializableDomainEvent, UserId};
+use crate::ports::{ConsentRepository, EventPublisher};
+
+/// Command to record a consent decision.
+#[derive(Debug, Clone)]
+pub struct RecordConsentCommand {
+    pub user_id: UserId,
+    pub consent_type: ConsentType,
+    pub version: String,
+    pub granted: bool,
+    pub source: Option<String>,
+}
+
+/// Result of successfully recording consent.
+#[derive(Debug, Clone)]
+pub struct RecordConsentResult {
+    pub record: ConsentRecord,
+    pub event: ConsentRecorded,
+}
+
+/// Handler for recording consent decisions.
+pub struct RecordConsentHandler {
+    repository: Arc<dyn ConsentRepository>,
+    event_publisher: Arc<dyn EventPublisher>,
+    auditor: CommandAuditor,
+}
+
+impl RecordConsentHandler {
+    pub fn new(
+        repository: Arc<dyn ConsentRepository>,
+        event_publisher: Arc<dyn EventPublisher>,
+    ) -> Self {
+        Self {
+            repository,
+            event_publisher,
+            auditor: CommandAuditor::disabled(),
+        }
+    }
+
+    /// Records each command in the audit log.
+    pub fn with_auditor(mut self, auditor: CommandAuditor) -> Self {
+        self.auditor = auditor;
+        self
+    }
+
+    #[tracing::instrument(name = "RecordConsentHandler::handle", skip_all)]
+    pub async fn handle(
+        &self,
+        cmd: RecordConsentCommand,
+        metadata: CommandMetadata,
+    ) -> Result<RecordConsentResult, ConsentError> {
+        // 1. Build record (validates version)
+        let mut record = if cmd.granted {
+            ConsentRecord::grant(cmd.user_id, cmd.consent_type, cmd.version)?
+        } else {
+            ConsentRecord::withdraw(cmd.user_id, cmd.consent_type, cmd.version)?
+        };
+        if let Some(source) = cmd.source {
+            record = record.with_source(source);
+        }
+
+        // 2. Append to ledger
+        self.repository.append(&record).await?;
+
+        // 3. Publish event
+        let event = ConsentRecorded::from_record(&record);
+        let envelope = event
+            .to_envelope()
+            .with_correlation_id(metadata.correlation_id())
+            .with_user_id(metadata.user_id.to_string());
+
+        self.event_publisher.publish(envelope).await?;
+
+        self.auditor
+            .record(
+                AuditRecord::new(&metadata, "RecordConsent", "consent", record.id)
+                    .with_after(record.audit_summary()),
+            )
+            .await;
+
+        Ok(RecordConsentResult { record, event })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::adapters::{InMemoryConsentRepository, InMemoryEventBus};
+
+    fn user() -> UserId {
+        UserId::new("user-1").unwrap()
+    }
+
+    fn handler() -> (
+        RecordConsentHandler,
+        Arc<InMemoryConsentRepository>,
+        Arc<InMemoryEventBus>,
+    ) {
+        let repo = Arc::new(InMemoryConsentRepository::new());
+        let bus = Arc::new(InMemoryEventBus::new());
+        (
+            RecordConsentHandler::new(repo.clone(), bus.clone()),
+            repo,
+            bus,
+        )
+    }
+
+    fn command(granted: bool) -> RecordConsentCommand {
+        RecordConsentCommand {
+            user_id: user(),
+            consent_type: ConsentType::AiProcessing,
+            version: "1".to_string(),
+            granted,
+            source: Some("settings".to_string()),
+        }
+    }
+
+    #[tokio::test]
+    async fn records_grant_and_publishes_event() {
+        let (handler, repo, bus) = handler();
+
+        let result = handler
+            .handle(command(true), CommandMetadata::new(user()))
+            .await
+            .unwrap();
+
+        assert!(result.record.granted);
+        assert_eq!(result.record.source.as_deref(), Some("settings"));
+        assert_eq!(repo.record_count().await, 1);
+        assert!(bus.has_event("consent.recorded.v1"));
+    }
+
+    #[tokio::test]
+    async fn records_withdrawal() {
+        let (handler, repo, _) = handler();
+
+        handler
+            .handle(command(false), CommandMetadata::new(user()))
+            .await
+            .unwrap();
+
+        let records = repo.list_for_user(&user()).await.unwrap();
+        assert!(!records[0].granted);
+    }
+
+    #[tokio::test]
+    async fn rejects_empty_version() {
+        let (handler, repo, bus) = handler();
+        let mut cmd = command(true);
+        cmd.version = String::new();
+
+        let result = handler.handle(cmd, CommandMetadata::new(user())).await;
+
+        assert!(matches!(result, Err(ConsentError::ValidationFailed { .. })));
+        assert_eq!(repo.record_count().await, 0);
+        assert_eq!(bus.event_count(), 0);
+    }
+}
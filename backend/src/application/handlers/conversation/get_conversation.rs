@@ -21,6 +21,7 @@ impl GetConversationHandler {
         Self { reader }
     }
 
+    #[tracing::instrument(name = "GetConversationHandler::handle", skip_all)]
     pub async fn handle(
         &self,
         query: GetConversationQuery,
@@ -14,6 +14,7 @@ pub use send_message::{
     SendMessageResult,
     // Types
     MessageId,
+    MessageStream,
     MessageRole,
     StoredMessage,
     StreamEvent,
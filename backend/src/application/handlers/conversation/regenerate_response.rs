@@ -142,6 +142,7 @@ where
     /// Handles a regenerate response command.
     ///
     /// Returns a channel receiver for streaming events plus the final result.
+    #[tracing::instrument(name = "RegenerateResponseHandler::handle", skip_all)]
     pub async fn handle(
         &self,
         cmd: RegenerateResponseCommand,
@@ -195,7 +196,9 @@ where
             ownership.session_id,
             conversation.id,
             format!("regen-{}", new_message_id),
-        ))
+        )
+        // A regenerated response must differ from the one being replaced
+        .with_cache_bypass())
         .with_system_prompt(&conversation.system_prompt)
         .with_component_type(ownership.component_type);
 
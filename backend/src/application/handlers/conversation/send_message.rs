@@ -1,23 +1,34 @@
 //! SendMessage command handler.
 //!
 //! Handles sending user messages to a conversation and receiving AI responses.
-//! Supports streaming responses via WebSocket.
+//! Supports streaming responses via WebSocket or server-sent events.
+//!
+//! With a tool executor configured, the component's tools are offered to the
+//! model and each tool call is executed as soon as its arguments have
+//! streamed in, while the rest of the response is still arriving.
 
+use crate::application::handlers::profile::AdaptiveStyleResolver;
+use crate::domain::conversation::tools::{ToolCall, ToolResponse};
 use crate::domain::conversation::{
-    AgentPhase, ConversationState, PhaseTransitionEngine,
+    adaptive_agent_config_for_component, AgentPhase, ConversationState, PhaseTransitionEngine,
 };
 use crate::domain::foundation::{
     ComponentId, ComponentType, ConversationId, CycleId, DomainError, SessionId, Timestamp, UserId,
 };
 use crate::ports::{
-    AIError, AIProvider, CompletionRequest, Message, MessageRole as AIMessageRole, RequestMetadata,
-    TokenUsage,
+    AIError, AIProvider, AgentContextProvider, CompletionRequest, Message,
+    MessageRole as AIMessageRole, RequestMetadata, Sli, SloRecorder, StreamChunk as AIStreamChunk,
+    TokenUsage, ToolCallAssembler, ToolCallDelta, ToolExecutionContext, ToolExecutor,
 };
 use async_trait::async_trait;
+use futures::{Stream, StreamExt};
 use serde::{Deserialize, Serialize};
+use std::pin::Pin;
 use std::sync::Arc;
+use std::time::Instant;
 use thiserror::Error;
 use tokio::sync::mpsc;
+use tokio::task::JoinHandle;
 use uuid::Uuid;
 
 /// Unique identifier for a message.
@@ -355,6 +366,19 @@ pub enum StreamEvent {
         full_content: String,
         usage: Option<TokenUsage>,
     },
+    /// The AI called a tool, which is now executing.
+    ToolCall {
+        message_id: MessageId,
+        name: String,
+        parameters: serde_json::Value,
+    },
+    /// A tool call finished executing.
+    ToolResult {
+        message_id: MessageId,
+        name: String,
+        response: Option<ToolResponse>,
+        error: Option<String>,
+    },
     /// An error occurred.
     Error {
         message_id: MessageId,
@@ -365,20 +389,24 @@ pub enum StreamEvent {
 /// Handler for SendMessage commands.
 pub struct SendMessageHandler<O, R, A>
 where
-    O: ComponentOwnershipChecker,
-    R: ConversationRepository,
-    A: AIProvider,
+    O: ComponentOwnershipChecker + ?Sized,
+    R: ConversationRepository + ?Sized,
+    A: AIProvider + ?Sized,
 {
     ownership_checker: Arc<O>,
     conversation_repo: Arc<R>,
     ai_provider: Arc<A>,
+    slo_recorder: Option<Arc<dyn SloRecorder>>,
+    agent_context: Option<Arc<dyn AgentContextProvider>>,
+    adaptive_style: Option<Arc<AdaptiveStyleResolver>>,
+    tool_executor: Option<Arc<dyn ToolExecutor>>,
 }
 
 impl<O, R, A> SendMessageHandler<O, R, A>
 where
-    O: ComponentOwnershipChecker + 'static,
-    R: ConversationRepository + 'static,
-    A: AIProvider + 'static,
+    O: ComponentOwnershipChecker + ?Sized + 'static,
+    R: ConversationRepository + ?Sized + 'static,
+    A: AIProvider + ?Sized + 'static,
 {
     /// Creates a new handler with the given dependencies.
     pub fn new(
@@ -390,16 +418,246 @@ where
             ownership_checker,
             conversation_repo,
             ai_provider,
+            slo_recorder: None,
+            agent_context: None,
+            adaptive_style: None,
+            tool_executor: None,
         }
     }
 
+    /// Records send-message latency and stream outcomes for SLO tracking.
+    pub fn with_slo_recorder(mut self, recorder: Arc<dyn SloRecorder>) -> Self {
+        self.slo_recorder = Some(recorder);
+        self
+    }
+
+    /// Adds extra context (such as the user's team profile) to the system prompt.
+    pub fn with_agent_context(mut self, provider: Arc<dyn AgentContextProvider>) -> Self {
+        self.agent_context = Some(provider);
+        self
+    }
+
+    /// Adapts the agent's tone, pacing, challenge style and reading level to
+    /// the user's decision profile.
+    pub fn with_adaptive_style(mut self, resolver: Arc<AdaptiveStyleResolver>) -> Self {
+        self.adaptive_style = Some(resolver);
+        self
+    }
+
+    /// Offers the component's tools to the model and executes the calls it
+    /// makes while the response streams.
+    pub fn with_tool_executor(mut self, executor: Arc<dyn ToolExecutor>) -> Self {
+        self.tool_executor = Some(executor);
+        self
+    }
+
+    /// The conversation's system prompt plus adaptive style (or just the
+    /// reading level) and any extra agent context. Both are best-effort;
+    /// failing to load them never blocks the message.
+    async fn system_prompt_for(
+        &self,
+        user_id: &UserId,
+        conversation: &ConversationRecord,
+    ) -> String {
+        let mut prompt = conversation.system_prompt.clone();
+
+        if let Some(resolver) = &self.adaptive_style {
+            match resolver.preferences_for(user_id, &conversation.id).await {
+                Ok(Some(preferences)) => {
+                    let config = adaptive_agent_config_for_component(
+                        conversation.component_type,
+                        &preferences,
+                    );
+                    prompt.push_str("\n\n");
+                    prompt.push_str(&config.system_prompt(conversation.phase));
+                }
+                // Without adaptive style, the reading level still applies
+                Ok(None) => match resolver.reading_level_for(user_id).await {
+                    Ok(level) => {
+                        if let Some(instruction) = level.instruction() {
+                            prompt.push_str("\n\n");
+                            prompt.push_str(instruction);
+                        }
+                    }
+                    Err(err) => tracing::warn!(error = %err, "Failed to load reading level"),
+                },
+                Err(err) => tracing::warn!(error = %err, "Failed to load adaptive style"),
+            }
+        }
+
+        if let Some(provider) = &self.agent_context {
+            match provider.context_for(user_id).await {
+                Ok(Some(context)) => {
+                    prompt.push_str("\n\n");
+                    prompt.push_str(&context);
+                }
+                Ok(None) => {}
+                Err(err) => tracing::warn!(error = %err, "Failed to load agent context"),
+            }
+        }
+
+        prompt
+    }
+
     /// Handles a send message command.
     ///
     /// Returns a channel receiver for streaming events plus the final result.
+    #[tracing::instrument(name = "SendMessageHandler::handle", skip_all)]
     pub async fn handle(
         &self,
         cmd: SendMessageCommand,
     ) -> Result<(mpsc::Receiver<StreamEvent>, SendMessageResult), SendMessageError> {
+        let started = Instant::now();
+        let exchange = self.begin(&cmd).await?;
+        let user_message_id = exchange.user_message_id;
+        let assistant_message_id = exchange.assistant_message_id;
+        let (tx, rx) = mpsc::channel(32);
+
+        // Spawn task to handle streaming
+        let conversation_id = exchange.conversation.id;
+        let conversation_repo = Arc::clone(&self.conversation_repo);
+        let stream = exchange.stream;
+        let tools = exchange.tools;
+
+        let handle = tokio::spawn(async move {
+            let (full_content, final_usage) = match relay(stream, tools, &tx, assistant_message_id).await {
+                Relayed::Finished(content, usage) => (content, usage),
+                Relayed::Failed(error) => return Err(SendMessageError::AIProviderError(error)),
+                // The receiver is held until the task ends
+                Relayed::Cancelled => return Err(SendMessageError::DomainError("Stream cancelled".to_string())),
+            };
+
+            store_response(conversation_repo.as_ref(), &conversation_id, assistant_message_id, &full_content, &final_usage).await?;
+
+            // R17: Send complete event
+            let _ = tx
+                .send(StreamEvent::Complete {
+                    message_id: assistant_message_id,
+                    full_content: full_content.clone(),
+                    usage: final_usage.clone(),
+                })
+                .await;
+
+            Ok((full_content, final_usage))
+        });
+
+        // Wait for streaming to complete
+        let streamed = handle
+            .await
+            .map_err(|e| SendMessageError::DomainError(e.to_string()))?;
+        record_stream_outcome(&self.slo_recorder, streamed.is_ok());
+        let (_full_content, usage) = streamed?;
+
+        let (new_state, new_phase) = advance_conversation(
+            self.conversation_repo.as_ref(),
+            &exchange.conversation,
+            exchange.ownership.component_type,
+            &exchange.content,
+        )
+        .await?;
+
+        if let Some(recorder) = &self.slo_recorder {
+            recorder.record_latency(Sli::SendMessageLatency, started.elapsed());
+        }
+
+        Ok((
+            rx,
+            SendMessageResult {
+                user_message_id,
+                assistant_message_id,
+                new_phase,
+                new_state,
+                usage,
+            },
+        ))
+    }
+
+    /// Handles a send message command, returning as soon as the AI provider
+    /// starts responding.
+    ///
+    /// Events arrive on the returned receiver as they are produced, ending
+    /// with `Complete` once the response is stored and the conversation
+    /// state updated, or with `Error`. Dropping the receiver cancels the
+    /// exchange: the provider stream is dropped and no response is stored.
+    #[tracing::instrument(name = "SendMessageHandler::stream", skip_all)]
+    pub async fn stream(
+        &self,
+        cmd: SendMessageCommand,
+    ) -> Result<MessageStream, SendMessageError> {
+        let started = Instant::now();
+        let exchange = self.begin(&cmd).await?;
+        let user_message_id = exchange.user_message_id;
+        let assistant_message_id = exchange.assistant_message_id;
+        let (tx, rx) = mpsc::channel(32);
+
+        let conversation_repo = Arc::clone(&self.conversation_repo);
+        let slo_recorder = self.slo_recorder.clone();
+
+        tokio::spawn(async move {
+            let (full_content, final_usage) =
+                match relay(exchange.stream, exchange.tools, &tx, assistant_message_id).await {
+                    Relayed::Finished(content, usage) => (content, usage),
+                    Relayed::Failed(_) => {
+                        record_stream_outcome(&slo_recorder, false);
+                        return;
+                    }
+                    Relayed::Cancelled => {
+                        tracing::debug!(message_id = %assistant_message_id, "Client went away; response discarded");
+                        return;
+                    }
+                };
+            record_stream_outcome(&slo_recorder, true);
+
+            let saved = async {
+                store_response(
+                    conversation_repo.as_ref(),
+                    &exchange.conversation.id,
+                    assistant_message_id,
+                    &full_content,
+                    &final_usage,
+                )
+                .await?;
+                advance_conversation(
+                    conversation_repo.as_ref(),
+                    &exchange.conversation,
+                    exchange.ownership.component_type,
+                    &exchange.content,
+                )
+                .await
+            }
+            .await;
+
+            let event = match saved {
+                Ok(_) => StreamEvent::Complete {
+                    message_id: assistant_message_id,
+                    full_content,
+                    usage: final_usage,
+                },
+                Err(e) => {
+                    tracing::error!(error = %e, "Failed to save streamed response");
+                    StreamEvent::Error {
+                        message_id: assistant_message_id,
+                        error: "Failed to save response".to_string(),
+                    }
+                }
+            };
+            let _ = tx.send(event).await;
+
+            if let Some(recorder) = &slo_recorder {
+                recorder.record_latency(Sli::SendMessageLatency, started.elapsed());
+            }
+        });
+
+        Ok(MessageStream {
+            user_message_id,
+            assistant_message_id,
+            events: rx,
+        })
+    }
+
+    /// Validates the command, stores the user message and starts the AI
+    /// provider's response stream.
+    async fn begin(&self, cmd: &SendMessageCommand) -> Result<Exchange, SendMessageError> {
         // R3: Validate content is not empty
         let content = cmd.content.trim();
         if content.is_empty() {
@@ -451,18 +709,36 @@ where
 
         // R5: Build context and call AI provider
         let assistant_message_id = MessageId::new();
-        let (tx, rx) = mpsc::channel(32);
 
         // Build request
+        let system_prompt = self.system_prompt_for(&cmd.user_id, &conversation).await;
         let request = CompletionRequest::new(RequestMetadata::new(
             cmd.user_id.clone(),
             ownership.session_id,
             conversation.id,
             format!("msg-{}", assistant_message_id),
         ))
-        .with_system_prompt(&conversation.system_prompt)
+        .with_system_prompt(&system_prompt)
         .with_component_type(ownership.component_type);
 
+        let tools = self.tool_executor.as_ref().map(|executor| {
+            ToolRunner::new(
+                Arc::clone(executor),
+                ToolExecutionContext::new(
+                    ownership.cycle_id,
+                    ownership.component_type,
+                    conversation.user_message_count() as u32,
+                    format!("msg-{}", assistant_message_id),
+                ),
+            )
+        });
+        let request = match &self.tool_executor {
+            Some(executor) => {
+                request.with_tools(executor.available_tools(ownership.component_type, true))
+            }
+            None => request,
+        };
+
         // Add messages
         let mut request = request;
         for msg in conversation.messages_for_ai() {
@@ -470,112 +746,273 @@ where
         }
 
         // R16: Stream the response
-        let stream = self.ai_provider.stream_complete(request).await?;
+        let stream = self
+            .ai_provider
+            .stream_complete(request)
+            .await
+            .inspect_err(|_| record_stream_outcome(&self.slo_recorder, false))?;
+
+        Ok(Exchange {
+            conversation,
+            ownership,
+            content: content.to_string(),
+            user_message_id,
+            assistant_message_id,
+            stream,
+            tools,
+        })
+    }
+}
 
-        // Spawn task to handle streaming
-        let conversation_id = conversation.id;
-        let conversation_repo = Arc::clone(&self.conversation_repo);
+/// A message exchange whose response is still streaming.
+pub struct MessageStream {
+    /// ID of the user message that was stored.
+    pub user_message_id: MessageId,
+    /// ID the assistant response will be stored under.
+    pub assistant_message_id: MessageId,
+    /// Response events, ending with `Complete` or `Error`.
+    pub events: mpsc::Receiver<StreamEvent>,
+}
 
-        let handle = tokio::spawn(async move {
-            let mut full_content = String::new();
-            let mut final_usage = None;
-            let mut stream = stream;
-
-            loop {
-                use futures::StreamExt;
-                match stream.next().await {
-                    Some(Ok(chunk)) => {
-                        let delta = chunk.delta.clone();
-                        let is_final = chunk.is_final();
-                        let usage = chunk.usage.clone();
-
-                        full_content.push_str(&delta);
-
-                        // R16: Send chunk event
-                        let _ = tx
-                            .send(StreamEvent::Chunk {
-                                message_id: assistant_message_id,
-                                delta,
-                            })
-                            .await;
-
-                        // R17: Check for completion
-                        if is_final {
-                            final_usage = usage;
-                            break;
-                        }
-                    }
-                    Some(Err(e)) => {
-                        // R18: Send error event
-                        let _ = tx
-                            .send(StreamEvent::Error {
-                                message_id: assistant_message_id,
-                                error: e.to_string(),
-                            })
-                            .await;
-                        return Err(SendMessageError::AIProviderError(e.to_string()));
+type ResponseStream = Pin<Box<dyn Stream<Item = Result<AIStreamChunk, AIError>> + Send>>;
+
+/// A stored user message and the AI response stream answering it.
+struct Exchange {
+    /// The conversation, including the new user message.
+    conversation: ConversationRecord,
+    ownership: OwnershipInfo,
+    /// The trimmed user message.
+    content: String,
+    user_message_id: MessageId,
+    assistant_message_id: MessageId,
+    stream: ResponseStream,
+    /// Executes tool calls, when tools were offered.
+    tools: Option<ToolRunner>,
+}
+
+/// Starts each tool call as soon as the stream has delivered its
+/// arguments, reporting `ToolCall` and `ToolResult` events.
+struct ToolRunner {
+    executor: Arc<dyn ToolExecutor>,
+    context: ToolExecutionContext,
+    assembler: ToolCallAssembler,
+    running: Vec<JoinHandle<()>>,
+}
+
+impl ToolRunner {
+    fn new(executor: Arc<dyn ToolExecutor>, context: ToolExecutionContext) -> Self {
+        Self {
+            executor,
+            context,
+            assembler: ToolCallAssembler::new(),
+            running: Vec::new(),
+        }
+    }
+
+    /// Feeds a delta, starting its call if the arguments are now complete.
+    /// Returns false if the receiver has gone away.
+    async fn accept(
+        &mut self,
+        delta: ToolCallDelta,
+        tx: &mpsc::Sender<StreamEvent>,
+        message_id: MessageId,
+    ) -> bool {
+        match self.assembler.push(delta) {
+            Some(call) => self.start(call, tx, message_id).await,
+            None => true,
+        }
+    }
+
+    async fn start(
+        &mut self,
+        call: ToolCall,
+        tx: &mpsc::Sender<StreamEvent>,
+        message_id: MessageId,
+    ) -> bool {
+        let event = StreamEvent::ToolCall {
+            message_id,
+            name: call.name().to_string(),
+            parameters: call.parameters().clone(),
+        };
+        if tx.send(event).await.is_err() {
+            return false;
+        }
+
+        let executor = Arc::clone(&self.executor);
+        let context = self.context.clone();
+        let tx = tx.clone();
+        self.running.push(tokio::spawn(async move {
+            let name = call.name().to_string();
+            let event = match executor.execute(call, context).await {
+                Ok(response) => StreamEvent::ToolResult {
+                    message_id,
+                    name,
+                    response: Some(response),
+                    error: None,
+                },
+                Err(e) => {
+                    tracing::warn!(tool = %name, error = %e, "Tool call failed");
+                    StreamEvent::ToolResult {
+                        message_id,
+                        name,
+                        response: None,
+                        error: Some(e.to_string()),
                     }
-                    None => break,
                 }
-            }
+            };
+            let _ = tx.send(event).await;
+        }));
+        true
+    }
 
-            // R6 & R7: Store assistant message with token count
-            let mut assistant_msg = StoredMessage::assistant_with_id(assistant_message_id, &full_content);
-            if let Some(ref usage) = final_usage {
-                assistant_msg = assistant_msg.with_token_count(usage.completion_tokens);
+    /// Starts any calls the stream left unfinished and waits for every
+    /// call to report its result.
+    async fn finish(mut self, tx: &mpsc::Sender<StreamEvent>, message_id: MessageId) -> bool {
+        for call in self.assembler.finish() {
+            if !self.start(call, tx, message_id).await {
+                return false;
             }
-            conversation_repo
-                .add_message(&conversation_id, assistant_msg)
-                .await?;
+        }
+        for handle in self.running {
+            if let Err(e) = handle.await {
+                tracing::error!(error = %e, "Tool call task panicked");
+            }
+        }
+        !tx.is_closed()
+    }
+}
 
-            // R17: Send complete event
-            let _ = tx
-                .send(StreamEvent::Complete {
-                    message_id: assistant_message_id,
-                    full_content: full_content.clone(),
-                    usage: final_usage.clone(),
-                })
-                .await;
+/// How relaying a response stream ended.
+enum Relayed {
+    /// The provider finished; the full content and final usage.
+    Finished(String, Option<TokenUsage>),
+    /// The provider failed; an `Error` event has been sent.
+    Failed(String),
+    /// The receiver was dropped.
+    Cancelled,
+}
 
-            Ok((full_content, final_usage))
-        });
+/// Forwards the provider's chunks as `Chunk` events until the final chunk,
+/// a provider error, or the receiver going away. Tool calls are started as
+/// they complete and waited for before the response counts as finished.
+async fn relay(
+    mut stream: ResponseStream,
+    mut tools: Option<ToolRunner>,
+    tx: &mpsc::Sender<StreamEvent>,
+    message_id: MessageId,
+) -> Relayed {
+    let mut full_content = String::new();
+
+    let relayed = loop {
+        let next = tokio::select! {
+            biased;
+            _ = tx.closed() => return Relayed::Cancelled,
+            next = stream.next() => next,
+        };
+        match next {
+            Some(Ok(mut chunk)) => {
+                let is_final = chunk.is_final();
+                if let Some(delta) = chunk.tool_call.take() {
+                    if let Some(runner) = tools.as_mut() {
+                        if !runner.accept(delta, tx, message_id).await {
+                            return Relayed::Cancelled;
+                        }
+                    }
+                    if !is_final && chunk.delta.is_empty() {
+                        continue;
+                    }
+                }
+                full_content.push_str(&chunk.delta);
+
+                // R16: Send chunk event
+                let event = StreamEvent::Chunk {
+                    message_id,
+                    delta: chunk.delta,
+                };
+                if tx.send(event).await.is_err() {
+                    return Relayed::Cancelled;
+                }
 
-        // Wait for streaming to complete
-        let (_full_content, usage) = handle
-            .await
-            .map_err(|e| SendMessageError::DomainError(e.to_string()))??;
+                // R17: Check for completion
+                if is_final {
+                    break Relayed::Finished(full_content, chunk.usage);
+                }
+            }
+            Some(Err(e)) => {
+                // R18: Send error event
+                let _ = tx
+                    .send(StreamEvent::Error {
+                        message_id,
+                        error: e.to_string(),
+                    })
+                    .await;
+                return Relayed::Failed(e.to_string());
+            }
+            None => break Relayed::Finished(full_content, None),
+        }
+    };
 
-        // R8: Update state if first message
-        let new_state = if conversation.state == ConversationState::Ready {
-            ConversationState::InProgress
-        } else {
-            conversation.state
-        };
+    if let Some(runner) = tools {
+        if !runner.finish(tx, message_id).await {
+            return Relayed::Cancelled;
+        }
+    }
+    relayed
+}
 
-        // Determine new phase using transition engine
-        let engine = PhaseTransitionEngine::for_component(ownership.component_type);
-        let snapshot = crate::domain::conversation::ConversationSnapshot::new(
-            conversation.user_message_count() + 1, // Include the message we just added
-            Some(content.to_string()),
-            ownership.component_type,
-        );
-        let new_phase = engine.next_phase(conversation.phase, &snapshot);
+/// R6 & R7: Stores the assistant message with its token count.
+async fn store_response<R: ConversationRepository + ?Sized>(
+    conversation_repo: &R,
+    conversation_id: &ConversationId,
+    message_id: MessageId,
+    full_content: &str,
+    usage: &Option<TokenUsage>,
+) -> Result<(), SendMessageError> {
+    let mut assistant_msg = StoredMessage::assistant_with_id(message_id, full_content);
+    if let Some(usage) = usage {
+        assistant_msg = assistant_msg.with_token_count(usage.completion_tokens);
+    }
+    conversation_repo
+        .add_message(conversation_id, assistant_msg)
+        .await?;
+    Ok(())
+}
 
-        // Update conversation state
-        self.conversation_repo
-            .update_state(&conversation.id, new_state, new_phase)
-            .await?;
+/// Moves the conversation on after an exchange, returning its new state and
+/// phase.
+async fn advance_conversation<R: ConversationRepository + ?Sized>(
+    conversation_repo: &R,
+    conversation: &ConversationRecord,
+    component_type: ComponentType,
+    content: &str,
+) -> Result<(ConversationState, AgentPhase), SendMessageError> {
+    // R8: Update state if first message
+    let new_state = if conversation.state == ConversationState::Ready {
+        ConversationState::InProgress
+    } else {
+        conversation.state
+    };
+
+    // Determine new phase using transition engine
+    let engine = PhaseTransitionEngine::for_component(component_type);
+    let snapshot = crate::domain::conversation::ConversationSnapshot::new(
+        conversation.user_message_count() + 1, // Include the message we just added
+        Some(content.to_string()),
+        component_type,
+    );
+    let new_phase = engine.next_phase(conversation.phase, &snapshot);
+
+    // Update conversation state
+    conversation_repo
+        .update_state(&conversation.id, new_state, new_phase)
+        .await?;
+
+    Ok((new_state, new_phase))
+}
 
-        Ok((
-            rx,
-            SendMessageResult {
-                user_message_id,
-                assistant_message_id,
-                new_phase,
-                new_state,
-                usage,
-            },
-        ))
+fn record_stream_outcome(recorder: &Option<Arc<dyn SloRecorder>>, good: bool) {
+    if let Some(recorder) = recorder {
+        recorder.record_outcome(Sli::ConversationStreamErrors, good);
     }
 }
 
@@ -930,4 +1367,355 @@ mod tests {
             assert!(matches!(result, Err(SendMessageError::ConversationComplete)));
         }
     }
+
+    mod slo_recording {
+        use super::*;
+        use std::time::Duration;
+
+        #[derive(Default)]
+        struct RecordingSlo {
+            outcomes: Mutex<Vec<(Sli, bool)>>,
+            latencies: Mutex<Vec<Sli>>,
+        }
+
+        impl SloRecorder for RecordingSlo {
+            fn record_outcome(&self, sli: Sli, good: bool) {
+                self.outcomes.lock().unwrap().push((sli, good));
+            }
+
+            fn record_latency(&self, sli: Sli, _latency: Duration) {
+                self.latencies.lock().unwrap().push(sli);
+            }
+        }
+
+        #[tokio::test]
+        async fn records_stream_success_and_latency() {
+            let slo = Arc::new(RecordingSlo::default());
+            let handler = SendMessageHandler::new(
+                Arc::new(MockOwnershipChecker::allowing()),
+                Arc::new(MockConversationRepo::new()),
+                Arc::new(MockAIProvider::with_response("Hi")),
+            )
+            .with_slo_recorder(slo.clone());
+
+            let cmd = SendMessageCommand::new(
+                UserId::new("owner").unwrap(),
+                ComponentId::new(),
+                "Hello",
+            );
+            handler.handle(cmd).await.unwrap();
+
+            assert_eq!(
+                *slo.outcomes.lock().unwrap(),
+                vec![(Sli::ConversationStreamErrors, true)]
+            );
+            assert_eq!(*slo.latencies.lock().unwrap(), vec![Sli::SendMessageLatency]);
+        }
+
+        #[tokio::test]
+        async fn rejected_requests_are_not_recorded() {
+            let slo = Arc::new(RecordingSlo::default());
+            let handler = SendMessageHandler::new(
+                Arc::new(MockOwnershipChecker::denying()),
+                Arc::new(MockConversationRepo::new()),
+                Arc::new(MockAIProvider::with_response("Hi")),
+            )
+            .with_slo_recorder(slo.clone());
+
+            let cmd = SendMessageCommand::new(
+                UserId::new("owner").unwrap(),
+                ComponentId::new(),
+                "Hello",
+            );
+            assert!(handler.handle(cmd).await.is_err());
+
+            assert!(slo.outcomes.lock().unwrap().is_empty());
+            assert!(slo.latencies.lock().unwrap().is_empty());
+        }
+    }
+
+    mod streaming {
+        use super::*;
+        use tokio::sync::oneshot;
+
+        /// Sends one chunk, then waits forever. Dropping the stream drops
+        /// the sender handed out by `stream_complete`.
+        struct HangingAIProvider {
+            dropped: Mutex<Option<oneshot::Sender<()>>>,
+        }
+
+        #[async_trait]
+        impl AIProvider for HangingAIProvider {
+            async fn complete(
+                &self,
+                _request: CompletionRequest,
+            ) -> Result<crate::ports::CompletionResponse, AIError> {
+                unimplemented!()
+            }
+
+            async fn stream_complete(
+                &self,
+                _request: CompletionRequest,
+            ) -> Result<Pin<Box<dyn Stream<Item = Result<AIStreamChunk, AIError>> + Send>>, AIError>
+            {
+                let guard = self.dropped.lock().unwrap().take();
+                let chunks = stream::iter(vec![Ok(AIStreamChunk::content("Thinking"))])
+                    .chain(stream::pending())
+                    .map(move |chunk| {
+                        let _guard = &guard;
+                        chunk
+                    });
+                Ok(Box::pin(chunks))
+            }
+
+            fn estimate_tokens(&self, text: &str) -> u32 {
+                (text.len() / 4) as u32
+            }
+
+            fn provider_info(&self) -> crate::ports::ProviderInfo {
+                crate::ports::ProviderInfo::new("mock", "mock-model", 4096)
+            }
+        }
+
+        #[tokio::test]
+        async fn streams_chunks_then_completes_after_saving() {
+            let repo = Arc::new(MockConversationRepo::new());
+            let handler = SendMessageHandler::new(
+                Arc::new(MockOwnershipChecker::allowing()),
+                repo.clone(),
+                Arc::new(MockAIProvider::with_response("Hello there!")),
+            );
+            let cmd = SendMessageCommand::new(
+                UserId::new("owner").unwrap(),
+                ComponentId::new(),
+                "Hello",
+            );
+
+            let mut exchange = handler.stream(cmd).await.unwrap();
+            let mut events = Vec::new();
+            while let Some(event) = exchange.events.recv().await {
+                events.push(event);
+            }
+
+            assert!(matches!(&events[0], StreamEvent::Chunk { delta, .. } if delta == "Hello there!"));
+            assert!(matches!(
+                events.last(),
+                Some(StreamEvent::Complete { message_id, full_content, .. })
+                    if *message_id == exchange.assistant_message_id && full_content == "Hello there!"
+            ));
+            let messages = repo.messages.lock().unwrap();
+            assert_eq!(messages.len(), 2);
+            assert_eq!(messages[1].1.id, exchange.assistant_message_id);
+        }
+
+        #[tokio::test]
+        async fn dropping_the_receiver_cancels_the_provider_stream() {
+            let (dropped_tx, dropped_rx) = oneshot::channel();
+            let repo = Arc::new(MockConversationRepo::new());
+            let handler = SendMessageHandler::new(
+                Arc::new(MockOwnershipChecker::allowing()),
+                repo.clone(),
+                Arc::new(HangingAIProvider {
+                    dropped: Mutex::new(Some(dropped_tx)),
+                }),
+            );
+            let cmd = SendMessageCommand::new(
+                UserId::new("owner").unwrap(),
+                ComponentId::new(),
+                "Hello",
+            );
+
+            let mut exchange = handler.stream(cmd).await.unwrap();
+            assert!(matches!(exchange.events.recv().await, Some(StreamEvent::Chunk { .. })));
+            drop(exchange.events);
+
+            assert!(dropped_rx.await.is_err(), "provider stream should be dropped");
+            // Only the user message was stored
+            assert_eq!(repo.messages.lock().unwrap().len(), 1);
+        }
+    }
+
+    mod tool_use {
+        use super::*;
+        use crate::domain::conversation::tools::ToolDefinition;
+        use crate::domain::foundation::ValidationError;
+        use crate::ports::{ToolCallDelta, ToolExecutionError};
+        use std::time::Duration;
+        use tokio::sync::oneshot;
+
+        /// Streams a complete tool call, then holds the rest of the response
+        /// until the tool has started executing.
+        struct ToolCallingAIProvider {
+            tool_started: Mutex<Option<oneshot::Receiver<()>>>,
+            offered_tools: Mutex<Vec<String>>,
+        }
+
+        #[async_trait]
+        impl AIProvider for ToolCallingAIProvider {
+            async fn complete(
+                &self,
+                _request: CompletionRequest,
+            ) -> Result<crate::ports::CompletionResponse, AIError> {
+                unimplemented!()
+            }
+
+            async fn stream_complete(
+                &self,
+                request: CompletionRequest,
+            ) -> Result<Pin<Box<dyn Stream<Item = Result<AIStreamChunk, AIError>> + Send>>, AIError>
+            {
+                *self.offered_tools.lock().unwrap() =
+                    request.tools.iter().map(|t| t.name().to_string()).collect();
+                let started = self.tool_started.lock().unwrap().take().unwrap();
+
+                let call = stream::iter(vec![
+                    Ok(AIStreamChunk::tool_call(ToolCallDelta::start(0, "call_1", "add_objective"))),
+                    Ok(AIStreamChunk::tool_call(ToolCallDelta::arguments(0, "{\"name\":"))),
+                    Ok(AIStreamChunk::tool_call(ToolCallDelta::arguments(0, "\"Cost\"}"))),
+                ]);
+                let rest = stream::once(async move {
+                    let _ = started.await;
+                    Ok(AIStreamChunk::content("Added it."))
+                })
+                .chain(stream::iter(vec![Ok(AIStreamChunk::final_chunk(
+                    crate::ports::FinishReason::ToolUse,
+                    TokenUsage::new(10, 5, 0),
+                ))]));
+                Ok(Box::pin(call.chain(rest)))
+            }
+
+            fn estimate_tokens(&self, text: &str) -> u32 {
+                (text.len() / 4) as u32
+            }
+
+            fn provider_info(&self) -> crate::ports::ProviderInfo {
+                crate::ports::ProviderInfo::new("mock", "mock-model", 4096).with_functions(true)
+            }
+        }
+
+        struct SignallingExecutor {
+            started: Mutex<Option<oneshot::Sender<()>>>,
+            executed: Mutex<Vec<ToolCall>>,
+        }
+
+        #[async_trait]
+        impl ToolExecutor for SignallingExecutor {
+            async fn execute(
+                &self,
+                call: ToolCall,
+                _context: ToolExecutionContext,
+            ) -> Result<ToolResponse, ToolExecutionError> {
+                self.executed.lock().unwrap().push(call);
+                if let Some(started) = self.started.lock().unwrap().take() {
+                    let _ = started.send(());
+                }
+                Ok(ToolResponse::success_empty(true))
+            }
+
+            fn available_tools(
+                &self,
+                _component: ComponentType,
+                _include_cross_cutting: bool,
+            ) -> Vec<ToolDefinition> {
+                vec![ToolDefinition::simple("add_objective", "Add an objective")]
+            }
+
+            fn validate(&self, _call: &ToolCall) -> Result<(), ValidationError> {
+                Ok(())
+            }
+
+            fn has_tool(&self, name: &str) -> bool {
+                name == "add_objective"
+            }
+
+            fn get_tool(&self, _name: &str) -> Option<ToolDefinition> {
+                None
+            }
+        }
+
+        #[tokio::test]
+        async fn executes_tool_calls_before_the_response_finishes() {
+            let (started_tx, started_rx) = oneshot::channel();
+            let provider = Arc::new(ToolCallingAIProvider {
+                tool_started: Mutex::new(Some(started_rx)),
+                offered_tools: Mutex::new(Vec::new()),
+            });
+            let executor = Arc::new(SignallingExecutor {
+                started: Mutex::new(Some(started_tx)),
+                executed: Mutex::new(Vec::new()),
+            });
+            let handler = SendMessageHandler::new(
+                Arc::new(MockOwnershipChecker::allowing()),
+                Arc::new(MockConversationRepo::new()),
+                provider.clone(),
+            )
+            .with_tool_executor(executor.clone());
+            let cmd = SendMessageCommand::new(
+                UserId::new("owner").unwrap(),
+                ComponentId::new(),
+                "Cost matters most",
+            );
+
+            let mut exchange = handler.stream(cmd).await.unwrap();
+            let mut events = Vec::new();
+            // The provider only finishes once the tool has started
+            tokio::time::timeout(Duration::from_secs(5), async {
+                while let Some(event) = exchange.events.recv().await {
+                    events.push(event);
+                }
+            })
+            .await
+            .expect("tool call should run while the response streams");
+
+            assert_eq!(*provider.offered_tools.lock().unwrap(), vec!["add_objective"]);
+            let executed = executor.executed.lock().unwrap();
+            assert_eq!(executed.len(), 1);
+            assert_eq!(executed[0].parameters()["name"], "Cost");
+
+            assert!(matches!(
+                &events[0],
+                StreamEvent::ToolCall { name, .. } if name == "add_objective"
+            ));
+            assert!(events.iter().any(|e| matches!(
+                e,
+                StreamEvent::ToolResult { response: Some(_), error: None, .. }
+            )));
+            assert!(matches!(
+                events.last(),
+                Some(StreamEvent::Complete { full_content, .. }) if full_content == "Added it."
+            ));
+        }
+
+        #[tokio::test]
+        async fn tool_call_chunks_are_ignored_without_an_executor() {
+            let (started_tx, started_rx) = oneshot::channel::<()>();
+            let provider = Arc::new(ToolCallingAIProvider {
+                tool_started: Mutex::new(Some(started_rx)),
+                offered_tools: Mutex::new(Vec::new()),
+            });
+            let handler = SendMessageHandler::new(
+                Arc::new(MockOwnershipChecker::allowing()),
+                Arc::new(MockConversationRepo::new()),
+                provider.clone(),
+            );
+            let cmd = SendMessageCommand::new(
+                UserId::new("owner").unwrap(),
+                ComponentId::new(),
+                "Hello",
+            );
+
+            let mut exchange = handler.stream(cmd).await.unwrap();
+            drop(started_tx);
+            let mut events = Vec::new();
+            while let Some(event) = exchange.events.recv().await {
+                events.push(event);
+            }
+
+            assert!(provider.offered_tools.lock().unwrap().is_empty());
+            assert!(!events
+                .iter()
+                .any(|e| matches!(e, StreamEvent::ToolCall { .. } | StreamEvent::ToolResult { .. })));
+            assert!(matches!(events.last(), Some(StreamEvent::Complete { .. })));
+        }
+    }
 }
@@ -0,0 +1,205 @@
+//! AddAttachmentHandler - Command handler for attaching a file to a component.
+//!
+//! Validates the upload, stores the bytes under the attachment's storage key,
+//! then records the metadata. If recording fails the stored bytes are removed
+//! so storage never holds files the repository does not know about.
+
+use std::sync::Arc;
+
+use crate::domain::document::{Attachment, AttachmentError};
+use crate::domain::foundation::{ComponentType, CycleId, UserId};
+use crate::ports::{AttachmentRepository, CycleRepository, DocumentStorage, SessionRepository};
+
+use super::document_access::{authorize_cycle, storage_error};
+
+/// Command to attach a file to one of a cycle's components.
+#[derive(Debug, Clone)]
+pub struct AddAttachmentCommand {
+    pub cycle_id: CycleId,
+    pub component_type: ComponentType,
+    pub file_name: String,
+    pub content_type: String,
+    pub caption: Option<String>,
+    pub bytes: Vec<u8>,
+    pub user_id: UserId,
+}
+
+/// Result of attaching a file.
+pub type AddAttachmentResult = Attachment;
+
+/// Error type for attaching a file.
+pub type AddAttachmentError = AttachmentError;
+
+/// Handler for attaching files to components.
+pub struct AddAttachmentHandler {
+    cycle_repository: Arc<dyn CycleRepository>,
+    session_repository: Arc<dyn SessionRepository>,
+    attachment_repository: Arc<dyn AttachmentRepository>,
+    document_storage: Arc<dyn DocumentStorage>,
+}
+
+impl AddAttachmentHandler {
+    pub fn new(
+        cycle_repository: Arc<dyn CycleRepository>,
+        session_repository: Arc<dyn SessionRepository>,
+        attachment_repository: Arc<dyn AttachmentRepository>,
+        document_storage: Arc<dyn DocumentStorage>,
+    ) -> Self {
+        Self {
+            cycle_repository,
+            session_repository,
+            attachment_repository,
+            document_storage,
+        }
+    }
+
+    #[tracing::instrument(name = "AddAttachmentHandler::handle", skip_all)]
+    pub async fn handle(
+        &self,
+        cmd: AddAttachmentCommand,
+    ) -> Result<AddAttachmentResult, AddAttachmentError> {
+        authorize_cycle(
+            self.cycle_repository.as_ref(),
+            self.session_repository.as_ref(),
+            &cmd.cycle_id,
+            &cmd.user_id,
+        )
+        .await?;
+
+        let attachment = Attachment::new(
+            cmd.cycle_id,
+            cmd.component_type,
+            &cmd.file_name,
+            &cmd.content_type,
+            cmd.bytes.len(),
+            cmd.caption,
+            cmd.user_id,
+        )?;
+
+        let key = attachment.storage_key();
+        self.document_storage
+            .put(&key, &attachment.content_type, cmd.bytes)
+            .await
+            .map_err(storage_error)?;
+
+        if let Err(e) = self.attachment_repository.save(&attachment).await {
+            if let Err(cleanup) = self.document_storage.delete(&key).await {
+                tracing::warn!(key = %key, error = %cleanup, "Orphaned attachment bytes");
+            }
+            return Err(e.into());
+        }
+
+        Ok(attachment)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::document_access::fixtures::*;
+    use super::*;
+    use crate::adapters::document::InMemoryAttachmentRepository;
+    use crate::adapters::storage::InMemoryDocumentStorage;
+    use crate::domain::document::MAX_ATTACHMENT_BYTES;
+
+    fn setup_repositories() -> (
+        Arc<InMemoryAttachmentRepository>,
+        Arc<InMemoryDocumentStorage>,
+    ) {
+        (
+            Arc::new(InMemoryAttachmentRepository::new()),
+            Arc::new(InMemoryDocumentStorage::new()),
+        )
+    }
+
+    fn create_handler(
+        cycles: MockCycleRepository,
+        sessions: MockSessionRepository,
+        attachments: Arc<InMemoryAttachmentRepository>,
+        storage: Arc<InMemoryDocumentStorage>,
+    ) -> AddAttachmentHandler {
+        AddAttachmentHandler::new(Arc::new(cycles), Arc::new(sessions), attachments, storage)
+    }
+
+    fn command(cycle_id: CycleId, user_id: UserId, bytes: Vec<u8>) -> AddAttachmentCommand {
+        AddAttachmentCommand {
+            cycle_id,
+            component_type: ComponentType::Alternatives,
+            file_name: "whiteboard.jpg".to_string(),
+            content_type: "image/jpeg".to_string(),
+            caption: Some("Whiteboard list".to_string()),
+            bytes,
+            user_id,
+        }
+    }
+
+    #[tokio::test]
+    async fn stores_bytes_and_metadata() {
+        let (cycles, sessions, cycle_id) = repositories();
+        let (attachments, storage) = setup_repositories();
+        let handler = create_handler(cycles, sessions, attachments.clone(), storage.clone());
+
+        let attachment = handler
+            .handle(command(cycle_id, owner(), vec![1, 2, 3]))
+            .await
+            .unwrap();
+
+        assert_eq!(attachment.size_bytes, 3);
+        assert_eq!(attachment.component_type, ComponentType::Alternatives);
+        let stored = storage.get(&attachment.storage_key()).await.unwrap();
+        assert_eq!(stored.bytes, vec![1, 2, 3]);
+        assert_eq!(stored.content_type, "image/jpeg");
+        let listed = attachments.list_by_cycle(&cycle_id).await.unwrap();
+        assert_eq!(listed, vec![attachment]);
+    }
+
+    #[tokio::test]
+    async fn rejects_users_who_do_not_own_the_session() {
+        let (cycles, sessions, cycle_id) = repositories();
+        let (attachments, storage) = setup_repositories();
+        let handler = create_handler(cycles, sessions, attachments.clone(), storage);
+
+        let result = handler.handle(command(cycle_id, stranger(), vec![1])).await;
+
+        assert!(matches!(result, Err(AttachmentError::Forbidden)));
+        assert!(attachments
+            .list_by_cycle(&cycle_id)
+            .await
+            .unwrap()
+            .is_empty());
+    }
+
+    #[tokio::test]
+    async fn rejects_oversized_files_before_storing() {
+        let (cycles, sessions, cycle_id) = repositories();
+        let (attachments, storage) = setup_repositories();
+        let handler = create_handler(cycles, sessions, attachments.clone(), storage);
+
+        let result = handler
+            .handle(command(
+                cycle_id,
+                owner(),
+                vec![0; MAX_ATTACHMENT_BYTES + 1],
+            ))
+            .await;
+
+        assert!(matches!(result, Err(AttachmentError::TooLarge { .. })));
+        assert!(attachments
+            .list_by_cycle(&cycle_id)
+            .await
+            .unwrap()
+            .is_empty());
+    }
+
+    #[tokio::test]
+    async fn unknown_cycle_is_not_found() {
+        let (cycles, sessions, _) = repositories();
+        let (attachments, storage) = setup_repositories();
+        let handler = create_handler(cycles, sessions, attachments, storage);
+
+        let result = handler
+            .handle(command(CycleId::new(), owner(), vec![1]))
+            .await;
+
+        assert!(matches!(result, Err(AttachmentError::CycleNotFound(_))));
+    }
+}
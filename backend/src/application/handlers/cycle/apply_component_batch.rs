@@ -0,0 +1,468 @@
+//! ApplyComponentBatchHandler - Command handler for applying several component
+//! commands at once.
+//!
+//! Frontends that edit offline queue `UpdateComponentOutput` and
+//! `CompleteComponent` commands and replay them here on reconnect. The
+//! operations are applied in order to one loaded cycle and saved with a single
+//! update, so either every operation lands or none does.
+//!
+//! After saving, one `component.batch_applied.v1` event is published for the
+//! whole batch, listing each operation in order. The per-component events
+//! (`component.output_updated.v1`, `component.completed.v1`) are not
+//! published; subscribers that track component changes handle the batch
+//! event alongside them.
+
+use std::sync::Arc;
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value as JsonValue;
+
+use crate::application::handlers::audit::CommandAuditor;
+use crate::domain::audit::{AuditRecord, AuditSummary};
+use crate::domain::cycle::Cycle;
+use crate::domain::foundation::{
+    domain_event, CommandMetadata, ComponentType, CycleId, DomainError, EventId,
+    SerializableDomainEvent, Timestamp,
+};
+use crate::ports::{CycleRepository, EventPublisher, SessionRepository};
+
+/// Most operations accepted in one batch.
+pub const MAX_BATCH_OPERATIONS: usize = 100;
+
+/// One command within a batch.
+#[derive(Debug, Clone)]
+pub enum ComponentBatchOperation {
+    /// Replace a component's output, as `UpdateComponentOutput` does.
+    UpdateOutput {
+        component_type: ComponentType,
+        output: JsonValue,
+    },
+    /// Complete a component, as `CompleteComponent` does.
+    Complete { component_type: ComponentType },
+}
+
+impl ComponentBatchOperation {
+    fn applied(&self) -> AppliedComponentOperation {
+        match self {
+            ComponentBatchOperation::UpdateOutput { component_type, .. } => {
+                AppliedComponentOperation {
+                    kind: ComponentOperationKind::UpdateOutput,
+                    component_type: *component_type,
+                }
+            }
+            ComponentBatchOperation::Complete { component_type } => AppliedComponentOperation {
+                kind: ComponentOperationKind::Complete,
+                component_type: *component_type,
+            },
+        }
+    }
+
+    fn apply(self, cycle: &mut Cycle) -> Result<(), DomainError> {
+        match self {
+            ComponentBatchOperation::UpdateOutput {
+                component_type,
+                output,
+            } => cycle.update_component_output(component_type, output),
+            ComponentBatchOperation::Complete { component_type } => {
+                cycle.complete_component(component_type)
+            }
+        }
+    }
+}
+
+/// Command to apply a batch of component operations to a cycle.
+#[derive(Debug, Clone)]
+pub struct ApplyComponentBatchCommand {
+    /// The cycle containing the components.
+    pub cycle_id: CycleId,
+    /// Operations to apply, in order.
+    pub operations: Vec<ComponentBatchOperation>,
+}
+
+/// Result of successfully applying a batch.
+#[derive(Debug, Clone)]
+pub struct ApplyComponentBatchResult {
+    /// The updated cycle.
+    pub cycle: Cycle,
+    /// The emitted event.
+    pub event: ComponentBatchAppliedEvent,
+    /// Cycle version after the batch, for reconciling optimistic updates.
+    pub version: u64,
+    /// IDs of the events this command published.
+    pub event_ids: Vec<EventId>,
+}
+
+/// What an operation in an applied batch did.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ComponentOperationKind {
+    UpdateOutput,
+    Complete,
+}
+
+/// An operation recorded in a [`ComponentBatchAppliedEvent`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AppliedComponentOperation {
+    pub kind: ComponentOperationKind,
+    pub component_type: ComponentType,
+}
+
+/// Event published when a batch of component operations is applied.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ComponentBatchAppliedEvent {
+    /// Unique event identifier.
+    pub event_id: EventId,
+    /// The cycle containing the components.
+    pub cycle_id: CycleId,
+    /// The operations applied, in order.
+    pub operations: Vec<AppliedComponentOperation>,
+    /// When the batch was applied.
+    pub applied_at: Timestamp,
+}
+
+domain_event!(
+    ComponentBatchAppliedEvent,
+    event_type = "component.batch_applied.v1",
+    schema_version = 1,
+    aggregate_id = cycle_id,
+    aggregate_type = "Cycle",
+    occurred_at = applied_at,
+    event_id = event_id
+);
+
+/// Error type for applying a batch.
+#[derive(Debug, Clone)]
+pub enum ApplyComponentBatchError {
+    /// Cycle not found.
+    CycleNotFound(CycleId),
+    /// User does not own the cycle's session.
+    Forbidden,
+    /// The batch has no operations, or more than [`MAX_BATCH_OPERATIONS`].
+    InvalidBatch(String),
+    /// An operation was rejected; nothing in the batch was saved.
+    OperationFailed { index: usize, error: DomainError },
+    /// Domain error.
+    Domain(DomainError),
+}
+
+impl std::fmt::Display for ApplyComponentBatchError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ApplyComponentBatchError::CycleNotFound(id) => write!(f, "Cycle not found: {}", id),
+            ApplyComponentBatchError::Forbidden => write!(f, "Permission denied"),
+            ApplyComponentBatchError::InvalidBatch(reason) => write!(f, "{}", reason),
+            ApplyComponentBatchError::OperationFailed { index, error } => {
+                write!(f, "Operation {} failed: {}", index, error)
+            }
+            ApplyComponentBatchError::Domain(err) => write!(f, "{}", err),
+        }
+    }
+}
+
+impl std::error::Error for ApplyComponentBatchError {}
+
+impl From<DomainError> for ApplyComponentBatchError {
+    fn from(err: DomainError) -> Self {
+        ApplyComponentBatchError::Domain(err)
+    }
+}
+
+/// Handler for applying component batches.
+pub struct ApplyComponentBatchHandler {
+    cycle_repository: Arc<dyn CycleRepository>,
+    session_repository: Arc<dyn SessionRepository>,
+    event_publisher: Arc<dyn EventPublisher>,
+    auditor: CommandAuditor,
+}
+
+impl ApplyComponentBatchHandler {
+    pub fn new(
+        cycle_repository: Arc<dyn CycleRepository>,
+        session_repository: Arc<dyn SessionRepository>,
+        event_publisher: Arc<dyn EventPublisher>,
+    ) -> Self {
+        Self {
+            cycle_repository,
+            session_repository,
+            event_publisher,
+            auditor: CommandAuditor::disabled(),
+        }
+    }
+
+    /// Records each command in the audit log.
+    pub fn with_auditor(mut self, auditor: CommandAuditor) -> Self {
+        self.auditor = auditor;
+        self
+    }
+
+    #[tracing::instrument(name = "ApplyComponentBatchHandler::handle", skip_all)]
+    pub async fn handle(
+        &self,
+        cmd: ApplyComponentBatchCommand,
+        metadata: CommandMetadata,
+    ) -> Result<ApplyComponentBatchResult, ApplyComponentBatchError> {
+        if cmd.operations.is_empty() {
+            return Err(ApplyComponentBatchError::InvalidBatch(
+                "Batch has no operations".to_string(),
+            ));
+        }
+        if cmd.operations.len() > MAX_BATCH_OPERATIONS {
+            return Err(ApplyComponentBatchError::InvalidBatch(format!(
+                "Batch has more than {} operations",
+                MAX_BATCH_OPERATIONS
+            )));
+        }
+
+        // 1. Find the cycle and check the user owns its session
+        let mut cycle = self
+            .cycle_repository
+            .find_by_id(&cmd.cycle_id)
+            .await?
+            .ok_or(ApplyComponentBatchError::CycleNotFound(cmd.cycle_id))?;
+        let session = self
+            .session_repository
+            .find_by_id(&cycle.session_id())
+            .await?
+            .ok_or(ApplyComponentBatchError::CycleNotFound(cmd.cycle_id))?;
+        session
+            .authorize(&metadata.user_id)
+            .map_err(|_| ApplyComponentBatchError::Forbidden)?;
+
+        // 2. Apply every operation in memory; the first failure discards
+        //    the whole batch
+        let before = cycle.audit_summary();
+        let applied: Vec<_> = cmd.operations.iter().map(|op| op.applied()).collect();
+        for (index, operation) in cmd.operations.into_iter().enumerate() {
+            operation
+                .apply(&mut cycle)
+                .map_err(|error| ApplyComponentBatchError::OperationFailed { index, error })?;
+        }
+
+        // 3. Persist the cycle once
+        self.cycle_repository.update(&cycle).await?;
+
+        // 4. Publish one event for the whole batch
+        let event = ComponentBatchAppliedEvent {
+            event_id: EventId::new(),
+            cycle_id: cmd.cycle_id,
+            operations: applied,
+            applied_at: Timestamp::now(),
+        };
+        let envelope = event
+            .to_envelope()
+            .with_correlation_id(metadata.correlation_id())
+            .with_user_id(metadata.user_id.to_string());
+
+        self.event_publisher.publish(envelope).await?;
+
+        self.auditor
+            .record(
+                AuditRecord::new(&metadata, "ApplyComponentBatch", "cycle", cmd.cycle_id)
+                    .with_before(before)
+                    .with_after(cycle.audit_summary()),
+            )
+            .await;
+
+        Ok(ApplyComponentBatchResult {
+            version: cycle.version(),
+            event_ids: vec![event.event_id.clone()],
+            cycle,
+            event,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::document_access::fixtures::*;
+    use super::*;
+    use crate::adapters::events::InMemoryEventBus;
+    use crate::domain::foundation::{ComponentStatus, UserId};
+    use serde_json::json;
+
+    fn setup_repositories() -> (
+        Arc<MockCycleRepository>,
+        Arc<MockSessionRepository>,
+        CycleId,
+    ) {
+        let (cycles, sessions, cycle_id) = repositories_with(|cycle| {
+            cycle.start_component(ComponentType::IssueRaising).unwrap();
+            cycle.take_events();
+        });
+        (Arc::new(cycles), Arc::new(sessions), cycle_id)
+    }
+
+    fn create_handler(
+        cycles: Arc<MockCycleRepository>,
+        sessions: Arc<MockSessionRepository>,
+        events: Arc<InMemoryEventBus>,
+    ) -> ApplyComponentBatchHandler {
+        ApplyComponentBatchHandler::new(cycles, sessions, events)
+    }
+
+    fn command(
+        cycle_id: CycleId,
+        operations: Vec<ComponentBatchOperation>,
+    ) -> ApplyComponentBatchCommand {
+        ApplyComponentBatchCommand {
+            cycle_id,
+            operations,
+        }
+    }
+
+    fn metadata(user_id: UserId) -> CommandMetadata {
+        CommandMetadata::new(user_id).with_correlation_id("test-correlation")
+    }
+
+    fn update_then_complete() -> Vec<ComponentBatchOperation> {
+        vec![
+            ComponentBatchOperation::UpdateOutput {
+                component_type: ComponentType::IssueRaising,
+                output: json!({
+                    "potential_decisions": ["Should we move?"],
+                    "objectives": ["Shorter commute"],
+                    "uncertainties": ["Rent increases"],
+                    "considerations": ["Lease ends in June"],
+                    "user_confirmed": true
+                }),
+            },
+            ComponentBatchOperation::Complete {
+                component_type: ComponentType::IssueRaising,
+            },
+        ]
+    }
+
+    #[tokio::test]
+    async fn applies_operations_in_order() {
+        let (cycles, sessions, cycle_id) = setup_repositories();
+        let handler = create_handler(cycles.clone(), sessions, Arc::new(InMemoryEventBus::new()));
+
+        let result = handler
+            .handle(command(cycle_id, update_then_complete()), metadata(owner()))
+            .await
+            .unwrap();
+
+        assert_eq!(
+            result.cycle.component_status(ComponentType::IssueRaising),
+            ComponentStatus::Complete
+        );
+        let stored = cycles.find_by_id(&cycle_id).await.unwrap().unwrap();
+        assert_eq!(
+            stored.component_status(ComponentType::IssueRaising),
+            ComponentStatus::Complete
+        );
+    }
+
+    #[tokio::test]
+    async fn publishes_one_event_for_the_batch() {
+        let (cycles, sessions, cycle_id) = setup_repositories();
+        let events = Arc::new(InMemoryEventBus::new());
+        let handler = create_handler(cycles, sessions, events.clone());
+
+        let result = handler
+            .handle(command(cycle_id, update_then_complete()), metadata(owner()))
+            .await
+            .unwrap();
+
+        let published = events.published_events();
+        assert_eq!(published.len(), 1);
+        assert_eq!(published[0].event_type, "component.batch_applied.v1");
+        assert_eq!(result.event_ids, vec![published[0].event_id.clone()]);
+        assert_eq!(
+            published[0].metadata.correlation_id.as_deref(),
+            Some("test-correlation")
+        );
+        let event: ComponentBatchAppliedEvent = published[0].payload_as().unwrap();
+        assert_eq!(event.cycle_id, cycle_id);
+        assert_eq!(
+            event.operations,
+            vec![
+                AppliedComponentOperation {
+                    kind: ComponentOperationKind::UpdateOutput,
+                    component_type: ComponentType::IssueRaising,
+                },
+                AppliedComponentOperation {
+                    kind: ComponentOperationKind::Complete,
+                    component_type: ComponentType::IssueRaising,
+                },
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn a_failing_operation_saves_nothing() {
+        let (cycles, sessions, cycle_id) = setup_repositories();
+        let events = Arc::new(InMemoryEventBus::new());
+        let handler = create_handler(cycles.clone(), sessions, events.clone());
+        let mut operations = update_then_complete();
+        operations.push(ComponentBatchOperation::Complete {
+            component_type: ComponentType::Tradeoffs,
+        });
+
+        let err = handler
+            .handle(command(cycle_id, operations), metadata(owner()))
+            .await
+            .unwrap_err();
+
+        assert!(matches!(
+            err,
+            ApplyComponentBatchError::OperationFailed { index: 2, .. }
+        ));
+        let stored = cycles.find_by_id(&cycle_id).await.unwrap().unwrap();
+        assert_eq!(
+            stored.component_status(ComponentType::IssueRaising),
+            ComponentStatus::InProgress
+        );
+        assert_eq!(events.event_count(), 0);
+    }
+
+    #[tokio::test]
+    async fn rejects_empty_batches() {
+        let (cycles, sessions, cycle_id) = setup_repositories();
+        let handler = create_handler(cycles, sessions, Arc::new(InMemoryEventBus::new()));
+
+        let err = handler
+            .handle(command(cycle_id, vec![]), metadata(owner()))
+            .await
+            .unwrap_err();
+
+        assert!(matches!(err, ApplyComponentBatchError::InvalidBatch(_)));
+    }
+
+    #[tokio::test]
+    async fn rejects_oversized_batches() {
+        let (cycles, sessions, cycle_id) = setup_repositories();
+        let handler = create_handler(cycles, sessions, Arc::new(InMemoryEventBus::new()));
+        let operations = vec![
+            ComponentBatchOperation::Complete {
+                component_type: ComponentType::IssueRaising,
+            };
+            MAX_BATCH_OPERATIONS + 1
+        ];
+
+        let err = handler
+            .handle(command(cycle_id, operations), metadata(owner()))
+            .await
+            .unwrap_err();
+
+        assert!(matches!(err, ApplyComponentBatchError::InvalidBatch(_)));
+    }
+
+    #[tokio::test]
+    async fn rejects_users_who_do_not_own_the_session() {
+        let (cycles, sessions, cycle_id) = setup_repositories();
+        let events = Arc::new(InMemoryEventBus::new());
+        let handler = create_handler(cycles, sessions, events.clone());
+
+        let err = handler
+            .handle(
+                command(cycle_id, update_then_complete()),
+                metadata(stranger()),
+            )
+            .await
+            .unwrap_err();
+
+        assert!(matches!(err, ApplyComponentBatchError::Forbidden));
+        assert_eq!(events.event_count(), 0);
+    }
+}
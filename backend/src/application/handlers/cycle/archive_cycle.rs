@@ -7,6 +7,8 @@ use std::sync::Arc;
 
 use serde::{Deserialize, Serialize};
 
+use crate::application::handlers::audit::CommandAuditor;
+use crate::domain::audit::{AuditRecord, AuditSummary};
 use crate::domain::cycle::Cycle;
 use crate::domain::foundation::{
     domain_event, CommandMetadata, CycleId, DomainError, EventId, SerializableDomainEvent,
@@ -28,6 +30,10 @@ pub struct ArchiveCycleResult {
     pub cycle: Cycle,
     /// The emitted event.
     pub event: CycleArchivedEvent,
+    /// Cycle version after this command, for reconciling optimistic updates.
+    pub version: u64,
+    /// IDs of the events this command published.
+    pub event_ids: Vec<EventId>,
 }
 
 /// Event published when a cycle is archived.
@@ -81,6 +87,7 @@ impl From<DomainError> for ArchiveCycleError {
 pub struct ArchiveCycleHandler {
     cycle_repository: Arc<dyn CycleRepository>,
     event_publisher: Arc<dyn EventPublisher>,
+    auditor: CommandAuditor,
 }
 
 impl ArchiveCycleHandler {
@@ -91,9 +98,17 @@ impl ArchiveCycleHandler {
         Self {
             cycle_repository,
             event_publisher,
+            auditor: CommandAuditor::disabled(),
         }
     }
 
+    /// Records each command in the audit log.
+    pub fn with_auditor(mut self, auditor: CommandAuditor) -> Self {
+        self.auditor = auditor;
+        self
+    }
+
+    #[tracing::instrument(name = "ArchiveCycleHandler::handle", skip_all)]
     pub async fn handle(
         &self,
         cmd: ArchiveCycleCommand,
@@ -107,6 +122,7 @@ impl ArchiveCycleHandler {
             .ok_or(ArchiveCycleError::CycleNotFound(cmd.cycle_id))?;
 
         // 2. Archive the cycle (domain logic handles validation)
+        let before = cycle.audit_summary();
         cycle.archive()?;
 
         // 3. Persist the updated cycle
@@ -126,7 +142,20 @@ impl ArchiveCycleHandler {
 
         self.event_publisher.publish(envelope).await?;
 
-        Ok(ArchiveCycleResult { cycle, event })
+        self.auditor
+            .record(
+                AuditRecord::new(&metadata, "ArchiveCycle", "cycle", cmd.cycle_id)
+                    .with_before(before)
+                    .with_after(cycle.audit_summary()),
+            )
+            .await;
+
+        Ok(ArchiveCycleResult {
+            version: cycle.version(),
+            event_ids: vec![event.event_id.clone()],
+            cycle,
+            event,
+        })
     }
 }
 
@@ -134,6 +163,7 @@ impl ArchiveCycleHandler {
 mod tests {
     use super::*;
     use crate::domain::foundation::{ComponentType, CycleStatus, ErrorCode, EventEnvelope, SessionId, UserId};
+    use crate::test_support::CycleBuilder;
     use async_trait::async_trait;
     use std::sync::Mutex;
 
@@ -265,27 +295,13 @@ mod tests {
     }
 
     fn create_active_cycle() -> Cycle {
-        let mut cycle = Cycle::new(SessionId::new());
-        cycle.start_component(ComponentType::IssueRaising).unwrap();
-        cycle.take_events(); // Clear setup events
-        cycle
+        CycleBuilder::new()
+            .in_progress(ComponentType::IssueRaising)
+            .build()
     }
 
     fn create_completed_cycle() -> Cycle {
-        use crate::domain::proact::ComponentSequence;
-        let mut cycle = Cycle::new(SessionId::new());
-
-        // Progress through all components except NotesNextSteps (optional)
-        for ct in ComponentSequence::all() {
-            if *ct == ComponentType::NotesNextSteps {
-                continue;
-            }
-            cycle.start_component(*ct).unwrap();
-            cycle.complete_component(*ct).unwrap();
-        }
-        cycle.complete().unwrap();
-        cycle.take_events(); // Clear events
-        cycle
+        CycleBuilder::new().completed().build()
     }
 
     fn create_handler(
@@ -9,6 +9,8 @@ use std::sync::Arc;
 
 use serde::{Deserialize, Serialize};
 
+use crate::application::handlers::audit::CommandAuditor;
+use crate::domain::audit::{AuditRecord, AuditSummary};
 use crate::domain::cycle::Cycle;
 use crate::domain::foundation::{
     domain_event, CommandMetadata, ComponentType, CycleId, DomainError, EventId,
@@ -35,6 +37,10 @@ pub struct BranchCycleResult {
     pub branch: Cycle,
     /// The emitted event.
     pub event: CycleBranchedEvent,
+    /// Cycle version after this command, for reconciling optimistic updates.
+    pub version: u64,
+    /// IDs of the events this command published.
+    pub event_ids: Vec<EventId>,
 }
 
 /// Event published when a cycle is branched.
@@ -100,6 +106,7 @@ pub struct BranchCycleHandler {
     cycle_repository: Arc<dyn CycleRepository>,
     access_checker: Arc<dyn AccessChecker>,
     event_publisher: Arc<dyn EventPublisher>,
+    auditor: CommandAuditor,
 }
 
 impl BranchCycleHandler {
@@ -112,9 +119,17 @@ impl BranchCycleHandler {
             cycle_repository,
             access_checker,
             event_publisher,
+            auditor: CommandAuditor::disabled(),
         }
     }
 
+    /// Records each command in the audit log.
+    pub fn with_auditor(mut self, auditor: CommandAuditor) -> Self {
+        self.auditor = auditor;
+        self
+    }
+
+    #[tracing::instrument(name = "BranchCycleHandler::handle", skip_all)]
     pub async fn handle(
         &self,
         cmd: BranchCycleCommand,
@@ -162,7 +177,19 @@ impl BranchCycleHandler {
 
         self.event_publisher.publish(envelope).await?;
 
-        Ok(BranchCycleResult { branch, event })
+        self.auditor
+            .record(
+                AuditRecord::new(&metadata, "BranchCycle", "cycle", branch.id())
+                    .with_after(branch.audit_summary()),
+            )
+            .await;
+
+        Ok(BranchCycleResult {
+            version: branch.version(),
+            event_ids: vec![event.event_id.clone()],
+            branch,
+            event,
+        })
     }
 }
 
@@ -172,6 +199,7 @@ mod tests {
     use crate::domain::foundation::{ErrorCode, EventEnvelope, UserId};
     use crate::domain::membership::TierLimits;
     use crate::ports::{AccessDeniedReason, UsageStats};
+    use crate::test_support::CycleBuilder;
     use async_trait::async_trait;
     use std::sync::Mutex;
 
@@ -377,12 +405,10 @@ mod tests {
     }
 
     fn create_parent_cycle_with_started_component() -> Cycle {
-        let session_id = SessionId::new();
-        let mut cycle = Cycle::new(session_id);
         // Start IssueRaising so we can branch at it
-        cycle.start_component(ComponentType::IssueRaising).unwrap();
-        cycle.take_events(); // Clear events from setup
-        cycle
+        CycleBuilder::new()
+            .in_progress(ComponentType::IssueRaising)
+            .build()
     }
 
     fn create_handler(
@@ -7,6 +7,8 @@ use std::sync::Arc;
 
 use serde::{Deserialize, Serialize};
 
+use crate::application::handlers::audit::CommandAuditor;
+use crate::domain::audit::{AuditRecord, AuditSummary};
 use crate::domain::cycle::Cycle;
 use crate::domain::foundation::{
     domain_event, CommandMetadata, ComponentType, CycleId, DomainError, EventId,
@@ -30,6 +32,10 @@ pub struct CompleteComponentResult {
     pub cycle: Cycle,
     /// The emitted event.
     pub event: ComponentCompletedEvent,
+    /// Cycle version after this command, for reconciling optimistic updates.
+    pub version: u64,
+    /// IDs of the events this command published.
+    pub event_ids: Vec<EventId>,
 }
 
 /// Event published when a component is completed.
@@ -85,6 +91,7 @@ impl From<DomainError> for CompleteComponentError {
 pub struct CompleteComponentHandler {
     cycle_repository: Arc<dyn CycleRepository>,
     event_publisher: Arc<dyn EventPublisher>,
+    auditor: CommandAuditor,
 }
 
 impl CompleteComponentHandler {
@@ -95,9 +102,17 @@ impl CompleteComponentHandler {
         Self {
             cycle_repository,
             event_publisher,
+            auditor: CommandAuditor::disabled(),
         }
     }
 
+    /// Records each command in the audit log.
+    pub fn with_auditor(mut self, auditor: CommandAuditor) -> Self {
+        self.auditor = auditor;
+        self
+    }
+
+    #[tracing::instrument(name = "CompleteComponentHandler::handle", skip_all)]
     pub async fn handle(
         &self,
         cmd: CompleteComponentCommand,
@@ -111,6 +126,7 @@ impl CompleteComponentHandler {
             .ok_or(CompleteComponentError::CycleNotFound(cmd.cycle_id))?;
 
         // 2. Complete the component (domain logic handles validation)
+        let before = cycle.audit_summary();
         cycle.complete_component(cmd.component_type)?;
 
         // 3. Persist the updated cycle
@@ -131,7 +147,20 @@ impl CompleteComponentHandler {
 
         self.event_publisher.publish(envelope).await?;
 
-        Ok(CompleteComponentResult { cycle, event })
+        self.auditor
+            .record(
+                AuditRecord::new(&metadata, "CompleteComponent", "cycle", cmd.cycle_id)
+                    .with_before(before)
+                    .with_after(cycle.audit_summary()),
+            )
+            .await;
+
+        Ok(CompleteComponentResult {
+            version: cycle.version(),
+            event_ids: vec![event.event_id.clone()],
+            cycle,
+            event,
+        })
     }
 }
 
@@ -4,6 +4,8 @@ use std::sync::Arc;
 
 use serde::{Deserialize, Serialize};
 
+use crate::application::handlers::audit::CommandAuditor;
+use crate::domain::audit::{AuditRecord, AuditSummary};
 use crate::domain::cycle::Cycle;
 use crate::domain::foundation::{
     domain_event, CommandMetadata, CycleId, DomainError, EventId, SerializableDomainEvent,
@@ -25,6 +27,10 @@ pub struct CreateCycleResult {
     pub cycle: Cycle,
     /// The emitted event.
     pub event: CycleCreatedEvent,
+    /// Cycle version after this command, for reconciling optimistic updates.
+    pub version: u64,
+    /// IDs of the events this command published.
+    pub event_ids: Vec<EventId>,
 }
 
 /// Event published when a cycle is created.
@@ -91,6 +97,7 @@ pub struct CreateCycleHandler {
     session_repository: Arc<dyn SessionRepository>,
     access_checker: Arc<dyn AccessChecker>,
     event_publisher: Arc<dyn EventPublisher>,
+    auditor: CommandAuditor,
 }
 
 impl CreateCycleHandler {
@@ -105,9 +112,17 @@ impl CreateCycleHandler {
             session_repository,
             access_checker,
             event_publisher,
+            auditor: CommandAuditor::disabled(),
         }
     }
 
+    /// Records each command in the audit log.
+    pub fn with_auditor(mut self, auditor: CommandAuditor) -> Self {
+        self.auditor = auditor;
+        self
+    }
+
+    #[tracing::instrument(name = "CreateCycleHandler::handle", skip_all)]
     pub async fn handle(
         &self,
         cmd: CreateCycleCommand,
@@ -154,7 +169,19 @@ impl CreateCycleHandler {
 
         self.event_publisher.publish(envelope).await?;
 
-        Ok(CreateCycleResult { cycle, event })
+        self.auditor
+            .record(
+                AuditRecord::new(&metadata, "CreateCycle", "cycle", cycle.id())
+                    .with_after(cycle.audit_summary()),
+            )
+            .await;
+
+        Ok(CreateCycleResult {
+            version: cycle.version(),
+            event_ids: vec![event.event_id.clone()],
+            cycle,
+            event,
+        })
     }
 }
 
@@ -0,0 +1,210 @@
+//! Shared ownership check for the attachment handlers, plus repository
+//! fixtures for tests of handlers that act on a cycle's documents.
+//!
+//! Attachments belong to a cycle, and only the owner of the cycle's session
+//! may add, list, read, or remove them.
+
+use crate::domain::cycle::Cycle;
+use crate::domain::document::AttachmentError;
+use crate::domain::foundation::{CycleId, UserId};
+use crate::ports::{CycleRepository, DocumentStorageError, SessionRepository};
+
+/// Loads the cycle and verifies `user_id` owns its session.
+pub(super) async fn authorize_cycle(
+    cycle_repository: &dyn CycleRepository,
+    session_repository: &dyn SessionRepository,
+    cycle_id: &CycleId,
+    user_id: &UserId,
+) -> Result<Cycle, AttachmentError> {
+    let cycle = cycle_repository
+        .find_by_id(cycle_id)
+        .await?
+        .ok_or(AttachmentError::CycleNotFound(*cycle_id))?;
+    let session = session_repository
+        .find_by_id(&cycle.session_id())
+        .await?
+        .ok_or(AttachmentError::CycleNotFound(*cycle_id))?;
+    session.authorize(user_id)?;
+    Ok(cycle)
+}
+
+pub(super) fn storage_error(err: DocumentStorageError) -> AttachmentError {
+    AttachmentError::Infrastructure(format!("Attachment storage failed: {}", err))
+}
+
+#[cfg(test)]
+pub(super) mod fixtures {
+    //! Repositories holding one session and its cycle, for handler tests.
+
+    use std::sync::Mutex;
+
+    use async_trait::async_trait;
+
+    use super::*;
+    use crate::domain::foundation::{DomainError, SessionId};
+    use crate::domain::session::Session;
+
+    pub struct MockCycleRepository {
+        cycles: Mutex<Vec<Cycle>>,
+    }
+
+    #[async_trait]
+    impl CycleRepository for MockCycleRepository {
+        async fn save(&self, cycle: &Cycle) -> Result<(), DomainError> {
+            self.cycles.lock().unwrap().push(cycle.clone());
+            Ok(())
+        }
+
+        async fn update(&self, cycle: &Cycle) -> Result<(), DomainError> {
+            let mut cycles = self.cycles.lock().unwrap();
+            if let Some(existing) = cycles.iter_mut().find(|c| c.id() == cycle.id()) {
+                *existing = cycle.clone();
+            }
+            Ok(())
+        }
+
+        async fn find_by_id(&self, id: &CycleId) -> Result<Option<Cycle>, DomainError> {
+            Ok(self
+                .cycles
+                .lock()
+                .unwrap()
+                .iter()
+                .find(|c| c.id() == *id)
+                .cloned())
+        }
+
+        async fn exists(&self, id: &CycleId) -> Result<bool, DomainError> {
+            Ok(self.cycles.lock().unwrap().iter().any(|c| c.id() == *id))
+        }
+
+        async fn find_by_session_id(
+            &self,
+            _session_id: &SessionId,
+        ) -> Result<Vec<Cycle>, DomainError> {
+            Ok(vec![])
+        }
+
+        async fn find_primary_by_session_id(
+            &self,
+            _session_id: &SessionId,
+        ) -> Result<Option<Cycle>, DomainError> {
+            Ok(None)
+        }
+
+        async fn find_branches(&self, _parent_id: &CycleId) -> Result<Vec<Cycle>, DomainError> {
+            Ok(vec![])
+        }
+
+        async fn count_by_session_id(&self, _session_id: &SessionId) -> Result<u32, DomainError> {
+            Ok(0)
+        }
+
+        async fn delete(&self, _id: &CycleId) -> Result<(), DomainError> {
+            Ok(())
+        }
+    }
+
+    pub struct MockSessionRepository {
+        sessions: Mutex<Vec<Session>>,
+    }
+
+    #[async_trait]
+    impl SessionRepository for MockSessionRepository {
+        async fn save(&self, session: &Session) -> Result<(), DomainError> {
+            self.sessions.lock().unwrap().push(session.clone());
+            Ok(())
+        }
+
+        async fn update(&self, _session: &Session) -> Result<(), DomainError> {
+            Ok(())
+        }
+
+        async fn find_by_id(&self, id: &SessionId) -> Result<Option<Session>, DomainError> {
+            Ok(self
+                .sessions
+                .lock()
+                .unwrap()
+                .iter()
+                .find(|s| s.id() == id)
+                .cloned())
+        }
+
+        async fn exists(&self, id: &SessionId) -> Result<bool, DomainError> {
+            Ok(self.sessions.lock().unwrap().iter().any(|s| s.id() == id))
+        }
+
+        async fn find_by_user_id(&self, _user_id: &UserId) -> Result<Vec<Session>, DomainError> {
+            Ok(vec![])
+        }
+
+        async fn count_active_by_user(&self, _user_id: &UserId) -> Result<u32, DomainError> {
+            Ok(0)
+        }
+
+        async fn delete(&self, _id: &SessionId) -> Result<(), DomainError> {
+            Ok(())
+        }
+    }
+
+    pub fn owner() -> UserId {
+        UserId::new("owner-1").unwrap()
+    }
+
+    pub fn stranger() -> UserId {
+        UserId::new("stranger-1").unwrap()
+    }
+
+    /// Repositories holding one cycle in a session owned by [`owner`].
+    pub fn repositories() -> (MockCycleRepository, MockSessionRepository, CycleId) {
+        repositories_with(|_| {})
+    }
+
+    /// Like [`repositories`], with the cycle set up by `prepare` first.
+    pub fn repositories_with(
+        prepare: impl FnOnce(&mut Cycle),
+    ) -> (MockCycleRepository, MockSessionRepository, CycleId) {
+        let session = Session::new(SessionId::new(), owner(), "Move?".to_string()).unwrap();
+        let mut cycle = Cycle::new(*session.id());
+        prepare(&mut cycle);
+        let cycle_id = cycle.id();
+        (
+            MockCycleRepository {
+                cycles: Mutex::new(vec![cycle]),
+            },
+            MockSessionRepository {
+                sessions: Mutex::new(vec![session]),
+            },
+            cycle_id,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::fixtures::*;
+    use super::*;
+
+    #[tokio::test]
+    async fn owner_is_authorized() {
+        let (cycles, sessions, cycle_id) = repositories();
+        let cycle = authorize_cycle(&cycles, &sessions, &cycle_id, &owner())
+            .await
+            .unwrap();
+        assert_eq!(cycle.id(), cycle_id);
+    }
+
+    #[tokio::test]
+    async fn other_users_are_forbidden() {
+        let (cycles, sessions, cycle_id) = repositories();
+        let result = authorize_cycle(&cycles, &sessions, &cycle_id, &stranger()).await;
+        assert!(matches!(result, Err(AttachmentError::Forbidden)));
+    }
+
+    #[tokio::test]
+    async fn unknown_cycle_is_not_found() {
+        let (cycles, sessions, _) = repositories();
+        let missing = CycleId::new();
+        let result = authorize_cycle(&cycles, &sessions, &missing, &owner()).await;
+        assert!(matches!(result, Err(AttachmentError::CycleNotFound(id)) if id == missing));
+    }
+}
@@ -0,0 +1,370 @@
+//! DraftImportFromTextHandler - Command handler for AI-drafting component
+//! outputs from pasted notes.
+//!
+//! Sends the user's pros/cons list or memo to the AI with the
+//! `add_objective`, `add_alternative`, and rating tools, extracts the tool
+//! calls from the reply with `DataExtractor`, and stores the result as a
+//! pending `ImportDraft`. The cycle is not changed until the draft is
+//! confirmed with [`super::ResolveImportDraftHandler`].
+
+use std::sync::Arc;
+
+use serde::Deserialize;
+use serde_json::json;
+
+use crate::application::handlers::audit::CommandAuditor;
+use crate::domain::audit::AuditRecord;
+use crate::domain::conversation::tools::ToolCall;
+use crate::domain::conversation::DataExtractor;
+use crate::domain::cycle::Cycle;
+use crate::domain::document::{text_import_tools, ImportDraft, MAX_IMPORT_TEXT_CHARS};
+use crate::domain::foundation::{
+    CommandMetadata, ComponentType, ConversationId, CycleId, DomainError,
+};
+use crate::domain::proact::{AlternativesOutput, ObjectivesOutput};
+use crate::ports::{
+    AIError, AIProvider, CompletionRequest, CycleRepository, ImportDraftRepository, MessageRole,
+    RequestMetadata, SessionRepository,
+};
+
+use super::import_consequences::output;
+
+/// Instructions for turning notes into tool calls.
+const TEXT_IMPORT_PROMPT: &str = "You turn a user's existing notes about a decision \
+(a pros/cons list, memo, or comparison) into structured decision data. Call the tools below \
+for every fundamental objective, alternative, and consequence the notes clearly state. \
+Do not invent anything the notes do not say. Ratings compare each alternative with the \
+status quo from -2 (much worse) to +2 (much better); when rating, put the alternative's name \
+in alternative_id and the objective's name in objective_id. Reply with only a JSON array of \
+calls, each shaped {\"name\": <tool name>, \"parameters\": {...}}.";
+
+/// Command to draft objectives, alternatives, and ratings from pasted text.
+#[derive(Debug, Clone)]
+pub struct DraftImportFromTextCommand {
+    pub cycle_id: CycleId,
+    pub text: String,
+}
+
+/// Error type for drafting an import from text.
+#[derive(Debug, Clone)]
+pub enum DraftImportFromTextError {
+    /// Cycle not found.
+    CycleNotFound(CycleId),
+    /// User does not own the cycle's session.
+    Forbidden,
+    /// The pasted text is empty or too long.
+    InvalidText(String),
+    /// The AI provider failed.
+    AIProvider(String),
+    /// The AI's reply held nothing that could be imported.
+    NothingDrafted(String),
+    /// Domain error.
+    Domain(DomainError),
+}
+
+impl std::fmt::Display for DraftImportFromTextError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DraftImportFromTextError::CycleNotFound(id) => write!(f, "Cycle not found: {}", id),
+            DraftImportFromTextError::Forbidden => write!(f, "Permission denied"),
+            DraftImportFromTextError::InvalidText(msg) => write!(f, "{}", msg),
+            DraftImportFromTextError::AIProvider(msg) => write!(f, "AI provider error: {}", msg),
+            DraftImportFromTextError::NothingDrafted(msg) => {
+                write!(f, "Nothing could be drafted from the text: {}", msg)
+            }
+            DraftImportFromTextError::Domain(err) => write!(f, "{}", err),
+        }
+    }
+}
+
+impl std::error::Error for DraftImportFromTextError {}
+
+impl From<DomainError> for DraftImportFromTextError {
+    fn from(err: DomainError) -> Self {
+        DraftImportFromTextError::Domain(err)
+    }
+}
+
+impl From<AIError> for DraftImportFromTextError {
+    fn from(err: AIError) -> Self {
+        DraftImportFromTextError::AIProvider(err.to_string())
+    }
+}
+
+/// The reply may be a bare array or wrapped in an object.
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum ToolCallReply {
+    Calls(Vec<ToolCall>),
+    Wrapped { tool_calls: Vec<ToolCall> },
+}
+
+/// Handler for drafting imports from pasted text.
+pub struct DraftImportFromTextHandler {
+    cycle_repository: Arc<dyn CycleRepository>,
+    session_repository: Arc<dyn SessionRepository>,
+    ai_provider: Arc<dyn AIProvider>,
+    drafts: Arc<dyn ImportDraftRepository>,
+    extractor: DataExtractor,
+    auditor: CommandAuditor,
+}
+
+impl DraftImportFromTextHandler {
+    pub fn new(
+        cycle_repository: Arc<dyn CycleRepository>,
+        session_repository: Arc<dyn SessionRepository>,
+        ai_provider: Arc<dyn AIProvider>,
+        drafts: Arc<dyn ImportDraftRepository>,
+    ) -> Self {
+        Self {
+            cycle_repository,
+            session_repository,
+            ai_provider,
+            drafts,
+            extractor: DataExtractor::new(),
+            auditor: CommandAuditor::disabled(),
+        }
+    }
+
+    /// Records each command in the audit log.
+    pub fn with_auditor(mut self, auditor: CommandAuditor) -> Self {
+        self.auditor = auditor;
+        self
+    }
+
+    #[tracing::instrument(name = "DraftImportFromTextHandler::handle", skip_all)]
+    pub async fn handle(
+        &self,
+        cmd: DraftImportFromTextCommand,
+        metadata: CommandMetadata,
+    ) -> Result<ImportDraft, DraftImportFromTextError> {
+        // 1. Validate the text
+        let text = cmd.text.trim();
+        if text.is_empty() {
+            return Err(DraftImportFromTextError::InvalidText(
+                "Paste some notes to import".to_string(),
+            ));
+        }
+        if text.chars().count() > MAX_IMPORT_TEXT_CHARS {
+            return Err(DraftImportFromTextError::InvalidText(format!(
+                "Notes are limited to {} characters",
+                MAX_IMPORT_TEXT_CHARS
+            )));
+        }
+
+        // 2. Check the user owns the cycle's session
+        let cycle = self
+            .cycle_repository
+            .find_by_id(&cmd.cycle_id)
+            .await?
+            .ok_or(DraftImportFromTextError::CycleNotFound(cmd.cycle_id))?;
+        let session = self
+            .session_repository
+            .find_by_id(&cycle.session_id())
+            .await?
+            .ok_or(DraftImportFromTextError::CycleNotFound(cmd.cycle_id))?;
+        session
+            .authorize(&metadata.user_id)
+            .map_err(|_| DraftImportFromTextError::Forbidden)?;
+
+        // 3. Ask the AI for tool calls
+        let request = CompletionRequest::new(
+            RequestMetadata::new(
+                metadata.user_id.clone(),
+                cycle.session_id(),
+                ConversationId::new(),
+                format!("import-{}", cmd.cycle_id),
+            )
+            .with_cycle_id(cmd.cycle_id),
+        )
+        .with_system_prompt(system_prompt(&cycle)?)
+        .with_message(MessageRole::User, text)
+        .with_temperature(0.2);
+        let response = self.ai_provider.complete(request).await?;
+
+        // 4. Collect the calls into a pending draft
+        let extracted = self
+            .extractor
+            .extract(ComponentType::Consequences, &response.content)
+            .map_err(|e| DraftImportFromTextError::NothingDrafted(e.to_string()))?;
+        let calls = match serde_json::from_value(extracted.data)
+            .map_err(|e| DraftImportFromTextError::NothingDrafted(e.to_string()))?
+        {
+            ToolCallReply::Calls(calls) | ToolCallReply::Wrapped { tool_calls: calls } => calls,
+        };
+        let draft = ImportDraft::from_tool_calls(cmd.cycle_id, metadata.user_id.clone(), calls)
+            .ok_or_else(|| {
+                DraftImportFromTextError::NothingDrafted(
+                    "no objectives, alternatives, or ratings were found".to_string(),
+                )
+            })?;
+
+        self.drafts.save(&draft).await?;
+
+        self.auditor
+            .record(
+                AuditRecord::new(&metadata, "DraftImportFromText", "import_draft", draft.id())
+                    .with_after(json!({
+                        "cycle_id": draft.cycle_id,
+                        "objectives": draft.objectives.len(),
+                        "alternatives": draft.alternatives.len(),
+                        "ratings": draft.ratings.len(),
+                    })),
+            )
+            .await;
+
+        Ok(draft)
+    }
+}
+
+/// The prompt, the tool schemas, and the names already in the cycle so
+/// the AI reuses them instead of drafting duplicates.
+fn system_prompt(cycle: &Cycle) -> Result<String, DomainError> {
+    let objectives: ObjectivesOutput = output(cycle, ComponentType::Objectives)?;
+    let alternatives: AlternativesOutput = output(cycle, ComponentType::Alternatives)?;
+    let tools: Vec<_> = text_import_tools()
+        .iter()
+        .map(|tool| tool.to_anthropic_format())
+        .collect();
+
+    let mut prompt = format!(
+        "{}\n\nTools:\n{}",
+        TEXT_IMPORT_PROMPT,
+        serde_json::Value::Array(tools)
+    );
+    let existing: Vec<&str> = objectives
+        .fundamental_objectives
+        .iter()
+        .map(|o| o.description.as_str())
+        .collect();
+    if !existing.is_empty() {
+        prompt.push_str(&format!(
+            "\n\nObjectives already in this decision: {}",
+            existing.join("; ")
+        ));
+    }
+    let existing: Vec<&str> = alternatives
+        .options
+        .iter()
+        .map(|a| a.name.as_str())
+        .collect();
+    if !existing.is_empty() {
+        prompt.push_str(&format!(
+            "\n\nAlternatives already in this decision: {}",
+            existing.join("; ")
+        ));
+    }
+    Ok(prompt)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::document_access::fixtures::*;
+    use super::*;
+    use crate::adapters::ai::MockAIProvider;
+    use crate::adapters::document::InMemoryImportDraftRepository;
+
+    const REPLY: &str = r#"Here is what I found:
+```json
+[
+  {"name": "add_objective", "parameters": {"name": "Commute", "measure": "Minutes per day", "direction": "lower", "is_fundamental": true}},
+  {"name": "add_alternative", "parameters": {"name": "Move downtown", "description": "Rent near the office", "is_status_quo": false}},
+  {"name": "rate_consequence", "parameters": {"alternative_id": "Move downtown", "objective_id": "Commute", "rating": 2, "reasoning": "Ten minute walk", "confidence": "high"}}
+]
+```"#;
+
+    fn create_handler(
+        cycles: MockCycleRepository,
+        sessions: MockSessionRepository,
+        ai: Arc<MockAIProvider>,
+        drafts: Arc<InMemoryImportDraftRepository>,
+    ) -> DraftImportFromTextHandler {
+        DraftImportFromTextHandler::new(Arc::new(cycles), Arc::new(sessions), ai, drafts)
+    }
+
+    fn command(cycle_id: CycleId) -> DraftImportFromTextCommand {
+        DraftImportFromTextCommand {
+            cycle_id,
+            text: "Moving downtown: pro - I could walk to work.".to_string(),
+        }
+    }
+
+    #[tokio::test]
+    async fn stores_a_pending_draft_from_the_ai_tool_calls() {
+        let (cycles, sessions, cycle_id) = repositories();
+        let ai = Arc::new(MockAIProvider::new().with_response(REPLY));
+        let drafts = Arc::new(InMemoryImportDraftRepository::new());
+        let handler = create_handler(cycles, sessions, ai.clone(), drafts.clone());
+
+        let draft = handler
+            .handle(command(cycle_id), CommandMetadata::new(owner()))
+            .await
+            .unwrap();
+
+        assert_eq!(draft.objectives.len(), 1);
+        assert_eq!(draft.alternatives.len(), 1);
+        assert_eq!(draft.ratings.len(), 1);
+        assert!(draft.confirmation.is_pending());
+        let pending = drafts.list_pending(&cycle_id).await.unwrap();
+        assert_eq!(pending[0].id(), draft.id());
+
+        let request = &ai.get_calls()[0];
+        assert!(request
+            .system_prompt
+            .as_deref()
+            .unwrap()
+            .contains("add_alternative"));
+        assert_eq!(request.metadata.cycle_id, Some(cycle_id));
+    }
+
+    #[tokio::test]
+    async fn replies_without_tool_calls_draft_nothing() {
+        let (cycles, sessions, cycle_id) = repositories();
+        let ai = Arc::new(MockAIProvider::new().with_response("[]"));
+        let drafts = Arc::new(InMemoryImportDraftRepository::new());
+        let handler = create_handler(cycles, sessions, ai.clone(), drafts.clone());
+
+        let result = handler
+            .handle(command(cycle_id), CommandMetadata::new(owner()))
+            .await;
+
+        assert!(matches!(
+            result,
+            Err(DraftImportFromTextError::NothingDrafted(_))
+        ));
+        assert!(drafts.list_pending(&cycle_id).await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn rejects_empty_text_without_calling_the_ai() {
+        let (cycles, sessions, cycle_id) = repositories();
+        let ai = Arc::new(MockAIProvider::new().with_response(REPLY));
+        let drafts = Arc::new(InMemoryImportDraftRepository::new());
+        let handler = create_handler(cycles, sessions, ai.clone(), drafts.clone());
+        let mut cmd = command(cycle_id);
+        cmd.text = "   ".to_string();
+
+        let result = handler.handle(cmd, CommandMetadata::new(owner())).await;
+
+        assert!(matches!(
+            result,
+            Err(DraftImportFromTextError::InvalidText(_))
+        ));
+        assert_eq!(ai.call_count(), 0);
+    }
+
+    #[tokio::test]
+    async fn rejects_users_who_do_not_own_the_session() {
+        let (cycles, sessions, cycle_id) = repositories();
+        let ai = Arc::new(MockAIProvider::new().with_response(REPLY));
+        let drafts = Arc::new(InMemoryImportDraftRepository::new());
+        let handler = create_handler(cycles, sessions, ai.clone(), drafts.clone());
+
+        let result = handler
+            .handle(command(cycle_id), CommandMetadata::new(stranger()))
+            .await;
+
+        assert!(matches!(result, Err(DraftImportFromTextError::Forbidden)));
+        assert_eq!(ai.call_count(), 0);
+    }
+}
@@ -0,0 +1,363 @@
+//! CycleDocumentMailer - Event handler that emails the decision document.
+//!
+//! On `cycle.completed.v1`, users who opted in via their
+//! `DocumentEmailPreference` get the cycle's decision document as a PDF
+//! attachment. Each send is tracked as a `DocumentDelivery`; deliveries that
+//! fail transiently stay pending and are retried by [`CycleDocumentMailer::run`],
+//! which polls for due deliveries the same way `OutboxPublisher` polls the
+//! outbox.
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use async_trait::async_trait;
+use tokio::sync::watch;
+use tokio::time;
+
+use crate::domain::document::DocumentDelivery;
+use crate::domain::foundation::{DomainError, ErrorCode, EventEnvelope, Timestamp, UserId};
+use crate::ports::{
+    DocumentDeliveryRepository, DocumentEmailPreferenceRepository, EmailAttachment, EmailMessage,
+    EmailSender, EmailTemplateKind, EmailTemplateRenderer, EventHandler, ExportFormat,
+    DEFAULT_EMAIL_LOCALE,
+};
+
+use super::complete_cycle::CycleCompletedEvent;
+use super::export_cycle_document::{
+    ExportCycleDocumentError, ExportCycleDocumentHandler, ExportCycleDocumentQuery,
+};
+
+/// Deliveries retried per poll.
+const RETRY_BATCH_SIZE: u32 = 50;
+
+/// Emails completed decision documents to users who opted in.
+pub struct CycleDocumentMailer {
+    preferences: Arc<dyn DocumentEmailPreferenceRepository>,
+    deliveries: Arc<dyn DocumentDeliveryRepository>,
+    export_handler: Arc<ExportCycleDocumentHandler>,
+    email_sender: Arc<dyn EmailSender>,
+    templates: Arc<dyn EmailTemplateRenderer>,
+}
+
+impl CycleDocumentMailer {
+    pub fn new(
+        preferences: Arc<dyn DocumentEmailPreferenceRepository>,
+        deliveries: Arc<dyn DocumentDeliveryRepository>,
+        export_handler: Arc<ExportCycleDocumentHandler>,
+        email_sender: Arc<dyn EmailSender>,
+        templates: Arc<dyn EmailTemplateRenderer>,
+    ) -> Self {
+        Self {
+            preferences,
+            deliveries,
+            export_handler,
+            email_sender,
+            templates,
+        }
+    }
+
+    /// Retries due deliveries every `poll_interval` until shutdown is signalled.
+    pub async fn run(
+        &self,
+        poll_interval: Duration,
+        mut shutdown: watch::Receiver<bool>,
+    ) -> Result<(), DomainError> {
+        let mut interval = time::interval(poll_interval);
+
+        loop {
+            tokio::select! {
+                _ = shutdown.changed() => {
+                    if *shutdown.borrow() {
+                        return Ok(());
+                    }
+                }
+
+                _ = interval.tick() => {
+                    self.retry_due(RETRY_BATCH_SIZE).await?;
+                }
+            }
+        }
+    }
+
+    /// Attempts up to `limit` due deliveries, returning how many were sent.
+    #[tracing::instrument(name = "CycleDocumentMailer::retry_due", skip_all)]
+    pub async fn retry_due(&self, limit: u32) -> Result<usize, DomainError> {
+        let due = self.deliveries.list_due(Timestamp::now(), limit).await?;
+        let mut sent = 0;
+        for mut delivery in due {
+            self.attempt(&mut delivery).await?;
+            if delivery.sent_at.is_some() {
+                sent += 1;
+            }
+        }
+        Ok(sent)
+    }
+
+    /// Renders and sends the document once, recording the outcome.
+    async fn attempt(&self, delivery: &mut DocumentDelivery) -> Result<(), DomainError> {
+        match self.send(delivery).await {
+            Ok(()) => delivery.record_sent(),
+            Err((error, retryable)) => {
+                tracing::warn!(
+                    delivery_id = %delivery.id,
+                    attempts = delivery.attempts + 1,
+                    retryable,
+                    error = %error,
+                    "Decision document email failed"
+                );
+                delivery.record_failure(error, retryable);
+            }
+        }
+        self.deliveries.save(delivery).await
+    }
+
+    /// Returns the failure message and whether it is worth retrying.
+    async fn send(&self, delivery: &DocumentDelivery) -> Result<(), (String, bool)> {
+        let email = self
+            .templates
+            .render(
+                EmailTemplateKind::CycleCompleted,
+                DEFAULT_EMAIL_LOCALE,
+                &serde_json::json!({}),
+            )
+            .map_err(|e| (e.to_string(), false))?;
+
+        let query = ExportCycleDocumentQuery {
+            cycle_id: delivery.cycle_id,
+            format: ExportFormat::Pdf,
+            user_id: delivery.user_id.clone(),
+            organization: None,
+        };
+        let document = self
+            .export_handler
+            .handle(query)
+            .await
+            .map_err(|e| (e.to_string(), export_error_is_retryable(&e)))?;
+
+        let message = EmailMessage {
+            to: delivery.recipient.clone(),
+            subject: email.subject,
+            text_body: email.text_body,
+            attachments: vec![EmailAttachment {
+                file_name: document.file_name,
+                content_type: "application/pdf".to_string(),
+                bytes: document.bytes,
+            }],
+        };
+        self.email_sender
+            .send(&message)
+            .await
+            .map_err(|e| (e.to_string(), e.is_retryable()))
+    }
+}
+
+/// Tier, ownership, and configuration problems will not fix themselves.
+fn export_error_is_retryable(error: &ExportCycleDocumentError) -> bool {
+    matches!(
+        error,
+        ExportCycleDocumentError::Export(_) | ExportCycleDocumentError::Domain(_)
+    )
+}
+
+#[async_trait]
+impl EventHandler for CycleDocumentMailer {
+    async fn handle(&self, event: EventEnvelope) -> Result<(), DomainError> {
+        let completed: CycleCompletedEvent = serde_json::from_value(event.payload.clone())
+            .map_err(|e| DomainError::new(ErrorCode::ValidationFailed, e.to_string()))?;
+
+        let Some(user_id) = event.metadata.user_id.as_deref() else {
+            tracing::debug!(cycle_id = %completed.cycle_id, "Completed cycle has no user");
+            return Ok(());
+        };
+        let user_id = UserId::new(user_id)
+            .map_err(|e| DomainError::new(ErrorCode::ValidationFailed, e.to_string()))?;
+
+        let preference = match self.preferences.get(&user_id).await? {
+            Some(preference) if preference.enabled => preference,
+            _ => return Ok(()),
+        };
+
+        let mut delivery = DocumentDelivery::new(completed.cycle_id, user_id, preference.email);
+        self.deliveries.save(&delivery).await?;
+        self.attempt(&mut delivery).await
+    }
+
+    fn name(&self) -> &'static str {
+        "CycleDocumentMailer"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::export_cycle_document::fixtures::*;
+    use super::*;
+    use crate::adapters::document::{
+        InMemoryDocumentDeliveryRepository, InMemoryDocumentEmailPreferenceRepository,
+    };
+    use crate::adapters::email::{InMemoryEmailSender, TeraEmailTemplateRenderer};
+    use crate::domain::document::{DeliveryStatus, DocumentEmailPreference};
+    use crate::domain::foundation::{CycleId, EventId, SerializableDomainEvent};
+    use crate::ports::EmailError;
+
+    fn setup_repositories() -> (
+        Arc<InMemoryDocumentEmailPreferenceRepository>,
+        Arc<InMemoryDocumentDeliveryRepository>,
+        Arc<InMemoryEmailSender>,
+    ) {
+        (
+            Arc::new(InMemoryDocumentEmailPreferenceRepository::new()),
+            Arc::new(InMemoryDocumentDeliveryRepository::new()),
+            Arc::new(InMemoryEmailSender::new()),
+        )
+    }
+
+    fn create_handler(
+        cycle_id: CycleId,
+        can_export: bool,
+        preferences: Arc<InMemoryDocumentEmailPreferenceRepository>,
+        deliveries: Arc<InMemoryDocumentDeliveryRepository>,
+        email_sender: Arc<InMemoryEmailSender>,
+    ) -> CycleDocumentMailer {
+        CycleDocumentMailer::new(
+            preferences,
+            deliveries,
+            Arc::new(handler(Some(cycle_view(cycle_id)), can_export, false)),
+            email_sender,
+            Arc::new(TeraEmailTemplateRenderer::new()),
+        )
+    }
+
+    fn user() -> UserId {
+        UserId::new("user-1").unwrap()
+    }
+
+    fn completed(cycle_id: CycleId) -> EventEnvelope {
+        CycleCompletedEvent {
+            event_id: EventId::new(),
+            cycle_id,
+            completed_at: Timestamp::now(),
+        }
+        .to_envelope()
+        .with_user_id("user-1")
+    }
+
+    async fn set_preference(
+        preferences: &InMemoryDocumentEmailPreferenceRepository,
+        enabled: bool,
+    ) {
+        preferences
+            .set(&DocumentEmailPreference::new(
+                user(),
+                "jo@example.com",
+                enabled,
+            ))
+            .await
+            .unwrap();
+    }
+
+    async fn list_deliveries(
+        deliveries: &InMemoryDocumentDeliveryRepository,
+        cycle_id: CycleId,
+    ) -> Vec<DocumentDelivery> {
+        deliveries.list_for_cycle(&cycle_id, &user()).await.unwrap()
+    }
+
+    #[tokio::test]
+    async fn emails_the_pdf_to_opted_in_users() {
+        let cycle_id = CycleId::new();
+        let (preferences, deliveries, email_sender) = setup_repositories();
+        set_preference(&preferences, true).await;
+        let mailer = create_handler(
+            cycle_id,
+            true,
+            preferences,
+            deliveries.clone(),
+            email_sender.clone(),
+        );
+
+        mailer.handle(completed(cycle_id)).await.unwrap();
+
+        let sent = email_sender.sent();
+        assert_eq!(sent.len(), 1);
+        assert_eq!(sent[0].to, "jo@example.com");
+        assert_eq!(sent[0].attachments[0].content_type, "application/pdf");
+        assert!(sent[0].attachments[0].bytes.starts_with(b"%PDF-"));
+
+        let deliveries = list_deliveries(&deliveries, cycle_id).await;
+        assert_eq!(deliveries.len(), 1);
+        assert_eq!(deliveries[0].status, DeliveryStatus::Sent);
+    }
+
+    #[tokio::test]
+    async fn skips_users_who_have_not_opted_in() {
+        let cycle_id = CycleId::new();
+        let (preferences, deliveries, email_sender) = setup_repositories();
+        set_preference(&preferences, false).await;
+        let mailer = create_handler(
+            cycle_id,
+            true,
+            preferences,
+            deliveries.clone(),
+            email_sender.clone(),
+        );
+
+        mailer.handle(completed(cycle_id)).await.unwrap();
+
+        assert!(email_sender.sent().is_empty());
+        assert!(list_deliveries(&deliveries, cycle_id).await.is_empty());
+    }
+
+    #[tokio::test]
+    async fn retries_transient_send_failures() {
+        let cycle_id = CycleId::new();
+        let (preferences, deliveries, email_sender) = setup_repositories();
+        set_preference(&preferences, true).await;
+        email_sender.fail_next(EmailError::Unavailable("timeout".to_string()));
+        let mailer = create_handler(
+            cycle_id,
+            true,
+            preferences,
+            deliveries.clone(),
+            email_sender.clone(),
+        );
+
+        mailer.handle(completed(cycle_id)).await.unwrap();
+
+        let mut delivery = list_deliveries(&deliveries, cycle_id).await.remove(0);
+        assert_eq!(delivery.status, DeliveryStatus::Pending);
+        assert_eq!(delivery.attempts, 1);
+
+        // Not due until the backoff elapses
+        assert_eq!(mailer.retry_due(10).await.unwrap(), 0);
+
+        delivery.next_attempt_at = Timestamp::now();
+        deliveries.save(&delivery).await.unwrap();
+        assert_eq!(mailer.retry_due(10).await.unwrap(), 1);
+
+        let delivery = list_deliveries(&deliveries, cycle_id).await.remove(0);
+        assert_eq!(delivery.status, DeliveryStatus::Sent);
+        assert_eq!(delivery.attempts, 2);
+        assert_eq!(email_sender.sent().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn gives_up_when_export_is_not_in_the_tier() {
+        let cycle_id = CycleId::new();
+        let (preferences, deliveries, email_sender) = setup_repositories();
+        set_preference(&preferences, true).await;
+        let mailer = create_handler(
+            cycle_id,
+            false,
+            preferences,
+            deliveries.clone(),
+            email_sender.clone(),
+        );
+
+        mailer.handle(completed(cycle_id)).await.unwrap();
+
+        let delivery = list_deliveries(&deliveries, cycle_id).await.remove(0);
+        assert_eq!(delivery.status, DeliveryStatus::Failed);
+        assert!(delivery.last_error.is_some());
+        assert!(email_sender.sent().is_empty());
+    }
+}
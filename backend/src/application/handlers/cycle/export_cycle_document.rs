@@ -0,0 +1,642 @@
+//! ExportCycleDocumentHandler - Query handler for exporting the decision document.
+//!
+//! Assembles the cycle's dashboard overview, the session's cycle tree, and the
+//! cycle's attachments into a `DecisionDocument` and renders it with the
+//! exporter for the requested format. Export is a paid-tier feature, checked
+//! via `AccessChecker::can_export`; the stakeholder slide deck additionally
+//! requires the Annual tier. With profile summaries configured, the document
+//! carries the user's reading level so exporters can write plainly.
+
+use std::sync::Arc;
+
+use crate::domain::foundation::{CycleId, DomainError, ErrorCode, Timestamp, UserId};
+use crate::domain::membership::MembershipTier;
+use crate::domain::profile::ReadingLevel;
+use crate::ports::{
+    AccessChecker, AccessDeniedReason, AccessResult, AttachmentRepository, CycleReader,
+    DashboardError, DashboardReader, DecisionDocument, DocumentAttachment, DocumentExportError,
+    DocumentExporter, DocumentStorage, ExportFormat, ExportedDocument, ProfileSummaryRepository,
+};
+
+/// Query to export a cycle's decision document.
+#[derive(Debug, Clone)]
+pub struct ExportCycleDocumentQuery {
+    pub cycle_id: CycleId,
+    pub format: ExportFormat,
+    pub user_id: UserId,
+    /// Organization whose document template applies, if known.
+    pub organization: Option<String>,
+}
+
+/// Result of a successful export.
+pub type ExportCycleDocumentResult = ExportedDocument;
+
+/// Error type for document export.
+#[derive(Debug, Clone)]
+pub enum ExportCycleDocumentError {
+    /// Cycle not found.
+    CycleNotFound(CycleId),
+    /// Cycle belongs to another user.
+    Unauthorized,
+    /// Export not included in the user's tier.
+    AccessDenied(AccessDeniedReason),
+    /// No exporter is configured for the format.
+    UnsupportedFormat(ExportFormat),
+    /// Rendering failed.
+    Export(DocumentExportError),
+    /// Domain error.
+    Domain(DomainError),
+}
+
+impl std::fmt::Display for ExportCycleDocumentError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ExportCycleDocumentError::CycleNotFound(id) => write!(f, "Cycle not found: {}", id),
+            ExportCycleDocumentError::Unauthorized => write!(f, "Unauthorized access to cycle"),
+            ExportCycleDocumentError::AccessDenied(reason) => {
+                write!(f, "{}", reason.user_message())
+            }
+            ExportCycleDocumentError::UnsupportedFormat(format) => {
+                write!(f, "Unsupported export format: {}", format)
+            }
+            ExportCycleDocumentError::Export(err) => write!(f, "{}", err),
+            ExportCycleDocumentError::Domain(err) => write!(f, "{}", err),
+        }
+    }
+}
+
+impl std::error::Error for ExportCycleDocumentError {}
+
+impl From<DomainError> for ExportCycleDocumentError {
+    fn from(err: DomainError) -> Self {
+        ExportCycleDocumentError::Domain(err)
+    }
+}
+
+impl From<DashboardError> for ExportCycleDocumentError {
+    fn from(err: DashboardError) -> Self {
+        match err {
+            DashboardError::CycleNotFound(id) => ExportCycleDocumentError::CycleNotFound(id),
+            DashboardError::Unauthorized => ExportCycleDocumentError::Unauthorized,
+            other => ExportCycleDocumentError::Domain(DomainError::new(
+                ErrorCode::DatabaseError,
+                other.to_string(),
+            )),
+        }
+    }
+}
+
+/// Handler for exporting the decision document.
+pub struct ExportCycleDocumentHandler {
+    cycle_reader: Arc<dyn CycleReader>,
+    dashboard_reader: Arc<dyn DashboardReader>,
+    access_checker: Arc<dyn AccessChecker>,
+    attachment_repository: Arc<dyn AttachmentRepository>,
+    document_storage: Arc<dyn DocumentStorage>,
+    exporters: Vec<Arc<dyn DocumentExporter>>,
+    profile_summaries: Option<Arc<dyn ProfileSummaryRepository>>,
+}
+
+impl ExportCycleDocumentHandler {
+    pub fn new(
+        cycle_reader: Arc<dyn CycleReader>,
+        dashboard_reader: Arc<dyn DashboardReader>,
+        access_checker: Arc<dyn AccessChecker>,
+        attachment_repository: Arc<dyn AttachmentRepository>,
+        document_storage: Arc<dyn DocumentStorage>,
+        exporters: Vec<Arc<dyn DocumentExporter>>,
+    ) -> Self {
+        Self {
+            cycle_reader,
+            dashboard_reader,
+            access_checker,
+            attachment_repository,
+            document_storage,
+            exporters,
+            profile_summaries: None,
+        }
+    }
+
+    /// Renders documents at the reading level stored in each user's profile.
+    pub fn with_profile_summaries(mut self, summaries: Arc<dyn ProfileSummaryRepository>) -> Self {
+        self.profile_summaries = Some(summaries);
+        self
+    }
+
+    /// Best-effort; a lookup failure falls back to the standard level.
+    async fn reading_level_for(&self, user_id: &UserId) -> ReadingLevel {
+        let Some(summaries) = &self.profile_summaries else {
+            return ReadingLevel::Standard;
+        };
+        match summaries.find_by_user(user_id).await {
+            Ok(summary) => summary
+                .map(|s| s.communication.reading_level)
+                .unwrap_or_default(),
+            Err(e) => {
+                tracing::warn!(error = %e, "Failed to load reading level for export");
+                ReadingLevel::Standard
+            }
+        }
+    }
+
+    #[tracing::instrument(name = "ExportCycleDocumentHandler::handle", skip_all)]
+    pub async fn handle(
+        &self,
+        query: ExportCycleDocumentQuery,
+    ) -> Result<ExportCycleDocumentResult, ExportCycleDocumentError> {
+        let exporter = self
+            .exporters
+            .iter()
+            .find(|e| e.format() == query.format)
+            .ok_or(ExportCycleDocumentError::UnsupportedFormat(query.format))?;
+
+        if let AccessResult::Denied(reason) = self.access_checker.can_export(&query.user_id).await?
+        {
+            return Err(ExportCycleDocumentError::AccessDenied(reason));
+        }
+
+        if query.format == ExportFormat::Slides {
+            let limits = self.access_checker.get_tier_limits(&query.user_id).await?;
+            if !limits.can_export_slide_deck() {
+                return Err(ExportCycleDocumentError::AccessDenied(
+                    AccessDeniedReason::FeatureNotIncluded {
+                        feature: "Slide deck export".to_string(),
+                        required_tier: MembershipTier::Annual,
+                    },
+                ));
+            }
+        }
+
+        let cycle = self
+            .cycle_reader
+            .get_by_id(&query.cycle_id)
+            .await?
+            .ok_or(ExportCycleDocumentError::CycleNotFound(query.cycle_id))?;
+
+        // The dashboard reader enforces session ownership
+        let overview = self
+            .dashboard_reader
+            .get_overview(cycle.session_id, Some(query.cycle_id), &query.user_id)
+            .await?;
+        let cycle_tree = self.cycle_reader.get_tree(&cycle.session_id).await?;
+        let attachments = self.load_attachments(&query.cycle_id).await?;
+        let reading_level = self.reading_level_for(&query.user_id).await;
+
+        let document = DecisionDocument {
+            cycle_id: query.cycle_id,
+            overview,
+            cycle_tree,
+            organization: query.organization,
+            attachments,
+            generated_at: Timestamp::now(),
+            reading_level,
+        };
+        exporter
+            .export(&document)
+            .await
+            .map_err(ExportCycleDocumentError::Export)
+    }
+
+    /// Loads attachment metadata, plus the bytes of images so exporters can
+    /// embed them. An image that cannot be read is listed without its bytes
+    /// rather than failing the export.
+    async fn load_attachments(
+        &self,
+        cycle_id: &CycleId,
+    ) -> Result<Vec<DocumentAttachment>, DomainError> {
+        let mut attachments = Vec::new();
+        for attachment in self.attachment_repository.list_by_cycle(cycle_id).await? {
+            let image = if attachment.is_image() {
+                match self.document_storage.get(&attachment.storage_key()).await {
+                    Ok(stored) => Some(stored.bytes),
+                    Err(e) => {
+                        tracing::warn!(
+                            attachment_id = %attachment.id,
+                            error = %e,
+                            "Attachment image unavailable for export"
+                        );
+                        None
+                    }
+                }
+            } else {
+                None
+            };
+            attachments.push(DocumentAttachment { attachment, image });
+        }
+        Ok(attachments)
+    }
+}
+
+/// Mocks for exercising the export handler, shared with handlers that
+/// render documents through it.
+#[cfg(test)]
+pub(super) mod fixtures {
+    use super::*;
+    use crate::adapters::document::{
+        HtmlPageExporter, InMemoryAttachmentRepository, PdfDocumentExporter, SlideDeckExporter,
+    };
+    use crate::adapters::storage::InMemoryDocumentStorage;
+    use crate::domain::dashboard::{ComponentDetailView, CycleComparison, DashboardOverview};
+    use crate::domain::foundation::{ComponentType, CycleStatus, OrganizationId, SessionId};
+    use crate::domain::membership::TierLimits;
+    use crate::ports::{
+        ComponentOutputView, CycleProgressView, CycleSummary, CycleTreeNode, CycleView, UsageStats,
+    };
+    use async_trait::async_trait;
+    use std::collections::HashMap;
+
+    // ─────────────────────────────────────────────────────────────────────
+    // Mock Implementations
+    // ─────────────────────────────────────────────────────────────────────
+
+    pub struct MockCycleReader {
+        pub cycle: Option<CycleView>,
+    }
+
+    #[async_trait]
+    impl CycleReader for MockCycleReader {
+        async fn get_by_id(&self, _id: &CycleId) -> Result<Option<CycleView>, DomainError> {
+            Ok(self.cycle.clone())
+        }
+
+        async fn list_by_session_id(
+            &self,
+            _session_id: &SessionId,
+        ) -> Result<Vec<CycleSummary>, DomainError> {
+            Ok(vec![])
+        }
+
+        async fn list_by_organization(
+            &self,
+            _organization_id: &OrganizationId,
+        ) -> Result<HashMap<SessionId, Vec<CycleSummary>>, DomainError> {
+            Ok(HashMap::new())
+        }
+
+        async fn get_tree(
+            &self,
+            _session_id: &SessionId,
+        ) -> Result<Option<CycleTreeNode>, DomainError> {
+            Ok(None)
+        }
+
+        async fn get_progress(
+            &self,
+            _id: &CycleId,
+        ) -> Result<Option<CycleProgressView>, DomainError> {
+            Ok(None)
+        }
+
+        async fn get_lineage(&self, _id: &CycleId) -> Result<Vec<CycleSummary>, DomainError> {
+            Ok(vec![])
+        }
+
+        async fn get_component_output(
+            &self,
+            _cycle_id: &CycleId,
+            _component_type: ComponentType,
+        ) -> Result<Option<ComponentOutputView>, DomainError> {
+            Ok(None)
+        }
+
+        async fn get_proact_tree_view(
+            &self,
+            _session_id: &SessionId,
+        ) -> Result<Option<crate::domain::cycle::CycleTreeNode>, DomainError> {
+            Ok(None)
+        }
+    }
+
+    pub struct MockDashboardReader {
+        pub unauthorized: bool,
+    }
+
+    #[async_trait]
+    impl DashboardReader for MockDashboardReader {
+        async fn get_overview(
+            &self,
+            session_id: SessionId,
+            _cycle_id: Option<CycleId>,
+            _user_id: &UserId,
+        ) -> Result<DashboardOverview, DashboardError> {
+            if self.unauthorized {
+                return Err(DashboardError::Unauthorized);
+            }
+            Ok(DashboardOverview {
+                session_id,
+                session_title: "Career move".to_string(),
+                decision_statement: None,
+                stakeholders: vec![],
+                objectives: vec![],
+                alternatives: vec![],
+                consequences_table: None,
+                key_uncertainties: vec![],
+                recommendation: None,
+                dq_score: None,
+                active_cycle_id: None,
+                cycle_count: 1,
+                last_updated: chrono::Utc::now(),
+            })
+        }
+
+        async fn get_component_detail(
+            &self,
+            _cycle_id: CycleId,
+            _component_type: ComponentType,
+            _user_id: &UserId,
+        ) -> Result<ComponentDetailView, DashboardError> {
+            Err(DashboardError::InvalidInput("not used".to_string()))
+        }
+
+        async fn compare_cycles(
+            &self,
+            _cycle_ids: &[CycleId],
+            _user_id: &UserId,
+        ) -> Result<CycleComparison, DashboardError> {
+            Err(DashboardError::InvalidInput("not used".to_string()))
+        }
+    }
+
+    pub struct MockAccessChecker {
+        pub can_export: bool,
+    }
+
+    #[async_trait]
+    impl AccessChecker for MockAccessChecker {
+        async fn can_create_session(&self, _user_id: &UserId) -> Result<AccessResult, DomainError> {
+            Ok(AccessResult::Allowed)
+        }
+
+        async fn can_create_cycle(
+            &self,
+            _user_id: &UserId,
+            _session_id: &SessionId,
+        ) -> Result<AccessResult, DomainError> {
+            Ok(AccessResult::Allowed)
+        }
+
+        async fn can_export(&self, _user_id: &UserId) -> Result<AccessResult, DomainError> {
+            if self.can_export {
+                Ok(AccessResult::Allowed)
+            } else {
+                Ok(AccessResult::Denied(
+                    AccessDeniedReason::FeatureNotIncluded {
+                        feature: "export".to_string(),
+                        required_tier: MembershipTier::Monthly,
+                    },
+                ))
+            }
+        }
+
+        async fn get_tier_limits(&self, _user_id: &UserId) -> Result<TierLimits, DomainError> {
+            Ok(TierLimits::for_tier(MembershipTier::Monthly))
+        }
+
+        async fn get_usage(&self, _user_id: &UserId) -> Result<UsageStats, DomainError> {
+            Ok(UsageStats::new())
+        }
+    }
+
+    // ─────────────────────────────────────────────────────────────────────
+    // Helpers
+    // ─────────────────────────────────────────────────────────────────────
+
+    pub fn cycle_view(id: CycleId) -> CycleView {
+        CycleView {
+            id,
+            session_id: SessionId::new(),
+            parent_cycle_id: None,
+            branch_point: None,
+            status: CycleStatus::Active,
+            current_step: ComponentType::Objectives,
+            component_statuses: vec![],
+            progress_percent: 20,
+            is_complete: false,
+            branch_count: 0,
+            created_at: Timestamp::now(),
+            updated_at: Timestamp::now(),
+        }
+    }
+
+    pub fn handler(
+        cycle: Option<CycleView>,
+        can_export: bool,
+        unauthorized: bool,
+    ) -> ExportCycleDocumentHandler {
+        ExportCycleDocumentHandler::new(
+            Arc::new(MockCycleReader { cycle }),
+            Arc::new(MockDashboardReader { unauthorized }),
+            Arc::new(MockAccessChecker { can_export }),
+            Arc::new(InMemoryAttachmentRepository::new()),
+            Arc::new(InMemoryDocumentStorage::new()),
+            vec![
+                Arc::new(PdfDocumentExporter::new()),
+                Arc::new(SlideDeckExporter::new()),
+                Arc::new(HtmlPageExporter::new()),
+            ],
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::fixtures::*;
+    use super::*;
+    use crate::adapters::document::{InMemoryAttachmentRepository, TemplateDocumentExporter};
+    use crate::adapters::storage::InMemoryDocumentStorage;
+    use crate::domain::document::Attachment;
+    use crate::domain::foundation::ComponentType;
+
+    fn query(cycle_id: CycleId) -> ExportCycleDocumentQuery {
+        ExportCycleDocumentQuery {
+            cycle_id,
+            format: ExportFormat::Pdf,
+            user_id: UserId::new("user-1").unwrap(),
+            organization: None,
+        }
+    }
+
+    // ─────────────────────────────────────────────────────────────────────
+    // Tests
+    // ─────────────────────────────────────────────────────────────────────
+
+    #[tokio::test]
+    async fn exports_pdf_for_paid_user() {
+        let cycle_id = CycleId::new();
+        let handler = handler(Some(cycle_view(cycle_id)), true, false);
+
+        let exported = handler.handle(query(cycle_id)).await.unwrap();
+
+        assert_eq!(exported.format, ExportFormat::Pdf);
+        assert_eq!(exported.file_name, "career-move.pdf");
+        assert!(exported.bytes.starts_with(b"%PDF-"));
+    }
+
+    #[tokio::test]
+    async fn denies_tiers_without_export() {
+        let cycle_id = CycleId::new();
+        let handler = handler(Some(cycle_view(cycle_id)), false, false);
+
+        let result = handler.handle(query(cycle_id)).await;
+
+        assert!(matches!(
+            result,
+            Err(ExportCycleDocumentError::AccessDenied(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn slide_deck_requires_annual_tier() {
+        let cycle_id = CycleId::new();
+        let handler = handler(Some(cycle_view(cycle_id)), true, false);
+        let query = ExportCycleDocumentQuery {
+            format: ExportFormat::Slides,
+            ..query(cycle_id)
+        };
+
+        let result = handler.handle(query).await;
+
+        assert!(matches!(
+            result,
+            Err(ExportCycleDocumentError::AccessDenied(
+                AccessDeniedReason::FeatureNotIncluded {
+                    required_tier: MembershipTier::Annual,
+                    ..
+                }
+            ))
+        ));
+    }
+
+    #[tokio::test]
+    async fn returns_not_found_for_missing_cycle() {
+        let cycle_id = CycleId::new();
+        let handler = handler(None, true, false);
+
+        let result = handler.handle(query(cycle_id)).await;
+
+        assert!(
+            matches!(result, Err(ExportCycleDocumentError::CycleNotFound(id)) if id == cycle_id)
+        );
+    }
+
+    #[tokio::test]
+    async fn rejects_other_users_cycles() {
+        let cycle_id = CycleId::new();
+        let handler = handler(Some(cycle_view(cycle_id)), true, true);
+
+        let result = handler.handle(query(cycle_id)).await;
+
+        assert!(matches!(
+            result,
+            Err(ExportCycleDocumentError::Unauthorized)
+        ));
+    }
+
+    #[tokio::test]
+    async fn rejects_formats_without_an_exporter() {
+        let handler = ExportCycleDocumentHandler::new(
+            Arc::new(MockCycleReader { cycle: None }),
+            Arc::new(MockDashboardReader {
+                unauthorized: false,
+            }),
+            Arc::new(MockAccessChecker { can_export: true }),
+            Arc::new(InMemoryAttachmentRepository::new()),
+            Arc::new(InMemoryDocumentStorage::new()),
+            vec![],
+        );
+
+        let result = handler.handle(query(CycleId::new())).await;
+
+        assert!(matches!(
+            result,
+            Err(ExportCycleDocumentError::UnsupportedFormat(
+                ExportFormat::Pdf
+            ))
+        ));
+    }
+
+    #[tokio::test]
+    async fn references_attachments_in_the_document() {
+        let cycle_id = CycleId::new();
+        let attachments = Arc::new(InMemoryAttachmentRepository::new());
+        let storage = Arc::new(InMemoryDocumentStorage::new());
+        let photo = Attachment::new(
+            cycle_id,
+            ComponentType::Alternatives,
+            "whiteboard.jpg",
+            "image/jpeg",
+            4,
+            Some("Whiteboard list".to_string()),
+            UserId::new("user-1").unwrap(),
+        )
+        .unwrap();
+        storage
+            .put(&photo.storage_key(), "image/jpeg", vec![0xFF, 0xD8, 0xFF, 0xD9])
+            .await
+            .unwrap();
+        attachments.save(&photo).await.unwrap();
+
+        let handler = ExportCycleDocumentHandler::new(
+            Arc::new(MockCycleReader {
+                cycle: Some(cycle_view(cycle_id)),
+            }),
+            Arc::new(MockDashboardReader {
+                unauthorized: false,
+            }),
+            Arc::new(MockAccessChecker { can_export: true }),
+            attachments,
+            storage,
+            vec![Arc::new(TemplateDocumentExporter::new(vec![]))],
+        );
+        let query = ExportCycleDocumentQuery {
+            format: ExportFormat::Markdown,
+            ..query(cycle_id)
+        };
+
+        let exported = handler.handle(query).await.unwrap();
+        let markdown = String::from_utf8(exported.bytes).unwrap();
+
+        assert!(markdown.contains(&format!(
+            "![Whiteboard list]({})",
+            photo.download_path()
+        )));
+    }
+
+    #[tokio::test]
+    async fn markdown_follows_the_users_reading_level() {
+        use crate::adapters::InMemoryProfileSummaryRepository;
+        use crate::domain::profile::ProfileSummary;
+
+        let cycle_id = CycleId::new();
+        let summaries = Arc::new(InMemoryProfileSummaryRepository::new());
+        let mut summary = ProfileSummary::default();
+        summary.communication.reading_level = ReadingLevel::Plain;
+        summaries
+            .save(&UserId::new("user-1").unwrap(), &summary)
+            .await
+            .unwrap();
+        let handler = ExportCycleDocumentHandler::new(
+            Arc::new(MockCycleReader {
+                cycle: Some(cycle_view(cycle_id)),
+            }),
+            Arc::new(MockDashboardReader {
+                unauthorized: false,
+            }),
+            Arc::new(MockAccessChecker { can_export: true }),
+            Arc::new(InMemoryAttachmentRepository::new()),
+            Arc::new(InMemoryDocumentStorage::new()),
+            vec![Arc::new(TemplateDocumentExporter::new(vec![]))],
+        )
+        .with_profile_summaries(summaries);
+        let query = ExportCycleDocumentQuery {
+            format: ExportFormat::Markdown,
+            ..query(cycle_id)
+        };
+
+        let exported = handler.handle(query).await.unwrap();
+        let markdown = String::from_utf8(exported.bytes).unwrap();
+
+        assert!(markdown.contains("## What you are deciding"));
+        assert!(!markdown.contains("## Decision Quality"));
+    }
+}
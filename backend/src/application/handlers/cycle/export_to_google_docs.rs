@@ -0,0 +1,322 @@
+//! ExportToGoogleDocsHandler - Command handler for publishing the decision
+//! document to Google Docs.
+//!
+//! Renders the Markdown export (so tier checks, ownership, and organization
+//! templates apply as for downloads) and creates a Google Doc from it in the
+//! user's own Drive. Expired access tokens are refreshed and saved first.
+
+use std::sync::Arc;
+
+use crate::domain::foundation::{CycleId, DomainError, Timestamp, UserId};
+use crate::ports::{
+    ExportFormat, GoogleAccountStore, GoogleDoc, GoogleDocsClient, GoogleDocsError,
+};
+
+use super::export_cycle_document::{
+    ExportCycleDocumentError, ExportCycleDocumentHandler, ExportCycleDocumentQuery,
+};
+
+/// Command to publish a cycle's decision document to Google Docs.
+#[derive(Debug, Clone)]
+pub struct ExportToGoogleDocsCommand {
+    pub cycle_id: CycleId,
+    pub user_id: UserId,
+    /// Organization whose document template applies, if known.
+    pub organization: Option<String>,
+}
+
+/// Result of a successful publish.
+pub type ExportToGoogleDocsResult = GoogleDoc;
+
+/// Error type for publishing to Google Docs.
+#[derive(Debug, Clone)]
+pub enum ExportToGoogleDocsError {
+    /// The user has not connected a Google account.
+    NotConnected,
+    /// Rendering the document failed or was not allowed.
+    Export(ExportCycleDocumentError),
+    /// Google refused or could not be reached.
+    Google(GoogleDocsError),
+    /// Domain error.
+    Domain(DomainError),
+}
+
+impl std::fmt::Display for ExportToGoogleDocsError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ExportToGoogleDocsError::NotConnected => write!(f, "No Google account connected"),
+            ExportToGoogleDocsError::Export(err) => write!(f, "{}", err),
+            ExportToGoogleDocsError::Google(err) => write!(f, "{}", err),
+            ExportToGoogleDocsError::Domain(err) => write!(f, "{}", err),
+        }
+    }
+}
+
+impl std::error::Error for ExportToGoogleDocsError {}
+
+impl From<DomainError> for ExportToGoogleDocsError {
+    fn from(err: DomainError) -> Self {
+        ExportToGoogleDocsError::Domain(err)
+    }
+}
+
+impl From<ExportCycleDocumentError> for ExportToGoogleDocsError {
+    fn from(err: ExportCycleDocumentError) -> Self {
+        ExportToGoogleDocsError::Export(err)
+    }
+}
+
+impl From<GoogleDocsError> for ExportToGoogleDocsError {
+    fn from(err: GoogleDocsError) -> Self {
+        ExportToGoogleDocsError::Google(err)
+    }
+}
+
+/// Handler for publishing the decision document to Google Docs.
+pub struct ExportToGoogleDocsHandler {
+    export_handler: Arc<ExportCycleDocumentHandler>,
+    accounts: Arc<dyn GoogleAccountStore>,
+    google: Arc<dyn GoogleDocsClient>,
+}
+
+impl ExportToGoogleDocsHandler {
+    pub fn new(
+        export_handler: Arc<ExportCycleDocumentHandler>,
+        accounts: Arc<dyn GoogleAccountStore>,
+        google: Arc<dyn GoogleDocsClient>,
+    ) -> Self {
+        Self {
+            export_handler,
+            accounts,
+            google,
+        }
+    }
+
+    #[tracing::instrument(name = "ExportToGoogleDocsHandler::handle", skip_all)]
+    pub async fn handle(
+        &self,
+        cmd: ExportToGoogleDocsCommand,
+    ) -> Result<ExportToGoogleDocsResult, ExportToGoogleDocsError> {
+        let mut tokens = self
+            .accounts
+            .get(&cmd.user_id)
+            .await?
+            .ok_or(ExportToGoogleDocsError::NotConnected)?;
+
+        let query = ExportCycleDocumentQuery {
+            cycle_id: cmd.cycle_id,
+            format: ExportFormat::Markdown,
+            user_id: cmd.user_id.clone(),
+            organization: cmd.organization,
+        };
+        let exported = self.export_handler.handle(query).await?;
+        let markdown = String::from_utf8_lossy(&exported.bytes);
+
+        if tokens.is_expired(Timestamp::now()) {
+            tokens = self.google.refresh(&tokens).await?;
+            self.accounts.save(&cmd.user_id, &tokens).await?;
+        }
+
+        let title = document_title(&markdown, &exported.file_name);
+        Ok(self
+            .google
+            .create_document(&tokens.access_token, &title, &markdown)
+            .await?)
+    }
+}
+
+/// The document's top-level heading, or the download name without its
+/// extension if it has none.
+fn document_title(markdown: &str, file_name: &str) -> String {
+    markdown
+        .lines()
+        .find_map(|line| line.strip_prefix("# "))
+        .map(|title| title.trim().to_string())
+        .filter(|title| !title.is_empty())
+        .unwrap_or_else(|| {
+            file_name
+                .rsplit_once('.')
+                .map_or(file_name, |(stem, _)| stem)
+                .to_string()
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::export_cycle_document::fixtures::*;
+    use super::*;
+    use crate::adapters::document::{InMemoryAttachmentRepository, TemplateDocumentExporter};
+    use crate::adapters::google::InMemoryGoogleAccountStore;
+    use crate::adapters::storage::InMemoryDocumentStorage;
+    use crate::ports::GoogleTokens;
+    use async_trait::async_trait;
+    use secrecy::{ExposeSecret, Secret};
+    use std::sync::Mutex;
+
+    /// Records created documents and refreshes tokens to "refreshed".
+    #[derive(Default)]
+    struct MockGoogleDocsClient {
+        created: Mutex<Vec<(String, String, String)>>,
+    }
+
+    #[async_trait]
+    impl GoogleDocsClient for MockGoogleDocsClient {
+        fn authorization_url(&self, state: &str) -> String {
+            format!("https://accounts.example.com/auth?state={}", state)
+        }
+
+        async fn exchange_code(&self, _code: &str) -> Result<GoogleTokens, GoogleDocsError> {
+            Ok(tokens("exchanged", 3600))
+        }
+
+        async fn refresh(&self, _tokens: &GoogleTokens) -> Result<GoogleTokens, GoogleDocsError> {
+            Ok(tokens("refreshed", 3600))
+        }
+
+        async fn create_document(
+            &self,
+            access_token: &Secret<String>,
+            title: &str,
+            markdown: &str,
+        ) -> Result<GoogleDoc, GoogleDocsError> {
+            self.created.lock().unwrap().push((
+                access_token.expose_secret().clone(),
+                title.to_string(),
+                markdown.to_string(),
+            ));
+            Ok(GoogleDoc {
+                document_id: "doc-1".to_string(),
+                url: "https://docs.google.com/document/d/doc-1/edit".to_string(),
+            })
+        }
+    }
+
+    fn tokens(access: &str, expires_in: u64) -> GoogleTokens {
+        GoogleTokens {
+            access_token: Secret::new(access.to_string()),
+            refresh_token: Some(Secret::new("refresh".to_string())),
+            expires_at: Timestamp::now().plus_secs(expires_in),
+        }
+    }
+
+    fn user() -> UserId {
+        UserId::new("user-1").unwrap()
+    }
+
+    fn setup_repositories() -> (Arc<InMemoryGoogleAccountStore>, Arc<MockGoogleDocsClient>) {
+        (
+            Arc::new(InMemoryGoogleAccountStore::new()),
+            Arc::new(MockGoogleDocsClient::default()),
+        )
+    }
+
+    /// Exports `cycle_id`'s document as Markdown, refusing if `can_export`
+    /// is false.
+    fn create_handler(
+        cycle_id: CycleId,
+        can_export: bool,
+        accounts: Arc<InMemoryGoogleAccountStore>,
+        google: Arc<MockGoogleDocsClient>,
+    ) -> ExportToGoogleDocsHandler {
+        let export_handler = ExportCycleDocumentHandler::new(
+            Arc::new(MockCycleReader {
+                cycle: Some(cycle_view(cycle_id)),
+            }),
+            Arc::new(MockDashboardReader {
+                unauthorized: false,
+            }),
+            Arc::new(MockAccessChecker { can_export }),
+            Arc::new(InMemoryAttachmentRepository::new()),
+            Arc::new(InMemoryDocumentStorage::new()),
+            vec![Arc::new(TemplateDocumentExporter::new(vec![]))],
+        );
+        ExportToGoogleDocsHandler::new(Arc::new(export_handler), accounts, google)
+    }
+
+    fn command(cycle_id: CycleId) -> ExportToGoogleDocsCommand {
+        ExportToGoogleDocsCommand {
+            cycle_id,
+            user_id: user(),
+            organization: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn creates_a_doc_from_the_markdown_export() {
+        let cycle_id = CycleId::new();
+        let (accounts, google) = setup_repositories();
+        accounts
+            .save(&user(), &tokens("access", 3600))
+            .await
+            .unwrap();
+        let handler = create_handler(cycle_id, true, accounts, google.clone());
+
+        let doc = handler.handle(command(cycle_id)).await.unwrap();
+
+        assert_eq!(doc.url, "https://docs.google.com/document/d/doc-1/edit");
+        let created = google.created.lock().unwrap();
+        assert_eq!(created.len(), 1);
+        let (access, title, markdown) = &created[0];
+        assert_eq!(access, "access");
+        assert_eq!(title, "Career move");
+        assert!(markdown.starts_with("# Career move"));
+    }
+
+    #[tokio::test]
+    async fn refreshes_and_saves_expired_tokens() {
+        let cycle_id = CycleId::new();
+        let (accounts, google) = setup_repositories();
+        accounts.save(&user(), &tokens("stale", 0)).await.unwrap();
+        let handler = create_handler(cycle_id, true, accounts.clone(), google.clone());
+
+        handler.handle(command(cycle_id)).await.unwrap();
+
+        assert_eq!(google.created.lock().unwrap()[0].0, "refreshed");
+        let saved = accounts.get(&user()).await.unwrap().unwrap();
+        assert_eq!(saved.access_token.expose_secret(), "refreshed");
+    }
+
+    #[tokio::test]
+    async fn requires_a_connected_account() {
+        let cycle_id = CycleId::new();
+        let (accounts, google) = setup_repositories();
+        let handler = create_handler(cycle_id, true, accounts, google);
+
+        let result = handler.handle(command(cycle_id)).await;
+
+        assert!(matches!(result, Err(ExportToGoogleDocsError::NotConnected)));
+    }
+
+    #[tokio::test]
+    async fn applies_export_tier_checks() {
+        let cycle_id = CycleId::new();
+        let (accounts, google) = setup_repositories();
+        accounts
+            .save(&user(), &tokens("access", 3600))
+            .await
+            .unwrap();
+        let handler = create_handler(cycle_id, false, accounts, google.clone());
+
+        let result = handler.handle(command(cycle_id)).await;
+
+        assert!(matches!(
+            result,
+            Err(ExportToGoogleDocsError::Export(
+                ExportCycleDocumentError::AccessDenied(_)
+            ))
+        ));
+        assert!(google.created.lock().unwrap().is_empty());
+    }
+
+    #[test]
+    fn titles_fall_back_to_the_file_name() {
+        assert_eq!(
+            document_title("# Move abroad?\n\nBody", "x.md"),
+            "Move abroad?"
+        );
+        assert_eq!(
+            document_title("No heading", "career-move.md"),
+            "career-move"
+        );
+    }
+}
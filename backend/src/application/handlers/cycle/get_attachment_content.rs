@@ -0,0 +1,191 @@
+//! GetAttachmentContentHandler - Query handler for downloading an attachment.
+//!
+//! Document exports link to attachments by their download path; this handler
+//! serves those links after checking the reader owns the cycle.
+
+use std::sync::Arc;
+
+use crate::domain::document::{Attachment, AttachmentError};
+use crate::domain::foundation::{AttachmentId, CycleId, UserId};
+use crate::ports::{
+    AttachmentRepository, CycleRepository, DocumentStorage, DocumentStorageError, SessionRepository,
+};
+
+use super::document_access::{authorize_cycle, storage_error};
+
+/// Query for an attachment's content.
+#[derive(Debug, Clone)]
+pub struct GetAttachmentContentQuery {
+    pub cycle_id: CycleId,
+    pub attachment_id: AttachmentId,
+    pub user_id: UserId,
+}
+
+/// An attachment with its bytes.
+#[derive(Debug, Clone)]
+pub struct GetAttachmentContentResult {
+    pub attachment: Attachment,
+    pub bytes: Vec<u8>,
+}
+
+/// Handler for downloading attachments.
+pub struct GetAttachmentContentHandler {
+    cycle_repository: Arc<dyn CycleRepository>,
+    session_repository: Arc<dyn SessionRepository>,
+    attachment_repository: Arc<dyn AttachmentRepository>,
+    document_storage: Arc<dyn DocumentStorage>,
+}
+
+impl GetAttachmentContentHandler {
+    pub fn new(
+        cycle_repository: Arc<dyn CycleRepository>,
+        session_repository: Arc<dyn SessionRepository>,
+        attachment_repository: Arc<dyn AttachmentRepository>,
+        document_storage: Arc<dyn DocumentStorage>,
+    ) -> Self {
+        Self {
+            cycle_repository,
+            session_repository,
+            attachment_repository,
+            document_storage,
+        }
+    }
+
+    #[tracing::instrument(name = "GetAttachmentContentHandler::handle", skip_all)]
+    pub async fn handle(
+        &self,
+        query: GetAttachmentContentQuery,
+    ) -> Result<GetAttachmentContentResult, AttachmentError> {
+        authorize_cycle(
+            self.cycle_repository.as_ref(),
+            self.session_repository.as_ref(),
+            &query.cycle_id,
+            &query.user_id,
+        )
+        .await?;
+
+        let attachment = self
+            .attachment_repository
+            .find_by_id(&query.attachment_id)
+            .await?
+            .filter(|a| a.cycle_id == query.cycle_id)
+            .ok_or(AttachmentError::NotFound(query.attachment_id))?;
+
+        let stored = self
+            .document_storage
+            .get(&attachment.storage_key())
+            .await
+            .map_err(|e| match e {
+                DocumentStorageError::NotFound(_) => AttachmentError::NotFound(attachment.id),
+                other => storage_error(other),
+            })?;
+
+        Ok(GetAttachmentContentResult {
+            attachment,
+            bytes: stored.bytes,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::document_access::fixtures::*;
+    use super::*;
+    use crate::adapters::document::InMemoryAttachmentRepository;
+    use crate::adapters::storage::InMemoryDocumentStorage;
+    use crate::domain::foundation::ComponentType;
+
+    /// Repositories holding a CSV attachment on `cycle_id`, with its bytes
+    /// stored if `with_bytes`.
+    async fn setup_repositories(
+        cycle_id: CycleId,
+        with_bytes: bool,
+    ) -> (
+        Arc<InMemoryAttachmentRepository>,
+        Arc<InMemoryDocumentStorage>,
+        AttachmentId,
+    ) {
+        let attachments = Arc::new(InMemoryAttachmentRepository::new());
+        let storage = Arc::new(InMemoryDocumentStorage::new());
+        let attachment = Attachment::new(
+            cycle_id,
+            ComponentType::Consequences,
+            "quotes.csv",
+            "text/csv",
+            7,
+            None,
+            owner(),
+        )
+        .unwrap();
+        attachments.save(&attachment).await.unwrap();
+        if with_bytes {
+            storage
+                .put(&attachment.storage_key(), "text/csv", b"a,b\n1,2".to_vec())
+                .await
+                .unwrap();
+        }
+        (attachments, storage, attachment.id)
+    }
+
+    fn create_handler(
+        cycles: MockCycleRepository,
+        sessions: MockSessionRepository,
+        attachments: Arc<InMemoryAttachmentRepository>,
+        storage: Arc<InMemoryDocumentStorage>,
+    ) -> GetAttachmentContentHandler {
+        GetAttachmentContentHandler::new(Arc::new(cycles), Arc::new(sessions), attachments, storage)
+    }
+
+    #[tokio::test]
+    async fn returns_metadata_and_bytes() {
+        let (cycles, sessions, cycle_id) = repositories();
+        let (attachments, storage, attachment_id) = setup_repositories(cycle_id, true).await;
+        let handler = create_handler(cycles, sessions, attachments, storage);
+
+        let result = handler
+            .handle(GetAttachmentContentQuery {
+                cycle_id,
+                attachment_id,
+                user_id: owner(),
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(result.attachment.file_name, "quotes.csv");
+        assert_eq!(result.bytes, b"a,b\n1,2".to_vec());
+    }
+
+    #[tokio::test]
+    async fn missing_bytes_are_not_found() {
+        let (cycles, sessions, cycle_id) = repositories();
+        let (attachments, storage, attachment_id) = setup_repositories(cycle_id, false).await;
+        let handler = create_handler(cycles, sessions, attachments, storage);
+
+        let result = handler
+            .handle(GetAttachmentContentQuery {
+                cycle_id,
+                attachment_id,
+                user_id: owner(),
+            })
+            .await;
+
+        assert!(matches!(result, Err(AttachmentError::NotFound(id)) if id == attachment_id));
+    }
+
+    #[tokio::test]
+    async fn rejects_users_who_do_not_own_the_session() {
+        let (cycles, sessions, cycle_id) = repositories();
+        let (attachments, storage, attachment_id) = setup_repositories(cycle_id, true).await;
+        let handler = create_handler(cycles, sessions, attachments, storage);
+
+        let result = handler
+            .handle(GetAttachmentContentQuery {
+                cycle_id,
+                attachment_id,
+                user_id: stranger(),
+            })
+            .await;
+
+        assert!(matches!(result, Err(AttachmentError::Forbidden)));
+    }
+}
@@ -33,6 +33,7 @@ impl GetComponentHandler {
         Self { reader }
     }
 
+    #[tracing::instrument(name = "GetComponentHandler::handle", skip_all)]
     pub async fn handle(&self, query: GetComponentQuery) -> Result<GetComponentResult, DomainError> {
         self.reader
             .get_component_output(&query.cycle_id, query.component_type)
@@ -43,10 +44,11 @@ impl GetComponentHandler {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::domain::foundation::{ComponentStatus, SessionId, Timestamp};
+    use crate::domain::foundation::{ComponentStatus, OrganizationId, SessionId, Timestamp};
     use crate::ports::{CycleProgressView, CycleSummary, CycleTreeNode, CycleView};
     use async_trait::async_trait;
     use serde_json::json;
+    use std::collections::HashMap;
 
     // ─────────────────────────────────────────────────────────────────────
     // Mock Implementation
@@ -93,6 +95,13 @@ mod tests {
             Ok(vec![])
         }
 
+        async fn list_by_organization(
+            &self,
+            _organization_id: &OrganizationId,
+        ) -> Result<HashMap<SessionId, Vec<CycleSummary>>, DomainError> {
+            Ok(HashMap::new())
+        }
+
         async fn get_tree(
             &self,
             _session_id: &SessionId,
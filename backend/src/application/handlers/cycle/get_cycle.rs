@@ -31,6 +31,7 @@ impl GetCycleHandler {
         Self { reader }
     }
 
+    #[tracing::instrument(name = "GetCycleHandler::handle", skip_all)]
     pub async fn handle(&self, query: GetCycleQuery) -> Result<GetCycleResult, DomainError> {
         self.reader.get_by_id(&query.cycle_id).await
     }
@@ -39,9 +40,10 @@ impl GetCycleHandler {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::domain::foundation::{ComponentStatus, ComponentType, CycleStatus, SessionId, Timestamp};
+    use crate::domain::foundation::{ComponentStatus, ComponentType, CycleStatus, OrganizationId, SessionId, Timestamp};
     use crate::ports::{ComponentStatusItem, CycleProgressView, CycleSummary, CycleTreeNode};
     use async_trait::async_trait;
+    use std::collections::HashMap;
 
     // ─────────────────────────────────────────────────────────────────────
     // Mock Implementation
@@ -94,6 +96,13 @@ mod tests {
             Ok(vec![])
         }
 
+        async fn list_by_organization(
+            &self,
+            _organization_id: &OrganizationId,
+        ) -> Result<HashMap<SessionId, Vec<CycleSummary>>, DomainError> {
+            Ok(HashMap::new())
+        }
+
         async fn get_tree(
             &self,
             _session_id: &SessionId,
@@ -0,0 +1,418 @@
+//! GetCycleGraphHandler - Query handler for the "what-if" branch map.
+//!
+//! Flattens a session's cycle tree into nodes and edges with layout
+//! coordinates, so the frontend can draw branches without walking the tree.
+//! Each cycle keeps its parent's lane when it is the first branch; later
+//! branches open new lanes to the right, like a commit graph.
+
+use std::sync::Arc;
+
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::domain::foundation::{
+    ComponentType, CycleId, CycleStatus, DomainError, SessionId, Timestamp,
+};
+use crate::domain::proact::DecisionQualityOutput;
+use crate::ports::{CycleReader, CycleTreeNode};
+
+/// Query to get the cycle graph for a session.
+#[derive(Debug, Clone)]
+pub struct GetCycleGraphQuery {
+    /// The session to get the cycle graph for.
+    pub session_id: SessionId,
+}
+
+/// Result of successful cycle graph query.
+pub type GetCycleGraphResult = Option<CycleGraph>;
+
+/// Layout-ready graph of a session's cycles.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct CycleGraph {
+    /// The session's primary cycle.
+    pub root_id: CycleId,
+    /// Nodes in depth-first order, parents before children.
+    pub nodes: Vec<CycleGraphNode>,
+    /// One edge per branch, parent to child.
+    pub edges: Vec<CycleGraphEdge>,
+    /// Number of lanes used, for sizing the canvas.
+    pub lane_count: u32,
+    /// Deepest generation, for sizing the canvas.
+    pub max_depth: u32,
+}
+
+/// One cycle in the graph.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct CycleGraphNode {
+    pub id: CycleId,
+    pub parent_id: Option<CycleId>,
+    /// Generation: 0 for the root, 1 for its branches, and so on.
+    pub depth: u32,
+    /// Horizontal position; siblings never share a lane.
+    pub lane: u32,
+    pub status: CycleStatus,
+    pub current_step: ComponentType,
+    pub progress_percent: u8,
+    /// Overall decision quality, once rated.
+    pub dq_score: Option<u8>,
+    /// Component this cycle diverged from its parent at.
+    pub branch_point: Option<ComponentType>,
+    /// Number of direct branches taken from this cycle.
+    pub branch_count: u32,
+    pub created_at: Timestamp,
+}
+
+/// A branch from a parent cycle to a child.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct CycleGraphEdge {
+    pub from: CycleId,
+    pub to: CycleId,
+    /// Component where the child diverged.
+    pub divergence_component: Option<ComponentType>,
+    /// Components before the divergence, shared with the parent.
+    pub shared_components: Vec<ComponentType>,
+    /// Short text for the edge, e.g. "Branched at Alternatives".
+    pub label: String,
+    /// Child's DQ score minus the parent's, when both are rated.
+    pub dq_delta: Option<i16>,
+}
+
+/// Handler for retrieving the cycle graph.
+///
+/// Returns `None` if no cycles exist for the session.
+pub struct GetCycleGraphHandler {
+    reader: Arc<dyn CycleReader>,
+}
+
+impl GetCycleGraphHandler {
+    pub fn new(reader: Arc<dyn CycleReader>) -> Self {
+        Self { reader }
+    }
+
+    #[tracing::instrument(name = "GetCycleGraphHandler::handle", skip_all)]
+    pub async fn handle(
+        &self,
+        query: GetCycleGraphQuery,
+    ) -> Result<GetCycleGraphResult, DomainError> {
+        let Some(tree) = self.reader.get_tree(&query.session_id).await? else {
+            return Ok(None);
+        };
+
+        let mut graph = CycleGraph {
+            root_id: tree.cycle.id,
+            nodes: Vec::new(),
+            edges: Vec::new(),
+            lane_count: 0,
+            max_depth: 0,
+        };
+        let mut next_lane = 0;
+        self.visit(&tree, None, 0, &mut next_lane, &mut graph)
+            .await?;
+        graph.lane_count = next_lane;
+        Ok(Some(graph))
+    }
+
+    /// Adds `node` and its branches, returning the node's DQ score.
+    ///
+    /// Boxed because async recursion needs a sized future.
+    fn visit<'a>(
+        &'a self,
+        node: &'a CycleTreeNode,
+        parent_id: Option<CycleId>,
+        depth: u32,
+        next_lane: &'a mut u32,
+        graph: &'a mut CycleGraph,
+    ) -> futures::future::BoxFuture<'a, Result<Option<u8>, DomainError>> {
+        Box::pin(async move {
+            let cycle = &node.cycle;
+            let lane = *next_lane;
+            let dq_score = self.dq_score(&cycle.id).await?;
+            graph.max_depth = graph.max_depth.max(depth);
+            graph.nodes.push(CycleGraphNode {
+                id: cycle.id,
+                parent_id,
+                depth,
+                lane,
+                status: cycle.status,
+                current_step: cycle.current_step,
+                progress_percent: cycle.progress_percent,
+                dq_score,
+                branch_point: cycle.branch_point,
+                branch_count: node.children.len() as u32,
+                created_at: cycle.created_at,
+            });
+
+            if node.children.is_empty() {
+                *next_lane += 1;
+            }
+            // The first branch continues this lane; the rest get fresh ones
+            for child in &node.children {
+                let child_dq = self
+                    .visit(child, Some(cycle.id), depth + 1, next_lane, graph)
+                    .await?;
+                graph.edges.push(edge(cycle.id, dq_score, child, child_dq));
+            }
+            Ok(dq_score)
+        })
+    }
+
+    async fn dq_score(&self, cycle_id: &CycleId) -> Result<Option<u8>, DomainError> {
+        let view = self
+            .reader
+            .get_component_output(cycle_id, ComponentType::DecisionQuality)
+            .await?;
+        Ok(view
+            .and_then(|view| serde_json::from_value::<DecisionQualityOutput>(view.output).ok())
+            .filter(|output| !output.elements.is_empty())
+            .map(|output| output.overall_score.value()))
+    }
+}
+
+fn edge(
+    from: CycleId,
+    from_dq: Option<u8>,
+    child: &CycleTreeNode,
+    child_dq: Option<u8>,
+) -> CycleGraphEdge {
+    let divergence = child.cycle.branch_point;
+    let (shared_components, label) = match divergence {
+        Some(component) => (
+            ComponentType::all()[..component.order_index()].to_vec(),
+            format!("Branched at {}", component.display_name()),
+        ),
+        None => (Vec::new(), "Branched".to_string()),
+    };
+    CycleGraphEdge {
+        from,
+        to: child.cycle.id,
+        divergence_component: divergence,
+        shared_components,
+        label,
+        dq_delta: from_dq
+            .zip(child_dq)
+            .map(|(parent, child)| child as i16 - parent as i16),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::foundation::{ComponentStatus, ErrorCode, OrganizationId};
+    use crate::ports::{ComponentOutputView, CycleProgressView, CycleSummary, CycleView};
+    use async_trait::async_trait;
+    use std::collections::HashMap;
+
+    // ─────────────────────────────────────────────────────────────────────
+    // Mock Implementation
+    // ─────────────────────────────────────────────────────────────────────
+
+    struct MockCycleReader {
+        tree: Option<CycleTreeNode>,
+        dq_scores: HashMap<CycleId, u8>,
+        fail_read: bool,
+    }
+
+    #[async_trait]
+    impl CycleReader for MockCycleReader {
+        async fn get_by_id(&self, _id: &CycleId) -> Result<Option<CycleView>, DomainError> {
+            Ok(None)
+        }
+
+        async fn list_by_session_id(
+            &self,
+            _session_id: &SessionId,
+        ) -> Result<Vec<CycleSummary>, DomainError> {
+            Ok(vec![])
+        }
+
+        async fn list_by_organization(
+            &self,
+            _organization_id: &OrganizationId,
+        ) -> Result<HashMap<SessionId, Vec<CycleSummary>>, DomainError> {
+            Ok(HashMap::new())
+        }
+
+        async fn get_tree(
+            &self,
+            _session_id: &SessionId,
+        ) -> Result<Option<CycleTreeNode>, DomainError> {
+            if self.fail_read {
+                return Err(DomainError::new(
+                    ErrorCode::DatabaseError,
+                    "Simulated read failure",
+                ));
+            }
+            Ok(self.tree.clone())
+        }
+
+        async fn get_progress(
+            &self,
+            _id: &CycleId,
+        ) -> Result<Option<CycleProgressView>, DomainError> {
+            Ok(None)
+        }
+
+        async fn get_lineage(&self, _id: &CycleId) -> Result<Vec<CycleSummary>, DomainError> {
+            Ok(vec![])
+        }
+
+        async fn get_component_output(
+            &self,
+            cycle_id: &CycleId,
+            component_type: ComponentType,
+        ) -> Result<Option<ComponentOutputView>, DomainError> {
+            Ok(self.dq_scores.get(cycle_id).map(|score| ComponentOutputView {
+                cycle_id: *cycle_id,
+                component_type,
+                status: ComponentStatus::Complete,
+                output: serde_json::json!({
+                    "elements": [
+                        {"name": "Clear Objectives", "score": score, "rationale": "", "improvement": ""}
+                    ],
+                    "overall_score": score,
+                    "improvement_paths": []
+                }),
+                updated_at: Timestamp::now(),
+            }))
+        }
+
+        async fn get_proact_tree_view(
+            &self,
+            _session_id: &SessionId,
+        ) -> Result<Option<crate::domain::cycle::CycleTreeNode>, DomainError> {
+            Ok(None)
+        }
+    }
+
+    // ─────────────────────────────────────────────────────────────────────
+    // Test Helpers
+    // ─────────────────────────────────────────────────────────────────────
+
+    fn node(branch_point: Option<ComponentType>, children: Vec<CycleTreeNode>) -> CycleTreeNode {
+        CycleTreeNode {
+            cycle: CycleSummary {
+                id: CycleId::new(),
+                is_branch: branch_point.is_some(),
+                branch_point,
+                status: CycleStatus::Active,
+                current_step: ComponentType::Tradeoffs,
+                progress_percent: 50,
+                created_at: Timestamp::now(),
+            },
+            children,
+        }
+    }
+
+    async fn graph_of(tree: CycleTreeNode, dq_scores: HashMap<CycleId, u8>) -> CycleGraph {
+        let handler = GetCycleGraphHandler::new(Arc::new(MockCycleReader {
+            tree: Some(tree),
+            dq_scores,
+            fail_read: false,
+        }));
+        handler
+            .handle(GetCycleGraphQuery {
+                session_id: SessionId::new(),
+            })
+            .await
+            .unwrap()
+            .unwrap()
+    }
+
+    // ─────────────────────────────────────────────────────────────────────
+    // Tests
+    // ─────────────────────────────────────────────────────────────────────
+
+    #[tokio::test]
+    async fn first_branch_keeps_parent_lane() {
+        // root ── a ── a1
+        //   │     └─ a2
+        //   └─ b
+        let a = node(
+            Some(ComponentType::Alternatives),
+            vec![
+                node(Some(ComponentType::Consequences), vec![]),
+                node(Some(ComponentType::Tradeoffs), vec![]),
+            ],
+        );
+        let tree = node(None, vec![a, node(Some(ComponentType::Objectives), vec![])]);
+
+        let graph = graph_of(tree, HashMap::new()).await;
+
+        let layout: Vec<_> = graph.nodes.iter().map(|n| (n.depth, n.lane)).collect();
+        assert_eq!(layout, vec![(0, 0), (1, 0), (2, 0), (2, 1), (1, 2)]);
+        assert_eq!(graph.lane_count, 3);
+        assert_eq!(graph.max_depth, 2);
+        assert_eq!(graph.edges.len(), 4);
+        assert_eq!(graph.nodes[0].branch_count, 2);
+    }
+
+    #[tokio::test]
+    async fn edges_describe_divergence_and_dq_change() {
+        let child = node(Some(ComponentType::Alternatives), vec![]);
+        let tree = node(None, vec![child.clone()]);
+        let dq_scores = HashMap::from([(tree.cycle.id, 50), (child.cycle.id, 70)]);
+
+        let graph = graph_of(tree.clone(), dq_scores).await;
+
+        let edge = &graph.edges[0];
+        assert_eq!(edge.from, tree.cycle.id);
+        assert_eq!(edge.to, child.cycle.id);
+        assert_eq!(edge.label, "Branched at Alternatives");
+        assert_eq!(
+            edge.shared_components,
+            vec![
+                ComponentType::IssueRaising,
+                ComponentType::ProblemFrame,
+                ComponentType::StakeholderAnalysis,
+                ComponentType::Objectives
+            ]
+        );
+        assert_eq!(edge.dq_delta, Some(20));
+        assert_eq!(graph.nodes[1].dq_score, Some(70));
+    }
+
+    #[tokio::test]
+    async fn unrated_cycles_have_no_dq_delta() {
+        let tree = node(None, vec![node(Some(ComponentType::Objectives), vec![])]);
+        let dq_scores = HashMap::from([(tree.cycle.id, 50)]);
+
+        let graph = graph_of(tree, dq_scores).await;
+
+        assert_eq!(graph.nodes[0].dq_score, Some(50));
+        assert_eq!(graph.edges[0].dq_delta, None);
+    }
+
+    #[tokio::test]
+    async fn returns_none_when_no_cycles() {
+        let handler = GetCycleGraphHandler::new(Arc::new(MockCycleReader {
+            tree: None,
+            dq_scores: HashMap::new(),
+            fail_read: false,
+        }));
+
+        let result = handler
+            .handle(GetCycleGraphQuery {
+                session_id: SessionId::new(),
+            })
+            .await;
+
+        assert!(result.unwrap().is_none());
+    }
+
+    #[tokio::test]
+    async fn returns_error_on_read_failure() {
+        let handler = GetCycleGraphHandler::new(Arc::new(MockCycleReader {
+            tree: None,
+            dq_scores: HashMap::new(),
+            fail_read: true,
+        }));
+
+        let result = handler
+            .handle(GetCycleGraphQuery {
+                session_id: SessionId::new(),
+            })
+            .await;
+
+        assert!(result.is_err());
+    }
+}
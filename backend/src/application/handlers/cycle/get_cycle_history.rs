@@ -0,0 +1,220 @@
+//! GetCycleHistoryHandler - Query handler for a cycle's recorded history.
+//!
+//! Answers audit questions such as "when did the objectives change?" from
+//! the cycle's event stream, and rebuilds the cycle as it stood at an
+//! earlier version from the nearest snapshot and the events after it.
+
+use std::sync::Arc;
+
+use crate::domain::cycle::{Cycle, RecordedCycleEvent};
+use crate::domain::foundation::{ComponentType, CycleId, DomainError};
+use crate::ports::CycleEventStore;
+
+/// Query for the events recorded against a cycle.
+#[derive(Debug, Clone)]
+pub struct GetCycleHistoryQuery {
+    pub cycle_id: CycleId,
+    /// Only events concerning this component, when set.
+    pub component_type: Option<ComponentType>,
+}
+
+/// Events oldest first. Cycles saved before event sourcing have history
+/// only from their first save afterwards.
+pub type GetCycleHistoryResult = Vec<RecordedCycleEvent>;
+
+/// Query for a cycle as it stood at one version.
+#[derive(Debug, Clone)]
+pub struct GetCycleAtVersionQuery {
+    pub cycle_id: CycleId,
+    /// Version to rebuild; the latest when `None`.
+    pub version: Option<u64>,
+}
+
+/// Handler for cycle history queries.
+pub struct GetCycleHistoryHandler {
+    store: Arc<dyn CycleEventStore>,
+}
+
+impl GetCycleHistoryHandler {
+    pub fn new(store: Arc<dyn CycleEventStore>) -> Self {
+        Self { store }
+    }
+
+    #[tracing::instrument(name = "GetCycleHistoryHandler::handle", skip_all)]
+    pub async fn handle(
+        &self,
+        query: GetCycleHistoryQuery,
+    ) -> Result<GetCycleHistoryResult, DomainError> {
+        let events = self.store.load_events(&query.cycle_id, 0, None).await?;
+        Ok(match query.component_type {
+            Some(ct) => events
+                .into_iter()
+                .filter(|e| e.event.component_type() == Some(ct))
+                .collect(),
+            None => events,
+        })
+    }
+
+    /// Rebuilds the cycle at the requested version.
+    ///
+    /// Returns `None` when no history reaches back that far.
+    #[tracing::instrument(name = "GetCycleHistoryHandler::at_version", skip_all)]
+    pub async fn at_version(
+        &self,
+        query: GetCycleAtVersionQuery,
+    ) -> Result<Option<Cycle>, DomainError> {
+        let snapshot = self
+            .store
+            .latest_snapshot(&query.cycle_id, query.version)
+            .await?;
+        let after = snapshot.as_ref().map(|s| s.version).unwrap_or(0);
+        let events = self
+            .store
+            .load_events(&query.cycle_id, after, query.version)
+            .await?;
+
+        if snapshot.is_none() && events.is_empty() {
+            return Ok(None);
+        }
+        Cycle::replay(snapshot, &events).map(Some)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::adapters::InMemoryCycleEventStore;
+    use crate::domain::cycle::{CycleEvent, SNAPSHOT_INTERVAL};
+    use crate::domain::foundation::{ComponentStatus, SessionId};
+
+    fn objectives_output() -> serde_json::Value {
+        serde_json::json!({
+            "fundamental_objectives": [],
+            "means_objectives": []
+        })
+    }
+
+    /// A cycle saved after every command, as the repository does.
+    async fn saved_cycle(store: &InMemoryCycleEventStore) -> Cycle {
+        let mut cycle = Cycle::new(SessionId::new());
+        for ct in [
+            ComponentType::IssueRaising,
+            ComponentType::ProblemFrame,
+            ComponentType::StakeholderAnalysis,
+            ComponentType::Objectives,
+        ] {
+            cycle.start_component(ct).unwrap();
+        }
+        store.append(&cycle).await.unwrap();
+        cycle.take_events();
+        cycle
+    }
+
+    #[tokio::test]
+    async fn history_filters_by_component() {
+        let store = Arc::new(InMemoryCycleEventStore::new());
+        let mut cycle = saved_cycle(&store).await;
+        cycle
+            .update_component_output(ComponentType::Objectives, objectives_output())
+            .unwrap();
+        store.append(&cycle).await.unwrap();
+        let handler = GetCycleHistoryHandler::new(store);
+
+        let history = handler
+            .handle(GetCycleHistoryQuery {
+                cycle_id: cycle.id(),
+                component_type: Some(ComponentType::Objectives),
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(history.len(), 2);
+        assert!(matches!(
+            history[0].event,
+            CycleEvent::ComponentStarted { .. }
+        ));
+        assert!(matches!(
+            history[1].event,
+            CycleEvent::ComponentOutputUpdated { .. }
+        ));
+        assert_eq!(history[1].version, cycle.version());
+    }
+
+    #[tokio::test]
+    async fn rebuilds_earlier_version() {
+        let store = Arc::new(InMemoryCycleEventStore::new());
+        let mut cycle = saved_cycle(&store).await;
+        let before = cycle.version();
+        cycle.navigate_to(ComponentType::IssueRaising).unwrap();
+        store.append(&cycle).await.unwrap();
+        let handler = GetCycleHistoryHandler::new(store);
+
+        let earlier = handler
+            .at_version(GetCycleAtVersionQuery {
+                cycle_id: cycle.id(),
+                version: Some(before),
+            })
+            .await
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(earlier.version(), before);
+        assert_eq!(earlier.current_step(), ComponentType::Objectives);
+        assert_eq!(
+            earlier.component_status(ComponentType::Objectives),
+            ComponentStatus::InProgress
+        );
+    }
+
+    #[tokio::test]
+    async fn rebuilds_latest_version_from_snapshot() {
+        let store = Arc::new(InMemoryCycleEventStore::new());
+        let mut cycle = saved_cycle(&store).await;
+        while cycle.version() < SNAPSHOT_INTERVAL + 3 {
+            cycle.navigate_to(ComponentType::ProblemFrame).unwrap();
+            store.append(&cycle).await.unwrap();
+            cycle.take_events();
+        }
+        let handler = GetCycleHistoryHandler::new(store.clone());
+
+        let latest = handler
+            .at_version(GetCycleAtVersionQuery {
+                cycle_id: cycle.id(),
+                version: None,
+            })
+            .await
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(
+            store.snapshot_versions(&cycle.id()),
+            vec![SNAPSHOT_INTERVAL]
+        );
+        assert_eq!(latest.version(), cycle.version());
+        assert_eq!(latest.current_step(), ComponentType::ProblemFrame);
+    }
+
+    #[tokio::test]
+    async fn unknown_cycle_has_no_history() {
+        let handler = GetCycleHistoryHandler::new(Arc::new(InMemoryCycleEventStore::new()));
+        let cycle_id = CycleId::new();
+
+        let history = handler
+            .handle(GetCycleHistoryQuery {
+                cycle_id,
+                component_type: None,
+            })
+            .await
+            .unwrap();
+        let rebuilt = handler
+            .at_version(GetCycleAtVersionQuery {
+                cycle_id,
+                version: None,
+            })
+            .await
+            .unwrap();
+
+        assert!(history.is_empty());
+        assert!(rebuilt.is_none());
+    }
+}
@@ -31,6 +31,7 @@ impl GetCycleTreeHandler {
         Self { reader }
     }
 
+    #[tracing::instrument(name = "GetCycleTreeHandler::handle", skip_all)]
     pub async fn handle(&self, query: GetCycleTreeQuery) -> Result<GetCycleTreeResult, DomainError> {
         self.reader.get_tree(&query.session_id).await
     }
@@ -39,9 +40,10 @@ impl GetCycleTreeHandler {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::domain::foundation::{ComponentType, CycleId, CycleStatus, Timestamp};
+    use crate::domain::foundation::{ComponentType, CycleId, CycleStatus, OrganizationId, Timestamp};
     use crate::ports::{CycleProgressView, CycleSummary, CycleView};
     use async_trait::async_trait;
+    use std::collections::HashMap;
 
     // ─────────────────────────────────────────────────────────────────────
     // Mock Implementation
@@ -88,6 +90,13 @@ mod tests {
             Ok(vec![])
         }
 
+        async fn list_by_organization(
+            &self,
+            _organization_id: &OrganizationId,
+        ) -> Result<HashMap<SessionId, Vec<CycleSummary>>, DomainError> {
+            Ok(HashMap::new())
+        }
+
         async fn get_tree(
             &self,
             session_id: &SessionId,
@@ -0,0 +1,187 @@
+//! GetDocumentDiffHandler - Query handler for comparing document versions.
+//!
+//! Loads two stored versions of a cycle's decision document and returns a
+//! section-level change summary plus a unified diff of the Markdown.
+
+use std::sync::Arc;
+
+use crate::domain::document::{DocumentVersion, VersionDiff};
+use crate::domain::foundation::{CycleId, DomainError, UserId};
+use crate::ports::{CycleRepository, DocumentVersionRepository, SessionRepository};
+
+/// Query to diff two versions of a cycle's document.
+#[derive(Debug, Clone)]
+pub struct GetDocumentDiffQuery {
+    pub cycle_id: CycleId,
+    pub from_version: u32,
+    pub to_version: u32,
+    pub user_id: UserId,
+}
+
+/// Result of comparing two versions.
+pub type GetDocumentDiffResult = VersionDiff;
+
+/// Error type for comparing document versions.
+#[derive(Debug, Clone)]
+pub enum GetDocumentDiffError {
+    /// Cycle not found.
+    CycleNotFound(CycleId),
+    /// The cycle has no version with this number.
+    VersionNotFound(u32),
+    /// User does not own the cycle's session.
+    Forbidden,
+    /// Domain error.
+    Domain(DomainError),
+}
+
+impl std::fmt::Display for GetDocumentDiffError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            GetDocumentDiffError::CycleNotFound(id) => write!(f, "Cycle not found: {}", id),
+            GetDocumentDiffError::VersionNotFound(version) => {
+                write!(f, "Document version not found: v{}", version)
+            }
+            GetDocumentDiffError::Forbidden => write!(f, "Permission denied"),
+            GetDocumentDiffError::Domain(err) => write!(f, "{}", err),
+        }
+    }
+}
+
+impl std::error::Error for GetDocumentDiffError {}
+
+impl From<DomainError> for GetDocumentDiffError {
+    fn from(err: DomainError) -> Self {
+        GetDocumentDiffError::Domain(err)
+    }
+}
+
+/// Handler for diffing document versions.
+pub struct GetDocumentDiffHandler {
+    cycle_repository: Arc<dyn CycleRepository>,
+    session_repository: Arc<dyn SessionRepository>,
+    document_versions: Arc<dyn DocumentVersionRepository>,
+}
+
+impl GetDocumentDiffHandler {
+    pub fn new(
+        cycle_repository: Arc<dyn CycleRepository>,
+        session_repository: Arc<dyn SessionRepository>,
+        document_versions: Arc<dyn DocumentVersionRepository>,
+    ) -> Self {
+        Self {
+            cycle_repository,
+            session_repository,
+            document_versions,
+        }
+    }
+
+    #[tracing::instrument(name = "GetDocumentDiffHandler::handle", skip_all)]
+    pub async fn handle(
+        &self,
+        query: GetDocumentDiffQuery,
+    ) -> Result<GetDocumentDiffResult, GetDocumentDiffError> {
+        // 1. Check the user owns the cycle's session
+        let cycle = self
+            .cycle_repository
+            .find_by_id(&query.cycle_id)
+            .await?
+            .ok_or(GetDocumentDiffError::CycleNotFound(query.cycle_id))?;
+        let session = self
+            .session_repository
+            .find_by_id(&cycle.session_id())
+            .await?
+            .ok_or(GetDocumentDiffError::CycleNotFound(query.cycle_id))?;
+        session
+            .authorize(&query.user_id)
+            .map_err(|_| GetDocumentDiffError::Forbidden)?;
+
+        // 2. Load both versions and compare
+        let from = self.version(&query.cycle_id, query.from_version).await?;
+        let to = self.version(&query.cycle_id, query.to_version).await?;
+        Ok(VersionDiff::between(&from, &to))
+    }
+
+    async fn version(
+        &self,
+        cycle_id: &CycleId,
+        version: u32,
+    ) -> Result<DocumentVersion, GetDocumentDiffError> {
+        self.document_versions
+            .find(cycle_id, version)
+            .await?
+            .ok_or(GetDocumentDiffError::VersionNotFound(version))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::document_access::fixtures::*;
+    use super::*;
+    use crate::adapters::document::InMemoryDocumentVersionRepository;
+    use crate::domain::document::{MarkdownContent, SectionChangeKind};
+
+    async fn handler() -> (GetDocumentDiffHandler, CycleId) {
+        let (cycles, sessions, cycle_id) = repositories();
+        let versions = Arc::new(InMemoryDocumentVersionRepository::new());
+        for (number, text) in [
+            (1, "# Plan\n\n## Decision\n\nMove?\n"),
+            (2, "# Plan\n\n## Decision\n\nMove to Lisbon?\n"),
+        ] {
+            versions
+                .save(&DocumentVersion::new(
+                    cycle_id,
+                    number,
+                    MarkdownContent::new(text),
+                ))
+                .await
+                .unwrap();
+        }
+        let handler = GetDocumentDiffHandler::new(Arc::new(cycles), Arc::new(sessions), versions);
+        (handler, cycle_id)
+    }
+
+    fn query(cycle_id: CycleId, from: u32, to: u32, user_id: UserId) -> GetDocumentDiffQuery {
+        GetDocumentDiffQuery {
+            cycle_id,
+            from_version: from,
+            to_version: to,
+            user_id,
+        }
+    }
+
+    #[tokio::test]
+    async fn diffs_two_versions() {
+        let (handler, cycle_id) = handler().await;
+
+        let diff = handler
+            .handle(query(cycle_id, 1, 2, owner()))
+            .await
+            .unwrap();
+
+        assert_eq!(diff.sections.len(), 1);
+        assert_eq!(diff.sections[0].heading, "Decision");
+        assert_eq!(diff.sections[0].change, SectionChangeKind::Modified);
+        assert!(diff.unified.contains("-Move?\n+Move to Lisbon?\n"));
+    }
+
+    #[tokio::test]
+    async fn missing_version_is_reported() {
+        let (handler, cycle_id) = handler().await;
+
+        let result = handler.handle(query(cycle_id, 1, 7, owner())).await;
+
+        assert!(matches!(
+            result,
+            Err(GetDocumentDiffError::VersionNotFound(7))
+        ));
+    }
+
+    #[tokio::test]
+    async fn rejects_users_who_do_not_own_the_session() {
+        let (handler, cycle_id) = handler().await;
+
+        let result = handler.handle(query(cycle_id, 1, 2, stranger())).await;
+
+        assert!(matches!(result, Err(GetDocumentDiffError::Forbidden)));
+    }
+}
@@ -32,6 +32,7 @@ impl GetProactTreeViewHandler {
         Self { reader }
     }
 
+    #[tracing::instrument(name = "GetProactTreeViewHandler::handle", skip_all)]
     pub async fn handle(&self, query: GetProactTreeViewQuery) -> Result<GetProactTreeViewResult, DomainError> {
         self.reader.get_proact_tree_view(&query.session_id).await
     }
@@ -41,9 +42,10 @@ impl GetProactTreeViewHandler {
 mod tests {
     use super::*;
     use crate::domain::cycle::{LetterStatus, PrOACTLetter, PrOACTStatus};
-    use crate::domain::foundation::{ComponentType, CycleId, CycleStatus, Timestamp};
+    use crate::domain::foundation::{ComponentType, CycleId, CycleStatus, OrganizationId, Timestamp};
     use crate::ports::{ComponentOutputView, CycleProgressView, CycleSummary, CycleTreeNode, CycleView};
     use async_trait::async_trait;
+    use std::collections::HashMap;
 
     // ─────────────────────────────────────────────────────────────────────
     // Mock Implementation
@@ -90,6 +92,13 @@ mod tests {
             Ok(vec![])
         }
 
+        async fn list_by_organization(
+            &self,
+            _organization_id: &OrganizationId,
+        ) -> Result<HashMap<SessionId, Vec<CycleSummary>>, DomainError> {
+            Ok(HashMap::new())
+        }
+
         async fn get_tree(
             &self,
             _session_id: &SessionId,
@@ -0,0 +1,475 @@
+//! ImportConsequencesHandler - Command handler for importing a comparison
+//! matrix from a spreadsheet.
+//!
+//! Reads a CSV or XLSX sheet with alternatives as rows and objectives as
+//! columns, maps it onto the cycle's Alternatives and Consequences outputs,
+//! and saves them through [`UpdateComponentOutputHandler`] so the usual state
+//! checks and `component.output_updated.v1` events apply. Nothing is saved
+//! unless the whole sheet maps cleanly.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use serde::de::DeserializeOwned;
+
+use crate::application::handlers::audit::CommandAuditor;
+use crate::domain::audit::{AuditRecord, AuditSummary};
+use crate::domain::cycle::Cycle;
+use crate::domain::document::{import_consequences_matrix, ColumnMatch, ImportIssue};
+use crate::domain::foundation::{
+    CommandMetadata, ComponentType, CycleId, DomainError, ErrorCode, EventId,
+};
+use crate::ports::{
+    CycleRepository, EventPublisher, SessionRepository, SpreadsheetError, SpreadsheetFormat,
+    SpreadsheetParser,
+};
+
+use super::update_component_output::{
+    UpdateComponentOutputCommand, UpdateComponentOutputError, UpdateComponentOutputHandler,
+    UpdateComponentOutputResult,
+};
+
+/// Command to import a consequences matrix into a cycle.
+#[derive(Debug, Clone)]
+pub struct ImportConsequencesCommand {
+    pub cycle_id: CycleId,
+    pub format: SpreadsheetFormat,
+    pub bytes: Vec<u8>,
+    /// Column header to objective ID, for headers that do not match an
+    /// objective's ID or description.
+    pub mapping: HashMap<String, String>,
+}
+
+/// Result of a successful import.
+#[derive(Debug, Clone)]
+pub struct ImportConsequencesResult {
+    /// The cycle after both outputs were saved.
+    pub cycle: Cycle,
+    /// How each column was matched to an objective.
+    pub columns: Vec<ColumnMatch>,
+    /// Names of alternatives created by the import.
+    pub added_alternatives: Vec<String>,
+    /// Cycle version after the import.
+    pub version: u64,
+    /// IDs of the `component.output_updated.v1` events published.
+    pub event_ids: Vec<EventId>,
+}
+
+/// Error type for importing a consequences matrix.
+#[derive(Debug, Clone)]
+pub enum ImportConsequencesError {
+    /// Cycle not found.
+    CycleNotFound(CycleId),
+    /// User does not own the cycle's session.
+    Forbidden,
+    /// The file could not be read as a spreadsheet.
+    Unreadable(SpreadsheetError),
+    /// The sheet does not map onto the cycle's objectives.
+    Invalid(Vec<ImportIssue>),
+    /// Saving an output failed (e.g., component not in progress).
+    Update(UpdateComponentOutputError),
+    /// Domain error.
+    Domain(DomainError),
+}
+
+impl std::fmt::Display for ImportConsequencesError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ImportConsequencesError::CycleNotFound(id) => write!(f, "Cycle not found: {}", id),
+            ImportConsequencesError::Forbidden => write!(f, "Permission denied"),
+            ImportConsequencesError::Unreadable(err) => write!(f, "{}", err),
+            ImportConsequencesError::Invalid(issues) => {
+                write!(f, "The sheet has {} problem(s)", issues.len())
+            }
+            ImportConsequencesError::Update(err) => write!(f, "{}", err),
+            ImportConsequencesError::Domain(err) => write!(f, "{}", err),
+        }
+    }
+}
+
+impl std::error::Error for ImportConsequencesError {}
+
+impl From<DomainError> for ImportConsequencesError {
+    fn from(err: DomainError) -> Self {
+        ImportConsequencesError::Domain(err)
+    }
+}
+
+impl From<UpdateComponentOutputError> for ImportConsequencesError {
+    fn from(err: UpdateComponentOutputError) -> Self {
+        ImportConsequencesError::Update(err)
+    }
+}
+
+/// Handler for importing consequences matrices.
+pub struct ImportConsequencesHandler {
+    cycle_repository: Arc<dyn CycleRepository>,
+    session_repository: Arc<dyn SessionRepository>,
+    parser: Arc<dyn SpreadsheetParser>,
+    update_handler: UpdateComponentOutputHandler,
+    auditor: CommandAuditor,
+}
+
+impl ImportConsequencesHandler {
+    pub fn new(
+        cycle_repository: Arc<dyn CycleRepository>,
+        session_repository: Arc<dyn SessionRepository>,
+        parser: Arc<dyn SpreadsheetParser>,
+        event_publisher: Arc<dyn EventPublisher>,
+    ) -> Self {
+        Self {
+            update_handler: UpdateComponentOutputHandler::new(
+                cycle_repository.clone(),
+                event_publisher,
+            ),
+            cycle_repository,
+            session_repository,
+            parser,
+            auditor: CommandAuditor::disabled(),
+        }
+    }
+
+    /// Records each command in the audit log.
+    pub fn with_auditor(mut self, auditor: CommandAuditor) -> Self {
+        self.auditor = auditor;
+        self
+    }
+
+    #[tracing::instrument(name = "ImportConsequencesHandler::handle", skip_all)]
+    pub async fn handle(
+        &self,
+        cmd: ImportConsequencesCommand,
+        metadata: CommandMetadata,
+    ) -> Result<ImportConsequencesResult, ImportConsequencesError> {
+        // 1. Check the user owns the cycle's session
+        let cycle = self
+            .cycle_repository
+            .find_by_id(&cmd.cycle_id)
+            .await?
+            .ok_or(ImportConsequencesError::CycleNotFound(cmd.cycle_id))?;
+        let session = self
+            .session_repository
+            .find_by_id(&cycle.session_id())
+            .await?
+            .ok_or(ImportConsequencesError::CycleNotFound(cmd.cycle_id))?;
+        session
+            .authorize(&metadata.user_id)
+            .map_err(|_| ImportConsequencesError::Forbidden)?;
+
+        let before = cycle.audit_summary();
+
+        // 2. Read the sheet and map it onto the current outputs
+        let rows = self
+            .parser
+            .parse(cmd.format, &cmd.bytes)
+            .map_err(ImportConsequencesError::Unreadable)?;
+        let import = import_consequences_matrix(
+            &rows,
+            &output(&cycle, ComponentType::Objectives)?,
+            &output(&cycle, ComponentType::Alternatives)?,
+            &output(&cycle, ComponentType::Consequences)?,
+            &cmd.mapping,
+        )
+        .map_err(ImportConsequencesError::Invalid)?;
+
+        // 3. Save through the update handler, alternatives first. Both
+        //    components are checked up front so a locked Consequences does
+        //    not leave the new alternatives saved on their own.
+        let mut targets = vec![ComponentType::Consequences];
+        if !import.added_alternatives.is_empty() {
+            targets.insert(0, ComponentType::Alternatives);
+        }
+        ensure_accepts_output(&cycle, &targets)?;
+        let mut event_ids = Vec::new();
+        if !import.added_alternatives.is_empty() {
+            let saved = self
+                .update(
+                    cmd.cycle_id,
+                    ComponentType::Alternatives,
+                    &import.alternatives,
+                    &metadata,
+                )
+                .await?;
+            event_ids.extend(saved.event_ids);
+        }
+        let saved = self
+            .update(
+                cmd.cycle_id,
+                ComponentType::Consequences,
+                &import.consequences,
+                &metadata,
+            )
+            .await?;
+        event_ids.extend(saved.event_ids);
+
+        self.auditor
+            .record(
+                AuditRecord::new(&metadata, "ImportConsequences", "cycle", cmd.cycle_id)
+                    .with_before(before)
+                    .with_after(saved.cycle.audit_summary()),
+            )
+            .await;
+
+        Ok(ImportConsequencesResult {
+            version: saved.version,
+            cycle: saved.cycle,
+            columns: import.columns,
+            added_alternatives: import.added_alternatives,
+            event_ids,
+        })
+    }
+
+    async fn update(
+        &self,
+        cycle_id: CycleId,
+        component_type: ComponentType,
+        output: &impl serde::Serialize,
+        metadata: &CommandMetadata,
+    ) -> Result<UpdateComponentOutputResult, ImportConsequencesError> {
+        Ok(save_output(
+            &self.update_handler,
+            cycle_id,
+            component_type,
+            output,
+            metadata,
+        )
+        .await?)
+    }
+}
+
+/// Saves a typed output through the update handler.
+pub(super) async fn save_output(
+    update_handler: &UpdateComponentOutputHandler,
+    cycle_id: CycleId,
+    component_type: ComponentType,
+    output: &impl serde::Serialize,
+    metadata: &CommandMetadata,
+) -> Result<UpdateComponentOutputResult, UpdateComponentOutputError> {
+    let output = serde_json::to_value(output).map_err(|e| {
+        UpdateComponentOutputError::Domain(DomainError::new(
+            ErrorCode::InternalError,
+            e.to_string(),
+        ))
+    })?;
+    update_handler
+        .handle(
+            UpdateComponentOutputCommand {
+                cycle_id,
+                component_type,
+                output,
+            },
+            metadata.clone(),
+        )
+        .await
+}
+
+/// Fails with `ComponentLocked` unless every target accepts output.
+pub(super) fn ensure_accepts_output(
+    cycle: &Cycle,
+    targets: &[ComponentType],
+) -> Result<(), DomainError> {
+    match targets
+        .iter()
+        .find(|ct| !cycle.component_status(**ct).accepts_output())
+    {
+        Some(locked) => Err(DomainError::new(
+            ErrorCode::ComponentLocked,
+            format!(
+                "{} is {:?}; it must be in progress to import",
+                locked.display_name(),
+                cycle.component_status(*locked)
+            ),
+        )),
+        None => Ok(()),
+    }
+}
+
+/// Reads a component's output as its typed structure.
+pub(super) fn output<T: DeserializeOwned + Default>(
+    cycle: &Cycle,
+    component_type: ComponentType,
+) -> Result<T, DomainError> {
+    let Some(component) = cycle.component(component_type) else {
+        return Ok(T::default());
+    };
+    serde_json::from_value(component.output_as_value())
+        .map_err(|e| DomainError::new(ErrorCode::InternalError, e.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::document_access::fixtures::*;
+    use super::*;
+    use crate::adapters::events::InMemoryEventBus;
+    use crate::adapters::spreadsheet::SpreadsheetReader;
+    use crate::domain::foundation::Rating;
+    use crate::domain::proact::{AlternativesOutput, ConsequencesOutput};
+    use serde_json::json;
+
+    /// Starts every component through Consequences and sets two objectives.
+    fn prepare(cycle: &mut Cycle) {
+        for ct in [
+            ComponentType::IssueRaising,
+            ComponentType::ProblemFrame,
+            ComponentType::StakeholderAnalysis,
+            ComponentType::Objectives,
+            ComponentType::Alternatives,
+            ComponentType::Consequences,
+        ] {
+            cycle.start_component(ct).unwrap();
+        }
+        let objectives = json!({
+            "fundamental_objectives": [
+                {
+                    "id": "obj-cost",
+                    "description": "Monthly cost",
+                    "performance_measure": {
+                        "description": "Rent in dollars",
+                        "is_quantitative": true,
+                        "unit": "dollars",
+                        "direction": "lower_is_better"
+                    }
+                },
+                {
+                    "id": "obj-commute",
+                    "description": "Commute",
+                    "performance_measure": {
+                        "description": "Daily travel",
+                        "is_quantitative": false,
+                        "direction": "higher_is_better"
+                    }
+                }
+            ],
+            "means_objectives": []
+        });
+        cycle
+            .update_component_output(ComponentType::Objectives, objectives)
+            .unwrap();
+        cycle.take_events();
+    }
+
+    fn create_handler(
+        cycles: MockCycleRepository,
+        sessions: MockSessionRepository,
+        events: Arc<InMemoryEventBus>,
+    ) -> ImportConsequencesHandler {
+        ImportConsequencesHandler::new(
+            Arc::new(cycles),
+            Arc::new(sessions),
+            Arc::new(SpreadsheetReader::new()),
+            events,
+        )
+    }
+
+    fn command(cycle_id: CycleId, csv: &str) -> ImportConsequencesCommand {
+        ImportConsequencesCommand {
+            cycle_id,
+            format: SpreadsheetFormat::Csv,
+            bytes: csv.as_bytes().to_vec(),
+            mapping: HashMap::new(),
+        }
+    }
+
+    const MATRIX: &str = "Option,Monthly cost,Commute\n\
+                          Stay put,1450,0\n\
+                          Move downtown,2100,\"+2: ten minute walk\"\n";
+
+    #[tokio::test]
+    async fn imports_alternatives_and_consequences() {
+        let (cycles, sessions, cycle_id) = repositories_with(prepare);
+        let events = Arc::new(InMemoryEventBus::new());
+        let handler = create_handler(cycles, sessions, events.clone());
+
+        let result = handler
+            .handle(command(cycle_id, MATRIX), CommandMetadata::new(owner()))
+            .await
+            .unwrap();
+
+        assert_eq!(result.added_alternatives, vec!["Stay put", "Move downtown"]);
+        let alternatives: AlternativesOutput =
+            output(&result.cycle, ComponentType::Alternatives).unwrap();
+        assert_eq!(alternatives.options.len(), 2);
+
+        let consequences: ConsequencesOutput =
+            output(&result.cycle, ComponentType::Consequences).unwrap();
+        let downtown = &alternatives.options[1].id;
+        let commute = &consequences.table.cells[downtown]["obj-commute"];
+        assert_eq!(commute.rating, Rating::MuchBetter);
+        assert_eq!(commute.explanation, "ten minute walk");
+        assert_eq!(
+            consequences.table.cells[downtown]["obj-cost"].quant_value,
+            Some(2100.0)
+        );
+
+        assert_eq!(events.event_count(), 2);
+    }
+
+    #[tokio::test]
+    async fn reports_every_problem_without_saving() {
+        let (cycles, sessions, cycle_id) = repositories_with(prepare);
+        let events = Arc::new(InMemoryEventBus::new());
+        let handler = create_handler(cycles, sessions, events.clone());
+        let csv = "Option,Monthly cost,Parking\nStay put,cheap,1\n";
+
+        let result = handler
+            .handle(command(cycle_id, csv), CommandMetadata::new(owner()))
+            .await;
+
+        let Err(ImportConsequencesError::Invalid(issues)) = result else {
+            panic!("expected validation issues");
+        };
+        assert_eq!(issues.len(), 2);
+        assert_eq!(events.event_count(), 0);
+    }
+
+    #[tokio::test]
+    async fn requires_the_components_to_accept_output() {
+        let (cycles, sessions, cycle_id) = repositories_with(|cycle| {
+            prepare(cycle);
+            cycle
+                .complete_component(ComponentType::Consequences)
+                .unwrap();
+        });
+        let events = Arc::new(InMemoryEventBus::new());
+        let handler = create_handler(cycles, sessions, events.clone());
+
+        let result = handler
+            .handle(command(cycle_id, MATRIX), CommandMetadata::new(owner()))
+            .await;
+
+        assert!(matches!(
+            result,
+            Err(ImportConsequencesError::Domain(ref err)) if err.code == ErrorCode::ComponentLocked
+        ));
+        assert_eq!(events.event_count(), 0);
+    }
+
+    #[tokio::test]
+    async fn rejects_users_who_do_not_own_the_session() {
+        let (cycles, sessions, cycle_id) = repositories_with(prepare);
+        let events = Arc::new(InMemoryEventBus::new());
+        let handler = create_handler(cycles, sessions, events.clone());
+
+        let result = handler
+            .handle(command(cycle_id, MATRIX), CommandMetadata::new(stranger()))
+            .await;
+
+        assert!(matches!(result, Err(ImportConsequencesError::Forbidden)));
+    }
+
+    #[tokio::test]
+    async fn rejects_unreadable_files() {
+        let (cycles, sessions, cycle_id) = repositories_with(prepare);
+        let events = Arc::new(InMemoryEventBus::new());
+        let handler = create_handler(cycles, sessions, events.clone());
+        let mut cmd = command(cycle_id, MATRIX);
+        cmd.format = SpreadsheetFormat::Xlsx;
+
+        let result = handler.handle(cmd, CommandMetadata::new(owner())).await;
+
+        assert!(matches!(
+            result,
+            Err(ImportConsequencesError::Unreadable(_))
+        ));
+    }
+}
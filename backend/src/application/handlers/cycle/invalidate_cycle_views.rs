@@ -0,0 +1,167 @@
+//! CycleViewInvalidator - Drops cached cycle views when a cycle changes.
+//!
+//! Reader adapters cache views under `cycle_cache_tag` and
+//! `session_cache_tag`. This handler invalidates those tags as component
+//! output, progress, or the session's cycles change, so a cache hit never
+//! serves a view older than the last published change.
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use serde::Deserialize;
+
+use crate::domain::foundation::{CycleId, DomainError, ErrorCode, EventEnvelope, SessionId};
+use crate::ports::{cycle_cache_tag, session_cache_tag, Cache, EventHandler, EventSubscriber};
+
+/// Events after which cached views of a cycle are stale.
+pub const CYCLE_VIEW_EVENTS: &[&str] = &[
+    "component.output_updated.v1",
+    "component.started.v1",
+    "component.completed.v1",
+    "component.batch_applied.v1",
+    "cycle.navigated.v1",
+    "cycle.completed.v1",
+    "cycle.archived.v1",
+    "cycle.created.v1",
+    "cycle.branched.v1",
+];
+
+/// The fields shared by every cycle event this handler reacts to.
+#[derive(Debug, Deserialize)]
+struct CycleChange {
+    cycle_id: CycleId,
+    #[serde(default)]
+    session_id: Option<SessionId>,
+    #[serde(default)]
+    parent_cycle_id: Option<CycleId>,
+}
+
+/// Invalidates cached cycle and dashboard views.
+pub struct CycleViewInvalidator {
+    cache: Arc<dyn Cache>,
+}
+
+impl CycleViewInvalidator {
+    pub fn new(cache: Arc<dyn Cache>) -> Self {
+        Self { cache }
+    }
+
+    /// Subscribe to every event in [`CYCLE_VIEW_EVENTS`].
+    pub fn register(self: &Arc<Self>, subscriber: &impl EventSubscriber) {
+        subscriber.subscribe_all(CYCLE_VIEW_EVENTS, self.clone());
+    }
+}
+
+#[async_trait]
+impl EventHandler for CycleViewInvalidator {
+    async fn handle(&self, event: EventEnvelope) -> Result<(), DomainError> {
+        let change: CycleChange = event
+            .payload_as()
+            .map_err(|e| DomainError::new(ErrorCode::ValidationFailed, e.to_string()))?;
+
+        let mut tags = vec![cycle_cache_tag(&change.cycle_id)];
+        // A branch changes its parent's branch count
+        if let Some(parent_id) = change.parent_cycle_id {
+            tags.push(cycle_cache_tag(&parent_id));
+        }
+        // A new cycle becomes the session's active cycle
+        if let Some(session_id) = change.session_id {
+            tags.push(session_cache_tag(&session_id));
+        }
+
+        for tag in tags {
+            if let Err(e) = self.cache.invalidate_tag(&tag).await {
+                tracing::warn!(tag, error = %e, "Failed to invalidate cached views");
+            }
+        }
+        Ok(())
+    }
+
+    fn name(&self) -> &'static str {
+        "CycleViewInvalidator"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    use crate::adapters::cache::InMemoryCache;
+    use crate::adapters::events::InMemoryEventBus;
+    use crate::application::handlers::cycle::{
+        AppliedComponentOperation, ComponentBatchAppliedEvent, ComponentOperationKind,
+        ComponentOutputUpdatedEvent, CycleBranchedEvent,
+    };
+    use crate::domain::foundation::{ComponentType, EventId, SerializableDomainEvent, Timestamp};
+    use crate::ports::{CacheEntryOptions, EventPublisher};
+
+    async fn cache_view(cache: &InMemoryCache, key: &str, tag: String) {
+        let options = CacheEntryOptions::new(Duration::from_secs(60)).with_tag(tag);
+        cache.set(key, b"{}".to_vec(), &options).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn output_update_invalidates_the_cycle_views() {
+        let cache = Arc::new(InMemoryCache::new());
+        let bus = InMemoryEventBus::new();
+        Arc::new(CycleViewInvalidator::new(cache.clone())).register(&bus);
+        let (cycle_id, other_id) = (CycleId::new(), CycleId::new());
+        cache_view(&cache, "view", cycle_cache_tag(&cycle_id)).await;
+        cache_view(&cache, "other", cycle_cache_tag(&other_id)).await;
+
+        let event = ComponentOutputUpdatedEvent {
+            event_id: EventId::new(),
+            cycle_id,
+            component_type: ComponentType::Objectives,
+            updated_at: Timestamp::now(),
+        };
+        bus.publish(event.to_envelope()).await.unwrap();
+
+        assert_eq!(cache.get("view").await.unwrap(), None);
+        assert!(cache.get("other").await.unwrap().is_some());
+    }
+
+    #[tokio::test]
+    async fn batch_invalidates_the_cycle_views() {
+        let cache = Arc::new(InMemoryCache::new());
+        let bus = InMemoryEventBus::new();
+        Arc::new(CycleViewInvalidator::new(cache.clone())).register(&bus);
+        let cycle_id = CycleId::new();
+        cache_view(&cache, "view", cycle_cache_tag(&cycle_id)).await;
+
+        let event = ComponentBatchAppliedEvent {
+            event_id: EventId::new(),
+            cycle_id,
+            operations: vec![AppliedComponentOperation {
+                kind: ComponentOperationKind::Complete,
+                component_type: ComponentType::IssueRaising,
+            }],
+            applied_at: Timestamp::now(),
+        };
+        bus.publish(event.to_envelope()).await.unwrap();
+
+        assert!(cache.is_empty().await);
+    }
+
+    #[tokio::test]
+    async fn branch_invalidates_parent_and_session() {
+        let cache = Arc::new(InMemoryCache::new());
+        let invalidator = CycleViewInvalidator::new(cache.clone());
+        let (parent_id, session_id) = (CycleId::new(), SessionId::new());
+        cache_view(&cache, "parent", cycle_cache_tag(&parent_id)).await;
+        cache_view(&cache, "overview", session_cache_tag(&session_id)).await;
+
+        let event = CycleBranchedEvent {
+            event_id: EventId::new(),
+            cycle_id: CycleId::new(),
+            parent_cycle_id: parent_id,
+            session_id,
+            branch_point: ComponentType::Alternatives,
+            created_at: Timestamp::now(),
+        };
+        invalidator.handle(event.to_envelope()).await.unwrap();
+
+        assert!(cache.is_empty().await);
+    }
+}
@@ -0,0 +1,118 @@
+//! ListAttachmentsHandler - Query handler for a cycle's attachments.
+
+use std::sync::Arc;
+
+use crate::domain::document::{Attachment, AttachmentError};
+use crate::domain::foundation::{CycleId, UserId};
+use crate::ports::{AttachmentRepository, CycleRepository, SessionRepository};
+
+use super::document_access::authorize_cycle;
+
+/// Query for the attachments on a cycle.
+#[derive(Debug, Clone)]
+pub struct ListAttachmentsQuery {
+    pub cycle_id: CycleId,
+    pub user_id: UserId,
+}
+
+/// Attachments on the cycle, oldest first.
+pub type ListAttachmentsResult = Vec<Attachment>;
+
+/// Handler for listing attachments.
+pub struct ListAttachmentsHandler {
+    cycle_repository: Arc<dyn CycleRepository>,
+    session_repository: Arc<dyn SessionRepository>,
+    attachment_repository: Arc<dyn AttachmentRepository>,
+}
+
+impl ListAttachmentsHandler {
+    pub fn new(
+        cycle_repository: Arc<dyn CycleRepository>,
+        session_repository: Arc<dyn SessionRepository>,
+        attachment_repository: Arc<dyn AttachmentRepository>,
+    ) -> Self {
+        Self {
+            cycle_repository,
+            session_repository,
+            attachment_repository,
+        }
+    }
+
+    #[tracing::instrument(name = "ListAttachmentsHandler::handle", skip_all)]
+    pub async fn handle(
+        &self,
+        query: ListAttachmentsQuery,
+    ) -> Result<ListAttachmentsResult, AttachmentError> {
+        authorize_cycle(
+            self.cycle_repository.as_ref(),
+            self.session_repository.as_ref(),
+            &query.cycle_id,
+            &query.user_id,
+        )
+        .await?;
+
+        Ok(self
+            .attachment_repository
+            .list_by_cycle(&query.cycle_id)
+            .await?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::document_access::fixtures::*;
+    use super::*;
+    use crate::adapters::document::InMemoryAttachmentRepository;
+    use crate::domain::foundation::ComponentType;
+
+    #[tokio::test]
+    async fn lists_only_the_cycles_attachments() {
+        let (cycles, sessions, cycle_id) = repositories();
+        let attachments = Arc::new(InMemoryAttachmentRepository::new());
+        for cycle in [cycle_id, CycleId::new()] {
+            let attachment = Attachment::new(
+                cycle,
+                ComponentType::Objectives,
+                "notes.txt",
+                "text/plain",
+                5,
+                None,
+                owner(),
+            )
+            .unwrap();
+            attachments.save(&attachment).await.unwrap();
+        }
+        let handler =
+            ListAttachmentsHandler::new(Arc::new(cycles), Arc::new(sessions), attachments);
+
+        let listed = handler
+            .handle(ListAttachmentsQuery {
+                cycle_id,
+                user_id: owner(),
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(listed.len(), 1);
+        assert_eq!(listed[0].cycle_id, cycle_id);
+    }
+
+    #[tokio::test]
+    async fn rejects_users_who_do_not_own_the_session() {
+        let (cycles, sessions, cycle_id) = repositories();
+        let handler = ListAttachmentsHandler::new(
+            Arc::new(cycles),
+            Arc::new(sessions),
+            Arc::new(InMemoryAttachmentRepository::new()),
+        );
+
+        let result = handler
+            .handle(ListAttachmentsQuery {
+                cycle_id,
+                user_id: stranger(),
+            })
+            .await;
+
+        assert!(matches!(result, Err(AttachmentError::Forbidden)));
+    }
+}
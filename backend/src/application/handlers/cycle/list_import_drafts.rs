@@ -0,0 +1,131 @@
+//! ListImportDraftsHandler - Query handler for a cycle's pending import drafts.
+
+use std::sync::Arc;
+
+use crate::domain::document::ImportDraft;
+use crate::domain::foundation::{CycleId, DomainError, UserId};
+use crate::ports::{CycleRepository, ImportDraftRepository, SessionRepository};
+
+/// Query for the import drafts awaiting confirmation on a cycle.
+#[derive(Debug, Clone)]
+pub struct ListImportDraftsQuery {
+    pub cycle_id: CycleId,
+    pub user_id: UserId,
+}
+
+/// Error type for listing import drafts.
+#[derive(Debug, Clone)]
+pub enum ListImportDraftsError {
+    /// Cycle not found.
+    CycleNotFound(CycleId),
+    /// User does not own the cycle's session.
+    Forbidden,
+    /// Domain error.
+    Domain(DomainError),
+}
+
+impl std::fmt::Display for ListImportDraftsError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ListImportDraftsError::CycleNotFound(id) => write!(f, "Cycle not found: {}", id),
+            ListImportDraftsError::Forbidden => write!(f, "Permission denied"),
+            ListImportDraftsError::Domain(err) => write!(f, "{}", err),
+        }
+    }
+}
+
+impl std::error::Error for ListImportDraftsError {}
+
+impl From<DomainError> for ListImportDraftsError {
+    fn from(err: DomainError) -> Self {
+        ListImportDraftsError::Domain(err)
+    }
+}
+
+/// Handler for listing pending import drafts.
+pub struct ListImportDraftsHandler {
+    cycle_repository: Arc<dyn CycleRepository>,
+    session_repository: Arc<dyn SessionRepository>,
+    drafts: Arc<dyn ImportDraftRepository>,
+}
+
+impl ListImportDraftsHandler {
+    pub fn new(
+        cycle_repository: Arc<dyn CycleRepository>,
+        session_repository: Arc<dyn SessionRepository>,
+        drafts: Arc<dyn ImportDraftRepository>,
+    ) -> Self {
+        Self {
+            cycle_repository,
+            session_repository,
+            drafts,
+        }
+    }
+
+    /// Returns pending drafts, newest first.
+    #[tracing::instrument(name = "ListImportDraftsHandler::handle", skip_all)]
+    pub async fn handle(
+        &self,
+        query: ListImportDraftsQuery,
+    ) -> Result<Vec<ImportDraft>, ListImportDraftsError> {
+        let cycle = self
+            .cycle_repository
+            .find_by_id(&query.cycle_id)
+            .await?
+            .ok_or(ListImportDraftsError::CycleNotFound(query.cycle_id))?;
+        let session = self
+            .session_repository
+            .find_by_id(&cycle.session_id())
+            .await?
+            .ok_or(ListImportDraftsError::CycleNotFound(query.cycle_id))?;
+        session
+            .authorize(&query.user_id)
+            .map_err(|_| ListImportDraftsError::Forbidden)?;
+
+        Ok(self.drafts.list_pending(&query.cycle_id).await?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::document_access::fixtures::*;
+    use super::*;
+    use crate::adapters::document::InMemoryImportDraftRepository;
+    use crate::domain::conversation::tools::ToolCall;
+    use serde_json::json;
+
+    #[tokio::test]
+    async fn lists_pending_drafts_for_the_owner_only() {
+        let (cycles, sessions, cycle_id) = repositories();
+        let drafts = Arc::new(InMemoryImportDraftRepository::new());
+        let draft = ImportDraft::from_tool_calls(
+            cycle_id,
+            owner(),
+            vec![ToolCall::new(
+                "add_alternative",
+                json!({"name": "Move downtown", "description": "", "is_status_quo": false}),
+            )],
+        )
+        .unwrap();
+        drafts.save(&draft).await.unwrap();
+        let handler = ListImportDraftsHandler::new(Arc::new(cycles), Arc::new(sessions), drafts);
+
+        let listed = handler
+            .handle(ListImportDraftsQuery {
+                cycle_id,
+                user_id: owner(),
+            })
+            .await
+            .unwrap();
+        assert_eq!(listed.len(), 1);
+        assert_eq!(listed[0].id(), draft.id());
+
+        let result = handler
+            .handle(ListImportDraftsQuery {
+                cycle_id,
+                user_id: stranger(),
+            })
+            .await;
+        assert!(matches!(result, Err(ListImportDraftsError::Forbidden)));
+    }
+}
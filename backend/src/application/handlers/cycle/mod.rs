@@ -2,22 +2,58 @@
 //!
 //! Handlers for cycle lifecycle operations and queries.
 
+mod document_access;
+mod recommendation_digest;
+
 // Command handlers
+mod add_attachment;
+mod apply_component_batch;
 mod archive_cycle;
 mod branch_cycle;
 mod complete_component;
 mod complete_cycle;
 mod create_cycle;
+mod draft_import_from_text;
+mod export_to_google_docs;
+mod import_consequences;
 mod navigate_to_component;
+mod publish_document;
+mod reanalyze_cycle;
+mod remove_attachment;
+mod resolve_import_draft;
+mod share_to_slack;
+mod share_to_teams;
 mod start_component;
+mod sync_document;
+mod unpublish_document;
 mod update_component_output;
 
 // Query handlers
+mod export_cycle_document;
+mod get_attachment_content;
 mod get_component;
 mod get_cycle;
+mod get_cycle_graph;
+mod get_cycle_history;
 mod get_cycle_tree;
+mod get_document_diff;
 mod get_proact_tree_view;
+mod list_attachments;
+mod list_import_drafts;
+mod view_published_document;
+
+// Event handlers
+mod email_completed_document;
+mod invalidate_cycle_views;
 
+pub use add_attachment::{
+    AddAttachmentCommand, AddAttachmentError, AddAttachmentHandler, AddAttachmentResult,
+};
+pub use apply_component_batch::{
+    AppliedComponentOperation, ApplyComponentBatchCommand, ApplyComponentBatchError,
+    ApplyComponentBatchHandler, ApplyComponentBatchResult, ComponentBatchAppliedEvent,
+    ComponentBatchOperation, ComponentOperationKind, MAX_BATCH_OPERATIONS,
+};
 pub use archive_cycle::{
     ArchiveCycleCommand, ArchiveCycleError, ArchiveCycleHandler, ArchiveCycleResult,
     CycleArchivedEvent,
@@ -36,23 +72,89 @@ pub use complete_cycle::{
 pub use create_cycle::{
     CreateCycleCommand, CreateCycleError, CreateCycleHandler, CreateCycleResult, CycleCreatedEvent,
 };
+pub use draft_import_from_text::{
+    DraftImportFromTextCommand, DraftImportFromTextError, DraftImportFromTextHandler,
+};
+pub use export_to_google_docs::{
+    ExportToGoogleDocsCommand, ExportToGoogleDocsError, ExportToGoogleDocsHandler,
+    ExportToGoogleDocsResult,
+};
+pub use import_consequences::{
+    ImportConsequencesCommand, ImportConsequencesError, ImportConsequencesHandler,
+    ImportConsequencesResult,
+};
 pub use navigate_to_component::{
     NavigateToComponentCommand, NavigateToComponentError, NavigateToComponentHandler,
     NavigateToComponentResult, NavigatedToComponentEvent,
 };
+pub use publish_document::{
+    PublishDocumentCommand, PublishDocumentError, PublishDocumentHandler, PublishDocumentResult,
+};
+pub use reanalyze_cycle::{
+    ReanalyzeCycleCommand, ReanalyzeCycleError, ReanalyzeCycleHandler, ReanalyzeCycleResult,
+};
+pub use remove_attachment::{
+    RemoveAttachmentCommand, RemoveAttachmentError, RemoveAttachmentHandler,
+};
+pub use resolve_import_draft::{
+    ResolveImportDraftCommand, ResolveImportDraftError, ResolveImportDraftHandler,
+    ResolveImportDraftResult,
+};
+pub use share_to_slack::{
+    ShareToSlackCommand, ShareToSlackError, ShareToSlackHandler, ShareToSlackResult,
+};
+pub use share_to_teams::{
+    ShareToTeamsCommand, ShareToTeamsError, ShareToTeamsHandler, ShareToTeamsResult,
+};
 pub use start_component::{
     ComponentStartedEvent, StartComponentCommand, StartComponentError, StartComponentHandler,
     StartComponentResult,
 };
+pub use sync_document::{
+    DocumentSectionsChangedEvent, SyncDocumentCommand, SyncDocumentError, SyncDocumentHandler,
+    SyncDocumentResult,
+};
+pub use unpublish_document::{
+    UnpublishDocumentCommand, UnpublishDocumentError, UnpublishDocumentHandler,
+    UnpublishDocumentResult,
+};
 pub use update_component_output::{
     ComponentOutputUpdatedEvent, UpdateComponentOutputCommand, UpdateComponentOutputError,
     UpdateComponentOutputHandler, UpdateComponentOutputResult,
 };
 
 // Query handlers
+pub use export_cycle_document::{
+    ExportCycleDocumentError, ExportCycleDocumentHandler, ExportCycleDocumentQuery,
+    ExportCycleDocumentResult,
+};
+pub use get_attachment_content::{
+    GetAttachmentContentHandler, GetAttachmentContentQuery, GetAttachmentContentResult,
+};
 pub use get_component::{GetComponentHandler, GetComponentQuery, GetComponentResult};
 pub use get_cycle::{GetCycleHandler, GetCycleQuery, GetCycleResult};
+pub use get_cycle_graph::{
+    CycleGraph, CycleGraphEdge, CycleGraphNode, GetCycleGraphHandler, GetCycleGraphQuery,
+    GetCycleGraphResult,
+};
+pub use get_cycle_history::{
+    GetCycleAtVersionQuery, GetCycleHistoryHandler, GetCycleHistoryQuery, GetCycleHistoryResult,
+};
 pub use get_cycle_tree::{GetCycleTreeHandler, GetCycleTreeQuery, GetCycleTreeResult};
+pub use get_document_diff::{
+    GetDocumentDiffError, GetDocumentDiffHandler, GetDocumentDiffQuery, GetDocumentDiffResult,
+};
 pub use get_proact_tree_view::{
     GetProactTreeViewHandler, GetProactTreeViewQuery, GetProactTreeViewResult,
 };
+pub use list_attachments::{ListAttachmentsHandler, ListAttachmentsQuery, ListAttachmentsResult};
+pub use list_import_drafts::{
+    ListImportDraftsError, ListImportDraftsHandler, ListImportDraftsQuery,
+};
+pub use view_published_document::{
+    ViewPublishedDocumentError, ViewPublishedDocumentHandler, ViewPublishedDocumentQuery,
+};
+
+// Event handlers
+pub use email_completed_document::CycleDocumentMailer;
+pub use invalidate_cycle_views::{CycleViewInvalidator, CYCLE_VIEW_EVENTS};
@@ -8,6 +8,8 @@ use std::sync::Arc;
 
 use serde::{Deserialize, Serialize};
 
+use crate::application::handlers::audit::CommandAuditor;
+use crate::domain::audit::{AuditRecord, AuditSummary};
 use crate::domain::cycle::Cycle;
 use crate::domain::foundation::{
     domain_event, CommandMetadata, ComponentType, CycleId, DomainError, EventId,
@@ -31,6 +33,10 @@ pub struct NavigateToComponentResult {
     pub cycle: Cycle,
     /// The emitted event.
     pub event: NavigatedToComponentEvent,
+    /// Cycle version after this command, for reconciling optimistic updates.
+    pub version: u64,
+    /// IDs of the events this command published.
+    pub event_ids: Vec<EventId>,
 }
 
 /// Event published when navigation occurs.
@@ -88,6 +94,7 @@ impl From<DomainError> for NavigateToComponentError {
 pub struct NavigateToComponentHandler {
     cycle_repository: Arc<dyn CycleRepository>,
     event_publisher: Arc<dyn EventPublisher>,
+    auditor: CommandAuditor,
 }
 
 impl NavigateToComponentHandler {
@@ -98,9 +105,17 @@ impl NavigateToComponentHandler {
         Self {
             cycle_repository,
             event_publisher,
+            auditor: CommandAuditor::disabled(),
         }
     }
 
+    /// Records each command in the audit log.
+    pub fn with_auditor(mut self, auditor: CommandAuditor) -> Self {
+        self.auditor = auditor;
+        self
+    }
+
+    #[tracing::instrument(name = "NavigateToComponentHandler::handle", skip_all)]
     pub async fn handle(
         &self,
         cmd: NavigateToComponentCommand,
@@ -117,6 +132,7 @@ impl NavigateToComponentHandler {
         let from_component = cycle.current_step();
 
         // 3. Navigate to the component (domain logic handles validation)
+        let before = cycle.audit_summary();
         cycle.navigate_to(cmd.component_type)?;
 
         // 4. Persist the updated cycle
@@ -138,7 +154,20 @@ impl NavigateToComponentHandler {
 
         self.event_publisher.publish(envelope).await?;
 
-        Ok(NavigateToComponentResult { cycle, event })
+        self.auditor
+            .record(
+                AuditRecord::new(&metadata, "NavigateToComponent", "cycle", cmd.cycle_id)
+                    .with_before(before)
+                    .with_after(cycle.audit_summary()),
+            )
+            .await;
+
+        Ok(NavigateToComponentResult {
+            version: cycle.version(),
+            event_ids: vec![event.event_id.clone()],
+            cycle,
+            event,
+        })
     }
 }
 
@@ -0,0 +1,179 @@
+//! PublishDocumentHandler - Command handler for sharing the decision document
+//! by public link.
+//!
+//! Renders the HTML page export (so tier checks and ownership apply as for
+//! downloads), stores it as a `DocumentPublication`, and returns a signed
+//! link that works without an account until it expires or is revoked with
+//! [`super::UnpublishDocumentHandler`].
+
+use std::sync::Arc;
+
+use crate::domain::document::{DocumentPublication, DEFAULT_PUBLICATION_TTL_DAYS};
+use crate::domain::foundation::{CycleId, DomainError, UserId};
+use crate::ports::{DocumentPublicationRepository, ExportFormat, PublicLinkSigner};
+
+use super::export_cycle_document::{
+    ExportCycleDocumentError, ExportCycleDocumentHandler, ExportCycleDocumentQuery,
+};
+
+/// Command to publish a cycle's decision document.
+#[derive(Debug, Clone)]
+pub struct PublishDocumentCommand {
+    pub cycle_id: CycleId,
+    pub user_id: UserId,
+    /// Organization whose document template applies, if known.
+    pub organization: Option<String>,
+    /// Link lifetime; defaults to [`DEFAULT_PUBLICATION_TTL_DAYS`].
+    pub ttl_days: Option<i64>,
+}
+
+/// Result of a successful publish.
+#[derive(Debug, Clone)]
+pub struct PublishDocumentResult {
+    pub publication: DocumentPublication,
+    /// Public URL to share.
+    pub url: String,
+}
+
+/// Error type for publishing a document.
+#[derive(Debug, Clone)]
+pub enum PublishDocumentError {
+    /// Rendering the document failed or was not allowed.
+    Export(ExportCycleDocumentError),
+    /// Domain error.
+    Domain(DomainError),
+}
+
+impl std::fmt::Display for PublishDocumentError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PublishDocumentError::Export(err) => write!(f, "{}", err),
+            PublishDocumentError::Domain(err) => write!(f, "{}", err),
+        }
+    }
+}
+
+impl std::error::Error for PublishDocumentError {}
+
+impl From<DomainError> for PublishDocumentError {
+    fn from(err: DomainError) -> Self {
+        PublishDocumentError::Domain(err)
+    }
+}
+
+impl From<ExportCycleDocumentError> for PublishDocumentError {
+    fn from(err: ExportCycleDocumentError) -> Self {
+        PublishDocumentError::Export(err)
+    }
+}
+
+/// Handler for publishing decision documents.
+pub struct PublishDocumentHandler {
+    export_handler: Arc<ExportCycleDocumentHandler>,
+    publications: Arc<dyn DocumentPublicationRepository>,
+    signer: Arc<dyn PublicLinkSigner>,
+}
+
+impl PublishDocumentHandler {
+    pub fn new(
+        export_handler: Arc<ExportCycleDocumentHandler>,
+        publications: Arc<dyn DocumentPublicationRepository>,
+        signer: Arc<dyn PublicLinkSigner>,
+    ) -> Self {
+        Self {
+            export_handler,
+            publications,
+            signer,
+        }
+    }
+
+    #[tracing::instrument(name = "PublishDocumentHandler::handle", skip_all)]
+    pub async fn handle(
+        &self,
+        cmd: PublishDocumentCommand,
+    ) -> Result<PublishDocumentResult, PublishDocumentError> {
+        let query = ExportCycleDocumentQuery {
+            cycle_id: cmd.cycle_id,
+            format: ExportFormat::Html,
+            user_id: cmd.user_id.clone(),
+            organization: cmd.organization,
+        };
+        let exported = self.export_handler.handle(query).await?;
+
+        let title = exported
+            .file_name
+            .rsplit_once('.')
+            .map_or(exported.file_name.as_str(), |(stem, _)| stem)
+            .to_string();
+        let publication = DocumentPublication::new(
+            cmd.cycle_id,
+            cmd.user_id,
+            title,
+            String::from_utf8_lossy(&exported.bytes),
+            cmd.ttl_days.unwrap_or(DEFAULT_PUBLICATION_TTL_DAYS),
+        );
+        self.publications.save(&publication).await?;
+
+        let url = self.signer.issue(&publication.id, publication.expires_at);
+        Ok(PublishDocumentResult { publication, url })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::export_cycle_document::fixtures::*;
+    use super::*;
+    use crate::adapters::document::InMemoryDocumentPublicationRepository;
+    use crate::adapters::storage::HmacPublicLinkSigner;
+    use crate::domain::foundation::Timestamp;
+
+    fn command(cycle_id: CycleId) -> PublishDocumentCommand {
+        PublishDocumentCommand {
+            cycle_id,
+            user_id: UserId::new("user-1").unwrap(),
+            organization: None,
+            ttl_days: Some(7),
+        }
+    }
+
+    fn publisher(can_export: bool) -> (PublishDocumentHandler, Arc<HmacPublicLinkSigner>) {
+        let cycle_id = CycleId::new();
+        let signer = Arc::new(HmacPublicLinkSigner::new(
+            "test-secret-that-is-long-enough",
+            "https://app.example.com",
+        ));
+        let handler = PublishDocumentHandler::new(
+            Arc::new(handler(Some(cycle_view(cycle_id)), can_export, false)),
+            Arc::new(InMemoryDocumentPublicationRepository::new()),
+            signer.clone(),
+        );
+        (handler, signer)
+    }
+
+    #[tokio::test]
+    async fn stores_an_html_snapshot_behind_a_signed_link() {
+        let (handler, signer) = publisher(true);
+
+        let result = handler.handle(command(CycleId::new())).await.unwrap();
+
+        assert!(result.publication.html.starts_with("<!DOCTYPE html>"));
+        assert!(result.publication.is_live(Timestamp::now()));
+        assert!(!result.publication.is_live(Timestamp::now().plus_days(8)));
+        let token = result.url.rsplit('/').next().unwrap();
+        assert_eq!(signer.verify(token), Ok(result.publication.id));
+    }
+
+    #[tokio::test]
+    async fn export_rules_apply() {
+        let (handler, _) = publisher(false);
+
+        let result = handler.handle(command(CycleId::new())).await;
+
+        assert!(matches!(
+            result,
+            Err(PublishDocumentError::Export(
+                ExportCycleDocumentError::AccessDenied(_)
+            ))
+        ));
+    }
+}
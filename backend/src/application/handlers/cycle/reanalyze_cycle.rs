@@ -0,0 +1,492 @@
+//! ReanalyzeCycleHandler - Command handler for refreshing stale analysis.
+//!
+//! When the user changes objective weights or switches analysis method,
+//! everything computed from the consequences table is out of date. This
+//! command brings the cycle back in line:
+//!
+//! 1. Queues a `ReanalysisRequested` event for each started component an
+//!    analyzer computes; the analysis trigger handler re-runs them off the
+//!    event bus, so the request returns before the analysis finishes.
+//! 2. Notes the affected sections in the latest decision document and stores
+//!    the result as a new version.
+//! 3. Suggests revisiting the recommendation and DQ ratings, which record the
+//!    user's judgment and cannot be recomputed. A component that already has
+//!    a pending suggestion for the same cause gets no second one.
+
+use std::sync::Arc;
+
+use crate::domain::analysis::{ReanalysisPlan, ReanalysisRequested, ReanalysisTrigger};
+use crate::domain::conversation::tools::{RevisitPriority, RevisitSuggestion};
+use crate::domain::document::{mark_stale_sections, DocumentVersion};
+use crate::domain::foundation::{
+    ComponentType, CycleId, DomainError, ErrorCode, EventId, SerializableDomainEvent, Timestamp,
+    UserId,
+};
+use crate::ports::{
+    CycleRepository, DocumentVersionRepository, EventPublisher, RevisitSuggestionRepoError,
+    RevisitSuggestionRepository, SessionRepository,
+};
+
+/// Command to re-run a cycle's analysis after its basis changed.
+#[derive(Debug, Clone)]
+pub struct ReanalyzeCycleCommand {
+    pub cycle_id: CycleId,
+    pub user_id: UserId,
+    /// What changed.
+    pub trigger: ReanalysisTrigger,
+}
+
+/// Result of queueing a reanalysis.
+#[derive(Debug, Clone)]
+pub struct ReanalyzeCycleResult {
+    /// Components whose analysis was queued, in PrOACT order.
+    pub queued: Vec<ComponentType>,
+    /// Document headings newly noted as out of date.
+    pub stale_sections: Vec<String>,
+    /// The document version holding the notes, if one was stored.
+    pub document_version: Option<u32>,
+    /// Revisit suggestions created for judgment components.
+    pub suggestions: Vec<RevisitSuggestion>,
+}
+
+/// Error type for reanalyzing a cycle.
+#[derive(Debug, Clone)]
+pub enum ReanalyzeCycleError {
+    /// Cycle not found.
+    CycleNotFound(CycleId),
+    /// User does not own the cycle's session.
+    Forbidden,
+    /// Revisit suggestions could not be stored.
+    Suggestions(RevisitSuggestionRepoError),
+    /// Domain error (e.g., cycle completed, persistence failure).
+    Domain(DomainError),
+}
+
+impl std::fmt::Display for ReanalyzeCycleError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ReanalyzeCycleError::CycleNotFound(id) => write!(f, "Cycle not found: {}", id),
+            ReanalyzeCycleError::Forbidden => write!(f, "Permission denied"),
+            ReanalyzeCycleError::Suggestions(err) => write!(f, "{}", err),
+            ReanalyzeCycleError::Domain(err) => write!(f, "{}", err),
+        }
+    }
+}
+
+impl std::error::Error for ReanalyzeCycleError {}
+
+impl From<DomainError> for ReanalyzeCycleError {
+    fn from(err: DomainError) -> Self {
+        ReanalyzeCycleError::Domain(err)
+    }
+}
+
+impl From<RevisitSuggestionRepoError> for ReanalyzeCycleError {
+    fn from(err: RevisitSuggestionRepoError) -> Self {
+        ReanalyzeCycleError::Suggestions(err)
+    }
+}
+
+/// Handler for reanalyzing cycles.
+pub struct ReanalyzeCycleHandler {
+    cycle_repository: Arc<dyn CycleRepository>,
+    session_repository: Arc<dyn SessionRepository>,
+    document_versions: Arc<dyn DocumentVersionRepository>,
+    revisit_suggestions: Arc<dyn RevisitSuggestionRepository>,
+    event_publisher: Arc<dyn EventPublisher>,
+}
+
+impl ReanalyzeCycleHandler {
+    pub fn new(
+        cycle_repository: Arc<dyn CycleRepository>,
+        session_repository: Arc<dyn SessionRepository>,
+        document_versions: Arc<dyn DocumentVersionRepository>,
+        revisit_suggestions: Arc<dyn RevisitSuggestionRepository>,
+        event_publisher: Arc<dyn EventPublisher>,
+    ) -> Self {
+        Self {
+            cycle_repository,
+            session_repository,
+            document_versions,
+            revisit_suggestions,
+            event_publisher,
+        }
+    }
+
+    #[tracing::instrument(name = "ReanalyzeCycleHandler::handle", skip_all)]
+    pub async fn handle(
+        &self,
+        cmd: ReanalyzeCycleCommand,
+    ) -> Result<ReanalyzeCycleResult, ReanalyzeCycleError> {
+        // 1. Check the user owns the cycle's session
+        let cycle = self
+            .cycle_repository
+            .find_by_id(&cmd.cycle_id)
+            .await?
+            .ok_or(ReanalyzeCycleError::CycleNotFound(cmd.cycle_id))?;
+        let session = self
+            .session_repository
+            .find_by_id(&cycle.session_id())
+            .await?
+            .ok_or(ReanalyzeCycleError::CycleNotFound(cmd.cycle_id))?;
+        session
+            .authorize(&cmd.user_id)
+            .map_err(|_| ReanalyzeCycleError::Forbidden)?;
+        if !cycle.status().is_mutable() {
+            return Err(DomainError::new(
+                ErrorCode::CycleArchived,
+                "Cannot reanalyze archived or completed cycle",
+            )
+            .into());
+        }
+
+        let plan = ReanalysisPlan::for_statuses(|ct| cycle.component_status(ct));
+
+        // 2. Queue the analyzers
+        let now = Timestamp::now();
+        let envelopes: Vec<_> = plan
+            .recompute
+            .iter()
+            .map(|&component_type| {
+                ReanalysisRequested {
+                    event_id: EventId::new(),
+                    cycle_id: cmd.cycle_id,
+                    session_id: cycle.session_id(),
+                    component_type,
+                    trigger: cmd.trigger,
+                    requested_at: now,
+                }
+                .to_envelope()
+            })
+            .collect();
+        if !envelopes.is_empty() {
+            self.event_publisher.publish_all(envelopes).await?;
+        }
+
+        // 3. Note stale sections in the latest document
+        let (stale_sections, document_version) = self.mark_document(&cmd, &plan).await?;
+
+        // 4. Ask the user to revisit what only they can judge
+        let suggestions = self.suggest_revisits(&cmd, &plan).await?;
+
+        tracing::info!(
+            cycle_id = %cmd.cycle_id,
+            trigger = ?cmd.trigger,
+            queued = plan.recompute.len(),
+            suggestions = suggestions.len(),
+            "Reanalysis queued"
+        );
+
+        Ok(ReanalyzeCycleResult {
+            queued: plan.recompute,
+            stale_sections,
+            document_version,
+            suggestions,
+        })
+    }
+
+    async fn mark_document(
+        &self,
+        cmd: &ReanalyzeCycleCommand,
+        plan: &ReanalysisPlan,
+    ) -> Result<(Vec<String>, Option<u32>), ReanalyzeCycleError> {
+        let Some(latest) = self.document_versions.latest(&cmd.cycle_id).await? else {
+            return Ok((Vec::new(), None));
+        };
+        let (content, marked) = mark_stale_sections(
+            &latest.content,
+            &plan.stale_components(),
+            cmd.trigger.description(),
+        );
+        if marked.is_empty() {
+            return Ok((marked, None));
+        }
+        let version = DocumentVersion::new(cmd.cycle_id, latest.version + 1, content);
+        self.document_versions.save(&version).await?;
+        Ok((marked, Some(version.version)))
+    }
+
+    async fn suggest_revisits(
+        &self,
+        cmd: &ReanalyzeCycleCommand,
+        plan: &ReanalysisPlan,
+    ) -> Result<Vec<RevisitSuggestion>, ReanalyzeCycleError> {
+        let trigger = cmd.trigger.description();
+        let mut created = Vec::new();
+        for &component in &plan.revisit {
+            let pending = self
+                .revisit_suggestions
+                .find_pending_for_component(cmd.cycle_id, component)
+                .await?;
+            if pending.iter().any(|s| s.trigger() == trigger) {
+                continue;
+            }
+            let (reason, priority) = match component {
+                ComponentType::Recommendation => (
+                    "Check the recommendation still follows from the updated analysis",
+                    RevisitPriority::High,
+                ),
+                _ => (
+                    "Re-rate decision quality against the updated analysis",
+                    RevisitPriority::Medium,
+                ),
+            };
+            let suggestion =
+                RevisitSuggestion::new(cmd.cycle_id, component, reason, trigger, priority);
+            self.revisit_suggestions.save(suggestion.clone()).await?;
+            created.push(suggestion);
+        }
+        Ok(created)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Mutex;
+
+    use async_trait::async_trait;
+
+    use super::super::document_access::fixtures::*;
+    use super::*;
+    use crate::adapters::document::InMemoryDocumentVersionRepository;
+    use crate::domain::document::{MarkdownContent, STALE_NOTE_PREFIX};
+    use crate::domain::foundation::{EventEnvelope, RevisitSuggestionId};
+    use crate::ports::RevisitSuggestionCounts;
+
+    #[derive(Default)]
+    struct MockSuggestions {
+        saved: Mutex<Vec<RevisitSuggestion>>,
+    }
+
+    #[async_trait]
+    impl RevisitSuggestionRepository for MockSuggestions {
+        async fn save(
+            &self,
+            suggestion: RevisitSuggestion,
+        ) -> Result<(), RevisitSuggestionRepoError> {
+            self.saved.lock().unwrap().push(suggestion);
+            Ok(())
+        }
+
+        async fn update(
+            &self,
+            _suggestion: &RevisitSuggestion,
+        ) -> Result<(), RevisitSuggestionRepoError> {
+            Ok(())
+        }
+
+        async fn find_by_id(
+            &self,
+            _id: RevisitSuggestionId,
+        ) -> Result<Option<RevisitSuggestion>, RevisitSuggestionRepoError> {
+            Ok(None)
+        }
+
+        async fn find_pending(
+            &self,
+            _cycle_id: CycleId,
+        ) -> Result<Vec<RevisitSuggestion>, RevisitSuggestionRepoError> {
+            Ok(self.saved.lock().unwrap().clone())
+        }
+
+        async fn find_pending_for_component(
+            &self,
+            cycle_id: CycleId,
+            component: ComponentType,
+        ) -> Result<Vec<RevisitSuggestion>, RevisitSuggestionRepoError> {
+            Ok(self
+                .saved
+                .lock()
+                .unwrap()
+                .iter()
+                .filter(|s| s.cycle_id() == cycle_id && s.target_component() == component)
+                .cloned()
+                .collect())
+        }
+
+        async fn find_by_cycle(
+            &self,
+            _cycle_id: CycleId,
+        ) -> Result<Vec<RevisitSuggestion>, RevisitSuggestionRepoError> {
+            Ok(self.saved.lock().unwrap().clone())
+        }
+
+        async fn count_pending_by_priority(
+            &self,
+            _cycle_id: CycleId,
+        ) -> Result<RevisitSuggestionCounts, RevisitSuggestionRepoError> {
+            Ok(RevisitSuggestionCounts::default())
+        }
+
+        async fn expire_all_pending(
+            &self,
+            _cycle_id: CycleId,
+        ) -> Result<usize, RevisitSuggestionRepoError> {
+            Ok(0)
+        }
+    }
+
+    #[derive(Default)]
+    struct MockEventPublisher {
+        published: Mutex<Vec<EventEnvelope>>,
+    }
+
+    #[async_trait]
+    impl EventPublisher for MockEventPublisher {
+        async fn publish(&self, event: EventEnvelope) -> Result<(), DomainError> {
+            self.published.lock().unwrap().push(event);
+            Ok(())
+        }
+
+        async fn publish_all(&self, events: Vec<EventEnvelope>) -> Result<(), DomainError> {
+            self.published.lock().unwrap().extend(events);
+            Ok(())
+        }
+    }
+
+    const DOCUMENT: &str = "# Move?\n\n## Consequences\n\n| | A |\n\n## Recommendation\n\nStay.\n";
+
+    /// Repositories holding a cycle started through `last`.
+    fn started_through(
+        last: ComponentType,
+    ) -> (MockCycleRepository, MockSessionRepository, CycleId) {
+        repositories_with(|cycle| {
+            for &ct in ComponentType::all() {
+                cycle.start_component(ct).unwrap();
+                if ct == last {
+                    break;
+                }
+            }
+        })
+    }
+
+    /// A version repository holding one stored document for `cycle_id`.
+    async fn setup_repositories(
+        cycle_id: CycleId,
+    ) -> (
+        Arc<InMemoryDocumentVersionRepository>,
+        Arc<MockSuggestions>,
+        Arc<MockEventPublisher>,
+    ) {
+        let versions = Arc::new(InMemoryDocumentVersionRepository::new());
+        versions
+            .save(&DocumentVersion::new(
+                cycle_id,
+                1,
+                MarkdownContent::new(DOCUMENT),
+            ))
+            .await
+            .unwrap();
+        (
+            versions,
+            Arc::new(MockSuggestions::default()),
+            Arc::new(MockEventPublisher::default()),
+        )
+    }
+
+    fn create_handler(
+        cycles: MockCycleRepository,
+        sessions: MockSessionRepository,
+        versions: Arc<InMemoryDocumentVersionRepository>,
+        suggestions: Arc<MockSuggestions>,
+        publisher: Arc<MockEventPublisher>,
+    ) -> ReanalyzeCycleHandler {
+        ReanalyzeCycleHandler::new(
+            Arc::new(cycles),
+            Arc::new(sessions),
+            versions,
+            suggestions,
+            publisher,
+        )
+    }
+
+    fn command(cycle_id: CycleId) -> ReanalyzeCycleCommand {
+        ReanalyzeCycleCommand {
+            cycle_id,
+            user_id: owner(),
+            trigger: ReanalysisTrigger::ObjectiveWeightsChanged,
+        }
+    }
+
+    #[tokio::test]
+    async fn queues_analyzers_marks_document_and_suggests_revisits() {
+        let (cycles, sessions, cycle_id) = started_through(ComponentType::Recommendation);
+        let (versions, suggestions, publisher) = setup_repositories(cycle_id).await;
+        let handler = create_handler(
+            cycles,
+            sessions,
+            versions.clone(),
+            suggestions,
+            publisher.clone(),
+        );
+
+        let result = handler.handle(command(cycle_id)).await.unwrap();
+
+        assert_eq!(
+            result.queued,
+            vec![ComponentType::Consequences, ComponentType::Tradeoffs]
+        );
+        let published = publisher.published.lock().unwrap().clone();
+        assert_eq!(published.len(), 2);
+        assert!(published
+            .iter()
+            .all(|e| e.event_type == "analysis.reanalysis_requested.v1"));
+
+        assert_eq!(
+            result.stale_sections,
+            vec!["Consequences", "Recommendation"]
+        );
+        assert_eq!(result.document_version, Some(2));
+        let latest = versions.latest(&cycle_id).await.unwrap().unwrap();
+        assert_eq!(
+            latest.content.as_str().matches(STALE_NOTE_PREFIX).count(),
+            2
+        );
+
+        assert_eq!(result.suggestions.len(), 1);
+        assert_eq!(
+            result.suggestions[0].target_component(),
+            ComponentType::Recommendation
+        );
+        assert_eq!(result.suggestions[0].priority(), RevisitPriority::High);
+    }
+
+    #[tokio::test]
+    async fn repeating_the_same_trigger_adds_nothing_new() {
+        let (cycles, sessions, cycle_id) = started_through(ComponentType::DecisionQuality);
+        let (versions, suggestions, publisher) = setup_repositories(cycle_id).await;
+        let handler = create_handler(
+            cycles,
+            sessions,
+            versions,
+            suggestions.clone(),
+            publisher.clone(),
+        );
+        handler.handle(command(cycle_id)).await.unwrap();
+
+        let again = handler.handle(command(cycle_id)).await.unwrap();
+
+        assert!(again.stale_sections.is_empty());
+        assert_eq!(again.document_version, None);
+        assert!(again.suggestions.is_empty());
+        assert_eq!(suggestions.saved.lock().unwrap().len(), 2);
+        // Analyzers still re-run, since their inputs may have changed again
+        assert_eq!(publisher.published.lock().unwrap().len(), 6);
+    }
+
+    #[tokio::test]
+    async fn only_the_owner_can_reanalyze() {
+        let (cycles, sessions, cycle_id) = started_through(ComponentType::Recommendation);
+        let (versions, suggestions, publisher) = setup_repositories(cycle_id).await;
+        let handler = create_handler(cycles, sessions, versions, suggestions, publisher.clone());
+        let cmd = ReanalyzeCycleCommand {
+            user_id: stranger(),
+            ..command(cycle_id)
+        };
+
+        let result = handler.handle(cmd).await;
+
+        assert!(matches!(result, Err(ReanalyzeCycleError::Forbidden)));
+        assert!(publisher.published.lock().unwrap().is_empty());
+    }
+}
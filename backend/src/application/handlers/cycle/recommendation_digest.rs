@@ -0,0 +1,218 @@
+//! The `RecommendationDigest` posted when a recommendation is shared to
+//! Slack or Teams, plus fixtures for tests of the share handlers.
+//!
+//! The digest snapshots the Recommendation output, the standout
+//! alternative's name, and the Decision Quality summary once rated, with the
+//! title and public link of the document just published for the share.
+
+use crate::domain::document::RecommendationDigest;
+use crate::domain::foundation::{ComponentType, CycleId, DomainError};
+use crate::domain::proact::{AlternativesOutput, DecisionQualityOutput, RecommendationOutput};
+use crate::ports::CycleReader;
+
+use super::publish_document::PublishDocumentResult;
+
+/// The digest for `published`, or None if the cycle has no recommendation.
+pub(super) async fn recommendation_digest(
+    cycle_reader: &dyn CycleReader,
+    cycle_id: &CycleId,
+    published: PublishDocumentResult,
+) -> Result<Option<RecommendationDigest>, DomainError> {
+    let Some(recommendation) = recommendation(cycle_reader, cycle_id).await? else {
+        return Ok(None);
+    };
+    let standout = match &recommendation.standout_option {
+        Some(id) => alternative_name(cycle_reader, cycle_id, id).await?,
+        None => None,
+    };
+    let mut digest = RecommendationDigest::new(
+        published.publication.title,
+        &recommendation,
+        standout,
+        published.url,
+    );
+    if let Some(decision_quality) = decision_quality(cycle_reader, cycle_id).await? {
+        digest = digest.with_decision_quality(&decision_quality);
+    }
+    Ok(Some(digest))
+}
+
+async fn recommendation(
+    cycle_reader: &dyn CycleReader,
+    cycle_id: &CycleId,
+) -> Result<Option<RecommendationOutput>, DomainError> {
+    let Some(view) = cycle_reader
+        .get_component_output(cycle_id, ComponentType::Recommendation)
+        .await?
+    else {
+        return Ok(None);
+    };
+    let recommendation: RecommendationOutput =
+        serde_json::from_value(view.output).unwrap_or_default();
+    Ok(Some(recommendation).filter(|r| !r.synthesis.trim().is_empty()))
+}
+
+/// The Decision Quality output, if any element has been rated.
+async fn decision_quality(
+    cycle_reader: &dyn CycleReader,
+    cycle_id: &CycleId,
+) -> Result<Option<DecisionQualityOutput>, DomainError> {
+    let Some(view) = cycle_reader
+        .get_component_output(cycle_id, ComponentType::DecisionQuality)
+        .await?
+    else {
+        return Ok(None);
+    };
+    let output: Option<DecisionQualityOutput> = serde_json::from_value(view.output).ok();
+    Ok(output.filter(|output| !output.elements.is_empty()))
+}
+
+/// Name of the alternative with `id`, if the Alternatives output has it.
+async fn alternative_name(
+    cycle_reader: &dyn CycleReader,
+    cycle_id: &CycleId,
+    id: &str,
+) -> Result<Option<String>, DomainError> {
+    let Some(view) = cycle_reader
+        .get_component_output(cycle_id, ComponentType::Alternatives)
+        .await?
+    else {
+        return Ok(None);
+    };
+    let alternatives: Option<AlternativesOutput> = serde_json::from_value(view.output).ok();
+    Ok(alternatives.and_then(|output| {
+        output
+            .options
+            .into_iter()
+            .find(|alternative| alternative.id == id)
+            .map(|alternative| alternative.name)
+    }))
+}
+
+#[cfg(test)]
+pub(super) mod fixtures {
+    //! A cycle with Recommendation, Alternatives, and Decision Quality
+    //! outputs, and a publish handler for it, for share handler tests.
+
+    use std::collections::HashMap;
+    use std::sync::Arc;
+
+    use async_trait::async_trait;
+
+    use super::super::export_cycle_document::fixtures::{cycle_view, handler};
+    use super::super::publish_document::PublishDocumentHandler;
+    use crate::adapters::document::InMemoryDocumentPublicationRepository;
+    use crate::adapters::storage::HmacPublicLinkSigner;
+    use crate::domain::foundation::{
+        ComponentStatus, ComponentType, CycleId, DomainError, OrganizationId, SessionId, Timestamp,
+    };
+    use crate::ports::{
+        ComponentOutputView, CycleProgressView, CycleReader, CycleSummary, CycleTreeNode, CycleView,
+    };
+
+    /// Serves fixed Recommendation, Alternatives, and Decision Quality outputs.
+    pub struct OutputsCycleReader {
+        pub recommendation: Option<serde_json::Value>,
+    }
+
+    #[async_trait]
+    impl CycleReader for OutputsCycleReader {
+        async fn get_by_id(&self, _id: &CycleId) -> Result<Option<CycleView>, DomainError> {
+            Ok(None)
+        }
+
+        async fn list_by_session_id(
+            &self,
+            _session_id: &SessionId,
+        ) -> Result<Vec<CycleSummary>, DomainError> {
+            Ok(vec![])
+        }
+
+        async fn list_by_organization(
+            &self,
+            _organization_id: &OrganizationId,
+        ) -> Result<HashMap<SessionId, Vec<CycleSummary>>, DomainError> {
+            Ok(HashMap::new())
+        }
+
+        async fn get_tree(
+            &self,
+            _session_id: &SessionId,
+        ) -> Result<Option<CycleTreeNode>, DomainError> {
+            Ok(None)
+        }
+
+        async fn get_progress(
+            &self,
+            _id: &CycleId,
+        ) -> Result<Option<CycleProgressView>, DomainError> {
+            Ok(None)
+        }
+
+        async fn get_lineage(&self, _id: &CycleId) -> Result<Vec<CycleSummary>, DomainError> {
+            Ok(vec![])
+        }
+
+        async fn get_component_output(
+            &self,
+            cycle_id: &CycleId,
+            component_type: ComponentType,
+        ) -> Result<Option<ComponentOutputView>, DomainError> {
+            let output = match component_type {
+                ComponentType::Recommendation => self.recommendation.clone(),
+                ComponentType::Alternatives => Some(serde_json::json!({
+                    "options": [
+                        {"id": "alt-1", "name": "Lisbon", "description": "", "assumptions": [], "is_status_quo": false},
+                        {"id": "alt-2", "name": "Stay put", "description": "", "assumptions": [], "is_status_quo": true}
+                    ],
+                    "strategy_table": null,
+                    "has_status_quo": true
+                })),
+                ComponentType::DecisionQuality => Some(serde_json::json!({
+                    "elements": [
+                        {"name": "Clear Objectives", "score": 80, "rationale": "", "improvement": ""},
+                        {"name": "Clear Tradeoffs", "score": 60, "rationale": "", "improvement": ""}
+                    ],
+                    "overall_score": 60,
+                    "improvement_paths": []
+                })),
+                _ => None,
+            };
+            Ok(output.map(|output| ComponentOutputView {
+                cycle_id: *cycle_id,
+                component_type,
+                status: ComponentStatus::Complete,
+                output,
+                updated_at: Timestamp::now(),
+            }))
+        }
+
+        async fn get_proact_tree_view(
+            &self,
+            _session_id: &SessionId,
+        ) -> Result<Option<crate::domain::cycle::CycleTreeNode>, DomainError> {
+            Ok(None)
+        }
+    }
+
+    pub fn recommendation() -> serde_json::Value {
+        serde_json::json!({
+            "standout_option": "alt-1",
+            "synthesis": "Lisbon balances cost and career growth.",
+            "caveats": ["Visa timing is uncertain"],
+            "additional_info": []
+        })
+    }
+
+    /// Publishes `cycle_id`'s document, refusing if `can_export` is false.
+    pub fn publish_handler(cycle_id: CycleId, can_export: bool) -> Arc<PublishDocumentHandler> {
+        Arc::new(PublishDocumentHandler::new(
+            Arc::new(handler(Some(cycle_view(cycle_id)), can_export, false)),
+            Arc::new(InMemoryDocumentPublicationRepository::new()),
+            Arc::new(HmacPublicLinkSigner::new(
+                "test-secret-that-is-long-enough",
+                "https://app.example.com",
+            )),
+        ))
+    }
+}
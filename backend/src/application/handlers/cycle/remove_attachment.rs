@@ -0,0 +1,181 @@
+//! RemoveAttachmentHandler - Command handler for deleting an attachment.
+//!
+//! Removes the metadata first so the attachment disappears from exports even
+//! if deleting the stored bytes fails; leftover bytes are only logged.
+
+use std::sync::Arc;
+
+use crate::domain::document::AttachmentError;
+use crate::domain::foundation::{AttachmentId, CycleId, UserId};
+use crate::ports::{AttachmentRepository, CycleRepository, DocumentStorage, SessionRepository};
+
+use super::document_access::authorize_cycle;
+
+/// Command to delete an attachment from a cycle.
+#[derive(Debug, Clone)]
+pub struct RemoveAttachmentCommand {
+    pub cycle_id: CycleId,
+    pub attachment_id: AttachmentId,
+    pub user_id: UserId,
+}
+
+/// Error type for deleting an attachment.
+pub type RemoveAttachmentError = AttachmentError;
+
+/// Handler for deleting attachments.
+pub struct RemoveAttachmentHandler {
+    cycle_repository: Arc<dyn CycleRepository>,
+    session_repository: Arc<dyn SessionRepository>,
+    attachment_repository: Arc<dyn AttachmentRepository>,
+    document_storage: Arc<dyn DocumentStorage>,
+}
+
+impl RemoveAttachmentHandler {
+    pub fn new(
+        cycle_repository: Arc<dyn CycleRepository>,
+        session_repository: Arc<dyn SessionRepository>,
+        attachment_repository: Arc<dyn AttachmentRepository>,
+        document_storage: Arc<dyn DocumentStorage>,
+    ) -> Self {
+        Self {
+            cycle_repository,
+            session_repository,
+            attachment_repository,
+            document_storage,
+        }
+    }
+
+    #[tracing::instrument(name = "RemoveAttachmentHandler::handle", skip_all)]
+    pub async fn handle(&self, cmd: RemoveAttachmentCommand) -> Result<(), RemoveAttachmentError> {
+        authorize_cycle(
+            self.cycle_repository.as_ref(),
+            self.session_repository.as_ref(),
+            &cmd.cycle_id,
+            &cmd.user_id,
+        )
+        .await?;
+
+        let attachment = self
+            .attachment_repository
+            .find_by_id(&cmd.attachment_id)
+            .await?
+            .filter(|a| a.cycle_id == cmd.cycle_id)
+            .ok_or(AttachmentError::NotFound(cmd.attachment_id))?;
+
+        self.attachment_repository.delete(&attachment.id).await?;
+
+        let key = attachment.storage_key();
+        if let Err(e) = self.document_storage.delete(&key).await {
+            tracing::warn!(key = %key, error = %e, "Orphaned attachment bytes");
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::document_access::fixtures::*;
+    use super::*;
+    use crate::adapters::document::InMemoryAttachmentRepository;
+    use crate::adapters::storage::InMemoryDocumentStorage;
+    use crate::domain::document::Attachment;
+    use crate::domain::foundation::ComponentType;
+
+    async fn stored_attachment(
+        attachments: &InMemoryAttachmentRepository,
+        storage: &InMemoryDocumentStorage,
+        cycle_id: CycleId,
+    ) -> Attachment {
+        let attachment = Attachment::new(
+            cycle_id,
+            ComponentType::Objectives,
+            "notes.txt",
+            "text/plain",
+            5,
+            None,
+            owner(),
+        )
+        .unwrap();
+        storage
+            .put(&attachment.storage_key(), "text/plain", b"notes".to_vec())
+            .await
+            .unwrap();
+        attachments.save(&attachment).await.unwrap();
+        attachment
+    }
+
+    fn handler(
+        attachments: Arc<InMemoryAttachmentRepository>,
+        storage: Arc<InMemoryDocumentStorage>,
+    ) -> (RemoveAttachmentHandler, CycleId) {
+        let (cycles, sessions, cycle_id) = repositories();
+        let handler = RemoveAttachmentHandler::new(
+            Arc::new(cycles),
+            Arc::new(sessions),
+            attachments,
+            storage,
+        );
+        (handler, cycle_id)
+    }
+
+    #[tokio::test]
+    async fn deletes_metadata_and_bytes() {
+        let attachments = Arc::new(InMemoryAttachmentRepository::new());
+        let storage = Arc::new(InMemoryDocumentStorage::new());
+        let (handler, cycle_id) = handler(attachments.clone(), storage.clone());
+        let attachment = stored_attachment(&attachments, &storage, cycle_id).await;
+
+        handler
+            .handle(RemoveAttachmentCommand {
+                cycle_id,
+                attachment_id: attachment.id,
+                user_id: owner(),
+            })
+            .await
+            .unwrap();
+
+        assert!(attachments
+            .find_by_id(&attachment.id)
+            .await
+            .unwrap()
+            .is_none());
+        assert!(!storage.exists(&attachment.storage_key()).await.unwrap());
+    }
+
+    #[tokio::test]
+    async fn attachment_on_another_cycle_is_not_found() {
+        let attachments = Arc::new(InMemoryAttachmentRepository::new());
+        let storage = Arc::new(InMemoryDocumentStorage::new());
+        let (handler, cycle_id) = handler(attachments.clone(), storage.clone());
+        let other = stored_attachment(&attachments, &storage, CycleId::new()).await;
+
+        let result = handler
+            .handle(RemoveAttachmentCommand {
+                cycle_id,
+                attachment_id: other.id,
+                user_id: owner(),
+            })
+            .await;
+
+        assert!(matches!(result, Err(AttachmentError::NotFound(_))));
+        assert!(attachments.find_by_id(&other.id).await.unwrap().is_some());
+    }
+
+    #[tokio::test]
+    async fn rejects_users_who_do_not_own_the_session() {
+        let attachments = Arc::new(InMemoryAttachmentRepository::new());
+        let storage = Arc::new(InMemoryDocumentStorage::new());
+        let (handler, cycle_id) = handler(attachments.clone(), storage.clone());
+        let attachment = stored_attachment(&attachments, &storage, cycle_id).await;
+
+        let result = handler
+            .handle(RemoveAttachmentCommand {
+                cycle_id,
+                attachment_id: attachment.id,
+                user_id: stranger(),
+            })
+            .await;
+
+        assert!(matches!(result, Err(AttachmentError::Forbidden)));
+    }
+}
@@ -0,0 +1,479 @@
+//! ResolveImportDraftHandler - Command handler for confirming or discarding
+//! an AI-drafted import.
+//!
+//! Confirming merges the draft into the cycle's Objectives, Alternatives,
+//! and Consequences outputs through `UpdateComponentOutputHandler`;
+//! discarding leaves the cycle untouched. Either way the draft's
+//! confirmation request is resolved so it cannot be applied twice.
+
+use std::sync::Arc;
+
+use serde_json::json;
+
+use crate::application::handlers::audit::CommandAuditor;
+use crate::domain::audit::AuditRecord;
+use crate::domain::cycle::Cycle;
+use crate::domain::document::ImportDraft;
+use crate::domain::foundation::{
+    CommandMetadata, ComponentType, ConfirmationRequestId, CycleId, DomainError, EventId,
+};
+use crate::ports::{CycleRepository, EventPublisher, ImportDraftRepository, SessionRepository};
+
+use super::import_consequences::{ensure_accepts_output, output, save_output};
+use super::update_component_output::{
+    UpdateComponentOutputError, UpdateComponentOutputHandler, UpdateComponentOutputResult,
+};
+
+/// Index of the "Apply" option on a draft's confirmation request.
+const APPLY_OPTION: usize = 0;
+
+/// Command to confirm or discard an import draft.
+#[derive(Debug, Clone)]
+pub struct ResolveImportDraftCommand {
+    pub cycle_id: CycleId,
+    pub draft_id: ConfirmationRequestId,
+    /// `true` to apply the draft, `false` to discard it.
+    pub apply: bool,
+}
+
+/// Result of resolving an import draft.
+#[derive(Debug, Clone)]
+pub struct ResolveImportDraftResult {
+    /// The resolved draft.
+    pub draft: ImportDraft,
+    /// The cycle after applying, or `None` if the draft was discarded.
+    pub cycle: Option<Cycle>,
+    /// Cycle version after applying, or `None` if the draft was discarded.
+    pub version: Option<u64>,
+    /// IDs of the `component.output_updated.v1` events published.
+    pub event_ids: Vec<EventId>,
+}
+
+/// Error type for resolving an import draft.
+#[derive(Debug, Clone)]
+pub enum ResolveImportDraftError {
+    /// No draft with this ID belongs to the cycle.
+    DraftNotFound(ConfirmationRequestId),
+    /// User does not own the cycle's session.
+    Forbidden,
+    /// The draft was already confirmed or discarded.
+    AlreadyResolved,
+    /// The draft waited too long for confirmation.
+    Expired,
+    /// Saving a component output failed.
+    Update(UpdateComponentOutputError),
+    /// Domain error.
+    Domain(DomainError),
+}
+
+impl std::fmt::Display for ResolveImportDraftError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ResolveImportDraftError::DraftNotFound(id) => {
+                write!(f, "Import draft not found: {}", id)
+            }
+            ResolveImportDraftError::Forbidden => write!(f, "Permission denied"),
+            ResolveImportDraftError::AlreadyResolved => {
+                write!(f, "Import draft has already been resolved")
+            }
+            ResolveImportDraftError::Expired => write!(f, "Import draft has expired"),
+            ResolveImportDraftError::Update(err) => write!(f, "{}", err),
+            ResolveImportDraftError::Domain(err) => write!(f, "{}", err),
+        }
+    }
+}
+
+impl std::error::Error for ResolveImportDraftError {}
+
+impl From<DomainError> for ResolveImportDraftError {
+    fn from(err: DomainError) -> Self {
+        ResolveImportDraftError::Domain(err)
+    }
+}
+
+impl From<UpdateComponentOutputError> for ResolveImportDraftError {
+    fn from(err: UpdateComponentOutputError) -> Self {
+        ResolveImportDraftError::Update(err)
+    }
+}
+
+/// Handler for confirming or discarding import drafts.
+pub struct ResolveImportDraftHandler {
+    cycle_repository: Arc<dyn CycleRepository>,
+    session_repository: Arc<dyn SessionRepository>,
+    drafts: Arc<dyn ImportDraftRepository>,
+    update_handler: UpdateComponentOutputHandler,
+    auditor: CommandAuditor,
+}
+
+impl ResolveImportDraftHandler {
+    pub fn new(
+        cycle_repository: Arc<dyn CycleRepository>,
+        session_repository: Arc<dyn SessionRepository>,
+        drafts: Arc<dyn ImportDraftRepository>,
+        event_publisher: Arc<dyn EventPublisher>,
+    ) -> Self {
+        Self {
+            update_handler: UpdateComponentOutputHandler::new(
+                cycle_repository.clone(),
+                event_publisher,
+            ),
+            cycle_repository,
+            session_repository,
+            drafts,
+            auditor: CommandAuditor::disabled(),
+        }
+    }
+
+    /// Records each command in the audit log.
+    pub fn with_auditor(mut self, auditor: CommandAuditor) -> Self {
+        self.auditor = auditor;
+        self
+    }
+
+    #[tracing::instrument(name = "ResolveImportDraftHandler::handle", skip_all)]
+    pub async fn handle(
+        &self,
+        cmd: ResolveImportDraftCommand,
+        metadata: CommandMetadata,
+    ) -> Result<ResolveImportDraftResult, ResolveImportDraftError> {
+        // 1. Find the draft and check the user owns its cycle's session
+        let mut draft = self
+            .drafts
+            .find_by_id(&cmd.draft_id)
+            .await?
+            .filter(|draft| draft.cycle_id == cmd.cycle_id)
+            .ok_or(ResolveImportDraftError::DraftNotFound(cmd.draft_id))?;
+        let cycle = self
+            .cycle_repository
+            .find_by_id(&cmd.cycle_id)
+            .await?
+            .ok_or(ResolveImportDraftError::DraftNotFound(cmd.draft_id))?;
+        let session = self
+            .session_repository
+            .find_by_id(&cycle.session_id())
+            .await?
+            .ok_or(ResolveImportDraftError::DraftNotFound(cmd.draft_id))?;
+        session
+            .authorize(&metadata.user_id)
+            .map_err(|_| ResolveImportDraftError::Forbidden)?;
+
+        // 2. Only pending, unexpired drafts can be resolved
+        if !draft.confirmation.is_pending() {
+            return Err(ResolveImportDraftError::AlreadyResolved);
+        }
+        if draft.confirmation.is_expired() {
+            draft.confirmation.expire();
+            self.drafts.update(&draft).await?;
+            return Err(ResolveImportDraftError::Expired);
+        }
+
+        if !cmd.apply {
+            draft.confirmation.reject();
+            self.drafts.update(&draft).await?;
+            self.auditor
+                .record(
+                    AuditRecord::new(&metadata, "ResolveImportDraft", "import_draft", draft.id())
+                        .with_after(json!({"cycle_id": cmd.cycle_id, "applied": false})),
+                )
+                .await;
+            return Ok(ResolveImportDraftResult {
+                draft,
+                cycle: None,
+                version: None,
+                event_ids: Vec::new(),
+            });
+        }
+
+        // 3. Merge into the current outputs. Every changed component is
+        //    checked up front so nothing is saved if any one is locked.
+        let applied = draft.apply(
+            &output(&cycle, ComponentType::Objectives)?,
+            &output(&cycle, ComponentType::Alternatives)?,
+            &output(&cycle, ComponentType::Consequences)?,
+        );
+        let mut targets = Vec::new();
+        if applied.objectives.is_some() {
+            targets.push(ComponentType::Objectives);
+        }
+        if applied.alternatives.is_some() {
+            targets.push(ComponentType::Alternatives);
+        }
+        if applied.consequences.is_some() {
+            targets.push(ComponentType::Consequences);
+        }
+        ensure_accepts_output(&cycle, &targets)?;
+
+        let mut updated = cycle;
+        let mut event_ids = Vec::new();
+        if let Some(objectives) = &applied.objectives {
+            let saved = self
+                .save(
+                    cmd.cycle_id,
+                    ComponentType::Objectives,
+                    objectives,
+                    &metadata,
+                )
+                .await?;
+            event_ids.extend(saved.event_ids);
+            updated = saved.cycle;
+        }
+        if let Some(alternatives) = &applied.alternatives {
+            let saved = self
+                .save(
+                    cmd.cycle_id,
+                    ComponentType::Alternatives,
+                    alternatives,
+                    &metadata,
+                )
+                .await?;
+            event_ids.extend(saved.event_ids);
+            updated = saved.cycle;
+        }
+        if let Some(consequences) = &applied.consequences {
+            let saved = self
+                .save(
+                    cmd.cycle_id,
+                    ComponentType::Consequences,
+                    consequences,
+                    &metadata,
+                )
+                .await?;
+            event_ids.extend(saved.event_ids);
+            updated = saved.cycle;
+        }
+
+        // 4. Record the confirmation
+        draft.confirmation.confirm(APPLY_OPTION);
+        self.drafts.update(&draft).await?;
+        self.auditor
+            .record(
+                AuditRecord::new(&metadata, "ResolveImportDraft", "import_draft", draft.id())
+                    .with_after(json!({"cycle_id": cmd.cycle_id, "applied": true})),
+            )
+            .await;
+
+        Ok(ResolveImportDraftResult {
+            draft,
+            version: Some(updated.version()),
+            cycle: Some(updated),
+            event_ids,
+        })
+    }
+
+    async fn save(
+        &self,
+        cycle_id: CycleId,
+        component_type: ComponentType,
+        output: &impl serde::Serialize,
+        metadata: &CommandMetadata,
+    ) -> Result<UpdateComponentOutputResult, UpdateComponentOutputError> {
+        save_output(
+            &self.update_handler,
+            cycle_id,
+            component_type,
+            output,
+            metadata,
+        )
+        .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::document_access::fixtures::*;
+    use super::*;
+    use crate::adapters::document::InMemoryImportDraftRepository;
+    use crate::adapters::events::InMemoryEventBus;
+    use crate::domain::conversation::tools::ToolCall;
+    use crate::domain::document::AI_DRAFT_SOURCE;
+    use crate::domain::proact::{AlternativesOutput, ConsequencesOutput, ObjectivesOutput};
+    use serde_json::json;
+
+    fn start_through_consequences(cycle: &mut Cycle) {
+        for ct in [
+            ComponentType::IssueRaising,
+            ComponentType::ProblemFrame,
+            ComponentType::StakeholderAnalysis,
+            ComponentType::Objectives,
+            ComponentType::Alternatives,
+            ComponentType::Consequences,
+        ] {
+            cycle.start_component(ct).unwrap();
+        }
+        cycle.take_events();
+    }
+
+    fn draft(cycle_id: CycleId) -> ImportDraft {
+        let calls = vec![
+            ToolCall::new(
+                "add_objective",
+                json!({"name": "Commute", "measure": "Minutes per day", "direction": "lower", "is_fundamental": true}),
+            ),
+            ToolCall::new(
+                "add_alternative",
+                json!({"name": "Move downtown", "description": "Rent near the office", "is_status_quo": false}),
+            ),
+            ToolCall::new(
+                "rate_consequence",
+                json!({"alternative_id": "Move downtown", "objective_id": "Commute", "rating": 2, "reasoning": "Ten minute walk", "confidence": "high"}),
+            ),
+        ];
+        ImportDraft::from_tool_calls(cycle_id, owner(), calls).unwrap()
+    }
+
+    /// An import draft repository holding one pending draft for `cycle_id`.
+    async fn setup_repositories(
+        cycle_id: CycleId,
+    ) -> (
+        Arc<InMemoryImportDraftRepository>,
+        Arc<InMemoryEventBus>,
+        ConfirmationRequestId,
+    ) {
+        let drafts = Arc::new(InMemoryImportDraftRepository::new());
+        let draft = draft(cycle_id);
+        drafts.save(&draft).await.unwrap();
+        (drafts, Arc::new(InMemoryEventBus::new()), draft.id())
+    }
+
+    fn create_handler(
+        cycles: Arc<MockCycleRepository>,
+        sessions: MockSessionRepository,
+        drafts: Arc<InMemoryImportDraftRepository>,
+        events: Arc<InMemoryEventBus>,
+    ) -> ResolveImportDraftHandler {
+        ResolveImportDraftHandler::new(cycles, Arc::new(sessions), drafts, events)
+    }
+
+    fn command(
+        cycle_id: CycleId,
+        draft_id: ConfirmationRequestId,
+        apply: bool,
+    ) -> ResolveImportDraftCommand {
+        ResolveImportDraftCommand {
+            cycle_id,
+            draft_id,
+            apply,
+        }
+    }
+
+    #[tokio::test]
+    async fn applying_merges_the_draft_into_the_cycle() {
+        let (cycles, sessions, cycle_id) = repositories_with(start_through_consequences);
+        let (drafts, events, draft_id) = setup_repositories(cycle_id).await;
+        let handler = create_handler(Arc::new(cycles), sessions, drafts.clone(), events.clone());
+
+        let result = handler
+            .handle(
+                command(cycle_id, draft_id, true),
+                CommandMetadata::new(owner()),
+            )
+            .await
+            .unwrap();
+
+        let cycle = result.cycle.unwrap();
+        let objectives: ObjectivesOutput = output(&cycle, ComponentType::Objectives).unwrap();
+        let alternatives: AlternativesOutput = output(&cycle, ComponentType::Alternatives).unwrap();
+        let consequences: ConsequencesOutput = output(&cycle, ComponentType::Consequences).unwrap();
+        assert_eq!(objectives.fundamental_objectives[0].description, "Commute");
+        assert_eq!(alternatives.options[0].name, "Move downtown");
+        let cell = &consequences.table.cells[&alternatives.options[0].id]
+            [&objectives.fundamental_objectives[0].id];
+        assert_eq!(cell.source.as_deref(), Some(AI_DRAFT_SOURCE));
+        assert_eq!(events.event_count(), 3);
+        assert!(drafts.list_pending(&cycle_id).await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn discarding_leaves_the_cycle_untouched() {
+        let (cycles, sessions, cycle_id) = repositories_with(start_through_consequences);
+        let cycles = Arc::new(cycles);
+        let (drafts, events, draft_id) = setup_repositories(cycle_id).await;
+        let handler = create_handler(cycles.clone(), sessions, drafts, events.clone());
+
+        let result = handler
+            .handle(
+                command(cycle_id, draft_id, false),
+                CommandMetadata::new(owner()),
+            )
+            .await
+            .unwrap();
+
+        assert!(result.cycle.is_none());
+        assert!(!result.draft.confirmation.is_pending());
+        assert_eq!(events.event_count(), 0);
+        let cycle = cycles.find_by_id(&cycle_id).await.unwrap().unwrap();
+        let objectives: ObjectivesOutput = output(&cycle, ComponentType::Objectives).unwrap();
+        assert!(objectives.fundamental_objectives.is_empty());
+    }
+
+    #[tokio::test]
+    async fn drafts_resolve_only_once() {
+        let (cycles, sessions, cycle_id) = repositories_with(start_through_consequences);
+        let (drafts, events, draft_id) = setup_repositories(cycle_id).await;
+        let handler = create_handler(Arc::new(cycles), sessions, drafts.clone(), events.clone());
+        handler
+            .handle(
+                command(cycle_id, draft_id, false),
+                CommandMetadata::new(owner()),
+            )
+            .await
+            .unwrap();
+
+        let result = handler
+            .handle(
+                command(cycle_id, draft_id, true),
+                CommandMetadata::new(owner()),
+            )
+            .await;
+
+        assert!(matches!(
+            result,
+            Err(ResolveImportDraftError::AlreadyResolved)
+        ));
+    }
+
+    #[tokio::test]
+    async fn locked_components_save_nothing() {
+        let (cycles, sessions, cycle_id) = repositories_with(|cycle| {
+            for ct in [
+                ComponentType::IssueRaising,
+                ComponentType::ProblemFrame,
+                ComponentType::StakeholderAnalysis,
+                ComponentType::Objectives,
+            ] {
+                cycle.start_component(ct).unwrap();
+            }
+            cycle.take_events();
+        });
+        let (drafts, events, draft_id) = setup_repositories(cycle_id).await;
+        let handler = create_handler(Arc::new(cycles), sessions, drafts.clone(), events.clone());
+
+        let result = handler
+            .handle(
+                command(cycle_id, draft_id, true),
+                CommandMetadata::new(owner()),
+            )
+            .await;
+
+        assert!(matches!(result, Err(ResolveImportDraftError::Domain(_))));
+        assert_eq!(events.event_count(), 0);
+        assert_eq!(drafts.list_pending(&cycle_id).await.unwrap().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn rejects_users_who_do_not_own_the_session() {
+        let (cycles, sessions, cycle_id) = repositories_with(start_through_consequences);
+        let (drafts, events, draft_id) = setup_repositories(cycle_id).await;
+        let handler = create_handler(Arc::new(cycles), sessions, drafts.clone(), events.clone());
+
+        let result = handler
+            .handle(
+                command(cycle_id, draft_id, true),
+                CommandMetadata::new(stranger()),
+            )
+            .await;
+
+        assert!(matches!(result, Err(ResolveImportDraftError::Forbidden)));
+    }
+}
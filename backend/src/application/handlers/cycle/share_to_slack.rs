@@ -0,0 +1,566 @@
+//! ShareToSlackHandler - Command handler for posting a cycle's recommendation
+//! to a Slack channel.
+//!
+//! Publishes the decision document by public link (so tier checks and
+//! ownership apply as for [`super::PublishDocumentHandler`]), snapshots the
+//! Recommendation output as a `RecommendationDigest`, and posts it with the
+//! workspace's bot token. Members of an organization that chose Teams in its
+//! `OrganizationChatSettings` share with [`super::ShareToTeamsHandler`]
+//! instead. Each share is tracked as a `SlackShare`; shares that fail
+//! transiently stay pending and are retried by [`ShareToSlackHandler::run`],
+//! which polls for due shares the same way `CycleDocumentMailer` does.
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use tokio::sync::watch;
+use tokio::time;
+
+use crate::domain::document::{ChatPlatform, SlackShare};
+use crate::domain::foundation::{CycleId, DomainError, Timestamp, UserId};
+use crate::ports::{
+    CycleReader, OrganizationChatSettingsRepository, SlackClient, SlackShareRepository,
+    SlackWorkspaceStore,
+};
+
+use super::publish_document::{
+    PublishDocumentCommand, PublishDocumentError, PublishDocumentHandler,
+};
+use super::recommendation_digest::recommendation_digest;
+
+/// Shares retried per poll.
+const RETRY_BATCH_SIZE: u32 = 50;
+
+/// Command to share a cycle's recommendation to a Slack channel.
+#[derive(Debug, Clone)]
+pub struct ShareToSlackCommand {
+    pub cycle_id: CycleId,
+    pub user_id: UserId,
+    /// Connected workspace to post in.
+    pub team_id: String,
+    /// Channel ID or name.
+    pub channel: String,
+    /// The user's organization, whose document template and chat settings
+    /// apply, if known.
+    pub organization: Option<String>,
+}
+
+/// Result of a share: sent, pending a retry, or failed permanently.
+pub type ShareToSlackResult = SlackShare;
+
+/// Error type for sharing to Slack.
+#[derive(Debug, Clone)]
+pub enum ShareToSlackError {
+    /// The user has not connected this Slack workspace.
+    NotConnected,
+    /// No channel was given.
+    InvalidChannel,
+    /// The user's organization shares to Teams instead.
+    SlackNotEnabled,
+    /// The cycle has no recommendation to share yet.
+    NoRecommendation,
+    /// Publishing the document link failed or was not allowed.
+    Publish(PublishDocumentError),
+    /// Domain error.
+    Domain(DomainError),
+}
+
+impl std::fmt::Display for ShareToSlackError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ShareToSlackError::NotConnected => write!(f, "Slack workspace not connected"),
+            ShareToSlackError::InvalidChannel => write!(f, "A Slack channel is required"),
+            ShareToSlackError::SlackNotEnabled => {
+                write!(f, "Sharing to Slack is not enabled")
+            }
+            ShareToSlackError::NoRecommendation => {
+                write!(f, "The cycle has no recommendation yet")
+            }
+            ShareToSlackError::Publish(err) => write!(f, "{}", err),
+            ShareToSlackError::Domain(err) => write!(f, "{}", err),
+        }
+    }
+}
+
+impl std::error::Error for ShareToSlackError {}
+
+impl From<DomainError> for ShareToSlackError {
+    fn from(err: DomainError) -> Self {
+        ShareToSlackError::Domain(err)
+    }
+}
+
+impl From<PublishDocumentError> for ShareToSlackError {
+    fn from(err: PublishDocumentError) -> Self {
+        ShareToSlackError::Publish(err)
+    }
+}
+
+/// Handler for sharing recommendations to Slack and retrying failed posts.
+pub struct ShareToSlackHandler {
+    publish_handler: Arc<PublishDocumentHandler>,
+    cycle_reader: Arc<dyn CycleReader>,
+    chat_settings: Arc<dyn OrganizationChatSettingsRepository>,
+    workspaces: Arc<dyn SlackWorkspaceStore>,
+    shares: Arc<dyn SlackShareRepository>,
+    slack: Arc<dyn SlackClient>,
+}
+
+impl ShareToSlackHandler {
+    pub fn new(
+        publish_handler: Arc<PublishDocumentHandler>,
+        cycle_reader: Arc<dyn CycleReader>,
+        chat_settings: Arc<dyn OrganizationChatSettingsRepository>,
+        workspaces: Arc<dyn SlackWorkspaceStore>,
+        shares: Arc<dyn SlackShareRepository>,
+        slack: Arc<dyn SlackClient>,
+    ) -> Self {
+        Self {
+            publish_handler,
+            cycle_reader,
+            chat_settings,
+            workspaces,
+            shares,
+            slack,
+        }
+    }
+
+    #[tracing::instrument(name = "ShareToSlackHandler::handle", skip_all)]
+    pub async fn handle(
+        &self,
+        cmd: ShareToSlackCommand,
+    ) -> Result<ShareToSlackResult, ShareToSlackError> {
+        if let Some(organization) = &cmd.organization {
+            let settings = self.chat_settings.get(organization).await?;
+            if settings.is_some_and(|settings| settings.platform != ChatPlatform::Slack) {
+                return Err(ShareToSlackError::SlackNotEnabled);
+            }
+        }
+        let channel = cmd.channel.trim();
+        if channel.is_empty() {
+            return Err(ShareToSlackError::InvalidChannel);
+        }
+        if self
+            .workspaces
+            .get(&cmd.user_id, &cmd.team_id)
+            .await?
+            .is_none()
+        {
+            return Err(ShareToSlackError::NotConnected);
+        }
+
+        // Publishing first applies the ownership and tier checks
+        let published = self
+            .publish_handler
+            .handle(PublishDocumentCommand {
+                cycle_id: cmd.cycle_id,
+                user_id: cmd.user_id.clone(),
+                organization: cmd.organization,
+                ttl_days: None,
+            })
+            .await?;
+
+        let digest = recommendation_digest(self.cycle_reader.as_ref(), &cmd.cycle_id, published)
+            .await?
+            .ok_or(ShareToSlackError::NoRecommendation)?;
+
+        let mut share = SlackShare::new(cmd.cycle_id, cmd.user_id, cmd.team_id, channel, digest);
+        self.shares.save(&share).await?;
+        self.attempt(&mut share).await?;
+        Ok(share)
+    }
+
+    /// Retries due shares every `poll_interval` until shutdown is signalled.
+    pub async fn run(
+        &self,
+        poll_interval: Duration,
+        mut shutdown: watch::Receiver<bool>,
+    ) -> Result<(), DomainError> {
+        let mut interval = time::interval(poll_interval);
+
+        loop {
+            tokio::select! {
+                _ = shutdown.changed() => {
+                    if *shutdown.borrow() {
+                        return Ok(());
+                    }
+                }
+
+                _ = interval.tick() => {
+                    self.retry_due(RETRY_BATCH_SIZE).await?;
+                }
+            }
+        }
+    }
+
+    /// Attempts up to `limit` due shares, returning how many were posted.
+    #[tracing::instrument(name = "ShareToSlackHandler::retry_due", skip_all)]
+    pub async fn retry_due(&self, limit: u32) -> Result<usize, DomainError> {
+        let due = self.shares.list_due(Timestamp::now(), limit).await?;
+        let mut sent = 0;
+        for mut share in due {
+            self.attempt(&mut share).await?;
+            if share.sent_at.is_some() {
+                sent += 1;
+            }
+        }
+        Ok(sent)
+    }
+
+    /// Posts the share once, recording the outcome.
+    async fn attempt(&self, share: &mut SlackShare) -> Result<(), DomainError> {
+        match self.post(share).await {
+            Ok(ts) => share.record_sent(ts),
+            Err((error, retryable)) => {
+                tracing::warn!(
+                    share_id = %share.id,
+                    attempts = share.attempts + 1,
+                    retryable,
+                    error = %error,
+                    "Slack share failed"
+                );
+                share.record_failure(error, retryable);
+            }
+        }
+        self.shares.save(share).await
+    }
+
+    /// Returns the message timestamp, or the failure message and whether it
+    /// is worth retrying.
+    async fn post(&self, share: &SlackShare) -> Result<String, (String, bool)> {
+        let workspace = self
+            .workspaces
+            .get(&share.user_id, &share.team_id)
+            .await
+            .map_err(|e| (e.to_string(), true))?
+            .ok_or_else(|| ("Slack workspace disconnected".to_string(), false))?;
+
+        self.slack
+            .post_recommendation(&workspace.bot_token, &share.channel, &share.digest)
+            .await
+            .map_err(|e| (e.to_string(), e.is_retryable()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::recommendation_digest::fixtures::*;
+    use super::*;
+    use crate::adapters::slack::{InMemorySlackShareRepository, InMemorySlackWorkspaceStore};
+    use crate::adapters::teams::InMemoryOrganizationChatSettingsRepository;
+    use crate::domain::document::{
+        DeliveryStatus, OrganizationChatSettings, RecommendationDigest, TeamsChannel,
+    };
+    use crate::ports::{SlackError, SlackWorkspace};
+    use async_trait::async_trait;
+    use secrecy::{ExposeSecret, Secret};
+    use std::sync::Mutex;
+
+    /// Records posts and fails with `failure` while it is set.
+    #[derive(Default)]
+    struct MockSlackClient {
+        posted: Mutex<Vec<(String, String, RecommendationDigest)>>,
+        failure: Mutex<Option<SlackError>>,
+    }
+
+    #[async_trait]
+    impl SlackClient for MockSlackClient {
+        fn authorization_url(&self, state: &str) -> String {
+            format!("https://slack.example.com/oauth?state={}", state)
+        }
+
+        async fn exchange_code(&self, _code: &str) -> Result<SlackWorkspace, SlackError> {
+            Ok(workspace())
+        }
+
+        async fn post_recommendation(
+            &self,
+            bot_token: &Secret<String>,
+            channel: &str,
+            digest: &RecommendationDigest,
+        ) -> Result<String, SlackError> {
+            if let Some(error) = self.failure.lock().unwrap().clone() {
+                return Err(error);
+            }
+            self.posted.lock().unwrap().push((
+                bot_token.expose_secret().clone(),
+                channel.to_string(),
+                digest.clone(),
+            ));
+            Ok("1700000000.000100".to_string())
+        }
+
+        async fn post_message(
+            &self,
+            _bot_token: &Secret<String>,
+            _channel: &str,
+            _text: &str,
+        ) -> Result<String, SlackError> {
+            Err(SlackError::Api(
+                "not used by recommendation shares".to_string(),
+            ))
+        }
+    }
+
+    fn workspace() -> SlackWorkspace {
+        SlackWorkspace {
+            team_id: "T123".to_string(),
+            team_name: "Acme".to_string(),
+            bot_token: Secret::new("xoxb-token".to_string()),
+            connected_at: Timestamp::now(),
+        }
+    }
+
+    fn user() -> UserId {
+        UserId::new("user-1").unwrap()
+    }
+
+    /// Empty repositories, with the user connected to workspace T123.
+    async fn setup_repositories() -> (
+        Arc<InMemoryOrganizationChatSettingsRepository>,
+        Arc<InMemorySlackWorkspaceStore>,
+        Arc<InMemorySlackShareRepository>,
+    ) {
+        let workspaces = Arc::new(InMemorySlackWorkspaceStore::new());
+        workspaces.save(&user(), &workspace()).await.unwrap();
+        (
+            Arc::new(InMemoryOrganizationChatSettingsRepository::new()),
+            workspaces,
+            Arc::new(InMemorySlackShareRepository::new()),
+        )
+    }
+
+    fn create_handler(
+        cycle_id: CycleId,
+        recommendation: Option<serde_json::Value>,
+        can_export: bool,
+        chat_settings: Arc<InMemoryOrganizationChatSettingsRepository>,
+        workspaces: Arc<InMemorySlackWorkspaceStore>,
+        shares: Arc<InMemorySlackShareRepository>,
+        slack: Arc<MockSlackClient>,
+    ) -> ShareToSlackHandler {
+        ShareToSlackHandler::new(
+            publish_handler(cycle_id, can_export),
+            Arc::new(OutputsCycleReader { recommendation }),
+            chat_settings,
+            workspaces,
+            shares,
+            slack,
+        )
+    }
+
+    fn command(cycle_id: CycleId) -> ShareToSlackCommand {
+        ShareToSlackCommand {
+            cycle_id,
+            user_id: user(),
+            team_id: "T123".to_string(),
+            channel: " C456 ".to_string(),
+            organization: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn posts_the_recommendation_with_a_share_link() {
+        let (chat_settings, workspaces, shares) = setup_repositories().await;
+        let slack = Arc::new(MockSlackClient::default());
+        let cycle_id = CycleId::new();
+        let handler = create_handler(
+            cycle_id,
+            Some(recommendation()),
+            true,
+            chat_settings,
+            workspaces,
+            shares,
+            slack.clone(),
+        );
+
+        let share = handler.handle(command(cycle_id)).await.unwrap();
+
+        assert_eq!(share.status, DeliveryStatus::Sent);
+        assert_eq!(share.message_ts.as_deref(), Some("1700000000.000100"));
+        let posted = slack.posted.lock().unwrap();
+        let (token, channel, digest) = &posted[0];
+        assert_eq!(token, "xoxb-token");
+        assert_eq!(channel, "C456");
+        assert_eq!(digest.standout.as_deref(), Some("Lisbon"));
+        assert_eq!(digest.caveats, ["Visa timing is uncertain"]);
+        assert!(digest
+            .link
+            .starts_with("https://app.example.com/public/documents/"));
+        let dq = digest.decision_quality.as_ref().unwrap();
+        assert_eq!(dq.overall_score.value(), 60);
+        assert_eq!(dq.weakest_elements, ["Clear Tradeoffs"]);
+    }
+
+    #[tokio::test]
+    async fn requires_a_connected_workspace() {
+        let (chat_settings, workspaces, shares) = setup_repositories().await;
+        workspaces.delete(&user(), "T123").await.unwrap();
+        let cycle_id = CycleId::new();
+        let handler = create_handler(
+            cycle_id,
+            Some(recommendation()),
+            true,
+            chat_settings,
+            workspaces,
+            shares,
+            Arc::new(MockSlackClient::default()),
+        );
+
+        let result = handler.handle(command(cycle_id)).await;
+
+        assert!(matches!(result, Err(ShareToSlackError::NotConnected)));
+    }
+
+    #[tokio::test]
+    async fn requires_a_recommendation() {
+        let (chat_settings, workspaces, shares) = setup_repositories().await;
+        let cycle_id = CycleId::new();
+        let handler = create_handler(
+            cycle_id,
+            None,
+            true,
+            chat_settings,
+            workspaces,
+            shares,
+            Arc::new(MockSlackClient::default()),
+        );
+
+        let result = handler.handle(command(cycle_id)).await;
+
+        assert!(matches!(result, Err(ShareToSlackError::NoRecommendation)));
+    }
+
+    #[tokio::test]
+    async fn applies_export_tier_checks() {
+        let (chat_settings, workspaces, shares) = setup_repositories().await;
+        let slack = Arc::new(MockSlackClient::default());
+        let cycle_id = CycleId::new();
+        let handler = create_handler(
+            cycle_id,
+            Some(recommendation()),
+            false,
+            chat_settings,
+            workspaces,
+            shares,
+            slack.clone(),
+        );
+
+        let result = handler.handle(command(cycle_id)).await;
+
+        assert!(matches!(result, Err(ShareToSlackError::Publish(_))));
+        assert!(slack.posted.lock().unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn refused_once_the_organization_selects_teams() {
+        let (chat_settings, workspaces, shares) = setup_repositories().await;
+        let settings = OrganizationChatSettings::new(
+            "acme",
+            ChatPlatform::Teams,
+            vec![
+                TeamsChannel::new("Decisions", "https://acme.webhook.office.com/webhookb2/abc")
+                    .unwrap(),
+            ],
+        )
+        .unwrap();
+        chat_settings.save(&settings).await.unwrap();
+        let slack = Arc::new(MockSlackClient::default());
+        let cycle_id = CycleId::new();
+        let handler = create_handler(
+            cycle_id,
+            Some(recommendation()),
+            true,
+            chat_settings,
+            workspaces,
+            shares,
+            slack.clone(),
+        );
+        let cmd = ShareToSlackCommand {
+            organization: Some("acme".to_string()),
+            ..command(cycle_id)
+        };
+
+        let result = handler.handle(cmd).await;
+
+        assert!(matches!(result, Err(ShareToSlackError::SlackNotEnabled)));
+        assert!(slack.posted.lock().unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn transient_failures_stay_queued_for_retry() {
+        let (chat_settings, workspaces, shares) = setup_repositories().await;
+        let slack = Arc::new(MockSlackClient::default());
+        *slack.failure.lock().unwrap() = Some(SlackError::Unavailable("ratelimited".to_string()));
+        let cycle_id = CycleId::new();
+        let handler = create_handler(
+            cycle_id,
+            Some(recommendation()),
+            true,
+            chat_settings,
+            workspaces,
+            shares.clone(),
+            slack.clone(),
+        );
+
+        let share = handler.handle(command(cycle_id)).await.unwrap();
+
+        assert_eq!(share.status, DeliveryStatus::Pending);
+        assert_eq!(share.attempts, 1);
+
+        // Make the share due now and let Slack recover
+        let mut stored = shares.find_by_id(&share.id).await.unwrap().unwrap();
+        stored.next_attempt_at = Timestamp::now();
+        shares.save(&stored).await.unwrap();
+        *slack.failure.lock().unwrap() = None;
+
+        assert_eq!(handler.retry_due(10).await.unwrap(), 1);
+        let stored = shares.find_by_id(&share.id).await.unwrap().unwrap();
+        assert_eq!(stored.status, DeliveryStatus::Sent);
+    }
+
+    #[tokio::test]
+    async fn unknown_channels_fail_without_retry() {
+        let (chat_settings, workspaces, shares) = setup_repositories().await;
+        let slack = Arc::new(MockSlackClient::default());
+        *slack.failure.lock().unwrap() = Some(SlackError::Api("channel_not_found".to_string()));
+        let cycle_id = CycleId::new();
+        let handler = create_handler(
+            cycle_id,
+            Some(recommendation()),
+            true,
+            chat_settings,
+            workspaces,
+            shares,
+            slack,
+        );
+
+        let share = handler.handle(command(cycle_id)).await.unwrap();
+
+        assert_eq!(share.status, DeliveryStatus::Failed);
+        assert!(share.last_error.unwrap().contains("channel_not_found"));
+    }
+
+    #[tokio::test]
+    async fn rejects_blank_channels() {
+        let (chat_settings, workspaces, shares) = setup_repositories().await;
+        let cycle_id = CycleId::new();
+        let handler = create_handler(
+            cycle_id,
+            Some(recommendation()),
+            true,
+            chat_settings,
+            workspaces,
+            shares,
+            Arc::new(MockSlackClient::default()),
+        );
+        let cmd = ShareToSlackCommand {
+            channel: "  ".to_string(),
+            ..command(cycle_id)
+        };
+
+        let result = handler.handle(cmd).await;
+
+        assert!(matches!(result, Err(ShareToSlackError::InvalidChannel)));
+    }
+}
@@ -0,0 +1,442 @@
+//! ShareToTeamsHandler - Command handler for posting a cycle's recommendation
+//! to a Microsoft Teams channel.
+//!
+//! Only members of an organization whose `OrganizationChatSettings` select
+//! Teams can share this way, and only to a channel its admin registered.
+//! The document is published and digested exactly as for
+//! [`super::ShareToSlackHandler`], then posted to the channel's incoming
+//! webhook. Each share is tracked as a `TeamsShare`; shares that fail
+//! transiently stay pending and are retried by [`ShareToTeamsHandler::run`].
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use tokio::sync::watch;
+use tokio::time;
+
+use crate::domain::document::{ChatPlatform, TeamsShare};
+use crate::domain::foundation::{CycleId, DomainError, Timestamp, UserId};
+use crate::ports::{
+    CycleReader, OrganizationChatSettingsRepository, TeamsClient, TeamsShareRepository,
+};
+
+use super::publish_document::{
+    PublishDocumentCommand, PublishDocumentError, PublishDocumentHandler,
+};
+use super::recommendation_digest::recommendation_digest;
+
+/// Shares retried per poll.
+const RETRY_BATCH_SIZE: u32 = 50;
+
+/// Command to share a cycle's recommendation to a Teams channel.
+#[derive(Debug, Clone)]
+pub struct ShareToTeamsCommand {
+    pub cycle_id: CycleId,
+    pub user_id: UserId,
+    /// Name of a channel registered in the organization's settings.
+    pub channel: String,
+    /// The user's organization, whose document template and chat settings
+    /// apply, if known.
+    pub organization: Option<String>,
+}
+
+/// Result of a share: sent, pending a retry, or failed permanently.
+pub type ShareToTeamsResult = TeamsShare;
+
+/// Error type for sharing to Teams.
+#[derive(Debug, Clone)]
+pub enum ShareToTeamsError {
+    /// The user's organization has not selected Teams.
+    TeamsNotEnabled,
+    /// No channel was given.
+    InvalidChannel,
+    /// The organization has not registered a channel by that name.
+    UnknownChannel,
+    /// The cycle has no recommendation to share yet.
+    NoRecommendation,
+    /// Publishing the document link failed or was not allowed.
+    Publish(PublishDocumentError),
+    /// Domain error.
+    Domain(DomainError),
+}
+
+impl std::fmt::Display for ShareToTeamsError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ShareToTeamsError::TeamsNotEnabled => write!(f, "Sharing to Teams is not enabled"),
+            ShareToTeamsError::InvalidChannel => write!(f, "A Teams channel is required"),
+            ShareToTeamsError::UnknownChannel => write!(f, "Teams channel not registered"),
+            ShareToTeamsError::NoRecommendation => {
+                write!(f, "The cycle has no recommendation yet")
+            }
+            ShareToTeamsError::Publish(err) => write!(f, "{}", err),
+            ShareToTeamsError::Domain(err) => write!(f, "{}", err),
+        }
+    }
+}
+
+impl std::error::Error for ShareToTeamsError {}
+
+impl From<DomainError> for ShareToTeamsError {
+    fn from(err: DomainError) -> Self {
+        ShareToTeamsError::Domain(err)
+    }
+}
+
+impl From<PublishDocumentError> for ShareToTeamsError {
+    fn from(err: PublishDocumentError) -> Self {
+        ShareToTeamsError::Publish(err)
+    }
+}
+
+/// Handler for sharing recommendations to Teams and retrying failed posts.
+pub struct ShareToTeamsHandler {
+    publish_handler: Arc<PublishDocumentHandler>,
+    cycle_reader: Arc<dyn CycleReader>,
+    chat_settings: Arc<dyn OrganizationChatSettingsRepository>,
+    shares: Arc<dyn TeamsShareRepository>,
+    teams: Arc<dyn TeamsClient>,
+}
+
+impl ShareToTeamsHandler {
+    pub fn new(
+        publish_handler: Arc<PublishDocumentHandler>,
+        cycle_reader: Arc<dyn CycleReader>,
+        chat_settings: Arc<dyn OrganizationChatSettingsRepository>,
+        shares: Arc<dyn TeamsShareRepository>,
+        teams: Arc<dyn TeamsClient>,
+    ) -> Self {
+        Self {
+            publish_handler,
+            cycle_reader,
+            chat_settings,
+            shares,
+            teams,
+        }
+    }
+
+    #[tracing::instrument(name = "ShareToTeamsHandler::handle", skip_all)]
+    pub async fn handle(
+        &self,
+        cmd: ShareToTeamsCommand,
+    ) -> Result<ShareToTeamsResult, ShareToTeamsError> {
+        if cmd.channel.trim().is_empty() {
+            return Err(ShareToTeamsError::InvalidChannel);
+        }
+        let Some(organization) = &cmd.organization else {
+            return Err(ShareToTeamsError::TeamsNotEnabled);
+        };
+        let settings = self
+            .chat_settings
+            .get(organization)
+            .await?
+            .filter(|settings| settings.platform == ChatPlatform::Teams)
+            .ok_or(ShareToTeamsError::TeamsNotEnabled)?;
+        let channel = settings
+            .teams_channel(&cmd.channel)
+            .ok_or(ShareToTeamsError::UnknownChannel)?
+            .name
+            .clone();
+
+        // Publishing first applies the ownership and tier checks
+        let published = self
+            .publish_handler
+            .handle(PublishDocumentCommand {
+                cycle_id: cmd.cycle_id,
+                user_id: cmd.user_id.clone(),
+                organization: cmd.organization,
+                ttl_days: None,
+            })
+            .await?;
+
+        let digest = recommendation_digest(self.cycle_reader.as_ref(), &cmd.cycle_id, published)
+            .await?
+            .ok_or(ShareToTeamsError::NoRecommendation)?;
+
+        let mut share = TeamsShare::new(
+            cmd.cycle_id,
+            cmd.user_id,
+            settings.organization,
+            channel,
+            digest,
+        );
+        self.shares.save(&share).await?;
+        self.attempt(&mut share).await?;
+        Ok(share)
+    }
+
+    /// Retries due shares every `poll_interval` until shutdown is signalled.
+    pub async fn run(
+        &self,
+        poll_interval: Duration,
+        mut shutdown: watch::Receiver<bool>,
+    ) -> Result<(), DomainError> {
+        let mut interval = time::interval(poll_interval);
+
+        loop {
+            tokio::select! {
+                _ = shutdown.changed() => {
+                    if *shutdown.borrow() {
+                        return Ok(());
+                    }
+                }
+
+                _ = interval.tick() => {
+                    self.retry_due(RETRY_BATCH_SIZE).await?;
+                }
+            }
+        }
+    }
+
+    /// Attempts up to `limit` due shares, returning how many were posted.
+    #[tracing::instrument(name = "ShareToTeamsHandler::retry_due", skip_all)]
+    pub async fn retry_due(&self, limit: u32) -> Result<usize, DomainError> {
+        let due = self.shares.list_due(Timestamp::now(), limit).await?;
+        let mut sent = 0;
+        for mut share in due {
+            self.attempt(&mut share).await?;
+            if share.sent_at.is_some() {
+                sent += 1;
+            }
+        }
+        Ok(sent)
+    }
+
+    /// Posts the share once, recording the outcome.
+    async fn attempt(&self, share: &mut TeamsShare) -> Result<(), DomainError> {
+        match self.post(share).await {
+            Ok(()) => share.record_sent(),
+            Err((error, retryable)) => {
+                tracing::warn!(
+                    share_id = %share.id,
+                    attempts = share.attempts + 1,
+                    retryable,
+                    error = %error,
+                    "Teams share failed"
+                );
+                share.record_failure(error, retryable);
+            }
+        }
+        self.shares.save(share).await
+    }
+
+    /// Looks up the channel's webhook at send time, so a channel an admin
+    /// removed is never posted to. Returns the failure message and whether
+    /// it is worth retrying.
+    async fn post(&self, share: &TeamsShare) -> Result<(), (String, bool)> {
+        let settings = self
+            .chat_settings
+            .get(&share.organization)
+            .await
+            .map_err(|e| (e.to_string(), true))?
+            .filter(|settings| settings.platform == ChatPlatform::Teams);
+        let channel = settings
+            .as_ref()
+            .and_then(|settings| settings.teams_channel(&share.channel))
+            .ok_or_else(|| ("Teams channel no longer registered".to_string(), false))?;
+
+        self.teams
+            .post_recommendation(&channel.webhook_url, &share.digest)
+            .await
+            .map_err(|e| (e.to_string(), e.is_retryable()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::recommendation_digest::fixtures::*;
+    use super::*;
+    use crate::adapters::teams::{
+        InMemoryOrganizationChatSettingsRepository, InMemoryTeamsShareRepository,
+    };
+    use crate::domain::document::{
+        DeliveryStatus, OrganizationChatSettings, RecommendationDigest, TeamsChannel,
+    };
+    use crate::ports::TeamsError;
+    use async_trait::async_trait;
+    use secrecy::{ExposeSecret, Secret};
+    use std::sync::Mutex;
+
+    const WEBHOOK: &str = "https://acme.webhook.office.com/webhookb2/abc/IncomingWebhook/def";
+
+    /// Records webhook posts and fails with `failure` while it is set.
+    #[derive(Default)]
+    struct MockTeamsClient {
+        posted: Mutex<Vec<(String, RecommendationDigest)>>,
+        failure: Mutex<Option<TeamsError>>,
+    }
+
+    #[async_trait]
+    impl TeamsClient for MockTeamsClient {
+        async fn post_recommendation(
+            &self,
+            webhook_url: &Secret<String>,
+            digest: &RecommendationDigest,
+        ) -> Result<(), TeamsError> {
+            if let Some(error) = self.failure.lock().unwrap().clone() {
+                return Err(error);
+            }
+            self.posted
+                .lock()
+                .unwrap()
+                .push((webhook_url.expose_secret().clone(), digest.clone()));
+            Ok(())
+        }
+    }
+
+    /// Chat settings with acme sharing to its "Decisions" channel.
+    async fn setup_chat_settings() -> Arc<InMemoryOrganizationChatSettingsRepository> {
+        let chat_settings = Arc::new(InMemoryOrganizationChatSettingsRepository::new());
+        let settings = OrganizationChatSettings::new(
+            "acme",
+            ChatPlatform::Teams,
+            vec![TeamsChannel::new("Decisions", WEBHOOK).unwrap()],
+        )
+        .unwrap();
+        chat_settings.save(&settings).await.unwrap();
+        chat_settings
+    }
+
+    fn create_handler(
+        cycle_id: CycleId,
+        chat_settings: Arc<InMemoryOrganizationChatSettingsRepository>,
+        shares: Arc<InMemoryTeamsShareRepository>,
+        teams: Arc<MockTeamsClient>,
+    ) -> ShareToTeamsHandler {
+        ShareToTeamsHandler::new(
+            publish_handler(cycle_id, true),
+            Arc::new(OutputsCycleReader {
+                recommendation: Some(recommendation()),
+            }),
+            chat_settings,
+            shares,
+            teams,
+        )
+    }
+
+    fn command(cycle_id: CycleId, channel: &str) -> ShareToTeamsCommand {
+        ShareToTeamsCommand {
+            cycle_id,
+            user_id: UserId::new("user-1").unwrap(),
+            channel: channel.to_string(),
+            organization: Some("acme".to_string()),
+        }
+    }
+
+    #[tokio::test]
+    async fn posts_to_the_organizations_teams_channel() {
+        let teams = Arc::new(MockTeamsClient::default());
+        let cycle_id = CycleId::new();
+        let handler = create_handler(
+            cycle_id,
+            setup_chat_settings().await,
+            Arc::new(InMemoryTeamsShareRepository::new()),
+            teams.clone(),
+        );
+
+        let share = handler
+            .handle(command(cycle_id, "decisions"))
+            .await
+            .unwrap();
+
+        assert_eq!(share.status, DeliveryStatus::Sent);
+        assert_eq!(share.organization, "acme");
+        assert_eq!(share.channel, "Decisions");
+        let posted = teams.posted.lock().unwrap();
+        assert_eq!(posted[0].0, WEBHOOK);
+        assert_eq!(posted[0].1.standout.as_deref(), Some("Lisbon"));
+        assert!(posted[0].1.decision_quality.is_some());
+    }
+
+    #[tokio::test]
+    async fn requires_the_organization_to_select_teams() {
+        let chat_settings = Arc::new(InMemoryOrganizationChatSettingsRepository::new());
+        let cycle_id = CycleId::new();
+        let handler = create_handler(
+            cycle_id,
+            chat_settings.clone(),
+            Arc::new(InMemoryTeamsShareRepository::new()),
+            Arc::new(MockTeamsClient::default()),
+        );
+
+        let result = handler.handle(command(cycle_id, "Decisions")).await;
+        assert!(matches!(result, Err(ShareToTeamsError::TeamsNotEnabled)));
+
+        let slack = OrganizationChatSettings::new("acme", ChatPlatform::Slack, vec![]).unwrap();
+        chat_settings.save(&slack).await.unwrap();
+        let result = handler.handle(command(cycle_id, "Decisions")).await;
+        assert!(matches!(result, Err(ShareToTeamsError::TeamsNotEnabled)));
+    }
+
+    #[tokio::test]
+    async fn channels_must_be_registered() {
+        let cycle_id = CycleId::new();
+        let handler = create_handler(
+            cycle_id,
+            setup_chat_settings().await,
+            Arc::new(InMemoryTeamsShareRepository::new()),
+            Arc::new(MockTeamsClient::default()),
+        );
+
+        let result = handler.handle(command(cycle_id, "General")).await;
+        assert!(matches!(result, Err(ShareToTeamsError::UnknownChannel)));
+
+        let result = handler.handle(command(cycle_id, " ")).await;
+        assert!(matches!(result, Err(ShareToTeamsError::InvalidChannel)));
+    }
+
+    #[tokio::test]
+    async fn retries_stop_when_the_channel_is_removed() {
+        let chat_settings = setup_chat_settings().await;
+        let shares = Arc::new(InMemoryTeamsShareRepository::new());
+        let teams = Arc::new(MockTeamsClient::default());
+        *teams.failure.lock().unwrap() = Some(TeamsError::Unavailable("429".to_string()));
+        let cycle_id = CycleId::new();
+        let handler = create_handler(
+            cycle_id,
+            chat_settings.clone(),
+            shares.clone(),
+            teams.clone(),
+        );
+
+        let share = handler
+            .handle(command(cycle_id, "Decisions"))
+            .await
+            .unwrap();
+        assert_eq!(share.status, DeliveryStatus::Pending);
+
+        // The admin switches back to Slack before the retry
+        let slack = OrganizationChatSettings::new("acme", ChatPlatform::Slack, vec![]).unwrap();
+        chat_settings.save(&slack).await.unwrap();
+        let mut stored = shares.find_by_id(&share.id).await.unwrap().unwrap();
+        stored.next_attempt_at = Timestamp::now();
+        shares.save(&stored).await.unwrap();
+        *teams.failure.lock().unwrap() = None;
+
+        assert_eq!(handler.retry_due(10).await.unwrap(), 0);
+        let stored = shares.find_by_id(&share.id).await.unwrap().unwrap();
+        assert_eq!(stored.status, DeliveryStatus::Failed);
+        assert!(teams.posted.lock().unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn removed_webhooks_fail_without_retry() {
+        let teams = Arc::new(MockTeamsClient::default());
+        *teams.failure.lock().unwrap() = Some(TeamsError::WebhookGone("410".to_string()));
+        let cycle_id = CycleId::new();
+        let handler = create_handler(
+            cycle_id,
+            setup_chat_settings().await,
+            Arc::new(InMemoryTeamsShareRepository::new()),
+            teams,
+        );
+
+        let share = handler
+            .handle(command(cycle_id, "Decisions"))
+            .await
+            .unwrap();
+
+        assert_eq!(share.status, DeliveryStatus::Failed);
+        assert_eq!(share.attempts, 1);
+    }
+}
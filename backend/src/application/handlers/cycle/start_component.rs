@@ -8,6 +8,8 @@ use std::sync::Arc;
 
 use serde::{Deserialize, Serialize};
 
+use crate::application::handlers::audit::CommandAuditor;
+use crate::domain::audit::{AuditRecord, AuditSummary};
 use crate::domain::cycle::Cycle;
 use crate::domain::foundation::{
     domain_event, CommandMetadata, ComponentType, CycleId, DomainError, EventId,
@@ -31,6 +33,10 @@ pub struct StartComponentResult {
     pub cycle: Cycle,
     /// The emitted event.
     pub event: ComponentStartedEvent,
+    /// Cycle version after this command, for reconciling optimistic updates.
+    pub version: u64,
+    /// IDs of the events this command published.
+    pub event_ids: Vec<EventId>,
 }
 
 /// Event published when a component is started.
@@ -86,6 +92,7 @@ impl From<DomainError> for StartComponentError {
 pub struct StartComponentHandler {
     cycle_repository: Arc<dyn CycleRepository>,
     event_publisher: Arc<dyn EventPublisher>,
+    auditor: CommandAuditor,
 }
 
 impl StartComponentHandler {
@@ -96,9 +103,17 @@ impl StartComponentHandler {
         Self {
             cycle_repository,
             event_publisher,
+            auditor: CommandAuditor::disabled(),
         }
     }
 
+    /// Records each command in the audit log.
+    pub fn with_auditor(mut self, auditor: CommandAuditor) -> Self {
+        self.auditor = auditor;
+        self
+    }
+
+    #[tracing::instrument(name = "StartComponentHandler::handle", skip_all)]
     pub async fn handle(
         &self,
         cmd: StartComponentCommand,
@@ -112,6 +127,7 @@ impl StartComponentHandler {
             .ok_or(StartComponentError::CycleNotFound(cmd.cycle_id))?;
 
         // 2. Start the component (domain logic handles validation)
+        let before = cycle.audit_summary();
         cycle.start_component(cmd.component_type)?;
 
         // 3. Persist the updated cycle
@@ -132,7 +148,20 @@ impl StartComponentHandler {
 
         self.event_publisher.publish(envelope).await?;
 
-        Ok(StartComponentResult { cycle, event })
+        self.auditor
+            .record(
+                AuditRecord::new(&metadata, "StartComponent", "cycle", cmd.cycle_id)
+                    .with_before(before)
+                    .with_after(cycle.audit_summary()),
+            )
+            .await;
+
+        Ok(StartComponentResult {
+            version: cycle.version(),
+            event_ids: vec![event.event_id.clone()],
+            cycle,
+            event,
+        })
     }
 }
 
@@ -0,0 +1,860 @@
+//! SyncDocumentHandler - Command handler for writing document edits back.
+//!
+//! Users can edit the exported Markdown decision document. This command
+//! compares the edited copy with the document it was exported as, maps each
+//! change to a component output update, validates the updated output against
+//! the component schema, and saves every component that passes. Edits that
+//! cannot be mapped or fail validation are reported as `ParseError`s rather
+//! than failing the whole sync.
+//!
+//! A sync that updates anything stores the edited document as a new
+//! `DocumentVersion`, preceded by the original when it is not already the
+//! latest version, so the history can be diffed later.
+//!
+//! It also publishes a `DocumentSectionsChangedEvent` carrying the changed
+//! sections, author, and version, which reaches collaborators through the
+//! session's WebSocket room. When the author started from an older version,
+//! sections someone else changed in the meantime are reported as conflicts:
+//! the sync still applies, last writer wins, but both sides are told.
+
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+use serde::{Deserialize, Serialize};
+
+use crate::application::handlers::audit::CommandAuditor;
+use crate::domain::audit::{AuditRecord, AuditSummary};
+use crate::domain::cycle::Cycle;
+use crate::domain::document::{
+    diff_sections, overlapping_sections, section_patches, ComponentEdit, DocumentVersion,
+    MarkdownContent, MarkdownDocumentParser, ParseError, ParseSeverity, SectionPatch,
+};
+use crate::domain::foundation::{
+    domain_event, CommandMetadata, ComponentType, CycleId, DomainError, EventId,
+    SerializableDomainEvent, SessionId, Timestamp, UserId,
+};
+use crate::ports::{
+    ComponentSchemaValidator, CycleRepository, DocumentVersionRepository, EventPublisher,
+};
+
+use super::ComponentOutputUpdatedEvent;
+
+/// Command to sync an edited Markdown document back into a cycle.
+#[derive(Debug, Clone)]
+pub struct SyncDocumentCommand {
+    /// The cycle the document was exported from.
+    pub cycle_id: CycleId,
+    /// The document as it was exported, before the user's edits.
+    pub original: MarkdownContent,
+    /// The document after the user's edits.
+    pub edited: MarkdownContent,
+    /// The latest version the user had seen when they started editing,
+    /// for conflict detection. `None` skips the check.
+    pub base_version: Option<u32>,
+}
+
+/// Result of syncing a document.
+#[derive(Debug, Clone)]
+pub struct SyncDocumentResult {
+    /// The cycle after the edits were applied.
+    pub cycle: Cycle,
+    /// Components whose outputs were updated, in PrOACT order.
+    pub updated_components: Vec<ComponentType>,
+    /// Edits that were not applied.
+    pub errors: Vec<ParseError>,
+    /// One event per updated component.
+    pub events: Vec<ComponentOutputUpdatedEvent>,
+    /// Version the edited document was stored as, if anything was synced.
+    pub document_version: Option<u32>,
+    /// Cycle version after the sync.
+    pub version: u64,
+    /// IDs of the events this sync published, component updates first.
+    pub event_ids: Vec<EventId>,
+    /// Headings of sections this sync overwrote after someone else changed
+    /// them since `base_version`.
+    pub conflicts: Vec<String>,
+}
+
+impl SyncDocumentResult {
+    /// Whether any edit was rejected, as opposed to merely ignored.
+    pub fn has_errors(&self) -> bool {
+        self.errors
+            .iter()
+            .any(|e| e.severity == ParseSeverity::Error)
+    }
+}
+
+/// Event published when a sync changes the document, so collaborators
+/// viewing it can apply the same change.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DocumentSectionsChangedEvent {
+    /// Unique event identifier.
+    pub event_id: EventId,
+    /// The cycle the document belongs to.
+    pub cycle_id: CycleId,
+    /// The session, for routing to its WebSocket room.
+    pub session_id: SessionId,
+    /// The version the edited document was stored as.
+    pub version: u32,
+    /// The version the author started from, if known.
+    pub base_version: Option<u32>,
+    /// Who made the edit.
+    pub author: UserId,
+    /// The sections the author changed, with their new text.
+    pub sections: Vec<SectionPatch>,
+    /// Sections someone else changed since `base_version` that this edit
+    /// overwrote.
+    pub conflicts: Vec<String>,
+    /// When the edit was synced.
+    pub changed_at: Timestamp,
+}
+
+domain_event!(
+    DocumentSectionsChangedEvent,
+    event_type = "document.sections_changed.v1",
+    schema_version = 1,
+    aggregate_id = cycle_id,
+    aggregate_type = "Cycle",
+    occurred_at = changed_at,
+    event_id = event_id
+);
+
+/// Error type for syncing a document.
+#[derive(Debug, Clone)]
+pub enum SyncDocumentError {
+    /// Cycle not found.
+    CycleNotFound(CycleId),
+    /// Domain error (e.g., cycle archived, persistence failure).
+    Domain(DomainError),
+}
+
+impl std::fmt::Display for SyncDocumentError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SyncDocumentError::CycleNotFound(id) => write!(f, "Cycle not found: {}", id),
+            SyncDocumentError::Domain(err) => write!(f, "{}", err),
+        }
+    }
+}
+
+impl std::error::Error for SyncDocumentError {}
+
+impl From<DomainError> for SyncDocumentError {
+    fn from(err: DomainError) -> Self {
+        SyncDocumentError::Domain(err)
+    }
+}
+
+/// Handler for syncing edited documents.
+pub struct SyncDocumentHandler {
+    cycle_repository: Arc<dyn CycleRepository>,
+    document_versions: Arc<dyn DocumentVersionRepository>,
+    schema_validator: Arc<dyn ComponentSchemaValidator>,
+    event_publisher: Arc<dyn EventPublisher>,
+    parser: MarkdownDocumentParser,
+    auditor: CommandAuditor,
+}
+
+impl SyncDocumentHandler {
+    pub fn new(
+        cycle_repository: Arc<dyn CycleRepository>,
+        document_versions: Arc<dyn DocumentVersionRepository>,
+        schema_validator: Arc<dyn ComponentSchemaValidator>,
+        event_publisher: Arc<dyn EventPublisher>,
+    ) -> Self {
+        Self {
+            cycle_repository,
+            document_versions,
+            schema_validator,
+            event_publisher,
+            parser: MarkdownDocumentParser::new(),
+            auditor: CommandAuditor::disabled(),
+        }
+    }
+
+    /// Records each command in the audit log.
+    pub fn with_auditor(mut self, auditor: CommandAuditor) -> Self {
+        self.auditor = auditor;
+        self
+    }
+
+    #[tracing::instrument(name = "SyncDocumentHandler::handle", skip_all)]
+    pub async fn handle(
+        &self,
+        cmd: SyncDocumentCommand,
+        metadata: CommandMetadata,
+    ) -> Result<SyncDocumentResult, SyncDocumentError> {
+        // 1. Find the cycle
+        let mut cycle = self
+            .cycle_repository
+            .find_by_id(&cmd.cycle_id)
+            .await?
+            .ok_or(SyncDocumentError::CycleNotFound(cmd.cycle_id))?;
+
+        let before = cycle.audit_summary();
+
+        // 2. Diff the edited document against the original
+        let diff = diff_sections(
+            &self.parser.parse(&cmd.original),
+            &self.parser.parse(&cmd.edited),
+        );
+        let mut errors = diff.errors;
+
+        let mut by_component: BTreeMap<usize, (ComponentType, Vec<ComponentEdit>)> =
+            BTreeMap::new();
+        for edit in diff.edits {
+            by_component
+                .entry(edit.component_type.order_index())
+                .or_insert_with(|| (edit.component_type, Vec::new()))
+                .1
+                .push(edit);
+        }
+
+        // 3. Apply, validate, and store each component's edits
+        let mut updated_components = Vec::new();
+        for (_, (component_type, edits)) in by_component {
+            match self.apply_edits(&mut cycle, component_type, &edits, &mut errors) {
+                Ok(true) => updated_components.push(component_type),
+                Ok(false) => {}
+                Err(message) => errors.push(ParseError::new(
+                    ParseSeverity::Error,
+                    &edits[0].section,
+                    edits[0].line,
+                    message,
+                )),
+            }
+        }
+
+        if updated_components.is_empty() {
+            return Ok(SyncDocumentResult {
+                version: cycle.version(),
+                cycle,
+                updated_components,
+                errors,
+                events: Vec::new(),
+                document_version: None,
+                event_ids: Vec::new(),
+                conflicts: Vec::new(),
+            });
+        }
+
+        // 4. Persist the updated cycle
+        self.cycle_repository.update(&cycle).await?;
+
+        // 5. Check for concurrent edits, then record the document history
+        let latest = self.document_versions.latest(&cmd.cycle_id).await?;
+        let sections = section_patches(&cmd.original, &cmd.edited);
+        let conflicts = self
+            .find_conflicts(&cmd, latest.as_ref(), &sections)
+            .await?;
+        let document_version = self.record_versions(&cmd, latest).await?;
+
+        // 6. Create and publish events
+        let events: Vec<_> = updated_components
+            .iter()
+            .map(|component_type| ComponentOutputUpdatedEvent {
+                event_id: EventId::new(),
+                cycle_id: cmd.cycle_id,
+                component_type: *component_type,
+                updated_at: Timestamp::now(),
+            })
+            .collect();
+        let document_event = DocumentSectionsChangedEvent {
+            event_id: EventId::new(),
+            cycle_id: cmd.cycle_id,
+            session_id: cycle.session_id(),
+            version: document_version,
+            base_version: cmd.base_version,
+            author: metadata.user_id.clone(),
+            sections,
+            conflicts: conflicts.clone(),
+            changed_at: Timestamp::now(),
+        };
+        let envelopes = events
+            .iter()
+            .map(|event| event.to_envelope())
+            .chain(std::iter::once(document_event.to_envelope()))
+            .map(|envelope| {
+                envelope
+                    .with_correlation_id(metadata.correlation_id())
+                    .with_user_id(metadata.user_id.to_string())
+            })
+            .collect();
+        self.event_publisher.publish_all(envelopes).await?;
+
+        let event_ids = events
+            .iter()
+            .map(|event| event.event_id.clone())
+            .chain(std::iter::once(document_event.event_id.clone()))
+            .collect();
+
+        self.auditor
+            .record(
+                AuditRecord::new(&metadata, "SyncDocument", "cycle", cmd.cycle_id)
+                    .with_before(before)
+                    .with_after(cycle.audit_summary()),
+            )
+            .await;
+
+        Ok(SyncDocumentResult {
+            version: cycle.version(),
+            cycle,
+            updated_components,
+            errors,
+            events,
+            document_version: Some(document_version),
+            event_ids,
+            conflicts,
+        })
+    }
+
+    /// Sections changed both by this edit and by others since the author's
+    /// base version.
+    async fn find_conflicts(
+        &self,
+        cmd: &SyncDocumentCommand,
+        latest: Option<&DocumentVersion>,
+        ours: &[SectionPatch],
+    ) -> Result<Vec<String>, DomainError> {
+        let (Some(base_version), Some(latest)) = (cmd.base_version, latest) else {
+            return Ok(Vec::new());
+        };
+        if latest.version <= base_version {
+            return Ok(Vec::new());
+        }
+        let Some(base) = self
+            .document_versions
+            .find(&cmd.cycle_id, base_version)
+            .await?
+        else {
+            return Ok(Vec::new());
+        };
+        let theirs = section_patches(&base.content, &latest.content);
+        Ok(overlapping_sections(ours, &theirs))
+    }
+
+    /// Store the edited document as the next version, returning its number.
+    async fn record_versions(
+        &self,
+        cmd: &SyncDocumentCommand,
+        latest: Option<DocumentVersion>,
+    ) -> Result<u32, DomainError> {
+        let mut next = latest.as_ref().map_or(1, |v| v.version + 1);
+        if latest.is_none_or(|v| v.content != cmd.original) {
+            self.document_versions
+                .save(&DocumentVersion::new(
+                    cmd.cycle_id,
+                    next,
+                    cmd.original.clone(),
+                ))
+                .await?;
+            next += 1;
+        }
+        self.document_versions
+            .save(&DocumentVersion::new(cmd.cycle_id, next, cmd.edited.clone()))
+            .await?;
+        Ok(next)
+    }
+
+    /// Apply one component's edits, returning whether the output changed.
+    ///
+    /// Individual edits that no longer apply are reported and skipped; a
+    /// schema or state failure rejects them all.
+    fn apply_edits(
+        &self,
+        cycle: &mut Cycle,
+        component_type: ComponentType,
+        edits: &[ComponentEdit],
+        errors: &mut Vec<ParseError>,
+    ) -> Result<bool, String> {
+        let status = cycle.component_status(component_type);
+        if !status.accepts_output() {
+            return Err(format!(
+                "{} is {:?}; reopen it for revision before editing it in the document",
+                component_type.display_name(),
+                status
+            ));
+        }
+        let mut output = cycle
+            .component(component_type)
+            .map(|component| component.output_as_value())
+            .ok_or_else(|| format!("{} not found", component_type.display_name()))?;
+
+        let mut applied = 0;
+        for edit in edits {
+            match edit.change.apply(&mut output) {
+                Ok(()) => applied += 1,
+                Err(message) => errors.push(ParseError::new(
+                    ParseSeverity::Error,
+                    &edit.section,
+                    edit.line,
+                    message,
+                )),
+            }
+        }
+        if applied == 0 {
+            return Ok(false);
+        }
+
+        self.schema_validator
+            .validate_partial(component_type, &output)
+            .map_err(|e| e.to_client_message())?;
+        cycle
+            .update_component_output(component_type, output)
+            .map_err(|e| e.to_string())?;
+        Ok(true)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::adapters::document::InMemoryDocumentVersionRepository;
+    use crate::domain::foundation::{ErrorCode, EventEnvelope, SessionId, UserId};
+    use crate::ports::SchemaValidationError;
+    use async_trait::async_trait;
+    use serde_json::Value;
+    use std::sync::Mutex;
+
+    // ─────────────────────────────────────────────────────────────────────
+    // Mock implementations
+    // ─────────────────────────────────────────────────────────────────────
+
+    struct MockCycleRepository {
+        cycles: Mutex<Vec<Cycle>>,
+        updated_cycles: Mutex<Vec<Cycle>>,
+    }
+
+    impl MockCycleRepository {
+        fn with_cycle(cycle: Cycle) -> Self {
+            Self {
+                cycles: Mutex::new(vec![cycle]),
+                updated_cycles: Mutex::new(Vec::new()),
+            }
+        }
+
+        fn updated_cycles(&self) -> Vec<Cycle> {
+            self.updated_cycles.lock().unwrap().clone()
+        }
+    }
+
+    #[async_trait]
+    impl CycleRepository for MockCycleRepository {
+        async fn save(&self, _cycle: &Cycle) -> Result<(), DomainError> {
+            Ok(())
+        }
+
+        async fn update(&self, cycle: &Cycle) -> Result<(), DomainError> {
+            self.updated_cycles.lock().unwrap().push(cycle.clone());
+            Ok(())
+        }
+
+        async fn find_by_id(&self, id: &CycleId) -> Result<Option<Cycle>, DomainError> {
+            Ok(self
+                .cycles
+                .lock()
+                .unwrap()
+                .iter()
+                .find(|c| c.id() == *id)
+                .cloned())
+        }
+
+        async fn exists(&self, id: &CycleId) -> Result<bool, DomainError> {
+            Ok(self.cycles.lock().unwrap().iter().any(|c| c.id() == *id))
+        }
+
+        async fn find_by_session_id(&self, _: &SessionId) -> Result<Vec<Cycle>, DomainError> {
+            Ok(vec![])
+        }
+
+        async fn find_primary_by_session_id(
+            &self,
+            _: &SessionId,
+        ) -> Result<Option<Cycle>, DomainError> {
+            Ok(None)
+        }
+
+        async fn find_branches(&self, _: &CycleId) -> Result<Vec<Cycle>, DomainError> {
+            Ok(vec![])
+        }
+
+        async fn count_by_session_id(&self, _: &SessionId) -> Result<u32, DomainError> {
+            Ok(0)
+        }
+
+        async fn delete(&self, _: &CycleId) -> Result<(), DomainError> {
+            Ok(())
+        }
+    }
+
+    struct MockEventPublisher {
+        published_events: Mutex<Vec<EventEnvelope>>,
+    }
+
+    impl MockEventPublisher {
+        fn new() -> Self {
+            Self {
+                published_events: Mutex::new(Vec::new()),
+            }
+        }
+
+        fn published_events(&self) -> Vec<EventEnvelope> {
+            self.published_events.lock().unwrap().clone()
+        }
+    }
+
+    #[async_trait]
+    impl EventPublisher for MockEventPublisher {
+        async fn publish(&self, event: EventEnvelope) -> Result<(), DomainError> {
+            self.published_events.lock().unwrap().push(event);
+            Ok(())
+        }
+
+        async fn publish_all(&self, events: Vec<EventEnvelope>) -> Result<(), DomainError> {
+            for event in events {
+                self.publish(event).await?;
+            }
+            Ok(())
+        }
+    }
+
+    /// Accepts everything except the listed component types.
+    struct MockSchemaValidator {
+        reject: Vec<ComponentType>,
+        schema: Value,
+    }
+
+    impl MockSchemaValidator {
+        fn accepting() -> Self {
+            Self::rejecting(vec![])
+        }
+
+        fn rejecting(reject: Vec<ComponentType>) -> Self {
+            Self {
+                reject,
+                schema: Value::Null,
+            }
+        }
+    }
+
+    impl ComponentSchemaValidator for MockSchemaValidator {
+        fn validate(&self, ct: ComponentType, output: &Value) -> Result<(), SchemaValidationError> {
+            self.validate_partial(ct, output)
+        }
+
+        fn schema_for(&self, _: ComponentType) -> &Value {
+            &self.schema
+        }
+
+        fn validate_partial(
+            &self,
+            ct: ComponentType,
+            _: &Value,
+        ) -> Result<(), SchemaValidationError> {
+            if self.reject.contains(&ct) {
+                return Err(SchemaValidationError::MissingRequired {
+                    field: "synthesis".to_string(),
+                });
+            }
+            Ok(())
+        }
+    }
+
+    // ─────────────────────────────────────────────────────────────────────
+    // Test helpers
+    // ─────────────────────────────────────────────────────────────────────
+
+    const ORIGINAL: &str = "\
+# Choose a supplier
+
+## Decision
+
+Which supplier should we use?
+
+## Alternatives
+
+- **Current supplier** (status quo)
+
+## Recommendation
+
+Stay with the current supplier.
+";
+
+    fn test_metadata() -> CommandMetadata {
+        CommandMetadata::new(UserId::new("test-user-123").unwrap())
+            .with_correlation_id("test-correlation")
+    }
+
+    /// A cycle with every component up to Recommendation in progress, and
+    /// Problem Frame carrying the original decision statement.
+    fn create_cycle() -> Cycle {
+        let mut cycle = Cycle::new(SessionId::new());
+        for ct in [
+            ComponentType::IssueRaising,
+            ComponentType::ProblemFrame,
+            ComponentType::StakeholderAnalysis,
+            ComponentType::Objectives,
+            ComponentType::Alternatives,
+            ComponentType::Consequences,
+            ComponentType::UncertaintyRegister,
+            ComponentType::Tradeoffs,
+            ComponentType::Recommendation,
+        ] {
+            cycle.start_component(ct).unwrap();
+        }
+        let mut frame = cycle
+            .component(ComponentType::ProblemFrame)
+            .unwrap()
+            .output_as_value();
+        frame["decision_statement"] = Value::from("Which supplier should we use?");
+        cycle
+            .update_component_output(ComponentType::ProblemFrame, frame)
+            .unwrap();
+        cycle.take_events();
+        cycle
+    }
+
+    fn command(cycle_id: CycleId, edited: String) -> SyncDocumentCommand {
+        SyncDocumentCommand {
+            cycle_id,
+            original: MarkdownContent::new(ORIGINAL),
+            edited: MarkdownContent::new(edited),
+            base_version: None,
+        }
+    }
+
+    fn output(cycle: &Cycle, ct: ComponentType) -> Value {
+        cycle.component(ct).unwrap().output_as_value()
+    }
+
+    // ─────────────────────────────────────────────────────────────────────
+    // Tests
+    // ─────────────────────────────────────────────────────────────────────
+
+    #[tokio::test]
+    async fn writes_edits_back_to_components() {
+        let cycle = create_cycle();
+        let cycle_id = cycle.id();
+        let repo = Arc::new(MockCycleRepository::with_cycle(cycle));
+        let publisher = Arc::new(MockEventPublisher::new());
+        let versions = Arc::new(InMemoryDocumentVersionRepository::new());
+        let handler = SyncDocumentHandler::new(
+            repo.clone(),
+            versions.clone(),
+            Arc::new(MockSchemaValidator::accepting()),
+            publisher.clone(),
+        );
+
+        let edited = ORIGINAL
+            .replace("should we use?", "should we use in 2027?")
+            .replace("(status quo)", "(status quo)\n- **Acme Ltd**");
+        let result = handler
+            .handle(command(cycle_id, edited), test_metadata())
+            .await
+            .unwrap();
+
+        assert_eq!(
+            result.updated_components,
+            vec![ComponentType::ProblemFrame, ComponentType::Alternatives]
+        );
+        assert!(result.errors.is_empty());
+        assert_eq!(
+            output(&result.cycle, ComponentType::ProblemFrame)["decision_statement"],
+            "Which supplier should we use in 2027?"
+        );
+        assert_eq!(
+            output(&result.cycle, ComponentType::Alternatives)["options"][0]["name"],
+            "Acme Ltd"
+        );
+        assert_eq!(repo.updated_cycles().len(), 1);
+
+        let events = publisher.published_events();
+        assert_eq!(events.len(), 3);
+        assert_eq!(events[0].event_type, "component.output_updated.v1");
+        assert_eq!(events[2].event_type, "document.sections_changed.v1");
+
+        // The original is stored as the baseline, then the edited copy
+        assert_eq!(result.document_version, Some(2));
+        let baseline = versions.find(&cycle_id, 1).await.unwrap().unwrap();
+        assert_eq!(baseline.content.as_str(), ORIGINAL);
+    }
+
+    #[tokio::test]
+    async fn repeated_syncs_extend_the_history() {
+        let cycle = create_cycle();
+        let cycle_id = cycle.id();
+        let versions = Arc::new(InMemoryDocumentVersionRepository::new());
+        let handler = SyncDocumentHandler::new(
+            Arc::new(MockCycleRepository::with_cycle(cycle)),
+            versions.clone(),
+            Arc::new(MockSchemaValidator::accepting()),
+            Arc::new(MockEventPublisher::new()),
+        );
+
+        let first = ORIGINAL.replace("should we use?", "should we pick?");
+        handler
+            .handle(command(cycle_id, first.clone()), test_metadata())
+            .await
+            .unwrap();
+        let second = first.replace("should we pick?", "should we choose?");
+        let result = handler
+            .handle(
+                SyncDocumentCommand {
+                    cycle_id,
+                    original: MarkdownContent::new(first),
+                    edited: MarkdownContent::new(second),
+                    base_version: Some(2),
+                },
+                test_metadata(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(result.document_version, Some(3));
+        assert_eq!(versions.latest(&cycle_id).await.unwrap().unwrap().version, 3);
+        assert!(result.conflicts.is_empty());
+    }
+
+    #[tokio::test]
+    async fn broadcasts_changed_sections_and_flags_concurrent_edits() {
+        let cycle = create_cycle();
+        let cycle_id = cycle.id();
+        let session_id = cycle.session_id();
+        let publisher = Arc::new(MockEventPublisher::new());
+        let handler = SyncDocumentHandler::new(
+            Arc::new(MockCycleRepository::with_cycle(cycle)),
+            Arc::new(InMemoryDocumentVersionRepository::new()),
+            Arc::new(MockSchemaValidator::accepting()),
+            publisher.clone(),
+        );
+
+        // A collaborator syncs first, from the same starting point (v1)
+        let theirs = ORIGINAL.replace("should we use?", "should we pick?");
+        handler
+            .handle(command(cycle_id, theirs), test_metadata())
+            .await
+            .unwrap();
+
+        let ours = ORIGINAL
+            .replace("should we use?", "should we use in 2027?")
+            .replace("Stay with", "Leave");
+        let mut cmd = command(cycle_id, ours);
+        cmd.base_version = Some(1);
+        let result = handler.handle(cmd, test_metadata()).await.unwrap();
+
+        assert_eq!(result.conflicts, vec!["Decision"]);
+        let events = publisher.published_events();
+        let changed = events.last().unwrap();
+        assert_eq!(changed.event_type, "document.sections_changed.v1");
+        assert_eq!(changed.payload["session_id"], session_id.to_string());
+        assert_eq!(changed.payload["author"], "test-user-123");
+        assert_eq!(changed.payload["base_version"], 1);
+        assert_eq!(changed.payload["sections"].as_array().unwrap().len(), 2);
+        assert_eq!(changed.payload["conflicts"], serde_json::json!(["Decision"]));
+    }
+
+    #[tokio::test]
+    async fn unchanged_document_saves_nothing() {
+        let cycle = create_cycle();
+        let cycle_id = cycle.id();
+        let repo = Arc::new(MockCycleRepository::with_cycle(cycle));
+        let publisher = Arc::new(MockEventPublisher::new());
+        let versions = Arc::new(InMemoryDocumentVersionRepository::new());
+        let handler = SyncDocumentHandler::new(
+            repo.clone(),
+            versions.clone(),
+            Arc::new(MockSchemaValidator::accepting()),
+            publisher.clone(),
+        );
+
+        let result = handler
+            .handle(command(cycle_id, ORIGINAL.to_string()), test_metadata())
+            .await
+            .unwrap();
+
+        assert!(result.updated_components.is_empty());
+        assert!(repo.updated_cycles().is_empty());
+        assert!(publisher.published_events().is_empty());
+        assert!(versions.latest(&cycle_id).await.unwrap().is_none());
+    }
+
+    #[tokio::test]
+    async fn schema_failures_are_reported_and_other_components_still_sync() {
+        let cycle = create_cycle();
+        let cycle_id = cycle.id();
+        let handler = SyncDocumentHandler::new(
+            Arc::new(MockCycleRepository::with_cycle(cycle)),
+            Arc::new(InMemoryDocumentVersionRepository::new()),
+            Arc::new(MockSchemaValidator::rejecting(vec![
+                ComponentType::Recommendation,
+            ])),
+            Arc::new(MockEventPublisher::new()),
+        );
+
+        let edited = ORIGINAL
+            .replace("should we use?", "should we pick?")
+            .replace("Stay with", "Leave");
+        let result = handler
+            .handle(command(cycle_id, edited), test_metadata())
+            .await
+            .unwrap();
+
+        assert_eq!(result.updated_components, vec![ComponentType::ProblemFrame]);
+        assert!(result.has_errors());
+        assert_eq!(result.errors[0].section, "Recommendation");
+        assert_eq!(
+            output(&result.cycle, ComponentType::Recommendation)["synthesis"],
+            ""
+        );
+    }
+
+    #[tokio::test]
+    async fn edits_to_completed_components_are_rejected() {
+        let mut cycle = create_cycle();
+        cycle
+            .complete_component(ComponentType::ProblemFrame)
+            .unwrap();
+        let cycle_id = cycle.id();
+        let handler = SyncDocumentHandler::new(
+            Arc::new(MockCycleRepository::with_cycle(cycle)),
+            Arc::new(InMemoryDocumentVersionRepository::new()),
+            Arc::new(MockSchemaValidator::accepting()),
+            Arc::new(MockEventPublisher::new()),
+        );
+
+        let edited = ORIGINAL.replace("should we use?", "should we pick?");
+        let result = handler
+            .handle(command(cycle_id, edited), test_metadata())
+            .await
+            .unwrap();
+
+        assert!(result.updated_components.is_empty());
+        assert_eq!(result.errors.len(), 1);
+        assert!(result.errors[0].message.contains("reopen it for revision"));
+    }
+
+    #[tokio::test]
+    async fn fails_when_cycle_not_found() {
+        let handler = SyncDocumentHandler::new(
+            Arc::new(MockCycleRepository::with_cycle(create_cycle())),
+            Arc::new(InMemoryDocumentVersionRepository::new()),
+            Arc::new(MockSchemaValidator::accepting()),
+            Arc::new(MockEventPublisher::new()),
+        );
+
+        let result = handler
+            .handle(
+                command(CycleId::new(), ORIGINAL.to_string()),
+                test_metadata(),
+            )
+            .await;
+
+        assert!(matches!(result, Err(SyncDocumentError::CycleNotFound(_))));
+    }
+
+    #[test]
+    fn display_formats_errors() {
+        let err = SyncDocumentError::Domain(DomainError::new(ErrorCode::DatabaseError, "boom"));
+        assert!(err.to_string().contains("boom"));
+    }
+}
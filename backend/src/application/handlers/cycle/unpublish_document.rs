@@ -0,0 +1,146 @@
+//! UnpublishDocumentHandler - Command handler for revoking a public link.
+//!
+//! Revocation is recorded on the publication, so every link to it stops
+//! working at once, including links that have not expired yet.
+
+use std::sync::Arc;
+
+use crate::domain::document::DocumentPublication;
+use crate::domain::foundation::{CycleId, DomainError, PublicationId, UserId};
+use crate::ports::DocumentPublicationRepository;
+
+/// Command to revoke a published document.
+#[derive(Debug, Clone)]
+pub struct UnpublishDocumentCommand {
+    pub cycle_id: CycleId,
+    pub publication_id: PublicationId,
+    pub user_id: UserId,
+}
+
+/// Result of a successful unpublish.
+pub type UnpublishDocumentResult = DocumentPublication;
+
+/// Error type for unpublishing a document.
+#[derive(Debug, Clone)]
+pub enum UnpublishDocumentError {
+    /// No publication with this ID belongs to the cycle.
+    NotFound(PublicationId),
+    /// Only the user who published the document may unpublish it.
+    Forbidden,
+    /// Domain error.
+    Domain(DomainError),
+}
+
+impl std::fmt::Display for UnpublishDocumentError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            UnpublishDocumentError::NotFound(id) => write!(f, "Publication not found: {}", id),
+            UnpublishDocumentError::Forbidden => write!(f, "Permission denied"),
+            UnpublishDocumentError::Domain(err) => write!(f, "{}", err),
+        }
+    }
+}
+
+impl std::error::Error for UnpublishDocumentError {}
+
+impl From<DomainError> for UnpublishDocumentError {
+    fn from(err: DomainError) -> Self {
+        UnpublishDocumentError::Domain(err)
+    }
+}
+
+/// Handler for unpublishing decision documents.
+pub struct UnpublishDocumentHandler {
+    publications: Arc<dyn DocumentPublicationRepository>,
+}
+
+impl UnpublishDocumentHandler {
+    pub fn new(publications: Arc<dyn DocumentPublicationRepository>) -> Self {
+        Self { publications }
+    }
+
+    /// Revokes the publication. Unpublishing twice succeeds.
+    #[tracing::instrument(name = "UnpublishDocumentHandler::handle", skip_all)]
+    pub async fn handle(
+        &self,
+        cmd: UnpublishDocumentCommand,
+    ) -> Result<UnpublishDocumentResult, UnpublishDocumentError> {
+        let mut publication = self
+            .publications
+            .find_by_id(&cmd.publication_id)
+            .await?
+            .filter(|p| p.cycle_id == cmd.cycle_id)
+            .ok_or(UnpublishDocumentError::NotFound(cmd.publication_id))?;
+        if publication.published_by != cmd.user_id {
+            return Err(UnpublishDocumentError::Forbidden);
+        }
+
+        if !publication.is_revoked() {
+            publication.revoke();
+            self.publications.update(&publication).await?;
+        }
+        Ok(publication)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::adapters::document::InMemoryDocumentPublicationRepository;
+    use crate::domain::foundation::Timestamp;
+
+    async fn published() -> (
+        UnpublishDocumentHandler,
+        Arc<InMemoryDocumentPublicationRepository>,
+        DocumentPublication,
+    ) {
+        let publications = Arc::new(InMemoryDocumentPublicationRepository::new());
+        let publication = DocumentPublication::new(
+            CycleId::new(),
+            UserId::new("user-1").unwrap(),
+            "move",
+            "<html></html>",
+            7,
+        );
+        publications.save(&publication).await.unwrap();
+        (
+            UnpublishDocumentHandler::new(publications.clone()),
+            publications,
+            publication,
+        )
+    }
+
+    fn command(publication: &DocumentPublication, user: &str) -> UnpublishDocumentCommand {
+        UnpublishDocumentCommand {
+            cycle_id: publication.cycle_id,
+            publication_id: publication.id,
+            user_id: UserId::new(user).unwrap(),
+        }
+    }
+
+    #[tokio::test]
+    async fn revokes_the_publication() {
+        let (handler, publications, publication) = published().await;
+
+        handler
+            .handle(command(&publication, "user-1"))
+            .await
+            .unwrap();
+
+        let stored = publications
+            .find_by_id(&publication.id)
+            .await
+            .unwrap()
+            .unwrap();
+        assert!(!stored.is_live(Timestamp::now()));
+    }
+
+    #[tokio::test]
+    async fn only_the_publisher_may_unpublish() {
+        let (handler, _, publication) = published().await;
+
+        let result = handler.handle(command(&publication, "user-2")).await;
+
+        assert!(matches!(result, Err(UnpublishDocumentError::Forbidden)));
+    }
+}
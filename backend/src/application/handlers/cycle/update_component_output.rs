@@ -8,6 +8,8 @@ use std::sync::Arc;
 use serde::{Deserialize, Serialize};
 use serde_json::Value as JsonValue;
 
+use crate::application::handlers::audit::CommandAuditor;
+use crate::domain::audit::{AuditRecord, AuditSummary};
 use crate::domain::cycle::Cycle;
 use crate::domain::foundation::{
     domain_event, CommandMetadata, ComponentType, CycleId, DomainError, EventId,
@@ -33,6 +35,10 @@ pub struct UpdateComponentOutputResult {
     pub cycle: Cycle,
     /// The emitted event.
     pub event: ComponentOutputUpdatedEvent,
+    /// Cycle version after this command, for reconciling optimistic updates.
+    pub version: u64,
+    /// IDs of the events this command published.
+    pub event_ids: Vec<EventId>,
 }
 
 /// Event published when a component's output is updated.
@@ -88,6 +94,7 @@ impl From<DomainError> for UpdateComponentOutputError {
 pub struct UpdateComponentOutputHandler {
     cycle_repository: Arc<dyn CycleRepository>,
     event_publisher: Arc<dyn EventPublisher>,
+    auditor: CommandAuditor,
 }
 
 impl UpdateComponentOutputHandler {
@@ -98,9 +105,17 @@ impl UpdateComponentOutputHandler {
         Self {
             cycle_repository,
             event_publisher,
+            auditor: CommandAuditor::disabled(),
         }
     }
 
+    /// Records each command in the audit log.
+    pub fn with_auditor(mut self, auditor: CommandAuditor) -> Self {
+        self.auditor = auditor;
+        self
+    }
+
+    #[tracing::instrument(name = "UpdateComponentOutputHandler::handle", skip_all)]
     pub async fn handle(
         &self,
         cmd: UpdateComponentOutputCommand,
@@ -114,6 +129,7 @@ impl UpdateComponentOutputHandler {
             .ok_or(UpdateComponentOutputError::CycleNotFound(cmd.cycle_id))?;
 
         // 2. Update the component output (domain logic handles validation)
+        let before = cycle.audit_summary();
         cycle.update_component_output(cmd.component_type, cmd.output)?;
 
         // 3. Persist the updated cycle
@@ -134,7 +150,20 @@ impl UpdateComponentOutputHandler {
 
         self.event_publisher.publish(envelope).await?;
 
-        Ok(UpdateComponentOutputResult { cycle, event })
+        self.auditor
+            .record(
+                AuditRecord::new(&metadata, "UpdateComponentOutput", "cycle", cmd.cycle_id)
+                    .with_before(before)
+                    .with_after(cycle.audit_summary()),
+            )
+            .await;
+
+        Ok(UpdateComponentOutputResult {
+            version: cycle.version(),
+            event_ids: vec![event.event_id.clone()],
+            cycle,
+            event,
+        })
     }
 }
 
@@ -0,0 +1,185 @@
+//! ViewPublishedDocumentHandler - Query handler for public document links.
+//!
+//! Anyone holding a link may read the snapshot; no account is needed. The
+//! token's signature and expiry are checked first, then the stored
+//! publication's own expiry and revocation.
+
+use std::sync::Arc;
+
+use crate::domain::document::DocumentPublication;
+use crate::domain::foundation::{DomainError, Timestamp};
+use crate::ports::{DocumentPublicationRepository, PublicLinkError, PublicLinkSigner};
+
+/// Query for the snapshot behind a public link.
+#[derive(Debug, Clone)]
+pub struct ViewPublishedDocumentQuery {
+    /// Token from the public URL.
+    pub token: String,
+}
+
+/// Error type for viewing a published document.
+#[derive(Debug, Clone)]
+pub enum ViewPublishedDocumentError {
+    /// The link is malformed, forged, or names no publication.
+    InvalidLink,
+    /// The link expired or the document was unpublished.
+    Unavailable,
+    /// Domain error.
+    Domain(DomainError),
+}
+
+impl std::fmt::Display for ViewPublishedDocumentError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ViewPublishedDocumentError::InvalidLink => write!(f, "Document not found"),
+            ViewPublishedDocumentError::Unavailable => {
+                write!(f, "This document is no longer shared")
+            }
+            ViewPublishedDocumentError::Domain(err) => write!(f, "{}", err),
+        }
+    }
+}
+
+impl std::error::Error for ViewPublishedDocumentError {}
+
+impl From<DomainError> for ViewPublishedDocumentError {
+    fn from(err: DomainError) -> Self {
+        ViewPublishedDocumentError::Domain(err)
+    }
+}
+
+/// Handler for reading published documents.
+pub struct ViewPublishedDocumentHandler {
+    publications: Arc<dyn DocumentPublicationRepository>,
+    signer: Arc<dyn PublicLinkSigner>,
+}
+
+impl ViewPublishedDocumentHandler {
+    pub fn new(
+        publications: Arc<dyn DocumentPublicationRepository>,
+        signer: Arc<dyn PublicLinkSigner>,
+    ) -> Self {
+        Self {
+            publications,
+            signer,
+        }
+    }
+
+    #[tracing::instrument(name = "ViewPublishedDocumentHandler::handle", skip_all)]
+    pub async fn handle(
+        &self,
+        query: ViewPublishedDocumentQuery,
+    ) -> Result<DocumentPublication, ViewPublishedDocumentError> {
+        let id = self.signer.verify(&query.token).map_err(|e| match e {
+            PublicLinkError::Expired => ViewPublishedDocumentError::Unavailable,
+            PublicLinkError::Malformed | PublicLinkError::InvalidSignature => {
+                ViewPublishedDocumentError::InvalidLink
+            }
+        })?;
+        let publication = self
+            .publications
+            .find_by_id(&id)
+            .await?
+            .ok_or(ViewPublishedDocumentError::InvalidLink)?;
+        if !publication.is_live(Timestamp::now()) {
+            return Err(ViewPublishedDocumentError::Unavailable);
+        }
+        Ok(publication)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::adapters::document::InMemoryDocumentPublicationRepository;
+    use crate::adapters::storage::HmacPublicLinkSigner;
+    use crate::domain::foundation::{CycleId, PublicationId, UserId};
+
+    /// A publication repository holding one live publication, and the
+    /// signer for its links.
+    async fn setup_repositories() -> (
+        Arc<InMemoryDocumentPublicationRepository>,
+        Arc<HmacPublicLinkSigner>,
+        DocumentPublication,
+    ) {
+        let publications = Arc::new(InMemoryDocumentPublicationRepository::new());
+        let publication = DocumentPublication::new(
+            CycleId::new(),
+            UserId::new("user-1").unwrap(),
+            "move",
+            "<html>snapshot</html>",
+            7,
+        );
+        publications.save(&publication).await.unwrap();
+        let signer = Arc::new(HmacPublicLinkSigner::new(
+            "test-secret-that-is-long-enough",
+            "https://app.example.com",
+        ));
+        (publications, signer, publication)
+    }
+
+    fn create_handler(
+        publications: Arc<InMemoryDocumentPublicationRepository>,
+        signer: Arc<HmacPublicLinkSigner>,
+    ) -> ViewPublishedDocumentHandler {
+        ViewPublishedDocumentHandler::new(publications, signer)
+    }
+
+    fn query(signer: &HmacPublicLinkSigner, id: &PublicationId) -> ViewPublishedDocumentQuery {
+        let url = signer.issue(id, Timestamp::now().plus_days(1));
+        ViewPublishedDocumentQuery {
+            token: url.rsplit('/').next().unwrap().to_string(),
+        }
+    }
+
+    #[tokio::test]
+    async fn serves_live_publications() {
+        let (publications, signer, publication) = setup_repositories().await;
+        let handler = create_handler(publications, signer.clone());
+
+        let publication = handler
+            .handle(query(&signer, &publication.id))
+            .await
+            .unwrap();
+
+        assert_eq!(publication.html, "<html>snapshot</html>");
+    }
+
+    #[tokio::test]
+    async fn revoked_publications_are_unavailable() {
+        let (publications, signer, publication) = setup_repositories().await;
+        let mut revoked = publication.clone();
+        revoked.revoke();
+        publications.update(&revoked).await.unwrap();
+        let handler = create_handler(publications, signer.clone());
+
+        let result = handler.handle(query(&signer, &publication.id)).await;
+
+        assert!(matches!(
+            result,
+            Err(ViewPublishedDocumentError::Unavailable)
+        ));
+    }
+
+    #[tokio::test]
+    async fn forged_and_unknown_links_are_invalid() {
+        let (publications, signer, publication) = setup_repositories().await;
+        let handler = create_handler(publications, signer.clone());
+
+        let forged = handler
+            .handle(ViewPublishedDocumentQuery {
+                token: format!("{}.99999999999.00", publication.id),
+            })
+            .await;
+        let unknown = handler.handle(query(&signer, &PublicationId::new())).await;
+
+        assert!(matches!(
+            forged,
+            Err(ViewPublishedDocumentError::InvalidLink)
+        ));
+        assert!(matches!(
+            unknown,
+            Err(ViewPublishedDocumentError::InvalidLink)
+        ));
+    }
+}
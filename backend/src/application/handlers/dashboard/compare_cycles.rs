@@ -32,6 +32,7 @@ impl CompareCyclesHandler {
         Self { reader }
     }
 
+    #[tracing::instrument(name = "CompareCyclesHandler::handle", skip_all)]
     pub async fn handle(
         &self,
         query: CompareCyclesQuery,
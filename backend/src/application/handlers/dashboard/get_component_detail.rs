@@ -1,13 +1,14 @@
 //! GetComponentDetailHandler - Query handler for retrieving component details.
 //!
 //! Returns detailed view of a specific component including structured output,
-//! conversation metadata, and navigation context.
+//! conversation metadata, navigation context, and (when a usage tracker is
+//! configured) the cycle's AI cost rollup.
 
 use std::sync::Arc;
 
-use crate::domain::dashboard::ComponentDetailView;
+use crate::domain::dashboard::{AiCostLine, AiCostSummary, ComponentDetailView};
 use crate::domain::foundation::{ComponentType, CycleId, UserId};
-use crate::ports::{DashboardError, DashboardReader};
+use crate::ports::{CostBreakdown, CycleCostRollup, DashboardError, DashboardReader, UsageTracker};
 
 /// Query to get component detail.
 #[derive(Debug, Clone)]
@@ -28,20 +29,63 @@ pub type GetComponentDetailResult = ComponentDetailView;
 /// Returns full component data for drill-down views.
 pub struct GetComponentDetailHandler {
     reader: Arc<dyn DashboardReader>,
+    usage_tracker: Option<Arc<dyn UsageTracker>>,
 }
 
 impl GetComponentDetailHandler {
     pub fn new(reader: Arc<dyn DashboardReader>) -> Self {
-        Self { reader }
+        Self {
+            reader,
+            usage_tracker: None,
+        }
+    }
+
+    /// Adds the cycle's AI cost rollup to the detail view.
+    pub fn with_usage_tracker(mut self, usage_tracker: Arc<dyn UsageTracker>) -> Self {
+        self.usage_tracker = Some(usage_tracker);
+        self
     }
 
+    #[tracing::instrument(name = "GetComponentDetailHandler::handle", skip_all)]
     pub async fn handle(
         &self,
         query: GetComponentDetailQuery,
     ) -> Result<GetComponentDetailResult, DashboardError> {
-        self.reader
+        let mut detail = self
+            .reader
             .get_component_detail(query.cycle_id, query.component_type, &query.user_id)
-            .await
+            .await?;
+
+        if let Some(tracker) = &self.usage_tracker {
+            // Cost data is supplementary; the view is still useful without it
+            match tracker.get_cycle_costs(query.cycle_id).await {
+                Ok(rollup) => detail.ai_cost = Some(to_cost_summary(&rollup, query.component_type)),
+                Err(e) => tracing::warn!(cycle_id = %query.cycle_id, "Failed to load AI cost rollup: {}", e),
+            }
+        }
+
+        Ok(detail)
+    }
+}
+
+fn to_cost_summary(rollup: &CycleCostRollup, component_type: ComponentType) -> AiCostSummary {
+    AiCostSummary {
+        component: to_cost_line(&rollup.component(component_type)),
+        cycle: to_cost_line(&rollup.total),
+        by_component: rollup
+            .by_component
+            .iter()
+            .map(|(ct, breakdown)| (*ct, to_cost_line(breakdown)))
+            .collect(),
+    }
+}
+
+fn to_cost_line(breakdown: &CostBreakdown) -> AiCostLine {
+    AiCostLine {
+        cost_cents: breakdown.cost_cents(),
+        tokens: breakdown.tokens,
+        requests: breakdown.requests,
+        average_latency_ms: breakdown.average_latency_ms(),
     }
 }
 
@@ -145,6 +189,9 @@ mod tests {
             can_revise: true,
             previous_component: Some(ComponentType::ProblemFrame),
             next_component: Some(ComponentType::Alternatives),
+            ai_cost: None,
+            stakeholder_grid: None,
+            risk_register: None,
         }
     }
 
@@ -172,6 +219,54 @@ mod tests {
         assert_eq!(returned_detail.status, ComponentStatus::Complete);
     }
 
+    #[tokio::test]
+    async fn test_get_detail_includes_cycle_cost_rollup() {
+        use crate::adapters::ai::InMemoryUsageTracker;
+        use crate::ports::UsageRecord;
+
+        let detail = create_test_component_detail();
+        let tracker = Arc::new(InMemoryUsageTracker::new());
+        for (component_type, cost) in [
+            (ComponentType::Objectives, 400_000),
+            (ComponentType::ProblemFrame, 250_000),
+        ] {
+            tracker
+                .record_usage(
+                    UsageRecord::new(
+                        test_user_id(),
+                        SessionId::new(),
+                        "anthropic",
+                        "claude-sonnet-4",
+                        100,
+                        50,
+                        0,
+                        Some(component_type),
+                    )
+                    .with_cycle_id(detail.cycle_id)
+                    .with_cost_micro_cents(cost)
+                    .with_latency_ms(1200),
+                )
+                .await
+                .unwrap();
+        }
+
+        let reader = Arc::new(MockDashboardReader::with_component_detail(detail.clone()));
+        let handler = GetComponentDetailHandler::new(reader).with_usage_tracker(tracker);
+
+        let query = GetComponentDetailQuery {
+            cycle_id: detail.cycle_id,
+            component_type: ComponentType::Objectives,
+            user_id: test_user_id(),
+        };
+
+        let cost = handler.handle(query).await.unwrap().ai_cost.unwrap();
+        assert_eq!(cost.component.requests, 1);
+        assert!((cost.component.cost_cents - 0.4).abs() < 1e-9);
+        assert!((cost.cycle.cost_cents - 0.65).abs() < 1e-9);
+        assert_eq!(cost.cycle.average_latency_ms, Some(1200));
+        assert_eq!(cost.by_component[0].0, ComponentType::ProblemFrame);
+    }
+
     #[tokio::test]
     async fn test_get_detail_passes_component_type() {
         let detail = create_test_component_detail();
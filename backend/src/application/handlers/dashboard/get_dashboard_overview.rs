@@ -35,6 +35,7 @@ impl GetDashboardOverviewHandler {
         Self { reader }
     }
 
+    #[tracing::instrument(name = "GetDashboardOverviewHandler::handle", skip_all)]
     pub async fn handle(
         &self,
         query: GetDashboardOverviewQuery,
@@ -142,9 +143,11 @@ mod tests {
             session_id: SessionId::new(),
             session_title: "Test Decision".to_string(),
             decision_statement: Some("Should we expand?".to_string()),
+            stakeholders: vec![],
             objectives: vec![],
             alternatives: vec![],
             consequences_table: None,
+            key_uncertainties: vec![],
             recommendation: None,
             dq_score: None,
             active_cycle_id: Some(CycleId::new()),
@@ -0,0 +1,187 @@
+//! GetDecisionTreesHandler - Query handler for a cycle's decision trees.
+//!
+//! Returns each tree the user built for the cycle, laid out for rendering
+//! and annotated with expected values once the tree is complete.
+
+use std::sync::Arc;
+
+use crate::domain::dashboard::DecisionTreeView;
+use crate::domain::foundation::{CycleId, UserId};
+use crate::ports::{DashboardError, DecisionTreeRepository};
+
+/// Query to get the decision trees of a cycle.
+#[derive(Debug, Clone)]
+pub struct GetDecisionTreesQuery {
+    /// The cycle the trees belong to.
+    pub cycle_id: CycleId,
+    /// User ID for authorization.
+    pub user_id: UserId,
+}
+
+/// Result of successful decision trees query, oldest tree first.
+pub type GetDecisionTreesResult = Vec<DecisionTreeView>;
+
+/// Handler for rendering a cycle's decision trees.
+pub struct GetDecisionTreesHandler {
+    repository: Arc<dyn DecisionTreeRepository>,
+}
+
+impl GetDecisionTreesHandler {
+    pub fn new(repository: Arc<dyn DecisionTreeRepository>) -> Self {
+        Self { repository }
+    }
+
+    #[tracing::instrument(name = "GetDecisionTreesHandler::handle", skip_all)]
+    pub async fn handle(
+        &self,
+        query: GetDecisionTreesQuery,
+    ) -> Result<GetDecisionTreesResult, DashboardError> {
+        let trees = self
+            .repository
+            .list_for_cycle(&query.cycle_id)
+            .await
+            .map_err(|e| DashboardError::Database(e.to_string()))?;
+
+        Ok(trees
+            .iter()
+            .filter(|tree| tree.user_id == query.user_id)
+            .map(DecisionTreeView::build)
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::decision_tree::{DecisionTree, NodeKind};
+    use crate::domain::foundation::{DecisionTreeId, DomainError, ErrorCode};
+    use async_trait::async_trait;
+
+    // ─────────────────────────────────────────────────────────────────────
+    // Mock Implementation
+    // ─────────────────────────────────────────────────────────────────────
+
+    struct MockDecisionTreeRepository {
+        trees: Vec<DecisionTree>,
+        should_fail: bool,
+    }
+
+    impl MockDecisionTreeRepository {
+        fn with_trees(trees: Vec<DecisionTree>) -> Self {
+            Self {
+                trees,
+                should_fail: false,
+            }
+        }
+
+        fn failing() -> Self {
+            Self {
+                trees: Vec::new(),
+                should_fail: true,
+            }
+        }
+    }
+
+    #[async_trait]
+    impl DecisionTreeRepository for MockDecisionTreeRepository {
+        async fn save(&self, _tree: &DecisionTree) -> Result<(), DomainError> {
+            unimplemented!()
+        }
+
+        async fn find_by_id(
+            &self,
+            _id: &DecisionTreeId,
+        ) -> Result<Option<DecisionTree>, DomainError> {
+            unimplemented!()
+        }
+
+        async fn list_for_cycle(
+            &self,
+            cycle_id: &CycleId,
+        ) -> Result<Vec<DecisionTree>, DomainError> {
+            if self.should_fail {
+                return Err(DomainError::new(ErrorCode::DatabaseError, "connection lost"));
+            }
+            Ok(self
+                .trees
+                .iter()
+                .filter(|t| &t.cycle_id == cycle_id)
+                .cloned()
+                .collect())
+        }
+
+        async fn delete(&self, _id: &DecisionTreeId) -> Result<(), DomainError> {
+            unimplemented!()
+        }
+    }
+
+    fn user(id: &str) -> UserId {
+        UserId::new(id).unwrap()
+    }
+
+    fn tree(cycle_id: CycleId, owner: &str) -> DecisionTree {
+        let mut tree = DecisionTree::new(cycle_id, user(owner), "Launch plan", None).unwrap();
+        let root = tree.add_root("Launch?", NodeKind::Decision).unwrap();
+        tree.add_branch(&root, "Yes", None, "Win", NodeKind::Terminal { payoff: 10.0 })
+            .unwrap();
+        tree
+    }
+
+    // ─────────────────────────────────────────────────────────────────────
+    // Tests
+    // ─────────────────────────────────────────────────────────────────────
+
+    #[tokio::test]
+    async fn test_returns_users_trees_for_cycle() {
+        let cycle_id = CycleId::new();
+        let repository = Arc::new(MockDecisionTreeRepository::with_trees(vec![
+            tree(cycle_id, "owner"),
+            tree(CycleId::new(), "owner"),
+        ]));
+        let handler = GetDecisionTreesHandler::new(repository);
+
+        let views = handler
+            .handle(GetDecisionTreesQuery {
+                cycle_id,
+                user_id: user("owner"),
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(views.len(), 1);
+        assert_eq!(views[0].expected_value, Some(10.0));
+    }
+
+    #[tokio::test]
+    async fn test_hides_other_users_trees() {
+        let cycle_id = CycleId::new();
+        let repository = Arc::new(MockDecisionTreeRepository::with_trees(vec![tree(
+            cycle_id, "owner",
+        )]));
+        let handler = GetDecisionTreesHandler::new(repository);
+
+        let views = handler
+            .handle(GetDecisionTreesQuery {
+                cycle_id,
+                user_id: user("someone-else"),
+            })
+            .await
+            .unwrap();
+
+        assert!(views.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_maps_repository_errors() {
+        let handler = GetDecisionTreesHandler::new(Arc::new(MockDecisionTreeRepository::failing()));
+
+        let result = handler
+            .handle(GetDecisionTreesQuery {
+                cycle_id: CycleId::new(),
+                user_id: user("owner"),
+            })
+            .await;
+
+        assert!(matches!(result, Err(DashboardError::Database(_))));
+    }
+}
@@ -0,0 +1,336 @@
+//! GetGroupComparisonHandler - Query handler for group preference aggregation.
+//!
+//! In multi-stakeholder cycles each participant records scores for the
+//! alternatives and weights for the objectives in the tradeoffs component.
+//! This handler aggregates them by Borda count, approval voting, and weight
+//! averaging, and reports where participants dissent from the group. The
+//! tradeoffs and consequences components are required; objective value
+//! functions and alternative names are used when available.
+
+use std::sync::Arc;
+
+use crate::domain::analysis::DEFAULT_APPROVAL_THRESHOLD;
+use crate::domain::dashboard::GroupComparisonView;
+use crate::domain::foundation::{ComponentType, CycleId, UserId};
+use crate::domain::proact::{AlternativesOutput, ConsequencesOutput, ObjectivesOutput, TradeoffsOutput};
+use crate::ports::{DashboardError, DashboardReader};
+
+use super::component_output;
+
+/// Query to get the group comparison of a cycle's alternatives.
+#[derive(Debug, Clone)]
+pub struct GetGroupComparisonQuery {
+    /// The cycle to aggregate.
+    pub cycle_id: CycleId,
+    /// User ID for authorization.
+    pub user_id: UserId,
+    /// Score (0-100) at which a participant approves an alternative.
+    /// Defaults to `DEFAULT_APPROVAL_THRESHOLD`.
+    pub approval_threshold: Option<f64>,
+}
+
+/// Result of successful group comparison query.
+pub type GetGroupComparisonResult = GroupComparisonView;
+
+/// Handler for aggregating participants' preferences.
+pub struct GetGroupComparisonHandler {
+    reader: Arc<dyn DashboardReader>,
+}
+
+impl GetGroupComparisonHandler {
+    pub fn new(reader: Arc<dyn DashboardReader>) -> Self {
+        Self { reader }
+    }
+
+    #[tracing::instrument(name = "GetGroupComparisonHandler::handle", skip_all)]
+    pub async fn handle(
+        &self,
+        query: GetGroupComparisonQuery,
+    ) -> Result<GetGroupComparisonResult, DashboardError> {
+        let approval_threshold = query.approval_threshold.unwrap_or(DEFAULT_APPROVAL_THRESHOLD);
+        if !(0.0..=100.0).contains(&approval_threshold) {
+            return Err(DashboardError::InvalidInput(format!(
+                "Approval threshold must be between 0 and 100, got {}",
+                approval_threshold
+            )));
+        }
+
+        let reader = self.reader.as_ref();
+        let (cycle_id, user_id) = (query.cycle_id, &query.user_id);
+        let tradeoffs: TradeoffsOutput =
+            component_output(reader, cycle_id, user_id, ComponentType::Tradeoffs)
+                .await?
+                .ok_or(DashboardError::ComponentNotFound(ComponentType::Tradeoffs))?;
+        let consequences: ConsequencesOutput =
+            component_output(reader, cycle_id, user_id, ComponentType::Consequences)
+                .await?
+                .ok_or(DashboardError::ComponentNotFound(ComponentType::Consequences))?;
+        let objectives: ObjectivesOutput =
+            component_output(reader, cycle_id, user_id, ComponentType::Objectives)
+                .await?
+                .unwrap_or_default();
+        let alternatives: Option<AlternativesOutput> =
+            component_output(reader, cycle_id, user_id, ComponentType::Alternatives).await?;
+
+        Ok(GroupComparisonView::build(
+            query.cycle_id,
+            &tradeoffs.participant_preferences,
+            &objectives,
+            alternatives.as_ref(),
+            &consequences,
+            approval_threshold,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::dashboard::{ComponentDetailView, CycleComparison, DashboardOverview};
+    use crate::domain::foundation::{ComponentId, ComponentStatus, SessionId};
+    use async_trait::async_trait;
+    use serde_json::json;
+    use std::collections::HashMap;
+
+    // ─────────────────────────────────────────────────────────────────────
+    // Mock Implementation
+    // ─────────────────────────────────────────────────────────────────────
+
+    struct MockDashboardReader {
+        outputs: HashMap<ComponentType, serde_json::Value>,
+        should_unauthorized: bool,
+    }
+
+    impl MockDashboardReader {
+        fn with_outputs(outputs: Vec<(ComponentType, serde_json::Value)>) -> Self {
+            Self {
+                outputs: outputs.into_iter().collect(),
+                should_unauthorized: false,
+            }
+        }
+
+        fn unauthorized() -> Self {
+            Self {
+                outputs: HashMap::new(),
+                should_unauthorized: true,
+            }
+        }
+    }
+
+    #[async_trait]
+    impl DashboardReader for MockDashboardReader {
+        async fn get_overview(
+            &self,
+            _session_id: SessionId,
+            _cycle_id: Option<CycleId>,
+            _user_id: &UserId,
+        ) -> Result<DashboardOverview, DashboardError> {
+            unimplemented!()
+        }
+
+        async fn get_component_detail(
+            &self,
+            cycle_id: CycleId,
+            component_type: ComponentType,
+            _user_id: &UserId,
+        ) -> Result<ComponentDetailView, DashboardError> {
+            if self.should_unauthorized {
+                return Err(DashboardError::Unauthorized);
+            }
+            let output = self
+                .outputs
+                .get(&component_type)
+                .cloned()
+                .ok_or(DashboardError::ComponentNotFound(component_type))?;
+            Ok(ComponentDetailView {
+                component_id: ComponentId::new(),
+                cycle_id,
+                component_type,
+                status: ComponentStatus::Complete,
+                structured_output: output,
+                conversation_message_count: 0,
+                last_message_at: None,
+                can_branch: false,
+                can_revise: false,
+                previous_component: None,
+                next_component: None,
+                ai_cost: None,
+                stakeholder_grid: None,
+                risk_register: None,
+            })
+        }
+
+        async fn compare_cycles(
+            &self,
+            _cycle_ids: &[CycleId],
+            _user_id: &UserId,
+        ) -> Result<CycleComparison, DashboardError> {
+            unimplemented!()
+        }
+    }
+
+    fn test_user_id() -> UserId {
+        UserId::new("test-user-123").unwrap()
+    }
+
+    fn query(approval_threshold: Option<f64>) -> GetGroupComparisonQuery {
+        GetGroupComparisonQuery {
+            cycle_id: CycleId::new(),
+            user_id: test_user_id(),
+            approval_threshold,
+        }
+    }
+
+    fn objectives_output() -> serde_json::Value {
+        json!({
+            "fundamental_objectives": [
+                {
+                    "id": "o1",
+                    "description": "Pay",
+                    "performance_measure": {
+                        "description": "Salary",
+                        "is_quantitative": false,
+                        "unit": null,
+                        "direction": "higher_is_better"
+                    },
+                    "affected_party_id": null,
+                    "weight": 0.2
+                },
+                {
+                    "id": "o2",
+                    "description": "Security",
+                    "performance_measure": {
+                        "description": "Job security",
+                        "is_quantitative": false,
+                        "unit": null,
+                        "direction": "higher_is_better"
+                    },
+                    "affected_party_id": null,
+                    "weight": 0.8
+                }
+            ],
+            "means_objectives": []
+        })
+    }
+
+    fn consequences_output() -> serde_json::Value {
+        let cell = |rating: &str| json!({
+            "rating": rating,
+            "explanation": "",
+            "quant_value": null,
+            "quant_unit": null,
+            "source": null,
+            "uncertainty": null
+        });
+        json!({
+            "table": {
+                "alternative_ids": ["a1", "a2"],
+                "objective_ids": ["o1", "o2"],
+                "cells": {
+                    "a1": { "o1": cell("MuchBetter"), "o2": cell("MuchWorse") },
+                    "a2": { "o1": cell("Same"), "o2": cell("Better") }
+                }
+            },
+            "uncertainties": []
+        })
+    }
+
+    fn tradeoffs_output() -> serde_json::Value {
+        json!({
+            "dominated_alternatives": [],
+            "irrelevant_objectives": [],
+            "tensions": [],
+            "participant_preferences": [
+                {
+                    "participant_id": "p1",
+                    "name": "Ana",
+                    "alternative_scores": { "a1": 90.0, "a2": 40.0 },
+                    "objective_weights": { "o1": 0.8, "o2": 0.2 }
+                },
+                {
+                    "participant_id": "p2",
+                    "name": "Ben",
+                    "alternative_scores": { "a1": 30.0, "a2": 80.0 },
+                    "objective_weights": { "o1": 0.2, "o2": 0.8 }
+                },
+                {
+                    "participant_id": "p3",
+                    "name": "Cy",
+                    "alternative_scores": { "a1": 50.0, "a2": 70.0 }
+                }
+            ]
+        })
+    }
+
+    // ─────────────────────────────────────────────────────────────────────
+    // Tests
+    // ─────────────────────────────────────────────────────────────────────
+
+    #[tokio::test]
+    async fn test_aggregates_participant_preferences() {
+        let reader = Arc::new(MockDashboardReader::with_outputs(vec![
+            (ComponentType::Objectives, objectives_output()),
+            (ComponentType::Consequences, consequences_output()),
+            (ComponentType::Tradeoffs, tradeoffs_output()),
+        ]));
+        let handler = GetGroupComparisonHandler::new(reader);
+
+        let view = handler.handle(query(None)).await.unwrap();
+
+        assert_eq!(view.participant_count, 3);
+        assert_eq!(view.approval_threshold, DEFAULT_APPROVAL_THRESHOLD);
+        assert_eq!(view.borda_winner_id.as_deref(), Some("a2"));
+        assert_eq!(view.alternatives[0].name, "a1");
+        assert_eq!(view.objectives[0].name, "Pay");
+        assert!(!view.dissent.participants[0].agrees_with_group);
+    }
+
+    #[tokio::test]
+    async fn test_uses_requested_approval_threshold() {
+        let reader = Arc::new(MockDashboardReader::with_outputs(vec![
+            (ComponentType::Consequences, consequences_output()),
+            (ComponentType::Tradeoffs, tradeoffs_output()),
+        ]));
+        let handler = GetGroupComparisonHandler::new(reader);
+
+        let view = handler.handle(query(Some(85.0))).await.unwrap();
+
+        assert_eq!(view.approval_winner_id.as_deref(), Some("a1"));
+    }
+
+    #[tokio::test]
+    async fn test_rejects_out_of_range_threshold() {
+        let reader = Arc::new(MockDashboardReader::with_outputs(vec![]));
+        let handler = GetGroupComparisonHandler::new(reader);
+
+        let result = handler.handle(query(Some(120.0))).await;
+
+        assert!(matches!(result, Err(DashboardError::InvalidInput(_))));
+    }
+
+    #[tokio::test]
+    async fn test_requires_tradeoffs() {
+        let reader = Arc::new(MockDashboardReader::with_outputs(vec![(
+            ComponentType::Consequences,
+            consequences_output(),
+        )]));
+        let handler = GetGroupComparisonHandler::new(reader);
+
+        let result = handler.handle(query(None)).await;
+
+        assert!(matches!(
+            result,
+            Err(DashboardError::ComponentNotFound(ComponentType::Tradeoffs))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_handles_unauthorized() {
+        let reader = Arc::new(MockDashboardReader::unauthorized());
+        let handler = GetGroupComparisonHandler::new(reader);
+
+        let result = handler.handle(query(None)).await;
+
+        assert!(matches!(result, Err(DashboardError::Unauthorized)));
+    }
+}
@@ -0,0 +1,303 @@
+//! GetWeightedComparisonHandler - Query handler for weighted alternative scores.
+//!
+//! Scores a cycle's alternatives with the weighted additive model and returns
+//! each alternative's total with per-objective contributions. The objectives
+//! and consequences components are required; alternative names and the
+//! cycle's selected scoring method are used when available.
+
+use std::sync::Arc;
+
+use crate::domain::dashboard::WeightedComparisonView;
+use crate::domain::foundation::{ComponentType, CycleId, UserId};
+use crate::domain::proact::{AlternativesOutput, ConsequencesOutput, ObjectivesOutput, TradeoffsOutput};
+use crate::ports::{DashboardError, DashboardReader};
+
+use super::component_output;
+
+/// Query to get the weighted comparison of a cycle's alternatives.
+#[derive(Debug, Clone)]
+pub struct GetWeightedComparisonQuery {
+    /// The cycle to score.
+    pub cycle_id: CycleId,
+    /// User ID for authorization.
+    pub user_id: UserId,
+}
+
+/// Result of successful weighted comparison query.
+pub type GetWeightedComparisonResult = WeightedComparisonView;
+
+/// Handler for scoring a cycle's alternatives.
+pub struct GetWeightedComparisonHandler {
+    reader: Arc<dyn DashboardReader>,
+}
+
+impl GetWeightedComparisonHandler {
+    pub fn new(reader: Arc<dyn DashboardReader>) -> Self {
+        Self { reader }
+    }
+
+    #[tracing::instrument(name = "GetWeightedComparisonHandler::handle", skip_all)]
+    pub async fn handle(
+        &self,
+        query: GetWeightedComparisonQuery,
+    ) -> Result<GetWeightedComparisonResult, DashboardError> {
+        let reader = self.reader.as_ref();
+        let (cycle_id, user_id) = (query.cycle_id, &query.user_id);
+        let objectives: ObjectivesOutput =
+            component_output(reader, cycle_id, user_id, ComponentType::Objectives)
+                .await?
+                .ok_or(DashboardError::ComponentNotFound(ComponentType::Objectives))?;
+        let consequences: ConsequencesOutput =
+            component_output(reader, cycle_id, user_id, ComponentType::Consequences)
+                .await?
+                .ok_or(DashboardError::ComponentNotFound(ComponentType::Consequences))?;
+        let alternatives: Option<AlternativesOutput> =
+            component_output(reader, cycle_id, user_id, ComponentType::Alternatives).await?;
+        let scoring_method =
+            component_output::<TradeoffsOutput>(reader, cycle_id, user_id, ComponentType::Tradeoffs)
+                .await?
+                .map(|t| t.scoring_method)
+                .unwrap_or_default();
+
+        Ok(WeightedComparisonView::build(
+            query.cycle_id,
+            scoring_method,
+            &objectives,
+            alternatives.as_ref(),
+            &consequences,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::dashboard::{ComponentDetailView, CycleComparison, DashboardOverview};
+    use crate::domain::foundation::{ComponentId, ComponentStatus, SessionId};
+    use async_trait::async_trait;
+    use serde_json::json;
+    use std::collections::HashMap;
+
+    // ─────────────────────────────────────────────────────────────────────
+    // Mock Implementation
+    // ─────────────────────────────────────────────────────────────────────
+
+    struct MockDashboardReader {
+        outputs: HashMap<ComponentType, serde_json::Value>,
+        should_unauthorized: bool,
+    }
+
+    impl MockDashboardReader {
+        fn with_outputs(outputs: Vec<(ComponentType, serde_json::Value)>) -> Self {
+            Self {
+                outputs: outputs.into_iter().collect(),
+                should_unauthorized: false,
+            }
+        }
+
+        fn unauthorized() -> Self {
+            Self {
+                outputs: HashMap::new(),
+                should_unauthorized: true,
+            }
+        }
+    }
+
+    #[async_trait]
+    impl DashboardReader for MockDashboardReader {
+        async fn get_overview(
+            &self,
+            _session_id: SessionId,
+            _cycle_id: Option<CycleId>,
+            _user_id: &UserId,
+        ) -> Result<DashboardOverview, DashboardError> {
+            unimplemented!()
+        }
+
+        async fn get_component_detail(
+            &self,
+            cycle_id: CycleId,
+            component_type: ComponentType,
+            _user_id: &UserId,
+        ) -> Result<ComponentDetailView, DashboardError> {
+            if self.should_unauthorized {
+                return Err(DashboardError::Unauthorized);
+            }
+            let output = self
+                .outputs
+                .get(&component_type)
+                .cloned()
+                .ok_or(DashboardError::ComponentNotFound(component_type))?;
+            Ok(ComponentDetailView {
+                component_id: ComponentId::new(),
+                cycle_id,
+                component_type,
+                status: ComponentStatus::Complete,
+                structured_output: output,
+                conversation_message_count: 0,
+                last_message_at: None,
+                can_branch: false,
+                can_revise: false,
+                previous_component: None,
+                next_component: None,
+                ai_cost: None,
+                stakeholder_grid: None,
+                risk_register: None,
+            })
+        }
+
+        async fn compare_cycles(
+            &self,
+            _cycle_ids: &[CycleId],
+            _user_id: &UserId,
+        ) -> Result<CycleComparison, DashboardError> {
+            unimplemented!()
+        }
+    }
+
+    fn test_user_id() -> UserId {
+        UserId::new("test-user-123").unwrap()
+    }
+
+    fn query() -> GetWeightedComparisonQuery {
+        GetWeightedComparisonQuery {
+            cycle_id: CycleId::new(),
+            user_id: test_user_id(),
+        }
+    }
+
+    fn objectives_output() -> serde_json::Value {
+        json!({
+            "fundamental_objectives": [
+                {
+                    "id": "o1",
+                    "description": "Pay",
+                    "performance_measure": {
+                        "description": "Salary",
+                        "is_quantitative": false,
+                        "unit": null,
+                        "direction": "higher_is_better"
+                    },
+                    "affected_party_id": null,
+                    "weight": 0.2
+                },
+                {
+                    "id": "o2",
+                    "description": "Security",
+                    "performance_measure": {
+                        "description": "Job security",
+                        "is_quantitative": false,
+                        "unit": null,
+                        "direction": "higher_is_better"
+                    },
+                    "affected_party_id": null,
+                    "weight": 0.8
+                }
+            ],
+            "means_objectives": []
+        })
+    }
+
+    fn consequences_output() -> serde_json::Value {
+        let cell = |rating: &str| json!({
+            "rating": rating,
+            "explanation": "",
+            "quant_value": null,
+            "quant_unit": null,
+            "source": null,
+            "uncertainty": null
+        });
+        json!({
+            "table": {
+                "alternative_ids": ["a1", "a2"],
+                "objective_ids": ["o1", "o2"],
+                "cells": {
+                    "a1": { "o1": cell("MuchBetter"), "o2": cell("MuchWorse") },
+                    "a2": { "o1": cell("Same"), "o2": cell("Better") }
+                }
+            },
+            "uncertainties": []
+        })
+    }
+
+    // ─────────────────────────────────────────────────────────────────────
+    // Tests
+    // ─────────────────────────────────────────────────────────────────────
+
+    #[tokio::test]
+    async fn test_scores_alternatives_with_selected_method() {
+        let reader = Arc::new(MockDashboardReader::with_outputs(vec![
+            (ComponentType::Objectives, objectives_output()),
+            (ComponentType::Consequences, consequences_output()),
+            (
+                ComponentType::Tradeoffs,
+                json!({
+                    "dominated_alternatives": [],
+                    "irrelevant_objectives": [],
+                    "tensions": [],
+                    "scoring_method": "weighted_additive"
+                }),
+            ),
+        ]));
+        let handler = GetWeightedComparisonHandler::new(reader);
+
+        let view = handler.handle(query()).await.unwrap();
+
+        assert_eq!(view.scoring_method, "weighted_additive");
+        assert_eq!(view.best_alternative_id.as_deref(), Some("a2"));
+        assert!((view.alternatives[0].score - 70.0).abs() < 1e-9);
+    }
+
+    #[tokio::test]
+    async fn test_defaults_to_pugh_without_tradeoffs() {
+        let reader = Arc::new(MockDashboardReader::with_outputs(vec![
+            (ComponentType::Objectives, objectives_output()),
+            (ComponentType::Consequences, consequences_output()),
+        ]));
+        let handler = GetWeightedComparisonHandler::new(reader);
+
+        let view = handler.handle(query()).await.unwrap();
+
+        assert_eq!(view.scoring_method, "pugh");
+        assert_eq!(view.alternatives.len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_requires_consequences() {
+        let reader = Arc::new(MockDashboardReader::with_outputs(vec![(
+            ComponentType::Objectives,
+            objectives_output(),
+        )]));
+        let handler = GetWeightedComparisonHandler::new(reader);
+
+        let result = handler.handle(query()).await;
+
+        assert!(matches!(
+            result,
+            Err(DashboardError::ComponentNotFound(ComponentType::Consequences))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_rejects_malformed_output() {
+        let reader = Arc::new(MockDashboardReader::with_outputs(vec![
+            (ComponentType::Objectives, json!({ "fundamental_objectives": "nope" })),
+            (ComponentType::Consequences, consequences_output()),
+        ]));
+        let handler = GetWeightedComparisonHandler::new(reader);
+
+        let result = handler.handle(query()).await;
+
+        assert!(matches!(result, Err(DashboardError::Database(_))));
+    }
+
+    #[tokio::test]
+    async fn test_handles_unauthorized() {
+        let reader = Arc::new(MockDashboardReader::unauthorized());
+        let handler = GetWeightedComparisonHandler::new(reader);
+
+        let result = handler.handle(query()).await;
+
+        assert!(matches!(result, Err(DashboardError::Unauthorized)));
+    }
+}
@@ -5,6 +5,9 @@
 mod compare_cycles;
 mod get_component_detail;
 mod get_dashboard_overview;
+mod get_decision_trees;
+mod get_group_comparison;
+mod get_weighted_comparison;
 
 pub use compare_cycles::{CompareCyclesHandler, CompareCyclesQuery, CompareCyclesResult};
 pub use get_component_detail::{
@@ -13,3 +16,38 @@ pub use get_component_detail::{
 pub use get_dashboard_overview::{
     GetDashboardOverviewHandler, GetDashboardOverviewQuery, GetDashboardOverviewResult,
 };
+pub use get_decision_trees::{
+    GetDecisionTreesHandler, GetDecisionTreesQuery, GetDecisionTreesResult,
+};
+pub use get_group_comparison::{
+    GetGroupComparisonHandler, GetGroupComparisonQuery, GetGroupComparisonResult,
+};
+pub use get_weighted_comparison::{
+    GetWeightedComparisonHandler, GetWeightedComparisonQuery, GetWeightedComparisonResult,
+};
+
+use serde::de::DeserializeOwned;
+
+use crate::domain::foundation::{ComponentType, CycleId, UserId};
+use crate::ports::{DashboardError, DashboardReader};
+
+/// Reads and parses a component's output; None if the component is
+/// missing.
+async fn component_output<T: DeserializeOwned>(
+    reader: &dyn DashboardReader,
+    cycle_id: CycleId,
+    user_id: &UserId,
+    component_type: ComponentType,
+) -> Result<Option<T>, DashboardError> {
+    let detail = match reader
+        .get_component_detail(cycle_id, component_type, user_id)
+        .await
+    {
+        Ok(detail) => detail,
+        Err(DashboardError::ComponentNotFound(_)) => return Ok(None),
+        Err(e) => return Err(e),
+    };
+    serde_json::from_value(detail.structured_output)
+        .map(Some)
+        .map_err(|e| DashboardError::Database(format!("Invalid {:?} output: {}", component_type, e)))
+}
@@ -0,0 +1,11 @@
+//! Demo data handlers.
+//!
+//! ## Commands
+//! - Seed a user's account with sample sessions, cycles, and conversations
+
+mod seed_demo_data;
+
+pub use seed_demo_data::{
+    SeedDemoDataCommand, SeedDemoDataError, SeedDemoDataHandler, SeedDemoDataResult,
+    DEMO_SCENARIO_TITLES,
+};
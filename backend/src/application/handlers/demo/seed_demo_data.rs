@@ -0,0 +1,1034 @@
+//! SeedDemoDataHandler - Command handler for generating sample data.
+//!
+//! Fills a user's account with a few realistic decisions so sales demos and
+//! frontend work don't require walking through all eleven components by hand.
+//! Each scenario becomes one session with one cycle, stopped at a different
+//! stage: one finished cycle, one midway through Consequences, and one still
+//! gathering Objectives. Every started component gets filled-in output and a
+//! short conversation transcript.
+//!
+//! Cycles and conversations are saved directly rather than through the
+//! per-step command handlers, so the session's cycle list is kept here
+//! instead of by `SessionCycleTracker`. `component.completed` events are
+//! still published for Consequences, Tradeoffs, and DecisionQuality so the
+//! analysis handlers compute Pugh, tradeoff, and DQ results as they would
+//! for a real cycle.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use crate::application::handlers::audit::CommandAuditor;
+use crate::application::handlers::cycle::ComponentCompletedEvent;
+use crate::domain::audit::{AuditRecord, AuditSummary};
+use crate::domain::conversation::{Conversation, Message};
+use crate::domain::cycle::Cycle;
+use crate::domain::foundation::{
+    CommandMetadata, ComponentId, ComponentType, ConversationId, CycleId, DomainError, EventId,
+    Percentage, Rating, SerializableDomainEvent, SessionId, Timestamp, UserId,
+};
+use crate::domain::proact::{
+    Alternative, AlternativesOutput, Cell, ComponentSequence, ConsequencesOutput,
+    ConsequencesTable, DQElement, DecisionQualityOutput, DominatedAlternative,
+    FundamentalObjective, ImpactLevel, IssueRaisingOutput, NotesNextStepsOutput,
+    ObjectivesOutput, PerformanceMeasure, PlannedAction, ProblemFrameOutput,
+    RecommendationOutput, RegisteredUncertainty, Stakeholder, StakeholderAnalysisOutput,
+    StakeholderLevel, Tension, TradeoffsOutput, Uncertainty, UncertaintyRegisterOutput,
+    DQ_ELEMENT_NAMES,
+};
+use crate::domain::session::Session;
+use crate::ports::{ConversationRepository, CycleRepository, EventPublisher, SessionRepository};
+
+/// Components whose completion triggers an analysis.
+const ANALYZED_COMPONENTS: [ComponentType; 3] = [
+    ComponentType::Consequences,
+    ComponentType::Tradeoffs,
+    ComponentType::DecisionQuality,
+];
+
+/// Session titles created by the seeder, used to detect an earlier run.
+pub const DEMO_SCENARIO_TITLES: [&str; 3] = [
+    "Job offer: stay or go?",
+    "Where should we live next year?",
+    "Open a second bakery location?",
+];
+
+/// Command to seed a user's account with demo data.
+#[derive(Debug, Clone)]
+pub struct SeedDemoDataCommand {
+    /// The user who will own the sample sessions.
+    pub user_id: UserId,
+}
+
+/// Result of seeding demo data.
+#[derive(Debug, Clone)]
+pub struct SeedDemoDataResult {
+    /// Created sessions, one per scenario.
+    pub session_ids: Vec<SessionId>,
+    /// Created cycles, in the same order as the sessions.
+    pub cycle_ids: Vec<CycleId>,
+    /// Number of component conversations created.
+    pub conversations: usize,
+}
+
+/// Error type for seeding demo data.
+#[derive(Debug, Clone)]
+pub enum SeedDemoDataError {
+    /// The user already has demo sessions.
+    AlreadySeeded(UserId),
+    /// Domain error.
+    Domain(DomainError),
+}
+
+impl std::fmt::Display for SeedDemoDataError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SeedDemoDataError::AlreadySeeded(user_id) => {
+                write!(f, "Demo data already exists for user: {}", user_id)
+            }
+            SeedDemoDataError::Domain(err) => write!(f, "{}", err),
+        }
+    }
+}
+
+impl std::error::Error for SeedDemoDataError {}
+
+impl From<DomainError> for SeedDemoDataError {
+    fn from(err: DomainError) -> Self {
+        SeedDemoDataError::Domain(err)
+    }
+}
+
+/// Handler for seeding demo data.
+pub struct SeedDemoDataHandler {
+    session_repository: Arc<dyn SessionRepository>,
+    cycle_repository: Arc<dyn CycleRepository>,
+    conversation_repository: Arc<dyn ConversationRepository>,
+    event_publisher: Arc<dyn EventPublisher>,
+    auditor: CommandAuditor,
+}
+
+impl SeedDemoDataHandler {
+    pub fn new(
+        session_repository: Arc<dyn SessionRepository>,
+        cycle_repository: Arc<dyn CycleRepository>,
+        conversation_repository: Arc<dyn ConversationRepository>,
+        event_publisher: Arc<dyn EventPublisher>,
+    ) -> Self {
+        Self {
+            session_repository,
+            cycle_repository,
+            conversation_repository,
+            event_publisher,
+            auditor: CommandAuditor::disabled(),
+        }
+    }
+
+    /// Records each command in the audit log.
+    pub fn with_auditor(mut self, auditor: CommandAuditor) -> Self {
+        self.auditor = auditor;
+        self
+    }
+
+    #[tracing::instrument(name = "SeedDemoDataHandler::handle", skip_all)]
+    pub async fn handle(
+        &self,
+        cmd: SeedDemoDataCommand,
+        metadata: CommandMetadata,
+    ) -> Result<SeedDemoDataResult, SeedDemoDataError> {
+        // 1. Refuse to seed twice
+        let existing = self
+            .session_repository
+            .find_by_user_id(&cmd.user_id)
+            .await?;
+        if existing
+            .iter()
+            .any(|session| DEMO_SCENARIO_TITLES.contains(&session.title()))
+        {
+            return Err(SeedDemoDataError::AlreadySeeded(cmd.user_id));
+        }
+
+        let mut result = SeedDemoDataResult {
+            session_ids: Vec::new(),
+            cycle_ids: Vec::new(),
+            conversations: 0,
+        };
+
+        for scenario in scenarios() {
+            // 2. Session
+            let mut session = Session::new(
+                SessionId::new(),
+                cmd.user_id.clone(),
+                scenario.title.to_string(),
+            )?;
+            session.update_description(Some(scenario.situation.to_string()))?;
+            self.session_repository.save(&session).await?;
+
+            // 3. Cycle, filled in up to the scenario's stopping point
+            let mut cycle = Cycle::new(*session.id());
+            let mut completed = Vec::new();
+            for ct in ComponentSequence::all() {
+                cycle.start_component(*ct)?;
+                cycle.update_component_output(*ct, scenario.output(*ct))?;
+                if scenario.stop_at == Some(*ct) {
+                    break;
+                }
+                cycle.complete_component(*ct)?;
+                completed.push(*ct);
+            }
+            if scenario.stop_at.is_none() {
+                cycle.complete()?;
+            }
+            self.cycle_repository.save(&cycle).await?;
+
+            session.add_cycle(cycle.id())?;
+            self.session_repository.update(&session).await?;
+
+            // 4. A conversation for every started component
+            for ct in ComponentSequence::all() {
+                let Some(component) = cycle.component(*ct) else {
+                    continue;
+                };
+                if !cycle.component_status(*ct).is_started() {
+                    continue;
+                }
+                let conversation =
+                    scenario.conversation(component.id(), *ct, completed.contains(ct))?;
+                self.conversation_repository.save(&conversation).await?;
+                result.conversations += 1;
+            }
+
+            // 5. Let the analysis handlers compute results
+            for ct in completed
+                .iter()
+                .filter(|ct| ANALYZED_COMPONENTS.contains(ct))
+            {
+                let event = ComponentCompletedEvent {
+                    event_id: EventId::new(),
+                    cycle_id: cycle.id(),
+                    component_type: *ct,
+                    completed_at: Timestamp::now(),
+                };
+                let envelope = event
+                    .to_envelope()
+                    .with_correlation_id(metadata.correlation_id())
+                    .with_user_id(cmd.user_id.to_string());
+                self.event_publisher.publish(envelope).await?;
+            }
+
+            self.auditor
+                .record(
+                    AuditRecord::new(&metadata, "SeedDemoData", "session", session.id())
+                        .with_after(session.audit_summary()),
+                )
+                .await;
+
+            result.session_ids.push(*session.id());
+            result.cycle_ids.push(cycle.id());
+        }
+
+        tracing::info!(
+            user_id = %cmd.user_id,
+            sessions = result.session_ids.len(),
+            conversations = result.conversations,
+            "Seeded demo data"
+        );
+        Ok(result)
+    }
+}
+
+// ════════════════════════════════════════════════════════════════════════════
+// Scenarios
+// ════════════════════════════════════════════════════════════════════════════
+
+/// One sample decision.
+struct DemoScenario {
+    title: &'static str,
+    situation: &'static str,
+    /// Completes "I need to decide ...".
+    decision: &'static str,
+    aim: &'static str,
+    /// (id, description, measure, unit, maximize)
+    objectives: &'static [(
+        &'static str,
+        &'static str,
+        &'static str,
+        Option<&'static str>,
+        bool,
+    )],
+    /// (id, name, description); the first is the status quo.
+    alternatives: &'static [(&'static str, &'static str, &'static str)],
+    /// Ratings against the status quo, one row per alternative after it.
+    ratings: &'static [&'static [i8]],
+    uncertainties: &'static [&'static str],
+    recommended: &'static str,
+    dq_scores: [u8; 7],
+    next_step: &'static str,
+    /// Component left in progress; `None` finishes the whole cycle.
+    stop_at: Option<ComponentType>,
+}
+
+fn scenarios() -> [DemoScenario; 3] {
+    [
+        DemoScenario {
+            title: DEMO_SCENARIO_TITLES[0],
+            situation: "I've been at Acme for four years and just got an offer from Northwind \
+                        for a team lead role. The pay is better but the commute is longer.",
+            decision: "whether to accept the Northwind offer",
+            aim: "A career that keeps me growing without burning out",
+            objectives: &[
+                (
+                    "o1",
+                    "Maximize total compensation",
+                    "Annual salary plus bonus",
+                    Some("USD/year"),
+                    true,
+                ),
+                (
+                    "o2",
+                    "Minimize commute time",
+                    "Door-to-door commute",
+                    Some("minutes/day"),
+                    false,
+                ),
+                (
+                    "o3",
+                    "Grow technical leadership skills",
+                    "Scope of people and projects led",
+                    None,
+                    true,
+                ),
+            ],
+            alternatives: &[
+                ("a1", "Stay at Acme", "Keep my current role and team"),
+                (
+                    "a2",
+                    "Accept the Northwind offer",
+                    "Team lead with five reports",
+                ),
+                (
+                    "a3",
+                    "Negotiate a promotion at Acme",
+                    "Use the offer to ask for a lead role",
+                ),
+            ],
+            ratings: &[&[2, -1, 2], &[1, 0, 1]],
+            uncertainties: &["Whether Acme would match the offer"],
+            recommended: "a2",
+            dq_scores: [85, 80, 70, 65, 75, 80, 90],
+            next_step: "Accept the Northwind offer and give Acme three weeks' notice",
+            stop_at: None,
+        },
+        DemoScenario {
+            title: DEMO_SCENARIO_TITLES[1],
+            situation: "Our lease ends in the summer and our oldest starts school next year. \
+                        We can't decide whether to keep renting, buy, or move closer to family.",
+            decision: "where our family will live from next summer",
+            aim: "A home that works for the whole family for the next five years",
+            objectives: &[
+                (
+                    "o1",
+                    "Minimize monthly housing cost",
+                    "Rent or mortgage plus fees",
+                    Some("USD/month"),
+                    false,
+                ),
+                (
+                    "o2",
+                    "Maximize school quality",
+                    "District rating",
+                    Some("rating/10"),
+                    true,
+                ),
+                (
+                    "o3",
+                    "Stay close to family",
+                    "Drive to the grandparents",
+                    Some("minutes"),
+                    false,
+                ),
+            ],
+            alternatives: &[
+                ("a1", "Keep renting downtown", "Renew the current lease"),
+                (
+                    "a2",
+                    "Buy in Riverside",
+                    "Three-bedroom house near the new school",
+                ),
+                (
+                    "a3",
+                    "Rent near the grandparents",
+                    "Move to the suburbs an hour out",
+                ),
+            ],
+            ratings: &[&[-1, 2, 0], &[1, 1, 2]],
+            uncertainties: &["Where mortgage rates go this year"],
+            recommended: "a3",
+            dq_scores: [80, 75, 70, 60, 65, 70, 70],
+            next_step: "Tour rentals near the grandparents this month",
+            stop_at: Some(ComponentType::Consequences),
+        },
+        DemoScenario {
+            title: DEMO_SCENARIO_TITLES[2],
+            situation: "The bakery is turning customers away on weekends. A storefront across \
+                        town just came up for lease and the landlord wants an answer soon.",
+            decision: "whether to open a second bakery location",
+            aim: "A profitable business I still enjoy running",
+            objectives: &[
+                (
+                    "o1",
+                    "Grow annual profit",
+                    "Net profit",
+                    Some("USD/year"),
+                    true,
+                ),
+                (
+                    "o2",
+                    "Protect work-life balance",
+                    "Hours worked per week",
+                    Some("hours/week"),
+                    false,
+                ),
+            ],
+            alternatives: &[
+                (
+                    "a1",
+                    "Stay with one location",
+                    "Raise prices and extend weekend hours",
+                ),
+                (
+                    "a2",
+                    "Open the second location",
+                    "Sign the lease across town",
+                ),
+                (
+                    "a3",
+                    "Add wholesale instead",
+                    "Supply local cafes from the current kitchen",
+                ),
+            ],
+            ratings: &[&[2, -2], &[1, -1]],
+            uncertainties: &["Whether weekend demand carries over to a new neighborhood"],
+            recommended: "a3",
+            dq_scores: [70, 65, 60, 55, 60, 60, 65],
+            next_step: "Ask three cafes whether they'd buy wholesale",
+            stop_at: Some(ComponentType::Objectives),
+        },
+    ]
+}
+
+impl DemoScenario {
+    fn rating(&self, alternative: usize, objective: usize) -> Rating {
+        match alternative {
+            0 => Rating::Same,
+            i => Rating::try_from_i8(self.ratings[i - 1][objective]).unwrap_or_default(),
+        }
+    }
+
+    fn output(&self, ct: ComponentType) -> serde_json::Value {
+        let output = match ct {
+            ComponentType::IssueRaising => serde_json::to_value(IssueRaisingOutput {
+                potential_decisions: vec![format!("Decide {}", self.decision)],
+                objectives: self.objectives.iter().map(|o| o.1.to_string()).collect(),
+                uncertainties: self.uncertainties.iter().map(|u| u.to_string()).collect(),
+                considerations: Vec::new(),
+                user_confirmed: true,
+            }),
+            ComponentType::ProblemFrame => serde_json::to_value(ProblemFrameOutput {
+                decision_maker: Some("Me".to_string()),
+                focal_decision: Some(format!("Decide {}", self.decision)),
+                ultimate_aim: Some(self.aim.to_string()),
+                decision_statement: Some(format!("I need to decide {}.", self.decision)),
+                ..Default::default()
+            }),
+            ComponentType::StakeholderAnalysis => {
+                serde_json::to_value(StakeholderAnalysisOutput {
+                    stakeholders: vec![Stakeholder {
+                        id: "family".to_string(),
+                        name: "My family".to_string(),
+                        role: "Lives with the outcome".to_string(),
+                        power: StakeholderLevel::Medium,
+                        interest: StakeholderLevel::High,
+                        concerns: vec![self.aim.to_string()],
+                    }],
+                    engagement_plan: Vec::new(),
+                })
+            }
+            ComponentType::Objectives => serde_json::to_value(ObjectivesOutput {
+                fundamental_objectives: self
+                    .objectives
+                    .iter()
+                    .map(
+                        |(id, description, measure, unit, maximize)| FundamentalObjective {
+                            id: id.to_string(),
+                            description: description.to_string(),
+                            performance_measure: PerformanceMeasure {
+                                description: measure.to_string(),
+                                is_quantitative: unit.is_some(),
+                                unit: unit.map(str::to_string),
+                                direction: if *maximize { "maximize" } else { "minimize" }
+                                    .to_string(),
+                                value_function: None,
+                            },
+                            affected_party_id: None,
+                            weight: None,
+                        },
+                    )
+                    .collect(),
+                means_objectives: Vec::new(),
+            }),
+            ComponentType::Alternatives => serde_json::to_value(AlternativesOutput {
+                options: self
+                    .alternatives
+                    .iter()
+                    .enumerate()
+                    .map(|(i, (id, name, description))| Alternative {
+                        id: id.to_string(),
+                        name: name.to_string(),
+                        description: description.to_string(),
+                        assumptions: Vec::new(),
+                        is_status_quo: i == 0,
+                    })
+                    .collect(),
+                strategy_table: None,
+                has_status_quo: true,
+            }),
+            ComponentType::Consequences => serde_json::to_value(self.consequences()),
+            ComponentType::UncertaintyRegister => {
+                serde_json::to_value(UncertaintyRegisterOutput {
+                    uncertainties: self
+                        .uncertainties
+                        .iter()
+                        .enumerate()
+                        .map(|(i, description)| RegisteredUncertainty {
+                            id: format!("r{}", i + 1),
+                            description: description.to_string(),
+                            consequence_uncertainty_id: Some(format!("u{}", i + 1)),
+                            affected_alternative_ids: Vec::new(),
+                            probability: None,
+                            probability_basis: None,
+                            impact: ImpactLevel::Medium,
+                            mitigations: Vec::new(),
+                        })
+                        .collect(),
+                })
+            }
+            ComponentType::Tradeoffs => serde_json::to_value(self.tradeoffs()),
+            ComponentType::Recommendation => serde_json::to_value(RecommendationOutput {
+                standout_option: Some(self.recommended.to_string()),
+                synthesis: format!(
+                    "{} does best on what matters most to you.",
+                    self.alternative_name(self.recommended)
+                ),
+                caveats: self.uncertainties.iter().map(|u| u.to_string()).collect(),
+                additional_info: Vec::new(),
+            }),
+            ComponentType::DecisionQuality => serde_json::to_value(DecisionQualityOutput {
+                elements: DQ_ELEMENT_NAMES
+                    .iter()
+                    .zip(self.dq_scores)
+                    .map(|(name, score)| DQElement {
+                        name: name.to_string(),
+                        score: Percentage::new(score),
+                        rationale: String::new(),
+                        improvement: String::new(),
+                    })
+                    .collect(),
+                overall_score: Percentage::new(self.dq_scores.into_iter().min().unwrap_or(0)),
+                improvement_paths: Vec::new(),
+            }),
+            ComponentType::NotesNextSteps => serde_json::to_value(NotesNextStepsOutput {
+                remaining_uncertainties: self.uncertainties.iter().map(|u| u.to_string()).collect(),
+                planned_actions: vec![PlannedAction {
+                    description: self.next_step.to_string(),
+                    due_date: None,
+                    owner: Some("Me".to_string()),
+                }],
+                ..Default::default()
+            }),
+        };
+        output.expect("demo outputs serialize")
+    }
+
+    fn consequences(&self) -> ConsequencesOutput {
+        let mut cells: HashMap<String, HashMap<String, Cell>> = HashMap::new();
+        for (a, (alternative_id, _, _)) in self.alternatives.iter().enumerate() {
+            for (o, (objective_id, ..)) in self.objectives.iter().enumerate() {
+                let rating = self.rating(a, o);
+                cells.entry(alternative_id.to_string()).or_default().insert(
+                    objective_id.to_string(),
+                    Cell {
+                        rating,
+                        explanation: format!("{} than staying put", rating.label()),
+                        quant_value: None,
+                        quant_unit: None,
+                        source: None,
+                        uncertainty: None,
+                    },
+                );
+            }
+        }
+
+        ConsequencesOutput {
+            table: ConsequencesTable {
+                alternative_ids: self.alternatives.iter().map(|a| a.0.to_string()).collect(),
+                objective_ids: self.objectives.iter().map(|o| o.0.to_string()).collect(),
+                cells,
+            },
+            uncertainties: self
+                .uncertainties
+                .iter()
+                .enumerate()
+                .map(|(i, description)| Uncertainty {
+                    id: format!("u{}", i + 1),
+                    description: description.to_string(),
+                    driver: "Outside our control".to_string(),
+                    worth_resolving: true,
+                    resolvable: false,
+                    outcomes: vec![],
+                    elicitation: None,
+                })
+                .collect(),
+        }
+    }
+
+    fn tradeoffs(&self) -> TradeoffsOutput {
+        let objectives = 0..self.objectives.len();
+        let mut output = TradeoffsOutput::default();
+
+        for a in 0..self.alternatives.len() {
+            // Dominated: no better anywhere and worse somewhere than another option
+            let dominated_by = (0..self.alternatives.len()).find(|&b| {
+                b != a
+                    && objectives
+                        .clone()
+                        .all(|o| self.rating(b, o) >= self.rating(a, o))
+                    && objectives
+                        .clone()
+                        .any(|o| self.rating(b, o) > self.rating(a, o))
+            });
+            if let Some(b) = dominated_by {
+                output.dominated_alternatives.push(DominatedAlternative {
+                    alternative_id: self.alternatives[a].0.to_string(),
+                    dominated_by_id: self.alternatives[b].0.to_string(),
+                    explanation: format!(
+                        "{} is at least as good on every objective",
+                        self.alternatives[b].1
+                    ),
+                });
+                continue;
+            }
+
+            let descriptions = |keep: fn(Rating) -> bool| -> Vec<String> {
+                objectives
+                    .clone()
+                    .filter(|&o| keep(self.rating(a, o)))
+                    .map(|o| self.objectives[o].1.to_string())
+                    .collect()
+            };
+            let (gains, losses) = (
+                descriptions(|r| r.is_positive()),
+                descriptions(|r| r.is_negative()),
+            );
+            if !gains.is_empty() && !losses.is_empty() {
+                output.tensions.push(Tension {
+                    alternative_id: self.alternatives[a].0.to_string(),
+                    gains,
+                    losses,
+                    uncertainty_impact: self.uncertainties.first().map(|u| u.to_string()),
+                });
+            }
+        }
+        output
+    }
+
+    fn alternative_name(&self, id: &str) -> &'static str {
+        self.alternatives
+            .iter()
+            .find(|a| a.0 == id)
+            .map(|a| a.1)
+            .unwrap_or("The standout option")
+    }
+
+    /// A short exchange about the component, left open unless `completed`.
+    fn conversation(
+        &self,
+        component_id: ComponentId,
+        ct: ComponentType,
+        completed: bool,
+    ) -> Result<Conversation, DomainError> {
+        let join = |items: Vec<&str>| items.join("; ");
+        let (question, answer) = match ct {
+            ComponentType::IssueRaising => (
+                "What's on your mind? Tell me about the situation you're facing.".to_string(),
+                self.situation.to_string(),
+            ),
+            ComponentType::ProblemFrame => (
+                "Let's pin down exactly what you're deciding.".to_string(),
+                format!("I need to decide {}.", self.decision),
+            ),
+            ComponentType::StakeholderAnalysis => (
+                "Who else does this decision affect, and who has a say in it?".to_string(),
+                "Mainly my family. They'll live with whatever I choose.".to_string(),
+            ),
+            ComponentType::Objectives => (
+                "What matters most to you in how this turns out?".to_string(),
+                join(self.objectives.iter().map(|o| o.1).collect()),
+            ),
+            ComponentType::Alternatives => (
+                "What options are you considering, including changing nothing?".to_string(),
+                join(self.alternatives.iter().map(|a| a.1).collect()),
+            ),
+            ComponentType::Consequences => (
+                "How does each option do on what matters to you?".to_string(),
+                "I've rated each option against staying put.".to_string(),
+            ),
+            ComponentType::UncertaintyRegister => (
+                "Which of these uncertainties could change your choice?".to_string(),
+                join(self.uncertainties.to_vec()),
+            ),
+            ComponentType::Tradeoffs => (
+                "Let's look at what you gain and give up with each option.".to_string(),
+                "Seeing the tradeoffs side by side helps.".to_string(),
+            ),
+            ComponentType::Recommendation => (
+                format!(
+                    "Based on your analysis, {} stands out. Does that fit your thinking?",
+                    self.alternative_name(self.recommended)
+                ),
+                "Yes, that matches my gut.".to_string(),
+            ),
+            ComponentType::DecisionQuality => (
+                "How would you rate the quality of this decision?".to_string(),
+                "Fairly confident. The consequence information is the weakest part.".to_string(),
+            ),
+            ComponentType::NotesNextSteps => (
+                "What are your next steps?".to_string(),
+                self.next_step.to_string(),
+            ),
+        };
+
+        let mut conversation = Conversation::new(ConversationId::new(), component_id);
+        conversation.mark_ready()?;
+        conversation.add_message(Message::assistant(question)?)?;
+        conversation.start()?;
+        conversation.add_message(Message::user(answer)?)?;
+        conversation.add_message(Message::assistant("Thanks, I've captured that.")?)?;
+        if completed {
+            conversation.complete()?;
+        }
+        Ok(conversation)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::foundation::{ComponentStatus, CycleStatus, EventEnvelope};
+    use async_trait::async_trait;
+    use std::sync::Mutex;
+
+    // ─────────────────────────────────────────────────────────────────────
+    // Mock implementations
+    // ─────────────────────────────────────────────────────────────────────
+
+    #[derive(Default)]
+    struct MockSessionRepository {
+        sessions: Mutex<Vec<Session>>,
+    }
+
+    #[async_trait]
+    impl SessionRepository for MockSessionRepository {
+        async fn save(&self, session: &Session) -> Result<(), DomainError> {
+            self.sessions.lock().unwrap().push(session.clone());
+            Ok(())
+        }
+
+        async fn update(&self, session: &Session) -> Result<(), DomainError> {
+            let mut sessions = self.sessions.lock().unwrap();
+            if let Some(pos) = sessions.iter().position(|s| s.id() == session.id()) {
+                sessions[pos] = session.clone();
+            }
+            Ok(())
+        }
+
+        async fn find_by_id(&self, id: &SessionId) -> Result<Option<Session>, DomainError> {
+            Ok(self
+                .sessions
+                .lock()
+                .unwrap()
+                .iter()
+                .find(|s| s.id() == id)
+                .cloned())
+        }
+
+        async fn exists(&self, id: &SessionId) -> Result<bool, DomainError> {
+            Ok(self.sessions.lock().unwrap().iter().any(|s| s.id() == id))
+        }
+
+        async fn find_by_user_id(&self, user_id: &UserId) -> Result<Vec<Session>, DomainError> {
+            Ok(self
+                .sessions
+                .lock()
+                .unwrap()
+                .iter()
+                .filter(|s| s.user_id() == user_id)
+                .cloned()
+                .collect())
+        }
+
+        async fn count_active_by_user(&self, _user_id: &UserId) -> Result<u32, DomainError> {
+            Ok(0)
+        }
+
+        async fn delete(&self, _id: &SessionId) -> Result<(), DomainError> {
+            Ok(())
+        }
+    }
+
+    #[derive(Default)]
+    struct MockCycleRepository {
+        cycles: Mutex<Vec<Cycle>>,
+    }
+
+    #[async_trait]
+    impl CycleRepository for MockCycleRepository {
+        async fn save(&self, cycle: &Cycle) -> Result<(), DomainError> {
+            self.cycles.lock().unwrap().push(cycle.clone());
+            Ok(())
+        }
+
+        async fn update(&self, _cycle: &Cycle) -> Result<(), DomainError> {
+            Ok(())
+        }
+
+        async fn find_by_id(&self, id: &CycleId) -> Result<Option<Cycle>, DomainError> {
+            Ok(self
+                .cycles
+                .lock()
+                .unwrap()
+                .iter()
+                .find(|c| c.id() == *id)
+                .cloned())
+        }
+
+        async fn exists(&self, id: &CycleId) -> Result<bool, DomainError> {
+            Ok(self.cycles.lock().unwrap().iter().any(|c| c.id() == *id))
+        }
+
+        async fn find_by_session_id(
+            &self,
+            _session_id: &SessionId,
+        ) -> Result<Vec<Cycle>, DomainError> {
+            Ok(vec![])
+        }
+
+        async fn find_primary_by_session_id(
+            &self,
+            _session_id: &SessionId,
+        ) -> Result<Option<Cycle>, DomainError> {
+            Ok(None)
+        }
+
+        async fn find_branches(&self, _parent_id: &CycleId) -> Result<Vec<Cycle>, DomainError> {
+            Ok(vec![])
+        }
+
+        async fn count_by_session_id(&self, _session_id: &SessionId) -> Result<u32, DomainError> {
+            Ok(0)
+        }
+
+        async fn delete(&self, _id: &CycleId) -> Result<(), DomainError> {
+            Ok(())
+        }
+    }
+
+    #[derive(Default)]
+    struct MockConversationRepository {
+        conversations: Mutex<Vec<Conversation>>,
+    }
+
+    #[async_trait]
+    impl ConversationRepository for MockConversationRepository {
+        async fn save(&self, conversation: &Conversation) -> Result<(), DomainError> {
+            self.conversations
+                .lock()
+                .unwrap()
+                .push(conversation.clone());
+            Ok(())
+        }
+
+        async fn update(&self, _conversation: &Conversation) -> Result<(), DomainError> {
+            Ok(())
+        }
+
+        async fn add_message(
+            &self,
+            _conversation_id: &ConversationId,
+            _message: &Message,
+        ) -> Result<(), DomainError> {
+            Ok(())
+        }
+
+        async fn find_by_id(
+            &self,
+            _id: &ConversationId,
+        ) -> Result<Option<Conversation>, DomainError> {
+            Ok(None)
+        }
+
+        async fn find_by_component(
+            &self,
+            _component_id: &ComponentId,
+        ) -> Result<Option<Conversation>, DomainError> {
+            Ok(None)
+        }
+
+        async fn exists_for_component(
+            &self,
+            _component_id: &ComponentId,
+        ) -> Result<bool, DomainError> {
+            Ok(false)
+        }
+
+        async fn delete(&self, _id: &ConversationId) -> Result<(), DomainError> {
+            Ok(())
+        }
+    }
+
+    #[derive(Default)]
+    struct MockEventPublisher {
+        published_events: Mutex<Vec<EventEnvelope>>,
+    }
+
+    #[async_trait]
+    impl EventPublisher for MockEventPublisher {
+        async fn publish(&self, event: EventEnvelope) -> Result<(), DomainError> {
+            self.published_events.lock().unwrap().push(event);
+            Ok(())
+        }
+
+        async fn publish_all(&self, events: Vec<EventEnvelope>) -> Result<(), DomainError> {
+            self.published_events.lock().unwrap().extend(events);
+            Ok(())
+        }
+    }
+
+    // ─────────────────────────────────────────────────────────────────────
+    // Tests
+    // ─────────────────────────────────────────────────────────────────────
+
+    fn setup_repositories() -> (
+        Arc<MockSessionRepository>,
+        Arc<MockCycleRepository>,
+        Arc<MockConversationRepository>,
+        Arc<MockEventPublisher>,
+    ) {
+        (
+            Arc::new(MockSessionRepository::default()),
+            Arc::new(MockCycleRepository::default()),
+            Arc::new(MockConversationRepository::default()),
+            Arc::new(MockEventPublisher::default()),
+        )
+    }
+
+    fn create_handler(
+        sessions: Arc<MockSessionRepository>,
+        cycles: Arc<MockCycleRepository>,
+        conversations: Arc<MockConversationRepository>,
+        events: Arc<MockEventPublisher>,
+    ) -> SeedDemoDataHandler {
+        SeedDemoDataHandler::new(sessions, cycles, conversations, events)
+    }
+
+    fn user() -> UserId {
+        UserId::new("demo-user").unwrap()
+    }
+
+    async fn seed(handler: &SeedDemoDataHandler) -> Result<SeedDemoDataResult, SeedDemoDataError> {
+        handler
+            .handle(
+                SeedDemoDataCommand { user_id: user() },
+                CommandMetadata::new(user()).with_source("cli"),
+            )
+            .await
+    }
+
+    #[tokio::test]
+    async fn seeds_cycles_at_different_stages() {
+        let (sessions, cycles, conversations, events) = setup_repositories();
+        let handler = create_handler(sessions.clone(), cycles.clone(), conversations, events);
+
+        let result = seed(&handler).await.unwrap();
+
+        assert_eq!(result.session_ids.len(), 3);
+        let cycles = cycles.cycles.lock().unwrap().clone();
+        assert_eq!(cycles[0].status(), CycleStatus::Completed);
+        assert_eq!(cycles[1].current_step(), ComponentType::Consequences);
+        assert_eq!(
+            cycles[1].component_status(ComponentType::Consequences),
+            ComponentStatus::InProgress
+        );
+        assert_eq!(cycles[2].current_step(), ComponentType::Objectives);
+
+        let sessions = sessions.sessions.lock().unwrap().clone();
+        assert!(sessions
+            .iter()
+            .zip(&cycles)
+            .all(|(s, c)| s.cycle_ids() == [c.id()]));
+    }
+
+    #[tokio::test]
+    async fn every_started_component_has_a_conversation() {
+        let (sessions, cycles, conversations, events) = setup_repositories();
+        let handler = create_handler(sessions, cycles, conversations.clone(), events);
+
+        let result = seed(&handler).await.unwrap();
+
+        // 11 + 6 + 4 started components
+        assert_eq!(result.conversations, 21);
+        let conversations = conversations.conversations.lock().unwrap().clone();
+        assert_eq!(conversations.iter().filter(|c| !c.is_complete()).count(), 2);
+        assert!(conversations.iter().all(|c| c.message_count() == 3));
+    }
+
+    #[tokio::test]
+    async fn publishes_completions_that_trigger_analysis() {
+        let (sessions, cycles, conversations, events) = setup_repositories();
+        let handler = create_handler(sessions, cycles, conversations, events.clone());
+
+        seed(&handler).await.unwrap();
+
+        // Only the finished cycle got past Consequences
+        let events = events.published_events.lock().unwrap().clone();
+        assert_eq!(events.len(), 3);
+        assert!(events
+            .iter()
+            .all(|e| e.event_type == "component.completed.v1"));
+    }
+
+    #[tokio::test]
+    async fn refuses_to_seed_twice() {
+        let (sessions, cycles, conversations, events) = setup_repositories();
+        let handler = create_handler(sessions, cycles, conversations, events);
+        seed(&handler).await.unwrap();
+
+        let err = seed(&handler).await.unwrap_err();
+
+        assert!(matches!(err, SeedDemoDataError::AlreadySeeded(_)));
+    }
+
+    #[test]
+    fn tradeoffs_flag_dominated_options() {
+        let scenario = &scenarios()[0];
+
+        let tradeoffs = scenario.tradeoffs();
+
+        // Negotiating is never worse than staying and better on pay
+        assert_eq!(tradeoffs.dominated_alternatives.len(), 1);
+        assert_eq!(tradeoffs.dominated_alternatives[0].alternative_id, "a1");
+        assert_eq!(tradeoffs.dominated_alternatives[0].dominated_by_id, "a3");
+        // Only the offer trades a gain for a loss
+        assert_eq!(tradeoffs.tensions.len(), 1);
+        assert_eq!(tradeoffs.tensions[0].alternative_id, "a2");
+    }
+}
@@ -0,0 +1,484 @@
+//! IntegrationDispatcher - Event handler that runs users' integrations.
+//!
+//! For every trigger event, each of the acting user's integrations whose
+//! filter matches gets an `IntegrationDelivery`, which is rendered and
+//! handed to the adapter registered for the action's kind. Deliveries that
+//! fail transiently stay pending and are retried by
+//! [`IntegrationDispatcher::run`], which polls for due deliveries the same
+//! way `CycleDocumentMailer` does.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+
+use async_trait::async_trait;
+use tokio::sync::watch;
+use tokio::time;
+
+use crate::domain::foundation::{DomainError, ErrorCode, EventEnvelope, Timestamp, UserId};
+use crate::domain::integration::{
+    default_summary, template_context, Integration, IntegrationAction, IntegrationActionKind,
+    IntegrationDelivery, TRIGGER_EVENT_TYPES,
+};
+use crate::ports::{
+    EventHandler, EventSubscriber, IntegrationActionAdapter, IntegrationActionError,
+    IntegrationDeliveryRepository, IntegrationPayload, IntegrationRepository,
+    IntegrationTemplateEngine,
+};
+
+/// Deliveries retried per poll.
+const RETRY_BATCH_SIZE: u32 = 50;
+
+/// Runs integrations when their trigger events arrive.
+pub struct IntegrationDispatcher {
+    integrations: Arc<dyn IntegrationRepository>,
+    deliveries: Arc<dyn IntegrationDeliveryRepository>,
+    templates: Arc<dyn IntegrationTemplateEngine>,
+    adapters: HashMap<IntegrationActionKind, Arc<dyn IntegrationActionAdapter>>,
+}
+
+impl IntegrationDispatcher {
+    /// `adapters` should hold one adapter per action kind; a later adapter
+    /// for the same kind replaces an earlier one.
+    pub fn new(
+        integrations: Arc<dyn IntegrationRepository>,
+        deliveries: Arc<dyn IntegrationDeliveryRepository>,
+        templates: Arc<dyn IntegrationTemplateEngine>,
+        adapters: Vec<Arc<dyn IntegrationActionAdapter>>,
+    ) -> Self {
+        Self {
+            integrations,
+            deliveries,
+            templates,
+            adapters: adapters
+                .into_iter()
+                .map(|adapter| (adapter.kind(), adapter))
+                .collect(),
+        }
+    }
+
+    /// Register this dispatcher for every trigger event type.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let dispatcher = Arc::new(IntegrationDispatcher::new(/* ... */));
+    /// dispatcher.register(&event_bus);
+    /// ```
+    pub fn register(self: &Arc<Self>, subscriber: &impl EventSubscriber) {
+        subscriber.subscribe_all(TRIGGER_EVENT_TYPES, self.clone());
+    }
+
+    /// Retries due deliveries every `poll_interval` until shutdown is signalled.
+    pub async fn run(
+        &self,
+        poll_interval: Duration,
+        mut shutdown: watch::Receiver<bool>,
+    ) -> Result<(), DomainError> {
+        let mut interval = time::interval(poll_interval);
+
+        loop {
+            tokio::select! {
+                _ = shutdown.changed() => {
+                    if *shutdown.borrow() {
+                        return Ok(());
+                    }
+                }
+
+                _ = interval.tick() => {
+                    self.retry_due(RETRY_BATCH_SIZE).await?;
+                }
+            }
+        }
+    }
+
+    /// Attempts up to `limit` due deliveries, returning how many were sent.
+    #[tracing::instrument(name = "IntegrationDispatcher::retry_due", skip_all)]
+    pub async fn retry_due(&self, limit: u32) -> Result<usize, DomainError> {
+        let due = self.deliveries.list_due(Timestamp::now(), limit).await?;
+        let mut sent = 0;
+        for mut delivery in due {
+            let integration = self.integrations.find_by_id(&delivery.integration_id).await?;
+            self.attempt(integration.as_ref(), &mut delivery).await?;
+            if delivery.sent_at.is_some() {
+                sent += 1;
+            }
+        }
+        Ok(sent)
+    }
+
+    /// Performs the action once, recording the outcome.
+    async fn attempt(
+        &self,
+        integration: Option<&Integration>,
+        delivery: &mut IntegrationDelivery,
+    ) -> Result<(), DomainError> {
+        let result = match integration {
+            Some(integration) if integration.enabled => self.send(integration, delivery).await,
+            Some(_) => Err(IntegrationActionError::Rejected(
+                "Integration is disabled".to_string(),
+            )),
+            None => Err(IntegrationActionError::Rejected(
+                "Integration was deleted".to_string(),
+            )),
+        };
+        match result {
+            Ok(()) => delivery.record_sent(),
+            Err(error) => {
+                tracing::warn!(
+                    delivery_id = %delivery.id,
+                    integration_id = %delivery.integration_id,
+                    attempts = delivery.attempts + 1,
+                    retryable = error.is_retryable(),
+                    error = %error,
+                    "Integration delivery failed"
+                );
+                delivery.record_failure(error.to_string(), error.is_retryable());
+            }
+        }
+        self.deliveries.save(delivery).await
+    }
+
+    async fn send(
+        &self,
+        integration: &Integration,
+        delivery: &IntegrationDelivery,
+    ) -> Result<(), IntegrationActionError> {
+        let kind = integration.action.kind();
+        let adapter = self.adapters.get(&kind).ok_or_else(|| {
+            IntegrationActionError::Rejected(format!("'{}' actions are not available", kind.as_str()))
+        })?;
+        let payload = self.render(integration, delivery)?;
+        adapter.execute(integration, &payload).await
+    }
+
+    /// Renders the payload and, for email, the subject. Without a template,
+    /// HTTP actions send the context as JSON and others a one-line summary.
+    fn render(
+        &self,
+        integration: &Integration,
+        delivery: &IntegrationDelivery,
+    ) -> Result<IntegrationPayload, IntegrationActionError> {
+        let body = match (&integration.template, &integration.action) {
+            (Some(template), _) => self.templates.render(template, &delivery.context)?,
+            (None, IntegrationAction::HttpPost { .. }) => delivery.context.to_string(),
+            (None, _) => default_summary(&delivery.context),
+        };
+        let subject = match &integration.action {
+            IntegrationAction::Email { subject, .. } => {
+                Some(self.templates.render(subject, &delivery.context)?)
+            }
+            _ => None,
+        };
+        Ok(IntegrationPayload {
+            delivery_id: delivery.id,
+            event_type: delivery.event_type.clone(),
+            body,
+            subject,
+        })
+    }
+}
+
+/// The acting user, falling back to the user the event is about.
+fn event_user(event: &EventEnvelope) -> Option<String> {
+    event.metadata.user_id.clone().or_else(|| {
+        event
+            .payload
+            .get("user_id")
+            .and_then(|v| v.as_str())
+            .map(str::to_string)
+    })
+}
+
+#[async_trait]
+impl EventHandler for IntegrationDispatcher {
+    async fn handle(&self, event: EventEnvelope) -> Result<(), DomainError> {
+        let Some(user_id) = event_user(&event) else {
+            tracing::debug!(event_type = %event.event_type, "Event has no user");
+            return Ok(());
+        };
+        let user_id = UserId::new(user_id)
+            .map_err(|e| DomainError::new(ErrorCode::ValidationFailed, e.to_string()))?;
+
+        let integrations = self.integrations.list_for_user(&user_id).await?;
+        if integrations.is_empty() {
+            return Ok(());
+        }
+        let context = template_context(&event);
+        for integration in integrations
+            .iter()
+            .filter(|integration| integration.is_triggered_by(&event, &context))
+        {
+            let mut delivery = IntegrationDelivery::new(
+                integration.id,
+                user_id.clone(),
+                event.event_id.clone(),
+                event.event_type.clone(),
+                context.clone(),
+            );
+            self.deliveries.save(&delivery).await?;
+            self.attempt(Some(integration), &mut delivery).await?;
+        }
+        Ok(())
+    }
+
+    fn name(&self) -> &'static str {
+        "IntegrationDispatcher"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::sync::Mutex;
+
+    use serde_json::json;
+
+    use crate::adapters::{
+        InMemoryIntegrationDeliveryRepository, InMemoryIntegrationRepository,
+        TeraIntegrationTemplateEngine,
+    };
+    use crate::domain::document::DeliveryStatus;
+    use crate::domain::integration::{EventFilter, FieldCondition};
+
+    /// Records payloads and fails with `failure` while it is set.
+    #[derive(Default)]
+    struct RecordingAdapter {
+        executed: Mutex<Vec<IntegrationPayload>>,
+        failure: Mutex<Option<IntegrationActionError>>,
+    }
+
+    #[async_trait]
+    impl IntegrationActionAdapter for RecordingAdapter {
+        fn kind(&self) -> IntegrationActionKind {
+            IntegrationActionKind::HttpPost
+        }
+
+        async fn execute(
+            &self,
+            _integration: &Integration,
+            payload: &IntegrationPayload,
+        ) -> Result<(), IntegrationActionError> {
+            if let Some(error) = self.failure.lock().unwrap().clone() {
+                return Err(error);
+            }
+            self.executed.lock().unwrap().push(payload.clone());
+            Ok(())
+        }
+    }
+
+    fn setup_repositories() -> (
+        Arc<InMemoryIntegrationRepository>,
+        Arc<InMemoryIntegrationDeliveryRepository>,
+        Arc<RecordingAdapter>,
+    ) {
+        (
+            Arc::new(InMemoryIntegrationRepository::new()),
+            Arc::new(InMemoryIntegrationDeliveryRepository::new()),
+            Arc::new(RecordingAdapter::default()),
+        )
+    }
+
+    fn create_handler(
+        integrations: Arc<InMemoryIntegrationRepository>,
+        deliveries: Arc<InMemoryIntegrationDeliveryRepository>,
+        adapter: Arc<RecordingAdapter>,
+    ) -> IntegrationDispatcher {
+        IntegrationDispatcher::new(
+            integrations,
+            deliveries,
+            Arc::new(TeraIntegrationTemplateEngine::new()),
+            vec![adapter],
+        )
+    }
+
+    fn user() -> UserId {
+        UserId::new("user-1").unwrap()
+    }
+
+    async fn integrate(
+        integrations: &InMemoryIntegrationRepository,
+        conditions: Vec<FieldCondition>,
+        template: Option<&str>,
+    ) -> Integration {
+        let integration = Integration::new(
+            user(),
+            "Zap",
+            EventFilter {
+                event_types: vec!["component.completed.v1".to_string()],
+                conditions,
+            },
+            IntegrationAction::HttpPost {
+                url: "https://hooks.example.com/catch".to_string(),
+            },
+            template.map(str::to_string),
+        )
+        .unwrap();
+        integrations.save(&integration).await.unwrap();
+        integration
+    }
+
+    fn completed(component_type: &str, user_id: &str) -> EventEnvelope {
+        EventEnvelope::new(
+            "component.completed.v1",
+            "cycle-1",
+            "Cycle",
+            json!({ "component_type": component_type }),
+        )
+        .with_user_id(user_id)
+    }
+
+    async fn list_deliveries(
+        deliveries: &InMemoryIntegrationDeliveryRepository,
+        integration: &Integration,
+    ) -> Vec<IntegrationDelivery> {
+        deliveries
+            .list_for_integration(&integration.id, 10)
+            .await
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn renders_the_template_for_matching_events() {
+        let (integrations, deliveries, adapter) = setup_repositories();
+        let dispatcher = create_handler(integrations.clone(), deliveries.clone(), adapter.clone());
+        let integration = integrate(
+            &integrations,
+            Vec::new(),
+            Some(r#"{"done": "{{ payload.component_type }}"}"#),
+        )
+        .await;
+
+        dispatcher
+            .handle(completed("recommendation", "user-1"))
+            .await
+            .unwrap();
+
+        let executed = adapter.executed.lock().unwrap().clone();
+        assert_eq!(executed.len(), 1);
+        assert_eq!(executed[0].body, r#"{"done": "recommendation"}"#);
+        assert_eq!(executed[0].event_type, "component.completed.v1");
+        let deliveries = list_deliveries(&deliveries, &integration).await;
+        assert_eq!(deliveries[0].status, DeliveryStatus::Sent);
+        assert_eq!(executed[0].delivery_id, deliveries[0].id);
+    }
+
+    #[tokio::test]
+    async fn sends_the_context_as_json_without_a_template() {
+        let (integrations, deliveries, adapter) = setup_repositories();
+        let dispatcher = create_handler(integrations.clone(), deliveries.clone(), adapter.clone());
+        integrate(&integrations, Vec::new(), None).await;
+
+        dispatcher
+            .handle(completed("objectives", "user-1"))
+            .await
+            .unwrap();
+
+        let executed = adapter.executed.lock().unwrap().clone();
+        let body: serde_json::Value = serde_json::from_str(&executed[0].body).unwrap();
+        assert_eq!(body["event"]["type"], "component.completed.v1");
+        assert_eq!(body["payload"]["component_type"], "objectives");
+    }
+
+    #[tokio::test]
+    async fn skips_other_users_and_unmatched_conditions() {
+        let (integrations, deliveries, adapter) = setup_repositories();
+        let dispatcher = create_handler(integrations.clone(), deliveries.clone(), adapter.clone());
+        let integration = integrate(
+            &integrations,
+            vec![FieldCondition {
+                path: "payload.component_type".to_string(),
+                equals: json!("recommendation"),
+            }],
+            None,
+        )
+        .await;
+
+        dispatcher
+            .handle(completed("objectives", "user-1"))
+            .await
+            .unwrap();
+        dispatcher
+            .handle(completed("recommendation", "user-2"))
+            .await
+            .unwrap();
+
+        assert!(adapter.executed.lock().unwrap().is_empty());
+        assert!(list_deliveries(&deliveries, &integration).await.is_empty());
+    }
+
+    #[tokio::test]
+    async fn retries_transient_failures() {
+        let (integrations, deliveries, adapter) = setup_repositories();
+        let dispatcher = create_handler(integrations.clone(), deliveries.clone(), adapter.clone());
+        let integration = integrate(&integrations, Vec::new(), None).await;
+        *adapter.failure.lock().unwrap() =
+            Some(IntegrationActionError::Unavailable("503".to_string()));
+
+        dispatcher
+            .handle(completed("objectives", "user-1"))
+            .await
+            .unwrap();
+
+        let mut delivery = list_deliveries(&deliveries, &integration).await.remove(0);
+        assert_eq!(delivery.status, DeliveryStatus::Pending);
+        assert_eq!(delivery.attempts, 1);
+
+        // Not due until the backoff elapses
+        *adapter.failure.lock().unwrap() = None;
+        assert_eq!(dispatcher.retry_due(10).await.unwrap(), 0);
+
+        delivery.next_attempt_at = Timestamp::now();
+        deliveries.save(&delivery).await.unwrap();
+        assert_eq!(dispatcher.retry_due(10).await.unwrap(), 1);
+
+        let delivery = list_deliveries(&deliveries, &integration).await.remove(0);
+        assert_eq!(delivery.status, DeliveryStatus::Sent);
+        assert_eq!(delivery.attempts, 2);
+    }
+
+    #[tokio::test]
+    async fn template_errors_fail_without_retrying() {
+        let (integrations, deliveries, adapter) = setup_repositories();
+        let dispatcher = create_handler(integrations.clone(), deliveries.clone(), adapter.clone());
+        let integration = integrate(
+            &integrations,
+            Vec::new(),
+            Some("{{ payload.missing.field }}"),
+        )
+        .await;
+
+        dispatcher
+            .handle(completed("objectives", "user-1"))
+            .await
+            .unwrap();
+
+        let delivery = list_deliveries(&deliveries, &integration).await.remove(0);
+        assert_eq!(delivery.status, DeliveryStatus::Failed);
+        assert!(delivery.last_error.unwrap().contains("Template"));
+        assert!(adapter.executed.lock().unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn pending_deliveries_of_deleted_integrations_fail() {
+        let (integrations, deliveries, adapter) = setup_repositories();
+        let dispatcher = create_handler(integrations.clone(), deliveries.clone(), adapter.clone());
+        let integration = integrate(&integrations, Vec::new(), None).await;
+        *adapter.failure.lock().unwrap() =
+            Some(IntegrationActionError::Unavailable("503".to_string()));
+        dispatcher
+            .handle(completed("objectives", "user-1"))
+            .await
+            .unwrap();
+        let mut delivery = list_deliveries(&deliveries, &integration).await.remove(0);
+        delivery.next_attempt_at = Timestamp::now();
+        deliveries.save(&delivery).await.unwrap();
+
+        integrations.delete(&integration.id).await.unwrap();
+        assert_eq!(dispatcher.retry_due(10).await.unwrap(), 0);
+
+        let delivery = list_deliveries(&deliveries, &integration).await.remove(0);
+        assert_eq!(delivery.status, DeliveryStatus::Failed);
+    }
+}
@@ -0,0 +1,505 @@
+//! Integration management handlers - Create, change, list, and remove a
+//! user's integrations, and inspect their recent deliveries.
+//!
+//! Templates are compiled before anything is saved, so a syntax error
+//! surfaces when the integration is configured rather than on its first
+//! delivery. Other users' integrations are reported as not found.
+
+use std::sync::Arc;
+
+use crate::domain::foundation::{DomainError, IntegrationId, UserId};
+use crate::domain::integration::{
+    EventFilter, Integration, IntegrationAction, IntegrationDelivery, IntegrationError,
+    MAX_INTEGRATIONS_PER_USER,
+};
+use crate::ports::{
+    IntegrationActionAdapter, IntegrationDeliveryRepository, IntegrationRepository,
+    IntegrationTemplateEngine,
+};
+
+/// Most recent deliveries returned per integration.
+pub const MAX_LISTED_DELIVERIES: u32 = 50;
+
+/// Errors from managing integrations.
+#[derive(Debug, Clone)]
+pub enum ManageIntegrationError {
+    /// No integration with that ID belongs to the user.
+    NotFound(IntegrationId),
+    /// The configuration is invalid.
+    Invalid(IntegrationError),
+    /// A template (payload or email subject) does not compile.
+    InvalidTemplate(String),
+    /// No adapter is registered for the action's kind.
+    ActionUnavailable(&'static str),
+    /// The user already has [`MAX_INTEGRATIONS_PER_USER`] integrations.
+    LimitReached,
+    /// Domain error.
+    Domain(DomainError),
+}
+
+impl std::fmt::Display for ManageIntegrationError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ManageIntegrationError::NotFound(id) => write!(f, "Integration {} not found", id),
+            ManageIntegrationError::Invalid(err) => write!(f, "{}", err),
+            ManageIntegrationError::InvalidTemplate(message) => write!(f, "{}", message),
+            ManageIntegrationError::ActionUnavailable(kind) => {
+                write!(f, "'{}' actions are not available", kind)
+            }
+            ManageIntegrationError::LimitReached => write!(
+                f,
+                "You can have at most {} integrations",
+                MAX_INTEGRATIONS_PER_USER
+            ),
+            ManageIntegrationError::Domain(err) => write!(f, "{}", err),
+        }
+    }
+}
+
+impl std::error::Error for ManageIntegrationError {}
+
+impl From<DomainError> for ManageIntegrationError {
+    fn from(err: DomainError) -> Self {
+        ManageIntegrationError::Domain(err)
+    }
+}
+
+impl From<IntegrationError> for ManageIntegrationError {
+    fn from(err: IntegrationError) -> Self {
+        ManageIntegrationError::Invalid(err)
+    }
+}
+
+/// The configurable part of an integration.
+#[derive(Debug, Clone)]
+pub struct IntegrationSpec {
+    pub name: String,
+    pub filter: EventFilter,
+    pub action: IntegrationAction,
+    pub template: Option<String>,
+}
+
+/// Rejects actions no adapter can perform and templates that do not compile.
+fn check_spec(
+    spec: &IntegrationSpec,
+    templates: &dyn IntegrationTemplateEngine,
+    adapters: &[Arc<dyn IntegrationActionAdapter>],
+) -> Result<(), ManageIntegrationError> {
+    let kind = spec.action.kind();
+    if !adapters.iter().any(|adapter| adapter.kind() == kind) {
+        return Err(ManageIntegrationError::ActionUnavailable(kind.as_str()));
+    }
+    let subject = match &spec.action {
+        IntegrationAction::Email { subject, .. } => Some(subject.as_str()),
+        _ => None,
+    };
+    for source in spec.template.iter().map(String::as_str).chain(subject) {
+        templates
+            .check(source)
+            .map_err(|e| ManageIntegrationError::InvalidTemplate(e.to_string()))?;
+    }
+    Ok(())
+}
+
+/// Loads an integration, treating other users' integrations as missing.
+async fn find_owned(
+    integrations: &dyn IntegrationRepository,
+    user_id: &UserId,
+    id: IntegrationId,
+) -> Result<Integration, ManageIntegrationError> {
+    integrations
+        .find_by_id(&id)
+        .await?
+        .filter(|integration| &integration.user_id == user_id)
+        .ok_or(ManageIntegrationError::NotFound(id))
+}
+
+// ════════════════════════════════════════════════════════════════════════════════
+// Create Integration
+// ════════════════════════════════════════════════════════════════════════════════
+
+/// Command to create an integration.
+#[derive(Debug, Clone)]
+pub struct CreateIntegrationCommand {
+    pub user_id: UserId,
+    pub spec: IntegrationSpec,
+}
+
+/// Handler that creates enabled integrations.
+pub struct CreateIntegrationHandler {
+    integrations: Arc<dyn IntegrationRepository>,
+    templates: Arc<dyn IntegrationTemplateEngine>,
+    adapters: Vec<Arc<dyn IntegrationActionAdapter>>,
+}
+
+impl CreateIntegrationHandler {
+    pub fn new(
+        integrations: Arc<dyn IntegrationRepository>,
+        templates: Arc<dyn IntegrationTemplateEngine>,
+        adapters: Vec<Arc<dyn IntegrationActionAdapter>>,
+    ) -> Self {
+        Self {
+            integrations,
+            templates,
+            adapters,
+        }
+    }
+
+    #[tracing::instrument(name = "CreateIntegrationHandler::handle", skip_all)]
+    pub async fn handle(
+        &self,
+        cmd: CreateIntegrationCommand,
+    ) -> Result<Integration, ManageIntegrationError> {
+        let existing = self.integrations.list_for_user(&cmd.user_id).await?;
+        if existing.len() >= MAX_INTEGRATIONS_PER_USER {
+            return Err(ManageIntegrationError::LimitReached);
+        }
+
+        let spec = cmd.spec;
+        let integration = Integration::new(
+            cmd.user_id,
+            spec.name.clone(),
+            spec.filter.clone(),
+            spec.action.clone(),
+            spec.template.clone(),
+        )?;
+        check_spec(&spec, self.templates.as_ref(), &self.adapters)?;
+
+        self.integrations.save(&integration).await?;
+        Ok(integration)
+    }
+}
+
+// ════════════════════════════════════════════════════════════════════════════════
+// Update Integration
+// ════════════════════════════════════════════════════════════════════════════════
+
+/// Command to replace an integration's configuration.
+#[derive(Debug, Clone)]
+pub struct UpdateIntegrationCommand {
+    pub user_id: UserId,
+    pub integration_id: IntegrationId,
+    pub spec: IntegrationSpec,
+    pub enabled: bool,
+}
+
+/// Handler that reconfigures, enables, or disables integrations.
+pub struct UpdateIntegrationHandler {
+    integrations: Arc<dyn IntegrationRepository>,
+    templates: Arc<dyn IntegrationTemplateEngine>,
+    adapters: Vec<Arc<dyn IntegrationActionAdapter>>,
+}
+
+impl UpdateIntegrationHandler {
+    pub fn new(
+        integrations: Arc<dyn IntegrationRepository>,
+        templates: Arc<dyn IntegrationTemplateEngine>,
+        adapters: Vec<Arc<dyn IntegrationActionAdapter>>,
+    ) -> Self {
+        Self {
+            integrations,
+            templates,
+            adapters,
+        }
+    }
+
+    #[tracing::instrument(name = "UpdateIntegrationHandler::handle", skip_all)]
+    pub async fn handle(
+        &self,
+        cmd: UpdateIntegrationCommand,
+    ) -> Result<Integration, ManageIntegrationError> {
+        let mut integration =
+            find_owned(self.integrations.as_ref(), &cmd.user_id, cmd.integration_id).await?;
+
+        let spec = cmd.spec;
+        integration.update(
+            spec.name.clone(),
+            spec.filter.clone(),
+            spec.action.clone(),
+            spec.template.clone(),
+            cmd.enabled,
+        )?;
+        check_spec(&spec, self.templates.as_ref(), &self.adapters)?;
+
+        self.integrations.save(&integration).await?;
+        Ok(integration)
+    }
+}
+
+// ════════════════════════════════════════════════════════════════════════════════
+// Delete Integration
+// ════════════════════════════════════════════════════════════════════════════════
+
+/// Command to remove an integration.
+#[derive(Debug, Clone)]
+pub struct DeleteIntegrationCommand {
+    pub user_id: UserId,
+    pub integration_id: IntegrationId,
+}
+
+/// Handler that removes integrations.
+pub struct DeleteIntegrationHandler {
+    integrations: Arc<dyn IntegrationRepository>,
+}
+
+impl DeleteIntegrationHandler {
+    pub fn new(integrations: Arc<dyn IntegrationRepository>) -> Self {
+        Self { integrations }
+    }
+
+    #[tracing::instrument(name = "DeleteIntegrationHandler::handle", skip_all)]
+    pub async fn handle(&self, cmd: DeleteIntegrationCommand) -> Result<(), ManageIntegrationError> {
+        let integration =
+            find_owned(self.integrations.as_ref(), &cmd.user_id, cmd.integration_id).await?;
+        self.integrations.delete(&integration.id).await?;
+        Ok(())
+    }
+}
+
+// ════════════════════════════════════════════════════════════════════════════════
+// List Integrations
+// ════════════════════════════════════════════════════════════════════════════════
+
+/// Handler listing a user's integrations and their deliveries.
+pub struct ListIntegrationsHandler {
+    integrations: Arc<dyn IntegrationRepository>,
+    deliveries: Arc<dyn IntegrationDeliveryRepository>,
+}
+
+impl ListIntegrationsHandler {
+    pub fn new(
+        integrations: Arc<dyn IntegrationRepository>,
+        deliveries: Arc<dyn IntegrationDeliveryRepository>,
+    ) -> Self {
+        Self {
+            integrations,
+            deliveries,
+        }
+    }
+
+    /// The user's integrations, oldest first.
+    #[tracing::instrument(name = "ListIntegrationsHandler::list", skip_all)]
+    pub async fn list(&self, user_id: &UserId) -> Result<Vec<Integration>, ManageIntegrationError> {
+        Ok(self.integrations.list_for_user(user_id).await?)
+    }
+
+    /// The integration's most recent deliveries, newest first.
+    #[tracing::instrument(name = "ListIntegrationsHandler::deliveries", skip_all)]
+    pub async fn deliveries(
+        &self,
+        user_id: &UserId,
+        integration_id: IntegrationId,
+    ) -> Result<Vec<IntegrationDelivery>, ManageIntegrationError> {
+        let integration =
+            find_owned(self.integrations.as_ref(), user_id, integration_id).await?;
+        Ok(self
+            .deliveries
+            .list_for_integration(&integration.id, MAX_LISTED_DELIVERIES)
+            .await?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::adapters::{
+        EmailActionAdapter, HttpPostActionAdapter, InMemoryIntegrationDeliveryRepository,
+        InMemoryIntegrationRepository, TeraIntegrationTemplateEngine,
+    };
+    use crate::adapters::email::InMemoryEmailSender;
+
+    fn user() -> UserId {
+        UserId::new("user-1").unwrap()
+    }
+
+    fn spec(action: IntegrationAction, template: Option<&str>) -> IntegrationSpec {
+        IntegrationSpec {
+            name: "Notify".to_string(),
+            filter: EventFilter {
+                event_types: vec!["cycle.completed.v1".to_string()],
+                conditions: Vec::new(),
+            },
+            action,
+            template: template.map(str::to_string),
+        }
+    }
+
+    fn http() -> IntegrationAction {
+        IntegrationAction::HttpPost {
+            url: "https://hooks.example.com/catch".to_string(),
+        }
+    }
+
+    fn setup_repositories() -> (
+        Arc<InMemoryIntegrationRepository>,
+        Arc<InMemoryIntegrationDeliveryRepository>,
+    ) {
+        (
+            Arc::new(InMemoryIntegrationRepository::new()),
+            Arc::new(InMemoryIntegrationDeliveryRepository::new()),
+        )
+    }
+
+    /// HTTP and email adapters; no Slack adapter is registered.
+    fn adapters() -> Vec<Arc<dyn IntegrationActionAdapter>> {
+        vec![
+            Arc::new(HttpPostActionAdapter::default()),
+            Arc::new(EmailActionAdapter::new(
+                Arc::new(InMemoryEmailSender::new()),
+            )),
+        ]
+    }
+
+    fn create_handler(
+        integrations: Arc<InMemoryIntegrationRepository>,
+    ) -> CreateIntegrationHandler {
+        CreateIntegrationHandler::new(
+            integrations,
+            Arc::new(TeraIntegrationTemplateEngine::new()),
+            adapters(),
+        )
+    }
+
+    async fn create(
+        handler: &CreateIntegrationHandler,
+        spec: IntegrationSpec,
+    ) -> Result<Integration, ManageIntegrationError> {
+        handler
+            .handle(CreateIntegrationCommand {
+                user_id: user(),
+                spec,
+            })
+            .await
+    }
+
+    #[tokio::test]
+    async fn creates_enabled_integrations() {
+        let (integrations, deliveries) = setup_repositories();
+        let handler = create_handler(integrations.clone());
+
+        let integration = create(&handler, spec(http(), Some(r#"{"id": "{{ event.id }}"}"#)))
+            .await
+            .unwrap();
+
+        assert!(integration.enabled);
+        let list = ListIntegrationsHandler::new(integrations, deliveries);
+        assert_eq!(list.list(&user()).await.unwrap(), vec![integration]);
+    }
+
+    #[tokio::test]
+    async fn rejects_templates_that_do_not_compile() {
+        let (integrations, deliveries) = setup_repositories();
+        let handler = create_handler(integrations.clone());
+
+        let result = create(&handler, spec(http(), Some("{% if %}"))).await;
+        assert!(matches!(
+            result,
+            Err(ManageIntegrationError::InvalidTemplate(_))
+        ));
+
+        let email = IntegrationAction::Email {
+            to: "team@example.com".to_string(),
+            subject: "{{ event.type".to_string(),
+        };
+        let result = create(&handler, spec(email, None)).await;
+        assert!(matches!(
+            result,
+            Err(ManageIntegrationError::InvalidTemplate(_))
+        ));
+        let list = ListIntegrationsHandler::new(integrations, deliveries);
+        assert!(list.list(&user()).await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn rejects_actions_without_an_adapter() {
+        let (integrations, _) = setup_repositories();
+        let handler = create_handler(integrations);
+        let slack = IntegrationAction::Slack {
+            team_id: "T1".to_string(),
+            channel: "#decisions".to_string(),
+        };
+
+        let result = create(&handler, spec(slack, None)).await;
+
+        assert!(matches!(
+            result,
+            Err(ManageIntegrationError::ActionUnavailable("slack"))
+        ));
+    }
+
+    #[tokio::test]
+    async fn limits_integrations_per_user() {
+        let (integrations, _) = setup_repositories();
+        let handler = create_handler(integrations);
+        for _ in 0..MAX_INTEGRATIONS_PER_USER {
+            create(&handler, spec(http(), None)).await.unwrap();
+        }
+
+        let result = create(&handler, spec(http(), None)).await;
+
+        assert!(matches!(result, Err(ManageIntegrationError::LimitReached)));
+    }
+
+    #[tokio::test]
+    async fn updates_and_disables_integrations() {
+        let (integrations, _) = setup_repositories();
+        let handler = create_handler(integrations.clone());
+        let integration = create(&handler, spec(http(), None)).await.unwrap();
+        let mut changed = spec(http(), Some("{{ event.type }}"));
+        changed.name = "Renamed".to_string();
+
+        let updated = UpdateIntegrationHandler::new(
+            integrations.clone(),
+            Arc::new(TeraIntegrationTemplateEngine::new()),
+            adapters(),
+        )
+        .handle(UpdateIntegrationCommand {
+            user_id: user(),
+            integration_id: integration.id,
+            spec: changed,
+            enabled: false,
+        })
+        .await
+        .unwrap();
+
+        assert_eq!(updated.name, "Renamed");
+        assert!(!updated.enabled);
+        let stored = integrations
+            .find_by_id(&integration.id)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(stored, updated);
+    }
+
+    #[tokio::test]
+    async fn other_users_integrations_are_not_found() {
+        let (integrations, deliveries) = setup_repositories();
+        let handler = create_handler(integrations.clone());
+        let integration = create(&handler, spec(http(), None)).await.unwrap();
+        let other = UserId::new("user-2").unwrap();
+        let delete = DeleteIntegrationHandler::new(integrations.clone());
+        let list = ListIntegrationsHandler::new(integrations, deliveries);
+
+        let result = delete
+            .handle(DeleteIntegrationCommand {
+                user_id: other.clone(),
+                integration_id: integration.id,
+            })
+            .await;
+        assert!(matches!(result, Err(ManageIntegrationError::NotFound(_))));
+
+        let result = list.deliveries(&other, integration.id).await;
+        assert!(matches!(result, Err(ManageIntegrationError::NotFound(_))));
+
+        delete
+            .handle(DeleteIntegrationCommand {
+                user_id: user(),
+                integration_id: integration.id,
+            })
+            .await
+            .unwrap();
+        assert!(list.list(&user()).await.unwrap().is_empty());
+    }
+}
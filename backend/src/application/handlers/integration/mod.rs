@@ -0,0 +1,21 @@
+//! Integration handlers.
+//!
+//! ## Commands
+//! - Create, update, enable/disable, and delete a user's integrations
+//!
+//! ## Queries
+//! - A user's integrations and each one's recent deliveries
+//!
+//! ## Event Handlers
+//! - `IntegrationDispatcher` - Runs matching integrations on trigger events
+//!   and retries their failed deliveries
+
+mod dispatcher;
+mod manage_integrations;
+
+pub use dispatcher::IntegrationDispatcher;
+pub use manage_integrations::{
+    CreateIntegrationCommand, CreateIntegrationHandler, DeleteIntegrationCommand,
+    DeleteIntegrationHandler, IntegrationSpec, ListIntegrationsHandler, ManageIntegrationError,
+    UpdateIntegrationCommand, UpdateIntegrationHandler, MAX_LISTED_DELIVERIES,
+};
@@ -41,6 +41,7 @@ impl CancelMembershipHandler {
         }
     }
 
+    #[tracing::instrument(name = "CancelMembershipHandler::handle", skip_all)]
     pub async fn handle(
         &self,
         cmd: CancelMembershipCommand,
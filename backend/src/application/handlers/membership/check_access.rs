@@ -32,6 +32,7 @@ impl CheckAccessHandler {
         Self { reader }
     }
 
+    #[tracing::instrument(name = "CheckAccessHandler::handle", skip_all)]
     pub async fn handle(
         &self,
         query: CheckAccessQuery,
@@ -0,0 +1,292 @@
+//! ConvertTrialHandler - Command handler for ending a free trial early.
+//!
+//! The customer already gave a payment method at checkout, so converting
+//! charges it straight away instead of sending them through a new checkout
+//! session. Trials that simply run out convert via the `invoice.paid`
+//! webhook instead.
+
+use std::sync::Arc;
+
+use crate::domain::foundation::{EventId, SerializableDomainEvent, Timestamp, UserId};
+use crate::domain::membership::{Membership, MembershipError, MembershipEvent, MembershipStatus};
+use crate::ports::{EventPublisher, MembershipRepository, PaymentProvider, SubscriptionStatus};
+
+/// Command to convert the user's trial to a paid membership.
+#[derive(Debug, Clone)]
+pub struct ConvertTrialCommand {
+    pub user_id: UserId,
+}
+
+/// Result of a successful conversion.
+#[derive(Debug, Clone)]
+pub struct ConvertTrialResult {
+    pub membership: Membership,
+    pub event: MembershipEvent,
+}
+
+/// Handler for converting trials without a new checkout.
+pub struct ConvertTrialHandler {
+    repository: Arc<dyn MembershipRepository>,
+    payment_provider: Arc<dyn PaymentProvider>,
+    event_publisher: Arc<dyn EventPublisher>,
+}
+
+impl ConvertTrialHandler {
+    pub fn new(
+        repository: Arc<dyn MembershipRepository>,
+        payment_provider: Arc<dyn PaymentProvider>,
+        event_publisher: Arc<dyn EventPublisher>,
+    ) -> Self {
+        Self {
+            repository,
+            payment_provider,
+            event_publisher,
+        }
+    }
+
+    #[tracing::instrument(name = "ConvertTrialHandler::handle", skip_all)]
+    pub async fn handle(
+        &self,
+        cmd: ConvertTrialCommand,
+    ) -> Result<ConvertTrialResult, MembershipError> {
+        // 1. Find the user's trialing membership
+        let mut membership = self
+            .repository
+            .find_by_user_id(&cmd.user_id)
+            .await?
+            .ok_or_else(|| MembershipError::not_found_for_user(cmd.user_id.clone()))?;
+
+        if membership.status != MembershipStatus::Trialing {
+            return Err(MembershipError::invalid_state(
+                format!("{:?}", membership.status),
+                "convert trial",
+            ));
+        }
+
+        let subscription_id = membership.stripe_subscription_id.clone().ok_or_else(|| {
+            MembershipError::validation("stripe_subscription_id", "Trial has no subscription")
+        })?;
+
+        // 2. End the trial at the payment provider, charging the first period
+        let subscription = self
+            .payment_provider
+            .end_trial(&subscription_id)
+            .await
+            .map_err(|e| MembershipError::payment_failed(e.message))?;
+
+        if subscription.status != SubscriptionStatus::Active {
+            return Err(MembershipError::payment_failed(format!(
+                "First payment did not complete (subscription is {:?})",
+                subscription.status
+            )));
+        }
+
+        // 3. Start the paid period
+        let period_start =
+            Timestamp::from_unix_secs(subscription.current_period_start.max(0) as u64);
+        let period_end = Timestamp::from_unix_secs(subscription.current_period_end.max(0) as u64);
+        membership
+            .convert_trial(period_start, period_end)
+            .map_err(|e| {
+                MembershipError::invalid_state(format!("{:?}", membership.status), e.to_string())
+            })?;
+
+        // 4. Persist the update
+        self.repository.update(&membership).await?;
+
+        // 5. Create and publish event
+        let event = MembershipEvent::TrialConverted {
+            event_id: EventId::new(),
+            membership_id: membership.id,
+            user_id: cmd.user_id,
+            period_start,
+            period_end,
+            occurred_at: Timestamp::now(),
+        };
+
+        let envelope = event.to_envelope();
+        self.event_publisher.publish(envelope).await?;
+
+        Ok(ConvertTrialResult { membership, event })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Mutex;
+
+    use async_trait::async_trait;
+
+    use crate::adapters::{InMemoryEventBus, MockPaymentProvider};
+    use crate::domain::foundation::{DomainError, MembershipId};
+    use crate::domain::membership::MembershipTier;
+    use crate::ports::{PaymentError, PaymentErrorCode};
+
+    // ════════════════════════════════════════════════════════════════════════════
+    // Mock Implementation
+    // ════════════════════════════════════════════════════════════════════════════
+
+    struct MockMembershipRepository {
+        memberships: Mutex<Vec<Membership>>,
+    }
+
+    impl MockMembershipRepository {
+        fn with_membership(membership: Membership) -> Self {
+            Self {
+                memberships: Mutex::new(vec![membership]),
+            }
+        }
+
+        fn get_memberships(&self) -> Vec<Membership> {
+            self.memberships.lock().unwrap().clone()
+        }
+    }
+
+    #[async_trait]
+    impl MembershipRepository for MockMembershipRepository {
+        async fn save(&self, membership: &Membership) -> Result<(), DomainError> {
+            self.memberships.lock().unwrap().push(membership.clone());
+            Ok(())
+        }
+
+        async fn update(&self, membership: &Membership) -> Result<(), DomainError> {
+            let mut memberships = self.memberships.lock().unwrap();
+            if let Some(m) = memberships.iter_mut().find(|m| m.id == membership.id) {
+                *m = membership.clone();
+            }
+            Ok(())
+        }
+
+        async fn find_by_id(&self, _id: &MembershipId) -> Result<Option<Membership>, DomainError> {
+            Ok(None)
+        }
+
+        async fn find_by_user_id(
+            &self,
+            user_id: &UserId,
+        ) -> Result<Option<Membership>, DomainError> {
+            let memberships = self.memberships.lock().unwrap();
+            Ok(memberships.iter().find(|m| &m.user_id == user_id).cloned())
+        }
+
+        async fn find_expiring_within_days(
+            &self,
+            _days: u32,
+        ) -> Result<Vec<Membership>, DomainError> {
+            Ok(vec![])
+        }
+
+        async fn delete(&self, _id: &MembershipId) -> Result<(), DomainError> {
+            Ok(())
+        }
+
+        async fn find_by_stripe_subscription_id(
+            &self,
+            _subscription_id: &str,
+        ) -> Result<Option<Membership>, DomainError> {
+            Ok(None)
+        }
+
+        async fn find_by_stripe_customer_id(
+            &self,
+            _customer_id: &str,
+        ) -> Result<Option<Membership>, DomainError> {
+            Ok(None)
+        }
+    }
+
+    // ════════════════════════════════════════════════════════════════════════════
+    // Test Helpers
+    // ════════════════════════════════════════════════════════════════════════════
+
+    fn test_user_id() -> UserId {
+        UserId::new("test-user-123").unwrap()
+    }
+
+    fn trialing_membership() -> Membership {
+        let mut membership = Membership::create_paid(
+            MembershipId::new(),
+            test_user_id(),
+            MembershipTier::Monthly,
+            "cus_123".to_string(),
+        );
+        membership
+            .start_trial(
+                Timestamp::now(),
+                Timestamp::now().add_days(14),
+                Some("sub_123".to_string()),
+            )
+            .unwrap();
+        membership
+    }
+
+    fn command() -> ConvertTrialCommand {
+        ConvertTrialCommand {
+            user_id: test_user_id(),
+        }
+    }
+
+    // ════════════════════════════════════════════════════════════════════════════
+    // Tests
+    // ════════════════════════════════════════════════════════════════════════════
+
+    #[tokio::test]
+    async fn converts_trial_without_checkout() {
+        let repo = Arc::new(MockMembershipRepository::with_membership(
+            trialing_membership(),
+        ));
+        let payments = Arc::new(MockPaymentProvider::with_trialing_subscription(
+            "cus_123", "sub_123",
+        ));
+        let events = Arc::new(InMemoryEventBus::new());
+        let handler = ConvertTrialHandler::new(repo.clone(), payments.clone(), events.clone());
+
+        let result = handler.handle(command()).await.unwrap();
+
+        assert_eq!(result.membership.status, MembershipStatus::Active);
+        assert_eq!(repo.get_memberships()[0].status, MembershipStatus::Active);
+        assert!(payments.was_called("end_trial"));
+        assert!(!payments.was_called("create_checkout_session"));
+        assert!(events.has_event("membership.trial_converted.v1"));
+    }
+
+    #[tokio::test]
+    async fn rejects_membership_that_is_not_trialing() {
+        let mut membership = trialing_membership();
+        membership
+            .convert_trial(Timestamp::now(), Timestamp::now().add_days(30))
+            .unwrap();
+        let repo = Arc::new(MockMembershipRepository::with_membership(membership));
+        let payments = Arc::new(MockPaymentProvider::new());
+        let events = Arc::new(InMemoryEventBus::new());
+        let handler = ConvertTrialHandler::new(repo, payments.clone(), events);
+
+        let result = handler.handle(command()).await;
+
+        assert!(matches!(result, Err(MembershipError::InvalidState { .. })));
+        assert!(!payments.was_called("end_trial"));
+    }
+
+    #[tokio::test]
+    async fn failed_charge_leaves_trial_in_place() {
+        let repo = Arc::new(MockMembershipRepository::with_membership(
+            trialing_membership(),
+        ));
+        let payments = Arc::new(MockPaymentProvider::with_trialing_subscription(
+            "cus_123", "sub_123",
+        ));
+        payments.set_method_error(
+            "end_trial",
+            PaymentError::new(PaymentErrorCode::CardDeclined, "Card declined"),
+        );
+        let events = Arc::new(InMemoryEventBus::new());
+        let handler = ConvertTrialHandler::new(repo.clone(), payments, events.clone());
+
+        let result = handler.handle(command()).await;
+
+        assert!(matches!(result, Err(MembershipError::PaymentFailed { .. })));
+        assert_eq!(repo.get_memberships()[0].status, MembershipStatus::Trialing);
+        assert_eq!(events.event_count(), 0);
+    }
+}
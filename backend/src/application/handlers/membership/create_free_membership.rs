@@ -42,6 +42,7 @@ impl CreateFreeMembershipHandler {
         }
     }
 
+    #[tracing::instrument(name = "CreateFreeMembershipHandler::handle", skip_all)]
     pub async fn handle(
         &self,
         cmd: CreateFreeMembershipCommand,
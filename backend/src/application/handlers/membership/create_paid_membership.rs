@@ -31,11 +31,13 @@ pub struct CreatePaidMembershipResult {
 /// Handler for initiating paid membership checkout.
 ///
 /// This creates a pending membership and redirects the user to the payment provider's
-/// checkout page. The membership is activated when the webhook confirms payment.
+/// checkout page. The membership is activated when the webhook confirms payment, or
+/// starts a trial when one is configured.
 pub struct CreatePaidMembershipHandler {
     repository: Arc<dyn MembershipRepository>,
     payment_provider: Arc<dyn PaymentProvider>,
     event_publisher: Arc<dyn EventPublisher>,
+    trial_days: Option<u32>,
 }
 
 impl CreatePaidMembershipHandler {
@@ -48,9 +50,17 @@ impl CreatePaidMembershipHandler {
             repository,
             payment_provider,
             event_publisher,
+            trial_days: None,
         }
     }
 
+    /// Offers a free trial of this many days before the first charge.
+    pub fn with_trial_days(mut self, trial_days: Option<u32>) -> Self {
+        self.trial_days = trial_days.filter(|days| *days > 0);
+        self
+    }
+
+    #[tracing::instrument(name = "CreatePaidMembershipHandler::handle", skip_all)]
     pub async fn handle(
         &self,
         cmd: CreatePaidMembershipCommand,
@@ -101,6 +111,7 @@ impl CreatePaidMembershipHandler {
                 success_url: cmd.success_url,
                 cancel_url: cmd.cancel_url,
                 promo_code: cmd.promo_code.clone(),
+                trial_days: self.trial_days,
             })
             .await
             .map_err(|e| MembershipError::payment_failed(e.message))?;
@@ -133,8 +144,8 @@ mod tests {
     use crate::domain::foundation::{DomainError, ErrorCode, EventEnvelope};
     use crate::domain::membership::MembershipStatus;
     use crate::ports::{
-        CheckoutSession, CreateSubscriptionRequest, Customer, PaymentError, PaymentErrorCode,
-        PortalSession, Subscription, SubscriptionStatus, WebhookEvent,
+        CheckoutSession, CreateSubscriptionRequest, Customer, MeteredUsageReport, PaymentError,
+        PaymentErrorCode, PortalSession, Subscription, SubscriptionStatus, WebhookEvent,
     };
     use async_trait::async_trait;
     use std::sync::Mutex;
@@ -254,6 +265,7 @@ mod tests {
     struct MockPaymentProvider {
         fail_create_customer: bool,
         fail_create_checkout: bool,
+        checkout_requests: Mutex<Vec<CreateCheckoutRequest>>,
     }
 
     impl MockPaymentProvider {
@@ -261,6 +273,7 @@ mod tests {
             Self {
                 fail_create_customer: false,
                 fail_create_checkout: false,
+                checkout_requests: Mutex::new(Vec::new()),
             }
         }
 
@@ -268,6 +281,7 @@ mod tests {
             Self {
                 fail_create_customer: true,
                 fail_create_checkout: false,
+                checkout_requests: Mutex::new(Vec::new()),
             }
         }
 
@@ -275,8 +289,13 @@ mod tests {
             Self {
                 fail_create_customer: false,
                 fail_create_checkout: true,
+                checkout_requests: Mutex::new(Vec::new()),
             }
         }
+
+        fn checkout_requests(&self) -> Vec<CreateCheckoutRequest> {
+            self.checkout_requests.lock().unwrap().clone()
+        }
     }
 
     #[async_trait]
@@ -303,6 +322,10 @@ mod tests {
             Ok(None)
         }
 
+        async fn delete_customer(&self, _customer_id: &str) -> Result<(), PaymentError> {
+            Ok(())
+        }
+
         async fn create_subscription(
             &self,
             _request: CreateSubscriptionRequest,
@@ -315,6 +338,7 @@ mod tests {
                 current_period_end: 1237246290,
                 cancel_at_period_end: false,
                 canceled_at: None,
+                quantity: 1,
             })
         }
 
@@ -338,6 +362,7 @@ mod tests {
                 current_period_end: 1237246290,
                 cancel_at_period_end: true,
                 canceled_at: Some(1234567890),
+                quantity: 1,
             })
         }
 
@@ -354,13 +379,52 @@ mod tests {
                 current_period_end: 1237246290,
                 cancel_at_period_end: false,
                 canceled_at: None,
+                quantity: 1,
+            })
+        }
+
+        async fn update_subscription_quantity(
+            &self,
+            _subscription_id: &str,
+            quantity: u32,
+        ) -> Result<Subscription, PaymentError> {
+            Ok(Subscription {
+                id: "sub_123".to_string(),
+                customer_id: "cus_123".to_string(),
+                status: SubscriptionStatus::Active,
+                current_period_start: 1234567890,
+                current_period_end: 1237246290,
+                cancel_at_period_end: false,
+                canceled_at: None,
+                quantity,
             })
         }
 
+        async fn end_trial(&self, subscription_id: &str) -> Result<Subscription, PaymentError> {
+            Ok(Subscription {
+                id: subscription_id.to_string(),
+                customer_id: "cus_123".to_string(),
+                status: SubscriptionStatus::Active,
+                current_period_start: 1234567890,
+                current_period_end: 1237246290,
+                cancel_at_period_end: false,
+                canceled_at: None,
+                quantity: 1,
+            })
+        }
+
+        async fn report_metered_usage(
+            &self,
+            _report: MeteredUsageReport,
+        ) -> Result<(), PaymentError> {
+            Ok(())
+        }
+
         async fn create_checkout_session(
             &self,
-            _request: CreateCheckoutRequest,
+            request: CreateCheckoutRequest,
         ) -> Result<CheckoutSession, PaymentError> {
+            self.checkout_requests.lock().unwrap().push(request);
             if self.fail_create_checkout {
                 return Err(PaymentError::new(
                     PaymentErrorCode::ProviderError,
@@ -473,6 +537,32 @@ mod tests {
         assert!(result.checkout_session.url.contains("checkout.stripe.com"));
     }
 
+    #[tokio::test]
+    async fn offers_configured_trial_at_checkout() {
+        let repo = Arc::new(MockMembershipRepository::new());
+        let payment = Arc::new(MockPaymentProvider::new());
+        let publisher = Arc::new(MockEventPublisher::new());
+
+        let handler = CreatePaidMembershipHandler::new(repo, payment.clone(), publisher)
+            .with_trial_days(Some(14));
+        handler.handle(test_command()).await.unwrap();
+
+        assert_eq!(payment.checkout_requests()[0].trial_days, Some(14));
+    }
+
+    #[tokio::test]
+    async fn checkout_has_no_trial_by_default() {
+        let repo = Arc::new(MockMembershipRepository::new());
+        let payment = Arc::new(MockPaymentProvider::new());
+        let publisher = Arc::new(MockEventPublisher::new());
+
+        let handler = CreatePaidMembershipHandler::new(repo, payment.clone(), publisher)
+            .with_trial_days(Some(0));
+        handler.handle(test_command()).await.unwrap();
+
+        assert_eq!(payment.checkout_requests()[0].trial_days, None);
+    }
+
     #[tokio::test]
     async fn publishes_membership_created_event() {
         let repo = Arc::new(MockMembershipRepository::new());
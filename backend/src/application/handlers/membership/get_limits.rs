@@ -0,0 +1,292 @@
+//! GetLimitsHandler - Query handler for the caller's current quotas.
+//!
+//! Gathers rate-limit buckets, the daily AI cost budget, and tier caps into
+//! one snapshot so clients can disable actions before they hit a 429.
+
+use std::sync::Arc;
+
+use crate::domain::foundation::{Timestamp, UserId};
+use crate::domain::membership::{MembershipError, TierLimits};
+use crate::ports::{AccessChecker, RateLimitKey, RateLimiter, UsageStats, UsageTracker};
+
+/// Per-user rate-limit resources reported by the query.
+///
+/// `None` is the general request bucket.
+const RATE_LIMIT_RESOURCES: [Option<&str>; 5] = [
+    None,
+    Some("conversation"),
+    Some("ai_completions"),
+    Some("session"),
+    Some("export"),
+];
+
+/// Query for a user's current limits.
+#[derive(Debug, Clone)]
+pub struct GetLimitsQuery {
+    pub user_id: UserId,
+}
+
+/// Current state of one rate-limit bucket.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RateLimitBucket {
+    /// Resource name, `"general"` for the request-wide bucket.
+    pub resource: String,
+    pub limit: u32,
+    pub remaining: u32,
+    pub window_secs: u32,
+    pub reset_at: Timestamp,
+}
+
+/// Remaining AI spend for the current UTC day.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AiBudget {
+    pub limit_cents: u32,
+    pub spent_cents: u32,
+    pub remaining_cents: u32,
+    /// Next UTC midnight.
+    pub reset_at: Timestamp,
+}
+
+/// Snapshot of every limit that applies to the user.
+#[derive(Debug, Clone)]
+pub struct GetLimitsResult {
+    pub tier_limits: TierLimits,
+    pub usage: UsageStats,
+    /// Empty when the rate limiter backend is unavailable.
+    pub rate_limits: Vec<RateLimitBucket>,
+    /// `None` when no daily AI budget is configured.
+    pub ai_budget: Option<AiBudget>,
+}
+
+/// Handler for the limits introspection query.
+///
+/// Membership lookups are required; rate-limit and AI-usage lookups degrade
+/// to empty sections so a Redis or usage store outage never hides tier caps.
+pub struct GetLimitsHandler {
+    access_checker: Arc<dyn AccessChecker>,
+    rate_limiter: Arc<dyn RateLimiter>,
+    usage_tracker: Arc<dyn UsageTracker>,
+    daily_ai_budget_cents: Option<u32>,
+}
+
+impl GetLimitsHandler {
+    pub fn new(
+        access_checker: Arc<dyn AccessChecker>,
+        rate_limiter: Arc<dyn RateLimiter>,
+        usage_tracker: Arc<dyn UsageTracker>,
+        daily_ai_budget_cents: Option<u32>,
+    ) -> Self {
+        Self {
+            access_checker,
+            rate_limiter,
+            usage_tracker,
+            daily_ai_budget_cents,
+        }
+    }
+
+    #[tracing::instrument(name = "GetLimitsHandler::handle", skip_all)]
+    pub async fn handle(&self, query: GetLimitsQuery) -> Result<GetLimitsResult, MembershipError> {
+        let tier_limits = self
+            .access_checker
+            .get_tier_limits(&query.user_id)
+            .await
+            .map_err(|e| MembershipError::infrastructure(e.to_string()))?;
+        let usage = self
+            .access_checker
+            .get_usage(&query.user_id)
+            .await
+            .map_err(|e| MembershipError::infrastructure(e.to_string()))?;
+
+        Ok(GetLimitsResult {
+            tier_limits,
+            usage,
+            rate_limits: self.rate_limit_buckets(&query.user_id).await,
+            ai_budget: self.ai_budget(&query.user_id).await,
+        })
+    }
+
+    async fn rate_limit_buckets(&self, user_id: &UserId) -> Vec<RateLimitBucket> {
+        let mut buckets = Vec::with_capacity(RATE_LIMIT_RESOURCES.len());
+        for resource in RATE_LIMIT_RESOURCES {
+            let key = match resource {
+                Some(resource) => RateLimitKey::user_resource(user_id, resource),
+                None => RateLimitKey::user(user_id),
+            };
+            match self.rate_limiter.status(key).await {
+                Ok(status) => buckets.push(RateLimitBucket {
+                    resource: resource.unwrap_or("general").to_string(),
+                    limit: status.limit,
+                    remaining: status.remaining,
+                    window_secs: status.window_secs,
+                    reset_at: status.reset_at,
+                }),
+                Err(e) => {
+                    tracing::warn!("Rate limit status unavailable: {}", e);
+                    return Vec::new();
+                }
+            }
+        }
+        buckets
+    }
+
+    async fn ai_budget(&self, user_id: &UserId) -> Option<AiBudget> {
+        let limit_cents = self.daily_ai_budget_cents?;
+        let spent_cents = match self.usage_tracker.get_daily_cost(user_id).await {
+            Ok(spent) => spent,
+            Err(e) => {
+                tracing::warn!("Daily AI cost unavailable: {}", e);
+                return None;
+            }
+        };
+
+        Some(AiBudget {
+            limit_cents,
+            spent_cents,
+            remaining_cents: limit_cents.saturating_sub(spent_cents),
+            reset_at: Timestamp::start_of_today().plus_days(1),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::adapters::{InMemoryRateLimiter, InMemoryUsageTracker, StubAccessChecker};
+    use crate::domain::foundation::SessionId;
+    use crate::domain::membership::MembershipTier;
+    use crate::ports::{RateLimitError, RateLimitResult, RateLimitStatus, UsageRecord};
+    use async_trait::async_trait;
+
+    struct UnavailableRateLimiter;
+
+    #[async_trait]
+    impl RateLimiter for UnavailableRateLimiter {
+        async fn check(&self, _key: RateLimitKey) -> Result<RateLimitResult, RateLimitError> {
+            Err(RateLimitError::Unavailable("redis down".to_string()))
+        }
+
+        async fn status(&self, _key: RateLimitKey) -> Result<RateLimitStatus, RateLimitError> {
+            Err(RateLimitError::Unavailable("redis down".to_string()))
+        }
+
+        async fn reset(&self, _key: RateLimitKey) -> Result<(), RateLimitError> {
+            Ok(())
+        }
+    }
+
+    fn test_user_id() -> UserId {
+        UserId::new("test-user-123").unwrap()
+    }
+
+    fn handler_with(
+        rate_limiter: Arc<dyn RateLimiter>,
+        usage_tracker: Arc<InMemoryUsageTracker>,
+        budget: Option<u32>,
+    ) -> GetLimitsHandler {
+        let usage = UsageStats {
+            active_sessions: 2,
+            total_cycles: 3,
+            exports_this_month: 0,
+        };
+        GetLimitsHandler::new(
+            Arc::new(StubAccessChecker::with_tier(MembershipTier::Free).with_usage(usage)),
+            rate_limiter,
+            usage_tracker,
+            budget,
+        )
+    }
+
+    #[tokio::test]
+    async fn reports_tier_caps_and_usage() {
+        let handler = handler_with(
+            Arc::new(InMemoryRateLimiter::with_defaults()),
+            Arc::new(InMemoryUsageTracker::new()),
+            None,
+        );
+
+        let result = handler
+            .handle(GetLimitsQuery {
+                user_id: test_user_id(),
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(result.tier_limits.max_active_sessions, Some(3));
+        assert_eq!(result.usage.active_sessions, 2);
+        assert!(result.ai_budget.is_none());
+    }
+
+    #[tokio::test]
+    async fn reports_remaining_rate_limit_per_resource() {
+        let limiter = Arc::new(InMemoryRateLimiter::with_defaults());
+        let user_id = test_user_id();
+        limiter
+            .check(RateLimitKey::user_resource(&user_id, "ai_completions"))
+            .await
+            .unwrap();
+        let handler = handler_with(limiter, Arc::new(InMemoryUsageTracker::new()), None);
+
+        let result = handler.handle(GetLimitsQuery { user_id }).await.unwrap();
+
+        let resources: Vec<&str> = result
+            .rate_limits
+            .iter()
+            .map(|b| b.resource.as_str())
+            .collect();
+        assert_eq!(
+            resources,
+            ["general", "conversation", "ai_completions", "session", "export"]
+        );
+        let ai = &result.rate_limits[2];
+        assert_eq!(ai.limit, 5);
+        assert_eq!(ai.remaining, 4);
+    }
+
+    #[tokio::test]
+    async fn reports_remaining_ai_budget() {
+        let tracker = Arc::new(InMemoryUsageTracker::new());
+        let user_id = test_user_id();
+        let record = UsageRecord::new(
+            user_id.clone(),
+            SessionId::new(),
+            "anthropic",
+            "claude-sonnet-4",
+            100,
+            50,
+            30,
+            None,
+        );
+        tracker.record_usage(record).await.unwrap();
+        let handler = handler_with(
+            Arc::new(InMemoryRateLimiter::with_defaults()),
+            tracker,
+            Some(100),
+        );
+
+        let result = handler.handle(GetLimitsQuery { user_id }).await.unwrap();
+
+        let budget = result.ai_budget.unwrap();
+        assert_eq!(budget.spent_cents, 30);
+        assert_eq!(budget.remaining_cents, 70);
+        assert!(budget.reset_at.is_after(&Timestamp::now()));
+    }
+
+    #[tokio::test]
+    async fn rate_limiter_outage_omits_buckets() {
+        let handler = handler_with(
+            Arc::new(UnavailableRateLimiter),
+            Arc::new(InMemoryUsageTracker::new()),
+            None,
+        );
+
+        let result = handler
+            .handle(GetLimitsQuery {
+                user_id: test_user_id(),
+            })
+            .await
+            .unwrap();
+
+        assert!(result.rate_limits.is_empty());
+        assert_eq!(result.tier_limits.tier, MembershipTier::Free);
+    }
+}
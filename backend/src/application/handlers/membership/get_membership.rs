@@ -28,6 +28,7 @@ impl GetMembershipHandler {
         Self { reader }
     }
 
+    #[tracing::instrument(name = "GetMembershipHandler::handle", skip_all)]
     pub async fn handle(
         &self,
         query: GetMembershipQuery,
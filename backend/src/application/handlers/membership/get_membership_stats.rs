@@ -26,6 +26,7 @@ impl GetMembershipStatsHandler {
         Self { reader }
     }
 
+    #[tracing::instrument(name = "GetMembershipStatsHandler::handle", skip_all)]
     pub async fn handle(
         &self,
         _query: GetMembershipStatsQuery,
@@ -111,6 +112,7 @@ mod tests {
             },
             by_status: StatusCounts {
                 pending: 5,
+                trialing: 8,
                 active: 120,
                 past_due: 10,
                 cancelled: 10,
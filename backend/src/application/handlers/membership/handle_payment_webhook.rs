@@ -3,10 +3,12 @@
 use std::sync::Arc;
 
 use crate::domain::foundation::{EventId, SerializableDomainEvent, Timestamp};
-use crate::domain::membership::{ExpiredReason, MembershipError, MembershipEvent};
+use crate::domain::membership::{
+    ExpiredReason, Membership, MembershipError, MembershipEvent, MembershipStatus,
+};
 use crate::ports::{
-    EventPublisher, MembershipRepository, PaymentProvider, WebhookEvent, WebhookEventData,
-    WebhookEventType,
+    EventPublisher, MembershipRepository, PaymentProvider, Sli, SloRecorder, SubscriptionStatus,
+    WebhookEvent, WebhookEventData, WebhookEventType,
 };
 
 /// Command to handle a payment webhook.
@@ -26,6 +28,23 @@ pub enum HandlePaymentWebhookResult {
         membership_id: String,
         user_id: String,
     },
+    /// Checkout completed for a trialing subscription, trial started.
+    TrialStarted {
+        membership_id: String,
+        user_id: String,
+        trial_ends_at: Timestamp,
+    },
+    /// Trial ends soon; the user has been notified via `TrialEnding`.
+    TrialEnding {
+        membership_id: String,
+        user_id: String,
+        trial_ends_at: Timestamp,
+    },
+    /// First charge after a trial succeeded, membership converted.
+    TrialConverted {
+        membership_id: String,
+        user_id: String,
+    },
     /// Invoice paid, membership renewed.
     MembershipRenewed {
         membership_id: String,
@@ -42,6 +61,12 @@ pub enum HandlePaymentWebhookResult {
         membership_id: String,
         user_id: String,
     },
+    /// Subscription quantity changed, membership seats updated.
+    SeatsChanged {
+        membership_id: String,
+        user_id: String,
+        seats: u32,
+    },
     /// Event acknowledged but no action taken.
     Acknowledged,
     /// Event ignored (unknown or unsupported type).
@@ -56,6 +81,7 @@ pub struct HandlePaymentWebhookHandler {
     repository: Arc<dyn MembershipRepository>,
     payment_provider: Arc<dyn PaymentProvider>,
     event_publisher: Arc<dyn EventPublisher>,
+    slo_recorder: Option<Arc<dyn SloRecorder>>,
 }
 
 impl HandlePaymentWebhookHandler {
@@ -68,12 +94,36 @@ impl HandlePaymentWebhookHandler {
             repository,
             payment_provider,
             event_publisher,
+            slo_recorder: None,
         }
     }
 
+    /// Records webhook processing success for SLO tracking.
+    pub fn with_slo_recorder(mut self, recorder: Arc<dyn SloRecorder>) -> Self {
+        self.slo_recorder = Some(recorder);
+        self
+    }
+
+    #[tracing::instrument(name = "HandlePaymentWebhookHandler::handle", skip_all)]
     pub async fn handle(
         &self,
         cmd: HandlePaymentWebhookCommand,
+    ) -> Result<HandlePaymentWebhookResult, MembershipError> {
+        let result = self.process(cmd).await;
+
+        // Forged or malformed deliveries are the sender's fault, not ours
+        if let Some(recorder) = &self.slo_recorder {
+            if !matches!(result, Err(MembershipError::InvalidWebhookSignature)) {
+                recorder.record_outcome(Sli::WebhookProcessing, result.is_ok());
+            }
+        }
+
+        result
+    }
+
+    async fn process(
+        &self,
+        cmd: HandlePaymentWebhookCommand,
     ) -> Result<HandlePaymentWebhookResult, MembershipError> {
         // 1. Verify webhook signature and parse event
         let webhook_event = self
@@ -95,17 +145,13 @@ impl HandlePaymentWebhookHandler {
                 self.handle_subscription_deleted(&webhook_event).await
             }
             WebhookEventType::SubscriptionUpdated => {
-                // Acknowledge but don't process - subscription updates are handled elsewhere
-                Ok(HandlePaymentWebhookResult::Acknowledged)
+                self.handle_subscription_updated(&webhook_event).await
             }
             WebhookEventType::SubscriptionCreated => {
                 // Subscription creation is handled via checkout completion
                 Ok(HandlePaymentWebhookResult::Acknowledged)
             }
-            WebhookEventType::TrialWillEnd => {
-                // We don't use trials, acknowledge only
-                Ok(HandlePaymentWebhookResult::Acknowledged)
-            }
+            WebhookEventType::TrialWillEnd => self.handle_trial_will_end(&webhook_event).await,
             WebhookEventType::Unknown(_) => Ok(HandlePaymentWebhookResult::Ignored),
         }
     }
@@ -139,6 +185,24 @@ impl HandlePaymentWebhookHandler {
                 ))
             })?;
 
+        // Trialing subscriptions start a trial instead of the paid period
+        if let Some(sub_id) = &subscription_id {
+            let subscription = self
+                .payment_provider
+                .get_subscription(sub_id)
+                .await
+                .map_err(|e| MembershipError::payment_failed(e.message))?;
+            if let Some(subscription) = subscription {
+                if subscription.status == SubscriptionStatus::Trialing {
+                    let trial_ends_at =
+                        Timestamp::from_unix_secs(subscription.current_period_end.max(0) as u64);
+                    return self
+                        .start_trial(membership, trial_ends_at, subscription_id)
+                        .await;
+                }
+            }
+        }
+
         // Activate the membership
         let now = Timestamp::now();
         let period_end = now.add_days(if membership.tier.is_annual() { 365 } else { 30 });
@@ -176,10 +240,12 @@ impl HandlePaymentWebhookHandler {
         &self,
         webhook_event: &WebhookEvent,
     ) -> Result<HandlePaymentWebhookResult, MembershipError> {
-        let subscription_id = match &webhook_event.data {
+        let (subscription_id, amount_paid) = match &webhook_event.data {
             WebhookEventData::Invoice {
-                subscription_id, ..
-            } => subscription_id.clone(),
+                subscription_id,
+                amount_paid,
+                ..
+            } => (subscription_id.clone(), *amount_paid),
             _ => {
                 return Err(MembershipError::infrastructure(
                     "Unexpected webhook data type for invoice.paid",
@@ -207,7 +273,15 @@ impl HandlePaymentWebhookHandler {
         let now = Timestamp::now();
         let period_end = now.add_days(if membership.tier.is_annual() { 365 } else { 30 });
 
-        let was_past_due = membership.status == crate::domain::membership::MembershipStatus::PastDue;
+        if membership.status == MembershipStatus::Trialing {
+            // The zero-amount invoice issued when a trial starts is not a conversion
+            if amount_paid == 0 {
+                return Ok(HandlePaymentWebhookResult::Acknowledged);
+            }
+            return self.convert_trial(membership, now, period_end).await;
+        }
+
+        let was_past_due = membership.status == MembershipStatus::PastDue;
 
         if was_past_due {
             membership.recover_payment(period_end).map_err(|e| {
@@ -252,6 +326,120 @@ impl HandlePaymentWebhookHandler {
         })
     }
 
+    async fn start_trial(
+        &self,
+        mut membership: Membership,
+        trial_ends_at: Timestamp,
+        subscription_id: Option<String>,
+    ) -> Result<HandlePaymentWebhookResult, MembershipError> {
+        let now = Timestamp::now();
+        membership
+            .start_trial(now, trial_ends_at, subscription_id)
+            .map_err(|e| {
+                MembershipError::invalid_state(format!("{:?}", membership.status), e.to_string())
+            })?;
+
+        self.repository.update(&membership).await?;
+
+        let event = MembershipEvent::TrialStarted {
+            event_id: EventId::new(),
+            membership_id: membership.id,
+            user_id: membership.user_id.clone(),
+            tier: membership.tier,
+            trial_ends_at,
+            occurred_at: now,
+        };
+        let envelope = event.to_envelope();
+        self.event_publisher.publish(envelope).await?;
+
+        Ok(HandlePaymentWebhookResult::TrialStarted {
+            membership_id: membership.id.to_string(),
+            user_id: membership.user_id.to_string(),
+            trial_ends_at,
+        })
+    }
+
+    async fn convert_trial(
+        &self,
+        mut membership: Membership,
+        period_start: Timestamp,
+        period_end: Timestamp,
+    ) -> Result<HandlePaymentWebhookResult, MembershipError> {
+        membership
+            .convert_trial(period_start, period_end)
+            .map_err(|e| {
+                MembershipError::invalid_state(format!("{:?}", membership.status), e.to_string())
+            })?;
+
+        self.repository.update(&membership).await?;
+
+        let event = MembershipEvent::TrialConverted {
+            event_id: EventId::new(),
+            membership_id: membership.id,
+            user_id: membership.user_id.clone(),
+            period_start,
+            period_end,
+            occurred_at: Timestamp::now(),
+        };
+        let envelope = event.to_envelope();
+        self.event_publisher.publish(envelope).await?;
+
+        Ok(HandlePaymentWebhookResult::TrialConverted {
+            membership_id: membership.id.to_string(),
+            user_id: membership.user_id.to_string(),
+        })
+    }
+
+    /// Announces the end of a trial so the user can be reminded before the
+    /// first charge.
+    async fn handle_trial_will_end(
+        &self,
+        webhook_event: &WebhookEvent,
+    ) -> Result<HandlePaymentWebhookResult, MembershipError> {
+        let (subscription_id, trial_end) = match &webhook_event.data {
+            WebhookEventData::Subscription {
+                subscription_id,
+                current_period_end,
+                ..
+            } => (subscription_id.clone(), *current_period_end),
+            _ => {
+                return Err(MembershipError::infrastructure(
+                    "Unexpected webhook data type for subscription.trial_will_end",
+                ))
+            }
+        };
+
+        let Some(membership) = self
+            .repository
+            .find_by_stripe_subscription_id(&subscription_id)
+            .await?
+        else {
+            return Ok(HandlePaymentWebhookResult::Acknowledged);
+        };
+
+        // Converted early or cancelled since the event was sent
+        if membership.status != MembershipStatus::Trialing {
+            return Ok(HandlePaymentWebhookResult::Acknowledged);
+        }
+
+        let trial_ends_at = Timestamp::from_unix_secs(trial_end.max(0) as u64);
+        let event = MembershipEvent::TrialEnding {
+            event_id: EventId::new(),
+            membership_id: membership.id,
+            user_id: membership.user_id.clone(),
+            trial_ends_at,
+            occurred_at: Timestamp::now(),
+        };
+        let envelope = event.to_envelope();
+        self.event_publisher.publish(envelope).await?;
+
+        Ok(HandlePaymentWebhookResult::TrialEnding {
+            membership_id: membership.id.to_string(),
+            user_id: membership.user_id.to_string(),
+            trial_ends_at,
+        })
+    }
+
     async fn handle_invoice_payment_failed(
         &self,
         webhook_event: &WebhookEvent,
@@ -311,6 +499,65 @@ impl HandlePaymentWebhookHandler {
         })
     }
 
+    /// Picks up seat changes made outside the app, such as in the billing
+    /// portal. Other subscription changes arrive through their own events.
+    async fn handle_subscription_updated(
+        &self,
+        webhook_event: &WebhookEvent,
+    ) -> Result<HandlePaymentWebhookResult, MembershipError> {
+        let (subscription_id, quantity) = match &webhook_event.data {
+            WebhookEventData::Subscription {
+                subscription_id,
+                quantity,
+                ..
+            } => (subscription_id.clone(), *quantity),
+            _ => {
+                return Err(MembershipError::infrastructure(
+                    "Unexpected webhook data type for subscription.updated",
+                ))
+            }
+        };
+
+        let Some(quantity) = quantity else {
+            return Ok(HandlePaymentWebhookResult::Acknowledged);
+        };
+
+        let Some(mut membership) = self
+            .repository
+            .find_by_stripe_subscription_id(&subscription_id)
+            .await?
+        else {
+            return Ok(HandlePaymentWebhookResult::Acknowledged);
+        };
+
+        if membership.seats == quantity {
+            return Ok(HandlePaymentWebhookResult::Acknowledged);
+        }
+
+        let previous_seats = membership
+            .set_seats(quantity)
+            .map_err(|e| MembershipError::validation("seats", e.to_string()))?;
+        self.repository.update(&membership).await?;
+
+        let event = MembershipEvent::SeatsChanged {
+            event_id: EventId::new(),
+            membership_id: membership.id,
+            user_id: membership.user_id.clone(),
+            previous_seats,
+            new_seats: quantity,
+            occurred_at: membership.updated_at,
+        };
+
+        let envelope = event.to_envelope();
+        self.event_publisher.publish(envelope).await?;
+
+        Ok(HandlePaymentWebhookResult::SeatsChanged {
+            membership_id: membership.id.to_string(),
+            user_id: membership.user_id.to_string(),
+            seats: quantity,
+        })
+    }
+
     async fn handle_subscription_deleted(
         &self,
         webhook_event: &WebhookEvent,
@@ -372,7 +619,8 @@ mod tests {
     use crate::domain::membership::{Membership, MembershipStatus, MembershipTier};
     use crate::ports::{
         CheckoutSession, CreateCheckoutRequest, CreateCustomerRequest, CreateSubscriptionRequest,
-        Customer, PaymentError, PaymentErrorCode, PortalSession, Subscription, SubscriptionStatus,
+        Customer, MeteredUsageReport, PaymentError, PaymentErrorCode, PortalSession, Subscription,
+        SubscriptionStatus,
     };
     use async_trait::async_trait;
     use std::sync::Mutex;
@@ -470,6 +718,7 @@ mod tests {
 
     struct MockPaymentProvider {
         webhook_event: Option<WebhookEvent>,
+        subscription: Option<Subscription>,
         fail_verify: bool,
     }
 
@@ -477,6 +726,7 @@ mod tests {
         fn with_event(event: WebhookEvent) -> Self {
             Self {
                 webhook_event: Some(event),
+                subscription: None,
                 fail_verify: false,
             }
         }
@@ -484,9 +734,15 @@ mod tests {
         fn failing() -> Self {
             Self {
                 webhook_event: None,
+                subscription: None,
                 fail_verify: true,
             }
         }
+
+        fn with_subscription(mut self, subscription: Subscription) -> Self {
+            self.subscription = Some(subscription);
+            self
+        }
     }
 
     #[async_trait]
@@ -507,6 +763,10 @@ mod tests {
             Ok(None)
         }
 
+        async fn delete_customer(&self, _customer_id: &str) -> Result<(), PaymentError> {
+            Ok(())
+        }
+
         async fn create_subscription(
             &self,
             _request: CreateSubscriptionRequest,
@@ -519,6 +779,7 @@ mod tests {
                 current_period_end: 1237246290,
                 cancel_at_period_end: false,
                 canceled_at: None,
+                quantity: 1,
             })
         }
 
@@ -526,7 +787,7 @@ mod tests {
             &self,
             _subscription_id: &str,
         ) -> Result<Option<Subscription>, PaymentError> {
-            Ok(None)
+            Ok(self.subscription.clone())
         }
 
         async fn cancel_subscription(
@@ -542,6 +803,7 @@ mod tests {
                 current_period_end: 1237246290,
                 cancel_at_period_end: true,
                 canceled_at: Some(1234567890),
+                quantity: 1,
             })
         }
 
@@ -558,9 +820,47 @@ mod tests {
                 current_period_end: 1237246290,
                 cancel_at_period_end: false,
                 canceled_at: None,
+                quantity: 1,
+            })
+        }
+
+        async fn update_subscription_quantity(
+            &self,
+            subscription_id: &str,
+            quantity: u32,
+        ) -> Result<Subscription, PaymentError> {
+            Ok(Subscription {
+                id: subscription_id.to_string(),
+                customer_id: "cus_123".to_string(),
+                status: SubscriptionStatus::Active,
+                current_period_start: 1234567890,
+                current_period_end: 1237246290,
+                cancel_at_period_end: false,
+                canceled_at: None,
+                quantity,
             })
         }
 
+        async fn end_trial(&self, subscription_id: &str) -> Result<Subscription, PaymentError> {
+            Ok(Subscription {
+                id: subscription_id.to_string(),
+                customer_id: "cus_123".to_string(),
+                status: SubscriptionStatus::Active,
+                current_period_start: 1234567890,
+                current_period_end: 1237246290,
+                cancel_at_period_end: false,
+                canceled_at: None,
+                quantity: 1,
+            })
+        }
+
+        async fn report_metered_usage(
+            &self,
+            _report: MeteredUsageReport,
+        ) -> Result<(), PaymentError> {
+            Ok(())
+        }
+
         async fn create_checkout_session(
             &self,
             _request: CreateCheckoutRequest,
@@ -664,6 +964,64 @@ mod tests {
         m
     }
 
+    fn trialing_membership() -> Membership {
+        let mut m = pending_membership();
+        m.start_trial(
+            Timestamp::now(),
+            Timestamp::now().add_days(14),
+            Some("sub_123".to_string()),
+        )
+        .unwrap();
+        m
+    }
+
+    fn trialing_subscription() -> Subscription {
+        Subscription {
+            id: "sub_123".to_string(),
+            customer_id: "cus_123".to_string(),
+            status: SubscriptionStatus::Trialing,
+            current_period_start: 1234567890,
+            current_period_end: 1235777490,
+            cancel_at_period_end: false,
+            canceled_at: None,
+            quantity: 1,
+        }
+    }
+
+    fn trial_will_end_event() -> WebhookEvent {
+        WebhookEvent {
+            id: "evt_128".to_string(),
+            event_type: WebhookEventType::TrialWillEnd,
+            data: WebhookEventData::Subscription {
+                subscription_id: "sub_123".to_string(),
+                customer_id: "cus_123".to_string(),
+                status: SubscriptionStatus::Trialing,
+                current_period_end: 1235777490,
+                quantity: Some(1),
+            },
+            created_at: 1234567890,
+        }
+    }
+
+    fn invoice_paid_for(amount_paid: i64) -> WebhookEvent {
+        let mut event = invoice_paid_event();
+        if let WebhookEventData::Invoice {
+            amount_paid: amount,
+            ..
+        } = &mut event.data
+        {
+            *amount = amount_paid;
+        }
+        event
+    }
+
+    fn webhook_command() -> HandlePaymentWebhookCommand {
+        HandlePaymentWebhookCommand {
+            payload: vec![],
+            signature: "valid".to_string(),
+        }
+    }
+
     fn checkout_completed_event() -> WebhookEvent {
         WebhookEvent {
             id: "evt_123".to_string(),
@@ -717,11 +1075,122 @@ mod tests {
                 customer_id: "cus_123".to_string(),
                 status: SubscriptionStatus::Canceled,
                 current_period_end: 1237246290,
+                quantity: None,
+            },
+            created_at: 1234567890,
+        }
+    }
+
+    fn subscription_updated_event(quantity: Option<u32>) -> WebhookEvent {
+        WebhookEvent {
+            id: "evt_127".to_string(),
+            event_type: WebhookEventType::SubscriptionUpdated,
+            data: WebhookEventData::Subscription {
+                subscription_id: "sub_123".to_string(),
+                customer_id: "cus_123".to_string(),
+                status: SubscriptionStatus::Active,
+                current_period_end: 1237246290,
+                quantity,
             },
             created_at: 1234567890,
         }
     }
 
+    // ════════════════════════════════════════════════════════════════════════════
+    // Trial Tests
+    // ════════════════════════════════════════════════════════════════════════════
+
+    #[tokio::test]
+    async fn checkout_for_trialing_subscription_starts_trial() {
+        let repo = Arc::new(MockMembershipRepository::with_membership(pending_membership()));
+        let payment = Arc::new(
+            MockPaymentProvider::with_event(checkout_completed_event())
+                .with_subscription(trialing_subscription()),
+        );
+        let publisher = Arc::new(MockEventPublisher::new());
+        let handler = HandlePaymentWebhookHandler::new(repo.clone(), payment, publisher.clone());
+
+        let result = handler.handle(webhook_command()).await.unwrap();
+
+        let expected_end = Timestamp::from_unix_secs(1235777490);
+        assert!(matches!(
+            result,
+            HandlePaymentWebhookResult::TrialStarted { trial_ends_at, .. } if trial_ends_at == expected_end
+        ));
+        let memberships = repo.get_memberships();
+        assert_eq!(memberships[0].status, MembershipStatus::Trialing);
+        assert_eq!(memberships[0].trial_ends_at(), Some(expected_end));
+        assert_eq!(
+            publisher.published_events()[0].event_type,
+            "membership.trial_started.v1"
+        );
+    }
+
+    #[tokio::test]
+    async fn trial_will_end_publishes_trial_ending() {
+        let repo = Arc::new(MockMembershipRepository::with_membership(trialing_membership()));
+        let payment = Arc::new(MockPaymentProvider::with_event(trial_will_end_event()));
+        let publisher = Arc::new(MockEventPublisher::new());
+        let handler = HandlePaymentWebhookHandler::new(repo, payment, publisher.clone());
+
+        let result = handler.handle(webhook_command()).await.unwrap();
+
+        assert!(matches!(
+            result,
+            HandlePaymentWebhookResult::TrialEnding { .. }
+        ));
+        assert_eq!(
+            publisher.published_events()[0].event_type,
+            "membership.trial_ending.v1"
+        );
+    }
+
+    #[tokio::test]
+    async fn trial_will_end_is_acknowledged_after_conversion() {
+        let repo = Arc::new(MockMembershipRepository::with_membership(active_membership()));
+        let payment = Arc::new(MockPaymentProvider::with_event(trial_will_end_event()));
+        let publisher = Arc::new(MockEventPublisher::new());
+        let handler = HandlePaymentWebhookHandler::new(repo, payment, publisher.clone());
+
+        let result = handler.handle(webhook_command()).await.unwrap();
+
+        assert!(matches!(result, HandlePaymentWebhookResult::Acknowledged));
+        assert!(publisher.published_events().is_empty());
+    }
+
+    #[tokio::test]
+    async fn first_paid_invoice_converts_trial() {
+        let repo = Arc::new(MockMembershipRepository::with_membership(trialing_membership()));
+        let payment = Arc::new(MockPaymentProvider::with_event(invoice_paid_for(2900)));
+        let publisher = Arc::new(MockEventPublisher::new());
+        let handler = HandlePaymentWebhookHandler::new(repo.clone(), payment, publisher.clone());
+
+        let result = handler.handle(webhook_command()).await.unwrap();
+
+        assert!(matches!(
+            result,
+            HandlePaymentWebhookResult::TrialConverted { .. }
+        ));
+        assert_eq!(repo.get_memberships()[0].status, MembershipStatus::Active);
+        assert_eq!(
+            publisher.published_events()[0].event_type,
+            "membership.trial_converted.v1"
+        );
+    }
+
+    #[tokio::test]
+    async fn zero_amount_trial_invoice_does_not_convert() {
+        let repo = Arc::new(MockMembershipRepository::with_membership(trialing_membership()));
+        let payment = Arc::new(MockPaymentProvider::with_event(invoice_paid_for(0)));
+        let publisher = Arc::new(MockEventPublisher::new());
+        let handler = HandlePaymentWebhookHandler::new(repo.clone(), payment, publisher);
+
+        let result = handler.handle(webhook_command()).await.unwrap();
+
+        assert!(matches!(result, HandlePaymentWebhookResult::Acknowledged));
+        assert_eq!(repo.get_memberships()[0].status, MembershipStatus::Trialing);
+    }
+
     // ════════════════════════════════════════════════════════════════════════════
     // Checkout Completed Tests
     // ════════════════════════════════════════════════════════════════════════════
@@ -895,6 +1364,59 @@ mod tests {
         assert_eq!(memberships[0].status, MembershipStatus::Expired);
     }
 
+    // ════════════════════════════════════════════════════════════════════════════
+    // Subscription Updated Tests
+    // ════════════════════════════════════════════════════════════════════════════
+
+    #[tokio::test]
+    async fn subscription_updated_syncs_seats_from_quantity() {
+        let repo = Arc::new(MockMembershipRepository::with_membership(
+            active_membership(),
+        ));
+        let event = subscription_updated_event(Some(4));
+        let payment = Arc::new(MockPaymentProvider::with_event(event));
+        let publisher = Arc::new(MockEventPublisher::new());
+
+        let handler = HandlePaymentWebhookHandler::new(repo.clone(), payment, publisher.clone());
+
+        let cmd = HandlePaymentWebhookCommand {
+            payload: vec![],
+            signature: "valid".to_string(),
+        };
+
+        let result = handler.handle(cmd).await.unwrap();
+        assert!(matches!(
+            result,
+            HandlePaymentWebhookResult::SeatsChanged { seats: 4, .. }
+        ));
+        assert_eq!(repo.get_memberships()[0].seats, 4);
+
+        let events = publisher.published_events();
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].event_type, "membership.seats_changed.v1");
+    }
+
+    #[tokio::test]
+    async fn subscription_updated_without_seat_change_is_acknowledged() {
+        let repo = Arc::new(MockMembershipRepository::with_membership(
+            active_membership(),
+        ));
+        let event = subscription_updated_event(Some(1));
+        let payment = Arc::new(MockPaymentProvider::with_event(event));
+        let publisher = Arc::new(MockEventPublisher::new());
+
+        let handler = HandlePaymentWebhookHandler::new(repo, payment, publisher.clone());
+
+        let cmd = HandlePaymentWebhookCommand {
+            payload: vec![],
+            signature: "valid".to_string(),
+        };
+
+        let result = handler.handle(cmd).await.unwrap();
+        assert!(matches!(result, HandlePaymentWebhookResult::Acknowledged));
+        assert!(publisher.published_events().is_empty());
+    }
+
     // ════════════════════════════════════════════════════════════════════════════
     // Error Tests
     // ════════════════════════════════════════════════════════════════════════════
@@ -945,4 +1467,70 @@ mod tests {
         assert!(matches!(result, HandlePaymentWebhookResult::Ignored));
         assert!(publisher.published_events().is_empty());
     }
+
+    // ════════════════════════════════════════════════════════════════════════════
+    // SLO Recording Tests
+    // ════════════════════════════════════════════════════════════════════════════
+
+    #[derive(Default)]
+    struct RecordingSlo {
+        outcomes: Mutex<Vec<(Sli, bool)>>,
+    }
+
+    impl SloRecorder for RecordingSlo {
+        fn record_outcome(&self, sli: Sli, good: bool) {
+            self.outcomes.lock().unwrap().push((sli, good));
+        }
+
+        fn record_latency(&self, _sli: Sli, _latency: std::time::Duration) {}
+    }
+
+    #[tokio::test]
+    async fn records_webhook_outcome_for_slo() {
+        let event = WebhookEvent {
+            id: "evt_unknown".to_string(),
+            event_type: WebhookEventType::Unknown("customer.created".to_string()),
+            data: WebhookEventData::Raw {
+                json: "{}".to_string(),
+            },
+            created_at: 1234567890,
+        };
+        let slo = Arc::new(RecordingSlo::default());
+        let handler = HandlePaymentWebhookHandler::new(
+            Arc::new(MockMembershipRepository::new()),
+            Arc::new(MockPaymentProvider::with_event(event)),
+            Arc::new(MockEventPublisher::new()),
+        )
+        .with_slo_recorder(slo.clone());
+
+        let cmd = HandlePaymentWebhookCommand {
+            payload: vec![],
+            signature: "valid".to_string(),
+        };
+        handler.handle(cmd).await.unwrap();
+
+        assert_eq!(
+            *slo.outcomes.lock().unwrap(),
+            vec![(Sli::WebhookProcessing, true)]
+        );
+    }
+
+    #[tokio::test]
+    async fn invalid_signature_does_not_count_against_slo() {
+        let slo = Arc::new(RecordingSlo::default());
+        let handler = HandlePaymentWebhookHandler::new(
+            Arc::new(MockMembershipRepository::new()),
+            Arc::new(MockPaymentProvider::failing()),
+            Arc::new(MockEventPublisher::new()),
+        )
+        .with_slo_recorder(slo.clone());
+
+        let cmd = HandlePaymentWebhookCommand {
+            payload: vec![],
+            signature: "invalid".to_string(),
+        };
+        assert!(handler.handle(cmd).await.is_err());
+
+        assert!(slo.outcomes.lock().unwrap().is_empty());
+    }
 }
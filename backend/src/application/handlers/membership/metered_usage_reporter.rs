@@ -0,0 +1,426 @@
+//! MeteredUsageReporter - Background job that bills AI token usage to
+//! metered subscriptions.
+//!
+//! Once a day the reporter totals each user's tokens for the previous UTC
+//! day and reports them against the metered item of their subscription.
+//! Reporting is idempotent at three levels: days already in the
+//! `UsageReportRepository` ledger are skipped, every report carries a key
+//! derived from the user and day, and the provider sets rather than adds
+//! the day's quantity. A day that failed is retried on the next run.
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use tokio::sync::watch;
+use tokio::time;
+
+use crate::domain::foundation::{Timestamp, UserId};
+use crate::domain::membership::MembershipError;
+use crate::ports::{
+    MembershipRepository, MeteredUsageReport, PaymentProvider, ReportedUsage,
+    UsageReportRepository, UsageTracker,
+};
+
+/// Seconds in a UTC day.
+pub(crate) const SECONDS_PER_DAY: u64 = 24 * 60 * 60;
+
+/// Outcome of reporting one day of usage.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct MeteredUsageRun {
+    /// Users whose usage was reported.
+    pub reported: usize,
+    /// Users whose day was already in the ledger.
+    pub already_reported: usize,
+    /// Users without a billable subscription.
+    pub not_billable: usize,
+    /// Users whose report failed and will be retried.
+    pub failed: usize,
+}
+
+/// Reports each user's daily token usage to the payment provider.
+pub struct MeteredUsageReporter {
+    usage: Arc<dyn UsageTracker>,
+    memberships: Arc<dyn MembershipRepository>,
+    reports: Arc<dyn UsageReportRepository>,
+    payment_provider: Arc<dyn PaymentProvider>,
+}
+
+impl MeteredUsageReporter {
+    pub fn new(
+        usage: Arc<dyn UsageTracker>,
+        memberships: Arc<dyn MembershipRepository>,
+        reports: Arc<dyn UsageReportRepository>,
+        payment_provider: Arc<dyn PaymentProvider>,
+    ) -> Self {
+        Self {
+            usage,
+            memberships,
+            reports,
+            payment_provider,
+        }
+    }
+
+    /// Reports the previous UTC day every `report_interval` until shutdown
+    /// is signalled.
+    pub async fn run(
+        &self,
+        report_interval: Duration,
+        mut shutdown: watch::Receiver<bool>,
+    ) -> Result<(), MembershipError> {
+        let mut interval = time::interval(report_interval);
+
+        loop {
+            tokio::select! {
+                _ = shutdown.changed() => {
+                    if *shutdown.borrow() {
+                        return Ok(());
+                    }
+                }
+
+                _ = interval.tick() => {
+                    self.report_day(Timestamp::start_of_today().minus_days(1)).await?;
+                }
+            }
+        }
+    }
+
+    /// Reports every user's usage on the UTC day containing `day`.
+    ///
+    /// A failure for one user is logged and counted rather than returned,
+    /// so it does not hold up everyone else's billing.
+    #[tracing::instrument(name = "MeteredUsageReporter::report_day", skip_all)]
+    pub async fn report_day(&self, day: Timestamp) -> Result<MeteredUsageRun, MembershipError> {
+        let day = day.start_of_day();
+        let users = self
+            .usage
+            .list_users_with_usage(day, day_end(&day))
+            .await
+            .map_err(|e| MembershipError::infrastructure(e.to_string()))?;
+
+        let mut run = MeteredUsageRun::default();
+        for user_id in users {
+            match self.report_user_day(&user_id, &day).await {
+                Ok(UserReport::Reported) => run.reported += 1,
+                Ok(UserReport::AlreadyReported) => run.already_reported += 1,
+                Ok(UserReport::NotBillable) => run.not_billable += 1,
+                Err(e) => {
+                    tracing::warn!(user_id = %user_id, error = %e, "Failed to report metered usage");
+                    run.failed += 1;
+                }
+            }
+        }
+
+        tracing::info!(
+            reported = run.reported,
+            already_reported = run.already_reported,
+            not_billable = run.not_billable,
+            failed = run.failed,
+            "Metered usage reported"
+        );
+        Ok(run)
+    }
+
+    async fn report_user_day(
+        &self,
+        user_id: &UserId,
+        day: &Timestamp,
+    ) -> Result<UserReport, MembershipError> {
+        if self.reports.find(user_id, day).await?.is_some() {
+            return Ok(UserReport::AlreadyReported);
+        }
+
+        let subscription_id = match self.memberships.find_by_user_id(user_id).await? {
+            Some(membership) if membership.has_access() => membership.stripe_subscription_id,
+            _ => None,
+        };
+        let Some(subscription_id) = subscription_id else {
+            return Ok(UserReport::NotBillable);
+        };
+
+        let summary = self
+            .usage
+            .get_usage_summary(user_id, *day, day_end(day))
+            .await
+            .map_err(|e| MembershipError::infrastructure(e.to_string()))?;
+
+        let idempotency_key = ReportedUsage::idempotency_key_for(user_id, day);
+        self.payment_provider
+            .report_metered_usage(MeteredUsageReport {
+                subscription_id: subscription_id.clone(),
+                quantity: summary.total_tokens as u64,
+                timestamp: day.as_unix_secs() as i64,
+                idempotency_key: idempotency_key.clone(),
+            })
+            .await
+            .map_err(|e| MembershipError::payment_failed(e.message))?;
+
+        self.reports
+            .save(&ReportedUsage {
+                user_id: user_id.clone(),
+                usage_day: *day,
+                subscription_id,
+                tokens: summary.total_tokens as u64,
+                cost_cents: summary.total_cost_cents,
+                idempotency_key,
+                reported_at: Timestamp::now(),
+            })
+            .await?;
+
+        Ok(UserReport::Reported)
+    }
+}
+
+enum UserReport {
+    Reported,
+    AlreadyReported,
+    NotBillable,
+}
+
+/// Last second of the UTC day starting at `day`.
+pub(crate) fn day_end(day: &Timestamp) -> Timestamp {
+    day.plus_secs(SECONDS_PER_DAY - 1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Mutex;
+
+    use async_trait::async_trait;
+
+    use crate::adapters::{
+        InMemoryUsageReportRepository, InMemoryUsageTracker, MockPaymentProvider,
+    };
+    use crate::domain::foundation::{DomainError, MembershipId, SessionId};
+    use crate::domain::membership::{Membership, MembershipTier};
+    use crate::ports::{PaymentError, PaymentErrorCode, UsageRecord};
+
+    // ════════════════════════════════════════════════════════════════════════════
+    // Mock Implementation
+    // ════════════════════════════════════════════════════════════════════════════
+
+    #[derive(Default)]
+    struct MockMembershipRepository {
+        memberships: Mutex<Vec<Membership>>,
+    }
+
+    #[async_trait]
+    impl MembershipRepository for MockMembershipRepository {
+        async fn save(&self, membership: &Membership) -> Result<(), DomainError> {
+            self.memberships.lock().unwrap().push(membership.clone());
+            Ok(())
+        }
+
+        async fn update(&self, _membership: &Membership) -> Result<(), DomainError> {
+            Ok(())
+        }
+
+        async fn find_by_id(&self, _id: &MembershipId) -> Result<Option<Membership>, DomainError> {
+            Ok(None)
+        }
+
+        async fn find_by_user_id(
+            &self,
+            user_id: &UserId,
+        ) -> Result<Option<Membership>, DomainError> {
+            let memberships = self.memberships.lock().unwrap();
+            Ok(memberships.iter().find(|m| &m.user_id == user_id).cloned())
+        }
+
+        async fn find_expiring_within_days(
+            &self,
+            _days: u32,
+        ) -> Result<Vec<Membership>, DomainError> {
+            Ok(vec![])
+        }
+
+        async fn delete(&self, _id: &MembershipId) -> Result<(), DomainError> {
+            Ok(())
+        }
+
+        async fn find_by_stripe_subscription_id(
+            &self,
+            _subscription_id: &str,
+        ) -> Result<Option<Membership>, DomainError> {
+            Ok(None)
+        }
+
+        async fn find_by_stripe_customer_id(
+            &self,
+            _customer_id: &str,
+        ) -> Result<Option<Membership>, DomainError> {
+            Ok(None)
+        }
+    }
+
+    // ════════════════════════════════════════════════════════════════════════════
+    // Test Helpers
+    // ════════════════════════════════════════════════════════════════════════════
+
+    fn setup_repositories() -> (
+        Arc<InMemoryUsageTracker>,
+        Arc<MockMembershipRepository>,
+        Arc<InMemoryUsageReportRepository>,
+        Arc<MockPaymentProvider>,
+    ) {
+        (
+            Arc::new(InMemoryUsageTracker::new()),
+            Arc::new(MockMembershipRepository::default()),
+            Arc::new(InMemoryUsageReportRepository::new()),
+            Arc::new(MockPaymentProvider::with_active_subscription(
+                "cus_123", "sub_123",
+            )),
+        )
+    }
+
+    fn create_handler(
+        usage: Arc<InMemoryUsageTracker>,
+        memberships: Arc<MockMembershipRepository>,
+        reports: Arc<InMemoryUsageReportRepository>,
+        payments: Arc<MockPaymentProvider>,
+    ) -> MeteredUsageReporter {
+        MeteredUsageReporter::new(usage, memberships, reports, payments)
+    }
+
+    fn yesterday() -> Timestamp {
+        Timestamp::start_of_today().minus_days(1)
+    }
+
+    async fn subscribe(memberships: &MockMembershipRepository, user: &str) {
+        let mut membership = Membership::create_paid(
+            MembershipId::new(),
+            UserId::new(user).unwrap(),
+            MembershipTier::Monthly,
+            "cus_123".to_string(),
+        );
+        membership
+            .activate(
+                Timestamp::now(),
+                Timestamp::now().add_days(30),
+                Some("sub_123".to_string()),
+            )
+            .unwrap();
+        memberships.save(&membership).await.unwrap();
+    }
+
+    async fn use_tokens(usage: &InMemoryUsageTracker, user: &str, tokens: u32, at: Timestamp) {
+        let mut record = UsageRecord::new(
+            UserId::new(user).unwrap(),
+            SessionId::new(),
+            "openai",
+            "gpt-4",
+            tokens / 2,
+            tokens - tokens / 2,
+            3,
+            None,
+        );
+        record.occurred_at = at;
+        usage.record_usage(record).await.unwrap();
+    }
+
+    // ════════════════════════════════════════════════════════════════════════════
+    // Tests
+    // ════════════════════════════════════════════════════════════════════════════
+
+    #[tokio::test]
+    async fn reports_daily_token_total_for_subscribed_user() {
+        let (usage, memberships, reports, payments) = setup_repositories();
+        let reporter = create_handler(
+            usage.clone(),
+            memberships.clone(),
+            reports.clone(),
+            payments.clone(),
+        );
+        subscribe(&memberships, "alice").await;
+        use_tokens(&usage, "alice", 100, yesterday().plus_secs(60)).await;
+        use_tokens(&usage, "alice", 50, yesterday().plus_secs(7200)).await;
+        use_tokens(
+            &usage,
+            "alice",
+            999,
+            Timestamp::start_of_today().plus_secs(1),
+        )
+        .await;
+
+        let run = reporter.report_day(yesterday()).await.unwrap();
+
+        assert_eq!(run.reported, 1);
+        let sent = payments.usage_reports();
+        assert_eq!(sent.len(), 1);
+        assert_eq!(sent[0].subscription_id, "sub_123");
+        assert_eq!(sent[0].quantity, 150);
+        assert_eq!(sent[0].timestamp, yesterday().as_unix_secs() as i64);
+
+        let alice = UserId::new("alice").unwrap();
+        let ledger = reports.find(&alice, &yesterday()).await.unwrap().unwrap();
+        assert_eq!(ledger.tokens, 150);
+        assert_eq!(ledger.cost_cents, 6);
+        assert_eq!(ledger.idempotency_key, sent[0].idempotency_key);
+    }
+
+    #[tokio::test]
+    async fn reporting_a_day_twice_bills_it_once() {
+        let (usage, memberships, reports, payments) = setup_repositories();
+        let reporter = create_handler(
+            usage.clone(),
+            memberships.clone(),
+            reports.clone(),
+            payments.clone(),
+        );
+        subscribe(&memberships, "alice").await;
+        use_tokens(&usage, "alice", 100, yesterday().plus_secs(60)).await;
+
+        reporter.report_day(yesterday()).await.unwrap();
+        let second = reporter.report_day(yesterday()).await.unwrap();
+
+        assert_eq!(second.reported, 0);
+        assert_eq!(second.already_reported, 1);
+        assert_eq!(payments.call_count("report_metered_usage"), 1);
+    }
+
+    #[tokio::test]
+    async fn skips_users_without_a_subscription() {
+        let (usage, memberships, reports, payments) = setup_repositories();
+        let reporter = create_handler(
+            usage.clone(),
+            memberships.clone(),
+            reports.clone(),
+            payments.clone(),
+        );
+        use_tokens(&usage, "bob", 100, yesterday().plus_secs(60)).await;
+
+        let run = reporter.report_day(yesterday()).await.unwrap();
+
+        assert_eq!(run.not_billable, 1);
+        assert!(payments.usage_reports().is_empty());
+    }
+
+    #[tokio::test]
+    async fn failed_report_is_left_out_of_the_ledger_for_retry() {
+        let (usage, memberships, reports, payments) = setup_repositories();
+        let reporter = create_handler(
+            usage.clone(),
+            memberships.clone(),
+            reports.clone(),
+            payments.clone(),
+        );
+        subscribe(&memberships, "alice").await;
+        use_tokens(&usage, "alice", 100, yesterday().plus_secs(60)).await;
+        payments.set_method_error(
+            "report_metered_usage",
+            PaymentError::new(PaymentErrorCode::NetworkError, "timeout"),
+        );
+
+        let run = reporter.report_day(yesterday()).await.unwrap();
+        assert_eq!(run.failed, 1);
+        assert!(reports
+            .list_between(&yesterday(), &yesterday())
+            .await
+            .unwrap()
+            .is_empty());
+
+        payments.clear_errors();
+        let retry = reporter.report_day(yesterday()).await.unwrap();
+        assert_eq!(retry.reported, 1);
+    }
+}
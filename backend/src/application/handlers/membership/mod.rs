@@ -5,24 +5,33 @@
 //! ## Commands
 //! - Creating free memberships via promo codes
 //! - Creating paid memberships via checkout
+//! - Converting trials to paid memberships without a new checkout
 //! - Cancelling memberships
 //! - Processing payment webhooks
+//! - Reporting AI token usage to metered subscriptions (background job)
 //!
 //! ## Queries
 //! - Get membership details
 //! - Check user access
+//! - Get current quotas and rate limits
 //! - Get membership statistics (admin)
+//! - Reconcile billed usage with tracked usage (admin)
 
 mod cancel_membership;
 mod check_access;
+mod convert_trial;
 mod create_free_membership;
 mod create_paid_membership;
+mod get_limits;
 mod get_membership;
 mod get_membership_stats;
 mod handle_payment_webhook;
+mod metered_usage_reporter;
+mod reconcile_metered_usage;
 
 // Commands
 pub use cancel_membership::{CancelMembershipCommand, CancelMembershipHandler, CancelMembershipResult};
+pub use convert_trial::{ConvertTrialCommand, ConvertTrialHandler, ConvertTrialResult};
 pub use create_free_membership::{
     CreateFreeMembershipCommand, CreateFreeMembershipHandler, CreateFreeMembershipResult,
 };
@@ -32,8 +41,16 @@ pub use create_paid_membership::{
 pub use handle_payment_webhook::{
     HandlePaymentWebhookCommand, HandlePaymentWebhookHandler, HandlePaymentWebhookResult,
 };
+pub use metered_usage_reporter::{MeteredUsageReporter, MeteredUsageRun};
 
 // Queries
 pub use check_access::{CheckAccessHandler, CheckAccessQuery, CheckAccessResult};
+pub use get_limits::{
+    AiBudget, GetLimitsHandler, GetLimitsQuery, GetLimitsResult, RateLimitBucket,
+};
 pub use get_membership::{GetMembershipHandler, GetMembershipQuery, GetMembershipResult};
 pub use get_membership_stats::{GetMembershipStatsHandler, GetMembershipStatsQuery, GetMembershipStatsResult};
+pub use reconcile_metered_usage::{
+    ReconcileMeteredUsageHandler, ReconcileMeteredUsageQuery, ReconcileMeteredUsageResult,
+    UsageDiscrepancy, MAX_RECONCILIATION_DAYS,
+};
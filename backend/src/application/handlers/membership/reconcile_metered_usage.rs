@@ -0,0 +1,396 @@
+//! ReconcileMeteredUsageHandler - Query handler comparing billed usage with
+//! tracked usage.
+//!
+//! The usage ledger records what was reported to the payment provider; the
+//! `UsageTracker` holds what users actually consumed. Usage recorded after
+//! a day was reported, or a day the reporter never reached, shows up as a
+//! discrepancy for an operator to review.
+
+use std::sync::Arc;
+
+use serde::Serialize;
+
+use crate::domain::foundation::{Timestamp, UserId};
+use crate::domain::membership::MembershipError;
+use crate::ports::{MembershipRepository, UsageReportRepository, UsageTracker};
+
+use super::metered_usage_reporter::{day_end, SECONDS_PER_DAY};
+
+/// Longest range a single reconciliation may cover, in days.
+pub const MAX_RECONCILIATION_DAYS: u64 = 31;
+
+/// Query to reconcile metered usage over a range of UTC days.
+#[derive(Debug, Clone)]
+pub struct ReconcileMeteredUsageQuery {
+    /// First day to check; any time within the day.
+    pub from_day: Timestamp,
+    /// Last day to check, inclusive; any time within the day.
+    pub to_day: Timestamp,
+}
+
+/// A user's day where billed and tracked usage disagree.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct UsageDiscrepancy {
+    pub user_id: UserId,
+    pub usage_day: Timestamp,
+    /// Tokens the usage tracker holds for the day.
+    pub tracked_tokens: u64,
+    /// Tokens billed for the day, or `None` if the day was never reported.
+    pub reported_tokens: Option<u64>,
+}
+
+/// Result of a reconciliation.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ReconcileMeteredUsageResult {
+    /// Days checked.
+    pub days: u64,
+    /// Reports in the ledger for those days.
+    pub reports_checked: usize,
+    /// Disagreements, oldest day first.
+    pub discrepancies: Vec<UsageDiscrepancy>,
+}
+
+/// Handler for reconciling metered usage.
+///
+/// Users without a billable subscription are never reported, so their
+/// unreported days are not discrepancies.
+pub struct ReconcileMeteredUsageHandler {
+    usage: Arc<dyn UsageTracker>,
+    memberships: Arc<dyn MembershipRepository>,
+    reports: Arc<dyn UsageReportRepository>,
+}
+
+impl ReconcileMeteredUsageHandler {
+    pub fn new(
+        usage: Arc<dyn UsageTracker>,
+        memberships: Arc<dyn MembershipRepository>,
+        reports: Arc<dyn UsageReportRepository>,
+    ) -> Self {
+        Self {
+            usage,
+            memberships,
+            reports,
+        }
+    }
+
+    #[tracing::instrument(name = "ReconcileMeteredUsageHandler::handle", skip_all)]
+    pub async fn handle(
+        &self,
+        query: ReconcileMeteredUsageQuery,
+    ) -> Result<ReconcileMeteredUsageResult, MembershipError> {
+        let from_day = query.from_day.start_of_day();
+        let to_day = query.to_day.start_of_day();
+        if to_day < from_day {
+            return Err(MembershipError::validation(
+                "to_day",
+                "must not be before from_day",
+            ));
+        }
+        let days = (to_day.as_unix_secs() - from_day.as_unix_secs()) / SECONDS_PER_DAY + 1;
+        if days > MAX_RECONCILIATION_DAYS {
+            return Err(MembershipError::validation(
+                "to_day",
+                format!("range must not exceed {} days", MAX_RECONCILIATION_DAYS),
+            ));
+        }
+
+        let reports = self.reports.list_between(&from_day, &to_day).await?;
+        let mut discrepancies = Vec::new();
+
+        for offset in 0..days {
+            let day = from_day.plus_secs(offset * SECONDS_PER_DAY);
+            let users = self
+                .usage
+                .list_users_with_usage(day, day_end(&day))
+                .await
+                .map_err(|e| MembershipError::infrastructure(e.to_string()))?;
+
+            for user_id in users {
+                let report = reports
+                    .iter()
+                    .find(|r| r.user_id == user_id && r.usage_day == day);
+                if report.is_none() && !self.is_billable(&user_id).await? {
+                    continue;
+                }
+
+                let tracked_tokens = self
+                    .usage
+                    .get_usage_summary(&user_id, day, day_end(&day))
+                    .await
+                    .map_err(|e| MembershipError::infrastructure(e.to_string()))?
+                    .total_tokens as u64;
+                let reported_tokens = report.map(|r| r.tokens);
+                if reported_tokens != Some(tracked_tokens) {
+                    discrepancies.push(UsageDiscrepancy {
+                        user_id,
+                        usage_day: day,
+                        tracked_tokens,
+                        reported_tokens,
+                    });
+                }
+            }
+        }
+
+        Ok(ReconcileMeteredUsageResult {
+            days,
+            reports_checked: reports.len(),
+            discrepancies,
+        })
+    }
+
+    async fn is_billable(&self, user_id: &UserId) -> Result<bool, MembershipError> {
+        Ok(self
+            .memberships
+            .find_by_user_id(user_id)
+            .await?
+            .is_some_and(|m| m.has_access() && m.stripe_subscription_id.is_some()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Mutex;
+
+    use async_trait::async_trait;
+
+    use crate::adapters::{InMemoryUsageReportRepository, InMemoryUsageTracker};
+    use crate::domain::foundation::{DomainError, MembershipId, SessionId};
+    use crate::domain::membership::{Membership, MembershipTier};
+    use crate::ports::{ReportedUsage, UsageRecord};
+
+    // ════════════════════════════════════════════════════════════════════════════
+    // Mock Implementation
+    // ════════════════════════════════════════════════════════════════════════════
+
+    #[derive(Default)]
+    struct MockMembershipRepository {
+        memberships: Mutex<Vec<Membership>>,
+    }
+
+    #[async_trait]
+    impl MembershipRepository for MockMembershipRepository {
+        async fn save(&self, membership: &Membership) -> Result<(), DomainError> {
+            self.memberships.lock().unwrap().push(membership.clone());
+            Ok(())
+        }
+
+        async fn update(&self, _membership: &Membership) -> Result<(), DomainError> {
+            Ok(())
+        }
+
+        async fn find_by_id(&self, _id: &MembershipId) -> Result<Option<Membership>, DomainError> {
+            Ok(None)
+        }
+
+        async fn find_by_user_id(
+            &self,
+            user_id: &UserId,
+        ) -> Result<Option<Membership>, DomainError> {
+            let memberships = self.memberships.lock().unwrap();
+            Ok(memberships.iter().find(|m| &m.user_id == user_id).cloned())
+        }
+
+        async fn find_expiring_within_days(
+            &self,
+            _days: u32,
+        ) -> Result<Vec<Membership>, DomainError> {
+            Ok(vec![])
+        }
+
+        async fn delete(&self, _id: &MembershipId) -> Result<(), DomainError> {
+            Ok(())
+        }
+
+        async fn find_by_stripe_subscription_id(
+            &self,
+            _subscription_id: &str,
+        ) -> Result<Option<Membership>, DomainError> {
+            Ok(None)
+        }
+
+        async fn find_by_stripe_customer_id(
+            &self,
+            _customer_id: &str,
+        ) -> Result<Option<Membership>, DomainError> {
+            Ok(None)
+        }
+    }
+
+    // ════════════════════════════════════════════════════════════════════════════
+    // Test Helpers
+    // ════════════════════════════════════════════════════════════════════════════
+
+    fn setup_repositories() -> (
+        Arc<InMemoryUsageTracker>,
+        Arc<MockMembershipRepository>,
+        Arc<InMemoryUsageReportRepository>,
+    ) {
+        (
+            Arc::new(InMemoryUsageTracker::new()),
+            Arc::new(MockMembershipRepository::default()),
+            Arc::new(InMemoryUsageReportRepository::new()),
+        )
+    }
+
+    fn create_handler(
+        usage: Arc<InMemoryUsageTracker>,
+        memberships: Arc<MockMembershipRepository>,
+        reports: Arc<InMemoryUsageReportRepository>,
+    ) -> ReconcileMeteredUsageHandler {
+        ReconcileMeteredUsageHandler::new(usage, memberships, reports)
+    }
+
+    fn yesterday() -> Timestamp {
+        Timestamp::start_of_today().minus_days(1)
+    }
+
+    fn user(id: &str) -> UserId {
+        UserId::new(id).unwrap()
+    }
+
+    async fn subscribe(memberships: &MockMembershipRepository, user_id: &str) {
+        let mut membership = Membership::create_paid(
+            MembershipId::new(),
+            user(user_id),
+            MembershipTier::Monthly,
+            "cus_123".to_string(),
+        );
+        membership
+            .activate(
+                Timestamp::now(),
+                Timestamp::now().add_days(30),
+                Some("sub_123".to_string()),
+            )
+            .unwrap();
+        memberships.save(&membership).await.unwrap();
+    }
+
+    async fn use_tokens(usage: &InMemoryUsageTracker, user_id: &str, tokens: u32, at: Timestamp) {
+        let mut record = UsageRecord::new(
+            user(user_id),
+            SessionId::new(),
+            "openai",
+            "gpt-4",
+            tokens,
+            0,
+            1,
+            None,
+        );
+        record.occurred_at = at;
+        usage.record_usage(record).await.unwrap();
+    }
+
+    async fn reported(
+        reports: &InMemoryUsageReportRepository,
+        user_id: &str,
+        day: Timestamp,
+        tokens: u64,
+    ) {
+        reports
+            .save(&ReportedUsage {
+                user_id: user(user_id),
+                usage_day: day,
+                subscription_id: "sub_123".to_string(),
+                tokens,
+                cost_cents: 1,
+                idempotency_key: ReportedUsage::idempotency_key_for(&user(user_id), &day),
+                reported_at: Timestamp::now(),
+            })
+            .await
+            .unwrap();
+    }
+
+    fn query(from_day: Timestamp, to_day: Timestamp) -> ReconcileMeteredUsageQuery {
+        ReconcileMeteredUsageQuery { from_day, to_day }
+    }
+
+    // ════════════════════════════════════════════════════════════════════════════
+    // Tests
+    // ════════════════════════════════════════════════════════════════════════════
+
+    #[tokio::test]
+    async fn matching_reports_have_no_discrepancies() {
+        let (usage, memberships, reports) = setup_repositories();
+        let handler = create_handler(usage.clone(), memberships.clone(), reports.clone());
+        subscribe(&memberships, "alice").await;
+        use_tokens(&usage, "alice", 100, yesterday().plus_secs(60)).await;
+        reported(&reports, "alice", yesterday(), 100).await;
+
+        let result = handler
+            .handle(query(yesterday(), yesterday()))
+            .await
+            .unwrap();
+
+        assert_eq!(result.days, 1);
+        assert_eq!(result.reports_checked, 1);
+        assert!(result.discrepancies.is_empty());
+    }
+
+    #[tokio::test]
+    async fn flags_usage_recorded_after_the_report() {
+        let (usage, memberships, reports) = setup_repositories();
+        let handler = create_handler(usage.clone(), memberships.clone(), reports.clone());
+        subscribe(&memberships, "alice").await;
+        use_tokens(&usage, "alice", 100, yesterday().plus_secs(60)).await;
+        use_tokens(&usage, "alice", 40, yesterday().plus_secs(120)).await;
+        reported(&reports, "alice", yesterday(), 100).await;
+
+        let result = handler
+            .handle(query(yesterday(), yesterday()))
+            .await
+            .unwrap();
+
+        assert_eq!(
+            result.discrepancies,
+            vec![UsageDiscrepancy {
+                user_id: user("alice"),
+                usage_day: yesterday(),
+                tracked_tokens: 140,
+                reported_tokens: Some(100),
+            }]
+        );
+    }
+
+    #[tokio::test]
+    async fn flags_unreported_days_only_for_billable_users() {
+        let (usage, memberships, reports) = setup_repositories();
+        let handler = create_handler(usage.clone(), memberships.clone(), reports.clone());
+        subscribe(&memberships, "alice").await;
+        let two_days_ago = yesterday().minus_days(1);
+        use_tokens(&usage, "alice", 100, two_days_ago.plus_secs(60)).await;
+        use_tokens(&usage, "bob", 70, two_days_ago.plus_secs(60)).await;
+
+        let result = handler
+            .handle(query(two_days_ago, yesterday()))
+            .await
+            .unwrap();
+
+        assert_eq!(result.days, 2);
+        assert_eq!(result.discrepancies.len(), 1);
+        assert_eq!(result.discrepancies[0].user_id, user("alice"));
+        assert_eq!(result.discrepancies[0].reported_tokens, None);
+    }
+
+    #[tokio::test]
+    async fn rejects_reversed_and_oversized_ranges() {
+        let (usage, memberships, reports) = setup_repositories();
+        let handler = create_handler(usage.clone(), memberships.clone(), reports.clone());
+
+        let reversed = handler
+            .handle(query(yesterday(), yesterday().minus_days(1)))
+            .await;
+        assert!(matches!(
+            reversed,
+            Err(MembershipError::ValidationFailed { .. })
+        ));
+
+        let oversized = handler
+            .handle(query(
+                yesterday().minus_days(MAX_RECONCILIATION_DAYS as i64),
+                yesterday(),
+            ))
+            .await;
+        assert!(oversized.is_err());
+    }
+}
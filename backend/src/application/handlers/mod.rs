@@ -4,21 +4,47 @@
 
 pub mod ai_engine;
 pub mod analysis;
+pub mod audit;
+pub mod auth;
+pub mod backup;
+pub mod consent;
 pub mod conversation;
 pub mod cycle;
 pub mod dashboard;
+pub mod demo;
+pub mod integration;
 pub mod membership;
+pub mod organization;
+pub mod privacy;
+pub mod profile;
+pub mod projection;
+pub mod provisioning;
+pub mod search;
 pub mod session;
 
 pub use cycle::{
     // Commands
+    AddAttachmentCommand, AddAttachmentError, AddAttachmentHandler, AddAttachmentResult,
     ArchiveCycleCommand, ArchiveCycleError, ArchiveCycleHandler, ArchiveCycleResult,
     BranchCycleCommand, BranchCycleError, BranchCycleHandler, BranchCycleResult,
     CompleteComponentCommand, CompleteComponentError, CompleteComponentHandler,
     CompleteComponentResult, CompleteCycleCommand, CompleteCycleError, CompleteCycleHandler,
-    CompleteCycleResult, NavigateToComponentCommand, NavigateToComponentError, NavigateToComponentHandler,
-    NavigateToComponentResult, StartComponentCommand, StartComponentError, StartComponentHandler,
-    StartComponentResult,
+    CompleteCycleResult, DraftImportFromTextCommand, DraftImportFromTextError,
+    DraftImportFromTextHandler, ExportToGoogleDocsCommand, ExportToGoogleDocsError,
+    ExportToGoogleDocsHandler, ExportToGoogleDocsResult, ImportConsequencesCommand,
+    ImportConsequencesError, ImportConsequencesHandler, ImportConsequencesResult,
+    NavigateToComponentCommand, NavigateToComponentError, NavigateToComponentHandler,
+    NavigateToComponentResult, PublishDocumentCommand, PublishDocumentError,
+    PublishDocumentHandler, PublishDocumentResult, ReanalyzeCycleCommand, ReanalyzeCycleError,
+    ReanalyzeCycleHandler, ReanalyzeCycleResult, RemoveAttachmentCommand, RemoveAttachmentError,
+    RemoveAttachmentHandler, ResolveImportDraftCommand, ResolveImportDraftError,
+    ResolveImportDraftHandler, ResolveImportDraftResult, ShareToSlackCommand,
+    ShareToSlackError, ShareToSlackHandler, ShareToSlackResult, ShareToTeamsCommand,
+    ShareToTeamsError, ShareToTeamsHandler, ShareToTeamsResult,
+    StartComponentCommand, StartComponentError, StartComponentHandler,
+    StartComponentResult, SyncDocumentCommand, SyncDocumentError, SyncDocumentHandler,
+    SyncDocumentResult, UnpublishDocumentCommand, UnpublishDocumentError,
+    UnpublishDocumentHandler, UnpublishDocumentResult,
     UpdateComponentOutputCommand, UpdateComponentOutputError, UpdateComponentOutputHandler,
     UpdateComponentOutputResult,
     // Events
@@ -27,32 +53,147 @@ pub use cycle::{
     CycleArchivedEvent, CycleBranchedEvent, CycleCompletedEvent, CycleCreatedEvent,
     NavigatedToComponentEvent,
     // Queries
+    ExportCycleDocumentError, ExportCycleDocumentHandler, ExportCycleDocumentQuery,
+    ExportCycleDocumentResult,
+    GetAttachmentContentHandler, GetAttachmentContentQuery, GetAttachmentContentResult,
     GetComponentHandler, GetComponentQuery, GetComponentResult,
     GetCycleHandler, GetCycleQuery, GetCycleResult,
+    CycleGraph, CycleGraphEdge, CycleGraphNode, GetCycleGraphHandler, GetCycleGraphQuery,
+    GetCycleGraphResult,
+    GetCycleAtVersionQuery, GetCycleHistoryHandler, GetCycleHistoryQuery, GetCycleHistoryResult,
     GetCycleTreeHandler, GetCycleTreeQuery, GetCycleTreeResult,
+    GetDocumentDiffError, GetDocumentDiffHandler, GetDocumentDiffQuery, GetDocumentDiffResult,
+    ListAttachmentsHandler, ListAttachmentsQuery, ListAttachmentsResult,
+    ListImportDraftsError, ListImportDraftsHandler, ListImportDraftsQuery,
+    ViewPublishedDocumentError, ViewPublishedDocumentHandler, ViewPublishedDocumentQuery,
+    // Event handlers
+    CycleDocumentMailer, CycleViewInvalidator,
+};
+pub use consent::{
+    // Commands
+    RecordConsentCommand, RecordConsentHandler, RecordConsentResult,
+    // Queries
+    CheckAiConsentHandler, CheckAiConsentQuery, ListConsentsHandler, ListConsentsQuery,
+    ListConsentsResult,
+};
+pub use auth::{
+    // Commands
+    LogoutAllCommand, LogoutAllHandler, LogoutAllResult,
 };
 pub use dashboard::{
     // Queries
     CompareCyclesHandler, CompareCyclesQuery, CompareCyclesResult,
     GetComponentDetailHandler, GetComponentDetailQuery, GetComponentDetailResult,
     GetDashboardOverviewHandler, GetDashboardOverviewQuery, GetDashboardOverviewResult,
+    GetDecisionTreesHandler, GetDecisionTreesQuery, GetDecisionTreesResult,
+    GetGroupComparisonHandler, GetGroupComparisonQuery, GetGroupComparisonResult,
+    GetWeightedComparisonHandler, GetWeightedComparisonQuery, GetWeightedComparisonResult,
+};
+pub use demo::{
+    SeedDemoDataCommand, SeedDemoDataError, SeedDemoDataHandler, SeedDemoDataResult,
+    DEMO_SCENARIO_TITLES,
+};
+pub use integration::{
+    // Commands
+    CreateIntegrationCommand, CreateIntegrationHandler, DeleteIntegrationCommand,
+    DeleteIntegrationHandler, IntegrationSpec, ManageIntegrationError, UpdateIntegrationCommand,
+    UpdateIntegrationHandler,
+    // Queries
+    ListIntegrationsHandler,
+    // Event handlers
+    IntegrationDispatcher,
 };
 pub use membership::{
     // Commands
     CancelMembershipCommand, CancelMembershipHandler, CancelMembershipResult,
+    ConvertTrialCommand, ConvertTrialHandler, ConvertTrialResult,
     CreateFreeMembershipCommand, CreateFreeMembershipHandler, CreateFreeMembershipResult,
     CreatePaidMembershipCommand, CreatePaidMembershipHandler, CreatePaidMembershipResult,
     HandlePaymentWebhookCommand, HandlePaymentWebhookHandler, HandlePaymentWebhookResult,
+    MeteredUsageReporter, MeteredUsageRun,
     // Queries
     CheckAccessHandler, CheckAccessQuery, CheckAccessResult,
+    AiBudget, GetLimitsHandler, GetLimitsQuery, GetLimitsResult, RateLimitBucket,
     GetMembershipHandler, GetMembershipQuery, GetMembershipResult,
     GetMembershipStatsHandler, GetMembershipStatsQuery, GetMembershipStatsResult,
+    ReconcileMeteredUsageHandler, ReconcileMeteredUsageQuery, ReconcileMeteredUsageResult,
+    UsageDiscrepancy, MAX_RECONCILIATION_DAYS,
+};
+pub use organization::{
+    // Commands
+    AddOrganizationMemberCommand, AddOrganizationMemberHandler, CreateOrganizationCommand,
+    CreateOrganizationHandler, ManageOrganizationError, RemoveOrganizationMemberCommand,
+    RemoveOrganizationMemberHandler, SetOrganizationBillingCommand, SetOrganizationBillingHandler,
+    ShareSessionWithOrganizationCommand, ShareSessionWithOrganizationHandler,
+    // Queries
+    ListOrganizationSessionsHandler, ListOrganizationsHandler, OrganizationResolver,
+};
+pub use privacy::{
+    // Commands
+    RequestDataExportCommand, RequestDataExportError, RequestDataExportHandler,
+    RequestDataExportResult,
+    DeleteAllUserDataCommand, DeleteAllUserDataError, DeleteAllUserDataHandler,
+    DeleteAllUserDataResult,
+    // Workers
+    UserDataExporter, DataErasureWorker,
+    // Data sources
+    DecisionHistorySource, SessionHistorySource, UsageHistorySource,
+    // Erasers
+    BillingEraser, ConversationEraser, DocumentEraser, ProfileEraser, SessionEraser, UsageEraser,
+};
+pub use profile::{
+    // Commands
+    OutcomeSurveyError, RecordOutcomeCommand, RecordOutcomeError, RecordOutcomeHandler,
+    RecordProfileUpdateCommand, RecordProfileUpdateHandler, RespondToOutcomeSurveyCommand,
+    RespondToOutcomeSurveyHandler, RollbackProfileCommand, RollbackProfileError,
+    RollbackProfileHandler, SetAdaptiveStyleCommand, SetAdaptiveStyleHandler,
+    SetExpectedOutcomeCommand, SetExpectedOutcomeHandler, UpdateTeamProfileSettingsCommand,
+    UpdateTeamProfileSettingsHandler,
+    // Queries
+    GetBenchmarksError, GetBenchmarksHandler, GetBenchmarksQuery, GetCalendarFeedHandler,
+    GetDecisionAnalyticsError, GetDecisionAnalyticsHandler, GetDecisionAnalyticsQuery,
+    GetProfileHistoryHandler, GetProfileHistoryQuery, ProfileHistoryEntry,
+    GetTeamProfileError, GetTeamProfileHandler, GetTeamProfileQuery,
+    // Workers
+    BenchmarkAggregator, OutcomeReminderMailer, OUTCOME_SURVEY_PATH,
+    // Agent context
+    AdaptiveStyleResolver, TeamProfileContextProvider,
+};
+pub use audit::{
+    AuditVerification, CommandAuditor, ListAuditEntriesHandler, VerifyAuditLogHandler,
+};
+pub use backup::{BackupError, BackupService};
+pub use projection::{
+    ActivityEntry, ActivityFeedProjection, AnalysisCacheProjection, CachedAnalysis,
+    CycleProgressEntry, DashboardProjection, DqTrendPoint, DqTrendProjection, ProjectionError,
+    ProjectionRunner, ProjectionStatus,
+};
+pub use provisioning::{
+    // Commands
+    DeprovisionUserCommand, DeprovisionUserHandler, ManageProvisionedUserError,
+    ProvisionUserCommand, ProvisionUserHandler, UpdateProvisionedUserCommand,
+    UpdateProvisionedUserHandler,
+    // Queries
+    GetProvisionedUsersHandler, MAX_PROVISIONED_USERS_PAGE,
+};
+pub use search::{
+    // Queries
+    FindSimilarDecisionsError, FindSimilarDecisionsHandler, FindSimilarDecisionsQuery,
+    SimilarDecisionView, SimilarTo,
+    // Event handlers
+    DecisionEmbeddingIndexer,
 };
 pub use session::{
     ArchiveSessionCommand, ArchiveSessionHandler, ArchiveSessionResult,
     CreateSessionCommand, CreateSessionHandler, CreateSessionResult,
     CycleCreated, SessionCycleTracker,
     RenameSessionCommand, RenameSessionHandler, RenameSessionResult,
+    GetAutoArchiveSettingsHandler, GetAutoArchiveSettingsQuery,
+    SetAutoArchiveExclusionCommand, SetAutoArchiveExclusionHandler,
+    // Workers
+    ArchivalRun, SessionArchiver, ColdStorageOffloader, SessionColdStorage,
+    // Cold storage rehydration
+    RehydratingCycleReader, RehydratingCycleRepository,
 };
 pub use ai_engine::{
     // Commands
@@ -72,7 +213,7 @@ pub use conversation::{
     // Queries
     GetConversationHandler, GetConversationQuery,
     // Types
-    MessageId, MessageRole, StoredMessage, StreamEvent,
+    MessageId, MessageRole, MessageStream, StoredMessage, StreamEvent,
     // Ports
     ComponentOwnershipChecker, ConversationRepository, ConversationRepositoryExt, ConversationRecord, OwnershipInfo,
 };
@@ -0,0 +1,1238 @@
+//! Organization management handlers - Create organizations, change their
+//! members and billing, and share sessions with them.
+//!
+//! Organizations a user does not belong to are reported as not found, so
+//! their existence is not revealed to outsiders.
+//!
+//! The paying membership has one seat per member. Member and billing
+//! changes update its subscription quantity before the organization is
+//! saved, so a payment provider failure leaves the organization unchanged.
+
+use std::sync::Arc;
+
+use crate::application::handlers::audit::CommandAuditor;
+use crate::domain::audit::{AuditRecord, AuditSummary};
+use crate::domain::foundation::{
+    CommandMetadata, DomainError, EventId, MembershipId, OrganizationId, SerializableDomainEvent,
+    SessionId, Timestamp, UserId,
+};
+use crate::domain::membership::MembershipEvent;
+use crate::domain::organization::{
+    Organization, OrganizationBillingChanged, OrganizationCreated, OrganizationError,
+    OrganizationMemberAdded, OrganizationMemberRemoved, OrganizationRole,
+};
+use crate::domain::session::Session;
+use crate::ports::{
+    EventPublisher, ListOptions, MembershipRepository, OrganizationRepository, PaymentProvider,
+    SessionList, SessionReader, SessionRepository,
+};
+
+/// Errors from managing organizations.
+#[derive(Debug, Clone)]
+pub enum ManageOrganizationError {
+    /// No organization with that ID has the user as a member.
+    NotFound(OrganizationId),
+    /// The change breaks an organization rule.
+    Invalid(OrganizationError),
+    /// No session with that ID belongs to the user.
+    SessionNotFound(SessionId),
+    /// The owner has no membership with access to pay for the organization.
+    NoActiveMembership,
+    /// Domain error.
+    Domain(DomainError),
+}
+
+impl std::fmt::Display for ManageOrganizationError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ManageOrganizationError::NotFound(id) => write!(f, "Organization {} not found", id),
+            ManageOrganizationError::Invalid(err) => write!(f, "{}", err),
+            ManageOrganizationError::SessionNotFound(id) => write!(f, "Session {} not found", id),
+            ManageOrganizationError::NoActiveMembership => {
+                write!(
+                    f,
+                    "An active membership is required to pay for an organization"
+                )
+            }
+            ManageOrganizationError::Domain(err) => write!(f, "{}", err),
+        }
+    }
+}
+
+impl std::error::Error for ManageOrganizationError {}
+
+impl From<DomainError> for ManageOrganizationError {
+    fn from(err: DomainError) -> Self {
+        ManageOrganizationError::Domain(err)
+    }
+}
+
+impl From<OrganizationError> for ManageOrganizationError {
+    fn from(err: OrganizationError) -> Self {
+        ManageOrganizationError::Invalid(err)
+    }
+}
+
+/// Loads an organization, treating ones the user is not in as missing.
+async fn find_joined(
+    organizations: &dyn OrganizationRepository,
+    user_id: &UserId,
+    id: OrganizationId,
+) -> Result<Organization, ManageOrganizationError> {
+    organizations
+        .find_by_id(&id)
+        .await?
+        .filter(|organization| organization.is_member(user_id))
+        .ok_or(ManageOrganizationError::NotFound(id))
+}
+
+async fn publish(
+    publisher: &dyn EventPublisher,
+    event: &impl SerializableDomainEvent,
+    metadata: &CommandMetadata,
+) -> Result<(), DomainError> {
+    let envelope = event
+        .to_envelope()
+        .with_correlation_id(metadata.correlation_id())
+        .with_user_id(metadata.user_id.to_string());
+    publisher.publish(envelope).await
+}
+
+/// Services that keep a paying membership's seats in step with its
+/// organization.
+struct SeatBilling {
+    memberships: Arc<dyn MembershipRepository>,
+    payment_provider: Arc<dyn PaymentProvider>,
+    event_publisher: Arc<dyn EventPublisher>,
+}
+
+impl SeatBilling {
+    /// Sets the membership's seats, and its subscription's quantity, to
+    /// `seats`. Memberships without a subscription only record the count.
+    async fn sync(
+        &self,
+        membership_id: &MembershipId,
+        seats: u32,
+        metadata: &CommandMetadata,
+    ) -> Result<(), DomainError> {
+        let Some(mut membership) = self.memberships.find_by_id(membership_id).await? else {
+            return Ok(());
+        };
+        if membership.seats == seats {
+            return Ok(());
+        }
+
+        if let Some(subscription_id) = &membership.stripe_subscription_id {
+            self.payment_provider
+                .update_subscription_quantity(subscription_id, seats)
+                .await?;
+        }
+        let previous_seats = membership.set_seats(seats)?;
+        self.memberships.update(&membership).await?;
+
+        let event = MembershipEvent::SeatsChanged {
+            event_id: EventId::new(),
+            membership_id: membership.id,
+            user_id: membership.user_id.clone(),
+            previous_seats,
+            new_seats: seats,
+            occurred_at: membership.updated_at,
+        };
+        publish(self.event_publisher.as_ref(), &event, metadata).await
+    }
+}
+
+// ════════════════════════════════════════════════════════════════════════════════
+// Create Organization
+// ════════════════════════════════════════════════════════════════════════════════
+
+/// Command to create an organization owned by the user.
+#[derive(Debug, Clone)]
+pub struct CreateOrganizationCommand {
+    pub user_id: UserId,
+    pub name: String,
+}
+
+/// Handler that creates organizations.
+pub struct CreateOrganizationHandler {
+    organizations: Arc<dyn OrganizationRepository>,
+    event_publisher: Arc<dyn EventPublisher>,
+    auditor: CommandAuditor,
+}
+
+impl CreateOrganizationHandler {
+    pub fn new(
+        organizations: Arc<dyn OrganizationRepository>,
+        event_publisher: Arc<dyn EventPublisher>,
+    ) -> Self {
+        Self {
+            organizations,
+            event_publisher,
+            auditor: CommandAuditor::disabled(),
+        }
+    }
+
+    /// Records each command in the audit log.
+    pub fn with_auditor(mut self, auditor: CommandAuditor) -> Self {
+        self.auditor = auditor;
+        self
+    }
+
+    #[tracing::instrument(name = "CreateOrganizationHandler::handle", skip_all)]
+    pub async fn handle(
+        &self,
+        cmd: CreateOrganizationCommand,
+        metadata: CommandMetadata,
+    ) -> Result<Organization, ManageOrganizationError> {
+        let organization = Organization::new(cmd.name, cmd.user_id.clone())?;
+        self.organizations.save(&organization).await?;
+
+        let event = OrganizationCreated {
+            event_id: EventId::new(),
+            organization_id: organization.id,
+            name: organization.name.clone(),
+            owner_id: cmd.user_id,
+            created_at: organization.created_at,
+        };
+        publish(self.event_publisher.as_ref(), &event, &metadata).await?;
+
+        self.auditor
+            .record(
+                AuditRecord::new(
+                    &metadata,
+                    "CreateOrganization",
+                    "organization",
+                    organization.id,
+                )
+                .with_after(organization.audit_summary()),
+            )
+            .await;
+
+        Ok(organization)
+    }
+}
+
+// ════════════════════════════════════════════════════════════════════════════════
+// Add / Remove Members
+// ════════════════════════════════════════════════════════════════════════════════
+
+/// Command to add a user to an organization.
+#[derive(Debug, Clone)]
+pub struct AddOrganizationMemberCommand {
+    pub user_id: UserId,
+    pub organization_id: OrganizationId,
+    pub member_id: UserId,
+    pub role: OrganizationRole,
+}
+
+/// Handler that adds members.
+pub struct AddOrganizationMemberHandler {
+    organizations: Arc<dyn OrganizationRepository>,
+    seats: SeatBilling,
+    event_publisher: Arc<dyn EventPublisher>,
+    auditor: CommandAuditor,
+}
+
+impl AddOrganizationMemberHandler {
+    pub fn new(
+        organizations: Arc<dyn OrganizationRepository>,
+        memberships: Arc<dyn MembershipRepository>,
+        payment_provider: Arc<dyn PaymentProvider>,
+        event_publisher: Arc<dyn EventPublisher>,
+    ) -> Self {
+        Self {
+            organizations,
+            seats: SeatBilling {
+                memberships,
+                payment_provider,
+                event_publisher: event_publisher.clone(),
+            },
+            event_publisher,
+            auditor: CommandAuditor::disabled(),
+        }
+    }
+
+    /// Records each command in the audit log.
+    pub fn with_auditor(mut self, auditor: CommandAuditor) -> Self {
+        self.auditor = auditor;
+        self
+    }
+
+    #[tracing::instrument(name = "AddOrganizationMemberHandler::handle", skip_all)]
+    pub async fn handle(
+        &self,
+        cmd: AddOrganizationMemberCommand,
+        metadata: CommandMetadata,
+    ) -> Result<Organization, ManageOrganizationError> {
+        let mut organization = find_joined(
+            self.organizations.as_ref(),
+            &cmd.user_id,
+            cmd.organization_id,
+        )
+        .await?;
+        let before = organization.audit_summary();
+        organization.add_member(&cmd.user_id, cmd.member_id.clone(), cmd.role)?;
+        if let Some(membership_id) = &organization.billing_membership_id {
+            self.seats
+                .sync(membership_id, organization.seat_count(), &metadata)
+                .await?;
+        }
+        self.organizations.save(&organization).await?;
+
+        let event = OrganizationMemberAdded {
+            event_id: EventId::new(),
+            organization_id: organization.id,
+            user_id: cmd.member_id,
+            role: cmd.role,
+            added_by: cmd.user_id,
+            seat_count: organization.seat_count(),
+            added_at: Timestamp::now(),
+        };
+        publish(self.event_publisher.as_ref(), &event, &metadata).await?;
+
+        self.auditor
+            .record(
+                AuditRecord::new(
+                    &metadata,
+                    "AddOrganizationMember",
+                    "organization",
+                    organization.id,
+                )
+                .with_before(before)
+                .with_after(organization.audit_summary()),
+            )
+            .await;
+
+        Ok(organization)
+    }
+}
+
+/// Command to remove a user from an organization, or to leave it when
+/// `member_id` is the user themselves.
+#[derive(Debug, Clone)]
+pub struct RemoveOrganizationMemberCommand {
+    pub user_id: UserId,
+    pub organization_id: OrganizationId,
+    pub member_id: UserId,
+}
+
+/// Handler that removes members.
+pub struct RemoveOrganizationMemberHandler {
+    organizations: Arc<dyn OrganizationRepository>,
+    seats: SeatBilling,
+    event_publisher: Arc<dyn EventPublisher>,
+    auditor: CommandAuditor,
+}
+
+impl RemoveOrganizationMemberHandler {
+    pub fn new(
+        organizations: Arc<dyn OrganizationRepository>,
+        memberships: Arc<dyn MembershipRepository>,
+        payment_provider: Arc<dyn PaymentProvider>,
+        event_publisher: Arc<dyn EventPublisher>,
+    ) -> Self {
+        Self {
+            organizations,
+            seats: SeatBilling {
+                memberships,
+                payment_provider,
+                event_publisher: event_publisher.clone(),
+            },
+            event_publisher,
+            auditor: CommandAuditor::disabled(),
+        }
+    }
+
+    /// Records each command in the audit log.
+    pub fn with_auditor(mut self, auditor: CommandAuditor) -> Self {
+        self.auditor = auditor;
+        self
+    }
+
+    #[tracing::instrument(name = "RemoveOrganizationMemberHandler::handle", skip_all)]
+    pub async fn handle(
+        &self,
+        cmd: RemoveOrganizationMemberCommand,
+        metadata: CommandMetadata,
+    ) -> Result<Organization, ManageOrganizationError> {
+        let mut organization = find_joined(
+            self.organizations.as_ref(),
+            &cmd.user_id,
+            cmd.organization_id,
+        )
+        .await?;
+        let before = organization.audit_summary();
+        organization.remove_member(&cmd.user_id, &cmd.member_id)?;
+        if let Some(membership_id) = &organization.billing_membership_id {
+            self.seats
+                .sync(membership_id, organization.seat_count(), &metadata)
+                .await?;
+        }
+        self.organizations.save(&organization).await?;
+
+        let event = OrganizationMemberRemoved {
+            event_id: EventId::new(),
+            organization_id: organization.id,
+            user_id: cmd.member_id,
+            removed_by: cmd.user_id,
+            seat_count: organization.seat_count(),
+            removed_at: Timestamp::now(),
+        };
+        publish(self.event_publisher.as_ref(), &event, &metadata).await?;
+
+        self.auditor
+            .record(
+                AuditRecord::new(
+                    &metadata,
+                    "RemoveOrganizationMember",
+                    "organization",
+                    organization.id,
+                )
+                .with_before(before)
+                .with_after(organization.audit_summary()),
+            )
+            .await;
+
+        Ok(organization)
+    }
+}
+
+// ════════════════════════════════════════════════════════════════════════════════
+// Set Billing
+// ════════════════════════════════════════════════════════════════════════════════
+
+/// Command to attach the owner's own membership as the organization's
+/// paying membership, or to detach billing.
+#[derive(Debug, Clone)]
+pub struct SetOrganizationBillingCommand {
+    pub user_id: UserId,
+    pub organization_id: OrganizationId,
+    pub attach: bool,
+}
+
+/// Handler that attaches or detaches the paying membership.
+///
+/// An attached membership is billed for every member; a detached one goes
+/// back to a single seat.
+pub struct SetOrganizationBillingHandler {
+    organizations: Arc<dyn OrganizationRepository>,
+    memberships: Arc<dyn MembershipRepository>,
+    seats: SeatBilling,
+    event_publisher: Arc<dyn EventPublisher>,
+    auditor: CommandAuditor,
+}
+
+impl SetOrganizationBillingHandler {
+    pub fn new(
+        organizations: Arc<dyn OrganizationRepository>,
+        memberships: Arc<dyn MembershipRepository>,
+        payment_provider: Arc<dyn PaymentProvider>,
+        event_publisher: Arc<dyn EventPublisher>,
+    ) -> Self {
+        Self {
+            organizations,
+            memberships: memberships.clone(),
+            seats: SeatBilling {
+                memberships,
+                payment_provider,
+                event_publisher: event_publisher.clone(),
+            },
+            event_publisher,
+            auditor: CommandAuditor::disabled(),
+        }
+    }
+
+    /// Records each command in the audit log.
+    pub fn with_auditor(mut self, auditor: CommandAuditor) -> Self {
+        self.auditor = auditor;
+        self
+    }
+
+    #[tracing::instrument(name = "SetOrganizationBillingHandler::handle", skip_all)]
+    pub async fn handle(
+        &self,
+        cmd: SetOrganizationBillingCommand,
+        metadata: CommandMetadata,
+    ) -> Result<Organization, ManageOrganizationError> {
+        let mut organization = find_joined(
+            self.organizations.as_ref(),
+            &cmd.user_id,
+            cmd.organization_id,
+        )
+        .await?;
+
+        let before = organization.audit_summary();
+
+        let membership_id = if cmd.attach {
+            let membership = self
+                .memberships
+                .find_by_user_id(&cmd.user_id)
+                .await?
+                .filter(|membership| membership.has_access())
+                .ok_or(ManageOrganizationError::NoActiveMembership)?;
+            Some(membership.id)
+        } else {
+            None
+        };
+        let previous_membership_id = organization.billing_membership_id;
+        organization.set_billing_membership(&cmd.user_id, membership_id)?;
+
+        if let Some(previous) = previous_membership_id.filter(|id| Some(*id) != membership_id) {
+            self.seats.sync(&previous, 1, &metadata).await?;
+        }
+        if let Some(current) = &membership_id {
+            self.seats
+                .sync(current, organization.seat_count(), &metadata)
+                .await?;
+        }
+        self.organizations.save(&organization).await?;
+
+        let event = OrganizationBillingChanged {
+            event_id: EventId::new(),
+            organization_id: organization.id,
+            billing_membership_id: membership_id,
+            changed_by: cmd.user_id,
+            seat_count: organization.seat_count(),
+            changed_at: organization.updated_at,
+        };
+        publish(self.event_publisher.as_ref(), &event, &metadata).await?;
+
+        self.auditor
+            .record(
+                AuditRecord::new(
+                    &metadata,
+                    "SetOrganizationBilling",
+                    "organization",
+                    organization.id,
+                )
+                .with_before(before)
+                .with_after(organization.audit_summary()),
+            )
+            .await;
+
+        Ok(organization)
+    }
+}
+
+// ════════════════════════════════════════════════════════════════════════════════
+// Share Session
+// ════════════════════════════════════════════════════════════════════════════════
+
+/// Command to share one of the user's sessions with an organization they
+/// belong to, or to stop sharing it when `organization_id` is `None`.
+#[derive(Debug, Clone)]
+pub struct ShareSessionWithOrganizationCommand {
+    pub user_id: UserId,
+    pub session_id: SessionId,
+    pub organization_id: Option<OrganizationId>,
+}
+
+/// Handler that moves sessions into and out of organizations.
+pub struct ShareSessionWithOrganizationHandler {
+    sessions: Arc<dyn SessionRepository>,
+    organizations: Arc<dyn OrganizationRepository>,
+}
+
+impl ShareSessionWithOrganizationHandler {
+    pub fn new(
+        sessions: Arc<dyn SessionRepository>,
+        organizations: Arc<dyn OrganizationRepository>,
+    ) -> Self {
+        Self {
+            sessions,
+            organizations,
+        }
+    }
+
+    #[tracing::instrument(name = "ShareSessionWithOrganizationHandler::handle", skip_all)]
+    pub async fn handle(
+        &self,
+        cmd: ShareSessionWithOrganizationCommand,
+    ) -> Result<Session, ManageOrganizationError> {
+        let mut session = self
+            .sessions
+            .find_by_id(&cmd.session_id)
+            .await?
+            .filter(|session| session.is_owner(&cmd.user_id))
+            .ok_or(ManageOrganizationError::SessionNotFound(cmd.session_id))?;
+
+        if let Some(organization_id) = cmd.organization_id {
+            find_joined(self.organizations.as_ref(), &cmd.user_id, organization_id).await?;
+        }
+
+        session.set_organization(cmd.organization_id)?;
+        self.sessions.update(&session).await?;
+        Ok(session)
+    }
+}
+
+// ════════════════════════════════════════════════════════════════════════════════
+// List Organization Sessions
+// ════════════════════════════════════════════════════════════════════════════════
+
+/// Handler that lists the sessions shared with an organization.
+pub struct ListOrganizationSessionsHandler {
+    organizations: Arc<dyn OrganizationRepository>,
+    sessions: Arc<dyn SessionReader>,
+}
+
+impl ListOrganizationSessionsHandler {
+    pub fn new(
+        organizations: Arc<dyn OrganizationRepository>,
+        sessions: Arc<dyn SessionReader>,
+    ) -> Self {
+        Self {
+            organizations,
+            sessions,
+        }
+    }
+
+    #[tracing::instrument(name = "ListOrganizationSessionsHandler::handle", skip_all)]
+    pub async fn handle(
+        &self,
+        user_id: &UserId,
+        organization_id: OrganizationId,
+        options: &ListOptions,
+    ) -> Result<SessionList, ManageOrganizationError> {
+        find_joined(self.organizations.as_ref(), user_id, organization_id).await?;
+        Ok(self
+            .sessions
+            .list_by_organization(&organization_id, options)
+            .await?)
+    }
+}
+
+/// Handler that lists the organizations a user belongs to.
+pub struct ListOrganizationsHandler {
+    organizations: Arc<dyn OrganizationRepository>,
+}
+
+impl ListOrganizationsHandler {
+    pub fn new(organizations: Arc<dyn OrganizationRepository>) -> Self {
+        Self { organizations }
+    }
+
+    #[tracing::instrument(name = "ListOrganizationsHandler::handle", skip_all)]
+    pub async fn handle(
+        &self,
+        user_id: &UserId,
+    ) -> Result<Vec<Organization>, ManageOrganizationError> {
+        Ok(self.organizations.list_for_member(user_id).await?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::adapters::MockPaymentProvider;
+    use crate::domain::foundation::{EventEnvelope, SessionStatus};
+    use crate::domain::membership::{Membership, MembershipTier};
+    use crate::ports::{
+        PaymentError, PaymentErrorCode, SessionSummary, SessionView, TrashedSession,
+    };
+    use async_trait::async_trait;
+    use std::sync::Mutex;
+
+    #[derive(Default)]
+    struct InMemoryOrganizations {
+        organizations: Mutex<Vec<Organization>>,
+    }
+
+    impl InMemoryOrganizations {
+        fn with(organization: Organization) -> Self {
+            Self {
+                organizations: Mutex::new(vec![organization]),
+            }
+        }
+
+        fn get(&self, id: &OrganizationId) -> Organization {
+            self.organizations
+                .lock()
+                .unwrap()
+                .iter()
+                .find(|o| &o.id == id)
+                .cloned()
+                .unwrap()
+        }
+    }
+
+    #[async_trait]
+    impl OrganizationRepository for InMemoryOrganizations {
+        async fn save(&self, organization: &Organization) -> Result<(), DomainError> {
+            let mut organizations = self.organizations.lock().unwrap();
+            organizations.retain(|o| o.id != organization.id);
+            organizations.push(organization.clone());
+            Ok(())
+        }
+
+        async fn find_by_id(
+            &self,
+            id: &OrganizationId,
+        ) -> Result<Option<Organization>, DomainError> {
+            let organizations = self.organizations.lock().unwrap();
+            Ok(organizations.iter().find(|o| &o.id == id).cloned())
+        }
+
+        async fn list_for_member(
+            &self,
+            user_id: &UserId,
+        ) -> Result<Vec<Organization>, DomainError> {
+            let organizations = self.organizations.lock().unwrap();
+            Ok(organizations
+                .iter()
+                .filter(|o| o.is_member(user_id))
+                .cloned()
+                .collect())
+        }
+
+        async fn delete(&self, id: &OrganizationId) -> Result<(), DomainError> {
+            self.organizations.lock().unwrap().retain(|o| &o.id != id);
+            Ok(())
+        }
+    }
+
+    #[derive(Default)]
+    struct InMemorySessions {
+        sessions: Mutex<Vec<Session>>,
+    }
+
+    #[async_trait]
+    impl SessionRepository for InMemorySessions {
+        async fn save(&self, session: &Session) -> Result<(), DomainError> {
+            self.sessions.lock().unwrap().push(session.clone());
+            Ok(())
+        }
+
+        async fn update(&self, session: &Session) -> Result<(), DomainError> {
+            let mut sessions = self.sessions.lock().unwrap();
+            if let Some(pos) = sessions.iter().position(|s| s.id() == session.id()) {
+                sessions[pos] = session.clone();
+            }
+            Ok(())
+        }
+
+        async fn find_by_id(&self, id: &SessionId) -> Result<Option<Session>, DomainError> {
+            let sessions = self.sessions.lock().unwrap();
+            Ok(sessions.iter().find(|s| s.id() == id).cloned())
+        }
+
+        async fn exists(&self, id: &SessionId) -> Result<bool, DomainError> {
+            Ok(self.sessions.lock().unwrap().iter().any(|s| s.id() == id))
+        }
+
+        async fn find_by_user_id(&self, _user_id: &UserId) -> Result<Vec<Session>, DomainError> {
+            Ok(vec![])
+        }
+
+        async fn count_active_by_user(&self, _user_id: &UserId) -> Result<u32, DomainError> {
+            Ok(0)
+        }
+
+        async fn delete(&self, _id: &SessionId) -> Result<(), DomainError> {
+            Ok(())
+        }
+    }
+
+    #[async_trait]
+    impl SessionReader for InMemorySessions {
+        async fn get_by_id(&self, _id: &SessionId) -> Result<Option<SessionView>, DomainError> {
+            Ok(None)
+        }
+
+        async fn list_by_user(
+            &self,
+            _user_id: &UserId,
+            _options: &ListOptions,
+        ) -> Result<SessionList, DomainError> {
+            unimplemented!()
+        }
+
+        async fn list_by_organization(
+            &self,
+            organization_id: &OrganizationId,
+            _options: &ListOptions,
+        ) -> Result<SessionList, DomainError> {
+            let sessions = self.sessions.lock().unwrap();
+            let items: Vec<SessionSummary> = sessions
+                .iter()
+                .filter(|s| s.organization_id() == Some(organization_id))
+                .map(|s| SessionSummary {
+                    id: *s.id(),
+                    title: s.title().to_string(),
+                    status: SessionStatus::Active,
+                    cycle_count: 0,
+                    updated_at: *s.updated_at(),
+                    in_cold_storage: false,
+                })
+                .collect();
+            Ok(SessionList {
+                total: items.len() as u64,
+                items,
+                has_more: false,
+            })
+        }
+
+        async fn search(
+            &self,
+            _user_id: &UserId,
+            _query: &str,
+            _options: &ListOptions,
+        ) -> Result<SessionList, DomainError> {
+            unimplemented!()
+        }
+
+        async fn count_by_status(
+            &self,
+            _user_id: &UserId,
+            _status: SessionStatus,
+        ) -> Result<u64, DomainError> {
+            Ok(0)
+        }
+
+        async fn list_trashed(&self, _user_id: &UserId) -> Result<Vec<TrashedSession>, DomainError> {
+            unimplemented!()
+        }
+
+        async fn list_purge_candidates(
+            &self,
+            _deleted_before: Timestamp,
+            _limit: u32,
+        ) -> Result<Vec<TrashedSession>, DomainError> {
+            unimplemented!()
+        }
+    }
+
+    struct SingleMembership(Mutex<Membership>);
+
+    impl SingleMembership {
+        fn new(membership: Membership) -> Self {
+            Self(Mutex::new(membership))
+        }
+
+        fn get(&self) -> Membership {
+            self.0.lock().unwrap().clone()
+        }
+    }
+
+    #[async_trait]
+    impl MembershipRepository for SingleMembership {
+        async fn save(&self, _membership: &Membership) -> Result<(), DomainError> {
+            Ok(())
+        }
+
+        async fn update(&self, membership: &Membership) -> Result<(), DomainError> {
+            *self.0.lock().unwrap() = membership.clone();
+            Ok(())
+        }
+
+        async fn find_by_id(&self, id: &MembershipId) -> Result<Option<Membership>, DomainError> {
+            Ok(Some(self.get()).filter(|m| &m.id == id))
+        }
+
+        async fn find_by_user_id(
+            &self,
+            user_id: &UserId,
+        ) -> Result<Option<Membership>, DomainError> {
+            Ok(Some(self.get()).filter(|m| &m.user_id == user_id))
+        }
+
+        async fn find_expiring_within_days(
+            &self,
+            _days: u32,
+        ) -> Result<Vec<Membership>, DomainError> {
+            Ok(vec![])
+        }
+
+        async fn delete(&self, _id: &MembershipId) -> Result<(), DomainError> {
+            Ok(())
+        }
+
+        async fn find_by_stripe_subscription_id(
+            &self,
+            _subscription_id: &str,
+        ) -> Result<Option<Membership>, DomainError> {
+            Ok(None)
+        }
+
+        async fn find_by_stripe_customer_id(
+            &self,
+            _customer_id: &str,
+        ) -> Result<Option<Membership>, DomainError> {
+            Ok(None)
+        }
+    }
+
+    #[derive(Default)]
+    struct RecordingPublisher {
+        events: Mutex<Vec<EventEnvelope>>,
+    }
+
+    impl RecordingPublisher {
+        fn event_types(&self) -> Vec<String> {
+            self.events
+                .lock()
+                .unwrap()
+                .iter()
+                .map(|e| e.event_type.clone())
+                .collect()
+        }
+    }
+
+    #[async_trait]
+    impl EventPublisher for RecordingPublisher {
+        async fn publish(&self, event: EventEnvelope) -> Result<(), DomainError> {
+            self.events.lock().unwrap().push(event);
+            Ok(())
+        }
+
+        async fn publish_all(&self, events: Vec<EventEnvelope>) -> Result<(), DomainError> {
+            self.events.lock().unwrap().extend(events);
+            Ok(())
+        }
+    }
+
+    fn user(id: &str) -> UserId {
+        UserId::new(id).unwrap()
+    }
+
+    fn metadata(id: &str) -> CommandMetadata {
+        CommandMetadata::new(user(id))
+    }
+
+    fn organization() -> Organization {
+        Organization::new("Acme Strategy", user("owner")).unwrap()
+    }
+
+    /// The owner's active monthly membership, billed through `sub_test`.
+    fn subscribed_membership() -> Membership {
+        let mut membership = Membership::create_paid(
+            MembershipId::new(),
+            user("owner"),
+            MembershipTier::Monthly,
+            "cus_test".to_string(),
+        );
+        let now = Timestamp::now();
+        membership
+            .activate(now, now.add_days(30), Some("sub_test".to_string()))
+            .unwrap();
+        membership
+    }
+
+    fn billing_services() -> (Arc<SingleMembership>, Arc<MockPaymentProvider>) {
+        (
+            Arc::new(SingleMembership::new(subscribed_membership())),
+            Arc::new(MockPaymentProvider::new()),
+        )
+    }
+
+    #[tokio::test]
+    async fn create_saves_organization_and_publishes_event() {
+        let organizations = Arc::new(InMemoryOrganizations::default());
+        let publisher = Arc::new(RecordingPublisher::default());
+        let handler = CreateOrganizationHandler::new(organizations.clone(), publisher.clone());
+
+        let organization = handler
+            .handle(
+                CreateOrganizationCommand {
+                    user_id: user("owner"),
+                    name: "Acme Strategy".to_string(),
+                },
+                metadata("owner"),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(organizations.get(&organization.id), organization);
+        assert_eq!(publisher.event_types(), vec!["organization.created.v1"]);
+    }
+
+    #[tokio::test]
+    async fn add_member_publishes_new_seat_count() {
+        let org = organization();
+        let organizations = Arc::new(InMemoryOrganizations::with(org.clone()));
+        let publisher = Arc::new(RecordingPublisher::default());
+        let (memberships, payments) = billing_services();
+        let handler = AddOrganizationMemberHandler::new(
+            organizations.clone(),
+            memberships,
+            payments.clone(),
+            publisher.clone(),
+        );
+
+        handler
+            .handle(
+                AddOrganizationMemberCommand {
+                    user_id: user("owner"),
+                    organization_id: org.id,
+                    member_id: user("member"),
+                    role: OrganizationRole::Member,
+                },
+                metadata("owner"),
+            )
+            .await
+            .unwrap();
+
+        assert!(organizations.get(&org.id).is_member(&user("member")));
+        let events = publisher.events.lock().unwrap();
+        assert_eq!(events[0].event_type, "organization.member_added.v1");
+        assert_eq!(events[0].payload["seat_count"], 2);
+        assert!(!payments.was_called("update_subscription_quantity"));
+    }
+
+    #[tokio::test]
+    async fn add_member_bills_another_seat() {
+        let membership = subscribed_membership();
+        let mut org = organization();
+        org.set_billing_membership(&user("owner"), Some(membership.id))
+            .unwrap();
+        let organizations = Arc::new(InMemoryOrganizations::with(org.clone()));
+        let memberships = Arc::new(SingleMembership::new(membership));
+        let payments = Arc::new(MockPaymentProvider::with_active_subscription(
+            "cus_test", "sub_test",
+        ));
+        let publisher = Arc::new(RecordingPublisher::default());
+        let handler = AddOrganizationMemberHandler::new(
+            organizations.clone(),
+            memberships.clone(),
+            payments.clone(),
+            publisher.clone(),
+        );
+
+        handler
+            .handle(
+                AddOrganizationMemberCommand {
+                    user_id: user("owner"),
+                    organization_id: org.id,
+                    member_id: user("member"),
+                    role: OrganizationRole::Member,
+                },
+                metadata("owner"),
+            )
+            .await
+            .unwrap();
+
+        let subscription = payments
+            .get_subscription("sub_test")
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(subscription.quantity, 2);
+        assert_eq!(memberships.get().seats, 2);
+        assert_eq!(
+            publisher.event_types(),
+            vec![
+                "membership.seats_changed.v1",
+                "organization.member_added.v1"
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn failed_quantity_update_leaves_members_unchanged() {
+        let membership = subscribed_membership();
+        let mut org = organization();
+        org.set_billing_membership(&user("owner"), Some(membership.id))
+            .unwrap();
+        let organizations = Arc::new(InMemoryOrganizations::with(org.clone()));
+        let memberships = Arc::new(SingleMembership::new(membership));
+        let payments = Arc::new(MockPaymentProvider::with_active_subscription(
+            "cus_test", "sub_test",
+        ));
+        payments.set_method_error(
+            "update_subscription_quantity",
+            PaymentError::new(PaymentErrorCode::ProviderError, "Stripe unavailable"),
+        );
+        let handler = AddOrganizationMemberHandler::new(
+            organizations.clone(),
+            memberships.clone(),
+            payments,
+            Arc::new(RecordingPublisher::default()),
+        );
+
+        let result = handler
+            .handle(
+                AddOrganizationMemberCommand {
+                    user_id: user("owner"),
+                    organization_id: org.id,
+                    member_id: user("member"),
+                    role: OrganizationRole::Member,
+                },
+                metadata("owner"),
+            )
+            .await;
+
+        assert!(matches!(result, Err(ManageOrganizationError::Domain(_))));
+        assert!(!organizations.get(&org.id).is_member(&user("member")));
+        assert_eq!(memberships.get().seats, 1);
+    }
+
+    #[tokio::test]
+    async fn outsiders_see_organizations_as_missing() {
+        let org = organization();
+        let organizations = Arc::new(InMemoryOrganizations::with(org.clone()));
+        let (memberships, payments) = billing_services();
+        let handler = RemoveOrganizationMemberHandler::new(
+            organizations,
+            memberships,
+            payments,
+            Arc::new(RecordingPublisher::default()),
+        );
+
+        let result = handler
+            .handle(
+                RemoveOrganizationMemberCommand {
+                    user_id: user("stranger"),
+                    organization_id: org.id,
+                    member_id: user("owner"),
+                },
+                metadata("stranger"),
+            )
+            .await;
+
+        assert!(matches!(result, Err(ManageOrganizationError::NotFound(id)) if id == org.id));
+    }
+
+    #[tokio::test]
+    async fn attaching_billing_requires_an_active_membership() {
+        let org = organization();
+        let organizations = Arc::new(InMemoryOrganizations::with(org.clone()));
+        let mut membership = Membership::create_paid(
+            MembershipId::new(),
+            user("owner"),
+            MembershipTier::Monthly,
+            "cus_test".to_string(),
+        );
+        let handler = |membership: Membership| {
+            SetOrganizationBillingHandler::new(
+                organizations.clone(),
+                Arc::new(SingleMembership::new(membership)),
+                Arc::new(MockPaymentProvider::new()),
+                Arc::new(RecordingPublisher::default()),
+            )
+        };
+        let cmd = SetOrganizationBillingCommand {
+            user_id: user("owner"),
+            organization_id: org.id,
+            attach: true,
+        };
+
+        let pending = handler(membership.clone())
+            .handle(cmd.clone(), metadata("owner"))
+            .await;
+        assert!(matches!(
+            pending,
+            Err(ManageOrganizationError::NoActiveMembership)
+        ));
+
+        let now = Timestamp::now();
+        membership.activate(now, now.add_days(30), None).unwrap();
+        let updated = handler(membership.clone())
+            .handle(cmd, metadata("owner"))
+            .await
+            .unwrap();
+        assert_eq!(updated.billing_membership_id, Some(membership.id));
+    }
+
+    #[tokio::test]
+    async fn billing_follows_the_member_count() {
+        let mut org = organization();
+        org.add_member(&user("owner"), user("member"), OrganizationRole::Member)
+            .unwrap();
+        org.add_member(&user("owner"), user("admin"), OrganizationRole::Admin)
+            .unwrap();
+        let organizations = Arc::new(InMemoryOrganizations::with(org.clone()));
+        let memberships = Arc::new(SingleMembership::new(subscribed_membership()));
+        let payments = Arc::new(MockPaymentProvider::with_active_subscription(
+            "cus_test", "sub_test",
+        ));
+        let handler = SetOrganizationBillingHandler::new(
+            organizations,
+            memberships.clone(),
+            payments.clone(),
+            Arc::new(RecordingPublisher::default()),
+        );
+        let cmd = |attach| SetOrganizationBillingCommand {
+            user_id: user("owner"),
+            organization_id: org.id,
+            attach,
+        };
+
+        handler.handle(cmd(true), metadata("owner")).await.unwrap();
+        assert_eq!(memberships.get().seats, 3);
+        let subscription = payments
+            .get_subscription("sub_test")
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(subscription.quantity, 3);
+
+        handler.handle(cmd(false), metadata("owner")).await.unwrap();
+        assert_eq!(memberships.get().seats, 1);
+        let subscription = payments
+            .get_subscription("sub_test")
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(subscription.quantity, 1);
+    }
+
+    #[tokio::test]
+    async fn shared_sessions_are_listed_for_members_only() {
+        let mut org = organization();
+        org.add_member(&user("owner"), user("member"), OrganizationRole::Member)
+            .unwrap();
+        let organizations = Arc::new(InMemoryOrganizations::with(org.clone()));
+        let sessions = Arc::new(InMemorySessions::default());
+        let session = Session::new(SessionId::new(), user("member"), "Hiring".to_string()).unwrap();
+        sessions.sessions.lock().unwrap().push(session.clone());
+
+        ShareSessionWithOrganizationHandler::new(sessions.clone(), organizations.clone())
+            .handle(ShareSessionWithOrganizationCommand {
+                user_id: user("member"),
+                session_id: *session.id(),
+                organization_id: Some(org.id),
+            })
+            .await
+            .unwrap();
+
+        let list = ListOrganizationSessionsHandler::new(organizations, sessions);
+        let shared = list
+            .handle(&user("owner"), org.id, &ListOptions::default())
+            .await
+            .unwrap();
+        assert_eq!(shared.items.len(), 1);
+        assert_eq!(shared.items[0].id, *session.id());
+
+        let outsider = list
+            .handle(&user("stranger"), org.id, &ListOptions::default())
+            .await;
+        assert!(matches!(
+            outsider,
+            Err(ManageOrganizationError::NotFound(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn only_the_session_owner_can_share_it() {
+        let mut org = organization();
+        org.add_member(&user("owner"), user("member"), OrganizationRole::Member)
+            .unwrap();
+        let organizations = Arc::new(InMemoryOrganizations::with(org.clone()));
+        let sessions = Arc::new(InMemorySessions::default());
+        let session = Session::new(SessionId::new(), user("member"), "Hiring".to_string()).unwrap();
+        sessions.sessions.lock().unwrap().push(session.clone());
+
+        let result = ShareSessionWithOrganizationHandler::new(sessions, organizations)
+            .handle(ShareSessionWithOrganizationCommand {
+                user_id: user("owner"),
+                session_id: *session.id(),
+                organization_id: Some(org.id),
+            })
+            .await;
+
+        assert!(matches!(
+            result,
+            Err(ManageOrganizationError::SessionNotFound(_))
+        ));
+    }
+}
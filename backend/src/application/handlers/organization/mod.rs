@@ -0,0 +1,21 @@
+//! Organization handlers.
+//!
+//! ## Commands
+//! - Create organizations, add and remove members, attach billing
+//! - Share a session with an organization or stop sharing it
+//!
+//! ## Queries
+//! - A user's organizations and the sessions shared with one
+//! - The organization a user belongs to, and whether they administer it
+
+mod manage_organizations;
+mod organization_resolver;
+
+pub use manage_organizations::{
+    AddOrganizationMemberCommand, AddOrganizationMemberHandler, CreateOrganizationCommand,
+    CreateOrganizationHandler, ListOrganizationSessionsHandler, ListOrganizationsHandler,
+    ManageOrganizationError, RemoveOrganizationMemberCommand, RemoveOrganizationMemberHandler,
+    SetOrganizationBillingCommand, SetOrganizationBillingHandler,
+    ShareSessionWithOrganizationCommand, ShareSessionWithOrganizationHandler,
+};
+pub use organization_resolver::OrganizationResolver;
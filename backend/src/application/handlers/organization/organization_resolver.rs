@@ -0,0 +1,150 @@
+//! OrganizationResolver - Which organization a user belongs to, and whether
+//! they administer one, read from organization membership.
+//!
+//! Per-organization settings (document templates, chat integrations, team
+//! profiles) are keyed by organization id. Everything that needs a user's
+//! organization or an organization admin check goes through here, so
+//! membership in `organization_members` is the only source of truth.
+
+use std::sync::Arc;
+
+use crate::domain::foundation::{DomainError, OrganizationId, UserId};
+use crate::ports::OrganizationRepository;
+
+/// Resolves organizations and admin rights from membership.
+#[derive(Clone)]
+pub struct OrganizationResolver {
+    organizations: Arc<dyn OrganizationRepository>,
+}
+
+impl OrganizationResolver {
+    pub fn new(organizations: Arc<dyn OrganizationRepository>) -> Self {
+        Self { organizations }
+    }
+
+    /// The organization whose settings apply to the user: the oldest one
+    /// they belong to.
+    pub async fn organization_of(
+        &self,
+        user_id: &UserId,
+    ) -> Result<Option<OrganizationId>, DomainError> {
+        Ok(self
+            .organizations
+            .list_for_member(user_id)
+            .await?
+            .first()
+            .map(|organization| organization.id))
+    }
+
+    /// Whether the user is an owner or admin of the organization.
+    pub async fn is_admin(
+        &self,
+        user_id: &UserId,
+        organization_id: &OrganizationId,
+    ) -> Result<bool, DomainError> {
+        Ok(self
+            .organizations
+            .find_by_id(organization_id)
+            .await?
+            .and_then(|organization| organization.role_of(user_id))
+            .is_some_and(|role| role.can_manage_members()))
+    }
+
+    /// Every member of the organization; empty if it does not exist.
+    pub async fn members(
+        &self,
+        organization_id: &OrganizationId,
+    ) -> Result<Vec<UserId>, DomainError> {
+        Ok(self
+            .organizations
+            .find_by_id(organization_id)
+            .await?
+            .map(|organization| {
+                organization
+                    .members
+                    .into_iter()
+                    .map(|member| member.user_id)
+                    .collect()
+            })
+            .unwrap_or_default())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::adapters::InMemoryOrganizationRepository;
+    use crate::domain::organization::{Organization, OrganizationRole};
+
+    fn user(id: &str) -> UserId {
+        UserId::new(id).unwrap()
+    }
+
+    async fn create_resolver() -> (OrganizationResolver, OrganizationId) {
+        let repo = Arc::new(InMemoryOrganizationRepository::new());
+        let mut organization = Organization::new("Acme", user("owner")).unwrap();
+        organization
+            .add_member(&user("owner"), user("admin"), OrganizationRole::Admin)
+            .unwrap();
+        organization
+            .add_member(&user("owner"), user("member"), OrganizationRole::Member)
+            .unwrap();
+        repo.save(&organization).await.unwrap();
+        (OrganizationResolver::new(repo), organization.id)
+    }
+
+    #[tokio::test]
+    async fn users_resolve_to_the_organization_they_joined() {
+        let (resolver, organization_id) = create_resolver().await;
+
+        assert_eq!(
+            resolver.organization_of(&user("member")).await.unwrap(),
+            Some(organization_id)
+        );
+        assert_eq!(
+            resolver.organization_of(&user("outsider")).await.unwrap(),
+            None
+        );
+    }
+
+    #[tokio::test]
+    async fn only_owners_and_admins_administer_the_organization() {
+        let (resolver, organization_id) = create_resolver().await;
+
+        for (id, expected) in [
+            ("owner", true),
+            ("admin", true),
+            ("member", false),
+            ("outsider", false),
+        ] {
+            assert_eq!(
+                resolver
+                    .is_admin(&user(id), &organization_id)
+                    .await
+                    .unwrap(),
+                expected,
+                "{}",
+                id
+            );
+        }
+        assert!(!resolver
+            .is_admin(&user("owner"), &OrganizationId::new())
+            .await
+            .unwrap());
+    }
+
+    #[tokio::test]
+    async fn members_lists_everyone_in_the_organization() {
+        let (resolver, organization_id) = create_resolver().await;
+
+        assert_eq!(
+            resolver.members(&organization_id).await.unwrap(),
+            vec![user("owner"), user("admin"), user("member")]
+        );
+        assert!(resolver
+            .members(&OrganizationId::new())
+            .await
+            .unwrap()
+            .is_empty());
+    }
+}
@@ -0,0 +1,327 @@
+//! DataErasureWorker - Background worker that carries out GDPR erasures.
+//!
+//! Polls for pending `DataErasure`s the same way `UserDataExporter` polls
+//! for exports. Each attempt runs every registered `UserDataEraser` in
+//! order, then asks each one what is left for the user. Only a clean report
+//! completes the erasure and emails the user; anything else is retried.
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use tokio::sync::watch;
+use tokio::time;
+
+use crate::domain::foundation::{DomainError, Timestamp, UserId};
+use crate::domain::privacy::{
+    DataErasure, DataErasureStatus, ErasedData, ErasureMethod, ErasureReport, ErasureStep,
+};
+use crate::ports::{DataErasureRepository, EmailMessage, EmailSender, UserDataEraser};
+
+/// Erasures attempted per poll.
+const ERASURE_BATCH_SIZE: u32 = 10;
+
+/// Carries out pending data erasures and emails the verification report.
+pub struct DataErasureWorker {
+    erasures: Arc<dyn DataErasureRepository>,
+    erasers: Vec<Arc<dyn UserDataEraser>>,
+    email_sender: Arc<dyn EmailSender>,
+}
+
+impl DataErasureWorker {
+    pub fn new(
+        erasures: Arc<dyn DataErasureRepository>,
+        erasers: Vec<Arc<dyn UserDataEraser>>,
+        email_sender: Arc<dyn EmailSender>,
+    ) -> Self {
+        Self {
+            erasures,
+            erasers,
+            email_sender,
+        }
+    }
+
+    /// Attempts due erasures every `poll_interval` until shutdown is
+    /// signalled.
+    pub async fn run(
+        &self,
+        poll_interval: Duration,
+        mut shutdown: watch::Receiver<bool>,
+    ) -> Result<(), DomainError> {
+        let mut interval = time::interval(poll_interval);
+
+        loop {
+            tokio::select! {
+                _ = shutdown.changed() => {
+                    if *shutdown.borrow() {
+                        return Ok(());
+                    }
+                }
+
+                _ = interval.tick() => {
+                    self.process_due(ERASURE_BATCH_SIZE).await?;
+                }
+            }
+        }
+    }
+
+    /// Attempts up to `limit` due erasures, returning how many completed.
+    #[tracing::instrument(name = "DataErasureWorker::process_due", skip_all)]
+    pub async fn process_due(&self, limit: u32) -> Result<usize, DomainError> {
+        let due = self.erasures.list_due(Timestamp::now(), limit).await?;
+        let mut completed = 0;
+        for mut erasure in due {
+            self.attempt(&mut erasure).await?;
+            if erasure.status == DataErasureStatus::Completed {
+                completed += 1;
+            }
+        }
+        Ok(completed)
+    }
+
+    /// Erases and verifies once, recording the outcome.
+    async fn attempt(&self, erasure: &mut DataErasure) -> Result<(), DomainError> {
+        match self.erase(&erasure.user_id).await {
+            Ok(report) if report.is_verified() => {
+                erasure.record_completed(report);
+                self.notify(erasure).await;
+                erasure.forget_recipient();
+            }
+            Ok(report) => {
+                tracing::warn!(
+                    erasure_id = %erasure.id,
+                    attempts = erasure.attempts + 1,
+                    sections = ?report.unverified_sections(),
+                    "Data erasure left data behind"
+                );
+                erasure.record_unverified(report);
+            }
+            Err(error) => {
+                tracing::warn!(
+                    erasure_id = %erasure.id,
+                    attempts = erasure.attempts + 1,
+                    error = %error,
+                    "Data erasure failed"
+                );
+                erasure.record_failure(error, true);
+            }
+        }
+        if erasure.status == DataErasureStatus::Failed {
+            tracing::error!(
+                erasure_id = %erasure.id,
+                error = erasure.last_error.as_deref().unwrap_or_default(),
+                "Data erasure gave up and needs manual follow-up"
+            );
+        }
+        self.erasures.save(erasure).await
+    }
+
+    /// Runs every eraser, then verifies each one, so data one eraser finds
+    /// through another's records is checked after both have run.
+    async fn erase(&self, user_id: &UserId) -> Result<ErasureReport, String> {
+        let mut erased: Vec<ErasedData> = Vec::with_capacity(self.erasers.len());
+        for eraser in &self.erasers {
+            erased.push(
+                eraser
+                    .erase(user_id)
+                    .await
+                    .map_err(|e| format!("{}: {}", eraser.section(), e))?,
+            );
+        }
+
+        let mut steps = Vec::with_capacity(self.erasers.len());
+        for (eraser, erased) in self.erasers.iter().zip(erased) {
+            let remaining = eraser
+                .remaining(user_id)
+                .await
+                .map_err(|e| format!("{} verification: {}", eraser.section(), e))?;
+            steps.push(ErasureStep::new(eraser.section(), erased, remaining));
+        }
+        Ok(ErasureReport::new(steps))
+    }
+
+    /// Emails the report. The erasure has already happened, so failures are
+    /// only logged.
+    async fn notify(&self, erasure: &DataErasure) {
+        let Some(recipient) = erasure.recipient.as_deref() else {
+            return;
+        };
+        let Some(report) = erasure.report.as_ref() else {
+            return;
+        };
+
+        let lines: String = report
+            .steps
+            .iter()
+            .map(|step| {
+                let method = match step.method {
+                    ErasureMethod::Deleted => "deleted",
+                    ErasureMethod::CryptoShredded => "made unreadable",
+                };
+                format!("- {}: {} records {}\n", step.section, step.records, method)
+            })
+            .collect();
+        let message = EmailMessage {
+            to: recipient.to_string(),
+            subject: "Your Choice Sherpa data has been erased".to_string(),
+            text_body: format!(
+                "As you requested, we have erased all the data we held about you and \
+                 checked that none of it remains:\n\n{}\nThis is the last email you \
+                 will receive from us. We have not kept your email address.\n",
+                lines
+            ),
+            attachments: Vec::new(),
+        };
+        if let Err(error) = self.email_sender.send(&message).await {
+            tracing::warn!(erasure_id = %erasure.id, error = %error, "Data erasure email failed");
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use async_trait::async_trait;
+    use std::sync::atomic::{AtomicU64, Ordering};
+
+    use crate::adapters::email::InMemoryEmailSender;
+    use crate::adapters::InMemoryDataErasureRepository;
+    use crate::domain::foundation::ErrorCode;
+
+    /// Holds `records` records until erased; fails every erase if `broken`.
+    struct CountingEraser {
+        section: &'static str,
+        records: AtomicU64,
+        /// Records that reappear after erasing, as if written concurrently.
+        stubborn: u64,
+        broken: bool,
+    }
+
+    impl CountingEraser {
+        fn new(section: &'static str, records: u64) -> Self {
+            Self {
+                section,
+                records: AtomicU64::new(records),
+                stubborn: 0,
+                broken: false,
+            }
+        }
+    }
+
+    #[async_trait]
+    impl UserDataEraser for CountingEraser {
+        fn section(&self) -> &'static str {
+            self.section
+        }
+
+        async fn erase(&self, _user_id: &UserId) -> Result<ErasedData, DomainError> {
+            if self.broken {
+                return Err(DomainError::new(
+                    ErrorCode::DatabaseError,
+                    "connection reset",
+                ));
+            }
+            let records = self.records.swap(self.stubborn, Ordering::SeqCst);
+            Ok(ErasedData::deleted(records))
+        }
+
+        async fn remaining(&self, _user_id: &UserId) -> Result<u64, DomainError> {
+            Ok(self.records.load(Ordering::SeqCst))
+        }
+    }
+
+    /// An erasure repository holding one pending request, and the mailbox
+    /// its report goes to.
+    async fn setup_repositories() -> (
+        Arc<InMemoryDataErasureRepository>,
+        Arc<InMemoryEmailSender>,
+        DataErasure,
+    ) {
+        let erasures = Arc::new(InMemoryDataErasureRepository::new());
+        let erasure = DataErasure::new(
+            UserId::new("user-1").unwrap(),
+            Some("jo@example.com".to_string()),
+        );
+        erasures.save(&erasure).await.unwrap();
+        (erasures, Arc::new(InMemoryEmailSender::new()), erasure)
+    }
+
+    /// A worker erasing four sessions, then whatever `extra` holds.
+    fn create_handler(
+        erasures: Arc<InMemoryDataErasureRepository>,
+        email_sender: Arc<InMemoryEmailSender>,
+        extra: CountingEraser,
+    ) -> DataErasureWorker {
+        let erasers: Vec<Arc<dyn UserDataEraser>> = vec![
+            Arc::new(CountingEraser::new("sessions", 4)),
+            Arc::new(extra),
+        ];
+        DataErasureWorker::new(erasures, erasers, email_sender)
+    }
+
+    async fn reload(
+        erasures: &InMemoryDataErasureRepository,
+        erasure: &DataErasure,
+    ) -> DataErasure {
+        erasures.find_by_id(&erasure.id).await.unwrap().unwrap()
+    }
+
+    #[tokio::test]
+    async fn completes_with_a_verified_report_and_forgets_the_address() {
+        let (erasures, email_sender, erasure) = setup_repositories().await;
+        let worker = create_handler(
+            erasures.clone(),
+            email_sender.clone(),
+            CountingEraser::new("usage", 7),
+        );
+
+        assert_eq!(worker.process_due(10).await.unwrap(), 1);
+
+        let erasure = reload(&erasures, &erasure).await;
+        assert_eq!(erasure.status, DataErasureStatus::Completed);
+        assert!(erasure.recipient.is_none());
+        let report = erasure.report.unwrap();
+        assert!(report.is_verified());
+        assert_eq!(report.steps[0].section, "sessions");
+        assert_eq!(report.steps[1].records, 7);
+
+        let sent = email_sender.sent();
+        assert_eq!(sent.len(), 1);
+        assert_eq!(sent[0].to, "jo@example.com");
+        assert!(sent[0].text_body.contains("- usage: 7 records deleted"));
+    }
+
+    #[tokio::test]
+    async fn retries_when_data_remains() {
+        let mut stubborn = CountingEraser::new("conversations", 3);
+        stubborn.stubborn = 1;
+        let (erasures, email_sender, erasure) = setup_repositories().await;
+        let worker = create_handler(erasures.clone(), email_sender.clone(), stubborn);
+
+        assert_eq!(worker.process_due(10).await.unwrap(), 0);
+
+        let erasure = reload(&erasures, &erasure).await;
+        assert_eq!(erasure.status, DataErasureStatus::Pending);
+        assert_eq!(erasure.attempts, 1);
+        assert_eq!(
+            erasure.report.unwrap().unverified_sections(),
+            vec!["conversations"]
+        );
+        assert_eq!(erasure.recipient.as_deref(), Some("jo@example.com"));
+        assert!(email_sender.sent().is_empty());
+    }
+
+    #[tokio::test]
+    async fn retries_when_an_eraser_fails() {
+        let mut broken = CountingEraser::new("billing", 1);
+        broken.broken = true;
+        let (erasures, email_sender, erasure) = setup_repositories().await;
+        let worker = create_handler(erasures.clone(), email_sender, broken);
+
+        worker.process_due(10).await.unwrap();
+
+        let erasure = reload(&erasures, &erasure).await;
+        assert_eq!(erasure.status, DataErasureStatus::Pending);
+        assert!(erasure.last_error.unwrap().starts_with("billing: "));
+        assert!(erasure.report.is_none());
+    }
+}
@@ -0,0 +1,272 @@
+//! UserDataExporter - Background worker that builds GDPR data exports.
+//!
+//! Polls for pending `DataExport`s the same way `CycleDocumentMailer` polls
+//! for due deliveries. Each attempt collects every registered
+//! `UserDataSource` into a `UserDataArchive`, writes it to document storage,
+//! and emails the user a link to `GET /api/user/export/:id/download`.
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use tokio::sync::watch;
+use tokio::time;
+
+use crate::domain::foundation::{DomainError, Timestamp};
+use crate::domain::privacy::{DataExport, UserDataArchive, ARCHIVE_RETENTION_DAYS};
+use crate::ports::{
+    DataExportRepository, DocumentStorage, EmailMessage, EmailSender, UserDataSource,
+};
+
+/// Exports built per poll.
+const EXPORT_BATCH_SIZE: u32 = 10;
+
+/// Builds pending data exports and emails the download link.
+pub struct UserDataExporter {
+    exports: Arc<dyn DataExportRepository>,
+    sources: Vec<Arc<dyn UserDataSource>>,
+    storage: Arc<dyn DocumentStorage>,
+    email_sender: Arc<dyn EmailSender>,
+    /// Public API origin used in emailed links, e.g. `https://api.choicesherpa.com`.
+    api_base_url: String,
+}
+
+impl UserDataExporter {
+    pub fn new(
+        exports: Arc<dyn DataExportRepository>,
+        sources: Vec<Arc<dyn UserDataSource>>,
+        storage: Arc<dyn DocumentStorage>,
+        email_sender: Arc<dyn EmailSender>,
+        api_base_url: impl Into<String>,
+    ) -> Self {
+        Self {
+            exports,
+            sources,
+            storage,
+            email_sender,
+            api_base_url: api_base_url.into().trim_end_matches('/').to_string(),
+        }
+    }
+
+    /// Builds due exports every `poll_interval` until shutdown is signalled.
+    pub async fn run(
+        &self,
+        poll_interval: Duration,
+        mut shutdown: watch::Receiver<bool>,
+    ) -> Result<(), DomainError> {
+        let mut interval = time::interval(poll_interval);
+
+        loop {
+            tokio::select! {
+                _ = shutdown.changed() => {
+                    if *shutdown.borrow() {
+                        return Ok(());
+                    }
+                }
+
+                _ = interval.tick() => {
+                    self.process_due(EXPORT_BATCH_SIZE).await?;
+                }
+            }
+        }
+    }
+
+    /// Attempts up to `limit` due exports, returning how many became ready.
+    #[tracing::instrument(name = "UserDataExporter::process_due", skip_all)]
+    pub async fn process_due(&self, limit: u32) -> Result<usize, DomainError> {
+        let due = self.exports.list_due(Timestamp::now(), limit).await?;
+        let mut ready = 0;
+        for mut export in due {
+            self.attempt(&mut export).await?;
+            if export.completed_at.is_some() {
+                ready += 1;
+            }
+        }
+        Ok(ready)
+    }
+
+    /// Builds the archive once, recording the outcome.
+    async fn attempt(&self, export: &mut DataExport) -> Result<(), DomainError> {
+        match self.build(export).await {
+            Ok(key) => {
+                export.record_ready(key);
+                self.notify(export).await;
+            }
+            Err(error) => {
+                tracing::warn!(
+                    export_id = %export.id,
+                    attempts = export.attempts + 1,
+                    error = %error,
+                    "Data export failed"
+                );
+                export.record_failure(error, true);
+            }
+        }
+        self.exports.save(export).await
+    }
+
+    /// Collects and stores the archive, returning its storage key.
+    async fn build(&self, export: &DataExport) -> Result<String, String> {
+        let mut archive = UserDataArchive::new(export.user_id.clone());
+        for source in &self.sources {
+            let data = source
+                .collect(&export.user_id)
+                .await
+                .map_err(|e| format!("{}: {}", source.section(), e))?;
+            archive.insert(source.section(), data);
+        }
+
+        let key = export.storage_key();
+        self.storage
+            .put(&key, "application/json", archive.to_json_bytes())
+            .await
+            .map_err(|e| e.to_string())?;
+        Ok(key)
+    }
+
+    /// Emails the download link. The archive stays available from the API if
+    /// the email cannot be sent, so failures are only logged.
+    async fn notify(&self, export: &DataExport) {
+        let message = EmailMessage {
+            to: export.recipient.clone(),
+            subject: "Your Choice Sherpa data export is ready".to_string(),
+            text_body: format!(
+                "The copy of your data you requested is ready. Sign in and download it \
+                 within {} days:\n\n{}/api/user/export/{}/download\n",
+                ARCHIVE_RETENTION_DAYS, self.api_base_url, export.id
+            ),
+            attachments: Vec::new(),
+        };
+        if let Err(error) = self.email_sender.send(&message).await {
+            tracing::warn!(export_id = %export.id, error = %error, "Data export email failed");
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use async_trait::async_trait;
+
+    use crate::adapters::email::InMemoryEmailSender;
+    use crate::adapters::storage::InMemoryDocumentStorage;
+    use crate::adapters::InMemoryDataExportRepository;
+    use crate::domain::foundation::{ErrorCode, UserId};
+    use crate::domain::privacy::DataExportStatus;
+
+    struct FixedSource {
+        section: &'static str,
+        data: Option<serde_json::Value>,
+    }
+
+    #[async_trait]
+    impl UserDataSource for FixedSource {
+        fn section(&self) -> &'static str {
+            self.section
+        }
+
+        async fn collect(&self, _user_id: &UserId) -> Result<serde_json::Value, DomainError> {
+            self.data
+                .clone()
+                .ok_or_else(|| DomainError::new(ErrorCode::DatabaseError, "connection reset"))
+        }
+    }
+
+    /// Repositories holding one pending export request, and the sinks the
+    /// exporter writes to.
+    async fn setup_repositories() -> (
+        Arc<InMemoryDataExportRepository>,
+        Arc<InMemoryDocumentStorage>,
+        Arc<InMemoryEmailSender>,
+        DataExport,
+    ) {
+        let exports = Arc::new(InMemoryDataExportRepository::new());
+        let export = DataExport::new(UserId::new("user-1").unwrap(), "jo@example.com");
+        exports.save(&export).await.unwrap();
+        (
+            exports,
+            Arc::new(InMemoryDocumentStorage::new()),
+            Arc::new(InMemoryEmailSender::new()),
+            export,
+        )
+    }
+
+    /// An exporter whose profile source returns `profile`, or fails if None.
+    fn create_handler(
+        exports: Arc<InMemoryDataExportRepository>,
+        storage: Arc<InMemoryDocumentStorage>,
+        email_sender: Arc<InMemoryEmailSender>,
+        profile: Option<serde_json::Value>,
+    ) -> UserDataExporter {
+        let sources: Vec<Arc<dyn UserDataSource>> = vec![
+            Arc::new(FixedSource {
+                section: "decision_profile",
+                data: profile,
+            }),
+            Arc::new(FixedSource {
+                section: "sessions",
+                data: Some(serde_json::json!([{"title": "Which job?"}])),
+            }),
+        ];
+        UserDataExporter::new(
+            exports,
+            sources,
+            storage,
+            email_sender,
+            "https://api.example.com/",
+        )
+    }
+
+    #[tokio::test]
+    async fn stores_every_section_and_emails_the_link() {
+        let (exports, storage, email_sender, export) = setup_repositories().await;
+        let exporter = create_handler(
+            exports.clone(),
+            storage.clone(),
+            email_sender.clone(),
+            Some(serde_json::json!({"risk_tolerance": "moderate"})),
+        );
+
+        assert_eq!(exporter.process_due(10).await.unwrap(), 1);
+
+        let export = exports.find_by_id(&export.id).await.unwrap().unwrap();
+        assert_eq!(export.status, DataExportStatus::Ready);
+
+        let stored = storage
+            .get(export.archive_key.as_deref().unwrap())
+            .await
+            .unwrap();
+        assert_eq!(stored.content_type, "application/json");
+        let archive: serde_json::Value = serde_json::from_slice(&stored.bytes).unwrap();
+        assert_eq!(
+            archive["sections"]["decision_profile"]["risk_tolerance"],
+            "moderate"
+        );
+        assert_eq!(archive["sections"]["sessions"][0]["title"], "Which job?");
+
+        let sent = email_sender.sent();
+        assert_eq!(sent.len(), 1);
+        assert_eq!(sent[0].to, "jo@example.com");
+        assert!(sent[0].text_body.contains(&format!(
+            "https://api.example.com/api/user/export/{}/download",
+            export.id
+        )));
+    }
+
+    #[tokio::test]
+    async fn retries_when_a_source_fails() {
+        let (exports, storage, email_sender, export) = setup_repositories().await;
+        let exporter = create_handler(exports.clone(), storage, email_sender.clone(), None);
+
+        assert_eq!(exporter.process_due(10).await.unwrap(), 0);
+
+        let export = exports.find_by_id(&export.id).await.unwrap().unwrap();
+        assert_eq!(export.status, DataExportStatus::Pending);
+        assert_eq!(export.attempts, 1);
+        assert!(export
+            .last_error
+            .as_deref()
+            .unwrap()
+            .starts_with("decision_profile"));
+        assert!(email_sender.sent().is_empty());
+    }
+}
@@ -0,0 +1,122 @@
+//! DeleteAllUserDataHandler - Command handler for GDPR erasure requests.
+//!
+//! Only records the request; `DataErasureWorker` erases and verifies in the
+//! background. A user has at most one erasure in progress at a time.
+
+use std::sync::Arc;
+
+use crate::domain::foundation::{DataErasureId, DomainError, UserId};
+use crate::domain::privacy::DataErasure;
+use crate::ports::DataErasureRepository;
+
+/// Command to erase all of a user's data.
+#[derive(Debug, Clone)]
+pub struct DeleteAllUserDataCommand {
+    pub user_id: UserId,
+    /// Address the completion email is sent to, if any.
+    pub email: Option<String>,
+}
+
+/// Result of successfully requesting an erasure.
+#[derive(Debug, Clone)]
+pub struct DeleteAllUserDataResult {
+    pub erasure: DataErasure,
+}
+
+/// Errors from requesting an erasure.
+#[derive(Debug, Clone)]
+pub enum DeleteAllUserDataError {
+    /// An earlier erasure for the user is still being carried out.
+    AlreadyInProgress(DataErasureId),
+    /// Domain error.
+    Domain(DomainError),
+}
+
+impl std::fmt::Display for DeleteAllUserDataError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DeleteAllUserDataError::AlreadyInProgress(id) => {
+                write!(f, "Data erasure {} is already in progress", id)
+            }
+            DeleteAllUserDataError::Domain(err) => write!(f, "{}", err),
+        }
+    }
+}
+
+impl std::error::Error for DeleteAllUserDataError {}
+
+impl From<DomainError> for DeleteAllUserDataError {
+    fn from(err: DomainError) -> Self {
+        DeleteAllUserDataError::Domain(err)
+    }
+}
+
+/// Handler for data erasure requests.
+pub struct DeleteAllUserDataHandler {
+    erasures: Arc<dyn DataErasureRepository>,
+}
+
+impl DeleteAllUserDataHandler {
+    pub fn new(erasures: Arc<dyn DataErasureRepository>) -> Self {
+        Self { erasures }
+    }
+
+    #[tracing::instrument(name = "DeleteAllUserDataHandler::handle", skip_all)]
+    pub async fn handle(
+        &self,
+        cmd: DeleteAllUserDataCommand,
+    ) -> Result<DeleteAllUserDataResult, DeleteAllUserDataError> {
+        if let Some(latest) = self.erasures.latest_for_user(&cmd.user_id).await? {
+            if latest.is_in_progress() {
+                return Err(DeleteAllUserDataError::AlreadyInProgress(latest.id));
+            }
+        }
+
+        let email = cmd.email.filter(|e| !e.trim().is_empty());
+        let erasure = DataErasure::new(cmd.user_id, email);
+        self.erasures.save(&erasure).await?;
+        Ok(DeleteAllUserDataResult { erasure })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::adapters::InMemoryDataErasureRepository;
+    use crate::domain::privacy::{DataErasureStatus, ErasureReport};
+
+    fn command() -> DeleteAllUserDataCommand {
+        DeleteAllUserDataCommand {
+            user_id: UserId::new("user-1").unwrap(),
+            email: Some("jo@example.com".to_string()),
+        }
+    }
+
+    #[tokio::test]
+    async fn queues_a_pending_erasure() {
+        let erasures = Arc::new(InMemoryDataErasureRepository::new());
+        let handler = DeleteAllUserDataHandler::new(erasures.clone());
+
+        let result = handler.handle(command()).await.unwrap();
+
+        assert_eq!(result.erasure.status, DataErasureStatus::Pending);
+        assert_eq!(result.erasure.recipient.as_deref(), Some("jo@example.com"));
+        let saved = erasures.find_by_id(&result.erasure.id).await.unwrap();
+        assert_eq!(saved, Some(result.erasure));
+    }
+
+    #[tokio::test]
+    async fn rejects_a_second_request_while_one_is_in_progress() {
+        let erasures = Arc::new(InMemoryDataErasureRepository::new());
+        let handler = DeleteAllUserDataHandler::new(erasures.clone());
+        let first = handler.handle(command()).await.unwrap().erasure;
+
+        let err = handler.handle(command()).await.unwrap_err();
+        assert!(matches!(err, DeleteAllUserDataError::AlreadyInProgress(id) if id == first.id));
+
+        let mut finished = first;
+        finished.record_completed(ErasureReport::new(Vec::new()));
+        erasures.save(&finished).await.unwrap();
+        assert!(handler.handle(command()).await.is_ok());
+    }
+}
@@ -0,0 +1,678 @@
+//! Built-in `UserDataEraser`s over the existing repository ports.
+//!
+//! Erasers run in registration order, and the ones that find data through
+//! the user's sessions (`ConversationEraser`, `DocumentEraser`,
+//! `ColdStorageEraser`) must be registered before `SessionEraser` removes
+//! those sessions. `DocumentEraser` also finds offloaded cycles through cold
+//! storage records, so it goes before `ColdStorageEraser`. In PostgreSQL
+//! the remaining cycle-scoped rows (components, document versions, decision
+//! records, reminders) go with their cycle through `ON DELETE CASCADE`.
+//! Rows keyed straight by user (credentials, consents, integrations,
+//! delivery preferences) are erased by `PostgresUserRecordsEraser`, which
+//! also fails verification while any `user_id` table still has the user's
+//! rows.
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use crate::application::handlers::session::SessionColdStorage;
+use crate::domain::cycle::Cycle;
+use crate::domain::foundation::{
+    ComponentType, ConversationId, CycleId, DomainError, ErrorCode, Timestamp, UserId,
+};
+use crate::domain::privacy::ErasedData;
+use crate::ports::{
+    AttachmentRepository, ConversationRepository, CycleRepository, DataExportRepository,
+    DecisionHistoryRepository, DocumentStorage, DocumentStorageError, MembershipRepository,
+    PaymentProvider, ProfileRevisionRepository, ProfileSummaryRepository,
+    SessionColdStorageRepository, SessionRepository, UsageTracker, UserDataEraser,
+};
+
+/// Counts a delete, treating a record that is already gone as erased by an
+/// earlier attempt.
+fn deleted(result: Result<(), DomainError>, not_found: ErrorCode) -> Result<u64, DomainError> {
+    match result {
+        Ok(()) => Ok(1),
+        Err(e) if e.code == not_found => Ok(0),
+        Err(e) => Err(e),
+    }
+}
+
+/// Every cycle in every session the user owns.
+async fn user_cycles(
+    sessions: &dyn SessionRepository,
+    cycles: &dyn CycleRepository,
+    user_id: &UserId,
+) -> Result<Vec<Cycle>, DomainError> {
+    let mut all = Vec::new();
+    for session in sessions.find_by_user_id(user_id).await? {
+        all.extend(cycles.find_by_session_id(session.id()).await?);
+    }
+    Ok(all)
+}
+
+/// IDs of every cycle the user owns, including those offloaded to cold
+/// storage, which only their session's cold storage record still lists.
+async fn user_cycle_ids(
+    sessions: &dyn SessionRepository,
+    cycles: &dyn CycleRepository,
+    cold_storage: &dyn SessionColdStorageRepository,
+    user_id: &UserId,
+) -> Result<Vec<CycleId>, DomainError> {
+    let mut ids: Vec<CycleId> = Vec::new();
+    for session in sessions.find_by_user_id(user_id).await? {
+        let hot = cycles.find_by_session_id(session.id()).await?;
+        let cold = cold_storage
+            .find(session.id())
+            .await?
+            .map(|record| record.cycle_ids)
+            .unwrap_or_default();
+        // A partly rehydrated session has cycles in both places
+        for id in hot.iter().map(|c| c.id()).chain(cold) {
+            if !ids.contains(&id) {
+                ids.push(id);
+            }
+        }
+    }
+    Ok(ids)
+}
+
+/// The user's sessions and the decision cycles within them.
+pub struct SessionEraser {
+    sessions: Arc<dyn SessionRepository>,
+    cycles: Arc<dyn CycleRepository>,
+}
+
+impl SessionEraser {
+    pub fn new(sessions: Arc<dyn SessionRepository>, cycles: Arc<dyn CycleRepository>) -> Self {
+        Self { sessions, cycles }
+    }
+}
+
+#[async_trait]
+impl UserDataEraser for SessionEraser {
+    fn section(&self) -> &'static str {
+        "sessions"
+    }
+
+    async fn erase(&self, user_id: &UserId) -> Result<ErasedData, DomainError> {
+        let mut records = 0;
+        for session in self.sessions.find_by_user_id(user_id).await? {
+            // Branches may already be gone with their parent
+            for cycle in self.cycles.find_by_session_id(session.id()).await? {
+                records += deleted(
+                    self.cycles.delete(&cycle.id()).await,
+                    ErrorCode::CycleNotFound,
+                )?;
+            }
+            records += deleted(
+                self.sessions.delete(session.id()).await,
+                ErrorCode::SessionNotFound,
+            )?;
+        }
+        Ok(ErasedData::deleted(records))
+    }
+
+    async fn remaining(&self, user_id: &UserId) -> Result<u64, DomainError> {
+        Ok(self.sessions.find_by_user_id(user_id).await?.len() as u64)
+    }
+}
+
+/// Conversations with the agent, one per component of each cycle.
+pub struct ConversationEraser {
+    sessions: Arc<dyn SessionRepository>,
+    cycles: Arc<dyn CycleRepository>,
+    conversations: Arc<dyn ConversationRepository>,
+}
+
+impl ConversationEraser {
+    pub fn new(
+        sessions: Arc<dyn SessionRepository>,
+        cycles: Arc<dyn CycleRepository>,
+        conversations: Arc<dyn ConversationRepository>,
+    ) -> Self {
+        Self {
+            sessions,
+            cycles,
+            conversations,
+        }
+    }
+
+    /// IDs of the conversations held for the user.
+    async fn conversation_ids(&self, user_id: &UserId) -> Result<Vec<ConversationId>, DomainError> {
+        let mut ids = Vec::new();
+        for cycle in user_cycles(self.sessions.as_ref(), self.cycles.as_ref(), user_id).await? {
+            for component_type in ComponentType::all() {
+                let Some(component) = cycle.component(*component_type) else {
+                    continue;
+                };
+                if let Some(conversation) = self
+                    .conversations
+                    .find_by_component(&component.id())
+                    .await?
+                {
+                    ids.push(*conversation.id());
+                }
+            }
+        }
+        Ok(ids)
+    }
+}
+
+#[async_trait]
+impl UserDataEraser for ConversationEraser {
+    fn section(&self) -> &'static str {
+        "conversations"
+    }
+
+    async fn erase(&self, user_id: &UserId) -> Result<ErasedData, DomainError> {
+        let mut records = 0;
+        for id in self.conversation_ids(user_id).await? {
+            records += deleted(
+                self.conversations.delete(&id).await,
+                ErrorCode::ConversationNotFound,
+            )?;
+        }
+        Ok(ErasedData::deleted(records))
+    }
+
+    async fn remaining(&self, user_id: &UserId) -> Result<u64, DomainError> {
+        Ok(self.conversation_ids(user_id).await?.len() as u64)
+    }
+}
+
+/// Files the user attached to their decisions, offloaded ones included, and
+/// archives from earlier data exports.
+pub struct DocumentEraser {
+    sessions: Arc<dyn SessionRepository>,
+    cycles: Arc<dyn CycleRepository>,
+    cold_storage: Arc<dyn SessionColdStorageRepository>,
+    attachments: Arc<dyn AttachmentRepository>,
+    exports: Arc<dyn DataExportRepository>,
+    storage: Arc<dyn DocumentStorage>,
+}
+
+impl DocumentEraser {
+    pub fn new(
+        sessions: Arc<dyn SessionRepository>,
+        cycles: Arc<dyn CycleRepository>,
+        cold_storage: Arc<dyn SessionColdStorageRepository>,
+        attachments: Arc<dyn AttachmentRepository>,
+        exports: Arc<dyn DataExportRepository>,
+        storage: Arc<dyn DocumentStorage>,
+    ) -> Self {
+        Self {
+            sessions,
+            cycles,
+            cold_storage,
+            attachments,
+            exports,
+            storage,
+        }
+    }
+
+    async fn cycle_ids(&self, user_id: &UserId) -> Result<Vec<CycleId>, DomainError> {
+        user_cycle_ids(
+            self.sessions.as_ref(),
+            self.cycles.as_ref(),
+            self.cold_storage.as_ref(),
+            user_id,
+        )
+        .await
+    }
+
+    async fn delete_stored(&self, key: &str) -> Result<(), DomainError> {
+        match self.storage.delete(key).await {
+            Ok(()) | Err(DocumentStorageError::NotFound(_)) => Ok(()),
+            Err(e) => Err(DomainError::new(
+                ErrorCode::ExternalServiceError,
+                e.to_string(),
+            )),
+        }
+    }
+}
+
+#[async_trait]
+impl UserDataEraser for DocumentEraser {
+    fn section(&self) -> &'static str {
+        "documents"
+    }
+
+    async fn erase(&self, user_id: &UserId) -> Result<ErasedData, DomainError> {
+        let mut records = 0;
+        for cycle_id in self.cycle_ids(user_id).await? {
+            for attachment in self.attachments.list_by_cycle(&cycle_id).await? {
+                // Content first, so a retry still finds the metadata
+                self.delete_stored(&attachment.storage_key()).await?;
+                self.attachments.delete(&attachment.id).await?;
+                records += 1;
+            }
+        }
+        for export in self.exports.list_for_user(user_id).await? {
+            if let Some(key) = export.archive_key.as_deref() {
+                self.delete_stored(key).await?;
+            }
+            self.exports.delete(&export.id).await?;
+            records += 1;
+        }
+        Ok(ErasedData::deleted(records))
+    }
+
+    async fn remaining(&self, user_id: &UserId) -> Result<u64, DomainError> {
+        let mut remaining = 0;
+        for cycle_id in self.cycle_ids(user_id).await? {
+            remaining += self.attachments.list_by_cycle(&cycle_id).await?.len() as u64;
+        }
+        remaining += self.exports.list_for_user(user_id).await?.len() as u64;
+        Ok(remaining)
+    }
+}
+
+/// Snapshots of sessions offloaded to cold storage, and their records.
+///
+/// Snapshots go first: deleting a session drops its record with it, after
+/// which nothing points at the snapshot any more.
+pub struct ColdStorageEraser {
+    sessions: Arc<dyn SessionRepository>,
+    records: Arc<dyn SessionColdStorageRepository>,
+    cold_storage: Arc<SessionColdStorage>,
+}
+
+impl ColdStorageEraser {
+    pub fn new(
+        sessions: Arc<dyn SessionRepository>,
+        records: Arc<dyn SessionColdStorageRepository>,
+        cold_storage: Arc<SessionColdStorage>,
+    ) -> Self {
+        Self {
+            sessions,
+            records,
+            cold_storage,
+        }
+    }
+}
+
+#[async_trait]
+impl UserDataEraser for ColdStorageEraser {
+    fn section(&self) -> &'static str {
+        "cold storage"
+    }
+
+    async fn erase(&self, user_id: &UserId) -> Result<ErasedData, DomainError> {
+        let mut records = 0;
+        for session in self.sessions.find_by_user_id(user_id).await? {
+            if self.cold_storage.discard(session.id()).await? {
+                records += 1;
+            }
+        }
+        Ok(ErasedData::deleted(records))
+    }
+
+    async fn remaining(&self, user_id: &UserId) -> Result<u64, DomainError> {
+        let mut remaining = 0;
+        for session in self.sessions.find_by_user_id(user_id).await? {
+            remaining += self.records.find(session.id()).await?.is_some() as u64;
+        }
+        Ok(remaining)
+    }
+}
+
+/// The decision profile: decision history, profile summary and its
+/// revisions.
+pub struct ProfileEraser {
+    history: Arc<dyn DecisionHistoryRepository>,
+    summaries: Arc<dyn ProfileSummaryRepository>,
+    revisions: Arc<dyn ProfileRevisionRepository>,
+}
+
+impl ProfileEraser {
+    pub fn new(
+        history: Arc<dyn DecisionHistoryRepository>,
+        summaries: Arc<dyn ProfileSummaryRepository>,
+        revisions: Arc<dyn ProfileRevisionRepository>,
+    ) -> Self {
+        Self {
+            history,
+            summaries,
+            revisions,
+        }
+    }
+}
+
+#[async_trait]
+impl UserDataEraser for ProfileEraser {
+    fn section(&self) -> &'static str {
+        "profile"
+    }
+
+    async fn erase(&self, user_id: &UserId) -> Result<ErasedData, DomainError> {
+        let records = self.history.delete_for_user(user_id).await?
+            + self.summaries.delete_for_user(user_id).await?
+            + self.revisions.delete_for_user(user_id).await?;
+        Ok(ErasedData::deleted(records))
+    }
+
+    async fn remaining(&self, user_id: &UserId) -> Result<u64, DomainError> {
+        let history = self.history.list_for_user(user_id).await?.len();
+        let summary = self.summaries.find_by_user(user_id).await?.is_some() as usize;
+        let revisions = self.revisions.list_for_user(user_id).await?.len();
+        Ok((history + summary + revisions) as u64)
+    }
+}
+
+/// AI usage records.
+pub struct UsageEraser {
+    usage: Arc<dyn UsageTracker>,
+}
+
+impl UsageEraser {
+    pub fn new(usage: Arc<dyn UsageTracker>) -> Self {
+        Self { usage }
+    }
+}
+
+#[async_trait]
+impl UserDataEraser for UsageEraser {
+    fn section(&self) -> &'static str {
+        "usage"
+    }
+
+    async fn erase(&self, user_id: &UserId) -> Result<ErasedData, DomainError> {
+        let records = self
+            .usage
+            .erase_user(user_id)
+            .await
+            .map_err(|e| DomainError::new(ErrorCode::DatabaseError, e.to_string()))?;
+        Ok(ErasedData::deleted(records))
+    }
+
+    async fn remaining(&self, user_id: &UserId) -> Result<u64, DomainError> {
+        let summary = self
+            .usage
+            .get_usage_summary(user_id, Timestamp::from_unix_secs(0), Timestamp::now())
+            .await
+            .map_err(|e| DomainError::new(ErrorCode::DatabaseError, e.to_string()))?;
+        Ok(u64::from(summary.request_count))
+    }
+}
+
+/// The membership and the payment provider's customer record linked to it.
+///
+/// Deleting the customer cancels any subscription it still has. Invoices
+/// the provider must retain for tax purposes stay with the provider.
+pub struct BillingEraser {
+    memberships: Arc<dyn MembershipRepository>,
+    payments: Arc<dyn PaymentProvider>,
+}
+
+impl BillingEraser {
+    pub fn new(
+        memberships: Arc<dyn MembershipRepository>,
+        payments: Arc<dyn PaymentProvider>,
+    ) -> Self {
+        Self {
+            memberships,
+            payments,
+        }
+    }
+}
+
+#[async_trait]
+impl UserDataEraser for BillingEraser {
+    fn section(&self) -> &'static str {
+        "billing"
+    }
+
+    async fn erase(&self, user_id: &UserId) -> Result<ErasedData, DomainError> {
+        let Some(membership) = self.memberships.find_by_user_id(user_id).await? else {
+            return Ok(ErasedData::deleted(0));
+        };
+
+        let mut records = 0;
+        // Customer first: once the membership is gone nothing links to it
+        if let Some(customer_id) = membership.stripe_customer_id.as_deref() {
+            self.payments
+                .delete_customer(customer_id)
+                .await
+                .map_err(|e| DomainError::new(ErrorCode::ExternalServiceError, e.to_string()))?;
+            records += 1;
+        }
+        records += deleted(
+            self.memberships.delete(&membership.id).await,
+            ErrorCode::MembershipNotFound,
+        )?;
+        Ok(ErasedData::deleted(records))
+    }
+
+    async fn remaining(&self, user_id: &UserId) -> Result<u64, DomainError> {
+        Ok(self.memberships.find_by_user_id(user_id).await?.is_some() as u64)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::adapters::ai::InMemoryUsageTracker;
+    use crate::adapters::storage::InMemoryDocumentStorage;
+    use crate::adapters::{
+        InMemoryAttachmentRepository, InMemoryDataExportRepository,
+        InMemoryDecisionHistoryRepository, InMemoryProfileRevisionRepository,
+        InMemoryProfileSummaryRepository, InMemorySessionColdStorageRepository,
+    };
+    use crate::domain::document::Attachment;
+    use crate::domain::foundation::SessionId;
+    use crate::domain::profile::ProfileSummary;
+    use crate::domain::session::{ColdStorageRecord, Session};
+    use crate::ports::UsageRecord;
+    use crate::test_support::SessionBuilder;
+
+    fn user() -> UserId {
+        UserId::new("user-1").unwrap()
+    }
+
+    /// Sessions owned by `user()`.
+    struct UserSessions(Vec<Session>);
+
+    #[async_trait]
+    impl SessionRepository for UserSessions {
+        async fn save(&self, _session: &Session) -> Result<(), DomainError> {
+            Ok(())
+        }
+
+        async fn update(&self, _session: &Session) -> Result<(), DomainError> {
+            Ok(())
+        }
+
+        async fn find_by_id(&self, id: &SessionId) -> Result<Option<Session>, DomainError> {
+            Ok(self.0.iter().find(|s| s.id() == id).cloned())
+        }
+
+        async fn exists(&self, id: &SessionId) -> Result<bool, DomainError> {
+            Ok(self.0.iter().any(|s| s.id() == id))
+        }
+
+        async fn find_by_user_id(&self, user_id: &UserId) -> Result<Vec<Session>, DomainError> {
+            Ok(self
+                .0
+                .iter()
+                .filter(|s| s.user_id() == user_id)
+                .cloned()
+                .collect())
+        }
+
+        async fn count_active_by_user(&self, _user_id: &UserId) -> Result<u32, DomainError> {
+            Ok(0)
+        }
+
+        async fn delete(&self, _id: &SessionId) -> Result<(), DomainError> {
+            Ok(())
+        }
+    }
+
+    /// No cycle left in the database, as after offloading.
+    struct NoHotCycles;
+
+    #[async_trait]
+    impl CycleRepository for NoHotCycles {
+        async fn save(&self, _cycle: &Cycle) -> Result<(), DomainError> {
+            Ok(())
+        }
+
+        async fn update(&self, _cycle: &Cycle) -> Result<(), DomainError> {
+            Ok(())
+        }
+
+        async fn find_by_id(&self, _id: &CycleId) -> Result<Option<Cycle>, DomainError> {
+            Ok(None)
+        }
+
+        async fn exists(&self, _id: &CycleId) -> Result<bool, DomainError> {
+            Ok(false)
+        }
+
+        async fn find_by_session_id(
+            &self,
+            _session_id: &SessionId,
+        ) -> Result<Vec<Cycle>, DomainError> {
+            Ok(Vec::new())
+        }
+
+        async fn find_primary_by_session_id(
+            &self,
+            _session_id: &SessionId,
+        ) -> Result<Option<Cycle>, DomainError> {
+            Ok(None)
+        }
+
+        async fn find_branches(&self, _parent_id: &CycleId) -> Result<Vec<Cycle>, DomainError> {
+            Ok(Vec::new())
+        }
+
+        async fn count_by_session_id(&self, _session_id: &SessionId) -> Result<u32, DomainError> {
+            Ok(0)
+        }
+
+        async fn delete(&self, _id: &CycleId) -> Result<(), DomainError> {
+            Err(DomainError::new(ErrorCode::CycleNotFound, "not found"))
+        }
+    }
+
+    #[tokio::test]
+    async fn offloaded_session_attachments_and_snapshot_are_erased() {
+        let session = SessionBuilder::new().user_id(user()).archived().build();
+        let sessions: Arc<dyn SessionRepository> = Arc::new(UserSessions(vec![session.clone()]));
+        let cycles: Arc<dyn CycleRepository> = Arc::new(NoHotCycles);
+        let storage = Arc::new(InMemoryDocumentStorage::new());
+        let records = Arc::new(InMemorySessionColdStorageRepository::new());
+        let attachments = Arc::new(InMemoryAttachmentRepository::new());
+
+        let cycle_id = CycleId::new();
+        let record = ColdStorageRecord {
+            session_id: *session.id(),
+            user_id: user(),
+            blob_key: ColdStorageRecord::blob_key_for(session.id()),
+            cycle_ids: vec![cycle_id],
+            offloaded_at: Timestamp::now(),
+        };
+        storage
+            .put(&record.blob_key, "application/json", b"{}".to_vec())
+            .await
+            .unwrap();
+        records.save(&record).await.unwrap();
+        let attachment = Attachment::new(
+            cycle_id,
+            ComponentType::IssueRaising,
+            "notes.pdf",
+            "application/pdf",
+            3,
+            None,
+            user(),
+        )
+        .unwrap();
+        storage
+            .put(
+                &attachment.storage_key(),
+                "application/pdf",
+                b"pdf".to_vec(),
+            )
+            .await
+            .unwrap();
+        attachments.save(&attachment).await.unwrap();
+
+        let documents = DocumentEraser::new(
+            sessions.clone(),
+            cycles.clone(),
+            records.clone(),
+            attachments.clone(),
+            Arc::new(InMemoryDataExportRepository::new()),
+            storage.clone(),
+        );
+        let cold = ColdStorageEraser::new(
+            sessions,
+            records.clone(),
+            Arc::new(SessionColdStorage::new(records, cycles, storage.clone())),
+        );
+
+        assert_eq!(documents.remaining(&user()).await.unwrap(), 1);
+        assert_eq!(cold.remaining(&user()).await.unwrap(), 1);
+        assert_eq!(documents.erase(&user()).await.unwrap().records, 1);
+        assert_eq!(cold.erase(&user()).await.unwrap().records, 1);
+
+        assert_eq!(documents.remaining(&user()).await.unwrap(), 0);
+        assert_eq!(cold.remaining(&user()).await.unwrap(), 0);
+        assert_eq!(storage.document_count().await, 0);
+        assert_eq!(cold.erase(&user()).await.unwrap().records, 0);
+    }
+
+    #[tokio::test]
+    async fn profile_eraser_removes_summary_and_verifies_clean() {
+        let summaries = Arc::new(InMemoryProfileSummaryRepository::new());
+        summaries
+            .save(&user(), &ProfileSummary::default())
+            .await
+            .unwrap();
+        let other = UserId::new("user-2").unwrap();
+        summaries
+            .save(&other, &ProfileSummary::default())
+            .await
+            .unwrap();
+        let eraser = ProfileEraser::new(
+            Arc::new(InMemoryDecisionHistoryRepository::new()),
+            summaries.clone(),
+            Arc::new(InMemoryProfileRevisionRepository::new()),
+        );
+
+        assert_eq!(eraser.remaining(&user()).await.unwrap(), 1);
+        assert_eq!(eraser.erase(&user()).await.unwrap().records, 1);
+        assert_eq!(eraser.remaining(&user()).await.unwrap(), 0);
+        assert!(summaries.find_by_user(&other).await.unwrap().is_some());
+    }
+
+    #[tokio::test]
+    async fn usage_eraser_is_idempotent() {
+        let usage = Arc::new(InMemoryUsageTracker::new());
+        for _ in 0..2 {
+            usage
+                .record_usage(UsageRecord::new(
+                    user(),
+                    SessionId::new(),
+                    "openai",
+                    "gpt-4",
+                    100,
+                    50,
+                    3,
+                    None,
+                ))
+                .await
+                .unwrap();
+        }
+        let eraser = UsageEraser::new(usage.clone());
+
+        assert_eq!(eraser.erase(&user()).await.unwrap(), ErasedData::deleted(2));
+        assert_eq!(eraser.erase(&user()).await.unwrap(), ErasedData::deleted(0));
+        assert_eq!(eraser.remaining(&user()).await.unwrap(), 0);
+        assert!(usage.is_empty());
+    }
+}
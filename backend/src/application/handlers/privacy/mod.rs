@@ -0,0 +1,46 @@
+//! Privacy command handlers and workers.
+//!
+//! ## Commands
+//! - Request an export of all of a user's data
+//! - Erase all of a user's data
+//!
+//! ## Workers
+//! - `UserDataExporter` - Builds pending exports and emails the download link
+//! - `DataErasureWorker` - Carries out pending erasures, verifies them and emails the report
+//!
+//! ## Data sources
+//! - `DecisionHistorySource` - Decision records and outcomes behind the decision profile
+//! - `SessionHistorySource` - Sessions and the decision cycles within them
+//! - `UsageHistorySource` - AI usage totals
+//!
+//! ## Erasers
+//! - `ConversationEraser` - Conversations with the agent
+//! - `DocumentEraser` - Attached files, offloaded ones included, and data export archives
+//! - `ColdStorageEraser` - Snapshots of sessions offloaded to cold storage
+//! - `SessionEraser` - Sessions and the decision cycles within them
+//! - `ProfileEraser` - Decision history, profile summary and its revisions
+//! - `UsageEraser` - AI usage records
+//! - `BillingEraser` - Membership and the payment provider's customer record
+
+mod data_erasure_worker;
+mod data_exporter;
+mod delete_all_user_data;
+mod erasers;
+mod request_data_export;
+mod sources;
+
+pub use data_erasure_worker::DataErasureWorker;
+pub use data_exporter::UserDataExporter;
+pub use delete_all_user_data::{
+    DeleteAllUserDataCommand, DeleteAllUserDataError, DeleteAllUserDataHandler,
+    DeleteAllUserDataResult,
+};
+pub use erasers::{
+    BillingEraser, ColdStorageEraser, ConversationEraser, DocumentEraser, ProfileEraser,
+    SessionEraser, UsageEraser,
+};
+pub use request_data_export::{
+    RequestDataExportCommand, RequestDataExportError, RequestDataExportHandler,
+    RequestDataExportResult,
+};
+pub use sources::{DecisionHistorySource, SessionHistorySource, UsageHistorySource};
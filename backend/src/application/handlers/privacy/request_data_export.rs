@@ -0,0 +1,120 @@
+//! RequestDataExportHandler - Command handler for GDPR data export requests.
+//!
+//! Only records the request; `UserDataExporter` builds the archive in the
+//! background. A user has at most one export in progress at a time.
+
+use std::sync::Arc;
+
+use crate::domain::foundation::{DataExportId, DomainError, UserId};
+use crate::domain::privacy::DataExport;
+use crate::ports::DataExportRepository;
+
+/// Command to export all of a user's data.
+#[derive(Debug, Clone)]
+pub struct RequestDataExportCommand {
+    pub user_id: UserId,
+    /// Address the download link is sent to.
+    pub email: String,
+}
+
+/// Result of successfully requesting an export.
+#[derive(Debug, Clone)]
+pub struct RequestDataExportResult {
+    pub export: DataExport,
+}
+
+/// Errors from requesting an export.
+#[derive(Debug, Clone)]
+pub enum RequestDataExportError {
+    /// An earlier export for the user is still being built.
+    AlreadyInProgress(DataExportId),
+    /// Domain error.
+    Domain(DomainError),
+}
+
+impl std::fmt::Display for RequestDataExportError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RequestDataExportError::AlreadyInProgress(id) => {
+                write!(f, "Data export {} is already in progress", id)
+            }
+            RequestDataExportError::Domain(err) => write!(f, "{}", err),
+        }
+    }
+}
+
+impl std::error::Error for RequestDataExportError {}
+
+impl From<DomainError> for RequestDataExportError {
+    fn from(err: DomainError) -> Self {
+        RequestDataExportError::Domain(err)
+    }
+}
+
+/// Handler for data export requests.
+pub struct RequestDataExportHandler {
+    exports: Arc<dyn DataExportRepository>,
+}
+
+impl RequestDataExportHandler {
+    pub fn new(exports: Arc<dyn DataExportRepository>) -> Self {
+        Self { exports }
+    }
+
+    #[tracing::instrument(name = "RequestDataExportHandler::handle", skip_all)]
+    pub async fn handle(
+        &self,
+        cmd: RequestDataExportCommand,
+    ) -> Result<RequestDataExportResult, RequestDataExportError> {
+        if let Some(latest) = self.exports.latest_for_user(&cmd.user_id).await? {
+            if latest.is_in_progress() {
+                return Err(RequestDataExportError::AlreadyInProgress(latest.id));
+            }
+        }
+
+        let export = DataExport::new(cmd.user_id, cmd.email);
+        self.exports.save(&export).await?;
+        Ok(RequestDataExportResult { export })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::adapters::InMemoryDataExportRepository;
+    use crate::domain::privacy::DataExportStatus;
+
+    fn command() -> RequestDataExportCommand {
+        RequestDataExportCommand {
+            user_id: UserId::new("user-1").unwrap(),
+            email: "jo@example.com".to_string(),
+        }
+    }
+
+    #[tokio::test]
+    async fn queues_a_pending_export() {
+        let exports = Arc::new(InMemoryDataExportRepository::new());
+        let handler = RequestDataExportHandler::new(exports.clone());
+
+        let result = handler.handle(command()).await.unwrap();
+
+        assert_eq!(result.export.status, DataExportStatus::Pending);
+        let saved = exports.find_by_id(&result.export.id).await.unwrap();
+        assert_eq!(saved, Some(result.export));
+    }
+
+    #[tokio::test]
+    async fn rejects_a_second_request_while_one_is_in_progress() {
+        let exports = Arc::new(InMemoryDataExportRepository::new());
+        let handler = RequestDataExportHandler::new(exports.clone());
+        let first = handler.handle(command()).await.unwrap().export;
+
+        let err = handler.handle(command()).await.unwrap_err();
+        assert!(matches!(err, RequestDataExportError::AlreadyInProgress(id) if id == first.id));
+
+        let mut finished = first;
+        finished.record_ready(finished.storage_key());
+        exports.save(&finished).await.unwrap();
+        assert!(handler.handle(command()).await.is_ok());
+    }
+}
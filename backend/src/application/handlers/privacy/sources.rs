@@ -0,0 +1,109 @@
+//! Built-in `UserDataSource`s over the existing read ports.
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use serde_json::json;
+
+use crate::domain::foundation::{DomainError, ErrorCode, Timestamp, UserId};
+use crate::ports::{
+    CycleReader, DecisionHistoryRepository, ListOptions, SessionReader, UsageTracker,
+    UserDataSource,
+};
+
+/// Sessions read per page while collecting.
+const SESSION_PAGE_SIZE: u32 = 100;
+
+/// Every session the user owns, archived ones included, each with its
+/// decision cycles.
+pub struct SessionHistorySource {
+    sessions: Arc<dyn SessionReader>,
+    cycles: Arc<dyn CycleReader>,
+}
+
+impl SessionHistorySource {
+    pub fn new(sessions: Arc<dyn SessionReader>, cycles: Arc<dyn CycleReader>) -> Self {
+        Self { sessions, cycles }
+    }
+}
+
+#[async_trait]
+impl UserDataSource for SessionHistorySource {
+    fn section(&self) -> &'static str {
+        "sessions"
+    }
+
+    async fn collect(&self, user_id: &UserId) -> Result<serde_json::Value, DomainError> {
+        let mut sessions = Vec::new();
+        let mut page = 1;
+        loop {
+            let options = ListOptions::paginated(page, SESSION_PAGE_SIZE).with_archived();
+            let list = self.sessions.list_by_user(user_id, &options).await?;
+            for summary in list.items {
+                let session = self.sessions.get_by_id(&summary.id).await?;
+                let cycles = self.cycles.list_by_session_id(&summary.id).await?;
+                sessions.push(json!({
+                    "session": session,
+                    "cycles": cycles,
+                }));
+            }
+            if !list.has_more {
+                break;
+            }
+            page += 1;
+        }
+        Ok(serde_json::Value::Array(sessions))
+    }
+}
+
+/// Decision records and recorded outcomes from the decision profile.
+pub struct DecisionHistorySource {
+    history: Arc<dyn DecisionHistoryRepository>,
+}
+
+impl DecisionHistorySource {
+    pub fn new(history: Arc<dyn DecisionHistoryRepository>) -> Self {
+        Self { history }
+    }
+}
+
+#[async_trait]
+impl UserDataSource for DecisionHistorySource {
+    fn section(&self) -> &'static str {
+        "decision_history"
+    }
+
+    async fn collect(&self, user_id: &UserId) -> Result<serde_json::Value, DomainError> {
+        let records = self.history.list_for_user(user_id).await?;
+        serde_json::to_value(records)
+            .map_err(|e| DomainError::new(ErrorCode::InternalError, e.to_string()))
+    }
+}
+
+/// The user's AI usage over the life of the account.
+pub struct UsageHistorySource {
+    usage: Arc<dyn UsageTracker>,
+}
+
+impl UsageHistorySource {
+    pub fn new(usage: Arc<dyn UsageTracker>) -> Self {
+        Self { usage }
+    }
+}
+
+#[async_trait]
+impl UserDataSource for UsageHistorySource {
+    fn section(&self) -> &'static str {
+        "usage"
+    }
+
+    async fn collect(&self, user_id: &UserId) -> Result<serde_json::Value, DomainError> {
+        let summary = self
+            .usage
+            .get_usage_summary(user_id, Timestamp::from_unix_secs(0), Timestamp::now())
+            .await
+            .map_err(|e| DomainError::new(ErrorCode::DatabaseError, e.to_string()))?;
+        serde_json::to_value(summary)
+            .map_err(|e| DomainError::new(ErrorCode::InternalError, e.to_string()))
+    }
+}
@@ -0,0 +1,213 @@
+//! Adaptive style - Which communication preferences, if any, the agent
+//! should adapt to in a conversation.
+//!
+//! Preferences are only used when the user granted `ProfileAgentAccess` and
+//! has not turned adaptive style off for the conversation. The reading level
+//! is an accessibility setting the user chose, so it applies regardless.
+
+use std::sync::Arc;
+
+use crate::domain::consent::ConsentStatus;
+use crate::domain::foundation::{ConversationId, DomainError, UserId};
+use crate::domain::profile::{CommunicationPreferences, ReadingLevel};
+use crate::ports::{AdaptiveStyleOverrideRepository, ConsentRepository, ProfileSummaryRepository};
+
+/// Resolves the communication preferences for a conversation.
+pub struct AdaptiveStyleResolver {
+    summaries: Arc<dyn ProfileSummaryRepository>,
+    consents: Arc<dyn ConsentRepository>,
+    overrides: Arc<dyn AdaptiveStyleOverrideRepository>,
+}
+
+impl AdaptiveStyleResolver {
+    pub fn new(
+        summaries: Arc<dyn ProfileSummaryRepository>,
+        consents: Arc<dyn ConsentRepository>,
+        overrides: Arc<dyn AdaptiveStyleOverrideRepository>,
+    ) -> Self {
+        Self {
+            summaries,
+            consents,
+            overrides,
+        }
+    }
+
+    /// The user's preferences, or `None` when the agent should use its
+    /// default style.
+    #[tracing::instrument(name = "AdaptiveStyleResolver::preferences_for", skip_all)]
+    pub async fn preferences_for(
+        &self,
+        user_id: &UserId,
+        conversation_id: &ConversationId,
+    ) -> Result<Option<CommunicationPreferences>, DomainError> {
+        if self.overrides.is_disabled(conversation_id).await? {
+            return Ok(None);
+        }
+        let consent = ConsentStatus::from_records(self.consents.list_for_user(user_id).await?)
+            .profile_consent();
+        if !consent.is_some_and(|c| c.agent_access_enabled) {
+            return Ok(None);
+        }
+        Ok(self
+            .summaries
+            .find_by_user(user_id)
+            .await?
+            .map(|summary| summary.communication))
+    }
+
+    /// The reading level the user chose; `Standard` if none.
+    #[tracing::instrument(name = "AdaptiveStyleResolver::reading_level_for", skip_all)]
+    pub async fn reading_level_for(&self, user_id: &UserId) -> Result<ReadingLevel, DomainError> {
+        Ok(self
+            .summaries
+            .find_by_user(user_id)
+            .await?
+            .map(|summary| summary.communication.reading_level)
+            .unwrap_or_default())
+    }
+}
+
+/// Command to turn adaptive style on or off for a conversation.
+#[derive(Debug, Clone)]
+pub struct SetAdaptiveStyleCommand {
+    pub conversation_id: ConversationId,
+    pub enabled: bool,
+}
+
+/// Handler for the per-conversation adaptive style switch.
+///
+/// Callers must check the user owns the conversation.
+pub struct SetAdaptiveStyleHandler {
+    overrides: Arc<dyn AdaptiveStyleOverrideRepository>,
+}
+
+impl SetAdaptiveStyleHandler {
+    pub fn new(overrides: Arc<dyn AdaptiveStyleOverrideRepository>) -> Self {
+        Self { overrides }
+    }
+
+    #[tracing::instrument(name = "SetAdaptiveStyleHandler::handle", skip_all)]
+    pub async fn handle(&self, cmd: SetAdaptiveStyleCommand) -> Result<(), DomainError> {
+        self.overrides
+            .set_disabled(&cmd.conversation_id, !cmd.enabled)
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::adapters::{
+        InMemoryAdaptiveStyleOverrideRepository, InMemoryConsentRepository,
+        InMemoryProfileSummaryRepository,
+    };
+    use crate::domain::consent::{ConsentRecord, ConsentType};
+    use crate::domain::profile::{PacingPreference, ProfileConfidence, ProfileSummary};
+
+    fn user() -> UserId {
+        UserId::new("user-1").unwrap()
+    }
+
+    async fn resolver(
+        consent_types: &[ConsentType],
+    ) -> (
+        AdaptiveStyleResolver,
+        Arc<InMemoryAdaptiveStyleOverrideRepository>,
+    ) {
+        let summaries = Arc::new(InMemoryProfileSummaryRepository::new());
+        let mut communication = CommunicationPreferences::default();
+        communication.interaction_style.pacing = PacingPreference::Quick;
+        communication.reading_level = ReadingLevel::Plain;
+        summaries
+            .save(
+                &user(),
+                &ProfileSummary {
+                    risk_classification: None,
+                    risk_confidence: 0.0,
+                    decisions_analyzed: 3,
+                    profile_confidence: ProfileConfidence::Medium,
+                    top_values: vec![],
+                    decision_style: None,
+                    active_blind_spots: vec![],
+                    communication,
+                },
+            )
+            .await
+            .unwrap();
+        let consents = Arc::new(InMemoryConsentRepository::new());
+        for consent_type in consent_types {
+            consents
+                .append(&ConsentRecord::grant(user(), *consent_type, "1").unwrap())
+                .await
+                .unwrap();
+        }
+        let overrides = Arc::new(InMemoryAdaptiveStyleOverrideRepository::new());
+        (
+            AdaptiveStyleResolver::new(summaries, consents, overrides.clone()),
+            overrides,
+        )
+    }
+
+    #[tokio::test]
+    async fn uses_preferences_when_agent_access_is_granted() {
+        let (resolver, _) = resolver(&[
+            ConsentType::ProfileCollection,
+            ConsentType::ProfileAgentAccess,
+        ])
+        .await;
+
+        let prefs = resolver
+            .preferences_for(&user(), &ConversationId::new())
+            .await
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(prefs.interaction_style.pacing, PacingPreference::Quick);
+    }
+
+    #[tokio::test]
+    async fn requires_agent_access_consent() {
+        let (resolver, _) = resolver(&[ConsentType::ProfileCollection]).await;
+
+        let prefs = resolver
+            .preferences_for(&user(), &ConversationId::new())
+            .await
+            .unwrap();
+
+        assert!(prefs.is_none());
+    }
+
+    #[tokio::test]
+    async fn conversation_override_turns_adaptation_off() {
+        let (resolver, overrides) = resolver(&[
+            ConsentType::ProfileCollection,
+            ConsentType::ProfileAgentAccess,
+        ])
+        .await;
+        let conversation_id = ConversationId::new();
+
+        SetAdaptiveStyleHandler::new(overrides)
+            .handle(SetAdaptiveStyleCommand {
+                conversation_id,
+                enabled: false,
+            })
+            .await
+            .unwrap();
+
+        let prefs = resolver
+            .preferences_for(&user(), &conversation_id)
+            .await
+            .unwrap();
+        assert!(prefs.is_none());
+    }
+
+    #[tokio::test]
+    async fn reading_level_does_not_need_consent() {
+        let (resolver, _) = resolver(&[]).await;
+
+        assert_eq!(
+            resolver.reading_level_for(&user()).await.unwrap(),
+            ReadingLevel::Plain
+        );
+    }
+}
@@ -0,0 +1,169 @@
+//! BenchmarkAggregator - Background job that maintains the anonymized
+//! benchmark distributions.
+//!
+//! Each refresh recomputes every distribution from scratch: it reads the
+//! history of users who currently consent to `ProfileBenchmarking`, reduces
+//! each to per-domain means, and replaces the stored aggregates. Users who
+//! withdraw consent drop out at the next refresh.
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use tokio::sync::watch;
+use tokio::time;
+
+use crate::domain::consent::ConsentStatus;
+use crate::domain::foundation::{DomainError, Timestamp};
+use crate::domain::profile::{aggregate_benchmarks, benchmark_values, BenchmarkValues};
+use crate::ports::{BenchmarkRepository, ConsentRepository, DecisionHistoryRepository};
+
+/// Rebuilds benchmark distributions from opted-in users' decision history.
+pub struct BenchmarkAggregator {
+    history: Arc<dyn DecisionHistoryRepository>,
+    consents: Arc<dyn ConsentRepository>,
+    benchmarks: Arc<dyn BenchmarkRepository>,
+}
+
+impl BenchmarkAggregator {
+    pub fn new(
+        history: Arc<dyn DecisionHistoryRepository>,
+        consents: Arc<dyn ConsentRepository>,
+        benchmarks: Arc<dyn BenchmarkRepository>,
+    ) -> Self {
+        Self {
+            history,
+            consents,
+            benchmarks,
+        }
+    }
+
+    /// Refreshes every `refresh_interval` until shutdown is signalled.
+    pub async fn run(
+        &self,
+        refresh_interval: Duration,
+        mut shutdown: watch::Receiver<bool>,
+    ) -> Result<(), DomainError> {
+        let mut interval = time::interval(refresh_interval);
+
+        loop {
+            tokio::select! {
+                _ = shutdown.changed() => {
+                    if *shutdown.borrow() {
+                        return Ok(());
+                    }
+                }
+
+                _ = interval.tick() => {
+                    self.refresh().await?;
+                }
+            }
+        }
+    }
+
+    /// Recomputes and stores the distributions, returning how many were
+    /// published.
+    #[tracing::instrument(name = "BenchmarkAggregator::refresh", skip_all)]
+    pub async fn refresh(&self) -> Result<usize, DomainError> {
+        let mut per_user: Vec<BenchmarkValues> = Vec::new();
+        for user_id in self.history.list_user_ids().await? {
+            let consent = ConsentStatus::from_records(self.consents.list_for_user(&user_id).await?)
+                .profile_consent();
+            if !consent.is_some_and(|c| c.benchmarking_enabled) {
+                continue;
+            }
+            let decisions = self.history.list_for_user(&user_id).await?;
+            per_user.push(benchmark_values(&decisions));
+        }
+
+        let distributions = aggregate_benchmarks(&per_user, Timestamp::now());
+        self.benchmarks.replace_all(&distributions).await?;
+        tracing::info!(
+            contributors = per_user.len(),
+            distributions = distributions.len(),
+            "Benchmark distributions refreshed"
+        );
+        Ok(distributions.len())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::adapters::{
+        InMemoryBenchmarkRepository, InMemoryConsentRepository, InMemoryDecisionHistoryRepository,
+    };
+    use crate::domain::consent::{ConsentRecord, ConsentType};
+    use crate::domain::foundation::{CycleId, UserId};
+    use crate::domain::profile::{DecisionDomain, DecisionRecord, MIN_BENCHMARK_CONTRIBUTORS};
+
+    fn record(dq_score: u8) -> DecisionRecord {
+        DecisionRecord {
+            cycle_id: CycleId::new(),
+            started_at: Timestamp::now().minus_days(3),
+            date: Timestamp::now(),
+            title: "Which offer?".to_string(),
+            domain: DecisionDomain::Career,
+            dq_score,
+            dq_element_scores: Default::default(),
+            key_tradeoff: "Pay vs. commute".to_string(),
+            chosen_alternative: "Offer B".to_string(),
+            objectives: vec!["Commute".to_string()],
+            predicted_satisfaction: None,
+            expected_outcome_at: None,
+            outcome: None,
+        }
+    }
+
+    /// `opted_in` users who consent, plus one who does not.
+    async fn aggregator(opted_in: u32) -> (BenchmarkAggregator, Arc<InMemoryBenchmarkRepository>) {
+        let history = Arc::new(InMemoryDecisionHistoryRepository::new());
+        let consents = Arc::new(InMemoryConsentRepository::new());
+        for i in 0..=opted_in {
+            let user = UserId::new(format!("user-{}", i)).unwrap();
+            history.save(&user, &record(50 + i as u8)).await.unwrap();
+            let mut granted = vec![ConsentType::ProfileCollection];
+            if i < opted_in {
+                granted.push(ConsentType::ProfileBenchmarking);
+            }
+            for consent_type in granted {
+                consents
+                    .append(&ConsentRecord::grant(user.clone(), consent_type, "1").unwrap())
+                    .await
+                    .unwrap();
+            }
+        }
+        let benchmarks = Arc::new(InMemoryBenchmarkRepository::new());
+        (
+            BenchmarkAggregator::new(history, consents, benchmarks.clone()),
+            benchmarks,
+        )
+    }
+
+    #[tokio::test]
+    async fn publishes_distributions_from_consenting_users_only() {
+        let (aggregator, benchmarks) = aggregator(MIN_BENCHMARK_CONTRIBUTORS).await;
+
+        assert!(aggregator.refresh().await.unwrap() > 0);
+
+        let career = benchmarks
+            .list_for_domain(DecisionDomain::Career)
+            .await
+            .unwrap();
+        assert!(career
+            .iter()
+            .all(|d| d.contributors == MIN_BENCHMARK_CONTRIBUTORS));
+    }
+
+    #[tokio::test]
+    async fn publishes_nothing_below_the_contributor_minimum() {
+        let (aggregator, benchmarks) = aggregator(MIN_BENCHMARK_CONTRIBUTORS - 1).await;
+
+        assert_eq!(aggregator.refresh().await.unwrap(), 0);
+
+        assert!(benchmarks
+            .list_for_domain(DecisionDomain::Career)
+            .await
+            .unwrap()
+            .is_empty());
+    }
+}
@@ -0,0 +1,170 @@
+//! GetCalendarFeedHandler - Query handler for a user's decision calendar.
+//!
+//! The feed lists two kinds of events: the decide-by deadline from each
+//! active decision's Problem Frame, and the day each pending outcome survey
+//! will be emailed. Event UIDs derive from the cycle and reminder ids, so
+//! subscribed calendars update events in place when dates change.
+
+use std::sync::Arc;
+
+use crate::domain::foundation::{DomainError, UserId};
+use crate::ports::{
+    CalendarEvent, CalendarRenderer, DecisionDeadlineReader, OutcomeReminderRepository,
+};
+
+/// Calendar name shown by subscribing apps.
+const FEED_NAME: &str = "Choice Sherpa decisions";
+
+/// Renders a user's decision deadlines and follow-ups as iCalendar.
+pub struct GetCalendarFeedHandler {
+    deadlines: Arc<dyn DecisionDeadlineReader>,
+    reminders: Arc<dyn OutcomeReminderRepository>,
+    renderer: Arc<dyn CalendarRenderer>,
+}
+
+impl GetCalendarFeedHandler {
+    pub fn new(
+        deadlines: Arc<dyn DecisionDeadlineReader>,
+        reminders: Arc<dyn OutcomeReminderRepository>,
+        renderer: Arc<dyn CalendarRenderer>,
+    ) -> Self {
+        Self {
+            deadlines,
+            reminders,
+            renderer,
+        }
+    }
+
+    #[tracing::instrument(name = "GetCalendarFeedHandler::handle", skip_all)]
+    pub async fn handle(&self, user_id: &UserId) -> Result<String, DomainError> {
+        let deadlines = self.deadlines.list_deadlines(user_id).await?;
+        let reminders = self.reminders.list_scheduled_for_user(user_id).await?;
+
+        let mut events: Vec<CalendarEvent> = deadlines
+            .into_iter()
+            .map(|deadline| CalendarEvent {
+                uid: format!("deadline-{}@choicesherpa.com", deadline.cycle_id),
+                summary: format!("Decide: {}", deadline.title),
+                description: "Decide-by date from the Problem Frame of this decision.".to_string(),
+                starts_at: deadline.decide_by,
+                all_day: false,
+            })
+            .chain(reminders.into_iter().map(|reminder| {
+                CalendarEvent {
+                    uid: format!("outcome-{}@choicesherpa.com", reminder.id),
+                    summary: format!("Follow up: {}", reminder.decision_title),
+                    description: "Choice Sherpa will email a short survey asking how this \
+                              decision turned out."
+                        .to_string(),
+                    starts_at: reminder.next_attempt_at,
+                    all_day: true,
+                }
+            }))
+            .collect();
+        events.sort_by_key(|event| event.starts_at);
+
+        Ok(self.renderer.render(FEED_NAME, &events))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use async_trait::async_trait;
+
+    use crate::adapters::{IcsCalendarRenderer, InMemoryOutcomeReminderRepository};
+    use crate::domain::foundation::{CycleId, SessionId, Timestamp};
+    use crate::domain::profile::OutcomeReminder;
+    use crate::ports::DecisionDeadline;
+
+    struct FixedDeadlines(Vec<DecisionDeadline>);
+
+    #[async_trait]
+    impl DecisionDeadlineReader for FixedDeadlines {
+        async fn list_deadlines(
+            &self,
+            _user_id: &UserId,
+        ) -> Result<Vec<DecisionDeadline>, DomainError> {
+            Ok(self.0.clone())
+        }
+    }
+
+    fn user() -> UserId {
+        UserId::new("user-1").unwrap()
+    }
+
+    fn handler(
+        deadlines: Vec<DecisionDeadline>,
+        reminders: Arc<InMemoryOutcomeReminderRepository>,
+    ) -> GetCalendarFeedHandler {
+        GetCalendarFeedHandler::new(
+            Arc::new(FixedDeadlines(deadlines)),
+            reminders,
+            Arc::new(IcsCalendarRenderer::new()),
+        )
+    }
+
+    #[tokio::test]
+    async fn lists_deadlines_and_follow_ups_in_date_order() {
+        let reminders = Arc::new(InMemoryOutcomeReminderRepository::new());
+        let reminder = OutcomeReminder::schedule(
+            user(),
+            CycleId::new(),
+            "jo@example.com",
+            "Which offer?",
+            Timestamp::now().plus_days(10),
+        );
+        reminders.save(&reminder).await.unwrap();
+        let deadline = DecisionDeadline {
+            session_id: SessionId::new(),
+            cycle_id: CycleId::new(),
+            title: "Move to Lisbon?".to_string(),
+            decide_by: Timestamp::now().plus_days(3),
+        };
+
+        let ics = handler(vec![deadline.clone()], reminders)
+            .handle(&user())
+            .await
+            .unwrap();
+
+        let deadline_at = ics.find("SUMMARY:Decide: Move to Lisbon?").unwrap();
+        let follow_up_at = ics.find("SUMMARY:Follow up: Which offer?").unwrap();
+        assert!(deadline_at < follow_up_at);
+        assert!(ics.contains(&format!(
+            "UID:deadline-{}@choicesherpa.com",
+            deadline.cycle_id
+        )));
+        assert!(ics.contains(&format!("UID:outcome-{}@choicesherpa.com", reminder.id)));
+    }
+
+    #[tokio::test]
+    async fn leaves_out_sent_and_other_users_reminders() {
+        let reminders = Arc::new(InMemoryOutcomeReminderRepository::new());
+        let mut sent = OutcomeReminder::schedule(
+            user(),
+            CycleId::new(),
+            "jo@example.com",
+            "Already asked",
+            Timestamp::now(),
+        );
+        sent.issue_survey_token();
+        sent.record_sent();
+        reminders.save(&sent).await.unwrap();
+        let other = OutcomeReminder::schedule(
+            UserId::new("user-2").unwrap(),
+            CycleId::new(),
+            "sam@example.com",
+            "Not yours",
+            Timestamp::now(),
+        );
+        reminders.save(&other).await.unwrap();
+
+        let ics = handler(Vec::new(), reminders)
+            .handle(&user())
+            .await
+            .unwrap();
+
+        assert!(!ics.contains("VEVENT"));
+    }
+}
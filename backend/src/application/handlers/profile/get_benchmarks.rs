@@ -0,0 +1,206 @@
+//! GetBenchmarksHandler - Query handler for a user's benchmark placements.
+//!
+//! Benchmarking is opt-in both ways: only users who granted
+//! `ProfileBenchmarking` contribute to the aggregates, and only they can see
+//! where they fall in them.
+
+use std::sync::Arc;
+
+use crate::domain::consent::ConsentStatus;
+use crate::domain::foundation::{DomainError, UserId};
+use crate::domain::profile::{benchmark_values, BenchmarkPlacement, DecisionDomain};
+use crate::ports::{BenchmarkRepository, ConsentRepository, DecisionHistoryRepository};
+
+/// Query for a user's percentile placements.
+#[derive(Debug, Clone)]
+pub struct GetBenchmarksQuery {
+    pub user_id: UserId,
+    /// Limit to one domain; all of the user's domains when `None`.
+    pub domain: Option<DecisionDomain>,
+}
+
+/// Errors from computing benchmark placements.
+#[derive(Debug, Clone)]
+pub enum GetBenchmarksError {
+    /// The user has not opted into benchmarking.
+    BenchmarkingNotConsented,
+    /// Domain error.
+    Domain(DomainError),
+}
+
+impl std::fmt::Display for GetBenchmarksError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            GetBenchmarksError::BenchmarkingNotConsented => {
+                write!(f, "Decision benchmarking has not been enabled")
+            }
+            GetBenchmarksError::Domain(err) => write!(f, "{}", err),
+        }
+    }
+}
+
+impl std::error::Error for GetBenchmarksError {}
+
+impl From<DomainError> for GetBenchmarksError {
+    fn from(err: DomainError) -> Self {
+        GetBenchmarksError::Domain(err)
+    }
+}
+
+/// Handler for benchmark placements.
+pub struct GetBenchmarksHandler {
+    history: Arc<dyn DecisionHistoryRepository>,
+    consents: Arc<dyn ConsentRepository>,
+    benchmarks: Arc<dyn BenchmarkRepository>,
+}
+
+impl GetBenchmarksHandler {
+    pub fn new(
+        history: Arc<dyn DecisionHistoryRepository>,
+        consents: Arc<dyn ConsentRepository>,
+        benchmarks: Arc<dyn BenchmarkRepository>,
+    ) -> Self {
+        Self {
+            history,
+            consents,
+            benchmarks,
+        }
+    }
+
+    /// Placements for every metric the user has a value for and that has a
+    /// published distribution. Metrics still short of contributors are left
+    /// out.
+    #[tracing::instrument(name = "GetBenchmarksHandler::handle", skip_all)]
+    pub async fn handle(
+        &self,
+        query: GetBenchmarksQuery,
+    ) -> Result<Vec<BenchmarkPlacement>, GetBenchmarksError> {
+        let consent =
+            ConsentStatus::from_records(self.consents.list_for_user(&query.user_id).await?)
+                .profile_consent();
+        if !consent.is_some_and(|c| c.benchmarking_enabled) {
+            return Err(GetBenchmarksError::BenchmarkingNotConsented);
+        }
+
+        let decisions = self.history.list_for_user(&query.user_id).await?;
+        let values = benchmark_values(&decisions);
+
+        let mut domains: Vec<DecisionDomain> = values.keys().map(|(domain, _)| *domain).collect();
+        domains.dedup();
+        if let Some(only) = query.domain {
+            domains.retain(|domain| *domain == only);
+        }
+
+        let mut placements = Vec::new();
+        for domain in domains {
+            for distribution in self.benchmarks.list_for_domain(domain).await? {
+                if let Some(value) = values.get(&(domain, distribution.metric.clone())) {
+                    placements.push(BenchmarkPlacement::new(&distribution, *value));
+                }
+            }
+        }
+        Ok(placements)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::adapters::{
+        InMemoryBenchmarkRepository, InMemoryConsentRepository, InMemoryDecisionHistoryRepository,
+    };
+    use crate::domain::consent::{ConsentRecord, ConsentType};
+    use crate::domain::foundation::{CycleId, Timestamp};
+    use crate::domain::profile::{BenchmarkDistribution, BenchmarkMetric, DecisionRecord};
+
+    fn user() -> UserId {
+        UserId::new("user-1").unwrap()
+    }
+
+    fn record(domain: DecisionDomain) -> DecisionRecord {
+        DecisionRecord {
+            cycle_id: CycleId::new(),
+            started_at: Timestamp::now().minus_days(3),
+            date: Timestamp::now(),
+            title: "Which offer?".to_string(),
+            domain,
+            dq_score: 50,
+            dq_element_scores: Default::default(),
+            key_tradeoff: "Pay vs. commute".to_string(),
+            chosen_alternative: "Offer B".to_string(),
+            objectives: vec!["Commute".to_string()],
+            predicted_satisfaction: None,
+            expected_outcome_at: None,
+            outcome: None,
+        }
+    }
+
+    async fn handler(consent_types: &[ConsentType]) -> GetBenchmarksHandler {
+        let history = Arc::new(InMemoryDecisionHistoryRepository::new());
+        history
+            .save(&user(), &record(DecisionDomain::Career))
+            .await
+            .unwrap();
+        history
+            .save(&user(), &record(DecisionDomain::Housing))
+            .await
+            .unwrap();
+        let consents = Arc::new(InMemoryConsentRepository::new());
+        for consent_type in consent_types {
+            consents
+                .append(&ConsentRecord::grant(user(), *consent_type, "1").unwrap())
+                .await
+                .unwrap();
+        }
+        let benchmarks = Arc::new(InMemoryBenchmarkRepository::new());
+        let career = BenchmarkDistribution::from_values(
+            DecisionDomain::Career,
+            BenchmarkMetric::DqOverall,
+            (0..=100).map(|v| v as f32).collect(),
+            Timestamp::now(),
+        )
+        .unwrap();
+        benchmarks.replace_all(&[career]).await.unwrap();
+        GetBenchmarksHandler::new(history, consents, benchmarks)
+    }
+
+    #[tokio::test]
+    async fn places_the_user_in_published_distributions() {
+        let handler = handler(&[
+            ConsentType::ProfileCollection,
+            ConsentType::ProfileBenchmarking,
+        ])
+        .await;
+
+        let placements = handler
+            .handle(GetBenchmarksQuery {
+                user_id: user(),
+                domain: None,
+            })
+            .await
+            .unwrap();
+
+        // Housing has no published distribution yet, so only career shows
+        assert_eq!(placements.len(), 1);
+        assert_eq!(placements[0].domain, DecisionDomain::Career);
+        assert_eq!(placements[0].metric, "dq_overall");
+        assert_eq!(placements[0].percentile, 50);
+        assert_eq!(placements[0].contributors, 101);
+    }
+
+    #[tokio::test]
+    async fn requires_benchmarking_consent() {
+        let handler =
+            handler(&[ConsentType::ProfileCollection, ConsentType::ProfileAnalysis]).await;
+
+        let err = handler
+            .handle(GetBenchmarksQuery {
+                user_id: user(),
+                domain: Some(DecisionDomain::Career),
+            })
+            .await
+            .unwrap_err();
+
+        assert!(matches!(err, GetBenchmarksError::BenchmarkingNotConsented));
+    }
+}
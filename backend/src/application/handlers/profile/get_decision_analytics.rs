@@ -0,0 +1,226 @@
+//! GetDecisionAnalyticsHandler - Query handler for cross-decision patterns.
+//!
+//! Analyzing history is a separate consent from collecting it, so patterns
+//! are only computed for users who granted `ProfileAnalysis`. Recurring
+//! decisions are only found when decision embeddings are configured.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use crate::domain::consent::ConsentStatus;
+use crate::domain::foundation::{CycleId, DomainError, Embedding, UserId};
+use crate::domain::profile::DecisionPatternAnalytics;
+use crate::ports::{ConsentRepository, DecisionEmbeddingRepository, DecisionHistoryRepository};
+
+/// Query for a user's decision pattern analytics.
+#[derive(Debug, Clone)]
+pub struct GetDecisionAnalyticsQuery {
+    pub user_id: UserId,
+}
+
+/// Errors from computing decision analytics.
+#[derive(Debug, Clone)]
+pub enum GetDecisionAnalyticsError {
+    /// The user has not consented to profile analysis.
+    AnalysisNotConsented,
+    /// Domain error.
+    Domain(DomainError),
+}
+
+impl std::fmt::Display for GetDecisionAnalyticsError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            GetDecisionAnalyticsError::AnalysisNotConsented => {
+                write!(f, "Decision profile analysis has not been enabled")
+            }
+            GetDecisionAnalyticsError::Domain(err) => write!(f, "{}", err),
+        }
+    }
+}
+
+impl std::error::Error for GetDecisionAnalyticsError {}
+
+impl From<DomainError> for GetDecisionAnalyticsError {
+    fn from(err: DomainError) -> Self {
+        GetDecisionAnalyticsError::Domain(err)
+    }
+}
+
+/// Handler for decision pattern analytics.
+pub struct GetDecisionAnalyticsHandler {
+    history: Arc<dyn DecisionHistoryRepository>,
+    consents: Arc<dyn ConsentRepository>,
+    embeddings: Option<Arc<dyn DecisionEmbeddingRepository>>,
+}
+
+impl GetDecisionAnalyticsHandler {
+    pub fn new(
+        history: Arc<dyn DecisionHistoryRepository>,
+        consents: Arc<dyn ConsentRepository>,
+    ) -> Self {
+        Self {
+            history,
+            consents,
+            embeddings: None,
+        }
+    }
+
+    /// Also report recurring decisions, compared by their stored embeddings.
+    pub fn with_embeddings(mut self, embeddings: Arc<dyn DecisionEmbeddingRepository>) -> Self {
+        self.embeddings = Some(embeddings);
+        self
+    }
+
+    #[tracing::instrument(name = "GetDecisionAnalyticsHandler::handle", skip_all)]
+    pub async fn handle(
+        &self,
+        query: GetDecisionAnalyticsQuery,
+    ) -> Result<DecisionPatternAnalytics, GetDecisionAnalyticsError> {
+        let consent =
+            ConsentStatus::from_records(self.consents.list_for_user(&query.user_id).await?)
+                .profile_consent();
+        if !consent.is_some_and(|c| c.analysis_enabled) {
+            return Err(GetDecisionAnalyticsError::AnalysisNotConsented);
+        }
+
+        let decisions = self.history.list_for_user(&query.user_id).await?;
+        let analytics = DecisionPatternAnalytics::from_history(&decisions);
+        let Some(embeddings) = &self.embeddings else {
+            return Ok(analytics);
+        };
+
+        // One vector per cycle: the mean of its component embeddings
+        let mut by_cycle: HashMap<CycleId, Vec<Embedding>> = HashMap::new();
+        for stored in embeddings.list_for_user(&query.user_id).await? {
+            by_cycle
+                .entry(stored.cycle_id)
+                .or_default()
+                .push(stored.embedding);
+        }
+        let centroids: HashMap<CycleId, Embedding> = by_cycle
+            .into_iter()
+            .filter_map(|(cycle_id, embeddings)| {
+                Embedding::centroid(&embeddings).map(|centroid| (cycle_id, centroid))
+            })
+            .collect();
+        Ok(analytics.with_recurring_decisions(&decisions, &centroids))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::adapters::{InMemoryConsentRepository, InMemoryDecisionHistoryRepository};
+    use crate::domain::consent::{ConsentRecord, ConsentType};
+    use crate::domain::foundation::{CycleId, Timestamp};
+    use crate::domain::profile::{DecisionDomain, DecisionRecord};
+
+    fn user() -> UserId {
+        UserId::new("user-1").unwrap()
+    }
+
+    fn record() -> DecisionRecord {
+        DecisionRecord {
+            cycle_id: CycleId::new(),
+            started_at: Timestamp::now().minus_days(3),
+            date: Timestamp::now(),
+            title: "Which offer?".to_string(),
+            domain: DecisionDomain::Career,
+            dq_score: 80,
+            dq_element_scores: Default::default(),
+            key_tradeoff: "Pay vs. commute".to_string(),
+            chosen_alternative: "Offer B".to_string(),
+            objectives: vec!["Commute".to_string()],
+            predicted_satisfaction: None,
+            expected_outcome_at: None,
+            outcome: None,
+        }
+    }
+
+    async fn handler(consent_types: &[ConsentType]) -> GetDecisionAnalyticsHandler {
+        let history = Arc::new(InMemoryDecisionHistoryRepository::new());
+        history.save(&user(), &record()).await.unwrap();
+        let consents = Arc::new(InMemoryConsentRepository::new());
+        for consent_type in consent_types {
+            consents
+                .append(&ConsentRecord::grant(user(), *consent_type, "1").unwrap())
+                .await
+                .unwrap();
+        }
+        GetDecisionAnalyticsHandler::new(history, consents)
+    }
+
+    #[tokio::test]
+    async fn aggregates_history_when_analysis_is_enabled() {
+        let handler =
+            handler(&[ConsentType::ProfileCollection, ConsentType::ProfileAnalysis]).await;
+
+        let analytics = handler
+            .handle(GetDecisionAnalyticsQuery { user_id: user() })
+            .await
+            .unwrap();
+
+        assert_eq!(analytics.decisions_analyzed, 1);
+        assert!(analytics.time_to_decide.is_some());
+    }
+
+    #[tokio::test]
+    async fn requires_analysis_consent() {
+        let handler = handler(&[ConsentType::ProfileCollection]).await;
+
+        let err = handler
+            .handle(GetDecisionAnalyticsQuery { user_id: user() })
+            .await
+            .unwrap_err();
+
+        assert!(matches!(
+            err,
+            GetDecisionAnalyticsError::AnalysisNotConsented
+        ));
+    }
+
+    #[tokio::test]
+    async fn finds_recurring_decisions_from_embeddings() {
+        use crate::adapters::search::InMemoryDecisionEmbeddingRepository;
+        use crate::domain::foundation::{ComponentType, SessionId};
+        use crate::ports::{DecisionEmbedding, EmbeddingSource};
+
+        let history = Arc::new(InMemoryDecisionHistoryRepository::new());
+        let embeddings = Arc::new(InMemoryDecisionEmbeddingRepository::new());
+        for _ in 0..2 {
+            let record = record();
+            history.save(&user(), &record).await.unwrap();
+            embeddings
+                .upsert(&DecisionEmbedding {
+                    cycle_id: record.cycle_id,
+                    component_type: ComponentType::IssueRaising,
+                    source: EmbeddingSource::ComponentOutput,
+                    session_id: SessionId::new(),
+                    user_id: user(),
+                    content_hash: "hash".to_string(),
+                    model: "test-model".to_string(),
+                    embedding: Embedding::new(vec![1.0, 0.0]).unwrap(),
+                    updated_at: Timestamp::now(),
+                })
+                .await
+                .unwrap();
+        }
+        let consents = Arc::new(InMemoryConsentRepository::new());
+        for consent_type in [ConsentType::ProfileCollection, ConsentType::ProfileAnalysis] {
+            consents
+                .append(&ConsentRecord::grant(user(), consent_type, "1").unwrap())
+                .await
+                .unwrap();
+        }
+        let handler =
+            GetDecisionAnalyticsHandler::new(history, consents).with_embeddings(embeddings);
+
+        let analytics = handler
+            .handle(GetDecisionAnalyticsQuery { user_id: user() })
+            .await
+            .unwrap();
+
+        assert_eq!(analytics.recurring_decisions.len(), 1);
+        assert_eq!(analytics.recurring_decisions[0].cycle_ids.len(), 2);
+    }
+}
@@ -0,0 +1,59 @@
+//! Decision profile handlers.
+//!
+//! ## Commands
+//! - Record a new version of a user's profile summary
+//! - Roll a user's profile back to an earlier version
+//! - Turn profile-driven agent style on or off for a conversation
+//! - Change an organization's team profile settings
+//! - Record how a decision turned out, directly or from the emailed survey
+//! - Set when the user expects to know an outcome, scheduling the survey
+//! - Choose the reading level for agent responses and decision documents
+//!
+//! ## Queries
+//! - Aggregate patterns across a user's decision history
+//! - Profile version history with per-version changes
+//! - Anonymized team profile for an organization
+//! - Percentile placements against anonymized decision benchmarks
+//! - Calendar feed of decision deadlines and scheduled follow-ups
+//! - The user's reading level
+//!
+//! ## Workers
+//! - `OutcomeReminderMailer` - Emails outcome surveys once they fall due
+//! - `BenchmarkAggregator` - Rebuilds anonymized benchmark distributions
+
+mod adaptive_style;
+mod benchmark_aggregator;
+mod calendar_feed;
+mod get_benchmarks;
+mod get_decision_analytics;
+mod outcome_reminder_mailer;
+mod outcomes;
+mod profile_history;
+mod reading_level;
+mod team_profile;
+
+pub use adaptive_style::{AdaptiveStyleResolver, SetAdaptiveStyleCommand, SetAdaptiveStyleHandler};
+pub use benchmark_aggregator::BenchmarkAggregator;
+pub use calendar_feed::GetCalendarFeedHandler;
+pub use get_benchmarks::{GetBenchmarksError, GetBenchmarksHandler, GetBenchmarksQuery};
+pub use get_decision_analytics::{
+    GetDecisionAnalyticsError, GetDecisionAnalyticsHandler, GetDecisionAnalyticsQuery,
+};
+pub use outcome_reminder_mailer::{OutcomeReminderMailer, OUTCOME_SURVEY_PATH};
+pub use outcomes::{
+    OutcomeSurveyError, RecordOutcomeCommand, RecordOutcomeError, RecordOutcomeHandler,
+    RespondToOutcomeSurveyCommand, RespondToOutcomeSurveyHandler, SetExpectedOutcomeCommand,
+    SetExpectedOutcomeHandler,
+};
+pub use profile_history::{
+    GetProfileHistoryHandler, GetProfileHistoryQuery, ProfileHistoryEntry,
+    RecordProfileUpdateCommand, RecordProfileUpdateHandler, RollbackProfileCommand,
+    RollbackProfileError, RollbackProfileHandler,
+};
+pub use reading_level::{
+    GetReadingLevelHandler, GetReadingLevelQuery, SetReadingLevelCommand, SetReadingLevelHandler,
+};
+pub use team_profile::{
+    GetTeamProfileError, GetTeamProfileHandler, GetTeamProfileQuery, TeamProfileContextProvider,
+    UpdateTeamProfileSettingsCommand, UpdateTeamProfileSettingsHandler,
+};
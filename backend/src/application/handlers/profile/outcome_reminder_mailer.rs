@@ -0,0 +1,380 @@
+//! OutcomeReminderMailer - Background worker that emails outcome surveys.
+//!
+//! Polls for due `OutcomeReminder`s the same way `UserDataExporter` polls for
+//! pending exports. Each reminder is sent once its decision's expected-outcome
+//! date arrives, with one link per satisfaction level pointing at
+//! `GET /public/outcome-survey/:token`. Reminders whose outcome was recorded
+//! some other way are closed without an email. Each email carries a one-off
+//! `.ics` attachment marking the last day the survey links work.
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use tokio::sync::watch;
+use tokio::time;
+
+use crate::domain::foundation::{DomainError, Timestamp};
+use crate::domain::profile::{OutcomeReminder, SatisfactionLevel, SURVEY_VALID_DAYS};
+use crate::ports::{
+    CalendarEvent, CalendarRenderer, DecisionHistoryRepository, EmailAttachment, EmailMessage,
+    EmailSender, EmailTemplateError, EmailTemplateKind, EmailTemplateRenderer,
+    OutcomeReminderRepository, DEFAULT_EMAIL_LOCALE,
+};
+
+/// Reminders sent per poll.
+const REMINDER_BATCH_SIZE: u32 = 50;
+
+/// Public path the survey links point at.
+pub const OUTCOME_SURVEY_PATH: &str = "/public/outcome-survey";
+
+/// Content type of the attached event; `method` lets mail clients offer
+/// "Add to calendar".
+const ICS_ATTACHMENT_CONTENT_TYPE: &str = "text/calendar; charset=utf-8; method=PUBLISH";
+
+/// Emails due outcome surveys.
+pub struct OutcomeReminderMailer {
+    history: Arc<dyn DecisionHistoryRepository>,
+    reminders: Arc<dyn OutcomeReminderRepository>,
+    email_sender: Arc<dyn EmailSender>,
+    calendar: Arc<dyn CalendarRenderer>,
+    templates: Arc<dyn EmailTemplateRenderer>,
+    /// Public API origin used in emailed links, e.g. `https://api.choicesherpa.com`.
+    api_base_url: String,
+}
+
+impl OutcomeReminderMailer {
+    pub fn new(
+        history: Arc<dyn DecisionHistoryRepository>,
+        reminders: Arc<dyn OutcomeReminderRepository>,
+        email_sender: Arc<dyn EmailSender>,
+        calendar: Arc<dyn CalendarRenderer>,
+        templates: Arc<dyn EmailTemplateRenderer>,
+        api_base_url: impl Into<String>,
+    ) -> Self {
+        Self {
+            history,
+            reminders,
+            email_sender,
+            calendar,
+            templates,
+            api_base_url: api_base_url.into().trim_end_matches('/').to_string(),
+        }
+    }
+
+    /// Sends due reminders every `poll_interval` until shutdown is signalled.
+    pub async fn run(
+        &self,
+        poll_interval: Duration,
+        mut shutdown: watch::Receiver<bool>,
+    ) -> Result<(), DomainError> {
+        let mut interval = time::interval(poll_interval);
+
+        loop {
+            tokio::select! {
+                _ = shutdown.changed() => {
+                    if *shutdown.borrow() {
+                        return Ok(());
+                    }
+                }
+
+                _ = interval.tick() => {
+                    self.process_due(REMINDER_BATCH_SIZE).await?;
+                }
+            }
+        }
+    }
+
+    /// Handles up to `limit` due reminders, returning how many were emailed.
+    #[tracing::instrument(name = "OutcomeReminderMailer::process_due", skip_all)]
+    pub async fn process_due(&self, limit: u32) -> Result<usize, DomainError> {
+        let due = self.reminders.list_due(Timestamp::now(), limit).await?;
+        let mut sent = 0;
+        for mut reminder in due {
+            if self.attempt(&mut reminder).await? {
+                sent += 1;
+            }
+            self.reminders.save(&reminder).await?;
+        }
+        Ok(sent)
+    }
+
+    /// Sends one reminder, returning whether an email went out.
+    async fn attempt(&self, reminder: &mut OutcomeReminder) -> Result<bool, DomainError> {
+        let record = self
+            .history
+            .find_by_cycle(&reminder.user_id, &reminder.cycle_id)
+            .await?;
+        match record {
+            None => {
+                reminder.cancel();
+                return Ok(false);
+            }
+            Some(record) if record.outcome.is_some() => {
+                reminder.record_response();
+                return Ok(false);
+            }
+            Some(_) => {}
+        }
+
+        let token = reminder.issue_survey_token();
+        let message = match self.message(reminder, &token) {
+            Ok(message) => message,
+            Err(error) => {
+                tracing::error!(
+                    reminder_id = %reminder.id,
+                    error = %error,
+                    "Outcome reminder email could not be rendered"
+                );
+                reminder.record_failure(error.to_string(), false);
+                return Ok(false);
+            }
+        };
+        match self.email_sender.send(&message).await {
+            Ok(()) => {
+                reminder.record_sent();
+                Ok(true)
+            }
+            Err(error) => {
+                tracing::warn!(
+                    reminder_id = %reminder.id,
+                    attempts = reminder.attempts + 1,
+                    error = %error,
+                    "Outcome reminder email failed"
+                );
+                reminder.record_failure(error.to_string(), error.is_retryable());
+                Ok(false)
+            }
+        }
+    }
+
+    fn message(
+        &self,
+        reminder: &OutcomeReminder,
+        token: &str,
+    ) -> Result<EmailMessage, EmailTemplateError> {
+        let links: Vec<serde_json::Value> = (1..=5)
+            .rev()
+            .filter_map(SatisfactionLevel::from_score)
+            .map(|level| {
+                serde_json::json!({
+                    "score": level.score(),
+                    "url": format!(
+                        "{}{}/{}?satisfaction={}",
+                        self.api_base_url,
+                        OUTCOME_SURVEY_PATH,
+                        token,
+                        level.score()
+                    ),
+                })
+            })
+            .collect();
+        let context = serde_json::json!({
+            "decision_title": reminder.decision_title,
+            "links": links,
+            "valid_days": SURVEY_VALID_DAYS,
+        });
+        let email = self.templates.render(
+            EmailTemplateKind::OutcomeReminder,
+            DEFAULT_EMAIL_LOCALE,
+            &context,
+        )?;
+
+        Ok(EmailMessage {
+            to: reminder.recipient.clone(),
+            subject: email.subject,
+            text_body: email.text_body,
+            attachments: vec![self.calendar_attachment(reminder)],
+        })
+    }
+
+    /// All-day event on the last day the survey links work.
+    fn calendar_attachment(&self, reminder: &OutcomeReminder) -> EmailAttachment {
+        let event = CalendarEvent {
+            uid: format!("outcome-survey-{}@choicesherpa.com", reminder.id),
+            summary: format!(
+                "Last day to record how \"{}\" turned out",
+                reminder.decision_title
+            ),
+            description: "The outcome survey links in your Choice Sherpa email expire after today."
+                .to_string(),
+            starts_at: Timestamp::now().plus_days(SURVEY_VALID_DAYS - 1),
+            all_day: true,
+        };
+        EmailAttachment {
+            file_name: "follow-up.ics".to_string(),
+            content_type: ICS_ATTACHMENT_CONTENT_TYPE.to_string(),
+            bytes: self
+                .calendar
+                .render("Choice Sherpa follow-up", &[event])
+                .into_bytes(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::adapters::email::{InMemoryEmailSender, TeraEmailTemplateRenderer};
+    use crate::adapters::{
+        IcsCalendarRenderer, InMemoryDecisionHistoryRepository, InMemoryOutcomeReminderRepository,
+    };
+    use crate::domain::foundation::{CycleId, UserId};
+    use crate::domain::profile::{
+        hash_survey_token, DecisionDomain, DecisionRecord, OutcomeRecord, OutcomeReminderStatus,
+    };
+    use crate::ports::EmailError;
+
+    fn user() -> UserId {
+        UserId::new("user-1").unwrap()
+    }
+
+    fn setup_repositories() -> (
+        Arc<InMemoryDecisionHistoryRepository>,
+        Arc<InMemoryOutcomeReminderRepository>,
+        Arc<InMemoryEmailSender>,
+    ) {
+        (
+            Arc::new(InMemoryDecisionHistoryRepository::new()),
+            Arc::new(InMemoryOutcomeReminderRepository::new()),
+            Arc::new(InMemoryEmailSender::new()),
+        )
+    }
+
+    fn create_handler(
+        history: Arc<InMemoryDecisionHistoryRepository>,
+        reminders: Arc<InMemoryOutcomeReminderRepository>,
+        email_sender: Arc<InMemoryEmailSender>,
+    ) -> OutcomeReminderMailer {
+        OutcomeReminderMailer::new(
+            history,
+            reminders,
+            email_sender,
+            Arc::new(IcsCalendarRenderer::new()),
+            Arc::new(TeraEmailTemplateRenderer::new()),
+            "https://api.example.com/",
+        )
+    }
+
+    /// Records a decision in `history` with a reminder due now.
+    async fn decision(
+        history: &InMemoryDecisionHistoryRepository,
+        reminders: &InMemoryOutcomeReminderRepository,
+        outcome: Option<OutcomeRecord>,
+    ) -> OutcomeReminder {
+        let record = DecisionRecord {
+            cycle_id: CycleId::new(),
+            started_at: Timestamp::now().minus_days(40),
+            date: Timestamp::now().minus_days(30),
+            title: "Which offer?".to_string(),
+            domain: DecisionDomain::Career,
+            dq_score: 80,
+            dq_element_scores: Default::default(),
+            key_tradeoff: "Pay vs. commute".to_string(),
+            chosen_alternative: "Offer B".to_string(),
+            objectives: vec!["Commute".to_string()],
+            predicted_satisfaction: None,
+            expected_outcome_at: Some(Timestamp::now()),
+            outcome,
+        };
+        history.save(&user(), &record).await.unwrap();
+        let reminder = OutcomeReminder::schedule(
+            user(),
+            record.cycle_id,
+            "jo@example.com",
+            record.title,
+            Timestamp::now(),
+        );
+        reminders.save(&reminder).await.unwrap();
+        reminder
+    }
+
+    #[tokio::test]
+    async fn emails_one_link_per_satisfaction_level() {
+        let (history, reminders, email_sender) = setup_repositories();
+        let mailer = create_handler(history.clone(), reminders.clone(), email_sender.clone());
+        let reminder = decision(&history, &reminders, None).await;
+
+        assert_eq!(mailer.process_due(10).await.unwrap(), 1);
+
+        let sent = email_sender.sent();
+        assert_eq!(sent.len(), 1);
+        assert_eq!(sent[0].to, "jo@example.com");
+        assert!(sent[0].subject.contains("Which offer?"));
+        let prefix = "https://api.example.com/public/outcome-survey/";
+        assert_eq!(sent[0].text_body.matches(prefix).count(), 5);
+
+        let start = sent[0].text_body.find(prefix).unwrap() + prefix.len();
+        let token = &sent[0].text_body[start..start + 64];
+        let stored = reminders
+            .find_by_token_hash(&hash_survey_token(token))
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(stored.id, reminder.id);
+        assert_eq!(stored.status, OutcomeReminderStatus::Sent);
+    }
+
+    #[tokio::test]
+    async fn attaches_the_survey_expiry_as_a_calendar_event() {
+        let (history, reminders, email_sender) = setup_repositories();
+        let mailer = create_handler(history.clone(), reminders.clone(), email_sender.clone());
+        let reminder = decision(&history, &reminders, None).await;
+
+        mailer.process_due(10).await.unwrap();
+
+        let sent = email_sender.sent();
+        let attachment = &sent[0].attachments[0];
+        assert_eq!(attachment.file_name, "follow-up.ics");
+        assert!(attachment.content_type.starts_with("text/calendar"));
+        let ics = String::from_utf8(attachment.bytes.clone()).unwrap();
+        let last_day = Timestamp::now()
+            .plus_days(SURVEY_VALID_DAYS - 1)
+            .as_datetime()
+            .format("%Y%m%d")
+            .to_string();
+        assert!(ics.contains(&format!("DTSTART;VALUE=DATE:{}", last_day)));
+        assert!(ics.contains(&format!(
+            "UID:outcome-survey-{}@choicesherpa.com",
+            reminder.id
+        )));
+        assert!(ics.contains("Which offer?"));
+    }
+
+    #[tokio::test]
+    async fn retries_when_the_email_provider_is_unavailable() {
+        let (history, reminders, email_sender) = setup_repositories();
+        let mailer = create_handler(history.clone(), reminders.clone(), email_sender.clone());
+        let reminder = decision(&history, &reminders, None).await;
+        email_sender.fail_next(EmailError::Unavailable("timeout".to_string()));
+
+        assert_eq!(mailer.process_due(10).await.unwrap(), 0);
+
+        let stored = reminders
+            .find_by_cycle(&user(), &reminder.cycle_id)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(stored.status, OutcomeReminderStatus::Scheduled);
+        assert_eq!(stored.attempts, 1);
+        assert!(stored.survey_token_hash.is_none());
+    }
+
+    #[tokio::test]
+    async fn closes_reminders_whose_outcome_is_already_recorded() {
+        let (history, reminders, email_sender) = setup_repositories();
+        let mailer = create_handler(history.clone(), reminders.clone(), email_sender.clone());
+        let outcome = OutcomeRecord::from_survey(SatisfactionLevel::Satisfied);
+        let reminder = decision(&history, &reminders, Some(outcome)).await;
+
+        assert_eq!(mailer.process_due(10).await.unwrap(), 0);
+
+        assert!(email_sender.sent().is_empty());
+        let stored = reminders
+            .find_by_cycle(&user(), &reminder.cycle_id)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(stored.status, OutcomeReminderStatus::Responded);
+    }
+}
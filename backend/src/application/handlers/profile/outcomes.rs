@@ -0,0 +1,459 @@
+//! Outcome handlers - Journal how decisions turned out.
+//!
+//! Users record an outcome directly, or set the date they expect to know it
+//! and answer the emailed satisfaction survey (see `OutcomeReminderMailer`).
+//! Either way the outcome lands on the decision's `DecisionRecord`, where
+//! decision analytics and prediction accuracy pick it up.
+
+use std::sync::Arc;
+
+use crate::domain::foundation::{CycleId, DomainError, Timestamp, UserId};
+use crate::domain::profile::{
+    hash_survey_token, DecisionRecord, OutcomeRecord, OutcomeReminder, OutcomeReminderStatus,
+    SatisfactionLevel,
+};
+use crate::ports::{DecisionHistoryRepository, OutcomeReminderRepository};
+
+/// Errors from recording an outcome or scheduling its reminder.
+#[derive(Debug, Clone)]
+pub enum RecordOutcomeError {
+    /// The user has no completed decision for that cycle.
+    DecisionNotFound(CycleId),
+    /// The outcome is already recorded, so there is nothing to remind about.
+    AlreadyRecorded(CycleId),
+    /// Domain error.
+    Domain(DomainError),
+}
+
+impl std::fmt::Display for RecordOutcomeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RecordOutcomeError::DecisionNotFound(cycle_id) => {
+                write!(f, "Decision {} not found", cycle_id)
+            }
+            RecordOutcomeError::AlreadyRecorded(cycle_id) => {
+                write!(f, "Outcome of decision {} is already recorded", cycle_id)
+            }
+            RecordOutcomeError::Domain(err) => write!(f, "{}", err),
+        }
+    }
+}
+
+impl std::error::Error for RecordOutcomeError {}
+
+impl From<DomainError> for RecordOutcomeError {
+    fn from(err: DomainError) -> Self {
+        RecordOutcomeError::Domain(err)
+    }
+}
+
+/// Saves the outcome on the decision and closes any open reminder for it.
+async fn save_outcome(
+    history: &dyn DecisionHistoryRepository,
+    reminders: &dyn OutcomeReminderRepository,
+    user_id: &UserId,
+    mut record: DecisionRecord,
+    outcome: OutcomeRecord,
+) -> Result<DecisionRecord, DomainError> {
+    record.outcome = Some(outcome);
+    history.save(user_id, &record).await?;
+
+    if let Some(mut reminder) = reminders.find_by_cycle(user_id, &record.cycle_id).await? {
+        if reminder.is_open() {
+            reminder.record_response();
+            reminders.save(&reminder).await?;
+        }
+    }
+    Ok(record)
+}
+
+// ════════════════════════════════════════════════════════════════════════════════
+// Record Outcome
+// ════════════════════════════════════════════════════════════════════════════════
+
+/// Command to record how a decision turned out.
+#[derive(Debug, Clone)]
+pub struct RecordOutcomeCommand {
+    pub user_id: UserId,
+    pub cycle_id: CycleId,
+    pub outcome: OutcomeRecord,
+}
+
+/// Handler that records outcomes, replacing any earlier one.
+pub struct RecordOutcomeHandler {
+    history: Arc<dyn DecisionHistoryRepository>,
+    reminders: Arc<dyn OutcomeReminderRepository>,
+}
+
+impl RecordOutcomeHandler {
+    pub fn new(
+        history: Arc<dyn DecisionHistoryRepository>,
+        reminders: Arc<dyn OutcomeReminderRepository>,
+    ) -> Self {
+        Self { history, reminders }
+    }
+
+    #[tracing::instrument(name = "RecordOutcomeHandler::handle", skip_all)]
+    pub async fn handle(
+        &self,
+        cmd: RecordOutcomeCommand,
+    ) -> Result<DecisionRecord, RecordOutcomeError> {
+        let record = self
+            .history
+            .find_by_cycle(&cmd.user_id, &cmd.cycle_id)
+            .await?
+            .ok_or(RecordOutcomeError::DecisionNotFound(cmd.cycle_id))?;
+
+        Ok(save_outcome(
+            self.history.as_ref(),
+            self.reminders.as_ref(),
+            &cmd.user_id,
+            record,
+            cmd.outcome,
+        )
+        .await?)
+    }
+}
+
+// ════════════════════════════════════════════════════════════════════════════════
+// Set Expected Outcome Date
+// ════════════════════════════════════════════════════════════════════════════════
+
+/// Command to set when the user expects to know how a decision turned out.
+#[derive(Debug, Clone)]
+pub struct SetExpectedOutcomeCommand {
+    pub user_id: UserId,
+    pub cycle_id: CycleId,
+    pub expected_outcome_at: Timestamp,
+    /// Address the outcome survey is emailed to.
+    pub recipient: String,
+}
+
+/// Handler that stores the expected-outcome date and schedules the survey
+/// reminder for it.
+pub struct SetExpectedOutcomeHandler {
+    history: Arc<dyn DecisionHistoryRepository>,
+    reminders: Arc<dyn OutcomeReminderRepository>,
+}
+
+impl SetExpectedOutcomeHandler {
+    pub fn new(
+        history: Arc<dyn DecisionHistoryRepository>,
+        reminders: Arc<dyn OutcomeReminderRepository>,
+    ) -> Self {
+        Self { history, reminders }
+    }
+
+    /// Replaces any reminder still open for the decision, so moving the
+    /// date never sends two surveys.
+    #[tracing::instrument(name = "SetExpectedOutcomeHandler::handle", skip_all)]
+    pub async fn handle(
+        &self,
+        cmd: SetExpectedOutcomeCommand,
+    ) -> Result<OutcomeReminder, RecordOutcomeError> {
+        let mut record = self
+            .history
+            .find_by_cycle(&cmd.user_id, &cmd.cycle_id)
+            .await?
+            .ok_or(RecordOutcomeError::DecisionNotFound(cmd.cycle_id))?;
+        if record.outcome.is_some() {
+            return Err(RecordOutcomeError::AlreadyRecorded(cmd.cycle_id));
+        }
+
+        record.expected_outcome_at = Some(cmd.expected_outcome_at);
+        self.history.save(&cmd.user_id, &record).await?;
+
+        if let Some(mut previous) = self
+            .reminders
+            .find_by_cycle(&cmd.user_id, &cmd.cycle_id)
+            .await?
+        {
+            if previous.is_open() {
+                previous.cancel();
+                self.reminders.save(&previous).await?;
+            }
+        }
+
+        let reminder = OutcomeReminder::schedule(
+            cmd.user_id,
+            cmd.cycle_id,
+            cmd.recipient,
+            record.title,
+            cmd.expected_outcome_at,
+        );
+        self.reminders.save(&reminder).await?;
+        Ok(reminder)
+    }
+}
+
+// ════════════════════════════════════════════════════════════════════════════════
+// Respond To Outcome Survey
+// ════════════════════════════════════════════════════════════════════════════════
+
+/// Command carrying an answer from an emailed survey link.
+#[derive(Debug, Clone)]
+pub struct RespondToOutcomeSurveyCommand {
+    /// Token from the survey link.
+    pub token: String,
+    pub satisfaction: SatisfactionLevel,
+}
+
+/// Errors from answering an outcome survey.
+#[derive(Debug, Clone)]
+pub enum OutcomeSurveyError {
+    /// The token matches no survey, or its decision no longer exists.
+    InvalidLink,
+    /// The survey was already answered.
+    AlreadyResponded,
+    /// The link is past its validity window.
+    Expired,
+    /// Domain error.
+    Domain(DomainError),
+}
+
+impl std::fmt::Display for OutcomeSurveyError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            OutcomeSurveyError::InvalidLink => write!(f, "Survey link is not valid"),
+            OutcomeSurveyError::AlreadyResponded => {
+                write!(f, "Survey has already been answered")
+            }
+            OutcomeSurveyError::Expired => write!(f, "Survey link has expired"),
+            OutcomeSurveyError::Domain(err) => write!(f, "{}", err),
+        }
+    }
+}
+
+impl std::error::Error for OutcomeSurveyError {}
+
+impl From<DomainError> for OutcomeSurveyError {
+    fn from(err: DomainError) -> Self {
+        OutcomeSurveyError::Domain(err)
+    }
+}
+
+/// Handler for one-click survey answers. The token is the only credential,
+/// so no session is needed.
+pub struct RespondToOutcomeSurveyHandler {
+    history: Arc<dyn DecisionHistoryRepository>,
+    reminders: Arc<dyn OutcomeReminderRepository>,
+}
+
+impl RespondToOutcomeSurveyHandler {
+    pub fn new(
+        history: Arc<dyn DecisionHistoryRepository>,
+        reminders: Arc<dyn OutcomeReminderRepository>,
+    ) -> Self {
+        Self { history, reminders }
+    }
+
+    #[tracing::instrument(name = "RespondToOutcomeSurveyHandler::handle", skip_all)]
+    pub async fn handle(
+        &self,
+        cmd: RespondToOutcomeSurveyCommand,
+    ) -> Result<DecisionRecord, OutcomeSurveyError> {
+        let reminder = self
+            .reminders
+            .find_by_token_hash(&hash_survey_token(&cmd.token))
+            .await?
+            .ok_or(OutcomeSurveyError::InvalidLink)?;
+        if !reminder.survey_is_open(Timestamp::now()) {
+            return Err(match reminder.status {
+                OutcomeReminderStatus::Responded => OutcomeSurveyError::AlreadyResponded,
+                OutcomeReminderStatus::Sent => OutcomeSurveyError::Expired,
+                _ => OutcomeSurveyError::InvalidLink,
+            });
+        }
+
+        let record = self
+            .history
+            .find_by_cycle(&reminder.user_id, &reminder.cycle_id)
+            .await?
+            .ok_or(OutcomeSurveyError::InvalidLink)?;
+
+        Ok(save_outcome(
+            self.history.as_ref(),
+            self.reminders.as_ref(),
+            &reminder.user_id,
+            record,
+            OutcomeRecord::from_survey(cmd.satisfaction),
+        )
+        .await?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::adapters::{InMemoryDecisionHistoryRepository, InMemoryOutcomeReminderRepository};
+    use crate::domain::profile::DecisionDomain;
+
+    fn user() -> UserId {
+        UserId::new("user-1").unwrap()
+    }
+
+    fn record() -> DecisionRecord {
+        DecisionRecord {
+            cycle_id: CycleId::new(),
+            started_at: Timestamp::now().minus_days(3),
+            date: Timestamp::now(),
+            title: "Which offer?".to_string(),
+            domain: DecisionDomain::Career,
+            dq_score: 80,
+            dq_element_scores: Default::default(),
+            key_tradeoff: "Pay vs. commute".to_string(),
+            chosen_alternative: "Offer B".to_string(),
+            objectives: vec!["Commute".to_string()],
+            predicted_satisfaction: Some(SatisfactionLevel::Satisfied),
+            expected_outcome_at: None,
+            outcome: None,
+        }
+    }
+
+    /// A decision history holding one recorded decision, an empty reminder
+    /// repository, and the decision's cycle.
+    async fn setup_repositories() -> (
+        Arc<InMemoryDecisionHistoryRepository>,
+        Arc<InMemoryOutcomeReminderRepository>,
+        CycleId,
+    ) {
+        let history = Arc::new(InMemoryDecisionHistoryRepository::new());
+        let record = record();
+        history.save(&user(), &record).await.unwrap();
+        (
+            history,
+            Arc::new(InMemoryOutcomeReminderRepository::new()),
+            record.cycle_id,
+        )
+    }
+
+    fn create_handler(
+        history: Arc<InMemoryDecisionHistoryRepository>,
+        reminders: Arc<InMemoryOutcomeReminderRepository>,
+    ) -> SetExpectedOutcomeHandler {
+        SetExpectedOutcomeHandler::new(history, reminders)
+    }
+
+    async fn schedule(
+        handler: &SetExpectedOutcomeHandler,
+        cycle_id: CycleId,
+        days: i64,
+    ) -> Result<OutcomeReminder, RecordOutcomeError> {
+        handler
+            .handle(SetExpectedOutcomeCommand {
+                user_id: user(),
+                cycle_id,
+                expected_outcome_at: Timestamp::now().plus_days(days),
+                recipient: "jo@example.com".to_string(),
+            })
+            .await
+    }
+
+    /// Marks the reminder sent, as the mailer would, returning the token.
+    async fn send(
+        reminders: &InMemoryOutcomeReminderRepository,
+        mut reminder: OutcomeReminder,
+    ) -> String {
+        let token = reminder.issue_survey_token();
+        reminder.record_sent();
+        reminders.save(&reminder).await.unwrap();
+        token
+    }
+
+    #[tokio::test]
+    async fn moving_the_expected_date_replaces_the_open_reminder() {
+        let (history, reminders, cycle_id) = setup_repositories().await;
+        let handler = create_handler(history.clone(), reminders.clone());
+        let first = schedule(&handler, cycle_id, 30).await.unwrap();
+        let second = schedule(&handler, cycle_id, 60).await.unwrap();
+
+        let due = reminders
+            .list_due(Timestamp::now().plus_days(90), 10)
+            .await
+            .unwrap();
+        assert_eq!(due.len(), 1);
+        assert_eq!(due[0].id, second.id);
+        assert_ne!(first.id, second.id);
+
+        let record = history
+            .find_by_cycle(&user(), &cycle_id)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(record.expected_outcome_at, Some(second.next_attempt_at));
+    }
+
+    #[tokio::test]
+    async fn survey_answers_become_the_decision_outcome() {
+        let (history, reminders, cycle_id) = setup_repositories().await;
+        let handler = create_handler(history.clone(), reminders.clone());
+        let reminder = schedule(&handler, cycle_id, 0).await.unwrap();
+        let token = send(&reminders, reminder).await;
+        let respond = RespondToOutcomeSurveyHandler::new(history, reminders);
+
+        let record = respond
+            .handle(RespondToOutcomeSurveyCommand {
+                token: token.clone(),
+                satisfaction: SatisfactionLevel::Neutral,
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(
+            record.outcome.as_ref().map(|o| o.satisfaction),
+            Some(SatisfactionLevel::Neutral)
+        );
+        assert_eq!(record.prediction_was_accurate(), Some(true));
+
+        let again = respond
+            .handle(RespondToOutcomeSurveyCommand {
+                token,
+                satisfaction: SatisfactionLevel::VerySatisfied,
+            })
+            .await;
+        assert!(matches!(again, Err(OutcomeSurveyError::AlreadyResponded)));
+    }
+
+    #[tokio::test]
+    async fn unknown_tokens_are_rejected() {
+        let (history, reminders, _) = setup_repositories().await;
+
+        let result = RespondToOutcomeSurveyHandler::new(history, reminders)
+            .handle(RespondToOutcomeSurveyCommand {
+                token: "not-a-token".to_string(),
+                satisfaction: SatisfactionLevel::Satisfied,
+            })
+            .await;
+
+        assert!(matches!(result, Err(OutcomeSurveyError::InvalidLink)));
+    }
+
+    #[tokio::test]
+    async fn recording_an_outcome_directly_closes_the_reminder() {
+        let (history, reminders, cycle_id) = setup_repositories().await;
+        let handler = create_handler(history.clone(), reminders.clone());
+        let reminder = schedule(&handler, cycle_id, 30).await.unwrap();
+
+        RecordOutcomeHandler::new(history, reminders.clone())
+            .handle(RecordOutcomeCommand {
+                user_id: user(),
+                cycle_id,
+                outcome: OutcomeRecord::from_survey(SatisfactionLevel::Satisfied),
+            })
+            .await
+            .unwrap();
+
+        let reminder = reminders
+            .find_by_cycle(&user(), &reminder.cycle_id)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(reminder.status, OutcomeReminderStatus::Responded);
+
+        let rescheduled = schedule(&handler, cycle_id, 0).await;
+        assert!(matches!(
+            rescheduled,
+            Err(RecordOutcomeError::AlreadyRecorded(_))
+        ));
+    }
+}
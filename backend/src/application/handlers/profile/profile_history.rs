@@ -0,0 +1,360 @@
+//! Profile history handlers - Record, inspect and roll back changes to a
+//! user's profile summary.
+//!
+//! Every update goes through `RecordProfileUpdateHandler`, which appends a
+//! revision before replacing the current summary, so the history always
+//! explains the summary users see. The reading level is chosen by the user
+//! rather than learned, so analysis updates and rollbacks keep the current
+//! one.
+
+use std::sync::Arc;
+
+use crate::domain::foundation::{CycleId, DomainError, Timestamp, UserId};
+use crate::domain::profile::{
+    diff_summaries, FieldChange, ProfileRevision, ProfileSummary, RevisionReason,
+};
+use crate::ports::{ProfileRevisionRepository, ProfileSummaryRepository};
+
+// ════════════════════════════════════════════════════════════════════════════════
+// Record Profile Update
+// ════════════════════════════════════════════════════════════════════════════════
+
+/// Command to replace a user's profile summary.
+#[derive(Debug, Clone)]
+pub struct RecordProfileUpdateCommand {
+    pub user_id: UserId,
+    pub summary: ProfileSummary,
+    /// Decision whose analysis produced the update, if any.
+    pub cycle_id: Option<CycleId>,
+}
+
+/// Handler that versions and saves profile summaries.
+pub struct RecordProfileUpdateHandler {
+    summaries: Arc<dyn ProfileSummaryRepository>,
+    revisions: Arc<dyn ProfileRevisionRepository>,
+}
+
+impl RecordProfileUpdateHandler {
+    pub fn new(
+        summaries: Arc<dyn ProfileSummaryRepository>,
+        revisions: Arc<dyn ProfileRevisionRepository>,
+    ) -> Self {
+        Self {
+            summaries,
+            revisions,
+        }
+    }
+
+    /// Returns the new revision, or `None` if the summary did not change.
+    #[tracing::instrument(name = "RecordProfileUpdateHandler::handle", skip_all)]
+    pub async fn handle(
+        &self,
+        cmd: RecordProfileUpdateCommand,
+    ) -> Result<Option<ProfileRevision>, DomainError> {
+        let latest = self.revisions.latest(&cmd.user_id).await?;
+        let mut summary = cmd.summary;
+        if let Some(latest) = &latest {
+            summary.communication.reading_level = latest.summary.communication.reading_level;
+        }
+        if latest.as_ref().is_some_and(|r| r.summary == summary) {
+            return Ok(None);
+        }
+
+        let revision = ProfileRevision::next(
+            latest.as_ref(),
+            summary,
+            RevisionReason::Analysis {
+                cycle_id: cmd.cycle_id,
+            },
+        );
+        self.revisions.append(&cmd.user_id, &revision).await?;
+        self.summaries.save(&cmd.user_id, &revision.summary).await?;
+        Ok(Some(revision))
+    }
+}
+
+// ════════════════════════════════════════════════════════════════════════════════
+// Get Profile History
+// ════════════════════════════════════════════════════════════════════════════════
+
+/// Query for a user's profile history.
+#[derive(Debug, Clone)]
+pub struct GetProfileHistoryQuery {
+    pub user_id: UserId,
+}
+
+/// One revision and what it changed from the one before.
+#[derive(Debug, Clone)]
+pub struct ProfileHistoryEntry {
+    pub version: u32,
+    pub reason: RevisionReason,
+    pub created_at: Timestamp,
+    /// Empty for the first revision.
+    pub changes: Vec<FieldChange>,
+}
+
+/// Handler for profile history queries.
+pub struct GetProfileHistoryHandler {
+    revisions: Arc<dyn ProfileRevisionRepository>,
+}
+
+impl GetProfileHistoryHandler {
+    pub fn new(revisions: Arc<dyn ProfileRevisionRepository>) -> Self {
+        Self { revisions }
+    }
+
+    /// Returns revisions newest first.
+    #[tracing::instrument(name = "GetProfileHistoryHandler::handle", skip_all)]
+    pub async fn handle(
+        &self,
+        query: GetProfileHistoryQuery,
+    ) -> Result<Vec<ProfileHistoryEntry>, DomainError> {
+        let revisions = self.revisions.list_for_user(&query.user_id).await?;
+        let mut entries: Vec<ProfileHistoryEntry> = revisions
+            .iter()
+            .enumerate()
+            .map(|(i, revision)| ProfileHistoryEntry {
+                version: revision.version,
+                reason: revision.reason,
+                created_at: revision.created_at,
+                changes: match i.checked_sub(1) {
+                    Some(prev) => diff_summaries(&revisions[prev].summary, &revision.summary),
+                    None => Vec::new(),
+                },
+            })
+            .collect();
+        entries.reverse();
+        Ok(entries)
+    }
+}
+
+// ════════════════════════════════════════════════════════════════════════════════
+// Rollback Profile
+// ════════════════════════════════════════════════════════════════════════════════
+
+/// Command to restore an earlier version of the user's profile.
+#[derive(Debug, Clone)]
+pub struct RollbackProfileCommand {
+    pub user_id: UserId,
+    pub to_version: u32,
+}
+
+/// Errors from rolling back a profile.
+#[derive(Debug, Clone)]
+pub enum RollbackProfileError {
+    /// No revision with that version exists for the user.
+    VersionNotFound(u32),
+    /// The requested version already matches the current profile.
+    AlreadyCurrent(u32),
+    /// Domain error.
+    Domain(DomainError),
+}
+
+impl std::fmt::Display for RollbackProfileError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RollbackProfileError::VersionNotFound(version) => {
+                write!(f, "Profile version {} not found", version)
+            }
+            RollbackProfileError::AlreadyCurrent(version) => {
+                write!(f, "Profile version {} is already current", version)
+            }
+            RollbackProfileError::Domain(err) => write!(f, "{}", err),
+        }
+    }
+}
+
+impl std::error::Error for RollbackProfileError {}
+
+impl From<DomainError> for RollbackProfileError {
+    fn from(err: DomainError) -> Self {
+        RollbackProfileError::Domain(err)
+    }
+}
+
+/// Handler for profile rollbacks.
+pub struct RollbackProfileHandler {
+    summaries: Arc<dyn ProfileSummaryRepository>,
+    revisions: Arc<dyn ProfileRevisionRepository>,
+}
+
+impl RollbackProfileHandler {
+    pub fn new(
+        summaries: Arc<dyn ProfileSummaryRepository>,
+        revisions: Arc<dyn ProfileRevisionRepository>,
+    ) -> Self {
+        Self {
+            summaries,
+            revisions,
+        }
+    }
+
+    /// Appends a revision restoring `to_version` and makes it current.
+    #[tracing::instrument(name = "RollbackProfileHandler::handle", skip_all)]
+    pub async fn handle(
+        &self,
+        cmd: RollbackProfileCommand,
+    ) -> Result<ProfileRevision, RollbackProfileError> {
+        let target = self
+            .revisions
+            .find(&cmd.user_id, cmd.to_version)
+            .await?
+            .ok_or(RollbackProfileError::VersionNotFound(cmd.to_version))?;
+        let latest = self.revisions.latest(&cmd.user_id).await?;
+        let mut summary = target.summary;
+        if let Some(latest) = &latest {
+            summary.communication.reading_level = latest.summary.communication.reading_level;
+        }
+        if latest.as_ref().is_some_and(|r| r.summary == summary) {
+            return Err(RollbackProfileError::AlreadyCurrent(cmd.to_version));
+        }
+
+        let revision = ProfileRevision::next(
+            latest.as_ref(),
+            summary,
+            RevisionReason::Rollback {
+                to_version: cmd.to_version,
+            },
+        );
+        self.revisions.append(&cmd.user_id, &revision).await?;
+        self.summaries.save(&cmd.user_id, &revision.summary).await?;
+        Ok(revision)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::adapters::{InMemoryProfileRevisionRepository, InMemoryProfileSummaryRepository};
+    use crate::domain::profile::{ProfileConfidence, RiskClassification};
+
+    fn user() -> UserId {
+        UserId::new("user-1").unwrap()
+    }
+
+    fn summary(risk: RiskClassification) -> ProfileSummary {
+        ProfileSummary {
+            risk_classification: Some(risk),
+            risk_confidence: 0.6,
+            decisions_analyzed: 4,
+            profile_confidence: ProfileConfidence::Medium,
+            top_values: vec![],
+            decision_style: None,
+            active_blind_spots: vec![],
+            communication: Default::default(),
+        }
+    }
+
+    fn setup_repositories() -> (
+        Arc<InMemoryProfileSummaryRepository>,
+        Arc<InMemoryProfileRevisionRepository>,
+    ) {
+        (
+            Arc::new(InMemoryProfileSummaryRepository::new()),
+            Arc::new(InMemoryProfileRevisionRepository::new()),
+        )
+    }
+
+    fn create_handler(
+        summaries: Arc<InMemoryProfileSummaryRepository>,
+        revisions: Arc<InMemoryProfileRevisionRepository>,
+    ) -> RecordProfileUpdateHandler {
+        RecordProfileUpdateHandler::new(summaries, revisions)
+    }
+
+    async fn record(
+        handler: &RecordProfileUpdateHandler,
+        risk: RiskClassification,
+    ) -> Option<ProfileRevision> {
+        handler
+            .handle(RecordProfileUpdateCommand {
+                user_id: user(),
+                summary: summary(risk),
+                cycle_id: None,
+            })
+            .await
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn unchanged_summaries_are_not_versioned() {
+        let (summaries, revisions) = setup_repositories();
+        let handler = create_handler(summaries, revisions);
+
+        let first = record(&handler, RiskClassification::RiskAverse).await;
+        assert_eq!(first.unwrap().version, 1);
+        assert!(record(&handler, RiskClassification::RiskAverse)
+            .await
+            .is_none());
+    }
+
+    #[tokio::test]
+    async fn history_is_newest_first_with_diffs() {
+        let (summaries, revisions) = setup_repositories();
+        let handler = create_handler(summaries, revisions.clone());
+        record(&handler, RiskClassification::RiskAverse).await;
+        record(&handler, RiskClassification::RiskSeeking).await;
+
+        let history = GetProfileHistoryHandler::new(revisions)
+            .handle(GetProfileHistoryQuery { user_id: user() })
+            .await
+            .unwrap();
+
+        assert_eq!(history[0].version, 2);
+        assert_eq!(history[0].changes[0].field, "risk_classification");
+        assert!(history[1].changes.is_empty());
+    }
+
+    #[tokio::test]
+    async fn rollback_appends_a_revision_restoring_the_old_summary() {
+        let (summaries, revisions) = setup_repositories();
+        let handler = create_handler(summaries.clone(), revisions.clone());
+        record(&handler, RiskClassification::RiskAverse).await;
+        record(&handler, RiskClassification::RiskSeeking).await;
+
+        let revision = RollbackProfileHandler::new(summaries.clone(), revisions)
+            .handle(RollbackProfileCommand {
+                user_id: user(),
+                to_version: 1,
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(revision.version, 3);
+        assert_eq!(revision.reason, RevisionReason::Rollback { to_version: 1 });
+        let current = summaries.find_by_user(&user()).await.unwrap().unwrap();
+        assert_eq!(
+            current.risk_classification,
+            Some(RiskClassification::RiskAverse)
+        );
+    }
+
+    #[tokio::test]
+    async fn rollback_rejects_unknown_and_current_versions() {
+        let (summaries, revisions) = setup_repositories();
+        record(
+            &create_handler(summaries.clone(), revisions.clone()),
+            RiskClassification::RiskAverse,
+        )
+        .await;
+        let handler = RollbackProfileHandler::new(summaries, revisions);
+
+        let missing = handler
+            .handle(RollbackProfileCommand {
+                user_id: user(),
+                to_version: 7,
+            })
+            .await
+            .unwrap_err();
+        assert!(matches!(missing, RollbackProfileError::VersionNotFound(7)));
+
+        let current = handler
+            .handle(RollbackProfileCommand {
+                user_id: user(),
+                to_version: 1,
+            })
+            .await
+            .unwrap_err();
+        assert!(matches!(current, RollbackProfileError::AlreadyCurrent(1)));
+    }
+}
@@ -0,0 +1,204 @@
+//! Reading level handlers - The user's plain-language setting.
+//!
+//! The reading level lives in the profile summary's communication
+//! preferences, but unlike the rest of the summary it is chosen, not learned.
+//! It applies whether or not the user consented to profile analysis, so
+//! setting it on an account with no profile yet starts an empty one.
+
+use std::sync::Arc;
+
+use crate::domain::foundation::{DomainError, UserId};
+use crate::domain::profile::{ProfileRevision, ReadingLevel, RevisionReason};
+use crate::ports::{ProfileRevisionRepository, ProfileSummaryRepository};
+
+/// Query for a user's reading level.
+#[derive(Debug, Clone)]
+pub struct GetReadingLevelQuery {
+    pub user_id: UserId,
+}
+
+/// Handler for reading level queries.
+pub struct GetReadingLevelHandler {
+    summaries: Arc<dyn ProfileSummaryRepository>,
+}
+
+impl GetReadingLevelHandler {
+    pub fn new(summaries: Arc<dyn ProfileSummaryRepository>) -> Self {
+        Self { summaries }
+    }
+
+    /// The user's reading level; `Standard` if they never chose one.
+    #[tracing::instrument(name = "GetReadingLevelHandler::handle", skip_all)]
+    pub async fn handle(&self, query: GetReadingLevelQuery) -> Result<ReadingLevel, DomainError> {
+        Ok(self
+            .summaries
+            .find_by_user(&query.user_id)
+            .await?
+            .map(|summary| summary.communication.reading_level)
+            .unwrap_or_default())
+    }
+}
+
+/// Command to change a user's reading level.
+#[derive(Debug, Clone)]
+pub struct SetReadingLevelCommand {
+    pub user_id: UserId,
+    pub reading_level: ReadingLevel,
+}
+
+/// Handler that records reading level changes as profile revisions.
+pub struct SetReadingLevelHandler {
+    summaries: Arc<dyn ProfileSummaryRepository>,
+    revisions: Arc<dyn ProfileRevisionRepository>,
+}
+
+impl SetReadingLevelHandler {
+    pub fn new(
+        summaries: Arc<dyn ProfileSummaryRepository>,
+        revisions: Arc<dyn ProfileRevisionRepository>,
+    ) -> Self {
+        Self {
+            summaries,
+            revisions,
+        }
+    }
+
+    /// Returns the new revision, or `None` if the level did not change.
+    #[tracing::instrument(name = "SetReadingLevelHandler::handle", skip_all)]
+    pub async fn handle(
+        &self,
+        cmd: SetReadingLevelCommand,
+    ) -> Result<Option<ProfileRevision>, DomainError> {
+        let latest = self.revisions.latest(&cmd.user_id).await?;
+        let mut summary = match &latest {
+            Some(revision) => revision.summary.clone(),
+            None => self
+                .summaries
+                .find_by_user(&cmd.user_id)
+                .await?
+                .unwrap_or_default(),
+        };
+        if summary.communication.reading_level == cmd.reading_level {
+            return Ok(None);
+        }
+        summary.communication.reading_level = cmd.reading_level;
+
+        let revision = ProfileRevision::next(latest.as_ref(), summary, RevisionReason::Preferences);
+        self.revisions.append(&cmd.user_id, &revision).await?;
+        self.summaries.save(&cmd.user_id, &revision.summary).await?;
+        Ok(Some(revision))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::adapters::{InMemoryProfileRevisionRepository, InMemoryProfileSummaryRepository};
+    use crate::application::handlers::profile::{
+        RecordProfileUpdateCommand, RecordProfileUpdateHandler, RollbackProfileCommand,
+        RollbackProfileHandler,
+    };
+    use crate::domain::profile::{ProfileSummary, RiskClassification};
+
+    fn user() -> UserId {
+        UserId::new("user-1").unwrap()
+    }
+
+    fn setup_repositories() -> (
+        Arc<InMemoryProfileSummaryRepository>,
+        Arc<InMemoryProfileRevisionRepository>,
+    ) {
+        (
+            Arc::new(InMemoryProfileSummaryRepository::new()),
+            Arc::new(InMemoryProfileRevisionRepository::new()),
+        )
+    }
+
+    fn create_handler(
+        summaries: Arc<InMemoryProfileSummaryRepository>,
+        revisions: Arc<InMemoryProfileRevisionRepository>,
+    ) -> SetReadingLevelHandler {
+        SetReadingLevelHandler::new(summaries, revisions)
+    }
+
+    async fn set(
+        handler: &SetReadingLevelHandler,
+        reading_level: ReadingLevel,
+    ) -> Option<ProfileRevision> {
+        handler
+            .handle(SetReadingLevelCommand {
+                user_id: user(),
+                reading_level,
+            })
+            .await
+            .unwrap()
+    }
+
+    async fn get(summaries: Arc<InMemoryProfileSummaryRepository>) -> ReadingLevel {
+        GetReadingLevelHandler::new(summaries)
+            .handle(GetReadingLevelQuery { user_id: user() })
+            .await
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn defaults_to_standard_without_a_profile() {
+        let (summaries, _) = setup_repositories();
+
+        assert_eq!(get(summaries).await, ReadingLevel::Standard);
+    }
+
+    #[tokio::test]
+    async fn setting_a_level_starts_an_empty_profile() {
+        let (summaries, revisions) = setup_repositories();
+        let handler = create_handler(summaries.clone(), revisions);
+
+        let revision = set(&handler, ReadingLevel::Plain).await.unwrap();
+
+        assert_eq!(revision.version, 1);
+        assert_eq!(revision.reason, RevisionReason::Preferences);
+        assert_eq!(revision.summary.decisions_analyzed, 0);
+        assert_eq!(get(summaries).await, ReadingLevel::Plain);
+        assert!(set(&handler, ReadingLevel::Plain).await.is_none());
+    }
+
+    #[tokio::test]
+    async fn analysis_updates_keep_the_chosen_level() {
+        let (summaries, revisions) = setup_repositories();
+        let handler = create_handler(summaries.clone(), revisions.clone());
+        set(&handler, ReadingLevel::Simple).await;
+
+        RecordProfileUpdateHandler::new(summaries.clone(), revisions)
+            .handle(RecordProfileUpdateCommand {
+                user_id: user(),
+                summary: ProfileSummary {
+                    risk_classification: Some(RiskClassification::RiskAverse),
+                    decisions_analyzed: 3,
+                    ..Default::default()
+                },
+                cycle_id: None,
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(get(summaries).await, ReadingLevel::Simple);
+    }
+
+    #[tokio::test]
+    async fn rollbacks_keep_the_chosen_level() {
+        let (summaries, revisions) = setup_repositories();
+        let handler = create_handler(summaries.clone(), revisions.clone());
+        set(&handler, ReadingLevel::Plain).await;
+        set(&handler, ReadingLevel::Simple).await;
+
+        RollbackProfileHandler::new(summaries.clone(), revisions)
+            .handle(RollbackProfileCommand {
+                user_id: user(),
+                to_version: 1,
+            })
+            .await
+            .unwrap_err();
+
+        assert_eq!(get(summaries).await, ReadingLevel::Simple);
+    }
+}
@@ -0,0 +1,381 @@
+//! Team profile handlers - Anonymized aggregates of an organization's
+//! member profiles.
+//!
+//! Members are only included once they grant `ProfileTeamSharing`. The
+//! aggregate itself enforces the minimum team size, so no query here can
+//! single out an individual member.
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use crate::application::handlers::organization::OrganizationResolver;
+use crate::domain::consent::ConsentStatus;
+use crate::domain::foundation::{DomainError, OrganizationId, UserId};
+use crate::domain::profile::{ProfileSummary, TeamProfile, TeamProfileError, TeamProfileSettings};
+use crate::ports::{
+    AgentContextProvider, ConsentRepository, OrganizationRepository, ProfileSummaryRepository,
+    TeamProfileSettingsRepository,
+};
+
+/// Summaries of an organization's members who consented to team sharing.
+async fn consenting_members(
+    summaries: &dyn ProfileSummaryRepository,
+    consents: &dyn ConsentRepository,
+    organizations: &OrganizationResolver,
+    organization: &str,
+) -> Result<Vec<ProfileSummary>, DomainError> {
+    let Ok(organization_id) = organization.parse::<OrganizationId>() else {
+        return Ok(Vec::new());
+    };
+    let mut included = Vec::new();
+    for user_id in organizations.members(&organization_id).await? {
+        let consent =
+            ConsentStatus::from_records(consents.list_for_user(&user_id).await?).profile_consent();
+        if !consent.is_some_and(|c| c.team_sharing_enabled) {
+            continue;
+        }
+        if let Some(summary) = summaries.find_by_user(&user_id).await? {
+            included.push(summary);
+        }
+    }
+    Ok(included)
+}
+
+// ════════════════════════════════════════════════════════════════════════════════
+// Get Team Profile
+// ════════════════════════════════════════════════════════════════════════════════
+
+/// Query for an organization's team profile.
+#[derive(Debug, Clone)]
+pub struct GetTeamProfileQuery {
+    pub organization: String,
+}
+
+/// Errors from building a team profile.
+#[derive(Debug, Clone)]
+pub enum GetTeamProfileError {
+    /// Too few members consented for the profile to stay anonymous.
+    TooFewMembers { required: u32, available: u32 },
+    /// Domain error.
+    Domain(DomainError),
+}
+
+impl std::fmt::Display for GetTeamProfileError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            GetTeamProfileError::TooFewMembers {
+                required,
+                available,
+            } => write!(
+                f,
+                "A team profile needs {} consenting members; {} have consented",
+                required, available
+            ),
+            GetTeamProfileError::Domain(err) => write!(f, "{}", err),
+        }
+    }
+}
+
+impl std::error::Error for GetTeamProfileError {}
+
+impl From<DomainError> for GetTeamProfileError {
+    fn from(err: DomainError) -> Self {
+        GetTeamProfileError::Domain(err)
+    }
+}
+
+impl From<TeamProfileError> for GetTeamProfileError {
+    fn from(err: TeamProfileError) -> Self {
+        match err {
+            TeamProfileError::TooFewMembers {
+                required,
+                available,
+            } => GetTeamProfileError::TooFewMembers {
+                required,
+                available,
+            },
+        }
+    }
+}
+
+/// Handler for team profile queries.
+pub struct GetTeamProfileHandler {
+    summaries: Arc<dyn ProfileSummaryRepository>,
+    consents: Arc<dyn ConsentRepository>,
+    organizations: OrganizationResolver,
+}
+
+impl GetTeamProfileHandler {
+    pub fn new(
+        summaries: Arc<dyn ProfileSummaryRepository>,
+        consents: Arc<dyn ConsentRepository>,
+        organizations: Arc<dyn OrganizationRepository>,
+    ) -> Self {
+        Self {
+            summaries,
+            consents,
+            organizations: OrganizationResolver::new(organizations),
+        }
+    }
+
+    #[tracing::instrument(name = "GetTeamProfileHandler::handle", skip_all)]
+    pub async fn handle(
+        &self,
+        query: GetTeamProfileQuery,
+    ) -> Result<TeamProfile, GetTeamProfileError> {
+        let members = consenting_members(
+            self.summaries.as_ref(),
+            self.consents.as_ref(),
+            &self.organizations,
+            &query.organization,
+        )
+        .await?;
+        Ok(TeamProfile::aggregate(query.organization, &members)?)
+    }
+}
+
+// ════════════════════════════════════════════════════════════════════════════════
+// Update Team Profile Settings
+// ════════════════════════════════════════════════════════════════════════════════
+
+/// Command to change an organization's team profile settings.
+#[derive(Debug, Clone)]
+pub struct UpdateTeamProfileSettingsCommand {
+    pub organization: String,
+    pub agent_context_enabled: bool,
+}
+
+/// Handler for updating team profile settings.
+pub struct UpdateTeamProfileSettingsHandler {
+    settings: Arc<dyn TeamProfileSettingsRepository>,
+}
+
+impl UpdateTeamProfileSettingsHandler {
+    pub fn new(settings: Arc<dyn TeamProfileSettingsRepository>) -> Self {
+        Self { settings }
+    }
+
+    #[tracing::instrument(name = "UpdateTeamProfileSettingsHandler::handle", skip_all)]
+    pub async fn handle(
+        &self,
+        cmd: UpdateTeamProfileSettingsCommand,
+    ) -> Result<TeamProfileSettings, DomainError> {
+        let settings = TeamProfileSettings::new(cmd.organization, cmd.agent_context_enabled);
+        self.settings.save(&settings).await?;
+        Ok(settings)
+    }
+}
+
+// ════════════════════════════════════════════════════════════════════════════════
+// Agent Context
+// ════════════════════════════════════════════════════════════════════════════════
+
+/// Gives agents the team profile of the user's organization, when the
+/// organization has turned that on and enough members have consented.
+pub struct TeamProfileContextProvider {
+    summaries: Arc<dyn ProfileSummaryRepository>,
+    consents: Arc<dyn ConsentRepository>,
+    settings: Arc<dyn TeamProfileSettingsRepository>,
+    organizations: OrganizationResolver,
+}
+
+impl TeamProfileContextProvider {
+    pub fn new(
+        summaries: Arc<dyn ProfileSummaryRepository>,
+        consents: Arc<dyn ConsentRepository>,
+        settings: Arc<dyn TeamProfileSettingsRepository>,
+        organizations: Arc<dyn OrganizationRepository>,
+    ) -> Self {
+        Self {
+            summaries,
+            consents,
+            settings,
+            organizations: OrganizationResolver::new(organizations),
+        }
+    }
+}
+
+#[async_trait]
+impl AgentContextProvider for TeamProfileContextProvider {
+    async fn context_for(&self, user_id: &UserId) -> Result<Option<String>, DomainError> {
+        let Some(organization) = self
+            .organizations
+            .organization_of(user_id)
+            .await?
+            .map(|id| id.to_string())
+        else {
+            return Ok(None);
+        };
+        let enabled = self
+            .settings
+            .get(&organization)
+            .await?
+            .is_some_and(|s| s.agent_context_enabled);
+        if !enabled {
+            return Ok(None);
+        }
+
+        let members = consenting_members(
+            self.summaries.as_ref(),
+            self.consents.as_ref(),
+            &self.organizations,
+            &organization,
+        )
+        .await?;
+        Ok(TeamProfile::aggregate(organization, &members)
+            .ok()
+            .map(|profile| profile.agent_context()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::adapters::{
+        InMemoryConsentRepository, InMemoryOrganizationRepository,
+        InMemoryProfileSummaryRepository, InMemoryTeamProfileSettingsRepository,
+    };
+    use crate::domain::consent::{ConsentRecord, ConsentType};
+    use crate::domain::organization::{Organization, OrganizationRole};
+    use crate::domain::profile::{ProfileConfidence, RiskClassification, StyleClassification};
+
+    fn summary() -> ProfileSummary {
+        ProfileSummary {
+            risk_classification: Some(RiskClassification::RiskAverse),
+            risk_confidence: 0.7,
+            decisions_analyzed: 5,
+            profile_confidence: ProfileConfidence::Medium,
+            top_values: vec![],
+            decision_style: Some(StyleClassification::Balanced),
+            active_blind_spots: vec!["Sunk cost".to_string()],
+            communication: Default::default(),
+        }
+    }
+
+    fn user(i: usize) -> UserId {
+        UserId::new(format!("user-{}", i)).unwrap()
+    }
+
+    /// An organization of `user-0` onwards, where `sharing` members consent
+    /// to team sharing and `private` members don't.
+    async fn setup_repositories(
+        sharing: usize,
+        private: usize,
+    ) -> (
+        Arc<InMemoryProfileSummaryRepository>,
+        Arc<InMemoryConsentRepository>,
+        Arc<InMemoryOrganizationRepository>,
+        String,
+    ) {
+        let summaries = Arc::new(InMemoryProfileSummaryRepository::new());
+        let consents = Arc::new(InMemoryConsentRepository::new());
+        let organizations = Arc::new(InMemoryOrganizationRepository::new());
+        let mut organization = Organization::new("Acme", user(0)).unwrap();
+        for i in 0..sharing + private {
+            if i > 0 {
+                organization
+                    .add_member(&user(0), user(i), OrganizationRole::Member)
+                    .unwrap();
+            }
+            summaries.save(&user(i), &summary()).await.unwrap();
+            let mut granted = vec![ConsentType::ProfileCollection];
+            if i < sharing {
+                granted.push(ConsentType::ProfileTeamSharing);
+            }
+            for consent_type in granted {
+                consents
+                    .append(&ConsentRecord::grant(user(i), consent_type, "1").unwrap())
+                    .await
+                    .unwrap();
+            }
+        }
+        organizations.save(&organization).await.unwrap();
+        (
+            summaries,
+            consents,
+            organizations,
+            organization.id.to_string(),
+        )
+    }
+
+    fn create_handler(
+        summaries: Arc<InMemoryProfileSummaryRepository>,
+        consents: Arc<InMemoryConsentRepository>,
+        organizations: Arc<InMemoryOrganizationRepository>,
+    ) -> GetTeamProfileHandler {
+        GetTeamProfileHandler::new(summaries, consents, organizations)
+    }
+
+    #[tokio::test]
+    async fn includes_only_consenting_members() {
+        let (summaries, consents, organizations, organization) = setup_repositories(5, 3).await;
+        let handler = create_handler(summaries, consents, organizations);
+
+        let profile = handler
+            .handle(GetTeamProfileQuery { organization })
+            .await
+            .unwrap();
+
+        assert_eq!(profile.contributing_members, 5);
+    }
+
+    #[tokio::test]
+    async fn ignores_summaries_of_non_members() {
+        let (summaries, consents, organizations, organization) = setup_repositories(4, 0).await;
+        let outsider = UserId::new("outsider").unwrap();
+        summaries.save(&outsider, &summary()).await.unwrap();
+        consents
+            .append(&ConsentRecord::grant(outsider, ConsentType::ProfileTeamSharing, "1").unwrap())
+            .await
+            .unwrap();
+        let handler = create_handler(summaries, consents, organizations);
+
+        let err = handler
+            .handle(GetTeamProfileQuery { organization })
+            .await
+            .unwrap_err();
+
+        assert!(matches!(
+            err,
+            GetTeamProfileError::TooFewMembers { available: 4, .. }
+        ));
+    }
+
+    #[tokio::test]
+    async fn refuses_when_too_few_members_consented() {
+        let (summaries, consents, organizations, organization) = setup_repositories(4, 10).await;
+        let handler = create_handler(summaries, consents, organizations);
+
+        let err = handler
+            .handle(GetTeamProfileQuery { organization })
+            .await
+            .unwrap_err();
+
+        assert!(matches!(
+            err,
+            GetTeamProfileError::TooFewMembers { available: 4, .. }
+        ));
+    }
+
+    #[tokio::test]
+    async fn agent_context_requires_the_organization_setting() {
+        let (summaries, consents, organizations, organization) = setup_repositories(5, 0).await;
+        let settings = Arc::new(InMemoryTeamProfileSettingsRepository::new());
+        let provider =
+            TeamProfileContextProvider::new(summaries, consents, settings.clone(), organizations);
+
+        assert_eq!(provider.context_for(&user(1)).await.unwrap(), None);
+
+        UpdateTeamProfileSettingsHandler::new(settings)
+            .handle(UpdateTeamProfileSettingsCommand {
+                organization,
+                agent_context_enabled: true,
+            })
+            .await
+            .unwrap();
+
+        let context = provider.context_for(&user(1)).await.unwrap().unwrap();
+        assert!(context.contains("Sunk cost"));
+    }
+}
@@ -0,0 +1,278 @@
+//! ActivityFeedProjection - Recent milestones per session.
+//!
+//! Turns session, cycle, and analysis events into short entries such as
+//! "Completed Objectives". Component events carry only a cycle ID, so the
+//! projection remembers which session each cycle was created in.
+
+use std::collections::{HashMap, VecDeque};
+use std::sync::RwLock;
+
+use async_trait::async_trait;
+
+use crate::application::handlers::cycle::{
+    ComponentBatchAppliedEvent, ComponentCompletedEvent, ComponentOperationKind,
+    CycleCompletedEvent, CycleCreatedEvent,
+};
+use crate::domain::analysis::DQScoresComputed;
+use crate::domain::foundation::{
+    CycleId, DomainError, ErrorCode, EventEnvelope, SessionId, Timestamp,
+};
+use crate::domain::session::{SessionCreated, SessionRenamed};
+use crate::ports::Projection;
+
+/// Entries kept per session; older ones are dropped.
+pub const MAX_FEED_ENTRIES: usize = 50;
+
+const HANDLED: &[&str] = &[
+    "session.created.v1",
+    "session.renamed.v1",
+    "cycle.created.v1",
+    "component.completed.v1",
+    "component.batch_applied.v1",
+    "cycle.completed.v1",
+    "analysis.dq_scores_computed.v1",
+];
+
+/// One line in a session's activity feed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ActivityEntry {
+    pub event_type: String,
+    pub cycle_id: Option<CycleId>,
+    pub summary: String,
+    pub occurred_at: Timestamp,
+}
+
+#[derive(Default)]
+struct Feeds {
+    sessions: HashMap<SessionId, VecDeque<ActivityEntry>>,
+    cycle_sessions: HashMap<CycleId, SessionId>,
+}
+
+impl Feeds {
+    fn push(&mut self, session_id: SessionId, entry: ActivityEntry) {
+        let feed = self.sessions.entry(session_id).or_default();
+        feed.push_front(entry);
+        feed.truncate(MAX_FEED_ENTRIES);
+    }
+
+    fn push_for_cycle(
+        &mut self,
+        cycle_id: CycleId,
+        event_type: &str,
+        summary: String,
+        at: Timestamp,
+    ) {
+        if let Some(&session_id) = self.cycle_sessions.get(&cycle_id) {
+            self.push(
+                session_id,
+                ActivityEntry {
+                    event_type: event_type.to_string(),
+                    cycle_id: Some(cycle_id),
+                    summary,
+                    occurred_at: at,
+                },
+            );
+        }
+    }
+}
+
+/// Read model of recent activity per session.
+#[derive(Default)]
+pub struct ActivityFeedProjection {
+    feeds: RwLock<Feeds>,
+}
+
+impl ActivityFeedProjection {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The session's entries, newest first.
+    pub fn feed(&self, session_id: &SessionId) -> Vec<ActivityEntry> {
+        self.feeds
+            .read()
+            .unwrap_or_else(|e| e.into_inner())
+            .sessions
+            .get(session_id)
+            .map(|feed| feed.iter().cloned().collect())
+            .unwrap_or_default()
+    }
+}
+
+#[async_trait]
+impl Projection for ActivityFeedProjection {
+    fn name(&self) -> &'static str {
+        "activity_feed"
+    }
+
+    fn handled_event_types(&self) -> &'static [&'static str] {
+        HANDLED
+    }
+
+    async fn apply(&self, event: &EventEnvelope) -> Result<(), DomainError> {
+        let invalid =
+            |e: serde_json::Error| DomainError::new(ErrorCode::ValidationFailed, e.to_string());
+        let event_type = event.event_type.as_str();
+        let mut feeds = self.feeds.write().unwrap_or_else(|e| e.into_inner());
+        match event_type {
+            "session.created.v1" => {
+                let created: SessionCreated = event.payload_as().map_err(invalid)?;
+                feeds.push(
+                    created.session_id,
+                    ActivityEntry {
+                        event_type: event_type.to_string(),
+                        cycle_id: None,
+                        summary: format!("Started \"{}\"", created.title),
+                        occurred_at: created.created_at,
+                    },
+                );
+            }
+            "session.renamed.v1" => {
+                let renamed: SessionRenamed = event.payload_as().map_err(invalid)?;
+                feeds.push(
+                    renamed.session_id,
+                    ActivityEntry {
+                        event_type: event_type.to_string(),
+                        cycle_id: None,
+                        summary: format!("Renamed to \"{}\"", renamed.new_title),
+                        occurred_at: renamed.renamed_at,
+                    },
+                );
+            }
+            "cycle.created.v1" => {
+                let created: CycleCreatedEvent = event.payload_as().map_err(invalid)?;
+                feeds
+                    .cycle_sessions
+                    .insert(created.cycle_id, created.session_id);
+                let summary = if created.parent_cycle_id.is_some() {
+                    "Branched a new cycle"
+                } else {
+                    "Started a cycle"
+                };
+                feeds.push_for_cycle(
+                    created.cycle_id,
+                    event_type,
+                    summary.to_string(),
+                    created.created_at,
+                );
+            }
+            "component.completed.v1" => {
+                let completed: ComponentCompletedEvent = event.payload_as().map_err(invalid)?;
+                let summary = format!("Completed {}", completed.component_type.display_name());
+                feeds.push_for_cycle(
+                    completed.cycle_id,
+                    event_type,
+                    summary,
+                    completed.completed_at,
+                );
+            }
+            "component.batch_applied.v1" => {
+                let batch: ComponentBatchAppliedEvent = event.payload_as().map_err(invalid)?;
+                for operation in &batch.operations {
+                    if operation.kind == ComponentOperationKind::Complete {
+                        let summary =
+                            format!("Completed {}", operation.component_type.display_name());
+                        feeds.push_for_cycle(batch.cycle_id, event_type, summary, batch.applied_at);
+                    }
+                }
+            }
+            "cycle.completed.v1" => {
+                let completed: CycleCompletedEvent = event.payload_as().map_err(invalid)?;
+                feeds.push_for_cycle(
+                    completed.cycle_id,
+                    event_type,
+                    "Completed the cycle".to_string(),
+                    completed.completed_at,
+                );
+            }
+            "analysis.dq_scores_computed.v1" => {
+                let dq: DQScoresComputed = event.payload_as().map_err(invalid)?;
+                feeds
+                    .cycle_sessions
+                    .entry(dq.cycle_id)
+                    .or_insert(dq.session_id);
+                let summary = format!("Decision quality scored {}%", dq.overall_score.value());
+                feeds.push_for_cycle(dq.cycle_id, event_type, summary, dq.computed_at);
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+
+    async fn reset(&self) -> Result<(), DomainError> {
+        *self.feeds.write().unwrap_or_else(|e| e.into_inner()) = Feeds::default();
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::handlers::cycle::AppliedComponentOperation;
+    use crate::domain::foundation::{ComponentType, EventId, SerializableDomainEvent};
+
+    #[tokio::test]
+    async fn follows_component_events_to_the_cycle_session() {
+        let projection = ActivityFeedProjection::new();
+        let session_id = SessionId::new();
+        let cycle_id = CycleId::new();
+        let events = [
+            CycleCreatedEvent {
+                event_id: EventId::new(),
+                cycle_id,
+                session_id,
+                parent_cycle_id: None,
+                created_at: Timestamp::now(),
+            }
+            .to_envelope(),
+            ComponentCompletedEvent {
+                event_id: EventId::new(),
+                cycle_id,
+                component_type: ComponentType::Objectives,
+                completed_at: Timestamp::now(),
+            }
+            .to_envelope(),
+            ComponentBatchAppliedEvent {
+                event_id: EventId::new(),
+                cycle_id,
+                operations: vec![
+                    AppliedComponentOperation {
+                        kind: ComponentOperationKind::UpdateOutput,
+                        component_type: ComponentType::Alternatives,
+                    },
+                    AppliedComponentOperation {
+                        kind: ComponentOperationKind::Complete,
+                        component_type: ComponentType::Alternatives,
+                    },
+                ],
+                applied_at: Timestamp::now(),
+            }
+            .to_envelope(),
+            // A cycle the projection never saw created has no session
+            ComponentCompletedEvent {
+                event_id: EventId::new(),
+                cycle_id: CycleId::new(),
+                component_type: ComponentType::Objectives,
+                completed_at: Timestamp::now(),
+            }
+            .to_envelope(),
+        ];
+        for event in &events {
+            projection.apply(event).await.unwrap();
+        }
+
+        let summaries: Vec<_> = projection
+            .feed(&session_id)
+            .into_iter()
+            .map(|e| e.summary)
+            .collect();
+        assert_eq!(
+            summaries,
+            vec![
+                "Completed Alternatives",
+                "Completed Objectives",
+                "Started a cycle"
+            ]
+        );
+    }
+}
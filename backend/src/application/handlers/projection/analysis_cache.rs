@@ -0,0 +1,128 @@
+//! AnalysisCacheProjection - Latest computed analysis per cycle.
+//!
+//! Keeps the most recent Pugh scores, tradeoff summary, and DQ scores the
+//! analysis trigger handler published for each cycle, so views can show
+//! them without recomputing.
+
+use std::collections::HashMap;
+use std::sync::RwLock;
+
+use async_trait::async_trait;
+
+use crate::domain::analysis::{DQScoresComputed, PughScoresComputed, TradeoffsAnalyzed};
+use crate::domain::foundation::{CycleId, DomainError, ErrorCode, EventEnvelope};
+use crate::ports::Projection;
+
+const HANDLED: &[&str] = &[
+    "analysis.pugh_scores_computed.v1",
+    "analysis.tradeoffs_analyzed.v1",
+    "analysis.dq_scores_computed.v1",
+];
+
+/// The latest analysis results for one cycle.
+#[derive(Debug, Clone, Default)]
+pub struct CachedAnalysis {
+    pub pugh: Option<PughScoresComputed>,
+    pub tradeoffs: Option<TradeoffsAnalyzed>,
+    pub dq: Option<DQScoresComputed>,
+}
+
+/// Read model of the latest analysis per cycle.
+#[derive(Default)]
+pub struct AnalysisCacheProjection {
+    cycles: RwLock<HashMap<CycleId, CachedAnalysis>>,
+}
+
+impl AnalysisCacheProjection {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The cached analysis for a cycle, if any was computed.
+    pub fn get(&self, cycle_id: &CycleId) -> Option<CachedAnalysis> {
+        self.cycles
+            .read()
+            .unwrap_or_else(|e| e.into_inner())
+            .get(cycle_id)
+            .cloned()
+    }
+}
+
+#[async_trait]
+impl Projection for AnalysisCacheProjection {
+    fn name(&self) -> &'static str {
+        "analysis_cache"
+    }
+
+    fn handled_event_types(&self) -> &'static [&'static str] {
+        HANDLED
+    }
+
+    async fn apply(&self, event: &EventEnvelope) -> Result<(), DomainError> {
+        let invalid =
+            |e: serde_json::Error| DomainError::new(ErrorCode::ValidationFailed, e.to_string());
+        let mut cycles = self.cycles.write().unwrap_or_else(|e| e.into_inner());
+        match event.event_type.as_str() {
+            "analysis.pugh_scores_computed.v1" => {
+                let pugh: PughScoresComputed = event.payload_as().map_err(invalid)?;
+                let cycle_id = pugh.cycle_id;
+                cycles.entry(cycle_id).or_default().pugh = Some(pugh);
+            }
+            "analysis.tradeoffs_analyzed.v1" => {
+                let tradeoffs: TradeoffsAnalyzed = event.payload_as().map_err(invalid)?;
+                let cycle_id = tradeoffs.cycle_id;
+                cycles.entry(cycle_id).or_default().tradeoffs = Some(tradeoffs);
+            }
+            "analysis.dq_scores_computed.v1" => {
+                let dq: DQScoresComputed = event.payload_as().map_err(invalid)?;
+                let cycle_id = dq.cycle_id;
+                cycles.entry(cycle_id).or_default().dq = Some(dq);
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+
+    async fn reset(&self) -> Result<(), DomainError> {
+        self.cycles
+            .write()
+            .unwrap_or_else(|e| e.into_inner())
+            .clear();
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::foundation::{EventId, SerializableDomainEvent, SessionId, Timestamp};
+
+    #[tokio::test]
+    async fn keeps_latest_scores_per_cycle() {
+        let projection = AnalysisCacheProjection::new();
+        let cycle_id = CycleId::new();
+        for best in ["alt-1", "alt-2"] {
+            let event = PughScoresComputed {
+                event_id: EventId::new(),
+                cycle_id,
+                session_id: SessionId::new(),
+                alternative_scores: HashMap::new(),
+                dominated_alternatives: vec![],
+                irrelevant_objectives: vec![],
+                best_alternative_id: Some(best.to_string()),
+                computed_at: Timestamp::now(),
+            };
+            projection.apply(&event.to_envelope()).await.unwrap();
+        }
+
+        let cached = projection.get(&cycle_id).unwrap();
+        assert_eq!(
+            cached.pugh.unwrap().best_alternative_id.as_deref(),
+            Some("alt-2")
+        );
+        assert!(cached.dq.is_none());
+
+        projection.reset().await.unwrap();
+        assert!(projection.get(&cycle_id).is_none());
+    }
+}
@@ -0,0 +1,266 @@
+//! DashboardProjection - Cycle progress for the dashboard overview.
+//!
+//! Tracks component statuses per cycle from lifecycle events, so progress
+//! bars and "current step" can be shown without loading each cycle.
+
+use std::collections::HashMap;
+use std::sync::RwLock;
+
+use async_trait::async_trait;
+
+use crate::application::handlers::cycle::{
+    ComponentBatchAppliedEvent, ComponentCompletedEvent, ComponentOperationKind,
+    ComponentStartedEvent, CycleCompletedEvent, CycleCreatedEvent,
+};
+use crate::domain::foundation::{
+    ComponentStatus, ComponentType, CycleId, DomainError, ErrorCode, EventEnvelope, SessionId,
+    Timestamp,
+};
+use crate::ports::Projection;
+
+const HANDLED: &[&str] = &[
+    "cycle.created.v1",
+    "component.started.v1",
+    "component.completed.v1",
+    "component.batch_applied.v1",
+    "cycle.completed.v1",
+];
+
+/// Progress summary for one cycle.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CycleProgressEntry {
+    pub cycle_id: CycleId,
+    pub session_id: SessionId,
+    pub parent_cycle_id: Option<CycleId>,
+    pub statuses: HashMap<ComponentType, ComponentStatus>,
+    /// The component most recently started.
+    pub current_step: Option<ComponentType>,
+    pub completed: bool,
+    pub updated_at: Timestamp,
+}
+
+impl CycleProgressEntry {
+    /// Share of components completed, 0-100.
+    pub fn progress_percent(&self) -> u8 {
+        let total = ComponentType::all().len();
+        let done = self.statuses.values().filter(|s| s.is_complete()).count();
+        ((done * 100) / total) as u8
+    }
+}
+
+/// Read model of cycle progress.
+#[derive(Default)]
+pub struct DashboardProjection {
+    cycles: RwLock<HashMap<CycleId, CycleProgressEntry>>,
+}
+
+impl DashboardProjection {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Progress of one cycle.
+    pub fn cycle(&self, cycle_id: &CycleId) -> Option<CycleProgressEntry> {
+        self.cycles
+            .read()
+            .unwrap_or_else(|e| e.into_inner())
+            .get(cycle_id)
+            .cloned()
+    }
+
+    /// Progress of every cycle in a session, oldest update first.
+    pub fn session_cycles(&self, session_id: &SessionId) -> Vec<CycleProgressEntry> {
+        let mut entries: Vec<_> = self
+            .cycles
+            .read()
+            .unwrap_or_else(|e| e.into_inner())
+            .values()
+            .filter(|e| e.session_id == *session_id)
+            .cloned()
+            .collect();
+        entries.sort_by_key(|e| e.updated_at);
+        entries
+    }
+}
+
+#[async_trait]
+impl Projection for DashboardProjection {
+    fn name(&self) -> &'static str {
+        "dashboard"
+    }
+
+    fn handled_event_types(&self) -> &'static [&'static str] {
+        HANDLED
+    }
+
+    async fn apply(&self, event: &EventEnvelope) -> Result<(), DomainError> {
+        let invalid =
+            |e: serde_json::Error| DomainError::new(ErrorCode::ValidationFailed, e.to_string());
+        let mut cycles = self.cycles.write().unwrap_or_else(|e| e.into_inner());
+        match event.event_type.as_str() {
+            "cycle.created.v1" => {
+                let created: CycleCreatedEvent = event.payload_as().map_err(invalid)?;
+                cycles.insert(
+                    created.cycle_id,
+                    CycleProgressEntry {
+                        cycle_id: created.cycle_id,
+                        session_id: created.session_id,
+                        parent_cycle_id: created.parent_cycle_id,
+                        statuses: ComponentType::all()
+                            .iter()
+                            .map(|&c| (c, ComponentStatus::NotStarted))
+                            .collect(),
+                        current_step: None,
+                        completed: false,
+                        updated_at: created.created_at,
+                    },
+                );
+            }
+            "component.started.v1" => {
+                let started: ComponentStartedEvent = event.payload_as().map_err(invalid)?;
+                if let Some(entry) = cycles.get_mut(&started.cycle_id) {
+                    entry
+                        .statuses
+                        .insert(started.component_type, ComponentStatus::InProgress);
+                    entry.current_step = Some(started.component_type);
+                    entry.updated_at = started.started_at;
+                }
+            }
+            "component.completed.v1" => {
+                let completed: ComponentCompletedEvent = event.payload_as().map_err(invalid)?;
+                if let Some(entry) = cycles.get_mut(&completed.cycle_id) {
+                    entry
+                        .statuses
+                        .insert(completed.component_type, ComponentStatus::Complete);
+                    entry.updated_at = completed.completed_at;
+                }
+            }
+            "component.batch_applied.v1" => {
+                let batch: ComponentBatchAppliedEvent = event.payload_as().map_err(invalid)?;
+                if let Some(entry) = cycles.get_mut(&batch.cycle_id) {
+                    for operation in &batch.operations {
+                        if operation.kind == ComponentOperationKind::Complete {
+                            entry
+                                .statuses
+                                .insert(operation.component_type, ComponentStatus::Complete);
+                        }
+                    }
+                    entry.updated_at = batch.applied_at;
+                }
+            }
+            "cycle.completed.v1" => {
+                let completed: CycleCompletedEvent = event.payload_as().map_err(invalid)?;
+                if let Some(entry) = cycles.get_mut(&completed.cycle_id) {
+                    entry.completed = true;
+                    entry.updated_at = completed.completed_at;
+                }
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+
+    async fn reset(&self) -> Result<(), DomainError> {
+        self.cycles
+            .write()
+            .unwrap_or_else(|e| e.into_inner())
+            .clear();
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::handlers::cycle::AppliedComponentOperation;
+    use crate::domain::foundation::{EventId, SerializableDomainEvent};
+
+    #[tokio::test]
+    async fn tracks_component_progress() {
+        let projection = DashboardProjection::new();
+        let session_id = SessionId::new();
+        let cycle_id = CycleId::new();
+        let events = [
+            CycleCreatedEvent {
+                event_id: EventId::new(),
+                cycle_id,
+                session_id,
+                parent_cycle_id: None,
+                created_at: Timestamp::now(),
+            }
+            .to_envelope(),
+            ComponentStartedEvent {
+                event_id: EventId::new(),
+                cycle_id,
+                component_type: ComponentType::IssueRaising,
+                started_at: Timestamp::now(),
+            }
+            .to_envelope(),
+            ComponentCompletedEvent {
+                event_id: EventId::new(),
+                cycle_id,
+                component_type: ComponentType::IssueRaising,
+                completed_at: Timestamp::now(),
+            }
+            .to_envelope(),
+            ComponentStartedEvent {
+                event_id: EventId::new(),
+                cycle_id,
+                component_type: ComponentType::ProblemFrame,
+                started_at: Timestamp::now(),
+            }
+            .to_envelope(),
+        ];
+        for event in &events {
+            projection.apply(event).await.unwrap();
+        }
+
+        let entry = projection.cycle(&cycle_id).unwrap();
+        assert_eq!(entry.current_step, Some(ComponentType::ProblemFrame));
+        assert_eq!(entry.progress_percent(), 9);
+        assert!(!entry.completed);
+        assert_eq!(projection.session_cycles(&session_id).len(), 1);
+    }
+
+    #[tokio::test]
+    async fn tracks_completions_applied_in_a_batch() {
+        let projection = DashboardProjection::new();
+        let cycle_id = CycleId::new();
+        let events = [
+            CycleCreatedEvent {
+                event_id: EventId::new(),
+                cycle_id,
+                session_id: SessionId::new(),
+                parent_cycle_id: None,
+                created_at: Timestamp::now(),
+            }
+            .to_envelope(),
+            ComponentBatchAppliedEvent {
+                event_id: EventId::new(),
+                cycle_id,
+                operations: vec![
+                    AppliedComponentOperation {
+                        kind: ComponentOperationKind::UpdateOutput,
+                        component_type: ComponentType::IssueRaising,
+                    },
+                    AppliedComponentOperation {
+                        kind: ComponentOperationKind::Complete,
+                        component_type: ComponentType::IssueRaising,
+                    },
+                ],
+                applied_at: Timestamp::now(),
+            }
+            .to_envelope(),
+        ];
+        for event in &events {
+            projection.apply(event).await.unwrap();
+        }
+
+        let entry = projection.cycle(&cycle_id).unwrap();
+        assert_eq!(
+            entry.statuses[&ComponentType::IssueRaising],
+            ComponentStatus::Complete
+        );
+        assert_eq!(entry.progress_percent(), 9);
+    }
+}
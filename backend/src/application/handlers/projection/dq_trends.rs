@@ -0,0 +1,126 @@
+//! DqTrendProjection - Decision quality over a session's cycles.
+//!
+//! Records each cycle's latest overall DQ score, so a session can show
+//! whether branching and revising is improving the decision.
+
+use std::collections::HashMap;
+use std::sync::RwLock;
+
+use async_trait::async_trait;
+
+use crate::domain::analysis::DQScoresComputed;
+use crate::domain::foundation::{
+    CycleId, DomainError, ErrorCode, EventEnvelope, Percentage, SessionId, Timestamp,
+};
+use crate::ports::Projection;
+
+const HANDLED: &[&str] = &["analysis.dq_scores_computed.v1"];
+
+/// One cycle's latest DQ score.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DqTrendPoint {
+    pub cycle_id: CycleId,
+    pub overall_score: Percentage,
+    pub weakest_element: String,
+    pub computed_at: Timestamp,
+}
+
+/// Read model of DQ scores per session.
+#[derive(Default)]
+pub struct DqTrendProjection {
+    sessions: RwLock<HashMap<SessionId, Vec<DqTrendPoint>>>,
+}
+
+impl DqTrendProjection {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The session's points, oldest first.
+    pub fn trend(&self, session_id: &SessionId) -> Vec<DqTrendPoint> {
+        self.sessions
+            .read()
+            .unwrap_or_else(|e| e.into_inner())
+            .get(session_id)
+            .cloned()
+            .unwrap_or_default()
+    }
+}
+
+#[async_trait]
+impl Projection for DqTrendProjection {
+    fn name(&self) -> &'static str {
+        "dq_trends"
+    }
+
+    fn handled_event_types(&self) -> &'static [&'static str] {
+        HANDLED
+    }
+
+    async fn apply(&self, event: &EventEnvelope) -> Result<(), DomainError> {
+        let dq: DQScoresComputed = event
+            .payload_as()
+            .map_err(|e| DomainError::new(ErrorCode::ValidationFailed, e.to_string()))?;
+        let mut sessions = self.sessions.write().unwrap_or_else(|e| e.into_inner());
+        let points = sessions.entry(dq.session_id).or_default();
+        points.retain(|p| p.cycle_id != dq.cycle_id);
+        points.push(DqTrendPoint {
+            cycle_id: dq.cycle_id,
+            overall_score: dq.overall_score,
+            weakest_element: dq.weakest_element,
+            computed_at: dq.computed_at,
+        });
+        points.sort_by_key(|p| p.computed_at);
+        Ok(())
+    }
+
+    async fn reset(&self) -> Result<(), DomainError> {
+        self.sessions
+            .write()
+            .unwrap_or_else(|e| e.into_inner())
+            .clear();
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::foundation::{EventId, SerializableDomainEvent};
+
+    fn scored(session_id: SessionId, cycle_id: CycleId, score: u8) -> EventEnvelope {
+        DQScoresComputed {
+            event_id: EventId::new(),
+            cycle_id,
+            session_id,
+            element_scores: vec![],
+            overall_score: Percentage::new(score),
+            weakest_element: "Clear Tradeoffs".to_string(),
+            improvement_suggestions: vec![],
+            computed_at: Timestamp::now(),
+        }
+        .to_envelope()
+    }
+
+    #[tokio::test]
+    async fn one_point_per_cycle_in_time_order() {
+        let projection = DqTrendProjection::new();
+        let session_id = SessionId::new();
+        let (first, second) = (CycleId::new(), CycleId::new());
+
+        for event in [
+            scored(session_id, first, 40),
+            scored(session_id, second, 60),
+            scored(session_id, first, 70),
+        ] {
+            projection.apply(&event).await.unwrap();
+        }
+
+        let scores: Vec<_> = projection
+            .trend(&session_id)
+            .iter()
+            .map(|p| (p.cycle_id, p.overall_score.value()))
+            .collect();
+        assert_eq!(scores, vec![(second, 60), (first, 70)]);
+    }
+}
@@ -0,0 +1,16 @@
+//! Projection handlers.
+//!
+//! Read models rebuilt from the event store, and the runner that keeps
+//! them current and reports how far behind each one is.
+
+mod activity_feed;
+mod analysis_cache;
+mod dashboard;
+mod dq_trends;
+mod runner;
+
+pub use activity_feed::{ActivityEntry, ActivityFeedProjection, MAX_FEED_ENTRIES};
+pub use analysis_cache::{AnalysisCacheProjection, CachedAnalysis};
+pub use dashboard::{CycleProgressEntry, DashboardProjection};
+pub use dq_trends::{DqTrendPoint, DqTrendProjection};
+pub use runner::{ProjectionError, ProjectionRunner, ProjectionStatus};
@@ -0,0 +1,398 @@
+//! ProjectionRunner - Keeps projections up to date with the event store.
+//!
+//! Projections never consume events straight off the bus. When a handled
+//! event is published, the runner reads the event store from the
+//! projection's checkpoint onward, so live updates, catching up after a
+//! restart, and rebuilding all take the same path and a projection sees each
+//! event exactly once, in order. Events pass through `EventReplayer` on the
+//! way, so stored events in an old schema are upcast before being applied.
+//!
+//! Applying stops at the first event a projection fails on; the checkpoint
+//! stays just before it, and the next run retries it.
+
+use std::collections::{BTreeSet, HashMap};
+use std::convert::Infallible;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use tokio::sync::Mutex;
+
+use crate::domain::foundation::{
+    DomainError, ErrorCode, EventEnvelope, EventReplayer, ReplayStats, Timestamp, UpcasterRegistry,
+};
+use crate::ports::{
+    EventHandler, EventStore, EventSubscriber, Projection, ProjectionCheckpoint,
+    ProjectionCheckpointStore,
+};
+
+/// Events read from the store per batch.
+const BATCH_SIZE: u32 = 500;
+
+/// Error type for running projections.
+#[derive(Debug, Clone)]
+pub enum ProjectionError {
+    /// No projection is registered under this name.
+    UnknownProjection(String),
+    /// Store, checkpoint, or projection failure.
+    Domain(DomainError),
+}
+
+impl std::fmt::Display for ProjectionError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ProjectionError::UnknownProjection(name) => write!(f, "Unknown projection: {}", name),
+            ProjectionError::Domain(err) => write!(f, "{}", err),
+        }
+    }
+}
+
+impl std::error::Error for ProjectionError {}
+
+impl From<DomainError> for ProjectionError {
+    fn from(err: DomainError) -> Self {
+        ProjectionError::Domain(err)
+    }
+}
+
+/// How far a projection is behind the event store.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ProjectionStatus {
+    pub name: &'static str,
+    pub handled_event_types: &'static [&'static str],
+    /// Last event store position the projection has read.
+    pub position: u64,
+    /// Newest position in the event store.
+    pub head_position: u64,
+    /// Handled events not yet applied.
+    pub lag: u64,
+    /// When the checkpoint last moved, `None` if the projection never ran.
+    pub updated_at: Option<Timestamp>,
+}
+
+struct Registered {
+    projection: Arc<dyn Projection>,
+    /// Held while the projection is being advanced, so two runs never apply
+    /// the same event.
+    running: Mutex<()>,
+}
+
+/// Runs registered projections against the event store.
+pub struct ProjectionRunner {
+    store: Arc<dyn EventStore>,
+    checkpoints: Arc<dyn ProjectionCheckpointStore>,
+    replayer: EventReplayer,
+    projections: Vec<Registered>,
+}
+
+impl ProjectionRunner {
+    /// `registry` upcasts stored events; a later projection with the same
+    /// name as an earlier one replaces it.
+    pub fn new(
+        store: Arc<dyn EventStore>,
+        checkpoints: Arc<dyn ProjectionCheckpointStore>,
+        registry: UpcasterRegistry,
+        projections: Vec<Arc<dyn Projection>>,
+    ) -> Self {
+        let mut registered: Vec<Registered> = Vec::new();
+        for projection in projections {
+            registered.retain(|r| r.projection.name() != projection.name());
+            registered.push(Registered {
+                projection,
+                running: Mutex::new(()),
+            });
+        }
+        Self {
+            store,
+            checkpoints,
+            replayer: EventReplayer::new(registry),
+            projections: registered,
+        }
+    }
+
+    /// Register the runner for every event type a projection handles.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let runner = Arc::new(ProjectionRunner::new(/* ... */));
+    /// runner.register(&event_bus);
+    /// ```
+    pub fn register(self: &Arc<Self>, subscriber: &impl EventSubscriber) {
+        let event_types: BTreeSet<&str> = self
+            .projections
+            .iter()
+            .flat_map(|r| r.projection.handled_event_types().iter().copied())
+            .collect();
+        let event_types: Vec<&str> = event_types.into_iter().collect();
+        subscriber.subscribe_all(&event_types, self.clone());
+    }
+
+    /// Names of the registered projections, in registration order.
+    pub fn names(&self) -> Vec<&'static str> {
+        self.projections
+            .iter()
+            .map(|r| r.projection.name())
+            .collect()
+    }
+
+    /// Applies every event since the projection's checkpoint.
+    #[tracing::instrument(name = "ProjectionRunner::catch_up", skip(self))]
+    pub async fn catch_up(&self, name: &str) -> Result<ReplayStats, ProjectionError> {
+        let registered = self.find(name)?;
+        let _running = registered.running.lock().await;
+        self.advance(registered.projection.as_ref()).await
+    }
+
+    /// Catches up every projection, stopping at the first failure.
+    pub async fn catch_up_all(&self) -> Result<(), ProjectionError> {
+        for name in self.names() {
+            self.catch_up(name).await?;
+        }
+        Ok(())
+    }
+
+    /// Clears the projection and replays the whole event store into it.
+    #[tracing::instrument(name = "ProjectionRunner::rebuild", skip(self))]
+    pub async fn rebuild(&self, name: &str) -> Result<ReplayStats, ProjectionError> {
+        let registered = self.find(name)?;
+        let _running = registered.running.lock().await;
+        let projection = registered.projection.as_ref();
+
+        projection.reset().await?;
+        self.checkpoints
+            .save(&ProjectionCheckpoint::new(projection.name(), 0))
+            .await?;
+        let stats = self.advance(projection).await?;
+
+        tracing::info!(
+            projection = projection.name(),
+            applied = stats.processed,
+            skipped = stats.skipped,
+            failed = stats.failed,
+            "Projection rebuilt"
+        );
+        Ok(stats)
+    }
+
+    /// Checkpoint and lag of every projection.
+    pub async fn status(&self) -> Result<Vec<ProjectionStatus>, ProjectionError> {
+        let head_position = self.store.head_position().await?;
+        let mut statuses = Vec::with_capacity(self.projections.len());
+        for registered in &self.projections {
+            let projection = &registered.projection;
+            let checkpoint = self.checkpoints.load(projection.name()).await?;
+            let position = checkpoint.as_ref().map_or(0, |c| c.position);
+            let lag = self
+                .store
+                .count_after(position, projection.handled_event_types())
+                .await?;
+            statuses.push(ProjectionStatus {
+                name: projection.name(),
+                handled_event_types: projection.handled_event_types(),
+                position,
+                head_position,
+                lag,
+                updated_at: checkpoint.map(|c| c.updated_at),
+            });
+        }
+        Ok(statuses)
+    }
+
+    fn find(&self, name: &str) -> Result<&Registered, ProjectionError> {
+        self.projections
+            .iter()
+            .find(|r| r.projection.name() == name)
+            .ok_or_else(|| ProjectionError::UnknownProjection(name.to_string()))
+    }
+
+    async fn advance(&self, projection: &dyn Projection) -> Result<ReplayStats, ProjectionError> {
+        let handled = projection.handled_event_types();
+        let mut position = self
+            .checkpoints
+            .load(projection.name())
+            .await?
+            .map_or(0, |c| c.position);
+        let mut totals = ReplayStats::new();
+
+        loop {
+            let batch = self.store.read_after(position, BATCH_SIZE).await?;
+            let Some(last) = batch.last().map(|e| e.position) else {
+                break;
+            };
+            let positions: HashMap<String, u64> = batch
+                .iter()
+                .map(|e| (e.event.event_id.as_str().to_string(), e.position))
+                .collect();
+            let events = batch.into_iter().map(|e| e.event).collect();
+            let Ok((relevant, stats)) =
+                self.replayer
+                    .replay_and_collect(events, |event: EventEnvelope| {
+                        Ok::<_, Infallible>(
+                            handled
+                                .contains(&event.event_type.as_str())
+                                .then_some(event),
+                        )
+                    });
+            for error in &stats.errors {
+                tracing::warn!(
+                    projection = projection.name(),
+                    error,
+                    "Skipped event that could not be upcast"
+                );
+            }
+            totals.total += stats.total;
+            totals.skipped += stats.skipped;
+            totals.failed += stats.failed;
+            totals.errors.extend(stats.errors);
+
+            for event in relevant {
+                if let Err(e) = projection.apply(&event).await {
+                    // Resume from just before the failed event next time
+                    let failed_at = positions[event.event_id.as_str()];
+                    self.checkpoints
+                        .save(&ProjectionCheckpoint::new(projection.name(), failed_at - 1))
+                        .await?;
+                    return Err(e.into());
+                }
+                totals.processed += 1;
+            }
+
+            position = last;
+            self.checkpoints
+                .save(&ProjectionCheckpoint::new(projection.name(), position))
+                .await?;
+        }
+
+        Ok(totals)
+    }
+}
+
+#[async_trait]
+impl EventHandler for ProjectionRunner {
+    async fn handle(&self, event: EventEnvelope) -> Result<(), DomainError> {
+        let names: Vec<&'static str> = self
+            .projections
+            .iter()
+            .filter(|r| {
+                r.projection
+                    .handled_event_types()
+                    .contains(&event.event_type.as_str())
+            })
+            .map(|r| r.projection.name())
+            .collect();
+        for name in names {
+            self.catch_up(name).await.map_err(|e| match e {
+                ProjectionError::Domain(err) => err,
+                other => DomainError::new(ErrorCode::InternalError, other.to_string()),
+            })?;
+        }
+        Ok(())
+    }
+
+    fn name(&self) -> &'static str {
+        "ProjectionRunner"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::adapters::events::{
+        InMemoryEventBus, InMemoryEventStore, InMemoryProjectionCheckpointStore,
+        StoringEventPublisher,
+    };
+    use crate::application::handlers::projection::DqTrendProjection;
+    use crate::domain::analysis::DQScoresComputed;
+    use crate::domain::foundation::{
+        CycleId, EventId, Percentage, SerializableDomainEvent, SessionId,
+    };
+    use crate::ports::EventPublisher;
+
+    fn scored(session_id: SessionId) -> EventEnvelope {
+        DQScoresComputed {
+            event_id: EventId::new(),
+            cycle_id: CycleId::new(),
+            session_id,
+            element_scores: vec![],
+            overall_score: Percentage::new(50),
+            weakest_element: "Clear Tradeoffs".to_string(),
+            improvement_suggestions: vec![],
+            computed_at: Timestamp::now(),
+        }
+        .to_envelope()
+    }
+
+    fn runner(store: Arc<InMemoryEventStore>, trends: Arc<DqTrendProjection>) -> ProjectionRunner {
+        ProjectionRunner::new(
+            store,
+            Arc::new(InMemoryProjectionCheckpointStore::new()),
+            UpcasterRegistry::new(),
+            vec![trends],
+        )
+    }
+
+    #[tokio::test]
+    async fn status_reports_lag_until_caught_up() {
+        let store = Arc::new(InMemoryEventStore::new());
+        let trends = Arc::new(DqTrendProjection::new());
+        let runner = runner(store.clone(), trends.clone());
+        let session_id = SessionId::new();
+        store.append(&scored(session_id)).await.unwrap();
+        store.append(&EventEnvelope::test_fixture()).await.unwrap();
+        store.append(&scored(session_id)).await.unwrap();
+
+        let status = runner.status().await.unwrap();
+        assert_eq!((status[0].position, status[0].lag), (0, 2));
+        assert_eq!(status[0].updated_at, None);
+
+        let stats = runner.catch_up("dq_trends").await.unwrap();
+        assert_eq!(stats.processed, 2);
+        assert_eq!(trends.trend(&session_id).len(), 2);
+
+        let status = runner.status().await.unwrap();
+        assert_eq!(
+            (status[0].position, status[0].head_position, status[0].lag),
+            (3, 3, 0)
+        );
+    }
+
+    #[tokio::test]
+    async fn rebuild_replays_from_the_start() {
+        let store = Arc::new(InMemoryEventStore::new());
+        let trends = Arc::new(DqTrendProjection::new());
+        let runner = runner(store.clone(), trends.clone());
+        let session_id = SessionId::new();
+        store.append(&scored(session_id)).await.unwrap();
+        runner.catch_up("dq_trends").await.unwrap();
+        trends.reset().await.unwrap();
+
+        // Catching up again applies nothing new
+        runner.catch_up("dq_trends").await.unwrap();
+        assert!(trends.trend(&session_id).is_empty());
+
+        let stats = runner.rebuild("dq_trends").await.unwrap();
+        assert_eq!(stats.processed, 1);
+        assert_eq!(trends.trend(&session_id).len(), 1);
+
+        assert!(matches!(
+            runner.rebuild("missing").await,
+            Err(ProjectionError::UnknownProjection(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn published_events_reach_projections() {
+        let bus = Arc::new(InMemoryEventBus::new());
+        let store = Arc::new(InMemoryEventStore::new());
+        let trends = Arc::new(DqTrendProjection::new());
+        let runner = Arc::new(runner(store.clone(), trends.clone()));
+        runner.register(bus.as_ref());
+        let publisher = StoringEventPublisher::new(bus, store);
+
+        let session_id = SessionId::new();
+        publisher.publish(scored(session_id)).await.unwrap();
+
+        assert_eq!(trends.trend(&session_id).len(), 1);
+        assert_eq!(runner.status().await.unwrap()[0].lag, 0);
+    }
+}
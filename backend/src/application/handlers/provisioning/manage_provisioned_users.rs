@@ -0,0 +1,756 @@
+//! Provisioning handlers - Create, update, and deprovision users on behalf
+//! of an identity provider, and look them up for its sync runs.
+//!
+//! Every change publishes an event naming the provisioning client, so
+//! automated account management leaves the same audit trail as changes
+//! made by people. Requests that change nothing publish nothing.
+//!
+//! Each client only sees and changes the users it provisioned; another
+//! client's users are reported as not found.
+
+use std::sync::Arc;
+
+use crate::domain::foundation::{DomainError, ErrorCode, EventId, SerializableDomainEvent, UserId};
+use crate::domain::provisioning::{
+    ProvisionedUser, ProvisionedUserChanges, ProvisionedUserUpdated, ProvisioningError,
+    ProvisioningOutcome, UserDeprovisioned, UserProvisioned, UserReprovisioned,
+};
+use crate::ports::{EventPublisher, ProvisionedUserList, ProvisionedUserRepository};
+
+/// Most users returned in one page.
+pub const MAX_PROVISIONED_USERS_PAGE: u32 = 200;
+
+/// Errors from managing provisioned users.
+#[derive(Debug, Clone)]
+pub enum ManageProvisionedUserError {
+    /// No provisioned user has that ID.
+    NotFound(UserId),
+    /// A user with that ID was already provisioned.
+    AlreadyProvisioned(UserId),
+    /// Another user already has that `userName`.
+    UserNameTaken(String),
+    /// An attribute value is invalid.
+    Invalid(ProvisioningError),
+    /// Domain error.
+    Domain(DomainError),
+}
+
+impl std::fmt::Display for ManageProvisionedUserError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ManageProvisionedUserError::NotFound(id) => write!(f, "User {} not found", id),
+            ManageProvisionedUserError::AlreadyProvisioned(id) => {
+                write!(f, "User {} already exists", id)
+            }
+            ManageProvisionedUserError::UserNameTaken(name) => {
+                write!(f, "userName '{}' is already in use", name)
+            }
+            ManageProvisionedUserError::Invalid(err) => write!(f, "{}", err),
+            ManageProvisionedUserError::Domain(err) => write!(f, "{}", err),
+        }
+    }
+}
+
+impl std::error::Error for ManageProvisionedUserError {}
+
+impl From<DomainError> for ManageProvisionedUserError {
+    fn from(err: DomainError) -> Self {
+        ManageProvisionedUserError::Domain(err)
+    }
+}
+
+impl From<ProvisioningError> for ManageProvisionedUserError {
+    fn from(err: ProvisioningError) -> Self {
+        ManageProvisionedUserError::Invalid(err)
+    }
+}
+
+/// Fails if a different user already holds `user_name`.
+async fn ensure_user_name_free(
+    users: &dyn ProvisionedUserRepository,
+    user_name: &str,
+    user_id: &UserId,
+) -> Result<(), ManageProvisionedUserError> {
+    match users.find_by_user_name(user_name).await? {
+        Some(existing) if &existing.user_id != user_id => Err(
+            ManageProvisionedUserError::UserNameTaken(user_name.trim().to_string()),
+        ),
+        _ => Ok(()),
+    }
+}
+
+/// The user `client_id` provisioned, or `NotFound` if there is none.
+async fn find_provisioned(
+    users: &dyn ProvisionedUserRepository,
+    client_id: &str,
+    user_id: &UserId,
+) -> Result<ProvisionedUser, ManageProvisionedUserError> {
+    users
+        .find_by_id(user_id)
+        .await?
+        .filter(|user| user.provisioned_by == client_id)
+        .ok_or_else(|| ManageProvisionedUserError::NotFound(user_id.clone()))
+}
+
+async fn publish(
+    publisher: &dyn EventPublisher,
+    event: &impl SerializableDomainEvent,
+    user_id: &UserId,
+) -> Result<(), DomainError> {
+    publisher
+        .publish(event.to_envelope().with_user_id(user_id.to_string()))
+        .await
+}
+
+/// Publishes the events describing `outcome`.
+async fn publish_outcome(
+    publisher: &dyn EventPublisher,
+    user: &ProvisionedUser,
+    outcome: ProvisioningOutcome,
+    client_id: &str,
+) -> Result<(), DomainError> {
+    if !outcome.changed_attributes.is_empty() {
+        let event = ProvisionedUserUpdated {
+            event_id: EventId::new(),
+            user_id: user.user_id.clone(),
+            changed_attributes: outcome
+                .changed_attributes
+                .iter()
+                .map(|attribute| attribute.to_string())
+                .collect(),
+            updated_by: client_id.to_string(),
+            updated_at: user.updated_at,
+        };
+        publish(publisher, &event, &user.user_id).await?;
+    }
+    match outcome.activation {
+        Some(false) => {
+            let event = UserDeprovisioned {
+                event_id: EventId::new(),
+                user_id: user.user_id.clone(),
+                deprovisioned_by: client_id.to_string(),
+                deprovisioned_at: user.updated_at,
+            };
+            publish(publisher, &event, &user.user_id).await
+        }
+        Some(true) => {
+            let event = UserReprovisioned {
+                event_id: EventId::new(),
+                user_id: user.user_id.clone(),
+                reprovisioned_by: client_id.to_string(),
+                reprovisioned_at: user.updated_at,
+            };
+            publish(publisher, &event, &user.user_id).await
+        }
+        None => Ok(()),
+    }
+}
+
+// ════════════════════════════════════════════════════════════════════════════════
+// Provision User
+// ════════════════════════════════════════════════════════════════════════════════
+
+/// Command to create a user for an identity provider.
+#[derive(Debug, Clone)]
+pub struct ProvisionUserCommand {
+    /// OAuth client ID of the provisioning service.
+    pub client_id: String,
+    pub user_id: UserId,
+    pub user_name: String,
+    pub email: Option<String>,
+    pub display_name: Option<String>,
+    /// Providers may create users that start out deactivated.
+    pub active: bool,
+}
+
+/// Handler that provisions new users.
+pub struct ProvisionUserHandler {
+    users: Arc<dyn ProvisionedUserRepository>,
+    event_publisher: Arc<dyn EventPublisher>,
+}
+
+impl ProvisionUserHandler {
+    pub fn new(
+        users: Arc<dyn ProvisionedUserRepository>,
+        event_publisher: Arc<dyn EventPublisher>,
+    ) -> Self {
+        Self {
+            users,
+            event_publisher,
+        }
+    }
+
+    #[tracing::instrument(name = "ProvisionUserHandler::handle", skip_all)]
+    pub async fn handle(
+        &self,
+        cmd: ProvisionUserCommand,
+    ) -> Result<ProvisionedUser, ManageProvisionedUserError> {
+        if self.users.find_by_id(&cmd.user_id).await?.is_some() {
+            return Err(ManageProvisionedUserError::AlreadyProvisioned(cmd.user_id));
+        }
+        ensure_user_name_free(self.users.as_ref(), &cmd.user_name, &cmd.user_id).await?;
+
+        let mut user = ProvisionedUser::new(
+            cmd.user_id,
+            cmd.user_name,
+            cmd.email,
+            cmd.display_name,
+            cmd.client_id.clone(),
+        )?;
+        let deactivated = !cmd.active && user.deactivate();
+        match self.users.create(&user).await {
+            Ok(()) => {}
+            // Another request provisioned the same user since the checks above
+            Err(e) if e.code == ErrorCode::ConcurrentModification => {
+                return Err(if self.users.find_by_id(&user.user_id).await?.is_some() {
+                    ManageProvisionedUserError::AlreadyProvisioned(user.user_id)
+                } else {
+                    ManageProvisionedUserError::UserNameTaken(user.user_name)
+                });
+            }
+            Err(e) => return Err(e.into()),
+        }
+
+        let event = UserProvisioned {
+            event_id: EventId::new(),
+            user_id: user.user_id.clone(),
+            user_name: user.user_name.clone(),
+            provisioned_by: cmd.client_id.clone(),
+            provisioned_at: user.created_at,
+        };
+        publish(self.event_publisher.as_ref(), &event, &user.user_id).await?;
+        if deactivated {
+            let outcome = ProvisioningOutcome {
+                activation: Some(false),
+                ..Default::default()
+            };
+            publish_outcome(
+                self.event_publisher.as_ref(),
+                &user,
+                outcome,
+                &cmd.client_id,
+            )
+            .await?;
+        }
+
+        Ok(user)
+    }
+}
+
+// ════════════════════════════════════════════════════════════════════════════════
+// Update Provisioned User
+// ════════════════════════════════════════════════════════════════════════════════
+
+/// Command to change a provisioned user's attributes, including `active`.
+#[derive(Debug, Clone)]
+pub struct UpdateProvisionedUserCommand {
+    pub client_id: String,
+    pub user_id: UserId,
+    pub changes: ProvisionedUserChanges,
+}
+
+/// Handler that applies attribute changes from the identity provider.
+pub struct UpdateProvisionedUserHandler {
+    users: Arc<dyn ProvisionedUserRepository>,
+    event_publisher: Arc<dyn EventPublisher>,
+}
+
+impl UpdateProvisionedUserHandler {
+    pub fn new(
+        users: Arc<dyn ProvisionedUserRepository>,
+        event_publisher: Arc<dyn EventPublisher>,
+    ) -> Self {
+        Self {
+            users,
+            event_publisher,
+        }
+    }
+
+    #[tracing::instrument(name = "UpdateProvisionedUserHandler::handle", skip_all)]
+    pub async fn handle(
+        &self,
+        cmd: UpdateProvisionedUserCommand,
+    ) -> Result<ProvisionedUser, ManageProvisionedUserError> {
+        let mut user = find_provisioned(self.users.as_ref(), &cmd.client_id, &cmd.user_id).await?;
+        if let Some(user_name) = &cmd.changes.user_name {
+            ensure_user_name_free(self.users.as_ref(), user_name, &user.user_id).await?;
+        }
+
+        let outcome = user.apply(cmd.changes)?;
+        if outcome.is_empty() {
+            return Ok(user);
+        }
+        self.users.save(&user).await?;
+        publish_outcome(
+            self.event_publisher.as_ref(),
+            &user,
+            outcome,
+            &cmd.client_id,
+        )
+        .await?;
+
+        Ok(user)
+    }
+}
+
+// ════════════════════════════════════════════════════════════════════════════════
+// Deprovision User
+// ════════════════════════════════════════════════════════════════════════════════
+
+/// Command to deactivate a provisioned user. Their data is kept.
+#[derive(Debug, Clone)]
+pub struct DeprovisionUserCommand {
+    pub client_id: String,
+    pub user_id: UserId,
+}
+
+/// Handler that deactivates users the identity provider removed.
+pub struct DeprovisionUserHandler {
+    users: Arc<dyn ProvisionedUserRepository>,
+    event_publisher: Arc<dyn EventPublisher>,
+}
+
+impl DeprovisionUserHandler {
+    pub fn new(
+        users: Arc<dyn ProvisionedUserRepository>,
+        event_publisher: Arc<dyn EventPublisher>,
+    ) -> Self {
+        Self {
+            users,
+            event_publisher,
+        }
+    }
+
+    #[tracing::instrument(name = "DeprovisionUserHandler::handle", skip_all)]
+    pub async fn handle(
+        &self,
+        cmd: DeprovisionUserCommand,
+    ) -> Result<ProvisionedUser, ManageProvisionedUserError> {
+        UpdateProvisionedUserHandler::new(self.users.clone(), self.event_publisher.clone())
+            .handle(UpdateProvisionedUserCommand {
+                client_id: cmd.client_id,
+                user_id: cmd.user_id,
+                changes: ProvisionedUserChanges {
+                    active: Some(false),
+                    ..Default::default()
+                },
+            })
+            .await
+    }
+}
+
+// ════════════════════════════════════════════════════════════════════════════════
+// Queries
+// ════════════════════════════════════════════════════════════════════════════════
+
+/// Handler that looks up the users a client provisioned.
+pub struct GetProvisionedUsersHandler {
+    users: Arc<dyn ProvisionedUserRepository>,
+}
+
+impl GetProvisionedUsersHandler {
+    pub fn new(users: Arc<dyn ProvisionedUserRepository>) -> Self {
+        Self { users }
+    }
+
+    #[tracing::instrument(name = "GetProvisionedUsersHandler::get", skip_all)]
+    pub async fn get(
+        &self,
+        client_id: &str,
+        user_id: &UserId,
+    ) -> Result<ProvisionedUser, ManageProvisionedUserError> {
+        find_provisioned(self.users.as_ref(), client_id, user_id).await
+    }
+
+    #[tracing::instrument(name = "GetProvisionedUsersHandler::find_by_user_name", skip_all)]
+    pub async fn find_by_user_name(
+        &self,
+        client_id: &str,
+        user_name: &str,
+    ) -> Result<Option<ProvisionedUser>, ManageProvisionedUserError> {
+        Ok(self
+            .users
+            .find_by_user_name(user_name)
+            .await?
+            .filter(|user| user.provisioned_by == client_id))
+    }
+
+    /// Lists users oldest first. `limit` is capped at
+    /// [`MAX_PROVISIONED_USERS_PAGE`].
+    #[tracing::instrument(name = "GetProvisionedUsersHandler::list", skip_all)]
+    pub async fn list(
+        &self,
+        client_id: &str,
+        offset: u32,
+        limit: u32,
+    ) -> Result<ProvisionedUserList, ManageProvisionedUserError> {
+        Ok(self
+            .users
+            .list(client_id, offset, limit.min(MAX_PROVISIONED_USERS_PAGE))
+            .await?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::foundation::EventEnvelope;
+    use async_trait::async_trait;
+    use std::sync::Mutex;
+
+    #[derive(Default)]
+    struct InMemoryUsers {
+        users: Mutex<Vec<ProvisionedUser>>,
+    }
+
+    #[async_trait]
+    impl ProvisionedUserRepository for InMemoryUsers {
+        async fn create(&self, user: &ProvisionedUser) -> Result<(), DomainError> {
+            let mut users = self.users.lock().unwrap();
+            if users.iter().any(|u| {
+                u.user_id == user.user_id || u.user_name.eq_ignore_ascii_case(&user.user_name)
+            }) {
+                return Err(DomainError::new(
+                    ErrorCode::ConcurrentModification,
+                    "Provisioned user already exists",
+                ));
+            }
+            users.push(user.clone());
+            Ok(())
+        }
+
+        async fn save(&self, user: &ProvisionedUser) -> Result<(), DomainError> {
+            let mut users = self.users.lock().unwrap();
+            users.retain(|u| u.user_id != user.user_id);
+            users.push(user.clone());
+            Ok(())
+        }
+
+        async fn find_by_id(
+            &self,
+            user_id: &UserId,
+        ) -> Result<Option<ProvisionedUser>, DomainError> {
+            let users = self.users.lock().unwrap();
+            Ok(users.iter().find(|u| &u.user_id == user_id).cloned())
+        }
+
+        async fn find_by_user_name(
+            &self,
+            user_name: &str,
+        ) -> Result<Option<ProvisionedUser>, DomainError> {
+            let users = self.users.lock().unwrap();
+            Ok(users
+                .iter()
+                .find(|u| u.user_name.eq_ignore_ascii_case(user_name.trim()))
+                .cloned())
+        }
+
+        async fn list(
+            &self,
+            client_id: &str,
+            offset: u32,
+            limit: u32,
+        ) -> Result<ProvisionedUserList, DomainError> {
+            let users: Vec<ProvisionedUser> = self
+                .users
+                .lock()
+                .unwrap()
+                .iter()
+                .filter(|u| u.provisioned_by == client_id)
+                .cloned()
+                .collect();
+            Ok(ProvisionedUserList {
+                total: users.len() as u64,
+                users: users
+                    .into_iter()
+                    .skip(offset as usize)
+                    .take(limit as usize)
+                    .collect(),
+            })
+        }
+    }
+
+    #[derive(Default)]
+    struct RecordingPublisher {
+        events: Mutex<Vec<EventEnvelope>>,
+    }
+
+    impl RecordingPublisher {
+        fn event_types(&self) -> Vec<String> {
+            self.events
+                .lock()
+                .unwrap()
+                .iter()
+                .map(|e| e.event_type.clone())
+                .collect()
+        }
+    }
+
+    #[async_trait]
+    impl EventPublisher for RecordingPublisher {
+        async fn publish(&self, event: EventEnvelope) -> Result<(), DomainError> {
+            self.events.lock().unwrap().push(event);
+            Ok(())
+        }
+
+        async fn publish_all(&self, events: Vec<EventEnvelope>) -> Result<(), DomainError> {
+            self.events.lock().unwrap().extend(events);
+            Ok(())
+        }
+    }
+
+    fn provision(id: &str, user_name: &str) -> ProvisionUserCommand {
+        ProvisionUserCommand {
+            client_id: "okta-scim".to_string(),
+            user_id: UserId::new(id).unwrap(),
+            user_name: user_name.to_string(),
+            email: Some(user_name.to_string()),
+            display_name: None,
+            active: true,
+        }
+    }
+
+    fn setup() -> (Arc<InMemoryUsers>, Arc<RecordingPublisher>) {
+        (
+            Arc::new(InMemoryUsers::default()),
+            Arc::new(RecordingPublisher::default()),
+        )
+    }
+
+    #[tokio::test]
+    async fn provisioning_saves_user_and_publishes_event() {
+        let (users, publisher) = setup();
+        let handler = ProvisionUserHandler::new(users.clone(), publisher.clone());
+
+        let user = handler
+            .handle(provision("idp-1", "ada@example.com"))
+            .await
+            .unwrap();
+
+        assert!(user.active);
+        assert_eq!(users.users.lock().unwrap().len(), 1);
+        assert_eq!(
+            publisher.event_types(),
+            vec!["provisioning.user_provisioned.v1"]
+        );
+        let events = publisher.events.lock().unwrap();
+        assert_eq!(events[0].payload["provisioned_by"], "okta-scim");
+    }
+
+    #[tokio::test]
+    async fn provisioning_rejects_duplicates() {
+        let (users, publisher) = setup();
+        let handler = ProvisionUserHandler::new(users, publisher);
+        handler
+            .handle(provision("idp-1", "ada@example.com"))
+            .await
+            .unwrap();
+
+        let same_id = handler
+            .handle(provision("idp-1", "other@example.com"))
+            .await;
+        assert!(matches!(
+            same_id,
+            Err(ManageProvisionedUserError::AlreadyProvisioned(_))
+        ));
+
+        let same_name = handler.handle(provision("idp-2", "ADA@example.com")).await;
+        assert!(matches!(
+            same_name,
+            Err(ManageProvisionedUserError::UserNameTaken(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn another_client_provisioning_the_same_user_conflicts() {
+        let (users, publisher) = setup();
+        let handler = ProvisionUserHandler::new(users.clone(), publisher);
+        handler
+            .handle(provision("idp-1", "ada@example.com"))
+            .await
+            .unwrap();
+
+        let result = handler
+            .handle(ProvisionUserCommand {
+                client_id: "azure-scim".to_string(),
+                ..provision("idp-1", "ada.lovelace@example.com")
+            })
+            .await;
+
+        assert!(matches!(
+            result,
+            Err(ManageProvisionedUserError::AlreadyProvisioned(_))
+        ));
+        let user = users
+            .find_by_id(&UserId::new("idp-1").unwrap())
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(user.provisioned_by, "okta-scim");
+    }
+
+    #[tokio::test]
+    async fn users_created_inactive_are_deprovisioned_immediately() {
+        let (users, publisher) = setup();
+        let handler = ProvisionUserHandler::new(users, publisher.clone());
+
+        let user = handler
+            .handle(ProvisionUserCommand {
+                active: false,
+                ..provision("idp-1", "ada@example.com")
+            })
+            .await
+            .unwrap();
+
+        assert!(!user.active);
+        assert_eq!(
+            publisher.event_types(),
+            vec![
+                "provisioning.user_provisioned.v1",
+                "provisioning.user_deprovisioned.v1"
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn update_publishes_changes_and_activation_separately() {
+        let (users, publisher) = setup();
+        ProvisionUserHandler::new(users.clone(), publisher.clone())
+            .handle(provision("idp-1", "ada@example.com"))
+            .await
+            .unwrap();
+
+        let user = UpdateProvisionedUserHandler::new(users, publisher.clone())
+            .handle(UpdateProvisionedUserCommand {
+                client_id: "okta-scim".to_string(),
+                user_id: UserId::new("idp-1").unwrap(),
+                changes: ProvisionedUserChanges {
+                    display_name: Some(Some("Ada".to_string())),
+                    active: Some(false),
+                    ..Default::default()
+                },
+            })
+            .await
+            .unwrap();
+
+        assert!(!user.active);
+        assert_eq!(
+            publisher.event_types()[1..],
+            [
+                "provisioning.user_updated.v1",
+                "provisioning.user_deprovisioned.v1"
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn repeated_deprovisioning_publishes_once() {
+        let (users, publisher) = setup();
+        ProvisionUserHandler::new(users.clone(), publisher.clone())
+            .handle(provision("idp-1", "ada@example.com"))
+            .await
+            .unwrap();
+        let handler = DeprovisionUserHandler::new(users, publisher.clone());
+        let cmd = DeprovisionUserCommand {
+            client_id: "okta-scim".to_string(),
+            user_id: UserId::new("idp-1").unwrap(),
+        };
+
+        handler.handle(cmd.clone()).await.unwrap();
+        handler.handle(cmd).await.unwrap();
+
+        assert_eq!(publisher.event_types().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn updating_unknown_users_fails() {
+        let (users, publisher) = setup();
+
+        let result = DeprovisionUserHandler::new(users, publisher)
+            .handle(DeprovisionUserCommand {
+                client_id: "okta-scim".to_string(),
+                user_id: UserId::new("idp-404").unwrap(),
+            })
+            .await;
+
+        assert!(matches!(
+            result,
+            Err(ManageProvisionedUserError::NotFound(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn other_clients_cannot_change_users() {
+        let (users, publisher) = setup();
+        ProvisionUserHandler::new(users.clone(), publisher.clone())
+            .handle(provision("idp-1", "ada@example.com"))
+            .await
+            .unwrap();
+        let user_id = UserId::new("idp-1").unwrap();
+
+        let update = UpdateProvisionedUserHandler::new(users.clone(), publisher.clone())
+            .handle(UpdateProvisionedUserCommand {
+                client_id: "azure-scim".to_string(),
+                user_id: user_id.clone(),
+                changes: ProvisionedUserChanges {
+                    display_name: Some(Some("Mallory".to_string())),
+                    ..Default::default()
+                },
+            })
+            .await;
+        let deprovision = DeprovisionUserHandler::new(users.clone(), publisher.clone())
+            .handle(DeprovisionUserCommand {
+                client_id: "azure-scim".to_string(),
+                user_id: user_id.clone(),
+            })
+            .await;
+
+        assert!(matches!(
+            update,
+            Err(ManageProvisionedUserError::NotFound(_))
+        ));
+        assert!(matches!(
+            deprovision,
+            Err(ManageProvisionedUserError::NotFound(_))
+        ));
+        let user = users.find_by_id(&user_id).await.unwrap().unwrap();
+        assert!(user.active);
+        assert_eq!(user.display_name, None);
+        assert_eq!(publisher.event_types().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn queries_only_see_the_clients_own_users() {
+        let (users, publisher) = setup();
+        let provisioner = ProvisionUserHandler::new(users.clone(), publisher);
+        provisioner
+            .handle(provision("idp-1", "ada@example.com"))
+            .await
+            .unwrap();
+        provisioner
+            .handle(ProvisionUserCommand {
+                client_id: "azure-scim".to_string(),
+                ..provision("idp-2", "grace@example.com")
+            })
+            .await
+            .unwrap();
+        let handler = GetProvisionedUsersHandler::new(users);
+
+        let page = handler.list("okta-scim", 0, 10).await.unwrap();
+        let foreign = handler
+            .get("okta-scim", &UserId::new("idp-2").unwrap())
+            .await;
+        let by_name = handler
+            .find_by_user_name("okta-scim", "grace@example.com")
+            .await
+            .unwrap();
+
+        assert_eq!(page.total, 1);
+        assert_eq!(page.users[0].user_name, "ada@example.com");
+        assert!(matches!(
+            foreign,
+            Err(ManageProvisionedUserError::NotFound(_))
+        ));
+        assert!(by_name.is_none());
+    }
+}
@@ -0,0 +1,15 @@
+//! Provisioning handlers.
+//!
+//! ## Commands
+//! - Provision, update, and deprovision users for an identity provider
+//!
+//! ## Queries
+//! - Provisioned users by ID, by `userName`, or page by page
+
+mod manage_provisioned_users;
+
+pub use manage_provisioned_users::{
+    DeprovisionUserCommand, DeprovisionUserHandler, GetProvisionedUsersHandler,
+    ManageProvisionedUserError, ProvisionUserCommand, ProvisionUserHandler,
+    UpdateProvisionedUserCommand, UpdateProvisionedUserHandler, MAX_PROVISIONED_USERS_PAGE,
+};
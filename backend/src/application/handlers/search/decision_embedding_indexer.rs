@@ -0,0 +1,418 @@
+//! DecisionEmbeddingIndexer - Keeps decision embeddings current.
+//!
+//! When a component's output changes, its output text is embedded; when a
+//! component completes, what the user said in its conversation is embedded
+//! as the conversation summary; a batch of component commands is indexed
+//! operation by operation. Embeddings are stored under the session
+//! owner, and text whose hash matches the stored embedding is not sent to
+//! the provider again.
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use serde::Deserialize;
+use serde_json::Value as JsonValue;
+use sha2::{Digest, Sha256};
+
+use crate::application::handlers::cycle::{ComponentBatchAppliedEvent, ComponentOperationKind};
+use crate::domain::conversation::Role;
+use crate::domain::cycle::Cycle;
+use crate::domain::foundation::{
+    ComponentType, CycleId, DomainError, ErrorCode, EventEnvelope, Timestamp,
+};
+use crate::ports::{
+    ConversationReader, CycleRepository, DecisionEmbedding, DecisionEmbeddingRepository,
+    EmbeddingProvider, EmbeddingSource, EventHandler, EventSubscriber, MessageListOptions,
+    SessionRepository,
+};
+
+/// Events after which a decision's embeddings may be stale.
+pub const DECISION_EMBEDDING_EVENTS: &[&str] = &[
+    "component.output_updated.v1",
+    "component.completed.v1",
+    "component.batch_applied.v1",
+];
+
+/// Most conversation messages read when summarizing a component.
+const SUMMARY_MESSAGE_LIMIT: u32 = 100;
+
+/// The fields shared by the component events this handler reacts to.
+#[derive(Debug, Deserialize)]
+struct ComponentChange {
+    cycle_id: CycleId,
+    component_type: ComponentType,
+}
+
+/// Embeds component outputs and conversation summaries as they change.
+pub struct DecisionEmbeddingIndexer {
+    cycles: Arc<dyn CycleRepository>,
+    sessions: Arc<dyn SessionRepository>,
+    conversations: Arc<dyn ConversationReader>,
+    provider: Arc<dyn EmbeddingProvider>,
+    embeddings: Arc<dyn DecisionEmbeddingRepository>,
+}
+
+impl DecisionEmbeddingIndexer {
+    pub fn new(
+        cycles: Arc<dyn CycleRepository>,
+        sessions: Arc<dyn SessionRepository>,
+        conversations: Arc<dyn ConversationReader>,
+        provider: Arc<dyn EmbeddingProvider>,
+        embeddings: Arc<dyn DecisionEmbeddingRepository>,
+    ) -> Self {
+        Self {
+            cycles,
+            sessions,
+            conversations,
+            provider,
+            embeddings,
+        }
+    }
+
+    /// Subscribe to every event in [`DECISION_EMBEDDING_EVENTS`].
+    pub fn register(self: &Arc<Self>, subscriber: &impl EventSubscriber) {
+        subscriber.subscribe_all(DECISION_EMBEDDING_EVENTS, self.clone());
+    }
+
+    /// Text of the component's output, or of the user's side of its conversation.
+    async fn source_text(
+        &self,
+        change: &ComponentChange,
+        source: EmbeddingSource,
+    ) -> Result<Option<(Cycle, String)>, DomainError> {
+        let Some(cycle) = self.cycles.find_by_id(&change.cycle_id).await? else {
+            return Ok(None);
+        };
+        let Some(component) = cycle.component(change.component_type) else {
+            return Ok(None);
+        };
+
+        let text = match source {
+            EmbeddingSource::ComponentOutput => output_text(&component.output_as_value()),
+            EmbeddingSource::ConversationSummary => {
+                let Some(conversation) =
+                    self.conversations.get_by_component(&component.id()).await?
+                else {
+                    return Ok(None);
+                };
+                let messages = self
+                    .conversations
+                    .get_messages(
+                        &conversation.id,
+                        &MessageListOptions::with_limit(SUMMARY_MESSAGE_LIMIT).visible_only(),
+                    )
+                    .await?;
+                messages
+                    .items
+                    .into_iter()
+                    .filter(|message| message.role == Role::User)
+                    .map(|message| message.content)
+                    .collect::<Vec<_>>()
+                    .join("\n")
+            }
+        };
+
+        if text.trim().is_empty() {
+            return Ok(None);
+        }
+        Ok(Some((cycle, text)))
+    }
+
+    /// Embeds `source` for the changed component, unless its text is
+    /// already embedded.
+    async fn index(
+        &self,
+        change: &ComponentChange,
+        source: EmbeddingSource,
+    ) -> Result<(), DomainError> {
+        let Some((cycle, text)) = self.source_text(change, source).await? else {
+            return Ok(());
+        };
+        let Some(session) = self.sessions.find_by_id(&cycle.session_id()).await? else {
+            return Ok(());
+        };
+
+        let content_hash = format!("{:x}", Sha256::digest(text.as_bytes()));
+        let model = self.provider.model().to_string();
+        let unchanged = self
+            .embeddings
+            .list_for_cycle(&change.cycle_id)
+            .await?
+            .iter()
+            .any(|stored| {
+                stored.component_type == change.component_type
+                    && stored.source == source
+                    && stored.model == model
+                    && stored.content_hash == content_hash
+            });
+        if unchanged {
+            return Ok(());
+        }
+
+        let embedding = self
+            .provider
+            .embed(&[text])
+            .await
+            .map_err(|e| DomainError::new(ErrorCode::AIProviderError, e.to_string()))?
+            .pop()
+            .ok_or_else(|| {
+                DomainError::new(ErrorCode::AIProviderError, "Provider returned no embedding")
+            })?;
+
+        self.embeddings
+            .upsert(&DecisionEmbedding {
+                cycle_id: change.cycle_id,
+                component_type: change.component_type,
+                source,
+                session_id: cycle.session_id(),
+                user_id: session.user_id().clone(),
+                content_hash,
+                model,
+                embedding,
+                updated_at: Timestamp::now(),
+            })
+            .await
+    }
+}
+
+#[async_trait]
+impl EventHandler for DecisionEmbeddingIndexer {
+    async fn handle(&self, event: EventEnvelope) -> Result<(), DomainError> {
+        let invalid =
+            |e: serde_json::Error| DomainError::new(ErrorCode::ValidationFailed, e.to_string());
+        match event.event_type.as_str() {
+            "component.batch_applied.v1" => {
+                let batch: ComponentBatchAppliedEvent = event.payload_as().map_err(invalid)?;
+                for operation in batch.operations {
+                    let change = ComponentChange {
+                        cycle_id: batch.cycle_id,
+                        component_type: operation.component_type,
+                    };
+                    let source = match operation.kind {
+                        ComponentOperationKind::UpdateOutput => EmbeddingSource::ComponentOutput,
+                        ComponentOperationKind::Complete => EmbeddingSource::ConversationSummary,
+                    };
+                    self.index(&change, source).await?;
+                }
+                Ok(())
+            }
+            "component.completed.v1" => {
+                let change = event.payload_as().map_err(invalid)?;
+                self.index(&change, EmbeddingSource::ConversationSummary)
+                    .await
+            }
+            _ => {
+                let change = event.payload_as().map_err(invalid)?;
+                self.index(&change, EmbeddingSource::ComponentOutput).await
+            }
+        }
+    }
+
+    fn name(&self) -> &'static str {
+        "DecisionEmbeddingIndexer"
+    }
+}
+
+/// Every string in a component output, in document order. Keys, numbers
+/// and flags say little about what the decision is about.
+fn output_text(output: &JsonValue) -> String {
+    fn collect<'a>(value: &'a JsonValue, parts: &mut Vec<&'a str>) {
+        match value {
+            JsonValue::String(text) if !text.trim().is_empty() => parts.push(text),
+            JsonValue::Array(items) => items.iter().for_each(|item| collect(item, parts)),
+            JsonValue::Object(fields) => fields.values().for_each(|field| collect(field, parts)),
+            _ => {}
+        }
+    }
+
+    let mut parts = Vec::new();
+    collect(output, &mut parts);
+    parts.join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::fixtures::*;
+    use super::*;
+    use crate::adapters::ai::HashingEmbeddingProvider;
+    use crate::adapters::search::InMemoryDecisionEmbeddingRepository;
+    use crate::application::handlers::cycle::{
+        AppliedComponentOperation, ComponentCompletedEvent, ComponentOutputUpdatedEvent,
+    };
+    use crate::domain::foundation::{EventId, SerializableDomainEvent};
+    use serde_json::json;
+
+    struct Setup {
+        indexer: DecisionEmbeddingIndexer,
+        provider: Arc<CountingEmbeddingProvider>,
+        embeddings: Arc<InMemoryDecisionEmbeddingRepository>,
+        cycle_id: CycleId,
+    }
+
+    async fn setup(decisions: &[&str], user_messages: &[&str]) -> Setup {
+        let cycles = MockCycleRepository::default();
+        let sessions = MockSessionRepository::default();
+        let cycle_id = add_decision(&cycles, &sessions, owner(), "Move?", decisions).await;
+        let provider = Arc::new(CountingEmbeddingProvider::new(
+            HashingEmbeddingProvider::new(),
+        ));
+        let embeddings = Arc::new(InMemoryDecisionEmbeddingRepository::new());
+        let indexer = DecisionEmbeddingIndexer::new(
+            Arc::new(cycles),
+            Arc::new(sessions),
+            Arc::new(MockConversationReader::with_user_messages(user_messages)),
+            provider.clone(),
+            embeddings.clone(),
+        );
+        Setup {
+            indexer,
+            provider,
+            embeddings,
+            cycle_id,
+        }
+    }
+
+    fn output_updated(cycle_id: CycleId) -> EventEnvelope {
+        ComponentOutputUpdatedEvent {
+            event_id: EventId::new(),
+            cycle_id,
+            component_type: ComponentType::IssueRaising,
+            updated_at: Timestamp::now(),
+        }
+        .to_envelope()
+    }
+
+    fn completed(cycle_id: CycleId) -> EventEnvelope {
+        ComponentCompletedEvent {
+            event_id: EventId::new(),
+            cycle_id,
+            component_type: ComponentType::IssueRaising,
+            completed_at: Timestamp::now(),
+        }
+        .to_envelope()
+    }
+
+    #[tokio::test]
+    async fn output_update_embeds_output_under_session_owner() {
+        let setup = setup(&["Move to Lisbon?"], &[]).await;
+
+        setup
+            .indexer
+            .handle(output_updated(setup.cycle_id))
+            .await
+            .unwrap();
+
+        let stored = setup
+            .embeddings
+            .list_for_cycle(&setup.cycle_id)
+            .await
+            .unwrap();
+        assert_eq!(stored.len(), 1);
+        assert_eq!(stored[0].source, EmbeddingSource::ComponentOutput);
+        assert_eq!(stored[0].user_id, owner());
+    }
+
+    #[tokio::test]
+    async fn unchanged_output_is_not_embedded_again() {
+        let setup = setup(&["Move to Lisbon?"], &[]).await;
+
+        setup
+            .indexer
+            .handle(output_updated(setup.cycle_id))
+            .await
+            .unwrap();
+        setup
+            .indexer
+            .handle(output_updated(setup.cycle_id))
+            .await
+            .unwrap();
+
+        assert_eq!(setup.provider.calls(), 1);
+    }
+
+    #[tokio::test]
+    async fn completion_embeds_the_users_messages() {
+        let setup = setup(&[], &["I keep thinking about Lisbon"]).await;
+
+        setup
+            .indexer
+            .handle(completed(setup.cycle_id))
+            .await
+            .unwrap();
+
+        let stored = setup
+            .embeddings
+            .list_for_cycle(&setup.cycle_id)
+            .await
+            .unwrap();
+        assert_eq!(stored.len(), 1);
+        assert_eq!(stored[0].source, EmbeddingSource::ConversationSummary);
+    }
+
+    #[tokio::test]
+    async fn batches_embed_each_operation() {
+        let setup = setup(&["Move to Lisbon?"], &["I keep thinking about Lisbon"]).await;
+        let batch = ComponentBatchAppliedEvent {
+            event_id: EventId::new(),
+            cycle_id: setup.cycle_id,
+            operations: vec![
+                AppliedComponentOperation {
+                    kind: ComponentOperationKind::UpdateOutput,
+                    component_type: ComponentType::IssueRaising,
+                },
+                AppliedComponentOperation {
+                    kind: ComponentOperationKind::Complete,
+                    component_type: ComponentType::IssueRaising,
+                },
+            ],
+            applied_at: Timestamp::now(),
+        };
+
+        setup.indexer.handle(batch.to_envelope()).await.unwrap();
+
+        let mut sources: Vec<_> = setup
+            .embeddings
+            .list_for_cycle(&setup.cycle_id)
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|stored| stored.source)
+            .collect();
+        sources.sort_by_key(|source| source == &EmbeddingSource::ConversationSummary);
+        assert_eq!(
+            sources,
+            vec![
+                EmbeddingSource::ComponentOutput,
+                EmbeddingSource::ConversationSummary
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn empty_output_is_skipped() {
+        let setup = setup(&[], &[]).await;
+
+        setup
+            .indexer
+            .handle(output_updated(setup.cycle_id))
+            .await
+            .unwrap();
+
+        assert_eq!(setup.provider.calls(), 0);
+    }
+
+    #[test]
+    fn output_text_collects_nested_strings() {
+        let output = json!({
+            "objectives": [{"name": "Career growth", "weight": 3}],
+            "notes": "Family nearby",
+            "user_confirmed": true
+        });
+
+        let text = output_text(&output);
+
+        assert!(text.contains("Career growth"));
+        assert!(text.contains("Family nearby"));
+        assert!(!text.contains("weight"));
+    }
+}
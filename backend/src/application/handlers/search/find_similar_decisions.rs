@@ -0,0 +1,359 @@
+//! FindSimilarDecisionsHandler - Query handler for a user's past decisions
+//! resembling a cycle or a piece of text.
+//!
+//! A cycle is compared by the centroid of its stored embeddings, so it
+//! only finds matches once it has been indexed. Text is embedded on the
+//! fly. Only the requesting user's own decisions are searched.
+
+use std::sync::Arc;
+
+use crate::domain::foundation::{
+    ComponentType, CycleId, DomainError, Embedding, SessionId, UserId,
+};
+use crate::ports::{
+    AIError, CycleRepository, DecisionEmbeddingRepository, EmbeddingProvider, EmbeddingSource,
+    SessionRepository,
+};
+
+/// Decisions returned when no limit is given.
+pub const DEFAULT_SIMILAR_DECISIONS_LIMIT: u32 = 5;
+
+/// Most decisions returned by one query.
+pub const MAX_SIMILAR_DECISIONS_LIMIT: u32 = 20;
+
+/// What to compare past decisions against.
+#[derive(Debug, Clone)]
+pub enum SimilarTo {
+    /// An existing cycle, which is left out of the results.
+    Cycle(CycleId),
+    /// Free text, such as a decision the user is about to start.
+    Text(String),
+}
+
+/// Query for the past decisions closest to a cycle or text.
+#[derive(Debug, Clone)]
+pub struct FindSimilarDecisionsQuery {
+    pub user_id: UserId,
+    pub similar_to: SimilarTo,
+    /// Clamped to [`MAX_SIMILAR_DECISIONS_LIMIT`]; defaults when `None`.
+    pub limit: Option<u32>,
+}
+
+/// A similar past decision with its session title.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SimilarDecisionView {
+    pub cycle_id: CycleId,
+    pub session_id: SessionId,
+    pub session_title: String,
+    pub component_type: ComponentType,
+    pub source: EmbeddingSource,
+    pub similarity: f32,
+}
+
+/// Error type for finding similar decisions.
+#[derive(Debug)]
+pub enum FindSimilarDecisionsError {
+    /// Cycle not found.
+    CycleNotFound(CycleId),
+    /// User does not own the cycle's session.
+    Forbidden,
+    /// The search text is empty.
+    InvalidQuery(String),
+    /// The text could not be embedded.
+    Embedding(AIError),
+    /// Domain error.
+    Domain(DomainError),
+}
+
+impl std::fmt::Display for FindSimilarDecisionsError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            FindSimilarDecisionsError::CycleNotFound(id) => write!(f, "Cycle not found: {}", id),
+            FindSimilarDecisionsError::Forbidden => write!(f, "Permission denied"),
+            FindSimilarDecisionsError::InvalidQuery(msg) => write!(f, "Invalid query: {}", msg),
+            FindSimilarDecisionsError::Embedding(err) => write!(f, "Embedding failed: {}", err),
+            FindSimilarDecisionsError::Domain(err) => write!(f, "{}", err),
+        }
+    }
+}
+
+impl std::error::Error for FindSimilarDecisionsError {}
+
+impl From<DomainError> for FindSimilarDecisionsError {
+    fn from(err: DomainError) -> Self {
+        FindSimilarDecisionsError::Domain(err)
+    }
+}
+
+/// Handler for finding similar past decisions.
+pub struct FindSimilarDecisionsHandler {
+    cycle_repository: Arc<dyn CycleRepository>,
+    session_repository: Arc<dyn SessionRepository>,
+    provider: Arc<dyn EmbeddingProvider>,
+    embeddings: Arc<dyn DecisionEmbeddingRepository>,
+}
+
+impl FindSimilarDecisionsHandler {
+    pub fn new(
+        cycle_repository: Arc<dyn CycleRepository>,
+        session_repository: Arc<dyn SessionRepository>,
+        provider: Arc<dyn EmbeddingProvider>,
+        embeddings: Arc<dyn DecisionEmbeddingRepository>,
+    ) -> Self {
+        Self {
+            cycle_repository,
+            session_repository,
+            provider,
+            embeddings,
+        }
+    }
+
+    /// Returns the closest past decisions, most similar first.
+    #[tracing::instrument(name = "FindSimilarDecisionsHandler::handle", skip_all)]
+    pub async fn handle(
+        &self,
+        query: FindSimilarDecisionsQuery,
+    ) -> Result<Vec<SimilarDecisionView>, FindSimilarDecisionsError> {
+        let limit = query
+            .limit
+            .unwrap_or(DEFAULT_SIMILAR_DECISIONS_LIMIT)
+            .clamp(1, MAX_SIMILAR_DECISIONS_LIMIT);
+        let model = self.provider.model().to_string();
+
+        let (embedding, exclude) = match query.similar_to {
+            SimilarTo::Cycle(cycle_id) => {
+                self.authorize_cycle(&cycle_id, &query.user_id).await?;
+                let stored = self.embeddings.list_for_cycle(&cycle_id).await?;
+                let centroid = Embedding::centroid(
+                    stored
+                        .iter()
+                        .filter(|stored| stored.model == model)
+                        .map(|stored| &stored.embedding),
+                );
+                match centroid {
+                    Some(centroid) => (centroid, Some(cycle_id)),
+                    // Not indexed yet
+                    None => return Ok(vec![]),
+                }
+            }
+            SimilarTo::Text(text) => {
+                let text = text.trim();
+                if text.is_empty() {
+                    return Err(FindSimilarDecisionsError::InvalidQuery(
+                        "Search text is empty".to_string(),
+                    ));
+                }
+                let embedding = self
+                    .provider
+                    .embed(&[text.to_string()])
+                    .await
+                    .map_err(FindSimilarDecisionsError::Embedding)?
+                    .pop()
+                    .ok_or_else(|| {
+                        FindSimilarDecisionsError::Embedding(AIError::parse(
+                            "Provider returned no embedding",
+                        ))
+                    })?;
+                (embedding, None)
+            }
+        };
+
+        let similar = self
+            .embeddings
+            .find_similar(&query.user_id, &embedding, &model, exclude, limit)
+            .await?;
+
+        let mut views = Vec::with_capacity(similar.len());
+        for decision in similar {
+            // Sessions deleted or trashed since indexing drop out
+            let Some(session) = self
+                .session_repository
+                .find_by_id(&decision.session_id)
+                .await?
+            else {
+                continue;
+            };
+            if session.status().is_deleted() {
+                continue;
+            }
+            views.push(SimilarDecisionView {
+                cycle_id: decision.cycle_id,
+                session_id: decision.session_id,
+                session_title: session.title().to_string(),
+                component_type: decision.component_type,
+                source: decision.source,
+                similarity: decision.similarity,
+            });
+        }
+        Ok(views)
+    }
+
+    async fn authorize_cycle(
+        &self,
+        cycle_id: &CycleId,
+        user_id: &UserId,
+    ) -> Result<(), FindSimilarDecisionsError> {
+        let cycle = self
+            .cycle_repository
+            .find_by_id(cycle_id)
+            .await?
+            .ok_or(FindSimilarDecisionsError::CycleNotFound(*cycle_id))?;
+        let session = self
+            .session_repository
+            .find_by_id(&cycle.session_id())
+            .await?
+            .ok_or(FindSimilarDecisionsError::CycleNotFound(*cycle_id))?;
+        session
+            .authorize(user_id)
+            .map_err(|_| FindSimilarDecisionsError::Forbidden)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::fixtures::*;
+    use super::super::DecisionEmbeddingIndexer;
+    use super::*;
+    use crate::adapters::ai::HashingEmbeddingProvider;
+    use crate::adapters::search::InMemoryDecisionEmbeddingRepository;
+    use crate::application::handlers::cycle::ComponentOutputUpdatedEvent;
+    use crate::domain::foundation::{EventId, SerializableDomainEvent, Timestamp};
+    use crate::ports::EventHandler;
+
+    struct Setup {
+        handler: FindSimilarDecisionsHandler,
+        lisbon: CycleId,
+        porto: CycleId,
+        laptop: CycleId,
+        strangers: CycleId,
+    }
+
+    /// Three of the owner's decisions and one of a stranger's, all indexed.
+    async fn setup() -> Setup {
+        let cycles = Arc::new(MockCycleRepository::default());
+        let sessions = Arc::new(MockSessionRepository::default());
+        let lisbon = add_decision(
+            &cycles,
+            &sessions,
+            owner(),
+            "Lisbon",
+            &["Relocate to Lisbon for the new job"],
+        )
+        .await;
+        let porto = add_decision(
+            &cycles,
+            &sessions,
+            owner(),
+            "Porto",
+            &["Relocate to Porto for the new job"],
+        )
+        .await;
+        let laptop = add_decision(
+            &cycles,
+            &sessions,
+            owner(),
+            "Laptop",
+            &["Which laptop should I buy"],
+        )
+        .await;
+        let strangers = add_decision(
+            &cycles,
+            &sessions,
+            stranger(),
+            "Theirs",
+            &["Relocate to Lisbon for the new job"],
+        )
+        .await;
+
+        let provider = Arc::new(HashingEmbeddingProvider::new());
+        let embeddings = Arc::new(InMemoryDecisionEmbeddingRepository::new());
+        let indexer = DecisionEmbeddingIndexer::new(
+            cycles.clone(),
+            sessions.clone(),
+            Arc::new(MockConversationReader::with_user_messages(&[])),
+            provider.clone(),
+            embeddings.clone(),
+        );
+        for cycle_id in [lisbon, porto, laptop, strangers] {
+            let event = ComponentOutputUpdatedEvent {
+                event_id: EventId::new(),
+                cycle_id,
+                component_type: ComponentType::IssueRaising,
+                updated_at: Timestamp::now(),
+            };
+            indexer.handle(event.to_envelope()).await.unwrap();
+        }
+
+        Setup {
+            handler: FindSimilarDecisionsHandler::new(cycles, sessions, provider, embeddings),
+            lisbon,
+            porto,
+            laptop,
+            strangers,
+        }
+    }
+
+    fn query(similar_to: SimilarTo) -> FindSimilarDecisionsQuery {
+        FindSimilarDecisionsQuery {
+            user_id: owner(),
+            similar_to,
+            limit: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn similar_to_cycle_ranks_own_other_decisions() {
+        let setup = setup().await;
+
+        let similar = setup
+            .handler
+            .handle(query(SimilarTo::Cycle(setup.lisbon)))
+            .await
+            .unwrap();
+
+        let ids: Vec<_> = similar.iter().map(|view| view.cycle_id).collect();
+        assert_eq!(ids, vec![setup.porto, setup.laptop]);
+        assert_eq!(similar[0].session_title, "Porto");
+        assert!(!ids.contains(&setup.strangers));
+    }
+
+    #[tokio::test]
+    async fn similar_to_text_embeds_the_text() {
+        let setup = setup().await;
+
+        let similar = setup
+            .handler
+            .handle(query(SimilarTo::Text("buy a laptop".to_string())))
+            .await
+            .unwrap();
+
+        assert_eq!(similar[0].cycle_id, setup.laptop);
+    }
+
+    #[tokio::test]
+    async fn other_users_cycles_are_forbidden() {
+        let setup = setup().await;
+
+        let result = setup
+            .handler
+            .handle(query(SimilarTo::Cycle(setup.strangers)))
+            .await;
+
+        assert!(matches!(result, Err(FindSimilarDecisionsError::Forbidden)));
+    }
+
+    #[tokio::test]
+    async fn empty_text_is_rejected() {
+        let setup = setup().await;
+
+        let result = setup
+            .handler
+            .handle(query(SimilarTo::Text("  ".to_string())))
+            .await;
+
+        assert!(matches!(
+            result,
+            Err(FindSimilarDecisionsError::InvalidQuery(_))
+        ));
+    }
+}
@@ -0,0 +1,254 @@
+//! Repositories and providers for semantic search handler tests.
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Mutex;
+
+use async_trait::async_trait;
+use serde_json::{json, Value as JsonValue};
+
+use crate::adapters::ai::HashingEmbeddingProvider;
+use crate::domain::conversation::{ConversationState, Role};
+use crate::domain::cycle::Cycle;
+use crate::domain::foundation::{
+    ComponentId, ComponentType, ConversationId, CycleId, DomainError, Embedding, SessionId,
+    Timestamp, UserId,
+};
+use crate::domain::session::Session;
+use crate::ports::{
+    AIError, ConversationReader, ConversationView, CycleRepository, EmbeddingProvider, MessageList,
+    MessageListOptions, MessageView, SessionRepository,
+};
+
+#[derive(Default)]
+pub struct MockCycleRepository {
+    cycles: Mutex<Vec<Cycle>>,
+}
+
+#[async_trait]
+impl CycleRepository for MockCycleRepository {
+    async fn save(&self, cycle: &Cycle) -> Result<(), DomainError> {
+        self.cycles.lock().unwrap().push(cycle.clone());
+        Ok(())
+    }
+
+    async fn update(&self, _cycle: &Cycle) -> Result<(), DomainError> {
+        Ok(())
+    }
+
+    async fn find_by_id(&self, id: &CycleId) -> Result<Option<Cycle>, DomainError> {
+        Ok(self
+            .cycles
+            .lock()
+            .unwrap()
+            .iter()
+            .find(|c| c.id() == *id)
+            .cloned())
+    }
+
+    async fn exists(&self, id: &CycleId) -> Result<bool, DomainError> {
+        Ok(self.cycles.lock().unwrap().iter().any(|c| c.id() == *id))
+    }
+
+    async fn find_by_session_id(&self, _session_id: &SessionId) -> Result<Vec<Cycle>, DomainError> {
+        Ok(vec![])
+    }
+
+    async fn find_primary_by_session_id(
+        &self,
+        _session_id: &SessionId,
+    ) -> Result<Option<Cycle>, DomainError> {
+        Ok(None)
+    }
+
+    async fn find_branches(&self, _parent_id: &CycleId) -> Result<Vec<Cycle>, DomainError> {
+        Ok(vec![])
+    }
+
+    async fn count_by_session_id(&self, _session_id: &SessionId) -> Result<u32, DomainError> {
+        Ok(0)
+    }
+
+    async fn delete(&self, _id: &CycleId) -> Result<(), DomainError> {
+        Ok(())
+    }
+}
+
+#[derive(Default)]
+pub struct MockSessionRepository {
+    sessions: Mutex<Vec<Session>>,
+}
+
+#[async_trait]
+impl SessionRepository for MockSessionRepository {
+    async fn save(&self, session: &Session) -> Result<(), DomainError> {
+        self.sessions.lock().unwrap().push(session.clone());
+        Ok(())
+    }
+
+    async fn update(&self, _session: &Session) -> Result<(), DomainError> {
+        Ok(())
+    }
+
+    async fn find_by_id(&self, id: &SessionId) -> Result<Option<Session>, DomainError> {
+        Ok(self
+            .sessions
+            .lock()
+            .unwrap()
+            .iter()
+            .find(|s| s.id() == id)
+            .cloned())
+    }
+
+    async fn exists(&self, id: &SessionId) -> Result<bool, DomainError> {
+        Ok(self.sessions.lock().unwrap().iter().any(|s| s.id() == id))
+    }
+
+    async fn find_by_user_id(&self, _user_id: &UserId) -> Result<Vec<Session>, DomainError> {
+        Ok(vec![])
+    }
+
+    async fn count_active_by_user(&self, _user_id: &UserId) -> Result<u32, DomainError> {
+        Ok(0)
+    }
+
+    async fn delete(&self, _id: &SessionId) -> Result<(), DomainError> {
+        Ok(())
+    }
+}
+
+/// Every component has one conversation holding the same user messages.
+pub struct MockConversationReader {
+    user_messages: Vec<String>,
+}
+
+impl MockConversationReader {
+    pub fn with_user_messages(messages: &[&str]) -> Self {
+        Self {
+            user_messages: messages.iter().map(|m| m.to_string()).collect(),
+        }
+    }
+}
+
+#[async_trait]
+impl ConversationReader for MockConversationReader {
+    async fn get(&self, _id: &ConversationId) -> Result<Option<ConversationView>, DomainError> {
+        Ok(None)
+    }
+
+    async fn get_by_component(
+        &self,
+        component_id: &ComponentId,
+    ) -> Result<Option<ConversationView>, DomainError> {
+        Ok(Some(ConversationView {
+            id: ConversationId::new(),
+            component_id: *component_id,
+            state: ConversationState::Ready,
+            message_count: self.user_messages.len() as u32 * 2,
+            created_at: Timestamp::now(),
+            updated_at: Timestamp::now(),
+        }))
+    }
+
+    async fn get_messages(
+        &self,
+        _conversation_id: &ConversationId,
+        _options: &MessageListOptions,
+    ) -> Result<MessageList, DomainError> {
+        let items: Vec<_> = self
+            .user_messages
+            .iter()
+            .flat_map(|content| {
+                [
+                    MessageView {
+                        id: "user".to_string(),
+                        role: Role::User,
+                        content: content.clone(),
+                        created_at: Timestamp::now(),
+                    },
+                    MessageView {
+                        id: "assistant".to_string(),
+                        role: Role::Assistant,
+                        content: "Tell me more about that.".to_string(),
+                        created_at: Timestamp::now(),
+                    },
+                ]
+            })
+            .collect();
+        Ok(MessageList {
+            total: items.len() as u64,
+            items,
+            has_more: false,
+        })
+    }
+}
+
+/// Hashing embeddings, counting how many times the provider was called.
+pub struct CountingEmbeddingProvider {
+    inner: HashingEmbeddingProvider,
+    calls: AtomicUsize,
+}
+
+impl CountingEmbeddingProvider {
+    pub fn new(inner: HashingEmbeddingProvider) -> Self {
+        Self {
+            inner,
+            calls: AtomicUsize::new(0),
+        }
+    }
+
+    pub fn calls(&self) -> usize {
+        self.calls.load(Ordering::SeqCst)
+    }
+}
+
+#[async_trait]
+impl EmbeddingProvider for CountingEmbeddingProvider {
+    async fn embed(&self, inputs: &[String]) -> Result<Vec<Embedding>, AIError> {
+        self.calls.fetch_add(1, Ordering::SeqCst);
+        self.inner.embed(inputs).await
+    }
+
+    fn model(&self) -> &str {
+        self.inner.model()
+    }
+}
+
+pub fn owner() -> UserId {
+    UserId::new("owner-1").unwrap()
+}
+
+pub fn stranger() -> UserId {
+    UserId::new("stranger-1").unwrap()
+}
+
+/// An issue raising output listing `decisions`.
+pub fn issue_output(decisions: &[&str]) -> JsonValue {
+    json!({
+        "potential_decisions": decisions,
+        "objectives": [],
+        "uncertainties": [],
+        "considerations": [],
+        "user_confirmed": false
+    })
+}
+
+/// Adds a session owned by `user` with one cycle whose issue raising
+/// output lists `decisions`.
+pub async fn add_decision(
+    cycles: &MockCycleRepository,
+    sessions: &MockSessionRepository,
+    user: UserId,
+    title: &str,
+    decisions: &[&str],
+) -> CycleId {
+    let session = Session::new(SessionId::new(), user, title.to_string()).unwrap();
+    let mut cycle = Cycle::new(*session.id());
+    cycle.start_component(ComponentType::IssueRaising).unwrap();
+    cycle
+        .update_component_output(ComponentType::IssueRaising, issue_output(decisions))
+        .unwrap();
+    let cycle_id = cycle.id();
+    sessions.save(&session).await.unwrap();
+    cycles.save(&cycle).await.unwrap();
+    cycle_id
+}
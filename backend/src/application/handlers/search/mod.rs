@@ -0,0 +1,17 @@
+//! Semantic search handlers.
+//!
+//! - `DecisionEmbeddingIndexer` - Embeds component outputs and conversation
+//!   summaries as components change
+//! - `FindSimilarDecisionsHandler` - Finds a user's past decisions resembling
+//!   a cycle or a piece of text
+
+mod decision_embedding_indexer;
+mod find_similar_decisions;
+#[cfg(test)]
+pub(crate) mod fixtures;
+
+pub use decision_embedding_indexer::{DecisionEmbeddingIndexer, DECISION_EMBEDDING_EVENTS};
+pub use find_similar_decisions::{
+    FindSimilarDecisionsError, FindSimilarDecisionsHandler, FindSimilarDecisionsQuery,
+    SimilarDecisionView, SimilarTo, DEFAULT_SIMILAR_DECISIONS_LIMIT, MAX_SIMILAR_DECISIONS_LIMIT,
+};
@@ -2,6 +2,8 @@
 
 use std::sync::Arc;
 
+use crate::application::handlers::audit::CommandAuditor;
+use crate::domain::audit::{AuditRecord, AuditSummary};
 use crate::domain::foundation::{
     CommandMetadata, EventId, SerializableDomainEvent, SessionId, Timestamp, UserId,
 };
@@ -26,6 +28,7 @@ pub struct ArchiveSessionResult {
 pub struct ArchiveSessionHandler {
     repository: Arc<dyn SessionRepository>,
     event_publisher: Arc<dyn EventPublisher>,
+    auditor: CommandAuditor,
 }
 
 impl ArchiveSessionHandler {
@@ -36,9 +39,17 @@ impl ArchiveSessionHandler {
         Self {
             repository,
             event_publisher,
+            auditor: CommandAuditor::disabled(),
         }
     }
 
+    /// Records each command in the audit log.
+    pub fn with_auditor(mut self, auditor: CommandAuditor) -> Self {
+        self.auditor = auditor;
+        self
+    }
+
+    #[tracing::instrument(name = "ArchiveSessionHandler::handle", skip_all)]
     pub async fn handle(
         &self,
         cmd: ArchiveSessionCommand,
@@ -55,6 +66,7 @@ impl ArchiveSessionHandler {
         session.authorize(&cmd.user_id)?;
 
         // 3. Archive
+        let before = session.audit_summary();
         session.archive()?;
 
         // 4. Persist
@@ -65,6 +77,7 @@ impl ArchiveSessionHandler {
             event_id: EventId::new(),
             session_id: cmd.session_id,
             user_id: cmd.user_id,
+            automatic: false,
             archived_at: Timestamp::now(),
         };
 
@@ -75,6 +88,14 @@ impl ArchiveSessionHandler {
 
         self.event_publisher.publish(envelope).await?;
 
+        self.auditor
+            .record(
+                AuditRecord::new(&metadata, "ArchiveSession", "session", cmd.session_id)
+                    .with_before(before)
+                    .with_after(session.audit_summary()),
+            )
+            .await;
+
         Ok(ArchiveSessionResult { session, event })
     }
 }
@@ -83,6 +104,7 @@ impl ArchiveSessionHandler {
 mod tests {
     use super::*;
     use crate::domain::foundation::{DomainError, EventEnvelope, SessionStatus};
+    use crate::test_support::SessionBuilder;
     use async_trait::async_trait;
     use std::sync::Mutex;
 
@@ -191,7 +213,13 @@ mod tests {
     }
 
     fn test_session() -> Session {
-        Session::new(SessionId::new(), test_user_id(), "Test Session".to_string()).unwrap()
+        session_builder().build()
+    }
+
+    fn session_builder() -> SessionBuilder {
+        SessionBuilder::new()
+            .user_id(test_user_id())
+            .title("Test Session")
     }
 
     fn test_metadata() -> CommandMetadata {
@@ -280,8 +308,7 @@ mod tests {
 
     #[tokio::test]
     async fn fails_when_already_archived() {
-        let mut session = test_session();
-        session.archive().unwrap();
+        let session = session_builder().archived().build();
         let session_id = *session.id();
         let repo = Arc::new(MockSessionRepository::with_session(session));
         let publisher = Arc::new(MockEventPublisher::new());
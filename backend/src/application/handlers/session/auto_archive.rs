@@ -0,0 +1,303 @@
+//! Auto-archive settings handlers - Per-session exclusion from the
+//! archival policy.
+//!
+//! Changing the setting also records the owner's email address, which is
+//! where `SessionArchiver` sends warnings for the session.
+
+use std::sync::Arc;
+
+use crate::application::handlers::audit::CommandAuditor;
+use crate::domain::audit::AuditRecord;
+use crate::domain::foundation::{
+    CommandMetadata, EventId, SerializableDomainEvent, SessionId, Timestamp, UserId,
+};
+use crate::domain::session::{
+    SessionArchivalSettings, SessionAutoArchiveExclusionChanged, SessionError,
+};
+use crate::ports::{EventPublisher, SessionArchivalRepository, SessionRepository};
+
+/// Query for a session's auto-archive settings.
+#[derive(Debug, Clone)]
+pub struct GetAutoArchiveSettingsQuery {
+    pub session_id: SessionId,
+    pub user_id: UserId,
+}
+
+/// Handler for auto-archive settings queries.
+pub struct GetAutoArchiveSettingsHandler {
+    sessions: Arc<dyn SessionRepository>,
+    archival: Arc<dyn SessionArchivalRepository>,
+}
+
+impl GetAutoArchiveSettingsHandler {
+    pub fn new(
+        sessions: Arc<dyn SessionRepository>,
+        archival: Arc<dyn SessionArchivalRepository>,
+    ) -> Self {
+        Self { sessions, archival }
+    }
+
+    /// The session's settings; the defaults if it has none yet.
+    #[tracing::instrument(name = "GetAutoArchiveSettingsHandler::handle", skip_all)]
+    pub async fn handle(
+        &self,
+        query: GetAutoArchiveSettingsQuery,
+    ) -> Result<SessionArchivalSettings, SessionError> {
+        load_settings(
+            self.sessions.as_ref(),
+            self.archival.as_ref(),
+            &query.session_id,
+            &query.user_id,
+        )
+        .await
+    }
+}
+
+/// Command to exclude a session from auto-archive, or include it again.
+#[derive(Debug, Clone)]
+pub struct SetAutoArchiveExclusionCommand {
+    pub session_id: SessionId,
+    pub user_id: UserId,
+    pub excluded: bool,
+    /// The owner's current address, for archive warnings.
+    pub notify_email: Option<String>,
+}
+
+/// Handler for auto-archive exclusion changes.
+pub struct SetAutoArchiveExclusionHandler {
+    sessions: Arc<dyn SessionRepository>,
+    archival: Arc<dyn SessionArchivalRepository>,
+    event_publisher: Arc<dyn EventPublisher>,
+    auditor: CommandAuditor,
+}
+
+impl SetAutoArchiveExclusionHandler {
+    pub fn new(
+        sessions: Arc<dyn SessionRepository>,
+        archival: Arc<dyn SessionArchivalRepository>,
+        event_publisher: Arc<dyn EventPublisher>,
+    ) -> Self {
+        Self {
+            sessions,
+            archival,
+            event_publisher,
+            auditor: CommandAuditor::disabled(),
+        }
+    }
+
+    /// Records each command in the audit log.
+    pub fn with_auditor(mut self, auditor: CommandAuditor) -> Self {
+        self.auditor = auditor;
+        self
+    }
+
+    /// Publishes `SessionAutoArchiveExclusionChanged` only when the
+    /// exclusion actually changes.
+    #[tracing::instrument(name = "SetAutoArchiveExclusionHandler::handle", skip_all)]
+    pub async fn handle(
+        &self,
+        cmd: SetAutoArchiveExclusionCommand,
+        metadata: CommandMetadata,
+    ) -> Result<SessionArchivalSettings, SessionError> {
+        let mut settings = load_settings(
+            self.sessions.as_ref(),
+            self.archival.as_ref(),
+            &cmd.session_id,
+            &cmd.user_id,
+        )
+        .await?;
+        let before = settings.excluded;
+        let changed = before != cmd.excluded;
+        settings.excluded = cmd.excluded;
+        if let Some(email) = cmd.notify_email.filter(|e| !e.trim().is_empty()) {
+            settings.notify_email = Some(email);
+        }
+        self.archival.save_settings(&settings).await?;
+
+        if changed {
+            let event = SessionAutoArchiveExclusionChanged {
+                event_id: EventId::new(),
+                session_id: cmd.session_id,
+                user_id: cmd.user_id,
+                excluded: cmd.excluded,
+                changed_at: Timestamp::now(),
+            };
+            let envelope = event
+                .to_envelope()
+                .with_correlation_id(metadata.correlation_id())
+                .with_user_id(metadata.user_id.to_string());
+            self.event_publisher.publish(envelope).await?;
+        }
+
+        self.auditor
+            .record(
+                AuditRecord::new(
+                    &metadata,
+                    "SetAutoArchiveExclusion",
+                    "session",
+                    cmd.session_id,
+                )
+                .with_before(serde_json::json!({ "excluded": before }))
+                .with_after(serde_json::json!({ "excluded": settings.excluded })),
+            )
+            .await;
+
+        Ok(settings)
+    }
+}
+
+async fn load_settings(
+    sessions: &dyn SessionRepository,
+    archival: &dyn SessionArchivalRepository,
+    session_id: &SessionId,
+    user_id: &UserId,
+) -> Result<SessionArchivalSettings, SessionError> {
+    let session = sessions
+        .find_by_id(session_id)
+        .await?
+        .ok_or_else(|| SessionError::not_found(*session_id))?;
+    session.authorize(user_id)?;
+
+    Ok(archival
+        .find_settings(session_id)
+        .await?
+        .unwrap_or_else(|| SessionArchivalSettings::new(*session_id, session.user_id().clone())))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use async_trait::async_trait;
+
+    use crate::adapters::{InMemoryEventBus, InMemorySessionArchivalRepository};
+    use crate::domain::foundation::DomainError;
+    use crate::domain::session::Session;
+
+    struct SingleSessionRepository(Session);
+
+    #[async_trait]
+    impl SessionRepository for SingleSessionRepository {
+        async fn save(&self, _session: &Session) -> Result<(), DomainError> {
+            Ok(())
+        }
+
+        async fn update(&self, _session: &Session) -> Result<(), DomainError> {
+            Ok(())
+        }
+
+        async fn find_by_id(&self, id: &SessionId) -> Result<Option<Session>, DomainError> {
+            Ok((self.0.id() == id).then(|| self.0.clone()))
+        }
+
+        async fn exists(&self, id: &SessionId) -> Result<bool, DomainError> {
+            Ok(self.0.id() == id)
+        }
+
+        async fn find_by_user_id(&self, _user_id: &UserId) -> Result<Vec<Session>, DomainError> {
+            Ok(vec![self.0.clone()])
+        }
+
+        async fn count_active_by_user(&self, _user_id: &UserId) -> Result<u32, DomainError> {
+            Ok(1)
+        }
+
+        async fn delete(&self, _id: &SessionId) -> Result<(), DomainError> {
+            Ok(())
+        }
+    }
+
+    fn owner() -> UserId {
+        UserId::new("owner").unwrap()
+    }
+
+    fn setup_repositories() -> (
+        Arc<SingleSessionRepository>,
+        Arc<InMemorySessionArchivalRepository>,
+        SessionId,
+    ) {
+        let session = Session::new(SessionId::new(), owner(), "Car".to_string()).unwrap();
+        let session_id = *session.id();
+        (
+            Arc::new(SingleSessionRepository(session)),
+            Arc::new(InMemorySessionArchivalRepository::new()),
+            session_id,
+        )
+    }
+
+    fn create_handler(
+        sessions: Arc<SingleSessionRepository>,
+        archival: Arc<InMemorySessionArchivalRepository>,
+        events: Arc<InMemoryEventBus>,
+    ) -> SetAutoArchiveExclusionHandler {
+        SetAutoArchiveExclusionHandler::new(sessions, archival, events)
+    }
+
+    async fn set(
+        handler: &SetAutoArchiveExclusionHandler,
+        session_id: SessionId,
+        user_id: UserId,
+        excluded: bool,
+    ) -> Result<SessionArchivalSettings, SessionError> {
+        handler
+            .handle(
+                SetAutoArchiveExclusionCommand {
+                    session_id,
+                    user_id: user_id.clone(),
+                    excluded,
+                    notify_email: Some("owner@example.com".to_string()),
+                },
+                CommandMetadata::new(user_id),
+            )
+            .await
+    }
+
+    #[tokio::test]
+    async fn defaults_to_included() {
+        let (sessions, archival, session_id) = setup_repositories();
+
+        let settings = GetAutoArchiveSettingsHandler::new(sessions, archival)
+            .handle(GetAutoArchiveSettingsQuery {
+                session_id,
+                user_id: owner(),
+            })
+            .await
+            .unwrap();
+
+        assert!(!settings.excluded);
+        assert!(settings.notify_email.is_none());
+    }
+
+    #[tokio::test]
+    async fn excluding_records_email_and_publishes_once() {
+        let (sessions, archival, session_id) = setup_repositories();
+        let events = Arc::new(InMemoryEventBus::new());
+        let handler = create_handler(sessions, archival.clone(), events.clone());
+
+        let settings = set(&handler, session_id, owner(), true).await.unwrap();
+        set(&handler, session_id, owner(), true).await.unwrap();
+
+        assert!(settings.excluded);
+        assert_eq!(settings.notify_email.as_deref(), Some("owner@example.com"));
+        let stored = archival.find_settings(&session_id).await.unwrap();
+        assert_eq!(stored, Some(settings));
+        let events = events.events_of_type("session.auto_archive_exclusion_changed.v1");
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].payload["excluded"], true);
+    }
+
+    #[tokio::test]
+    async fn only_the_owner_can_change_it() {
+        let (sessions, archival, session_id) = setup_repositories();
+        let handler = create_handler(sessions, archival, Arc::new(InMemoryEventBus::new()));
+
+        let result = set(
+            &handler,
+            session_id,
+            UserId::new("someone-else").unwrap(),
+            true,
+        )
+        .await;
+
+        assert!(matches!(result, Err(SessionError::Forbidden)));
+    }
+}
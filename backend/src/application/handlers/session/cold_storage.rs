@@ -0,0 +1,443 @@
+//! SessionColdStorage - Moves finished sessions' cycles to and from the
+//! document store.
+//!
+//! Offloading writes a `ColdSessionSnapshot`, reads it back to check it,
+//! records where it lives and only then deletes the cycles, so a crash at any
+//! point leaves the cycles readable from one place or the other. Rehydrating
+//! restores any cycle that is missing, then drops the record and the
+//! snapshot. Both are safe to repeat.
+//!
+//! `ColdStorageOffloader` is the background job that offloads sessions the
+//! `ColdStoragePolicy` considers due.
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use tokio::sync::{watch, Mutex};
+use tokio::time;
+
+use crate::domain::foundation::{CycleId, DomainError, ErrorCode, SessionId, Timestamp, UserId};
+use crate::domain::session::{ColdSessionSnapshot, ColdStoragePolicy, ColdStorageRecord};
+use crate::ports::{
+    CycleRepository, DocumentStorage, DocumentStorageError, SessionColdStorageRepository,
+};
+
+/// Content type snapshots are stored with.
+const SNAPSHOT_CONTENT_TYPE: &str = "application/json";
+
+/// Sessions offloaded per poll.
+const OFFLOAD_BATCH_SIZE: u32 = 50;
+
+/// Offloads and rehydrates sessions.
+pub struct SessionColdStorage {
+    records: Arc<dyn SessionColdStorageRepository>,
+    /// The database repository itself, never a rehydrating wrapper.
+    cycles: Arc<dyn CycleRepository>,
+    storage: Arc<dyn DocumentStorage>,
+    /// Keeps concurrent requests for one cold session from restoring it twice.
+    rehydrating: Mutex<()>,
+}
+
+impl SessionColdStorage {
+    pub fn new(
+        records: Arc<dyn SessionColdStorageRepository>,
+        cycles: Arc<dyn CycleRepository>,
+        storage: Arc<dyn DocumentStorage>,
+    ) -> Self {
+        Self {
+            records,
+            cycles,
+            storage,
+            rehydrating: Mutex::new(()),
+        }
+    }
+
+    /// Moves a session's cycles to the document store. Returns `false`,
+    /// doing nothing, when the session has no cycles or one is still active.
+    #[tracing::instrument(name = "SessionColdStorage::offload", skip_all, fields(session_id = %session_id))]
+    pub async fn offload(
+        &self,
+        session_id: &SessionId,
+        user_id: &UserId,
+    ) -> Result<bool, DomainError> {
+        let cycles = self.cycles.find_by_session_id(session_id).await?;
+        if cycles.is_empty() || cycles.iter().any(|c| c.status().is_mutable()) {
+            return Ok(false);
+        }
+
+        let snapshot = ColdSessionSnapshot::new(*session_id, &cycles);
+        let record = ColdStorageRecord::new(&snapshot, user_id.clone());
+        let bytes = serde_json::to_vec(&snapshot)
+            .map_err(|e| DomainError::new(ErrorCode::InternalError, e.to_string()))?;
+        self.storage
+            .put(&record.blob_key, SNAPSHOT_CONTENT_TYPE, bytes)
+            .await
+            .map_err(storage_error)?;
+        if self.load(&record).await? != snapshot {
+            return Err(DomainError::new(
+                ErrorCode::ExternalServiceError,
+                format!(
+                    "Cold storage snapshot {} did not read back intact",
+                    record.blob_key
+                ),
+            ));
+        }
+        self.records.save(&record).await?;
+
+        // Branches first; deleting a parent would take them with it anyway
+        for cycle in snapshot.cycles.iter().rev() {
+            match self.cycles.delete(&cycle.id).await {
+                Ok(()) => {}
+                Err(e) if e.code == ErrorCode::CycleNotFound => {}
+                Err(e) => return Err(e),
+            }
+        }
+        tracing::info!(
+            cycles = record.cycle_ids.len(),
+            "Session moved to cold storage"
+        );
+        Ok(true)
+    }
+
+    /// Brings a cold session's cycles back. Returns whether it was cold.
+    pub async fn rehydrate(&self, session_id: &SessionId) -> Result<bool, DomainError> {
+        if self.records.find(session_id).await?.is_none() {
+            return Ok(false);
+        }
+        let _guard = self.rehydrating.lock().await;
+        match self.records.find(session_id).await? {
+            Some(record) => self.restore(record).await.map(|()| true),
+            // Another request restored it while this one waited
+            None => Ok(true),
+        }
+    }
+
+    /// Brings back the cold session holding `cycle_id`, if there is one.
+    pub async fn rehydrate_cycle(&self, cycle_id: &CycleId) -> Result<bool, DomainError> {
+        match self.records.find_by_cycle(cycle_id).await? {
+            Some(record) => self.rehydrate(&record.session_id).await,
+            None => Ok(false),
+        }
+    }
+
+    /// Drops a cold session's record and snapshot without restoring its
+    /// cycles, for sessions being purged. Returns whether it was cold.
+    #[tracing::instrument(name = "SessionColdStorage::discard", skip_all, fields(session_id = %session_id))]
+    pub async fn discard(&self, session_id: &SessionId) -> Result<bool, DomainError> {
+        let Some(record) = self.records.find(session_id).await? else {
+            return Ok(false);
+        };
+        match self.storage.delete(&record.blob_key).await {
+            Ok(()) | Err(DocumentStorageError::NotFound(_)) => {}
+            Err(e) => return Err(storage_error(e)),
+        }
+        self.records.delete(session_id).await?;
+        Ok(true)
+    }
+
+    #[tracing::instrument(name = "SessionColdStorage::restore", skip_all, fields(session_id = %record.session_id))]
+    async fn restore(&self, record: ColdStorageRecord) -> Result<(), DomainError> {
+        let snapshot = self.load(&record).await?;
+        for cycle in snapshot.into_cycles()? {
+            if !self.cycles.exists(&cycle.id()).await? {
+                self.cycles.save(&cycle).await?;
+            }
+        }
+        self.records.delete(&record.session_id).await?;
+        match self.storage.delete(&record.blob_key).await {
+            Ok(()) | Err(DocumentStorageError::NotFound(_)) => {}
+            // The cycles are back; an orphaned snapshot is only wasted space
+            Err(e) => tracing::warn!(error = %e, "Failed to delete cold storage snapshot"),
+        }
+        tracing::info!("Session rehydrated from cold storage");
+        Ok(())
+    }
+
+    async fn load(&self, record: &ColdStorageRecord) -> Result<ColdSessionSnapshot, DomainError> {
+        let stored = self
+            .storage
+            .get(&record.blob_key)
+            .await
+            .map_err(storage_error)?;
+        serde_json::from_slice(&stored.bytes).map_err(|e| {
+            DomainError::new(
+                ErrorCode::InternalError,
+                format!("Invalid cold storage snapshot {}: {}", record.blob_key, e),
+            )
+        })
+    }
+}
+
+fn storage_error(err: DocumentStorageError) -> DomainError {
+    DomainError::new(ErrorCode::ExternalServiceError, err.to_string())
+}
+
+/// Offloads finished sessions once the policy considers them due.
+pub struct ColdStorageOffloader {
+    policy: ColdStoragePolicy,
+    records: Arc<dyn SessionColdStorageRepository>,
+    cold_storage: Arc<SessionColdStorage>,
+}
+
+impl ColdStorageOffloader {
+    pub fn new(
+        policy: ColdStoragePolicy,
+        records: Arc<dyn SessionColdStorageRepository>,
+        cold_storage: Arc<SessionColdStorage>,
+    ) -> Self {
+        Self {
+            policy,
+            records,
+            cold_storage,
+        }
+    }
+
+    /// Offloads due sessions every `poll_interval` until shutdown is
+    /// signalled. Returns immediately if the policy offloads nothing.
+    pub async fn run(
+        &self,
+        poll_interval: Duration,
+        mut shutdown: watch::Receiver<bool>,
+    ) -> Result<(), DomainError> {
+        if !self.policy.is_enabled() {
+            return Ok(());
+        }
+        let mut interval = time::interval(poll_interval);
+
+        loop {
+            tokio::select! {
+                _ = shutdown.changed() => {
+                    if *shutdown.borrow() {
+                        return Ok(());
+                    }
+                }
+
+                _ = interval.tick() => {
+                    self.process_due(OFFLOAD_BATCH_SIZE).await?;
+                }
+            }
+        }
+    }
+
+    /// Offloads up to `limit` due sessions, returning how many moved. A
+    /// session that fails is logged and retried on the next pass.
+    #[tracing::instrument(name = "ColdStorageOffloader::process_due", skip_all)]
+    pub async fn process_due(&self, limit: u32) -> Result<usize, DomainError> {
+        let archived_before = self.policy.archived_before(Timestamp::now());
+        let due = self.records.list_candidates(archived_before, limit).await?;
+        let mut offloaded = 0;
+        for candidate in due {
+            match self
+                .cold_storage
+                .offload(&candidate.session_id, &candidate.user_id)
+                .await
+            {
+                Ok(true) => offloaded += 1,
+                Ok(false) => {}
+                Err(error) => tracing::warn!(
+                    session_id = %candidate.session_id,
+                    error = %error,
+                    "Failed to move session to cold storage"
+                ),
+            }
+        }
+        Ok(offloaded)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use async_trait::async_trait;
+    use std::sync::Mutex as StdMutex;
+
+    use crate::adapters::storage::InMemoryDocumentStorage;
+    use crate::adapters::InMemorySessionColdStorageRepository;
+    use crate::application::handlers::session::RehydratingCycleRepository;
+    use crate::domain::cycle::Cycle;
+    use crate::domain::foundation::ComponentType;
+
+    #[derive(Default)]
+    struct InMemoryCycles {
+        cycles: StdMutex<Vec<Cycle>>,
+    }
+
+    impl InMemoryCycles {
+        fn ids(&self) -> Vec<CycleId> {
+            self.cycles.lock().unwrap().iter().map(|c| c.id()).collect()
+        }
+    }
+
+    #[async_trait]
+    impl CycleRepository for InMemoryCycles {
+        async fn save(&self, cycle: &Cycle) -> Result<(), DomainError> {
+            self.cycles.lock().unwrap().push(cycle.clone());
+            Ok(())
+        }
+
+        async fn update(&self, _cycle: &Cycle) -> Result<(), DomainError> {
+            Ok(())
+        }
+
+        async fn find_by_id(&self, id: &CycleId) -> Result<Option<Cycle>, DomainError> {
+            Ok(self
+                .cycles
+                .lock()
+                .unwrap()
+                .iter()
+                .find(|c| c.id() == *id)
+                .cloned())
+        }
+
+        async fn exists(&self, id: &CycleId) -> Result<bool, DomainError> {
+            Ok(self.find_by_id(id).await?.is_some())
+        }
+
+        async fn find_by_session_id(
+            &self,
+            session_id: &SessionId,
+        ) -> Result<Vec<Cycle>, DomainError> {
+            Ok(self
+                .cycles
+                .lock()
+                .unwrap()
+                .iter()
+                .filter(|c| c.session_id() == *session_id)
+                .cloned()
+                .collect())
+        }
+
+        async fn find_primary_by_session_id(
+            &self,
+            session_id: &SessionId,
+        ) -> Result<Option<Cycle>, DomainError> {
+            Ok(self
+                .find_by_session_id(session_id)
+                .await?
+                .into_iter()
+                .find(|c| !c.is_branch()))
+        }
+
+        async fn find_branches(&self, _parent_id: &CycleId) -> Result<Vec<Cycle>, DomainError> {
+            Ok(vec![])
+        }
+
+        async fn count_by_session_id(&self, session_id: &SessionId) -> Result<u32, DomainError> {
+            Ok(self.find_by_session_id(session_id).await?.len() as u32)
+        }
+
+        async fn delete(&self, id: &CycleId) -> Result<(), DomainError> {
+            let mut cycles = self.cycles.lock().unwrap();
+            let before = cycles.len();
+            cycles.retain(|c| c.id() != *id);
+            if cycles.len() == before {
+                return Err(DomainError::new(
+                    ErrorCode::CycleNotFound,
+                    "Cycle not found",
+                ));
+            }
+            Ok(())
+        }
+    }
+
+    fn setup_repositories() -> (
+        Arc<InMemorySessionColdStorageRepository>,
+        Arc<InMemoryCycles>,
+        Arc<InMemoryDocumentStorage>,
+    ) {
+        (
+            Arc::new(InMemorySessionColdStorageRepository::new()),
+            Arc::new(InMemoryCycles::default()),
+            Arc::new(InMemoryDocumentStorage::new()),
+        )
+    }
+
+    fn create_handler(
+        records: Arc<InMemorySessionColdStorageRepository>,
+        cycles: Arc<InMemoryCycles>,
+        storage: Arc<InMemoryDocumentStorage>,
+    ) -> Arc<SessionColdStorage> {
+        Arc::new(SessionColdStorage::new(records, cycles, storage))
+    }
+
+    fn user() -> UserId {
+        UserId::new("user-1").unwrap()
+    }
+
+    /// An archived root cycle with an archived branch.
+    async fn finished_session(cycles: &InMemoryCycles) -> (SessionId, Vec<CycleId>) {
+        let session_id = SessionId::new();
+        let mut root = Cycle::new(session_id);
+        root.start_component(ComponentType::IssueRaising).unwrap();
+        let mut branch = root.branch_at(ComponentType::IssueRaising, None).unwrap();
+        root.archive().unwrap();
+        branch.archive().unwrap();
+        cycles.save(&root).await.unwrap();
+        cycles.save(&branch).await.unwrap();
+        (session_id, vec![root.id(), branch.id()])
+    }
+
+    #[tokio::test]
+    async fn offloads_cycles_and_rehydrates_them_on_access() {
+        let (records, cycles, storage) = setup_repositories();
+        let cold_storage = create_handler(records.clone(), cycles.clone(), storage.clone());
+        let (session_id, cycle_ids) = finished_session(&cycles).await;
+
+        assert!(cold_storage.offload(&session_id, &user()).await.unwrap());
+
+        assert!(cycles.ids().is_empty());
+        let record = records.find(&session_id).await.unwrap().unwrap();
+        assert_eq!(record.cycle_ids, cycle_ids);
+        assert!(storage.exists(&record.blob_key).await.unwrap());
+
+        let repository = RehydratingCycleRepository::new(cycles.clone(), cold_storage.clone());
+        let branch = repository.find_by_id(&cycle_ids[1]).await.unwrap().unwrap();
+
+        assert_eq!(branch.parent_cycle_id(), Some(cycle_ids[0]));
+        assert_eq!(cycles.ids(), cycle_ids);
+        assert!(records.find(&session_id).await.unwrap().is_none());
+        assert!(!storage.exists(&record.blob_key).await.unwrap());
+        assert_eq!(
+            repository.count_by_session_id(&session_id).await.unwrap(),
+            2
+        );
+    }
+
+    #[tokio::test]
+    async fn leaves_sessions_with_an_active_cycle_alone() {
+        let (records, cycles, storage) = setup_repositories();
+        let cold_storage = create_handler(records.clone(), cycles.clone(), storage);
+        let session_id = SessionId::new();
+        cycles.save(&Cycle::new(session_id)).await.unwrap();
+
+        assert!(!cold_storage.offload(&session_id, &user()).await.unwrap());
+        assert_eq!(cycles.ids().len(), 1);
+        assert!(records.find(&session_id).await.unwrap().is_none());
+    }
+
+    #[tokio::test]
+    async fn offloader_moves_sessions_archived_long_enough_ago() {
+        let (records, cycles, storage) = setup_repositories();
+        let cold_storage = create_handler(records.clone(), cycles.clone(), storage);
+        let now = Timestamp::now();
+        let (old, _) = finished_session(&cycles).await;
+        let (recent, _) = finished_session(&cycles).await;
+        records
+            .track_finished(old, user(), now.minus_days(400))
+            .await;
+        records
+            .track_finished(recent, user(), now.minus_days(30))
+            .await;
+        let offloader = ColdStorageOffloader::new(
+            ColdStoragePolicy::after_months(12),
+            records.clone(),
+            cold_storage.clone(),
+        );
+
+        assert_eq!(offloader.process_due(10).await.unwrap(), 1);
+
+        assert!(records.find(&old).await.unwrap().is_some());
+        assert!(records.find(&recent).await.unwrap().is_none());
+        assert_eq!(cycles.ids().len(), 2);
+    }
+}
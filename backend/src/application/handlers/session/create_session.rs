@@ -2,6 +2,8 @@
 
 use std::sync::Arc;
 
+use crate::application::handlers::audit::CommandAuditor;
+use crate::domain::audit::{AuditRecord, AuditSummary};
 use crate::domain::foundation::{
     CommandMetadata, EventId, SerializableDomainEvent, SessionId, UserId,
 };
@@ -28,6 +30,7 @@ pub struct CreateSessionHandler {
     repository: Arc<dyn SessionRepository>,
     access_checker: Arc<dyn AccessChecker>,
     event_publisher: Arc<dyn EventPublisher>,
+    auditor: CommandAuditor,
 }
 
 impl CreateSessionHandler {
@@ -40,9 +43,17 @@ impl CreateSessionHandler {
             repository,
             access_checker,
             event_publisher,
+            auditor: CommandAuditor::disabled(),
         }
     }
 
+    /// Records each command in the audit log.
+    pub fn with_auditor(mut self, auditor: CommandAuditor) -> Self {
+        self.auditor = auditor;
+        self
+    }
+
+    #[tracing::instrument(name = "CreateSessionHandler::handle", skip_all)]
     pub async fn handle(
         &self,
         cmd: CreateSessionCommand,
@@ -84,6 +95,13 @@ impl CreateSessionHandler {
 
         self.event_publisher.publish(envelope).await?;
 
+        self.auditor
+            .record(
+                AuditRecord::new(&metadata, "CreateSession", "session", session.id())
+                    .with_after(session.audit_summary()),
+            )
+            .await;
+
         Ok(CreateSessionResult { session, event })
     }
 }
@@ -23,6 +23,7 @@ impl GetSessionHandler {
         Self { reader }
     }
 
+    #[tracing::instrument(name = "GetSessionHandler::handle", skip_all)]
     pub async fn handle(&self, query: GetSessionQuery) -> Result<SessionView, SessionError> {
         // Fetch the session
         let session = self
@@ -43,8 +44,10 @@ impl GetSessionHandler {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::domain::foundation::{DomainError, ErrorCode, SessionStatus, Timestamp};
-    use crate::ports::{ListOptions, SessionList, SessionSummary};
+    use crate::domain::foundation::{
+        DomainError, ErrorCode, OrganizationId, SessionStatus, Timestamp,
+    };
+    use crate::ports::{ListOptions, SessionList, SessionSummary, TrashedSession};
     use async_trait::async_trait;
 
     struct MockSessionReader {
@@ -81,6 +84,18 @@ mod tests {
             })
         }
 
+        async fn list_by_organization(
+            &self,
+            _organization_id: &OrganizationId,
+            _options: &ListOptions,
+        ) -> Result<SessionList, DomainError> {
+            Ok(SessionList {
+                items: vec![],
+                total: 0,
+                has_more: false,
+            })
+        }
+
         async fn search(
             &self,
             _user_id: &UserId,
@@ -101,6 +116,18 @@ mod tests {
         ) -> Result<u64, DomainError> {
             Ok(0)
         }
+
+        async fn list_trashed(&self, _user_id: &UserId) -> Result<Vec<TrashedSession>, DomainError> {
+            Ok(vec![])
+        }
+
+        async fn list_purge_candidates(
+            &self,
+            _deleted_before: Timestamp,
+            _limit: u32,
+        ) -> Result<Vec<TrashedSession>, DomainError> {
+            Ok(vec![])
+        }
     }
 
     fn test_user_id() -> UserId {
@@ -111,6 +138,7 @@ mod tests {
         SessionView {
             id: SessionId::new(),
             user_id,
+            organization_id: None,
             title: "Test Session".to_string(),
             description: None,
             status: SessionStatus::Active,
@@ -68,6 +68,7 @@ impl ListUserSessionsHandler {
         Self { reader }
     }
 
+    #[tracing::instrument(name = "ListUserSessionsHandler::handle", skip_all)]
     pub async fn handle(&self, query: ListUserSessionsQuery) -> Result<SessionList, SessionError> {
         let options = query.to_list_options();
         let list = self.reader.list_by_user(&query.user_id, &options).await?;
@@ -78,8 +79,8 @@ impl ListUserSessionsHandler {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::domain::foundation::{DomainError, SessionId, Timestamp};
-    use crate::ports::{SessionSummary, SessionView};
+    use crate::domain::foundation::{DomainError, OrganizationId, SessionId, Timestamp};
+    use crate::ports::{SessionSummary, SessionView, TrashedSession};
     use async_trait::async_trait;
 
     struct MockSessionReader {
@@ -137,6 +138,18 @@ mod tests {
             })
         }
 
+        async fn list_by_organization(
+            &self,
+            _organization_id: &OrganizationId,
+            _options: &ListOptions,
+        ) -> Result<SessionList, DomainError> {
+            Ok(SessionList {
+                items: vec![],
+                total: 0,
+                has_more: false,
+            })
+        }
+
         async fn search(
             &self,
             _user_id: &UserId,
@@ -157,6 +170,18 @@ mod tests {
         ) -> Result<u64, DomainError> {
             Ok(0)
         }
+
+        async fn list_trashed(&self, _user_id: &UserId) -> Result<Vec<TrashedSession>, DomainError> {
+            Ok(vec![])
+        }
+
+        async fn list_purge_candidates(
+            &self,
+            _deleted_before: Timestamp,
+            _limit: u32,
+        ) -> Result<Vec<TrashedSession>, DomainError> {
+            Ok(vec![])
+        }
     }
 
     fn test_user_id() -> UserId {
@@ -170,6 +195,7 @@ mod tests {
             status,
             cycle_count: 0,
             updated_at: Timestamp::now(),
+            in_cold_storage: false,
         }
     }
 
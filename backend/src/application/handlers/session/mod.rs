@@ -1,15 +1,34 @@
 //! Session command and query handlers.
 
 mod archive_session;
+mod auto_archive;
+mod cold_storage;
 mod create_session;
 mod get_session;
 mod list_user_sessions;
+mod rehydrating_cycles;
 mod rename_session;
+mod session_archiver;
 mod session_cycle_tracker;
+mod session_purger;
+mod trash;
 
 pub use archive_session::{ArchiveSessionCommand, ArchiveSessionHandler, ArchiveSessionResult};
+pub use auto_archive::{
+    GetAutoArchiveSettingsHandler, GetAutoArchiveSettingsQuery, SetAutoArchiveExclusionCommand,
+    SetAutoArchiveExclusionHandler,
+};
+pub use cold_storage::{ColdStorageOffloader, SessionColdStorage};
 pub use create_session::{CreateSessionCommand, CreateSessionHandler, CreateSessionResult};
 pub use get_session::{GetSessionHandler, GetSessionQuery};
 pub use list_user_sessions::{ListUserSessionsHandler, ListUserSessionsQuery};
+pub use rehydrating_cycles::{RehydratingCycleReader, RehydratingCycleRepository};
 pub use rename_session::{RenameSessionCommand, RenameSessionHandler, RenameSessionResult};
+pub use session_archiver::{ArchivalRun, SessionArchiver};
 pub use session_cycle_tracker::{CycleCreated, SessionCycleTracker};
+pub use session_purger::SessionPurger;
+pub use trash::{
+    DeleteSessionCommand, DeleteSessionHandler, DeleteSessionResult, ListTrashedSessionsHandler,
+    ListTrashedSessionsQuery, PurgeSessionCommand, PurgeSessionHandler, RestoreSessionCommand,
+    RestoreSessionHandler, RestoreSessionResult,
+};
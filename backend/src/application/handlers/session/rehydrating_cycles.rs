@@ -0,0 +1,215 @@
+//! Cycle repository and reader wrappers that rehydrate cold sessions.
+//!
+//! Both pass every call straight through. Only when a lookup finds nothing
+//! do they ask `SessionColdStorage` whether the session or cycle is cold,
+//! bring it back if so, and repeat the lookup, so hot sessions pay nothing
+//! and callers never see the difference.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use crate::domain::cycle::{Cycle, CycleTreeNode as PrOACTTreeNode};
+use crate::domain::foundation::{ComponentType, CycleId, DomainError, OrganizationId, SessionId};
+use crate::ports::{
+    ComponentOutputView, CycleProgressView, CycleReader, CycleRepository, CycleSummary,
+    CycleTreeNode, CycleView,
+};
+
+use super::SessionColdStorage;
+
+/// `CycleRepository` that rehydrates cold sessions on access.
+pub struct RehydratingCycleRepository {
+    inner: Arc<dyn CycleRepository>,
+    cold_storage: Arc<SessionColdStorage>,
+}
+
+impl RehydratingCycleRepository {
+    /// Wraps `inner`, which must be the repository `cold_storage` uses.
+    pub fn new(inner: Arc<dyn CycleRepository>, cold_storage: Arc<SessionColdStorage>) -> Self {
+        Self {
+            inner,
+            cold_storage,
+        }
+    }
+}
+
+#[async_trait]
+impl CycleRepository for RehydratingCycleRepository {
+    async fn save(&self, cycle: &Cycle) -> Result<(), DomainError> {
+        self.inner.save(cycle).await
+    }
+
+    async fn update(&self, cycle: &Cycle) -> Result<(), DomainError> {
+        self.inner.update(cycle).await
+    }
+
+    async fn find_by_id(&self, id: &CycleId) -> Result<Option<Cycle>, DomainError> {
+        let found = self.inner.find_by_id(id).await?;
+        if found.is_none() && self.cold_storage.rehydrate_cycle(id).await? {
+            return self.inner.find_by_id(id).await;
+        }
+        Ok(found)
+    }
+
+    async fn exists(&self, id: &CycleId) -> Result<bool, DomainError> {
+        Ok(self.inner.exists(id).await? || self.cold_storage.rehydrate_cycle(id).await?)
+    }
+
+    async fn find_by_session_id(&self, session_id: &SessionId) -> Result<Vec<Cycle>, DomainError> {
+        let found = self.inner.find_by_session_id(session_id).await?;
+        if found.is_empty() && self.cold_storage.rehydrate(session_id).await? {
+            return self.inner.find_by_session_id(session_id).await;
+        }
+        Ok(found)
+    }
+
+    async fn find_primary_by_session_id(
+        &self,
+        session_id: &SessionId,
+    ) -> Result<Option<Cycle>, DomainError> {
+        let found = self.inner.find_primary_by_session_id(session_id).await?;
+        if found.is_none() && self.cold_storage.rehydrate(session_id).await? {
+            return self.inner.find_primary_by_session_id(session_id).await;
+        }
+        Ok(found)
+    }
+
+    async fn find_branches(&self, parent_id: &CycleId) -> Result<Vec<Cycle>, DomainError> {
+        let found = self.inner.find_branches(parent_id).await?;
+        if found.is_empty() && self.cold_storage.rehydrate_cycle(parent_id).await? {
+            return self.inner.find_branches(parent_id).await;
+        }
+        Ok(found)
+    }
+
+    async fn count_by_session_id(&self, session_id: &SessionId) -> Result<u32, DomainError> {
+        let count = self.inner.count_by_session_id(session_id).await?;
+        if count == 0 && self.cold_storage.rehydrate(session_id).await? {
+            return self.inner.count_by_session_id(session_id).await;
+        }
+        Ok(count)
+    }
+
+    async fn delete(&self, id: &CycleId) -> Result<(), DomainError> {
+        self.cold_storage.rehydrate_cycle(id).await?;
+        self.inner.delete(id).await
+    }
+}
+
+/// `CycleReader` that rehydrates cold sessions on access.
+pub struct RehydratingCycleReader {
+    inner: Arc<dyn CycleReader>,
+    cold_storage: Arc<SessionColdStorage>,
+}
+
+impl RehydratingCycleReader {
+    pub fn new(inner: Arc<dyn CycleReader>, cold_storage: Arc<SessionColdStorage>) -> Self {
+        Self {
+            inner,
+            cold_storage,
+        }
+    }
+}
+
+#[async_trait]
+impl CycleReader for RehydratingCycleReader {
+    async fn get_by_id(&self, id: &CycleId) -> Result<Option<CycleView>, DomainError> {
+        let found = self.inner.get_by_id(id).await?;
+        if found.is_none() && self.cold_storage.rehydrate_cycle(id).await? {
+            return self.inner.get_by_id(id).await;
+        }
+        Ok(found)
+    }
+
+    async fn list_by_session_id(
+        &self,
+        session_id: &SessionId,
+    ) -> Result<Vec<CycleSummary>, DomainError> {
+        let found = self.inner.list_by_session_id(session_id).await?;
+        if found.is_empty() && self.cold_storage.rehydrate(session_id).await? {
+            return self.inner.list_by_session_id(session_id).await;
+        }
+        Ok(found)
+    }
+
+    /// Cold sessions are left out rather than rehydrated in bulk.
+    async fn list_by_organization(
+        &self,
+        organization_id: &OrganizationId,
+    ) -> Result<HashMap<SessionId, Vec<CycleSummary>>, DomainError> {
+        self.inner.list_by_organization(organization_id).await
+    }
+
+    async fn get_tree(&self, session_id: &SessionId) -> Result<Option<CycleTreeNode>, DomainError> {
+        let found = self.inner.get_tree(session_id).await?;
+        if found.is_none() && self.cold_storage.rehydrate(session_id).await? {
+            return self.inner.get_tree(session_id).await;
+        }
+        Ok(found)
+    }
+
+    async fn get_progress(&self, id: &CycleId) -> Result<Option<CycleProgressView>, DomainError> {
+        let found = self.inner.get_progress(id).await?;
+        if found.is_none() && self.cold_storage.rehydrate_cycle(id).await? {
+            return self.inner.get_progress(id).await;
+        }
+        Ok(found)
+    }
+
+    async fn get_lineage(&self, id: &CycleId) -> Result<Vec<CycleSummary>, DomainError> {
+        let found = self.inner.get_lineage(id).await?;
+        if found.is_empty() && self.cold_storage.rehydrate_cycle(id).await? {
+            return self.inner.get_lineage(id).await;
+        }
+        Ok(found)
+    }
+
+    async fn get_component_output(
+        &self,
+        cycle_id: &CycleId,
+        component_type: ComponentType,
+    ) -> Result<Option<ComponentOutputView>, DomainError> {
+        let found = self
+            .inner
+            .get_component_output(cycle_id, component_type)
+            .await?;
+        if found.is_none() && self.cold_storage.rehydrate_cycle(cycle_id).await? {
+            return self
+                .inner
+                .get_component_output(cycle_id, component_type)
+                .await;
+        }
+        Ok(found)
+    }
+
+    async fn get_component_outputs(
+        &self,
+        cycle_id: &CycleId,
+        component_types: &[ComponentType],
+    ) -> Result<HashMap<ComponentType, ComponentOutputView>, DomainError> {
+        let found = self
+            .inner
+            .get_component_outputs(cycle_id, component_types)
+            .await?;
+        if found.is_empty() && self.cold_storage.rehydrate_cycle(cycle_id).await? {
+            return self
+                .inner
+                .get_component_outputs(cycle_id, component_types)
+                .await;
+        }
+        Ok(found)
+    }
+
+    async fn get_proact_tree_view(
+        &self,
+        session_id: &SessionId,
+    ) -> Result<Option<PrOACTTreeNode>, DomainError> {
+        let found = self.inner.get_proact_tree_view(session_id).await?;
+        if found.is_none() && self.cold_storage.rehydrate(session_id).await? {
+            return self.inner.get_proact_tree_view(session_id).await;
+        }
+        Ok(found)
+    }
+}
@@ -2,6 +2,8 @@
 
 use std::sync::Arc;
 
+use crate::application::handlers::audit::CommandAuditor;
+use crate::domain::audit::{AuditRecord, AuditSummary};
 use crate::domain::foundation::{
     CommandMetadata, EventId, SerializableDomainEvent, SessionId, Timestamp, UserId,
 };
@@ -27,6 +29,7 @@ pub struct RenameSessionResult {
 pub struct RenameSessionHandler {
     repository: Arc<dyn SessionRepository>,
     event_publisher: Arc<dyn EventPublisher>,
+    auditor: CommandAuditor,
 }
 
 impl RenameSessionHandler {
@@ -37,9 +40,17 @@ impl RenameSessionHandler {
         Self {
             repository,
             event_publisher,
+            auditor: CommandAuditor::disabled(),
         }
     }
 
+    /// Records each command in the audit log.
+    pub fn with_auditor(mut self, auditor: CommandAuditor) -> Self {
+        self.auditor = auditor;
+        self
+    }
+
+    #[tracing::instrument(name = "RenameSessionHandler::handle", skip_all)]
     pub async fn handle(
         &self,
         cmd: RenameSessionCommand,
@@ -57,6 +68,7 @@ impl RenameSessionHandler {
 
         // 3. Capture old title for event
         let old_title = session.title().to_string();
+        let before = session.audit_summary();
 
         // 4. Apply rename
         session.rename(cmd.new_title.clone())?;
@@ -81,6 +93,14 @@ impl RenameSessionHandler {
 
         self.event_publisher.publish(envelope).await?;
 
+        self.auditor
+            .record(
+                AuditRecord::new(&metadata, "RenameSession", "session", cmd.session_id)
+                    .with_before(before)
+                    .with_after(session.audit_summary()),
+            )
+            .await;
+
         Ok(RenameSessionResult { session, event })
     }
 }
@@ -89,6 +109,7 @@ impl RenameSessionHandler {
 mod tests {
     use super::*;
     use crate::domain::foundation::{DomainError, ErrorCode, EventEnvelope};
+    use crate::test_support::SessionBuilder;
     use async_trait::async_trait;
     use std::sync::Mutex;
 
@@ -206,7 +227,13 @@ mod tests {
     }
 
     fn test_session() -> Session {
-        Session::new(SessionId::new(), test_user_id(), "Original Title".to_string()).unwrap()
+        session_builder().build()
+    }
+
+    fn session_builder() -> SessionBuilder {
+        SessionBuilder::new()
+            .user_id(test_user_id())
+            .title("Original Title")
     }
 
     fn test_metadata() -> CommandMetadata {
@@ -321,8 +348,7 @@ mod tests {
 
     #[tokio::test]
     async fn fails_when_session_archived() {
-        let mut session = test_session();
-        session.archive().unwrap();
+        let session = session_builder().archived().build();
         let session_id = *session.id();
         let repo = Arc::new(MockSessionRepository::with_session(session));
         let publisher = Arc::new(MockEventPublisher::new());
@@ -0,0 +1,484 @@
+//! SessionArchiver - Background job that auto-archives idle sessions.
+//!
+//! Polls `SessionArchivalRepository::list_due` for sessions the
+//! `ArchivalPolicy` wants warned or archived. A warning publishes
+//! `SessionArchiveWarningIssued` and, when the owner's address is known,
+//! emails them a link to the session; archiving publishes `SessionArchived`
+//! with `automatic` set. Both events carry the owner as the acting user, so
+//! dashboards and the owner's integrations pick them up.
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use tokio::sync::watch;
+use tokio::time;
+
+use crate::domain::foundation::{
+    DomainError, EventId, SerializableDomainEvent, SessionStatus, Timestamp,
+};
+use crate::domain::session::{
+    ArchivalDecision, ArchivalPolicy, SessionArchiveWarningIssued, SessionArchived,
+};
+use crate::ports::{
+    ArchivalCandidate, EmailMessage, EmailSender, EmailTemplateError, EmailTemplateKind,
+    EmailTemplateRenderer, EventPublisher, SessionArchivalRepository, SessionRepository,
+    DEFAULT_EMAIL_LOCALE,
+};
+
+/// Sessions handled per poll.
+const ARCHIVAL_BATCH_SIZE: u32 = 100;
+
+/// What one pass did.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ArchivalRun {
+    pub warned: usize,
+    pub archived: usize,
+}
+
+/// Warns about and archives idle sessions.
+pub struct SessionArchiver {
+    policy: ArchivalPolicy,
+    archival: Arc<dyn SessionArchivalRepository>,
+    sessions: Arc<dyn SessionRepository>,
+    event_publisher: Arc<dyn EventPublisher>,
+    email_sender: Arc<dyn EmailSender>,
+    templates: Arc<dyn EmailTemplateRenderer>,
+    /// Web app origin used in emailed links, e.g. `https://app.choicesherpa.com`.
+    app_base_url: String,
+}
+
+impl SessionArchiver {
+    pub fn new(
+        policy: ArchivalPolicy,
+        archival: Arc<dyn SessionArchivalRepository>,
+        sessions: Arc<dyn SessionRepository>,
+        event_publisher: Arc<dyn EventPublisher>,
+        email_sender: Arc<dyn EmailSender>,
+        templates: Arc<dyn EmailTemplateRenderer>,
+        app_base_url: impl Into<String>,
+    ) -> Self {
+        Self {
+            policy,
+            archival,
+            sessions,
+            event_publisher,
+            email_sender,
+            templates,
+            app_base_url: app_base_url.into().trim_end_matches('/').to_string(),
+        }
+    }
+
+    /// Processes idle sessions every `poll_interval` until shutdown is
+    /// signalled. Returns immediately if the policy archives nothing.
+    pub async fn run(
+        &self,
+        poll_interval: Duration,
+        mut shutdown: watch::Receiver<bool>,
+    ) -> Result<(), DomainError> {
+        if !self.policy.is_enabled() {
+            return Ok(());
+        }
+        let mut interval = time::interval(poll_interval);
+
+        loop {
+            tokio::select! {
+                _ = shutdown.changed() => {
+                    if *shutdown.borrow() {
+                        return Ok(());
+                    }
+                }
+
+                _ = interval.tick() => {
+                    self.process_due(ARCHIVAL_BATCH_SIZE).await?;
+                }
+            }
+        }
+    }
+
+    /// Warns about or archives up to `limit` due sessions.
+    #[tracing::instrument(name = "SessionArchiver::process_due", skip_all)]
+    pub async fn process_due(&self, limit: u32) -> Result<ArchivalRun, DomainError> {
+        let now = Timestamp::now();
+        let due = self.archival.list_due(&self.policy, now, limit).await?;
+        let mut run = ArchivalRun::default();
+        for candidate in due {
+            match self.policy.evaluate(
+                candidate.tier,
+                candidate.last_activity,
+                &candidate.settings,
+                now,
+            ) {
+                ArchivalDecision::Keep => {}
+                ArchivalDecision::Warn { archive_on } => {
+                    self.warn(candidate, archive_on, now).await?;
+                    run.warned += 1;
+                }
+                ArchivalDecision::Archive => {
+                    if self.archive(candidate, now).await? {
+                        run.archived += 1;
+                    }
+                }
+            }
+        }
+        Ok(run)
+    }
+
+    async fn warn(
+        &self,
+        candidate: ArchivalCandidate,
+        archive_on: Timestamp,
+        now: Timestamp,
+    ) -> Result<(), DomainError> {
+        let idle_days = self.policy.idle_days(candidate.tier).unwrap_or_default();
+        let mut settings = candidate.settings.clone();
+        settings.record_warning(now);
+        self.archival.save_settings(&settings).await?;
+
+        let event = SessionArchiveWarningIssued {
+            event_id: EventId::new(),
+            session_id: candidate.session_id,
+            user_id: candidate.user_id.clone(),
+            idle_days,
+            archive_on,
+            issued_at: now,
+        };
+        self.event_publisher
+            .publish(
+                event
+                    .to_envelope()
+                    .with_user_id(candidate.user_id.to_string()),
+            )
+            .await?;
+
+        let Some(recipient) = settings.notify_email.as_deref() else {
+            return Ok(());
+        };
+        let message = match self.warning_message(&candidate, recipient, idle_days, archive_on) {
+            Ok(message) => message,
+            Err(error) => {
+                tracing::error!(
+                    session_id = %candidate.session_id,
+                    error = %error,
+                    "Archive warning email could not be rendered"
+                );
+                return Ok(());
+            }
+        };
+        // The warning stands either way; the event already reached dashboards
+        if let Err(error) = self.email_sender.send(&message).await {
+            tracing::warn!(
+                session_id = %candidate.session_id,
+                error = %error,
+                "Archive warning email failed"
+            );
+        }
+        Ok(())
+    }
+
+    /// Archives one session, returning whether it was still active.
+    async fn archive(
+        &self,
+        candidate: ArchivalCandidate,
+        now: Timestamp,
+    ) -> Result<bool, DomainError> {
+        let session = self.sessions.find_by_id(&candidate.session_id).await?;
+        let Some(mut session) = session.filter(|s| s.status() == SessionStatus::Active) else {
+            return Ok(false);
+        };
+        session.archive()?;
+        self.sessions.update(&session).await?;
+
+        let mut settings = candidate.settings;
+        settings.record_archived(now);
+        self.archival.save_settings(&settings).await?;
+
+        let event = SessionArchived {
+            event_id: EventId::new(),
+            session_id: candidate.session_id,
+            user_id: candidate.user_id.clone(),
+            automatic: true,
+            archived_at: now,
+        };
+        self.event_publisher
+            .publish(
+                event
+                    .to_envelope()
+                    .with_user_id(candidate.user_id.to_string()),
+            )
+            .await?;
+        Ok(true)
+    }
+
+    fn warning_message(
+        &self,
+        candidate: &ArchivalCandidate,
+        recipient: &str,
+        idle_days: u32,
+        archive_on: Timestamp,
+    ) -> Result<EmailMessage, EmailTemplateError> {
+        let context = serde_json::json!({
+            "session_title": candidate.title,
+            "idle_days": idle_days,
+            "archive_on": archive_on.as_datetime().format("%Y-%m-%d").to_string(),
+            "session_url": format!("{}/sessions/{}", self.app_base_url, candidate.session_id),
+        });
+        let email = self.templates.render(
+            EmailTemplateKind::ArchiveWarning,
+            DEFAULT_EMAIL_LOCALE,
+            &context,
+        )?;
+
+        Ok(EmailMessage {
+            to: recipient.to_string(),
+            subject: email.subject,
+            text_body: email.text_body,
+            attachments: Vec::new(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use async_trait::async_trait;
+    use std::sync::Mutex;
+
+    use crate::adapters::email::{InMemoryEmailSender, TeraEmailTemplateRenderer};
+    use crate::adapters::{InMemoryEventBus, InMemorySessionArchivalRepository};
+    use crate::domain::foundation::{SessionId, UserId};
+    use crate::domain::membership::MembershipTier;
+    use crate::domain::session::{Session, SessionArchivalSettings};
+
+    #[derive(Default)]
+    struct MockSessionRepository {
+        sessions: Mutex<Vec<Session>>,
+    }
+
+    impl MockSessionRepository {
+        fn get(&self, id: &SessionId) -> Session {
+            self.sessions
+                .lock()
+                .unwrap()
+                .iter()
+                .find(|s| s.id() == id)
+                .cloned()
+                .unwrap()
+        }
+    }
+
+    #[async_trait]
+    impl SessionRepository for MockSessionRepository {
+        async fn save(&self, session: &Session) -> Result<(), DomainError> {
+            self.sessions.lock().unwrap().push(session.clone());
+            Ok(())
+        }
+
+        async fn update(&self, session: &Session) -> Result<(), DomainError> {
+            let mut sessions = self.sessions.lock().unwrap();
+            if let Some(pos) = sessions.iter().position(|s| s.id() == session.id()) {
+                sessions[pos] = session.clone();
+            }
+            Ok(())
+        }
+
+        async fn find_by_id(&self, id: &SessionId) -> Result<Option<Session>, DomainError> {
+            Ok(self
+                .sessions
+                .lock()
+                .unwrap()
+                .iter()
+                .find(|s| s.id() == id)
+                .cloned())
+        }
+
+        async fn exists(&self, id: &SessionId) -> Result<bool, DomainError> {
+            Ok(self.sessions.lock().unwrap().iter().any(|s| s.id() == id))
+        }
+
+        async fn find_by_user_id(&self, _user_id: &UserId) -> Result<Vec<Session>, DomainError> {
+            Ok(vec![])
+        }
+
+        async fn count_active_by_user(&self, _user_id: &UserId) -> Result<u32, DomainError> {
+            Ok(0)
+        }
+
+        async fn delete(&self, _id: &SessionId) -> Result<(), DomainError> {
+            Ok(())
+        }
+    }
+
+    fn user() -> UserId {
+        UserId::new("user-1").unwrap()
+    }
+
+    fn setup_repositories() -> (
+        Arc<InMemorySessionArchivalRepository>,
+        Arc<MockSessionRepository>,
+        Arc<InMemoryEventBus>,
+        Arc<InMemoryEmailSender>,
+    ) {
+        (
+            Arc::new(InMemorySessionArchivalRepository::new()),
+            Arc::new(MockSessionRepository::default()),
+            Arc::new(InMemoryEventBus::new()),
+            Arc::new(InMemoryEmailSender::new()),
+        )
+    }
+
+    fn create_handler(
+        archival: Arc<InMemorySessionArchivalRepository>,
+        sessions: Arc<MockSessionRepository>,
+        events: Arc<InMemoryEventBus>,
+        email_sender: Arc<InMemoryEmailSender>,
+    ) -> SessionArchiver {
+        SessionArchiver::new(
+            ArchivalPolicy::default(),
+            archival,
+            sessions,
+            events,
+            email_sender,
+            Arc::new(TeraEmailTemplateRenderer::new()),
+            "https://app.example.com/",
+        )
+    }
+
+    async fn idle_session(
+        sessions: &MockSessionRepository,
+        archival: &InMemorySessionArchivalRepository,
+        idle_days: i64,
+    ) -> SessionId {
+        let session = Session::new(SessionId::new(), user(), "Where to live".to_string()).unwrap();
+        let id = *session.id();
+        sessions.save(&session).await.unwrap();
+        archival
+            .track(
+                id,
+                user(),
+                "Where to live",
+                MembershipTier::Free,
+                Timestamp::now().minus_days(idle_days),
+            )
+            .await;
+        id
+    }
+
+    #[tokio::test]
+    async fn warns_owners_before_archiving() {
+        let (archival, sessions, events, email_sender) = setup_repositories();
+        let archiver = create_handler(
+            archival.clone(),
+            sessions.clone(),
+            events.clone(),
+            email_sender.clone(),
+        );
+        let id = idle_session(&sessions, &archival, 170).await;
+        let mut settings = SessionArchivalSettings::new(id, user());
+        settings.notify_email = Some("owner@example.com".to_string());
+        archival.save_settings(&settings).await.unwrap();
+
+        let run = archiver.process_due(10).await.unwrap();
+
+        assert_eq!(
+            run,
+            ArchivalRun {
+                warned: 1,
+                archived: 0
+            }
+        );
+        let warnings = events.events_of_type("session.archive_warning_issued.v1");
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings[0].payload["idle_days"], 180);
+        assert_eq!(warnings[0].metadata.user_id.as_deref(), Some("user-1"));
+        let sent = email_sender.sent();
+        assert_eq!(sent.len(), 1);
+        assert_eq!(sent[0].to, "owner@example.com");
+        assert!(sent[0]
+            .text_body
+            .contains(&format!("https://app.example.com/sessions/{}", id)));
+        assert_eq!(sessions.get(&id).status(), SessionStatus::Active);
+
+        // Warned once per idle stretch
+        assert_eq!(
+            archiver.process_due(10).await.unwrap(),
+            ArchivalRun::default()
+        );
+    }
+
+    #[tokio::test]
+    async fn archives_after_the_warning_period() {
+        let (archival, sessions, events, email_sender) = setup_repositories();
+        let archiver = create_handler(
+            archival.clone(),
+            sessions.clone(),
+            events.clone(),
+            email_sender.clone(),
+        );
+        let id = idle_session(&sessions, &archival, 200).await;
+        let mut settings = SessionArchivalSettings::new(id, user());
+        settings.record_warning(Timestamp::now().minus_days(15));
+        archival.save_settings(&settings).await.unwrap();
+
+        let run = archiver.process_due(10).await.unwrap();
+
+        assert_eq!(
+            run,
+            ArchivalRun {
+                warned: 0,
+                archived: 1
+            }
+        );
+        assert_eq!(sessions.get(&id).status(), SessionStatus::Archived);
+        let archived = events.events_of_type("session.archived.v1");
+        assert_eq!(archived.len(), 1);
+        assert_eq!(archived[0].payload["automatic"], true);
+        let stored = archival.find_settings(&id).await.unwrap().unwrap();
+        assert!(stored.archived_at.is_some());
+        assert!(email_sender.sent().is_empty());
+    }
+
+    #[tokio::test]
+    async fn leaves_excluded_sessions_alone() {
+        let (archival, sessions, events, email_sender) = setup_repositories();
+        let archiver = create_handler(
+            archival.clone(),
+            sessions.clone(),
+            events.clone(),
+            email_sender.clone(),
+        );
+        let id = idle_session(&sessions, &archival, 400).await;
+        let mut settings = SessionArchivalSettings::new(id, user());
+        settings.excluded = true;
+        archival.save_settings(&settings).await.unwrap();
+
+        assert_eq!(
+            archiver.process_due(10).await.unwrap(),
+            ArchivalRun::default()
+        );
+        assert_eq!(events.event_count(), 0);
+    }
+
+    #[tokio::test]
+    async fn skips_sessions_archived_by_hand() {
+        let (archival, sessions, events, email_sender) = setup_repositories();
+        let archiver = create_handler(
+            archival.clone(),
+            sessions.clone(),
+            events.clone(),
+            email_sender.clone(),
+        );
+        let id = idle_session(&sessions, &archival, 200).await;
+        let mut settings = SessionArchivalSettings::new(id, user());
+        settings.record_warning(Timestamp::now().minus_days(15));
+        archival.save_settings(&settings).await.unwrap();
+        let mut session = sessions.get(&id);
+        session.archive().unwrap();
+        sessions.update(&session).await.unwrap();
+
+        assert_eq!(
+            archiver.process_due(10).await.unwrap(),
+            ArchivalRun::default()
+        );
+        assert!(!events.has_event("session.archived.v1"));
+    }
+}
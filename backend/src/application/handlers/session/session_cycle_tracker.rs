@@ -114,6 +114,7 @@ mod tests {
     use super::*;
     use crate::domain::foundation::UserId;
     use crate::domain::session::Session;
+    use crate::test_support::SessionBuilder;
     use serde_json::json;
     use std::sync::Mutex;
 
@@ -222,7 +223,13 @@ mod tests {
     }
 
     fn test_session() -> Session {
-        Session::new(SessionId::new(), test_user_id(), "Test Session".to_string()).unwrap()
+        session_builder().build()
+    }
+
+    fn session_builder() -> SessionBuilder {
+        SessionBuilder::new()
+            .user_id(test_user_id())
+            .title("Test Session")
     }
 
     fn cycle_created_event(session_id: SessionId, cycle_id: CycleId) -> EventEnvelope {
@@ -307,8 +314,7 @@ mod tests {
     #[tokio::test]
     async fn second_cycle_is_not_root() {
         // Session with one existing cycle
-        let mut session = test_session();
-        session.add_cycle(CycleId::new()).unwrap();
+        let session = session_builder().cycle(CycleId::new()).build();
         let session_id = *session.id();
 
         let repo = Arc::new(MockSessionRepository::with_session(session));
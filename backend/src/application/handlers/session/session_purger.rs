@@ -0,0 +1,295 @@
+//! SessionPurger - Background job that empties expired sessions from the
+//! trash.
+//!
+//! Polls `SessionReader::list_purge_candidates` for sessions deleted more
+//! than `TRASH_RETENTION_DAYS` ago and purges each through
+//! `PurgeSessionHandler`, which publishes `SessionPurged` with `automatic`
+//! set and the owner as the acting user.
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use tokio::sync::watch;
+use tokio::time;
+
+use crate::domain::foundation::{DomainError, Timestamp};
+use crate::domain::session::TRASH_RETENTION_DAYS;
+use crate::ports::SessionReader;
+
+use super::PurgeSessionHandler;
+
+/// Sessions purged per poll.
+const PURGE_BATCH_SIZE: u32 = 100;
+
+/// Purges sessions whose trash retention window has run out.
+pub struct SessionPurger {
+    reader: Arc<dyn SessionReader>,
+    purge: Arc<PurgeSessionHandler>,
+}
+
+impl SessionPurger {
+    pub fn new(reader: Arc<dyn SessionReader>, purge: Arc<PurgeSessionHandler>) -> Self {
+        Self { reader, purge }
+    }
+
+    /// Purges expired sessions every `poll_interval` until shutdown is
+    /// signalled.
+    pub async fn run(
+        &self,
+        poll_interval: Duration,
+        mut shutdown: watch::Receiver<bool>,
+    ) -> Result<(), DomainError> {
+        let mut interval = time::interval(poll_interval);
+
+        loop {
+            tokio::select! {
+                _ = shutdown.changed() => {
+                    if *shutdown.borrow() {
+                        return Ok(());
+                    }
+                }
+
+                _ = interval.tick() => {
+                    self.process_due(PURGE_BATCH_SIZE).await?;
+                }
+            }
+        }
+    }
+
+    /// Purges up to `limit` expired sessions, returning how many went. A
+    /// session that fails is logged and retried on the next pass.
+    #[tracing::instrument(name = "SessionPurger::process_due", skip_all)]
+    pub async fn process_due(&self, limit: u32) -> Result<usize, DomainError> {
+        let deleted_before = Timestamp::now().minus_days(TRASH_RETENTION_DAYS);
+        let due = self
+            .reader
+            .list_purge_candidates(deleted_before, limit)
+            .await?;
+        let mut purged = 0;
+        for candidate in due {
+            match self.purge.purge_expired(&candidate.id).await {
+                Ok(true) => purged += 1,
+                Ok(false) => {}
+                Err(error) => tracing::warn!(
+                    session_id = %candidate.id,
+                    error = %error,
+                    "Failed to purge session from the trash"
+                ),
+            }
+        }
+        Ok(purged)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use async_trait::async_trait;
+    use std::sync::Mutex;
+
+    use crate::domain::foundation::{
+        EventEnvelope, OrganizationId, SessionId, SessionStatus, UserId,
+    };
+    use crate::domain::session::Session;
+    use crate::ports::{
+        EventPublisher, ListOptions, SessionList, SessionRepository, SessionView, TrashedSession,
+    };
+
+    /// Sessions shared by the repository and the reader.
+    #[derive(Default)]
+    struct InMemorySessions {
+        sessions: Mutex<Vec<Session>>,
+    }
+
+    impl InMemorySessions {
+        fn ids(&self) -> Vec<SessionId> {
+            self.sessions
+                .lock()
+                .unwrap()
+                .iter()
+                .map(|s| *s.id())
+                .collect()
+        }
+    }
+
+    #[async_trait]
+    impl SessionRepository for InMemorySessions {
+        async fn save(&self, session: &Session) -> Result<(), DomainError> {
+            self.sessions.lock().unwrap().push(session.clone());
+            Ok(())
+        }
+
+        async fn update(&self, _session: &Session) -> Result<(), DomainError> {
+            Ok(())
+        }
+
+        async fn find_by_id(&self, id: &SessionId) -> Result<Option<Session>, DomainError> {
+            Ok(self
+                .sessions
+                .lock()
+                .unwrap()
+                .iter()
+                .find(|s| s.id() == id)
+                .cloned())
+        }
+
+        async fn exists(&self, id: &SessionId) -> Result<bool, DomainError> {
+            Ok(self.find_by_id(id).await?.is_some())
+        }
+
+        async fn find_by_user_id(&self, _user_id: &UserId) -> Result<Vec<Session>, DomainError> {
+            Ok(vec![])
+        }
+
+        async fn count_active_by_user(&self, _user_id: &UserId) -> Result<u32, DomainError> {
+            Ok(0)
+        }
+
+        async fn delete(&self, id: &SessionId) -> Result<(), DomainError> {
+            self.sessions.lock().unwrap().retain(|s| s.id() != id);
+            Ok(())
+        }
+    }
+
+    #[async_trait]
+    impl SessionReader for InMemorySessions {
+        async fn get_by_id(&self, _id: &SessionId) -> Result<Option<SessionView>, DomainError> {
+            Ok(None)
+        }
+
+        async fn list_by_user(
+            &self,
+            _user_id: &UserId,
+            _options: &ListOptions,
+        ) -> Result<SessionList, DomainError> {
+            unimplemented!()
+        }
+
+        async fn list_by_organization(
+            &self,
+            _organization_id: &OrganizationId,
+            _options: &ListOptions,
+        ) -> Result<SessionList, DomainError> {
+            unimplemented!()
+        }
+
+        async fn search(
+            &self,
+            _user_id: &UserId,
+            _query: &str,
+            _options: &ListOptions,
+        ) -> Result<SessionList, DomainError> {
+            unimplemented!()
+        }
+
+        async fn count_by_status(
+            &self,
+            _user_id: &UserId,
+            _status: SessionStatus,
+        ) -> Result<u64, DomainError> {
+            Ok(0)
+        }
+
+        async fn list_trashed(
+            &self,
+            _user_id: &UserId,
+        ) -> Result<Vec<TrashedSession>, DomainError> {
+            Ok(vec![])
+        }
+
+        async fn list_purge_candidates(
+            &self,
+            deleted_before: Timestamp,
+            limit: u32,
+        ) -> Result<Vec<TrashedSession>, DomainError> {
+            Ok(self
+                .sessions
+                .lock()
+                .unwrap()
+                .iter()
+                .filter(|s| s.deleted_at().is_some_and(|at| *at < deleted_before))
+                .take(limit as usize)
+                .map(|s| TrashedSession {
+                    id: *s.id(),
+                    user_id: s.user_id().clone(),
+                    title: s.title().to_string(),
+                    status_before_deletion: s
+                        .status_before_deletion()
+                        .unwrap_or(SessionStatus::Active),
+                    cycle_count: s.cycle_count() as u32,
+                    deleted_at: *s.deleted_at().unwrap(),
+                    purge_after: s.purge_after().unwrap(),
+                })
+                .collect())
+        }
+    }
+
+    #[derive(Default)]
+    struct RecordingPublisher {
+        events: Mutex<Vec<EventEnvelope>>,
+    }
+
+    #[async_trait]
+    impl EventPublisher for RecordingPublisher {
+        async fn publish(&self, event: EventEnvelope) -> Result<(), DomainError> {
+            self.events.lock().unwrap().push(event);
+            Ok(())
+        }
+
+        async fn publish_all(&self, events: Vec<EventEnvelope>) -> Result<(), DomainError> {
+            self.events.lock().unwrap().extend(events);
+            Ok(())
+        }
+    }
+
+    fn session_deleted_days_ago(days: i64) -> Session {
+        let user_id = UserId::new("owner-1").unwrap();
+        Session::new(SessionId::new(), user_id, "Trashed".to_string())
+            .unwrap()
+            .with_deletion(Timestamp::now().minus_days(days), SessionStatus::Active)
+    }
+
+    #[tokio::test]
+    async fn purges_sessions_past_the_retention_window() {
+        let sessions = Arc::new(InMemorySessions::default());
+        let expired = session_deleted_days_ago(TRASH_RETENTION_DAYS + 1);
+        let recent = session_deleted_days_ago(1);
+        sessions.save(&expired).await.unwrap();
+        sessions.save(&recent).await.unwrap();
+        let publisher = Arc::new(RecordingPublisher::default());
+        let purger = SessionPurger::new(
+            sessions.clone(),
+            Arc::new(PurgeSessionHandler::new(
+                sessions.clone(),
+                publisher.clone(),
+            )),
+        );
+
+        let purged = purger.process_due(10).await.unwrap();
+
+        assert_eq!(purged, 1);
+        assert_eq!(sessions.ids(), vec![*recent.id()]);
+        let events = publisher.events.lock().unwrap();
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].event_type, "session.purged.v1");
+        assert_eq!(events[0].payload["automatic"], true);
+    }
+
+    #[tokio::test]
+    async fn run_stops_on_shutdown() {
+        let sessions = Arc::new(InMemorySessions::default());
+        let purger = SessionPurger::new(
+            sessions.clone(),
+            Arc::new(PurgeSessionHandler::new(
+                sessions,
+                Arc::new(RecordingPublisher::default()),
+            )),
+        );
+        let (tx, rx) = watch::channel(false);
+
+        let handle = tokio::spawn(async move { purger.run(Duration::from_secs(60), rx).await });
+        tx.send(true).unwrap();
+
+        assert!(handle.await.unwrap().is_ok());
+    }
+}
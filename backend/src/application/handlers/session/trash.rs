@@ -0,0 +1,645 @@
+//! Trash handlers - Deleting, restoring and purging sessions.
+//!
+//! Deleting a session moves it to the trash, where it stays restorable for
+//! `TRASH_RETENTION_DAYS`. Purging removes it and its cycles for good,
+//! either because the owner emptied it from the trash or, through
+//! `SessionPurger`, because the retention window ran out.
+
+use std::sync::Arc;
+
+use crate::application::handlers::audit::CommandAuditor;
+use crate::domain::audit::{AuditRecord, AuditSummary};
+use crate::domain::foundation::{
+    CommandMetadata, DomainError, EventId, SerializableDomainEvent, SessionId, Timestamp, UserId,
+};
+use crate::domain::session::{
+    Session, SessionDeleted, SessionError, SessionPurged, SessionRestored, TRASH_RETENTION_DAYS,
+};
+use crate::ports::{EventPublisher, SessionReader, SessionRepository, TrashedSession};
+
+use super::SessionColdStorage;
+
+/// Command to move a session to the trash.
+#[derive(Debug, Clone)]
+pub struct DeleteSessionCommand {
+    pub session_id: SessionId,
+    pub user_id: UserId,
+}
+
+/// Result of moving a session to the trash.
+#[derive(Debug, Clone)]
+pub struct DeleteSessionResult {
+    pub session: Session,
+    pub event: SessionDeleted,
+}
+
+/// Handler for moving sessions to the trash.
+pub struct DeleteSessionHandler {
+    repository: Arc<dyn SessionRepository>,
+    event_publisher: Arc<dyn EventPublisher>,
+    auditor: CommandAuditor,
+}
+
+impl DeleteSessionHandler {
+    pub fn new(
+        repository: Arc<dyn SessionRepository>,
+        event_publisher: Arc<dyn EventPublisher>,
+    ) -> Self {
+        Self {
+            repository,
+            event_publisher,
+            auditor: CommandAuditor::disabled(),
+        }
+    }
+
+    /// Records each command in the audit log.
+    pub fn with_auditor(mut self, auditor: CommandAuditor) -> Self {
+        self.auditor = auditor;
+        self
+    }
+
+    #[tracing::instrument(name = "DeleteSessionHandler::handle", skip_all)]
+    pub async fn handle(
+        &self,
+        cmd: DeleteSessionCommand,
+        metadata: CommandMetadata,
+    ) -> Result<DeleteSessionResult, SessionError> {
+        let mut session = self
+            .repository
+            .find_by_id(&cmd.session_id)
+            .await?
+            .ok_or_else(|| SessionError::not_found(cmd.session_id))?;
+
+        session.authorize(&cmd.user_id)?;
+        let before = session.audit_summary();
+        session.delete()?;
+        self.repository.update(&session).await?;
+
+        let deleted_at = session.deleted_at().copied().unwrap_or_else(Timestamp::now);
+        let event = SessionDeleted {
+            event_id: EventId::new(),
+            session_id: cmd.session_id,
+            user_id: cmd.user_id,
+            purge_after: deleted_at.add_days(TRASH_RETENTION_DAYS),
+            deleted_at,
+        };
+
+        let envelope = event
+            .to_envelope()
+            .with_correlation_id(metadata.correlation_id())
+            .with_user_id(metadata.user_id.to_string());
+
+        self.event_publisher.publish(envelope).await?;
+
+        self.auditor
+            .record(
+                AuditRecord::new(&metadata, "DeleteSession", "session", cmd.session_id)
+                    .with_before(before)
+                    .with_after(session.audit_summary()),
+            )
+            .await;
+
+        Ok(DeleteSessionResult { session, event })
+    }
+}
+
+/// Command to take a session back out of the trash.
+#[derive(Debug, Clone)]
+pub struct RestoreSessionCommand {
+    pub session_id: SessionId,
+    pub user_id: UserId,
+}
+
+/// Result of restoring a session.
+#[derive(Debug, Clone)]
+pub struct RestoreSessionResult {
+    pub session: Session,
+    pub event: SessionRestored,
+}
+
+/// Handler for restoring sessions from the trash.
+pub struct RestoreSessionHandler {
+    repository: Arc<dyn SessionRepository>,
+    event_publisher: Arc<dyn EventPublisher>,
+    auditor: CommandAuditor,
+}
+
+impl RestoreSessionHandler {
+    pub fn new(
+        repository: Arc<dyn SessionRepository>,
+        event_publisher: Arc<dyn EventPublisher>,
+    ) -> Self {
+        Self {
+            repository,
+            event_publisher,
+            auditor: CommandAuditor::disabled(),
+        }
+    }
+
+    /// Records each command in the audit log.
+    pub fn with_auditor(mut self, auditor: CommandAuditor) -> Self {
+        self.auditor = auditor;
+        self
+    }
+
+    #[tracing::instrument(name = "RestoreSessionHandler::handle", skip_all)]
+    pub async fn handle(
+        &self,
+        cmd: RestoreSessionCommand,
+        metadata: CommandMetadata,
+    ) -> Result<RestoreSessionResult, SessionError> {
+        let mut session = self
+            .repository
+            .find_by_id(&cmd.session_id)
+            .await?
+            .ok_or_else(|| SessionError::not_found(cmd.session_id))?;
+
+        session.authorize(&cmd.user_id)?;
+        let before = session.audit_summary();
+        let status = session.restore()?;
+        self.repository.update(&session).await?;
+
+        let event = SessionRestored {
+            event_id: EventId::new(),
+            session_id: cmd.session_id,
+            user_id: cmd.user_id,
+            status,
+            restored_at: Timestamp::now(),
+        };
+
+        let envelope = event
+            .to_envelope()
+            .with_correlation_id(metadata.correlation_id())
+            .with_user_id(metadata.user_id.to_string());
+
+        self.event_publisher.publish(envelope).await?;
+
+        self.auditor
+            .record(
+                AuditRecord::new(&metadata, "RestoreSession", "session", cmd.session_id)
+                    .with_before(before)
+                    .with_after(session.audit_summary()),
+            )
+            .await;
+
+        Ok(RestoreSessionResult { session, event })
+    }
+}
+
+/// Command to permanently remove a session from the trash.
+#[derive(Debug, Clone)]
+pub struct PurgeSessionCommand {
+    pub session_id: SessionId,
+    pub user_id: UserId,
+}
+
+/// Handler for purging trashed sessions.
+pub struct PurgeSessionHandler {
+    repository: Arc<dyn SessionRepository>,
+    event_publisher: Arc<dyn EventPublisher>,
+    cold_storage: Option<Arc<SessionColdStorage>>,
+    auditor: CommandAuditor,
+}
+
+impl PurgeSessionHandler {
+    pub fn new(
+        repository: Arc<dyn SessionRepository>,
+        event_publisher: Arc<dyn EventPublisher>,
+    ) -> Self {
+        Self {
+            repository,
+            event_publisher,
+            cold_storage: None,
+            auditor: CommandAuditor::disabled(),
+        }
+    }
+
+    /// Records each command in the audit log.
+    pub fn with_auditor(mut self, auditor: CommandAuditor) -> Self {
+        self.auditor = auditor;
+        self
+    }
+
+    /// Also discards the cold storage snapshots of purged sessions.
+    pub fn with_cold_storage(mut self, cold_storage: Arc<SessionColdStorage>) -> Self {
+        self.cold_storage = Some(cold_storage);
+        self
+    }
+
+    #[tracing::instrument(name = "PurgeSessionHandler::handle", skip_all)]
+    pub async fn handle(
+        &self,
+        cmd: PurgeSessionCommand,
+        metadata: CommandMetadata,
+    ) -> Result<SessionPurged, SessionError> {
+        let session = self
+            .repository
+            .find_by_id(&cmd.session_id)
+            .await?
+            .ok_or_else(|| SessionError::not_found(cmd.session_id))?;
+
+        session.authorize(&cmd.user_id)?;
+        if !session.status().is_deleted() {
+            return Err(SessionError::invalid_state(
+                "Only sessions in the trash can be purged",
+            ));
+        }
+        self.remove(&cmd.session_id).await?;
+
+        let event = SessionPurged {
+            event_id: EventId::new(),
+            session_id: cmd.session_id,
+            user_id: cmd.user_id,
+            automatic: false,
+            purged_at: Timestamp::now(),
+        };
+
+        let envelope = event
+            .to_envelope()
+            .with_correlation_id(metadata.correlation_id())
+            .with_user_id(metadata.user_id.to_string());
+
+        self.event_publisher.publish(envelope).await?;
+
+        self.auditor
+            .record(
+                AuditRecord::new(&metadata, "PurgeSession", "session", cmd.session_id)
+                    .with_before(session.audit_summary()),
+            )
+            .await;
+
+        Ok(event)
+    }
+
+    /// Purges a session whose retention window has run out, with the owner
+    /// as the acting user and "scheduler" as the audit source. Returns
+    /// whether it was purged; sessions restored or already gone since being
+    /// listed are left alone.
+    #[tracing::instrument(name = "PurgeSessionHandler::purge_expired", skip_all, fields(session_id = %session_id))]
+    pub async fn purge_expired(&self, session_id: &SessionId) -> Result<bool, DomainError> {
+        let Some(session) = self.repository.find_by_id(session_id).await? else {
+            return Ok(false);
+        };
+        let now = Timestamp::now();
+        if !session.is_due_for_purge(&now) {
+            return Ok(false);
+        }
+        self.remove(session_id).await?;
+
+        let event = SessionPurged {
+            event_id: EventId::new(),
+            session_id: *session_id,
+            user_id: session.user_id().clone(),
+            automatic: true,
+            purged_at: now,
+        };
+        self.event_publisher
+            .publish(
+                event
+                    .to_envelope()
+                    .with_user_id(session.user_id().to_string()),
+            )
+            .await?;
+
+        let metadata = CommandMetadata::new(session.user_id().clone()).with_source("scheduler");
+        self.auditor
+            .record(
+                AuditRecord::new(&metadata, "PurgeSession", "session", session_id)
+                    .with_before(session.audit_summary()),
+            )
+            .await;
+        Ok(true)
+    }
+
+    /// Deletes the session; its cycles go with it.
+    async fn remove(&self, session_id: &SessionId) -> Result<(), DomainError> {
+        if let Some(cold_storage) = &self.cold_storage {
+            cold_storage.discard(session_id).await?;
+        }
+        self.repository.delete(session_id).await
+    }
+}
+
+/// Query for the user's trashed sessions.
+#[derive(Debug, Clone)]
+pub struct ListTrashedSessionsQuery {
+    pub user_id: UserId,
+}
+
+/// Handler for listing the user's trash.
+pub struct ListTrashedSessionsHandler {
+    reader: Arc<dyn SessionReader>,
+}
+
+impl ListTrashedSessionsHandler {
+    pub fn new(reader: Arc<dyn SessionReader>) -> Self {
+        Self { reader }
+    }
+
+    /// The user's trashed sessions, most recently deleted first.
+    #[tracing::instrument(name = "ListTrashedSessionsHandler::handle", skip_all)]
+    pub async fn handle(
+        &self,
+        query: ListTrashedSessionsQuery,
+    ) -> Result<Vec<TrashedSession>, SessionError> {
+        Ok(self.reader.list_trashed(&query.user_id).await?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::foundation::{EventEnvelope, SessionStatus};
+    use async_trait::async_trait;
+    use std::sync::Mutex;
+
+    #[derive(Default)]
+    struct MockSessionRepository {
+        sessions: Mutex<Vec<Session>>,
+    }
+
+    impl MockSessionRepository {
+        fn with_session(session: Session) -> Self {
+            Self {
+                sessions: Mutex::new(vec![session]),
+            }
+        }
+
+        fn get_session(&self, id: &SessionId) -> Option<Session> {
+            self.sessions
+                .lock()
+                .unwrap()
+                .iter()
+                .find(|s| s.id() == id)
+                .cloned()
+        }
+    }
+
+    #[async_trait]
+    impl SessionRepository for MockSessionRepository {
+        async fn save(&self, session: &Session) -> Result<(), DomainError> {
+            self.sessions.lock().unwrap().push(session.clone());
+            Ok(())
+        }
+
+        async fn update(&self, session: &Session) -> Result<(), DomainError> {
+            let mut sessions = self.sessions.lock().unwrap();
+            if let Some(pos) = sessions.iter().position(|s| s.id() == session.id()) {
+                sessions[pos] = session.clone();
+            }
+            Ok(())
+        }
+
+        async fn find_by_id(&self, id: &SessionId) -> Result<Option<Session>, DomainError> {
+            Ok(self.get_session(id))
+        }
+
+        async fn exists(&self, id: &SessionId) -> Result<bool, DomainError> {
+            Ok(self.get_session(id).is_some())
+        }
+
+        async fn find_by_user_id(&self, _user_id: &UserId) -> Result<Vec<Session>, DomainError> {
+            Ok(vec![])
+        }
+
+        async fn count_active_by_user(&self, _user_id: &UserId) -> Result<u32, DomainError> {
+            Ok(0)
+        }
+
+        async fn delete(&self, id: &SessionId) -> Result<(), DomainError> {
+            self.sessions.lock().unwrap().retain(|s| s.id() != id);
+            Ok(())
+        }
+    }
+
+    #[derive(Default)]
+    struct MockEventPublisher {
+        published_events: Mutex<Vec<EventEnvelope>>,
+    }
+
+    impl MockEventPublisher {
+        fn published_events(&self) -> Vec<EventEnvelope> {
+            self.published_events.lock().unwrap().clone()
+        }
+    }
+
+    #[async_trait]
+    impl EventPublisher for MockEventPublisher {
+        async fn publish(&self, event: EventEnvelope) -> Result<(), DomainError> {
+            self.published_events.lock().unwrap().push(event);
+            Ok(())
+        }
+
+        async fn publish_all(&self, events: Vec<EventEnvelope>) -> Result<(), DomainError> {
+            for event in events {
+                self.publish(event).await?;
+            }
+            Ok(())
+        }
+    }
+
+    fn test_user_id() -> UserId {
+        UserId::new("test-user-123").unwrap()
+    }
+
+    fn test_session() -> Session {
+        Session::new(SessionId::new(), test_user_id(), "Test Session".to_string()).unwrap()
+    }
+
+    /// A session deleted `days_ago` days ago.
+    fn trashed_session(days_ago: i64) -> Session {
+        test_session().with_deletion(Timestamp::now().minus_days(days_ago), SessionStatus::Active)
+    }
+
+    fn test_metadata() -> CommandMetadata {
+        CommandMetadata::new(test_user_id()).with_correlation_id("test-correlation")
+    }
+
+    fn delete_command(session_id: SessionId) -> DeleteSessionCommand {
+        DeleteSessionCommand {
+            session_id,
+            user_id: test_user_id(),
+        }
+    }
+
+    #[tokio::test]
+    async fn delete_moves_session_to_trash() {
+        let session = test_session();
+        let session_id = *session.id();
+        let repo = Arc::new(MockSessionRepository::with_session(session));
+        let publisher = Arc::new(MockEventPublisher::default());
+        let handler = DeleteSessionHandler::new(repo.clone(), publisher.clone());
+
+        let result = handler
+            .handle(delete_command(session_id), test_metadata())
+            .await
+            .unwrap();
+
+        let stored = repo.get_session(&session_id).unwrap();
+        assert_eq!(stored.status(), SessionStatus::Deleted);
+        assert_eq!(Some(result.event.purge_after), stored.purge_after());
+        let events = publisher.published_events();
+        assert_eq!(events[0].event_type, "session.deleted.v1");
+        assert_eq!(
+            events[0].metadata.correlation_id,
+            Some("test-correlation".to_string())
+        );
+    }
+
+    #[tokio::test]
+    async fn delete_fails_when_not_owner() {
+        let session = test_session();
+        let session_id = *session.id();
+        let repo = Arc::new(MockSessionRepository::with_session(session));
+        let publisher = Arc::new(MockEventPublisher::default());
+        let handler = DeleteSessionHandler::new(repo, publisher.clone());
+        let other_user = UserId::new("other-user").unwrap();
+
+        let result = handler
+            .handle(
+                DeleteSessionCommand {
+                    session_id,
+                    user_id: other_user.clone(),
+                },
+                CommandMetadata::new(other_user),
+            )
+            .await;
+
+        assert!(matches!(result, Err(SessionError::Forbidden)));
+        assert!(publisher.published_events().is_empty());
+    }
+
+    #[tokio::test]
+    async fn delete_fails_when_already_in_trash() {
+        let session = trashed_session(1);
+        let session_id = *session.id();
+        let repo = Arc::new(MockSessionRepository::with_session(session));
+        let handler = DeleteSessionHandler::new(repo, Arc::new(MockEventPublisher::default()));
+
+        let result = handler
+            .handle(delete_command(session_id), test_metadata())
+            .await;
+
+        assert!(matches!(result, Err(SessionError::InvalidState(_))));
+    }
+
+    #[tokio::test]
+    async fn restore_returns_session_to_previous_status() {
+        let session =
+            test_session().with_deletion(Timestamp::now().minus_days(3), SessionStatus::Archived);
+        let session_id = *session.id();
+        let repo = Arc::new(MockSessionRepository::with_session(session));
+        let publisher = Arc::new(MockEventPublisher::default());
+        let handler = RestoreSessionHandler::new(repo.clone(), publisher.clone());
+
+        let result = handler
+            .handle(
+                RestoreSessionCommand {
+                    session_id,
+                    user_id: test_user_id(),
+                },
+                test_metadata(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(result.event.status, SessionStatus::Archived);
+        let stored = repo.get_session(&session_id).unwrap();
+        assert_eq!(stored.status(), SessionStatus::Archived);
+        assert!(stored.deleted_at().is_none());
+        assert_eq!(
+            publisher.published_events()[0].event_type,
+            "session.restored.v1"
+        );
+    }
+
+    #[tokio::test]
+    async fn restore_fails_when_not_in_trash() {
+        let session = test_session();
+        let session_id = *session.id();
+        let repo = Arc::new(MockSessionRepository::with_session(session));
+        let handler = RestoreSessionHandler::new(repo, Arc::new(MockEventPublisher::default()));
+
+        let result = handler
+            .handle(
+                RestoreSessionCommand {
+                    session_id,
+                    user_id: test_user_id(),
+                },
+                test_metadata(),
+            )
+            .await;
+
+        assert!(matches!(result, Err(SessionError::InvalidState(_))));
+    }
+
+    #[tokio::test]
+    async fn purge_removes_trashed_session() {
+        let session = trashed_session(1);
+        let session_id = *session.id();
+        let repo = Arc::new(MockSessionRepository::with_session(session));
+        let publisher = Arc::new(MockEventPublisher::default());
+        let handler = PurgeSessionHandler::new(repo.clone(), publisher.clone());
+
+        let event = handler
+            .handle(
+                PurgeSessionCommand {
+                    session_id,
+                    user_id: test_user_id(),
+                },
+                test_metadata(),
+            )
+            .await
+            .unwrap();
+
+        assert!(!event.automatic);
+        assert!(repo.get_session(&session_id).is_none());
+        assert_eq!(
+            publisher.published_events()[0].event_type,
+            "session.purged.v1"
+        );
+    }
+
+    #[tokio::test]
+    async fn purge_refuses_sessions_outside_the_trash() {
+        let session = test_session();
+        let session_id = *session.id();
+        let repo = Arc::new(MockSessionRepository::with_session(session));
+        let handler =
+            PurgeSessionHandler::new(repo.clone(), Arc::new(MockEventPublisher::default()));
+
+        let result = handler
+            .handle(
+                PurgeSessionCommand {
+                    session_id,
+                    user_id: test_user_id(),
+                },
+                test_metadata(),
+            )
+            .await;
+
+        assert!(matches!(result, Err(SessionError::InvalidState(_))));
+        assert!(repo.get_session(&session_id).is_some());
+    }
+
+    #[tokio::test]
+    async fn purge_expired_only_removes_sessions_past_retention() {
+        let expired = trashed_session(31);
+        let recent = trashed_session(2);
+        let (expired_id, recent_id) = (*expired.id(), *recent.id());
+        let repo = Arc::new(MockSessionRepository::default());
+        repo.save(&expired).await.unwrap();
+        repo.save(&recent).await.unwrap();
+        let publisher = Arc::new(MockEventPublisher::default());
+        let handler = PurgeSessionHandler::new(repo.clone(), publisher.clone());
+
+        assert!(handler.purge_expired(&expired_id).await.unwrap());
+        assert!(!handler.purge_expired(&recent_id).await.unwrap());
+
+        assert!(repo.get_session(&expired_id).is_none());
+        assert!(repo.get_session(&recent_id).is_some());
+        let events = publisher.published_events();
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].metadata.user_id, Some(test_user_id().to_string()));
+    }
+}
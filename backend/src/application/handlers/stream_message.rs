@@ -448,6 +448,7 @@ where
     }
 
     /// Handles a streaming message command.
+    #[tracing::instrument(name = "StreamingMessageHandler::handle", skip_all)]
     pub async fn handle(
         &self,
         cmd: StreamMessageCommand,
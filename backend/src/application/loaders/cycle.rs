@@ -0,0 +1,294 @@
+//! RequestCycleReader - `CycleReader` that reads each view at most once.
+//!
+//! Handlers are built per request, so a handler given a fresh
+//! `RequestCycleReader` never repeats a read however many of its steps ask
+//! for the same cycle. Component outputs are batched: the first request for
+//! any component of a cycle loads all of that cycle's outputs in one read.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use crate::domain::cycle::CycleTreeNode as PrOACTTreeNode;
+use crate::domain::foundation::{ComponentType, CycleId, DomainError, OrganizationId, SessionId};
+use crate::ports::{
+    ComponentOutputView, CycleProgressView, CycleReader, CycleSummary, CycleTreeNode, CycleView,
+};
+
+use super::memo::Memo;
+
+type ComponentOutputs = Arc<HashMap<ComponentType, ComponentOutputView>>;
+
+/// Request-scoped memoizing wrapper around a `CycleReader`.
+pub struct RequestCycleReader {
+    inner: Arc<dyn CycleReader>,
+    views: Memo<CycleId, Option<CycleView>>,
+    session_cycles: Memo<SessionId, Vec<CycleSummary>>,
+    organization_cycles: Memo<OrganizationId, HashMap<SessionId, Vec<CycleSummary>>>,
+    trees: Memo<SessionId, Option<CycleTreeNode>>,
+    progress: Memo<CycleId, Option<CycleProgressView>>,
+    lineages: Memo<CycleId, Vec<CycleSummary>>,
+    components: Memo<CycleId, ComponentOutputs>,
+    proact_trees: Memo<SessionId, Option<PrOACTTreeNode>>,
+}
+
+impl RequestCycleReader {
+    /// Wraps `inner` for the lifetime of one request.
+    pub fn new(inner: Arc<dyn CycleReader>) -> Self {
+        Self {
+            inner,
+            views: Memo::new(),
+            session_cycles: Memo::new(),
+            organization_cycles: Memo::new(),
+            trees: Memo::new(),
+            progress: Memo::new(),
+            lineages: Memo::new(),
+            components: Memo::new(),
+            proact_trees: Memo::new(),
+        }
+    }
+
+    /// Every component output of the cycle, loaded in one batch.
+    async fn components(&self, cycle_id: &CycleId) -> Result<ComponentOutputs, DomainError> {
+        self.components
+            .get_or_load(*cycle_id, || async {
+                self.inner
+                    .get_component_outputs(cycle_id, ComponentType::all())
+                    .await
+                    .map(Arc::new)
+            })
+            .await
+    }
+}
+
+#[async_trait]
+impl CycleReader for RequestCycleReader {
+    async fn get_by_id(&self, id: &CycleId) -> Result<Option<CycleView>, DomainError> {
+        self.views
+            .get_or_load(*id, || self.inner.get_by_id(id))
+            .await
+    }
+
+    async fn list_by_session_id(
+        &self,
+        session_id: &SessionId,
+    ) -> Result<Vec<CycleSummary>, DomainError> {
+        self.session_cycles
+            .get_or_load(*session_id, || self.inner.list_by_session_id(session_id))
+            .await
+    }
+
+    async fn list_by_organization(
+        &self,
+        organization_id: &OrganizationId,
+    ) -> Result<HashMap<SessionId, Vec<CycleSummary>>, DomainError> {
+        self.organization_cycles
+            .get_or_load(*organization_id, || {
+                self.inner.list_by_organization(organization_id)
+            })
+            .await
+    }
+
+    async fn get_tree(&self, session_id: &SessionId) -> Result<Option<CycleTreeNode>, DomainError> {
+        self.trees
+            .get_or_load(*session_id, || self.inner.get_tree(session_id))
+            .await
+    }
+
+    async fn get_progress(&self, id: &CycleId) -> Result<Option<CycleProgressView>, DomainError> {
+        self.progress
+            .get_or_load(*id, || self.inner.get_progress(id))
+            .await
+    }
+
+    async fn get_lineage(&self, id: &CycleId) -> Result<Vec<CycleSummary>, DomainError> {
+        self.lineages
+            .get_or_load(*id, || self.inner.get_lineage(id))
+            .await
+    }
+
+    async fn get_component_output(
+        &self,
+        cycle_id: &CycleId,
+        component_type: ComponentType,
+    ) -> Result<Option<ComponentOutputView>, DomainError> {
+        Ok(self
+            .components(cycle_id)
+            .await?
+            .get(&component_type)
+            .cloned())
+    }
+
+    async fn get_component_outputs(
+        &self,
+        cycle_id: &CycleId,
+        component_types: &[ComponentType],
+    ) -> Result<HashMap<ComponentType, ComponentOutputView>, DomainError> {
+        let outputs = self.components(cycle_id).await?;
+        Ok(component_types
+            .iter()
+            .filter_map(|ct| outputs.get(ct).map(|view| (*ct, view.clone())))
+            .collect())
+    }
+
+    async fn get_proact_tree_view(
+        &self,
+        session_id: &SessionId,
+    ) -> Result<Option<PrOACTTreeNode>, DomainError> {
+        self.proact_trees
+            .get_or_load(*session_id, || self.inner.get_proact_tree_view(session_id))
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Mutex;
+
+    use crate::domain::foundation::{ComponentStatus, Timestamp};
+
+    /// Records every call that reaches the underlying reader.
+    #[derive(Default)]
+    struct CountingCycleReader {
+        calls: Mutex<Vec<&'static str>>,
+    }
+
+    impl CountingCycleReader {
+        fn record(&self, call: &'static str) {
+            self.calls.lock().unwrap().push(call);
+        }
+
+        fn calls(&self) -> Vec<&'static str> {
+            self.calls.lock().unwrap().clone()
+        }
+    }
+
+    #[async_trait]
+    impl CycleReader for CountingCycleReader {
+        async fn get_by_id(&self, _id: &CycleId) -> Result<Option<CycleView>, DomainError> {
+            self.record("get_by_id");
+            Ok(None)
+        }
+
+        async fn list_by_session_id(
+            &self,
+            _session_id: &SessionId,
+        ) -> Result<Vec<CycleSummary>, DomainError> {
+            self.record("list_by_session_id");
+            Ok(vec![])
+        }
+
+        async fn list_by_organization(
+            &self,
+            _organization_id: &OrganizationId,
+        ) -> Result<HashMap<SessionId, Vec<CycleSummary>>, DomainError> {
+            self.record("list_by_organization");
+            Ok(HashMap::new())
+        }
+
+        async fn get_tree(
+            &self,
+            _session_id: &SessionId,
+        ) -> Result<Option<CycleTreeNode>, DomainError> {
+            self.record("get_tree");
+            Ok(None)
+        }
+
+        async fn get_progress(
+            &self,
+            _id: &CycleId,
+        ) -> Result<Option<CycleProgressView>, DomainError> {
+            self.record("get_progress");
+            Ok(None)
+        }
+
+        async fn get_lineage(&self, _id: &CycleId) -> Result<Vec<CycleSummary>, DomainError> {
+            self.record("get_lineage");
+            Ok(vec![])
+        }
+
+        async fn get_component_output(
+            &self,
+            _cycle_id: &CycleId,
+            _component_type: ComponentType,
+        ) -> Result<Option<ComponentOutputView>, DomainError> {
+            self.record("get_component_output");
+            Ok(None)
+        }
+
+        async fn get_component_outputs(
+            &self,
+            cycle_id: &CycleId,
+            component_types: &[ComponentType],
+        ) -> Result<HashMap<ComponentType, ComponentOutputView>, DomainError> {
+            self.record("get_component_outputs");
+            Ok(component_types
+                .iter()
+                .filter(|ct| **ct != ComponentType::NotesNextSteps)
+                .map(|&component_type| {
+                    let view = ComponentOutputView {
+                        cycle_id: *cycle_id,
+                        component_type,
+                        status: ComponentStatus::Complete,
+                        output: serde_json::json!({}),
+                        updated_at: Timestamp::now(),
+                    };
+                    (component_type, view)
+                })
+                .collect())
+        }
+
+        async fn get_proact_tree_view(
+            &self,
+            _session_id: &SessionId,
+        ) -> Result<Option<PrOACTTreeNode>, DomainError> {
+            self.record("get_proact_tree_view");
+            Ok(None)
+        }
+    }
+
+    #[tokio::test]
+    async fn repeated_reads_reach_the_inner_reader_once() {
+        let inner = Arc::new(CountingCycleReader::default());
+        let reader = RequestCycleReader::new(inner.clone());
+        let (cycle_id, session_id) = (CycleId::new(), SessionId::new());
+
+        for _ in 0..3 {
+            reader.get_by_id(&cycle_id).await.unwrap();
+            reader.get_tree(&session_id).await.unwrap();
+        }
+        reader.get_by_id(&CycleId::new()).await.unwrap();
+
+        assert_eq!(inner.calls(), vec!["get_by_id", "get_tree", "get_by_id"]);
+    }
+
+    #[tokio::test]
+    async fn component_outputs_are_batched_per_cycle() {
+        let inner = Arc::new(CountingCycleReader::default());
+        let reader = RequestCycleReader::new(inner.clone());
+        let cycle_id = CycleId::new();
+
+        let recommendation = reader
+            .get_component_output(&cycle_id, ComponentType::Recommendation)
+            .await
+            .unwrap();
+        let notes = reader
+            .get_component_output(&cycle_id, ComponentType::NotesNextSteps)
+            .await
+            .unwrap();
+        let pair = reader
+            .get_component_outputs(
+                &cycle_id,
+                &[ComponentType::Objectives, ComponentType::NotesNextSteps],
+            )
+            .await
+            .unwrap();
+
+        assert!(recommendation.is_some());
+        assert!(notes.is_none());
+        assert_eq!(pair.len(), 1);
+        assert_eq!(inner.calls(), vec!["get_component_outputs"]);
+    }
+}
@@ -0,0 +1,77 @@
+//! RequestDashboardReader - `DashboardReader` that builds each view at
+//! most once per request.
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use crate::domain::dashboard::{ComponentDetailView, CycleComparison, DashboardOverview};
+use crate::domain::foundation::{ComponentType, CycleId, SessionId, UserId};
+use crate::ports::{DashboardError, DashboardReader};
+
+use super::memo::Memo;
+
+/// Request-scoped memoizing wrapper around a `DashboardReader`.
+///
+/// Views are keyed by the requesting user as well, since the inner reader
+/// checks ownership.
+pub struct RequestDashboardReader {
+    inner: Arc<dyn DashboardReader>,
+    overviews: Memo<(SessionId, Option<CycleId>, UserId), DashboardOverview>,
+    details: Memo<(CycleId, ComponentType, UserId), ComponentDetailView>,
+    comparisons: Memo<(Vec<CycleId>, UserId), CycleComparison>,
+}
+
+impl RequestDashboardReader {
+    /// Wraps `inner` for the lifetime of one request.
+    pub fn new(inner: Arc<dyn DashboardReader>) -> Self {
+        Self {
+            inner,
+            overviews: Memo::new(),
+            details: Memo::new(),
+            comparisons: Memo::new(),
+        }
+    }
+}
+
+#[async_trait]
+impl DashboardReader for RequestDashboardReader {
+    async fn get_overview(
+        &self,
+        session_id: SessionId,
+        cycle_id: Option<CycleId>,
+        user_id: &UserId,
+    ) -> Result<DashboardOverview, DashboardError> {
+        self.overviews
+            .get_or_load((session_id, cycle_id, user_id.clone()), || {
+                self.inner.get_overview(session_id, cycle_id, user_id)
+            })
+            .await
+    }
+
+    async fn get_component_detail(
+        &self,
+        cycle_id: CycleId,
+        component_type: ComponentType,
+        user_id: &UserId,
+    ) -> Result<ComponentDetailView, DashboardError> {
+        self.details
+            .get_or_load((cycle_id, component_type, user_id.clone()), || {
+                self.inner
+                    .get_component_detail(cycle_id, component_type, user_id)
+            })
+            .await
+    }
+
+    async fn compare_cycles(
+        &self,
+        cycle_ids: &[CycleId],
+        user_id: &UserId,
+    ) -> Result<CycleComparison, DashboardError> {
+        self.comparisons
+            .get_or_load((cycle_ids.to_vec(), user_id.clone()), || {
+                self.inner.compare_cycles(cycle_ids, user_id)
+            })
+            .await
+    }
+}
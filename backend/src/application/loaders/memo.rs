@@ -0,0 +1,62 @@
+//! Memo - Per-key single-flight memoization.
+
+use std::collections::HashMap;
+use std::future::Future;
+use std::hash::Hash;
+use std::sync::{Arc, Mutex};
+
+use tokio::sync::OnceCell;
+
+/// Remembers one loaded value per key.
+///
+/// Concurrent loads of the same key wait on a single call. A failed load is
+/// not remembered, so the next caller retries it.
+pub(super) struct Memo<K, V> {
+    cells: Mutex<HashMap<K, Arc<OnceCell<V>>>>,
+}
+
+impl<K: Eq + Hash, V: Clone> Memo<K, V> {
+    pub fn new() -> Self {
+        Self {
+            cells: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// The value for `key`, running `load` only if no earlier call succeeded.
+    pub async fn get_or_load<E, F, Fut>(&self, key: K, load: F) -> Result<V, E>
+    where
+        F: FnOnce() -> Fut,
+        Fut: Future<Output = Result<V, E>>,
+    {
+        let cell = self
+            .cells
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .entry(key)
+            .or_default()
+            .clone();
+        cell.get_or_try_init(load).await.cloned()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicU32, Ordering};
+
+    #[tokio::test]
+    async fn loads_each_key_once_and_retries_failures() {
+        let memo: Memo<u32, u32> = Memo::new();
+        let calls = AtomicU32::new(0);
+        let load = |value: Result<u32, ()>| {
+            calls.fetch_add(1, Ordering::SeqCst);
+            async move { value }
+        };
+
+        assert_eq!(memo.get_or_load(1, || load(Err(()))).await, Err(()));
+        assert_eq!(memo.get_or_load(1, || load(Ok(10))).await, Ok(10));
+        assert_eq!(memo.get_or_load(1, || load(Ok(20))).await, Ok(10));
+        assert_eq!(memo.get_or_load(2, || load(Ok(20))).await, Ok(20));
+        assert_eq!(calls.load(Ordering::SeqCst), 3);
+    }
+}
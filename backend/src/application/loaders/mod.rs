@@ -0,0 +1,19 @@
+//! Request-scoped loaders.
+//!
+//! Reader wrappers that memoize every read for the lifetime of one request
+//! (the dataloader pattern). Wrap a shared reader in a fresh loader each
+//! time a handler is built for a request:
+//!
+//! ```ignore
+//! let reader: Arc<dyn CycleReader> =
+//!     Arc::new(RequestCycleReader::new(state.cycle_reader.clone()));
+//! ```
+//!
+//! Never share a loader across requests; it does not see later writes.
+
+mod cycle;
+mod dashboard;
+mod memo;
+
+pub use cycle::RequestCycleReader;
+pub use dashboard::RequestDashboardReader;
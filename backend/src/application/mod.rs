@@ -2,8 +2,10 @@
 //!
 //! This layer orchestrates domain operations and coordinates between ports.
 //! Following CQRS, it separates command handlers (write) from query handlers (read).
+//! `loaders` memoizes reads within a single request.
 
 pub mod handlers;
+pub mod loaders;
 
 pub use handlers::{
     // Session handlers
@@ -1,9 +1,11 @@
 //! AI provider configuration
 
 use serde::Deserialize;
+use std::collections::{BTreeMap, HashMap};
 use std::time::Duration;
 
 use super::error::ValidationError;
+use crate::domain::foundation::ComponentType;
 
 /// AI provider configuration
 #[derive(Debug, Clone, Deserialize)]
@@ -14,6 +16,43 @@ pub struct AiConfig {
     /// Anthropic API key
     pub anthropic_api_key: Option<String>,
 
+    /// Azure OpenAI resource endpoint (e.g. `https://my-resource.openai.azure.com`)
+    pub azure_openai_endpoint: Option<String>,
+
+    /// Azure OpenAI API key
+    pub azure_openai_api_key: Option<String>,
+
+    /// Azure OpenAI deployment used by default
+    pub azure_openai_deployment: Option<String>,
+
+    /// Azure OpenAI deployments to use for specific components, keyed by
+    /// component name (e.g. `consequences = "gpt4o-high-quota"`)
+    #[serde(default)]
+    pub azure_openai_component_deployments: HashMap<ComponentType, String>,
+
+    /// Azure OpenAI data-plane API version
+    #[serde(default = "default_azure_openai_api_version")]
+    pub azure_openai_api_version: String,
+
+    /// Model behind the Azure deployments, used for pricing
+    #[serde(default = "default_azure_openai_model")]
+    pub azure_openai_model: String,
+
+    /// Google Gemini API key
+    pub gemini_api_key: Option<String>,
+
+    /// Base URL of a self-hosted Ollama server (e.g. `http://localhost:11434`)
+    pub ollama_base_url: Option<String>,
+
+    /// Default Ollama model tag
+    #[serde(default = "default_ollama_model")]
+    pub ollama_model: String,
+
+    /// Ollama model tags to use for specific components, keyed by component
+    /// name (e.g. `consequences = "qwen2.5:32b"`)
+    #[serde(default)]
+    pub ollama_component_models: HashMap<ComponentType, String>,
+
     /// Primary AI provider
     #[serde(default = "default_provider")]
     pub primary_provider: AiProvider,
@@ -28,6 +67,26 @@ pub struct AiConfig {
     /// Maximum retries on failure
     #[serde(default = "default_retries")]
     pub max_retries: u32,
+
+    /// Per-model price overrides for cost attribution, keyed by model name
+    /// prefix (e.g. `claude-sonnet-4`). Unlisted models use built-in list prices.
+    #[serde(default)]
+    pub price_overrides: BTreeMap<String, ModelPriceOverride>,
+
+    /// How long identical completions are served from the response cache.
+    /// Unset disables the cache.
+    pub response_cache_ttl_secs: Option<u64>,
+
+    /// Response cache key namespace; change it to invalidate every entry
+    #[serde(default = "default_response_cache_namespace")]
+    pub response_cache_namespace: String,
+}
+
+/// Price of a model in cents per million tokens
+#[derive(Debug, Clone, Copy, Deserialize, PartialEq, Eq)]
+pub struct ModelPriceOverride {
+    pub input_cents_per_mtok: u64,
+    pub output_cents_per_mtok: u64,
 }
 
 /// AI provider type
@@ -35,8 +94,12 @@ pub struct AiConfig {
 #[serde(rename_all = "lowercase")]
 pub enum AiProvider {
     OpenAI,
+    #[serde(rename = "azure_openai")]
+    AzureOpenAI,
     #[default]
     Anthropic,
+    Gemini,
+    Ollama,
 }
 
 impl AiConfig {
@@ -45,6 +108,13 @@ impl AiConfig {
         Duration::from_secs(self.timeout_secs)
     }
 
+    /// Response cache TTL, if the cache is enabled
+    pub fn response_cache_ttl(&self) -> Option<Duration> {
+        self.response_cache_ttl_secs
+            .filter(|secs| *secs > 0)
+            .map(Duration::from_secs)
+    }
+
     /// Check if OpenAI is configured
     pub fn has_openai(&self) -> bool {
         self.openai_api_key.as_ref().is_some_and(|k| !k.is_empty())
@@ -55,10 +125,36 @@ impl AiConfig {
         self.anthropic_api_key.as_ref().is_some_and(|k| !k.is_empty())
     }
 
+    /// Check if Azure OpenAI is configured (endpoint, key and deployment)
+    pub fn has_azure_openai(&self) -> bool {
+        [
+            &self.azure_openai_endpoint,
+            &self.azure_openai_api_key,
+            &self.azure_openai_deployment,
+        ]
+        .iter()
+        .all(|v| v.as_ref().is_some_and(|v| !v.is_empty()))
+    }
+
+    /// Check if Gemini is configured
+    pub fn has_gemini(&self) -> bool {
+        self.gemini_api_key.as_ref().is_some_and(|k| !k.is_empty())
+    }
+
+    /// Check if a local Ollama server is configured
+    pub fn has_ollama(&self) -> bool {
+        self.ollama_base_url.as_ref().is_some_and(|u| !u.is_empty())
+    }
+
     /// Validate AI configuration
     pub fn validate(&self) -> Result<(), ValidationError> {
-        // At least one provider must have an API key
-        if !self.has_openai() && !self.has_anthropic() {
+        // At least one provider must have an API key (or a local server)
+        if !self.has_openai()
+            && !self.has_azure_openai()
+            && !self.has_anthropic()
+            && !self.has_gemini()
+            && !self.has_ollama()
+        {
             return Err(ValidationError::NoAiProviderConfigured);
         }
 
@@ -67,9 +163,25 @@ impl AiConfig {
             AiProvider::OpenAI if !self.has_openai() => {
                 return Err(ValidationError::MissingRequired("OPENAI_API_KEY"));
             }
+            AiProvider::AzureOpenAI if !self.has_azure_openai() => {
+                let missing = if self.azure_openai_endpoint.as_ref().is_none_or(|v| v.is_empty()) {
+                    "AZURE_OPENAI_ENDPOINT"
+                } else if self.azure_openai_api_key.as_ref().is_none_or(|v| v.is_empty()) {
+                    "AZURE_OPENAI_API_KEY"
+                } else {
+                    "AZURE_OPENAI_DEPLOYMENT"
+                };
+                return Err(ValidationError::MissingRequired(missing));
+            }
             AiProvider::Anthropic if !self.has_anthropic() => {
                 return Err(ValidationError::MissingRequired("ANTHROPIC_API_KEY"));
             }
+            AiProvider::Gemini if !self.has_gemini() => {
+                return Err(ValidationError::MissingRequired("GEMINI_API_KEY"));
+            }
+            AiProvider::Ollama if !self.has_ollama() => {
+                return Err(ValidationError::MissingRequired("OLLAMA_BASE_URL"));
+            }
             _ => {}
         }
 
@@ -82,10 +194,23 @@ impl Default for AiConfig {
         Self {
             openai_api_key: None,
             anthropic_api_key: None,
+            azure_openai_endpoint: None,
+            azure_openai_api_key: None,
+            azure_openai_deployment: None,
+            azure_openai_component_deployments: HashMap::new(),
+            azure_openai_api_version: default_azure_openai_api_version(),
+            azure_openai_model: default_azure_openai_model(),
+            gemini_api_key: None,
+            ollama_base_url: None,
+            ollama_model: default_ollama_model(),
+            ollama_component_models: HashMap::new(),
             primary_provider: default_provider(),
             fallback_provider: None,
             timeout_secs: default_timeout(),
             max_retries: default_retries(),
+            price_overrides: BTreeMap::new(),
+            response_cache_ttl_secs: None,
+            response_cache_namespace: default_response_cache_namespace(),
         }
     }
 }
@@ -94,6 +219,22 @@ fn default_provider() -> AiProvider {
     AiProvider::Anthropic
 }
 
+fn default_azure_openai_api_version() -> String {
+    "2024-10-21".to_string()
+}
+
+fn default_azure_openai_model() -> String {
+    "gpt-4o".to_string()
+}
+
+fn default_ollama_model() -> String {
+    "llama3.1:8b".to_string()
+}
+
+fn default_response_cache_namespace() -> String {
+    "v1".to_string()
+}
+
 fn default_timeout() -> u64 {
     120
 }
@@ -114,6 +255,23 @@ mod tests {
         assert_eq!(config.max_retries, 3);
     }
 
+    #[test]
+    fn test_response_cache_ttl() {
+        assert_eq!(AiConfig::default().response_cache_ttl(), None);
+
+        let config = AiConfig {
+            response_cache_ttl_secs: Some(0),
+            ..Default::default()
+        };
+        assert_eq!(config.response_cache_ttl(), None);
+
+        let config = AiConfig {
+            response_cache_ttl_secs: Some(600),
+            ..Default::default()
+        };
+        assert_eq!(config.response_cache_ttl(), Some(Duration::from_secs(600)));
+    }
+
     #[test]
     fn test_timeout_duration() {
         let config = AiConfig {
@@ -134,6 +292,24 @@ mod tests {
         assert!(!config.has_anthropic());
     }
 
+    #[test]
+    fn test_price_overrides_deserialization() {
+        let json = r#"{
+            "anthropic_api_key": "sk-ant-xxx",
+            "price_overrides": {
+                "claude-sonnet-4": {"input_cents_per_mtok": 250, "output_cents_per_mtok": 1250}
+            }
+        }"#;
+        let config: AiConfig = serde_json::from_str(json).unwrap();
+        assert_eq!(
+            config.price_overrides.get("claude-sonnet-4"),
+            Some(&ModelPriceOverride {
+                input_cents_per_mtok: 250,
+                output_cents_per_mtok: 1250,
+            })
+        );
+    }
+
     #[test]
     fn test_validation_no_provider() {
         let config = AiConfig::default();
@@ -161,6 +337,83 @@ mod tests {
         assert!(config.validate().is_ok());
     }
 
+    #[test]
+    fn test_validation_gemini_primary() {
+        let config = AiConfig {
+            primary_provider: AiProvider::Gemini,
+            gemini_api_key: Some("AIza-xxx".to_string()),
+            ..Default::default()
+        };
+        assert!(config.has_gemini());
+        assert!(config.validate().is_ok());
+
+        let missing = AiConfig {
+            primary_provider: AiProvider::Gemini,
+            anthropic_api_key: Some("sk-ant-xxx".to_string()),
+            ..Default::default()
+        };
+        assert!(missing.validate().is_err());
+    }
+
+    #[test]
+    fn test_gemini_provider_deserialization() {
+        let json = r#"{"gemini_api_key": "AIza-xxx", "primary_provider": "gemini"}"#;
+        let config: AiConfig = serde_json::from_str(json).unwrap();
+        assert_eq!(config.primary_provider, AiProvider::Gemini);
+    }
+
+    #[test]
+    fn test_ollama_needs_no_api_key() {
+        let json = r#"{
+            "primary_provider": "ollama",
+            "ollama_base_url": "http://localhost:11434",
+            "ollama_component_models": {"consequences": "qwen2.5:32b"}
+        }"#;
+        let config: AiConfig = serde_json::from_str(json).unwrap();
+        assert!(config.validate().is_ok());
+        assert_eq!(config.ollama_model, "llama3.1:8b");
+        assert_eq!(
+            config
+                .ollama_component_models
+                .get(&ComponentType::Consequences)
+                .map(String::as_str),
+            Some("qwen2.5:32b")
+        );
+
+        let missing = AiConfig {
+            primary_provider: AiProvider::Ollama,
+            anthropic_api_key: Some("sk-ant-xxx".to_string()),
+            ..Default::default()
+        };
+        assert!(missing.validate().is_err());
+    }
+
+    #[test]
+    fn test_azure_openai_config() {
+        let json = r#"{
+            "primary_provider": "azure_openai",
+            "azure_openai_endpoint": "https://my-resource.openai.azure.com",
+            "azure_openai_api_key": "azure-key",
+            "azure_openai_deployment": "gpt4o-prod",
+            "azure_openai_component_deployments": {"consequences": "gpt4o-high-quota"}
+        }"#;
+        let config: AiConfig = serde_json::from_str(json).unwrap();
+        assert!(config.has_azure_openai());
+        assert!(config.validate().is_ok());
+        assert_eq!(config.azure_openai_api_version, "2024-10-21");
+        assert_eq!(config.azure_openai_model, "gpt-4o");
+
+        let missing_deployment = AiConfig {
+            azure_openai_deployment: None,
+            openai_api_key: Some("sk-xxx".to_string()),
+            ..config
+        };
+        assert!(matches!(
+            missing_deployment.validate(),
+            Err(ValidationError::MissingRequired("AZURE_OPENAI_DEPLOYMENT"))
+        ));
+    }
+
     #[test]
     fn test_validation_with_fallback() {
         let config = AiConfig {
@@ -0,0 +1,151 @@
+//! Automatic session archival configuration
+
+use serde::Deserialize;
+use std::time::Duration;
+
+use super::error::ValidationError;
+
+/// Per-tier auto-archive rules for idle sessions
+///
+/// A tier's idle period of 0 means its sessions are never archived.
+#[derive(Debug, Clone, Deserialize)]
+pub struct ArchivalConfig {
+    /// Run the auto-archive job
+    #[serde(default = "default_enabled")]
+    pub enabled: bool,
+
+    /// Days without activity before free-tier sessions are archived
+    #[serde(default = "default_free_idle_days")]
+    pub free_idle_days: u32,
+
+    /// Days without activity before monthly-tier sessions are archived
+    #[serde(default = "default_monthly_idle_days")]
+    pub monthly_idle_days: u32,
+
+    /// Days without activity before annual-tier sessions are archived
+    #[serde(default)]
+    pub annual_idle_days: u32,
+
+    /// Days of notice the owner gets before a session is archived
+    #[serde(default = "default_warning_days")]
+    pub warning_days: u32,
+
+    /// Months after archival before a finished session's cycles move to
+    /// cold storage; 0 keeps them in the database
+    #[serde(default = "default_cold_storage_after_months")]
+    pub cold_storage_after_months: u32,
+
+    /// How often idle sessions are checked, in seconds
+    #[serde(default = "default_poll_interval")]
+    pub poll_interval_secs: u64,
+}
+
+impl ArchivalConfig {
+    /// Get the poll interval as Duration
+    pub fn poll_interval(&self) -> Duration {
+        Duration::from_secs(self.poll_interval_secs)
+    }
+
+    /// Idle periods of the tiers that archive, as `(tier, days)` pairs
+    pub fn tier_idle_days(&self) -> Vec<(&'static str, u32)> {
+        [
+            ("free", self.free_idle_days),
+            ("monthly", self.monthly_idle_days),
+            ("annual", self.annual_idle_days),
+        ]
+        .into_iter()
+        .filter(|(_, days)| self.enabled && *days > 0)
+        .collect()
+    }
+
+    /// Validate archival configuration
+    pub fn validate(&self) -> Result<(), ValidationError> {
+        if self
+            .tier_idle_days()
+            .iter()
+            .any(|(_, days)| *days <= self.warning_days)
+        {
+            return Err(ValidationError::InvalidArchivalPeriod);
+        }
+        if self.poll_interval_secs == 0 {
+            return Err(ValidationError::InvalidTimeout);
+        }
+        Ok(())
+    }
+}
+
+impl Default for ArchivalConfig {
+    fn default() -> Self {
+        Self {
+            enabled: default_enabled(),
+            free_idle_days: default_free_idle_days(),
+            monthly_idle_days: default_monthly_idle_days(),
+            annual_idle_days: 0,
+            warning_days: default_warning_days(),
+            cold_storage_after_months: default_cold_storage_after_months(),
+            poll_interval_secs: default_poll_interval(),
+        }
+    }
+}
+
+fn default_enabled() -> bool {
+    true
+}
+
+fn default_free_idle_days() -> u32 {
+    180
+}
+
+fn default_monthly_idle_days() -> u32 {
+    365
+}
+
+fn default_warning_days() -> u32 {
+    14
+}
+
+fn default_cold_storage_after_months() -> u32 {
+    12
+}
+
+fn default_poll_interval() -> u64 {
+    3600
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_archival_config_defaults() {
+        let config = ArchivalConfig::default();
+        assert_eq!(
+            config.tier_idle_days(),
+            vec![("free", 180), ("monthly", 365)]
+        );
+        assert_eq!(config.cold_storage_after_months, 12);
+        assert_eq!(config.poll_interval(), Duration::from_secs(3600));
+        assert!(config.validate().is_ok());
+    }
+
+    #[test]
+    fn test_disabled_archives_nothing() {
+        let config = ArchivalConfig {
+            enabled: false,
+            ..Default::default()
+        };
+        assert!(config.tier_idle_days().is_empty());
+    }
+
+    #[test]
+    fn test_validation_rejects_period_shorter_than_notice() {
+        let config = ArchivalConfig {
+            annual_idle_days: 7,
+            ..Default::default()
+        };
+        assert!(matches!(
+            config.validate(),
+            Err(ValidationError::InvalidArchivalPeriod)
+        ));
+    }
+}
@@ -6,18 +6,64 @@ use std::time::Duration;
 use super::error::ValidationError;
 use super::server::Environment;
 
-/// Authentication configuration (Zitadel OIDC)
+/// Identity provider that issues access tokens
+#[derive(Debug, Clone, Copy, Deserialize, PartialEq, Eq, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum IdentityProvider {
+    /// Zitadel, using its fixed JWKS endpoint and claim names
+    #[default]
+    Zitadel,
+    /// Any OIDC provider (Keycloak, Auth0, ...) via discovery
+    Oidc,
+}
+
+/// Authentication configuration (Zitadel or generic OIDC)
+///
+/// Only the selected provider's settings are required.
 #[derive(Debug, Clone, Deserialize)]
 pub struct AuthConfig {
+    /// Identity provider
+    #[serde(default)]
+    pub provider: IdentityProvider,
+
     /// Zitadel authority URL
+    #[serde(default)]
     pub zitadel_authority: String,
 
     /// OAuth2 client ID
+    #[serde(default)]
     pub zitadel_client_id: String,
 
     /// Expected audience for tokens
+    #[serde(default)]
     pub zitadel_audience: String,
 
+    /// OIDC issuer URL; discovery is read from
+    /// `{issuer}/.well-known/openid-configuration`
+    #[serde(default)]
+    pub oidc_issuer: String,
+
+    /// Expected audience for OIDC tokens
+    #[serde(default)]
+    pub oidc_audience: String,
+
+    /// Claim holding the user ID (dotted paths reach nested claims)
+    #[serde(default = "default_user_id_claim")]
+    pub oidc_user_id_claim: String,
+
+    /// Claim holding the user's email address
+    #[serde(default = "default_email_claim")]
+    pub oidc_email_claim: String,
+
+    /// Claim holding the user's display name
+    #[serde(default = "default_name_claim")]
+    pub oidc_name_claim: String,
+
+    /// Comma-separated OAuth client IDs allowed to authenticate as service
+    /// accounts with client-credentials tokens
+    #[serde(default)]
+    pub service_client_ids: Option<String>,
+
     /// JWKS cache TTL in seconds
     #[serde(default = "default_jwks_cache_ttl")]
     pub jwks_cache_ttl_secs: u64,
@@ -29,14 +75,33 @@ impl AuthConfig {
         Duration::from_secs(self.jwks_cache_ttl_secs)
     }
 
-    /// Get the issuer URL (alias for zitadel_authority for clarity)
+    /// Get service account client IDs as a vector
+    pub fn service_client_ids_list(&self) -> Vec<String> {
+        self.service_client_ids
+            .as_ref()
+            .map(|s| {
+                s.split(',')
+                    .map(|s| s.trim().to_string())
+                    .filter(|s| !s.is_empty())
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    /// Get the issuer URL of the selected provider
     pub fn issuer_url(&self) -> &str {
-        &self.zitadel_authority
+        match self.provider {
+            IdentityProvider::Zitadel => &self.zitadel_authority,
+            IdentityProvider::Oidc => &self.oidc_issuer,
+        }
     }
 
-    /// Get the expected audience
+    /// Get the expected audience of the selected provider
     pub fn audience(&self) -> &str {
-        &self.zitadel_audience
+        match self.provider {
+            IdentityProvider::Zitadel => &self.zitadel_audience,
+            IdentityProvider::Oidc => &self.oidc_audience,
+        }
     }
 
     /// Validate authentication configuration
@@ -44,6 +109,20 @@ impl AuthConfig {
     /// In production, requires HTTPS for the authority URL.
     /// In development, allows localhost with HTTP/HTTPS.
     pub fn validate(&self, environment: &Environment) -> Result<(), ValidationError> {
+        match self.provider {
+            IdentityProvider::Zitadel => self.validate_zitadel()?,
+            IdentityProvider::Oidc => self.validate_oidc()?,
+        }
+
+        // In production, require HTTPS
+        if *environment == Environment::Production && !self.issuer_url().starts_with("https://") {
+            return Err(ValidationError::AuthorityMustBeHttps);
+        }
+
+        Ok(())
+    }
+
+    fn validate_zitadel(&self) -> Result<(), ValidationError> {
         if self.zitadel_authority.is_empty() {
             return Err(ValidationError::MissingRequired("ZITADEL_AUTHORITY"));
         }
@@ -53,12 +132,22 @@ impl AuthConfig {
         if self.zitadel_audience.is_empty() {
             return Err(ValidationError::MissingRequired("ZITADEL_AUDIENCE"));
         }
+        Ok(())
+    }
 
-        // In production, require HTTPS
-        if *environment == Environment::Production && !self.zitadel_authority.starts_with("https://") {
-            return Err(ValidationError::AuthorityMustBeHttps);
+    fn validate_oidc(&self) -> Result<(), ValidationError> {
+        if self.oidc_issuer.is_empty() {
+            return Err(ValidationError::MissingRequired("OIDC_ISSUER"));
+        }
+        if self.oidc_audience.is_empty() {
+            return Err(ValidationError::MissingRequired("OIDC_AUDIENCE"));
+        }
+        if self.oidc_user_id_claim.is_empty() {
+            return Err(ValidationError::MissingRequired("OIDC_USER_ID_CLAIM"));
+        }
+        if self.oidc_email_claim.is_empty() {
+            return Err(ValidationError::MissingRequired("OIDC_EMAIL_CLAIM"));
         }
-
         Ok(())
     }
 }
@@ -66,9 +155,16 @@ impl AuthConfig {
 impl Default for AuthConfig {
     fn default() -> Self {
         Self {
+            provider: IdentityProvider::default(),
             zitadel_authority: String::new(),
             zitadel_client_id: String::new(),
             zitadel_audience: String::new(),
+            oidc_issuer: String::new(),
+            oidc_audience: String::new(),
+            oidc_user_id_claim: default_user_id_claim(),
+            oidc_email_claim: default_email_claim(),
+            oidc_name_claim: default_name_claim(),
+            service_client_ids: None,
             jwks_cache_ttl_secs: default_jwks_cache_ttl(),
         }
     }
@@ -78,6 +174,18 @@ fn default_jwks_cache_ttl() -> u64 {
     3600
 }
 
+fn default_user_id_claim() -> String {
+    "sub".to_string()
+}
+
+fn default_email_claim() -> String {
+    "email".to_string()
+}
+
+fn default_name_claim() -> String {
+    "name".to_string()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -136,4 +244,67 @@ mod tests {
         };
         assert!(config.validate(&Environment::Production).is_ok());
     }
+
+    #[test]
+    fn test_oidc_validation_ignores_zitadel_fields() {
+        let config = AuthConfig {
+            provider: IdentityProvider::Oidc,
+            oidc_issuer: "https://keycloak.example.com/realms/choice-sherpa".to_string(),
+            oidc_audience: "choice-sherpa-api".to_string(),
+            ..Default::default()
+        };
+        assert!(config.validate(&Environment::Production).is_ok());
+        assert_eq!(
+            config.issuer_url(),
+            "https://keycloak.example.com/realms/choice-sherpa"
+        );
+        assert_eq!(config.audience(), "choice-sherpa-api");
+    }
+
+    #[test]
+    fn test_oidc_validation_requires_issuer_and_audience() {
+        let config = AuthConfig {
+            provider: IdentityProvider::Oidc,
+            oidc_issuer: "https://tenant.auth0.com/".to_string(),
+            ..Default::default()
+        };
+        assert!(matches!(
+            config.validate(&Environment::Development),
+            Err(ValidationError::MissingRequired("OIDC_AUDIENCE"))
+        ));
+    }
+
+    #[test]
+    fn test_oidc_validation_production_requires_https() {
+        let config = AuthConfig {
+            provider: IdentityProvider::Oidc,
+            oidc_issuer: "http://localhost:8080/realms/dev".to_string(),
+            oidc_audience: "choice-sherpa-api".to_string(),
+            ..Default::default()
+        };
+        assert!(config.validate(&Environment::Development).is_ok());
+        assert!(config.validate(&Environment::Production).is_err());
+    }
+
+    #[test]
+    fn test_service_client_ids_list() {
+        let config = AuthConfig {
+            service_client_ids: Some("sync-worker, export-worker,".to_string()),
+            ..Default::default()
+        };
+        assert_eq!(
+            config.service_client_ids_list(),
+            vec!["sync-worker", "export-worker"]
+        );
+        assert!(AuthConfig::default().service_client_ids_list().is_empty());
+    }
+
+    #[test]
+    fn test_oidc_claim_defaults() {
+        let config = AuthConfig::default();
+        assert_eq!(config.provider, IdentityProvider::Zitadel);
+        assert_eq!(config.oidc_user_id_claim, "sub");
+        assert_eq!(config.oidc_email_claim, "email");
+        assert_eq!(config.oidc_name_claim, "name");
+    }
 }
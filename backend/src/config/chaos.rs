@@ -0,0 +1,49 @@
+//! Fault injection configuration
+
+use serde::Deserialize;
+
+use super::error::ValidationError;
+use super::server::Environment;
+
+/// Chaos testing mode
+///
+/// When enabled, adapters are wrapped with fault injection decorators and the
+/// admin chaos API is mounted. Never allowed in production.
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct ChaosConfig {
+    /// Wrap adapters with fault injection
+    #[serde(default)]
+    pub enabled: bool,
+}
+
+impl ChaosConfig {
+    /// Validate chaos configuration
+    pub fn validate(&self, env: &Environment) -> Result<(), ValidationError> {
+        if self.enabled && *env == Environment::Production {
+            return Err(ValidationError::ChaosInProduction);
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_disabled_by_default() {
+        let config = ChaosConfig::default();
+        assert!(!config.enabled);
+        assert!(config.validate(&Environment::Production).is_ok());
+    }
+
+    #[test]
+    fn test_rejected_in_production() {
+        let config = ChaosConfig { enabled: true };
+        assert!(config.validate(&Environment::Staging).is_ok());
+        assert!(matches!(
+            config.validate(&Environment::Production),
+            Err(ValidationError::ChaosInProduction)
+        ));
+    }
+}
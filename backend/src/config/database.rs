@@ -5,10 +5,19 @@ use std::time::Duration;
 
 use super::error::ValidationError;
 
+/// Database engine, selected by the URL scheme
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DatabaseBackend {
+    /// PostgreSQL (`postgres://` or `postgresql://`)
+    Postgres,
+    /// SQLite (`sqlite:`), requires the `sqlite` feature
+    Sqlite,
+}
+
 /// Database configuration
 #[derive(Debug, Clone, Deserialize)]
 pub struct DatabaseConfig {
-    /// PostgreSQL connection URL
+    /// PostgreSQL connection URL, or a `sqlite:` URL for self-hosting
     pub url: String,
 
     /// Minimum connections to maintain
@@ -37,6 +46,15 @@ pub struct DatabaseConfig {
 }
 
 impl DatabaseConfig {
+    /// Get the database engine the URL selects
+    pub fn backend(&self) -> DatabaseBackend {
+        if self.url.starts_with("sqlite:") {
+            DatabaseBackend::Sqlite
+        } else {
+            DatabaseBackend::Postgres
+        }
+    }
+
     /// Get acquire timeout as Duration
     pub fn acquire_timeout(&self) -> Duration {
         Duration::from_secs(self.acquire_timeout_secs)
@@ -57,8 +75,16 @@ impl DatabaseConfig {
         if self.url.is_empty() {
             return Err(ValidationError::MissingRequired("DATABASE_URL"));
         }
-        if !self.url.starts_with("postgres://") && !self.url.starts_with("postgresql://") {
-            return Err(ValidationError::InvalidDatabaseUrl);
+        match self.backend() {
+            DatabaseBackend::Sqlite if !cfg!(feature = "sqlite") => {
+                return Err(ValidationError::SqliteNotEnabled);
+            }
+            DatabaseBackend::Sqlite => {}
+            DatabaseBackend::Postgres => {
+                if !self.url.starts_with("postgres://") && !self.url.starts_with("postgresql://") {
+                    return Err(ValidationError::InvalidDatabaseUrl);
+                }
+            }
         }
         if self.min_connections > self.max_connections {
             return Err(ValidationError::InvalidPoolSize);
@@ -175,4 +201,27 @@ mod tests {
         };
         assert!(config.validate().is_ok());
     }
+
+    #[test]
+    fn test_backend_from_url() {
+        let postgres = DatabaseConfig {
+            url: "postgres://localhost/test".to_string(),
+            ..Default::default()
+        };
+        let sqlite = DatabaseConfig {
+            url: "sqlite://choice-sherpa.db".to_string(),
+            ..Default::default()
+        };
+        assert_eq!(postgres.backend(), DatabaseBackend::Postgres);
+        assert_eq!(sqlite.backend(), DatabaseBackend::Sqlite);
+    }
+
+    #[test]
+    fn test_validation_sqlite_url() {
+        let config = DatabaseConfig {
+            url: "sqlite://choice-sherpa.db".to_string(),
+            ..Default::default()
+        };
+        assert_eq!(config.validate().is_ok(), cfg!(feature = "sqlite"));
+    }
 }
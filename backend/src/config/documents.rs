@@ -0,0 +1,88 @@
+//! Document export configuration
+
+use serde::Deserialize;
+
+use super::error::ValidationError;
+
+/// Decision document export settings
+///
+/// Organization templates are read from the database first, then from
+/// `<template_dir>/<organization>.md.tera`.
+#[derive(Debug, Clone, Deserialize)]
+pub struct DocumentsConfig {
+    /// Directory of per-organization Markdown templates shipped with the deployment
+    #[serde(default)]
+    pub template_dir: Option<String>,
+
+    /// Largest accepted template source, in bytes
+    #[serde(default = "default_max_template_bytes")]
+    pub max_template_bytes: usize,
+
+    /// Largest rendered document, in bytes
+    #[serde(default = "default_max_output_bytes")]
+    pub max_output_bytes: usize,
+}
+
+impl DocumentsConfig {
+    /// Validate document configuration
+    pub fn validate(&self) -> Result<(), ValidationError> {
+        if self.max_template_bytes == 0 || self.max_output_bytes == 0 {
+            return Err(ValidationError::InvalidDocumentLimit);
+        }
+        if self.template_dir.as_deref().is_some_and(|d| d.trim().is_empty()) {
+            return Err(ValidationError::MissingRequired("documents.template_dir"));
+        }
+        Ok(())
+    }
+}
+
+impl Default for DocumentsConfig {
+    fn default() -> Self {
+        Self {
+            template_dir: None,
+            max_template_bytes: default_max_template_bytes(),
+            max_output_bytes: default_max_output_bytes(),
+        }
+    }
+}
+
+fn default_max_template_bytes() -> usize {
+    64 * 1024
+}
+
+fn default_max_output_bytes() -> usize {
+    1024 * 1024
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_defaults_are_valid() {
+        let config = DocumentsConfig::default();
+        assert!(config.template_dir.is_none());
+        assert!(config.validate().is_ok());
+    }
+
+    #[test]
+    fn test_rejects_zero_limits() {
+        let config = DocumentsConfig {
+            max_output_bytes: 0,
+            ..Default::default()
+        };
+        assert!(matches!(
+            config.validate(),
+            Err(ValidationError::InvalidDocumentLimit)
+        ));
+    }
+
+    #[test]
+    fn test_rejects_blank_template_dir() {
+        let config = DocumentsConfig {
+            template_dir: Some("  ".to_string()),
+            ..Default::default()
+        };
+        assert!(config.validate().is_err());
+    }
+}
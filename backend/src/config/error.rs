@@ -24,9 +24,18 @@ pub enum ValidationError {
     #[error("Invalid request timeout")]
     InvalidTimeout,
 
+    #[error("Max concurrent requests must be greater than zero")]
+    InvalidConcurrencyLimit,
+
+    #[error("WebSocket idle timeout must exceed a non-zero ping interval")]
+    InvalidWebSocketPolicy,
+
     #[error("Invalid database URL format")]
     InvalidDatabaseUrl,
 
+    #[error("SQLite database URLs require the sqlite feature")]
+    SqliteNotEnabled,
+
     #[error("Invalid Redis URL format")]
     InvalidRedisUrl,
 
@@ -53,4 +62,52 @@ pub enum ValidationError {
 
     #[error("Invalid from email address")]
     InvalidFromEmail,
+
+    #[error("Email fallback provider must differ from the primary provider")]
+    DuplicateEmailFallback,
+
+    #[error("SMTP host must be a bare host name")]
+    InvalidSmtpHost,
+
+    #[error("SMTP username and password must be set together")]
+    IncompleteSmtpCredentials,
+
+    #[error("SMTP credentials require TLS or STARTTLS")]
+    SmtpCredentialsRequireTls,
+
+    #[error("Invalid SES region")]
+    InvalidSesRegion,
+
+    #[error("Invalid SES access key ID format")]
+    InvalidSesAccessKey,
+
+    #[error("Trace sample ratio must be between 0.0 and 1.0")]
+    InvalidSampleRatio,
+
+    #[error("Invalid OTLP endpoint URL")]
+    InvalidOtlpEndpoint,
+
+    #[error("Invalid log level: {0}")]
+    InvalidLogLevel(String),
+
+    #[error("SLO target must be between 0.0 and 1.0 (exclusive)")]
+    InvalidSloTarget,
+
+    #[error("SLO window must be between 1 and 90 days")]
+    InvalidSloWindow,
+
+    #[error("Auto-archive idle period must be longer than the warning period")]
+    InvalidArchivalPeriod,
+
+    #[error("Invalid SIEM endpoint")]
+    InvalidSiemEndpoint,
+
+    #[error("SIEM batch size must be between 1 and 1000")]
+    InvalidSiemBatchSize,
+
+    #[error("Fault injection cannot be enabled in production")]
+    ChaosInProduction,
+
+    #[error("Document template and output limits must be greater than zero")]
+    InvalidDocumentLimit,
 }
@@ -33,6 +33,21 @@ impl Default for FeatureFlags {
     }
 }
 
+impl FeatureFlags {
+    /// Boot-time values keyed by flag name.
+    ///
+    /// Used as local fallbacks by runtime flag providers when the remote
+    /// source is unreachable or does not define a flag.
+    pub fn fallback_values(&self) -> Vec<(&'static str, bool)> {
+        vec![
+            ("enable_streaming", self.enable_streaming),
+            ("enable_ai_fallback", self.enable_ai_fallback),
+            ("verbose_errors", self.verbose_errors),
+            ("enable_tracing", self.enable_tracing),
+        ]
+    }
+}
+
 fn default_enable_tracing() -> bool {
     true
 }
@@ -50,6 +65,18 @@ mod tests {
         assert!(flags.enable_tracing);
     }
 
+    #[test]
+    fn test_fallback_values_cover_all_flags() {
+        let flags = FeatureFlags {
+            enable_streaming: true,
+            ..FeatureFlags::default()
+        };
+        let values = flags.fallback_values();
+        assert_eq!(values.len(), 4);
+        assert!(values.contains(&("enable_streaming", true)));
+        assert!(values.contains(&("enable_tracing", true)));
+    }
+
     #[test]
     fn test_feature_flags_deserialization() {
         let json = r#"{
@@ -16,24 +16,36 @@
 //! ```
 
 mod ai;
+mod archival;
 mod auth;
+mod chaos;
 mod database;
+mod documents;
 mod email;
 mod error;
 mod features;
+mod observability;
 mod payment;
 mod redis;
 mod server;
+mod siem;
+mod slo;
 
-pub use ai::{AiConfig, AiProvider};
-pub use auth::AuthConfig;
-pub use database::DatabaseConfig;
-pub use email::EmailConfig;
+pub use ai::{AiConfig, AiProvider, ModelPriceOverride};
+pub use archival::ArchivalConfig;
+pub use auth::{AuthConfig, IdentityProvider};
+pub use chaos::ChaosConfig;
+pub use database::{DatabaseBackend, DatabaseConfig};
+pub use documents::DocumentsConfig;
+pub use email::{EmailConfig, EmailProvider, SmtpSecurity};
 pub use error::{ConfigError, ValidationError};
 pub use features::FeatureFlags;
+pub use observability::{LogFormat, ObservabilityConfig};
 pub use payment::PaymentConfig;
 pub use redis::RedisConfig;
 pub use server::{Environment, ServerConfig};
+pub use siem::{SiemConfig, SiemSinkKind, SyslogProtocol};
+pub use slo::SloConfig;
 
 use serde::Deserialize;
 
@@ -53,7 +65,7 @@ pub struct AppConfig {
     /// Redis configuration (cache/pubsub)
     pub redis: RedisConfig,
 
-    /// Authentication configuration (Zitadel OIDC)
+    /// Authentication configuration (Zitadel or generic OIDC)
     pub auth: AuthConfig,
 
     /// AI provider configuration (OpenAI/Anthropic)
@@ -63,12 +75,36 @@ pub struct AppConfig {
     /// Payment configuration (Stripe)
     pub payment: PaymentConfig,
 
-    /// Email configuration (Resend)
+    /// Email configuration (Resend, SMTP, or SES)
     pub email: EmailConfig,
 
     /// Feature flags
     #[serde(default)]
     pub features: FeatureFlags,
+
+    /// Logging and tracing export (OpenTelemetry)
+    #[serde(default)]
+    pub observability: ObservabilityConfig,
+
+    /// Audit and auth event export (required in production)
+    #[serde(default)]
+    pub siem: SiemConfig,
+
+    /// Service level objectives and error budgets
+    #[serde(default)]
+    pub slo: SloConfig,
+
+    /// Fault injection for testing environments (never in production)
+    #[serde(default)]
+    pub chaos: ChaosConfig,
+
+    /// Decision document export (templates and limits)
+    #[serde(default)]
+    pub documents: DocumentsConfig,
+
+    /// Automatic archival of idle sessions, per membership tier
+    #[serde(default)]
+    pub archival: ArchivalConfig,
 }
 
 impl AppConfig {
@@ -120,11 +156,20 @@ impl AppConfig {
     pub fn validate(&self) -> Result<(), ValidationError> {
         self.server.validate()?;
         self.database.validate()?;
-        self.redis.validate()?;
+        // Single-binary SQLite deployments may run without Redis
+        if self.database.backend() == DatabaseBackend::Postgres || !self.redis.url.is_empty() {
+            self.redis.validate()?;
+        }
         self.auth.validate(&self.server.environment)?;
         self.ai.validate()?;
         self.payment.validate()?;
         self.email.validate()?;
+        self.observability.validate()?;
+        self.siem.validate(&self.server.environment)?;
+        self.slo.validate()?;
+        self.chaos.validate(&self.server.environment)?;
+        self.documents.validate()?;
+        self.archival.validate()?;
         Ok(())
     }
 
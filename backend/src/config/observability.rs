@@ -0,0 +1,274 @@
+//! Observability configuration (logging and OpenTelemetry tracing)
+
+use serde::Deserialize;
+use std::collections::BTreeMap;
+use std::time::Duration;
+
+use super::error::ValidationError;
+use super::server::Environment;
+
+const LOG_LEVELS: [&str; 6] = ["trace", "debug", "info", "warn", "error", "off"];
+
+/// Log output format
+#[derive(Debug, Clone, Copy, Deserialize, PartialEq, Eq, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum LogFormat {
+    /// JSON in production, human-readable elsewhere
+    #[default]
+    Auto,
+    /// Human-readable console output
+    Pretty,
+    /// One JSON object per line
+    Json,
+}
+
+/// Observability configuration
+///
+/// Controls log output and OTLP span export. When `otlp_enabled` is false
+/// spans are still created (and appear in logs) but are not exported.
+#[derive(Debug, Clone, Deserialize)]
+pub struct ObservabilityConfig {
+    /// Log output format
+    #[serde(default)]
+    pub log_format: LogFormat,
+
+    /// Default log level for all modules
+    #[serde(default = "default_log_level")]
+    pub log_level: String,
+
+    /// Per-module log levels, e.g. `choice_sherpa::adapters::postgres = "debug"`
+    ///
+    /// `RUST_LOG`, when set, takes precedence over both level settings.
+    #[serde(default)]
+    pub log_levels: BTreeMap<String, String>,
+
+    /// Export spans via OTLP
+    #[serde(default)]
+    pub otlp_enabled: bool,
+
+    /// OTLP collector endpoint (gRPC)
+    #[serde(default = "default_otlp_endpoint")]
+    pub otlp_endpoint: String,
+
+    /// Service name reported on every span
+    #[serde(default = "default_service_name")]
+    pub service_name: String,
+
+    /// Fraction of root traces to sample (0.0 - 1.0)
+    #[serde(default = "default_sample_ratio")]
+    pub sample_ratio: f64,
+
+    /// Export timeout in seconds
+    #[serde(default = "default_export_timeout")]
+    pub export_timeout_secs: u64,
+
+    /// Postgres queries slower than this are logged and counted (0 disables)
+    #[serde(default = "default_slow_query_threshold")]
+    pub slow_query_threshold_ms: u64,
+}
+
+impl ObservabilityConfig {
+    /// Get export timeout as Duration
+    pub fn export_timeout(&self) -> Duration {
+        Duration::from_secs(self.export_timeout_secs)
+    }
+
+    /// Slow query threshold, or `None` when detection is disabled
+    pub fn slow_query_threshold(&self) -> Option<Duration> {
+        (self.slow_query_threshold_ms > 0).then(|| Duration::from_millis(self.slow_query_threshold_ms))
+    }
+
+    /// Whether logs should be emitted as JSON in the given environment
+    pub fn use_json_logs(&self, environment: &Environment) -> bool {
+        match self.log_format {
+            LogFormat::Auto => *environment == Environment::Production,
+            LogFormat::Pretty => false,
+            LogFormat::Json => true,
+        }
+    }
+
+    /// Build an `EnvFilter` directive string from the configured levels
+    pub fn log_filter(&self) -> String {
+        let mut directives = vec![self.log_level.to_lowercase()];
+        for (module, level) in &self.log_levels {
+            directives.push(format!("{}={}", module, level.to_lowercase()));
+        }
+        directives.join(",")
+    }
+
+    /// Validate observability configuration
+    pub fn validate(&self) -> Result<(), ValidationError> {
+        for level in std::iter::once(&self.log_level).chain(self.log_levels.values()) {
+            if !LOG_LEVELS.contains(&level.to_lowercase().as_str()) {
+                return Err(ValidationError::InvalidLogLevel(level.clone()));
+            }
+        }
+        if !(0.0..=1.0).contains(&self.sample_ratio) {
+            return Err(ValidationError::InvalidSampleRatio);
+        }
+        if self.otlp_enabled {
+            if self.service_name.is_empty() {
+                return Err(ValidationError::MissingRequired("OBSERVABILITY_SERVICE_NAME"));
+            }
+            if !self.otlp_endpoint.starts_with("http://")
+                && !self.otlp_endpoint.starts_with("https://")
+            {
+                return Err(ValidationError::InvalidOtlpEndpoint);
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Default for ObservabilityConfig {
+    fn default() -> Self {
+        Self {
+            log_format: LogFormat::default(),
+            log_level: default_log_level(),
+            log_levels: BTreeMap::new(),
+            otlp_enabled: false,
+            otlp_endpoint: default_otlp_endpoint(),
+            service_name: default_service_name(),
+            sample_ratio: default_sample_ratio(),
+            export_timeout_secs: default_export_timeout(),
+            slow_query_threshold_ms: default_slow_query_threshold(),
+        }
+    }
+}
+
+fn default_slow_query_threshold() -> u64 {
+    250
+}
+
+fn default_log_level() -> String {
+    "info".to_string()
+}
+
+fn default_otlp_endpoint() -> String {
+    "http://localhost:4317".to_string()
+}
+
+fn default_service_name() -> String {
+    "choice-sherpa".to_string()
+}
+
+fn default_sample_ratio() -> f64 {
+    1.0
+}
+
+fn default_export_timeout() -> u64 {
+    10
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_observability_config_defaults() {
+        let config = ObservabilityConfig::default();
+        assert!(!config.otlp_enabled);
+        assert_eq!(config.otlp_endpoint, "http://localhost:4317");
+        assert_eq!(config.service_name, "choice-sherpa");
+        assert_eq!(config.sample_ratio, 1.0);
+        assert!(config.validate().is_ok());
+    }
+
+    #[test]
+    fn test_slow_query_threshold() {
+        let config = ObservabilityConfig::default();
+        assert_eq!(config.slow_query_threshold(), Some(Duration::from_millis(250)));
+
+        let config = ObservabilityConfig {
+            slow_query_threshold_ms: 0,
+            ..Default::default()
+        };
+        assert_eq!(config.slow_query_threshold(), None);
+    }
+
+    #[test]
+    fn test_auto_format_uses_json_in_production() {
+        let config = ObservabilityConfig::default();
+        assert!(config.use_json_logs(&Environment::Production));
+        assert!(!config.use_json_logs(&Environment::Development));
+    }
+
+    #[test]
+    fn test_explicit_format_overrides_environment() {
+        let config = ObservabilityConfig {
+            log_format: LogFormat::Pretty,
+            ..Default::default()
+        };
+        assert!(!config.use_json_logs(&Environment::Production));
+
+        let config = ObservabilityConfig {
+            log_format: LogFormat::Json,
+            ..Default::default()
+        };
+        assert!(config.use_json_logs(&Environment::Development));
+    }
+
+    #[test]
+    fn test_log_filter_includes_module_levels() {
+        let mut log_levels = BTreeMap::new();
+        log_levels.insert("sqlx".to_string(), "WARN".to_string());
+        log_levels.insert("choice_sherpa::adapters::ai".to_string(), "debug".to_string());
+        let config = ObservabilityConfig {
+            log_levels,
+            ..Default::default()
+        };
+
+        assert_eq!(
+            config.log_filter(),
+            "info,choice_sherpa::adapters::ai=debug,sqlx=warn"
+        );
+    }
+
+    #[test]
+    fn test_validation_rejects_unknown_level() {
+        let mut log_levels = BTreeMap::new();
+        log_levels.insert("sqlx".to_string(), "loud".to_string());
+        let config = ObservabilityConfig {
+            log_levels,
+            ..Default::default()
+        };
+        assert!(matches!(
+            config.validate(),
+            Err(ValidationError::InvalidLogLevel(level)) if level == "loud"
+        ));
+    }
+
+    #[test]
+    fn test_validation_sample_ratio_out_of_range() {
+        let config = ObservabilityConfig {
+            sample_ratio: 1.5,
+            ..Default::default()
+        };
+        assert!(matches!(
+            config.validate(),
+            Err(ValidationError::InvalidSampleRatio)
+        ));
+    }
+
+    #[test]
+    fn test_validation_invalid_endpoint_when_enabled() {
+        let config = ObservabilityConfig {
+            otlp_enabled: true,
+            otlp_endpoint: "collector:4317".to_string(),
+            ..Default::default()
+        };
+        assert!(matches!(
+            config.validate(),
+            Err(ValidationError::InvalidOtlpEndpoint)
+        ));
+    }
+
+    #[test]
+    fn test_endpoint_not_checked_when_disabled() {
+        let config = ObservabilityConfig {
+            otlp_endpoint: String::new(),
+            ..Default::default()
+        };
+        assert!(config.validate().is_ok());
+    }
+}
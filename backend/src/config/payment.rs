@@ -18,6 +18,10 @@ pub struct PaymentConfig {
 
     /// Stripe price ID for annual plan
     pub stripe_annual_price_id: Option<String>,
+
+    /// Free trial length in days for new paid subscriptions
+    #[serde(default)]
+    pub trial_days: Option<u32>,
 }
 
 impl PaymentConfig {
@@ -120,6 +124,7 @@ mod tests {
             stripe_webhook_secret: "whsec_xyz789".to_string(),
             stripe_monthly_price_id: Some("price_monthly".to_string()),
             stripe_annual_price_id: Some("price_annual".to_string()),
+            trial_days: Some(14),
         };
         assert!(config.validate().is_ok());
     }
@@ -2,6 +2,7 @@
 
 use serde::Deserialize;
 use std::net::SocketAddr;
+use std::time::Duration;
 
 use super::error::ValidationError;
 
@@ -30,6 +31,42 @@ pub struct ServerConfig {
 
     /// CORS allowed origins (comma-separated)
     pub cors_origins: Option<String>,
+
+    /// Requests handled concurrently before load shedding queues kick in
+    #[serde(default = "default_max_concurrent_requests")]
+    pub max_concurrent_requests: usize,
+
+    /// Longest a queued request waits for a slot before it is shed, in milliseconds
+    #[serde(default = "default_shed_queue_timeout")]
+    pub shed_queue_timeout_ms: u64,
+
+    /// Queue depth for interactive requests (conversations, writes)
+    #[serde(default = "default_interactive_queue_limit")]
+    pub interactive_queue_limit: usize,
+
+    /// Queue depth for dashboard and other reads
+    #[serde(default = "default_dashboard_queue_limit")]
+    pub dashboard_queue_limit: usize,
+
+    /// Queue depth for document exports
+    #[serde(default = "default_export_queue_limit")]
+    pub export_queue_limit: usize,
+
+    /// Queue depth for analytics and admin reports (0 = shed when busy)
+    #[serde(default)]
+    pub analytics_queue_limit: usize,
+
+    /// How often the server pings WebSocket clients, in seconds
+    #[serde(default = "default_ws_ping_interval")]
+    pub ws_ping_interval_secs: u64,
+
+    /// WebSocket connections silent for this long are closed, in seconds
+    #[serde(default = "default_ws_idle_timeout")]
+    pub ws_idle_timeout_secs: u64,
+
+    /// Longest a WebSocket connection stays open, in seconds (0 = no limit)
+    #[serde(default = "default_ws_max_connection_lifetime")]
+    pub ws_max_connection_lifetime_secs: u64,
 }
 
 /// Application environment
@@ -63,6 +100,37 @@ impl ServerConfig {
             .unwrap_or_default()
     }
 
+    /// Get the load shedding queue timeout as Duration
+    pub fn shed_queue_timeout(&self) -> Duration {
+        Duration::from_millis(self.shed_queue_timeout_ms)
+    }
+
+    /// Queue depths in priority order: interactive, dashboard, export, analytics
+    pub fn shed_queue_limits(&self) -> [usize; 4] {
+        [
+            self.interactive_queue_limit,
+            self.dashboard_queue_limit,
+            self.export_queue_limit,
+            self.analytics_queue_limit,
+        ]
+    }
+
+    /// Get the WebSocket ping interval as Duration
+    pub fn ws_ping_interval(&self) -> Duration {
+        Duration::from_secs(self.ws_ping_interval_secs)
+    }
+
+    /// Get the WebSocket idle timeout as Duration
+    pub fn ws_idle_timeout(&self) -> Duration {
+        Duration::from_secs(self.ws_idle_timeout_secs)
+    }
+
+    /// Get the WebSocket connection lifetime limit, if any
+    pub fn ws_max_connection_lifetime(&self) -> Option<Duration> {
+        (self.ws_max_connection_lifetime_secs > 0)
+            .then(|| Duration::from_secs(self.ws_max_connection_lifetime_secs))
+    }
+
     /// Validate server configuration
     pub fn validate(&self) -> Result<(), ValidationError> {
         if self.port == 0 {
@@ -71,6 +139,18 @@ impl ServerConfig {
         if self.request_timeout_secs == 0 || self.request_timeout_secs > 300 {
             return Err(ValidationError::InvalidTimeout);
         }
+        if self.max_concurrent_requests == 0 {
+            return Err(ValidationError::InvalidConcurrencyLimit);
+        }
+        if self.shed_queue_timeout_ms > self.request_timeout_secs * 1000 {
+            return Err(ValidationError::InvalidTimeout);
+        }
+        // An idle client must get at least one ping before it is closed
+        if self.ws_ping_interval_secs == 0
+            || self.ws_idle_timeout_secs <= self.ws_ping_interval_secs
+        {
+            return Err(ValidationError::InvalidWebSocketPolicy);
+        }
         Ok(())
     }
 }
@@ -84,6 +164,15 @@ impl Default for ServerConfig {
             log_level: default_log_level(),
             request_timeout_secs: default_request_timeout(),
             cors_origins: None,
+            max_concurrent_requests: default_max_concurrent_requests(),
+            shed_queue_timeout_ms: default_shed_queue_timeout(),
+            interactive_queue_limit: default_interactive_queue_limit(),
+            dashboard_queue_limit: default_dashboard_queue_limit(),
+            export_queue_limit: default_export_queue_limit(),
+            analytics_queue_limit: 0,
+            ws_ping_interval_secs: default_ws_ping_interval(),
+            ws_idle_timeout_secs: default_ws_idle_timeout(),
+            ws_max_connection_lifetime_secs: default_ws_max_connection_lifetime(),
         }
     }
 }
@@ -108,6 +197,38 @@ fn default_request_timeout() -> u64 {
     30
 }
 
+fn default_max_concurrent_requests() -> usize {
+    256
+}
+
+fn default_shed_queue_timeout() -> u64 {
+    2000
+}
+
+fn default_interactive_queue_limit() -> usize {
+    128
+}
+
+fn default_dashboard_queue_limit() -> usize {
+    64
+}
+
+fn default_export_queue_limit() -> usize {
+    16
+}
+
+fn default_ws_ping_interval() -> u64 {
+    30
+}
+
+fn default_ws_idle_timeout() -> u64 {
+    90
+}
+
+fn default_ws_max_connection_lifetime() -> u64 {
+    4 * 60 * 60
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -175,4 +296,56 @@ mod tests {
         };
         assert!(config.validate().is_err());
     }
+
+    #[test]
+    fn test_load_shedding_defaults() {
+        let config = ServerConfig::default();
+        assert_eq!(config.max_concurrent_requests, 256);
+        assert_eq!(config.shed_queue_limits(), [128, 64, 16, 0]);
+        assert_eq!(config.shed_queue_timeout(), Duration::from_secs(2));
+        assert!(config.validate().is_ok());
+    }
+
+    #[test]
+    fn test_validation_invalid_load_shedding() {
+        let config = ServerConfig {
+            max_concurrent_requests: 0,
+            ..Default::default()
+        };
+        assert!(matches!(
+            config.validate(),
+            Err(ValidationError::InvalidConcurrencyLimit)
+        ));
+
+        let config = ServerConfig {
+            shed_queue_timeout_ms: 60_000,
+            ..Default::default()
+        };
+        assert!(config.validate().is_err());
+    }
+
+    #[test]
+    fn test_websocket_policy_settings() {
+        let config = ServerConfig::default();
+        assert_eq!(config.ws_ping_interval(), Duration::from_secs(30));
+        assert_eq!(
+            config.ws_max_connection_lifetime(),
+            Some(Duration::from_secs(14_400))
+        );
+
+        let config = ServerConfig {
+            ws_max_connection_lifetime_secs: 0,
+            ..Default::default()
+        };
+        assert_eq!(config.ws_max_connection_lifetime(), None);
+
+        let config = ServerConfig {
+            ws_idle_timeout_secs: 30,
+            ..Default::default()
+        };
+        assert!(matches!(
+            config.validate(),
+            Err(ValidationError::InvalidWebSocketPolicy)
+        ));
+    }
 }
@@ -0,0 +1,193 @@
+//! SIEM export configuration
+
+use serde::Deserialize;
+use std::time::Duration;
+
+use super::error::ValidationError;
+use super::server::Environment;
+
+/// Where security events are shipped
+#[derive(Debug, Clone, Copy, Deserialize, PartialEq, Eq, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum SiemSinkKind {
+    /// JSON batches POSTed to a collector URL
+    #[default]
+    Http,
+    /// RFC 5424 syslog to `host:port`
+    Syslog,
+}
+
+/// Syslog transport protocol
+#[derive(Debug, Clone, Copy, Deserialize, PartialEq, Eq, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum SyslogProtocol {
+    Udp,
+    #[default]
+    Tcp,
+}
+
+/// Audit and authentication event export
+///
+/// Compliance requires export in production, so validation fails there when
+/// it is disabled.
+#[derive(Debug, Clone, Deserialize)]
+pub struct SiemConfig {
+    /// Ship audit and auth events
+    #[serde(default)]
+    pub enabled: bool,
+
+    /// Sink type
+    #[serde(default)]
+    pub sink: SiemSinkKind,
+
+    /// Collector URL (http) or `host:port` (syslog)
+    #[serde(default)]
+    pub endpoint: String,
+
+    /// Bearer token for the HTTP collector
+    #[serde(default)]
+    pub auth_token: Option<String>,
+
+    /// Syslog transport protocol
+    #[serde(default)]
+    pub syslog_protocol: SyslogProtocol,
+
+    /// Maximum events per delivery
+    #[serde(default = "default_batch_size")]
+    pub batch_size: usize,
+
+    /// Longest an event waits before delivery, in milliseconds
+    #[serde(default = "default_flush_interval")]
+    pub flush_interval_ms: u64,
+
+    /// Retries per batch before it is dropped
+    #[serde(default = "default_max_retries")]
+    pub max_retries: u32,
+
+    /// Events buffered before new ones are dropped
+    #[serde(default = "default_queue_capacity")]
+    pub queue_capacity: usize,
+}
+
+impl SiemConfig {
+    /// Get the flush interval as Duration
+    pub fn flush_interval(&self) -> Duration {
+        Duration::from_millis(self.flush_interval_ms)
+    }
+
+    /// Validate SIEM configuration
+    pub fn validate(&self, environment: &Environment) -> Result<(), ValidationError> {
+        if !self.enabled {
+            if *environment == Environment::Production {
+                return Err(ValidationError::MissingRequired("SIEM_ENABLED"));
+            }
+            return Ok(());
+        }
+
+        if self.endpoint.is_empty() {
+            return Err(ValidationError::MissingRequired("SIEM_ENDPOINT"));
+        }
+        match self.sink {
+            SiemSinkKind::Http => {
+                let https = self.endpoint.starts_with("https://");
+                let http = self.endpoint.starts_with("http://");
+                if !(https || (http && *environment != Environment::Production)) {
+                    return Err(ValidationError::InvalidSiemEndpoint);
+                }
+            }
+            SiemSinkKind::Syslog => {
+                let has_port = self
+                    .endpoint
+                    .rsplit_once(':')
+                    .is_some_and(|(host, port)| !host.is_empty() && port.parse::<u16>().is_ok());
+                if !has_port {
+                    return Err(ValidationError::InvalidSiemEndpoint);
+                }
+            }
+        }
+        if self.batch_size == 0 || self.batch_size > 1000 {
+            return Err(ValidationError::InvalidSiemBatchSize);
+        }
+        Ok(())
+    }
+}
+
+impl Default for SiemConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            sink: SiemSinkKind::default(),
+            endpoint: String::new(),
+            auth_token: None,
+            syslog_protocol: SyslogProtocol::default(),
+            batch_size: default_batch_size(),
+            flush_interval_ms: default_flush_interval(),
+            max_retries: default_max_retries(),
+            queue_capacity: default_queue_capacity(),
+        }
+    }
+}
+
+fn default_batch_size() -> usize {
+    100
+}
+
+fn default_flush_interval() -> u64 {
+    5000
+}
+
+fn default_max_retries() -> u32 {
+    5
+}
+
+fn default_queue_capacity() -> usize {
+    10_000
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_disabled_is_valid_outside_production() {
+        let config = SiemConfig::default();
+        assert!(config.validate(&Environment::Development).is_ok());
+    }
+
+    #[test]
+    fn test_required_in_production() {
+        let config = SiemConfig::default();
+        assert!(matches!(
+            config.validate(&Environment::Production),
+            Err(ValidationError::MissingRequired("SIEM_ENABLED"))
+        ));
+    }
+
+    #[test]
+    fn test_production_http_requires_https() {
+        let config = SiemConfig {
+            enabled: true,
+            endpoint: "http://siem.internal/ingest".to_string(),
+            ..Default::default()
+        };
+        assert!(config.validate(&Environment::Development).is_ok());
+        assert!(matches!(
+            config.validate(&Environment::Production),
+            Err(ValidationError::InvalidSiemEndpoint)
+        ));
+    }
+
+    #[test]
+    fn test_syslog_requires_host_and_port() {
+        let mut config = SiemConfig {
+            enabled: true,
+            sink: SiemSinkKind::Syslog,
+            endpoint: "syslog.internal".to_string(),
+            ..Default::default()
+        };
+        assert!(config.validate(&Environment::Production).is_err());
+
+        config.endpoint = "syslog.internal:6514".to_string();
+        assert!(config.validate(&Environment::Production).is_ok());
+    }
+}
@@ -0,0 +1,157 @@
+//! Service level objective configuration
+
+use serde::Deserialize;
+use std::time::Duration;
+
+use super::error::ValidationError;
+
+/// SLO targets and error-budget evaluation
+///
+/// Each target is the fraction of events that must be good over the rolling
+/// window; the remainder is the error budget.
+#[derive(Debug, Clone, Deserialize)]
+pub struct SloConfig {
+    /// Track SLIs and evaluate budgets
+    #[serde(default = "default_enabled")]
+    pub enabled: bool,
+
+    /// Rolling window for error budgets, in days
+    #[serde(default = "default_window_days")]
+    pub window_days: u32,
+
+    /// Send-message latency threshold; slower requests count against the budget
+    #[serde(default = "default_send_message_latency_ms")]
+    pub send_message_latency_ms: u64,
+
+    /// Fraction of send-message requests that must finish under the threshold (p95 = 0.95)
+    #[serde(default = "default_send_message_latency_target")]
+    pub send_message_latency_target: f64,
+
+    /// Fraction of conversation streams that must complete without error
+    #[serde(default = "default_stream_success_target")]
+    pub stream_success_target: f64,
+
+    /// Fraction of payment webhooks that must process successfully
+    #[serde(default = "default_webhook_success_target")]
+    pub webhook_success_target: f64,
+
+    /// How often budgets are evaluated and reported, in seconds
+    #[serde(default = "default_evaluation_interval")]
+    pub evaluation_interval_secs: u64,
+}
+
+impl SloConfig {
+    /// Get the budget window as Duration
+    pub fn window(&self) -> Duration {
+        Duration::from_secs(u64::from(self.window_days) * 24 * 60 * 60)
+    }
+
+    /// Get the send-message latency threshold as Duration
+    pub fn send_message_latency(&self) -> Duration {
+        Duration::from_millis(self.send_message_latency_ms)
+    }
+
+    /// Get the evaluation interval as Duration
+    pub fn evaluation_interval(&self) -> Duration {
+        Duration::from_secs(self.evaluation_interval_secs)
+    }
+
+    /// Validate SLO configuration
+    pub fn validate(&self) -> Result<(), ValidationError> {
+        let targets = [
+            self.send_message_latency_target,
+            self.stream_success_target,
+            self.webhook_success_target,
+        ];
+        if targets.iter().any(|&t| t <= 0.0 || t >= 1.0) {
+            return Err(ValidationError::InvalidSloTarget);
+        }
+        if self.window_days == 0 || self.window_days > 90 {
+            return Err(ValidationError::InvalidSloWindow);
+        }
+        if self.evaluation_interval_secs == 0 {
+            return Err(ValidationError::InvalidTimeout);
+        }
+        Ok(())
+    }
+}
+
+impl Default for SloConfig {
+    fn default() -> Self {
+        Self {
+            enabled: default_enabled(),
+            window_days: default_window_days(),
+            send_message_latency_ms: default_send_message_latency_ms(),
+            send_message_latency_target: default_send_message_latency_target(),
+            stream_success_target: default_stream_success_target(),
+            webhook_success_target: default_webhook_success_target(),
+            evaluation_interval_secs: default_evaluation_interval(),
+        }
+    }
+}
+
+fn default_enabled() -> bool {
+    true
+}
+
+fn default_window_days() -> u32 {
+    28
+}
+
+fn default_send_message_latency_ms() -> u64 {
+    30_000
+}
+
+fn default_send_message_latency_target() -> f64 {
+    0.95
+}
+
+fn default_stream_success_target() -> f64 {
+    0.99
+}
+
+fn default_webhook_success_target() -> f64 {
+    0.999
+}
+
+fn default_evaluation_interval() -> u64 {
+    60
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_slo_config_defaults() {
+        let config = SloConfig::default();
+        assert!(config.enabled);
+        assert_eq!(config.window(), Duration::from_secs(28 * 24 * 60 * 60));
+        assert_eq!(config.send_message_latency(), Duration::from_secs(30));
+        assert!(config.validate().is_ok());
+    }
+
+    #[test]
+    fn test_validation_rejects_target_of_one() {
+        let config = SloConfig {
+            webhook_success_target: 1.0,
+            ..Default::default()
+        };
+        assert!(matches!(
+            config.validate(),
+            Err(ValidationError::InvalidSloTarget)
+        ));
+    }
+
+    #[test]
+    fn test_validation_rejects_empty_window() {
+        let config = SloConfig {
+            window_days: 0,
+            ..Default::default()
+        };
+        assert!(matches!(
+            config.validate(),
+            Err(ValidationError::InvalidSloWindow)
+        ));
+    }
+}
@@ -0,0 +1,437 @@
+//! `choice-sherpa doctor` - Startup self-check.
+//!
+//! Loads configuration the same way the server does, then checks every
+//! external dependency and prints a readiness report:
+//!
+//! ```text
+//! Choice Sherpa doctor
+//!
+//!   [ OK ] configuration  loaded and validated
+//!   [ OK ] postgres       connected; 42 migrations applied
+//!   [FAIL] redis          connection refused (redis://localhost:6379)
+//!   [ OK ] anthropic      API key accepted
+//!   [WARN] stripe         test-mode API key in production
+//!
+//! Not ready: 1 failure, 1 warning
+//! ```
+//!
+//! Exits non-zero if any check fails. Warnings do not affect the exit code.
+
+use std::fmt;
+use std::future::Future;
+use std::time::Duration;
+
+use choice_sherpa::config::{AiProvider, AppConfig, DatabaseConfig, PaymentConfig, RedisConfig};
+use sqlx::postgres::PgPoolOptions;
+
+/// Upper bound on any single network check.
+const CHECK_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Length of a Stripe webhook secret after the `whsec_` prefix.
+const MIN_WEBHOOK_SECRET_BODY_LEN: usize = 32;
+
+/// Outcome of a single check.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CheckStatus {
+    Ok,
+    Warn,
+    Fail,
+    Skip,
+}
+
+impl fmt::Display for CheckStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let label = match self {
+            CheckStatus::Ok => " OK ",
+            CheckStatus::Warn => "WARN",
+            CheckStatus::Fail => "FAIL",
+            CheckStatus::Skip => "SKIP",
+        };
+        f.write_str(label)
+    }
+}
+
+/// Result of a single named check.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CheckResult {
+    pub name: &'static str,
+    pub status: CheckStatus,
+    pub detail: String,
+}
+
+impl CheckResult {
+    fn new(name: &'static str, status: CheckStatus, detail: impl Into<String>) -> Self {
+        Self {
+            name,
+            status,
+            detail: detail.into(),
+        }
+    }
+
+    fn ok(name: &'static str, detail: impl Into<String>) -> Self {
+        Self::new(name, CheckStatus::Ok, detail)
+    }
+
+    fn warn(name: &'static str, detail: impl Into<String>) -> Self {
+        Self::new(name, CheckStatus::Warn, detail)
+    }
+
+    fn fail(name: &'static str, detail: impl Into<String>) -> Self {
+        Self::new(name, CheckStatus::Fail, detail)
+    }
+
+    fn skip(name: &'static str, detail: impl Into<String>) -> Self {
+        Self::new(name, CheckStatus::Skip, detail)
+    }
+}
+
+/// All check results, in the order they ran.
+#[derive(Debug, Default)]
+pub struct Report {
+    pub checks: Vec<CheckResult>,
+}
+
+impl Report {
+    fn push(&mut self, result: CheckResult) {
+        self.checks.push(result);
+    }
+
+    fn count(&self, status: CheckStatus) -> usize {
+        self.checks.iter().filter(|c| c.status == status).count()
+    }
+
+    /// True if no check failed.
+    pub fn is_ready(&self) -> bool {
+        self.count(CheckStatus::Fail) == 0
+    }
+}
+
+impl fmt::Display for Report {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let width = self.checks.iter().map(|c| c.name.len()).max().unwrap_or(0);
+
+        writeln!(f, "Choice Sherpa doctor")?;
+        writeln!(f)?;
+        for check in &self.checks {
+            writeln!(
+                f,
+                "  [{}] {:<width$}  {}",
+                check.status,
+                check.name,
+                check.detail,
+                width = width
+            )?;
+        }
+        writeln!(f)?;
+
+        let failures = self.count(CheckStatus::Fail);
+        let warnings = self.count(CheckStatus::Warn);
+        let summary = format!(
+            "{} failure{}, {} warning{}",
+            failures,
+            if failures == 1 { "" } else { "s" },
+            warnings,
+            if warnings == 1 { "" } else { "s" }
+        );
+        if self.is_ready() {
+            writeln!(f, "Ready: {}", summary)
+        } else {
+            writeln!(f, "Not ready: {}", summary)
+        }
+    }
+}
+
+/// Run all checks, print the report, and return the process exit code.
+pub async fn run() -> i32 {
+    let report = diagnose().await;
+    print!("{}", report);
+    if report.is_ready() {
+        0
+    } else {
+        1
+    }
+}
+
+/// Run all checks.
+pub async fn diagnose() -> Report {
+    let mut report = Report::default();
+
+    let config = match AppConfig::load() {
+        Ok(config) => config,
+        Err(e) => {
+            report.push(CheckResult::fail("configuration", format!("failed to load: {}", e)));
+            for name in ["postgres", "redis", "ai", "stripe"] {
+                report.push(CheckResult::skip(name, "configuration not loaded"));
+            }
+            return report;
+        }
+    };
+
+    // Keep going after validation errors so every problem shows up in one run
+    report.push(match config.validate() {
+        Ok(()) => CheckResult::ok("configuration", "loaded and validated"),
+        Err(e) => CheckResult::fail("configuration", format!("invalid: {}", e)),
+    });
+
+    report.push(check_postgres(&config.database).await);
+    report.push(check_redis(&config.redis).await);
+
+    let client = reqwest::Client::builder()
+        .timeout(CHECK_TIMEOUT)
+        .build()
+        .expect("Failed to create HTTP client");
+    for result in check_ai_providers(&client, &config).await {
+        report.push(result);
+    }
+
+    report.push(check_stripe(&config.payment, config.is_production()));
+
+    report
+}
+
+async fn check_postgres(config: &DatabaseConfig) -> CheckResult {
+    const NAME: &str = "postgres";
+
+    let connect = async {
+        let pool = PgPoolOptions::new()
+            .max_connections(1)
+            .acquire_timeout(CHECK_TIMEOUT)
+            .connect(&config.url)
+            .await?;
+
+        sqlx::query("SELECT 1").execute(&pool).await?;
+
+        let applied: Option<i64> = sqlx::query_scalar(
+            "SELECT COUNT(*) FROM _sqlx_migrations WHERE success",
+        )
+        .fetch_one(&pool)
+        .await
+        .ok();
+
+        pool.close().await;
+        Ok::<_, sqlx::Error>(applied)
+    };
+
+    match with_timeout(connect).await {
+        Ok(Ok(Some(applied))) => {
+            let expected = sqlx::migrate!("./migrations").iter().count() as i64;
+            if applied < expected {
+                CheckResult::warn(
+                    NAME,
+                    format!("connected; {} of {} migrations applied", applied, expected),
+                )
+            } else {
+                CheckResult::ok(NAME, format!("connected; {} migrations applied", applied))
+            }
+        }
+        Ok(Ok(None)) => CheckResult::warn(NAME, "connected; no migrations have been run"),
+        Ok(Err(e)) => CheckResult::fail(NAME, format!("{} ({})", e, redact_url(&config.url))),
+        Err(()) => CheckResult::fail(NAME, format!("timed out ({})", redact_url(&config.url))),
+    }
+}
+
+async fn check_redis(config: &RedisConfig) -> CheckResult {
+    const NAME: &str = "redis";
+
+    let ping = async {
+        let client = redis::Client::open(config.url.as_str())?;
+        let mut conn = client.get_multiplexed_tokio_connection().await?;
+        let pong: String = redis::cmd("PING").query_async(&mut conn).await?;
+        Ok::<_, redis::RedisError>(pong)
+    };
+
+    match with_timeout(ping).await {
+        Ok(Ok(_)) => CheckResult::ok(NAME, "PING succeeded"),
+        Ok(Err(e)) => CheckResult::fail(NAME, format!("{} ({})", e, redact_url(&config.url))),
+        Err(()) => CheckResult::fail(NAME, format!("timed out ({})", redact_url(&config.url))),
+    }
+}
+
+/// Verify each configured AI key by listing models, which costs no tokens.
+async fn check_ai_providers(client: &reqwest::Client, config: &AppConfig) -> Vec<CheckResult> {
+    let ai = &config.ai;
+    let mut results = Vec::new();
+
+    if let Some(key) = ai.anthropic_api_key.as_deref().filter(|k| !k.is_empty()) {
+        let request = client
+            .get("https://api.anthropic.com/v1/models?limit=1")
+            .header("x-api-key", key)
+            .header("anthropic-version", "2023-06-01");
+        results.push(check_ai_key("anthropic", request).await);
+    }
+
+    if let Some(key) = ai.openai_api_key.as_deref().filter(|k| !k.is_empty()) {
+        let request = client
+            .get("https://api.openai.com/v1/models")
+            .bearer_auth(key);
+        results.push(check_ai_key("openai", request).await);
+    }
+
+    if ai.has_azure_openai() {
+        let endpoint = ai.azure_openai_endpoint.as_deref().unwrap_or_default();
+        let request = client
+            .get(format!(
+                "{}/openai/models?api-version={}",
+                endpoint.trim_end_matches('/'),
+                ai.azure_openai_api_version
+            ))
+            .header("api-key", ai.azure_openai_api_key.as_deref().unwrap_or_default());
+        results.push(check_ai_key("azure_openai", request).await);
+    }
+
+    if let Some(key) = ai.gemini_api_key.as_deref().filter(|k| !k.is_empty()) {
+        let request = client
+            .get("https://generativelanguage.googleapis.com/v1beta/models?pageSize=1")
+            .header("x-goog-api-key", key);
+        results.push(check_ai_key("gemini", request).await);
+    }
+
+    if let Some(url) = ai.ollama_base_url.as_deref().filter(|u| !u.is_empty()) {
+        let request = client.get(format!("{}/api/tags", url.trim_end_matches('/')));
+        results.push(check_ai_key("ollama", request).await);
+    }
+
+    if results.is_empty() {
+        results.push(CheckResult::fail("ai", "no AI provider configured"));
+    }
+
+    let primary_name = match ai.primary_provider {
+        AiProvider::Anthropic => "anthropic",
+        AiProvider::OpenAI => "openai",
+        AiProvider::AzureOpenAI => "azure_openai",
+        AiProvider::Gemini => "gemini",
+        AiProvider::Ollama => "ollama",
+    };
+    if let Some(primary) = results.iter_mut().find(|r| r.name == primary_name) {
+        primary.detail.push_str(" (primary)");
+    }
+
+    results
+}
+
+async fn check_ai_key(name: &'static str, request: reqwest::RequestBuilder) -> CheckResult {
+    match request.send().await {
+        Ok(response) => classify_ai_status(name, response.status()),
+        Err(e) if e.is_timeout() => CheckResult::fail(name, "timed out"),
+        Err(e) => CheckResult::fail(name, format!("request failed: {}", e)),
+    }
+}
+
+fn classify_ai_status(name: &'static str, status: reqwest::StatusCode) -> CheckResult {
+    match status.as_u16() {
+        200..=299 => CheckResult::ok(name, "API key accepted"),
+        401 | 403 => CheckResult::fail(name, format!("API key rejected ({})", status)),
+        429 => CheckResult::warn(name, "API key accepted but rate limited"),
+        _ => CheckResult::warn(name, format!("unexpected response {}", status)),
+    }
+}
+
+fn check_stripe(config: &PaymentConfig, is_production: bool) -> CheckResult {
+    const NAME: &str = "stripe";
+
+    let Some(body) = config.stripe_webhook_secret.strip_prefix("whsec_") else {
+        return CheckResult::fail(NAME, "webhook secret must start with whsec_");
+    };
+    if body.len() < MIN_WEBHOOK_SECRET_BODY_LEN || !body.chars().all(|c| c.is_ascii_alphanumeric()) {
+        return CheckResult::fail(
+            NAME,
+            "webhook secret looks truncated; copy it again from the Stripe dashboard",
+        );
+    }
+
+    if !config.is_test_mode() && !config.is_live_mode() {
+        return CheckResult::fail(NAME, "API key must start with sk_test_ or sk_live_");
+    }
+    if is_production && config.is_test_mode() {
+        return CheckResult::warn(NAME, "test-mode API key in production");
+    }
+    if !is_production && config.is_live_mode() {
+        return CheckResult::warn(NAME, "live-mode API key outside production");
+    }
+
+    let mode = if config.is_live_mode() { "live" } else { "test" };
+    CheckResult::ok(NAME, format!("webhook secret format valid; {} mode", mode))
+}
+
+async fn with_timeout<F: Future>(future: F) -> Result<F::Output, ()> {
+    tokio::time::timeout(CHECK_TIMEOUT, future).await.map_err(|_| ())
+}
+
+/// Hide the password in a connection URL.
+fn redact_url(url: &str) -> String {
+    let Some((scheme, rest)) = url.split_once("://") else {
+        return url.to_string();
+    };
+    let Some((credentials, host)) = rest.rsplit_once('@') else {
+        return url.to_string();
+    };
+    match credentials.split_once(':') {
+        Some((user, _)) => format!("{}://{}:***@{}", scheme, user, host),
+        None => url.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn payment(api_key: &str, webhook_secret: &str) -> PaymentConfig {
+        PaymentConfig {
+            stripe_api_key: api_key.to_string(),
+            stripe_webhook_secret: webhook_secret.to_string(),
+            stripe_monthly_price_id: None,
+            stripe_annual_price_id: None,
+            trial_days: None,
+        }
+    }
+
+    const VALID_SECRET: &str = "whsec_abcdefghijklmnopqrstuvwxyz012345";
+
+    #[test]
+    fn stripe_valid_test_mode_outside_production() {
+        let result = check_stripe(&payment("sk_test_abc", VALID_SECRET), false);
+        assert_eq!(result.status, CheckStatus::Ok);
+    }
+
+    #[test]
+    fn stripe_truncated_secret_fails() {
+        let result = check_stripe(&payment("sk_test_abc", "whsec_abc"), false);
+        assert_eq!(result.status, CheckStatus::Fail);
+    }
+
+    #[test]
+    fn stripe_test_key_in_production_warns() {
+        let result = check_stripe(&payment("sk_test_abc", VALID_SECRET), true);
+        assert_eq!(result.status, CheckStatus::Warn);
+    }
+
+    #[test]
+    fn ai_status_classification() {
+        use reqwest::StatusCode;
+        assert_eq!(classify_ai_status("openai", StatusCode::OK).status, CheckStatus::Ok);
+        assert_eq!(classify_ai_status("openai", StatusCode::UNAUTHORIZED).status, CheckStatus::Fail);
+        assert_eq!(classify_ai_status("openai", StatusCode::TOO_MANY_REQUESTS).status, CheckStatus::Warn);
+    }
+
+    #[test]
+    fn urls_have_passwords_redacted() {
+        assert_eq!(
+            redact_url("postgresql://app:hunter2@db:5432/app"),
+            "postgresql://app:***@db:5432/app"
+        );
+        assert_eq!(redact_url("redis://localhost:6379"), "redis://localhost:6379");
+    }
+
+    #[test]
+    fn report_readiness_and_summary() {
+        let mut report = Report::default();
+        report.push(CheckResult::ok("configuration", "loaded and validated"));
+        report.push(CheckResult::warn("stripe", "test-mode API key in production"));
+        assert!(report.is_ready());
+        assert!(report.to_string().contains("Ready: 0 failures, 1 warning"));
+
+        report.push(CheckResult::fail("redis", "connection refused"));
+        assert!(!report.is_ready());
+        assert!(report.to_string().contains("  [FAIL] redis          connection refused"));
+    }
+}
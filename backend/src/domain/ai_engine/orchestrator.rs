@@ -233,9 +233,11 @@ impl Orchestrator {
         vec![
             ComponentType::IssueRaising,
             ComponentType::ProblemFrame,
+            ComponentType::StakeholderAnalysis,
             ComponentType::Objectives,
             ComponentType::Alternatives,
             ComponentType::Consequences,
+            ComponentType::UncertaintyRegister,
             ComponentType::Tradeoffs,
             ComponentType::Recommendation,
             ComponentType::DecisionQuality,
@@ -475,11 +477,11 @@ mod tests {
 
         // Get context for third step
         orchestrator
-            .transition_to(ComponentType::Objectives)
+            .transition_to(ComponentType::StakeholderAnalysis)
             .unwrap();
-        let context = orchestrator.context_for_step(ComponentType::Objectives);
+        let context = orchestrator.context_for_step(ComponentType::StakeholderAnalysis);
 
-        assert_eq!(context.component, ComponentType::Objectives);
+        assert_eq!(context.component, ComponentType::StakeholderAnalysis);
         assert_eq!(context.prior_summaries.len(), 2);
     }
 
@@ -503,9 +505,11 @@ mod tests {
         let steps = vec![
             ComponentType::IssueRaising,
             ComponentType::ProblemFrame,
+            ComponentType::StakeholderAnalysis,
             ComponentType::Objectives,
             ComponentType::Alternatives,
             ComponentType::Consequences,
+            ComponentType::UncertaintyRegister,
             ComponentType::Tradeoffs,
             ComponentType::Recommendation,
             ComponentType::DecisionQuality,
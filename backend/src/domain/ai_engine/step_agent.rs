@@ -150,6 +150,47 @@ pub mod agents {
         }
     }
 
+    /// Stakeholder Analysis agent specification
+    pub fn stakeholder_analysis() -> StepAgentSpec {
+        StepAgentSpec {
+            component: ComponentType::StakeholderAnalysis,
+            role: "Help user map stakeholders by power and interest and plan their engagement".to_string(),
+            objectives: vec![
+                "Identify everyone who affects or is affected by the decision".to_string(),
+                "Place each stakeholder on the power/interest grid".to_string(),
+                "Capture each stakeholder's concerns".to_string(),
+                "Agree how each stakeholder will be engaged".to_string(),
+            ],
+            techniques: vec![
+                "Ask who could block or change the decision".to_string(),
+                "Ask who lives with the outcome".to_string(),
+                "Turn concerns into candidate objectives".to_string(),
+            ],
+            output_schema: OutputSchema {
+                schema_version: "1.0".to_string(),
+                fields: vec![
+                    SchemaField {
+                        name: "stakeholders".to_string(),
+                        field_type: FieldType::Array,
+                        required: true,
+                        description: "Stakeholders with power, interest and concerns".to_string(),
+                    },
+                    SchemaField {
+                        name: "engagement_plan".to_string(),
+                        field_type: FieldType::Array,
+                        required: true,
+                        description: "How each stakeholder will be engaged".to_string(),
+                    },
+                ],
+            },
+            transitions: TransitionRules {
+                min_turns: 2,
+                required_outputs: vec!["stakeholders".to_string()],
+                completion_signals: vec!["ready for objectives".to_string(), "stakeholders mapped".to_string()],
+            },
+        }
+    }
+
     /// Objectives agent specification
     pub fn objectives() -> StepAgentSpec {
         StepAgentSpec {
@@ -165,6 +206,7 @@ pub mod agents {
                 "Ask 'why is that important?' to find fundamental objectives".to_string(),
                 "Ask 'how would you measure that?' for each objective".to_string(),
                 "Look for conflicts between objectives".to_string(),
+                "Ask for the midvalue of quantitative measures to find diminishing returns".to_string(),
             ],
             output_schema: OutputSchema {
                 schema_version: "1.0".to_string(),
@@ -259,6 +301,7 @@ pub mod agents {
                 "Go through alternatives one by one".to_string(),
                 "For each, rate against all objectives".to_string(),
                 "Ask for evidence supporting each rating".to_string(),
+                "Elicit uncertainty probabilities with three-point estimates or reference lotteries".to_string(),
             ],
             output_schema: OutputSchema {
                 schema_version: "1.0".to_string(),
@@ -291,6 +334,40 @@ pub mod agents {
         }
     }
 
+    /// Uncertainty Register agent specification
+    pub fn uncertainty_register() -> StepAgentSpec {
+        StepAgentSpec {
+            component: ComponentType::UncertaintyRegister,
+            role: "Help user register key uncertainties with probability, impact and mitigation".to_string(),
+            objectives: vec![
+                "Surface the uncertainties behind the consequence ratings".to_string(),
+                "Estimate how likely each unfavourable outcome is".to_string(),
+                "Judge how much each could change the decision".to_string(),
+                "Identify ways to resolve, reduce or hedge each one".to_string(),
+            ],
+            techniques: vec![
+                "Start from uncertainties noted in the consequence table".to_string(),
+                "Ask for a rough percentage and what it is based on".to_string(),
+                "Ask what would have to be true for the ranking to flip".to_string(),
+                "Sketch a decision tree when a later choice depends on how an uncertainty resolves".to_string(),
+            ],
+            output_schema: OutputSchema {
+                schema_version: "1.0".to_string(),
+                fields: vec![SchemaField {
+                    name: "uncertainties".to_string(),
+                    field_type: FieldType::Array,
+                    required: true,
+                    description: "Uncertainties with probability, impact and mitigations".to_string(),
+                }],
+            },
+            transitions: TransitionRules {
+                min_turns: 3,
+                required_outputs: vec!["uncertainties".to_string()],
+                completion_signals: vec!["register complete".to_string(), "ready for tradeoffs".to_string()],
+            },
+        }
+    }
+
     /// Tradeoffs agent specification
     pub fn tradeoffs() -> StepAgentSpec {
         StepAgentSpec {
@@ -306,6 +383,9 @@ pub mod agents {
                 "Compare alternatives pairwise".to_string(),
                 "Look for alternatives that are worse on all objectives".to_string(),
                 "Identify objectives where all alternatives are equal".to_string(),
+                "Propose even swaps the user can accept to neutralize an objective".to_string(),
+                "Offer weighted scoring when objectives clearly differ in importance".to_string(),
+                "In group decisions, record each participant's scores and discuss where they dissent".to_string(),
             ],
             output_schema: OutputSchema {
                 schema_version: "1.0".to_string(),
@@ -353,6 +433,7 @@ pub mod agents {
                 "Recap the journey through PrOACT".to_string(),
                 "Present findings, not recommendations".to_string(),
                 "Ask 'what stands out to you from this analysis?'".to_string(),
+                "Say how much it is worth paying to resolve key uncertainties first".to_string(),
             ],
             output_schema: OutputSchema {
                 schema_version: "1.0".to_string(),
@@ -437,9 +518,11 @@ pub mod agents {
         vec![
             issue_raising(),
             problem_frame(),
+            stakeholder_analysis(),
             objectives(),
             alternatives(),
             consequences(),
+            uncertainty_register(),
             tradeoffs(),
             recommendation(),
             decision_quality(),
@@ -451,9 +534,11 @@ pub mod agents {
         match component {
             ComponentType::IssueRaising => Some(issue_raising()),
             ComponentType::ProblemFrame => Some(problem_frame()),
+            ComponentType::StakeholderAnalysis => Some(stakeholder_analysis()),
             ComponentType::Objectives => Some(objectives()),
             ComponentType::Alternatives => Some(alternatives()),
             ComponentType::Consequences => Some(consequences()),
+            ComponentType::UncertaintyRegister => Some(uncertainty_register()),
             ComponentType::Tradeoffs => Some(tradeoffs()),
             ComponentType::Recommendation => Some(recommendation()),
             ComponentType::DecisionQuality => Some(decision_quality()),
@@ -469,7 +554,7 @@ mod tests {
     #[test]
     fn test_step_agent_spec_all_components_defined() {
         let specs = agents::all();
-        assert_eq!(specs.len(), 8); // All 8 PrOACT components (excluding NotesNextSteps)
+        assert_eq!(specs.len(), 10); // All 10 PrOACT components (excluding NotesNextSteps)
     }
 
     #[test]
@@ -506,6 +591,32 @@ mod tests {
             .any(|f| f.name == "decision_statement"));
     }
 
+    #[test]
+    fn test_stakeholder_analysis_agent() {
+        let spec = agents::stakeholder_analysis();
+
+        assert_eq!(spec.component, ComponentType::StakeholderAnalysis);
+        assert!(spec.role.contains("power and interest"));
+        assert!(spec
+            .output_schema
+            .fields
+            .iter()
+            .any(|f| f.name == "engagement_plan"));
+    }
+
+    #[test]
+    fn test_uncertainty_register_agent() {
+        let spec = agents::uncertainty_register();
+
+        assert_eq!(spec.component, ComponentType::UncertaintyRegister);
+        assert!(spec.role.contains("probability"));
+        assert!(spec
+            .output_schema
+            .fields
+            .iter()
+            .any(|f| f.name == "uncertainties"));
+    }
+
     #[test]
     fn test_objectives_agent() {
         let spec = agents::objectives();
@@ -0,0 +1,577 @@
+//! Even Swaps - Simplifying the consequences table by trading objectives.
+//!
+//! An even swap changes one alternative's rating on one objective and
+//! compensates with an equally valued change on another, leaving the
+//! alternative no better or worse overall. Swaps are chosen so that an
+//! objective ends up rated the same for every alternative (and can be
+//! dropped) or so that an alternative becomes dominated (and can be
+//! eliminated). Repeating this shrinks the table until the choice is clear.
+//!
+//! The analyzer only proposes swaps. Whether a swap is truly even is the
+//! user's judgment, so every proposal goes through a `ConfirmationRequest`
+//! and is applied only once accepted.
+
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+
+use crate::domain::conversation::tools::{ConfirmationOption, ConfirmationRequest};
+use crate::domain::foundation::{CycleId, Rating};
+
+use super::{Cell, ConsequencesTable, DominatedAlternative, IrrelevantObjective, PughAnalyzer};
+
+/// Minutes a swap proposal waits for the user's answer (one day).
+const SWAP_TTL_MINUTES: i64 = 24 * 60;
+
+/// Confirmation option index for accepting the swap as proposed.
+pub const ACCEPT_SWAP_OPTION: usize = 0;
+
+/// Confirmation option index for rejecting the swap.
+pub const REJECT_SWAP_OPTION: usize = 1;
+
+/// A change to one alternative: give up (or gain) on one objective and
+/// compensate on another.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EvenSwap {
+    pub alternative_id: String,
+    /// Objective whose rating is moved to match the other alternatives.
+    pub give_objective_id: String,
+    pub give_from: Rating,
+    pub give_to: Rating,
+    /// Objective that absorbs the compensating change.
+    pub take_objective_id: String,
+    pub take_from: Rating,
+    pub take_to: Rating,
+}
+
+impl EvenSwap {
+    /// Describes the swap in plain words.
+    pub fn describe(&self) -> String {
+        format!(
+            "For {}, change {} from {} to {} and compensate by changing {} from {} to {}",
+            self.alternative_id,
+            self.give_objective_id,
+            self.give_from.label(),
+            self.give_to.label(),
+            self.take_objective_id,
+            self.take_from.label(),
+            self.take_to.label()
+        )
+    }
+
+    /// Asks the user whether the swap is even.
+    ///
+    /// Option [`ACCEPT_SWAP_OPTION`] applies the swap as proposed and
+    /// [`REJECT_SWAP_OPTION`] discards it. A user who thinks a different
+    /// compensation would be even answers with custom input instead.
+    pub fn confirmation_request(
+        &self,
+        cycle_id: CycleId,
+        conversation_turn: u32,
+    ) -> ConfirmationRequest {
+        ConfirmationRequest::new(
+            cycle_id,
+            conversation_turn,
+            format!(
+                "{}. Would you consider these equally good?",
+                self.describe()
+            ),
+            vec![
+                ConfirmationOption::new("Accept", "The two changes balance out; apply the swap"),
+                ConfirmationOption::new("Reject", "The changes are not equal; leave the table"),
+            ],
+            Some(ACCEPT_SWAP_OPTION),
+            SWAP_TTL_MINUTES,
+        )
+    }
+}
+
+/// A proposed swap with what it would achieve.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SwapCandidate {
+    pub swap: EvenSwap,
+    /// Whether the give objective would no longer distinguish alternatives.
+    pub neutralizes_objective: bool,
+    /// Alternatives that would become dominated after the swap.
+    pub newly_dominated: Vec<DominatedAlternative>,
+}
+
+impl SwapCandidate {
+    /// How much the swap would shrink the table.
+    pub fn simplification(&self) -> usize {
+        usize::from(self.neutralizes_objective) + self.newly_dominated.len()
+    }
+}
+
+/// A table with irrelevant objectives and dominated alternatives removed.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SimplifiedTable {
+    pub table: ConsequencesTable,
+    pub removed_objectives: Vec<IrrelevantObjective>,
+    pub removed_alternatives: Vec<DominatedAlternative>,
+}
+
+/// Why a swap could not be applied.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum EvenSwapError {
+    #[error("Alternative '{0}' is not in the consequences table")]
+    UnknownAlternative(String),
+
+    #[error("Objective '{0}' is not in the consequences table")]
+    UnknownObjective(String),
+
+    #[error("A swap must trade between two different objectives")]
+    SameObjective,
+
+    #[error("A swap must give up on one objective to gain on the other")]
+    NotCompensating,
+
+    #[error(
+        "The rating of {alternative_id} on {objective_id} has changed since the swap was proposed"
+    )]
+    Stale {
+        alternative_id: String,
+        objective_id: String,
+    },
+}
+
+/// Even swaps analysis functions.
+pub struct EvenSwapsAnalyzer;
+
+impl EvenSwapsAnalyzer {
+    /// Finds swaps that would simplify the table.
+    ///
+    /// # Algorithm
+    /// For each objective that still distinguishes alternatives, the target
+    /// rating is the one most alternatives share. Each alternative off the
+    /// target is moved onto it, compensated by the same number of steps in
+    /// the opposite direction on the first other objective that has room and
+    /// still distinguishes alternatives. The swap is kept if it neutralizes
+    /// the objective or exposes a new dominated alternative.
+    ///
+    /// Candidates are ordered by how much they simplify the table.
+    ///
+    /// # Edge Cases
+    /// - Fewer than 2 alternatives or objectives: Returns empty Vec
+    /// - No objective with room to compensate: No candidate for that cell
+    pub fn find_candidates(table: &ConsequencesTable) -> Vec<SwapCandidate> {
+        let mut candidates = Vec::new();
+
+        if table.alternative_ids.len() < 2 || table.objective_ids.len() < 2 {
+            return candidates;
+        }
+
+        let irrelevant: HashSet<String> = PughAnalyzer::find_irrelevant_objectives(table)
+            .into_iter()
+            .map(|o| o.objective_id)
+            .collect();
+        let dominated_before: HashSet<String> = PughAnalyzer::find_dominated(table)
+            .into_iter()
+            .map(|d| d.alternative_id)
+            .collect();
+
+        for obj_id in &table.objective_ids {
+            if irrelevant.contains(obj_id) {
+                continue;
+            }
+            let target = Self::target_rating(table, obj_id);
+
+            for alt_id in &table.alternative_ids {
+                if dominated_before.contains(alt_id) {
+                    continue;
+                }
+                let Some(swap) = Self::compensated_swap(table, alt_id, obj_id, target, &irrelevant)
+                else {
+                    continue;
+                };
+                let Ok(swapped) = Self::apply_swap(table, &swap) else {
+                    continue;
+                };
+
+                let neutralizes_objective = PughAnalyzer::find_irrelevant_objectives(&swapped)
+                    .iter()
+                    .any(|o| &o.objective_id == obj_id);
+                let newly_dominated: Vec<DominatedAlternative> =
+                    PughAnalyzer::find_dominated(&swapped)
+                        .into_iter()
+                        .filter(|d| !dominated_before.contains(&d.alternative_id))
+                        .collect();
+
+                if neutralizes_objective || !newly_dominated.is_empty() {
+                    candidates.push(SwapCandidate {
+                        swap,
+                        neutralizes_objective,
+                        newly_dominated,
+                    });
+                }
+            }
+        }
+
+        candidates.sort_by_key(|c| std::cmp::Reverse(c.simplification()));
+        candidates
+    }
+
+    /// Applies an accepted swap, returning the updated table.
+    ///
+    /// The swap's `from` ratings must still match the table, so a swap
+    /// proposed before the table changed is refused rather than misapplied.
+    pub fn apply_swap(
+        table: &ConsequencesTable,
+        swap: &EvenSwap,
+    ) -> Result<ConsequencesTable, EvenSwapError> {
+        if !table.alternative_ids.contains(&swap.alternative_id) {
+            return Err(EvenSwapError::UnknownAlternative(
+                swap.alternative_id.clone(),
+            ));
+        }
+        for obj_id in [&swap.give_objective_id, &swap.take_objective_id] {
+            if !table.objective_ids.contains(obj_id) {
+                return Err(EvenSwapError::UnknownObjective(obj_id.clone()));
+            }
+        }
+        if swap.give_objective_id == swap.take_objective_id {
+            return Err(EvenSwapError::SameObjective);
+        }
+
+        let give_delta = swap.give_to.value() - swap.give_from.value();
+        let take_delta = swap.take_to.value() - swap.take_from.value();
+        if give_delta.signum() * take_delta.signum() != -1 {
+            return Err(EvenSwapError::NotCompensating);
+        }
+
+        for (obj_id, from) in [
+            (&swap.give_objective_id, swap.give_from),
+            (&swap.take_objective_id, swap.take_from),
+        ] {
+            if Self::rating(table, &swap.alternative_id, obj_id) != from {
+                return Err(EvenSwapError::Stale {
+                    alternative_id: swap.alternative_id.clone(),
+                    objective_id: obj_id.clone(),
+                });
+            }
+        }
+
+        let mut swapped = table.clone();
+        let rationale = format!("Even swap: {}", swap.describe());
+        for (obj_id, to) in [
+            (&swap.give_objective_id, swap.give_to),
+            (&swap.take_objective_id, swap.take_to),
+        ] {
+            swapped.cells.insert(
+                format!("{}:{}", swap.alternative_id, obj_id),
+                Cell::with_rationale(swap.alternative_id.clone(), obj_id.clone(), to, &rationale),
+            );
+        }
+
+        Ok(swapped)
+    }
+
+    /// Removes irrelevant objectives and dominated alternatives.
+    ///
+    /// Eliminating an alternative can leave another objective uniform, so
+    /// both are repeated until neither finds anything more to remove.
+    pub fn simplify(table: &ConsequencesTable) -> SimplifiedTable {
+        let mut table = table.clone();
+        let mut removed_objectives = Vec::new();
+        let mut removed_alternatives = Vec::new();
+
+        loop {
+            let irrelevant = PughAnalyzer::find_irrelevant_objectives(&table);
+            let dominated = PughAnalyzer::find_dominated(&table);
+            if irrelevant.is_empty() && dominated.is_empty() {
+                break;
+            }
+
+            let drop_objectives: HashSet<&str> =
+                irrelevant.iter().map(|o| o.objective_id.as_str()).collect();
+            let drop_alternatives: HashSet<&str> = dominated
+                .iter()
+                .map(|d| d.alternative_id.as_str())
+                .collect();
+
+            table
+                .objective_ids
+                .retain(|id| !drop_objectives.contains(id.as_str()));
+            table
+                .alternative_ids
+                .retain(|id| !drop_alternatives.contains(id.as_str()));
+            table.cells.retain(|_, cell| {
+                !drop_objectives.contains(cell.objective_id.as_str())
+                    && !drop_alternatives.contains(cell.alternative_id.as_str())
+            });
+
+            removed_objectives.extend(irrelevant);
+            removed_alternatives.extend(dominated);
+        }
+
+        SimplifiedTable {
+            table,
+            removed_objectives,
+            removed_alternatives,
+        }
+    }
+
+    /// The rating most alternatives share on an objective.
+    ///
+    /// Ties go to the rating seen first in alternative order.
+    fn target_rating(table: &ConsequencesTable, obj_id: &str) -> Rating {
+        let mut counts: HashMap<Rating, usize> = HashMap::new();
+        let mut order = Vec::new();
+        for alt_id in &table.alternative_ids {
+            let rating = Self::rating(table, alt_id, obj_id);
+            let count = counts.entry(rating).or_insert(0);
+            if *count == 0 {
+                order.push(rating);
+            }
+            *count += 1;
+        }
+
+        order
+            .into_iter()
+            .fold(None, |best: Option<Rating>, rating| match best {
+                Some(b) if counts[&b] >= counts[&rating] => Some(b),
+                _ => Some(rating),
+            })
+            .unwrap_or_default()
+    }
+
+    /// Builds the swap moving `alt_id` onto `target` for `obj_id`.
+    fn compensated_swap(
+        table: &ConsequencesTable,
+        alt_id: &str,
+        obj_id: &str,
+        target: Rating,
+        irrelevant: &HashSet<String>,
+    ) -> Option<EvenSwap> {
+        let give_from = Self::rating(table, alt_id, obj_id);
+        let delta = target.value() - give_from.value();
+        if delta == 0 {
+            return None;
+        }
+
+        table
+            .objective_ids
+            .iter()
+            .filter(|other| other.as_str() != obj_id && !irrelevant.contains(*other))
+            .find_map(|other| {
+                let take_from = Self::rating(table, alt_id, other);
+                let take_to = Rating::try_from_i8(take_from.value() - delta).ok()?;
+                Some(EvenSwap {
+                    alternative_id: alt_id.to_string(),
+                    give_objective_id: obj_id.to_string(),
+                    give_from,
+                    give_to: target,
+                    take_objective_id: other.clone(),
+                    take_from,
+                    take_to,
+                })
+            })
+    }
+
+    /// A cell's rating, with missing cells treated as neutral.
+    fn rating(table: &ConsequencesTable, alt_id: &str, obj_id: &str) -> Rating {
+        table
+            .get_cell(alt_id, obj_id)
+            .map(|c| c.rating)
+            .unwrap_or_default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A and B differ only on cost, where C stands out; C trails on speed.
+    fn commute_table() -> ConsequencesTable {
+        ConsequencesTable::builder()
+            .alternatives(vec!["A", "B", "C"])
+            .objectives(vec!["cost", "speed", "comfort"])
+            .cell("A", "cost", Rating::Same)
+            .cell("A", "speed", Rating::Better)
+            .cell("A", "comfort", Rating::Worse)
+            .cell("B", "cost", Rating::Same)
+            .cell("B", "speed", Rating::Worse)
+            .cell("B", "comfort", Rating::Better)
+            .cell("C", "cost", Rating::Better)
+            .cell("C", "speed", Rating::Worse)
+            .cell("C", "comfort", Rating::Same)
+            .build()
+    }
+
+    fn swap(give_from: Rating, give_to: Rating, take_from: Rating, take_to: Rating) -> EvenSwap {
+        EvenSwap {
+            alternative_id: "C".to_string(),
+            give_objective_id: "cost".to_string(),
+            give_from,
+            give_to,
+            take_objective_id: "speed".to_string(),
+            take_from,
+            take_to,
+        }
+    }
+
+    #[test]
+    fn candidates_include_swap_that_neutralizes_objective() {
+        let candidates = EvenSwapsAnalyzer::find_candidates(&commute_table());
+
+        let neutralizing = candidates
+            .iter()
+            .find(|c| c.swap.alternative_id == "C" && c.swap.give_objective_id == "cost")
+            .unwrap();
+
+        assert!(neutralizing.neutralizes_objective);
+        assert_eq!(neutralizing.swap.give_to, Rating::Same);
+        assert_eq!(neutralizing.swap.take_objective_id, "speed");
+        assert_eq!(neutralizing.swap.take_to, Rating::Same);
+    }
+
+    #[test]
+    fn candidates_are_ordered_by_simplification() {
+        let candidates = EvenSwapsAnalyzer::find_candidates(&commute_table());
+
+        assert!(!candidates.is_empty());
+        for pair in candidates.windows(2) {
+            assert!(pair[0].simplification() >= pair[1].simplification());
+        }
+    }
+
+    #[test]
+    fn no_candidates_for_single_alternative() {
+        let table = ConsequencesTable::builder()
+            .alternatives(vec!["A"])
+            .objectives(vec!["cost", "speed"])
+            .cell("A", "cost", Rating::Better)
+            .build();
+
+        assert!(EvenSwapsAnalyzer::find_candidates(&table).is_empty());
+    }
+
+    #[test]
+    fn no_compensation_room_means_no_candidate() {
+        let table = ConsequencesTable::builder()
+            .alternatives(vec!["A", "B", "C"])
+            .objectives(vec!["cost", "speed"])
+            .cell("A", "cost", Rating::Same)
+            .cell("B", "cost", Rating::Same)
+            .cell("C", "cost", Rating::MuchBetter)
+            .cell("A", "speed", Rating::Better)
+            .cell("B", "speed", Rating::MuchWorse)
+            .cell("C", "speed", Rating::Better)
+            .build();
+
+        let candidates = EvenSwapsAnalyzer::find_candidates(&table);
+
+        assert!(!candidates
+            .iter()
+            .any(|c| c.swap.alternative_id == "C" && c.swap.give_objective_id == "cost"));
+    }
+
+    #[test]
+    fn apply_swap_updates_both_cells_with_rationale() {
+        let table = commute_table();
+        let swapped = EvenSwapsAnalyzer::apply_swap(
+            &table,
+            &swap(Rating::Better, Rating::Same, Rating::Worse, Rating::Same),
+        )
+        .unwrap();
+
+        let cost = swapped.get_cell("C", "cost").unwrap();
+        assert_eq!(cost.rating, Rating::Same);
+        assert!(cost.rationale.as_deref().unwrap().starts_with("Even swap"));
+        assert_eq!(swapped.get_cell("C", "speed").unwrap().rating, Rating::Same);
+        assert_eq!(table.get_cell("C", "cost").unwrap().rating, Rating::Better);
+    }
+
+    #[test]
+    fn apply_swap_rejects_changes_in_the_same_direction() {
+        let result = EvenSwapsAnalyzer::apply_swap(
+            &commute_table(),
+            &swap(
+                Rating::Better,
+                Rating::Same,
+                Rating::Worse,
+                Rating::MuchWorse,
+            ),
+        );
+
+        assert_eq!(result, Err(EvenSwapError::NotCompensating));
+    }
+
+    #[test]
+    fn apply_swap_rejects_stale_ratings() {
+        let result = EvenSwapsAnalyzer::apply_swap(
+            &commute_table(),
+            &swap(
+                Rating::MuchBetter,
+                Rating::Same,
+                Rating::Worse,
+                Rating::Better,
+            ),
+        );
+
+        assert!(matches!(result, Err(EvenSwapError::Stale { .. })));
+    }
+
+    #[test]
+    fn apply_swap_rejects_unknown_objective() {
+        let mut bad = swap(Rating::Better, Rating::Same, Rating::Worse, Rating::Same);
+        bad.take_objective_id = "noise".to_string();
+
+        let result = EvenSwapsAnalyzer::apply_swap(&commute_table(), &bad);
+
+        assert_eq!(
+            result,
+            Err(EvenSwapError::UnknownObjective("noise".to_string()))
+        );
+    }
+
+    #[test]
+    fn simplify_removes_neutralized_objective_and_dominated_alternatives() {
+        let swapped = EvenSwapsAnalyzer::apply_swap(
+            &commute_table(),
+            &swap(Rating::Better, Rating::Same, Rating::Worse, Rating::Same),
+        )
+        .unwrap();
+
+        let simplified = EvenSwapsAnalyzer::simplify(&swapped);
+
+        assert!(simplified
+            .removed_objectives
+            .iter()
+            .any(|o| o.objective_id == "cost"));
+        assert!(!simplified.table.objective_ids.contains(&"cost".to_string()));
+        assert!(simplified
+            .table
+            .cells
+            .values()
+            .all(|c| c.objective_id != "cost"));
+    }
+
+    #[test]
+    fn simplify_leaves_balanced_table_unchanged() {
+        let table = ConsequencesTable::builder()
+            .alternatives(vec!["A", "B"])
+            .objectives(vec!["cost", "speed"])
+            .cell("A", "cost", Rating::Better)
+            .cell("A", "speed", Rating::Worse)
+            .cell("B", "cost", Rating::Worse)
+            .cell("B", "speed", Rating::Better)
+            .build();
+
+        let simplified = EvenSwapsAnalyzer::simplify(&table);
+
+        assert_eq!(simplified.table, table);
+        assert!(simplified.removed_objectives.is_empty());
+        assert!(simplified.removed_alternatives.is_empty());
+    }
+
+    #[test]
+    fn confirmation_request_offers_accept_and_reject() {
+        let request = swap(Rating::Better, Rating::Same, Rating::Worse, Rating::Same)
+            .confirmation_request(CycleId::new(), 4);
+
+        assert!(request.status().is_pending());
+        assert_eq!(request.options()[ACCEPT_SWAP_OPTION].label, "Accept");
+        assert_eq!(request.options()[REJECT_SWAP_OPTION].label, "Reject");
+        assert!(request.summary().contains("For C, change cost"));
+    }
+}
@@ -9,9 +9,11 @@ use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
 use crate::domain::foundation::{
-    domain_event, CycleId, EventId, Percentage, SessionId, Timestamp,
+    domain_event, ComponentType, CycleId, EventId, Percentage, SessionId, Timestamp,
 };
 
+use super::ReanalysisTrigger;
+
 /// Published when Pugh matrix scores are computed for a cycle.
 ///
 /// This event is triggered by `ComponentCompleted` for the Consequences component.
@@ -136,6 +138,37 @@ domain_event!(
     event_id = event_id
 );
 
+/// Published to queue a re-run of one component's analysis.
+///
+/// Issued by `ReanalyzeCycleCommand` once per computed component; the
+/// analysis trigger handler picks each one up as if the component had just
+/// been completed.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReanalysisRequested {
+    /// Unique event identifier for deduplication.
+    pub event_id: EventId,
+    /// The cycle to reanalyze.
+    pub cycle_id: CycleId,
+    /// The session containing this cycle.
+    pub session_id: SessionId,
+    /// The component whose analysis should be re-run.
+    pub component_type: ComponentType,
+    /// What made the existing analysis stale.
+    pub trigger: ReanalysisTrigger,
+    /// When the reanalysis was requested.
+    pub requested_at: Timestamp,
+}
+
+domain_event!(
+    ReanalysisRequested,
+    event_type = "analysis.reanalysis_requested.v1",
+    schema_version = 1,
+    aggregate_id = cycle_id,
+    aggregate_type = "Analysis",
+    occurred_at = requested_at,
+    event_id = event_id
+);
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -0,0 +1,572 @@
+//! Group Aggregation - Combines participants' preferences into a group view.
+//!
+//! When several people share a decision, each can score the alternatives
+//! (0-100) and weight the objectives. Three aggregation methods are
+//! reported side by side, since they can disagree:
+//!
+//! - **Borda count**: each participant's ranking gives an alternative one
+//!   point per alternative it beats (half a point per tie).
+//! - **Approval voting**: an alternative is approved by every participant
+//!   who scores it at or above a threshold.
+//! - **Weight averaging**: each participant's weights are normalized and
+//!   averaged, and the consequences table is scored with the group weights.
+//!
+//! The dissent report shows who disagrees with the group ranking and which
+//! objectives and alternatives the group is split on.
+
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+use crate::domain::proact::{ConsequencesOutput, ObjectivesOutput, ParticipantPreferences};
+
+use super::{ValueScorer, MAX_WEIGHTED_SCORE};
+
+/// Participants scoring an alternative at or above this approve of it.
+pub const DEFAULT_APPROVAL_THRESHOLD: f64 = 60.0;
+
+/// Objectives whose normalized weights have a standard deviation above
+/// this are reported as contested.
+pub const CONTESTED_WEIGHT_SPREAD: f64 = 0.15;
+
+/// Alternatives whose scores have a standard deviation above this are
+/// reported as contested.
+pub const CONTESTED_SCORE_SPREAD: f64 = 25.0;
+
+/// Values closer than this are treated as tied.
+const TIE_EPSILON: f64 = 1e-9;
+
+/// How the group rates one alternative under each method.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct GroupAlternative {
+    pub alternative_id: String,
+    pub borda_points: f64,
+    pub approvals: usize,
+    /// Mean of the participants' scores, if anyone scored it.
+    pub mean_score: Option<f64>,
+    /// Standard deviation of the participants' scores.
+    pub score_spread: f64,
+    /// Score out of 100 using the group's averaged weights.
+    pub weighted_score: f64,
+}
+
+/// The group's weight for one objective.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct GroupObjectiveWeight {
+    pub objective_id: String,
+    /// Mean of the participants' normalized weights.
+    pub mean_weight: f64,
+    /// Standard deviation of the participants' normalized weights.
+    pub spread: f64,
+    pub min_weight: f64,
+    pub max_weight: f64,
+}
+
+/// How far one participant is from the group ranking.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ParticipantDissent {
+    pub participant_id: String,
+    pub name: String,
+    /// The participant's single highest-scored alternative, if not tied.
+    pub top_choice: Option<String>,
+    /// Whether their top choice is the Borda winner.
+    pub agrees_with_group: bool,
+    /// Normalized Spearman footrule distance between their ranking and
+    /// the Borda ranking: 0 = identical, 1 = reversed.
+    pub rank_distance: f64,
+}
+
+/// Where the group disagrees.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct DissentReport {
+    /// Participants who scored at least one alternative, in input order.
+    pub participants: Vec<ParticipantDissent>,
+    /// Objectives with weight spread above [`CONTESTED_WEIGHT_SPREAD`].
+    pub contested_objectives: Vec<String>,
+    /// Alternatives with score spread above [`CONTESTED_SCORE_SPREAD`].
+    pub contested_alternatives: Vec<String>,
+    /// 1 minus the mean rank distance; None if nobody scored anything.
+    pub consensus: Option<f64>,
+}
+
+/// Result of aggregating a group's preferences.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct GroupAggregation {
+    pub participant_count: usize,
+    pub approval_threshold: f64,
+    /// In table order.
+    pub alternatives: Vec<GroupAlternative>,
+    /// In table order.
+    pub objectives: Vec<GroupObjectiveWeight>,
+    /// Winner under each method; None when tied or empty.
+    pub borda_winner: Option<String>,
+    pub approval_winner: Option<String>,
+    pub weighted_winner: Option<String>,
+    pub dissent: DissentReport,
+}
+
+/// Group preference aggregation.
+pub struct GroupAggregator;
+
+impl GroupAggregator {
+    /// Borda points per alternative. Alternatives a participant did not
+    /// score get no points from them.
+    pub fn borda(
+        participants: &[ParticipantPreferences],
+        alternative_ids: &[String],
+    ) -> HashMap<String, f64> {
+        let mut points: HashMap<String, f64> =
+            alternative_ids.iter().map(|id| (id.clone(), 0.0)).collect();
+        for participant in participants {
+            let scored: Vec<(&String, f64)> = alternative_ids
+                .iter()
+                .filter_map(|id| Some((id, *participant.alternative_scores.get(id)?)))
+                .collect();
+            for (id, score) in &scored {
+                let earned: f64 = scored
+                    .iter()
+                    .map(|(_, other)| {
+                        if (score - other).abs() <= TIE_EPSILON {
+                            0.5
+                        } else if score > other {
+                            1.0
+                        } else {
+                            0.0
+                        }
+                    })
+                    .sum::<f64>()
+                    - 0.5; // the alternative "ties" with itself
+                *points.entry((*id).clone()).or_default() += earned;
+            }
+        }
+        points
+    }
+
+    /// Number of participants approving each alternative.
+    pub fn approval(
+        participants: &[ParticipantPreferences],
+        alternative_ids: &[String],
+        threshold: f64,
+    ) -> HashMap<String, usize> {
+        alternative_ids
+            .iter()
+            .map(|id| {
+                let approvals = participants
+                    .iter()
+                    .filter(|p| {
+                        p.alternative_scores
+                            .get(id)
+                            .is_some_and(|s| *s >= threshold)
+                    })
+                    .count();
+                (id.clone(), approvals)
+            })
+            .collect()
+    }
+
+    /// Averages the participants' weights after normalizing each
+    /// participant's weights to sum to 1. Participants who weighted none of
+    /// the objectives are left out; if nobody did, objectives are weighted
+    /// equally.
+    pub fn average_weights(
+        participants: &[ParticipantPreferences],
+        objective_ids: &[String],
+    ) -> Vec<GroupObjectiveWeight> {
+        let weightings: Vec<Vec<f64>> = participants
+            .iter()
+            .filter_map(|p| {
+                let raw: Vec<f64> = objective_ids
+                    .iter()
+                    .map(|id| p.objective_weights.get(id).copied().unwrap_or(0.0).max(0.0))
+                    .collect();
+                let total: f64 = raw.iter().sum();
+                (total > 0.0).then(|| raw.iter().map(|w| w / total).collect())
+            })
+            .collect();
+
+        objective_ids
+            .iter()
+            .enumerate()
+            .map(|(i, id)| {
+                let weights: Vec<f64> = if weightings.is_empty() {
+                    vec![1.0 / objective_ids.len() as f64]
+                } else {
+                    weightings.iter().map(|w| w[i]).collect()
+                };
+                let (mean, spread) = mean_and_spread(&weights);
+                GroupObjectiveWeight {
+                    objective_id: id.clone(),
+                    mean_weight: mean,
+                    spread,
+                    min_weight: weights.iter().copied().fold(f64::INFINITY, f64::min),
+                    max_weight: weights.iter().copied().fold(f64::NEG_INFINITY, f64::max),
+                }
+            })
+            .collect()
+    }
+
+    /// Aggregates the participants' preferences over the alternatives and
+    /// objectives of the consequences table.
+    pub fn aggregate(
+        participants: &[ParticipantPreferences],
+        objectives: &ObjectivesOutput,
+        consequences: &ConsequencesOutput,
+        approval_threshold: f64,
+    ) -> GroupAggregation {
+        let alternative_ids = &consequences.table.alternative_ids;
+        let objective_ids = &consequences.table.objective_ids;
+
+        let borda = Self::borda(participants, alternative_ids);
+        let approvals = Self::approval(participants, alternative_ids, approval_threshold);
+        let group_weights = Self::average_weights(participants, objective_ids);
+        let weight_map: HashMap<String, f64> = group_weights
+            .iter()
+            .map(|w| (w.objective_id.clone(), w.mean_weight))
+            .collect();
+        let weighted = ValueScorer::score(objectives, consequences).weighted_totals(&weight_map);
+
+        let alternatives: Vec<GroupAlternative> = alternative_ids
+            .iter()
+            .map(|id| {
+                let scores: Vec<f64> = participants
+                    .iter()
+                    .filter_map(|p| p.alternative_scores.get(id).copied())
+                    .collect();
+                let (mean, spread) = mean_and_spread(&scores);
+                GroupAlternative {
+                    alternative_id: id.clone(),
+                    borda_points: borda[id],
+                    approvals: approvals[id],
+                    mean_score: (!scores.is_empty()).then_some(mean),
+                    score_spread: spread,
+                    weighted_score: weighted.get(id).copied().unwrap_or(0.0) * MAX_WEIGHTED_SCORE,
+                }
+            })
+            .collect();
+
+        let borda_winner = unique_max(
+            alternatives
+                .iter()
+                .map(|a| (&a.alternative_id, a.borda_points)),
+        );
+        let approval_winner = unique_max(
+            alternatives
+                .iter()
+                .map(|a| (&a.alternative_id, a.approvals as f64)),
+        );
+        let weighted_winner = unique_max(
+            alternatives
+                .iter()
+                .map(|a| (&a.alternative_id, a.weighted_score)),
+        );
+
+        let group_ranks = ranks(alternative_ids, |id| Some(borda[id]));
+        let dissenters: Vec<ParticipantDissent> = participants
+            .iter()
+            .filter(|p| {
+                alternative_ids
+                    .iter()
+                    .any(|id| p.alternative_scores.contains_key(id))
+            })
+            .map(|p| {
+                let top_choice = unique_max(
+                    alternative_ids
+                        .iter()
+                        .filter_map(|id| Some((id, *p.alternative_scores.get(id)?))),
+                );
+                let own_ranks = ranks(alternative_ids, |id| p.alternative_scores.get(id).copied());
+                ParticipantDissent {
+                    participant_id: p.participant_id.clone(),
+                    name: p.name.clone(),
+                    agrees_with_group: top_choice.is_some() && top_choice == borda_winner,
+                    top_choice,
+                    rank_distance: footrule(&own_ranks, &group_ranks),
+                }
+            })
+            .collect();
+        let consensus = (!dissenters.is_empty()).then(|| {
+            1.0 - dissenters.iter().map(|d| d.rank_distance).sum::<f64>() / dissenters.len() as f64
+        });
+
+        let dissent = DissentReport {
+            participants: dissenters,
+            contested_objectives: group_weights
+                .iter()
+                .filter(|w| w.spread > CONTESTED_WEIGHT_SPREAD)
+                .map(|w| w.objective_id.clone())
+                .collect(),
+            contested_alternatives: alternatives
+                .iter()
+                .filter(|a| a.score_spread > CONTESTED_SCORE_SPREAD)
+                .map(|a| a.alternative_id.clone())
+                .collect(),
+            consensus,
+        };
+
+        GroupAggregation {
+            participant_count: participants.len(),
+            approval_threshold,
+            alternatives,
+            objectives: group_weights,
+            borda_winner,
+            approval_winner,
+            weighted_winner,
+            dissent,
+        }
+    }
+}
+
+/// Mean and population standard deviation; (0, 0) when empty.
+fn mean_and_spread(values: &[f64]) -> (f64, f64) {
+    if values.is_empty() {
+        return (0.0, 0.0);
+    }
+    let n = values.len() as f64;
+    let mean = values.iter().sum::<f64>() / n;
+    let variance = values.iter().map(|v| (v - mean).powi(2)).sum::<f64>() / n;
+    (mean, variance.sqrt())
+}
+
+/// The ID with the single highest value, or None if tied or empty.
+fn unique_max<'a>(values: impl Iterator<Item = (&'a String, f64)>) -> Option<String> {
+    let mut best: Option<(&String, f64)> = None;
+    let mut tied = false;
+    for (id, value) in values {
+        match best {
+            Some((_, top)) if (value - top).abs() <= TIE_EPSILON => tied = true,
+            Some((_, top)) if value < top => {}
+            _ => {
+                best = Some((id, value));
+                tied = false;
+            }
+        }
+    }
+    if tied {
+        None
+    } else {
+        best.map(|(id, _)| id.clone())
+    }
+}
+
+/// Rank of each alternative (1 = best); ties share a rank and unscored
+/// alternatives rank below every scored one.
+fn ranks(alternative_ids: &[String], score: impl Fn(&String) -> Option<f64>) -> Vec<usize> {
+    let scores: Vec<Option<f64>> = alternative_ids.iter().map(&score).collect();
+    scores
+        .iter()
+        .map(|own| {
+            1 + scores
+                .iter()
+                .filter(|other| match (other, own) {
+                    (Some(o), Some(s)) => *o > s + TIE_EPSILON,
+                    (Some(_), None) => true,
+                    (None, _) => false,
+                })
+                .count()
+        })
+        .collect()
+}
+
+/// Spearman footrule distance scaled to 0-1 by its maximum, ⌊n²/2⌋.
+fn footrule(a: &[usize], b: &[usize]) -> f64 {
+    let n = a.len();
+    let max = (n * n / 2) as f64;
+    if max == 0.0 {
+        return 0.0;
+    }
+    let distance: usize = a.iter().zip(b).map(|(x, y)| x.abs_diff(*y)).sum();
+    (distance as f64 / max).min(1.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::foundation::Rating;
+    use crate::domain::proact::Cell;
+
+    fn participant(
+        id: &str,
+        scores: &[(&str, f64)],
+        weights: &[(&str, f64)],
+    ) -> ParticipantPreferences {
+        ParticipantPreferences {
+            participant_id: id.to_string(),
+            name: id.to_string(),
+            alternative_scores: scores.iter().map(|(a, s)| (a.to_string(), *s)).collect(),
+            objective_weights: weights.iter().map(|(o, w)| (o.to_string(), *w)).collect(),
+        }
+    }
+
+    fn ids(ids: &[&str]) -> Vec<String> {
+        ids.iter().map(|s| s.to_string()).collect()
+    }
+
+    /// Startup is great on pay, bank on security.
+    fn job_table() -> ConsequencesOutput {
+        let mut output = ConsequencesOutput::default();
+        output.table.alternative_ids = ids(&["startup", "bank", "gov"]);
+        output.table.objective_ids = ids(&["pay", "security"]);
+        let ratings = [
+            ("startup", "pay", Rating::MuchBetter),
+            ("startup", "security", Rating::MuchWorse),
+            ("bank", "pay", Rating::Same),
+            ("bank", "security", Rating::MuchBetter),
+            ("gov", "pay", Rating::Worse),
+            ("gov", "security", Rating::Better),
+        ];
+        for (alt, obj, rating) in ratings {
+            output
+                .table
+                .cells
+                .entry(alt.to_string())
+                .or_default()
+                .insert(obj.to_string(), Cell::new(rating, ""));
+        }
+        output
+    }
+
+    fn group() -> Vec<ParticipantPreferences> {
+        vec![
+            participant(
+                "ana",
+                &[("startup", 90.0), ("bank", 50.0), ("gov", 20.0)],
+                &[("pay", 0.8), ("security", 0.2)],
+            ),
+            participant(
+                "ben",
+                &[("startup", 30.0), ("bank", 80.0), ("gov", 70.0)],
+                &[("pay", 0.2), ("security", 0.8)],
+            ),
+            participant(
+                "cy",
+                &[("startup", 40.0), ("bank", 85.0), ("gov", 60.0)],
+                &[("pay", 1.0), ("security", 1.0)],
+            ),
+        ]
+    }
+
+    #[test]
+    fn borda_gives_a_point_per_alternative_beaten() {
+        let points = GroupAggregator::borda(&group(), &ids(&["startup", "bank", "gov"]));
+
+        assert_eq!(points["startup"], 2.0);
+        assert_eq!(points["bank"], 5.0);
+        assert_eq!(points["gov"], 2.0);
+    }
+
+    #[test]
+    fn borda_splits_ties() {
+        let participants = vec![participant(
+            "ana",
+            &[("a", 50.0), ("b", 50.0), ("c", 10.0)],
+            &[],
+        )];
+
+        let points = GroupAggregator::borda(&participants, &ids(&["a", "b", "c", "d"]));
+
+        assert_eq!(points["a"], 1.5);
+        assert_eq!(points["b"], 1.5);
+        assert_eq!(points["c"], 0.0);
+        assert_eq!(points["d"], 0.0);
+    }
+
+    #[test]
+    fn approval_counts_scores_at_threshold() {
+        let approvals =
+            GroupAggregator::approval(&group(), &ids(&["startup", "bank", "gov"]), 60.0);
+
+        assert_eq!(approvals["startup"], 1);
+        assert_eq!(approvals["bank"], 2);
+        assert_eq!(approvals["gov"], 2);
+    }
+
+    #[test]
+    fn weights_are_normalized_before_averaging() {
+        let weights = GroupAggregator::average_weights(&group(), &ids(&["pay", "security"]));
+
+        assert!((weights[0].mean_weight - 0.5).abs() < 1e-9);
+        assert!((weights[0].min_weight - 0.2).abs() < 1e-9);
+        assert!((weights[0].max_weight - 0.8).abs() < 1e-9);
+        assert!(weights[0].spread > CONTESTED_WEIGHT_SPREAD);
+    }
+
+    #[test]
+    fn no_weights_mean_equal_weights() {
+        let participants = vec![participant("ana", &[("a", 50.0)], &[])];
+
+        let weights = GroupAggregator::average_weights(&participants, &ids(&["x", "y"]));
+
+        assert_eq!(weights[0].mean_weight, 0.5);
+        assert_eq!(weights[0].spread, 0.0);
+    }
+
+    #[test]
+    fn aggregate_reports_winners_and_dissent() {
+        let result = GroupAggregator::aggregate(
+            &group(),
+            &ObjectivesOutput::default(),
+            &job_table(),
+            DEFAULT_APPROVAL_THRESHOLD,
+        );
+
+        assert_eq!(result.participant_count, 3);
+        assert_eq!(result.borda_winner.as_deref(), Some("bank"));
+        assert_eq!(result.approval_winner, None);
+        assert_eq!(result.weighted_winner.as_deref(), Some("bank"));
+
+        let ana = &result.dissent.participants[0];
+        assert_eq!(ana.top_choice.as_deref(), Some("startup"));
+        assert!(!ana.agrees_with_group);
+        assert!(ana.rank_distance > 0.5);
+        assert!(result.dissent.participants[1].agrees_with_group);
+        assert_eq!(
+            result.dissent.contested_objectives,
+            ids(&["pay", "security"])
+        );
+        assert_eq!(result.dissent.contested_alternatives, ids(&["startup"]));
+        assert!(result.dissent.consensus.unwrap() < 1.0);
+    }
+
+    #[test]
+    fn unanimous_group_has_full_consensus() {
+        let participants = vec![
+            participant(
+                "ana",
+                &[("startup", 10.0), ("bank", 90.0), ("gov", 50.0)],
+                &[],
+            ),
+            participant(
+                "ben",
+                &[("startup", 20.0), ("bank", 80.0), ("gov", 40.0)],
+                &[],
+            ),
+        ];
+
+        let result = GroupAggregator::aggregate(
+            &participants,
+            &ObjectivesOutput::default(),
+            &job_table(),
+            DEFAULT_APPROVAL_THRESHOLD,
+        );
+
+        assert_eq!(result.dissent.consensus, Some(1.0));
+        assert!(result
+            .dissent
+            .participants
+            .iter()
+            .all(|p| p.agrees_with_group));
+    }
+
+    #[test]
+    fn no_participants_means_no_consensus() {
+        let result = GroupAggregator::aggregate(
+            &[],
+            &ObjectivesOutput::default(),
+            &job_table(),
+            DEFAULT_APPROVAL_THRESHOLD,
+        );
+
+        assert_eq!(result.borda_winner, None);
+        assert_eq!(result.dissent.consensus, None);
+        assert!(result.dissent.participants.is_empty());
+    }
+}
@@ -9,6 +9,18 @@
 //! - `PughAnalyzer` - Score computation, dominance detection, irrelevant objectives
 //! - `DQCalculator` - Decision Quality scoring (7 elements, overall = minimum)
 //! - `TradeoffAnalyzer` - Tension analysis for non-dominated alternatives
+//! - `EvenSwapsAnalyzer` - Proposes and applies even swaps that shrink the
+//!   consequences table
+//! - `InformationValueCalculator` - Value of perfect or partial information
+//!   about consequences-table uncertainties
+//! - `ValueScorer` - Converts consequences to 0-1 values through each
+//!   objective's value function, for weighted scoring
+//! - `WeightedScoringAnalyzer` - Absolute weighted scores with per-objective
+//!   contributions, an alternative to Pugh comparison
+//! - `GroupAggregator` - Combines participants' scores and weights by Borda
+//!   count, approval voting, and weight averaging, with a dissent report
+//! - `ReanalysisPlan` - Components to re-run or revisit after a
+//!   `ReanalysisTrigger` such as changed objective weights
 //!
 //! # Design Philosophy
 //!
@@ -18,17 +30,45 @@
 
 mod consequences_table;
 mod dq_calculator;
+mod even_swaps;
 mod events;
+mod group_aggregation;
 mod pugh_analyzer;
+mod reanalysis;
 mod tradeoff_analyzer;
+mod value_of_information;
+mod value_scoring;
+mod weighted_scoring;
 
 // Re-export all public types
 pub use consequences_table::{Cell, ConsequencesTable, ConsequencesTableBuilder};
 pub use dq_calculator::{
     DQCalculator, DQElement, Priority, DQ_ACCEPTABLE_THRESHOLD, DQ_ELEMENT_NAMES,
 };
+pub use even_swaps::{
+    EvenSwap, EvenSwapError, EvenSwapsAnalyzer, SimplifiedTable, SwapCandidate,
+    ACCEPT_SWAP_OPTION, REJECT_SWAP_OPTION,
+};
 pub use events::{
-    DQElementScore, DQScoresComputed, PughScoresComputed, TensionSummary, TradeoffsAnalyzed,
+    DQElementScore, DQScoresComputed, PughScoresComputed, ReanalysisRequested, TensionSummary,
+    TradeoffsAnalyzed,
+};
+pub use group_aggregation::{
+    DissentReport, GroupAggregation, GroupAggregator, GroupAlternative, GroupObjectiveWeight,
+    ParticipantDissent, CONTESTED_SCORE_SPREAD, CONTESTED_WEIGHT_SPREAD,
+    DEFAULT_APPROVAL_THRESHOLD,
 };
 pub use pugh_analyzer::{DominatedAlternative, IrrelevantObjective, PughAnalyzer};
+pub use reanalysis::{
+    ReanalysisPlan, ReanalysisTrigger, ANALYZED_COMPONENTS, JUDGMENT_COMPONENTS,
+};
 pub use tradeoff_analyzer::{Tension, TradeoffAnalyzer, TradeoffSummary};
+pub use value_of_information::{
+    InformationValue, InformationValueCalculator, InformationValueError, OutcomeChoice,
+    ScoreConversion,
+};
+pub use value_scoring::{ObjectiveValues, ValueBasis, ValueScorer};
+pub use weighted_scoring::{
+    AlternativeScore, ObjectiveContribution, ObjectiveWeight, WeightedScores,
+    WeightedScoringAnalyzer, MAX_WEIGHTED_SCORE,
+};
@@ -0,0 +1,125 @@
+//! Reanalysis planning - Which components go stale when the basis of the
+//! analysis changes.
+//!
+//! Changing objective weights or the analysis method invalidates everything
+//! computed downstream of the consequences table. Computed parts (Pugh
+//! scores, tradeoff tensions, the overall DQ score) can simply be re-run;
+//! parts that record the user's judgment (the recommendation and their DQ
+//! ratings) cannot, so the user is asked to revisit them instead.
+
+use serde::{Deserialize, Serialize};
+
+use crate::domain::foundation::{ComponentStatus, ComponentType};
+
+/// Components whose outputs an analyzer computes, in PrOACT order.
+pub const ANALYZED_COMPONENTS: [ComponentType; 3] = [
+    ComponentType::Consequences,
+    ComponentType::Tradeoffs,
+    ComponentType::DecisionQuality,
+];
+
+/// Components holding the user's own judgment about the analysis, in PrOACT
+/// order.
+pub const JUDGMENT_COMPONENTS: [ComponentType; 2] = [
+    ComponentType::Recommendation,
+    ComponentType::DecisionQuality,
+];
+
+/// What changed to make existing analysis stale.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ReanalysisTrigger {
+    /// The relative importance of objectives changed.
+    ObjectiveWeightsChanged,
+    /// The user switched to a different analysis method.
+    AnalysisMethodChanged,
+}
+
+impl ReanalysisTrigger {
+    /// What changed, as a sentence fragment for document notes and
+    /// suggestions.
+    pub fn description(&self) -> &'static str {
+        match self {
+            ReanalysisTrigger::ObjectiveWeightsChanged => "Objective weights changed",
+            ReanalysisTrigger::AnalysisMethodChanged => "The analysis method changed",
+        }
+    }
+}
+
+/// The work a reanalysis involves for one cycle.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ReanalysisPlan {
+    /// Components to re-run analyzers for.
+    pub recompute: Vec<ComponentType>,
+    /// Components the user should revisit.
+    pub revisit: Vec<ComponentType>,
+}
+
+impl ReanalysisPlan {
+    /// Plans a reanalysis from the cycle's component statuses. Components
+    /// not yet started have nothing to go stale, so they are left out.
+    pub fn for_statuses(status: impl Fn(ComponentType) -> ComponentStatus) -> Self {
+        let started = |ct: &ComponentType| status(*ct).is_started();
+        Self {
+            recompute: ANALYZED_COMPONENTS.into_iter().filter(started).collect(),
+            revisit: JUDGMENT_COMPONENTS.into_iter().filter(started).collect(),
+        }
+    }
+
+    /// Every affected component, in PrOACT order without duplicates.
+    pub fn stale_components(&self) -> Vec<ComponentType> {
+        let mut components: Vec<_> = self
+            .recompute
+            .iter()
+            .chain(&self.revisit)
+            .copied()
+            .collect();
+        components.sort_by_key(|ct| ct.order_index());
+        components.dedup();
+        components
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.recompute.is_empty() && self.revisit.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn plans_only_started_components() {
+        let plan = ReanalysisPlan::for_statuses(|ct| match ct {
+            ComponentType::Consequences | ComponentType::Tradeoffs => ComponentStatus::Complete,
+            ComponentType::Recommendation => ComponentStatus::InProgress,
+            _ => ComponentStatus::NotStarted,
+        });
+
+        assert_eq!(
+            plan.recompute,
+            vec![ComponentType::Consequences, ComponentType::Tradeoffs]
+        );
+        assert_eq!(plan.revisit, vec![ComponentType::Recommendation]);
+    }
+
+    #[test]
+    fn stale_components_are_ordered_and_deduplicated() {
+        let plan = ReanalysisPlan::for_statuses(|_| ComponentStatus::Complete);
+
+        assert_eq!(
+            plan.stale_components(),
+            vec![
+                ComponentType::Consequences,
+                ComponentType::Tradeoffs,
+                ComponentType::Recommendation,
+                ComponentType::DecisionQuality,
+            ]
+        );
+    }
+
+    #[test]
+    fn nothing_to_do_before_consequences() {
+        assert!(ReanalysisPlan::for_statuses(|_| ComponentStatus::NotStarted).is_empty());
+    }
+}
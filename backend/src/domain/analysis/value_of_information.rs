@@ -0,0 +1,464 @@
+//! Value of Information - How much resolving an uncertainty is worth.
+//!
+//! Each consequences-table uncertainty may list the ways it could resolve,
+//! with a probability and the ratings that would change under each. The
+//! expected value of perfect information (EVPI) is how much better the
+//! decision is expected to turn out if the outcome were known before
+//! choosing; partial information uses a source that reports the right
+//! outcome only some of the time. Values are in Pugh score points, and a
+//! `ScoreConversion` turns them into the amount worth paying.
+
+use serde::{Deserialize, Serialize};
+
+use crate::domain::proact::{Uncertainty, UncertaintyOutcome, PROBABILITY_TOLERANCE};
+
+use super::{Cell, ConsequencesTable, PughAnalyzer};
+
+/// Values below this are treated as zero.
+const VALUE_EPSILON: f64 = 1e-9;
+
+/// Converts Pugh score points into a unit the user pays in.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ScoreConversion {
+    /// e.g. "USD" or "hours".
+    pub unit: String,
+    /// How much one point of Pugh score is worth in that unit.
+    pub per_point: f64,
+}
+
+/// The alternative that would be chosen under one outcome.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct OutcomeChoice {
+    pub outcome: String,
+    pub alternative_id: String,
+}
+
+/// The value of learning about one uncertainty before deciding.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct InformationValue {
+    pub uncertainty_id: String,
+    pub description: String,
+    /// Reliability of the information source (1.0 = perfect).
+    pub accuracy: f64,
+    /// Alternative with the best expected score when deciding now.
+    pub best_without: Option<String>,
+    pub expected_score_without: f64,
+    pub expected_score_with: f64,
+    /// Expected gain in Pugh score from learning before deciding.
+    pub value: f64,
+    /// Best alternative if each outcome were known.
+    pub best_by_outcome: Vec<OutcomeChoice>,
+}
+
+impl InformationValue {
+    /// Returns true if the information could change the choice.
+    pub fn changes_decision(&self) -> bool {
+        self.value > VALUE_EPSILON
+    }
+
+    /// Most worth paying for the information, in the conversion's unit.
+    pub fn worth_paying(&self, conversion: &ScoreConversion) -> f64 {
+        self.value * conversion.per_point
+    }
+
+    /// One-sentence advice for the Recommendation component.
+    pub fn advice(&self, conversion: Option<&ScoreConversion>) -> String {
+        if !self.changes_decision() {
+            return format!(
+                "Resolving \"{}\" would not change the choice, so it is not worth paying for before deciding.",
+                self.description
+            );
+        }
+
+        let action = if self.accuracy < 1.0 {
+            format!(
+                "for information about \"{}\" that is {:.0}% reliable",
+                self.description,
+                self.accuracy * 100.0
+            )
+        } else {
+            format!("to resolve \"{}\"", self.description)
+        };
+
+        match conversion {
+            Some(conversion) => format!(
+                "It is worth paying up to {:.0} {} {} before deciding.",
+                self.worth_paying(conversion),
+                conversion.unit,
+                action
+            ),
+            None => format!(
+                "It is worth up to {:.2} points of expected score {} before deciding.",
+                self.value, action
+            ),
+        }
+    }
+}
+
+/// Why the value of information could not be computed.
+#[derive(Debug, Clone, PartialEq, thiserror::Error)]
+pub enum InformationValueError {
+    #[error("Uncertainty '{0}' has no outcomes to compare")]
+    NoOutcomes(String),
+
+    #[error("Uncertainty '{0}' has an outcome with a negative probability")]
+    NegativeProbability(String),
+
+    #[error("Outcome probabilities for uncertainty '{uncertainty_id}' sum to {sum}, not 1")]
+    IncoherentProbabilities { uncertainty_id: String, sum: f64 },
+
+    #[error("Information accuracy must be greater than 0 and at most 1, got {0}")]
+    InvalidAccuracy(f64),
+}
+
+/// Value of information calculations.
+pub struct InformationValueCalculator;
+
+impl InformationValueCalculator {
+    /// Computes the expected value of perfect information.
+    pub fn perfect(
+        table: &ConsequencesTable,
+        uncertainty: &Uncertainty,
+    ) -> Result<InformationValue, InformationValueError> {
+        Self::partial(table, uncertainty, 1.0)
+    }
+
+    /// Computes the expected value of information from a source that
+    /// reports the true outcome with probability `accuracy`, and each other
+    /// outcome equally often otherwise.
+    ///
+    /// # Algorithm
+    /// - Without information: max over alternatives of Σ p(s)·V(a, s)
+    /// - With information: Σ over reports k of max over alternatives of
+    ///   Σ p(s)·p(k | s)·V(a, s)
+    ///
+    /// where V(a, s) is the Pugh score of `a` with outcome `s`'s rating
+    /// changes applied. The value is the difference.
+    pub fn partial(
+        table: &ConsequencesTable,
+        uncertainty: &Uncertainty,
+        accuracy: f64,
+    ) -> Result<InformationValue, InformationValueError> {
+        if !(accuracy > 0.0 && accuracy <= 1.0) {
+            return Err(InformationValueError::InvalidAccuracy(accuracy));
+        }
+        Self::check_probabilities(uncertainty)?;
+
+        let outcomes = &uncertainty.outcomes;
+        let scores: Vec<Vec<f64>> = outcomes
+            .iter()
+            .map(|outcome| Self::scores_under(table, outcome))
+            .collect();
+
+        let expected: Vec<f64> = (0..table.alternative_ids.len())
+            .map(|a| {
+                outcomes
+                    .iter()
+                    .zip(&scores)
+                    .map(|(outcome, s)| outcome.probability * s[a])
+                    .sum()
+            })
+            .collect();
+        let (best_now, expected_score_without) = Self::best(&expected);
+
+        let n = outcomes.len();
+        let likelihood = |report: usize, truth: usize| {
+            if n == 1 {
+                1.0
+            } else if report == truth {
+                accuracy
+            } else {
+                (1.0 - accuracy) / (n - 1) as f64
+            }
+        };
+        let expected_score_with: f64 = (0..n)
+            .map(|report| {
+                let weighted: Vec<f64> = (0..table.alternative_ids.len())
+                    .map(|a| {
+                        outcomes
+                            .iter()
+                            .zip(&scores)
+                            .enumerate()
+                            .map(|(truth, (outcome, s))| {
+                                outcome.probability * likelihood(report, truth) * s[a]
+                            })
+                            .sum()
+                    })
+                    .collect();
+                Self::best(&weighted).1
+            })
+            .sum();
+
+        let best_by_outcome = outcomes
+            .iter()
+            .zip(&scores)
+            .filter_map(|(outcome, s)| {
+                Self::best(s).0.map(|a| OutcomeChoice {
+                    outcome: outcome.label.clone(),
+                    alternative_id: table.alternative_ids[a].clone(),
+                })
+            })
+            .collect();
+
+        Ok(InformationValue {
+            uncertainty_id: uncertainty.id.clone(),
+            description: uncertainty.description.clone(),
+            accuracy,
+            best_without: best_now.map(|a| table.alternative_ids[a].clone()),
+            expected_score_without,
+            expected_score_with,
+            value: (expected_score_with - expected_score_without).max(0.0),
+            best_by_outcome,
+        })
+    }
+
+    /// Values every uncertainty that has usable outcomes, highest first.
+    ///
+    /// Uncertainties without outcomes or with incoherent probabilities are
+    /// skipped.
+    pub fn rank(
+        table: &ConsequencesTable,
+        uncertainties: &[Uncertainty],
+        accuracy: f64,
+    ) -> Vec<InformationValue> {
+        let mut values: Vec<InformationValue> = uncertainties
+            .iter()
+            .filter_map(|u| Self::partial(table, u, accuracy).ok())
+            .collect();
+        values.sort_by(|a, b| b.value.total_cmp(&a.value));
+        values
+    }
+
+    /// Checks that outcomes exist, are non-negative and sum to 1.
+    fn check_probabilities(uncertainty: &Uncertainty) -> Result<(), InformationValueError> {
+        if uncertainty.outcomes.is_empty() {
+            return Err(InformationValueError::NoOutcomes(uncertainty.id.clone()));
+        }
+        if uncertainty.outcomes.iter().any(|o| o.probability < 0.0) {
+            return Err(InformationValueError::NegativeProbability(
+                uncertainty.id.clone(),
+            ));
+        }
+        let sum: f64 = uncertainty.outcomes.iter().map(|o| o.probability).sum();
+        if (sum - 1.0).abs() > PROBABILITY_TOLERANCE {
+            return Err(InformationValueError::IncoherentProbabilities {
+                uncertainty_id: uncertainty.id.clone(),
+                sum,
+            });
+        }
+        Ok(())
+    }
+
+    /// Pugh scores in alternative order with an outcome's changes applied.
+    fn scores_under(table: &ConsequencesTable, outcome: &UncertaintyOutcome) -> Vec<f64> {
+        let mut changed = table.clone();
+        for change in &outcome.rating_changes {
+            changed.cells.insert(
+                format!("{}:{}", change.alternative_id, change.objective_id),
+                Cell::new(
+                    change.alternative_id.clone(),
+                    change.objective_id.clone(),
+                    change.rating,
+                ),
+            );
+        }
+
+        let scores = PughAnalyzer::compute_scores(&changed);
+        table
+            .alternative_ids
+            .iter()
+            .map(|id| scores.get(id).copied().unwrap_or(0) as f64)
+            .collect()
+    }
+
+    /// Index and value of the highest value; ties go to the first.
+    fn best(values: &[f64]) -> (Option<usize>, f64) {
+        values
+            .iter()
+            .enumerate()
+            .fold((None, 0.0), |(best, max), (i, &v)| match best {
+                Some(_) if v <= max => (best, max),
+                _ => (Some(i), v),
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::foundation::Rating;
+    use crate::domain::proact::RatingChange;
+
+    /// A and B both score 1 today; A's growth depends on the economy.
+    fn table() -> ConsequencesTable {
+        ConsequencesTable::builder()
+            .alternatives(vec!["A", "B"])
+            .objectives(vec!["cost", "growth"])
+            .cell("A", "cost", Rating::Same)
+            .cell("A", "growth", Rating::Better)
+            .cell("B", "cost", Rating::Better)
+            .cell("B", "growth", Rating::Same)
+            .build()
+    }
+
+    fn outcome(label: &str, probability: f64, a_growth: Option<Rating>) -> UncertaintyOutcome {
+        UncertaintyOutcome {
+            label: label.to_string(),
+            probability,
+            rating_changes: a_growth
+                .map(|rating| RatingChange {
+                    alternative_id: "A".to_string(),
+                    objective_id: "growth".to_string(),
+                    rating,
+                })
+                .into_iter()
+                .collect(),
+        }
+    }
+
+    fn economy(outcomes: Vec<UncertaintyOutcome>) -> Uncertainty {
+        Uncertainty {
+            id: "u1".to_string(),
+            description: "Economy".to_string(),
+            driver: "Interest rates".to_string(),
+            worth_resolving: true,
+            resolvable: true,
+            outcomes,
+            elicitation: None,
+        }
+    }
+
+    fn boom_or_bust() -> Uncertainty {
+        economy(vec![
+            outcome("boom", 0.6, Some(Rating::MuchBetter)),
+            outcome("bust", 0.4, Some(Rating::Worse)),
+        ])
+    }
+
+    fn assert_close(actual: f64, expected: f64) {
+        assert!(
+            (actual - expected).abs() < 1e-9,
+            "expected {}, got {}",
+            expected,
+            actual
+        );
+    }
+
+    #[test]
+    fn perfect_information_matches_hand_calculation() {
+        let value = InformationValueCalculator::perfect(&table(), &boom_or_bust()).unwrap();
+
+        // Now: A = 0.6·2 + 0.4·(−1) = 0.8, B = 1.0. Knowing: 0.6·2 + 0.4·1 = 1.6.
+        assert_eq!(value.best_without.as_deref(), Some("B"));
+        assert_close(value.expected_score_without, 1.0);
+        assert_close(value.expected_score_with, 1.6);
+        assert_close(value.value, 0.6);
+        assert_eq!(
+            value.best_by_outcome,
+            vec![
+                OutcomeChoice {
+                    outcome: "boom".to_string(),
+                    alternative_id: "A".to_string()
+                },
+                OutcomeChoice {
+                    outcome: "bust".to_string(),
+                    alternative_id: "B".to_string()
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn partial_information_is_worth_less_than_perfect() {
+        let value = InformationValueCalculator::partial(&table(), &boom_or_bust(), 0.8).unwrap();
+
+        // Report boom: A = 0.48·2 − 0.08 = 0.88. Report bust: B = 0.44.
+        assert_close(value.expected_score_with, 1.32);
+        assert_close(value.value, 0.32);
+    }
+
+    #[test]
+    fn information_that_never_changes_the_choice_is_worthless() {
+        let uncertainty = economy(vec![
+            outcome("boom", 0.5, Some(Rating::MuchBetter)),
+            outcome("flat", 0.5, None),
+        ]);
+
+        let value = InformationValueCalculator::perfect(&table(), &uncertainty).unwrap();
+
+        assert!(!value.changes_decision());
+        assert!(value.advice(None).contains("would not change the choice"));
+    }
+
+    #[test]
+    fn probabilities_must_sum_to_one() {
+        let uncertainty = economy(vec![outcome("boom", 0.6, None), outcome("bust", 0.6, None)]);
+
+        let result = InformationValueCalculator::perfect(&table(), &uncertainty);
+
+        assert!(matches!(
+            result,
+            Err(InformationValueError::IncoherentProbabilities { .. })
+        ));
+    }
+
+    #[test]
+    fn negative_probabilities_are_rejected() {
+        let uncertainty = economy(vec![
+            outcome("boom", 1.2, None),
+            outcome("bust", -0.2, None),
+        ]);
+
+        let result = InformationValueCalculator::perfect(&table(), &uncertainty);
+
+        assert_eq!(
+            result,
+            Err(InformationValueError::NegativeProbability("u1".to_string()))
+        );
+    }
+
+    #[test]
+    fn uncertainty_without_outcomes_is_rejected() {
+        let result = InformationValueCalculator::perfect(&table(), &economy(vec![]));
+
+        assert_eq!(
+            result,
+            Err(InformationValueError::NoOutcomes("u1".to_string()))
+        );
+    }
+
+    #[test]
+    fn accuracy_must_be_in_range() {
+        let result = InformationValueCalculator::partial(&table(), &boom_or_bust(), 0.0);
+
+        assert_eq!(result, Err(InformationValueError::InvalidAccuracy(0.0)));
+    }
+
+    #[test]
+    fn rank_orders_by_value_and_skips_unusable_uncertainties() {
+        let mut flat = economy(vec![outcome("flat", 1.0, None)]);
+        flat.id = "u2".to_string();
+        let mut unknown = economy(vec![]);
+        unknown.id = "u3".to_string();
+
+        let ranked =
+            InformationValueCalculator::rank(&table(), &[flat, boom_or_bust(), unknown], 1.0);
+
+        let ids: Vec<&str> = ranked.iter().map(|v| v.uncertainty_id.as_str()).collect();
+        assert_eq!(ids, ["u1", "u2"]);
+    }
+
+    #[test]
+    fn advice_converts_value_to_amount_worth_paying() {
+        let value = InformationValueCalculator::perfect(&table(), &boom_or_bust()).unwrap();
+        let conversion = ScoreConversion {
+            unit: "USD".to_string(),
+            per_point: 500.0,
+        };
+
+        assert_eq!(
+            value.advice(Some(&conversion)),
+            "It is worth paying up to 300 USD to resolve \"Economy\" before deciding."
+        );
+    }
+}
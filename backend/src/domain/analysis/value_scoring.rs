@@ -0,0 +1,262 @@
+//! Value Scoring - Puts every consequence on a common 0-1 value scale.
+//!
+//! Weighted scoring needs each alternative's performance on each objective
+//! expressed as a value between 0 (worst) and 1 (best). When every
+//! alternative has a quantitative level for an objective, the objective's
+//! `PerformanceMeasure` value function converts it, so diminishing returns
+//! are respected. Otherwise the Pugh rating is mapped linearly, -2 to 0 and
+//! +2 to 1.
+
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+use crate::domain::proact::{Cell, ConsequencesOutput, ObjectivesOutput, PerformanceMeasure};
+
+/// How a consequence was turned into a value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ValueBasis {
+    /// The quantitative level through the objective's value function.
+    Measure,
+    /// The Pugh rating.
+    Rating,
+}
+
+/// Values (0.0-1.0) of each alternative on each objective.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ObjectiveValues {
+    pub alternative_ids: Vec<String>,
+    pub objective_ids: Vec<String>,
+    /// values[alt_id][obj_id]; missing cells have no value.
+    pub values: HashMap<String, HashMap<String, f64>>,
+    /// How each objective's values were derived.
+    pub basis: HashMap<String, ValueBasis>,
+}
+
+impl ObjectiveValues {
+    /// Value of an alternative on an objective.
+    pub fn get(&self, alternative_id: &str, objective_id: &str) -> Option<f64> {
+        self.values
+            .get(alternative_id)
+            .and_then(|row| row.get(objective_id))
+            .copied()
+    }
+
+    /// Weights of the table's objectives scaled to sum to 1.
+    ///
+    /// Objectives without a weight count as 0; if no objective has a
+    /// positive weight, all are weighted equally.
+    pub fn normalized_weights(&self, weights: &HashMap<String, f64>) -> HashMap<String, f64> {
+        let total: f64 = self
+            .objective_ids
+            .iter()
+            .map(|id| weights.get(id).copied().unwrap_or(0.0).max(0.0))
+            .sum();
+        self.objective_ids
+            .iter()
+            .map(|id| {
+                let weight = if total > 0.0 {
+                    weights.get(id).copied().unwrap_or(0.0).max(0.0) / total
+                } else {
+                    1.0 / self.objective_ids.len() as f64
+                };
+                (id.clone(), weight)
+            })
+            .collect()
+    }
+
+    /// Weighted sum of values per alternative. Missing cells add nothing.
+    pub fn weighted_totals(&self, weights: &HashMap<String, f64>) -> HashMap<String, f64> {
+        let weights = self.normalized_weights(weights);
+        self.alternative_ids
+            .iter()
+            .map(|alt| {
+                let total = self
+                    .objective_ids
+                    .iter()
+                    .filter_map(|obj| Some(weights[obj] * self.get(alt, obj)?))
+                    .sum();
+                (alt.clone(), total)
+            })
+            .collect()
+    }
+}
+
+/// Converts consequences into values.
+pub struct ValueScorer;
+
+impl ValueScorer {
+    /// Computes the value of every filled cell in the consequences table.
+    pub fn score(objectives: &ObjectivesOutput, consequences: &ConsequencesOutput) -> ObjectiveValues {
+        let table = &consequences.table;
+        let mut result = ObjectiveValues {
+            alternative_ids: table.alternative_ids.clone(),
+            objective_ids: table.objective_ids.clone(),
+            ..Default::default()
+        };
+
+        for obj in &table.objective_ids {
+            let cells: Vec<_> = table
+                .alternative_ids
+                .iter()
+                .filter_map(|alt| Some((alt, table.cells.get(alt)?.get(obj)?)))
+                .collect();
+            let measure = objectives
+                .fundamental_objectives
+                .iter()
+                .find(|o| &o.id == obj)
+                .map(|o| &o.performance_measure);
+            let levels: Option<Vec<f64>> = cells.iter().map(|(_, cell)| cell.quant_value).collect();
+
+            let basis = match (measure, levels) {
+                (Some(measure), Some(levels)) if !levels.is_empty() => {
+                    Self::score_levels(&mut result, obj, measure, &cells, &levels);
+                    ValueBasis::Measure
+                }
+                _ => {
+                    for (alt, cell) in &cells {
+                        let value = (f64::from(cell.rating.value()) + 2.0) / 4.0;
+                        result
+                            .values
+                            .entry((*alt).clone())
+                            .or_default()
+                            .insert(obj.clone(), value);
+                    }
+                    ValueBasis::Rating
+                }
+            };
+            result.basis.insert(obj.clone(), basis);
+        }
+
+        result
+    }
+
+    fn score_levels(
+        result: &mut ObjectiveValues,
+        objective_id: &str,
+        measure: &PerformanceMeasure,
+        cells: &[(&String, &Cell)],
+        levels: &[f64],
+    ) {
+        let min = levels.iter().copied().fold(f64::INFINITY, f64::min);
+        let max = levels.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+        for ((alt, _), level) in cells.iter().zip(levels) {
+            result
+                .values
+                .entry((*alt).clone())
+                .or_default()
+                .insert(objective_id.to_string(), measure.value(*level, min, max));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::foundation::Rating;
+    use crate::domain::proact::{FundamentalObjective, ValueFunction};
+
+    fn objective(id: &str, direction: &str, value_function: Option<ValueFunction>) -> FundamentalObjective {
+        FundamentalObjective {
+            id: id.to_string(),
+            description: id.to_string(),
+            performance_measure: PerformanceMeasure {
+                description: id.to_string(),
+                is_quantitative: true,
+                unit: None,
+                direction: direction.to_string(),
+                value_function,
+            },
+            affected_party_id: None,
+            weight: None,
+        }
+    }
+
+    fn consequences(cells: &[(&str, &str, Cell)]) -> ConsequencesOutput {
+        let mut output = ConsequencesOutput::default();
+        for (alt, obj, cell) in cells {
+            if !output.table.alternative_ids.iter().any(|a| a == alt) {
+                output.table.alternative_ids.push(alt.to_string());
+            }
+            if !output.table.objective_ids.iter().any(|o| o == obj) {
+                output.table.objective_ids.push(obj.to_string());
+            }
+            output
+                .table
+                .cells
+                .entry(alt.to_string())
+                .or_default()
+                .insert(obj.to_string(), cell.clone());
+        }
+        output
+    }
+
+    fn salary_table() -> ConsequencesOutput {
+        consequences(&[
+            ("a", "salary", Cell::new(Rating::Same, "").with_quantitative(50_000.0, "USD")),
+            ("b", "salary", Cell::new(Rating::Better, "").with_quantitative(80_000.0, "USD")),
+            ("c", "salary", Cell::new(Rating::MuchBetter, "").with_quantitative(150_000.0, "USD")),
+        ])
+    }
+
+    #[test]
+    fn quantitative_levels_use_linear_value_by_default() {
+        let objectives = ObjectivesOutput {
+            fundamental_objectives: vec![objective("salary", "higher_is_better", None)],
+            means_objectives: vec![],
+        };
+
+        let values = ValueScorer::score(&objectives, &salary_table());
+
+        assert_eq!(values.basis["salary"], ValueBasis::Measure);
+        assert_eq!(values.get("a", "salary"), Some(0.0));
+        assert!((values.get("b", "salary").unwrap() - 0.3).abs() < 1e-9);
+        assert_eq!(values.get("c", "salary"), Some(1.0));
+    }
+
+    #[test]
+    fn value_function_models_diminishing_returns() {
+        let concave = ValueFunction::exponential_from_midvalue(50_000.0, 150_000.0, 80_000.0).unwrap();
+        let objectives = ObjectivesOutput {
+            fundamental_objectives: vec![objective("salary", "higher_is_better", Some(concave))],
+            means_objectives: vec![],
+        };
+
+        let values = ValueScorer::score(&objectives, &salary_table());
+
+        assert!((values.get("b", "salary").unwrap() - 0.5).abs() < 1e-6);
+    }
+
+    #[test]
+    fn ratings_are_used_without_levels() {
+        let table = consequences(&[
+            ("a", "fun", Cell::new(Rating::MuchWorse, "")),
+            ("b", "fun", Cell::new(Rating::Better, "")),
+        ]);
+
+        let values = ValueScorer::score(&ObjectivesOutput::default(), &table);
+
+        assert_eq!(values.basis["fun"], ValueBasis::Rating);
+        assert_eq!(values.get("a", "fun"), Some(0.0));
+        assert_eq!(values.get("b", "fun"), Some(0.75));
+    }
+
+    #[test]
+    fn weighted_totals_normalize_weights() {
+        let table = consequences(&[
+            ("a", "x", Cell::new(Rating::MuchBetter, "")),
+            ("a", "y", Cell::new(Rating::MuchWorse, "")),
+            ("b", "x", Cell::new(Rating::MuchWorse, "")),
+            ("b", "y", Cell::new(Rating::MuchBetter, "")),
+        ]);
+        let values = ValueScorer::score(&ObjectivesOutput::default(), &table);
+
+        let weights = HashMap::from([("x".to_string(), 3.0), ("y".to_string(), 1.0)]);
+        let totals = values.weighted_totals(&weights);
+        assert!((totals["a"] - 0.75).abs() < 1e-9);
+        assert!((totals["b"] - 0.25).abs() < 1e-9);
+
+        let equal = values.weighted_totals(&HashMap::new());
+        assert!((equal["a"] - 0.5).abs() < 1e-9);
+    }
+}
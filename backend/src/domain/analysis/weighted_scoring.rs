@@ -0,0 +1,260 @@
+//! Weighted Scoring - Absolute scores from weighted objective values.
+//!
+//! An alternative to Pugh comparison. Each consequence is converted to a 0-1
+//! value by `ValueScorer`, multiplied by its objective's normalized weight,
+//! and summed into a score out of 100. Unlike Pugh totals, which only say how
+//! alternatives compare to a baseline, the breakdown shows how many points
+//! each objective contributes.
+
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+use crate::domain::proact::{ConsequencesOutput, ObjectivesOutput};
+
+use super::{ValueBasis, ValueScorer};
+
+/// Scores are reported out of this many points.
+pub const MAX_WEIGHTED_SCORE: f64 = 100.0;
+
+/// Scores closer than this are ranked as tied.
+const TIE_EPSILON: f64 = 1e-9;
+
+/// Weight given to one objective.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ObjectiveWeight {
+    pub objective_id: String,
+    /// Normalized weight; weights of all objectives sum to 1.
+    pub weight: f64,
+    pub basis: ValueBasis,
+}
+
+/// Points an objective adds to an alternative's score.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ObjectiveContribution {
+    pub objective_id: String,
+    /// Value of the consequence (0.0-1.0), if rated.
+    pub value: Option<f64>,
+    /// weight × value, in score points.
+    pub points: f64,
+}
+
+/// Weighted score of one alternative.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AlternativeScore {
+    pub alternative_id: String,
+    /// Total out of [`MAX_WEIGHTED_SCORE`].
+    pub score: f64,
+    /// 1 = best; tied alternatives share a rank.
+    pub rank: usize,
+    /// One entry per objective, in table order.
+    pub contributions: Vec<ObjectiveContribution>,
+}
+
+/// Result of weighted scoring.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct WeightedScores {
+    /// In table order.
+    pub objectives: Vec<ObjectiveWeight>,
+    /// In table order.
+    pub alternatives: Vec<AlternativeScore>,
+}
+
+impl WeightedScores {
+    /// Score of an alternative.
+    pub fn score(&self, alternative_id: &str) -> Option<f64> {
+        self.alternatives
+            .iter()
+            .find(|a| a.alternative_id == alternative_id)
+            .map(|a| a.score)
+    }
+
+    /// The alternative ranked first, unless several tie for it.
+    pub fn best(&self) -> Option<&str> {
+        let mut leaders = self.alternatives.iter().filter(|a| a.rank == 1);
+        match (leaders.next(), leaders.next()) {
+            (Some(leader), None) => Some(&leader.alternative_id),
+            _ => None,
+        }
+    }
+}
+
+/// Weighted additive scoring.
+pub struct WeightedScoringAnalyzer;
+
+impl WeightedScoringAnalyzer {
+    /// Scores every alternative in the consequences table using the
+    /// fundamental objectives' weights and value functions. Objectives are
+    /// weighted equally if none has a weight.
+    pub fn compute(objectives: &ObjectivesOutput, consequences: &ConsequencesOutput) -> WeightedScores {
+        let values = ValueScorer::score(objectives, consequences);
+        let weights: HashMap<String, f64> = objectives
+            .fundamental_objectives
+            .iter()
+            .filter_map(|o| Some((o.id.clone(), o.weight?)))
+            .collect();
+        let weights = values.normalized_weights(&weights);
+
+        let objective_weights = values
+            .objective_ids
+            .iter()
+            .map(|obj| ObjectiveWeight {
+                objective_id: obj.clone(),
+                weight: weights[obj],
+                basis: values.basis.get(obj).copied().unwrap_or(ValueBasis::Rating),
+            })
+            .collect();
+
+        let mut alternatives: Vec<AlternativeScore> = values
+            .alternative_ids
+            .iter()
+            .map(|alt| {
+                let contributions: Vec<ObjectiveContribution> = values
+                    .objective_ids
+                    .iter()
+                    .map(|obj| {
+                        let value = values.get(alt, obj);
+                        ObjectiveContribution {
+                            objective_id: obj.clone(),
+                            value,
+                            points: weights[obj] * value.unwrap_or(0.0) * MAX_WEIGHTED_SCORE,
+                        }
+                    })
+                    .collect();
+                AlternativeScore {
+                    alternative_id: alt.clone(),
+                    score: contributions.iter().map(|c| c.points).sum(),
+                    rank: 0,
+                    contributions,
+                }
+            })
+            .collect();
+
+        let scores: Vec<f64> = alternatives.iter().map(|a| a.score).collect();
+        for alternative in &mut alternatives {
+            alternative.rank = 1 + scores
+                .iter()
+                .filter(|&&other| other > alternative.score + TIE_EPSILON)
+                .count();
+        }
+
+        WeightedScores {
+            objectives: objective_weights,
+            alternatives,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::foundation::Rating;
+    use crate::domain::proact::{Cell, FundamentalObjective, PerformanceMeasure};
+
+    fn objective(id: &str, weight: Option<f64>) -> FundamentalObjective {
+        FundamentalObjective {
+            id: id.to_string(),
+            description: id.to_string(),
+            performance_measure: PerformanceMeasure {
+                description: id.to_string(),
+                is_quantitative: false,
+                unit: None,
+                direction: "higher_is_better".to_string(),
+                value_function: None,
+            },
+            affected_party_id: None,
+            weight,
+        }
+    }
+
+    fn consequences(ratings: &[(&str, &str, Rating)]) -> ConsequencesOutput {
+        let mut output = ConsequencesOutput::default();
+        for (alt, obj, rating) in ratings {
+            if !output.table.alternative_ids.iter().any(|a| a == alt) {
+                output.table.alternative_ids.push(alt.to_string());
+            }
+            if !output.table.objective_ids.iter().any(|o| o == obj) {
+                output.table.objective_ids.push(obj.to_string());
+            }
+            output
+                .table
+                .cells
+                .entry(alt.to_string())
+                .or_default()
+                .insert(obj.to_string(), Cell::new(*rating, ""));
+        }
+        output
+    }
+
+    fn job_table() -> ConsequencesOutput {
+        consequences(&[
+            ("startup", "pay", Rating::MuchBetter),
+            ("startup", "security", Rating::MuchWorse),
+            ("bank", "pay", Rating::Same),
+            ("bank", "security", Rating::MuchBetter),
+        ])
+    }
+
+    #[test]
+    fn weights_decide_the_winner() {
+        let objectives = ObjectivesOutput {
+            fundamental_objectives: vec![objective("pay", Some(0.3)), objective("security", Some(0.7))],
+            means_objectives: vec![],
+        };
+
+        let scores = WeightedScoringAnalyzer::compute(&objectives, &job_table());
+
+        assert!((scores.score("startup").unwrap() - 30.0).abs() < 1e-9);
+        assert!((scores.score("bank").unwrap() - 85.0).abs() < 1e-9);
+        assert_eq!(scores.best(), Some("bank"));
+    }
+
+    #[test]
+    fn contributions_sum_to_score() {
+        let objectives = ObjectivesOutput {
+            fundamental_objectives: vec![objective("pay", Some(0.3)), objective("security", Some(0.7))],
+            means_objectives: vec![],
+        };
+
+        let scores = WeightedScoringAnalyzer::compute(&objectives, &job_table());
+
+        let bank = &scores.alternatives[1];
+        assert_eq!(bank.contributions.len(), 2);
+        assert!((bank.contributions[0].points - 15.0).abs() < 1e-9);
+        assert!((bank.contributions[1].points - 70.0).abs() < 1e-9);
+        let total: f64 = bank.contributions.iter().map(|c| c.points).sum();
+        assert!((total - bank.score).abs() < 1e-9);
+    }
+
+    #[test]
+    fn missing_weights_mean_equal_weights() {
+        let objectives = ObjectivesOutput {
+            fundamental_objectives: vec![objective("pay", None), objective("security", None)],
+            means_objectives: vec![],
+        };
+
+        let scores = WeightedScoringAnalyzer::compute(&objectives, &job_table());
+
+        assert_eq!(scores.objectives[0].weight, 0.5);
+        assert!((scores.score("startup").unwrap() - 50.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn tied_alternatives_share_rank() {
+        let table = consequences(&[("a", "x", Rating::Better), ("b", "x", Rating::Better)]);
+
+        let scores = WeightedScoringAnalyzer::compute(&ObjectivesOutput::default(), &table);
+
+        assert_eq!(scores.alternatives[0].rank, 1);
+        assert_eq!(scores.alternatives[1].rank, 1);
+        assert_eq!(scores.best(), None);
+    }
+
+    #[test]
+    fn empty_table_has_no_scores() {
+        let scores =
+            WeightedScoringAnalyzer::compute(&ObjectivesOutput::default(), &ConsequencesOutput::default());
+
+        assert!(scores.alternatives.is_empty());
+        assert_eq!(scores.best(), None);
+    }
+}
@@ -0,0 +1,370 @@
+//! AuditRecord and AuditEntry - One command execution in the audit log.
+
+use std::collections::{HashMap, HashSet};
+
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+use crate::domain::foundation::{CommandMetadata, Timestamp, UserId};
+
+/// `previous_hash` of the first entry in the log.
+pub const AUDIT_GENESIS_HASH: &str =
+    "0000000000000000000000000000000000000000000000000000000000000000";
+
+/// What a command did, as handed to the audit log.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AuditRecord {
+    /// User the command ran as.
+    pub actor_id: UserId,
+    /// Command name, e.g. "RenameSession".
+    pub command_type: String,
+    /// Kind of aggregate changed, e.g. "session".
+    pub aggregate_type: String,
+    pub aggregate_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub correlation_id: Option<String>,
+    /// Where the command came from ("api", "scheduler", ...).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub source: Option<String>,
+    /// Summary of the aggregate before the command; `None` when created.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub before: Option<serde_json::Value>,
+    /// Summary of the aggregate after the command; `None` when removed.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub after: Option<serde_json::Value>,
+}
+
+impl AuditRecord {
+    /// Starts a record of `command_type` run with `metadata`.
+    pub fn new(
+        metadata: &CommandMetadata,
+        command_type: impl Into<String>,
+        aggregate_type: impl Into<String>,
+        aggregate_id: impl ToString,
+    ) -> Self {
+        Self {
+            actor_id: metadata.user_id.clone(),
+            command_type: command_type.into(),
+            aggregate_type: aggregate_type.into(),
+            aggregate_id: aggregate_id.to_string(),
+            correlation_id: metadata.correlation_id_opt().map(str::to_string),
+            source: metadata.source().map(str::to_string),
+            before: None,
+            after: None,
+        }
+    }
+
+    pub fn with_before(mut self, before: serde_json::Value) -> Self {
+        self.before = Some(before);
+        self
+    }
+
+    pub fn with_after(mut self, after: serde_json::Value) -> Self {
+        self.after = Some(after);
+        self
+    }
+
+    /// Every user the record names: the actor, and each `user_id` in the
+    /// summaries.
+    pub fn user_ids(&self) -> HashSet<UserId> {
+        let mut user_ids = HashSet::from([self.actor_id.clone()]);
+        for summary in self.before.iter().chain(&self.after) {
+            collect_user_ids(summary, &mut user_ids);
+        }
+        user_ids
+    }
+
+    /// The record with every user it names replaced by their entry in
+    /// `pseudonyms`.
+    pub fn pseudonymized(mut self, pseudonyms: &HashMap<UserId, UserId>) -> Self {
+        if let Some(pseudonym) = pseudonyms.get(&self.actor_id) {
+            self.actor_id = pseudonym.clone();
+        }
+        for summary in self.before.iter_mut().chain(self.after.iter_mut()) {
+            replace_user_ids(summary, pseudonyms);
+        }
+        self
+    }
+}
+
+fn collect_user_ids(value: &serde_json::Value, user_ids: &mut HashSet<UserId>) {
+    match value {
+        serde_json::Value::Object(fields) => {
+            for (name, field) in fields {
+                match field.as_str().filter(|_| name == "user_id") {
+                    Some(id) => user_ids.extend(UserId::new(id).ok()),
+                    None => collect_user_ids(field, user_ids),
+                }
+            }
+        }
+        serde_json::Value::Array(items) => items
+            .iter()
+            .for_each(|item| collect_user_ids(item, user_ids)),
+        _ => {}
+    }
+}
+
+fn replace_user_ids(value: &mut serde_json::Value, pseudonyms: &HashMap<UserId, UserId>) {
+    match value {
+        serde_json::Value::Object(fields) => {
+            for (name, field) in fields.iter_mut() {
+                let pseudonym = field
+                    .as_str()
+                    .filter(|_| name == "user_id")
+                    .and_then(|id| UserId::new(id).ok())
+                    .and_then(|id| pseudonyms.get(&id));
+                match pseudonym {
+                    Some(pseudonym) => *field = pseudonym.as_str().into(),
+                    None => replace_user_ids(field, pseudonyms),
+                }
+            }
+        }
+        serde_json::Value::Array(items) => items
+            .iter_mut()
+            .for_each(|item| replace_user_ids(item, pseudonyms)),
+        _ => {}
+    }
+}
+
+/// A record sealed into the log.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AuditEntry {
+    /// Position in the log, starting at 1 with no gaps.
+    pub sequence: u64,
+    pub record: AuditRecord,
+    pub recorded_at: Timestamp,
+    /// Hash of the entry before, or [`AUDIT_GENESIS_HASH`] for the first.
+    pub previous_hash: String,
+    /// SHA-256 over `previous_hash` and the entry's content, hex-encoded.
+    pub hash: String,
+}
+
+/// The part of an entry its hash covers. Times are hashed to the
+/// microsecond, the precision they are stored with.
+#[derive(Serialize)]
+struct HashedContent<'a> {
+    sequence: u64,
+    record: &'a AuditRecord,
+    recorded_at_micros: i64,
+}
+
+impl AuditEntry {
+    /// Seals `record` as the entry after one hashed `previous_hash`.
+    pub fn seal(
+        record: AuditRecord,
+        sequence: u64,
+        previous_hash: impl Into<String>,
+        recorded_at: Timestamp,
+    ) -> Self {
+        let previous_hash = previous_hash.into();
+        let hash = compute_hash(sequence, &record, &recorded_at, &previous_hash);
+        Self {
+            sequence,
+            record,
+            recorded_at,
+            previous_hash,
+            hash,
+        }
+    }
+
+    /// Whether the stored hash still matches the entry's content.
+    pub fn is_intact(&self) -> bool {
+        compute_hash(
+            self.sequence,
+            &self.record,
+            &self.recorded_at,
+            &self.previous_hash,
+        ) == self.hash
+    }
+}
+
+fn compute_hash(
+    sequence: u64,
+    record: &AuditRecord,
+    recorded_at: &Timestamp,
+    previous_hash: &str,
+) -> String {
+    let content = serde_json::to_vec(&HashedContent {
+        sequence,
+        record,
+        recorded_at_micros: recorded_at.as_datetime().timestamp_micros(),
+    })
+    .expect("audit record serialization should not fail");
+    let mut hasher = Sha256::new();
+    hasher.update(previous_hash.as_bytes());
+    hasher.update(&content);
+    format!("{:x}", hasher.finalize())
+}
+
+/// Why a chain failed verification.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum AuditChainBreak {
+    #[error("Audit entry {sequence} was modified after it was recorded")]
+    Tampered { sequence: u64 },
+
+    #[error("Audit entry {expected} is missing (found {found})")]
+    Missing { expected: u64, found: u64 },
+
+    #[error("Audit entry {sequence} does not link to the entry before it")]
+    Unlinked { sequence: u64 },
+}
+
+/// Checks that `entries`, in sequence order, continue the chain after
+/// `previous` (or start it, when `None`).
+///
+/// # Errors
+///
+/// Returns the first break found.
+pub fn verify_chain(
+    entries: &[AuditEntry],
+    previous: Option<&AuditEntry>,
+) -> Result<(), AuditChainBreak> {
+    let mut expected_sequence = previous.map_or(1, |p| p.sequence + 1);
+    let mut expected_hash = previous.map_or(AUDIT_GENESIS_HASH, |p| p.hash.as_str());
+    for entry in entries {
+        if entry.sequence != expected_sequence {
+            return Err(AuditChainBreak::Missing {
+                expected: expected_sequence,
+                found: entry.sequence,
+            });
+        }
+        if !entry.is_intact() {
+            return Err(AuditChainBreak::Tampered {
+                sequence: entry.sequence,
+            });
+        }
+        if entry.previous_hash != expected_hash {
+            return Err(AuditChainBreak::Unlinked {
+                sequence: entry.sequence,
+            });
+        }
+        expected_sequence = entry.sequence + 1;
+        expected_hash = &entry.hash;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn record(command_type: &str) -> AuditRecord {
+        let metadata = CommandMetadata::test_fixture();
+        AuditRecord::new(&metadata, command_type, "session", "s-1")
+            .with_before(serde_json::json!({"title": "Old"}))
+            .with_after(serde_json::json!({"title": "New"}))
+    }
+
+    fn chain(length: u64) -> Vec<AuditEntry> {
+        let mut entries: Vec<AuditEntry> = Vec::new();
+        for sequence in 1..=length {
+            let previous_hash = entries
+                .last()
+                .map_or(AUDIT_GENESIS_HASH.to_string(), |e| e.hash.clone());
+            entries.push(AuditEntry::seal(
+                record("RenameSession"),
+                sequence,
+                previous_hash,
+                Timestamp::now(),
+            ));
+        }
+        entries
+    }
+
+    #[test]
+    fn records_take_actor_and_context_from_metadata() {
+        let record = record("RenameSession");
+
+        assert_eq!(record.actor_id.as_str(), "test-user-123");
+        assert_eq!(
+            record.correlation_id.as_deref(),
+            Some("test-correlation-id")
+        );
+        assert_eq!(record.source.as_deref(), Some("test"));
+    }
+
+    #[test]
+    fn pseudonymized_records_name_no_user() {
+        let record = record("AddMember").with_after(serde_json::json!({
+            "members": [{ "user_id": "member-1", "role": "owner" }],
+        }));
+        let pseudonyms: HashMap<UserId, UserId> = record
+            .user_ids()
+            .into_iter()
+            .map(|id| {
+                let pseudonym = UserId::new(format!("pseudonym:{}", id)).unwrap();
+                (id, pseudonym)
+            })
+            .collect();
+        assert_eq!(pseudonyms.len(), 2);
+
+        let record = record.pseudonymized(&pseudonyms);
+
+        assert_eq!(record.actor_id.as_str(), "pseudonym:test-user-123");
+        assert_eq!(
+            record.after.unwrap()["members"][0]["user_id"],
+            "pseudonym:member-1"
+        );
+    }
+
+    #[test]
+    fn intact_chains_verify() {
+        let entries = chain(3);
+
+        assert_eq!(verify_chain(&entries, None), Ok(()));
+        assert_eq!(verify_chain(&entries[1..], Some(&entries[0])), Ok(()));
+    }
+
+    #[test]
+    fn edited_entries_are_detected() {
+        let mut entries = chain(3);
+        entries[1].record.after = Some(serde_json::json!({"title": "Forged"}));
+
+        assert_eq!(
+            verify_chain(&entries, None),
+            Err(AuditChainBreak::Tampered { sequence: 2 })
+        );
+    }
+
+    #[test]
+    fn removed_entries_are_detected() {
+        let mut entries = chain(3);
+        entries.remove(1);
+
+        assert_eq!(
+            verify_chain(&entries, None),
+            Err(AuditChainBreak::Missing {
+                expected: 2,
+                found: 3
+            })
+        );
+    }
+
+    #[test]
+    fn rehashed_entries_no_longer_link() {
+        let mut entries = chain(3);
+        let forged = record("DeleteSession");
+        entries[1] = AuditEntry::seal(forged, 2, "f".repeat(64), entries[1].recorded_at);
+
+        assert_eq!(
+            verify_chain(&entries, None),
+            Err(AuditChainBreak::Unlinked { sequence: 2 })
+        );
+    }
+
+    #[test]
+    fn hashes_survive_storage_precision_and_json() {
+        let entry = chain(1).remove(0);
+        let stored = Timestamp::from_datetime(
+            chrono::DateTime::from_timestamp_micros(
+                entry.recorded_at.as_datetime().timestamp_micros(),
+            )
+            .unwrap(),
+        );
+        let json = serde_json::to_value(&entry).unwrap();
+        let mut parsed: AuditEntry = serde_json::from_value(json).unwrap();
+        parsed.recorded_at = stored;
+
+        assert!(parsed.is_intact());
+    }
+}
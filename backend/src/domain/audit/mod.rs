@@ -0,0 +1,25 @@
+//! Audit domain module.
+//!
+//! Every command handler execution is recorded in an append-only log: who
+//! ran which command against which aggregate, and a summary of the
+//! aggregate before and after. Entries are hash-chained, each hash covering
+//! the entry and the hash before it, so editing, removing or reordering any
+//! entry is detectable. Users are named in the log only by pseudonyms
+//! keyed per user, so erasing a user unlinks their entries without
+//! breaking the chain.
+//!
+//! # Types
+//!
+//! - `AuditRecord` - What a command did, before it is appended
+//! - `AuditEntry` - A record sealed into the chain with its sequence and hash
+//! - `AuditChainBreak` - Where and how a chain fails verification
+//! - `AuditSummary` - The state of an aggregate recorded before and after a command
+//! - `PseudonymKey` - Per-user key user ids are pseudonymized with
+
+mod entry;
+mod pseudonym;
+mod summary;
+
+pub use entry::{verify_chain, AuditChainBreak, AuditEntry, AuditRecord, AUDIT_GENESIS_HASH};
+pub use pseudonym::{PseudonymKey, PSEUDONYM_PREFIX};
+pub use summary::AuditSummary;
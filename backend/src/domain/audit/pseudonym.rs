@@ -0,0 +1,108 @@
+//! PseudonymKey - Per-user key that user ids are pseudonymized with before
+//! they enter the audit log.
+//!
+//! The log is append-only and hash-chained, so nothing in it can be erased.
+//! Instead it never holds a user id: actors, and every `user_id` in a
+//! summary, are stored as an HMAC of the id under that user's key. Erasing
+//! the user deletes the key, after which their entries can no longer be
+//! linked back to them.
+
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
+use uuid::Uuid;
+
+use crate::domain::foundation::UserId;
+
+type HmacSha256 = Hmac<Sha256>;
+
+/// Prefix marking a user id as a pseudonym.
+pub const PSEUDONYM_PREFIX: &str = "pseudonym:";
+
+/// A user's audit pseudonym key.
+#[derive(Clone, PartialEq, Eq)]
+pub struct PseudonymKey(String);
+
+impl PseudonymKey {
+    /// A fresh random key.
+    pub fn generate() -> Self {
+        Self(format!(
+            "{}{}",
+            Uuid::new_v4().simple(),
+            Uuid::new_v4().simple()
+        ))
+    }
+
+    /// A key as stored.
+    pub fn from_secret(secret: impl Into<String>) -> Self {
+        Self(secret.into())
+    }
+
+    /// The key, for storing.
+    pub fn expose_secret(&self) -> &str {
+        &self.0
+    }
+
+    /// The pseudonym `user_id` is recorded under.
+    pub fn pseudonym(&self, user_id: &UserId) -> UserId {
+        let mut mac =
+            HmacSha256::new_from_slice(self.0.as_bytes()).expect("HMAC can take key of any size");
+        mac.update(user_id.as_str().as_bytes());
+        let digest: String = mac
+            .finalize()
+            .into_bytes()
+            .iter()
+            .map(|b| format!("{:02x}", b))
+            .collect();
+        UserId::new(format!("{}{}", PSEUDONYM_PREFIX, digest)).expect("pseudonyms are never empty")
+    }
+}
+
+impl std::fmt::Debug for PseudonymKey {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("PseudonymKey(..)")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn user(id: &str) -> UserId {
+        UserId::new(id).unwrap()
+    }
+
+    #[test]
+    fn pseudonyms_are_stable_per_key() {
+        let key = PseudonymKey::generate();
+
+        assert_eq!(
+            key.pseudonym(&user("user-1")),
+            key.pseudonym(&user("user-1"))
+        );
+        assert_ne!(
+            key.pseudonym(&user("user-1")),
+            key.pseudonym(&user("user-2"))
+        );
+        assert!(key
+            .pseudonym(&user("user-1"))
+            .as_str()
+            .starts_with(PSEUDONYM_PREFIX));
+    }
+
+    #[test]
+    fn pseudonyms_differ_between_keys() {
+        let user_id = user("user-1");
+
+        assert_ne!(
+            PseudonymKey::generate().pseudonym(&user_id),
+            PseudonymKey::generate().pseudonym(&user_id)
+        );
+    }
+
+    #[test]
+    fn debug_output_hides_the_key() {
+        let key = PseudonymKey::from_secret("secret");
+
+        assert_eq!(format!("{:?}", key), "PseudonymKey(..)");
+    }
+}
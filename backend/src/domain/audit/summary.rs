@@ -0,0 +1,121 @@
+//! AuditSummary - The slice of an aggregate recorded before and after a
+//! command.
+//!
+//! Summaries hold the state an auditor asks about (statuses, who belongs
+//! where), not the full aggregate: nothing the user wrote, titles included,
+//! goes into the log, which is kept longer than the sessions it describes.
+//! Users are named under a `user_id` key so the auditor can pseudonymize
+//! them before the record is stored.
+
+use serde_json::json;
+
+use crate::domain::consent::ConsentRecord;
+use crate::domain::cycle::Cycle;
+use crate::domain::foundation::{ComponentStatus, ComponentType};
+use crate::domain::organization::Organization;
+use crate::domain::session::Session;
+
+/// An aggregate that can be summarized for the audit log.
+pub trait AuditSummary {
+    fn audit_summary(&self) -> serde_json::Value;
+}
+
+impl AuditSummary for Session {
+    fn audit_summary(&self) -> serde_json::Value {
+        json!({
+            "status": self.status(),
+            "organization_id": self.organization_id().map(|id| id.to_string()),
+            "cycle_count": self.cycle_count(),
+        })
+    }
+}
+
+impl AuditSummary for Cycle {
+    fn audit_summary(&self) -> serde_json::Value {
+        let components: Vec<serde_json::Value> = ComponentType::all()
+            .iter()
+            .map(|ct| (ct, self.component_status(*ct)))
+            .filter(|(_, status)| *status != ComponentStatus::NotStarted)
+            .map(|(ct, status)| json!({ "component": ct, "status": status }))
+            .collect();
+        json!({
+            "session_id": self.session_id().to_string(),
+            "parent_cycle_id": self.parent_cycle_id().map(|id| id.to_string()),
+            "status": self.status(),
+            "current_step": self.current_step(),
+            "version": self.version(),
+            "components": components,
+        })
+    }
+}
+
+impl AuditSummary for Organization {
+    fn audit_summary(&self) -> serde_json::Value {
+        let members: Vec<serde_json::Value> = self
+            .members
+            .iter()
+            .map(|m| json!({ "user_id": m.user_id.as_str(), "role": m.role.as_str() }))
+            .collect();
+        json!({
+            "name": self.name,
+            "members": members,
+            "billing_membership_id": self.billing_membership_id.map(|id| id.to_string()),
+        })
+    }
+}
+
+impl AuditSummary for ConsentRecord {
+    fn audit_summary(&self) -> serde_json::Value {
+        json!({
+            "consent_type": self.consent_type,
+            "version": self.version,
+            "granted": self.granted,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::foundation::{SessionId, UserId};
+
+    #[test]
+    fn session_summaries_leave_out_user_written_content() {
+        let mut session = Session::new(
+            SessionId::new(),
+            UserId::new("user-1").unwrap(),
+            "Move to Lisbon?".to_string(),
+        )
+        .unwrap();
+        session
+            .update_description(Some("Private notes".to_string()))
+            .unwrap();
+
+        let summary = session.audit_summary();
+
+        assert_eq!(summary["status"], "active");
+        assert!(!summary.to_string().contains("Lisbon"));
+        assert!(!summary.to_string().contains("Private notes"));
+    }
+
+    #[test]
+    fn cycle_summaries_list_started_components_only() {
+        let cycle = Cycle::new(SessionId::new());
+
+        let summary = cycle.audit_summary();
+
+        assert_eq!(summary["components"], json!([]));
+        assert_eq!(summary["version"], cycle.version());
+    }
+
+    #[test]
+    fn organization_summaries_list_members_and_roles() {
+        let owner = UserId::new("owner-1").unwrap();
+        let organization = Organization::new("Acme", owner).unwrap();
+
+        let summary = organization.audit_summary();
+
+        assert_eq!(summary["members"][0]["user_id"], "owner-1");
+        assert_eq!(summary["members"][0]["role"], "owner");
+    }
+}
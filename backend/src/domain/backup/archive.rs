@@ -0,0 +1,271 @@
+//! BackupArchive - Everything an instance holds, as one JSON document.
+//!
+//! Records are grouped into named sections (`memberships`, `sessions`, ...)
+//! so new kinds of data can be added without changing the layout, and
+//! stored documents travel alongside them base64-encoded. Archives record
+//! the [`BACKUP_FORMAT_VERSION`] they were written with; an instance refuses
+//! to restore archives from a newer format than it understands.
+
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::domain::cycle::CycleSnapshot;
+use crate::domain::foundation::{BackupId, DomainError, ErrorCode, Timestamp};
+use crate::domain::session::{ColdStorageRecord, Session};
+
+/// Version of the archive layout, bumped when sections change shape.
+pub const BACKUP_FORMAT_VERSION: u32 = 1;
+
+/// Document storage prefix archives are written under. Documents under it
+/// are left out of backups so archives do not nest.
+pub const BACKUP_KEY_PREFIX: &str = "backups/";
+
+/// A full backup of an instance.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BackupArchive {
+    pub format_version: u32,
+    pub backup_id: BackupId,
+    pub created_at: Timestamp,
+    /// Records per section, in the shape the section's source wrote them.
+    pub sections: BTreeMap<String, Vec<serde_json::Value>>,
+    pub documents: Vec<BackupDocument>,
+}
+
+/// One document from document storage.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BackupDocument {
+    pub key: String,
+    pub content_type: String,
+    #[serde(with = "base64_bytes")]
+    pub bytes: Vec<u8>,
+}
+
+/// How many records each section and how many documents an archive holds.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct BackupSummary {
+    pub backup_id: BackupId,
+    pub created_at: Timestamp,
+    pub records: BTreeMap<String, usize>,
+    pub documents: usize,
+}
+
+/// Only the version, read before the rest so newer layouts get a clear error.
+#[derive(Deserialize)]
+struct FormatVersion {
+    format_version: u32,
+}
+
+impl BackupArchive {
+    pub fn new() -> Self {
+        Self {
+            format_version: BACKUP_FORMAT_VERSION,
+            backup_id: BackupId::new(),
+            created_at: Timestamp::now(),
+            sections: BTreeMap::new(),
+            documents: Vec::new(),
+        }
+    }
+
+    /// Adds a section, replacing any earlier one with the same name.
+    pub fn insert(&mut self, section: impl Into<String>, records: Vec<serde_json::Value>) {
+        self.sections.insert(section.into(), records);
+    }
+
+    /// Adds a stored document.
+    pub fn add_document(
+        &mut self,
+        key: impl Into<String>,
+        content_type: impl Into<String>,
+        bytes: Vec<u8>,
+    ) {
+        self.documents.push(BackupDocument {
+            key: key.into(),
+            content_type: content_type.into(),
+            bytes,
+        });
+    }
+
+    /// Document storage key of this archive.
+    pub fn storage_key(&self) -> String {
+        Self::storage_key_for(&self.backup_id)
+    }
+
+    /// Document storage key of the archive with this ID.
+    pub fn storage_key_for(backup_id: &BackupId) -> String {
+        format!("{}{}.json", BACKUP_KEY_PREFIX, backup_id)
+    }
+
+    /// The backup ID stored under `key`, if it is an archive key.
+    pub fn id_from_storage_key(key: &str) -> Option<BackupId> {
+        key.strip_prefix(BACKUP_KEY_PREFIX)?
+            .strip_suffix(".json")?
+            .parse()
+            .ok()
+    }
+
+    pub fn summary(&self) -> BackupSummary {
+        BackupSummary {
+            backup_id: self.backup_id,
+            created_at: self.created_at,
+            records: self
+                .sections
+                .iter()
+                .map(|(section, records)| (section.clone(), records.len()))
+                .collect(),
+            documents: self.documents.len(),
+        }
+    }
+
+    /// The archive as JSON.
+    pub fn to_json_bytes(&self) -> Vec<u8> {
+        serde_json::to_vec(self).expect("archive serialization should not fail")
+    }
+
+    /// Parses an archive, rejecting formats newer than this build.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ValidationFailed` if the bytes are not an archive or were
+    /// written with a newer format version.
+    pub fn from_json_bytes(bytes: &[u8]) -> Result<Self, DomainError> {
+        let malformed = |e: serde_json::Error| {
+            DomainError::new(
+                ErrorCode::ValidationFailed,
+                format!("Malformed backup archive: {}", e),
+            )
+        };
+        let version: FormatVersion = serde_json::from_slice(bytes).map_err(malformed)?;
+        if version.format_version > BACKUP_FORMAT_VERSION {
+            return Err(DomainError::new(
+                ErrorCode::ValidationFailed,
+                format!(
+                    "Backup format version {} is newer than the supported version {}",
+                    version.format_version, BACKUP_FORMAT_VERSION
+                ),
+            ));
+        }
+        serde_json::from_slice(bytes).map_err(malformed)
+    }
+}
+
+impl Default for BackupArchive {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// A session with everything needed to bring back its cycles.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SessionBackupRecord {
+    pub session: Session,
+    /// Parents before their branches, so restoring in order keeps
+    /// references valid.
+    pub cycles: Vec<CycleSnapshot>,
+    /// Set when the session's cycles live in a cold storage snapshot,
+    /// which travels with the archive's documents.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cold_storage: Option<ColdStorageRecord>,
+}
+
+mod base64_bytes {
+    use base64::engine::general_purpose::STANDARD;
+    use base64::Engine;
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S: Serializer>(bytes: &[u8], serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&STANDARD.encode(bytes))
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vec<u8>, D::Error> {
+        let encoded = String::deserialize(deserializer)?;
+        STANDARD.decode(encoded).map_err(serde::de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::cycle::Cycle;
+    use crate::domain::foundation::{SessionId, UserId};
+
+    #[test]
+    fn archives_round_trip_with_documents() {
+        let mut archive = BackupArchive::new();
+        archive.insert("memberships", vec![serde_json::json!({"id": "m-1"})]);
+        archive.add_document(
+            "exports/c1/decision.pdf",
+            "application/pdf",
+            b"%PDF".to_vec(),
+        );
+
+        let parsed = BackupArchive::from_json_bytes(&archive.to_json_bytes()).unwrap();
+
+        assert_eq!(parsed, archive);
+        assert_eq!(parsed.summary().records["memberships"], 1);
+        assert_eq!(parsed.summary().documents, 1);
+    }
+
+    #[test]
+    fn documents_are_base64_encoded() {
+        let mut archive = BackupArchive::new();
+        archive.add_document("a.md", "text/markdown", b"hi".to_vec());
+
+        let json: serde_json::Value = serde_json::from_slice(&archive.to_json_bytes()).unwrap();
+
+        assert_eq!(json["documents"][0]["bytes"], "aGk=");
+    }
+
+    #[test]
+    fn newer_formats_are_rejected() {
+        let mut json = serde_json::to_value(BackupArchive::new()).unwrap();
+        json["format_version"] = serde_json::json!(BACKUP_FORMAT_VERSION + 1);
+        json["sections"] = serde_json::json!("reshaped in a later version");
+
+        let err = BackupArchive::from_json_bytes(&serde_json::to_vec(&json).unwrap()).unwrap_err();
+
+        assert_eq!(err.code, ErrorCode::ValidationFailed);
+        assert!(err.to_string().contains("newer than the supported version"));
+    }
+
+    #[test]
+    fn garbage_is_rejected() {
+        let err = BackupArchive::from_json_bytes(b"not json").unwrap_err();
+        assert_eq!(err.code, ErrorCode::ValidationFailed);
+    }
+
+    #[test]
+    fn storage_keys_round_trip_ids() {
+        let archive = BackupArchive::new();
+
+        assert!(archive.storage_key().starts_with(BACKUP_KEY_PREFIX));
+        assert_eq!(
+            BackupArchive::id_from_storage_key(&archive.storage_key()),
+            Some(archive.backup_id)
+        );
+        assert_eq!(BackupArchive::id_from_storage_key("exports/a.json"), None);
+    }
+
+    #[test]
+    fn session_records_round_trip() {
+        let session = Session::new(
+            SessionId::new(),
+            UserId::new("user-1").unwrap(),
+            "Move?".to_string(),
+        )
+        .unwrap();
+        let cycle = Cycle::new(*session.id());
+        let record = SessionBackupRecord {
+            session: session.clone(),
+            cycles: vec![cycle.snapshot()],
+            cold_storage: None,
+        };
+
+        let json = serde_json::to_value(&record).unwrap();
+        let parsed: SessionBackupRecord = serde_json::from_value(json).unwrap();
+
+        assert_eq!(parsed.session.id(), session.id());
+        assert_eq!(parsed.cycles[0].cycle_id, cycle.id());
+        assert!(parsed.cold_storage.is_none());
+    }
+}
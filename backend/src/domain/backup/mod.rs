@@ -0,0 +1,20 @@
+//! Backup domain module.
+//!
+//! Self-hosted instances back up everything they hold into one versioned
+//! archive: every aggregate, section by section, plus the contents of
+//! document storage. Restoring replays the archive through the
+//! repositories, so a backup taken on one instance can seed another.
+//!
+//! # Types
+//!
+//! - `BackupArchive` - The archive, one section of records per kind of aggregate
+//! - `BackupDocument` - One stored document carried in an archive
+//! - `BackupSummary` - How many records and documents an archive holds
+//! - `SessionBackupRecord` - A session with its cycles, as kept in the `sessions` section
+
+mod archive;
+
+pub use archive::{
+    BackupArchive, BackupDocument, BackupSummary, SessionBackupRecord, BACKUP_FORMAT_VERSION,
+    BACKUP_KEY_PREFIX,
+};
@@ -0,0 +1,91 @@
+//! Consent-specific error types.
+
+use crate::domain::foundation::{DomainError, ErrorCode};
+
+use super::ConsentType;
+
+/// Consent-specific errors.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ConsentError {
+    /// Required consents have not been accepted.
+    ConsentRequired(Vec<ConsentType>),
+    /// Validation failed.
+    ValidationFailed { field: String, message: String },
+    /// Infrastructure error.
+    Infrastructure(String),
+}
+
+impl ConsentError {
+    pub fn consent_required(missing: Vec<ConsentType>) -> Self {
+        ConsentError::ConsentRequired(missing)
+    }
+    pub fn validation(field: impl Into<String>, message: impl Into<String>) -> Self {
+        ConsentError::ValidationFailed {
+            field: field.into(),
+            message: message.into(),
+        }
+    }
+    pub fn infrastructure(message: impl Into<String>) -> Self {
+        ConsentError::Infrastructure(message.into())
+    }
+    pub fn code(&self) -> ErrorCode {
+        match self {
+            ConsentError::ConsentRequired(_) => ErrorCode::Forbidden,
+            ConsentError::ValidationFailed { .. } => ErrorCode::ValidationFailed,
+            ConsentError::Infrastructure(_) => ErrorCode::DatabaseError,
+        }
+    }
+    pub fn message(&self) -> String {
+        match self {
+            ConsentError::ConsentRequired(missing) => {
+                let names: Vec<&str> = missing.iter().map(|t| t.as_str()).collect();
+                format!("Consent required: {}", names.join(", "))
+            }
+            ConsentError::ValidationFailed { field, message } => {
+                format!("Validation failed for '{}': {}", field, message)
+            }
+            ConsentError::Infrastructure(msg) => format!("Error: {}", msg),
+        }
+    }
+}
+
+impl std::fmt::Display for ConsentError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.message())
+    }
+}
+
+impl std::error::Error for ConsentError {}
+
+impl From<DomainError> for ConsentError {
+    fn from(err: DomainError) -> Self {
+        match err.code {
+            ErrorCode::ValidationFailed => ConsentError::ValidationFailed {
+                field: err.details.get("field").cloned().unwrap_or_default(),
+                message: err.message,
+            },
+            _ => ConsentError::Infrastructure(err.to_string()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn consent_required_lists_missing_types() {
+        let err = ConsentError::consent_required(vec![
+            ConsentType::TermsOfService,
+            ConsentType::AiProcessing,
+        ]);
+        assert_eq!(err.message(), "Consent required: terms_of_service, ai_processing");
+        assert_eq!(err.code(), ErrorCode::Forbidden);
+    }
+
+    #[test]
+    fn domain_validation_error_converts() {
+        let err: ConsentError = DomainError::validation("version", "empty").into();
+        assert!(matches!(err, ConsentError::ValidationFailed { ref field, .. } if field == "version"));
+    }
+}
@@ -0,0 +1,79 @@
+//! Consent domain events.
+
+use serde::{Deserialize, Serialize};
+
+use crate::domain::foundation::{domain_event, ConsentRecordId, EventId, Timestamp, UserId};
+
+use super::{ConsentRecord, ConsentType};
+
+/// Published when a user grants or withdraws a consent.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ConsentRecorded {
+    /// Unique identifier for this event.
+    pub event_id: EventId,
+
+    /// ID of the appended consent record.
+    pub record_id: ConsentRecordId,
+
+    /// User whose consent changed.
+    pub user_id: UserId,
+
+    /// What was consented to.
+    pub consent_type: ConsentType,
+
+    /// Document or purpose version.
+    pub version: String,
+
+    /// True for a grant, false for a withdrawal.
+    pub granted: bool,
+
+    /// When the consent was recorded.
+    pub recorded_at: Timestamp,
+}
+
+impl ConsentRecorded {
+    /// Build the event for a newly appended record.
+    pub fn from_record(record: &ConsentRecord) -> Self {
+        Self {
+            event_id: EventId::new(),
+            record_id: record.id,
+            user_id: record.user_id.clone(),
+            consent_type: record.consent_type,
+            version: record.version.clone(),
+            granted: record.granted,
+            recorded_at: record.recorded_at,
+        }
+    }
+}
+
+domain_event!(
+    ConsentRecorded,
+    event_type = "consent.recorded.v1",
+    schema_version = 1,
+    aggregate_id = user_id,
+    aggregate_type = "Consent",
+    occurred_at = recorded_at,
+    event_id = event_id
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::foundation::DomainEvent;
+
+    #[test]
+    fn event_mirrors_record() {
+        let record = ConsentRecord::grant(
+            UserId::new("user-1").unwrap(),
+            ConsentType::AiProcessing,
+            "1",
+        )
+        .unwrap();
+        let event = ConsentRecorded::from_record(&record);
+
+        assert_eq!(event.record_id, record.id);
+        assert!(event.granted);
+        assert_eq!(event.event_type(), "consent.recorded.v1");
+        assert_eq!(event.aggregate_id(), "user-1");
+    }
+}
@@ -0,0 +1,28 @@
+//! Consent domain module.
+//!
+//! Tracks which legal documents and data-processing purposes each user has
+//! agreed to. Consent is an append-only ledger: every grant or withdrawal is a
+//! new `ConsentRecord`, and the current state is derived from the latest
+//! record per `ConsentType`.
+//!
+//! # Types
+//!
+//! - `ConsentType` - What is being consented to (ToS, privacy policy, AI processing, profiling)
+//! - `ConsentRecord` - One grant or withdrawal, with document version and timestamp
+//! - `ConsentStatus` - Current consent state derived from a user's records
+//! - `ConsentRequirements` - Document versions a user must have accepted
+//! - `ProfileConsent` - Decision-profile consent flags derived from the ledger
+//!
+//! # Events
+//!
+//! - `ConsentRecorded` - Published for every grant or withdrawal
+
+mod errors;
+mod events;
+mod record;
+mod status;
+
+pub use errors::ConsentError;
+pub use events::ConsentRecorded;
+pub use record::{ConsentRecord, ConsentType};
+pub use status::{ConsentRequirements, ConsentStatus, ProfileConsent};
@@ -0,0 +1,214 @@
+//! Consent records - append-only grants and withdrawals.
+
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::str::FromStr;
+
+use crate::domain::foundation::{ConsentRecordId, DomainError, Timestamp, UserId};
+
+/// Maximum length of a document version string.
+pub const MAX_VERSION_LENGTH: usize = 50;
+
+/// What a user is consenting to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ConsentType {
+    /// Terms of service (versioned document).
+    TermsOfService,
+    /// Privacy policy (versioned document).
+    PrivacyPolicy,
+    /// Sending decision content to third-party AI providers.
+    AiProcessing,
+    /// Collecting decision history into a decision profile.
+    ProfileCollection,
+    /// Analyzing collected history to derive profile traits.
+    ProfileAnalysis,
+    /// Letting agents read the decision profile during conversations.
+    ProfileAgentAccess,
+    /// Including the decision profile, anonymized, in the organization's
+    /// team profile.
+    ProfileTeamSharing,
+    /// Comparing decision quality against anonymized aggregates of other
+    /// users, and contributing to those aggregates.
+    ProfileBenchmarking,
+}
+
+impl ConsentType {
+    /// All consent types, in display order.
+    pub const ALL: [ConsentType; 8] = [
+        ConsentType::TermsOfService,
+        ConsentType::PrivacyPolicy,
+        ConsentType::AiProcessing,
+        ConsentType::ProfileCollection,
+        ConsentType::ProfileAnalysis,
+        ConsentType::ProfileAgentAccess,
+        ConsentType::ProfileTeamSharing,
+        ConsentType::ProfileBenchmarking,
+    ];
+
+    /// Stable string form used in storage and APIs.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ConsentType::TermsOfService => "terms_of_service",
+            ConsentType::PrivacyPolicy => "privacy_policy",
+            ConsentType::AiProcessing => "ai_processing",
+            ConsentType::ProfileCollection => "profile_collection",
+            ConsentType::ProfileAnalysis => "profile_analysis",
+            ConsentType::ProfileAgentAccess => "profile_agent_access",
+            ConsentType::ProfileTeamSharing => "profile_team_sharing",
+            ConsentType::ProfileBenchmarking => "profile_benchmarking",
+        }
+    }
+
+    /// Whether this consent is tied to a versioned legal document.
+    pub fn is_versioned_document(&self) -> bool {
+        matches!(self, ConsentType::TermsOfService | ConsentType::PrivacyPolicy)
+    }
+}
+
+impl fmt::Display for ConsentType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl FromStr for ConsentType {
+    type Err = DomainError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        ConsentType::ALL
+            .iter()
+            .copied()
+            .find(|t| t.as_str() == s)
+            .ok_or_else(|| DomainError::validation("consent_type", format!("Unknown consent type: {}", s)))
+    }
+}
+
+/// A single consent grant or withdrawal.
+///
+/// Records are never updated or deleted in place; withdrawing consent appends
+/// a new record with `granted = false`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ConsentRecord {
+    pub id: ConsentRecordId,
+    pub user_id: UserId,
+    pub consent_type: ConsentType,
+    /// Version of the document or purpose text the user saw.
+    pub version: String,
+    /// True for a grant, false for a withdrawal.
+    pub granted: bool,
+    pub recorded_at: Timestamp,
+    /// Where the consent was captured (e.g., "signup", "settings", "api").
+    pub source: Option<String>,
+}
+
+impl ConsentRecord {
+    /// Record that a user granted consent.
+    pub fn grant(
+        user_id: UserId,
+        consent_type: ConsentType,
+        version: impl Into<String>,
+    ) -> Result<Self, DomainError> {
+        Self::new(user_id, consent_type, version, true)
+    }
+
+    /// Record that a user withdrew consent.
+    pub fn withdraw(
+        user_id: UserId,
+        consent_type: ConsentType,
+        version: impl Into<String>,
+    ) -> Result<Self, DomainError> {
+        Self::new(user_id, consent_type, version, false)
+    }
+
+    fn new(
+        user_id: UserId,
+        consent_type: ConsentType,
+        version: impl Into<String>,
+        granted: bool,
+    ) -> Result<Self, DomainError> {
+        let version = version.into().trim().to_string();
+        if version.is_empty() {
+            return Err(DomainError::validation("version", "Version cannot be empty"));
+        }
+        if version.len() > MAX_VERSION_LENGTH {
+            return Err(DomainError::validation(
+                "version",
+                format!("Version cannot exceed {} characters", MAX_VERSION_LENGTH),
+            ));
+        }
+
+        Ok(Self {
+            id: ConsentRecordId::new(),
+            user_id,
+            consent_type,
+            version,
+            granted,
+            recorded_at: Timestamp::now(),
+            source: None,
+        })
+    }
+
+    /// Builder: set the capture source.
+    pub fn with_source(mut self, source: impl Into<String>) -> Self {
+        self.source = Some(source.into());
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn user() -> UserId {
+        UserId::new("user-1").unwrap()
+    }
+
+    #[test]
+    fn consent_type_roundtrips_through_string() {
+        for t in ConsentType::ALL {
+            assert_eq!(t.as_str().parse::<ConsentType>().unwrap(), t);
+        }
+        assert!("marketing".parse::<ConsentType>().is_err());
+    }
+
+    #[test]
+    fn consent_type_serializes_snake_case() {
+        let json = serde_json::to_string(&ConsentType::AiProcessing).unwrap();
+        assert_eq!(json, "\"ai_processing\"");
+    }
+
+    #[test]
+    fn grant_creates_granted_record() {
+        let record = ConsentRecord::grant(user(), ConsentType::TermsOfService, " 2026-01 ")
+            .unwrap()
+            .with_source("signup");
+        assert!(record.granted);
+        assert_eq!(record.version, "2026-01");
+        assert_eq!(record.source.as_deref(), Some("signup"));
+    }
+
+    #[test]
+    fn withdraw_creates_revoked_record() {
+        let record = ConsentRecord::withdraw(user(), ConsentType::AiProcessing, "1").unwrap();
+        assert!(!record.granted);
+    }
+
+    #[test]
+    fn empty_version_is_rejected() {
+        assert!(ConsentRecord::grant(user(), ConsentType::PrivacyPolicy, "  ").is_err());
+    }
+
+    #[test]
+    fn overlong_version_is_rejected() {
+        let version = "v".repeat(MAX_VERSION_LENGTH + 1);
+        assert!(ConsentRecord::grant(user(), ConsentType::PrivacyPolicy, version).is_err());
+    }
+
+    #[test]
+    fn only_legal_documents_are_versioned() {
+        assert!(ConsentType::TermsOfService.is_versioned_document());
+        assert!(ConsentType::PrivacyPolicy.is_versioned_document());
+        assert!(!ConsentType::AiProcessing.is_versioned_document());
+    }
+}
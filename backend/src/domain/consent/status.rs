@@ -0,0 +1,256 @@
+//! Consent status - current state derived from the consent ledger.
+
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+use crate::domain::foundation::Timestamp;
+
+use super::{ConsentRecord, ConsentType};
+
+/// Document versions a user must currently have accepted.
+///
+/// Bumping a version (e.g., publishing new terms) invalidates earlier
+/// acceptances, so users are prompted again before using AI features.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ConsentRequirements {
+    pub terms_version: String,
+    pub privacy_version: String,
+}
+
+impl ConsentRequirements {
+    pub fn new(terms_version: impl Into<String>, privacy_version: impl Into<String>) -> Self {
+        Self {
+            terms_version: terms_version.into(),
+            privacy_version: privacy_version.into(),
+        }
+    }
+
+    /// The version required for a consent type, if it is versioned.
+    pub fn required_version(&self, consent_type: ConsentType) -> Option<&str> {
+        match consent_type {
+            ConsentType::TermsOfService => Some(&self.terms_version),
+            ConsentType::PrivacyPolicy => Some(&self.privacy_version),
+            _ => None,
+        }
+    }
+}
+
+/// Decision-profile consent flags, derived from the consent ledger.
+///
+/// The decision profile reads these flags rather than storing its own copy,
+/// so withdrawing consent in settings takes effect everywhere at once.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ProfileConsent {
+    pub collection_enabled: bool,
+    pub analysis_enabled: bool,
+    pub agent_access_enabled: bool,
+    pub team_sharing_enabled: bool,
+    pub benchmarking_enabled: bool,
+    pub consented_at: Timestamp,
+    pub last_reviewed: Timestamp,
+}
+
+/// Current consent state for one user.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ConsentStatus {
+    latest: BTreeMap<ConsentType, ConsentRecord>,
+}
+
+impl ConsentStatus {
+    /// Build status from a user's records (any order).
+    pub fn from_records(records: impl IntoIterator<Item = ConsentRecord>) -> Self {
+        let mut latest: BTreeMap<ConsentType, ConsentRecord> = BTreeMap::new();
+        for record in records {
+            let newer = latest
+                .get(&record.consent_type)
+                .is_none_or(|existing| !record.recorded_at.is_before(&existing.recorded_at));
+            if newer {
+                latest.insert(record.consent_type, record);
+            }
+        }
+        Self { latest }
+    }
+
+    /// Latest record for a consent type.
+    pub fn latest(&self, consent_type: ConsentType) -> Option<&ConsentRecord> {
+        self.latest.get(&consent_type)
+    }
+
+    /// Whether the consent is currently granted (any version).
+    pub fn is_granted(&self, consent_type: ConsentType) -> bool {
+        self.latest(consent_type).is_some_and(|r| r.granted)
+    }
+
+    /// Whether the consent is granted at the version `requirements` demand.
+    pub fn is_satisfied(&self, consent_type: ConsentType, requirements: &ConsentRequirements) -> bool {
+        match self.latest(consent_type) {
+            Some(record) if record.granted => requirements
+                .required_version(consent_type)
+                .is_none_or(|required| record.version == required),
+            _ => false,
+        }
+    }
+
+    /// Consents required before AI features may be used that are not satisfied.
+    pub fn missing_for_ai(&self, requirements: &ConsentRequirements) -> Vec<ConsentType> {
+        [
+            ConsentType::TermsOfService,
+            ConsentType::PrivacyPolicy,
+            ConsentType::AiProcessing,
+        ]
+        .into_iter()
+        .filter(|t| !self.is_satisfied(*t, requirements))
+        .collect()
+    }
+
+    /// Whether AI features may be used.
+    pub fn allows_ai(&self, requirements: &ConsentRequirements) -> bool {
+        self.missing_for_ai(requirements).is_empty()
+    }
+
+    /// Decision-profile consent, if the user has ever opted into collection.
+    pub fn profile_consent(&self) -> Option<ProfileConsent> {
+        let collection = self.latest(ConsentType::ProfileCollection)?;
+
+        let profile_records = [
+            ConsentType::ProfileCollection,
+            ConsentType::ProfileAnalysis,
+            ConsentType::ProfileAgentAccess,
+            ConsentType::ProfileTeamSharing,
+            ConsentType::ProfileBenchmarking,
+        ];
+        let last_reviewed = profile_records
+            .iter()
+            .filter_map(|t| self.latest(*t))
+            .map(|r| r.recorded_at)
+            .fold(collection.recorded_at, |acc, t| if t.is_after(&acc) { t } else { acc });
+
+        Some(ProfileConsent {
+            collection_enabled: collection.granted,
+            analysis_enabled: collection.granted && self.is_granted(ConsentType::ProfileAnalysis),
+            agent_access_enabled: collection.granted
+                && self.is_granted(ConsentType::ProfileAgentAccess),
+            team_sharing_enabled: collection.granted
+                && self.is_granted(ConsentType::ProfileTeamSharing),
+            benchmarking_enabled: collection.granted
+                && self.is_granted(ConsentType::ProfileBenchmarking),
+            consented_at: collection.recorded_at,
+            last_reviewed,
+        })
+    }
+
+    /// Iterate over the latest record of each consent type.
+    pub fn records(&self) -> impl Iterator<Item = &ConsentRecord> {
+        self.latest.values()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::foundation::UserId;
+
+    fn user() -> UserId {
+        UserId::new("user-1").unwrap()
+    }
+
+    fn requirements() -> ConsentRequirements {
+        ConsentRequirements::new("tos-2", "pp-1")
+    }
+
+    fn grant(t: ConsentType, version: &str) -> ConsentRecord {
+        ConsentRecord::grant(user(), t, version).unwrap()
+    }
+
+    fn at(mut record: ConsentRecord, secs: u64) -> ConsentRecord {
+        record.recorded_at = Timestamp::from_unix_secs(secs);
+        record
+    }
+
+    #[test]
+    fn empty_status_requires_all_ai_consents() {
+        let status = ConsentStatus::default();
+        assert_eq!(
+            status.missing_for_ai(&requirements()),
+            vec![
+                ConsentType::TermsOfService,
+                ConsentType::PrivacyPolicy,
+                ConsentType::AiProcessing
+            ]
+        );
+        assert!(!status.allows_ai(&requirements()));
+    }
+
+    #[test]
+    fn current_versions_allow_ai() {
+        let status = ConsentStatus::from_records(vec![
+            grant(ConsentType::TermsOfService, "tos-2"),
+            grant(ConsentType::PrivacyPolicy, "pp-1"),
+            grant(ConsentType::AiProcessing, "1"),
+        ]);
+        assert!(status.allows_ai(&requirements()));
+    }
+
+    #[test]
+    fn outdated_terms_version_blocks_ai() {
+        let status = ConsentStatus::from_records(vec![
+            grant(ConsentType::TermsOfService, "tos-1"),
+            grant(ConsentType::PrivacyPolicy, "pp-1"),
+            grant(ConsentType::AiProcessing, "1"),
+        ]);
+        assert_eq!(
+            status.missing_for_ai(&requirements()),
+            vec![ConsentType::TermsOfService]
+        );
+    }
+
+    #[test]
+    fn latest_record_wins_regardless_of_input_order() {
+        let withdrawn = at(
+            ConsentRecord::withdraw(user(), ConsentType::AiProcessing, "1").unwrap(),
+            200,
+        );
+        let granted = at(grant(ConsentType::AiProcessing, "1"), 100);
+
+        let status = ConsentStatus::from_records(vec![withdrawn, granted]);
+        assert!(!status.is_granted(ConsentType::AiProcessing));
+    }
+
+    #[test]
+    fn profile_consent_absent_without_collection_record() {
+        let status = ConsentStatus::from_records(vec![grant(ConsentType::ProfileAnalysis, "1")]);
+        assert!(status.profile_consent().is_none());
+    }
+
+    #[test]
+    fn profile_consent_derives_flags() {
+        let status = ConsentStatus::from_records(vec![
+            at(grant(ConsentType::ProfileCollection, "1"), 100),
+            at(grant(ConsentType::ProfileAgentAccess, "1"), 300),
+        ]);
+
+        let consent = status.profile_consent().unwrap();
+        assert!(consent.collection_enabled);
+        assert!(!consent.analysis_enabled);
+        assert!(consent.agent_access_enabled);
+        assert!(!consent.team_sharing_enabled);
+        assert!(!consent.benchmarking_enabled);
+        assert_eq!(consent.consented_at, Timestamp::from_unix_secs(100));
+        assert_eq!(consent.last_reviewed, Timestamp::from_unix_secs(300));
+    }
+
+    #[test]
+    fn withdrawn_collection_disables_dependent_flags() {
+        let status = ConsentStatus::from_records(vec![
+            at(grant(ConsentType::ProfileAnalysis, "1"), 100),
+            at(
+                ConsentRecord::withdraw(user(), ConsentType::ProfileCollection, "1").unwrap(),
+                200,
+            ),
+        ]);
+
+        let consent = status.profile_consent().unwrap();
+        assert!(!consent.collection_enabled);
+        assert!(!consent.analysis_enabled);
+    }
+}
@@ -255,6 +255,7 @@ impl Conversation {
 #[cfg(test)]
 mod tests {
     use crate::domain::foundation::StateMachine;
+    use crate::test_support::ConversationBuilder;
 
     use super::*;
 
@@ -320,10 +321,9 @@ mod tests {
 
         #[test]
         fn fails_when_complete() {
-            let mut conv = test_conversation();
-            conv.mark_ready().unwrap();
-            conv.start().unwrap();
-            conv.complete().unwrap();
+            let mut conv = ConversationBuilder::new()
+                .state(ConversationState::Complete)
+                .build();
 
             let msg = Message::user("Too late").unwrap();
             let result = conv.add_message(msg);
@@ -443,10 +443,9 @@ mod tests {
 
         #[test]
         fn cannot_add_message_when_complete() {
-            let mut conv = test_conversation();
-            conv.mark_ready().unwrap();
-            conv.start().unwrap();
-            conv.complete().unwrap();
+            let conv = ConversationBuilder::new()
+                .state(ConversationState::Complete)
+                .build();
             assert!(!conv.can_add_message());
         }
     }
@@ -462,9 +461,10 @@ mod tests {
 
         #[test]
         fn last_message_returns_most_recent() {
-            let mut conv = test_conversation();
-            conv.add_message(Message::system("First").unwrap()).unwrap();
-            conv.add_message(Message::system("Last").unwrap()).unwrap();
+            let conv = ConversationBuilder::new()
+                .system("First")
+                .system("Last")
+                .build();
             assert_eq!(conv.last_message().unwrap().content(), "Last");
         }
 
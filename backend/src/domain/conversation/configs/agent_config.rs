@@ -1,9 +1,15 @@
 //! Agent configuration for component-specific behavior.
 //!
 //! Defines how the AI agent should behave in each PrOACT component,
-//! including phase-specific prompts and completion criteria.
+//! including phase-specific prompts and completion criteria. With a user's
+//! communication preferences, the rendered prompt also adapts tone, pacing
+//! and how hard the agent pushes back.
 
+use crate::domain::conversation::AgentPhase;
 use crate::domain::foundation::ComponentType;
+use crate::domain::profile::{
+    ChallengeStyle, CommunicationPreferences, PacingPreference, PreferenceLevel, UncertaintyStyle,
+};
 
 /// Configuration for an agent within a specific component.
 #[derive(Debug, Clone)]
@@ -16,6 +22,136 @@ pub struct AgentConfig {
     pub phase_prompts: PhasePrompts,
     /// Criteria for completing this component.
     pub completion_criteria: CompletionCriteria,
+    /// The user's communication preferences, when adapting to them.
+    pub communication: Option<CommunicationPreferences>,
+}
+
+impl AgentConfig {
+    /// Guidance for the given phase.
+    pub fn phase_prompt(&self, phase: AgentPhase) -> &'static str {
+        match phase {
+            AgentPhase::Intro => self.phase_prompts.intro,
+            AgentPhase::Gather => self.phase_prompts.gather,
+            AgentPhase::Clarify => self.phase_prompts.clarify,
+            AgentPhase::Extract => self.phase_prompts.extract,
+            AgentPhase::Confirm => self.phase_prompts.confirm,
+        }
+    }
+
+    /// Style instructions derived from the communication preferences.
+    ///
+    /// Empty when the config is not adapted to a user.
+    pub fn style_instructions(&self) -> Vec<String> {
+        let Some(prefs) = &self.communication else {
+            return Vec::new();
+        };
+        let style = &prefs.interaction_style;
+
+        let mut instructions = vec![
+            pacing_instruction(style.pacing).to_string(),
+            challenge_instruction(style.challenge_style).to_string(),
+            uncertainty_instruction(style.uncertainty_handling).to_string(),
+        ];
+        match style.preamble_preference {
+            PreferenceLevel::Minimal | PreferenceLevel::Low => {
+                instructions.push("Skip preamble; lead with your question.".to_string())
+            }
+            PreferenceLevel::High | PreferenceLevel::Extensive => {
+                instructions.push("Give a sentence of context before each question.".to_string())
+            }
+            PreferenceLevel::Medium => {}
+        }
+        match style.explanation_depth {
+            PreferenceLevel::Minimal | PreferenceLevel::Low => instructions
+                .push("Keep explanations of techniques to a single sentence.".to_string()),
+            PreferenceLevel::High | PreferenceLevel::Extensive => instructions
+                .push("Explain the reasoning behind each technique you use.".to_string()),
+            PreferenceLevel::Medium => {}
+        }
+        if !prefs.positive_patterns.is_empty() {
+            instructions.push(format!(
+                "Phrasing that works well with this user: {}.",
+                prefs.positive_patterns.join("; ")
+            ));
+        }
+        if !prefs.negative_patterns.is_empty() {
+            instructions.push(format!(
+                "Avoid phrasing like: {}.",
+                prefs.negative_patterns.join("; ")
+            ));
+        }
+        if let Some(instruction) = prefs.reading_level.instruction() {
+            instructions.push(instruction.to_string());
+        }
+        instructions
+    }
+
+    /// Renders the system prompt for the given phase.
+    pub fn system_prompt(&self, phase: AgentPhase) -> String {
+        let mut prompt = format!(
+            "Purpose: {}\n\nCurrent phase guidance: {}\n\nCompletion: {}",
+            self.purpose,
+            self.phase_prompt(phase),
+            self.completion_criteria.description
+        );
+        let instructions = self.style_instructions();
+        if !instructions.is_empty() {
+            prompt.push_str("\n\nCommunication style for this user:");
+            for instruction in instructions {
+                prompt.push_str("\n- ");
+                prompt.push_str(&instruction);
+            }
+        }
+        prompt
+    }
+}
+
+fn pacing_instruction(pacing: PacingPreference) -> &'static str {
+    match pacing {
+        PacingPreference::Quick => {
+            "Keep responses short and move on as soon as a point is captured. Ask one question at a time."
+        }
+        PacingPreference::Steady => {
+            "Move at a steady pace: cover each point, then continue without lingering."
+        }
+        PacingPreference::Thorough => {
+            "Take time on each point. Probe for depth and summarize before moving on."
+        }
+        PacingPreference::UserControlled => {
+            "Ask before moving to the next topic; let the user set the pace."
+        }
+    }
+}
+
+fn challenge_instruction(style: ChallengeStyle) -> &'static str {
+    match style {
+        ChallengeStyle::Gentle => {
+            "Challenge assumptions gently, framing concerns as things worth double-checking."
+        }
+        ChallengeStyle::DevilsAdvocate => {
+            "Play devil's advocate: argue the strongest case against the user's current leaning at least once in this phase."
+        }
+        ChallengeStyle::Socratic => {
+            "Challenge through questions rather than statements, letting the user find weak spots themselves."
+        }
+        ChallengeStyle::Direct => "Name weak assumptions plainly and say why they are weak.",
+        ChallengeStyle::Collaborative => {
+            "Raise doubts as shared questions to explore together."
+        }
+    }
+}
+
+fn uncertainty_instruction(style: UncertaintyStyle) -> &'static str {
+    match style {
+        UncertaintyStyle::Explicit => "When you don't know something, say so directly.",
+        UncertaintyStyle::Probabilistic => "Express uncertainty as rough confidence percentages.",
+        UncertaintyStyle::Hedged => {
+            "Qualify uncertain statements with words like \"likely\" or \"possibly\"."
+        }
+        UncertaintyStyle::Exploratory => {
+            "Turn uncertainties into questions the user could investigate."
+        }
+    }
 }
 
 /// Prompts and guidance for each agent phase.
@@ -59,9 +195,11 @@ pub fn agent_config_for_component(component_type: ComponentType) -> AgentConfig
     match component_type {
         ComponentType::IssueRaising => issue_raising_config(),
         ComponentType::ProblemFrame => problem_frame_config(),
+        ComponentType::StakeholderAnalysis => stakeholder_analysis_config(),
         ComponentType::Objectives => objectives_config(),
         ComponentType::Alternatives => alternatives_config(),
         ComponentType::Consequences => consequences_config(),
+        ComponentType::UncertaintyRegister => uncertainty_register_config(),
         ComponentType::Tradeoffs => tradeoffs_config(),
         ComponentType::Recommendation => recommendation_config(),
         ComponentType::DecisionQuality => decision_quality_config(),
@@ -69,6 +207,18 @@ pub fn agent_config_for_component(component_type: ComponentType) -> AgentConfig
     }
 }
 
+/// Returns the agent configuration for a component, adapted to a user's
+/// communication preferences.
+pub fn adaptive_agent_config_for_component(
+    component_type: ComponentType,
+    preferences: &CommunicationPreferences,
+) -> AgentConfig {
+    AgentConfig {
+        communication: Some(preferences.clone()),
+        ..agent_config_for_component(component_type)
+    }
+}
+
 fn issue_raising_config() -> AgentConfig {
     AgentConfig {
         component_type: ComponentType::IssueRaising,
@@ -85,6 +235,7 @@ fn issue_raising_config() -> AgentConfig {
             requires_confirmation: true,
             description: "At least 1 potential_decision identified; user confirms categorization.",
         },
+        communication: None,
     }
 }
 
@@ -104,6 +255,27 @@ fn problem_frame_config() -> AgentConfig {
             requires_confirmation: true,
             description: "Decision maker identified; focal decision statement defined (min 10 chars); scope clarified; user confirms frame is accurate.",
         },
+        communication: None,
+    }
+}
+
+fn stakeholder_analysis_config() -> AgentConfig {
+    AgentConfig {
+        component_type: ComponentType::StakeholderAnalysis,
+        purpose: "Map stakeholders by power and interest, capture concerns, plan engagement",
+        phase_prompts: PhasePrompts {
+            intro: "Reference the focal decision and parties from Problem Frame. Ask: 'Who will this decision affect, and who could shape it?'",
+            gather: "List stakeholders with their role. For each, ask how much they can influence the decision (power) and how much the outcome matters to them (interest). Capture what each cares or worries about.",
+            clarify: "Challenge the grid placement where it seems off. Look for missing stakeholders: people who live with the outcome but weren't mentioned, or people who could block it.",
+            extract: "Build a stakeholders array with id, name, role, power, interest (low|medium|high) and concerns, and an engagement_plan array with stakeholder_id, strategy, actions and timing.",
+            confirm: "Present the power/interest grid and engagement plan. Note concerns that may become objectives. Verify no key stakeholder is missing.",
+        },
+        completion_criteria: CompletionCriteria {
+            min_items: 1,
+            requires_confirmation: true,
+            description: "At least one stakeholder mapped with power and interest; high-power stakeholders have an engagement plan; user confirms the map is complete.",
+        },
+        communication: None,
     }
 }
 
@@ -123,6 +295,7 @@ fn objectives_config() -> AgentConfig {
             requires_confirmation: true,
             description: "At least 1 fundamental objective; user confirms objectives capture what matters.",
         },
+        communication: None,
     }
 }
 
@@ -142,6 +315,7 @@ fn alternatives_config() -> AgentConfig {
             requires_confirmation: true,
             description: "At least 2 alternatives (including status quo); status quo explicitly identified; user confirms completeness.",
         },
+        communication: None,
     }
 }
 
@@ -161,6 +335,27 @@ fn consequences_config() -> AgentConfig {
             requires_confirmation: true,
             description: "All cells in consequence table filled; user confirms ratings are reasonable.",
         },
+        communication: None,
+    }
+}
+
+fn uncertainty_register_config() -> AgentConfig {
+    AgentConfig {
+        component_type: ComponentType::UncertaintyRegister,
+        purpose: "Register key uncertainties with probability, impact and mitigation options",
+        phase_prompts: PhasePrompts {
+            intro: "Load the uncertainties noted in the consequence table. Explain that the register makes them explicit: how likely, how much it matters, and what can be done about it.",
+            gather: "For each uncertainty, ask for a rough probability that the unfavourable outcome occurs and what the estimate is based on. Ask how much it could change the decision (low, medium, high, critical) and which alternatives it affects.",
+            clarify: "Probe estimates that seem over- or under-confident. For high-impact uncertainties, ask what could resolve, reduce or hedge them, and at what cost. Check for uncertainties that weren't noted in the consequence table.",
+            extract: "Build an uncertainties array with id, description, consequence_uncertainty_id linking back to the consequence table, affected_alternative_ids, probability (0-1), probability_basis, impact and mitigations (description, kind, cost).",
+            confirm: "Present the register ranked by exposure (probability times impact). Flag high-impact uncertainties with no mitigation. Verify the user is comfortable with the estimates.",
+        },
+        completion_criteria: CompletionCriteria {
+            min_items: 1,
+            requires_confirmation: true,
+            description: "Each uncertainty from the consequence table is registered with an impact level; high-impact entries have a probability estimate and at least one mitigation option; user confirms.",
+        },
+        communication: None,
     }
 }
 
@@ -180,6 +375,7 @@ fn tradeoffs_config() -> AgentConfig {
             requires_confirmation: true,
             description: "Dominance analysis complete; user understands key tradeoffs.",
         },
+        communication: None,
     }
 }
 
@@ -199,6 +395,7 @@ fn recommendation_config() -> AgentConfig {
             requires_confirmation: true,
             description: "Synthesis written (min 50 chars); user acknowledges summary.",
         },
+        communication: None,
     }
 }
 
@@ -218,6 +415,7 @@ fn decision_quality_config() -> AgentConfig {
             requires_confirmation: true,
             description: "All 7 elements scored; user confirms scores reflect their confidence.",
         },
+        communication: None,
     }
 }
 
@@ -237,6 +435,7 @@ fn notes_next_steps_config() -> AgentConfig {
             requires_confirmation: true,
             description: "User confirms they're ready to wrap up.",
         },
+        communication: None,
     }
 }
 
@@ -249,9 +448,11 @@ mod tests {
         let components = [
             ComponentType::IssueRaising,
             ComponentType::ProblemFrame,
+            ComponentType::StakeholderAnalysis,
             ComponentType::Objectives,
             ComponentType::Alternatives,
             ComponentType::Consequences,
+            ComponentType::UncertaintyRegister,
             ComponentType::Tradeoffs,
             ComponentType::Recommendation,
             ComponentType::DecisionQuality,
@@ -296,9 +497,11 @@ mod tests {
         let components = [
             ComponentType::IssueRaising,
             ComponentType::ProblemFrame,
+            ComponentType::StakeholderAnalysis,
             ComponentType::Objectives,
             ComponentType::Alternatives,
             ComponentType::Consequences,
+            ComponentType::UncertaintyRegister,
             ComponentType::Tradeoffs,
             ComponentType::Recommendation,
             ComponentType::DecisionQuality,
@@ -327,4 +530,84 @@ mod tests {
         let config = agent_config_for_component(ComponentType::Recommendation);
         assert!(config.phase_prompts.intro.contains("decision is yours"));
     }
+
+    fn preferences(
+        pacing: PacingPreference,
+        challenge_style: ChallengeStyle,
+        uncertainty_handling: UncertaintyStyle,
+    ) -> CommunicationPreferences {
+        CommunicationPreferences {
+            interaction_style: crate::domain::profile::InteractionStyle {
+                pacing,
+                challenge_style,
+                uncertainty_handling,
+                ..Default::default()
+            },
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn unadapted_prompt_has_no_style_section() {
+        let prompt =
+            agent_config_for_component(ComponentType::Objectives).system_prompt(AgentPhase::Gather);
+        assert!(prompt.contains("How would you know if you achieved this?"));
+        assert!(!prompt.contains("Communication style"));
+    }
+
+    #[test]
+    fn preferences_change_the_rendered_prompt() {
+        let quick = adaptive_agent_config_for_component(
+            ComponentType::Alternatives,
+            &preferences(
+                PacingPreference::Quick,
+                ChallengeStyle::Gentle,
+                UncertaintyStyle::Explicit,
+            ),
+        )
+        .system_prompt(AgentPhase::Gather);
+        let thorough = adaptive_agent_config_for_component(
+            ComponentType::Alternatives,
+            &preferences(
+                PacingPreference::Thorough,
+                ChallengeStyle::DevilsAdvocate,
+                UncertaintyStyle::Probabilistic,
+            ),
+        )
+        .system_prompt(AgentPhase::Gather);
+
+        assert_ne!(quick, thorough);
+        assert!(quick.contains("Ask one question at a time"));
+        assert!(quick.contains("gently"));
+        assert!(quick.contains("say so directly"));
+        assert!(thorough.contains("summarize before moving on"));
+        assert!(thorough.contains("devil's advocate"));
+        assert!(thorough.contains("confidence percentages"));
+    }
+
+    #[test]
+    fn learned_phrasing_is_included() {
+        let mut prefs = CommunicationPreferences::default();
+        prefs.interaction_style.preamble_preference = PreferenceLevel::Minimal;
+        prefs.negative_patterns = vec!["You should".to_string()];
+
+        let prompt = adaptive_agent_config_for_component(ComponentType::Tradeoffs, &prefs)
+            .system_prompt(AgentPhase::Clarify);
+
+        assert!(prompt.contains("Skip preamble"));
+        assert!(prompt.contains("Avoid phrasing like: You should."));
+    }
+
+    #[test]
+    fn reading_level_is_included() {
+        let mut prefs = CommunicationPreferences::default();
+        let standard = adaptive_agent_config_for_component(ComponentType::Objectives, &prefs)
+            .system_prompt(AgentPhase::Gather);
+        assert!(!standard.contains("reading level"));
+
+        prefs.reading_level = crate::domain::profile::ReadingLevel::Simple;
+        let simple = adaptive_agent_config_for_component(ComponentType::Objectives, &prefs)
+            .system_prompt(AgentPhase::Gather);
+        assert!(simple.contains("5th-grade reading level"));
+    }
 }
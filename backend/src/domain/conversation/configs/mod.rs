@@ -8,7 +8,7 @@ mod templates;
 
 pub use agent_config::{
     AgentConfig, PhasePrompts, CompletionCriteria,
-    agent_config_for_component,
+    agent_config_for_component, adaptive_agent_config_for_component,
 };
 pub use templates::{
     opening_message_for_component,
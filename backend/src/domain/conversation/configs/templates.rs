@@ -1,6 +1,6 @@
 //! Message templates for component conversations.
 //!
-//! Provides opening messages and extraction prompts for all 9 PrOACT components.
+//! Provides opening messages and extraction prompts for all 11 PrOACT components.
 
 use crate::domain::foundation::ComponentType;
 
@@ -12,9 +12,11 @@ pub fn opening_message_for_component(component_type: ComponentType) -> &'static
     match component_type {
         ComponentType::IssueRaising => ISSUE_RAISING_OPENING,
         ComponentType::ProblemFrame => PROBLEM_FRAME_OPENING,
+        ComponentType::StakeholderAnalysis => STAKEHOLDER_ANALYSIS_OPENING,
         ComponentType::Objectives => OBJECTIVES_OPENING,
         ComponentType::Alternatives => ALTERNATIVES_OPENING,
         ComponentType::Consequences => CONSEQUENCES_OPENING,
+        ComponentType::UncertaintyRegister => UNCERTAINTY_REGISTER_OPENING,
         ComponentType::Tradeoffs => TRADEOFFS_OPENING,
         ComponentType::Recommendation => RECOMMENDATION_OPENING,
         ComponentType::DecisionQuality => DECISION_QUALITY_OPENING,
@@ -30,9 +32,11 @@ pub fn extraction_prompt_for_component(component_type: ComponentType) -> &'stati
     match component_type {
         ComponentType::IssueRaising => ISSUE_RAISING_EXTRACTION,
         ComponentType::ProblemFrame => PROBLEM_FRAME_EXTRACTION,
+        ComponentType::StakeholderAnalysis => STAKEHOLDER_ANALYSIS_EXTRACTION,
         ComponentType::Objectives => OBJECTIVES_EXTRACTION,
         ComponentType::Alternatives => ALTERNATIVES_EXTRACTION,
         ComponentType::Consequences => CONSEQUENCES_EXTRACTION,
+        ComponentType::UncertaintyRegister => UNCERTAINTY_REGISTER_EXTRACTION,
         ComponentType::Tradeoffs => TRADEOFFS_EXTRACTION,
         ComponentType::Recommendation => RECOMMENDATION_EXTRACTION,
         ComponentType::DecisionQuality => DECISION_QUALITY_EXTRACTION,
@@ -64,6 +68,16 @@ If you've already captured some potential decisions, we can start from there. Ot
 
 **Which decision would you like to focus on?**"#;
 
+const STAKEHOLDER_ANALYSIS_OPENING: &str = r#"With the decision framed, let's look at the people it touches.
+
+We'll place each stakeholder on a simple grid:
+- **Power** — how much they can shape or block the decision
+- **Interest** — how much the outcome matters to them
+
+Their concerns often turn into objectives, and their position suggests how to involve them.
+
+**Who will this decision affect, and who has a say in it?**"#;
+
 const OBJECTIVES_OPENING: &str = r#"With the decision framed, let's explore what outcomes matter most to you.
 
 Objectives come in two types:
@@ -98,6 +112,15 @@ For each alternative and objective, I'll ask you to rate the comparison and expl
 
 **Let's start with your first objective. How does each alternative compare to the status quo?**"#;
 
+const UNCERTAINTY_REGISTER_OPENING: &str = r#"Some of your consequence ratings rest on things nobody can know for sure yet.
+
+For each key uncertainty, we'll capture:
+- **Probability** — how likely the unfavourable outcome is
+- **Impact** — how much it could change which alternative is best
+- **Mitigation** — ways to resolve, reduce or hedge it
+
+**Which of the uncertainties we noted worries you most?**"#;
+
 const TRADEOFFS_OPENING: &str = r#"Now let's analyze the tradeoffs in your consequence table.
 
 I'll help you identify:
@@ -206,6 +229,35 @@ Rules:
 - Constraints are limitations on the decision
 - Parties include stakeholders affected by or influencing the decision"#;
 
+const STAKEHOLDER_ANALYSIS_EXTRACTION: &str = r#"Extract structured data about stakeholders.
+
+Output JSON with the following structure:
+{
+  "stakeholders": [
+    {
+      "id": "string",
+      "name": "string",
+      "role": "string",
+      "power": "low|medium|high",
+      "interest": "low|medium|high",
+      "concerns": ["string"]
+    }
+  ],
+  "engagement_plan": [
+    {
+      "stakeholder_id": "string",
+      "strategy": "manage_closely|keep_satisfied|keep_informed|monitor",
+      "actions": ["string"],
+      "timing": "string (optional)"
+    }
+  ]
+}
+
+Rules:
+- Power is the stakeholder's ability to shape or block the decision
+- Interest is how much the outcome matters to them
+- Every engagement_plan entry must reference a stakeholder id"#;
+
 const OBJECTIVES_EXTRACTION: &str = r#"Extract structured data about objectives.
 
 Output JSON with the following structure:
@@ -280,6 +332,35 @@ Rules:
 - Include the rationale explaining the rating
 - Uncertainty reflects confidence in the rating"#;
 
+const UNCERTAINTY_REGISTER_EXTRACTION: &str = r#"Extract the uncertainty register.
+
+Output JSON with the following structure:
+{
+  "uncertainties": [
+    {
+      "id": "string",
+      "description": "string",
+      "consequence_uncertainty_id": "string (optional)",
+      "affected_alternative_ids": ["string"],
+      "probability": 0.0-1.0 (optional),
+      "probability_basis": "string (optional)",
+      "impact": "low|medium|high|critical",
+      "mitigations": [
+        {
+          "description": "string",
+          "kind": "resolve|reduce|hedge|accept",
+          "cost": "string (optional)"
+        }
+      ]
+    }
+  ]
+}
+
+Rules:
+- Probability is the chance the unfavourable outcome occurs
+- Critical impact means the outcome could change which alternative is best
+- Link to the consequence table uncertainty id when the entry expands on one"#;
+
 const TRADEOFFS_EXTRACTION: &str = r#"Extract tradeoff analysis results.
 
 Output JSON with the following structure:
@@ -385,9 +466,11 @@ mod tests {
         let components = [
             ComponentType::IssueRaising,
             ComponentType::ProblemFrame,
+            ComponentType::StakeholderAnalysis,
             ComponentType::Objectives,
             ComponentType::Alternatives,
             ComponentType::Consequences,
+            ComponentType::UncertaintyRegister,
             ComponentType::Tradeoffs,
             ComponentType::Recommendation,
             ComponentType::DecisionQuality,
@@ -406,9 +489,11 @@ mod tests {
         let components = [
             ComponentType::IssueRaising,
             ComponentType::ProblemFrame,
+            ComponentType::StakeholderAnalysis,
             ComponentType::Objectives,
             ComponentType::Alternatives,
             ComponentType::Consequences,
+            ComponentType::UncertaintyRegister,
             ComponentType::Tradeoffs,
             ComponentType::Recommendation,
             ComponentType::DecisionQuality,
@@ -464,9 +549,11 @@ mod tests {
         let components = [
             ComponentType::IssueRaising,
             ComponentType::ProblemFrame,
+            ComponentType::StakeholderAnalysis,
             ComponentType::Objectives,
             ComponentType::Alternatives,
             ComponentType::Consequences,
+            ComponentType::UncertaintyRegister,
             ComponentType::Tradeoffs,
             ComponentType::Recommendation,
             ComponentType::DecisionQuality,
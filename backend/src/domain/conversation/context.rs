@@ -36,12 +36,14 @@ impl TokenBudget {
             // Simple components with shorter contexts
             ComponentType::IssueRaising
             | ComponentType::ProblemFrame
+            | ComponentType::StakeholderAnalysis
             | ComponentType::Objectives
             | ComponentType::Alternatives
             | ComponentType::DecisionQuality => Self::new(16_000, 2_000),
 
             // Complex components with larger contexts
             ComponentType::Consequences
+            | ComponentType::UncertaintyRegister
             | ComponentType::Tradeoffs
             | ComponentType::Recommendation => Self::new(32_000, 4_000),
 
@@ -61,6 +61,10 @@ impl PhaseTransitionConfig {
                 ],
                 ..Default::default()
             },
+            ComponentType::StakeholderAnalysis => Self {
+                min_messages_for_extraction: 2,
+                ..Default::default()
+            },
             ComponentType::Objectives => Self {
                 min_messages_for_extraction: 2,
                 ..Default::default()
@@ -73,6 +77,10 @@ impl PhaseTransitionConfig {
                 min_messages_for_extraction: 4,
                 ..Default::default()
             },
+            ComponentType::UncertaintyRegister => Self {
+                min_messages_for_extraction: 3,
+                ..Default::default()
+            },
             ComponentType::Tradeoffs => Self {
                 min_messages_for_extraction: 3,
                 ..Default::default()
@@ -942,9 +950,11 @@ mod tests {
             let components = [
                 ComponentType::IssueRaising,
                 ComponentType::ProblemFrame,
+                ComponentType::StakeholderAnalysis,
                 ComponentType::Objectives,
                 ComponentType::Alternatives,
                 ComponentType::Consequences,
+                ComponentType::UncertaintyRegister,
                 ComponentType::Tradeoffs,
                 ComponentType::Recommendation,
                 ComponentType::DecisionQuality,
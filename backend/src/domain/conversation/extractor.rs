@@ -1079,9 +1079,11 @@ Your overall Decision Quality is 60% (the minimum of all elements)."#;
             let components = [
                 ComponentType::IssueRaising,
                 ComponentType::ProblemFrame,
+                ComponentType::StakeholderAnalysis,
                 ComponentType::Objectives,
                 ComponentType::Alternatives,
                 ComponentType::Consequences,
+                ComponentType::UncertaintyRegister,
                 ComponentType::Tradeoffs,
                 ComponentType::Recommendation,
                 ComponentType::DecisionQuality,
@@ -35,6 +35,7 @@ pub use context::{
 };
 pub use configs::{
     AgentConfig, PhasePrompts, CompletionCriteria,
-    agent_config_for_component, opening_message_for_component,
+    agent_config_for_component, adaptive_agent_config_for_component,
+    opening_message_for_component,
     extraction_prompt_for_component,
 };
@@ -5,6 +5,7 @@
 //! what kind of dialogue the agent should engage in.
 
 use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
 
 /// The current phase of AI agent behavior within an active conversation.
 ///
@@ -12,7 +13,7 @@ use serde::{Deserialize, Serialize};
 /// - `Intro` → `Gather` → `Clarify` (optional) → `Extract` → `Confirm`
 ///
 /// Each phase has a distinct directive that guides the AI's responses.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Default, ToSchema)]
 #[serde(rename_all = "snake_case")]
 pub enum AgentPhase {
     /// Initial greeting and context setting.
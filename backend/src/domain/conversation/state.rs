@@ -3,6 +3,7 @@
 //! Defines the lifecycle states of a conversation and valid transitions.
 
 use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
 
 use crate::domain::foundation::StateMachine;
 
@@ -14,7 +15,7 @@ use crate::domain::foundation::StateMachine;
 /// - `InProgress`: Active dialogue with user
 /// - `Confirmed`: Data extracted and awaiting save
 /// - `Complete`: Read-only, component finished
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Default, ToSchema)]
 #[serde(rename_all = "snake_case")]
 pub enum ConversationState {
     /// Conversation created, loading configuration.
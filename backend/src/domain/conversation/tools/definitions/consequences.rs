@@ -138,6 +138,91 @@ pub struct SetConsequenceRangeParams {
     pub high: i8,
 }
 
+/// A rating that changes under an uncertainty outcome.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OutcomeRatingInput {
+    /// ID of the alternative
+    pub alternative_id: String,
+    /// ID of the objective
+    pub objective_id: String,
+    /// Rating under this outcome (-2 to +2)
+    pub rating: i8,
+}
+
+/// One way an uncertainty could resolve.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UncertaintyOutcomeInput {
+    /// Short name for the outcome
+    pub label: String,
+    /// Probability of this outcome (0.0-1.0)
+    pub probability: f64,
+    /// Ratings that would differ from the table
+    #[serde(default)]
+    pub rating_changes: Vec<OutcomeRatingInput>,
+}
+
+/// A probability stated for one outcome.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OutcomeProbabilityInput {
+    /// Outcome label
+    pub label: String,
+    /// Probability (0.0-1.0)
+    pub probability: f64,
+}
+
+/// A labelled point of a three-point estimate.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EstimatePointInput {
+    /// Outcome label for this point
+    pub label: String,
+    /// Value of the uncertain quantity
+    pub value: f64,
+}
+
+/// Parameters for eliciting probabilities stated directly.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ElicitFixedProbabilitiesParams {
+    /// ID of the uncertainty
+    pub uncertainty_id: String,
+    /// Probability of every outcome; must sum to 1
+    pub probabilities: Vec<OutcomeProbabilityInput>,
+}
+
+/// Parameters for eliciting a three-point estimate.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ElicitThreePointEstimateParams {
+    /// ID of the uncertainty
+    pub uncertainty_id: String,
+    /// 10th percentile
+    pub low: EstimatePointInput,
+    /// Most likely value
+    pub most_likely: EstimatePointInput,
+    /// 90th percentile
+    pub high: EstimatePointInput,
+    /// Unit of the values
+    pub unit: Option<String>,
+}
+
+/// Parameters for recording a reference lottery assessment.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RecordReferenceLotteryParams {
+    /// ID of the uncertainty
+    pub uncertainty_id: String,
+    /// Outcome the bet was on
+    pub outcome_label: String,
+    /// Lottery win chance at which the user was indifferent (0.0-1.0)
+    pub indifference_probability: f64,
+}
+
+/// Parameters for describing how an uncertainty could resolve.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SetUncertaintyOutcomesParams {
+    /// ID of the uncertainty
+    pub uncertainty_id: String,
+    /// Every way it could resolve; probabilities sum to 1
+    pub outcomes: Vec<UncertaintyOutcomeInput>,
+}
+
 // ═══════════════════════════════════════════════════════════════════════════
 // Tool Results
 // ═══════════════════════════════════════════════════════════════════════════
@@ -219,6 +304,32 @@ pub struct SetConsequenceRangeResult {
     pub document_updated: bool,
 }
 
+/// Result of eliciting probabilities.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ElicitProbabilitiesResult {
+    /// Whether the probabilities were stored
+    pub success: bool,
+    /// Probability of each outcome after the assessment
+    pub probabilities: Vec<OutcomeProbabilityInput>,
+    /// Sum of the outcome probabilities
+    pub probability_total: f64,
+    /// Whether the probabilities are non-negative and sum to 1
+    pub coherent: bool,
+    /// Whether the document was updated
+    pub document_updated: bool,
+}
+
+/// Result of describing uncertainty outcomes.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SetUncertaintyOutcomesResult {
+    /// Whether the outcomes were stored
+    pub success: bool,
+    /// Number of outcomes stored
+    pub outcome_count: usize,
+    /// Whether the document was updated
+    pub document_updated: bool,
+}
+
 // ═══════════════════════════════════════════════════════════════════════════
 // Tool Definitions
 // ═══════════════════════════════════════════════════════════════════════════
@@ -440,6 +551,186 @@ pub fn set_consequence_range_tool() -> ToolDefinition {
     )
 }
 
+/// Creates the set_uncertainty_outcomes tool definition.
+pub fn set_uncertainty_outcomes_tool() -> ToolDefinition {
+    ToolDefinition::new(
+        "set_uncertainty_outcomes",
+        "Describe the ways an uncertainty could resolve, how likely each is, and which ratings would change. Needed to value resolving it before deciding.",
+        serde_json::json!({
+            "type": "object",
+            "required": ["uncertainty_id", "outcomes"],
+            "properties": {
+                "uncertainty_id": {
+                    "type": "string",
+                    "description": "ID of the uncertainty"
+                },
+                "outcomes": {
+                    "type": "array",
+                    "minItems": 1,
+                    "description": "Every way it could resolve; probabilities must sum to 1",
+                    "items": {
+                        "type": "object",
+                        "required": ["label", "probability"],
+                        "properties": {
+                            "label": { "type": "string" },
+                            "probability": {
+                                "type": "number",
+                                "minimum": 0,
+                                "maximum": 1
+                            },
+                            "rating_changes": {
+                                "type": "array",
+                                "items": {
+                                    "type": "object",
+                                    "required": ["alternative_id", "objective_id", "rating"],
+                                    "properties": {
+                                        "alternative_id": { "type": "string" },
+                                        "objective_id": { "type": "string" },
+                                        "rating": {
+                                            "type": "integer",
+                                            "minimum": -2,
+                                            "maximum": 2
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }),
+        serde_json::json!({
+            "type": "object",
+            "properties": {
+                "success": { "type": "boolean" },
+                "outcome_count": { "type": "integer" },
+                "document_updated": { "type": "boolean" }
+            }
+        }),
+    )
+}
+
+/// Output schema shared by the probability elicitation tools.
+fn elicit_probabilities_result_schema() -> serde_json::Value {
+    serde_json::json!({
+        "type": "object",
+        "properties": {
+            "success": { "type": "boolean" },
+            "probabilities": {
+                "type": "array",
+                "items": {
+                    "type": "object",
+                    "properties": {
+                        "label": { "type": "string" },
+                        "probability": { "type": "number" }
+                    }
+                }
+            },
+            "probability_total": { "type": "number" },
+            "coherent": { "type": "boolean" },
+            "document_updated": { "type": "boolean" }
+        }
+    })
+}
+
+/// Creates the elicit_fixed_probabilities tool definition.
+pub fn elicit_fixed_probabilities_tool() -> ToolDefinition {
+    ToolDefinition::new(
+        "elicit_fixed_probabilities",
+        "Record probabilities the user states directly for every outcome of an uncertainty. Rejected unless none is negative and they sum to 1.",
+        serde_json::json!({
+            "type": "object",
+            "required": ["uncertainty_id", "probabilities"],
+            "properties": {
+                "uncertainty_id": {
+                    "type": "string",
+                    "description": "ID of the uncertainty"
+                },
+                "probabilities": {
+                    "type": "array",
+                    "minItems": 1,
+                    "items": {
+                        "type": "object",
+                        "required": ["label", "probability"],
+                        "properties": {
+                            "label": { "type": "string" },
+                            "probability": {
+                                "type": "number",
+                                "minimum": 0,
+                                "maximum": 1
+                            }
+                        }
+                    }
+                }
+            }
+        }),
+        elicit_probabilities_result_schema(),
+    )
+}
+
+/// Creates the elicit_three_point_estimate tool definition.
+pub fn elicit_three_point_estimate_tool() -> ToolDefinition {
+    let point = serde_json::json!({
+        "type": "object",
+        "required": ["label", "value"],
+        "properties": {
+            "label": { "type": "string" },
+            "value": { "type": "number" }
+        }
+    });
+    ToolDefinition::new(
+        "elicit_three_point_estimate",
+        "Assess an uncertain quantity by its low (1 in 10 chance of lower), most likely, and high (1 in 10 chance of higher) values. The three become outcomes weighted 30/40/30.",
+        serde_json::json!({
+            "type": "object",
+            "required": ["uncertainty_id", "low", "most_likely", "high"],
+            "properties": {
+                "uncertainty_id": {
+                    "type": "string",
+                    "description": "ID of the uncertainty"
+                },
+                "low": point,
+                "most_likely": point,
+                "high": point,
+                "unit": {
+                    "type": "string",
+                    "description": "Unit of the values"
+                }
+            }
+        }),
+        elicit_probabilities_result_schema(),
+    )
+}
+
+/// Creates the record_reference_lottery tool definition.
+pub fn record_reference_lottery_tool() -> ToolDefinition {
+    ToolDefinition::new(
+        "record_reference_lottery",
+        "Record one outcome's probability from a reference lottery: the chance of winning a lottery the user finds exactly as attractive as a bet on the outcome. Assess each outcome, then check the result is coherent.",
+        serde_json::json!({
+            "type": "object",
+            "required": ["uncertainty_id", "outcome_label", "indifference_probability"],
+            "properties": {
+                "uncertainty_id": {
+                    "type": "string",
+                    "description": "ID of the uncertainty"
+                },
+                "outcome_label": {
+                    "type": "string",
+                    "description": "Outcome the bet was on"
+                },
+                "indifference_probability": {
+                    "type": "number",
+                    "minimum": 0,
+                    "maximum": 1,
+                    "description": "Lottery win chance at which the user was indifferent"
+                }
+            }
+        }),
+        elicit_probabilities_result_schema(),
+    )
+}
+
 /// Returns all Consequences tool definitions.
 pub fn all_consequences_tools() -> Vec<ToolDefinition> {
     vec![
@@ -448,6 +739,10 @@ pub fn all_consequences_tools() -> Vec<ToolDefinition> {
         add_consequence_uncertainty_tool(),
         update_rating_reasoning_tool(),
         set_consequence_range_tool(),
+        set_uncertainty_outcomes_tool(),
+        elicit_fixed_probabilities_tool(),
+        elicit_three_point_estimate_tool(),
+        record_reference_lottery_tool(),
     ]
 }
 
@@ -491,9 +786,33 @@ mod tests {
     }
 
     #[test]
-    fn all_consequences_tools_returns_five_tools() {
+    fn all_consequences_tools_returns_nine_tools() {
         let tools = all_consequences_tools();
-        assert_eq!(tools.len(), 5);
+        assert_eq!(tools.len(), 9);
+    }
+
+    #[test]
+    fn elicitation_tools_bound_probabilities() {
+        let fixed = elicit_fixed_probabilities_tool();
+        let item = &fixed.parameters_schema()["properties"]["probabilities"]["items"];
+        assert_eq!(item["properties"]["probability"]["minimum"], 0);
+        assert_eq!(item["properties"]["probability"]["maximum"], 1);
+
+        let lottery = record_reference_lottery_tool();
+        let p = &lottery.parameters_schema()["properties"]["indifference_probability"];
+        assert_eq!(p["minimum"], 0);
+        assert_eq!(p["maximum"], 1);
+    }
+
+    #[test]
+    fn uncertainty_outcome_rating_changes_default_to_empty() {
+        let params: SetUncertaintyOutcomesParams = serde_json::from_value(serde_json::json!({
+            "uncertainty_id": "u1",
+            "outcomes": [{ "label": "Rates stay flat", "probability": 1.0 }]
+        }))
+        .unwrap();
+
+        assert!(params.outcomes[0].rating_changes.is_empty());
     }
 
     #[test]
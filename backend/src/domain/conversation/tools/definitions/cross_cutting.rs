@@ -43,12 +43,16 @@ pub enum UncertaintyStatus {
 pub enum DocumentSection {
     /// Problem frame section
     ProblemFrame,
+    /// Stakeholder analysis section
+    StakeholderAnalysis,
     /// Objectives section
     Objectives,
     /// Alternatives section
     Alternatives,
     /// Consequences section
     Consequences,
+    /// Uncertainty register section
+    UncertaintyRegister,
     /// Tradeoffs section
     Tradeoffs,
     /// Recommendation section
@@ -691,9 +695,11 @@ pub fn get_document_section_tool() -> ToolDefinition {
                     "type": "string",
                     "enum": [
                         "problem_frame",
+                        "stakeholder_analysis",
                         "objectives",
                         "alternatives",
                         "consequences",
+                        "uncertainty_register",
                         "tradeoffs",
                         "recommendation",
                         "decision_quality",
@@ -868,7 +874,7 @@ mod tests {
         let schema = tool.parameters_schema();
         let section = &schema["properties"]["section"];
         let enum_values = section["enum"].as_array().unwrap();
-        assert_eq!(enum_values.len(), 9);
+        assert_eq!(enum_values.len(), 11);
     }
 
     #[test]
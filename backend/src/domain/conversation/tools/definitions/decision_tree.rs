@@ -0,0 +1,395 @@
+//! Decision Tree Tools - Tools for modelling sequential decisions.
+//!
+//! When a choice now leads to uncertain events and further choices later,
+//! the agent sketches a decision tree with the user one branch at a time,
+//! then rolls it back to find the expected value of each option.
+
+use serde::{Deserialize, Serialize};
+
+use crate::domain::conversation::tools::ToolDefinition;
+
+// ═══════════════════════════════════════════════════════════════════════════
+// Enums
+// ═══════════════════════════════════════════════════════════════════════════
+
+/// Type of node added to a decision tree.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TreeNodeType {
+    /// A choice the user controls
+    Decision,
+    /// An uncertain event
+    Chance,
+    /// End of a path with a payoff
+    Terminal,
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// Tool Parameters
+// ═══════════════════════════════════════════════════════════════════════════
+
+/// Parameters for starting a decision tree.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreateDecisionTreeParams {
+    /// Short name for the tree
+    pub name: String,
+    /// Unit payoffs are measured in (e.g. "USD")
+    pub payoff_unit: Option<String>,
+    /// Label of the first decision
+    pub root_label: String,
+}
+
+/// Parameters for adding a node below an existing one.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AddTreeBranchParams {
+    /// Tree to extend
+    pub tree_id: String,
+    /// Decision or chance node to branch from
+    pub parent_node_id: String,
+    /// Option taken or outcome that occurs
+    pub branch_label: String,
+    /// Probability of the outcome (chance parents only)
+    pub probability: Option<f64>,
+    /// Label of the new node
+    pub node_label: String,
+    /// Type of the new node
+    pub node_type: TreeNodeType,
+    /// Payoff (terminal nodes only)
+    pub payoff: Option<f64>,
+}
+
+/// Parameters for changing an outcome's probability.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SetOutcomeProbabilityParams {
+    pub tree_id: String,
+    /// Node the outcome leads to
+    pub node_id: String,
+    /// Probability (0.0-1.0)
+    pub probability: f64,
+}
+
+/// Parameters for changing a terminal node's payoff.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SetTerminalPayoffParams {
+    pub tree_id: String,
+    /// Terminal node
+    pub node_id: String,
+    pub payoff: f64,
+}
+
+/// Parameters for pruning a node and everything below it.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RemoveTreeNodeParams {
+    pub tree_id: String,
+    pub node_id: String,
+}
+
+/// Parameters for rolling back a tree.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RollBackDecisionTreeParams {
+    pub tree_id: String,
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// Tool Results
+// ═══════════════════════════════════════════════════════════════════════════
+
+/// Result of starting a decision tree.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreateDecisionTreeResult {
+    pub tree_id: String,
+    /// ID of the root decision node
+    pub root_node_id: String,
+}
+
+/// Result of changing a tree.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DecisionTreeUpdateResult {
+    /// Whether the change was applied
+    pub success: bool,
+    /// ID of the node added, if any
+    pub node_id: Option<String>,
+    /// Nodes in the tree after the change
+    pub node_count: usize,
+    /// Problem preventing rollback, such as probabilities not summing to 1
+    pub validation_issue: Option<String>,
+}
+
+/// Expected value of one option at a decision node.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TreeOptionValue {
+    pub decision_node_id: String,
+    pub option_label: String,
+    pub expected_value: f64,
+    /// Whether this is the best option at its decision node
+    pub is_best: bool,
+}
+
+/// Result of rolling back a tree.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RollBackDecisionTreeResult {
+    /// Expected value of following the optimal strategy
+    pub expected_value: f64,
+    /// Value of every option at every decision node
+    pub options: Vec<TreeOptionValue>,
+    /// Node IDs reached by the optimal strategy
+    pub optimal_node_ids: Vec<String>,
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// Tool Definitions
+// ═══════════════════════════════════════════════════════════════════════════
+
+/// Creates the create_decision_tree tool definition.
+pub fn create_decision_tree_tool() -> ToolDefinition {
+    ToolDefinition::new(
+        "create_decision_tree",
+        "Start a decision tree for a decision that unfolds in stages. The tree begins with a root decision node.",
+        serde_json::json!({
+            "type": "object",
+            "required": ["name", "root_label"],
+            "properties": {
+                "name": {
+                    "type": "string",
+                    "maxLength": 200,
+                    "description": "Short name for the tree"
+                },
+                "payoff_unit": {
+                    "type": "string",
+                    "description": "Unit payoffs are measured in (e.g. 'USD', 'utility')"
+                },
+                "root_label": {
+                    "type": "string",
+                    "description": "The first decision to make"
+                }
+            }
+        }),
+        serde_json::json!({
+            "type": "object",
+            "properties": {
+                "tree_id": { "type": "string" },
+                "root_node_id": { "type": "string" }
+            }
+        }),
+    )
+}
+
+/// Creates the add_tree_branch tool definition.
+pub fn add_tree_branch_tool() -> ToolDefinition {
+    ToolDefinition::new(
+        "add_tree_branch",
+        "Add an option below a decision node or an outcome below a chance node, ending in a new decision, chance, or terminal node.",
+        serde_json::json!({
+            "type": "object",
+            "required": ["tree_id", "parent_node_id", "branch_label", "node_label", "node_type"],
+            "properties": {
+                "tree_id": { "type": "string" },
+                "parent_node_id": {
+                    "type": "string",
+                    "description": "Decision or chance node to branch from"
+                },
+                "branch_label": {
+                    "type": "string",
+                    "description": "Option taken or outcome that occurs"
+                },
+                "probability": {
+                    "type": "number",
+                    "minimum": 0,
+                    "maximum": 1,
+                    "description": "Probability of the outcome; required below chance nodes, omitted below decision nodes"
+                },
+                "node_label": {
+                    "type": "string",
+                    "description": "What happens next"
+                },
+                "node_type": {
+                    "type": "string",
+                    "enum": ["decision", "chance", "terminal"]
+                },
+                "payoff": {
+                    "type": "number",
+                    "description": "Value of ending here; required for terminal nodes"
+                }
+            }
+        }),
+        serde_json::json!({
+            "type": "object",
+            "properties": {
+                "success": { "type": "boolean" },
+                "node_id": { "type": "string" },
+                "node_count": { "type": "integer" },
+                "validation_issue": { "type": "string" }
+            }
+        }),
+    )
+}
+
+/// Creates the set_outcome_probability tool definition.
+pub fn set_outcome_probability_tool() -> ToolDefinition {
+    ToolDefinition::new(
+        "set_outcome_probability",
+        "Change the probability of the chance outcome leading to a node.",
+        serde_json::json!({
+            "type": "object",
+            "required": ["tree_id", "node_id", "probability"],
+            "properties": {
+                "tree_id": { "type": "string" },
+                "node_id": {
+                    "type": "string",
+                    "description": "Node the outcome leads to"
+                },
+                "probability": { "type": "number", "minimum": 0, "maximum": 1 }
+            }
+        }),
+        serde_json::json!({
+            "type": "object",
+            "properties": {
+                "success": { "type": "boolean" },
+                "node_count": { "type": "integer" },
+                "validation_issue": { "type": "string" }
+            }
+        }),
+    )
+}
+
+/// Creates the set_terminal_payoff tool definition.
+pub fn set_terminal_payoff_tool() -> ToolDefinition {
+    ToolDefinition::new(
+        "set_terminal_payoff",
+        "Change the payoff at the end of a path.",
+        serde_json::json!({
+            "type": "object",
+            "required": ["tree_id", "node_id", "payoff"],
+            "properties": {
+                "tree_id": { "type": "string" },
+                "node_id": {
+                    "type": "string",
+                    "description": "Terminal node"
+                },
+                "payoff": { "type": "number" }
+            }
+        }),
+        serde_json::json!({
+            "type": "object",
+            "properties": {
+                "success": { "type": "boolean" },
+                "node_count": { "type": "integer" },
+                "validation_issue": { "type": "string" }
+            }
+        }),
+    )
+}
+
+/// Creates the remove_tree_node tool definition.
+pub fn remove_tree_node_tool() -> ToolDefinition {
+    ToolDefinition::new(
+        "remove_tree_node",
+        "Remove a node, the branch leading to it, and everything below it.",
+        serde_json::json!({
+            "type": "object",
+            "required": ["tree_id", "node_id"],
+            "properties": {
+                "tree_id": { "type": "string" },
+                "node_id": { "type": "string" }
+            }
+        }),
+        serde_json::json!({
+            "type": "object",
+            "properties": {
+                "success": { "type": "boolean" },
+                "node_count": { "type": "integer" },
+                "validation_issue": { "type": "string" }
+            }
+        }),
+    )
+}
+
+/// Creates the roll_back_decision_tree tool definition.
+pub fn roll_back_decision_tree_tool() -> ToolDefinition {
+    ToolDefinition::new(
+        "roll_back_decision_tree",
+        "Compute expected values from the payoffs back to the root and find the best option at each decision node.",
+        serde_json::json!({
+            "type": "object",
+            "required": ["tree_id"],
+            "properties": {
+                "tree_id": { "type": "string" }
+            }
+        }),
+        serde_json::json!({
+            "type": "object",
+            "properties": {
+                "expected_value": { "type": "number" },
+                "options": {
+                    "type": "array",
+                    "items": {
+                        "type": "object",
+                        "properties": {
+                            "decision_node_id": { "type": "string" },
+                            "option_label": { "type": "string" },
+                            "expected_value": { "type": "number" },
+                            "is_best": { "type": "boolean" }
+                        }
+                    }
+                },
+                "optimal_node_ids": {
+                    "type": "array",
+                    "items": { "type": "string" }
+                }
+            }
+        }),
+    )
+}
+
+/// Returns all Decision Tree tool definitions.
+pub fn all_decision_tree_tools() -> Vec<ToolDefinition> {
+    vec![
+        create_decision_tree_tool(),
+        add_tree_branch_tool(),
+        set_outcome_probability_tool(),
+        set_terminal_payoff_tool(),
+        remove_tree_node_tool(),
+        roll_back_decision_tree_tool(),
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn tree_node_type_serializes_to_snake_case() {
+        assert_eq!(serde_json::to_string(&TreeNodeType::Decision).unwrap(), "\"decision\"");
+        assert_eq!(serde_json::to_string(&TreeNodeType::Terminal).unwrap(), "\"terminal\"");
+    }
+
+    #[test]
+    fn add_tree_branch_params_allow_missing_probability_and_payoff() {
+        let params: AddTreeBranchParams = serde_json::from_value(serde_json::json!({
+            "tree_id": "t1",
+            "parent_node_id": "n1",
+            "branch_label": "Run a pilot",
+            "node_label": "Pilot result",
+            "node_type": "chance"
+        }))
+        .unwrap();
+
+        assert_eq!(params.node_type, TreeNodeType::Chance);
+        assert!(params.probability.is_none());
+        assert!(params.payoff.is_none());
+    }
+
+    #[test]
+    fn all_decision_tree_tools_returns_six_tools() {
+        let tools = all_decision_tree_tools();
+        assert_eq!(tools.len(), 6);
+    }
+
+    #[test]
+    fn tool_names_are_distinct() {
+        let tools = all_decision_tree_tools();
+        let names: Vec<&str> = tools.iter().map(|t| t.name()).collect();
+        let unique_names: std::collections::HashSet<&str> = names.iter().copied().collect();
+        assert_eq!(names.len(), unique_names.len());
+    }
+}
@@ -7,31 +7,40 @@
 //!
 //! - [`issue_raising`] - Tools for categorizing initial thoughts
 //! - [`problem_frame`] - Tools for defining decision architecture
+//! - [`stakeholder_analysis`] - Tools for mapping stakeholders and engagement
 //! - [`objectives`] - Tools for identifying and organizing objectives
 //! - [`alternatives`] - Tools for capturing options
 //! - [`consequences`] - Tools for building consequence tables
+//! - [`uncertainty_register`] - Tools for estimating and mitigating key uncertainties
 //! - [`tradeoffs`] - Tools for surfacing dominated alternatives
 //! - [`recommendation`] - Tools for synthesizing analysis
 //! - [`decision_quality`] - Tools for rating decision quality elements
+//! - [`decision_tree`] - Tools for modelling sequential decisions as trees
 //! - [`cross_cutting`] - Tools available in all components
 
 pub mod issue_raising;
 pub mod problem_frame;
+pub mod stakeholder_analysis;
 pub mod objectives;
 pub mod alternatives;
 pub mod consequences;
+pub mod uncertainty_register;
 pub mod tradeoffs;
 pub mod recommendation;
 pub mod decision_quality;
+pub mod decision_tree;
 pub mod cross_cutting;
 
 // Re-export common types
 pub use issue_raising::*;
 pub use problem_frame::*;
+pub use stakeholder_analysis::*;
 pub use objectives::*;
 pub use alternatives::*;
 pub use consequences::*;
+pub use uncertainty_register::*;
 pub use tradeoffs::*;
 pub use recommendation::*;
 pub use decision_quality::*;
+pub use decision_tree::*;
 pub use cross_cutting::*;
@@ -24,6 +24,16 @@ pub enum ObjectiveDirection {
     Target,
 }
 
+/// Shape of an objective's value function.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ValueFunctionKind {
+    /// Value rises evenly from worst to best
+    Linear,
+    /// Straight lines between user-valued levels
+    PiecewiseLinear,
+}
+
 // ═══════════════════════════════════════════════════════════════════════════
 // Tool Parameters
 // ═══════════════════════════════════════════════════════════════════════════
@@ -77,6 +87,39 @@ pub struct PromoteToFundamentalParams {
     pub reason: String,
 }
 
+/// A level of a measure and its value.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ValuePointInput {
+    /// Level of the measure
+    pub level: f64,
+    /// Value of the level (0.0-1.0)
+    pub value: f64,
+}
+
+/// Parameters for setting an objective's value function.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SetValueFunctionParams {
+    /// ID of the fundamental objective
+    pub objective_id: String,
+    /// Shape of the function
+    pub kind: ValueFunctionKind,
+    /// Valued levels, for piecewise linear functions
+    pub points: Option<Vec<ValuePointInput>>,
+}
+
+/// Parameters for eliciting an objective's midvalue.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ElicitMidvalueParams {
+    /// ID of the fundamental objective
+    pub objective_id: String,
+    /// Worst level considered
+    pub worst: f64,
+    /// Best level considered
+    pub best: f64,
+    /// Level whose value is halfway between worst and best
+    pub midvalue: f64,
+}
+
 // ═══════════════════════════════════════════════════════════════════════════
 // Tool Results
 // ═══════════════════════════════════════════════════════════════════════════
@@ -150,6 +193,19 @@ pub struct PromoteToFundamentalResult {
     pub document_updated: bool,
 }
 
+/// Result of setting or eliciting a value function.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SetValueFunctionResult {
+    /// Whether the value function was stored
+    pub success: bool,
+    /// The objective that was updated
+    pub objective_name: String,
+    /// Exponential curvature; positive means diminishing returns
+    pub curvature: Option<f64>,
+    /// Whether the document was updated
+    pub document_updated: bool,
+}
+
 // ═══════════════════════════════════════════════════════════════════════════
 // Tool Definitions
 // ═══════════════════════════════════════════════════════════════════════════
@@ -320,6 +376,91 @@ pub fn promote_to_fundamental_tool() -> ToolDefinition {
     )
 }
 
+/// Output schema shared by the value function tools.
+fn set_value_function_result_schema() -> serde_json::Value {
+    serde_json::json!({
+        "type": "object",
+        "properties": {
+            "success": { "type": "boolean" },
+            "objective_name": { "type": "string" },
+            "curvature": { "type": "number" },
+            "document_updated": { "type": "boolean" }
+        }
+    })
+}
+
+/// Creates the set_value_function tool definition.
+pub fn set_value_function_tool() -> ToolDefinition {
+    ToolDefinition::new(
+        "set_value_function",
+        "Set how much each level of an objective's measure is worth. Use piecewise_linear when the user values some levels disproportionately (e.g., salary above a comfortable threshold adds little); linear resets to the default.",
+        serde_json::json!({
+            "type": "object",
+            "required": ["objective_id", "kind"],
+            "properties": {
+                "objective_id": {
+                    "type": "string",
+                    "description": "ID of the fundamental objective"
+                },
+                "kind": {
+                    "type": "string",
+                    "enum": ["linear", "piecewise_linear"],
+                    "description": "Shape of the value function"
+                },
+                "points": {
+                    "type": "array",
+                    "minItems": 2,
+                    "description": "Levels with their value from 0 (worst) to 1 (best); required for piecewise_linear",
+                    "items": {
+                        "type": "object",
+                        "required": ["level", "value"],
+                        "properties": {
+                            "level": { "type": "number" },
+                            "value": {
+                                "type": "number",
+                                "minimum": 0,
+                                "maximum": 1
+                            }
+                        }
+                    }
+                }
+            }
+        }),
+        set_value_function_result_schema(),
+    )
+}
+
+/// Creates the elicit_midvalue tool definition.
+pub fn elicit_midvalue_tool() -> ToolDefinition {
+    ToolDefinition::new(
+        "elicit_midvalue",
+        "Fit an exponential value function from the midvalue: the level the user says is halfway in value between worst and best (e.g., going from $50k to $80k is worth as much as $80k to $150k). A midvalue nearer the worst level means diminishing returns.",
+        serde_json::json!({
+            "type": "object",
+            "required": ["objective_id", "worst", "best", "midvalue"],
+            "properties": {
+                "objective_id": {
+                    "type": "string",
+                    "description": "ID of the fundamental objective"
+                },
+                "worst": {
+                    "type": "number",
+                    "description": "Worst level considered"
+                },
+                "best": {
+                    "type": "number",
+                    "description": "Best level considered"
+                },
+                "midvalue": {
+                    "type": "number",
+                    "description": "Level halfway in value between worst and best"
+                }
+            }
+        }),
+        set_value_function_result_schema(),
+    )
+}
+
 /// Returns all Objectives tool definitions.
 pub fn all_objectives_tools() -> Vec<ToolDefinition> {
     vec![
@@ -328,6 +469,8 @@ pub fn all_objectives_tools() -> Vec<ToolDefinition> {
         update_objective_measure_tool(),
         remove_objective_tool(),
         promote_to_fundamental_tool(),
+        set_value_function_tool(),
+        elicit_midvalue_tool(),
     ]
 }
 
@@ -356,9 +499,24 @@ mod tests {
     }
 
     #[test]
-    fn all_objectives_tools_returns_five_tools() {
+    fn all_objectives_tools_returns_seven_tools() {
         let tools = all_objectives_tools();
-        assert_eq!(tools.len(), 5);
+        assert_eq!(tools.len(), 7);
+    }
+
+    #[test]
+    fn set_value_function_params_deserialize() {
+        let params: SetValueFunctionParams = serde_json::from_value(serde_json::json!({
+            "objective_id": "salary",
+            "kind": "piecewise_linear",
+            "points": [
+                { "level": 50000, "value": 0 },
+                { "level": 100000, "value": 0.8 }
+            ]
+        }))
+        .unwrap();
+        assert_eq!(params.kind, ValueFunctionKind::PiecewiseLinear);
+        assert_eq!(params.points.unwrap().len(), 2);
     }
 
     #[test]
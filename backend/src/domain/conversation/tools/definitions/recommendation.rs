@@ -6,6 +6,7 @@
 
 use serde::{Deserialize, Serialize};
 
+use crate::domain::analysis::{InformationValue, ScoreConversion};
 use crate::domain::conversation::tools::ToolDefinition;
 
 // ═══════════════════════════════════════════════════════════════════════════
@@ -96,6 +97,18 @@ pub struct AddRemainingUncertaintyParams {
     pub affects: Vec<String>,
 }
 
+/// Parameters for assessing the value of resolving uncertainties.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AssessInformationValueParams {
+    /// Uncertainties to assess (all with outcomes if empty)
+    #[serde(default)]
+    pub uncertainty_ids: Vec<String>,
+    /// Reliability of the information source (1.0 = perfect)
+    pub accuracy: Option<f64>,
+    /// What a point of Pugh score is worth, to express value as a price
+    pub conversion: Option<ScoreConversion>,
+}
+
 /// Parameters for setting a decision prompt.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SetDecisionPromptParams {
@@ -177,6 +190,17 @@ pub struct AddRemainingUncertaintyResult {
     pub document_updated: bool,
 }
 
+/// Result of assessing the value of information.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AssessInformationValueResult {
+    /// Value of each assessed uncertainty, highest first
+    pub assessments: Vec<InformationValue>,
+    /// Advice sentence for each assessment, in the same order
+    pub advice: Vec<String>,
+    /// Uncertainties whose resolution could change the choice
+    pub worth_resolving_count: usize,
+}
+
 /// Result of setting a decision prompt.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SetDecisionPromptResult {
@@ -367,6 +391,53 @@ pub fn add_remaining_uncertainty_tool() -> ToolDefinition {
     )
 }
 
+/// Creates the assess_information_value tool definition.
+pub fn assess_information_value_tool() -> ToolDefinition {
+    ToolDefinition::new(
+        "assess_information_value",
+        "Compute how much resolving each uncertainty before deciding is worth, from its outcomes in the consequences table. Use to advise whether to gather more information first.",
+        serde_json::json!({
+            "type": "object",
+            "properties": {
+                "uncertainty_ids": {
+                    "type": "array",
+                    "items": { "type": "string" },
+                    "description": "Uncertainties to assess; all with outcomes if omitted"
+                },
+                "accuracy": {
+                    "type": "number",
+                    "exclusiveMinimum": 0,
+                    "maximum": 1,
+                    "description": "How often the information source is right (1.0 = perfect information)"
+                },
+                "conversion": {
+                    "type": "object",
+                    "required": ["unit", "per_point"],
+                    "description": "What one point of Pugh score is worth, e.g. 500 USD",
+                    "properties": {
+                        "unit": { "type": "string" },
+                        "per_point": { "type": "number", "minimum": 0 }
+                    }
+                }
+            }
+        }),
+        serde_json::json!({
+            "type": "object",
+            "properties": {
+                "assessments": {
+                    "type": "array",
+                    "items": { "type": "object" }
+                },
+                "advice": {
+                    "type": "array",
+                    "items": { "type": "string" }
+                },
+                "worth_resolving_count": { "type": "integer" }
+            }
+        }),
+    )
+}
+
 /// Creates the set_decision_prompt tool definition.
 pub fn set_decision_prompt_tool() -> ToolDefinition {
     ToolDefinition::new(
@@ -445,6 +516,7 @@ pub fn all_recommendation_tools() -> Vec<ToolDefinition> {
         set_standout_tool(),
         add_key_consideration_tool(),
         add_remaining_uncertainty_tool(),
+        assess_information_value_tool(),
         set_decision_prompt_tool(),
         summarize_frame_tool(),
     ]
@@ -480,9 +552,9 @@ mod tests {
     }
 
     #[test]
-    fn all_recommendation_tools_returns_six_tools() {
+    fn all_recommendation_tools_returns_seven_tools() {
         let tools = all_recommendation_tools();
-        assert_eq!(tools.len(), 6);
+        assert_eq!(tools.len(), 7);
     }
 
     #[test]
@@ -494,6 +566,7 @@ mod tests {
         assert!(names.contains(&"set_standout"));
         assert!(names.contains(&"add_key_consideration"));
         assert!(names.contains(&"add_remaining_uncertainty"));
+        assert!(names.contains(&"assess_information_value"));
         assert!(names.contains(&"set_decision_prompt"));
         assert!(names.contains(&"summarize_frame"));
     }
@@ -0,0 +1,276 @@
+//! Stakeholder Analysis Tools - Tools for mapping stakeholders.
+//!
+//! Stakeholder Analysis is where users place the people a decision touches on
+//! a power/interest grid, record their concerns, and plan how to engage each
+//! of them.
+
+use serde::{Deserialize, Serialize};
+
+use crate::domain::conversation::tools::ToolDefinition;
+use crate::domain::proact::{EngagementStrategy, StakeholderLevel};
+
+// ═══════════════════════════════════════════════════════════════════════════
+// Tool Parameters
+// ═══════════════════════════════════════════════════════════════════════════
+
+/// Parameters for adding a stakeholder.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AddStakeholderParams {
+    /// Name of the stakeholder
+    pub name: String,
+    /// Relationship to the decision
+    pub role: String,
+    /// Ability to shape or block the decision
+    pub power: StakeholderLevel,
+    /// How much the outcome matters to them
+    pub interest: StakeholderLevel,
+    /// What they care or worry about
+    #[serde(default)]
+    pub concerns: Vec<String>,
+}
+
+/// Parameters for moving a stakeholder on the grid.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SetStakeholderPositionParams {
+    /// ID of the stakeholder
+    pub stakeholder_id: String,
+    /// New power level
+    pub power: StakeholderLevel,
+    /// New interest level
+    pub interest: StakeholderLevel,
+}
+
+/// Parameters for planning how a stakeholder is engaged.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PlanEngagementParams {
+    /// ID of the stakeholder
+    pub stakeholder_id: String,
+    /// Engagement approach
+    pub strategy: EngagementStrategy,
+    /// Concrete steps
+    #[serde(default)]
+    pub actions: Vec<String>,
+    /// When the engagement should happen
+    pub timing: Option<String>,
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// Tool Results
+// ═══════════════════════════════════════════════════════════════════════════
+
+/// Result of adding a stakeholder.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AddStakeholderResult {
+    /// ID of the added stakeholder
+    pub stakeholder_id: String,
+    /// Engagement approach suggested by the grid position
+    pub suggested_strategy: EngagementStrategy,
+    /// Total number of stakeholders
+    pub total_stakeholders: usize,
+    /// Whether the document was updated
+    pub document_updated: bool,
+}
+
+/// Result of moving a stakeholder on the grid.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SetStakeholderPositionResult {
+    /// Whether the operation succeeded
+    pub success: bool,
+    /// Engagement approach suggested by the new position
+    pub suggested_strategy: EngagementStrategy,
+    /// Whether the document was updated
+    pub document_updated: bool,
+}
+
+/// Result of planning an engagement.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PlanEngagementResult {
+    /// Whether the operation succeeded
+    pub success: bool,
+    /// Stakeholders still without an engagement plan
+    pub unplanned_count: usize,
+    /// Whether the document was updated
+    pub document_updated: bool,
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// Tool Definitions
+// ═══════════════════════════════════════════════════════════════════════════
+
+/// Creates the add_stakeholder tool definition.
+pub fn add_stakeholder_tool() -> ToolDefinition {
+    ToolDefinition::new(
+        "add_stakeholder",
+        "Add a stakeholder with their position on the power/interest grid. Use when the user names someone the decision affects or who can shape it.",
+        serde_json::json!({
+            "type": "object",
+            "required": ["name", "role", "power", "interest"],
+            "properties": {
+                "name": {
+                    "type": "string",
+                    "description": "Name of the stakeholder"
+                },
+                "role": {
+                    "type": "string",
+                    "description": "Relationship to the decision"
+                },
+                "power": {
+                    "type": "string",
+                    "enum": ["low", "medium", "high"],
+                    "description": "Ability to shape or block the decision"
+                },
+                "interest": {
+                    "type": "string",
+                    "enum": ["low", "medium", "high"],
+                    "description": "How much the outcome matters to them"
+                },
+                "concerns": {
+                    "type": "array",
+                    "items": { "type": "string" },
+                    "description": "What they care or worry about"
+                }
+            }
+        }),
+        serde_json::json!({
+            "type": "object",
+            "properties": {
+                "stakeholder_id": { "type": "string" },
+                "suggested_strategy": { "type": "string" },
+                "total_stakeholders": { "type": "integer" },
+                "document_updated": { "type": "boolean" }
+            }
+        }),
+    )
+}
+
+/// Creates the set_stakeholder_position tool definition.
+pub fn set_stakeholder_position_tool() -> ToolDefinition {
+    ToolDefinition::new(
+        "set_stakeholder_position",
+        "Move a stakeholder on the power/interest grid when the user corrects how much influence or interest they have.",
+        serde_json::json!({
+            "type": "object",
+            "required": ["stakeholder_id", "power", "interest"],
+            "properties": {
+                "stakeholder_id": {
+                    "type": "string",
+                    "description": "ID of the stakeholder"
+                },
+                "power": {
+                    "type": "string",
+                    "enum": ["low", "medium", "high"]
+                },
+                "interest": {
+                    "type": "string",
+                    "enum": ["low", "medium", "high"]
+                }
+            }
+        }),
+        serde_json::json!({
+            "type": "object",
+            "properties": {
+                "success": { "type": "boolean" },
+                "suggested_strategy": { "type": "string" },
+                "document_updated": { "type": "boolean" }
+            }
+        }),
+    )
+}
+
+/// Creates the plan_engagement tool definition.
+pub fn plan_engagement_tool() -> ToolDefinition {
+    ToolDefinition::new(
+        "plan_engagement",
+        "Record how a stakeholder will be engaged. Manage high-power, high-interest stakeholders closely; keep powerful ones satisfied and interested ones informed.",
+        serde_json::json!({
+            "type": "object",
+            "required": ["stakeholder_id", "strategy"],
+            "properties": {
+                "stakeholder_id": {
+                    "type": "string",
+                    "description": "ID of the stakeholder"
+                },
+                "strategy": {
+                    "type": "string",
+                    "enum": ["manage_closely", "keep_satisfied", "keep_informed", "monitor"],
+                    "description": "Engagement approach"
+                },
+                "actions": {
+                    "type": "array",
+                    "items": { "type": "string" },
+                    "description": "Concrete steps"
+                },
+                "timing": {
+                    "type": "string",
+                    "description": "When the engagement should happen"
+                }
+            }
+        }),
+        serde_json::json!({
+            "type": "object",
+            "properties": {
+                "success": { "type": "boolean" },
+                "unplanned_count": { "type": "integer" },
+                "document_updated": { "type": "boolean" }
+            }
+        }),
+    )
+}
+
+/// Returns all Stakeholder Analysis tool definitions.
+pub fn all_stakeholder_analysis_tools() -> Vec<ToolDefinition> {
+    vec![
+        add_stakeholder_tool(),
+        set_stakeholder_position_tool(),
+        plan_engagement_tool(),
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn add_stakeholder_params_deserialize_levels() {
+        let params: AddStakeholderParams = serde_json::from_value(serde_json::json!({
+            "name": "Sam",
+            "role": "partner",
+            "power": "high",
+            "interest": "medium"
+        }))
+        .unwrap();
+
+        assert_eq!(params.power, StakeholderLevel::High);
+        assert_eq!(params.interest, StakeholderLevel::Medium);
+        assert!(params.concerns.is_empty());
+    }
+
+    #[test]
+    fn all_stakeholder_analysis_tools_returns_three_tools() {
+        let names: Vec<String> = all_stakeholder_analysis_tools()
+            .iter()
+            .map(|t| t.name().to_string())
+            .collect();
+
+        assert_eq!(
+            names,
+            [
+                "add_stakeholder",
+                "set_stakeholder_position",
+                "plan_engagement"
+            ]
+        );
+    }
+
+    #[test]
+    fn plan_engagement_strategy_enum_matches_domain() {
+        let tool = plan_engagement_tool();
+        let schema = tool.parameters_schema();
+        let strategies = schema["properties"]["strategy"]["enum"].as_array().unwrap();
+
+        for strategy in strategies {
+            let parsed: EngagementStrategy = serde_json::from_value(strategy.clone()).unwrap();
+            assert_eq!(serde_json::to_value(parsed).unwrap(), *strategy);
+        }
+    }
+}
@@ -3,10 +3,21 @@
 //! Tradeoffs is where dominated alternatives are identified, irrelevant
 //! objectives are surfaced, and key tensions are highlighted. This component
 //! helps focus the decision on what truly matters.
+//!
+//! The cycle's scoring method is chosen here too: Pugh comparison against a
+//! baseline, or weighted additive scores with per-objective contributions.
+//!
+//! It is also where even swaps simplify the table: the agent finds candidate
+//! swaps, proposes one for the user to confirm, and applies it once accepted.
+//!
+//! In group decisions each participant's scores and weights are recorded
+//! here and aggregated into a group view with a dissent report.
 
 use serde::{Deserialize, Serialize};
 
+use crate::domain::analysis::SwapCandidate;
 use crate::domain::conversation::tools::ToolDefinition;
+use crate::domain::proact::ScoringMethod;
 
 // ═══════════════════════════════════════════════════════════════════════════
 // Enums
@@ -63,6 +74,25 @@ pub struct SensitivityCheckParams {
     pub weight_range: Option<(f64, f64)>,
 }
 
+/// An objective's importance weight.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ObjectiveWeightInput {
+    /// Objective ID
+    pub objective_id: String,
+    /// Weight (0.0-1.0); weights are normalized when scoring
+    pub weight: f64,
+}
+
+/// Parameters for selecting the cycle's scoring method.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SelectScoringMethodParams {
+    /// Scoring method to use
+    pub method: ScoringMethod,
+    /// Objective weights to record (weighted additive only)
+    #[serde(default)]
+    pub weights: Vec<ObjectiveWeightInput>,
+}
+
 // ═══════════════════════════════════════════════════════════════════════════
 // Tool Parameters - Tradeoff Marking Tools
 // ═══════════════════════════════════════════════════════════════════════════
@@ -128,6 +158,74 @@ pub struct HighlightTradeoffParams {
     pub summary: String,
 }
 
+// ═══════════════════════════════════════════════════════════════════════════
+// Tool Parameters - Even Swap Tools
+// ═══════════════════════════════════════════════════════════════════════════
+
+/// Parameters for finding candidate even swaps.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FindEvenSwapsParams {
+    /// Most candidates to return (best first)
+    pub max_candidates: Option<usize>,
+}
+
+/// Parameters for proposing an even swap to the user.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProposeEvenSwapParams {
+    /// ID of the alternative to adjust
+    pub alternative_id: String,
+    /// ID of the objective whose rating is moved
+    pub give_objective_id: String,
+    /// New rating on that objective (-2 to +2)
+    pub give_to: i8,
+    /// ID of the objective that compensates
+    pub take_objective_id: String,
+    /// New rating on the compensating objective (-2 to +2)
+    pub take_to: i8,
+}
+
+/// Parameters for applying a confirmed even swap.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ApplyEvenSwapParams {
+    /// ID of the confirmation request the swap was proposed with
+    pub confirmation_id: String,
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// Tool Parameters - Group Preference Tools
+// ═══════════════════════════════════════════════════════════════════════════
+
+/// A participant's score for one alternative.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ParticipantScoreInput {
+    /// Alternative ID
+    pub alternative_id: String,
+    /// Overall score (0-100)
+    pub score: f64,
+}
+
+/// Parameters for recording one participant's preferences.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RecordParticipantPreferencesParams {
+    /// Stable ID for the participant; recording again replaces their entry
+    pub participant_id: String,
+    /// Display name
+    pub name: String,
+    /// Scores for the alternatives the participant rated
+    #[serde(default)]
+    pub scores: Vec<ParticipantScoreInput>,
+    /// Importance the participant gives each objective
+    #[serde(default)]
+    pub weights: Vec<ObjectiveWeightInput>,
+}
+
+/// Parameters for aggregating the group's preferences.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AggregateGroupPreferencesParams {
+    /// Score (0-100) at which a participant approves an alternative
+    pub approval_threshold: Option<f64>,
+}
+
 // ═══════════════════════════════════════════════════════════════════════════
 // Tool Results - Analysis Tools
 // ═══════════════════════════════════════════════════════════════════════════
@@ -233,6 +331,19 @@ pub struct SensitivityCheckResult {
     pub high_weight_winner: Option<String>,
 }
 
+/// Result of selecting the scoring method.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SelectScoringMethodResult {
+    /// Whether the method was selected
+    pub success: bool,
+    /// Method now in use
+    pub method: ScoringMethod,
+    /// Number of objective weights recorded
+    pub weights_updated: usize,
+    /// Whether the document was updated
+    pub document_updated: bool,
+}
+
 // ═══════════════════════════════════════════════════════════════════════════
 // Tool Results - Tradeoff Marking Tools
 // ═══════════════════════════════════════════════════════════════════════════
@@ -300,6 +411,79 @@ pub struct HighlightTradeoffResult {
     pub document_updated: bool,
 }
 
+// ═══════════════════════════════════════════════════════════════════════════
+// Tool Results - Even Swap Tools
+// ═══════════════════════════════════════════════════════════════════════════
+
+/// Result of finding candidate even swaps.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FindEvenSwapsResult {
+    /// Candidate swaps, most simplifying first
+    pub candidates: Vec<SwapCandidate>,
+    /// Total candidates found before truncation
+    pub total_found: usize,
+}
+
+/// Result of proposing an even swap.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProposeEvenSwapResult {
+    /// ID of the confirmation request awaiting the user
+    pub confirmation_id: String,
+    /// The swap as it will be shown to the user
+    pub summary: String,
+}
+
+/// Result of applying an even swap.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ApplyEvenSwapResult {
+    /// Whether the swap was applied
+    pub success: bool,
+    /// Objectives dropped because they no longer distinguish alternatives
+    pub removed_objectives: Vec<String>,
+    /// Alternatives dropped because they became dominated
+    pub removed_alternatives: Vec<String>,
+    /// Alternatives still in the table
+    pub remaining_alternatives: usize,
+    /// Whether the document was updated
+    pub document_updated: bool,
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// Tool Results - Group Preference Tools
+// ═══════════════════════════════════════════════════════════════════════════
+
+/// Result of recording a participant's preferences.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RecordParticipantPreferencesResult {
+    /// Whether the preferences were recorded
+    pub success: bool,
+    /// Whether an earlier entry for the participant was replaced
+    pub replaced: bool,
+    /// Participants with recorded preferences
+    pub participant_count: usize,
+    /// Whether the document was updated
+    pub document_updated: bool,
+}
+
+/// Result of aggregating the group's preferences.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AggregateGroupPreferencesResult {
+    /// Winner by Borda count (None if tied)
+    pub borda_winner: Option<String>,
+    /// Winner by approval voting (None if tied)
+    pub approval_winner: Option<String>,
+    /// Winner using averaged objective weights (None if tied)
+    pub weighted_winner: Option<String>,
+    /// Agreement with the group ranking (0.0-1.0)
+    pub consensus: Option<f64>,
+    /// Participants whose top choice differs from the Borda winner
+    pub dissenting_participants: Vec<String>,
+    /// Objectives participants weight very differently
+    pub contested_objectives: Vec<String>,
+    /// Alternatives participants score very differently
+    pub contested_alternatives: Vec<String>,
+}
+
 // ═══════════════════════════════════════════════════════════════════════════
 // Tool Definitions - Analysis Tools
 // ═══════════════════════════════════════════════════════════════════════════
@@ -432,6 +616,253 @@ pub fn sensitivity_check_tool() -> ToolDefinition {
     )
 }
 
+/// Creates the select_scoring_method tool definition.
+pub fn select_scoring_method_tool() -> ToolDefinition {
+    ToolDefinition::new(
+        "select_scoring_method",
+        "Choose how alternatives are scored for this cycle: Pugh comparison or weighted additive scores. Weights can be recorded at the same time.",
+        serde_json::json!({
+            "type": "object",
+            "required": ["method"],
+            "properties": {
+                "method": {
+                    "type": "string",
+                    "enum": ["pugh", "weighted_additive"],
+                    "description": "pugh compares against a baseline; weighted_additive gives absolute scores out of 100"
+                },
+                "weights": {
+                    "type": "array",
+                    "items": {
+                        "type": "object",
+                        "required": ["objective_id", "weight"],
+                        "properties": {
+                            "objective_id": { "type": "string" },
+                            "weight": { "type": "number", "minimum": 0, "maximum": 1 }
+                        }
+                    },
+                    "description": "Importance of each fundamental objective"
+                }
+            }
+        }),
+        serde_json::json!({
+            "type": "object",
+            "properties": {
+                "success": { "type": "boolean" },
+                "method": { "type": "string" },
+                "weights_updated": { "type": "integer" },
+                "document_updated": { "type": "boolean" }
+            }
+        }),
+    )
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// Tool Definitions - Even Swap Tools
+// ═══════════════════════════════════════════════════════════════════════════
+
+/// Creates the find_even_swaps tool definition.
+pub fn find_even_swaps_tool() -> ToolDefinition {
+    ToolDefinition::new(
+        "find_even_swaps",
+        "Find even swaps that would simplify the consequences table by making an objective irrelevant or an alternative dominated.",
+        serde_json::json!({
+            "type": "object",
+            "properties": {
+                "max_candidates": {
+                    "type": "integer",
+                    "minimum": 1,
+                    "description": "Most candidates to return (best first)"
+                }
+            }
+        }),
+        serde_json::json!({
+            "type": "object",
+            "properties": {
+                "candidates": {
+                    "type": "array",
+                    "items": { "type": "object" }
+                },
+                "total_found": { "type": "integer" }
+            }
+        }),
+    )
+}
+
+/// Creates the propose_even_swap tool definition.
+pub fn propose_even_swap_tool() -> ToolDefinition {
+    ToolDefinition::new(
+        "propose_even_swap",
+        "Ask the user to confirm an even swap: moving one alternative's rating on one objective, compensated by an equal-value change on another. Nothing changes until the user accepts.",
+        serde_json::json!({
+            "type": "object",
+            "required": ["alternative_id", "give_objective_id", "give_to", "take_objective_id", "take_to"],
+            "properties": {
+                "alternative_id": {
+                    "type": "string",
+                    "description": "ID of the alternative to adjust"
+                },
+                "give_objective_id": {
+                    "type": "string",
+                    "description": "ID of the objective whose rating is moved"
+                },
+                "give_to": {
+                    "type": "integer",
+                    "minimum": -2,
+                    "maximum": 2,
+                    "description": "New rating on that objective"
+                },
+                "take_objective_id": {
+                    "type": "string",
+                    "description": "ID of the objective that compensates"
+                },
+                "take_to": {
+                    "type": "integer",
+                    "minimum": -2,
+                    "maximum": 2,
+                    "description": "New rating on the compensating objective"
+                }
+            }
+        }),
+        serde_json::json!({
+            "type": "object",
+            "properties": {
+                "confirmation_id": { "type": "string" },
+                "summary": { "type": "string" }
+            }
+        }),
+    )
+}
+
+/// Creates the apply_even_swap tool definition.
+pub fn apply_even_swap_tool() -> ToolDefinition {
+    ToolDefinition::new(
+        "apply_even_swap",
+        "Apply an even swap the user accepted, then drop objectives that no longer distinguish alternatives and alternatives that became dominated.",
+        serde_json::json!({
+            "type": "object",
+            "required": ["confirmation_id"],
+            "properties": {
+                "confirmation_id": {
+                    "type": "string",
+                    "description": "ID of the confirmation request the swap was proposed with"
+                }
+            }
+        }),
+        serde_json::json!({
+            "type": "object",
+            "properties": {
+                "success": { "type": "boolean" },
+                "removed_objectives": {
+                    "type": "array",
+                    "items": { "type": "string" }
+                },
+                "removed_alternatives": {
+                    "type": "array",
+                    "items": { "type": "string" }
+                },
+                "remaining_alternatives": { "type": "integer" },
+                "document_updated": { "type": "boolean" }
+            }
+        }),
+    )
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// Tool Definitions - Group Preference Tools
+// ═══════════════════════════════════════════════════════════════════════════
+
+/// Creates the record_participant_preferences tool definition.
+pub fn record_participant_preferences_tool() -> ToolDefinition {
+    ToolDefinition::new(
+        "record_participant_preferences",
+        "Record one participant's scores for the alternatives and weights for the objectives in a group decision. Recording the same participant again replaces their entry.",
+        serde_json::json!({
+            "type": "object",
+            "required": ["participant_id", "name"],
+            "properties": {
+                "participant_id": {
+                    "type": "string",
+                    "description": "Stable ID for the participant"
+                },
+                "name": { "type": "string" },
+                "scores": {
+                    "type": "array",
+                    "items": {
+                        "type": "object",
+                        "required": ["alternative_id", "score"],
+                        "properties": {
+                            "alternative_id": { "type": "string" },
+                            "score": { "type": "number", "minimum": 0, "maximum": 100 }
+                        }
+                    },
+                    "description": "Overall score the participant gives each alternative"
+                },
+                "weights": {
+                    "type": "array",
+                    "items": {
+                        "type": "object",
+                        "required": ["objective_id", "weight"],
+                        "properties": {
+                            "objective_id": { "type": "string" },
+                            "weight": { "type": "number", "minimum": 0, "maximum": 1 }
+                        }
+                    },
+                    "description": "Importance the participant gives each fundamental objective"
+                }
+            }
+        }),
+        serde_json::json!({
+            "type": "object",
+            "properties": {
+                "success": { "type": "boolean" },
+                "replaced": { "type": "boolean" },
+                "participant_count": { "type": "integer" },
+                "document_updated": { "type": "boolean" }
+            }
+        }),
+    )
+}
+
+/// Creates the aggregate_group_preferences tool definition.
+pub fn aggregate_group_preferences_tool() -> ToolDefinition {
+    ToolDefinition::new(
+        "aggregate_group_preferences",
+        "Combine the participants' preferences by Borda count, approval voting, and weight averaging, and report who dissents and which objectives and alternatives are contested.",
+        serde_json::json!({
+            "type": "object",
+            "properties": {
+                "approval_threshold": {
+                    "type": "number",
+                    "minimum": 0,
+                    "maximum": 100,
+                    "description": "Score at which a participant approves an alternative (default 60)"
+                }
+            }
+        }),
+        serde_json::json!({
+            "type": "object",
+            "properties": {
+                "borda_winner": { "type": "string" },
+                "approval_winner": { "type": "string" },
+                "weighted_winner": { "type": "string" },
+                "consensus": { "type": "number" },
+                "dissenting_participants": {
+                    "type": "array",
+                    "items": { "type": "string" }
+                },
+                "contested_objectives": {
+                    "type": "array",
+                    "items": { "type": "string" }
+                },
+                "contested_alternatives": {
+                    "type": "array",
+                    "items": { "type": "string" }
+                }
+            }
+        }),
+    )
+}
+
 // ═══════════════════════════════════════════════════════════════════════════
 // Tool Definitions - Tradeoff Marking Tools
 // ═══════════════════════════════════════════════════════════════════════════
@@ -635,6 +1066,14 @@ pub fn all_tradeoffs_tools() -> Vec<ToolDefinition> {
         find_dominated_alternatives_tool(),
         find_irrelevant_objectives_tool(),
         sensitivity_check_tool(),
+        select_scoring_method_tool(),
+        // Even swap tools
+        find_even_swaps_tool(),
+        propose_even_swap_tool(),
+        apply_even_swap_tool(),
+        // Group preference tools
+        record_participant_preferences_tool(),
+        aggregate_group_preferences_tool(),
         // Marking tools
         mark_dominated_tool(),
         mark_irrelevant_objective_tool(),
@@ -674,9 +1113,37 @@ mod tests {
     }
 
     #[test]
-    fn all_tradeoffs_tools_returns_nine_tools() {
+    fn all_tradeoffs_tools_returns_fifteen_tools() {
         let tools = all_tradeoffs_tools();
-        assert_eq!(tools.len(), 9);
+        assert_eq!(tools.len(), 15);
+    }
+
+    #[test]
+    fn select_scoring_method_params_default_to_no_weights() {
+        let params: SelectScoringMethodParams =
+            serde_json::from_value(serde_json::json!({ "method": "weighted_additive" })).unwrap();
+        assert_eq!(params.method, ScoringMethod::WeightedAdditive);
+        assert!(params.weights.is_empty());
+    }
+
+    #[test]
+    fn record_participant_preferences_params_default_to_nothing_rated() {
+        let params: RecordParticipantPreferencesParams = serde_json::from_value(
+            serde_json::json!({ "participant_id": "p1", "name": "Ana" }),
+        )
+        .unwrap();
+        assert!(params.scores.is_empty());
+        assert!(params.weights.is_empty());
+    }
+
+    #[test]
+    fn propose_even_swap_requires_both_sides_of_the_swap() {
+        let tool = propose_even_swap_tool();
+        let schema = tool.parameters_schema();
+        let required = schema["required"].as_array().unwrap();
+        for field in ["give_objective_id", "give_to", "take_objective_id", "take_to"] {
+            assert!(required.iter().any(|v| v == field), "missing {}", field);
+        }
     }
 
     #[test]
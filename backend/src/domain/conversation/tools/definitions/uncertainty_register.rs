@@ -0,0 +1,269 @@
+//! Uncertainty Register Tools - Tools for tracking key uncertainties.
+//!
+//! The Uncertainty Register is where users take the uncertainties noted in the
+//! consequence table and estimate how likely they are, how much they matter,
+//! and what could be done about them.
+
+use serde::{Deserialize, Serialize};
+
+use crate::domain::conversation::tools::ToolDefinition;
+use crate::domain::proact::{ImpactLevel, MitigationKind};
+
+// ═══════════════════════════════════════════════════════════════════════════
+// Tool Parameters
+// ═══════════════════════════════════════════════════════════════════════════
+
+/// Parameters for registering an uncertainty.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RegisterUncertaintyParams {
+    /// What is uncertain
+    pub description: String,
+    /// ID of the consequence table uncertainty this expands on
+    pub consequence_uncertainty_id: Option<String>,
+    /// Alternatives whose consequences depend on it
+    #[serde(default)]
+    pub affected_alternative_ids: Vec<String>,
+    /// How much it could change the decision
+    pub impact: ImpactLevel,
+}
+
+/// Parameters for estimating the probability of an uncertainty.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EstimateUncertaintyProbabilityParams {
+    /// ID of the registered uncertainty
+    pub uncertainty_id: String,
+    /// Probability (0.0-1.0) that the unfavourable outcome occurs
+    pub probability: f64,
+    /// Where the estimate comes from
+    pub basis: Option<String>,
+}
+
+/// Parameters for adding a mitigation option.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AddMitigationParams {
+    /// ID of the registered uncertainty
+    pub uncertainty_id: String,
+    /// What would be done
+    pub description: String,
+    /// Whether it resolves, reduces, hedges or accepts the uncertainty
+    pub kind: MitigationKind,
+    /// Rough cost or effort
+    pub cost: Option<String>,
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// Tool Results
+// ═══════════════════════════════════════════════════════════════════════════
+
+/// Result of registering an uncertainty.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RegisterUncertaintyResult {
+    /// ID of the registered uncertainty
+    pub uncertainty_id: String,
+    /// Total number of registered uncertainties
+    pub total_uncertainties: usize,
+    /// Consequence table uncertainties not yet registered
+    pub unregistered_count: usize,
+    /// Whether the document was updated
+    pub document_updated: bool,
+}
+
+/// Result of estimating a probability.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EstimateUncertaintyProbabilityResult {
+    /// Whether the operation succeeded
+    pub success: bool,
+    /// Probability weighted by impact
+    pub exposure: f64,
+    /// Position in the register when ranked by exposure (1 = highest)
+    pub rank: usize,
+    /// Whether the document was updated
+    pub document_updated: bool,
+}
+
+/// Result of adding a mitigation option.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AddMitigationResult {
+    /// Whether the operation succeeded
+    pub success: bool,
+    /// High-impact uncertainties still without a mitigation option
+    pub unmitigated_count: usize,
+    /// Whether the document was updated
+    pub document_updated: bool,
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// Tool Definitions
+// ═══════════════════════════════════════════════════════════════════════════
+
+/// Creates the register_uncertainty tool definition.
+pub fn register_uncertainty_tool() -> ToolDefinition {
+    ToolDefinition::new(
+        "register_uncertainty",
+        "Add a key uncertainty to the register with its impact on the decision. Link it to the consequence table uncertainty it expands on when there is one.",
+        serde_json::json!({
+            "type": "object",
+            "required": ["description", "impact"],
+            "properties": {
+                "description": {
+                    "type": "string",
+                    "description": "What is uncertain"
+                },
+                "consequence_uncertainty_id": {
+                    "type": "string",
+                    "description": "ID of the consequence table uncertainty this expands on"
+                },
+                "affected_alternative_ids": {
+                    "type": "array",
+                    "items": { "type": "string" },
+                    "description": "Alternatives whose consequences depend on it"
+                },
+                "impact": {
+                    "type": "string",
+                    "enum": ["low", "medium", "high", "critical"],
+                    "description": "How much it could change the decision; critical means it could change which alternative is best"
+                }
+            }
+        }),
+        serde_json::json!({
+            "type": "object",
+            "properties": {
+                "uncertainty_id": { "type": "string" },
+                "total_uncertainties": { "type": "integer" },
+                "unregistered_count": { "type": "integer" },
+                "document_updated": { "type": "boolean" }
+            }
+        }),
+    )
+}
+
+/// Creates the estimate_uncertainty_probability tool definition.
+pub fn estimate_uncertainty_probability_tool() -> ToolDefinition {
+    ToolDefinition::new(
+        "estimate_uncertainty_probability",
+        "Record the user's estimate of how likely the unfavourable outcome of an uncertainty is, and what the estimate is based on.",
+        serde_json::json!({
+            "type": "object",
+            "required": ["uncertainty_id", "probability"],
+            "properties": {
+                "uncertainty_id": {
+                    "type": "string",
+                    "description": "ID of the registered uncertainty"
+                },
+                "probability": {
+                    "type": "number",
+                    "minimum": 0,
+                    "maximum": 1,
+                    "description": "Probability that the unfavourable outcome occurs"
+                },
+                "basis": {
+                    "type": "string",
+                    "description": "Where the estimate comes from"
+                }
+            }
+        }),
+        serde_json::json!({
+            "type": "object",
+            "properties": {
+                "success": { "type": "boolean" },
+                "exposure": { "type": "number" },
+                "rank": { "type": "integer" },
+                "document_updated": { "type": "boolean" }
+            }
+        }),
+    )
+}
+
+/// Creates the add_mitigation tool definition.
+pub fn add_mitigation_tool() -> ToolDefinition {
+    ToolDefinition::new(
+        "add_mitigation",
+        "Record an option for dealing with a registered uncertainty: resolve it with more information, reduce its likelihood, hedge against it, or accept it.",
+        serde_json::json!({
+            "type": "object",
+            "required": ["uncertainty_id", "description", "kind"],
+            "properties": {
+                "uncertainty_id": {
+                    "type": "string",
+                    "description": "ID of the registered uncertainty"
+                },
+                "description": {
+                    "type": "string",
+                    "description": "What would be done"
+                },
+                "kind": {
+                    "type": "string",
+                    "enum": ["resolve", "reduce", "hedge", "accept"]
+                },
+                "cost": {
+                    "type": "string",
+                    "description": "Rough cost or effort"
+                }
+            }
+        }),
+        serde_json::json!({
+            "type": "object",
+            "properties": {
+                "success": { "type": "boolean" },
+                "unmitigated_count": { "type": "integer" },
+                "document_updated": { "type": "boolean" }
+            }
+        }),
+    )
+}
+
+/// Returns all Uncertainty Register tool definitions.
+pub fn all_uncertainty_register_tools() -> Vec<ToolDefinition> {
+    vec![
+        register_uncertainty_tool(),
+        estimate_uncertainty_probability_tool(),
+        add_mitigation_tool(),
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn register_uncertainty_params_default_affected_alternatives() {
+        let params: RegisterUncertaintyParams = serde_json::from_value(serde_json::json!({
+            "description": "Mortgage rates rise",
+            "impact": "critical"
+        }))
+        .unwrap();
+
+        assert_eq!(params.impact, ImpactLevel::Critical);
+        assert!(params.consequence_uncertainty_id.is_none());
+        assert!(params.affected_alternative_ids.is_empty());
+    }
+
+    #[test]
+    fn all_uncertainty_register_tools_returns_three_tools() {
+        let names: Vec<String> = all_uncertainty_register_tools()
+            .iter()
+            .map(|t| t.name().to_string())
+            .collect();
+
+        assert_eq!(
+            names,
+            [
+                "register_uncertainty",
+                "estimate_uncertainty_probability",
+                "add_mitigation"
+            ]
+        );
+    }
+
+    #[test]
+    fn add_mitigation_kind_enum_matches_domain() {
+        let tool = add_mitigation_tool();
+        let schema = tool.parameters_schema();
+        let kinds = schema["properties"]["kind"]["enum"].as_array().unwrap();
+
+        for kind in kinds {
+            let parsed: MitigationKind = serde_json::from_value(kind.clone()).unwrap();
+            assert_eq!(serde_json::to_value(parsed).unwrap(), *kind);
+        }
+    }
+}
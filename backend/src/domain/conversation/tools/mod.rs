@@ -7,6 +7,7 @@
 //! ## Key Types
 //!
 //! - [`ToolInvocation`] - Entity tracking every tool call for audit
+//! - [`ToolInvoked`] - Event published when an invocation completes
 //! - [`ToolResult`] - Outcome of a tool execution
 //! - [`ToolCall`] - Request to invoke a tool
 //! - [`ToolResponse`] - Result returned from a tool
@@ -24,6 +25,7 @@
 
 mod tool_result;
 mod tool_invocation;
+mod tool_invoked;
 mod tool_call;
 mod tool_definition;
 mod tool_registry;
@@ -33,6 +35,7 @@ pub mod definitions;
 
 pub use tool_result::ToolResult;
 pub use tool_invocation::ToolInvocation;
+pub use tool_invoked::ToolInvoked;
 pub use tool_call::{ToolCall, ToolResponse};
 pub use tool_definition::ToolDefinition;
 pub use tool_registry::ToolRegistry;
@@ -0,0 +1,80 @@
+//! ToolInvoked event - published when a tool invocation completes.
+//!
+//! Carries what was called and how it went, but not the parameters or the
+//! conversation text that triggered the call: those stay in the
+//! `ToolInvocation` audit record.
+
+use serde::{Deserialize, Serialize};
+
+use crate::domain::foundation::{
+    domain_event, ComponentType, CycleId, EventId, Timestamp, ToolInvocationId,
+};
+
+use super::{ToolInvocation, ToolResult};
+
+/// Published when a tool invocation completes, successfully or not.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ToolInvoked {
+    pub event_id: EventId,
+    pub invocation_id: ToolInvocationId,
+    pub cycle_id: CycleId,
+    pub component: ComponentType,
+    pub tool_name: String,
+    pub result: ToolResult,
+    pub duration_ms: u32,
+    pub invoked_at: Timestamp,
+}
+
+domain_event!(
+    ToolInvoked,
+    event_type = "tool.invoked.v1",
+    schema_version = 1,
+    aggregate_id = cycle_id,
+    aggregate_type = "Cycle",
+    occurred_at = invoked_at,
+    event_id = event_id
+);
+
+impl From<&ToolInvocation> for ToolInvoked {
+    fn from(invocation: &ToolInvocation) -> Self {
+        Self {
+            event_id: EventId::new(),
+            invocation_id: invocation.id(),
+            cycle_id: invocation.cycle_id(),
+            component: invocation.component(),
+            tool_name: invocation.tool_name().to_string(),
+            result: invocation.result(),
+            duration_ms: invocation.duration_ms(),
+            invoked_at: invocation.invoked_at(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::foundation::SerializableDomainEvent;
+
+    #[test]
+    fn leaves_parameters_and_reasoning_out_of_the_payload() {
+        let mut invocation = ToolInvocation::new(
+            CycleId::new(),
+            ComponentType::Objectives,
+            "add_objective".to_string(),
+            serde_json::json!({ "name": "Stay near my sister" }),
+            3,
+            "User mentioned family".to_string(),
+        );
+        invocation.complete(None);
+
+        let envelope = ToolInvoked::from(&invocation).to_envelope();
+
+        assert_eq!(envelope.event_type, "tool.invoked.v1");
+        assert_eq!(envelope.aggregate_id, invocation.cycle_id().to_string());
+        assert_eq!(envelope.payload["tool_name"], "add_objective");
+        assert_eq!(envelope.payload["result"], "success");
+        let payload = envelope.payload.to_string();
+        assert!(!payload.contains("sister"));
+        assert!(!payload.contains("family"));
+    }
+}
@@ -11,7 +11,9 @@ use crate::domain::foundation::{
 };
 use crate::domain::proact::{ComponentSequence, ComponentVariant};
 
-use super::{BranchMetadata, CycleEvent};
+use super::{
+    BranchMetadata, ComponentSnapshot, CycleEvent, CycleSnapshot, RecordedCycleEvent,
+};
 
 /// The Cycle aggregate root.
 ///
@@ -30,7 +32,10 @@ pub struct Cycle {
     components: HashMap<ComponentType, ComponentVariant>,
     created_at: Timestamp,
     updated_at: Timestamp,
-    domain_events: Vec<CycleEvent>,
+    /// Incremented on every recorded change; clients use it to reconcile
+    /// optimistic updates and detect missed events.
+    version: u64,
+    domain_events: Vec<RecordedCycleEvent>,
 }
 
 impl Cycle {
@@ -39,7 +44,7 @@ impl Cycle {
         let id = CycleId::new();
         let now = Timestamp::now();
 
-        // Initialize all 9 components
+        // Initialize all 11 components
         let mut components = HashMap::new();
         for ct in ComponentSequence::all() {
             components.insert(*ct, ComponentVariant::new(*ct));
@@ -56,11 +61,14 @@ impl Cycle {
             components,
             created_at: now,
             updated_at: now,
+            version: 0,
             domain_events: Vec::new(),
         };
 
         cycle.record_event(CycleEvent::Created {
             cycle_id: id,
+            session_id,
+            components: cycle.component_snapshots(),
             created_at: now,
         });
 
@@ -83,6 +91,7 @@ impl Cycle {
         components: HashMap<ComponentType, ComponentVariant>,
         created_at: Timestamp,
         updated_at: Timestamp,
+        version: u64,
     ) -> Result<Self, DomainError> {
         Ok(Self {
             id,
@@ -95,10 +104,79 @@ impl Cycle {
             components,
             created_at,
             updated_at,
+            version,
             domain_events: Vec::new(),
         })
     }
 
+    /// Reconstitutes a cycle from a snapshot.
+    pub fn from_snapshot(snapshot: CycleSnapshot) -> Result<Self, DomainError> {
+        let components = snapshot
+            .components
+            .iter()
+            .map(|c| Ok((c.component_type, c.to_component()?)))
+            .collect::<Result<HashMap<_, _>, DomainError>>()?;
+
+        Self::reconstitute(
+            snapshot.cycle_id,
+            snapshot.session_id,
+            snapshot.parent_cycle_id,
+            snapshot.branch_point,
+            snapshot.branch_metadata,
+            snapshot.status,
+            snapshot.current_step,
+            components,
+            snapshot.created_at,
+            snapshot.updated_at,
+            snapshot.version,
+        )
+    }
+
+    /// Rebuilds a cycle by replaying its events.
+    ///
+    /// Replay starts from `snapshot` when given, skipping events it already
+    /// covers; otherwise the first event must be `Created` or `Branched`.
+    /// Versions must follow on without gaps.
+    pub fn replay(
+        snapshot: Option<CycleSnapshot>,
+        events: &[RecordedCycleEvent],
+    ) -> Result<Self, DomainError> {
+        let mut events = events.iter();
+        let mut cycle = match snapshot {
+            Some(snapshot) => Self::from_snapshot(snapshot)?,
+            None => {
+                let first = events.next().ok_or_else(|| {
+                    DomainError::new(ErrorCode::InternalError, "Cycle has no recorded history")
+                })?;
+                Self::from_origin(first)?
+            }
+        };
+
+        let start = cycle.version;
+        for recorded in events.filter(|e| e.version > start) {
+            cycle.apply(recorded)?;
+        }
+
+        Ok(cycle)
+    }
+
+    /// Captures the cycle's current state.
+    pub fn snapshot(&self) -> CycleSnapshot {
+        CycleSnapshot {
+            cycle_id: self.id,
+            session_id: self.session_id,
+            parent_cycle_id: self.parent_cycle_id,
+            branch_point: self.branch_point,
+            branch_metadata: self.branch_metadata.clone(),
+            status: self.status,
+            current_step: self.current_step,
+            components: self.component_snapshots(),
+            created_at: self.created_at,
+            updated_at: self.updated_at,
+            version: self.version,
+        }
+    }
+
     // ───────────────────────────────────────────────────────────────
     // Accessors
     // ───────────────────────────────────────────────────────────────
@@ -148,6 +226,17 @@ impl Cycle {
         self.updated_at
     }
 
+    /// Returns the aggregate version (number of changes since creation).
+    pub fn version(&self) -> u64 {
+        self.version
+    }
+
+    /// Returns the version this cycle was loaded at, before any uncommitted
+    /// events. Repositories use it as the expected version when saving.
+    pub fn loaded_version(&self) -> u64 {
+        self.version - self.domain_events.len() as u64
+    }
+
     /// Returns the status of a specific component.
     pub fn component_status(&self, ct: ComponentType) -> ComponentStatus {
         self.components
@@ -174,6 +263,14 @@ impl Cycle {
     /// Takes accumulated domain events, clearing the internal buffer.
     pub fn take_events(&mut self) -> Vec<CycleEvent> {
         std::mem::take(&mut self.domain_events)
+            .into_iter()
+            .map(|recorded| recorded.event)
+            .collect()
+    }
+
+    /// Returns events recorded since the cycle was loaded, with their versions.
+    pub fn uncommitted_events(&self) -> &[RecordedCycleEvent] {
+        &self.domain_events
     }
 
     // ───────────────────────────────────────────────────────────────
@@ -320,12 +417,14 @@ impl Cycle {
         component
             .set_output_from_value(output)
             .map_err(|e| DomainError::new(ErrorCode::InvalidFormat, e.to_string()))?;
+        let output = component.output_as_value();
 
         self.updated_at = Timestamp::now();
 
         self.record_event(CycleEvent::ComponentOutputUpdated {
             cycle_id: self.id,
             component_type: ct,
+            output,
         });
 
         Ok(())
@@ -582,13 +681,17 @@ impl Cycle {
             components: new_components,
             created_at: now,
             updated_at: now,
+            version: 0,
             domain_events: Vec::new(),
         };
 
         branch.record_event(CycleEvent::Branched {
             cycle_id: id,
+            session_id: self.session_id,
             parent_cycle_id: self.id,
             branch_point,
+            branch_label: branch.branch_metadata.branch_label.clone(),
+            components: branch.component_snapshots(),
             created_at: now,
         });
 
@@ -707,7 +810,154 @@ impl Cycle {
     // ───────────────────────────────────────────────────────────────
 
     fn record_event(&mut self, event: CycleEvent) {
-        self.domain_events.push(event);
+        self.version += 1;
+        self.domain_events.push(RecordedCycleEvent {
+            version: self.version,
+            occurred_at: self.updated_at,
+            event,
+        });
+    }
+
+    fn component_snapshots(&self) -> Vec<ComponentSnapshot> {
+        ComponentSequence::all()
+            .iter()
+            .filter_map(|ct| self.components.get(ct))
+            .map(ComponentSnapshot::of)
+            .collect()
+    }
+
+    // ───────────────────────────────────────────────────────────────
+    // Event Replay
+    // ───────────────────────────────────────────────────────────────
+
+    /// Builds a cycle from the event that started its history.
+    fn from_origin(recorded: &RecordedCycleEvent) -> Result<Self, DomainError> {
+        let snapshot = match &recorded.event {
+            CycleEvent::Created {
+                cycle_id,
+                session_id,
+                components,
+                created_at,
+            } => CycleSnapshot {
+                cycle_id: *cycle_id,
+                session_id: *session_id,
+                parent_cycle_id: None,
+                branch_point: None,
+                branch_metadata: BranchMetadata::root(),
+                status: CycleStatus::Active,
+                current_step: ComponentSequence::first(),
+                components: components.clone(),
+                created_at: *created_at,
+                updated_at: recorded.occurred_at,
+                version: recorded.version,
+            },
+            CycleEvent::Branched {
+                cycle_id,
+                session_id,
+                parent_cycle_id,
+                branch_point,
+                branch_label,
+                components,
+                created_at,
+            } => CycleSnapshot {
+                cycle_id: *cycle_id,
+                session_id: *session_id,
+                parent_cycle_id: Some(*parent_cycle_id),
+                branch_point: Some(*branch_point),
+                branch_metadata: BranchMetadata::branched(branch_label.clone()),
+                status: CycleStatus::Active,
+                current_step: *branch_point,
+                components: components.clone(),
+                created_at: *created_at,
+                updated_at: recorded.occurred_at,
+                version: recorded.version,
+            },
+            other => {
+                return Err(DomainError::new(
+                    ErrorCode::InternalError,
+                    format!("Cycle history starts with {}", other.event_type()),
+                ))
+            }
+        };
+
+        Self::from_snapshot(snapshot)
+    }
+
+    /// Applies one recorded event without re-validating it.
+    fn apply(&mut self, recorded: &RecordedCycleEvent) -> Result<(), DomainError> {
+        if recorded.version != self.version + 1 {
+            return Err(DomainError::new(
+                ErrorCode::InternalError,
+                format!(
+                    "Cycle {} history jumps from version {} to {}",
+                    self.id, self.version, recorded.version
+                ),
+            ));
+        }
+
+        match &recorded.event {
+            CycleEvent::Created { .. } | CycleEvent::Branched { .. } => {
+                return Err(DomainError::new(
+                    ErrorCode::InternalError,
+                    format!(
+                        "Cycle {} history restarts at version {}",
+                        self.id, recorded.version
+                    ),
+                ))
+            }
+            CycleEvent::Completed { .. } => self.status = CycleStatus::Completed,
+            CycleEvent::Archived { .. } => self.status = CycleStatus::Archived,
+            CycleEvent::ComponentStarted { component_type, .. } => {
+                self.replayed_component(*component_type)?
+                    .start()
+                    .map_err(|e| DomainError::new(ErrorCode::InternalError, e.to_string()))?;
+                self.current_step = *component_type;
+            }
+            CycleEvent::ComponentCompleted { component_type, .. } => {
+                self.replayed_component(*component_type)?
+                    .complete()
+                    .map_err(|e| DomainError::new(ErrorCode::InternalError, e.to_string()))?;
+            }
+            CycleEvent::ComponentMarkedForRevision {
+                component_type,
+                reason,
+                ..
+            } => {
+                self.replayed_component(*component_type)?
+                    .mark_for_revision(reason.clone())
+                    .map_err(|e| DomainError::new(ErrorCode::InternalError, e.to_string()))?;
+                self.current_step = *component_type;
+            }
+            CycleEvent::NavigatedTo { component_type, .. } => {
+                self.current_step = *component_type;
+            }
+            CycleEvent::ComponentOutputUpdated {
+                component_type,
+                output,
+                ..
+            } => {
+                self.replayed_component(*component_type)?
+                    .set_output_from_value(output.clone())
+                    .map_err(|e| DomainError::new(ErrorCode::InternalError, e.to_string()))?;
+            }
+        }
+
+        self.updated_at = recorded.occurred_at;
+        self.version = recorded.version;
+        Ok(())
+    }
+
+    fn replayed_component(
+        &mut self,
+        ct: ComponentType,
+    ) -> Result<&mut ComponentVariant, DomainError> {
+        let id = self.id;
+        self.components.get_mut(&ct).ok_or_else(|| {
+            DomainError::new(
+                ErrorCode::InternalError,
+                format!("Cycle {} history refers to missing {:?}", id, ct),
+            )
+        })
     }
 }
 
@@ -764,6 +1014,30 @@ mod tests {
         assert!(matches!(events[0], CycleEvent::Created { .. }));
     }
 
+    #[test]
+    fn new_cycle_starts_at_version_one() {
+        assert_eq!(create_test_cycle().version(), 1);
+    }
+
+    #[test]
+    fn each_change_increments_version() {
+        let mut cycle = create_test_cycle();
+        cycle.start_component(ComponentType::IssueRaising).unwrap();
+        cycle.navigate_to(ComponentType::IssueRaising).unwrap();
+        assert_eq!(cycle.version(), 3);
+
+        // Taking events does not reset the version
+        cycle.take_events();
+        assert_eq!(cycle.version(), 3);
+    }
+
+    #[test]
+    fn rejected_change_keeps_version() {
+        let mut cycle = create_test_cycle();
+        assert!(cycle.complete().is_err());
+        assert_eq!(cycle.version(), 1);
+    }
+
     // ───────────────────────────────────────────────────────────────
     // Start Component Tests
     // ───────────────────────────────────────────────────────────────
@@ -1062,6 +1336,12 @@ mod tests {
         cycle
             .complete_component(ComponentType::ProblemFrame)
             .unwrap();
+        cycle
+            .start_component(ComponentType::StakeholderAnalysis)
+            .unwrap();
+        cycle
+            .complete_component(ComponentType::StakeholderAnalysis)
+            .unwrap();
         cycle.start_component(ComponentType::Objectives).unwrap();
 
         let branch = cycle.branch_at(ComponentType::Objectives, None).unwrap();
@@ -1082,6 +1362,12 @@ mod tests {
         cycle
             .complete_component(ComponentType::ProblemFrame)
             .unwrap();
+        cycle
+            .start_component(ComponentType::StakeholderAnalysis)
+            .unwrap();
+        cycle
+            .complete_component(ComponentType::StakeholderAnalysis)
+            .unwrap();
         cycle.start_component(ComponentType::Objectives).unwrap();
 
         let branch = cycle.branch_at(ComponentType::Objectives, None).unwrap();
@@ -1332,4 +1618,108 @@ mod tests {
         let result = cycle.validate_component_completion_rules(ComponentType::DecisionQuality, &output);
         assert!(result.is_err());
     }
+
+    // ───────────────────────────────────────────────────────────────
+    // Event Replay Tests
+    // ───────────────────────────────────────────────────────────────
+
+    fn worked_cycle() -> Cycle {
+        let mut cycle = create_test_cycle();
+        cycle.start_component(ComponentType::IssueRaising).unwrap();
+        cycle
+            .update_component_output(
+                ComponentType::IssueRaising,
+                serde_json::json!({
+                    "potential_decisions": ["Option A"],
+                    "objectives": [],
+                    "uncertainties": [],
+                    "considerations": [],
+                    "user_confirmed": true
+                }),
+            )
+            .unwrap();
+        cycle.complete_component(ComponentType::IssueRaising).unwrap();
+        cycle.start_component(ComponentType::ProblemFrame).unwrap();
+        cycle.navigate_to(ComponentType::IssueRaising).unwrap();
+        cycle
+    }
+
+    fn assert_same_state(rebuilt: &Cycle, original: &Cycle) {
+        let (mut rebuilt, mut original) = (rebuilt.snapshot(), original.snapshot());
+        // Replay cannot reproduce when a component's in-memory timestamp moved.
+        for c in rebuilt.components.iter_mut().chain(original.components.iter_mut()) {
+            c.updated_at = c.created_at;
+        }
+        assert_eq!(rebuilt, original);
+    }
+
+    #[test]
+    fn events_carry_consecutive_versions() {
+        let cycle = worked_cycle();
+        let versions: Vec<u64> = cycle.uncommitted_events().iter().map(|e| e.version).collect();
+        assert_eq!(versions, (1..=cycle.version()).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn replay_rebuilds_cycle_from_events() {
+        let cycle = worked_cycle();
+
+        let rebuilt = Cycle::replay(None, cycle.uncommitted_events()).unwrap();
+
+        assert_same_state(&rebuilt, &cycle);
+        assert_eq!(
+            rebuilt
+                .component(ComponentType::IssueRaising)
+                .unwrap()
+                .output_as_value()["potential_decisions"],
+            serde_json::json!(["Option A"])
+        );
+        assert!(rebuilt.uncommitted_events().is_empty());
+    }
+
+    #[test]
+    fn replay_from_snapshot_applies_only_later_events() {
+        let mut cycle = create_test_cycle();
+        cycle.start_component(ComponentType::IssueRaising).unwrap();
+        let snapshot = cycle.snapshot();
+        cycle.complete_component(ComponentType::IssueRaising).unwrap();
+
+        let rebuilt = Cycle::replay(Some(snapshot), cycle.uncommitted_events()).unwrap();
+
+        assert_same_state(&rebuilt, &cycle);
+        assert_eq!(rebuilt.version(), 3);
+    }
+
+    #[test]
+    fn replay_rebuilds_branch_without_parent() {
+        let parent = worked_cycle();
+        let branch = parent
+            .branch_at(ComponentType::IssueRaising, Some("Remote".to_string()))
+            .unwrap();
+
+        let rebuilt = Cycle::replay(None, branch.uncommitted_events()).unwrap();
+
+        assert_same_state(&rebuilt, &branch);
+        assert_eq!(rebuilt.parent_cycle_id(), Some(parent.id()));
+        assert_eq!(
+            rebuilt.branch_metadata().branch_label.as_deref(),
+            Some("Remote")
+        );
+    }
+
+    #[test]
+    fn replay_rejects_gaps_in_history() {
+        let cycle = worked_cycle();
+        let mut events = cycle.uncommitted_events().to_vec();
+        events.remove(2);
+
+        assert!(Cycle::replay(None, &events).is_err());
+    }
+
+    #[test]
+    fn replay_requires_an_origin_event() {
+        let cycle = worked_cycle();
+        assert!(Cycle::replay(None, &cycle.uncommitted_events()[1..]).is_err());
+        assert!(Cycle::replay(None, &[]).is_err());
+    }
 }
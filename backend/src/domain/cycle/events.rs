@@ -2,24 +2,43 @@
 //!
 //! Events emitted during cycle lifecycle operations. These events are used
 //! for event sourcing, audit trails, and triggering side effects.
-
-use crate::domain::foundation::{ComponentType, CycleId, Timestamp};
+//!
+//! Each event carries what replay needs to rebuild the cycle, so creation and
+//! branching events include the initial components. Stored events are read
+//! back through an [`UpcasterRegistry`], which lifts older schema versions to
+//! the current shape before deserialization.
+
+use crate::domain::foundation::{
+    ComponentType, CycleId, EventEnvelope, SessionId, Timestamp, UpcastError, UpcasterRegistry,
+};
 use serde::{Deserialize, Serialize};
 
+use super::ComponentSnapshot;
+
+/// Aggregate type stored events are filed under.
+pub const CYCLE_AGGREGATE_TYPE: &str = "Cycle";
+
 /// Events that can occur during cycle lifecycle.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub enum CycleEvent {
     /// A new cycle was created.
     Created {
         cycle_id: CycleId,
+        session_id: SessionId,
+        /// The fresh components the cycle starts with.
+        components: Vec<ComponentSnapshot>,
         created_at: Timestamp,
     },
 
     /// A cycle was branched from a parent.
     Branched {
         cycle_id: CycleId,
+        session_id: SessionId,
         parent_cycle_id: CycleId,
         branch_point: ComponentType,
+        branch_label: Option<String>,
+        /// Components as copied from the parent at the branch point.
+        components: Vec<ComponentSnapshot>,
         created_at: Timestamp,
     },
 
@@ -58,9 +77,19 @@ pub enum CycleEvent {
     ComponentOutputUpdated {
         cycle_id: CycleId,
         component_type: ComponentType,
+        output: serde_json::Value,
     },
 }
 
+/// A cycle event as stored, with the version it produced.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RecordedCycleEvent {
+    /// Cycle version after this event; the first event is version 1.
+    pub version: u64,
+    pub occurred_at: Timestamp,
+    pub event: CycleEvent,
+}
+
 impl CycleEvent {
     /// Returns the cycle ID associated with this event.
     ///
@@ -93,6 +122,81 @@ impl CycleEvent {
             CycleEvent::ComponentOutputUpdated { .. } => "ComponentOutputUpdated",
         }
     }
+
+    /// Returns the versioned type the event is stored under.
+    pub fn stored_type(&self) -> &'static str {
+        match self {
+            CycleEvent::Created { .. } => "cycle.created.v1",
+            CycleEvent::Branched { .. } => "cycle.branched.v1",
+            CycleEvent::Completed { .. } => "cycle.completed.v1",
+            CycleEvent::Archived { .. } => "cycle.archived.v1",
+            CycleEvent::ComponentStarted { .. } => "cycle.component_started.v1",
+            CycleEvent::ComponentCompleted { .. } => "cycle.component_completed.v1",
+            CycleEvent::ComponentMarkedForRevision { .. } => {
+                "cycle.component_marked_for_revision.v1"
+            }
+            CycleEvent::NavigatedTo { .. } => "cycle.navigated_to.v1",
+            CycleEvent::ComponentOutputUpdated { .. } => "cycle.component_output_updated.v1",
+        }
+    }
+
+    /// Returns the component the event concerns, if any.
+    pub fn component_type(&self) -> Option<ComponentType> {
+        match self {
+            CycleEvent::ComponentStarted { component_type, .. }
+            | CycleEvent::ComponentCompleted { component_type, .. }
+            | CycleEvent::ComponentMarkedForRevision { component_type, .. }
+            | CycleEvent::NavigatedTo { component_type, .. }
+            | CycleEvent::ComponentOutputUpdated { component_type, .. } => Some(*component_type),
+            CycleEvent::Branched { branch_point, .. } => Some(*branch_point),
+            CycleEvent::Created { .. } | CycleEvent::Completed { .. } | CycleEvent::Archived { .. } => {
+                None
+            }
+        }
+    }
+
+    /// Wraps the event for storage.
+    pub fn to_envelope(&self, occurred_at: Timestamp) -> Result<EventEnvelope, UpcastError> {
+        let mut envelope = EventEnvelope::new(
+            self.stored_type(),
+            self.cycle_id().to_string(),
+            CYCLE_AGGREGATE_TYPE,
+            serde_json::to_value(self)?,
+        );
+        envelope.occurred_at = occurred_at;
+        Ok(envelope)
+    }
+
+    /// Reads a stored event, upcasting it to the current schema first.
+    pub fn from_envelope(
+        envelope: EventEnvelope,
+        upcasters: &UpcasterRegistry,
+    ) -> Result<Self, UpcastError> {
+        let current = upcasters.upcast_to_current(envelope)?;
+        Ok(serde_json::from_value(current.payload)?)
+    }
+}
+
+/// Upcasters for stored cycle events.
+///
+/// Every event type is at schema version 1. When a payload changes shape,
+/// register the upcaster here and bump the type's current version.
+pub fn cycle_event_upcasters() -> UpcasterRegistry {
+    let mut registry = UpcasterRegistry::new();
+    for base_type in [
+        "cycle.created",
+        "cycle.branched",
+        "cycle.completed",
+        "cycle.archived",
+        "cycle.component_started",
+        "cycle.component_completed",
+        "cycle.component_marked_for_revision",
+        "cycle.navigated_to",
+        "cycle.component_output_updated",
+    ] {
+        registry.set_current_version(base_type, 1);
+    }
+    registry
 }
 
 #[cfg(test)]
@@ -103,6 +207,31 @@ mod tests {
         CycleId::new()
     }
 
+    fn created(cycle_id: CycleId) -> CycleEvent {
+        CycleEvent::Created {
+            cycle_id,
+            session_id: SessionId::new(),
+            components: vec![],
+            created_at: Timestamp::now(),
+        }
+    }
+
+    fn branched(
+        cycle_id: CycleId,
+        parent_cycle_id: CycleId,
+        branch_point: ComponentType,
+    ) -> CycleEvent {
+        CycleEvent::Branched {
+            cycle_id,
+            session_id: SessionId::new(),
+            parent_cycle_id,
+            branch_point,
+            branch_label: None,
+            components: vec![],
+            created_at: Timestamp::now(),
+        }
+    }
+
     // ───────────────────────────────────────────────────────────────
     // cycle_id accessor tests
     // ───────────────────────────────────────────────────────────────
@@ -110,10 +239,7 @@ mod tests {
     #[test]
     fn cycle_id_returns_id_for_created() {
         let id = test_cycle_id();
-        let event = CycleEvent::Created {
-            cycle_id: id,
-            created_at: Timestamp::now(),
-        };
+        let event = created(id);
         assert_eq!(event.cycle_id(), id);
     }
 
@@ -121,12 +247,7 @@ mod tests {
     fn cycle_id_returns_id_for_branched() {
         let id = test_cycle_id();
         let parent_id = test_cycle_id();
-        let event = CycleEvent::Branched {
-            cycle_id: id,
-            parent_cycle_id: parent_id,
-            branch_point: ComponentType::Objectives,
-            created_at: Timestamp::now(),
-        };
+        let event = branched(id, parent_id, ComponentType::Objectives);
         assert_eq!(event.cycle_id(), id);
     }
 
@@ -191,6 +312,7 @@ mod tests {
         let event = CycleEvent::ComponentOutputUpdated {
             cycle_id: id,
             component_type: ComponentType::IssueRaising,
+            output: serde_json::json!({}),
         };
         assert_eq!(event.cycle_id(), id);
     }
@@ -204,11 +326,7 @@ mod tests {
         let id = test_cycle_id();
 
         assert_eq!(
-            CycleEvent::Created {
-                cycle_id: id,
-                created_at: Timestamp::now()
-            }
-            .event_type(),
+            created(id).event_type(),
             "CycleCreated"
         );
 
@@ -234,7 +352,8 @@ mod tests {
         assert_eq!(
             CycleEvent::ComponentOutputUpdated {
                 cycle_id: id,
-                component_type: ComponentType::Objectives
+                component_type: ComponentType::Objectives,
+                output: serde_json::json!({})
             }
             .event_type(),
             "ComponentOutputUpdated"
@@ -248,10 +367,7 @@ mod tests {
     #[test]
     fn serializes_created_to_json() {
         let id = test_cycle_id();
-        let event = CycleEvent::Created {
-            cycle_id: id,
-            created_at: Timestamp::now(),
-        };
+        let event = created(id);
 
         let json = serde_json::to_string(&event).expect("serialization failed");
         assert!(json.contains("Created"));
@@ -275,12 +391,7 @@ mod tests {
     fn serializes_branched_to_json() {
         let id = test_cycle_id();
         let parent_id = test_cycle_id();
-        let event = CycleEvent::Branched {
-            cycle_id: id,
-            parent_cycle_id: parent_id,
-            branch_point: ComponentType::Alternatives,
-            created_at: Timestamp::now(),
-        };
+        let event = branched(id, parent_id, ComponentType::Alternatives);
 
         let json = serde_json::to_string(&event).expect("serialization failed");
         assert!(json.contains("Branched"));
@@ -295,10 +406,7 @@ mod tests {
     #[test]
     fn deserializes_created_from_json() {
         let id = test_cycle_id();
-        let original = CycleEvent::Created {
-            cycle_id: id,
-            created_at: Timestamp::now(),
-        };
+        let original = created(id);
 
         let json = serde_json::to_string(&original).expect("serialization failed");
         let deserialized: CycleEvent = serde_json::from_str(&json).expect("deserialization failed");
@@ -334,12 +442,7 @@ mod tests {
         let id = test_cycle_id();
         let parent_id = test_cycle_id();
 
-        let original = CycleEvent::Branched {
-            cycle_id: id,
-            parent_cycle_id: parent_id,
-            branch_point: ComponentType::Recommendation,
-            created_at: Timestamp::now(),
-        };
+        let original = branched(id, parent_id, ComponentType::Recommendation);
 
         let json = serde_json::to_string(&original).expect("serialization failed");
         let deserialized: CycleEvent = serde_json::from_str(&json).expect("deserialization failed");
@@ -381,4 +484,103 @@ mod tests {
             panic!("Expected ComponentMarkedForRevision event");
         }
     }
+
+    // ───────────────────────────────────────────────────────────────
+    // Storage tests
+    // ───────────────────────────────────────────────────────────────
+
+    #[test]
+    fn envelope_round_trip_preserves_event() {
+        let id = test_cycle_id();
+        let occurred_at = Timestamp::from_unix_secs(1_700_000_000);
+        let event = CycleEvent::ComponentOutputUpdated {
+            cycle_id: id,
+            component_type: ComponentType::Objectives,
+            output: serde_json::json!({ "fundamental_objectives": [] }),
+        };
+
+        let envelope = event.to_envelope(occurred_at).unwrap();
+        assert_eq!(envelope.event_type, "cycle.component_output_updated.v1");
+        assert_eq!(envelope.aggregate_id, id.to_string());
+        assert_eq!(envelope.occurred_at, occurred_at);
+
+        let restored = CycleEvent::from_envelope(envelope, &cycle_event_upcasters()).unwrap();
+        assert_eq!(restored, event);
+    }
+
+    #[test]
+    fn stored_types_are_versioned_under_cycle_namespace() {
+        let id = test_cycle_id();
+        let events = [
+            created(id),
+            branched(id, test_cycle_id(), ComponentType::Objectives),
+            CycleEvent::Completed { cycle_id: id },
+            CycleEvent::NavigatedTo {
+                cycle_id: id,
+                component_type: ComponentType::Objectives,
+            },
+        ];
+        for event in events {
+            assert!(event.stored_type().starts_with("cycle."));
+            assert!(event.stored_type().ends_with(".v1"));
+        }
+    }
+
+    struct AddEmptyOutput;
+
+    impl crate::domain::foundation::Upcaster for AddEmptyOutput {
+        fn source_type(&self) -> &str {
+            "cycle.component_output_updated.v1"
+        }
+
+        fn target_type(&self) -> &str {
+            "cycle.component_output_updated.v2"
+        }
+
+        fn upcast(&self, mut payload: serde_json::Value) -> Result<serde_json::Value, UpcastError> {
+            payload["ComponentOutputUpdated"]["output"] = serde_json::Value::Null;
+            Ok(payload)
+        }
+    }
+
+    #[test]
+    fn from_envelope_upcasts_older_payloads() {
+        let id = test_cycle_id();
+        let mut registry = cycle_event_upcasters();
+        registry.register(std::sync::Arc::new(AddEmptyOutput));
+        registry.set_current_version("cycle.component_output_updated", 2);
+
+        let envelope = EventEnvelope::new(
+            "cycle.component_output_updated.v1",
+            id.to_string(),
+            CYCLE_AGGREGATE_TYPE,
+            serde_json::json!({
+                "ComponentOutputUpdated": { "cycle_id": id, "component_type": "objectives" }
+            }),
+        );
+
+        let event = CycleEvent::from_envelope(envelope, &registry).unwrap();
+        assert_eq!(
+            event,
+            CycleEvent::ComponentOutputUpdated {
+                cycle_id: id,
+                component_type: ComponentType::Objectives,
+                output: serde_json::Value::Null,
+            }
+        );
+    }
+
+    #[test]
+    fn component_type_identifies_affected_component() {
+        let id = test_cycle_id();
+        assert_eq!(created(id).component_type(), None);
+        assert_eq!(
+            CycleEvent::ComponentStarted {
+                cycle_id: id,
+                component_type: ComponentType::Tradeoffs,
+            }
+            .component_type(),
+            Some(ComponentType::Tradeoffs)
+        );
+    }
 }
@@ -6,11 +6,15 @@
 mod aggregate;
 mod events;
 mod progress;
+mod snapshot;
 mod tree_view;
 
 pub use aggregate::Cycle;
-pub use events::CycleEvent;
+pub use events::{cycle_event_upcasters, CycleEvent, RecordedCycleEvent, CYCLE_AGGREGATE_TYPE};
 pub use progress::CycleProgress;
+pub use snapshot::{
+    crosses_snapshot_boundary, ComponentSnapshot, CycleSnapshot, SNAPSHOT_INTERVAL,
+};
 pub use tree_view::{
     BranchMetadata, CycleTreeNode, LetterStatus, PrOACTLetter, PrOACTStatus, PositionHint,
 };
@@ -41,7 +41,7 @@ impl CycleProgress {
 
     /// Returns the total number of required components (excluding optional NotesNextSteps).
     pub fn required_count(&self) -> usize {
-        8 // All components except NotesNextSteps are required
+        10 // All components except NotesNextSteps are required
     }
 
     /// Returns the completion percentage (0-100).
@@ -144,13 +144,14 @@ mod tests {
 
     #[test]
     fn percent_complete_calculates_correctly() {
-        // 2 of 8 required components complete = 25%
+        // 3 of 10 required components complete = 30%
         let progress = progress_with(vec![
             (ComponentType::IssueRaising, ComponentStatus::Complete),
             (ComponentType::ProblemFrame, ComponentStatus::Complete),
+            (ComponentType::StakeholderAnalysis, ComponentStatus::Complete),
             (ComponentType::Objectives, ComponentStatus::InProgress),
         ]);
-        assert_eq!(progress.percent_complete(), 25);
+        assert_eq!(progress.percent_complete(), 30);
     }
 
     #[test]
@@ -162,10 +163,11 @@ mod tests {
 
     #[test]
     fn percent_complete_half_done() {
-        // 4 of 8 required components complete = 50%
+        // 5 of 10 required components complete = 50%
         let progress = progress_with(vec![
             (ComponentType::IssueRaising, ComponentStatus::Complete),
             (ComponentType::ProblemFrame, ComponentStatus::Complete),
+            (ComponentType::StakeholderAnalysis, ComponentStatus::Complete),
             (ComponentType::Objectives, ComponentStatus::Complete),
             (ComponentType::Alternatives, ComponentStatus::Complete),
         ]);
@@ -211,11 +213,11 @@ mod tests {
         let progress = progress_with(vec![
             (ComponentType::IssueRaising, ComponentStatus::Complete),
             (ComponentType::ProblemFrame, ComponentStatus::Complete),
-            // Objectives is not complete, so it should be found
+            // StakeholderAnalysis is not complete, so it should be found
         ]);
         assert_eq!(
             progress.first_incomplete(),
-            Some(ComponentType::Objectives)
+            Some(ComponentType::StakeholderAnalysis)
         );
     }
 
@@ -243,12 +245,12 @@ mod tests {
         let progress = empty_progress();
         let statuses = progress.step_statuses();
 
-        // Should have all 9 components
-        assert_eq!(statuses.len(), 9);
+        // Should have all 11 components
+        assert_eq!(statuses.len(), 11);
 
         // Should be in sequence order
         assert_eq!(statuses[0].0, ComponentType::IssueRaising);
-        assert_eq!(statuses[8].0, ComponentType::NotesNextSteps);
+        assert_eq!(statuses[10].0, ComponentType::NotesNextSteps);
     }
 
     #[test]
@@ -341,8 +343,8 @@ mod tests {
     }
 
     #[test]
-    fn required_count_is_eight() {
+    fn required_count_is_ten() {
         let progress = empty_progress();
-        assert_eq!(progress.required_count(), 8);
+        assert_eq!(progress.required_count(), 10);
     }
 }
@@ -0,0 +1,110 @@
+//! Cycle snapshots - Serializable cycle state for event-sourced persistence.
+//!
+//! A snapshot captures a cycle at one version so replay can start there
+//! instead of at the cycle's first event. Components are kept in the same
+//! shape the component tables use.
+
+use serde::{Deserialize, Serialize};
+
+use crate::domain::foundation::{
+    ComponentId, ComponentStatus, ComponentType, CycleId, CycleStatus, DomainError, SessionId,
+    Timestamp,
+};
+use crate::domain::proact::ComponentVariant;
+
+use super::BranchMetadata;
+
+/// Number of events between snapshots of a cycle.
+pub const SNAPSHOT_INTERVAL: u64 = 50;
+
+/// Whether moving a cycle from `from_version` to `to_version` crosses a
+/// snapshot boundary.
+pub fn crosses_snapshot_boundary(from_version: u64, to_version: u64) -> bool {
+    to_version / SNAPSHOT_INTERVAL > from_version / SNAPSHOT_INTERVAL
+}
+
+/// One component's state inside a snapshot or event.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ComponentSnapshot {
+    pub id: ComponentId,
+    pub component_type: ComponentType,
+    pub status: ComponentStatus,
+    pub output: serde_json::Value,
+    pub created_at: Timestamp,
+    pub updated_at: Timestamp,
+}
+
+impl ComponentSnapshot {
+    /// Captures a component's current state.
+    pub fn of(component: &ComponentVariant) -> Self {
+        Self {
+            id: component.id(),
+            component_type: component.component_type(),
+            status: component.status(),
+            output: component.output_as_value(),
+            created_at: component.created_at(),
+            updated_at: component.updated_at(),
+        }
+    }
+
+    /// Rebuilds the component.
+    pub fn to_component(&self) -> Result<ComponentVariant, DomainError> {
+        ComponentVariant::reconstitute(
+            self.id,
+            self.component_type,
+            self.status,
+            self.output.clone(),
+            self.created_at,
+            self.updated_at,
+        )
+    }
+}
+
+/// A cycle's full state at one version.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CycleSnapshot {
+    pub cycle_id: CycleId,
+    pub session_id: SessionId,
+    pub parent_cycle_id: Option<CycleId>,
+    pub branch_point: Option<ComponentType>,
+    pub branch_metadata: BranchMetadata,
+    pub status: CycleStatus,
+    pub current_step: ComponentType,
+    pub components: Vec<ComponentSnapshot>,
+    pub created_at: Timestamp,
+    pub updated_at: Timestamp,
+    /// Version of the last event folded into this snapshot.
+    pub version: u64,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn snapshot_boundary_is_crossed_once_per_interval() {
+        assert!(!crosses_snapshot_boundary(0, SNAPSHOT_INTERVAL - 1));
+        assert!(crosses_snapshot_boundary(0, SNAPSHOT_INTERVAL));
+        assert!(crosses_snapshot_boundary(
+            SNAPSHOT_INTERVAL - 1,
+            SNAPSHOT_INTERVAL + 1
+        ));
+        assert!(!crosses_snapshot_boundary(
+            SNAPSHOT_INTERVAL,
+            2 * SNAPSHOT_INTERVAL - 1
+        ));
+    }
+
+    #[test]
+    fn component_snapshot_round_trips() {
+        let mut component = ComponentVariant::new(ComponentType::Objectives);
+        component.start().unwrap();
+
+        let snapshot = ComponentSnapshot::of(&component);
+        let rebuilt = snapshot.to_component().unwrap();
+
+        assert_eq!(rebuilt.id(), component.id());
+        assert_eq!(rebuilt.status(), ComponentStatus::InProgress);
+        assert_eq!(ComponentSnapshot::of(&rebuilt), snapshot);
+    }
+}
@@ -4,6 +4,7 @@
 
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
 
 use crate::domain::foundation::{ComponentType, CycleId};
 
@@ -22,7 +23,7 @@ use crate::domain::foundation::{ComponentType, CycleId};
 /// - **A**: Analysis/Consequences
 /// - **C**: Clear Tradeoffs
 /// - **T**: Think Through (Recommendation + Decision Quality)
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
 pub enum PrOACTLetter {
     /// Problem Frame
     P,
@@ -77,7 +78,7 @@ impl PrOACTLetter {
 /// Status of a single letter in the PrOACT visualization.
 ///
 /// Aggregates the status of one or more underlying components.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
 #[serde(rename_all = "snake_case")]
 pub enum LetterStatus {
     /// Component(s) not yet started
@@ -101,7 +102,7 @@ pub enum LetterStatus {
 /// P  r  O  A  C  T
 /// ●  ●  ◉  ○  ○  ○
 /// ```
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
 pub struct PrOACTStatus {
     /// P - Problem Frame
     pub p: LetterStatus,
@@ -150,7 +151,8 @@ impl PrOACTStatus {
 /// A node in the cycle tree visualization.
 ///
 /// Represents a single cycle with its PrOACT status and child branches.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[schema(as = PrOACTTreeNode)]
 pub struct CycleTreeNode {
     /// Unique identifier for this cycle
     pub cycle_id: CycleId,
@@ -161,6 +163,7 @@ pub struct CycleTreeNode {
     /// Status of all six PrOACT letters
     pub letter_statuses: PrOACTStatus,
     /// Child cycles branched from this one
+    #[schema(no_recursion)]
     pub children: Vec<CycleTreeNode>,
     /// When this cycle was last updated
     pub updated_at: DateTime<Utc>,
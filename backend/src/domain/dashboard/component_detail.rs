@@ -1,9 +1,13 @@
 use chrono::{DateTime, Utc};
 use serde::Serialize;
+use utoipa::ToSchema;
 use crate::domain::foundation::{ComponentId, ComponentStatus, ComponentType, CycleId};
+use crate::domain::proact::{
+    EngagementStrategy, Stakeholder, StakeholderAnalysisOutput, UncertaintyRegisterOutput,
+};
 
 /// Detailed view of a single component
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Serialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct ComponentDetailView {
     pub component_id: ComponentId,
@@ -25,6 +29,165 @@ pub struct ComponentDetailView {
     /// Navigation context
     pub previous_component: Option<ComponentType>,
     pub next_component: Option<ComponentType>,
+
+    /// AI spend for this component and its cycle (when usage tracking is enabled)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ai_cost: Option<AiCostSummary>,
+
+    /// Power/interest grid (Stakeholder Analysis only)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stakeholder_grid: Option<StakeholderGrid>,
+
+    /// Uncertainties ranked by exposure (Uncertainty Register only)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub risk_register: Option<RiskRegisterView>,
+}
+
+/// AI cost and latency rollup shown on the component detail view
+#[derive(Debug, Clone, PartialEq, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct AiCostSummary {
+    /// Spend on this component
+    pub component: AiCostLine,
+
+    /// Spend across the whole cycle
+    pub cycle: AiCostLine,
+
+    /// Spend per component in the cycle, in PrOACT order
+    pub by_component: Vec<(ComponentType, AiCostLine)>,
+}
+
+/// Cost, token, and latency totals for a group of AI requests
+#[derive(Debug, Clone, Default, PartialEq, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct AiCostLine {
+    /// Cost in cents (fractional; requests often cost less than a cent)
+    pub cost_cents: f64,
+    pub tokens: u64,
+    pub requests: u32,
+    pub average_latency_ms: Option<u64>,
+}
+
+/// Stakeholders grouped by power/interest quadrant
+#[derive(Debug, Clone, Default, PartialEq, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct StakeholderGrid {
+    /// High power, high interest
+    pub manage_closely: Vec<StakeholderCard>,
+    /// High power, low interest
+    pub keep_satisfied: Vec<StakeholderCard>,
+    /// Low power, high interest
+    pub keep_informed: Vec<StakeholderCard>,
+    /// Low power, low interest
+    pub monitor: Vec<StakeholderCard>,
+    /// Stakeholders with no engagement plan yet
+    pub unplanned_count: usize,
+}
+
+/// A stakeholder as shown in a grid quadrant
+#[derive(Debug, Clone, PartialEq, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct StakeholderCard {
+    pub id: String,
+    pub name: String,
+    pub role: String,
+    pub concern_count: usize,
+    /// Whether the engagement plan covers this stakeholder
+    pub has_plan: bool,
+}
+
+impl StakeholderGrid {
+    /// Builds the grid for a Stakeholder Analysis component's output.
+    ///
+    /// Returns `None` for other component types, or when the output
+    /// doesn't parse.
+    pub fn for_component(component_type: ComponentType, output: &serde_json::Value) -> Option<Self> {
+        if component_type != ComponentType::StakeholderAnalysis {
+            return None;
+        }
+        let output: StakeholderAnalysisOutput = serde_json::from_value(output.clone()).ok()?;
+        Some(Self::from_output(&output))
+    }
+
+    /// Groups the output's stakeholders by the quadrant they fall in.
+    pub fn from_output(output: &StakeholderAnalysisOutput) -> Self {
+        let unplanned: Vec<&str> = output.unplanned().iter().map(|s| s.id.as_str()).collect();
+        let card = |s: &Stakeholder| StakeholderCard {
+            id: s.id.clone(),
+            name: s.name.clone(),
+            role: s.role.clone(),
+            concern_count: s.concerns.len(),
+            has_plan: !unplanned.contains(&s.id.as_str()),
+        };
+        let quadrant = |strategy| output.in_quadrant(strategy).into_iter().map(card).collect();
+
+        Self {
+            manage_closely: quadrant(EngagementStrategy::ManageClosely),
+            keep_satisfied: quadrant(EngagementStrategy::KeepSatisfied),
+            keep_informed: quadrant(EngagementStrategy::KeepInformed),
+            monitor: quadrant(EngagementStrategy::Monitor),
+            unplanned_count: unplanned.len(),
+        }
+    }
+}
+
+/// Registered uncertainties, highest exposure first
+#[derive(Debug, Clone, Default, PartialEq, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct RiskRegisterView {
+    pub entries: Vec<RiskEntry>,
+    /// High or critical impact uncertainties with no mitigation option
+    pub unmitigated_count: usize,
+}
+
+/// A registered uncertainty as shown in the risk register
+#[derive(Debug, Clone, PartialEq, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct RiskEntry {
+    pub id: String,
+    pub description: String,
+    pub probability: Option<f64>,
+    /// low, medium, high or critical
+    pub impact: String,
+    /// Probability weighted by impact
+    pub exposure: Option<f64>,
+    pub mitigation_count: usize,
+    /// Consequence table uncertainty this entry expands on
+    pub consequence_uncertainty_id: Option<String>,
+}
+
+impl RiskRegisterView {
+    /// Builds the register for an Uncertainty Register component's output.
+    ///
+    /// Returns `None` for other component types, or when the output
+    /// doesn't parse.
+    pub fn for_component(component_type: ComponentType, output: &serde_json::Value) -> Option<Self> {
+        if component_type != ComponentType::UncertaintyRegister {
+            return None;
+        }
+        let output: UncertaintyRegisterOutput = serde_json::from_value(output.clone()).ok()?;
+        Some(Self::from_output(&output))
+    }
+
+    /// Ranks the output's uncertainties by exposure.
+    pub fn from_output(output: &UncertaintyRegisterOutput) -> Self {
+        Self {
+            entries: output
+                .ranked()
+                .into_iter()
+                .map(|u| RiskEntry {
+                    id: u.id.clone(),
+                    description: u.description.clone(),
+                    probability: u.probability,
+                    impact: u.impact.as_str().to_string(),
+                    exposure: u.exposure(),
+                    mitigation_count: u.mitigations.len(),
+                    consequence_uncertainty_id: u.consequence_uncertainty_id.clone(),
+                })
+                .collect(),
+            unmitigated_count: output.unmitigated().len(),
+        }
+    }
 }
 
 impl ComponentDetailView {
@@ -1,7 +1,9 @@
 #[cfg(test)]
 mod tests {
     use crate::domain::foundation::{ComponentId, ComponentStatus, ComponentType, CycleId};
-    use crate::domain::dashboard::component_detail::ComponentDetailView;
+    use crate::domain::dashboard::component_detail::{
+        ComponentDetailView, RiskRegisterView, StakeholderGrid,
+    };
     use serde_json::json;
 
     fn create_test_component_detail() -> ComponentDetailView {
@@ -21,6 +23,9 @@ mod tests {
             can_revise: true,
             previous_component: Some(ComponentType::ProblemFrame),
             next_component: Some(ComponentType::Alternatives),
+            ai_cost: None,
+            stakeholder_grid: None,
+            risk_register: None,
         }
     }
 
@@ -80,4 +85,54 @@ mod tests {
         let detail = create_test_component_detail();
         assert!(detail.can_revise);
     }
+
+    #[test]
+    fn test_stakeholder_grid_groups_by_quadrant() {
+        let output = json!({
+            "stakeholders": [
+                {"id": "spouse", "name": "Sam", "role": "partner", "power": "high", "interest": "high"},
+                {"id": "kids", "name": "Kids", "role": "family", "power": "low", "interest": "high",
+                 "concerns": ["New school"]}
+            ],
+            "engagement_plan": [
+                {"stakeholder_id": "spouse", "strategy": "manage_closely"}
+            ]
+        });
+
+        let grid = StakeholderGrid::for_component(ComponentType::StakeholderAnalysis, &output).unwrap();
+
+        assert_eq!(grid.manage_closely.len(), 1);
+        assert!(grid.manage_closely[0].has_plan);
+        assert_eq!(grid.keep_informed[0].concern_count, 1);
+        assert!(grid.keep_satisfied.is_empty());
+        assert_eq!(grid.unplanned_count, 1);
+    }
+
+    #[test]
+    fn test_stakeholder_grid_only_for_stakeholder_analysis() {
+        let grid = StakeholderGrid::for_component(ComponentType::Objectives, &json!({}));
+        assert!(grid.is_none());
+    }
+
+    #[test]
+    fn test_risk_register_ranks_by_exposure() {
+        let output = json!({
+            "uncertainties": [
+                {"id": "weather", "description": "Rainy summer", "probability": 0.5, "impact": "low"},
+                {"id": "job", "description": "Role is cut", "probability": 0.2, "impact": "critical",
+                 "consequence_uncertainty_id": "u1"},
+                {"id": "rates", "description": "Rates rise", "impact": "high",
+                 "mitigations": [{"description": "Fix the rate", "kind": "hedge"}]}
+            ]
+        });
+
+        let register =
+            RiskRegisterView::for_component(ComponentType::UncertaintyRegister, &output).unwrap();
+
+        let ids: Vec<_> = register.entries.iter().map(|e| e.id.as_str()).collect();
+        assert_eq!(ids, vec!["job", "weather", "rates"]);
+        assert_eq!(register.entries[0].impact, "critical");
+        assert_eq!(register.entries[2].mitigation_count, 1);
+        assert_eq!(register.unmitigated_count, 1);
+    }
 }
@@ -1,8 +1,9 @@
 use serde::Serialize;
+use utoipa::ToSchema;
 use crate::domain::foundation::{ComponentType, CycleId};
 
 /// Comparison view for multiple cycles
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Serialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct CycleComparison {
     pub cycles: Vec<CycleComparisonItem>,
@@ -10,7 +11,7 @@ pub struct CycleComparison {
     pub summary: ComparisonSummary,
 }
 
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Serialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct CycleComparisonItem {
     pub cycle_id: CycleId,
@@ -22,7 +23,7 @@ pub struct CycleComparisonItem {
 }
 
 /// Simplified progress snapshot for comparison view
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Serialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct CycleProgressSnapshot {
     pub completed_count: usize,
@@ -31,7 +32,7 @@ pub struct CycleProgressSnapshot {
     pub current_step: Option<ComponentType>,
 }
 
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Serialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct ComponentComparisonSummary {
     pub component_type: ComponentType,
@@ -41,7 +42,7 @@ pub struct ComponentComparisonSummary {
     pub differs_from_others: bool,
 }
 
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Serialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct ComparisonDifference {
     pub component_type: ComponentType,
@@ -50,7 +51,7 @@ pub struct ComparisonDifference {
     pub significance: DifferenceSignificance,
 }
 
-#[derive(Debug, Clone, Copy, Serialize, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, Serialize, PartialEq, Eq, ToSchema)]
 #[serde(rename_all = "lowercase")]
 pub enum DifferenceSignificance {
     Minor,
@@ -58,7 +59,7 @@ pub enum DifferenceSignificance {
     Major,
 }
 
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Serialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct ComparisonSummary {
     pub total_cycles: usize,
@@ -0,0 +1,101 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use crate::domain::decision_tree::{DecisionTree, NodeKind};
+use crate::domain::foundation::{CycleId, DecisionTreeId};
+
+/// A decision tree laid out for rendering, with rollback results
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct DecisionTreeView {
+    pub id: DecisionTreeId,
+    pub cycle_id: CycleId,
+    pub name: String,
+    pub payoff_unit: Option<String>,
+    /// Root's expected value (None until the tree can be rolled back)
+    pub expected_value: Option<f64>,
+    /// Why the tree cannot be rolled back yet, if it cannot
+    pub validation_issue: Option<String>,
+    /// Nodes in depth-first order, root first
+    pub nodes: Vec<DecisionTreeNodeView>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct DecisionTreeNodeView {
+    pub id: String,
+    pub label: String,
+    /// decision, chance, or terminal
+    pub kind: String,
+    /// Distance from the root (root = 0)
+    pub depth: usize,
+    pub parent_id: Option<String>,
+    /// Option or outcome leading to this node
+    pub branch_label: Option<String>,
+    /// Probability of the outcome leading to this node
+    pub probability: Option<f64>,
+    /// Payoff of terminal nodes
+    pub payoff: Option<f64>,
+    pub expected_value: Option<f64>,
+    /// Whether the optimal strategy reaches this node
+    pub on_optimal_path: bool,
+}
+
+impl DecisionTreeView {
+    /// Lays out the tree and annotates it with rollback results when the
+    /// tree is complete. Nodes not reachable from the root are omitted.
+    pub fn build(tree: &DecisionTree) -> Self {
+        let (rollback, validation_issue) = match tree.rollback() {
+            Ok(rollback) => (Some(rollback), None),
+            Err(e) => (None, Some(e.to_string())),
+        };
+        let optimal = rollback
+            .as_ref()
+            .map(|r| r.optimal_nodes(tree))
+            .unwrap_or_default();
+
+        let mut nodes = Vec::new();
+        let mut stack: Vec<(String, usize)> =
+            tree.root_id.iter().map(|id| (id.clone(), 0)).collect();
+        while let Some((id, depth)) = stack.pop() {
+            let Some(node) = tree.node(&id) else {
+                continue;
+            };
+            let parent = tree.parent_of(&id);
+            nodes.push(DecisionTreeNodeView {
+                id: node.id.clone(),
+                label: node.label.clone(),
+                kind: node.kind.as_str().to_string(),
+                depth,
+                parent_id: parent.map(|(p, _)| p.id.clone()),
+                branch_label: parent.map(|(_, b)| b.label.clone()),
+                probability: parent.and_then(|(_, b)| b.probability),
+                payoff: match node.kind {
+                    NodeKind::Terminal { payoff } => Some(payoff),
+                    _ => None,
+                },
+                expected_value: rollback.as_ref().and_then(|r| r.value(&node.id)),
+                on_optimal_path: optimal.contains(&node.id),
+            });
+            stack.extend(
+                node.branches
+                    .iter()
+                    .rev()
+                    .map(|b| (b.child_id.clone(), depth + 1)),
+            );
+        }
+
+        Self {
+            id: tree.id,
+            cycle_id: tree.cycle_id,
+            name: tree.name.clone(),
+            payoff_unit: tree.payoff_unit.clone(),
+            expected_value: rollback.as_ref().map(|r| r.expected_value),
+            validation_issue,
+            nodes,
+        }
+    }
+}
+
+#[cfg(test)]
+#[path = "decision_tree_view_test.rs"]
+mod decision_tree_view_test;
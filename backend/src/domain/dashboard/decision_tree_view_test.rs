@@ -0,0 +1,75 @@
+#[cfg(test)]
+mod tests {
+    use crate::domain::dashboard::decision_tree_view::*;
+    use crate::domain::decision_tree::{DecisionTree, NodeKind};
+    use crate::domain::foundation::{CycleId, UserId};
+
+    fn tree() -> DecisionTree {
+        let mut tree = DecisionTree::new(
+            CycleId::new(),
+            UserId::new("user-1").unwrap(),
+            "Launch plan",
+            Some("USD".to_string()),
+        )
+        .unwrap();
+        let root = tree.add_root("How to launch?", NodeKind::Decision).unwrap();
+        let demand = tree
+            .add_branch(&root, "Launch now", None, "Demand", NodeKind::Chance)
+            .unwrap();
+        tree.add_branch(&demand, "High", Some(0.5), "Win", NodeKind::Terminal { payoff: 100.0 })
+            .unwrap();
+        tree.add_branch(&demand, "Low", Some(0.5), "Loss", NodeKind::Terminal { payoff: -20.0 })
+            .unwrap();
+        tree.add_branch(&root, "Wait", None, "Nothing", NodeKind::Terminal { payoff: 0.0 })
+            .unwrap();
+        tree
+    }
+
+    #[test]
+    fn nodes_are_laid_out_depth_first() {
+        let view = DecisionTreeView::build(&tree());
+
+        let ids: Vec<&str> = view.nodes.iter().map(|n| n.id.as_str()).collect();
+        assert_eq!(ids, ["n1", "n2", "n3", "n4", "n5"]);
+        let depths: Vec<usize> = view.nodes.iter().map(|n| n.depth).collect();
+        assert_eq!(depths, [0, 1, 2, 2, 1]);
+        assert_eq!(view.nodes[2].branch_label.as_deref(), Some("High"));
+        assert_eq!(view.nodes[2].probability, Some(0.5));
+        assert_eq!(view.nodes[2].payoff, Some(100.0));
+    }
+
+    #[test]
+    fn complete_tree_shows_rollback() {
+        let view = DecisionTreeView::build(&tree());
+
+        assert_eq!(view.expected_value, Some(40.0));
+        assert!(view.validation_issue.is_none());
+        let optimal: Vec<&str> = view
+            .nodes
+            .iter()
+            .filter(|n| n.on_optimal_path)
+            .map(|n| n.id.as_str())
+            .collect();
+        assert_eq!(optimal, ["n1", "n2", "n3", "n4"]);
+    }
+
+    #[test]
+    fn incomplete_tree_reports_issue() {
+        let mut tree = tree();
+        tree.set_probability("n4", 0.2).unwrap();
+
+        let view = DecisionTreeView::build(&tree);
+
+        assert!(view.expected_value.is_none());
+        assert!(view.validation_issue.unwrap().contains("sum to"));
+        assert!(view.nodes.iter().all(|n| n.expected_value.is_none() && !n.on_optimal_path));
+    }
+
+    #[test]
+    fn view_serializes_camel_case() {
+        let json = serde_json::to_value(DecisionTreeView::build(&tree())).unwrap();
+
+        assert!(json.get("expectedValue").is_some());
+        assert!(json["nodes"][0].get("onOptimalPath").is_some());
+    }
+}
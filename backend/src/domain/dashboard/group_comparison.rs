@@ -0,0 +1,168 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use crate::domain::analysis::GroupAggregator;
+use crate::domain::foundation::CycleId;
+use crate::domain::proact::{
+    AlternativesOutput, ConsequencesOutput, ObjectivesOutput, ParticipantPreferences,
+};
+
+/// Group view of a multi-stakeholder cycle's preferences, with dissent
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct GroupComparisonView {
+    pub cycle_id: CycleId,
+    /// Participants who recorded preferences
+    pub participant_count: usize,
+    /// Score (0-100) at or above which a participant approves an alternative
+    pub approval_threshold: f64,
+    /// Alternatives in table order
+    pub alternatives: Vec<GroupAlternativeRow>,
+    /// Objectives in table order, with averaged weights
+    pub objectives: Vec<GroupObjectiveRow>,
+    /// Winner under each method (None if tied or empty)
+    pub borda_winner_id: Option<String>,
+    pub approval_winner_id: Option<String>,
+    pub weighted_winner_id: Option<String>,
+    pub dissent: GroupDissentView,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct GroupAlternativeRow {
+    pub id: String,
+    pub name: String,
+    pub borda_points: f64,
+    pub approvals: usize,
+    /// Mean participant score (None if nobody scored it)
+    pub mean_score: Option<f64>,
+    /// Standard deviation of participant scores
+    pub score_spread: f64,
+    /// Score out of 100 using the averaged weights
+    pub weighted_score: f64,
+    /// Whether participants' scores are far apart
+    pub contested: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct GroupObjectiveRow {
+    pub id: String,
+    pub name: String,
+    /// Mean normalized weight (weights sum to 1)
+    pub mean_weight: f64,
+    /// Standard deviation of participants' normalized weights
+    pub spread: f64,
+    pub min_weight: f64,
+    pub max_weight: f64,
+    /// Whether participants weight it very differently
+    pub contested: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct GroupDissentView {
+    /// Participants who scored at least one alternative
+    pub participants: Vec<ParticipantDissentRow>,
+    /// Agreement with the Borda ranking: 1 = everyone agrees (None if no scores)
+    pub consensus: Option<f64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct ParticipantDissentRow {
+    pub participant_id: String,
+    pub name: String,
+    /// Their highest-scored alternative (None if tied)
+    pub top_choice_id: Option<String>,
+    /// Whether their top choice is the Borda winner
+    pub agrees_with_group: bool,
+    /// Distance from the Borda ranking: 0 = identical, 1 = reversed
+    pub rank_distance: f64,
+}
+
+impl GroupComparisonView {
+    /// Aggregates participant preferences over the consequences table and
+    /// labels the result with objective and alternative names. IDs stand in
+    /// for names that cannot be found.
+    pub fn build(
+        cycle_id: CycleId,
+        participants: &[ParticipantPreferences],
+        objectives: &ObjectivesOutput,
+        alternatives: Option<&AlternativesOutput>,
+        consequences: &ConsequencesOutput,
+        approval_threshold: f64,
+    ) -> Self {
+        let group =
+            GroupAggregator::aggregate(participants, objectives, consequences, approval_threshold);
+
+        let objective_name = |id: &str| {
+            objectives
+                .fundamental_objectives
+                .iter()
+                .find(|o| o.id == id)
+                .map(|o| o.description.clone())
+                .unwrap_or_else(|| id.to_string())
+        };
+        let alternative_name = |id: &str| {
+            alternatives
+                .and_then(|a| a.options.iter().find(|o| o.id == id))
+                .map(|o| o.name.clone())
+                .unwrap_or_else(|| id.to_string())
+        };
+        let dissent = &group.dissent;
+
+        Self {
+            cycle_id,
+            participant_count: group.participant_count,
+            approval_threshold,
+            alternatives: group
+                .alternatives
+                .iter()
+                .map(|a| GroupAlternativeRow {
+                    id: a.alternative_id.clone(),
+                    name: alternative_name(&a.alternative_id),
+                    borda_points: a.borda_points,
+                    approvals: a.approvals,
+                    mean_score: a.mean_score,
+                    score_spread: a.score_spread,
+                    weighted_score: a.weighted_score,
+                    contested: dissent.contested_alternatives.contains(&a.alternative_id),
+                })
+                .collect(),
+            objectives: group
+                .objectives
+                .iter()
+                .map(|o| GroupObjectiveRow {
+                    id: o.objective_id.clone(),
+                    name: objective_name(&o.objective_id),
+                    mean_weight: o.mean_weight,
+                    spread: o.spread,
+                    min_weight: o.min_weight,
+                    max_weight: o.max_weight,
+                    contested: dissent.contested_objectives.contains(&o.objective_id),
+                })
+                .collect(),
+            borda_winner_id: group.borda_winner.clone(),
+            approval_winner_id: group.approval_winner.clone(),
+            weighted_winner_id: group.weighted_winner.clone(),
+            dissent: GroupDissentView {
+                participants: dissent
+                    .participants
+                    .iter()
+                    .map(|p| ParticipantDissentRow {
+                        participant_id: p.participant_id.clone(),
+                        name: p.name.clone(),
+                        top_choice_id: p.top_choice.clone(),
+                        agrees_with_group: p.agrees_with_group,
+                        rank_distance: p.rank_distance,
+                    })
+                    .collect(),
+                consensus: dissent.consensus,
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+#[path = "group_comparison_test.rs"]
+mod group_comparison_test;
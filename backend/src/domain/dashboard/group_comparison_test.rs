@@ -0,0 +1,150 @@
+#[cfg(test)]
+mod tests {
+    use crate::domain::dashboard::group_comparison::*;
+    use crate::domain::foundation::{CycleId, Rating};
+    use crate::domain::proact::{
+        Alternative, AlternativesOutput, Cell, ConsequencesOutput, ObjectivesOutput,
+        ParticipantPreferences,
+    };
+
+    fn alternatives() -> AlternativesOutput {
+        let alternative = |id: &str, name: &str| Alternative {
+            id: id.to_string(),
+            name: name.to_string(),
+            description: String::new(),
+            assumptions: vec![],
+            is_status_quo: false,
+        };
+        AlternativesOutput {
+            options: vec![alternative("a1", "Startup"), alternative("a2", "Bank")],
+            strategy_table: None,
+            has_status_quo: false,
+        }
+    }
+
+    fn consequences() -> ConsequencesOutput {
+        let mut output = ConsequencesOutput::default();
+        output.table.alternative_ids = vec!["a1".to_string(), "a2".to_string()];
+        output.table.objective_ids = vec!["o1".to_string(), "o2".to_string()];
+        for (alt, obj, rating) in [
+            ("a1", "o1", Rating::MuchBetter),
+            ("a1", "o2", Rating::MuchWorse),
+            ("a2", "o1", Rating::Same),
+            ("a2", "o2", Rating::Better),
+        ] {
+            output
+                .table
+                .cells
+                .entry(alt.to_string())
+                .or_default()
+                .insert(obj.to_string(), Cell::new(rating, ""));
+        }
+        output
+    }
+
+    fn participant(id: &str, startup: f64, bank: f64, pay_weight: f64) -> ParticipantPreferences {
+        ParticipantPreferences {
+            participant_id: id.to_string(),
+            name: id.to_uppercase(),
+            alternative_scores: [("a1".to_string(), startup), ("a2".to_string(), bank)].into(),
+            objective_weights: [
+                ("o1".to_string(), pay_weight),
+                ("o2".to_string(), 1.0 - pay_weight),
+            ]
+            .into(),
+        }
+    }
+
+    #[test]
+    fn test_rows_follow_table_order_with_names() {
+        let participants = vec![
+            participant("p1", 90.0, 40.0, 0.9),
+            participant("p2", 20.0, 80.0, 0.1),
+            participant("p3", 30.0, 70.0, 0.5),
+        ];
+
+        let view = GroupComparisonView::build(
+            CycleId::new(),
+            &participants,
+            &ObjectivesOutput::default(),
+            Some(&alternatives()),
+            &consequences(),
+            60.0,
+        );
+
+        assert_eq!(view.participant_count, 3);
+        assert_eq!(view.alternatives[0].name, "Startup");
+        assert_eq!(view.alternatives[1].name, "Bank");
+        assert_eq!(view.alternatives[1].approvals, 2);
+        assert_eq!(view.borda_winner_id.as_deref(), Some("a2"));
+        assert!(view.alternatives[0].contested);
+        assert!(view.objectives.iter().all(|o| o.contested));
+    }
+
+    #[test]
+    fn test_dissent_names_participants_who_disagree() {
+        let participants = vec![
+            participant("p1", 90.0, 40.0, 0.5),
+            participant("p2", 20.0, 80.0, 0.5),
+            participant("p3", 10.0, 60.0, 0.5),
+        ];
+
+        let view = GroupComparisonView::build(
+            CycleId::new(),
+            &participants,
+            &ObjectivesOutput::default(),
+            None,
+            &consequences(),
+            60.0,
+        );
+
+        let dissenters: Vec<&str> = view
+            .dissent
+            .participants
+            .iter()
+            .filter(|p| !p.agrees_with_group)
+            .map(|p| p.name.as_str())
+            .collect();
+        assert_eq!(dissenters, vec!["P1"]);
+        assert_eq!(
+            view.dissent.participants[0].top_choice_id.as_deref(),
+            Some("a1")
+        );
+        assert_eq!(view.dissent.participants[0].rank_distance, 1.0);
+    }
+
+    #[test]
+    fn test_missing_names_fall_back_to_ids() {
+        let view = GroupComparisonView::build(
+            CycleId::new(),
+            &[],
+            &ObjectivesOutput::default(),
+            None,
+            &consequences(),
+            60.0,
+        );
+
+        assert_eq!(view.alternatives[0].name, "a1");
+        assert_eq!(view.objectives[0].name, "o1");
+        assert_eq!(view.dissent.consensus, None);
+    }
+
+    #[test]
+    fn test_serializes_to_camel_case() {
+        let view = GroupComparisonView::build(
+            CycleId::new(),
+            &[participant("p1", 90.0, 40.0, 0.5)],
+            &ObjectivesOutput::default(),
+            None,
+            &consequences(),
+            60.0,
+        );
+
+        let json = serde_json::to_value(&view).unwrap();
+        assert!(json.get("bordaWinnerId").is_some());
+        assert!(json["alternatives"][0].get("weightedScore").is_some());
+        assert!(json["dissent"]["participants"][0]
+            .get("agreesWithGroup")
+            .is_some());
+    }
+}
@@ -1,13 +1,27 @@
 pub mod component_detail;
 pub mod cycle_comparison;
+pub mod decision_tree_view;
+pub mod group_comparison;
 pub mod overview;
+pub mod weighted_comparison;
 
-pub use component_detail::ComponentDetailView;
+pub use component_detail::{
+    AiCostLine, AiCostSummary, ComponentDetailView, RiskEntry, RiskRegisterView, StakeholderCard,
+    StakeholderGrid,
+};
 pub use cycle_comparison::{
     ComparisonDifference, ComparisonSummary, ComponentComparisonSummary, CycleComparison,
     CycleComparisonItem, CycleProgressSnapshot, DifferenceSignificance,
 };
+pub use decision_tree_view::{DecisionTreeNodeView, DecisionTreeView};
+pub use group_comparison::{
+    GroupAlternativeRow, GroupComparisonView, GroupDissentView, GroupObjectiveRow,
+    ParticipantDissentRow,
+};
 pub use overview::{
     AlternativeSummary, CellColor, CellSummary, CompactConsequencesTable, DashboardOverview,
-    ObjectiveSummary, RecommendationSummary,
+    ObjectiveSummary, RecommendationSummary, StakeholderSummary, UncertaintySummary,
+};
+pub use weighted_comparison::{
+    ContributionCell, WeightedAlternativeRow, WeightedComparisonView, WeightedObjectiveColumn,
 };
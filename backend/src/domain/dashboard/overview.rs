@@ -1,9 +1,11 @@
 use chrono::{DateTime, Utc};
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
 use crate::domain::foundation::{CycleId, Percentage, SessionId};
+use crate::domain::proact::{RegisteredUncertainty, Stakeholder};
 
 /// The main dashboard overview - aggregates all component data
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct DashboardOverview {
     /// Session information
@@ -13,6 +15,10 @@ pub struct DashboardOverview {
     /// From ProblemFrame component
     pub decision_statement: Option<String>,
 
+    /// From StakeholderAnalysis component
+    #[serde(default)]
+    pub stakeholders: Vec<StakeholderSummary>,
+
     /// Summary of objectives
     pub objectives: Vec<ObjectiveSummary>,
 
@@ -22,6 +28,10 @@ pub struct DashboardOverview {
     /// Compact consequences table
     pub consequences_table: Option<CompactConsequencesTable>,
 
+    /// Highest-exposure uncertainties from UncertaintyRegister component
+    #[serde(default)]
+    pub key_uncertainties: Vec<UncertaintySummary>,
+
     /// Recommendation summary
     pub recommendation: Option<RecommendationSummary>,
 
@@ -36,7 +46,53 @@ pub struct DashboardOverview {
     pub last_updated: DateTime<Utc>,
 }
 
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct StakeholderSummary {
+    pub id: String,
+    pub name: String,
+    pub role: String,
+    /// Engagement approach for their power/interest quadrant
+    pub engagement: String,
+    pub concerns: Vec<String>,
+}
+
+impl From<&Stakeholder> for StakeholderSummary {
+    fn from(stakeholder: &Stakeholder) -> Self {
+        Self {
+            id: stakeholder.id.clone(),
+            name: stakeholder.name.clone(),
+            role: stakeholder.role.clone(),
+            engagement: stakeholder.suggested_strategy().display_name().to_string(),
+            concerns: stakeholder.concerns.clone(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct UncertaintySummary {
+    pub id: String,
+    pub description: String,
+    pub probability: Option<f64>,
+    /// low, medium, high or critical
+    pub impact: String,
+    pub mitigated: bool,
+}
+
+impl From<&RegisteredUncertainty> for UncertaintySummary {
+    fn from(uncertainty: &RegisteredUncertainty) -> Self {
+        Self {
+            id: uncertainty.id.clone(),
+            description: uncertainty.description.clone(),
+            probability: uncertainty.probability,
+            impact: uncertainty.impact.as_str().to_string(),
+            mitigated: !uncertainty.mitigations.is_empty(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct ObjectiveSummary {
     pub id: String,
@@ -46,7 +102,7 @@ pub struct ObjectiveSummary {
     pub measure: Option<String>,
 }
 
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct AlternativeSummary {
     pub id: String,
@@ -60,7 +116,7 @@ pub struct AlternativeSummary {
     pub is_dominated: bool,
 }
 
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct CompactConsequencesTable {
     /// Column headers (alternative names)
@@ -71,7 +127,7 @@ pub struct CompactConsequencesTable {
     pub cells: Vec<Vec<CellSummary>>,
 }
 
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct CellSummary {
     pub rating: i8,
@@ -81,7 +137,7 @@ pub struct CellSummary {
 }
 
 /// Cell color based on rating
-#[derive(Debug, Clone, Copy, Serialize)]
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, ToSchema)]
 #[serde(rename_all = "lowercase")]
 pub enum CellColor {
     Red,    // -2, -1
@@ -100,7 +156,7 @@ impl From<i8> for CellColor {
     }
 }
 
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct RecommendationSummary {
     /// Whether there's a standout option
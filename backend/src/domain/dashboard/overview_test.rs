@@ -9,11 +9,13 @@ mod tests {
             session_id: SessionId::new(),
             session_title: "My Decision".to_string(),
             decision_statement: Some("Should we expand to new markets?".to_string()),
+            stakeholders: vec![],
             cycle_count: 3,
             active_cycle_id: Some(CycleId::new()),
             objectives: vec![],
             alternatives: vec![],
             consequences_table: None,
+            key_uncertainties: vec![],
             recommendation: None,
             dq_score: None,
             last_updated: chrono::Utc::now(),
@@ -35,11 +37,13 @@ mod tests {
             session_id: SessionId::new(),
             session_title: "Empty Decision".to_string(),
             decision_statement: None,
+            stakeholders: vec![],
             cycle_count: 1,
             active_cycle_id: None,
             objectives: vec![],
             alternatives: vec![],
             consequences_table: None,
+            key_uncertainties: vec![],
             recommendation: None,
             dq_score: None,
             last_updated: chrono::Utc::now(),
@@ -58,11 +62,13 @@ mod tests {
             session_id,
             session_title: "Test Session".to_string(),
             decision_statement: None,
+            stakeholders: vec![],
             cycle_count: 2,
             active_cycle_id: None,
             objectives: vec![],
             alternatives: vec![],
             consequences_table: None,
+            key_uncertainties: vec![],
             recommendation: None,
             dq_score: None,
             last_updated: chrono::Utc::now(),
@@ -79,11 +85,13 @@ mod tests {
             session_id: SessionId::new(),
             session_title: "Test".to_string(),
             decision_statement: None,
+            stakeholders: vec![],
             cycle_count: 5,
             active_cycle_id: Some(cycle_id),
             objectives: vec![],
             alternatives: vec![],
             consequences_table: None,
+            key_uncertainties: vec![],
             recommendation: None,
             dq_score: None,
             last_updated: chrono::Utc::now(),
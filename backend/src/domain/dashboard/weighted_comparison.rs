@@ -0,0 +1,129 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use crate::domain::analysis::{ValueBasis, WeightedScoringAnalyzer};
+use crate::domain::foundation::CycleId;
+use crate::domain::proact::{AlternativesOutput, ConsequencesOutput, ObjectivesOutput, ScoringMethod};
+
+/// Weighted scores of a cycle's alternatives with per-objective breakdowns
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct WeightedComparisonView {
+    pub cycle_id: CycleId,
+    /// Method selected for the cycle: pugh or weighted_additive
+    pub scoring_method: String,
+    /// Objectives in table order
+    pub objectives: Vec<WeightedObjectiveColumn>,
+    /// Alternatives from best to worst
+    pub alternatives: Vec<WeightedAlternativeRow>,
+    /// Single top-ranked alternative (None if tied or empty)
+    pub best_alternative_id: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct WeightedObjectiveColumn {
+    pub id: String,
+    pub name: String,
+    /// Normalized weight (weights sum to 1)
+    pub weight: f64,
+    /// How values were derived: measure or rating
+    pub basis: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct WeightedAlternativeRow {
+    pub id: String,
+    pub name: String,
+    /// Score out of 100
+    pub score: f64,
+    /// Rank among alternatives (1 = best)
+    pub rank: usize,
+    /// One cell per objective, in column order
+    pub contributions: Vec<ContributionCell>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct ContributionCell {
+    pub objective_id: String,
+    /// Value of the consequence (0-1), if rated
+    pub value: Option<f64>,
+    /// Points this objective adds to the score
+    pub points: f64,
+}
+
+impl WeightedComparisonView {
+    /// Scores the consequences table and labels it with objective and
+    /// alternative names. IDs stand in for names that cannot be found.
+    pub fn build(
+        cycle_id: CycleId,
+        scoring_method: ScoringMethod,
+        objectives: &ObjectivesOutput,
+        alternatives: Option<&AlternativesOutput>,
+        consequences: &ConsequencesOutput,
+    ) -> Self {
+        let scores = WeightedScoringAnalyzer::compute(objectives, consequences);
+
+        let objective_name = |id: &str| {
+            objectives
+                .fundamental_objectives
+                .iter()
+                .find(|o| o.id == id)
+                .map(|o| o.description.clone())
+                .unwrap_or_else(|| id.to_string())
+        };
+        let alternative_name = |id: &str| {
+            alternatives
+                .and_then(|a| a.options.iter().find(|o| o.id == id))
+                .map(|o| o.name.clone())
+                .unwrap_or_else(|| id.to_string())
+        };
+
+        let mut rows: Vec<WeightedAlternativeRow> = scores
+            .alternatives
+            .iter()
+            .map(|a| WeightedAlternativeRow {
+                id: a.alternative_id.clone(),
+                name: alternative_name(&a.alternative_id),
+                score: a.score,
+                rank: a.rank,
+                contributions: a
+                    .contributions
+                    .iter()
+                    .map(|c| ContributionCell {
+                        objective_id: c.objective_id.clone(),
+                        value: c.value,
+                        points: c.points,
+                    })
+                    .collect(),
+            })
+            .collect();
+        rows.sort_by_key(|row| row.rank);
+
+        Self {
+            cycle_id,
+            scoring_method: scoring_method.as_str().to_string(),
+            objectives: scores
+                .objectives
+                .iter()
+                .map(|o| WeightedObjectiveColumn {
+                    id: o.objective_id.clone(),
+                    name: objective_name(&o.objective_id),
+                    weight: o.weight,
+                    basis: match o.basis {
+                        ValueBasis::Measure => "measure",
+                        ValueBasis::Rating => "rating",
+                    }
+                    .to_string(),
+                })
+                .collect(),
+            best_alternative_id: scores.best().map(str::to_string),
+            alternatives: rows,
+        }
+    }
+}
+
+#[cfg(test)]
+#[path = "weighted_comparison_test.rs"]
+mod weighted_comparison_test;
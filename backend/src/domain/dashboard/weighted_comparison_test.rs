@@ -0,0 +1,118 @@
+#[cfg(test)]
+mod tests {
+    use crate::domain::dashboard::weighted_comparison::*;
+    use crate::domain::foundation::{CycleId, Rating};
+    use crate::domain::proact::{
+        Alternative, AlternativesOutput, Cell, ConsequencesOutput, FundamentalObjective,
+        ObjectivesOutput, PerformanceMeasure, ScoringMethod,
+    };
+
+    fn objectives() -> ObjectivesOutput {
+        let objective = |id: &str, description: &str, weight: f64| FundamentalObjective {
+            id: id.to_string(),
+            description: description.to_string(),
+            performance_measure: PerformanceMeasure {
+                description: description.to_string(),
+                is_quantitative: false,
+                unit: None,
+                direction: "higher_is_better".to_string(),
+                value_function: None,
+            },
+            affected_party_id: None,
+            weight: Some(weight),
+        };
+        ObjectivesOutput {
+            fundamental_objectives: vec![
+                objective("o1", "Pay", 0.25),
+                objective("o2", "Security", 0.75),
+            ],
+            means_objectives: vec![],
+        }
+    }
+
+    fn alternatives() -> AlternativesOutput {
+        let alternative = |id: &str, name: &str| Alternative {
+            id: id.to_string(),
+            name: name.to_string(),
+            description: String::new(),
+            assumptions: vec![],
+            is_status_quo: false,
+        };
+        AlternativesOutput {
+            options: vec![alternative("a1", "Startup"), alternative("a2", "Bank")],
+            strategy_table: None,
+            has_status_quo: false,
+        }
+    }
+
+    fn consequences() -> ConsequencesOutput {
+        let mut output = ConsequencesOutput::default();
+        output.table.alternative_ids = vec!["a1".to_string(), "a2".to_string()];
+        output.table.objective_ids = vec!["o1".to_string(), "o2".to_string()];
+        for (alt, obj, rating) in [
+            ("a1", "o1", Rating::MuchBetter),
+            ("a1", "o2", Rating::MuchWorse),
+            ("a2", "o1", Rating::Same),
+            ("a2", "o2", Rating::Better),
+        ] {
+            output
+                .table
+                .cells
+                .entry(alt.to_string())
+                .or_default()
+                .insert(obj.to_string(), Cell::new(rating, ""));
+        }
+        output
+    }
+
+    #[test]
+    fn test_rows_are_sorted_by_rank_with_names() {
+        let view = WeightedComparisonView::build(
+            CycleId::new(),
+            ScoringMethod::WeightedAdditive,
+            &objectives(),
+            Some(&alternatives()),
+            &consequences(),
+        );
+
+        assert_eq!(view.scoring_method, "weighted_additive");
+        assert_eq!(view.alternatives[0].name, "Bank");
+        assert_eq!(view.alternatives[0].rank, 1);
+        assert_eq!(view.best_alternative_id.as_deref(), Some("a2"));
+        assert_eq!(view.objectives[1].name, "Security");
+        assert_eq!(view.objectives[1].basis, "rating");
+        assert!((view.alternatives[0].score - 68.75).abs() < 1e-9);
+        assert_eq!(view.alternatives[0].contributions.len(), 2);
+    }
+
+    #[test]
+    fn test_ids_stand_in_for_missing_names() {
+        let view = WeightedComparisonView::build(
+            CycleId::new(),
+            ScoringMethod::Pugh,
+            &ObjectivesOutput::default(),
+            None,
+            &consequences(),
+        );
+
+        assert_eq!(view.scoring_method, "pugh");
+        assert_eq!(view.objectives[0].name, "o1");
+        assert!(view.alternatives.iter().any(|a| a.name == "a1"));
+    }
+
+    #[test]
+    fn test_view_serializes_camel_case() {
+        let view = WeightedComparisonView::build(
+            CycleId::new(),
+            ScoringMethod::WeightedAdditive,
+            &objectives(),
+            Some(&alternatives()),
+            &consequences(),
+        );
+
+        let json = serde_json::to_string(&view).unwrap();
+        assert!(json.contains("scoringMethod"));
+        assert!(json.contains("bestAlternativeId"));
+        assert!(json.contains("objectiveId"));
+    }
+}
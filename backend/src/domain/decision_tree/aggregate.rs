@@ -0,0 +1,510 @@
+//! DecisionTree aggregate - Sequential choices and chance events.
+//!
+//! The tree is grown one branch at a time from its root, so it never
+//! contains cycles and every node but the root has exactly one parent.
+//! Decision nodes branch into options the user controls, chance nodes into
+//! outcomes with probabilities, and terminal nodes carry a payoff.
+
+use serde::{Deserialize, Serialize};
+
+use crate::domain::foundation::{CycleId, DecisionTreeId, Timestamp, UserId};
+use crate::domain::proact::PROBABILITY_TOLERANCE;
+
+/// Longest accepted tree name, in characters.
+pub const MAX_TREE_NAME_CHARS: usize = 200;
+
+/// Nodes one tree may have.
+pub const MAX_TREE_NODES: usize = 200;
+
+/// What a node represents.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum NodeKind {
+    /// A choice the decision maker controls.
+    Decision,
+    /// An event whose outcome is uncertain.
+    Chance,
+    /// The end of a path, worth `payoff`.
+    Terminal { payoff: f64 },
+}
+
+impl NodeKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            NodeKind::Decision => "decision",
+            NodeKind::Chance => "chance",
+            NodeKind::Terminal { .. } => "terminal",
+        }
+    }
+}
+
+/// An edge from a decision or chance node to a child.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TreeBranch {
+    /// Option taken or outcome that occurred.
+    pub label: String,
+    pub child_id: String,
+    /// Probability of the outcome; chance branches only.
+    pub probability: Option<f64>,
+}
+
+/// A node of the tree.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TreeNode {
+    /// Short ID such as `n3`, unique within the tree.
+    pub id: String,
+    pub label: String,
+    #[serde(flatten)]
+    pub kind: NodeKind,
+    /// Children in the order they were added.
+    #[serde(default)]
+    pub branches: Vec<TreeBranch>,
+}
+
+/// Why a tree change or evaluation was rejected.
+#[derive(Debug, Clone, PartialEq, thiserror::Error)]
+pub enum DecisionTreeError {
+    #[error("Tree name must be 1-{MAX_TREE_NAME_CHARS} characters")]
+    InvalidName,
+
+    #[error("Node and branch labels must not be empty")]
+    EmptyLabel,
+
+    #[error("The tree already has a root")]
+    RootExists,
+
+    #[error("The tree has no root yet")]
+    NoRoot,
+
+    #[error("Node {0} not found")]
+    NodeNotFound(String),
+
+    #[error("Terminal node {0} cannot have branches")]
+    TerminalHasNoBranches(String),
+
+    #[error("Node {0} is not a terminal node")]
+    NotTerminal(String),
+
+    #[error("Outcomes of chance node {0} need a probability")]
+    ProbabilityRequired(String),
+
+    #[error("Options of decision node {0} do not take a probability")]
+    UnexpectedProbability(String),
+
+    #[error("Probability {0} is outside 0 to 1")]
+    ProbabilityOutOfRange(f64),
+
+    #[error("Payoffs and probabilities must be finite numbers")]
+    NotFinite,
+
+    #[error("Node {0} has no branches")]
+    Incomplete(String),
+
+    #[error("Outcome probabilities of chance node {node_id} sum to {total}, not 1")]
+    ProbabilitiesDoNotSum { node_id: String, total: f64 },
+
+    #[error("Trees can have at most {MAX_TREE_NODES} nodes")]
+    NodeLimitReached,
+}
+
+/// A decision tree for one cycle's sequential decision.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DecisionTree {
+    pub id: DecisionTreeId,
+    pub cycle_id: CycleId,
+    /// User who built the tree; only they can read it.
+    pub user_id: UserId,
+    pub name: String,
+    /// Unit payoffs are measured in, such as "USD" or "utility".
+    pub payoff_unit: Option<String>,
+    pub root_id: Option<String>,
+    /// Nodes in the order they were added.
+    pub nodes: Vec<TreeNode>,
+    pub created_at: Timestamp,
+    pub updated_at: Timestamp,
+}
+
+impl DecisionTree {
+    /// Creates an empty tree for a cycle.
+    pub fn new(
+        cycle_id: CycleId,
+        user_id: UserId,
+        name: impl Into<String>,
+        payoff_unit: Option<String>,
+    ) -> Result<Self, DecisionTreeError> {
+        let now = Timestamp::now();
+        Ok(Self {
+            id: DecisionTreeId::new(),
+            cycle_id,
+            user_id,
+            name: validate_name(name)?,
+            payoff_unit,
+            root_id: None,
+            nodes: Vec::new(),
+            created_at: now,
+            updated_at: now,
+        })
+    }
+
+    pub fn node(&self, id: &str) -> Option<&TreeNode> {
+        self.nodes.iter().find(|n| n.id == id)
+    }
+
+    pub fn root(&self) -> Option<&TreeNode> {
+        self.root_id.as_deref().and_then(|id| self.node(id))
+    }
+
+    /// Parent of `id` and the branch leading to it; None for the root.
+    pub fn parent_of(&self, id: &str) -> Option<(&TreeNode, &TreeBranch)> {
+        self.nodes.iter().find_map(|node| {
+            node.branches
+                .iter()
+                .find(|b| b.child_id == id)
+                .map(|branch| (node, branch))
+        })
+    }
+
+    /// Adds the first node. Returns its ID.
+    pub fn add_root(
+        &mut self,
+        label: impl Into<String>,
+        kind: NodeKind,
+    ) -> Result<String, DecisionTreeError> {
+        if self.root_id.is_some() {
+            return Err(DecisionTreeError::RootExists);
+        }
+        let id = self.push_node(label, kind)?;
+        self.root_id = Some(id.clone());
+        self.touch();
+        Ok(id)
+    }
+
+    /// Adds a child below a decision or chance node. Outcomes of chance
+    /// nodes need a probability; options of decision nodes must not have
+    /// one. Returns the child's ID.
+    pub fn add_branch(
+        &mut self,
+        parent_id: &str,
+        branch_label: impl Into<String>,
+        probability: Option<f64>,
+        label: impl Into<String>,
+        kind: NodeKind,
+    ) -> Result<String, DecisionTreeError> {
+        let parent = self
+            .node(parent_id)
+            .ok_or_else(|| DecisionTreeError::NodeNotFound(parent_id.to_string()))?;
+        check_branch_probability(parent, probability)?;
+        let branch_label = validate_label(branch_label)?;
+
+        let child_id = self.push_node(label, kind)?;
+        let parent = self.node_mut(parent_id)?;
+        parent.branches.push(TreeBranch {
+            label: branch_label,
+            child_id: child_id.clone(),
+            probability,
+        });
+        self.touch();
+        Ok(child_id)
+    }
+
+    /// Changes the probability of the outcome leading to `child_id`.
+    pub fn set_probability(
+        &mut self,
+        child_id: &str,
+        probability: f64,
+    ) -> Result<(), DecisionTreeError> {
+        let (parent, _) = self
+            .parent_of(child_id)
+            .ok_or_else(|| DecisionTreeError::NodeNotFound(child_id.to_string()))?;
+        check_branch_probability(parent, Some(probability))?;
+        let parent_id = parent.id.clone();
+
+        let parent = self.node_mut(&parent_id)?;
+        if let Some(branch) = parent.branches.iter_mut().find(|b| b.child_id == child_id) {
+            branch.probability = Some(probability);
+        }
+        self.touch();
+        Ok(())
+    }
+
+    /// Changes the payoff of a terminal node.
+    pub fn set_payoff(&mut self, node_id: &str, payoff: f64) -> Result<(), DecisionTreeError> {
+        if !payoff.is_finite() {
+            return Err(DecisionTreeError::NotFinite);
+        }
+        let node = self.node_mut(node_id)?;
+        match &mut node.kind {
+            NodeKind::Terminal { payoff: current } => *current = payoff,
+            _ => return Err(DecisionTreeError::NotTerminal(node_id.to_string())),
+        }
+        self.touch();
+        Ok(())
+    }
+
+    /// Removes a node, the branch leading to it, and everything below it.
+    /// Returns how many nodes were removed.
+    pub fn remove_subtree(&mut self, node_id: &str) -> Result<usize, DecisionTreeError> {
+        if self.node(node_id).is_none() {
+            return Err(DecisionTreeError::NodeNotFound(node_id.to_string()));
+        }
+
+        let mut doomed = vec![node_id.to_string()];
+        let mut index = 0;
+        while index < doomed.len() {
+            if let Some(node) = self.node(&doomed[index]) {
+                doomed.extend(node.branches.iter().map(|b| b.child_id.clone()));
+            }
+            index += 1;
+        }
+
+        self.nodes.retain(|n| !doomed.contains(&n.id));
+        for node in &mut self.nodes {
+            node.branches.retain(|b| b.child_id != node_id);
+        }
+        if self.root_id.as_deref() == Some(node_id) {
+            self.root_id = None;
+        }
+        self.touch();
+        Ok(doomed.len())
+    }
+
+    /// Checks the tree can be rolled back: it has a root, every decision
+    /// and chance node has branches, and each chance node's outcome
+    /// probabilities sum to 1.
+    pub fn validate(&self) -> Result<(), DecisionTreeError> {
+        if self.root().is_none() {
+            return Err(DecisionTreeError::NoRoot);
+        }
+        for node in &self.nodes {
+            match node.kind {
+                NodeKind::Terminal { .. } => {}
+                NodeKind::Decision | NodeKind::Chance if node.branches.is_empty() => {
+                    return Err(DecisionTreeError::Incomplete(node.id.clone()));
+                }
+                NodeKind::Decision => {}
+                NodeKind::Chance => {
+                    let total: f64 = node.branches.iter().filter_map(|b| b.probability).sum();
+                    if (total - 1.0).abs() > PROBABILITY_TOLERANCE {
+                        return Err(DecisionTreeError::ProbabilitiesDoNotSum {
+                            node_id: node.id.clone(),
+                            total,
+                        });
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn push_node(
+        &mut self,
+        label: impl Into<String>,
+        kind: NodeKind,
+    ) -> Result<String, DecisionTreeError> {
+        if self.nodes.len() >= MAX_TREE_NODES {
+            return Err(DecisionTreeError::NodeLimitReached);
+        }
+        if let NodeKind::Terminal { payoff } = kind {
+            if !payoff.is_finite() {
+                return Err(DecisionTreeError::NotFinite);
+            }
+        }
+        let label = validate_label(label)?;
+        let id = self.next_node_id();
+        self.nodes.push(TreeNode {
+            id: id.clone(),
+            label,
+            kind,
+            branches: Vec::new(),
+        });
+        Ok(id)
+    }
+
+    fn node_mut(&mut self, id: &str) -> Result<&mut TreeNode, DecisionTreeError> {
+        self.nodes
+            .iter_mut()
+            .find(|n| n.id == id)
+            .ok_or_else(|| DecisionTreeError::NodeNotFound(id.to_string()))
+    }
+
+    /// `n<k>` for the smallest k above every existing node's number.
+    fn next_node_id(&self) -> String {
+        let highest = self
+            .nodes
+            .iter()
+            .filter_map(|n| n.id.strip_prefix('n')?.parse::<u32>().ok())
+            .max()
+            .unwrap_or(0);
+        format!("n{}", highest + 1)
+    }
+
+    fn touch(&mut self) {
+        self.updated_at = Timestamp::now();
+    }
+}
+
+fn validate_name(name: impl Into<String>) -> Result<String, DecisionTreeError> {
+    let name = name.into().trim().to_string();
+    if name.is_empty() || name.chars().count() > MAX_TREE_NAME_CHARS {
+        return Err(DecisionTreeError::InvalidName);
+    }
+    Ok(name)
+}
+
+fn validate_label(label: impl Into<String>) -> Result<String, DecisionTreeError> {
+    let label = label.into().trim().to_string();
+    if label.is_empty() {
+        return Err(DecisionTreeError::EmptyLabel);
+    }
+    Ok(label)
+}
+
+fn check_branch_probability(
+    parent: &TreeNode,
+    probability: Option<f64>,
+) -> Result<(), DecisionTreeError> {
+    match (parent.kind, probability) {
+        (NodeKind::Terminal { .. }, _) => {
+            Err(DecisionTreeError::TerminalHasNoBranches(parent.id.clone()))
+        }
+        (NodeKind::Decision, Some(_)) => {
+            Err(DecisionTreeError::UnexpectedProbability(parent.id.clone()))
+        }
+        (NodeKind::Decision, None) => Ok(()),
+        (NodeKind::Chance, None) => Err(DecisionTreeError::ProbabilityRequired(parent.id.clone())),
+        (NodeKind::Chance, Some(p)) if !p.is_finite() => Err(DecisionTreeError::NotFinite),
+        (NodeKind::Chance, Some(p)) if !(0.0..=1.0).contains(&p) => {
+            Err(DecisionTreeError::ProbabilityOutOfRange(p))
+        }
+        (NodeKind::Chance, Some(_)) => Ok(()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tree() -> DecisionTree {
+        DecisionTree::new(
+            CycleId::new(),
+            UserId::new("user-1").unwrap(),
+            "Launch plan",
+            Some("USD".to_string()),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn branches_get_sequential_ids() {
+        let mut tree = tree();
+        let root = tree.add_root("Launch?", NodeKind::Decision).unwrap();
+        let child = tree
+            .add_branch(&root, "Launch", None, "Demand", NodeKind::Chance)
+            .unwrap();
+
+        assert_eq!(root, "n1");
+        assert_eq!(child, "n2");
+        let (parent, branch) = tree.parent_of("n2").unwrap();
+        assert_eq!(parent.id, "n1");
+        assert_eq!(branch.label, "Launch");
+    }
+
+    #[test]
+    fn only_one_root() {
+        let mut tree = tree();
+        tree.add_root("Launch?", NodeKind::Decision).unwrap();
+
+        assert_eq!(
+            tree.add_root("Again", NodeKind::Decision),
+            Err(DecisionTreeError::RootExists)
+        );
+    }
+
+    #[test]
+    fn chance_outcomes_need_probabilities() {
+        let mut tree = tree();
+        let root = tree.add_root("Demand", NodeKind::Chance).unwrap();
+
+        assert_eq!(
+            tree.add_branch(&root, "High", None, "Win", NodeKind::Terminal { payoff: 1.0 }),
+            Err(DecisionTreeError::ProbabilityRequired(root.clone()))
+        );
+        assert_eq!(
+            tree.add_branch(&root, "High", Some(1.5), "Win", NodeKind::Terminal { payoff: 1.0 }),
+            Err(DecisionTreeError::ProbabilityOutOfRange(1.5))
+        );
+    }
+
+    #[test]
+    fn terminal_nodes_cannot_branch() {
+        let mut tree = tree();
+        let root = tree.add_root("Done", NodeKind::Terminal { payoff: 0.0 }).unwrap();
+
+        assert_eq!(
+            tree.add_branch(&root, "More", None, "x", NodeKind::Decision),
+            Err(DecisionTreeError::TerminalHasNoBranches(root))
+        );
+    }
+
+    #[test]
+    fn removing_a_subtree_detaches_it() {
+        let mut tree = tree();
+        let root = tree.add_root("Launch?", NodeKind::Decision).unwrap();
+        let demand = tree
+            .add_branch(&root, "Launch", None, "Demand", NodeKind::Chance)
+            .unwrap();
+        tree.add_branch(&demand, "High", Some(0.5), "Win", NodeKind::Terminal { payoff: 10.0 })
+            .unwrap();
+
+        assert_eq!(tree.remove_subtree(&demand).unwrap(), 2);
+        assert_eq!(tree.nodes.len(), 1);
+        assert!(tree.root().unwrap().branches.is_empty());
+        let next = tree
+            .add_branch(&root, "Wait", None, "Hold", NodeKind::Terminal { payoff: 0.0 })
+            .unwrap();
+        assert_eq!(next, "n2");
+    }
+
+    #[test]
+    fn validate_checks_probability_totals() {
+        let mut tree = tree();
+        let root = tree.add_root("Demand", NodeKind::Chance).unwrap();
+        tree.add_branch(&root, "High", Some(0.6), "Win", NodeKind::Terminal { payoff: 10.0 })
+            .unwrap();
+        let low = tree
+            .add_branch(&root, "Low", Some(0.3), "Lose", NodeKind::Terminal { payoff: -5.0 })
+            .unwrap();
+
+        assert!(matches!(
+            tree.validate(),
+            Err(DecisionTreeError::ProbabilitiesDoNotSum { .. })
+        ));
+        tree.set_probability(&low, 0.4).unwrap();
+        assert_eq!(tree.validate(), Ok(()));
+    }
+
+    #[test]
+    fn set_payoff_only_on_terminals() {
+        let mut tree = tree();
+        let root = tree.add_root("Launch?", NodeKind::Decision).unwrap();
+        let leaf = tree
+            .add_branch(&root, "Wait", None, "Hold", NodeKind::Terminal { payoff: 0.0 })
+            .unwrap();
+
+        tree.set_payoff(&leaf, 3.0).unwrap();
+        assert_eq!(tree.node(&leaf).unwrap().kind, NodeKind::Terminal { payoff: 3.0 });
+        assert_eq!(tree.set_payoff(&root, 1.0), Err(DecisionTreeError::NotTerminal(root)));
+    }
+
+    #[test]
+    fn node_kind_serializes_flat() {
+        let mut tree = tree();
+        tree.add_root("Done", NodeKind::Terminal { payoff: 2.0 }).unwrap();
+
+        let json = serde_json::to_value(&tree.nodes[0]).unwrap();
+        assert_eq!(json["kind"], "terminal");
+        assert_eq!(json["payoff"], 2.0);
+        let back: TreeNode = serde_json::from_value(json).unwrap();
+        assert_eq!(back, tree.nodes[0]);
+    }
+}
@@ -0,0 +1,21 @@
+//! Decision tree domain module.
+//!
+//! Some decisions unfold in stages: a choice now is followed by an
+//! uncertain event and then another choice. A `DecisionTree` models such a
+//! sequential decision for a cycle, and rolling it back gives the expected
+//! value of each node and the best option at each decision point.
+//!
+//! # Types
+//!
+//! - `DecisionTree` - Decision, chance, and terminal nodes linked to a cycle
+//! - `NodeKind` - What a node represents; terminals carry a payoff
+//! - `Rollback` - Expected values and the optimal strategy
+
+mod aggregate;
+mod rollback;
+
+pub use aggregate::{
+    DecisionTree, DecisionTreeError, NodeKind, TreeBranch, TreeNode, MAX_TREE_NAME_CHARS,
+    MAX_TREE_NODES,
+};
+pub use rollback::Rollback;
@@ -0,0 +1,183 @@
+//! Rollback - Expected values computed from the leaves back to the root.
+//!
+//! A terminal node is worth its payoff, a chance node the probability-
+//! weighted average of its outcomes, and a decision node its best option.
+//! The best option at every decision node together form the optimal
+//! strategy.
+
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use super::{DecisionTree, DecisionTreeError, NodeKind};
+
+/// Options closer in value than this are treated as tied; the earlier one
+/// is chosen.
+const TIE_EPSILON: f64 = 1e-9;
+
+/// Result of rolling back a tree.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Rollback {
+    /// Expected value of the whole tree (the root's value).
+    pub expected_value: f64,
+    /// Expected value of every node, by node ID.
+    pub values: HashMap<String, f64>,
+    /// Best child of every decision node, by decision node ID.
+    pub choices: HashMap<String, String>,
+}
+
+impl Rollback {
+    pub fn value(&self, node_id: &str) -> Option<f64> {
+        self.values.get(node_id).copied()
+    }
+
+    /// Nodes reached when following the optimal strategy from the root:
+    /// the chosen option at decision nodes and every outcome at chance
+    /// nodes, in depth-first order.
+    pub fn optimal_nodes(&self, tree: &DecisionTree) -> Vec<String> {
+        let mut reached = Vec::new();
+        let mut stack: Vec<String> = tree.root_id.iter().cloned().collect();
+        while let Some(id) = stack.pop() {
+            let Some(node) = tree.node(&id) else {
+                continue;
+            };
+            match node.kind {
+                NodeKind::Decision => stack.extend(self.choices.get(&id).cloned()),
+                NodeKind::Chance => {
+                    stack.extend(node.branches.iter().rev().map(|b| b.child_id.clone()))
+                }
+                NodeKind::Terminal { .. } => {}
+            }
+            reached.push(id);
+        }
+        reached
+    }
+}
+
+impl DecisionTree {
+    /// Computes expected values and the optimal strategy. The tree must
+    /// pass [`DecisionTree::validate`].
+    pub fn rollback(&self) -> Result<Rollback, DecisionTreeError> {
+        self.validate()?;
+        let root_id = self.root_id.clone().ok_or(DecisionTreeError::NoRoot)?;
+
+        let mut rollback = Rollback::default();
+        // Children are always added after their parents, so walking the
+        // nodes backwards visits every child before its parent.
+        for node in self.nodes.iter().rev() {
+            let child_value = |id: &str| rollback.values.get(id).copied().unwrap_or(0.0);
+            let value = match node.kind {
+                NodeKind::Terminal { payoff } => payoff,
+                NodeKind::Chance => node
+                    .branches
+                    .iter()
+                    .map(|b| b.probability.unwrap_or(0.0) * child_value(&b.child_id))
+                    .sum(),
+                NodeKind::Decision => {
+                    let mut best: Option<(&str, f64)> = None;
+                    for branch in &node.branches {
+                        let value = child_value(&branch.child_id);
+                        if best.is_none_or(|(_, top)| value > top + TIE_EPSILON) {
+                            best = Some((&branch.child_id, value));
+                        }
+                    }
+                    let (choice, value) = best.ok_or_else(|| DecisionTreeError::Incomplete(node.id.clone()))?;
+                    rollback.choices.insert(node.id.clone(), choice.to_string());
+                    value
+                }
+            };
+            rollback.values.insert(node.id.clone(), value);
+        }
+
+        rollback.expected_value = rollback.value(&root_id).ok_or(DecisionTreeError::NoRoot)?;
+        Ok(rollback)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::foundation::{CycleId, UserId};
+
+    /// Launch now into uncertain demand, or run a pilot first and decide
+    /// again once the pilot's result is known.
+    fn launch_tree() -> DecisionTree {
+        let mut tree = DecisionTree::new(
+            CycleId::new(),
+            UserId::new("user-1").unwrap(),
+            "Launch plan",
+            None,
+        )
+        .unwrap();
+        let root = tree.add_root("How to launch?", NodeKind::Decision).unwrap();
+
+        let demand = tree
+            .add_branch(&root, "Launch now", None, "Demand", NodeKind::Chance)
+            .unwrap();
+        tree.add_branch(&demand, "High", Some(0.4), "Big win", NodeKind::Terminal { payoff: 100.0 })
+            .unwrap();
+        tree.add_branch(&demand, "Low", Some(0.6), "Loss", NodeKind::Terminal { payoff: -40.0 })
+            .unwrap();
+
+        let pilot = tree
+            .add_branch(&root, "Pilot first", None, "Pilot result", NodeKind::Chance)
+            .unwrap();
+        let good = tree
+            .add_branch(&pilot, "Good", Some(0.5), "Launch after pilot?", NodeKind::Decision)
+            .unwrap();
+        tree.add_branch(&good, "Launch", None, "Win", NodeKind::Terminal { payoff: 70.0 })
+            .unwrap();
+        tree.add_branch(&good, "Stop", None, "Nothing", NodeKind::Terminal { payoff: -5.0 })
+            .unwrap();
+        tree.add_branch(&pilot, "Bad", Some(0.5), "Stop", NodeKind::Terminal { payoff: -5.0 })
+            .unwrap();
+        tree
+    }
+
+    #[test]
+    fn rollback_averages_chance_and_maximizes_decisions() {
+        let tree = launch_tree();
+
+        let rollback = tree.rollback().unwrap();
+
+        // Launch now: 0.4 × 100 + 0.6 × -40 = 16
+        assert!((rollback.value("n2").unwrap() - 16.0).abs() < 1e-9);
+        // Pilot: 0.5 × max(70, -5) + 0.5 × -5 = 32.5
+        assert!((rollback.value("n5").unwrap() - 32.5).abs() < 1e-9);
+        assert!((rollback.expected_value - 32.5).abs() < 1e-9);
+        assert_eq!(rollback.choices["n1"], "n5");
+        assert_eq!(rollback.choices["n6"], "n7");
+    }
+
+    #[test]
+    fn optimal_nodes_follow_the_strategy() {
+        let tree = launch_tree();
+
+        let rollback = tree.rollback().unwrap();
+
+        assert_eq!(rollback.optimal_nodes(&tree), vec!["n1", "n5", "n6", "n7", "n9"]);
+    }
+
+    #[test]
+    fn ties_go_to_the_first_option() {
+        let mut tree = DecisionTree::new(CycleId::new(), UserId::new("user-1").unwrap(), "Tie", None)
+            .unwrap();
+        let root = tree.add_root("Pick", NodeKind::Decision).unwrap();
+        tree.add_branch(&root, "A", None, "A", NodeKind::Terminal { payoff: 1.0 })
+            .unwrap();
+        tree.add_branch(&root, "B", None, "B", NodeKind::Terminal { payoff: 1.0 })
+            .unwrap();
+
+        assert_eq!(tree.rollback().unwrap().choices["n1"], "n2");
+    }
+
+    #[test]
+    fn incomplete_trees_cannot_roll_back() {
+        let mut tree = DecisionTree::new(CycleId::new(), UserId::new("user-1").unwrap(), "Draft", None)
+            .unwrap();
+        assert_eq!(tree.rollback(), Err(DecisionTreeError::NoRoot));
+
+        let root = tree.add_root("Pick", NodeKind::Decision).unwrap();
+        assert_eq!(tree.rollback(), Err(DecisionTreeError::Incomplete(root)));
+    }
+}
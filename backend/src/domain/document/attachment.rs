@@ -0,0 +1,297 @@
+//! Attachment - A file attached to a cycle component.
+//!
+//! Users attach supporting material to a component, such as a photo of a
+//! whiteboard list of alternatives. The bytes live in document storage under
+//! [`Attachment::storage_key`]; this type is the metadata that references
+//! them and appears in exported documents.
+
+use crate::domain::foundation::{
+    AttachmentId, ComponentType, CycleId, DomainError, ErrorCode, Timestamp, UserId,
+};
+
+/// Largest file that can be attached.
+pub const MAX_ATTACHMENT_BYTES: usize = 10 * 1024 * 1024;
+
+/// Longest caption that can be set on an attachment.
+pub const MAX_CAPTION_LENGTH: usize = 500;
+
+/// Content types accepted for attachments.
+pub const ALLOWED_ATTACHMENT_TYPES: [&str; 7] = [
+    "image/jpeg",
+    "image/png",
+    "image/gif",
+    "image/webp",
+    "application/pdf",
+    "text/plain",
+    "text/csv",
+];
+
+/// Errors from attachment commands and queries.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AttachmentError {
+    /// Cycle was not found.
+    CycleNotFound(CycleId),
+    /// Attachment was not found on the cycle.
+    NotFound(AttachmentId),
+    /// User does not own the cycle's session.
+    Forbidden,
+    /// File was rejected before storage.
+    Invalid(String),
+    /// File exceeds [`MAX_ATTACHMENT_BYTES`].
+    TooLarge { size: usize, max: usize },
+    /// Storage or persistence failure.
+    Infrastructure(String),
+}
+
+impl AttachmentError {
+    pub fn code(&self) -> ErrorCode {
+        match self {
+            AttachmentError::CycleNotFound(_) => ErrorCode::CycleNotFound,
+            AttachmentError::NotFound(_) => ErrorCode::NotFound,
+            AttachmentError::Forbidden => ErrorCode::Forbidden,
+            AttachmentError::Invalid(_) | AttachmentError::TooLarge { .. } => {
+                ErrorCode::ValidationFailed
+            }
+            AttachmentError::Infrastructure(_) => ErrorCode::DatabaseError,
+        }
+    }
+}
+
+impl std::fmt::Display for AttachmentError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AttachmentError::CycleNotFound(id) => write!(f, "Cycle not found: {}", id),
+            AttachmentError::NotFound(id) => write!(f, "Attachment not found: {}", id),
+            AttachmentError::Forbidden => write!(f, "Permission denied"),
+            AttachmentError::Invalid(msg) => write!(f, "Invalid attachment: {}", msg),
+            AttachmentError::TooLarge { size, max } => {
+                write!(f, "Attachment is {} bytes; the limit is {}", size, max)
+            }
+            AttachmentError::Infrastructure(msg) => write!(f, "{}", msg),
+        }
+    }
+}
+
+impl std::error::Error for AttachmentError {}
+
+impl From<DomainError> for AttachmentError {
+    fn from(err: DomainError) -> Self {
+        match err.code {
+            ErrorCode::Forbidden => AttachmentError::Forbidden,
+            _ => AttachmentError::Infrastructure(err.to_string()),
+        }
+    }
+}
+
+/// Metadata for a file attached to a component.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Attachment {
+    pub id: AttachmentId,
+    pub cycle_id: CycleId,
+    pub component_type: ComponentType,
+    /// Sanitized file name, safe to use in paths and headers.
+    pub file_name: String,
+    pub content_type: String,
+    pub size_bytes: usize,
+    pub caption: Option<String>,
+    pub uploaded_by: UserId,
+    pub uploaded_at: Timestamp,
+}
+
+impl Attachment {
+    /// Validates an upload and creates its metadata.
+    ///
+    /// # Errors
+    ///
+    /// - `TooLarge` if the file exceeds [`MAX_ATTACHMENT_BYTES`]
+    /// - `Invalid` if the file is empty, its type is not allowed, or the
+    ///   caption is too long
+    pub fn new(
+        cycle_id: CycleId,
+        component_type: ComponentType,
+        file_name: &str,
+        content_type: &str,
+        size_bytes: usize,
+        caption: Option<String>,
+        uploaded_by: UserId,
+    ) -> Result<Self, AttachmentError> {
+        if size_bytes == 0 {
+            return Err(AttachmentError::Invalid("file is empty".to_string()));
+        }
+        if size_bytes > MAX_ATTACHMENT_BYTES {
+            return Err(AttachmentError::TooLarge {
+                size: size_bytes,
+                max: MAX_ATTACHMENT_BYTES,
+            });
+        }
+        let content_type = essence(content_type);
+        if !ALLOWED_ATTACHMENT_TYPES.contains(&content_type.as_str()) {
+            return Err(AttachmentError::Invalid(format!(
+                "files of type {} cannot be attached",
+                content_type
+            )));
+        }
+        let caption = caption
+            .map(|c| c.trim().to_string())
+            .filter(|c| !c.is_empty());
+        if caption
+            .as_ref()
+            .is_some_and(|c| c.chars().count() > MAX_CAPTION_LENGTH)
+        {
+            return Err(AttachmentError::Invalid(format!(
+                "caption exceeds {} characters",
+                MAX_CAPTION_LENGTH
+            )));
+        }
+
+        Ok(Self {
+            id: AttachmentId::new(),
+            cycle_id,
+            component_type,
+            file_name: sanitize_file_name(file_name),
+            content_type,
+            size_bytes,
+            caption,
+            uploaded_by,
+            uploaded_at: Timestamp::now(),
+        })
+    }
+
+    /// Where the file's bytes are kept in document storage.
+    pub fn storage_key(&self) -> String {
+        format!(
+            "attachments/{}/{}/{}",
+            self.cycle_id, self.id, self.file_name
+        )
+    }
+
+    /// API path the file can be downloaded from.
+    pub fn download_path(&self) -> String {
+        format!("/api/cycles/{}/attachments/{}", self.cycle_id, self.id)
+    }
+
+    pub fn is_image(&self) -> bool {
+        self.content_type.starts_with("image/")
+    }
+
+    /// Caption if set, otherwise the file name.
+    pub fn label(&self) -> &str {
+        self.caption.as_deref().unwrap_or(&self.file_name)
+    }
+}
+
+/// `image/JPEG; charset=x` → `image/jpeg`
+fn essence(content_type: &str) -> String {
+    content_type
+        .split(';')
+        .next()
+        .unwrap_or_default()
+        .trim()
+        .to_ascii_lowercase()
+}
+
+/// Keeps the final path segment and replaces anything outside
+/// `[A-Za-z0-9._-]`, so names are safe in storage keys and headers.
+fn sanitize_file_name(file_name: &str) -> String {
+    let base = file_name.rsplit(['/', '\\']).next().unwrap_or_default();
+    let sanitized: String = base
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() || matches!(c, '.' | '_' | '-') {
+                c
+            } else {
+                '_'
+            }
+        })
+        .take(100)
+        .collect();
+    let sanitized = sanitized.trim_start_matches('.');
+    if sanitized.is_empty() {
+        "attachment".to_string()
+    } else {
+        sanitized.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn attach(
+        file_name: &str,
+        content_type: &str,
+        size: usize,
+    ) -> Result<Attachment, AttachmentError> {
+        Attachment::new(
+            CycleId::new(),
+            ComponentType::Alternatives,
+            file_name,
+            content_type,
+            size,
+            None,
+            UserId::new("user-1").unwrap(),
+        )
+    }
+
+    #[test]
+    fn accepts_allowed_types() {
+        let attachment = attach("whiteboard.jpg", "image/JPEG", 2048).unwrap();
+        assert_eq!(attachment.content_type, "image/jpeg");
+        assert!(attachment.is_image());
+        assert_eq!(attachment.label(), "whiteboard.jpg");
+    }
+
+    #[test]
+    fn rejects_disallowed_types_and_sizes() {
+        assert!(matches!(
+            attach("run.sh", "application/x-sh", 10),
+            Err(AttachmentError::Invalid(_))
+        ));
+        assert!(matches!(
+            attach("empty.txt", "text/plain", 0),
+            Err(AttachmentError::Invalid(_))
+        ));
+        assert!(matches!(
+            attach("huge.png", "image/png", MAX_ATTACHMENT_BYTES + 1),
+            Err(AttachmentError::TooLarge { .. })
+        ));
+    }
+
+    #[test]
+    fn sanitizes_file_names() {
+        assert_eq!(sanitize_file_name("../../etc/passwd"), "passwd");
+        assert_eq!(
+            sanitize_file_name("C:\\photos\\my board.jpg"),
+            "my_board.jpg"
+        );
+        assert_eq!(sanitize_file_name(".hidden"), "hidden");
+        assert_eq!(sanitize_file_name(""), "attachment");
+    }
+
+    #[test]
+    fn storage_key_is_scoped_to_cycle() {
+        let attachment = attach("board.png", "image/png", 10).unwrap();
+        assert_eq!(
+            attachment.storage_key(),
+            format!(
+                "attachments/{}/{}/board.png",
+                attachment.cycle_id, attachment.id
+            )
+        );
+    }
+
+    #[test]
+    fn blank_caption_is_dropped() {
+        let attachment = Attachment::new(
+            CycleId::new(),
+            ComponentType::Alternatives,
+            "board.png",
+            "image/png",
+            10,
+            Some("   ".to_string()),
+            UserId::new("user-1").unwrap(),
+        )
+        .unwrap();
+        assert_eq!(attachment.caption, None);
+    }
+}
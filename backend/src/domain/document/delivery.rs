@@ -0,0 +1,205 @@
+//! DocumentDelivery - Emailing the decision document when a cycle completes.
+//!
+//! Users opt in with a `DocumentEmailPreference`. Each completed cycle then
+//! gets one `DocumentDelivery`, which records every send attempt. Transient
+//! failures are retried with exponential backoff until
+//! [`MAX_DELIVERY_ATTEMPTS`]; permanent failures stop immediately.
+
+use serde::{Deserialize, Serialize};
+
+use crate::domain::foundation::{CycleId, DocumentDeliveryId, Timestamp, UserId};
+
+/// Attempts before a delivery is marked failed.
+pub const MAX_DELIVERY_ATTEMPTS: u32 = 5;
+
+/// Delay before the first retry; doubles with each further attempt.
+pub const FIRST_RETRY_DELAY_SECS: u64 = 60;
+
+/// A user's choice to receive completed decision documents by email.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DocumentEmailPreference {
+    pub user_id: UserId,
+    /// Address the document is sent to.
+    pub email: String,
+    pub enabled: bool,
+    pub updated_at: Timestamp,
+}
+
+impl DocumentEmailPreference {
+    pub fn new(user_id: UserId, email: impl Into<String>, enabled: bool) -> Self {
+        Self {
+            user_id,
+            email: email.into(),
+            enabled,
+            updated_at: Timestamp::now(),
+        }
+    }
+}
+
+/// Where a delivery stands.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DeliveryStatus {
+    /// Waiting for its first attempt or a retry.
+    Pending,
+    Sent,
+    /// Gave up: a permanent error, or out of attempts.
+    Failed,
+}
+
+impl DeliveryStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            DeliveryStatus::Pending => "pending",
+            DeliveryStatus::Sent => "sent",
+            DeliveryStatus::Failed => "failed",
+        }
+    }
+
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "pending" => Some(DeliveryStatus::Pending),
+            "sent" => Some(DeliveryStatus::Sent),
+            "failed" => Some(DeliveryStatus::Failed),
+            _ => None,
+        }
+    }
+}
+
+/// One emailed copy of a cycle's decision document.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DocumentDelivery {
+    pub id: DocumentDeliveryId,
+    pub cycle_id: CycleId,
+    pub user_id: UserId,
+    pub recipient: String,
+    pub status: DeliveryStatus,
+    pub attempts: u32,
+    pub last_error: Option<String>,
+    /// When a pending delivery should next be attempted.
+    pub next_attempt_at: Timestamp,
+    pub created_at: Timestamp,
+    pub sent_at: Option<Timestamp>,
+}
+
+impl DocumentDelivery {
+    /// A pending delivery, due immediately.
+    pub fn new(cycle_id: CycleId, user_id: UserId, recipient: impl Into<String>) -> Self {
+        let now = Timestamp::now();
+        Self {
+            id: DocumentDeliveryId::new(),
+            cycle_id,
+            user_id,
+            recipient: recipient.into(),
+            status: DeliveryStatus::Pending,
+            attempts: 0,
+            last_error: None,
+            next_attempt_at: now,
+            created_at: now,
+            sent_at: None,
+        }
+    }
+
+    /// Whether a retry worker should attempt this delivery at `now`.
+    pub fn is_due(&self, now: Timestamp) -> bool {
+        self.status == DeliveryStatus::Pending && !self.next_attempt_at.is_after(&now)
+    }
+
+    pub fn record_sent(&mut self) {
+        self.attempts += 1;
+        self.status = DeliveryStatus::Sent;
+        self.last_error = None;
+        self.sent_at = Some(Timestamp::now());
+    }
+
+    /// Records a failed attempt, scheduling a retry if `retryable` and
+    /// attempts remain.
+    pub fn record_failure(&mut self, error: impl Into<String>, retryable: bool) {
+        self.attempts += 1;
+        self.last_error = Some(error.into());
+        if retryable && self.attempts < MAX_DELIVERY_ATTEMPTS {
+            self.next_attempt_at = Timestamp::now().plus_secs(retry_delay_secs(self.attempts));
+        } else {
+            self.status = DeliveryStatus::Failed;
+        }
+    }
+}
+
+/// Backoff after `attempts` failures: 1, 2, 4, 8... minutes.
+pub(crate) fn retry_delay_secs(attempts: u32) -> u64 {
+    FIRST_RETRY_DELAY_SECS << attempts.saturating_sub(1).min(10)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn delivery() -> DocumentDelivery {
+        DocumentDelivery::new(
+            CycleId::new(),
+            UserId::new("user-1").unwrap(),
+            "jo@example.com",
+        )
+    }
+
+    #[test]
+    fn new_deliveries_are_due_immediately() {
+        let delivery = delivery();
+        assert_eq!(delivery.status, DeliveryStatus::Pending);
+        assert!(delivery.is_due(Timestamp::now()));
+    }
+
+    #[test]
+    fn transient_failures_back_off_until_attempts_run_out() {
+        let mut delivery = delivery();
+
+        delivery.record_failure("timeout", true);
+        assert_eq!(delivery.status, DeliveryStatus::Pending);
+        assert!(!delivery.is_due(Timestamp::now()));
+        assert!(delivery.is_due(Timestamp::now().plus_secs(FIRST_RETRY_DELAY_SECS + 1)));
+
+        for _ in 1..MAX_DELIVERY_ATTEMPTS {
+            delivery.record_failure("timeout", true);
+        }
+        assert_eq!(delivery.status, DeliveryStatus::Failed);
+        assert_eq!(delivery.attempts, MAX_DELIVERY_ATTEMPTS);
+    }
+
+    #[test]
+    fn permanent_failures_stop_immediately() {
+        let mut delivery = delivery();
+        delivery.record_failure("mailbox does not exist", false);
+        assert_eq!(delivery.status, DeliveryStatus::Failed);
+        assert_eq!(
+            delivery.last_error.as_deref(),
+            Some("mailbox does not exist")
+        );
+    }
+
+    #[test]
+    fn sent_deliveries_are_no_longer_due() {
+        let mut delivery = delivery();
+        delivery.record_sent();
+        assert_eq!(delivery.status, DeliveryStatus::Sent);
+        assert!(delivery.sent_at.is_some());
+        assert!(!delivery.is_due(Timestamp::now()));
+    }
+
+    #[test]
+    fn retry_delay_doubles() {
+        assert_eq!(retry_delay_secs(1), 60);
+        assert_eq!(retry_delay_secs(2), 120);
+        assert_eq!(retry_delay_secs(4), 480);
+    }
+
+    #[test]
+    fn status_round_trips_through_strings() {
+        for status in [
+            DeliveryStatus::Pending,
+            DeliveryStatus::Sent,
+            DeliveryStatus::Failed,
+        ] {
+            assert_eq!(DeliveryStatus::parse(status.as_str()), Some(status));
+        }
+    }
+}
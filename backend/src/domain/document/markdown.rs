@@ -0,0 +1,260 @@
+//! MarkdownDocumentParser - Splits a decision document into sections.
+//!
+//! The document layout is the one produced by the default Markdown export
+//! template: a `# Title` followed by `## Section` headings. Headings inside
+//! fenced code blocks are ignored.
+
+use serde::{Deserialize, Serialize};
+
+use crate::domain::foundation::ComponentType;
+
+/// Raw Markdown text of a decision document.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct MarkdownContent(String);
+
+impl MarkdownContent {
+    pub fn new(text: impl Into<String>) -> Self {
+        Self(text.into())
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl From<String> for MarkdownContent {
+    fn from(text: String) -> Self {
+        Self(text)
+    }
+}
+
+/// Which part of the decision document a section holds.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum SectionKind {
+    /// The `# Title` heading and anything before the first `##` heading.
+    Title,
+    Decision,
+    Stakeholders,
+    Objectives,
+    Alternatives,
+    Consequences,
+    KeyUncertainties,
+    Recommendation,
+    DecisionQuality,
+    /// A heading the document layout does not know about.
+    Other(String),
+}
+
+impl SectionKind {
+    /// Classify a `##` heading, ignoring case.
+    pub fn from_heading(heading: &str) -> Self {
+        let normalized = heading.trim().to_ascii_lowercase();
+        match normalized.as_str() {
+            "decision" => SectionKind::Decision,
+            "stakeholders" => SectionKind::Stakeholders,
+            "objectives" => SectionKind::Objectives,
+            "alternatives" => SectionKind::Alternatives,
+            "consequences" => SectionKind::Consequences,
+            "key uncertainties" => SectionKind::KeyUncertainties,
+            "recommendation" => SectionKind::Recommendation,
+            "decision quality" => SectionKind::DecisionQuality,
+            _ => SectionKind::Other(heading.trim().to_string()),
+        }
+    }
+
+    /// The component whose output this section is rendered from, if any.
+    pub fn component_type(&self) -> Option<ComponentType> {
+        match self {
+            SectionKind::Decision => Some(ComponentType::ProblemFrame),
+            SectionKind::Stakeholders => Some(ComponentType::StakeholderAnalysis),
+            SectionKind::Objectives => Some(ComponentType::Objectives),
+            SectionKind::Alternatives => Some(ComponentType::Alternatives),
+            SectionKind::Consequences => Some(ComponentType::Consequences),
+            SectionKind::KeyUncertainties => Some(ComponentType::UncertaintyRegister),
+            SectionKind::Recommendation => Some(ComponentType::Recommendation),
+            SectionKind::DecisionQuality => Some(ComponentType::DecisionQuality),
+            SectionKind::Title | SectionKind::Other(_) => None,
+        }
+    }
+
+    /// Whether edits to this section can be written back to its component.
+    ///
+    /// Stakeholders, Consequences, Key Uncertainties and Decision Quality are
+    /// rendered from structured or computed data, so there is no single field
+    /// an edit could land in.
+    pub fn is_editable(&self) -> bool {
+        matches!(
+            self,
+            SectionKind::Decision
+                | SectionKind::Objectives
+                | SectionKind::Alternatives
+                | SectionKind::Recommendation
+        )
+    }
+}
+
+/// One section of a parsed document.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParsedSection {
+    pub kind: SectionKind,
+    /// Heading text as written, without the leading `#`s.
+    pub heading: String,
+    /// Everything between this heading and the next, trimmed.
+    pub body: String,
+    /// 1-based line number of the heading.
+    pub line: usize,
+}
+
+/// Splits Markdown decision documents into sections.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct MarkdownDocumentParser;
+
+impl MarkdownDocumentParser {
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Parse a document into sections in document order.
+    ///
+    /// The first section is always [`SectionKind::Title`], holding the `#`
+    /// heading (if any) and any text before the first `##` heading.
+    pub fn parse(&self, content: &MarkdownContent) -> Vec<ParsedSection> {
+        let mut sections = vec![ParsedSection {
+            kind: SectionKind::Title,
+            heading: String::new(),
+            body: String::new(),
+            line: 1,
+        }];
+        let mut body: Vec<&str> = Vec::new();
+        let mut in_fence = false;
+
+        for (index, line) in content.as_str().lines().enumerate() {
+            let trimmed = line.trim_start();
+            if trimmed.starts_with("```") || trimmed.starts_with("~~~") {
+                in_fence = !in_fence;
+            }
+            if !in_fence {
+                if let Some(heading) = trimmed.strip_prefix("## ") {
+                    finish(&mut sections, &mut body);
+                    sections.push(ParsedSection {
+                        kind: SectionKind::from_heading(heading),
+                        heading: heading.trim().to_string(),
+                        body: String::new(),
+                        line: index + 1,
+                    });
+                    continue;
+                }
+                if sections.len() == 1 && sections[0].heading.is_empty() {
+                    if let Some(title) = trimmed.strip_prefix("# ") {
+                        sections[0].heading = title.trim().to_string();
+                        sections[0].line = index + 1;
+                        continue;
+                    }
+                }
+            }
+            body.push(line);
+        }
+        finish(&mut sections, &mut body);
+        sections
+    }
+}
+
+fn finish(sections: &mut [ParsedSection], body: &mut Vec<&str>) {
+    if let Some(section) = sections.last_mut() {
+        section.body = body.join("\n").trim().to_string();
+    }
+    body.clear();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DOCUMENT: &str = "\
+# Choose a supplier
+
+_Decision document · 2026-01-05_
+
+## Decision
+
+Which supplier should we use next year?
+
+## Objectives
+
+- Lower cost
+- Reliable delivery
+
+## Appendix: Cycle Tree
+
+```
+## not a heading
+```
+";
+
+    #[test]
+    fn splits_document_by_heading() {
+        let sections = MarkdownDocumentParser::new().parse(&MarkdownContent::new(DOCUMENT));
+        let kinds: Vec<_> = sections.iter().map(|s| s.kind.clone()).collect();
+        assert_eq!(
+            kinds,
+            vec![
+                SectionKind::Title,
+                SectionKind::Decision,
+                SectionKind::Objectives,
+                SectionKind::Other("Appendix: Cycle Tree".to_string()),
+            ]
+        );
+        assert_eq!(sections[0].heading, "Choose a supplier");
+        assert_eq!(sections[0].body, "_Decision document · 2026-01-05_");
+        assert_eq!(sections[1].body, "Which supplier should we use next year?");
+        assert_eq!(sections[2].line, 9);
+    }
+
+    #[test]
+    fn ignores_headings_in_code_fences() {
+        let sections = MarkdownDocumentParser::new().parse(&MarkdownContent::new(DOCUMENT));
+        assert!(sections.last().unwrap().body.contains("## not a heading"));
+    }
+
+    #[test]
+    fn classifies_headings_case_insensitively() {
+        assert_eq!(
+            SectionKind::from_heading("Decision Quality"),
+            SectionKind::DecisionQuality
+        );
+        assert_eq!(
+            SectionKind::from_heading(" recommendation "),
+            SectionKind::Recommendation
+        );
+        assert!(SectionKind::Objectives.is_editable());
+        assert!(!SectionKind::Consequences.is_editable());
+    }
+
+    #[test]
+    fn stakeholders_section_maps_to_stakeholder_analysis() {
+        let kind = SectionKind::from_heading("Stakeholders");
+        assert_eq!(
+            kind.component_type(),
+            Some(ComponentType::StakeholderAnalysis)
+        );
+        assert!(!kind.is_editable());
+    }
+
+    #[test]
+    fn key_uncertainties_section_maps_to_uncertainty_register() {
+        let kind = SectionKind::from_heading("Key Uncertainties");
+        assert_eq!(
+            kind.component_type(),
+            Some(ComponentType::UncertaintyRegister)
+        );
+        assert!(!kind.is_editable());
+    }
+
+    #[test]
+    fn document_without_headings_is_all_title() {
+        let sections = MarkdownDocumentParser::new().parse(&MarkdownContent::new("just text"));
+        assert_eq!(sections.len(), 1);
+        assert_eq!(sections[0].body, "just text");
+    }
+}
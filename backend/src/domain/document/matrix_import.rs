@@ -0,0 +1,497 @@
+//! Matrix import - Maps a spreadsheet comparison matrix onto component outputs.
+//!
+//! The first row holds headers: the first column names the alternatives and
+//! every other column is one fundamental objective. Each later row is one
+//! alternative.
+//!
+//! ```text
+//! Option        | Cost    | Commute          | Space
+//! Stay put      | 0       | 0                | 0
+//! Move downtown | +1 rent | -2 long drive    | 1
+//! ```
+//!
+//! Columns are matched to objectives by an explicit mapping, or else by
+//! objective ID or description, ignoring case. Rows are matched to existing
+//! alternatives by name; unknown names become new alternatives.
+//!
+//! Cells for quantitative objectives hold a number (with optional note), stored
+//! as `quant_value` in the objective's unit with a neutral rating for the user
+//! to judge. Other cells hold a Pugh rating from -2 to +2, optionally followed
+//! by an explanation. Empty cells are left unrated.
+//!
+//! Every problem in the sheet is reported at once, so users can fix the file
+//! in one pass.
+
+use std::collections::{HashMap, HashSet};
+
+use serde::Serialize;
+
+use crate::domain::foundation::Rating;
+use crate::domain::proact::{
+    Alternative, AlternativesOutput, Cell, ConsequencesOutput, FundamentalObjective,
+    ObjectivesOutput,
+};
+
+/// Rows accepted in one import, excluding the header.
+pub const MAX_IMPORT_ROWS: usize = 200;
+
+/// A problem with the imported sheet.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ImportIssue {
+    /// 1-based row in the sheet; `None` for problems with the sheet as a whole.
+    pub row: Option<usize>,
+    /// Header of the column, if the problem is in one column.
+    pub column: Option<String>,
+    pub message: String,
+}
+
+impl ImportIssue {
+    fn sheet(message: impl Into<String>) -> Self {
+        Self {
+            row: None,
+            column: None,
+            message: message.into(),
+        }
+    }
+
+    fn column(column: &str, message: impl Into<String>) -> Self {
+        Self {
+            row: Some(1),
+            column: Some(column.to_string()),
+            message: message.into(),
+        }
+    }
+
+    fn cell(row: usize, column: &str, message: impl Into<String>) -> Self {
+        Self {
+            row: Some(row),
+            column: Some(column.to_string()),
+            message: message.into(),
+        }
+    }
+}
+
+/// Which objective a spreadsheet column was mapped to.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ColumnMatch {
+    pub header: String,
+    pub objective_id: String,
+}
+
+/// Component outputs with the sheet applied.
+#[derive(Debug, Clone)]
+pub struct MatrixImport {
+    pub alternatives: AlternativesOutput,
+    pub consequences: ConsequencesOutput,
+    pub columns: Vec<ColumnMatch>,
+    /// Names of alternatives that did not exist before the import.
+    pub added_alternatives: Vec<String>,
+}
+
+/// Applies `rows` to the existing outputs.
+///
+/// `mapping` maps column headers to objective IDs and takes precedence over
+/// matching by name. Alternatives and cells not in the sheet are kept.
+pub fn import_consequences_matrix(
+    rows: &[Vec<String>],
+    objectives: &ObjectivesOutput,
+    alternatives: &AlternativesOutput,
+    consequences: &ConsequencesOutput,
+    mapping: &HashMap<String, String>,
+) -> Result<MatrixImport, Vec<ImportIssue>> {
+    let rows: Vec<&Vec<String>> = rows
+        .iter()
+        .filter(|row| row.iter().any(|cell| !cell.trim().is_empty()))
+        .collect();
+    let Some((header, body)) = rows.split_first() else {
+        return Err(vec![ImportIssue::sheet("The sheet is empty")]);
+    };
+    if body.is_empty() {
+        return Err(vec![ImportIssue::sheet("The sheet has no alternatives")]);
+    }
+    if body.len() > MAX_IMPORT_ROWS {
+        return Err(vec![ImportIssue::sheet(format!(
+            "The sheet has {} alternatives; at most {} can be imported",
+            body.len(),
+            MAX_IMPORT_ROWS
+        ))]);
+    }
+
+    let mut issues = Vec::new();
+    let columns = map_columns(header, objectives, mapping, &mut issues);
+
+    let mut alternatives = alternatives.clone();
+    let mut consequences = consequences.clone();
+    let mut added_alternatives = Vec::new();
+    let mut seen_names = HashSet::new();
+
+    for (index, row) in body.iter().enumerate() {
+        let line = index + 2;
+        let name = row.first().map(|n| n.trim()).unwrap_or_default();
+        if name.is_empty() {
+            issues.push(ImportIssue::cell(
+                line,
+                &header[0],
+                "Alternative name is missing",
+            ));
+            continue;
+        }
+        if !seen_names.insert(name.to_lowercase()) {
+            issues.push(ImportIssue::cell(
+                line,
+                &header[0],
+                format!("Alternative \"{}\" appears more than once", name),
+            ));
+            continue;
+        }
+
+        let alternative_id = match alternatives
+            .options
+            .iter()
+            .find(|a| a.name.eq_ignore_ascii_case(name))
+        {
+            Some(existing) => existing.id.clone(),
+            None => {
+                let id = uuid::Uuid::new_v4().to_string();
+                alternatives.options.push(Alternative {
+                    id: id.clone(),
+                    name: name.to_string(),
+                    description: String::new(),
+                    assumptions: vec![],
+                    is_status_quo: false,
+                });
+                added_alternatives.push(name.to_string());
+                id
+            }
+        };
+
+        for (column, objective) in &columns {
+            let text = row.get(*column).map(|c| c.trim()).unwrap_or_default();
+            if text.is_empty() {
+                continue;
+            }
+            match parse_cell(text, objective) {
+                Ok(cell) => {
+                    consequences
+                        .table
+                        .cells
+                        .entry(alternative_id.clone())
+                        .or_default()
+                        .insert(objective.id.clone(), cell);
+                }
+                Err(message) => issues.push(ImportIssue::cell(line, &header[*column], message)),
+            }
+        }
+
+        if !consequences.table.alternative_ids.contains(&alternative_id) {
+            consequences.table.alternative_ids.push(alternative_id);
+        }
+    }
+
+    if !issues.is_empty() {
+        return Err(issues);
+    }
+
+    for (_, objective) in &columns {
+        if !consequences.table.objective_ids.contains(&objective.id) {
+            consequences.table.objective_ids.push(objective.id.clone());
+        }
+    }
+    alternatives.has_status_quo = alternatives.options.iter().any(|a| a.is_status_quo);
+
+    Ok(MatrixImport {
+        alternatives,
+        consequences,
+        columns: columns
+            .iter()
+            .map(|(column, objective)| ColumnMatch {
+                header: header[*column].trim().to_string(),
+                objective_id: objective.id.clone(),
+            })
+            .collect(),
+        added_alternatives,
+    })
+}
+
+/// Resolves every header after the first to a fundamental objective.
+fn map_columns<'a>(
+    header: &[String],
+    objectives: &'a ObjectivesOutput,
+    mapping: &HashMap<String, String>,
+    issues: &mut Vec<ImportIssue>,
+) -> Vec<(usize, &'a FundamentalObjective)> {
+    let fundamental = &objectives.fundamental_objectives;
+    if fundamental.is_empty() {
+        issues.push(ImportIssue::sheet(
+            "The cycle has no fundamental objectives to map columns to",
+        ));
+        return Vec::new();
+    }
+
+    let mut columns = Vec::new();
+    let mut used: HashMap<&str, &str> = HashMap::new();
+    for (index, title) in header.iter().enumerate().skip(1) {
+        let title = title.trim();
+        if title.is_empty() {
+            continue;
+        }
+        let objective = match mapping.get(title) {
+            Some(id) => match fundamental.iter().find(|o| &o.id == id) {
+                Some(objective) => objective,
+                None => {
+                    issues.push(ImportIssue::column(
+                        title,
+                        format!("Mapped to unknown objective \"{}\"", id),
+                    ));
+                    continue;
+                }
+            },
+            None => match fundamental.iter().find(|o| {
+                o.id.eq_ignore_ascii_case(title) || o.description.trim().eq_ignore_ascii_case(title)
+            }) {
+                Some(objective) => objective,
+                None => {
+                    issues.push(ImportIssue::column(
+                        title,
+                        "Does not match an objective; add it to the column mapping",
+                    ));
+                    continue;
+                }
+            },
+        };
+        if let Some(previous) = used.insert(objective.id.as_str(), title) {
+            issues.push(ImportIssue::column(
+                title,
+                format!(
+                    "Objective \"{}\" is already mapped from column \"{}\"",
+                    objective.description, previous
+                ),
+            ));
+            continue;
+        }
+        columns.push((index, objective));
+    }
+    if columns.is_empty() && issues.is_empty() {
+        issues.push(ImportIssue::sheet("The sheet has no objective columns"));
+    }
+    columns
+}
+
+/// Parses one cell for `objective`, returning a message if it is invalid.
+fn parse_cell(text: &str, objective: &FundamentalObjective) -> Result<Cell, String> {
+    let (value, note) = match text.find(|c: char| c.is_whitespace() || c == ':') {
+        Some(split) => (&text[..split], text[split + 1..].trim()),
+        None => (text, ""),
+    };
+
+    if objective.performance_measure.is_quantitative {
+        let number: f64 = value
+            .replace(',', "")
+            .parse()
+            .map_err(|_| format!("Expected a number, found \"{}\"", value))?;
+        let mut cell = Cell::new(Rating::Same, note);
+        cell.quant_value = Some(number);
+        cell.quant_unit = objective.performance_measure.unit.clone();
+        return Ok(cell);
+    }
+
+    let rating = value
+        .trim_start_matches('+')
+        .parse::<i8>()
+        .ok()
+        .and_then(|v| Rating::try_from_i8(v).ok())
+        .ok_or_else(|| format!("Expected a rating from -2 to +2, found \"{}\"", value))?;
+    Ok(Cell::new(rating, note))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::proact::PerformanceMeasure;
+
+    fn objective(id: &str, description: &str, unit: Option<&str>) -> FundamentalObjective {
+        FundamentalObjective {
+            id: id.to_string(),
+            description: description.to_string(),
+            performance_measure: PerformanceMeasure {
+                description: description.to_string(),
+                is_quantitative: unit.is_some(),
+                unit: unit.map(str::to_string),
+                direction: "higher_is_better".to_string(),
+                value_function: None,
+            },
+            affected_party_id: None,
+            weight: None,
+        }
+    }
+
+    fn objectives() -> ObjectivesOutput {
+        ObjectivesOutput {
+            fundamental_objectives: vec![
+                objective("obj-cost", "Monthly cost", Some("dollars")),
+                objective("obj-commute", "Commute", None),
+            ],
+            means_objectives: vec![],
+        }
+    }
+
+    fn existing_alternatives() -> AlternativesOutput {
+        AlternativesOutput {
+            options: vec![Alternative {
+                id: "alt-stay".to_string(),
+                name: "Stay put".to_string(),
+                description: "Keep the current flat".to_string(),
+                assumptions: vec![],
+                is_status_quo: true,
+            }],
+            strategy_table: None,
+            has_status_quo: true,
+        }
+    }
+
+    fn sheet(rows: &[&[&str]]) -> Vec<Vec<String>> {
+        rows.iter()
+            .map(|row| row.iter().map(|c| c.to_string()).collect())
+            .collect()
+    }
+
+    fn import(
+        rows: &[&[&str]],
+        mapping: &[(&str, &str)],
+    ) -> Result<MatrixImport, Vec<ImportIssue>> {
+        let mapping = mapping
+            .iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect();
+        import_consequences_matrix(
+            &sheet(rows),
+            &objectives(),
+            &existing_alternatives(),
+            &ConsequencesOutput::default(),
+            &mapping,
+        )
+    }
+
+    #[test]
+    fn imports_ratings_values_and_new_alternatives() {
+        let result = import(
+            &[
+                &["Option", "Monthly cost", "commute"],
+                &["Stay put", "1,450", "0"],
+                &["Move downtown", "2100 incl. parking", "+2: walkable"],
+            ],
+            &[],
+        )
+        .unwrap();
+
+        assert_eq!(result.added_alternatives, vec!["Move downtown"]);
+        assert_eq!(result.alternatives.options.len(), 2);
+        assert!(result.alternatives.has_status_quo);
+
+        let table = &result.consequences.table;
+        assert_eq!(table.objective_ids, vec!["obj-cost", "obj-commute"]);
+        assert_eq!(table.alternative_ids[0], "alt-stay");
+        assert_eq!(
+            table.cells["alt-stay"]["obj-cost"].quant_value,
+            Some(1450.0)
+        );
+
+        let downtown = &table.cells[&table.alternative_ids[1]];
+        assert_eq!(downtown["obj-cost"].explanation, "incl. parking");
+        assert_eq!(downtown["obj-cost"].quant_unit.as_deref(), Some("dollars"));
+        assert_eq!(downtown["obj-commute"].rating, Rating::MuchBetter);
+        assert_eq!(downtown["obj-commute"].explanation, "walkable");
+    }
+
+    #[test]
+    fn explicit_mapping_overrides_name_matching() {
+        let result = import(
+            &[
+                &["Option", "Rent", "Drive time"],
+                &["Stay put", "1450", "-1"],
+            ],
+            &[("Rent", "obj-cost"), ("Drive time", "obj-commute")],
+        )
+        .unwrap();
+
+        assert_eq!(
+            result.columns,
+            vec![
+                ColumnMatch {
+                    header: "Rent".to_string(),
+                    objective_id: "obj-cost".to_string(),
+                },
+                ColumnMatch {
+                    header: "Drive time".to_string(),
+                    objective_id: "obj-commute".to_string(),
+                },
+            ]
+        );
+        assert_eq!(
+            result.consequences.table.cells["alt-stay"]["obj-commute"].rating,
+            Rating::Worse
+        );
+    }
+
+    #[test]
+    fn reports_every_problem_at_once() {
+        let issues = import(
+            &[
+                &["Option", "Rent", "Commute", "Commute"],
+                &["Stay put", "1450", "3", "0"],
+                &["stay put", "1500", "0", "0"],
+            ],
+            &[],
+        )
+        .unwrap_err();
+
+        let messages: Vec<_> = issues
+            .iter()
+            .map(|i| (i.row, i.column.as_deref(), i.message.as_str()))
+            .collect();
+        assert_eq!(
+            messages,
+            vec![
+                (
+                    Some(1),
+                    Some("Rent"),
+                    "Does not match an objective; add it to the column mapping"
+                ),
+                (
+                    Some(1),
+                    Some("Commute"),
+                    "Objective \"Commute\" is already mapped from column \"Commute\""
+                ),
+                (
+                    Some(2),
+                    Some("Commute"),
+                    "Expected a rating from -2 to +2, found \"3\""
+                ),
+                (
+                    Some(3),
+                    Some("Option"),
+                    "Alternative \"stay put\" appears more than once"
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn rejects_sheets_without_alternatives() {
+        let issues = import(&[&["Option", "Commute"], &["", ""]], &[]).unwrap_err();
+        assert_eq!(issues[0].message, "The sheet has no alternatives");
+    }
+
+    #[test]
+    fn rejects_mappings_to_unknown_objectives() {
+        let issues = import(
+            &[&["Option", "Rent"], &["Stay put", "1"]],
+            &[("Rent", "obj-missing")],
+        )
+        .unwrap_err();
+        assert_eq!(
+            issues[0].message,
+            "Mapped to unknown objective \"obj-missing\""
+        );
+    }
+}
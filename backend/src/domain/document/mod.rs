@@ -0,0 +1,86 @@
+//! Document Module - Content of exported decision documents.
+//!
+//! The exported Markdown document is generated from component outputs. This
+//! module reads an edited copy back so changes can flow into the structured
+//! outputs again, keeps the document's version history, and describes the
+//! files users attach to components so exports can reference or embed them.
+//!
+//! # Components
+//!
+//! - `MarkdownDocumentParser` - Splits Markdown into `ParsedSection`s by heading
+//! - `Attachment` - Metadata for files attached to components and embedded
+//!   in exports
+//! - `diff_sections` - Compares edited sections with the originals and maps
+//!   changes to `ComponentEdit`s, reporting anything unmappable as a `ParseError`
+//! - `DocumentDelivery` - One emailed copy of a completed cycle's document,
+//!   with its retry state; users opt in via `DocumentEmailPreference`
+//! - `import_consequences_matrix` - Maps a spreadsheet comparison matrix onto
+//!   the Alternatives and Consequences outputs, reporting `ImportIssue`s
+//! - `ImportDraft` - AI-drafted objectives, alternatives, and ratings from
+//!   pasted notes, applied only once the user confirms
+//! - `DocumentPublication` - A rendered snapshot shared by signed public
+//!   link until it expires or is revoked
+//! - `DocumentVersion` - One stored revision of the document; `VersionDiff`
+//!   compares two of them section by section and line by line
+//! - `section_patches` - Changed sections with their new text, broadcast to
+//!   collaborators; `overlapping_sections` flags concurrent edits to the same
+//!   section
+//! - `mark_stale_sections` - Notes under sections whose analysis a queued
+//!   reanalysis will replace
+//! - `SlackShare` - A recommendation posted to a Slack channel as a
+//!   `RecommendationDigest`, with the same retry state as deliveries
+//! - `TeamsShare` - The same, posted to a Teams channel an organization
+//!   registered; `OrganizationChatSettings` picks the platform per organization
+//!
+//! # Design Philosophy
+//!
+//! Like `analysis`, everything here is pure: no ports, no I/O. The sync
+//! command handler loads the cycle, applies the edits, and validates them.
+
+mod attachment;
+mod delivery;
+mod markdown;
+mod matrix_import;
+mod publication;
+mod slack_share;
+mod stale;
+mod sync;
+mod teams_share;
+mod text_import;
+mod version;
+
+pub use attachment::{
+    Attachment, AttachmentError, ALLOWED_ATTACHMENT_TYPES, MAX_ATTACHMENT_BYTES,
+    MAX_CAPTION_LENGTH,
+};
+pub(crate) use delivery::retry_delay_secs;
+pub use delivery::{
+    DeliveryStatus, DocumentDelivery, DocumentEmailPreference, FIRST_RETRY_DELAY_SECS,
+    MAX_DELIVERY_ATTEMPTS,
+};
+pub use markdown::{MarkdownContent, MarkdownDocumentParser, ParsedSection, SectionKind};
+pub use matrix_import::{
+    import_consequences_matrix, ColumnMatch, ImportIssue, MatrixImport, MAX_IMPORT_ROWS,
+};
+pub use publication::{
+    DocumentPublication, DEFAULT_PUBLICATION_TTL_DAYS, MAX_PUBLICATION_TTL_DAYS,
+};
+pub use slack_share::{
+    DecisionQualityDigest, RecommendationDigest, SlackShare, MAX_DIGEST_CAVEATS,
+};
+pub use stale::{mark_stale_sections, STALE_NOTE_PREFIX};
+pub use sync::{
+    diff_sections, ComponentEdit, DocumentDiff, OutputChange, ParseError, ParseSeverity,
+};
+pub use teams_share::{
+    ChatPlatform, ChatSettingsError, OrganizationChatSettings, TeamsChannel, TeamsShare,
+    MAX_TEAMS_CHANNELS,
+};
+pub use text_import::{
+    text_import_tools, AppliedDraft, DraftAlternative, DraftObjective, DraftRating, ImportDraft,
+    AI_DRAFT_SOURCE, MAX_IMPORT_TEXT_CHARS,
+};
+pub use version::{
+    overlapping_sections, section_patches, DocumentVersion, SectionChange, SectionChangeKind,
+    SectionPatch, VersionDiff,
+};
@@ -0,0 +1,113 @@
+//! DocumentPublication - A public, read-only snapshot of a decision document.
+//!
+//! Publishing renders the document once and keeps the HTML, so stakeholders
+//! see what the owner chose to share rather than later edits. Links to the
+//! snapshot are signed tokens carrying the publication ID and expiry; the
+//! publication itself records revocation, so unpublishing takes effect even
+//! for links that have not expired yet.
+
+use serde::{Deserialize, Serialize};
+
+use crate::domain::foundation::{CycleId, PublicationId, Timestamp, UserId};
+
+/// Default lifetime of a published link.
+pub const DEFAULT_PUBLICATION_TTL_DAYS: i64 = 30;
+
+/// Longest lifetime an owner may choose for a published link.
+pub const MAX_PUBLICATION_TTL_DAYS: i64 = 365;
+
+/// A rendered snapshot of a decision document shared by link.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DocumentPublication {
+    pub id: PublicationId,
+    pub cycle_id: CycleId,
+    pub published_by: UserId,
+    pub title: String,
+    /// Standalone HTML page served to link holders.
+    pub html: String,
+    pub created_at: Timestamp,
+    pub expires_at: Timestamp,
+    pub revoked_at: Option<Timestamp>,
+}
+
+impl DocumentPublication {
+    pub fn new(
+        cycle_id: CycleId,
+        published_by: UserId,
+        title: impl Into<String>,
+        html: impl Into<String>,
+        ttl_days: i64,
+    ) -> Self {
+        let created_at = Timestamp::now();
+        Self {
+            id: PublicationId::new(),
+            cycle_id,
+            published_by,
+            title: title.into(),
+            html: html.into(),
+            created_at,
+            expires_at: created_at.plus_days(ttl_days.clamp(1, MAX_PUBLICATION_TTL_DAYS)),
+            revoked_at: None,
+        }
+    }
+
+    /// Stops the snapshot from being served. Revoking twice keeps the first time.
+    pub fn revoke(&mut self) {
+        if self.revoked_at.is_none() {
+            self.revoked_at = Some(Timestamp::now());
+        }
+    }
+
+    pub fn is_revoked(&self) -> bool {
+        self.revoked_at.is_some()
+    }
+
+    pub fn is_expired(&self, now: Timestamp) -> bool {
+        !now.is_before(&self.expires_at)
+    }
+
+    /// Whether link holders may see the snapshot.
+    pub fn is_live(&self, now: Timestamp) -> bool {
+        !self.is_revoked() && !self.is_expired(now)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn publication(ttl_days: i64) -> DocumentPublication {
+        DocumentPublication::new(
+            CycleId::new(),
+            UserId::new("owner-1").unwrap(),
+            "Move?",
+            "<html></html>",
+            ttl_days,
+        )
+    }
+
+    #[test]
+    fn new_publications_are_live_until_they_expire() {
+        let publication = publication(7);
+        assert!(publication.is_live(Timestamp::now()));
+        assert!(!publication.is_live(Timestamp::now().plus_days(8)));
+    }
+
+    #[test]
+    fn revoked_publications_are_not_live() {
+        let mut publication = publication(7);
+        publication.revoke();
+        let revoked_at = publication.revoked_at;
+        publication.revoke();
+
+        assert!(!publication.is_live(Timestamp::now()));
+        assert_eq!(publication.revoked_at, revoked_at);
+    }
+
+    #[test]
+    fn lifetime_is_clamped() {
+        let publication = publication(10_000);
+        assert!(publication.is_live(Timestamp::now().plus_days(MAX_PUBLICATION_TTL_DAYS - 1)));
+        assert!(!publication.is_live(Timestamp::now().plus_days(MAX_PUBLICATION_TTL_DAYS + 1)));
+    }
+}
@@ -0,0 +1,286 @@
+//! SlackShare - A cycle's recommendation posted to a Slack channel.
+//!
+//! Sharing snapshots the recommendation as a `RecommendationDigest` with a
+//! public link to the decision document, so retries post exactly what the
+//! user shared. Failed posts are retried with the same backoff as a
+//! `DocumentDelivery`.
+
+use serde::{Deserialize, Serialize};
+
+use crate::domain::foundation::{CycleId, Percentage, SlackShareId, Timestamp, UserId};
+use crate::domain::proact::{DecisionQualityOutput, RecommendationOutput};
+
+use super::delivery::{retry_delay_secs, DeliveryStatus, MAX_DELIVERY_ATTEMPTS};
+
+/// Caveats included in a digest; the rest are behind the link.
+pub const MAX_DIGEST_CAVEATS: usize = 3;
+
+/// The Decision Quality part of a digest.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DecisionQualityDigest {
+    /// The lowest element score, which caps overall decision quality.
+    pub overall_score: Percentage,
+    /// Names of the elements scoring `overall_score`.
+    pub weakest_elements: Vec<String>,
+}
+
+impl DecisionQualityDigest {
+    pub fn new(output: &DecisionQualityOutput) -> Self {
+        Self {
+            overall_score: output.overall_score,
+            weakest_elements: output
+                .elements
+                .iter()
+                .filter(|element| element.score == output.overall_score)
+                .map(|element| element.name.clone())
+                .collect(),
+        }
+    }
+}
+
+/// What a chat message says about a cycle's recommendation.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RecommendationDigest {
+    /// Decision title, from the document.
+    pub title: String,
+    /// Name of the alternative that stands out, if any.
+    pub standout: Option<String>,
+    pub synthesis: String,
+    pub caveats: Vec<String>,
+    /// Public link to the full decision document.
+    pub link: String,
+    /// Absent until the Decision Quality step has been rated.
+    #[serde(default)]
+    pub decision_quality: Option<DecisionQualityDigest>,
+}
+
+impl RecommendationDigest {
+    /// `standout` is the standout alternative's name, resolved by the caller
+    /// from the ID in `recommendation`.
+    pub fn new(
+        title: impl Into<String>,
+        recommendation: &RecommendationOutput,
+        standout: Option<String>,
+        link: impl Into<String>,
+    ) -> Self {
+        Self {
+            title: title.into(),
+            standout,
+            synthesis: recommendation.synthesis.trim().to_string(),
+            caveats: recommendation
+                .caveats
+                .iter()
+                .take(MAX_DIGEST_CAVEATS)
+                .cloned()
+                .collect(),
+            link: link.into(),
+            decision_quality: None,
+        }
+    }
+
+    /// Adds the Decision Quality summary.
+    pub fn with_decision_quality(mut self, output: &DecisionQualityOutput) -> Self {
+        self.decision_quality = Some(DecisionQualityDigest::new(output));
+        self
+    }
+}
+
+/// One recommendation posted, or waiting to be posted, to a Slack channel.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SlackShare {
+    pub id: SlackShareId,
+    pub cycle_id: CycleId,
+    pub user_id: UserId,
+    /// Slack workspace (team) ID.
+    pub team_id: String,
+    /// Channel ID or name.
+    pub channel: String,
+    pub digest: RecommendationDigest,
+    pub status: DeliveryStatus,
+    pub attempts: u32,
+    pub last_error: Option<String>,
+    /// When a pending share should next be attempted.
+    pub next_attempt_at: Timestamp,
+    pub created_at: Timestamp,
+    pub sent_at: Option<Timestamp>,
+    /// Slack's timestamp ID of the posted message.
+    pub message_ts: Option<String>,
+}
+
+impl SlackShare {
+    /// A pending share, due immediately.
+    pub fn new(
+        cycle_id: CycleId,
+        user_id: UserId,
+        team_id: impl Into<String>,
+        channel: impl Into<String>,
+        digest: RecommendationDigest,
+    ) -> Self {
+        let now = Timestamp::now();
+        Self {
+            id: SlackShareId::new(),
+            cycle_id,
+            user_id,
+            team_id: team_id.into(),
+            channel: channel.into(),
+            digest,
+            status: DeliveryStatus::Pending,
+            attempts: 0,
+            last_error: None,
+            next_attempt_at: now,
+            created_at: now,
+            sent_at: None,
+            message_ts: None,
+        }
+    }
+
+    /// Whether a retry worker should attempt this share at `now`.
+    pub fn is_due(&self, now: Timestamp) -> bool {
+        self.status == DeliveryStatus::Pending && !self.next_attempt_at.is_after(&now)
+    }
+
+    pub fn record_sent(&mut self, message_ts: impl Into<String>) {
+        self.attempts += 1;
+        self.status = DeliveryStatus::Sent;
+        self.last_error = None;
+        self.sent_at = Some(Timestamp::now());
+        self.message_ts = Some(message_ts.into());
+    }
+
+    /// Records a failed attempt, scheduling a retry if `retryable` and
+    /// attempts remain.
+    pub fn record_failure(&mut self, error: impl Into<String>, retryable: bool) {
+        self.attempts += 1;
+        self.last_error = Some(error.into());
+        if retryable && self.attempts < MAX_DELIVERY_ATTEMPTS {
+            self.next_attempt_at = Timestamp::now().plus_secs(retry_delay_secs(self.attempts));
+        } else {
+            self.status = DeliveryStatus::Failed;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::proact::DQElement;
+
+    fn digest() -> RecommendationDigest {
+        RecommendationDigest {
+            title: "Choose a supplier".to_string(),
+            standout: Some("Acme".to_string()),
+            synthesis: "Acme wins on cost.".to_string(),
+            caveats: vec![],
+            link: "https://app.example.com/public/documents/abc".to_string(),
+            decision_quality: None,
+        }
+    }
+
+    fn share() -> SlackShare {
+        SlackShare::new(
+            CycleId::new(),
+            UserId::new("user-1").unwrap(),
+            "T123",
+            "C456",
+            digest(),
+        )
+    }
+
+    fn element(name: &str, score: u8) -> DQElement {
+        DQElement {
+            name: name.to_string(),
+            score: Percentage::new(score),
+            rationale: String::new(),
+            improvement: String::new(),
+        }
+    }
+
+    #[test]
+    fn digest_keeps_the_first_caveats() {
+        let recommendation = RecommendationOutput {
+            standout_option: Some("alt-1".to_string()),
+            synthesis: "  Acme wins on cost.\n".to_string(),
+            caveats: (1..=5).map(|i| format!("Caveat {}", i)).collect(),
+            additional_info: vec!["Check references".to_string()],
+        };
+
+        let digest = RecommendationDigest::new(
+            "Choose a supplier",
+            &recommendation,
+            Some("Acme".to_string()),
+            "https://app.example.com/public/documents/abc",
+        );
+
+        assert_eq!(digest.synthesis, "Acme wins on cost.");
+        assert_eq!(digest.caveats.len(), MAX_DIGEST_CAVEATS);
+        assert_eq!(digest.caveats[0], "Caveat 1");
+        assert_eq!(digest.standout.as_deref(), Some("Acme"));
+    }
+
+    #[test]
+    fn decision_quality_digest_names_the_weakest_elements() {
+        let output = DecisionQualityOutput {
+            elements: vec![
+                element("Clear Objectives", 80),
+                element("Creative Alternatives", 55),
+                element("Clear Tradeoffs", 55),
+            ],
+            overall_score: Percentage::new(55),
+            improvement_paths: vec![],
+        };
+
+        let digest = digest().with_decision_quality(&output);
+
+        let dq = digest.decision_quality.unwrap();
+        assert_eq!(dq.overall_score.value(), 55);
+        assert_eq!(
+            dq.weakest_elements,
+            ["Creative Alternatives", "Clear Tradeoffs"]
+        );
+    }
+
+    #[test]
+    fn digests_stored_without_decision_quality_still_load() {
+        let json = r#"{"title":"t","standout":null,"synthesis":"s","caveats":[],"link":"l"}"#;
+        let digest: RecommendationDigest = serde_json::from_str(json).unwrap();
+        assert!(digest.decision_quality.is_none());
+    }
+
+    #[test]
+    fn new_shares_are_due_immediately() {
+        let share = share();
+        assert_eq!(share.status, DeliveryStatus::Pending);
+        assert!(share.is_due(Timestamp::now()));
+    }
+
+    #[test]
+    fn sent_shares_keep_the_message_timestamp() {
+        let mut share = share();
+        share.record_sent("1700000000.000100");
+        assert_eq!(share.status, DeliveryStatus::Sent);
+        assert_eq!(share.message_ts.as_deref(), Some("1700000000.000100"));
+        assert!(!share.is_due(Timestamp::now()));
+    }
+
+    #[test]
+    fn failures_back_off_until_attempts_run_out() {
+        let mut share = share();
+
+        share.record_failure("rate_limited", true);
+        assert_eq!(share.status, DeliveryStatus::Pending);
+        assert!(!share.is_due(Timestamp::now()));
+
+        for _ in 1..MAX_DELIVERY_ATTEMPTS {
+            share.record_failure("rate_limited", true);
+        }
+        assert_eq!(share.status, DeliveryStatus::Failed);
+    }
+
+    #[test]
+    fn permanent_failures_stop_immediately() {
+        let mut share = share();
+        share.record_failure("channel_not_found", false);
+        assert_eq!(share.status, DeliveryStatus::Failed);
+        assert_eq!(share.last_error.as_deref(), Some("channel_not_found"));
+    }
+}
@@ -0,0 +1,116 @@
+//! Stale section notes - Flags document sections whose analysis is out of date.
+//!
+//! After a reanalysis is queued, the sections rendered from affected
+//! components get a blockquote note under their heading until the document is
+//! exported again. Sections already carrying a note are left alone, so
+//! repeated reanalyses do not stack notes.
+
+use crate::domain::foundation::ComponentType;
+
+use super::markdown::{MarkdownContent, SectionKind};
+
+/// How every stale note starts.
+pub const STALE_NOTE_PREFIX: &str = "> **Out of date:**";
+
+/// Adds a stale note under each `##` heading rendered from one of
+/// `components`, giving `reason` as the cause.
+///
+/// Returns the updated document and the headings that were newly marked, in
+/// document order. Headings inside fenced code blocks are ignored.
+pub fn mark_stale_sections(
+    content: &MarkdownContent,
+    components: &[ComponentType],
+    reason: &str,
+) -> (MarkdownContent, Vec<String>) {
+    let lines: Vec<&str> = content.as_str().lines().collect();
+    let note = format!(
+        "{} {}; this section will be updated.",
+        STALE_NOTE_PREFIX, reason
+    );
+    let mut output = Vec::with_capacity(lines.len());
+    let mut marked = Vec::new();
+    let mut in_fence = false;
+
+    for (index, line) in lines.iter().enumerate() {
+        output.push(line.to_string());
+        let trimmed = line.trim_start();
+        if trimmed.starts_with("```") || trimmed.starts_with("~~~") {
+            in_fence = !in_fence;
+        }
+        if in_fence {
+            continue;
+        }
+        let Some(heading) = trimmed.strip_prefix("## ") else {
+            continue;
+        };
+        let affected = SectionKind::from_heading(heading)
+            .component_type()
+            .is_some_and(|ct| components.contains(&ct));
+        let already_marked = lines[index + 1..]
+            .iter()
+            .find(|l| !l.trim().is_empty())
+            .is_some_and(|l| l.trim_start().starts_with(STALE_NOTE_PREFIX));
+        if affected && !already_marked {
+            output.push(String::new());
+            output.push(note.clone());
+            marked.push(heading.trim().to_string());
+        }
+    }
+
+    let mut text = output.join("\n");
+    if content.as_str().ends_with('\n') {
+        text.push('\n');
+    }
+    (MarkdownContent::new(text), marked)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DOCUMENT: &str = "\
+# Choose a supplier
+
+## Objectives
+
+- Lower cost
+
+## Consequences
+
+| | A | B |
+
+## Recommendation
+
+Go with A.
+";
+
+    #[test]
+    fn notes_affected_sections_only() {
+        let (content, marked) = mark_stale_sections(
+            &MarkdownContent::new(DOCUMENT),
+            &[ComponentType::Consequences, ComponentType::Recommendation],
+            "Objective weights changed",
+        );
+
+        assert_eq!(marked, vec!["Consequences", "Recommendation"]);
+        let text = content.as_str();
+        assert!(text.contains(
+            "## Recommendation\n\n> **Out of date:** Objective weights changed; this section will be updated.\n\nGo with A.\n"
+        ));
+        assert!(text.contains("## Objectives\n\n- Lower cost"));
+    }
+
+    #[test]
+    fn does_not_stack_notes() {
+        let components = [ComponentType::Recommendation];
+        let (once, _) = mark_stale_sections(
+            &MarkdownContent::new(DOCUMENT),
+            &components,
+            "Weights changed",
+        );
+        let (twice, marked) = mark_stale_sections(&once, &components, "Method changed");
+
+        assert!(marked.is_empty());
+        assert_eq!(once, twice);
+    }
+}
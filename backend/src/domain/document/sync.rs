@@ -0,0 +1,567 @@
+//! Section diffing - Maps edits in a Markdown document to component updates.
+//!
+//! The edited document is compared section by section with the document the
+//! user started from, never with a fresh render, so changes elsewhere in the
+//! cycle since the export are not mistaken for edits.
+//!
+//! | Section | Writable change |
+//! |---------|-----------------|
+//! | Decision | Problem Frame `decision_statement` |
+//! | Objectives | Renaming an objective |
+//! | Alternatives | Renaming or adding an alternative |
+//! | Recommendation | `synthesis` |
+//!
+//! Everything else (removing list items, adding objectives, editing the
+//! title or computed sections) is reported as a [`ParseError`].
+
+use serde::Serialize;
+use serde_json::{json, Value};
+
+use crate::domain::foundation::ComponentType;
+
+use super::markdown::{ParsedSection, SectionKind};
+
+/// How serious an unmapped edit is.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ParseSeverity {
+    /// Nothing was lost that the user is likely to care about.
+    Info,
+    /// The edit was ignored.
+    Warning,
+    /// The edit looked writable but was rejected.
+    Error,
+}
+
+/// An edit that could not be written back to a component.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ParseError {
+    pub severity: ParseSeverity,
+    /// Heading of the section the edit was in.
+    pub section: String,
+    /// 1-based line of the section heading in the edited document.
+    pub line: usize,
+    pub message: String,
+}
+
+impl ParseError {
+    pub fn new(
+        severity: ParseSeverity,
+        section: impl Into<String>,
+        line: usize,
+        message: impl Into<String>,
+    ) -> Self {
+        Self {
+            severity,
+            section: section.into(),
+            line,
+            message: message.into(),
+        }
+    }
+}
+
+impl std::fmt::Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} (line {}): {}", self.section, self.line, self.message)
+    }
+}
+
+/// A change to one field of a component's output.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum OutputChange {
+    DecisionStatement(Option<String>),
+    RenameObjective { from: String, to: String },
+    RenameAlternative { from: String, to: String },
+    AddAlternative { name: String },
+    Synthesis(String),
+}
+
+impl OutputChange {
+    /// Apply the change to a component output in its serialized form.
+    pub fn apply(&self, output: &mut Value) -> Result<(), String> {
+        match self {
+            OutputChange::DecisionStatement(statement) => {
+                output["decision_statement"] = json!(statement);
+                Ok(())
+            }
+            OutputChange::RenameObjective { from, to } => {
+                for key in ["fundamental_objectives", "means_objectives"] {
+                    let objectives = output.get_mut(key).and_then(Value::as_array_mut);
+                    if let Some(objective) = objectives.and_then(|list| {
+                        list.iter_mut()
+                            .find(|o| o["description"].as_str() == Some(from.as_str()))
+                    }) {
+                        objective["description"] = json!(to);
+                        return Ok(());
+                    }
+                }
+                Err(format!("Objective \"{}\" no longer exists", from))
+            }
+            OutputChange::RenameAlternative { from, to } => {
+                let alternative = output
+                    .get_mut("options")
+                    .and_then(Value::as_array_mut)
+                    .and_then(|options| {
+                        options
+                            .iter_mut()
+                            .find(|o| o["name"].as_str() == Some(from.as_str()))
+                    })
+                    .ok_or_else(|| format!("Alternative \"{}\" no longer exists", from))?;
+                alternative["name"] = json!(to);
+                Ok(())
+            }
+            OutputChange::AddAlternative { name } => {
+                if !output["options"].is_array() {
+                    output["options"] = json!([]);
+                }
+                if let Some(options) = output["options"].as_array_mut() {
+                    options.push(json!({
+                        "id": uuid::Uuid::new_v4().to_string(),
+                        "name": name,
+                        "description": "",
+                        "assumptions": [],
+                        "is_status_quo": false,
+                    }));
+                }
+                Ok(())
+            }
+            OutputChange::Synthesis(synthesis) => {
+                output["synthesis"] = json!(synthesis);
+                Ok(())
+            }
+        }
+    }
+}
+
+/// A writable edit, with where it came from for error reporting.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ComponentEdit {
+    pub component_type: ComponentType,
+    pub section: String,
+    pub line: usize,
+    pub change: OutputChange,
+}
+
+/// Result of comparing an edited document with its original.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct DocumentDiff {
+    pub edits: Vec<ComponentEdit>,
+    pub errors: Vec<ParseError>,
+}
+
+/// Compare edited sections with the originals.
+pub fn diff_sections(base: &[ParsedSection], edited: &[ParsedSection]) -> DocumentDiff {
+    let mut diff = DocumentDiff::default();
+
+    for original in base {
+        let mut matches = edited.iter().filter(|s| s.kind == original.kind);
+        let Some(section) = matches.next() else {
+            diff.errors.push(ParseError::new(
+                ParseSeverity::Warning,
+                &original.heading,
+                original.line,
+                "Section was removed; removing sections does not change the decision",
+            ));
+            continue;
+        };
+        if let Some(duplicate) = matches.next() {
+            diff.errors.push(ParseError::new(
+                ParseSeverity::Warning,
+                &duplicate.heading,
+                duplicate.line,
+                "Duplicate section ignored; only the first one is read",
+            ));
+        }
+        if section.body == original.body && section.heading == original.heading {
+            continue;
+        }
+        diff_section(original, section, &mut diff);
+    }
+
+    for section in edited {
+        if !base.iter().any(|s| s.kind == section.kind) {
+            diff.errors.push(ParseError::new(
+                ParseSeverity::Info,
+                &section.heading,
+                section.line,
+                "New section is kept in the document only",
+            ));
+        }
+    }
+
+    diff
+}
+
+fn diff_section(original: &ParsedSection, section: &ParsedSection, diff: &mut DocumentDiff) {
+    let Some(component_type) = section
+        .kind
+        .component_type()
+        .filter(|_| section.kind.is_editable())
+    else {
+        diff.errors.push(ParseError::new(
+            ParseSeverity::Warning,
+            section_name(section),
+            section.line,
+            "This section is generated from the analysis; edits to it are not saved",
+        ));
+        return;
+    };
+    let mut edit = |change| {
+        diff.edits.push(ComponentEdit {
+            component_type,
+            section: section.heading.clone(),
+            line: section.line,
+            change,
+        })
+    };
+
+    match section.kind {
+        SectionKind::Decision => {
+            let text = prose(&section.body);
+            if text != prose(&original.body) {
+                edit(OutputChange::DecisionStatement(
+                    Some(text).filter(|t| !t.is_empty()),
+                ));
+            }
+        }
+        SectionKind::Recommendation => {
+            let text = prose(&section.body);
+            if text != prose(&original.body) {
+                edit(OutputChange::Synthesis(text));
+            }
+        }
+        SectionKind::Objectives | SectionKind::Alternatives => {
+            let alternatives = section.kind == SectionKind::Alternatives;
+            let item = if alternatives {
+                alternative_name
+            } else {
+                objective_description
+            };
+            let (before, _) = list_items(&original.body, item);
+            let (after, free_text) = list_items(&section.body, item);
+            if free_text {
+                diff.errors.push(ParseError::new(
+                    ParseSeverity::Warning,
+                    &section.heading,
+                    section.line,
+                    "Text outside the bulleted list is not saved",
+                ));
+            }
+            for hunk in diff_lists(&before, &after) {
+                let renamed = hunk.removed.len().min(hunk.inserted.len());
+                for (from, to) in hunk.removed.iter().zip(&hunk.inserted) {
+                    let (from, to) = (from.clone(), to.clone());
+                    edit(if alternatives {
+                        OutputChange::RenameAlternative { from, to }
+                    } else {
+                        OutputChange::RenameObjective { from, to }
+                    });
+                }
+                for name in &hunk.inserted[renamed..] {
+                    if alternatives {
+                        edit(OutputChange::AddAlternative { name: name.clone() });
+                    } else {
+                        diff.errors.push(ParseError::new(
+                            ParseSeverity::Error,
+                            &section.heading,
+                            section.line,
+                            format!(
+                                "Objective \"{}\" needs a performance measure; add it in the Objectives step",
+                                name
+                            ),
+                        ));
+                    }
+                }
+                for name in &hunk.removed[renamed..] {
+                    diff.errors.push(ParseError::new(
+                        ParseSeverity::Error,
+                        &section.heading,
+                        section.line,
+                        format!(
+                            "\"{}\" is referenced by later steps and cannot be removed from the document",
+                            name
+                        ),
+                    ));
+                }
+            }
+        }
+        _ => {}
+    }
+}
+
+fn section_name(section: &ParsedSection) -> &str {
+    if section.heading.is_empty() {
+        "Title"
+    } else {
+        &section.heading
+    }
+}
+
+/// Section text without the generated emphasis lines: placeholders such as
+/// `_No recommendation yet._`, the caveat count, and the standout option.
+fn prose(body: &str) -> String {
+    body.lines()
+        .map(str::trim)
+        .filter(|line| !is_generated_line(line))
+        .collect::<Vec<_>>()
+        .join("\n")
+        .trim()
+        .to_string()
+}
+
+fn is_generated_line(line: &str) -> bool {
+    (line.len() > 1 && line.starts_with('_') && line.ends_with('_'))
+        || (line.starts_with("**Standout option:") && line.ends_with("**"))
+}
+
+/// Bullet items of a list section, and whether other text was present.
+fn list_items(body: &str, item: fn(&str) -> String) -> (Vec<String>, bool) {
+    let mut items = Vec::new();
+    let mut free_text = false;
+    for line in body.lines().map(str::trim) {
+        if let Some(text) = line.strip_prefix("- ").or_else(|| line.strip_prefix("* ")) {
+            items.push(item(text.trim()));
+        } else if !line.is_empty() && !is_generated_line(line) {
+            free_text = true;
+        }
+    }
+    (items, free_text)
+}
+
+/// `Lower cost (measured by annual spend) _[means]_` → `Lower cost`
+fn objective_description(text: &str) -> String {
+    let text = text.strip_suffix("_[means]_").unwrap_or(text).trim_end();
+    match text.rfind(" (measured by ") {
+        Some(index) if text.ends_with(')') => text[..index].trim().to_string(),
+        _ => text.to_string(),
+    }
+}
+
+/// `**New supplier** (status quo) — rank 1` → `New supplier`
+fn alternative_name(text: &str) -> String {
+    text.strip_prefix("**")
+        .and_then(|rest| rest.split_once("**"))
+        .map(|(name, _)| name.trim().to_string())
+        .unwrap_or_else(|| text.to_string())
+}
+
+/// A run of removed and inserted items between unchanged ones.
+#[derive(Debug, Default, PartialEq, Eq)]
+struct Hunk {
+    removed: Vec<String>,
+    inserted: Vec<String>,
+}
+
+/// Longest-common-subsequence diff of two lists, grouped into hunks.
+fn diff_lists(before: &[String], after: &[String]) -> Vec<Hunk> {
+    let (n, m) = (before.len(), after.len());
+    let mut lcs = vec![vec![0usize; m + 1]; n + 1];
+    for i in (0..n).rev() {
+        for j in (0..m).rev() {
+            lcs[i][j] = if before[i] == after[j] {
+                lcs[i + 1][j + 1] + 1
+            } else {
+                lcs[i + 1][j].max(lcs[i][j + 1])
+            };
+        }
+    }
+
+    let mut hunks = Vec::new();
+    let mut hunk = Hunk::default();
+    let (mut i, mut j) = (0, 0);
+    while i < n || j < m {
+        if i < n && j < m && before[i] == after[j] {
+            if hunk != Hunk::default() {
+                hunks.push(std::mem::take(&mut hunk));
+            }
+            i += 1;
+            j += 1;
+        } else if j < m && (i == n || lcs[i][j + 1] >= lcs[i + 1][j]) {
+            hunk.inserted.push(after[j].clone());
+            j += 1;
+        } else {
+            hunk.removed.push(before[i].clone());
+            i += 1;
+        }
+    }
+    if hunk != Hunk::default() {
+        hunks.push(hunk);
+    }
+    hunks
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::document::{MarkdownContent, MarkdownDocumentParser};
+
+    const BASE: &str = "\
+# Choose a supplier
+
+## Decision
+
+Which supplier should we use next year?
+
+## Objectives
+
+- Lower cost (measured by annual spend)
+- Reliable delivery _[means]_
+
+## Alternatives
+
+- **Current supplier** (status quo) — rank 2
+- **New supplier** — rank 1, Pugh score +2
+
+## Consequences
+
+| Objective | Current supplier | New supplier |
+
+## Recommendation
+
+**Standout option: New supplier**
+
+Switch suppliers at the end of the contract.
+
+_1 caveat noted._
+";
+
+    fn diff(edited: &str) -> DocumentDiff {
+        let parser = MarkdownDocumentParser::new();
+        diff_sections(
+            &parser.parse(&MarkdownContent::new(BASE)),
+            &parser.parse(&MarkdownContent::new(edited)),
+        )
+    }
+
+    fn changes(diff: &DocumentDiff) -> Vec<OutputChange> {
+        diff.edits.iter().map(|e| e.change.clone()).collect()
+    }
+
+    #[test]
+    fn unchanged_document_has_no_edits() {
+        assert_eq!(diff(BASE), DocumentDiff::default());
+    }
+
+    #[test]
+    fn decision_edit_updates_statement() {
+        let result = diff(&BASE.replace("next year?", "for 2027?"));
+        assert_eq!(
+            changes(&result),
+            vec![OutputChange::DecisionStatement(Some(
+                "Which supplier should we use for 2027?".to_string()
+            ))]
+        );
+        assert_eq!(result.edits[0].component_type, ComponentType::ProblemFrame);
+    }
+
+    #[test]
+    fn recommendation_edit_ignores_generated_lines() {
+        let result = diff(&BASE.replace("end of the contract", "end of Q2"));
+        assert_eq!(
+            changes(&result),
+            vec![OutputChange::Synthesis(
+                "Switch suppliers at the end of Q2.".to_string()
+            )]
+        );
+    }
+
+    #[test]
+    fn renamed_list_items_are_renames() {
+        let result = diff(
+            &BASE
+                .replace("Lower cost", "Lowest total cost")
+                .replace("**New supplier**", "**Acme Ltd**"),
+        );
+        assert_eq!(
+            changes(&result),
+            vec![
+                OutputChange::RenameObjective {
+                    from: "Lower cost".to_string(),
+                    to: "Lowest total cost".to_string()
+                },
+                OutputChange::RenameAlternative {
+                    from: "New supplier".to_string(),
+                    to: "Acme Ltd".to_string()
+                },
+            ]
+        );
+        assert!(result.errors.is_empty());
+    }
+
+    #[test]
+    fn added_alternative_is_mapped_but_added_objective_is_not() {
+        let result = diff(
+            &BASE
+                .replace(
+                    "- Reliable delivery _[means]_",
+                    "- Reliable delivery _[means]_\n- Low risk",
+                )
+                .replace("rank 1, Pugh score +2", "rank 1\n- **Build in-house**"),
+        );
+        assert_eq!(
+            changes(&result),
+            vec![OutputChange::AddAlternative {
+                name: "Build in-house".to_string()
+            }]
+        );
+        assert_eq!(result.errors.len(), 1);
+        assert_eq!(result.errors[0].severity, ParseSeverity::Error);
+        assert_eq!(result.errors[0].section, "Objectives");
+    }
+
+    #[test]
+    fn removed_items_are_errors() {
+        let result = diff(&BASE.replace("- Reliable delivery _[means]_\n", ""));
+        assert!(result.edits.is_empty());
+        assert_eq!(result.errors[0].severity, ParseSeverity::Error);
+        assert!(result.errors[0].message.contains("Reliable delivery"));
+    }
+
+    #[test]
+    fn generated_sections_are_warnings() {
+        let result = diff(&BASE.replace("| Objective |", "| Goal |"));
+        assert!(result.edits.is_empty());
+        assert_eq!(result.errors[0].severity, ParseSeverity::Warning);
+        assert_eq!(result.errors[0].section, "Consequences");
+    }
+
+    #[test]
+    fn new_sections_are_info() {
+        let result = diff(&format!("{}\n## Notes\n\nCall Acme first.\n", BASE));
+        assert_eq!(result.errors[0].severity, ParseSeverity::Info);
+        assert_eq!(result.errors[0].section, "Notes");
+    }
+
+    #[test]
+    fn output_changes_apply_to_serialized_outputs() {
+        let mut objectives = json!({
+            "fundamental_objectives": [{ "id": "o1", "description": "Lower cost" }],
+            "means_objectives": []
+        });
+        OutputChange::RenameObjective {
+            from: "Lower cost".to_string(),
+            to: "Lowest cost".to_string(),
+        }
+        .apply(&mut objectives)
+        .unwrap();
+        assert_eq!(
+            objectives["fundamental_objectives"][0]["description"],
+            "Lowest cost"
+        );
+
+        let mut alternatives = json!({ "options": [], "has_status_quo": false });
+        OutputChange::AddAlternative {
+            name: "Acme".to_string(),
+        }
+        .apply(&mut alternatives)
+        .unwrap();
+        assert_eq!(alternatives["options"][0]["name"], "Acme");
+
+        let missing = OutputChange::RenameAlternative {
+            from: "Gone".to_string(),
+            to: "Back".to_string(),
+        }
+        .apply(&mut alternatives);
+        assert!(missing.is_err());
+    }
+}
@@ -0,0 +1,361 @@
+//! TeamsShare - A cycle's recommendation posted to a Microsoft Teams channel.
+//!
+//! Teams channels are registered by an organization admin as incoming-webhook
+//! URLs, and `OrganizationChatSettings` records whether the organization's
+//! members share to Slack or to those channels. Shares snapshot the same
+//! `RecommendationDigest` as a `SlackShare` and retry with the same backoff.
+
+use secrecy::{ExposeSecret, Secret};
+use serde::{Deserialize, Serialize};
+
+use crate::domain::foundation::{CycleId, TeamsShareId, Timestamp, UserId};
+
+use super::delivery::{retry_delay_secs, DeliveryStatus, MAX_DELIVERY_ATTEMPTS};
+use super::slack_share::RecommendationDigest;
+
+/// Teams channels an organization can register.
+pub const MAX_TEAMS_CHANNELS: usize = 20;
+
+/// Hosts that serve Teams incoming webhooks: classic connectors and
+/// Workflows (Power Automate) triggers.
+const TEAMS_WEBHOOK_HOST_SUFFIXES: [&str; 2] = [".webhook.office.com", ".logic.azure.com"];
+
+/// Where an organization's members share recommendations.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ChatPlatform {
+    Slack,
+    Teams,
+}
+
+impl ChatPlatform {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ChatPlatform::Slack => "slack",
+            ChatPlatform::Teams => "teams",
+        }
+    }
+
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "slack" => Some(ChatPlatform::Slack),
+            "teams" => Some(ChatPlatform::Teams),
+            _ => None,
+        }
+    }
+}
+
+/// A Teams channel registered by its incoming-webhook URL.
+#[derive(Debug, Clone)]
+pub struct TeamsChannel {
+    /// Name members pick when sharing.
+    pub name: String,
+    /// Anyone holding the URL can post to the channel, so it is kept secret.
+    pub webhook_url: Secret<String>,
+}
+
+impl TeamsChannel {
+    /// Accepts only HTTPS URLs on Teams webhook hosts, so a share can never
+    /// be pointed at an arbitrary server.
+    pub fn new(
+        name: impl Into<String>,
+        webhook_url: impl Into<String>,
+    ) -> Result<Self, ChatSettingsError> {
+        let name = name.into().trim().to_string();
+        let webhook_url = webhook_url.into().trim().to_string();
+        if name.is_empty() {
+            return Err(ChatSettingsError::EmptyChannelName);
+        }
+        if teams_webhook_host(&webhook_url).is_none() {
+            return Err(ChatSettingsError::InvalidWebhookUrl(name));
+        }
+        Ok(Self {
+            name,
+            webhook_url: Secret::new(webhook_url),
+        })
+    }
+
+    /// The webhook's host, which identifies it without granting access.
+    pub fn webhook_host(&self) -> String {
+        teams_webhook_host(self.webhook_url.expose_secret()).unwrap_or_default()
+    }
+}
+
+/// The lowercased host of `url` if it is an HTTPS Teams webhook.
+fn teams_webhook_host(url: &str) -> Option<String> {
+    let rest = url.strip_prefix("https://")?;
+    let host = rest
+        .split(['/', '?', '#'])
+        .next()
+        .unwrap_or_default()
+        .to_ascii_lowercase();
+    let valid = !host.contains(['@', ':'])
+        && TEAMS_WEBHOOK_HOST_SUFFIXES
+            .iter()
+            .any(|suffix| host.ends_with(suffix));
+    valid.then_some(host)
+}
+
+/// Why organization chat settings were rejected.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum ChatSettingsError {
+    #[error("Channel name must not be empty")]
+    EmptyChannelName,
+
+    #[error("Channel '{0}' does not have a Teams webhook URL")]
+    InvalidWebhookUrl(String),
+
+    #[error("Channel '{0}' is listed more than once")]
+    DuplicateChannel(String),
+
+    #[error("At most {MAX_TEAMS_CHANNELS} Teams channels can be registered")]
+    TooManyChannels,
+
+    #[error("Teams requires at least one channel")]
+    NoTeamsChannels,
+}
+
+/// Which chat platform an organization's members share recommendations to.
+#[derive(Debug, Clone)]
+pub struct OrganizationChatSettings {
+    pub organization: String,
+    pub platform: ChatPlatform,
+    /// Registered channels; only used when `platform` is Teams.
+    pub teams_channels: Vec<TeamsChannel>,
+    pub updated_at: Timestamp,
+}
+
+impl OrganizationChatSettings {
+    pub fn new(
+        organization: impl Into<String>,
+        platform: ChatPlatform,
+        teams_channels: Vec<TeamsChannel>,
+    ) -> Result<Self, ChatSettingsError> {
+        if teams_channels.len() > MAX_TEAMS_CHANNELS {
+            return Err(ChatSettingsError::TooManyChannels);
+        }
+        for (i, channel) in teams_channels.iter().enumerate() {
+            if teams_channels[..i]
+                .iter()
+                .any(|other| other.name.eq_ignore_ascii_case(&channel.name))
+            {
+                return Err(ChatSettingsError::DuplicateChannel(channel.name.clone()));
+            }
+        }
+        if platform == ChatPlatform::Teams && teams_channels.is_empty() {
+            return Err(ChatSettingsError::NoTeamsChannels);
+        }
+        Ok(Self {
+            organization: organization.into(),
+            platform,
+            teams_channels,
+            updated_at: Timestamp::now(),
+        })
+    }
+
+    /// The registered Teams channel called `name`, ignoring case.
+    pub fn teams_channel(&self, name: &str) -> Option<&TeamsChannel> {
+        self.teams_channels
+            .iter()
+            .find(|channel| channel.name.eq_ignore_ascii_case(name.trim()))
+    }
+}
+
+/// One recommendation posted, or waiting to be posted, to a Teams channel.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TeamsShare {
+    pub id: TeamsShareId,
+    pub cycle_id: CycleId,
+    pub user_id: UserId,
+    /// Organization that registered the channel.
+    pub organization: String,
+    /// The channel's registered name; its webhook is looked up at send time.
+    pub channel: String,
+    pub digest: RecommendationDigest,
+    pub status: DeliveryStatus,
+    pub attempts: u32,
+    pub last_error: Option<String>,
+    /// When a pending share should next be attempted.
+    pub next_attempt_at: Timestamp,
+    pub created_at: Timestamp,
+    pub sent_at: Option<Timestamp>,
+}
+
+impl TeamsShare {
+    /// A pending share, due immediately.
+    pub fn new(
+        cycle_id: CycleId,
+        user_id: UserId,
+        organization: impl Into<String>,
+        channel: impl Into<String>,
+        digest: RecommendationDigest,
+    ) -> Self {
+        let now = Timestamp::now();
+        Self {
+            id: TeamsShareId::new(),
+            cycle_id,
+            user_id,
+            organization: organization.into(),
+            channel: channel.into(),
+            digest,
+            status: DeliveryStatus::Pending,
+            attempts: 0,
+            last_error: None,
+            next_attempt_at: now,
+            created_at: now,
+            sent_at: None,
+        }
+    }
+
+    /// Whether a retry worker should attempt this share at `now`.
+    pub fn is_due(&self, now: Timestamp) -> bool {
+        self.status == DeliveryStatus::Pending && !self.next_attempt_at.is_after(&now)
+    }
+
+    pub fn record_sent(&mut self) {
+        self.attempts += 1;
+        self.status = DeliveryStatus::Sent;
+        self.last_error = None;
+        self.sent_at = Some(Timestamp::now());
+    }
+
+    /// Records a failed attempt, scheduling a retry if `retryable` and
+    /// attempts remain.
+    pub fn record_failure(&mut self, error: impl Into<String>, retryable: bool) {
+        self.attempts += 1;
+        self.last_error = Some(error.into());
+        if retryable && self.attempts < MAX_DELIVERY_ATTEMPTS {
+            self.next_attempt_at = Timestamp::now().plus_secs(retry_delay_secs(self.attempts));
+        } else {
+            self.status = DeliveryStatus::Failed;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const WEBHOOK: &str = "https://acme.webhook.office.com/webhookb2/abc/IncomingWebhook/def";
+
+    fn share() -> TeamsShare {
+        TeamsShare::new(
+            CycleId::new(),
+            UserId::new("user-1").unwrap(),
+            "acme",
+            "Decisions",
+            RecommendationDigest {
+                title: "Choose a supplier".to_string(),
+                standout: Some("Acme".to_string()),
+                synthesis: "Acme wins on cost.".to_string(),
+                caveats: vec![],
+                link: "https://app.example.com/public/documents/abc".to_string(),
+                decision_quality: None,
+            },
+        )
+    }
+
+    #[test]
+    fn platforms_round_trip_through_strings() {
+        for platform in [ChatPlatform::Slack, ChatPlatform::Teams] {
+            assert_eq!(ChatPlatform::parse(platform.as_str()), Some(platform));
+        }
+    }
+
+    #[test]
+    fn teams_channels_require_a_teams_webhook_host() {
+        assert!(TeamsChannel::new("Decisions", WEBHOOK).is_ok());
+        assert!(TeamsChannel::new(
+            "Flows",
+            "https://prod-01.westus.logic.azure.com:443/workflows/abc"
+        )
+        .is_err());
+        assert!(TeamsChannel::new(
+            "Flows",
+            "https://prod-01.westus.logic.azure.com/workflows/abc"
+        )
+        .is_ok());
+
+        for url in [
+            "http://acme.webhook.office.com/webhookb2/abc",
+            "https://evil.example.com/webhook.office.com",
+            "https://acme.webhook.office.com@evil.example.com/",
+            "https://webhook.office.com.evil.example.com/",
+        ] {
+            assert_eq!(
+                TeamsChannel::new("Decisions", url).err(),
+                Some(ChatSettingsError::InvalidWebhookUrl(
+                    "Decisions".to_string()
+                )),
+                "{}",
+                url
+            );
+        }
+        assert_eq!(
+            TeamsChannel::new("  ", WEBHOOK).err(),
+            Some(ChatSettingsError::EmptyChannelName)
+        );
+    }
+
+    #[test]
+    fn webhook_urls_are_only_identified_by_host() {
+        let channel = TeamsChannel::new("Decisions", WEBHOOK).unwrap();
+
+        assert_eq!(channel.webhook_host(), "acme.webhook.office.com");
+        assert!(!format!("{:?}", channel).contains("IncomingWebhook"));
+    }
+
+    #[test]
+    fn teams_settings_need_distinct_channels() {
+        let channel = |name: &str| TeamsChannel::new(name, WEBHOOK).unwrap();
+
+        assert_eq!(
+            OrganizationChatSettings::new("acme", ChatPlatform::Teams, vec![]).err(),
+            Some(ChatSettingsError::NoTeamsChannels)
+        );
+        assert_eq!(
+            OrganizationChatSettings::new(
+                "acme",
+                ChatPlatform::Teams,
+                vec![channel("Decisions"), channel("decisions")]
+            )
+            .err(),
+            Some(ChatSettingsError::DuplicateChannel("decisions".to_string()))
+        );
+
+        let settings =
+            OrganizationChatSettings::new("acme", ChatPlatform::Teams, vec![channel("Decisions")])
+                .unwrap();
+        assert!(settings.teams_channel(" DECISIONS ").is_some());
+        assert!(settings.teams_channel("General").is_none());
+    }
+
+    #[test]
+    fn new_shares_are_due_immediately() {
+        let share = share();
+        assert_eq!(share.status, DeliveryStatus::Pending);
+        assert!(share.is_due(Timestamp::now()));
+    }
+
+    #[test]
+    fn failures_back_off_until_attempts_run_out() {
+        let mut share = share();
+
+        share.record_failure("429", true);
+        assert_eq!(share.status, DeliveryStatus::Pending);
+        assert!(!share.is_due(Timestamp::now()));
+
+        for _ in 1..MAX_DELIVERY_ATTEMPTS {
+            share.record_failure("429", true);
+        }
+        assert_eq!(share.status, DeliveryStatus::Failed);
+    }
+
+    #[test]
+    fn permanent_failures_stop_immediately() {
+        let mut share = share();
+        share.record_failure("webhook not found", false);
+        assert_eq!(share.status, DeliveryStatus::Failed);
+        assert_eq!(share.last_error.as_deref(), Some("webhook not found"));
+    }
+}
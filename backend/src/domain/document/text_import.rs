@@ -0,0 +1,448 @@
+//! Text import - AI-drafted component outputs from pasted notes.
+//!
+//! Users starting mid-decision often already have a pros/cons list or memo.
+//! The AI reads it and answers with tool calls (`add_objective`,
+//! `add_alternative`, `rate_consequence`, `batch_rate_consequences`), which
+//! are collected here into an `ImportDraft`. Nothing reaches the cycle until
+//! the user confirms the draft's `ConfirmationRequest`; applying it merges
+//! the drafted items into the existing outputs by name.
+//!
+//! The AI does not know IDs for items it has just drafted, so ratings name
+//! the alternative and objective instead. Drafted consequence cells carry
+//! [`AI_DRAFT_SOURCE`] as their source so they stay recognizable after
+//! confirmation.
+
+use serde::{Deserialize, Serialize};
+
+use crate::domain::conversation::tools::{
+    definitions::{
+        add_alternative_tool, add_objective_tool, batch_rate_consequences_tool,
+        rate_consequence_tool, AddAlternativeParams, AddObjectiveParams,
+        BatchRateConsequencesParams, ObjectiveDirection, RateConsequenceParams,
+    },
+    ConfirmationOption, ConfirmationRequest, ToolCall, ToolDefinition,
+};
+use crate::domain::foundation::{ConfirmationRequestId, CycleId, Rating, Timestamp, UserId};
+use crate::domain::proact::{
+    Alternative, AlternativesOutput, Cell, ConsequencesOutput, FundamentalObjective,
+    ObjectivesOutput, PerformanceMeasure,
+};
+
+/// Longest pasted text accepted, in characters.
+pub const MAX_IMPORT_TEXT_CHARS: usize = 20_000;
+
+/// Source recorded on consequence cells drafted from pasted text.
+pub const AI_DRAFT_SOURCE: &str = "AI draft from imported notes";
+
+/// Minutes a draft waits for confirmation (one week).
+const DRAFT_TTL_MINUTES: i64 = 7 * 24 * 60;
+
+/// Tools the AI may call when drafting from text.
+pub fn text_import_tools() -> Vec<ToolDefinition> {
+    vec![
+        add_objective_tool(),
+        add_alternative_tool(),
+        rate_consequence_tool(),
+        batch_rate_consequences_tool(),
+    ]
+}
+
+/// An objective the AI drafted.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DraftObjective {
+    pub name: String,
+    pub measure: String,
+    pub direction: ObjectiveDirection,
+}
+
+/// An alternative the AI drafted.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DraftAlternative {
+    pub name: String,
+    pub description: String,
+    pub is_status_quo: bool,
+}
+
+/// A consequence rating the AI drafted, by alternative and objective name.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DraftRating {
+    pub alternative: String,
+    pub objective: String,
+    pub rating: Rating,
+    pub reasoning: String,
+}
+
+/// AI-drafted objectives, alternatives, and consequences awaiting the
+/// user's confirmation.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ImportDraft {
+    pub cycle_id: CycleId,
+    pub created_by: UserId,
+    pub objectives: Vec<DraftObjective>,
+    pub alternatives: Vec<DraftAlternative>,
+    pub ratings: Vec<DraftRating>,
+    /// Tool calls that could not be used, with the reason.
+    pub skipped: Vec<String>,
+    /// Whether the user has applied or discarded the draft.
+    pub confirmation: ConfirmationRequest,
+    pub created_at: Timestamp,
+}
+
+/// Outputs with a confirmed draft merged in.
+#[derive(Debug, Clone)]
+pub struct AppliedDraft {
+    pub objectives: Option<ObjectivesOutput>,
+    pub alternatives: Option<AlternativesOutput>,
+    pub consequences: Option<ConsequencesOutput>,
+}
+
+impl ImportDraft {
+    /// Collects the AI's tool calls into a draft.
+    ///
+    /// Returns `None` if no call produced anything to import.
+    pub fn from_tool_calls(
+        cycle_id: CycleId,
+        created_by: UserId,
+        calls: Vec<ToolCall>,
+    ) -> Option<Self> {
+        let mut objectives: Vec<DraftObjective> = Vec::new();
+        let mut alternatives: Vec<DraftAlternative> = Vec::new();
+        let mut ratings = Vec::new();
+        let mut skipped = Vec::new();
+
+        for call in calls {
+            let name = call.name().to_string();
+            let result = match name.as_str() {
+                "add_objective" => parse::<AddObjectiveParams>(call).and_then(|p| {
+                    if !p.is_fundamental {
+                        return Err("means objectives are added in the Objectives step".into());
+                    }
+                    push_unique(
+                        &mut objectives,
+                        DraftObjective {
+                            name: p.name.trim().to_string(),
+                            measure: p.measure.trim().to_string(),
+                            direction: p.direction,
+                        },
+                        |o| &o.name,
+                    )
+                }),
+                "add_alternative" => parse::<AddAlternativeParams>(call).and_then(|p| {
+                    push_unique(
+                        &mut alternatives,
+                        DraftAlternative {
+                            name: p.name.trim().to_string(),
+                            description: p.description.trim().to_string(),
+                            is_status_quo: p.is_status_quo,
+                        },
+                        |a| &a.name,
+                    )
+                }),
+                "rate_consequence" => parse::<RateConsequenceParams>(call).and_then(|p| {
+                    ratings.push(rating(
+                        p.alternative_id,
+                        p.objective_id,
+                        p.rating,
+                        p.reasoning,
+                    )?);
+                    Ok(())
+                }),
+                "batch_rate_consequences" => {
+                    parse::<BatchRateConsequencesParams>(call).and_then(|p| {
+                        for r in p.ratings {
+                            ratings.push(rating(
+                                r.alternative_id,
+                                r.objective_id,
+                                r.rating,
+                                r.reasoning,
+                            )?);
+                        }
+                        Ok(())
+                    })
+                }
+                _ => Err("not available when importing text".to_string()),
+            };
+            if let Err(reason) = result {
+                skipped.push(format!("{}: {}", name, reason));
+            }
+        }
+
+        // Ratings are kept even if they name nothing drafted: they may match
+        // an alternative or objective the cycle already has.
+        if objectives.is_empty() && alternatives.is_empty() && ratings.is_empty() {
+            return None;
+        }
+
+        let summary = format!(
+            "Drafted {} objective(s), {} alternative(s), and {} rating(s) from your notes. \
+             Apply them to this cycle?",
+            objectives.len(),
+            alternatives.len(),
+            ratings.len()
+        );
+        let confirmation = ConfirmationRequest::new(
+            cycle_id,
+            0,
+            summary,
+            vec![
+                ConfirmationOption::new("Apply", "Add the drafted items to the cycle"),
+                ConfirmationOption::new("Discard", "Leave the cycle unchanged"),
+            ],
+            Some(0),
+            DRAFT_TTL_MINUTES,
+        );
+
+        Some(Self {
+            cycle_id,
+            created_by,
+            objectives,
+            alternatives,
+            ratings,
+            skipped,
+            confirmation,
+            created_at: Timestamp::now(),
+        })
+    }
+
+    /// The draft's ID, shared with its confirmation request.
+    pub fn id(&self) -> ConfirmationRequestId {
+        self.confirmation.id()
+    }
+
+    /// Merges the draft into the current outputs.
+    ///
+    /// Items whose names already exist (ignoring case) are reused rather than
+    /// duplicated. Ratings naming an unknown alternative or objective are
+    /// dropped. Outputs the draft does not change are `None`.
+    pub fn apply(
+        &self,
+        objectives: &ObjectivesOutput,
+        alternatives: &AlternativesOutput,
+        consequences: &ConsequencesOutput,
+    ) -> AppliedDraft {
+        let mut objectives = objectives.clone();
+        let mut objectives_changed = false;
+        for drafted in &self.objectives {
+            if objectives
+                .fundamental_objectives
+                .iter()
+                .any(|o| o.description.eq_ignore_ascii_case(&drafted.name))
+            {
+                continue;
+            }
+            objectives
+                .fundamental_objectives
+                .push(FundamentalObjective {
+                    id: uuid::Uuid::new_v4().to_string(),
+                    description: drafted.name.clone(),
+                    performance_measure: PerformanceMeasure {
+                        description: drafted.measure.clone(),
+                        is_quantitative: false,
+                        unit: None,
+                        direction: direction_label(drafted.direction).to_string(),
+                        value_function: None,
+                    },
+                    affected_party_id: None,
+                    weight: None,
+                });
+            objectives_changed = true;
+        }
+
+        let mut alternatives = alternatives.clone();
+        let mut alternatives_changed = false;
+        for drafted in &self.alternatives {
+            if alternatives
+                .options
+                .iter()
+                .any(|a| a.name.eq_ignore_ascii_case(&drafted.name))
+            {
+                continue;
+            }
+            alternatives.options.push(Alternative {
+                id: uuid::Uuid::new_v4().to_string(),
+                name: drafted.name.clone(),
+                description: drafted.description.clone(),
+                assumptions: vec![],
+                is_status_quo: drafted.is_status_quo && !alternatives.has_status_quo,
+            });
+            alternatives.has_status_quo = alternatives.options.iter().any(|a| a.is_status_quo);
+            alternatives_changed = true;
+        }
+
+        let mut consequences = consequences.clone();
+        let mut consequences_changed = false;
+        for drafted in &self.ratings {
+            let alternative = alternatives
+                .options
+                .iter()
+                .find(|a| a.name.eq_ignore_ascii_case(&drafted.alternative));
+            let objective = objectives
+                .fundamental_objectives
+                .iter()
+                .find(|o| o.description.eq_ignore_ascii_case(&drafted.objective));
+            let (Some(alternative), Some(objective)) = (alternative, objective) else {
+                continue;
+            };
+
+            let table = &mut consequences.table;
+            if !table.alternative_ids.contains(&alternative.id) {
+                table.alternative_ids.push(alternative.id.clone());
+            }
+            if !table.objective_ids.contains(&objective.id) {
+                table.objective_ids.push(objective.id.clone());
+            }
+            table
+                .cells
+                .entry(alternative.id.clone())
+                .or_default()
+                .insert(
+                    objective.id.clone(),
+                    Cell::new(drafted.rating, drafted.reasoning.clone())
+                        .with_source(AI_DRAFT_SOURCE),
+                );
+            consequences_changed = true;
+        }
+
+        AppliedDraft {
+            objectives: objectives_changed.then_some(objectives),
+            alternatives: alternatives_changed.then_some(alternatives),
+            consequences: consequences_changed.then_some(consequences),
+        }
+    }
+}
+
+fn parse<T: serde::de::DeserializeOwned>(call: ToolCall) -> Result<T, String> {
+    serde_json::from_value(call.into_parameters()).map_err(|e| e.to_string())
+}
+
+fn push_unique<T>(items: &mut Vec<T>, item: T, name: impl Fn(&T) -> &String) -> Result<(), String> {
+    if name(&item).is_empty() {
+        return Err("name is empty".to_string());
+    }
+    if items
+        .iter()
+        .any(|existing| name(existing).eq_ignore_ascii_case(name(&item)))
+    {
+        return Err(format!("\"{}\" was drafted twice", name(&item)));
+    }
+    items.push(item);
+    Ok(())
+}
+
+fn rating(
+    alternative: String,
+    objective: String,
+    value: i8,
+    reasoning: String,
+) -> Result<DraftRating, String> {
+    Ok(DraftRating {
+        rating: Rating::try_from_i8(value).map_err(|e| e.to_string())?,
+        alternative: alternative.trim().to_string(),
+        objective: objective.trim().to_string(),
+        reasoning: reasoning.trim().to_string(),
+    })
+}
+
+fn direction_label(direction: ObjectiveDirection) -> &'static str {
+    match direction {
+        ObjectiveDirection::Higher => "higher_is_better",
+        ObjectiveDirection::Lower => "lower_is_better",
+        ObjectiveDirection::Target => "target",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn calls() -> Vec<ToolCall> {
+        vec![
+            ToolCall::new(
+                "add_objective",
+                json!({"name": "Commute", "measure": "Minutes per day", "direction": "lower", "is_fundamental": true}),
+            ),
+            ToolCall::new(
+                "add_alternative",
+                json!({"name": "Move downtown", "description": "Rent near the office", "is_status_quo": false}),
+            ),
+            ToolCall::new(
+                "rate_consequence",
+                json!({"alternative_id": "Move downtown", "objective_id": "Commute", "rating": 2, "reasoning": "Walkable", "confidence": "medium"}),
+            ),
+            ToolCall::new("complete_component", json!({})),
+        ]
+    }
+
+    fn draft() -> ImportDraft {
+        ImportDraft::from_tool_calls(CycleId::new(), UserId::new("user-1").unwrap(), calls())
+            .unwrap()
+    }
+
+    #[test]
+    fn collects_tool_calls_into_a_pending_draft() {
+        let draft = draft();
+
+        assert_eq!(draft.objectives.len(), 1);
+        assert_eq!(draft.alternatives[0].name, "Move downtown");
+        assert_eq!(draft.ratings[0].rating, Rating::MuchBetter);
+        assert_eq!(draft.skipped.len(), 1);
+        assert!(draft.skipped[0].starts_with("complete_component"));
+        assert!(draft.confirmation.is_pending());
+    }
+
+    #[test]
+    fn skips_invalid_calls_and_returns_none_when_nothing_is_left() {
+        let calls = vec![ToolCall::new(
+            "rate_consequence",
+            json!({"alternative_id": "A", "objective_id": "B", "rating": 5, "reasoning": "", "confidence": "low"}),
+        )];
+        assert!(
+            ImportDraft::from_tool_calls(CycleId::new(), UserId::new("u").unwrap(), calls)
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn applies_drafted_items_and_marks_cells_as_ai_drafted() {
+        let applied = draft().apply(
+            &ObjectivesOutput::default(),
+            &AlternativesOutput::default(),
+            &ConsequencesOutput::default(),
+        );
+
+        let objectives = applied.objectives.unwrap();
+        let alternatives = applied.alternatives.unwrap();
+        let consequences = applied.consequences.unwrap();
+        let objective = &objectives.fundamental_objectives[0];
+        assert_eq!(objective.performance_measure.direction, "lower_is_better");
+        let cell = &consequences.table.cells[&alternatives.options[0].id][&objective.id];
+        assert_eq!(cell.rating, Rating::MuchBetter);
+        assert_eq!(cell.source.as_deref(), Some(AI_DRAFT_SOURCE));
+    }
+
+    #[test]
+    fn reuses_existing_items_with_the_same_name() {
+        let existing = AlternativesOutput {
+            options: vec![Alternative {
+                id: "alt-1".to_string(),
+                name: "move downtown".to_string(),
+                description: String::new(),
+                assumptions: vec![],
+                is_status_quo: false,
+            }],
+            strategy_table: None,
+            has_status_quo: false,
+        };
+
+        let applied = draft().apply(
+            &ObjectivesOutput::default(),
+            &existing,
+            &ConsequencesOutput::default(),
+        );
+
+        assert!(applied.alternatives.is_none());
+        let consequences = applied.consequences.unwrap();
+        assert!(consequences.table.cells.contains_key("alt-1"));
+    }
+}
@@ -0,0 +1,480 @@
+//! DocumentVersion - Stored history of a cycle's Markdown decision document.
+//!
+//! Versions are numbered from 1 per cycle. Comparing two versions produces a
+//! section-level summary (which headings were added, removed, or changed) and
+//! a unified line diff of the whole document.
+//!
+//! [`section_patches`] gives the changed sections with their new text, for
+//! sending an edit to collaborators, and [`overlapping_sections`] finds
+//! sections two concurrent edits both touched.
+
+use std::collections::HashMap;
+use std::fmt::Write as _;
+
+use serde::{Deserialize, Serialize};
+
+use crate::domain::foundation::{CycleId, Timestamp};
+
+use super::markdown::{MarkdownContent, MarkdownDocumentParser, ParsedSection, SectionKind};
+
+/// Unchanged lines shown around each change in the unified diff.
+const CONTEXT_LINES: usize = 3;
+
+/// One stored revision of a cycle's decision document.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DocumentVersion {
+    pub cycle_id: CycleId,
+    /// 1-based, increasing per cycle.
+    pub version: u32,
+    pub content: MarkdownContent,
+    pub created_at: Timestamp,
+}
+
+impl DocumentVersion {
+    pub fn new(cycle_id: CycleId, version: u32, content: MarkdownContent) -> Self {
+        Self {
+            cycle_id,
+            version,
+            content,
+            created_at: Timestamp::now(),
+        }
+    }
+
+    /// Parses a version label as used in URLs: `v3` or `3`.
+    pub fn parse_label(label: &str) -> Option<u32> {
+        let digits = label
+            .trim()
+            .strip_prefix(['v', 'V'])
+            .unwrap_or(label.trim());
+        digits.parse().ok().filter(|v| *v > 0)
+    }
+
+    /// `v3`
+    pub fn label(&self) -> String {
+        format!("v{}", self.version)
+    }
+}
+
+/// How a section differs between two versions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SectionChangeKind {
+    Added,
+    Removed,
+    Modified,
+}
+
+/// Summary of one changed section.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct SectionChange {
+    /// Heading text; empty for the untitled preamble.
+    pub heading: String,
+    pub change: SectionChangeKind,
+    pub lines_added: usize,
+    pub lines_removed: usize,
+}
+
+/// Difference between two versions of a document.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct VersionDiff {
+    pub from_version: u32,
+    pub to_version: u32,
+    /// Changed sections, in the order they appear in the newer version, with
+    /// removed sections last.
+    pub sections: Vec<SectionChange>,
+    /// Unified diff of the Markdown source, with `@@` hunk headers.
+    pub unified: String,
+}
+
+impl VersionDiff {
+    /// Compares `from` with `to`. Sections are matched by heading, ignoring
+    /// case, so a moved section is reported as modified only if its text
+    /// changed.
+    pub fn between(from: &DocumentVersion, to: &DocumentVersion) -> Self {
+        let parser = MarkdownDocumentParser::new();
+        let before = parser.parse(&from.content);
+        let after = parser.parse(&to.content);
+
+        let mut remaining: HashMap<String, &ParsedSection> =
+            before.iter().map(|s| (section_key(s), s)).collect();
+        let mut sections = Vec::new();
+        for section in &after {
+            match remaining.remove(&section_key(section)) {
+                Some(old) => {
+                    let (added, removed) = count_changes(&old.body, &section.body);
+                    if added + removed > 0 || old.heading != section.heading {
+                        sections.push(SectionChange {
+                            heading: section.heading.clone(),
+                            change: SectionChangeKind::Modified,
+                            lines_added: added,
+                            lines_removed: removed,
+                        });
+                    }
+                }
+                None => sections.push(SectionChange {
+                    heading: section.heading.clone(),
+                    change: SectionChangeKind::Added,
+                    lines_added: body_lines(&section.body),
+                    lines_removed: 0,
+                }),
+            }
+        }
+        for section in &before {
+            if remaining.contains_key(&section_key(section)) {
+                sections.push(SectionChange {
+                    heading: section.heading.clone(),
+                    change: SectionChangeKind::Removed,
+                    lines_added: 0,
+                    lines_removed: body_lines(&section.body),
+                });
+            }
+        }
+
+        Self {
+            from_version: from.version,
+            to_version: to.version,
+            sections,
+            unified: unified_diff(from.content.as_str(), to.content.as_str()),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.sections.is_empty()
+    }
+}
+
+/// One changed section with its new text.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SectionPatch {
+    /// Heading text; empty for the untitled preamble.
+    pub heading: String,
+    pub change: SectionChangeKind,
+    /// The section body after the change; `None` when removed.
+    pub content: Option<String>,
+}
+
+/// The sections that differ between two documents, matched by heading as
+/// in [`VersionDiff::between`], with removed sections last.
+pub fn section_patches(before: &MarkdownContent, after: &MarkdownContent) -> Vec<SectionPatch> {
+    let parser = MarkdownDocumentParser::new();
+    let before = parser.parse(before);
+    let after = parser.parse(after);
+
+    let mut remaining: HashMap<String, &ParsedSection> =
+        before.iter().map(|s| (section_key(s), s)).collect();
+    let mut patches = Vec::new();
+    for section in &after {
+        let change = match remaining.remove(&section_key(section)) {
+            Some(old) if old.body == section.body && old.heading == section.heading => continue,
+            Some(_) => SectionChangeKind::Modified,
+            None => SectionChangeKind::Added,
+        };
+        patches.push(SectionPatch {
+            heading: section.heading.clone(),
+            change,
+            content: Some(section.body.clone()),
+        });
+    }
+    for section in &before {
+        if remaining.contains_key(&section_key(section)) {
+            patches.push(SectionPatch {
+                heading: section.heading.clone(),
+                change: SectionChangeKind::Removed,
+                content: None,
+            });
+        }
+    }
+    patches
+}
+
+/// Headings (as written in `ours`) of sections changed by both edits,
+/// ignoring case.
+pub fn overlapping_sections(ours: &[SectionPatch], theirs: &[SectionPatch]) -> Vec<String> {
+    ours.iter()
+        .filter(|patch| {
+            theirs
+                .iter()
+                .any(|other| other.heading.to_lowercase() == patch.heading.to_lowercase())
+        })
+        .map(|patch| patch.heading.clone())
+        .collect()
+}
+
+fn section_key(section: &ParsedSection) -> String {
+    // The preamble always matches the preamble, whatever its title
+    if section.kind == SectionKind::Title {
+        String::new()
+    } else {
+        section.heading.to_lowercase()
+    }
+}
+
+fn body_lines(body: &str) -> usize {
+    body.lines().filter(|l| !l.trim().is_empty()).count()
+}
+
+fn count_changes(before: &str, after: &str) -> (usize, usize) {
+    let before: Vec<&str> = before.lines().collect();
+    let after: Vec<&str> = after.lines().collect();
+    line_diff(&before, &after)
+        .iter()
+        .fold((0, 0), |(added, removed), line| match line {
+            DiffLine::Added(_) => (added + 1, removed),
+            DiffLine::Removed(_) => (added, removed + 1),
+            DiffLine::Same(_) => (added, removed),
+        })
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum DiffLine<'a> {
+    Same(&'a str),
+    Added(&'a str),
+    Removed(&'a str),
+}
+
+/// Longest-common-subsequence line diff, removals before additions.
+fn line_diff<'a>(before: &[&'a str], after: &[&'a str]) -> Vec<DiffLine<'a>> {
+    let (n, m) = (before.len(), after.len());
+    // lcs[i][j] = LCS length of before[i..] and after[j..]
+    let mut lcs = vec![vec![0usize; m + 1]; n + 1];
+    for i in (0..n).rev() {
+        for j in (0..m).rev() {
+            lcs[i][j] = if before[i] == after[j] {
+                lcs[i + 1][j + 1] + 1
+            } else {
+                lcs[i + 1][j].max(lcs[i][j + 1])
+            };
+        }
+    }
+
+    let mut lines = Vec::with_capacity(n.max(m));
+    let (mut i, mut j) = (0, 0);
+    while i < n || j < m {
+        if i < n && j < m && before[i] == after[j] {
+            lines.push(DiffLine::Same(before[i]));
+            i += 1;
+            j += 1;
+        } else if i < n && (j == m || lcs[i + 1][j] >= lcs[i][j + 1]) {
+            lines.push(DiffLine::Removed(before[i]));
+            i += 1;
+        } else {
+            lines.push(DiffLine::Added(after[j]));
+            j += 1;
+        }
+    }
+    lines
+}
+
+/// Renders a unified diff with [`CONTEXT_LINES`] of context per hunk.
+fn unified_diff(before: &str, after: &str) -> String {
+    let before: Vec<&str> = before.lines().collect();
+    let after: Vec<&str> = after.lines().collect();
+    let lines = line_diff(&before, &after);
+
+    let changed: Vec<usize> = lines
+        .iter()
+        .enumerate()
+        .filter(|(_, line)| !matches!(line, DiffLine::Same(_)))
+        .map(|(index, _)| index)
+        .collect();
+    if changed.is_empty() {
+        return String::new();
+    }
+
+    // Group changes whose context windows touch into one hunk
+    let mut hunks: Vec<(usize, usize)> = Vec::new();
+    for index in changed {
+        let start = index.saturating_sub(CONTEXT_LINES);
+        let end = (index + CONTEXT_LINES + 1).min(lines.len());
+        match hunks.last_mut() {
+            Some(last) if start <= last.1 => last.1 = end,
+            _ => hunks.push((start, end)),
+        }
+    }
+
+    // 1-based line numbers in each file at the start of every diff line
+    let mut positions = Vec::with_capacity(lines.len());
+    let (mut old_line, mut new_line) = (1, 1);
+    for line in &lines {
+        positions.push((old_line, new_line));
+        match line {
+            DiffLine::Same(_) => {
+                old_line += 1;
+                new_line += 1;
+            }
+            DiffLine::Removed(_) => old_line += 1,
+            DiffLine::Added(_) => new_line += 1,
+        }
+    }
+
+    let mut out = String::new();
+    for (start, end) in hunks {
+        let hunk = &lines[start..end];
+        let old_count = hunk
+            .iter()
+            .filter(|l| !matches!(l, DiffLine::Added(_)))
+            .count();
+        let new_count = hunk
+            .iter()
+            .filter(|l| !matches!(l, DiffLine::Removed(_)))
+            .count();
+        let (old_start, new_start) = positions[start];
+        let _ = writeln!(
+            out,
+            "@@ -{},{} +{},{} @@",
+            old_start, old_count, new_start, new_count
+        );
+        for line in hunk {
+            let _ = match line {
+                DiffLine::Same(text) => writeln!(out, " {}", text),
+                DiffLine::Removed(text) => writeln!(out, "-{}", text),
+                DiffLine::Added(text) => writeln!(out, "+{}", text),
+            };
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const V1: &str = "\
+# Choose a supplier
+
+## Decision
+
+Which supplier should we use?
+
+## Alternatives
+
+- **Current supplier** (status quo)
+
+## Notes
+
+Call Acme back.
+";
+
+    fn version(number: u32, content: &str) -> DocumentVersion {
+        DocumentVersion::new(CycleId::new(), number, MarkdownContent::new(content))
+    }
+
+    #[test]
+    fn parses_version_labels() {
+        assert_eq!(DocumentVersion::parse_label("v3"), Some(3));
+        assert_eq!(DocumentVersion::parse_label("7"), Some(7));
+        assert_eq!(DocumentVersion::parse_label("v0"), None);
+        assert_eq!(DocumentVersion::parse_label("latest"), None);
+        assert_eq!(version(4, V1).label(), "v4");
+    }
+
+    #[test]
+    fn identical_versions_have_no_changes() {
+        let diff = VersionDiff::between(&version(1, V1), &version(2, V1));
+        assert!(diff.is_empty());
+        assert_eq!(diff.unified, "");
+    }
+
+    #[test]
+    fn summarizes_section_changes() {
+        let v2 = V1
+            .replace(
+                "- **Current supplier** (status quo)",
+                "- **Current supplier** (status quo)\n- **Acme Ltd**",
+            )
+            .replace(
+                "## Notes\n\nCall Acme back.\n",
+                "## Risks\n\nAcme is new.\n",
+            );
+
+        let diff = VersionDiff::between(&version(1, V1), &version(2, &v2));
+
+        assert_eq!(
+            diff.sections,
+            vec![
+                SectionChange {
+                    heading: "Alternatives".to_string(),
+                    change: SectionChangeKind::Modified,
+                    lines_added: 1,
+                    lines_removed: 0,
+                },
+                SectionChange {
+                    heading: "Risks".to_string(),
+                    change: SectionChangeKind::Added,
+                    lines_added: 1,
+                    lines_removed: 0,
+                },
+                SectionChange {
+                    heading: "Notes".to_string(),
+                    change: SectionChangeKind::Removed,
+                    lines_added: 0,
+                    lines_removed: 1,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn renders_unified_hunks_with_context() {
+        let v2 = V1.replace("should we use?", "should we use in 2027?");
+
+        let diff = VersionDiff::between(&version(1, V1), &version(2, &v2));
+
+        let expected = [
+            "@@ -2,7 +2,7 @@",
+            " ",
+            " ## Decision",
+            " ",
+            "-Which supplier should we use?",
+            "+Which supplier should we use in 2027?",
+            " ",
+            " ## Alternatives",
+            " ",
+        ];
+        assert_eq!(diff.unified, expected.join("\n") + "\n");
+    }
+
+    #[test]
+    fn distant_changes_get_separate_hunks() {
+        let v2 = V1
+            .replace("# Choose a supplier", "# Choose a vendor")
+            .replace("Call Acme back.", "Acme called.");
+
+        let diff = VersionDiff::between(&version(1, V1), &version(2, &v2));
+
+        assert_eq!(diff.unified.matches("@@ -").count(), 2);
+        assert!(diff
+            .unified
+            .contains("-# Choose a supplier\n+# Choose a vendor\n"));
+        assert!(diff.sections.iter().any(|s| s.heading == "Choose a vendor"));
+    }
+
+    #[test]
+    fn patches_carry_new_section_text_and_find_overlaps() {
+        let ours = V1
+            .replace("should we use?", "should we use in 2027?")
+            .replace("## Notes\n\nCall Acme back.\n", "");
+        let theirs = V1.replace("should we use?", "should we pick?");
+        let before = MarkdownContent::new(V1);
+
+        let patches = section_patches(&before, &MarkdownContent::new(ours));
+        assert_eq!(
+            patches,
+            vec![
+                SectionPatch {
+                    heading: "Decision".to_string(),
+                    change: SectionChangeKind::Modified,
+                    content: Some("Which supplier should we use in 2027?".to_string()),
+                },
+                SectionPatch {
+                    heading: "Notes".to_string(),
+                    change: SectionChangeKind::Removed,
+                    content: None,
+                },
+            ]
+        );
+
+        let concurrent = section_patches(&before, &MarkdownContent::new(theirs));
+        assert_eq!(overlapping_sections(&patches, &concurrent), vec!["Decision"]);
+    }
+}
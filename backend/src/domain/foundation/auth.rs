@@ -1,6 +1,7 @@
 //! Authentication types for the domain layer.
 //!
-//! These types represent an authenticated user extracted from a JWT token.
+//! These types represent an authenticated user or service extracted from a
+//! JWT token.
 //! They have **no external dependencies** - any auth provider (Zitadel, Auth0,
 //! Keycloak) can populate them via the `SessionValidator` port.
 //!
@@ -19,13 +20,15 @@
 //!     email: "user@example.com".to_string(),
 //!     display_name: Some("Alice".to_string()),
 //!     email_verified: true,
+//!     locale: Some("es".to_string()),
+//!     issued_at: None,
 //! };
 //!
 //! // Inject into request extensions for handlers to use
 //! request.extensions_mut().insert(user);
 //! ```
 
-use super::UserId;
+use super::{Timestamp, UserId};
 use thiserror::Error;
 
 /// Authenticated user extracted from a validated JWT.
@@ -45,6 +48,14 @@ pub struct AuthenticatedUser {
 
     /// Whether the user's email has been verified by the auth provider.
     pub email_verified: bool,
+
+    /// Preferred locale from the user's profile (`locale` claim), if set.
+    pub locale: Option<String>,
+
+    /// When the token was issued (`iat` claim), if the provider set it.
+    ///
+    /// Used to tell tokens issued before a logout-all apart from new ones.
+    pub issued_at: Option<Timestamp>,
 }
 
 impl AuthenticatedUser {
@@ -63,15 +74,118 @@ impl AuthenticatedUser {
             email: email.into(),
             display_name,
             email_verified,
+            locale: None,
+            issued_at: None,
         }
     }
 
+    /// Sets the user's preferred locale.
+    pub fn with_locale(mut self, locale: impl Into<String>) -> Self {
+        self.locale = Some(locale.into());
+        self
+    }
+
+    /// Sets when the user's token was issued.
+    pub fn with_issued_at(mut self, issued_at: Timestamp) -> Self {
+        self.issued_at = Some(issued_at);
+        self
+    }
+
     /// Returns the user's display name, or email as fallback.
     pub fn display_name_or_email(&self) -> &str {
         self.display_name.as_deref().unwrap_or(&self.email)
     }
 }
 
+/// Machine client authenticated with a client-credentials token.
+///
+/// Integration workers authenticate as themselves rather than impersonating
+/// a user. They carry no email or profile, only the OAuth client ID and the
+/// scopes the auth provider granted.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ServicePrincipal {
+    /// OAuth client ID of the service.
+    pub client_id: String,
+
+    /// Scopes granted to the token (e.g. `sessions:read`).
+    pub scopes: Vec<String>,
+}
+
+impl ServicePrincipal {
+    /// Creates a new service principal.
+    pub fn new(
+        client_id: impl Into<String>,
+        scopes: impl IntoIterator<Item = impl Into<String>>,
+    ) -> Self {
+        Self {
+            client_id: client_id.into(),
+            scopes: scopes.into_iter().map(Into::into).collect(),
+        }
+    }
+
+    /// Returns true if the token was granted `scope`.
+    pub fn has_scope(&self, scope: &str) -> bool {
+        self.scopes.iter().any(|s| s == scope)
+    }
+
+    /// Fails with `InsufficientPermissions` unless `scope` was granted.
+    pub fn require_scope(&self, scope: &str) -> Result<(), AuthError> {
+        if self.has_scope(scope) {
+            Ok(())
+        } else {
+            Err(AuthError::InsufficientPermissions)
+        }
+    }
+}
+
+/// Who made a request: a human user or a machine client.
+#[derive(Debug, Clone)]
+pub enum AuthenticatedPrincipal {
+    /// A human user signed in through the auth provider.
+    User(AuthenticatedUser),
+
+    /// A service using a client-credentials token.
+    Service(ServicePrincipal),
+}
+
+impl AuthenticatedPrincipal {
+    /// Returns the user, if this principal is one.
+    pub fn as_user(&self) -> Option<&AuthenticatedUser> {
+        match self {
+            AuthenticatedPrincipal::User(user) => Some(user),
+            AuthenticatedPrincipal::Service(_) => None,
+        }
+    }
+
+    /// Returns the service, if this principal is one.
+    pub fn as_service(&self) -> Option<&ServicePrincipal> {
+        match self {
+            AuthenticatedPrincipal::User(_) => None,
+            AuthenticatedPrincipal::Service(service) => Some(service),
+        }
+    }
+
+    /// Stable identifier for logs and audit trails: the user ID or client ID.
+    pub fn subject(&self) -> &str {
+        match self {
+            AuthenticatedPrincipal::User(user) => user.id.as_str(),
+            AuthenticatedPrincipal::Service(service) => &service.client_id,
+        }
+    }
+}
+
+impl From<AuthenticatedUser> for AuthenticatedPrincipal {
+    fn from(user: AuthenticatedUser) -> Self {
+        AuthenticatedPrincipal::User(user)
+    }
+}
+
+impl From<ServicePrincipal> for AuthenticatedPrincipal {
+    fn from(service: ServicePrincipal) -> Self {
+        AuthenticatedPrincipal::Service(service)
+    }
+}
+
 /// Authentication errors that can occur during token validation.
 ///
 /// These errors are **domain-centric** - they describe what went wrong
@@ -86,6 +200,10 @@ pub enum AuthError {
     #[error("Token expired")]
     TokenExpired,
 
+    /// The token is valid but was revoked (e.g. by logging out everywhere).
+    #[error("Token revoked")]
+    TokenRevoked,
+
     /// Token is valid but the user no longer exists in the system.
     #[error("User not found")]
     UserNotFound,
@@ -109,7 +227,10 @@ impl AuthError {
     pub fn requires_reauthentication(&self) -> bool {
         matches!(
             self,
-            AuthError::InvalidToken | AuthError::TokenExpired | AuthError::UserNotFound
+            AuthError::InvalidToken
+                | AuthError::TokenExpired
+                | AuthError::TokenRevoked
+                | AuthError::UserNotFound
         )
     }
 
@@ -161,6 +282,34 @@ mod tests {
         assert_eq!(user.display_name_or_email(), "bob@example.com");
     }
 
+    #[test]
+    fn service_principal_checks_scopes() {
+        let service = ServicePrincipal::new("sync-worker", ["sessions:read", "cycles:write"]);
+
+        assert!(service.has_scope("sessions:read"));
+        assert!(!service.has_scope("sessions:write"));
+        assert!(service.require_scope("cycles:write").is_ok());
+        assert!(matches!(
+            service.require_scope("admin"),
+            Err(AuthError::InsufficientPermissions)
+        ));
+    }
+
+    #[test]
+    fn principal_exposes_user_or_service() {
+        let user: AuthenticatedPrincipal =
+            AuthenticatedUser::new(test_user_id(), "test@example.com", None, true).into();
+        assert!(user.as_user().is_some());
+        assert!(user.as_service().is_none());
+        assert_eq!(user.subject(), "user-123");
+
+        let service: AuthenticatedPrincipal =
+            ServicePrincipal::new("sync-worker", Vec::<String>::new()).into();
+        assert!(service.as_user().is_none());
+        assert_eq!(service.as_service().unwrap().client_id, "sync-worker");
+        assert_eq!(service.subject(), "sync-worker");
+    }
+
     #[test]
     fn auth_error_invalid_token_displays_correctly() {
         let err = AuthError::InvalidToken;
@@ -183,6 +332,7 @@ mod tests {
     fn auth_error_requires_reauthentication_for_token_errors() {
         assert!(AuthError::InvalidToken.requires_reauthentication());
         assert!(AuthError::TokenExpired.requires_reauthentication());
+        assert!(AuthError::TokenRevoked.requires_reauthentication());
         assert!(AuthError::UserNotFound.requires_reauthentication());
         assert!(!AuthError::InsufficientPermissions.requires_reauthentication());
         assert!(!AuthError::service_unavailable("").requires_reauthentication());
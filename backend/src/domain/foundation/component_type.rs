@@ -1,17 +1,20 @@
-//! ComponentType enum representing the 9 PrOACT phases.
+//! ComponentType enum representing the 11 PrOACT phases.
 
 use serde::{Deserialize, Serialize};
 use std::fmt;
+use utoipa::ToSchema;
 
-/// The 9 PrOACT phases (including Issue Raising and Notes/Next Steps).
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+/// The 11 PrOACT phases (including Issue Raising and Notes/Next Steps).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, ToSchema)]
 #[serde(rename_all = "snake_case")]
 pub enum ComponentType {
     IssueRaising,
     ProblemFrame,
+    StakeholderAnalysis,
     Objectives,
     Alternatives,
     Consequences,
+    UncertaintyRegister,
     Tradeoffs,
     Recommendation,
     DecisionQuality,
@@ -24,9 +27,11 @@ impl ComponentType {
         &[
             ComponentType::IssueRaising,
             ComponentType::ProblemFrame,
+            ComponentType::StakeholderAnalysis,
             ComponentType::Objectives,
             ComponentType::Alternatives,
             ComponentType::Consequences,
+            ComponentType::UncertaintyRegister,
             ComponentType::Tradeoffs,
             ComponentType::Recommendation,
             ComponentType::DecisionQuality,
@@ -73,9 +78,11 @@ impl ComponentType {
         match self {
             ComponentType::IssueRaising => "Issue Raising",
             ComponentType::ProblemFrame => "Problem Frame",
+            ComponentType::StakeholderAnalysis => "Stakeholder Analysis",
             ComponentType::Objectives => "Objectives",
             ComponentType::Alternatives => "Alternatives",
             ComponentType::Consequences => "Consequences",
+            ComponentType::UncertaintyRegister => "Uncertainty Register",
             ComponentType::Tradeoffs => "Tradeoffs",
             ComponentType::Recommendation => "Recommendation",
             ComponentType::DecisionQuality => "Decision Quality",
@@ -88,9 +95,11 @@ impl ComponentType {
         match self {
             ComponentType::IssueRaising => "IR",
             ComponentType::ProblemFrame => "PF",
+            ComponentType::StakeholderAnalysis => "SA",
             ComponentType::Objectives => "OBJ",
             ComponentType::Alternatives => "ALT",
             ComponentType::Consequences => "CON",
+            ComponentType::UncertaintyRegister => "UR",
             ComponentType::Tradeoffs => "TRD",
             ComponentType::Recommendation => "REC",
             ComponentType::DecisionQuality => "DQ",
@@ -110,8 +119,8 @@ mod tests {
     use super::*;
 
     #[test]
-    fn all_returns_9_components() {
-        assert_eq!(ComponentType::all().len(), 9);
+    fn all_returns_11_components() {
+        assert_eq!(ComponentType::all().len(), 11);
     }
 
     #[test]
@@ -119,26 +128,30 @@ mod tests {
         let all = ComponentType::all();
         assert_eq!(all[0], ComponentType::IssueRaising);
         assert_eq!(all[1], ComponentType::ProblemFrame);
-        assert_eq!(all[2], ComponentType::Objectives);
-        assert_eq!(all[3], ComponentType::Alternatives);
-        assert_eq!(all[4], ComponentType::Consequences);
-        assert_eq!(all[5], ComponentType::Tradeoffs);
-        assert_eq!(all[6], ComponentType::Recommendation);
-        assert_eq!(all[7], ComponentType::DecisionQuality);
-        assert_eq!(all[8], ComponentType::NotesNextSteps);
+        assert_eq!(all[2], ComponentType::StakeholderAnalysis);
+        assert_eq!(all[3], ComponentType::Objectives);
+        assert_eq!(all[4], ComponentType::Alternatives);
+        assert_eq!(all[5], ComponentType::Consequences);
+        assert_eq!(all[6], ComponentType::UncertaintyRegister);
+        assert_eq!(all[7], ComponentType::Tradeoffs);
+        assert_eq!(all[8], ComponentType::Recommendation);
+        assert_eq!(all[9], ComponentType::DecisionQuality);
+        assert_eq!(all[10], ComponentType::NotesNextSteps);
     }
 
     #[test]
     fn order_index_returns_correct_values() {
         assert_eq!(ComponentType::IssueRaising.order_index(), 0);
         assert_eq!(ComponentType::ProblemFrame.order_index(), 1);
-        assert_eq!(ComponentType::Objectives.order_index(), 2);
-        assert_eq!(ComponentType::Alternatives.order_index(), 3);
-        assert_eq!(ComponentType::Consequences.order_index(), 4);
-        assert_eq!(ComponentType::Tradeoffs.order_index(), 5);
-        assert_eq!(ComponentType::Recommendation.order_index(), 6);
-        assert_eq!(ComponentType::DecisionQuality.order_index(), 7);
-        assert_eq!(ComponentType::NotesNextSteps.order_index(), 8);
+        assert_eq!(ComponentType::StakeholderAnalysis.order_index(), 2);
+        assert_eq!(ComponentType::Objectives.order_index(), 3);
+        assert_eq!(ComponentType::Alternatives.order_index(), 4);
+        assert_eq!(ComponentType::Consequences.order_index(), 5);
+        assert_eq!(ComponentType::UncertaintyRegister.order_index(), 6);
+        assert_eq!(ComponentType::Tradeoffs.order_index(), 7);
+        assert_eq!(ComponentType::Recommendation.order_index(), 8);
+        assert_eq!(ComponentType::DecisionQuality.order_index(), 9);
+        assert_eq!(ComponentType::NotesNextSteps.order_index(), 10);
     }
 
     #[test]
@@ -147,6 +160,10 @@ mod tests {
             ComponentType::IssueRaising.next(),
             Some(ComponentType::ProblemFrame)
         );
+        assert_eq!(
+            ComponentType::ProblemFrame.next(),
+            Some(ComponentType::StakeholderAnalysis)
+        );
         assert_eq!(
             ComponentType::DecisionQuality.next(),
             Some(ComponentType::NotesNextSteps)
@@ -0,0 +1,147 @@
+//! Embedding value object - a text's position in a model's vector space.
+
+use serde::{Deserialize, Serialize};
+
+use super::ValidationError;
+
+/// A non-empty vector of finite values produced by an embedding model.
+///
+/// Embeddings are only comparable with others of the same length from the
+/// same model.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(try_from = "Vec<f32>", into = "Vec<f32>")]
+pub struct Embedding(Vec<f32>);
+
+impl Embedding {
+    /// Creates an embedding, rejecting empty vectors and NaN or infinite values.
+    pub fn new(values: Vec<f32>) -> Result<Self, ValidationError> {
+        if values.is_empty() {
+            return Err(ValidationError::empty_field("embedding"));
+        }
+        if values.iter().any(|v| !v.is_finite()) {
+            return Err(ValidationError::invalid_format(
+                "embedding",
+                "values must be finite",
+            ));
+        }
+        Ok(Self(values))
+    }
+
+    /// Number of dimensions.
+    pub fn dimensions(&self) -> usize {
+        self.0.len()
+    }
+
+    pub fn as_slice(&self) -> &[f32] {
+        &self.0
+    }
+
+    /// Cosine similarity, from -1 (opposite) to 1 (same direction).
+    ///
+    /// Returns 0 when the lengths differ or either vector is all zeros.
+    pub fn cosine_similarity(&self, other: &Embedding) -> f32 {
+        if self.0.len() != other.0.len() {
+            return 0.0;
+        }
+        let mut dot = 0.0;
+        let mut norm_a = 0.0;
+        let mut norm_b = 0.0;
+        for (a, b) in self.0.iter().zip(&other.0) {
+            dot += a * b;
+            norm_a += a * a;
+            norm_b += b * b;
+        }
+        if norm_a == 0.0 || norm_b == 0.0 {
+            return 0.0;
+        }
+        (dot / (norm_a.sqrt() * norm_b.sqrt())).clamp(-1.0, 1.0)
+    }
+
+    /// The mean of `embeddings`, or `None` if there are none or their
+    /// lengths differ.
+    pub fn centroid<'a>(embeddings: impl IntoIterator<Item = &'a Embedding>) -> Option<Embedding> {
+        let mut iter = embeddings.into_iter();
+        let mut sum = iter.next()?.0.clone();
+        let mut count = 1.0;
+        for embedding in iter {
+            if embedding.0.len() != sum.len() {
+                return None;
+            }
+            for (total, value) in sum.iter_mut().zip(&embedding.0) {
+                *total += value;
+            }
+            count += 1.0;
+        }
+        for total in &mut sum {
+            *total /= count;
+        }
+        Some(Embedding(sum))
+    }
+}
+
+impl TryFrom<Vec<f32>> for Embedding {
+    type Error = ValidationError;
+
+    fn try_from(values: Vec<f32>) -> Result<Self, Self::Error> {
+        Self::new(values)
+    }
+}
+
+impl From<Embedding> for Vec<f32> {
+    fn from(embedding: Embedding) -> Self {
+        embedding.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn embedding(values: &[f32]) -> Embedding {
+        Embedding::new(values.to_vec()).unwrap()
+    }
+
+    #[test]
+    fn rejects_empty_and_non_finite_vectors() {
+        assert!(Embedding::new(vec![]).is_err());
+        assert!(Embedding::new(vec![1.0, f32::NAN]).is_err());
+        assert!(Embedding::new(vec![f32::INFINITY]).is_err());
+    }
+
+    #[test]
+    fn cosine_similarity_ignores_magnitude() {
+        let a = embedding(&[1.0, 0.0]);
+
+        assert!((a.cosine_similarity(&embedding(&[3.0, 0.0])) - 1.0).abs() < 1e-6);
+        assert!(a.cosine_similarity(&embedding(&[0.0, 2.0])).abs() < 1e-6);
+        assert!((a.cosine_similarity(&embedding(&[-1.0, 0.0])) + 1.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn cosine_similarity_of_mismatched_or_zero_vectors_is_zero() {
+        let a = embedding(&[1.0, 0.0]);
+
+        assert_eq!(a.cosine_similarity(&embedding(&[1.0, 0.0, 0.0])), 0.0);
+        assert_eq!(a.cosine_similarity(&embedding(&[0.0, 0.0])), 0.0);
+    }
+
+    #[test]
+    fn centroid_averages_each_dimension() {
+        let centroid = Embedding::centroid([&embedding(&[1.0, 0.0]), &embedding(&[0.0, 1.0])]);
+
+        assert_eq!(centroid, Some(embedding(&[0.5, 0.5])));
+        assert_eq!(Embedding::centroid([]), None);
+        assert_eq!(
+            Embedding::centroid([&embedding(&[1.0]), &embedding(&[1.0, 2.0])]),
+            None
+        );
+    }
+
+    #[test]
+    fn deserialization_validates() {
+        let parsed: Embedding = serde_json::from_str("[0.5, 1.5]").unwrap();
+
+        assert_eq!(parsed.as_slice(), &[0.5, 1.5]);
+        assert!(serde_json::from_str::<Embedding>("[]").is_err());
+    }
+}
@@ -72,6 +72,7 @@ pub enum ErrorCode {
     PreviousComponentRequired,
     InvalidComponentOutput,
     CannotBranch,
+    ConcurrentModification,
 
     // Authorization errors
     Unauthorized,
@@ -121,6 +122,7 @@ impl fmt::Display for ErrorCode {
             ErrorCode::PreviousComponentRequired => "PREVIOUS_COMPONENT_REQUIRED",
             ErrorCode::InvalidComponentOutput => "INVALID_COMPONENT_OUTPUT",
             ErrorCode::CannotBranch => "CANNOT_BRANCH",
+            ErrorCode::ConcurrentModification => "CONCURRENT_MODIFICATION",
             ErrorCode::Unauthorized => "UNAUTHORIZED",
             ErrorCode::Forbidden => "FORBIDDEN",
             ErrorCode::AIProviderError => "AI_PROVIDER_ERROR",
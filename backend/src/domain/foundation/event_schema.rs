@@ -0,0 +1,569 @@
+//! Event schema registry for checked event evolution.
+//!
+//! Records the JSON schema of each versioned event type so that:
+//! - Publishers can reject payloads that drift from their declared shape
+//! - Upcasters can be checked to turn a valid source payload into a valid
+//!   target payload, catching a forgotten field in tests rather than during
+//!   a projection rebuild
+//!
+//! Schemas use a subset of JSON Schema: `type` (a name or a list of names),
+//! `required`, `properties`, `additionalProperties: false`, and `items`.
+//!
+//! # Example
+//!
+//! ```ignore
+//! let mut schemas = EventSchemaRegistry::new();
+//! schemas.record(&sample_session_created);
+//!
+//! // Fails if the v1 → v2 step does not produce a valid v2 payload
+//! schemas.check_upcaster(upcaster.as_ref(), v1_payload)?;
+//! ```
+
+use serde_json::{json, Map, Value as JsonValue};
+use std::collections::HashMap;
+use thiserror::Error;
+
+use super::{EventEnvelope, SerializableDomainEvent, UpcastError, Upcaster};
+
+// ============================================
+// Error Types
+// ============================================
+
+/// Errors raised when checking events against their schemas.
+#[derive(Debug, Error)]
+pub enum EventSchemaError {
+    /// No schema has been recorded for the event type.
+    #[error("no schema recorded for {0}")]
+    UnknownEventType(String),
+
+    /// The payload does not satisfy the recorded schema.
+    #[error("{event_type} does not match its schema: {}", violations.join("; "))]
+    Mismatch {
+        event_type: String,
+        violations: Vec<String>,
+    },
+
+    /// The upcaster under check failed.
+    #[error("upcast failed: {0}")]
+    Upcast(#[from] UpcastError),
+}
+
+// ============================================
+// Event Schema
+// ============================================
+
+/// JSON schema of one event version's payload.
+#[derive(Debug, Clone, PartialEq)]
+pub struct EventSchema(JsonValue);
+
+impl EventSchema {
+    /// Wraps a hand-written JSON schema.
+    pub fn from_json(schema: JsonValue) -> Self {
+        Self(schema)
+    }
+
+    /// Infers a schema from a representative payload.
+    ///
+    /// Every top-level field becomes required with the JSON type it has in
+    /// the sample, or null, since optional fields serialize as null. Fields
+    /// that are null in the sample accept any value, and nested values are
+    /// only checked for their type.
+    pub fn infer(sample: &JsonValue) -> Self {
+        let Some(fields) = sample.as_object() else {
+            return Self(json!({ "type": json_type(sample) }));
+        };
+
+        let properties: Map<String, JsonValue> = fields
+            .iter()
+            .map(|(name, value)| {
+                let schema = match value {
+                    JsonValue::Null => json!({}),
+                    value => json!({ "type": [json_type(value), "null"] }),
+                };
+                (name.clone(), schema)
+            })
+            .collect();
+        let mut required: Vec<&String> = fields.keys().collect();
+        required.sort();
+
+        Self(json!({
+            "type": "object",
+            "required": required,
+            "properties": properties,
+        }))
+    }
+
+    /// Infers the schema of an event's payload from an instance of it.
+    pub fn of<E: SerializableDomainEvent>(event: &E) -> Self {
+        Self::infer(&event.to_envelope().payload)
+    }
+
+    /// Returns the raw JSON schema.
+    pub fn as_json(&self) -> &JsonValue {
+        &self.0
+    }
+
+    /// Checks a payload, returning every violation found.
+    pub fn violations(&self, payload: &JsonValue) -> Vec<String> {
+        let mut violations = Vec::new();
+        check(&self.0, payload, "$", &mut violations);
+        violations
+    }
+}
+
+/// JSON type name of a value. All numbers are reported as "number" so a
+/// float field whose sample happens to be whole is not pinned to integers.
+fn json_type(value: &JsonValue) -> &'static str {
+    match value {
+        JsonValue::Null => "null",
+        JsonValue::Bool(_) => "boolean",
+        JsonValue::Number(_) => "number",
+        JsonValue::String(_) => "string",
+        JsonValue::Array(_) => "array",
+        JsonValue::Object(_) => "object",
+    }
+}
+
+fn type_matches(expected: &str, value: &JsonValue) -> bool {
+    match expected {
+        "integer" => value.is_i64() || value.is_u64(),
+        expected => json_type(value) == expected,
+    }
+}
+
+fn check(schema: &JsonValue, value: &JsonValue, path: &str, violations: &mut Vec<String>) {
+    let allowed: Vec<&str> = match schema.get("type") {
+        Some(JsonValue::String(name)) => vec![name.as_str()],
+        Some(JsonValue::Array(names)) => names.iter().filter_map(|n| n.as_str()).collect(),
+        _ => Vec::new(),
+    };
+    if !allowed.is_empty() && !allowed.iter().any(|t| type_matches(t, value)) {
+        violations.push(format!(
+            "{}: expected {}, got {}",
+            path,
+            allowed.join(" or "),
+            json_type(value)
+        ));
+        return;
+    }
+
+    if let Some(fields) = value.as_object() {
+        let properties = schema.get("properties").and_then(|p| p.as_object());
+
+        if let Some(required) = schema.get("required").and_then(|r| r.as_array()) {
+            for name in required.iter().filter_map(|n| n.as_str()) {
+                if !fields.contains_key(name) {
+                    violations.push(format!("{}.{}: missing", path, name));
+                }
+            }
+        }
+        if let Some(properties) = properties {
+            for (name, field_schema) in properties {
+                if let Some(field) = fields.get(name) {
+                    check(
+                        field_schema,
+                        field,
+                        &format!("{}.{}", path, name),
+                        violations,
+                    );
+                }
+            }
+        }
+        if schema.get("additionalProperties") == Some(&JsonValue::Bool(false)) {
+            for name in fields.keys() {
+                if !properties.is_some_and(|p| p.contains_key(name)) {
+                    violations.push(format!("{}.{}: not allowed", path, name));
+                }
+            }
+        }
+    }
+
+    if let (Some(items), Some(elements)) = (schema.get("items"), value.as_array()) {
+        for (i, element) in elements.iter().enumerate() {
+            check(items, element, &format!("{}[{}]", path, i), violations);
+        }
+    }
+}
+
+// ============================================
+// Event Schema Registry
+// ============================================
+
+/// Schemas of every recorded event version, keyed by versioned event type
+/// (e.g., "session.created.v2").
+#[derive(Debug, Clone, Default)]
+pub struct EventSchemaRegistry {
+    schemas: HashMap<String, EventSchema>,
+}
+
+impl EventSchemaRegistry {
+    /// Creates an empty registry.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Records the schema for a versioned event type, replacing any earlier one.
+    pub fn register(&mut self, event_type: impl Into<String>, schema: EventSchema) {
+        self.schemas.insert(event_type.into(), schema);
+    }
+
+    /// Records the schema inferred from an instance of the event.
+    pub fn record<E: SerializableDomainEvent>(&mut self, event: &E) {
+        self.register(event.event_type(), EventSchema::of(event));
+    }
+
+    /// Returns the schema recorded for a versioned event type.
+    pub fn schema_for(&self, event_type: &str) -> Option<&EventSchema> {
+        self.schemas.get(event_type)
+    }
+
+    /// Returns the recorded versions of an event base type, oldest first.
+    pub fn versions(&self, base_type: &str) -> Vec<u32> {
+        let mut versions: Vec<u32> = self
+            .schemas
+            .keys()
+            .filter(|event_type| {
+                event_type
+                    .rsplit_once(".v")
+                    .is_some_and(|(base, _)| base == base_type)
+            })
+            .map(|event_type| EventEnvelope::extract_version(event_type))
+            .collect();
+        versions.sort_unstable();
+        versions
+    }
+
+    /// Checks an envelope against the schema of its event type.
+    ///
+    /// Event types without a recorded schema pass, so schemas can be
+    /// adopted one event at a time.
+    pub fn validate(&self, envelope: &EventEnvelope) -> Result<(), EventSchemaError> {
+        let Some(schema) = self.schemas.get(&envelope.event_type) else {
+            return Ok(());
+        };
+
+        let mut violations = schema.violations(&envelope.payload);
+        let declared = EventEnvelope::extract_version(&envelope.event_type);
+        if envelope.schema_version != declared {
+            violations.push(format!(
+                "schema_version {} does not match event type version {}",
+                envelope.schema_version, declared
+            ));
+        }
+        mismatch(&envelope.event_type, violations)
+    }
+
+    /// Upcasts a sample payload, checking it against the source schema
+    /// before and the target schema after.
+    ///
+    /// Both versions must have recorded schemas. Returns the upcast payload
+    /// so tests can assert on its values.
+    pub fn check_upcaster(
+        &self,
+        upcaster: &dyn Upcaster,
+        sample: JsonValue,
+    ) -> Result<JsonValue, EventSchemaError> {
+        let source = self.require(upcaster.source_type())?;
+        let target = self.require(upcaster.target_type())?;
+
+        mismatch(upcaster.source_type(), source.violations(&sample))?;
+        let upcast = upcaster.upcast(sample)?;
+        mismatch(upcaster.target_type(), target.violations(&upcast))?;
+        Ok(upcast)
+    }
+
+    fn require(&self, event_type: &str) -> Result<&EventSchema, EventSchemaError> {
+        self.schemas
+            .get(event_type)
+            .ok_or_else(|| EventSchemaError::UnknownEventType(event_type.to_string()))
+    }
+}
+
+fn mismatch(event_type: &str, violations: Vec<String>) -> Result<(), EventSchemaError> {
+    if violations.is_empty() {
+        Ok(())
+    } else {
+        Err(EventSchemaError::Mismatch {
+            event_type: event_type.to_string(),
+            violations,
+        })
+    }
+}
+
+// ============================================
+// Tests
+// ============================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::foundation::{domain_event, EventId, Timestamp, UpcasterBuilder};
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Debug, Clone, Serialize, Deserialize)]
+    struct NoteAddedV1 {
+        event_id: EventId,
+        note_id: String,
+        text: String,
+        pinned: Option<bool>,
+        added_at: Timestamp,
+    }
+
+    domain_event!(
+        NoteAddedV1,
+        event_type = "note.added.v1",
+        schema_version = 1,
+        aggregate_id = note_id,
+        aggregate_type = "Note",
+        occurred_at = added_at,
+        event_id = event_id
+    );
+
+    #[derive(Debug, Clone, Serialize, Deserialize)]
+    struct NoteAddedV2 {
+        event_id: EventId,
+        note_id: String,
+        text: String,
+        pinned: Option<bool>,
+        tags: Vec<String>,
+        added_at: Timestamp,
+    }
+
+    domain_event!(
+        NoteAddedV2,
+        event_type = "note.added.v2",
+        schema_version = 2,
+        aggregate_id = note_id,
+        aggregate_type = "Note",
+        occurred_at = added_at,
+        event_id = event_id
+    );
+
+    fn note_v1() -> NoteAddedV1 {
+        NoteAddedV1 {
+            event_id: EventId::from_string("evt-1"),
+            note_id: "note-1".to_string(),
+            text: "Check the lease terms".to_string(),
+            pinned: None,
+            added_at: Timestamp::now(),
+        }
+    }
+
+    fn note_v2() -> NoteAddedV2 {
+        NoteAddedV2 {
+            event_id: EventId::from_string("evt-2"),
+            note_id: "note-2".to_string(),
+            text: "Compare commute times".to_string(),
+            pinned: Some(true),
+            tags: vec!["housing".to_string()],
+            added_at: Timestamp::now(),
+        }
+    }
+
+    fn registry() -> EventSchemaRegistry {
+        let mut registry = EventSchemaRegistry::new();
+        registry.record(&note_v1());
+        registry.record(&note_v2());
+        registry
+    }
+
+    // ============================================================
+    // EventSchema Tests
+    // ============================================================
+
+    #[test]
+    fn inferred_schema_accepts_other_instances() {
+        let schema = EventSchema::of(&note_v1());
+        let mut other = note_v1();
+        other.pinned = Some(false);
+        other.text = "Another note".to_string();
+
+        assert!(schema.violations(&other.to_envelope().payload).is_empty());
+    }
+
+    #[test]
+    fn inferred_schema_allows_null_for_optional_fields() {
+        let schema = EventSchema::of(&note_v2());
+        let mut unpinned = note_v2();
+        unpinned.pinned = None;
+
+        assert!(schema
+            .violations(&unpinned.to_envelope().payload)
+            .is_empty());
+    }
+
+    #[test]
+    fn inferred_schema_reports_missing_and_mistyped_fields() {
+        let schema = EventSchema::of(&note_v1());
+
+        let violations = schema.violations(&json!({
+            "event_id": "evt-1",
+            "note_id": 42,
+            "pinned": null,
+            "added_at": "2026-01-01T00:00:00Z"
+        }));
+
+        assert_eq!(
+            violations,
+            vec![
+                "$.text: missing".to_string(),
+                "$.note_id: expected string or null, got number".to_string(),
+            ]
+        );
+    }
+
+    #[test]
+    fn hand_written_schema_checks_items_and_extra_fields() {
+        let schema = EventSchema::from_json(json!({
+            "type": "object",
+            "required": ["scores"],
+            "properties": {
+                "scores": { "type": "array", "items": { "type": "integer" } }
+            },
+            "additionalProperties": false
+        }));
+
+        assert!(schema.violations(&json!({"scores": [1, 2]})).is_empty());
+        assert_eq!(
+            schema.violations(&json!({"scores": [1, 2.5], "extra": true})),
+            vec![
+                "$.scores[1]: expected integer, got number".to_string(),
+                "$.extra: not allowed".to_string(),
+            ]
+        );
+    }
+
+    // ============================================================
+    // EventSchemaRegistry Tests
+    // ============================================================
+
+    #[test]
+    fn registry_lists_recorded_versions() {
+        let registry = registry();
+
+        assert_eq!(registry.versions("note.added"), vec![1, 2]);
+        assert!(registry.versions("note.removed").is_empty());
+        assert!(registry.schema_for("note.added.v2").is_some());
+    }
+
+    #[test]
+    fn registry_accepts_matching_envelope() {
+        let registry = registry();
+
+        assert!(registry.validate(&note_v2().to_envelope()).is_ok());
+    }
+
+    #[test]
+    fn registry_rejects_envelope_not_matching_its_version() {
+        let registry = registry();
+        let mut envelope = note_v1().to_envelope();
+        envelope.event_type = "note.added.v2".to_string();
+        envelope.schema_version = 2;
+
+        let err = registry.validate(&envelope).unwrap_err();
+
+        assert!(matches!(
+            err,
+            EventSchemaError::Mismatch { ref violations, .. }
+                if violations == &vec!["$.tags: missing".to_string()]
+        ));
+    }
+
+    #[test]
+    fn registry_rejects_inconsistent_schema_version() {
+        let registry = registry();
+        let mut envelope = note_v1().to_envelope();
+        envelope.schema_version = 2;
+
+        assert!(registry.validate(&envelope).is_err());
+    }
+
+    #[test]
+    fn registry_passes_undeclared_event_types() {
+        let registry = registry();
+
+        assert!(registry.validate(&EventEnvelope::test_fixture()).is_ok());
+    }
+
+    // ============================================================
+    // Upcaster Round-Trip Tests
+    // ============================================================
+
+    #[test]
+    fn check_upcaster_round_trips_valid_step() {
+        let registry = registry();
+        let upcaster = UpcasterBuilder::new("note.added").convert(|v1: NoteAddedV1| {
+            Ok(NoteAddedV2 {
+                event_id: v1.event_id,
+                note_id: v1.note_id,
+                text: v1.text,
+                pinned: v1.pinned,
+                tags: Vec::new(),
+                added_at: v1.added_at,
+            })
+        });
+        let original = note_v1();
+
+        let upcast = registry
+            .check_upcaster(upcaster.as_ref(), original.to_envelope().payload)
+            .unwrap();
+        let v2: NoteAddedV2 = serde_json::from_value(upcast).unwrap();
+
+        assert_eq!(v2.note_id, original.note_id);
+        assert_eq!(v2.text, original.text);
+        assert!(v2.tags.is_empty());
+    }
+
+    #[test]
+    fn check_upcaster_catches_forgotten_field() {
+        let registry = registry();
+        let upcaster = UpcasterBuilder::new("note.added").convert(|v1: NoteAddedV1| {
+            Ok(json!({
+                "event_id": v1.event_id,
+                "note_id": v1.note_id,
+                "text": v1.text,
+                "pinned": v1.pinned,
+                "added_at": v1.added_at,
+            }))
+        });
+
+        let err = registry
+            .check_upcaster(upcaster.as_ref(), note_v1().to_envelope().payload)
+            .unwrap_err();
+
+        assert!(matches!(
+            err,
+            EventSchemaError::Mismatch { ref event_type, .. } if event_type == "note.added.v2"
+        ));
+    }
+
+    #[test]
+    fn check_upcaster_rejects_invalid_sample() {
+        let registry = registry();
+        let upcaster = UpcasterBuilder::new("note.added")
+            .convert(|v1: NoteAddedV1| Ok(json!({ "text": v1.text })));
+
+        let err = registry
+            .check_upcaster(upcaster.as_ref(), json!({"text": "no ids"}))
+            .unwrap_err();
+
+        assert!(matches!(
+            err,
+            EventSchemaError::Mismatch { ref event_type, .. } if event_type == "note.added.v1"
+        ));
+    }
+
+    #[test]
+    fn check_upcaster_requires_both_schemas() {
+        let mut registry = EventSchemaRegistry::new();
+        registry.record(&note_v1());
+        let upcaster = UpcasterBuilder::new("note.added")
+            .convert(|v1: NoteAddedV1| Ok(json!({ "text": v1.text })));
+
+        let err = registry
+            .check_upcaster(upcaster.as_ref(), note_v1().to_envelope().payload)
+            .unwrap_err();
+
+        assert!(matches!(err, EventSchemaError::UnknownEventType(ref t) if t == "note.added.v2"));
+    }
+}
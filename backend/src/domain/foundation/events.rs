@@ -206,7 +206,7 @@ impl fmt::Display for EventId {
 /// - `correlation_id` - Links related events across a request
 /// - `causation_id` - ID of the event that caused this one
 /// - `user_id` - User who triggered this event chain
-/// - `trace_id` - Distributed tracing identifier
+/// - `trace_id` / `span_id` - W3C trace context of the span that emitted the event
 #[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
 pub struct EventMetadata {
     /// ID linking related events across a single user request.
@@ -221,9 +221,28 @@ pub struct EventMetadata {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub user_id: Option<String>,
 
-    /// Distributed tracing span/trace ID.
+    /// Distributed tracing trace ID (32 lowercase hex chars).
     #[serde(skip_serializing_if = "Option::is_none")]
     pub trace_id: Option<String>,
+
+    /// ID of the span that published the event (16 lowercase hex chars).
+    ///
+    /// Event handlers use this as the parent span so asynchronous work is
+    /// stitched into the originating trace.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub span_id: Option<String>,
+}
+
+impl EventMetadata {
+    /// Formats the trace context as a W3C `traceparent` header value.
+    ///
+    /// Returns `None` unless both `trace_id` and `span_id` are set.
+    pub fn traceparent(&self) -> Option<String> {
+        match (&self.trace_id, &self.span_id) {
+            (Some(trace_id), Some(span_id)) => Some(format!("00-{}-{}-01", trace_id, span_id)),
+            _ => None,
+        }
+    }
 }
 
 /// Transport envelope for domain events.
@@ -368,6 +387,17 @@ impl EventEnvelope {
         self
     }
 
+    /// Add the full trace context (trace ID and parent span ID).
+    pub fn with_trace_context(
+        mut self,
+        trace_id: impl Into<String>,
+        span_id: impl Into<String>,
+    ) -> Self {
+        self.metadata.trace_id = Some(trace_id.into());
+        self.metadata.span_id = Some(span_id.into());
+        self
+    }
+
     /// Deserialize payload to a specific event type.
     pub fn payload_as<T: for<'de> Deserialize<'de>>(&self) -> Result<T, serde_json::Error> {
         serde_json::from_value(self.payload.clone())
@@ -456,6 +486,7 @@ mod tests {
             causation_id: None,
             user_id: None,
             trace_id: None,
+            span_id: None,
         };
         let json = serde_json::to_string(&meta).unwrap();
         assert!(json.contains("correlation_id"));
@@ -471,12 +502,33 @@ mod tests {
             causation_id: Some("cause-1".to_string()),
             user_id: Some("user-1".to_string()),
             trace_id: Some("trace-1".to_string()),
+            span_id: Some("span-1".to_string()),
         };
         let json = serde_json::to_string(&meta).unwrap();
         let restored: EventMetadata = serde_json::from_str(&json).unwrap();
         assert_eq!(meta, restored);
     }
 
+    #[test]
+    fn event_metadata_without_span_id_deserializes() {
+        let json = r#"{"trace_id":"trace-1"}"#;
+        let meta: EventMetadata = serde_json::from_str(json).unwrap();
+        assert_eq!(meta.trace_id, Some("trace-1".to_string()));
+        assert!(meta.span_id.is_none());
+        assert!(meta.traceparent().is_none());
+    }
+
+    #[test]
+    fn event_metadata_formats_traceparent() {
+        let envelope = EventEnvelope::new("test.event", "agg-1", "Test", json!({}))
+            .with_trace_context("4bf92f3577b34da6a3ce929d0e0e4736", "00f067aa0ba902b7");
+
+        assert_eq!(
+            envelope.metadata.traceparent(),
+            Some("00-4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7-01".to_string())
+        );
+    }
+
     // ============================================================
     // EventEnvelope Tests
     // ============================================================
@@ -7,6 +7,7 @@ mod auth;
 mod ids;
 mod timestamp;
 mod percentage;
+mod embedding;
 mod rating;
 mod component_type;
 mod component_status;
@@ -15,16 +16,20 @@ mod session_status;
 mod state_machine;
 mod errors;
 mod events;
+mod event_schema;
 mod upcaster;
 mod command;
 
-pub use auth::{AuthenticatedUser, AuthError};
+pub use auth::{AuthenticatedPrincipal, AuthenticatedUser, AuthError, ServicePrincipal};
 pub use ids::{
     SessionId, CycleId, ComponentId, ConversationId, UserId, MembershipId,
-    ToolInvocationId, RevisitSuggestionId, ConfirmationRequestId,
+    ToolInvocationId, RevisitSuggestionId, ConfirmationRequestId, ConsentRecordId, AttachmentId,
+    DocumentDeliveryId, PublicationId, DataExportId, DataErasureId, OutcomeReminderId, SlackShareId,
+    TeamsShareId, IntegrationId, IntegrationDeliveryId, OrganizationId, BackupId, DecisionTreeId,
 };
 pub use timestamp::Timestamp;
 pub use percentage::Percentage;
+pub use embedding::Embedding;
 pub use rating::Rating;
 pub use component_type::ComponentType;
 pub use component_status::ComponentStatus;
@@ -33,5 +38,6 @@ pub use session_status::SessionStatus;
 pub use state_machine::StateMachine;
 pub use errors::{DomainError, ErrorCode, ValidationError};
 pub use events::{DomainEvent, SerializableDomainEvent, EventId, EventMetadata, EventEnvelope, domain_event};
-pub use upcaster::{Upcaster, UpcasterRegistry, UpcastError, EventDeserializer, DeserializeError, EventReplayer, ReplayStats};
+pub use event_schema::{EventSchema, EventSchemaError, EventSchemaRegistry};
+pub use upcaster::{Upcaster, UpcasterBuilder, UpcasterRegistry, UpcastError, EventDeserializer, DeserializeError, EventReplayer, ReplayStats};
 pub use command::CommandMetadata;
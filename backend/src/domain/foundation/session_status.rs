@@ -1,15 +1,18 @@
 //! SessionStatus enum for tracking lifecycle of decision sessions.
 
 use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
 use std::fmt;
 
 /// Lifecycle status of a decision session.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Default, ToSchema)]
 #[serde(rename_all = "snake_case")]
 pub enum SessionStatus {
     #[default]
     Active,
     Archived,
+    /// In the trash; restorable until the retention window ends.
+    Deleted,
 }
 
 impl SessionStatus {
@@ -22,9 +25,21 @@ impl SessionStatus {
     ///
     /// Valid transitions:
     /// - Active -> Archived
+    /// - Active | Archived -> Deleted
+    ///
+    /// Restoring a deleted session returns it to the status it had before,
+    /// which the session itself remembers.
     pub fn can_transition_to(&self, target: &SessionStatus) -> bool {
         use SessionStatus::*;
-        matches!((self, target), (Active, Archived))
+        matches!(
+            (self, target),
+            (Active, Archived) | (Active, Deleted) | (Archived, Deleted)
+        )
+    }
+
+    /// Returns true if the session is in the trash.
+    pub fn is_deleted(&self) -> bool {
+        matches!(self, SessionStatus::Deleted)
     }
 }
 
@@ -33,6 +48,7 @@ impl fmt::Display for SessionStatus {
         let s = match self {
             SessionStatus::Active => "Active",
             SessionStatus::Archived => "Archived",
+            SessionStatus::Deleted => "Deleted",
         };
         write!(f, "{}", s)
     }
@@ -51,6 +67,7 @@ mod tests {
     fn is_mutable_works_correctly() {
         assert!(SessionStatus::Active.is_mutable());
         assert!(!SessionStatus::Archived.is_mutable());
+        assert!(!SessionStatus::Deleted.is_mutable());
     }
 
     #[test]
@@ -73,10 +90,23 @@ mod tests {
         assert!(!SessionStatus::Archived.can_transition_to(&SessionStatus::Archived));
     }
 
+    #[test]
+    fn active_and_archived_can_be_deleted() {
+        assert!(SessionStatus::Active.can_transition_to(&SessionStatus::Deleted));
+        assert!(SessionStatus::Archived.can_transition_to(&SessionStatus::Deleted));
+        assert!(!SessionStatus::Deleted.can_transition_to(&SessionStatus::Deleted));
+    }
+
+    #[test]
+    fn deleted_cannot_be_archived() {
+        assert!(!SessionStatus::Deleted.can_transition_to(&SessionStatus::Archived));
+    }
+
     #[test]
     fn display_works_correctly() {
         assert_eq!(format!("{}", SessionStatus::Active), "Active");
         assert_eq!(format!("{}", SessionStatus::Archived), "Archived");
+        assert_eq!(format!("{}", SessionStatus::Deleted), "Deleted");
     }
 
     #[test]
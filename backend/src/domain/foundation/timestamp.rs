@@ -2,9 +2,10 @@
 
 use chrono::{DateTime, Duration, Utc};
 use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
 
 /// Immutable point in time, always UTC.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize, ToSchema)]
 #[serde(transparent)]
 pub struct Timestamp(DateTime<Utc>);
 
@@ -78,6 +79,11 @@ impl Timestamp {
         Self(start)
     }
 
+    /// Returns the start of this timestamp's day (00:00:00 UTC).
+    pub fn start_of_day(&self) -> Self {
+        Self(self.0.date_naive().and_hms_opt(0, 0, 0).unwrap().and_utc())
+    }
+
     /// Creates a timestamp from Unix seconds.
     pub fn from_unix_secs(secs: u64) -> Self {
         use chrono::TimeZone;
@@ -89,6 +95,17 @@ impl Timestamp {
         self.0.timestamp() as u64
     }
 
+    /// Creates a timestamp from Unix milliseconds.
+    pub fn from_unix_millis(millis: u64) -> Self {
+        use chrono::TimeZone;
+        Self(Utc.timestamp_millis_opt(millis as i64).unwrap())
+    }
+
+    /// Returns the timestamp as Unix milliseconds.
+    pub fn as_unix_millis(&self) -> u64 {
+        self.0.timestamp_millis() as u64
+    }
+
     /// Creates a new timestamp by adding the specified number of seconds.
     pub fn plus_secs(&self, secs: u64) -> Self {
         Self(self.0 + Duration::seconds(secs as i64))
@@ -190,10 +207,26 @@ mod tests {
         assert_eq!(ts.as_unix_secs(), unix_secs);
     }
 
+    #[test]
+    fn timestamp_as_unix_millis_roundtrips() {
+        let unix_millis = 1_705_276_800_123_u64;
+        let ts = Timestamp::from_unix_millis(unix_millis);
+        assert_eq!(ts.as_unix_millis(), unix_millis);
+        assert_eq!(ts.as_unix_secs(), 1705276800);
+    }
+
     #[test]
     fn timestamp_plus_secs_adds_correctly() {
         let ts1 = Timestamp::from_unix_secs(1000);
         let ts2 = ts1.plus_secs(60);
         assert_eq!(ts2.as_unix_secs(), 1060);
     }
+
+    #[test]
+    fn timestamp_start_of_day_truncates_to_midnight() {
+        // 2024-01-15T10:30:00Z
+        let ts = Timestamp::from_unix_secs(1705314600);
+        assert_eq!(ts.start_of_day().as_unix_secs(), 1705276800);
+        assert_eq!(ts.start_of_day().start_of_day(), ts.start_of_day());
+    }
 }
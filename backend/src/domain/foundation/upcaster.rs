@@ -7,6 +7,7 @@
 //!
 //! - `Upcaster` trait - Transforms a single version step (v1 → v2)
 //! - `UpcasterRegistry` - Chains multiple upcasters to reach current version
+//! - `UpcasterBuilder` - Builds an upcaster from a typed payload conversion
 //! - `UpcastError` - Error types for failed transformations
 //!
 //! # Example
@@ -29,6 +30,7 @@
 
 use serde_json::Value as JsonValue;
 use std::collections::HashMap;
+use std::marker::PhantomData;
 use std::sync::Arc;
 use thiserror::Error;
 
@@ -250,6 +252,93 @@ impl Default for UpcasterRegistry {
     }
 }
 
+// ============================================
+// Typed Upcaster Builder
+// ============================================
+
+/// Builds an `Upcaster` from a typed conversion between two payload versions.
+///
+/// The source payload is deserialized into `S`, converted, and the resulting
+/// `T` serialized as the target payload, so a step is written against the
+/// event structs rather than raw JSON. Fields missing from `S` are dropped;
+/// `S` must describe the whole source payload.
+///
+/// # Example
+///
+/// ```ignore
+/// let upcaster = UpcasterBuilder::new("session.created")
+///     .from_version(1)
+///     .convert(|v1: SessionCreatedV1| {
+///         Ok(SessionCreatedV2 { title: v1.title, description: None })
+///     });
+/// registry.register(upcaster);
+/// ```
+pub struct UpcasterBuilder {
+    base_type: String,
+    from_version: u32,
+}
+
+impl UpcasterBuilder {
+    /// Starts a builder for an event base type (e.g., "session.created").
+    ///
+    /// The step upcasts from version 1 unless `from_version` says otherwise.
+    pub fn new(base_type: impl Into<String>) -> Self {
+        Self {
+            base_type: base_type.into(),
+            from_version: 1,
+        }
+    }
+
+    /// Sets the source version; the target is always the next version.
+    pub fn from_version(mut self, version: u32) -> Self {
+        self.from_version = version;
+        self
+    }
+
+    /// Finishes the upcaster with the conversion from `S` to `T`.
+    pub fn convert<S, T, F>(self, convert: F) -> Arc<dyn Upcaster>
+    where
+        S: serde::de::DeserializeOwned + 'static,
+        T: serde::Serialize + 'static,
+        F: Fn(S) -> Result<T, UpcastError> + Send + Sync + 'static,
+    {
+        Arc::new(TypedUpcaster {
+            source_type: format!("{}.v{}", self.base_type, self.from_version),
+            target_type: format!("{}.v{}", self.base_type, self.from_version + 1),
+            convert,
+            _payloads: PhantomData,
+        })
+    }
+}
+
+struct TypedUpcaster<S, T, F> {
+    source_type: String,
+    target_type: String,
+    convert: F,
+    _payloads: PhantomData<fn(S) -> T>,
+}
+
+impl<S, T, F> Upcaster for TypedUpcaster<S, T, F>
+where
+    S: serde::de::DeserializeOwned,
+    T: serde::Serialize,
+    F: Fn(S) -> Result<T, UpcastError> + Send + Sync,
+{
+    fn source_type(&self) -> &str {
+        &self.source_type
+    }
+
+    fn target_type(&self) -> &str {
+        &self.target_type
+    }
+
+    fn upcast(&self, payload: JsonValue) -> Result<JsonValue, UpcastError> {
+        let source: S = serde_json::from_value(payload)?;
+        let target = (self.convert)(source)?;
+        Ok(serde_json::to_value(target)?)
+    }
+}
+
 // ============================================
 // Event Deserializer
 // ============================================
@@ -793,6 +882,7 @@ mod tests {
                 causation_id: None,
                 user_id: Some("user-1".to_string()),
                 trace_id: None,
+                span_id: None,
             },
         };
 
@@ -1174,4 +1264,69 @@ mod tests {
         stats.record_failed("error");
         assert_eq!(stats.success_rate(), 0.0);
     }
+
+    // ============================================================
+    // UpcasterBuilder Tests
+    // ============================================================
+
+    #[derive(serde::Deserialize)]
+    struct RenamedV1 {
+        user_id: String,
+    }
+
+    #[derive(serde::Serialize)]
+    struct RenamedV2 {
+        owner_id: String,
+    }
+
+    #[test]
+    fn builder_names_next_version_step() {
+        let upcaster = UpcasterBuilder::new("test.event")
+            .from_version(2)
+            .convert(|v: RenamedV1| Ok(RenamedV2 { owner_id: v.user_id }));
+
+        assert_eq!(upcaster.source_type(), "test.event.v2");
+        assert_eq!(upcaster.target_type(), "test.event.v3");
+    }
+
+    #[test]
+    fn builder_converts_typed_payloads() {
+        let upcaster = UpcasterBuilder::new("test.event")
+            .convert(|v: RenamedV1| Ok(RenamedV2 { owner_id: v.user_id }));
+
+        let payload = upcaster.upcast(json!({"user_id": "user-123"})).unwrap();
+
+        assert_eq!(payload, json!({"owner_id": "user-123"}));
+    }
+
+    #[test]
+    fn builder_rejects_payload_not_matching_source_type() {
+        let upcaster = UpcasterBuilder::new("test.event")
+            .convert(|v: RenamedV1| Ok(RenamedV2 { owner_id: v.user_id }));
+
+        let result = upcaster.upcast(json!({"owner_id": "user-123"}));
+
+        assert!(matches!(result, Err(UpcastError::JsonError(_))));
+    }
+
+    #[test]
+    fn builder_upcasters_chain_in_registry() {
+        let mut registry = UpcasterRegistry::new();
+        registry.register(
+            UpcasterBuilder::new("test.event")
+                .convert(|v: RenamedV1| Ok(RenamedV2 { owner_id: v.user_id })),
+        );
+        registry.set_current_version("test.event", 2);
+
+        let v1_envelope = EventEnvelope::new(
+            "test.event.v1",
+            "agg-1",
+            "Test",
+            json!({"user_id": "user-123"}),
+        );
+        let current = registry.upcast_to_current(v1_envelope).unwrap();
+
+        assert_eq!(current.event_type, "test.event.v2");
+        assert_eq!(current.payload["owner_id"], "user-123");
+    }
 }
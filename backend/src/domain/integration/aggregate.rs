@@ -0,0 +1,564 @@
+//! Integration aggregate - "When this happens, do that" automations.
+//!
+//! An `Integration` pairs an `EventFilter` (which domain events, and
+//! optionally which field values) with one `IntegrationAction` (POST to a
+//! URL, post to Slack, or send an email). The action's body comes from an
+//! optional Tera template rendered against the event's
+//! [`template_context`]; without one, HTTP actions send the context as JSON
+//! and the others send a one-line summary.
+
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value as JsonValue};
+
+use crate::domain::foundation::{EventEnvelope, IntegrationId, Timestamp, UserId};
+
+/// Domain events integrations can be triggered by: those published with
+/// the acting user, so each integration only sees its owner's events.
+pub const TRIGGER_EVENT_TYPES: &[&str] = &[
+    "session.created.v1",
+    "session.renamed.v1",
+    "session.archived.v1",
+    "session.deleted.v1",
+    "session.restored.v1",
+    "session.archive_warning_issued.v1",
+    "session.auto_archive_exclusion_changed.v1",
+    "cycle.created.v1",
+    "cycle.branched.v1",
+    "cycle.completed.v1",
+    "cycle.archived.v1",
+    "component.started.v1",
+    "component.completed.v1",
+    "component.batch_applied.v1",
+];
+
+/// Integrations one user may configure.
+pub const MAX_INTEGRATIONS_PER_USER: usize = 25;
+
+/// Field conditions per filter.
+pub const MAX_FILTER_CONDITIONS: usize = 10;
+
+/// Longest accepted payload template, in bytes.
+pub const MAX_TEMPLATE_BYTES: usize = 16 * 1024;
+
+const MAX_NAME_CHARS: usize = 100;
+const MAX_SUBJECT_CHARS: usize = 200;
+
+/// Host names an HTTP action may never target.
+const BLOCKED_HOSTS: [&str; 2] = ["localhost", "metadata.google.internal"];
+
+/// Host suffixes reserved for private networks.
+const BLOCKED_HOST_SUFFIXES: [&str; 4] = [".localhost", ".local", ".internal", ".lan"];
+
+/// Requires a field of the event to equal a value.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FieldCondition {
+    /// Dot path into the template context, e.g. `payload.component_type`.
+    pub path: String,
+    pub equals: JsonValue,
+}
+
+impl FieldCondition {
+    fn matches(&self, context: &JsonValue) -> bool {
+        lookup(context, &self.path) == Some(&self.equals)
+    }
+}
+
+/// Which events trigger an integration.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct EventFilter {
+    /// Any of these event types; each must be in [`TRIGGER_EVENT_TYPES`].
+    pub event_types: Vec<String>,
+    /// All of these must hold.
+    #[serde(default)]
+    pub conditions: Vec<FieldCondition>,
+}
+
+impl EventFilter {
+    /// Whether an event of `event_type` with `context` passes the filter.
+    pub fn matches(&self, event_type: &str, context: &JsonValue) -> bool {
+        self.event_types.iter().any(|t| t == event_type)
+            && self.conditions.iter().all(|c| c.matches(context))
+    }
+
+    fn validate(&self) -> Result<(), IntegrationError> {
+        if self.event_types.is_empty() {
+            return Err(IntegrationError::NoEventTypes);
+        }
+        for (i, event_type) in self.event_types.iter().enumerate() {
+            if !TRIGGER_EVENT_TYPES.contains(&event_type.as_str()) {
+                return Err(IntegrationError::UnsupportedEventType(event_type.clone()));
+            }
+            if self.event_types[..i].contains(event_type) {
+                return Err(IntegrationError::DuplicateEventType(event_type.clone()));
+            }
+        }
+        if self.conditions.len() > MAX_FILTER_CONDITIONS {
+            return Err(IntegrationError::TooManyConditions);
+        }
+        for condition in &self.conditions {
+            let valid_root = ["event.", "payload."]
+                .iter()
+                .any(|root| condition.path.starts_with(root));
+            if !valid_root || condition.path.split('.').any(str::is_empty) {
+                return Err(IntegrationError::InvalidConditionPath(
+                    condition.path.clone(),
+                ));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Discriminant of [`IntegrationAction`], used to pick its adapter.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum IntegrationActionKind {
+    HttpPost,
+    Slack,
+    Email,
+}
+
+impl IntegrationActionKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            IntegrationActionKind::HttpPost => "http_post",
+            IntegrationActionKind::Slack => "slack",
+            IntegrationActionKind::Email => "email",
+        }
+    }
+}
+
+/// What an integration does when triggered.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum IntegrationAction {
+    /// POST the rendered payload to an HTTPS URL on a public host.
+    HttpPost { url: String },
+    /// Post the rendered text through one of the owner's Slack workspaces.
+    Slack { team_id: String, channel: String },
+    /// Email the rendered text; `subject` is a template too.
+    Email { to: String, subject: String },
+}
+
+impl IntegrationAction {
+    pub fn kind(&self) -> IntegrationActionKind {
+        match self {
+            IntegrationAction::HttpPost { .. } => IntegrationActionKind::HttpPost,
+            IntegrationAction::Slack { .. } => IntegrationActionKind::Slack,
+            IntegrationAction::Email { .. } => IntegrationActionKind::Email,
+        }
+    }
+
+    fn validate(&self) -> Result<(), IntegrationError> {
+        match self {
+            IntegrationAction::HttpPost { url } => {
+                if !is_public_https_url(url) {
+                    return Err(IntegrationError::InvalidUrl);
+                }
+            }
+            IntegrationAction::Slack { team_id, channel } => {
+                if team_id.trim().is_empty() || channel.trim().is_empty() {
+                    return Err(IntegrationError::InvalidSlackTarget);
+                }
+            }
+            IntegrationAction::Email { to, subject } => {
+                let valid_address = to.contains('@')
+                    && !to.starts_with('@')
+                    && !to.ends_with('@')
+                    && !to.contains(char::is_whitespace);
+                if !valid_address {
+                    return Err(IntegrationError::InvalidEmail);
+                }
+                if subject.trim().is_empty() || subject.chars().count() > MAX_SUBJECT_CHARS {
+                    return Err(IntegrationError::InvalidSubject);
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// HTTPS on a named, public host: no IP literals, ports, credentials, or
+/// private-network names, so an integration cannot probe internal services.
+fn is_public_https_url(url: &str) -> bool {
+    let Some(rest) = url.strip_prefix("https://") else {
+        return false;
+    };
+    let host = rest
+        .split(['/', '?', '#'])
+        .next()
+        .unwrap_or_default()
+        .to_ascii_lowercase();
+    let is_ip_literal = host.starts_with('[')
+        || host
+            .split('.')
+            .all(|label| !label.is_empty() && label.chars().all(|c| c.is_ascii_digit()));
+    host.contains('.')
+        && !host.contains(['@', ':'])
+        && !is_ip_literal
+        && !BLOCKED_HOSTS.contains(&host.as_str())
+        && !BLOCKED_HOST_SUFFIXES
+            .iter()
+            .any(|suffix| host.ends_with(suffix))
+}
+
+/// Why an integration was rejected.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum IntegrationError {
+    #[error("Integration name must be 1-{MAX_NAME_CHARS} characters")]
+    InvalidName,
+
+    #[error("Choose at least one event type")]
+    NoEventTypes,
+
+    #[error("Event type '{0}' cannot trigger integrations")]
+    UnsupportedEventType(String),
+
+    #[error("Event type '{0}' is listed more than once")]
+    DuplicateEventType(String),
+
+    #[error("At most {MAX_FILTER_CONDITIONS} conditions are allowed")]
+    TooManyConditions,
+
+    #[error("Condition path '{0}' must start with 'event.' or 'payload.'")]
+    InvalidConditionPath(String),
+
+    #[error("URL must use HTTPS on a public host name")]
+    InvalidUrl,
+
+    #[error("Slack actions need a workspace and a channel")]
+    InvalidSlackTarget,
+
+    #[error("Email address is not valid")]
+    InvalidEmail,
+
+    #[error("Email subject must be 1-{MAX_SUBJECT_CHARS} characters")]
+    InvalidSubject,
+
+    #[error("Template is larger than {MAX_TEMPLATE_BYTES} bytes")]
+    TemplateTooLarge,
+}
+
+/// A user's automation: an event filter and the action it triggers.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Integration {
+    pub id: IntegrationId,
+    /// Only this user's events trigger the integration.
+    pub user_id: UserId,
+    pub name: String,
+    pub filter: EventFilter,
+    pub action: IntegrationAction,
+    /// Tera template for the payload; `None` uses the default.
+    pub template: Option<String>,
+    pub enabled: bool,
+    pub created_at: Timestamp,
+    pub updated_at: Timestamp,
+}
+
+impl Integration {
+    /// A validated, enabled integration.
+    pub fn new(
+        user_id: UserId,
+        name: impl Into<String>,
+        filter: EventFilter,
+        action: IntegrationAction,
+        template: Option<String>,
+    ) -> Result<Self, IntegrationError> {
+        let now = Timestamp::now();
+        let mut integration = Self {
+            id: IntegrationId::new(),
+            user_id,
+            name: String::new(),
+            filter: filter.clone(),
+            action: action.clone(),
+            template: None,
+            enabled: true,
+            created_at: now,
+            updated_at: now,
+        };
+        integration.update(name, filter, action, template, true)?;
+        Ok(integration)
+    }
+
+    /// Replaces the configuration, leaving the integration unchanged if any
+    /// part is invalid.
+    pub fn update(
+        &mut self,
+        name: impl Into<String>,
+        filter: EventFilter,
+        action: IntegrationAction,
+        template: Option<String>,
+        enabled: bool,
+    ) -> Result<(), IntegrationError> {
+        let name = name.into().trim().to_string();
+        if name.is_empty() || name.chars().count() > MAX_NAME_CHARS {
+            return Err(IntegrationError::InvalidName);
+        }
+        filter.validate()?;
+        action.validate()?;
+        let template = template.filter(|t| !t.trim().is_empty());
+        if template.as_ref().is_some_and(|t| t.len() > MAX_TEMPLATE_BYTES) {
+            return Err(IntegrationError::TemplateTooLarge);
+        }
+
+        self.name = name;
+        self.filter = filter;
+        self.action = action;
+        self.template = template;
+        self.enabled = enabled;
+        self.updated_at = Timestamp::now();
+        Ok(())
+    }
+
+    /// Whether `event`, with its [`template_context`], should trigger this
+    /// integration.
+    pub fn is_triggered_by(&self, event: &EventEnvelope, context: &JsonValue) -> bool {
+        self.enabled && self.filter.matches(&event.event_type, context)
+    }
+}
+
+/// Variables available to templates and conditions:
+///
+/// ```text
+/// event.id, event.type, event.occurred_at, event.aggregate_id, event.aggregate_type
+/// payload.*      - the event's own fields
+/// ```
+pub fn template_context(event: &EventEnvelope) -> JsonValue {
+    json!({
+        "event": {
+            "id": event.event_id.to_string(),
+            "type": event.event_type,
+            "occurred_at": event.occurred_at,
+            "aggregate_id": event.aggregate_id,
+            "aggregate_type": event.aggregate_type,
+        },
+        "payload": event.payload,
+    })
+}
+
+/// One-line summary used when a Slack or email action has no template,
+/// e.g. "Choice Sherpa: cycle completed (Cycle 7f3c...)".
+pub fn default_summary(context: &JsonValue) -> String {
+    let field = |path: &str| lookup(context, path).and_then(JsonValue::as_str).unwrap_or("");
+    let event_type = field("event.type");
+    let unversioned = match event_type.rsplit_once(".v") {
+        Some((base, version)) if version.chars().all(|c| c.is_ascii_digit()) => base,
+        _ => event_type,
+    };
+    format!(
+        "Choice Sherpa: {} ({} {})",
+        unversioned.replace(['.', '_'], " "),
+        field("event.aggregate_type"),
+        field("event.aggregate_id")
+    )
+}
+
+fn lookup<'a>(value: &'a JsonValue, path: &str) -> Option<&'a JsonValue> {
+    path.split('.')
+        .try_fold(value, |current, segment| current.get(segment))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn user() -> UserId {
+        UserId::new("user-1").unwrap()
+    }
+
+    fn filter(event_types: &[&str]) -> EventFilter {
+        EventFilter {
+            event_types: event_types.iter().map(|t| t.to_string()).collect(),
+            conditions: Vec::new(),
+        }
+    }
+
+    fn http(url: &str) -> IntegrationAction {
+        IntegrationAction::HttpPost {
+            url: url.to_string(),
+        }
+    }
+
+    fn event(event_type: &str, payload: JsonValue) -> EventEnvelope {
+        EventEnvelope::new(event_type, "cycle-1", "Cycle", payload)
+    }
+
+    #[test]
+    fn new_integrations_are_enabled() {
+        let integration = Integration::new(
+            user(),
+            " Notify Zapier ",
+            filter(&["cycle.completed.v1"]),
+            http("https://hooks.zapier.com/hooks/catch/1/abc"),
+            Some("  ".to_string()),
+        )
+        .unwrap();
+
+        assert!(integration.enabled);
+        assert_eq!(integration.name, "Notify Zapier");
+        assert_eq!(integration.template, None);
+    }
+
+    #[test]
+    fn rejects_unknown_and_duplicate_event_types() {
+        let action = http("https://example.com/hook");
+        assert_eq!(
+            Integration::new(user(), "x", filter(&[]), action.clone(), None).unwrap_err(),
+            IntegrationError::NoEventTypes
+        );
+        assert_eq!(
+            Integration::new(user(), "x", filter(&["membership.created.v1"]), action.clone(), None)
+                .unwrap_err(),
+            IntegrationError::UnsupportedEventType("membership.created.v1".to_string())
+        );
+        assert_eq!(
+            Integration::new(
+                user(),
+                "x",
+                filter(&["cycle.completed.v1", "cycle.completed.v1"]),
+                action,
+                None
+            )
+            .unwrap_err(),
+            IntegrationError::DuplicateEventType("cycle.completed.v1".to_string())
+        );
+    }
+
+    #[test]
+    fn http_actions_only_target_public_https_hosts() {
+        for url in [
+            "http://example.com/hook",
+            "https://localhost/hook",
+            "https://127.0.0.1/hook",
+            "https://[::1]/hook",
+            "https://example.com:8443/hook",
+            "https://user@example.com/hook",
+            "https://db.internal/hook",
+            "https://printer.local/hook",
+            "https://intranet/hook",
+        ] {
+            assert_eq!(http(url).validate(), Err(IntegrationError::InvalidUrl), "{}", url);
+        }
+        assert!(http("https://hooks.example.com/a?b=c").validate().is_ok());
+    }
+
+    #[test]
+    fn email_actions_need_an_address_and_subject() {
+        let email = |to: &str, subject: &str| IntegrationAction::Email {
+            to: to.to_string(),
+            subject: subject.to_string(),
+        };
+        assert_eq!(
+            email("not an address", "Hi").validate(),
+            Err(IntegrationError::InvalidEmail)
+        );
+        assert_eq!(
+            email("team@example.com", " ").validate(),
+            Err(IntegrationError::InvalidSubject)
+        );
+        assert!(email("team@example.com", "{{ event.type }}").validate().is_ok());
+    }
+
+    #[test]
+    fn failed_updates_leave_the_integration_unchanged() {
+        let mut integration = Integration::new(
+            user(),
+            "Notify",
+            filter(&["cycle.completed.v1"]),
+            http("https://example.com/hook"),
+            None,
+        )
+        .unwrap();
+        let before = integration.clone();
+
+        let result = integration.update(
+            "Renamed",
+            filter(&["cycle.completed.v1"]),
+            http("https://localhost/hook"),
+            None,
+            false,
+        );
+
+        assert_eq!(result, Err(IntegrationError::InvalidUrl));
+        assert_eq!(integration, before);
+    }
+
+    #[test]
+    fn filters_match_event_types_and_conditions() {
+        let mut integration = Integration::new(
+            user(),
+            "Recommendations",
+            EventFilter {
+                event_types: vec!["component.completed.v1".to_string()],
+                conditions: vec![FieldCondition {
+                    path: "payload.component_type".to_string(),
+                    equals: json!("recommendation"),
+                }],
+            },
+            http("https://example.com/hook"),
+            None,
+        )
+        .unwrap();
+
+        let matching = event(
+            "component.completed.v1",
+            json!({ "component_type": "recommendation" }),
+        );
+        let other_component = event(
+            "component.completed.v1",
+            json!({ "component_type": "objectives" }),
+        );
+        let other_type = event(
+            "cycle.completed.v1",
+            json!({ "component_type": "recommendation" }),
+        );
+        let triggered =
+            |i: &Integration, e: &EventEnvelope| i.is_triggered_by(e, &template_context(e));
+
+        assert!(triggered(&integration, &matching));
+        assert!(!triggered(&integration, &other_component));
+        assert!(!triggered(&integration, &other_type));
+
+        integration.enabled = false;
+        assert!(!triggered(&integration, &matching));
+    }
+
+    #[test]
+    fn condition_paths_must_name_event_or_payload_fields() {
+        let mut filter = filter(&["cycle.completed.v1"]);
+        filter.conditions.push(FieldCondition {
+            path: "metadata.user_id".to_string(),
+            equals: json!("user-1"),
+        });
+        assert_eq!(
+            filter.validate(),
+            Err(IntegrationError::InvalidConditionPath(
+                "metadata.user_id".to_string()
+            ))
+        );
+    }
+
+    #[test]
+    fn default_summary_names_the_event() {
+        let context = template_context(&event("cycle.completed.v1", json!({})));
+        assert_eq!(
+            default_summary(&context),
+            "Choice Sherpa: cycle completed (Cycle cycle-1)"
+        );
+    }
+
+    #[test]
+    fn actions_serialize_with_a_type_tag() {
+        let action = IntegrationAction::Slack {
+            team_id: "T1".to_string(),
+            channel: "#decisions".to_string(),
+        };
+        let value = serde_json::to_value(&action).unwrap();
+        assert_eq!(value["type"], "slack");
+        assert_eq!(action.kind().as_str(), "slack");
+        assert_eq!(
+            serde_json::from_value::<IntegrationAction>(value).unwrap(),
+            action
+        );
+    }
+}
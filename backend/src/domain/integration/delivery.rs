@@ -0,0 +1,138 @@
+//! IntegrationDelivery - One triggered run of an integration's action.
+//!
+//! Each matching event gets one delivery holding the event's template
+//! context, so retries render the same data even after the event is gone.
+//! Retry state follows `DocumentDelivery`: transient failures back off
+//! exponentially until `MAX_DELIVERY_ATTEMPTS`; permanent failures stop.
+
+use serde_json::Value as JsonValue;
+
+use crate::domain::document::{retry_delay_secs, DeliveryStatus, MAX_DELIVERY_ATTEMPTS};
+use crate::domain::foundation::{
+    EventId, IntegrationDeliveryId, IntegrationId, Timestamp, UserId,
+};
+
+/// One event delivered, or still to be delivered, by an integration.
+#[derive(Debug, Clone, PartialEq)]
+pub struct IntegrationDelivery {
+    pub id: IntegrationDeliveryId,
+    pub integration_id: IntegrationId,
+    pub user_id: UserId,
+    pub event_id: EventId,
+    pub event_type: String,
+    /// Template context captured when the event arrived.
+    pub context: JsonValue,
+    pub status: DeliveryStatus,
+    pub attempts: u32,
+    pub last_error: Option<String>,
+    /// When a pending delivery should next be attempted.
+    pub next_attempt_at: Timestamp,
+    pub created_at: Timestamp,
+    pub sent_at: Option<Timestamp>,
+}
+
+impl IntegrationDelivery {
+    /// A pending delivery, due immediately.
+    pub fn new(
+        integration_id: IntegrationId,
+        user_id: UserId,
+        event_id: EventId,
+        event_type: impl Into<String>,
+        context: JsonValue,
+    ) -> Self {
+        let now = Timestamp::now();
+        Self {
+            id: IntegrationDeliveryId::new(),
+            integration_id,
+            user_id,
+            event_id,
+            event_type: event_type.into(),
+            context,
+            status: DeliveryStatus::Pending,
+            attempts: 0,
+            last_error: None,
+            next_attempt_at: now,
+            created_at: now,
+            sent_at: None,
+        }
+    }
+
+    /// Whether a retry worker should attempt this delivery at `now`.
+    pub fn is_due(&self, now: Timestamp) -> bool {
+        self.status == DeliveryStatus::Pending && !self.next_attempt_at.is_after(&now)
+    }
+
+    pub fn record_sent(&mut self) {
+        self.attempts += 1;
+        self.status = DeliveryStatus::Sent;
+        self.last_error = None;
+        self.sent_at = Some(Timestamp::now());
+    }
+
+    /// Records a failed attempt, scheduling a retry if `retryable` and
+    /// attempts remain.
+    pub fn record_failure(&mut self, error: impl Into<String>, retryable: bool) {
+        self.attempts += 1;
+        self.last_error = Some(error.into());
+        if retryable && self.attempts < MAX_DELIVERY_ATTEMPTS {
+            self.next_attempt_at = Timestamp::now().plus_secs(retry_delay_secs(self.attempts));
+        } else {
+            self.status = DeliveryStatus::Failed;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::document::FIRST_RETRY_DELAY_SECS;
+    use serde_json::json;
+
+    fn delivery() -> IntegrationDelivery {
+        IntegrationDelivery::new(
+            IntegrationId::new(),
+            UserId::new("user-1").unwrap(),
+            EventId::new(),
+            "cycle.completed.v1",
+            json!({ "event": { "type": "cycle.completed.v1" } }),
+        )
+    }
+
+    #[test]
+    fn new_deliveries_are_due_immediately() {
+        let delivery = delivery();
+        assert_eq!(delivery.status, DeliveryStatus::Pending);
+        assert!(delivery.is_due(Timestamp::now()));
+    }
+
+    #[test]
+    fn transient_failures_back_off_until_attempts_run_out() {
+        let mut delivery = delivery();
+
+        delivery.record_failure("503 Service Unavailable", true);
+        assert_eq!(delivery.status, DeliveryStatus::Pending);
+        assert!(!delivery.is_due(Timestamp::now()));
+        assert!(delivery.is_due(Timestamp::now().plus_secs(FIRST_RETRY_DELAY_SECS + 1)));
+
+        for _ in 1..MAX_DELIVERY_ATTEMPTS {
+            delivery.record_failure("503 Service Unavailable", true);
+        }
+        assert_eq!(delivery.status, DeliveryStatus::Failed);
+    }
+
+    #[test]
+    fn permanent_failures_stop_immediately() {
+        let mut delivery = delivery();
+        delivery.record_failure("404 Not Found", false);
+        assert_eq!(delivery.status, DeliveryStatus::Failed);
+        assert!(!delivery.is_due(Timestamp::now()));
+    }
+
+    #[test]
+    fn sent_deliveries_are_no_longer_due() {
+        let mut delivery = delivery();
+        delivery.record_sent();
+        assert_eq!(delivery.status, DeliveryStatus::Sent);
+        assert!(delivery.sent_at.is_some());
+    }
+}
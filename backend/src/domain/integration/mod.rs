@@ -0,0 +1,22 @@
+//! Integration domain module.
+//!
+//! Outbound automations that react to a user's domain events, like "when
+//! any cycle completes, POST the summary here". A superset of plain
+//! webhooks: actions are pluggable and payloads are templated.
+//!
+//! # Types
+//!
+//! - `Integration` - An `EventFilter` plus the `IntegrationAction` it triggers
+//! - `IntegrationAction` - HTTP POST, Slack message, or email
+//! - `IntegrationDelivery` - One triggered action with its retry state
+//! - `template_context` - Variables templates and filter conditions can use
+
+mod aggregate;
+mod delivery;
+
+pub use aggregate::{
+    default_summary, template_context, EventFilter, FieldCondition, Integration,
+    IntegrationAction, IntegrationActionKind, IntegrationError, MAX_FILTER_CONDITIONS,
+    MAX_INTEGRATIONS_PER_USER, MAX_TEMPLATE_BYTES, TRIGGER_EVENT_TYPES,
+};
+pub use delivery::IntegrationDelivery;
@@ -15,6 +15,10 @@ use serde::{Deserialize, Serialize};
 
 use super::{MembershipStatus, MembershipTier};
 
+fn default_seats() -> u32 {
+    1
+}
+
 /// Membership aggregate - represents a user's subscription.
 ///
 /// # Invariants
@@ -52,6 +56,11 @@ pub struct Membership {
     /// Stripe subscription ID (for paid subscriptions).
     pub stripe_subscription_id: Option<String>,
 
+    /// Seats paid for: 1 for an individual, the member count when the
+    /// membership pays for an organization.
+    #[serde(default = "default_seats")]
+    pub seats: u32,
+
     /// When the membership was created.
     pub created_at: Timestamp,
 
@@ -85,6 +94,7 @@ impl Membership {
             promo_code: Some(promo_code),
             stripe_customer_id: None,
             stripe_subscription_id: None,
+            seats: 1,
             created_at: now,
             updated_at: now,
             cancelled_at: None,
@@ -111,6 +121,7 @@ impl Membership {
             promo_code: None,
             stripe_customer_id: Some(stripe_customer_id),
             stripe_subscription_id: None,
+            seats: 1,
             created_at: now,
             updated_at: now,
             cancelled_at: None,
@@ -154,6 +165,62 @@ impl Membership {
         Ok(())
     }
 
+    /// Start a free trial after checkout.
+    ///
+    /// The current period runs until the trial ends. Billing starts when the
+    /// trial converts.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if transition from current status is not allowed.
+    pub fn start_trial(
+        &mut self,
+        trial_start: Timestamp,
+        trial_end: Timestamp,
+        stripe_subscription_id: Option<String>,
+    ) -> Result<(), DomainError> {
+        self.transition_to(MembershipStatus::Trialing)?;
+        self.current_period_start = trial_start;
+        self.current_period_end = trial_end;
+        if let Some(sub_id) = stripe_subscription_id {
+            self.stripe_subscription_id = Some(sub_id);
+        }
+        self.updated_at = Timestamp::now();
+        Ok(())
+    }
+
+    /// When the trial ends, if the membership is trialing.
+    pub fn trial_ends_at(&self) -> Option<Timestamp> {
+        (self.status == MembershipStatus::Trialing).then_some(self.current_period_end)
+    }
+
+    /// Convert a trial to a paid membership for its first billing period.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the membership is not trialing.
+    pub fn convert_trial(
+        &mut self,
+        period_start: Timestamp,
+        period_end: Timestamp,
+    ) -> Result<(), DomainError> {
+        if self.status != MembershipStatus::Trialing {
+            return Err(DomainError::new(
+                ErrorCode::InvalidStateTransition,
+                format!(
+                    "Cannot convert trial: expected Trialing status, got {:?}",
+                    self.status
+                ),
+            ));
+        }
+
+        self.transition_to(MembershipStatus::Active)?;
+        self.current_period_start = period_start;
+        self.current_period_end = period_end;
+        self.updated_at = Timestamp::now();
+        Ok(())
+    }
+
     /// Cancel this membership (effective at period end).
     ///
     /// # Errors
@@ -240,6 +307,25 @@ impl Membership {
         Ok(())
     }
 
+    /// Change the number of seats paid for, returning the previous count.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if `seats` is zero.
+    pub fn set_seats(&mut self, seats: u32) -> Result<u32, DomainError> {
+        if seats == 0 {
+            return Err(DomainError::validation(
+                "seats",
+                "A membership needs at least one seat",
+            ));
+        }
+
+        let previous = self.seats;
+        self.seats = seats;
+        self.updated_at = Timestamp::now();
+        Ok(previous)
+    }
+
     /// Days remaining in current period.
     ///
     /// Returns 0 if period has ended.
@@ -421,6 +507,60 @@ mod tests {
         assert_eq!(membership.stripe_subscription_id, Some("sub_123".to_string()));
     }
 
+    #[test]
+    fn pending_can_start_trial() {
+        let mut membership = Membership::create_paid(
+            test_membership_id(),
+            test_user_id(),
+            MembershipTier::Monthly,
+            "cus_123".to_string(),
+        );
+        let trial_end = Timestamp::now().add_days(14);
+
+        membership
+            .start_trial(period_start(), trial_end, Some("sub_123".to_string()))
+            .unwrap();
+
+        assert_eq!(membership.status, MembershipStatus::Trialing);
+        assert_eq!(membership.trial_ends_at(), Some(trial_end));
+        assert!(membership.has_access());
+    }
+
+    #[test]
+    fn trial_converts_to_active_for_new_period() {
+        let mut membership = Membership::create_paid(
+            test_membership_id(),
+            test_user_id(),
+            MembershipTier::Monthly,
+            "cus_123".to_string(),
+        );
+        membership
+            .start_trial(period_start(), Timestamp::now().add_days(14), None)
+            .unwrap();
+        let end = period_end();
+
+        membership.convert_trial(period_start(), end).unwrap();
+
+        assert_eq!(membership.status, MembershipStatus::Active);
+        assert_eq!(membership.current_period_end, end);
+        assert_eq!(membership.trial_ends_at(), None);
+    }
+
+    #[test]
+    fn only_trials_can_convert() {
+        let mut membership = Membership::create_free(
+            test_membership_id(),
+            test_user_id(),
+            MembershipTier::Annual,
+            "PROMO".to_string(),
+            period_start(),
+            period_end(),
+        );
+
+        let result = membership.convert_trial(period_start(), period_end());
+        assert_eq!(result.unwrap_err().code, ErrorCode::InvalidStateTransition);
+    }
+
     #[test]
     fn active_can_cancel() {
         let mut membership = Membership::create_free(
@@ -554,6 +694,45 @@ mod tests {
         assert!(result.is_err());
     }
 
+    // Seat tests
+
+    #[test]
+    fn new_memberships_have_one_seat() {
+        let membership = Membership::create_paid(
+            test_membership_id(),
+            test_user_id(),
+            MembershipTier::Monthly,
+            "cus_123".to_string(),
+        );
+        assert_eq!(membership.seats, 1);
+    }
+
+    #[test]
+    fn set_seats_returns_previous_count() {
+        let mut membership = Membership::create_paid(
+            test_membership_id(),
+            test_user_id(),
+            MembershipTier::Monthly,
+            "cus_123".to_string(),
+        );
+
+        assert_eq!(membership.set_seats(5).unwrap(), 1);
+        assert_eq!(membership.seats, 5);
+    }
+
+    #[test]
+    fn cannot_set_zero_seats() {
+        let mut membership = Membership::create_paid(
+            test_membership_id(),
+            test_user_id(),
+            MembershipTier::Monthly,
+            "cus_123".to_string(),
+        );
+
+        assert!(membership.set_seats(0).is_err());
+        assert_eq!(membership.seats, 1);
+    }
+
     // Renewal tests
 
     #[test]
@@ -637,6 +816,7 @@ mod tests {
             promo_code: Some("PROMO".to_string()),
             stripe_customer_id: None,
             stripe_subscription_id: None,
+            seats: 1,
             created_at: past_start,
             updated_at: Timestamp::now().add_days(-30),
             cancelled_at: Some(Timestamp::now().add_days(-35)),
@@ -772,6 +952,7 @@ mod tests {
             promo_code: Some("PROMO".to_string()),
             stripe_customer_id: None,
             stripe_subscription_id: None,
+            seats: 1,
             created_at: past_start,
             updated_at: Timestamp::now().add_days(-30),
             cancelled_at: Some(Timestamp::now().add_days(-35)),
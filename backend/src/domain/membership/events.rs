@@ -53,6 +53,47 @@ pub enum MembershipEvent {
         occurred_at: Timestamp,
     },
 
+    /// Membership started a free trial.
+    ///
+    /// State transition: Pending → Trialing
+    ///
+    /// Trigger: `checkout.session.completed` webhook for a trialing subscription
+    TrialStarted {
+        event_id: EventId,
+        membership_id: MembershipId,
+        user_id: UserId,
+        tier: MembershipTier,
+        trial_ends_at: Timestamp,
+        occurred_at: Timestamp,
+    },
+
+    /// Trial is about to end; the first charge follows at `trial_ends_at`.
+    ///
+    /// Trigger: `customer.subscription.trial_will_end` webhook
+    TrialEnding {
+        event_id: EventId,
+        membership_id: MembershipId,
+        user_id: UserId,
+        trial_ends_at: Timestamp,
+        occurred_at: Timestamp,
+    },
+
+    /// Trial converted to a paid membership.
+    ///
+    /// State transition: Trialing → Active
+    ///
+    /// Triggers:
+    /// - User ends the trial early
+    /// - `invoice.paid` webhook for the first charge after the trial
+    TrialConverted {
+        event_id: EventId,
+        membership_id: MembershipId,
+        user_id: UserId,
+        period_start: Timestamp,
+        period_end: Timestamp,
+        occurred_at: Timestamp,
+    },
+
     /// Membership was renewed for a new billing period.
     ///
     /// State transition: Active → Active (renewal)
@@ -146,6 +187,20 @@ pub enum MembershipEvent {
         occurred_at: Timestamp,
     },
 
+    /// Number of seats paid for changed.
+    ///
+    /// Triggers:
+    /// - Members added to or removed from the organization it pays for
+    /// - `customer.subscription.updated` webhook with a new quantity
+    SeatsChanged {
+        event_id: EventId,
+        membership_id: MembershipId,
+        user_id: UserId,
+        previous_seats: u32,
+        new_seats: u32,
+        occurred_at: Timestamp,
+    },
+
     /// Access was checked (for audit logging of access control).
     ///
     /// Note: This is a high-volume event, may be sampled in production.
@@ -192,6 +247,9 @@ impl MembershipEvent {
         match self {
             MembershipEvent::Created { .. } => "membership.created.v1",
             MembershipEvent::Activated { .. } => "membership.activated.v1",
+            MembershipEvent::TrialStarted { .. } => "membership.trial_started.v1",
+            MembershipEvent::TrialEnding { .. } => "membership.trial_ending.v1",
+            MembershipEvent::TrialConverted { .. } => "membership.trial_converted.v1",
             MembershipEvent::Renewed { .. } => "membership.renewed.v1",
             MembershipEvent::PaymentFailed { .. } => "membership.payment_failed.v1",
             MembershipEvent::PaymentRecovered { .. } => "membership.payment_recovered.v1",
@@ -199,6 +257,7 @@ impl MembershipEvent {
             MembershipEvent::Reactivated { .. } => "membership.reactivated.v1",
             MembershipEvent::Expired { .. } => "membership.expired.v1",
             MembershipEvent::TierUpgraded { .. } => "membership.tier_upgraded.v1",
+            MembershipEvent::SeatsChanged { .. } => "membership.seats_changed.v1",
             MembershipEvent::AccessChecked { .. } => "membership.access_checked.v1",
         }
     }
@@ -208,13 +267,17 @@ impl MembershipEvent {
         match self {
             MembershipEvent::Created { membership_id, .. }
             | MembershipEvent::Activated { membership_id, .. }
+            | MembershipEvent::TrialStarted { membership_id, .. }
+            | MembershipEvent::TrialEnding { membership_id, .. }
+            | MembershipEvent::TrialConverted { membership_id, .. }
             | MembershipEvent::Renewed { membership_id, .. }
             | MembershipEvent::PaymentFailed { membership_id, .. }
             | MembershipEvent::PaymentRecovered { membership_id, .. }
             | MembershipEvent::Cancelled { membership_id, .. }
             | MembershipEvent::Reactivated { membership_id, .. }
             | MembershipEvent::Expired { membership_id, .. }
-            | MembershipEvent::TierUpgraded { membership_id, .. } => Some(membership_id),
+            | MembershipEvent::TierUpgraded { membership_id, .. }
+            | MembershipEvent::SeatsChanged { membership_id, .. } => Some(membership_id),
             MembershipEvent::AccessChecked { membership_id, .. } => membership_id.as_ref(),
         }
     }
@@ -224,6 +287,9 @@ impl MembershipEvent {
         match self {
             MembershipEvent::Created { user_id, .. }
             | MembershipEvent::Activated { user_id, .. }
+            | MembershipEvent::TrialStarted { user_id, .. }
+            | MembershipEvent::TrialEnding { user_id, .. }
+            | MembershipEvent::TrialConverted { user_id, .. }
             | MembershipEvent::Renewed { user_id, .. }
             | MembershipEvent::PaymentFailed { user_id, .. }
             | MembershipEvent::PaymentRecovered { user_id, .. }
@@ -231,6 +297,7 @@ impl MembershipEvent {
             | MembershipEvent::Reactivated { user_id, .. }
             | MembershipEvent::Expired { user_id, .. }
             | MembershipEvent::TierUpgraded { user_id, .. }
+            | MembershipEvent::SeatsChanged { user_id, .. }
             | MembershipEvent::AccessChecked { user_id, .. } => user_id,
         }
     }
@@ -240,6 +307,9 @@ impl MembershipEvent {
         match self {
             MembershipEvent::Created { occurred_at, .. }
             | MembershipEvent::Activated { occurred_at, .. }
+            | MembershipEvent::TrialStarted { occurred_at, .. }
+            | MembershipEvent::TrialEnding { occurred_at, .. }
+            | MembershipEvent::TrialConverted { occurred_at, .. }
             | MembershipEvent::Renewed { occurred_at, .. }
             | MembershipEvent::PaymentFailed { occurred_at, .. }
             | MembershipEvent::PaymentRecovered { occurred_at, .. }
@@ -247,6 +317,7 @@ impl MembershipEvent {
             | MembershipEvent::Reactivated { occurred_at, .. }
             | MembershipEvent::Expired { occurred_at, .. }
             | MembershipEvent::TierUpgraded { occurred_at, .. }
+            | MembershipEvent::SeatsChanged { occurred_at, .. }
             | MembershipEvent::AccessChecked { occurred_at, .. } => *occurred_at,
         }
     }
@@ -256,6 +327,9 @@ impl MembershipEvent {
         match self {
             MembershipEvent::Created { event_id, .. }
             | MembershipEvent::Activated { event_id, .. }
+            | MembershipEvent::TrialStarted { event_id, .. }
+            | MembershipEvent::TrialEnding { event_id, .. }
+            | MembershipEvent::TrialConverted { event_id, .. }
             | MembershipEvent::Renewed { event_id, .. }
             | MembershipEvent::PaymentFailed { event_id, .. }
             | MembershipEvent::PaymentRecovered { event_id, .. }
@@ -263,6 +337,7 @@ impl MembershipEvent {
             | MembershipEvent::Reactivated { event_id, .. }
             | MembershipEvent::Expired { event_id, .. }
             | MembershipEvent::TierUpgraded { event_id, .. }
+            | MembershipEvent::SeatsChanged { event_id, .. }
             | MembershipEvent::AccessChecked { event_id, .. } => event_id,
         }
     }
@@ -516,6 +591,29 @@ mod tests {
                 period_end: now(),
                 occurred_at: now(),
             },
+            MembershipEvent::TrialStarted {
+                event_id: test_event_id(),
+                membership_id: test_membership_id(),
+                user_id: test_user_id(),
+                tier: MembershipTier::Monthly,
+                trial_ends_at: now(),
+                occurred_at: now(),
+            },
+            MembershipEvent::TrialEnding {
+                event_id: test_event_id(),
+                membership_id: test_membership_id(),
+                user_id: test_user_id(),
+                trial_ends_at: now(),
+                occurred_at: now(),
+            },
+            MembershipEvent::TrialConverted {
+                event_id: test_event_id(),
+                membership_id: test_membership_id(),
+                user_id: test_user_id(),
+                period_start: now(),
+                period_end: now(),
+                occurred_at: now(),
+            },
             MembershipEvent::Renewed {
                 event_id: test_event_id(),
                 membership_id: test_membership_id(),
@@ -566,6 +664,14 @@ mod tests {
                 new_tier: MembershipTier::Monthly,
                 occurred_at: now(),
             },
+            MembershipEvent::SeatsChanged {
+                event_id: test_event_id(),
+                membership_id: test_membership_id(),
+                user_id: test_user_id(),
+                previous_seats: 1,
+                new_seats: 3,
+                occurred_at: now(),
+            },
             MembershipEvent::AccessChecked {
                 event_id: test_event_id(),
                 membership_id: Some(test_membership_id()),
@@ -5,18 +5,23 @@
 
 use crate::domain::foundation::StateMachine;
 use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
 
 /// Membership subscription status.
 ///
 /// Represents the current state of a user's subscription in the
 /// payment lifecycle.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
 #[serde(rename_all = "snake_case")]
 pub enum MembershipStatus {
     /// Initial state for paid subscriptions awaiting first payment.
     /// No access until payment completes.
     Pending,
 
+    /// Free trial of a paid tier with complete access.
+    /// Billing starts when the trial converts.
+    Trialing,
+
     /// Fully paid subscription with complete access.
     Active,
 
@@ -37,6 +42,7 @@ impl MembershipStatus {
     /// Returns true if this status grants access to the application.
     ///
     /// Access is granted for:
+    /// - Trialing: Full access until the trial ends
     /// - Active: Full paid access
     /// - PastDue: Grace period during payment retry
     /// - Cancelled: Until period end
@@ -47,7 +53,10 @@ impl MembershipStatus {
     pub fn has_access(&self) -> bool {
         matches!(
             self,
-            MembershipStatus::Active | MembershipStatus::PastDue | MembershipStatus::Cancelled
+            MembershipStatus::Trialing
+                | MembershipStatus::Active
+                | MembershipStatus::PastDue
+                | MembershipStatus::Cancelled
         )
     }
 }
@@ -59,7 +68,13 @@ impl StateMachine for MembershipStatus {
             (self, target),
             // From PENDING
             (Pending, Active)
+                | (Pending, Trialing)
                 | (Pending, Expired)
+            // From TRIALING
+                | (Trialing, Active) // Converted
+                | (Trialing, PastDue) // First charge failed
+                | (Trialing, Cancelled)
+                | (Trialing, Expired)
             // From ACTIVE
                 | (Active, PastDue)
                 | (Active, Cancelled)
@@ -80,7 +95,8 @@ impl StateMachine for MembershipStatus {
     fn valid_transitions(&self) -> Vec<Self> {
         use MembershipStatus::*;
         match self {
-            Pending => vec![Active, Expired],
+            Pending => vec![Active, Trialing, Expired],
+            Trialing => vec![Active, PastDue, Cancelled, Expired],
             Active => vec![PastDue, Cancelled, Expired, Active],
             PastDue => vec![Active, Expired, Cancelled],
             Cancelled => vec![Active, Expired],
@@ -122,6 +138,31 @@ mod tests {
         assert!(result.is_err());
     }
 
+    #[test]
+    fn pending_can_start_trial() {
+        let status = MembershipStatus::Pending;
+        assert!(status.can_transition_to(&MembershipStatus::Trialing));
+
+        let result = status.transition_to(MembershipStatus::Trialing);
+        assert_eq!(result, Ok(MembershipStatus::Trialing));
+    }
+
+    #[test]
+    fn trialing_can_convert_to_active() {
+        let status = MembershipStatus::Trialing;
+        assert!(status.can_transition_to(&MembershipStatus::Active));
+
+        let result = status.transition_to(MembershipStatus::Active);
+        assert_eq!(result, Ok(MembershipStatus::Active));
+    }
+
+    #[test]
+    fn trialing_cannot_restart_trial() {
+        let status = MembershipStatus::Trialing;
+        assert!(!status.can_transition_to(&MembershipStatus::Trialing));
+        assert!(!MembershipStatus::Active.can_transition_to(&MembershipStatus::Trialing));
+    }
+
     #[test]
     fn active_can_transition_to_past_due() {
         let status = MembershipStatus::Active;
@@ -201,6 +242,11 @@ mod tests {
         assert!(MembershipStatus::Active.has_access());
     }
 
+    #[test]
+    fn has_access_true_for_trialing() {
+        assert!(MembershipStatus::Trialing.has_access());
+    }
+
     #[test]
     fn has_access_true_for_past_due_in_grace() {
         assert!(MembershipStatus::PastDue.has_access());
@@ -227,6 +273,7 @@ mod tests {
     fn valid_transitions_are_consistent_with_can_transition_to() {
         for status in [
             MembershipStatus::Pending,
+            MembershipStatus::Trialing,
             MembershipStatus::Active,
             MembershipStatus::PastDue,
             MembershipStatus::Cancelled,
@@ -3,11 +3,12 @@
 //! Represents the subscription tier levels available in Choice Sherpa.
 
 use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
 
 /// Membership subscription tier.
 ///
 /// Determines feature access, usage limits, and pricing.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, ToSchema)]
 #[serde(rename_all = "lowercase")]
 pub enum MembershipTier {
     /// Free tier - limited features, good for evaluation.
@@ -91,6 +91,9 @@ pub struct TierLimits {
 
     /// Whether PDF/CSV export is enabled.
     pub pdf_export_enabled: bool,
+    /// Whether the stakeholder slide deck export is enabled.
+    #[serde(default)]
+    pub slide_deck_export_enabled: bool,
     /// Whether share link generation is enabled.
     pub share_link_enabled: bool,
     /// Whether API access is enabled.
@@ -113,6 +116,12 @@ impl TierLimits {
     /// | Free | 50 | Standard | No |
     /// | Monthly | 200 | Standard | Yes |
     /// | Annual | Unlimited | Advanced | Yes |
+    ///
+    /// | Tier | PDF Export | Slide Deck Export |
+    /// |------|------------|-------------------|
+    /// | Free | No | No |
+    /// | Monthly | Yes | No |
+    /// | Annual | Yes | Yes |
     pub fn for_tier(tier: MembershipTier) -> Self {
         match tier {
             MembershipTier::Free => Self::free(),
@@ -147,6 +156,7 @@ impl TierLimits {
 
             // Export & Sharing
             pdf_export_enabled: false,
+            slide_deck_export_enabled: false,
             share_link_enabled: false,
             api_access: false,
         }
@@ -178,6 +188,7 @@ impl TierLimits {
 
             // Export & Sharing
             pdf_export_enabled: true,
+            slide_deck_export_enabled: false,
             share_link_enabled: true,
             api_access: false,
         }
@@ -209,6 +220,7 @@ impl TierLimits {
 
             // Export & Sharing
             pdf_export_enabled: true,
+            slide_deck_export_enabled: true,
             share_link_enabled: true,
             api_access: true,
         }
@@ -239,6 +251,7 @@ impl TierLimits {
             improvement_suggestions_enabled: false,
 
             pdf_export_enabled: false,
+            slide_deck_export_enabled: false,
             share_link_enabled: false,
             api_access: false,
         }
@@ -299,6 +312,11 @@ impl TierLimits {
         self.pdf_export_enabled
     }
 
+    /// Check if user can export the stakeholder slide deck.
+    pub fn can_export_slide_deck(&self) -> bool {
+        self.slide_deck_export_enabled
+    }
+
     /// Check if user can create share links.
     pub fn can_share(&self) -> bool {
         self.share_link_enabled
@@ -509,6 +527,12 @@ mod tests {
         assert!(limits.can_export_pdf());
     }
 
+    #[test]
+    fn premium_tier_has_no_slide_deck_export() {
+        let limits = TierLimits::premium();
+        assert!(!limits.can_export_slide_deck());
+    }
+
     #[test]
     fn premium_tier_has_share() {
         let limits = TierLimits::premium();
@@ -566,6 +590,12 @@ mod tests {
         assert!(limits.api_access);
     }
 
+    #[test]
+    fn pro_tier_has_slide_deck_export() {
+        let limits = TierLimits::pro();
+        assert!(limits.can_export_slide_deck());
+    }
+
     // ─── No Membership Tests ───────────────────────────────────────
 
     #[test]
@@ -3,21 +3,41 @@
 //! # Module Organization
 //!
 //! - `foundation` - Shared domain primitives (value objects, IDs, enums, errors)
+//! - `audit` - Hash-chained log of every command handler execution
+//! - `backup` - Versioned archives of a whole instance for backup and restore
 //! - `membership` - Subscription lifecycle and access control
 //! - `proact` - PrOACT component types and traits
 //! - `session` - Decision session lifecycle and events
+//! - `consent` - Terms of service, privacy, and data-processing consent ledger
+//! - `privacy` - Data subject requests such as exporting all of a user's data
+//! - `profile` - Decision history and patterns across a user's decisions
 //! - `cycle` - Decision cycle aggregate and lifecycle management
 //! - `analysis` - Pure domain services for decision analysis (Pugh, DQ, tradeoffs)
+//! - `decision_tree` - Sequential decisions as trees rolled back to expected values
 //! - `conversation` - AI-guided dialogues within PrOACT components
 //! - `ai_engine` - AI conversation orchestration and PrOACT flow management
 //! - `dashboard` - Read models and view compositions for dashboard interface
+//! - `document` - Markdown decision documents parsed back into component edits
+//! - `integration` - User-configured outbound automations triggered by domain events
+//! - `organization` - Teams sharing sessions and a subscription
+//! - `provisioning` - Users managed by an identity provider through SCIM
 
 pub mod ai_engine;
 pub mod analysis;
+pub mod audit;
+pub mod backup;
+pub mod consent;
 pub mod conversation;
 pub mod cycle;
 pub mod dashboard;
+pub mod decision_tree;
+pub mod document;
 pub mod foundation;
+pub mod integration;
 pub mod membership;
+pub mod organization;
+pub mod privacy;
 pub mod proact;
+pub mod profile;
+pub mod provisioning;
 pub mod session;
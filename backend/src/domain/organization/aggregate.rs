@@ -0,0 +1,356 @@
+//! Organization aggregate - A team of users sharing sessions and a plan.
+//!
+//! Members hold one of three roles. Owners and admins manage the member
+//! list; only owners decide which `Membership` pays for the organization.
+//! Every member gets that membership's tier, so `seat_count` is what a
+//! per-seat subscription is billed for.
+
+use serde::{Deserialize, Serialize};
+
+use crate::domain::foundation::{MembershipId, OrganizationId, Timestamp, UserId};
+
+/// Longest accepted organization name, in characters.
+pub const MAX_ORGANIZATION_NAME_CHARS: usize = 100;
+
+/// Members one organization may have.
+pub const MAX_ORGANIZATION_MEMBERS: usize = 500;
+
+/// A member's role within an organization.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum OrganizationRole {
+    /// Manages members and billing; at least one is always present.
+    Owner,
+    /// Manages members.
+    Admin,
+    /// Shares the organization's sessions and plan.
+    Member,
+}
+
+impl OrganizationRole {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            OrganizationRole::Owner => "owner",
+            OrganizationRole::Admin => "admin",
+            OrganizationRole::Member => "member",
+        }
+    }
+
+    pub fn parse(s: &str) -> Option<Self> {
+        match s {
+            "owner" => Some(OrganizationRole::Owner),
+            "admin" => Some(OrganizationRole::Admin),
+            "member" => Some(OrganizationRole::Member),
+            _ => None,
+        }
+    }
+
+    /// Whether this role may add and remove members.
+    pub fn can_manage_members(&self) -> bool {
+        matches!(self, OrganizationRole::Owner | OrganizationRole::Admin)
+    }
+}
+
+/// A user's place in an organization.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct OrganizationMember {
+    pub user_id: UserId,
+    pub role: OrganizationRole,
+    pub joined_at: Timestamp,
+}
+
+/// Why an organization change was rejected.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum OrganizationError {
+    #[error("Organization name must be 1-{MAX_ORGANIZATION_NAME_CHARS} characters")]
+    InvalidName,
+
+    #[error("User {0} is already a member")]
+    AlreadyMember(UserId),
+
+    #[error("User {0} is not a member")]
+    NotAMember(UserId),
+
+    #[error("Organizations can have at most {MAX_ORGANIZATION_MEMBERS} members")]
+    MemberLimitReached,
+
+    #[error("Your role does not allow this change")]
+    NotPermitted,
+
+    #[error("An organization must keep at least one owner")]
+    LastOwner,
+}
+
+/// A team whose members share sessions and, optionally, a paid plan.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Organization {
+    pub id: OrganizationId,
+    pub name: String,
+    /// Members in the order they joined.
+    pub members: Vec<OrganizationMember>,
+    /// Membership whose plan covers every member; `None` until an owner
+    /// attaches one.
+    pub billing_membership_id: Option<MembershipId>,
+    pub created_at: Timestamp,
+    pub updated_at: Timestamp,
+}
+
+impl Organization {
+    /// Creates an organization with `owner` as its only member.
+    pub fn new(name: impl Into<String>, owner: UserId) -> Result<Self, OrganizationError> {
+        let now = Timestamp::now();
+        Ok(Self {
+            id: OrganizationId::new(),
+            name: validate_name(name)?,
+            members: vec![OrganizationMember {
+                user_id: owner,
+                role: OrganizationRole::Owner,
+                joined_at: now,
+            }],
+            billing_membership_id: None,
+            created_at: now,
+            updated_at: now,
+        })
+    }
+
+    /// The member record for `user_id`, if they belong to the organization.
+    pub fn member(&self, user_id: &UserId) -> Option<&OrganizationMember> {
+        self.members.iter().find(|m| &m.user_id == user_id)
+    }
+
+    pub fn is_member(&self, user_id: &UserId) -> bool {
+        self.member(user_id).is_some()
+    }
+
+    pub fn role_of(&self, user_id: &UserId) -> Option<OrganizationRole> {
+        self.member(user_id).map(|m| m.role)
+    }
+
+    /// Number of members, each of whom occupies one seat of the plan.
+    pub fn seat_count(&self) -> u32 {
+        self.members.len() as u32
+    }
+
+    /// Renames the organization. Owners and admins only.
+    pub fn rename(
+        &mut self,
+        actor: &UserId,
+        name: impl Into<String>,
+    ) -> Result<(), OrganizationError> {
+        self.require_manager(actor)?;
+        self.name = validate_name(name)?;
+        self.updated_at = Timestamp::now();
+        Ok(())
+    }
+
+    /// Adds `user_id` with `role`. Owners and admins only, and only owners
+    /// may add other owners.
+    pub fn add_member(
+        &mut self,
+        actor: &UserId,
+        user_id: UserId,
+        role: OrganizationRole,
+    ) -> Result<(), OrganizationError> {
+        let actor_role = self.require_manager(actor)?;
+        if role == OrganizationRole::Owner && actor_role != OrganizationRole::Owner {
+            return Err(OrganizationError::NotPermitted);
+        }
+        if self.is_member(&user_id) {
+            return Err(OrganizationError::AlreadyMember(user_id));
+        }
+        if self.members.len() >= MAX_ORGANIZATION_MEMBERS {
+            return Err(OrganizationError::MemberLimitReached);
+        }
+
+        self.members.push(OrganizationMember {
+            user_id,
+            role,
+            joined_at: Timestamp::now(),
+        });
+        self.updated_at = Timestamp::now();
+        Ok(())
+    }
+
+    /// Removes `user_id`. Members may always leave; removing someone else
+    /// takes an owner or admin, and only owners may remove owners.
+    pub fn remove_member(
+        &mut self,
+        actor: &UserId,
+        user_id: &UserId,
+    ) -> Result<OrganizationMember, OrganizationError> {
+        let role = self
+            .role_of(user_id)
+            .ok_or_else(|| OrganizationError::NotAMember(user_id.clone()))?;
+        if actor != user_id {
+            let actor_role = self.require_manager(actor)?;
+            if role == OrganizationRole::Owner && actor_role != OrganizationRole::Owner {
+                return Err(OrganizationError::NotPermitted);
+            }
+        }
+        if role == OrganizationRole::Owner && self.owner_count() == 1 {
+            return Err(OrganizationError::LastOwner);
+        }
+
+        let index = self
+            .members
+            .iter()
+            .position(|m| &m.user_id == user_id)
+            .expect("member was found above");
+        let removed = self.members.remove(index);
+        self.updated_at = Timestamp::now();
+        Ok(removed)
+    }
+
+    /// Sets or clears the membership that pays for the organization.
+    /// Owners only.
+    pub fn set_billing_membership(
+        &mut self,
+        actor: &UserId,
+        membership_id: Option<MembershipId>,
+    ) -> Result<(), OrganizationError> {
+        if self.role_of(actor) != Some(OrganizationRole::Owner) {
+            return Err(OrganizationError::NotPermitted);
+        }
+        self.billing_membership_id = membership_id;
+        self.updated_at = Timestamp::now();
+        Ok(())
+    }
+
+    fn owner_count(&self) -> usize {
+        self.members
+            .iter()
+            .filter(|m| m.role == OrganizationRole::Owner)
+            .count()
+    }
+
+    fn require_manager(&self, actor: &UserId) -> Result<OrganizationRole, OrganizationError> {
+        self.role_of(actor)
+            .filter(OrganizationRole::can_manage_members)
+            .ok_or(OrganizationError::NotPermitted)
+    }
+}
+
+fn validate_name(name: impl Into<String>) -> Result<String, OrganizationError> {
+    let name = name.into().trim().to_string();
+    if name.is_empty() || name.chars().count() > MAX_ORGANIZATION_NAME_CHARS {
+        return Err(OrganizationError::InvalidName);
+    }
+    Ok(name)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn user(id: &str) -> UserId {
+        UserId::new(id).unwrap()
+    }
+
+    fn organization() -> Organization {
+        Organization::new("Acme Strategy", user("owner")).unwrap()
+    }
+
+    #[test]
+    fn creator_becomes_the_only_owner() {
+        let org = organization();
+
+        assert_eq!(org.role_of(&user("owner")), Some(OrganizationRole::Owner));
+        assert_eq!(org.seat_count(), 1);
+        assert!(org.billing_membership_id.is_none());
+    }
+
+    #[test]
+    fn roles_round_trip_through_strings() {
+        for role in [
+            OrganizationRole::Owner,
+            OrganizationRole::Admin,
+            OrganizationRole::Member,
+        ] {
+            assert_eq!(OrganizationRole::parse(role.as_str()), Some(role));
+        }
+        assert_eq!(OrganizationRole::parse("guest"), None);
+    }
+
+    #[test]
+    fn rejects_blank_names() {
+        assert_eq!(
+            Organization::new("   ", user("owner")).unwrap_err(),
+            OrganizationError::InvalidName
+        );
+    }
+
+    #[test]
+    fn admins_add_members_but_not_owners() {
+        let mut org = organization();
+        org.add_member(&user("owner"), user("admin"), OrganizationRole::Admin)
+            .unwrap();
+
+        org.add_member(&user("admin"), user("member"), OrganizationRole::Member)
+            .unwrap();
+        assert_eq!(
+            org.add_member(&user("admin"), user("other"), OrganizationRole::Owner),
+            Err(OrganizationError::NotPermitted)
+        );
+        assert_eq!(org.seat_count(), 3);
+    }
+
+    #[test]
+    fn plain_members_cannot_add_members() {
+        let mut org = organization();
+        org.add_member(&user("owner"), user("member"), OrganizationRole::Member)
+            .unwrap();
+
+        assert_eq!(
+            org.add_member(&user("member"), user("other"), OrganizationRole::Member),
+            Err(OrganizationError::NotPermitted)
+        );
+    }
+
+    #[test]
+    fn rejects_duplicate_members() {
+        let mut org = organization();
+
+        assert_eq!(
+            org.add_member(&user("owner"), user("owner"), OrganizationRole::Member),
+            Err(OrganizationError::AlreadyMember(user("owner")))
+        );
+    }
+
+    #[test]
+    fn members_can_leave_on_their_own() {
+        let mut org = organization();
+        org.add_member(&user("owner"), user("member"), OrganizationRole::Member)
+            .unwrap();
+
+        let removed = org.remove_member(&user("member"), &user("member")).unwrap();
+
+        assert_eq!(removed.user_id, user("member"));
+        assert!(!org.is_member(&user("member")));
+    }
+
+    #[test]
+    fn last_owner_cannot_be_removed() {
+        let mut org = organization();
+
+        assert_eq!(
+            org.remove_member(&user("owner"), &user("owner")),
+            Err(OrganizationError::LastOwner)
+        );
+    }
+
+    #[test]
+    fn only_owners_set_billing() {
+        let mut org = organization();
+        org.add_member(&user("owner"), user("admin"), OrganizationRole::Admin)
+            .unwrap();
+        let membership_id = MembershipId::new();
+
+        assert_eq!(
+            org.set_billing_membership(&user("admin"), Some(membership_id)),
+            Err(OrganizationError::NotPermitted)
+        );
+        org.set_billing_membership(&user("owner"), Some(membership_id))
+            .unwrap();
+        assert_eq!(org.billing_membership_id, Some(membership_id));
+    }
+}
@@ -0,0 +1,106 @@
+//! Organization domain events.
+//!
+//! Membership changes carry the resulting `seat_count`, so billing can
+//! keep a per-seat subscription in step without reloading the organization:
+//! - `OrganizationCreated` - New organization with its first owner
+//! - `OrganizationMemberAdded` - User joined an organization
+//! - `OrganizationMemberRemoved` - User left or was removed
+//! - `OrganizationBillingChanged` - Paying membership attached or detached
+
+use serde::{Deserialize, Serialize};
+
+use crate::domain::foundation::{
+    domain_event, EventId, MembershipId, OrganizationId, Timestamp, UserId,
+};
+
+use super::OrganizationRole;
+
+/// Published when an organization is created.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OrganizationCreated {
+    pub event_id: EventId,
+    pub organization_id: OrganizationId,
+    pub name: String,
+    /// The creator, who becomes the first owner.
+    pub owner_id: UserId,
+    pub created_at: Timestamp,
+}
+
+domain_event!(
+    OrganizationCreated,
+    event_type = "organization.created.v1",
+    schema_version = 1,
+    aggregate_id = organization_id,
+    aggregate_type = "Organization",
+    occurred_at = created_at,
+    event_id = event_id
+);
+
+/// Published when a user joins an organization.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OrganizationMemberAdded {
+    pub event_id: EventId,
+    pub organization_id: OrganizationId,
+    pub user_id: UserId,
+    pub role: OrganizationRole,
+    /// Who added them.
+    pub added_by: UserId,
+    /// Members after the change.
+    pub seat_count: u32,
+    pub added_at: Timestamp,
+}
+
+domain_event!(
+    OrganizationMemberAdded,
+    event_type = "organization.member_added.v1",
+    schema_version = 1,
+    aggregate_id = organization_id,
+    aggregate_type = "Organization",
+    occurred_at = added_at,
+    event_id = event_id
+);
+
+/// Published when a user leaves or is removed from an organization.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OrganizationMemberRemoved {
+    pub event_id: EventId,
+    pub organization_id: OrganizationId,
+    pub user_id: UserId,
+    /// Who removed them; the user themselves when they left.
+    pub removed_by: UserId,
+    /// Members after the change.
+    pub seat_count: u32,
+    pub removed_at: Timestamp,
+}
+
+domain_event!(
+    OrganizationMemberRemoved,
+    event_type = "organization.member_removed.v1",
+    schema_version = 1,
+    aggregate_id = organization_id,
+    aggregate_type = "Organization",
+    occurred_at = removed_at,
+    event_id = event_id
+);
+
+/// Published when an owner attaches or detaches the paying membership.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OrganizationBillingChanged {
+    pub event_id: EventId,
+    pub organization_id: OrganizationId,
+    /// The new paying membership; `None` when billing was detached.
+    pub billing_membership_id: Option<MembershipId>,
+    pub changed_by: UserId,
+    pub seat_count: u32,
+    pub changed_at: Timestamp,
+}
+
+domain_event!(
+    OrganizationBillingChanged,
+    event_type = "organization.billing_changed.v1",
+    schema_version = 1,
+    aggregate_id = organization_id,
+    aggregate_type = "Organization",
+    occurred_at = changed_at,
+    event_id = event_id
+);
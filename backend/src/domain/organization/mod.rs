@@ -0,0 +1,27 @@
+//! Organization domain module.
+//!
+//! Teams of users who share decision sessions and a subscription. A user's
+//! access comes from their own membership or from any organization they
+//! belong to whose billing membership is in good standing.
+//!
+//! Unlike the email-domain keys that scope document templates and team
+//! profile settings, organizations are created explicitly and can include
+//! users from any domain.
+//!
+//! # Types
+//!
+//! - `Organization` - Members with roles, plus the membership that pays
+//! - `OrganizationRole` - Owner, admin, or member
+//! - `events` - Creation, membership, and billing changes
+
+mod aggregate;
+mod events;
+
+pub use aggregate::{
+    Organization, OrganizationError, OrganizationMember, OrganizationRole,
+    MAX_ORGANIZATION_MEMBERS, MAX_ORGANIZATION_NAME_CHARS,
+};
+pub use events::{
+    OrganizationBillingChanged, OrganizationCreated, OrganizationMemberAdded,
+    OrganizationMemberRemoved,
+};
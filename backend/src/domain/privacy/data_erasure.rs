@@ -0,0 +1,311 @@
+//! DataErasure - A user's request to have all their data erased.
+//!
+//! Requests are saved as pending and carried out by a background worker,
+//! which runs every registered eraser, then asks each one what is left and
+//! records the answers in an `ErasureReport`. An erasure only completes once
+//! the report shows nothing remaining; otherwise it is retried with the same
+//! backoff as data exports until [`MAX_ERASURE_ATTEMPTS`]. Erasers are
+//! idempotent, so a retry simply runs them all again.
+//!
+//! The request itself is kept as proof the erasure happened. It holds only
+//! the user ID and the report: the recipient address is forgotten once the
+//! completion email has been sent.
+
+use serde::{Deserialize, Serialize};
+
+use super::data_export::retry_delay_secs;
+use crate::domain::foundation::{DataErasureId, Timestamp, UserId};
+
+/// Attempts before an erasure is marked failed and left for an operator.
+pub const MAX_ERASURE_ATTEMPTS: u32 = 5;
+
+/// Where an erasure stands.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DataErasureStatus {
+    /// Waiting for its first attempt or a retry.
+    Pending,
+    /// Every eraser ran and verification found nothing left.
+    Completed,
+    /// Gave up: a permanent error, or out of attempts.
+    Failed,
+}
+
+impl DataErasureStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            DataErasureStatus::Pending => "pending",
+            DataErasureStatus::Completed => "completed",
+            DataErasureStatus::Failed => "failed",
+        }
+    }
+
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "pending" => Some(DataErasureStatus::Pending),
+            "completed" => Some(DataErasureStatus::Completed),
+            "failed" => Some(DataErasureStatus::Failed),
+            _ => None,
+        }
+    }
+}
+
+/// How a kind of data was made unrecoverable.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ErasureMethod {
+    /// Records were deleted from the store.
+    Deleted,
+    /// Records that cannot be removed in place, such as encrypted backups,
+    /// were made unreadable by destroying the key they were encrypted with.
+    CryptoShredded,
+}
+
+/// What one eraser did.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ErasedData {
+    pub method: ErasureMethod,
+    /// Records erased by this run; zero when an earlier attempt got them.
+    pub records: u64,
+}
+
+impl ErasedData {
+    pub fn deleted(records: u64) -> Self {
+        Self {
+            method: ErasureMethod::Deleted,
+            records,
+        }
+    }
+
+    pub fn crypto_shredded(records: u64) -> Self {
+        Self {
+            method: ErasureMethod::CryptoShredded,
+            records,
+        }
+    }
+}
+
+/// One section of the verification report.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ErasureStep {
+    /// Kind of data, e.g. `"sessions"`.
+    pub section: String,
+    pub method: ErasureMethod,
+    pub records: u64,
+    /// Records still found for the user after erasing.
+    pub remaining: u64,
+}
+
+impl ErasureStep {
+    pub fn new(section: impl Into<String>, erased: ErasedData, remaining: u64) -> Self {
+        Self {
+            section: section.into(),
+            method: erased.method,
+            records: erased.records,
+            remaining,
+        }
+    }
+}
+
+/// What was erased, section by section, and what verification found left.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ErasureReport {
+    pub steps: Vec<ErasureStep>,
+    pub generated_at: Timestamp,
+}
+
+impl ErasureReport {
+    pub fn new(steps: Vec<ErasureStep>) -> Self {
+        Self {
+            steps,
+            generated_at: Timestamp::now(),
+        }
+    }
+
+    /// Whether verification found nothing left in any section.
+    pub fn is_verified(&self) -> bool {
+        self.steps.iter().all(|step| step.remaining == 0)
+    }
+
+    /// Sections still holding data for the user.
+    pub fn unverified_sections(&self) -> Vec<&str> {
+        self.steps
+            .iter()
+            .filter(|step| step.remaining > 0)
+            .map(|step| step.section.as_str())
+            .collect()
+    }
+
+    /// Records erased across all sections.
+    pub fn total_records(&self) -> u64 {
+        self.steps.iter().map(|step| step.records).sum()
+    }
+}
+
+/// One request to erase a user's data.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DataErasure {
+    pub id: DataErasureId,
+    pub user_id: UserId,
+    /// Address the completion email is sent to; cleared once sent.
+    pub recipient: Option<String>,
+    pub status: DataErasureStatus,
+    pub attempts: u32,
+    pub last_error: Option<String>,
+    /// When a pending erasure should next be attempted.
+    pub next_attempt_at: Timestamp,
+    pub requested_at: Timestamp,
+    /// Report from the latest attempt.
+    pub report: Option<ErasureReport>,
+    pub completed_at: Option<Timestamp>,
+}
+
+impl DataErasure {
+    /// A pending erasure, due immediately.
+    pub fn new(user_id: UserId, recipient: Option<String>) -> Self {
+        let now = Timestamp::now();
+        Self {
+            id: DataErasureId::new(),
+            user_id,
+            recipient,
+            status: DataErasureStatus::Pending,
+            attempts: 0,
+            last_error: None,
+            next_attempt_at: now,
+            requested_at: now,
+            report: None,
+            completed_at: None,
+        }
+    }
+
+    /// Whether a worker should attempt this erasure at `now`.
+    pub fn is_due(&self, now: Timestamp) -> bool {
+        self.status == DataErasureStatus::Pending && !self.next_attempt_at.is_after(&now)
+    }
+
+    /// Whether the erasure is still being carried out.
+    pub fn is_in_progress(&self) -> bool {
+        self.status == DataErasureStatus::Pending
+    }
+
+    /// Records an attempt whose report verified clean.
+    pub fn record_completed(&mut self, report: ErasureReport) {
+        self.attempts += 1;
+        self.status = DataErasureStatus::Completed;
+        self.last_error = None;
+        self.report = Some(report);
+        self.completed_at = Some(Timestamp::now());
+    }
+
+    /// Records an attempt that found data left behind. Always retryable:
+    /// the erasers run again and usually catch writes that raced the first
+    /// pass.
+    pub fn record_unverified(&mut self, report: ErasureReport) {
+        let error = format!(
+            "Data remaining after erasure: {}",
+            report.unverified_sections().join(", ")
+        );
+        self.report = Some(report);
+        self.record_failure(error, true);
+    }
+
+    /// Records a failed attempt, scheduling a retry if `retryable` and
+    /// attempts remain.
+    pub fn record_failure(&mut self, error: impl Into<String>, retryable: bool) {
+        self.attempts += 1;
+        self.last_error = Some(error.into());
+        if retryable && self.attempts < MAX_ERASURE_ATTEMPTS {
+            self.next_attempt_at = Timestamp::now().plus_secs(retry_delay_secs(self.attempts));
+        } else {
+            self.status = DataErasureStatus::Failed;
+        }
+    }
+
+    /// Drops the recipient address once it is no longer needed.
+    pub fn forget_recipient(&mut self) {
+        self.recipient = None;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn erasure() -> DataErasure {
+        DataErasure::new(
+            UserId::new("user-1").unwrap(),
+            Some("jo@example.com".to_string()),
+        )
+    }
+
+    fn report(remaining: u64) -> ErasureReport {
+        ErasureReport::new(vec![
+            ErasureStep::new("sessions", ErasedData::deleted(3), remaining),
+            ErasureStep::new("usage", ErasedData::crypto_shredded(12), 0),
+        ])
+    }
+
+    #[test]
+    fn new_erasures_are_due_immediately() {
+        let erasure = erasure();
+        assert_eq!(erasure.status, DataErasureStatus::Pending);
+        assert!(erasure.is_due(Timestamp::now()));
+        assert!(erasure.is_in_progress());
+    }
+
+    #[test]
+    fn completes_with_a_verified_report() {
+        let mut erasure = erasure();
+        erasure.record_completed(report(0));
+        erasure.forget_recipient();
+
+        assert_eq!(erasure.status, DataErasureStatus::Completed);
+        assert!(erasure.completed_at.is_some());
+        assert!(erasure.recipient.is_none());
+        let report = erasure.report.unwrap();
+        assert!(report.is_verified());
+        assert_eq!(report.total_records(), 15);
+    }
+
+    #[test]
+    fn leftover_data_is_retried_until_attempts_run_out() {
+        let mut erasure = erasure();
+
+        erasure.record_unverified(report(2));
+        assert_eq!(erasure.status, DataErasureStatus::Pending);
+        assert!(!erasure.is_due(Timestamp::now()));
+        assert_eq!(
+            erasure.last_error.as_deref(),
+            Some("Data remaining after erasure: sessions")
+        );
+        assert_eq!(
+            erasure.report.as_ref().unwrap().unverified_sections(),
+            vec!["sessions"]
+        );
+
+        for _ in 1..MAX_ERASURE_ATTEMPTS {
+            erasure.record_failure("database unavailable", true);
+        }
+        assert_eq!(erasure.status, DataErasureStatus::Failed);
+        assert_eq!(erasure.attempts, MAX_ERASURE_ATTEMPTS);
+    }
+
+    #[test]
+    fn reports_serialize_methods_in_snake_case() {
+        let json = serde_json::to_value(report(0)).unwrap();
+        assert_eq!(json["steps"][0]["method"], "deleted");
+        assert_eq!(json["steps"][1]["method"], "crypto_shredded");
+        assert_eq!(json["steps"][1]["records"], 12);
+    }
+
+    #[test]
+    fn status_round_trips_through_strings() {
+        for status in [
+            DataErasureStatus::Pending,
+            DataErasureStatus::Completed,
+            DataErasureStatus::Failed,
+        ] {
+            assert_eq!(DataErasureStatus::parse(status.as_str()), Some(status));
+        }
+    }
+}
@@ -0,0 +1,248 @@
+//! DataExport - A user's request for a copy of all their data.
+//!
+//! Requests are saved as pending and built by a background worker, which
+//! collects every registered data source into a `UserDataArchive`, stores it,
+//! and emails the user a download link. Transient failures are retried with
+//! exponential backoff until [`MAX_EXPORT_ATTEMPTS`]. Archives are kept for
+//! [`ARCHIVE_RETENTION_DAYS`] and then stop being downloadable.
+
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::domain::foundation::{DataExportId, Timestamp, UserId};
+
+/// Version of the archive layout, bumped when sections change shape.
+pub const ARCHIVE_FORMAT_VERSION: u32 = 1;
+
+/// Days a finished archive stays available for download.
+pub const ARCHIVE_RETENTION_DAYS: i64 = 7;
+
+/// Attempts before an export is marked failed.
+pub const MAX_EXPORT_ATTEMPTS: u32 = 5;
+
+/// Delay before the first retry; doubles with each further attempt.
+const FIRST_RETRY_DELAY_SECS: u64 = 60;
+
+/// Where an export stands.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DataExportStatus {
+    /// Waiting for its first attempt or a retry.
+    Pending,
+    /// Archive stored and the user emailed.
+    Ready,
+    /// Gave up: a permanent error, or out of attempts.
+    Failed,
+}
+
+impl DataExportStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            DataExportStatus::Pending => "pending",
+            DataExportStatus::Ready => "ready",
+            DataExportStatus::Failed => "failed",
+        }
+    }
+
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "pending" => Some(DataExportStatus::Pending),
+            "ready" => Some(DataExportStatus::Ready),
+            "failed" => Some(DataExportStatus::Failed),
+            _ => None,
+        }
+    }
+}
+
+/// One request to export a user's data.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DataExport {
+    pub id: DataExportId,
+    pub user_id: UserId,
+    /// Address the download link is sent to.
+    pub recipient: String,
+    pub status: DataExportStatus,
+    pub attempts: u32,
+    pub last_error: Option<String>,
+    /// When a pending export should next be attempted.
+    pub next_attempt_at: Timestamp,
+    pub requested_at: Timestamp,
+    /// Storage key of the finished archive.
+    pub archive_key: Option<String>,
+    pub completed_at: Option<Timestamp>,
+}
+
+impl DataExport {
+    /// A pending export, due immediately.
+    pub fn new(user_id: UserId, recipient: impl Into<String>) -> Self {
+        let now = Timestamp::now();
+        Self {
+            id: DataExportId::new(),
+            user_id,
+            recipient: recipient.into(),
+            status: DataExportStatus::Pending,
+            attempts: 0,
+            last_error: None,
+            next_attempt_at: now,
+            requested_at: now,
+            archive_key: None,
+            completed_at: None,
+        }
+    }
+
+    /// Storage key the archive is written under.
+    pub fn storage_key(&self) -> String {
+        format!("data-exports/{}.json", self.id)
+    }
+
+    /// Whether a worker should attempt this export at `now`.
+    pub fn is_due(&self, now: Timestamp) -> bool {
+        self.status == DataExportStatus::Pending && !self.next_attempt_at.is_after(&now)
+    }
+
+    /// Whether the export is still being built. Users get one at a time.
+    pub fn is_in_progress(&self) -> bool {
+        self.status == DataExportStatus::Pending
+    }
+
+    /// When the archive stops being downloadable.
+    pub fn expires_at(&self) -> Option<Timestamp> {
+        self.completed_at
+            .map(|completed| completed.plus_days(ARCHIVE_RETENTION_DAYS))
+    }
+
+    /// Whether the archive can be downloaded at `now`.
+    pub fn is_downloadable(&self, now: Timestamp) -> bool {
+        self.status == DataExportStatus::Ready
+            && self.archive_key.is_some()
+            && self
+                .expires_at()
+                .is_some_and(|expires| now.is_before(&expires))
+    }
+
+    pub fn record_ready(&mut self, archive_key: impl Into<String>) {
+        self.attempts += 1;
+        self.status = DataExportStatus::Ready;
+        self.last_error = None;
+        self.archive_key = Some(archive_key.into());
+        self.completed_at = Some(Timestamp::now());
+    }
+
+    /// Records a failed attempt, scheduling a retry if `retryable` and
+    /// attempts remain.
+    pub fn record_failure(&mut self, error: impl Into<String>, retryable: bool) {
+        self.attempts += 1;
+        self.last_error = Some(error.into());
+        if retryable && self.attempts < MAX_EXPORT_ATTEMPTS {
+            self.next_attempt_at = Timestamp::now().plus_secs(retry_delay_secs(self.attempts));
+        } else {
+            self.status = DataExportStatus::Failed;
+        }
+    }
+}
+
+/// Backoff after `attempts` failures: 1, 2, 4, 8... minutes.
+pub(super) fn retry_delay_secs(attempts: u32) -> u64 {
+    FIRST_RETRY_DELAY_SECS << attempts.saturating_sub(1).min(10)
+}
+
+/// Everything held about one user, as written to the download.
+///
+/// Each kind of data (`decision_profile`, `sessions`, `conversations`,
+/// `usage`, ...) is a named section, so new sources can be added without
+/// changing the archive layout.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct UserDataArchive {
+    pub format_version: u32,
+    pub user_id: UserId,
+    pub generated_at: Timestamp,
+    pub sections: BTreeMap<String, serde_json::Value>,
+}
+
+impl UserDataArchive {
+    pub fn new(user_id: UserId) -> Self {
+        Self {
+            format_version: ARCHIVE_FORMAT_VERSION,
+            user_id,
+            generated_at: Timestamp::now(),
+            sections: BTreeMap::new(),
+        }
+    }
+
+    /// Adds a section, replacing any earlier one with the same name.
+    pub fn insert(&mut self, section: impl Into<String>, data: serde_json::Value) {
+        self.sections.insert(section.into(), data);
+    }
+
+    /// The archive as pretty-printed JSON.
+    pub fn to_json_bytes(&self) -> Vec<u8> {
+        serde_json::to_vec_pretty(self).expect("archive serialization should not fail")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn export() -> DataExport {
+        DataExport::new(UserId::new("user-1").unwrap(), "jo@example.com")
+    }
+
+    #[test]
+    fn new_exports_are_due_immediately() {
+        let export = export();
+        assert_eq!(export.status, DataExportStatus::Pending);
+        assert!(export.is_due(Timestamp::now()));
+        assert!(!export.is_downloadable(Timestamp::now()));
+    }
+
+    #[test]
+    fn transient_failures_back_off_until_attempts_run_out() {
+        let mut export = export();
+
+        export.record_failure("storage unavailable", true);
+        assert_eq!(export.status, DataExportStatus::Pending);
+        assert!(!export.is_due(Timestamp::now()));
+
+        for _ in 1..MAX_EXPORT_ATTEMPTS {
+            export.record_failure("storage unavailable", true);
+        }
+        assert_eq!(export.status, DataExportStatus::Failed);
+        assert_eq!(export.attempts, MAX_EXPORT_ATTEMPTS);
+    }
+
+    #[test]
+    fn ready_archives_expire_after_the_retention_period() {
+        let mut export = export();
+        export.record_ready(export.storage_key());
+
+        let now = Timestamp::now();
+        assert!(export.is_downloadable(now));
+        assert!(!export.is_downloadable(now.plus_days(ARCHIVE_RETENTION_DAYS + 1)));
+        assert!(export.archive_key.unwrap().starts_with("data-exports/"));
+    }
+
+    #[test]
+    fn archives_serialize_sections_by_name() {
+        let mut archive = UserDataArchive::new(UserId::new("user-1").unwrap());
+        archive.insert("usage", serde_json::json!({"request_count": 3}));
+        archive.insert("sessions", serde_json::json!([]));
+
+        let json: serde_json::Value = serde_json::from_slice(&archive.to_json_bytes()).unwrap();
+        assert_eq!(json["format_version"], 1);
+        assert_eq!(json["sections"]["usage"]["request_count"], 3);
+        assert!(json["sections"]["sessions"].is_array());
+    }
+
+    #[test]
+    fn status_round_trips_through_strings() {
+        for status in [
+            DataExportStatus::Pending,
+            DataExportStatus::Ready,
+            DataExportStatus::Failed,
+        ] {
+            assert_eq!(DataExportStatus::parse(status.as_str()), Some(status));
+        }
+    }
+}
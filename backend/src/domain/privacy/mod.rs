@@ -0,0 +1,26 @@
+//! Privacy domain module.
+//!
+//! Data subject requests under GDPR. Users can download everything the
+//! service holds about them as a machine-readable archive, and have all of
+//! it erased.
+//!
+//! # Types
+//!
+//! - `DataExport` - One export request, built in the background and retried on failure
+//! - `DataExportStatus` - Where an export stands
+//! - `UserDataArchive` - The archive handed to the user, one section per kind of data
+//! - `DataErasure` - One erasure request, carried out in the background and verified
+//! - `DataErasureStatus` - Where an erasure stands
+//! - `ErasureReport` - What was erased per kind of data, and what verification found left
+
+mod data_erasure;
+mod data_export;
+
+pub use data_erasure::{
+    DataErasure, DataErasureStatus, ErasedData, ErasureMethod, ErasureReport, ErasureStep,
+    MAX_ERASURE_ATTEMPTS,
+};
+pub use data_export::{
+    DataExport, DataExportStatus, UserDataArchive, ARCHIVE_FORMAT_VERSION, ARCHIVE_RETENTION_DAYS,
+    MAX_EXPORT_ATTEMPTS,
+};
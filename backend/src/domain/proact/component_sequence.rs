@@ -1,14 +1,14 @@
 //! ComponentSequence - Centralized ordering logic for PrOACT components.
 //!
-//! The PrOACT framework has a defined progression through 9 components. This module
+//! The PrOACT framework has a defined progression through 11 components. This module
 //! consolidates all ordering logic into a single location to avoid duplication
 //! across the codebase.
 //!
 //! # Component Order
 //!
-//! 1. IssueRaising → 2. ProblemFrame → 3. Objectives → 4. Alternatives →
-//! 5. Consequences → 6. Tradeoffs → 7. Recommendation → 8. DecisionQuality →
-//! 9. NotesNextSteps
+//! 1. IssueRaising → 2. ProblemFrame → 3. StakeholderAnalysis → 4. Objectives →
+//! 5. Alternatives → 6. Consequences → 7. UncertaintyRegister → 8. Tradeoffs →
+//! 9. Recommendation → 10. DecisionQuality → 11. NotesNextSteps
 //!
 //! # Usage
 //!
@@ -21,15 +21,15 @@
 //!
 //! // Navigation
 //! let next = ComponentSequence::next(ComponentType::Objectives); // Some(Alternatives)
-//! let prev = ComponentSequence::previous(ComponentType::Objectives); // Some(ProblemFrame)
+//! let prev = ComponentSequence::previous(ComponentType::Objectives); // Some(StakeholderAnalysis)
 //!
 //! // Queries
-//! let idx = ComponentSequence::order_index(ComponentType::Tradeoffs); // 5
+//! let idx = ComponentSequence::order_index(ComponentType::Tradeoffs); // 7
 //! let is_before = ComponentSequence::is_before(ComponentType::Objectives, ComponentType::Consequences); // true
 //!
 //! // Get all components up to and including a specific point
 //! let up_to = ComponentSequence::components_up_to(ComponentType::Alternatives);
-//! // [IssueRaising, ProblemFrame, Objectives, Alternatives]
+//! // [IssueRaising, ProblemFrame, StakeholderAnalysis, Objectives, Alternatives]
 //! ```
 
 use crate::domain::foundation::ComponentType;
@@ -42,12 +42,14 @@ pub struct ComponentSequence;
 
 impl ComponentSequence {
     /// The canonical order of PrOACT components.
-    pub const ORDER: [ComponentType; 9] = [
+    pub const ORDER: [ComponentType; 11] = [
         ComponentType::IssueRaising,
         ComponentType::ProblemFrame,
+        ComponentType::StakeholderAnalysis,
         ComponentType::Objectives,
         ComponentType::Alternatives,
         ComponentType::Consequences,
+        ComponentType::UncertaintyRegister,
         ComponentType::Tradeoffs,
         ComponentType::Recommendation,
         ComponentType::DecisionQuality,
@@ -55,7 +57,7 @@ impl ComponentSequence {
     ];
 
     /// Returns all component types in order.
-    pub fn all() -> &'static [ComponentType; 9] {
+    pub fn all() -> &'static [ComponentType; 11] {
         &Self::ORDER
     }
 
@@ -98,7 +100,7 @@ impl ComponentSequence {
     /// ```ignore
     /// assert_eq!(
     ///     ComponentSequence::previous(ComponentType::Objectives),
-    ///     Some(ComponentType::ProblemFrame)
+    ///     Some(ComponentType::StakeholderAnalysis)
     /// );
     /// assert_eq!(
     ///     ComponentSequence::previous(ComponentType::IssueRaising),
@@ -148,6 +150,7 @@ impl ComponentSequence {
     /// assert_eq!(up_to, vec![
     ///     ComponentType::IssueRaising,
     ///     ComponentType::ProblemFrame,
+    ///     ComponentType::StakeholderAnalysis,
     ///     ComponentType::Objectives,
     ///     ComponentType::Alternatives,
     /// ]);
@@ -209,8 +212,8 @@ mod tests {
     use super::*;
 
     #[test]
-    fn order_contains_all_nine_components() {
-        assert_eq!(ComponentSequence::ORDER.len(), 9);
+    fn order_contains_all_eleven_components() {
+        assert_eq!(ComponentSequence::ORDER.len(), 11);
     }
 
     #[test]
@@ -222,13 +225,15 @@ mod tests {
     fn order_index_returns_correct_position() {
         assert_eq!(ComponentSequence::order_index(ComponentType::IssueRaising), 0);
         assert_eq!(ComponentSequence::order_index(ComponentType::ProblemFrame), 1);
-        assert_eq!(ComponentSequence::order_index(ComponentType::Objectives), 2);
-        assert_eq!(ComponentSequence::order_index(ComponentType::Alternatives), 3);
-        assert_eq!(ComponentSequence::order_index(ComponentType::Consequences), 4);
-        assert_eq!(ComponentSequence::order_index(ComponentType::Tradeoffs), 5);
-        assert_eq!(ComponentSequence::order_index(ComponentType::Recommendation), 6);
-        assert_eq!(ComponentSequence::order_index(ComponentType::DecisionQuality), 7);
-        assert_eq!(ComponentSequence::order_index(ComponentType::NotesNextSteps), 8);
+        assert_eq!(ComponentSequence::order_index(ComponentType::StakeholderAnalysis), 2);
+        assert_eq!(ComponentSequence::order_index(ComponentType::Objectives), 3);
+        assert_eq!(ComponentSequence::order_index(ComponentType::Alternatives), 4);
+        assert_eq!(ComponentSequence::order_index(ComponentType::Consequences), 5);
+        assert_eq!(ComponentSequence::order_index(ComponentType::UncertaintyRegister), 6);
+        assert_eq!(ComponentSequence::order_index(ComponentType::Tradeoffs), 7);
+        assert_eq!(ComponentSequence::order_index(ComponentType::Recommendation), 8);
+        assert_eq!(ComponentSequence::order_index(ComponentType::DecisionQuality), 9);
+        assert_eq!(ComponentSequence::order_index(ComponentType::NotesNextSteps), 10);
     }
 
     #[test]
@@ -304,9 +309,10 @@ mod tests {
     #[test]
     fn components_up_to_returns_inclusive_slice() {
         let up_to = ComponentSequence::components_up_to(ComponentType::Alternatives);
-        assert_eq!(up_to.len(), 4);
+        assert_eq!(up_to.len(), 5);
         assert_eq!(up_to[0], ComponentType::IssueRaising);
-        assert_eq!(up_to[3], ComponentType::Alternatives);
+        assert_eq!(up_to[2], ComponentType::StakeholderAnalysis);
+        assert_eq!(up_to[4], ComponentType::Alternatives);
     }
 
     #[test]
@@ -319,15 +325,15 @@ mod tests {
     #[test]
     fn components_up_to_last_returns_all() {
         let up_to = ComponentSequence::components_up_to(ComponentType::NotesNextSteps);
-        assert_eq!(up_to.len(), 9);
+        assert_eq!(up_to.len(), 11);
     }
 
     #[test]
     fn components_after_returns_remaining() {
         let after = ComponentSequence::components_after(ComponentType::Consequences);
-        assert_eq!(after.len(), 4);
-        assert_eq!(after[0], ComponentType::Tradeoffs);
-        assert_eq!(after[3], ComponentType::NotesNextSteps);
+        assert_eq!(after.len(), 5);
+        assert_eq!(after[0], ComponentType::UncertaintyRegister);
+        assert_eq!(after[4], ComponentType::NotesNextSteps);
     }
 
     #[test]
@@ -366,11 +372,11 @@ mod tests {
     fn distance_calculates_correctly() {
         assert_eq!(
             ComponentSequence::distance(ComponentType::IssueRaising, ComponentType::Objectives),
-            2
+            3
         );
         assert_eq!(
             ComponentSequence::distance(ComponentType::Objectives, ComponentType::IssueRaising),
-            -2
+            -3
         );
         assert_eq!(
             ComponentSequence::distance(ComponentType::Alternatives, ComponentType::Alternatives),
@@ -378,7 +384,7 @@ mod tests {
         );
         assert_eq!(
             ComponentSequence::distance(ComponentType::IssueRaising, ComponentType::NotesNextSteps),
-            8
+            10
         );
     }
 }
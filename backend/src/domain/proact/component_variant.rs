@@ -4,7 +4,8 @@ use crate::domain::foundation::{ComponentId, ComponentStatus, ComponentType, Dom
 
 use super::{
     Alternatives, Component, ComponentBase, ComponentError, Consequences, DecisionQuality, IssueRaising,
-    NotesNextSteps, Objectives, ProblemFrame, Recommendation, Tradeoffs,
+    NotesNextSteps, Objectives, ProblemFrame, Recommendation, StakeholderAnalysis, Tradeoffs,
+    UncertaintyRegister,
 };
 
 /// Sum type for all component types.
@@ -12,9 +13,11 @@ use super::{
 pub enum ComponentVariant {
     IssueRaising(IssueRaising),
     ProblemFrame(ProblemFrame),
+    StakeholderAnalysis(StakeholderAnalysis),
     Objectives(Objectives),
     Alternatives(Alternatives),
     Consequences(Consequences),
+    UncertaintyRegister(UncertaintyRegister),
     Tradeoffs(Tradeoffs),
     Recommendation(Recommendation),
     DecisionQuality(DecisionQuality),
@@ -27,9 +30,15 @@ impl ComponentVariant {
         match component_type {
             ComponentType::IssueRaising => ComponentVariant::IssueRaising(IssueRaising::new()),
             ComponentType::ProblemFrame => ComponentVariant::ProblemFrame(ProblemFrame::new()),
+            ComponentType::StakeholderAnalysis => {
+                ComponentVariant::StakeholderAnalysis(StakeholderAnalysis::new())
+            }
             ComponentType::Objectives => ComponentVariant::Objectives(Objectives::new()),
             ComponentType::Alternatives => ComponentVariant::Alternatives(Alternatives::new()),
             ComponentType::Consequences => ComponentVariant::Consequences(Consequences::new()),
+            ComponentType::UncertaintyRegister => {
+                ComponentVariant::UncertaintyRegister(UncertaintyRegister::new())
+            }
             ComponentType::Tradeoffs => ComponentVariant::Tradeoffs(Tradeoffs::new()),
             ComponentType::Recommendation => {
                 ComponentVariant::Recommendation(Recommendation::new())
@@ -48,9 +57,11 @@ impl ComponentVariant {
         match self {
             ComponentVariant::IssueRaising(c) => c.id(),
             ComponentVariant::ProblemFrame(c) => c.id(),
+            ComponentVariant::StakeholderAnalysis(c) => c.id(),
             ComponentVariant::Objectives(c) => c.id(),
             ComponentVariant::Alternatives(c) => c.id(),
             ComponentVariant::Consequences(c) => c.id(),
+            ComponentVariant::UncertaintyRegister(c) => c.id(),
             ComponentVariant::Tradeoffs(c) => c.id(),
             ComponentVariant::Recommendation(c) => c.id(),
             ComponentVariant::DecisionQuality(c) => c.id(),
@@ -63,9 +74,11 @@ impl ComponentVariant {
         match self {
             ComponentVariant::IssueRaising(_) => ComponentType::IssueRaising,
             ComponentVariant::ProblemFrame(_) => ComponentType::ProblemFrame,
+            ComponentVariant::StakeholderAnalysis(_) => ComponentType::StakeholderAnalysis,
             ComponentVariant::Objectives(_) => ComponentType::Objectives,
             ComponentVariant::Alternatives(_) => ComponentType::Alternatives,
             ComponentVariant::Consequences(_) => ComponentType::Consequences,
+            ComponentVariant::UncertaintyRegister(_) => ComponentType::UncertaintyRegister,
             ComponentVariant::Tradeoffs(_) => ComponentType::Tradeoffs,
             ComponentVariant::Recommendation(_) => ComponentType::Recommendation,
             ComponentVariant::DecisionQuality(_) => ComponentType::DecisionQuality,
@@ -78,9 +91,11 @@ impl ComponentVariant {
         match self {
             ComponentVariant::IssueRaising(c) => c.status(),
             ComponentVariant::ProblemFrame(c) => c.status(),
+            ComponentVariant::StakeholderAnalysis(c) => c.status(),
             ComponentVariant::Objectives(c) => c.status(),
             ComponentVariant::Alternatives(c) => c.status(),
             ComponentVariant::Consequences(c) => c.status(),
+            ComponentVariant::UncertaintyRegister(c) => c.status(),
             ComponentVariant::Tradeoffs(c) => c.status(),
             ComponentVariant::Recommendation(c) => c.status(),
             ComponentVariant::DecisionQuality(c) => c.status(),
@@ -93,9 +108,11 @@ impl ComponentVariant {
         match self {
             ComponentVariant::IssueRaising(c) => c.created_at(),
             ComponentVariant::ProblemFrame(c) => c.created_at(),
+            ComponentVariant::StakeholderAnalysis(c) => c.created_at(),
             ComponentVariant::Objectives(c) => c.created_at(),
             ComponentVariant::Alternatives(c) => c.created_at(),
             ComponentVariant::Consequences(c) => c.created_at(),
+            ComponentVariant::UncertaintyRegister(c) => c.created_at(),
             ComponentVariant::Tradeoffs(c) => c.created_at(),
             ComponentVariant::Recommendation(c) => c.created_at(),
             ComponentVariant::DecisionQuality(c) => c.created_at(),
@@ -108,9 +125,11 @@ impl ComponentVariant {
         match self {
             ComponentVariant::IssueRaising(c) => c.updated_at(),
             ComponentVariant::ProblemFrame(c) => c.updated_at(),
+            ComponentVariant::StakeholderAnalysis(c) => c.updated_at(),
             ComponentVariant::Objectives(c) => c.updated_at(),
             ComponentVariant::Alternatives(c) => c.updated_at(),
             ComponentVariant::Consequences(c) => c.updated_at(),
+            ComponentVariant::UncertaintyRegister(c) => c.updated_at(),
             ComponentVariant::Tradeoffs(c) => c.updated_at(),
             ComponentVariant::Recommendation(c) => c.updated_at(),
             ComponentVariant::DecisionQuality(c) => c.updated_at(),
@@ -123,9 +142,11 @@ impl ComponentVariant {
         match self {
             ComponentVariant::IssueRaising(c) => c.start(),
             ComponentVariant::ProblemFrame(c) => c.start(),
+            ComponentVariant::StakeholderAnalysis(c) => c.start(),
             ComponentVariant::Objectives(c) => c.start(),
             ComponentVariant::Alternatives(c) => c.start(),
             ComponentVariant::Consequences(c) => c.start(),
+            ComponentVariant::UncertaintyRegister(c) => c.start(),
             ComponentVariant::Tradeoffs(c) => c.start(),
             ComponentVariant::Recommendation(c) => c.start(),
             ComponentVariant::DecisionQuality(c) => c.start(),
@@ -138,9 +159,11 @@ impl ComponentVariant {
         match self {
             ComponentVariant::IssueRaising(c) => c.complete(),
             ComponentVariant::ProblemFrame(c) => c.complete(),
+            ComponentVariant::StakeholderAnalysis(c) => c.complete(),
             ComponentVariant::Objectives(c) => c.complete(),
             ComponentVariant::Alternatives(c) => c.complete(),
             ComponentVariant::Consequences(c) => c.complete(),
+            ComponentVariant::UncertaintyRegister(c) => c.complete(),
             ComponentVariant::Tradeoffs(c) => c.complete(),
             ComponentVariant::Recommendation(c) => c.complete(),
             ComponentVariant::DecisionQuality(c) => c.complete(),
@@ -153,9 +176,11 @@ impl ComponentVariant {
         match self {
             ComponentVariant::IssueRaising(c) => c.mark_for_revision(reason),
             ComponentVariant::ProblemFrame(c) => c.mark_for_revision(reason),
+            ComponentVariant::StakeholderAnalysis(c) => c.mark_for_revision(reason),
             ComponentVariant::Objectives(c) => c.mark_for_revision(reason),
             ComponentVariant::Alternatives(c) => c.mark_for_revision(reason),
             ComponentVariant::Consequences(c) => c.mark_for_revision(reason),
+            ComponentVariant::UncertaintyRegister(c) => c.mark_for_revision(reason),
             ComponentVariant::Tradeoffs(c) => c.mark_for_revision(reason),
             ComponentVariant::Recommendation(c) => c.mark_for_revision(reason),
             ComponentVariant::DecisionQuality(c) => c.mark_for_revision(reason),
@@ -168,9 +193,11 @@ impl ComponentVariant {
         match self {
             ComponentVariant::IssueRaising(c) => c.output_as_value(),
             ComponentVariant::ProblemFrame(c) => c.output_as_value(),
+            ComponentVariant::StakeholderAnalysis(c) => c.output_as_value(),
             ComponentVariant::Objectives(c) => c.output_as_value(),
             ComponentVariant::Alternatives(c) => c.output_as_value(),
             ComponentVariant::Consequences(c) => c.output_as_value(),
+            ComponentVariant::UncertaintyRegister(c) => c.output_as_value(),
             ComponentVariant::Tradeoffs(c) => c.output_as_value(),
             ComponentVariant::Recommendation(c) => c.output_as_value(),
             ComponentVariant::DecisionQuality(c) => c.output_as_value(),
@@ -183,9 +210,11 @@ impl ComponentVariant {
         match self {
             ComponentVariant::IssueRaising(c) => c.set_output_from_value(value),
             ComponentVariant::ProblemFrame(c) => c.set_output_from_value(value),
+            ComponentVariant::StakeholderAnalysis(c) => c.set_output_from_value(value),
             ComponentVariant::Objectives(c) => c.set_output_from_value(value),
             ComponentVariant::Alternatives(c) => c.set_output_from_value(value),
             ComponentVariant::Consequences(c) => c.set_output_from_value(value),
+            ComponentVariant::UncertaintyRegister(c) => c.set_output_from_value(value),
             ComponentVariant::Tradeoffs(c) => c.set_output_from_value(value),
             ComponentVariant::Recommendation(c) => c.set_output_from_value(value),
             ComponentVariant::DecisionQuality(c) => c.set_output_from_value(value),
@@ -263,6 +292,15 @@ impl ComponentVariant {
                 })?;
                 ComponentVariant::ProblemFrame(super::ProblemFrame::reconstitute(base, out))
             }
+            ComponentType::StakeholderAnalysis => {
+                let out = serde_json::from_value(output).map_err(|e| {
+                    DomainError::new(
+                        crate::domain::foundation::ErrorCode::InvalidFormat,
+                        format!("Failed to deserialize StakeholderAnalysis output: {}", e),
+                    )
+                })?;
+                ComponentVariant::StakeholderAnalysis(StakeholderAnalysis::reconstitute(base, out))
+            }
             ComponentType::Objectives => {
                 let out = serde_json::from_value(output).map_err(|e| {
                     DomainError::new(
@@ -290,6 +328,15 @@ impl ComponentVariant {
                 })?;
                 ComponentVariant::Consequences(Consequences::reconstitute(base, out))
             }
+            ComponentType::UncertaintyRegister => {
+                let out = serde_json::from_value(output).map_err(|e| {
+                    DomainError::new(
+                        crate::domain::foundation::ErrorCode::InvalidFormat,
+                        format!("Failed to deserialize UncertaintyRegister output: {}", e),
+                    )
+                })?;
+                ComponentVariant::UncertaintyRegister(UncertaintyRegister::reconstitute(base, out))
+            }
             ComponentType::Tradeoffs => {
                 let out = serde_json::from_value(output).map_err(|e| {
                     DomainError::new(
@@ -417,6 +464,6 @@ mod tests {
 
         // Check all IDs are unique
         let unique_count = ids.iter().collect::<std::collections::HashSet<_>>().len();
-        assert_eq!(unique_count, 9);
+        assert_eq!(unique_count, 11);
     }
 }
@@ -5,7 +5,7 @@ use std::collections::HashMap;
 
 use crate::domain::foundation::{ComponentId, ComponentStatus, ComponentType, Rating, Timestamp};
 
-use super::{Component, ComponentBase, ComponentError};
+use super::{Component, ComponentBase, ComponentError, ProbabilityElicitation};
 
 /// A cell in the consequences table.
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -68,6 +68,31 @@ pub struct Uncertainty {
     pub worth_resolving: bool,
     /// Can it be reduced within the decision timeframe?
     pub resolvable: bool,
+    /// Ways it could resolve, with how likely each is.
+    #[serde(default)]
+    pub outcomes: Vec<UncertaintyOutcome>,
+    /// How the outcome probabilities were assessed.
+    #[serde(default)]
+    pub elicitation: Option<ProbabilityElicitation>,
+}
+
+/// One way an uncertainty could resolve.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct UncertaintyOutcome {
+    pub label: String,
+    /// Probability of this outcome (0.0-1.0).
+    pub probability: f64,
+    /// Ratings that would differ from the table under this outcome.
+    #[serde(default)]
+    pub rating_changes: Vec<RatingChange>,
+}
+
+/// A consequence rating that changes under an uncertainty outcome.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RatingChange {
+    pub alternative_id: String,
+    pub objective_id: String,
+    pub rating: Rating,
 }
 
 /// The consequences table structure.
@@ -321,6 +346,8 @@ mod tests {
             driver: "Economic conditions".to_string(),
             worth_resolving: true,
             resolvable: false,
+            outcomes: vec![],
+            elicitation: None,
         };
         con.add_uncertainty(uncertainty);
 
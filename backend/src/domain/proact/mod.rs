@@ -3,7 +3,7 @@
 //! This module defines:
 //! - The Component trait that all PrOACT components implement
 //! - The ComponentBase struct with lifecycle methods
-//! - The 9 concrete component types (IssueRaising, ProblemFrame, etc.)
+//! - The 11 concrete component types (IssueRaising, ProblemFrame, etc.)
 //! - The ComponentVariant enum for pattern matching
 //! - Message types for conversation history
 
@@ -14,9 +14,13 @@ mod component_sequence;
 mod component_variant;
 mod issue_raising;
 mod problem_frame;
+mod stakeholder_analysis;
 mod objectives;
+mod value_function;
 mod alternatives;
 mod consequences;
+mod probability_elicitation;
+mod uncertainty_register;
 mod tradeoffs;
 mod recommendation;
 mod decision_quality;
@@ -31,15 +35,32 @@ pub use issue_raising::{IssueRaising, IssueRaisingOutput};
 pub use problem_frame::{
     Constraint, DecisionHierarchy, LinkedDecision, Party, ProblemFrame, ProblemFrameOutput,
 };
+pub use stakeholder_analysis::{
+    EngagementAction, EngagementStrategy, Stakeholder, StakeholderAnalysis,
+    StakeholderAnalysisOutput, StakeholderLevel,
+};
 pub use objectives::{
     FundamentalObjective, MeansObjective, Objectives, ObjectivesOutput, PerformanceMeasure,
 };
+pub use value_function::{ValueFunction, ValueFunctionError, ValuePoint};
 pub use alternatives::{
     Alternative, Alternatives, AlternativesOutput, DecisionColumn, Strategy, StrategyTable,
 };
-pub use consequences::{Cell, Consequences, ConsequencesOutput, ConsequencesTable, Uncertainty};
+pub use consequences::{
+    Cell, Consequences, ConsequencesOutput, ConsequencesTable, RatingChange, Uncertainty,
+    UncertaintyOutcome,
+};
+pub use probability_elicitation::{
+    ElicitationError, ElicitationMethod, ProbabilityElicitation, ThreePointEstimate,
+    PROBABILITY_TOLERANCE, SWANSON_WEIGHTS,
+};
+pub use uncertainty_register::{
+    ImpactLevel, Mitigation, MitigationKind, RegisteredUncertainty, UncertaintyRegister,
+    UncertaintyRegisterOutput,
+};
 pub use tradeoffs::{
-    DominatedAlternative, IrrelevantObjective, Tension, Tradeoffs, TradeoffsOutput,
+    DominatedAlternative, IrrelevantObjective, ParticipantPreferences, ScoringMethod, Tension,
+    Tradeoffs, TradeoffsOutput,
 };
 pub use recommendation::{Recommendation, RecommendationOutput};
 pub use decision_quality::{
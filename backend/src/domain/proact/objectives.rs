@@ -1,10 +1,11 @@
 //! Objectives component - fundamental and means objectives with measures.
 
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 
 use crate::domain::foundation::{ComponentId, ComponentStatus, ComponentType, Timestamp};
 
-use super::{Component, ComponentBase, ComponentError};
+use super::{Component, ComponentBase, ComponentError, ValueFunction, ValueFunctionError};
 
 /// How to measure achievement of an objective.
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -15,6 +16,30 @@ pub struct PerformanceMeasure {
     pub unit: Option<String>,
     /// Direction: "higher_is_better" or "lower_is_better".
     pub direction: String,
+    /// How much each level is worth; linear when not set.
+    #[serde(default)]
+    pub value_function: Option<ValueFunction>,
+}
+
+impl PerformanceMeasure {
+    /// Returns true if lower levels of the measure are better.
+    pub fn lower_is_better(&self) -> bool {
+        matches!(self.direction.as_str(), "lower_is_better" | "lower" | "minimize")
+    }
+
+    /// Value (0.0-1.0) of `level`, given the lowest and highest levels among
+    /// the alternatives.
+    pub fn value(&self, level: f64, min: f64, max: f64) -> f64 {
+        let (worst, best) = if self.lower_is_better() {
+            (max, min)
+        } else {
+            (min, max)
+        };
+        self.value_function
+            .as_ref()
+            .unwrap_or(&ValueFunction::Linear)
+            .value(level, worst, best)
+    }
 }
 
 /// A fundamental objective - what we ultimately care about.
@@ -25,6 +50,9 @@ pub struct FundamentalObjective {
     pub performance_measure: PerformanceMeasure,
     /// Links to Party.id from ProblemFrame.
     pub affected_party_id: Option<String>,
+    /// Relative importance for weighted scoring (0.0-1.0).
+    #[serde(default)]
+    pub weight: Option<f64>,
 }
 
 /// A means objective - a way to achieve fundamental objectives.
@@ -101,6 +129,60 @@ impl Objectives {
     pub fn find_fundamental(&self, id: &str) -> Option<&FundamentalObjective> {
         self.output.fundamental_objectives.iter().find(|o| o.id == id)
     }
+
+    /// Sets the weight of a fundamental objective.
+    ///
+    /// Returns false if there is no fundamental objective with that ID.
+    pub fn set_weight(&mut self, objective_id: &str, weight: f64) -> Result<bool, ComponentError> {
+        if !(0.0..=1.0).contains(&weight) {
+            return Err(ComponentError::InvalidOutput(format!(
+                "Weight {} is outside 0 to 1",
+                weight
+            )));
+        }
+        let Some(objective) = self
+            .output
+            .fundamental_objectives
+            .iter_mut()
+            .find(|o| o.id == objective_id)
+        else {
+            return Ok(false);
+        };
+        objective.weight = Some(weight);
+        self.base.touch();
+        Ok(true)
+    }
+
+    /// Weights of the fundamental objectives that have one, by ID.
+    pub fn weights(&self) -> HashMap<String, f64> {
+        self.output
+            .fundamental_objectives
+            .iter()
+            .filter_map(|o| Some((o.id.clone(), o.weight?)))
+            .collect()
+    }
+
+    /// Sets the value function of a fundamental objective's measure.
+    ///
+    /// Returns false if there is no fundamental objective with that ID.
+    pub fn set_value_function(
+        &mut self,
+        objective_id: &str,
+        value_function: ValueFunction,
+    ) -> Result<bool, ValueFunctionError> {
+        value_function.validate()?;
+        let Some(objective) = self
+            .output
+            .fundamental_objectives
+            .iter_mut()
+            .find(|o| o.id == objective_id)
+        else {
+            return Ok(false);
+        };
+        objective.performance_measure.value_function = Some(value_function);
+        self.base.touch();
+        Ok(true)
+    }
 }
 
 impl Default for Objectives {
@@ -177,8 +259,10 @@ mod tests {
                 is_quantitative: true,
                 unit: Some("dollars".to_string()),
                 direction: "higher_is_better".to_string(),
+                value_function: None,
             },
             affected_party_id: None,
+            weight: None,
         };
         obj.add_fundamental(fundamental);
 
@@ -209,8 +293,10 @@ mod tests {
                 is_quantitative: false,
                 unit: None,
                 direction: "higher_is_better".to_string(),
+                value_function: None,
             },
             affected_party_id: None,
+            weight: None,
         };
         obj.add_fundamental(fundamental);
 
@@ -225,6 +311,74 @@ mod tests {
         assert!(obj.find_fundamental("nonexistent").is_none());
     }
 
+    #[test]
+    fn measure_value_follows_direction() {
+        let mut measure = PerformanceMeasure {
+            description: "Commute".to_string(),
+            is_quantitative: true,
+            unit: Some("minutes".to_string()),
+            direction: "lower_is_better".to_string(),
+            value_function: None,
+        };
+        assert_eq!(measure.value(15.0, 15.0, 60.0), 1.0);
+        assert_eq!(measure.value(60.0, 15.0, 60.0), 0.0);
+
+        measure.direction = "higher_is_better".to_string();
+        assert_eq!(measure.value(15.0, 15.0, 60.0), 0.0);
+    }
+
+    #[test]
+    fn set_value_function_validates_and_stores() {
+        let mut obj = Objectives::new();
+        obj.add_fundamental(FundamentalObjective {
+            id: "f1".to_string(),
+            description: "Salary".to_string(),
+            performance_measure: PerformanceMeasure {
+                description: "Annual salary".to_string(),
+                is_quantitative: true,
+                unit: Some("USD".to_string()),
+                direction: "higher_is_better".to_string(),
+                value_function: None,
+            },
+            affected_party_id: None,
+            weight: None,
+        });
+
+        let invalid = ValueFunction::PiecewiseLinear { points: vec![] };
+        assert!(obj.set_value_function("f1", invalid).is_err());
+
+        let concave = ValueFunction::exponential_from_midvalue(50.0, 150.0, 80.0).unwrap();
+        assert_eq!(obj.set_value_function("f1", concave.clone()), Ok(true));
+        assert_eq!(obj.set_value_function("missing", concave.clone()), Ok(false));
+        assert_eq!(
+            obj.find_fundamental("f1").unwrap().performance_measure.value_function,
+            Some(concave)
+        );
+    }
+
+    #[test]
+    fn set_weight_rejects_out_of_range() {
+        let mut obj = Objectives::new();
+        obj.add_fundamental(FundamentalObjective {
+            id: "f1".to_string(),
+            description: "Salary".to_string(),
+            performance_measure: PerformanceMeasure {
+                description: "Annual salary".to_string(),
+                is_quantitative: true,
+                unit: None,
+                direction: "higher_is_better".to_string(),
+                value_function: None,
+            },
+            affected_party_id: None,
+            weight: None,
+        });
+
+        assert!(obj.set_weight("f1", 1.5).is_err());
+        assert!(obj.set_weight("f1", 0.4).unwrap());
+        assert!(!obj.set_weight("missing", 0.4).unwrap());
+        assert_eq!(obj.weights().get("f1"), Some(&0.4));
+    }
+
     #[test]
     fn output_roundtrips_through_json() {
         let mut obj = Objectives::new();
@@ -236,8 +390,10 @@ mod tests {
                 is_quantitative: true,
                 unit: Some("units".to_string()),
                 direction: "lower_is_better".to_string(),
+                value_function: None,
             },
             affected_party_id: Some("p1".to_string()),
+            weight: None,
         });
 
         let value = obj.output_as_value();
@@ -0,0 +1,410 @@
+//! Probability elicitation - structured ways of putting numbers on uncertainties.
+//!
+//! Asking "what's the probability?" outright gives poorly calibrated answers,
+//! so uncertainty outcomes can be assessed three ways:
+//!
+//! - **Fixed value**: the user states each outcome's probability directly.
+//! - **Three-point**: the user gives a low (P10), most likely (P50) and high
+//!   (P90) value, discretized with Swanson's rule into 30/40/30 weights.
+//! - **Reference lottery**: for each outcome, the user finds the chance of
+//!   winning a lottery they find as attractive as a bet on the outcome.
+//!
+//! Every assessment is checked for coherence (no negative probabilities,
+//! total of 1), and the method is recorded on the uncertainty so estimates
+//! can later be scored against what actually happened.
+
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+
+use crate::domain::foundation::Timestamp;
+
+use super::{Uncertainty, UncertaintyOutcome};
+
+/// How far probabilities may stray from summing to 1.
+pub const PROBABILITY_TOLERANCE: f64 = 1e-3;
+
+/// Swanson's rule weights for the P10, P50 and P90 points.
+pub const SWANSON_WEIGHTS: [f64; 3] = [0.3, 0.4, 0.3];
+
+/// How an uncertainty's probabilities were assessed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ElicitationMethod {
+    FixedValue,
+    ThreePoint,
+    ReferenceLottery,
+}
+
+/// Low, most likely and high values of an uncertain quantity.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ThreePointEstimate {
+    /// 10th percentile: only 1 in 10 chance of lower.
+    pub low: f64,
+    pub most_likely: f64,
+    /// 90th percentile: only 1 in 10 chance of higher.
+    pub high: f64,
+    /// e.g. "USD" or "months".
+    pub unit: Option<String>,
+}
+
+/// Record of the latest assessment of an uncertainty's probabilities.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ProbabilityElicitation {
+    pub method: ElicitationMethod,
+    /// The values given, for three-point assessments.
+    pub three_point: Option<ThreePointEstimate>,
+    pub elicited_at: Timestamp,
+}
+
+impl ProbabilityElicitation {
+    fn new(method: ElicitationMethod) -> Self {
+        Self {
+            method,
+            three_point: None,
+            elicited_at: Timestamp::now(),
+        }
+    }
+}
+
+/// Why an assessment was rejected.
+#[derive(Debug, Clone, PartialEq, thiserror::Error)]
+pub enum ElicitationError {
+    #[error("At least one outcome needs a probability")]
+    NoOutcomes,
+
+    #[error("Outcome '{0}' is listed more than once")]
+    DuplicateOutcome(String),
+
+    #[error("Probability for '{0}' is negative")]
+    NegativeProbability(String),
+
+    #[error("Probability for '{0}' is greater than 1")]
+    ProbabilityAboveOne(String),
+
+    #[error("Probabilities sum to {0}, not 1")]
+    IncoherentTotal(f64),
+
+    #[error("Three-point values must satisfy low <= most likely <= high")]
+    InvalidThreePoint,
+}
+
+impl Uncertainty {
+    /// Sets outcome probabilities stated directly by the user.
+    ///
+    /// The listed outcomes replace the current ones; outcomes keeping their
+    /// label keep their rating changes.
+    pub fn elicit_fixed(
+        &mut self,
+        probabilities: &[(String, f64)],
+    ) -> Result<(), ElicitationError> {
+        if probabilities.is_empty() {
+            return Err(ElicitationError::NoOutcomes);
+        }
+        let mut seen = HashSet::new();
+        for (label, probability) in probabilities {
+            if !seen.insert(label.as_str()) {
+                return Err(ElicitationError::DuplicateOutcome(label.clone()));
+            }
+            check_probability(label, *probability)?;
+        }
+        check_total(probabilities.iter().map(|(_, p)| *p))?;
+
+        self.replace_outcomes(probabilities);
+        self.elicitation = Some(ProbabilityElicitation::new(ElicitationMethod::FixedValue));
+        Ok(())
+    }
+
+    /// Sets three outcomes from a low / most likely / high estimate.
+    ///
+    /// `labels` name the low, most likely and high outcomes, which get
+    /// [`SWANSON_WEIGHTS`] as their probabilities.
+    pub fn elicit_three_point(
+        &mut self,
+        estimate: ThreePointEstimate,
+        labels: [String; 3],
+    ) -> Result<(), ElicitationError> {
+        let ordered = estimate.low <= estimate.most_likely && estimate.most_likely <= estimate.high;
+        if !ordered {
+            return Err(ElicitationError::InvalidThreePoint);
+        }
+        if labels[0] == labels[1] || labels[1] == labels[2] || labels[0] == labels[2] {
+            return Err(ElicitationError::DuplicateOutcome(labels[1].clone()));
+        }
+
+        let probabilities: Vec<(String, f64)> = labels.into_iter().zip(SWANSON_WEIGHTS).collect();
+        self.replace_outcomes(&probabilities);
+        self.elicitation = Some(ProbabilityElicitation {
+            three_point: Some(estimate),
+            ..ProbabilityElicitation::new(ElicitationMethod::ThreePoint)
+        });
+        Ok(())
+    }
+
+    /// Sets one outcome's probability from a reference lottery.
+    ///
+    /// Outcomes are assessed one at a time, so the total is only checked by
+    /// [`Uncertainty::is_coherent`] once all have been assessed. An unknown
+    /// label adds a new outcome.
+    pub fn record_lottery_indifference(
+        &mut self,
+        label: &str,
+        indifference_probability: f64,
+    ) -> Result<(), ElicitationError> {
+        check_probability(label, indifference_probability)?;
+
+        match self.outcomes.iter_mut().find(|o| o.label == label) {
+            Some(outcome) => outcome.probability = indifference_probability,
+            None => self.outcomes.push(UncertaintyOutcome {
+                label: label.to_string(),
+                probability: indifference_probability,
+                rating_changes: vec![],
+            }),
+        }
+        self.elicitation = Some(ProbabilityElicitation::new(
+            ElicitationMethod::ReferenceLottery,
+        ));
+        Ok(())
+    }
+
+    /// Sum of the outcome probabilities.
+    pub fn probability_total(&self) -> f64 {
+        self.outcomes.iter().map(|o| o.probability).sum()
+    }
+
+    /// Returns true if there are outcomes and their probabilities are
+    /// non-negative and sum to 1.
+    pub fn is_coherent(&self) -> bool {
+        !self.outcomes.is_empty()
+            && self.outcomes.iter().all(|o| o.probability >= 0.0)
+            && (self.probability_total() - 1.0).abs() <= PROBABILITY_TOLERANCE
+    }
+
+    /// Brier score of the assessed probabilities once the outcome is known.
+    ///
+    /// 0 is a perfect forecast and 2 the worst possible. Returns `None` if
+    /// the probabilities are incoherent or `occurred` is not an outcome.
+    pub fn brier_score(&self, occurred: &str) -> Option<f64> {
+        if !self.is_coherent() || !self.outcomes.iter().any(|o| o.label == occurred) {
+            return None;
+        }
+        Some(
+            self.outcomes
+                .iter()
+                .map(|o| {
+                    let actual = if o.label == occurred { 1.0 } else { 0.0 };
+                    (o.probability - actual).powi(2)
+                })
+                .sum(),
+        )
+    }
+
+    /// Replaces the outcomes, carrying over rating changes by label.
+    fn replace_outcomes(&mut self, probabilities: &[(String, f64)]) {
+        let previous = std::mem::take(&mut self.outcomes);
+        self.outcomes = probabilities
+            .iter()
+            .map(|(label, probability)| UncertaintyOutcome {
+                label: label.clone(),
+                probability: *probability,
+                rating_changes: previous
+                    .iter()
+                    .find(|o| &o.label == label)
+                    .map(|o| o.rating_changes.clone())
+                    .unwrap_or_default(),
+            })
+            .collect();
+    }
+}
+
+fn check_probability(label: &str, probability: f64) -> Result<(), ElicitationError> {
+    if probability < 0.0 {
+        return Err(ElicitationError::NegativeProbability(label.to_string()));
+    }
+    if probability > 1.0 {
+        return Err(ElicitationError::ProbabilityAboveOne(label.to_string()));
+    }
+    Ok(())
+}
+
+fn check_total(probabilities: impl Iterator<Item = f64>) -> Result<(), ElicitationError> {
+    let total: f64 = probabilities.sum();
+    if (total - 1.0).abs() > PROBABILITY_TOLERANCE {
+        return Err(ElicitationError::IncoherentTotal(total));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::foundation::Rating;
+    use crate::domain::proact::RatingChange;
+
+    fn uncertainty() -> Uncertainty {
+        Uncertainty {
+            id: "u1".to_string(),
+            description: "Housing market".to_string(),
+            driver: "Interest rates".to_string(),
+            worth_resolving: true,
+            resolvable: false,
+            outcomes: vec![UncertaintyOutcome {
+                label: "falls".to_string(),
+                probability: 0.5,
+                rating_changes: vec![RatingChange {
+                    alternative_id: "buy".to_string(),
+                    objective_id: "cost".to_string(),
+                    rating: Rating::Better,
+                }],
+            }],
+            elicitation: None,
+        }
+    }
+
+    fn labelled(pairs: &[(&str, f64)]) -> Vec<(String, f64)> {
+        pairs.iter().map(|(l, p)| (l.to_string(), *p)).collect()
+    }
+
+    #[test]
+    fn fixed_probabilities_replace_outcomes_and_keep_rating_changes() {
+        let mut u = uncertainty();
+
+        u.elicit_fixed(&labelled(&[("falls", 0.3), ("rises", 0.7)]))
+            .unwrap();
+
+        assert_eq!(u.outcomes.len(), 2);
+        assert_eq!(u.outcomes[0].rating_changes.len(), 1);
+        assert!(u.outcomes[1].rating_changes.is_empty());
+        assert_eq!(u.elicitation.unwrap().method, ElicitationMethod::FixedValue);
+    }
+
+    #[test]
+    fn fixed_probabilities_must_sum_to_one() {
+        let mut u = uncertainty();
+
+        let result = u.elicit_fixed(&labelled(&[("falls", 0.3), ("rises", 0.3)]));
+
+        assert!(matches!(result, Err(ElicitationError::IncoherentTotal(_))));
+        assert_eq!(u.outcomes.len(), 1);
+    }
+
+    #[test]
+    fn negative_probabilities_are_rejected() {
+        let mut u = uncertainty();
+
+        let result = u.elicit_fixed(&labelled(&[("falls", -0.2), ("rises", 1.2)]));
+
+        assert_eq!(
+            result,
+            Err(ElicitationError::NegativeProbability("falls".to_string()))
+        );
+    }
+
+    #[test]
+    fn duplicate_labels_are_rejected() {
+        let mut u = uncertainty();
+
+        let result = u.elicit_fixed(&labelled(&[("falls", 0.5), ("falls", 0.5)]));
+
+        assert_eq!(
+            result,
+            Err(ElicitationError::DuplicateOutcome("falls".to_string()))
+        );
+    }
+
+    #[test]
+    fn three_point_uses_swanson_weights_and_records_estimate() {
+        let mut u = uncertainty();
+        let estimate = ThreePointEstimate {
+            low: -10.0,
+            most_likely: 2.0,
+            high: 8.0,
+            unit: Some("% price change".to_string()),
+        };
+
+        u.elicit_three_point(
+            estimate.clone(),
+            ["falls".to_string(), "flat".to_string(), "rises".to_string()],
+        )
+        .unwrap();
+
+        let probabilities: Vec<f64> = u.outcomes.iter().map(|o| o.probability).collect();
+        assert_eq!(probabilities, SWANSON_WEIGHTS);
+        assert!(u.is_coherent());
+        let elicitation = u.elicitation.unwrap();
+        assert_eq!(elicitation.method, ElicitationMethod::ThreePoint);
+        assert_eq!(elicitation.three_point, Some(estimate));
+    }
+
+    #[test]
+    fn three_point_values_must_be_ordered() {
+        let mut u = uncertainty();
+
+        let result = u.elicit_three_point(
+            ThreePointEstimate {
+                low: 5.0,
+                most_likely: 2.0,
+                high: 8.0,
+                unit: None,
+            },
+            ["low".to_string(), "mid".to_string(), "high".to_string()],
+        );
+
+        assert_eq!(result, Err(ElicitationError::InvalidThreePoint));
+    }
+
+    #[test]
+    fn lottery_assessments_are_coherent_once_complete() {
+        let mut u = uncertainty();
+
+        u.record_lottery_indifference("falls", 0.25).unwrap();
+        assert!(!u.is_coherent());
+
+        u.record_lottery_indifference("rises", 0.75).unwrap();
+        assert!(u.is_coherent());
+        assert_eq!(
+            u.elicitation.unwrap().method,
+            ElicitationMethod::ReferenceLottery
+        );
+    }
+
+    #[test]
+    fn lottery_probability_above_one_is_rejected() {
+        let mut u = uncertainty();
+
+        let result = u.record_lottery_indifference("falls", 1.5);
+
+        assert_eq!(
+            result,
+            Err(ElicitationError::ProbabilityAboveOne("falls".to_string()))
+        );
+    }
+
+    #[test]
+    fn brier_score_rewards_confident_correct_forecasts() {
+        let mut u = uncertainty();
+        u.elicit_fixed(&labelled(&[("falls", 0.8), ("rises", 0.2)]))
+            .unwrap();
+
+        let hit = u.brier_score("falls").unwrap();
+        let miss = u.brier_score("rises").unwrap();
+
+        assert!((hit - 0.08).abs() < 1e-9);
+        assert!((miss - 1.28).abs() < 1e-9);
+        assert!(u.brier_score("crashes").is_none());
+    }
+
+    #[test]
+    fn elicitation_roundtrips_through_json() {
+        let mut u = uncertainty();
+        u.elicit_fixed(&labelled(&[("falls", 1.0)])).unwrap();
+
+        let value = serde_json::to_value(&u).unwrap();
+        assert_eq!(value["elicitation"]["method"], "fixed_value");
+
+        let back: Uncertainty = serde_json::from_value(value).unwrap();
+        assert_eq!(
+            back.elicitation.unwrap().method,
+            ElicitationMethod::FixedValue
+        );
+    }
+}
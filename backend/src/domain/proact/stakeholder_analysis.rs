@@ -0,0 +1,348 @@
+//! StakeholderAnalysis component - power/interest mapping and engagement.
+//!
+//! Sits between ProblemFrame and Objectives: once the decision is framed, the
+//! people it touches are mapped on a power/interest grid, their concerns are
+//! recorded (they often become objectives), and an engagement plan says how
+//! each will be involved.
+
+use serde::{Deserialize, Serialize};
+
+use crate::domain::foundation::{ComponentId, ComponentStatus, ComponentType, Timestamp};
+
+use super::{Component, ComponentBase, ComponentError};
+
+/// How much power or interest a stakeholder has.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum StakeholderLevel {
+    Low,
+    Medium,
+    High,
+}
+
+/// Engagement approach for a quadrant of the power/interest grid.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum EngagementStrategy {
+    /// High power, high interest: involve in the decision.
+    ManageClosely,
+    /// High power, low interest: consult on what matters to them.
+    KeepSatisfied,
+    /// Low power, high interest: keep up to date.
+    KeepInformed,
+    /// Low power, low interest: watch for changes.
+    Monitor,
+}
+
+impl EngagementStrategy {
+    /// The grid quadrant for a power/interest pair. Medium counts as high,
+    /// so only stakeholders low on an axis fall to its weaker side.
+    pub fn for_grid(power: StakeholderLevel, interest: StakeholderLevel) -> Self {
+        match (
+            power > StakeholderLevel::Low,
+            interest > StakeholderLevel::Low,
+        ) {
+            (true, true) => EngagementStrategy::ManageClosely,
+            (true, false) => EngagementStrategy::KeepSatisfied,
+            (false, true) => EngagementStrategy::KeepInformed,
+            (false, false) => EngagementStrategy::Monitor,
+        }
+    }
+
+    /// Returns the display name.
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            EngagementStrategy::ManageClosely => "Manage closely",
+            EngagementStrategy::KeepSatisfied => "Keep satisfied",
+            EngagementStrategy::KeepInformed => "Keep informed",
+            EngagementStrategy::Monitor => "Monitor",
+        }
+    }
+}
+
+/// Someone the decision affects or who can affect it.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Stakeholder {
+    pub id: String,
+    pub name: String,
+    /// Relationship to the decision, e.g. "spouse", "manager", "regulator".
+    pub role: String,
+    /// Ability to shape or block the decision.
+    pub power: StakeholderLevel,
+    /// How much the outcome matters to them.
+    pub interest: StakeholderLevel,
+    /// What they care about or worry about.
+    #[serde(default)]
+    pub concerns: Vec<String>,
+}
+
+impl Stakeholder {
+    /// The engagement approach their grid position suggests.
+    pub fn suggested_strategy(&self) -> EngagementStrategy {
+        EngagementStrategy::for_grid(self.power, self.interest)
+    }
+}
+
+/// How one stakeholder will be engaged.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EngagementAction {
+    pub stakeholder_id: String,
+    pub strategy: EngagementStrategy,
+    /// Concrete steps, e.g. "Walk through the shortlist before deciding".
+    #[serde(default)]
+    pub actions: Vec<String>,
+    /// When the engagement should happen.
+    pub timing: Option<String>,
+}
+
+/// Structured stakeholder analysis output.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct StakeholderAnalysisOutput {
+    /// Everyone mapped on the power/interest grid.
+    #[serde(default)]
+    pub stakeholders: Vec<Stakeholder>,
+
+    /// How each stakeholder will be involved.
+    #[serde(default)]
+    pub engagement_plan: Vec<EngagementAction>,
+}
+
+impl StakeholderAnalysisOutput {
+    /// Finds a stakeholder by ID.
+    pub fn stakeholder(&self, id: &str) -> Option<&Stakeholder> {
+        self.stakeholders.iter().find(|s| s.id == id)
+    }
+
+    /// Stakeholders that fall in a grid quadrant.
+    pub fn in_quadrant(&self, strategy: EngagementStrategy) -> Vec<&Stakeholder> {
+        self.stakeholders
+            .iter()
+            .filter(|s| s.suggested_strategy() == strategy)
+            .collect()
+    }
+
+    /// Stakeholders with no entry in the engagement plan.
+    pub fn unplanned(&self) -> Vec<&Stakeholder> {
+        self.stakeholders
+            .iter()
+            .filter(|s| {
+                !self
+                    .engagement_plan
+                    .iter()
+                    .any(|action| action.stakeholder_id == s.id)
+            })
+            .collect()
+    }
+}
+
+/// The StakeholderAnalysis component.
+#[derive(Debug, Clone)]
+pub struct StakeholderAnalysis {
+    base: ComponentBase,
+    output: StakeholderAnalysisOutput,
+}
+
+impl StakeholderAnalysis {
+    /// Creates a new StakeholderAnalysis component.
+    pub fn new() -> Self {
+        Self {
+            base: ComponentBase::new(ComponentType::StakeholderAnalysis),
+            output: StakeholderAnalysisOutput::default(),
+        }
+    }
+
+    /// Reconstitutes a StakeholderAnalysis component from persisted data.
+    pub(crate) fn reconstitute(base: ComponentBase, output: StakeholderAnalysisOutput) -> Self {
+        Self { base, output }
+    }
+
+    /// Returns the output.
+    pub fn output(&self) -> &StakeholderAnalysisOutput {
+        &self.output
+    }
+
+    /// Sets the output.
+    pub fn set_output(&mut self, output: StakeholderAnalysisOutput) {
+        self.output = output;
+        self.base.touch();
+    }
+
+    /// Adds a stakeholder.
+    pub fn add_stakeholder(&mut self, stakeholder: Stakeholder) {
+        self.output.stakeholders.push(stakeholder);
+        self.base.touch();
+    }
+
+    /// Adds an engagement plan entry.
+    pub fn add_engagement(&mut self, action: EngagementAction) {
+        self.output.engagement_plan.push(action);
+        self.base.touch();
+    }
+}
+
+impl Default for StakeholderAnalysis {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Component for StakeholderAnalysis {
+    fn id(&self) -> ComponentId {
+        self.base.id
+    }
+
+    fn component_type(&self) -> ComponentType {
+        self.base.component_type
+    }
+
+    fn status(&self) -> ComponentStatus {
+        self.base.status
+    }
+
+    fn created_at(&self) -> Timestamp {
+        self.base.created_at
+    }
+
+    fn updated_at(&self) -> Timestamp {
+        self.base.updated_at
+    }
+
+    fn start(&mut self) -> Result<(), ComponentError> {
+        self.base.start()
+    }
+
+    fn complete(&mut self) -> Result<(), ComponentError> {
+        self.base.complete()
+    }
+
+    fn mark_for_revision(&mut self, reason: String) -> Result<(), ComponentError> {
+        self.base.mark_for_revision(reason)
+    }
+
+    fn output_as_value(&self) -> serde_json::Value {
+        serde_json::to_value(&self.output).unwrap_or_default()
+    }
+
+    fn set_output_from_value(&mut self, value: serde_json::Value) -> Result<(), ComponentError> {
+        self.output = serde_json::from_value(value)
+            .map_err(|e| ComponentError::InvalidOutput(e.to_string()))?;
+        self.base.touch();
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn stakeholder(id: &str, power: StakeholderLevel, interest: StakeholderLevel) -> Stakeholder {
+        Stakeholder {
+            id: id.to_string(),
+            name: id.to_string(),
+            role: "family".to_string(),
+            power,
+            interest,
+            concerns: vec![],
+        }
+    }
+
+    #[test]
+    fn stakeholder_analysis_has_correct_component_type() {
+        let sa = StakeholderAnalysis::new();
+        assert_eq!(sa.component_type(), ComponentType::StakeholderAnalysis);
+    }
+
+    #[test]
+    fn grid_quadrants_map_to_strategies() {
+        use StakeholderLevel::*;
+
+        assert_eq!(
+            EngagementStrategy::for_grid(High, High),
+            EngagementStrategy::ManageClosely
+        );
+        assert_eq!(
+            EngagementStrategy::for_grid(High, Low),
+            EngagementStrategy::KeepSatisfied
+        );
+        assert_eq!(
+            EngagementStrategy::for_grid(Low, Medium),
+            EngagementStrategy::KeepInformed
+        );
+        assert_eq!(
+            EngagementStrategy::for_grid(Low, Low),
+            EngagementStrategy::Monitor
+        );
+    }
+
+    #[test]
+    fn in_quadrant_filters_by_suggested_strategy() {
+        let mut sa = StakeholderAnalysis::new();
+        sa.add_stakeholder(stakeholder(
+            "spouse",
+            StakeholderLevel::High,
+            StakeholderLevel::High,
+        ));
+        sa.add_stakeholder(stakeholder(
+            "landlord",
+            StakeholderLevel::Medium,
+            StakeholderLevel::Low,
+        ));
+
+        let closely = sa.output().in_quadrant(EngagementStrategy::ManageClosely);
+
+        assert_eq!(closely.len(), 1);
+        assert_eq!(closely[0].id, "spouse");
+    }
+
+    #[test]
+    fn unplanned_lists_stakeholders_without_engagement() {
+        let mut sa = StakeholderAnalysis::new();
+        sa.add_stakeholder(stakeholder(
+            "spouse",
+            StakeholderLevel::High,
+            StakeholderLevel::High,
+        ));
+        sa.add_stakeholder(stakeholder(
+            "kids",
+            StakeholderLevel::Low,
+            StakeholderLevel::High,
+        ));
+        sa.add_engagement(EngagementAction {
+            stakeholder_id: "spouse".to_string(),
+            strategy: EngagementStrategy::ManageClosely,
+            actions: vec!["Decide together".to_string()],
+            timing: None,
+        });
+
+        let unplanned = sa.output().unplanned();
+
+        assert_eq!(unplanned.len(), 1);
+        assert_eq!(unplanned[0].id, "kids");
+    }
+
+    #[test]
+    fn output_roundtrips_through_json() {
+        let mut sa = StakeholderAnalysis::new();
+        sa.add_stakeholder(stakeholder(
+            "spouse",
+            StakeholderLevel::High,
+            StakeholderLevel::High,
+        ));
+
+        let value = sa.output_as_value();
+        assert_eq!(value["stakeholders"][0]["power"], "high");
+
+        let mut sa2 = StakeholderAnalysis::new();
+        sa2.set_output_from_value(value).unwrap();
+        assert_eq!(sa2.output().stakeholders.len(), 1);
+    }
+
+    #[test]
+    fn missing_lists_default_to_empty() {
+        let mut sa = StakeholderAnalysis::new();
+        sa.set_output_from_value(serde_json::json!({})).unwrap();
+
+        assert!(sa.output().stakeholders.is_empty());
+        assert!(sa.output().engagement_plan.is_empty());
+    }
+}
@@ -1,5 +1,7 @@
 //! Tradeoffs component - dominated alternatives, irrelevant objectives, tensions.
 
+use std::collections::HashMap;
+
 use serde::{Deserialize, Serialize};
 
 use crate::domain::foundation::{ComponentId, ComponentStatus, ComponentType, Timestamp};
@@ -34,12 +36,54 @@ pub struct Tension {
     pub uncertainty_impact: Option<String>,
 }
 
+/// How alternatives are compared in this cycle.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ScoringMethod {
+    /// Sum of Pugh ratings against the baseline.
+    #[default]
+    Pugh,
+    /// Weighted sum of 0-1 values per objective.
+    WeightedAdditive,
+}
+
+impl ScoringMethod {
+    /// Returns the method name as used in JSON.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ScoringMethod::Pugh => "pugh",
+            ScoringMethod::WeightedAdditive => "weighted_additive",
+        }
+    }
+}
+
+/// One participant's view of the alternatives in a group decision.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ParticipantPreferences {
+    /// Stakeholder ID when the participant was mapped in Stakeholder
+    /// Analysis, otherwise any stable ID.
+    pub participant_id: String,
+    pub name: String,
+    /// Overall score (0-100) the participant gives each alternative.
+    #[serde(default)]
+    pub alternative_scores: HashMap<String, f64>,
+    /// Importance (0.0-1.0) the participant gives each objective.
+    #[serde(default)]
+    pub objective_weights: HashMap<String, f64>,
+}
+
 /// Tradeoffs output structure.
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct TradeoffsOutput {
     pub dominated_alternatives: Vec<DominatedAlternative>,
     pub irrelevant_objectives: Vec<IrrelevantObjective>,
     pub tensions: Vec<Tension>,
+    /// Method used to compare alternatives.
+    #[serde(default)]
+    pub scoring_method: ScoringMethod,
+    /// Per-participant scores and weights, for group decisions.
+    #[serde(default)]
+    pub participant_preferences: Vec<ParticipantPreferences>,
 }
 
 /// The Tradeoffs component.
@@ -92,6 +136,41 @@ impl Tradeoffs {
         self.base.touch();
     }
 
+    /// Selects how alternatives are compared.
+    pub fn set_scoring_method(&mut self, method: ScoringMethod) {
+        self.output.scoring_method = method;
+        self.base.touch();
+    }
+
+    /// Records a participant's preferences, replacing any they gave before.
+    /// Scores must be 0-100 and weights 0-1.
+    pub fn record_participant_preferences(
+        &mut self,
+        preferences: ParticipantPreferences,
+    ) -> Result<(), ComponentError> {
+        if preferences.alternative_scores.values().any(|s| !(0.0..=100.0).contains(s)) {
+            return Err(ComponentError::InvalidOutput(
+                "Alternative scores must be between 0 and 100".to_string(),
+            ));
+        }
+        if preferences.objective_weights.values().any(|w| !(0.0..=1.0).contains(w)) {
+            return Err(ComponentError::InvalidOutput(
+                "Objective weights must be between 0 and 1".to_string(),
+            ));
+        }
+
+        let participants = &mut self.output.participant_preferences;
+        match participants
+            .iter_mut()
+            .find(|p| p.participant_id == preferences.participant_id)
+        {
+            Some(existing) => *existing = preferences,
+            None => participants.push(preferences),
+        }
+        self.base.touch();
+        Ok(())
+    }
+
     /// Returns the count of viable alternatives (total - dominated).
     pub fn viable_alternative_count(&self, total_alternatives: usize) -> usize {
         total_alternatives.saturating_sub(self.output.dominated_alternatives.len())
@@ -180,6 +259,24 @@ mod tests {
         assert_eq!(to.component_type(), ComponentType::Tradeoffs);
     }
 
+    #[test]
+    fn record_participant_preferences_replaces_earlier_entry() {
+        let mut to = Tradeoffs::new();
+        let preferences = |score: f64| ParticipantPreferences {
+            participant_id: "s1".to_string(),
+            name: "Sam".to_string(),
+            alternative_scores: HashMap::from([("a1".to_string(), score)]),
+            objective_weights: HashMap::new(),
+        };
+
+        to.record_participant_preferences(preferences(40.0)).unwrap();
+        to.record_participant_preferences(preferences(70.0)).unwrap();
+
+        assert_eq!(to.output().participant_preferences.len(), 1);
+        assert_eq!(to.output().participant_preferences[0].alternative_scores["a1"], 70.0);
+        assert!(to.record_participant_preferences(preferences(120.0)).is_err());
+    }
+
     #[test]
     fn add_dominated_adds_to_list() {
         let mut to = Tradeoffs::new();
@@ -292,4 +389,19 @@ mod tests {
         );
         assert_eq!(to.output().tensions.len(), to2.output().tensions.len());
     }
+
+    #[test]
+    fn scoring_method_defaults_to_pugh() {
+        let output: TradeoffsOutput = serde_json::from_value(serde_json::json!({
+            "dominated_alternatives": [],
+            "irrelevant_objectives": [],
+            "tensions": []
+        }))
+        .unwrap();
+        assert_eq!(output.scoring_method, ScoringMethod::Pugh);
+
+        let mut to = Tradeoffs::new();
+        to.set_scoring_method(ScoringMethod::WeightedAdditive);
+        assert_eq!(to.output_as_value()["scoring_method"], "weighted_additive");
+    }
 }
@@ -0,0 +1,386 @@
+//! UncertaintyRegister component - key uncertainties, likelihood and mitigation.
+//!
+//! Sits between Consequences and Tradeoffs: once the alternatives have been
+//! scored, the uncertainties behind those scores are registered with a
+//! probability estimate, an impact level and the options for reducing or
+//! hedging them. Entries can point back at the `Uncertainty` records noted
+//! while filling in the consequences table.
+
+use serde::{Deserialize, Serialize};
+
+use crate::domain::foundation::{ComponentId, ComponentStatus, ComponentType, Timestamp};
+
+use super::{Component, ComponentBase, ComponentError, ConsequencesOutput};
+
+/// How much an uncertainty could change the decision if it breaks badly.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ImpactLevel {
+    Low,
+    Medium,
+    High,
+    /// Would change which alternative is best.
+    Critical,
+}
+
+impl ImpactLevel {
+    /// Returns the serialized name.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ImpactLevel::Low => "low",
+            ImpactLevel::Medium => "medium",
+            ImpactLevel::High => "high",
+            ImpactLevel::Critical => "critical",
+        }
+    }
+
+    /// Weight used when ranking uncertainties by exposure.
+    pub fn weight(&self) -> f64 {
+        match self {
+            ImpactLevel::Low => 1.0,
+            ImpactLevel::Medium => 2.0,
+            ImpactLevel::High => 3.0,
+            ImpactLevel::Critical => 4.0,
+        }
+    }
+}
+
+/// What a mitigation does to an uncertainty.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MitigationKind {
+    /// Gather information to narrow the uncertainty before deciding.
+    Resolve,
+    /// Make the bad outcome less likely.
+    Reduce,
+    /// Limit the damage if the bad outcome happens.
+    Hedge,
+    /// Live with it.
+    Accept,
+}
+
+/// An option for dealing with an uncertainty.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Mitigation {
+    pub description: String,
+    pub kind: MitigationKind,
+    /// Rough cost or effort, e.g. "two evenings of research".
+    pub cost: Option<String>,
+}
+
+/// An uncertainty tracked in the register.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RegisteredUncertainty {
+    pub id: String,
+    pub description: String,
+    /// ID of the `Uncertainty` in the Consequences output this entry expands on.
+    pub consequence_uncertainty_id: Option<String>,
+    /// Alternatives whose consequences depend on this uncertainty.
+    #[serde(default)]
+    pub affected_alternative_ids: Vec<String>,
+    /// Probability (0.0-1.0) that the unfavourable outcome occurs.
+    pub probability: Option<f64>,
+    /// Where the probability estimate comes from.
+    pub probability_basis: Option<String>,
+    pub impact: ImpactLevel,
+    #[serde(default)]
+    pub mitigations: Vec<Mitigation>,
+}
+
+impl RegisteredUncertainty {
+    /// Probability weighted by impact, when a probability has been estimated.
+    pub fn exposure(&self) -> Option<f64> {
+        self.probability.map(|p| p * self.impact.weight())
+    }
+}
+
+/// Structured uncertainty register output.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct UncertaintyRegisterOutput {
+    #[serde(default)]
+    pub uncertainties: Vec<RegisteredUncertainty>,
+}
+
+impl UncertaintyRegisterOutput {
+    /// Finds an uncertainty by ID.
+    pub fn uncertainty(&self, id: &str) -> Option<&RegisteredUncertainty> {
+        self.uncertainties.iter().find(|u| u.id == id)
+    }
+
+    /// Uncertainties ordered by exposure, highest first. Entries without a
+    /// probability estimate come last, ordered by impact.
+    pub fn ranked(&self) -> Vec<&RegisteredUncertainty> {
+        let mut ranked: Vec<_> = self.uncertainties.iter().collect();
+        ranked.sort_by(|a, b| match (a.exposure(), b.exposure()) {
+            (Some(x), Some(y)) => y.total_cmp(&x),
+            (Some(_), None) => std::cmp::Ordering::Less,
+            (None, Some(_)) => std::cmp::Ordering::Greater,
+            (None, None) => b.impact.cmp(&a.impact),
+        });
+        ranked
+    }
+
+    /// High or critical impact uncertainties with no mitigation option.
+    pub fn unmitigated(&self) -> Vec<&RegisteredUncertainty> {
+        self.uncertainties
+            .iter()
+            .filter(|u| u.impact >= ImpactLevel::High && u.mitigations.is_empty())
+            .collect()
+    }
+
+    /// IDs of uncertainties noted in the consequences table that the register
+    /// does not yet cover.
+    pub fn unregistered<'a>(&self, consequences: &'a ConsequencesOutput) -> Vec<&'a str> {
+        consequences
+            .uncertainties
+            .iter()
+            .filter(|c| {
+                !self
+                    .uncertainties
+                    .iter()
+                    .any(|u| u.consequence_uncertainty_id.as_deref() == Some(c.id.as_str()))
+            })
+            .map(|c| c.id.as_str())
+            .collect()
+    }
+}
+
+/// The UncertaintyRegister component.
+#[derive(Debug, Clone)]
+pub struct UncertaintyRegister {
+    base: ComponentBase,
+    output: UncertaintyRegisterOutput,
+}
+
+impl UncertaintyRegister {
+    /// Creates a new UncertaintyRegister component.
+    pub fn new() -> Self {
+        Self {
+            base: ComponentBase::new(ComponentType::UncertaintyRegister),
+            output: UncertaintyRegisterOutput::default(),
+        }
+    }
+
+    /// Reconstitutes an UncertaintyRegister component from persisted data.
+    pub(crate) fn reconstitute(base: ComponentBase, output: UncertaintyRegisterOutput) -> Self {
+        Self { base, output }
+    }
+
+    /// Returns the output.
+    pub fn output(&self) -> &UncertaintyRegisterOutput {
+        &self.output
+    }
+
+    /// Sets the output.
+    pub fn set_output(&mut self, output: UncertaintyRegisterOutput) {
+        self.output = output;
+        self.base.touch();
+    }
+
+    /// Adds an uncertainty to the register.
+    pub fn add_uncertainty(&mut self, uncertainty: RegisteredUncertainty) {
+        self.output.uncertainties.push(uncertainty);
+        self.base.touch();
+    }
+
+    /// Adds a mitigation option to a registered uncertainty.
+    pub fn add_mitigation(
+        &mut self,
+        uncertainty_id: &str,
+        mitigation: Mitigation,
+    ) -> Result<(), ComponentError> {
+        let uncertainty = self
+            .output
+            .uncertainties
+            .iter_mut()
+            .find(|u| u.id == uncertainty_id)
+            .ok_or_else(|| {
+                ComponentError::InvalidOutput(format!("Unknown uncertainty: {}", uncertainty_id))
+            })?;
+        uncertainty.mitigations.push(mitigation);
+        self.base.touch();
+        Ok(())
+    }
+}
+
+impl Default for UncertaintyRegister {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Component for UncertaintyRegister {
+    fn id(&self) -> ComponentId {
+        self.base.id
+    }
+
+    fn component_type(&self) -> ComponentType {
+        self.base.component_type
+    }
+
+    fn status(&self) -> ComponentStatus {
+        self.base.status
+    }
+
+    fn created_at(&self) -> Timestamp {
+        self.base.created_at
+    }
+
+    fn updated_at(&self) -> Timestamp {
+        self.base.updated_at
+    }
+
+    fn start(&mut self) -> Result<(), ComponentError> {
+        self.base.start()
+    }
+
+    fn complete(&mut self) -> Result<(), ComponentError> {
+        self.base.complete()
+    }
+
+    fn mark_for_revision(&mut self, reason: String) -> Result<(), ComponentError> {
+        self.base.mark_for_revision(reason)
+    }
+
+    fn output_as_value(&self) -> serde_json::Value {
+        serde_json::to_value(&self.output).unwrap_or_default()
+    }
+
+    fn set_output_from_value(&mut self, value: serde_json::Value) -> Result<(), ComponentError> {
+        self.output = serde_json::from_value(value)
+            .map_err(|e| ComponentError::InvalidOutput(e.to_string()))?;
+        self.base.touch();
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::proact::Uncertainty;
+
+    fn uncertainty(
+        id: &str,
+        probability: Option<f64>,
+        impact: ImpactLevel,
+    ) -> RegisteredUncertainty {
+        RegisteredUncertainty {
+            id: id.to_string(),
+            description: id.to_string(),
+            consequence_uncertainty_id: None,
+            affected_alternative_ids: vec![],
+            probability,
+            probability_basis: None,
+            impact,
+            mitigations: vec![],
+        }
+    }
+
+    #[test]
+    fn uncertainty_register_has_correct_component_type() {
+        let ur = UncertaintyRegister::new();
+        assert_eq!(ur.component_type(), ComponentType::UncertaintyRegister);
+    }
+
+    #[test]
+    fn exposure_weights_probability_by_impact() {
+        let u = uncertainty("rates", Some(0.5), ImpactLevel::High);
+        assert_eq!(u.exposure(), Some(1.5));
+        assert_eq!(uncertainty("x", None, ImpactLevel::High).exposure(), None);
+    }
+
+    #[test]
+    fn ranked_orders_by_exposure_then_impact() {
+        let mut ur = UncertaintyRegister::new();
+        ur.add_uncertainty(uncertainty("unknown-low", None, ImpactLevel::Low));
+        ur.add_uncertainty(uncertainty("likely-medium", Some(0.8), ImpactLevel::Medium));
+        ur.add_uncertainty(uncertainty("unknown-critical", None, ImpactLevel::Critical));
+        ur.add_uncertainty(uncertainty("rare-high", Some(0.1), ImpactLevel::High));
+
+        let ids: Vec<_> = ur.output().ranked().iter().map(|u| u.id.as_str()).collect();
+
+        assert_eq!(
+            ids,
+            vec![
+                "likely-medium",
+                "rare-high",
+                "unknown-critical",
+                "unknown-low"
+            ]
+        );
+    }
+
+    #[test]
+    fn unmitigated_lists_high_impact_entries_without_options() {
+        let mut ur = UncertaintyRegister::new();
+        ur.add_uncertainty(uncertainty("job", Some(0.3), ImpactLevel::Critical));
+        ur.add_uncertainty(uncertainty("weather", Some(0.3), ImpactLevel::Low));
+        ur.add_uncertainty(uncertainty("rates", Some(0.3), ImpactLevel::High));
+        ur.add_mitigation(
+            "rates",
+            Mitigation {
+                description: "Fix the mortgage rate".to_string(),
+                kind: MitigationKind::Hedge,
+                cost: None,
+            },
+        )
+        .unwrap();
+
+        let unmitigated = ur.output().unmitigated();
+
+        assert_eq!(unmitigated.len(), 1);
+        assert_eq!(unmitigated[0].id, "job");
+    }
+
+    #[test]
+    fn add_mitigation_rejects_unknown_uncertainty() {
+        let mut ur = UncertaintyRegister::new();
+        let result = ur.add_mitigation(
+            "missing",
+            Mitigation {
+                description: "Ask around".to_string(),
+                kind: MitigationKind::Resolve,
+                cost: None,
+            },
+        );
+        assert!(matches!(result, Err(ComponentError::InvalidOutput(_))));
+    }
+
+    #[test]
+    fn unregistered_lists_consequence_uncertainties_not_linked() {
+        let consequences = ConsequencesOutput {
+            uncertainties: ["u1", "u2"]
+                .iter()
+                .map(|id| Uncertainty {
+                    id: id.to_string(),
+                    description: "Market".to_string(),
+                    driver: "Economy".to_string(),
+                    worth_resolving: true,
+                    resolvable: false,
+                    outcomes: vec![],
+                    elicitation: None,
+                })
+                .collect(),
+            ..Default::default()
+        };
+        let mut ur = UncertaintyRegister::new();
+        let mut linked = uncertainty("r1", None, ImpactLevel::Medium);
+        linked.consequence_uncertainty_id = Some("u1".to_string());
+        ur.add_uncertainty(linked);
+
+        assert_eq!(ur.output().unregistered(&consequences), vec!["u2"]);
+    }
+
+    #[test]
+    fn output_roundtrips_through_json() {
+        let mut ur = UncertaintyRegister::new();
+        ur.add_uncertainty(uncertainty("rates", Some(0.4), ImpactLevel::High));
+
+        let value = ur.output_as_value();
+        assert_eq!(value["uncertainties"][0]["impact"], "high");
+
+        let mut ur2 = UncertaintyRegister::new();
+        ur2.set_output_from_value(value).unwrap();
+        assert_eq!(ur2.output().uncertainties.len(), 1);
+    }
+}
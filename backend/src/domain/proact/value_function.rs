@@ -0,0 +1,307 @@
+//! Value functions - how much each level of a performance measure is worth.
+//!
+//! By default a measure's value rises linearly from the worst level among the
+//! alternatives (0) to the best (1). That overstates extra salary above a
+//! comfortable threshold, or an extra week of holiday when there are already
+//! six, so an objective can carry its own value function:
+//!
+//! - **Piecewise linear**: values the user gives at a few levels, joined by
+//!   straight lines.
+//! - **Exponential**: a smooth curve through the level the user says is
+//!   halfway in value between worst and best (the midvalue).
+
+use serde::{Deserialize, Serialize};
+
+/// Below this curvature the exponential is treated as linear.
+const LINEAR_CURVATURE: f64 = 1e-6;
+
+/// Largest curvature a midvalue can produce; steeper is effectively a step.
+const MAX_CURVATURE: f64 = 50.0;
+
+/// A level of a measure and what it is worth.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct ValuePoint {
+    /// Level of the measure, in its unit.
+    pub level: f64,
+    /// Value of the level (0.0-1.0).
+    pub value: f64,
+}
+
+/// Maps levels of a performance measure onto a 0-1 value scale.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum ValueFunction {
+    /// Straight line from the worst level among the alternatives to the best.
+    #[default]
+    Linear,
+    /// Straight lines between points sorted by level. Levels outside the
+    /// points take the nearest end's value.
+    PiecewiseLinear { points: Vec<ValuePoint> },
+    /// `(1 - e^(-c·z)) / (1 - e^(-c))`, where `z` is the level's fraction of
+    /// the way from `worst` to `best`. Positive curvature means diminishing
+    /// returns, negative increasing returns.
+    Exponential {
+        worst: f64,
+        best: f64,
+        curvature: f64,
+    },
+}
+
+/// Why a value function was rejected.
+#[derive(Debug, Clone, PartialEq, thiserror::Error)]
+pub enum ValueFunctionError {
+    #[error("A piecewise linear value function needs at least two points")]
+    TooFewPoints,
+
+    #[error("Level {0} appears more than once")]
+    DuplicateLevel(f64),
+
+    #[error("Value {0} is outside 0 to 1")]
+    ValueOutOfRange(f64),
+
+    #[error("Worst and best levels must differ")]
+    EmptyRange,
+
+    #[error("Midvalue {0} must lie strictly between the worst and best levels")]
+    MidvalueOutOfRange(f64),
+
+    #[error("Value function parameters must be finite numbers")]
+    NotFinite,
+}
+
+impl ValueFunction {
+    /// Builds a piecewise linear function, sorting the points by level.
+    pub fn piecewise_linear(mut points: Vec<ValuePoint>) -> Result<Self, ValueFunctionError> {
+        points.sort_by(|a, b| a.level.total_cmp(&b.level));
+        let function = ValueFunction::PiecewiseLinear { points };
+        function.validate()?;
+        Ok(function)
+    }
+
+    /// Fits an exponential through the midvalue: the level whose value is
+    /// halfway between `worst` (0) and `best` (1).
+    pub fn exponential_from_midvalue(
+        worst: f64,
+        best: f64,
+        midvalue: f64,
+    ) -> Result<Self, ValueFunctionError> {
+        if !(worst.is_finite() && best.is_finite() && midvalue.is_finite()) {
+            return Err(ValueFunctionError::NotFinite);
+        }
+        if worst == best {
+            return Err(ValueFunctionError::EmptyRange);
+        }
+        let z = (midvalue - worst) / (best - worst);
+        if z <= 0.0 || z >= 1.0 {
+            return Err(ValueFunctionError::MidvalueOutOfRange(midvalue));
+        }
+
+        // The value at z rises with curvature, so bisect for 0.5.
+        let (mut low, mut high) = (-MAX_CURVATURE, MAX_CURVATURE);
+        for _ in 0..100 {
+            let mid = (low + high) / 2.0;
+            if exponential(z, mid) < 0.5 {
+                low = mid;
+            } else {
+                high = mid;
+            }
+        }
+        Ok(ValueFunction::Exponential {
+            worst,
+            best,
+            curvature: (low + high) / 2.0,
+        })
+    }
+
+    /// Checks the parameters describe a usable function.
+    pub fn validate(&self) -> Result<(), ValueFunctionError> {
+        match self {
+            ValueFunction::Linear => Ok(()),
+            ValueFunction::PiecewiseLinear { points } => {
+                if points.len() < 2 {
+                    return Err(ValueFunctionError::TooFewPoints);
+                }
+                for point in points {
+                    if !(point.level.is_finite() && point.value.is_finite()) {
+                        return Err(ValueFunctionError::NotFinite);
+                    }
+                    if !(0.0..=1.0).contains(&point.value) {
+                        return Err(ValueFunctionError::ValueOutOfRange(point.value));
+                    }
+                }
+                for pair in points.windows(2) {
+                    if pair[0].level == pair[1].level {
+                        return Err(ValueFunctionError::DuplicateLevel(pair[0].level));
+                    }
+                }
+                Ok(())
+            }
+            ValueFunction::Exponential {
+                worst,
+                best,
+                curvature,
+            } => {
+                if !(worst.is_finite() && best.is_finite() && curvature.is_finite()) {
+                    return Err(ValueFunctionError::NotFinite);
+                }
+                if worst == best {
+                    return Err(ValueFunctionError::EmptyRange);
+                }
+                Ok(())
+            }
+        }
+    }
+
+    /// Value of `level` (0.0-1.0). `worst` and `best` are the levels among
+    /// the alternatives, used only by the linear function.
+    pub fn value(&self, level: f64, worst: f64, best: f64) -> f64 {
+        match self {
+            ValueFunction::Linear => {
+                if worst == best {
+                    return 1.0;
+                }
+                ((level - worst) / (best - worst)).clamp(0.0, 1.0)
+            }
+            ValueFunction::PiecewiseLinear { points } => piecewise(points, level),
+            ValueFunction::Exponential {
+                worst,
+                best,
+                curvature,
+            } => {
+                let z = ((level - worst) / (best - worst)).clamp(0.0, 1.0);
+                exponential(z, *curvature)
+            }
+        }
+    }
+}
+
+fn exponential(z: f64, curvature: f64) -> f64 {
+    if curvature.abs() < LINEAR_CURVATURE {
+        return z;
+    }
+    (1.0 - (-curvature * z).exp()) / (1.0 - (-curvature).exp())
+}
+
+fn piecewise(points: &[ValuePoint], level: f64) -> f64 {
+    let (Some(first), Some(last)) = (points.first(), points.last()) else {
+        return 0.0;
+    };
+    if level <= first.level {
+        return first.value;
+    }
+    if level >= last.level {
+        return last.value;
+    }
+    points
+        .windows(2)
+        .find(|pair| level <= pair[1].level)
+        .map(|pair| {
+            let t = (level - pair[0].level) / (pair[1].level - pair[0].level);
+            pair[0].value + t * (pair[1].value - pair[0].value)
+        })
+        .unwrap_or(last.value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn point(level: f64, value: f64) -> ValuePoint {
+        ValuePoint { level, value }
+    }
+
+    #[test]
+    fn linear_spans_the_alternatives_range() {
+        let f = ValueFunction::Linear;
+
+        assert_eq!(f.value(50.0, 0.0, 100.0), 0.5);
+        assert_eq!(f.value(20.0, 100.0, 0.0), 0.8);
+        assert_eq!(f.value(150.0, 0.0, 100.0), 1.0);
+        assert_eq!(f.value(7.0, 7.0, 7.0), 1.0);
+    }
+
+    #[test]
+    fn piecewise_interpolates_and_clamps() {
+        let f = ValueFunction::piecewise_linear(vec![
+            point(150_000.0, 1.0),
+            point(50_000.0, 0.0),
+            point(100_000.0, 0.8),
+        ])
+        .unwrap();
+
+        assert!((f.value(75_000.0, 0.0, 0.0) - 0.4).abs() < 1e-9);
+        assert!((f.value(125_000.0, 0.0, 0.0) - 0.9).abs() < 1e-9);
+        assert_eq!(f.value(10_000.0, 0.0, 0.0), 0.0);
+        assert_eq!(f.value(500_000.0, 0.0, 0.0), 1.0);
+    }
+
+    #[test]
+    fn piecewise_rejects_bad_points() {
+        assert_eq!(
+            ValueFunction::piecewise_linear(vec![point(1.0, 0.5)]),
+            Err(ValueFunctionError::TooFewPoints)
+        );
+        assert_eq!(
+            ValueFunction::piecewise_linear(vec![point(1.0, 0.0), point(1.0, 1.0)]),
+            Err(ValueFunctionError::DuplicateLevel(1.0))
+        );
+        assert_eq!(
+            ValueFunction::piecewise_linear(vec![point(1.0, 0.0), point(2.0, 1.5)]),
+            Err(ValueFunctionError::ValueOutOfRange(1.5))
+        );
+    }
+
+    #[test]
+    fn low_midvalue_gives_diminishing_returns() {
+        let f = ValueFunction::exponential_from_midvalue(50_000.0, 150_000.0, 80_000.0).unwrap();
+
+        assert!((f.value(80_000.0, 0.0, 0.0) - 0.5).abs() < 1e-6);
+        assert_eq!(f.value(50_000.0, 0.0, 0.0), 0.0);
+        assert!((f.value(150_000.0, 0.0, 0.0) - 1.0).abs() < 1e-9);
+        let ValueFunction::Exponential { curvature, .. } = f else {
+            panic!("expected exponential");
+        };
+        assert!(curvature > 0.0);
+    }
+
+    #[test]
+    fn central_midvalue_is_linear() {
+        let f = ValueFunction::exponential_from_midvalue(0.0, 10.0, 5.0).unwrap();
+
+        assert!((f.value(2.5, 0.0, 0.0) - 0.25).abs() < 1e-6);
+    }
+
+    #[test]
+    fn midvalue_works_when_lower_is_better() {
+        let f = ValueFunction::exponential_from_midvalue(90.0, 10.0, 70.0).unwrap();
+
+        assert!((f.value(70.0, 0.0, 0.0) - 0.5).abs() < 1e-6);
+        assert!(f.value(40.0, 0.0, 0.0) > 0.5);
+    }
+
+    #[test]
+    fn midvalue_must_lie_inside_range() {
+        assert_eq!(
+            ValueFunction::exponential_from_midvalue(0.0, 10.0, 12.0),
+            Err(ValueFunctionError::MidvalueOutOfRange(12.0))
+        );
+        assert_eq!(
+            ValueFunction::exponential_from_midvalue(5.0, 5.0, 5.0),
+            Err(ValueFunctionError::EmptyRange)
+        );
+    }
+
+    #[test]
+    fn value_function_serializes_with_kind_tag() {
+        let f = ValueFunction::Exponential {
+            worst: 0.0,
+            best: 1.0,
+            curvature: 2.0,
+        };
+
+        let json = serde_json::to_value(&f).unwrap();
+        assert_eq!(json["kind"], "exponential");
+        let back: ValueFunction = serde_json::from_value(json).unwrap();
+        assert_eq!(back, f);
+    }
+}
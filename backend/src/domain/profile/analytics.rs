@@ -0,0 +1,453 @@
+//! Cross-decision pattern analytics.
+//!
+//! Pure aggregations over a user's `DecisionRecord`s, shown on the profile
+//! dashboard:
+//!
+//! - domains where outcomes are rated below neutral
+//! - objectives that are repeatedly the heaviest weighted
+//! - average time from starting a decision to making it
+//! - how well predicted satisfaction matched outcomes, by quarter
+//! - decisions the user keeps facing, grouped by embedding similarity
+//!
+//! Patterns need at least [`MIN_PATTERN_SAMPLES`] decisions behind them, so
+//! one bad outcome does not label a whole domain.
+
+use std::collections::{BTreeMap, HashMap};
+
+use chrono::Datelike;
+use serde::Serialize;
+
+use crate::domain::foundation::{CycleId, Embedding};
+
+use super::history::{DecisionDomain, DecisionRecord};
+
+/// Average satisfaction score below which a domain is flagged (3 = neutral).
+pub const LOW_SATISFACTION_THRESHOLD: f32 = 3.0;
+
+/// Decisions needed before a domain, objective, or trend is reported.
+pub const MIN_PATTERN_SAMPLES: u32 = 2;
+
+/// Cosine similarity at which two decisions count as the same recurring one.
+pub const RECURRING_DECISION_SIMILARITY: f32 = 0.8;
+
+/// Change in accuracy between the first and latest quarter that counts as a
+/// trend rather than noise.
+const TREND_THRESHOLD: f32 = 0.1;
+
+/// A domain where the user's outcomes tend to disappoint.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct DomainSatisfaction {
+    pub domain: DecisionDomain,
+    pub outcomes_recorded: u32,
+    /// Mean satisfaction score, 1 to 5.
+    pub average_satisfaction: f32,
+}
+
+/// An objective that keeps coming out on top.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct DominantObjective {
+    pub objective: String,
+    pub times_dominant: u32,
+    /// Fraction of decisions where this objective weighed most.
+    pub share: f32,
+}
+
+/// How long decisions take.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct TimeToDecide {
+    pub average_days: f32,
+    pub sample_size: u32,
+}
+
+/// Prediction accuracy for outcomes recorded in one quarter.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct PredictionAccuracyPoint {
+    /// Calendar quarter, e.g. `"2026-Q1"`.
+    pub period: String,
+    /// Fraction of predictions within one step of the outcome.
+    pub accuracy: f32,
+    pub sample_size: u32,
+}
+
+/// A decision the user has faced more than once.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct RecurringDecision {
+    /// Titles of each occurrence, oldest first.
+    pub titles: Vec<String>,
+    /// Cycles of each occurrence, oldest first.
+    pub cycle_ids: Vec<CycleId>,
+}
+
+/// Direction prediction accuracy is moving.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AccuracyTrend {
+    Improving,
+    Declining,
+    Steady,
+    InsufficientData,
+}
+
+/// Patterns across all of a user's decisions.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct DecisionPatternAnalytics {
+    pub decisions_analyzed: u32,
+    /// Lowest satisfaction first.
+    pub low_satisfaction_domains: Vec<DomainSatisfaction>,
+    /// Most frequent first.
+    pub dominant_objectives: Vec<DominantObjective>,
+    pub time_to_decide: Option<TimeToDecide>,
+    /// Oldest quarter first.
+    pub prediction_accuracy: Vec<PredictionAccuracyPoint>,
+    pub accuracy_trend: AccuracyTrend,
+    /// Most occurrences first; empty unless embeddings were supplied.
+    pub recurring_decisions: Vec<RecurringDecision>,
+}
+
+impl DecisionPatternAnalytics {
+    pub fn from_history(decisions: &[DecisionRecord]) -> Self {
+        let prediction_accuracy = prediction_accuracy(decisions);
+        let accuracy_trend = accuracy_trend(&prediction_accuracy);
+        Self {
+            decisions_analyzed: decisions.len() as u32,
+            low_satisfaction_domains: low_satisfaction_domains(decisions),
+            dominant_objectives: dominant_objectives(decisions),
+            time_to_decide: time_to_decide(decisions),
+            prediction_accuracy,
+            accuracy_trend,
+            recurring_decisions: Vec::new(),
+        }
+    }
+
+    /// Adds recurring decisions, comparing each decision's embedding.
+    ///
+    /// Decisions are grouped in order: each joins the first group whose
+    /// earliest decision is at least [`RECURRING_DECISION_SIMILARITY`]
+    /// similar. Decisions without an embedding are left out.
+    pub fn with_recurring_decisions(
+        mut self,
+        decisions: &[DecisionRecord],
+        embeddings: &HashMap<CycleId, Embedding>,
+    ) -> Self {
+        let mut groups: Vec<(&Embedding, RecurringDecision)> = Vec::new();
+        for decision in decisions {
+            let Some(embedding) = embeddings.get(&decision.cycle_id) else {
+                continue;
+            };
+            let group = groups.iter_mut().find(|(seed, _)| {
+                seed.cosine_similarity(embedding) >= RECURRING_DECISION_SIMILARITY
+            });
+            match group {
+                Some((_, group)) => {
+                    group.titles.push(decision.title.clone());
+                    group.cycle_ids.push(decision.cycle_id);
+                }
+                None => groups.push((
+                    embedding,
+                    RecurringDecision {
+                        titles: vec![decision.title.clone()],
+                        cycle_ids: vec![decision.cycle_id],
+                    },
+                )),
+            }
+        }
+
+        let mut recurring: Vec<RecurringDecision> = groups
+            .into_iter()
+            .map(|(_, group)| group)
+            .filter(|group| group.cycle_ids.len() as u32 >= MIN_PATTERN_SAMPLES)
+            .collect();
+        recurring.sort_by_key(|group| std::cmp::Reverse(group.cycle_ids.len()));
+        self.recurring_decisions = recurring;
+        self
+    }
+}
+
+fn low_satisfaction_domains(decisions: &[DecisionRecord]) -> Vec<DomainSatisfaction> {
+    let mut scores: BTreeMap<DecisionDomain, (u32, u32)> = BTreeMap::new();
+    for decision in decisions {
+        if let Some(outcome) = &decision.outcome {
+            let entry = scores.entry(decision.domain).or_default();
+            entry.0 += 1;
+            entry.1 += outcome.satisfaction.score() as u32;
+        }
+    }
+
+    let mut low: Vec<DomainSatisfaction> = scores
+        .into_iter()
+        .filter(|(_, (count, _))| *count >= MIN_PATTERN_SAMPLES)
+        .map(|(domain, (count, total))| DomainSatisfaction {
+            domain,
+            outcomes_recorded: count,
+            average_satisfaction: total as f32 / count as f32,
+        })
+        .filter(|d| d.average_satisfaction < LOW_SATISFACTION_THRESHOLD)
+        .collect();
+    low.sort_by(|a, b| a.average_satisfaction.total_cmp(&b.average_satisfaction));
+    low
+}
+
+fn dominant_objectives(decisions: &[DecisionRecord]) -> Vec<DominantObjective> {
+    // Keyed case-insensitively; reported with the first spelling seen
+    let mut counts: BTreeMap<String, (String, u32)> = BTreeMap::new();
+    for objective in decisions.iter().filter_map(|d| d.dominant_objective()) {
+        let key = objective.trim().to_lowercase();
+        if key.is_empty() {
+            continue;
+        }
+        counts
+            .entry(key)
+            .or_insert_with(|| (objective.trim().to_string(), 0))
+            .1 += 1;
+    }
+
+    let total = decisions.len() as f32;
+    let mut dominant: Vec<DominantObjective> = counts
+        .into_values()
+        .filter(|(_, count)| *count >= MIN_PATTERN_SAMPLES)
+        .map(|(objective, count)| DominantObjective {
+            objective,
+            times_dominant: count,
+            share: count as f32 / total,
+        })
+        .collect();
+    dominant.sort_by_key(|d| std::cmp::Reverse(d.times_dominant));
+    dominant
+}
+
+fn time_to_decide(decisions: &[DecisionRecord]) -> Option<TimeToDecide> {
+    if decisions.is_empty() {
+        return None;
+    }
+    let total: f32 = decisions.iter().map(DecisionRecord::days_to_decide).sum();
+    Some(TimeToDecide {
+        average_days: total / decisions.len() as f32,
+        sample_size: decisions.len() as u32,
+    })
+}
+
+fn prediction_accuracy(decisions: &[DecisionRecord]) -> Vec<PredictionAccuracyPoint> {
+    let mut quarters: BTreeMap<(i32, u32), (u32, u32)> = BTreeMap::new();
+    for decision in decisions {
+        let (Some(accurate), Some(outcome)) = (
+            decision.prediction_was_accurate(),
+            decision.outcome.as_ref(),
+        ) else {
+            continue;
+        };
+        let recorded = outcome.recorded_at.as_datetime();
+        let entry = quarters
+            .entry((recorded.year(), recorded.month0() / 3 + 1))
+            .or_default();
+        entry.0 += 1;
+        if accurate {
+            entry.1 += 1;
+        }
+    }
+
+    quarters
+        .into_iter()
+        .map(
+            |((year, quarter), (count, accurate))| PredictionAccuracyPoint {
+                period: format!("{}-Q{}", year, quarter),
+                accuracy: accurate as f32 / count as f32,
+                sample_size: count,
+            },
+        )
+        .collect()
+}
+
+fn accuracy_trend(points: &[PredictionAccuracyPoint]) -> AccuracyTrend {
+    let samples: u32 = points.iter().map(|p| p.sample_size).sum();
+    let (Some(first), Some(last)) = (points.first(), points.last()) else {
+        return AccuracyTrend::InsufficientData;
+    };
+    if points.len() < 2 || samples < MIN_PATTERN_SAMPLES {
+        return AccuracyTrend::InsufficientData;
+    }
+    let change = last.accuracy - first.accuracy;
+    if change >= TREND_THRESHOLD {
+        AccuracyTrend::Improving
+    } else if change <= -TREND_THRESHOLD {
+        AccuracyTrend::Declining
+    } else {
+        AccuracyTrend::Steady
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::foundation::{CycleId, Timestamp};
+    use crate::domain::profile::{OutcomeRecord, SatisfactionLevel};
+
+    fn timestamp(rfc3339: &str) -> Timestamp {
+        Timestamp::from_datetime(rfc3339.parse().unwrap())
+    }
+
+    fn decision(domain: DecisionDomain, top_objective: &str) -> DecisionRecord {
+        DecisionRecord {
+            cycle_id: CycleId::new(),
+            started_at: timestamp("2026-01-01T00:00:00Z"),
+            date: timestamp("2026-01-11T00:00:00Z"),
+            title: "Decision".to_string(),
+            domain,
+            dq_score: 70,
+            dq_element_scores: Default::default(),
+            key_tradeoff: "Pay vs. time".to_string(),
+            chosen_alternative: "A".to_string(),
+            objectives: vec![top_objective.to_string(), "Other".to_string()],
+            predicted_satisfaction: None,
+            expected_outcome_at: None,
+            outcome: None,
+        }
+    }
+
+    fn with_outcome(
+        mut decision: DecisionRecord,
+        predicted: SatisfactionLevel,
+        actual: SatisfactionLevel,
+        recorded_at: &str,
+    ) -> DecisionRecord {
+        decision.predicted_satisfaction = Some(predicted);
+        decision.outcome = Some(OutcomeRecord {
+            recorded_at: timestamp(recorded_at),
+            satisfaction: actual,
+            actual_consequences: String::new(),
+            surprises: Vec::new(),
+            would_decide_same: Some(true),
+        });
+        decision
+    }
+
+    #[test]
+    fn flags_domains_with_repeatedly_low_satisfaction() {
+        use SatisfactionLevel::*;
+        let history = vec![
+            with_outcome(
+                decision(DecisionDomain::Housing, "Cost"),
+                Satisfied,
+                Dissatisfied,
+                "2026-03-01T00:00:00Z",
+            ),
+            with_outcome(
+                decision(DecisionDomain::Housing, "Cost"),
+                Satisfied,
+                Neutral,
+                "2026-03-01T00:00:00Z",
+            ),
+            // A single poor outcome is not a pattern
+            with_outcome(
+                decision(DecisionDomain::Career, "Growth"),
+                Satisfied,
+                VeryDissatisfied,
+                "2026-03-01T00:00:00Z",
+            ),
+        ];
+
+        let analytics = DecisionPatternAnalytics::from_history(&history);
+
+        assert_eq!(analytics.low_satisfaction_domains.len(), 1);
+        assert_eq!(
+            analytics.low_satisfaction_domains[0].domain,
+            DecisionDomain::Housing
+        );
+        assert_eq!(
+            analytics.low_satisfaction_domains[0].average_satisfaction,
+            2.5
+        );
+    }
+
+    #[test]
+    fn counts_objectives_that_repeatedly_dominate() {
+        let history = vec![
+            decision(DecisionDomain::Career, "Work-life balance"),
+            decision(DecisionDomain::Housing, "work-life balance "),
+            decision(DecisionDomain::Financial, "Return"),
+            decision(DecisionDomain::Career, "Salary"),
+        ];
+
+        let analytics = DecisionPatternAnalytics::from_history(&history);
+
+        assert_eq!(analytics.dominant_objectives.len(), 1);
+        assert_eq!(
+            analytics.dominant_objectives[0].objective,
+            "Work-life balance"
+        );
+        assert_eq!(analytics.dominant_objectives[0].times_dominant, 2);
+        assert_eq!(analytics.dominant_objectives[0].share, 0.5);
+        assert_eq!(analytics.time_to_decide.unwrap().average_days, 10.0);
+    }
+
+    #[test]
+    fn tracks_prediction_accuracy_by_quarter() {
+        use SatisfactionLevel::*;
+        let history = vec![
+            with_outcome(
+                decision(DecisionDomain::Career, "Pay"),
+                VerySatisfied,
+                Dissatisfied,
+                "2026-02-01T00:00:00Z",
+            ),
+            with_outcome(
+                decision(DecisionDomain::Career, "Pay"),
+                Satisfied,
+                Satisfied,
+                "2026-02-15T00:00:00Z",
+            ),
+            with_outcome(
+                decision(DecisionDomain::Career, "Pay"),
+                Satisfied,
+                VerySatisfied,
+                "2026-05-01T00:00:00Z",
+            ),
+        ];
+
+        let analytics = DecisionPatternAnalytics::from_history(&history);
+
+        let periods: Vec<&str> = analytics
+            .prediction_accuracy
+            .iter()
+            .map(|p| p.period.as_str())
+            .collect();
+        assert_eq!(periods, vec!["2026-Q1", "2026-Q2"]);
+        assert_eq!(analytics.prediction_accuracy[0].accuracy, 0.5);
+        assert_eq!(analytics.prediction_accuracy[1].accuracy, 1.0);
+        assert_eq!(analytics.accuracy_trend, AccuracyTrend::Improving);
+    }
+
+    #[test]
+    fn empty_history_has_no_patterns() {
+        let analytics = DecisionPatternAnalytics::from_history(&[]);
+        assert_eq!(analytics.decisions_analyzed, 0);
+        assert!(analytics.time_to_decide.is_none());
+        assert_eq!(analytics.accuracy_trend, AccuracyTrend::InsufficientData);
+    }
+
+    #[test]
+    fn groups_decisions_with_similar_embeddings() {
+        let history = vec![
+            decision(DecisionDomain::Housing, "Cost"),
+            decision(DecisionDomain::Career, "Growth"),
+            decision(DecisionDomain::Housing, "Cost"),
+            decision(DecisionDomain::Health, "Energy"),
+        ];
+        let embeddings: HashMap<CycleId, Embedding> = [
+            (history[0].cycle_id, vec![1.0, 0.0]),
+            (history[1].cycle_id, vec![0.0, 1.0]),
+            (history[2].cycle_id, vec![0.95, 0.1]),
+        ]
+        .into_iter()
+        .map(|(id, values)| (id, Embedding::new(values).unwrap()))
+        .collect();
+
+        let analytics = DecisionPatternAnalytics::from_history(&history)
+            .with_recurring_decisions(&history, &embeddings);
+
+        assert_eq!(analytics.recurring_decisions.len(), 1);
+        assert_eq!(
+            analytics.recurring_decisions[0].cycle_ids,
+            vec![history[0].cycle_id, history[2].cycle_id]
+        );
+    }
+}
@@ -0,0 +1,345 @@
+//! Anonymous benchmarking - How a user's decisions compare with everyone
+//! else's.
+//!
+//! Users who opt in contribute one value per metric and decision domain: the
+//! mean over their own decisions, so prolific users weigh no more than
+//! anyone else. The aggregation job turns those values into
+//! [`BenchmarkDistribution`]s, which keep only the 5th to 95th percentile
+//! points. No distribution is published until [`MIN_BENCHMARK_CONTRIBUTORS`]
+//! users contribute to it, and the extremes are never stored, so no single
+//! user's value can be read back out.
+
+use std::collections::BTreeMap;
+use std::fmt;
+
+use serde::Serialize;
+
+use crate::domain::foundation::Timestamp;
+
+use super::history::{DecisionDomain, DecisionRecord};
+
+/// Contributors needed before a distribution is published.
+pub const MIN_BENCHMARK_CONTRIBUTORS: u32 = 20;
+
+/// Spacing of the stored percentile points: 5th, 10th, ... 95th.
+const QUANTILE_STEP: u8 = 5;
+
+const DQ_ELEMENT_PREFIX: &str = "dq_element:";
+
+/// Something measured about a decision.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum BenchmarkMetric {
+    /// Overall decision quality score, 0-100.
+    DqOverall,
+    /// Score for one DQ element, 0-100, by element name.
+    DqElement(String),
+    /// Days from starting a decision to making it.
+    DaysToDecide,
+    /// Objectives the decision was weighed against.
+    ObjectivesConsidered,
+}
+
+impl BenchmarkMetric {
+    /// Stable key used in storage and APIs, e.g. `"dq_element:Clear Values"`.
+    pub fn key(&self) -> String {
+        match self {
+            BenchmarkMetric::DqOverall => "dq_overall".to_string(),
+            BenchmarkMetric::DqElement(name) => format!("{}{}", DQ_ELEMENT_PREFIX, name),
+            BenchmarkMetric::DaysToDecide => "days_to_decide".to_string(),
+            BenchmarkMetric::ObjectivesConsidered => "objectives_considered".to_string(),
+        }
+    }
+
+    pub fn parse(key: &str) -> Option<Self> {
+        match key {
+            "dq_overall" => Some(BenchmarkMetric::DqOverall),
+            "days_to_decide" => Some(BenchmarkMetric::DaysToDecide),
+            "objectives_considered" => Some(BenchmarkMetric::ObjectivesConsidered),
+            _ => key
+                .strip_prefix(DQ_ELEMENT_PREFIX)
+                .filter(|name| !name.is_empty())
+                .map(|name| BenchmarkMetric::DqElement(name.to_string())),
+        }
+    }
+}
+
+impl fmt::Display for BenchmarkMetric {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.key())
+    }
+}
+
+/// One user's values: the mean of each metric per decision domain.
+pub type BenchmarkValues = BTreeMap<(DecisionDomain, BenchmarkMetric), f32>;
+
+/// Averages each metric over the user's decisions in each domain.
+pub fn benchmark_values(decisions: &[DecisionRecord]) -> BenchmarkValues {
+    let mut sums: BTreeMap<(DecisionDomain, BenchmarkMetric), (f32, u32)> = BTreeMap::new();
+    let mut add = |domain: DecisionDomain, metric: BenchmarkMetric, value: f32| {
+        let entry = sums.entry((domain, metric)).or_default();
+        entry.0 += value;
+        entry.1 += 1;
+    };
+
+    for decision in decisions {
+        add(
+            decision.domain,
+            BenchmarkMetric::DqOverall,
+            decision.dq_score as f32,
+        );
+        for (element, score) in &decision.dq_element_scores {
+            add(
+                decision.domain,
+                BenchmarkMetric::DqElement(element.clone()),
+                *score as f32,
+            );
+        }
+        add(
+            decision.domain,
+            BenchmarkMetric::DaysToDecide,
+            decision.days_to_decide(),
+        );
+        add(
+            decision.domain,
+            BenchmarkMetric::ObjectivesConsidered,
+            decision.objectives.len() as f32,
+        );
+    }
+
+    sums.into_iter()
+        .map(|(key, (total, count))| (key, total / count as f32))
+        .collect()
+}
+
+/// Anonymized distribution of one metric in one domain.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BenchmarkDistribution {
+    pub domain: DecisionDomain,
+    pub metric: BenchmarkMetric,
+    /// Users whose values make up the distribution.
+    pub contributors: u32,
+    /// Values at the 5th, 10th, ... 95th percentiles.
+    pub quantiles: Vec<f32>,
+    pub generated_at: Timestamp,
+}
+
+impl BenchmarkDistribution {
+    /// Builds the distribution, or `None` if too few users contributed.
+    pub fn from_values(
+        domain: DecisionDomain,
+        metric: BenchmarkMetric,
+        mut values: Vec<f32>,
+        generated_at: Timestamp,
+    ) -> Option<Self> {
+        if (values.len() as u32) < MIN_BENCHMARK_CONTRIBUTORS {
+            return None;
+        }
+        values.sort_by(f32::total_cmp);
+
+        let quantiles = (1..100 / QUANTILE_STEP)
+            .map(|i| quantile(&values, (i * QUANTILE_STEP) as f32 / 100.0))
+            .collect();
+        Some(Self {
+            domain,
+            metric,
+            contributors: values.len() as u32,
+            quantiles,
+            generated_at,
+        })
+    }
+
+    /// Percentile `value` falls at, interpolated between the stored points
+    /// and kept within 5 to 95.
+    pub fn percentile_of(&self, value: f32) -> u8 {
+        let step = QUANTILE_STEP as f32;
+        let (Some(first), Some(last)) = (self.quantiles.first(), self.quantiles.last()) else {
+            return 50;
+        };
+        if value <= *first {
+            return QUANTILE_STEP;
+        }
+        if value >= *last {
+            return 100 - QUANTILE_STEP;
+        }
+
+        // Highest point at or below the value; ties resolve to the top of the run
+        let i = self
+            .quantiles
+            .iter()
+            .rposition(|q| *q <= value)
+            .unwrap_or(0);
+        let (low, high) = (self.quantiles[i], self.quantiles[i + 1]);
+        let fraction = if high > low {
+            (value - low) / (high - low)
+        } else {
+            0.0
+        };
+        (step * (i as f32 + 1.0 + fraction)).round() as u8
+    }
+}
+
+/// Linear interpolation between the closest ranks of sorted `values`.
+fn quantile(values: &[f32], p: f32) -> f32 {
+    let rank = p * (values.len() - 1) as f32;
+    let below = rank.floor() as usize;
+    let above = rank.ceil() as usize;
+    values[below] + (values[above] - values[below]) * (rank - below as f32)
+}
+
+/// Builds every distribution with enough contributors from each opted-in
+/// user's [`benchmark_values`].
+pub fn aggregate_benchmarks(
+    per_user: &[BenchmarkValues],
+    generated_at: Timestamp,
+) -> Vec<BenchmarkDistribution> {
+    let mut grouped: BTreeMap<(DecisionDomain, BenchmarkMetric), Vec<f32>> = BTreeMap::new();
+    for values in per_user {
+        for (key, value) in values {
+            grouped.entry(key.clone()).or_default().push(*value);
+        }
+    }
+
+    grouped
+        .into_iter()
+        .filter_map(|((domain, metric), values)| {
+            BenchmarkDistribution::from_values(domain, metric, values, generated_at)
+        })
+        .collect()
+}
+
+/// Where a user's value sits in a published distribution.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct BenchmarkPlacement {
+    pub domain: DecisionDomain,
+    pub metric: String,
+    /// The user's mean over their decisions in the domain.
+    pub value: f32,
+    /// 5 to 95.
+    pub percentile: u8,
+    pub contributors: u32,
+}
+
+impl BenchmarkPlacement {
+    pub fn new(distribution: &BenchmarkDistribution, value: f32) -> Self {
+        Self {
+            domain: distribution.domain,
+            metric: distribution.metric.key(),
+            value,
+            percentile: distribution.percentile_of(value),
+            contributors: distribution.contributors,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::foundation::CycleId;
+
+    fn decision(domain: DecisionDomain, dq_score: u8) -> DecisionRecord {
+        DecisionRecord {
+            cycle_id: CycleId::new(),
+            started_at: Timestamp::from_unix_secs(0),
+            date: Timestamp::from_unix_secs(2 * 86_400),
+            title: "Decision".to_string(),
+            domain,
+            dq_score,
+            dq_element_scores: BTreeMap::from([("Clear Values".to_string(), dq_score + 10)]),
+            key_tradeoff: "Pay vs. time".to_string(),
+            chosen_alternative: "A".to_string(),
+            objectives: vec!["Pay".to_string(), "Time".to_string()],
+            predicted_satisfaction: None,
+            expected_outcome_at: None,
+            outcome: None,
+        }
+    }
+
+    fn distribution(values: impl IntoIterator<Item = f32>) -> Option<BenchmarkDistribution> {
+        BenchmarkDistribution::from_values(
+            DecisionDomain::Career,
+            BenchmarkMetric::DqOverall,
+            values.into_iter().collect(),
+            Timestamp::now(),
+        )
+    }
+
+    #[test]
+    fn users_contribute_their_mean_per_domain() {
+        let values = benchmark_values(&[
+            decision(DecisionDomain::Career, 60),
+            decision(DecisionDomain::Career, 80),
+            decision(DecisionDomain::Housing, 50),
+        ]);
+
+        let career = |metric| values[&(DecisionDomain::Career, metric)];
+        assert_eq!(career(BenchmarkMetric::DqOverall), 70.0);
+        assert_eq!(
+            career(BenchmarkMetric::DqElement("Clear Values".to_string())),
+            80.0
+        );
+        assert_eq!(career(BenchmarkMetric::DaysToDecide), 2.0);
+        assert_eq!(career(BenchmarkMetric::ObjectivesConsidered), 2.0);
+        assert_eq!(
+            values[&(DecisionDomain::Housing, BenchmarkMetric::DqOverall)],
+            50.0
+        );
+    }
+
+    #[test]
+    fn distributions_need_enough_contributors() {
+        let too_few = (1..MIN_BENCHMARK_CONTRIBUTORS).map(|v| v as f32);
+        assert!(distribution(too_few).is_none());
+
+        let enough = (1..=MIN_BENCHMARK_CONTRIBUTORS).map(|v| v as f32);
+        let distribution = distribution(enough).unwrap();
+        assert_eq!(distribution.contributors, MIN_BENCHMARK_CONTRIBUTORS);
+        assert_eq!(distribution.quantiles.len(), 19);
+    }
+
+    #[test]
+    fn placements_interpolate_and_clamp_the_extremes() {
+        // Values 0..=100 put each percentile point at its own percentile
+        let distribution = distribution((0..=100).map(|v| v as f32)).unwrap();
+
+        assert_eq!(distribution.percentile_of(50.0), 50);
+        assert_eq!(distribution.percentile_of(72.5), 73);
+        assert_eq!(distribution.percentile_of(0.0), 5);
+        assert_eq!(distribution.percentile_of(100.0), 95);
+    }
+
+    #[test]
+    fn aggregation_groups_by_domain_and_metric() {
+        let per_user: Vec<BenchmarkValues> = (0..MIN_BENCHMARK_CONTRIBUTORS)
+            .map(|i| benchmark_values(&[decision(DecisionDomain::Career, 50 + i as u8)]))
+            .collect();
+
+        let distributions = aggregate_benchmarks(&per_user, Timestamp::now());
+
+        let metrics: Vec<String> = distributions.iter().map(|d| d.metric.key()).collect();
+        assert_eq!(
+            metrics,
+            vec![
+                "dq_overall",
+                "dq_element:Clear Values",
+                "days_to_decide",
+                "objectives_considered"
+            ]
+        );
+        assert!(distributions
+            .iter()
+            .all(|d| d.domain == DecisionDomain::Career));
+    }
+
+    #[test]
+    fn metric_keys_round_trip() {
+        for metric in [
+            BenchmarkMetric::DqOverall,
+            BenchmarkMetric::DqElement("Clear Values".to_string()),
+            BenchmarkMetric::DaysToDecide,
+            BenchmarkMetric::ObjectivesConsidered,
+        ] {
+            assert_eq!(BenchmarkMetric::parse(&metric.key()), Some(metric));
+        }
+        assert_eq!(BenchmarkMetric::parse("dq_element:"), None);
+    }
+}
@@ -0,0 +1,162 @@
+//! Communication preferences - how the agent should talk to a user.
+//!
+//! Learned from conversation history and used to adapt agent prompts; see
+//! `conversation::configs::adaptive_agent_config_for_component`. The reading
+//! level is the exception: users choose it, and it also applies to generated
+//! decision documents.
+
+use serde::{Deserialize, Serialize};
+
+/// How much of something the user wants.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PreferenceLevel {
+    Minimal,
+    Low,
+    #[default]
+    Medium,
+    High,
+    Extensive,
+}
+
+/// How the agent should challenge the user's assumptions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ChallengeStyle {
+    Gentle,
+    DevilsAdvocate,
+    Socratic,
+    Direct,
+    #[default]
+    Collaborative,
+}
+
+/// How quickly the conversation should move.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PacingPreference {
+    Quick,
+    #[default]
+    Steady,
+    Thorough,
+    UserControlled,
+}
+
+/// How the agent should express its own uncertainty.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum UncertaintyStyle {
+    /// Say "I don't know" directly.
+    Explicit,
+    /// Give confidence percentages.
+    Probabilistic,
+    /// Use qualifiers.
+    #[default]
+    Hedged,
+    /// Turn uncertainty into questions.
+    Exploratory,
+}
+
+/// How plainly the agent and generated documents should be written.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ReadingLevel {
+    /// No adjustment; decision-analysis terms are used as-is.
+    #[default]
+    Standard,
+    /// Plain language, around an 8th-grade reading level.
+    Plain,
+    /// Short sentences and everyday words, around a 5th-grade reading level.
+    Simple,
+}
+
+impl ReadingLevel {
+    /// Approximate US school grade the text should be readable at.
+    pub fn target_grade(&self) -> Option<u8> {
+        match self {
+            ReadingLevel::Standard => None,
+            ReadingLevel::Plain => Some(8),
+            ReadingLevel::Simple => Some(5),
+        }
+    }
+
+    pub fn is_plain_language(&self) -> bool {
+        *self != ReadingLevel::Standard
+    }
+
+    /// Prompt instruction for the agent, if the level needs one.
+    pub fn instruction(&self) -> Option<&'static str> {
+        match self {
+            ReadingLevel::Standard => None,
+            ReadingLevel::Plain => Some(
+                "Write in plain language at about an 8th-grade reading level: short sentences, everyday words, and explain any decision-analysis term the first time you use it.",
+            ),
+            ReadingLevel::Simple => Some(
+                "Write at about a 5th-grade reading level: very short sentences, common words, one idea per sentence, and no jargon.",
+            ),
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ReadingLevel::Standard => "standard",
+            ReadingLevel::Plain => "plain",
+            ReadingLevel::Simple => "simple",
+        }
+    }
+}
+
+/// Interaction style settings.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct InteractionStyle {
+    /// How much context before questions.
+    pub preamble_preference: PreferenceLevel,
+    pub challenge_style: ChallengeStyle,
+    /// Depth of explanations.
+    pub explanation_depth: PreferenceLevel,
+    pub pacing: PacingPreference,
+    pub uncertainty_handling: UncertaintyStyle,
+}
+
+/// How a user prefers the agent to communicate.
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct CommunicationPreferences {
+    pub interaction_style: InteractionStyle,
+    /// Phrasing that resonates with the user.
+    pub positive_patterns: Vec<String>,
+    /// Phrasing to avoid.
+    pub negative_patterns: Vec<String>,
+    /// Sessions these preferences were learned from.
+    pub learned_from_sessions: u32,
+    /// Chosen by the user rather than learned; kept across profile updates.
+    #[serde(default)]
+    pub reading_level: ReadingLevel,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reading_level_defaults_for_stored_preferences() {
+        let prefs: CommunicationPreferences = serde_json::from_value(serde_json::json!({
+            "interaction_style": InteractionStyle::default(),
+            "positive_patterns": [],
+            "negative_patterns": [],
+            "learned_from_sessions": 2,
+        }))
+        .unwrap();
+        assert_eq!(prefs.reading_level, ReadingLevel::Standard);
+    }
+
+    #[test]
+    fn only_adjusted_levels_have_instructions() {
+        assert!(ReadingLevel::Standard.instruction().is_none());
+        assert!(!ReadingLevel::Standard.is_plain_language());
+        for level in [ReadingLevel::Plain, ReadingLevel::Simple] {
+            assert!(level.is_plain_language());
+            assert!(level.instruction().is_some());
+        }
+        assert!(ReadingLevel::Simple.target_grade() < ReadingLevel::Plain.target_grade());
+    }
+}
@@ -0,0 +1,183 @@
+//! Decision history - past decisions and how they turned out.
+
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::domain::foundation::{CycleId, Timestamp};
+
+/// Area of life a decision falls in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DecisionDomain {
+    Career,
+    Financial,
+    Family,
+    Health,
+    Relationship,
+    Education,
+    Housing,
+    Lifestyle,
+    Business,
+    Other,
+}
+
+impl DecisionDomain {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            DecisionDomain::Career => "career",
+            DecisionDomain::Financial => "financial",
+            DecisionDomain::Family => "family",
+            DecisionDomain::Health => "health",
+            DecisionDomain::Relationship => "relationship",
+            DecisionDomain::Education => "education",
+            DecisionDomain::Housing => "housing",
+            DecisionDomain::Lifestyle => "lifestyle",
+            DecisionDomain::Business => "business",
+            DecisionDomain::Other => "other",
+        }
+    }
+
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "career" => Some(DecisionDomain::Career),
+            "financial" => Some(DecisionDomain::Financial),
+            "family" => Some(DecisionDomain::Family),
+            "health" => Some(DecisionDomain::Health),
+            "relationship" => Some(DecisionDomain::Relationship),
+            "education" => Some(DecisionDomain::Education),
+            "housing" => Some(DecisionDomain::Housing),
+            "lifestyle" => Some(DecisionDomain::Lifestyle),
+            "business" => Some(DecisionDomain::Business),
+            "other" => Some(DecisionDomain::Other),
+            _ => None,
+        }
+    }
+}
+
+/// Five-point satisfaction scale.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SatisfactionLevel {
+    VeryDissatisfied,
+    Dissatisfied,
+    Neutral,
+    Satisfied,
+    VerySatisfied,
+}
+
+impl SatisfactionLevel {
+    /// The level at position `score` on the scale, 1 to 5.
+    pub fn from_score(score: u8) -> Option<Self> {
+        match score {
+            1 => Some(SatisfactionLevel::VeryDissatisfied),
+            2 => Some(SatisfactionLevel::Dissatisfied),
+            3 => Some(SatisfactionLevel::Neutral),
+            4 => Some(SatisfactionLevel::Satisfied),
+            5 => Some(SatisfactionLevel::VerySatisfied),
+            _ => None,
+        }
+    }
+
+    /// Survey wording for the level.
+    pub fn label(&self) -> &'static str {
+        match self {
+            SatisfactionLevel::VeryDissatisfied => "Very dissatisfied",
+            SatisfactionLevel::Dissatisfied => "Dissatisfied",
+            SatisfactionLevel::Neutral => "Neutral",
+            SatisfactionLevel::Satisfied => "Satisfied",
+            SatisfactionLevel::VerySatisfied => "Very satisfied",
+        }
+    }
+
+    /// Position on the scale, 1 (very dissatisfied) to 5 (very satisfied).
+    pub fn score(&self) -> u8 {
+        match self {
+            SatisfactionLevel::VeryDissatisfied => 1,
+            SatisfactionLevel::Dissatisfied => 2,
+            SatisfactionLevel::Neutral => 3,
+            SatisfactionLevel::Satisfied => 4,
+            SatisfactionLevel::VerySatisfied => 5,
+        }
+    }
+}
+
+/// How a decision turned out, recorded by the user some time after deciding.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct OutcomeRecord {
+    pub recorded_at: Timestamp,
+    pub satisfaction: SatisfactionLevel,
+    #[serde(default)]
+    pub actual_consequences: String,
+    #[serde(default)]
+    pub surprises: Vec<String>,
+    /// Unknown when the outcome came from the one-click email survey.
+    #[serde(default)]
+    pub would_decide_same: Option<bool>,
+}
+
+impl OutcomeRecord {
+    /// An outcome captured from the emailed satisfaction survey, which asks
+    /// only how satisfied the user is.
+    pub fn from_survey(satisfaction: SatisfactionLevel) -> Self {
+        Self {
+            recorded_at: Timestamp::now(),
+            satisfaction,
+            actual_consequences: String::new(),
+            surprises: Vec::new(),
+            would_decide_same: None,
+        }
+    }
+}
+
+/// One completed decision.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DecisionRecord {
+    pub cycle_id: CycleId,
+    /// When work on the decision began.
+    pub started_at: Timestamp,
+    /// When the decision was made.
+    pub date: Timestamp,
+    pub title: String,
+    pub domain: DecisionDomain,
+    pub dq_score: u8,
+    /// Score per DQ element (0-100), keyed by element name, e.g.
+    /// "Helpful Problem Frame". `dq_score` is the weakest of these.
+    #[serde(default)]
+    pub dq_element_scores: BTreeMap<String, u8>,
+    pub key_tradeoff: String,
+    pub chosen_alternative: String,
+    /// Objectives in order of weight, heaviest first.
+    pub objectives: Vec<String>,
+    /// Satisfaction the user expected when deciding.
+    pub predicted_satisfaction: Option<SatisfactionLevel>,
+    /// When the user expects to know how the decision turned out. An outcome
+    /// reminder is emailed on this date.
+    #[serde(default)]
+    pub expected_outcome_at: Option<Timestamp>,
+    pub outcome: Option<OutcomeRecord>,
+}
+
+impl DecisionRecord {
+    /// The most heavily weighted objective.
+    pub fn dominant_objective(&self) -> Option<&str> {
+        self.objectives.first().map(String::as_str)
+    }
+
+    /// Days from starting the decision to making it.
+    pub fn days_to_decide(&self) -> f32 {
+        self.date
+            .duration_since(&self.started_at)
+            .num_seconds()
+            .max(0) as f32
+            / 86_400.0
+    }
+
+    /// Whether the predicted satisfaction was within one step of the actual
+    /// outcome. `None` until both are known.
+    pub fn prediction_was_accurate(&self) -> Option<bool> {
+        let predicted = self.predicted_satisfaction?;
+        let actual = self.outcome.as_ref()?.satisfaction;
+        Some(predicted.score().abs_diff(actual.score()) <= 1)
+    }
+}
@@ -0,0 +1,52 @@
+//! Decision profile domain module.
+//!
+//! Cross-decision intelligence: what a user's past decisions say about how
+//! they decide. See `features/user/decision-profile.md`.
+//!
+//! # Types
+//!
+//! - `DecisionRecord` - One completed decision and, later, its outcome
+//! - `DecisionDomain` - Area of life a decision falls in (career, housing, ...)
+//! - `OutcomeRecord` - How the decision turned out
+//! - `SatisfactionLevel` - Five-point satisfaction scale
+//! - `OutcomeReminder` - Emailed survey asking how a decision turned out
+//! - `DecisionPatternAnalytics` - Patterns aggregated across a user's decisions
+//! - `BenchmarkDistribution` - Anonymized distribution of a metric across users
+//! - `ProfileSummary` - Headline traits: risk attitude, style, blind spots
+//! - `CommunicationPreferences` - How the agent should talk to the user
+//! - `ProfileRevision` - One version of a user's profile summary
+//! - `TeamProfile` - Anonymized aggregate of an organization's member profiles
+
+mod analytics;
+mod benchmark;
+mod communication;
+mod history;
+mod outcome_reminder;
+mod revision;
+mod team;
+mod traits;
+
+pub use analytics::{
+    AccuracyTrend, DecisionPatternAnalytics, DomainSatisfaction, DominantObjective,
+    PredictionAccuracyPoint, RecurringDecision, TimeToDecide, LOW_SATISFACTION_THRESHOLD,
+    MIN_PATTERN_SAMPLES, RECURRING_DECISION_SIMILARITY,
+};
+pub use benchmark::{
+    aggregate_benchmarks, benchmark_values, BenchmarkDistribution, BenchmarkMetric,
+    BenchmarkPlacement, BenchmarkValues, MIN_BENCHMARK_CONTRIBUTORS,
+};
+pub use communication::{
+    ChallengeStyle, CommunicationPreferences, InteractionStyle, PacingPreference, PreferenceLevel,
+    ReadingLevel, UncertaintyStyle,
+};
+pub use history::{DecisionDomain, DecisionRecord, OutcomeRecord, SatisfactionLevel};
+pub use outcome_reminder::{
+    hash_survey_token, OutcomeReminder, OutcomeReminderStatus, MAX_REMINDER_ATTEMPTS,
+    SURVEY_VALID_DAYS,
+};
+pub use revision::{diff_summaries, FieldChange, ProfileRevision, RevisionReason};
+pub use team::{
+    CategoryShare, SharedBlindSpot, TeamProfile, TeamProfileError, TeamProfileSettings,
+    MIN_SHARED_BLIND_SPOT_MEMBERS, MIN_TEAM_SIZE,
+};
+pub use traits::{ProfileConfidence, ProfileSummary, RiskClassification, StyleClassification};
@@ -0,0 +1,271 @@
+//! OutcomeReminder - A scheduled nudge to journal how a decision turned out.
+//!
+//! When a user sets the date by which they expect to know how a decision
+//! worked out, a reminder is scheduled for that date. A background worker
+//! emails the user a one-click satisfaction survey; the answer becomes the
+//! decision's `OutcomeRecord`, which is what prediction accuracy and the rest
+//! of the profile learn from.
+//!
+//! Survey links carry a random token. Only its SHA-256 hash is stored, so a
+//! leaked database does not leak working links. Links stay open for
+//! [`SURVEY_VALID_DAYS`] after the email is sent. Transient send failures
+//! are retried with exponential backoff until [`MAX_REMINDER_ATTEMPTS`].
+
+use sha2::{Digest, Sha256};
+use uuid::Uuid;
+
+use crate::domain::foundation::{CycleId, OutcomeReminderId, Timestamp, UserId};
+
+/// Attempts before a reminder is marked failed.
+pub const MAX_REMINDER_ATTEMPTS: u32 = 5;
+
+/// Days a survey link can be answered after the reminder is sent.
+pub const SURVEY_VALID_DAYS: i64 = 60;
+
+/// Delay before the first retry; doubles with each further attempt.
+const FIRST_RETRY_DELAY_SECS: u64 = 300;
+
+/// Where a reminder stands.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OutcomeReminderStatus {
+    /// Waiting for its due date or a retry.
+    Scheduled,
+    /// Survey emailed; waiting for an answer.
+    Sent,
+    /// The outcome has been recorded.
+    Responded,
+    /// Gave up: a permanent error, or out of attempts.
+    Failed,
+    /// Replaced by a newer reminder for the same decision.
+    Cancelled,
+}
+
+impl OutcomeReminderStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            OutcomeReminderStatus::Scheduled => "scheduled",
+            OutcomeReminderStatus::Sent => "sent",
+            OutcomeReminderStatus::Responded => "responded",
+            OutcomeReminderStatus::Failed => "failed",
+            OutcomeReminderStatus::Cancelled => "cancelled",
+        }
+    }
+
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "scheduled" => Some(OutcomeReminderStatus::Scheduled),
+            "sent" => Some(OutcomeReminderStatus::Sent),
+            "responded" => Some(OutcomeReminderStatus::Responded),
+            "failed" => Some(OutcomeReminderStatus::Failed),
+            "cancelled" => Some(OutcomeReminderStatus::Cancelled),
+            _ => None,
+        }
+    }
+}
+
+/// One reminder to record the outcome of a decision.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OutcomeReminder {
+    pub id: OutcomeReminderId,
+    pub user_id: UserId,
+    pub cycle_id: CycleId,
+    /// Address the survey is sent to.
+    pub recipient: String,
+    /// Decision title, quoted in the email.
+    pub decision_title: String,
+    pub status: OutcomeReminderStatus,
+    pub attempts: u32,
+    pub last_error: Option<String>,
+    /// The expected-outcome date, then the next retry after a failed send.
+    pub next_attempt_at: Timestamp,
+    /// SHA-256 hex digest of the survey link token, once one is issued.
+    pub survey_token_hash: Option<String>,
+    pub sent_at: Option<Timestamp>,
+    pub responded_at: Option<Timestamp>,
+    pub created_at: Timestamp,
+}
+
+impl OutcomeReminder {
+    /// A reminder due at `due_at`.
+    pub fn schedule(
+        user_id: UserId,
+        cycle_id: CycleId,
+        recipient: impl Into<String>,
+        decision_title: impl Into<String>,
+        due_at: Timestamp,
+    ) -> Self {
+        Self {
+            id: OutcomeReminderId::new(),
+            user_id,
+            cycle_id,
+            recipient: recipient.into(),
+            decision_title: decision_title.into(),
+            status: OutcomeReminderStatus::Scheduled,
+            attempts: 0,
+            last_error: None,
+            next_attempt_at: due_at,
+            survey_token_hash: None,
+            sent_at: None,
+            responded_at: None,
+            created_at: Timestamp::now(),
+        }
+    }
+
+    /// Whether a worker should send this reminder at `now`.
+    pub fn is_due(&self, now: Timestamp) -> bool {
+        self.status == OutcomeReminderStatus::Scheduled && !self.next_attempt_at.is_after(&now)
+    }
+
+    /// Whether the reminder is still waiting to be sent or answered.
+    pub fn is_open(&self) -> bool {
+        matches!(
+            self.status,
+            OutcomeReminderStatus::Scheduled | OutcomeReminderStatus::Sent
+        )
+    }
+
+    /// Whether a survey answer can be accepted at `now`.
+    pub fn survey_is_open(&self, now: Timestamp) -> bool {
+        self.status == OutcomeReminderStatus::Sent
+            && self
+                .survey_expires_at()
+                .is_some_and(|expires| now.is_before(&expires))
+    }
+
+    /// When the emailed survey link stops working.
+    pub fn survey_expires_at(&self) -> Option<Timestamp> {
+        self.sent_at.map(|sent| sent.plus_days(SURVEY_VALID_DAYS))
+    }
+
+    /// Generates a fresh survey token, keeping only its hash. The returned
+    /// token goes into the emailed links and is not recoverable afterwards.
+    pub fn issue_survey_token(&mut self) -> String {
+        let token = format!("{}{}", Uuid::new_v4().simple(), Uuid::new_v4().simple());
+        self.survey_token_hash = Some(hash_survey_token(&token));
+        token
+    }
+
+    pub fn record_sent(&mut self) {
+        self.attempts += 1;
+        self.status = OutcomeReminderStatus::Sent;
+        self.last_error = None;
+        self.sent_at = Some(Timestamp::now());
+    }
+
+    /// Records a failed send, scheduling a retry if `retryable` and
+    /// attempts remain.
+    pub fn record_failure(&mut self, error: impl Into<String>, retryable: bool) {
+        self.attempts += 1;
+        self.last_error = Some(error.into());
+        self.survey_token_hash = None;
+        if retryable && self.attempts < MAX_REMINDER_ATTEMPTS {
+            self.next_attempt_at = Timestamp::now().plus_secs(retry_delay_secs(self.attempts));
+        } else {
+            self.status = OutcomeReminderStatus::Failed;
+        }
+    }
+
+    /// Closes the reminder once the outcome is recorded, by survey or
+    /// otherwise.
+    pub fn record_response(&mut self) {
+        self.status = OutcomeReminderStatus::Responded;
+        self.responded_at = Some(Timestamp::now());
+    }
+
+    pub fn cancel(&mut self) {
+        self.status = OutcomeReminderStatus::Cancelled;
+    }
+}
+
+/// Hex-encoded SHA-256 of a survey token, as stored and looked up.
+pub fn hash_survey_token(token: &str) -> String {
+    Sha256::digest(token.as_bytes())
+        .iter()
+        .map(|byte| format!("{:02x}", byte))
+        .collect()
+}
+
+/// Backoff after `attempts` failures: 5, 10, 20, 40... minutes.
+fn retry_delay_secs(attempts: u32) -> u64 {
+    FIRST_RETRY_DELAY_SECS << attempts.saturating_sub(1).min(10)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn reminder(due_at: Timestamp) -> OutcomeReminder {
+        OutcomeReminder::schedule(
+            UserId::new("user-1").unwrap(),
+            CycleId::new(),
+            "jo@example.com",
+            "Which offer?",
+            due_at,
+        )
+    }
+
+    #[test]
+    fn reminders_fall_due_on_the_expected_outcome_date() {
+        let now = Timestamp::now();
+        let reminder = reminder(now.plus_days(30));
+
+        assert!(!reminder.is_due(now));
+        assert!(reminder.is_due(now.plus_days(30)));
+    }
+
+    #[test]
+    fn only_the_hash_of_the_survey_token_is_kept() {
+        let mut reminder = reminder(Timestamp::now());
+        let token = reminder.issue_survey_token();
+
+        assert_eq!(token.len(), 64);
+        assert_eq!(
+            reminder.survey_token_hash.as_deref(),
+            Some(hash_survey_token(&token).as_str())
+        );
+        assert_ne!(reminder.survey_token_hash.as_deref(), Some(token.as_str()));
+    }
+
+    #[test]
+    fn surveys_close_after_the_validity_window_or_a_response() {
+        let mut reminder = reminder(Timestamp::now());
+        reminder.issue_survey_token();
+        reminder.record_sent();
+
+        let now = Timestamp::now();
+        assert!(reminder.survey_is_open(now));
+        assert!(!reminder.survey_is_open(now.plus_days(SURVEY_VALID_DAYS + 1)));
+
+        reminder.record_response();
+        assert!(!reminder.survey_is_open(now));
+    }
+
+    #[test]
+    fn failed_sends_back_off_until_attempts_run_out() {
+        let mut reminder = reminder(Timestamp::now());
+
+        reminder.issue_survey_token();
+        reminder.record_failure("smtp timeout", true);
+        assert_eq!(reminder.status, OutcomeReminderStatus::Scheduled);
+        assert!(reminder.survey_token_hash.is_none());
+        assert!(!reminder.is_due(Timestamp::now()));
+
+        for _ in 1..MAX_REMINDER_ATTEMPTS {
+            reminder.record_failure("smtp timeout", true);
+        }
+        assert_eq!(reminder.status, OutcomeReminderStatus::Failed);
+    }
+
+    #[test]
+    fn status_round_trips_through_strings() {
+        for status in [
+            OutcomeReminderStatus::Scheduled,
+            OutcomeReminderStatus::Sent,
+            OutcomeReminderStatus::Responded,
+            OutcomeReminderStatus::Failed,
+            OutcomeReminderStatus::Cancelled,
+        ] {
+            assert_eq!(OutcomeReminderStatus::parse(status.as_str()), Some(status));
+        }
+    }
+}
@@ -0,0 +1,125 @@
+//! Profile revisions - every change to a user's profile summary, kept so the
+//! user can see what changed and undo an update they disagree with.
+//!
+//! Rolling back never rewrites history: it appends a new revision carrying
+//! the old summary, so the update being undone stays visible.
+
+use serde::{Deserialize, Serialize};
+
+use crate::domain::foundation::{CycleId, Timestamp};
+
+use super::traits::ProfileSummary;
+
+/// Why a revision was created.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum RevisionReason {
+    /// Analysis after a decision, or a manual recompute when `cycle_id` is `None`.
+    Analysis { cycle_id: Option<CycleId> },
+    /// The user restored an earlier version.
+    Rollback { to_version: u32 },
+    /// The user changed a setting stored with the profile, such as their
+    /// reading level.
+    Preferences,
+}
+
+/// One version of a user's profile summary.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ProfileRevision {
+    /// Starts at 1 and increases by one per revision.
+    pub version: u32,
+    pub summary: ProfileSummary,
+    pub reason: RevisionReason,
+    pub created_at: Timestamp,
+}
+
+impl ProfileRevision {
+    /// The revision following `previous` (or the first, when `None`).
+    pub fn next(
+        previous: Option<&ProfileRevision>,
+        summary: ProfileSummary,
+        reason: RevisionReason,
+    ) -> Self {
+        Self {
+            version: previous.map_or(1, |p| p.version + 1),
+            summary,
+            reason,
+            created_at: Timestamp::now(),
+        }
+    }
+}
+
+/// One summary field that changed between two revisions.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct FieldChange {
+    pub field: String,
+    pub before: serde_json::Value,
+    pub after: serde_json::Value,
+}
+
+/// Fields that differ between two summaries, sorted by field name.
+pub fn diff_summaries(before: &ProfileSummary, after: &ProfileSummary) -> Vec<FieldChange> {
+    let (serde_json::Value::Object(before), serde_json::Value::Object(after)) = (
+        serde_json::to_value(before).unwrap_or_default(),
+        serde_json::to_value(after).unwrap_or_default(),
+    ) else {
+        return Vec::new();
+    };
+
+    after
+        .into_iter()
+        .filter_map(|(field, after_value)| {
+            let before_value = before.get(&field).cloned().unwrap_or_default();
+            (before_value != after_value).then_some(FieldChange {
+                field,
+                before: before_value,
+                after: after_value,
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::profile::{ProfileConfidence, RiskClassification};
+
+    fn summary(risk: RiskClassification, decisions: u32) -> ProfileSummary {
+        ProfileSummary {
+            risk_classification: Some(risk),
+            risk_confidence: 0.6,
+            decisions_analyzed: decisions,
+            profile_confidence: ProfileConfidence::Medium,
+            top_values: vec!["Security".to_string()],
+            decision_style: None,
+            active_blind_spots: vec![],
+            communication: Default::default(),
+        }
+    }
+
+    #[test]
+    fn versions_increase_from_one() {
+        let reason = RevisionReason::Analysis { cycle_id: None };
+        let first = ProfileRevision::next(None, summary(RiskClassification::RiskAverse, 3), reason);
+        let second = ProfileRevision::next(
+            Some(&first),
+            summary(RiskClassification::RiskAverse, 4),
+            reason,
+        );
+        assert_eq!(first.version, 1);
+        assert_eq!(second.version, 2);
+    }
+
+    #[test]
+    fn diff_lists_only_changed_fields() {
+        let changes = diff_summaries(
+            &summary(RiskClassification::RiskAverse, 3),
+            &summary(RiskClassification::RiskNeutral, 4),
+        );
+
+        let fields: Vec<&str> = changes.iter().map(|c| c.field.as_str()).collect();
+        assert_eq!(fields, vec!["decisions_analyzed", "risk_classification"]);
+        assert_eq!(changes[1].before, "risk_averse");
+        assert_eq!(changes[1].after, "risk_neutral");
+    }
+}
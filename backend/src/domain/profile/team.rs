@@ -0,0 +1,284 @@
+//! Team profile - member profiles combined into an anonymized view of an
+//! organization.
+//!
+//! Only counts are kept, never which member contributed what. A team profile
+//! needs at least [`MIN_TEAM_SIZE`] contributing members, and a blind spot is
+//! only listed once [`MIN_SHARED_BLIND_SPOT_MEMBERS`] members share it, so no
+//! individual can be picked out.
+
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::domain::foundation::Timestamp;
+
+use super::traits::{ProfileSummary, RiskClassification, StyleClassification};
+
+/// Contributing members needed before a team profile is shown.
+pub const MIN_TEAM_SIZE: u32 = 5;
+
+/// Members who must share a blind spot before it is listed.
+pub const MIN_SHARED_BLIND_SPOT_MEMBERS: u32 = 2;
+
+/// How many members fall into one category.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CategoryShare<T> {
+    pub category: T,
+    pub members: u32,
+    /// Fraction of contributing members.
+    pub share: f32,
+}
+
+/// A blind spot several members have in common.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SharedBlindSpot {
+    pub blind_spot: String,
+    pub members: u32,
+}
+
+/// Anonymized aggregate of an organization's member profiles.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TeamProfile {
+    pub organization: String,
+    pub contributing_members: u32,
+    /// Largest group first.
+    pub risk_distribution: Vec<CategoryShare<RiskClassification>>,
+    /// Largest group first.
+    pub style_mix: Vec<CategoryShare<StyleClassification>>,
+    /// Most widely shared first.
+    pub common_blind_spots: Vec<SharedBlindSpot>,
+    pub generated_at: Timestamp,
+}
+
+/// Why a team profile could not be built.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TeamProfileError {
+    /// Too few members have consented for the aggregate to stay anonymous.
+    TooFewMembers { required: u32, available: u32 },
+}
+
+impl std::fmt::Display for TeamProfileError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TeamProfileError::TooFewMembers {
+                required,
+                available,
+            } => write!(
+                f,
+                "A team profile needs {} consenting members; {} have consented",
+                required, available
+            ),
+        }
+    }
+}
+
+impl std::error::Error for TeamProfileError {}
+
+impl TeamProfile {
+    /// Combines the profiles of members who consented to team sharing.
+    pub fn aggregate(
+        organization: impl Into<String>,
+        members: &[ProfileSummary],
+    ) -> Result<Self, TeamProfileError> {
+        let count = members.len() as u32;
+        if count < MIN_TEAM_SIZE {
+            return Err(TeamProfileError::TooFewMembers {
+                required: MIN_TEAM_SIZE,
+                available: count,
+            });
+        }
+
+        let risk_distribution = shares(members.iter().filter_map(|m| m.risk_classification), count);
+        let style_mix = shares(members.iter().filter_map(|m| m.decision_style), count);
+
+        // Keyed case-insensitively, counted once per member
+        let mut blind_spots: BTreeMap<String, (String, u32)> = BTreeMap::new();
+        for member in members {
+            let mut seen = std::collections::BTreeSet::new();
+            for spot in &member.active_blind_spots {
+                let key = spot.trim().to_lowercase();
+                if key.is_empty() || !seen.insert(key.clone()) {
+                    continue;
+                }
+                blind_spots
+                    .entry(key)
+                    .or_insert_with(|| (spot.trim().to_string(), 0))
+                    .1 += 1;
+            }
+        }
+        let mut common_blind_spots: Vec<SharedBlindSpot> = blind_spots
+            .into_values()
+            .filter(|(_, members)| *members >= MIN_SHARED_BLIND_SPOT_MEMBERS)
+            .map(|(blind_spot, members)| SharedBlindSpot {
+                blind_spot,
+                members,
+            })
+            .collect();
+        common_blind_spots.sort_by_key(|c| std::cmp::Reverse(c.members));
+
+        Ok(Self {
+            organization: organization.into(),
+            contributing_members: count,
+            risk_distribution,
+            style_mix,
+            common_blind_spots,
+            generated_at: Timestamp::now(),
+        })
+    }
+
+    /// Summary for an agent's system prompt.
+    pub fn agent_context(&self) -> String {
+        let mut context = format!(
+            "Team context ({} members, anonymized):\n",
+            self.contributing_members
+        );
+        if !self.risk_distribution.is_empty() {
+            let risk: Vec<String> = self
+                .risk_distribution
+                .iter()
+                .map(|s| format!("{:.0}% {}", s.share * 100.0, s.category.label()))
+                .collect();
+            context.push_str(&format!("- Risk attitudes: {}\n", risk.join(", ")));
+        }
+        if !self.style_mix.is_empty() {
+            let styles: Vec<String> = self
+                .style_mix
+                .iter()
+                .map(|s| format!("{:.0}% {}", s.share * 100.0, s.category.label()))
+                .collect();
+            context.push_str(&format!("- Decision styles: {}\n", styles.join(", ")));
+        }
+        if !self.common_blind_spots.is_empty() {
+            let spots: Vec<&str> = self
+                .common_blind_spots
+                .iter()
+                .map(|s| s.blind_spot.as_str())
+                .collect();
+            context.push_str(&format!(
+                "- Common blind spots to watch for: {}\n",
+                spots.join(", ")
+            ));
+        }
+        context
+    }
+}
+
+/// Share of `total` in each category, largest first.
+fn shares<T: Ord + Copy>(categories: impl Iterator<Item = T>, total: u32) -> Vec<CategoryShare<T>> {
+    let mut counts: BTreeMap<T, u32> = BTreeMap::new();
+    for category in categories {
+        *counts.entry(category).or_default() += 1;
+    }
+    let mut shares: Vec<CategoryShare<T>> = counts
+        .into_iter()
+        .map(|(category, members)| CategoryShare {
+            category,
+            members,
+            share: members as f32 / total as f32,
+        })
+        .collect();
+    shares.sort_by_key(|s| std::cmp::Reverse(s.members));
+    shares
+}
+
+/// An organization's team profile settings.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TeamProfileSettings {
+    pub organization: String,
+    /// Whether agents see the team profile in members' sessions.
+    pub agent_context_enabled: bool,
+    pub updated_at: Timestamp,
+}
+
+impl TeamProfileSettings {
+    pub fn new(organization: impl Into<String>, agent_context_enabled: bool) -> Self {
+        Self {
+            organization: organization.into(),
+            agent_context_enabled,
+            updated_at: Timestamp::now(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::profile::ProfileConfidence;
+
+    fn member(
+        risk: RiskClassification,
+        style: StyleClassification,
+        blind_spots: &[&str],
+    ) -> ProfileSummary {
+        ProfileSummary {
+            risk_classification: Some(risk),
+            risk_confidence: 0.8,
+            decisions_analyzed: 6,
+            profile_confidence: ProfileConfidence::Medium,
+            top_values: vec!["Security".to_string()],
+            decision_style: Some(style),
+            active_blind_spots: blind_spots.iter().map(|s| s.to_string()).collect(),
+            communication: Default::default(),
+        }
+    }
+
+    fn team() -> Vec<ProfileSummary> {
+        use RiskClassification::*;
+        use StyleClassification::*;
+        vec![
+            member(RiskAverse, AnalyticalCautious, &["Sunk cost", "Anchoring"]),
+            member(RiskAverse, AnalyticalCautious, &["sunk cost"]),
+            member(RiskAverse, Balanced, &["Sunk cost"]),
+            member(RiskNeutral, AnalyticalDynamic, &["Overconfidence"]),
+            member(RiskSeeking, IntuitiveDynamic, &[]),
+        ]
+    }
+
+    #[test]
+    fn refuses_teams_too_small_to_stay_anonymous() {
+        let err = TeamProfile::aggregate("acme.com", &team()[..4]).unwrap_err();
+        assert_eq!(
+            err,
+            TeamProfileError::TooFewMembers {
+                required: 5,
+                available: 4
+            }
+        );
+    }
+
+    #[test]
+    fn aggregates_risk_style_and_shared_blind_spots() {
+        let profile = TeamProfile::aggregate("acme.com", &team()).unwrap();
+
+        assert_eq!(profile.contributing_members, 5);
+        assert_eq!(
+            profile.risk_distribution[0].category,
+            RiskClassification::RiskAverse
+        );
+        assert_eq!(profile.risk_distribution[0].share, 0.6);
+        assert_eq!(
+            profile.style_mix[0].category,
+            StyleClassification::AnalyticalCautious
+        );
+
+        // Anchoring and overconfidence belong to one member each
+        assert_eq!(
+            profile.common_blind_spots,
+            vec![SharedBlindSpot {
+                blind_spot: "Sunk cost".to_string(),
+                members: 3
+            }]
+        );
+    }
+
+    #[test]
+    fn agent_context_summarizes_without_naming_members() {
+        let context = TeamProfile::aggregate("acme.com", &team())
+            .unwrap()
+            .agent_context();
+        assert!(context.contains("5 members"));
+        assert!(context.contains("60% risk-averse"));
+        assert!(context.contains("Sunk cost"));
+        assert!(!context.contains("Anchoring"));
+    }
+}
@@ -0,0 +1,97 @@
+//! Profile traits - what analysis concluded about how a user decides.
+
+use serde::{Deserialize, Serialize};
+
+use super::communication::CommunicationPreferences;
+
+/// Overall attitude to uncertainty.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RiskClassification {
+    RiskSeeking,
+    RiskNeutral,
+    RiskAverse,
+}
+
+impl RiskClassification {
+    pub fn label(&self) -> &'static str {
+        match self {
+            RiskClassification::RiskSeeking => "risk-seeking",
+            RiskClassification::RiskNeutral => "risk-neutral",
+            RiskClassification::RiskAverse => "risk-averse",
+        }
+    }
+}
+
+/// Primary decision-making approach.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum StyleClassification {
+    /// Data-driven, careful.
+    AnalyticalCautious,
+    /// Data-driven, action-oriented.
+    AnalyticalDynamic,
+    /// Gut-feel, careful.
+    IntuitiveCautious,
+    /// Gut-feel, action-oriented.
+    IntuitiveDynamic,
+    /// Mix of approaches.
+    Balanced,
+}
+
+impl StyleClassification {
+    pub fn label(&self) -> &'static str {
+        match self {
+            StyleClassification::AnalyticalCautious => "analytical and cautious",
+            StyleClassification::AnalyticalDynamic => "analytical and action-oriented",
+            StyleClassification::IntuitiveCautious => "intuitive and cautious",
+            StyleClassification::IntuitiveDynamic => "intuitive and action-oriented",
+            StyleClassification::Balanced => "balanced",
+        }
+    }
+}
+
+/// How much the profile can be trusted, from the number of decisions behind it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ProfileConfidence {
+    /// Fewer than 3 decisions analyzed.
+    #[default]
+    Low,
+    /// 3-7 decisions.
+    Medium,
+    /// 8-15 decisions.
+    High,
+    /// More than 15 decisions.
+    VeryHigh,
+}
+
+impl ProfileConfidence {
+    pub fn from_decisions(decisions_analyzed: u32) -> Self {
+        match decisions_analyzed {
+            0..=2 => ProfileConfidence::Low,
+            3..=7 => ProfileConfidence::Medium,
+            8..=15 => ProfileConfidence::High,
+            _ => ProfileConfidence::VeryHigh,
+        }
+    }
+}
+
+/// The headline traits of a user's decision profile.
+///
+/// The default is the summary of a user with no decisions analyzed yet.
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+pub struct ProfileSummary {
+    /// `None` until enough decisions have been analyzed.
+    pub risk_classification: Option<RiskClassification>,
+    /// Confidence in the risk classification, 0.0 to 1.0.
+    pub risk_confidence: f32,
+    pub decisions_analyzed: u32,
+    pub profile_confidence: ProfileConfidence,
+    pub top_values: Vec<String>,
+    pub decision_style: Option<StyleClassification>,
+    pub active_blind_spots: Vec<String>,
+    /// Defaults for summaries stored before preferences were learned.
+    #[serde(default)]
+    pub communication: CommunicationPreferences,
+}
@@ -0,0 +1,251 @@
+//! ProvisionedUser aggregate - A user an identity provider manages via SCIM.
+//!
+//! The user's ID is their subject at the identity provider, so tokens issued
+//! to a provisioned user resolve to the same `UserId`. Deprovisioning never
+//! deletes anything; it marks the user inactive, which withdraws their plan
+//! access until the provider reactivates them.
+
+use serde::{Deserialize, Serialize};
+
+use crate::domain::foundation::{Timestamp, UserId};
+
+/// Longest accepted `userName`, in characters.
+pub const MAX_USER_NAME_CHARS: usize = 255;
+
+/// Why a provisioning change was rejected.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum ProvisioningError {
+    #[error("userName must be 1-{MAX_USER_NAME_CHARS} characters")]
+    InvalidUserName,
+
+    #[error("'{0}' is not a valid email address")]
+    InvalidEmail(String),
+}
+
+/// Attribute changes requested by the identity provider.
+///
+/// `None` leaves an attribute alone; `Some(None)` clears an optional one.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ProvisionedUserChanges {
+    pub user_name: Option<String>,
+    pub email: Option<Option<String>>,
+    pub display_name: Option<Option<String>>,
+    pub active: Option<bool>,
+}
+
+/// What applying [`ProvisionedUserChanges`] actually changed.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ProvisioningOutcome {
+    /// Profile attributes whose value changed, by SCIM attribute name.
+    pub changed_attributes: Vec<&'static str>,
+    /// `Some(active)` when the user was deactivated or reactivated.
+    pub activation: Option<bool>,
+}
+
+impl ProvisioningOutcome {
+    pub fn is_empty(&self) -> bool {
+        self.changed_attributes.is_empty() && self.activation.is_none()
+    }
+}
+
+/// A user created and kept in sync by an identity provider.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ProvisionedUser {
+    /// Subject of the user at the identity provider.
+    pub user_id: UserId,
+    /// Unique login name, usually the user's email address.
+    pub user_name: String,
+    pub email: Option<String>,
+    pub display_name: Option<String>,
+    /// False once the identity provider deprovisions the user.
+    pub active: bool,
+    /// OAuth client ID of the provisioning service.
+    pub provisioned_by: String,
+    pub created_at: Timestamp,
+    pub updated_at: Timestamp,
+}
+
+impl ProvisionedUser {
+    /// Creates an active provisioned user.
+    pub fn new(
+        user_id: UserId,
+        user_name: impl Into<String>,
+        email: Option<String>,
+        display_name: Option<String>,
+        provisioned_by: impl Into<String>,
+    ) -> Result<Self, ProvisioningError> {
+        let now = Timestamp::now();
+        Ok(Self {
+            user_id,
+            user_name: validate_user_name(user_name)?,
+            email: validate_email(email)?,
+            display_name: normalize(display_name),
+            active: true,
+            provisioned_by: provisioned_by.into(),
+            created_at: now,
+            updated_at: now,
+        })
+    }
+
+    /// Applies the provider's changes, validating all of them before any
+    /// takes effect.
+    pub fn apply(
+        &mut self,
+        changes: ProvisionedUserChanges,
+    ) -> Result<ProvisioningOutcome, ProvisioningError> {
+        let user_name = changes.user_name.map(validate_user_name).transpose()?;
+        let email = changes.email.map(validate_email).transpose()?;
+        let display_name = changes.display_name.map(normalize);
+
+        let mut outcome = ProvisioningOutcome::default();
+        if let Some(user_name) = user_name.filter(|name| name != &self.user_name) {
+            self.user_name = user_name;
+            outcome.changed_attributes.push("userName");
+        }
+        if let Some(email) = email.filter(|email| email != &self.email) {
+            self.email = email;
+            outcome.changed_attributes.push("emails");
+        }
+        if let Some(display_name) = display_name.filter(|name| name != &self.display_name) {
+            self.display_name = display_name;
+            outcome.changed_attributes.push("displayName");
+        }
+        if let Some(active) = changes.active.filter(|active| *active != self.active) {
+            self.active = active;
+            outcome.activation = Some(active);
+        }
+
+        if !outcome.is_empty() {
+            self.updated_at = Timestamp::now();
+        }
+        Ok(outcome)
+    }
+
+    /// Marks the user inactive. Returns false if they already were.
+    pub fn deactivate(&mut self) -> bool {
+        let outcome = self
+            .apply(ProvisionedUserChanges {
+                active: Some(false),
+                ..Default::default()
+            })
+            .expect("activation changes are always valid");
+        outcome.activation.is_some()
+    }
+}
+
+fn normalize(value: Option<String>) -> Option<String> {
+    value
+        .map(|v| v.trim().to_string())
+        .filter(|v| !v.is_empty())
+}
+
+fn validate_user_name(user_name: impl Into<String>) -> Result<String, ProvisioningError> {
+    let user_name = user_name.into().trim().to_string();
+    if user_name.is_empty() || user_name.chars().count() > MAX_USER_NAME_CHARS {
+        return Err(ProvisioningError::InvalidUserName);
+    }
+    Ok(user_name)
+}
+
+fn validate_email(email: Option<String>) -> Result<Option<String>, ProvisioningError> {
+    match normalize(email) {
+        Some(email) if !email.contains('@') => Err(ProvisioningError::InvalidEmail(email)),
+        email => Ok(email),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn user() -> ProvisionedUser {
+        ProvisionedUser::new(
+            UserId::new("idp-123").unwrap(),
+            "ada@example.com",
+            Some("ada@example.com".to_string()),
+            Some("Ada Lovelace".to_string()),
+            "okta-scim",
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn new_users_are_active() {
+        let user = user();
+
+        assert!(user.active);
+        assert_eq!(user.provisioned_by, "okta-scim");
+    }
+
+    #[test]
+    fn rejects_blank_user_names_and_bad_emails() {
+        let id = UserId::new("idp-123").unwrap();
+
+        assert_eq!(
+            ProvisionedUser::new(id.clone(), "  ", None, None, "okta-scim"),
+            Err(ProvisioningError::InvalidUserName)
+        );
+        assert_eq!(
+            ProvisionedUser::new(id, "ada", Some("ada".to_string()), None, "okta-scim"),
+            Err(ProvisioningError::InvalidEmail("ada".to_string()))
+        );
+    }
+
+    #[test]
+    fn apply_reports_only_real_changes() {
+        let mut user = user();
+
+        let outcome = user
+            .apply(ProvisionedUserChanges {
+                user_name: Some("ada@example.com".to_string()),
+                display_name: Some(Some("Countess of Lovelace".to_string())),
+                active: Some(true),
+                ..Default::default()
+            })
+            .unwrap();
+
+        assert_eq!(outcome.changed_attributes, vec!["displayName"]);
+        assert_eq!(outcome.activation, None);
+        assert_eq!(user.display_name.as_deref(), Some("Countess of Lovelace"));
+    }
+
+    #[test]
+    fn apply_is_all_or_nothing() {
+        let mut user = user();
+        let before = user.clone();
+
+        let result = user.apply(ProvisionedUserChanges {
+            display_name: Some(None),
+            email: Some(Some("not-an-email".to_string())),
+            ..Default::default()
+        });
+
+        assert!(result.is_err());
+        assert_eq!(user, before);
+    }
+
+    #[test]
+    fn deactivate_is_idempotent() {
+        let mut user = user();
+
+        assert!(user.deactivate());
+        assert!(!user.active);
+        assert!(!user.deactivate());
+    }
+
+    #[test]
+    fn reactivation_is_reported() {
+        let mut user = user();
+        user.deactivate();
+
+        let outcome = user
+            .apply(ProvisionedUserChanges {
+                active: Some(true),
+                ..Default::default()
+            })
+            .unwrap();
+
+        assert_eq!(outcome.activation, Some(true));
+        assert!(user.active);
+    }
+}
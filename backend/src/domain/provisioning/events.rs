@@ -0,0 +1,92 @@
+//! Provisioning domain events.
+//!
+//! Every change an identity provider makes is recorded with the client that
+//! made it, giving an audit trail of automated account management:
+//! - `UserProvisioned` - Identity provider created a user
+//! - `ProvisionedUserUpdated` - Profile attributes changed
+//! - `UserDeprovisioned` - User deactivated and lost plan access
+//! - `UserReprovisioned` - Deactivated user made active again
+
+use serde::{Deserialize, Serialize};
+
+use crate::domain::foundation::{domain_event, EventId, Timestamp, UserId};
+
+/// Published when an identity provider creates a user.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UserProvisioned {
+    pub event_id: EventId,
+    pub user_id: UserId,
+    pub user_name: String,
+    /// OAuth client ID of the provisioning service.
+    pub provisioned_by: String,
+    pub provisioned_at: Timestamp,
+}
+
+domain_event!(
+    UserProvisioned,
+    event_type = "provisioning.user_provisioned.v1",
+    schema_version = 1,
+    aggregate_id = user_id,
+    aggregate_type = "ProvisionedUser",
+    occurred_at = provisioned_at,
+    event_id = event_id
+);
+
+/// Published when an identity provider changes a user's profile attributes.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProvisionedUserUpdated {
+    pub event_id: EventId,
+    pub user_id: UserId,
+    /// SCIM names of the attributes that changed; values are left out.
+    pub changed_attributes: Vec<String>,
+    pub updated_by: String,
+    pub updated_at: Timestamp,
+}
+
+domain_event!(
+    ProvisionedUserUpdated,
+    event_type = "provisioning.user_updated.v1",
+    schema_version = 1,
+    aggregate_id = user_id,
+    aggregate_type = "ProvisionedUser",
+    occurred_at = updated_at,
+    event_id = event_id
+);
+
+/// Published when an identity provider deactivates a user.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UserDeprovisioned {
+    pub event_id: EventId,
+    pub user_id: UserId,
+    pub deprovisioned_by: String,
+    pub deprovisioned_at: Timestamp,
+}
+
+domain_event!(
+    UserDeprovisioned,
+    event_type = "provisioning.user_deprovisioned.v1",
+    schema_version = 1,
+    aggregate_id = user_id,
+    aggregate_type = "ProvisionedUser",
+    occurred_at = deprovisioned_at,
+    event_id = event_id
+);
+
+/// Published when an identity provider reactivates a deactivated user.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UserReprovisioned {
+    pub event_id: EventId,
+    pub user_id: UserId,
+    pub reprovisioned_by: String,
+    pub reprovisioned_at: Timestamp,
+}
+
+domain_event!(
+    UserReprovisioned,
+    event_type = "provisioning.user_reprovisioned.v1",
+    schema_version = 1,
+    aggregate_id = user_id,
+    aggregate_type = "ProvisionedUser",
+    occurred_at = reprovisioned_at,
+    event_id = event_id
+);
@@ -0,0 +1,20 @@
+//! Provisioning domain module.
+//!
+//! Users that an enterprise identity provider creates, updates, and
+//! deactivates through SCIM. Deactivated users keep their data but lose
+//! plan access, both from their own membership and from organizations.
+//!
+//! # Types
+//!
+//! - `ProvisionedUser` - Profile and active flag managed by the provider
+//! - `ProvisionedUserChanges` - Attribute changes from a SCIM request
+//! - `events` - Audit trail of provisioning changes
+
+mod aggregate;
+mod events;
+
+pub use aggregate::{
+    ProvisionedUser, ProvisionedUserChanges, ProvisioningError, ProvisioningOutcome,
+    MAX_USER_NAME_CHARS,
+};
+pub use events::{ProvisionedUserUpdated, UserDeprovisioned, UserProvisioned, UserReprovisioned};
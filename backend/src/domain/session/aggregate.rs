@@ -2,6 +2,13 @@
 //!
 //! Sessions are the top-level container for decision contexts.
 //! Each session belongs to one user and can contain multiple cycles.
+//! A session may also be shared with one of its owner's organizations,
+//! which makes it visible to the organization's members.
+//!
+//! # Trash
+//!
+//! Deleting a session moves it to the trash, where it stays restorable for
+//! [`TRASH_RETENTION_DAYS`] before it is purged for good.
 //!
 //! # Ownership
 //!
@@ -9,13 +16,16 @@
 //! Cycles are managed by the Cycle module.
 
 use crate::domain::foundation::{
-    CycleId, DomainError, ErrorCode, SessionId, SessionStatus, Timestamp, UserId,
+    CycleId, DomainError, ErrorCode, OrganizationId, SessionId, SessionStatus, Timestamp, UserId,
 };
 use serde::{Deserialize, Serialize};
 
 /// Maximum length for session title.
 pub const MAX_TITLE_LENGTH: usize = 500;
 
+/// Days a deleted session stays in the trash before it is purged.
+pub const TRASH_RETENTION_DAYS: i64 = 30;
+
 /// Session aggregate - top-level container for a decision context.
 ///
 /// # Invariants
@@ -23,7 +33,8 @@ pub const MAX_TITLE_LENGTH: usize = 500;
 /// - `id` is globally unique
 /// - `title` is 1-500 characters, non-empty
 /// - `cycle_ids` contains no duplicates
-/// - Archived sessions cannot be modified
+/// - Archived and deleted sessions cannot be modified
+/// - `deleted_at` and `status_before_deletion` are set exactly when deleted
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct Session {
     /// Unique identifier for this session.
@@ -32,15 +43,27 @@ pub struct Session {
     /// User who owns this session.
     user_id: UserId,
 
+    /// Organization the session is shared with, if any.
+    #[serde(default)]
+    organization_id: Option<OrganizationId>,
+
     /// Session title.
     title: String,
 
     /// Optional description.
     description: Option<String>,
 
-    /// Current status (Active or Archived).
+    /// Current status (Active, Archived or Deleted).
     status: SessionStatus,
 
+    /// When the session was moved to the trash.
+    #[serde(default)]
+    deleted_at: Option<Timestamp>,
+
+    /// Status to return to when restored from the trash.
+    #[serde(default)]
+    status_before_deletion: Option<SessionStatus>,
+
     /// IDs of cycles in this session (not owned).
     cycle_ids: Vec<CycleId>,
 
@@ -64,9 +87,12 @@ impl Session {
         Ok(Self {
             id,
             user_id,
+            organization_id: None,
             title,
             description: None,
             status: SessionStatus::Active,
+            deleted_at: None,
+            status_before_deletion: None,
             cycle_ids: Vec::new(),
             created_at: now,
             updated_at: now,
@@ -88,15 +114,37 @@ impl Session {
         Self {
             id,
             user_id,
+            organization_id: None,
             title,
             description,
             status,
+            deleted_at: None,
+            status_before_deletion: None,
             cycle_ids,
             created_at,
             updated_at,
         }
     }
 
+    /// Sets the organization of a reconstituted session.
+    pub fn with_organization(mut self, organization_id: Option<OrganizationId>) -> Self {
+        self.organization_id = organization_id;
+        self
+    }
+
+    /// Marks a reconstituted session as deleted at `deleted_at`, to be
+    /// restored to `status_before_deletion`.
+    pub fn with_deletion(
+        mut self,
+        deleted_at: Timestamp,
+        status_before_deletion: SessionStatus,
+    ) -> Self {
+        self.status = SessionStatus::Deleted;
+        self.deleted_at = Some(deleted_at);
+        self.status_before_deletion = Some(status_before_deletion);
+        self
+    }
+
     // ─────────────────────────────────────────────────────────────────────────
     // Accessors
     // ─────────────────────────────────────────────────────────────────────────
@@ -111,6 +159,11 @@ impl Session {
         &self.user_id
     }
 
+    /// Returns the organization the session is shared with.
+    pub fn organization_id(&self) -> Option<&OrganizationId> {
+        self.organization_id.as_ref()
+    }
+
     /// Returns the session title.
     pub fn title(&self) -> &str {
         &self.title
@@ -126,6 +179,22 @@ impl Session {
         self.status
     }
 
+    /// Returns when the session was moved to the trash.
+    pub fn deleted_at(&self) -> Option<&Timestamp> {
+        self.deleted_at.as_ref()
+    }
+
+    /// Returns the status a deleted session is restored to.
+    pub fn status_before_deletion(&self) -> Option<SessionStatus> {
+        self.status_before_deletion
+    }
+
+    /// Returns when a deleted session is due to be purged.
+    pub fn purge_after(&self) -> Option<Timestamp> {
+        self.deleted_at
+            .map(|deleted_at| deleted_at.add_days(TRASH_RETENTION_DAYS))
+    }
+
     /// Returns the cycle IDs.
     pub fn cycle_ids(&self) -> &[CycleId] {
         &self.cycle_ids
@@ -206,6 +275,23 @@ impl Session {
         Ok(old_description)
     }
 
+    /// Share the session with an organization, or make it private again
+    /// with `None`. Callers check that the owner belongs to the organization.
+    ///
+    /// # Errors
+    ///
+    /// - `SessionArchived` if session is archived
+    pub fn set_organization(
+        &mut self,
+        organization_id: Option<OrganizationId>,
+    ) -> Result<Option<OrganizationId>, DomainError> {
+        self.ensure_mutable()?;
+
+        let old_organization = std::mem::replace(&mut self.organization_id, organization_id);
+        self.updated_at = Timestamp::now();
+        Ok(old_organization)
+    }
+
     /// Add a cycle to this session.
     ///
     /// # Errors
@@ -243,6 +329,56 @@ impl Session {
         Ok(())
     }
 
+    /// Move the session to the trash, remembering its status for restore.
+    ///
+    /// # Errors
+    ///
+    /// - `InvalidStateTransition` if already deleted
+    pub fn delete(&mut self) -> Result<(), DomainError> {
+        if !self.status.can_transition_to(&SessionStatus::Deleted) {
+            return Err(DomainError::new(
+                ErrorCode::InvalidStateTransition,
+                "Session is already in the trash",
+            ));
+        }
+
+        let now = Timestamp::now();
+        self.status_before_deletion = Some(self.status);
+        self.status = SessionStatus::Deleted;
+        self.deleted_at = Some(now);
+        self.updated_at = now;
+        Ok(())
+    }
+
+    /// Take the session out of the trash, returning the status it is
+    /// restored to.
+    ///
+    /// # Errors
+    ///
+    /// - `InvalidStateTransition` if not deleted
+    pub fn restore(&mut self) -> Result<SessionStatus, DomainError> {
+        if !self.status.is_deleted() {
+            return Err(DomainError::new(
+                ErrorCode::InvalidStateTransition,
+                "Session is not in the trash",
+            ));
+        }
+
+        self.status = self
+            .status_before_deletion
+            .take()
+            .unwrap_or(SessionStatus::Active);
+        self.deleted_at = None;
+        self.updated_at = Timestamp::now();
+        Ok(self.status)
+    }
+
+    /// Checks if the trash retention window has ended at `now`.
+    pub fn is_due_for_purge(&self, now: &Timestamp) -> bool {
+        self.purge_after()
+            .is_some_and(|purge_after| !purge_after.is_after(now))
+    }
+
     // ─────────────────────────────────────────────────────────────────────────
     // Private helpers
     // ─────────────────────────────────────────────────────────────────────────
@@ -251,6 +387,11 @@ impl Session {
     fn ensure_mutable(&self) -> Result<(), DomainError> {
         if self.status.is_mutable() {
             Ok(())
+        } else if self.status.is_deleted() {
+            Err(DomainError::new(
+                ErrorCode::SessionArchived,
+                "Cannot modify a deleted session",
+            ))
         } else {
             Err(DomainError::new(
                 ErrorCode::SessionArchived,
@@ -351,6 +492,25 @@ mod tests {
         assert_eq!(session.description(), Some("New description"));
     }
 
+    // Organization tests
+
+    #[test]
+    fn set_organization_returns_previous() {
+        let mut session = test_session();
+        let org_id = OrganizationId::new();
+
+        assert_eq!(session.set_organization(Some(org_id)).unwrap(), None);
+        assert_eq!(session.organization_id(), Some(&org_id));
+        assert_eq!(session.set_organization(None).unwrap(), Some(org_id));
+    }
+
+    #[test]
+    fn set_organization_fails_when_archived() {
+        let mut session = test_session();
+        session.archive().unwrap();
+        assert!(session.set_organization(Some(OrganizationId::new())).is_err());
+    }
+
     // Cycle management tests
 
     #[test]
@@ -394,6 +554,79 @@ mod tests {
         assert!(result.is_err());
     }
 
+    // Trash tests
+
+    #[test]
+    fn delete_moves_session_to_trash() {
+        let mut session = test_session();
+        session.delete().unwrap();
+
+        assert_eq!(session.status(), SessionStatus::Deleted);
+        assert_eq!(
+            session.status_before_deletion(),
+            Some(SessionStatus::Active)
+        );
+        let deleted_at = *session.deleted_at().unwrap();
+        assert_eq!(
+            session.purge_after(),
+            Some(deleted_at.add_days(TRASH_RETENTION_DAYS))
+        );
+    }
+
+    #[test]
+    fn delete_twice_fails() {
+        let mut session = test_session();
+        session.delete().unwrap();
+        assert!(session.delete().is_err());
+    }
+
+    #[test]
+    fn deleted_session_cannot_be_modified_or_archived() {
+        let mut session = test_session();
+        session.delete().unwrap();
+        assert!(session.rename("New Title".to_string()).is_err());
+        assert!(session.archive().is_err());
+    }
+
+    #[test]
+    fn restore_returns_to_previous_status() {
+        let mut session = test_session();
+        session.archive().unwrap();
+        session.delete().unwrap();
+
+        assert_eq!(session.restore().unwrap(), SessionStatus::Archived);
+        assert_eq!(session.status(), SessionStatus::Archived);
+        assert!(session.deleted_at().is_none());
+        assert!(session.status_before_deletion().is_none());
+    }
+
+    #[test]
+    fn restore_fails_when_not_deleted() {
+        let mut session = test_session();
+        assert!(session.restore().is_err());
+    }
+
+    #[test]
+    fn with_deletion_reconstitutes_trashed_session() {
+        let deleted_at = Timestamp::now().minus_days(TRASH_RETENTION_DAYS + 1);
+        let session = test_session().with_deletion(deleted_at, SessionStatus::Archived);
+
+        assert_eq!(session.status(), SessionStatus::Deleted);
+        assert_eq!(
+            session.status_before_deletion(),
+            Some(SessionStatus::Archived)
+        );
+        assert!(session.is_due_for_purge(&Timestamp::now()));
+    }
+
+    #[test]
+    fn recently_deleted_session_is_not_due_for_purge() {
+        let mut session = test_session();
+        session.delete().unwrap();
+        assert!(!session.is_due_for_purge(&Timestamp::now()));
+        assert!(!test_session().is_due_for_purge(&Timestamp::now()));
+    }
+
     // Authorization tests
 
     #[test]
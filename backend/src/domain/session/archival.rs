@@ -0,0 +1,304 @@
+//! Automatic archival of idle sessions.
+//!
+//! Each membership tier may archive sessions nobody has touched for a number
+//! of days. The owner is warned `warning_days` before that happens, and the
+//! session is only archived once a warning issued after its last activity
+//! has been outstanding for the full warning period, so a missed poll or a
+//! policy change never archives without notice. Any activity resets the
+//! clock, and owners can exclude individual sessions altogether.
+
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::domain::foundation::{SessionId, Timestamp, UserId};
+use crate::domain::membership::MembershipTier;
+
+/// Default idle period before free-tier sessions are archived.
+pub const DEFAULT_FREE_IDLE_DAYS: u32 = 180;
+
+/// Default idle period before monthly-tier sessions are archived.
+pub const DEFAULT_MONTHLY_IDLE_DAYS: u32 = 365;
+
+/// Default notice given before archiving.
+pub const DEFAULT_ARCHIVE_WARNING_DAYS: u32 = 14;
+
+/// Per-tier auto-archive rules.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ArchivalPolicy {
+    idle_days: HashMap<MembershipTier, u32>,
+    warning_days: u32,
+}
+
+/// What the policy wants done with a session right now.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ArchivalDecision {
+    /// Nothing to do yet.
+    Keep,
+    /// Warn the owner that the session will be archived on `archive_on`.
+    Warn { archive_on: Timestamp },
+    /// Archive the session.
+    Archive,
+}
+
+impl ArchivalPolicy {
+    /// A policy that never archives anything.
+    pub fn disabled() -> Self {
+        Self {
+            idle_days: HashMap::new(),
+            warning_days: DEFAULT_ARCHIVE_WARNING_DAYS,
+        }
+    }
+
+    /// Archives `tier` sessions after `days` without activity.
+    pub fn with_idle_days(mut self, tier: MembershipTier, days: u32) -> Self {
+        self.idle_days.insert(tier, days);
+        self
+    }
+
+    /// Never archives `tier` sessions.
+    pub fn without_tier(mut self, tier: MembershipTier) -> Self {
+        self.idle_days.remove(&tier);
+        self
+    }
+
+    /// Warns owners `days` before archiving.
+    pub fn with_warning_days(mut self, days: u32) -> Self {
+        self.warning_days = days;
+        self
+    }
+
+    /// Idle days after which `tier` sessions are archived, if ever.
+    pub fn idle_days(&self, tier: MembershipTier) -> Option<u32> {
+        self.idle_days.get(&tier).copied()
+    }
+
+    pub fn warning_days(&self) -> u32 {
+        self.warning_days
+    }
+
+    /// Whether any tier archives sessions.
+    pub fn is_enabled(&self) -> bool {
+        !self.idle_days.is_empty()
+    }
+
+    /// Decides what to do with a session last active at `last_activity`.
+    pub fn evaluate(
+        &self,
+        tier: MembershipTier,
+        last_activity: Timestamp,
+        settings: &SessionArchivalSettings,
+        now: Timestamp,
+    ) -> ArchivalDecision {
+        let Some(idle_days) = self.idle_days(tier) else {
+            return ArchivalDecision::Keep;
+        };
+        if settings.excluded || settings.archived_at.is_some() {
+            return ArchivalDecision::Keep;
+        }
+
+        let archive_at = last_activity.plus_days(i64::from(idle_days));
+        let warn_at =
+            last_activity.plus_days(i64::from(idle_days.saturating_sub(self.warning_days)));
+        match settings.warning_since(&last_activity) {
+            None if !now.is_before(&warn_at) => ArchivalDecision::Warn {
+                archive_on: archive_at.max(now.plus_days(i64::from(self.warning_days))),
+            },
+            None => ArchivalDecision::Keep,
+            Some(warned_at)
+                if !now.is_before(&archive_at)
+                    && !now.is_before(&warned_at.plus_days(i64::from(self.warning_days))) =>
+            {
+                ArchivalDecision::Archive
+            }
+            Some(_) => ArchivalDecision::Keep,
+        }
+    }
+}
+
+impl Default for ArchivalPolicy {
+    /// Free sessions after 180 idle days, monthly after a year, annual never.
+    fn default() -> Self {
+        Self::disabled()
+            .with_idle_days(MembershipTier::Free, DEFAULT_FREE_IDLE_DAYS)
+            .with_idle_days(MembershipTier::Monthly, DEFAULT_MONTHLY_IDLE_DAYS)
+    }
+}
+
+/// Per-session auto-archive state.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SessionArchivalSettings {
+    pub session_id: SessionId,
+    pub user_id: UserId,
+    /// The owner opted this session out of auto-archive.
+    pub excluded: bool,
+    /// Where archive warnings are emailed; recorded from the owner's account.
+    pub notify_email: Option<String>,
+    /// Most recent archive warning.
+    pub warned_at: Option<Timestamp>,
+    /// When the policy archived the session.
+    pub archived_at: Option<Timestamp>,
+}
+
+impl SessionArchivalSettings {
+    /// Settings for a session nobody has configured: included, never warned.
+    pub fn new(session_id: SessionId, user_id: UserId) -> Self {
+        Self {
+            session_id,
+            user_id,
+            excluded: false,
+            notify_email: None,
+            warned_at: None,
+            archived_at: None,
+        }
+    }
+
+    /// The warning still in force for a session last active at
+    /// `last_activity`; activity after a warning cancels it.
+    pub fn warning_since(&self, last_activity: &Timestamp) -> Option<Timestamp> {
+        self.warned_at
+            .filter(|warned_at| !warned_at.is_before(last_activity))
+    }
+
+    pub fn record_warning(&mut self, at: Timestamp) {
+        self.warned_at = Some(at);
+    }
+
+    pub fn record_archived(&mut self, at: Timestamp) {
+        self.archived_at = Some(at);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn settings() -> SessionArchivalSettings {
+        SessionArchivalSettings::new(SessionId::new(), UserId::new("user-1").unwrap())
+    }
+
+    fn days_ago(days: i64) -> Timestamp {
+        Timestamp::now().minus_days(days)
+    }
+
+    #[test]
+    fn default_policy_spares_annual_members() {
+        let policy = ArchivalPolicy::default();
+        assert_eq!(policy.idle_days(MembershipTier::Free), Some(180));
+        assert_eq!(policy.idle_days(MembershipTier::Monthly), Some(365));
+        assert_eq!(policy.idle_days(MembershipTier::Annual), None);
+        assert_eq!(
+            policy.evaluate(
+                MembershipTier::Annual,
+                days_ago(1000),
+                &settings(),
+                Timestamp::now()
+            ),
+            ArchivalDecision::Keep
+        );
+        assert!(!ArchivalPolicy::disabled().is_enabled());
+    }
+
+    #[test]
+    fn warns_before_the_idle_period_ends() {
+        let policy = ArchivalPolicy::default();
+        let now = Timestamp::now();
+        let last_activity = now.minus_days(170);
+
+        assert_eq!(
+            policy.evaluate(MembershipTier::Free, now.minus_days(160), &settings(), now),
+            ArchivalDecision::Keep
+        );
+        assert_eq!(
+            policy.evaluate(MembershipTier::Free, last_activity, &settings(), now),
+            ArchivalDecision::Warn {
+                archive_on: now.plus_days(14)
+            }
+        );
+    }
+
+    #[test]
+    fn archives_only_after_a_full_warning_period() {
+        let policy = ArchivalPolicy::default();
+        let now = Timestamp::now();
+        let mut warned = settings();
+
+        // Long idle but never warned: warn first, never archive outright
+        assert!(matches!(
+            policy.evaluate(MembershipTier::Free, days_ago(400), &warned, now),
+            ArchivalDecision::Warn { .. }
+        ));
+
+        warned.record_warning(now.minus_days(13));
+        assert_eq!(
+            policy.evaluate(MembershipTier::Free, days_ago(400), &warned, now),
+            ArchivalDecision::Keep
+        );
+
+        warned.record_warning(now.minus_days(14));
+        assert_eq!(
+            policy.evaluate(MembershipTier::Free, days_ago(400), &warned, now),
+            ArchivalDecision::Archive
+        );
+    }
+
+    #[test]
+    fn activity_after_a_warning_cancels_it() {
+        let policy = ArchivalPolicy::default();
+        let now = Timestamp::now();
+        let mut warned = settings();
+        warned.record_warning(now.minus_days(200));
+
+        assert_eq!(warned.warning_since(&now.minus_days(190)), None);
+        assert!(matches!(
+            policy.evaluate(MembershipTier::Free, now.minus_days(190), &warned, now),
+            ArchivalDecision::Warn { .. }
+        ));
+    }
+
+    #[test]
+    fn excluded_and_already_archived_sessions_are_kept() {
+        let policy = ArchivalPolicy::default();
+        let now = Timestamp::now();
+
+        let mut excluded = settings();
+        excluded.excluded = true;
+        excluded.record_warning(now.minus_days(30));
+        assert_eq!(
+            policy.evaluate(MembershipTier::Free, days_ago(400), &excluded, now),
+            ArchivalDecision::Keep
+        );
+
+        let mut archived = settings();
+        archived.record_archived(now);
+        assert_eq!(
+            policy.evaluate(MembershipTier::Free, days_ago(400), &archived, now),
+            ArchivalDecision::Keep
+        );
+    }
+
+    #[test]
+    fn tiers_can_be_reconfigured() {
+        let policy = ArchivalPolicy::default()
+            .with_idle_days(MembershipTier::Annual, 730)
+            .without_tier(MembershipTier::Free)
+            .with_warning_days(30);
+        let now = Timestamp::now();
+
+        assert_eq!(
+            policy.evaluate(MembershipTier::Free, days_ago(400), &settings(), now),
+            ArchivalDecision::Keep
+        );
+        assert_eq!(
+            policy.evaluate(
+                MembershipTier::Annual,
+                now.minus_days(700),
+                &settings(),
+                now
+            ),
+            ArchivalDecision::Warn {
+                archive_on: now.plus_days(30)
+            }
+        );
+    }
+}
@@ -0,0 +1,276 @@
+//! Cold storage of finished sessions.
+//!
+//! Archived sessions whose cycles are all finished are rarely opened again,
+//! yet their cycles and components make up most of the hot database. After
+//! a configurable number of months those cycles are serialized into one
+//! [`ColdSessionSnapshot`] in the document store and removed from the
+//! database. The session row stays, together with a [`ColdStorageRecord`]
+//! saying where the snapshot lives, so lists still show the session and any
+//! later access can bring its cycles back.
+
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::domain::cycle::{BranchMetadata, Cycle};
+use crate::domain::foundation::{
+    ComponentId, ComponentStatus, ComponentType, CycleId, CycleStatus, DomainError, SessionId,
+    Timestamp, UserId,
+};
+use crate::domain::proact::ComponentVariant;
+
+/// Format version written into snapshots.
+pub const COLD_SNAPSHOT_FORMAT_VERSION: u32 = 1;
+
+/// When finished sessions move to cold storage.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ColdStoragePolicy {
+    /// Months after archival; 0 never offloads.
+    after_months: u32,
+}
+
+impl ColdStoragePolicy {
+    /// Offloads sessions archived at least `months` ago.
+    pub fn after_months(months: u32) -> Self {
+        Self {
+            after_months: months,
+        }
+    }
+
+    /// A policy that never offloads anything.
+    pub fn disabled() -> Self {
+        Self::after_months(0)
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.after_months > 0
+    }
+
+    /// Sessions archived before this are due at `now`.
+    pub fn archived_before(&self, now: Timestamp) -> Timestamp {
+        now.add_months(-i64::from(self.after_months))
+    }
+}
+
+/// Where a cold session's snapshot lives.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ColdStorageRecord {
+    pub session_id: SessionId,
+    pub user_id: UserId,
+    /// Document storage key of the snapshot.
+    pub blob_key: String,
+    /// Cycles held in the snapshot, so access by cycle can find it.
+    pub cycle_ids: Vec<CycleId>,
+    pub offloaded_at: Timestamp,
+}
+
+impl ColdStorageRecord {
+    pub fn new(snapshot: &ColdSessionSnapshot, user_id: UserId) -> Self {
+        Self {
+            session_id: snapshot.session_id,
+            user_id,
+            blob_key: Self::blob_key_for(&snapshot.session_id),
+            cycle_ids: snapshot.cycles.iter().map(|c| c.id).collect(),
+            offloaded_at: snapshot.offloaded_at,
+        }
+    }
+
+    /// Document storage key for a session's snapshot.
+    pub fn blob_key_for(session_id: &SessionId) -> String {
+        format!("cold-storage/sessions/{}.json", session_id)
+    }
+}
+
+/// Everything removed from the database when a session goes cold.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ColdSessionSnapshot {
+    pub format_version: u32,
+    pub session_id: SessionId,
+    /// Parents before their branches, so restoring in order keeps
+    /// references valid.
+    pub cycles: Vec<CycleSnapshot>,
+    pub offloaded_at: Timestamp,
+}
+
+/// One cycle with its components.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CycleSnapshot {
+    pub id: CycleId,
+    pub parent_cycle_id: Option<CycleId>,
+    pub branch_point: Option<ComponentType>,
+    pub branch_metadata: BranchMetadata,
+    pub status: CycleStatus,
+    pub current_step: ComponentType,
+    pub components: Vec<ComponentSnapshot>,
+    pub created_at: Timestamp,
+    pub updated_at: Timestamp,
+    pub version: u64,
+}
+
+/// One component's state and output.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ComponentSnapshot {
+    pub id: ComponentId,
+    pub component_type: ComponentType,
+    pub status: ComponentStatus,
+    pub output: serde_json::Value,
+    pub created_at: Timestamp,
+    pub updated_at: Timestamp,
+}
+
+impl ColdSessionSnapshot {
+    /// Snapshots a session's cycles, oldest first.
+    pub fn new(session_id: SessionId, cycles: &[Cycle]) -> Self {
+        let mut cycles: Vec<&Cycle> = cycles.iter().collect();
+        cycles.sort_by_key(|c| (c.created_at(), c.is_branch()));
+        Self {
+            format_version: COLD_SNAPSHOT_FORMAT_VERSION,
+            session_id,
+            cycles: cycles.into_iter().map(CycleSnapshot::from).collect(),
+            offloaded_at: Timestamp::now(),
+        }
+    }
+
+    /// Rebuilds the cycles, parents first.
+    pub fn into_cycles(self) -> Result<Vec<Cycle>, DomainError> {
+        let session_id = self.session_id;
+        self.cycles
+            .into_iter()
+            .map(|cycle| cycle.into_cycle(session_id))
+            .collect()
+    }
+}
+
+impl From<&Cycle> for CycleSnapshot {
+    fn from(cycle: &Cycle) -> Self {
+        Self {
+            id: cycle.id(),
+            parent_cycle_id: cycle.parent_cycle_id(),
+            branch_point: cycle.branch_point(),
+            branch_metadata: cycle.branch_metadata().clone(),
+            status: cycle.status(),
+            current_step: cycle.current_step(),
+            components: ComponentType::all()
+                .iter()
+                .filter_map(|ct| cycle.component(*ct))
+                .map(|component| ComponentSnapshot {
+                    id: component.id(),
+                    component_type: component.component_type(),
+                    status: component.status(),
+                    output: component.output_as_value(),
+                    created_at: component.created_at(),
+                    updated_at: component.updated_at(),
+                })
+                .collect(),
+            created_at: cycle.created_at(),
+            updated_at: cycle.updated_at(),
+            version: cycle.version(),
+        }
+    }
+}
+
+impl CycleSnapshot {
+    fn into_cycle(self, session_id: SessionId) -> Result<Cycle, DomainError> {
+        let mut components = self
+            .components
+            .into_iter()
+            .map(|c| {
+                let component = ComponentVariant::reconstitute(
+                    c.id,
+                    c.component_type,
+                    c.status,
+                    c.output,
+                    c.created_at,
+                    c.updated_at,
+                )?;
+                Ok((c.component_type, component))
+            })
+            .collect::<Result<HashMap<_, _>, DomainError>>()?;
+        // Snapshots taken before a component joined the sequence lack it;
+        // it comes back not started, as the migrations backfill stored cycles
+        for component_type in ComponentType::all() {
+            components
+                .entry(*component_type)
+                .or_insert_with(|| ComponentVariant::new(*component_type));
+        }
+        Cycle::reconstitute(
+            self.id,
+            session_id,
+            self.parent_cycle_id,
+            self.branch_point,
+            self.branch_metadata,
+            self.status,
+            self.current_step,
+            components,
+            self.created_at,
+            self.updated_at,
+            self.version,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn disabled_policy_never_offloads() {
+        assert!(!ColdStoragePolicy::disabled().is_enabled());
+        assert!(ColdStoragePolicy::after_months(12).is_enabled());
+    }
+
+    #[test]
+    fn cutoff_is_months_before_now() {
+        let now = Timestamp::from_unix_secs(400 * 86_400);
+        let cutoff = ColdStoragePolicy::after_months(6).archived_before(now);
+        assert_eq!(cutoff, Timestamp::from_unix_secs(220 * 86_400));
+    }
+
+    #[test]
+    fn snapshot_round_trips_cycles_through_json() {
+        let session_id = SessionId::new();
+        let mut root = Cycle::new(session_id);
+        root.start_component(ComponentType::IssueRaising).unwrap();
+        let branch = root.branch_at(ComponentType::IssueRaising, None).unwrap();
+
+        let snapshot = ColdSessionSnapshot::new(session_id, &[branch.clone(), root.clone()]);
+        let json = serde_json::to_string(&snapshot).unwrap();
+        let restored: ColdSessionSnapshot = serde_json::from_str(&json).unwrap();
+        let record = ColdStorageRecord::new(&restored, UserId::new("user-1").unwrap());
+        let cycles = restored.into_cycles().unwrap();
+
+        assert_eq!(record.cycle_ids, vec![root.id(), branch.id()]);
+        assert_eq!(cycles.len(), 2);
+        assert_eq!(cycles[0].id(), root.id());
+        assert_eq!(cycles[1].parent_cycle_id(), Some(root.id()));
+        assert_eq!(
+            cycles[0].component_status(ComponentType::IssueRaising),
+            ComponentStatus::InProgress
+        );
+        assert_eq!(cycles[0].version(), root.version());
+    }
+
+    #[test]
+    fn snapshots_missing_a_newer_component_restore_it_not_started() {
+        let session_id = SessionId::new();
+        let mut snapshot = ColdSessionSnapshot::new(session_id, &[Cycle::new(session_id)]);
+        snapshot.cycles[0]
+            .components
+            .retain(|c| c.component_type != ComponentType::StakeholderAnalysis);
+
+        let mut cycle = snapshot.into_cycles().unwrap().remove(0);
+
+        assert_eq!(
+            cycle.component_status(ComponentType::StakeholderAnalysis),
+            ComponentStatus::NotStarted
+        );
+        for component_type in [
+            ComponentType::IssueRaising,
+            ComponentType::ProblemFrame,
+            ComponentType::StakeholderAnalysis,
+            ComponentType::Objectives,
+        ] {
+            cycle.start_component(component_type).unwrap();
+        }
+    }
+}
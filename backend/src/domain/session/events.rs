@@ -5,12 +5,17 @@
 //! - `SessionRenamed` - Session title changed
 //! - `SessionDescriptionUpdated` - Session description changed
 //! - `SessionArchived` - Session archived (soft delete)
+//! - `SessionDeleted` - Session moved to the trash
+//! - `SessionRestored` - Session taken back out of the trash
+//! - `SessionPurged` - Session permanently removed from the trash
+//! - `SessionArchiveWarningIssued` - Idle session will be auto-archived soon
+//! - `SessionAutoArchiveExclusionChanged` - Session opted in or out of auto-archive
 //! - `CycleAddedToSession` - Cycle linked to session
 
 use serde::{Deserialize, Serialize};
 
 use crate::domain::foundation::{
-    domain_event, CycleId, EventId, SessionId, Timestamp, UserId,
+    domain_event, CycleId, EventId, SessionId, SessionStatus, Timestamp, UserId,
 };
 
 // ════════════════════════════════════════════════════════════════════════════
@@ -142,9 +147,13 @@ pub struct SessionArchived {
     /// ID of the archived session.
     pub session_id: SessionId,
 
-    /// User who archived the session.
+    /// User who archived the session; the owner when archived automatically.
     pub user_id: UserId,
 
+    /// Whether the auto-archive policy archived it rather than the user.
+    #[serde(default)]
+    pub automatic: bool,
+
     /// When the session was archived.
     pub archived_at: Timestamp,
 }
@@ -159,6 +168,180 @@ domain_event!(
     event_id = event_id
 );
 
+// ════════════════════════════════════════════════════════════════════════════
+// SessionDeleted
+// ════════════════════════════════════════════════════════════════════════════
+
+/// Published when a session is moved to the trash.
+///
+/// The session can be restored until `purge_after`, when it is purged.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SessionDeleted {
+    /// Unique identifier for this event.
+    pub event_id: EventId,
+
+    /// ID of the deleted session.
+    pub session_id: SessionId,
+
+    /// User who deleted the session.
+    pub user_id: UserId,
+
+    /// When the session will be purged unless restored.
+    pub purge_after: Timestamp,
+
+    /// When the session was deleted.
+    pub deleted_at: Timestamp,
+}
+
+domain_event!(
+    SessionDeleted,
+    event_type = "session.deleted.v1",
+    schema_version = 1,
+    aggregate_id = session_id,
+    aggregate_type = "Session",
+    occurred_at = deleted_at,
+    event_id = event_id
+);
+
+// ════════════════════════════════════════════════════════════════════════════
+// SessionRestored
+// ════════════════════════════════════════════════════════════════════════════
+
+/// Published when a session is taken back out of the trash.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SessionRestored {
+    /// Unique identifier for this event.
+    pub event_id: EventId,
+
+    /// ID of the restored session.
+    pub session_id: SessionId,
+
+    /// User who restored the session.
+    pub user_id: UserId,
+
+    /// Status the session was restored to.
+    pub status: SessionStatus,
+
+    /// When the session was restored.
+    pub restored_at: Timestamp,
+}
+
+domain_event!(
+    SessionRestored,
+    event_type = "session.restored.v1",
+    schema_version = 1,
+    aggregate_id = session_id,
+    aggregate_type = "Session",
+    occurred_at = restored_at,
+    event_id = event_id
+);
+
+// ════════════════════════════════════════════════════════════════════════════
+// SessionPurged
+// ════════════════════════════════════════════════════════════════════════════
+
+/// Published when a deleted session and its cycles are removed for good.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SessionPurged {
+    /// Unique identifier for this event.
+    pub event_id: EventId,
+
+    /// ID of the purged session.
+    pub session_id: SessionId,
+
+    /// User who purged the session; the owner when purged automatically.
+    pub user_id: UserId,
+
+    /// Whether the retention window ran out rather than the user emptying it.
+    #[serde(default)]
+    pub automatic: bool,
+
+    /// When the session was purged.
+    pub purged_at: Timestamp,
+}
+
+domain_event!(
+    SessionPurged,
+    event_type = "session.purged.v1",
+    schema_version = 1,
+    aggregate_id = session_id,
+    aggregate_type = "Session",
+    occurred_at = purged_at,
+    event_id = event_id
+);
+
+// ════════════════════════════════════════════════════════════════════════════
+// SessionArchiveWarningIssued
+// ════════════════════════════════════════════════════════════════════════════
+
+/// Published when an idle session is about to be archived automatically.
+///
+/// Any activity on the session before `archive_on` cancels the archival.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SessionArchiveWarningIssued {
+    /// Unique identifier for this event.
+    pub event_id: EventId,
+
+    /// ID of the idle session.
+    pub session_id: SessionId,
+
+    /// Owner of the session.
+    pub user_id: UserId,
+
+    /// Idle days after which the owner's tier archives sessions.
+    pub idle_days: u32,
+
+    /// Earliest time the session will be archived.
+    pub archive_on: Timestamp,
+
+    /// When the warning was issued.
+    pub issued_at: Timestamp,
+}
+
+domain_event!(
+    SessionArchiveWarningIssued,
+    event_type = "session.archive_warning_issued.v1",
+    schema_version = 1,
+    aggregate_id = session_id,
+    aggregate_type = "Session",
+    occurred_at = issued_at,
+    event_id = event_id
+);
+
+// ════════════════════════════════════════════════════════════════════════════
+// SessionAutoArchiveExclusionChanged
+// ════════════════════════════════════════════════════════════════════════════
+
+/// Published when the owner excludes a session from auto-archive, or
+/// includes it again.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SessionAutoArchiveExclusionChanged {
+    /// Unique identifier for this event.
+    pub event_id: EventId,
+
+    /// ID of the session.
+    pub session_id: SessionId,
+
+    /// User who changed the setting.
+    pub user_id: UserId,
+
+    /// Whether the session is now excluded.
+    pub excluded: bool,
+
+    /// When the setting changed.
+    pub changed_at: Timestamp,
+}
+
+domain_event!(
+    SessionAutoArchiveExclusionChanged,
+    event_type = "session.auto_archive_exclusion_changed.v1",
+    schema_version = 1,
+    aggregate_id = session_id,
+    aggregate_type = "Session",
+    occurred_at = changed_at,
+    event_id = event_id
+);
+
 // ════════════════════════════════════════════════════════════════════════════
 // CycleAddedToSession
 // ════════════════════════════════════════════════════════════════════════════
@@ -339,6 +522,7 @@ mod tests {
             event_id: EventId::new(),
             session_id: SessionId::new(),
             user_id: UserId::new("user-1").unwrap(),
+            automatic: false,
             archived_at: Timestamp::now(),
         };
 
@@ -352,6 +536,7 @@ mod tests {
             event_id: EventId::from_string("evt-archive"),
             session_id: SessionId::new(),
             user_id: UserId::new("user-1").unwrap(),
+            automatic: false,
             archived_at: Timestamp::now(),
         };
 
@@ -361,6 +546,85 @@ mod tests {
         assert_eq!(restored.event_id.as_str(), "evt-archive");
     }
 
+    #[test]
+    fn session_archived_defaults_to_manual() {
+        let json = serde_json::json!({
+            "event_id": "evt-archive",
+            "session_id": SessionId::new(),
+            "user_id": "user-1",
+            "archived_at": Timestamp::now(),
+        });
+
+        let restored: SessionArchived = serde_json::from_value(json).unwrap();
+
+        assert!(!restored.automatic);
+    }
+
+    // ────────────────────────────────────────────────────────────────────────
+    // Trash Tests
+    // ────────────────────────────────────────────────────────────────────────
+
+    #[test]
+    fn trash_events_implement_domain_event() {
+        let session_id = SessionId::new();
+        let user_id = UserId::new("user-1").unwrap();
+        let deleted = SessionDeleted {
+            event_id: EventId::new(),
+            session_id,
+            user_id: user_id.clone(),
+            purge_after: Timestamp::now().plus_days(30),
+            deleted_at: Timestamp::now(),
+        };
+        let restored = SessionRestored {
+            event_id: EventId::new(),
+            session_id,
+            user_id: user_id.clone(),
+            status: SessionStatus::Archived,
+            restored_at: Timestamp::now(),
+        };
+        let purged = SessionPurged {
+            event_id: EventId::new(),
+            session_id,
+            user_id,
+            automatic: true,
+            purged_at: Timestamp::now(),
+        };
+
+        assert_eq!(deleted.event_type(), "session.deleted.v1");
+        assert_eq!(restored.event_type(), "session.restored.v1");
+        assert_eq!(purged.event_type(), "session.purged.v1");
+        assert_eq!(purged.aggregate_id(), session_id.to_string());
+    }
+
+    #[test]
+    fn session_purged_defaults_to_manual() {
+        let json = serde_json::json!({
+            "event_id": "evt-purge",
+            "session_id": SessionId::new(),
+            "user_id": "user-1",
+            "purged_at": Timestamp::now(),
+        });
+
+        let purged: SessionPurged = serde_json::from_value(json).unwrap();
+
+        assert!(!purged.automatic);
+    }
+
+    #[test]
+    fn archive_warning_implements_domain_event() {
+        let event = SessionArchiveWarningIssued {
+            event_id: EventId::new(),
+            session_id: SessionId::new(),
+            user_id: UserId::new("user-1").unwrap(),
+            idle_days: 180,
+            archive_on: Timestamp::now().plus_days(14),
+            issued_at: Timestamp::now(),
+        };
+
+        assert_eq!(event.event_type(), "session.archive_warning_issued.v1");
+        assert_eq!(event.to_envelope().aggregate_type, "Session");
+    }
+
     // ────────────────────────────────────────────────────────────────────────
     // CycleAddedToSession Tests
     // ────────────────────────────────────────────────────────────────────────
@@ -461,6 +725,7 @@ mod tests {
             event_id: EventId::new(),
             session_id,
             user_id: user_id.clone(),
+            automatic: false,
             archived_at: Timestamp::now(),
         };
 
@@ -1,27 +1,55 @@
 //! Session domain module.
 //!
 //! Handles decision session lifecycle including creation, modification,
-//! and archival. Sessions are the top-level containers for decision contexts.
+//! archival and the trash. Sessions are the top-level containers for
+//! decision contexts.
 //!
 //! # Aggregate
 //!
 //! - `Session` - The session aggregate entity
 //!
+//! # Auto-archive
+//!
+//! - `ArchivalPolicy` - Per-tier idle periods and warning notice
+//! - `SessionArchivalSettings` - Per-session exclusion and warning state
+//!
+//! # Cold storage
+//!
+//! - `ColdStoragePolicy` - How long after archival finished sessions are offloaded
+//! - `ColdSessionSnapshot` - A session's cycles, serialized to the document store
+//! - `ColdStorageRecord` - Where an offloaded session's snapshot lives
+//!
 //! # Events
 //!
 //! - `SessionCreated` - Published when a new session is created
 //! - `SessionRenamed` - Published when a session's title changes
 //! - `SessionDescriptionUpdated` - Published when description changes
 //! - `SessionArchived` - Published when a session is archived
+//! - `SessionDeleted` - Published when a session is moved to the trash
+//! - `SessionRestored` - Published when a session is restored from the trash
+//! - `SessionPurged` - Published when a deleted session is removed for good
+//! - `SessionArchiveWarningIssued` - Published before an idle session is auto-archived
+//! - `SessionAutoArchiveExclusionChanged` - Published when a session opts out of (or back into) auto-archive
 //! - `CycleAddedToSession` - Published when a cycle is linked to the session
 
 mod aggregate;
+mod archival;
+mod cold_storage;
 mod errors;
 mod events;
 
-pub use aggregate::{Session, MAX_TITLE_LENGTH};
+pub use aggregate::{Session, MAX_TITLE_LENGTH, TRASH_RETENTION_DAYS};
+pub use archival::{
+    ArchivalDecision, ArchivalPolicy, SessionArchivalSettings, DEFAULT_ARCHIVE_WARNING_DAYS,
+    DEFAULT_FREE_IDLE_DAYS, DEFAULT_MONTHLY_IDLE_DAYS,
+};
+pub use cold_storage::{
+    ColdSessionSnapshot, ColdStoragePolicy, ColdStorageRecord, ComponentSnapshot, CycleSnapshot,
+    COLD_SNAPSHOT_FORMAT_VERSION,
+};
 pub use errors::SessionError;
 pub use events::{
-    CycleAddedToSession, SessionArchived, SessionCreated, SessionDescriptionUpdated,
-    SessionRenamed,
+    CycleAddedToSession, SessionArchiveWarningIssued, SessionArchived,
+    SessionAutoArchiveExclusionChanged, SessionCreated, SessionDeleted, SessionDescriptionUpdated,
+    SessionPurged, SessionRenamed, SessionRestored,
 };
@@ -8,3 +8,6 @@ pub mod application;
 pub mod config;
 pub mod domain;
 pub mod ports;
+
+#[cfg(any(test, feature = "test-support"))]
+pub mod test_support;
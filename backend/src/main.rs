@@ -1,3 +1,29 @@
-fn main() {
-    println!("Choice Sherpa - Decision Support Application");
+mod doctor;
+mod seed_demo;
+mod validate_template;
+
+const USAGE: &str = "\
+Usage: choice-sherpa [COMMAND]
+
+Commands:
+  doctor                    Check configuration and connectivity, then print a readiness report
+  validate-template <file>  Check a document template renders in the export sandbox
+  seed-demo <user-id>       Create sample sessions, cycles, and conversations for a user
+  help                      Print this message";
+
+#[tokio::main]
+async fn main() {
+    match std::env::args().nth(1).as_deref() {
+        None => println!("Choice Sherpa - Decision Support Application"),
+        Some("doctor") => std::process::exit(doctor::run().await),
+        Some("validate-template") => {
+            std::process::exit(validate_template::run(std::env::args().nth(2)))
+        }
+        Some("seed-demo") => std::process::exit(seed_demo::run(std::env::args().nth(2)).await),
+        Some("help" | "--help" | "-h") => println!("{}", USAGE),
+        Some(other) => {
+            eprintln!("Unknown command: {}\n\n{}", other, USAGE);
+            std::process::exit(2);
+        }
+    }
 }
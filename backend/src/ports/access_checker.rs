@@ -8,6 +8,10 @@
 //! The AccessChecker follows a **fail-secure** design: on ANY error, access is denied.
 //! Users without membership get zero access (no implicit free tier).
 //!
+//! A user's membership is either their own or the billing membership of an
+//! organization they belong to; implementations use whichever grants access
+//! at the highest tier.
+//!
 //! # Example
 //!
 //! ```ignore
@@ -114,6 +118,10 @@ pub enum AccessDeniedReason {
     /// User's payment is past due (outside grace period).
     MembershipPastDue,
 
+    /// User's access comes from an organization whose paid seats are all
+    /// taken by members who joined earlier.
+    NoSeatAvailable,
+
     /// Maximum number of sessions reached for tier.
     SessionLimitReached {
         /// Current number of active sessions.
@@ -152,6 +160,10 @@ impl AccessDeniedReason {
             AccessDeniedReason::MembershipPastDue => {
                 "Your payment is past due. Please update your payment method.".to_string()
             }
+            AccessDeniedReason::NoSeatAvailable => {
+                "Your organization has no seat available for you. Ask an admin to add seats."
+                    .to_string()
+            }
             AccessDeniedReason::SessionLimitReached { current, max } => {
                 format!(
                     "You've reached the limit of {} sessions (currently have {}). Upgrade for more.",
@@ -260,6 +272,12 @@ mod tests {
         assert!(reason.user_message().contains("past due"));
     }
 
+    #[test]
+    fn no_seat_message() {
+        let reason = AccessDeniedReason::NoSeatAvailable;
+        assert!(reason.user_message().contains("no seat available"));
+    }
+
     #[test]
     fn session_limit_message_shows_counts() {
         let reason = AccessDeniedReason::SessionLimitReached { current: 3, max: 3 };
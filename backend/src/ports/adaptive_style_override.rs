@@ -0,0 +1,31 @@
+//! AdaptiveStyleOverrideRepository port - Per-conversation opt-out from
+//! profile-driven agent style.
+
+use async_trait::async_trait;
+
+use crate::domain::foundation::{ConversationId, DomainError};
+
+/// Port for conversations where the user turned adaptive style off.
+///
+/// Adaptive style is on by default; only opt-outs need storing.
+#[async_trait]
+pub trait AdaptiveStyleOverrideRepository: Send + Sync {
+    /// Whether adaptive style is turned off for the conversation.
+    async fn is_disabled(&self, conversation_id: &ConversationId) -> Result<bool, DomainError>;
+
+    /// Turn adaptive style off (`true`) or back on (`false`).
+    async fn set_disabled(
+        &self,
+        conversation_id: &ConversationId,
+        disabled: bool,
+    ) -> Result<(), DomainError>;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Compile-time check that trait is object-safe
+    #[allow(dead_code)]
+    fn assert_object_safe(_: &dyn AdaptiveStyleOverrideRepository) {}
+}
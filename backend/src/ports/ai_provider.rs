@@ -8,6 +8,11 @@
 //! - Supports both streaming and non-streaming completions
 //! - Provider-agnostic message format
 //! - Built-in token usage and cost tracking
+//! - Optional batch submission for non-interactive work, billed at batch
+//!   rates and collected later by polling [`AIProvider::batch_status`]
+//! - Tool calls streamed as incremental deltas, reassembled with
+//!   [`ToolCallAssembler`] so each call can run as soon as its arguments
+//!   are complete
 //! - Error types for common failure modes (rate limits, context too long, etc.)
 //!
 //! # Example
@@ -34,10 +39,12 @@
 use async_trait::async_trait;
 use futures::Stream;
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 use std::pin::Pin;
 
+use crate::domain::conversation::tools::{ToolCall, ToolDefinition};
 use crate::domain::foundation::{
-    ComponentType, ConversationId, SessionId, UserId,
+    ComponentType, ConversationId, CycleId, SessionId, UserId,
 };
 
 /// Port for AI/LLM provider interactions.
@@ -67,6 +74,32 @@ pub trait AIProvider: Send + Sync {
 
     /// Get provider information (name, model, capabilities).
     fn provider_info(&self) -> ProviderInfo;
+
+    /// Submit requests for asynchronous processing at batch pricing.
+    ///
+    /// Batches can take up to 24 hours; poll [`batch_status`](Self::batch_status)
+    /// until it is finished, then fetch the results. Providers without batch
+    /// support (see `ProviderInfo::supports_batch`) fail with `InvalidRequest`.
+    async fn submit_batch(&self, requests: Vec<BatchRequest>) -> Result<BatchJob, AIError> {
+        let _ = requests;
+        Err(batch_unsupported(&self.provider_info()))
+    }
+
+    /// Current state of a submitted batch.
+    async fn batch_status(&self, batch_id: &str) -> Result<BatchJob, AIError> {
+        let _ = batch_id;
+        Err(batch_unsupported(&self.provider_info()))
+    }
+
+    /// Results of a finished batch, one per request that produced an outcome.
+    async fn batch_results(&self, batch_id: &str) -> Result<Vec<BatchResult>, AIError> {
+        let _ = batch_id;
+        Err(batch_unsupported(&self.provider_info()))
+    }
+}
+
+fn batch_unsupported(info: &ProviderInfo) -> AIError {
+    AIError::InvalidRequest(format!("{} does not support batch completions", info.name))
 }
 
 /// Request for AI completion.
@@ -82,6 +115,8 @@ pub struct CompletionRequest {
     pub temperature: Option<f32>,
     /// Component type for prompt templating.
     pub component_type: Option<ComponentType>,
+    /// Tools the model may call. Providers without tool support ignore them.
+    pub tools: Vec<ToolDefinition>,
     /// Request metadata for tracing and billing.
     pub metadata: RequestMetadata,
 }
@@ -95,6 +130,7 @@ impl CompletionRequest {
             max_tokens: None,
             temperature: None,
             component_type: None,
+            tools: Vec::new(),
             metadata,
         }
     }
@@ -131,6 +167,12 @@ impl CompletionRequest {
         self.component_type = Some(component_type);
         self
     }
+
+    /// Offers tools the model may call.
+    pub fn with_tools(mut self, tools: Vec<ToolDefinition>) -> Self {
+        self.tools = tools;
+        self
+    }
 }
 
 /// A message in the conversation.
@@ -190,6 +232,10 @@ pub struct RequestMetadata {
     pub conversation_id: ConversationId,
     /// Trace ID for distributed tracing.
     pub trace_id: String,
+    /// Cycle the request is made for, used for per-cycle cost rollups.
+    pub cycle_id: Option<CycleId>,
+    /// Skip response caches and always ask the model (e.g. regeneration).
+    pub bypass_cache: bool,
 }
 
 impl RequestMetadata {
@@ -205,8 +251,22 @@ impl RequestMetadata {
             session_id,
             conversation_id,
             trace_id: trace_id.into(),
+            cycle_id: None,
+            bypass_cache: false,
         }
     }
+
+    /// Attributes the request to a cycle.
+    pub fn with_cycle_id(mut self, cycle_id: CycleId) -> Self {
+        self.cycle_id = Some(cycle_id);
+        self
+    }
+
+    /// Asks for a fresh completion even if an identical one is cached.
+    pub fn with_cache_bypass(mut self) -> Self {
+        self.bypass_cache = true;
+        self
+    }
 }
 
 /// Response from AI completion.
@@ -246,6 +306,12 @@ impl TokenUsage {
         }
     }
 
+    /// The same usage billed at batch rates, half the interactive price.
+    pub fn at_batch_price(mut self) -> Self {
+        self.estimated_cost_cents /= 2;
+        self
+    }
+
     /// Creates zero usage.
     pub fn zero() -> Self {
         Self::default()
@@ -262,6 +328,8 @@ pub enum FinishReason {
     Length,
     /// Content was filtered for safety.
     ContentFilter,
+    /// The model stopped to call tools.
+    ToolUse,
     /// An error occurred.
     Error,
 }
@@ -275,6 +343,8 @@ pub struct StreamChunk {
     pub finish_reason: Option<FinishReason>,
     /// Token usage (only present on final chunk).
     pub usage: Option<TokenUsage>,
+    /// Piece of a tool call, if the model is calling a tool.
+    pub tool_call: Option<ToolCallDelta>,
 }
 
 impl StreamChunk {
@@ -284,6 +354,17 @@ impl StreamChunk {
             delta: delta.into(),
             finish_reason: None,
             usage: None,
+            tool_call: None,
+        }
+    }
+
+    /// Creates a chunk carrying part of a tool call.
+    pub fn tool_call(delta: ToolCallDelta) -> Self {
+        Self {
+            delta: String::new(),
+            finish_reason: None,
+            usage: None,
+            tool_call: Some(delta),
         }
     }
 
@@ -293,6 +374,7 @@ impl StreamChunk {
             delta: String::new(),
             finish_reason: Some(finish_reason),
             usage: Some(usage),
+            tool_call: None,
         }
     }
 
@@ -302,6 +384,110 @@ impl StreamChunk {
     }
 }
 
+/// Incremental piece of a tool call in a streamed response.
+///
+/// Providers send a call's ID and name first, then its JSON arguments in
+/// fragments. Deltas with the same `index` belong to the same call.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ToolCallDelta {
+    /// Position of the call within the response.
+    pub index: u32,
+    /// Provider-assigned call ID, usually only on the first delta.
+    pub id: Option<String>,
+    /// Tool name, usually only on the first delta.
+    pub name: Option<String>,
+    /// Next fragment of the JSON arguments.
+    pub arguments_delta: String,
+}
+
+impl ToolCallDelta {
+    /// The opening delta of a call.
+    pub fn start(index: u32, id: impl Into<String>, name: impl Into<String>) -> Self {
+        Self {
+            index,
+            id: Some(id.into()),
+            name: Some(name.into()),
+            arguments_delta: String::new(),
+        }
+    }
+
+    /// A fragment of a call's arguments.
+    pub fn arguments(index: u32, fragment: impl Into<String>) -> Self {
+        Self {
+            index,
+            id: None,
+            name: None,
+            arguments_delta: fragment.into(),
+        }
+    }
+}
+
+/// Reassembles streamed tool call deltas into complete calls.
+///
+/// A call is complete once it has a name and its arguments parse as a JSON
+/// object, so it can be executed while the rest of the response streams.
+#[derive(Debug, Default)]
+pub struct ToolCallAssembler {
+    pending: BTreeMap<u32, PendingToolCall>,
+}
+
+#[derive(Debug, Default)]
+struct PendingToolCall {
+    name: String,
+    arguments: String,
+    emitted: bool,
+}
+
+impl ToolCallAssembler {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a delta, returning its call if this delta completed it.
+    ///
+    /// Each call is returned at most once.
+    pub fn push(&mut self, delta: ToolCallDelta) -> Option<ToolCall> {
+        let call = self.pending.entry(delta.index).or_default();
+        if call.emitted {
+            return None;
+        }
+        if let Some(name) = delta.name {
+            call.name.push_str(&name);
+        }
+        call.arguments.push_str(&delta.arguments_delta);
+
+        if call.name.is_empty() {
+            return None;
+        }
+        match serde_json::from_str::<serde_json::Value>(&call.arguments) {
+            Ok(arguments @ serde_json::Value::Object(_)) => {
+                call.emitted = true;
+                Some(ToolCall::new(call.name.clone(), arguments))
+            }
+            _ => None,
+        }
+    }
+
+    /// Calls still outstanding when the stream ends.
+    ///
+    /// Calls with no arguments get an empty object; calls whose arguments
+    /// never became valid JSON are dropped.
+    pub fn finish(&mut self) -> Vec<ToolCall> {
+        std::mem::take(&mut self.pending)
+            .into_values()
+            .filter(|call| !call.emitted && !call.name.is_empty())
+            .filter_map(|call| {
+                let arguments = if call.arguments.trim().is_empty() {
+                    serde_json::json!({})
+                } else {
+                    serde_json::from_str(&call.arguments).ok()?
+                };
+                Some(ToolCall::new(call.name, arguments))
+            })
+            .collect()
+    }
+}
+
 /// Provider information and capabilities.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ProviderInfo {
@@ -315,6 +501,9 @@ pub struct ProviderInfo {
     pub supports_streaming: bool,
     /// Whether function/tool calling is supported.
     pub supports_functions: bool,
+    /// Whether batch submission is supported.
+    #[serde(default)]
+    pub supports_batch: bool,
 }
 
 impl ProviderInfo {
@@ -330,6 +519,7 @@ impl ProviderInfo {
             max_context_tokens,
             supports_streaming: true,
             supports_functions: false,
+            supports_batch: false,
         }
     }
 
@@ -344,6 +534,107 @@ impl ProviderInfo {
         self.supports_functions = supports;
         self
     }
+
+    /// Sets batch submission support.
+    pub fn with_batch(mut self, supports: bool) -> Self {
+        self.supports_batch = supports;
+        self
+    }
+}
+
+/// Maximum length of a batch request's `custom_id`.
+pub const MAX_BATCH_CUSTOM_ID_LENGTH: usize = 64;
+
+/// One request in a batch, identified by a caller-chosen ID.
+#[derive(Debug, Clone)]
+pub struct BatchRequest {
+    /// Matches the request to its result; unique within the batch and made
+    /// of letters, digits, `-` and `_`.
+    pub custom_id: String,
+    pub request: CompletionRequest,
+}
+
+impl BatchRequest {
+    pub fn new(custom_id: impl Into<String>, request: CompletionRequest) -> Self {
+        Self {
+            custom_id: custom_id.into(),
+            request,
+        }
+    }
+}
+
+/// Processing state of a submitted batch.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BatchStatus {
+    /// Queued, running, or being cancelled.
+    InProgress,
+    /// Processing ended; results are available.
+    Ended,
+    /// The batch as a whole was rejected.
+    Failed,
+    /// The provider's completion window passed first.
+    Expired,
+    /// Cancelled before it ended.
+    Cancelled,
+}
+
+impl BatchStatus {
+    /// Whether the batch will make no further progress.
+    pub fn is_finished(&self) -> bool {
+        !matches!(self, BatchStatus::InProgress)
+    }
+}
+
+/// A submitted batch and its progress.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BatchJob {
+    /// Provider-assigned batch ID.
+    pub id: String,
+    pub status: BatchStatus,
+    /// Requests in the batch.
+    pub request_count: u32,
+    /// Requests that produced a completion so far.
+    pub succeeded_count: u32,
+    /// Requests that errored, expired, or were cancelled so far.
+    pub failed_count: u32,
+}
+
+/// Outcome of one request in a finished batch.
+#[derive(Debug)]
+pub struct BatchResult {
+    pub custom_id: String,
+    /// The completion, priced at batch rates, or why the request failed.
+    pub result: Result<CompletionResponse, AIError>,
+}
+
+/// Checks a batch before submission: non-empty, with unique, well-formed IDs.
+pub fn validate_batch(requests: &[BatchRequest]) -> Result<(), AIError> {
+    if requests.is_empty() {
+        return Err(AIError::InvalidRequest("batch has no requests".to_string()));
+    }
+    let mut seen = std::collections::HashSet::new();
+    for item in requests {
+        let id = &item.custom_id;
+        let well_formed = !id.is_empty()
+            && id.len() <= MAX_BATCH_CUSTOM_ID_LENGTH
+            && id
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_');
+        if !well_formed {
+            return Err(AIError::InvalidRequest(format!(
+                "invalid batch custom_id: {:?}",
+                id
+            )));
+        }
+        if !seen.insert(id.as_str()) {
+            return Err(AIError::InvalidRequest(format!(
+                "duplicate batch custom_id: {}",
+                id
+            )));
+        }
+    }
+    Ok(())
 }
 
 /// AI provider errors.
@@ -498,6 +789,26 @@ mod tests {
         assert_eq!(request.component_type, Some(ComponentType::IssueRaising));
     }
 
+    #[test]
+    fn batch_validation_rejects_bad_ids() {
+        let item = |id: &str| BatchRequest::new(id, CompletionRequest::new(test_metadata()));
+
+        assert!(validate_batch(&[item("profile-1"), item("dq_narrative_2")]).is_ok());
+        assert!(validate_batch(&[]).is_err());
+        assert!(validate_batch(&[item("")]).is_err());
+        assert!(validate_batch(&[item("has space")]).is_err());
+        assert!(validate_batch(&[item(&"x".repeat(65))]).is_err());
+        assert!(validate_batch(&[item("a"), item("a")]).is_err());
+    }
+
+    #[test]
+    fn batch_usage_is_half_price() {
+        let usage = TokenUsage::new(1000, 500, 9).at_batch_price();
+
+        assert_eq!(usage.total_tokens, 1500);
+        assert_eq!(usage.estimated_cost_cents, 4);
+    }
+
     #[test]
     fn message_constructors_work() {
         let system = Message::system("You are helpful");
@@ -624,4 +935,60 @@ mod tests {
             "cost limit exceeded: 1000 cents spent, limit is 500 cents"
         );
     }
+
+    #[test]
+    fn assembler_completes_call_once_arguments_parse() {
+        let mut assembler = ToolCallAssembler::new();
+
+        assert!(assembler
+            .push(ToolCallDelta::start(0, "call_1", "add_objective"))
+            .is_none());
+        assert!(assembler
+            .push(ToolCallDelta::arguments(0, "{\"name\": \"Min"))
+            .is_none());
+        let call = assembler
+            .push(ToolCallDelta::arguments(0, "imize cost\"}"))
+            .unwrap();
+
+        assert_eq!(call.name(), "add_objective");
+        assert_eq!(call.parameters()["name"], "Minimize cost");
+        // Already emitted
+        assert!(assembler.push(ToolCallDelta::arguments(0, " ")).is_none());
+        assert!(assembler.finish().is_empty());
+    }
+
+    #[test]
+    fn assembler_tracks_interleaved_calls_by_index() {
+        let mut assembler = ToolCallAssembler::new();
+
+        assembler.push(ToolCallDelta::start(0, "a", "first"));
+        assembler.push(ToolCallDelta::start(1, "b", "second"));
+        assembler.push(ToolCallDelta::arguments(0, "{\"x\":"));
+        let second = assembler.push(ToolCallDelta::arguments(1, "{}")).unwrap();
+        let first = assembler.push(ToolCallDelta::arguments(0, "1}")).unwrap();
+
+        assert_eq!(second.name(), "second");
+        assert_eq!(first.parameters()["x"], 1);
+    }
+
+    #[test]
+    fn assembler_finish_flushes_argumentless_and_drops_malformed_calls() {
+        let mut assembler = ToolCallAssembler::new();
+
+        assembler.push(ToolCallDelta::start(0, "a", "list_objectives"));
+        assembler.push(ToolCallDelta::start(1, "b", "broken"));
+        assembler.push(ToolCallDelta::arguments(1, "{\"unterminated\""));
+
+        let calls = assembler.finish();
+        assert_eq!(calls.len(), 1);
+        assert_eq!(calls[0].name(), "list_objectives");
+        assert_eq!(calls[0].parameters(), &serde_json::json!({}));
+    }
+
+    #[test]
+    fn tool_call_chunks_are_not_final() {
+        let chunk = StreamChunk::tool_call(ToolCallDelta::arguments(0, "{}"));
+        assert!(!chunk.is_final());
+        assert!(chunk.delta.is_empty());
+    }
 }
@@ -0,0 +1,101 @@
+//! AnalyticsSink port - Interface for shipping usage events to analytics.
+//!
+//! Domain events are anonymized into [`UsageEvent`]s before they reach a
+//! sink. A usage event says what kind of thing happened, to which
+//! pseudonymous user and cycle, and a few numbers about it. It never holds
+//! real IDs or anything the user wrote, so sinks can be third-party
+//! warehouses that are kept outside GDPR erasure.
+
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+
+use crate::domain::conversation::tools::ToolResult;
+use crate::domain::foundation::{ComponentType, DomainError, Timestamp};
+
+/// A pseudonymous usage event.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct UsageEvent {
+    /// Pseudonym of the source event, stable across redeliveries.
+    pub id: String,
+
+    /// When it happened, truncated to the hour.
+    pub occurred_at: Timestamp,
+
+    /// Pseudonym of the user, when the source event named one.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user: Option<String>,
+
+    /// Pseudonym of the cycle.
+    pub cycle: String,
+
+    #[serde(flatten)]
+    pub kind: UsageEventKind,
+}
+
+/// What happened, with only the measurements analytics needs.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum UsageEventKind {
+    ComponentStarted {
+        component: ComponentType,
+    },
+    ComponentCompleted {
+        component: ComponentType,
+        /// Seconds since the component was started, when the start was seen.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        duration_secs: Option<u64>,
+    },
+    ToolInvoked {
+        component: ComponentType,
+        tool: String,
+        result: ToolResult,
+        duration_ms: u32,
+    },
+    DqScored {
+        overall_score: u8,
+        element_scores: Vec<DqElementUsage>,
+    },
+}
+
+/// One Decision Quality element score, without its rationale.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DqElementUsage {
+    /// One of the standard element names.
+    pub element: String,
+    pub score: u8,
+}
+
+/// Port for recording usage events.
+#[async_trait]
+pub trait AnalyticsSink: Send + Sync {
+    async fn record(&self, event: &UsageEvent) -> Result<(), DomainError>;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Compile-time check that trait is object-safe
+    #[allow(dead_code)]
+    fn assert_object_safe(_: &dyn AnalyticsSink) {}
+
+    #[test]
+    fn serializes_kind_inline() {
+        let event = UsageEvent {
+            id: "e1".to_string(),
+            occurred_at: Timestamp::from_unix_secs(3600),
+            user: None,
+            cycle: "c1".to_string(),
+            kind: UsageEventKind::ComponentCompleted {
+                component: ComponentType::Objectives,
+                duration_secs: Some(90),
+            },
+        };
+
+        let json = serde_json::to_value(&event).unwrap();
+        assert_eq!(json["kind"], "component_completed");
+        assert_eq!(json["component"], "objectives");
+        assert_eq!(json["duration_secs"], 90);
+        assert!(json.get("user").is_none());
+    }
+}
@@ -0,0 +1,34 @@
+//! AttachmentRepository port - Metadata for files attached to components.
+//!
+//! File bytes are written to `DocumentStorage` under the attachment's storage
+//! key; this port persists only the metadata that points at them.
+
+use async_trait::async_trait;
+
+use crate::domain::document::Attachment;
+use crate::domain::foundation::{AttachmentId, CycleId, DomainError};
+
+/// Port for persisting attachment metadata.
+#[async_trait]
+pub trait AttachmentRepository: Send + Sync {
+    /// Save a new attachment.
+    async fn save(&self, attachment: &Attachment) -> Result<(), DomainError>;
+
+    /// Find an attachment by ID.
+    async fn find_by_id(&self, id: &AttachmentId) -> Result<Option<Attachment>, DomainError>;
+
+    /// List a cycle's attachments, oldest first.
+    async fn list_by_cycle(&self, cycle_id: &CycleId) -> Result<Vec<Attachment>, DomainError>;
+
+    /// Delete an attachment's metadata. Deleting a missing ID is not an error.
+    async fn delete(&self, id: &AttachmentId) -> Result<(), DomainError>;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Compile-time check that trait is object-safe
+    #[allow(dead_code)]
+    fn assert_object_safe(_: &dyn AttachmentRepository) {}
+}
@@ -0,0 +1,82 @@
+//! AuditLog port - Append-only, hash-chained record of command executions.
+//!
+//! Implementations append entries in one total order, sealing each onto the
+//! hash of the entry before, and never update or delete them.
+
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+
+use crate::domain::audit::{AuditEntry, AuditRecord};
+use crate::domain::foundation::{DomainError, UserId};
+
+/// Filters and paging for listing audit entries, newest first.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct AuditQuery {
+    pub actor_id: Option<UserId>,
+    pub aggregate_type: Option<String>,
+    pub aggregate_id: Option<String>,
+    pub command_type: Option<String>,
+    /// Maximum number of results to return.
+    pub limit: Option<u32>,
+    /// Number of results to skip.
+    pub offset: Option<u32>,
+}
+
+impl AuditQuery {
+    /// Create a query for one page of entries.
+    pub fn paginated(page: u32, per_page: u32) -> Self {
+        Self {
+            limit: Some(per_page),
+            offset: Some(page.saturating_sub(1) * per_page),
+            ..Self::default()
+        }
+    }
+}
+
+/// Paginated list of audit entries.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AuditPage {
+    /// Entries in this page, newest first.
+    pub items: Vec<AuditEntry>,
+
+    /// Total number of matching entries.
+    pub total: u64,
+
+    /// Whether there are more results.
+    pub has_more: bool,
+}
+
+/// Port for the audit log.
+#[async_trait]
+pub trait AuditLog: Send + Sync {
+    /// Seals `record` onto the end of the chain and stores it.
+    async fn append(&self, record: AuditRecord) -> Result<AuditEntry, DomainError>;
+
+    /// Entries matching `query`, newest first.
+    async fn list(&self, query: &AuditQuery) -> Result<AuditPage, DomainError>;
+
+    /// Up to `limit` entries after `after_sequence`, oldest first, for
+    /// walking the chain.
+    async fn entries_after(
+        &self,
+        after_sequence: u64,
+        limit: u32,
+    ) -> Result<Vec<AuditEntry>, DomainError>;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Compile-time check that the trait is object-safe
+    #[allow(dead_code)]
+    fn assert_object_safe(_: &dyn AuditLog) {}
+
+    #[test]
+    fn paginated_skips_earlier_pages() {
+        let query = AuditQuery::paginated(3, 20);
+
+        assert_eq!(query.limit, Some(20));
+        assert_eq!(query.offset, Some(40));
+    }
+}
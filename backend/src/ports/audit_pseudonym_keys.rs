@@ -0,0 +1,31 @@
+//! AuditPseudonymKeys port - Per-user keys that pseudonymize users in the
+//! audit log.
+//!
+//! Implementations delete a user's key when the user is erased, which is
+//! what unlinks the user's audit entries from them.
+
+use async_trait::async_trait;
+
+use crate::domain::audit::PseudonymKey;
+use crate::domain::foundation::{DomainError, UserId};
+
+/// Port for audit pseudonym keys.
+#[async_trait]
+pub trait AuditPseudonymKeys: Send + Sync {
+    /// The user's key, created on first use. Concurrent first uses agree
+    /// on one key.
+    async fn key_for(&self, user_id: &UserId) -> Result<PseudonymKey, DomainError>;
+
+    /// The user's key, or `None` if they have never been audited or have
+    /// been erased.
+    async fn find(&self, user_id: &UserId) -> Result<Option<PseudonymKey>, DomainError>;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Compile-time check that the trait is object-safe
+    #[allow(dead_code)]
+    fn assert_object_safe(_: &dyn AuditPseudonymKeys) {}
+}
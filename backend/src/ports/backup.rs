@@ -0,0 +1,36 @@
+//! Backup ports - Whole-instance backup and restore.
+//!
+//! `BackupSource` is implemented once per kind of aggregate (memberships,
+//! organizations, sessions, conversations), so a new store joins backups by
+//! registering a source, the same way `UserDataSource` works for exports.
+
+use async_trait::async_trait;
+
+use crate::domain::foundation::DomainError;
+
+/// One kind of aggregate included in backups.
+///
+/// Sources are restored in the order they are registered, so sources whose
+/// records others refer to (memberships before organizations, sessions
+/// before conversations) must come first.
+#[async_trait]
+pub trait BackupSource: Send + Sync {
+    /// Archive section this source fills, e.g. `"sessions"`.
+    fn section(&self) -> &'static str;
+
+    /// Every record this source holds, as JSON.
+    async fn export(&self) -> Result<Vec<serde_json::Value>, DomainError>;
+
+    /// Saves records written by `export`, replacing any with the same ID.
+    /// Returns how many were restored.
+    async fn restore(&self, records: Vec<serde_json::Value>) -> Result<usize, DomainError>;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Compile-time check that the trait is object-safe
+    #[allow(dead_code)]
+    fn assert_object_safe(_: &dyn BackupSource) {}
+}
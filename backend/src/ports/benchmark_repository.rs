@@ -0,0 +1,33 @@
+//! BenchmarkRepository port - Anonymized benchmark distributions.
+//!
+//! Holds only the aggregates published by `BenchmarkAggregator`; no
+//! per-user values are stored.
+
+use async_trait::async_trait;
+
+use crate::domain::foundation::DomainError;
+use crate::domain::profile::{BenchmarkDistribution, DecisionDomain};
+
+/// Port for persisting benchmark distributions.
+#[async_trait]
+pub trait BenchmarkRepository: Send + Sync {
+    /// Replace every stored distribution with `distributions`, so metrics
+    /// that fell below the contributor minimum stop being served.
+    async fn replace_all(&self, distributions: &[BenchmarkDistribution])
+        -> Result<(), DomainError>;
+
+    /// Distributions for one decision domain.
+    async fn list_for_domain(
+        &self,
+        domain: DecisionDomain,
+    ) -> Result<Vec<BenchmarkDistribution>, DomainError>;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Compile-time check that trait is object-safe
+    #[allow(dead_code)]
+    fn assert_object_safe(_: &dyn BenchmarkRepository) {}
+}
@@ -0,0 +1,123 @@
+//! Cache port - Shared key/value cache for hot read views.
+//!
+//! Entries expire after a TTL and can carry tags. Invalidating a tag drops
+//! every entry stored with it, so a write only needs to know which entity
+//! changed, not which views were built from it.
+//!
+//! Callers treat the cache as an optimization: a `CacheError` should be
+//! logged and the value read from the source of truth instead.
+
+use std::time::Duration;
+
+use async_trait::async_trait;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+use crate::domain::foundation::{CycleId, SessionId};
+
+/// How long an entry lives and which tags it is invalidated by.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CacheEntryOptions {
+    pub ttl: Duration,
+    pub tags: Vec<String>,
+}
+
+impl CacheEntryOptions {
+    pub fn new(ttl: Duration) -> Self {
+        Self {
+            ttl,
+            tags: Vec::new(),
+        }
+    }
+
+    pub fn with_tag(mut self, tag: impl Into<String>) -> Self {
+        self.tags.push(tag.into());
+        self
+    }
+}
+
+/// Tag for every cached view built from a cycle.
+pub fn cycle_cache_tag(cycle_id: &CycleId) -> String {
+    format!("cycle:{}", cycle_id)
+}
+
+/// Tag for every cached view built from a session.
+pub fn session_cache_tag(session_id: &SessionId) -> String {
+    format!("session:{}", session_id)
+}
+
+/// Shared cache with TTLs and tag-based invalidation.
+#[async_trait]
+pub trait Cache: Send + Sync {
+    /// Returns the stored bytes, or `None` if missing or expired.
+    async fn get(&self, key: &str) -> Result<Option<Vec<u8>>, CacheError>;
+
+    /// Stores a value, replacing any existing entry.
+    async fn set(
+        &self,
+        key: &str,
+        value: Vec<u8>,
+        options: &CacheEntryOptions,
+    ) -> Result<(), CacheError>;
+
+    /// Removes one entry.
+    async fn invalidate(&self, key: &str) -> Result<(), CacheError>;
+
+    /// Removes every entry stored with the tag, returning how many.
+    async fn invalidate_tag(&self, tag: &str) -> Result<u64, CacheError>;
+}
+
+/// JSON helpers over [`Cache`].
+#[async_trait]
+pub trait CacheExt: Cache {
+    /// Reads and deserializes an entry. An entry that no longer matches the
+    /// type (e.g. after a deploy changed it) counts as a miss.
+    async fn get_json<T: DeserializeOwned>(&self, key: &str) -> Result<Option<T>, CacheError> {
+        Ok(self
+            .get(key)
+            .await?
+            .and_then(|bytes| serde_json::from_slice(&bytes).ok()))
+    }
+
+    /// Serializes and stores an entry.
+    async fn set_json<T: Serialize + Sync>(
+        &self,
+        key: &str,
+        value: &T,
+        options: &CacheEntryOptions,
+    ) -> Result<(), CacheError> {
+        let bytes =
+            serde_json::to_vec(value).map_err(|e| CacheError::Serialization(e.to_string()))?;
+        self.set(key, bytes, options).await
+    }
+}
+
+impl<C: Cache + ?Sized> CacheExt for C {}
+
+/// Errors from cache operations.
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum CacheError {
+    /// Cache backend is unavailable.
+    #[error("cache unavailable: {0}")]
+    Unavailable(String),
+
+    /// Value could not be encoded.
+    #[error("cache serialization failed: {0}")]
+    Serialization(String),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn options_collect_tags() {
+        let cycle_id = CycleId::new();
+        let options = CacheEntryOptions::new(Duration::from_secs(30))
+            .with_tag(cycle_cache_tag(&cycle_id))
+            .with_tag("other");
+
+        assert_eq!(options.tags.len(), 2);
+        assert_eq!(options.tags[0], format!("cycle:{}", cycle_id));
+    }
+}
@@ -0,0 +1,120 @@
+//! Calendar Ports - Decision deadlines and follow-ups as calendar events.
+//!
+//! Each user gets a private iCalendar feed URL whose token is signed by a
+//! [`CalendarFeedSigner`], so calendar apps can subscribe without logging
+//! in. Tokens carry the user's feed version from a
+//! [`CalendarFeedVersionRepository`]; rotating it retires a leaked URL
+//! without touching anyone else's. The feed lists the deadlines set in the Problem Frame of active
+//! decisions, read through a [`DecisionDeadlineReader`], and the scheduled
+//! outcome follow-ups. A [`CalendarRenderer`] turns events into an `.ics`
+//! document, for the feed and for one-off email attachments.
+//!
+//! ```text
+//! GET /api/user/calendar ──current(user)──► CalendarFeedVersionRepository
+//!        └──feed_url(user, version)──► CalendarFeedSigner ──► URL ──► calendar app
+//!
+//! POST /api/user/calendar/rotate ──rotate(user)──► new version ──► new URL
+//!
+//! GET /public/calendar/{token}.ics ──verify(token)──► UserId + version
+//!        ├──current(user) == version?──► CalendarFeedVersionRepository
+//!        ├──list_deadlines()──► DecisionDeadlineReader
+//!        └──list_scheduled_for_user()──► OutcomeReminderRepository
+//!                  └──► CalendarEvent[] ──render()──► text/calendar
+//! ```
+
+use async_trait::async_trait;
+
+use crate::domain::foundation::{CycleId, DomainError, SessionId, Timestamp, UserId};
+
+/// MIME type of rendered calendars.
+pub const CALENDAR_CONTENT_TYPE: &str = "text/calendar; charset=utf-8";
+
+/// One event on a user's decision calendar.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CalendarEvent {
+    /// Globally unique and stable across renders, so calendar apps update
+    /// the event instead of duplicating it.
+    pub uid: String,
+    pub summary: String,
+    pub description: String,
+    pub starts_at: Timestamp,
+    /// Whether the event spans the day of `starts_at` rather than an instant.
+    pub all_day: bool,
+}
+
+/// A deadline set in the Problem Frame of a user's active decision.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DecisionDeadline {
+    pub session_id: SessionId,
+    pub cycle_id: CycleId,
+    /// Session title.
+    pub title: String,
+    pub decide_by: Timestamp,
+}
+
+/// What a verified feed token names.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CalendarFeedToken {
+    pub user_id: UserId,
+    /// Feed version the URL was issued for; stale once the user rotates.
+    pub version: u32,
+}
+
+/// Reasons a calendar feed token is refused.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum CalendarFeedError {
+    #[error("Malformed feed token")]
+    Malformed,
+
+    #[error("Invalid feed token signature")]
+    InvalidSignature,
+}
+
+/// Issues and verifies the tokens in users' calendar feed URLs.
+pub trait CalendarFeedSigner: Send + Sync {
+    /// Full feed URL for version `version` of `user_id`'s feed.
+    fn feed_url(&self, user_id: &UserId, version: u32) -> String;
+
+    /// Checks a token from a feed URL and returns the user and version it
+    /// names. Callers compare the version with the user's current one.
+    fn verify(&self, token: &str) -> Result<CalendarFeedToken, CalendarFeedError>;
+}
+
+/// Port for each user's calendar feed version.
+#[async_trait]
+pub trait CalendarFeedVersionRepository: Send + Sync {
+    /// The user's current feed version; 0 until they first rotate.
+    async fn current(&self, user_id: &UserId) -> Result<u32, DomainError>;
+
+    /// Moves the user to a new feed version, retiring URLs issued for the
+    /// old one, and returns it.
+    async fn rotate(&self, user_id: &UserId) -> Result<u32, DomainError>;
+}
+
+/// Renders events as an iCalendar (RFC 5545) document.
+pub trait CalendarRenderer: Send + Sync {
+    /// `name` is shown by calendar apps for subscribed feeds.
+    fn render(&self, name: &str, events: &[CalendarEvent]) -> String;
+}
+
+/// Port for reading decision deadlines.
+#[async_trait]
+pub trait DecisionDeadlineReader: Send + Sync {
+    /// Deadlines of the user's active cycles in active sessions, soonest first.
+    async fn list_deadlines(&self, user_id: &UserId) -> Result<Vec<DecisionDeadline>, DomainError>;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Compile-time check that traits are object-safe
+    #[allow(dead_code)]
+    fn assert_object_safe(
+        _: &dyn CalendarFeedSigner,
+        _: &dyn CalendarFeedVersionRepository,
+        _: &dyn CalendarRenderer,
+        _: &dyn DecisionDeadlineReader,
+    ) {
+    }
+}
@@ -18,10 +18,15 @@
 //! Half-Open --[any failure]--> Open
 //! ```
 //!
+//! An administrator can also force a circuit open during an incident; it
+//! then stays open, without moving to half-open, until reset.
+//!
 //! See `docs/architecture/SCALING-READINESS.md` for full details.
 
 use std::time::Duration;
 
+use crate::domain::foundation::Timestamp;
+
 /// Circuit breaker states for external service protection.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum CircuitState {
@@ -135,7 +140,7 @@ impl CircuitBreakerConfig {
 ///                 Ok(response)
 ///             }
 ///             Err(e) => {
-///                 self.circuit_breaker.record_failure();
+///                 self.circuit_breaker.record_failure(&e.to_string());
 ///                 Err(e)
 ///             }
 ///         }
@@ -160,17 +165,22 @@ pub trait CircuitBreaker: Send + Sync {
     /// In closed state, this may reset failure counts.
     fn record_success(&self);
 
-    /// Record a failed request.
+    /// Record a failed request and the error it failed with.
     ///
     /// In closed state, this counts toward the failure threshold.
     /// In half-open state, this immediately reopens the circuit.
-    fn record_failure(&self);
+    fn record_failure(&self, error: &str);
 
     /// Force reset the circuit to closed state.
     ///
     /// Use sparingly - typically for administrative intervention.
     fn reset(&self);
 
+    /// Force the circuit open, rejecting requests until `reset`.
+    ///
+    /// For administrative intervention when a service is known to be down.
+    fn force_open(&self);
+
     /// Get metrics about the circuit breaker.
     fn metrics(&self) -> CircuitBreakerMetrics;
 }
@@ -198,6 +208,15 @@ pub struct CircuitBreakerMetrics {
 
     /// Time until circuit transitions to half-open (when open)
     pub time_until_half_open: Option<Duration>,
+
+    /// Whether an administrator forced the circuit open
+    pub forced_open: bool,
+
+    /// Error of the most recent failure
+    pub last_error: Option<String>,
+
+    /// When the most recent failure happened
+    pub last_failure_at: Option<Timestamp>,
 }
 
 #[cfg(test)]
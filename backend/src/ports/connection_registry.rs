@@ -13,13 +13,19 @@
 //! 5. Server B publishes message to Server A's channel
 //! 6. Server A delivers message to User's WebSocket
 //!
+//! Dashboard updates are routed the same way by session: each server records
+//! the session rooms it holds, and the server where an event occurs forwards
+//! it through a [`ServerMessenger`] to the others. Servers heartbeat their
+//! `ServerId`; any server may clean up after one that stops heartbeating.
+//!
 //! See `docs/architecture/SCALING-READINESS.md` for full details.
 
 use async_trait::async_trait;
 use serde::{Deserialize, Serialize};
 use std::fmt;
+use std::time::Duration;
 
-use crate::domain::foundation::UserId;
+use crate::domain::foundation::{EventEnvelope, SessionId, UserId};
 
 /// Unique identifier for a server instance in a multi-server deployment.
 ///
@@ -169,8 +175,53 @@ pub trait ConnectionRegistry: Send + Sync {
 
     /// Clean up all connections for a server.
     ///
-    /// Called on graceful server shutdown.
+    /// Called on graceful server shutdown, and by other servers once it
+    /// stops heartbeating. Also removes its session rooms and heartbeat.
     async fn cleanup_server(&self, server_id: &ServerId) -> Result<u64, ConnectionRegistryError>;
+
+    /// Record that a server holds clients in a session room.
+    ///
+    /// Calling again refreshes the entry's TTL.
+    async fn join_session(
+        &self,
+        session_id: &SessionId,
+        server_id: &ServerId,
+    ) -> Result<(), ConnectionRegistryError>;
+
+    /// Record that a server's last client in a session room left.
+    async fn leave_session(
+        &self,
+        session_id: &SessionId,
+        server_id: &ServerId,
+    ) -> Result<(), ConnectionRegistryError>;
+
+    /// Find all servers holding clients in a session room.
+    async fn find_session_servers(
+        &self,
+        session_id: &SessionId,
+    ) -> Result<Vec<ServerId>, ConnectionRegistryError>;
+
+    /// Record that a server is alive.
+    async fn heartbeat_server(&self, server_id: &ServerId) -> Result<(), ConnectionRegistryError>;
+
+    /// Servers that have not heartbeated within `max_silence`.
+    async fn find_stale_servers(
+        &self,
+        max_silence: Duration,
+    ) -> Result<Vec<ServerId>, ConnectionRegistryError>;
+}
+
+/// Port for delivering events to rooms held by another server.
+#[async_trait]
+pub trait ServerMessenger: Send + Sync {
+    /// Send a session's event to one server, which broadcasts it to its
+    /// local clients in that session room.
+    async fn publish_to_server(
+        &self,
+        server_id: &ServerId,
+        session_id: &SessionId,
+        event: &EventEnvelope,
+    ) -> Result<(), ConnectionRegistryError>;
 }
 
 #[cfg(test)]
@@ -190,6 +241,10 @@ mod tests {
         assert_eq!(format!("{}", server_id), "server-1:8080");
     }
 
+    // Compile-time check that traits are object-safe
+    #[allow(dead_code)]
+    fn assert_object_safe(_: &dyn ConnectionRegistry, _: &dyn ServerMessenger) {}
+
     #[test]
     fn server_id_from_string() {
         let server_id: ServerId = "server-2:9000".into();
@@ -0,0 +1,28 @@
+//! ConsentRepository port - Persistence for the consent ledger.
+//!
+//! Consent records are append-only: implementations must never update or
+//! delete existing rows (except for right-to-erasure workflows).
+
+use async_trait::async_trait;
+
+use crate::domain::consent::ConsentRecord;
+use crate::domain::foundation::{DomainError, UserId};
+
+/// Port for persisting consent records.
+#[async_trait]
+pub trait ConsentRepository: Send + Sync {
+    /// Append a consent record.
+    async fn append(&self, record: &ConsentRecord) -> Result<(), DomainError>;
+
+    /// List all consent records for a user, oldest first.
+    async fn list_for_user(&self, user_id: &UserId) -> Result<Vec<ConsentRecord>, DomainError>;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Compile-time check that trait is object-safe
+    #[allow(dead_code)]
+    fn assert_object_safe(_: &dyn ConsentRepository) {}
+}
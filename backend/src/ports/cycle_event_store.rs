@@ -0,0 +1,49 @@
+//! Cycle event store port - Event streams and snapshots for cycles.
+//!
+//! Every change to a cycle is recorded as a versioned `CycleEvent`, and a
+//! `CycleSnapshot` is kept every `SNAPSHOT_INTERVAL` events. A cycle at any
+//! version is its nearest earlier snapshot plus the events after it, which
+//! answers history questions the current-state tables cannot.
+
+use async_trait::async_trait;
+
+use crate::domain::cycle::{Cycle, CycleSnapshot, RecordedCycleEvent};
+use crate::domain::foundation::{CycleId, DomainError};
+
+/// Per-cycle event streams and snapshots.
+#[async_trait]
+pub trait CycleEventStore: Send + Sync {
+    /// Records the cycle's uncommitted events, snapshotting when due.
+    ///
+    /// Events already stored at the same version are skipped, so saving a
+    /// cycle twice is harmless. A cycle with no stored history, such as one
+    /// created before event sourcing, gets a snapshot of its current state.
+    async fn append(&self, cycle: &Cycle) -> Result<(), DomainError>;
+
+    /// Events with versions after `after_version` and up to `up_to_version`
+    /// (inclusive, unbounded when `None`), oldest first.
+    async fn load_events(
+        &self,
+        cycle_id: &CycleId,
+        after_version: u64,
+        up_to_version: Option<u64>,
+    ) -> Result<Vec<RecordedCycleEvent>, DomainError>;
+
+    /// The latest snapshot at or before `at_version` (the latest overall when
+    /// `None`).
+    async fn latest_snapshot(
+        &self,
+        cycle_id: &CycleId,
+        at_version: Option<u64>,
+    ) -> Result<Option<CycleSnapshot>, DomainError>;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cycle_event_store_is_object_safe() {
+        fn _accepts_dyn(_store: &dyn CycleEventStore) {}
+    }
+}
@@ -8,13 +8,18 @@
 //! - **Read-optimized**: Can use caching, denormalized views
 //! - **Separated from write**: CQRS pattern for scalability
 //! - **Tree support**: Queries for cycle branches and lineage
+//! - **Organization support**: Cycles across a team's shared sessions
+
+use std::collections::HashMap;
 
 use crate::domain::cycle::CycleTreeNode as PrOACTTreeNode;
 use crate::domain::foundation::{
-    ComponentStatus, ComponentType, CycleId, CycleStatus, DomainError, SessionId, Timestamp,
+    ComponentStatus, ComponentType, CycleId, CycleStatus, DomainError, OrganizationId, SessionId,
+    Timestamp,
 };
 use async_trait::async_trait;
 use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
 use serde_json::Value as JsonValue;
 
 /// Reader port for cycle queries.
@@ -34,6 +39,15 @@ pub trait CycleReader: Send + Sync {
     async fn list_by_session_id(&self, session_id: &SessionId)
         -> Result<Vec<CycleSummary>, DomainError>;
 
+    /// List cycles in every session shared with an organization.
+    ///
+    /// Returns each session's cycles ordered by created_at descending.
+    /// Callers check that the requesting user is a member.
+    async fn list_by_organization(
+        &self,
+        organization_id: &OrganizationId,
+    ) -> Result<HashMap<SessionId, Vec<CycleSummary>>, DomainError>;
+
     /// Get the cycle tree for a session.
     ///
     /// Returns the root cycle with all its branches organized hierarchically.
@@ -58,6 +72,25 @@ pub trait CycleReader: Send + Sync {
         component_type: ComponentType,
     ) -> Result<Option<ComponentOutputView>, DomainError>;
 
+    /// Get several components' outputs from a cycle in one read.
+    ///
+    /// Components without output are left out of the map. The default
+    /// implementation reads each component separately; adapters should
+    /// override it with a single query.
+    async fn get_component_outputs(
+        &self,
+        cycle_id: &CycleId,
+        component_types: &[ComponentType],
+    ) -> Result<HashMap<ComponentType, ComponentOutputView>, DomainError> {
+        let mut outputs = HashMap::new();
+        for &component_type in component_types {
+            if let Some(view) = self.get_component_output(cycle_id, component_type).await? {
+                outputs.insert(component_type, view);
+            }
+        }
+        Ok(outputs)
+    }
+
     /// Get the PrOACT letter-based tree view for a session.
     ///
     /// Returns a tree structure optimized for PrOACT visualization with
@@ -124,7 +157,7 @@ pub struct ComponentStatusItem {
 }
 
 /// Summary view of a cycle for lists.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct CycleSummary {
     /// Cycle ID.
     pub id: CycleId,
@@ -149,12 +182,13 @@ pub struct CycleSummary {
 }
 
 /// Tree node for cycle hierarchy visualization.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct CycleTreeNode {
     /// Summary of this cycle.
     pub cycle: CycleSummary,
 
     /// Child branches.
+    #[schema(no_recursion)]
     pub children: Vec<CycleTreeNode>,
 }
 
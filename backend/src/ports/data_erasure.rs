@@ -0,0 +1,53 @@
+//! Data erasure ports - GDPR right to erasure.
+//!
+//! `DataErasureRepository` holds erasure requests; pending erasures double as
+//! the work queue, polled through `list_due` like data exports.
+//! `UserDataEraser` is the erasure counterpart of `UserDataSource`: one per
+//! kind of data, so a new store joins the erasure by registering an eraser.
+
+use async_trait::async_trait;
+
+use crate::domain::foundation::{DataErasureId, DomainError, Timestamp, UserId};
+use crate::domain::privacy::{DataErasure, ErasedData};
+
+/// Port for persisting data erasure requests.
+#[async_trait]
+pub trait DataErasureRepository: Send + Sync {
+    /// Insert or update an erasure.
+    async fn save(&self, erasure: &DataErasure) -> Result<(), DomainError>;
+
+    /// Find an erasure by ID.
+    async fn find_by_id(&self, id: &DataErasureId) -> Result<Option<DataErasure>, DomainError>;
+
+    /// The user's most recent erasure, if any.
+    async fn latest_for_user(&self, user_id: &UserId) -> Result<Option<DataErasure>, DomainError>;
+
+    /// Pending erasures whose next attempt is at or before `now`, oldest
+    /// first.
+    async fn list_due(&self, now: Timestamp, limit: u32) -> Result<Vec<DataErasure>, DomainError>;
+}
+
+/// One kind of user data removed by erasures.
+///
+/// Implementations must be idempotent: a failed or unverified erasure runs
+/// every eraser again.
+#[async_trait]
+pub trait UserDataEraser: Send + Sync {
+    /// Report section this eraser fills, e.g. `"sessions"`.
+    fn section(&self) -> &'static str;
+
+    /// Erases everything this eraser holds about the user.
+    async fn erase(&self, user_id: &UserId) -> Result<ErasedData, DomainError>;
+
+    /// Records still held about the user; zero once erased.
+    async fn remaining(&self, user_id: &UserId) -> Result<u64, DomainError>;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Compile-time check that traits are object-safe
+    #[allow(dead_code)]
+    fn assert_object_safe(_: &dyn DataErasureRepository, _: &dyn UserDataEraser) {}
+}
@@ -0,0 +1,54 @@
+//! Data export ports - GDPR data portability.
+//!
+//! `DataExportRepository` holds export requests; pending exports double as
+//! the work queue, polled through `list_due` like document deliveries.
+//! `UserDataSource` is implemented once per kind of data (decision profile,
+//! sessions, conversations, usage), so a new store joins the export by
+//! registering a source rather than by changing the exporter.
+
+use async_trait::async_trait;
+
+use crate::domain::foundation::{DataExportId, DomainError, Timestamp, UserId};
+use crate::domain::privacy::DataExport;
+
+/// Port for persisting data export requests.
+#[async_trait]
+pub trait DataExportRepository: Send + Sync {
+    /// Insert or update an export.
+    async fn save(&self, export: &DataExport) -> Result<(), DomainError>;
+
+    /// Find an export by ID.
+    async fn find_by_id(&self, id: &DataExportId) -> Result<Option<DataExport>, DomainError>;
+
+    /// The user's most recent export, if any.
+    async fn latest_for_user(&self, user_id: &UserId) -> Result<Option<DataExport>, DomainError>;
+
+    /// Pending exports whose next attempt is at or before `now`, oldest
+    /// first.
+    async fn list_due(&self, now: Timestamp, limit: u32) -> Result<Vec<DataExport>, DomainError>;
+
+    /// All of the user's exports, newest first.
+    async fn list_for_user(&self, user_id: &UserId) -> Result<Vec<DataExport>, DomainError>;
+
+    /// Delete an export. Deleting one that no longer exists succeeds.
+    async fn delete(&self, id: &DataExportId) -> Result<(), DomainError>;
+}
+
+/// One kind of user data included in exports.
+#[async_trait]
+pub trait UserDataSource: Send + Sync {
+    /// Archive section this source fills, e.g. `"sessions"`.
+    fn section(&self) -> &'static str;
+
+    /// Everything this source holds about the user, as JSON.
+    async fn collect(&self, user_id: &UserId) -> Result<serde_json::Value, DomainError>;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Compile-time check that traits are object-safe
+    #[allow(dead_code)]
+    fn assert_object_safe(_: &dyn DataExportRepository, _: &dyn UserDataSource) {}
+}
@@ -0,0 +1,115 @@
+//! Decision Embedding Repository Port - Stored embeddings of past decisions.
+//!
+//! Each cycle has up to two embeddings per component: one of its structured
+//! output and one of its conversation summary. They back "find similar past
+//! decisions" and the recurring-decision pattern on the decision profile.
+//! Similarity is only computed between a user's own decisions.
+
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+
+use crate::domain::foundation::{
+    ComponentType, CycleId, DomainError, Embedding, SessionId, Timestamp, UserId,
+};
+
+/// What text an embedding was computed from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum EmbeddingSource {
+    /// The component's structured output.
+    ComponentOutput,
+    /// What the user said in the component's conversation.
+    ConversationSummary,
+}
+
+impl EmbeddingSource {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            EmbeddingSource::ComponentOutput => "component_output",
+            EmbeddingSource::ConversationSummary => "conversation_summary",
+        }
+    }
+
+    pub fn parse(s: &str) -> Option<Self> {
+        [
+            EmbeddingSource::ComponentOutput,
+            EmbeddingSource::ConversationSummary,
+        ]
+        .into_iter()
+        .find(|source| source.as_str() == s)
+    }
+}
+
+/// One stored embedding.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DecisionEmbedding {
+    pub cycle_id: CycleId,
+    pub component_type: ComponentType,
+    pub source: EmbeddingSource,
+    pub session_id: SessionId,
+    /// Owner of the session; similarity searches never cross users.
+    pub user_id: UserId,
+    /// SHA-256 of the embedded text, so unchanged text is not re-embedded.
+    pub content_hash: String,
+    pub model: String,
+    pub embedding: Embedding,
+    pub updated_at: Timestamp,
+}
+
+/// A past decision close to the one searched for.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SimilarDecision {
+    pub cycle_id: CycleId,
+    pub session_id: SessionId,
+    /// Component whose embedding matched best.
+    pub component_type: ComponentType,
+    pub source: EmbeddingSource,
+    /// Cosine similarity of the best match, from -1 to 1.
+    pub similarity: f32,
+}
+
+/// Port for storing and comparing decision embeddings.
+#[async_trait]
+pub trait DecisionEmbeddingRepository: Send + Sync {
+    /// Stores an embedding, replacing any for the same cycle, component and source.
+    async fn upsert(&self, embedding: &DecisionEmbedding) -> Result<(), DomainError>;
+
+    /// Every embedding of a cycle.
+    async fn list_for_cycle(
+        &self,
+        cycle_id: &CycleId,
+    ) -> Result<Vec<DecisionEmbedding>, DomainError>;
+
+    /// Every embedding of a user's decisions.
+    async fn list_for_user(&self, user_id: &UserId) -> Result<Vec<DecisionEmbedding>, DomainError>;
+
+    /// The user's cycles closest to `embedding`, most similar first, with
+    /// each cycle listed once under its best-matching embedding.
+    ///
+    /// Only embeddings from `model` are compared; `exclude` leaves out the
+    /// cycle being compared against.
+    async fn find_similar(
+        &self,
+        user_id: &UserId,
+        embedding: &Embedding,
+        model: &str,
+        exclude: Option<CycleId>,
+        limit: u32,
+    ) -> Result<Vec<SimilarDecision>, DomainError>;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn source_round_trips() {
+        for source in [
+            EmbeddingSource::ComponentOutput,
+            EmbeddingSource::ConversationSummary,
+        ] {
+            assert_eq!(EmbeddingSource::parse(source.as_str()), Some(source));
+        }
+        assert_eq!(EmbeddingSource::parse("message"), None);
+    }
+}
@@ -0,0 +1,39 @@
+//! DecisionHistoryRepository port - Completed decisions feeding the decision
+//! profile.
+
+use async_trait::async_trait;
+
+use crate::domain::foundation::{CycleId, DomainError, UserId};
+use crate::domain::profile::DecisionRecord;
+
+/// Port for persisting a user's decision history.
+#[async_trait]
+pub trait DecisionHistoryRepository: Send + Sync {
+    /// Insert or replace the record for `record.cycle_id`.
+    async fn save(&self, user_id: &UserId, record: &DecisionRecord) -> Result<(), DomainError>;
+
+    /// Find the record for one decision.
+    async fn find_by_cycle(
+        &self,
+        user_id: &UserId,
+        cycle_id: &CycleId,
+    ) -> Result<Option<DecisionRecord>, DomainError>;
+
+    /// All of the user's decisions, oldest first.
+    async fn list_for_user(&self, user_id: &UserId) -> Result<Vec<DecisionRecord>, DomainError>;
+
+    /// Every user with at least one recorded decision.
+    async fn list_user_ids(&self) -> Result<Vec<UserId>, DomainError>;
+
+    /// Delete all of the user's decisions, returning how many were removed.
+    async fn delete_for_user(&self, user_id: &UserId) -> Result<u64, DomainError>;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Compile-time check that trait is object-safe
+    #[allow(dead_code)]
+    fn assert_object_safe(_: &dyn DecisionHistoryRepository) {}
+}
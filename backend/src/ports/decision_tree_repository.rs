@@ -0,0 +1,33 @@
+//! Decision tree repository port (write side).
+//!
+//! Trees are saved whole, nodes and branches included, so every change made
+//! through the aggregate is persisted atomically.
+
+use async_trait::async_trait;
+
+use crate::domain::decision_tree::DecisionTree;
+use crate::domain::foundation::{CycleId, DecisionTreeId, DomainError};
+
+/// Port for persisting decision trees.
+#[async_trait]
+pub trait DecisionTreeRepository: Send + Sync {
+    /// Insert or update a tree.
+    async fn save(&self, tree: &DecisionTree) -> Result<(), DomainError>;
+
+    async fn find_by_id(&self, id: &DecisionTreeId) -> Result<Option<DecisionTree>, DomainError>;
+
+    /// Trees built for a cycle, oldest first.
+    async fn list_for_cycle(&self, cycle_id: &CycleId) -> Result<Vec<DecisionTree>, DomainError>;
+
+    async fn delete(&self, id: &DecisionTreeId) -> Result<(), DomainError>;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn decision_tree_repository_is_object_safe() {
+        fn _accepts_dyn(_repo: &dyn DecisionTreeRepository) {}
+    }
+}
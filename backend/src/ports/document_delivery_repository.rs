@@ -0,0 +1,61 @@
+//! Document delivery ports - Opt-in preferences and the delivery queue for
+//! emailing completed decision documents.
+//!
+//! Pending deliveries double as the retry queue: workers poll `list_due` and
+//! save each delivery back after an attempt.
+
+use async_trait::async_trait;
+
+use crate::domain::document::{DocumentDelivery, DocumentEmailPreference};
+use crate::domain::foundation::{CycleId, DocumentDeliveryId, DomainError, Timestamp, UserId};
+
+/// Port for persisting document deliveries.
+#[async_trait]
+pub trait DocumentDeliveryRepository: Send + Sync {
+    /// Insert or update a delivery.
+    async fn save(&self, delivery: &DocumentDelivery) -> Result<(), DomainError>;
+
+    /// Find a delivery by ID.
+    async fn find_by_id(
+        &self,
+        id: &DocumentDeliveryId,
+    ) -> Result<Option<DocumentDelivery>, DomainError>;
+
+    /// A user's deliveries for one cycle, oldest first.
+    async fn list_for_cycle(
+        &self,
+        cycle_id: &CycleId,
+        user_id: &UserId,
+    ) -> Result<Vec<DocumentDelivery>, DomainError>;
+
+    /// Pending deliveries whose next attempt is at or before `now`, oldest
+    /// first.
+    async fn list_due(
+        &self,
+        now: Timestamp,
+        limit: u32,
+    ) -> Result<Vec<DocumentDelivery>, DomainError>;
+}
+
+/// Port for persisting users' document email preferences.
+#[async_trait]
+pub trait DocumentEmailPreferenceRepository: Send + Sync {
+    /// The user's preference, if they have ever set one.
+    async fn get(&self, user_id: &UserId) -> Result<Option<DocumentEmailPreference>, DomainError>;
+
+    /// Insert or replace the user's preference.
+    async fn set(&self, preference: &DocumentEmailPreference) -> Result<(), DomainError>;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Compile-time check that traits are object-safe
+    #[allow(dead_code)]
+    fn assert_object_safe(
+        _: &dyn DocumentDeliveryRepository,
+        _: &dyn DocumentEmailPreferenceRepository,
+    ) {
+    }
+}
@@ -0,0 +1,185 @@
+//! Document Exporter Port - Renders the decision document into a file format.
+//!
+//! The decision document is the dashboard overview of one cycle plus the
+//! session's cycle tree and the files attached to the cycle. Each exporter renders it into one format; the export
+//! handler picks the exporter matching the requested format.
+//!
+//! ```text
+//! DashboardReader ──overview──┐
+//!                             ├─► DecisionDocument ──► DocumentExporter ──► ExportedDocument
+//! CycleReader ──cycle tree────┤
+//! AttachmentRepository ───────┘
+//! ```
+
+use std::fmt;
+use std::str::FromStr;
+
+use async_trait::async_trait;
+
+use crate::domain::dashboard::DashboardOverview;
+use crate::domain::document::Attachment;
+use crate::domain::foundation::{CycleId, Timestamp};
+use crate::domain::profile::ReadingLevel;
+
+use super::CycleTreeNode;
+
+/// Errors that can occur while exporting a document.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum DocumentExportError {
+    #[error("Unsupported export format: {0}")]
+    UnsupportedFormat(String),
+
+    #[error("Rendering failed: {0}")]
+    Render(String),
+}
+
+/// File formats the decision document can be exported to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ExportFormat {
+    Pdf,
+    /// Stakeholder slide deck as a self-contained reveal.js HTML page.
+    Slides,
+    /// Markdown rendered from the organization's document template.
+    Markdown,
+    /// Read-only standalone web page, used for published documents.
+    Html,
+}
+
+impl ExportFormat {
+    /// Query-string name of the format.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ExportFormat::Pdf => "pdf",
+            ExportFormat::Slides => "slides",
+            ExportFormat::Markdown => "markdown",
+            ExportFormat::Html => "html",
+        }
+    }
+
+    /// MIME type of the rendered file.
+    pub fn content_type(&self) -> &'static str {
+        match self {
+            ExportFormat::Pdf => "application/pdf",
+            ExportFormat::Slides => "text/html; charset=utf-8",
+            ExportFormat::Markdown => "text/markdown; charset=utf-8",
+            ExportFormat::Html => "text/html; charset=utf-8",
+        }
+    }
+
+    /// File extension, without the dot.
+    pub fn extension(&self) -> &'static str {
+        match self {
+            ExportFormat::Pdf => "pdf",
+            ExportFormat::Slides => "html",
+            ExportFormat::Markdown => "md",
+            ExportFormat::Html => "html",
+        }
+    }
+}
+
+impl fmt::Display for ExportFormat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl FromStr for ExportFormat {
+    type Err = DocumentExportError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "pdf" => Ok(ExportFormat::Pdf),
+            "slides" => Ok(ExportFormat::Slides),
+            "markdown" | "md" => Ok(ExportFormat::Markdown),
+            "html" => Ok(ExportFormat::Html),
+            other => Err(DocumentExportError::UnsupportedFormat(other.to_string())),
+        }
+    }
+}
+
+/// Everything an exporter needs to render one cycle's decision document.
+#[derive(Debug, Clone)]
+pub struct DecisionDocument {
+    /// Cycle the document describes.
+    pub cycle_id: CycleId,
+
+    /// Aggregated component outputs for the cycle.
+    pub overview: DashboardOverview,
+
+    /// All cycles in the session, rendered as an appendix.
+    pub cycle_tree: Option<CycleTreeNode>,
+
+    /// Organization whose document template applies, if known.
+    pub organization: Option<String>,
+
+    /// Files attached to the cycle's components, oldest first.
+    pub attachments: Vec<DocumentAttachment>,
+
+    /// When the export was requested.
+    pub generated_at: Timestamp,
+
+    /// The reader's chosen reading level; exporters that support it write
+    /// their own text in plain language when it is not `Standard`.
+    pub reading_level: ReadingLevel,
+}
+
+/// An attachment as seen by exporters.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DocumentAttachment {
+    pub attachment: Attachment,
+
+    /// File bytes for images, so formats that can embed them do. `None` for
+    /// other files and for images that could not be loaded, which exporters
+    /// list by name instead.
+    pub image: Option<Vec<u8>>,
+}
+
+/// A rendered document ready to be returned or stored.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExportedDocument {
+    pub format: ExportFormat,
+
+    /// Suggested download file name.
+    pub file_name: String,
+
+    /// Raw file bytes.
+    pub bytes: Vec<u8>,
+}
+
+/// Port for rendering the decision document into one file format.
+#[async_trait]
+pub trait DocumentExporter: Send + Sync {
+    /// Format this exporter produces.
+    fn format(&self) -> ExportFormat;
+
+    /// Render the document.
+    async fn export(
+        &self,
+        document: &DecisionDocument,
+    ) -> Result<ExportedDocument, DocumentExportError>;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_format_case_insensitively() {
+        assert_eq!("pdf".parse::<ExportFormat>().unwrap(), ExportFormat::Pdf);
+        assert_eq!("PDF".parse::<ExportFormat>().unwrap(), ExportFormat::Pdf);
+        assert_eq!(
+            "Slides".parse::<ExportFormat>().unwrap(),
+            ExportFormat::Slides
+        );
+        assert_eq!("md".parse::<ExportFormat>().unwrap(), ExportFormat::Markdown);
+        assert_eq!(
+            "docx".parse::<ExportFormat>().unwrap_err(),
+            DocumentExportError::UnsupportedFormat("docx".to_string())
+        );
+    }
+
+    #[test]
+    fn document_exporter_is_object_safe() {
+        fn _accepts_dyn(_exporter: &dyn DocumentExporter) {}
+    }
+}
@@ -0,0 +1,71 @@
+//! Document Publication Ports - Public, read-only snapshots shared by link.
+//!
+//! Publishing stores a `DocumentPublication` and hands the owner a link
+//! whose token is signed by a [`PublicLinkSigner`]. Serving the link checks
+//! the signature and expiry on the token, then revocation on the stored
+//! publication.
+//!
+//! ```text
+//! Publish handler ──save()──► DocumentPublicationRepository
+//!        └──issue(id, expires_at)──► PublicLinkSigner ──► URL ──► stakeholder
+//!
+//! GET /public/documents/{token} ──verify(token)──► PublicationId ──find_by_id()──► HTML
+//! ```
+
+use async_trait::async_trait;
+
+use crate::domain::document::DocumentPublication;
+use crate::domain::foundation::{CycleId, DomainError, PublicationId, Timestamp};
+
+/// Reasons a public link is refused.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum PublicLinkError {
+    #[error("Malformed link")]
+    Malformed,
+
+    #[error("Invalid link signature")]
+    InvalidSignature,
+
+    #[error("Link has expired")]
+    Expired,
+}
+
+/// Issues and verifies tamper-proof public document links.
+pub trait PublicLinkSigner: Send + Sync {
+    /// Full public URL for a publication, valid until `expires_at`.
+    fn issue(&self, id: &PublicationId, expires_at: Timestamp) -> String;
+
+    /// Checks a token from a public URL and returns the publication it names.
+    fn verify(&self, token: &str) -> Result<PublicationId, PublicLinkError>;
+}
+
+/// Port for persisting published document snapshots.
+#[async_trait]
+pub trait DocumentPublicationRepository: Send + Sync {
+    /// Save a new publication.
+    async fn save(&self, publication: &DocumentPublication) -> Result<(), DomainError>;
+
+    /// Store a publication's revocation.
+    async fn update(&self, publication: &DocumentPublication) -> Result<(), DomainError>;
+
+    /// Find a publication by ID.
+    async fn find_by_id(
+        &self,
+        id: &PublicationId,
+    ) -> Result<Option<DocumentPublication>, DomainError>;
+
+    /// A cycle's publications, newest first.
+    async fn list_by_cycle(
+        &self,
+        cycle_id: &CycleId,
+    ) -> Result<Vec<DocumentPublication>, DomainError>;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Compile-time check that traits are object-safe
+    #[allow(dead_code)]
+    fn assert_object_safe(_: &dyn DocumentPublicationRepository, _: &dyn PublicLinkSigner) {}
+}
@@ -0,0 +1,194 @@
+//! Document Storage Port - Interface for storing exported decision documents.
+//!
+//! Exports (markdown, PDF, slide decks) and shared documents are written to a
+//! blob store keyed by path. Backends that can hand out direct, time-limited
+//! download links expose the optional [`SignedUrlIssuer`] capability, so large
+//! files are fetched from storage instead of being proxied through the API.
+//!
+//! ```text
+//! Export handler ──put()──► DocumentStorage
+//!        │
+//!        └──signed_url_issuer()?.issue(key, ttl)──► SignedUrl ──► client
+//! ```
+
+use std::time::Duration;
+
+use async_trait::async_trait;
+
+use crate::domain::foundation::Timestamp;
+
+/// Default lifetime for signed download URLs.
+pub const DEFAULT_SIGNED_URL_TTL: Duration = Duration::from_secs(15 * 60);
+
+/// Maximum lifetime a signed URL may be issued for.
+pub const MAX_SIGNED_URL_TTL: Duration = Duration::from_secs(7 * 24 * 60 * 60);
+
+/// Errors that can occur during document storage operations.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum DocumentStorageError {
+    #[error("Document not found: {0}")]
+    NotFound(String),
+
+    #[error("Invalid document key: {0}")]
+    InvalidKey(String),
+
+    #[error("Signed URL has expired")]
+    UrlExpired,
+
+    #[error("Signed URL signature is invalid")]
+    InvalidSignature,
+
+    #[error("Requested URL lifetime exceeds maximum of {max_secs} seconds")]
+    TtlTooLong { max_secs: u64 },
+
+    #[error("Storage error: {0}")]
+    Storage(String),
+}
+
+impl DocumentStorageError {
+    /// Returns true if the error was caused by a bad or stale link.
+    pub fn is_link_error(&self) -> bool {
+        matches!(
+            self,
+            DocumentStorageError::UrlExpired | DocumentStorageError::InvalidSignature
+        )
+    }
+}
+
+/// A document retrieved from storage.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StoredDocument {
+    /// Storage key (e.g., "exports/{cycle_id}/decision.pdf").
+    pub key: String,
+
+    /// MIME type recorded at upload time.
+    pub content_type: String,
+
+    /// Raw document bytes.
+    pub bytes: Vec<u8>,
+}
+
+impl StoredDocument {
+    /// Size of the document in bytes.
+    pub fn size(&self) -> usize {
+        self.bytes.len()
+    }
+}
+
+/// A short-lived URL granting read access to one stored document.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SignedUrl {
+    /// Fully-qualified download URL.
+    pub url: String,
+
+    /// When the URL stops working.
+    pub expires_at: Timestamp,
+}
+
+/// Port for storing and retrieving exported documents.
+#[async_trait]
+pub trait DocumentStorage: Send + Sync {
+    /// Store a document under `key`, replacing any existing content.
+    async fn put(
+        &self,
+        key: &str,
+        content_type: &str,
+        bytes: Vec<u8>,
+    ) -> Result<(), DocumentStorageError>;
+
+    /// Load a document by key.
+    ///
+    /// # Errors
+    /// Returns `DocumentStorageError::NotFound` if nothing is stored under `key`.
+    async fn get(&self, key: &str) -> Result<StoredDocument, DocumentStorageError>;
+
+    /// Delete a document. Deleting a missing key is not an error.
+    async fn delete(&self, key: &str) -> Result<(), DocumentStorageError>;
+
+    /// Check whether a document exists.
+    async fn exists(&self, key: &str) -> Result<bool, DocumentStorageError>;
+
+    /// Keys of every document whose key starts with `prefix`, sorted. An
+    /// empty prefix lists everything.
+    async fn list(&self, prefix: &str) -> Result<Vec<String>, DocumentStorageError>;
+
+    /// Signed URL capability, if this backend supports it.
+    ///
+    /// Callers should fall back to streaming the document through the API
+    /// when this returns `None`.
+    fn signed_url_issuer(&self) -> Option<&dyn SignedUrlIssuer> {
+        None
+    }
+}
+
+/// Capability for issuing short-lived, tamper-proof download URLs.
+pub trait SignedUrlIssuer: Send + Sync {
+    /// Issue a URL granting read access to `key` for `ttl`.
+    ///
+    /// # Errors
+    /// Returns `DocumentStorageError::TtlTooLong` if `ttl` exceeds
+    /// [`MAX_SIGNED_URL_TTL`].
+    fn issue(&self, key: &str, ttl: Duration) -> Result<SignedUrl, DocumentStorageError>;
+}
+
+/// Validate a document key before it reaches a backend.
+///
+/// Keys are relative, slash-separated paths without traversal segments or
+/// control characters. Signed URLs sign the key as one line of the message,
+/// so a newline in it could forge a different message.
+pub fn validate_document_key(key: &str) -> Result<(), DocumentStorageError> {
+    let invalid = key.is_empty()
+        || key.starts_with('/')
+        || key.contains('\\')
+        || key.chars().any(char::is_control)
+        || key.split('/').any(|segment| segment.is_empty() || segment == "." || segment == "..");
+
+    if invalid {
+        return Err(DocumentStorageError::InvalidKey(key.to_string()));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn valid_keys_are_accepted() {
+        assert!(validate_document_key("exports/abc/decision.pdf").is_ok());
+        assert!(validate_document_key("decision.md").is_ok());
+    }
+
+    #[test]
+    fn traversal_and_absolute_keys_are_rejected() {
+        assert!(validate_document_key("").is_err());
+        assert!(validate_document_key("/etc/passwd").is_err());
+        assert!(validate_document_key("exports/../secrets").is_err());
+        assert!(validate_document_key("exports//double").is_err());
+        assert!(validate_document_key("exports\\windows").is_err());
+    }
+
+    #[test]
+    fn control_characters_are_rejected() {
+        assert!(validate_document_key("exports/a.pdf\n1700000000").is_err());
+        assert!(validate_document_key("exports/a\r.pdf").is_err());
+        assert!(validate_document_key("exports/a\0.pdf").is_err());
+    }
+
+    #[test]
+    fn link_errors_are_classified() {
+        assert!(DocumentStorageError::UrlExpired.is_link_error());
+        assert!(DocumentStorageError::InvalidSignature.is_link_error());
+        assert!(!DocumentStorageError::NotFound("k".to_string()).is_link_error());
+    }
+
+    #[test]
+    fn stored_document_reports_size() {
+        let doc = StoredDocument {
+            key: "a.md".to_string(),
+            content_type: "text/markdown".to_string(),
+            bytes: b"# Title".to_vec(),
+        };
+        assert_eq!(doc.size(), 7);
+    }
+}
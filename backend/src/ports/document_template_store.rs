@@ -0,0 +1,112 @@
+//! Document Template Store Port - Per-organization layouts for exported documents.
+//!
+//! Enterprise customers can replace the default Markdown layout of the
+//! decision document with their own Tera template so exports match their
+//! report house style. Templates are keyed by organization id; a user gets
+//! the template of the organization they belong to.
+//!
+//! Stores only persist templates; validation and sandboxed rendering live in
+//! the template exporter, which callers must run before `save`.
+
+use async_trait::async_trait;
+
+use crate::domain::foundation::Timestamp;
+
+/// Maximum length of an organization key.
+pub const MAX_ORGANIZATION_LENGTH: usize = 253;
+
+/// Errors from document template storage.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum DocumentTemplateError {
+    #[error("Invalid organization: {0}")]
+    InvalidOrganization(String),
+
+    #[error("Document templates are read-only: {0}")]
+    ReadOnly(String),
+
+    #[error("Document template storage error: {0}")]
+    Storage(String),
+}
+
+/// A stored document template.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DocumentTemplate {
+    /// Organization the template applies to.
+    pub organization: String,
+
+    /// Tera template source.
+    pub source: String,
+
+    pub updated_at: Timestamp,
+}
+
+impl DocumentTemplate {
+    pub fn new(organization: impl Into<String>, source: impl Into<String>) -> Self {
+        Self {
+            organization: organization.into(),
+            source: source.into(),
+            updated_at: Timestamp::now(),
+        }
+    }
+}
+
+/// Port for loading and saving per-organization document templates.
+#[async_trait]
+pub trait DocumentTemplateStore: Send + Sync {
+    /// Get the template for an organization, if one is configured.
+    async fn get(&self, organization: &str)
+        -> Result<Option<DocumentTemplate>, DocumentTemplateError>;
+
+    /// Create or replace an organization's template.
+    async fn save(&self, template: DocumentTemplate) -> Result<(), DocumentTemplateError>;
+
+    /// Remove an organization's template. Returns whether one existed.
+    async fn delete(&self, organization: &str) -> Result<bool, DocumentTemplateError>;
+}
+
+/// Check that an organization key is lowercase letters, digits, dots and
+/// dashes, as organization ids are.
+///
+/// Keys double as file names in the file-backed store, so anything outside
+/// `[a-z0-9.-]` or starting with a dot is rejected.
+pub fn validate_organization(organization: &str) -> Result<(), DocumentTemplateError> {
+    let valid = !organization.is_empty()
+        && organization.len() <= MAX_ORGANIZATION_LENGTH
+        && organization
+            .chars()
+            .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '.' || c == '-')
+        && organization
+            .chars()
+            .next()
+            .is_some_and(|c| c.is_ascii_alphanumeric());
+    if valid {
+        Ok(())
+    } else {
+        Err(DocumentTemplateError::InvalidOrganization(
+            organization.to_string(),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::foundation::OrganizationId;
+
+    #[test]
+    fn organization_keys_are_dns_names() {
+        assert!(validate_organization("acme.com").is_ok());
+        assert!(validate_organization(&OrganizationId::new().to_string()).is_ok());
+        assert!(validate_organization("eu-west.acme.co.uk").is_ok());
+        assert!(validate_organization("").is_err());
+        assert!(validate_organization("Acme.com").is_err());
+        assert!(validate_organization("../etc").is_err());
+        assert!(validate_organization(".hidden").is_err());
+        assert!(validate_organization("acme.com/x").is_err());
+    }
+
+    #[test]
+    fn document_template_store_is_object_safe() {
+        fn _accepts_dyn(_store: &dyn DocumentTemplateStore) {}
+    }
+}
@@ -0,0 +1,39 @@
+//! DocumentVersionRepository port - History of a cycle's decision document.
+//!
+//! Each stored version is the full Markdown source; diffs are computed on
+//! read with `VersionDiff::between`.
+
+use async_trait::async_trait;
+
+use crate::domain::document::DocumentVersion;
+use crate::domain::foundation::{CycleId, DomainError};
+
+/// Port for persisting document versions.
+#[async_trait]
+pub trait DocumentVersionRepository: Send + Sync {
+    /// Save a new version.
+    ///
+    /// # Errors
+    ///
+    /// - `DatabaseError` if the cycle already has a version with this number
+    async fn save(&self, version: &DocumentVersion) -> Result<(), DomainError>;
+
+    /// Find one version of a cycle's document.
+    async fn find(
+        &self,
+        cycle_id: &CycleId,
+        version: u32,
+    ) -> Result<Option<DocumentVersion>, DomainError>;
+
+    /// The most recent version, if any have been saved.
+    async fn latest(&self, cycle_id: &CycleId) -> Result<Option<DocumentVersion>, DomainError>;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Compile-time check that trait is object-safe
+    #[allow(dead_code)]
+    fn assert_object_safe(_: &dyn DocumentVersionRepository) {}
+}
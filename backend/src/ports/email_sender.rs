@@ -0,0 +1,68 @@
+//! EmailSender port - Outbound transactional email.
+//!
+//! Implementations deliver one message at a time. Errors say whether a retry
+//! could succeed so callers can decide between backing off and giving up.
+
+use async_trait::async_trait;
+
+/// A file attached to an email.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EmailAttachment {
+    pub file_name: String,
+    pub content_type: String,
+    pub bytes: Vec<u8>,
+}
+
+/// An email ready to send.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EmailMessage {
+    pub to: String,
+    pub subject: String,
+    /// Plain-text body.
+    pub text_body: String,
+    pub attachments: Vec<EmailAttachment>,
+}
+
+/// Errors that can occur when sending email.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum EmailError {
+    /// The provider refused the message (bad address, policy); retrying
+    /// will not help.
+    #[error("Email rejected: {0}")]
+    Rejected(String),
+
+    /// The provider could not be reached or is throttling.
+    #[error("Email provider unavailable: {0}")]
+    Unavailable(String),
+}
+
+impl EmailError {
+    /// Whether sending the same message again later could succeed.
+    pub fn is_retryable(&self) -> bool {
+        matches!(self, EmailError::Unavailable(_))
+    }
+}
+
+/// Port for sending email.
+#[async_trait]
+pub trait EmailSender: Send + Sync {
+    async fn send(&self, message: &EmailMessage) -> Result<(), EmailError>;
+
+    /// Provider name for logging.
+    fn name(&self) -> &'static str;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Compile-time check that trait is object-safe
+    #[allow(dead_code)]
+    fn assert_object_safe(_: &dyn EmailSender) {}
+
+    #[test]
+    fn only_unavailable_errors_are_retryable() {
+        assert!(EmailError::Unavailable("timeout".to_string()).is_retryable());
+        assert!(!EmailError::Rejected("no such mailbox".to_string()).is_retryable());
+    }
+}
@@ -0,0 +1,187 @@
+//! EmailTemplateRenderer port - Localized transactional email content.
+//!
+//! Each [`EmailTemplateKind`] has a fixed set of variables. Renderers pick
+//! the closest locale variant they have (`pt-BR`, then `pt`, then
+//! [`DEFAULT_EMAIL_LOCALE`]) so callers can pass whatever the user asked for.
+
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value as JsonValue};
+
+/// Locale used when no variant matches the requested one.
+pub const DEFAULT_EMAIL_LOCALE: &str = "en";
+
+/// The transactional emails Choice Sherpa sends.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum EmailTemplateKind {
+    /// First email after sign-up.
+    Welcome,
+    /// A subscription payment failed.
+    Dunning,
+    /// A decision cycle finished; the document is attached.
+    CycleCompleted,
+    /// Outcome survey for a past decision.
+    OutcomeReminder,
+    /// Someone shared a decision with the recipient.
+    ShareInvitation,
+    /// An idle session is about to be archived automatically.
+    ArchiveWarning,
+}
+
+impl EmailTemplateKind {
+    pub const ALL: [EmailTemplateKind; 6] = [
+        EmailTemplateKind::Welcome,
+        EmailTemplateKind::Dunning,
+        EmailTemplateKind::CycleCompleted,
+        EmailTemplateKind::OutcomeReminder,
+        EmailTemplateKind::ShareInvitation,
+        EmailTemplateKind::ArchiveWarning,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            EmailTemplateKind::Welcome => "welcome",
+            EmailTemplateKind::Dunning => "dunning",
+            EmailTemplateKind::CycleCompleted => "cycle_completed",
+            EmailTemplateKind::OutcomeReminder => "outcome_reminder",
+            EmailTemplateKind::ShareInvitation => "share_invitation",
+            EmailTemplateKind::ArchiveWarning => "archive_warning",
+        }
+    }
+
+    pub fn parse(s: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|kind| kind.as_str() == s)
+    }
+
+    /// Example variables for previews and snapshot tests.
+    pub fn sample_context(&self) -> JsonValue {
+        match self {
+            EmailTemplateKind::Welcome => json!({
+                "name": "Jo",
+                "app_url": "https://app.choicesherpa.com",
+            }),
+            EmailTemplateKind::Dunning => json!({
+                "name": "Jo",
+                "plan": "Monthly",
+                "amount": "$19.99",
+                "retry_date": "2026-03-08",
+                "billing_url": "https://app.choicesherpa.com/settings/billing",
+            }),
+            EmailTemplateKind::CycleCompleted => json!({
+                "decision_title": "Which job offer to accept",
+            }),
+            EmailTemplateKind::OutcomeReminder => json!({
+                "decision_title": "Which job offer to accept",
+                "valid_days": 30,
+                "links": (1..=5)
+                    .rev()
+                    .map(|score| json!({
+                        "score": score,
+                        "url": format!(
+                            "https://api.choicesherpa.com/public/outcome-survey/token?satisfaction={}",
+                            score
+                        ),
+                    }))
+                    .collect::<Vec<_>>(),
+            }),
+            EmailTemplateKind::ShareInvitation => json!({
+                "inviter_name": "Sam",
+                "decision_title": "Which job offer to accept",
+                "share_url": "https://app.choicesherpa.com/shared/abc123",
+            }),
+            EmailTemplateKind::ArchiveWarning => json!({
+                "session_title": "Where to move next year",
+                "idle_days": 180,
+                "archive_on": "2026-03-22",
+                "session_url": "https://app.choicesherpa.com/sessions/abc123",
+            }),
+        }
+    }
+}
+
+impl std::fmt::Display for EmailTemplateKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Subject and body produced from a template.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct RenderedEmail {
+    /// Locale variant that was used.
+    pub locale: String,
+    pub subject: String,
+    /// Plain-text body.
+    pub text_body: String,
+}
+
+/// Errors from rendering an email template.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum EmailTemplateError {
+    /// The context is missing a variable or has the wrong shape.
+    #[error("Email template rendering failed: {0}")]
+    Render(String),
+
+    /// The template did not produce a subject line.
+    #[error("Email template has no subject line")]
+    MissingSubject,
+}
+
+/// Port for rendering transactional emails.
+pub trait EmailTemplateRenderer: Send + Sync {
+    /// Renders `kind` in the locale closest to `locale`.
+    fn render(
+        &self,
+        kind: EmailTemplateKind,
+        locale: &str,
+        context: &JsonValue,
+    ) -> Result<RenderedEmail, EmailTemplateError>;
+
+    /// Locales with templates, default first.
+    fn locales(&self) -> Vec<String>;
+}
+
+/// Candidate locales for `requested`, most specific first: `pt-BR`, `pt`,
+/// then [`DEFAULT_EMAIL_LOCALE`]. Tags are lower-cased and `_` is read as `-`.
+pub fn locale_fallbacks(requested: &str) -> Vec<String> {
+    let requested = requested.trim().replace('_', "-").to_ascii_lowercase();
+    let mut candidates = Vec::new();
+    if !requested.is_empty() {
+        candidates.push(requested.clone());
+        if let Some((language, _)) = requested.split_once('-') {
+            candidates.push(language.to_string());
+        }
+    }
+    if !candidates.iter().any(|c| c == DEFAULT_EMAIL_LOCALE) {
+        candidates.push(DEFAULT_EMAIL_LOCALE.to_string());
+    }
+    candidates
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Compile-time check that trait is object-safe
+    #[allow(dead_code)]
+    fn assert_object_safe(_: &dyn EmailTemplateRenderer) {}
+
+    #[test]
+    fn kinds_round_trip_through_their_names() {
+        for kind in EmailTemplateKind::ALL {
+            assert_eq!(EmailTemplateKind::parse(kind.as_str()), Some(kind));
+            assert_eq!(
+                serde_json::to_value(kind).unwrap(),
+                serde_json::Value::String(kind.as_str().to_string())
+            );
+        }
+        assert_eq!(EmailTemplateKind::parse("newsletter"), None);
+    }
+
+    #[test]
+    fn locale_fallbacks_narrow_to_language_then_default() {
+        assert_eq!(locale_fallbacks("pt_BR"), vec!["pt-br", "pt", "en"]);
+        assert_eq!(locale_fallbacks("en-GB"), vec!["en-gb", "en"]);
+        assert_eq!(locale_fallbacks(""), vec!["en"]);
+    }
+}
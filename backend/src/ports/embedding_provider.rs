@@ -0,0 +1,53 @@
+//! Embedding Provider Port - Vector embeddings of text for semantic search.
+//!
+//! Embeddings place texts with similar meaning close together, so decisions
+//! can be matched on what they are about rather than on shared keywords.
+//! Stored vectors are tagged with the model that produced them; vectors from
+//! different models are never compared.
+
+use async_trait::async_trait;
+
+use crate::domain::foundation::Embedding;
+
+use super::ai_provider::AIError;
+
+/// Dimensions of every stored embedding, matching the `vector` column.
+///
+/// Providers must return (or be configured to return) vectors of this size.
+pub const EMBEDDING_DIMENSIONS: usize = 1536;
+
+/// Most characters of one input sent for embedding; longer texts are cut.
+pub const MAX_EMBEDDING_INPUT_CHARS: usize = 8000;
+
+/// Port for turning text into embeddings.
+#[async_trait]
+pub trait EmbeddingProvider: Send + Sync {
+    /// Embeds each input, returning one embedding per input in order.
+    async fn embed(&self, inputs: &[String]) -> Result<Vec<Embedding>, AIError>;
+
+    /// Name of the model, stored alongside each vector.
+    fn model(&self) -> &str;
+}
+
+/// Cuts `text` to at most [`MAX_EMBEDDING_INPUT_CHARS`] characters.
+pub fn truncate_embedding_input(text: &str) -> &str {
+    match text.char_indices().nth(MAX_EMBEDDING_INPUT_CHARS) {
+        Some((end, _)) => &text[..end],
+        None => text,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn truncates_long_input_on_a_char_boundary() {
+        let long = "é".repeat(MAX_EMBEDDING_INPUT_CHARS + 10);
+
+        let truncated = truncate_embedding_input(&long);
+
+        assert_eq!(truncated.chars().count(), MAX_EMBEDDING_INPUT_CHARS);
+        assert_eq!(truncate_embedding_input("short"), "short");
+    }
+}
@@ -0,0 +1,404 @@
+//! Feature Flag Port - Runtime-reloadable feature flags with targeting.
+//!
+//! `config::FeatureFlags` is fixed at boot. This port exposes flags that can
+//! be flipped at runtime and targeted per membership tier or per user.
+//!
+//! Providers publish the current flag set on a `tokio::sync::watch` channel,
+//! so consumers always read the latest values without polling or restarts:
+//!
+//! ```ignore
+//! let flags = provider.subscribe();
+//! let ctx = FlagContext::for_user(user.id.clone()).with_tier(tier);
+//! if flags.borrow().is_enabled("enable_streaming", &ctx) {
+//!     // ...
+//! }
+//! ```
+//!
+//! Evaluation precedence: user override > tier override > default. A
+//! rollout percentage narrows an enabled default to a stable subset of users,
+//! so gradual rollouts never flip back and forth for the same person.
+//! Unknown flags evaluate to `false`.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use tokio::sync::watch;
+
+use crate::domain::foundation::{Timestamp, UserId};
+use crate::domain::membership::MembershipTier;
+
+/// Maximum length of a flag key.
+pub const MAX_FLAG_KEY_LENGTH: usize = 64;
+
+/// Errors from feature flag operations.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum FeatureFlagError {
+    #[error("Invalid flag key: {0}")]
+    InvalidKey(String),
+
+    #[error("Feature flag not found: {0}")]
+    NotFound(String),
+
+    #[error("Rollout percentage must be between 0 and 100, got {0}")]
+    InvalidRollout(u8),
+
+    #[error("Feature flags are read-only: {0}")]
+    ReadOnly(String),
+
+    #[error("Feature flag storage error: {0}")]
+    Storage(String),
+}
+
+/// A single feature flag with targeting rules.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FeatureFlag {
+    /// Unique key (lowercase letters, digits, `_`, `-`, `.`).
+    pub key: String,
+
+    /// Human-readable purpose of the flag.
+    pub description: Option<String>,
+
+    /// Value when no override matches.
+    pub enabled: bool,
+
+    /// Per-tier overrides.
+    #[serde(default)]
+    pub tier_overrides: HashMap<MembershipTier, bool>,
+
+    /// Per-user overrides, keyed by user ID.
+    #[serde(default)]
+    pub user_overrides: HashMap<String, bool>,
+
+    /// Percentage of users (0-100) who receive an enabled default.
+    /// `None` means everyone.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rollout_percentage: Option<u8>,
+
+    /// When the flag was last changed.
+    pub updated_at: Timestamp,
+}
+
+impl FeatureFlag {
+    /// Creates a flag with no overrides.
+    ///
+    /// # Errors
+    /// Returns `FeatureFlagError::InvalidKey` if the key is empty, too long,
+    /// or contains characters other than `[a-z0-9_.-]`.
+    pub fn new(key: impl Into<String>, enabled: bool) -> Result<Self, FeatureFlagError> {
+        let key = key.into();
+        validate_flag_key(&key)?;
+        Ok(Self {
+            key,
+            description: None,
+            enabled,
+            tier_overrides: HashMap::new(),
+            user_overrides: HashMap::new(),
+            rollout_percentage: None,
+            updated_at: Timestamp::now(),
+        })
+    }
+
+    /// Sets the description.
+    pub fn with_description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    /// Overrides the value for a membership tier.
+    pub fn with_tier_override(mut self, tier: MembershipTier, enabled: bool) -> Self {
+        self.tier_overrides.insert(tier, enabled);
+        self
+    }
+
+    /// Overrides the value for a single user.
+    pub fn with_user_override(mut self, user_id: &UserId, enabled: bool) -> Self {
+        self.user_overrides.insert(user_id.to_string(), enabled);
+        self
+    }
+
+    /// Limits an enabled default to `percentage` of users.
+    ///
+    /// # Errors
+    /// Returns `FeatureFlagError::InvalidRollout` if `percentage` exceeds 100.
+    pub fn with_rollout(mut self, percentage: u8) -> Result<Self, FeatureFlagError> {
+        validate_rollout(percentage)?;
+        self.rollout_percentage = Some(percentage);
+        Ok(self)
+    }
+
+    /// Evaluates the flag for a context.
+    pub fn evaluate(&self, ctx: &FlagContext) -> bool {
+        if let Some(enabled) = ctx
+            .user_id
+            .as_ref()
+            .and_then(|user_id| self.user_overrides.get(user_id.as_str()))
+        {
+            return *enabled;
+        }
+        if let Some(enabled) = ctx.tier.and_then(|tier| self.tier_overrides.get(&tier)) {
+            return *enabled;
+        }
+        match self.rollout_percentage {
+            Some(percentage) if self.enabled && percentage < 100 => ctx
+                .user_id
+                .as_ref()
+                .is_some_and(|user_id| rollout_bucket(&self.key, user_id) < percentage),
+            _ => self.enabled,
+        }
+    }
+}
+
+/// Who a flag is being evaluated for.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct FlagContext {
+    pub user_id: Option<UserId>,
+    pub tier: Option<MembershipTier>,
+}
+
+impl FlagContext {
+    /// Context with no user or tier (only defaults apply).
+    pub fn anonymous() -> Self {
+        Self::default()
+    }
+
+    /// Context for a specific user.
+    pub fn for_user(user_id: UserId) -> Self {
+        Self {
+            user_id: Some(user_id),
+            tier: None,
+        }
+    }
+
+    /// Adds the user's membership tier.
+    pub fn with_tier(mut self, tier: MembershipTier) -> Self {
+        self.tier = Some(tier);
+        self
+    }
+}
+
+/// Immutable snapshot of all flags.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct FeatureFlagSet {
+    flags: HashMap<String, FeatureFlag>,
+}
+
+impl FeatureFlagSet {
+    /// Builds a set from flags. Later flags replace earlier ones with the same key.
+    pub fn new(flags: impl IntoIterator<Item = FeatureFlag>) -> Self {
+        Self {
+            flags: flags.into_iter().map(|flag| (flag.key.clone(), flag)).collect(),
+        }
+    }
+
+    /// Evaluates a flag; unknown keys are disabled.
+    pub fn is_enabled(&self, key: &str, ctx: &FlagContext) -> bool {
+        self.flags.get(key).is_some_and(|flag| flag.evaluate(ctx))
+    }
+
+    /// Looks up a flag definition.
+    pub fn get(&self, key: &str) -> Option<&FeatureFlag> {
+        self.flags.get(key)
+    }
+
+    /// All flags, sorted by key.
+    pub fn flags(&self) -> Vec<&FeatureFlag> {
+        let mut flags: Vec<_> = self.flags.values().collect();
+        flags.sort_by(|a, b| a.key.cmp(&b.key));
+        flags
+    }
+
+    /// Evaluates every flag for a context.
+    pub fn evaluate_all(&self, ctx: &FlagContext) -> HashMap<String, bool> {
+        self.flags
+            .iter()
+            .map(|(key, flag)| (key.clone(), flag.evaluate(ctx)))
+            .collect()
+    }
+
+    pub fn len(&self) -> usize {
+        self.flags.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.flags.is_empty()
+    }
+}
+
+/// Port for runtime feature flags.
+#[async_trait]
+pub trait FeatureFlagProvider: Send + Sync {
+    /// Current flag snapshot.
+    fn current(&self) -> Arc<FeatureFlagSet>;
+
+    /// Receiver that observes every flag change.
+    fn subscribe(&self) -> watch::Receiver<Arc<FeatureFlagSet>>;
+
+    /// Create or replace a flag. Subscribers see the change immediately.
+    async fn upsert(&self, flag: FeatureFlag) -> Result<(), FeatureFlagError>;
+
+    /// Delete a flag.
+    ///
+    /// # Errors
+    /// Returns `FeatureFlagError::NotFound` if the flag does not exist.
+    async fn remove(&self, key: &str) -> Result<(), FeatureFlagError>;
+
+    /// Reload flags from the backing store (picks up changes made by other
+    /// instances).
+    async fn refresh(&self) -> Result<(), FeatureFlagError>;
+
+    /// Convenience: evaluate one flag against the current snapshot.
+    fn is_enabled(&self, key: &str, ctx: &FlagContext) -> bool {
+        self.current().is_enabled(key, ctx)
+    }
+}
+
+/// Validate a flag key.
+pub fn validate_flag_key(key: &str) -> Result<(), FeatureFlagError> {
+    let valid = !key.is_empty()
+        && key.len() <= MAX_FLAG_KEY_LENGTH
+        && key
+            .chars()
+            .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || matches!(c, '_' | '-' | '.'));
+
+    if !valid {
+        return Err(FeatureFlagError::InvalidKey(key.to_string()));
+    }
+    Ok(())
+}
+
+/// Validate a rollout percentage.
+pub fn validate_rollout(percentage: u8) -> Result<(), FeatureFlagError> {
+    if percentage > 100 {
+        return Err(FeatureFlagError::InvalidRollout(percentage));
+    }
+    Ok(())
+}
+
+/// Stable bucket in `0..100` for a user and flag.
+///
+/// Hashing the flag key with the user ID keeps buckets independent across
+/// flags, so the same users are not always first into every rollout.
+pub fn rollout_bucket(key: &str, user_id: &UserId) -> u8 {
+    let digest = Sha256::new()
+        .chain_update(key.as_bytes())
+        .chain_update(b":")
+        .chain_update(user_id.as_str().as_bytes())
+        .finalize();
+    let value = u32::from_be_bytes([digest[0], digest[1], digest[2], digest[3]]);
+    (value % 100) as u8
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn user(id: &str) -> UserId {
+        UserId::new(id).unwrap()
+    }
+
+    #[test]
+    fn rejects_invalid_keys() {
+        assert!(FeatureFlag::new("", true).is_err());
+        assert!(FeatureFlag::new("Has Spaces", true).is_err());
+        assert!(FeatureFlag::new("a".repeat(65), true).is_err());
+        assert!(FeatureFlag::new("ai.streaming_v2", true).is_ok());
+    }
+
+    #[test]
+    fn default_applies_without_overrides() {
+        let flag = FeatureFlag::new("streaming", true).unwrap();
+        assert!(flag.evaluate(&FlagContext::anonymous()));
+    }
+
+    #[test]
+    fn tier_override_beats_default() {
+        let flag = FeatureFlag::new("export", false)
+            .unwrap()
+            .with_tier_override(MembershipTier::Annual, true);
+
+        let annual = FlagContext::for_user(user("u1")).with_tier(MembershipTier::Annual);
+        let free = FlagContext::for_user(user("u1")).with_tier(MembershipTier::Free);
+        assert!(flag.evaluate(&annual));
+        assert!(!flag.evaluate(&free));
+    }
+
+    #[test]
+    fn user_override_beats_tier() {
+        let flag = FeatureFlag::new("export", false)
+            .unwrap()
+            .with_tier_override(MembershipTier::Annual, true)
+            .with_user_override(&user("u1"), false);
+
+        let ctx = FlagContext::for_user(user("u1")).with_tier(MembershipTier::Annual);
+        assert!(!flag.evaluate(&ctx));
+    }
+
+    #[test]
+    fn unknown_flags_are_disabled() {
+        let set = FeatureFlagSet::new(vec![FeatureFlag::new("a", true).unwrap()]);
+        assert!(set.is_enabled("a", &FlagContext::anonymous()));
+        assert!(!set.is_enabled("b", &FlagContext::anonymous()));
+    }
+
+    #[test]
+    fn flags_are_sorted_by_key() {
+        let set = FeatureFlagSet::new(vec![
+            FeatureFlag::new("b", true).unwrap(),
+            FeatureFlag::new("a", true).unwrap(),
+        ]);
+        let keys: Vec<_> = set.flags().iter().map(|f| f.key.as_str()).collect();
+        assert_eq!(keys, vec!["a", "b"]);
+    }
+
+    #[test]
+    fn flag_round_trips_through_json() {
+        let flag = FeatureFlag::new("export", false)
+            .unwrap()
+            .with_tier_override(MembershipTier::Monthly, true)
+            .with_user_override(&user("u1"), true);
+
+        let json = serde_json::to_string(&flag).unwrap();
+        assert!(json.contains(r#""monthly":true"#));
+        let restored: FeatureFlag = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored, flag);
+    }
+
+    #[test]
+    fn rollout_is_stable_and_partial() {
+        let flag = FeatureFlag::new("new_agent", true).unwrap().with_rollout(30).unwrap();
+
+        let enabled = (0..1000)
+            .filter(|i| flag.evaluate(&FlagContext::for_user(user(&format!("user-{}", i)))))
+            .count();
+        assert!((200..400).contains(&enabled), "enabled for {} of 1000", enabled);
+
+        let ctx = FlagContext::for_user(user("u1"));
+        assert_eq!(flag.evaluate(&ctx), flag.evaluate(&ctx));
+        assert!(!flag.evaluate(&FlagContext::anonymous()));
+    }
+
+    #[test]
+    fn overrides_bypass_rollout() {
+        let flag = FeatureFlag::new("new_agent", true)
+            .unwrap()
+            .with_rollout(0)
+            .unwrap()
+            .with_user_override(&user("u1"), true);
+
+        assert!(flag.evaluate(&FlagContext::for_user(user("u1"))));
+        assert!(!flag.evaluate(&FlagContext::for_user(user("u2"))));
+    }
+
+    #[test]
+    fn rollout_above_100_is_rejected() {
+        let result = FeatureFlag::new("a", true).unwrap().with_rollout(101);
+        assert_eq!(result.unwrap_err(), FeatureFlagError::InvalidRollout(101));
+    }
+
+    // Compile-time check that trait is object-safe
+    #[allow(dead_code)]
+    fn assert_object_safe(_: &dyn FeatureFlagProvider) {}
+}
@@ -0,0 +1,116 @@
+//! Google Docs Port - Publishing decision documents to a user's Google Drive.
+//!
+//! Each user connects their own Google account with OAuth; the document is
+//! then created in their Drive with their credentials, so it is theirs to
+//! share inside their Workspace.
+//!
+//! ```text
+//! authorization_url ──► Google consent ──► code ──► exchange_code ──► GoogleAccountStore
+//!                                                                          │
+//! DecisionDocument ──Markdown──► create_document(access token) ◄───────────┘
+//! ```
+
+use async_trait::async_trait;
+use secrecy::Secret;
+
+use crate::domain::foundation::{DomainError, Timestamp, UserId};
+
+/// Seconds before expiry at which an access token is treated as expired.
+pub const GOOGLE_TOKEN_EXPIRY_MARGIN_SECS: u64 = 60;
+
+/// Errors from Google's OAuth and Drive APIs.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum GoogleDocsError {
+    /// The grant was revoked or the code is invalid; the user must reconnect.
+    #[error("Google authorization failed: {0}")]
+    Unauthorized(String),
+
+    /// Google rejected the request.
+    #[error("Google API error: {0}")]
+    Api(String),
+
+    /// Google could not be reached.
+    #[error("Google API unavailable: {0}")]
+    Unavailable(String),
+}
+
+/// OAuth credentials for one user's Google account.
+#[derive(Debug, Clone)]
+pub struct GoogleTokens {
+    pub access_token: Secret<String>,
+    /// Absent if Google did not issue one (the user had already consented).
+    pub refresh_token: Option<Secret<String>>,
+    pub expires_at: Timestamp,
+}
+
+impl GoogleTokens {
+    /// Whether the access token must be refreshed before use at `now`.
+    pub fn is_expired(&self, now: Timestamp) -> bool {
+        !self
+            .expires_at
+            .is_after(&now.plus_secs(GOOGLE_TOKEN_EXPIRY_MARGIN_SECS))
+    }
+}
+
+/// A document created in Google Docs.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GoogleDoc {
+    pub document_id: String,
+    /// Link that opens the document in Google Docs.
+    pub url: String,
+}
+
+/// Port for Google's OAuth and Drive APIs.
+#[async_trait]
+pub trait GoogleDocsClient: Send + Sync {
+    /// URL of Google's consent screen; `state` is echoed back to the caller.
+    fn authorization_url(&self, state: &str) -> String;
+
+    /// Exchanges an authorization code for tokens.
+    async fn exchange_code(&self, code: &str) -> Result<GoogleTokens, GoogleDocsError>;
+
+    /// Gets a new access token, keeping the refresh token.
+    async fn refresh(&self, tokens: &GoogleTokens) -> Result<GoogleTokens, GoogleDocsError>;
+
+    /// Creates a Google Doc from Markdown, which Google converts into
+    /// headings, lists, and tables.
+    async fn create_document(
+        &self,
+        access_token: &Secret<String>,
+        title: &str,
+        markdown: &str,
+    ) -> Result<GoogleDoc, GoogleDocsError>;
+}
+
+/// Port for storing users' Google credentials.
+#[async_trait]
+pub trait GoogleAccountStore: Send + Sync {
+    async fn get(&self, user_id: &UserId) -> Result<Option<GoogleTokens>, DomainError>;
+
+    /// Saves, replacing any existing credentials.
+    async fn save(&self, user_id: &UserId, tokens: &GoogleTokens) -> Result<(), DomainError>;
+
+    /// Removes the credentials; succeeds if none are stored.
+    async fn delete(&self, user_id: &UserId) -> Result<(), DomainError>;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tokens(expires_at: Timestamp) -> GoogleTokens {
+        GoogleTokens {
+            access_token: Secret::new("access".to_string()),
+            refresh_token: None,
+            expires_at,
+        }
+    }
+
+    #[test]
+    fn tokens_expire_shortly_before_their_deadline() {
+        let now = Timestamp::now();
+        assert!(!tokens(now.plus_secs(3600)).is_expired(now));
+        assert!(tokens(now.plus_secs(30)).is_expired(now));
+        assert!(tokens(now).is_expired(now));
+    }
+}
@@ -0,0 +1,221 @@
+//! Help Content Port - In-app explanations of the decision methodology.
+//!
+//! Articles explain PrOACT concepts, guide users through each component, and
+//! define glossary terms. Each has a stable ID such as `concept.proact`,
+//! `component.objectives` or `glossary.swing-weight`, and is stored per
+//! locale with every edit kept as a new version.
+//!
+//! Agent responses reference articles with `[[help:<id>]]` markers; see
+//! [`help_references`]. The frontend turns those into "learn more" cards,
+//! so copy lives in the database rather than in either codebase.
+
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+
+use crate::domain::foundation::{ComponentType, Timestamp, UserId};
+
+use super::email_template::locale_fallbacks;
+
+/// Maximum length of an article ID.
+pub const MAX_HELP_ID_LENGTH: usize = 100;
+
+/// What an article explains.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum HelpArticleKind {
+    /// A methodology concept, e.g. decision quality.
+    Concept,
+    /// How to work through one PrOACT component.
+    ComponentGuide,
+    /// Definition of a single term.
+    Glossary,
+}
+
+impl HelpArticleKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            HelpArticleKind::Concept => "concept",
+            HelpArticleKind::ComponentGuide => "component_guide",
+            HelpArticleKind::Glossary => "glossary",
+        }
+    }
+
+    pub fn parse(s: &str) -> Option<Self> {
+        [
+            HelpArticleKind::Concept,
+            HelpArticleKind::ComponentGuide,
+            HelpArticleKind::Glossary,
+        ]
+        .into_iter()
+        .find(|kind| kind.as_str() == s)
+    }
+}
+
+/// One version of a help article in one locale.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct HelpArticle {
+    pub id: String,
+    pub locale: String,
+    /// Starts at 1 and increases with every saved edit.
+    pub version: u32,
+    pub kind: HelpArticleKind,
+    /// Component the article belongs to, for component guides.
+    pub component_type: Option<ComponentType>,
+    pub title: String,
+    /// One or two sentences for the "learn more" card.
+    pub summary: String,
+    /// Full Markdown body.
+    pub body: String,
+    pub updated_by: Option<UserId>,
+    pub updated_at: Timestamp,
+}
+
+/// Errors from help content storage.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum HelpContentError {
+    #[error("Invalid help article ID: {0}")]
+    InvalidId(String),
+
+    /// The article changed since the editor loaded it.
+    #[error("Help article was edited concurrently (expected version {expected}, found {actual})")]
+    VersionConflict { expected: u32, actual: u32 },
+
+    #[error("Help content storage error: {0}")]
+    Storage(String),
+}
+
+/// Port for reading and editing help articles.
+#[async_trait]
+pub trait HelpContentRepository: Send + Sync {
+    /// Latest version of an article in exactly `locale`.
+    async fn get(&self, id: &str, locale: &str) -> Result<Option<HelpArticle>, HelpContentError>;
+
+    /// Latest version of every article in exactly `locale`, ordered by ID.
+    async fn list(
+        &self,
+        locale: &str,
+        kind: Option<HelpArticleKind>,
+    ) -> Result<Vec<HelpArticle>, HelpContentError>;
+
+    /// Stores an edit as the next version and returns it.
+    ///
+    /// `expected_version` is the version the editor started from (0 for a
+    /// new article); a mismatch fails with `VersionConflict`.
+    async fn save(
+        &self,
+        article: HelpArticle,
+        expected_version: u32,
+    ) -> Result<HelpArticle, HelpContentError>;
+
+    /// Every version of an article in `locale`, newest first.
+    async fn history(&self, id: &str, locale: &str) -> Result<Vec<HelpArticle>, HelpContentError>;
+
+    /// The article in the locale closest to `requested` (`pt-BR`, `pt`,
+    /// then the default).
+    async fn get_localized(
+        &self,
+        id: &str,
+        requested: &str,
+    ) -> Result<Option<HelpArticle>, HelpContentError> {
+        for locale in locale_fallbacks(requested) {
+            if let Some(article) = self.get(id, &locale).await? {
+                return Ok(Some(article));
+            }
+        }
+        Ok(None)
+    }
+}
+
+/// Check that an article ID is a dotted, lowercase slug like
+/// `glossary.swing-weight`.
+pub fn validate_help_id(id: &str) -> Result<(), HelpContentError> {
+    let valid = !id.is_empty()
+        && id.len() <= MAX_HELP_ID_LENGTH
+        && id.split('.').all(|part| {
+            !part.is_empty()
+                && part
+                    .chars()
+                    .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-' || c == '_')
+        });
+    if valid {
+        Ok(())
+    } else {
+        Err(HelpContentError::InvalidId(id.to_string()))
+    }
+}
+
+/// ID of the guide for a PrOACT component, e.g. `component.issue_raising`.
+pub fn component_help_id(component_type: ComponentType) -> String {
+    let name = serde_json::to_value(component_type)
+        .ok()
+        .and_then(|v| v.as_str().map(str::to_string))
+        .unwrap_or_default();
+    format!("component.{}", name)
+}
+
+/// Distinct article IDs referenced by `[[help:<id>]]` markers, in order of
+/// first appearance. Malformed markers are ignored.
+pub fn help_references(text: &str) -> Vec<String> {
+    let mut ids: Vec<String> = Vec::new();
+    let mut rest = text;
+    while let Some(start) = rest.find("[[help:") {
+        rest = &rest[start + "[[help:".len()..];
+        let Some(end) = rest.find("]]") else {
+            break;
+        };
+        let id = rest[..end].trim();
+        if validate_help_id(id).is_ok() && !ids.iter().any(|existing| existing == id) {
+            ids.push(id.to_string());
+        }
+        rest = &rest[end + 2..];
+    }
+    ids
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ids_are_dotted_slugs() {
+        assert!(validate_help_id("concept.proact").is_ok());
+        assert!(validate_help_id("glossary.swing-weight").is_ok());
+        assert!(validate_help_id("component.issue_raising").is_ok());
+        assert!(validate_help_id("").is_err());
+        assert!(validate_help_id("Concept.Proact").is_err());
+        assert!(validate_help_id("concept..proact").is_err());
+        assert!(validate_help_id("concept/../etc").is_err());
+    }
+
+    #[test]
+    fn component_ids_use_serialized_names() {
+        assert_eq!(
+            component_help_id(ComponentType::IssueRaising),
+            "component.issue_raising"
+        );
+    }
+
+    #[test]
+    fn finds_distinct_references_in_order() {
+        let text = "Weigh your [[help:concept.tradeoffs]] using \
+                    [[help:glossary.swing-weight]], see [[help:concept.tradeoffs]] \
+                    and [[help:Not Valid]] [[help:unterminated";
+
+        assert_eq!(
+            help_references(text),
+            vec!["concept.tradeoffs", "glossary.swing-weight"]
+        );
+        assert!(help_references("No references here").is_empty());
+    }
+
+    #[test]
+    fn kinds_round_trip_through_their_names() {
+        for kind in [
+            HelpArticleKind::Concept,
+            HelpArticleKind::ComponentGuide,
+            HelpArticleKind::Glossary,
+        ] {
+            assert_eq!(HelpArticleKind::parse(kind.as_str()), Some(kind));
+        }
+    }
+}
@@ -0,0 +1,37 @@
+//! ImportDraftRepository port - AI-drafted imports awaiting confirmation.
+//!
+//! Drafts are keyed by their `ConfirmationRequest` ID and keep the request's
+//! status, so a confirmed or discarded draft stays on record.
+
+use async_trait::async_trait;
+
+use crate::domain::document::ImportDraft;
+use crate::domain::foundation::{ConfirmationRequestId, CycleId, DomainError};
+
+/// Port for persisting import drafts.
+#[async_trait]
+pub trait ImportDraftRepository: Send + Sync {
+    /// Save a new draft.
+    async fn save(&self, draft: &ImportDraft) -> Result<(), DomainError>;
+
+    /// Store a draft's updated confirmation status.
+    async fn update(&self, draft: &ImportDraft) -> Result<(), DomainError>;
+
+    /// Find a draft by ID.
+    async fn find_by_id(
+        &self,
+        id: &ConfirmationRequestId,
+    ) -> Result<Option<ImportDraft>, DomainError>;
+
+    /// Drafts for a cycle still awaiting confirmation, newest first.
+    async fn list_pending(&self, cycle_id: &CycleId) -> Result<Vec<ImportDraft>, DomainError>;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Compile-time check that trait is object-safe
+    #[allow(dead_code)]
+    fn assert_object_safe(_: &dyn ImportDraftRepository) {}
+}
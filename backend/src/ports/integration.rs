@@ -0,0 +1,134 @@
+//! Integration ports - Persistence, templating, and pluggable actions.
+//!
+//! ```text
+//! EventEnvelope ──► IntegrationDispatcher ──► IntegrationTemplateEngine
+//!                          │                          │
+//!                          ▼                          ▼
+//!          IntegrationDeliveryRepository    IntegrationActionAdapter (per kind)
+//! ```
+//!
+//! Each `IntegrationActionKind` is served by one registered adapter, so new
+//! kinds of action plug in without touching the dispatcher.
+
+use async_trait::async_trait;
+use serde_json::Value as JsonValue;
+
+use crate::domain::foundation::{DomainError, IntegrationDeliveryId, IntegrationId, Timestamp, UserId};
+use crate::domain::integration::{Integration, IntegrationActionKind, IntegrationDelivery};
+
+/// Errors from rendering or performing an integration's action.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum IntegrationActionError {
+    /// The template does not compile or cannot render this event.
+    #[error("Template error: {0}")]
+    InvalidTemplate(String),
+
+    /// The target refused the request, e.g. a 4xx response or unknown channel.
+    #[error("Rejected: {0}")]
+    Rejected(String),
+
+    /// The target could not be reached or asked us to slow down.
+    #[error("Unavailable: {0}")]
+    Unavailable(String),
+}
+
+impl IntegrationActionError {
+    /// Whether retrying the same request later may succeed.
+    pub fn is_retryable(&self) -> bool {
+        matches!(self, IntegrationActionError::Unavailable(_))
+    }
+}
+
+/// A rendered action, ready for its adapter.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IntegrationPayload {
+    /// Sent along so receivers can deduplicate retries.
+    pub delivery_id: IntegrationDeliveryId,
+    pub event_type: String,
+    /// Rendered template, or the default body.
+    pub body: String,
+    /// Rendered subject, for actions that have one.
+    pub subject: Option<String>,
+}
+
+/// Performs one kind of integration action.
+#[async_trait]
+pub trait IntegrationActionAdapter: Send + Sync {
+    /// The action kind this adapter handles.
+    fn kind(&self) -> IntegrationActionKind;
+
+    /// Performs `integration`'s action with the rendered payload.
+    async fn execute(
+        &self,
+        integration: &Integration,
+        payload: &IntegrationPayload,
+    ) -> Result<(), IntegrationActionError>;
+}
+
+/// Compiles and renders user-written payload templates.
+pub trait IntegrationTemplateEngine: Send + Sync {
+    /// Checks that `source` compiles, without rendering it.
+    fn check(&self, source: &str) -> Result<(), IntegrationActionError>;
+
+    /// Renders `source` with `context` as its variables.
+    fn render(&self, source: &str, context: &JsonValue) -> Result<String, IntegrationActionError>;
+}
+
+/// Port for persisting integrations.
+#[async_trait]
+pub trait IntegrationRepository: Send + Sync {
+    /// Insert or update an integration.
+    async fn save(&self, integration: &Integration) -> Result<(), DomainError>;
+
+    async fn find_by_id(&self, id: &IntegrationId) -> Result<Option<Integration>, DomainError>;
+
+    /// The user's integrations, oldest first.
+    async fn list_for_user(&self, user_id: &UserId) -> Result<Vec<Integration>, DomainError>;
+
+    /// Deletes the integration. Its pending deliveries are not attempted again.
+    async fn delete(&self, id: &IntegrationId) -> Result<(), DomainError>;
+}
+
+/// Port for persisting integration deliveries. Pending deliveries double as
+/// the retry queue, polled with `list_due`.
+#[async_trait]
+pub trait IntegrationDeliveryRepository: Send + Sync {
+    /// Insert or update a delivery.
+    async fn save(&self, delivery: &IntegrationDelivery) -> Result<(), DomainError>;
+
+    /// Pending deliveries whose next attempt is at or before `now`, oldest first.
+    async fn list_due(
+        &self,
+        now: Timestamp,
+        limit: u32,
+    ) -> Result<Vec<IntegrationDelivery>, DomainError>;
+
+    /// The integration's most recent deliveries, newest first.
+    async fn list_for_integration(
+        &self,
+        integration_id: &IntegrationId,
+        limit: u32,
+    ) -> Result<Vec<IntegrationDelivery>, DomainError>;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Compile-time check that traits are object-safe
+    #[allow(dead_code)]
+    fn assert_object_safe(
+        _: &dyn IntegrationActionAdapter,
+        _: &dyn IntegrationTemplateEngine,
+        _: &dyn IntegrationRepository,
+        _: &dyn IntegrationDeliveryRepository,
+    ) {
+    }
+
+    #[test]
+    fn only_unavailable_targets_are_retried() {
+        assert!(IntegrationActionError::Unavailable("timeout".to_string()).is_retryable());
+        assert!(!IntegrationActionError::Rejected("404".to_string()).is_retryable());
+        assert!(!IntegrationActionError::InvalidTemplate("syntax".to_string()).is_retryable());
+    }
+}
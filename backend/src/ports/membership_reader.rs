@@ -150,6 +150,9 @@ pub struct StatusCounts {
     /// Pending memberships.
     pub pending: u64,
 
+    /// Memberships in a free trial.
+    pub trialing: u64,
+
     /// Active memberships.
     pub active: u64,
 
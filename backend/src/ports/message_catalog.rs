@@ -0,0 +1,256 @@
+//! MessageCatalog port - Localized user-facing strings.
+//!
+//! API responses carry a handful of fixed, user-facing strings: error
+//! messages, `NextAction` descriptions, and Decision Quality element names.
+//! A catalog maps a message key (`error-not-found`, `dq-clear-objectives`)
+//! and a locale to the translated text, walking the same fallback chain as
+//! email templates (`pt-BR`, then `pt`, then [`DEFAULT_EMAIL_LOCALE`]).
+//!
+//! The helpers below fall back to the English text already on the value when
+//! a key is missing, so a partial catalog never blanks out a response.
+
+use crate::domain::foundation::{ComponentType, ErrorCode};
+
+use super::cycle_reader::{NextAction, NextActionType};
+use super::email_template::{locale_fallbacks, DEFAULT_EMAIL_LOCALE};
+
+/// Source of translated user-facing strings.
+pub trait MessageCatalog: Send + Sync {
+    /// Formats `key` for exactly `locale`, substituting `args` into its
+    /// `{ $name }` placeholders. Returns `None` if the locale lacks the key.
+    fn format(&self, locale: &str, key: &str, args: &[(&str, &str)]) -> Option<String>;
+
+    /// Locales this catalog has messages for.
+    fn locales(&self) -> Vec<&'static str>;
+
+    /// Formats `key` for the closest available variant of `locale`.
+    fn localize(&self, locale: &str, key: &str, args: &[(&str, &str)]) -> Option<String> {
+        locale_fallbacks(locale)
+            .iter()
+            .find_map(|candidate| self.format(candidate, key, args))
+    }
+}
+
+/// Picks the best supported locale for an `Accept-Language` header value.
+///
+/// Ranges are tried in descending `q` order (ties keep header order); each
+/// matches exactly or by its primary language. Falls back to the default
+/// locale when nothing matches.
+pub fn negotiate_locale(accept_language: &str, supported: &[&str]) -> String {
+    let mut ranges: Vec<(String, f32)> = accept_language
+        .split(',')
+        .filter_map(|part| {
+            let mut pieces = part.split(';');
+            let tag = pieces.next()?.trim().replace('_', "-").to_ascii_lowercase();
+            if tag.is_empty() || tag == "*" {
+                return None;
+            }
+            let quality = pieces
+                .filter_map(|p| p.trim().strip_prefix("q="))
+                .find_map(|q| q.trim().parse::<f32>().ok())
+                .unwrap_or(1.0);
+            (quality > 0.0).then_some((tag, quality))
+        })
+        .collect();
+    ranges.sort_by(|a, b| b.1.total_cmp(&a.1));
+
+    for (tag, _) in &ranges {
+        let language = tag.split('-').next().unwrap_or(tag);
+        let matched = supported
+            .iter()
+            .find(|s| s.eq_ignore_ascii_case(tag))
+            .or_else(|| supported.iter().find(|s| s.eq_ignore_ascii_case(language)));
+        if let Some(locale) = matched {
+            return locale.to_string();
+        }
+    }
+    DEFAULT_EMAIL_LOCALE.to_string()
+}
+
+/// Catalog key for an error code, e.g. `error-not-found`.
+pub fn error_message_key(code: &str) -> String {
+    format!(
+        "error-{}",
+        code.trim().to_ascii_lowercase().replace('_', "-")
+    )
+}
+
+/// Localized message for a domain error code, or `fallback` if untranslated.
+pub fn localize_error(
+    catalog: &dyn MessageCatalog,
+    locale: &str,
+    code: ErrorCode,
+    fallback: &str,
+) -> String {
+    catalog
+        .localize(locale, &error_message_key(&code.to_string()), &[])
+        .unwrap_or_else(|| fallback.to_string())
+}
+
+fn component_key(component: ComponentType) -> &'static str {
+    match component {
+        ComponentType::IssueRaising => "component-issue-raising",
+        ComponentType::ProblemFrame => "component-problem-frame",
+        ComponentType::StakeholderAnalysis => "component-stakeholder-analysis",
+        ComponentType::Objectives => "component-objectives",
+        ComponentType::Alternatives => "component-alternatives",
+        ComponentType::Consequences => "component-consequences",
+        ComponentType::UncertaintyRegister => "component-uncertainty-register",
+        ComponentType::Tradeoffs => "component-tradeoffs",
+        ComponentType::Recommendation => "component-recommendation",
+        ComponentType::DecisionQuality => "component-decision-quality",
+        ComponentType::NotesNextSteps => "component-notes-next-steps",
+    }
+}
+
+fn next_action_key(action_type: NextActionType) -> &'static str {
+    match action_type {
+        NextActionType::StartFirst | NextActionType::StartNext => "next-action-start",
+        NextActionType::ContinueCurrent => "next-action-continue",
+        NextActionType::ReviseComponent => "next-action-revise",
+        NextActionType::CompleteCycle => "next-action-complete-cycle",
+        NextActionType::AlreadyComplete => "next-action-already-complete",
+    }
+}
+
+/// Localized display name for a PrOACT component.
+pub fn localize_component(
+    catalog: &dyn MessageCatalog,
+    locale: &str,
+    component: ComponentType,
+) -> String {
+    catalog
+        .localize(locale, component_key(component), &[])
+        .unwrap_or_else(|| component.display_name().to_string())
+}
+
+/// Localized description for a next action, or its stored description.
+pub fn localize_next_action(
+    catalog: &dyn MessageCatalog,
+    locale: &str,
+    action: &NextAction,
+) -> String {
+    let component = action
+        .component
+        .map(|c| localize_component(catalog, locale, c))
+        .unwrap_or_default();
+    catalog
+        .localize(
+            locale,
+            next_action_key(action.action_type),
+            &[("component", component.as_str())],
+        )
+        .unwrap_or_else(|| action.description.clone())
+}
+
+/// Localized name for one of the standard Decision Quality elements.
+///
+/// Custom element names have no key and come back unchanged.
+pub fn localize_dq_element(catalog: &dyn MessageCatalog, locale: &str, name: &str) -> String {
+    let key = format!("dq-{}", name.trim().to_ascii_lowercase().replace(' ', "-"));
+    catalog
+        .localize(locale, &key, &[])
+        .unwrap_or_else(|| name.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    // Compile-time check that trait is object-safe
+    #[allow(dead_code)]
+    fn assert_object_safe(_: &dyn MessageCatalog) {}
+
+    struct MapCatalog(HashMap<(&'static str, &'static str), &'static str>);
+
+    impl MessageCatalog for MapCatalog {
+        fn format(&self, locale: &str, key: &str, args: &[(&str, &str)]) -> Option<String> {
+            let (_, text) = self
+                .0
+                .iter()
+                .find(|((l, k), _)| *l == locale && *k == key)?;
+            let mut text = text.to_string();
+            for (name, value) in args {
+                text = text.replace(&format!("{{ ${} }}", name), value);
+            }
+            Some(text)
+        }
+
+        fn locales(&self) -> Vec<&'static str> {
+            vec!["en", "es"]
+        }
+    }
+
+    fn catalog() -> MapCatalog {
+        MapCatalog(HashMap::from([
+            (("es", "next-action-revise"), "Revisar { $component }"),
+            (("es", "component-objectives"), "Objetivos"),
+            (("es", "dq-clear-objectives"), "Objetivos claros"),
+            (("en", "error-not-found"), "Not found"),
+        ]))
+    }
+
+    #[test]
+    fn negotiation_respects_quality_and_primary_language() {
+        let supported = ["en", "es", "fr"];
+        assert_eq!(negotiate_locale("fr-CA, es;q=0.9", &supported), "fr");
+        assert_eq!(negotiate_locale("de, es;q=0.5, fr;q=0.8", &supported), "fr");
+        assert_eq!(negotiate_locale("es;q=0, de", &supported), "en");
+        assert_eq!(negotiate_locale("", &supported), "en");
+        assert_eq!(negotiate_locale("*", &supported), "en");
+    }
+
+    #[test]
+    fn error_keys_are_kebab_case() {
+        assert_eq!(
+            error_message_key("VALIDATION_FAILED"),
+            "error-validation-failed"
+        );
+    }
+
+    #[test]
+    fn localize_falls_back_through_locale_chain() {
+        let catalog = catalog();
+        assert_eq!(
+            catalog
+                .localize("es-MX", "component-objectives", &[])
+                .as_deref(),
+            Some("Objetivos")
+        );
+        assert_eq!(
+            localize_error(&catalog, "es", ErrorCode::NotFound, "Cycle not found"),
+            "Not found"
+        );
+        assert_eq!(
+            localize_error(&catalog, "es", ErrorCode::Forbidden, "Nope"),
+            "Nope"
+        );
+    }
+
+    #[test]
+    fn next_action_substitutes_localized_component() {
+        let action = NextAction {
+            action_type: NextActionType::ReviseComponent,
+            component: Some(ComponentType::Objectives),
+            description: "Revise Objectives".to_string(),
+        };
+        assert_eq!(
+            localize_next_action(&catalog(), "es", &action),
+            "Revisar Objetivos"
+        );
+        assert_eq!(
+            localize_next_action(&catalog(), "fr", &action),
+            "Revise Objectives"
+        );
+    }
+
+    #[test]
+    fn dq_elements_fall_back_to_their_name() {
+        assert_eq!(
+            localize_dq_element(&catalog(), "es", "Clear Objectives"),
+            "Objetivos claros"
+        );
+        assert_eq!(localize_dq_element(&catalog(), "es", "Custom"), "Custom");
+    }
+}
@@ -6,10 +6,14 @@
 //! ## Authentication Port
 //!
 //! - `SessionValidator` - Validates JWT tokens and extracts authenticated user
+//! - `TokenRevocationStore` - Per-user cutoffs that revoke tokens before they expire
 //!
-//! ## Access Control Port
+//! ## Access Control Ports
 //!
 //! - `AccessChecker` - Port for membership-based access control
+//! - `OrganizationRepository` - Teams whose members share sessions and a plan
+//! - `ProvisionedUserRepository` - Users an identity provider manages via SCIM
+//! - `ConsentRepository` - Append-only consent ledger (ToS, privacy, AI processing)
 //!
 //! ## Event Ports
 //!
@@ -17,10 +21,15 @@
 //! - `EventSubscriber` - Port for subscribing to domain events
 //! - `EventHandler` - Handler that processes incoming events
 //! - `ProcessedEventStore` - Idempotency tracking for event handlers
+//! - `EventStore` - Append-only log of published events, read by projections
+//! - `CycleEventStore` - Versioned cycle events and snapshots cycles are rebuilt from
+//! - `Projection` - Read model folded from events, with a `ProjectionCheckpoint`
+//!   kept in a `ProjectionCheckpointStore`
 //!
 //! ## AI Provider Port
 //!
 //! - `AIProvider` - Port for LLM provider integrations (OpenAI, Anthropic)
+//! - `ShadowComparisonRepository` - Production vs. candidate outputs from shadow traffic
 //!
 //! ## Atomic Decision Tools Ports
 //!
@@ -28,12 +37,107 @@
 //! - `ToolInvocationRepository` - Audit log for tool invocations
 //! - `RevisitSuggestionRepository` - Queued component revisit suggestions
 //! - `ConfirmationRequestRepository` - User confirmation requests
+//! - `DecisionTreeRepository` - Decision trees built for sequential decisions
 //!
 //! ## Scaling Infrastructure Ports
 //!
 //! - `OutboxWriter` - Transactional event persistence for guaranteed delivery
 //! - `ConnectionRegistry` - Multi-server WebSocket connection tracking
+//! - `ServerMessenger` - Cross-server delivery of session events to WebSocket rooms
 //! - `CircuitBreaker` - External service resilience pattern
+//! - `Cache` - Shared cache with TTLs and tag invalidation for hot read views
+//!
+//! ## Document Ports
+//!
+//! - `DocumentStorage` - Storage for exported decision documents
+//! - `DocumentExporter` - Renders the decision document to a file format (PDF, slides, Markdown, HTML)
+//! - `DocumentTemplateStore` - Per-organization Markdown templates for exports
+//! - `AttachmentRepository` - Metadata for files attached to components
+//! - `DocumentVersionRepository` - Stored history of the Markdown decision document
+//! - `DocumentDeliveryRepository` - Emailed documents and their retry state
+//! - `DocumentEmailPreferenceRepository` - Opt-in for emailing completed documents
+//! - `ImportDraftRepository` - AI-drafted imports awaiting the user's confirmation
+//! - `DocumentPublicationRepository` - Public read-only snapshots of the document
+//! - `PublicLinkSigner` - Signs and verifies expiring public document links
+//! - `SignedUrlIssuer` - Optional capability for short-lived download URLs
+//! - `GoogleDocsClient` - Creates Google Docs with a user's OAuth credentials
+//! - `GoogleAccountStore` - Users' connected Google accounts
+//! - `SlackClient` - Installs the Slack app and posts recommendations to channels
+//! - `SlackWorkspaceStore` - Users' connected Slack workspaces
+//! - `SlackShareRepository` - Recommendations shared to Slack and their retry state
+//! - `TeamsClient` - Posts recommendations to Teams channels' incoming webhooks
+//! - `TeamsShareRepository` - Recommendations shared to Teams and their retry state
+//! - `OrganizationChatSettingsRepository` - Each organization's chat platform and Teams channels
+//! - `SpreadsheetParser` - Reads uploaded CSV/XLSX files for imports
+//!
+//! ## Decision Profile Ports
+//!
+//! - `DecisionHistoryRepository` - Completed decisions and their outcomes
+//! - `OutcomeReminderRepository` - Scheduled outcome survey emails
+//! - `DecisionDeadlineReader` - Problem Frame deadlines of active decisions
+//! - `CalendarFeedSigner` - Signs and verifies private calendar feed URLs
+//! - `CalendarFeedVersionRepository` - Rotatable per-user calendar feed versions
+//! - `CalendarRenderer` - Renders deadlines and follow-ups as iCalendar
+//! - `BenchmarkRepository` - Anonymized cross-user benchmark distributions
+//! - `ProfileSummaryRepository` - Latest profile summary per user
+//! - `ProfileRevisionRepository` - Version history of profile summaries
+//! - `TeamProfileSettingsRepository` - Per-organization team profile settings
+//! - `AgentContextProvider` - Extra system-prompt context for agents
+//! - `AdaptiveStyleOverrideRepository` - Conversations opted out of adaptive style
+//!
+//! ## Session Ports
+//!
+//! - `SessionArchivalRepository` - Auto-archive settings and idle sessions due for a warning or archival
+//! - `SessionColdStorageRepository` - Sessions offloaded to the document store and those due next
+//!
+//! ## Privacy Ports
+//!
+//! - `DataExportRepository` - GDPR data export requests and their retry state
+//! - `UserDataSource` - One kind of user data included in data exports
+//! - `DataErasureRepository` - GDPR erasure requests, their retry state and verification reports
+//! - `UserDataEraser` - One kind of user data removed by erasures
+//! - `AnalyticsSink` - Receives pseudonymous usage events stripped of decision content
+//!
+//! ## Integration Ports
+//!
+//! - `IntegrationRepository` - Users' event-triggered automations
+//! - `IntegrationDeliveryRepository` - Triggered actions and their retry state
+//! - `IntegrationActionAdapter` - Performs one kind of action (HTTP POST, Slack, email)
+//! - `IntegrationTemplateEngine` - Renders user-written payload templates
+//!
+//! ## Email Ports
+//!
+//! - `EmailSender` - Outbound transactional email
+//! - `EmailTemplateRenderer` - Localized subjects and bodies for each email kind
+//!
+//! ## Localization Port
+//!
+//! - `MessageCatalog` - Translated error messages, next actions, and DQ element names
+//!
+//! ## Search Port
+//!
+//! - `SearchService` - Ranked full-text search over sessions, component outputs and messages
+//! - `EmbeddingProvider` - Vector embeddings of text for semantic search
+//! - `DecisionEmbeddingRepository` - Embedded component outputs and conversation summaries
+//!
+//! ## Help Content Port
+//!
+//! - `HelpContentRepository` - Versioned, localized methodology articles and glossary entries
+//!
+//! ## Feature Flag Port
+//!
+//! - `FeatureFlagProvider` - Runtime-reloadable flags with tier/user targeting
+//!
+//! ## Observability Ports
+//!
+//! - `SloRecorder` - Records SLI events for error-budget tracking
+//! - `SecurityEventSink` - Delivers audit and auth events to a SIEM
+//! - `AuditLog` - Hash-chained log of every command handler execution
+//! - `AuditPseudonymKeys` - Per-user keys that pseudonymize users in the audit log
+//!
+//! ## Billing Port
+//!
+//! - `UsageReportRepository` - AI usage billed through metered subscriptions, per user and day
 //!
 //! ## Rate Limiting Port
 //!
@@ -42,73 +146,206 @@
 //! See `docs/architecture/SCALING-READINESS.md` for architectural details.
 
 mod access_checker;
+mod adaptive_style_override;
 mod ai_engine;
 mod ai_provider;
+mod analytics_sink;
+mod attachment_repository;
+mod audit_log;
+mod audit_pseudonym_keys;
 mod auth_provider;
+mod backup;
+mod benchmark_repository;
+mod cache;
+mod calendar;
 mod circuit_breaker;
 mod confirmation_request_repository;
 mod connection_registry;
+mod consent_repository;
 mod conversation_reader;
 mod conversation_repository;
+mod cycle_event_store;
 mod cycle_reader;
 mod cycle_repository;
 mod dashboard_reader;
+mod data_erasure;
+mod data_export;
+mod decision_embedding_repository;
+mod decision_history_repository;
+mod decision_tree_repository;
+mod document_delivery_repository;
+mod document_exporter;
+mod document_publication;
+mod document_storage;
+mod document_template_store;
+mod document_version_repository;
+mod email_sender;
+mod email_template;
+mod embedding_provider;
 mod event_publisher;
 mod event_subscriber;
+mod feature_flags;
+mod google_docs;
+mod help_content;
+mod import_draft_repository;
+mod integration;
 mod membership_reader;
 mod membership_repository;
+mod message_catalog;
+mod organization_repository;
 mod outbox_writer;
+mod outcome_reminder_repository;
 mod payment_provider;
 mod processed_event_store;
+mod profile_revision_repository;
+mod projection;
 mod promo_code_validator;
+mod provisioned_user_repository;
 mod rate_limiter;
 mod revisit_suggestion_repository;
 mod schema_validator;
+mod search_service;
+mod security_event_sink;
+mod session_archival_repository;
+mod session_cold_storage;
 mod session_reader;
 mod session_repository;
 mod session_validator;
+mod shadow_traffic;
+mod slack;
+mod slo_recorder;
+mod spreadsheet_parser;
 mod state_storage;
 mod step_agent;
+mod team_profile;
+mod teams;
+mod token_revocation_store;
 mod tool_executor;
 mod tool_invocation_repository;
+mod usage_report_repository;
 mod usage_tracker;
 
 pub use access_checker::{AccessChecker, AccessDeniedReason, AccessResult, UsageStats};
+pub use adaptive_style_override::AdaptiveStyleOverrideRepository;
 pub use ai_engine::{AIEngine, ResponseChunk, SessionHandle};
 pub use ai_provider::{
-    AIError, AIProvider, CompletionRequest, CompletionResponse, FinishReason, Message,
-    MessageRole, ProviderInfo, RequestMetadata, StreamChunk, TokenUsage,
+    validate_batch, AIError, AIProvider, BatchJob, BatchRequest, BatchResult, BatchStatus,
+    CompletionRequest, CompletionResponse, FinishReason, Message, MessageRole, ProviderInfo,
+    RequestMetadata, StreamChunk, TokenUsage, ToolCallAssembler, ToolCallDelta,
+    MAX_BATCH_CUSTOM_ID_LENGTH,
 };
+pub use analytics_sink::{AnalyticsSink, DqElementUsage, UsageEvent, UsageEventKind};
+pub use attachment_repository::AttachmentRepository;
+pub use audit_log::{AuditLog, AuditPage, AuditQuery};
+pub use audit_pseudonym_keys::AuditPseudonymKeys;
 pub use auth_provider::AuthProvider;
-pub use circuit_breaker::{CircuitBreaker, CircuitBreakerConfig, CircuitState};
-pub use connection_registry::{ConnectionRegistry, ConnectionRegistryError, ServerId};
+pub use backup::BackupSource;
+pub use benchmark_repository::BenchmarkRepository;
+pub use cache::{
+    cycle_cache_tag, session_cache_tag, Cache, CacheEntryOptions, CacheError, CacheExt,
+};
+pub use calendar::{
+    CalendarEvent, CalendarFeedError, CalendarFeedSigner, CalendarFeedToken,
+    CalendarFeedVersionRepository, CalendarRenderer, DecisionDeadline, DecisionDeadlineReader,
+    CALENDAR_CONTENT_TYPE,
+};
+pub use circuit_breaker::{
+    CircuitBreaker, CircuitBreakerConfig, CircuitBreakerMetrics, CircuitState,
+};
+pub use connection_registry::{
+    ConnectionRegistry, ConnectionRegistryError, ServerId, ServerMessenger,
+};
+pub use consent_repository::ConsentRepository;
 pub use conversation_reader::{
     ConversationReader, ConversationView, MessageList, MessageListOptions, MessageView,
 };
 pub use conversation_repository::ConversationRepository;
+pub use cycle_event_store::CycleEventStore;
 pub use cycle_reader::{
     ComponentOutputView, ComponentStatusItem, CycleProgressView, CycleReader, CycleSummary,
     CycleTreeNode, CycleView, NextAction, NextActionType, ProgressStep,
 };
 pub use cycle_repository::CycleRepository;
 pub use dashboard_reader::{DashboardError, DashboardReader};
+pub use data_erasure::{DataErasureRepository, UserDataEraser};
+pub use data_export::{DataExportRepository, UserDataSource};
+pub use decision_embedding_repository::{
+    DecisionEmbedding, DecisionEmbeddingRepository, EmbeddingSource, SimilarDecision,
+};
+pub use decision_history_repository::DecisionHistoryRepository;
+pub use decision_tree_repository::DecisionTreeRepository;
+pub use document_delivery_repository::{
+    DocumentDeliveryRepository, DocumentEmailPreferenceRepository,
+};
+pub use document_exporter::{
+    DecisionDocument, DocumentAttachment, DocumentExportError, DocumentExporter, ExportFormat,
+    ExportedDocument,
+};
+pub use document_storage::{
+    validate_document_key, DocumentStorage, DocumentStorageError, SignedUrl, SignedUrlIssuer,
+    StoredDocument, DEFAULT_SIGNED_URL_TTL, MAX_SIGNED_URL_TTL,
+};
+pub use document_publication::{
+    DocumentPublicationRepository, PublicLinkError, PublicLinkSigner,
+};
+pub use document_template_store::{
+    validate_organization, DocumentTemplate, DocumentTemplateError,
+    DocumentTemplateStore, MAX_ORGANIZATION_LENGTH,
+};
+pub use document_version_repository::DocumentVersionRepository;
+pub use email_sender::{EmailAttachment, EmailError, EmailMessage, EmailSender};
+pub use email_template::{
+    locale_fallbacks, EmailTemplateError, EmailTemplateKind, EmailTemplateRenderer, RenderedEmail,
+    DEFAULT_EMAIL_LOCALE,
+};
+pub use embedding_provider::{
+    truncate_embedding_input, EmbeddingProvider, EMBEDDING_DIMENSIONS, MAX_EMBEDDING_INPUT_CHARS,
+};
 pub use event_publisher::EventPublisher;
 pub use event_subscriber::{EventBus, EventHandler, EventSubscriber};
+pub use feature_flags::{
+    rollout_bucket, validate_flag_key, validate_rollout, FeatureFlag, FeatureFlagError,
+    FeatureFlagProvider, FeatureFlagSet, FlagContext, MAX_FLAG_KEY_LENGTH,
+};
+pub use google_docs::{
+    GoogleAccountStore, GoogleDoc, GoogleDocsClient, GoogleDocsError, GoogleTokens,
+    GOOGLE_TOKEN_EXPIRY_MARGIN_SECS,
+};
+pub use help_content::{
+    component_help_id, help_references, validate_help_id, HelpArticle, HelpArticleKind,
+    HelpContentError, HelpContentRepository, MAX_HELP_ID_LENGTH,
+};
+pub use import_draft_repository::ImportDraftRepository;
+pub use integration::{
+    IntegrationActionAdapter, IntegrationActionError, IntegrationDeliveryRepository,
+    IntegrationPayload, IntegrationRepository, IntegrationTemplateEngine,
+};
 pub use membership_reader::{
     MembershipReader, MembershipStatistics, MembershipSummary, MembershipView, StatusCounts,
     TierCounts,
 };
 pub use membership_repository::MembershipRepository;
+pub use message_catalog::{
+    error_message_key, localize_component, localize_dq_element, localize_error,
+    localize_next_action, negotiate_locale, MessageCatalog,
+};
+pub use organization_repository::OrganizationRepository;
 pub use outbox_writer::{OutboxEntry, OutboxStatus, OutboxWriter};
+pub use outcome_reminder_repository::OutcomeReminderRepository;
 pub use payment_provider::{
     CheckoutSession, CreateCheckoutRequest, CreateCustomerRequest, CreateSubscriptionRequest,
-    Customer, PaymentError, PaymentErrorCode, PaymentProvider, PortalSession, Subscription,
-    SubscriptionStatus, WebhookEvent, WebhookEventData, WebhookEventType,
+    Customer, MeteredUsageReport, PaymentError, PaymentErrorCode, PaymentProvider, PortalSession,
+    Subscription, SubscriptionStatus, WebhookEvent, WebhookEventData, WebhookEventType,
 };
 pub use processed_event_store::ProcessedEventStore;
+pub use profile_revision_repository::ProfileRevisionRepository;
+pub use projection::{
+    EventStore, Projection, ProjectionCheckpoint, ProjectionCheckpointStore, StoredEvent,
+};
 pub use promo_code_validator::{
     PromoCodeInvalidReason, PromoCodeValidation, PromoCodeValidator,
 };
+pub use provisioned_user_repository::{ProvisionedUserList, ProvisionedUserRepository};
 pub use rate_limiter::{
     RateLimitDenied, RateLimitError, RateLimitKey, RateLimitResult, RateLimitScope,
     RateLimitStatus, RateLimiter,
@@ -117,17 +354,48 @@ pub use revisit_suggestion_repository::{
     RevisitSuggestionRepository, RevisitSuggestionRepoError, RevisitSuggestionCounts,
 };
 pub use schema_validator::{ComponentSchemaValidator, SchemaValidationError};
-pub use session_reader::{ListOptions, SessionList, SessionReader, SessionSummary, SessionView};
+pub use search_service::{
+    SearchError, SearchHit, SearchHitKind, SearchQuery, SearchResults, SearchService,
+    DEFAULT_SEARCH_LIMIT, MAX_SEARCH_LIMIT, MAX_SEARCH_QUERY_LENGTH,
+};
+pub use security_event_sink::{
+    SecurityEvent, SecurityEventCategory, SecurityEventSink, SecurityExportError,
+    SecurityOutcome,
+};
+pub use session_archival_repository::{ArchivalCandidate, SessionArchivalRepository};
+pub use session_cold_storage::{ColdStorageCandidate, SessionColdStorageRepository};
+pub use session_reader::{
+    ListOptions, SessionList, SessionReader, SessionSummary, SessionView, TrashedSession,
+};
 pub use session_repository::SessionRepository;
 pub use session_validator::SessionValidator;
+pub use shadow_traffic::{
+    ShadowComparison, ShadowComparisonRepository, ShadowMetrics, ShadowOutput,
+};
+pub use slack::{
+    SlackClient, SlackError, SlackShareRepository, SlackWorkspace, SlackWorkspaceStore,
+};
+pub use slo_recorder::{Sli, SloRecorder};
+pub use spreadsheet_parser::{
+    SpreadsheetError, SpreadsheetFormat, SpreadsheetParser, MAX_SPREADSHEET_BYTES,
+};
 pub use state_storage::{StateStorage, StateStorageError};
 pub use step_agent::{StepAgent, ToolDefinition};
+pub use team_profile::{
+    AgentContextProvider, ProfileSummaryRepository, TeamProfileSettingsRepository,
+};
+pub use teams::{
+    OrganizationChatSettingsRepository, TeamsClient, TeamsError, TeamsShareRepository,
+};
+pub use token_revocation_store::{is_token_revoked, TokenRevocationStore};
 pub use tool_executor::{ToolExecutor, ToolExecutionContext, ToolExecutionError};
 pub use tool_invocation_repository::{
     ToolInvocationRepository, ToolInvocationRepoError, ToolInvocationStats,
 };
+pub use usage_report_repository::{ReportedUsage, UsageReportRepository};
 pub use usage_tracker::{
-    ProviderUsage, UsageLimitStatus, UsageRecord, UsageSummary, UsageTracker, UsageTrackerError,
+    CostBreakdown, CycleCostRollup, ModelPrice, PriceTable, ProviderUsage, UsageLimitStatus,
+    UsageRecord, UsageSummary, UsageTracker, UsageTrackerError, MICRO_CENTS_PER_CENT,
 };
 pub use confirmation_request_repository::{
     ConfirmationRequestRepository, ConfirmationRequestRepoError, ConfirmationRequestCounts,
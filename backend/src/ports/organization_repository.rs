@@ -0,0 +1,34 @@
+//! Organization repository port (write side).
+//!
+//! Organizations are saved whole, members included, so membership changes
+//! made through the aggregate are persisted atomically.
+
+use async_trait::async_trait;
+
+use crate::domain::foundation::{DomainError, OrganizationId, UserId};
+use crate::domain::organization::Organization;
+
+/// Port for persisting organizations and their members.
+#[async_trait]
+pub trait OrganizationRepository: Send + Sync {
+    /// Insert or update an organization, replacing its member list.
+    async fn save(&self, organization: &Organization) -> Result<(), DomainError>;
+
+    async fn find_by_id(&self, id: &OrganizationId) -> Result<Option<Organization>, DomainError>;
+
+    /// Organizations the user belongs to, oldest first.
+    async fn list_for_member(&self, user_id: &UserId) -> Result<Vec<Organization>, DomainError>;
+
+    /// Deletes the organization. Its sessions stay with their owners.
+    async fn delete(&self, id: &OrganizationId) -> Result<(), DomainError>;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn organization_repository_is_object_safe() {
+        fn _accepts_dyn(_repo: &dyn OrganizationRepository) {}
+    }
+}
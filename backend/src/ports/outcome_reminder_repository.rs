@@ -0,0 +1,52 @@
+//! OutcomeReminderRepository port - Scheduled outcome-journaling reminders.
+//!
+//! Scheduled reminders double as the work queue for `OutcomeReminderMailer`,
+//! polled through `list_due` like data exports.
+
+use async_trait::async_trait;
+
+use crate::domain::foundation::{CycleId, DomainError, Timestamp, UserId};
+use crate::domain::profile::OutcomeReminder;
+
+/// Port for persisting outcome reminders.
+#[async_trait]
+pub trait OutcomeReminderRepository: Send + Sync {
+    /// Insert or update a reminder.
+    async fn save(&self, reminder: &OutcomeReminder) -> Result<(), DomainError>;
+
+    /// The most recent reminder for one decision.
+    async fn find_by_cycle(
+        &self,
+        user_id: &UserId,
+        cycle_id: &CycleId,
+    ) -> Result<Option<OutcomeReminder>, DomainError>;
+
+    /// The reminder whose survey token hashes to `token_hash`.
+    async fn find_by_token_hash(
+        &self,
+        token_hash: &str,
+    ) -> Result<Option<OutcomeReminder>, DomainError>;
+
+    /// Scheduled reminders whose next attempt is at or before `now`, oldest
+    /// first.
+    async fn list_due(
+        &self,
+        now: Timestamp,
+        limit: u32,
+    ) -> Result<Vec<OutcomeReminder>, DomainError>;
+
+    /// The user's reminders still waiting to be sent, soonest first.
+    async fn list_scheduled_for_user(
+        &self,
+        user_id: &UserId,
+    ) -> Result<Vec<OutcomeReminder>, DomainError>;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Compile-time check that trait is object-safe
+    #[allow(dead_code)]
+    fn assert_object_safe(_: &dyn OutcomeReminderRepository) {}
+}
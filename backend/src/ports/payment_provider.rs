@@ -30,6 +30,11 @@ pub trait PaymentProvider: Send + Sync {
     /// Get customer by provider ID.
     async fn get_customer(&self, customer_id: &str) -> Result<Option<Customer>, PaymentError>;
 
+    /// Delete a customer, cancelling any subscriptions it still has.
+    ///
+    /// Deleting a customer that no longer exists succeeds.
+    async fn delete_customer(&self, customer_id: &str) -> Result<(), PaymentError>;
+
     /// Create a subscription for a customer.
     ///
     /// Returns the subscription details including provider IDs.
@@ -60,6 +65,27 @@ pub trait PaymentProvider: Send + Sync {
         new_tier: MembershipTier,
     ) -> Result<Subscription, PaymentError>;
 
+    /// Change how many seats a subscription bills for.
+    ///
+    /// The provider prorates the change onto the next invoice.
+    async fn update_subscription_quantity(
+        &self,
+        subscription_id: &str,
+        quantity: u32,
+    ) -> Result<Subscription, PaymentError>;
+
+    /// End a subscription's trial now, starting its first paid period.
+    ///
+    /// The customer is charged immediately with the payment method they
+    /// gave at checkout.
+    async fn end_trial(&self, subscription_id: &str) -> Result<Subscription, PaymentError>;
+
+    /// Report usage against the subscription's metered price.
+    ///
+    /// A report replaces any earlier one for the same subscription and
+    /// timestamp, so reporting a day again does not bill it twice.
+    async fn report_metered_usage(&self, report: MeteredUsageReport) -> Result<(), PaymentError>;
+
     /// Create a checkout session for initial subscription.
     ///
     /// Returns a URL for the customer to complete payment.
@@ -158,6 +184,30 @@ pub struct Subscription {
 
     /// When cancellation was requested (if applicable).
     pub canceled_at: Option<i64>,
+
+    /// Seats billed for (1 for individual plans).
+    #[serde(default = "default_quantity")]
+    pub quantity: u32,
+}
+
+fn default_quantity() -> u32 {
+    1
+}
+
+/// Usage to bill on a subscription's metered price.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MeteredUsageReport {
+    /// Provider's subscription ID.
+    pub subscription_id: String,
+
+    /// Units used (AI tokens).
+    pub quantity: u64,
+
+    /// When the usage happened (Unix timestamp).
+    pub timestamp: i64,
+
+    /// Idempotency key for safe retries.
+    pub idempotency_key: String,
 }
 
 /// Subscription status from payment provider.
@@ -222,6 +272,9 @@ pub struct CreateCheckoutRequest {
 
     /// Optional promo/coupon code.
     pub promo_code: Option<String>,
+
+    /// Length of a free trial before the first charge, if any.
+    pub trial_days: Option<u32>,
 }
 
 /// Checkout session for payment completion.
@@ -312,6 +365,9 @@ pub enum WebhookEventData {
         customer_id: String,
         status: SubscriptionStatus,
         current_period_end: i64,
+        /// Seats billed for, when the provider reports it.
+        #[serde(default)]
+        quantity: Option<u32>,
     },
 
     /// Invoice data.
@@ -0,0 +1,42 @@
+//! ProfileRevisionRepository port - Version history of users' profile
+//! summaries.
+
+use async_trait::async_trait;
+
+use crate::domain::foundation::{DomainError, UserId};
+use crate::domain::profile::ProfileRevision;
+
+/// Port for the append-only history of a user's profile summary.
+#[async_trait]
+pub trait ProfileRevisionRepository: Send + Sync {
+    /// Append a revision. Fails if the version already exists, so two
+    /// concurrent updates cannot both claim the same version.
+    async fn append(&self, user_id: &UserId, revision: &ProfileRevision)
+        -> Result<(), DomainError>;
+
+    /// The most recent revision.
+    async fn latest(&self, user_id: &UserId) -> Result<Option<ProfileRevision>, DomainError>;
+
+    /// One revision by version.
+    async fn find(
+        &self,
+        user_id: &UserId,
+        version: u32,
+    ) -> Result<Option<ProfileRevision>, DomainError>;
+
+    /// All revisions, oldest first.
+    async fn list_for_user(&self, user_id: &UserId) -> Result<Vec<ProfileRevision>, DomainError>;
+
+    /// Delete every revision for the user, returning how many were removed.
+    /// Only for right-to-erasure workflows.
+    async fn delete_for_user(&self, user_id: &UserId) -> Result<u64, DomainError>;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Compile-time check that trait is object-safe
+    #[allow(dead_code)]
+    fn assert_object_safe(_: &dyn ProfileRevisionRepository) {}
+}
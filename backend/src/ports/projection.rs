@@ -0,0 +1,84 @@
+//! Projection ports - Read models built from the event log.
+//!
+//! A projection folds the events it handles into a read model. Every
+//! published event is appended to the `EventStore`, which numbers events in
+//! publication order; a projection's `ProjectionCheckpoint` records the last
+//! position it applied, so it can resume after a restart, report how far
+//! behind it is, and be rebuilt from position zero.
+
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+
+use crate::domain::foundation::{DomainError, EventEnvelope, Timestamp};
+
+/// A read model maintained from events.
+///
+/// `apply` must be deterministic given the event sequence, since rebuilding
+/// replays every handled event after `reset`.
+#[async_trait]
+pub trait Projection: Send + Sync {
+    /// Unique name, used for checkpoints and the admin API.
+    fn name(&self) -> &'static str;
+
+    /// Event types this projection applies; others are skipped.
+    fn handled_event_types(&self) -> &'static [&'static str];
+
+    /// Folds one event into the read model.
+    async fn apply(&self, event: &EventEnvelope) -> Result<(), DomainError>;
+
+    /// Clears the read model before a rebuild.
+    async fn reset(&self) -> Result<(), DomainError>;
+}
+
+/// An event with its position in the store.
+#[derive(Debug, Clone)]
+pub struct StoredEvent {
+    /// 1-based, increasing in the order events were appended.
+    pub position: u64,
+    pub event: EventEnvelope,
+}
+
+/// Append-only log of every published event.
+#[async_trait]
+pub trait EventStore: Send + Sync {
+    /// Appends an event, returning its position.
+    async fn append(&self, event: &EventEnvelope) -> Result<u64, DomainError>;
+
+    /// Events after `position`, oldest first, at most `limit` of them.
+    async fn read_after(&self, position: u64, limit: u32) -> Result<Vec<StoredEvent>, DomainError>;
+
+    /// Number of events of the given types after `position`.
+    async fn count_after(&self, position: u64, event_types: &[&str]) -> Result<u64, DomainError>;
+
+    /// Position of the newest event, 0 when the store is empty.
+    async fn head_position(&self) -> Result<u64, DomainError>;
+}
+
+/// How far a projection has got through the event store.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ProjectionCheckpoint {
+    pub projection: String,
+    /// Position of the last event read, whether applied or skipped.
+    pub position: u64,
+    pub updated_at: Timestamp,
+}
+
+impl ProjectionCheckpoint {
+    pub fn new(projection: impl Into<String>, position: u64) -> Self {
+        Self {
+            projection: projection.into(),
+            position,
+            updated_at: Timestamp::now(),
+        }
+    }
+}
+
+/// Persistence for projection checkpoints.
+#[async_trait]
+pub trait ProjectionCheckpointStore: Send + Sync {
+    /// The checkpoint for `projection`, if it has ever run.
+    async fn load(&self, projection: &str) -> Result<Option<ProjectionCheckpoint>, DomainError>;
+
+    /// Inserts or replaces the checkpoint.
+    async fn save(&self, checkpoint: &ProjectionCheckpoint) -> Result<(), DomainError>;
+}
@@ -0,0 +1,58 @@
+//! Provisioned user repository port.
+//!
+//! Stores the users an identity provider manages through SCIM. Lookups by
+//! `userName` let the provider find a user it created before it learned
+//! the user's ID.
+
+use async_trait::async_trait;
+
+use crate::domain::foundation::{DomainError, UserId};
+use crate::domain::provisioning::ProvisionedUser;
+
+/// One page of provisioned users.
+#[derive(Debug, Clone, Default)]
+pub struct ProvisionedUserList {
+    /// Users in this page, oldest first.
+    pub users: Vec<ProvisionedUser>,
+
+    /// Total number of matching users.
+    pub total: u64,
+}
+
+/// Port for persisting provisioned users.
+#[async_trait]
+pub trait ProvisionedUserRepository: Send + Sync {
+    /// Insert a newly provisioned user. Fails with `ConcurrentModification`
+    /// if a user with the same ID or `userName` was stored first.
+    async fn create(&self, user: &ProvisionedUser) -> Result<(), DomainError>;
+
+    /// Insert or update a provisioned user.
+    async fn save(&self, user: &ProvisionedUser) -> Result<(), DomainError>;
+
+    async fn find_by_id(&self, user_id: &UserId) -> Result<Option<ProvisionedUser>, DomainError>;
+
+    /// Finds a user by `userName`, ignoring case as SCIM requires.
+    async fn find_by_user_name(
+        &self,
+        user_name: &str,
+    ) -> Result<Option<ProvisionedUser>, DomainError>;
+
+    /// Lists the users `client_id` provisioned, oldest first, skipping
+    /// `offset` and returning at most `limit`.
+    async fn list(
+        &self,
+        client_id: &str,
+        offset: u32,
+        limit: u32,
+    ) -> Result<ProvisionedUserList, DomainError>;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn provisioned_user_repository_is_object_safe() {
+        fn _accepts_dyn(_repo: &dyn ProvisionedUserRepository) {}
+    }
+}
@@ -1,8 +1,8 @@
 //! Rate limiting port for protecting APIs and controlling costs.
 //!
-//! This port defines the interface for rate limiting operations using
-//! a token bucket algorithm. Implementations can use in-memory storage
-//! for testing or Redis for production.
+//! This port defines the interface for rate limiting operations.
+//! Implementations can use in-memory storage for testing or Redis for
+//! production, and choose the limiting algorithm from their configuration.
 
 use async_trait::async_trait;
 use serde::{Deserialize, Serialize};
@@ -13,7 +13,8 @@ use crate::domain::foundation::{Timestamp, UserId};
 /// Port for rate limiting operations.
 ///
 /// Implementations should be thread-safe and support concurrent access.
-/// The rate limiter uses a fixed-window counter algorithm for simplicity.
+/// The algorithm (fixed window, sliding window, token bucket, GCRA) is an
+/// adapter configuration concern.
 #[async_trait]
 pub trait RateLimiter: Send + Sync {
     /// Check if request is allowed, consuming a token if so.
@@ -34,7 +35,8 @@ pub trait RateLimiter: Send + Sync {
 
 /// Key identifying what to rate limit.
 ///
-/// Rate limits can be scoped globally, per-IP, per-user, or per-resource.
+/// Rate limits can be scoped globally, per-IP, per-user, per-service, or
+/// per-resource.
 #[derive(Debug, Clone, Hash, Eq, PartialEq)]
 pub struct RateLimitKey {
     /// The scope of this rate limit.
@@ -55,6 +57,8 @@ pub enum RateLimitScope {
     Ip,
     /// Per-authenticated-user rate limit.
     User,
+    /// Per-service-account rate limit, separate from user buckets.
+    Service,
     /// Per-resource rate limit (e.g., specific API endpoint).
     Resource,
 }
@@ -96,6 +100,24 @@ impl RateLimitKey {
         }
     }
 
+    /// Creates a service-account rate limit key.
+    pub fn service(client_id: &str) -> Self {
+        Self {
+            scope: RateLimitScope::Service,
+            identifier: client_id.to_string(),
+            resource: None,
+        }
+    }
+
+    /// Creates a service-account rate limit key for a specific resource.
+    pub fn service_resource(client_id: &str, resource: &str) -> Self {
+        Self {
+            scope: RateLimitScope::Service,
+            identifier: client_id.to_string(),
+            resource: Some(resource.to_string()),
+        }
+    }
+
     /// Returns the Redis key string for this rate limit key.
     pub fn to_redis_key(&self) -> String {
         match &self.resource {
@@ -117,6 +139,7 @@ impl RateLimitScope {
             RateLimitScope::Global => "global",
             RateLimitScope::Ip => "ip",
             RateLimitScope::User => "user",
+            RateLimitScope::Service => "service",
             RateLimitScope::Resource => "resource",
         }
     }
@@ -225,6 +248,21 @@ mod tests {
         assert_eq!(key.resource, Some("ai_completions".to_string()));
     }
 
+    #[test]
+    fn service_key_is_separate_from_user_key() {
+        let user_id = UserId::new("sync-worker").unwrap();
+        let service = RateLimitKey::service_resource("sync-worker", "ai_completions");
+        assert_eq!(service.scope, RateLimitScope::Service);
+        assert_eq!(
+            service.to_redis_key(),
+            "ratelimit:service:sync-worker:ai_completions"
+        );
+        assert_ne!(
+            RateLimitKey::service("sync-worker").to_redis_key(),
+            RateLimitKey::user(&user_id).to_redis_key()
+        );
+    }
+
     #[test]
     fn redis_key_format_without_resource() {
         let key = RateLimitKey::ip("10.0.0.1");
@@ -0,0 +1,181 @@
+//! Search Service Port - Full-text search across a user's decisions.
+//!
+//! Matches session titles and descriptions, component outputs, and the
+//! user and assistant messages of component conversations. Results only
+//! come from sessions the user owns or that are shared with one of their
+//! organizations, most relevant first.
+
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+
+use crate::domain::foundation::{ComponentType, CycleId, SessionId, Timestamp, UserId};
+
+/// Maximum length of a search query, in characters.
+pub const MAX_SEARCH_QUERY_LENGTH: usize = 200;
+
+/// Results per page when the caller does not ask for a limit.
+pub const DEFAULT_SEARCH_LIMIT: u32 = 20;
+
+/// Most results returned in one page.
+pub const MAX_SEARCH_LIMIT: u32 = 50;
+
+/// What a search result matched.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SearchHitKind {
+    /// A session's title or description.
+    Session,
+    /// The structured output of a component.
+    ComponentOutput,
+    /// A message in a component's conversation.
+    Message,
+}
+
+impl SearchHitKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SearchHitKind::Session => "session",
+            SearchHitKind::ComponentOutput => "component_output",
+            SearchHitKind::Message => "message",
+        }
+    }
+
+    pub fn parse(s: &str) -> Option<Self> {
+        [
+            SearchHitKind::Session,
+            SearchHitKind::ComponentOutput,
+            SearchHitKind::Message,
+        ]
+        .into_iter()
+        .find(|kind| kind.as_str() == s)
+    }
+}
+
+/// A validated search request.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SearchQuery {
+    /// Trimmed query text. Supports quoted phrases, `or` and `-excluded`.
+    pub text: String,
+    pub limit: u32,
+    pub offset: u32,
+}
+
+impl SearchQuery {
+    /// Validates the text and clamps the limit to `1..=MAX_SEARCH_LIMIT`.
+    pub fn new(text: &str, limit: Option<u32>, offset: Option<u32>) -> Result<Self, SearchError> {
+        let text = text.trim();
+        if text.is_empty() {
+            return Err(SearchError::InvalidQuery(
+                "Search query must not be empty".to_string(),
+            ));
+        }
+        if text.chars().count() > MAX_SEARCH_QUERY_LENGTH {
+            return Err(SearchError::InvalidQuery(format!(
+                "Search query must be at most {} characters",
+                MAX_SEARCH_QUERY_LENGTH
+            )));
+        }
+
+        Ok(Self {
+            text: text.to_string(),
+            limit: limit
+                .unwrap_or(DEFAULT_SEARCH_LIMIT)
+                .clamp(1, MAX_SEARCH_LIMIT),
+            offset: offset.unwrap_or(0),
+        })
+    }
+}
+
+/// One ranked search result.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SearchHit {
+    pub kind: SearchHitKind,
+    pub session_id: SessionId,
+    pub session_title: String,
+    /// Cycle holding the matched component or conversation.
+    pub cycle_id: Option<CycleId>,
+    /// Component whose output or conversation matched.
+    pub component_type: Option<ComponentType>,
+    /// Excerpt of the matched text with matching terms wrapped in `**`.
+    pub snippet: String,
+    /// Relevance score; higher is better. Only comparable within one search.
+    pub rank: f32,
+    /// When the matched session, component or message last changed.
+    pub updated_at: Timestamp,
+}
+
+/// One page of search results.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SearchResults {
+    pub hits: Vec<SearchHit>,
+    pub has_more: bool,
+}
+
+/// Errors from searching.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum SearchError {
+    #[error("Invalid search query: {0}")]
+    InvalidQuery(String),
+
+    #[error("Search storage error: {0}")]
+    Storage(String),
+}
+
+/// Port for ranked full-text search over a user's decisions.
+#[async_trait]
+pub trait SearchService: Send + Sync {
+    /// One page of results `user_id` may see, most relevant first.
+    async fn search(
+        &self,
+        user_id: &UserId,
+        query: &SearchQuery,
+    ) -> Result<SearchResults, SearchError>;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn query_is_trimmed_and_limit_clamped() {
+        let query = SearchQuery::new("  pricing  ", Some(500), None).unwrap();
+
+        assert_eq!(query.text, "pricing");
+        assert_eq!(query.limit, MAX_SEARCH_LIMIT);
+        assert_eq!(query.offset, 0);
+        assert_eq!(
+            SearchQuery::new("pricing", Some(0), Some(20))
+                .unwrap()
+                .limit,
+            1
+        );
+        assert_eq!(
+            SearchQuery::new("pricing", None, None).unwrap().limit,
+            DEFAULT_SEARCH_LIMIT
+        );
+    }
+
+    #[test]
+    fn blank_or_long_queries_are_rejected() {
+        assert!(matches!(
+            SearchQuery::new("   ", None, None),
+            Err(SearchError::InvalidQuery(_))
+        ));
+        assert!(matches!(
+            SearchQuery::new(&"a".repeat(MAX_SEARCH_QUERY_LENGTH + 1), None, None),
+            Err(SearchError::InvalidQuery(_))
+        ));
+    }
+
+    #[test]
+    fn hit_kind_round_trips() {
+        for kind in [
+            SearchHitKind::Session,
+            SearchHitKind::ComponentOutput,
+            SearchHitKind::Message,
+        ] {
+            assert_eq!(SearchHitKind::parse(kind.as_str()), Some(kind));
+        }
+        assert_eq!(SearchHitKind::parse("cycle"), None);
+    }
+}
@@ -0,0 +1,169 @@
+//! SecurityEventSink port - Interface for shipping security events to a SIEM.
+//!
+//! Audit-relevant domain events and authentication outcomes are normalized
+//! into [`SecurityEvent`]s and delivered in batches. Sinks only deliver; the
+//! exporter in front of them owns batching, retries, and backoff.
+
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+use crate::domain::foundation::Timestamp;
+
+/// Broad class of a security event.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SecurityEventCategory {
+    /// State change recorded for compliance (consent, membership, archival).
+    Audit,
+    /// Token validation outcome.
+    Authentication,
+}
+
+/// Whether the recorded action succeeded.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SecurityOutcome {
+    Success,
+    Failure,
+}
+
+/// A normalized security event.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SecurityEvent {
+    /// Unique ID (the domain event ID for audit events).
+    pub id: String,
+
+    /// When the action happened.
+    pub occurred_at: Timestamp,
+
+    pub category: SecurityEventCategory,
+
+    /// What happened, e.g. "consent.recorded.v1" or "auth.token_rejected".
+    pub action: String,
+
+    pub outcome: SecurityOutcome,
+
+    /// User who performed or attempted the action.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub actor_id: Option<String>,
+
+    /// Type of the affected resource (e.g. "Membership").
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub resource_type: Option<String>,
+
+    /// ID of the affected resource.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub resource_id: Option<String>,
+
+    /// Request correlation ID, for joining with application logs.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub correlation_id: Option<String>,
+
+    /// Action-specific details. Must not contain secrets or tokens.
+    #[serde(default, skip_serializing_if = "serde_json::Value::is_null")]
+    pub details: serde_json::Value,
+}
+
+impl SecurityEvent {
+    /// Creates an event with no actor, resource, or details.
+    pub fn new(
+        id: impl Into<String>,
+        category: SecurityEventCategory,
+        action: impl Into<String>,
+        outcome: SecurityOutcome,
+    ) -> Self {
+        Self {
+            id: id.into(),
+            occurred_at: Timestamp::now(),
+            category,
+            action: action.into(),
+            outcome,
+            actor_id: None,
+            resource_type: None,
+            resource_id: None,
+            correlation_id: None,
+            details: serde_json::Value::Null,
+        }
+    }
+
+    pub fn with_actor(mut self, actor_id: impl Into<String>) -> Self {
+        self.actor_id = Some(actor_id.into());
+        self
+    }
+
+    pub fn with_resource(
+        mut self,
+        resource_type: impl Into<String>,
+        resource_id: impl Into<String>,
+    ) -> Self {
+        self.resource_type = Some(resource_type.into());
+        self.resource_id = Some(resource_id.into());
+        self
+    }
+
+    pub fn with_details(mut self, details: serde_json::Value) -> Self {
+        self.details = details;
+        self
+    }
+}
+
+/// Errors from delivering a batch.
+#[derive(Debug, Clone, Error)]
+pub enum SecurityExportError {
+    /// Sink unreachable or temporarily failing; the batch may be retried.
+    #[error("SIEM sink unavailable: {0}")]
+    Unavailable(String),
+
+    /// Sink refused the batch; retrying will not help.
+    #[error("SIEM sink rejected batch: {0}")]
+    Rejected(String),
+}
+
+impl SecurityExportError {
+    /// Whether the batch should be retried.
+    pub fn is_retryable(&self) -> bool {
+        matches!(self, SecurityExportError::Unavailable(_))
+    }
+}
+
+/// Port for delivering security events to a SIEM.
+#[async_trait]
+pub trait SecurityEventSink: Send + Sync {
+    /// Deliver a batch. Either the whole batch is accepted or an error is returned.
+    async fn send_batch(&self, events: &[SecurityEvent]) -> Result<(), SecurityExportError>;
+
+    /// Sink name for logging.
+    fn name(&self) -> &'static str;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Compile-time check that trait is object-safe
+    #[allow(dead_code)]
+    fn assert_object_safe(_: &dyn SecurityEventSink) {}
+
+    #[test]
+    fn serializes_without_empty_fields() {
+        let event = SecurityEvent::new(
+            "evt-1",
+            SecurityEventCategory::Authentication,
+            "auth.token_rejected",
+            SecurityOutcome::Failure,
+        );
+
+        let json = serde_json::to_value(&event).unwrap();
+        assert_eq!(json["category"], "authentication");
+        assert_eq!(json["outcome"], "failure");
+        assert!(json.get("actor_id").is_none());
+        assert!(json.get("details").is_none());
+    }
+
+    #[test]
+    fn only_unavailable_is_retryable() {
+        assert!(SecurityExportError::Unavailable("timeout".into()).is_retryable());
+        assert!(!SecurityExportError::Rejected("400".into()).is_retryable());
+    }
+}
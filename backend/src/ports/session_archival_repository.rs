@@ -0,0 +1,57 @@
+//! SessionArchivalRepository port - Auto-archive state for idle sessions.
+//!
+//! Stores each session's `SessionArchivalSettings` and finds the sessions an
+//! `ArchivalPolicy` wants warned or archived, which form the work queue for
+//! `SessionArchiver`. A session's last activity is the latest update to it
+//! or any of its cycles; the owner's tier comes from their membership, with
+//! lapsed memberships counting as free.
+
+use async_trait::async_trait;
+
+use crate::domain::foundation::{DomainError, SessionId, Timestamp, UserId};
+use crate::domain::membership::MembershipTier;
+use crate::domain::session::{ArchivalPolicy, SessionArchivalSettings};
+
+/// An active session the policy wants warned or archived.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ArchivalCandidate {
+    pub session_id: SessionId,
+    pub user_id: UserId,
+    pub title: String,
+    /// The owner's effective membership tier.
+    pub tier: MembershipTier,
+    pub last_activity: Timestamp,
+    /// Stored settings, or the defaults if the session has none yet.
+    pub settings: SessionArchivalSettings,
+}
+
+/// Port for auto-archive settings and candidates.
+#[async_trait]
+pub trait SessionArchivalRepository: Send + Sync {
+    /// The session's stored settings, if any.
+    async fn find_settings(
+        &self,
+        session_id: &SessionId,
+    ) -> Result<Option<SessionArchivalSettings>, DomainError>;
+
+    /// Insert or update a session's settings.
+    async fn save_settings(&self, settings: &SessionArchivalSettings) -> Result<(), DomainError>;
+
+    /// Active, non-excluded sessions for which `policy` decides to warn or
+    /// archive at `now`, least recently active first.
+    async fn list_due(
+        &self,
+        policy: &ArchivalPolicy,
+        now: Timestamp,
+        limit: u32,
+    ) -> Result<Vec<ArchivalCandidate>, DomainError>;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Compile-time check that trait is object-safe
+    #[allow(dead_code)]
+    fn assert_object_safe(_: &dyn SessionArchivalRepository) {}
+}
@@ -0,0 +1,55 @@
+//! SessionColdStorageRepository port - Which sessions live in cold storage.
+//!
+//! Holds a `ColdStorageRecord` for every session whose cycles were moved to
+//! the document store, and finds the archived, finished sessions a
+//! `ColdStoragePolicy` wants moved next. A session is finished when none of
+//! its cycles is still active.
+
+use async_trait::async_trait;
+
+use crate::domain::foundation::{CycleId, DomainError, SessionId, Timestamp, UserId};
+use crate::domain::session::ColdStorageRecord;
+
+/// An archived, finished session still held in the database.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ColdStorageCandidate {
+    pub session_id: SessionId,
+    pub user_id: UserId,
+    pub archived_at: Timestamp,
+}
+
+/// Port for cold storage records and candidates.
+#[async_trait]
+pub trait SessionColdStorageRepository: Send + Sync {
+    /// Insert or replace a session's record.
+    async fn save(&self, record: &ColdStorageRecord) -> Result<(), DomainError>;
+
+    /// The session's record, if it is in cold storage.
+    async fn find(&self, session_id: &SessionId) -> Result<Option<ColdStorageRecord>, DomainError>;
+
+    /// The record of the cold session holding `cycle_id`, if any.
+    async fn find_by_cycle(
+        &self,
+        cycle_id: &CycleId,
+    ) -> Result<Option<ColdStorageRecord>, DomainError>;
+
+    /// Remove a session's record. Removing a missing record is not an error.
+    async fn delete(&self, session_id: &SessionId) -> Result<(), DomainError>;
+
+    /// Finished sessions archived before `archived_before` that still have
+    /// cycles in the database, longest archived first.
+    async fn list_candidates(
+        &self,
+        archived_before: Timestamp,
+        limit: u32,
+    ) -> Result<Vec<ColdStorageCandidate>, DomainError>;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Compile-time check that trait is object-safe
+    #[allow(dead_code)]
+    fn assert_object_safe(_: &dyn SessionColdStorageRepository) {}
+}
@@ -8,8 +8,12 @@
 //! - **Read-optimized**: Can use caching, denormalized views
 //! - **Separated from write**: CQRS pattern for scalability
 //! - **Search support**: Full-text search on title and description
+//! - **Organization support**: Sessions shared with a team, across members
+//! - **Trash**: Deleted sessions are only listed through `list_trashed`
 
-use crate::domain::foundation::{DomainError, SessionId, SessionStatus, Timestamp, UserId};
+use crate::domain::foundation::{
+    DomainError, OrganizationId, SessionId, SessionStatus, Timestamp, UserId,
+};
 use async_trait::async_trait;
 use serde::{Deserialize, Serialize};
 
@@ -33,6 +37,16 @@ pub trait SessionReader: Send + Sync {
         options: &ListOptions,
     ) -> Result<SessionList, DomainError>;
 
+    /// List sessions shared with an organization, whoever owns them.
+    ///
+    /// Returns sessions ordered by updated_at descending. Callers check
+    /// that the requesting user is a member.
+    async fn list_by_organization(
+        &self,
+        organization_id: &OrganizationId,
+        options: &ListOptions,
+    ) -> Result<SessionList, DomainError>;
+
     /// Search sessions by title/description.
     ///
     /// Performs full-text search across title and description fields.
@@ -49,6 +63,21 @@ pub trait SessionReader: Send + Sync {
         user_id: &UserId,
         status: SessionStatus,
     ) -> Result<u64, DomainError>;
+
+    /// List a user's sessions in the trash.
+    ///
+    /// Returns sessions ordered by deleted_at descending.
+    async fn list_trashed(&self, user_id: &UserId) -> Result<Vec<TrashedSession>, DomainError>;
+
+    /// List sessions of any user deleted before `deleted_before`.
+    ///
+    /// Returns at most `limit` sessions, longest deleted first. Used by the
+    /// purge job once their retention window has ended.
+    async fn list_purge_candidates(
+        &self,
+        deleted_before: Timestamp,
+        limit: u32,
+    ) -> Result<Vec<TrashedSession>, DomainError>;
 }
 
 /// Options for listing sessions.
@@ -63,8 +92,12 @@ pub struct ListOptions {
     /// Filter by status (None = all statuses).
     pub status: Option<SessionStatus>,
 
-    /// Include archived sessions.
+    /// Include archived sessions. Deleted sessions are only listed when
+    /// `status` asks for them.
     pub include_archived: bool,
+
+    /// Only sessions shared with this organization (None = any).
+    pub organization_id: Option<OrganizationId>,
 }
 
 impl ListOptions {
@@ -75,6 +108,7 @@ impl ListOptions {
             offset: Some((page.saturating_sub(1)) * per_page),
             status: None,
             include_archived: false,
+            organization_id: None,
         }
     }
 
@@ -89,6 +123,12 @@ impl ListOptions {
         self.status = Some(status);
         self
     }
+
+    /// Filter to sessions shared with an organization.
+    pub fn with_organization(mut self, organization_id: OrganizationId) -> Self {
+        self.organization_id = Some(organization_id);
+        self
+    }
 }
 
 /// Paginated list of sessions.
@@ -113,6 +153,9 @@ pub struct SessionView {
     /// Owner's user ID.
     pub user_id: UserId,
 
+    /// Organization the session is shared with, if any.
+    pub organization_id: Option<OrganizationId>,
+
     /// Session title.
     pub title: String,
 
@@ -149,6 +192,35 @@ pub struct SessionSummary {
 
     /// When the session was last updated.
     pub updated_at: Timestamp,
+
+    /// Whether the session's cycles are in cold storage. They are brought
+    /// back transparently when the session is opened, which takes longer.
+    pub in_cold_storage: bool,
+}
+
+/// A session in the trash.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TrashedSession {
+    /// Session ID.
+    pub id: SessionId,
+
+    /// Owner's user ID.
+    pub user_id: UserId,
+
+    /// Session title.
+    pub title: String,
+
+    /// Status the session returns to when restored.
+    pub status_before_deletion: SessionStatus,
+
+    /// Number of cycles.
+    pub cycle_count: u32,
+
+    /// When the session was moved to the trash.
+    pub deleted_at: Timestamp,
+
+    /// When the session will be purged unless restored.
+    pub purge_after: Timestamp,
 }
 
 #[cfg(test)]
@@ -183,4 +255,13 @@ mod tests {
         let options = ListOptions::default().with_archived();
         assert!(options.include_archived);
     }
+
+    #[test]
+    fn list_options_default_spans_organizations() {
+        let organization_id = OrganizationId::new();
+        assert!(ListOptions::default().organization_id.is_none());
+
+        let options = ListOptions::paginated(1, 10).with_organization(organization_id);
+        assert_eq!(options.organization_id, Some(organization_id));
+    }
 }
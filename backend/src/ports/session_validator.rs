@@ -28,7 +28,7 @@
 
 use async_trait::async_trait;
 
-use crate::domain::foundation::{AuthenticatedUser, AuthError};
+use crate::domain::foundation::{AuthError, AuthenticatedPrincipal, AuthenticatedUser};
 
 /// Validates access tokens and extracts user identity.
 ///
@@ -58,6 +58,15 @@ pub trait SessionValidator: Send + Sync {
     /// * `Err(AuthError::TokenExpired)` - Token signature valid but expired
     /// * `Err(AuthError::ServiceUnavailable)` - Auth provider unreachable
     async fn validate(&self, token: &str) -> Result<AuthenticatedUser, AuthError>;
+
+    /// Validate a token that may belong to a user or a service.
+    ///
+    /// Client-credentials tokens carry no user profile, so `validate` rejects
+    /// them. Validators that recognise such tokens override this to return
+    /// `AuthenticatedPrincipal::Service`; the default accepts users only.
+    async fn validate_principal(&self, token: &str) -> Result<AuthenticatedPrincipal, AuthError> {
+        self.validate(token).await.map(AuthenticatedPrincipal::User)
+    }
 }
 
 #[cfg(test)]
@@ -127,6 +136,18 @@ mod tests {
         assert!(matches!(result, Err(AuthError::InvalidToken)));
     }
 
+    #[tokio::test]
+    async fn validate_principal_defaults_to_user() {
+        let validator = TestSessionValidator::new();
+        validator.add_valid_token("valid-token-123", test_user());
+
+        let principal = validator
+            .validate_principal("valid-token-123")
+            .await
+            .unwrap();
+        assert_eq!(principal.as_user().unwrap().id.as_str(), "user-123");
+    }
+
     #[tokio::test]
     async fn session_validator_trait_is_send_sync() {
         fn assert_send_sync<T: Send + Sync + ?Sized>() {}
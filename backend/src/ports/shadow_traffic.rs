@@ -0,0 +1,173 @@
+//! Shadow traffic port - Records side-by-side runs of a candidate model or
+//! prompt against production completions.
+//!
+//! A sample of real `CompletionRequest`s is replayed to a candidate variant
+//! after the user's response has been served. Both outputs are recorded
+//! here with latency, tokens, and whether structured data could be
+//! extracted, and [`ShadowMetrics`] summarizes them per variant. Candidate
+//! output is never shown to users.
+
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::domain::foundation::{ComponentType, DomainError, Timestamp};
+
+/// One model's answer to a shadowed request.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ShadowOutput {
+    pub model: String,
+    pub content: String,
+    pub latency_ms: u64,
+    pub prompt_tokens: u32,
+    pub completion_tokens: u32,
+    pub cost_cents: u32,
+    /// Whether `DataExtractor` parsed structured data from the content.
+    /// `None` when the request was not for a component.
+    pub extraction_succeeded: Option<bool>,
+}
+
+/// A production completion and the candidate's answer to the same request.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ShadowComparison {
+    pub id: Uuid,
+    /// Name of the candidate variant.
+    pub variant: String,
+    pub component_type: Option<ComponentType>,
+    /// Trace of the production request, for finding it in logs.
+    pub trace_id: String,
+    pub primary: ShadowOutput,
+    /// The candidate's output, or why it failed.
+    pub candidate: Result<ShadowOutput, String>,
+    pub recorded_at: Timestamp,
+}
+
+/// Summary of a variant's comparisons.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ShadowMetrics {
+    pub variant: String,
+    pub samples: u64,
+    pub candidate_failures: u64,
+    /// Share of component requests with extractable output, 0.0-1.0.
+    pub primary_extraction_rate: Option<f64>,
+    pub candidate_extraction_rate: Option<f64>,
+    pub mean_primary_latency_ms: Option<f64>,
+    pub mean_candidate_latency_ms: Option<f64>,
+    pub mean_primary_completion_tokens: Option<f64>,
+    pub mean_candidate_completion_tokens: Option<f64>,
+    pub primary_cost_cents: u64,
+    pub candidate_cost_cents: u64,
+}
+
+fn mean(values: impl Iterator<Item = f64>) -> Option<f64> {
+    let (sum, count) = values.fold((0.0, 0u64), |(sum, count), v| (sum + v, count + 1));
+    (count > 0).then(|| sum / count as f64)
+}
+
+fn extraction_rate<'a>(outputs: impl Iterator<Item = &'a ShadowOutput>) -> Option<f64> {
+    mean(
+        outputs
+            .filter_map(|o| o.extraction_succeeded)
+            .map(|ok| if ok { 1.0 } else { 0.0 }),
+    )
+}
+
+impl ShadowMetrics {
+    /// Summarizes comparisons of one variant.
+    ///
+    /// Candidate rates and means cover successful candidate runs only; the
+    /// primary's cover every sample.
+    pub fn from_comparisons(variant: impl Into<String>, comparisons: &[ShadowComparison]) -> Self {
+        let primary = || comparisons.iter().map(|c| &c.primary);
+        let candidate = || comparisons.iter().filter_map(|c| c.candidate.as_ref().ok());
+        Self {
+            variant: variant.into(),
+            samples: comparisons.len() as u64,
+            candidate_failures: comparisons.iter().filter(|c| c.candidate.is_err()).count() as u64,
+            primary_extraction_rate: extraction_rate(primary()),
+            candidate_extraction_rate: extraction_rate(candidate()),
+            mean_primary_latency_ms: mean(primary().map(|o| o.latency_ms as f64)),
+            mean_candidate_latency_ms: mean(candidate().map(|o| o.latency_ms as f64)),
+            mean_primary_completion_tokens: mean(primary().map(|o| o.completion_tokens as f64)),
+            mean_candidate_completion_tokens: mean(candidate().map(|o| o.completion_tokens as f64)),
+            primary_cost_cents: primary().map(|o| o.cost_cents as u64).sum(),
+            candidate_cost_cents: candidate().map(|o| o.cost_cents as u64).sum(),
+        }
+    }
+}
+
+/// Storage for shadow comparisons.
+#[async_trait]
+pub trait ShadowComparisonRepository: Send + Sync {
+    /// Stores a comparison.
+    async fn record(&self, comparison: &ShadowComparison) -> Result<(), DomainError>;
+
+    /// Most recent comparisons of a variant, newest first.
+    async fn list_by_variant(
+        &self,
+        variant: &str,
+        limit: u32,
+    ) -> Result<Vec<ShadowComparison>, DomainError>;
+
+    /// Every comparison of a variant, for computing metrics.
+    async fn all_by_variant(&self, variant: &str) -> Result<Vec<ShadowComparison>, DomainError>;
+
+    /// Names of every variant with recorded comparisons.
+    async fn variants(&self) -> Result<Vec<String>, DomainError>;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn output(latency_ms: u64, extraction_succeeded: Option<bool>) -> ShadowOutput {
+        ShadowOutput {
+            model: "model".to_string(),
+            content: "{}".to_string(),
+            latency_ms,
+            prompt_tokens: 100,
+            completion_tokens: 20,
+            cost_cents: 2,
+            extraction_succeeded,
+        }
+    }
+
+    fn comparison(candidate: Result<ShadowOutput, String>) -> ShadowComparison {
+        ShadowComparison {
+            id: Uuid::new_v4(),
+            variant: "terse-prompt".to_string(),
+            component_type: Some(ComponentType::Objectives),
+            trace_id: "trace".to_string(),
+            primary: output(100, Some(true)),
+            candidate,
+            recorded_at: Timestamp::now(),
+        }
+    }
+
+    #[test]
+    fn metrics_compare_successful_candidate_runs() {
+        let comparisons = vec![
+            comparison(Ok(output(50, Some(true)))),
+            comparison(Ok(output(150, Some(false)))),
+            comparison(Err("timeout".to_string())),
+        ];
+
+        let metrics = ShadowMetrics::from_comparisons("terse-prompt", &comparisons);
+
+        assert_eq!(metrics.samples, 3);
+        assert_eq!(metrics.candidate_failures, 1);
+        assert_eq!(metrics.primary_extraction_rate, Some(1.0));
+        assert_eq!(metrics.candidate_extraction_rate, Some(0.5));
+        assert_eq!(metrics.mean_candidate_latency_ms, Some(100.0));
+        assert_eq!(metrics.primary_cost_cents, 6);
+        assert_eq!(metrics.candidate_cost_cents, 4);
+    }
+
+    #[test]
+    fn metrics_of_no_samples_are_empty() {
+        let metrics = ShadowMetrics::from_comparisons("none", &[]);
+        assert_eq!(metrics.samples, 0);
+        assert_eq!(metrics.primary_extraction_rate, None);
+        assert_eq!(metrics.mean_primary_latency_ms, None);
+    }
+}
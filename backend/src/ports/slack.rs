@@ -0,0 +1,133 @@
+//! Slack Port - Sharing recommendations to Slack channels.
+//!
+//! A user connects each Slack workspace once with Slack's OAuth v2 flow,
+//! which installs the app and issues a bot token for that workspace. Shares
+//! are posted with the bot token and tracked as `SlackShare`s; pending shares
+//! double as the retry queue, polled with `list_due` like document deliveries.
+//!
+//! ```text
+//! authorization_url ──► Slack consent ──► code ──► exchange_code ──► SlackWorkspaceStore
+//!                                                                          │
+//! RecommendationDigest ──► post_recommendation(bot token, channel) ◄───────┘
+//! ```
+
+use async_trait::async_trait;
+use secrecy::Secret;
+
+use crate::domain::document::{RecommendationDigest, SlackShare};
+use crate::domain::foundation::{DomainError, SlackShareId, Timestamp, UserId};
+
+/// Errors from Slack's OAuth and Web APIs.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum SlackError {
+    /// The app was uninstalled or the token revoked; the user must reconnect.
+    #[error("Slack authorization failed: {0}")]
+    Unauthorized(String),
+
+    /// Slack rejected the request, e.g. an unknown or archived channel.
+    #[error("Slack API error: {0}")]
+    Api(String),
+
+    /// Slack could not be reached or is rate limiting.
+    #[error("Slack unavailable: {0}")]
+    Unavailable(String),
+}
+
+impl SlackError {
+    /// Whether retrying the same request later may succeed.
+    pub fn is_retryable(&self) -> bool {
+        matches!(self, SlackError::Unavailable(_))
+    }
+}
+
+/// A Slack workspace the app is installed in.
+#[derive(Debug, Clone)]
+pub struct SlackWorkspace {
+    /// Slack team ID, e.g. `T0123ABCD`.
+    pub team_id: String,
+    pub team_name: String,
+    /// Bot token (`xoxb-`) scoped to the workspace.
+    pub bot_token: Secret<String>,
+    pub connected_at: Timestamp,
+}
+
+/// Port for Slack's OAuth and Web APIs.
+#[async_trait]
+pub trait SlackClient: Send + Sync {
+    /// URL of Slack's install screen; `state` is echoed back to the caller.
+    fn authorization_url(&self, state: &str) -> String;
+
+    /// Exchanges an authorization code for the workspace's bot token.
+    async fn exchange_code(&self, code: &str) -> Result<SlackWorkspace, SlackError>;
+
+    /// Posts a formatted recommendation to `channel`, returning the
+    /// message's timestamp ID.
+    async fn post_recommendation(
+        &self,
+        bot_token: &Secret<String>,
+        channel: &str,
+        digest: &RecommendationDigest,
+    ) -> Result<String, SlackError>;
+
+    /// Posts `text` (Slack mrkdwn) to `channel`, returning the message's
+    /// timestamp ID. Used by integrations.
+    async fn post_message(
+        &self,
+        bot_token: &Secret<String>,
+        channel: &str,
+        text: &str,
+    ) -> Result<String, SlackError>;
+}
+
+/// Port for storing the Slack workspaces each user has connected.
+#[async_trait]
+pub trait SlackWorkspaceStore: Send + Sync {
+    async fn get(
+        &self,
+        user_id: &UserId,
+        team_id: &str,
+    ) -> Result<Option<SlackWorkspace>, DomainError>;
+
+    /// The user's workspaces, by name.
+    async fn list(&self, user_id: &UserId) -> Result<Vec<SlackWorkspace>, DomainError>;
+
+    /// Saves, replacing any existing connection to the same workspace.
+    async fn save(&self, user_id: &UserId, workspace: &SlackWorkspace) -> Result<(), DomainError>;
+
+    /// Removes the connection; succeeds if none is stored.
+    async fn delete(&self, user_id: &UserId, team_id: &str) -> Result<(), DomainError>;
+}
+
+/// Port for persisting Slack shares.
+#[async_trait]
+pub trait SlackShareRepository: Send + Sync {
+    /// Insert or update a share.
+    async fn save(&self, share: &SlackShare) -> Result<(), DomainError>;
+
+    /// Find a share by ID.
+    async fn find_by_id(&self, id: &SlackShareId) -> Result<Option<SlackShare>, DomainError>;
+
+    /// Pending shares whose next attempt is at or before `now`, oldest first.
+    async fn list_due(&self, now: Timestamp, limit: u32) -> Result<Vec<SlackShare>, DomainError>;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Compile-time check that traits are object-safe
+    #[allow(dead_code)]
+    fn assert_object_safe(
+        _: &dyn SlackClient,
+        _: &dyn SlackWorkspaceStore,
+        _: &dyn SlackShareRepository,
+    ) {
+    }
+
+    #[test]
+    fn only_outages_are_retryable() {
+        assert!(SlackError::Unavailable("ratelimited".to_string()).is_retryable());
+        assert!(!SlackError::Api("channel_not_found".to_string()).is_retryable());
+        assert!(!SlackError::Unauthorized("token_revoked".to_string()).is_retryable());
+    }
+}
@@ -0,0 +1,92 @@
+//! SloRecorder port - Interface for recording service level indicators.
+//!
+//! Handlers report each SLI event (good or bad) as it happens; the adapter
+//! keeps the rolling window, computes error budgets, and raises burn-rate
+//! alerts. Recording must be cheap and must never fail the caller.
+//!
+//! ## Indicators
+//!
+//! | SLI | Good event |
+//! |-----|------------|
+//! | `SendMessageLatency` | Send-message request finished under the latency threshold |
+//! | `ConversationStreamErrors` | AI response stream completed without error |
+//! | `WebhookProcessing` | Authentic payment webhook processed successfully |
+
+use std::fmt;
+use std::time::Duration;
+
+use serde::Serialize;
+
+/// Service level indicators tracked against an objective.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Sli {
+    /// Send-message end-to-end latency (judged against a threshold).
+    SendMessageLatency,
+
+    /// Conversation stream error rate.
+    ConversationStreamErrors,
+
+    /// Payment webhook processing success.
+    WebhookProcessing,
+}
+
+impl Sli {
+    /// All indicators, in reporting order.
+    pub const ALL: [Sli; 3] = [
+        Sli::SendMessageLatency,
+        Sli::ConversationStreamErrors,
+        Sli::WebhookProcessing,
+    ];
+
+    /// Stable identifier used in logs, events, and the admin API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Sli::SendMessageLatency => "send_message_latency",
+            Sli::ConversationStreamErrors => "conversation_stream_errors",
+            Sli::WebhookProcessing => "webhook_processing",
+        }
+    }
+}
+
+impl fmt::Display for Sli {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Port for recording SLI events.
+///
+/// # Example
+///
+/// ```ignore
+/// let started = Instant::now();
+/// let result = process().await;
+/// recorder.record_outcome(Sli::WebhookProcessing, result.is_ok());
+/// recorder.record_latency(Sli::SendMessageLatency, started.elapsed());
+/// ```
+pub trait SloRecorder: Send + Sync {
+    /// Record a good (`true`) or bad (`false`) event.
+    fn record_outcome(&self, sli: Sli, good: bool);
+
+    /// Record a latency; the adapter decides whether it was good.
+    fn record_latency(&self, sli: Sli, latency: Duration);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Compile-time check that trait is object-safe
+    #[allow(dead_code)]
+    fn assert_object_safe(_: &dyn SloRecorder) {}
+
+    #[test]
+    fn sli_identifiers_are_stable() {
+        assert_eq!(Sli::SendMessageLatency.to_string(), "send_message_latency");
+        assert_eq!(
+            serde_json::to_value(Sli::WebhookProcessing).unwrap(),
+            "webhook_processing"
+        );
+    }
+}
@@ -0,0 +1,99 @@
+//! Spreadsheet Parser Port - Reads uploaded spreadsheets into rows of text.
+//!
+//! Imports work on plain text cells so the mapping rules do not depend on
+//! the file format. Numbers are rendered without trailing `.0`.
+
+use std::fmt;
+
+/// Largest spreadsheet upload accepted (2 MB).
+pub const MAX_SPREADSHEET_BYTES: usize = 2 * 1024 * 1024;
+
+/// Errors that can occur while reading a spreadsheet.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum SpreadsheetError {
+    #[error("Unsupported spreadsheet format: {0}")]
+    UnsupportedFormat(String),
+
+    #[error("Could not read spreadsheet: {0}")]
+    Malformed(String),
+}
+
+/// Spreadsheet file formats that can be imported.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SpreadsheetFormat {
+    Csv,
+    Xlsx,
+}
+
+impl SpreadsheetFormat {
+    /// Detects the format from an upload's content type, falling back to the
+    /// file extension for generic types like `application/octet-stream`.
+    pub fn detect(content_type: &str, file_name: &str) -> Result<Self, SpreadsheetError> {
+        let mime = content_type
+            .split(';')
+            .next()
+            .unwrap_or_default()
+            .trim()
+            .to_ascii_lowercase();
+        match mime.as_str() {
+            "text/csv" | "application/csv" => return Ok(SpreadsheetFormat::Csv),
+            "application/vnd.openxmlformats-officedocument.spreadsheetml.sheet" => {
+                return Ok(SpreadsheetFormat::Xlsx)
+            }
+            _ => {}
+        }
+        let extension = file_name
+            .rsplit_once('.')
+            .map(|(_, ext)| ext.to_ascii_lowercase())
+            .unwrap_or_default();
+        match extension.as_str() {
+            "csv" => Ok(SpreadsheetFormat::Csv),
+            "xlsx" => Ok(SpreadsheetFormat::Xlsx),
+            _ => Err(SpreadsheetError::UnsupportedFormat(if mime.is_empty() {
+                file_name.to_string()
+            } else {
+                mime
+            })),
+        }
+    }
+}
+
+impl fmt::Display for SpreadsheetFormat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SpreadsheetFormat::Csv => write!(f, "csv"),
+            SpreadsheetFormat::Xlsx => write!(f, "xlsx"),
+        }
+    }
+}
+
+/// Port for reading spreadsheets.
+pub trait SpreadsheetParser: Send + Sync {
+    /// Reads the first sheet as rows of trimmed cell text.
+    fn parse(
+        &self,
+        format: SpreadsheetFormat,
+        bytes: &[u8],
+    ) -> Result<Vec<Vec<String>>, SpreadsheetError>;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn detects_format_from_content_type_then_extension() {
+        assert_eq!(
+            SpreadsheetFormat::detect("text/csv; charset=utf-8", "x.bin"),
+            Ok(SpreadsheetFormat::Csv)
+        );
+        assert_eq!(
+            SpreadsheetFormat::detect("application/octet-stream", "Matrix.XLSX"),
+            Ok(SpreadsheetFormat::Xlsx)
+        );
+        assert!(matches!(
+            SpreadsheetFormat::detect("application/pdf", "matrix.pdf"),
+            Err(SpreadsheetError::UnsupportedFormat(_))
+        ));
+    }
+}
@@ -0,0 +1,51 @@
+//! Team profile ports - Member profile summaries, organization settings,
+//! and extra context for agents.
+
+use async_trait::async_trait;
+
+use crate::domain::foundation::{DomainError, UserId};
+use crate::domain::profile::{ProfileSummary, TeamProfileSettings};
+
+/// Port for the latest profile summary of each user.
+#[async_trait]
+pub trait ProfileSummaryRepository: Send + Sync {
+    /// Insert or replace the user's summary.
+    async fn save(&self, user_id: &UserId, summary: &ProfileSummary) -> Result<(), DomainError>;
+
+    /// The user's summary, if one has been computed.
+    async fn find_by_user(&self, user_id: &UserId) -> Result<Option<ProfileSummary>, DomainError>;
+
+    /// Delete the user's summary, returning how many were removed.
+    async fn delete_for_user(&self, user_id: &UserId) -> Result<u64, DomainError>;
+}
+
+/// Port for per-organization team profile settings.
+#[async_trait]
+pub trait TeamProfileSettingsRepository: Send + Sync {
+    /// Settings for the organization, or `None` if never configured.
+    async fn get(&self, organization: &str) -> Result<Option<TeamProfileSettings>, DomainError>;
+
+    /// Insert or replace the organization's settings.
+    async fn save(&self, settings: &TeamProfileSettings) -> Result<(), DomainError>;
+}
+
+/// Supplies extra system-prompt context for a user's agent conversations.
+#[async_trait]
+pub trait AgentContextProvider: Send + Sync {
+    /// Context to add for this user, or `None` when there is nothing to add.
+    async fn context_for(&self, user_id: &UserId) -> Result<Option<String>, DomainError>;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Compile-time check that traits are object-safe
+    #[allow(dead_code)]
+    fn assert_object_safe(
+        _: &dyn ProfileSummaryRepository,
+        _: &dyn TeamProfileSettingsRepository,
+        _: &dyn AgentContextProvider,
+    ) {
+    }
+}
@@ -0,0 +1,102 @@
+//! Teams Port - Sharing recommendations to Microsoft Teams channels.
+//!
+//! Teams channels are registered by an organization admin as incoming
+//! webhooks (a classic connector or a Workflows trigger), so no per-user
+//! OAuth is needed: posting an Adaptive Card to the channel's webhook URL is
+//! the whole integration. Shares are tracked as `TeamsShare`s; pending shares
+//! double as the retry queue, polled with `list_due` like Slack shares.
+//!
+//! ```text
+//! OrganizationChatSettings ──► TeamsChannel.webhook_url
+//!                                        │
+//! RecommendationDigest ──► post_recommendation ──► Teams channel
+//! ```
+
+use async_trait::async_trait;
+use secrecy::Secret;
+
+use crate::domain::document::{OrganizationChatSettings, RecommendationDigest, TeamsShare};
+use crate::domain::foundation::{DomainError, TeamsShareId, Timestamp};
+
+/// Errors from posting to a Teams incoming webhook.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum TeamsError {
+    /// The webhook was removed or disabled; an admin must register it again.
+    #[error("Teams webhook not found: {0}")]
+    WebhookGone(String),
+
+    /// Teams rejected the card.
+    #[error("Teams rejected the message: {0}")]
+    Rejected(String),
+
+    /// Teams could not be reached or is throttling.
+    #[error("Teams unavailable: {0}")]
+    Unavailable(String),
+}
+
+impl TeamsError {
+    /// Whether retrying the same request later may succeed.
+    pub fn is_retryable(&self) -> bool {
+        matches!(self, TeamsError::Unavailable(_))
+    }
+}
+
+/// Port for posting to Teams incoming webhooks.
+#[async_trait]
+pub trait TeamsClient: Send + Sync {
+    /// Posts the recommendation as an Adaptive Card. The webhook URL embeds
+    /// its own credential, so it is kept secret.
+    async fn post_recommendation(
+        &self,
+        webhook_url: &Secret<String>,
+        digest: &RecommendationDigest,
+    ) -> Result<(), TeamsError>;
+}
+
+/// Port for persisting Teams shares.
+#[async_trait]
+pub trait TeamsShareRepository: Send + Sync {
+    /// Insert or update a share.
+    async fn save(&self, share: &TeamsShare) -> Result<(), DomainError>;
+
+    /// Find a share by ID.
+    async fn find_by_id(&self, id: &TeamsShareId) -> Result<Option<TeamsShare>, DomainError>;
+
+    /// Pending shares whose next attempt is at or before `now`, oldest first.
+    async fn list_due(&self, now: Timestamp, limit: u32) -> Result<Vec<TeamsShare>, DomainError>;
+}
+
+/// Port for each organization's chat integration settings. Webhook URLs are
+/// credentials, so implementations must not store them in plain text.
+#[async_trait]
+pub trait OrganizationChatSettingsRepository: Send + Sync {
+    /// Settings for `organization`, or None if it never chose a platform.
+    async fn get(
+        &self,
+        organization: &str,
+    ) -> Result<Option<OrganizationChatSettings>, DomainError>;
+
+    /// Saves, replacing any existing settings for the organization.
+    async fn save(&self, settings: &OrganizationChatSettings) -> Result<(), DomainError>;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Compile-time check that traits are object-safe
+    #[allow(dead_code)]
+    fn assert_object_safe(
+        _: &dyn TeamsClient,
+        _: &dyn TeamsShareRepository,
+        _: &dyn OrganizationChatSettingsRepository,
+    ) {
+    }
+
+    #[test]
+    fn only_outages_are_retryable() {
+        assert!(TeamsError::Unavailable("429".to_string()).is_retryable());
+        assert!(!TeamsError::Rejected("400".to_string()).is_retryable());
+        assert!(!TeamsError::WebhookGone("404".to_string()).is_retryable());
+    }
+}
@@ -0,0 +1,91 @@
+//! Token revocation port - Invalidates access tokens before they expire.
+//!
+//! Access tokens are stateless JWTs, so logging out at the identity provider
+//! does not stop a copied token from working until its `exp`. This port
+//! records a per-user cutoff instead: every token issued before the cutoff
+//! is rejected by the auth middleware, while tokens from a fresh
+//! login keep working.
+//!
+//! A cutoff only needs to outlive the tokens it revokes, so stores may
+//! expire it after the longest access token lifetime.
+
+use async_trait::async_trait;
+
+use crate::domain::foundation::{DomainError, Timestamp, UserId};
+
+/// Per-user revocation cutoffs consulted on every authenticated request.
+#[async_trait]
+pub trait TokenRevocationStore: Send + Sync {
+    /// Revokes every token issued to the user before `revoked_at`. Stores
+    /// keep the cutoff to at least the millisecond.
+    async fn revoke_all_for_user(
+        &self,
+        user_id: &UserId,
+        revoked_at: Timestamp,
+    ) -> Result<(), DomainError>;
+
+    /// Returns the user's current cutoff, if one is still in effect.
+    async fn revoked_before(&self, user_id: &UserId) -> Result<Option<Timestamp>, DomainError>;
+}
+
+/// Whether a token issued at `issued_at` falls under the user's cutoff.
+///
+/// `iat` is truncated to the second, so a token issued in the same second
+/// as the cutoff, before or after it, counts as revoked; one issued in any
+/// later second is accepted. Tokens without `iat` cannot be told apart from
+/// old ones and are revoked while a cutoff is in effect.
+pub fn is_token_revoked(issued_at: Option<&Timestamp>, cutoff: Option<&Timestamp>) -> bool {
+    match (issued_at, cutoff) {
+        (_, None) => false,
+        (None, Some(_)) => true,
+        (Some(issued_at), Some(cutoff)) => issued_at.is_before(cutoff),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn tokens_are_valid_without_cutoff() {
+        let issued_at = Timestamp::from_unix_secs(1_000);
+        assert!(!is_token_revoked(Some(&issued_at), None));
+        assert!(!is_token_revoked(None, None));
+    }
+
+    #[test]
+    fn tokens_issued_before_cutoff_are_revoked() {
+        let cutoff = Timestamp::from_unix_millis(1_000_500);
+        assert!(is_token_revoked(
+            Some(&Timestamp::from_unix_secs(999)),
+            Some(&cutoff)
+        ));
+        assert!(is_token_revoked(
+            Some(&Timestamp::from_unix_secs(1_000)),
+            Some(&cutoff)
+        ));
+    }
+
+    #[test]
+    fn tokens_issued_after_cutoff_are_accepted() {
+        let cutoff = Timestamp::from_unix_secs(1_000);
+        assert!(!is_token_revoked(
+            Some(&Timestamp::from_unix_secs(1_000)),
+            Some(&cutoff)
+        ));
+        assert!(!is_token_revoked(
+            Some(&Timestamp::from_unix_secs(1_001)),
+            Some(&cutoff)
+        ));
+        assert!(!is_token_revoked(
+            Some(&Timestamp::from_unix_secs(1_001)),
+            Some(&Timestamp::from_unix_millis(1_000_500))
+        ));
+    }
+
+    #[test]
+    fn tokens_without_issue_time_are_revoked_under_cutoff() {
+        let cutoff = Timestamp::from_unix_secs(1_000);
+        assert!(is_token_revoked(None, Some(&cutoff)));
+    }
+}
@@ -0,0 +1,87 @@
+//! Usage report repository port - Ledger of AI usage billed through metered
+//! subscriptions.
+//!
+//! The reporter records one entry per user and UTC day once the payment
+//! provider accepts it, so a day is never billed twice. Reconciliation
+//! compares the ledger with what the `UsageTracker` holds now.
+
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+
+use crate::domain::foundation::{DomainError, Timestamp, UserId};
+
+/// A day of one user's usage as reported to the payment provider.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ReportedUsage {
+    /// User the usage belongs to.
+    pub user_id: UserId,
+    /// Start of the UTC day the usage happened on.
+    pub usage_day: Timestamp,
+    /// Subscription the usage was billed to.
+    pub subscription_id: String,
+    /// Tokens billed.
+    pub tokens: u64,
+    /// Tracked AI cost of the usage, in cents.
+    pub cost_cents: u32,
+    /// Key the report was sent with.
+    pub idempotency_key: String,
+    /// When the payment provider accepted the report.
+    pub reported_at: Timestamp,
+}
+
+impl ReportedUsage {
+    /// Idempotency key for a user's usage on a day.
+    ///
+    /// Derived from the user and day alone, so a retried report reuses it.
+    pub fn idempotency_key_for(user_id: &UserId, usage_day: &Timestamp) -> String {
+        format!(
+            "usage-{}-{}",
+            user_id,
+            usage_day.as_datetime().format("%Y-%m-%d")
+        )
+    }
+}
+
+/// Ledger of reported usage, keyed by user and day.
+#[async_trait]
+pub trait UsageReportRepository: Send + Sync {
+    /// Finds the report for a user's day, if it was reported.
+    async fn find(
+        &self,
+        user_id: &UserId,
+        usage_day: &Timestamp,
+    ) -> Result<Option<ReportedUsage>, DomainError>;
+
+    /// Saves a report, replacing any earlier one for the same user and day.
+    async fn save(&self, report: &ReportedUsage) -> Result<(), DomainError>;
+
+    /// Lists reports for days from `from_day` to `to_day` inclusive, oldest
+    /// first.
+    async fn list_between(
+        &self,
+        from_day: &Timestamp,
+        to_day: &Timestamp,
+    ) -> Result<Vec<ReportedUsage>, DomainError>;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn idempotency_key_names_user_and_day() {
+        let user_id = UserId::new("user-1").unwrap();
+        // 2024-01-15T10:30:00Z
+        let day = Timestamp::from_unix_secs(1705314600).start_of_day();
+
+        assert_eq!(
+            ReportedUsage::idempotency_key_for(&user_id, &day),
+            "usage-user-1-2024-01-15"
+        );
+    }
+
+    #[test]
+    fn usage_report_repository_is_object_safe() {
+        fn _accepts_dyn(_repo: &dyn UsageReportRepository) {}
+    }
+}
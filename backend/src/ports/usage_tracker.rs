@@ -1,12 +1,100 @@
 //! UsageTracker port - Interface for tracking AI usage and costs.
 //!
 //! This port defines how AI token usage is tracked and queried,
-//! enabling cost attribution per user, session, and daily limits.
+//! enabling cost attribution per user, session, cycle, and daily limits.
+//!
+//! Costs are priced from a [`PriceTable`] and kept in micro-cents
+//! (millionths of a cent) so that small requests, which cost well under a
+//! cent each, still add up correctly in rollups.
+
+use std::collections::HashMap;
 
 use async_trait::async_trait;
 use serde::{Deserialize, Serialize};
 
-use crate::domain::foundation::{ComponentType, SessionId, Timestamp, UserId};
+use crate::domain::foundation::{ComponentType, CycleId, SessionId, Timestamp, UserId};
+
+/// Micro-cents per cent.
+pub const MICRO_CENTS_PER_CENT: u64 = 1_000_000;
+
+/// Price of one model, in cents per million tokens.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ModelPrice {
+    /// Cents per million prompt tokens.
+    pub input_cents_per_mtok: u64,
+    /// Cents per million completion tokens.
+    pub output_cents_per_mtok: u64,
+}
+
+impl ModelPrice {
+    pub fn new(input_cents_per_mtok: u64, output_cents_per_mtok: u64) -> Self {
+        Self {
+            input_cents_per_mtok,
+            output_cents_per_mtok,
+        }
+    }
+
+    /// Cost of a request in micro-cents.
+    ///
+    /// Tokens times cents-per-million-tokens is exactly micro-cents.
+    pub fn cost_micro_cents(&self, prompt_tokens: u32, completion_tokens: u32) -> u64 {
+        prompt_tokens as u64 * self.input_cents_per_mtok
+            + completion_tokens as u64 * self.output_cents_per_mtok
+    }
+}
+
+/// Model prices keyed by model name prefix.
+///
+/// Lookups use the longest matching prefix, so `"claude-3-5-haiku"` can be
+/// priced separately from a broader `"claude-3"` entry.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PriceTable {
+    prices: HashMap<String, ModelPrice>,
+}
+
+impl PriceTable {
+    /// Creates an empty table. Unknown models are priced at zero.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Published list prices for the models the application uses.
+    pub fn with_default_prices() -> Self {
+        Self::new()
+            .with_price("claude-opus-4", ModelPrice::new(1500, 7500))
+            .with_price("claude-3-opus", ModelPrice::new(1500, 7500))
+            .with_price("claude-sonnet-4", ModelPrice::new(300, 1500))
+            .with_price("claude-3-5-sonnet", ModelPrice::new(300, 1500))
+            .with_price("claude-3-5-haiku", ModelPrice::new(80, 400))
+            .with_price("claude-3-haiku", ModelPrice::new(25, 125))
+            .with_price("gpt-4o-mini", ModelPrice::new(15, 60))
+            .with_price("gpt-4o", ModelPrice::new(250, 1000))
+            .with_price("gpt-4-turbo", ModelPrice::new(1000, 3000))
+            .with_price("gpt-4", ModelPrice::new(3000, 6000))
+            .with_price("gpt-3.5-turbo", ModelPrice::new(50, 150))
+    }
+
+    /// Adds or replaces the price for a model prefix.
+    pub fn with_price(mut self, model_prefix: impl Into<String>, price: ModelPrice) -> Self {
+        self.prices.insert(model_prefix.into(), price);
+        self
+    }
+
+    /// Price for a model, by longest matching prefix.
+    pub fn price_for(&self, model: &str) -> Option<ModelPrice> {
+        self.prices
+            .iter()
+            .filter(|(prefix, _)| model.starts_with(prefix.as_str()))
+            .max_by_key(|(prefix, _)| prefix.len())
+            .map(|(_, price)| *price)
+    }
+
+    /// Cost of a request in micro-cents. Unknown models cost zero.
+    pub fn cost_micro_cents(&self, model: &str, prompt_tokens: u32, completion_tokens: u32) -> u64 {
+        self.price_for(model)
+            .map_or(0, |price| price.cost_micro_cents(prompt_tokens, completion_tokens))
+    }
+}
 
 /// Record of AI usage for a single request.
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -27,6 +115,15 @@ pub struct UsageRecord {
     pub cost_cents: u32,
     /// Component type (for analytics).
     pub component_type: Option<ComponentType>,
+    /// Cycle the request was made for, if any.
+    #[serde(default)]
+    pub cycle_id: Option<CycleId>,
+    /// Exact cost in micro-cents.
+    #[serde(default)]
+    pub cost_micro_cents: u64,
+    /// Wall-clock time of the provider call.
+    #[serde(default)]
+    pub latency_ms: Option<u64>,
     /// When the usage occurred.
     pub occurred_at: Timestamp,
 }
@@ -53,10 +150,32 @@ impl UsageRecord {
             completion_tokens,
             cost_cents,
             component_type,
+            cycle_id: None,
+            cost_micro_cents: cost_cents as u64 * MICRO_CENTS_PER_CENT,
+            latency_ms: None,
             occurred_at: Timestamp::now(),
         }
     }
 
+    /// Attributes the usage to a cycle.
+    pub fn with_cycle_id(mut self, cycle_id: CycleId) -> Self {
+        self.cycle_id = Some(cycle_id);
+        self
+    }
+
+    /// Sets the exact cost, keeping `cost_cents` consistent.
+    pub fn with_cost_micro_cents(mut self, cost_micro_cents: u64) -> Self {
+        self.cost_micro_cents = cost_micro_cents;
+        self.cost_cents = (cost_micro_cents / MICRO_CENTS_PER_CENT) as u32;
+        self
+    }
+
+    /// Records how long the provider call took.
+    pub fn with_latency_ms(mut self, latency_ms: u64) -> Self {
+        self.latency_ms = Some(latency_ms);
+        self
+    }
+
     /// Total tokens used.
     pub fn total_tokens(&self) -> u32 {
         self.prompt_tokens + self.completion_tokens
@@ -89,6 +208,89 @@ pub struct ProviderUsage {
     pub requests: u32,
 }
 
+/// AI cost and latency for one cycle, broken down by component.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct CycleCostRollup {
+    /// Totals across the whole cycle.
+    pub total: CostBreakdown,
+    /// Per-component totals, in PrOACT order.
+    pub by_component: Vec<(ComponentType, CostBreakdown)>,
+    /// Per-model totals, sorted by model name.
+    pub by_model: Vec<(String, CostBreakdown)>,
+}
+
+impl CycleCostRollup {
+    /// Builds a rollup from usage records (records for other cycles are
+    /// the caller's responsibility to exclude).
+    pub fn from_records<'a>(records: impl IntoIterator<Item = &'a UsageRecord>) -> Self {
+        let mut rollup = Self::default();
+        let mut by_component: HashMap<ComponentType, CostBreakdown> = HashMap::new();
+        let mut by_model: HashMap<String, CostBreakdown> = HashMap::new();
+
+        for record in records {
+            rollup.total.add(record);
+            if let Some(component_type) = record.component_type {
+                by_component.entry(component_type).or_default().add(record);
+            }
+            by_model.entry(record.model.clone()).or_default().add(record);
+        }
+
+        rollup.by_component = by_component.into_iter().collect();
+        rollup
+            .by_component
+            .sort_by_key(|(component_type, _)| component_type.order_index());
+        rollup.by_model = by_model.into_iter().collect();
+        rollup.by_model.sort_by(|a, b| a.0.cmp(&b.0));
+        rollup
+    }
+
+    /// Totals for one component (zero if it made no AI requests).
+    pub fn component(&self, component_type: ComponentType) -> CostBreakdown {
+        self.by_component
+            .iter()
+            .find(|(ct, _)| *ct == component_type)
+            .map(|(_, breakdown)| breakdown.clone())
+            .unwrap_or_default()
+    }
+}
+
+/// Aggregated cost, tokens, and latency for a set of requests.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CostBreakdown {
+    /// Total cost in micro-cents.
+    pub cost_micro_cents: u64,
+    /// Total tokens used.
+    pub tokens: u64,
+    /// Number of requests.
+    pub requests: u32,
+    /// Sum of recorded latencies.
+    pub total_latency_ms: u64,
+    /// Requests that recorded a latency.
+    pub timed_requests: u32,
+}
+
+impl CostBreakdown {
+    fn add(&mut self, record: &UsageRecord) {
+        self.cost_micro_cents += record.cost_micro_cents;
+        self.tokens += record.total_tokens() as u64;
+        self.requests += 1;
+        if let Some(latency_ms) = record.latency_ms {
+            self.total_latency_ms += latency_ms;
+            self.timed_requests += 1;
+        }
+    }
+
+    /// Total cost in (fractional) cents.
+    pub fn cost_cents(&self) -> f64 {
+        self.cost_micro_cents as f64 / MICRO_CENTS_PER_CENT as f64
+    }
+
+    /// Mean latency across timed requests.
+    pub fn average_latency_ms(&self) -> Option<u64> {
+        (self.timed_requests > 0).then(|| self.total_latency_ms / self.timed_requests as u64)
+    }
+}
+
 /// Status of usage relative to a limit.
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub enum UsageLimitStatus {
@@ -163,6 +365,9 @@ pub trait UsageTracker: Send + Sync {
     /// Gets total cost for a specific session.
     async fn get_session_cost(&self, session_id: SessionId) -> Result<u32, UsageTrackerError>;
 
+    /// Gets cost and latency for a cycle, broken down by component and model.
+    async fn get_cycle_costs(&self, cycle_id: CycleId) -> Result<CycleCostRollup, UsageTrackerError>;
+
     /// Gets usage summary for a user within a time range.
     async fn get_usage_summary(
         &self,
@@ -171,6 +376,13 @@ pub trait UsageTracker: Send + Sync {
         to: Timestamp,
     ) -> Result<UsageSummary, UsageTrackerError>;
 
+    /// Lists users with any usage within a time range (inclusive).
+    async fn list_users_with_usage(
+        &self,
+        from: Timestamp,
+        to: Timestamp,
+    ) -> Result<Vec<UserId>, UsageTrackerError>;
+
     /// Checks if user is within daily limit.
     async fn check_daily_limit(
         &self,
@@ -184,6 +396,10 @@ pub trait UsageTracker: Send + Sync {
         session_id: SessionId,
         limit_cents: u32,
     ) -> Result<UsageLimitStatus, UsageTrackerError>;
+
+    /// Deletes every usage record for a user, returning how many were
+    /// removed. Only for right-to-erasure workflows.
+    async fn erase_user(&self, user_id: &UserId) -> Result<u64, UsageTrackerError>;
 }
 
 /// Errors from the usage tracker.
@@ -222,6 +438,58 @@ mod tests {
         assert_eq!(record.total_tokens(), 150);
     }
 
+    #[test]
+    fn price_table_uses_longest_prefix() {
+        let table = PriceTable::with_default_prices();
+        assert_eq!(table.price_for("gpt-4o-mini-2024-07-18"), Some(ModelPrice::new(15, 60)));
+        assert_eq!(table.price_for("gpt-4o-2024-08-06"), Some(ModelPrice::new(250, 1000)));
+        assert_eq!(table.price_for("gpt-4-0613"), Some(ModelPrice::new(3000, 6000)));
+        assert_eq!(table.price_for("unknown-model"), None);
+    }
+
+    #[test]
+    fn price_table_costs_sub_cent_requests_exactly() {
+        let table = PriceTable::new().with_price("claude-sonnet-4", ModelPrice::new(300, 1500));
+        // 1000 prompt + 200 completion tokens = 0.6 cents
+        assert_eq!(table.cost_micro_cents("claude-sonnet-4-20250514", 1000, 200), 600_000);
+        assert_eq!(table.cost_micro_cents("unknown", 1000, 200), 0);
+    }
+
+    #[test]
+    fn with_cost_micro_cents_keeps_cents_consistent() {
+        let record = UsageRecord::new(UserId::new("u").unwrap(), SessionId::new(), "anthropic", "m", 1, 1, 0, None)
+            .with_cost_micro_cents(2_500_000);
+        assert_eq!(record.cost_cents, 2);
+        assert_eq!(record.cost_micro_cents, 2_500_000);
+    }
+
+    #[test]
+    fn cycle_rollup_groups_by_component_and_model() {
+        let user_id = UserId::new("u").unwrap();
+        let session_id = SessionId::new();
+        let record = |component, model: &str, cost, latency| {
+            UsageRecord::new(user_id.clone(), session_id, "anthropic", model, 100, 50, 0, Some(component))
+                .with_cost_micro_cents(cost)
+                .with_latency_ms(latency)
+        };
+        let records = vec![
+            record(ComponentType::Objectives, "claude-sonnet-4", 400_000, 1000),
+            record(ComponentType::IssueRaising, "claude-sonnet-4", 300_000, 3000),
+            record(ComponentType::Objectives, "claude-3-haiku", 100_000, 500),
+        ];
+
+        let rollup = CycleCostRollup::from_records(&records);
+
+        assert_eq!(rollup.total.cost_micro_cents, 800_000);
+        assert_eq!(rollup.total.requests, 3);
+        assert_eq!(rollup.by_component[0].0, ComponentType::IssueRaising);
+        let objectives = rollup.component(ComponentType::Objectives);
+        assert_eq!(objectives.cost_micro_cents, 500_000);
+        assert_eq!(objectives.average_latency_ms(), Some(750));
+        assert_eq!(rollup.by_model.len(), 2);
+        assert_eq!(rollup.component(ComponentType::Recommendation), CostBreakdown::default());
+    }
+
     #[test]
     fn usage_limit_status_under_limit() {
         let status = UsageLimitStatus::from_usage(50, 100);
@@ -0,0 +1,109 @@
+//! `choice-sherpa seed-demo <user-id>` - Fill an account with sample data.
+//!
+//! Creates three sample decisions for the user, stopped at different stages,
+//! with component outputs and conversation transcripts, for sales demos and
+//! frontend development:
+//!
+//! ```text
+//! $ choice-sherpa seed-demo auth0|demo-user
+//! Seeded 3 sessions and 17 conversations for auth0|demo-user
+//! ```
+//!
+//! Writes straight to the configured database. The CLI has no event bus, so
+//! the `component.completed` events the seeder publishes are not relayed to
+//! the analysis handlers.
+//!
+//! Exits 0 on success, 1 if seeding fails, and 2 on bad usage or
+//! configuration.
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use choice_sherpa::adapters::postgres::{
+    PostgresConversationRepository, PostgresCycleRepository, PostgresSessionRepository,
+};
+use choice_sherpa::application::handlers::demo::{
+    SeedDemoDataCommand, SeedDemoDataError, SeedDemoDataHandler,
+};
+use choice_sherpa::config::AppConfig;
+use choice_sherpa::domain::foundation::{CommandMetadata, DomainError, EventEnvelope, UserId};
+use choice_sherpa::ports::EventPublisher;
+use sqlx::postgres::PgPoolOptions;
+
+/// Drops events; the CLI has no bus to relay them to.
+struct DiscardEvents;
+
+#[async_trait]
+impl EventPublisher for DiscardEvents {
+    async fn publish(&self, _event: EventEnvelope) -> Result<(), DomainError> {
+        Ok(())
+    }
+
+    async fn publish_all(&self, _events: Vec<EventEnvelope>) -> Result<(), DomainError> {
+        Ok(())
+    }
+}
+
+pub async fn run(user_id: Option<String>) -> i32 {
+    let Some(user_id) = user_id.and_then(|id| UserId::new(id).ok()) else {
+        eprintln!("Usage: choice-sherpa seed-demo <user-id>");
+        return 2;
+    };
+
+    let config = match AppConfig::load() {
+        Ok(config) => config,
+        Err(e) => {
+            eprintln!("Failed to load configuration: {}", e);
+            return 2;
+        }
+    };
+
+    let pool = match PgPoolOptions::new()
+        .max_connections(1)
+        .connect(&config.database.url)
+        .await
+    {
+        Ok(pool) => pool,
+        Err(e) => {
+            eprintln!("Failed to connect to the database: {}", e);
+            return 1;
+        }
+    };
+
+    let handler = SeedDemoDataHandler::new(
+        Arc::new(PostgresSessionRepository::new(pool.clone())),
+        Arc::new(PostgresCycleRepository::new(pool.clone())),
+        Arc::new(PostgresConversationRepository::new(pool.clone())),
+        Arc::new(DiscardEvents),
+    );
+    let metadata = CommandMetadata::new(user_id.clone()).with_source("cli");
+    let result = handler
+        .handle(
+            SeedDemoDataCommand {
+                user_id: user_id.clone(),
+            },
+            metadata,
+        )
+        .await;
+    pool.close().await;
+
+    match result {
+        Ok(result) => {
+            println!(
+                "Seeded {} sessions and {} conversations for {}",
+                result.session_ids.len(),
+                result.conversations,
+                user_id
+            );
+            0
+        }
+        Err(e @ SeedDemoDataError::AlreadySeeded(_)) => {
+            eprintln!("{}", e);
+            0
+        }
+        Err(e) => {
+            eprintln!("Failed to seed demo data: {}", e);
+            1
+        }
+    }
+}
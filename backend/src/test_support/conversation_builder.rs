@@ -0,0 +1,125 @@
+//! ConversationBuilder - Conversations with scripted messages for tests.
+
+use crate::domain::conversation::{Conversation, ConversationState, Message};
+use crate::domain::foundation::{ComponentId, ConversationId, Timestamp};
+
+/// Builds a [`Conversation`] with the given messages, in the state the
+/// messages imply unless one is set.
+///
+/// ```ignore
+/// let conversation = ConversationBuilder::new()
+///     .component_id(component.id())
+///     .assistant("What's on your mind?")
+///     .user("Whether to take the new job")
+///     .build();
+/// ```
+#[derive(Debug, Clone)]
+pub struct ConversationBuilder {
+    id: ConversationId,
+    component_id: ComponentId,
+    state: Option<ConversationState>,
+    messages: Vec<Message>,
+}
+
+impl Default for ConversationBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ConversationBuilder {
+    /// An empty conversation for a new component.
+    pub fn new() -> Self {
+        Self {
+            id: ConversationId::new(),
+            component_id: ComponentId::new(),
+            state: None,
+            messages: Vec::new(),
+        }
+    }
+
+    pub fn id(mut self, id: ConversationId) -> Self {
+        self.id = id;
+        self
+    }
+
+    pub fn component_id(mut self, component_id: ComponentId) -> Self {
+        self.component_id = component_id;
+        self
+    }
+
+    /// Overrides the state implied by the messages.
+    pub fn state(mut self, state: ConversationState) -> Self {
+        self.state = Some(state);
+        self
+    }
+
+    /// # Panics
+    ///
+    /// If `content` is not a valid message.
+    pub fn user(mut self, content: impl Into<String>) -> Self {
+        self.messages
+            .push(Message::user(content).expect("valid user message"));
+        self
+    }
+
+    /// # Panics
+    ///
+    /// If `content` is not a valid message.
+    pub fn assistant(mut self, content: impl Into<String>) -> Self {
+        self.messages
+            .push(Message::assistant(content).expect("valid assistant message"));
+        self
+    }
+
+    /// # Panics
+    ///
+    /// If `content` is not a valid message.
+    pub fn system(mut self, content: impl Into<String>) -> Self {
+        self.messages
+            .push(Message::system(content).expect("valid system message"));
+        self
+    }
+
+    /// Without an explicit state: `InProgress` once the user has spoken,
+    /// otherwise `Ready`.
+    pub fn build(self) -> Conversation {
+        let state = self
+            .state
+            .unwrap_or(if self.messages.iter().any(Message::is_user) {
+                ConversationState::InProgress
+            } else {
+                ConversationState::Ready
+            });
+        let now = Timestamp::now();
+        Conversation::reconstitute(self.id, self.component_id, state, self.messages, now, now)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn state_follows_the_messages() {
+        let ready = ConversationBuilder::new().assistant("Hello").build();
+        assert_eq!(ready.state(), ConversationState::Ready);
+
+        let started = ConversationBuilder::new()
+            .assistant("What's on your mind?")
+            .user("Whether to take the new job")
+            .build();
+        assert_eq!(started.state(), ConversationState::InProgress);
+        assert_eq!(started.message_count(), 2);
+    }
+
+    #[test]
+    fn explicit_state_wins() {
+        let conversation = ConversationBuilder::new()
+            .user("Done")
+            .state(ConversationState::Complete)
+            .build();
+
+        assert!(conversation.is_complete());
+    }
+}
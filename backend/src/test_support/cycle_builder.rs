@@ -0,0 +1,199 @@
+//! CycleBuilder - Cycles at any stage for tests.
+
+use std::collections::HashMap;
+
+use crate::domain::cycle::Cycle;
+use crate::domain::foundation::{ComponentType, SessionId};
+use crate::domain::proact::ComponentSequence;
+
+/// Builds a [`Cycle`] with components started and completed in order, the
+/// way a user would have left them.
+///
+/// ```ignore
+/// let cycle = CycleBuilder::new()
+///     .session_id(session.id().clone())
+///     .completed_through(ComponentType::Alternatives)
+///     .in_progress(ComponentType::Consequences)
+///     .output(ComponentType::Consequences, json!({ ... }))
+///     .build();
+/// ```
+///
+/// Built cycles have no pending events, so tests only see the events their
+/// own actions raise.
+#[derive(Debug, Clone)]
+pub struct CycleBuilder {
+    session_id: SessionId,
+    completed_through: Option<ComponentType>,
+    in_progress: Option<ComponentType>,
+    outputs: HashMap<ComponentType, serde_json::Value>,
+    completed: bool,
+    archived: bool,
+}
+
+impl Default for CycleBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl CycleBuilder {
+    /// A fresh cycle in a new session.
+    pub fn new() -> Self {
+        Self {
+            session_id: SessionId::new(),
+            completed_through: None,
+            in_progress: None,
+            outputs: HashMap::new(),
+            completed: false,
+            archived: false,
+        }
+    }
+
+    pub fn session_id(mut self, session_id: SessionId) -> Self {
+        self.session_id = session_id;
+        self
+    }
+
+    /// Completes every component up to and including `ct`.
+    pub fn completed_through(mut self, ct: ComponentType) -> Self {
+        self.completed_through = Some(ct);
+        self
+    }
+
+    /// Leaves `ct` in progress, completing every component before it.
+    pub fn in_progress(mut self, ct: ComponentType) -> Self {
+        self.in_progress = Some(ct);
+        self
+    }
+
+    /// Output to set on `ct` once it is started.
+    pub fn output(mut self, ct: ComponentType, output: serde_json::Value) -> Self {
+        self.outputs.insert(ct, output);
+        self
+    }
+
+    /// Completes the cycle, completing components through DecisionQuality
+    /// if needed.
+    pub fn completed(mut self) -> Self {
+        self.completed = true;
+        self
+    }
+
+    pub fn archived(mut self) -> Self {
+        self.archived = true;
+        self
+    }
+
+    /// # Panics
+    ///
+    /// If the requested stages contradict each other, e.g. a cycle both
+    /// completed and with a component in progress, or an output that the
+    /// component's schema rejects.
+    pub fn build(self) -> Cycle {
+        let order = |ct: ComponentType| ComponentSequence::order_index(ct);
+        let mut completed_through = self.completed_through.map(order);
+        if self.completed {
+            assert!(
+                self.in_progress.is_none(),
+                "a completed cycle has no component in progress"
+            );
+            let dq = order(ComponentType::DecisionQuality);
+            completed_through = Some(completed_through.map_or(dq, |i| i.max(dq)));
+        }
+        let in_progress = self.in_progress.map(order);
+        if let (Some(done), Some(open)) = (completed_through, in_progress) {
+            assert!(
+                open > done,
+                "the in-progress component must come after the completed ones"
+            );
+        }
+
+        let mut cycle = Cycle::new(self.session_id);
+        for (i, ct) in ComponentSequence::all().iter().enumerate() {
+            let complete = completed_through.is_some_and(|done| i <= done);
+            let open = in_progress.is_some_and(|open| i <= open);
+            if !complete && !open {
+                break;
+            }
+            cycle.start_component(*ct).expect("component can start");
+            if let Some(output) = self.outputs.get(ct) {
+                cycle
+                    .update_component_output(*ct, output.clone())
+                    .expect("output matches the component schema");
+            }
+            if in_progress != Some(i) {
+                cycle
+                    .complete_component(*ct)
+                    .expect("component can complete");
+            }
+        }
+
+        if self.completed {
+            cycle.complete().expect("cycle can complete");
+        }
+        if self.archived {
+            cycle.archive().expect("cycle can be archived");
+        }
+        cycle.take_events();
+        cycle
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::foundation::{ComponentStatus, CycleStatus};
+
+    #[test]
+    fn builds_a_fresh_cycle_by_default() {
+        let mut cycle = CycleBuilder::new().build();
+
+        assert_eq!(cycle.status(), CycleStatus::Active);
+        assert_eq!(
+            cycle.component_status(ComponentType::IssueRaising),
+            ComponentStatus::NotStarted
+        );
+        assert!(cycle.take_events().is_empty());
+    }
+
+    #[test]
+    fn completes_components_before_the_one_in_progress() {
+        let cycle = CycleBuilder::new()
+            .in_progress(ComponentType::Consequences)
+            .build();
+
+        assert_eq!(
+            cycle.component_status(ComponentType::Alternatives),
+            ComponentStatus::Complete
+        );
+        assert_eq!(
+            cycle.component_status(ComponentType::Consequences),
+            ComponentStatus::InProgress
+        );
+        assert_eq!(
+            cycle.component_status(ComponentType::Tradeoffs),
+            ComponentStatus::NotStarted
+        );
+        assert_eq!(cycle.current_step(), ComponentType::Consequences);
+    }
+
+    #[test]
+    fn completed_cycles_have_decision_quality_done() {
+        let cycle = CycleBuilder::new().completed().build();
+
+        assert_eq!(cycle.status(), CycleStatus::Completed);
+        assert_eq!(
+            cycle.component_status(ComponentType::DecisionQuality),
+            ComponentStatus::Complete
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "in-progress component must come after")]
+    fn rejects_contradictory_stages() {
+        CycleBuilder::new()
+            .completed_through(ComponentType::Tradeoffs)
+            .in_progress(ComponentType::Objectives)
+            .build();
+    }
+}
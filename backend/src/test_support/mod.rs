@@ -0,0 +1,32 @@
+//! Test support - mocks, fakes, and aggregate builders.
+//!
+//! Compiled for this crate's own tests and, behind the `test-support`
+//! feature, for downstream integration and contract tests:
+//!
+//! ```toml
+//! [dev-dependencies]
+//! choice-sherpa = { path = "../backend", features = ["test-support"] }
+//! ```
+//!
+//! # Types
+//!
+//! - `MockAIProvider` - Scripted AI responses and errors
+//! - `MockAuthProvider`, `MockSessionValidator` - Auth without an identity provider
+//! - `InMemoryEmailSender` - Records emails instead of sending them
+//! - `MockPaymentProvider` - In-memory payment provider
+//! - `InMemoryEventBus` - Captures published events for assertions
+//! - `StubAccessChecker` - Membership checks that always allow (or deny)
+//! - `SessionBuilder`, `CycleBuilder`, `ConversationBuilder` - Aggregates in
+//!   realistic states without walking through the command handlers
+
+mod conversation_builder;
+mod cycle_builder;
+mod session_builder;
+
+pub use crate::adapters::{
+    InMemoryEmailSender, InMemoryEventBus, MockAIProvider, MockAuthProvider, MockError,
+    MockPaymentProvider, MockResponse, MockSessionValidator, StubAccessChecker,
+};
+pub use conversation_builder::ConversationBuilder;
+pub use cycle_builder::CycleBuilder;
+pub use session_builder::SessionBuilder;
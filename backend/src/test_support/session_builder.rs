@@ -0,0 +1,123 @@
+//! SessionBuilder - Sessions for tests.
+
+use crate::domain::foundation::{CycleId, SessionId, UserId};
+use crate::domain::session::Session;
+
+/// Builds a [`Session`] owned by `test-user` unless told otherwise.
+///
+/// ```ignore
+/// let session = SessionBuilder::new()
+///     .user_id(user.clone())
+///     .title("Which job?")
+///     .cycle(cycle.id())
+///     .build();
+/// ```
+#[derive(Debug, Clone)]
+pub struct SessionBuilder {
+    id: SessionId,
+    user_id: UserId,
+    title: String,
+    description: Option<String>,
+    cycle_ids: Vec<CycleId>,
+    archived: bool,
+}
+
+impl Default for SessionBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SessionBuilder {
+    pub fn new() -> Self {
+        Self {
+            id: SessionId::new(),
+            user_id: UserId::new("test-user").expect("valid user id"),
+            title: "Test session".to_string(),
+            description: None,
+            cycle_ids: Vec::new(),
+            archived: false,
+        }
+    }
+
+    pub fn id(mut self, id: SessionId) -> Self {
+        self.id = id;
+        self
+    }
+
+    pub fn user_id(mut self, user_id: UserId) -> Self {
+        self.user_id = user_id;
+        self
+    }
+
+    pub fn title(mut self, title: impl Into<String>) -> Self {
+        self.title = title.into();
+        self
+    }
+
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    /// Adds a cycle; the first one added is the root cycle.
+    pub fn cycle(mut self, cycle_id: CycleId) -> Self {
+        self.cycle_ids.push(cycle_id);
+        self
+    }
+
+    pub fn archived(mut self) -> Self {
+        self.archived = true;
+        self
+    }
+
+    /// # Panics
+    ///
+    /// If the title is empty or too long for a session.
+    pub fn build(self) -> Session {
+        let mut session =
+            Session::new(self.id, self.user_id, self.title).expect("valid session title");
+        session
+            .update_description(self.description)
+            .expect("session is active");
+        for cycle_id in self.cycle_ids {
+            session.add_cycle(cycle_id).expect("session is active");
+        }
+        if self.archived {
+            session.archive().expect("session is active");
+        }
+        session
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::foundation::SessionStatus;
+
+    #[test]
+    fn builds_an_active_session_by_default() {
+        let session = SessionBuilder::new().build();
+
+        assert_eq!(session.status(), SessionStatus::Active);
+        assert_eq!(session.user_id().as_str(), "test-user");
+        assert!(session.cycle_ids().is_empty());
+    }
+
+    #[test]
+    fn applies_cycles_and_archiving() {
+        let cycle_id = CycleId::new();
+
+        let session = SessionBuilder::new()
+            .title("Which job?")
+            .description("Two offers on the table")
+            .cycle(cycle_id)
+            .archived()
+            .build();
+
+        assert_eq!(session.title(), "Which job?");
+        assert_eq!(session.description(), Some("Two offers on the table"));
+        assert_eq!(session.cycle_ids(), [cycle_id]);
+        assert_eq!(session.status(), SessionStatus::Archived);
+    }
+}
@@ -0,0 +1,41 @@
+//! `choice-sherpa validate-template <file>` - Check a document template.
+//!
+//! Compiles the template in the same sandbox the exporter uses and renders
+//! it against a complete and an empty sample document, so admins can check
+//! a template before uploading it:
+//!
+//! ```text
+//! $ choice-sherpa validate-template acme.com.md.tera
+//! acme.com.md.tera: template OK
+//! ```
+//!
+//! Exits 0 if the template is valid, 1 if it is not, and 2 if the file
+//! cannot be read.
+
+use choice_sherpa::adapters::TemplateDocumentExporter;
+
+pub fn run(path: Option<String>) -> i32 {
+    let Some(path) = path else {
+        eprintln!("Usage: choice-sherpa validate-template <file>");
+        return 2;
+    };
+
+    let source = match std::fs::read_to_string(&path) {
+        Ok(source) => source,
+        Err(e) => {
+            eprintln!("{}: {}", path, e);
+            return 2;
+        }
+    };
+
+    match TemplateDocumentExporter::new(Vec::new()).validate(&source) {
+        Ok(()) => {
+            println!("{}: template OK", path);
+            0
+        }
+        Err(e) => {
+            eprintln!("{}: {}", path, e);
+            1
+        }
+    }
+}
@@ -51,7 +51,8 @@ export type DashboardUpdateType =
     | 'component_output'    // Component output updated
     | 'conversation_message' // New chat message
     | 'analysis_scores'     // Pugh/DQ scores computed
-    | 'cycle_completed';    // Cycle finished
+    | 'cycle_completed'     // Cycle finished
+    | 'document_changed';   // Document sections edited
 
 /**
  * Error message from server.
@@ -15,8 +15,8 @@ import {
 } from './types';
 
 describe('COMPONENT_ORDER', () => {
-	it('has 9 components', () => {
-		expect(COMPONENT_ORDER).toHaveLength(9);
+	it('has 11 components', () => {
+		expect(COMPONENT_ORDER).toHaveLength(11);
 	});
 
 	it('starts with issue_raising', () => {
@@ -24,7 +24,7 @@ describe('COMPONENT_ORDER', () => {
 	});
 
 	it('ends with notes_next_steps', () => {
-		expect(COMPONENT_ORDER[8]).toBe('notes_next_steps');
+		expect(COMPONENT_ORDER[10]).toBe('notes_next_steps');
 	});
 });
 
@@ -49,11 +49,11 @@ describe('getComponentIndex', () => {
 	});
 
 	it('returns correct index for middle component', () => {
-		expect(getComponentIndex('alternatives')).toBe(3);
+		expect(getComponentIndex('alternatives')).toBe(4);
 	});
 
-	it('returns 8 for last component', () => {
-		expect(getComponentIndex('notes_next_steps')).toBe(8);
+	it('returns 10 for last component', () => {
+		expect(getComponentIndex('notes_next_steps')).toBe(10);
 	});
 });
 
@@ -128,7 +128,7 @@ describe('calculateProgress', () => {
 	});
 
 	it('returns correct percentage for partial completion', () => {
-		expect(calculateProgress(threeComplete)).toBe(33); // 3/9 = 33%
+		expect(calculateProgress(threeComplete)).toBe(27); // 3/11 = 27%
 	});
 
 	it('returns 100 when all components complete', () => {
@@ -12,9 +12,11 @@
 export type ComponentType =
 	| 'issue_raising'
 	| 'problem_frame'
+	| 'stakeholder_analysis'
 	| 'objectives'
 	| 'alternatives'
 	| 'consequences'
+	| 'uncertainty_register'
 	| 'tradeoffs'
 	| 'recommendation'
 	| 'decision_quality'
@@ -23,9 +25,11 @@ export type ComponentType =
 export const COMPONENT_ORDER: ComponentType[] = [
 	'issue_raising',
 	'problem_frame',
+	'stakeholder_analysis',
 	'objectives',
 	'alternatives',
 	'consequences',
+	'uncertainty_register',
 	'tradeoffs',
 	'recommendation',
 	'decision_quality',
@@ -35,9 +39,11 @@ export const COMPONENT_ORDER: ComponentType[] = [
 export const COMPONENT_LABELS: Record<ComponentType, string> = {
 	issue_raising: 'Issue Raising',
 	problem_frame: 'Problem Frame',
+	stakeholder_analysis: 'Stakeholder Analysis',
 	objectives: 'Objectives',
 	alternatives: 'Alternatives',
 	consequences: 'Consequences',
+	uncertainty_register: 'Uncertainty Register',
 	tradeoffs: 'Tradeoffs',
 	recommendation: 'Recommendation',
 	decision_quality: 'Decision Quality',
@@ -10,7 +10,10 @@ import type { ComponentType } from '../../cycle/domain/types';
 import type {
 	DashboardOverview,
 	ComponentDetailView,
-	CycleComparison
+	CycleComparison,
+	DecisionTreeView,
+	GroupComparisonView,
+	WeightedComparisonView
 } from '../domain/types';
 
 // ─────────────────────────────────────────────────────────────────────
@@ -135,3 +138,57 @@ export async function compareCycles(
 	);
 	return handleResponse(response);
 }
+
+/**
+ * Get weighted additive scores for a cycle's alternatives.
+ * @param session - Auth session
+ * @param cycleId - Cycle ID
+ */
+export async function getWeightedComparison(
+	session: Session | null,
+	cycleId: string
+): Promise<WeightedComparisonView> {
+	const response = await authFetch(
+		`/api/cycles/${cycleId}/comparison/weighted`,
+		session,
+		{ method: 'GET' }
+	);
+	return handleResponse(response);
+}
+
+/**
+ * Get participants' preferences aggregated into a group view with dissent.
+ * @param session - Auth session
+ * @param cycleId - Cycle ID
+ * @param approvalThreshold - Score (0-100) at which a participant approves (default 60)
+ */
+export async function getGroupComparison(
+	session: Session | null,
+	cycleId: string,
+	approvalThreshold?: number
+): Promise<GroupComparisonView> {
+	const query = approvalThreshold === undefined ? '' : `?approval_threshold=${approvalThreshold}`;
+	const response = await authFetch(
+		`/api/cycles/${cycleId}/comparison/group${query}`,
+		session,
+		{ method: 'GET' }
+	);
+	return handleResponse(response);
+}
+
+/**
+ * Get the decision trees built for a cycle.
+ * @param session - Auth session
+ * @param cycleId - Cycle ID
+ */
+export async function getDecisionTrees(
+	session: Session | null,
+	cycleId: string
+): Promise<DecisionTreeView[]> {
+	const response = await authFetch(
+		`/api/cycles/${cycleId}/decision-trees`,
+		session,
+		{ method: 'GET' }
+	);
+	return handleResponse(response);
+}
@@ -156,6 +156,147 @@ export interface ComparisonSummary {
 	recommendation_differs: boolean;
 }
 
+/**
+ * Weighted additive scores for a cycle's alternatives.
+ */
+export interface WeightedComparisonView {
+	cycleId: string;
+	scoringMethod: 'pugh' | 'weighted_additive';
+	objectives: WeightedObjectiveColumn[];
+	/** Ordered from best to worst */
+	alternatives: WeightedAlternativeRow[];
+	bestAlternativeId: string | null;
+}
+
+/**
+ * Objective column with its normalized weight.
+ */
+export interface WeightedObjectiveColumn {
+	id: string;
+	name: string;
+	weight: number;
+	basis: 'measure' | 'rating';
+}
+
+/**
+ * Alternative row with its score out of 100.
+ */
+export interface WeightedAlternativeRow {
+	id: string;
+	name: string;
+	score: number;
+	rank: number;
+	contributions: ContributionCell[];
+}
+
+/**
+ * Points one objective adds to an alternative's score.
+ */
+export interface ContributionCell {
+	objectiveId: string;
+	value: number | null;
+	points: number;
+}
+
+/**
+ * A decision tree laid out for rendering.
+ */
+export interface DecisionTreeView {
+	id: string;
+	cycleId: string;
+	name: string;
+	payoffUnit: string | null;
+	/** Null until the tree is complete */
+	expectedValue: number | null;
+	/** Why the tree cannot be rolled back yet */
+	validationIssue: string | null;
+	/** Depth-first order, root first */
+	nodes: DecisionTreeNodeView[];
+}
+
+/**
+ * One node of a decision tree.
+ */
+export interface DecisionTreeNodeView {
+	id: string;
+	label: string;
+	kind: 'decision' | 'chance' | 'terminal';
+	depth: number;
+	parentId: string | null;
+	branchLabel: string | null;
+	probability: number | null;
+	payoff: number | null;
+	expectedValue: number | null;
+	onOptimalPath: boolean;
+}
+
+/**
+ * Participants' preferences aggregated into a group view.
+ */
+export interface GroupComparisonView {
+	cycleId: string;
+	participantCount: number;
+	/** Score (0-100) at which a participant approves an alternative */
+	approvalThreshold: number;
+	/** Table order */
+	alternatives: GroupAlternativeRow[];
+	/** Table order */
+	objectives: GroupObjectiveRow[];
+	bordaWinnerId: string | null;
+	approvalWinnerId: string | null;
+	weightedWinnerId: string | null;
+	dissent: GroupDissentView;
+}
+
+/**
+ * How the group rates one alternative under each method.
+ */
+export interface GroupAlternativeRow {
+	id: string;
+	name: string;
+	bordaPoints: number;
+	approvals: number;
+	meanScore: number | null;
+	scoreSpread: number;
+	/** Score out of 100 using the averaged weights */
+	weightedScore: number;
+	contested: boolean;
+}
+
+/**
+ * The group's averaged weight for one objective.
+ */
+export interface GroupObjectiveRow {
+	id: string;
+	name: string;
+	meanWeight: number;
+	spread: number;
+	minWeight: number;
+	maxWeight: number;
+	contested: boolean;
+}
+
+/**
+ * Where participants disagree with the group.
+ */
+export interface GroupDissentView {
+	participants: ParticipantDissentRow[];
+	/** 1 = everyone agrees with the Borda ranking */
+	consensus: number | null;
+}
+
+/**
+ * How far one participant is from the group ranking.
+ */
+export interface ParticipantDissentRow {
+	participantId: string;
+	name: string;
+	topChoiceId: string | null;
+	agreesWithGroup: boolean;
+	/** 0 = same ranking as the group, 1 = reversed */
+	rankDistance: number;
+}
+
 // ─────────────────────────────────────────────────────────────────────
 // Helper Functions
 // ─────────────────────────────────────────────────────────────────────
@@ -18,7 +18,18 @@ export type {
 	CycleProgressSummary,
 	ComponentComparisonSummary,
 	ComparisonDifference,
-	ComparisonSummary
+	ComparisonSummary,
+	WeightedComparisonView,
+	WeightedObjectiveColumn,
+	WeightedAlternativeRow,
+	ContributionCell,
+	DecisionTreeView,
+	DecisionTreeNodeView,
+	GroupComparisonView,
+	GroupAlternativeRow,
+	GroupObjectiveRow,
+	GroupDissentView,
+	ParticipantDissentRow
 } from './domain/types';
 
 export {
@@ -35,6 +46,9 @@ export {
 	getDashboardOverview,
 	getComponentDetail,
 	compareCycles,
+	getWeightedComparison,
+	getDecisionTrees,
+	getGroupComparison,
 	ApiError
 } from './api/dashboard-api';
 
@@ -47,5 +61,8 @@ export { default as DecisionStatement } from './components/DecisionStatement.sve
 export { default as ObjectivesList } from './components/ObjectivesList.svelte';
 export { default as AlternativesPills } from './components/AlternativesPills.svelte';
 export { default as ConsequencesMatrix } from './components/ConsequencesMatrix.svelte';
+export { default as WeightedScoresView } from './components/WeightedScoresView.svelte';
+export { default as DecisionTreeDiagram } from './components/DecisionTreeDiagram.svelte';
+export { default as GroupPreferencesPanel } from './components/GroupPreferencesPanel.svelte';
 export { default as RecommendationCard } from './components/RecommendationCard.svelte';
 export { default as DQScoreBadge } from './components/DQScoreBadge.svelte';
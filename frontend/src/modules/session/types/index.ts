@@ -20,6 +20,8 @@ export interface SessionSummary {
   title: string;
   status: SessionStatus;
   cycle_count: number;
+  /** Cycles are in cold storage and load back when the session is opened */
+  in_cold_storage: boolean;
   updated_at: string;
 }
 